@@ -0,0 +1,18659 @@
+use phf::phf_map;
+
+/// Number of adjacent word pairs in the corpus the counts were taken from
+pub const CORPUS_PAIRS: u64 = 3564463;
+
+/// How often adjacent word pairs occur in the corpus, e.g. "of the"
+pub static WORD_BIGRAMS: phf::Map<&'static str, u32> = phf_map! {
+    "a a" => 100,
+    "a able" => 3,
+    "a across" => 21,
+    "a add" => 10,
+    "a address" => 3,
+    "a after" => 9,
+    "a allows" => 3,
+    "a although" => 4,
+    "a an" => 5,
+    "a and" => 1378,
+    "a are" => 16,
+    "a argument" => 13,
+    "a arm" => 4,
+    "a as" => 54,
+    "a associated" => 6,
+    "a asynchronous" => 3,
+    "a at" => 12,
+    "a attribute" => 48,
+    "a available" => 3,
+    "a back" => 10,
+    "a backend" => 29,
+    "a badge" => 3,
+    "a base" => 47,
+    "a based" => 11,
+    "a basic" => 86,
+    "a because" => 14,
+    "a been" => 3,
+    "a before" => 11,
+    "a behavior" => 5,
+    "a being" => 28,
+    "a benchmarks" => 3,
+    "a best" => 56,
+    "a better" => 129,
+    "a between" => 3,
+    "a big" => 72,
+    "a binary" => 197,
+    "a bindings" => 4,
+    "a bit" => 804,
+    "a bits" => 5,
+    "a block" => 247,
+    "a body" => 37,
+    "a book" => 32,
+    "a boolean" => 120,
+    "a both" => 3,
+    "a bound" => 74,
+    "a bounds" => 4,
+    "a branch" => 77,
+    "a break" => 10,
+    "a breaking" => 120,
+    "a buffer" => 442,
+    "a bug" => 199,
+    "a build" => 183,
+    "a building" => 19,
+    "a built" => 56,
+    "a but" => 21,
+    "a by" => 207,
+    "a byte" => 330,
+    "a bytes" => 6,
+    "a cache" => 33,
+    "a call" => 175,
+    "a callback" => 127,
+    "a calling" => 4,
+    "a can" => 53,
+    "a cannot" => 4,
+    "a cargo" => 117,
+    "a case" => 68,
+    "a certain" => 150,
+    "a change" => 152,
+    "a changed" => 18,
+    "a channel" => 140,
+    "a chapter" => 5,
+    "a char" => 14,
+    "a character" => 294,
+    "a chat" => 7,
+    "a check" => 52,
+    "a child" => 105,
+    "a christian" => 5,
+    "a class" => 152,
+    "a clear" => 36,
+    "a client" => 106,
+    "a close" => 16,
+    "a closed" => 68,
+    "a closure" => 215,
+    "a code" => 92,
+    "a collection" => 197,
+    "a color" => 57,
+    "a column" => 42,
+    "a command" => 406,
+    "a comment" => 146,
+    "a commit" => 69,
+    "a common" => 177,
+    "a comparison" => 32,
+    "a compatibility" => 9,
+    "a compatible" => 37,
+    "a compilation" => 48,
+    "a compile" => 114,
+    "a compiled" => 33,
+    "a compiler" => 108,
+    "a complete" => 126,
+    "a completion" => 4,
+    "a complex" => 53,
+    "a configuration" => 37,
+    "a configure" => 20,
+    "a connection" => 48,
+    "a consider" => 4,
+    "a const" => 56,
+    "a constant" => 128,
+    "a contained" => 26,
+    "a containing" => 35,
+    "a contains" => 10,
+    "a content" => 5,
+    "a context" => 72,
+    "a contributing" => 15,
+    "a control" => 19,
+    "a conversion" => 23,
+    "a copy" => 247,
+    "a core" => 40,
+    "a correct" => 25,
+    "a corresponding" => 45,
+    "a count" => 101,
+    "a cpu" => 20,
+    "a crash" => 155,
+    "a crate" => 371,
+    "a crates" => 3,
+    "a created" => 9,
+    "a cross" => 81,
+    "a current" => 11,
+    "a cursor" => 44,
+    "a custom" => 349,
+    "a data" => 87,
+    "a day" => 46,
+    "a debug" => 23,
+    "a default" => 234,
+    "a define" => 3,
+    "a defined" => 9,
+    "a definition" => 16,
+    "a delete" => 7,
+    "a dependency" => 351,
+    "a deprecated" => 7,
+    "a derive" => 33,
+    "a development" => 22,
+    "a didn't" => 3,
+    "a different" => 579,
+    "a directly" => 7,
+    "a directory" => 365,
+    "a display" => 19,
+    "a do" => 7,
+    "a doc" => 5,
+    "a document" => 39,
+    "a documentation" => 8,
+    "a does" => 15,
+    "a doesn't" => 9,
+    "a double" => 172,
+    "a drop" => 63,
+    "a dual" => 5,
+    "a dynamic" => 57,
+    "a element" => 8,
+    "a enables" => 3,
+    "a entry" => 5,
+    "a environment" => 34,
+    "a error" => 21,
+    "a etc" => 6,
+    "a event" => 58,
+    "a executable" => 5,
+    "a expression" => 64,
+    "a extension" => 21,
+    "a fail" => 4,
+    "a failure" => 50,
+    "a fast" => 98,
+    "a faster" => 22,
+    "a feature" => 209,
+    "a few" => 1133,
+    "a field" => 174,
+    "a file" => 1608,
+    "a filename" => 24,
+    "a first" => 43,
+    "a fix" => 14,
+    "a fixed" => 146,
+    "a flag" => 92,
+    "a flags" => 6,
+    "a float" => 43,
+    "a floating" => 43,
+    "a fold" => 80,
+    "a followed" => 5,
+    "a following" => 35,
+    "a for" => 106,
+    "a form" => 50,
+    "a format" => 50,
+    "a formatting" => 7,
+    "a four" => 6,
+    "a free" => 53,
+    "a from" => 72,
+    "a full" => 217,
+    "a fully" => 51,
+    "a function" => 1368,
+    "a further" => 8,
+    "a future" => 191,
+    "a general" => 168,
+    "a generated" => 7,
+    "a generic" => 220,
+    "a get" => 7,
+    "a git" => 100,
+    "a given" => 360,
+    "a global" => 181,
+    "a go" => 3,
+    "a going" => 5,
+    "a good" => 343,
+    "a got" => 4,
+    "a great" => 182,
+    "a greater" => 19,
+    "a group" => 64,
+    "a guide" => 18,
+    "a half" => 66,
+    "a hand" => 17,
+    "a handle" => 55,
+    "a has" => 22,
+    "a hash" => 68,
+    "a have" => 6,
+    "a header" => 52,
+    "a heap" => 36,
+    "a help" => 61,
+    "a here" => 5,
+    "a high" => 164,
+    "a higher" => 116,
+    "a highlight" => 21,
+    "a history" => 16,
+    "a home" => 5,
+    "a host" => 51,
+    "a house" => 16,
+    "a however" => 3,
+    "a i" => 12,
+    "a if" => 31,
+    "a implementation" => 62,
+    "a implements" => 5,
+    "a import" => 4,
+    "a in" => 111,
+    "a input" => 3,
+    "a insert" => 7,
+    "a inside" => 6,
+    "a instance" => 92,
+    "a instead" => 30,
+    "a instruction" => 21,
+    "a integer" => 5,
+    "a interface" => 3,
+    "a into" => 192,
+    "a invalid" => 3,
+    "a is" => 268,
+    "a issue" => 3,
+    "a it" => 19,
+    "a item" => 20,
+    "a iterator" => 5,
+    "a job" => 94,
+    "a just" => 10,
+    "a keep" => 3,
+    "a key" => 212,
+    "a keyword" => 45,
+    "a kind" => 40,
+    "a known" => 61,
+    "a language" => 59,
+    "a large" => 269,
+    "a last" => 13,
+    "a later" => 32,
+    "a latin" => 28,
+    "a layout" => 18,
+    "a least" => 5,
+    "a left" => 63,
+    "a length" => 55,
+    "a less" => 20,
+    "a let" => 7,
+    "a letter" => 32,
+    "a level" => 15,
+    "a library" => 413,
+    "a license" => 12,
+    "a lifetime" => 75,
+    "a like" => 23,
+    "a likely" => 3,
+    "a line" => 571,
+    "a link" => 83,
+    "a lint" => 46,
+    "a list" => 728,
+    "a listing" => 7,
+    "a literal" => 65,
+    "a little" => 460,
+    "a load" => 11,
+    "a local" => 182,
+    "a location" => 46,
+    "a lock" => 54,
+    "a log" => 20,
+    "a long" => 270,
+    "a longer" => 25,
+    "a look" => 231,
+    "a loop" => 125,
+    "a lot" => 450,
+    "a low" => 146,
+    "a lower" => 69,
+    "a mac" => 24,
+    "a machine" => 26,
+    "a macro" => 187,
+    "a main" => 14,
+    "a man" => 175,
+    "a manually" => 6,
+    "a map" => 73,
+    "a mapping" => 200,
+    "a mark" => 39,
+    "a mask" => 35,
+    "a match" => 173,
+    "a matches" => 8,
+    "a matching" => 56,
+    "a maximum" => 48,
+    "a may" => 12,
+    "a means" => 16,
+    "a memory" => 108,
+    "a menu" => 116,
+    "a message" => 263,
+    "a metadata" => 7,
+    "a method" => 331,
+    "a minimum" => 74,
+    "a minor" => 136,
+    "a missing" => 36,
+    "a mit" => 3,
+    "a mode" => 10,
+    "a model" => 29,
+    "a module" => 225,
+    "a more" => 337,
+    "a most" => 29,
+    "a mouse" => 48,
+    "a move" => 9,
+    "a much" => 65,
+    "a multiple" => 58,
+    "a must" => 13,
+    "a mutable" => 373,
+    "a name" => 205,
+    "a named" => 54,
+    "a native" => 122,
+    "a necessary" => 4,
+    "a need" => 8,
+    "a needs" => 3,
+    "a network" => 66,
+    "a new" => 2158,
+    "a next" => 20,
+    "a nightly" => 47,
+    "a no" => 126,
+    "a node" => 101,
+    "a non" => 422,
+    "a none" => 8,
+    "a normal" => 159,
+    "a not" => 28,
+    "a note" => 47,
+    "a null" => 110,
+    "a number" => 685,
+    "a object" => 80,
+    "a of" => 64,
+    "a on" => 24,
+    "a one" => 68,
+    "a only" => 3,
+    "a operation" => 6,
+    "a option" => 12,
+    "a or" => 269,
+    "a otherwise" => 5,
+    "a out" => 5,
+    "a package" => 232,
+    "a packed" => 10,
+    "a page" => 38,
+    "a panic" => 146,
+    "a parameter" => 103,
+    "a parent" => 34,
+    "a parse" => 3,
+    "a parser" => 60,
+    "a parsing" => 6,
+    "a part" => 102,
+    "a particular" => 211,
+    "a pass" => 4,
+    "a passed" => 6,
+    "a passing" => 5,
+    "a patch" => 58,
+    "a path" => 326,
+    "a pattern" => 271,
+    "a per" => 25,
+    "a performance" => 26,
+    "a place" => 87,
+    "a platform" => 67,
+    "a plugin" => 85,
+    "a point" => 45,
+    "a pointer" => 392,
+    "a popup" => 130,
+    "a port" => 76,
+    "a position" => 34,
+    "a possible" => 58,
+    "a precision" => 3,
+    "a prefix" => 68,
+    "a present" => 4,
+    "a previous" => 106,
+    "a print" => 11,
+    "a problem" => 134,
+    "a process" => 91,
+    "a program" => 170,
+    "a project" => 144,
+    "a projects" => 3,
+    "a properly" => 10,
+    "a property" => 86,
+    "a protocol" => 14,
+    "a provided" => 17,
+    "a public" => 81,
+    "a pull" => 190,
+    "a put" => 9,
+    "a python" => 43,
+    "a quite" => 6,
+    "a range" => 207,
+    "a rather" => 26,
+    "a raw" => 245,
+    "a re" => 16,
+    "a read" => 77,
+    "a really" => 8,
+    "a reason" => 16,
+    "a recommended" => 3,
+    "a reference" => 685,
+    "a register" => 113,
+    "a registry" => 69,
+    "a related" => 11,
+    "a relative" => 49,
+    "a release" => 56,
+    "a remote" => 96,
+    "a report" => 23,
+    "a repository" => 33,
+    "a representation" => 17,
+    "a request" => 103,
+    "a required" => 21,
+    "a resolution" => 9,
+    "a response" => 57,
+    "a result" => 159,
+    "a return" => 61,
+    "a returned" => 3,
+    "a returning" => 4,
+    "a returns" => 9,
+    "a right" => 86,
+    "a root" => 19,
+    "a round" => 7,
+    "a run" => 8,
+    "a running" => 44,
+    "a runtime" => 110,
+    "a rust" => 556,
+    "a safe" => 159,
+    "a safety" => 9,
+    "a same" => 18,
+    "a scope" => 38,
+    "a screen" => 35,
+    "a script" => 221,
+    "a search" => 93,
+    "a second" => 142,
+    "a section" => 78,
+    "a security" => 31,
+    "a see" => 5,
+    "a select" => 11,
+    "a selected" => 35,
+    "a selection" => 27,
+    "a self" => 32,
+    "a send" => 4,
+    "a separate" => 326,
+    "a sequence" => 196,
+    "a serialization" => 9,
+    "a server" => 98,
+    "a session" => 72,
+    "a set" => 302,
+    "a sets" => 3,
+    "a setting" => 6,
+    "a settings" => 3,
+    "a shared" => 170,
+    "a shell" => 141,
+    "a short" => 146,
+    "a should" => 3,
+    "a show" => 5,
+    "a side" => 22,
+    "a sign" => 75,
+    "a signature" => 25,
+    "a signed" => 72,
+    "a similar" => 125,
+    "a simple" => 521,
+    "a since" => 5,
+    "a single" => 1355,
+    "a size" => 35,
+    "a skip" => 6,
+    "a slice" => 313,
+    "a small" => 330,
+    "a so" => 24,
+    "a socket" => 94,
+    "a software" => 13,
+    "a solution" => 33,
+    "a source" => 69,
+    "a space" => 178,
+    "a special" => 242,
+    "a specific" => 445,
+    "a specification" => 15,
+    "a specified" => 54,
+    "a split" => 8,
+    "a stable" => 89,
+    "a stack" => 55,
+    "a standard" => 96,
+    "a start" => 22,
+    "a starting" => 22,
+    "a state" => 56,
+    "a statement" => 74,
+    "a static" => 131,
+    "a status" => 42,
+    "a still" => 5,
+    "a stop" => 10,
+    "a store" => 28,
+    "a stored" => 5,
+    "a stream" => 175,
+    "a string" => 1080,
+    "a structure" => 51,
+    "a style" => 11,
+    "a submitted" => 3,
+    "a such" => 5,
+    "a support" => 3,
+    "a supported" => 20,
+    "a sure" => 4,
+    "a syntax" => 82,
+    "a system" => 108,
+    "a systems" => 6,
+    "a tab" => 145,
+    "a table" => 99,
+    "a tag" => 113,
+    "a tags" => 40,
+    "a target" => 161,
+    "a term" => 22,
+    "a terminal" => 330,
+    "a test" => 528,
+    "a testing" => 4,
+    "a tests" => 5,
+    "a text" => 118,
+    "a that" => 143,
+    "a the" => 64,
+    "a then" => 29,
+    "a thing" => 47,
+    "a this" => 12,
+    "a those" => 30,
+    "a thought" => 8,
+    "a thread" => 168,
+    "a three" => 25,
+    "a through" => 4,
+    "a time" => 205,
+    "a to" => 1323,
+    "a token" => 68,
+    "a too" => 8,
+    "a tool" => 65,
+    "a top" => 38,
+    "a trait" => 415,
+    "a tree" => 79,
+    "a true" => 24,
+    "a try" => 42,
+    "a tuple" => 212,
+    "a two" => 27,
+    "a type" => 827,
+    "a unit" => 36,
+    "a unix" => 62,
+    "a up" => 4,
+    "a usage" => 8,
+    "a use" => 29,
+    "a used" => 13,
+    "a useful" => 46,
+    "a user" => 263,
+    "a uses" => 5,
+    "a using" => 82,
+    "a valid" => 278,
+    "a value" => 993,
+    "a variable" => 355,
+    "a variant" => 82,
+    "a vector" => 379,
+    "a version" => 155,
+    "a very" => 389,
+    "a via" => 5,
+    "a vim" => 194,
+    "a visual" => 55,
+    "a warning" => 154,
+    "a was" => 9,
+    "a way" => 382,
+    "a we" => 5,
+    "a web" => 69,
+    "a well" => 60,
+    "a when" => 106,
+    "a where" => 17,
+    "a which" => 80,
+    "a while" => 113,
+    "a white" => 30,
+    "a whole" => 122,
+    "a whose" => 8,
+    "a width" => 11,
+    "a will" => 61,
+    "a win" => 4,
+    "a window" => 337,
+    "a windows" => 44,
+    "a with" => 215,
+    "a within" => 24,
+    "a without" => 26,
+    "a word" => 237,
+    "a work" => 65,
+    "a working" => 44,
+    "a world" => 21,
+    "a would" => 9,
+    "a wrapper" => 138,
+    "a write" => 35,
+    "a writing" => 3,
+    "a wrong" => 59,
+    "a yes" => 3,
+    "a you" => 21,
+    "a zero" => 127,
+    "able to" => 839,
+    "about a" => 121,
+    "about an" => 13,
+    "about and" => 23,
+    "about as" => 11,
+    "about for" => 11,
+    "about in" => 34,
+    "about is" => 6,
+    "about it" => 133,
+    "about not" => 12,
+    "about on" => 4,
+    "about or" => 4,
+    "about that" => 60,
+    "about the" => 706,
+    "about this" => 118,
+    "about to" => 93,
+    "about using" => 49,
+    "about vim" => 8,
+    "about when" => 12,
+    "about with" => 5,
+    "about you" => 7,
+    "above a" => 20,
+    "above and" => 39,
+    "above are" => 19,
+    "above as" => 9,
+    "above by" => 3,
+    "above can" => 24,
+    "above for" => 20,
+    "above in" => 10,
+    "above is" => 33,
+    "above it" => 24,
+    "above or" => 36,
+    "above that" => 9,
+    "above the" => 134,
+    "above this" => 8,
+    "above to" => 15,
+    "above when" => 4,
+    "above with" => 9,
+    "above you" => 9,
+    "absolute the" => 3,
+    "accept the" => 29,
+    "accepted the" => 4,
+    "accepts the" => 19,
+    "access a" => 31,
+    "access and" => 46,
+    "access as" => 14,
+    "access for" => 7,
+    "access in" => 29,
+    "access is" => 25,
+    "access it" => 27,
+    "access of" => 13,
+    "access that" => 4,
+    "access the" => 194,
+    "access this" => 15,
+    "access to" => 608,
+    "access with" => 22,
+    "accessed the" => 3,
+    "accessing the" => 91,
+    "according to" => 796,
+    "account the" => 20,
+    "across a" => 30,
+    "across and" => 6,
+    "across the" => 137,
+    "across to" => 6,
+    "active the" => 7,
+    "actually a" => 30,
+    "actually is" => 7,
+    "actually the" => 11,
+    "add a" => 1248,
+    "add add" => 3,
+    "add all" => 14,
+    "add an" => 193,
+    "add and" => 92,
+    "add any" => 18,
+    "add as" => 63,
+    "add bit" => 7,
+    "add code" => 17,
+    "add command" => 23,
+    "add crate" => 5,
+    "add documentation" => 3,
+    "add error" => 14,
+    "add example" => 3,
+    "add file" => 11,
+    "add files" => 4,
+    "add for" => 13,
+    "add from" => 5,
+    "add function" => 14,
+    "add has" => 8,
+    "add i" => 4,
+    "add if" => 12,
+    "add in" => 19,
+    "add is" => 5,
+    "add it" => 133,
+    "add license" => 4,
+    "add line" => 6,
+    "add more" => 237,
+    "add no" => 5,
+    "add not" => 5,
+    "add on" => 26,
+    "add one" => 45,
+    "add or" => 36,
+    "add other" => 5,
+    "add rust" => 5,
+    "add test" => 71,
+    "add that" => 17,
+    "add the" => 1310,
+    "add this" => 383,
+    "add to" => 297,
+    "add type" => 140,
+    "add value" => 3,
+    "add vim" => 19,
+    "add with" => 6,
+    "add your" => 44,
+    "added a" => 83,
+    "added and" => 39,
+    "added as" => 59,
+    "added for" => 37,
+    "added in" => 105,
+    "added is" => 4,
+    "added it" => 6,
+    "added on" => 6,
+    "added or" => 20,
+    "added that" => 7,
+    "added the" => 177,
+    "added to" => 355,
+    "added with" => 14,
+    "adding a" => 183,
+    "adding and" => 21,
+    "adding in" => 4,
+    "adding of" => 4,
+    "adding the" => 182,
+    "adding to" => 68,
+    "addition a" => 6,
+    "addition the" => 33,
+    "addition to" => 201,
+    "additionally the" => 46,
+    "address a" => 12,
+    "address and" => 70,
+    "address for" => 16,
+    "address in" => 28,
+    "address is" => 49,
+    "address of" => 58,
+    "address the" => 17,
+    "address to" => 30,
+    "addresses the" => 5,
+    "adds a" => 129,
+    "adds and" => 43,
+    "adds the" => 82,
+    "adds to" => 21,
+    "adjust the" => 219,
+    "adjust to" => 6,
+    "affect the" => 68,
+    "after a" => 373,
+    "after all" => 98,
+    "after an" => 91,
+    "after and" => 17,
+    "after any" => 17,
+    "after as" => 6,
+    "after but" => 5,
+    "after file" => 4,
+    "after function" => 8,
+    "after i" => 16,
+    "after if" => 9,
+    "after in" => 10,
+    "after is" => 18,
+    "after it" => 109,
+    "after one" => 6,
+    "after or" => 11,
+    "after patch" => 33,
+    "after rust" => 4,
+    "after that" => 75,
+    "after the" => 991,
+    "after this" => 51,
+    "after to" => 4,
+    "after type" => 3,
+    "after using" => 92,
+    "after vim" => 19,
+    "after which" => 25,
+    "after will" => 5,
+    "after you" => 42,
+    "after your" => 7,
+    "again a" => 9,
+    "again and" => 84,
+    "again for" => 17,
+    "again in" => 30,
+    "again is" => 5,
+    "again the" => 28,
+    "again this" => 17,
+    "again to" => 34,
+    "against a" => 85,
+    "against and" => 15,
+    "against in" => 3,
+    "against is" => 5,
+    "against the" => 268,
+    "algorithm and" => 20,
+    "algorithm the" => 3,
+    "algorithm to" => 24,
+    "alice the" => 3,
+    "alignment to" => 4,
+    "all a" => 13,
+    "all and" => 42,
+    "all are" => 9,
+    "all as" => 11,
+    "all at" => 27,
+    "all be" => 25,
+    "all bit" => 10,
+    "all build" => 3,
+    "all but" => 34,
+    "all by" => 8,
+    "all code" => 38,
+    "all command" => 8,
+    "all crates" => 37,
+    "all data" => 45,
+    "all default" => 9,
+    "all do" => 7,
+    "all does" => 4,
+    "all elements" => 247,
+    "all error" => 12,
+    "all example" => 11,
+    "all examples" => 18,
+    "all feature" => 6,
+    "all file" => 17,
+    "all files" => 65,
+    "all first" => 3,
+    "all for" => 16,
+    "all from" => 4,
+    "all function" => 13,
+    "all have" => 23,
+    "all he" => 9,
+    "all i" => 33,
+    "all if" => 5,
+    "all in" => 58,
+    "all into" => 3,
+    "all is" => 15,
+    "all it" => 19,
+    "all library" => 3,
+    "all must" => 3,
+    "all name" => 5,
+    "all new" => 14,
+    "all no" => 3,
+    "all not" => 3,
+    "all number" => 3,
+    "all of" => 646,
+    "all on" => 47,
+    "all one" => 6,
+    "all option" => 5,
+    "all or" => 24,
+    "all other" => 212,
+    "all out" => 4,
+    "all rust" => 28,
+    "all set" => 12,
+    "all so" => 4,
+    "all string" => 4,
+    "all support" => 3,
+    "all test" => 9,
+    "all that" => 111,
+    "all the" => 1363,
+    "all then" => 10,
+    "all there" => 7,
+    "all these" => 73,
+    "all they" => 11,
+    "all this" => 83,
+    "all time" => 3,
+    "all to" => 24,
+    "all type" => 3,
+    "all types" => 86,
+    "all up" => 5,
+    "all use" => 18,
+    "all used" => 3,
+    "all value" => 3,
+    "all version" => 10,
+    "all vim" => 13,
+    "all was" => 23,
+    "all we" => 11,
+    "all when" => 4,
+    "all will" => 6,
+    "all with" => 9,
+    "all without" => 3,
+    "all work" => 12,
+    "all you" => 54,
+    "all your" => 51,
+    "allocate the" => 23,
+    "allocated to" => 13,
+    "allocation and" => 48,
+    "allocation of" => 14,
+    "allocation the" => 6,
+    "allocation to" => 18,
+    "allocator to" => 13,
+    "allow a" => 48,
+    "allow for" => 128,
+    "allow in" => 5,
+    "allow that" => 7,
+    "allow the" => 105,
+    "allow this" => 13,
+    "allow to" => 27,
+    "allowed and" => 9,
+    "allowed for" => 24,
+    "allowed in" => 62,
+    "allowed to" => 235,
+    "allowing the" => 54,
+    "allows a" => 60,
+    "allows and" => 5,
+    "allows for" => 187,
+    "allows it" => 24,
+    "allows the" => 144,
+    "allows this" => 6,
+    "allows to" => 94,
+    "allows you" => 318,
+    "almost the" => 28,
+    "along a" => 4,
+    "along the" => 89,
+    "along to" => 12,
+    "already a" => 9,
+    "already and" => 3,
+    "already in" => 41,
+    "already is" => 11,
+    "already the" => 6,
+    "also a" => 152,
+    "also add" => 45,
+    "also all" => 5,
+    "also an" => 27,
+    "also and" => 28,
+    "also are" => 7,
+    "also as" => 7,
+    "also at" => 5,
+    "also be" => 843,
+    "also but" => 4,
+    "also by" => 11,
+    "also can" => 12,
+    "also for" => 106,
+    "also from" => 13,
+    "also has" => 99,
+    "also have" => 95,
+    "also i" => 4,
+    "also if" => 12,
+    "also in" => 49,
+    "also is" => 16,
+    "also it" => 11,
+    "also license" => 3,
+    "also like" => 4,
+    "also may" => 8,
+    "also more" => 6,
+    "also no" => 14,
+    "also not" => 46,
+    "also of" => 11,
+    "also on" => 11,
+    "also one" => 3,
+    "also only" => 7,
+    "also rust" => 4,
+    "also see" => 113,
+    "also set" => 59,
+    "also test" => 8,
+    "also that" => 30,
+    "also the" => 182,
+    "also there" => 7,
+    "also this" => 8,
+    "also to" => 27,
+    "also type" => 4,
+    "also use" => 262,
+    "also used" => 100,
+    "also using" => 7,
+    "also was" => 7,
+    "also we" => 4,
+    "also when" => 86,
+    "also which" => 15,
+    "also will" => 6,
+    "also with" => 19,
+    "also you" => 9,
+    "alternatively the" => 18,
+    "although a" => 6,
+    "although the" => 74,
+    "always a" => 38,
+    "always an" => 7,
+    "always as" => 4,
+    "always be" => 251,
+    "always for" => 4,
+    "always in" => 18,
+    "always is" => 6,
+    "always on" => 4,
+    "always the" => 52,
+    "always to" => 13,
+    "always when" => 5,
+    "always with" => 3,
+    "am a" => 21,
+    "am and" => 7,
+    "am the" => 9,
+    "am to" => 15,
+    "among the" => 139,
+    "amount to" => 10,
+    "an a" => 10,
+    "an add" => 6,
+    "an additional" => 109,
+    "an after" => 7,
+    "an all" => 10,
+    "an an" => 4,
+    "an and" => 60,
+    "an any" => 5,
+    "an argument" => 292,
+    "an as" => 26,
+    "an available" => 3,
+    "an because" => 10,
+    "an before" => 3,
+    "an bit" => 21,
+    "an but" => 10,
+    "an by" => 10,
+    "an call" => 9,
+    "an can" => 13,
+    "an directory" => 3,
+    "an element" => 136,
+    "an end" => 68,
+    "an error" => 1764,
+    "an ex" => 59,
+    "an example" => 730,
+    "an expression" => 360,
+    "an feature" => 6,
+    "an file" => 11,
+    "an for" => 18,
+    "an from" => 29,
+    "an function" => 31,
+    "an i" => 27,
+    "an if" => 49,
+    "an implementation" => 289,
+    "an in" => 84,
+    "an instead" => 8,
+    "an integer" => 231,
+    "an into" => 12,
+    "an is" => 91,
+    "an it" => 8,
+    "an macro" => 7,
+    "an may" => 8,
+    "an message" => 4,
+    "an method" => 23,
+    "an no" => 4,
+    "an non" => 4,
+    "an number" => 4,
+    "an object" => 414,
+    "an of" => 15,
+    "an on" => 17,
+    "an only" => 3,
+    "an option" => 177,
+    "an options" => 6,
+    "an or" => 53,
+    "an other" => 3,
+    "an otherwise" => 6,
+    "an out" => 25,
+    "an output" => 40,
+    "an over" => 4,
+    "an pointer" => 5,
+    "an reference" => 4,
+    "an rust" => 3,
+    "an script" => 4,
+    "an so" => 7,
+    "an string" => 3,
+    "an that" => 24,
+    "an the" => 8,
+    "an then" => 20,
+    "an there" => 3,
+    "an time" => 3,
+    "an to" => 45,
+    "an trait" => 12,
+    "an type" => 20,
+    "an up" => 7,
+    "an using" => 13,
+    "an value" => 54,
+    "an we" => 4,
+    "an when" => 4,
+    "an which" => 19,
+    "an while" => 4,
+    "an will" => 11,
+    "an with" => 50,
+    "an without" => 8,
+    "an you" => 5,
+    "and a" => 1406,
+    "and about" => 11,
+    "and above" => 47,
+    "and access" => 33,
+    "and across" => 12,
+    "and actually" => 8,
+    "and add" => 226,
+    "and added" => 22,
+    "and adding" => 17,
+    "and additional" => 13,
+    "and address" => 8,
+    "and adds" => 43,
+    "and after" => 116,
+    "and again" => 23,
+    "and algorithm" => 6,
+    "and all" => 285,
+    "and allocation" => 3,
+    "and allow" => 39,
+    "and allowed" => 5,
+    "and allows" => 58,
+    "and already" => 6,
+    "and also" => 169,
+    "and always" => 42,
+    "and am" => 6,
+    "and an" => 239,
+    "and and" => 198,
+    "and another" => 59,
+    "and any" => 104,
+    "and anything" => 9,
+    "and apache" => 39,
+    "and apis" => 27,
+    "and application" => 7,
+    "and applications" => 17,
+    "and are" => 686,
+    "and argument" => 6,
+    "and arguments" => 37,
+    "and arm" => 19,
+    "and around" => 7,
+    "and array" => 9,
+    "and as" => 289,
+    "and ascii" => 5,
+    "and associated" => 44,
+    "and at" => 110,
+    "and attribute" => 15,
+    "and attributes" => 44,
+    "and auto" => 12,
+    "and automatically" => 36,
+    "and available" => 20,
+    "and avoid" => 33,
+    "and away" => 5,
+    "and back" => 26,
+    "and base" => 13,
+    "and based" => 10,
+    "and basic" => 7,
+    "and be" => 72,
+    "and because" => 35,
+    "and been" => 7,
+    "and before" => 39,
+    "and behavior" => 22,
+    "and being" => 15,
+    "and below" => 23,
+    "and benchmarks" => 33,
+    "and better" => 9,
+    "and between" => 8,
+    "and binary" => 45,
+    "and bindings" => 4,
+    "and bit" => 51,
+    "and bits" => 11,
+    "and block" => 11,
+    "and body" => 14,
+    "and boolean" => 7,
+    "and both" => 55,
+    "and bound" => 7,
+    "and bounds" => 9,
+    "and branch" => 5,
+    "and break" => 6,
+    "and buffer" => 25,
+    "and build" => 114,
+    "and building" => 17,
+    "and built" => 14,
+    "and but" => 32,
+    "and by" => 137,
+    "and byte" => 28,
+    "and bytes" => 17,
+    "and cache" => 7,
+    "and call" => 63,
+    "and called" => 19,
+    "and calling" => 23,
+    "and calls" => 31,
+    "and can" => 597,
+    "and can't" => 19,
+    "and cannot" => 72,
+    "and cargo" => 51,
+    "and case" => 9,
+    "and cause" => 13,
+    "and certain" => 3,
+    "and change" => 48,
+    "and changed" => 13,
+    "and changes" => 19,
+    "and channel" => 12,
+    "and chapter" => 9,
+    "and character" => 13,
+    "and characters" => 26,
+    "and check" => 71,
+    "and checking" => 8,
+    "and checks" => 18,
+    "and class" => 7,
+    "and clear" => 11,
+    "and client" => 31,
+    "and closure" => 8,
+    "and code" => 89,
+    "and color" => 13,
+    "and colors" => 10,
+    "and column" => 32,
+    "and come" => 19,
+    "and command" => 44,
+    "and commands" => 57,
+    "and comment" => 11,
+    "and comments" => 16,
+    "and commit" => 18,
+    "and common" => 16,
+    "and compare" => 19,
+    "and compatibility" => 11,
+    "and compatible" => 9,
+    "and compilation" => 8,
+    "and compile" => 33,
+    "and compiled" => 8,
+    "and compiler" => 18,
+    "and complete" => 13,
+    "and completion" => 4,
+    "and complex" => 12,
+    "and conditions" => 8,
+    "and configuration" => 20,
+    "and configure" => 11,
+    "and consider" => 13,
+    "and const" => 17,
+    "and contain" => 8,
+    "and containing" => 19,
+    "and contains" => 22,
+    "and contents" => 4,
+    "and context" => 9,
+    "and contributing" => 4,
+    "and control" => 24,
+    "and conversion" => 14,
+    "and convert" => 34,
+    "and copied" => 3,
+    "and copy" => 443,
+    "and core" => 8,
+    "and correct" => 23,
+    "and corresponding" => 6,
+    "and could" => 43,
+    "and count" => 5,
+    "and crash" => 10,
+    "and crate" => 29,
+    "and crates" => 63,
+    "and create" => 42,
+    "and created" => 4,
+    "and creates" => 22,
+    "and creating" => 21,
+    "and cross" => 17,
+    "and current" => 8,
+    "and currently" => 6,
+    "and cursor" => 11,
+    "and custom" => 23,
+    "and data" => 63,
+    "and day" => 11,
+    "and default" => 18,
+    "and define" => 24,
+    "and defined" => 7,
+    "and delete" => 35,
+    "and dependencies" => 27,
+    "and dependency" => 6,
+    "and depends" => 13,
+    "and derive" => 17,
+    "and details" => 6,
+    "and development" => 12,
+    "and did" => 23,
+    "and didn't" => 13,
+    "and different" => 16,
+    "and directly" => 13,
+    "and directory" => 15,
+    "and disable" => 14,
+    "and display" => 39,
+    "and do" => 165,
+    "and doc" => 6,
+    "and documentation" => 35,
+    "and does" => 178,
+    "and doesn't" => 46,
+    "and doing" => 30,
+    "and don't" => 95,
+    "and done" => 6,
+    "and double" => 21,
+    "and down" => 72,
+    "and during" => 6,
+    "and each" => 58,
+    "and easy" => 55,
+    "and edit" => 45,
+    "and editing" => 13,
+    "and either" => 31,
+    "and element" => 12,
+    "and elements" => 4,
+    "and empty" => 13,
+    "and enable" => 31,
+    "and enabled" => 12,
+    "and enables" => 27,
+    "and encoding" => 28,
+    "and end" => 100,
+    "and ensure" => 23,
+    "and environment" => 38,
+    "and equal" => 24,
+    "and equivalent" => 9,
+    "and error" => 75,
+    "and errors" => 24,
+    "and etc" => 5,
+    "and even" => 116,
+    "and event" => 30,
+    "and events" => 23,
+    "and ever" => 4,
+    "and every" => 51,
+    "and example" => 13,
+    "and examples" => 99,
+    "and except" => 8,
+    "and exceptions" => 4,
+    "and execute" => 47,
+    "and executed" => 13,
+    "and execution" => 15,
+    "and exists" => 6,
+    "and expected" => 5,
+    "and explicitly" => 21,
+    "and expression" => 6,
+    "and expressions" => 31,
+    "and extension" => 6,
+    "and external" => 10,
+    "and extra" => 4,
+    "and fail" => 22,
+    "and fails" => 7,
+    "and fast" => 39,
+    "and feature" => 42,
+    "and features" => 84,
+    "and field" => 14,
+    "and fields" => 39,
+    "and file" => 42,
+    "and filename" => 9,
+    "and files" => 59,
+    "and find" => 38,
+    "and first" => 12,
+    "and fix" => 58,
+    "and fixed" => 17,
+    "and flag" => 5,
+    "and flags" => 58,
+    "and floating" => 14,
+    "and follow" => 21,
+    "and followed" => 14,
+    "and following" => 7,
+    "and for" => 462,
+    "and form" => 9,
+    "and format" => 17,
+    "and found" => 41,
+    "and free" => 15,
+    "and from" => 154,
+    "and full" => 16,
+    "and function" => 60,
+    "and functionality" => 9,
+    "and functions" => 227,
+    "and future" => 19,
+    "and general" => 17,
+    "and generate" => 29,
+    "and generated" => 6,
+    "and generic" => 18,
+    "and get" => 74,
+    "and getting" => 14,
+    "and git" => 29,
+    "and give" => 47,
+    "and given" => 7,
+    "and global" => 17,
+    "and go" => 60,
+    "and going" => 8,
+    "and good" => 17,
+    "and group" => 14,
+    "and had" => 95,
+    "and half" => 21,
+    "and handle" => 36,
+    "and handling" => 9,
+    "and has" => 201,
+    "and hash" => 13,
+    "and have" => 210,
+    "and having" => 31,
+    "and he" => 255,
+    "and header" => 9,
+    "and help" => 26,
+    "and her" => 54,
+    "and here" => 35,
+    "and high" => 18,
+    "and highlighting" => 9,
+    "and his" => 193,
+    "and host" => 10,
+    "and how" => 217,
+    "and however" => 3,
+    "and i" => 679,
+    "and if" => 281,
+    "and image" => 28,
+    "and implement" => 45,
+    "and implementation" => 26,
+    "and implementations" => 51,
+    "and implemented" => 12,
+    "and implements" => 23,
+    "and in" => 464,
+    "and include" => 24,
+    "and included" => 12,
+    "and includes" => 22,
+    "and including" => 26,
+    "and index" => 41,
+    "and information" => 31,
+    "and input" => 19,
+    "and insert" => 32,
+    "and inside" => 7,
+    "and install" => 50,
+    "and instance" => 10,
+    "and instead" => 63,
+    "and instruction" => 6,
+    "and instructions" => 14,
+    "and integer" => 6,
+    "and integers" => 5,
+    "and integration" => 40,
+    "and intended" => 4,
+    "and interface" => 5,
+    "and internal" => 8,
+    "and into" => 33,
+    "and invalid" => 7,
+    "and is" => 905,
+    "and isn't" => 11,
+    "and issue" => 10,
+    "and issues" => 6,
+    "and it" => 583,
+    "and it's" => 71,
+    "and item" => 6,
+    "and items" => 17,
+    "and iterator" => 3,
+    "and its" => 309,
+    "and just" => 44,
+    "and keep" => 32,
+    "and key" => 30,
+    "and keys" => 13,
+    "and keyword" => 9,
+    "and kind" => 3,
+    "and know" => 6,
+    "and known" => 9,
+    "and language" => 18,
+    "and large" => 7,
+    "and last" => 40,
+    "and later" => 92,
+    "and layout" => 16,
+    "and left" => 42,
+    "and length" => 7,
+    "and less" => 23,
+    "and let" => 64,
+    "and libraries" => 32,
+    "and library" => 19,
+    "and license" => 115,
+    "and licensed" => 8,
+    "and lifetime" => 17,
+    "and like" => 17,
+    "and line" => 40,
+    "and lines" => 21,
+    "and link" => 36,
+    "and list" => 23,
+    "and listing" => 12,
+    "and little" => 7,
+    "and load" => 25,
+    "and local" => 27,
+    "and location" => 15,
+    "and lock" => 6,
+    "and long" => 16,
+    "and look" => 29,
+    "and loop" => 13,
+    "and low" => 24,
+    "and lower" => 12,
+    "and macro" => 21,
+    "and macros" => 60,
+    "and made" => 64,
+    "and main" => 8,
+    "and make" => 148,
+    "and makes" => 51,
+    "and making" => 23,
+    "and man" => 4,
+    "and many" => 87,
+    "and map" => 13,
+    "and mapping" => 5,
+    "and mark" => 11,
+    "and mask" => 4,
+    "and match" => 22,
+    "and matches" => 8,
+    "and matching" => 16,
+    "and maximum" => 14,
+    "and may" => 306,
+    "and me" => 20,
+    "and means" => 6,
+    "and memory" => 53,
+    "and menu" => 7,
+    "and message" => 11,
+    "and messages" => 8,
+    "and method" => 16,
+    "and methods" => 185,
+    "and might" => 34,
+    "and missing" => 10,
+    "and mit" => 25,
+    "and mode" => 6,
+    "and module" => 12,
+    "and modules" => 38,
+    "and more" => 239,
+    "and most" => 63,
+    "and mouse" => 11,
+    "and move" => 21,
+    "and much" => 18,
+    "and multiple" => 22,
+    "and must" => 140,
+    "and mutable" => 12,
+    "and my" => 125,
+    "and name" => 18,
+    "and named" => 11,
+    "and names" => 8,
+    "and native" => 15,
+    "and need" => 37,
+    "and needed" => 5,
+    "and needs" => 17,
+    "and network" => 14,
+    "and never" => 44,
+    "and new" => 37,
+    "and next" => 13,
+    "and nightly" => 26,
+    "and no" => 204,
+    "and node" => 17,
+    "and non" => 89,
+    "and none" => 15,
+    "and normal" => 7,
+    "and not" => 335,
+    "and note" => 5,
+    "and nothing" => 26,
+    "and now" => 116,
+    "and null" => 10,
+    "and number" => 22,
+    "and numbers" => 7,
+    "and object" => 12,
+    "and objects" => 29,
+    "and of" => 177,
+    "and off" => 11,
+    "and often" => 26,
+    "and old" => 4,
+    "and on" => 170,
+    "and once" => 49,
+    "and one" => 156,
+    "and only" => 230,
+    "and open" => 79,
+    "and operation" => 5,
+    "and operations" => 37,
+    "and operator" => 16,
+    "and option" => 13,
+    "and optional" => 26,
+    "and options" => 52,
+    "and or" => 324,
+    "and other" => 407,
+    "and otherwise" => 58,
+    "and our" => 21,
+    "and out" => 29,
+    "and output" => 76,
+    "and over" => 28,
+    "and package" => 7,
+    "and packed" => 3,
+    "and page" => 5,
+    "and panic" => 11,
+    "and panics" => 6,
+    "and parameters" => 29,
+    "and parse" => 16,
+    "and parser" => 12,
+    "and parsing" => 30,
+    "and part" => 6,
+    "and pass" => 68,
+    "and passed" => 29,
+    "and passing" => 23,
+    "and patch" => 12,
+    "and path" => 19,
+    "and paths" => 8,
+    "and pattern" => 11,
+    "and patterns" => 12,
+    "and people" => 6,
+    "and per" => 7,
+    "and performance" => 48,
+    "and place" => 20,
+    "and platform" => 16,
+    "and platforms" => 6,
+    "and plugin" => 3,
+    "and point" => 10,
+    "and pointer" => 7,
+    "and pointers" => 14,
+    "and popup" => 8,
+    "and position" => 18,
+    "and possible" => 4,
+    "and precision" => 6,
+    "and present" => 7,
+    "and previous" => 10,
+    "and print" => 40,
+    "and problems" => 3,
+    "and process" => 4,
+    "and produce" => 31,
+    "and program" => 8,
+    "and programs" => 6,
+    "and project" => 8,
+    "and properly" => 15,
+    "and properties" => 35,
+    "and property" => 8,
+    "and protocol" => 7,
+    "and provide" => 59,
+    "and provided" => 14,
+    "and provides" => 95,
+    "and public" => 27,
+    "and put" => 71,
+    "and python" => 41,
+    "and range" => 10,
+    "and rather" => 3,
+    "and raw" => 22,
+    "and re" => 40,
+    "and read" => 68,
+    "and reading" => 22,
+    "and reference" => 17,
+    "and references" => 25,
+    "and register" => 7,
+    "and related" => 34,
+    "and release" => 26,
+    "and remove" => 53,
+    "and removed" => 33,
+    "and request" => 9,
+    "and require" => 27,
+    "and required" => 8,
+    "and requirements" => 5,
+    "and requires" => 111,
+    "and result" => 7,
+    "and results" => 12,
+    "and return" => 236,
+    "and returned" => 31,
+    "and returning" => 31,
+    "and returns" => 389,
+    "and rfc" => 14,
+    "and right" => 48,
+    "and round" => 11,
+    "and rounding" => 7,
+    "and rules" => 3,
+    "and run" => 184,
+    "and running" => 63,
+    "and runtime" => 25,
+    "and rust" => 122,
+    "and safe" => 43,
+    "and safety" => 27,
+    "and said" => 90,
+    "and say" => 21,
+    "and scope" => 8,
+    "and screen" => 4,
+    "and script" => 9,
+    "and search" => 22,
+    "and second" => 12,
+    "and section" => 7,
+    "and see" => 74,
+    "and select" => 32,
+    "and self" => 10,
+    "and send" => 43,
+    "and sequence" => 5,
+    "and server" => 48,
+    "and set" => 181,
+    "and sets" => 71,
+    "and setting" => 29,
+    "and several" => 26,
+    "and shall" => 9,
+    "and shared" => 11,
+    "and she" => 146,
+    "and shell" => 4,
+    "and should" => 185,
+    "and show" => 20,
+    "and shows" => 13,
+    "and side" => 4,
+    "and sign" => 12,
+    "and signed" => 16,
+    "and similar" => 50,
+    "and simple" => 25,
+    "and since" => 16,
+    "and single" => 12,
+    "and size" => 28,
+    "and slice" => 18,
+    "and small" => 29,
+    "and so" => 360,
+    "and socket" => 4,
+    "and software" => 22,
+    "and some" => 165,
+    "and something" => 8,
+    "and sometimes" => 39,
+    "and source" => 24,
+    "and space" => 15,
+    "and special" => 25,
+    "and specific" => 6,
+    "and specified" => 8,
+    "and specify" => 14,
+    "and stable" => 11,
+    "and stack" => 9,
+    "and standard" => 19,
+    "and start" => 65,
+    "and started" => 20,
+    "and starting" => 3,
+    "and state" => 7,
+    "and static" => 10,
+    "and status" => 11,
+    "and std" => 14,
+    "and still" => 44,
+    "and store" => 1880,
+    "and stored" => 16,
+    "and stream" => 11,
+    "and string" => 20,
+    "and strings" => 31,
+    "and structure" => 6,
+    "and style" => 21,
+    "and submitted" => 3,
+    "and such" => 41,
+    "and support" => 56,
+    "and supported" => 3,
+    "and supports" => 81,
+    "and suppress" => 1530,
+    "and syntax" => 38,
+    "and system" => 26,
+    "and systems" => 4,
+    "and tab" => 13,
+    "and table" => 4,
+    "and tag" => 5,
+    "and tags" => 13,
+    "and take" => 59,
+    "and takes" => 21,
+    "and target" => 31,
+    "and targets" => 17,
+    "and tell" => 32,
+    "and term" => 8,
+    "and terminal" => 16,
+    "and test" => 74,
+    "and tested" => 20,
+    "and testing" => 43,
+    "and tests" => 47,
+    "and text" => 40,
+    "and that" => 458,
+    "and that's" => 43,
+    "and the" => 4794,
+    "and their" => 180,
+    "and them" => 6,
+    "and then" => 1401,
+    "and there" => 276,
+    "and these" => 46,
+    "and they" => 228,
+    "and things" => 21,
+    "and think" => 4,
+    "and this" => 267,
+    "and those" => 46,
+    "and though" => 9,
+    "and thread" => 11,
+    "and threads" => 8,
+    "and three" => 17,
+    "and through" => 13,
+    "and thus" => 171,
+    "and time" => 58,
+    "and times" => 8,
+    "and to" => 546,
+    "and together" => 9,
+    "and token" => 4,
+    "and tom" => 51,
+    "and too" => 8,
+    "and tools" => 17,
+    "and top" => 3,
+    "and trait" => 57,
+    "and traits" => 203,
+    "and tree" => 8,
+    "and true" => 9,
+    "and try" => 40,
+    "and tuple" => 22,
+    "and two" => 47,
+    "and type" => 113,
+    "and types" => 164,
+    "and undefined" => 3,
+    "and under" => 17,
+    "and unix" => 33,
+    "and unsafe" => 21,
+    "and unsigned" => 19,
+    "and until" => 3,
+    "and up" => 72,
+    "and update" => 32,
+    "and upon" => 10,
+    "and upper" => 12,
+    "and usage" => 26,
+    "and use" => 388,
+    "and used" => 71,
+    "and useful" => 15,
+    "and user" => 33,
+    "and users" => 13,
+    "and uses" => 72,
+    "and using" => 236,
+    "and valid" => 10,
+    "and value" => 47,
+    "and values" => 93,
+    "and variable" => 11,
+    "and variables" => 30,
+    "and various" => 28,
+    "and vector" => 8,
+    "and version" => 33,
+    "and versions" => 35,
+    "and very" => 41,
+    "and via" => 11,
+    "and vim" => 94,
+    "and visual" => 20,
+    "and want" => 67,
+    "and warning" => 3,
+    "and was" => 187,
+    "and we" => 245,
+    "and we'll" => 34,
+    "and web" => 17,
+    "and well" => 23,
+    "and were" => 54,
+    "and what" => 163,
+    "and when" => 262,
+    "and where" => 69,
+    "and whether" => 24,
+    "and which" => 154,
+    "and while" => 37,
+    "and who" => 31,
+    "and whole" => 3,
+    "and why" => 41,
+    "and will" => 515,
+    "and win" => 42,
+    "and window" => 24,
+    "and windows" => 167,
+    "and with" => 240,
+    "and within" => 9,
+    "and without" => 82,
+    "and won't" => 11,
+    "and word" => 10,
+    "and work" => 34,
+    "and working" => 21,
+    "and works" => 25,
+    "and would" => 86,
+    "and write" => 149,
+    "and writing" => 110,
+    "and written" => 23,
+    "and wrong" => 7,
+    "and yes" => 5,
+    "and yet" => 92,
+    "and you" => 439,
+    "and you'll" => 18,
+    "and you're" => 25,
+    "and your" => 101,
+    "and zero" => 32,
+    "another a" => 3,
+    "another and" => 16,
+    "another as" => 8,
+    "another by" => 3,
+    "another for" => 7,
+    "another in" => 5,
+    "another is" => 9,
+    "another of" => 8,
+    "another that" => 6,
+    "another the" => 3,
+    "another to" => 8,
+    "another with" => 16,
+    "another you" => 5,
+    "any a" => 12,
+    "any an" => 3,
+    "any and" => 32,
+    "any any" => 26,
+    "any are" => 13,
+    "any as" => 3,
+    "any bit" => 8,
+    "any build" => 14,
+    "any but" => 6,
+    "any code" => 58,
+    "any command" => 21,
+    "any crate" => 17,
+    "any data" => 42,
+    "any default" => 3,
+    "any documentation" => 6,
+    "any elements" => 20,
+    "any error" => 35,
+    "any file" => 34,
+    "any files" => 18,
+    "any function" => 19,
+    "any i" => 4,
+    "any if" => 4,
+    "any is" => 9,
+    "any it" => 5,
+    "any library" => 6,
+    "any line" => 6,
+    "any more" => 69,
+    "any name" => 8,
+    "any new" => 56,
+    "any number" => 111,
+    "any of" => 389,
+    "any one" => 55,
+    "any or" => 25,
+    "any other" => 437,
+    "any problem" => 4,
+    "any rust" => 26,
+    "any set" => 8,
+    "any string" => 27,
+    "any test" => 12,
+    "any that" => 17,
+    "any the" => 43,
+    "any then" => 4,
+    "any time" => 111,
+    "any type" => 182,
+    "any types" => 11,
+    "any use" => 40,
+    "any used" => 5,
+    "any value" => 67,
+    "any version" => 22,
+    "any vim" => 7,
+    "any which" => 6,
+    "any will" => 8,
+    "any work" => 12,
+    "anything and" => 7,
+    "anything of" => 13,
+    "anything to" => 22,
+    "apache and" => 87,
+    "apache for" => 8,
+    "apache or" => 735,
+    "apache with" => 6,
+    "apis and" => 38,
+    "apis in" => 24,
+    "apis is" => 8,
+    "apis of" => 3,
+    "apis the" => 7,
+    "apis to" => 34,
+    "appear to" => 46,
+    "append the" => 26,
+    "application and" => 27,
+    "application for" => 11,
+    "application in" => 10,
+    "application is" => 33,
+    "application of" => 17,
+    "application the" => 3,
+    "application to" => 34,
+    "applications a" => 4,
+    "applications and" => 43,
+    "applications of" => 9,
+    "applications the" => 7,
+    "applications to" => 24,
+    "applied the" => 7,
+    "applies the" => 27,
+    "apply a" => 21,
+    "apply the" => 60,
+    "apply to" => 126,
+    "appropriate to" => 18,
+    "architecture the" => 6,
+    "are a" => 344,
+    "are about" => 8,
+    "are additional" => 6,
+    "are all" => 126,
+    "are also" => 355,
+    "are always" => 207,
+    "are an" => 35,
+    "are and" => 100,
+    "are another" => 3,
+    "are any" => 22,
+    "are are" => 5,
+    "are as" => 46,
+    "are at" => 45,
+    "are available" => 453,
+    "are being" => 70,
+    "are bit" => 8,
+    "are both" => 47,
+    "are by" => 15,
+    "are bytes" => 6,
+    "are called" => 77,
+    "are can" => 3,
+    "are case" => 24,
+    "are changes" => 6,
+    "are code" => 4,
+    "are command" => 3,
+    "are commands" => 6,
+    "are compile" => 3,
+    "are compiler" => 3,
+    "are crates" => 4,
+    "are data" => 4,
+    "are defined" => 193,
+    "are different" => 66,
+    "are each" => 7,
+    "are either" => 17,
+    "are even" => 5,
+    "are examples" => 17,
+    "are files" => 4,
+    "are first" => 25,
+    "are following" => 5,
+    "are for" => 44,
+    "are from" => 11,
+    "are functions" => 23,
+    "are given" => 85,
+    "are here" => 5,
+    "are how" => 4,
+    "are however" => 8,
+    "are if" => 3,
+    "are implementation" => 5,
+    "are in" => 300,
+    "are instead" => 6,
+    "are integers" => 5,
+    "are is" => 5,
+    "are its" => 4,
+    "are just" => 40,
+    "are key" => 6,
+    "are library" => 3,
+    "are licensed" => 32,
+    "are like" => 10,
+    "are many" => 89,
+    "are memory" => 3,
+    "are more" => 93,
+    "are most" => 22,
+    "are multiple" => 59,
+    "are my" => 7,
+    "are new" => 13,
+    "are no" => 299,
+    "are non" => 27,
+    "are not" => 1768,
+    "are now" => 150,
+    "are of" => 38,
+    "are on" => 52,
+    "are one" => 26,
+    "are only" => 244,
+    "are or" => 13,
+    "are other" => 36,
+    "are otherwise" => 10,
+    "are out" => 14,
+    "are output" => 7,
+    "are over" => 5,
+    "are possible" => 62,
+    "are read" => 33,
+    "are run" => 56,
+    "are rust" => 8,
+    "are said" => 10,
+    "are script" => 4,
+    "are set" => 124,
+    "are single" => 8,
+    "are so" => 43,
+    "are some" => 187,
+    "are specific" => 18,
+    "are specified" => 55,
+    "are standard" => 6,
+    "are still" => 142,
+    "are supported" => 303,
+    "are tests" => 3,
+    "are that" => 17,
+    "are the" => 454,
+    "are then" => 37,
+    "are there" => 16,
+    "are these" => 8,
+    "are they" => 20,
+    "are to" => 63,
+    "are two" => 228,
+    "are type" => 9,
+    "are types" => 8,
+    "are under" => 6,
+    "are up" => 3,
+    "are used" => 550,
+    "are using" => 218,
+    "are values" => 6,
+    "are very" => 112,
+    "are we" => 24,
+    "are well" => 17,
+    "are what" => 20,
+    "are when" => 6,
+    "are which" => 11,
+    "are windows" => 4,
+    "are with" => 3,
+    "are you" => 81,
+    "are your" => 4,
+    "area the" => 3,
+    "aren't the" => 6,
+    "argument a" => 8,
+    "argument and" => 125,
+    "argument as" => 25,
+    "argument by" => 6,
+    "argument can" => 61,
+    "argument for" => 80,
+    "argument from" => 14,
+    "argument if" => 20,
+    "argument in" => 49,
+    "argument is" => 259,
+    "argument it" => 15,
+    "argument not" => 4,
+    "argument of" => 137,
+    "argument on" => 6,
+    "argument or" => 27,
+    "argument solution" => 3,
+    "argument that" => 42,
+    "argument the" => 17,
+    "argument to" => 351,
+    "argument when" => 20,
+    "argument will" => 29,
+    "argument with" => 29,
+    "argument you" => 7,
+    "arguments and" => 84,
+    "arguments for" => 50,
+    "arguments in" => 45,
+    "arguments is" => 43,
+    "arguments it" => 3,
+    "arguments of" => 45,
+    "arguments that" => 34,
+    "arguments the" => 12,
+    "arguments to" => 169,
+    "arguments with" => 14,
+    "arguments you" => 14,
+    "arm a" => 5,
+    "arm and" => 31,
+    "arm in" => 8,
+    "arm is" => 15,
+    "arm of" => 11,
+    "arm the" => 5,
+    "arm to" => 7,
+    "around a" => 47,
+    "around and" => 47,
+    "around for" => 10,
+    "around in" => 24,
+    "around is" => 6,
+    "around the" => 245,
+    "around this" => 45,
+    "around to" => 21,
+    "array a" => 10,
+    "array and" => 29,
+    "array for" => 12,
+    "array in" => 52,
+    "array is" => 28,
+    "array of" => 248,
+    "array that" => 5,
+    "array the" => 7,
+    "array to" => 19,
+    "as a" => 3652,
+    "as above" => 1061,
+    "as additional" => 8,
+    "as all" => 30,
+    "as also" => 3,
+    "as always" => 5,
+    "as an" => 904,
+    "as and" => 219,
+    "as another" => 16,
+    "as any" => 45,
+    "as apache" => 4,
+    "as are" => 16,
+    "as argument" => 24,
+    "as as" => 17,
+    "as at" => 12,
+    "as be" => 4,
+    "as because" => 10,
+    "as before" => 48,
+    "as being" => 56,
+    "as bit" => 19,
+    "as block" => 3,
+    "as both" => 22,
+    "as buffer" => 4,
+    "as build" => 12,
+    "as but" => 54,
+    "as by" => 20,
+    "as byte" => 7,
+    "as bytes" => 14,
+    "as can" => 22,
+    "as cargo" => 15,
+    "as code" => 14,
+    "as command" => 5,
+    "as commands" => 7,
+    "as compiler" => 4,
+    "as crate" => 3,
+    "as crates" => 8,
+    "as cursor" => 3,
+    "as data" => 19,
+    "as default" => 12,
+    "as defined" => 1075,
+    "as different" => 4,
+    "as do" => 8,
+    "as documentation" => 13,
+    "as does" => 19,
+    "as each" => 10,
+    "as either" => 23,
+    "as elements" => 4,
+    "as enabled" => 3,
+    "as end" => 3,
+    "as error" => 15,
+    "as even" => 5,
+    "as ex" => 5,
+    "as example" => 3,
+    "as examples" => 9,
+    "as exceptions" => 4,
+    "as feature" => 7,
+    "as features" => 7,
+    "as file" => 13,
+    "as files" => 7,
+    "as first" => 12,
+    "as floating" => 3,
+    "as following" => 4,
+    "as for" => 140,
+    "as format" => 6,
+    "as from" => 11,
+    "as function" => 13,
+    "as functions" => 10,
+    "as given" => 6,
+    "as had" => 4,
+    "as has" => 6,
+    "as have" => 3,
+    "as he" => 210,
+    "as help" => 3,
+    "as her" => 9,
+    "as his" => 30,
+    "as how" => 12,
+    "as i" => 328,
+    "as if" => 450,
+    "as in" => 302,
+    "as information" => 5,
+    "as instead" => 22,
+    "as integer" => 3,
+    "as integers" => 4,
+    "as is" => 190,
+    "as it" => 524,
+    "as it's" => 34,
+    "as its" => 136,
+    "as just" => 17,
+    "as key" => 10,
+    "as last" => 3,
+    "as library" => 6,
+    "as license" => 5,
+    "as line" => 4,
+    "as list" => 5,
+    "as macro" => 3,
+    "as make" => 4,
+    "as many" => 116,
+    "as may" => 15,
+    "as memory" => 3,
+    "as method" => 3,
+    "as methods" => 28,
+    "as might" => 7,
+    "as mit" => 89,
+    "as module" => 3,
+    "as more" => 13,
+    "as most" => 15,
+    "as multiple" => 10,
+    "as my" => 21,
+    "as name" => 7,
+    "as new" => 25,
+    "as no" => 22,
+    "as non" => 25,
+    "as not" => 29,
+    "as number" => 6,
+    "as object" => 8,
+    "as of" => 188,
+    "as on" => 26,
+    "as one" => 89,
+    "as only" => 10,
+    "as or" => 173,
+    "as other" => 40,
+    "as otherwise" => 5,
+    "as our" => 20,
+    "as out" => 6,
+    "as output" => 11,
+    "as packed" => 30,
+    "as part" => 241,
+    "as pattern" => 6,
+    "as possible" => 330,
+    "as provided" => 15,
+    "as read" => 10,
+    "as result" => 3,
+    "as return" => 6,
+    "as returned" => 19,
+    "as right" => 3,
+    "as run" => 3,
+    "as rust" => 40,
+    "as same" => 4,
+    "as set" => 9,
+    "as she" => 106,
+    "as single" => 4,
+    "as so" => 20,
+    "as some" => 23,
+    "as source" => 3,
+    "as specified" => 43,
+    "as standard" => 8,
+    "as start" => 3,
+    "as still" => 3,
+    "as string" => 16,
+    "as such" => 181,
+    "as support" => 4,
+    "as supported" => 5,
+    "as syntax" => 3,
+    "as target" => 6,
+    "as test" => 7,
+    "as tests" => 9,
+    "as text" => 19,
+    "as that" => 81,
+    "as the" => 2228,
+    "as their" => 40,
+    "as then" => 10,
+    "as there" => 46,
+    "as these" => 27,
+    "as they" => 223,
+    "as this" => 117,
+    "as through" => 7,
+    "as time" => 18,
+    "as to" => 235,
+    "as trait" => 6,
+    "as two" => 38,
+    "as type" => 13,
+    "as types" => 14,
+    "as use" => 3,
+    "as used" => 49,
+    "as using" => 17,
+    "as value" => 6,
+    "as values" => 11,
+    "as via" => 4,
+    "as vim" => 14,
+    "as was" => 15,
+    "as we" => 250,
+    "as well" => 1143,
+    "as were" => 6,
+    "as what" => 27,
+    "as when" => 76,
+    "as where" => 23,
+    "as which" => 28,
+    "as while" => 3,
+    "as will" => 17,
+    "as windows" => 13,
+    "as with" => 164,
+    "as without" => 9,
+    "as would" => 7,
+    "as you" => 299,
+    "as your" => 50,
+    "as zero" => 5,
+    "ascii and" => 9,
+    "ascii to" => 3,
+    "ask the" => 25,
+    "asked the" => 17,
+    "assert the" => 4,
+    "assign the" => 17,
+    "assigned the" => 9,
+    "assignment the" => 3,
+    "associated a" => 4,
+    "associated and" => 5,
+    "associated in" => 3,
+    "associated to" => 21,
+    "assume the" => 29,
+    "at a" => 559,
+    "at all" => 356,
+    "at an" => 80,
+    "at and" => 37,
+    "at any" => 180,
+    "at bit" => 9,
+    "at build" => 44,
+    "at but" => 4,
+    "at can" => 4,
+    "at code" => 6,
+    "at crate" => 4,
+    "at crates" => 10,
+    "at documentation" => 4,
+    "at does" => 3,
+    "at each" => 55,
+    "at example" => 3,
+    "at examples" => 14,
+    "at first" => 107,
+    "at for" => 9,
+    "at from" => 4,
+    "at his" => 79,
+    "at if" => 3,
+    "at in" => 8,
+    "at is" => 11,
+    "at it" => 58,
+    "at its" => 31,
+    "at license" => 7,
+    "at line" => 8,
+    "at more" => 7,
+    "at new" => 6,
+    "at no" => 11,
+    "at not" => 4,
+    "at one" => 48,
+    "at or" => 20,
+    "at other" => 8,
+    "at rust" => 7,
+    "at some" => 102,
+    "at that" => 127,
+    "at the" => 3192,
+    "at then" => 3,
+    "at these" => 11,
+    "at this" => 327,
+    "at time" => 11,
+    "at to" => 7,
+    "at two" => 15,
+    "at using" => 4,
+    "at version" => 5,
+    "at which" => 82,
+    "at will" => 10,
+    "at with" => 10,
+    "at work" => 18,
+    "at you" => 13,
+    "at your" => 1252,
+    "attempt a" => 5,
+    "attempt the" => 9,
+    "attempt to" => 339,
+    "attribute a" => 3,
+    "attribute and" => 29,
+    "attribute for" => 27,
+    "attribute in" => 28,
+    "attribute is" => 125,
+    "attribute of" => 35,
+    "attribute that" => 20,
+    "attribute the" => 4,
+    "attribute this" => 5,
+    "attribute to" => 82,
+    "attribute with" => 17,
+    "attributes a" => 3,
+    "attributes and" => 33,
+    "attributes for" => 25,
+    "attributes in" => 23,
+    "attributes is" => 11,
+    "attributes of" => 17,
+    "attributes that" => 28,
+    "attributes the" => 3,
+    "attributes to" => 27,
+    "attributes with" => 4,
+    "automatically and" => 17,
+    "automatically be" => 24,
+    "automatically for" => 10,
+    "automatically in" => 11,
+    "automatically to" => 7,
+    "available a" => 4,
+    "available and" => 53,
+    "available are" => 9,
+    "available as" => 65,
+    "available by" => 13,
+    "available for" => 211,
+    "available from" => 30,
+    "available if" => 18,
+    "available in" => 342,
+    "available is" => 4,
+    "available it" => 8,
+    "available on" => 452,
+    "available or" => 20,
+    "available that" => 5,
+    "available the" => 9,
+    "available this" => 4,
+    "available to" => 147,
+    "available use" => 4,
+    "available vim" => 3,
+    "available when" => 89,
+    "available will" => 4,
+    "available with" => 49,
+    "available you" => 6,
+    "avoid a" => 71,
+    "avoid that" => 78,
+    "avoid the" => 169,
+    "avoid this" => 85,
+    "away a" => 5,
+    "away and" => 51,
+    "away the" => 29,
+    "away to" => 20,
+    "back a" => 18,
+    "back and" => 87,
+    "back for" => 8,
+    "back in" => 78,
+    "back it" => 5,
+    "back of" => 35,
+    "back that" => 5,
+    "back the" => 57,
+    "back to" => 670,
+    "back with" => 20,
+    "back you" => 4,
+    "backend the" => 3,
+    "backend to" => 14,
+    "background the" => 4,
+    "bar the" => 3,
+    "base and" => 21,
+    "base for" => 14,
+    "base in" => 6,
+    "base is" => 18,
+    "base of" => 17,
+    "base to" => 13,
+    "based and" => 13,
+    "basic and" => 10,
+    "be a" => 1252,
+    "be about" => 5,
+    "be added" => 229,
+    "be additional" => 3,
+    "be after" => 8,
+    "be all" => 14,
+    "be also" => 14,
+    "be always" => 5,
+    "be an" => 253,
+    "be and" => 34,
+    "be another" => 9,
+    "be any" => 104,
+    "be as" => 104,
+    "be at" => 88,
+    "be automatically" => 71,
+    "be available" => 124,
+    "be back" => 8,
+    "be based" => 5,
+    "be be" => 4,
+    "be because" => 6,
+    "be before" => 6,
+    "be between" => 9,
+    "be bit" => 3,
+    "be both" => 5,
+    "be built" => 179,
+    "be but" => 23,
+    "be by" => 14,
+    "be byte" => 7,
+    "be bytes" => 4,
+    "be called" => 525,
+    "be cases" => 3,
+    "be created" => 189,
+    "be currently" => 4,
+    "be defined" => 147,
+    "be different" => 37,
+    "be directly" => 32,
+    "be done" => 340,
+    "be either" => 76,
+    "be empty" => 49,
+    "be enabled" => 304,
+    "be even" => 9,
+    "be explicitly" => 44,
+    "be following" => 5,
+    "be for" => 43,
+    "be found" => 636,
+    "be from" => 11,
+    "be function" => 6,
+    "be generated" => 203,
+    "be given" => 92,
+    "be he" => 4,
+    "be here" => 13,
+    "be his" => 9,
+    "be i" => 3,
+    "be if" => 70,
+    "be in" => 303,
+    "be instead" => 4,
+    "be integers" => 4,
+    "be is" => 3,
+    "be it" => 12,
+    "be its" => 3,
+    "be just" => 11,
+    "be language" => 3,
+    "be last" => 3,
+    "be left" => 42,
+    "be licensed" => 174,
+    "be like" => 15,
+    "be local" => 14,
+    "be long" => 7,
+    "be lower" => 8,
+    "be made" => 156,
+    "be many" => 5,
+    "be more" => 164,
+    "be most" => 7,
+    "be multiple" => 14,
+    "be my" => 12,
+    "be no" => 85,
+    "be non" => 40,
+    "be normal" => 4,
+    "be not" => 13,
+    "be number" => 3,
+    "be object" => 6,
+    "be of" => 78,
+    "be on" => 44,
+    "be one" => 561,
+    "be only" => 15,
+    "be open" => 15,
+    "be or" => 107,
+    "be other" => 7,
+    "be otherwise" => 7,
+    "be our" => 7,
+    "be out" => 11,
+    "be output" => 5,
+    "be over" => 6,
+    "be packed" => 6,
+    "be part" => 39,
+    "be passed" => 265,
+    "be pointer" => 3,
+    "be possible" => 102,
+    "be provided" => 108,
+    "be read" => 118,
+    "be required" => 44,
+    "be returned" => 180,
+    "be returns" => 3,
+    "be right" => 13,
+    "be run" => 182,
+    "be running" => 25,
+    "be rust" => 18,
+    "be safe" => 47,
+    "be said" => 13,
+    "be same" => 5,
+    "be set" => 397,
+    "be simple" => 12,
+    "be since" => 3,
+    "be so" => 44,
+    "be some" => 26,
+    "be specified" => 551,
+    "be still" => 7,
+    "be string" => 10,
+    "be such" => 5,
+    "be supported" => 95,
+    "be that" => 35,
+    "be the" => 587,
+    "be then" => 4,
+    "be there" => 28,
+    "be this" => 3,
+    "be those" => 3,
+    "be thread" => 7,
+    "be through" => 3,
+    "be to" => 62,
+    "be two" => 12,
+    "be type" => 10,
+    "be under" => 4,
+    "be unless" => 3,
+    "be up" => 16,
+    "be use" => 3,
+    "be used" => 4269,
+    "be useful" => 251,
+    "be using" => 39,
+    "be valid" => 160,
+    "be values" => 3,
+    "be very" => 88,
+    "be well" => 16,
+    "be what" => 18,
+    "be when" => 11,
+    "be where" => 7,
+    "be which" => 8,
+    "be while" => 6,
+    "be with" => 21,
+    "be within" => 11,
+    "be without" => 6,
+    "be you" => 3,
+    "be your" => 11,
+    "be zero" => 33,
+    "because a" => 57,
+    "because an" => 17,
+    "because and" => 8,
+    "because as" => 4,
+    "because by" => 6,
+    "because can" => 4,
+    "because for" => 7,
+    "because if" => 17,
+    "because in" => 13,
+    "because is" => 63,
+    "because it" => 425,
+    "because not" => 7,
+    "because of" => 267,
+    "because on" => 4,
+    "because that" => 33,
+    "because the" => 546,
+    "because this" => 71,
+    "because vim" => 19,
+    "because when" => 7,
+    "because with" => 6,
+    "because you" => 70,
+    "become the" => 31,
+    "becomes the" => 35,
+    "been a" => 65,
+    "been an" => 13,
+    "been and" => 5,
+    "been as" => 7,
+    "been for" => 11,
+    "been in" => 44,
+    "been not" => 3,
+    "been of" => 8,
+    "been on" => 11,
+    "been that" => 3,
+    "been the" => 43,
+    "been to" => 15,
+    "been using" => 18,
+    "been with" => 3,
+    "before a" => 112,
+    "before an" => 13,
+    "before and" => 87,
+    "before as" => 5,
+    "before for" => 4,
+    "before from" => 3,
+    "before i" => 67,
+    "before in" => 5,
+    "before is" => 14,
+    "before it" => 131,
+    "before or" => 29,
+    "before that" => 17,
+    "before the" => 792,
+    "before this" => 29,
+    "before to" => 3,
+    "before use" => 5,
+    "before using" => 49,
+    "before vim" => 10,
+    "before when" => 4,
+    "before which" => 5,
+    "before will" => 5,
+    "before with" => 7,
+    "before you" => 99,
+    "begin the" => 5,
+    "behavior a" => 3,
+    "behavior and" => 35,
+    "behavior for" => 46,
+    "behavior in" => 46,
+    "behavior is" => 185,
+    "behavior it" => 6,
+    "behavior of" => 223,
+    "behavior that" => 19,
+    "behavior the" => 10,
+    "behavior this" => 3,
+    "behavior to" => 45,
+    "behavior with" => 16,
+    "behavior you" => 5,
+    "behind the" => 99,
+    "being a" => 81,
+    "being and" => 4,
+    "being as" => 3,
+    "being in" => 26,
+    "being of" => 6,
+    "being on" => 5,
+    "being that" => 9,
+    "being the" => 53,
+    "being to" => 5,
+    "below a" => 16,
+    "below and" => 26,
+    "below for" => 93,
+    "below in" => 11,
+    "below is" => 56,
+    "below the" => 147,
+    "below this" => 6,
+    "below to" => 17,
+    "benchmark the" => 13,
+    "benchmarks and" => 23,
+    "benchmarks the" => 7,
+    "benchmarks to" => 12,
+    "best to" => 74,
+    "better and" => 9,
+    "better for" => 15,
+    "better in" => 7,
+    "better to" => 65,
+    "between a" => 64,
+    "between an" => 13,
+    "between and" => 192,
+    "between it" => 3,
+    "between the" => 412,
+    "between this" => 13,
+    "between when" => 5,
+    "between you" => 3,
+    "beyond the" => 127,
+    "big the" => 3,
+    "big to" => 3,
+    "binaries to" => 13,
+    "binary and" => 45,
+    "binary for" => 15,
+    "binary in" => 17,
+    "binary is" => 48,
+    "binary that" => 23,
+    "binary the" => 15,
+    "binary to" => 37,
+    "binary with" => 14,
+    "binding the" => 6,
+    "bindings and" => 40,
+    "bindings for" => 232,
+    "bindings in" => 16,
+    "bindings is" => 8,
+    "bindings of" => 17,
+    "bindings the" => 3,
+    "bindings to" => 186,
+    "bit and" => 57,
+    "bit bit" => 13,
+    "bit build" => 4,
+    "bit but" => 5,
+    "bit code" => 7,
+    "bit data" => 7,
+    "bit elements" => 88,
+    "bit for" => 6,
+    "bit from" => 15,
+    "bit function" => 6,
+    "bit i" => 4,
+    "bit if" => 3,
+    "bit in" => 71,
+    "bit is" => 1617,
+    "bit like" => 6,
+    "bit more" => 57,
+    "bit number" => 9,
+    "bit of" => 183,
+    "bit or" => 10,
+    "bit set" => 97,
+    "bit support" => 3,
+    "bit the" => 4,
+    "bit to" => 18,
+    "bit types" => 3,
+    "bit using" => 4,
+    "bit value" => 33,
+    "bit version" => 7,
+    "bit when" => 4,
+    "bit with" => 7,
+    "bits and" => 96,
+    "bits for" => 13,
+    "bits in" => 132,
+    "bits is" => 9,
+    "bits of" => 404,
+    "bits that" => 14,
+    "bits the" => 5,
+    "bits to" => 39,
+    "bits with" => 8,
+    "block a" => 13,
+    "block and" => 57,
+    "block are" => 18,
+    "block as" => 8,
+    "block for" => 38,
+    "block if" => 14,
+    "block in" => 23,
+    "block is" => 83,
+    "block it" => 10,
+    "block not" => 5,
+    "block of" => 131,
+    "block on" => 25,
+    "block or" => 25,
+    "block that" => 21,
+    "block the" => 80,
+    "block to" => 29,
+    "block with" => 30,
+    "blocking the" => 25,
+    "blocks the" => 27,
+    "blocks to" => 13,
+    "body and" => 26,
+    "body of" => 147,
+    "body the" => 5,
+    "body to" => 13,
+    "book the" => 8,
+    "book to" => 6,
+    "boolean a" => 5,
+    "boolean and" => 9,
+    "boolean for" => 6,
+    "boolean is" => 3,
+    "boolean the" => 3,
+    "boolean to" => 9,
+    "borrow the" => 16,
+    "both a" => 66,
+    "both an" => 14,
+    "both and" => 237,
+    "both are" => 39,
+    "both as" => 11,
+    "both be" => 15,
+    "both by" => 4,
+    "both can" => 8,
+    "both files" => 9,
+    "both for" => 27,
+    "both in" => 36,
+    "both of" => 103,
+    "both on" => 12,
+    "both that" => 3,
+    "both the" => 341,
+    "both this" => 9,
+    "both to" => 18,
+    "both use" => 6,
+    "both when" => 4,
+    "both with" => 19,
+    "both you" => 3,
+    "bound and" => 16,
+    "bound in" => 23,
+    "bound is" => 35,
+    "bound of" => 31,
+    "bound the" => 8,
+    "bound to" => 149,
+    "bounds and" => 11,
+    "bounds the" => 10,
+    "bounds to" => 14,
+    "box the" => 4,
+    "branch and" => 66,
+    "branch in" => 23,
+    "branch is" => 40,
+    "branch of" => 34,
+    "branch the" => 10,
+    "branch to" => 34,
+    "branches the" => 5,
+    "break a" => 17,
+    "break and" => 7,
+    "break the" => 45,
+    "break to" => 3,
+    "breaking a" => 5,
+    "breaking the" => 19,
+    "broken the" => 6,
+    "buffer a" => 16,
+    "buffer an" => 4,
+    "buffer and" => 85,
+    "buffer are" => 7,
+    "buffer as" => 18,
+    "buffer by" => 11,
+    "buffer can" => 19,
+    "buffer for" => 42,
+    "buffer from" => 16,
+    "buffer if" => 24,
+    "buffer in" => 75,
+    "buffer is" => 191,
+    "buffer it" => 17,
+    "buffer not" => 7,
+    "buffer of" => 41,
+    "buffer on" => 17,
+    "buffer or" => 35,
+    "buffer that" => 54,
+    "buffer the" => 61,
+    "buffer to" => 106,
+    "buffer use" => 13,
+    "buffer using" => 5,
+    "buffer vim" => 4,
+    "buffer when" => 35,
+    "buffer which" => 22,
+    "buffer will" => 22,
+    "buffer with" => 44,
+    "buffer you" => 11,
+    "buffers the" => 8,
+    "buffers to" => 14,
+    "build a" => 144,
+    "build all" => 20,
+    "build an" => 14,
+    "build and" => 114,
+    "build any" => 14,
+    "build as" => 17,
+    "build but" => 10,
+    "build by" => 8,
+    "build can" => 5,
+    "build documentation" => 10,
+    "build file" => 15,
+    "build files" => 6,
+    "build for" => 39,
+    "build from" => 15,
+    "build if" => 7,
+    "build in" => 16,
+    "build is" => 21,
+    "build it" => 30,
+    "build of" => 41,
+    "build on" => 96,
+    "build one" => 8,
+    "build only" => 4,
+    "build or" => 9,
+    "build problem" => 8,
+    "build rust" => 71,
+    "build test" => 13,
+    "build that" => 6,
+    "build the" => 176,
+    "build this" => 27,
+    "build to" => 8,
+    "build type" => 3,
+    "build use" => 3,
+    "build using" => 15,
+    "build vim" => 15,
+    "build when" => 4,
+    "build will" => 9,
+    "build with" => 222,
+    "build you" => 3,
+    "build your" => 59,
+    "building a" => 96,
+    "building and" => 38,
+    "building for" => 26,
+    "building in" => 13,
+    "building of" => 14,
+    "building the" => 200,
+    "building this" => 11,
+    "building to" => 5,
+    "builds a" => 22,
+    "builds the" => 15,
+    "builds to" => 3,
+    "built a" => 9,
+    "built and" => 24,
+    "built for" => 70,
+    "built in" => 498,
+    "built is" => 4,
+    "built of" => 4,
+    "built the" => 8,
+    "built this" => 4,
+    "built to" => 10,
+    "bump the" => 24,
+    "but a" => 164,
+    "but add" => 5,
+    "but after" => 34,
+    "but all" => 36,
+    "but also" => 147,
+    "but an" => 32,
+    "but and" => 14,
+    "but any" => 26,
+    "but are" => 93,
+    "but as" => 77,
+    "but at" => 51,
+    "but be" => 5,
+    "but by" => 23,
+    "but can" => 102,
+    "but do" => 44,
+    "but does" => 89,
+    "but for" => 148,
+    "but from" => 7,
+    "but has" => 54,
+    "but have" => 26,
+    "but he" => 151,
+    "but i" => 368,
+    "but if" => 182,
+    "but in" => 160,
+    "but is" => 241,
+    "but it" => 700,
+    "but like" => 7,
+    "but make" => 5,
+    "but may" => 72,
+    "but more" => 21,
+    "but must" => 18,
+    "but no" => 76,
+    "but not" => 494,
+    "but of" => 26,
+    "but on" => 43,
+    "but one" => 61,
+    "but only" => 140,
+    "but or" => 3,
+    "but other" => 17,
+    "but rust" => 15,
+    "but see" => 4,
+    "but set" => 7,
+    "but should" => 26,
+    "but so" => 7,
+    "but some" => 42,
+    "but support" => 7,
+    "but that" => 163,
+    "but the" => 748,
+    "but then" => 72,
+    "but there" => 150,
+    "but these" => 35,
+    "but they" => 174,
+    "but this" => 256,
+    "but to" => 68,
+    "but use" => 58,
+    "but used" => 8,
+    "but using" => 59,
+    "but vim" => 10,
+    "but was" => 32,
+    "but we" => 126,
+    "but when" => 108,
+    "but which" => 18,
+    "but will" => 110,
+    "but with" => 139,
+    "but without" => 113,
+    "but you" => 302,
+    "but your" => 18,
+    "button the" => 4,
+    "by a" => 860,
+    "by about" => 5,
+    "by after" => 3,
+    "by all" => 61,
+    "by also" => 6,
+    "by always" => 5,
+    "by an" => 165,
+    "by and" => 157,
+    "by another" => 52,
+    "by any" => 76,
+    "by are" => 13,
+    "by as" => 19,
+    "by at" => 19,
+    "by because" => 4,
+    "by before" => 3,
+    "by bit" => 3,
+    "by both" => 23,
+    "by buffer" => 6,
+    "by build" => 5,
+    "by but" => 16,
+    "by by" => 7,
+    "by can" => 5,
+    "by cargo" => 52,
+    "by case" => 11,
+    "by character" => 6,
+    "by code" => 9,
+    "by command" => 5,
+    "by crate" => 10,
+    "by crates" => 7,
+    "by default" => 2079,
+    "by different" => 12,
+    "by each" => 62,
+    "by either" => 28,
+    "by element" => 15,
+    "by elements" => 26,
+    "by example" => 39,
+    "by expression" => 6,
+    "by feature" => 10,
+    "by file" => 9,
+    "by files" => 3,
+    "by first" => 13,
+    "by following" => 21,
+    "by for" => 11,
+    "by from" => 7,
+    "by function" => 5,
+    "by functions" => 7,
+    "by has" => 6,
+    "by his" => 54,
+    "by i" => 9,
+    "by if" => 14,
+    "by in" => 14,
+    "by instead" => 4,
+    "by integer" => 7,
+    "by into" => 6,
+    "by is" => 31,
+    "by it" => 17,
+    "by its" => 74,
+    "by just" => 11,
+    "by key" => 16,
+    "by library" => 3,
+    "by line" => 23,
+    "by may" => 3,
+    "by me" => 13,
+    "by method" => 3,
+    "by more" => 9,
+    "by most" => 13,
+    "by must" => 6,
+    "by my" => 35,
+    "by name" => 71,
+    "by new" => 3,
+    "by no" => 20,
+    "by non" => 12,
+    "by not" => 21,
+    "by now" => 11,
+    "by number" => 6,
+    "by object" => 3,
+    "by on" => 6,
+    "by one" => 162,
+    "by only" => 28,
+    "by or" => 37,
+    "by other" => 74,
+    "by over" => 3,
+    "by packed" => 16,
+    "by patch" => 3,
+    "by path" => 10,
+    "by project" => 3,
+    "by reference" => 36,
+    "by returns" => 4,
+    "by rust" => 45,
+    "by should" => 4,
+    "by so" => 13,
+    "by some" => 68,
+    "by string" => 3,
+    "by such" => 9,
+    "by syntax" => 8,
+    "by system" => 4,
+    "by terminal" => 3,
+    "by test" => 4,
+    "by tests" => 53,
+    "by text" => 4,
+    "by that" => 62,
+    "by the" => 3516,
+    "by their" => 70,
+    "by them" => 8,
+    "by then" => 8,
+    "by these" => 31,
+    "by this" => 333,
+    "by time" => 5,
+    "by to" => 32,
+    "by trait" => 5,
+    "by two" => 30,
+    "by type" => 12,
+    "by types" => 7,
+    "by use" => 11,
+    "by using" => 483,
+    "by value" => 75,
+    "by variable" => 3,
+    "by vim" => 51,
+    "by way" => 14,
+    "by we" => 4,
+    "by what" => 17,
+    "by when" => 8,
+    "by where" => 11,
+    "by which" => 59,
+    "by while" => 23,
+    "by will" => 9,
+    "by windows" => 5,
+    "by with" => 17,
+    "by without" => 5,
+    "by you" => 1135,
+    "by your" => 39,
+    "byte and" => 15,
+    "byte as" => 6,
+    "byte for" => 9,
+    "byte in" => 31,
+    "byte is" => 37,
+    "byte of" => 65,
+    "byte that" => 4,
+    "byte the" => 3,
+    "byte to" => 13,
+    "byte with" => 8,
+    "bytes a" => 11,
+    "bytes and" => 77,
+    "bytes are" => 72,
+    "bytes as" => 22,
+    "bytes can" => 6,
+    "bytes for" => 23,
+    "bytes if" => 4,
+    "bytes in" => 115,
+    "bytes is" => 16,
+    "bytes it" => 16,
+    "bytes not" => 10,
+    "bytes of" => 126,
+    "bytes on" => 17,
+    "bytes or" => 38,
+    "bytes that" => 44,
+    "bytes the" => 14,
+    "bytes this" => 3,
+    "bytes to" => 136,
+    "bytes with" => 12,
+    "cache a" => 3,
+    "cache and" => 13,
+    "cache of" => 12,
+    "cache the" => 33,
+    "cache to" => 10,
+    "call a" => 85,
+    "call an" => 8,
+    "call and" => 44,
+    "call are" => 3,
+    "call as" => 9,
+    "call by" => 7,
+    "call can" => 3,
+    "call for" => 25,
+    "call from" => 13,
+    "call if" => 12,
+    "call in" => 46,
+    "call is" => 55,
+    "call it" => 98,
+    "call of" => 12,
+    "call on" => 96,
+    "call or" => 29,
+    "call that" => 25,
+    "call the" => 251,
+    "call this" => 85,
+    "call to" => 404,
+    "call when" => 27,
+    "call will" => 50,
+    "call with" => 41,
+    "call you" => 9,
+    "callback and" => 10,
+    "callback is" => 76,
+    "callback of" => 3,
+    "callback the" => 7,
+    "callback to" => 26,
+    "called a" => 83,
+    "called an" => 21,
+    "called and" => 57,
+    "called as" => 17,
+    "called can" => 3,
+    "called for" => 35,
+    "called if" => 25,
+    "called in" => 60,
+    "called is" => 11,
+    "called it" => 22,
+    "called on" => 111,
+    "called or" => 11,
+    "called that" => 32,
+    "called the" => 98,
+    "called this" => 4,
+    "called to" => 47,
+    "called when" => 89,
+    "called with" => 109,
+    "calling a" => 93,
+    "calling and" => 29,
+    "calling for" => 5,
+    "calling in" => 8,
+    "calling is" => 23,
+    "calling the" => 143,
+    "calling this" => 128,
+    "calling to" => 14,
+    "calls a" => 29,
+    "calls and" => 36,
+    "calls for" => 11,
+    "calls in" => 15,
+    "calls of" => 10,
+    "calls the" => 65,
+    "calls to" => 230,
+    "came a" => 14,
+    "came the" => 17,
+    "came to" => 89,
+    "can a" => 8,
+    "can add" => 172,
+    "can all" => 12,
+    "can also" => 1060,
+    "can always" => 40,
+    "can and" => 23,
+    "can any" => 4,
+    "can as" => 3,
+    "can at" => 11,
+    "can be" => 9033,
+    "can both" => 5,
+    "can build" => 76,
+    "can but" => 5,
+    "can call" => 115,
+    "can change" => 134,
+    "can check" => 63,
+    "can compile" => 30,
+    "can do" => 302,
+    "can either" => 56,
+    "can end" => 14,
+    "can even" => 110,
+    "can for" => 8,
+    "can get" => 135,
+    "can have" => 218,
+    "can he" => 5,
+    "can i" => 61,
+    "can in" => 9,
+    "can instead" => 35,
+    "can it" => 13,
+    "can just" => 36,
+    "can list" => 8,
+    "can make" => 158,
+    "can name" => 5,
+    "can no" => 21,
+    "can not" => 87,
+    "can now" => 134,
+    "can of" => 7,
+    "can one" => 5,
+    "can only" => 439,
+    "can or" => 6,
+    "can point" => 14,
+    "can read" => 95,
+    "can reference" => 14,
+    "can result" => 35,
+    "can return" => 52,
+    "can run" => 216,
+    "can see" => 254,
+    "can set" => 159,
+    "can start" => 56,
+    "can still" => 146,
+    "can store" => 31,
+    "can support" => 15,
+    "can target" => 7,
+    "can test" => 35,
+    "can the" => 8,
+    "can then" => 216,
+    "can they" => 3,
+    "can this" => 11,
+    "can to" => 13,
+    "can type" => 31,
+    "can use" => 1533,
+    "can very" => 3,
+    "can we" => 29,
+    "can with" => 3,
+    "can work" => 56,
+    "can you" => 36,
+    "cannot be" => 791,
+    "capacity to" => 12,
+    "capture the" => 37,
+    "cargo and" => 58,
+    "cargo as" => 9,
+    "cargo by" => 5,
+    "cargo can" => 17,
+    "cargo for" => 8,
+    "cargo from" => 39,
+    "cargo in" => 7,
+    "cargo is" => 35,
+    "cargo it" => 5,
+    "cargo not" => 6,
+    "cargo of" => 3,
+    "cargo or" => 4,
+    "cargo that" => 10,
+    "cargo the" => 9,
+    "cargo to" => 79,
+    "cargo use" => 5,
+    "cargo will" => 240,
+    "cargo with" => 7,
+    "cargo you" => 5,
+    "case a" => 26,
+    "case an" => 7,
+    "case and" => 51,
+    "case are" => 5,
+    "case as" => 14,
+    "case by" => 11,
+    "case can" => 4,
+    "case for" => 87,
+    "case if" => 20,
+    "case in" => 50,
+    "case is" => 135,
+    "case it" => 60,
+    "case of" => 310,
+    "case on" => 5,
+    "case or" => 9,
+    "case that" => 51,
+    "case the" => 186,
+    "case this" => 30,
+    "case to" => 21,
+    "case use" => 4,
+    "case when" => 37,
+    "case with" => 20,
+    "case you" => 107,
+    "cases a" => 15,
+    "cases and" => 38,
+    "cases for" => 28,
+    "cases in" => 19,
+    "cases is" => 9,
+    "cases of" => 34,
+    "cases that" => 22,
+    "cases the" => 72,
+    "cases this" => 26,
+    "cases to" => 22,
+    "cast the" => 8,
+    "catch the" => 32,
+    "cause a" => 178,
+    "cause and" => 8,
+    "cause for" => 3,
+    "cause of" => 54,
+    "cause the" => 128,
+    "cause to" => 21,
+    "caused the" => 39,
+    "causes a" => 77,
+    "causes the" => 114,
+    "causes to" => 7,
+    "certain of" => 5,
+    "certain to" => 3,
+    "chain the" => 4,
+    "change a" => 49,
+    "change an" => 12,
+    "change and" => 56,
+    "change as" => 29,
+    "change by" => 11,
+    "change can" => 4,
+    "change for" => 23,
+    "change from" => 29,
+    "change if" => 16,
+    "change in" => 287,
+    "change is" => 51,
+    "change it" => 43,
+    "change of" => 21,
+    "change on" => 5,
+    "change or" => 22,
+    "change that" => 35,
+    "change the" => 657,
+    "change this" => 54,
+    "change to" => 195,
+    "change use" => 4,
+    "change vim" => 3,
+    "change when" => 12,
+    "change will" => 14,
+    "change with" => 22,
+    "change you" => 12,
+    "changed a" => 3,
+    "changed and" => 24,
+    "changed for" => 5,
+    "changed in" => 136,
+    "changed the" => 59,
+    "changed to" => 78,
+    "changes a" => 10,
+    "changes and" => 83,
+    "changes are" => 89,
+    "changes as" => 8,
+    "changes can" => 15,
+    "changes for" => 27,
+    "changes in" => 141,
+    "changes is" => 5,
+    "changes it" => 10,
+    "changes of" => 10,
+    "changes on" => 22,
+    "changes or" => 11,
+    "changes that" => 46,
+    "changes the" => 178,
+    "changes this" => 6,
+    "changes to" => 314,
+    "changes with" => 5,
+    "changes you" => 32,
+    "changing a" => 27,
+    "changing the" => 222,
+    "changing to" => 14,
+    "channel and" => 67,
+    "channel for" => 12,
+    "channel in" => 15,
+    "channel is" => 49,
+    "channel it" => 3,
+    "channel of" => 7,
+    "channel that" => 15,
+    "channel the" => 4,
+    "channel this" => 8,
+    "channel to" => 26,
+    "channel with" => 25,
+    "chapter a" => 4,
+    "chapter and" => 24,
+    "chapter for" => 61,
+    "chapter in" => 23,
+    "chapter is" => 17,
+    "chapter of" => 28,
+    "chapter the" => 5,
+    "chapter to" => 15,
+    "char to" => 27,
+    "character a" => 9,
+    "character and" => 60,
+    "character are" => 4,
+    "character as" => 31,
+    "character by" => 11,
+    "character can" => 11,
+    "character for" => 17,
+    "character if" => 6,
+    "character in" => 117,
+    "character is" => 119,
+    "character it" => 4,
+    "character not" => 7,
+    "character of" => 73,
+    "character on" => 13,
+    "character or" => 30,
+    "character that" => 49,
+    "character the" => 15,
+    "character this" => 8,
+    "character to" => 51,
+    "character when" => 20,
+    "character with" => 48,
+    "character you" => 8,
+    "characters a" => 4,
+    "characters and" => 69,
+    "characters are" => 165,
+    "characters as" => 31,
+    "characters can" => 27,
+    "characters for" => 37,
+    "characters if" => 6,
+    "characters in" => 200,
+    "characters is" => 23,
+    "characters it" => 7,
+    "characters not" => 13,
+    "characters of" => 26,
+    "characters on" => 11,
+    "characters or" => 27,
+    "characters that" => 67,
+    "characters the" => 13,
+    "characters to" => 71,
+    "characters with" => 26,
+    "characters you" => 13,
+    "check a" => 9,
+    "check and" => 22,
+    "check as" => 3,
+    "check by" => 3,
+    "check for" => 1085,
+    "check if" => 257,
+    "check in" => 23,
+    "check is" => 28,
+    "check it" => 21,
+    "check not" => 3,
+    "check of" => 8,
+    "check on" => 6,
+    "check or" => 3,
+    "check that" => 200,
+    "check the" => 474,
+    "check this" => 17,
+    "check to" => 40,
+    "check when" => 10,
+    "check will" => 5,
+    "check with" => 12,
+    "checked the" => 5,
+    "checking a" => 9,
+    "checking and" => 19,
+    "checking of" => 14,
+    "checking the" => 74,
+    "checking to" => 4,
+    "checks and" => 10,
+    "checks in" => 14,
+    "checks the" => 31,
+    "checks to" => 30,
+    "child to" => 10,
+    "choice the" => 3,
+    "choose the" => 49,
+    "class a" => 12,
+    "class and" => 24,
+    "class for" => 6,
+    "class in" => 12,
+    "class is" => 74,
+    "class of" => 34,
+    "class the" => 19,
+    "class to" => 16,
+    "classes the" => 4,
+    "clause the" => 5,
+    "clean the" => 4,
+    "clear a" => 4,
+    "clear and" => 28,
+    "clear the" => 109,
+    "clear to" => 18,
+    "click the" => 26,
+    "client and" => 59,
+    "client for" => 32,
+    "client in" => 5,
+    "client is" => 18,
+    "client of" => 8,
+    "client the" => 3,
+    "client to" => 24,
+    "clipboard the" => 4,
+    "clone the" => 64,
+    "close a" => 20,
+    "close the" => 115,
+    "close to" => 84,
+    "closed the" => 26,
+    "closes files" => 2904,
+    "closes the" => 31,
+    "closing the" => 51,
+    "closure and" => 18,
+    "closure for" => 5,
+    "closure in" => 9,
+    "closure is" => 39,
+    "closure of" => 3,
+    "closure the" => 4,
+    "closure to" => 50,
+    "code a" => 27,
+    "code add" => 5,
+    "code after" => 14,
+    "code all" => 3,
+    "code also" => 4,
+    "code an" => 5,
+    "code and" => 258,
+    "code are" => 24,
+    "code as" => 70,
+    "code at" => 45,
+    "code be" => 3,
+    "code but" => 42,
+    "code by" => 36,
+    "code can" => 93,
+    "code documentation" => 6,
+    "code example" => 31,
+    "code examples" => 25,
+    "code file" => 5,
+    "code files" => 6,
+    "code for" => 270,
+    "code from" => 123,
+    "code has" => 48,
+    "code i" => 5,
+    "code if" => 36,
+    "code in" => 418,
+    "code into" => 50,
+    "code is" => 456,
+    "code it" => 25,
+    "code like" => 34,
+    "code may" => 49,
+    "code more" => 19,
+    "code no" => 5,
+    "code not" => 56,
+    "code of" => 285,
+    "code on" => 41,
+    "code only" => 10,
+    "code or" => 51,
+    "code other" => 6,
+    "code rust" => 3,
+    "code see" => 5,
+    "code so" => 38,
+    "code some" => 3,
+    "code that" => 371,
+    "code the" => 57,
+    "code then" => 16,
+    "code this" => 8,
+    "code to" => 418,
+    "code use" => 8,
+    "code used" => 11,
+    "code using" => 85,
+    "code was" => 37,
+    "code we" => 37,
+    "code when" => 32,
+    "code which" => 53,
+    "code will" => 136,
+    "code with" => 93,
+    "code you" => 56,
+    "code your" => 3,
+    "collection the" => 3,
+    "collection to" => 8,
+    "color and" => 28,
+    "color for" => 16,
+    "color in" => 19,
+    "color is" => 21,
+    "color of" => 16,
+    "color the" => 6,
+    "color to" => 12,
+    "colors and" => 28,
+    "colors the" => 7,
+    "colors to" => 13,
+    "column and" => 18,
+    "column is" => 36,
+    "column of" => 56,
+    "column the" => 8,
+    "column to" => 19,
+    "come and" => 23,
+    "come in" => 65,
+    "come of" => 4,
+    "come the" => 4,
+    "come to" => 90,
+    "comes the" => 6,
+    "command a" => 14,
+    "command all" => 3,
+    "command also" => 10,
+    "command an" => 3,
+    "command and" => 97,
+    "command are" => 14,
+    "command as" => 32,
+    "command at" => 12,
+    "command but" => 17,
+    "command by" => 11,
+    "command can" => 77,
+    "command files" => 3,
+    "command for" => 69,
+    "command from" => 27,
+    "command i" => 4,
+    "command if" => 17,
+    "command in" => 155,
+    "command is" => 235,
+    "command it" => 15,
+    "command may" => 27,
+    "command no" => 5,
+    "command not" => 20,
+    "command of" => 5,
+    "command on" => 26,
+    "command only" => 7,
+    "command or" => 45,
+    "command see" => 10,
+    "command set" => 6,
+    "command that" => 110,
+    "command the" => 58,
+    "command this" => 11,
+    "command to" => 307,
+    "command use" => 17,
+    "command used" => 10,
+    "command using" => 10,
+    "command vim" => 16,
+    "command was" => 25,
+    "command we" => 3,
+    "command when" => 21,
+    "command which" => 33,
+    "command will" => 113,
+    "command with" => 84,
+    "command you" => 50,
+    "commands a" => 7,
+    "commands and" => 70,
+    "commands are" => 103,
+    "commands as" => 6,
+    "commands for" => 43,
+    "commands in" => 79,
+    "commands is" => 17,
+    "commands it" => 8,
+    "commands not" => 12,
+    "commands of" => 4,
+    "commands on" => 6,
+    "commands or" => 11,
+    "commands that" => 108,
+    "commands the" => 14,
+    "commands this" => 4,
+    "commands to" => 106,
+    "commands with" => 26,
+    "commands you" => 25,
+    "comment and" => 17,
+    "comment of" => 4,
+    "comment the" => 5,
+    "comment to" => 4,
+    "comments and" => 26,
+    "comments of" => 6,
+    "comments to" => 13,
+    "commit a" => 6,
+    "commit and" => 28,
+    "commit in" => 14,
+    "commit is" => 20,
+    "commit of" => 11,
+    "commit the" => 14,
+    "commit to" => 29,
+    "common and" => 12,
+    "common for" => 12,
+    "common in" => 22,
+    "common to" => 44,
+    "compare a" => 9,
+    "compare and" => 27,
+    "compare the" => 105,
+    "compare to" => 15,
+    "compared the" => 5,
+    "compares the" => 45,
+    "comparison the" => 3,
+    "comparison to" => 32,
+    "compatibility and" => 16,
+    "compatibility of" => 12,
+    "compatibility the" => 5,
+    "compatible and" => 15,
+    "compatible for" => 4,
+    "compatible in" => 4,
+    "compatible is" => 16,
+    "compatible to" => 4,
+    "compilation and" => 51,
+    "compilation is" => 18,
+    "compilation of" => 26,
+    "compilation the" => 6,
+    "compilation to" => 16,
+    "compile a" => 37,
+    "compile and" => 64,
+    "compile are" => 3,
+    "compile as" => 4,
+    "compile for" => 55,
+    "compile if" => 16,
+    "compile in" => 16,
+    "compile it" => 26,
+    "compile on" => 68,
+    "compile or" => 7,
+    "compile that" => 5,
+    "compile the" => 80,
+    "compile this" => 39,
+    "compile to" => 19,
+    "compile when" => 12,
+    "compile with" => 166,
+    "compiled and" => 49,
+    "compiled for" => 43,
+    "compiled in" => 32,
+    "compiled the" => 5,
+    "compiled to" => 36,
+    "compiler a" => 3,
+    "compiler and" => 87,
+    "compiler as" => 12,
+    "compiler by" => 5,
+    "compiler can" => 40,
+    "compiler for" => 30,
+    "compiler in" => 17,
+    "compiler is" => 79,
+    "compiler on" => 11,
+    "compiler or" => 11,
+    "compiler that" => 46,
+    "compiler the" => 10,
+    "compiler this" => 9,
+    "compiler to" => 83,
+    "compiler when" => 11,
+    "compiler with" => 9,
+    "compiler you" => 6,
+    "compiling a" => 40,
+    "compiling the" => 48,
+    "compiling to" => 19,
+    "complete a" => 7,
+    "complete and" => 24,
+    "complete in" => 3,
+    "complete the" => 26,
+    "complete to" => 8,
+    "completion and" => 16,
+    "completion in" => 20,
+    "completion is" => 25,
+    "completion of" => 53,
+    "completion the" => 8,
+    "completion to" => 11,
+    "complex and" => 16,
+    "complex to" => 13,
+    "compute a" => 7,
+    "compute the" => 346,
+    "computes the" => 119,
+    "conditions and" => 14,
+    "conditions for" => 11,
+    "conditions in" => 14,
+    "conditions is" => 6,
+    "conditions of" => 10,
+    "conditions that" => 11,
+    "conditions the" => 3,
+    "conditions to" => 4,
+    "configuration and" => 18,
+    "configuration for" => 30,
+    "configuration in" => 20,
+    "configuration is" => 24,
+    "configuration of" => 25,
+    "configuration to" => 15,
+    "configure a" => 12,
+    "configure and" => 20,
+    "configure for" => 5,
+    "configure is" => 4,
+    "configure the" => 75,
+    "configure to" => 15,
+    "connection the" => 3,
+    "connection to" => 39,
+    "consider a" => 28,
+    "consider the" => 95,
+    "considered a" => 130,
+    "considered the" => 22,
+    "considered to" => 87,
+    "console the" => 13,
+    "console to" => 10,
+    "const and" => 9,
+    "const is" => 5,
+    "constant a" => 4,
+    "constant the" => 3,
+    "constant to" => 4,
+    "construct the" => 13,
+    "constructor the" => 3,
+    "contain a" => 114,
+    "contain and" => 9,
+    "contain in" => 5,
+    "contain the" => 140,
+    "contain to" => 4,
+    "contained a" => 11,
+    "contained the" => 7,
+    "containing a" => 95,
+    "containing and" => 3,
+    "containing the" => 295,
+    "contains a" => 336,
+    "contains and" => 14,
+    "contains for" => 5,
+    "contains it" => 3,
+    "contains the" => 337,
+    "contains this" => 6,
+    "content to" => 24,
+    "contents and" => 17,
+    "contents for" => 3,
+    "contents in" => 17,
+    "contents is" => 11,
+    "contents of" => 453,
+    "contents to" => 11,
+    "context a" => 7,
+    "context and" => 29,
+    "context for" => 31,
+    "context in" => 22,
+    "context is" => 52,
+    "context of" => 126,
+    "context the" => 9,
+    "context to" => 27,
+    "continue the" => 13,
+    "continue to" => 136,
+    "contract the" => 8,
+    "contributing and" => 24,
+    "contributing in" => 4,
+    "contributing to" => 39,
+    "contribution is" => 21,
+    "contribution to" => 24,
+    "contribution you" => 6,
+    "control and" => 25,
+    "control for" => 4,
+    "control in" => 87,
+    "control is" => 16,
+    "control of" => 38,
+    "control the" => 89,
+    "control to" => 21,
+    "convenience the" => 7,
+    "convention the" => 3,
+    "conversion and" => 10,
+    "conversion of" => 59,
+    "conversion to" => 44,
+    "convert a" => 71,
+    "convert and" => 3,
+    "convert the" => 246,
+    "convert this" => 7,
+    "convert to" => 101,
+    "converting the" => 30,
+    "converts the" => 59,
+    "copied and" => 14,
+    "copied in" => 4,
+    "copied the" => 6,
+    "copied to" => 31,
+    "copies the" => 61,
+    "copy a" => 39,
+    "copy and" => 25,
+    "copy for" => 5,
+    "copy in" => 3,
+    "copy is" => 23,
+    "copy it" => 13,
+    "copy of" => 367,
+    "copy that" => 5,
+    "copy the" => 545,
+    "copy this" => 5,
+    "copy to" => 16,
+    "copy with" => 3,
+    "copying the" => 40,
+    "copyright the" => 3,
+    "core a" => 4,
+    "core and" => 36,
+    "core in" => 5,
+    "core is" => 11,
+    "core of" => 38,
+    "correct a" => 3,
+    "correct and" => 42,
+    "correct the" => 66,
+    "correct to" => 4,
+    "correctly the" => 3,
+    "corresponding and" => 6,
+    "corresponding is" => 8,
+    "corresponding or" => 3,
+    "corresponding the" => 3,
+    "corresponding to" => 93,
+    "could and" => 8,
+    "could be" => 454,
+    "could for" => 9,
+    "could in" => 6,
+    "could it" => 10,
+    "could not" => 401,
+    "could the" => 3,
+    "could to" => 4,
+    "could with" => 7,
+    "could you" => 12,
+    "count a" => 3,
+    "count and" => 22,
+    "count for" => 26,
+    "count in" => 13,
+    "count is" => 43,
+    "count of" => 62,
+    "count the" => 42,
+    "count to" => 33,
+    "course the" => 17,
+    "covered the" => 7,
+    "cpu the" => 3,
+    "cpu to" => 8,
+    "crash and" => 9,
+    "crash for" => 8,
+    "crash in" => 46,
+    "crash of" => 6,
+    "crash the" => 6,
+    "crate a" => 16,
+    "crate add" => 16,
+    "crate all" => 3,
+    "crate also" => 69,
+    "crate and" => 221,
+    "crate are" => 59,
+    "crate as" => 96,
+    "crate at" => 7,
+    "crate be" => 4,
+    "crate build" => 3,
+    "crate but" => 37,
+    "crate by" => 175,
+    "crate can" => 153,
+    "crate crate" => 127,
+    "crate do" => 3,
+    "crate documentation" => 86,
+    "crate does" => 102,
+    "crate example" => 4,
+    "crate feature" => 91,
+    "crate file" => 6,
+    "crate files" => 4,
+    "crate for" => 291,
+    "crate from" => 32,
+    "crate has" => 181,
+    "crate have" => 19,
+    "crate i" => 11,
+    "crate if" => 22,
+    "crate in" => 122,
+    "crate into" => 14,
+    "crate is" => 1096,
+    "crate it" => 16,
+    "crate license" => 14,
+    "crate like" => 15,
+    "crate make" => 3,
+    "crate may" => 32,
+    "crate more" => 6,
+    "crate must" => 11,
+    "crate name" => 57,
+    "crate no" => 20,
+    "crate not" => 16,
+    "crate of" => 54,
+    "crate on" => 38,
+    "crate only" => 36,
+    "crate or" => 42,
+    "crate rust" => 4,
+    "crate see" => 9,
+    "crate should" => 54,
+    "crate so" => 32,
+    "crate support" => 5,
+    "crate that" => 161,
+    "crate the" => 38,
+    "crate then" => 8,
+    "crate they" => 6,
+    "crate this" => 9,
+    "crate to" => 253,
+    "crate type" => 22,
+    "crate types" => 22,
+    "crate use" => 16,
+    "crate used" => 11,
+    "crate using" => 18,
+    "crate version" => 54,
+    "crate was" => 51,
+    "crate we" => 13,
+    "crate when" => 12,
+    "crate which" => 138,
+    "crate will" => 115,
+    "crate with" => 103,
+    "crate without" => 18,
+    "crate work" => 4,
+    "crate you" => 48,
+    "crates and" => 77,
+    "crates are" => 60,
+    "crates as" => 5,
+    "crates by" => 13,
+    "crates can" => 25,
+    "crates for" => 22,
+    "crates from" => 31,
+    "crates if" => 4,
+    "crates in" => 87,
+    "crates is" => 5,
+    "crates it" => 3,
+    "crates of" => 6,
+    "crates on" => 10,
+    "crates or" => 4,
+    "crates that" => 108,
+    "crates the" => 9,
+    "crates this" => 4,
+    "crates to" => 34,
+    "crates use" => 3,
+    "crates using" => 13,
+    "crates which" => 28,
+    "crates will" => 11,
+    "crates with" => 28,
+    "crates you" => 9,
+    "create a" => 977,
+    "create and" => 59,
+    "create as" => 4,
+    "create in" => 6,
+    "create is" => 7,
+    "create it" => 7,
+    "create or" => 9,
+    "create that" => 5,
+    "create the" => 143,
+    "create this" => 4,
+    "create with" => 4,
+    "created a" => 37,
+    "created and" => 50,
+    "created for" => 33,
+    "created in" => 42,
+    "created that" => 4,
+    "created the" => 26,
+    "created this" => 9,
+    "created to" => 19,
+    "creates a" => 576,
+    "creates and" => 38,
+    "creates for" => 3,
+    "creates the" => 30,
+    "creating a" => 344,
+    "creating and" => 38,
+    "creating in" => 3,
+    "creating the" => 57,
+    "cross a" => 7,
+    "cross and" => 3,
+    "cross the" => 13,
+    "current and" => 42,
+    "current of" => 3,
+    "current or" => 8,
+    "current vim" => 7,
+    "current with" => 3,
+    "currently a" => 18,
+    "currently and" => 6,
+    "currently be" => 4,
+    "currently in" => 47,
+    "currently is" => 18,
+    "currently it" => 22,
+    "currently that" => 3,
+    "currently the" => 120,
+    "currently this" => 43,
+    "cursor a" => 5,
+    "cursor and" => 44,
+    "cursor are" => 5,
+    "cursor as" => 8,
+    "cursor by" => 8,
+    "cursor can" => 9,
+    "cursor files" => 4,
+    "cursor for" => 4,
+    "cursor if" => 14,
+    "cursor in" => 101,
+    "cursor is" => 225,
+    "cursor it" => 6,
+    "cursor not" => 4,
+    "cursor of" => 4,
+    "cursor on" => 78,
+    "cursor or" => 9,
+    "cursor the" => 5,
+    "cursor to" => 245,
+    "cursor when" => 23,
+    "cursor with" => 16,
+    "custom and" => 8,
+    "custom for" => 3,
+    "custom of" => 5,
+    "data a" => 10,
+    "data and" => 149,
+    "data are" => 16,
+    "data as" => 68,
+    "data at" => 21,
+    "data but" => 19,
+    "data by" => 17,
+    "data can" => 51,
+    "data file" => 27,
+    "data files" => 8,
+    "data for" => 78,
+    "data from" => 187,
+    "data if" => 12,
+    "data in" => 176,
+    "data is" => 285,
+    "data it" => 21,
+    "data not" => 3,
+    "data of" => 17,
+    "data on" => 61,
+    "data or" => 30,
+    "data that" => 110,
+    "data the" => 20,
+    "data this" => 8,
+    "data to" => 225,
+    "data type" => 72,
+    "data use" => 5,
+    "data used" => 5,
+    "data using" => 21,
+    "data was" => 38,
+    "data when" => 19,
+    "data which" => 22,
+    "data will" => 64,
+    "data with" => 57,
+    "data you" => 24,
+    "database the" => 6,
+    "date the" => 5,
+    "day a" => 5,
+    "day and" => 48,
+    "day of" => 41,
+    "day the" => 15,
+    "day to" => 26,
+    "days the" => 4,
+    "debug a" => 7,
+    "debug the" => 8,
+    "debugging the" => 10,
+    "declare the" => 29,
+    "decode the" => 23,
+    "decoding the" => 6,
+    "default a" => 19,
+    "default all" => 26,
+    "default also" => 5,
+    "default an" => 7,
+    "default and" => 85,
+    "default any" => 3,
+    "default are" => 4,
+    "default as" => 10,
+    "default be" => 4,
+    "default but" => 50,
+    "default by" => 11,
+    "default can" => 7,
+    "default code" => 12,
+    "default crate" => 9,
+    "default file" => 8,
+    "default files" => 3,
+    "default for" => 198,
+    "default from" => 6,
+    "default function" => 7,
+    "default i" => 4,
+    "default if" => 49,
+    "default in" => 50,
+    "default is" => 176,
+    "default it" => 40,
+    "default may" => 3,
+    "default no" => 12,
+    "default not" => 3,
+    "default of" => 14,
+    "default on" => 88,
+    "default one" => 7,
+    "default only" => 18,
+    "default or" => 19,
+    "default rust" => 15,
+    "default see" => 5,
+    "default set" => 32,
+    "default test" => 4,
+    "default that" => 4,
+    "default the" => 161,
+    "default this" => 66,
+    "default to" => 129,
+    "default type" => 14,
+    "default use" => 12,
+    "default value" => 244,
+    "default version" => 8,
+    "default vim" => 17,
+    "default we" => 9,
+    "default when" => 36,
+    "default which" => 13,
+    "default will" => 28,
+    "default with" => 31,
+    "default you" => 10,
+    "define a" => 264,
+    "define and" => 15,
+    "define for" => 8,
+    "define in" => 10,
+    "define is" => 4,
+    "define the" => 155,
+    "define to" => 5,
+    "defined a" => 20,
+    "defined and" => 48,
+    "defined are" => 3,
+    "defined as" => 122,
+    "defined by" => 285,
+    "defined for" => 58,
+    "defined from" => 5,
+    "defined in" => 1444,
+    "defined is" => 10,
+    "defined it" => 10,
+    "defined not" => 6,
+    "defined on" => 63,
+    "defined or" => 21,
+    "defined that" => 5,
+    "defined the" => 21,
+    "defined this" => 4,
+    "defined to" => 40,
+    "defined use" => 3,
+    "defined vim" => 4,
+    "defined when" => 9,
+    "defined with" => 77,
+    "defined you" => 3,
+    "defines a" => 105,
+    "defines the" => 205,
+    "defining the" => 57,
+    "definition and" => 22,
+    "definition of" => 128,
+    "definition the" => 5,
+    "definition to" => 10,
+    "delete a" => 62,
+    "delete and" => 8,
+    "delete is" => 3,
+    "delete the" => 220,
+    "delete to" => 4,
+    "deleted the" => 12,
+    "deleting the" => 37,
+    "dependencies and" => 119,
+    "dependencies for" => 47,
+    "dependencies in" => 79,
+    "dependencies is" => 5,
+    "dependencies it" => 5,
+    "dependencies of" => 24,
+    "dependencies that" => 38,
+    "dependencies the" => 8,
+    "dependencies this" => 4,
+    "dependencies to" => 84,
+    "dependencies with" => 17,
+    "dependencies you" => 10,
+    "dependency and" => 44,
+    "dependency for" => 36,
+    "dependency in" => 78,
+    "dependency is" => 46,
+    "dependency it" => 3,
+    "dependency of" => 42,
+    "dependency that" => 14,
+    "dependency the" => 4,
+    "dependency this" => 3,
+    "dependency to" => 113,
+    "dependency with" => 20,
+    "derive a" => 13,
+    "derive and" => 12,
+    "derive the" => 25,
+    "derive to" => 4,
+    "described the" => 9,
+    "describes the" => 113,
+    "descriptor the" => 5,
+    "design the" => 6,
+    "desired the" => 4,
+    "details and" => 46,
+    "details as" => 4,
+    "details for" => 15,
+    "details in" => 20,
+    "details of" => 104,
+    "details on" => 267,
+    "details or" => 6,
+    "details that" => 12,
+    "details the" => 7,
+    "details to" => 8,
+    "details you" => 5,
+    "detect the" => 56,
+    "detected the" => 4,
+    "detection the" => 3,
+    "detects the" => 21,
+    "determine the" => 80,
+    "determined the" => 3,
+    "developed the" => 3,
+    "developers the" => 5,
+    "development and" => 56,
+    "development in" => 9,
+    "development is" => 9,
+    "development of" => 75,
+    "development to" => 3,
+    "dialog the" => 5,
+    "dictionary the" => 4,
+    "did a" => 7,
+    "did and" => 4,
+    "did for" => 3,
+    "did in" => 24,
+    "did the" => 33,
+    "did to" => 7,
+    "differ the" => 3,
+    "difference to" => 6,
+    "different and" => 34,
+    "different files" => 11,
+    "different for" => 15,
+    "different if" => 4,
+    "different in" => 8,
+    "different on" => 6,
+    "different or" => 3,
+    "different the" => 7,
+    "different to" => 9,
+    "different use" => 12,
+    "different when" => 7,
+    "digits the" => 5,
+    "direct the" => 4,
+    "directly and" => 35,
+    "directly for" => 7,
+    "directly in" => 56,
+    "directly is" => 4,
+    "directly the" => 7,
+    "directly this" => 3,
+    "directly to" => 92,
+    "directories the" => 5,
+    "directories to" => 14,
+    "directory a" => 6,
+    "directory and" => 129,
+    "directory are" => 10,
+    "directory as" => 47,
+    "directory by" => 15,
+    "directory can" => 12,
+    "directory files" => 3,
+    "directory for" => 135,
+    "directory from" => 15,
+    "directory if" => 28,
+    "directory in" => 79,
+    "directory is" => 110,
+    "directory it" => 7,
+    "directory not" => 9,
+    "directory of" => 139,
+    "directory on" => 43,
+    "directory or" => 52,
+    "directory that" => 49,
+    "directory the" => 26,
+    "directory this" => 3,
+    "directory to" => 93,
+    "directory use" => 8,
+    "directory vim" => 4,
+    "directory when" => 26,
+    "directory will" => 21,
+    "directory with" => 58,
+    "directory you" => 17,
+    "disable a" => 7,
+    "disable the" => 156,
+    "disable to" => 3,
+    "disabled the" => 21,
+    "disabled to" => 7,
+    "display a" => 60,
+    "display and" => 19,
+    "display for" => 6,
+    "display in" => 23,
+    "display is" => 21,
+    "display of" => 22,
+    "display the" => 116,
+    "display to" => 7,
+    "distributed to" => 3,
+    "distribution the" => 3,
+    "do a" => 95,
+    "do all" => 17,
+    "do an" => 9,
+    "do and" => 32,
+    "do any" => 33,
+    "do as" => 18,
+    "do at" => 3,
+    "do but" => 13,
+    "do by" => 4,
+    "do for" => 17,
+    "do have" => 15,
+    "do i" => 70,
+    "do if" => 16,
+    "do in" => 41,
+    "do is" => 59,
+    "do it" => 175,
+    "do more" => 19,
+    "do not" => 2012,
+    "do on" => 12,
+    "do one" => 16,
+    "do or" => 7,
+    "do set" => 5,
+    "do that" => 131,
+    "do the" => 183,
+    "do this" => 413,
+    "do to" => 33,
+    "do use" => 19,
+    "do was" => 4,
+    "do when" => 15,
+    "do which" => 6,
+    "do with" => 102,
+    "do you" => 242,
+    "do your" => 16,
+    "doc and" => 8,
+    "doc for" => 6,
+    "doc if" => 62,
+    "doc of" => 7,
+    "doc the" => 3,
+    "doc to" => 4,
+    "document the" => 35,
+    "document to" => 9,
+    "documentation a" => 3,
+    "documentation and" => 96,
+    "documentation are" => 9,
+    "documentation as" => 15,
+    "documentation at" => 17,
+    "documentation build" => 14,
+    "documentation but" => 7,
+    "documentation by" => 10,
+    "documentation can" => 34,
+    "documentation crate" => 7,
+    "documentation documentation" => 48,
+    "documentation example" => 25,
+    "documentation examples" => 22,
+    "documentation file" => 4,
+    "documentation files" => 11,
+    "documentation for" => 863,
+    "documentation from" => 13,
+    "documentation has" => 5,
+    "documentation have" => 3,
+    "documentation in" => 30,
+    "documentation is" => 122,
+    "documentation license" => 94,
+    "documentation may" => 4,
+    "documentation no" => 3,
+    "documentation of" => 115,
+    "documentation on" => 101,
+    "documentation only" => 79,
+    "documentation or" => 22,
+    "documentation rust" => 16,
+    "documentation see" => 51,
+    "documentation so" => 6,
+    "documentation string" => 6,
+    "documentation test" => 7,
+    "documentation that" => 9,
+    "documentation the" => 32,
+    "documentation there" => 5,
+    "documentation this" => 3,
+    "documentation to" => 35,
+    "documentation use" => 5,
+    "documentation using" => 3,
+    "documentation version" => 12,
+    "documentation was" => 3,
+    "documentation which" => 7,
+    "documentation will" => 11,
+    "documentation with" => 16,
+    "documentation you" => 6,
+    "documents the" => 12,
+    "does a" => 39,
+    "does all" => 9,
+    "does an" => 7,
+    "does and" => 8,
+    "does at" => 3,
+    "does but" => 9,
+    "does for" => 9,
+    "does have" => 16,
+    "does in" => 15,
+    "does is" => 20,
+    "does it" => 70,
+    "does not" => 3954,
+    "does on" => 7,
+    "does or" => 3,
+    "does see" => 3,
+    "does that" => 17,
+    "does the" => 143,
+    "does this" => 68,
+    "does use" => 9,
+    "does when" => 9,
+    "does which" => 3,
+    "does with" => 11,
+    "does you" => 4,
+    "does your" => 4,
+    "doesn't it" => 4,
+    "doing a" => 40,
+    "doing and" => 6,
+    "doing in" => 5,
+    "doing the" => 42,
+    "don't be" => 25,
+    "don't use" => 212,
+    "don't you" => 54,
+    "done a" => 9,
+    "done and" => 15,
+    "done for" => 56,
+    "done in" => 89,
+    "done is" => 3,
+    "done it" => 21,
+    "done that" => 14,
+    "done the" => 14,
+    "done this" => 9,
+    "done to" => 41,
+    "done with" => 157,
+    "done you" => 6,
+    "dot the" => 3,
+    "double in" => 3,
+    "double the" => 20,
+    "down a" => 41,
+    "down and" => 464,
+    "down for" => 7,
+    "down in" => 48,
+    "down that" => 5,
+    "down the" => 209,
+    "down to" => 141,
+    "down with" => 22,
+    "download the" => 50,
+    "downloads the" => 9,
+    "drop a" => 10,
+    "drop the" => 60,
+    "drop to" => 5,
+    "dropped the" => 22,
+    "dual to" => 3,
+    "due to" => 595,
+    "duplicate the" => 5,
+    "during a" => 40,
+    "during the" => 245,
+    "each and" => 18,
+    "each as" => 3,
+    "each be" => 3,
+    "each can" => 3,
+    "each file" => 73,
+    "each for" => 4,
+    "each in" => 11,
+    "each is" => 8,
+    "each of" => 302,
+    "each patch" => 3,
+    "each rust" => 6,
+    "each to" => 6,
+    "each use" => 5,
+    "each will" => 7,
+    "each with" => 13,
+    "earlier the" => 4,
+    "easy and" => 18,
+    "easy in" => 4,
+    "easy the" => 4,
+    "easy to" => 479,
+    "ecosystem the" => 3,
+    "edit a" => 90,
+    "edit and" => 12,
+    "edit for" => 4,
+    "edit in" => 3,
+    "edit is" => 4,
+    "edit the" => 131,
+    "edit to" => 6,
+    "editing a" => 154,
+    "editing and" => 15,
+    "editing of" => 3,
+    "editing the" => 86,
+    "edition a" => 4,
+    "edition and" => 16,
+    "edition in" => 3,
+    "edition is" => 16,
+    "edition of" => 39,
+    "edition the" => 27,
+    "edition to" => 25,
+    "editor the" => 6,
+    "effect the" => 5,
+    "effect to" => 3,
+    "either a" => 118,
+    "either an" => 32,
+    "either and" => 6,
+    "either as" => 16,
+    "either be" => 49,
+    "either by" => 42,
+    "either for" => 7,
+    "either from" => 10,
+    "either if" => 7,
+    "either in" => 23,
+    "either is" => 12,
+    "either it" => 5,
+    "either of" => 1158,
+    "either on" => 15,
+    "either or" => 169,
+    "either the" => 197,
+    "either this" => 5,
+    "either to" => 16,
+    "either use" => 16,
+    "either when" => 8,
+    "either with" => 11,
+    "either you" => 5,
+    "element a" => 3,
+    "element and" => 28,
+    "element are" => 5,
+    "element as" => 8,
+    "element by" => 19,
+    "element for" => 9,
+    "element from" => 219,
+    "element if" => 22,
+    "element in" => 662,
+    "element is" => 500,
+    "element it" => 7,
+    "element not" => 4,
+    "element of" => 835,
+    "element on" => 4,
+    "element or" => 40,
+    "element that" => 30,
+    "element the" => 11,
+    "element this" => 3,
+    "element to" => 36,
+    "element will" => 8,
+    "element with" => 8,
+    "elements all" => 7,
+    "elements and" => 261,
+    "elements are" => 1366,
+    "elements as" => 11,
+    "elements at" => 26,
+    "elements but" => 7,
+    "elements by" => 10,
+    "elements can" => 4,
+    "elements for" => 47,
+    "elements from" => 576,
+    "elements have" => 7,
+    "elements i" => 4,
+    "elements in" => 1568,
+    "elements is" => 37,
+    "elements it" => 8,
+    "elements not" => 20,
+    "elements of" => 624,
+    "elements on" => 5,
+    "elements or" => 10,
+    "elements set" => 11,
+    "elements that" => 54,
+    "elements the" => 25,
+    "elements this" => 3,
+    "elements to" => 61,
+    "elements using" => 6,
+    "elements which" => 83,
+    "elements will" => 37,
+    "elements with" => 27,
+    "elements you" => 7,
+    "else a" => 4,
+    "else the" => 12,
+    "else to" => 16,
+    "emit the" => 32,
+    "emitted and" => 17,
+    "emitted the" => 11,
+    "emitted to" => 4,
+    "empty a" => 4,
+    "empty and" => 39,
+    "empty for" => 8,
+    "empty in" => 4,
+    "empty is" => 9,
+    "empty the" => 28,
+    "empty to" => 4,
+    "empty with" => 21,
+    "enable a" => 26,
+    "enable and" => 19,
+    "enable for" => 4,
+    "enable that" => 3,
+    "enable the" => 395,
+    "enable this" => 69,
+    "enable to" => 24,
+    "enable with" => 10,
+    "enabled a" => 13,
+    "enabled and" => 48,
+    "enabled as" => 10,
+    "enabled for" => 63,
+    "enabled in" => 62,
+    "enabled is" => 10,
+    "enabled it" => 23,
+    "enabled the" => 67,
+    "enabled this" => 23,
+    "enabled to" => 23,
+    "enabled with" => 71,
+    "enabled you" => 16,
+    "enables a" => 33,
+    "enables and" => 27,
+    "enables for" => 5,
+    "enables in" => 10,
+    "enables the" => 294,
+    "enables this" => 4,
+    "enables to" => 6,
+    "enabling the" => 107,
+    "encode the" => 26,
+    "encoded the" => 3,
+    "encoding a" => 11,
+    "encoding and" => 110,
+    "encoding for" => 39,
+    "encoding in" => 19,
+    "encoding is" => 144,
+    "encoding of" => 113,
+    "encoding that" => 11,
+    "encoding the" => 23,
+    "encoding to" => 70,
+    "encoding with" => 12,
+    "end a" => 11,
+    "end and" => 39,
+    "end are" => 7,
+    "end for" => 11,
+    "end if" => 3,
+    "end in" => 55,
+    "end is" => 16,
+    "end it" => 8,
+    "end of" => 1341,
+    "end or" => 7,
+    "end that" => 6,
+    "end the" => 39,
+    "end this" => 4,
+    "end to" => 43,
+    "end when" => 9,
+    "end with" => 46,
+    "end you" => 5,
+    "endian the" => 16,
+    "ends the" => 14,
+    "english the" => 3,
+    "english to" => 3,
+    "enough and" => 13,
+    "enough of" => 15,
+    "enough the" => 4,
+    "enough to" => 164,
+    "ensure a" => 17,
+    "ensure the" => 78,
+    "enter a" => 47,
+    "enter the" => 75,
+    "enter to" => 15,
+    "entered the" => 45,
+    "entries the" => 4,
+    "entries to" => 28,
+    "entry and" => 20,
+    "entry in" => 113,
+    "entry is" => 36,
+    "entry of" => 15,
+    "entry the" => 6,
+    "entry to" => 35,
+    "environment and" => 32,
+    "environment as" => 6,
+    "environment for" => 23,
+    "environment in" => 11,
+    "environment is" => 27,
+    "environment it" => 7,
+    "environment of" => 5,
+    "environment or" => 6,
+    "environment that" => 156,
+    "environment the" => 6,
+    "environment to" => 21,
+    "environment with" => 13,
+    "environment you" => 14,
+    "equal and" => 76,
+    "equal in" => 3,
+    "equal is" => 3,
+    "equal to" => 534,
+    "equivalent and" => 3,
+    "equivalent for" => 5,
+    "equivalent in" => 19,
+    "equivalent of" => 117,
+    "equivalent to" => 595,
+    "error a" => 10,
+    "error an" => 7,
+    "error and" => 79,
+    "error are" => 9,
+    "error as" => 27,
+    "error at" => 13,
+    "error be" => 3,
+    "error but" => 20,
+    "error by" => 10,
+    "error can" => 15,
+    "error code" => 83,
+    "error file" => 20,
+    "error for" => 313,
+    "error from" => 19,
+    "error function" => 5,
+    "error if" => 260,
+    "error in" => 210,
+    "error is" => 195,
+    "error it" => 7,
+    "error no" => 4,
+    "error not" => 4,
+    "error of" => 33,
+    "error on" => 40,
+    "error only" => 5,
+    "error or" => 29,
+    "error test" => 3,
+    "error that" => 55,
+    "error the" => 37,
+    "error this" => 7,
+    "error to" => 72,
+    "error type" => 102,
+    "error use" => 6,
+    "error using" => 8,
+    "error value" => 21,
+    "error was" => 13,
+    "error we" => 8,
+    "error when" => 259,
+    "error which" => 27,
+    "error will" => 71,
+    "error with" => 79,
+    "error you" => 6,
+    "errors and" => 69,
+    "errors for" => 38,
+    "errors in" => 75,
+    "errors is" => 18,
+    "errors of" => 8,
+    "errors that" => 38,
+    "errors the" => 5,
+    "errors this" => 3,
+    "errors to" => 43,
+    "errors with" => 24,
+    "errors you" => 5,
+    "esc the" => 3,
+    "escape a" => 8,
+    "escape the" => 32,
+    "escape to" => 5,
+    "especially the" => 11,
+    "etc and" => 15,
+    "etc in" => 6,
+    "etc is" => 3,
+    "even a" => 26,
+    "even and" => 4,
+    "even as" => 12,
+    "even be" => 13,
+    "even for" => 38,
+    "even if" => 504,
+    "even in" => 63,
+    "even of" => 6,
+    "even on" => 29,
+    "even that" => 6,
+    "even the" => 53,
+    "even this" => 4,
+    "even to" => 27,
+    "even with" => 22,
+    "event and" => 34,
+    "event as" => 5,
+    "event for" => 12,
+    "event in" => 14,
+    "event is" => 290,
+    "event it" => 6,
+    "event of" => 19,
+    "event that" => 17,
+    "event the" => 6,
+    "event to" => 34,
+    "event with" => 8,
+    "events and" => 46,
+    "events is" => 5,
+    "events of" => 12,
+    "events the" => 4,
+    "events to" => 16,
+    "ever a" => 4,
+    "ever and" => 10,
+    "ever of" => 3,
+    "ever to" => 8,
+    "every in" => 6,
+    "every is" => 3,
+    "everything a" => 4,
+    "everything the" => 5,
+    "everything to" => 7,
+    "ex and" => 4,
+    "ex in" => 5,
+    "ex or" => 3,
+    "exactly a" => 3,
+    "exactly and" => 3,
+    "exactly the" => 115,
+    "exactly to" => 6,
+    "example a" => 129,
+    "example add" => 5,
+    "example after" => 7,
+    "example all" => 14,
+    "example also" => 5,
+    "example an" => 21,
+    "example and" => 59,
+    "example any" => 7,
+    "example are" => 7,
+    "example as" => 17,
+    "example be" => 8,
+    "example but" => 17,
+    "example by" => 26,
+    "example can" => 45,
+    "example code" => 33,
+    "example command" => 9,
+    "example crate" => 5,
+    "example documentation" => 7,
+    "example example" => 7,
+    "example file" => 14,
+    "example files" => 3,
+    "example for" => 147,
+    "example from" => 29,
+    "example function" => 5,
+    "example has" => 11,
+    "example have" => 4,
+    "example i" => 7,
+    "example if" => 299,
+    "example in" => 137,
+    "example into" => 4,
+    "example is" => 185,
+    "example it" => 51,
+    "example license" => 46,
+    "example may" => 8,
+    "example no" => 13,
+    "example not" => 3,
+    "example of" => 457,
+    "example on" => 64,
+    "example one" => 10,
+    "example only" => 6,
+    "example or" => 32,
+    "example rust" => 5,
+    "example see" => 21,
+    "example so" => 4,
+    "example some" => 3,
+    "example test" => 7,
+    "example that" => 91,
+    "example the" => 364,
+    "example then" => 6,
+    "example there" => 19,
+    "example this" => 82,
+    "example to" => 229,
+    "example type" => 6,
+    "example use" => 25,
+    "example used" => 4,
+    "example using" => 76,
+    "example value" => 12,
+    "example version" => 3,
+    "example vim" => 3,
+    "example was" => 4,
+    "example we" => 97,
+    "example when" => 85,
+    "example which" => 24,
+    "example will" => 58,
+    "example with" => 96,
+    "example you" => 104,
+    "examples a" => 5,
+    "examples and" => 64,
+    "examples are" => 92,
+    "examples as" => 3,
+    "examples at" => 4,
+    "examples but" => 4,
+    "examples by" => 5,
+    "examples can" => 51,
+    "examples for" => 79,
+    "examples from" => 21,
+    "examples have" => 10,
+    "examples if" => 3,
+    "examples in" => 70,
+    "examples is" => 3,
+    "examples license" => 44,
+    "examples of" => 216,
+    "examples on" => 22,
+    "examples one" => 4,
+    "examples or" => 9,
+    "examples rust" => 10,
+    "examples see" => 31,
+    "examples that" => 22,
+    "examples the" => 11,
+    "examples to" => 25,
+    "examples use" => 22,
+    "examples using" => 11,
+    "examples when" => 3,
+    "examples which" => 8,
+    "examples will" => 10,
+    "examples with" => 21,
+    "examples you" => 7,
+    "except a" => 11,
+    "except and" => 18,
+    "except for" => 146,
+    "except in" => 36,
+    "except the" => 72,
+    "except to" => 9,
+    "exception and" => 18,
+    "exception for" => 22,
+    "exception in" => 10,
+    "exception is" => 138,
+    "exception of" => 76,
+    "exception the" => 12,
+    "exception to" => 31,
+    "exceptions and" => 12,
+    "exceptions for" => 7,
+    "exceptions in" => 8,
+    "exceptions that" => 7,
+    "exceptions to" => 6,
+    "exclusive the" => 3,
+    "executable the" => 3,
+    "executable to" => 22,
+    "execute a" => 64,
+    "execute and" => 11,
+    "execute in" => 22,
+    "execute is" => 10,
+    "execute the" => 134,
+    "execute to" => 6,
+    "executed and" => 32,
+    "executed the" => 14,
+    "executed to" => 6,
+    "executing the" => 71,
+    "execution and" => 26,
+    "execution in" => 12,
+    "execution is" => 9,
+    "execution of" => 138,
+    "execution the" => 4,
+    "execution to" => 3,
+    "exist the" => 13,
+    "exist to" => 12,
+    "existing and" => 14,
+    "existing in" => 7,
+    "exists a" => 9,
+    "exists and" => 44,
+    "exists the" => 17,
+    "exists to" => 40,
+    "exit a" => 4,
+    "exit the" => 39,
+    "expand the" => 31,
+    "expect the" => 20,
+    "expected a" => 5,
+    "expected and" => 9,
+    "expected the" => 3,
+    "expected to" => 198,
+    "explicit the" => 3,
+    "explicitly be" => 3,
+    "explicitly in" => 4,
+    "explicitly this" => 5,
+    "explicitly to" => 8,
+    "explicitly with" => 13,
+    "export the" => 27,
+    "exported the" => 4,
+    "exports the" => 31,
+    "expose the" => 40,
+    "exposes the" => 43,
+    "expression a" => 5,
+    "expression and" => 53,
+    "expression are" => 11,
+    "expression as" => 25,
+    "expression can" => 8,
+    "expression for" => 27,
+    "expression if" => 7,
+    "expression in" => 74,
+    "expression is" => 178,
+    "expression it" => 5,
+    "expression not" => 9,
+    "expression of" => 64,
+    "expression on" => 7,
+    "expression or" => 28,
+    "expression that" => 53,
+    "expression the" => 24,
+    "expression this" => 3,
+    "expression to" => 47,
+    "expression when" => 5,
+    "expression with" => 22,
+    "expression you" => 7,
+    "expressions and" => 56,
+    "expressions for" => 10,
+    "expressions in" => 25,
+    "expressions is" => 10,
+    "expressions of" => 9,
+    "expressions the" => 3,
+    "expressions to" => 6,
+    "extend the" => 58,
+    "extension and" => 19,
+    "extension of" => 28,
+    "extension the" => 7,
+    "extension to" => 26,
+    "external and" => 5,
+    "external to" => 8,
+    "extra for" => 3,
+    "extra is" => 5,
+    "face the" => 8,
+    "fact the" => 11,
+    "fail and" => 25,
+    "fail in" => 24,
+    "fail the" => 20,
+    "fail to" => 89,
+    "failed the" => 3,
+    "fails a" => 8,
+    "fails and" => 17,
+    "fails for" => 24,
+    "fails in" => 59,
+    "fails the" => 18,
+    "fails to" => 83,
+    "fails with" => 108,
+    "failure the" => 5,
+    "failure to" => 23,
+    "false the" => 7,
+    "far the" => 23,
+    "far to" => 4,
+    "fast and" => 87,
+    "fast in" => 5,
+    "fast the" => 5,
+    "fast to" => 15,
+    "faster to" => 13,
+    "feature a" => 4,
+    "feature and" => 104,
+    "feature as" => 16,
+    "feature at" => 10,
+    "feature but" => 15,
+    "feature by" => 11,
+    "feature can" => 53,
+    "feature file" => 3,
+    "feature for" => 65,
+    "feature from" => 13,
+    "feature if" => 22,
+    "feature in" => 114,
+    "feature is" => 508,
+    "feature it" => 13,
+    "feature not" => 13,
+    "feature of" => 109,
+    "feature on" => 32,
+    "feature or" => 34,
+    "feature that" => 64,
+    "feature the" => 37,
+    "feature this" => 10,
+    "feature to" => 164,
+    "feature use" => 10,
+    "feature using" => 4,
+    "feature was" => 24,
+    "feature when" => 14,
+    "feature which" => 66,
+    "feature will" => 54,
+    "feature with" => 21,
+    "feature you" => 40,
+    "features a" => 14,
+    "features an" => 6,
+    "features and" => 162,
+    "features are" => 328,
+    "features as" => 23,
+    "features at" => 15,
+    "features by" => 16,
+    "features can" => 48,
+    "features for" => 98,
+    "features from" => 26,
+    "features i" => 3,
+    "features if" => 8,
+    "features in" => 85,
+    "features is" => 13,
+    "features it" => 8,
+    "features not" => 8,
+    "features of" => 194,
+    "features on" => 31,
+    "features or" => 42,
+    "features rust" => 8,
+    "features that" => 147,
+    "features the" => 26,
+    "features this" => 9,
+    "features to" => 132,
+    "features use" => 15,
+    "features using" => 9,
+    "features when" => 7,
+    "features which" => 30,
+    "features will" => 23,
+    "features with" => 18,
+    "features you" => 37,
+    "feel the" => 16,
+    "felt the" => 19,
+    "fetch the" => 28,
+    "few and" => 6,
+    "few of" => 26,
+    "field and" => 39,
+    "field for" => 35,
+    "field in" => 95,
+    "field is" => 122,
+    "field it" => 7,
+    "field of" => 142,
+    "field that" => 20,
+    "field the" => 11,
+    "field this" => 7,
+    "field to" => 68,
+    "field with" => 40,
+    "field you" => 3,
+    "fields and" => 40,
+    "fields for" => 14,
+    "fields in" => 69,
+    "fields is" => 24,
+    "fields of" => 111,
+    "fields the" => 6,
+    "fields to" => 27,
+    "file a" => 56,
+    "file add" => 9,
+    "file after" => 17,
+    "file also" => 7,
+    "file an" => 62,
+    "file and" => 391,
+    "file are" => 33,
+    "file as" => 71,
+    "file at" => 51,
+    "file but" => 41,
+    "file by" => 34,
+    "file can" => 67,
+    "file command" => 10,
+    "file data" => 14,
+    "file default" => 4,
+    "file do" => 5,
+    "file does" => 38,
+    "file error" => 5,
+    "file file" => 18,
+    "file files" => 3,
+    "file first" => 7,
+    "file for" => 267,
+    "file from" => 92,
+    "file function" => 4,
+    "file has" => 73,
+    "file i" => 19,
+    "file if" => 67,
+    "file in" => 424,
+    "file into" => 46,
+    "file is" => 640,
+    "file it" => 39,
+    "file like" => 30,
+    "file line" => 5,
+    "file list" => 36,
+    "file make" => 5,
+    "file may" => 44,
+    "file must" => 21,
+    "file name" => 654,
+    "file new" => 5,
+    "file no" => 8,
+    "file not" => 31,
+    "file number" => 10,
+    "file of" => 30,
+    "file on" => 64,
+    "file one" => 7,
+    "file only" => 22,
+    "file or" => 168,
+    "file out" => 5,
+    "file patch" => 3,
+    "file same" => 3,
+    "file see" => 19,
+    "file set" => 6,
+    "file should" => 25,
+    "file so" => 16,
+    "file some" => 3,
+    "file support" => 4,
+    "file test" => 10,
+    "file that" => 163,
+    "file the" => 98,
+    "file then" => 19,
+    "file there" => 6,
+    "file they" => 6,
+    "file this" => 24,
+    "file to" => 272,
+    "file two" => 3,
+    "file type" => 98,
+    "file types" => 34,
+    "file use" => 19,
+    "file used" => 18,
+    "file using" => 25,
+    "file version" => 5,
+    "file vim" => 31,
+    "file was" => 108,
+    "file we" => 12,
+    "file when" => 83,
+    "file where" => 27,
+    "file which" => 85,
+    "file will" => 131,
+    "file with" => 245,
+    "file without" => 36,
+    "file you" => 118,
+    "file your" => 4,
+    "filename and" => 9,
+    "filename in" => 5,
+    "filename is" => 30,
+    "filename of" => 20,
+    "filename the" => 4,
+    "filename to" => 11,
+    "files a" => 9,
+    "files add" => 4,
+    "files after" => 4,
+    "files all" => 4,
+    "files also" => 7,
+    "files and" => 223,
+    "files are" => 325,
+    "files as" => 50,
+    "files at" => 11,
+    "files available" => 3,
+    "files be" => 3,
+    "files because" => 3,
+    "files before" => 7,
+    "files but" => 15,
+    "files by" => 22,
+    "files can" => 30,
+    "files change" => 4,
+    "files could" => 6,
+    "files default" => 3,
+    "files do" => 10,
+    "files does" => 8,
+    "files doesn't" => 3,
+    "files each" => 4,
+    "files end" => 5,
+    "files files" => 3,
+    "files for" => 103,
+    "files from" => 56,
+    "files has" => 4,
+    "files have" => 25,
+    "files if" => 12,
+    "files in" => 254,
+    "files instead" => 6,
+    "files into" => 19,
+    "files is" => 31,
+    "files it" => 20,
+    "files like" => 16,
+    "files may" => 20,
+    "files must" => 7,
+    "files no" => 7,
+    "files not" => 21,
+    "files now" => 7,
+    "files of" => 22,
+    "files on" => 55,
+    "files one" => 6,
+    "files only" => 7,
+    "files or" => 68,
+    "files over" => 7,
+    "files read" => 3,
+    "files see" => 8,
+    "files set" => 4,
+    "files should" => 5,
+    "files so" => 4,
+    "files such" => 14,
+    "files support" => 5,
+    "files that" => 126,
+    "files the" => 28,
+    "files then" => 5,
+    "files there" => 10,
+    "files they" => 9,
+    "files this" => 9,
+    "files to" => 98,
+    "files under" => 12,
+    "files use" => 26,
+    "files used" => 9,
+    "files using" => 26,
+    "files vim" => 3,
+    "files was" => 3,
+    "files were" => 22,
+    "files when" => 23,
+    "files where" => 8,
+    "files which" => 23,
+    "files while" => 3,
+    "files will" => 39,
+    "files with" => 95,
+    "files without" => 12,
+    "files would" => 7,
+    "files you" => 45,
+    "filled the" => 10,
+    "filter the" => 26,
+    "finally the" => 34,
+    "find a" => 199,
+    "find and" => 27,
+    "find in" => 11,
+    "find is" => 3,
+    "find it" => 102,
+    "find that" => 57,
+    "find the" => 331,
+    "find this" => 29,
+    "find with" => 3,
+    "find you" => 8,
+    "finding the" => 46,
+    "finished the" => 14,
+    "first a" => 11,
+    "first an" => 3,
+    "first and" => 102,
+    "first at" => 3,
+    "first be" => 11,
+    "first by" => 7,
+    "first file" => 30,
+    "first i" => 17,
+    "first if" => 10,
+    "first in" => 25,
+    "first is" => 46,
+    "first it" => 17,
+    "first of" => 49,
+    "first on" => 8,
+    "first or" => 15,
+    "first problem" => 5,
+    "first rust" => 6,
+    "first that" => 16,
+    "first the" => 28,
+    "first to" => 31,
+    "first type" => 8,
+    "first use" => 23,
+    "first using" => 3,
+    "first vim" => 5,
+    "first which" => 7,
+    "first will" => 18,
+    "first with" => 11,
+    "first you" => 65,
+    "fit the" => 19,
+    "fix a" => 52,
+    "fix and" => 14,
+    "fix for" => 30,
+    "fix in" => 12,
+    "fix is" => 12,
+    "fix that" => 41,
+    "fix the" => 197,
+    "fix this" => 60,
+    "fix to" => 9,
+    "fix with" => 4,
+    "fixed a" => 35,
+    "fixed and" => 14,
+    "fixed for" => 13,
+    "fixed in" => 17,
+    "fixed the" => 20,
+    "fixed to" => 16,
+    "fixes the" => 10,
+    "flag and" => 50,
+    "flag are" => 7,
+    "flag as" => 7,
+    "flag can" => 37,
+    "flag for" => 64,
+    "flag if" => 23,
+    "flag in" => 130,
+    "flag is" => 182,
+    "flag it" => 8,
+    "flag of" => 13,
+    "flag on" => 15,
+    "flag or" => 24,
+    "flag that" => 34,
+    "flag the" => 7,
+    "flag this" => 3,
+    "flag to" => 270,
+    "flag when" => 27,
+    "flag with" => 19,
+    "flags a" => 5,
+    "flags and" => 32,
+    "flags for" => 43,
+    "flags in" => 33,
+    "flags is" => 5,
+    "flags of" => 19,
+    "flags to" => 122,
+    "float to" => 15,
+    "focus the" => 5,
+    "fold the" => 4,
+    "folding the" => 4,
+    "folds the" => 3,
+    "follow a" => 20,
+    "follow the" => 222,
+    "follow to" => 4,
+    "followed a" => 7,
+    "followed and" => 10,
+    "followed the" => 21,
+    "followed to" => 3,
+    "following a" => 17,
+    "following an" => 3,
+    "following are" => 43,
+    "following as" => 5,
+    "following can" => 3,
+    "following files" => 10,
+    "following for" => 6,
+    "following from" => 4,
+    "following in" => 47,
+    "following is" => 96,
+    "following it" => 8,
+    "following that" => 9,
+    "following the" => 130,
+    "following this" => 7,
+    "following to" => 253,
+    "following vim" => 3,
+    "following will" => 20,
+    "follows a" => 12,
+    "follows the" => 111,
+    "font the" => 7,
+    "font to" => 11,
+    "for a" => 3095,
+    "for about" => 10,
+    "for above" => 3,
+    "for access" => 13,
+    "for add" => 5,
+    "for added" => 8,
+    "for adding" => 37,
+    "for additional" => 52,
+    "for after" => 5,
+    "for all" => 727,
+    "for already" => 6,
+    "for also" => 3,
+    "for an" => 596,
+    "for and" => 346,
+    "for another" => 57,
+    "for any" => 357,
+    "for apache" => 6,
+    "for application" => 16,
+    "for are" => 9,
+    "for argument" => 21,
+    "for arguments" => 10,
+    "for array" => 6,
+    "for as" => 55,
+    "for associated" => 9,
+    "for at" => 58,
+    "for attribute" => 6,
+    "for attributes" => 15,
+    "for automatically" => 11,
+    "for available" => 16,
+    "for base" => 22,
+    "for based" => 8,
+    "for basic" => 16,
+    "for be" => 3,
+    "for because" => 10,
+    "for being" => 29,
+    "for better" => 37,
+    "for binary" => 46,
+    "for bindings" => 6,
+    "for bit" => 53,
+    "for block" => 6,
+    "for boolean" => 7,
+    "for both" => 153,
+    "for buffer" => 28,
+    "for build" => 45,
+    "for building" => 136,
+    "for built" => 11,
+    "for but" => 22,
+    "for by" => 22,
+    "for byte" => 32,
+    "for bytes" => 20,
+    "for calling" => 21,
+    "for calls" => 14,
+    "for can" => 3,
+    "for cargo" => 35,
+    "for case" => 11,
+    "for cases" => 25,
+    "for change" => 9,
+    "for changed" => 10,
+    "for changes" => 23,
+    "for channel" => 9,
+    "for character" => 20,
+    "for characters" => 14,
+    "for check" => 3,
+    "for class" => 7,
+    "for client" => 19,
+    "for code" => 51,
+    "for color" => 6,
+    "for command" => 50,
+    "for commands" => 25,
+    "for common" => 37,
+    "for compatible" => 4,
+    "for compile" => 10,
+    "for compiler" => 8,
+    "for configuration" => 17,
+    "for configure" => 9,
+    "for contains" => 4,
+    "for context" => 10,
+    "for control" => 4,
+    "for copy" => 11,
+    "for crate" => 16,
+    "for crates" => 41,
+    "for current" => 18,
+    "for cursor" => 13,
+    "for custom" => 41,
+    "for data" => 50,
+    "for default" => 9,
+    "for define" => 3,
+    "for defined" => 4,
+    "for dependencies" => 24,
+    "for dependency" => 5,
+    "for details" => 633,
+    "for different" => 106,
+    "for directory" => 12,
+    "for display" => 20,
+    "for do" => 4,
+    "for doc" => 3,
+    "for documentation" => 53,
+    "for does" => 23,
+    "for double" => 13,
+    "for each" => 706,
+    "for either" => 21,
+    "for element" => 4,
+    "for elements" => 6,
+    "for empty" => 25,
+    "for enables" => 4,
+    "for encoding" => 55,
+    "for end" => 20,
+    "for environment" => 7,
+    "for equal" => 3,
+    "for error" => 49,
+    "for errors" => 32,
+    "for etc" => 5,
+    "for even" => 17,
+    "for event" => 15,
+    "for every" => 148,
+    "for ex" => 15,
+    "for example" => 3060,
+    "for examples" => 92,
+    "for exception" => 4,
+    "for existing" => 22,
+    "for explicitly" => 5,
+    "for expression" => 3,
+    "for expressions" => 11,
+    "for extra" => 17,
+    "for feature" => 5,
+    "for features" => 17,
+    "for field" => 10,
+    "for fields" => 8,
+    "for file" => 51,
+    "for files" => 56,
+    "for find" => 3,
+    "for first" => 10,
+    "for fix" => 4,
+    "for fixed" => 8,
+    "for flag" => 3,
+    "for flags" => 7,
+    "for floating" => 48,
+    "for following" => 10,
+    "for for" => 47,
+    "for format" => 7,
+    "for free" => 18,
+    "for from" => 13,
+    "for full" => 44,
+    "for function" => 64,
+    "for functionality" => 4,
+    "for functions" => 57,
+    "for future" => 60,
+    "for generated" => 7,
+    "for generic" => 44,
+    "for get" => 8,
+    "for git" => 14,
+    "for given" => 16,
+    "for global" => 23,
+    "for go" => 7,
+    "for good" => 11,
+    "for group" => 5,
+    "for half" => 16,
+    "for has" => 13,
+    "for he" => 69,
+    "for help" => 37,
+    "for her" => 41,
+    "for high" => 22,
+    "for him" => 54,
+    "for his" => 66,
+    "for how" => 103,
+    "for i" => 103,
+    "for if" => 26,
+    "for image" => 10,
+    "for implementation" => 13,
+    "for implementations" => 14,
+    "for implements" => 3,
+    "for in" => 87,
+    "for include" => 8,
+    "for included" => 3,
+    "for including" => 15,
+    "for inclusion" => 1151,
+    "for index" => 3,
+    "for information" => 100,
+    "for input" => 33,
+    "for insert" => 55,
+    "for install" => 3,
+    "for instance" => 386,
+    "for instead" => 9,
+    "for integer" => 23,
+    "for integers" => 13,
+    "for is" => 78,
+    "for it" => 285,
+    "for it's" => 5,
+    "for items" => 23,
+    "for its" => 98,
+    "for itself" => 8,
+    "for just" => 9,
+    "for key" => 25,
+    "for keys" => 15,
+    "for language" => 6,
+    "for last" => 8,
+    "for latest" => 4,
+    "for length" => 7,
+    "for less" => 60,
+    "for letter" => 3,
+    "for level" => 3,
+    "for libraries" => 32,
+    "for library" => 9,
+    "for license" => 5,
+    "for lifetime" => 3,
+    "for like" => 4,
+    "for line" => 21,
+    "for lines" => 20,
+    "for link" => 5,
+    "for list" => 14,
+    "for listing" => 10,
+    "for literal" => 6,
+    "for little" => 14,
+    "for local" => 38,
+    "for long" => 28,
+    "for longer" => 11,
+    "for loop" => 51,
+    "for lower" => 5,
+    "for macro" => 5,
+    "for macros" => 10,
+    "for main" => 3,
+    "for make" => 12,
+    "for many" => 117,
+    "for map" => 7,
+    "for match" => 6,
+    "for matches" => 13,
+    "for may" => 18,
+    "for me" => 97,
+    "for memory" => 35,
+    "for menu" => 6,
+    "for message" => 13,
+    "for messages" => 24,
+    "for method" => 14,
+    "for methods" => 7,
+    "for missing" => 68,
+    "for mode" => 4,
+    "for module" => 4,
+    "for more" => 2251,
+    "for most" => 131,
+    "for mouse" => 17,
+    "for much" => 5,
+    "for multiple" => 61,
+    "for must" => 5,
+    "for mutable" => 8,
+    "for my" => 63,
+    "for name" => 12,
+    "for native" => 13,
+    "for new" => 85,
+    "for next" => 7,
+    "for no" => 44,
+    "for node" => 37,
+    "for non" => 125,
+    "for normal" => 48,
+    "for not" => 101,
+    "for note" => 4,
+    "for now" => 157,
+    "for null" => 101,
+    "for number" => 14,
+    "for numbers" => 20,
+    "for object" => 12,
+    "for objects" => 24,
+    "for of" => 8,
+    "for old" => 16,
+    "for on" => 25,
+    "for once" => 3,
+    "for one" => 146,
+    "for only" => 27,
+    "for open" => 7,
+    "for operations" => 17,
+    "for option" => 15,
+    "for optional" => 4,
+    "for options" => 14,
+    "for or" => 47,
+    "for other" => 222,
+    "for otherwise" => 4,
+    "for our" => 59,
+    "for out" => 18,
+    "for output" => 8,
+    "for over" => 8,
+    "for package" => 10,
+    "for packed" => 19,
+    "for panic" => 3,
+    "for panics" => 8,
+    "for parameters" => 3,
+    "for part" => 5,
+    "for patch" => 10,
+    "for path" => 14,
+    "for pattern" => 21,
+    "for patterns" => 7,
+    "for performance" => 49,
+    "for platform" => 8,
+    "for platforms" => 9,
+    "for point" => 3,
+    "for pointer" => 18,
+    "for popup" => 23,
+    "for possible" => 19,
+    "for previous" => 4,
+    "for process" => 4,
+    "for program" => 7,
+    "for project" => 35,
+    "for property" => 8,
+    "for public" => 21,
+    "for python" => 59,
+    "for range" => 3,
+    "for raw" => 30,
+    "for read" => 18,
+    "for reference" => 25,
+    "for references" => 13,
+    "for release" => 11,
+    "for request" => 5,
+    "for requires" => 3,
+    "for return" => 7,
+    "for right" => 10,
+    "for run" => 3,
+    "for running" => 65,
+    "for runtime" => 11,
+    "for rust" => 531,
+    "for safe" => 18,
+    "for safety" => 28,
+    "for screen" => 4,
+    "for script" => 17,
+    "for search" => 14,
+    "for section" => 3,
+    "for see" => 7,
+    "for server" => 9,
+    "for set" => 6,
+    "for sets" => 7,
+    "for setting" => 48,
+    "for several" => 59,
+    "for she" => 24,
+    "for sign" => 8,
+    "for signed" => 49,
+    "for similar" => 5,
+    "for simple" => 37,
+    "for since" => 3,
+    "for single" => 34,
+    "for size" => 20,
+    "for slice" => 10,
+    "for small" => 30,
+    "for so" => 21,
+    "for some" => 384,
+    "for something" => 31,
+    "for source" => 6,
+    "for space" => 8,
+    "for special" => 35,
+    "for specific" => 72,
+    "for stable" => 3,
+    "for stack" => 6,
+    "for standard" => 12,
+    "for start" => 5,
+    "for starting" => 11,
+    "for state" => 8,
+    "for static" => 20,
+    "for status" => 3,
+    "for stream" => 10,
+    "for string" => 52,
+    "for strings" => 36,
+    "for style" => 3,
+    "for such" => 56,
+    "for support" => 25,
+    "for supported" => 5,
+    "for syntax" => 44,
+    "for system" => 12,
+    "for systems" => 19,
+    "for tab" => 11,
+    "for table" => 3,
+    "for tag" => 6,
+    "for target" => 10,
+    "for targets" => 37,
+    "for terminal" => 42,
+    "for test" => 24,
+    "for testing" => 139,
+    "for tests" => 36,
+    "for text" => 65,
+    "for that" => 297,
+    "for the" => 6412,
+    "for their" => 98,
+    "for them" => 73,
+    "for then" => 4,
+    "for there" => 19,
+    "for these" => 130,
+    "for they" => 23,
+    "for things" => 31,
+    "for this" => 1010,
+    "for those" => 109,
+    "for thread" => 10,
+    "for three" => 18,
+    "for time" => 18,
+    "for to" => 90,
+    "for tom" => 6,
+    "for too" => 5,
+    "for trait" => 22,
+    "for traits" => 11,
+    "for two" => 77,
+    "for type" => 56,
+    "for types" => 143,
+    "for unix" => 100,
+    "for unless" => 4,
+    "for unsafe" => 7,
+    "for unsigned" => 21,
+    "for up" => 7,
+    "for us" => 87,
+    "for usage" => 50,
+    "for use" => 270,
+    "for useful" => 3,
+    "for user" => 66,
+    "for users" => 52,
+    "for uses" => 3,
+    "for using" => 269,
+    "for valid" => 17,
+    "for value" => 9,
+    "for values" => 34,
+    "for variable" => 21,
+    "for variables" => 13,
+    "for various" => 68,
+    "for vector" => 5,
+    "for version" => 20,
+    "for versions" => 14,
+    "for very" => 60,
+    "for vim" => 269,
+    "for visual" => 42,
+    "for want" => 3,
+    "for was" => 3,
+    "for we" => 15,
+    "for well" => 6,
+    "for what" => 82,
+    "for when" => 106,
+    "for where" => 22,
+    "for whether" => 9,
+    "for which" => 240,
+    "for while" => 4,
+    "for why" => 14,
+    "for will" => 9,
+    "for win" => 62,
+    "for window" => 11,
+    "for windows" => 108,
+    "for with" => 33,
+    "for without" => 5,
+    "for word" => 6,
+    "for words" => 13,
+    "for work" => 6,
+    "for working" => 107,
+    "for write" => 8,
+    "for writing" => 139,
+    "for wrong" => 22,
+    "for yet" => 4,
+    "for you" => 363,
+    "for your" => 423,
+    "for zero" => 53,
+    "force the" => 35,
+    "fork the" => 18,
+    "form a" => 55,
+    "form and" => 21,
+    "form for" => 9,
+    "form in" => 12,
+    "form is" => 46,
+    "form of" => 238,
+    "form the" => 19,
+    "form to" => 11,
+    "format a" => 30,
+    "format and" => 50,
+    "format as" => 21,
+    "format for" => 70,
+    "format in" => 23,
+    "format is" => 110,
+    "format it" => 4,
+    "format not" => 3,
+    "format of" => 110,
+    "format that" => 31,
+    "format the" => 42,
+    "format to" => 30,
+    "format with" => 15,
+    "format you" => 14,
+    "formats the" => 17,
+    "formatting a" => 9,
+    "formatting the" => 8,
+    "formatting to" => 5,
+    "forward the" => 14,
+    "forward to" => 53,
+    "found a" => 57,
+    "found and" => 23,
+    "found for" => 10,
+    "found in" => 435,
+    "found is" => 7,
+    "found it" => 44,
+    "found that" => 69,
+    "found the" => 65,
+    "found this" => 9,
+    "found to" => 31,
+    "found with" => 25,
+    "found you" => 6,
+    "four to" => 5,
+    "free a" => 10,
+    "free and" => 59,
+    "free for" => 4,
+    "free in" => 3,
+    "free of" => 25,
+    "free the" => 127,
+    "free to" => 228,
+    "from a" => 2138,
+    "from about" => 4,
+    "from after" => 5,
+    "from all" => 49,
+    "from an" => 251,
+    "from and" => 207,
+    "from any" => 120,
+    "from are" => 16,
+    "from as" => 11,
+    "from at" => 28,
+    "from because" => 5,
+    "from before" => 20,
+    "from bit" => 25,
+    "from buffer" => 11,
+    "from build" => 8,
+    "from but" => 8,
+    "from by" => 4,
+    "from can" => 7,
+    "from cargo" => 20,
+    "from code" => 7,
+    "from command" => 11,
+    "from crate" => 19,
+    "from crates" => 58,
+    "from data" => 5,
+    "from default" => 4,
+    "from do" => 5,
+    "from documentation" => 6,
+    "from each" => 41,
+    "from either" => 16,
+    "from error" => 4,
+    "from example" => 3,
+    "from examples" => 8,
+    "from feature" => 3,
+    "from file" => 21,
+    "from files" => 17,
+    "from for" => 10,
+    "from from" => 4,
+    "from function" => 9,
+    "from functions" => 11,
+    "from his" => 117,
+    "from how" => 10,
+    "from i" => 9,
+    "from if" => 15,
+    "from in" => 29,
+    "from into" => 47,
+    "from is" => 37,
+    "from it" => 85,
+    "from its" => 118,
+    "from library" => 4,
+    "from line" => 8,
+    "from list" => 7,
+    "from make" => 6,
+    "from may" => 5,
+    "from memory" => 167,
+    "from mode" => 3,
+    "from more" => 5,
+    "from must" => 3,
+    "from my" => 74,
+    "from new" => 23,
+    "from no" => 5,
+    "from not" => 4,
+    "from now" => 10,
+    "from number" => 10,
+    "from object" => 5,
+    "from on" => 10,
+    "from one" => 238,
+    "from only" => 8,
+    "from or" => 64,
+    "from other" => 120,
+    "from out" => 12,
+    "from packed" => 66,
+    "from project" => 6,
+    "from result" => 3,
+    "from rust" => 95,
+    "from script" => 6,
+    "from see" => 4,
+    "from should" => 4,
+    "from so" => 8,
+    "from some" => 25,
+    "from string" => 10,
+    "from such" => 25,
+    "from support" => 3,
+    "from test" => 10,
+    "from tests" => 4,
+    "from text" => 9,
+    "from that" => 104,
+    "from the" => 4170,
+    "from them" => 14,
+    "from then" => 3,
+    "from there" => 35,
+    "from these" => 26,
+    "from this" => 179,
+    "from time" => 36,
+    "from to" => 320,
+    "from trait" => 7,
+    "from two" => 17,
+    "from type" => 5,
+    "from types" => 4,
+    "from under" => 15,
+    "from up" => 3,
+    "from use" => 7,
+    "from using" => 35,
+    "from values" => 4,
+    "from version" => 19,
+    "from vim" => 37,
+    "from we" => 4,
+    "from what" => 44,
+    "from when" => 17,
+    "from where" => 13,
+    "from which" => 119,
+    "from will" => 12,
+    "from window" => 7,
+    "from windows" => 11,
+    "from with" => 18,
+    "from without" => 9,
+    "from you" => 23,
+    "from your" => 114,
+    "front the" => 5,
+    "full and" => 12,
+    "full in" => 5,
+    "full is" => 3,
+    "full of" => 71,
+    "function a" => 58,
+    "function add" => 4,
+    "function after" => 9,
+    "function all" => 3,
+    "function also" => 5,
+    "function an" => 9,
+    "function and" => 184,
+    "function any" => 7,
+    "function are" => 37,
+    "function as" => 94,
+    "function at" => 12,
+    "function but" => 20,
+    "function by" => 22,
+    "function can" => 135,
+    "function default" => 3,
+    "function do" => 7,
+    "function does" => 59,
+    "function error" => 70,
+    "function example" => 3,
+    "function for" => 120,
+    "function from" => 66,
+    "function function" => 31,
+    "function has" => 58,
+    "function have" => 3,
+    "function i" => 5,
+    "function if" => 17,
+    "function in" => 175,
+    "function into" => 11,
+    "function is" => 820,
+    "function it" => 28,
+    "function like" => 47,
+    "function line" => 5,
+    "function may" => 67,
+    "function name" => 179,
+    "function no" => 3,
+    "function not" => 15,
+    "function of" => 40,
+    "function on" => 92,
+    "function only" => 14,
+    "function or" => 135,
+    "function see" => 5,
+    "function so" => 33,
+    "function string" => 4,
+    "function support" => 3,
+    "function test" => 10,
+    "function that" => 352,
+    "function the" => 109,
+    "function then" => 13,
+    "function this" => 18,
+    "function to" => 403,
+    "function type" => 16,
+    "function types" => 7,
+    "function use" => 12,
+    "function used" => 31,
+    "function using" => 24,
+    "function value" => 3,
+    "function version" => 3,
+    "function was" => 43,
+    "function we" => 34,
+    "function when" => 42,
+    "function which" => 133,
+    "function will" => 419,
+    "function with" => 191,
+    "function you" => 30,
+    "functionality a" => 3,
+    "functionality and" => 37,
+    "functionality for" => 56,
+    "functionality in" => 38,
+    "functionality is" => 79,
+    "functionality of" => 48,
+    "functionality the" => 3,
+    "functionality to" => 62,
+    "functions a" => 6,
+    "functions and" => 243,
+    "functions are" => 211,
+    "functions as" => 46,
+    "functions by" => 10,
+    "functions can" => 99,
+    "functions for" => 140,
+    "functions from" => 45,
+    "functions if" => 7,
+    "functions in" => 144,
+    "functions is" => 18,
+    "functions it" => 3,
+    "functions not" => 9,
+    "functions of" => 16,
+    "functions on" => 29,
+    "functions or" => 47,
+    "functions that" => 247,
+    "functions the" => 15,
+    "functions to" => 172,
+    "functions use" => 9,
+    "functions using" => 13,
+    "functions when" => 18,
+    "functions will" => 26,
+    "functions with" => 71,
+    "functions you" => 12,
+    "further the" => 8,
+    "further to" => 6,
+    "future a" => 5,
+    "future and" => 17,
+    "future for" => 6,
+    "future in" => 15,
+    "future is" => 30,
+    "future of" => 4,
+    "future that" => 17,
+    "future the" => 8,
+    "future this" => 5,
+    "future to" => 26,
+    "gave the" => 16,
+    "general a" => 12,
+    "general and" => 6,
+    "general in" => 3,
+    "general is" => 6,
+    "general of" => 3,
+    "general the" => 12,
+    "general to" => 3,
+    "generally a" => 18,
+    "generally the" => 12,
+    "generate a" => 181,
+    "generate and" => 28,
+    "generate for" => 3,
+    "generate is" => 4,
+    "generate the" => 133,
+    "generated a" => 7,
+    "generated and" => 39,
+    "generated for" => 52,
+    "generated in" => 32,
+    "generated is" => 3,
+    "generated that" => 3,
+    "generated the" => 15,
+    "generated to" => 4,
+    "generated with" => 45,
+    "generates the" => 62,
+    "generating the" => 43,
+    "generic and" => 16,
+    "generic to" => 4,
+    "get a" => 407,
+    "get an" => 150,
+    "get and" => 23,
+    "get as" => 6,
+    "get by" => 14,
+    "get for" => 3,
+    "get from" => 20,
+    "get if" => 3,
+    "get in" => 39,
+    "get is" => 4,
+    "get it" => 29,
+    "get on" => 21,
+    "get or" => 6,
+    "get that" => 7,
+    "get the" => 617,
+    "get this" => 59,
+    "get to" => 57,
+    "get vim" => 3,
+    "get when" => 8,
+    "get you" => 36,
+    "gets a" => 68,
+    "gets the" => 93,
+    "gets to" => 6,
+    "getting a" => 65,
+    "getting and" => 5,
+    "getting the" => 86,
+    "getting to" => 4,
+    "git a" => 4,
+    "git and" => 23,
+    "git for" => 4,
+    "git in" => 4,
+    "git is" => 30,
+    "git that" => 12,
+    "git this" => 3,
+    "git to" => 37,
+    "give a" => 146,
+    "give the" => 117,
+    "give to" => 9,
+    "given a" => 87,
+    "given and" => 38,
+    "given as" => 27,
+    "given for" => 14,
+    "given if" => 5,
+    "given in" => 81,
+    "given is" => 27,
+    "given it" => 9,
+    "given on" => 5,
+    "given or" => 7,
+    "given that" => 30,
+    "given the" => 134,
+    "given this" => 13,
+    "given to" => 98,
+    "given with" => 24,
+    "given you" => 6,
+    "gives the" => 39,
+    "gives to" => 3,
+    "global and" => 18,
+    "global in" => 4,
+    "global is" => 9,
+    "go a" => 9,
+    "go and" => 41,
+    "go for" => 19,
+    "go in" => 34,
+    "go of" => 5,
+    "go the" => 4,
+    "go this" => 3,
+    "go to" => 450,
+    "go with" => 23,
+    "going and" => 6,
+    "going the" => 4,
+    "going to" => 328,
+    "good a" => 6,
+    "good and" => 16,
+    "good for" => 32,
+    "good in" => 6,
+    "good of" => 5,
+    "good to" => 22,
+    "got a" => 36,
+    "got the" => 33,
+    "got to" => 90,
+    "great a" => 4,
+    "great to" => 7,
+    "group and" => 27,
+    "group for" => 13,
+    "group in" => 6,
+    "group is" => 25,
+    "group of" => 69,
+    "group the" => 10,
+    "group to" => 20,
+    "guarantee the" => 11,
+    "guaranteed to" => 344,
+    "guarantees the" => 6,
+    "guard the" => 9,
+    "guide the" => 7,
+    "guide to" => 36,
+    "had a" => 172,
+    "had an" => 43,
+    "had and" => 3,
+    "had as" => 11,
+    "had by" => 3,
+    "had for" => 7,
+    "had in" => 17,
+    "had it" => 13,
+    "had not" => 132,
+    "had of" => 5,
+    "had on" => 10,
+    "had that" => 4,
+    "had the" => 72,
+    "had this" => 14,
+    "had to" => 126,
+    "had when" => 5,
+    "had with" => 3,
+    "had you" => 3,
+    "half a" => 66,
+    "half is" => 6,
+    "half of" => 166,
+    "half the" => 22,
+    "half to" => 4,
+    "hand a" => 7,
+    "hand and" => 66,
+    "hand of" => 8,
+    "hand the" => 14,
+    "hand to" => 14,
+    "handle a" => 47,
+    "handle and" => 25,
+    "handle for" => 14,
+    "handle in" => 14,
+    "handle is" => 21,
+    "handle of" => 6,
+    "handle that" => 15,
+    "handle the" => 135,
+    "handle this" => 28,
+    "handle to" => 57,
+    "handled the" => 21,
+    "handler the" => 9,
+    "handles the" => 23,
+    "handling a" => 11,
+    "handling and" => 21,
+    "handling in" => 18,
+    "handling is" => 24,
+    "handling of" => 87,
+    "handling the" => 20,
+    "handling to" => 6,
+    "hands the" => 4,
+    "happens the" => 7,
+    "hard to" => 128,
+    "hardware the" => 5,
+    "has a" => 977,
+    "has all" => 10,
+    "has also" => 18,
+    "has an" => 195,
+    "has and" => 13,
+    "has any" => 14,
+    "has as" => 5,
+    "has at" => 17,
+    "has but" => 5,
+    "has by" => 3,
+    "has file" => 3,
+    "has for" => 4,
+    "has in" => 11,
+    "has is" => 4,
+    "has it" => 13,
+    "has more" => 30,
+    "has no" => 298,
+    "has not" => 226,
+    "has one" => 57,
+    "has only" => 33,
+    "has or" => 13,
+    "has patch" => 5,
+    "has set" => 6,
+    "has that" => 15,
+    "has the" => 470,
+    "has this" => 20,
+    "has to" => 206,
+    "has type" => 36,
+    "has with" => 3,
+    "has your" => 5,
+    "hash a" => 6,
+    "hash and" => 10,
+    "hash is" => 7,
+    "hash of" => 20,
+    "hash the" => 9,
+    "hash to" => 12,
+    "have a" => 1496,
+    "have all" => 21,
+    "have also" => 19,
+    "have an" => 264,
+    "have and" => 17,
+    "have any" => 179,
+    "have as" => 18,
+    "have at" => 23,
+    "have but" => 4,
+    "have code" => 5,
+    "have data" => 10,
+    "have default" => 10,
+    "have documentation" => 4,
+    "have files" => 5,
+    "have for" => 14,
+    "have i" => 14,
+    "have in" => 31,
+    "have is" => 4,
+    "have it" => 74,
+    "have more" => 49,
+    "have new" => 3,
+    "have no" => 230,
+    "have not" => 97,
+    "have of" => 7,
+    "have on" => 8,
+    "have one" => 76,
+    "have only" => 35,
+    "have or" => 8,
+    "have other" => 7,
+    "have rust" => 15,
+    "have set" => 14,
+    "have so" => 8,
+    "have some" => 90,
+    "have support" => 4,
+    "have that" => 27,
+    "have the" => 715,
+    "have these" => 21,
+    "have they" => 3,
+    "have this" => 67,
+    "have time" => 10,
+    "have to" => 902,
+    "have type" => 14,
+    "have types" => 3,
+    "have used" => 33,
+    "have vim" => 4,
+    "have we" => 5,
+    "have when" => 6,
+    "have with" => 6,
+    "have work" => 3,
+    "have you" => 103,
+    "have your" => 36,
+    "having a" => 86,
+    "having the" => 48,
+    "having to" => 143,
+    "he and" => 25,
+    "he as" => 3,
+    "he at" => 11,
+    "he be" => 3,
+    "he but" => 6,
+    "he can" => 23,
+    "he have" => 7,
+    "he i" => 7,
+    "he in" => 4,
+    "he is" => 146,
+    "he not" => 22,
+    "he only" => 9,
+    "he or" => 7,
+    "he set" => 7,
+    "he that" => 9,
+    "he the" => 3,
+    "he to" => 9,
+    "he used" => 13,
+    "he was" => 629,
+    "he will" => 26,
+    "he with" => 8,
+    "he you" => 3,
+    "header and" => 28,
+    "header the" => 3,
+    "header to" => 13,
+    "headers the" => 5,
+    "heap to" => 8,
+    "heard the" => 58,
+    "help a" => 3,
+    "help and" => 25,
+    "help for" => 49,
+    "help in" => 25,
+    "help is" => 16,
+    "help it" => 15,
+    "help of" => 36,
+    "help the" => 19,
+    "help to" => 27,
+    "help with" => 70,
+    "help you" => 123,
+    "helps the" => 5,
+    "her a" => 7,
+    "her and" => 63,
+    "her as" => 17,
+    "her for" => 6,
+    "her if" => 5,
+    "her in" => 19,
+    "her of" => 5,
+    "her on" => 6,
+    "her or" => 6,
+    "her that" => 20,
+    "her the" => 7,
+    "her to" => 59,
+    "her with" => 24,
+    "here a" => 7,
+    "here and" => 83,
+    "here are" => 174,
+    "here as" => 20,
+    "here can" => 4,
+    "here for" => 71,
+    "here if" => 12,
+    "here in" => 34,
+    "here is" => 353,
+    "here it" => 20,
+    "here on" => 15,
+    "here or" => 13,
+    "here that" => 17,
+    "here the" => 45,
+    "here this" => 9,
+    "here to" => 48,
+    "here when" => 5,
+    "here with" => 18,
+    "here you" => 24,
+    "hidden the" => 3,
+    "high and" => 19,
+    "high in" => 6,
+    "high to" => 3,
+    "higher the" => 11,
+    "higher to" => 4,
+    "highlight a" => 8,
+    "highlight the" => 52,
+    "highlighting a" => 7,
+    "highlighting and" => 21,
+    "highlighting in" => 13,
+    "highlighting is" => 52,
+    "highlighting of" => 25,
+    "highlighting the" => 5,
+    "highlighting to" => 15,
+    "him a" => 37,
+    "him and" => 132,
+    "him as" => 34,
+    "him for" => 39,
+    "him in" => 60,
+    "him is" => 3,
+    "him it" => 3,
+    "him not" => 8,
+    "him of" => 10,
+    "him that" => 51,
+    "him the" => 40,
+    "him to" => 137,
+    "him with" => 39,
+    "him you" => 5,
+    "his and" => 4,
+    "his or" => 3,
+    "his will" => 6,
+    "history to" => 10,
+    "hit the" => 15,
+    "hold the" => 51,
+    "holmes the" => 8,
+    "home to" => 19,
+    "host and" => 22,
+    "host of" => 6,
+    "host the" => 8,
+    "host to" => 8,
+    "hour the" => 7,
+    "hours the" => 6,
+    "house a" => 5,
+    "house the" => 3,
+    "house to" => 8,
+    "how a" => 66,
+    "how an" => 6,
+    "how and" => 18,
+    "how are" => 10,
+    "how can" => 52,
+    "how in" => 8,
+    "how is" => 31,
+    "how it" => 163,
+    "how on" => 5,
+    "how or" => 3,
+    "how that" => 11,
+    "how the" => 268,
+    "how this" => 70,
+    "how to" => 1220,
+    "how using" => 4,
+    "how vim" => 9,
+    "how will" => 5,
+    "how you" => 111,
+    "however a" => 23,
+    "however and" => 22,
+    "however are" => 6,
+    "however as" => 30,
+    "however be" => 5,
+    "however for" => 34,
+    "however if" => 163,
+    "however in" => 60,
+    "however is" => 31,
+    "however it" => 123,
+    "however not" => 8,
+    "however on" => 7,
+    "however that" => 60,
+    "however the" => 227,
+    "however this" => 153,
+    "however to" => 49,
+    "however with" => 15,
+    "however you" => 51,
+    "i a" => 11,
+    "i also" => 16,
+    "i and" => 41,
+    "i as" => 6,
+    "i at" => 7,
+    "i be" => 5,
+    "i but" => 8,
+    "i can" => 130,
+    "i command" => 14,
+    "i do" => 118,
+    "i first" => 12,
+    "i for" => 9,
+    "i have" => 607,
+    "i i" => 53,
+    "i in" => 18,
+    "i is" => 13,
+    "i like" => 21,
+    "i list" => 3,
+    "i make" => 10,
+    "i may" => 64,
+    "i must" => 91,
+    "i no" => 3,
+    "i not" => 22,
+    "i of" => 12,
+    "i only" => 28,
+    "i or" => 16,
+    "i same" => 19,
+    "i see" => 57,
+    "i set" => 7,
+    "i should" => 215,
+    "i so" => 6,
+    "i string" => 3,
+    "i test" => 3,
+    "i the" => 17,
+    "i then" => 29,
+    "i to" => 43,
+    "i use" => 59,
+    "i used" => 21,
+    "i was" => 621,
+    "i when" => 3,
+    "i will" => 179,
+    "i with" => 33,
+    "i would" => 114,
+    "i you" => 9,
+    "i'm the" => 3,
+    "identifier and" => 11,
+    "identifier in" => 7,
+    "identifier is" => 30,
+    "identifier of" => 33,
+    "identifier the" => 3,
+    "identifier to" => 7,
+    "if a" => 921,
+    "if additional" => 5,
+    "if after" => 5,
+    "if all" => 117,
+    "if also" => 4,
+    "if an" => 272,
+    "if and" => 94,
+    "if another" => 33,
+    "if any" => 330,
+    "if at" => 27,
+    "if available" => 43,
+    "if being" => 4,
+    "if block" => 4,
+    "if both" => 77,
+    "if buffer" => 11,
+    "if by" => 15,
+    "if called" => 33,
+    "if can" => 13,
+    "if cannot" => 4,
+    "if cargo" => 50,
+    "if changes" => 5,
+    "if code" => 3,
+    "if command" => 6,
+    "if crates" => 4,
+    "if cursor" => 3,
+    "if data" => 6,
+    "if default" => 6,
+    "if defined" => 9,
+    "if different" => 7,
+    "if directory" => 3,
+    "if does" => 67,
+    "if doesn't" => 4,
+    "if each" => 13,
+    "if either" => 45,
+    "if expression" => 4,
+    "if feature" => 9,
+    "if file" => 7,
+    "if files" => 8,
+    "if first" => 3,
+    "if flag" => 5,
+    "if for" => 43,
+    "if from" => 4,
+    "if given" => 12,
+    "if had" => 8,
+    "if has" => 59,
+    "if he" => 151,
+    "if her" => 4,
+    "if his" => 10,
+    "if however" => 6,
+    "if i" => 188,
+    "if if" => 7,
+    "if in" => 27,
+    "if instead" => 16,
+    "if is" => 953,
+    "if it" => 1068,
+    "if it's" => 120,
+    "if its" => 46,
+    "if just" => 3,
+    "if line" => 3,
+    "if match" => 4,
+    "if memory" => 7,
+    "if mode" => 3,
+    "if more" => 27,
+    "if most" => 3,
+    "if multiple" => 30,
+    "if my" => 16,
+    "if name" => 3,
+    "if new" => 6,
+    "if no" => 224,
+    "if non" => 5,
+    "if not" => 189,
+    "if on" => 10,
+    "if one" => 122,
+    "if only" => 28,
+    "if or" => 49,
+    "if other" => 7,
+    "if out" => 5,
+    "if possible" => 146,
+    "if result" => 3,
+    "if returns" => 32,
+    "if run" => 4,
+    "if rust" => 25,
+    "if script" => 4,
+    "if set" => 78,
+    "if she" => 51,
+    "if should" => 3,
+    "if size" => 3,
+    "if so" => 25,
+    "if some" => 30,
+    "if specified" => 29,
+    "if still" => 5,
+    "if such" => 21,
+    "if support" => 4,
+    "if supported" => 12,
+    "if syntax" => 4,
+    "if target" => 21,
+    "if terminal" => 3,
+    "if that" => 123,
+    "if the" => 5288,
+    "if their" => 27,
+    "if then" => 9,
+    "if there" => 607,
+    "if these" => 33,
+    "if they" => 384,
+    "if this" => 490,
+    "if to" => 24,
+    "if two" => 40,
+    "if used" => 37,
+    "if user" => 4,
+    "if using" => 64,
+    "if version" => 4,
+    "if vim" => 53,
+    "if was" => 29,
+    "if we" => 351,
+    "if were" => 12,
+    "if what" => 4,
+    "if while" => 4,
+    "if with" => 5,
+    "if would" => 5,
+    "if you" => 4675,
+    "if your" => 375,
+    "ignore a" => 15,
+    "ignore the" => 87,
+    "ignored the" => 5,
+    "image and" => 13,
+    "image for" => 6,
+    "image in" => 6,
+    "image is" => 7,
+    "image of" => 14,
+    "image that" => 8,
+    "image the" => 6,
+    "image to" => 9,
+    "immediately the" => 3,
+    "implement a" => 151,
+    "implement and" => 86,
+    "implement as" => 10,
+    "implement for" => 60,
+    "implement in" => 12,
+    "implement is" => 3,
+    "implement it" => 54,
+    "implement that" => 9,
+    "implement the" => 426,
+    "implement this" => 55,
+    "implement to" => 16,
+    "implement with" => 5,
+    "implement you" => 3,
+    "implementation a" => 5,
+    "implementation and" => 79,
+    "implementation are" => 9,
+    "implementation as" => 13,
+    "implementation by" => 6,
+    "implementation can" => 27,
+    "implementation for" => 214,
+    "implementation from" => 14,
+    "implementation if" => 12,
+    "implementation in" => 87,
+    "implementation is" => 194,
+    "implementation it" => 7,
+    "implementation of" => 929,
+    "implementation on" => 13,
+    "implementation or" => 24,
+    "implementation that" => 69,
+    "implementation the" => 14,
+    "implementation this" => 4,
+    "implementation to" => 51,
+    "implementation use" => 3,
+    "implementation when" => 9,
+    "implementation will" => 16,
+    "implementation with" => 33,
+    "implementation you" => 8,
+    "implementations and" => 26,
+    "implementations for" => 137,
+    "implementations in" => 19,
+    "implementations of" => 212,
+    "implementations the" => 3,
+    "implementations to" => 26,
+    "implemented a" => 4,
+    "implemented and" => 26,
+    "implemented for" => 193,
+    "implemented in" => 135,
+    "implemented is" => 4,
+    "implemented the" => 19,
+    "implemented to" => 13,
+    "implementing a" => 64,
+    "implementing the" => 142,
+    "implementing to" => 8,
+    "implements a" => 105,
+    "implements and" => 81,
+    "implements for" => 40,
+    "implements is" => 3,
+    "implements the" => 305,
+    "implements to" => 13,
+    "import a" => 13,
+    "import the" => 77,
+    "import to" => 7,
+    "important and" => 8,
+    "important to" => 126,
+    "imported the" => 3,
+    "imports the" => 6,
+    "improve the" => 70,
+    "in a" => 7329,
+    "in about" => 8,
+    "in access" => 3,
+    "in across" => 4,
+    "in add" => 4,
+    "in adding" => 4,
+    "in additional" => 7,
+    "in after" => 6,
+    "in again" => 4,
+    "in all" => 297,
+    "in an" => 890,
+    "in and" => 530,
+    "in another" => 204,
+    "in any" => 366,
+    "in application" => 14,
+    "in are" => 23,
+    "in argument" => 12,
+    "in arguments" => 4,
+    "in array" => 4,
+    "in as" => 57,
+    "in associated" => 9,
+    "in at" => 42,
+    "in attribute" => 9,
+    "in attributes" => 16,
+    "in automatically" => 5,
+    "in base" => 14,
+    "in because" => 6,
+    "in before" => 8,
+    "in behavior" => 4,
+    "in being" => 7,
+    "in better" => 6,
+    "in between" => 67,
+    "in binary" => 26,
+    "in bit" => 26,
+    "in bits" => 27,
+    "in block" => 13,
+    "in boolean" => 4,
+    "in both" => 123,
+    "in branch" => 4,
+    "in buffer" => 12,
+    "in build" => 25,
+    "in building" => 14,
+    "in but" => 45,
+    "in by" => 75,
+    "in byte" => 15,
+    "in bytes" => 169,
+    "in call" => 3,
+    "in calls" => 3,
+    "in can" => 15,
+    "in cargo" => 141,
+    "in case" => 213,
+    "in cases" => 59,
+    "in change" => 6,
+    "in channel" => 3,
+    "in chapter" => 267,
+    "in characters" => 7,
+    "in check" => 8,
+    "in class" => 7,
+    "in client" => 4,
+    "in closes" => 3,
+    "in code" => 53,
+    "in color" => 10,
+    "in command" => 53,
+    "in commands" => 8,
+    "in common" => 12,
+    "in compatible" => 9,
+    "in compilation" => 3,
+    "in compile" => 4,
+    "in compiled" => 16,
+    "in compiler" => 7,
+    "in complete" => 8,
+    "in completion" => 7,
+    "in complex" => 4,
+    "in configuration" => 5,
+    "in configure" => 31,
+    "in const" => 31,
+    "in context" => 8,
+    "in contributing" => 15,
+    "in control" => 4,
+    "in core" => 10,
+    "in corresponding" => 3,
+    "in count" => 27,
+    "in crate" => 16,
+    "in crates" => 19,
+    "in creating" => 14,
+    "in cross" => 10,
+    "in current" => 40,
+    "in currently" => 3,
+    "in custom" => 5,
+    "in data" => 10,
+    "in default" => 13,
+    "in dependencies" => 17,
+    "in dependency" => 7,
+    "in derive" => 6,
+    "in development" => 14,
+    "in different" => 132,
+    "in directory" => 12,
+    "in do" => 12,
+    "in doc" => 8,
+    "in documentation" => 33,
+    "in does" => 3,
+    "in doing" => 10,
+    "in don't" => 3,
+    "in double" => 11,
+    "in down" => 4,
+    "in each" => 208,
+    "in edit" => 4,
+    "in edition" => 15,
+    "in either" => 70,
+    "in element" => 6,
+    "in elements" => 9,
+    "in empty" => 4,
+    "in enabled" => 3,
+    "in encoding" => 9,
+    "in environment" => 4,
+    "in error" => 36,
+    "in errors" => 17,
+    "in etc" => 11,
+    "in even" => 8,
+    "in every" => 74,
+    "in ex" => 69,
+    "in example" => 8,
+    "in examples" => 22,
+    "in execution" => 5,
+    "in expression" => 22,
+    "in expressions" => 24,
+    "in external" => 11,
+    "in extra" => 6,
+    "in fast" => 4,
+    "in feature" => 16,
+    "in features" => 6,
+    "in field" => 4,
+    "in file" => 64,
+    "in filename" => 6,
+    "in files" => 33,
+    "in find" => 5,
+    "in first" => 24,
+    "in fixed" => 7,
+    "in flags" => 3,
+    "in floating" => 3,
+    "in following" => 6,
+    "in for" => 67,
+    "in form" => 7,
+    "in format" => 13,
+    "in free" => 8,
+    "in from" => 50,
+    "in full" => 17,
+    "in function" => 45,
+    "in functionality" => 12,
+    "in functions" => 51,
+    "in future" => 59,
+    "in general" => 193,
+    "in generated" => 11,
+    "in generic" => 15,
+    "in get" => 10,
+    "in git" => 76,
+    "in global" => 5,
+    "in go" => 5,
+    "in good" => 11,
+    "in half" => 7,
+    "in handle" => 3,
+    "in handling" => 4,
+    "in has" => 17,
+    "in he" => 5,
+    "in help" => 20,
+    "in her" => 115,
+    "in here" => 12,
+    "in high" => 10,
+    "in him" => 17,
+    "in his" => 383,
+    "in how" => 31,
+    "in i" => 6,
+    "in if" => 28,
+    "in image" => 7,
+    "in implementation" => 6,
+    "in implementations" => 7,
+    "in in" => 29,
+    "in included" => 7,
+    "in including" => 3,
+    "in index" => 3,
+    "in information" => 5,
+    "in input" => 12,
+    "in insert" => 317,
+    "in instance" => 20,
+    "in instead" => 6,
+    "in integer" => 14,
+    "in internal" => 4,
+    "in is" => 137,
+    "in issue" => 5,
+    "in it" => 193,
+    "in it's" => 4,
+    "in item" => 9,
+    "in items" => 7,
+    "in its" => 275,
+    "in itself" => 17,
+    "in just" => 17,
+    "in key" => 7,
+    "in language" => 6,
+    "in large" => 14,
+    "in last" => 7,
+    "in later" => 10,
+    "in latest" => 3,
+    "in layout" => 3,
+    "in left" => 25,
+    "in length" => 20,
+    "in less" => 20,
+    "in libraries" => 19,
+    "in library" => 11,
+    "in license" => 19,
+    "in like" => 18,
+    "in line" => 45,
+    "in lines" => 5,
+    "in link" => 3,
+    "in list" => 38,
+    "in listing" => 455,
+    "in literal" => 5,
+    "in little" => 24,
+    "in local" => 6,
+    "in location" => 4,
+    "in long" => 7,
+    "in low" => 3,
+    "in lower" => 7,
+    "in macro" => 17,
+    "in macros" => 12,
+    "in main" => 9,
+    "in make" => 10,
+    "in making" => 14,
+    "in many" => 159,
+    "in map" => 3,
+    "in mapping" => 5,
+    "in mask" => 205,
+    "in match" => 6,
+    "in matching" => 3,
+    "in may" => 9,
+    "in me" => 17,
+    "in memory" => 276,
+    "in menu" => 15,
+    "in message" => 7,
+    "in messages" => 10,
+    "in method" => 16,
+    "in methods" => 5,
+    "in might" => 3,
+    "in mode" => 17,
+    "in module" => 27,
+    "in modules" => 29,
+    "in more" => 82,
+    "in most" => 164,
+    "in mouse" => 7,
+    "in much" => 5,
+    "in multiple" => 66,
+    "in must" => 16,
+    "in mutable" => 7,
+    "in my" => 331,
+    "in name" => 9,
+    "in native" => 23,
+    "in need" => 10,
+    "in new" => 48,
+    "in next" => 8,
+    "in no" => 62,
+    "in node" => 74,
+    "in non" => 62,
+    "in normal" => 85,
+    "in not" => 16,
+    "in now" => 7,
+    "in number" => 10,
+    "in object" => 27,
+    "in objects" => 11,
+    "in of" => 9,
+    "in old" => 18,
+    "in on" => 19,
+    "in once" => 3,
+    "in one" => 351,
+    "in only" => 12,
+    "in open" => 7,
+    "in operations" => 5,
+    "in operator" => 9,
+    "in option" => 6,
+    "in options" => 9,
+    "in or" => 89,
+    "in order" => 817,
+    "in other" => 427,
+    "in otherwise" => 6,
+    "in our" => 161,
+    "in out" => 11,
+    "in output" => 8,
+    "in over" => 3,
+    "in package" => 7,
+    "in packed" => 6,
+    "in parameter" => 8,
+    "in parsing" => 9,
+    "in part" => 16,
+    "in particular" => 355,
+    "in passing" => 3,
+    "in patch" => 45,
+    "in path" => 58,
+    "in paths" => 13,
+    "in pattern" => 7,
+    "in patterns" => 9,
+    "in performance" => 20,
+    "in place" => 257,
+    "in platform" => 5,
+    "in point" => 6,
+    "in pointer" => 3,
+    "in popup" => 41,
+    "in position" => 5,
+    "in previous" => 25,
+    "in process" => 9,
+    "in program" => 8,
+    "in project" => 4,
+    "in protocol" => 6,
+    "in public" => 15,
+    "in python" => 77,
+    "in range" => 26,
+    "in rather" => 7,
+    "in raw" => 15,
+    "in read" => 25,
+    "in reading" => 3,
+    "in reference" => 4,
+    "in references" => 3,
+    "in register" => 5,
+    "in release" => 64,
+    "in result" => 20,
+    "in return" => 27,
+    "in returned" => 12,
+    "in returns" => 6,
+    "in right" => 24,
+    "in running" => 10,
+    "in runtime" => 12,
+    "in rust" => 1098,
+    "in safe" => 60,
+    "in safety" => 8,
+    "in same" => 11,
+    "in scope" => 100,
+    "in screen" => 12,
+    "in script" => 64,
+    "in search" => 38,
+    "in second" => 4,
+    "in section" => 89,
+    "in see" => 6,
+    "in sequence" => 18,
+    "in server" => 4,
+    "in set" => 18,
+    "in setting" => 4,
+    "in several" => 58,
+    "in she" => 5,
+    "in should" => 8,
+    "in sign" => 35,
+    "in similar" => 4,
+    "in simple" => 15,
+    "in since" => 4,
+    "in single" => 29,
+    "in size" => 24,
+    "in slice" => 3,
+    "in small" => 16,
+    "in so" => 27,
+    "in some" => 398,
+    "in something" => 4,
+    "in source" => 33,
+    "in space" => 5,
+    "in special" => 4,
+    "in specific" => 18,
+    "in specified" => 4,
+    "in stable" => 24,
+    "in stack" => 8,
+    "in standard" => 11,
+    "in state" => 3,
+    "in static" => 8,
+    "in stream" => 3,
+    "in string" => 25,
+    "in strings" => 17,
+    "in structure" => 3,
+    "in such" => 137,
+    "in support" => 32,
+    "in supported" => 3,
+    "in syntax" => 16,
+    "in system" => 7,
+    "in systems" => 4,
+    "in tab" => 11,
+    "in table" => 4,
+    "in tag" => 17,
+    "in tags" => 17,
+    "in target" => 11,
+    "in targets" => 9,
+    "in term" => 7,
+    "in terminal" => 77,
+    "in terms" => 105,
+    "in test" => 64,
+    "in testing" => 8,
+    "in tests" => 41,
+    "in text" => 22,
+    "in that" => 469,
+    "in the" => 17494,
+    "in their" => 227,
+    "in them" => 38,
+    "in then" => 22,
+    "in there" => 24,
+    "in these" => 136,
+    "in this" => 1985,
+    "in those" => 66,
+    "in though" => 4,
+    "in thread" => 5,
+    "in threads" => 14,
+    "in three" => 33,
+    "in time" => 104,
+    "in to" => 201,
+    "in token" => 5,
+    "in tools" => 3,
+    "in top" => 7,
+    "in trait" => 28,
+    "in traits" => 21,
+    "in tree" => 7,
+    "in try" => 4,
+    "in two" => 177,
+    "in type" => 26,
+    "in types" => 29,
+    "in undefined" => 46,
+    "in under" => 7,
+    "in unix" => 27,
+    "in unsafe" => 20,
+    "in up" => 5,
+    "in upon" => 10,
+    "in upper" => 5,
+    "in usage" => 3,
+    "in use" => 100,
+    "in user" => 20,
+    "in using" => 73,
+    "in valid" => 4,
+    "in value" => 6,
+    "in values" => 9,
+    "in variable" => 13,
+    "in variables" => 9,
+    "in various" => 55,
+    "in version" => 155,
+    "in versions" => 13,
+    "in very" => 23,
+    "in via" => 10,
+    "in vim" => 307,
+    "in visual" => 187,
+    "in was" => 5,
+    "in we" => 26,
+    "in what" => 33,
+    "in when" => 28,
+    "in where" => 10,
+    "in which" => 609,
+    "in while" => 11,
+    "in will" => 28,
+    "in win" => 24,
+    "in window" => 12,
+    "in windows" => 39,
+    "in with" => 70,
+    "in within" => 4,
+    "in without" => 6,
+    "in word" => 4,
+    "in words" => 3,
+    "in working" => 4,
+    "in write" => 5,
+    "in writing" => 14,
+    "in wrong" => 31,
+    "in you" => 31,
+    "in your" => 1331,
+    "in zero" => 7,
+    "include a" => 133,
+    "include and" => 24,
+    "include for" => 5,
+    "include in" => 27,
+    "include is" => 3,
+    "include that" => 5,
+    "include the" => 302,
+    "include this" => 27,
+    "include with" => 4,
+    "included a" => 16,
+    "included and" => 10,
+    "included for" => 15,
+    "included in" => 314,
+    "included is" => 5,
+    "included the" => 25,
+    "included to" => 6,
+    "includes a" => 157,
+    "includes and" => 11,
+    "includes for" => 3,
+    "includes the" => 135,
+    "including a" => 32,
+    "including and" => 32,
+    "including for" => 3,
+    "including in" => 12,
+    "including the" => 185,
+    "inclusion in" => 1150,
+    "inclusion of" => 11,
+    "increase the" => 89,
+    "indeed the" => 8,
+    "indent the" => 10,
+    "indent to" => 14,
+    "index a" => 6,
+    "index and" => 38,
+    "index for" => 14,
+    "index in" => 101,
+    "index is" => 130,
+    "index of" => 104,
+    "index that" => 11,
+    "index the" => 11,
+    "index to" => 35,
+    "indicate the" => 51,
+    "indicates the" => 80,
+    "info to" => 15,
+    "information and" => 74,
+    "information are" => 5,
+    "information as" => 21,
+    "information can" => 20,
+    "information for" => 65,
+    "information from" => 36,
+    "information if" => 6,
+    "information in" => 70,
+    "information is" => 84,
+    "information it" => 9,
+    "information of" => 19,
+    "information on" => 332,
+    "information or" => 7,
+    "information that" => 26,
+    "information the" => 3,
+    "information to" => 71,
+    "information when" => 8,
+    "information will" => 15,
+    "information with" => 8,
+    "information you" => 21,
+    "initialize the" => 87,
+    "initialized the" => 8,
+    "input a" => 8,
+    "input and" => 107,
+    "input for" => 13,
+    "input in" => 20,
+    "input is" => 71,
+    "input it" => 6,
+    "input of" => 13,
+    "input that" => 11,
+    "input the" => 11,
+    "input to" => 49,
+    "input with" => 11,
+    "input you" => 3,
+    "insert a" => 114,
+    "insert and" => 32,
+    "insert in" => 9,
+    "insert is" => 3,
+    "insert of" => 3,
+    "insert the" => 69,
+    "insert to" => 7,
+    "insert with" => 5,
+    "inserted the" => 3,
+    "inside a" => 204,
+    "inside and" => 13,
+    "inside for" => 3,
+    "inside is" => 3,
+    "inside of" => 115,
+    "inside the" => 252,
+    "install a" => 29,
+    "install and" => 26,
+    "install for" => 4,
+    "install in" => 4,
+    "install is" => 5,
+    "install the" => 184,
+    "install to" => 8,
+    "installation to" => 3,
+    "installed a" => 3,
+    "installed the" => 9,
+    "installed to" => 8,
+    "instance a" => 22,
+    "instance and" => 27,
+    "instance for" => 7,
+    "instance in" => 25,
+    "instance is" => 71,
+    "instance of" => 356,
+    "instance that" => 45,
+    "instance the" => 50,
+    "instance this" => 7,
+    "instance to" => 33,
+    "instance with" => 23,
+    "instances to" => 14,
+    "instead a" => 8,
+    "instead an" => 4,
+    "instead and" => 15,
+    "instead as" => 11,
+    "instead be" => 10,
+    "instead for" => 8,
+    "instead from" => 4,
+    "instead i" => 3,
+    "instead if" => 14,
+    "instead in" => 8,
+    "instead is" => 5,
+    "instead it" => 60,
+    "instead of" => 2236,
+    "instead or" => 7,
+    "instead the" => 45,
+    "instead this" => 10,
+    "instead to" => 20,
+    "instead use" => 70,
+    "instead using" => 8,
+    "instead which" => 28,
+    "instead you" => 34,
+    "instruction and" => 10,
+    "instruction is" => 34,
+    "instruction to" => 15,
+    "instructions and" => 24,
+    "instructions in" => 27,
+    "instructions is" => 4,
+    "instructions of" => 3,
+    "instructions to" => 40,
+    "integer a" => 15,
+    "integer and" => 69,
+    "integer as" => 28,
+    "integer can" => 7,
+    "integer for" => 3,
+    "integer if" => 12,
+    "integer in" => 103,
+    "integer is" => 18,
+    "integer it" => 3,
+    "integer not" => 3,
+    "integer of" => 14,
+    "integer or" => 36,
+    "integer that" => 18,
+    "integer the" => 98,
+    "integer to" => 53,
+    "integer with" => 42,
+    "integers and" => 232,
+    "integers are" => 31,
+    "integers as" => 13,
+    "integers can" => 6,
+    "integers for" => 7,
+    "integers in" => 1087,
+    "integers is" => 7,
+    "integers of" => 7,
+    "integers or" => 34,
+    "integers that" => 9,
+    "integers the" => 7,
+    "integers this" => 7,
+    "integers to" => 26,
+    "integers when" => 3,
+    "integers with" => 189,
+    "integration and" => 12,
+    "integration to" => 3,
+    "intended and" => 7,
+    "intended to" => 292,
+    "interface a" => 4,
+    "interface and" => 45,
+    "interface for" => 116,
+    "interface in" => 15,
+    "interface is" => 44,
+    "interface of" => 15,
+    "interface the" => 9,
+    "interface to" => 106,
+    "interfaces the" => 3,
+    "internal and" => 7,
+    "internal in" => 3,
+    "internal to" => 12,
+    "internally the" => 10,
+    "into a" => 1244,
+    "into all" => 13,
+    "into an" => 235,
+    "into and" => 42,
+    "into any" => 27,
+    "into are" => 3,
+    "into as" => 6,
+    "into bit" => 3,
+    "into documentation" => 3,
+    "into files" => 5,
+    "into for" => 4,
+    "into from" => 7,
+    "into in" => 4,
+    "into is" => 7,
+    "into it" => 77,
+    "into more" => 13,
+    "into one" => 67,
+    "into or" => 14,
+    "into rust" => 47,
+    "into that" => 30,
+    "into the" => 1331,
+    "into this" => 68,
+    "into using" => 8,
+    "into vim" => 7,
+    "into when" => 3,
+    "into which" => 21,
+    "into with" => 3,
+    "into your" => 151,
+    "intrinsic to" => 11,
+    "intro of" => 3,
+    "intro to" => 4,
+    "introduced the" => 11,
+    "invalid and" => 7,
+    "invalid in" => 10,
+    "invalid the" => 4,
+    "invalid to" => 4,
+    "invocation the" => 5,
+    "invoked the" => 13,
+    "invoked to" => 7,
+    "is a" => 5621,
+    "is able" => 62,
+    "is about" => 72,
+    "is above" => 5,
+    "is access" => 3,
+    "is actually" => 112,
+    "is add" => 3,
+    "is added" => 111,
+    "is adding" => 3,
+    "is after" => 28,
+    "is again" => 8,
+    "is against" => 4,
+    "is all" => 134,
+    "is allow" => 22,
+    "is allowed" => 175,
+    "is already" => 191,
+    "is also" => 827,
+    "is always" => 347,
+    "is an" => 1520,
+    "is and" => 158,
+    "is another" => 70,
+    "is any" => 52,
+    "is apache" => 3,
+    "is are" => 4,
+    "is around" => 12,
+    "is as" => 146,
+    "is associated" => 37,
+    "is at" => 121,
+    "is automatically" => 115,
+    "is available" => 572,
+    "is back" => 3,
+    "is based" => 184,
+    "is basic" => 3,
+    "is because" => 136,
+    "is before" => 15,
+    "is being" => 240,
+    "is below" => 16,
+    "is better" => 47,
+    "is between" => 8,
+    "is binary" => 3,
+    "is bit" => 4,
+    "is bits" => 12,
+    "is boolean" => 9,
+    "is both" => 34,
+    "is bound" => 44,
+    "is buffer" => 6,
+    "is building" => 4,
+    "is built" => 183,
+    "is but" => 25,
+    "is by" => 88,
+    "is byte" => 3,
+    "is bytes" => 21,
+    "is called" => 581,
+    "is calling" => 5,
+    "is case" => 16,
+    "is changed" => 69,
+    "is characters" => 3,
+    "is check" => 4,
+    "is code" => 5,
+    "is common" => 44,
+    "is compatible" => 106,
+    "is compiled" => 80,
+    "is compiler" => 3,
+    "is complete" => 33,
+    "is complex" => 5,
+    "is context" => 3,
+    "is copied" => 235,
+    "is could" => 3,
+    "is crate" => 5,
+    "is crates" => 6,
+    "is created" => 366,
+    "is creating" => 5,
+    "is cross" => 42,
+    "is currently" => 347,
+    "is data" => 6,
+    "is default" => 10,
+    "is defined" => 375,
+    "is different" => 137,
+    "is directly" => 14,
+    "is do" => 3,
+    "is doing" => 26,
+    "is don't" => 4,
+    "is done" => 784,
+    "is double" => 7,
+    "is down" => 4,
+    "is dual" => 75,
+    "is each" => 3,
+    "is easy" => 63,
+    "is either" => 71,
+    "is empty" => 232,
+    "is enabled" => 413,
+    "is encoding" => 4,
+    "is equal" => 134,
+    "is equivalent" => 306,
+    "is error" => 4,
+    "is even" => 19,
+    "is every" => 5,
+    "is example" => 6,
+    "is expected" => 149,
+    "is explicitly" => 30,
+    "is fast" => 31,
+    "is feature" => 7,
+    "is file" => 6,
+    "is first" => 32,
+    "is fixed" => 29,
+    "is following" => 10,
+    "is for" => 238,
+    "is found" => 165,
+    "is free" => 49,
+    "is from" => 23,
+    "is full" => 42,
+    "is function" => 5,
+    "is general" => 4,
+    "is generated" => 103,
+    "is generic" => 43,
+    "is given" => 175,
+    "is global" => 3,
+    "is good" => 40,
+    "is half" => 6,
+    "is handling" => 3,
+    "is has" => 3,
+    "is have" => 3,
+    "is having" => 6,
+    "is he" => 17,
+    "is her" => 6,
+    "is here" => 25,
+    "is high" => 5,
+    "is his" => 14,
+    "is how" => 80,
+    "is however" => 15,
+    "is i" => 15,
+    "is if" => 91,
+    "is implementation" => 5,
+    "is implemented" => 229,
+    "is in" => 687,
+    "is included" => 149,
+    "is inside" => 31,
+    "is instead" => 16,
+    "is intentionally" => 23,
+    "is internal" => 5,
+    "is invalid" => 73,
+    "is is" => 14,
+    "is it" => 168,
+    "is it's" => 4,
+    "is its" => 25,
+    "is itself" => 13,
+    "is just" => 161,
+    "is known" => 105,
+    "is large" => 12,
+    "is later" => 12,
+    "is left" => 42,
+    "is length" => 3,
+    "is less" => 164,
+    "is license" => 3,
+    "is licensed" => 493,
+    "is like" => 87,
+    "is line" => 4,
+    "is lines" => 4,
+    "is list" => 3,
+    "is little" => 9,
+    "is local" => 19,
+    "is long" => 12,
+    "is longer" => 18,
+    "is low" => 6,
+    "is lower" => 7,
+    "is made" => 150,
+    "is making" => 3,
+    "is many" => 5,
+    "is match" => 4,
+    "is maximum" => 3,
+    "is may" => 4,
+    "is memory" => 4,
+    "is missing" => 147,
+    "is mit" => 8,
+    "is more" => 218,
+    "is most" => 49,
+    "is much" => 110,
+    "is multiple" => 3,
+    "is must" => 3,
+    "is mutable" => 13,
+    "is my" => 41,
+    "is named" => 46,
+    "is needed" => 158,
+    "is never" => 92,
+    "is new" => 8,
+    "is next" => 4,
+    "is no" => 1085,
+    "is non" => 171,
+    "is none" => 12,
+    "is normal" => 5,
+    "is not" => 6508,
+    "is nothing" => 41,
+    "is now" => 356,
+    "is null" => 79,
+    "is number" => 6,
+    "is object" => 7,
+    "is of" => 105,
+    "is off" => 88,
+    "is often" => 136,
+    "is old" => 33,
+    "is on" => 221,
+    "is once" => 7,
+    "is one" => 279,
+    "is only" => 712,
+    "is open" => 44,
+    "is operator" => 4,
+    "is optional" => 63,
+    "is or" => 100,
+    "is otherwise" => 42,
+    "is our" => 20,
+    "is out" => 86,
+    "is output" => 8,
+    "is over" => 25,
+    "is packed" => 3,
+    "is panic" => 3,
+    "is part" => 191,
+    "is passed" => 231,
+    "is passing" => 3,
+    "is performance" => 4,
+    "is platform" => 31,
+    "is popup" => 9,
+    "is possible" => 379,
+    "is present" => 141,
+    "is properly" => 19,
+    "is provided" => 356,
+    "is public" => 12,
+    "is put" => 41,
+    "is python" => 4,
+    "is rather" => 27,
+    "is read" => 73,
+    "is reading" => 5,
+    "is reference" => 3,
+    "is removed" => 89,
+    "is required" => 332,
+    "is return" => 3,
+    "is returned" => 440,
+    "is returns" => 14,
+    "is right" => 11,
+    "is root" => 3,
+    "is run" => 80,
+    "is running" => 82,
+    "is runtime" => 3,
+    "is rust" => 37,
+    "is safe" => 180,
+    "is said" => 29,
+    "is same" => 4,
+    "is script" => 4,
+    "is see" => 3,
+    "is separate" => 4,
+    "is set" => 1001,
+    "is setting" => 5,
+    "is shared" => 31,
+    "is she" => 3,
+    "is should" => 3,
+    "is signed" => 4,
+    "is similar" => 234,
+    "is simple" => 34,
+    "is single" => 11,
+    "is small" => 20,
+    "is so" => 90,
+    "is some" => 40,
+    "is something" => 54,
+    "is source" => 4,
+    "is space" => 3,
+    "is special" => 14,
+    "is specific" => 10,
+    "is specified" => 221,
+    "is stable" => 41,
+    "is standard" => 4,
+    "is started" => 48,
+    "is starting" => 4,
+    "is static" => 7,
+    "is still" => 357,
+    "is such" => 20,
+    "is support" => 6,
+    "is supported" => 223,
+    "is syntax" => 5,
+    "is take" => 3,
+    "is target" => 3,
+    "is terminal" => 4,
+    "is tested" => 72,
+    "is testing" => 3,
+    "is text" => 5,
+    "is that" => 837,
+    "is the" => 2960,
+    "is their" => 4,
+    "is then" => 159,
+    "is there" => 37,
+    "is these" => 3,
+    "is they" => 22,
+    "is this" => 116,
+    "is though" => 3,
+    "is thread" => 6,
+    "is three" => 7,
+    "is through" => 15,
+    "is thus" => 24,
+    "is time" => 6,
+    "is to" => 1162,
+    "is too" => 196,
+    "is true" => 137,
+    "is two" => 16,
+    "is type" => 5,
+    "is undefined" => 188,
+    "is under" => 45,
+    "is unless" => 5,
+    "is unsafe" => 111,
+    "is up" => 57,
+    "is upon" => 3,
+    "is upper" => 3,
+    "is use" => 8,
+    "is used" => 2022,
+    "is useful" => 351,
+    "is user" => 5,
+    "is using" => 87,
+    "is valid" => 141,
+    "is version" => 7,
+    "is very" => 322,
+    "is via" => 13,
+    "is vim" => 10,
+    "is way" => 6,
+    "is we" => 16,
+    "is well" => 35,
+    "is what" => 135,
+    "is when" => 75,
+    "is where" => 97,
+    "is whether" => 7,
+    "is which" => 81,
+    "is while" => 7,
+    "is who" => 3,
+    "is why" => 77,
+    "is will" => 4,
+    "is windows" => 4,
+    "is with" => 45,
+    "is within" => 29,
+    "is without" => 19,
+    "is words" => 3,
+    "is work" => 4,
+    "is working" => 36,
+    "is write" => 9,
+    "is writing" => 7,
+    "is written" => 193,
+    "is wrong" => 132,
+    "is yes" => 3,
+    "is you" => 22,
+    "is your" => 55,
+    "is zero" => 227,
+    "is zeroed" => 160,
+    "isn't a" => 44,
+    "isn't in" => 6,
+    "isn't the" => 28,
+    "issue a" => 15,
+    "issue and" => 38,
+    "issue for" => 68,
+    "issue in" => 19,
+    "issue is" => 21,
+    "issue of" => 32,
+    "issue the" => 11,
+    "issue this" => 4,
+    "issue to" => 22,
+    "issues a" => 9,
+    "issues and" => 49,
+    "issues in" => 19,
+    "issues is" => 4,
+    "issues of" => 13,
+    "issues the" => 6,
+    "issues to" => 8,
+    "it a" => 120,
+    "it about" => 6,
+    "it add" => 19,
+    "it added" => 3,
+    "it after" => 19,
+    "it all" => 91,
+    "it allows" => 124,
+    "it also" => 293,
+    "it always" => 25,
+    "it an" => 16,
+    "it and" => 363,
+    "it another" => 6,
+    "it any" => 5,
+    "it are" => 16,
+    "it as" => 272,
+    "it at" => 76,
+    "it available" => 23,
+    "it back" => 66,
+    "it be" => 51,
+    "it because" => 12,
+    "it before" => 30,
+    "it being" => 19,
+    "it between" => 4,
+    "it both" => 3,
+    "it but" => 66,
+    "it by" => 94,
+    "it called" => 3,
+    "it can" => 950,
+    "it cannot" => 120,
+    "it change" => 3,
+    "it changes" => 13,
+    "it closes" => 4,
+    "it contains" => 121,
+    "it could" => 101,
+    "it currently" => 26,
+    "it do" => 14,
+    "it does" => 509,
+    "it doesn't" => 275,
+    "it don't" => 10,
+    "it done" => 4,
+    "it down" => 25,
+    "it each" => 3,
+    "it either" => 9,
+    "it even" => 15,
+    "it explicitly" => 5,
+    "it first" => 8,
+    "it for" => 209,
+    "it found" => 3,
+    "it from" => 146,
+    "it had" => 123,
+    "it has" => 492,
+    "it have" => 7,
+    "it he" => 15,
+    "it here" => 28,
+    "it however" => 5,
+    "it i" => 36,
+    "it if" => 68,
+    "it in" => 501,
+    "it instead" => 20,
+    "it into" => 176,
+    "it is" => 5515,
+    "it it" => 13,
+    "it it's" => 10,
+    "it just" => 53,
+    "it last" => 4,
+    "it like" => 58,
+    "it may" => 495,
+    "it might" => 201,
+    "it more" => 38,
+    "it most" => 4,
+    "it must" => 269,
+    "it my" => 7,
+    "it need" => 3,
+    "it no" => 21,
+    "it non" => 3,
+    "it not" => 51,
+    "it now" => 49,
+    "it of" => 8,
+    "it on" => 130,
+    "it one" => 6,
+    "it only" => 106,
+    "it options" => 6,
+    "it or" => 53,
+    "it otherwise" => 5,
+    "it out" => 127,
+    "it over" => 39,
+    "it possible" => 208,
+    "it provides" => 166,
+    "it read" => 3,
+    "it results" => 7,
+    "it return" => 8,
+    "it returned" => 4,
+    "it returns" => 155,
+    "it right" => 18,
+    "it run" => 8,
+    "it running" => 3,
+    "it said" => 37,
+    "it see" => 6,
+    "it set" => 5,
+    "it shall" => 14,
+    "it she" => 5,
+    "it should" => 360,
+    "it since" => 10,
+    "it so" => 55,
+    "it some" => 4,
+    "it still" => 37,
+    "it support" => 5,
+    "it tests" => 3,
+    "it that" => 46,
+    "it the" => 86,
+    "it then" => 55,
+    "it there" => 16,
+    "it they" => 5,
+    "it this" => 12,
+    "it through" => 24,
+    "it to" => 916,
+    "it two" => 3,
+    "it under" => 17,
+    "it unless" => 7,
+    "it up" => 95,
+    "it usage" => 3,
+    "it use" => 20,
+    "it used" => 11,
+    "it useful" => 17,
+    "it uses" => 209,
+    "it using" => 40,
+    "it very" => 28,
+    "it via" => 32,
+    "it vim" => 3,
+    "it was" => 1511,
+    "it we" => 19,
+    "it well" => 6,
+    "it were" => 68,
+    "it what" => 4,
+    "it when" => 66,
+    "it where" => 9,
+    "it which" => 35,
+    "it while" => 10,
+    "it will" => 1358,
+    "it with" => 318,
+    "it within" => 12,
+    "it without" => 28,
+    "it work" => 92,
+    "it would" => 382,
+    "it write" => 3,
+    "it you" => 69,
+    "it your" => 4,
+    "it's a" => 193,
+    "it's an" => 37,
+    "it's as" => 7,
+    "it's for" => 5,
+    "it's in" => 11,
+    "it's not" => 250,
+    "it's on" => 15,
+    "it's that" => 3,
+    "it's the" => 95,
+    "it's you" => 4,
+    "item a" => 3,
+    "item and" => 25,
+    "item be" => 3,
+    "item for" => 18,
+    "item in" => 140,
+    "item is" => 91,
+    "item of" => 36,
+    "item that" => 45,
+    "item the" => 19,
+    "item this" => 3,
+    "item to" => 72,
+    "item with" => 24,
+    "items a" => 3,
+    "items and" => 53,
+    "items are" => 107,
+    "items as" => 21,
+    "items by" => 7,
+    "items can" => 40,
+    "items for" => 19,
+    "items if" => 3,
+    "items in" => 154,
+    "items is" => 21,
+    "items it" => 4,
+    "items not" => 3,
+    "items of" => 37,
+    "items on" => 8,
+    "items or" => 19,
+    "items that" => 78,
+    "items the" => 9,
+    "items to" => 64,
+    "items when" => 3,
+    "items with" => 43,
+    "items you" => 9,
+    "iteration the" => 6,
+    "iterator and" => 41,
+    "iterator for" => 10,
+    "iterator in" => 10,
+    "iterator is" => 70,
+    "iterator of" => 39,
+    "iterator the" => 5,
+    "iterator to" => 8,
+    "its and" => 15,
+    "its as" => 3,
+    "its file" => 7,
+    "its is" => 5,
+    "its not" => 3,
+    "its solution" => 3,
+    "its type" => 49,
+    "its use" => 35,
+    "its vim" => 4,
+    "itself a" => 10,
+    "itself and" => 56,
+    "itself for" => 6,
+    "itself in" => 19,
+    "itself is" => 61,
+    "itself the" => 8,
+    "itself to" => 63,
+    "job to" => 22,
+    "join the" => 67,
+    "just a" => 168,
+    "just an" => 21,
+    "just and" => 6,
+    "just as" => 190,
+    "just be" => 18,
+    "just by" => 10,
+    "just for" => 24,
+    "just in" => 32,
+    "just is" => 4,
+    "just not" => 6,
+    "just on" => 3,
+    "just that" => 20,
+    "just the" => 105,
+    "just this" => 7,
+    "just to" => 35,
+    "just use" => 61,
+    "just with" => 5,
+    "keep a" => 48,
+    "keep and" => 5,
+    "keep in" => 71,
+    "keep the" => 212,
+    "kept the" => 5,
+    "key a" => 9,
+    "key and" => 117,
+    "key are" => 4,
+    "key as" => 14,
+    "key by" => 5,
+    "key can" => 25,
+    "key for" => 63,
+    "key if" => 7,
+    "key in" => 106,
+    "key is" => 130,
+    "key it" => 7,
+    "key not" => 5,
+    "key of" => 33,
+    "key on" => 8,
+    "key or" => 20,
+    "key that" => 33,
+    "key the" => 20,
+    "key to" => 77,
+    "key when" => 12,
+    "key with" => 24,
+    "key you" => 17,
+    "keys a" => 7,
+    "keys and" => 75,
+    "keys for" => 29,
+    "keys in" => 56,
+    "keys is" => 12,
+    "keys of" => 26,
+    "keys the" => 11,
+    "keys to" => 61,
+    "keyword a" => 3,
+    "keyword and" => 26,
+    "keyword the" => 9,
+    "keyword to" => 30,
+    "kind and" => 8,
+    "kind to" => 10,
+    "knew the" => 12,
+    "know a" => 13,
+    "know and" => 13,
+    "know for" => 10,
+    "know in" => 12,
+    "know is" => 14,
+    "know of" => 24,
+    "know that" => 160,
+    "know the" => 105,
+    "know this" => 10,
+    "know to" => 3,
+    "known and" => 11,
+    "known in" => 14,
+    "known the" => 7,
+    "known to" => 104,
+    "language a" => 3,
+    "language and" => 66,
+    "language for" => 38,
+    "language in" => 21,
+    "language is" => 34,
+    "language of" => 27,
+    "language that" => 33,
+    "language the" => 12,
+    "language this" => 3,
+    "language to" => 34,
+    "language with" => 19,
+    "languages a" => 3,
+    "languages the" => 7,
+    "languages to" => 4,
+    "large a" => 5,
+    "large and" => 15,
+    "large in" => 6,
+    "large the" => 3,
+    "large to" => 11,
+    "larger the" => 4,
+    "last a" => 5,
+    "last and" => 17,
+    "last as" => 3,
+    "last in" => 18,
+    "last is" => 3,
+    "last it" => 3,
+    "last of" => 10,
+    "last on" => 3,
+    "last or" => 4,
+    "last the" => 18,
+    "last to" => 8,
+    "last when" => 3,
+    "last with" => 8,
+    "later a" => 3,
+    "later and" => 19,
+    "later in" => 40,
+    "later is" => 17,
+    "later the" => 20,
+    "later to" => 8,
+    "latin to" => 5,
+    "layout and" => 25,
+    "layout in" => 4,
+    "layout is" => 25,
+    "layout of" => 95,
+    "layout the" => 3,
+    "layout to" => 4,
+    "lead the" => 4,
+    "leak the" => 6,
+    "learn the" => 12,
+    "least a" => 34,
+    "least and" => 5,
+    "least for" => 10,
+    "least in" => 20,
+    "least the" => 51,
+    "least to" => 4,
+    "leave the" => 67,
+    "leaving the" => 58,
+    "left a" => 15,
+    "left and" => 47,
+    "left for" => 7,
+    "left in" => 24,
+    "left is" => 8,
+    "left of" => 26,
+    "left the" => 60,
+    "left this" => 4,
+    "left to" => 130,
+    "length and" => 59,
+    "length for" => 11,
+    "length in" => 18,
+    "length is" => 87,
+    "length of" => 361,
+    "length the" => 4,
+    "length this" => 12,
+    "length to" => 14,
+    "less a" => 3,
+    "less and" => 9,
+    "less for" => 3,
+    "less in" => 3,
+    "less of" => 4,
+    "less to" => 3,
+    "let a" => 10,
+    "let and" => 5,
+    "let for" => 3,
+    "let the" => 66,
+    "lets the" => 13,
+    "letter a" => 36,
+    "letter and" => 14,
+    "letter in" => 6,
+    "letter is" => 9,
+    "letter of" => 14,
+    "letter to" => 9,
+    "level and" => 49,
+    "level as" => 7,
+    "level for" => 18,
+    "level in" => 17,
+    "level is" => 29,
+    "level it" => 6,
+    "level not" => 3,
+    "level of" => 137,
+    "level that" => 7,
+    "level the" => 13,
+    "level to" => 23,
+    "level with" => 8,
+    "level you" => 3,
+    "libraries and" => 73,
+    "libraries for" => 34,
+    "libraries in" => 26,
+    "libraries is" => 14,
+    "libraries the" => 4,
+    "libraries to" => 24,
+    "library a" => 6,
+    "library an" => 5,
+    "library and" => 201,
+    "library are" => 82,
+    "library as" => 48,
+    "library at" => 17,
+    "library be" => 3,
+    "library but" => 27,
+    "library by" => 49,
+    "library can" => 80,
+    "library code" => 14,
+    "library crate" => 53,
+    "library documentation" => 24,
+    "library file" => 17,
+    "library files" => 3,
+    "library for" => 493,
+    "library from" => 21,
+    "library function" => 7,
+    "library have" => 5,
+    "library i" => 3,
+    "library if" => 16,
+    "library in" => 95,
+    "library is" => 426,
+    "library it" => 15,
+    "library license" => 4,
+    "library more" => 5,
+    "library not" => 5,
+    "library of" => 80,
+    "library on" => 36,
+    "library one" => 3,
+    "library only" => 22,
+    "library or" => 53,
+    "library see" => 11,
+    "library set" => 3,
+    "library that" => 173,
+    "library the" => 26,
+    "library this" => 13,
+    "library to" => 184,
+    "library type" => 4,
+    "library use" => 8,
+    "library used" => 11,
+    "library using" => 28,
+    "library version" => 9,
+    "library was" => 24,
+    "library when" => 9,
+    "library which" => 69,
+    "library will" => 53,
+    "library with" => 93,
+    "library you" => 37,
+    "library your" => 3,
+    "license a" => 4,
+    "license and" => 143,
+    "license as" => 15,
+    "license at" => 222,
+    "license by" => 3,
+    "license crate" => 4,
+    "license file" => 90,
+    "license files" => 10,
+    "license for" => 57,
+    "license from" => 3,
+    "license in" => 3,
+    "license is" => 27,
+    "license license" => 908,
+    "license of" => 12,
+    "license or" => 76,
+    "license rust" => 3,
+    "license same" => 3,
+    "license see" => 44,
+    "license that" => 3,
+    "license the" => 13,
+    "license this" => 37,
+    "license version" => 1303,
+    "license when" => 6,
+    "license with" => 17,
+    "license without" => 10,
+    "license you" => 6,
+    "license your" => 3,
+    "licensed and" => 6,
+    "licensed as" => 1125,
+    "licensed by" => 3,
+    "licensed to" => 19,
+    "life the" => 4,
+    "life to" => 9,
+    "lifetime and" => 18,
+    "lifetime for" => 9,
+    "lifetime in" => 12,
+    "lifetime is" => 42,
+    "lifetime of" => 126,
+    "lifetime to" => 18,
+    "lifetimes the" => 5,
+    "light the" => 3,
+    "like a" => 410,
+    "like all" => 26,
+    "like an" => 80,
+    "like and" => 191,
+    "like any" => 42,
+    "like are" => 17,
+    "like as" => 10,
+    "like at" => 6,
+    "like but" => 113,
+    "like can" => 8,
+    "like code" => 9,
+    "like file" => 3,
+    "like files" => 4,
+    "like for" => 45,
+    "like function" => 15,
+    "like i" => 12,
+    "like if" => 9,
+    "like in" => 107,
+    "like is" => 23,
+    "like it" => 87,
+    "like may" => 3,
+    "like more" => 3,
+    "like no" => 4,
+    "like of" => 4,
+    "like on" => 16,
+    "like one" => 16,
+    "like only" => 3,
+    "like or" => 115,
+    "like rust" => 14,
+    "like that" => 85,
+    "like the" => 347,
+    "like this" => 781,
+    "like to" => 369,
+    "like type" => 8,
+    "like use" => 5,
+    "like using" => 25,
+    "like vim" => 6,
+    "like was" => 3,
+    "like we" => 7,
+    "like when" => 22,
+    "like which" => 13,
+    "like will" => 15,
+    "like with" => 78,
+    "like you" => 51,
+    "like your" => 11,
+    "likely a" => 7,
+    "likely the" => 8,
+    "likely to" => 135,
+    "limit the" => 72,
+    "limitations the" => 4,
+    "line a" => 20,
+    "line add" => 3,
+    "line also" => 8,
+    "line and" => 144,
+    "line are" => 6,
+    "line as" => 31,
+    "line at" => 48,
+    "line but" => 14,
+    "line by" => 32,
+    "line can" => 24,
+    "line code" => 4,
+    "line file" => 7,
+    "line for" => 59,
+    "line from" => 31,
+    "line function" => 7,
+    "line i" => 3,
+    "line if" => 28,
+    "line in" => 173,
+    "line is" => 167,
+    "line it" => 26,
+    "line may" => 12,
+    "line no" => 7,
+    "line not" => 18,
+    "line of" => 216,
+    "line on" => 20,
+    "line one" => 5,
+    "line only" => 16,
+    "line or" => 50,
+    "line see" => 3,
+    "line test" => 3,
+    "line that" => 86,
+    "line the" => 57,
+    "line this" => 6,
+    "line to" => 151,
+    "line type" => 3,
+    "line use" => 15,
+    "line used" => 4,
+    "line using" => 6,
+    "line vim" => 3,
+    "line was" => 26,
+    "line we" => 7,
+    "line when" => 49,
+    "line which" => 11,
+    "line will" => 33,
+    "line with" => 122,
+    "line you" => 21,
+    "lines a" => 5,
+    "lines and" => 68,
+    "lines are" => 81,
+    "lines as" => 9,
+    "lines for" => 33,
+    "lines in" => 134,
+    "lines is" => 23,
+    "lines not" => 16,
+    "lines of" => 117,
+    "lines on" => 9,
+    "lines or" => 17,
+    "lines that" => 75,
+    "lines the" => 21,
+    "lines this" => 3,
+    "lines to" => 110,
+    "lines with" => 48,
+    "lines you" => 12,
+    "link a" => 11,
+    "link and" => 15,
+    "link for" => 13,
+    "link in" => 14,
+    "link is" => 20,
+    "link it" => 22,
+    "link that" => 6,
+    "link the" => 32,
+    "link this" => 6,
+    "link to" => 161,
+    "link with" => 19,
+    "linking the" => 11,
+    "links the" => 7,
+    "lint and" => 5,
+    "lint in" => 9,
+    "lint is" => 93,
+    "lint to" => 54,
+    "list a" => 8,
+    "list an" => 5,
+    "list and" => 91,
+    "list are" => 14,
+    "list as" => 16,
+    "list at" => 5,
+    "list by" => 12,
+    "list can" => 29,
+    "list files" => 3,
+    "list for" => 60,
+    "list from" => 16,
+    "list if" => 16,
+    "list in" => 78,
+    "list is" => 151,
+    "list it" => 14,
+    "list not" => 4,
+    "list of" => 1271,
+    "list or" => 66,
+    "list that" => 25,
+    "list the" => 88,
+    "list to" => 39,
+    "list use" => 6,
+    "list using" => 15,
+    "list when" => 11,
+    "list which" => 10,
+    "list will" => 16,
+    "list with" => 61,
+    "list you" => 10,
+    "listed the" => 4,
+    "listing a" => 8,
+    "listing and" => 20,
+    "listing for" => 5,
+    "listing in" => 6,
+    "listing is" => 16,
+    "listing of" => 20,
+    "listing the" => 26,
+    "listing to" => 30,
+    "lists the" => 43,
+    "literal and" => 13,
+    "literal in" => 6,
+    "literal is" => 25,
+    "literal of" => 3,
+    "literal the" => 5,
+    "literal to" => 3,
+    "little and" => 29,
+    "little in" => 6,
+    "little of" => 16,
+    "little the" => 4,
+    "little to" => 18,
+    "load a" => 65,
+    "load and" => 16,
+    "load of" => 7,
+    "load the" => 93,
+    "load to" => 4,
+    "loaded the" => 8,
+    "loading the" => 59,
+    "local and" => 27,
+    "local for" => 3,
+    "local to" => 111,
+    "location a" => 7,
+    "location and" => 17,
+    "location in" => 39,
+    "location is" => 21,
+    "location of" => 97,
+    "location to" => 21,
+    "lock a" => 13,
+    "lock and" => 11,
+    "lock of" => 3,
+    "lock the" => 20,
+    "lock to" => 11,
+    "log a" => 4,
+    "log the" => 9,
+    "log to" => 7,
+    "logging the" => 4,
+    "long a" => 10,
+    "long and" => 40,
+    "long for" => 7,
+    "long in" => 5,
+    "long is" => 3,
+    "long it" => 14,
+    "long that" => 6,
+    "long the" => 15,
+    "long to" => 26,
+    "long with" => 23,
+    "longer a" => 9,
+    "longer and" => 10,
+    "longer for" => 7,
+    "longer in" => 9,
+    "longer is" => 6,
+    "longer of" => 5,
+    "longer the" => 7,
+    "longer to" => 16,
+    "look a" => 10,
+    "look and" => 16,
+    "look for" => 102,
+    "look in" => 52,
+    "look of" => 18,
+    "look the" => 7,
+    "look to" => 9,
+    "looking a" => 3,
+    "looking to" => 20,
+    "looks a" => 6,
+    "looks the" => 4,
+    "lookup the" => 8,
+    "loop and" => 29,
+    "loop for" => 14,
+    "loop in" => 22,
+    "loop is" => 27,
+    "loop of" => 8,
+    "loop the" => 8,
+    "loop to" => 34,
+    "lost the" => 17,
+    "lot to" => 6,
+    "low and" => 9,
+    "lower and" => 16,
+    "lower in" => 3,
+    "lower is" => 4,
+    "lower the" => 5,
+    "lower to" => 5,
+    "mac to" => 13,
+    "machine the" => 4,
+    "machine to" => 13,
+    "macro and" => 51,
+    "macro are" => 9,
+    "macro as" => 20,
+    "macro by" => 9,
+    "macro can" => 45,
+    "macro for" => 107,
+    "macro if" => 5,
+    "macro in" => 34,
+    "macro is" => 115,
+    "macro it" => 7,
+    "macro on" => 8,
+    "macro or" => 24,
+    "macro that" => 78,
+    "macro the" => 14,
+    "macro this" => 5,
+    "macro to" => 139,
+    "macro use" => 38,
+    "macro with" => 21,
+    "macro you" => 11,
+    "macros and" => 66,
+    "macros for" => 123,
+    "macros in" => 40,
+    "macros is" => 8,
+    "macros of" => 3,
+    "macros that" => 46,
+    "macros the" => 7,
+    "macros to" => 76,
+    "macros with" => 9,
+    "made a" => 95,
+    "made and" => 25,
+    "made for" => 37,
+    "made in" => 35,
+    "made of" => 38,
+    "made that" => 11,
+    "made the" => 78,
+    "made this" => 14,
+    "made to" => 191,
+    "made with" => 28,
+    "main and" => 8,
+    "main in" => 3,
+    "make a" => 411,
+    "make all" => 171,
+    "make an" => 40,
+    "make and" => 11,
+    "make any" => 46,
+    "make are" => 5,
+    "make as" => 8,
+    "make but" => 3,
+    "make file" => 5,
+    "make for" => 7,
+    "make from" => 4,
+    "make if" => 3,
+    "make in" => 8,
+    "make is" => 4,
+    "make it" => 464,
+    "make of" => 8,
+    "make or" => 8,
+    "make rust" => 6,
+    "make test" => 30,
+    "make that" => 25,
+    "make the" => 471,
+    "make this" => 93,
+    "make to" => 18,
+    "make type" => 5,
+    "make use" => 84,
+    "make using" => 7,
+    "make vim" => 53,
+    "make you" => 22,
+    "make your" => 75,
+    "makes a" => 94,
+    "makes and" => 4,
+    "makes for" => 9,
+    "makes the" => 229,
+    "makes this" => 23,
+    "making a" => 140,
+    "making of" => 5,
+    "making the" => 87,
+    "man a" => 6,
+    "man and" => 44,
+    "man in" => 29,
+    "man is" => 9,
+    "man of" => 30,
+    "man the" => 4,
+    "man to" => 9,
+    "manually the" => 3,
+    "manually to" => 5,
+    "many a" => 12,
+    "many and" => 6,
+    "many are" => 3,
+    "many as" => 20,
+    "many in" => 3,
+    "many of" => 118,
+    "many to" => 3,
+    "map a" => 15,
+    "map and" => 36,
+    "map for" => 11,
+    "map in" => 19,
+    "map is" => 16,
+    "map of" => 19,
+    "map the" => 18,
+    "map to" => 51,
+    "mapping a" => 5,
+    "mapping and" => 16,
+    "mapping in" => 17,
+    "mapping is" => 46,
+    "mapping of" => 31,
+    "mapping the" => 13,
+    "mapping to" => 38,
+    "mappings to" => 24,
+    "maps the" => 22,
+    "mark a" => 35,
+    "mark and" => 7,
+    "mark of" => 9,
+    "mark the" => 74,
+    "mark to" => 7,
+    "marked the" => 8,
+    "marks the" => 17,
+    "mask a" => 22,
+    "mask and" => 10,
+    "mask for" => 6,
+    "mask in" => 3,
+    "mask is" => 15,
+    "mask of" => 13,
+    "mask that" => 3,
+    "mask to" => 9,
+    "mask with" => 36,
+    "master the" => 4,
+    "match a" => 74,
+    "match and" => 58,
+    "match as" => 8,
+    "match for" => 33,
+    "match if" => 9,
+    "match in" => 41,
+    "match is" => 60,
+    "match it" => 9,
+    "match not" => 4,
+    "match of" => 17,
+    "match on" => 28,
+    "match or" => 22,
+    "match that" => 9,
+    "match the" => 258,
+    "match this" => 11,
+    "match to" => 12,
+    "match with" => 61,
+    "match you" => 14,
+    "matched the" => 13,
+    "matches a" => 56,
+    "matches and" => 24,
+    "matches for" => 28,
+    "matches in" => 36,
+    "matches is" => 8,
+    "matches of" => 20,
+    "matches the" => 162,
+    "matches to" => 11,
+    "matching a" => 43,
+    "matching and" => 15,
+    "matching in" => 4,
+    "matching is" => 18,
+    "matching the" => 59,
+    "matching to" => 8,
+    "matrix the" => 3,
+    "matter the" => 7,
+    "maximum is" => 3,
+    "maximum of" => 66,
+    "maximum the" => 4,
+    "may add" => 26,
+    "may also" => 435,
+    "may as" => 6,
+    "may be" => 2316,
+    "may by" => 9,
+    "may have" => 262,
+    "may i" => 12,
+    "may in" => 10,
+    "may no" => 5,
+    "may not" => 549,
+    "may of" => 3,
+    "may only" => 87,
+    "may or" => 70,
+    "may see" => 16,
+    "may set" => 7,
+    "may then" => 17,
+    "may there" => 3,
+    "may use" => 142,
+    "me a" => 75,
+    "me an" => 9,
+    "me and" => 185,
+    "me are" => 4,
+    "me as" => 50,
+    "me by" => 23,
+    "me for" => 49,
+    "me if" => 13,
+    "me in" => 76,
+    "me it" => 8,
+    "me of" => 23,
+    "me on" => 39,
+    "me or" => 7,
+    "me that" => 90,
+    "me the" => 62,
+    "me this" => 5,
+    "me to" => 215,
+    "me when" => 13,
+    "me with" => 86,
+    "me you" => 5,
+    "mean the" => 20,
+    "meaning the" => 22,
+    "means a" => 14,
+    "means and" => 7,
+    "means for" => 8,
+    "means in" => 20,
+    "means is" => 8,
+    "means of" => 65,
+    "means that" => 686,
+    "means the" => 112,
+    "means this" => 16,
+    "means to" => 36,
+    "meant the" => 4,
+    "mechanism the" => 3,
+    "memory a" => 3,
+    "memory and" => 93,
+    "memory are" => 3,
+    "memory as" => 36,
+    "memory by" => 21,
+    "memory can" => 7,
+    "memory file" => 7,
+    "memory for" => 48,
+    "memory from" => 9,
+    "memory if" => 8,
+    "memory in" => 95,
+    "memory is" => 70,
+    "memory it" => 14,
+    "memory not" => 3,
+    "memory of" => 31,
+    "memory on" => 27,
+    "memory or" => 29,
+    "memory that" => 63,
+    "memory the" => 23,
+    "memory to" => 31,
+    "memory use" => 19,
+    "memory using" => 63,
+    "memory when" => 117,
+    "memory which" => 17,
+    "memory will" => 15,
+    "memory with" => 41,
+    "memory you" => 5,
+    "mentioned the" => 8,
+    "menu and" => 30,
+    "menu for" => 40,
+    "menu in" => 10,
+    "menu is" => 56,
+    "menu the" => 7,
+    "menu to" => 22,
+    "menus the" => 4,
+    "merge the" => 23,
+    "message and" => 86,
+    "message as" => 11,
+    "message by" => 5,
+    "message can" => 20,
+    "message files" => 6,
+    "message for" => 112,
+    "message if" => 30,
+    "message in" => 48,
+    "message is" => 153,
+    "message it" => 4,
+    "message not" => 10,
+    "message of" => 11,
+    "message on" => 24,
+    "message or" => 18,
+    "message that" => 44,
+    "message the" => 11,
+    "message to" => 95,
+    "message when" => 91,
+    "message with" => 26,
+    "message you" => 12,
+    "messages and" => 49,
+    "messages for" => 34,
+    "messages in" => 39,
+    "messages is" => 16,
+    "messages of" => 9,
+    "messages that" => 22,
+    "messages the" => 7,
+    "messages this" => 3,
+    "messages to" => 99,
+    "metadata the" => 3,
+    "metadata to" => 16,
+    "method a" => 4,
+    "method and" => 78,
+    "method are" => 10,
+    "method as" => 22,
+    "method by" => 6,
+    "method can" => 84,
+    "method for" => 113,
+    "method from" => 17,
+    "method if" => 9,
+    "method in" => 28,
+    "method is" => 437,
+    "method it" => 7,
+    "method not" => 7,
+    "method of" => 75,
+    "method on" => 273,
+    "method or" => 22,
+    "method that" => 94,
+    "method the" => 26,
+    "method to" => 172,
+    "method using" => 10,
+    "method when" => 10,
+    "method will" => 157,
+    "method with" => 48,
+    "method you" => 30,
+    "methods and" => 86,
+    "methods as" => 8,
+    "methods for" => 101,
+    "methods in" => 34,
+    "methods is" => 13,
+    "methods it" => 3,
+    "methods not" => 3,
+    "methods of" => 121,
+    "methods that" => 78,
+    "methods the" => 10,
+    "methods this" => 3,
+    "methods to" => 115,
+    "methods with" => 28,
+    "methods you" => 7,
+    "metrics the" => 6,
+    "might a" => 4,
+    "might and" => 5,
+    "might as" => 17,
+    "might be" => 466,
+    "might in" => 4,
+    "might not" => 195,
+    "might or" => 3,
+    "might with" => 3,
+    "mind the" => 13,
+    "minimum and" => 13,
+    "minimum is" => 3,
+    "minimum of" => 55,
+    "minimum the" => 4,
+    "minimum with" => 5,
+    "minutes the" => 8,
+    "missing a" => 14,
+    "missing and" => 5,
+    "missing in" => 24,
+    "missing the" => 12,
+    "mit and" => 62,
+    "mit files" => 3,
+    "mit for" => 73,
+    "mit is" => 4,
+    "mit or" => 894,
+    "mode a" => 12,
+    "mode an" => 3,
+    "mode and" => 137,
+    "mode are" => 7,
+    "mode as" => 14,
+    "mode by" => 27,
+    "mode can" => 16,
+    "mode for" => 42,
+    "mode from" => 14,
+    "mode if" => 11,
+    "mode in" => 41,
+    "mode is" => 139,
+    "mode it" => 11,
+    "mode not" => 15,
+    "mode of" => 26,
+    "mode on" => 16,
+    "mode or" => 54,
+    "mode that" => 10,
+    "mode the" => 90,
+    "mode this" => 8,
+    "mode to" => 75,
+    "mode use" => 22,
+    "mode using" => 12,
+    "mode vim" => 12,
+    "mode when" => 68,
+    "mode will" => 15,
+    "mode with" => 79,
+    "mode you" => 29,
+    "model a" => 4,
+    "model the" => 13,
+    "model to" => 14,
+    "modified the" => 9,
+    "modifier the" => 3,
+    "modify the" => 97,
+    "module a" => 8,
+    "module an" => 5,
+    "module and" => 68,
+    "module are" => 26,
+    "module as" => 28,
+    "module by" => 7,
+    "module can" => 20,
+    "module files" => 6,
+    "module for" => 66,
+    "module if" => 3,
+    "module in" => 45,
+    "module is" => 161,
+    "module it" => 5,
+    "module not" => 6,
+    "module of" => 21,
+    "module or" => 47,
+    "module that" => 43,
+    "module the" => 24,
+    "module to" => 50,
+    "module when" => 7,
+    "module with" => 46,
+    "module you" => 4,
+    "modules and" => 65,
+    "modules in" => 19,
+    "modules is" => 5,
+    "modules the" => 3,
+    "modules to" => 14,
+    "moment the" => 38,
+    "more a" => 5,
+    "more and" => 51,
+    "more are" => 3,
+    "more as" => 7,
+    "more at" => 12,
+    "more but" => 5,
+    "more by" => 3,
+    "more can" => 5,
+    "more code" => 18,
+    "more command" => 5,
+    "more documentation" => 19,
+    "more error" => 20,
+    "more example" => 3,
+    "more file" => 9,
+    "more files" => 20,
+    "more for" => 14,
+    "more from" => 3,
+    "more i" => 8,
+    "more if" => 3,
+    "more in" => 50,
+    "more into" => 3,
+    "more is" => 11,
+    "more it" => 3,
+    "more like" => 22,
+    "more line" => 4,
+    "more of" => 77,
+    "more on" => 55,
+    "more one" => 3,
+    "more or" => 25,
+    "more rust" => 3,
+    "more see" => 14,
+    "more so" => 11,
+    "more test" => 69,
+    "more that" => 5,
+    "more the" => 13,
+    "more to" => 38,
+    "more type" => 19,
+    "more use" => 6,
+    "more with" => 8,
+    "morning the" => 9,
+    "most a" => 5,
+    "most if" => 3,
+    "most in" => 3,
+    "most of" => 312,
+    "mostly the" => 10,
+    "mouse a" => 4,
+    "mouse and" => 19,
+    "mouse in" => 25,
+    "mouse is" => 24,
+    "mouse to" => 27,
+    "move a" => 30,
+    "move and" => 6,
+    "move in" => 10,
+    "move is" => 5,
+    "move of" => 5,
+    "move that" => 4,
+    "move the" => 332,
+    "move to" => 148,
+    "move with" => 5,
+    "moved a" => 3,
+    "moved the" => 38,
+    "moved to" => 90,
+    "moves the" => 65,
+    "moving the" => 99,
+    "much a" => 9,
+    "much and" => 4,
+    "much for" => 16,
+    "much in" => 13,
+    "much is" => 8,
+    "much of" => 76,
+    "much that" => 4,
+    "much the" => 23,
+    "much to" => 21,
+    "multiple of" => 71,
+    "multiple with" => 3,
+    "multiply the" => 157,
+    "must a" => 4,
+    "must all" => 10,
+    "must at" => 8,
+    "must be" => 2111,
+    "must i" => 6,
+    "must not" => 335,
+    "must use" => 127,
+    "mutable and" => 12,
+    "mutable the" => 3,
+    "mutable to" => 3,
+    "my solution" => 3,
+    "my will" => 3,
+    "myself the" => 3,
+    "name a" => 19,
+    "name all" => 8,
+    "name an" => 4,
+    "name and" => 210,
+    "name are" => 9,
+    "name as" => 93,
+    "name at" => 6,
+    "name but" => 27,
+    "name by" => 15,
+    "name can" => 40,
+    "name file" => 5,
+    "name for" => 190,
+    "name from" => 28,
+    "name function" => 8,
+    "name if" => 24,
+    "name in" => 144,
+    "name is" => 361,
+    "name it" => 19,
+    "name not" => 9,
+    "name of" => 704,
+    "name on" => 46,
+    "name one" => 3,
+    "name only" => 9,
+    "name or" => 72,
+    "name set" => 3,
+    "name that" => 73,
+    "name the" => 65,
+    "name this" => 8,
+    "name to" => 115,
+    "name type" => 15,
+    "name use" => 9,
+    "name used" => 17,
+    "name using" => 10,
+    "name value" => 43,
+    "name vim" => 14,
+    "name was" => 41,
+    "name when" => 37,
+    "name which" => 25,
+    "name will" => 38,
+    "name with" => 63,
+    "name you" => 29,
+    "name your" => 3,
+    "named and" => 41,
+    "named in" => 27,
+    "named is" => 4,
+    "named of" => 5,
+    "named the" => 8,
+    "named to" => 6,
+    "names a" => 5,
+    "names and" => 96,
+    "names for" => 50,
+    "names in" => 70,
+    "names is" => 12,
+    "names it" => 4,
+    "names of" => 100,
+    "names that" => 47,
+    "names the" => 12,
+    "names to" => 51,
+    "names with" => 35,
+    "names you" => 3,
+    "namespace the" => 3,
+    "nan the" => 6,
+    "native and" => 12,
+    "native to" => 7,
+    "near the" => 72,
+    "necessary and" => 18,
+    "necessary the" => 5,
+    "necessary to" => 217,
+    "need a" => 200,
+    "need an" => 48,
+    "need and" => 16,
+    "need are" => 3,
+    "need be" => 3,
+    "need for" => 122,
+    "need if" => 3,
+    "need in" => 17,
+    "need is" => 11,
+    "need it" => 42,
+    "need not" => 36,
+    "need of" => 25,
+    "need on" => 3,
+    "need that" => 8,
+    "need the" => 79,
+    "need this" => 20,
+    "need to" => 2529,
+    "need with" => 3,
+    "need you" => 3,
+    "needed a" => 3,
+    "needed and" => 28,
+    "needed for" => 78,
+    "needed in" => 27,
+    "needed is" => 7,
+    "needed the" => 11,
+    "needed to" => 112,
+    "needs a" => 32,
+    "needs and" => 8,
+    "needs for" => 4,
+    "needs in" => 4,
+    "needs is" => 5,
+    "needs of" => 27,
+    "needs the" => 17,
+    "needs to" => 591,
+    "negative the" => 4,
+    "neither the" => 15,
+    "network a" => 4,
+    "network and" => 41,
+    "network of" => 3,
+    "network to" => 8,
+    "never a" => 12,
+    "never be" => 100,
+    "never in" => 4,
+    "never the" => 4,
+    "never to" => 19,
+    "new and" => 59,
+    "new as" => 3,
+    "new crate" => 20,
+    "new file" => 115,
+    "new files" => 18,
+    "new for" => 16,
+    "new from" => 32,
+    "new function" => 53,
+    "new i" => 6,
+    "new in" => 66,
+    "new is" => 12,
+    "new it" => 3,
+    "new of" => 3,
+    "new or" => 11,
+    "new patch" => 36,
+    "new rust" => 20,
+    "new test" => 19,
+    "new that" => 16,
+    "new the" => 5,
+    "new to" => 27,
+    "new type" => 38,
+    "new vim" => 8,
+    "new which" => 8,
+    "new will" => 5,
+    "new with" => 49,
+    "next a" => 5,
+    "next and" => 19,
+    "next be" => 3,
+    "next for" => 3,
+    "next in" => 5,
+    "next is" => 5,
+    "next it" => 4,
+    "next the" => 13,
+    "next to" => 87,
+    "next you" => 37,
+    "night the" => 15,
+    "nightly and" => 11,
+    "no a" => 5,
+    "no and" => 8,
+    "no but" => 11,
+    "no code" => 23,
+    "no command" => 18,
+    "no crate" => 3,
+    "no default" => 41,
+    "no documentation" => 5,
+    "no error" => 138,
+    "no example" => 3,
+    "no file" => 35,
+    "no files" => 3,
+    "no function" => 8,
+    "no i" => 31,
+    "no in" => 8,
+    "no is" => 15,
+    "no it" => 9,
+    "no license" => 3,
+    "no like" => 3,
+    "no line" => 24,
+    "no more" => 145,
+    "no no" => 113,
+    "no not" => 7,
+    "no one" => 82,
+    "no or" => 16,
+    "no other" => 114,
+    "no problem" => 10,
+    "no rust" => 4,
+    "no so" => 3,
+    "no solution" => 3,
+    "no test" => 147,
+    "no that" => 3,
+    "no the" => 3,
+    "no to" => 4,
+    "no type" => 17,
+    "no use" => 34,
+    "no value" => 39,
+    "no version" => 7,
+    "no vim" => 3,
+    "no we" => 3,
+    "no you" => 7,
+    "node and" => 14,
+    "node for" => 4,
+    "node in" => 3,
+    "node is" => 12,
+    "node of" => 3,
+    "node that" => 4,
+    "node the" => 3,
+    "node to" => 8,
+    "node with" => 5,
+    "non is" => 5,
+    "none and" => 8,
+    "none is" => 11,
+    "none of" => 131,
+    "none the" => 7,
+    "none to" => 8,
+    "nor the" => 20,
+    "normal and" => 30,
+    "normal for" => 5,
+    "normal in" => 4,
+    "normal that" => 4,
+    "normal to" => 3,
+    "normally the" => 28,
+    "not a" => 639,
+    "not access" => 14,
+    "not add" => 27,
+    "not added" => 3,
+    "not after" => 7,
+    "not all" => 239,
+    "not also" => 8,
+    "not always" => 224,
+    "not an" => 155,
+    "not and" => 31,
+    "not another" => 5,
+    "not any" => 15,
+    "not are" => 3,
+    "not as" => 73,
+    "not at" => 68,
+    "not available" => 261,
+    "not be" => 1449,
+    "not because" => 16,
+    "not been" => 181,
+    "not before" => 8,
+    "not being" => 81,
+    "not block" => 20,
+    "not both" => 8,
+    "not build" => 31,
+    "not but" => 17,
+    "not by" => 25,
+    "not byte" => 3,
+    "not bytes" => 3,
+    "not call" => 37,
+    "not called" => 14,
+    "not case" => 5,
+    "not change" => 90,
+    "not characters" => 6,
+    "not check" => 67,
+    "not compile" => 46,
+    "not could" => 5,
+    "not create" => 28,
+    "not current" => 4,
+    "not defined" => 79,
+    "not do" => 50,
+    "not end" => 24,
+    "not error" => 3,
+    "not even" => 49,
+    "not following" => 4,
+    "not for" => 79,
+    "not from" => 9,
+    "not function" => 4,
+    "not get" => 67,
+    "not given" => 37,
+    "not had" => 6,
+    "not have" => 528,
+    "not he" => 5,
+    "not here" => 5,
+    "not how" => 3,
+    "not however" => 8,
+    "not i" => 14,
+    "not if" => 7,
+    "not in" => 282,
+    "not is" => 6,
+    "not it" => 22,
+    "not its" => 3,
+    "not just" => 86,
+    "not last" => 4,
+    "not like" => 28,
+    "not list" => 5,
+    "not make" => 63,
+    "not many" => 12,
+    "not match" => 122,
+    "not more" => 22,
+    "not my" => 7,
+    "not need" => 263,
+    "not new" => 3,
+    "not now" => 6,
+    "not of" => 65,
+    "not on" => 49,
+    "not one" => 20,
+    "not only" => 137,
+    "not or" => 17,
+    "not otherwise" => 12,
+    "not out" => 3,
+    "not output" => 8,
+    "not over" => 7,
+    "not patch" => 3,
+    "not point" => 15,
+    "not possible" => 199,
+    "not read" => 26,
+    "not reference" => 4,
+    "not result" => 29,
+    "not return" => 73,
+    "not returned" => 6,
+    "not right" => 6,
+    "not run" => 41,
+    "not said" => 5,
+    "not see" => 38,
+    "not set" => 1868,
+    "not so" => 51,
+    "not some" => 7,
+    "not specific" => 8,
+    "not specified" => 142,
+    "not standard" => 6,
+    "not start" => 34,
+    "not store" => 11,
+    "not such" => 8,
+    "not support" => 276,
+    "not supported" => 351,
+    "not test" => 9,
+    "not text" => 4,
+    "not that" => 37,
+    "not the" => 399,
+    "not their" => 5,
+    "not then" => 12,
+    "not there" => 27,
+    "not they" => 7,
+    "not this" => 10,
+    "not through" => 6,
+    "not time" => 4,
+    "not to" => 365,
+    "not two" => 8,
+    "not type" => 5,
+    "not under" => 4,
+    "not up" => 5,
+    "not use" => 338,
+    "not used" => 234,
+    "not using" => 130,
+    "not very" => 56,
+    "not want" => 62,
+    "not well" => 30,
+    "not what" => 36,
+    "not when" => 39,
+    "not where" => 4,
+    "not windows" => 12,
+    "not with" => 16,
+    "not without" => 6,
+    "not work" => 633,
+    "not would" => 4,
+    "not write" => 22,
+    "not you" => 21,
+    "not your" => 16,
+    "not zero" => 15,
+    "note and" => 5,
+    "note as" => 6,
+    "note for" => 12,
+    "note from" => 3,
+    "note if" => 9,
+    "note in" => 8,
+    "note is" => 16,
+    "note it" => 6,
+    "note of" => 14,
+    "note on" => 48,
+    "note that" => 1996,
+    "note the" => 81,
+    "note this" => 41,
+    "note to" => 10,
+    "note when" => 4,
+    "notes the" => 5,
+    "nothing and" => 22,
+    "nothing in" => 26,
+    "nothing is" => 42,
+    "nothing of" => 27,
+    "nothing to" => 54,
+    "notice the" => 27,
+    "now a" => 45,
+    "now an" => 10,
+    "now and" => 85,
+    "now as" => 15,
+    "now be" => 62,
+    "now by" => 11,
+    "now can" => 3,
+    "now for" => 25,
+    "now from" => 4,
+    "now if" => 27,
+    "now in" => 48,
+    "now is" => 29,
+    "now it" => 57,
+    "now of" => 6,
+    "now on" => 13,
+    "now or" => 5,
+    "now that" => 131,
+    "now the" => 131,
+    "now this" => 14,
+    "now to" => 31,
+    "now use" => 73,
+    "now using" => 5,
+    "now vim" => 12,
+    "now when" => 27,
+    "now will" => 9,
+    "now with" => 14,
+    "now you" => 167,
+    "null a" => 3,
+    "null and" => 19,
+    "null for" => 4,
+    "null in" => 11,
+    "null is" => 4,
+    "null the" => 15,
+    "null to" => 16,
+    "number a" => 38,
+    "number an" => 7,
+    "number and" => 74,
+    "number are" => 5,
+    "number as" => 22,
+    "number at" => 12,
+    "number but" => 9,
+    "number by" => 8,
+    "number can" => 14,
+    "number for" => 53,
+    "number from" => 12,
+    "number function" => 16,
+    "number if" => 38,
+    "number in" => 137,
+    "number is" => 261,
+    "number it" => 18,
+    "number not" => 4,
+    "number of" => 2412,
+    "number on" => 12,
+    "number one" => 15,
+    "number only" => 4,
+    "number or" => 75,
+    "number set" => 13,
+    "number that" => 23,
+    "number the" => 190,
+    "number to" => 104,
+    "number type" => 16,
+    "number used" => 6,
+    "number using" => 7,
+    "number value" => 7,
+    "number vim" => 7,
+    "number was" => 7,
+    "number when" => 19,
+    "number which" => 26,
+    "number will" => 20,
+    "number with" => 44,
+    "number you" => 6,
+    "numbers and" => 64,
+    "numbers for" => 24,
+    "numbers in" => 189,
+    "numbers is" => 14,
+    "numbers of" => 21,
+    "numbers to" => 25,
+    "object a" => 19,
+    "object an" => 19,
+    "object and" => 64,
+    "object are" => 8,
+    "object as" => 36,
+    "object be" => 4,
+    "object by" => 9,
+    "object can" => 30,
+    "object files" => 28,
+    "object for" => 37,
+    "object from" => 31,
+    "object if" => 21,
+    "object in" => 42,
+    "object is" => 176,
+    "object it" => 22,
+    "object not" => 3,
+    "object of" => 41,
+    "object on" => 17,
+    "object or" => 37,
+    "object that" => 102,
+    "object the" => 66,
+    "object this" => 5,
+    "object to" => 74,
+    "object using" => 12,
+    "object when" => 7,
+    "object will" => 38,
+    "object with" => 85,
+    "object you" => 8,
+    "objects and" => 48,
+    "objects for" => 16,
+    "objects in" => 61,
+    "objects is" => 20,
+    "objects of" => 21,
+    "objects the" => 6,
+    "objects this" => 4,
+    "objects to" => 36,
+    "obtain the" => 74,
+    "occur the" => 5,
+    "occurred the" => 5,
+    "occurs the" => 6,
+    "of a" => 4446,
+    "of about" => 10,
+    "of access" => 10,
+    "of add" => 8,
+    "of adding" => 21,
+    "of additional" => 22,
+    "of address" => 5,
+    "of adds" => 4,
+    "of after" => 13,
+    "of again" => 5,
+    "of algorithm" => 5,
+    "of all" => 560,
+    "of allocation" => 3,
+    "of allowed" => 6,
+    "of always" => 20,
+    "of am" => 17,
+    "of an" => 819,
+    "of and" => 522,
+    "of another" => 107,
+    "of any" => 311,
+    "of anything" => 10,
+    "of apache" => 929,
+    "of apis" => 18,
+    "of application" => 9,
+    "of applications" => 21,
+    "of are" => 76,
+    "of argument" => 14,
+    "of arguments" => 97,
+    "of arm" => 6,
+    "of around" => 5,
+    "of array" => 15,
+    "of as" => 98,
+    "of ascii" => 34,
+    "of associated" => 10,
+    "of at" => 73,
+    "of attribute" => 8,
+    "of attributes" => 21,
+    "of automatically" => 7,
+    "of available" => 43,
+    "of base" => 15,
+    "of based" => 7,
+    "of basic" => 13,
+    "of because" => 9,
+    "of before" => 13,
+    "of behavior" => 6,
+    "of being" => 93,
+    "of below" => 4,
+    "of better" => 3,
+    "of between" => 4,
+    "of binary" => 25,
+    "of bindings" => 12,
+    "of bit" => 122,
+    "of bits" => 204,
+    "of block" => 22,
+    "of body" => 3,
+    "of boolean" => 11,
+    "of both" => 182,
+    "of bound" => 5,
+    "of break" => 4,
+    "of buffer" => 32,
+    "of build" => 10,
+    "of building" => 24,
+    "of built" => 16,
+    "of but" => 29,
+    "of by" => 49,
+    "of byte" => 38,
+    "of bytes" => 513,
+    "of cache" => 11,
+    "of call" => 8,
+    "of called" => 9,
+    "of calling" => 56,
+    "of calls" => 16,
+    "of can" => 34,
+    "of cannot" => 6,
+    "of cargo" => 60,
+    "of case" => 6,
+    "of cases" => 15,
+    "of certain" => 24,
+    "of change" => 11,
+    "of changed" => 9,
+    "of changes" => 46,
+    "of channel" => 6,
+    "of chapter" => 21,
+    "of character" => 29,
+    "of characters" => 123,
+    "of check" => 14,
+    "of checking" => 20,
+    "of checks" => 7,
+    "of class" => 12,
+    "of client" => 9,
+    "of code" => 228,
+    "of color" => 21,
+    "of command" => 35,
+    "of commands" => 51,
+    "of comment" => 12,
+    "of comments" => 12,
+    "of commit" => 3,
+    "of common" => 34,
+    "of compatibility" => 3,
+    "of compatible" => 4,
+    "of compilation" => 6,
+    "of compiled" => 7,
+    "of compiler" => 9,
+    "of complete" => 6,
+    "of completion" => 12,
+    "of complex" => 13,
+    "of conditions" => 9,
+    "of configuration" => 14,
+    "of const" => 14,
+    "of containing" => 6,
+    "of contents" => 104,
+    "of context" => 11,
+    "of contribution" => 5,
+    "of control" => 9,
+    "of conversion" => 9,
+    "of copy" => 4,
+    "of core" => 13,
+    "of correct" => 21,
+    "of corresponding" => 7,
+    "of count" => 3,
+    "of crate" => 27,
+    "of crates" => 51,
+    "of creating" => 47,
+    "of cross" => 8,
+    "of current" => 44,
+    "of currently" => 7,
+    "of cursor" => 12,
+    "of custom" => 23,
+    "of data" => 265,
+    "of day" => 14,
+    "of default" => 12,
+    "of definition" => 3,
+    "of dependencies" => 55,
+    "of dependency" => 12,
+    "of derive" => 5,
+    "of development" => 17,
+    "of did" => 3,
+    "of different" => 106,
+    "of directly" => 12,
+    "of directory" => 12,
+    "of display" => 6,
+    "of do" => 10,
+    "of doc" => 4,
+    "of documentation" => 19,
+    "of does" => 13,
+    "of doesn't" => 4,
+    "of doing" => 32,
+    "of double" => 12,
+    "of each" => 440,
+    "of editing" => 5,
+    "of either" => 81,
+    "of element" => 8,
+    "of elements" => 166,
+    "of empty" => 6,
+    "of enable" => 3,
+    "of enabled" => 10,
+    "of encoding" => 21,
+    "of end" => 3,
+    "of environment" => 24,
+    "of equal" => 12,
+    "of error" => 50,
+    "of errors" => 44,
+    "of etc" => 4,
+    "of even" => 9,
+    "of event" => 18,
+    "of events" => 55,
+    "of every" => 70,
+    "of ex" => 10,
+    "of exactly" => 15,
+    "of example" => 10,
+    "of examples" => 24,
+    "of except" => 8,
+    "of exception" => 12,
+    "of execution" => 18,
+    "of existing" => 24,
+    "of expected" => 11,
+    "of explicitly" => 5,
+    "of expression" => 12,
+    "of expressions" => 37,
+    "of extension" => 6,
+    "of external" => 22,
+    "of extra" => 12,
+    "of fail" => 3,
+    "of fast" => 6,
+    "of feature" => 9,
+    "of features" => 126,
+    "of field" => 11,
+    "of fields" => 39,
+    "of file" => 153,
+    "of files" => 122,
+    "of find" => 3,
+    "of first" => 15,
+    "of fix" => 3,
+    "of fixed" => 13,
+    "of flag" => 3,
+    "of flags" => 24,
+    "of floating" => 33,
+    "of followed" => 6,
+    "of following" => 8,
+    "of for" => 158,
+    "of form" => 5,
+    "of format" => 3,
+    "of free" => 11,
+    "of from" => 42,
+    "of full" => 3,
+    "of function" => 43,
+    "of functionality" => 36,
+    "of functions" => 93,
+    "of future" => 18,
+    "of general" => 9,
+    "of generated" => 10,
+    "of generic" => 33,
+    "of get" => 7,
+    "of getting" => 19,
+    "of git" => 42,
+    "of given" => 3,
+    "of global" => 19,
+    "of go" => 3,
+    "of going" => 20,
+    "of good" => 22,
+    "of group" => 5,
+    "of half" => 19,
+    "of handling" => 21,
+    "of has" => 25,
+    "of hash" => 16,
+    "of have" => 5,
+    "of having" => 52,
+    "of help" => 8,
+    "of her" => 166,
+    "of here" => 3,
+    "of high" => 21,
+    "of highlighting" => 4,
+    "of him" => 70,
+    "of his" => 473,
+    "of host" => 12,
+    "of how" => 214,
+    "of i" => 32,
+    "of identifier" => 8,
+    "of if" => 41,
+    "of image" => 12,
+    "of implementation" => 9,
+    "of implementations" => 11,
+    "of important" => 5,
+    "of in" => 125,
+    "of include" => 5,
+    "of included" => 4,
+    "of including" => 9,
+    "of index" => 10,
+    "of information" => 48,
+    "of input" => 30,
+    "of insert" => 19,
+    "of instead" => 14,
+    "of instruction" => 11,
+    "of instructions" => 22,
+    "of integer" => 63,
+    "of integers" => 89,
+    "of internal" => 13,
+    "of into" => 26,
+    "of invalid" => 9,
+    "of is" => 249,
+    "of issues" => 20,
+    "of it" => 375,
+    "of it's" => 4,
+    "of item" => 14,
+    "of items" => 114,
+    "of iterator" => 6,
+    "of its" => 406,
+    "of itself" => 18,
+    "of just" => 36,
+    "of key" => 41,
+    "of keys" => 32,
+    "of known" => 19,
+    "of language" => 12,
+    "of large" => 12,
+    "of last" => 14,
+    "of latest" => 10,
+    "of length" => 35,
+    "of less" => 3,
+    "of libraries" => 24,
+    "of library" => 34,
+    "of license" => 7,
+    "of lifetime" => 9,
+    "of like" => 6,
+    "of line" => 124,
+    "of lines" => 158,
+    "of lint" => 4,
+    "of list" => 6,
+    "of listing" => 4,
+    "of literal" => 8,
+    "of little" => 20,
+    "of local" => 22,
+    "of location" => 4,
+    "of lock" => 6,
+    "of long" => 19,
+    "of longer" => 5,
+    "of loop" => 9,
+    "of low" => 10,
+    "of macro" => 15,
+    "of macros" => 22,
+    "of main" => 4,
+    "of make" => 5,
+    "of makes" => 6,
+    "of making" => 34,
+    "of man" => 27,
+    "of many" => 44,
+    "of map" => 7,
+    "of mapping" => 10,
+    "of mark" => 4,
+    "of mask" => 5,
+    "of match" => 15,
+    "of matches" => 32,
+    "of matching" => 21,
+    "of may" => 20,
+    "of me" => 40,
+    "of means" => 9,
+    "of memory" => 290,
+    "of message" => 13,
+    "of messages" => 40,
+    "of method" => 23,
+    "of methods" => 50,
+    "of might" => 3,
+    "of missing" => 7,
+    "of mit" => 11,
+    "of module" => 5,
+    "of modules" => 17,
+    "of more" => 30,
+    "of most" => 24,
+    "of mouse" => 9,
+    "of move" => 4,
+    "of much" => 5,
+    "of multiple" => 62,
+    "of must" => 24,
+    "of mutable" => 21,
+    "of my" => 441,
+    "of name" => 17,
+    "of named" => 14,
+    "of names" => 26,
+    "of native" => 22,
+    "of need" => 6,
+    "of network" => 9,
+    "of new" => 61,
+    "of next" => 7,
+    "of no" => 27,
+    "of node" => 94,
+    "of non" => 68,
+    "of none" => 6,
+    "of normal" => 6,
+    "of not" => 26,
+    "of note" => 14,
+    "of nothing" => 9,
+    "of now" => 14,
+    "of null" => 23,
+    "of number" => 14,
+    "of numbers" => 39,
+    "of object" => 23,
+    "of objects" => 78,
+    "of of" => 8,
+    "of off" => 3,
+    "of old" => 22,
+    "of on" => 65,
+    "of once" => 4,
+    "of one" => 208,
+    "of only" => 32,
+    "of open" => 16,
+    "of operation" => 24,
+    "of operations" => 29,
+    "of operator" => 5,
+    "of option" => 9,
+    "of optional" => 7,
+    "of options" => 47,
+    "of or" => 225,
+    "of order" => 15,
+    "of original" => 7,
+    "of other" => 129,
+    "of our" => 202,
+    "of out" => 3,
+    "of output" => 22,
+    "of over" => 5,
+    "of package" => 22,
+    "of packed" => 353,
+    "of page" => 9,
+    "of panic" => 10,
+    "of panics" => 6,
+    "of parameter" => 6,
+    "of parameters" => 16,
+    "of parser" => 6,
+    "of parsing" => 20,
+    "of part" => 5,
+    "of particular" => 7,
+    "of passed" => 4,
+    "of passing" => 17,
+    "of patch" => 5,
+    "of path" => 17,
+    "of paths" => 35,
+    "of pattern" => 17,
+    "of patterns" => 13,
+    "of performance" => 25,
+    "of place" => 3,
+    "of platform" => 8,
+    "of platforms" => 21,
+    "of pointer" => 14,
+    "of pointers" => 39,
+    "of popup" => 18,
+    "of position" => 4,
+    "of possible" => 50,
+    "of precision" => 11,
+    "of previous" => 8,
+    "of print" => 6,
+    "of problems" => 19,
+    "of process" => 8,
+    "of program" => 9,
+    "of project" => 7,
+    "of properties" => 7,
+    "of property" => 8,
+    "of protocol" => 5,
+    "of provided" => 9,
+    "of public" => 26,
+    "of python" => 30,
+    "of range" => 28,
+    "of rather" => 6,
+    "of raw" => 24,
+    "of re" => 4,
+    "of read" => 8,
+    "of reading" => 24,
+    "of reference" => 22,
+    "of references" => 38,
+    "of register" => 21,
+    "of related" => 9,
+    "of release" => 7,
+    "of request" => 10,
+    "of required" => 10,
+    "of requirements" => 8,
+    "of result" => 4,
+    "of results" => 14,
+    "of return" => 9,
+    "of returned" => 15,
+    "of returning" => 26,
+    "of rfc" => 26,
+    "of right" => 5,
+    "of root" => 3,
+    "of rules" => 14,
+    "of running" => 31,
+    "of runtime" => 12,
+    "of rust" => 394,
+    "of safe" => 10,
+    "of safety" => 8,
+    "of said" => 6,
+    "of say" => 5,
+    "of scope" => 149,
+    "of screen" => 25,
+    "of script" => 9,
+    "of search" => 5,
+    "of second" => 8,
+    "of section" => 6,
+    "of see" => 8,
+    "of self" => 28,
+    "of separate" => 4,
+    "of sequence" => 5,
+    "of server" => 9,
+    "of set" => 13,
+    "of sets" => 3,
+    "of setting" => 30,
+    "of several" => 32,
+    "of shared" => 6,
+    "of shell" => 13,
+    "of should" => 15,
+    "of side" => 4,
+    "of signed" => 24,
+    "of similar" => 5,
+    "of simple" => 14,
+    "of since" => 4,
+    "of single" => 19,
+    "of size" => 21,
+    "of slice" => 4,
+    "of small" => 20,
+    "of so" => 44,
+    "of socket" => 11,
+    "of some" => 169,
+    "of something" => 24,
+    "of source" => 24,
+    "of space" => 33,
+    "of special" => 30,
+    "of specific" => 12,
+    "of specified" => 4,
+    "of specify" => 4,
+    "of stable" => 7,
+    "of stack" => 25,
+    "of standard" => 25,
+    "of start" => 3,
+    "of starting" => 5,
+    "of state" => 11,
+    "of statement" => 3,
+    "of static" => 12,
+    "of std" => 12,
+    "of still" => 4,
+    "of stored" => 4,
+    "of stream" => 23,
+    "of string" => 43,
+    "of strings" => 101,
+    "of structure" => 7,
+    "of style" => 9,
+    "of such" => 109,
+    "of support" => 24,
+    "of supported" => 45,
+    "of syntax" => 32,
+    "of system" => 19,
+    "of systems" => 9,
+    "of tab" => 9,
+    "of table" => 4,
+    "of tag" => 5,
+    "of tags" => 20,
+    "of target" => 15,
+    "of targets" => 10,
+    "of term" => 13,
+    "of terminal" => 19,
+    "of test" => 51,
+    "of testing" => 13,
+    "of tests" => 58,
+    "of text" => 164,
+    "of that" => 469,
+    "of the" => 20163,
+    "of their" => 215,
+    "of them" => 383,
+    "of then" => 20,
+    "of there" => 5,
+    "of these" => 766,
+    "of they" => 3,
+    "of things" => 52,
+    "of this" => 2250,
+    "of those" => 251,
+    "of thread" => 14,
+    "of threads" => 46,
+    "of three" => 69,
+    "of thus" => 3,
+    "of time" => 201,
+    "of times" => 108,
+    "of to" => 180,
+    "of token" => 16,
+    "of tom" => 6,
+    "of tools" => 26,
+    "of trait" => 39,
+    "of traits" => 61,
+    "of tree" => 6,
+    "of true" => 6,
+    "of tuple" => 9,
+    "of two" => 371,
+    "of type" => 403,
+    "of types" => 112,
+    "of undefined" => 15,
+    "of underlying" => 5,
+    "of unix" => 8,
+    "of unless" => 3,
+    "of unsafe" => 49,
+    "of unsigned" => 36,
+    "of up" => 10,
+    "of us" => 66,
+    "of usage" => 15,
+    "of use" => 80,
+    "of used" => 13,
+    "of useful" => 23,
+    "of user" => 28,
+    "of users" => 24,
+    "of uses" => 7,
+    "of using" => 249,
+    "of valid" => 16,
+    "of value" => 35,
+    "of values" => 147,
+    "of variable" => 24,
+    "of variables" => 24,
+    "of various" => 62,
+    "of vector" => 9,
+    "of version" => 29,
+    "of versions" => 10,
+    "of very" => 16,
+    "of vim" => 207,
+    "of visual" => 16,
+    "of warning" => 4,
+    "of was" => 6,
+    "of we" => 18,
+    "of we'll" => 3,
+    "of web" => 8,
+    "of well" => 5,
+    "of were" => 3,
+    "of what" => 203,
+    "of when" => 47,
+    "of where" => 51,
+    "of whether" => 84,
+    "of which" => 311,
+    "of while" => 4,
+    "of who" => 7,
+    "of whole" => 5,
+    "of why" => 6,
+    "of will" => 70,
+    "of win" => 4,
+    "of window" => 38,
+    "of windows" => 43,
+    "of with" => 75,
+    "of within" => 6,
+    "of without" => 8,
+    "of word" => 30,
+    "of words" => 35,
+    "of work" => 67,
+    "of working" => 12,
+    "of works" => 5,
+    "of would" => 18,
+    "of write" => 3,
+    "of writing" => 86,
+    "of written" => 8,
+    "of you" => 73,
+    "of your" => 656,
+    "of zero" => 37,
+    "off a" => 19,
+    "off and" => 49,
+    "off for" => 28,
+    "off in" => 29,
+    "off of" => 24,
+    "off that" => 4,
+    "off the" => 133,
+    "off this" => 7,
+    "off to" => 46,
+    "offers the" => 20,
+    "offset a" => 3,
+    "offset the" => 7,
+    "offset to" => 22,
+    "often a" => 18,
+    "often and" => 8,
+    "often for" => 4,
+    "often in" => 10,
+    "often is" => 5,
+    "often the" => 28,
+    "often to" => 4,
+    "oh the" => 3,
+    "old and" => 37,
+    "old is" => 4,
+    "old the" => 3,
+    "old to" => 5,
+    "on a" => 1679,
+    "on add" => 3,
+    "on additional" => 3,
+    "on after" => 3,
+    "on all" => 223,
+    "on an" => 306,
+    "on and" => 185,
+    "on another" => 32,
+    "on any" => 237,
+    "on are" => 11,
+    "on as" => 34,
+    "on at" => 11,
+    "on because" => 4,
+    "on before" => 4,
+    "on being" => 10,
+    "on bit" => 86,
+    "on block" => 3,
+    "on both" => 70,
+    "on build" => 13,
+    "on but" => 19,
+    "on by" => 120,
+    "on bytes" => 7,
+    "on can" => 6,
+    "on cargo" => 7,
+    "on case" => 7,
+    "on change" => 9,
+    "on characters" => 3,
+    "on code" => 15,
+    "on command" => 17,
+    "on compile" => 10,
+    "on compiler" => 7,
+    "on crate" => 13,
+    "on crates" => 227,
+    "on current" => 8,
+    "on data" => 19,
+    "on different" => 39,
+    "on doc" => 4,
+    "on documentation" => 8,
+    "on each" => 151,
+    "on either" => 37,
+    "on end" => 8,
+    "on environment" => 41,
+    "on error" => 33,
+    "on feature" => 4,
+    "on features" => 10,
+    "on file" => 10,
+    "on files" => 7,
+    "on first" => 25,
+    "on floating" => 4,
+    "on for" => 50,
+    "on from" => 9,
+    "on function" => 9,
+    "on functions" => 10,
+    "on has" => 7,
+    "on her" => 48,
+    "on his" => 118,
+    "on how" => 301,
+    "on i" => 13,
+    "on if" => 12,
+    "on implementation" => 4,
+    "on in" => 56,
+    "on instead" => 9,
+    "on integer" => 5,
+    "on integers" => 15,
+    "on is" => 36,
+    "on it" => 230,
+    "on its" => 129,
+    "on just" => 6,
+    "on key" => 5,
+    "on last" => 8,
+    "on like" => 4,
+    "on line" => 79,
+    "on lines" => 5,
+    "on list" => 3,
+    "on many" => 31,
+    "on match" => 6,
+    "on me" => 28,
+    "on memory" => 27,
+    "on message" => 3,
+    "on method" => 4,
+    "on module" => 5,
+    "on more" => 20,
+    "on most" => 63,
+    "on multiple" => 18,
+    "on my" => 104,
+    "on new" => 5,
+    "on no" => 8,
+    "on non" => 92,
+    "on not" => 6,
+    "on now" => 4,
+    "on number" => 4,
+    "on object" => 3,
+    "on of" => 4,
+    "on on" => 13,
+    "on one" => 99,
+    "on only" => 6,
+    "on or" => 53,
+    "on other" => 105,
+    "on out" => 3,
+    "on patch" => 13,
+    "on path" => 3,
+    "on pattern" => 4,
+    "on project" => 7,
+    "on results" => 7,
+    "on runtime" => 5,
+    "on rust" => 96,
+    "on same" => 3,
+    "on see" => 4,
+    "on should" => 4,
+    "on single" => 6,
+    "on so" => 22,
+    "on some" => 216,
+    "on specific" => 8,
+    "on standard" => 7,
+    "on start" => 6,
+    "on state" => 3,
+    "on status" => 4,
+    "on string" => 5,
+    "on such" => 20,
+    "on supported" => 17,
+    "on syntax" => 8,
+    "on system" => 8,
+    "on target" => 11,
+    "on terminal" => 3,
+    "on test" => 6,
+    "on tests" => 3,
+    "on text" => 9,
+    "on that" => 138,
+    "on the" => 5831,
+    "on their" => 114,
+    "on them" => 59,
+    "on then" => 7,
+    "on there" => 3,
+    "on these" => 67,
+    "on this" => 418,
+    "on to" => 137,
+    "on trait" => 10,
+    "on two" => 14,
+    "on type" => 12,
+    "on types" => 25,
+    "on up" => 4,
+    "on usage" => 5,
+    "on use" => 6,
+    "on user" => 15,
+    "on uses" => 3,
+    "on using" => 38,
+    "on value" => 4,
+    "on values" => 13,
+    "on version" => 16,
+    "on very" => 10,
+    "on vim" => 31,
+    "on was" => 3,
+    "on we" => 10,
+    "on well" => 3,
+    "on what" => 130,
+    "on when" => 20,
+    "on where" => 19,
+    "on which" => 152,
+    "on while" => 5,
+    "on will" => 10,
+    "on windows" => 620,
+    "on with" => 58,
+    "on without" => 6,
+    "on work" => 3,
+    "on write" => 51,
+    "on you" => 33,
+    "on your" => 418,
+    "once a" => 83,
+    "once and" => 62,
+    "once for" => 24,
+    "once in" => 55,
+    "once is" => 13,
+    "once that" => 10,
+    "once the" => 153,
+    "once this" => 11,
+    "once to" => 25,
+    "once with" => 24,
+    "one a" => 8,
+    "one after" => 11,
+    "one all" => 3,
+    "one also" => 5,
+    "one and" => 68,
+    "one are" => 4,
+    "one as" => 15,
+    "one at" => 34,
+    "one be" => 4,
+    "one bit" => 5,
+    "one but" => 12,
+    "one by" => 37,
+    "one can" => 127,
+    "one command" => 29,
+    "one crate" => 22,
+    "one error" => 39,
+    "one example" => 26,
+    "one file" => 84,
+    "one for" => 100,
+    "one from" => 49,
+    "one function" => 23,
+    "one has" => 33,
+    "one he" => 5,
+    "one i" => 15,
+    "one if" => 15,
+    "one in" => 54,
+    "one is" => 151,
+    "one it" => 9,
+    "one library" => 7,
+    "one like" => 4,
+    "one line" => 183,
+    "one may" => 101,
+    "one more" => 48,
+    "one name" => 5,
+    "one not" => 4,
+    "one number" => 3,
+    "one of" => 1984,
+    "one on" => 19,
+    "one one" => 4,
+    "one only" => 8,
+    "one or" => 272,
+    "one other" => 16,
+    "one problem" => 7,
+    "one rust" => 4,
+    "one see" => 6,
+    "one set" => 8,
+    "one so" => 10,
+    "one solution" => 3,
+    "one string" => 13,
+    "one test" => 14,
+    "one that" => 136,
+    "one the" => 40,
+    "one then" => 4,
+    "one this" => 4,
+    "one time" => 68,
+    "one to" => 152,
+    "one type" => 40,
+    "one use" => 24,
+    "one used" => 12,
+    "one using" => 14,
+    "one value" => 39,
+    "one version" => 24,
+    "one vim" => 8,
+    "one was" => 32,
+    "one we" => 8,
+    "one when" => 14,
+    "one which" => 23,
+    "one will" => 31,
+    "one with" => 56,
+    "one you" => 43,
+    "only a" => 192,
+    "only add" => 15,
+    "only after" => 42,
+    "only an" => 15,
+    "only and" => 60,
+    "only are" => 6,
+    "only as" => 27,
+    "only at" => 30,
+    "only be" => 494,
+    "only bit" => 10,
+    "only build" => 11,
+    "only but" => 10,
+    "only by" => 33,
+    "only can" => 3,
+    "only code" => 6,
+    "only do" => 25,
+    "only file" => 17,
+    "only files" => 7,
+    "only for" => 169,
+    "only from" => 13,
+    "only function" => 3,
+    "only has" => 35,
+    "only have" => 59,
+    "only if" => 163,
+    "only in" => 90,
+    "only is" => 23,
+    "only library" => 4,
+    "only line" => 5,
+    "only no" => 10,
+    "only not" => 3,
+    "only of" => 19,
+    "only on" => 77,
+    "only one" => 340,
+    "only or" => 12,
+    "only other" => 8,
+    "only problem" => 3,
+    "only rust" => 6,
+    "only see" => 21,
+    "only set" => 50,
+    "only so" => 7,
+    "only some" => 15,
+    "only string" => 4,
+    "only support" => 38,
+    "only test" => 12,
+    "only that" => 38,
+    "only the" => 532,
+    "only then" => 5,
+    "only there" => 4,
+    "only this" => 7,
+    "only time" => 5,
+    "only to" => 106,
+    "only type" => 6,
+    "only use" => 125,
+    "only used" => 117,
+    "only using" => 20,
+    "only version" => 6,
+    "only vim" => 8,
+    "only when" => 209,
+    "only with" => 38,
+    "only you" => 6,
+    "open a" => 218,
+    "open and" => 56,
+    "open for" => 14,
+    "open in" => 23,
+    "open is" => 3,
+    "open that" => 8,
+    "open the" => 96,
+    "open to" => 32,
+    "opened the" => 30,
+    "opening the" => 42,
+    "operand the" => 5,
+    "operands the" => 3,
+    "operation and" => 70,
+    "operation for" => 7,
+    "operation in" => 14,
+    "operation is" => 100,
+    "operation of" => 14,
+    "operation the" => 4,
+    "operation to" => 16,
+    "operations and" => 35,
+    "operations for" => 27,
+    "operations in" => 49,
+    "operations is" => 5,
+    "operations of" => 9,
+    "operations that" => 63,
+    "operations the" => 7,
+    "operations this" => 3,
+    "operations to" => 31,
+    "operator a" => 4,
+    "operator and" => 30,
+    "operator in" => 20,
+    "operator is" => 38,
+    "operator the" => 11,
+    "operator to" => 30,
+    "optimizations the" => 3,
+    "optimized the" => 4,
+    "option a" => 6,
+    "option an" => 3,
+    "option and" => 35,
+    "option are" => 9,
+    "option as" => 11,
+    "option at" => 4,
+    "option but" => 6,
+    "option can" => 98,
+    "option for" => 88,
+    "option from" => 7,
+    "option i" => 3,
+    "option if" => 21,
+    "option in" => 34,
+    "option is" => 474,
+    "option it" => 10,
+    "option not" => 16,
+    "option of" => 45,
+    "option on" => 17,
+    "option or" => 27,
+    "option that" => 41,
+    "option the" => 16,
+    "option to" => 348,
+    "option type" => 4,
+    "option used" => 7,
+    "option using" => 4,
+    "option vim" => 4,
+    "option was" => 40,
+    "option when" => 21,
+    "option which" => 22,
+    "option will" => 40,
+    "option with" => 18,
+    "option you" => 11,
+    "optional a" => 8,
+    "optional and" => 41,
+    "optional for" => 6,
+    "optional in" => 10,
+    "optional is" => 4,
+    "optional the" => 9,
+    "optionally the" => 8,
+    "options a" => 3,
+    "options and" => 51,
+    "options are" => 196,
+    "options as" => 5,
+    "options by" => 3,
+    "options can" => 37,
+    "options for" => 107,
+    "options if" => 7,
+    "options in" => 29,
+    "options is" => 17,
+    "options it" => 5,
+    "options of" => 14,
+    "options on" => 9,
+    "options or" => 16,
+    "options that" => 47,
+    "options the" => 8,
+    "options this" => 3,
+    "options to" => 93,
+    "options when" => 8,
+    "options with" => 21,
+    "options you" => 14,
+    "or a" => 866,
+    "or about" => 4,
+    "or above" => 16,
+    "or add" => 42,
+    "or added" => 3,
+    "or after" => 51,
+    "or all" => 78,
+    "or also" => 4,
+    "or an" => 205,
+    "or and" => 83,
+    "or another" => 50,
+    "or any" => 172,
+    "or apache" => 130,
+    "or are" => 75,
+    "or argument" => 3,
+    "or as" => 132,
+    "or at" => 96,
+    "or available" => 3,
+    "or be" => 34,
+    "or because" => 16,
+    "or before" => 18,
+    "or being" => 9,
+    "or between" => 5,
+    "or bit" => 18,
+    "or block" => 30,
+    "or both" => 39,
+    "or buffer" => 22,
+    "or build" => 43,
+    "or but" => 19,
+    "or by" => 149,
+    "or bytes" => 36,
+    "or call" => 19,
+    "or can" => 35,
+    "or cannot" => 7,
+    "or cargo" => 7,
+    "or case" => 3,
+    "or change" => 31,
+    "or changes" => 4,
+    "or character" => 14,
+    "or characters" => 6,
+    "or check" => 15,
+    "or code" => 16,
+    "or command" => 31,
+    "or compile" => 5,
+    "or contribution" => 22,
+    "or could" => 3,
+    "or crate" => 11,
+    "or crates" => 7,
+    "or create" => 12,
+    "or current" => 4,
+    "or data" => 25,
+    "or default" => 5,
+    "or defined" => 5,
+    "or different" => 4,
+    "or directory" => 52,
+    "or do" => 22,
+    "or doc" => 24,
+    "or documentation" => 9,
+    "or does" => 22,
+    "or doesn't" => 4,
+    "or don't" => 10,
+    "or either" => 5,
+    "or end" => 46,
+    "or environment" => 22,
+    "or error" => 13,
+    "or even" => 95,
+    "or ex" => 6,
+    "or example" => 5,
+    "or examples" => 11,
+    "or expression" => 9,
+    "or feature" => 22,
+    "or features" => 17,
+    "or file" => 50,
+    "or files" => 9,
+    "or flag" => 5,
+    "or floating" => 8,
+    "or following" => 4,
+    "or for" => 127,
+    "or from" => 48,
+    "or function" => 53,
+    "or functions" => 23,
+    "or get" => 9,
+    "or given" => 3,
+    "or had" => 3,
+    "or has" => 25,
+    "or have" => 31,
+    "or he" => 4,
+    "or her" => 6,
+    "or his" => 7,
+    "or how" => 14,
+    "or i" => 19,
+    "or if" => 416,
+    "or implementation" => 15,
+    "or in" => 220,
+    "or instead" => 24,
+    "or integer" => 5,
+    "or into" => 9,
+    "or is" => 165,
+    "or it" => 64,
+    "or it's" => 5,
+    "or items" => 6,
+    "or its" => 46,
+    "or just" => 53,
+    "or key" => 8,
+    "or last" => 8,
+    "or library" => 16,
+    "or license" => 9,
+    "or like" => 3,
+    "or line" => 5,
+    "or lines" => 4,
+    "or list" => 10,
+    "or macro" => 10,
+    "or make" => 12,
+    "or many" => 3,
+    "or match" => 4,
+    "or may" => 95,
+    "or memory" => 12,
+    "or method" => 28,
+    "or might" => 7,
+    "or mit" => 978,
+    "or module" => 20,
+    "or more" => 355,
+    "or most" => 6,
+    "or multiple" => 16,
+    "or must" => 21,
+    "or my" => 7,
+    "or name" => 4,
+    "or need" => 10,
+    "or new" => 11,
+    "or no" => 49,
+    "or non" => 26,
+    "or not" => 344,
+    "or number" => 5,
+    "or object" => 27,
+    "or of" => 82,
+    "or on" => 94,
+    "or one" => 49,
+    "or only" => 15,
+    "or option" => 9,
+    "or options" => 11,
+    "or or" => 76,
+    "or other" => 230,
+    "or otherwise" => 123,
+    "or out" => 15,
+    "or output" => 20,
+    "or patch" => 10,
+    "or path" => 48,
+    "or pattern" => 33,
+    "or point" => 3,
+    "or pointer" => 19,
+    "or possible" => 4,
+    "or project" => 9,
+    "or read" => 12,
+    "or reference" => 16,
+    "or result" => 4,
+    "or return" => 23,
+    "or returns" => 18,
+    "or run" => 30,
+    "or runtime" => 13,
+    "or rust" => 55,
+    "or same" => 3,
+    "or script" => 11,
+    "or see" => 7,
+    "or set" => 57,
+    "or she" => 5,
+    "or should" => 23,
+    "or single" => 3,
+    "or size" => 20,
+    "or so" => 28,
+    "or some" => 42,
+    "or specific" => 5,
+    "or specified" => 5,
+    "or start" => 9,
+    "or state" => 3,
+    "or store" => 3,
+    "or string" => 40,
+    "or such" => 5,
+    "or support" => 8,
+    "or syntax" => 8,
+    "or target" => 5,
+    "or terminal" => 5,
+    "or test" => 9,
+    "or tests" => 6,
+    "or text" => 11,
+    "or that" => 42,
+    "or the" => 962,
+    "or their" => 8,
+    "or then" => 36,
+    "or there" => 13,
+    "or these" => 5,
+    "or they" => 15,
+    "or this" => 19,
+    "or time" => 8,
+    "or to" => 293,
+    "or trait" => 14,
+    "or two" => 84,
+    "or type" => 34,
+    "or types" => 16,
+    "or under" => 8,
+    "or use" => 155,
+    "or used" => 12,
+    "or user" => 27,
+    "or uses" => 5,
+    "or using" => 89,
+    "or value" => 24,
+    "or values" => 27,
+    "or variable" => 18,
+    "or version" => 5,
+    "or vim" => 24,
+    "or want" => 25,
+    "or was" => 12,
+    "or we" => 15,
+    "or what" => 14,
+    "or when" => 153,
+    "or where" => 22,
+    "or which" => 44,
+    "or while" => 5,
+    "or will" => 44,
+    "or window" => 9,
+    "or windows" => 16,
+    "or with" => 108,
+    "or without" => 32,
+    "or work" => 3,
+    "or would" => 12,
+    "or write" => 54,
+    "or you" => 164,
+    "or your" => 25,
+    "order and" => 35,
+    "order as" => 34,
+    "order for" => 63,
+    "order in" => 57,
+    "order is" => 28,
+    "order not" => 4,
+    "order of" => 221,
+    "order on" => 7,
+    "order or" => 18,
+    "order that" => 25,
+    "order the" => 29,
+    "order to" => 687,
+    "order with" => 23,
+    "order you" => 6,
+    "original and" => 7,
+    "original in" => 4,
+    "original is" => 8,
+    "other a" => 5,
+    "other and" => 33,
+    "other as" => 10,
+    "other at" => 6,
+    "other bit" => 4,
+    "other but" => 8,
+    "other by" => 5,
+    "other code" => 42,
+    "other crate" => 20,
+    "other documentation" => 7,
+    "other example" => 3,
+    "other file" => 22,
+    "other files" => 40,
+    "other for" => 10,
+    "other from" => 3,
+    "other function" => 6,
+    "other i" => 15,
+    "other in" => 13,
+    "other is" => 13,
+    "other more" => 5,
+    "other of" => 12,
+    "other on" => 3,
+    "other one" => 16,
+    "other or" => 8,
+    "other rust" => 39,
+    "other solution" => 4,
+    "other that" => 10,
+    "other the" => 9,
+    "other to" => 8,
+    "other type" => 28,
+    "other use" => 14,
+    "other using" => 5,
+    "other value" => 15,
+    "other version" => 6,
+    "other vim" => 8,
+    "other was" => 12,
+    "other when" => 3,
+    "other with" => 8,
+    "others the" => 4,
+    "otherwise a" => 34,
+    "otherwise an" => 15,
+    "otherwise be" => 27,
+    "otherwise by" => 4,
+    "otherwise can" => 3,
+    "otherwise if" => 47,
+    "otherwise in" => 4,
+    "otherwise is" => 17,
+    "otherwise it" => 99,
+    "otherwise not" => 4,
+    "otherwise the" => 170,
+    "otherwise this" => 15,
+    "otherwise you" => 45,
+    "our and" => 3,
+    "our as" => 3,
+    "our is" => 3,
+    "our to" => 5,
+    "out a" => 95,
+    "out all" => 17,
+    "out an" => 9,
+    "out and" => 81,
+    "out are" => 5,
+    "out as" => 36,
+    "out at" => 42,
+    "out but" => 9,
+    "out by" => 25,
+    "out file" => 3,
+    "out files" => 4,
+    "out for" => 67,
+    "out from" => 43,
+    "out i" => 7,
+    "out if" => 108,
+    "out in" => 81,
+    "out is" => 9,
+    "out it" => 3,
+    "out of" => 1234,
+    "out on" => 49,
+    "out or" => 16,
+    "out rust" => 3,
+    "out that" => 67,
+    "out the" => 488,
+    "out this" => 25,
+    "out to" => 131,
+    "out using" => 3,
+    "out was" => 3,
+    "out when" => 752,
+    "out which" => 26,
+    "out with" => 36,
+    "out you" => 8,
+    "out your" => 4,
+    "output a" => 23,
+    "output and" => 46,
+    "output are" => 10,
+    "output as" => 32,
+    "output by" => 8,
+    "output can" => 21,
+    "output files" => 11,
+    "output for" => 39,
+    "output if" => 13,
+    "output in" => 45,
+    "output is" => 161,
+    "output of" => 246,
+    "output on" => 13,
+    "output or" => 3,
+    "output that" => 18,
+    "output the" => 29,
+    "output this" => 3,
+    "output to" => 81,
+    "output when" => 11,
+    "output with" => 18,
+    "output you" => 8,
+    "outside a" => 10,
+    "outside the" => 131,
+    "over a" => 171,
+    "over an" => 33,
+    "over and" => 75,
+    "over as" => 5,
+    "over by" => 7,
+    "over for" => 6,
+    "over if" => 3,
+    "over in" => 22,
+    "over is" => 6,
+    "over it" => 42,
+    "over of" => 4,
+    "over on" => 4,
+    "over or" => 9,
+    "over that" => 16,
+    "over the" => 654,
+    "over this" => 21,
+    "over to" => 48,
+    "over use" => 3,
+    "over when" => 12,
+    "over with" => 31,
+    "overflow a" => 4,
+    "overflow the" => 14,
+    "overflow to" => 8,
+    "override the" => 94,
+    "overrides the" => 24,
+    "overview the" => 3,
+    "own a" => 10,
+    "own and" => 13,
+    "own for" => 3,
+    "own in" => 4,
+    "own it" => 6,
+    "own the" => 20,
+    "own to" => 6,
+    "own with" => 4,
+    "owned the" => 4,
+    "package and" => 49,
+    "package for" => 18,
+    "package in" => 61,
+    "package is" => 88,
+    "package it" => 12,
+    "package of" => 8,
+    "package that" => 25,
+    "package the" => 11,
+    "package to" => 45,
+    "package with" => 35,
+    "package you" => 6,
+    "packages to" => 21,
+    "packed and" => 4,
+    "page and" => 29,
+    "page for" => 43,
+    "page in" => 14,
+    "page is" => 23,
+    "page of" => 25,
+    "page the" => 10,
+    "page to" => 16,
+    "panic and" => 30,
+    "panic for" => 3,
+    "panic in" => 35,
+    "panic is" => 41,
+    "panic the" => 8,
+    "panics and" => 14,
+    "panics for" => 5,
+    "panics in" => 11,
+    "panics is" => 3,
+    "panics the" => 54,
+    "panics to" => 8,
+    "parameter and" => 52,
+    "parameter for" => 11,
+    "parameter in" => 29,
+    "parameter is" => 96,
+    "parameter of" => 41,
+    "parameter that" => 21,
+    "parameter the" => 10,
+    "parameter to" => 45,
+    "parameter with" => 14,
+    "parameter you" => 4,
+    "parameters and" => 68,
+    "parameters for" => 26,
+    "parameters in" => 32,
+    "parameters is" => 12,
+    "parameters of" => 32,
+    "parameters to" => 31,
+    "parent to" => 3,
+    "parse a" => 49,
+    "parse and" => 36,
+    "parse the" => 64,
+    "parsed the" => 3,
+    "parser a" => 4,
+    "parser and" => 24,
+    "parser in" => 12,
+    "parser is" => 48,
+    "parser to" => 10,
+    "parsing a" => 43,
+    "parsing and" => 87,
+    "parsing in" => 3,
+    "parsing is" => 22,
+    "parsing of" => 42,
+    "parsing the" => 44,
+    "parsing to" => 11,
+    "part a" => 3,
+    "part and" => 11,
+    "part as" => 6,
+    "part for" => 5,
+    "part in" => 18,
+    "part is" => 36,
+    "part of" => 1396,
+    "part that" => 14,
+    "part the" => 15,
+    "part this" => 4,
+    "part to" => 7,
+    "part with" => 9,
+    "part you" => 4,
+    "particular a" => 6,
+    "particular and" => 12,
+    "particular is" => 6,
+    "particular the" => 47,
+    "particular to" => 6,
+    "particularly the" => 4,
+    "parts the" => 4,
+    "parts to" => 10,
+    "pass a" => 64,
+    "pass and" => 12,
+    "pass for" => 4,
+    "pass in" => 40,
+    "pass the" => 194,
+    "pass to" => 69,
+    "passed a" => 30,
+    "passed and" => 21,
+    "passed for" => 9,
+    "passed in" => 143,
+    "passed the" => 28,
+    "passed to" => 532,
+    "passed with" => 10,
+    "passes the" => 28,
+    "passing a" => 75,
+    "passing and" => 13,
+    "passing in" => 29,
+    "passing is" => 4,
+    "passing the" => 88,
+    "passing to" => 26,
+    "past the" => 101,
+    "paste the" => 15,
+    "patch a" => 3,
+    "patch and" => 6,
+    "patch by" => 35,
+    "patch file" => 6,
+    "patch files" => 5,
+    "patch for" => 45,
+    "patch from" => 19,
+    "patch in" => 5,
+    "patch is" => 5,
+    "patch number" => 11,
+    "patch on" => 3,
+    "patch or" => 5,
+    "patch that" => 6,
+    "patch the" => 5,
+    "patch then" => 4,
+    "patch to" => 91,
+    "patch version" => 23,
+    "patch was" => 8,
+    "patch with" => 6,
+    "patch your" => 3,
+    "path a" => 3,
+    "path and" => 70,
+    "path are" => 3,
+    "path as" => 15,
+    "path can" => 13,
+    "path for" => 63,
+    "path if" => 9,
+    "path in" => 35,
+    "path is" => 115,
+    "path it" => 11,
+    "path not" => 6,
+    "path of" => 92,
+    "path on" => 19,
+    "path or" => 26,
+    "path that" => 50,
+    "path the" => 16,
+    "path to" => 403,
+    "path use" => 3,
+    "path when" => 13,
+    "path with" => 28,
+    "path you" => 7,
+    "paths and" => 25,
+    "paths in" => 61,
+    "paths is" => 7,
+    "paths of" => 22,
+    "paths the" => 4,
+    "paths to" => 52,
+    "pattern a" => 8,
+    "pattern and" => 66,
+    "pattern are" => 12,
+    "pattern as" => 14,
+    "pattern can" => 48,
+    "pattern for" => 50,
+    "pattern if" => 3,
+    "pattern in" => 87,
+    "pattern is" => 120,
+    "pattern it" => 10,
+    "pattern not" => 14,
+    "pattern of" => 41,
+    "pattern on" => 6,
+    "pattern or" => 18,
+    "pattern that" => 42,
+    "pattern the" => 28,
+    "pattern this" => 4,
+    "pattern to" => 63,
+    "pattern with" => 44,
+    "pattern you" => 10,
+    "patterns and" => 46,
+    "patterns for" => 27,
+    "patterns in" => 41,
+    "patterns of" => 9,
+    "patterns the" => 5,
+    "patterns to" => 30,
+    "people and" => 13,
+    "people the" => 4,
+    "people to" => 29,
+    "per the" => 25,
+    "perform a" => 57,
+    "perform the" => 63,
+    "performance a" => 3,
+    "performance and" => 77,
+    "performance for" => 18,
+    "performance in" => 25,
+    "performance is" => 47,
+    "performance of" => 95,
+    "performance that" => 6,
+    "performance the" => 4,
+    "performance this" => 3,
+    "performance to" => 8,
+    "performance with" => 14,
+    "performed the" => 8,
+    "performs the" => 66,
+    "perhaps the" => 25,
+    "place a" => 22,
+    "place and" => 40,
+    "place for" => 29,
+    "place in" => 48,
+    "place is" => 13,
+    "place of" => 67,
+    "place the" => 67,
+    "place this" => 3,
+    "place to" => 81,
+    "placed the" => 5,
+    "places the" => 12,
+    "platform and" => 48,
+    "platform for" => 11,
+    "platform is" => 15,
+    "platform of" => 3,
+    "platform that" => 10,
+    "platform the" => 11,
+    "platform this" => 11,
+    "platform to" => 7,
+    "platforms and" => 82,
+    "platforms for" => 9,
+    "platforms in" => 6,
+    "platforms is" => 11,
+    "platforms the" => 24,
+    "platforms to" => 6,
+    "please and" => 3,
+    "please be" => 20,
+    "please to" => 3,
+    "plugin and" => 17,
+    "plugin in" => 8,
+    "plugin is" => 40,
+    "plugin the" => 4,
+    "plugin to" => 25,
+    "plus the" => 48,
+    "point a" => 5,
+    "point and" => 27,
+    "point are" => 6,
+    "point as" => 3,
+    "point for" => 33,
+    "point from" => 5,
+    "point if" => 5,
+    "point in" => 74,
+    "point is" => 29,
+    "point it" => 11,
+    "point of" => 111,
+    "point on" => 17,
+    "point or" => 5,
+    "point that" => 17,
+    "point the" => 30,
+    "point to" => 244,
+    "point when" => 4,
+    "point will" => 10,
+    "point with" => 5,
+    "point you" => 32,
+    "pointer a" => 11,
+    "pointer and" => 87,
+    "pointer as" => 42,
+    "pointer by" => 14,
+    "pointer can" => 27,
+    "pointer for" => 22,
+    "pointer if" => 11,
+    "pointer in" => 30,
+    "pointer is" => 208,
+    "pointer it" => 4,
+    "pointer not" => 5,
+    "pointer of" => 21,
+    "pointer on" => 6,
+    "pointer or" => 23,
+    "pointer that" => 47,
+    "pointer the" => 10,
+    "pointer this" => 10,
+    "pointer to" => 328,
+    "pointer when" => 22,
+    "pointer with" => 22,
+    "pointer you" => 3,
+    "pointers a" => 3,
+    "pointers and" => 55,
+    "pointers of" => 4,
+    "pointers to" => 88,
+    "points the" => 3,
+    "points to" => 181,
+    "policy the" => 7,
+    "policy to" => 8,
+    "pool the" => 3,
+    "popup a" => 3,
+    "popup and" => 9,
+    "popup for" => 6,
+    "popup in" => 4,
+    "popup is" => 25,
+    "popup the" => 5,
+    "popup to" => 7,
+    "port a" => 3,
+    "port the" => 12,
+    "port to" => 21,
+    "position and" => 50,
+    "position for" => 17,
+    "position in" => 137,
+    "position is" => 49,
+    "position of" => 110,
+    "position the" => 56,
+    "position to" => 22,
+    "positions the" => 6,
+    "possible and" => 45,
+    "possible as" => 8,
+    "possible by" => 26,
+    "possible for" => 95,
+    "possible if" => 10,
+    "possible in" => 39,
+    "possible is" => 5,
+    "possible it" => 13,
+    "possible on" => 13,
+    "possible or" => 7,
+    "possible that" => 76,
+    "possible the" => 12,
+    "possible this" => 11,
+    "possible to" => 920,
+    "possible use" => 13,
+    "possible when" => 11,
+    "possible with" => 46,
+    "possible you" => 6,
+    "possibly the" => 6,
+    "power the" => 3,
+    "precision and" => 8,
+    "precision by" => 4,
+    "precision for" => 6,
+    "precision is" => 8,
+    "precision of" => 84,
+    "precision or" => 5,
+    "precision with" => 4,
+    "prefer the" => 26,
+    "prefix a" => 3,
+    "prefix the" => 9,
+    "prefix to" => 17,
+    "present a" => 12,
+    "present and" => 32,
+    "present in" => 147,
+    "present is" => 25,
+    "present of" => 3,
+    "present the" => 56,
+    "present to" => 16,
+    "press the" => 22,
+    "prevent the" => 51,
+    "previous a" => 4,
+    "previous to" => 10,
+    "previously the" => 17,
+    "print a" => 63,
+    "print and" => 12,
+    "print the" => 136,
+    "print to" => 17,
+    "printed the" => 4,
+    "printing the" => 35,
+    "prints the" => 68,
+    "private to" => 10,
+    "probably the" => 26,
+    "problem a" => 6,
+    "problem and" => 13,
+    "problem as" => 6,
+    "problem at" => 3,
+    "problem because" => 4,
+    "problem but" => 11,
+    "problem by" => 16,
+    "problem can" => 3,
+    "problem for" => 22,
+    "problem has" => 4,
+    "problem if" => 8,
+    "problem in" => 19,
+    "problem is" => 56,
+    "problem not" => 4,
+    "problem of" => 23,
+    "problem on" => 7,
+    "problem or" => 3,
+    "problem see" => 3,
+    "problem that" => 21,
+    "problem the" => 3,
+    "problem to" => 8,
+    "problem use" => 5,
+    "problem using" => 4,
+    "problem was" => 10,
+    "problem we" => 7,
+    "problem when" => 32,
+    "problem which" => 9,
+    "problem with" => 130,
+    "problem you" => 5,
+    "problems and" => 18,
+    "problems of" => 5,
+    "problems the" => 7,
+    "process a" => 14,
+    "process and" => 75,
+    "process as" => 10,
+    "process for" => 30,
+    "process in" => 19,
+    "process is" => 84,
+    "process it" => 4,
+    "process of" => 93,
+    "process that" => 20,
+    "process the" => 40,
+    "process to" => 72,
+    "process with" => 28,
+    "process you" => 5,
+    "processes the" => 4,
+    "processing the" => 23,
+    "produce a" => 130,
+    "produce and" => 5,
+    "produce in" => 4,
+    "produce of" => 3,
+    "produce the" => 59,
+    "produced the" => 5,
+    "produces the" => 36,
+    "producing the" => 7,
+    "profile the" => 3,
+    "program a" => 6,
+    "program and" => 59,
+    "program for" => 32,
+    "program in" => 34,
+    "program is" => 97,
+    "program it" => 9,
+    "program that" => 75,
+    "program the" => 10,
+    "program this" => 3,
+    "program to" => 87,
+    "program with" => 32,
+    "program you" => 17,
+    "programs and" => 21,
+    "programs the" => 3,
+    "programs to" => 35,
+    "project a" => 7,
+    "project and" => 103,
+    "project are" => 15,
+    "project as" => 22,
+    "project by" => 74,
+    "project can" => 9,
+    "project files" => 3,
+    "project for" => 18,
+    "project from" => 11,
+    "project if" => 3,
+    "project in" => 39,
+    "project is" => 549,
+    "project it" => 8,
+    "project not" => 3,
+    "project of" => 9,
+    "project on" => 6,
+    "project or" => 22,
+    "project that" => 67,
+    "project the" => 3,
+    "project this" => 8,
+    "project to" => 70,
+    "project use" => 9,
+    "project will" => 22,
+    "project with" => 40,
+    "project you" => 50,
+    "projects the" => 8,
+    "projects to" => 14,
+    "promise the" => 5,
+    "prompt the" => 15,
+    "properly and" => 13,
+    "properly in" => 16,
+    "properly to" => 3,
+    "properties and" => 35,
+    "properties of" => 80,
+    "properties to" => 20,
+    "property and" => 14,
+    "property for" => 10,
+    "property in" => 12,
+    "property is" => 126,
+    "property of" => 44,
+    "property the" => 3,
+    "property to" => 28,
+    "protocol and" => 30,
+    "protocol in" => 13,
+    "protocol is" => 50,
+    "protocol of" => 6,
+    "protocol the" => 4,
+    "protocol to" => 57,
+    "provide a" => 349,
+    "provide and" => 10,
+    "provide for" => 9,
+    "provide that" => 3,
+    "provide the" => 144,
+    "provide this" => 9,
+    "provide to" => 7,
+    "provided a" => 32,
+    "provided and" => 23,
+    "provided as" => 121,
+    "provided for" => 116,
+    "provided in" => 75,
+    "provided is" => 21,
+    "provided it" => 34,
+    "provided that" => 33,
+    "provided the" => 26,
+    "provided this" => 3,
+    "provided to" => 80,
+    "provided with" => 36,
+    "provided you" => 7,
+    "provides a" => 820,
+    "provides an" => 198,
+    "provides and" => 25,
+    "provides as" => 7,
+    "provides for" => 13,
+    "provides is" => 5,
+    "provides it" => 3,
+    "provides that" => 7,
+    "provides the" => 296,
+    "provides this" => 4,
+    "provides you" => 7,
+    "providing the" => 43,
+    "public and" => 28,
+    "public in" => 4,
+    "publish the" => 19,
+    "published the" => 6,
+    "pull the" => 15,
+    "purposes the" => 4,
+    "push the" => 18,
+    "put a" => 83,
+    "put and" => 6,
+    "put in" => 120,
+    "put the" => 239,
+    "put this" => 46,
+    "put to" => 8,
+    "python and" => 53,
+    "python in" => 3,
+    "python is" => 14,
+    "python that" => 3,
+    "python the" => 4,
+    "python to" => 11,
+    "query the" => 35,
+    "question the" => 8,
+    "quickly the" => 3,
+    "quite a" => 100,
+    "quite the" => 4,
+    "quote the" => 5,
+    "range a" => 3,
+    "range and" => 29,
+    "range for" => 24,
+    "range in" => 26,
+    "range is" => 61,
+    "range of" => 235,
+    "range that" => 19,
+    "range the" => 6,
+    "range to" => 20,
+    "range with" => 13,
+    "rather a" => 20,
+    "rather the" => 16,
+    "rather to" => 12,
+    "raw and" => 5,
+    "re the" => 4,
+    "read a" => 66,
+    "read an" => 8,
+    "read and" => 154,
+    "read as" => 14,
+    "read by" => 18,
+    "read for" => 17,
+    "read if" => 3,
+    "read in" => 38,
+    "read is" => 11,
+    "read it" => 49,
+    "read of" => 7,
+    "read on" => 13,
+    "read or" => 56,
+    "read that" => 8,
+    "read the" => 331,
+    "read this" => 27,
+    "read to" => 11,
+    "read when" => 4,
+    "read with" => 24,
+    "readable the" => 4,
+    "reading a" => 65,
+    "reading and" => 89,
+    "reading in" => 7,
+    "reading of" => 9,
+    "reading the" => 117,
+    "reads the" => 79,
+    "real to" => 7,
+    "really a" => 20,
+    "really the" => 7,
+    "really to" => 3,
+    "reason the" => 17,
+    "reason to" => 67,
+    "reasons the" => 19,
+    "receive the" => 25,
+    "received the" => 27,
+    "recognize the" => 55,
+    "recognized the" => 4,
+    "recommended the" => 3,
+    "recommended to" => 211,
+    "record the" => 7,
+    "redraw the" => 62,
+    "reduce the" => 180,
+    "refer to" => 551,
+    "reference a" => 14,
+    "reference an" => 8,
+    "reference and" => 41,
+    "reference as" => 15,
+    "reference by" => 10,
+    "reference can" => 12,
+    "reference for" => 126,
+    "reference if" => 5,
+    "reference in" => 36,
+    "reference is" => 91,
+    "reference it" => 9,
+    "reference of" => 22,
+    "reference on" => 12,
+    "reference or" => 42,
+    "reference that" => 21,
+    "reference the" => 37,
+    "reference this" => 3,
+    "reference to" => 728,
+    "reference when" => 3,
+    "reference with" => 20,
+    "reference you" => 9,
+    "references a" => 9,
+    "references and" => 52,
+    "references for" => 6,
+    "references in" => 48,
+    "references is" => 9,
+    "references of" => 7,
+    "references the" => 22,
+    "references to" => 216,
+    "regardless the" => 3,
+    "region the" => 3,
+    "register a" => 33,
+    "register and" => 21,
+    "register for" => 13,
+    "register in" => 23,
+    "register is" => 45,
+    "register of" => 5,
+    "register the" => 31,
+    "register to" => 20,
+    "registry to" => 23,
+    "related and" => 6,
+    "related the" => 3,
+    "related to" => 155,
+    "relative to" => 183,
+    "release a" => 7,
+    "release and" => 30,
+    "release for" => 5,
+    "release in" => 6,
+    "release is" => 25,
+    "release of" => 81,
+    "release that" => 6,
+    "release the" => 39,
+    "release this" => 6,
+    "release to" => 12,
+    "released the" => 3,
+    "releases the" => 6,
+    "remain the" => 10,
+    "remember the" => 69,
+    "remote the" => 3,
+    "remote to" => 4,
+    "remove a" => 69,
+    "remove and" => 5,
+    "remove for" => 4,
+    "remove that" => 4,
+    "remove the" => 457,
+    "remove this" => 10,
+    "removed a" => 9,
+    "removed and" => 24,
+    "removed in" => 93,
+    "removed is" => 5,
+    "removed the" => 61,
+    "removed to" => 8,
+    "removes the" => 90,
+    "removing the" => 41,
+    "rename the" => 48,
+    "rendering the" => 5,
+    "repeat the" => 57,
+    "repeated the" => 8,
+    "replace a" => 20,
+    "replace the" => 103,
+    "replace to" => 3,
+    "replaced the" => 8,
+    "repo the" => 3,
+    "report a" => 19,
+    "report the" => 28,
+    "report to" => 16,
+    "reported the" => 3,
+    "reporting the" => 16,
+    "reports the" => 11,
+    "repository a" => 3,
+    "repository and" => 64,
+    "repository for" => 35,
+    "repository in" => 7,
+    "repository is" => 51,
+    "repository the" => 5,
+    "repository to" => 21,
+    "represent the" => 76,
+    "representation and" => 12,
+    "representation of" => 254,
+    "representation the" => 4,
+    "representation to" => 7,
+    "representing the" => 178,
+    "represents the" => 99,
+    "request a" => 21,
+    "request and" => 43,
+    "request for" => 14,
+    "request in" => 7,
+    "request is" => 29,
+    "request of" => 5,
+    "request the" => 30,
+    "request to" => 66,
+    "requests a" => 6,
+    "requests the" => 3,
+    "requests to" => 41,
+    "require a" => 133,
+    "require and" => 3,
+    "require in" => 4,
+    "require the" => 126,
+    "require to" => 16,
+    "required a" => 12,
+    "required and" => 23,
+    "required for" => 140,
+    "required in" => 30,
+    "required is" => 8,
+    "required that" => 12,
+    "required the" => 12,
+    "required to" => 257,
+    "requirement the" => 5,
+    "requirements and" => 9,
+    "requirements of" => 36,
+    "requirements the" => 6,
+    "requirements to" => 10,
+    "requires a" => 172,
+    "requires and" => 8,
+    "requires for" => 3,
+    "requires that" => 93,
+    "requires the" => 234,
+    "requires to" => 29,
+    "reset the" => 103,
+    "reset to" => 21,
+    "resolution the" => 4,
+    "resolution to" => 10,
+    "resolve the" => 29,
+    "resource the" => 3,
+    "resources the" => 10,
+    "response a" => 4,
+    "response the" => 6,
+    "response to" => 91,
+    "restore the" => 104,
+    "result and" => 122,
+    "result are" => 42,
+    "result as" => 46,
+    "result can" => 3,
+    "result for" => 11,
+    "result from" => 42,
+    "result if" => 17,
+    "result in" => 1032,
+    "result is" => 301,
+    "result it" => 8,
+    "result of" => 375,
+    "result on" => 3,
+    "result or" => 22,
+    "result that" => 10,
+    "result the" => 19,
+    "result this" => 3,
+    "result to" => 107,
+    "result when" => 7,
+    "result will" => 50,
+    "result with" => 22,
+    "result you" => 6,
+    "results and" => 5,
+    "results are" => 43,
+    "results as" => 14,
+    "results by" => 3,
+    "results can" => 7,
+    "results for" => 29,
+    "results if" => 3,
+    "results in" => 1788,
+    "results is" => 6,
+    "results it" => 4,
+    "results of" => 77,
+    "results on" => 8,
+    "results or" => 3,
+    "results that" => 7,
+    "results the" => 3,
+    "results to" => 12,
+    "results when" => 10,
+    "results with" => 40,
+    "results you" => 6,
+    "return a" => 357,
+    "return an" => 252,
+    "return and" => 33,
+    "return are" => 4,
+    "return as" => 10,
+    "return by" => 8,
+    "return for" => 16,
+    "return from" => 31,
+    "return if" => 59,
+    "return in" => 22,
+    "return is" => 6,
+    "return it" => 24,
+    "return not" => 4,
+    "return of" => 5,
+    "return on" => 16,
+    "return or" => 26,
+    "return that" => 12,
+    "return the" => 350,
+    "return this" => 10,
+    "return to" => 87,
+    "return when" => 12,
+    "return will" => 3,
+    "return with" => 19,
+    "return you" => 4,
+    "returned a" => 15,
+    "returned and" => 27,
+    "returned as" => 57,
+    "returned for" => 23,
+    "returned in" => 29,
+    "returned is" => 49,
+    "returned it" => 3,
+    "returned that" => 3,
+    "returned the" => 32,
+    "returned to" => 63,
+    "returned with" => 20,
+    "returning a" => 101,
+    "returning and" => 4,
+    "returning the" => 122,
+    "returning to" => 25,
+    "returns a" => 880,
+    "returns an" => 384,
+    "returns and" => 26,
+    "returns as" => 4,
+    "returns at" => 5,
+    "returns but" => 7,
+    "returns for" => 29,
+    "returns from" => 6,
+    "returns if" => 537,
+    "returns in" => 11,
+    "returns is" => 4,
+    "returns it" => 45,
+    "returns of" => 3,
+    "returns on" => 8,
+    "returns or" => 38,
+    "returns that" => 5,
+    "returns the" => 1251,
+    "returns this" => 3,
+    "returns to" => 20,
+    "returns when" => 14,
+    "returns which" => 4,
+    "returns with" => 11,
+    "reverse the" => 17,
+    "rfc and" => 31,
+    "rfc is" => 10,
+    "rfc the" => 6,
+    "rfc to" => 8,
+    "right and" => 37,
+    "right as" => 6,
+    "right for" => 14,
+    "right in" => 15,
+    "right is" => 9,
+    "right of" => 37,
+    "right this" => 6,
+    "right to" => 154,
+    "right with" => 5,
+    "right you" => 4,
+    "room the" => 6,
+    "room to" => 15,
+    "root and" => 17,
+    "root in" => 7,
+    "root is" => 17,
+    "root of" => 213,
+    "root the" => 6,
+    "root to" => 9,
+    "round and" => 24,
+    "round it" => 11,
+    "round of" => 11,
+    "round the" => 84,
+    "round this" => 4,
+    "round to" => 504,
+    "rounding is" => 458,
+    "rounding of" => 3,
+    "rounding the" => 9,
+    "rounding to" => 17,
+    "rules and" => 36,
+    "rules for" => 72,
+    "rules in" => 12,
+    "rules is" => 12,
+    "rules of" => 44,
+    "rules the" => 3,
+    "rules to" => 31,
+    "run a" => 106,
+    "run an" => 27,
+    "run and" => 57,
+    "run are" => 4,
+    "run as" => 50,
+    "run by" => 38,
+    "run for" => 76,
+    "run from" => 20,
+    "run if" => 12,
+    "run in" => 175,
+    "run is" => 6,
+    "run it" => 87,
+    "run of" => 7,
+    "run on" => 164,
+    "run or" => 21,
+    "run that" => 9,
+    "run the" => 509,
+    "run this" => 99,
+    "run to" => 85,
+    "run using" => 24,
+    "run vim" => 21,
+    "run when" => 21,
+    "run with" => 124,
+    "run you" => 12,
+    "running a" => 98,
+    "running and" => 36,
+    "running for" => 7,
+    "running in" => 97,
+    "running is" => 3,
+    "running it" => 19,
+    "running the" => 243,
+    "running this" => 41,
+    "running to" => 4,
+    "running with" => 15,
+    "running you" => 4,
+    "runs the" => 56,
+    "runtime and" => 62,
+    "runtime are" => 5,
+    "runtime as" => 3,
+    "runtime by" => 11,
+    "runtime files" => 53,
+    "runtime for" => 24,
+    "runtime from" => 6,
+    "runtime if" => 10,
+    "runtime in" => 12,
+    "runtime is" => 33,
+    "runtime it" => 8,
+    "runtime not" => 4,
+    "runtime of" => 10,
+    "runtime on" => 8,
+    "runtime or" => 16,
+    "runtime that" => 16,
+    "runtime the" => 16,
+    "runtime this" => 3,
+    "runtime to" => 18,
+    "runtime using" => 6,
+    "runtime vim" => 4,
+    "runtime when" => 8,
+    "runtime will" => 14,
+    "runtime with" => 3,
+    "runtime you" => 12,
+    "rust a" => 16,
+    "rust all" => 6,
+    "rust also" => 31,
+    "rust and" => 234,
+    "rust are" => 28,
+    "rust as" => 18,
+    "rust at" => 4,
+    "rust build" => 15,
+    "rust but" => 16,
+    "rust by" => 37,
+    "rust can" => 44,
+    "rust code" => 370,
+    "rust command" => 3,
+    "rust crate" => 130,
+    "rust crates" => 33,
+    "rust data" => 20,
+    "rust do" => 5,
+    "rust documentation" => 46,
+    "rust does" => 83,
+    "rust error" => 5,
+    "rust example" => 4,
+    "rust feature" => 12,
+    "rust features" => 25,
+    "rust file" => 13,
+    "rust files" => 7,
+    "rust first" => 4,
+    "rust for" => 63,
+    "rust from" => 19,
+    "rust function" => 12,
+    "rust has" => 76,
+    "rust have" => 3,
+    "rust i" => 14,
+    "rust if" => 10,
+    "rust in" => 22,
+    "rust is" => 139,
+    "rust it" => 15,
+    "rust its" => 3,
+    "rust library" => 249,
+    "rust like" => 10,
+    "rust may" => 10,
+    "rust must" => 7,
+    "rust no" => 7,
+    "rust not" => 8,
+    "rust number" => 4,
+    "rust of" => 4,
+    "rust on" => 20,
+    "rust one" => 8,
+    "rust only" => 15,
+    "rust or" => 40,
+    "rust rust" => 19,
+    "rust see" => 4,
+    "rust should" => 9,
+    "rust so" => 20,
+    "rust string" => 14,
+    "rust support" => 13,
+    "rust test" => 41,
+    "rust that" => 56,
+    "rust the" => 48,
+    "rust there" => 3,
+    "rust they" => 3,
+    "rust this" => 19,
+    "rust to" => 54,
+    "rust type" => 47,
+    "rust types" => 88,
+    "rust use" => 8,
+    "rust using" => 26,
+    "rust value" => 9,
+    "rust version" => 1146,
+    "rust was" => 6,
+    "rust we" => 25,
+    "rust when" => 5,
+    "rust where" => 4,
+    "rust which" => 22,
+    "rust will" => 57,
+    "rust with" => 128,
+    "rust without" => 9,
+    "rust work" => 3,
+    "rust you" => 27,
+    "rust your" => 4,
+    "safe and" => 100,
+    "safe for" => 16,
+    "safe in" => 20,
+    "safe the" => 6,
+    "safe to" => 204,
+    "safe with" => 6,
+    "safety and" => 39,
+    "safety in" => 10,
+    "safety is" => 9,
+    "safety of" => 29,
+    "safety the" => 6,
+    "safety to" => 6,
+    "said a" => 15,
+    "said and" => 18,
+    "said as" => 8,
+    "said for" => 7,
+    "said if" => 5,
+    "said in" => 20,
+    "said is" => 5,
+    "said it" => 20,
+    "said that" => 72,
+    "said the" => 286,
+    "said this" => 24,
+    "said to" => 108,
+    "said with" => 16,
+    "said you" => 6,
+    "same and" => 33,
+    "same as" => 846,
+    "same but" => 21,
+    "same can" => 10,
+    "same crate" => 27,
+    "same file" => 96,
+    "same files" => 6,
+    "same for" => 36,
+    "same from" => 5,
+    "same function" => 19,
+    "same if" => 4,
+    "same in" => 20,
+    "same is" => 16,
+    "same license" => 7,
+    "same on" => 9,
+    "same one" => 4,
+    "same or" => 8,
+    "same problem" => 14,
+    "same rust" => 6,
+    "same set" => 22,
+    "same test" => 3,
+    "same that" => 7,
+    "same the" => 8,
+    "same this" => 6,
+    "same to" => 4,
+    "same type" => 114,
+    "same use" => 5,
+    "same value" => 64,
+    "same vim" => 3,
+    "same was" => 3,
+    "same when" => 7,
+    "same which" => 3,
+    "same with" => 12,
+    "save a" => 29,
+    "save the" => 103,
+    "save to" => 5,
+    "saw the" => 69,
+    "say a" => 21,
+    "say and" => 4,
+    "say the" => 21,
+    "say to" => 12,
+    "scale the" => 41,
+    "scheme the" => 4,
+    "scope a" => 4,
+    "scope and" => 60,
+    "scope for" => 41,
+    "scope in" => 35,
+    "scope is" => 33,
+    "scope of" => 120,
+    "scope the" => 29,
+    "scope to" => 23,
+    "screen and" => 18,
+    "screen in" => 8,
+    "screen is" => 29,
+    "screen of" => 5,
+    "screen the" => 11,
+    "screen to" => 13,
+    "script a" => 15,
+    "script and" => 60,
+    "script are" => 11,
+    "script as" => 7,
+    "script by" => 24,
+    "script can" => 24,
+    "script files" => 15,
+    "script for" => 35,
+    "script from" => 14,
+    "script if" => 11,
+    "script in" => 34,
+    "script is" => 125,
+    "script it" => 23,
+    "script not" => 10,
+    "script of" => 5,
+    "script on" => 5,
+    "script or" => 28,
+    "script that" => 62,
+    "script the" => 47,
+    "script this" => 7,
+    "script to" => 92,
+    "script use" => 5,
+    "script when" => 11,
+    "script with" => 21,
+    "script you" => 28,
+    "scripts the" => 7,
+    "scripts to" => 16,
+    "scroll the" => 27,
+    "search a" => 9,
+    "search and" => 35,
+    "search for" => 222,
+    "search in" => 42,
+    "search is" => 23,
+    "search of" => 19,
+    "search that" => 3,
+    "search the" => 30,
+    "search to" => 11,
+    "search with" => 9,
+    "searching the" => 9,
+    "second and" => 26,
+    "second in" => 4,
+    "second is" => 26,
+    "second of" => 3,
+    "second the" => 8,
+    "second to" => 17,
+    "section and" => 27,
+    "section for" => 97,
+    "section in" => 92,
+    "section is" => 42,
+    "section of" => 170,
+    "section that" => 12,
+    "section the" => 23,
+    "section this" => 3,
+    "section to" => 33,
+    "section with" => 13,
+    "secure the" => 6,
+    "see a" => 116,
+    "see all" => 27,
+    "see also" => 415,
+    "see an" => 40,
+    "see and" => 122,
+    "see any" => 15,
+    "see are" => 3,
+    "see as" => 13,
+    "see at" => 4,
+    "see build" => 6,
+    "see but" => 8,
+    "see by" => 8,
+    "see code" => 5,
+    "see crate" => 11,
+    "see documentation" => 40,
+    "see error" => 4,
+    "see example" => 22,
+    "see examples" => 43,
+    "see file" => 5,
+    "see files" => 4,
+    "see for" => 535,
+    "see from" => 7,
+    "see function" => 5,
+    "see i" => 6,
+    "see if" => 117,
+    "see in" => 65,
+    "see is" => 11,
+    "see it" => 65,
+    "see library" => 3,
+    "see license" => 210,
+    "see more" => 37,
+    "see no" => 10,
+    "see of" => 3,
+    "see on" => 8,
+    "see one" => 6,
+    "see only" => 3,
+    "see or" => 17,
+    "see other" => 4,
+    "see patch" => 9,
+    "see rust" => 31,
+    "see so" => 5,
+    "see some" => 18,
+    "see string" => 5,
+    "see support" => 20,
+    "see that" => 220,
+    "see the" => 2298,
+    "see there" => 10,
+    "see this" => 120,
+    "see to" => 22,
+    "see type" => 4,
+    "see using" => 10,
+    "see vim" => 38,
+    "see we" => 3,
+    "see when" => 13,
+    "see which" => 44,
+    "see with" => 5,
+    "see you" => 34,
+    "see your" => 25,
+    "seen the" => 25,
+    "seen to" => 5,
+    "select a" => 89,
+    "select and" => 7,
+    "select the" => 121,
+    "select to" => 3,
+    "selected a" => 6,
+    "selected the" => 5,
+    "selected to" => 10,
+    "selection the" => 4,
+    "selection to" => 13,
+    "self and" => 3,
+    "self to" => 4,
+    "send a" => 89,
+    "send and" => 34,
+    "send the" => 59,
+    "send to" => 23,
+    "sending the" => 25,
+    "sense the" => 3,
+    "sent a" => 10,
+    "sent the" => 12,
+    "sent to" => 116,
+    "separate and" => 7,
+    "separate the" => 32,
+    "sequence and" => 19,
+    "sequence in" => 14,
+    "sequence is" => 40,
+    "sequence of" => 265,
+    "sequence the" => 12,
+    "sequence to" => 11,
+    "serialization to" => 9,
+    "server a" => 7,
+    "server and" => 77,
+    "server for" => 34,
+    "server in" => 20,
+    "server is" => 51,
+    "server it" => 3,
+    "server that" => 28,
+    "server the" => 8,
+    "server to" => 65,
+    "server with" => 27,
+    "server you" => 19,
+    "service the" => 4,
+    "session a" => 5,
+    "session to" => 9,
+    "set a" => 173,
+    "set add" => 3,
+    "set after" => 10,
+    "set all" => 25,
+    "set an" => 31,
+    "set and" => 462,
+    "set any" => 4,
+    "set are" => 23,
+    "set as" => 39,
+    "set at" => 24,
+    "set bit" => 5,
+    "set but" => 30,
+    "set by" => 75,
+    "set can" => 10,
+    "set command" => 42,
+    "set crate" => 3,
+    "set default" => 20,
+    "set do" => 4,
+    "set file" => 4,
+    "set for" => 69,
+    "set from" => 24,
+    "set has" => 3,
+    "set if" => 32,
+    "set in" => 183,
+    "set is" => 44,
+    "set it" => 88,
+    "set like" => 13,
+    "set line" => 6,
+    "set may" => 3,
+    "set more" => 3,
+    "set name" => 10,
+    "set no" => 6,
+    "set not" => 5,
+    "set of" => 711,
+    "set on" => 62,
+    "set one" => 7,
+    "set only" => 9,
+    "set or" => 57,
+    "set see" => 4,
+    "set set" => 3,
+    "set so" => 7,
+    "set some" => 3,
+    "set string" => 4,
+    "set that" => 11,
+    "set the" => 1019,
+    "set then" => 45,
+    "set there" => 4,
+    "set this" => 51,
+    "set time" => 4,
+    "set to" => 926,
+    "set use" => 3,
+    "set used" => 4,
+    "set using" => 32,
+    "set value" => 7,
+    "set vim" => 16,
+    "set when" => 46,
+    "set which" => 20,
+    "set will" => 20,
+    "set with" => 73,
+    "set you" => 6,
+    "set your" => 25,
+    "sets a" => 26,
+    "sets and" => 19,
+    "sets for" => 16,
+    "sets in" => 5,
+    "sets of" => 48,
+    "sets the" => 417,
+    "sets to" => 6,
+    "setting a" => 49,
+    "setting and" => 16,
+    "setting for" => 30,
+    "setting in" => 23,
+    "setting is" => 24,
+    "setting of" => 9,
+    "setting the" => 301,
+    "setting this" => 24,
+    "setting to" => 34,
+    "settings the" => 3,
+    "settings to" => 15,
+    "setup the" => 14,
+    "several of" => 31,
+    "shall be" => 1292,
+    "shall in" => 5,
+    "shall the" => 17,
+    "share the" => 58,
+    "shared and" => 11,
+    "she and" => 11,
+    "she be" => 3,
+    "she can" => 11,
+    "she in" => 3,
+    "she is" => 45,
+    "she not" => 3,
+    "she to" => 5,
+    "shell and" => 18,
+    "shell is" => 26,
+    "shell the" => 4,
+    "shell to" => 22,
+    "shift the" => 71,
+    "shift to" => 6,
+    "short a" => 5,
+    "short the" => 7,
+    "should a" => 4,
+    "should all" => 8,
+    "should an" => 3,
+    "should at" => 3,
+    "should be" => 1577,
+    "should have" => 135,
+    "should i" => 40,
+    "should in" => 8,
+    "should it" => 9,
+    "should not" => 517,
+    "should the" => 12,
+    "should type" => 3,
+    "should use" => 172,
+    "should you" => 30,
+    "show a" => 41,
+    "show and" => 10,
+    "show in" => 17,
+    "show of" => 7,
+    "show the" => 175,
+    "shown the" => 3,
+    "shown to" => 8,
+    "shows a" => 57,
+    "shows the" => 129,
+    "side a" => 5,
+    "side and" => 49,
+    "side in" => 8,
+    "side is" => 22,
+    "side of" => 153,
+    "side the" => 8,
+    "side to" => 16,
+    "sign a" => 8,
+    "sign and" => 36,
+    "sign for" => 7,
+    "sign in" => 10,
+    "sign is" => 40,
+    "sign of" => 40,
+    "sign the" => 12,
+    "sign to" => 8,
+    "signal the" => 5,
+    "signature to" => 6,
+    "signed and" => 25,
+    "similar and" => 7,
+    "similar for" => 12,
+    "similar in" => 9,
+    "similar to" => 732,
+    "similarly the" => 14,
+    "simple and" => 78,
+    "simple in" => 4,
+    "simple is" => 3,
+    "simple that" => 5,
+    "simple to" => 29,
+    "simply a" => 13,
+    "simply the" => 12,
+    "simply to" => 6,
+    "since a" => 27,
+    "since and" => 7,
+    "since for" => 8,
+    "since is" => 35,
+    "since it" => 136,
+    "since that" => 18,
+    "since the" => 291,
+    "since this" => 107,
+    "since you" => 42,
+    "single and" => 13,
+    "single in" => 3,
+    "single or" => 9,
+    "single that" => 4,
+    "single use" => 4,
+    "sir the" => 3,
+    "situation the" => 6,
+    "situations the" => 15,
+    "size a" => 3,
+    "size and" => 150,
+    "size as" => 38,
+    "size for" => 41,
+    "size if" => 5,
+    "size in" => 61,
+    "size is" => 110,
+    "size it" => 3,
+    "size of" => 472,
+    "size on" => 10,
+    "size or" => 26,
+    "size that" => 21,
+    "size the" => 23,
+    "size to" => 38,
+    "size with" => 14,
+    "size you" => 5,
+    "skip a" => 14,
+    "skip the" => 96,
+    "skip to" => 13,
+    "sleep the" => 5,
+    "slice a" => 4,
+    "slice and" => 64,
+    "slice for" => 7,
+    "slice in" => 32,
+    "slice is" => 103,
+    "slice of" => 184,
+    "slice that" => 18,
+    "slice the" => 5,
+    "slice to" => 40,
+    "slice with" => 70,
+    "small a" => 5,
+    "small and" => 40,
+    "small for" => 5,
+    "small in" => 3,
+    "small that" => 3,
+    "small to" => 43,
+    "smaller the" => 5,
+    "so a" => 61,
+    "so add" => 6,
+    "so all" => 24,
+    "so also" => 4,
+    "so an" => 8,
+    "so and" => 41,
+    "so any" => 17,
+    "so are" => 9,
+    "so as" => 57,
+    "so at" => 9,
+    "so be" => 13,
+    "so but" => 4,
+    "so by" => 29,
+    "so can" => 35,
+    "so code" => 4,
+    "so for" => 45,
+    "so i" => 96,
+    "so if" => 127,
+    "so in" => 59,
+    "so is" => 62,
+    "so it" => 278,
+    "so may" => 22,
+    "so more" => 3,
+    "so no" => 29,
+    "so not" => 5,
+    "so of" => 4,
+    "so on" => 101,
+    "so one" => 6,
+    "so only" => 21,
+    "so or" => 5,
+    "so rust" => 13,
+    "so that" => 1049,
+    "so the" => 314,
+    "so this" => 119,
+    "so to" => 44,
+    "so use" => 18,
+    "so using" => 14,
+    "so version" => 4,
+    "so vim" => 4,
+    "so was" => 3,
+    "so we" => 202,
+    "so when" => 29,
+    "so which" => 3,
+    "so will" => 48,
+    "so with" => 16,
+    "so you" => 348,
+    "so your" => 18,
+    "socket a" => 3,
+    "socket and" => 18,
+    "socket in" => 4,
+    "socket is" => 65,
+    "socket the" => 19,
+    "socket to" => 36,
+    "software a" => 4,
+    "software and" => 31,
+    "software to" => 15,
+    "solution but" => 6,
+    "solution by" => 4,
+    "solution for" => 39,
+    "solution from" => 4,
+    "solution in" => 8,
+    "solution is" => 43,
+    "solution must" => 3,
+    "solution of" => 6,
+    "solution that" => 7,
+    "solution to" => 28,
+    "solution we" => 4,
+    "solution when" => 4,
+    "solution without" => 4,
+    "solution would" => 6,
+    "some and" => 6,
+    "some are" => 14,
+    "some bit" => 6,
+    "some but" => 3,
+    "some code" => 99,
+    "some crate" => 3,
+    "some documentation" => 9,
+    "some example" => 12,
+    "some file" => 14,
+    "some files" => 32,
+    "some for" => 3,
+    "some function" => 5,
+    "some in" => 4,
+    "some more" => 59,
+    "some not" => 3,
+    "some of" => 462,
+    "some one" => 18,
+    "some or" => 8,
+    "some problem" => 4,
+    "some rust" => 20,
+    "some set" => 3,
+    "some test" => 11,
+    "some this" => 3,
+    "some to" => 3,
+    "some type" => 36,
+    "some use" => 16,
+    "some value" => 10,
+    "some version" => 11,
+    "something a" => 6,
+    "something and" => 13,
+    "something for" => 6,
+    "something in" => 34,
+    "something is" => 21,
+    "something of" => 22,
+    "something the" => 6,
+    "something to" => 55,
+    "sometimes a" => 24,
+    "sometimes the" => 18,
+    "sometimes to" => 6,
+    "soon the" => 10,
+    "sort the" => 25,
+    "source a" => 11,
+    "source and" => 52,
+    "source as" => 13,
+    "source for" => 29,
+    "source in" => 10,
+    "source is" => 38,
+    "source it" => 7,
+    "source of" => 85,
+    "source or" => 10,
+    "source that" => 12,
+    "source the" => 17,
+    "source this" => 4,
+    "source to" => 9,
+    "source with" => 7,
+    "source you" => 4,
+    "space a" => 11,
+    "space and" => 60,
+    "space for" => 50,
+    "space in" => 82,
+    "space is" => 49,
+    "space of" => 17,
+    "space that" => 14,
+    "space the" => 12,
+    "space this" => 4,
+    "space to" => 54,
+    "span the" => 3,
+    "special and" => 7,
+    "special in" => 14,
+    "special is" => 4,
+    "special the" => 3,
+    "special to" => 5,
+    "specific and" => 20,
+    "specific for" => 9,
+    "specific in" => 5,
+    "specific or" => 4,
+    "specific to" => 80,
+    "specifically the" => 42,
+    "specification the" => 8,
+    "specification to" => 4,
+    "specified a" => 30,
+    "specified an" => 3,
+    "specified and" => 32,
+    "specified as" => 85,
+    "specified by" => 382,
+    "specified can" => 4,
+    "specified for" => 39,
+    "specified in" => 269,
+    "specified is" => 19,
+    "specified it" => 34,
+    "specified on" => 11,
+    "specified or" => 14,
+    "specified that" => 5,
+    "specified the" => 68,
+    "specified this" => 5,
+    "specified to" => 26,
+    "specified when" => 18,
+    "specified with" => 276,
+    "specifies a" => 79,
+    "specifies the" => 267,
+    "specify a" => 173,
+    "specify and" => 6,
+    "specify for" => 4,
+    "specify in" => 13,
+    "specify the" => 326,
+    "specify to" => 10,
+    "specifying the" => 103,
+    "split a" => 29,
+    "split the" => 57,
+    "split to" => 12,
+    "stabilized the" => 3,
+    "stable and" => 59,
+    "stable for" => 5,
+    "stable in" => 6,
+    "stable is" => 12,
+    "stable the" => 5,
+    "stack and" => 57,
+    "stack for" => 8,
+    "stack in" => 15,
+    "stack is" => 19,
+    "stack of" => 16,
+    "stack the" => 4,
+    "stack to" => 12,
+    "standard a" => 5,
+    "standard and" => 43,
+    "standard as" => 3,
+    "standard for" => 46,
+    "standard in" => 11,
+    "standard is" => 10,
+    "standard of" => 10,
+    "standard or" => 4,
+    "standard that" => 5,
+    "standard the" => 3,
+    "start a" => 118,
+    "start an" => 5,
+    "start and" => 94,
+    "start as" => 8,
+    "start by" => 49,
+    "start for" => 7,
+    "start in" => 46,
+    "start is" => 15,
+    "start it" => 14,
+    "start of" => 426,
+    "start on" => 6,
+    "start or" => 32,
+    "start that" => 3,
+    "start the" => 83,
+    "start to" => 35,
+    "start when" => 7,
+    "start with" => 313,
+    "started a" => 7,
+    "started and" => 15,
+    "started in" => 21,
+    "started is" => 8,
+    "started the" => 9,
+    "started to" => 19,
+    "starting a" => 44,
+    "starting and" => 10,
+    "starting in" => 28,
+    "starting the" => 54,
+    "starting to" => 84,
+    "starts a" => 35,
+    "starts the" => 23,
+    "starts to" => 8,
+    "startup the" => 3,
+    "state a" => 6,
+    "state and" => 57,
+    "state are" => 3,
+    "state as" => 6,
+    "state by" => 8,
+    "state can" => 6,
+    "state for" => 13,
+    "state if" => 7,
+    "state in" => 37,
+    "state is" => 52,
+    "state it" => 7,
+    "state of" => 170,
+    "state on" => 5,
+    "state or" => 12,
+    "state that" => 32,
+    "state the" => 15,
+    "state to" => 30,
+    "state when" => 13,
+    "state with" => 11,
+    "state you" => 7,
+    "statement and" => 13,
+    "statement of" => 9,
+    "statement the" => 15,
+    "statement to" => 21,
+    "states the" => 5,
+    "static and" => 19,
+    "static in" => 4,
+    "static is" => 5,
+    "static to" => 5,
+    "status a" => 4,
+    "status and" => 14,
+    "status as" => 6,
+    "status for" => 5,
+    "status in" => 8,
+    "status is" => 9,
+    "status not" => 3,
+    "status of" => 52,
+    "status on" => 9,
+    "status or" => 5,
+    "status that" => 3,
+    "status this" => 3,
+    "status to" => 10,
+    "status when" => 4,
+    "std and" => 24,
+    "std to" => 4,
+    "step the" => 6,
+    "steps the" => 4,
+    "still a" => 71,
+    "still and" => 6,
+    "still as" => 8,
+    "still be" => 179,
+    "still can" => 3,
+    "still for" => 3,
+    "still if" => 3,
+    "still in" => 65,
+    "still is" => 6,
+    "still it" => 9,
+    "still not" => 21,
+    "still of" => 4,
+    "still on" => 8,
+    "still that" => 3,
+    "still the" => 13,
+    "still this" => 3,
+    "still to" => 7,
+    "still with" => 6,
+    "stop a" => 9,
+    "stop the" => 46,
+    "stop to" => 5,
+    "store a" => 40,
+    "store an" => 8,
+    "store and" => 20,
+    "store as" => 7,
+    "store files" => 4,
+    "store for" => 9,
+    "store in" => 79,
+    "store is" => 12,
+    "store it" => 25,
+    "store of" => 4,
+    "store or" => 5,
+    "store that" => 10,
+    "store the" => 2333,
+    "store this" => 5,
+    "store to" => 10,
+    "store with" => 12,
+    "stored and" => 17,
+    "stored the" => 3,
+    "stored to" => 9,
+    "stores the" => 92,
+    "storing the" => 52,
+    "stream a" => 4,
+    "stream and" => 37,
+    "stream for" => 7,
+    "stream in" => 17,
+    "stream is" => 84,
+    "stream of" => 55,
+    "stream that" => 31,
+    "stream the" => 14,
+    "stream to" => 44,
+    "streams the" => 3,
+    "string a" => 55,
+    "string an" => 25,
+    "string and" => 108,
+    "string any" => 16,
+    "string are" => 8,
+    "string as" => 53,
+    "string at" => 9,
+    "string but" => 11,
+    "string by" => 14,
+    "string can" => 23,
+    "string file" => 3,
+    "string for" => 45,
+    "string from" => 27,
+    "string function" => 30,
+    "string i" => 4,
+    "string if" => 45,
+    "string in" => 88,
+    "string is" => 194,
+    "string it" => 28,
+    "string may" => 7,
+    "string no" => 3,
+    "string not" => 7,
+    "string of" => 42,
+    "string on" => 11,
+    "string one" => 9,
+    "string only" => 7,
+    "string or" => 101,
+    "string rust" => 3,
+    "string see" => 21,
+    "string set" => 4,
+    "string that" => 75,
+    "string the" => 230,
+    "string this" => 14,
+    "string to" => 141,
+    "string type" => 27,
+    "string use" => 9,
+    "string used" => 6,
+    "string using" => 25,
+    "string value" => 28,
+    "string vim" => 6,
+    "string was" => 6,
+    "string we" => 5,
+    "string when" => 24,
+    "string which" => 14,
+    "string will" => 39,
+    "string with" => 59,
+    "string you" => 9,
+    "strings a" => 3,
+    "strings and" => 77,
+    "strings for" => 20,
+    "strings in" => 52,
+    "strings is" => 4,
+    "strings of" => 14,
+    "strings the" => 6,
+    "strings to" => 44,
+    "structure a" => 5,
+    "structure and" => 36,
+    "structure in" => 20,
+    "structure is" => 51,
+    "structure of" => 73,
+    "structure the" => 3,
+    "structure to" => 32,
+    "style and" => 11,
+    "style for" => 13,
+    "style in" => 5,
+    "style is" => 11,
+    "style of" => 37,
+    "style to" => 8,
+    "submitted for" => 1138,
+    "submitted in" => 5,
+    "submitted to" => 13,
+    "subtract the" => 69,
+    "succeeded the" => 3,
+    "success the" => 15,
+    "such a" => 409,
+    "such an" => 78,
+    "such and" => 8,
+    "such are" => 3,
+    "such as" => 2193,
+    "such can" => 15,
+    "such files" => 8,
+    "such if" => 3,
+    "such in" => 4,
+    "such is" => 11,
+    "such it" => 22,
+    "such that" => 176,
+    "such the" => 13,
+    "such this" => 6,
+    "such you" => 6,
+    "suffix the" => 6,
+    "sum the" => 5,
+    "summary the" => 3,
+    "supplied the" => 4,
+    "support a" => 60,
+    "support all" => 38,
+    "support an" => 5,
+    "support and" => 76,
+    "support any" => 26,
+    "support are" => 7,
+    "support as" => 15,
+    "support at" => 5,
+    "support bit" => 5,
+    "support but" => 11,
+    "support by" => 19,
+    "support can" => 19,
+    "support code" => 8,
+    "support crate" => 8,
+    "support documentation" => 3,
+    "support files" => 4,
+    "support for" => 1644,
+    "support from" => 10,
+    "support i" => 4,
+    "support in" => 76,
+    "support is" => 190,
+    "support it" => 33,
+    "support license" => 6,
+    "support more" => 16,
+    "support not" => 6,
+    "support of" => 88,
+    "support on" => 20,
+    "support one" => 4,
+    "support only" => 9,
+    "support or" => 30,
+    "support rust" => 15,
+    "support see" => 12,
+    "support that" => 14,
+    "support the" => 168,
+    "support this" => 59,
+    "support to" => 63,
+    "support use" => 7,
+    "support using" => 40,
+    "support vim" => 3,
+    "support was" => 25,
+    "support when" => 5,
+    "support which" => 10,
+    "support will" => 10,
+    "support with" => 28,
+    "support you" => 15,
+    "supported and" => 48,
+    "supported are" => 5,
+    "supported as" => 27,
+    "supported by" => 275,
+    "supported for" => 69,
+    "supported if" => 5,
+    "supported in" => 60,
+    "supported is" => 5,
+    "supported it" => 3,
+    "supported on" => 95,
+    "supported or" => 6,
+    "supported the" => 14,
+    "supported to" => 3,
+    "supported use" => 3,
+    "supported when" => 18,
+    "supported with" => 30,
+    "supports a" => 75,
+    "supports and" => 36,
+    "supports for" => 4,
+    "supports in" => 4,
+    "supports is" => 3,
+    "supports that" => 4,
+    "supports the" => 182,
+    "supports this" => 14,
+    "supports to" => 3,
+    "supports with" => 8,
+    "suppress the" => 18,
+    "sure a" => 9,
+    "sure is" => 4,
+    "sure of" => 11,
+    "sure the" => 91,
+    "sure to" => 178,
+    "swap the" => 25,
+    "switch the" => 21,
+    "symbol the" => 17,
+    "sync the" => 5,
+    "syntax and" => 108,
+    "syntax are" => 5,
+    "syntax as" => 18,
+    "syntax by" => 4,
+    "syntax can" => 10,
+    "syntax files" => 53,
+    "syntax for" => 108,
+    "syntax from" => 4,
+    "syntax if" => 6,
+    "syntax in" => 49,
+    "syntax is" => 111,
+    "syntax it" => 5,
+    "syntax not" => 7,
+    "syntax of" => 82,
+    "syntax on" => 30,
+    "syntax or" => 11,
+    "syntax that" => 39,
+    "syntax the" => 9,
+    "syntax this" => 3,
+    "syntax to" => 53,
+    "syntax use" => 3,
+    "syntax vim" => 3,
+    "syntax when" => 8,
+    "syntax will" => 9,
+    "syntax with" => 21,
+    "syntax you" => 8,
+    "system and" => 85,
+    "system are" => 9,
+    "system as" => 14,
+    "system by" => 5,
+    "system can" => 7,
+    "system for" => 44,
+    "system if" => 5,
+    "system in" => 23,
+    "system is" => 51,
+    "system it" => 12,
+    "system of" => 20,
+    "system on" => 10,
+    "system or" => 28,
+    "system that" => 50,
+    "system the" => 23,
+    "system this" => 6,
+    "system to" => 60,
+    "system when" => 5,
+    "system with" => 32,
+    "system you" => 23,
+    "systems a" => 4,
+    "systems and" => 42,
+    "systems for" => 7,
+    "systems in" => 11,
+    "systems is" => 11,
+    "systems of" => 3,
+    "systems the" => 32,
+    "systems to" => 15,
+    "tab and" => 26,
+    "tab for" => 9,
+    "tab in" => 15,
+    "tab is" => 32,
+    "tab of" => 4,
+    "tab the" => 3,
+    "tab to" => 10,
+    "table a" => 3,
+    "table and" => 38,
+    "table for" => 21,
+    "table in" => 30,
+    "table is" => 33,
+    "table of" => 144,
+    "table the" => 8,
+    "table to" => 29,
+    "tag a" => 3,
+    "tag and" => 29,
+    "tag for" => 19,
+    "tag in" => 37,
+    "tag is" => 47,
+    "tag of" => 10,
+    "tag the" => 10,
+    "tag to" => 21,
+    "tags and" => 32,
+    "tags in" => 21,
+    "tags is" => 3,
+    "tags of" => 4,
+    "tags the" => 9,
+    "tags to" => 17,
+    "take a" => 357,
+    "take and" => 15,
+    "take for" => 11,
+    "take in" => 14,
+    "take that" => 7,
+    "take the" => 181,
+    "take this" => 21,
+    "take to" => 9,
+    "taken the" => 11,
+    "takes a" => 237,
+    "takes and" => 17,
+    "takes in" => 16,
+    "takes the" => 86,
+    "takes to" => 22,
+    "taking the" => 47,
+    "target a" => 7,
+    "target an" => 3,
+    "target and" => 64,
+    "target are" => 11,
+    "target as" => 13,
+    "target by" => 25,
+    "target can" => 69,
+    "target files" => 3,
+    "target for" => 78,
+    "target from" => 12,
+    "target in" => 36,
+    "target is" => 176,
+    "target it" => 6,
+    "target not" => 4,
+    "target of" => 16,
+    "target on" => 9,
+    "target or" => 15,
+    "target that" => 26,
+    "target the" => 27,
+    "target this" => 9,
+    "target to" => 42,
+    "target use" => 3,
+    "target when" => 11,
+    "target will" => 20,
+    "target with" => 32,
+    "target you" => 38,
+    "targets and" => 43,
+    "targets for" => 34,
+    "targets in" => 24,
+    "targets is" => 16,
+    "targets of" => 9,
+    "targets the" => 18,
+    "targets to" => 14,
+    "tell a" => 4,
+    "tell and" => 3,
+    "tell the" => 73,
+    "tell to" => 9,
+    "tells the" => 30,
+    "term and" => 4,
+    "term in" => 10,
+    "term is" => 19,
+    "term of" => 5,
+    "term to" => 11,
+    "terminal and" => 27,
+    "terminal are" => 4,
+    "terminal as" => 3,
+    "terminal can" => 5,
+    "terminal for" => 12,
+    "terminal if" => 3,
+    "terminal in" => 29,
+    "terminal is" => 41,
+    "terminal not" => 6,
+    "terminal on" => 3,
+    "terminal or" => 9,
+    "terminal that" => 8,
+    "terminal the" => 14,
+    "terminal to" => 23,
+    "terminal when" => 10,
+    "terminal with" => 20,
+    "terminal you" => 17,
+    "terms and" => 5,
+    "terms for" => 4,
+    "terms in" => 3,
+    "terms of" => 354,
+    "terms the" => 5,
+    "terms to" => 4,
+    "terms with" => 6,
+    "test a" => 25,
+    "test all" => 14,
+    "test also" => 3,
+    "test an" => 4,
+    "test and" => 81,
+    "test are" => 4,
+    "test as" => 24,
+    "test but" => 3,
+    "test by" => 13,
+    "test can" => 11,
+    "test code" => 28,
+    "test command" => 12,
+    "test crate" => 4,
+    "test data" => 9,
+    "test does" => 19,
+    "test examples" => 5,
+    "test file" => 45,
+    "test files" => 45,
+    "test for" => 373,
+    "test from" => 6,
+    "test function" => 74,
+    "test has" => 10,
+    "test i" => 3,
+    "test if" => 32,
+    "test in" => 41,
+    "test into" => 9,
+    "test is" => 201,
+    "test it" => 23,
+    "test like" => 3,
+    "test may" => 41,
+    "test more" => 9,
+    "test name" => 20,
+    "test new" => 4,
+    "test not" => 5,
+    "test number" => 4,
+    "test of" => 10,
+    "test on" => 42,
+    "test one" => 4,
+    "test only" => 6,
+    "test option" => 3,
+    "test or" => 16,
+    "test out" => 8,
+    "test set" => 8,
+    "test should" => 10,
+    "test so" => 4,
+    "test support" => 19,
+    "test test" => 10,
+    "test that" => 62,
+    "test the" => 89,
+    "test then" => 3,
+    "test this" => 17,
+    "test time" => 5,
+    "test to" => 67,
+    "test using" => 9,
+    "test version" => 3,
+    "test vim" => 953,
+    "test was" => 7,
+    "test we" => 5,
+    "test when" => 14,
+    "test will" => 27,
+    "test with" => 62,
+    "test without" => 6,
+    "test you" => 5,
+    "test your" => 17,
+    "tested and" => 26,
+    "tested in" => 36,
+    "tested the" => 6,
+    "tested to" => 7,
+    "testing a" => 11,
+    "testing and" => 32,
+    "testing for" => 61,
+    "testing in" => 9,
+    "testing is" => 28,
+    "testing of" => 21,
+    "testing the" => 27,
+    "testing to" => 10,
+    "tests a" => 9,
+    "tests and" => 100,
+    "tests are" => 167,
+    "tests as" => 20,
+    "tests by" => 9,
+    "tests can" => 36,
+    "tests for" => 211,
+    "tests from" => 18,
+    "tests if" => 14,
+    "tests in" => 94,
+    "tests is" => 16,
+    "tests it" => 8,
+    "tests not" => 3,
+    "tests of" => 11,
+    "tests on" => 28,
+    "tests or" => 11,
+    "tests that" => 78,
+    "tests the" => 20,
+    "tests to" => 77,
+    "tests use" => 10,
+    "tests when" => 8,
+    "tests will" => 22,
+    "tests with" => 105,
+    "tests you" => 28,
+    "text a" => 9,
+    "text and" => 120,
+    "text are" => 11,
+    "text as" => 36,
+    "text by" => 17,
+    "text can" => 16,
+    "text files" => 35,
+    "text for" => 55,
+    "text from" => 79,
+    "text i" => 4,
+    "text if" => 10,
+    "text in" => 212,
+    "text is" => 167,
+    "text it" => 14,
+    "text not" => 11,
+    "text of" => 57,
+    "text on" => 33,
+    "text or" => 28,
+    "text that" => 94,
+    "text the" => 18,
+    "text this" => 6,
+    "text to" => 145,
+    "text use" => 7,
+    "text using" => 6,
+    "text vim" => 7,
+    "text when" => 23,
+    "text which" => 15,
+    "text will" => 42,
+    "text with" => 72,
+    "text you" => 31,
+    "than a" => 210,
+    "than an" => 45,
+    "than and" => 89,
+    "than are" => 10,
+    "than as" => 15,
+    "than at" => 6,
+    "than by" => 20,
+    "than file" => 3,
+    "than for" => 16,
+    "than from" => 5,
+    "than i" => 38,
+    "than if" => 11,
+    "than in" => 48,
+    "than is" => 21,
+    "than it" => 52,
+    "than not" => 3,
+    "than of" => 16,
+    "than on" => 16,
+    "than or" => 298,
+    "than rust" => 6,
+    "than that" => 73,
+    "than the" => 675,
+    "than this" => 32,
+    "than to" => 53,
+    "than use" => 4,
+    "than using" => 45,
+    "than when" => 17,
+    "than will" => 7,
+    "than with" => 16,
+    "than you" => 34,
+    "that a" => 563,
+    "that about" => 3,
+    "that access" => 10,
+    "that add" => 10,
+    "that additional" => 3,
+    "that after" => 32,
+    "that all" => 259,
+    "that allow" => 51,
+    "that allows" => 127,
+    "that also" => 57,
+    "that always" => 20,
+    "that an" => 122,
+    "that and" => 89,
+    "that another" => 6,
+    "that any" => 124,
+    "that are" => 1147,
+    "that argument" => 5,
+    "that as" => 49,
+    "that at" => 39,
+    "that attribute" => 6,
+    "that automatically" => 8,
+    "that be" => 9,
+    "that because" => 27,
+    "that before" => 8,
+    "that behavior" => 5,
+    "that being" => 19,
+    "that bit" => 17,
+    "that block" => 24,
+    "that both" => 45,
+    "that buffer" => 24,
+    "that build" => 12,
+    "that but" => 14,
+    "that by" => 55,
+    "that byte" => 14,
+    "that bytes" => 6,
+    "that call" => 22,
+    "that called" => 4,
+    "that calling" => 26,
+    "that can" => 903,
+    "that cannot" => 60,
+    "that cargo" => 88,
+    "that case" => 113,
+    "that change" => 26,
+    "that changes" => 21,
+    "that character" => 11,
+    "that check" => 7,
+    "that code" => 61,
+    "that command" => 18,
+    "that commands" => 6,
+    "that compile" => 5,
+    "that contains" => 217,
+    "that could" => 111,
+    "that crate" => 26,
+    "that crates" => 9,
+    "that create" => 8,
+    "that created" => 6,
+    "that current" => 3,
+    "that currently" => 13,
+    "that data" => 60,
+    "that default" => 4,
+    "that dependency" => 11,
+    "that different" => 7,
+    "that directly" => 12,
+    "that directory" => 36,
+    "that do" => 130,
+    "that does" => 223,
+    "that doesn't" => 151,
+    "that don't" => 125,
+    "that done" => 3,
+    "that each" => 59,
+    "that either" => 31,
+    "that element" => 6,
+    "that elements" => 3,
+    "that enable" => 13,
+    "that encoding" => 5,
+    "that end" => 25,
+    "that environment" => 6,
+    "that error" => 14,
+    "that errors" => 4,
+    "that even" => 62,
+    "that event" => 3,
+    "that every" => 49,
+    "that explicitly" => 6,
+    "that expression" => 10,
+    "that fails" => 28,
+    "that feature" => 19,
+    "that features" => 5,
+    "that field" => 24,
+    "that file" => 82,
+    "that files" => 5,
+    "that first" => 15,
+    "that fix" => 6,
+    "that flag" => 3,
+    "that for" => 157,
+    "that format" => 5,
+    "that from" => 8,
+    "that function" => 47,
+    "that functions" => 15,
+    "that future" => 15,
+    "that generated" => 8,
+    "that get" => 10,
+    "that git" => 23,
+    "that given" => 11,
+    "that go" => 7,
+    "that had" => 83,
+    "that handle" => 6,
+    "that has" => 344,
+    "that have" => 302,
+    "that he" => 436,
+    "that help" => 10,
+    "that her" => 32,
+    "that here" => 7,
+    "that his" => 58,
+    "that however" => 5,
+    "that i" => 601,
+    "that if" => 231,
+    "that implement" => 115,
+    "that in" => 217,
+    "that include" => 23,
+    "that index" => 8,
+    "that information" => 20,
+    "that input" => 5,
+    "that insert" => 3,
+    "that instance" => 3,
+    "that instead" => 19,
+    "that into" => 10,
+    "that is" => 2154,
+    "that it" => 1067,
+    "that it's" => 103,
+    "that item" => 19,
+    "that its" => 49,
+    "that just" => 19,
+    "that key" => 15,
+    "that know" => 7,
+    "that language" => 8,
+    "that last" => 9,
+    "that library" => 11,
+    "that license" => 4,
+    "that like" => 8,
+    "that line" => 34,
+    "that lines" => 5,
+    "that link" => 4,
+    "that list" => 5,
+    "that long" => 5,
+    "that look" => 14,
+    "that macro" => 6,
+    "that macros" => 4,
+    "that made" => 19,
+    "that make" => 63,
+    "that many" => 45,
+    "that match" => 77,
+    "that may" => 277,
+    "that means" => 93,
+    "that memory" => 26,
+    "that message" => 13,
+    "that method" => 27,
+    "that methods" => 3,
+    "that might" => 88,
+    "that module" => 15,
+    "that more" => 21,
+    "that most" => 32,
+    "that move" => 5,
+    "that much" => 16,
+    "that multiple" => 13,
+    "that must" => 80,
+    "that my" => 74,
+    "that name" => 43,
+    "that names" => 4,
+    "that need" => 81,
+    "that never" => 15,
+    "that new" => 16,
+    "that next" => 8,
+    "that no" => 122,
+    "that node" => 9,
+    "that non" => 9,
+    "that not" => 28,
+    "that note" => 3,
+    "that now" => 12,
+    "that number" => 12,
+    "that object" => 19,
+    "that of" => 169,
+    "that on" => 39,
+    "that once" => 9,
+    "that one" => 107,
+    "that only" => 144,
+    "that open" => 3,
+    "that operations" => 5,
+    "that or" => 8,
+    "that order" => 24,
+    "that other" => 34,
+    "that otherwise" => 3,
+    "that our" => 76,
+    "that out" => 6,
+    "that output" => 13,
+    "that package" => 8,
+    "that part" => 21,
+    "that passed" => 3,
+    "that patch" => 6,
+    "that path" => 13,
+    "that pattern" => 9,
+    "that performance" => 6,
+    "that platform" => 8,
+    "that please" => 4,
+    "that point" => 48,
+    "that pointer" => 9,
+    "that problem" => 4,
+    "that process" => 10,
+    "that program" => 3,
+    "that project" => 15,
+    "that provide" => 35,
+    "that provided" => 4,
+    "that provides" => 142,
+    "that python" => 3,
+    "that range" => 23,
+    "that read" => 5,
+    "that reference" => 23,
+    "that required" => 3,
+    "that requires" => 61,
+    "that result" => 12,
+    "that results" => 12,
+    "that return" => 43,
+    "that returned" => 3,
+    "that returns" => 139,
+    "that right" => 3,
+    "that run" => 24,
+    "that running" => 3,
+    "that rust" => 69,
+    "that safe" => 3,
+    "that said" => 58,
+    "that same" => 26,
+    "that script" => 16,
+    "that search" => 3,
+    "that section" => 11,
+    "that see" => 3,
+    "that server" => 11,
+    "that set" => 6,
+    "that shall" => 4,
+    "that she" => 180,
+    "that should" => 146,
+    "that since" => 9,
+    "that single" => 7,
+    "that size" => 6,
+    "that small" => 3,
+    "that so" => 15,
+    "that some" => 107,
+    "that source" => 6,
+    "that specific" => 10,
+    "that start" => 62,
+    "that state" => 9,
+    "that still" => 13,
+    "that store" => 4,
+    "that string" => 11,
+    "that such" => 28,
+    "that support" => 113,
+    "that supports" => 92,
+    "that syntax" => 7,
+    "that system" => 8,
+    "that take" => 49,
+    "that target" => 25,
+    "that test" => 15,
+    "that tests" => 8,
+    "that text" => 17,
+    "that that" => 29,
+    "that the" => 3388,
+    "that their" => 35,
+    "that then" => 5,
+    "that there" => 300,
+    "that these" => 87,
+    "that they" => 342,
+    "that this" => 766,
+    "that those" => 16,
+    "that thread" => 13,
+    "that time" => 51,
+    "that to" => 81,
+    "that too" => 7,
+    "that trait" => 16,
+    "that two" => 33,
+    "that type" => 88,
+    "that types" => 18,
+    "that under" => 5,
+    "that unless" => 9,
+    "that up" => 10,
+    "that usage" => 4,
+    "that use" => 152,
+    "that used" => 30,
+    "that user" => 16,
+    "that uses" => 215,
+    "that using" => 71,
+    "that value" => 72,
+    "that values" => 12,
+    "that variable" => 14,
+    "that variables" => 6,
+    "that version" => 15,
+    "that very" => 16,
+    "that vim" => 98,
+    "that want" => 11,
+    "that was" => 385,
+    "that way" => 81,
+    "that we" => 520,
+    "that well" => 3,
+    "that were" => 146,
+    "that what" => 22,
+    "that when" => 169,
+    "that which" => 29,
+    "that while" => 37,
+    "that will" => 500,
+    "that window" => 27,
+    "that windows" => 9,
+    "that with" => 50,
+    "that within" => 3,
+    "that without" => 11,
+    "that work" => 51,
+    "that works" => 79,
+    "that would" => 218,
+    "that write" => 6,
+    "that you" => 1113,
+    "that your" => 135,
+    "that zero" => 6,
+    "that's a" => 55,
+    "that's the" => 68,
+    "the a" => 72,
+    "the ability" => 158,
+    "the about" => 3,
+    "the above" => 371,
+    "the absolute" => 114,
+    "the abstraction" => 7,
+    "the accepted" => 8,
+    "the access" => 49,
+    "the account" => 38,
+    "the action" => 12,
+    "the actions" => 7,
+    "the active" => 92,
+    "the actual" => 271,
+    "the actually" => 6,
+    "the add" => 32,
+    "the added" => 25,
+    "the adding" => 5,
+    "the addition" => 42,
+    "the additional" => 86,
+    "the address" => 174,
+    "the addresses" => 13,
+    "the adds" => 3,
+    "the advanced" => 17,
+    "the after" => 26,
+    "the algorithm" => 82,
+    "the algorithms" => 21,
+    "the alias" => 28,
+    "the alignment" => 113,
+    "the all" => 15,
+    "the allocated" => 63,
+    "the allocation" => 99,
+    "the allocator" => 91,
+    "the allow" => 6,
+    "the allowed" => 19,
+    "the allowing" => 4,
+    "the allows" => 11,
+    "the already" => 14,
+    "the also" => 9,
+    "the alternative" => 18,
+    "the am" => 3,
+    "the amount" => 223,
+    "the analysis" => 15,
+    "the and" => 1208,
+    "the android" => 21,
+    "the annotation" => 42,
+    "the ansi" => 27,
+    "the any" => 13,
+    "the apache" => 1312,
+    "the apis" => 54,
+    "the append" => 5,
+    "the apple" => 12,
+    "the application" => 159,
+    "the applications" => 4,
+    "the applied" => 4,
+    "the apply" => 3,
+    "the approach" => 21,
+    "the appropriate" => 161,
+    "the arabic" => 11,
+    "the arbitrary" => 4,
+    "the architecture" => 27,
+    "the are" => 24,
+    "the area" => 22,
+    "the argument" => 647,
+    "the arguments" => 190,
+    "the arithmetic" => 7,
+    "the arm" => 84,
+    "the array" => 158,
+    "the arrays" => 5,
+    "the artifacts" => 8,
+    "the as" => 46,
+    "the ascii" => 45,
+    "the assembly" => 96,
+    "the assert" => 10,
+    "the assigned" => 18,
+    "the assignment" => 31,
+    "the associated" => 180,
+    "the asynchronous" => 53,
+    "the at" => 20,
+    "the atomic" => 31,
+    "the attempt" => 7,
+    "the attempts" => 4,
+    "the attribute" => 687,
+    "the attributes" => 49,
+    "the authentication" => 32,
+    "the auto" => 16,
+    "the automatic" => 43,
+    "the automatically" => 11,
+    "the available" => 99,
+    "the back" => 82,
+    "the backend" => 73,
+    "the background" => 74,
+    "the backslash" => 54,
+    "the bad" => 25,
+    "the bar" => 15,
+    "the bare" => 22,
+    "the base" => 217,
+    "the based" => 6,
+    "the basic" => 118,
+    "the be" => 4,
+    "the because" => 8,
+    "the before" => 16,
+    "the beginning" => 199,
+    "the behavior" => 269,
+    "the being" => 18,
+    "the below" => 36,
+    "the benchmark" => 74,
+    "the benchmarks" => 79,
+    "the best" => 243,
+    "the better" => 22,
+    "the big" => 30,
+    "the binaries" => 17,
+    "the binary" => 301,
+    "the binding" => 31,
+    "the bindings" => 60,
+    "the bit" => 244,
+    "the bits" => 110,
+    "the bitwise" => 212,
+    "the black" => 26,
+    "the blob" => 41,
+    "the block" => 230,
+    "the blocking" => 16,
+    "the blocks" => 7,
+    "the body" => 205,
+    "the book" => 106,
+    "the boolean" => 54,
+    "the borrow" => 86,
+    "the borrowed" => 54,
+    "the both" => 4,
+    "the bound" => 48,
+    "the boundary" => 37,
+    "the bounds" => 48,
+    "the box" => 112,
+    "the boy" => 57,
+    "the branch" => 167,
+    "the branches" => 25,
+    "the break" => 10,
+    "the breaking" => 12,
+    "the broken" => 11,
+    "the browser" => 85,
+    "the buffer" => 788,
+    "the buffers" => 31,
+    "the bug" => 30,
+    "the build" => 423,
+    "the builder" => 49,
+    "the building" => 26,
+    "the builds" => 3,
+    "the built" => 108,
+    "the but" => 8,
+    "the button" => 15,
+    "the by" => 21,
+    "the byte" => 118,
+    "the bytes" => 120,
+    "the cache" => 87,
+    "the call" => 231,
+    "the callback" => 233,
+    "the called" => 8,
+    "the caller" => 223,
+    "the calling" => 77,
+    "the calls" => 37,
+    "the can" => 44,
+    "the capacity" => 105,
+    "the capital" => 5,
+    "the capture" => 13,
+    "the care" => 4,
+    "the cargo" => 236,
+    "the case" => 439,
+    "the cases" => 23,
+    "the cast" => 9,
+    "the catch" => 13,
+    "the cause" => 46,
+    "the causes" => 4,
+    "the cell" => 84,
+    "the certificate" => 56,
+    "the chain" => 30,
+    "the change" => 114,
+    "the changed" => 17,
+    "the changelog" => 72,
+    "the changes" => 168,
+    "the changing" => 5,
+    "the channel" => 246,
+    "the channels" => 6,
+    "the chapter" => 46,
+    "the char" => 6,
+    "the character" => 360,
+    "the characters" => 132,
+    "the chat" => 16,
+    "the check" => 91,
+    "the checked" => 8,
+    "the checking" => 5,
+    "the checks" => 16,
+    "the child" => 245,
+    "the choice" => 44,
+    "the chunk" => 29,
+    "the class" => 319,
+    "the classes" => 3,
+    "the clause" => 14,
+    "the clean" => 12,
+    "the clear" => 14,
+    "the click" => 6,
+    "the client" => 233,
+    "the clipboard" => 109,
+    "the clone" => 10,
+    "the close" => 19,
+    "the closed" => 19,
+    "the closing" => 13,
+    "the closure" => 305,
+    "the closures" => 11,
+    "the code" => 1094,
+    "the codes" => 17,
+    "the collection" => 83,
+    "the collections" => 20,
+    "the color" => 84,
+    "the colors" => 63,
+    "the column" => 107,
+    "the combination" => 24,
+    "the comma" => 22,
+    "the command" => 1601,
+    "the commands" => 149,
+    "the comment" => 69,
+    "the comments" => 35,
+    "the commit" => 117,
+    "the commits" => 37,
+    "the common" => 121,
+    "the communication" => 10,
+    "the community" => 51,
+    "the compare" => 4,
+    "the comparison" => 116,
+    "the compatibility" => 28,
+    "the compatible" => 18,
+    "the compilation" => 44,
+    "the compile" => 29,
+    "the compiled" => 55,
+    "the compiler" => 931,
+    "the compiling" => 3,
+    "the complete" => 79,
+    "the completion" => 75,
+    "the complex" => 165,
+    "the component" => 45,
+    "the components" => 17,
+    "the composed" => 5,
+    "the compression" => 27,
+    "the computer" => 30,
+    "the concurrent" => 5,
+    "the condition" => 96,
+    "the conditional" => 19,
+    "the conditions" => 26,
+    "the conduct" => 3,
+    "the configuration" => 112,
+    "the configure" => 36,
+    "the configured" => 14,
+    "the connection" => 106,
+    "the connections" => 7,
+    "the consistent" => 3,
+    "the console" => 105,
+    "the const" => 20,
+    "the constant" => 47,
+    "the constants" => 10,
+    "the construct" => 18,
+    "the constructor" => 106,
+    "the contained" => 120,
+    "the containing" => 21,
+    "the contains" => 29,
+    "the content" => 124,
+    "the contents" => 451,
+    "the context" => 238,
+    "the continue" => 4,
+    "the contract" => 60,
+    "the contributing" => 62,
+    "the contribution" => 18,
+    "the control" => 93,
+    "the convenience" => 13,
+    "the convenient" => 4,
+    "the convention" => 14,
+    "the conversion" => 100,
+    "the conversions" => 6,
+    "the converted" => 6,
+    "the copied" => 8,
+    "the copy" => 51,
+    "the copying" => 3,
+    "the copyright" => 20,
+    "the core" => 233,
+    "the correct" => 184,
+    "the correctly" => 3,
+    "the corresponding" => 1934,
+    "the cost" => 84,
+    "the could" => 6,
+    "the count" => 95,
+    "the course" => 28,
+    "the coverage" => 23,
+    "the cpu" => 82,
+    "the crash" => 9,
+    "the crate" => 2299,
+    "the crates" => 89,
+    "the create" => 8,
+    "the created" => 35,
+    "the creates" => 3,
+    "the creating" => 5,
+    "the creation" => 82,
+    "the cross" => 29,
+    "the current" => 2507,
+    "the currently" => 69,
+    "the cursor" => 1476,
+    "the curve" => 13,
+    "the custom" => 69,
+    "the data" => 771,
+    "the database" => 90,
+    "the date" => 50,
+    "the day" => 117,
+    "the days" => 15,
+    "the dead" => 37,
+    "the debug" => 45,
+    "the debugger" => 51,
+    "the debugging" => 16,
+    "the decimal" => 33,
+    "the declaration" => 44,
+    "the declarations" => 10,
+    "the declared" => 18,
+    "the decode" => 11,
+    "the decoding" => 14,
+    "the def" => 8,
+    "the default" => 1641,
+    "the defaults" => 46,
+    "the define" => 10,
+    "the defined" => 22,
+    "the defining" => 12,
+    "the definition" => 126,
+    "the definitions" => 34,
+    "the delete" => 23,
+    "the deleted" => 17,
+    "the dependencies" => 82,
+    "the dependency" => 228,
+    "the deprecated" => 22,
+    "the derive" => 85,
+    "the derived" => 16,
+    "the described" => 4,
+    "the description" => 39,
+    "the descriptor" => 18,
+    "the design" => 65,
+    "the desired" => 107,
+    "the detailed" => 17,
+    "the details" => 84,
+    "the detected" => 11,
+    "the detection" => 11,
+    "the developers" => 16,
+    "the development" => 89,
+    "the device" => 23,
+    "the diagnostic" => 20,
+    "the dialog" => 28,
+    "the dictionary" => 67,
+    "the did" => 6,
+    "the difference" => 165,
+    "the differences" => 74,
+    "the different" => 103,
+    "the digit" => 3,
+    "the digits" => 6,
+    "the direct" => 28,
+    "the directly" => 5,
+    "the directories" => 72,
+    "the directory" => 539,
+    "the disabled" => 3,
+    "the discord" => 25,
+    "the discussion" => 24,
+    "the disk" => 12,
+    "the display" => 74,
+    "the displayed" => 17,
+    "the distributed" => 24,
+    "the distribution" => 40,
+    "the do" => 13,
+    "the doc" => 27,
+    "the document" => 50,
+    "the documentation" => 705,
+    "the documented" => 3,
+    "the documents" => 9,
+    "the does" => 17,
+    "the doesn't" => 9,
+    "the domain" => 41,
+    "the door" => 216,
+    "the dos" => 20,
+    "the dot" => 26,
+    "the double" => 35,
+    "the down" => 3,
+    "the download" => 5,
+    "the downloads" => 3,
+    "the driver" => 47,
+    "the drop" => 31,
+    "the dropped" => 5,
+    "the dual" => 8,
+    "the duplicate" => 9,
+    "the duration" => 68,
+    "the dynamic" => 46,
+    "the dynamically" => 3,
+    "the earlier" => 15,
+    "the early" => 27,
+    "the easier" => 5,
+    "the easy" => 13,
+    "the ecosystem" => 38,
+    "the edit" => 54,
+    "the editing" => 10,
+    "the edition" => 246,
+    "the editor" => 50,
+    "the effect" => 91,
+    "the effects" => 27,
+    "the efficient" => 4,
+    "the effort" => 12,
+    "the either" => 3,
+    "the electronic" => 35,
+    "the element" => 526,
+    "the elements" => 211,
+    "the else" => 23,
+    "the embedded" => 40,
+    "the emitted" => 6,
+    "the empty" => 68,
+    "the enable" => 7,
+    "the enabled" => 14,
+    "the encode" => 7,
+    "the encoded" => 23,
+    "the encoding" => 195,
+    "the encryption" => 21,
+    "the end" => 1212,
+    "the ends" => 12,
+    "the engine" => 25,
+    "the english" => 72,
+    "the enter" => 11,
+    "the entered" => 4,
+    "the entire" => 395,
+    "the entries" => 48,
+    "the entry" => 119,
+    "the environment" => 624,
+    "the environments" => 4,
+    "the equal" => 9,
+    "the equivalent" => 101,
+    "the error" => 685,
+    "the errors" => 38,
+    "the esc" => 17,
+    "the escape" => 36,
+    "the etc" => 6,
+    "the evaluation" => 32,
+    "the even" => 18,
+    "the event" => 628,
+    "the events" => 35,
+    "the ever" => 5,
+    "the ex" => 28,
+    "the exact" => 215,
+    "the example" => 373,
+    "the examples" => 291,
+    "the except" => 21,
+    "the exception" => 215,
+    "the exceptions" => 10,
+    "the exclusive" => 7,
+    "the executable" => 137,
+    "the execute" => 6,
+    "the executed" => 18,
+    "the execution" => 85,
+    "the exist" => 5,
+    "the existing" => 159,
+    "the exists" => 4,
+    "the exit" => 47,
+    "the expand" => 7,
+    "the expanded" => 34,
+    "the expansion" => 19,
+    "the expected" => 145,
+    "the experimental" => 21,
+    "the explanation" => 12,
+    "the explicit" => 17,
+    "the explicitly" => 3,
+    "the export" => 13,
+    "the exported" => 19,
+    "the exports" => 12,
+    "the exposed" => 9,
+    "the expression" => 339,
+    "the expressions" => 26,
+    "the extended" => 21,
+    "the extension" => 92,
+    "the extensions" => 11,
+    "the extern" => 21,
+    "the external" => 66,
+    "the extra" => 64,
+    "the eyes" => 17,
+    "the face" => 30,
+    "the fact" => 113,
+    "the failed" => 22,
+    "the fails" => 3,
+    "the failure" => 53,
+    "the false" => 8,
+    "the family" => 68,
+    "the far" => 19,
+    "the fast" => 27,
+    "the faster" => 5,
+    "the father" => 11,
+    "the feature" => 1416,
+    "the features" => 208,
+    "the fetch" => 9,
+    "the few" => 15,
+    "the field" => 520,
+    "the fields" => 140,
+    "the figure" => 6,
+    "the file" => 2334,
+    "the filename" => 89,
+    "the files" => 209,
+    "the filesystem" => 64,
+    "the filled" => 10,
+    "the filter" => 50,
+    "the final" => 229,
+    "the finally" => 43,
+    "the find" => 24,
+    "the fine" => 4,
+    "the finished" => 4,
+    "the first" => 2451,
+    "the five" => 20,
+    "the fix" => 23,
+    "the fixed" => 20,
+    "the fixes" => 5,
+    "the flag" => 561,
+    "the flags" => 69,
+    "the float" => 10,
+    "the floating" => 28,
+    "the flow" => 15,
+    "the focus" => 25,
+    "the fold" => 86,
+    "the folder" => 87,
+    "the folding" => 13,
+    "the folds" => 24,
+    "the follow" => 3,
+    "the following" => 3522,
+    "the font" => 84,
+    "the fonts" => 17,
+    "the for" => 66,
+    "the force" => 15,
+    "the fork" => 16,
+    "the form" => 220,
+    "the format" => 202,
+    "the formats" => 6,
+    "the formatting" => 46,
+    "the forms" => 9,
+    "the forward" => 20,
+    "the found" => 8,
+    "the four" => 44,
+    "the frame" => 51,
+    "the framework" => 39,
+    "the free" => 28,
+    "the from" => 77,
+    "the front" => 109,
+    "the full" => 368,
+    "the fully" => 18,
+    "the function" => 1976,
+    "the functionality" => 105,
+    "the functions" => 149,
+    "the further" => 12,
+    "the future" => 612,
+    "the futures" => 25,
+    "the general" => 125,
+    "the generated" => 284,
+    "the generation" => 36,
+    "the generator" => 15,
+    "the generic" => 107,
+    "the get" => 6,
+    "the gets" => 6,
+    "the getting" => 6,
+    "the git" => 141,
+    "the given" => 712,
+    "the glob" => 27,
+    "the global" => 248,
+    "the gnu" => 67,
+    "the go" => 17,
+    "the goal" => 96,
+    "the goes" => 4,
+    "the good" => 40,
+    "the got" => 4,
+    "the graph" => 19,
+    "the great" => 105,
+    "the greater" => 14,
+    "the group" => 104,
+    "the groups" => 8,
+    "the guarantee" => 6,
+    "the guarantees" => 14,
+    "the guard" => 40,
+    "the guide" => 65,
+    "the had" => 5,
+    "the half" => 21,
+    "the hand" => 19,
+    "the handle" => 67,
+    "the handler" => 31,
+    "the handles" => 10,
+    "the handling" => 20,
+    "the hands" => 27,
+    "the happen" => 3,
+    "the happens" => 3,
+    "the hard" => 28,
+    "the hardware" => 67,
+    "the has" => 48,
+    "the hash" => 103,
+    "the have" => 3,
+    "the head" => 58,
+    "the header" => 131,
+    "the headers" => 45,
+    "the heap" => 236,
+    "the heart" => 23,
+    "the height" => 30,
+    "the help" => 198,
+    "the helper" => 25,
+    "the here" => 3,
+    "the hex" => 8,
+    "the hidden" => 27,
+    "the high" => 176,
+    "the higher" => 49,
+    "the highlight" => 37,
+    "the highlighted" => 60,
+    "the highlighting" => 63,
+    "the history" => 90,
+    "the hit" => 48,
+    "the home" => 41,
+    "the hook" => 99,
+    "the host" => 192,
+    "the hour" => 24,
+    "the hours" => 10,
+    "the house" => 192,
+    "the human" => 38,
+    "the i" => 59,
+    "the idea" => 111,
+    "the identical" => 5,
+    "the identifier" => 81,
+    "the identifiers" => 9,
+    "the if" => 55,
+    "the ignore" => 14,
+    "the image" => 62,
+    "the images" => 25,
+    "the immediately" => 5,
+    "the immutable" => 15,
+    "the implement" => 3,
+    "the implementation" => 448,
+    "the implementations" => 27,
+    "the implemented" => 9,
+    "the implementing" => 20,
+    "the implements" => 3,
+    "the implicit" => 34,
+    "the import" => 52,
+    "the important" => 22,
+    "the imported" => 26,
+    "the imports" => 10,
+    "the in" => 198,
+    "the include" => 28,
+    "the included" => 35,
+    "the inclusion" => 8,
+    "the incompatible" => 3,
+    "the indent" => 86,
+    "the index" => 322,
+    "the indices" => 18,
+    "the individual" => 44,
+    "the info" => 20,
+    "the information" => 113,
+    "the initial" => 132,
+    "the initialization" => 46,
+    "the initialized" => 6,
+    "the inline" => 33,
+    "the inner" => 226,
+    "the input" => 409,
+    "the inputs" => 45,
+    "the insert" => 38,
+    "the inserted" => 18,
+    "the inside" => 16,
+    "the install" => 32,
+    "the installation" => 33,
+    "the installed" => 13,
+    "the instance" => 134,
+    "the instances" => 24,
+    "the instead" => 4,
+    "the instruction" => 113,
+    "the instructions" => 70,
+    "the integer" => 146,
+    "the integers" => 9,
+    "the integration" => 43,
+    "the intended" => 28,
+    "the interface" => 110,
+    "the interfaces" => 14,
+    "the intermediate" => 262,
+    "the internal" => 209,
+    "the internally" => 5,
+    "the into" => 31,
+    "the intrinsic" => 63,
+    "the intro" => 14,
+    "the introduction" => 23,
+    "the invalid" => 8,
+    "the invocation" => 39,
+    "the invoked" => 3,
+    "the is" => 467,
+    "the iso" => 10,
+    "the issue" => 112,
+    "the issues" => 42,
+    "the it" => 13,
+    "the item" => 220,
+    "the items" => 126,
+    "the iteration" => 38,
+    "the iterator" => 294,
+    "the itself" => 10,
+    "the job" => 156,
+    "the join" => 15,
+    "the jump" => 24,
+    "the just" => 10,
+    "the keep" => 7,
+    "the kernel" => 75,
+    "the key" => 519,
+    "the keys" => 85,
+    "the keyword" => 285,
+    "the keywords" => 12,
+    "the kind" => 86,
+    "the kinds" => 14,
+    "the known" => 15,
+    "the label" => 17,
+    "the lambda" => 20,
+    "the lane" => 46,
+    "the lang" => 7,
+    "the language" => 244,
+    "the languages" => 5,
+    "the large" => 21,
+    "the larger" => 19,
+    "the last" => 1269,
+    "the later" => 8,
+    "the latest" => 373,
+    "the latin" => 5,
+    "the layout" => 107,
+    "the lead" => 9,
+    "the leading" => 36,
+    "the least" => 110,
+    "the left" => 231,
+    "the legacy" => 54,
+    "the length" => 389,
+    "the less" => 35,
+    "the let" => 7,
+    "the letter" => 41,
+    "the letters" => 19,
+    "the level" => 49,
+    "the lib" => 29,
+    "the libraries" => 29,
+    "the library" => 783,
+    "the license" => 159,
+    "the life" => 22,
+    "the lifetime" => 196,
+    "the lifetimes" => 38,
+    "the light" => 57,
+    "the like" => 22,
+    "the limit" => 20,
+    "the limitations" => 8,
+    "the limited" => 6,
+    "the line" => 750,
+    "the lines" => 149,
+    "the link" => 77,
+    "the linked" => 12,
+    "the linker" => 108,
+    "the linking" => 11,
+    "the links" => 13,
+    "the lint" => 410,
+    "the list" => 629,
+    "the listed" => 11,
+    "the listener" => 61,
+    "the listing" => 15,
+    "the lists" => 7,
+    "the literal" => 77,
+    "the literals" => 6,
+    "the little" => 89,
+    "the live" => 8,
+    "the load" => 45,
+    "the loaded" => 11,
+    "the loading" => 15,
+    "the local" => 259,
+    "the locale" => 33,
+    "the location" => 238,
+    "the lock" => 244,
+    "the log" => 69,
+    "the logging" => 26,
+    "the logic" => 51,
+    "the logical" => 18,
+    "the long" => 70,
+    "the longer" => 21,
+    "the look" => 22,
+    "the looks" => 4,
+    "the lookup" => 9,
+    "the loop" => 181,
+    "the lost" => 11,
+    "the low" => 261,
+    "the lower" => 1274,
+    "the mac" => 19,
+    "the machine" => 51,
+    "the macro" => 713,
+    "the macros" => 73,
+    "the main" => 637,
+    "the maintainers" => 35,
+    "the major" => 53,
+    "the make" => 8,
+    "the makes" => 4,
+    "the making" => 6,
+    "the man" => 113,
+    "the management" => 6,
+    "the manager" => 4,
+    "the manifest" => 152,
+    "the manner" => 20,
+    "the manual" => 28,
+    "the manually" => 3,
+    "the many" => 19,
+    "the map" => 138,
+    "the mapped" => 10,
+    "the mapping" => 99,
+    "the mappings" => 28,
+    "the maps" => 8,
+    "the mark" => 69,
+    "the marked" => 20,
+    "the marks" => 24,
+    "the mask" => 66,
+    "the master" => 59,
+    "the match" => 140,
+    "the matched" => 50,
+    "the matches" => 29,
+    "the matching" => 83,
+    "the matrix" => 12,
+    "the matter" => 138,
+    "the max" => 43,
+    "the maximum" => 367,
+    "the may" => 18,
+    "the mean" => 15,
+    "the meaning" => 64,
+    "the means" => 19,
+    "the mechanism" => 35,
+    "the member" => 19,
+    "the members" => 22,
+    "the memory" => 414,
+    "the men" => 24,
+    "the mentioned" => 10,
+    "the menu" => 135,
+    "the menus" => 35,
+    "the merge" => 18,
+    "the message" => 327,
+    "the messages" => 76,
+    "the metadata" => 87,
+    "the method" => 1364,
+    "the methods" => 139,
+    "the metrics" => 6,
+    "the middle" => 159,
+    "the might" => 5,
+    "the mind" => 9,
+    "the minimal" => 44,
+    "the minimum" => 540,
+    "the minor" => 16,
+    "the minutes" => 5,
+    "the missing" => 46,
+    "the mit" => 570,
+    "the mode" => 76,
+    "the model" => 49,
+    "the modes" => 3,
+    "the modified" => 36,
+    "the modifier" => 33,
+    "the modifiers" => 4,
+    "the module" => 885,
+    "the modules" => 31,
+    "the moment" => 131,
+    "the more" => 197,
+    "the morning" => 108,
+    "the most" => 759,
+    "the motif" => 14,
+    "the motion" => 34,
+    "the mouse" => 322,
+    "the move" => 9,
+    "the moved" => 4,
+    "the moving" => 3,
+    "the much" => 5,
+    "the multi" => 18,
+    "the multibyte" => 17,
+    "the multiple" => 9,
+    "the must" => 41,
+    "the mutable" => 60,
+    "the name" => 954,
+    "the named" => 28,
+    "the names" => 126,
+    "the namespace" => 30,
+    "the nan" => 5,
+    "the native" => 147,
+    "the near" => 24,
+    "the nearest" => 84,
+    "the necessary" => 74,
+    "the need" => 142,
+    "the needed" => 17,
+    "the needs" => 22,
+    "the negative" => 17,
+    "the nested" => 20,
+    "the net" => 17,
+    "the network" => 213,
+    "the never" => 12,
+    "the new" => 856,
+    "the newer" => 27,
+    "the next" => 948,
+    "the nice" => 8,
+    "the night" => 117,
+    "the nightly" => 120,
+    "the no" => 16,
+    "the node" => 291,
+    "the non" => 100,
+    "the none" => 6,
+    "the normal" => 151,
+    "the not" => 27,
+    "the note" => 23,
+    "the notes" => 12,
+    "the now" => 14,
+    "the nul" => 41,
+    "the null" => 25,
+    "the number" => 1330,
+    "the numbers" => 40,
+    "the numeric" => 92,
+    "the object" => 430,
+    "the objects" => 51,
+    "the of" => 70,
+    "the off" => 5,
+    "the official" => 144,
+    "the offset" => 112,
+    "the ok" => 9,
+    "the old" => 383,
+    "the older" => 40,
+    "the on" => 40,
+    "the once" => 3,
+    "the one" => 298,
+    "the ones" => 90,
+    "the only" => 467,
+    "the open" => 79,
+    "the opening" => 33,
+    "the operand" => 17,
+    "the operands" => 26,
+    "the operating" => 133,
+    "the operation" => 195,
+    "the operations" => 39,
+    "the operator" => 189,
+    "the operators" => 22,
+    "the opt" => 19,
+    "the optimizations" => 4,
+    "the optimized" => 8,
+    "the option" => 624,
+    "the optional" => 141,
+    "the optionally" => 3,
+    "the options" => 129,
+    "the or" => 225,
+    "the order" => 209,
+    "the ordering" => 52,
+    "the original" => 764,
+    "the other" => 880,
+    "the others" => 59,
+    "the out" => 17,
+    "the outer" => 82,
+    "the output" => 664,
+    "the outside" => 22,
+    "the overflow" => 13,
+    "the overhead" => 41,
+    "the override" => 11,
+    "the overrides" => 3,
+    "the overview" => 3,
+    "the own" => 4,
+    "the owned" => 20,
+    "the ownership" => 42,
+    "the package" => 430,
+    "the packages" => 32,
+    "the packed" => 236,
+    "the padding" => 16,
+    "the page" => 82,
+    "the pages" => 12,
+    "the pair" => 9,
+    "the pairs" => 7,
+    "the panic" => 154,
+    "the paper" => 54,
+    "the parallel" => 7,
+    "the parameter" => 250,
+    "the parameters" => 53,
+    "the parent" => 244,
+    "the parse" => 14,
+    "the parsed" => 30,
+    "the parser" => 109,
+    "the parsing" => 33,
+    "the part" => 100,
+    "the partial" => 20,
+    "the particular" => 21,
+    "the parts" => 43,
+    "the party" => 25,
+    "the pass" => 5,
+    "the passed" => 81,
+    "the past" => 67,
+    "the paste" => 19,
+    "the patch" => 82,
+    "the path" => 507,
+    "the paths" => 49,
+    "the pattern" => 485,
+    "the patterns" => 45,
+    "the people" => 45,
+    "the per" => 9,
+    "the performance" => 142,
+    "the place" => 106,
+    "the places" => 16,
+    "the plain" => 12,
+    "the platform" => 130,
+    "the platforms" => 13,
+    "the please" => 3,
+    "the plugin" => 121,
+    "the plus" => 7,
+    "the point" => 119,
+    "the pointer" => 456,
+    "the pointers" => 23,
+    "the points" => 11,
+    "the policy" => 42,
+    "the pool" => 84,
+    "the poor" => 52,
+    "the popup" => 194,
+    "the port" => 56,
+    "the portable" => 15,
+    "the position" => 136,
+    "the positions" => 6,
+    "the possible" => 61,
+    "the possibly" => 8,
+    "the post" => 22,
+    "the potentially" => 5,
+    "the power" => 48,
+    "the pre" => 36,
+    "the precision" => 77,
+    "the preferred" => 52,
+    "the prefix" => 94,
+    "the prelude" => 76,
+    "the present" => 37,
+    "the press" => 6,
+    "the pretty" => 10,
+    "the previous" => 458,
+    "the previously" => 45,
+    "the primary" => 182,
+    "the primitive" => 69,
+    "the primitives" => 9,
+    "the print" => 17,
+    "the printed" => 17,
+    "the printing" => 8,
+    "the prior" => 8,
+    "the private" => 37,
+    "the problem" => 147,
+    "the problems" => 33,
+    "the procedural" => 41,
+    "the process" => 462,
+    "the processes" => 10,
+    "the processing" => 19,
+    "the produced" => 14,
+    "the profile" => 76,
+    "the program" => 504,
+    "the programming" => 7,
+    "the programs" => 11,
+    "the progress" => 37,
+    "the project" => 484,
+    "the projects" => 20,
+    "the promise" => 65,
+    "the prompt" => 48,
+    "the properties" => 62,
+    "the property" => 298,
+    "the protocol" => 92,
+    "the provided" => 257,
+    "the provider" => 14,
+    "the provides" => 3,
+    "the public" => 208,
+    "the publish" => 12,
+    "the published" => 20,
+    "the pull" => 7,
+    "the pure" => 12,
+    "the purpose" => 104,
+    "the purposes" => 19,
+    "the push" => 16,
+    "the put" => 7,
+    "the python" => 96,
+    "the quality" => 31,
+    "the query" => 62,
+    "the question" => 71,
+    "the questions" => 8,
+    "the queue" => 74,
+    "the quick" => 17,
+    "the quote" => 8,
+    "the quotes" => 17,
+    "the random" => 18,
+    "the range" => 323,
+    "the rather" => 9,
+    "the raw" => 189,
+    "the re" => 38,
+    "the read" => 76,
+    "the readable" => 23,
+    "the reader" => 51,
+    "the reading" => 19,
+    "the real" => 92,
+    "the really" => 11,
+    "the reason" => 133,
+    "the reasons" => 8,
+    "the received" => 7,
+    "the recent" => 7,
+    "the recognized" => 4,
+    "the recommended" => 52,
+    "the record" => 20,
+    "the redraw" => 10,
+    "the reference" => 397,
+    "the references" => 38,
+    "the region" => 41,
+    "the register" => 171,
+    "the registers" => 15,
+    "the registry" => 113,
+    "the regular" => 53,
+    "the related" => 19,
+    "the relative" => 38,
+    "the release" => 93,
+    "the released" => 4,
+    "the releases" => 12,
+    "the relevant" => 104,
+    "the remaining" => 133,
+    "the remote" => 106,
+    "the remove" => 3,
+    "the removed" => 14,
+    "the rename" => 8,
+    "the rendering" => 8,
+    "the repeat" => 7,
+    "the repeated" => 17,
+    "the replace" => 7,
+    "the replaced" => 6,
+    "the replacement" => 40,
+    "the repo" => 47,
+    "the report" => 49,
+    "the reported" => 6,
+    "the reports" => 5,
+    "the repository" => 163,
+    "the representation" => 72,
+    "the represented" => 14,
+    "the represents" => 3,
+    "the request" => 205,
+    "the requests" => 8,
+    "the require" => 5,
+    "the required" => 157,
+    "the requirement" => 29,
+    "the requirements" => 47,
+    "the reserved" => 8,
+    "the reset" => 3,
+    "the resolution" => 23,
+    "the resolve" => 7,
+    "the resolved" => 14,
+    "the resource" => 98,
+    "the resources" => 30,
+    "the response" => 146,
+    "the rest" => 317,
+    "the result" => 1626,
+    "the resulting" => 329,
+    "the results" => 1646,
+    "the return" => 358,
+    "the returned" => 456,
+    "the returning" => 25,
+    "the returns" => 7,
+    "the reverse" => 40,
+    "the rfc" => 47,
+    "the right" => 609,
+    "the room" => 149,
+    "the root" => 296,
+    "the round" => 11,
+    "the rounding" => 380,
+    "the row" => 16,
+    "the ruby" => 33,
+    "the rule" => 31,
+    "the rules" => 70,
+    "the run" => 19,
+    "the running" => 34,
+    "the runtime" => 206,
+    "the rust" => 1247,
+    "the sae" => 215,
+    "the safe" => 45,
+    "the safety" => 57,
+    "the said" => 11,
+    "the same" => 4750,
+    "the save" => 9,
+    "the scalar" => 45,
+    "the scale" => 13,
+    "the schema" => 35,
+    "the scheme" => 20,
+    "the scope" => 183,
+    "the scopes" => 10,
+    "the screen" => 285,
+    "the script" => 411,
+    "the scripts" => 12,
+    "the scroll" => 20,
+    "the search" => 204,
+    "the searching" => 8,
+    "the second" => 671,
+    "the secret" => 49,
+    "the section" => 199,
+    "the sections" => 17,
+    "the secure" => 28,
+    "the security" => 34,
+    "the see" => 5,
+    "the select" => 9,
+    "the selected" => 130,
+    "the selection" => 103,
+    "the self" => 24,
+    "the semantics" => 47,
+    "the send" => 12,
+    "the sending" => 21,
+    "the sense" => 34,
+    "the separate" => 10,
+    "the sequence" => 90,
+    "the sequences" => 4,
+    "the serialization" => 38,
+    "the server" => 430,
+    "the service" => 59,
+    "the session" => 73,
+    "the set" => 210,
+    "the sets" => 3,
+    "the setting" => 51,
+    "the settings" => 46,
+    "the setup" => 8,
+    "the several" => 16,
+    "the shared" => 72,
+    "the shell" => 129,
+    "the shift" => 68,
+    "the short" => 66,
+    "the should" => 18,
+    "the show" => 5,
+    "the shown" => 5,
+    "the side" => 60,
+    "the sign" => 172,
+    "the signal" => 90,
+    "the signature" => 102,
+    "the signatures" => 16,
+    "the signed" => 25,
+    "the significant" => 3,
+    "the silent" => 8,
+    "the similar" => 6,
+    "the similarly" => 3,
+    "the simple" => 36,
+    "the since" => 4,
+    "the single" => 88,
+    "the situation" => 51,
+    "the situations" => 5,
+    "the six" => 11,
+    "the size" => 431,
+    "the skip" => 19,
+    "the sleep" => 12,
+    "the slice" => 376,
+    "the slices" => 7,
+    "the slow" => 8,
+    "the small" => 40,
+    "the smaller" => 23,
+    "the smart" => 26,
+    "the so" => 32,
+    "the socket" => 246,
+    "the software" => 96,
+    "the solution" => 33,
+    "the sort" => 33,
+    "the sound" => 46,
+    "the source" => 471,
+    "the sources" => 31,
+    "the space" => 85,
+    "the spaces" => 13,
+    "the span" => 50,
+    "the spec" => 55,
+    "the special" => 133,
+    "the specific" => 126,
+    "the specification" => 44,
+    "the specified" => 520,
+    "the specifies" => 8,
+    "the specifying" => 3,
+    "the speed" => 41,
+    "the spell" => 37,
+    "the split" => 22,
+    "the square" => 60,
+    "the stability" => 10,
+    "the stabilized" => 73,
+    "the stable" => 56,
+    "the stack" => 286,
+    "the standard" => 938,
+    "the start" => 488,
+    "the started" => 3,
+    "the starting" => 40,
+    "the startup" => 13,
+    "the state" => 211,
+    "the statement" => 63,
+    "the statements" => 12,
+    "the states" => 33,
+    "the static" => 71,
+    "the statically" => 4,
+    "the status" => 134,
+    "the std" => 56,
+    "the step" => 12,
+    "the steps" => 54,
+    "the still" => 8,
+    "the storage" => 40,
+    "the store" => 59,
+    "the stored" => 31,
+    "the stores" => 6,
+    "the stream" => 349,
+    "the streams" => 10,
+    "the strict" => 19,
+    "the string" => 587,
+    "the strings" => 31,
+    "the structure" => 103,
+    "the structures" => 6,
+    "the style" => 49,
+    "the sub" => 37,
+    "the subject" => 131,
+    "the substitute" => 32,
+    "the success" => 27,
+    "the suffix" => 42,
+    "the suite" => 27,
+    "the sum" => 74,
+    "the summary" => 8,
+    "the supplied" => 97,
+    "the support" => 46,
+    "the supported" => 61,
+    "the supports" => 4,
+    "the swap" => 115,
+    "the switch" => 12,
+    "the symbol" => 72,
+    "the symbols" => 20,
+    "the sync" => 16,
+    "the syntax" => 428,
+    "the system" => 382,
+    "the systems" => 6,
+    "the tab" => 81,
+    "the table" => 263,
+    "the tables" => 24,
+    "the tag" => 178,
+    "the tags" => 96,
+    "the takes" => 3,
+    "the talk" => 9,
+    "the target" => 1036,
+    "the targets" => 81,
+    "the task" => 82,
+    "the tasks" => 10,
+    "the team" => 13,
+    "the tells" => 3,
+    "the template" => 41,
+    "the temporary" => 57,
+    "the term" => 56,
+    "the terminal" => 414,
+    "the terms" => 265,
+    "the test" => 651,
+    "the testing" => 18,
+    "the tests" => 264,
+    "the text" => 891,
+    "the that" => 55,
+    "the the" => 75,
+    "the then" => 21,
+    "the thing" => 52,
+    "the things" => 39,
+    "the third" => 122,
+    "the this" => 11,
+    "the those" => 3,
+    "the thought" => 23,
+    "the thread" => 198,
+    "the threads" => 40,
+    "the three" => 143,
+    "the thrown" => 5,
+    "the tier" => 12,
+    "the time" => 539,
+    "the timeout" => 70,
+    "the timer" => 36,
+    "the times" => 12,
+    "the tiny" => 13,
+    "the title" => 76,
+    "the to" => 170,
+    "the token" => 113,
+    "the tokens" => 49,
+    "the tool" => 82,
+    "the toolchain" => 24,
+    "the tools" => 43,
+    "the top" => 353,
+    "the total" => 120,
+    "the trace" => 20,
+    "the tracing" => 18,
+    "the track" => 16,
+    "the tracking" => 21,
+    "the trailing" => 46,
+    "the trait" => 1343,
+    "the traits" => 112,
+    "the travis" => 4,
+    "the tree" => 107,
+    "the trigger" => 5,
+    "the true" => 29,
+    "the try" => 54,
+    "the tuple" => 71,
+    "the turn" => 6,
+    "the two" => 497,
+    "the type" => 1650,
+    "the typed" => 28,
+    "the types" => 247,
+    "the typing" => 5,
+    "the under" => 5,
+    "the underlying" => 575,
+    "the undo" => 67,
+    "the union" => 52,
+    "the unique" => 34,
+    "the unit" => 60,
+    "the units" => 4,
+    "the unix" => 58,
+    "the unknown" => 7,
+    "the unsafe" => 43,
+    "the unsigned" => 54,
+    "the unspecified" => 9,
+    "the unstable" => 72,
+    "the unused" => 17,
+    "the up" => 10,
+    "the update" => 31,
+    "the updated" => 18,
+    "the upper" => 1029,
+    "the us" => 9,
+    "the usage" => 82,
+    "the use" => 453,
+    "the used" => 36,
+    "the useful" => 3,
+    "the user" => 751,
+    "the users" => 30,
+    "the uses" => 16,
+    "the using" => 17,
+    "the usual" => 113,
+    "the usually" => 3,
+    "the utilities" => 6,
+    "the utility" => 26,
+    "the valid" => 37,
+    "the validation" => 11,
+    "the value" => 2167,
+    "the values" => 353,
+    "the variable" => 375,
+    "the variables" => 62,
+    "the variant" => 133,
+    "the variants" => 51,
+    "the various" => 123,
+    "the vector" => 230,
+    "the vectors" => 9,
+    "the verbose" => 20,
+    "the version" => 336,
+    "the versions" => 26,
+    "the very" => 154,
+    "the view" => 53,
+    "the vim" => 347,
+    "the virtual" => 50,
+    "the visible" => 3,
+    "the visual" => 113,
+    "the voice" => 18,
+    "the wait" => 19,
+    "the waiting" => 16,
+    "the warning" => 64,
+    "the warnings" => 24,
+    "the was" => 31,
+    "the way" => 349,
+    "the ways" => 29,
+    "the we" => 12,
+    "the web" => 105,
+    "the well" => 27,
+    "the what" => 4,
+    "the when" => 17,
+    "the where" => 15,
+    "the which" => 46,
+    "the while" => 19,
+    "the white" => 64,
+    "the whitespace" => 4,
+    "the whole" => 441,
+    "the wide" => 31,
+    "the width" => 70,
+    "the will" => 125,
+    "the win" => 65,
+    "the window" => 664,
+    "the windows" => 197,
+    "the with" => 53,
+    "the within" => 5,
+    "the without" => 6,
+    "the word" => 231,
+    "the words" => 88,
+    "the work" => 898,
+    "the worker" => 62,
+    "the working" => 62,
+    "the works" => 10,
+    "the workspace" => 133,
+    "the world" => 206,
+    "the would" => 9,
+    "the wrap" => 14,
+    "the wrapped" => 96,
+    "the wrapper" => 62,
+    "the wrapping" => 12,
+    "the write" => 62,
+    "the writing" => 12,
+    "the written" => 11,
+    "the wrong" => 228,
+    "the year" => 31,
+    "the years" => 14,
+    "the you" => 12,
+    "the young" => 78,
+    "the zero" => 35,
+    "their and" => 5,
+    "their use" => 19,
+    "them a" => 28,
+    "them and" => 118,
+    "them are" => 41,
+    "them as" => 115,
+    "them by" => 31,
+    "them can" => 18,
+    "them for" => 38,
+    "them from" => 56,
+    "them if" => 12,
+    "them in" => 231,
+    "them is" => 45,
+    "them it" => 6,
+    "them not" => 3,
+    "them of" => 6,
+    "them on" => 43,
+    "them or" => 18,
+    "them that" => 16,
+    "them the" => 24,
+    "them this" => 4,
+    "them to" => 288,
+    "them use" => 10,
+    "them using" => 18,
+    "them when" => 32,
+    "them will" => 7,
+    "them with" => 104,
+    "them you" => 41,
+    "then a" => 122,
+    "then add" => 64,
+    "then all" => 29,
+    "then also" => 6,
+    "then an" => 56,
+    "then and" => 62,
+    "then any" => 20,
+    "then as" => 16,
+    "then at" => 16,
+    "then be" => 125,
+    "then but" => 7,
+    "then by" => 8,
+    "then can" => 15,
+    "then for" => 34,
+    "then from" => 8,
+    "then have" => 17,
+    "then i" => 81,
+    "then if" => 21,
+    "then in" => 56,
+    "then is" => 83,
+    "then it" => 247,
+    "then no" => 18,
+    "then not" => 7,
+    "then of" => 8,
+    "then on" => 18,
+    "then one" => 19,
+    "then only" => 24,
+    "then rust" => 8,
+    "then see" => 4,
+    "then set" => 10,
+    "then that" => 42,
+    "then the" => 699,
+    "then this" => 113,
+    "then to" => 48,
+    "then type" => 15,
+    "then use" => 147,
+    "then used" => 18,
+    "then using" => 20,
+    "then value" => 5,
+    "then vim" => 22,
+    "then was" => 8,
+    "then we" => 92,
+    "then when" => 24,
+    "then will" => 47,
+    "then with" => 16,
+    "then you" => 328,
+    "then your" => 8,
+    "there a" => 21,
+    "there all" => 5,
+    "there also" => 11,
+    "there an" => 4,
+    "there and" => 48,
+    "there any" => 10,
+    "there are" => 1819,
+    "there as" => 9,
+    "there at" => 9,
+    "there but" => 11,
+    "there by" => 6,
+    "there can" => 63,
+    "there for" => 33,
+    "there have" => 9,
+    "there i" => 17,
+    "there in" => 23,
+    "there is" => 2243,
+    "there it" => 13,
+    "there may" => 76,
+    "there no" => 4,
+    "there not" => 5,
+    "there on" => 9,
+    "there only" => 5,
+    "there or" => 4,
+    "there set" => 3,
+    "there that" => 9,
+    "there the" => 13,
+    "there to" => 25,
+    "there used" => 3,
+    "there was" => 485,
+    "there we" => 6,
+    "there when" => 4,
+    "there which" => 5,
+    "there will" => 65,
+    "there with" => 8,
+    "there you" => 16,
+    "there's the" => 8,
+    "therefore a" => 9,
+    "therefore the" => 61,
+    "therefore to" => 16,
+    "these all" => 5,
+    "these and" => 10,
+    "these are" => 378,
+    "these as" => 12,
+    "these by" => 4,
+    "these can" => 66,
+    "these file" => 3,
+    "these files" => 58,
+    "these for" => 6,
+    "these have" => 8,
+    "these i" => 4,
+    "these in" => 22,
+    "these is" => 14,
+    "these the" => 6,
+    "these to" => 18,
+    "these use" => 4,
+    "these will" => 31,
+    "these with" => 6,
+    "these you" => 4,
+    "these your" => 4,
+    "they all" => 52,
+    "they and" => 5,
+    "they are" => 999,
+    "they be" => 7,
+    "they can" => 311,
+    "they have" => 162,
+    "they in" => 3,
+    "they test" => 3,
+    "they that" => 4,
+    "they use" => 50,
+    "they used" => 10,
+    "they was" => 3,
+    "they will" => 167,
+    "they're the" => 4,
+    "thing a" => 9,
+    "thing the" => 6,
+    "thing to" => 51,
+    "things a" => 6,
+    "things and" => 20,
+    "things for" => 15,
+    "things in" => 33,
+    "things is" => 4,
+    "things of" => 6,
+    "things the" => 6,
+    "things to" => 45,
+    "think a" => 3,
+    "think and" => 3,
+    "think of" => 112,
+    "think the" => 10,
+    "think to" => 6,
+    "this a" => 25,
+    "this access" => 6,
+    "this add" => 9,
+    "this added" => 4,
+    "this additional" => 6,
+    "this after" => 4,
+    "this again" => 3,
+    "this all" => 9,
+    "this allows" => 345,
+    "this also" => 159,
+    "this an" => 9,
+    "this and" => 75,
+    "this any" => 4,
+    "this are" => 29,
+    "this argument" => 15,
+    "this array" => 7,
+    "this as" => 68,
+    "this at" => 27,
+    "this attribute" => 65,
+    "this automatically" => 9,
+    "this back" => 3,
+    "this be" => 14,
+    "this because" => 14,
+    "this before" => 17,
+    "this behavior" => 137,
+    "this being" => 6,
+    "this binary" => 7,
+    "this block" => 10,
+    "this buffer" => 14,
+    "this build" => 5,
+    "this but" => 24,
+    "this by" => 102,
+    "this byte" => 5,
+    "this call" => 56,
+    "this calling" => 3,
+    "this can" => 759,
+    "this can't" => 3,
+    "this cannot" => 45,
+    "this cargo" => 8,
+    "this case" => 401,
+    "this change" => 51,
+    "this changes" => 12,
+    "this channel" => 30,
+    "this character" => 11,
+    "this check" => 11,
+    "this class" => 28,
+    "this closes" => 4,
+    "this code" => 395,
+    "this command" => 346,
+    "this compiler" => 3,
+    "this contains" => 13,
+    "this contribution" => 5,
+    "this copy" => 3,
+    "this could" => 66,
+    "this crate" => 3960,
+    "this crates" => 24,
+    "this create" => 11,
+    "this currently" => 8,
+    "this cursor" => 13,
+    "this data" => 60,
+    "this default" => 22,
+    "this dependency" => 14,
+    "this different" => 8,
+    "this directly" => 6,
+    "this directory" => 56,
+    "this documentation" => 29,
+    "this does" => 171,
+    "this doesn't" => 95,
+    "this down" => 4,
+    "this dual" => 5,
+    "this either" => 7,
+    "this element" => 9,
+    "this enables" => 54,
+    "this encoding" => 18,
+    "this end" => 7,
+    "this environment" => 21,
+    "this error" => 114,
+    "this even" => 3,
+    "this event" => 89,
+    "this every" => 3,
+    "this example" => 458,
+    "this explicitly" => 6,
+    "this expression" => 16,
+    "this fails" => 10,
+    "this feature" => 468,
+    "this features" => 7,
+    "this field" => 43,
+    "this file" => 236,
+    "this first" => 8,
+    "this fix" => 3,
+    "this flag" => 421,
+    "this floating" => 6,
+    "this for" => 76,
+    "this format" => 39,
+    "this from" => 24,
+    "this function" => 1374,
+    "this functions" => 3,
+    "this future" => 5,
+    "this generated" => 3,
+    "this generic" => 3,
+    "this git" => 3,
+    "this had" => 4,
+    "this handle" => 16,
+    "this has" => 149,
+    "this have" => 3,
+    "this he" => 12,
+    "this help" => 3,
+    "this here" => 6,
+    "this however" => 6,
+    "this i" => 21,
+    "this if" => 47,
+    "this image" => 3,
+    "this implementation" => 109,
+    "this in" => 209,
+    "this include" => 3,
+    "this information" => 53,
+    "this input" => 9,
+    "this instance" => 38,
+    "this instead" => 13,
+    "this integer" => 47,
+    "this interface" => 27,
+    "this into" => 27,
+    "this is" => 4708,
+    "this issue" => 58,
+    "this it" => 26,
+    "this it's" => 5,
+    "this item" => 15,
+    "this iterator" => 37,
+    "this just" => 14,
+    "this key" => 35,
+    "this language" => 6,
+    "this last" => 22,
+    "this length" => 3,
+    "this level" => 14,
+    "this library" => 1058,
+    "this license" => 9,
+    "this like" => 6,
+    "this line" => 88,
+    "this link" => 3,
+    "this list" => 74,
+    "this little" => 10,
+    "this macro" => 141,
+    "this made" => 4,
+    "this make" => 5,
+    "this makes" => 222,
+    "this many" => 7,
+    "this match" => 9,
+    "this may" => 338,
+    "this means" => 651,
+    "this memory" => 13,
+    "this message" => 40,
+    "this method" => 822,
+    "this might" => 105,
+    "this mode" => 32,
+    "this module" => 202,
+    "this more" => 14,
+    "this most" => 8,
+    "this must" => 35,
+    "this name" => 20,
+    "this new" => 45,
+    "this next" => 7,
+    "this no" => 3,
+    "this node" => 4,
+    "this non" => 17,
+    "this not" => 6,
+    "this note" => 7,
+    "this now" => 8,
+    "this number" => 28,
+    "this object" => 47,
+    "this off" => 7,
+    "this on" => 31,
+    "this once" => 12,
+    "this one" => 121,
+    "this only" => 98,
+    "this option" => 306,
+    "this optional" => 4,
+    "this or" => 16,
+    "this order" => 19,
+    "this other" => 6,
+    "this out" => 8,
+    "this output" => 33,
+    "this over" => 4,
+    "this package" => 83,
+    "this parameter" => 10,
+    "this part" => 20,
+    "this path" => 14,
+    "this pattern" => 33,
+    "this place" => 15,
+    "this platform" => 23,
+    "this please" => 5,
+    "this point" => 148,
+    "this pointer" => 54,
+    "this possible" => 4,
+    "this problem" => 78,
+    "this process" => 62,
+    "this program" => 82,
+    "this project" => 801,
+    "this provides" => 46,
+    "this put" => 6,
+    "this range" => 16,
+    "this reference" => 21,
+    "this release" => 21,
+    "this remove" => 4,
+    "this required" => 3,
+    "this requires" => 100,
+    "this result" => 10,
+    "this results" => 54,
+    "this returns" => 67,
+    "this right" => 3,
+    "this run" => 5,
+    "this runtime" => 9,
+    "this rust" => 36,
+    "this safe" => 7,
+    "this said" => 3,
+    "this same" => 24,
+    "this scope" => 4,
+    "this script" => 42,
+    "this search" => 5,
+    "this section" => 157,
+    "this see" => 9,
+    "this server" => 10,
+    "this set" => 23,
+    "this setting" => 26,
+    "this she" => 10,
+    "this should" => 128,
+    "this simple" => 15,
+    "this since" => 6,
+    "this single" => 5,
+    "this size" => 4,
+    "this slice" => 34,
+    "this small" => 8,
+    "this so" => 20,
+    "this solution" => 9,
+    "this source" => 22,
+    "this space" => 4,
+    "this specific" => 9,
+    "this specified" => 6,
+    "this standard" => 5,
+    "this state" => 25,
+    "this status" => 3,
+    "this still" => 14,
+    "this stream" => 21,
+    "this string" => 48,
+    "this support" => 6,
+    "this supports" => 16,
+    "this syntax" => 37,
+    "this system" => 13,
+    "this target" => 397,
+    "this test" => 30,
+    "this text" => 39,
+    "this that" => 17,
+    "this the" => 64,
+    "this then" => 13,
+    "this there" => 12,
+    "this they" => 5,
+    "this this" => 6,
+    "this thread" => 35,
+    "this time" => 198,
+    "this to" => 529,
+    "this too" => 8,
+    "this trait" => 199,
+    "this two" => 3,
+    "this type" => 344,
+    "this unless" => 7,
+    "this up" => 6,
+    "this usage" => 7,
+    "this use" => 58,
+    "this useful" => 3,
+    "this uses" => 83,
+    "this using" => 22,
+    "this value" => 115,
+    "this variable" => 53,
+    "this vector" => 9,
+    "this version" => 100,
+    "this very" => 24,
+    "this via" => 6,
+    "this vim" => 10,
+    "this was" => 222,
+    "this way" => 233,
+    "this we" => 51,
+    "this were" => 5,
+    "this when" => 43,
+    "this which" => 7,
+    "this while" => 6,
+    "this will" => 1052,
+    "this window" => 13,
+    "this with" => 110,
+    "this without" => 11,
+    "this work" => 50,
+    "this works" => 153,
+    "this would" => 134,
+    "this writing" => 26,
+    "this you" => 81,
+    "this your" => 4,
+    "those and" => 6,
+    "those for" => 27,
+    "those in" => 32,
+    "those is" => 3,
+    "those of" => 71,
+    "those that" => 41,
+    "those the" => 3,
+    "those to" => 10,
+    "those with" => 73,
+    "those you" => 9,
+    "though a" => 11,
+    "though and" => 10,
+    "though in" => 9,
+    "though is" => 17,
+    "though of" => 3,
+    "though the" => 105,
+    "thought a" => 5,
+    "thought the" => 13,
+    "thought to" => 11,
+    "thread a" => 4,
+    "thread and" => 60,
+    "thread for" => 15,
+    "thread in" => 14,
+    "thread is" => 52,
+    "thread it" => 3,
+    "thread of" => 9,
+    "thread that" => 40,
+    "thread the" => 9,
+    "thread this" => 3,
+    "thread to" => 61,
+    "thread with" => 16,
+    "threads and" => 45,
+    "threads in" => 27,
+    "threads is" => 7,
+    "threads of" => 3,
+    "threads the" => 4,
+    "threads to" => 25,
+    "three and" => 3,
+    "three in" => 4,
+    "three of" => 18,
+    "three to" => 4,
+    "through a" => 207,
+    "through and" => 48,
+    "through as" => 6,
+    "through in" => 4,
+    "through it" => 16,
+    "through of" => 4,
+    "through that" => 8,
+    "through the" => 666,
+    "through this" => 48,
+    "through to" => 19,
+    "through with" => 8,
+    "throw the" => 13,
+    "thrown the" => 5,
+    "thus a" => 22,
+    "thus for" => 13,
+    "thus in" => 11,
+    "thus is" => 17,
+    "thus the" => 94,
+    "thus to" => 11,
+    "tier the" => 4,
+    "time a" => 64,
+    "time all" => 4,
+    "time an" => 10,
+    "time and" => 245,
+    "time are" => 9,
+    "time as" => 46,
+    "time at" => 13,
+    "time be" => 3,
+    "time but" => 49,
+    "time by" => 37,
+    "time can" => 7,
+    "time crate" => 3,
+    "time for" => 99,
+    "time from" => 8,
+    "time function" => 3,
+    "time i" => 51,
+    "time if" => 26,
+    "time in" => 93,
+    "time is" => 83,
+    "time it" => 83,
+    "time not" => 8,
+    "time of" => 204,
+    "time on" => 56,
+    "time one" => 7,
+    "time only" => 3,
+    "time or" => 44,
+    "time rust" => 3,
+    "time see" => 6,
+    "time set" => 3,
+    "time that" => 62,
+    "time the" => 144,
+    "time this" => 26,
+    "time to" => 284,
+    "time type" => 32,
+    "time use" => 9,
+    "time using" => 22,
+    "time value" => 11,
+    "time vim" => 13,
+    "time was" => 10,
+    "time when" => 50,
+    "time which" => 37,
+    "time will" => 9,
+    "time with" => 37,
+    "time you" => 95,
+    "time your" => 5,
+    "timer the" => 5,
+    "times a" => 9,
+    "times and" => 99,
+    "times for" => 12,
+    "times in" => 51,
+    "times is" => 3,
+    "times of" => 5,
+    "times the" => 31,
+    "times this" => 4,
+    "times to" => 39,
+    "to a" => 3787,
+    "to about" => 9,
+    "to above" => 9,
+    "to absolute" => 4,
+    "to access" => 295,
+    "to active" => 3,
+    "to actually" => 37,
+    "to add" => 651,
+    "to adding" => 13,
+    "to additional" => 6,
+    "to address" => 40,
+    "to adjust" => 13,
+    "to after" => 38,
+    "to alignment" => 7,
+    "to all" => 343,
+    "to allocated" => 4,
+    "to allow" => 386,
+    "to allows" => 3,
+    "to along" => 6,
+    "to already" => 5,
+    "to also" => 58,
+    "to always" => 75,
+    "to am" => 6,
+    "to an" => 868,
+    "to and" => 508,
+    "to another" => 292,
+    "to any" => 246,
+    "to anything" => 24,
+    "to apache" => 4,
+    "to apis" => 3,
+    "to appear" => 36,
+    "to application" => 3,
+    "to applications" => 8,
+    "to apply" => 88,
+    "to arbitrary" => 23,
+    "to are" => 35,
+    "to arguments" => 5,
+    "to array" => 5,
+    "to as" => 122,
+    "to ascii" => 7,
+    "to associated" => 4,
+    "to asynchronous" => 3,
+    "to at" => 49,
+    "to attempt" => 20,
+    "to auto" => 9,
+    "to automatically" => 98,
+    "to avoid" => 963,
+    "to away" => 4,
+    "to back" => 18,
+    "to base" => 14,
+    "to based" => 5,
+    "to basic" => 5,
+    "to be" => 5761,
+    "to because" => 19,
+    "to before" => 29,
+    "to being" => 33,
+    "to below" => 7,
+    "to best" => 5,
+    "to better" => 27,
+    "to big" => 9,
+    "to binary" => 10,
+    "to bit" => 41,
+    "to bits" => 29,
+    "to block" => 38,
+    "to blocks" => 4,
+    "to boolean" => 3,
+    "to both" => 57,
+    "to bound" => 3,
+    "to branch" => 11,
+    "to break" => 57,
+    "to breaking" => 4,
+    "to buffer" => 16,
+    "to buffers" => 3,
+    "to build" => 651,
+    "to building" => 6,
+    "to but" => 126,
+    "to by" => 99,
+    "to byte" => 14,
+    "to bytes" => 60,
+    "to cache" => 29,
+    "to call" => 472,
+    "to called" => 3,
+    "to calling" => 79,
+    "to can" => 19,
+    "to cargo" => 113,
+    "to case" => 3,
+    "to cause" => 49,
+    "to causes" => 4,
+    "to certain" => 9,
+    "to change" => 501,
+    "to changes" => 14,
+    "to channel" => 4,
+    "to chapter" => 7,
+    "to char" => 10,
+    "to character" => 7,
+    "to characters" => 17,
+    "to chat" => 7,
+    "to check" => 381,
+    "to checking" => 3,
+    "to child" => 6,
+    "to clear" => 30,
+    "to client" => 7,
+    "to close" => 69,
+    "to code" => 27,
+    "to color" => 8,
+    "to colors" => 6,
+    "to column" => 10,
+    "to come" => 102,
+    "to command" => 17,
+    "to commands" => 9,
+    "to comment" => 4,
+    "to comments" => 5,
+    "to commit" => 22,
+    "to common" => 5,
+    "to compare" => 85,
+    "to comparison" => 6,
+    "to compile" => 476,
+    "to compiler" => 8,
+    "to compiling" => 5,
+    "to complete" => 101,
+    "to completion" => 30,
+    "to complex" => 23,
+    "to compute" => 72,
+    "to configuration" => 3,
+    "to configure" => 108,
+    "to consider" => 65,
+    "to console" => 13,
+    "to constant" => 9,
+    "to contain" => 49,
+    "to context" => 6,
+    "to continue" => 54,
+    "to contributing" => 7,
+    "to control" => 131,
+    "to conversion" => 3,
+    "to convert" => 258,
+    "to copy" => 116,
+    "to correct" => 21,
+    "to correctly" => 23,
+    "to count" => 55,
+    "to crash" => 10,
+    "to crate" => 12,
+    "to crates" => 68,
+    "to create" => 831,
+    "to creating" => 9,
+    "to cross" => 50,
+    "to current" => 22,
+    "to cursor" => 10,
+    "to custom" => 7,
+    "to data" => 28,
+    "to day" => 54,
+    "to debug" => 58,
+    "to default" => 13,
+    "to define" => 365,
+    "to definition" => 10,
+    "to delete" => 125,
+    "to depend" => 70,
+    "to dependencies" => 11,
+    "to dependency" => 6,
+    "to depending" => 4,
+    "to derive" => 45,
+    "to development" => 3,
+    "to different" => 33,
+    "to directly" => 38,
+    "to directories" => 4,
+    "to directory" => 11,
+    "to disable" => 171,
+    "to display" => 143,
+    "to do" => 1172,
+    "to doc" => 6,
+    "to document" => 28,
+    "to documentation" => 6,
+    "to doing" => 5,
+    "to double" => 14,
+    "to drop" => 53,
+    "to dynamic" => 5,
+    "to each" => 149,
+    "to easily" => 63,
+    "to easy" => 6,
+    "to edit" => 169,
+    "to editing" => 3,
+    "to edition" => 8,
+    "to effect" => 3,
+    "to either" => 64,
+    "to elements" => 17,
+    "to embedded" => 3,
+    "to empty" => 8,
+    "to enable" => 502,
+    "to encoding" => 25,
+    "to end" => 87,
+    "to english" => 7,
+    "to ensure" => 488,
+    "to enter" => 109,
+    "to entries" => 3,
+    "to environment" => 7,
+    "to equal" => 3,
+    "to equivalent" => 7,
+    "to error" => 24,
+    "to errors" => 43,
+    "to escape" => 38,
+    "to etc" => 8,
+    "to even" => 16,
+    "to events" => 8,
+    "to ever" => 16,
+    "to every" => 37,
+    "to everything" => 6,
+    "to ex" => 19,
+    "to exactly" => 10,
+    "to examples" => 5,
+    "to except" => 49,
+    "to exception" => 5,
+    "to exceptions" => 4,
+    "to execute" => 198,
+    "to execution" => 3,
+    "to exist" => 33,
+    "to existing" => 17,
+    "to exit" => 90,
+    "to explicitly" => 91,
+    "to external" => 18,
+    "to fail" => 77,
+    "to fails" => 4,
+    "to faster" => 4,
+    "to feature" => 4,
+    "to features" => 9,
+    "to fields" => 6,
+    "to file" => 60,
+    "to files" => 25,
+    "to find" => 583,
+    "to first" => 79,
+    "to fix" => 164,
+    "to fixed" => 3,
+    "to flag" => 5,
+    "to float" => 19,
+    "to floating" => 7,
+    "to fold" => 9,
+    "to follow" => 110,
+    "to followed" => 3,
+    "to for" => 81,
+    "to form" => 45,
+    "to format" => 78,
+    "to formatting" => 4,
+    "to forward" => 15,
+    "to four" => 18,
+    "to free" => 38,
+    "to from" => 115,
+    "to full" => 8,
+    "to fully" => 21,
+    "to function" => 31,
+    "to functions" => 52,
+    "to further" => 22,
+    "to future" => 10,
+    "to general" => 5,
+    "to generally" => 5,
+    "to generate" => 377,
+    "to generic" => 4,
+    "to get" => 1079,
+    "to getting" => 3,
+    "to git" => 20,
+    "to give" => 181,
+    "to given" => 7,
+    "to global" => 14,
+    "to go" => 347,
+    "to good" => 5,
+    "to greater" => 3,
+    "to group" => 23,
+    "to guide" => 16,
+    "to half" => 7,
+    "to hand" => 15,
+    "to handle" => 278,
+    "to handling" => 4,
+    "to hard" => 4,
+    "to has" => 11,
+    "to hash" => 19,
+    "to have" => 878,
+    "to having" => 8,
+    "to help" => 274,
+    "to her" => 145,
+    "to here" => 5,
+    "to high" => 5,
+    "to higher" => 6,
+    "to highlight" => 82,
+    "to him" => 178,
+    "to his" => 204,
+    "to holmes" => 5,
+    "to home" => 8,
+    "to host" => 11,
+    "to how" => 80,
+    "to i" => 10,
+    "to if" => 105,
+    "to ignore" => 100,
+    "to image" => 9,
+    "to implement" => 498,
+    "to implementation" => 18,
+    "to implementing" => 4,
+    "to import" => 68,
+    "to in" => 143,
+    "to include" => 233,
+    "to including" => 10,
+    "to indent" => 9,
+    "to index" => 19,
+    "to information" => 5,
+    "to input" => 15,
+    "to insert" => 125,
+    "to inside" => 7,
+    "to install" => 218,
+    "to instances" => 3,
+    "to instead" => 41,
+    "to instruction" => 3,
+    "to instructions" => 7,
+    "to integer" => 21,
+    "to integers" => 9,
+    "to interface" => 18,
+    "to internal" => 8,
+    "to into" => 4,
+    "to invalid" => 5,
+    "to is" => 102,
+    "to issue" => 18,
+    "to issues" => 5,
+    "to it" => 438,
+    "to it's" => 5,
+    "to item" => 3,
+    "to items" => 19,
+    "to iterator" => 10,
+    "to its" => 262,
+    "to itself" => 31,
+    "to job" => 12,
+    "to just" => 69,
+    "to keep" => 329,
+    "to key" => 5,
+    "to know" => 282,
+    "to known" => 3,
+    "to language" => 5,
+    "to large" => 8,
+    "to last" => 32,
+    "to later" => 6,
+    "to latest" => 6,
+    "to latin" => 8,
+    "to least" => 3,
+    "to left" => 74,
+    "to length" => 3,
+    "to less" => 3,
+    "to let" => 81,
+    "to level" => 3,
+    "to libraries" => 9,
+    "to library" => 9,
+    "to license" => 7,
+    "to life" => 13,
+    "to lifetime" => 5,
+    "to like" => 13,
+    "to line" => 29,
+    "to lines" => 10,
+    "to link" => 140,
+    "to list" => 101,
+    "to listing" => 6,
+    "to little" => 5,
+    "to load" => 194,
+    "to local" => 21,
+    "to location" => 5,
+    "to lock" => 21,
+    "to log" => 28,
+    "to long" => 13,
+    "to look" => 211,
+    "to loop" => 9,
+    "to low" => 9,
+    "to lower" => 19,
+    "to mac" => 7,
+    "to machine" => 7,
+    "to macro" => 3,
+    "to macros" => 7,
+    "to main" => 8,
+    "to make" => 1350,
+    "to makes" => 4,
+    "to making" => 11,
+    "to man" => 4,
+    "to manually" => 72,
+    "to many" => 34,
+    "to map" => 52,
+    "to mark" => 87,
+    "to mask" => 13,
+    "to match" => 261,
+    "to matching" => 6,
+    "to may" => 22,
+    "to me" => 388,
+    "to memory" => 117,
+    "to menu" => 4,
+    "to method" => 10,
+    "to methods" => 6,
+    "to might" => 3,
+    "to minimum" => 3,
+    "to missing" => 9,
+    "to mode" => 12,
+    "to model" => 13,
+    "to module" => 4,
+    "to modules" => 7,
+    "to more" => 30,
+    "to most" => 14,
+    "to mouse" => 7,
+    "to move" => 228,
+    "to multiple" => 28,
+    "to multiply" => 6,
+    "to must" => 44,
+    "to mutable" => 5,
+    "to my" => 242,
+    "to name" => 46,
+    "to named" => 5,
+    "to names" => 6,
+    "to native" => 21,
+    "to nearest" => 479,
+    "to need" => 10,
+    "to network" => 4,
+    "to never" => 27,
+    "to new" => 86,
+    "to next" => 36,
+    "to nightly" => 6,
+    "to no" => 32,
+    "to node" => 23,
+    "to non" => 40,
+    "to none" => 9,
+    "to normal" => 49,
+    "to not" => 167,
+    "to note" => 48,
+    "to nothing" => 10,
+    "to now" => 9,
+    "to null" => 49,
+    "to number" => 18,
+    "to numbers" => 4,
+    "to object" => 11,
+    "to objects" => 7,
+    "to of" => 6,
+    "to off" => 8,
+    "to offset" => 5,
+    "to old" => 9,
+    "to on" => 45,
+    "to once" => 3,
+    "to one" => 206,
+    "to only" => 147,
+    "to open" => 204,
+    "to operating" => 3,
+    "to option" => 5,
+    "to optional" => 3,
+    "to or" => 217,
+    "to order" => 8,
+    "to original" => 3,
+    "to other" => 183,
+    "to otherwise" => 4,
+    "to our" => 106,
+    "to out" => 5,
+    "to output" => 33,
+    "to outside" => 3,
+    "to overflow" => 14,
+    "to own" => 11,
+    "to package" => 13,
+    "to packages" => 3,
+    "to packed" => 568,
+    "to page" => 5,
+    "to panic" => 53,
+    "to panics" => 7,
+    "to parent" => 3,
+    "to parse" => 205,
+    "to parsing" => 6,
+    "to part" => 16,
+    "to parts" => 5,
+    "to pass" => 231,
+    "to passing" => 10,
+    "to patch" => 12,
+    "to path" => 10,
+    "to paths" => 4,
+    "to pattern" => 5,
+    "to patterns" => 6,
+    "to people" => 14,
+    "to per" => 3,
+    "to perform" => 224,
+    "to performance" => 9,
+    "to place" => 39,
+    "to platform" => 14,
+    "to please" => 7,
+    "to point" => 65,
+    "to pointer" => 24,
+    "to pointers" => 6,
+    "to popup" => 16,
+    "to port" => 19,
+    "to position" => 17,
+    "to possible" => 5,
+    "to prefix" => 6,
+    "to present" => 10,
+    "to previous" => 52,
+    "to previously" => 4,
+    "to print" => 107,
+    "to private" => 7,
+    "to problems" => 5,
+    "to process" => 58,
+    "to produce" => 149,
+    "to program" => 5,
+    "to properly" => 31,
+    "to provide" => 469,
+    "to provided" => 4,
+    "to public" => 8,
+    "to pull" => 47,
+    "to put" => 212,
+    "to python" => 17,
+    "to range" => 4,
+    "to rather" => 11,
+    "to raw" => 13,
+    "to re" => 66,
+    "to read" => 442,
+    "to reading" => 8,
+    "to real" => 6,
+    "to really" => 5,
+    "to reason" => 26,
+    "to refer" => 82,
+    "to reference" => 44,
+    "to references" => 4,
+    "to register" => 60,
+    "to related" => 3,
+    "to release" => 37,
+    "to remote" => 8,
+    "to remove" => 202,
+    "to replace" => 119,
+    "to report" => 56,
+    "to repository" => 3,
+    "to request" => 30,
+    "to requests" => 5,
+    "to require" => 26,
+    "to reset" => 16,
+    "to result" => 19,
+    "to return" => 277,
+    "to returning" => 10,
+    "to returns" => 9,
+    "to rfc" => 15,
+    "to right" => 86,
+    "to round" => 9,
+    "to rounding" => 7,
+    "to ruby" => 17,
+    "to run" => 810,
+    "to running" => 13,
+    "to runtime" => 7,
+    "to rust" => 186,
+    "to safe" => 8,
+    "to safety" => 4,
+    "to save" => 111,
+    "to say" => 163,
+    "to scope" => 3,
+    "to screen" => 5,
+    "to script" => 9,
+    "to search" => 116,
+    "to section" => 5,
+    "to security" => 11,
+    "to see" => 801,
+    "to select" => 144,
+    "to self" => 3,
+    "to send" => 220,
+    "to sent" => 3,
+    "to separate" => 89,
+    "to server" => 14,
+    "to set" => 615,
+    "to setting" => 5,
+    "to several" => 4,
+    "to shared" => 5,
+    "to shell" => 4,
+    "to shift" => 8,
+    "to should" => 5,
+    "to show" => 207,
+    "to side" => 8,
+    "to sign" => 28,
+    "to signed" => 17,
+    "to simply" => 15,
+    "to since" => 9,
+    "to single" => 24,
+    "to size" => 16,
+    "to skip" => 120,
+    "to slice" => 3,
+    "to so" => 28,
+    "to software" => 5,
+    "to some" => 122,
+    "to something" => 52,
+    "to sometimes" => 4,
+    "to source" => 23,
+    "to special" => 3,
+    "to specific" => 29,
+    "to specify" => 474,
+    "to split" => 48,
+    "to stable" => 9,
+    "to stack" => 5,
+    "to standard" => 33,
+    "to start" => 332,
+    "to starting" => 8,
+    "to state" => 9,
+    "to static" => 7,
+    "to std" => 11,
+    "to still" => 10,
+    "to stop" => 96,
+    "to store" => 259,
+    "to stream" => 11,
+    "to string" => 59,
+    "to strings" => 24,
+    "to structure" => 13,
+    "to such" => 31,
+    "to support" => 391,
+    "to suppress" => 21,
+    "to syntax" => 14,
+    "to system" => 18,
+    "to tab" => 5,
+    "to tag" => 32,
+    "to tags" => 4,
+    "to take" => 282,
+    "to target" => 32,
+    "to tell" => 202,
+    "to term" => 6,
+    "to terminal" => 23,
+    "to test" => 228,
+    "to testing" => 4,
+    "to tests" => 5,
+    "to text" => 22,
+    "to than" => 3,
+    "to that" => 334,
+    "to the" => 12687,
+    "to their" => 165,
+    "to them" => 126,
+    "to then" => 40,
+    "to there" => 5,
+    "to these" => 66,
+    "to things" => 5,
+    "to think" => 89,
+    "to this" => 606,
+    "to those" => 124,
+    "to though" => 4,
+    "to thread" => 6,
+    "to threads" => 4,
+    "to three" => 15,
+    "to through" => 6,
+    "to time" => 54,
+    "to times" => 18,
+    "to to" => 154,
+    "to token" => 3,
+    "to tom" => 19,
+    "to tools" => 7,
+    "to top" => 10,
+    "to trait" => 18,
+    "to traits" => 7,
+    "to tree" => 3,
+    "to true" => 52,
+    "to truncate" => 6,
+    "to try" => 142,
+    "to turn" => 97,
+    "to two" => 40,
+    "to type" => 171,
+    "to types" => 32,
+    "to undefined" => 35,
+    "to undo" => 34,
+    "to unit" => 3,
+    "to unix" => 29,
+    "to unless" => 5,
+    "to unsafe" => 12,
+    "to unsigned" => 25,
+    "to until" => 4,
+    "to update" => 154,
+    "to upper" => 7,
+    "to us" => 57,
+    "to usage" => 6,
+    "to use" => 3749,
+    "to user" => 21,
+    "to users" => 27,
+    "to using" => 121,
+    "to valid" => 12,
+    "to value" => 21,
+    "to values" => 28,
+    "to variable" => 7,
+    "to variables" => 13,
+    "to various" => 20,
+    "to vector" => 5,
+    "to version" => 23,
+    "to versions" => 5,
+    "to very" => 9,
+    "to via" => 13,
+    "to vim" => 107,
+    "to visual" => 10,
+    "to wait" => 122,
+    "to want" => 9,
+    "to warning" => 4,
+    "to we" => 14,
+    "to we'll" => 3,
+    "to web" => 6,
+    "to well" => 3,
+    "to what" => 91,
+    "to when" => 53,
+    "to where" => 75,
+    "to whether" => 12,
+    "to which" => 218,
+    "to while" => 10,
+    "to white" => 3,
+    "to whose" => 7,
+    "to why" => 12,
+    "to will" => 73,
+    "to win" => 15,
+    "to window" => 19,
+    "to windows" => 27,
+    "to with" => 88,
+    "to within" => 6,
+    "to without" => 17,
+    "to word" => 3,
+    "to work" => 519,
+    "to working" => 3,
+    "to works" => 6,
+    "to would" => 8,
+    "to write" => 599,
+    "to writing" => 11,
+    "to wrong" => 10,
+    "to yes" => 5,
+    "to you" => 214,
+    "to your" => 1419,
+    "to zero" => 142,
+    "together a" => 6,
+    "together and" => 34,
+    "together the" => 9,
+    "together to" => 20,
+    "token and" => 16,
+    "token for" => 21,
+    "token in" => 7,
+    "token is" => 38,
+    "token of" => 8,
+    "token to" => 12,
+    "tokens the" => 4,
+    "told the" => 8,
+    "tom a" => 5,
+    "tom and" => 34,
+    "tom in" => 7,
+    "tom the" => 5,
+    "tom to" => 5,
+    "too a" => 3,
+    "too and" => 12,
+    "too for" => 8,
+    "too in" => 4,
+    "too that" => 4,
+    "took the" => 52,
+    "tool the" => 4,
+    "tool to" => 43,
+    "tools and" => 44,
+    "tools in" => 14,
+    "tools is" => 4,
+    "tools the" => 4,
+    "tools to" => 36,
+    "top and" => 16,
+    "top is" => 4,
+    "top of" => 373,
+    "top to" => 7,
+    "towards the" => 71,
+    "trace the" => 4,
+    "tracing the" => 3,
+    "track the" => 42,
+    "tracking the" => 15,
+    "trait an" => 3,
+    "trait and" => 88,
+    "trait are" => 17,
+    "trait as" => 31,
+    "trait be" => 3,
+    "trait by" => 5,
+    "trait can" => 41,
+    "trait for" => 238,
+    "trait from" => 25,
+    "trait if" => 22,
+    "trait in" => 33,
+    "trait is" => 184,
+    "trait it" => 12,
+    "trait of" => 16,
+    "trait on" => 51,
+    "trait or" => 49,
+    "trait that" => 70,
+    "trait the" => 17,
+    "trait this" => 3,
+    "trait to" => 103,
+    "trait using" => 5,
+    "trait when" => 3,
+    "trait will" => 18,
+    "trait with" => 36,
+    "trait you" => 11,
+    "traits a" => 3,
+    "traits and" => 141,
+    "traits for" => 130,
+    "traits in" => 46,
+    "traits is" => 6,
+    "traits of" => 6,
+    "traits that" => 66,
+    "traits the" => 8,
+    "traits this" => 3,
+    "traits to" => 40,
+    "traits with" => 30,
+    "treated the" => 9,
+    "tree a" => 4,
+    "tree and" => 24,
+    "tree in" => 12,
+    "tree is" => 10,
+    "tree of" => 40,
+    "tree to" => 7,
+    "tries the" => 4,
+    "trigger the" => 42,
+    "true and" => 32,
+    "true in" => 10,
+    "true is" => 8,
+    "true of" => 4,
+    "true the" => 16,
+    "true to" => 23,
+    "truncate the" => 29,
+    "truncate to" => 3,
+    "try a" => 8,
+    "try and" => 29,
+    "try in" => 5,
+    "try is" => 5,
+    "try the" => 46,
+    "try this" => 15,
+    "try to" => 478,
+    "trying the" => 8,
+    "tuple a" => 4,
+    "tuple and" => 13,
+    "tuple of" => 71,
+    "turn the" => 34,
+    "turn to" => 18,
+    "turned the" => 11,
+    "twice the" => 18,
+    "two and" => 21,
+    "two are" => 24,
+    "two as" => 3,
+    "two at" => 24,
+    "two by" => 4,
+    "two file" => 3,
+    "two files" => 34,
+    "two i" => 3,
+    "two in" => 6,
+    "two is" => 11,
+    "two of" => 36,
+    "two or" => 77,
+    "two rust" => 4,
+    "two that" => 4,
+    "two the" => 3,
+    "two to" => 10,
+    "two which" => 4,
+    "two will" => 3,
+    "two you" => 3,
+    "type a" => 74,
+    "type add" => 10,
+    "type after" => 15,
+    "type also" => 14,
+    "type an" => 12,
+    "type and" => 290,
+    "type any" => 13,
+    "type are" => 33,
+    "type as" => 101,
+    "type at" => 10,
+    "type be" => 9,
+    "type but" => 65,
+    "type by" => 18,
+    "type can" => 119,
+    "type data" => 3,
+    "type default" => 4,
+    "type does" => 37,
+    "type error" => 25,
+    "type first" => 3,
+    "type for" => 191,
+    "type from" => 66,
+    "type function" => 9,
+    "type has" => 75,
+    "type i" => 11,
+    "type if" => 30,
+    "type in" => 170,
+    "type into" => 25,
+    "type is" => 634,
+    "type it" => 42,
+    "type library" => 3,
+    "type like" => 31,
+    "type may" => 40,
+    "type more" => 5,
+    "type must" => 89,
+    "type name" => 25,
+    "type no" => 5,
+    "type not" => 23,
+    "type number" => 3,
+    "type of" => 721,
+    "type on" => 20,
+    "type one" => 4,
+    "type only" => 9,
+    "type or" => 102,
+    "type other" => 15,
+    "type out" => 4,
+    "type should" => 10,
+    "type so" => 27,
+    "type string" => 6,
+    "type support" => 8,
+    "type than" => 7,
+    "type that" => 318,
+    "type the" => 109,
+    "type then" => 26,
+    "type there" => 4,
+    "type these" => 7,
+    "type they" => 7,
+    "type this" => 31,
+    "type to" => 226,
+    "type type" => 46,
+    "type use" => 8,
+    "type used" => 33,
+    "type using" => 23,
+    "type value" => 13,
+    "type was" => 18,
+    "type we" => 28,
+    "type when" => 33,
+    "type which" => 123,
+    "type will" => 65,
+    "type with" => 104,
+    "type without" => 16,
+    "type you" => 42,
+    "type your" => 6,
+    "typed the" => 4,
+    "types a" => 10,
+    "types all" => 12,
+    "types an" => 3,
+    "types and" => 329,
+    "types are" => 252,
+    "types as" => 60,
+    "types at" => 13,
+    "types but" => 22,
+    "types by" => 17,
+    "types can" => 84,
+    "types for" => 174,
+    "types from" => 94,
+    "types have" => 37,
+    "types i" => 5,
+    "types if" => 8,
+    "types in" => 190,
+    "types is" => 65,
+    "types it" => 13,
+    "types not" => 13,
+    "types of" => 285,
+    "types on" => 9,
+    "types or" => 25,
+    "types rust" => 6,
+    "types see" => 14,
+    "types that" => 309,
+    "types the" => 25,
+    "types this" => 19,
+    "types to" => 127,
+    "types use" => 7,
+    "types used" => 36,
+    "types using" => 22,
+    "types when" => 12,
+    "types which" => 88,
+    "types will" => 19,
+    "types with" => 91,
+    "types you" => 32,
+    "typically the" => 12,
+    "typing the" => 39,
+    "undefined a" => 12,
+    "undefined and" => 6,
+    "undefined the" => 35,
+    "under a" => 81,
+    "under an" => 7,
+    "under and" => 11,
+    "under in" => 7,
+    "under it" => 10,
+    "under of" => 3,
+    "under on" => 3,
+    "under or" => 7,
+    "under that" => 10,
+    "under the" => 1348,
+    "under this" => 18,
+    "under to" => 3,
+    "under vim" => 3,
+    "underlying and" => 3,
+    "underlying in" => 3,
+    "underlying the" => 7,
+    "understand the" => 36,
+    "undo a" => 6,
+    "undo the" => 18,
+    "unix a" => 6,
+    "unix and" => 109,
+    "unix for" => 4,
+    "unix in" => 5,
+    "unix is" => 4,
+    "unix that" => 4,
+    "unix the" => 25,
+    "unix this" => 16,
+    "unix to" => 8,
+    "unix with" => 10,
+    "unknown to" => 12,
+    "unless a" => 16,
+    "unless and" => 3,
+    "unless for" => 4,
+    "unless in" => 4,
+    "unless is" => 28,
+    "unless it" => 48,
+    "unless that" => 4,
+    "unless the" => 131,
+    "unless this" => 4,
+    "unless you" => 1204,
+    "unlike the" => 70,
+    "unsafe and" => 16,
+    "unsafe for" => 14,
+    "unsafe in" => 3,
+    "unsafe is" => 4,
+    "unsafe to" => 24,
+    "unsigned and" => 10,
+    "unstable the" => 5,
+    "until a" => 66,
+    "until is" => 20,
+    "until the" => 327,
+    "until to" => 3,
+    "up a" => 216,
+    "up all" => 21,
+    "up an" => 31,
+    "up and" => 653,
+    "up as" => 41,
+    "up at" => 46,
+    "up by" => 50,
+    "up can" => 3,
+    "up file" => 3,
+    "up for" => 37,
+    "up from" => 29,
+    "up i" => 13,
+    "up if" => 10,
+    "up in" => 230,
+    "up it" => 6,
+    "up of" => 47,
+    "up on" => 44,
+    "up or" => 30,
+    "up that" => 9,
+    "up the" => 374,
+    "up this" => 17,
+    "up to" => 661,
+    "up use" => 4,
+    "up using" => 7,
+    "up vim" => 10,
+    "up when" => 20,
+    "up which" => 5,
+    "up with" => 147,
+    "up you" => 10,
+    "update a" => 14,
+    "update and" => 11,
+    "update for" => 8,
+    "update in" => 3,
+    "update is" => 4,
+    "update of" => 5,
+    "update the" => 267,
+    "update to" => 81,
+    "updated the" => 10,
+    "updated to" => 93,
+    "updates the" => 38,
+    "updating the" => 81,
+    "upon a" => 49,
+    "upon and" => 5,
+    "upon for" => 7,
+    "upon in" => 6,
+    "upon the" => 369,
+    "upon this" => 10,
+    "upon to" => 15,
+    "upper and" => 12,
+    "us a" => 28,
+    "us and" => 34,
+    "us for" => 3,
+    "us in" => 33,
+    "us is" => 3,
+    "us of" => 4,
+    "us the" => 34,
+    "us to" => 180,
+    "usage a" => 6,
+    "usage and" => 50,
+    "usage as" => 10,
+    "usage by" => 4,
+    "usage can" => 3,
+    "usage for" => 12,
+    "usage in" => 48,
+    "usage is" => 34,
+    "usage of" => 235,
+    "usage on" => 7,
+    "usage or" => 6,
+    "usage that" => 6,
+    "usage the" => 10,
+    "usage this" => 3,
+    "usage to" => 9,
+    "usage with" => 54,
+    "usage you" => 5,
+    "use a" => 1099,
+    "use add" => 16,
+    "use after" => 27,
+    "use all" => 34,
+    "use an" => 227,
+    "use and" => 191,
+    "use any" => 93,
+    "use are" => 14,
+    "use argument" => 3,
+    "use as" => 136,
+    "use at" => 59,
+    "use because" => 11,
+    "use before" => 6,
+    "use bit" => 10,
+    "use both" => 14,
+    "use but" => 22,
+    "use by" => 60,
+    "use call" => 4,
+    "use can" => 5,
+    "use cargo" => 35,
+    "use case" => 208,
+    "use check" => 4,
+    "use code" => 5,
+    "use command" => 9,
+    "use compile" => 4,
+    "use crate" => 15,
+    "use crates" => 21,
+    "use current" => 3,
+    "use data" => 12,
+    "use default" => 11,
+    "use different" => 34,
+    "use directory" => 3,
+    "use each" => 4,
+    "use either" => 26,
+    "use feature" => 13,
+    "use features" => 10,
+    "use file" => 26,
+    "use files" => 4,
+    "use first" => 17,
+    "use floating" => 4,
+    "use following" => 14,
+    "use for" => 232,
+    "use from" => 20,
+    "use function" => 15,
+    "use functions" => 8,
+    "use get" => 11,
+    "use has" => 3,
+    "use i" => 9,
+    "use if" => 42,
+    "use in" => 313,
+    "use instead" => 299,
+    "use is" => 54,
+    "use it" => 527,
+    "use it's" => 5,
+    "use its" => 25,
+    "use just" => 7,
+    "use key" => 7,
+    "use library" => 5,
+    "use like" => 9,
+    "use line" => 12,
+    "use list" => 4,
+    "use macro" => 9,
+    "use make" => 7,
+    "use may" => 12,
+    "use memory" => 3,
+    "use method" => 7,
+    "use more" => 25,
+    "use must" => 4,
+    "use new" => 12,
+    "use no" => 10,
+    "use not" => 8,
+    "use object" => 3,
+    "use of" => 1025,
+    "use on" => 65,
+    "use one" => 88,
+    "use only" => 44,
+    "use option" => 4,
+    "use or" => 148,
+    "use other" => 23,
+    "use path" => 8,
+    "use return" => 7,
+    "use runtime" => 3,
+    "use rust" => 31,
+    "use same" => 6,
+    "use script" => 4,
+    "use see" => 464,
+    "use set" => 4,
+    "use should" => 8,
+    "use single" => 33,
+    "use so" => 9,
+    "use some" => 27,
+    "use string" => 8,
+    "use such" => 10,
+    "use syntax" => 15,
+    "use test" => 7,
+    "use text" => 6,
+    "use than" => 8,
+    "use that" => 91,
+    "use the" => 3406,
+    "use their" => 15,
+    "use them" => 118,
+    "use these" => 100,
+    "use this" => 721,
+    "use to" => 499,
+    "use trait" => 10,
+    "use two" => 29,
+    "use type" => 10,
+    "use types" => 10,
+    "use under" => 6,
+    "use up" => 5,
+    "use use" => 7,
+    "use values" => 4,
+    "use version" => 4,
+    "use vim" => 104,
+    "use we" => 5,
+    "use what" => 5,
+    "use when" => 65,
+    "use where" => 10,
+    "use which" => 42,
+    "use while" => 4,
+    "use will" => 3,
+    "use windows" => 3,
+    "use with" => 201,
+    "use without" => 17,
+    "use would" => 3,
+    "use you" => 17,
+    "use your" => 68,
+    "used a" => 46,
+    "used after" => 51,
+    "used all" => 3,
+    "used an" => 10,
+    "used and" => 112,
+    "used any" => 12,
+    "used are" => 10,
+    "used as" => 690,
+    "used at" => 39,
+    "used but" => 39,
+    "used by" => 648,
+    "used command" => 3,
+    "used crate" => 5,
+    "used file" => 5,
+    "used for" => 1157,
+    "used from" => 49,
+    "used if" => 68,
+    "used in" => 1067,
+    "used is" => 32,
+    "used it" => 27,
+    "used library" => 3,
+    "used like" => 33,
+    "used may" => 5,
+    "used more" => 10,
+    "used no" => 4,
+    "used not" => 5,
+    "used on" => 166,
+    "used one" => 7,
+    "used only" => 35,
+    "used or" => 24,
+    "used rust" => 7,
+    "used see" => 13,
+    "used so" => 13,
+    "used string" => 3,
+    "used that" => 16,
+    "used the" => 124,
+    "used then" => 19,
+    "used there" => 4,
+    "used this" => 15,
+    "used to" => 2565,
+    "used vim" => 8,
+    "used we" => 3,
+    "used when" => 252,
+    "used which" => 40,
+    "used with" => 370,
+    "used without" => 60,
+    "used you" => 13,
+    "useful and" => 26,
+    "useful for" => 405,
+    "useful in" => 103,
+    "useful is" => 3,
+    "useful it" => 3,
+    "useful that" => 5,
+    "useful the" => 3,
+    "useful to" => 204,
+    "useful with" => 8,
+    "useful you" => 4,
+    "user a" => 8,
+    "user and" => 28,
+    "user as" => 7,
+    "user for" => 11,
+    "user if" => 4,
+    "user in" => 9,
+    "user is" => 27,
+    "user not" => 3,
+    "user of" => 39,
+    "user on" => 9,
+    "user or" => 11,
+    "user that" => 8,
+    "user the" => 7,
+    "user to" => 136,
+    "user with" => 10,
+    "user you" => 8,
+    "users a" => 5,
+    "users and" => 49,
+    "users in" => 13,
+    "users of" => 89,
+    "users the" => 7,
+    "users to" => 139,
+    "uses a" => 283,
+    "uses an" => 54,
+    "uses and" => 50,
+    "uses are" => 6,
+    "uses as" => 24,
+    "uses by" => 5,
+    "uses can" => 4,
+    "uses for" => 52,
+    "uses in" => 15,
+    "uses it" => 39,
+    "uses of" => 81,
+    "uses on" => 5,
+    "uses or" => 7,
+    "uses that" => 10,
+    "uses the" => 667,
+    "uses this" => 42,
+    "uses to" => 103,
+    "uses when" => 5,
+    "uses with" => 3,
+    "uses you" => 3,
+    "using a" => 1103,
+    "using add" => 4,
+    "using after" => 4,
+    "using all" => 13,
+    "using an" => 254,
+    "using and" => 172,
+    "using any" => 48,
+    "using are" => 15,
+    "using as" => 79,
+    "using at" => 8,
+    "using before" => 4,
+    "using bit" => 48,
+    "using buffer" => 11,
+    "using build" => 4,
+    "using but" => 6,
+    "using by" => 6,
+    "using can" => 16,
+    "using cargo" => 61,
+    "using code" => 4,
+    "using command" => 10,
+    "using crate" => 8,
+    "using crates" => 20,
+    "using data" => 5,
+    "using default" => 21,
+    "using does" => 5,
+    "using elements" => 6,
+    "using error" => 3,
+    "using examples" => 5,
+    "using feature" => 6,
+    "using features" => 9,
+    "using file" => 8,
+    "using files" => 3,
+    "using for" => 63,
+    "using from" => 8,
+    "using function" => 17,
+    "using functions" => 13,
+    "using has" => 3,
+    "using i" => 7,
+    "using if" => 9,
+    "using in" => 81,
+    "using instead" => 69,
+    "using is" => 42,
+    "using it" => 131,
+    "using its" => 24,
+    "using library" => 5,
+    "using license" => 3,
+    "using like" => 4,
+    "using line" => 7,
+    "using list" => 3,
+    "using may" => 3,
+    "using memory" => 7,
+    "using method" => 6,
+    "using more" => 18,
+    "using must" => 4,
+    "using new" => 7,
+    "using not" => 6,
+    "using number" => 4,
+    "using on" => 45,
+    "using one" => 45,
+    "using only" => 30,
+    "using or" => 134,
+    "using other" => 20,
+    "using packed" => 12,
+    "using runtime" => 3,
+    "using rust" => 62,
+    "using see" => 6,
+    "using set" => 7,
+    "using so" => 6,
+    "using some" => 15,
+    "using string" => 4,
+    "using such" => 7,
+    "using text" => 8,
+    "using that" => 28,
+    "using the" => 2653,
+    "using them" => 41,
+    "using then" => 7,
+    "using these" => 55,
+    "using this" => 316,
+    "using to" => 118,
+    "using trait" => 22,
+    "using two" => 22,
+    "using type" => 16,
+    "using types" => 7,
+    "using up" => 8,
+    "using use" => 6,
+    "using values" => 38,
+    "using version" => 6,
+    "using vim" => 55,
+    "using we" => 10,
+    "using when" => 7,
+    "using where" => 7,
+    "using which" => 28,
+    "using will" => 27,
+    "using windows" => 6,
+    "using with" => 54,
+    "using without" => 7,
+    "using would" => 3,
+    "using you" => 15,
+    "using your" => 43,
+    "usually a" => 29,
+    "usually the" => 30,
+    "valid and" => 21,
+    "valid for" => 108,
+    "valid in" => 33,
+    "valid is" => 5,
+    "valid to" => 43,
+    "value a" => 19,
+    "value add" => 3,
+    "value after" => 12,
+    "value an" => 4,
+    "value and" => 286,
+    "value any" => 3,
+    "value are" => 23,
+    "value as" => 89,
+    "value at" => 59,
+    "value but" => 33,
+    "value by" => 39,
+    "value can" => 68,
+    "value do" => 3,
+    "value file" => 3,
+    "value for" => 282,
+    "value from" => 206,
+    "value has" => 41,
+    "value if" => 101,
+    "value in" => 351,
+    "value into" => 54,
+    "value is" => 919,
+    "value it" => 36,
+    "value like" => 6,
+    "value may" => 74,
+    "value no" => 5,
+    "value not" => 10,
+    "value of" => 1219,
+    "value on" => 71,
+    "value one" => 6,
+    "value only" => 14,
+    "value or" => 97,
+    "value other" => 8,
+    "value see" => 14,
+    "value set" => 16,
+    "value so" => 23,
+    "value string" => 3,
+    "value that" => 221,
+    "value the" => 67,
+    "value then" => 15,
+    "value this" => 15,
+    "value to" => 314,
+    "value type" => 41,
+    "value use" => 12,
+    "value used" => 13,
+    "value using" => 30,
+    "value vim" => 3,
+    "value was" => 44,
+    "value we" => 32,
+    "value when" => 70,
+    "value which" => 67,
+    "value will" => 124,
+    "value with" => 86,
+    "value you" => 42,
+    "values a" => 3,
+    "values and" => 129,
+    "values are" => 279,
+    "values as" => 42,
+    "values by" => 22,
+    "values can" => 52,
+    "values for" => 130,
+    "values from" => 145,
+    "values in" => 296,
+    "values is" => 22,
+    "values it" => 10,
+    "values not" => 12,
+    "values of" => 307,
+    "values on" => 10,
+    "values or" => 25,
+    "values that" => 103,
+    "values the" => 13,
+    "values this" => 5,
+    "values to" => 99,
+    "values use" => 10,
+    "values using" => 13,
+    "values when" => 16,
+    "values will" => 37,
+    "values with" => 62,
+    "values you" => 15,
+    "variable a" => 11,
+    "variable and" => 44,
+    "variable as" => 18,
+    "variable by" => 9,
+    "variable can" => 21,
+    "variable for" => 34,
+    "variable if" => 19,
+    "variable in" => 70,
+    "variable is" => 238,
+    "variable it" => 4,
+    "variable not" => 5,
+    "variable of" => 14,
+    "variable on" => 14,
+    "variable or" => 76,
+    "variable that" => 48,
+    "variable the" => 13,
+    "variable this" => 5,
+    "variable to" => 141,
+    "variable when" => 21,
+    "variable with" => 40,
+    "variable you" => 10,
+    "variables and" => 97,
+    "variables for" => 24,
+    "variables in" => 77,
+    "variables is" => 14,
+    "variables of" => 18,
+    "variables that" => 80,
+    "variables the" => 7,
+    "variables to" => 38,
+    "variables with" => 18,
+    "variant and" => 14,
+    "variant of" => 103,
+    "variant the" => 4,
+    "variant to" => 9,
+    "variants the" => 4,
+    "variants to" => 18,
+    "vector a" => 5,
+    "vector and" => 39,
+    "vector as" => 8,
+    "vector for" => 9,
+    "vector in" => 33,
+    "vector is" => 29,
+    "vector of" => 277,
+    "vector that" => 10,
+    "vector the" => 4,
+    "vector to" => 37,
+    "vector with" => 97,
+    "vectors the" => 3,
+    "verify the" => 41,
+    "version a" => 8,
+    "version and" => 179,
+    "version are" => 7,
+    "version as" => 52,
+    "version at" => 18,
+    "version be" => 3,
+    "version but" => 10,
+    "version by" => 18,
+    "version can" => 65,
+    "version code" => 4,
+    "version command" => 11,
+    "version crate" => 17,
+    "version for" => 79,
+    "version from" => 23,
+    "version has" => 9,
+    "version if" => 12,
+    "version in" => 53,
+    "version into" => 3,
+    "version is" => 374,
+    "version it" => 14,
+    "version license" => 10,
+    "version like" => 8,
+    "version may" => 21,
+    "version no" => 3,
+    "version not" => 6,
+    "version number" => 111,
+    "version of" => 1298,
+    "version on" => 15,
+    "version one" => 3,
+    "version only" => 6,
+    "version or" => 34,
+    "version rust" => 11,
+    "version see" => 5,
+    "version so" => 10,
+    "version string" => 19,
+    "version support" => 30,
+    "version that" => 48,
+    "version the" => 38,
+    "version this" => 12,
+    "version to" => 71,
+    "version use" => 5,
+    "version used" => 10,
+    "version using" => 8,
+    "version version" => 16,
+    "version vim" => 15,
+    "version was" => 14,
+    "version we" => 4,
+    "version when" => 11,
+    "version which" => 17,
+    "version will" => 54,
+    "version with" => 45,
+    "version you" => 41,
+    "versions and" => 74,
+    "versions for" => 12,
+    "versions in" => 15,
+    "versions is" => 6,
+    "versions it" => 4,
+    "versions of" => 501,
+    "versions that" => 30,
+    "versions the" => 11,
+    "versions to" => 21,
+    "versions with" => 16,
+    "via a" => 121,
+    "via and" => 44,
+    "via as" => 4,
+    "via for" => 5,
+    "via is" => 8,
+    "via the" => 521,
+    "via this" => 17,
+    "via to" => 10,
+    "view the" => 60,
+    "vim a" => 10,
+    "vim about" => 3,
+    "vim after" => 20,
+    "vim also" => 14,
+    "vim an" => 3,
+    "vim and" => 111,
+    "vim are" => 3,
+    "vim as" => 30,
+    "vim at" => 4,
+    "vim before" => 3,
+    "vim buffer" => 4,
+    "vim but" => 8,
+    "vim by" => 8,
+    "vim can" => 87,
+    "vim code" => 3,
+    "vim command" => 40,
+    "vim default" => 27,
+    "vim directory" => 14,
+    "vim do" => 7,
+    "vim documentation" => 4,
+    "vim does" => 67,
+    "vim error" => 6,
+    "vim features" => 5,
+    "vim file" => 34,
+    "vim files" => 19,
+    "vim for" => 34,
+    "vim from" => 16,
+    "vim function" => 19,
+    "vim functions" => 10,
+    "vim has" => 71,
+    "vim i" => 4,
+    "vim if" => 8,
+    "vim in" => 97,
+    "vim instead" => 10,
+    "vim into" => 4,
+    "vim is" => 200,
+    "vim it" => 13,
+    "vim license" => 4,
+    "vim like" => 8,
+    "vim line" => 3,
+    "vim list" => 3,
+    "vim may" => 30,
+    "vim must" => 4,
+    "vim name" => 4,
+    "vim no" => 4,
+    "vim not" => 10,
+    "vim now" => 14,
+    "vim on" => 41,
+    "vim only" => 11,
+    "vim option" => 4,
+    "vim or" => 27,
+    "vim problem" => 3,
+    "vim run" => 4,
+    "vim runtime" => 76,
+    "vim see" => 6,
+    "vim set" => 7,
+    "vim should" => 12,
+    "vim so" => 5,
+    "vim syntax" => 33,
+    "vim test" => 7,
+    "vim tests" => 3,
+    "vim that" => 30,
+    "vim the" => 42,
+    "vim then" => 12,
+    "vim there" => 3,
+    "vim they" => 4,
+    "vim this" => 11,
+    "vim to" => 175,
+    "vim type" => 64,
+    "vim types" => 5,
+    "vim use" => 12,
+    "vim used" => 3,
+    "vim using" => 9,
+    "vim version" => 196,
+    "vim vim" => 43,
+    "vim was" => 75,
+    "vim way" => 5,
+    "vim what" => 7,
+    "vim when" => 21,
+    "vim where" => 3,
+    "vim which" => 17,
+    "vim will" => 333,
+    "vim window" => 92,
+    "vim windows" => 6,
+    "vim with" => 94,
+    "vim without" => 17,
+    "vim work" => 6,
+    "vim would" => 32,
+    "vim you" => 34,
+    "visual and" => 17,
+    "voice the" => 5,
+    "wait the" => 5,
+    "wait to" => 9,
+    "want a" => 96,
+    "want an" => 29,
+    "want and" => 16,
+    "want as" => 3,
+    "want by" => 3,
+    "want for" => 5,
+    "want from" => 6,
+    "want in" => 4,
+    "want is" => 5,
+    "want it" => 31,
+    "want of" => 11,
+    "want or" => 9,
+    "want that" => 5,
+    "want the" => 114,
+    "want this" => 39,
+    "want to" => 2521,
+    "want vim" => 9,
+    "want when" => 5,
+    "want you" => 19,
+    "wanted the" => 7,
+    "warning and" => 18,
+    "warning for" => 376,
+    "warning in" => 16,
+    "warning is" => 25,
+    "warning the" => 6,
+    "warning to" => 14,
+    "warnings to" => 6,
+    "was a" => 633,
+    "was after" => 7,
+    "was all" => 50,
+    "was also" => 31,
+    "was an" => 113,
+    "was and" => 21,
+    "was any" => 9,
+    "was as" => 44,
+    "was at" => 70,
+    "was but" => 26,
+    "was by" => 11,
+    "was for" => 24,
+    "was from" => 12,
+    "was he" => 26,
+    "was i" => 28,
+    "was in" => 183,
+    "was it" => 34,
+    "was like" => 9,
+    "was more" => 35,
+    "was new" => 4,
+    "was no" => 121,
+    "was not" => 497,
+    "was of" => 24,
+    "was on" => 52,
+    "was one" => 50,
+    "was only" => 73,
+    "was or" => 4,
+    "was out" => 22,
+    "was set" => 110,
+    "was so" => 65,
+    "was some" => 11,
+    "was that" => 73,
+    "was the" => 334,
+    "was then" => 18,
+    "was there" => 36,
+    "was this" => 18,
+    "was time" => 7,
+    "was to" => 166,
+    "was used" => 121,
+    "was using" => 24,
+    "was when" => 13,
+    "was with" => 15,
+    "was without" => 3,
+    "was you" => 7,
+    "was your" => 9,
+    "way a" => 14,
+    "way an" => 3,
+    "way and" => 50,
+    "way as" => 97,
+    "way by" => 13,
+    "way can" => 8,
+    "way for" => 53,
+    "way from" => 4,
+    "way if" => 14,
+    "way in" => 41,
+    "way is" => 62,
+    "way it" => 39,
+    "way not" => 3,
+    "way of" => 182,
+    "way on" => 8,
+    "way or" => 11,
+    "way that" => 134,
+    "way the" => 57,
+    "way this" => 13,
+    "way to" => 1038,
+    "way vim" => 7,
+    "way when" => 11,
+    "way with" => 16,
+    "way you" => 68,
+    "ways a" => 7,
+    "ways and" => 14,
+    "ways to" => 247,
+    "we add" => 46,
+    "we all" => 20,
+    "we also" => 94,
+    "we are" => 267,
+    "we can" => 1004,
+    "we have" => 492,
+    "we in" => 3,
+    "we like" => 4,
+    "we may" => 78,
+    "we no" => 9,
+    "we not" => 4,
+    "we only" => 60,
+    "we see" => 35,
+    "we set" => 15,
+    "we test" => 7,
+    "we then" => 26,
+    "we to" => 4,
+    "we use" => 264,
+    "we used" => 54,
+    "we was" => 3,
+    "we will" => 214,
+    "web and" => 10,
+    "web to" => 4,
+    "well a" => 3,
+    "well and" => 33,
+    "well as" => 657,
+    "well be" => 14,
+    "well for" => 38,
+    "well if" => 20,
+    "well in" => 26,
+    "well it" => 17,
+    "well not" => 4,
+    "well of" => 4,
+    "well on" => 12,
+    "well or" => 3,
+    "well that" => 15,
+    "well the" => 16,
+    "well this" => 4,
+    "well to" => 33,
+    "well when" => 23,
+    "well with" => 53,
+    "well you" => 24,
+    "went to" => 70,
+    "were a" => 41,
+    "were an" => 4,
+    "were and" => 5,
+    "were as" => 11,
+    "were by" => 3,
+    "were for" => 4,
+    "were in" => 55,
+    "were it" => 7,
+    "were not" => 144,
+    "were of" => 17,
+    "were on" => 18,
+    "were the" => 48,
+    "were to" => 66,
+    "were with" => 6,
+    "were you" => 10,
+    "what a" => 108,
+    "what an" => 9,
+    "what are" => 42,
+    "what can" => 40,
+    "what files" => 7,
+    "what for" => 8,
+    "what i" => 128,
+    "what if" => 45,
+    "what in" => 3,
+    "what is" => 409,
+    "what it" => 151,
+    "what not" => 3,
+    "what of" => 11,
+    "what on" => 8,
+    "what patch" => 4,
+    "what problem" => 6,
+    "what that" => 19,
+    "what the" => 291,
+    "what this" => 47,
+    "what to" => 100,
+    "what use" => 5,
+    "what vim" => 12,
+    "what will" => 32,
+    "what with" => 3,
+    "what you" => 288,
+    "when a" => 1011,
+    "when about" => 3,
+    "when after" => 4,
+    "when all" => 59,
+    "when an" => 237,
+    "when and" => 34,
+    "when another" => 11,
+    "when any" => 18,
+    "when at" => 17,
+    "when available" => 26,
+    "when both" => 35,
+    "when buffer" => 9,
+    "when build" => 13,
+    "when by" => 4,
+    "when called" => 74,
+    "when cargo" => 13,
+    "when case" => 3,
+    "when code" => 8,
+    "when command" => 4,
+    "when corresponding" => 15,
+    "when current" => 6,
+    "when cursor" => 10,
+    "when data" => 16,
+    "when default" => 4,
+    "when different" => 3,
+    "when directory" => 5,
+    "when do" => 6,
+    "when does" => 3,
+    "when each" => 4,
+    "when either" => 43,
+    "when end" => 4,
+    "when ex" => 3,
+    "when feature" => 13,
+    "when features" => 4,
+    "when file" => 11,
+    "when files" => 5,
+    "when first" => 6,
+    "when following" => 3,
+    "when for" => 7,
+    "when function" => 10,
+    "when functions" => 5,
+    "when has" => 10,
+    "when he" => 200,
+    "when his" => 12,
+    "when i" => 323,
+    "when if" => 13,
+    "when in" => 101,
+    "when is" => 307,
+    "when it" => 498,
+    "when it's" => 121,
+    "when its" => 16,
+    "when just" => 3,
+    "when key" => 7,
+    "when last" => 7,
+    "when line" => 6,
+    "when list" => 16,
+    "when make" => 3,
+    "when memory" => 5,
+    "when method" => 3,
+    "when more" => 16,
+    "when my" => 25,
+    "when new" => 12,
+    "when no" => 143,
+    "when non" => 6,
+    "when not" => 169,
+    "when number" => 22,
+    "when of" => 3,
+    "when on" => 16,
+    "when one" => 55,
+    "when only" => 22,
+    "when option" => 3,
+    "when or" => 23,
+    "when other" => 8,
+    "when out" => 23,
+    "when path" => 5,
+    "when possible" => 84,
+    "when return" => 4,
+    "when returns" => 8,
+    "when run" => 39,
+    "when rust" => 16,
+    "when set" => 49,
+    "when she" => 93,
+    "when should" => 12,
+    "when so" => 7,
+    "when some" => 24,
+    "when specified" => 3,
+    "when start" => 3,
+    "when still" => 4,
+    "when string" => 3,
+    "when such" => 12,
+    "when syntax" => 14,
+    "when system" => 3,
+    "when target" => 3,
+    "when terminal" => 14,
+    "when test" => 5,
+    "when tests" => 4,
+    "when text" => 26,
+    "when that" => 31,
+    "when the" => 3633,
+    "when their" => 8,
+    "when there" => 376,
+    "when these" => 12,
+    "when they" => 180,
+    "when this" => 133,
+    "when time" => 3,
+    "when to" => 65,
+    "when two" => 19,
+    "when type" => 8,
+    "when use" => 5,
+    "when used" => 178,
+    "when using" => 1858,
+    "when values" => 3,
+    "when variable" => 6,
+    "when vim" => 137,
+    "when was" => 6,
+    "when we" => 299,
+    "when what" => 3,
+    "when which" => 3,
+    "when will" => 5,
+    "when window" => 6,
+    "when with" => 5,
+    "when would" => 12,
+    "when you" => 883,
+    "when your" => 48,
+    "whenever the" => 49,
+    "where a" => 202,
+    "where an" => 37,
+    "where and" => 20,
+    "where are" => 27,
+    "where as" => 3,
+    "where at" => 3,
+    "where by" => 3,
+    "where can" => 20,
+    "where file" => 3,
+    "where i" => 45,
+    "where if" => 7,
+    "where in" => 16,
+    "where is" => 268,
+    "where it" => 224,
+    "where on" => 3,
+    "where rust" => 4,
+    "where that" => 14,
+    "where the" => 789,
+    "where this" => 59,
+    "where to" => 99,
+    "where vim" => 22,
+    "where you" => 208,
+    "whether a" => 120,
+    "whether and" => 4,
+    "whether for" => 3,
+    "whether in" => 20,
+    "whether is" => 18,
+    "whether the" => 357,
+    "whether this" => 30,
+    "whether to" => 79,
+    "which a" => 59,
+    "which add" => 4,
+    "which all" => 21,
+    "which also" => 49,
+    "which an" => 7,
+    "which any" => 3,
+    "which are" => 450,
+    "which as" => 16,
+    "which at" => 15,
+    "which by" => 9,
+    "which can" => 983,
+    "which command" => 5,
+    "which crate" => 7,
+    "which data" => 9,
+    "which do" => 35,
+    "which does" => 80,
+    "which each" => 8,
+    "which elements" => 5,
+    "which feature" => 3,
+    "which features" => 44,
+    "which file" => 4,
+    "which files" => 26,
+    "which for" => 5,
+    "which from" => 3,
+    "which has" => 166,
+    "which have" => 79,
+    "which he" => 163,
+    "which his" => 6,
+    "which i" => 283,
+    "which if" => 11,
+    "which in" => 71,
+    "which is" => 1551,
+    "which it" => 111,
+    "which its" => 3,
+    "which like" => 5,
+    "which make" => 10,
+    "which may" => 218,
+    "which memory" => 4,
+    "which must" => 68,
+    "which no" => 19,
+    "which number" => 3,
+    "which of" => 26,
+    "which on" => 5,
+    "which one" => 58,
+    "which only" => 34,
+    "which other" => 3,
+    "which returns" => 56,
+    "which rust" => 6,
+    "which should" => 61,
+    "which so" => 4,
+    "which some" => 4,
+    "which support" => 8,
+    "which that" => 10,
+    "which the" => 370,
+    "which then" => 5,
+    "which there" => 6,
+    "which these" => 7,
+    "which they" => 83,
+    "which this" => 57,
+    "which to" => 83,
+    "which two" => 5,
+    "which type" => 16,
+    "which types" => 7,
+    "which use" => 30,
+    "which used" => 3,
+    "which value" => 3,
+    "which version" => 19,
+    "which vim" => 11,
+    "which was" => 233,
+    "which we" => 118,
+    "which when" => 21,
+    "which will" => 415,
+    "which with" => 3,
+    "which work" => 4,
+    "which would" => 132,
+    "which you" => 184,
+    "which your" => 9,
+    "while a" => 56,
+    "while an" => 9,
+    "while and" => 21,
+    "while can" => 4,
+    "while for" => 12,
+    "while in" => 57,
+    "while is" => 34,
+    "while it" => 88,
+    "while not" => 23,
+    "while on" => 10,
+    "while or" => 3,
+    "while that" => 8,
+    "while the" => 336,
+    "while this" => 90,
+    "while to" => 15,
+    "while with" => 5,
+    "while you" => 31,
+    "whitespace the" => 3,
+    "who in" => 9,
+    "who is" => 69,
+    "who the" => 4,
+    "who this" => 4,
+    "whole is" => 3,
+    "whole of" => 9,
+    "whom the" => 23,
+    "why a" => 16,
+    "why and" => 5,
+    "why in" => 4,
+    "why is" => 34,
+    "why of" => 3,
+    "why the" => 65,
+    "width the" => 3,
+    "width to" => 13,
+    "will add" => 47,
+    "will all" => 10,
+    "will also" => 279,
+    "will and" => 3,
+    "will at" => 5,
+    "will be" => 4435,
+    "will buffer" => 3,
+    "will build" => 47,
+    "will but" => 4,
+    "will by" => 17,
+    "will call" => 54,
+    "will change" => 51,
+    "will check" => 31,
+    "will default" => 24,
+    "will do" => 88,
+    "will error" => 12,
+    "will first" => 25,
+    "will for" => 8,
+    "will function" => 3,
+    "will get" => 168,
+    "will have" => 332,
+    "will he" => 3,
+    "will i" => 11,
+    "will if" => 10,
+    "will in" => 8,
+    "will instead" => 13,
+    "will it" => 11,
+    "will list" => 7,
+    "will make" => 132,
+    "will need" => 253,
+    "will no" => 44,
+    "will not" => 1055,
+    "will now" => 86,
+    "will number" => 3,
+    "will of" => 5,
+    "will only" => 250,
+    "will point" => 6,
+    "will result" => 182,
+    "will return" => 445,
+    "will run" => 121,
+    "will see" => 82,
+    "will set" => 54,
+    "will store" => 13,
+    "will support" => 18,
+    "will test" => 7,
+    "will the" => 17,
+    "will then" => 117,
+    "will they" => 5,
+    "will this" => 4,
+    "will to" => 4,
+    "will use" => 281,
+    "will want" => 36,
+    "will work" => 94,
+    "will you" => 53,
+    "win a" => 8,
+    "win and" => 32,
+    "win for" => 6,
+    "win the" => 7,
+    "win to" => 7,
+    "win with" => 13,
+    "window a" => 14,
+    "window and" => 170,
+    "window are" => 15,
+    "window as" => 6,
+    "window by" => 8,
+    "window can" => 15,
+    "window for" => 70,
+    "window from" => 13,
+    "window if" => 16,
+    "window in" => 71,
+    "window is" => 182,
+    "window it" => 14,
+    "window not" => 19,
+    "window of" => 17,
+    "window on" => 30,
+    "window or" => 25,
+    "window that" => 34,
+    "window the" => 52,
+    "window to" => 91,
+    "window use" => 14,
+    "window using" => 5,
+    "window vim" => 7,
+    "window when" => 49,
+    "window will" => 45,
+    "window with" => 89,
+    "window you" => 9,
+    "windows a" => 18,
+    "windows an" => 3,
+    "windows and" => 153,
+    "windows are" => 32,
+    "windows as" => 14,
+    "windows by" => 3,
+    "windows can" => 13,
+    "windows files" => 4,
+    "windows for" => 14,
+    "windows from" => 5,
+    "windows i" => 5,
+    "windows if" => 12,
+    "windows in" => 30,
+    "windows is" => 26,
+    "windows it" => 20,
+    "windows not" => 11,
+    "windows of" => 19,
+    "windows on" => 32,
+    "windows or" => 36,
+    "windows that" => 6,
+    "windows the" => 54,
+    "windows this" => 30,
+    "windows to" => 15,
+    "windows use" => 8,
+    "windows using" => 14,
+    "windows vim" => 7,
+    "windows when" => 26,
+    "windows which" => 6,
+    "windows will" => 12,
+    "windows with" => 47,
+    "windows you" => 24,
+    "wish the" => 4,
+    "with a" => 3744,
+    "with about" => 4,
+    "with access" => 10,
+    "with added" => 7,
+    "with additional" => 37,
+    "with after" => 8,
+    "with all" => 222,
+    "with always" => 3,
+    "with an" => 937,
+    "with and" => 407,
+    "with another" => 78,
+    "with any" => 211,
+    "with apache" => 6,
+    "with are" => 33,
+    "with argument" => 9,
+    "with arguments" => 21,
+    "with as" => 63,
+    "with at" => 59,
+    "with attribute" => 10,
+    "with attributes" => 6,
+    "with back" => 4,
+    "with because" => 8,
+    "with before" => 3,
+    "with being" => 11,
+    "with binary" => 12,
+    "with bit" => 100,
+    "with bits" => 3,
+    "with block" => 3,
+    "with both" => 63,
+    "with buffer" => 9,
+    "with build" => 9,
+    "with but" => 34,
+    "with by" => 38,
+    "with byte" => 9,
+    "with bytes" => 30,
+    "with can" => 19,
+    "with cargo" => 135,
+    "with case" => 5,
+    "with changes" => 8,
+    "with channel" => 4,
+    "with character" => 6,
+    "with characters" => 10,
+    "with code" => 32,
+    "with command" => 15,
+    "with commands" => 9,
+    "with compile" => 4,
+    "with corresponding" => 31,
+    "with crate" => 27,
+    "with crates" => 10,
+    "with current" => 5,
+    "with currently" => 3,
+    "with cursor" => 3,
+    "with data" => 27,
+    "with default" => 53,
+    "with dependencies" => 9,
+    "with details" => 3,
+    "with different" => 133,
+    "with do" => 4,
+    "with doc" => 4,
+    "with documentation" => 6,
+    "with doesn't" => 3,
+    "with each" => 104,
+    "with either" => 56,
+    "with element" => 11,
+    "with elements" => 40,
+    "with empty" => 19,
+    "with enable" => 6,
+    "with enabled" => 14,
+    "with encoding" => 12,
+    "with environment" => 18,
+    "with error" => 26,
+    "with errors" => 8,
+    "with even" => 7,
+    "with event" => 12,
+    "with every" => 32,
+    "with ex" => 5,
+    "with examples" => 15,
+    "with exceptions" => 3,
+    "with expression" => 4,
+    "with feature" => 46,
+    "with features" => 10,
+    "with file" => 12,
+    "with files" => 19,
+    "with first" => 3,
+    "with flag" => 9,
+    "with following" => 5,
+    "with for" => 51,
+    "with format" => 7,
+    "with from" => 14,
+    "with function" => 18,
+    "with functions" => 16,
+    "with get" => 4,
+    "with given" => 6,
+    "with has" => 4,
+    "with have" => 4,
+    "with help" => 10,
+    "with her" => 95,
+    "with here" => 3,
+    "with him" => 80,
+    "with his" => 222,
+    "with how" => 12,
+    "with i" => 16,
+    "with if" => 31,
+    "with in" => 106,
+    "with include" => 5,
+    "with information" => 21,
+    "with input" => 5,
+    "with insert" => 4,
+    "with instead" => 20,
+    "with integer" => 6,
+    "with into" => 4,
+    "with is" => 55,
+    "with it" => 213,
+    "with it's" => 4,
+    "with items" => 6,
+    "with its" => 153,
+    "with just" => 30,
+    "with key" => 13,
+    "with language" => 6,
+    "with level" => 4,
+    "with library" => 4,
+    "with like" => 7,
+    "with line" => 35,
+    "with lines" => 5,
+    "with link" => 3,
+    "with list" => 4,
+    "with local" => 13,
+    "with long" => 23,
+    "with lower" => 3,
+    "with macro" => 10,
+    "with macros" => 9,
+    "with make" => 11,
+    "with many" => 48,
+    "with mask" => 3,
+    "with match" => 4,
+    "with may" => 4,
+    "with me" => 84,
+    "with means" => 3,
+    "with memory" => 9,
+    "with message" => 14,
+    "with method" => 3,
+    "with methods" => 50,
+    "with might" => 3,
+    "with minimum" => 5,
+    "with mode" => 5,
+    "with more" => 83,
+    "with most" => 18,
+    "with move" => 3,
+    "with multiple" => 89,
+    "with must" => 7,
+    "with my" => 72,
+    "with names" => 4,
+    "with new" => 54,
+    "with next" => 7,
+    "with no" => 278,
+    "with node" => 21,
+    "with non" => 56,
+    "with not" => 12,
+    "with now" => 3,
+    "with number" => 5,
+    "with object" => 11,
+    "with of" => 4,
+    "with on" => 43,
+    "with once" => 3,
+    "with one" => 157,
+    "with only" => 90,
+    "with option" => 3,
+    "with options" => 19,
+    "with or" => 227,
+    "with other" => 272,
+    "with our" => 45,
+    "with out" => 4,
+    "with output" => 5,
+    "with package" => 3,
+    "with patch" => 4,
+    "with path" => 12,
+    "with pattern" => 11,
+    "with performance" => 11,
+    "with pointer" => 9,
+    "with precision" => 4,
+    "with provided" => 3,
+    "with range" => 7,
+    "with read" => 6,
+    "with reference" => 5,
+    "with requires" => 4,
+    "with returns" => 13,
+    "with right" => 5,
+    "with running" => 6,
+    "with runtime" => 9,
+    "with rust" => 199,
+    "with safe" => 5,
+    "with same" => 8,
+    "with script" => 7,
+    "with search" => 5,
+    "with section" => 8,
+    "with see" => 16,
+    "with server" => 3,
+    "with set" => 28,
+    "with should" => 3,
+    "with since" => 5,
+    "with single" => 19,
+    "with so" => 27,
+    "with some" => 203,
+    "with source" => 7,
+    "with specific" => 32,
+    "with specified" => 4,
+    "with standard" => 16,
+    "with start" => 9,
+    "with status" => 8,
+    "with string" => 11,
+    "with such" => 59,
+    "with support" => 163,
+    "with syntax" => 46,
+    "with system" => 8,
+    "with target" => 3,
+    "with terminal" => 15,
+    "with test" => 8,
+    "with tests" => 7,
+    "with text" => 30,
+    "with that" => 127,
+    "with the" => 6453,
+    "with their" => 176,
+    "with them" => 77,
+    "with then" => 16,
+    "with these" => 103,
+    "with they" => 7,
+    "with this" => 516,
+    "with those" => 45,
+    "with thread" => 4,
+    "with time" => 6,
+    "with to" => 86,
+    "with trait" => 13,
+    "with traits" => 13,
+    "with two" => 119,
+    "with type" => 40,
+    "with types" => 29,
+    "with unix" => 10,
+    "with up" => 15,
+    "with usage" => 4,
+    "with use" => 13,
+    "with useful" => 6,
+    "with user" => 12,
+    "with uses" => 3,
+    "with using" => 32,
+    "with value" => 21,
+    "with values" => 20,
+    "with variable" => 7,
+    "with variables" => 6,
+    "with version" => 30,
+    "with versions" => 8,
+    "with very" => 26,
+    "with via" => 6,
+    "with vim" => 101,
+    "with we" => 13,
+    "with well" => 3,
+    "with what" => 29,
+    "with when" => 28,
+    "with where" => 9,
+    "with which" => 139,
+    "with while" => 5,
+    "with will" => 46,
+    "with win" => 5,
+    "with window" => 5,
+    "with windows" => 17,
+    "with with" => 18,
+    "with without" => 9,
+    "with would" => 3,
+    "with write" => 5,
+    "with you" => 110,
+    "with your" => 151,
+    "with zero" => 39,
+    "within a" => 363,
+    "within and" => 13,
+    "within it" => 12,
+    "within that" => 32,
+    "within the" => 711,
+    "within this" => 46,
+    "within to" => 6,
+    "without a" => 334,
+    "without all" => 3,
+    "without an" => 74,
+    "without and" => 9,
+    "without in" => 3,
+    "without is" => 3,
+    "without it" => 18,
+    "without or" => 6,
+    "without that" => 6,
+    "without the" => 480,
+    "without this" => 83,
+    "without type" => 7,
+    "without using" => 47,
+    "without which" => 3,
+    "without you" => 11,
+    "without your" => 3,
+    "word a" => 7,
+    "word and" => 35,
+    "word for" => 13,
+    "word in" => 38,
+    "word is" => 41,
+    "word of" => 23,
+    "word the" => 11,
+    "word to" => 31,
+    "words a" => 9,
+    "words and" => 37,
+    "words for" => 12,
+    "words in" => 38,
+    "words is" => 9,
+    "words of" => 22,
+    "words the" => 33,
+    "words to" => 23,
+    "work and" => 82,
+    "work are" => 6,
+    "work as" => 102,
+    "work at" => 26,
+    "work but" => 43,
+    "work by" => 768,
+    "work for" => 236,
+    "work from" => 17,
+    "work i" => 4,
+    "work if" => 48,
+    "work in" => 288,
+    "work is" => 67,
+    "work it" => 17,
+    "work of" => 44,
+    "work on" => 311,
+    "work or" => 7,
+    "work rust" => 3,
+    "work see" => 4,
+    "work that" => 22,
+    "work the" => 47,
+    "work to" => 71,
+    "work use" => 7,
+    "work was" => 10,
+    "work when" => 114,
+    "work which" => 7,
+    "work will" => 6,
+    "work with" => 420,
+    "work you" => 33,
+    "worker the" => 4,
+    "working and" => 3,
+    "working for" => 11,
+    "working in" => 23,
+    "working of" => 3,
+    "working the" => 3,
+    "working to" => 3,
+    "works and" => 17,
+    "works for" => 113,
+    "works in" => 107,
+    "works is" => 4,
+    "works of" => 7,
+    "works the" => 28,
+    "works this" => 3,
+    "works to" => 10,
+    "works with" => 155,
+    "workspace the" => 3,
+    "world a" => 4,
+    "world the" => 3,
+    "world to" => 11,
+    "would a" => 4,
+    "would be" => 899,
+    "would for" => 5,
+    "would i" => 17,
+    "would if" => 9,
+    "would in" => 10,
+    "would it" => 13,
+    "would not" => 232,
+    "would on" => 3,
+    "would the" => 8,
+    "would to" => 3,
+    "would use" => 48,
+    "would with" => 10,
+    "would you" => 39,
+    "wrap the" => 43,
+    "wrapped the" => 3,
+    "wrapper to" => 10,
+    "wrapping the" => 25,
+    "write a" => 180,
+    "write and" => 47,
+    "write are" => 7,
+    "write as" => 5,
+    "write for" => 10,
+    "write if" => 5,
+    "write in" => 21,
+    "write is" => 10,
+    "write it" => 43,
+    "write of" => 6,
+    "write on" => 5,
+    "write or" => 12,
+    "write that" => 9,
+    "write the" => 212,
+    "write this" => 28,
+    "write to" => 154,
+    "write with" => 6,
+    "writes the" => 34,
+    "writing a" => 166,
+    "writing and" => 28,
+    "writing in" => 9,
+    "writing is" => 14,
+    "writing of" => 14,
+    "writing the" => 99,
+    "writing this" => 23,
+    "writing to" => 88,
+    "written a" => 11,
+    "written and" => 30,
+    "written for" => 26,
+    "written in" => 293,
+    "written the" => 15,
+    "written this" => 5,
+    "written to" => 208,
+    "wrong and" => 15,
+    "wrong for" => 18,
+    "wrong in" => 27,
+    "wrong the" => 3,
+    "year the" => 3,
+    "years the" => 4,
+    "yes and" => 12,
+    "yes the" => 4,
+    "yes to" => 3,
+    "yet a" => 12,
+    "yet and" => 21,
+    "yet in" => 12,
+    "yet the" => 16,
+    "yet to" => 15,
+    "you a" => 107,
+    "you about" => 14,
+    "you access" => 14,
+    "you add" => 44,
+    "you added" => 8,
+    "you all" => 20,
+    "you also" => 78,
+    "you always" => 21,
+    "you an" => 27,
+    "you and" => 83,
+    "you any" => 8,
+    "you are" => 1218,
+    "you as" => 997,
+    "you at" => 14,
+    "you back" => 22,
+    "you be" => 17,
+    "you because" => 3,
+    "you been" => 9,
+    "you before" => 5,
+    "you both" => 4,
+    "you build" => 29,
+    "you but" => 16,
+    "you by" => 16,
+    "you call" => 52,
+    "you called" => 4,
+    "you can" => 6200,
+    "you cannot" => 126,
+    "you change" => 28,
+    "you check" => 13,
+    "you compile" => 12,
+    "you copy" => 5,
+    "you could" => 247,
+    "you create" => 47,
+    "you currently" => 4,
+    "you defined" => 6,
+    "you do" => 367,
+    "you don't" => 624,
+    "you done" => 4,
+    "you down" => 6,
+    "you either" => 12,
+    "you enabled" => 5,
+    "you end" => 17,
+    "you explicitly" => 1130,
+    "you find" => 85,
+    "you first" => 28,
+    "you for" => 48,
+    "you found" => 17,
+    "you from" => 47,
+    "you get" => 222,
+    "you had" => 80,
+    "you have" => 1405,
+    "you he" => 10,
+    "you here" => 5,
+    "you how" => 33,
+    "you however" => 3,
+    "you i" => 18,
+    "you if" => 31,
+    "you implement" => 8,
+    "you in" => 58,
+    "you instead" => 5,
+    "you into" => 9,
+    "you is" => 6,
+    "you it" => 10,
+    "you just" => 113,
+    "you last" => 10,
+    "you like" => 105,
+    "you link" => 7,
+    "you list" => 3,
+    "you make" => 87,
+    "you many" => 3,
+    "you may" => 790,
+    "you might" => 358,
+    "you more" => 10,
+    "you most" => 3,
+    "you must" => 474,
+    "you my" => 26,
+    "you name" => 4,
+    "you need" => 1059,
+    "you no" => 16,
+    "you not" => 45,
+    "you now" => 39,
+    "you of" => 9,
+    "you on" => 20,
+    "you one" => 5,
+    "you only" => 107,
+    "you or" => 27,
+    "you out" => 15,
+    "you package" => 4,
+    "you program" => 3,
+    "you read" => 26,
+    "you return" => 11,
+    "you right" => 3,
+    "you run" => 161,
+    "you said" => 43,
+    "you search" => 4,
+    "you see" => 202,
+    "you set" => 52,
+    "you shall" => 170,
+    "you she" => 6,
+    "you should" => 621,
+    "you so" => 22,
+    "you some" => 11,
+    "you specified" => 3,
+    "you start" => 56,
+    "you state" => 4,
+    "you still" => 38,
+    "you store" => 4,
+    "you support" => 4,
+    "you test" => 3,
+    "you that" => 67,
+    "you the" => 89,
+    "you then" => 30,
+    "you there" => 5,
+    "you this" => 7,
+    "you through" => 8,
+    "you to" => 803,
+    "you two" => 8,
+    "you type" => 109,
+    "you up" => 11,
+    "you use" => 346,
+    "you used" => 38,
+    "you very" => 6,
+    "you want" => 1710,
+    "you was" => 18,
+    "you well" => 3,
+    "you were" => 115,
+    "you what" => 16,
+    "you when" => 11,
+    "you where" => 9,
+    "you which" => 7,
+    "you will" => 805,
+    "you with" => 29,
+    "you work" => 19,
+    "you would" => 346,
+    "you write" => 84,
+    "you you" => 19,
+    "you your" => 9,
+    "you're a" => 28,
+    "you're in" => 15,
+    "you're the" => 5,
+    "you're to" => 4,
+    "your add" => 11,
+    "your and" => 16,
+    "your as" => 7,
+    "your build" => 41,
+    "your code" => 384,
+    "your command" => 14,
+    "your crate" => 230,
+    "your data" => 48,
+    "your default" => 14,
+    "your documentation" => 25,
+    "your error" => 12,
+    "your example" => 8,
+    "your examples" => 4,
+    "your feature" => 18,
+    "your file" => 189,
+    "your files" => 18,
+    "your for" => 3,
+    "your function" => 47,
+    "your is" => 11,
+    "your library" => 52,
+    "your like" => 10,
+    "your name" => 23,
+    "your new" => 33,
+    "your number" => 3,
+    "your only" => 5,
+    "your option" => 1138,
+    "your or" => 27,
+    "your other" => 6,
+    "your problem" => 4,
+    "your rust" => 128,
+    "your solution" => 10,
+    "your string" => 4,
+    "your support" => 10,
+    "your test" => 31,
+    "your time" => 12,
+    "your to" => 18,
+    "your type" => 45,
+    "your types" => 24,
+    "your use" => 48,
+    "your version" => 9,
+    "your vim" => 20,
+    "your will" => 3,
+    "your with" => 10,
+    "your work" => 62,
+    "your you" => 8,
+    "zero a" => 6,
+    "zero and" => 41,
+    "zero as" => 8,
+    "zero for" => 12,
+    "zero in" => 15,
+    "zero is" => 21,
+    "zero it" => 5,
+    "zero of" => 6,
+    "zero the" => 43,
+    "zero this" => 5,
+    "zero to" => 23,
+    "zero with" => 4,
+    "zeroed in" => 8,
+};
@@ -1,5 +1,8 @@
 use encoding_rs::{UTF_16BE, UTF_16LE, UTF_8};
-use std::collections::HashSet;
+use gibberish_or_not::{lookup_word, HunspellDictionary};
+use regex::Regex;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs::File;
 use std::io::{self, Write};
@@ -7,8 +10,79 @@ use std::path::Path;
 
 fn main() -> io::Result<()> {
     let args: Vec<String> = env::args().collect();
+    if args.len() == 4 && args[1] == "--count-words" {
+        return write_word_counts(Path::new(&args[2]), &args[3]);
+    }
+    if args.len() == 4 && args[1] == "--frequencies" {
+        return write_frequencies(Path::new(&args[2]), &args[3]);
+    }
+    if args.len() == 4 && args[1] == "--count-bigrams" {
+        return write_pair_counts(Path::new(&args[2]), &args[3]);
+    }
+    if args.len() == 4 && args[1] == "--bigrams" {
+        return write_bigrams(Path::new(&args[2]), &args[3]);
+    }
+    if args.len() == 5 && args[1] == "--word-classes" {
+        return write_word_classes(Path::new(&args[2]), Path::new(&args[3]), &args[4]);
+    }
+    if args.len() == 5 && args[1] == "--count-class-pairs" {
+        return write_class_pair_counts(Path::new(&args[2]), Path::new(&args[3]), &args[4]);
+    }
+    if args.len() == 5 && args[1] == "--classes" {
+        return write_classes(Path::new(&args[2]), Path::new(&args[3]), &args[4]);
+    }
+    if args.len() == 6 && args[1] == "--words" {
+        return write_language_words(Path::new(&args[2]), Path::new(&args[3]), &args[4], &args[5]);
+    }
+    if args.len() == 5 && args[1] == "--hunspell" {
+        let dictionary = HunspellDictionary::from_files(&args[2], &args[3])
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let seen_words: HashSet<String> = dictionary
+            .words()
+            .filter(|word| word.len() > 2 && !word.contains(char::is_whitespace))
+            .map(str::to_string)
+            .collect();
+        println!("Total unique words found: {}", seen_words.len());
+        return write_words(&seen_words, "ENGLISH_WORDS", &args[4]);
+    }
     if args.len() != 3 {
         eprintln!("Usage: {} <input-file-or-dir> <output-rs-file>", args[0]);
+        eprintln!(
+            "       {} --count-words <corpus-file-or-dir> <output-list>",
+            args[0]
+        );
+        eprintln!(
+            "       {} --frequencies <frequency-list> <output-rs-file>",
+            args[0]
+        );
+        eprintln!(
+            "       {} --count-bigrams <corpus-file-or-dir> <output-list>",
+            args[0]
+        );
+        eprintln!(
+            "       {} --bigrams <pair-counts> <output-rs-file>",
+            args[0]
+        );
+        eprintln!(
+            "       {} --word-classes <harper-dictionary> <harper-annotations> <output-list>",
+            args[0]
+        );
+        eprintln!(
+            "       {} --count-class-pairs <corpus-file-or-dir> <word-classes> <output-list>",
+            args[0]
+        );
+        eprintln!(
+            "       {} --classes <word-classes> <class-pair-counts> <output-rs-file>",
+            args[0]
+        );
+        eprintln!(
+            "       {} --words <vocabulary> <stop-words> <static-name> <output-rs-file>",
+            args[0]
+        );
+        eprintln!(
+            "       {} --hunspell <dic-file> <aff-file> <output-rs-file>",
+            args[0]
+        );
         std::process::exit(1);
     }
 
@@ -40,14 +114,25 @@ fn main() -> io::Result<()> {
     println!("Total unique words found: {}", seen_words.len());
 
     // Create output Rust file after processing all inputs
+    write_words(&seen_words, "ENGLISH_WORDS", output_path)
+}
+
+fn write_words(
+    seen_words: &HashSet<String>,
+    static_name: &str,
+    output_path: &str,
+) -> io::Result<()> {
     let mut output = File::create(output_path)?;
     writeln!(output, "use phf::phf_set;\n")?;
     writeln!(
         output,
-        "pub static ENGLISH_WORDS: phf::Set<&'static str> = phf_set! {{"
+        "pub static {}: phf::Set<&'static str> = phf_set! {{",
+        static_name
     )?;
 
-    for word in seen_words.iter() {
+    let mut words: Vec<&String> = seen_words.iter().collect();
+    words.sort();
+    for word in words {
         writeln!(output, "    \"{}\",", word)?;
     }
 
@@ -55,7 +140,706 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
-fn process_file(path: &Path, seen_words: &mut HashSet<String>) -> io::Result<()> {
+/// English Zipf value from which a vocabulary word is left out of a
+/// language pack, unless it is one of the language's stop words.
+///
+/// The vocabularies come from text that quotes English here and there, and
+/// words such as "the" or "house" in a French pack would make English text
+/// look French.
+const ENGLISH_ZIPF_CUTOFF: f64 = 4.0;
+
+/// Generate the word set of a language pack from a vocabulary and a list of
+/// stop words.
+///
+/// Each line of either list holds one word, which is lowercased; lines
+/// starting with `#` are comments. Every stop word is kept, along with the
+/// vocabulary words that are not common English words. Unlike the English
+/// dictionary, short words are kept, since the packs rely on articles and
+/// prepositions such as "le" or "y".
+fn write_language_words(
+    vocabulary_path: &Path,
+    stop_words_path: &Path,
+    static_name: &str,
+    output_path: &str,
+) -> io::Result<()> {
+    let read_words = |path: &Path| -> io::Result<Vec<String>> {
+        Ok(read_text(path)?
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter(|word| !word.contains(char::is_whitespace))
+            .map(str::to_lowercase)
+            .collect())
+    };
+    let is_common_english = |word: &str| {
+        SHORT_WORDS.contains(&word)
+            || lookup_word(word)
+                .and_then(|info| info.zipf)
+                .is_some_and(|zipf| zipf >= ENGLISH_ZIPF_CUTOFF)
+    };
+
+    let mut seen_words: HashSet<String> = read_words(stop_words_path)?.into_iter().collect();
+    let vocabulary = read_words(vocabulary_path)?;
+    let vocabulary_size = vocabulary.len();
+    let mut left_out = 0;
+    for word in vocabulary {
+        if is_common_english(&word) && !seen_words.contains(&word) {
+            left_out += 1;
+        } else {
+            seen_words.insert(word);
+        }
+    }
+
+    println!(
+        "Total unique words found: {} ({} of {} vocabulary words left out as English)",
+        seen_words.len(),
+        left_out,
+        vocabulary_size
+    );
+    write_words(&seen_words, static_name, output_path)
+}
+
+/// English words of one or two letters. The dictionary leaves these out, so
+/// they are the only words kept in the frequency table without being in it.
+const SHORT_WORDS: &[&str] = &[
+    "a", "i", "am", "an", "as", "at", "be", "by", "do", "go", "he", "hi", "if", "in", "is", "it",
+    "me", "my", "no", "of", "oh", "ok", "on", "or", "ox", "so", "to", "up", "us", "we",
+];
+
+/// Zipf value of the most frequent word when only ranks are known.
+///
+/// Under Zipf's law the word at rank r has a frequency proportional to 1/r,
+/// and "the" makes up roughly 6% of English text, i.e. about 10^7.8 per
+/// billion words.
+const TOP_RANK_ZIPF: f64 = 7.8;
+
+/// Generate the Zipf frequency table from a frequency list.
+///
+/// Each line holds a word, optionally followed by its corpus count. With
+/// counts the Zipf value is log10 of the frequency per billion words; without
+/// them the list must be ordered most frequent first and the value is
+/// estimated from the rank. Lines starting with `#` are comments.
+///
+/// Every counted word goes towards the corpus total, but only dictionary words
+/// and [`SHORT_WORDS`] make it into the table.
+fn write_frequencies(input_path: &Path, output_path: &str) -> io::Result<()> {
+    let text = read_text(input_path)?;
+
+    let mut entries: Vec<(String, Option<f64>)> = Vec::new();
+    let mut seen_words = HashSet::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let word = fields.next().unwrap().to_lowercase();
+        let count = fields.next().and_then(|count| count.parse::<f64>().ok());
+        // Keep the first, i.e. most frequent, occurrence of a word
+        if seen_words.insert(word.clone()) {
+            entries.push((word, count));
+        }
+    }
+
+    let total: f64 = entries.iter().filter_map(|(_, count)| *count).sum();
+    let mut zipf_values: Vec<(String, u8)> = Vec::new();
+    for (rank, (word, count)) in entries.into_iter().enumerate() {
+        if lookup_word(&word).is_none() && !SHORT_WORDS.contains(&word.as_str()) {
+            continue;
+        }
+        let zipf = match count {
+            Some(count) if total > 0.0 => (count / total * 1e9).log10(),
+            _ => TOP_RANK_ZIPF - ((rank + 1) as f64).log10(),
+        };
+        // Stored in tenths so the table can use integers
+        zipf_values.push((word, (zipf * 10.0).round().clamp(0.0, 255.0) as u8));
+    }
+    zipf_values.sort();
+
+    println!("Total words with frequencies: {}", zipf_values.len());
+
+    let mut output = File::create(output_path)?;
+    writeln!(output, "use phf::phf_map;\n")?;
+    writeln!(
+        output,
+        "/// Zipf frequency of words in tenths, e.g. {} for \"the\"",
+        zipf_values
+            .iter()
+            .find(|(word, _)| word == "the")
+            .map_or(0, |&(_, zipf)| zipf)
+    )?;
+    writeln!(
+        output,
+        "pub static WORD_ZIPF: phf::Map<&'static str, u8> = phf_map! {{"
+    )?;
+
+    for (word, zipf) in zipf_values.iter() {
+        writeln!(output, "    \"{}\" => {},", word, zipf)?;
+    }
+
+    writeln!(output, "}};")?;
+    Ok(())
+}
+
+/// Lowercased words of a line of corpus text: runs of letters and apostrophes,
+/// with curly apostrophes straightened and quotes around a word dropped
+fn corpus_words(line: &str) -> impl Iterator<Item = String> + '_ {
+    line.split(|c: char| !c.is_alphabetic() && !matches!(c, '\'' | '\u{2019}'))
+        .map(|word| word.replace('\u{2019}', "'"))
+        .map(|word| word.trim_matches('\'').to_lowercase())
+        .filter(|word| !word.is_empty())
+}
+
+/// Read every file of a corpus, in name order, or a single file
+fn read_corpus(path: &Path) -> io::Result<Vec<(String, String)>> {
+    let mut paths = if path.is_dir() {
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(path)? {
+            let path = entry?.path();
+            if path.is_file() && !path.file_name().unwrap().to_string_lossy().starts_with('.') {
+                paths.push(path);
+            }
+        }
+        paths
+    } else {
+        vec![path.to_path_buf()]
+    };
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            Ok((name, read_text(path)?))
+        })
+        .collect()
+}
+
+/// Count every word in a corpus of plain text, writing a frequency list of
+/// `word count` lines, most frequent first, for `--frequencies`
+fn write_word_counts(input_path: &Path, output_path: &str) -> io::Result<()> {
+    let corpus = read_corpus(input_path)?;
+
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    for (_, text) in &corpus {
+        for word in text.lines().flat_map(corpus_words) {
+            *counts.entry(word).or_insert(0) += 1;
+        }
+    }
+    let total: u64 = counts.values().sum();
+    let mut counts: Vec<(String, u64)> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    println!("Total words: {}, unique: {}", total, counts.len());
+
+    let mut output = File::create(output_path)?;
+    writeln!(
+        output,
+        "# Word counts over a corpus of {} words, generated by `dictionary --count-words`.",
+        total
+    )?;
+    writeln!(output, "# Sources:")?;
+    for (name, _) in &corpus {
+        writeln!(output, "#   {}", name)?;
+    }
+    for (word, count) in counts {
+        writeln!(output, "{} {}", word, count)?;
+    }
+    Ok(())
+}
+
+/// Sentences of a corpus text as lists of words. The texts are hard-wrapped,
+/// so the lines of a paragraph are joined first; sentences end at `.`, `!`,
+/// `?`, `;` or `:`.
+fn corpus_sentences(text: &str) -> Vec<Vec<String>> {
+    let mut sentences = Vec::new();
+    let mut paragraph = String::new();
+    for line in text.lines().chain(std::iter::once("")) {
+        if !line.trim().is_empty() {
+            paragraph.push_str(line);
+            paragraph.push(' ');
+            continue;
+        }
+        for sentence in paragraph.split(['.', '!', '?', ';', ':']) {
+            let words: Vec<String> = corpus_words(sentence).collect();
+            if !words.is_empty() {
+                sentences.push(words);
+            }
+        }
+        paragraph.clear();
+    }
+    sentences
+}
+
+/// Smallest number of times a pair is kept by `--count-bigrams` would be
+/// expected to occur if its two words were independent.
+///
+/// The library judges rarer pairs by the classes of their words instead, so
+/// the list can leave them out. It is half the library's own cut-off to allow
+/// for Zipf values being rounded.
+const MIN_EXPECTED_PAIRS: f64 = 10.0;
+
+/// Fewest times a pair is seen to be kept by `--count-bigrams`. Pairs seen
+/// less often all score close to a pair never seen.
+const MIN_PAIR_COUNT: u64 = 3;
+
+/// Count adjacent word pairs within the sentences of a corpus, writing
+/// `first second count` lines, most frequent first, for `--bigrams`.
+///
+/// Only pairs of words that make it into the frequency table are kept, and
+/// only those seen at least [`MIN_PAIR_COUNT`] times and common enough to be
+/// expected at least [`MIN_EXPECTED_PAIRS`] times by chance.
+fn write_pair_counts(input_path: &Path, output_path: &str) -> io::Result<()> {
+    let corpus = read_corpus(input_path)?;
+
+    let mut word_counts: HashMap<String, u64> = HashMap::new();
+    let mut pair_counts: HashMap<(String, String), u64> = HashMap::new();
+    for (_, text) in &corpus {
+        for words in corpus_sentences(text) {
+            for word in &words {
+                *word_counts.entry(word.clone()).or_insert(0) += 1;
+            }
+            for pair in words.windows(2) {
+                *pair_counts
+                    .entry((pair[0].clone(), pair[1].clone()))
+                    .or_insert(0) += 1;
+            }
+        }
+    }
+    let total_words: u64 = word_counts.values().sum();
+    let total_pairs: u64 = pair_counts.values().sum();
+
+    let in_table = |word: &str| lookup_word(word).is_some() || SHORT_WORDS.contains(&word);
+    let expected = |first: &str, second: &str| {
+        let probability = |word: &str| word_counts[word] as f64 / total_words as f64;
+        probability(first) * probability(second) * total_pairs as f64
+    };
+    let mut counts: Vec<((String, String), u64)> = pair_counts
+        .into_iter()
+        .filter(|(_, count)| *count >= MIN_PAIR_COUNT)
+        .filter(|((first, second), _)| in_table(first) && in_table(second))
+        .filter(|((first, second), _)| expected(first, second) >= MIN_EXPECTED_PAIRS)
+        .collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    println!("Total pairs: {}, kept: {}", total_pairs, counts.len());
+
+    let mut output = File::create(output_path)?;
+    writeln!(
+        output,
+        "# Word pair counts, generated by `dictionary --count-bigrams`."
+    )?;
+    writeln!(output, "# Total pairs: {}", total_pairs)?;
+    writeln!(output, "# Sources:")?;
+    for (name, _) in &corpus {
+        writeln!(output, "#   {}", name)?;
+    }
+    for ((first, second), count) in counts {
+        writeln!(output, "{} {} {}", first, second, count)?;
+    }
+    Ok(())
+}
+
+/// Generate the word-bigram table from a list of pair counts.
+///
+/// Each line holds two words and the number of times they were seen next to
+/// each other. A `# Total pairs: N` comment gives the number of pairs in the
+/// whole corpus, which the library needs to work out how often a pair would
+/// occur by chance. Other lines starting with `#` are comments.
+fn write_bigrams(input_path: &Path, output_path: &str) -> io::Result<()> {
+    let text = read_text(input_path)?;
+
+    let mut total_pairs = None;
+    let mut bigrams: Vec<(String, u32)> = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if let Some(total) = line.strip_prefix("# Total pairs:") {
+            total_pairs = total.trim().parse::<u64>().ok();
+            continue;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let count = match fields.as_slice() {
+            [_, _, count] => count.parse::<u32>().ok(),
+            _ => None,
+        };
+        let Some(count) = count else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("expected `first second count`, found {:?}", line),
+            ));
+        };
+        bigrams.push((
+            format!("{} {}", fields[0].to_lowercase(), fields[1].to_lowercase()),
+            count,
+        ));
+    }
+    let Some(total_pairs) = total_pairs else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing `# Total pairs:` line",
+        ));
+    };
+    bigrams.sort();
+
+    println!("Total bigrams: {}", bigrams.len());
+
+    let mut output = File::create(output_path)?;
+    writeln!(output, "use phf::phf_map;\n")?;
+    writeln!(
+        output,
+        "/// Number of adjacent word pairs in the corpus the counts were taken from"
+    )?;
+    writeln!(output, "pub const CORPUS_PAIRS: u64 = {};\n", total_pairs)?;
+    writeln!(
+        output,
+        "/// How often adjacent word pairs occur in the corpus, e.g. \"of the\""
+    )?;
+    writeln!(
+        output,
+        "pub static WORD_BIGRAMS: phf::Map<&'static str, u32> = phf_map! {{"
+    )?;
+
+    for (bigram, count) in bigrams.iter() {
+        writeln!(output, "    \"{}\" => {},", bigram, count)?;
+    }
+
+    writeln!(output, "}};")?;
+    Ok(())
+}
+
+/// Word classes of `--word-classes` lists, in the order of their bits in the
+/// generated table
+const CLASS_NAMES: [&str; 10] = [
+    "NOUN", "PROPN", "VERB", "AUX", "ADJ", "ADV", "PREP", "DET", "PRON", "CONJ",
+];
+
+/// Classes named by a metadata object of Harper's annotations, e.g.
+/// `{"noun": {"is_proper": true}}` for a proper noun
+fn harper_classes(metadata: &Value) -> HashSet<&'static str> {
+    let is_set = |value: &Value, key: &str| value[key].as_bool().unwrap_or(false);
+    let mut classes = HashSet::new();
+    for (key, value) in metadata.as_object().into_iter().flatten() {
+        classes.insert(match key.as_str() {
+            "noun" if is_set(value, "is_proper") => "PROPN",
+            "noun" => "NOUN",
+            "verb" if is_set(value, "is_auxiliary") || is_set(value, "is_linking") => "AUX",
+            "verb" => "VERB",
+            "adjective" => "ADJ",
+            "adverb" => "ADV",
+            "preposition" => "PREP",
+            "determiner" => "DET",
+            "pronoun" => "PRON",
+            "conjunction" => "CONJ",
+            _ => continue,
+        });
+    }
+    classes
+}
+
+/// List the word classes of the words in the frequency table, from the
+/// dictionary of the Harper grammar checker, writing `word CLASS...` lines for
+/// `--count-class-pairs` and `--classes`.
+///
+/// Each entry of `dictionary.dict` is a word with flags. A flag either names a
+/// property, which gives the word its classes, or an affix from
+/// `annotations.json`, which derives more words, each with the classes of the
+/// affix's targets or else those of the word.
+fn write_word_classes(
+    dictionary_path: &Path,
+    annotations_path: &Path,
+    output_path: &str,
+) -> io::Result<()> {
+    let invalid = |err: String| io::Error::new(io::ErrorKind::InvalidData, err);
+    let annotations: Value = serde_json::from_str(&read_text(annotations_path)?)
+        .map_err(|err| invalid(err.to_string()))?;
+
+    let mut conditions: HashMap<String, Regex> = HashMap::new();
+    let mut classes: HashMap<String, HashSet<&str>> = HashMap::new();
+    for line in read_text(dictionary_path)?.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() || line.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+        let (word, flags) = line.split_once('/').unwrap_or((line, ""));
+        let word = word.to_lowercase();
+        let flags: Vec<String> = flags
+            .split_whitespace()
+            .next()
+            .unwrap_or("")
+            .chars()
+            .map(String::from)
+            .collect();
+
+        let base: HashSet<&str> = flags
+            .iter()
+            .flat_map(|flag| harper_classes(&annotations["properties"][flag]["metadata"]))
+            .collect();
+        classes.entry(word.clone()).or_default().extend(&base);
+
+        for flag in &flags {
+            let affix = &annotations["affixes"][flag];
+            let Some(replacements) = affix["replacements"].as_array() else {
+                continue;
+            };
+            // A target that only applies to some classes of word is skipped
+            // for words of other classes
+            let mut derived = HashSet::new();
+            for target in affix["target"].as_array().into_iter().flatten() {
+                let if_base = harper_classes(&target["if_base"]);
+                if if_base.is_empty() || base.is_empty() || !if_base.is_disjoint(&base) {
+                    derived.extend(harper_classes(&target["metadata"]));
+                }
+            }
+            if derived.is_empty() {
+                derived = base.clone();
+            }
+
+            for replacement in replacements {
+                let field = |key: &str| replacement[key].as_str().unwrap_or("");
+                let derived_word = if affix["kind"] == "prefix" {
+                    format!("{}{}", field("add"), word)
+                } else {
+                    let condition = match conditions.get(field("condition")) {
+                        Some(condition) => condition,
+                        None => {
+                            let pattern = format!("(?:{})$", field("condition"));
+                            let condition =
+                                Regex::new(&pattern).map_err(|err| invalid(err.to_string()))?;
+                            conditions
+                                .entry(field("condition").to_string())
+                                .or_insert(condition)
+                        }
+                    };
+                    if !condition.is_match(&word) {
+                        continue;
+                    }
+                    let Some(stem) = word.strip_suffix(field("remove")) else {
+                        continue;
+                    };
+                    format!("{}{}", stem, field("add"))
+                };
+                classes.entry(derived_word).or_default().extend(&derived);
+            }
+        }
+    }
+
+    let in_table = |word: &str| {
+        lookup_word(word).is_some_and(|info| info.zipf.is_some()) || SHORT_WORDS.contains(&word)
+    };
+    let mut words: Vec<(String, HashSet<&str>)> = classes
+        .into_iter()
+        .filter(|(word, classes)| !classes.is_empty() && in_table(word))
+        .collect();
+    words.sort_by(|a, b| a.0.cmp(&b.0));
+
+    println!("Total words with classes: {}", words.len());
+
+    let mut output = File::create(output_path)?;
+    writeln!(
+        output,
+        "# Word classes from the Harper dictionary, generated by `dictionary --word-classes`."
+    )?;
+    for (word, classes) in words {
+        let names: Vec<&str> = CLASS_NAMES
+            .into_iter()
+            .filter(|name| classes.contains(name))
+            .collect();
+        writeln!(output, "{} {}", word, names.join(" "))?;
+    }
+    Ok(())
+}
+
+/// Read a `--word-classes` list into a map from each word to its classes, one
+/// bit per entry of [`CLASS_NAMES`]
+fn read_word_classes(path: &Path) -> io::Result<HashMap<String, u16>> {
+    let mut classes = HashMap::new();
+    for line in read_text(path)?.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let word = fields.next().unwrap_or_default();
+        let mut bits = 0u16;
+        for name in fields {
+            let Some(bit) = CLASS_NAMES.iter().position(|&known| known == name) else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unknown word class {:?} in {:?}", name, line),
+                ));
+            };
+            bits |= 1 << bit;
+        }
+        classes.insert(word.to_string(), bits);
+    }
+    Ok(classes)
+}
+
+/// Count word classes and adjacent pairs of word classes within the sentences
+/// of a corpus, writing `CLASS count` and `FIRST SECOND count` lines for
+/// `--classes`.
+///
+/// Only words with a single class are counted, so that a pair such as "the
+/// walk" says nothing about verbs following determiners.
+fn write_class_pair_counts(
+    input_path: &Path,
+    classes_path: &Path,
+    output_path: &str,
+) -> io::Result<()> {
+    let classes = read_word_classes(classes_path)?;
+    let corpus = read_corpus(input_path)?;
+
+    let single_class = |word: &String| {
+        classes
+            .get(word)
+            .filter(|bits| bits.count_ones() == 1)
+            .map(|bits| bits.trailing_zeros() as usize)
+    };
+    let mut class_counts = [0u64; CLASS_NAMES.len()];
+    let mut pair_counts = [[0u64; CLASS_NAMES.len()]; CLASS_NAMES.len()];
+    for (_, text) in &corpus {
+        for words in corpus_sentences(text) {
+            let sequence: Vec<Option<usize>> = words.iter().map(single_class).collect();
+            for &class in sequence.iter().flatten() {
+                class_counts[class] += 1;
+            }
+            for pair in sequence.windows(2) {
+                if let [Some(first), Some(second)] = *pair {
+                    pair_counts[first][second] += 1;
+                }
+            }
+        }
+    }
+
+    let mut output = File::create(output_path)?;
+    writeln!(
+        output,
+        "# Counts of words with a single class, generated by `dictionary --count-class-pairs`."
+    )?;
+    writeln!(output, "# Sources:")?;
+    for (name, _) in &corpus {
+        writeln!(output, "#   {}", name)?;
+    }
+    for (class, count) in CLASS_NAMES.iter().zip(class_counts) {
+        writeln!(output, "{} {}", class, count)?;
+    }
+    for (first, counts) in CLASS_NAMES.iter().zip(pair_counts) {
+        for (second, count) in CLASS_NAMES.iter().zip(counts) {
+            writeln!(output, "{} {} {}", first, second, count)?;
+        }
+    }
+    Ok(())
+}
+
+/// Generate the word-class table from a `--word-classes` list and the
+/// `--count-class-pairs` counts.
+///
+/// Each pair of classes gets the ratio of how often it was seen to how often
+/// it would be seen if the classes of adjacent words were independent.
+fn write_classes(classes_path: &Path, counts_path: &Path, output_path: &str) -> io::Result<()> {
+    let classes = read_word_classes(classes_path)?;
+
+    let class_index = |name: &str| {
+        CLASS_NAMES
+            .iter()
+            .position(|&known| known == name)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unknown word class {:?}", name),
+                )
+            })
+    };
+    let mut class_counts = [0u64; CLASS_NAMES.len()];
+    let mut pair_counts = [[0u64; CLASS_NAMES.len()]; CLASS_NAMES.len()];
+    for line in read_text(counts_path)?.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let count = fields.last().and_then(|count| count.parse::<u64>().ok());
+        match (fields.as_slice(), count) {
+            ([class, _], Some(count)) => class_counts[class_index(class)?] = count,
+            ([first, second, _], Some(count)) => {
+                pair_counts[class_index(first)?][class_index(second)?] = count
+            }
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "expected `CLASS count` or `FIRST SECOND count`, found {:?}",
+                        line
+                    ),
+                ))
+            }
+        }
+    }
+    let total_words: u64 = class_counts.iter().sum();
+    let total_pairs: u64 = pair_counts.iter().flatten().sum();
+    let ratio = |first: usize, second: usize| {
+        let probability = |class: usize| class_counts[class] as f64 / total_words as f64;
+        let expected = probability(first) * probability(second) * total_pairs as f64;
+        if expected > 0.0 {
+            pair_counts[first][second] as f64 / expected
+        } else {
+            1.0
+        }
+    };
+
+    let mut words: Vec<(&String, &u16)> = classes.iter().collect();
+    words.sort();
+
+    println!("Total words with classes: {}", words.len());
+
+    let mut output = File::create(output_path)?;
+    writeln!(output, "use phf::phf_map;\n")?;
+    writeln!(
+        output,
+        "/// Number of word classes: {}",
+        CLASS_NAMES.join(", ")
+    )?;
+    writeln!(
+        output,
+        "pub const CLASS_COUNT: usize = {};\n",
+        CLASS_NAMES.len()
+    )?;
+    writeln!(
+        output,
+        "/// Classes a word can belong to, one bit each, in the order listed for [`CLASS_COUNT`]"
+    )?;
+    writeln!(
+        output,
+        "pub static WORD_CLASSES: phf::Map<&'static str, u16> = phf_map! {{"
+    )?;
+    for (word, bits) in words {
+        writeln!(output, "    \"{}\" => {},", word, bits)?;
+    }
+    writeln!(output, "}};\n")?;
+    writeln!(
+        output,
+        "/// How often a word of one class is followed by a word of another in the\n\
+         /// corpus, relative to chance, indexed by the first class and then the second"
+    )?;
+    writeln!(
+        output,
+        "pub static CLASS_PAIR_RATIOS: [[f64; CLASS_COUNT]; CLASS_COUNT] = ["
+    )?;
+    for first in 0..CLASS_NAMES.len() {
+        let row: Vec<String> = (0..CLASS_NAMES.len())
+            .map(|second| format!("{:.4}", ratio(first, second)))
+            .collect();
+        writeln!(output, "    [{}],", row.join(", "))?;
+    }
+    writeln!(output, "];")?;
+    Ok(())
+}
+
+/// Read a word list, honouring a UTF-8 or UTF-16 byte order mark
+fn read_text(path: &Path) -> io::Result<String> {
     let bytes = std::fs::read(path)?;
 
     let (encoding, bom_length) = if bytes.starts_with(&[0xFF, 0xFE]) {
@@ -69,6 +853,11 @@ fn process_file(path: &Path, seen_words: &mut HashSet<String>) -> io::Result<()>
     };
 
     let (text, _, _) = encoding.decode(&bytes[bom_length..]);
+    Ok(text.into_owned())
+}
+
+fn process_file(path: &Path, seen_words: &mut HashSet<String>) -> io::Result<()> {
+    let text = read_text(path)?;
 
     for line in text.lines() {
         let word = line.trim();
@@ -1,14 +1,43 @@
-use gibberish_or_not::{is_gibberish, Sensitivity};
+use gibberish_or_not::{english_strings, is_gibberish, Sensitivity, StringEncoding};
 use std::env;
 
 fn print_usage(program: &str) {
     eprintln!("Usage: {} <text> [sensitivity]", program);
+    eprintln!("       {} --strings <file> [sensitivity]", program);
     eprintln!("  sensitivity: low (strict), medium, high (lenient, default)");
+    eprintln!("  --strings: print the English runs of ASCII or UTF-16LE text in a binary file");
     std::process::exit(1);
 }
 
+/// Print each English string in the file with its hex offset, like `strings -t x`
+fn print_english_strings(path: &str, sensitivity: Sensitivity) {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            eprintln!("Failed to read {}: {}", path, err);
+            std::process::exit(1);
+        }
+    };
+
+    for found in english_strings(&bytes).sensitivity(sensitivity) {
+        let encoding = match found.encoding {
+            StringEncoding::Ascii => "ascii",
+            StringEncoding::Utf16Le => "utf16le",
+        };
+        println!(
+            "{:>8x} {:<7} {:.2} {}",
+            found.offset, encoding, found.score, found.text
+        );
+    }
+}
+
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+
+    let strings_mode = args.get(1).is_some_and(|arg| arg == "--strings");
+    if strings_mode {
+        args.remove(1);
+    }
 
     if args.len() < 2 || args.len() > 3 {
         print_usage(&args[0]);
@@ -32,6 +61,11 @@ fn main() {
         Sensitivity::High
     };
 
+    if strings_mode {
+        print_english_strings(text, sensitivity);
+        return;
+    }
+
     if is_gibberish(text, sensitivity) {
         println!("This text appears to be gibberish");
     } else {
@@ -0,0 +1,140 @@
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8, WINDOWS_1252};
+
+/// Share of bytes in one position of each pair that must be zero for text
+/// without a BOM to be read as UTF-16
+const UTF16_NULL_RATIO: f64 = 0.4;
+
+/// How the encoding of raw bytes was worked out, see [`decode_bytes`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EncodingDetection {
+    /// A UTF-8, UTF-16LE or UTF-16BE byte order mark
+    Bom,
+    /// UTF-16 without a BOM, recognised by the zero high bytes of ASCII characters
+    NullBytes,
+    /// The bytes are valid UTF-8
+    ValidUtf8,
+    /// Nothing else matched, so the bytes were read as Latin-1
+    Fallback,
+}
+
+/// Text decoded from raw bytes, see [`decode_bytes`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoded {
+    /// The decoded text, without any BOM
+    pub text: String,
+    /// WHATWG name of the encoding used, e.g. "UTF-16LE". The Latin-1 fallback
+    /// is reported as "windows-1252", its superset.
+    pub encoding: &'static str,
+    /// How the encoding was chosen
+    pub detection: EncodingDetection,
+}
+
+/// UTF-16 byte order guessed from where the zero bytes are
+fn sniff_utf16(bytes: &[u8]) -> Option<&'static Encoding> {
+    let pairs = bytes.len() / 2;
+    if pairs == 0 {
+        return None;
+    }
+    let zeros_at = |offset: usize| {
+        bytes
+            .chunks_exact(2)
+            .filter(|pair| pair[offset] == 0)
+            .count() as f64
+            / pairs as f64
+    };
+    let (even, odd) = (zeros_at(0), zeros_at(1));
+
+    // ASCII in UTF-16LE is "a\0", in UTF-16BE "\0a"
+    if odd >= UTF16_NULL_RATIO && even < UTF16_NULL_RATIO / 4.0 {
+        Some(UTF_16LE)
+    } else if even >= UTF16_NULL_RATIO && odd < UTF16_NULL_RATIO / 4.0 {
+        Some(UTF_16BE)
+    } else {
+        None
+    }
+}
+
+/// Decode raw bytes of unknown encoding.
+///
+/// Checks for a byte order mark first, then for UTF-16 without one by the
+/// pattern of zero bytes, then for valid UTF-8, and falls back to Latin-1.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::{decode_bytes, EncodingDetection};
+///
+/// let utf16: Vec<u8> = "hello".encode_utf16().flat_map(u16::to_le_bytes).collect();
+/// let decoded = decode_bytes(&utf16);
+/// assert_eq!(decoded.text, "hello");
+/// assert_eq!(decoded.encoding, "UTF-16LE");
+/// assert_eq!(decoded.detection, EncodingDetection::NullBytes);
+///
+/// assert_eq!(decode_bytes(b"caf\xe9").text, "café");
+/// ```
+pub fn decode_bytes(bytes: &[u8]) -> Decoded {
+    let decode = |encoding: &'static Encoding, bytes: &[u8], detection| Decoded {
+        text: encoding.decode_without_bom_handling(bytes).0.into_owned(),
+        encoding: encoding.name(),
+        detection,
+    };
+
+    if let Some((encoding, bom_length)) = Encoding::for_bom(bytes) {
+        return decode(encoding, &bytes[bom_length..], EncodingDetection::Bom);
+    }
+    if let Some(encoding) = sniff_utf16(bytes) {
+        return decode(encoding, bytes, EncodingDetection::NullBytes);
+    }
+    if let Some(text) = UTF_8.decode_without_bom_handling_and_without_replacement(bytes) {
+        return Decoded {
+            text: text.into_owned(),
+            encoding: UTF_8.name(),
+            detection: EncodingDetection::ValidUtf8,
+        };
+    }
+    decode(WINDOWS_1252, bytes, EncodingDetection::Fallback)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16be(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(u16::to_be_bytes).collect()
+    }
+
+    #[test]
+    fn test_boms() {
+        let decoded = decode_bytes(b"\xEF\xBB\xBFhello");
+        assert_eq!(decoded.text, "hello");
+        assert_eq!(decoded.encoding, "UTF-8");
+        assert_eq!(decoded.detection, EncodingDetection::Bom);
+
+        let mut bytes = vec![0xFE, 0xFF];
+        bytes.extend(utf16be("hello"));
+        let decoded = decode_bytes(&bytes);
+        assert_eq!(decoded.text, "hello");
+        assert_eq!(decoded.encoding, "UTF-16BE");
+    }
+
+    #[test]
+    fn test_utf16_without_bom() {
+        let decoded = decode_bytes(&utf16be("the quick brown fox"));
+        assert_eq!(decoded.text, "the quick brown fox");
+        assert_eq!(decoded.detection, EncodingDetection::NullBytes);
+    }
+
+    #[test]
+    fn test_utf8_and_fallback() {
+        let decoded = decode_bytes("naïve café".as_bytes());
+        assert_eq!(decoded.text, "naïve café");
+        assert_eq!(decoded.detection, EncodingDetection::ValidUtf8);
+
+        let decoded = decode_bytes(b"na\xefve caf\xe9");
+        assert_eq!(decoded.text, "naïve café");
+        assert_eq!(decoded.encoding, "windows-1252");
+        assert_eq!(decoded.detection, EncodingDetection::Fallback);
+
+        assert_eq!(decode_bytes(b"").text, "");
+    }
+}
@@ -0,0 +1,154 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{english_score, is_gibberish, Score, Sensitivity};
+
+/// Shift every ASCII letter in the text forward by `shift` places, wrapping around
+/// the alphabet. Case is preserved and all other characters are left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::caesar_shift;
+///
+/// assert_eq!(caesar_shift("Uryyb, jbeyq!", 13), "Hello, world!");
+/// ```
+pub fn caesar_shift(text: &str, shift: u8) -> String {
+    let shift = shift % 26;
+    text.chars()
+        .map(|c| match c {
+            'a'..='z' => (b'a' + (c as u8 - b'a' + shift) % 26) as char,
+            'A'..='Z' => (b'A' + (c as u8 - b'A' + shift) % 26) as char,
+            _ => c,
+        })
+        .collect()
+}
+
+/// Apply the Atbash cipher, mapping `a` to `z`, `b` to `y` and so on.
+/// Case is preserved and all other characters are left untouched.
+pub fn atbash(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            'a'..='z' => (b'z' - (c as u8 - b'a')) as char,
+            'A'..='Z' => (b'Z' - (c as u8 - b'A')) as char,
+            _ => c,
+        })
+        .collect()
+}
+
+/// Reverse the text grapheme by grapheme, so combining marks stay attached
+fn reverse(text: &str) -> String {
+    text.graphemes(true).rev().collect()
+}
+
+/// Returns true if the text is confidently English.
+///
+/// Uses the strictest sensitivity so that a decoding only counts when the
+/// heuristics are sure about it.
+fn is_confidently_english(text: &str) -> bool {
+    !is_gibberish(text, Sensitivity::Low)
+}
+
+/// Score a decoded candidate, returning `None` unless it is confidently English.
+///
+/// Callers check once that the original text is not confidently English.
+fn score_candidate(candidate: &str) -> Option<Score> {
+    is_confidently_english(candidate).then(|| english_score(candidate))
+}
+
+/// Detects text that is gibberish as-is but English after a Caesar/ROT shift.
+///
+/// All 25 non-trivial shifts are scored with the English heuristics. The best
+/// shift is returned only if its output is confidently English and the original
+/// text is not. Apply the shift with [`caesar_shift`] to recover the plaintext.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::detect_caesar;
+///
+/// let (shift, _score) = detect_caesar("Gur dhvpx oebja sbk whzcf bire gur ynml qbt").unwrap();
+/// assert_eq!(shift, 13);
+///
+/// assert!(detect_caesar("The quick brown fox jumps over the lazy dog").is_none());
+/// ```
+pub fn detect_caesar(text: &str) -> Option<(u8, Score)> {
+    if is_confidently_english(text) {
+        return None;
+    }
+
+    (1..26)
+        .filter_map(|shift| {
+            let candidate = caesar_shift(text, shift);
+            score_candidate(&candidate).map(|score| (shift, score))
+        })
+        // Keep the earliest shift on ties so results are deterministic
+        .fold(None, |best: Option<(u8, Score)>, current| match best {
+            Some(best) if best.1 >= current.1 => Some(best),
+            _ => Some(current),
+        })
+}
+
+/// Detects text that is gibberish as-is but English after applying Atbash.
+///
+/// Returns the English score of the decoded text. Decode it with [`atbash`].
+pub fn detect_atbash(text: &str) -> Option<Score> {
+    if is_confidently_english(text) {
+        return None;
+    }
+    score_candidate(&atbash(text))
+}
+
+/// Detects text that is gibberish as-is but English when read backwards.
+///
+/// Returns the English score of the reversed text.
+pub fn detect_reversed(text: &str) -> Option<Score> {
+    if is_confidently_english(text) {
+        return None;
+    }
+    score_candidate(&reverse(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caesar_shift_round_trip() {
+        let text = "Hello, World! 123";
+        for shift in 0..26 {
+            let encoded = caesar_shift(text, shift);
+            assert_eq!(caesar_shift(&encoded, 26 - shift), text);
+        }
+    }
+
+    #[test]
+    fn test_detect_caesar_finds_shift() {
+        let (shift, score) = detect_caesar("Vszzc hvwg wg zcbu").unwrap();
+        assert_eq!(shift, 12);
+        assert!(score > 0.0);
+    }
+
+    #[test]
+    fn test_detect_caesar_rejects_english() {
+        assert!(detect_caesar("This is a simple English sentence.").is_none());
+    }
+
+    #[test]
+    fn test_detect_caesar_rejects_noise() {
+        assert!(detect_caesar("h2=ReOrS9DAnED8o").is_none());
+    }
+
+    #[test]
+    fn test_atbash() {
+        assert_eq!(atbash(atbash("Hello, World!").as_str()), "Hello, World!");
+        let encoded = atbash("The quick brown fox jumps over the lazy dog");
+        assert!(detect_atbash(&encoded).is_some());
+        assert!(detect_atbash("The quick brown fox jumps over the lazy dog").is_none());
+    }
+
+    #[test]
+    fn test_detect_reversed() {
+        assert!(detect_reversed("god yzal eht revo spmuj xof nworb kciuq ehT").is_some());
+        assert!(detect_reversed("The quick brown fox jumps over the lazy dog").is_none());
+    }
+}
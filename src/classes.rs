@@ -0,0 +1,24502 @@
+use phf::phf_map;
+
+/// Number of word classes: NOUN, PROPN, VERB, AUX, ADJ, ADV, PREP, DET, PRON, CONJ
+pub const CLASS_COUNT: usize = 10;
+
+/// Classes a word can belong to, one bit each, in the order listed for [`CLASS_COUNT`]
+pub static WORD_CLASSES: phf::Map<&'static str, u16> = phf_map! {
+    "a" => 192,
+    "aaa" => 3,
+    "aaron" => 3,
+    "aba" => 3,
+    "abacus" => 1,
+    "abandon" => 5,
+    "abandoned" => 20,
+    "abandoning" => 21,
+    "abandons" => 5,
+    "abash" => 4,
+    "abashed" => 20,
+    "abating" => 21,
+    "abbey" => 1,
+    "abbot" => 1,
+    "abbots" => 1,
+    "abbreviate" => 21,
+    "abbreviated" => 20,
+    "abbreviating" => 21,
+    "abbreviation" => 21,
+    "abbreviations" => 21,
+    "abc" => 19,
+    "abduction" => 1,
+    "abe" => 3,
+    "abel" => 2,
+    "aberdeen" => 3,
+    "aberration" => 1,
+    "abhor" => 4,
+    "abhorred" => 20,
+    "abhorrence" => 1,
+    "abhorrent" => 16,
+    "abide" => 4,
+    "abides" => 4,
+    "abiding" => 21,
+    "abilities" => 1,
+    "ability" => 1,
+    "abject" => 21,
+    "abjure" => 4,
+    "ablaze" => 16,
+    "able" => 21,
+    "ablutions" => 1,
+    "abnormal" => 17,
+    "abnormally" => 32,
+    "aboard" => 64,
+    "abode" => 5,
+    "abolished" => 20,
+    "abolishing" => 21,
+    "abominable" => 16,
+    "abominably" => 32,
+    "abomination" => 21,
+    "abort" => 5,
+    "aborted" => 20,
+    "aborting" => 21,
+    "abortion" => 1,
+    "abortive" => 21,
+    "aborts" => 5,
+    "abound" => 4,
+    "abounded" => 20,
+    "abounding" => 21,
+    "about" => 80,
+    "above" => 81,
+    "abraham" => 3,
+    "abreast" => 80,
+    "abridge" => 4,
+    "abridged" => 20,
+    "abridging" => 21,
+    "abroad" => 81,
+    "abrupt" => 21,
+    "abruptly" => 32,
+    "abseil" => 5,
+    "absence" => 1,
+    "absent" => 85,
+    "absented" => 20,
+    "absently" => 32,
+    "absolute" => 17,
+    "absolutely" => 32,
+    "absolution" => 17,
+    "absolved" => 20,
+    "absorb" => 4,
+    "absorbed" => 20,
+    "absorbing" => 21,
+    "absorbs" => 4,
+    "abstain" => 4,
+    "abstained" => 20,
+    "abstract" => 21,
+    "abstracted" => 20,
+    "abstractedly" => 32,
+    "abstracting" => 21,
+    "abstraction" => 1,
+    "abstractions" => 1,
+    "abstractly" => 32,
+    "abstracts" => 5,
+    "abstruse" => 16,
+    "absurd" => 17,
+    "absurdity" => 1,
+    "absurdly" => 32,
+    "abundance" => 1,
+    "abundant" => 16,
+    "abundantly" => 32,
+    "abuse" => 5,
+    "abused" => 20,
+    "abusing" => 21,
+    "abusive" => 21,
+    "abutted" => 4,
+    "abyss" => 1,
+    "acacias" => 1,
+    "academia" => 1,
+    "academic" => 17,
+    "academics" => 1,
+    "academy" => 1,
+    "acceded" => 20,
+    "accelerate" => 20,
+    "accelerated" => 20,
+    "accelerates" => 4,
+    "accelerating" => 21,
+    "acceleration" => 21,
+    "accelerations" => 20,
+    "accelerator" => 1,
+    "accelerators" => 1,
+    "accelerometer" => 1,
+    "accent" => 5,
+    "accented" => 20,
+    "accents" => 5,
+    "accentuated" => 20,
+    "accept" => 21,
+    "acceptability" => 1,
+    "acceptable" => 21,
+    "acceptably" => 32,
+    "acceptance" => 1,
+    "acceptances" => 1,
+    "acceptation" => 1,
+    "accepted" => 20,
+    "accepting" => 21,
+    "accepts" => 5,
+    "access" => 5,
+    "accessed" => 20,
+    "accesses" => 5,
+    "accessibility" => 1,
+    "accessible" => 16,
+    "accessing" => 21,
+    "accessory" => 17,
+    "accident" => 17,
+    "accidental" => 17,
+    "accidentally" => 32,
+    "accidents" => 1,
+    "accommodate" => 20,
+    "accommodated" => 20,
+    "accommodates" => 4,
+    "accommodating" => 21,
+    "accommodation" => 21,
+    "accompanied" => 20,
+    "accompanies" => 4,
+    "accompany" => 4,
+    "accompanying" => 21,
+    "accomplice" => 1,
+    "accomplish" => 4,
+    "accomplished" => 20,
+    "accomplishes" => 4,
+    "accomplishing" => 21,
+    "accomplishment" => 5,
+    "accomplishments" => 1,
+    "accord" => 5,
+    "accordance" => 1,
+    "accorded" => 20,
+    "according" => 21,
+    "accordingly" => 32,
+    "accosted" => 20,
+    "account" => 5,
+    "accountant" => 17,
+    "accounted" => 20,
+    "accounting" => 21,
+    "accounts" => 5,
+    "accoutred" => 20,
+    "accoutrements" => 1,
+    "accreditation" => 1,
+    "accredited" => 20,
+    "accumulate" => 20,
+    "accumulated" => 20,
+    "accumulates" => 4,
+    "accumulating" => 21,
+    "accumulation" => 21,
+    "accumulations" => 20,
+    "accumulative" => 20,
+    "accumulator" => 1,
+    "accuracy" => 1,
+    "accurate" => 16,
+    "accurately" => 32,
+    "accursed" => 20,
+    "accusation" => 1,
+    "accusations" => 1,
+    "accusative" => 17,
+    "accuse" => 5,
+    "accused" => 20,
+    "accuser" => 1,
+    "accuses" => 5,
+    "accusing" => 21,
+    "accusingly" => 32,
+    "accustomed" => 20,
+    "ace" => 21,
+    "aces" => 5,
+    "ache" => 5,
+    "ached" => 20,
+    "aches" => 5,
+    "achievable" => 20,
+    "achieve" => 4,
+    "achieved" => 20,
+    "achievement" => 5,
+    "achievements" => 1,
+    "achieves" => 4,
+    "achieving" => 21,
+    "aching" => 21,
+    "acid" => 17,
+    "acknowledge" => 4,
+    "acknowledged" => 20,
+    "acknowledgement" => 1,
+    "acknowledgements" => 1,
+    "acknowledges" => 4,
+    "acknowledging" => 21,
+    "acknowledgment" => 1,
+    "acknowledgments" => 1,
+    "acme" => 1,
+    "acorns" => 1,
+    "acoustic" => 17,
+    "acquaintance" => 1,
+    "acquaintances" => 1,
+    "acquainted" => 20,
+    "acquiesce" => 4,
+    "acquiesced" => 20,
+    "acquire" => 4,
+    "acquired" => 20,
+    "acquirement" => 5,
+    "acquires" => 4,
+    "acquiring" => 21,
+    "acquisition" => 1,
+    "acquisitions" => 1,
+    "acquit" => 4,
+    "acquittal" => 1,
+    "acquitted" => 20,
+    "acre" => 1,
+    "acres" => 1,
+    "acrobat" => 5,
+    "acrobatic" => 16,
+    "acrobatics" => 17,
+    "acrobats" => 5,
+    "acronym" => 5,
+    "acronyms" => 5,
+    "acropolis" => 3,
+    "across" => 65,
+    "acrylic" => 17,
+    "act" => 7,
+    "acted" => 20,
+    "acting" => 21,
+    "action" => 21,
+    "actionable" => 17,
+    "actions" => 5,
+    "activate" => 4,
+    "activated" => 20,
+    "activates" => 4,
+    "activating" => 21,
+    "activation" => 5,
+    "active" => 21,
+    "actively" => 32,
+    "activities" => 1,
+    "activity" => 1,
+    "actor" => 1,
+    "actors" => 1,
+    "actress" => 1,
+    "acts" => 7,
+    "actual" => 17,
+    "actuality" => 1,
+    "actually" => 32,
+    "actuated" => 20,
+    "actuators" => 1,
+    "acute" => 21,
+    "acuteness" => 21,
+    "acyclic" => 16,
+    "ada" => 2,
+    "adam" => 2,
+    "adam's" => 1,
+    "adams" => 3,
+    "adapt" => 20,
+    "adaptability" => 1,
+    "adaptable" => 20,
+    "adaptation" => 1,
+    "adaptations" => 1,
+    "adapted" => 20,
+    "adapter" => 17,
+    "adapters" => 20,
+    "adapting" => 21,
+    "adaption" => 1,
+    "adaptive" => 20,
+    "adaptor" => 1,
+    "adaptors" => 1,
+    "adapts" => 4,
+    "add" => 5,
+    "added" => 20,
+    "addenda" => 1,
+    "adder" => 1,
+    "addicted" => 20,
+    "adding" => 21,
+    "addison" => 2,
+    "addition" => 1,
+    "additional" => 17,
+    "additionally" => 32,
+    "additions" => 1,
+    "additive" => 17,
+    "address" => 5,
+    "addressable" => 16,
+    "addressed" => 20,
+    "addresses" => 5,
+    "addressing" => 21,
+    "adds" => 5,
+    "adduced" => 20,
+    "adelaide" => 2,
+    "aden" => 2,
+    "adept" => 17,
+    "adequate" => 20,
+    "adequately" => 32,
+    "adhere" => 4,
+    "adherence" => 1,
+    "adherent" => 17,
+    "adheres" => 4,
+    "adhering" => 21,
+    "adhesion" => 1,
+    "adhesive" => 17,
+    "adieu" => 1,
+    "adjacency" => 1,
+    "adjacent" => 81,
+    "adjacently" => 32,
+    "adjective" => 21,
+    "adjectives" => 5,
+    "adjoin" => 4,
+    "adjoined" => 20,
+    "adjoining" => 21,
+    "adjourn" => 4,
+    "adjournment" => 5,
+    "adjunct" => 21,
+    "adjuration" => 1,
+    "adjust" => 4,
+    "adjustable" => 17,
+    "adjusted" => 20,
+    "adjusting" => 21,
+    "adjustment" => 5,
+    "adjustments" => 1,
+    "adjusts" => 4,
+    "adler" => 2,
+    "admin" => 5,
+    "administer" => 4,
+    "administered" => 20,
+    "administrating" => 21,
+    "administration" => 5,
+    "administrative" => 20,
+    "administratively" => 32,
+    "administrator" => 1,
+    "administrators" => 1,
+    "admirable" => 4,
+    "admirably" => 32,
+    "admiralty" => 3,
+    "admiration" => 1,
+    "admire" => 4,
+    "admired" => 20,
+    "admirers" => 4,
+    "admiring" => 21,
+    "admiringly" => 32,
+    "admissibility" => 1,
+    "admission" => 1,
+    "admit" => 4,
+    "admits" => 4,
+    "admittance" => 1,
+    "admitted" => 4,
+    "admitting" => 5,
+    "ado" => 1,
+    "adobe" => 1,
+    "adopt" => 4,
+    "adopted" => 20,
+    "adopter" => 1,
+    "adopters" => 1,
+    "adopting" => 21,
+    "adoption" => 1,
+    "adopts" => 4,
+    "adorable" => 4,
+    "adoration" => 1,
+    "adore" => 4,
+    "adored" => 20,
+    "adorn" => 21,
+    "adorned" => 20,
+    "adorns" => 5,
+    "adrian" => 18,
+    "adriatic" => 18,
+    "adrift" => 16,
+    "adroit" => 16,
+    "adroitness" => 17,
+    "ads" => 1,
+    "adult" => 21,
+    "adults" => 5,
+    "adv" => 1,
+    "advance" => 21,
+    "advanced" => 20,
+    "advancement" => 21,
+    "advancements" => 1,
+    "advances" => 5,
+    "advancing" => 21,
+    "advantage" => 5,
+    "advantageous" => 16,
+    "advantages" => 5,
+    "advent" => 7,
+    "adventitious" => 16,
+    "adventure" => 5,
+    "adventurer" => 1,
+    "adventurers" => 5,
+    "adventures" => 5,
+    "adventurous" => 16,
+    "adverb" => 5,
+    "adverbs" => 5,
+    "adversarial" => 16,
+    "adversaries" => 1,
+    "adversary" => 1,
+    "adverse" => 16,
+    "adversely" => 32,
+    "adversity" => 1,
+    "advertise" => 4,
+    "advertised" => 20,
+    "advertisement" => 5,
+    "advertisements" => 1,
+    "advertises" => 4,
+    "advertising" => 21,
+    "advice" => 1,
+    "advisable" => 21,
+    "advise" => 5,
+    "advised" => 20,
+    "adviser" => 1,
+    "advises" => 5,
+    "advisory" => 17,
+    "advocate" => 5,
+    "advocated" => 20,
+    "advocates" => 5,
+    "aegis" => 1,
+    "aerial" => 17,
+    "aerospace" => 17,
+    "aesthetic" => 17,
+    "aesthetically" => 17,
+    "aesthetics" => 1,
+    "affability" => 1,
+    "affair" => 1,
+    "affairs" => 1,
+    "affect" => 5,
+    "affectation" => 1,
+    "affectations" => 1,
+    "affected" => 21,
+    "affecting" => 21,
+    "affection" => 5,
+    "affectionate" => 20,
+    "affectionately" => 32,
+    "affections" => 5,
+    "affects" => 5,
+    "affiliated" => 20,
+    "affiliates" => 5,
+    "affiliation" => 5,
+    "affiliations" => 1,
+    "affinities" => 1,
+    "affinity" => 1,
+    "affirm" => 4,
+    "affirmation" => 1,
+    "affirmative" => 17,
+    "affirmed" => 20,
+    "affix" => 5,
+    "affixed" => 20,
+    "affixes" => 5,
+    "afflict" => 4,
+    "afflicted" => 20,
+    "afflicting" => 21,
+    "affliction" => 1,
+    "affluence" => 1,
+    "afford" => 4,
+    "affordability" => 1,
+    "affordable" => 4,
+    "afforded" => 20,
+    "affording" => 21,
+    "affords" => 4,
+    "affront" => 5,
+    "afghan" => 19,
+    "afghanistan" => 2,
+    "afloat" => 80,
+    "afoot" => 16,
+    "aforementioned" => 17,
+    "afraid" => 16,
+    "africa" => 2,
+    "african" => 17,
+    "africans" => 1,
+    "afrikaans" => 19,
+    "afro" => 1,
+    "aft" => 19,
+    "after" => 64,
+    "afternoon" => 1,
+    "afternoons" => 1,
+    "afterward" => 32,
+    "afterwards" => 32,
+    "again" => 64,
+    "against" => 576,
+    "agatha" => 2,
+    "agave" => 1,
+    "age" => 5,
+    "aged" => 20,
+    "agencies" => 1,
+    "agency" => 1,
+    "agenda" => 1,
+    "agent" => 1,
+    "agents" => 1,
+    "ages" => 5,
+    "aggravate" => 4,
+    "aggravated" => 20,
+    "aggravation" => 5,
+    "aggregate" => 21,
+    "aggregated" => 20,
+    "aggregates" => 5,
+    "aggregating" => 21,
+    "aggregation" => 21,
+    "aggregations" => 21,
+    "aggressive" => 17,
+    "aggressively" => 32,
+    "agile" => 17,
+    "agility" => 1,
+    "agitated" => 20,
+    "agitates" => 4,
+    "agitation" => 5,
+    "agnostic" => 17,
+    "ago" => 80,
+    "agonies" => 1,
+    "agonised" => 20,
+    "agonising" => 21,
+    "agonizing" => 21,
+    "agonizingly" => 32,
+    "agony" => 1,
+    "agree" => 4,
+    "agreeable" => 4,
+    "agreed" => 20,
+    "agreeing" => 5,
+    "agreement" => 5,
+    "agreements" => 1,
+    "agrees" => 4,
+    "agricultural" => 17,
+    "agriculture" => 1,
+    "agronomy" => 1,
+    "aground" => 16,
+    "ahem" => 4,
+    "aid" => 5,
+    "aida" => 2,
+    "aide" => 1,
+    "aided" => 20,
+    "aides" => 1,
+    "aiding" => 21,
+    "aids" => 21,
+    "ailing" => 21,
+    "ailment" => 21,
+    "ails" => 5,
+    "aim" => 5,
+    "aimed" => 20,
+    "aiming" => 21,
+    "aimlessly" => 32,
+    "aims" => 5,
+    "ain't" => 4,
+    "air" => 5,
+    "aircraft" => 1,
+    "aired" => 20,
+    "airedale" => 3,
+    "airily" => 32,
+    "airplane" => 5,
+    "airplanes" => 5,
+    "airport" => 1,
+    "airs" => 5,
+    "airy" => 16,
+    "aisle" => 1,
+    "aisles" => 1,
+    "ajar" => 20,
+    "akimbo" => 16,
+    "akin" => 16,
+    "ala" => 3,
+    "alabama" => 3,
+    "alacrity" => 1,
+    "aladdin" => 2,
+    "alan" => 3,
+    "alarm" => 5,
+    "alarmed" => 20,
+    "alarming" => 21,
+    "alarms" => 5,
+    "alas" => 1,
+    "alb" => 1,
+    "albanian" => 19,
+    "albany" => 2,
+    "albatross" => 1,
+    "albeit" => 512,
+    "albert" => 3,
+    "alchemists" => 1,
+    "alcohol" => 1,
+    "alderman" => 1,
+    "ale" => 1,
+    "alec" => 2,
+    "alert" => 21,
+    "alerting" => 21,
+    "alertly" => 32,
+    "alerts" => 5,
+    "alex" => 2,
+    "alexander" => 3,
+    "alexandria" => 2,
+    "alexis" => 2,
+    "alfred" => 2,
+    "alga" => 1,
+    "algebra" => 1,
+    "algebraic" => 16,
+    "algorithm" => 1,
+    "algorithmic" => 16,
+    "algorithmically" => 16,
+    "algorithms" => 1,
+    "alias" => 5,
+    "aliases" => 5,
+    "alice" => 2,
+    "alicia" => 2,
+    "alien" => 21,
+    "alighted" => 20,
+    "alighting" => 21,
+    "align" => 4,
+    "aligned" => 20,
+    "aligning" => 21,
+    "alignment" => 5,
+    "alignments" => 1,
+    "aligns" => 4,
+    "alike" => 16,
+    "alive" => 1,
+    "all" => 913,
+    "allan" => 2,
+    "alleged" => 20,
+    "allegiance" => 1,
+    "alleging" => 21,
+    "allegro" => 17,
+    "allen" => 3,
+    "allergic" => 17,
+    "alleviate" => 4,
+    "alleviated" => 20,
+    "alleviates" => 4,
+    "alley" => 1,
+    "alleys" => 1,
+    "alliance" => 5,
+    "allied" => 20,
+    "allies" => 7,
+    "alligators" => 5,
+    "allison" => 2,
+    "alliterative" => 20,
+    "allocate" => 4,
+    "allocated" => 20,
+    "allocates" => 4,
+    "allocating" => 21,
+    "allocation" => 5,
+    "allocations" => 1,
+    "allocator" => 1,
+    "allotted" => 4,
+    "allow" => 4,
+    "allowable" => 17,
+    "allowance" => 5,
+    "allowances" => 5,
+    "allowed" => 20,
+    "allowing" => 21,
+    "allows" => 4,
+    "alloy" => 5,
+    "allude" => 4,
+    "alluded" => 20,
+    "allured" => 20,
+    "allurements" => 1,
+    "alluring" => 21,
+    "allusion" => 1,
+    "allusions" => 1,
+    "ally" => 5,
+    "almanac" => 1,
+    "almighty" => 18,
+    "almost" => 32,
+    "alms" => 1,
+    "alone" => 16,
+    "along" => 64,
+    "alongside" => 64,
+    "aloud" => 16,
+    "alpaca" => 1,
+    "alpha" => 17,
+    "alphabet" => 5,
+    "alphabetic" => 17,
+    "alphabetical" => 16,
+    "alphabetically" => 32,
+    "alphabetize" => 4,
+    "alphabets" => 5,
+    "alphanumeric" => 17,
+    "alphas" => 1,
+    "alpine" => 19,
+    "alps" => 3,
+    "alright" => 16,
+    "alt" => 17,
+    "altar" => 1,
+    "alter" => 5,
+    "alteration" => 1,
+    "alterations" => 1,
+    "altered" => 21,
+    "altering" => 21,
+    "alternate" => 21,
+    "alternately" => 32,
+    "alternates" => 5,
+    "alternating" => 21,
+    "alternation" => 21,
+    "alternative" => 21,
+    "alternatively" => 32,
+    "alternatives" => 1,
+    "alters" => 5,
+    "although" => 512,
+    "altitude" => 1,
+    "altogether" => 1,
+    "altos" => 1,
+    "altruistic" => 16,
+    "aluminum" => 1,
+    "alumni" => 1,
+    "alveolar" => 17,
+    "always" => 32,
+    "am" => 23,
+    "amalgam" => 5,
+    "amalgamation" => 21,
+    "amassed" => 20,
+    "amateur" => 17,
+    "amazed" => 20,
+    "amazement" => 5,
+    "amazing" => 21,
+    "amazingly" => 32,
+    "amazon" => 7,
+    "ambassadors" => 1,
+    "amber" => 23,
+    "ambience" => 1,
+    "ambient" => 17,
+    "ambiguities" => 1,
+    "ambiguity" => 1,
+    "ambiguous" => 16,
+    "ambiguously" => 32,
+    "ambition" => 5,
+    "ambitions" => 5,
+    "ambitious" => 16,
+    "ambulance" => 5,
+    "ambuscade" => 5,
+    "ambush" => 5,
+    "ameliorate" => 4,
+    "amen" => 23,
+    "amenable" => 5,
+    "amend" => 5,
+    "amended" => 20,
+    "amending" => 21,
+    "amendment" => 5,
+    "amendments" => 1,
+    "america" => 2,
+    "american" => 19,
+    "americans" => 1,
+    "amethyst" => 17,
+    "amharic" => 2,
+    "amiable" => 16,
+    "amicable" => 16,
+    "amid" => 65,
+    "amidships" => 5,
+    "amidst" => 64,
+    "amiss" => 17,
+    "amman" => 2,
+    "ammonia" => 1,
+    "ammunition" => 5,
+    "among" => 64,
+    "amongst" => 64,
+    "amorphous" => 16,
+    "amortised" => 20,
+    "amortization" => 1,
+    "amortize" => 4,
+    "amortized" => 20,
+    "amortizing" => 21,
+    "amos" => 2,
+    "amount" => 5,
+    "amounted" => 20,
+    "amounts" => 5,
+    "amour" => 1,
+    "amp" => 5,
+    "ampersand" => 5,
+    "ampersands" => 5,
+    "amphitheatre" => 1,
+    "ample" => 16,
+    "amplification" => 5,
+    "amplified" => 20,
+    "amplify" => 4,
+    "amplifying" => 21,
+    "amply" => 32,
+    "amputated" => 20,
+    "amsterdam" => 2,
+    "amuse" => 4,
+    "amused" => 20,
+    "amusement" => 5,
+    "amusements" => 1,
+    "amusing" => 21,
+    "amy" => 2,
+    "an" => 192,
+    "anagrams" => 5,
+    "analog" => 17,
+    "analogous" => 16,
+    "analogously" => 32,
+    "analogue" => 17,
+    "analogues" => 1,
+    "analogy" => 1,
+    "analyse" => 4,
+    "analyser" => 1,
+    "analyses" => 5,
+    "analysing" => 21,
+    "analysis" => 1,
+    "analyst" => 1,
+    "analysts" => 1,
+    "analytic" => 16,
+    "analytical" => 16,
+    "analytically" => 32,
+    "analyze" => 4,
+    "analyzed" => 20,
+    "analyzer" => 1,
+    "analyzers" => 1,
+    "analyzes" => 4,
+    "analyzing" => 21,
+    "anatomise" => 4,
+    "anatomy" => 1,
+    "ancestor" => 5,
+    "ancestors" => 5,
+    "ancestral" => 17,
+    "ancestry" => 1,
+    "anchor" => 5,
+    "anchored" => 20,
+    "anchors" => 5,
+    "ancient" => 17,
+    "ancillary" => 17,
+    "and" => 516,
+    "andersen" => 2,
+    "anderson" => 2,
+    "andes" => 2,
+    "andiron" => 1,
+    "andre" => 2,
+    "andrea" => 2,
+    "andrew" => 2,
+    "andrews" => 2,
+    "android" => 19,
+    "andy" => 3,
+    "anecdotal" => 16,
+    "anemic" => 17,
+    "angel" => 7,
+    "angelic" => 16,
+    "angelica" => 3,
+    "angels" => 5,
+    "anger" => 5,
+    "angle" => 5,
+    "angled" => 20,
+    "angles" => 5,
+    "anglia" => 2,
+    "anglicised" => 20,
+    "anglo" => 1,
+    "anglophones" => 1,
+    "angrily" => 32,
+    "angry" => 20,
+    "angstrom" => 1,
+    "anguish" => 5,
+    "anguished" => 20,
+    "angular" => 17,
+    "angus" => 2,
+    "animal" => 17,
+    "animals" => 1,
+    "animate" => 20,
+    "animated" => 20,
+    "animating" => 21,
+    "animation" => 21,
+    "animations" => 1,
+    "animator" => 1,
+    "animosity" => 1,
+    "ankle" => 5,
+    "ankles" => 5,
+    "ann" => 19,
+    "annals" => 1,
+    "anne" => 2,
+    "annealing" => 21,
+    "annex" => 5,
+    "annexed" => 20,
+    "annihilation" => 5,
+    "anniversary" => 1,
+    "annotate" => 4,
+    "annotated" => 20,
+    "annotates" => 4,
+    "annotating" => 21,
+    "annotation" => 5,
+    "annotations" => 5,
+    "announce" => 4,
+    "announced" => 20,
+    "announcement" => 5,
+    "announcements" => 1,
+    "announcing" => 21,
+    "annoy" => 5,
+    "annoyance" => 1,
+    "annoyances" => 1,
+    "annoyed" => 20,
+    "annoying" => 21,
+    "annual" => 17,
+    "annually" => 32,
+    "anoints" => 4,
+    "anomalies" => 1,
+    "anomaly" => 1,
+    "anon" => 17,
+    "anonymity" => 1,
+    "anonymous" => 16,
+    "anonymously" => 32,
+    "another" => 384,
+    "anselm" => 2,
+    "ansi" => 2,
+    "answer" => 5,
+    "answered" => 20,
+    "answering" => 21,
+    "answers" => 5,
+    "ant" => 5,
+    "antagonist" => 1,
+    "antagonists" => 1,
+    "antecedents" => 1,
+    "antedating" => 21,
+    "antelope" => 1,
+    "anterior" => 16,
+    "anteroom" => 1,
+    "anthony" => 2,
+    "anthropological" => 16,
+    "anti" => 81,
+    "anticipate" => 4,
+    "anticipated" => 20,
+    "anticipates" => 4,
+    "anticipating" => 21,
+    "anticipation" => 5,
+    "anticipations" => 4,
+    "antics" => 5,
+    "antidote" => 5,
+    "antipathies" => 1,
+    "antipathy" => 1,
+    "antipodes" => 3,
+    "antique" => 21,
+    "antiquity" => 1,
+    "antithesis" => 1,
+    "antoine" => 2,
+    "antoinette" => 2,
+    "anton" => 2,
+    "antonio" => 2,
+    "antony" => 2,
+    "ants" => 5,
+    "anvil" => 5,
+    "anxieties" => 1,
+    "anxiety" => 1,
+    "anxious" => 16,
+    "anxiously" => 32,
+    "any" => 416,
+    "anybody" => 256,
+    "anyhow" => 16,
+    "anyone" => 256,
+    "anything" => 261,
+    "anytime" => 16,
+    "anywhere" => 257,
+    "apache" => 3,
+    "apaches" => 1,
+    "apart" => 16,
+    "apartment" => 1,
+    "apartments" => 1,
+    "apathetically" => 16,
+    "apathy" => 1,
+    "ape" => 21,
+    "aperture" => 1,
+    "apex" => 1,
+    "apis" => 1,
+    "apollo" => 3,
+    "apologetically" => 16,
+    "apologies" => 1,
+    "apologise" => 4,
+    "apologize" => 4,
+    "apology" => 1,
+    "apoplectic" => 17,
+    "apostrophe" => 1,
+    "apostrophes" => 1,
+    "apothecary" => 1,
+    "appalled" => 20,
+    "appalling" => 21,
+    "apparatus" => 1,
+    "apparel" => 5,
+    "apparelled" => 4,
+    "apparent" => 16,
+    "apparently" => 32,
+    "apparition" => 1,
+    "appeal" => 5,
+    "appealed" => 20,
+    "appealing" => 21,
+    "appealingly" => 32,
+    "appeals" => 5,
+    "appear" => 4,
+    "appearance" => 1,
+    "appearances" => 1,
+    "appeared" => 20,
+    "appearing" => 21,
+    "appears" => 4,
+    "appeased" => 20,
+    "appellate" => 16,
+    "append" => 5,
+    "appendages" => 1,
+    "appended" => 20,
+    "appendices" => 1,
+    "appending" => 21,
+    "appendix" => 1,
+    "appendixes" => 1,
+    "appends" => 5,
+    "appertaining" => 21,
+    "appetite" => 1,
+    "appetites" => 1,
+    "applauded" => 20,
+    "applause" => 1,
+    "apple" => 3,
+    "apples" => 1,
+    "applicability" => 1,
+    "applicable" => 16,
+    "applicant" => 1,
+    "application" => 21,
+    "applications" => 20,
+    "applied" => 20,
+    "applies" => 4,
+    "apply" => 20,
+    "applying" => 21,
+    "appoint" => 4,
+    "appointed" => 20,
+    "appointment" => 5,
+    "appointments" => 1,
+    "apportioned" => 20,
+    "apportionment" => 5,
+    "appreciate" => 4,
+    "appreciated" => 20,
+    "appreciation" => 5,
+    "appreciative" => 20,
+    "apprehended" => 20,
+    "apprehending" => 21,
+    "apprehension" => 1,
+    "apprehensions" => 1,
+    "apprehensive" => 17,
+    "apprehensively" => 32,
+    "apprentice" => 5,
+    "apprenticed" => 20,
+    "apprise" => 4,
+    "apprised" => 20,
+    "approach" => 5,
+    "approachable" => 21,
+    "approached" => 20,
+    "approaches" => 5,
+    "approaching" => 21,
+    "approbation" => 1,
+    "appropriate" => 20,
+    "appropriated" => 20,
+    "appropriately" => 32,
+    "appropriateness" => 21,
+    "appropriation" => 21,
+    "appropriations" => 20,
+    "approval" => 1,
+    "approvals" => 1,
+    "approve" => 4,
+    "approved" => 20,
+    "approves" => 4,
+    "approving" => 21,
+    "approvingly" => 32,
+    "approximate" => 20,
+    "approximated" => 20,
+    "approximately" => 32,
+    "approximates" => 4,
+    "approximating" => 21,
+    "approximation" => 21,
+    "approximations" => 20,
+    "apr" => 3,
+    "april" => 2,
+    "apron" => 5,
+    "apt" => 17,
+    "aptly" => 32,
+    "aptness" => 17,
+    "aqua" => 17,
+    "aquamarine" => 17,
+    "aquaplanes" => 5,
+    "aquiline" => 16,
+    "aquinas" => 2,
+    "arab" => 17,
+    "arabian" => 17,
+    "arabic" => 19,
+    "arabs" => 1,
+    "arbiter" => 5,
+    "arbiters" => 5,
+    "arbitrarily" => 32,
+    "arbitrary" => 17,
+    "arc" => 23,
+    "arcane" => 16,
+    "arch" => 21,
+    "archaeological" => 16,
+    "archaic" => 17,
+    "archangel" => 1,
+    "archbishop" => 1,
+    "arched" => 20,
+    "archery" => 1,
+    "arches" => 5,
+    "archetype" => 5,
+    "archie" => 3,
+    "archimedes" => 2,
+    "architect" => 5,
+    "architects" => 5,
+    "architectural" => 16,
+    "architecturally" => 32,
+    "architecture" => 1,
+    "architectures" => 1,
+    "archival" => 17,
+    "archive" => 21,
+    "archived" => 20,
+    "archives" => 5,
+    "archiving" => 21,
+    "archivist" => 1,
+    "archivists" => 1,
+    "archway" => 1,
+    "arcs" => 5,
+    "arctic" => 19,
+    "ardent" => 16,
+    "ardently" => 32,
+    "ardour" => 1,
+    "arduous" => 16,
+    "are" => 4,
+    "area" => 1,
+    "areas" => 1,
+    "aren't" => 4,
+    "arena" => 1,
+    "arenas" => 1,
+    "ares" => 6,
+    "argon" => 1,
+    "arguably" => 32,
+    "argue" => 4,
+    "argued" => 20,
+    "argues" => 4,
+    "arguing" => 21,
+    "argument" => 5,
+    "argumentation" => 1,
+    "arguments" => 5,
+    "ariadne" => 2,
+    "aright" => 4,
+    "arise" => 5,
+    "arisen" => 4,
+    "arises" => 5,
+    "arising" => 21,
+    "aristocracy" => 1,
+    "aristocratic" => 16,
+    "aristotle" => 2,
+    "arithmetic" => 17,
+    "arizona" => 2,
+    "ark" => 3,
+    "arkansas" => 2,
+    "arks" => 1,
+    "arm" => 21,
+    "armada" => 1,
+    "armchair" => 21,
+    "armchairs" => 5,
+    "armed" => 20,
+    "armenian" => 19,
+    "armenians" => 1,
+    "armies" => 1,
+    "arming" => 21,
+    "armistice" => 3,
+    "armored" => 20,
+    "armour" => 7,
+    "armoury" => 1,
+    "arms" => 5,
+    "army" => 1,
+    "arnold" => 2,
+    "arose" => 4,
+    "around" => 80,
+    "arouse" => 4,
+    "aroused" => 20,
+    "arrange" => 5,
+    "arranged" => 20,
+    "arrangement" => 5,
+    "arrangements" => 1,
+    "arranges" => 5,
+    "arranging" => 21,
+    "array" => 5,
+    "arrayed" => 20,
+    "arrays" => 5,
+    "arrest" => 5,
+    "arrested" => 20,
+    "arresting" => 21,
+    "arrival" => 1,
+    "arrivals" => 1,
+    "arrive" => 4,
+    "arrived" => 20,
+    "arrives" => 4,
+    "arriving" => 21,
+    "arrogant" => 16,
+    "arrow" => 5,
+    "arrows" => 5,
+    "arsenal" => 1,
+    "arsenals" => 1,
+    "art" => 7,
+    "artefacts" => 1,
+    "arteries" => 1,
+    "artery" => 1,
+    "artfully" => 32,
+    "arthur" => 3,
+    "article" => 5,
+    "articles" => 5,
+    "articulate" => 21,
+    "articulated" => 20,
+    "articulation" => 21,
+    "artifact" => 1,
+    "artifacts" => 1,
+    "artifice" => 5,
+    "artificial" => 16,
+    "artificially" => 32,
+    "artillery" => 1,
+    "artisans" => 1,
+    "artist" => 17,
+    "artistic" => 16,
+    "artists" => 1,
+    "artlessly" => 32,
+    "arts" => 5,
+    "as" => 33,
+    "ascend" => 4,
+    "ascended" => 20,
+    "ascending" => 21,
+    "ascends" => 4,
+    "ascent" => 1,
+    "ascertain" => 4,
+    "ascertained" => 20,
+    "ascertaining" => 21,
+    "ascertains" => 4,
+    "ascii" => 2,
+    "ascribe" => 4,
+    "ascribed" => 20,
+    "ascription" => 1,
+    "ash" => 5,
+    "ashamed" => 20,
+    "ashen" => 5,
+    "ashes" => 5,
+    "ashley" => 2,
+    "asia" => 2,
+    "asian" => 17,
+    "aside" => 17,
+    "asinine" => 16,
+    "ask" => 5,
+    "askance" => 20,
+    "asked" => 20,
+    "asking" => 21,
+    "asks" => 5,
+    "asleep" => 16,
+    "asp" => 1,
+    "aspect" => 5,
+    "aspects" => 5,
+    "aspirated" => 20,
+    "aspirations" => 21,
+    "aspire" => 4,
+    "aspired" => 20,
+    "aspires" => 4,
+    "ass" => 17,
+    "assailants" => 1,
+    "assailed" => 20,
+    "assassin" => 5,
+    "assassinated" => 20,
+    "assassination" => 5,
+    "assassins" => 5,
+    "assault" => 5,
+    "assaulted" => 20,
+    "assemblage" => 1,
+    "assemblages" => 1,
+    "assemble" => 4,
+    "assembled" => 20,
+    "assembler" => 1,
+    "assemblers" => 1,
+    "assemblies" => 1,
+    "assembling" => 21,
+    "assembly" => 3,
+    "assent" => 5,
+    "assented" => 20,
+    "assert" => 5,
+    "asserted" => 20,
+    "asserting" => 21,
+    "assertion" => 1,
+    "assertions" => 1,
+    "asserts" => 5,
+    "assess" => 4,
+    "assessed" => 20,
+    "assesses" => 4,
+    "assessing" => 21,
+    "assessments" => 1,
+    "asset" => 1,
+    "assets" => 1,
+    "assiduously" => 32,
+    "assign" => 5,
+    "assignable" => 16,
+    "assigned" => 20,
+    "assignee" => 1,
+    "assigning" => 21,
+    "assignment" => 5,
+    "assignments" => 1,
+    "assigns" => 5,
+    "assimilated" => 20,
+    "assist" => 5,
+    "assistance" => 1,
+    "assistant" => 17,
+    "assistants" => 1,
+    "assisted" => 20,
+    "assisting" => 21,
+    "assists" => 5,
+    "assizes" => 5,
+    "associate" => 21,
+    "associated" => 20,
+    "associates" => 5,
+    "associating" => 21,
+    "association" => 21,
+    "associations" => 1,
+    "associative" => 21,
+    "assuage" => 4,
+    "assume" => 4,
+    "assumed" => 20,
+    "assumes" => 4,
+    "assuming" => 21,
+    "assumption" => 1,
+    "assumptions" => 1,
+    "assurance" => 1,
+    "assurances" => 1,
+    "assure" => 4,
+    "assured" => 21,
+    "assuredly" => 32,
+    "assures" => 4,
+    "assuring" => 21,
+    "assyrian" => 19,
+    "ast" => 1,
+    "asterisk" => 5,
+    "asterisks" => 5,
+    "asteroids" => 1,
+    "astir" => 16,
+    "astonish" => 4,
+    "astonished" => 20,
+    "astonishing" => 21,
+    "astonishment" => 5,
+    "astoria" => 2,
+    "astounded" => 20,
+    "astounding" => 21,
+    "astounds" => 4,
+    "astrakhan" => 3,
+    "astral" => 17,
+    "astride" => 64,
+    "astrologers" => 1,
+    "astronaut" => 1,
+    "astronomer" => 1,
+    "astronomers" => 1,
+    "astronomical" => 16,
+    "astronomy" => 1,
+    "astute" => 16,
+    "astuteness" => 17,
+    "asylum" => 1,
+    "asymmetric" => 16,
+    "asymmetrical" => 16,
+    "asymptotic" => 16,
+    "asymptotically" => 16,
+    "asynchronous" => 16,
+    "asynchronously" => 32,
+    "at" => 65,
+    "ate" => 5,
+    "athena" => 3,
+    "athens" => 2,
+    "athlete" => 1,
+    "athwart" => 64,
+    "atkinson" => 2,
+    "atlantic" => 19,
+    "atlas" => 3,
+    "atlases" => 1,
+    "atmosphere" => 1,
+    "atom" => 1,
+    "atomic" => 17,
+    "atomically" => 17,
+    "atoms" => 1,
+    "atone" => 4,
+    "atop" => 64,
+    "atrium" => 1,
+    "atrocious" => 16,
+    "attach" => 4,
+    "attached" => 20,
+    "attaches" => 4,
+    "attaching" => 21,
+    "attachment" => 5,
+    "attachments" => 1,
+    "attack" => 21,
+    "attacked" => 20,
+    "attacker" => 17,
+    "attackers" => 21,
+    "attacks" => 5,
+    "attain" => 4,
+    "attainder" => 1,
+    "attained" => 20,
+    "attaining" => 21,
+    "attainment" => 1,
+    "attainments" => 1,
+    "attempt" => 5,
+    "attempted" => 20,
+    "attempting" => 21,
+    "attempts" => 5,
+    "attend" => 4,
+    "attendance" => 1,
+    "attendant" => 17,
+    "attendants" => 1,
+    "attended" => 20,
+    "attendees" => 1,
+    "attending" => 21,
+    "attends" => 4,
+    "attention" => 1,
+    "attentions" => 1,
+    "attentive" => 16,
+    "attentively" => 32,
+    "attenuated" => 20,
+    "attest" => 4,
+    "attested" => 20,
+    "attests" => 4,
+    "attic" => 19,
+    "attics" => 1,
+    "attired" => 20,
+    "attitude" => 5,
+    "attorney" => 5,
+    "attract" => 4,
+    "attracted" => 20,
+    "attracting" => 21,
+    "attraction" => 1,
+    "attractions" => 1,
+    "attractive" => 20,
+    "attractively" => 32,
+    "attractiveness" => 1,
+    "attracts" => 4,
+    "attributable" => 5,
+    "attribute" => 5,
+    "attributed" => 20,
+    "attributes" => 5,
+    "attribution" => 5,
+    "attributions" => 5,
+    "attrition" => 5,
+    "auburn" => 19,
+    "auckland" => 2,
+    "audacious" => 16,
+    "audacity" => 1,
+    "audible" => 21,
+    "audibly" => 32,
+    "audience" => 1,
+    "audiences" => 1,
+    "audio" => 17,
+    "audit" => 5,
+    "audited" => 20,
+    "auditing" => 21,
+    "auditor" => 1,
+    "auditory" => 17,
+    "audits" => 5,
+    "audrey" => 2,
+    "aught" => 261,
+    "augment" => 5,
+    "augmented" => 20,
+    "augmenting" => 21,
+    "augments" => 5,
+    "auguries" => 1,
+    "augury" => 1,
+    "august" => 23,
+    "aunt" => 1,
+    "auntie" => 5,
+    "aunts" => 1,
+    "aurora" => 3,
+    "auspices" => 5,
+    "auspicious" => 16,
+    "austere" => 16,
+    "austin" => 18,
+    "australasia" => 2,
+    "australia" => 2,
+    "australian" => 19,
+    "australians" => 1,
+    "austria" => 2,
+    "austrian" => 17,
+    "authentic" => 16,
+    "authentically" => 32,
+    "authenticate" => 20,
+    "authenticated" => 20,
+    "authenticates" => 4,
+    "authenticating" => 21,
+    "authentication" => 21,
+    "authenticity" => 1,
+    "author" => 5,
+    "authored" => 20,
+    "authoring" => 21,
+    "authoritative" => 16,
+    "authoritatively" => 32,
+    "authorities" => 1,
+    "authority" => 1,
+    "authorization" => 1,
+    "authorize" => 4,
+    "authorized" => 20,
+    "authors" => 5,
+    "authorship" => 1,
+    "auto" => 21,
+    "autobahn" => 1,
+    "automata" => 1,
+    "automate" => 4,
+    "automated" => 20,
+    "automates" => 4,
+    "automatic" => 17,
+    "automatically" => 17,
+    "automatics" => 1,
+    "automating" => 21,
+    "automation" => 5,
+    "automaton" => 1,
+    "automobile" => 21,
+    "automobiles" => 5,
+    "automotive" => 17,
+    "autonomy" => 1,
+    "autosuggestion" => 1,
+    "autumn" => 7,
+    "autumnal" => 16,
+    "auxiliary" => 17,
+    "avail" => 21,
+    "availability" => 1,
+    "available" => 21,
+    "availed" => 20,
+    "avalanche" => 5,
+    "avarice" => 1,
+    "avaricious" => 16,
+    "avariciously" => 32,
+    "ave" => 1,
+    "avenge" => 5,
+    "avenged" => 20,
+    "avenger" => 1,
+    "avenue" => 1,
+    "avenues" => 1,
+    "average" => 21,
+    "averages" => 5,
+    "averaging" => 21,
+    "averred" => 4,
+    "averse" => 20,
+    "aversion" => 21,
+    "avert" => 4,
+    "averted" => 20,
+    "avery" => 2,
+    "avian" => 17,
+    "aviation" => 1,
+    "avidity" => 1,
+    "avocations" => 1,
+    "avoid" => 4,
+    "avoidable" => 21,
+    "avoidance" => 1,
+    "avoided" => 20,
+    "avoiding" => 21,
+    "avoids" => 4,
+    "avow" => 5,
+    "avowal" => 1,
+    "avowed" => 20,
+    "avowedly" => 32,
+    "await" => 5,
+    "awaited" => 20,
+    "awaiting" => 21,
+    "awaits" => 5,
+    "awake" => 20,
+    "awaken" => 4,
+    "awakened" => 20,
+    "awakening" => 21,
+    "awaking" => 21,
+    "award" => 5,
+    "awarded" => 20,
+    "aware" => 20,
+    "awareness" => 21,
+    "away" => 20,
+    "awe" => 5,
+    "awed" => 20,
+    "awesome" => 17,
+    "awful" => 16,
+    "awfully" => 32,
+    "awfulness" => 17,
+    "awkward" => 17,
+    "awkwardly" => 32,
+    "awnings" => 1,
+    "awoke" => 4,
+    "awoken" => 4,
+    "awry" => 16,
+    "axe" => 5,
+    "axes" => 5,
+    "axial" => 17,
+    "axiom" => 1,
+    "axiomatic" => 16,
+    "axioms" => 1,
+    "axis" => 3,
+    "aye" => 1,
+    "azerbaijani" => 19,
+    "aztec" => 19,
+    "azure" => 21,
+    "babbled" => 20,
+    "babe" => 1,
+    "babel" => 3,
+    "babes" => 1,
+    "babies" => 5,
+    "baboon" => 1,
+    "baby" => 21,
+    "bachelor" => 1,
+    "bachelors" => 1,
+    "back" => 21,
+    "backbone" => 1,
+    "backbones" => 1,
+    "backed" => 20,
+    "backend" => 1,
+    "backer" => 17,
+    "backers" => 21,
+    "backgammon" => 5,
+    "background" => 21,
+    "backgrounds" => 5,
+    "backing" => 21,
+    "backlog" => 5,
+    "backlogs" => 5,
+    "backs" => 5,
+    "backslash" => 5,
+    "backslashes" => 5,
+    "backspace" => 5,
+    "backspaced" => 20,
+    "backspaces" => 5,
+    "backspacing" => 21,
+    "backstop" => 5,
+    "backtrack" => 5,
+    "backtracking" => 21,
+    "backtracks" => 5,
+    "backup" => 17,
+    "backups" => 1,
+    "backward" => 17,
+    "backwards" => 1,
+    "backwater" => 5,
+    "backyard" => 1,
+    "bacon" => 3,
+    "bad" => 21,
+    "bade" => 4,
+    "badge" => 5,
+    "badges" => 5,
+    "badly" => 32,
+    "badness" => 21,
+    "baffled" => 20,
+    "bag" => 5,
+    "baggage" => 1,
+    "bagged" => 20,
+    "bagging" => 5,
+    "baggy" => 17,
+    "bags" => 5,
+    "bah" => 1,
+    "bail" => 5,
+    "bailey" => 3,
+    "bailiff" => 1,
+    "bailing" => 21,
+    "bails" => 5,
+    "baird" => 2,
+    "bait" => 21,
+    "baits" => 5,
+    "bake" => 5,
+    "baked" => 20,
+    "baker" => 3,
+    "baker's" => 1,
+    "bakers" => 5,
+    "bakes" => 5,
+    "baking" => 21,
+    "balance" => 5,
+    "balanced" => 20,
+    "balancers" => 5,
+    "balances" => 5,
+    "balancing" => 21,
+    "balcony" => 1,
+    "bald" => 21,
+    "baldwin" => 3,
+    "baleful" => 16,
+    "balefully" => 32,
+    "balked" => 20,
+    "ball" => 7,
+    "ballast" => 5,
+    "balloon" => 5,
+    "ballooned" => 20,
+    "balloons" => 5,
+    "ballot" => 5,
+    "ballots" => 5,
+    "ballpark" => 21,
+    "ballroom" => 5,
+    "balls" => 5,
+    "balm" => 5,
+    "balminess" => 17,
+    "balmy" => 16,
+    "baltic" => 18,
+    "baltimore" => 3,
+    "balzac" => 2,
+    "bamboo" => 21,
+    "bamboos" => 5,
+    "ban" => 5,
+    "bananas" => 1,
+    "band" => 5,
+    "bandage" => 5,
+    "bandaged" => 20,
+    "bandages" => 5,
+    "bandanna" => 1,
+    "banded" => 20,
+    "bandied" => 20,
+    "bands" => 5,
+    "bandwidth" => 1,
+    "bandy" => 21,
+    "bang" => 5,
+    "banged" => 20,
+    "banging" => 21,
+    "bangs" => 5,
+    "banish" => 4,
+    "banished" => 20,
+    "banishment" => 5,
+    "banisters" => 5,
+    "banjo" => 5,
+    "bank" => 5,
+    "banker" => 1,
+    "bankers" => 5,
+    "banking" => 21,
+    "banknotes" => 1,
+    "bankruptcies" => 1,
+    "bankruptcy" => 1,
+    "bankrupted" => 20,
+    "banks" => 7,
+    "banned" => 20,
+    "banner" => 21,
+    "banners" => 5,
+    "banns" => 1,
+    "banquet" => 5,
+    "bantering" => 21,
+    "bar" => 69,
+    "barbara" => 2,
+    "barbaric" => 16,
+    "barbarity" => 1,
+    "barbarous" => 16,
+    "barbarously" => 32,
+    "barbed" => 20,
+    "barber" => 7,
+    "barber's" => 1,
+    "bare" => 21,
+    "bared" => 20,
+    "barefoot" => 16,
+    "barelegged" => 16,
+    "barely" => 32,
+    "bargain" => 5,
+    "bargaining" => 21,
+    "barges" => 5,
+    "bark" => 5,
+    "barker" => 3,
+    "barking" => 21,
+    "barks" => 5,
+    "barley" => 1,
+    "barlow" => 3,
+    "barmaid" => 1,
+    "barn" => 5,
+    "barnes" => 2,
+    "barnett" => 2,
+    "barnyard" => 17,
+    "barometer" => 1,
+    "barometric" => 16,
+    "baronet" => 1,
+    "baroque" => 17,
+    "barque" => 1,
+    "barred" => 20,
+    "barrel" => 5,
+    "barrelled" => 20,
+    "barrels" => 5,
+    "barren" => 17,
+    "barricade" => 5,
+    "barricaded" => 20,
+    "barrier" => 5,
+    "barriers" => 5,
+    "barring" => 85,
+    "barrow" => 1,
+    "barrows" => 1,
+    "bars" => 5,
+    "bart" => 3,
+    "bartenders" => 1,
+    "barton" => 2,
+    "basalt" => 1,
+    "base" => 21,
+    "baseball" => 1,
+    "based" => 20,
+    "baseline" => 5,
+    "baselines" => 5,
+    "bases" => 5,
+    "basest" => 16,
+    "bash" => 5,
+    "bashful" => 16,
+    "basic" => 19,
+    "basically" => 17,
+    "basics" => 3,
+    "basil" => 7,
+    "basin" => 5,
+    "basing" => 21,
+    "basis" => 1,
+    "basket" => 5,
+    "baskets" => 5,
+    "basque" => 19,
+    "bass" => 23,
+    "bastards" => 5,
+    "bastion" => 5,
+    "bat" => 5,
+    "batch" => 21,
+    "batched" => 20,
+    "batches" => 5,
+    "batching" => 21,
+    "bated" => 20,
+    "bath" => 5,
+    "bathed" => 20,
+    "bathing" => 21,
+    "bathroom" => 5,
+    "bathrooms" => 5,
+    "baths" => 5,
+    "baton" => 5,
+    "bats" => 5,
+    "battalion" => 5,
+    "battered" => 20,
+    "batteries" => 1,
+    "battery" => 1,
+    "battle" => 23,
+    "battledores" => 1,
+    "battlefield" => 1,
+    "battles" => 5,
+    "battleship" => 1,
+    "bauble" => 1,
+    "baud" => 1,
+    "bavarian" => 19,
+    "bawled" => 20,
+    "bay" => 21,
+    "baying" => 21,
+    "bazaar" => 1,
+    "bazaars" => 1,
+    "bbc" => 3,
+    "be" => 9,
+    "beach" => 7,
+    "beacon" => 5,
+    "beaded" => 20,
+    "beads" => 5,
+    "beak" => 5,
+    "beam" => 5,
+    "beamed" => 20,
+    "beaming" => 21,
+    "beams" => 5,
+    "bean" => 7,
+    "bear" => 21,
+    "bearable" => 21,
+    "beard" => 7,
+    "bearded" => 20,
+    "bearer" => 17,
+    "bearing" => 21,
+    "bearings" => 21,
+    "bears" => 5,
+    "beast" => 21,
+    "beasts" => 5,
+    "beat" => 21,
+    "beaten" => 21,
+    "beating" => 21,
+    "beats" => 5,
+    "beau" => 7,
+    "beaufort" => 3,
+    "beauteous" => 16,
+    "beauties" => 5,
+    "beautiful" => 17,
+    "beautifully" => 32,
+    "beautify" => 4,
+    "beauty" => 21,
+    "beaver" => 5,
+    "became" => 4,
+    "because" => 576,
+    "beck" => 7,
+    "beckoned" => 20,
+    "beckoning" => 21,
+    "becky" => 3,
+    "become" => 4,
+    "becomes" => 4,
+    "becoming" => 21,
+    "bed" => 21,
+    "bedchamber" => 1,
+    "bedclothes" => 1,
+    "bedded" => 20,
+    "bedim" => 4,
+    "bedpost" => 1,
+    "bedridden" => 16,
+    "bedrock" => 5,
+    "bedroom" => 1,
+    "bedrooms" => 1,
+    "beds" => 5,
+    "bedside" => 1,
+    "bedsteads" => 1,
+    "bedtime" => 1,
+    "bee" => 5,
+    "beech" => 1,
+    "beeches" => 1,
+    "beef" => 21,
+    "beefy" => 16,
+    "beehive" => 5,
+    "been" => 5,
+    "beep" => 5,
+    "beeped" => 20,
+    "beeping" => 21,
+    "beeps" => 5,
+    "beer" => 5,
+    "bees" => 5,
+    "beet" => 5,
+    "beetle" => 21,
+    "beetles" => 5,
+    "befall" => 5,
+    "befallen" => 5,
+    "befitted" => 4,
+    "befitting" => 20,
+    "before" => 576,
+    "beforehand" => 16,
+    "befriend" => 4,
+    "befriended" => 20,
+    "beg" => 5,
+    "began" => 4,
+    "beget" => 4,
+    "beggar" => 5,
+    "beggars" => 5,
+    "beggary" => 17,
+    "begged" => 4,
+    "begging" => 5,
+    "begin" => 5,
+    "beginner" => 1,
+    "beginners" => 1,
+    "beginning" => 21,
+    "beginnings" => 5,
+    "begins" => 5,
+    "begone" => 4,
+    "begotten" => 20,
+    "begs" => 5,
+    "begun" => 4,
+    "behalf" => 1,
+    "behave" => 4,
+    "behaved" => 20,
+    "behaves" => 4,
+    "behaving" => 21,
+    "behavior" => 1,
+    "behavioral" => 16,
+    "behaviors" => 1,
+    "behaviour" => 1,
+    "behaviours" => 1,
+    "behead" => 4,
+    "beheaded" => 20,
+    "beheading" => 1,
+    "beheld" => 4,
+    "behind" => 81,
+    "behold" => 4,
+    "beholden" => 4,
+    "beholding" => 21,
+    "beige" => 17,
+    "being" => 533,
+    "beings" => 5,
+    "belaboring" => 21,
+    "belated" => 20,
+    "beleaguered" => 20,
+    "belgium" => 2,
+    "belie" => 4,
+    "belief" => 1,
+    "beliefs" => 1,
+    "believe" => 4,
+    "believed" => 20,
+    "believes" => 4,
+    "believing" => 21,
+    "belittled" => 20,
+    "bell" => 7,
+    "belle" => 1,
+    "bellman" => 1,
+    "bellow" => 7,
+    "bellows" => 5,
+    "bells" => 5,
+    "belly" => 5,
+    "belong" => 68,
+    "belonged" => 20,
+    "belonging" => 21,
+    "belongings" => 68,
+    "belongs" => 4,
+    "beloved" => 21,
+    "below" => 64,
+    "belt" => 5,
+    "belted" => 20,
+    "belting" => 21,
+    "beluga" => 1,
+    "ben" => 3,
+    "bench" => 5,
+    "benches" => 5,
+    "benchmark" => 5,
+    "benchmarks" => 5,
+    "bend" => 7,
+    "bender" => 3,
+    "bending" => 21,
+    "beneath" => 64,
+    "benediction" => 1,
+    "benefactor" => 1,
+    "benefactors" => 1,
+    "beneficence" => 1,
+    "beneficial" => 17,
+    "benefit" => 5,
+    "benefited" => 20,
+    "benefiting" => 21,
+    "benefits" => 5,
+    "benevolence" => 1,
+    "benevolent" => 16,
+    "benevolently" => 32,
+    "bengal" => 3,
+    "bengali" => 17,
+    "benignity" => 1,
+    "benjamin" => 3,
+    "benny" => 3,
+    "bent" => 21,
+    "benumbed" => 20,
+    "beowulf" => 2,
+    "bequeath" => 4,
+    "bequeathed" => 20,
+    "bequest" => 5,
+    "bereaved" => 20,
+    "berg" => 3,
+    "bergen" => 2,
+    "berkeley" => 2,
+    "berkshire" => 3,
+    "bermuda" => 3,
+    "bernard" => 2,
+    "bernoulli" => 2,
+    "bernstein" => 2,
+    "berries" => 5,
+    "berry" => 7,
+    "bert" => 2,
+    "berth" => 5,
+    "berths" => 5,
+    "bertrand" => 2,
+    "beryl" => 19,
+    "beseeching" => 21,
+    "besetting" => 21,
+    "beside" => 64,
+    "besides" => 64,
+    "besieged" => 20,
+    "besought" => 4,
+    "bespoke" => 20,
+    "bespoken" => 20,
+    "best" => 31,
+    "bestow" => 5,
+    "bestowed" => 20,
+    "bestowing" => 21,
+    "bestride" => 4,
+    "bet" => 69,
+    "beta" => 21,
+    "betel" => 1,
+    "bethesda" => 3,
+    "bethought" => 20,
+    "betook" => 4,
+    "betray" => 4,
+    "betrayal" => 1,
+    "betrayed" => 20,
+    "betraying" => 21,
+    "betrays" => 4,
+    "betrothal" => 1,
+    "bets" => 5,
+    "better" => 29,
+    "betters" => 5,
+    "betting" => 21,
+    "betty" => 3,
+    "between" => 65,
+    "bevel" => 21,
+    "beverage" => 1,
+    "beverages" => 1,
+    "bevy" => 1,
+    "beware" => 4,
+    "bewildered" => 20,
+    "bewilderment" => 5,
+    "bewitched" => 20,
+    "bewitching" => 21,
+    "beyond" => 65,
+    "bias" => 21,
+    "biased" => 20,
+    "biases" => 5,
+    "biasing" => 21,
+    "bib" => 5,
+    "bible" => 3,
+    "bibles" => 1,
+    "biblical" => 16,
+    "bibliographic" => 16,
+    "bibliographies" => 1,
+    "bibliography" => 1,
+    "bicameral" => 16,
+    "bicep" => 1,
+    "bicycles" => 5,
+    "bicycling" => 21,
+    "bid" => 5,
+    "bidding" => 5,
+    "bide" => 4,
+    "bidirectional" => 17,
+    "bids" => 5,
+    "bier" => 17,
+    "big" => 17,
+    "bigger" => 16,
+    "biggest" => 16,
+    "bight" => 5,
+    "bigness" => 17,
+    "bijou" => 17,
+    "bile" => 5,
+    "bilingual" => 17,
+    "bill" => 7,
+    "billet" => 5,
+    "billions" => 5,
+    "billow" => 5,
+    "billows" => 5,
+    "billowy" => 16,
+    "bills" => 5,
+    "billy" => 3,
+    "bin" => 5,
+    "binaries" => 1,
+    "binary" => 17,
+    "bind" => 5,
+    "binder" => 1,
+    "binders" => 1,
+    "binding" => 21,
+    "bindings" => 5,
+    "binds" => 5,
+    "bingo" => 21,
+    "binomial" => 17,
+    "bins" => 5,
+    "biographical" => 16,
+    "biographies" => 5,
+    "biography" => 5,
+    "biological" => 17,
+    "biology" => 1,
+    "biomedical" => 17,
+    "bionic" => 16,
+    "bios" => 1,
+    "bipartite" => 16,
+    "bird" => 23,
+    "birds" => 5,
+    "birmingham" => 2,
+    "birth" => 21,
+    "birthday" => 5,
+    "biscuit" => 1,
+    "biscuits" => 1,
+    "bisect" => 5,
+    "bishops" => 5,
+    "bit" => 5,
+    "bitch" => 5,
+    "bite" => 5,
+    "biting" => 21,
+    "bits" => 5,
+    "bitten" => 4,
+    "bitter" => 21,
+    "bitterest" => 16,
+    "bitterly" => 32,
+    "bitterness" => 21,
+    "bitwise" => 16,
+    "bizarre" => 16,
+    "blab" => 5,
+    "black" => 21,
+    "blackbird" => 5,
+    "blackboard" => 5,
+    "blackened" => 20,
+    "blackest" => 16,
+    "blackguard" => 5,
+    "blackguards" => 5,
+    "blacking" => 21,
+    "blacklist" => 5,
+    "blacklisted" => 20,
+    "blacklisting" => 21,
+    "blackmailing" => 21,
+    "blackness" => 21,
+    "blacksmith" => 5,
+    "bladder" => 5,
+    "blade" => 5,
+    "bladed" => 20,
+    "blades" => 5,
+    "blake" => 2,
+    "blamable" => 21,
+    "blame" => 21,
+    "blamed" => 20,
+    "blameless" => 16,
+    "blaming" => 21,
+    "blanchard" => 2,
+    "blanche" => 2,
+    "blanched" => 20,
+    "blanching" => 21,
+    "bland" => 21,
+    "blandly" => 32,
+    "blank" => 21,
+    "blanked" => 20,
+    "blanket" => 21,
+    "blanketed" => 20,
+    "blankets" => 5,
+    "blanking" => 21,
+    "blankly" => 32,
+    "blanks" => 5,
+    "blast" => 5,
+    "blasted" => 20,
+    "blasts" => 5,
+    "blatantly" => 32,
+    "blaze" => 5,
+    "blazed" => 20,
+    "blazers" => 5,
+    "blazing" => 21,
+    "blazingly" => 32,
+    "bleach" => 21,
+    "bleak" => 17,
+    "bleakness" => 17,
+    "bled" => 20,
+    "bleed" => 5,
+    "bleeding" => 21,
+    "bleeds" => 5,
+    "blemishes" => 5,
+    "blend" => 5,
+    "blended" => 20,
+    "blender" => 5,
+    "blending" => 21,
+    "blends" => 5,
+    "bless" => 4,
+    "blessed" => 20,
+    "blessing" => 21,
+    "blessings" => 4,
+    "blew" => 21,
+    "blight" => 5,
+    "blighted" => 20,
+    "blind" => 21,
+    "blinded" => 20,
+    "blindfold" => 21,
+    "blinding" => 21,
+    "blindly" => 32,
+    "blindness" => 21,
+    "blinds" => 5,
+    "blink" => 5,
+    "blinked" => 20,
+    "blinking" => 21,
+    "blinks" => 5,
+    "bliss" => 1,
+    "blissful" => 16,
+    "blister" => 5,
+    "blithely" => 32,
+    "blitz" => 5,
+    "bloat" => 21,
+    "bloated" => 20,
+    "bloating" => 21,
+    "blob" => 5,
+    "blobs" => 5,
+    "bloc" => 1,
+    "block" => 5,
+    "blockaded" => 20,
+    "blocked" => 20,
+    "blocking" => 21,
+    "blocks" => 5,
+    "blond" => 21,
+    "blonde" => 21,
+    "blood" => 5,
+    "blooded" => 20,
+    "bloodiest" => 16,
+    "bloodless" => 16,
+    "bloodshed" => 1,
+    "bloodstains" => 1,
+    "bloody" => 21,
+    "bloom" => 7,
+    "bloomed" => 20,
+    "blooming" => 21,
+    "blooms" => 5,
+    "bloomsbury" => 2,
+    "bloop" => 5,
+    "blossom" => 5,
+    "blossomed" => 20,
+    "blossoms" => 5,
+    "blot" => 5,
+    "blotched" => 20,
+    "blotches" => 5,
+    "blotted" => 20,
+    "blotting" => 5,
+    "blow" => 21,
+    "blowing" => 21,
+    "blown" => 20,
+    "blows" => 5,
+    "blowup" => 1,
+    "blubber" => 5,
+    "blubbering" => 21,
+    "blue" => 21,
+    "blueberries" => 5,
+    "blueprint" => 5,
+    "bluer" => 17,
+    "blues" => 5,
+    "bluest" => 16,
+    "bluff" => 21,
+    "bluffs" => 5,
+    "blunder" => 5,
+    "blundering" => 21,
+    "blunders" => 5,
+    "blunt" => 21,
+    "blur" => 21,
+    "blurb" => 5,
+    "blurred" => 20,
+    "blurring" => 5,
+    "blurry" => 16,
+    "blurs" => 5,
+    "blurted" => 20,
+    "blush" => 5,
+    "blushed" => 20,
+    "blushing" => 21,
+    "bluster" => 5,
+    "blustered" => 20,
+    "boa" => 1,
+    "boar" => 1,
+    "board" => 5,
+    "boarded" => 20,
+    "boarder" => 1,
+    "boarding" => 21,
+    "boards" => 5,
+    "boast" => 5,
+    "boasted" => 20,
+    "boastful" => 16,
+    "boasting" => 21,
+    "boat" => 5,
+    "boats" => 5,
+    "bob" => 7,
+    "bobbed" => 20,
+    "bode" => 5,
+    "bodes" => 5,
+    "bodies" => 5,
+    "bodily" => 16,
+    "boding" => 21,
+    "body" => 5,
+    "boggling" => 21,
+    "bogs" => 5,
+    "bogus" => 17,
+    "bohemia" => 3,
+    "bohemian" => 19,
+    "boil" => 5,
+    "boiled" => 20,
+    "boiler" => 1,
+    "boilerplate" => 21,
+    "boilers" => 5,
+    "boiling" => 21,
+    "boils" => 5,
+    "boisterous" => 16,
+    "boisterously" => 32,
+    "bold" => 21,
+    "bolder" => 17,
+    "boldly" => 32,
+    "boldness" => 21,
+    "bollard" => 1,
+    "bolstering" => 21,
+    "bolt" => 5,
+    "bolted" => 20,
+    "bolts" => 5,
+    "bomb" => 21,
+    "bombay" => 3,
+    "bombs" => 5,
+    "bond" => 23,
+    "bondage" => 1,
+    "bonding" => 21,
+    "bonds" => 5,
+    "bone" => 21,
+    "bones" => 5,
+    "bonnet" => 5,
+    "bonnets" => 5,
+    "bonniest" => 16,
+    "bonny" => 17,
+    "bonsai" => 5,
+    "bonus" => 5,
+    "bonuses" => 5,
+    "booby" => 21,
+    "book" => 5,
+    "bookcases" => 1,
+    "bookkeeper" => 1,
+    "bookkeeping" => 5,
+    "bookmark" => 5,
+    "bookmarks" => 5,
+    "books" => 5,
+    "bookshelf" => 1,
+    "bookworm" => 1,
+    "boolean" => 17,
+    "boom" => 5,
+    "boomed" => 20,
+    "booming" => 21,
+    "boon" => 17,
+    "boost" => 5,
+    "boosted" => 20,
+    "boosting" => 21,
+    "boosts" => 5,
+    "boot" => 5,
+    "booted" => 20,
+    "booting" => 21,
+    "bootlegger" => 1,
+    "bootleggers" => 1,
+    "boots" => 5,
+    "bootstrap" => 5,
+    "bootstrapped" => 4,
+    "bootstrapping" => 5,
+    "bootstraps" => 5,
+    "bordeaux" => 3,
+    "border" => 5,
+    "bordered" => 20,
+    "bordering" => 21,
+    "borderline" => 21,
+    "borders" => 5,
+    "bore" => 5,
+    "bored" => 20,
+    "boredom" => 1,
+    "bores" => 5,
+    "boring" => 21,
+    "boris" => 2,
+    "born" => 23,
+    "borne" => 20,
+    "borough" => 1,
+    "borrow" => 5,
+    "borrowed" => 20,
+    "borrower" => 1,
+    "borrowing" => 21,
+    "borrows" => 5,
+    "bosch" => 2,
+    "bosh" => 5,
+    "bosnian" => 17,
+    "bosom" => 21,
+    "bosoms" => 5,
+    "boss" => 21,
+    "boston" => 3,
+    "boswell" => 3,
+    "botany" => 1,
+    "botch" => 5,
+    "both" => 896,
+    "bother" => 5,
+    "bothered" => 20,
+    "bothering" => 21,
+    "bothersome" => 16,
+    "bottle" => 5,
+    "bottleneck" => 5,
+    "bottlenecks" => 5,
+    "bottles" => 5,
+    "bottom" => 21,
+    "bottoms" => 5,
+    "bough" => 1,
+    "boughs" => 1,
+    "bought" => 5,
+    "boulevard" => 1,
+    "bounce" => 5,
+    "bounced" => 20,
+    "bouncing" => 21,
+    "bound" => 21,
+    "boundaries" => 1,
+    "boundary" => 1,
+    "bounded" => 20,
+    "bounding" => 21,
+    "boundless" => 16,
+    "bounds" => 5,
+    "bounties" => 5,
+    "bounty" => 5,
+    "bouquet" => 1,
+    "bout" => 69,
+    "boutiques" => 1,
+    "bouts" => 5,
+    "bow" => 5,
+    "bowed" => 20,
+    "bowels" => 5,
+    "bowers" => 7,
+    "bowie" => 3,
+    "bowing" => 21,
+    "bowl" => 5,
+    "bowler" => 1,
+    "bowls" => 5,
+    "bows" => 5,
+    "box" => 5,
+    "boxed" => 20,
+    "boxer" => 1,
+    "boxes" => 5,
+    "boxing" => 21,
+    "boy" => 5,
+    "boycott" => 5,
+    "boyhood" => 1,
+    "boyish" => 16,
+    "boys" => 5,
+    "brace" => 5,
+    "braced" => 20,
+    "bracelets" => 5,
+    "braces" => 5,
+    "bracing" => 21,
+    "bracket" => 5,
+    "bracketed" => 20,
+    "brackets" => 5,
+    "brad" => 7,
+    "bradford" => 3,
+    "bradshaw" => 3,
+    "brag" => 21,
+    "bragging" => 5,
+    "brahma" => 3,
+    "braid" => 21,
+    "braided" => 20,
+    "braille" => 23,
+    "brain" => 7,
+    "brains" => 5,
+    "brainstorm" => 5,
+    "brake" => 5,
+    "brakes" => 5,
+    "bramble" => 5,
+    "brambles" => 5,
+    "branch" => 7,
+    "branched" => 20,
+    "branches" => 5,
+    "branching" => 21,
+    "brand" => 5,
+    "branded" => 20,
+    "branding" => 21,
+    "brandon" => 2,
+    "brands" => 5,
+    "brandy" => 7,
+    "brass" => 21,
+    "brassy" => 17,
+    "brave" => 21,
+    "braved" => 20,
+    "bravely" => 32,
+    "bravery" => 1,
+    "braving" => 21,
+    "bravo" => 5,
+    "brawling" => 21,
+    "brawls" => 5,
+    "brawny" => 16,
+    "brazen" => 20,
+    "brazier" => 1,
+    "brazil" => 2,
+    "brazilian" => 17,
+    "breach" => 5,
+    "breaches" => 5,
+    "bread" => 5,
+    "breadcrumb" => 5,
+    "breadcrumbs" => 5,
+    "breadth" => 5,
+    "break" => 5,
+    "breakable" => 21,
+    "breakage" => 1,
+    "breakdown" => 1,
+    "breakers" => 5,
+    "breakfast" => 5,
+    "breakfasted" => 20,
+    "breakfasts" => 5,
+    "breaking" => 21,
+    "breakout" => 17,
+    "breakpoint" => 1,
+    "breakpoints" => 1,
+    "breaks" => 5,
+    "breakthrough" => 17,
+    "breast" => 5,
+    "breasted" => 20,
+    "breath" => 21,
+    "breathe" => 4,
+    "breathed" => 20,
+    "breathing" => 21,
+    "breathless" => 16,
+    "breathlessly" => 32,
+    "breaths" => 1,
+    "bred" => 5,
+    "breed" => 5,
+    "breeding" => 21,
+    "breeze" => 5,
+    "breezes" => 5,
+    "breezy" => 17,
+    "brendan" => 2,
+    "brennan" => 2,
+    "brent" => 2,
+    "brest" => 18,
+    "brethren" => 17,
+    "breton" => 19,
+    "breve" => 1,
+    "brevity" => 1,
+    "brew" => 5,
+    "brewer" => 3,
+    "brews" => 5,
+    "brian" => 2,
+    "briar" => 1,
+    "bribe" => 5,
+    "bribed" => 20,
+    "bribery" => 1,
+    "bribes" => 5,
+    "brick" => 21,
+    "bricks" => 5,
+    "bridal" => 17,
+    "bride" => 5,
+    "bridegroom" => 1,
+    "brides" => 5,
+    "bridesmaid" => 5,
+    "bridge" => 5,
+    "bridged" => 20,
+    "bridges" => 7,
+    "bridging" => 21,
+    "bridle" => 5,
+    "brief" => 21,
+    "briefly" => 32,
+    "brigade" => 5,
+    "brigadier" => 1,
+    "brigadiers" => 1,
+    "brigantine" => 1,
+    "bright" => 23,
+    "brighten" => 21,
+    "brightened" => 20,
+    "brighter" => 17,
+    "brightest" => 16,
+    "brightly" => 32,
+    "brightness" => 21,
+    "brill" => 17,
+    "brilliancy" => 1,
+    "brilliant" => 17,
+    "brilliantly" => 32,
+    "brilliants" => 1,
+    "brim" => 21,
+    "brimful" => 17,
+    "brimmed" => 20,
+    "brimming" => 20,
+    "brims" => 5,
+    "brimstone" => 1,
+    "bring" => 4,
+    "bringing" => 21,
+    "brings" => 4,
+    "brink" => 1,
+    "brisk" => 20,
+    "briskly" => 32,
+    "bristling" => 21,
+    "bristol" => 3,
+    "britain" => 19,
+    "britannica" => 2,
+    "british" => 19,
+    "brittle" => 21,
+    "broad" => 17,
+    "broadcast" => 21,
+    "broadcaster" => 1,
+    "broadcasters" => 1,
+    "broadcasting" => 21,
+    "broadcasts" => 5,
+    "broadened" => 20,
+    "broadening" => 21,
+    "broader" => 16,
+    "broadest" => 16,
+    "broadly" => 32,
+    "broadside" => 5,
+    "broadsides" => 5,
+    "broadway" => 19,
+    "broccoli" => 1,
+    "brochure" => 1,
+    "broiled" => 20,
+    "broiling" => 21,
+    "broke" => 21,
+    "broken" => 20,
+    "broker" => 21,
+    "brokers" => 5,
+    "bronze" => 21,
+    "brooch" => 5,
+    "brood" => 21,
+    "brooded" => 20,
+    "brooding" => 21,
+    "brook" => 5,
+    "brooks" => 7,
+    "broom" => 5,
+    "brothel" => 1,
+    "brother" => 5,
+    "brotherly" => 32,
+    "brothers" => 1,
+    "brougham" => 1,
+    "brought" => 4,
+    "brow" => 5,
+    "brown" => 23,
+    "browned" => 20,
+    "brownian" => 16,
+    "brownish" => 16,
+    "brownstone" => 1,
+    "brows" => 5,
+    "browse" => 5,
+    "browsed" => 20,
+    "browser" => 1,
+    "browsers" => 5,
+    "browsing" => 21,
+    "bruce" => 2,
+    "bruise" => 5,
+    "bruised" => 20,
+    "bruises" => 5,
+    "bruno" => 2,
+    "brunswick" => 2,
+    "brush" => 5,
+    "brushed" => 20,
+    "brushes" => 5,
+    "brushing" => 21,
+    "brutality" => 1,
+    "brute" => 21,
+    "bryan" => 2,
+    "bubble" => 5,
+    "bubbles" => 5,
+    "bubbling" => 21,
+    "buchanan" => 2,
+    "buck" => 7,
+    "bucket" => 5,
+    "bucketing" => 21,
+    "buckets" => 5,
+    "buckles" => 5,
+    "bucks" => 5,
+    "bud" => 7,
+    "buddhism" => 1,
+    "buddhist" => 17,
+    "budding" => 21,
+    "buddy" => 279,
+    "budge" => 21,
+    "budged" => 20,
+    "budget" => 21,
+    "budgets" => 5,
+    "buds" => 5,
+    "buff" => 21,
+    "buffalo" => 7,
+    "buffaloes" => 5,
+    "buffer" => 21,
+    "buffered" => 20,
+    "buffering" => 21,
+    "buffers" => 5,
+    "buffet" => 7,
+    "buffeted" => 20,
+    "buffs" => 5,
+    "bug" => 5,
+    "bugaboo" => 1,
+    "bugger" => 5,
+    "buggy" => 17,
+    "bugs" => 5,
+    "build" => 5,
+    "builder" => 1,
+    "builders" => 5,
+    "building" => 21,
+    "buildings" => 5,
+    "builds" => 5,
+    "built" => 21,
+    "bulbous" => 16,
+    "bulgarian" => 17,
+    "bulge" => 5,
+    "bulged" => 20,
+    "bulk" => 21,
+    "bulkiness" => 17,
+    "bulky" => 16,
+    "bull" => 21,
+    "bulldog" => 5,
+    "bullet" => 5,
+    "bulletin" => 5,
+    "bulletproof" => 20,
+    "bullets" => 5,
+    "bullion" => 1,
+    "bullseye" => 1,
+    "bully" => 21,
+    "bum" => 21,
+    "bumming" => 4,
+    "bump" => 5,
+    "bumped" => 20,
+    "bumpers" => 5,
+    "bumping" => 21,
+    "bumps" => 5,
+    "bun" => 5,
+    "bunch" => 5,
+    "bundle" => 5,
+    "bundled" => 20,
+    "bundler" => 1,
+    "bundles" => 5,
+    "bundling" => 21,
+    "bungalow" => 1,
+    "bungalows" => 1,
+    "bunks" => 5,
+    "bunsen" => 3,
+    "bunyan" => 2,
+    "buoyancy" => 1,
+    "buoyed" => 20,
+    "burden" => 5,
+    "burdened" => 20,
+    "burdens" => 5,
+    "burdensome" => 16,
+    "bureau" => 1,
+    "bureaus" => 1,
+    "burglar" => 5,
+    "burglars" => 5,
+    "burgled" => 20,
+    "buried" => 20,
+    "burke" => 2,
+    "burlington" => 2,
+    "burly" => 48,
+    "burmese" => 19,
+    "burn" => 5,
+    "burned" => 20,
+    "burner" => 1,
+    "burning" => 21,
+    "burnished" => 20,
+    "burnout" => 1,
+    "burns" => 7,
+    "burnt" => 20,
+    "burp" => 5,
+    "burrow" => 5,
+    "burrowing" => 21,
+    "burst" => 5,
+    "bursting" => 21,
+    "bursts" => 5,
+    "burton" => 2,
+    "bury" => 5,
+    "burying" => 21,
+    "bus" => 5,
+    "busby" => 1,
+    "buses" => 5,
+    "bush" => 23,
+    "bushel" => 5,
+    "bushes" => 5,
+    "bushy" => 17,
+    "busied" => 20,
+    "busier" => 17,
+    "busily" => 32,
+    "business" => 21,
+    "businesslike" => 16,
+    "bust" => 21,
+    "busted" => 20,
+    "bustle" => 5,
+    "bustled" => 20,
+    "bustling" => 21,
+    "busts" => 5,
+    "busy" => 21,
+    "busybody" => 5,
+    "but" => 577,
+    "butcher" => 21,
+    "butchers" => 21,
+    "butler" => 7,
+    "butt" => 5,
+    "butted" => 20,
+    "butter" => 5,
+    "buttercup" => 1,
+    "buttered" => 20,
+    "butterflies" => 5,
+    "butterfly" => 5,
+    "buttery" => 17,
+    "button" => 5,
+    "buttoned" => 20,
+    "buttoning" => 21,
+    "buttons" => 5,
+    "buy" => 5,
+    "buying" => 21,
+    "buys" => 5,
+    "buzz" => 5,
+    "buzzing" => 21,
+    "by" => 81,
+    "bye" => 81,
+    "bygone" => 17,
+    "bypass" => 5,
+    "bypassed" => 20,
+    "bypasses" => 5,
+    "bypassing" => 21,
+    "byproduct" => 1,
+    "byron" => 2,
+    "byronic" => 16,
+    "bystander" => 1,
+    "bystanders" => 1,
+    "byte" => 1,
+    "bytecode" => 1,
+    "bytes" => 1,
+    "byzantine" => 17,
+    "cab" => 5,
+    "cabal" => 5,
+    "cabby" => 1,
+    "cabin" => 5,
+    "cabinet" => 1,
+    "cabinets" => 1,
+    "cabins" => 5,
+    "cable" => 5,
+    "cabriolet" => 1,
+    "cabs" => 5,
+    "cache" => 5,
+    "cacheable" => 16,
+    "cached" => 20,
+    "caches" => 5,
+    "caching" => 21,
+    "cackled" => 20,
+    "cactus" => 17,
+    "cad" => 1,
+    "cadaver" => 1,
+    "cadaverous" => 16,
+    "caddy" => 1,
+    "cadence" => 5,
+    "cadet" => 1,
+    "cadets" => 1,
+    "caffeine" => 1,
+    "cage" => 7,
+    "caged" => 20,
+    "cahoots" => 5,
+    "cairo" => 2,
+    "caitiff" => 17,
+    "cake" => 5,
+    "cakes" => 5,
+    "cal" => 3,
+    "calais" => 2,
+    "calamine" => 5,
+    "calamities" => 1,
+    "calamity" => 1,
+    "calculate" => 4,
+    "calculated" => 20,
+    "calculates" => 4,
+    "calculating" => 21,
+    "calculation" => 5,
+    "calculations" => 4,
+    "calculator" => 1,
+    "calculi" => 1,
+    "calcutta" => 2,
+    "calendar" => 5,
+    "calendars" => 5,
+    "calf" => 1,
+    "calgary" => 2,
+    "calibration" => 5,
+    "calibre" => 1,
+    "california" => 2,
+    "californian" => 17,
+    "call" => 5,
+    "callable" => 17,
+    "callback" => 1,
+    "called" => 20,
+    "caller" => 1,
+    "callers" => 1,
+    "calling" => 21,
+    "callous" => 21,
+    "calls" => 5,
+    "calm" => 21,
+    "calmed" => 20,
+    "calmer" => 17,
+    "calmly" => 32,
+    "calmness" => 21,
+    "calves" => 4,
+    "calvin" => 2,
+    "cam" => 7,
+    "cambrian" => 19,
+    "cambric" => 1,
+    "cambridge" => 2,
+    "came" => 69,
+    "camel" => 19,
+    "camellia" => 1,
+    "camellias" => 1,
+    "camera" => 1,
+    "cameras" => 1,
+    "camp" => 21,
+    "campaign" => 5,
+    "campaigner" => 1,
+    "campbell" => 3,
+    "campus" => 5,
+    "campuses" => 5,
+    "can" => 11,
+    "can't" => 8,
+    "canada" => 3,
+    "canadian" => 19,
+    "canal" => 5,
+    "canaries" => 7,
+    "canary" => 21,
+    "canberra" => 2,
+    "cancel" => 5,
+    "canceled" => 20,
+    "canceling" => 21,
+    "cancellation" => 1,
+    "cancelled" => 20,
+    "cancelling" => 4,
+    "cancels" => 5,
+    "cancer" => 19,
+    "candid" => 17,
+    "candidate" => 5,
+    "candidates" => 5,
+    "candle" => 5,
+    "candles" => 5,
+    "candour" => 1,
+    "cane" => 5,
+    "canes" => 5,
+    "canister" => 5,
+    "canisters" => 5,
+    "cannes" => 2,
+    "cannibals" => 1,
+    "cannon" => 23,
+    "cannot" => 5,
+    "canon" => 19,
+    "canonical" => 17,
+    "canopied" => 20,
+    "canopy" => 5,
+    "cans" => 1,
+    "canst" => 4,
+    "canterbury" => 2,
+    "canton" => 7,
+    "canvas" => 5,
+    "canvassed" => 20,
+    "cap" => 7,
+    "capabilities" => 1,
+    "capability" => 1,
+    "capable" => 21,
+    "capacious" => 16,
+    "capacities" => 1,
+    "capacity" => 17,
+    "caparisoned" => 20,
+    "cape" => 5,
+    "capering" => 21,
+    "capes" => 5,
+    "capital" => 17,
+    "capitalists" => 1,
+    "capitalization" => 1,
+    "capitalize" => 4,
+    "capitalized" => 20,
+    "capitalizes" => 4,
+    "capitalizing" => 21,
+    "capitals" => 1,
+    "capitation" => 1,
+    "capitulated" => 20,
+    "capped" => 20,
+    "capping" => 5,
+    "caprice" => 1,
+    "caprices" => 1,
+    "capricious" => 16,
+    "capriciously" => 32,
+    "caps" => 5,
+    "capstone" => 5,
+    "capsules" => 5,
+    "captain" => 5,
+    "caption" => 5,
+    "captive" => 21,
+    "captives" => 5,
+    "captivity" => 1,
+    "captors" => 1,
+    "capture" => 5,
+    "captured" => 20,
+    "captures" => 5,
+    "capturing" => 21,
+    "car" => 1,
+    "caramel" => 21,
+    "caravansary" => 1,
+    "carbon" => 5,
+    "carbonized" => 20,
+    "carbuncle" => 1,
+    "card" => 5,
+    "cardboard" => 17,
+    "cardiff" => 2,
+    "cardinal" => 17,
+    "cardinality" => 1,
+    "cards" => 5,
+    "care" => 5,
+    "cared" => 16,
+    "career" => 21,
+    "careers" => 5,
+    "careful" => 16,
+    "carefully" => 32,
+    "careless" => 16,
+    "carelessly" => 32,
+    "carelessness" => 17,
+    "cares" => 5,
+    "caress" => 5,
+    "caressed" => 20,
+    "caresses" => 5,
+    "caressing" => 21,
+    "caret" => 1,
+    "caretaker" => 17,
+    "cargo" => 5,
+    "caribbean" => 19,
+    "caricature" => 21,
+    "caring" => 21,
+    "carl" => 3,
+    "carnage" => 1,
+    "carnegie" => 2,
+    "carnival" => 5,
+    "carol" => 7,
+    "carolina" => 2,
+    "caroline" => 19,
+    "carousing" => 21,
+    "carpenter" => 7,
+    "carpet" => 5,
+    "carpeted" => 20,
+    "carpeting" => 21,
+    "carpets" => 5,
+    "carriage" => 1,
+    "carriages" => 1,
+    "carried" => 20,
+    "carrier" => 3,
+    "carriers" => 5,
+    "carries" => 5,
+    "carroll" => 2,
+    "carry" => 5,
+    "carrying" => 21,
+    "cars" => 1,
+    "carson" => 2,
+    "cart" => 5,
+    "carter" => 3,
+    "cartesian" => 17,
+    "carthage" => 2,
+    "carton" => 5,
+    "cartoons" => 5,
+    "carts" => 5,
+    "cartwheels" => 5,
+    "carve" => 5,
+    "carved" => 20,
+    "carver" => 3,
+    "carving" => 21,
+    "cascade" => 5,
+    "cascades" => 7,
+    "cascading" => 21,
+    "case" => 7,
+    "cased" => 20,
+    "casement" => 5,
+    "cases" => 5,
+    "cash" => 23,
+    "cashier" => 5,
+    "cashmere" => 1,
+    "casing" => 21,
+    "casino" => 1,
+    "casket" => 5,
+    "cassowary" => 1,
+    "cast" => 21,
+    "castaway" => 17,
+    "castilian" => 19,
+    "casting" => 21,
+    "castle" => 5,
+    "castles" => 5,
+    "casts" => 5,
+    "casual" => 17,
+    "casually" => 32,
+    "casualness" => 17,
+    "casualties" => 1,
+    "cat" => 21,
+    "cat's" => 1,
+    "catalan" => 19,
+    "catalog" => 5,
+    "catalogue" => 5,
+    "catalogued" => 20,
+    "catalogues" => 5,
+    "cataloguing" => 21,
+    "catalyst" => 1,
+    "catalytic" => 16,
+    "catalyze" => 4,
+    "catalyzed" => 20,
+    "catapult" => 5,
+    "cataract" => 1,
+    "cataracts" => 1,
+    "catastrophe" => 1,
+    "catastrophic" => 16,
+    "catbird" => 1,
+    "catch" => 5,
+    "catcher" => 1,
+    "catches" => 5,
+    "catching" => 21,
+    "catchwords" => 1,
+    "catechism" => 1,
+    "categorical" => 17,
+    "categorically" => 32,
+    "categories" => 1,
+    "categorization" => 1,
+    "categorized" => 20,
+    "categorizes" => 4,
+    "categorizing" => 21,
+    "category" => 1,
+    "cater" => 5,
+    "caterers" => 5,
+    "catering" => 21,
+    "caterpillar" => 5,
+    "caters" => 5,
+    "caterwauling" => 21,
+    "catfish" => 5,
+    "cathedral" => 19,
+    "catherine" => 2,
+    "catholic" => 17,
+    "catlike" => 16,
+    "cats" => 5,
+    "cattle" => 5,
+    "caucus" => 5,
+    "caught" => 20,
+    "cauldron" => 1,
+    "causal" => 17,
+    "causality" => 1,
+    "causation" => 1,
+    "cause" => 517,
+    "caused" => 20,
+    "causes" => 5,
+    "causing" => 21,
+    "caution" => 5,
+    "cautioned" => 20,
+    "cautions" => 5,
+    "cautious" => 16,
+    "cautiously" => 32,
+    "cavalier" => 21,
+    "cave" => 5,
+    "caveat" => 5,
+    "caveats" => 5,
+    "caved" => 20,
+    "cavern" => 5,
+    "caves" => 5,
+    "caw" => 5,
+    "caxton" => 3,
+    "cdt" => 1,
+    "cease" => 5,
+    "ceased" => 20,
+    "ceaseless" => 16,
+    "ceaselessly" => 32,
+    "ceases" => 5,
+    "ceasing" => 21,
+    "cecil" => 2,
+    "cedar" => 1,
+    "cedars" => 1,
+    "cedilla" => 1,
+    "ceiling" => 5,
+    "celebrate" => 4,
+    "celebrated" => 20,
+    "celebrates" => 4,
+    "celebrating" => 21,
+    "celebration" => 5,
+    "celebrities" => 1,
+    "celebrity" => 1,
+    "celerity" => 1,
+    "celestial" => 17,
+    "cell" => 5,
+    "cellar" => 5,
+    "cellars" => 5,
+    "cells" => 5,
+    "cellular" => 17,
+    "celsius" => 16,
+    "celtic" => 18,
+    "cement" => 5,
+    "cemetery" => 1,
+    "censor" => 5,
+    "censorship" => 1,
+    "census" => 5,
+    "cent" => 1,
+    "center" => 21,
+    "centered" => 20,
+    "centering" => 21,
+    "centerpiece" => 1,
+    "centers" => 1,
+    "central" => 19,
+    "centralised" => 20,
+    "centralization" => 1,
+    "centralize" => 4,
+    "centralized" => 20,
+    "centralizing" => 21,
+    "centre" => 5,
+    "centred" => 20,
+    "centres" => 5,
+    "centric" => 16,
+    "centrifugal" => 17,
+    "centroid" => 1,
+    "cents" => 1,
+    "centuries" => 1,
+    "century" => 1,
+    "cereals" => 1,
+    "ceremonies" => 1,
+    "ceremoniously" => 32,
+    "ceremony" => 1,
+    "cert" => 17,
+    "certain" => 272,
+    "certainly" => 32,
+    "certainty" => 1,
+    "certificate" => 5,
+    "certificates" => 5,
+    "certification" => 5,
+    "certified" => 20,
+    "certify" => 4,
+    "cession" => 1,
+    "ceylon" => 3,
+    "ceylonese" => 17,
+    "chad" => 3,
+    "chafed" => 20,
+    "chaff" => 5,
+    "chaffed" => 20,
+    "chaffing" => 21,
+    "chagrin" => 21,
+    "chagrined" => 20,
+    "chain" => 5,
+    "chained" => 20,
+    "chaining" => 21,
+    "chains" => 5,
+    "chair" => 5,
+    "chairman" => 5,
+    "chairs" => 5,
+    "chaise" => 1,
+    "chalk" => 5,
+    "challenge" => 5,
+    "challenged" => 20,
+    "challenges" => 5,
+    "challenging" => 21,
+    "chamber" => 5,
+    "chamberlain" => 3,
+    "chambermaids" => 1,
+    "chambers" => 7,
+    "chamois" => 21,
+    "champagne" => 21,
+    "champion" => 21,
+    "champions" => 5,
+    "champs" => 5,
+    "chance" => 23,
+    "chanced" => 20,
+    "chancery" => 1,
+    "chances" => 5,
+    "chandelier" => 1,
+    "change" => 5,
+    "changeable" => 16,
+    "changed" => 20,
+    "changes" => 5,
+    "changing" => 21,
+    "channel" => 5,
+    "channels" => 5,
+    "chaos" => 1,
+    "chap" => 5,
+    "chaps" => 5,
+    "chapter" => 5,
+    "chapters" => 5,
+    "char" => 5,
+    "character" => 5,
+    "characterise" => 4,
+    "characterised" => 20,
+    "characterises" => 4,
+    "characteristic" => 17,
+    "characteristics" => 1,
+    "characterization" => 1,
+    "characterize" => 4,
+    "characterized" => 20,
+    "characterizing" => 21,
+    "characters" => 5,
+    "charcoal" => 21,
+    "charge" => 5,
+    "charged" => 20,
+    "charges" => 5,
+    "charging" => 21,
+    "charities" => 1,
+    "charity" => 3,
+    "charles" => 2,
+    "charlie" => 3,
+    "charm" => 5,
+    "charmed" => 20,
+    "charming" => 21,
+    "charms" => 5,
+    "charred" => 20,
+    "chars" => 5,
+    "chart" => 5,
+    "charter" => 21,
+    "chartered" => 20,
+    "chartreuse" => 17,
+    "charts" => 5,
+    "chary" => 16,
+    "chase" => 7,
+    "chased" => 20,
+    "chasing" => 21,
+    "chasm" => 1,
+    "chasms" => 1,
+    "chastened" => 20,
+    "chastise" => 4,
+    "chat" => 5,
+    "chatted" => 4,
+    "chatter" => 5,
+    "chattered" => 20,
+    "chattering" => 21,
+    "chatting" => 5,
+    "chaucer" => 2,
+    "chauffeur" => 5,
+    "chauffeurs" => 5,
+    "cheap" => 21,
+    "cheapened" => 20,
+    "cheaper" => 17,
+    "cheapest" => 16,
+    "cheaply" => 32,
+    "cheat" => 5,
+    "cheated" => 20,
+    "cheating" => 21,
+    "cheats" => 5,
+    "check" => 21,
+    "checked" => 20,
+    "checker" => 5,
+    "checkered" => 20,
+    "checkers" => 5,
+    "checking" => 21,
+    "checklist" => 5,
+    "checkmate" => 5,
+    "checkout" => 1,
+    "checkouts" => 1,
+    "checkpointing" => 21,
+    "checkpoints" => 5,
+    "checks" => 5,
+    "checksum" => 5,
+    "checksums" => 5,
+    "cheek" => 5,
+    "cheekbone" => 1,
+    "cheekbones" => 1,
+    "cheeked" => 20,
+    "cheeks" => 5,
+    "cheer" => 5,
+    "cheered" => 20,
+    "cheerful" => 16,
+    "cheerfully" => 32,
+    "cheerfulness" => 17,
+    "cheerily" => 32,
+    "cheeriness" => 17,
+    "cheering" => 21,
+    "cheerless" => 16,
+    "cheers" => 5,
+    "cheery" => 16,
+    "cheese" => 5,
+    "cheetah" => 1,
+    "chef" => 5,
+    "chefs" => 5,
+    "chemical" => 17,
+    "chemically" => 32,
+    "chemist" => 1,
+    "chemistry" => 1,
+    "chemists" => 1,
+    "cheque" => 17,
+    "cheques" => 1,
+    "cherish" => 4,
+    "cherished" => 20,
+    "cherry" => 19,
+    "cherub" => 1,
+    "cheshire" => 2,
+    "chess" => 1,
+    "chest" => 261,
+    "chester" => 3,
+    "chesterfield" => 3,
+    "chestnut" => 17,
+    "chests" => 5,
+    "chew" => 5,
+    "chewed" => 20,
+    "chewing" => 21,
+    "chews" => 5,
+    "chi" => 3,
+    "chic" => 17,
+    "chicago" => 2,
+    "chickadee" => 1,
+    "chicken" => 21,
+    "chief" => 21,
+    "chiefly" => 32,
+    "chiefs" => 5,
+    "chiffon" => 1,
+    "child" => 5,
+    "child's" => 1,
+    "childhood" => 1,
+    "childish" => 16,
+    "childless" => 16,
+    "children" => 1,
+    "chili" => 1,
+    "chill" => 21,
+    "chilled" => 20,
+    "chillier" => 17,
+    "chilly" => 17,
+    "chimed" => 20,
+    "chimera" => 3,
+    "chimeras" => 3,
+    "chimerical" => 16,
+    "chimney" => 5,
+    "chimneys" => 5,
+    "chin" => 7,
+    "china" => 3,
+    "chinchilla" => 1,
+    "chine" => 5,
+    "chinese" => 19,
+    "chink" => 5,
+    "chinks" => 5,
+    "chins" => 5,
+    "chip" => 5,
+    "chipped" => 20,
+    "chips" => 5,
+    "chirp" => 5,
+    "chirped" => 20,
+    "chirping" => 21,
+    "chisel" => 5,
+    "chivalrous" => 16,
+    "chivalry" => 1,
+    "chlorine" => 1,
+    "choice" => 17,
+    "choices" => 1,
+    "choicest" => 16,
+    "choir" => 5,
+    "choke" => 5,
+    "choked" => 20,
+    "choking" => 21,
+    "cholera" => 1,
+    "chomp" => 5,
+    "choose" => 517,
+    "chooser" => 1,
+    "choosers" => 517,
+    "chooses" => 5,
+    "choosing" => 21,
+    "chop" => 5,
+    "chopped" => 20,
+    "chopping" => 21,
+    "chord" => 5,
+    "chords" => 5,
+    "chore" => 5,
+    "chores" => 5,
+    "chorus" => 5,
+    "chose" => 5,
+    "chosen" => 21,
+    "chow" => 5,
+    "chris" => 2,
+    "christ" => 3,
+    "christendom" => 1,
+    "christian" => 19,
+    "christianity" => 2,
+    "christians" => 1,
+    "christmas" => 23,
+    "christopher" => 2,
+    "chrome" => 5,
+    "chromes" => 5,
+    "chromium" => 1,
+    "chronic" => 17,
+    "chronicle" => 5,
+    "chronicled" => 20,
+    "chronicler" => 1,
+    "chronological" => 16,
+    "chronometer" => 1,
+    "chronometers" => 1,
+    "chrysalis" => 5,
+    "chuck" => 7,
+    "chucked" => 20,
+    "chuckled" => 20,
+    "chuckling" => 21,
+    "chunk" => 5,
+    "chunked" => 20,
+    "chunking" => 21,
+    "chunks" => 5,
+    "church" => 7,
+    "churches" => 5,
+    "churchyard" => 1,
+    "churn" => 5,
+    "cicada" => 1,
+    "cid" => 3,
+    "cigar" => 1,
+    "cigarette" => 5,
+    "cigarettes" => 5,
+    "cigars" => 1,
+    "cinder" => 5,
+    "cinnamon" => 17,
+    "cipher" => 5,
+    "ciphered" => 20,
+    "ciphers" => 5,
+    "circa" => 64,
+    "circle" => 5,
+    "circled" => 20,
+    "circles" => 5,
+    "circling" => 21,
+    "circuit" => 5,
+    "circuited" => 20,
+    "circuiting" => 21,
+    "circuits" => 5,
+    "circular" => 21,
+    "circularly" => 32,
+    "circulate" => 4,
+    "circulates" => 4,
+    "circulating" => 21,
+    "circulation" => 1,
+    "circumference" => 5,
+    "circumflex" => 21,
+    "circumspect" => 16,
+    "circumstance" => 5,
+    "circumstances" => 5,
+    "circumstantial" => 17,
+    "circumvent" => 4,
+    "circumvented" => 20,
+    "circumventing" => 21,
+    "circumvents" => 4,
+    "circus" => 5,
+    "circuses" => 5,
+    "cirrus" => 1,
+    "cisterns" => 1,
+    "citadel" => 1,
+    "citation" => 1,
+    "citations" => 1,
+    "cite" => 5,
+    "cited" => 20,
+    "cites" => 5,
+    "cities" => 1,
+    "citified" => 20,
+    "citing" => 21,
+    "citizen" => 1,
+    "citizens" => 1,
+    "citrus" => 17,
+    "city" => 1,
+    "civet" => 1,
+    "civil" => 16,
+    "civilians" => 1,
+    "civilisation" => 1,
+    "civilised" => 20,
+    "civilization" => 3,
+    "civilly" => 32,
+    "clack" => 5,
+    "clad" => 20,
+    "claim" => 5,
+    "claimed" => 20,
+    "claiming" => 21,
+    "claims" => 5,
+    "clairvoyant" => 17,
+    "clam" => 21,
+    "clambered" => 20,
+    "clambering" => 21,
+    "clammy" => 16,
+    "clamor" => 5,
+    "clamored" => 20,
+    "clamorous" => 16,
+    "clamour" => 5,
+    "clamped" => 20,
+    "clamping" => 21,
+    "clamps" => 5,
+    "clan" => 1,
+    "clandestinely" => 32,
+    "clang" => 5,
+    "clanging" => 21,
+    "clank" => 5,
+    "clanking" => 21,
+    "clap" => 5,
+    "clapped" => 20,
+    "clapping" => 5,
+    "claps" => 5,
+    "clare" => 3,
+    "clarence" => 2,
+    "claret" => 21,
+    "clarification" => 5,
+    "clarifications" => 4,
+    "clarified" => 20,
+    "clarifies" => 4,
+    "clarify" => 4,
+    "clarifying" => 21,
+    "clarity" => 1,
+    "clark" => 2,
+    "clash" => 5,
+    "clashed" => 20,
+    "clashes" => 5,
+    "clashing" => 21,
+    "clasp" => 5,
+    "clasped" => 20,
+    "clasping" => 21,
+    "class" => 21,
+    "classes" => 5,
+    "classic" => 17,
+    "classical" => 17,
+    "classicist" => 1,
+    "classicists" => 1,
+    "classics" => 1,
+    "classification" => 5,
+    "classifications" => 1,
+    "classified" => 21,
+    "classifier" => 1,
+    "classifies" => 4,
+    "classify" => 4,
+    "classifying" => 21,
+    "classroom" => 1,
+    "classrooms" => 1,
+    "clatter" => 5,
+    "clattered" => 20,
+    "claudia" => 2,
+    "clause" => 5,
+    "clauses" => 5,
+    "clawed" => 20,
+    "claws" => 5,
+    "clay" => 7,
+    "clean" => 21,
+    "cleaned" => 20,
+    "cleaner" => 17,
+    "cleanest" => 16,
+    "cleaning" => 21,
+    "cleanliness" => 49,
+    "cleanly" => 48,
+    "cleans" => 5,
+    "cleanup" => 1,
+    "cleanups" => 1,
+    "clear" => 21,
+    "clearance" => 1,
+    "cleared" => 20,
+    "clearer" => 17,
+    "clearest" => 16,
+    "clearing" => 21,
+    "clearings" => 21,
+    "clearly" => 32,
+    "clearness" => 21,
+    "clears" => 5,
+    "cleaver" => 1,
+    "clemency" => 1,
+    "clenched" => 20,
+    "clergy" => 1,
+    "clergyman" => 1,
+    "clerics" => 1,
+    "clerk" => 5,
+    "clerks" => 5,
+    "clever" => 16,
+    "cleverly" => 32,
+    "cleverness" => 17,
+    "clew" => 5,
+    "clews" => 5,
+    "click" => 5,
+    "clicked" => 20,
+    "clicking" => 21,
+    "clicks" => 5,
+    "client" => 1,
+    "clientele" => 1,
+    "clients" => 1,
+    "cliff" => 3,
+    "clifford" => 2,
+    "cliffs" => 1,
+    "climate" => 5,
+    "climax" => 5,
+    "climb" => 5,
+    "climbed" => 20,
+    "climbing" => 21,
+    "clime" => 5,
+    "climes" => 5,
+    "clinched" => 20,
+    "cline" => 2,
+    "cling" => 5,
+    "clinging" => 21,
+    "clings" => 5,
+    "clinic" => 1,
+    "clinical" => 17,
+    "clink" => 5,
+    "clinked" => 20,
+    "clip" => 5,
+    "clipboard" => 5,
+    "clipped" => 20,
+    "clipper" => 5,
+    "clippers" => 5,
+    "clipping" => 21,
+    "clippings" => 5,
+    "cloak" => 5,
+    "cloaks" => 5,
+    "clobber" => 5,
+    "clobbered" => 20,
+    "clobbering" => 21,
+    "clobbers" => 5,
+    "clock" => 5,
+    "clocks" => 5,
+    "clockwise" => 16,
+    "clockwork" => 17,
+    "clods" => 5,
+    "clog" => 5,
+    "clogging" => 5,
+    "clogs" => 5,
+    "clone" => 5,
+    "cloned" => 20,
+    "clones" => 5,
+    "cloning" => 21,
+    "close" => 21,
+    "closed" => 20,
+    "closely" => 32,
+    "closer" => 17,
+    "closes" => 5,
+    "closest" => 16,
+    "closet" => 21,
+    "closets" => 5,
+    "closing" => 21,
+    "closure" => 5,
+    "closures" => 5,
+    "cloth" => 1,
+    "clothe" => 4,
+    "clothed" => 20,
+    "clothes" => 5,
+    "clothing" => 21,
+    "cloud" => 5,
+    "clouded" => 20,
+    "clouding" => 21,
+    "cloudless" => 16,
+    "clouds" => 5,
+    "cloudy" => 16,
+    "clove" => 5,
+    "clover" => 1,
+    "cloves" => 5,
+    "clown" => 5,
+    "clowns" => 5,
+    "club" => 5,
+    "clubs" => 5,
+    "clue" => 5,
+    "clues" => 5,
+    "clump" => 5,
+    "clumps" => 5,
+    "clumsy" => 17,
+    "clung" => 20,
+    "cluster" => 5,
+    "clustered" => 20,
+    "clustering" => 21,
+    "clusters" => 5,
+    "clutched" => 20,
+    "clutches" => 5,
+    "clutching" => 21,
+    "clutter" => 5,
+    "cluttered" => 20,
+    "cluttering" => 21,
+    "clutters" => 5,
+    "clyde" => 2,
+    "coach" => 5,
+    "coaches" => 5,
+    "coachman" => 1,
+    "coal" => 21,
+    "coalesce" => 4,
+    "coalesced" => 20,
+    "coalescence" => 1,
+    "coalescing" => 21,
+    "coarse" => 16,
+    "coarsely" => 32,
+    "coarser" => 16,
+    "coarsest" => 16,
+    "coast" => 5,
+    "coasting" => 21,
+    "coasts" => 5,
+    "coat" => 5,
+    "coated" => 20,
+    "coats" => 5,
+    "coaxed" => 20,
+    "coaxing" => 21,
+    "cob" => 5,
+    "cobalt" => 1,
+    "cobbled" => 20,
+    "cobol" => 2,
+    "cobs" => 5,
+    "cobwebs" => 1,
+    "cocaine" => 1,
+    "cock" => 7,
+    "cocked" => 20,
+    "cocking" => 21,
+    "cockney" => 19,
+    "cockroaches" => 1,
+    "cocks" => 5,
+    "cocksure" => 16,
+    "cocktail" => 21,
+    "cocktails" => 5,
+    "cocoa" => 17,
+    "coconut" => 1,
+    "coda" => 1,
+    "codas" => 1,
+    "code" => 5,
+    "codebase" => 1,
+    "coded" => 20,
+    "coder" => 1,
+    "codes" => 5,
+    "codex" => 1,
+    "codifies" => 4,
+    "codify" => 4,
+    "coding" => 21,
+    "coefficient" => 17,
+    "coefficients" => 1,
+    "coerce" => 4,
+    "coerced" => 20,
+    "coercers" => 4,
+    "coerces" => 4,
+    "coercing" => 21,
+    "coercion" => 5,
+    "coercive" => 4,
+    "coexist" => 4,
+    "coexists" => 4,
+    "coffee" => 21,
+    "coffers" => 5,
+    "coffin" => 5,
+    "cogitating" => 21,
+    "cognitive" => 17,
+    "cognizant" => 16,
+    "cohabiting" => 21,
+    "cohen" => 2,
+    "cohere" => 4,
+    "coherence" => 1,
+    "coherent" => 16,
+    "cohesive" => 17,
+    "coil" => 5,
+    "coils" => 5,
+    "coin" => 5,
+    "coincide" => 4,
+    "coincided" => 20,
+    "coincidence" => 1,
+    "coincidences" => 1,
+    "coincident" => 17,
+    "coincidental" => 16,
+    "coincides" => 4,
+    "coinciding" => 21,
+    "coined" => 20,
+    "coins" => 5,
+    "col" => 3,
+    "cold" => 17,
+    "colder" => 16,
+    "coldly" => 32,
+    "coldness" => 17,
+    "cole" => 2,
+    "colicky" => 16,
+    "colin" => 2,
+    "collaborate" => 4,
+    "collaborated" => 20,
+    "collaborating" => 21,
+    "collaboration" => 5,
+    "collaborative" => 21,
+    "collaborator" => 1,
+    "collaborators" => 1,
+    "collapse" => 5,
+    "collapsed" => 20,
+    "collapses" => 5,
+    "collapsing" => 21,
+    "collar" => 5,
+    "collaring" => 21,
+    "collate" => 4,
+    "collated" => 20,
+    "collateral" => 17,
+    "collating" => 21,
+    "collation" => 5,
+    "collator" => 1,
+    "colleague" => 5,
+    "colleagues" => 5,
+    "collect" => 21,
+    "collectable" => 17,
+    "collected" => 20,
+    "collecting" => 21,
+    "collection" => 1,
+    "collections" => 1,
+    "collective" => 21,
+    "collectively" => 32,
+    "collectives" => 1,
+    "collector" => 1,
+    "collectors" => 1,
+    "collects" => 5,
+    "college" => 1,
+    "colleges" => 1,
+    "collide" => 4,
+    "collides" => 4,
+    "colliding" => 21,
+    "collins" => 3,
+    "collision" => 1,
+    "collisions" => 1,
+    "colloquially" => 32,
+    "colloquy" => 5,
+    "cologne" => 3,
+    "colombo" => 2,
+    "colon" => 3,
+    "colonel" => 5,
+    "colonial" => 17,
+    "colonialism" => 1,
+    "colonies" => 5,
+    "colonisation" => 1,
+    "colonise" => 4,
+    "colonising" => 21,
+    "colonization" => 1,
+    "colons" => 1,
+    "colony" => 5,
+    "colophon" => 1,
+    "color" => 21,
+    "colorado" => 2,
+    "colored" => 21,
+    "colorful" => 16,
+    "coloring" => 21,
+    "colorize" => 4,
+    "colorized" => 20,
+    "colorizes" => 4,
+    "colorizing" => 21,
+    "colorless" => 16,
+    "colors" => 5,
+    "colossal" => 16,
+    "colour" => 21,
+    "coloured" => 21,
+    "colourful" => 16,
+    "colouring" => 21,
+    "colourless" => 16,
+    "colours" => 5,
+    "cols" => 1,
+    "colt" => 7,
+    "columbia" => 2,
+    "columbus" => 7,
+    "column" => 1,
+    "columnar" => 16,
+    "columned" => 16,
+    "columns" => 1,
+    "comb" => 5,
+    "combat" => 5,
+    "combatants" => 1,
+    "combed" => 20,
+    "combination" => 1,
+    "combinations" => 1,
+    "combine" => 5,
+    "combined" => 21,
+    "combines" => 5,
+    "combining" => 21,
+    "combo" => 5,
+    "combustibles" => 1,
+    "combustion" => 1,
+    "come" => 69,
+    "comely" => 20,
+    "comer" => 1,
+    "comes" => 5,
+    "comet" => 1,
+    "comfits" => 5,
+    "comfort" => 5,
+    "comfortable" => 17,
+    "comfortably" => 32,
+    "comforted" => 20,
+    "comforter" => 1,
+    "comforting" => 21,
+    "comfortless" => 16,
+    "comforts" => 5,
+    "comfy" => 16,
+    "comic" => 17,
+    "comical" => 16,
+    "coming" => 21,
+    "comma" => 5,
+    "command" => 5,
+    "commanded" => 20,
+    "commander" => 1,
+    "commanders" => 5,
+    "commanding" => 21,
+    "commands" => 5,
+    "commas" => 5,
+    "commence" => 4,
+    "commenced" => 20,
+    "commencement" => 5,
+    "commences" => 4,
+    "commendation" => 1,
+    "commended" => 20,
+    "commensurate" => 20,
+    "comment" => 21,
+    "commentary" => 1,
+    "commented" => 20,
+    "commenting" => 21,
+    "comments" => 5,
+    "commerce" => 5,
+    "commercial" => 17,
+    "commercially" => 32,
+    "commiserate" => 20,
+    "commission" => 5,
+    "commissionaire" => 1,
+    "commissioned" => 20,
+    "commissioner" => 1,
+    "commissions" => 5,
+    "commit" => 5,
+    "commitment" => 1,
+    "commitments" => 1,
+    "commits" => 5,
+    "committed" => 20,
+    "committee" => 1,
+    "committees" => 1,
+    "committing" => 5,
+    "commodity" => 1,
+    "commodore" => 3,
+    "common" => 21,
+    "commonly" => 32,
+    "commonplace" => 21,
+    "commonplaces" => 5,
+    "commons" => 7,
+    "commotion" => 1,
+    "communicate" => 4,
+    "communicated" => 20,
+    "communicates" => 4,
+    "communicating" => 21,
+    "communication" => 5,
+    "communications" => 5,
+    "communicative" => 20,
+    "communicator" => 1,
+    "communing" => 21,
+    "communion" => 7,
+    "communities" => 1,
+    "community" => 1,
+    "commutation" => 1,
+    "commutative" => 16,
+    "commuting" => 21,
+    "comp" => 21,
+    "compact" => 21,
+    "compacted" => 20,
+    "compaction" => 1,
+    "compactly" => 32,
+    "compactness" => 21,
+    "companies" => 5,
+    "companion" => 5,
+    "companions" => 5,
+    "companionship" => 1,
+    "company" => 5,
+    "comparability" => 1,
+    "comparable" => 21,
+    "comparably" => 32,
+    "comparative" => 17,
+    "comparatively" => 32,
+    "comparatives" => 1,
+    "compare" => 5,
+    "compared" => 20,
+    "compares" => 5,
+    "comparing" => 21,
+    "comparison" => 1,
+    "comparisons" => 1,
+    "compartmentalized" => 20,
+    "compartments" => 5,
+    "compass" => 5,
+    "compassed" => 20,
+    "compasses" => 5,
+    "compassion" => 5,
+    "compassionate" => 20,
+    "compatibility" => 1,
+    "compatible" => 17,
+    "compel" => 4,
+    "compelled" => 20,
+    "compelling" => 21,
+    "compendium" => 1,
+    "compensate" => 4,
+    "compensated" => 20,
+    "compensating" => 21,
+    "compensation" => 5,
+    "compensations" => 4,
+    "compete" => 4,
+    "competence" => 1,
+    "competent" => 16,
+    "competing" => 21,
+    "competition" => 1,
+    "competitive" => 16,
+    "competitor" => 1,
+    "competitors" => 1,
+    "compilation" => 1,
+    "compilations" => 1,
+    "compile" => 5,
+    "compiled" => 20,
+    "compiler" => 1,
+    "compilers" => 5,
+    "compiles" => 5,
+    "compiling" => 21,
+    "complacency" => 1,
+    "complacent" => 16,
+    "complain" => 4,
+    "complainants" => 1,
+    "complained" => 20,
+    "complaining" => 21,
+    "complains" => 4,
+    "complaint" => 1,
+    "complaints" => 1,
+    "complement" => 5,
+    "complementary" => 17,
+    "complemented" => 20,
+    "complements" => 5,
+    "complete" => 21,
+    "completed" => 20,
+    "completely" => 32,
+    "completeness" => 21,
+    "completes" => 5,
+    "completing" => 21,
+    "completion" => 21,
+    "completions" => 21,
+    "complex" => 21,
+    "complexion" => 5,
+    "complexioned" => 20,
+    "complexions" => 5,
+    "complexities" => 1,
+    "complexity" => 1,
+    "compliance" => 1,
+    "compliant" => 16,
+    "complicate" => 20,
+    "complicated" => 20,
+    "complicates" => 4,
+    "complicating" => 21,
+    "complication" => 5,
+    "complications" => 5,
+    "complicity" => 1,
+    "complied" => 20,
+    "complies" => 4,
+    "compliment" => 5,
+    "complimentary" => 16,
+    "complimented" => 20,
+    "compliments" => 5,
+    "comply" => 36,
+    "complying" => 21,
+    "component" => 17,
+    "components" => 1,
+    "compose" => 4,
+    "composed" => 20,
+    "composer" => 1,
+    "composers" => 1,
+    "composes" => 4,
+    "composing" => 21,
+    "composite" => 21,
+    "composites" => 5,
+    "compositing" => 21,
+    "composition" => 21,
+    "compositional" => 16,
+    "compositions" => 21,
+    "compositor" => 1,
+    "compositors" => 1,
+    "composure" => 1,
+    "compound" => 21,
+    "compounded" => 20,
+    "compounding" => 21,
+    "compounds" => 5,
+    "comprehend" => 4,
+    "comprehended" => 20,
+    "comprehensible" => 16,
+    "comprehension" => 1,
+    "comprehensive" => 17,
+    "comprehensively" => 32,
+    "comprehensiveness" => 17,
+    "compress" => 5,
+    "compressed" => 20,
+    "compresses" => 5,
+    "compressible" => 16,
+    "compressing" => 21,
+    "compression" => 1,
+    "compressor" => 1,
+    "comprise" => 4,
+    "comprised" => 20,
+    "comprises" => 4,
+    "comprising" => 21,
+    "compromise" => 5,
+    "compromised" => 20,
+    "compromises" => 5,
+    "compromising" => 21,
+    "compulsion" => 1,
+    "compulsory" => 17,
+    "compunction" => 1,
+    "computability" => 1,
+    "computable" => 16,
+    "computation" => 1,
+    "computational" => 16,
+    "computations" => 1,
+    "compute" => 5,
+    "computed" => 20,
+    "computer" => 5,
+    "computerization" => 1,
+    "computerized" => 20,
+    "computers" => 5,
+    "computes" => 5,
+    "computing" => 21,
+    "comrade" => 5,
+    "comrades" => 5,
+    "con" => 21,
+    "concatenate" => 20,
+    "concatenated" => 20,
+    "concatenates" => 4,
+    "concatenating" => 21,
+    "concatenation" => 21,
+    "conceal" => 4,
+    "concealable" => 4,
+    "concealed" => 20,
+    "concealing" => 21,
+    "concealment" => 5,
+    "concede" => 4,
+    "conceit" => 5,
+    "conceited" => 20,
+    "conceits" => 5,
+    "conceivable" => 20,
+    "conceivably" => 32,
+    "conceive" => 4,
+    "conceived" => 20,
+    "conceives" => 4,
+    "conceiving" => 21,
+    "concentrate" => 5,
+    "concentrated" => 20,
+    "concentrating" => 21,
+    "concentration" => 5,
+    "concept" => 5,
+    "conception" => 1,
+    "conceptions" => 1,
+    "concepts" => 5,
+    "conceptual" => 16,
+    "conceptualized" => 20,
+    "conceptually" => 32,
+    "concern" => 5,
+    "concerned" => 20,
+    "concerning" => 85,
+    "concerns" => 5,
+    "concert" => 5,
+    "concerts" => 5,
+    "concession" => 1,
+    "conciliating" => 21,
+    "concise" => 20,
+    "concisely" => 32,
+    "conciseness" => 21,
+    "conclude" => 4,
+    "concluded" => 20,
+    "concludes" => 4,
+    "concluding" => 21,
+    "conclusion" => 1,
+    "conclusions" => 1,
+    "conclusive" => 16,
+    "concocted" => 20,
+    "concordance" => 5,
+    "concordances" => 5,
+    "concrete" => 21,
+    "concretely" => 32,
+    "concur" => 4,
+    "concurred" => 4,
+    "concurrence" => 1,
+    "concurrency" => 1,
+    "concurrent" => 17,
+    "concurring" => 4,
+    "concussion" => 1,
+    "condemn" => 4,
+    "condemnation" => 1,
+    "condemned" => 20,
+    "condemns" => 4,
+    "condensed" => 20,
+    "condescend" => 5,
+    "condescending" => 20,
+    "condescension" => 1,
+    "condition" => 5,
+    "conditional" => 17,
+    "conditionally" => 32,
+    "conditionals" => 1,
+    "conditioned" => 20,
+    "conditioning" => 21,
+    "conditions" => 5,
+    "conduce" => 4,
+    "conducive" => 4,
+    "conduct" => 5,
+    "conducted" => 20,
+    "conducting" => 21,
+    "conductive" => 5,
+    "conductor" => 1,
+    "conductors" => 1,
+    "conduit" => 1,
+    "confederate" => 21,
+    "confederates" => 1,
+    "confederation" => 17,
+    "confer" => 4,
+    "conference" => 5,
+    "conferences" => 5,
+    "conferred" => 4,
+    "conferring" => 5,
+    "confers" => 4,
+    "confess" => 21,
+    "confessed" => 20,
+    "confession" => 1,
+    "confessor" => 1,
+    "confidant" => 1,
+    "confide" => 4,
+    "confided" => 20,
+    "confidence" => 1,
+    "confidences" => 1,
+    "confident" => 17,
+    "confidential" => 16,
+    "confidentiality" => 1,
+    "confidentially" => 32,
+    "confidently" => 32,
+    "configurability" => 1,
+    "configurable" => 4,
+    "configuration" => 1,
+    "configurations" => 1,
+    "configure" => 5,
+    "confine" => 21,
+    "confined" => 20,
+    "confinement" => 1,
+    "confirm" => 4,
+    "confirmation" => 1,
+    "confirmed" => 20,
+    "confirming" => 21,
+    "confirms" => 4,
+    "confiscated" => 20,
+    "conflagration" => 1,
+    "conflagrations" => 1,
+    "conflict" => 5,
+    "conflicted" => 20,
+    "conflicting" => 21,
+    "conflicts" => 5,
+    "conform" => 5,
+    "conformal" => 16,
+    "conformance" => 1,
+    "conformation" => 1,
+    "confound" => 5,
+    "confront" => 21,
+    "confucius" => 2,
+    "confusable" => 17,
+    "confuse" => 5,
+    "confused" => 20,
+    "confusedly" => 32,
+    "confusing" => 20,
+    "confusingly" => 32,
+    "confusion" => 5,
+    "congeal" => 4,
+    "congenial" => 16,
+    "conger" => 1,
+    "congestion" => 1,
+    "congratulate" => 4,
+    "congratulated" => 20,
+    "congratulating" => 21,
+    "congratulations" => 4,
+    "congratulatory" => 16,
+    "congregate" => 20,
+    "congregated" => 20,
+    "congregation" => 21,
+    "congress" => 7,
+    "congressional" => 16,
+    "conic" => 17,
+    "conjecture" => 5,
+    "conjectured" => 20,
+    "conjectures" => 5,
+    "conjugate" => 21,
+    "conjugates" => 5,
+    "conjugation" => 21,
+    "conjugations" => 21,
+    "conjunct" => 17,
+    "conjunction" => 5,
+    "conjure" => 5,
+    "conjured" => 20,
+    "conjuring" => 21,
+    "connect" => 5,
+    "connected" => 20,
+    "connecticut" => 2,
+    "connecting" => 21,
+    "connection" => 1,
+    "connections" => 1,
+    "connectivity" => 1,
+    "connector" => 1,
+    "connectors" => 1,
+    "connects" => 5,
+    "conned" => 4,
+    "connivance" => 1,
+    "conquer" => 4,
+    "conquered" => 20,
+    "conquering" => 21,
+    "conqueror" => 1,
+    "conquest" => 5,
+    "cons" => 5,
+    "conscience" => 5,
+    "conscientious" => 16,
+    "conscientiously" => 32,
+    "conscious" => 17,
+    "consciously" => 32,
+    "consciousness" => 17,
+    "consecrate" => 20,
+    "consecrated" => 20,
+    "consecutive" => 17,
+    "consecutively" => 32,
+    "consensus" => 5,
+    "consent" => 5,
+    "consented" => 20,
+    "consequence" => 5,
+    "consequences" => 5,
+    "consequent" => 17,
+    "consequential" => 16,
+    "consequently" => 32,
+    "conservancy" => 1,
+    "conservation" => 1,
+    "conservative" => 17,
+    "conservatively" => 32,
+    "conservatory" => 17,
+    "conserve" => 5,
+    "consider" => 4,
+    "considerable" => 17,
+    "considerably" => 32,
+    "considerate" => 20,
+    "considerateness" => 21,
+    "consideration" => 21,
+    "considerations" => 1,
+    "considered" => 20,
+    "considering" => 21,
+    "considers" => 4,
+    "consigned" => 20,
+    "consist" => 5,
+    "consisted" => 20,
+    "consistency" => 1,
+    "consistent" => 17,
+    "consistently" => 32,
+    "consisting" => 21,
+    "consists" => 5,
+    "consolation" => 1,
+    "console" => 21,
+    "consolidate" => 20,
+    "consolidated" => 20,
+    "consolidates" => 4,
+    "consolidating" => 21,
+    "consoling" => 21,
+    "consonant" => 17,
+    "consonants" => 1,
+    "consortium" => 1,
+    "conspicuous" => 16,
+    "conspicuously" => 32,
+    "conspicuousness" => 17,
+    "conspiracy" => 5,
+    "conspired" => 20,
+    "conspiring" => 21,
+    "const" => 17,
+    "constable" => 7,
+    "constables" => 5,
+    "constabulary" => 17,
+    "constant" => 17,
+    "constantinople" => 2,
+    "constantly" => 32,
+    "constants" => 1,
+    "consternation" => 1,
+    "constituent" => 17,
+    "constituents" => 1,
+    "constitute" => 5,
+    "constituted" => 20,
+    "constitutes" => 5,
+    "constituting" => 21,
+    "constitution" => 7,
+    "constitutional" => 17,
+    "constitutionally" => 32,
+    "constrain" => 5,
+    "constrained" => 20,
+    "constraint" => 1,
+    "constraints" => 1,
+    "construct" => 5,
+    "constructed" => 20,
+    "constructing" => 21,
+    "construction" => 1,
+    "constructions" => 1,
+    "constructive" => 21,
+    "constructor" => 1,
+    "constructors" => 1,
+    "constructs" => 5,
+    "construed" => 20,
+    "consul" => 1,
+    "consulate" => 1,
+    "consuls" => 1,
+    "consult" => 5,
+    "consultant" => 1,
+    "consultation" => 1,
+    "consultations" => 1,
+    "consultative" => 16,
+    "consulted" => 20,
+    "consulting" => 21,
+    "consults" => 5,
+    "consumable" => 21,
+    "consume" => 4,
+    "consumed" => 20,
+    "consumer" => 1,
+    "consumers" => 4,
+    "consumes" => 4,
+    "consuming" => 21,
+    "consummate" => 20,
+    "consummated" => 20,
+    "consummation" => 21,
+    "consumption" => 1,
+    "contact" => 5,
+    "contacted" => 20,
+    "contacting" => 21,
+    "contacts" => 5,
+    "contain" => 4,
+    "contained" => 20,
+    "container" => 1,
+    "containerized" => 20,
+    "containers" => 4,
+    "containing" => 21,
+    "containment" => 5,
+    "contains" => 4,
+    "contaminate" => 4,
+    "contaminates" => 4,
+    "contaminating" => 21,
+    "contamination" => 1,
+    "contemplate" => 4,
+    "contemplated" => 20,
+    "contemplating" => 21,
+    "contemplation" => 5,
+    "contemplative" => 21,
+    "contemporary" => 17,
+    "contempt" => 1,
+    "contemptible" => 16,
+    "contemptuous" => 16,
+    "contemptuously" => 32,
+    "contend" => 4,
+    "content" => 21,
+    "contented" => 20,
+    "contention" => 1,
+    "contentions" => 1,
+    "contentious" => 16,
+    "contentment" => 21,
+    "contents" => 5,
+    "contest" => 5,
+    "contested" => 20,
+    "contesting" => 21,
+    "context" => 5,
+    "contexts" => 1,
+    "contextual" => 16,
+    "contiguous" => 16,
+    "contiguously" => 32,
+    "continent" => 19,
+    "continental" => 17,
+    "continents" => 1,
+    "contingencies" => 1,
+    "contingency" => 1,
+    "continua" => 1,
+    "continual" => 16,
+    "continually" => 32,
+    "continuance" => 1,
+    "continuation" => 1,
+    "continuations" => 1,
+    "continue" => 5,
+    "continued" => 20,
+    "continues" => 5,
+    "continuing" => 21,
+    "continuity" => 1,
+    "continuous" => 16,
+    "continuously" => 32,
+    "continuum" => 1,
+    "contortions" => 1,
+    "contour" => 5,
+    "contra" => 69,
+    "contract" => 21,
+    "contracted" => 20,
+    "contracting" => 21,
+    "contraction" => 5,
+    "contractor" => 5,
+    "contractual" => 17,
+    "contradict" => 4,
+    "contradicted" => 20,
+    "contradicting" => 21,
+    "contradiction" => 1,
+    "contradictory" => 17,
+    "contradicts" => 4,
+    "contralto" => 1,
+    "contrary" => 21,
+    "contrast" => 5,
+    "contrasted" => 20,
+    "contrasting" => 21,
+    "contrasts" => 5,
+    "contribute" => 5,
+    "contributed" => 20,
+    "contributing" => 21,
+    "contribution" => 5,
+    "contributions" => 4,
+    "contributor" => 1,
+    "contributors" => 1,
+    "contrition" => 1,
+    "contrived" => 20,
+    "contriving" => 21,
+    "control" => 5,
+    "controllable" => 17,
+    "controlled" => 20,
+    "controller" => 1,
+    "controllers" => 1,
+    "controlling" => 21,
+    "controls" => 5,
+    "controversial" => 17,
+    "controversies" => 1,
+    "controversy" => 1,
+    "contumely" => 1,
+    "conundrum" => 1,
+    "conundrums" => 1,
+    "convalescence" => 1,
+    "convalescent" => 17,
+    "convene" => 4,
+    "convened" => 20,
+    "convenience" => 5,
+    "conveniences" => 5,
+    "convenient" => 16,
+    "conveniently" => 32,
+    "convent" => 5,
+    "convention" => 1,
+    "conventional" => 17,
+    "conventionally" => 32,
+    "conventions" => 1,
+    "converge" => 5,
+    "convergence" => 1,
+    "convergent" => 17,
+    "conversation" => 5,
+    "conversational" => 16,
+    "conversations" => 5,
+    "converse" => 21,
+    "conversely" => 32,
+    "conversion" => 5,
+    "convert" => 5,
+    "converted" => 20,
+    "converter" => 1,
+    "converters" => 1,
+    "convertibility" => 1,
+    "convertible" => 17,
+    "converting" => 21,
+    "converts" => 5,
+    "convex" => 21,
+    "convey" => 4,
+    "conveyance" => 5,
+    "conveyances" => 5,
+    "conveyed" => 20,
+    "conveying" => 21,
+    "conveys" => 4,
+    "convict" => 5,
+    "convicted" => 20,
+    "conviction" => 1,
+    "convince" => 4,
+    "convinced" => 20,
+    "convincing" => 21,
+    "convivial" => 16,
+    "convoluted" => 20,
+    "convolution" => 1,
+    "convolutions" => 1,
+    "convoy" => 5,
+    "convulse" => 4,
+    "convulsed" => 20,
+    "convulsion" => 5,
+    "convulsions" => 4,
+    "convulsive" => 20,
+    "convulsively" => 32,
+    "conway" => 2,
+    "cook" => 7,
+    "cookbook" => 1,
+    "cooked" => 20,
+    "cookie" => 5,
+    "cookies" => 5,
+    "cooking" => 21,
+    "cooks" => 5,
+    "cool" => 21,
+    "cooled" => 20,
+    "cooler" => 17,
+    "coolest" => 16,
+    "coolies" => 1,
+    "cooling" => 21,
+    "coolly" => 32,
+    "coolness" => 21,
+    "cooped" => 20,
+    "cooper" => 7,
+    "cooperate" => 4,
+    "cooperation" => 5,
+    "cooperative" => 21,
+    "cooperatively" => 32,
+    "coordinate" => 21,
+    "coordinated" => 20,
+    "coordinates" => 5,
+    "coordinating" => 21,
+    "coordination" => 21,
+    "coordinator" => 1,
+    "coordinators" => 1,
+    "coot" => 1,
+    "cope" => 5,
+    "copenhagen" => 3,
+    "copied" => 20,
+    "copier" => 1,
+    "copies" => 5,
+    "copilot" => 5,
+    "copper" => 21,
+    "coppers" => 5,
+    "copse" => 5,
+    "copses" => 5,
+    "copula" => 1,
+    "copy" => 5,
+    "copying" => 21,
+    "copyright" => 5,
+    "copyrighted" => 20,
+    "copyrights" => 5,
+    "coquettish" => 16,
+    "coral" => 17,
+    "cord" => 5,
+    "cordial" => 17,
+    "cordially" => 32,
+    "cordials" => 1,
+    "core" => 21,
+    "cores" => 5,
+    "corey" => 2,
+    "corkscrew" => 21,
+    "cormorants" => 1,
+    "corn" => 5,
+    "cornelius" => 2,
+    "cornell" => 2,
+    "corner" => 5,
+    "cornered" => 20,
+    "corners" => 5,
+    "cornets" => 1,
+    "cornices" => 5,
+    "cornish" => 19,
+    "cornwall" => 2,
+    "corollaries" => 1,
+    "corollary" => 17,
+    "coroner" => 1,
+    "coronet" => 1,
+    "coroutine" => 1,
+    "corpora" => 1,
+    "corporate" => 21,
+    "corporation" => 21,
+    "corporations" => 21,
+    "corps" => 1,
+    "corpse" => 5,
+    "corpses" => 1,
+    "corpulent" => 16,
+    "corpus" => 1,
+    "correct" => 21,
+    "corrected" => 20,
+    "correcting" => 21,
+    "correction" => 1,
+    "corrections" => 1,
+    "correctly" => 32,
+    "correctness" => 21,
+    "corrects" => 5,
+    "correlate" => 5,
+    "correlated" => 20,
+    "correlates" => 5,
+    "correlating" => 21,
+    "correlation" => 5,
+    "correspond" => 4,
+    "corresponded" => 20,
+    "correspondence" => 1,
+    "correspondent" => 17,
+    "corresponding" => 21,
+    "correspondingly" => 32,
+    "corresponds" => 4,
+    "corridor" => 1,
+    "corridors" => 1,
+    "corroborate" => 4,
+    "corroborated" => 20,
+    "corroborating" => 21,
+    "corroboration" => 5,
+    "corrugated" => 20,
+    "corrupt" => 20,
+    "corrupted" => 20,
+    "corrupting" => 21,
+    "corruption" => 1,
+    "corrupts" => 4,
+    "corsican" => 19,
+    "cortex" => 1,
+    "cos" => 769,
+    "cosh" => 21,
+    "cosily" => 32,
+    "cosine" => 1,
+    "cosiness" => 21,
+    "cosmetic" => 17,
+    "cosmic" => 16,
+    "cosmopolitan" => 17,
+    "cosmos" => 1,
+    "cost" => 5,
+    "costed" => 20,
+    "costing" => 21,
+    "costly" => 48,
+    "costs" => 5,
+    "costume" => 5,
+    "costumes" => 5,
+    "cosy" => 21,
+    "cot" => 1,
+    "cots" => 1,
+    "cottage" => 5,
+    "cottager" => 1,
+    "cottagers" => 5,
+    "cottages" => 5,
+    "cotton" => 23,
+    "couch" => 5,
+    "cough" => 5,
+    "could" => 9,
+    "couldn't" => 4,
+    "council" => 1,
+    "counsel" => 5,
+    "counseled" => 20,
+    "counsellor" => 1,
+    "counsellors" => 1,
+    "count" => 5,
+    "countable" => 17,
+    "countdown" => 5,
+    "counted" => 20,
+    "countenance" => 5,
+    "countenances" => 5,
+    "counter" => 21,
+    "counterbalanced" => 20,
+    "countered" => 4,
+    "counterfeit" => 21,
+    "counterfeited" => 20,
+    "counterfeiting" => 21,
+    "counterpart" => 5,
+    "counterparts" => 5,
+    "counters" => 5,
+    "countersign" => 5,
+    "countersigned" => 20,
+    "countess" => 1,
+    "counties" => 1,
+    "counting" => 21,
+    "countless" => 16,
+    "countries" => 1,
+    "country" => 17,
+    "countryman" => 1,
+    "countrymen" => 1,
+    "countryside" => 1,
+    "counts" => 5,
+    "county" => 17,
+    "couple" => 21,
+    "coupled" => 20,
+    "couples" => 5,
+    "coupling" => 21,
+    "courage" => 5,
+    "courageous" => 16,
+    "courageously" => 32,
+    "courgette" => 1,
+    "courier" => 5,
+    "course" => 5,
+    "coursed" => 20,
+    "courses" => 5,
+    "courseware" => 1,
+    "court" => 5,
+    "courted" => 20,
+    "courtesy" => 21,
+    "courtiers" => 1,
+    "courts" => 5,
+    "courtyard" => 1,
+    "cousin" => 5,
+    "cousins" => 5,
+    "covariance" => 1,
+    "covariant" => 17,
+    "covenant" => 5,
+    "coventry" => 2,
+    "cover" => 21,
+    "coverage" => 1,
+    "coveralls" => 1,
+    "covered" => 20,
+    "covering" => 21,
+    "coverlet" => 1,
+    "covers" => 5,
+    "covet" => 4,
+    "coveted" => 20,
+    "cow" => 5,
+    "coward" => 23,
+    "cowardice" => 1,
+    "cowardly" => 32,
+    "cowards" => 5,
+    "cowered" => 20,
+    "cowhide" => 5,
+    "coworker" => 1,
+    "coworkers" => 1,
+    "cows" => 5,
+    "cox" => 7,
+    "cpu" => 1,
+    "crab" => 5,
+    "crabbing" => 5,
+    "crack" => 21,
+    "cracked" => 20,
+    "crackers" => 21,
+    "cracking" => 21,
+    "crackling" => 21,
+    "cracks" => 5,
+    "cradles" => 5,
+    "craft" => 7,
+    "crafted" => 20,
+    "crafting" => 21,
+    "craggy" => 16,
+    "crags" => 1,
+    "craig" => 2,
+    "cram" => 5,
+    "cramp" => 21,
+    "cramped" => 20,
+    "crane" => 7,
+    "cranes" => 5,
+    "crank" => 21,
+    "cranking" => 21,
+    "crap" => 21,
+    "crash" => 21,
+    "crashed" => 20,
+    "crashes" => 5,
+    "crashing" => 21,
+    "crate" => 5,
+    "crater" => 7,
+    "crates" => 5,
+    "cravat" => 5,
+    "cravats" => 5,
+    "crave" => 5,
+    "craved" => 20,
+    "craving" => 21,
+    "cravings" => 5,
+    "crawl" => 5,
+    "crawled" => 20,
+    "crawler" => 1,
+    "crawling" => 21,
+    "crawls" => 5,
+    "cray" => 19,
+    "craze" => 5,
+    "crazed" => 20,
+    "crazy" => 17,
+    "creak" => 5,
+    "creaked" => 20,
+    "creaking" => 21,
+    "cream" => 21,
+    "creases" => 5,
+    "create" => 20,
+    "created" => 20,
+    "creates" => 4,
+    "creating" => 21,
+    "creation" => 21,
+    "creations" => 1,
+    "creative" => 21,
+    "creativity" => 1,
+    "creator" => 3,
+    "creators" => 1,
+    "creature" => 1,
+    "creatures" => 1,
+    "credential" => 21,
+    "credentials" => 5,
+    "credibility" => 1,
+    "credible" => 16,
+    "credit" => 5,
+    "creditable" => 5,
+    "credited" => 20,
+    "creditor" => 1,
+    "creditors" => 1,
+    "credits" => 5,
+    "credulity" => 1,
+    "credulous" => 16,
+    "creek" => 19,
+    "creeks" => 1,
+    "creep" => 5,
+    "creeping" => 21,
+    "creole" => 19,
+    "crept" => 4,
+    "crescendo" => 5,
+    "crescent" => 21,
+    "crest" => 7,
+    "crestfallen" => 16,
+    "crevice" => 5,
+    "crevices" => 5,
+    "crew" => 5,
+    "crib" => 5,
+    "crichton" => 2,
+    "cricket" => 5,
+    "crickets" => 5,
+    "cried" => 20,
+    "cries" => 5,
+    "crime" => 5,
+    "crimes" => 5,
+    "criminal" => 17,
+    "criminality" => 1,
+    "criminals" => 1,
+    "crimson" => 21,
+    "cringe" => 21,
+    "cringing" => 21,
+    "crinkled" => 20,
+    "cripple" => 21,
+    "crippled" => 20,
+    "cripples" => 5,
+    "crises" => 1,
+    "crisis" => 1,
+    "crisp" => 21,
+    "crisply" => 32,
+    "criteria" => 1,
+    "criterion" => 1,
+    "critical" => 17,
+    "criticality" => 1,
+    "critically" => 32,
+    "criticised" => 20,
+    "criticising" => 21,
+    "criticism" => 1,
+    "criticized" => 20,
+    "critics" => 5,
+    "critique" => 5,
+    "critiques" => 5,
+    "croak" => 5,
+    "croaking" => 21,
+    "croatian" => 19,
+    "crocodile" => 5,
+    "crocuses" => 1,
+    "crony" => 1,
+    "crooked" => 20,
+    "crookedness" => 21,
+    "crooned" => 20,
+    "crop" => 5,
+    "cropped" => 20,
+    "crops" => 5,
+    "croquet" => 5,
+    "cross" => 87,
+    "crossbar" => 5,
+    "crossbeam" => 1,
+    "crossbones" => 1,
+    "crossed" => 20,
+    "crosses" => 5,
+    "crosshatch" => 5,
+    "crossing" => 21,
+    "crossly" => 32,
+    "crossover" => 17,
+    "crossroads" => 1,
+    "crouched" => 20,
+    "crouching" => 21,
+    "crow" => 23,
+    "crowd" => 5,
+    "crowded" => 20,
+    "crowding" => 21,
+    "crowds" => 5,
+    "crown" => 21,
+    "crowned" => 20,
+    "crowns" => 5,
+    "crows" => 5,
+    "crt" => 1,
+    "crucial" => 16,
+    "crucially" => 32,
+    "crucible" => 1,
+    "crucified" => 20,
+    "crud" => 5,
+    "crude" => 17,
+    "crudely" => 32,
+    "crudest" => 16,
+    "cruel" => 21,
+    "cruellest" => 16,
+    "cruelly" => 32,
+    "cruelty" => 1,
+    "crumbled" => 20,
+    "crumbling" => 21,
+    "crumbly" => 49,
+    "crumbs" => 5,
+    "crumpled" => 20,
+    "crunch" => 5,
+    "crunched" => 20,
+    "crunching" => 21,
+    "crunchy" => 17,
+    "crusades" => 5,
+    "crush" => 5,
+    "crushed" => 20,
+    "crushing" => 21,
+    "crusoe" => 2,
+    "crust" => 5,
+    "crusted" => 20,
+    "crusts" => 5,
+    "crusty" => 17,
+    "crux" => 3,
+    "cry" => 5,
+    "crying" => 21,
+    "crypt" => 1,
+    "cryptanalysis" => 1,
+    "cryptic" => 17,
+    "crypto" => 1,
+    "cryptographer" => 1,
+    "cryptographers" => 1,
+    "cryptographic" => 16,
+    "cryptography" => 1,
+    "crystal" => 19,
+    "crystallised" => 20,
+    "crystals" => 1,
+    "cub" => 5,
+    "cube" => 21,
+    "cubes" => 5,
+    "cubic" => 17,
+    "cuboids" => 1,
+    "cuckoo" => 21,
+    "cucumber" => 1,
+    "cud" => 5,
+    "cudgelled" => 4,
+    "cue" => 5,
+    "cuff" => 5,
+    "cuffed" => 20,
+    "cuffing" => 21,
+    "cuffs" => 5,
+    "cull" => 5,
+    "culled" => 20,
+    "culminated" => 20,
+    "culmination" => 21,
+    "culprit" => 1,
+    "cultivate" => 4,
+    "cultivated" => 20,
+    "cultivating" => 21,
+    "cultivation" => 5,
+    "cultural" => 16,
+    "culturally" => 32,
+    "culture" => 5,
+    "cultured" => 20,
+    "cultures" => 5,
+    "culverts" => 5,
+    "cum" => 85,
+    "cumberland" => 2,
+    "cumbersome" => 16,
+    "cumbrous" => 16,
+    "cumulative" => 16,
+    "cunard" => 2,
+    "cunning" => 17,
+    "cup" => 5,
+    "cupboard" => 5,
+    "cupboards" => 5,
+    "cups" => 5,
+    "cur" => 1,
+    "curators" => 1,
+    "curb" => 5,
+    "curbed" => 20,
+    "curdles" => 4,
+    "cure" => 5,
+    "cured" => 20,
+    "curiosities" => 1,
+    "curiosity" => 1,
+    "curious" => 16,
+    "curiously" => 32,
+    "curl" => 5,
+    "curled" => 20,
+    "curling" => 21,
+    "curls" => 5,
+    "curly" => 49,
+    "currants" => 1,
+    "currencies" => 1,
+    "currency" => 1,
+    "current" => 17,
+    "currently" => 32,
+    "currents" => 1,
+    "curricula" => 1,
+    "curricular" => 16,
+    "curriculum" => 1,
+    "curried" => 20,
+    "curry" => 7,
+    "currying" => 21,
+    "curs" => 1,
+    "curse" => 5,
+    "cursed" => 20,
+    "curses" => 5,
+    "cursing" => 21,
+    "cursive" => 21,
+    "cursor" => 5,
+    "cursors" => 5,
+    "cursory" => 16,
+    "curt" => 22,
+    "curtail" => 5,
+    "curtain" => 5,
+    "curtained" => 20,
+    "curtains" => 5,
+    "curtsy" => 5,
+    "curve" => 21,
+    "curved" => 20,
+    "curves" => 5,
+    "curving" => 21,
+    "cushion" => 5,
+    "cushioned" => 20,
+    "cushions" => 5,
+    "cuss" => 5,
+    "cussed" => 20,
+    "custard" => 1,
+    "custodian" => 1,
+    "custody" => 1,
+    "custom" => 21,
+    "customarily" => 32,
+    "customary" => 17,
+    "customer" => 1,
+    "customers" => 21,
+    "customise" => 4,
+    "customised" => 20,
+    "customising" => 21,
+    "customizable" => 16,
+    "customization" => 1,
+    "customizations" => 1,
+    "customize" => 4,
+    "customized" => 20,
+    "customizes" => 4,
+    "customizing" => 21,
+    "customs" => 5,
+    "cut" => 21,
+    "cute" => 16,
+    "cutlass" => 5,
+    "cutler" => 1,
+    "cutoff" => 17,
+    "cuts" => 5,
+    "cutter" => 1,
+    "cutting" => 21,
+    "cuttings" => 5,
+    "cwt" => 1,
+    "cyan" => 17,
+    "cybernetics" => 17,
+    "cycle" => 5,
+    "cycles" => 5,
+    "cyclic" => 17,
+    "cyclical" => 17,
+    "cyclically" => 32,
+    "cycling" => 21,
+    "cyclone" => 5,
+    "cylinder" => 5,
+    "cylinders" => 5,
+    "cylindrical" => 16,
+    "cymbals" => 1,
+    "cynical" => 16,
+    "cynically" => 32,
+    "cypher" => 3,
+    "cypress" => 1,
+    "cyril" => 2,
+    "cyrillic" => 18,
+    "czech" => 19,
+    "dabble" => 5,
+    "dabbled" => 20,
+    "dad" => 5,
+    "daddy" => 21,
+    "daemon" => 1,
+    "daemons" => 1,
+    "dagger" => 5,
+    "daily" => 21,
+    "dainties" => 1,
+    "daintiest" => 16,
+    "daintily" => 32,
+    "dainty" => 17,
+    "dairy" => 17,
+    "daisies" => 1,
+    "daisy" => 3,
+    "dakota" => 3,
+    "dale" => 3,
+    "dalton" => 3,
+    "dam" => 21,
+    "damage" => 5,
+    "damaged" => 20,
+    "damages" => 5,
+    "damaging" => 21,
+    "dames" => 5,
+    "dammit" => 1,
+    "damn" => 21,
+    "damned" => 20,
+    "damning" => 21,
+    "damp" => 21,
+    "dampened" => 20,
+    "damper" => 17,
+    "damps" => 5,
+    "damsel" => 1,
+    "dan" => 3,
+    "dana" => 2,
+    "dance" => 5,
+    "danced" => 20,
+    "dancers" => 5,
+    "dances" => 5,
+    "dancing" => 21,
+    "dander" => 5,
+    "dane" => 3,
+    "danelaw" => 2,
+    "danger" => 21,
+    "dangerous" => 16,
+    "dangerously" => 32,
+    "dangers" => 21,
+    "dangle" => 5,
+    "dangled" => 20,
+    "dangles" => 5,
+    "dangling" => 21,
+    "daniel" => 3,
+    "danish" => 19,
+    "dank" => 21,
+    "danny" => 2,
+    "dante" => 2,
+    "dare" => 11,
+    "dared" => 16,
+    "dares" => 1,
+    "daring" => 21,
+    "dark" => 21,
+    "darken" => 21,
+    "darkened" => 20,
+    "darkening" => 21,
+    "darker" => 17,
+    "darkest" => 16,
+    "darkness" => 21,
+    "darling" => 19,
+    "darn" => 21,
+    "dart" => 5,
+    "darted" => 20,
+    "darting" => 21,
+    "dartmouth" => 2,
+    "darwin" => 2,
+    "dash" => 5,
+    "dashboard" => 5,
+    "dashboards" => 5,
+    "dashed" => 20,
+    "dasher" => 1,
+    "dashes" => 5,
+    "dashing" => 21,
+    "dat" => 1,
+    "data" => 1,
+    "database" => 5,
+    "databases" => 5,
+    "dataset" => 1,
+    "date" => 5,
+    "dated" => 20,
+    "dates" => 5,
+    "dating" => 21,
+    "dative" => 21,
+    "daubed" => 20,
+    "daubing" => 21,
+    "daughter" => 1,
+    "daunted" => 20,
+    "daunting" => 21,
+    "dauntless" => 16,
+    "dave" => 2,
+    "davenport" => 3,
+    "david" => 2,
+    "davidson" => 2,
+    "davis" => 2,
+    "davit" => 1,
+    "dawdling" => 21,
+    "dawn" => 7,
+    "dawned" => 20,
+    "day" => 3,
+    "daybreak" => 1,
+    "daydreams" => 5,
+    "daylight" => 5,
+    "days" => 1,
+    "daytime" => 17,
+    "daze" => 5,
+    "dazed" => 20,
+    "dazzled" => 20,
+    "dazzling" => 21,
+    "deactivate" => 4,
+    "deactivation" => 1,
+    "dead" => 21,
+    "deadliest" => 16,
+    "deadline" => 5,
+    "deadlines" => 5,
+    "deadlock" => 5,
+    "deadlocked" => 20,
+    "deadlocking" => 21,
+    "deadlocks" => 5,
+    "deadly" => 48,
+    "deaf" => 21,
+    "deafen" => 21,
+    "deafening" => 21,
+    "deal" => 21,
+    "dealer" => 17,
+    "dealers" => 21,
+    "dealing" => 21,
+    "dealings" => 21,
+    "deallocate" => 4,
+    "deals" => 5,
+    "dealt" => 4,
+    "dean" => 199,
+    "dear" => 21,
+    "dearer" => 17,
+    "dearest" => 17,
+    "dearly" => 32,
+    "dears" => 5,
+    "dearth" => 21,
+    "death" => 3,
+    "deathbed" => 1,
+    "deathless" => 16,
+    "deathlike" => 16,
+    "deaths" => 1,
+    "deathtrap" => 1,
+    "deb" => 1,
+    "debar" => 69,
+    "debarred" => 20,
+    "debasement" => 1,
+    "debatable" => 5,
+    "debate" => 5,
+    "debauchee" => 1,
+    "debilitated" => 20,
+    "debility" => 1,
+    "debit" => 21,
+    "debris" => 1,
+    "debt" => 1,
+    "debtor" => 1,
+    "debts" => 1,
+    "debug" => 5,
+    "debugged" => 20,
+    "debugger" => 1,
+    "debuggers" => 1,
+    "debugging" => 5,
+    "decade" => 1,
+    "decades" => 1,
+    "decanter" => 5,
+    "decay" => 5,
+    "decayed" => 20,
+    "decaying" => 21,
+    "decease" => 5,
+    "deceased" => 17,
+    "deceit" => 1,
+    "deceive" => 4,
+    "deceived" => 20,
+    "december" => 2,
+    "decencies" => 1,
+    "decency" => 1,
+    "decent" => 16,
+    "decently" => 32,
+    "deception" => 1,
+    "deceptive" => 16,
+    "deceptively" => 32,
+    "decide" => 4,
+    "decided" => 21,
+    "decidedly" => 32,
+    "decides" => 4,
+    "deciding" => 21,
+    "decimal" => 21,
+    "decimals" => 5,
+    "decipher" => 5,
+    "decision" => 5,
+    "decisions" => 5,
+    "decisive" => 16,
+    "decisively" => 32,
+    "deck" => 5,
+    "decked" => 20,
+    "decking" => 21,
+    "decks" => 5,
+    "declamatory" => 16,
+    "declarable" => 4,
+    "declaration" => 1,
+    "declarations" => 1,
+    "declarative" => 17,
+    "declaratively" => 32,
+    "declarator" => 1,
+    "declare" => 4,
+    "declared" => 20,
+    "declares" => 4,
+    "declaring" => 21,
+    "decline" => 5,
+    "declined" => 20,
+    "declining" => 21,
+    "declivity" => 1,
+    "decode" => 5,
+    "decoder" => 1,
+    "decompose" => 4,
+    "decomposition" => 1,
+    "decompress" => 5,
+    "decompression" => 1,
+    "decompressor" => 1,
+    "deconstruct" => 5,
+    "decorate" => 4,
+    "decorated" => 20,
+    "decorates" => 4,
+    "decorating" => 21,
+    "decoration" => 5,
+    "decorations" => 1,
+    "decorator" => 1,
+    "decorators" => 1,
+    "decorum" => 1,
+    "decouple" => 21,
+    "decoy" => 5,
+    "decoyed" => 20,
+    "decrease" => 5,
+    "decreasing" => 5,
+    "decreed" => 20,
+    "decrement" => 5,
+    "decremented" => 20,
+    "decrementing" => 21,
+    "decrements" => 5,
+    "decrepit" => 16,
+    "decrepitude" => 1,
+    "decrypt" => 4,
+    "decrypted" => 20,
+    "decrypting" => 21,
+    "decryption" => 1,
+    "decrypts" => 4,
+    "dedicate" => 21,
+    "dedicated" => 20,
+    "dedication" => 1,
+    "deduce" => 4,
+    "deduced" => 20,
+    "deduces" => 4,
+    "deduct" => 5,
+    "deducted" => 20,
+    "deductible" => 17,
+    "deduction" => 1,
+    "deductions" => 1,
+    "deductive" => 20,
+    "deductively" => 32,
+    "deed" => 5,
+    "deem" => 5,
+    "deemed" => 20,
+    "deems" => 5,
+    "deep" => 17,
+    "deepened" => 20,
+    "deepening" => 21,
+    "deeper" => 16,
+    "deepest" => 16,
+    "deeply" => 32,
+    "deeps" => 1,
+    "deer" => 5,
+    "def" => 17,
+    "default" => 5,
+    "defeat" => 5,
+    "defeated" => 20,
+    "defeating" => 21,
+    "defeats" => 5,
+    "defect" => 5,
+    "defective" => 21,
+    "defects" => 5,
+    "defence" => 1,
+    "defend" => 5,
+    "defended" => 20,
+    "defense" => 5,
+    "defenses" => 5,
+    "defensive" => 21,
+    "deference" => 1,
+    "deferral" => 1,
+    "deferred" => 21,
+    "deferring" => 5,
+    "defers" => 17,
+    "defiance" => 5,
+    "defiant" => 17,
+    "defiantly" => 32,
+    "deficiencies" => 1,
+    "deficiency" => 1,
+    "deficient" => 17,
+    "defies" => 5,
+    "definable" => 16,
+    "define" => 21,
+    "defined" => 20,
+    "defines" => 5,
+    "defining" => 21,
+    "definite" => 17,
+    "definitely" => 32,
+    "definiteness" => 17,
+    "definition" => 1,
+    "definitions" => 1,
+    "definitive" => 17,
+    "definitively" => 32,
+    "deflate" => 4,
+    "deflated" => 20,
+    "deflates" => 4,
+    "deflating" => 21,
+    "deflation" => 5,
+    "deflections" => 1,
+    "deformity" => 1,
+    "defray" => 5,
+    "deft" => 16,
+    "deftly" => 32,
+    "defunct" => 21,
+    "defy" => 5,
+    "degenerate" => 21,
+    "degradation" => 5,
+    "degrade" => 5,
+    "degree" => 1,
+    "degrees" => 1,
+    "deign" => 4,
+    "deities" => 1,
+    "deity" => 1,
+    "dejected" => 20,
+    "dejection" => 1,
+    "del" => 3,
+    "delaware" => 3,
+    "delay" => 21,
+    "delayed" => 20,
+    "delectable" => 17,
+    "delegate" => 5,
+    "delegated" => 20,
+    "delegating" => 21,
+    "delete" => 5,
+    "deleted" => 20,
+    "deletes" => 5,
+    "deleting" => 21,
+    "deletion" => 5,
+    "deletions" => 5,
+    "deliberate" => 20,
+    "deliberately" => 32,
+    "deliberations" => 20,
+    "delicacy" => 1,
+    "delicate" => 17,
+    "delicately" => 32,
+    "delicatessen" => 1,
+    "delicious" => 16,
+    "delight" => 21,
+    "delighted" => 20,
+    "delightful" => 16,
+    "delimit" => 21,
+    "delimitation" => 1,
+    "delineate" => 4,
+    "delineated" => 20,
+    "delinquent" => 17,
+    "delirious" => 16,
+    "delirium" => 1,
+    "deliver" => 20,
+    "deliverable" => 17,
+    "delivered" => 20,
+    "deliverer" => 1,
+    "deliverers" => 1,
+    "delivering" => 21,
+    "delivers" => 4,
+    "delivery" => 21,
+    "dell" => 3,
+    "delphi" => 2,
+    "delta" => 7,
+    "deltas" => 5,
+    "deluded" => 20,
+    "deluge" => 5,
+    "delusion" => 1,
+    "deluxe" => 17,
+    "delve" => 5,
+    "demand" => 5,
+    "demanded" => 20,
+    "demanding" => 21,
+    "demands" => 5,
+    "demeanour" => 1,
+    "demerit" => 5,
+    "demo" => 5,
+    "democracy" => 1,
+    "demographic" => 17,
+    "demolition" => 1,
+    "demon" => 1,
+    "demoniac" => 17,
+    "demoniacal" => 16,
+    "demonstrable" => 17,
+    "demonstrably" => 32,
+    "demonstrate" => 4,
+    "demonstrated" => 20,
+    "demonstrates" => 4,
+    "demonstrating" => 21,
+    "demonstration" => 5,
+    "demonstrations" => 5,
+    "demos" => 5,
+    "demoted" => 20,
+    "demoting" => 21,
+    "demotion" => 5,
+    "demurely" => 32,
+    "den" => 21,
+    "denial" => 1,
+    "denied" => 20,
+    "denies" => 4,
+    "denis" => 2,
+    "denizen" => 5,
+    "dennis" => 2,
+    "denominator" => 1,
+    "denote" => 5,
+    "denounce" => 4,
+    "denouncing" => 21,
+    "dens" => 16,
+    "dense" => 17,
+    "densely" => 32,
+    "density" => 1,
+    "dental" => 17,
+    "dentist" => 1,
+    "denver" => 2,
+    "deny" => 4,
+    "denying" => 21,
+    "depart" => 21,
+    "departed" => 21,
+    "department" => 1,
+    "departments" => 1,
+    "departure" => 1,
+    "depend" => 5,
+    "dependable" => 17,
+    "dependably" => 32,
+    "dependence" => 1,
+    "dependencies" => 1,
+    "dependency" => 1,
+    "dependent" => 17,
+    "dependents" => 1,
+    "depicted" => 20,
+    "depicts" => 4,
+    "deplorable" => 4,
+    "deplorably" => 32,
+    "deplored" => 20,
+    "deploy" => 5,
+    "deployed" => 20,
+    "deploying" => 21,
+    "deployment" => 5,
+    "deployments" => 1,
+    "deportment" => 1,
+    "depose" => 5,
+    "deposit" => 5,
+    "deposited" => 20,
+    "depositing" => 21,
+    "deposition" => 5,
+    "depositor" => 1,
+    "depositors" => 1,
+    "depository" => 1,
+    "deposits" => 5,
+    "depot" => 5,
+    "depraved" => 20,
+    "depravity" => 1,
+    "deprecate" => 4,
+    "deprecated" => 20,
+    "deprecates" => 4,
+    "deprecating" => 21,
+    "deprecation" => 5,
+    "depressing" => 20,
+    "depression" => 1,
+    "depressions" => 1,
+    "deprive" => 4,
+    "deprived" => 20,
+    "deprives" => 4,
+    "depth" => 1,
+    "depths" => 1,
+    "deputation" => 1,
+    "deputy" => 5,
+    "derail" => 5,
+    "derange" => 5,
+    "derbies" => 1,
+    "derby" => 3,
+    "dereference" => 5,
+    "derek" => 2,
+    "derision" => 1,
+    "derivable" => 4,
+    "derivation" => 1,
+    "derivations" => 1,
+    "derivative" => 17,
+    "derivatives" => 1,
+    "derive" => 5,
+    "derrick" => 7,
+    "descend" => 5,
+    "descendant" => 17,
+    "descendants" => 1,
+    "descended" => 20,
+    "descendent" => 17,
+    "descender" => 1,
+    "descending" => 21,
+    "descends" => 5,
+    "descent" => 5,
+    "describable" => 20,
+    "describe" => 4,
+    "described" => 20,
+    "describes" => 4,
+    "describing" => 21,
+    "description" => 1,
+    "descriptions" => 1,
+    "descriptive" => 17,
+    "descriptor" => 1,
+    "descriptors" => 1,
+    "deserialize" => 4,
+    "desert" => 21,
+    "deserted" => 20,
+    "deserting" => 21,
+    "desertion" => 1,
+    "deserts" => 5,
+    "deserve" => 5,
+    "deserved" => 20,
+    "deserving" => 21,
+    "desiderata" => 1,
+    "desideratum" => 1,
+    "design" => 5,
+    "designate" => 20,
+    "designated" => 20,
+    "designates" => 4,
+    "designating" => 21,
+    "designation" => 21,
+    "designator" => 1,
+    "designators" => 1,
+    "designed" => 20,
+    "designer" => 1,
+    "designing" => 21,
+    "designs" => 5,
+    "desirability" => 1,
+    "desirable" => 5,
+    "desire" => 5,
+    "desired" => 20,
+    "desirous" => 16,
+    "desk" => 5,
+    "desks" => 5,
+    "desktop" => 17,
+    "desktops" => 1,
+    "desolate" => 20,
+    "desolated" => 20,
+    "desolating" => 21,
+    "desolation" => 21,
+    "despair" => 5,
+    "despaired" => 20,
+    "despairing" => 21,
+    "despairingly" => 32,
+    "desperate" => 17,
+    "desperately" => 32,
+    "desperation" => 17,
+    "despicable" => 17,
+    "despise" => 4,
+    "despised" => 20,
+    "despising" => 21,
+    "despite" => 69,
+    "despondence" => 1,
+    "despondency" => 1,
+    "despondent" => 16,
+    "despotic" => 16,
+    "destination" => 1,
+    "destinations" => 1,
+    "destined" => 20,
+    "destiny" => 1,
+    "destitute" => 20,
+    "destroy" => 4,
+    "destroyed" => 20,
+    "destroyer" => 1,
+    "destroying" => 21,
+    "destroys" => 4,
+    "destruct" => 4,
+    "destruction" => 1,
+    "destructive" => 20,
+    "destructor" => 1,
+    "desultory" => 16,
+    "detach" => 4,
+    "detachable" => 4,
+    "detached" => 20,
+    "detaches" => 4,
+    "detaching" => 21,
+    "detachment" => 5,
+    "detail" => 21,
+    "detain" => 4,
+    "detained" => 20,
+    "detaining" => 21,
+    "detect" => 20,
+    "detectable" => 20,
+    "detected" => 20,
+    "detecting" => 21,
+    "detection" => 1,
+    "detective" => 21,
+    "detectives" => 1,
+    "detector" => 1,
+    "detectors" => 1,
+    "detects" => 4,
+    "deteriorated" => 20,
+    "deteriorates" => 4,
+    "deteriorating" => 21,
+    "deterioration" => 5,
+    "determinable" => 17,
+    "determinant" => 17,
+    "determination" => 1,
+    "determine" => 4,
+    "determined" => 20,
+    "determinedly" => 32,
+    "determiner" => 1,
+    "determiners" => 1,
+    "determines" => 4,
+    "determining" => 21,
+    "determinism" => 1,
+    "deterministic" => 16,
+    "detest" => 5,
+    "detestable" => 16,
+    "detestation" => 1,
+    "detour" => 5,
+    "detract" => 5,
+    "detracted" => 20,
+    "detrimental" => 17,
+    "detroit" => 2,
+    "deuce" => 1,
+    "devaluation" => 5,
+    "develop" => 4,
+    "developed" => 20,
+    "developer" => 1,
+    "developers" => 1,
+    "developing" => 21,
+    "development" => 5,
+    "developmental" => 17,
+    "developments" => 1,
+    "develops" => 4,
+    "deviate" => 21,
+    "deviated" => 20,
+    "deviates" => 5,
+    "deviating" => 21,
+    "deviation" => 21,
+    "deviations" => 21,
+    "device" => 85,
+    "devil" => 7,
+    "devil's" => 1,
+    "devilish" => 16,
+    "devilment" => 7,
+    "devils" => 5,
+    "devise" => 5,
+    "devoid" => 20,
+    "devolved" => 20,
+    "devote" => 4,
+    "devoted" => 20,
+    "devotedly" => 32,
+    "devotees" => 1,
+    "devoting" => 21,
+    "devotion" => 1,
+    "devoured" => 20,
+    "devouring" => 21,
+    "dew" => 5,
+    "dewar" => 3,
+    "dewdrops" => 1,
+    "dewy" => 16,
+    "dexterous" => 16,
+    "diabetes" => 1,
+    "diabolical" => 16,
+    "diabolically" => 32,
+    "diacritic" => 17,
+    "diacritics" => 1,
+    "diadem" => 5,
+    "diaeresis" => 1,
+    "diagnose" => 4,
+    "diagnosed" => 20,
+    "diagnostic" => 17,
+    "diagnostics" => 1,
+    "diagonal" => 17,
+    "diagonally" => 32,
+    "diagram" => 5,
+    "diagrams" => 5,
+    "dial" => 7,
+    "dialect" => 1,
+    "dialectal" => 16,
+    "dialects" => 1,
+    "dialog" => 5,
+    "dialogue" => 5,
+    "dialogues" => 5,
+    "dials" => 5,
+    "diamond" => 21,
+    "diamonds" => 5,
+    "diana" => 3,
+    "diane" => 2,
+    "diary" => 21,
+    "diatomic" => 17,
+    "dice" => 5,
+    "dichotomy" => 1,
+    "dickens" => 7,
+    "dickey" => 17,
+    "dickinson" => 2,
+    "dictate" => 5,
+    "dictated" => 20,
+    "dictates" => 5,
+    "dictator" => 1,
+    "dictionaries" => 5,
+    "dictionary" => 5,
+    "dictum" => 1,
+    "did" => 4,
+    "didn't" => 4,
+    "die" => 5,
+    "died" => 20,
+    "diego" => 3,
+    "diem" => 2,
+    "dies" => 5,
+    "diesel" => 5,
+    "diesels" => 5,
+    "diet" => 21,
+    "dietrich" => 2,
+    "differ" => 21,
+    "differed" => 20,
+    "difference" => 5,
+    "differences" => 5,
+    "different" => 17,
+    "differential" => 17,
+    "differentiate" => 5,
+    "differentiated" => 20,
+    "differentiates" => 5,
+    "differentiating" => 21,
+    "differentiation" => 5,
+    "differently" => 32,
+    "differing" => 21,
+    "differs" => 23,
+    "difficult" => 20,
+    "difficulties" => 1,
+    "difficulty" => 1,
+    "diffidence" => 1,
+    "diffident" => 16,
+    "diffused" => 20,
+    "diffusers" => 1,
+    "diffusing" => 21,
+    "diffusion" => 21,
+    "dig" => 5,
+    "digest" => 5,
+    "digestible" => 16,
+    "digesting" => 21,
+    "digests" => 5,
+    "digger" => 1,
+    "digging" => 5,
+    "diggings" => 5,
+    "digit" => 5,
+    "digital" => 17,
+    "digitally" => 32,
+    "digitization" => 1,
+    "digitize" => 4,
+    "digitized" => 20,
+    "digitizing" => 21,
+    "digits" => 5,
+    "dignified" => 20,
+    "dignitaries" => 1,
+    "dignity" => 1,
+    "digraph" => 1,
+    "digraphs" => 1,
+    "digs" => 5,
+    "dilapidated" => 20,
+    "dilate" => 4,
+    "dilation" => 5,
+    "dilatory" => 16,
+    "dilemma" => 1,
+    "diligence" => 1,
+    "diligent" => 16,
+    "diligently" => 32,
+    "diluted" => 20,
+    "dim" => 21,
+    "dime" => 5,
+    "dimension" => 5,
+    "dimensional" => 16,
+    "dimensioned" => 20,
+    "dimensions" => 5,
+    "dimes" => 5,
+    "diminish" => 4,
+    "diminished" => 20,
+    "diminishing" => 21,
+    "diminutive" => 17,
+    "dimly" => 32,
+    "dimmed" => 4,
+    "dimming" => 5,
+    "dimpled" => 20,
+    "dimples" => 5,
+    "din" => 5,
+    "dine" => 5,
+    "dined" => 20,
+    "diners" => 5,
+    "ding" => 21,
+    "dingy" => 21,
+    "dining" => 21,
+    "dinner" => 5,
+    "dinosaur" => 1,
+    "dint" => 5,
+    "dip" => 5,
+    "diphthongs" => 1,
+    "diploma" => 1,
+    "diplomacy" => 1,
+    "diplomat" => 1,
+    "diplomatic" => 17,
+    "dipped" => 20,
+    "dipping" => 5,
+    "dire" => 16,
+    "direct" => 20,
+    "directed" => 20,
+    "directing" => 21,
+    "direction" => 1,
+    "directional" => 17,
+    "directionality" => 1,
+    "directions" => 1,
+    "directive" => 21,
+    "directives" => 1,
+    "directly" => 544,
+    "director" => 1,
+    "directories" => 1,
+    "directors" => 1,
+    "directory" => 17,
+    "directs" => 4,
+    "dirge" => 5,
+    "dirk" => 7,
+    "dirt" => 5,
+    "dirtiness" => 21,
+    "dirty" => 20,
+    "disability" => 1,
+    "disable" => 20,
+    "disabled" => 20,
+    "disables" => 4,
+    "disabling" => 21,
+    "disadvantage" => 5,
+    "disagree" => 4,
+    "disagreement" => 1,
+    "disallow" => 4,
+    "disambiguate" => 4,
+    "disambiguated" => 20,
+    "disambiguating" => 21,
+    "disambiguation" => 4,
+    "disappear" => 4,
+    "disappearance" => 1,
+    "disappoint" => 4,
+    "disappointment" => 1,
+    "disapproval" => 1,
+    "disapprove" => 4,
+    "disapproving" => 20,
+    "disarray" => 5,
+    "disassemble" => 4,
+    "disassembler" => 1,
+    "disassembly" => 1,
+    "disassociate" => 21,
+    "disaster" => 1,
+    "disastrous" => 16,
+    "disbelief" => 1,
+    "disbelieve" => 4,
+    "disc" => 5,
+    "discard" => 5,
+    "discern" => 4,
+    "discerned" => 20,
+    "discernible" => 16,
+    "discerning" => 21,
+    "discernment" => 5,
+    "discharge" => 5,
+    "discharged" => 4,
+    "disciple" => 5,
+    "disciples" => 5,
+    "disciplinary" => 17,
+    "discipline" => 5,
+    "disciplined" => 20,
+    "disciplines" => 5,
+    "disciplining" => 21,
+    "disclaim" => 5,
+    "disclaimer" => 1,
+    "disclose" => 5,
+    "disclosed" => 20,
+    "discloses" => 5,
+    "disclosing" => 21,
+    "disclosure" => 5,
+    "disco" => 261,
+    "discomfort" => 5,
+    "discompose" => 4,
+    "disconcert" => 5,
+    "disconcerting" => 16,
+    "disconnect" => 5,
+    "disconnected" => 20,
+    "disconnection" => 1,
+    "disconsolate" => 17,
+    "discontent" => 21,
+    "discontented" => 20,
+    "discontinue" => 5,
+    "discontinuous" => 16,
+    "discord" => 5,
+    "discordant" => 16,
+    "discount" => 5,
+    "discourage" => 5,
+    "discouraged" => 20,
+    "discourages" => 5,
+    "discouraging" => 21,
+    "discourse" => 5,
+    "discover" => 21,
+    "discovered" => 20,
+    "discoverer" => 1,
+    "discoverers" => 1,
+    "discoveries" => 1,
+    "discovering" => 21,
+    "discovers" => 4,
+    "discovery" => 1,
+    "discreet" => 16,
+    "discreetly" => 32,
+    "discrepancies" => 1,
+    "discrepancy" => 1,
+    "discrete" => 16,
+    "discretely" => 32,
+    "discretion" => 17,
+    "discriminant" => 17,
+    "discriminate" => 20,
+    "discriminated" => 20,
+    "discriminates" => 4,
+    "discriminating" => 21,
+    "discrimination" => 21,
+    "discriminator" => 1,
+    "discriminators" => 1,
+    "discuss" => 5,
+    "discussion" => 1,
+    "discussions" => 1,
+    "disdain" => 5,
+    "disdaining" => 21,
+    "disease" => 5,
+    "disembark" => 4,
+    "disentangle" => 4,
+    "disgrace" => 5,
+    "disgraceful" => 16,
+    "disguise" => 5,
+    "disguised" => 20,
+    "disgust" => 5,
+    "disgusted" => 20,
+    "disgusting" => 20,
+    "dish" => 5,
+    "dishes" => 5,
+    "dishevelled" => 20,
+    "dishonest" => 20,
+    "dishonesty" => 1,
+    "dishwashers" => 1,
+    "disinformation" => 1,
+    "disingenuous" => 16,
+    "disinterested" => 16,
+    "disjoint" => 21,
+    "disjointed" => 16,
+    "disk" => 5,
+    "disks" => 5,
+    "dislike" => 597,
+    "dismal" => 17,
+    "dismally" => 32,
+    "dismay" => 5,
+    "dismayed" => 20,
+    "dismembered" => 20,
+    "dismissal" => 1,
+    "dismount" => 5,
+    "disobey" => 4,
+    "disorder" => 5,
+    "disorderly" => 32,
+    "disown" => 21,
+    "disparage" => 5,
+    "disparate" => 17,
+    "dispatch" => 5,
+    "dispatcher" => 1,
+    "dispel" => 5,
+    "dispelled" => 4,
+    "dispelling" => 4,
+    "dispense" => 5,
+    "dispensed" => 20,
+    "dispersed" => 20,
+    "displacement" => 1,
+    "display" => 5,
+    "displayable" => 17,
+    "disposable" => 17,
+    "disposal" => 1,
+    "dispose" => 5,
+    "disposed" => 20,
+    "disposition" => 5,
+    "dispositions" => 5,
+    "disproportionate" => 20,
+    "disputatious" => 16,
+    "dispute" => 5,
+    "disputed" => 20,
+    "disputes" => 5,
+    "disputing" => 21,
+    "disqualification" => 1,
+    "disqualify" => 5,
+    "disquiet" => 21,
+    "disquieted" => 20,
+    "disquieting" => 21,
+    "disquisition" => 1,
+    "disregard" => 5,
+    "disrepair" => 5,
+    "disreputable" => 5,
+    "disrespect" => 5,
+    "disrupt" => 20,
+    "disruption" => 1,
+    "disruptions" => 1,
+    "disruptive" => 20,
+    "dissatisfaction" => 1,
+    "dissect" => 4,
+    "dissected" => 20,
+    "dissecting" => 21,
+    "dissects" => 4,
+    "disseminate" => 4,
+    "disseminated" => 20,
+    "disseminating" => 21,
+    "dissemination" => 5,
+    "dissension" => 1,
+    "dissent" => 5,
+    "dissertation" => 1,
+    "dissimilar" => 17,
+    "dissimilarity" => 1,
+    "dissipate" => 4,
+    "dissipated" => 20,
+    "dissipates" => 4,
+    "dissociated" => 20,
+    "dissociating" => 21,
+    "dissoluble" => 16,
+    "dissolute" => 17,
+    "dissolve" => 5,
+    "dissolved" => 20,
+    "dissolving" => 21,
+    "dissuade" => 4,
+    "distaff" => 17,
+    "distance" => 5,
+    "distances" => 5,
+    "distant" => 16,
+    "distaste" => 5,
+    "distasteful" => 16,
+    "distemper" => 5,
+    "distill" => 581,
+    "distilled" => 20,
+    "distinct" => 20,
+    "distinction" => 1,
+    "distinctions" => 1,
+    "distinctive" => 21,
+    "distinctly" => 32,
+    "distinctness" => 21,
+    "distinguish" => 4,
+    "distinguishable" => 20,
+    "distinguished" => 20,
+    "distinguishes" => 4,
+    "distinguishing" => 21,
+    "distorted" => 20,
+    "distortion" => 1,
+    "distortions" => 1,
+    "distract" => 21,
+    "distracting" => 21,
+    "distraction" => 5,
+    "distraught" => 16,
+    "distress" => 5,
+    "distressed" => 20,
+    "distressing" => 21,
+    "distribute" => 4,
+    "distributed" => 20,
+    "distributes" => 4,
+    "distributing" => 21,
+    "distribution" => 5,
+    "distributions" => 1,
+    "distributive" => 21,
+    "distributor" => 1,
+    "distributors" => 1,
+    "district" => 21,
+    "distrust" => 21,
+    "distrustful" => 16,
+    "disturb" => 5,
+    "disturbance" => 1,
+    "disturbances" => 1,
+    "disturbed" => 20,
+    "disturbing" => 21,
+    "disturbs" => 5,
+    "disunion" => 1,
+    "disuse" => 5,
+    "ditch" => 5,
+    "dither" => 5,
+    "dithered" => 20,
+    "dithering" => 21,
+    "dithers" => 5,
+    "ditto" => 5,
+    "div" => 5,
+    "divan" => 1,
+    "dive" => 5,
+    "dived" => 20,
+    "diverge" => 4,
+    "diverged" => 20,
+    "divergence" => 1,
+    "divergent" => 16,
+    "diverges" => 4,
+    "diverging" => 21,
+    "diverse" => 16,
+    "diversified" => 20,
+    "diversify" => 4,
+    "diversion" => 17,
+    "diversity" => 1,
+    "divert" => 4,
+    "diverted" => 20,
+    "diverting" => 21,
+    "divide" => 5,
+    "divided" => 20,
+    "dividend" => 5,
+    "divider" => 1,
+    "divides" => 5,
+    "dividing" => 21,
+    "divine" => 23,
+    "divined" => 20,
+    "divinely" => 32,
+    "diving" => 21,
+    "divinities" => 1,
+    "divinity" => 1,
+    "divisibility" => 1,
+    "divisible" => 17,
+    "division" => 1,
+    "divisional" => 17,
+    "divisions" => 1,
+    "divisor" => 1,
+    "divisors" => 1,
+    "divorce" => 5,
+    "divorced" => 20,
+    "divot" => 5,
+    "divulge" => 4,
+    "divulged" => 20,
+    "diy" => 16,
+    "dizziness" => 21,
+    "dizzy" => 21,
+    "dna" => 7,
+    "do" => 9,
+    "doc" => 1,
+    "docile" => 16,
+    "dock" => 5,
+    "docker" => 3,
+    "docket" => 5,
+    "docketing" => 21,
+    "docking" => 21,
+    "docks" => 5,
+    "dockyard" => 1,
+    "doctor" => 5,
+    "doctoral" => 16,
+    "doctors" => 5,
+    "document" => 5,
+    "documentary" => 17,
+    "documentation" => 1,
+    "documented" => 20,
+    "documenting" => 21,
+    "documents" => 5,
+    "doddering" => 21,
+    "dodge" => 23,
+    "dodged" => 20,
+    "dodgy" => 16,
+    "dodo" => 1,
+    "doe" => 7,
+    "does" => 5,
+    "doesn't" => 4,
+    "dog" => 21,
+    "dog's" => 1,
+    "dogged" => 20,
+    "dogleg" => 5,
+    "dogmatic" => 17,
+    "dogmatically" => 17,
+    "dogmatism" => 1,
+    "dogs" => 5,
+    "doing" => 21,
+    "doings" => 13,
+    "doll" => 1,
+    "dollar" => 1,
+    "dollars" => 1,
+    "dolly" => 23,
+    "dolor" => 1,
+    "dolphin" => 1,
+    "dom" => 1,
+    "domain" => 1,
+    "domains" => 1,
+    "dome" => 5,
+    "domes" => 5,
+    "domestic" => 17,
+    "domesticated" => 20,
+    "domestics" => 1,
+    "domicile" => 5,
+    "dominance" => 1,
+    "dominant" => 17,
+    "dominate" => 21,
+    "dominated" => 20,
+    "dominates" => 5,
+    "dominion" => 3,
+    "dominions" => 1,
+    "dominique" => 3,
+    "don" => 7,
+    "don't" => 4,
+    "donald" => 2,
+    "donate" => 4,
+    "donated" => 20,
+    "donates" => 4,
+    "donating" => 21,
+    "donation" => 5,
+    "donations" => 4,
+    "done" => 21,
+    "dong" => 5,
+    "donna" => 2,
+    "donned" => 4,
+    "doodle" => 5,
+    "doolittle" => 2,
+    "doom" => 5,
+    "doomed" => 20,
+    "door" => 5,
+    "doorknob" => 1,
+    "doors" => 5,
+    "doorsteps" => 5,
+    "doorway" => 1,
+    "doppler" => 3,
+    "dormant" => 17,
+    "dormitory" => 1,
+    "dormouse" => 1,
+    "dorothy" => 2,
+    "dos" => 3,
+    "dosing" => 21,
+    "dot" => 71,
+    "doted" => 20,
+    "doting" => 21,
+    "dots" => 5,
+    "dotted" => 20,
+    "double" => 21,
+    "doubled" => 20,
+    "doubles" => 5,
+    "doublet" => 1,
+    "doubling" => 21,
+    "doubt" => 5,
+    "doubted" => 20,
+    "doubtful" => 17,
+    "doubtfully" => 32,
+    "doubting" => 21,
+    "doubtless" => 16,
+    "doubts" => 5,
+    "doug" => 2,
+    "doughnut" => 5,
+    "douglas" => 2,
+    "dover" => 2,
+    "dow" => 2,
+    "down" => 85,
+    "downcast" => 21,
+    "downgrade" => 5,
+    "downgraded" => 20,
+    "downgrades" => 5,
+    "downgrading" => 21,
+    "downhearted" => 16,
+    "downhill" => 21,
+    "download" => 5,
+    "downloaded" => 20,
+    "downloading" => 21,
+    "downloads" => 5,
+    "downright" => 16,
+    "downs" => 7,
+    "downside" => 1,
+    "downsides" => 1,
+    "downstairs" => 17,
+    "downstream" => 20,
+    "downtime" => 1,
+    "downward" => 16,
+    "downwards" => 16,
+    "dowry" => 5,
+    "doyle" => 3,
+    "doze" => 5,
+    "dozen" => 1,
+    "dozens" => 1,
+    "dozing" => 21,
+    "drab" => 21,
+    "draft" => 21,
+    "drafts" => 5,
+    "drag" => 5,
+    "dragged" => 4,
+    "dragging" => 21,
+    "dragon" => 1,
+    "dragonfly" => 1,
+    "dragons" => 1,
+    "drags" => 5,
+    "drain" => 5,
+    "drained" => 20,
+    "draining" => 21,
+    "drains" => 5,
+    "drake" => 3,
+    "dram" => 5,
+    "drama" => 1,
+    "dramatic" => 16,
+    "dramatically" => 16,
+    "drank" => 5,
+    "drastic" => 17,
+    "drastically" => 17,
+    "drat" => 4,
+    "draught" => 21,
+    "draughts" => 5,
+    "draw" => 5,
+    "drawback" => 1,
+    "drawbacks" => 1,
+    "drawbridge" => 1,
+    "drawer" => 1,
+    "drawers" => 5,
+    "drawing" => 21,
+    "drawings" => 5,
+    "drawled" => 20,
+    "drawling" => 21,
+    "drawn" => 20,
+    "draws" => 5,
+    "dread" => 21,
+    "dreaded" => 20,
+    "dreadful" => 17,
+    "dreadfully" => 32,
+    "dreading" => 21,
+    "dreads" => 5,
+    "dream" => 21,
+    "dreamed" => 20,
+    "dreamily" => 32,
+    "dreaming" => 21,
+    "dreams" => 5,
+    "dreamt" => 4,
+    "dreamy" => 16,
+    "drearier" => 16,
+    "drearily" => 32,
+    "dreariness" => 17,
+    "dreary" => 16,
+    "dregs" => 1,
+    "drenched" => 20,
+    "drenching" => 21,
+    "dress" => 5,
+    "dressed" => 20,
+    "dresser" => 1,
+    "dresses" => 5,
+    "dressing" => 21,
+    "drew" => 6,
+    "dried" => 20,
+    "driest" => 16,
+    "drift" => 5,
+    "drifted" => 20,
+    "drifting" => 21,
+    "drifts" => 5,
+    "driftwood" => 1,
+    "drill" => 5,
+    "drilling" => 21,
+    "drink" => 5,
+    "drinking" => 21,
+    "drinks" => 5,
+    "drip" => 5,
+    "dripping" => 5,
+    "drippings" => 5,
+    "drips" => 5,
+    "drive" => 5,
+    "drivel" => 5,
+    "driven" => 20,
+    "driver" => 1,
+    "drivers" => 5,
+    "drives" => 5,
+    "driving" => 21,
+    "drizzle" => 5,
+    "drone" => 5,
+    "droned" => 20,
+    "droning" => 21,
+    "drooped" => 20,
+    "drooping" => 21,
+    "drop" => 5,
+    "dropout" => 1,
+    "dropouts" => 1,
+    "dropped" => 20,
+    "dropping" => 5,
+    "drops" => 5,
+    "dross" => 5,
+    "drove" => 5,
+    "drown" => 4,
+    "drowned" => 20,
+    "drowning" => 21,
+    "drowsed" => 20,
+    "drowsiness" => 17,
+    "drowsing" => 21,
+    "drowsy" => 16,
+    "drudgery" => 1,
+    "drug" => 5,
+    "drugstore" => 1,
+    "druid" => 1,
+    "drum" => 5,
+    "drummer" => 1,
+    "drums" => 5,
+    "drunk" => 21,
+    "drunkard" => 1,
+    "drunken" => 21,
+    "drunkenness" => 21,
+    "dry" => 21,
+    "dryad" => 1,
+    "dryly" => 32,
+    "dual" => 21,
+    "duality" => 1,
+    "dubbed" => 20,
+    "dubious" => 16,
+    "dublin" => 2,
+    "duchess" => 5,
+    "duck" => 5,
+    "ducked" => 20,
+    "ducks" => 5,
+    "duckweed" => 1,
+    "duct" => 5,
+    "due" => 17,
+    "duel" => 5,
+    "duelling" => 5,
+    "duellists" => 1,
+    "duets" => 5,
+    "dug" => 5,
+    "duke" => 7,
+    "dukedom" => 1,
+    "dukes" => 5,
+    "dull" => 20,
+    "duller" => 17,
+    "dully" => 32,
+    "duluth" => 2,
+    "dumb" => 20,
+    "dumbbell" => 1,
+    "dumber" => 17,
+    "dumbness" => 21,
+    "dummies" => 5,
+    "dummy" => 5,
+    "dump" => 5,
+    "dumped" => 20,
+    "dumping" => 21,
+    "dumps" => 5,
+    "dun" => 21,
+    "duncan" => 2,
+    "dunce" => 1,
+    "dunces" => 1,
+    "dundee" => 2,
+    "dungeon" => 5,
+    "dungeons" => 5,
+    "dupes" => 5,
+    "duplex" => 21,
+    "duplicate" => 21,
+    "duplicated" => 20,
+    "duplicates" => 5,
+    "duplicating" => 21,
+    "duplication" => 21,
+    "durability" => 1,
+    "durable" => 17,
+    "duration" => 1,
+    "durations" => 1,
+    "during" => 68,
+    "dusk" => 21,
+    "dusky" => 17,
+    "dust" => 5,
+    "duster" => 1,
+    "dustin" => 2,
+    "dusty" => 19,
+    "dutch" => 23,
+    "dutchmen" => 1,
+    "duties" => 1,
+    "dutifully" => 32,
+    "duty" => 1,
+    "duvet" => 1,
+    "dwarf" => 21,
+    "dwarfed" => 20,
+    "dwell" => 5,
+    "dweller" => 1,
+    "dwellers" => 5,
+    "dwelling" => 21,
+    "dwellings" => 5,
+    "dwelt" => 4,
+    "dwindled" => 20,
+    "dyed" => 20,
+    "dying" => 21,
+    "dylan" => 2,
+    "dynamic" => 17,
+    "dynamical" => 16,
+    "dynamically" => 32,
+    "dynamics" => 1,
+    "dynamo" => 1,
+    "dysfunctional" => 16,
+    "each" => 128,
+    "eager" => 21,
+    "eagerly" => 32,
+    "eagerness" => 21,
+    "eagle" => 5,
+    "eagles" => 5,
+    "eaglet" => 1,
+    "ear" => 5,
+    "earl" => 3,
+    "earlier" => 16,
+    "earliest" => 16,
+    "earls" => 1,
+    "early" => 49,
+    "earn" => 5,
+    "earned" => 20,
+    "earnest" => 23,
+    "earnestly" => 32,
+    "earnestness" => 21,
+    "earning" => 21,
+    "earrings" => 1,
+    "ears" => 5,
+    "earshot" => 1,
+    "earth" => 7,
+    "earthly" => 49,
+    "earthquake" => 5,
+    "earthquakes" => 5,
+    "ease" => 5,
+    "eased" => 20,
+    "eases" => 5,
+    "easier" => 17,
+    "easiest" => 16,
+    "easily" => 32,
+    "easing" => 5,
+    "east" => 19,
+    "easterly" => 17,
+    "eastern" => 16,
+    "eastward" => 17,
+    "eastwood" => 7,
+    "easy" => 21,
+    "eat" => 4,
+    "eaten" => 20,
+    "eater" => 1,
+    "eating" => 21,
+    "eats" => 4,
+    "eaves" => 1,
+    "eavesdroppers" => 1,
+    "ebb" => 21,
+    "ebbing" => 21,
+    "ebony" => 19,
+    "ebullient" => 16,
+    "eccentric" => 17,
+    "eccentricities" => 1,
+    "eccentricity" => 1,
+    "ecclesiastical" => 16,
+    "echo" => 5,
+    "echoed" => 20,
+    "echoes" => 5,
+    "echoing" => 21,
+    "eclat" => 1,
+    "eclipse" => 5,
+    "eclipsed" => 20,
+    "eclipses" => 5,
+    "economic" => 16,
+    "economical" => 16,
+    "economically" => 32,
+    "economics" => 17,
+    "economies" => 1,
+    "economize" => 4,
+    "economized" => 20,
+    "economy" => 17,
+    "ecosystem" => 1,
+    "ecosystems" => 1,
+    "ecstasies" => 5,
+    "ecstasy" => 5,
+    "ecstatic" => 17,
+    "ecstatically" => 17,
+    "ectoplasm" => 1,
+    "ecumenical" => 16,
+    "eddies" => 5,
+    "eddy" => 7,
+    "eden" => 3,
+    "edgar" => 3,
+    "edge" => 5,
+    "edged" => 20,
+    "edges" => 5,
+    "edibles" => 1,
+    "edification" => 5,
+    "edifice" => 1,
+    "edifying" => 21,
+    "edinburgh" => 2,
+    "edit" => 5,
+    "editable" => 16,
+    "edited" => 20,
+    "edith" => 2,
+    "editing" => 21,
+    "edition" => 1,
+    "editions" => 1,
+    "editor" => 1,
+    "editorial" => 17,
+    "editorials" => 1,
+    "editors" => 1,
+    "edits" => 5,
+    "edp" => 3,
+    "educate" => 4,
+    "educated" => 20,
+    "educating" => 21,
+    "education" => 5,
+    "educational" => 17,
+    "educationally" => 32,
+    "educations" => 1,
+    "educators" => 1,
+    "educe" => 5,
+    "educes" => 5,
+    "edward" => 3,
+    "edwards" => 3,
+    "edwin" => 2,
+    "eel" => 5,
+    "eels" => 5,
+    "eerie" => 17,
+    "efface" => 4,
+    "effaced" => 20,
+    "effect" => 5,
+    "effected" => 20,
+    "effective" => 21,
+    "effectively" => 32,
+    "effectiveness" => 17,
+    "effects" => 5,
+    "effectual" => 16,
+    "effectually" => 32,
+    "effeminate" => 21,
+    "efficiencies" => 1,
+    "efficiency" => 1,
+    "efficient" => 17,
+    "efficiently" => 32,
+    "effort" => 5,
+    "effortless" => 16,
+    "effortlessly" => 32,
+    "efforts" => 5,
+    "effusion" => 21,
+    "effusions" => 21,
+    "effusive" => 21,
+    "eft" => 3,
+    "egg" => 5,
+    "eggs" => 5,
+    "ego" => 1,
+    "egotism" => 1,
+    "egregious" => 16,
+    "egregiously" => 32,
+    "egypt" => 2,
+    "egyptian" => 19,
+    "egyptians" => 1,
+    "eiffel" => 2,
+    "eight" => 17,
+    "eighteenth" => 17,
+    "eighth" => 21,
+    "eighths" => 5,
+    "eightieth" => 17,
+    "eisenhower" => 2,
+    "either" => 768,
+    "ejaculated" => 20,
+    "ejaculation" => 5,
+    "ejaculations" => 5,
+    "ejected" => 20,
+    "eke" => 5,
+    "elaborate" => 20,
+    "elaborated" => 20,
+    "elaborately" => 32,
+    "elaborateness" => 21,
+    "elaborates" => 4,
+    "elaboration" => 21,
+    "elapse" => 4,
+    "elapsed" => 20,
+    "elapses" => 4,
+    "elastic" => 17,
+    "elastically" => 17,
+    "elasticity" => 1,
+    "elbow" => 5,
+    "elbowed" => 20,
+    "elbows" => 5,
+    "elder" => 21,
+    "elderly" => 32,
+    "eldest" => 17,
+    "elect" => 21,
+    "elected" => 21,
+    "election" => 1,
+    "elections" => 1,
+    "elector" => 1,
+    "electors" => 1,
+    "electra" => 2,
+    "electric" => 17,
+    "electrical" => 17,
+    "electricity" => 1,
+    "electrified" => 20,
+    "electrocuted" => 20,
+    "electron" => 1,
+    "electronic" => 16,
+    "electronically" => 32,
+    "electronics" => 17,
+    "elegance" => 1,
+    "elegant" => 17,
+    "elegantly" => 32,
+    "elem" => 1,
+    "element" => 5,
+    "elemental" => 17,
+    "elementary" => 17,
+    "elements" => 5,
+    "elephant" => 1,
+    "elephants" => 1,
+    "elevate" => 20,
+    "elevated" => 20,
+    "elevates" => 4,
+    "elevating" => 21,
+    "elevation" => 21,
+    "elevator" => 5,
+    "eleven" => 1,
+    "eleventh" => 17,
+    "elf" => 7,
+    "eli" => 3,
+    "elias" => 2,
+    "elicit" => 20,
+    "elicitation" => 1,
+    "elicited" => 20,
+    "elide" => 4,
+    "elided" => 20,
+    "eliding" => 21,
+    "eligible" => 17,
+    "eliminate" => 4,
+    "eliminated" => 20,
+    "eliminates" => 4,
+    "eliminating" => 21,
+    "elimination" => 5,
+    "elise" => 2,
+    "elision" => 1,
+    "elixir" => 1,
+    "elizabeth" => 2,
+    "ell" => 1,
+    "ella" => 2,
+    "elliott" => 2,
+    "ellipse" => 5,
+    "ellipses" => 5,
+    "ellipsis" => 1,
+    "elliptic" => 17,
+    "ellis" => 2,
+    "elm" => 1,
+    "elms" => 1,
+    "elocution" => 1,
+    "elongating" => 21,
+    "elopement" => 5,
+    "eloquence" => 1,
+    "eloquent" => 16,
+    "eloquently" => 32,
+    "else" => 528,
+    "elsewhere" => 1,
+    "elsie" => 2,
+    "elude" => 4,
+    "eluded" => 20,
+    "eludes" => 4,
+    "eluding" => 21,
+    "elusive" => 16,
+    "elvis" => 3,
+    "elvish" => 16,
+    "elysian" => 19,
+    "emaciated" => 20,
+    "emaciation" => 21,
+    "emacs" => 2,
+    "email" => 5,
+    "emancipation" => 21,
+    "embalmed" => 20,
+    "embankment" => 5,
+    "embargo" => 5,
+    "embark" => 4,
+    "embarkation" => 1,
+    "embarked" => 20,
+    "embarking" => 21,
+    "embarks" => 4,
+    "embarrass" => 4,
+    "embarrassed" => 20,
+    "embarrassment" => 5,
+    "embassy" => 1,
+    "embattled" => 20,
+    "embed" => 5,
+    "embedded" => 20,
+    "embedder" => 1,
+    "embedding" => 5,
+    "embeds" => 5,
+    "embellish" => 4,
+    "embellishment" => 5,
+    "embellishments" => 1,
+    "embers" => 1,
+    "embittered" => 20,
+    "emblem" => 5,
+    "emblems" => 5,
+    "embodies" => 4,
+    "embodiment" => 1,
+    "emboldening" => 21,
+    "emboss" => 5,
+    "embrace" => 5,
+    "embraced" => 20,
+    "embraces" => 5,
+    "embracing" => 21,
+    "embroidered" => 20,
+    "emendation" => 1,
+    "emerald" => 21,
+    "emerge" => 5,
+    "emerged" => 20,
+    "emergence" => 1,
+    "emergencies" => 1,
+    "emergency" => 1,
+    "emerges" => 5,
+    "emerging" => 21,
+    "emigrant" => 1,
+    "emigrants" => 1,
+    "emigrated" => 20,
+    "emigration" => 5,
+    "emily" => 2,
+    "eminence" => 3,
+    "eminent" => 16,
+    "eminently" => 32,
+    "emir" => 1,
+    "emission" => 1,
+    "emissions" => 1,
+    "emit" => 4,
+    "emits" => 4,
+    "emitted" => 4,
+    "emitter" => 1,
+    "emitters" => 1,
+    "emitting" => 4,
+    "emmanuel" => 2,
+    "emolument" => 1,
+    "emoluments" => 1,
+    "emotion" => 5,
+    "emotional" => 17,
+    "emotionally" => 32,
+    "emotions" => 5,
+    "emperor" => 1,
+    "emphases" => 1,
+    "emphasis" => 1,
+    "emphasising" => 21,
+    "emphasize" => 4,
+    "emphasized" => 20,
+    "emphasizes" => 4,
+    "emphasizing" => 21,
+    "emphatically" => 17,
+    "empire" => 17,
+    "empires" => 1,
+    "empirical" => 17,
+    "empirically" => 32,
+    "employ" => 5,
+    "employed" => 20,
+    "employee" => 1,
+    "employer" => 1,
+    "employers" => 1,
+    "employing" => 21,
+    "employment" => 5,
+    "employments" => 1,
+    "employs" => 5,
+    "emporium" => 1,
+    "empower" => 4,
+    "empowering" => 21,
+    "empowers" => 4,
+    "emptied" => 20,
+    "empties" => 5,
+    "emptiness" => 21,
+    "empty" => 21,
+    "emptying" => 21,
+    "emulate" => 20,
+    "emulated" => 20,
+    "emulates" => 4,
+    "emulating" => 21,
+    "emulation" => 21,
+    "emulator" => 1,
+    "emulators" => 1,
+    "enable" => 4,
+    "enabled" => 20,
+    "enables" => 4,
+    "enabling" => 21,
+    "enact" => 4,
+    "enacted" => 20,
+    "encamp" => 4,
+    "encamped" => 20,
+    "encapsulate" => 4,
+    "encapsulated" => 20,
+    "encapsulates" => 4,
+    "encapsulating" => 21,
+    "encapsulation" => 5,
+    "encase" => 4,
+    "enchanted" => 20,
+    "enchanter" => 1,
+    "enchanting" => 21,
+    "enchantment" => 5,
+    "encipher" => 4,
+    "enciphered" => 20,
+    "encircle" => 4,
+    "encircled" => 20,
+    "enclave" => 5,
+    "enclaves" => 5,
+    "enclose" => 4,
+    "enclosed" => 20,
+    "encloses" => 4,
+    "enclosing" => 21,
+    "enclosure" => 1,
+    "enclosures" => 1,
+    "encode" => 5,
+    "encoded" => 20,
+    "encoder" => 1,
+    "encodes" => 5,
+    "encoding" => 1,
+    "encomiums" => 1,
+    "encompass" => 4,
+    "encompassed" => 20,
+    "encompasses" => 4,
+    "encompassing" => 21,
+    "encounter" => 5,
+    "encountered" => 20,
+    "encountering" => 21,
+    "encounters" => 5,
+    "encourage" => 4,
+    "encouraged" => 20,
+    "encouragement" => 5,
+    "encourages" => 4,
+    "encouraging" => 21,
+    "encroached" => 20,
+    "encrusted" => 20,
+    "encrypt" => 4,
+    "encrypted" => 20,
+    "encrypting" => 21,
+    "encryption" => 1,
+    "encrypts" => 4,
+    "encumbered" => 20,
+    "encumbrances" => 5,
+    "encyclopaedia" => 1,
+    "encyclopaedias" => 1,
+    "end" => 5,
+    "endangered" => 20,
+    "endeared" => 20,
+    "endeavor" => 5,
+    "endeavors" => 5,
+    "endeavour" => 5,
+    "endeavoured" => 20,
+    "endeavouring" => 21,
+    "endeavours" => 5,
+    "ended" => 20,
+    "endian" => 1,
+    "ending" => 21,
+    "endings" => 5,
+    "endive" => 5,
+    "endless" => 16,
+    "endlessly" => 32,
+    "endorsed" => 20,
+    "endorsement" => 5,
+    "endow" => 4,
+    "endowed" => 20,
+    "endowing" => 21,
+    "endowment" => 5,
+    "endowments" => 1,
+    "endpoint" => 5,
+    "endpoints" => 5,
+    "ends" => 5,
+    "endued" => 20,
+    "endurance" => 1,
+    "endure" => 4,
+    "endured" => 20,
+    "enduring" => 21,
+    "ene" => 17,
+    "enemies" => 5,
+    "enemy" => 5,
+    "energetic" => 17,
+    "energetically" => 17,
+    "energies" => 1,
+    "energy" => 1,
+    "enforce" => 4,
+    "enforced" => 20,
+    "enforcement" => 5,
+    "enforces" => 4,
+    "enforcing" => 21,
+    "enfranchised" => 20,
+    "engage" => 4,
+    "engaged" => 20,
+    "engagement" => 1,
+    "engagements" => 1,
+    "engages" => 4,
+    "engaging" => 21,
+    "engine" => 5,
+    "engineer" => 5,
+    "engineered" => 20,
+    "engineering" => 21,
+    "engineers" => 5,
+    "engines" => 5,
+    "england" => 2,
+    "english" => 23,
+    "englishman" => 1,
+    "englishmen" => 1,
+    "engraved" => 20,
+    "engravings" => 4,
+    "engrossed" => 20,
+    "enhance" => 4,
+    "enhanced" => 20,
+    "enhancement" => 5,
+    "enhancements" => 1,
+    "enhances" => 4,
+    "enhancing" => 21,
+    "enigmatic" => 16,
+    "enjoined" => 20,
+    "enjoy" => 4,
+    "enjoyable" => 4,
+    "enjoyed" => 20,
+    "enjoying" => 21,
+    "enjoyment" => 5,
+    "enjoyments" => 1,
+    "enjoys" => 4,
+    "enlarge" => 4,
+    "enlarged" => 20,
+    "enlargement" => 5,
+    "enlightened" => 21,
+    "enlightening" => 21,
+    "enlightenment" => 5,
+    "enlist" => 5,
+    "ennui" => 5,
+    "enormity" => 1,
+    "enormous" => 16,
+    "enormously" => 32,
+    "enough" => 257,
+    "enquire" => 4,
+    "enquired" => 20,
+    "enquiry" => 1,
+    "enraged" => 20,
+    "enraptured" => 20,
+    "enrich" => 4,
+    "enriched" => 20,
+    "enriching" => 21,
+    "enrichment" => 5,
+    "ens" => 1,
+    "ensconced" => 20,
+    "ensemble" => 5,
+    "ensembles" => 5,
+    "enshrined" => 20,
+    "enslave" => 4,
+    "enslaved" => 20,
+    "ensue" => 4,
+    "ensued" => 20,
+    "ensuing" => 21,
+    "ensure" => 4,
+    "ensured" => 20,
+    "ensures" => 4,
+    "ensuring" => 21,
+    "entail" => 5,
+    "entailed" => 20,
+    "entails" => 5,
+    "entangled" => 20,
+    "enter" => 5,
+    "entered" => 20,
+    "entering" => 21,
+    "enterprise" => 7,
+    "enterprises" => 5,
+    "enterprising" => 21,
+    "enters" => 5,
+    "entertain" => 5,
+    "entertained" => 20,
+    "entertainer" => 1,
+    "entertaining" => 21,
+    "entertainment" => 5,
+    "enthusiasm" => 1,
+    "enthusiast" => 1,
+    "enthusiastic" => 16,
+    "enthusiastically" => 16,
+    "enthusiasts" => 1,
+    "enticed" => 20,
+    "enticement" => 5,
+    "enticements" => 1,
+    "enticing" => 21,
+    "entire" => 17,
+    "entirely" => 32,
+    "entirety" => 1,
+    "entities" => 1,
+    "entitled" => 20,
+    "entitlements" => 1,
+    "entitles" => 4,
+    "entity" => 1,
+    "entomologists" => 1,
+    "entrance" => 5,
+    "entranced" => 20,
+    "entrant" => 1,
+    "entreat" => 5,
+    "entreated" => 20,
+    "entreaties" => 1,
+    "entreating" => 21,
+    "entrench" => 4,
+    "entries" => 1,
+    "entropy" => 1,
+    "entrusted" => 20,
+    "entry" => 1,
+    "entwined" => 20,
+    "enumerate" => 4,
+    "enumerated" => 20,
+    "enumerates" => 4,
+    "enumerating" => 21,
+    "enumeration" => 5,
+    "enumerators" => 1,
+    "enunciation" => 5,
+    "envelop" => 4,
+    "envelope" => 5,
+    "enveloped" => 20,
+    "envelopes" => 5,
+    "enviable" => 16,
+    "envied" => 20,
+    "envious" => 16,
+    "environment" => 1,
+    "environmental" => 17,
+    "environments" => 1,
+    "environs" => 5,
+    "envision" => 4,
+    "envisions" => 4,
+    "envy" => 5,
+    "envying" => 21,
+    "enzyme" => 1,
+    "ephemeral" => 17,
+    "epic" => 17,
+    "epicurean" => 17,
+    "epidemic" => 17,
+    "epigram" => 1,
+    "epilogue" => 5,
+    "episode" => 1,
+    "episodes" => 1,
+    "epistemology" => 1,
+    "epistle" => 5,
+    "epithet" => 5,
+    "epithets" => 5,
+    "epoch" => 5,
+    "epochs" => 1,
+    "eponymous" => 16,
+    "epsilon" => 1,
+    "equal" => 21,
+    "equaling" => 21,
+    "equality" => 1,
+    "equalize" => 4,
+    "equalized" => 20,
+    "equalizing" => 21,
+    "equalled" => 20,
+    "equally" => 32,
+    "equals" => 5,
+    "equanimity" => 1,
+    "equated" => 20,
+    "equation" => 5,
+    "equations" => 5,
+    "equator" => 1,
+    "equidistant" => 16,
+    "equilibrium" => 1,
+    "equinoctial" => 17,
+    "equinox" => 1,
+    "equinoxes" => 1,
+    "equipages" => 5,
+    "equipment" => 1,
+    "equipped" => 20,
+    "equips" => 5,
+    "equitable" => 16,
+    "equitably" => 32,
+    "equity" => 1,
+    "equivalence" => 5,
+    "equivalences" => 5,
+    "equivalent" => 21,
+    "equivalently" => 32,
+    "equivalents" => 5,
+    "equivocations" => 4,
+    "era" => 3,
+    "eradicated" => 20,
+    "eradicating" => 21,
+    "erase" => 5,
+    "erased" => 20,
+    "erases" => 5,
+    "erasing" => 21,
+    "erasure" => 1,
+    "ere" => 577,
+    "erect" => 20,
+    "erected" => 20,
+    "erection" => 1,
+    "ergonomic" => 16,
+    "ergonomically" => 16,
+    "ergonomics" => 17,
+    "eric" => 2,
+    "ernest" => 2,
+    "ernie" => 2,
+    "err" => 4,
+    "errand" => 5,
+    "errands" => 5,
+    "errant" => 17,
+    "errata" => 1,
+    "erratic" => 17,
+    "erratically" => 17,
+    "erred" => 20,
+    "erroneous" => 16,
+    "erroneously" => 32,
+    "error" => 5,
+    "errors" => 5,
+    "escalation" => 5,
+    "escapade" => 1,
+    "escape" => 5,
+    "escaped" => 20,
+    "escapes" => 5,
+    "escaping" => 21,
+    "escargot" => 1,
+    "escort" => 5,
+    "escorted" => 20,
+    "escorting" => 21,
+    "esoteric" => 17,
+    "esp" => 1,
+    "especial" => 16,
+    "especially" => 32,
+    "esperanto" => 2,
+    "espied" => 20,
+    "espoused" => 20,
+    "espresso" => 1,
+    "espy" => 5,
+    "espying" => 21,
+    "esquire" => 5,
+    "essay" => 5,
+    "essayed" => 20,
+    "essays" => 5,
+    "essence" => 1,
+    "essential" => 17,
+    "essentially" => 32,
+    "essentials" => 1,
+    "est" => 259,
+    "establish" => 4,
+    "established" => 20,
+    "establishes" => 4,
+    "establishing" => 21,
+    "establishment" => 5,
+    "establishments" => 1,
+    "estate" => 21,
+    "estates" => 5,
+    "esteem" => 5,
+    "esteemed" => 20,
+    "estimable" => 16,
+    "estimate" => 5,
+    "estimated" => 20,
+    "estimates" => 5,
+    "estimating" => 21,
+    "estimation" => 5,
+    "estimations" => 5,
+    "estimator" => 1,
+    "estimators" => 1,
+    "estonian" => 17,
+    "estoppel" => 1,
+    "estuary" => 1,
+    "eta" => 7,
+    "etchings" => 5,
+    "eternal" => 17,
+    "eternally" => 32,
+    "eternity" => 1,
+    "ether" => 5,
+    "ethereal" => 17,
+    "ethical" => 17,
+    "ethics" => 1,
+    "ethnically" => 17,
+    "ethos" => 1,
+    "eton" => 2,
+    "etymology" => 1,
+    "euclid" => 2,
+    "euclidean" => 16,
+    "eugene" => 2,
+    "eulogies" => 1,
+    "eulogy" => 1,
+    "euphemisms" => 1,
+    "euphoria" => 1,
+    "euro" => 1,
+    "europe" => 2,
+    "european" => 17,
+    "europeans" => 1,
+    "evaded" => 20,
+    "evaluate" => 4,
+    "evaluated" => 20,
+    "evaluates" => 4,
+    "evaluating" => 21,
+    "evaluation" => 5,
+    "evaluations" => 4,
+    "evaluative" => 4,
+    "evaluator" => 1,
+    "evan" => 2,
+    "evanescent" => 16,
+    "evans" => 2,
+    "evasion" => 1,
+    "evasions" => 1,
+    "eve" => 3,
+    "even" => 21,
+    "evening" => 21,
+    "evenings" => 21,
+    "evenly" => 32,
+    "evens" => 5,
+    "event" => 5,
+    "eventful" => 16,
+    "events" => 5,
+    "eventual" => 16,
+    "eventuality" => 1,
+    "eventually" => 32,
+    "ever" => 16,
+    "evergreen" => 21,
+    "everlasting" => 17,
+    "everlastingly" => 32,
+    "every" => 128,
+    "everybody" => 256,
+    "everyday" => 17,
+    "everyone" => 256,
+    "everything" => 260,
+    "evict" => 4,
+    "evicted" => 20,
+    "eviction" => 1,
+    "evictions" => 1,
+    "evicts" => 4,
+    "evidence" => 5,
+    "evidenced" => 20,
+    "evidences" => 5,
+    "evident" => 16,
+    "evidently" => 32,
+    "evil" => 17,
+    "evils" => 1,
+    "evinced" => 20,
+    "evoked" => 20,
+    "evokes" => 4,
+    "evolution" => 1,
+    "evolutionary" => 16,
+    "evolve" => 4,
+    "evolved" => 20,
+    "evolves" => 4,
+    "evolving" => 21,
+    "ewe" => 1,
+    "ex" => 21,
+    "exact" => 20,
+    "exacted" => 20,
+    "exacting" => 21,
+    "exactitude" => 1,
+    "exactly" => 32,
+    "exactness" => 21,
+    "exaggerate" => 20,
+    "exaggerated" => 20,
+    "exaggeration" => 21,
+    "exaltation" => 1,
+    "exalted" => 20,
+    "exalting" => 21,
+    "exam" => 5,
+    "examination" => 1,
+    "examine" => 5,
+    "examined" => 20,
+    "examines" => 5,
+    "examining" => 21,
+    "example" => 5,
+    "examples" => 5,
+    "exasperate" => 20,
+    "exasperated" => 20,
+    "excavating" => 21,
+    "exceed" => 4,
+    "exceeded" => 20,
+    "exceeding" => 21,
+    "exceedingly" => 32,
+    "exceeds" => 4,
+    "excel" => 4,
+    "excellence" => 1,
+    "excellent" => 16,
+    "excelling" => 4,
+    "excels" => 4,
+    "except" => 580,
+    "excepted" => 20,
+    "excepting" => 21,
+    "exception" => 1,
+    "exceptional" => 17,
+    "exceptionally" => 32,
+    "exceptions" => 1,
+    "excerpt" => 5,
+    "excess" => 21,
+    "excesses" => 5,
+    "excessive" => 21,
+    "excessively" => 32,
+    "exchange" => 5,
+    "exchanged" => 20,
+    "exchanger" => 1,
+    "exchanges" => 5,
+    "exchanging" => 21,
+    "exchequer" => 7,
+    "excises" => 5,
+    "excitable" => 4,
+    "excite" => 4,
+    "excited" => 20,
+    "excitedly" => 32,
+    "excitement" => 5,
+    "excitements" => 1,
+    "excites" => 4,
+    "exciting" => 21,
+    "excitingly" => 32,
+    "exclaim" => 5,
+    "exclaimed" => 20,
+    "exclaiming" => 21,
+    "exclamation" => 1,
+    "exclamations" => 1,
+    "exclude" => 4,
+    "excluded" => 20,
+    "excludes" => 4,
+    "excluding" => 21,
+    "exclusion" => 1,
+    "exclusions" => 1,
+    "exclusive" => 17,
+    "exclusively" => 32,
+    "exclusiveness" => 17,
+    "exclusivity" => 1,
+    "excommunication" => 21,
+    "exculpated" => 20,
+    "excursion" => 5,
+    "excursions" => 5,
+    "excuse" => 5,
+    "excused" => 20,
+    "excuses" => 5,
+    "exec" => 5,
+    "execrate" => 4,
+    "execrated" => 20,
+    "execration" => 5,
+    "execs" => 5,
+    "executable" => 21,
+    "execute" => 4,
+    "executed" => 20,
+    "executes" => 4,
+    "executing" => 21,
+    "execution" => 5,
+    "executioner" => 1,
+    "executioners" => 1,
+    "executions" => 4,
+    "executive" => 21,
+    "executives" => 1,
+    "executor" => 1,
+    "executors" => 1,
+    "exemplary" => 17,
+    "exemplified" => 20,
+    "exemplifies" => 4,
+    "exempt" => 21,
+    "exempted" => 20,
+    "exemptions" => 1,
+    "exercise" => 5,
+    "exercised" => 20,
+    "exercises" => 5,
+    "exercising" => 21,
+    "exert" => 4,
+    "exerted" => 20,
+    "exerting" => 21,
+    "exertion" => 1,
+    "exertions" => 1,
+    "exhaust" => 21,
+    "exhausted" => 20,
+    "exhausting" => 21,
+    "exhaustion" => 1,
+    "exhaustive" => 21,
+    "exhaustively" => 32,
+    "exhaustiveness" => 17,
+    "exhausts" => 5,
+    "exhibit" => 5,
+    "exhibited" => 20,
+    "exhibition" => 1,
+    "exhibits" => 5,
+    "exhilarated" => 20,
+    "exhilarating" => 21,
+    "exhortations" => 1,
+    "exile" => 5,
+    "exist" => 4,
+    "existed" => 20,
+    "existence" => 1,
+    "existent" => 17,
+    "existential" => 17,
+    "existing" => 21,
+    "exists" => 4,
+    "exit" => 5,
+    "exited" => 20,
+    "exiting" => 21,
+    "exits" => 5,
+    "exotic" => 17,
+    "expand" => 4,
+    "expanded" => 20,
+    "expander" => 1,
+    "expanding" => 21,
+    "expands" => 4,
+    "expanse" => 1,
+    "expansion" => 1,
+    "expansions" => 1,
+    "expansive" => 17,
+    "expat" => 1,
+    "expect" => 4,
+    "expectancy" => 1,
+    "expectant" => 17,
+    "expectantly" => 32,
+    "expectation" => 1,
+    "expectations" => 1,
+    "expected" => 21,
+    "expecting" => 21,
+    "expectorate" => 4,
+    "expectoration" => 5,
+    "expects" => 4,
+    "expedience" => 1,
+    "expedient" => 17,
+    "expedite" => 20,
+    "expedition" => 21,
+    "expeditions" => 20,
+    "expeditiously" => 32,
+    "expel" => 4,
+    "expend" => 4,
+    "expendable" => 21,
+    "expended" => 20,
+    "expending" => 21,
+    "expenditure" => 1,
+    "expenditures" => 1,
+    "expense" => 5,
+    "expenses" => 5,
+    "expensive" => 16,
+    "experience" => 5,
+    "experienced" => 20,
+    "experiences" => 5,
+    "experiencing" => 5,
+    "experiment" => 5,
+    "experimental" => 17,
+    "experimentally" => 32,
+    "experimentation" => 1,
+    "experimented" => 20,
+    "experimenter" => 1,
+    "experimenting" => 21,
+    "experiments" => 5,
+    "expert" => 17,
+    "expertise" => 5,
+    "experts" => 1,
+    "expiration" => 1,
+    "expire" => 4,
+    "expired" => 20,
+    "expires" => 4,
+    "expiring" => 21,
+    "expiry" => 1,
+    "explain" => 4,
+    "explainable" => 16,
+    "explained" => 20,
+    "explaining" => 21,
+    "explains" => 4,
+    "explanation" => 1,
+    "explanations" => 1,
+    "explanatory" => 16,
+    "explicable" => 16,
+    "explicit" => 17,
+    "explicitly" => 32,
+    "explicitness" => 17,
+    "explode" => 4,
+    "exploded" => 20,
+    "exploding" => 21,
+    "exploit" => 5,
+    "exploitable" => 5,
+    "exploitation" => 1,
+    "exploited" => 20,
+    "exploiting" => 21,
+    "exploits" => 5,
+    "exploration" => 1,
+    "exploratory" => 17,
+    "explore" => 5,
+    "explored" => 20,
+    "explorer" => 1,
+    "explorers" => 5,
+    "explores" => 5,
+    "exploring" => 21,
+    "explosion" => 1,
+    "explosive" => 17,
+    "exponent" => 1,
+    "exponential" => 17,
+    "exponentially" => 32,
+    "exponentiation" => 4,
+    "exponents" => 1,
+    "export" => 21,
+    "exportation" => 1,
+    "exported" => 20,
+    "exporter" => 17,
+    "exporters" => 21,
+    "exporting" => 21,
+    "exports" => 5,
+    "expose" => 4,
+    "exposed" => 20,
+    "exposes" => 4,
+    "exposing" => 21,
+    "exposition" => 1,
+    "expository" => 16,
+    "expostulate" => 4,
+    "expostulating" => 21,
+    "expostulation" => 5,
+    "exposure" => 1,
+    "expound" => 4,
+    "express" => 21,
+    "expressed" => 20,
+    "expresses" => 5,
+    "expressible" => 16,
+    "expressing" => 21,
+    "expression" => 1,
+    "expressions" => 1,
+    "expressive" => 21,
+    "expressiveness" => 17,
+    "expressly" => 32,
+    "exquisite" => 17,
+    "exquisitely" => 32,
+    "extant" => 16,
+    "extemporizing" => 21,
+    "extend" => 5,
+    "extendable" => 5,
+    "extended" => 20,
+    "extender" => 1,
+    "extending" => 21,
+    "extends" => 5,
+    "extensibility" => 1,
+    "extensible" => 16,
+    "extension" => 1,
+    "extensions" => 1,
+    "extensive" => 16,
+    "extensively" => 32,
+    "extent" => 17,
+    "extents" => 1,
+    "exterior" => 17,
+    "external" => 17,
+    "externalized" => 20,
+    "externally" => 32,
+    "externals" => 1,
+    "extinct" => 21,
+    "extinction" => 1,
+    "extinguish" => 4,
+    "extinguished" => 20,
+    "extinguisher" => 1,
+    "extinguishes" => 4,
+    "extort" => 20,
+    "extra" => 17,
+    "extract" => 5,
+    "extracted" => 20,
+    "extracting" => 21,
+    "extraction" => 1,
+    "extractions" => 1,
+    "extractive" => 5,
+    "extractor" => 1,
+    "extractors" => 1,
+    "extracts" => 5,
+    "extradition" => 5,
+    "extraneous" => 16,
+    "extraordinarily" => 32,
+    "extraordinary" => 17,
+    "extrapolate" => 4,
+    "extrapolating" => 21,
+    "extrapolation" => 5,
+    "extras" => 1,
+    "extravagantly" => 32,
+    "extravaganza" => 1,
+    "extreme" => 17,
+    "extremely" => 32,
+    "extremes" => 1,
+    "extremities" => 1,
+    "extremity" => 1,
+    "extremum" => 1,
+    "extricate" => 4,
+    "exuberant" => 16,
+    "exult" => 4,
+    "exultation" => 1,
+    "exulting" => 21,
+    "eye" => 5,
+    "eyeball" => 5,
+    "eyeballs" => 5,
+    "eyebrow" => 5,
+    "eyebrows" => 5,
+    "eyed" => 20,
+    "eyeglasses" => 1,
+    "eyelashes" => 1,
+    "eyelids" => 1,
+    "eyes" => 5,
+    "eyesore" => 1,
+    "eyre" => 2,
+    "fab" => 21,
+    "fabian" => 19,
+    "fable" => 5,
+    "fabric" => 5,
+    "fabricate" => 4,
+    "fabrication" => 5,
+    "fabulous" => 16,
+    "facade" => 1,
+    "face" => 5,
+    "faced" => 20,
+    "faces" => 5,
+    "facet" => 5,
+    "facetious" => 16,
+    "facetiously" => 32,
+    "facets" => 5,
+    "facile" => 16,
+    "facilitate" => 4,
+    "facilitated" => 20,
+    "facilitates" => 4,
+    "facilitating" => 21,
+    "facilities" => 1,
+    "facility" => 1,
+    "facing" => 21,
+    "facsimile" => 5,
+    "facsimiles" => 5,
+    "fact" => 1,
+    "factor" => 5,
+    "factored" => 20,
+    "factorial" => 17,
+    "factories" => 1,
+    "factoring" => 21,
+    "factorization" => 1,
+    "factorizations" => 1,
+    "factors" => 5,
+    "factory" => 17,
+    "facts" => 1,
+    "factual" => 17,
+    "faculties" => 1,
+    "faculty" => 1,
+    "fad" => 1,
+    "faddy" => 16,
+    "fade" => 21,
+    "faded" => 16,
+    "fading" => 21,
+    "fads" => 1,
+    "fagged" => 4,
+    "fahrenheit" => 16,
+    "fail" => 21,
+    "failed" => 20,
+    "failing" => 85,
+    "fails" => 5,
+    "failure" => 1,
+    "failures" => 1,
+    "fain" => 20,
+    "faint" => 21,
+    "fainted" => 20,
+    "fainter" => 17,
+    "faintest" => 16,
+    "fainting" => 21,
+    "faintly" => 32,
+    "faintness" => 21,
+    "fair" => 21,
+    "fairer" => 17,
+    "fairest" => 16,
+    "fairies" => 1,
+    "fairly" => 32,
+    "fairness" => 21,
+    "fairy" => 17,
+    "faith" => 3,
+    "faithful" => 17,
+    "faithfully" => 32,
+    "fake" => 21,
+    "faked" => 20,
+    "fakers" => 21,
+    "fakes" => 5,
+    "fakirs" => 1,
+    "falcon" => 5,
+    "falkland" => 2,
+    "fall" => 5,
+    "fallback" => 21,
+    "fallen" => 5,
+    "fallibility" => 1,
+    "fallible" => 16,
+    "fallibly" => 32,
+    "falling" => 21,
+    "falls" => 5,
+    "false" => 21,
+    "falsehood" => 1,
+    "falsehoods" => 1,
+    "falsely" => 32,
+    "faltered" => 20,
+    "faltering" => 21,
+    "fame" => 5,
+    "famed" => 20,
+    "familiar" => 17,
+    "familiarised" => 20,
+    "familiarity" => 1,
+    "familiarize" => 4,
+    "familiarizing" => 21,
+    "familiarly" => 32,
+    "families" => 1,
+    "family" => 17,
+    "famine" => 1,
+    "famished" => 20,
+    "famous" => 20,
+    "famously" => 32,
+    "fan" => 5,
+    "fanatical" => 16,
+    "fanaticism" => 1,
+    "fanatics" => 1,
+    "fancied" => 20,
+    "fancier" => 17,
+    "fancies" => 5,
+    "fanciest" => 16,
+    "fanciful" => 16,
+    "fancy" => 21,
+    "fancying" => 21,
+    "fang" => 5,
+    "fangs" => 5,
+    "fanlight" => 1,
+    "fanned" => 4,
+    "fanning" => 5,
+    "fans" => 5,
+    "fantastic" => 17,
+    "fantasy" => 5,
+    "far" => 21,
+    "fare" => 5,
+    "fared" => 20,
+    "farewell" => 21,
+    "farm" => 5,
+    "farmer" => 3,
+    "farmers" => 5,
+    "farmhouse" => 1,
+    "farming" => 21,
+    "farms" => 5,
+    "farsi" => 17,
+    "farther" => 20,
+    "farthest" => 16,
+    "farthing" => 1,
+    "farting" => 21,
+    "fascinate" => 4,
+    "fascinated" => 20,
+    "fascinating" => 21,
+    "fascination" => 5,
+    "fascinations" => 4,
+    "fashion" => 5,
+    "fashionable" => 21,
+    "fashionably" => 32,
+    "fashioned" => 20,
+    "fashions" => 5,
+    "fast" => 21,
+    "fasten" => 4,
+    "fastened" => 20,
+    "fasteners" => 1,
+    "fastening" => 21,
+    "fastenings" => 5,
+    "fastens" => 4,
+    "faster" => 17,
+    "fastest" => 16,
+    "fastidious" => 16,
+    "fat" => 21,
+    "fatal" => 17,
+    "fatality" => 1,
+    "fatally" => 32,
+    "fate" => 5,
+    "fated" => 20,
+    "fateful" => 16,
+    "father" => 7,
+    "father's" => 1,
+    "fathers" => 5,
+    "fathom" => 5,
+    "fathomed" => 20,
+    "fatigue" => 5,
+    "fatigued" => 20,
+    "fatigues" => 5,
+    "fatiguing" => 21,
+    "fatten" => 4,
+    "fattened" => 20,
+    "fattest" => 16,
+    "faucet" => 1,
+    "fault" => 5,
+    "faultiness" => 17,
+    "faults" => 5,
+    "faulty" => 16,
+    "favor" => 5,
+    "favored" => 20,
+    "favoring" => 21,
+    "favorite" => 21,
+    "favors" => 5,
+    "favour" => 5,
+    "favourable" => 17,
+    "favourably" => 32,
+    "favoured" => 20,
+    "favouring" => 21,
+    "favourite" => 21,
+    "favourites" => 5,
+    "favours" => 5,
+    "fawn" => 21,
+    "fax" => 5,
+    "fay" => 23,
+    "fear" => 5,
+    "feared" => 20,
+    "fearful" => 16,
+    "fearfully" => 32,
+    "fearing" => 21,
+    "fearless" => 16,
+    "fearlessly" => 32,
+    "fears" => 5,
+    "feasibility" => 1,
+    "feasible" => 16,
+    "feasibly" => 32,
+    "feast" => 5,
+    "feasting" => 21,
+    "feat" => 21,
+    "feather" => 5,
+    "feathers" => 5,
+    "feats" => 5,
+    "feature" => 5,
+    "featured" => 20,
+    "featureless" => 16,
+    "features" => 5,
+    "featuring" => 21,
+    "february" => 2,
+    "fed" => 23,
+    "federal" => 17,
+    "federated" => 20,
+    "federation" => 21,
+    "fedora" => 1,
+    "fee" => 5,
+    "feeble" => 20,
+    "feebleness" => 21,
+    "feebly" => 32,
+    "feed" => 5,
+    "feedback" => 5,
+    "feeder" => 1,
+    "feeding" => 21,
+    "feeds" => 5,
+    "feel" => 261,
+    "feeler" => 1,
+    "feelers" => 261,
+    "feeling" => 21,
+    "feelings" => 261,
+    "feels" => 5,
+    "fees" => 5,
+    "feet" => 1,
+    "feigned" => 20,
+    "feigning" => 21,
+    "feint" => 21,
+    "felicity" => 3,
+    "felix" => 2,
+    "fell" => 21,
+    "felled" => 20,
+    "feller" => 17,
+    "fellow" => 5,
+    "fellows" => 5,
+    "fellowship" => 5,
+    "felonies" => 1,
+    "felony" => 1,
+    "felt" => 21,
+    "felts" => 5,
+    "female" => 17,
+    "females" => 1,
+    "feminine" => 17,
+    "femme" => 1,
+    "fen" => 1,
+    "fence" => 5,
+    "fenced" => 20,
+    "fences" => 5,
+    "fencing" => 21,
+    "fender" => 5,
+    "fenders" => 5,
+    "fennel" => 1,
+    "ferguson" => 2,
+    "fermentation" => 1,
+    "fern" => 3,
+    "ferns" => 1,
+    "ferocious" => 16,
+    "ferociously" => 32,
+    "ferocity" => 1,
+    "ferrari" => 3,
+    "ferret" => 5,
+    "ferrets" => 5,
+    "ferris" => 2,
+    "ferrous" => 16,
+    "ferry" => 5,
+    "fertile" => 16,
+    "ferule" => 5,
+    "fervent" => 16,
+    "fervently" => 32,
+    "fervour" => 1,
+    "festering" => 21,
+    "festival" => 17,
+    "festivals" => 1,
+    "festive" => 17,
+    "festivities" => 1,
+    "festivity" => 1,
+    "festooning" => 21,
+    "festoons" => 5,
+    "fetch" => 5,
+    "fetched" => 20,
+    "fetches" => 5,
+    "fetching" => 21,
+    "fetter" => 5,
+    "fettered" => 20,
+    "fetters" => 5,
+    "feudal" => 16,
+    "fever" => 5,
+    "feverish" => 16,
+    "feverishly" => 32,
+    "few" => 384,
+    "fewer" => 144,
+    "fewest" => 384,
+    "fiat" => 5,
+    "fiber" => 1,
+    "fibers" => 5,
+    "fibre" => 1,
+    "fibres" => 1,
+    "fickle" => 20,
+    "fiction" => 1,
+    "fictional" => 16,
+    "fiddle" => 5,
+    "fiddles" => 5,
+    "fiddlesticks" => 1,
+    "fiddling" => 21,
+    "fiddly" => 16,
+    "fidelity" => 1,
+    "fidget" => 5,
+    "fidgeted" => 20,
+    "field" => 5,
+    "fields" => 7,
+    "fiend" => 5,
+    "fiendish" => 16,
+    "fiendishly" => 32,
+    "fierce" => 16,
+    "fiercely" => 32,
+    "fierceness" => 17,
+    "fiery" => 16,
+    "fifteen" => 1,
+    "fifteenth" => 17,
+    "fifth" => 21,
+    "fifths" => 1,
+    "fifties" => 1,
+    "fiftieth" => 17,
+    "fifty" => 1,
+    "fig" => 5,
+    "fight" => 5,
+    "fighting" => 21,
+    "fights" => 5,
+    "figment" => 5,
+    "figs" => 5,
+    "figurative" => 16,
+    "figure" => 5,
+    "figured" => 20,
+    "figures" => 5,
+    "figuring" => 21,
+    "fijian" => 19,
+    "file" => 5,
+    "filed" => 20,
+    "filename" => 1,
+    "filenames" => 1,
+    "filer" => 1,
+    "files" => 5,
+    "filet" => 5,
+    "filial" => 16,
+    "filigree" => 5,
+    "filing" => 21,
+    "fill" => 5,
+    "filled" => 20,
+    "filler" => 1,
+    "filling" => 21,
+    "fillip" => 5,
+    "fills" => 5,
+    "film" => 5,
+    "filming" => 21,
+    "films" => 5,
+    "filter" => 5,
+    "filtered" => 20,
+    "filtering" => 21,
+    "filters" => 5,
+    "filthy" => 20,
+    "fin" => 5,
+    "final" => 21,
+    "finalised" => 20,
+    "finalists" => 1,
+    "finality" => 1,
+    "finalize" => 4,
+    "finalized" => 20,
+    "finalizes" => 4,
+    "finalizing" => 21,
+    "finally" => 32,
+    "finals" => 5,
+    "finance" => 5,
+    "financial" => 16,
+    "financially" => 32,
+    "financier" => 5,
+    "find" => 5,
+    "finder" => 1,
+    "finding" => 21,
+    "findings" => 5,
+    "finds" => 5,
+    "fine" => 21,
+    "finely" => 32,
+    "finer" => 1,
+    "finery" => 1,
+    "fines" => 5,
+    "finest" => 16,
+    "finger" => 5,
+    "fingered" => 20,
+    "fingerprint" => 5,
+    "fingerprinting" => 21,
+    "fingerprints" => 5,
+    "fingers" => 5,
+    "fingertips" => 5,
+    "finis" => 1,
+    "finish" => 5,
+    "finished" => 20,
+    "finishes" => 5,
+    "finishing" => 21,
+    "finite" => 17,
+    "finitely" => 32,
+    "finn" => 3,
+    "finnish" => 17,
+    "finns" => 1,
+    "fir" => 1,
+    "fire" => 21,
+    "firearms" => 1,
+    "fireball" => 21,
+    "firecracker" => 1,
+    "fired" => 16,
+    "firelight" => 1,
+    "fireman" => 1,
+    "firemen" => 1,
+    "fireplace" => 1,
+    "fires" => 5,
+    "fireside" => 1,
+    "firesides" => 1,
+    "firewall" => 5,
+    "fireworks" => 1,
+    "firing" => 21,
+    "firm" => 21,
+    "firmest" => 16,
+    "firmly" => 32,
+    "firmness" => 21,
+    "firmware" => 1,
+    "firs" => 1,
+    "first" => 21,
+    "firsthand" => 48,
+    "firstly" => 32,
+    "fiscal" => 17,
+    "fischer" => 2,
+    "fish" => 5,
+    "fished" => 20,
+    "fisher" => 3,
+    "fishermen" => 1,
+    "fishers" => 5,
+    "fishes" => 5,
+    "fishing" => 21,
+    "fist" => 5,
+    "fists" => 5,
+    "fit" => 21,
+    "fitfully" => 32,
+    "fitness" => 1,
+    "fits" => 5,
+    "fitted" => 21,
+    "fitter" => 17,
+    "fitting" => 21,
+    "fittings" => 5,
+    "fitzgerald" => 2,
+    "five" => 1,
+    "fiver" => 1,
+    "fix" => 5,
+    "fixable" => 5,
+    "fixed" => 20,
+    "fixer" => 1,
+    "fixes" => 5,
+    "fixing" => 21,
+    "fixture" => 5,
+    "fixtures" => 5,
+    "flabby" => 16,
+    "flag" => 5,
+    "flagged" => 20,
+    "flagging" => 21,
+    "flagrant" => 16,
+    "flags" => 5,
+    "flagship" => 5,
+    "flake" => 5,
+    "flakes" => 5,
+    "flaky" => 16,
+    "flame" => 21,
+    "flamed" => 20,
+    "flames" => 5,
+    "flaming" => 21,
+    "flamingo" => 17,
+    "flanagan" => 2,
+    "flank" => 21,
+    "flanks" => 5,
+    "flannel" => 21,
+    "flannels" => 5,
+    "flap" => 5,
+    "flapped" => 20,
+    "flapper" => 1,
+    "flappers" => 1,
+    "flare" => 5,
+    "flaring" => 21,
+    "flash" => 21,
+    "flashed" => 20,
+    "flashes" => 5,
+    "flashing" => 21,
+    "flask" => 5,
+    "flat" => 21,
+    "flatboat" => 1,
+    "flatly" => 32,
+    "flatness" => 21,
+    "flatten" => 4,
+    "flattened" => 20,
+    "flattening" => 21,
+    "flattens" => 4,
+    "flatter" => 21,
+    "flattered" => 20,
+    "flattery" => 1,
+    "flaubert" => 2,
+    "flavor" => 5,
+    "flavored" => 20,
+    "flavors" => 5,
+    "flavour" => 5,
+    "flavours" => 5,
+    "flaw" => 5,
+    "flawed" => 20,
+    "flawless" => 16,
+    "flaws" => 5,
+    "flax" => 1,
+    "flaying" => 21,
+    "flecked" => 20,
+    "fled" => 16,
+    "fledged" => 20,
+    "flee" => 4,
+    "fleecy" => 16,
+    "fleet" => 21,
+    "fleetest" => 16,
+    "fleeting" => 21,
+    "fleming" => 3,
+    "flemish" => 18,
+    "flesh" => 5,
+    "fleshed" => 20,
+    "flew" => 21,
+    "flex" => 5,
+    "flexibility" => 1,
+    "flexible" => 17,
+    "flexibly" => 32,
+    "flicked" => 20,
+    "flicker" => 5,
+    "flickered" => 20,
+    "flickering" => 21,
+    "flickers" => 5,
+    "flicking" => 21,
+    "flies" => 5,
+    "flight" => 21,
+    "flighty" => 16,
+    "flinch" => 5,
+    "flinched" => 20,
+    "fling" => 21,
+    "flinging" => 21,
+    "flint" => 7,
+    "flip" => 21,
+    "flipped" => 4,
+    "flipper" => 17,
+    "flipping" => 21,
+    "flips" => 5,
+    "flirt" => 21,
+    "flirtation" => 1,
+    "flirting" => 21,
+    "flit" => 21,
+    "flitted" => 4,
+    "flitting" => 21,
+    "float" => 5,
+    "floated" => 20,
+    "floating" => 21,
+    "floats" => 5,
+    "flock" => 5,
+    "flocked" => 20,
+    "flocking" => 21,
+    "flocks" => 5,
+    "flogged" => 4,
+    "flogging" => 5,
+    "floggings" => 5,
+    "flood" => 5,
+    "flooded" => 20,
+    "flooding" => 21,
+    "floods" => 5,
+    "floor" => 5,
+    "flooring" => 21,
+    "flop" => 5,
+    "floppy" => 17,
+    "flora" => 3,
+    "florid" => 16,
+    "florida" => 2,
+    "florins" => 1,
+    "floss" => 7,
+    "flotilla" => 1,
+    "flounced" => 20,
+    "floundered" => 20,
+    "floundering" => 21,
+    "flourish" => 5,
+    "flourished" => 20,
+    "flourishing" => 21,
+    "flow" => 5,
+    "flowed" => 20,
+    "flower" => 5,
+    "flowered" => 20,
+    "flowers" => 7,
+    "flowery" => 16,
+    "flowing" => 21,
+    "flown" => 20,
+    "flows" => 5,
+    "floyd" => 2,
+    "fluctuate" => 4,
+    "fluctuating" => 21,
+    "fluctuations" => 4,
+    "fluent" => 17,
+    "fluently" => 32,
+    "fluff" => 5,
+    "fluffy" => 17,
+    "fluid" => 17,
+    "fluids" => 1,
+    "flume" => 5,
+    "flung" => 4,
+    "flurried" => 20,
+    "flurry" => 5,
+    "flush" => 21,
+    "flushed" => 20,
+    "flushes" => 5,
+    "flushing" => 21,
+    "flustered" => 20,
+    "flutes" => 5,
+    "flutter" => 5,
+    "fluttered" => 20,
+    "fluttering" => 21,
+    "flux" => 21,
+    "fly" => 21,
+    "flying" => 21,
+    "flyway" => 1,
+    "foam" => 5,
+    "foaming" => 21,
+    "foamy" => 17,
+    "fob" => 21,
+    "focal" => 17,
+    "focus" => 5,
+    "focused" => 20,
+    "focuses" => 5,
+    "focusing" => 21,
+    "foe" => 17,
+    "foes" => 1,
+    "fog" => 5,
+    "fogs" => 5,
+    "foil" => 5,
+    "fold" => 5,
+    "foldable" => 5,
+    "folded" => 20,
+    "folder" => 1,
+    "folders" => 1,
+    "folding" => 21,
+    "folds" => 5,
+    "foliage" => 1,
+    "folk" => 17,
+    "folklore" => 1,
+    "folks" => 1,
+    "follies" => 5,
+    "follow" => 5,
+    "followed" => 20,
+    "followers" => 5,
+    "following" => 85,
+    "followings" => 5,
+    "follows" => 5,
+    "folly" => 5,
+    "fond" => 21,
+    "fonder" => 17,
+    "fondled" => 20,
+    "fondly" => 32,
+    "fondness" => 21,
+    "font" => 5,
+    "fonts" => 5,
+    "food" => 1,
+    "fool" => 21,
+    "fooled" => 20,
+    "foolhardy" => 17,
+    "fooling" => 21,
+    "foolish" => 16,
+    "foolishly" => 32,
+    "foolishness" => 17,
+    "fools" => 5,
+    "foolscap" => 1,
+    "foot" => 5,
+    "footage" => 1,
+    "football" => 5,
+    "footed" => 20,
+    "footer" => 1,
+    "footers" => 5,
+    "footfall" => 1,
+    "footfalls" => 1,
+    "foothold" => 1,
+    "footing" => 21,
+    "footlights" => 1,
+    "footman" => 1,
+    "footmen" => 1,
+    "footnote" => 5,
+    "footnotes" => 5,
+    "footpaths" => 1,
+    "footprint" => 1,
+    "footprints" => 1,
+    "footstep" => 1,
+    "footsteps" => 1,
+    "for" => 576,
+    "forbade" => 4,
+    "forbear" => 5,
+    "forbearance" => 1,
+    "forbid" => 4,
+    "forbidden" => 20,
+    "forbidding" => 21,
+    "forbids" => 4,
+    "forbore" => 4,
+    "force" => 5,
+    "forced" => 20,
+    "forceful" => 16,
+    "forcefully" => 32,
+    "forceps" => 1,
+    "forces" => 5,
+    "forcible" => 16,
+    "forcibly" => 32,
+    "forcing" => 21,
+    "ford" => 7,
+    "forded" => 20,
+    "fore" => 17,
+    "forearm" => 5,
+    "forebodings" => 5,
+    "forecast" => 5,
+    "forecasting" => 21,
+    "forecastle" => 1,
+    "forefinger" => 1,
+    "forefingers" => 1,
+    "foregoing" => 21,
+    "foreground" => 5,
+    "forehead" => 1,
+    "foreign" => 17,
+    "foreigner" => 17,
+    "foreigners" => 17,
+    "foreman" => 3,
+    "foremost" => 16,
+    "forenoon" => 1,
+    "forerunner" => 1,
+    "foresail" => 1,
+    "foresaw" => 4,
+    "foresee" => 4,
+    "foreseeable" => 20,
+    "foreseen" => 4,
+    "foreshadowed" => 20,
+    "foresight" => 1,
+    "forest" => 7,
+    "forestalled" => 20,
+    "forestalling" => 21,
+    "forester" => 3,
+    "forests" => 5,
+    "foretaste" => 5,
+    "foretell" => 4,
+    "foretold" => 4,
+    "forever" => 17,
+    "forewarned" => 20,
+    "foreword" => 1,
+    "forfeit" => 21,
+    "forfeiture" => 1,
+    "forgave" => 4,
+    "forge" => 5,
+    "forged" => 20,
+    "forger" => 1,
+    "forgeries" => 1,
+    "forgery" => 1,
+    "forges" => 5,
+    "forget" => 4,
+    "forgetfulness" => 17,
+    "forgets" => 4,
+    "forgetting" => 5,
+    "forgive" => 5,
+    "forgiven" => 4,
+    "forgiveness" => 5,
+    "forgiving" => 21,
+    "forgo" => 4,
+    "forgot" => 4,
+    "forgotten" => 21,
+    "fork" => 5,
+    "forked" => 20,
+    "forking" => 21,
+    "forks" => 5,
+    "forlorn" => 21,
+    "forlornly" => 32,
+    "form" => 5,
+    "formal" => 17,
+    "formalism" => 1,
+    "formalities" => 1,
+    "formality" => 1,
+    "formalize" => 4,
+    "formalized" => 20,
+    "formally" => 32,
+    "format" => 5,
+    "formation" => 1,
+    "formations" => 1,
+    "formative" => 5,
+    "formats" => 5,
+    "formatted" => 20,
+    "formatter" => 1,
+    "formatters" => 1,
+    "formatting" => 5,
+    "formed" => 20,
+    "former" => 17,
+    "formerly" => 32,
+    "formidable" => 16,
+    "forming" => 21,
+    "formless" => 16,
+    "formosa" => 2,
+    "forms" => 5,
+    "formula" => 1,
+    "formulae" => 1,
+    "formulas" => 1,
+    "formulate" => 4,
+    "formulated" => 20,
+    "formulates" => 4,
+    "formulation" => 5,
+    "formulations" => 4,
+    "forrest" => 2,
+    "forsake" => 4,
+    "forsaken" => 20,
+    "forsakes" => 4,
+    "forsook" => 4,
+    "forster" => 2,
+    "fort" => 5,
+    "forth" => 576,
+    "forthcoming" => 21,
+    "forties" => 1,
+    "fortieth" => 17,
+    "fortifications" => 4,
+    "fortified" => 21,
+    "fortify" => 4,
+    "fortitude" => 1,
+    "fortnight" => 1,
+    "fortran" => 2,
+    "fortress" => 5,
+    "forts" => 5,
+    "fortuitously" => 32,
+    "fortunate" => 16,
+    "fortunately" => 32,
+    "fortune" => 5,
+    "fortunes" => 5,
+    "forty" => 17,
+    "forum" => 1,
+    "forums" => 1,
+    "forward" => 21,
+    "forwarded" => 20,
+    "forwarding" => 21,
+    "forwards" => 5,
+    "fossil" => 1,
+    "foster" => 23,
+    "fostering" => 21,
+    "fought" => 4,
+    "foul" => 21,
+    "fouls" => 5,
+    "found" => 5,
+    "foundation" => 1,
+    "foundations" => 1,
+    "founded" => 20,
+    "founder" => 5,
+    "founders" => 5,
+    "founding" => 21,
+    "foundries" => 1,
+    "foundry" => 1,
+    "fountain" => 5,
+    "fountains" => 5,
+    "four" => 1,
+    "fourier" => 2,
+    "fours" => 1,
+    "fourteenth" => 17,
+    "fourth" => 23,
+    "fourths" => 1,
+    "fowl" => 21,
+    "fowler" => 2,
+    "fowls" => 5,
+    "fox" => 7,
+    "foxes" => 5,
+    "fractals" => 1,
+    "fraction" => 5,
+    "fractional" => 17,
+    "fractions" => 5,
+    "fractiousness" => 17,
+    "fragile" => 17,
+    "fragment" => 5,
+    "fragmentary" => 16,
+    "fragmentation" => 1,
+    "fragmented" => 20,
+    "fragmenting" => 21,
+    "fragments" => 5,
+    "fragrance" => 5,
+    "fragrant" => 16,
+    "frail" => 21,
+    "frame" => 5,
+    "framed" => 20,
+    "frames" => 5,
+    "framework" => 1,
+    "frameworks" => 1,
+    "framing" => 21,
+    "franc" => 1,
+    "france" => 2,
+    "franchise" => 5,
+    "francis" => 2,
+    "francisco" => 2,
+    "franco" => 3,
+    "frank" => 23,
+    "frankenstein" => 7,
+    "franklin" => 3,
+    "frankly" => 32,
+    "frankness" => 21,
+    "frantic" => 17,
+    "frantically" => 17,
+    "fraternal" => 17,
+    "fraternity" => 1,
+    "fraud" => 5,
+    "frauds" => 5,
+    "fraudulent" => 16,
+    "fraught" => 21,
+    "fray" => 5,
+    "frayed" => 20,
+    "freak" => 21,
+    "freaks" => 5,
+    "freckled" => 20,
+    "frederick" => 2,
+    "free" => 21,
+    "freed" => 20,
+    "freedom" => 1,
+    "freeing" => 21,
+    "freelance" => 21,
+    "freely" => 32,
+    "freeman" => 3,
+    "freemason" => 1,
+    "freemasonry" => 3,
+    "freer" => 17,
+    "frees" => 5,
+    "freeze" => 5,
+    "freezes" => 5,
+    "freezing" => 21,
+    "freight" => 21,
+    "fremont" => 2,
+    "french" => 23,
+    "frenchman" => 1,
+    "frenchmen" => 1,
+    "frenchwoman" => 1,
+    "frenzy" => 21,
+    "frequencies" => 1,
+    "frequency" => 1,
+    "frequent" => 20,
+    "frequented" => 20,
+    "frequently" => 32,
+    "fresh" => 21,
+    "freshened" => 20,
+    "fresher" => 17,
+    "freshest" => 16,
+    "freshly" => 32,
+    "freshness" => 21,
+    "fresno" => 2,
+    "fret" => 5,
+    "fretting" => 5,
+    "friar" => 1,
+    "friars" => 1,
+    "fricative" => 17,
+    "fricatives" => 1,
+    "friction" => 1,
+    "frictions" => 1,
+    "friday" => 1,
+    "fried" => 20,
+    "friend" => 7,
+    "friendless" => 16,
+    "friendlier" => 16,
+    "friendliness" => 17,
+    "friendly" => 49,
+    "friends" => 5,
+    "friendship" => 1,
+    "friendships" => 1,
+    "fries" => 5,
+    "fright" => 21,
+    "frighten" => 21,
+    "frightened" => 20,
+    "frightening" => 21,
+    "frightful" => 16,
+    "frill" => 5,
+    "fringe" => 21,
+    "fringed" => 20,
+    "frisco" => 2,
+    "frisian" => 19,
+    "frisking" => 21,
+    "fritz" => 7,
+    "frivolity" => 1,
+    "frivolous" => 16,
+    "frivolously" => 32,
+    "fro" => 65,
+    "frock" => 5,
+    "frocks" => 5,
+    "frog" => 5,
+    "frogs" => 5,
+    "frolic" => 21,
+    "frolicked" => 4,
+    "frolicking" => 5,
+    "from" => 64,
+    "front" => 21,
+    "frontend" => 1,
+    "frontier" => 5,
+    "frontiers" => 5,
+    "fronting" => 21,
+    "frontispiece" => 5,
+    "fronts" => 5,
+    "frost" => 7,
+    "frosted" => 20,
+    "frosts" => 5,
+    "frosty" => 16,
+    "frothy" => 17,
+    "frown" => 5,
+    "frowned" => 20,
+    "frowning" => 21,
+    "frowns" => 5,
+    "froze" => 4,
+    "frozen" => 20,
+    "fructose" => 1,
+    "fruit" => 5,
+    "fruiterer" => 1,
+    "fruitful" => 16,
+    "fruitless" => 16,
+    "fruitlessly" => 32,
+    "fruits" => 5,
+    "fruity" => 17,
+    "frustrated" => 20,
+    "frustrating" => 21,
+    "frustratingly" => 32,
+    "frustration" => 21,
+    "fry" => 7,
+    "frying" => 21,
+    "fuchs" => 2,
+    "fuchsia" => 17,
+    "fuck" => 5,
+    "fuddled" => 20,
+    "fuel" => 5,
+    "fuelled" => 4,
+    "fuelling" => 5,
+    "fuels" => 5,
+    "fugitives" => 5,
+    "fuji" => 2,
+    "fulcrum" => 1,
+    "fulfil" => 4,
+    "fulfill" => 4,
+    "fulfilled" => 20,
+    "fulfilling" => 21,
+    "fulfillment" => 5,
+    "fulfills" => 4,
+    "fulfilment" => 5,
+    "fulfils" => 4,
+    "full" => 21,
+    "fuller" => 23,
+    "fullest" => 16,
+    "fully" => 4,
+    "fulton" => 2,
+    "fumbled" => 20,
+    "fumes" => 5,
+    "fun" => 21,
+    "function" => 5,
+    "functional" => 17,
+    "functionality" => 1,
+    "functionally" => 32,
+    "functionary" => 1,
+    "functioning" => 21,
+    "functions" => 5,
+    "fund" => 5,
+    "fundamental" => 17,
+    "fundamentally" => 32,
+    "fundamentals" => 1,
+    "funded" => 20,
+    "funding" => 21,
+    "funds" => 5,
+    "funeral" => 17,
+    "funerals" => 1,
+    "funereal" => 16,
+    "fungal" => 16,
+    "fungi" => 1,
+    "fungible" => 17,
+    "funky" => 16,
+    "funnel" => 5,
+    "funniest" => 16,
+    "funnily" => 32,
+    "funny" => 17,
+    "fur" => 581,
+    "furies" => 3,
+    "furious" => 16,
+    "furiously" => 32,
+    "furled" => 20,
+    "furlong" => 1,
+    "furnace" => 5,
+    "furnaces" => 5,
+    "furnish" => 5,
+    "furnished" => 20,
+    "furnishes" => 5,
+    "furniture" => 1,
+    "furred" => 20,
+    "furrow" => 5,
+    "furrowed" => 20,
+    "furrows" => 5,
+    "furs" => 5,
+    "further" => 20,
+    "furtherance" => 1,
+    "furthermore" => 16,
+    "furthers" => 4,
+    "furthest" => 16,
+    "furtive" => 16,
+    "furtively" => 32,
+    "fury" => 1,
+    "fuse" => 5,
+    "fused" => 20,
+    "fusing" => 21,
+    "fusion" => 5,
+    "fuss" => 5,
+    "futile" => 16,
+    "futility" => 1,
+    "future" => 17,
+    "futures" => 1,
+    "futurity" => 1,
+    "fuzz" => 5,
+    "fuzzed" => 20,
+    "fuzziness" => 17,
+    "fuzzy" => 17,
+    "gable" => 3,
+    "gables" => 1,
+    "gabriel" => 2,
+    "gadget" => 1,
+    "gadgets" => 1,
+    "gaelic" => 19,
+    "gag" => 5,
+    "gagged" => 4,
+    "gaggle" => 5,
+    "gaiety" => 1,
+    "gain" => 85,
+    "gained" => 20,
+    "gainer" => 1,
+    "gaining" => 21,
+    "gains" => 5,
+    "gainsay" => 4,
+    "gait" => 5,
+    "gaiters" => 5,
+    "gal" => 1,
+    "galactic" => 16,
+    "gale" => 7,
+    "gales" => 5,
+    "galileo" => 2,
+    "gall" => 7,
+    "gallant" => 21,
+    "galled" => 20,
+    "gallery" => 5,
+    "galleys" => 1,
+    "gallons" => 1,
+    "gallop" => 5,
+    "galloping" => 21,
+    "galloway" => 3,
+    "gallows" => 5,
+    "galois" => 2,
+    "gals" => 1,
+    "galvanised" => 20,
+    "galvanism" => 1,
+    "gamble" => 7,
+    "gambled" => 20,
+    "gambler" => 1,
+    "gamblers" => 5,
+    "game" => 21,
+    "games" => 5,
+    "gaming" => 21,
+    "gamma" => 1,
+    "gamut" => 1,
+    "gang" => 5,
+    "ganges" => 2,
+    "gantlet" => 1,
+    "gap" => 7,
+    "gape" => 5,
+    "gaped" => 20,
+    "gaping" => 21,
+    "gaps" => 5,
+    "garage" => 5,
+    "garages" => 5,
+    "garb" => 5,
+    "garbage" => 21,
+    "garbled" => 20,
+    "garden" => 21,
+    "gardener" => 17,
+    "gardeners" => 21,
+    "gardens" => 5,
+    "gardner" => 2,
+    "garment" => 5,
+    "garments" => 5,
+    "garner" => 7,
+    "garnished" => 20,
+    "garret" => 1,
+    "garrison" => 7,
+    "garrulous" => 16,
+    "garth" => 2,
+    "gary" => 3,
+    "gas" => 21,
+    "gash" => 21,
+    "gaslight" => 5,
+    "gasoline" => 1,
+    "gasp" => 5,
+    "gasped" => 20,
+    "gasping" => 21,
+    "gate" => 5,
+    "gated" => 20,
+    "gatekeeper" => 1,
+    "gates" => 7,
+    "gateway" => 5,
+    "gateways" => 5,
+    "gather" => 5,
+    "gathered" => 20,
+    "gatherer" => 1,
+    "gathering" => 21,
+    "gathers" => 5,
+    "gating" => 21,
+    "gatt" => 2,
+    "gaudier" => 16,
+    "gaudily" => 32,
+    "gaudiness" => 17,
+    "gaudy" => 17,
+    "gauge" => 5,
+    "gauges" => 5,
+    "gauging" => 21,
+    "gaunt" => 16,
+    "gaunter" => 16,
+    "gauss" => 2,
+    "gaussian" => 17,
+    "gauze" => 5,
+    "gave" => 4,
+    "gavin" => 2,
+    "gawk" => 5,
+    "gawky" => 17,
+    "gay" => 23,
+    "gaze" => 5,
+    "gazed" => 20,
+    "gazes" => 5,
+    "gazetteer" => 5,
+    "gazing" => 21,
+    "gear" => 21,
+    "geared" => 20,
+    "gears" => 5,
+    "gecko" => 5,
+    "geese" => 1,
+    "gem" => 5,
+    "gemini" => 3,
+    "gems" => 5,
+    "gen" => 3,
+    "gender" => 21,
+    "gene" => 3,
+    "genealogical" => 16,
+    "genera" => 1,
+    "general" => 21,
+    "generalise" => 4,
+    "generalities" => 1,
+    "generalization" => 1,
+    "generalize" => 4,
+    "generalized" => 20,
+    "generalizes" => 4,
+    "generalizing" => 21,
+    "generally" => 32,
+    "generals" => 5,
+    "generate" => 4,
+    "generated" => 20,
+    "generates" => 4,
+    "generating" => 21,
+    "generation" => 5,
+    "generations" => 1,
+    "generative" => 4,
+    "generator" => 1,
+    "generators" => 1,
+    "generic" => 17,
+    "generically" => 17,
+    "generosity" => 1,
+    "generous" => 16,
+    "generously" => 32,
+    "genesis" => 3,
+    "genetic" => 16,
+    "geneva" => 3,
+    "genial" => 16,
+    "geniality" => 1,
+    "genially" => 32,
+    "genie" => 1,
+    "genii" => 1,
+    "genitive" => 17,
+    "genius" => 17,
+    "genoa" => 3,
+    "genres" => 5,
+    "genteel" => 16,
+    "gentiles" => 1,
+    "gentle" => 21,
+    "gentleman" => 1,
+    "gentleman's" => 1,
+    "gentlemanly" => 48,
+    "gentlemen" => 1,
+    "gentleness" => 21,
+    "gentler" => 17,
+    "gently" => 32,
+    "genuine" => 16,
+    "genuinely" => 32,
+    "genuineness" => 17,
+    "genus" => 1,
+    "geodesic" => 17,
+    "geoffrey" => 2,
+    "geographic" => 16,
+    "geographical" => 17,
+    "geographically" => 32,
+    "geography" => 1,
+    "geology" => 1,
+    "geometric" => 16,
+    "geometrically" => 32,
+    "geometry" => 1,
+    "geopolitical" => 4,
+    "george" => 3,
+    "georgetown" => 2,
+    "georgia" => 2,
+    "georgian" => 17,
+    "gerald" => 2,
+    "german" => 19,
+    "germanic" => 19,
+    "germans" => 1,
+    "germany" => 2,
+    "gerry" => 2,
+    "gestalt" => 1,
+    "gesticulating" => 21,
+    "gesticulation" => 5,
+    "gesticulations" => 4,
+    "gesture" => 5,
+    "gestures" => 5,
+    "get" => 5,
+    "gets" => 5,
+    "getting" => 5,
+    "geyser" => 5,
+    "ghastly" => 16,
+    "gherkin" => 1,
+    "ghost" => 5,
+    "ghostly" => 48,
+    "ghosts" => 5,
+    "giant" => 17,
+    "giants" => 1,
+    "gibberish" => 17,
+    "gibbs" => 2,
+    "gibe" => 5,
+    "giblet" => 1,
+    "gibraltar" => 3,
+    "giddy" => 21,
+    "gift" => 5,
+    "gifted" => 20,
+    "gifts" => 5,
+    "gig" => 5,
+    "gigabits" => 1,
+    "gigabyte" => 1,
+    "gigabytes" => 1,
+    "gigantic" => 16,
+    "giggles" => 5,
+    "gilded" => 20,
+    "gilding" => 21,
+    "gilt" => 21,
+    "gimme" => 1,
+    "gimp" => 21,
+    "gin" => 517,
+    "gingerly" => 32,
+    "gins" => 5,
+    "girded" => 20,
+    "girders" => 5,
+    "girding" => 21,
+    "girl" => 5,
+    "girlfriend" => 1,
+    "girlhood" => 1,
+    "girls" => 5,
+    "girt" => 21,
+    "gist" => 5,
+    "git" => 7,
+    "gits" => 5,
+    "give" => 5,
+    "giveaway" => 17,
+    "given" => 85,
+    "giver" => 1,
+    "gives" => 5,
+    "giving" => 21,
+    "gizmos" => 1,
+    "glacial" => 17,
+    "glacier" => 1,
+    "glaciers" => 1,
+    "glad" => 21,
+    "glade" => 1,
+    "gladly" => 32,
+    "gladness" => 21,
+    "gladstone" => 3,
+    "glam" => 21,
+    "glamour" => 5,
+    "glance" => 5,
+    "glanced" => 20,
+    "glances" => 5,
+    "glancing" => 21,
+    "glands" => 1,
+    "glare" => 21,
+    "glared" => 20,
+    "glaring" => 21,
+    "glasgow" => 2,
+    "glass" => 7,
+    "glasses" => 5,
+    "glazed" => 20,
+    "glazes" => 5,
+    "gleam" => 5,
+    "gleamed" => 20,
+    "gleaming" => 21,
+    "glean" => 5,
+    "gleaned" => 20,
+    "glen" => 3,
+    "glenn" => 2,
+    "glens" => 1,
+    "glib" => 21,
+    "glide" => 5,
+    "glided" => 20,
+    "glides" => 5,
+    "gliding" => 21,
+    "glimmer" => 5,
+    "glimmered" => 20,
+    "glimmering" => 21,
+    "glimmers" => 5,
+    "glimpse" => 5,
+    "glimpsed" => 20,
+    "glimpses" => 5,
+    "glint" => 21,
+    "glinted" => 20,
+    "glinting" => 21,
+    "glints" => 5,
+    "glisten" => 5,
+    "glistened" => 20,
+    "glistening" => 21,
+    "glitch" => 5,
+    "glitches" => 5,
+    "glitter" => 5,
+    "glittered" => 20,
+    "glittering" => 21,
+    "gloated" => 20,
+    "gloating" => 21,
+    "glob" => 5,
+    "global" => 17,
+    "globally" => 32,
+    "globe" => 5,
+    "globs" => 5,
+    "gloom" => 5,
+    "gloomiest" => 16,
+    "gloomily" => 32,
+    "gloomy" => 17,
+    "gloria" => 2,
+    "gloried" => 20,
+    "glorified" => 20,
+    "glorious" => 16,
+    "gloriously" => 32,
+    "glory" => 5,
+    "glorying" => 21,
+    "gloss" => 5,
+    "glossary" => 1,
+    "glossed" => 20,
+    "glossy" => 17,
+    "glottal" => 17,
+    "glove" => 5,
+    "gloved" => 20,
+    "gloves" => 5,
+    "glow" => 5,
+    "glowed" => 20,
+    "glowering" => 21,
+    "glowing" => 21,
+    "glue" => 5,
+    "glued" => 20,
+    "glues" => 5,
+    "gluon" => 1,
+    "glut" => 5,
+    "glutted" => 4,
+    "glyph" => 1,
+    "glyphs" => 1,
+    "gmt" => 3,
+    "gnarly" => 16,
+    "gnashed" => 20,
+    "gnashes" => 5,
+    "gnashing" => 21,
+    "gnat" => 1,
+    "gnawed" => 20,
+    "gnawing" => 21,
+    "gnome" => 1,
+    "gnu" => 3,
+    "go" => 21,
+    "goading" => 21,
+    "goal" => 5,
+    "goals" => 5,
+    "goat" => 5,
+    "goatee" => 1,
+    "goats" => 5,
+    "goblin" => 1,
+    "god" => 7,
+    "goddard" => 2,
+    "goddess" => 1,
+    "godfrey" => 1,
+    "godlike" => 16,
+    "gods" => 5,
+    "goes" => 5,
+    "goggles" => 5,
+    "going" => 21,
+    "gold" => 21,
+    "golden" => 23,
+    "goldfish" => 1,
+    "goldilocks" => 2,
+    "golf" => 5,
+    "gone" => 84,
+    "goner" => 17,
+    "goners" => 84,
+    "gong" => 5,
+    "gongs" => 5,
+    "goo" => 5,
+    "good" => 23,
+    "goodbye" => 5,
+    "goodies" => 1,
+    "goodly" => 48,
+    "goodness" => 21,
+    "goods" => 5,
+    "goodwill" => 3,
+    "goody" => 17,
+    "gooey" => 16,
+    "goose" => 5,
+    "gooseberry" => 1,
+    "gordon" => 3,
+    "gorgeous" => 16,
+    "gorgeously" => 32,
+    "gorgeousness" => 17,
+    "goring" => 21,
+    "gory" => 16,
+    "gospel" => 5,
+    "gossip" => 5,
+    "gossiped" => 20,
+    "gossiping" => 21,
+    "gossips" => 5,
+    "got" => 4,
+    "gothic" => 19,
+    "gotten" => 20,
+    "gouging" => 21,
+    "gourd" => 1,
+    "gourmands" => 1,
+    "gov" => 1,
+    "govern" => 5,
+    "governance" => 1,
+    "governed" => 20,
+    "governess" => 5,
+    "governesses" => 5,
+    "governing" => 21,
+    "government" => 5,
+    "governments" => 1,
+    "governor" => 1,
+    "governors" => 1,
+    "governs" => 5,
+    "gown" => 5,
+    "gowned" => 20,
+    "gowns" => 5,
+    "gpo" => 3,
+    "grab" => 5,
+    "grabbed" => 4,
+    "grabber" => 1,
+    "grabbing" => 5,
+    "grabs" => 5,
+    "grace" => 7,
+    "graceful" => 16,
+    "gracefully" => 32,
+    "graceless" => 16,
+    "graces" => 5,
+    "gracie" => 2,
+    "gracious" => 16,
+    "grade" => 5,
+    "graders" => 1,
+    "grades" => 5,
+    "gradient" => 17,
+    "gradients" => 1,
+    "gradual" => 17,
+    "gradually" => 32,
+    "graduate" => 21,
+    "graduated" => 20,
+    "graduating" => 21,
+    "graham" => 3,
+    "grail" => 1,
+    "grain" => 5,
+    "grained" => 20,
+    "grains" => 5,
+    "gram" => 7,
+    "grammar" => 5,
+    "grammarians" => 1,
+    "grammars" => 5,
+    "grammatical" => 16,
+    "grammatically" => 32,
+    "grams" => 5,
+    "grand" => 17,
+    "grander" => 16,
+    "grandeur" => 1,
+    "grandfather" => 5,
+    "grandly" => 32,
+    "grandmothers" => 1,
+    "grandpa" => 1,
+    "grandparent" => 5,
+    "granite" => 1,
+    "granny" => 21,
+    "grant" => 7,
+    "granted" => 20,
+    "grantee" => 1,
+    "granting" => 21,
+    "grants" => 5,
+    "granular" => 16,
+    "granularity" => 1,
+    "granule" => 1,
+    "grape" => 21,
+    "grapes" => 5,
+    "graph" => 5,
+    "grapheme" => 1,
+    "graphemes" => 1,
+    "graphic" => 17,
+    "graphical" => 16,
+    "graphics" => 1,
+    "graphing" => 21,
+    "graphite" => 5,
+    "graphs" => 5,
+    "grapple" => 5,
+    "grappled" => 20,
+    "grappling" => 21,
+    "grasp" => 5,
+    "grasped" => 20,
+    "grasping" => 21,
+    "grass" => 7,
+    "grasses" => 5,
+    "grate" => 21,
+    "grated" => 20,
+    "grateful" => 16,
+    "gratefully" => 32,
+    "gratefulness" => 17,
+    "grates" => 5,
+    "gratification" => 5,
+    "gratified" => 20,
+    "gratify" => 4,
+    "gratifying" => 21,
+    "grating" => 21,
+    "gratings" => 21,
+    "gratitude" => 1,
+    "gratuitous" => 16,
+    "gratuitously" => 32,
+    "grave" => 21,
+    "gravel" => 5,
+    "gravely" => 32,
+    "graven" => 20,
+    "graver" => 17,
+    "graves" => 7,
+    "gravest" => 16,
+    "graveyard" => 1,
+    "gravitated" => 20,
+    "gravitating" => 21,
+    "gravitation" => 5,
+    "gravity" => 1,
+    "gravy" => 5,
+    "gray" => 23,
+    "grayish" => 16,
+    "grayscale" => 17,
+    "grazed" => 20,
+    "grease" => 5,
+    "greasy" => 16,
+    "great" => 17,
+    "greatcoat" => 1,
+    "greater" => 16,
+    "greatest" => 16,
+    "greatly" => 32,
+    "greatness" => 17,
+    "greece" => 2,
+    "greedily" => 32,
+    "greedy" => 16,
+    "greek" => 23,
+    "greeks" => 5,
+    "green" => 23,
+    "greene" => 2,
+    "greenest" => 16,
+    "greenhouse" => 5,
+    "greenland" => 2,
+    "greenness" => 21,
+    "greenwich" => 2,
+    "greenwood" => 1,
+    "greet" => 21,
+    "greeted" => 20,
+    "greeting" => 21,
+    "greetings" => 21,
+    "greets" => 5,
+    "greg" => 2,
+    "gregarious" => 16,
+    "gregorian" => 17,
+    "gregory" => 3,
+    "grenadier" => 1,
+    "grew" => 4,
+    "grey" => 23,
+    "greyish" => 16,
+    "grid" => 5,
+    "grids" => 5,
+    "grief" => 5,
+    "grievance" => 1,
+    "grievances" => 1,
+    "grieve" => 5,
+    "grieved" => 20,
+    "grieving" => 21,
+    "grievous" => 16,
+    "grievously" => 32,
+    "griffin" => 3,
+    "grim" => 21,
+    "grimace" => 5,
+    "grimaces" => 5,
+    "grime" => 5,
+    "grimly" => 32,
+    "grin" => 5,
+    "grind" => 5,
+    "grinder" => 1,
+    "grinding" => 21,
+    "grinned" => 4,
+    "grinning" => 5,
+    "grins" => 5,
+    "grip" => 5,
+    "gripped" => 16,
+    "gripping" => 21,
+    "grisly" => 17,
+    "grit" => 5,
+    "gritty" => 16,
+    "grizzled" => 20,
+    "groan" => 5,
+    "groaned" => 20,
+    "groaning" => 21,
+    "groans" => 5,
+    "grocer" => 5,
+    "groceries" => 5,
+    "grocery" => 5,
+    "groin" => 5,
+    "groom" => 5,
+    "groomed" => 20,
+    "groovy" => 17,
+    "grope" => 5,
+    "groped" => 20,
+    "groping" => 21,
+    "gross" => 23,
+    "grossly" => 32,
+    "grossness" => 21,
+    "grotesque" => 17,
+    "grottoes" => 1,
+    "ground" => 21,
+    "grounded" => 20,
+    "grounds" => 5,
+    "groundwork" => 1,
+    "group" => 5,
+    "grouped" => 20,
+    "grouping" => 21,
+    "groups" => 5,
+    "grove" => 5,
+    "grovel" => 4,
+    "groves" => 5,
+    "grow" => 4,
+    "growing" => 21,
+    "growl" => 5,
+    "growled" => 20,
+    "growling" => 21,
+    "growls" => 5,
+    "grown" => 20,
+    "grows" => 4,
+    "growth" => 5,
+    "growths" => 1,
+    "grub" => 5,
+    "grudge" => 5,
+    "grudges" => 5,
+    "grudging" => 21,
+    "gruff" => 21,
+    "gruffly" => 32,
+    "gruffness" => 21,
+    "grumbled" => 20,
+    "grunt" => 5,
+    "grunted" => 20,
+    "grunting" => 21,
+    "guarantee" => 5,
+    "guaranteed" => 20,
+    "guaranteeing" => 4,
+    "guarantees" => 5,
+    "guaranties" => 5,
+    "guarantying" => 21,
+    "guard" => 5,
+    "guarded" => 20,
+    "guardian" => 1,
+    "guardians" => 1,
+    "guardianship" => 1,
+    "guarding" => 21,
+    "guards" => 5,
+    "guardsmen" => 1,
+    "guess" => 5,
+    "guessed" => 20,
+    "guesses" => 5,
+    "guessing" => 21,
+    "guesswork" => 1,
+    "guest" => 5,
+    "guests" => 5,
+    "guidance" => 1,
+    "guide" => 5,
+    "guided" => 20,
+    "guideline" => 1,
+    "guidelines" => 1,
+    "guides" => 5,
+    "guiding" => 21,
+    "guild" => 1,
+    "guilds" => 1,
+    "guile" => 5,
+    "guileful" => 16,
+    "guillotining" => 21,
+    "guilt" => 5,
+    "guiltiest" => 16,
+    "guiltily" => 32,
+    "guiltless" => 16,
+    "guilty" => 17,
+    "guinea" => 3,
+    "guineas" => 1,
+    "guise" => 5,
+    "guitar" => 5,
+    "gujarati" => 19,
+    "gulf" => 5,
+    "gulfs" => 5,
+    "gullet" => 1,
+    "gullible" => 17,
+    "gulls" => 5,
+    "gully" => 5,
+    "gulp" => 5,
+    "gum" => 5,
+    "gummed" => 4,
+    "gun" => 5,
+    "guns" => 5,
+    "gunshots" => 1,
+    "gunwale" => 1,
+    "guppy" => 1,
+    "gurgling" => 21,
+    "guru" => 5,
+    "gus" => 2,
+    "gush" => 5,
+    "gushed" => 20,
+    "gushes" => 5,
+    "gust" => 5,
+    "gusts" => 5,
+    "gut" => 21,
+    "guts" => 5,
+    "gutter" => 5,
+    "guttering" => 21,
+    "guy" => 7,
+    "guys" => 5,
+    "gym" => 5,
+    "gymnast" => 1,
+    "gymnastic" => 17,
+    "gymnastics" => 1,
+    "gymnasts" => 1,
+    "gyp" => 5,
+    "gypsies" => 5,
+    "gyroscope" => 1,
+    "ha" => 3,
+    "habiliments" => 1,
+    "habit" => 5,
+    "habitable" => 5,
+    "habitation" => 1,
+    "habitations" => 1,
+    "habitats" => 1,
+    "habits" => 5,
+    "habitual" => 17,
+    "hack" => 5,
+    "hacked" => 20,
+    "hacker" => 5,
+    "hackers" => 5,
+    "hacking" => 21,
+    "hacks" => 5,
+    "had" => 4,
+    "hadn't" => 4,
+    "hag" => 5,
+    "haggard" => 17,
+    "hague" => 2,
+    "haiku" => 1,
+    "hail" => 21,
+    "hailed" => 20,
+    "hair" => 5,
+    "hair's" => 1,
+    "haired" => 20,
+    "hairline" => 17,
+    "hairs" => 5,
+    "haitian" => 19,
+    "hal" => 2,
+    "hale" => 23,
+    "half" => 85,
+    "halftone" => 5,
+    "halftones" => 5,
+    "halfway" => 16,
+    "halifax" => 2,
+    "hall" => 3,
+    "halls" => 1,
+    "hallway" => 1,
+    "halo" => 5,
+    "hals" => 2,
+    "halt" => 21,
+    "halted" => 20,
+    "halting" => 21,
+    "halts" => 5,
+    "halve" => 4,
+    "halved" => 20,
+    "halves" => 4,
+    "halving" => 21,
+    "ham" => 7,
+    "hamburg" => 7,
+    "hamlet" => 3,
+    "hamlets" => 1,
+    "hammer" => 5,
+    "hammered" => 20,
+    "hammerheads" => 1,
+    "hammering" => 21,
+    "hammers" => 5,
+    "hamming" => 4,
+    "hampered" => 20,
+    "hampers" => 5,
+    "hampshire" => 3,
+    "hams" => 5,
+    "hancock" => 2,
+    "hand" => 5,
+    "handbook" => 1,
+    "handcuffs" => 5,
+    "handed" => 20,
+    "handful" => 1,
+    "handfuls" => 1,
+    "handheld" => 17,
+    "handily" => 32,
+    "handing" => 21,
+    "handkerchief" => 1,
+    "handkerchiefs" => 1,
+    "handle" => 5,
+    "handlebars" => 1,
+    "handled" => 20,
+    "handler" => 1,
+    "handlers" => 5,
+    "handles" => 5,
+    "handling" => 21,
+    "handmade" => 17,
+    "handout" => 17,
+    "hands" => 5,
+    "handshake" => 5,
+    "handshakes" => 5,
+    "handshaking" => 21,
+    "handsome" => 20,
+    "handsomely" => 32,
+    "handsprings" => 1,
+    "handwork" => 5,
+    "handwriting" => 5,
+    "handwritten" => 20,
+    "handy" => 19,
+    "hang" => 5,
+    "hanged" => 20,
+    "hanging" => 21,
+    "hangman" => 1,
+    "hangs" => 5,
+    "hangup" => 1,
+    "hanoi" => 2,
+    "hanover" => 2,
+    "hans" => 3,
+    "hansom" => 1,
+    "haphazardly" => 32,
+    "hapless" => 16,
+    "happen" => 4,
+    "happened" => 20,
+    "happening" => 21,
+    "happenings" => 4,
+    "happens" => 4,
+    "happenstance" => 1,
+    "happier" => 17,
+    "happiest" => 16,
+    "happily" => 32,
+    "happiness" => 21,
+    "happy" => 21,
+    "harassed" => 20,
+    "harbor" => 5,
+    "harbour" => 5,
+    "hard" => 17,
+    "harden" => 21,
+    "hardened" => 20,
+    "hardening" => 21,
+    "harder" => 16,
+    "hardest" => 16,
+    "hardhat" => 1,
+    "hardihood" => 1,
+    "harding" => 2,
+    "hardly" => 32,
+    "hardness" => 17,
+    "hardship" => 5,
+    "hardships" => 5,
+    "hardware" => 1,
+    "hardy" => 19,
+    "hare" => 21,
+    "harem" => 1,
+    "hark" => 5,
+    "harlequin" => 23,
+    "harm" => 5,
+    "harmed" => 20,
+    "harmful" => 16,
+    "harmless" => 16,
+    "harmlessly" => 32,
+    "harmonic" => 17,
+    "harmonious" => 16,
+    "harmonised" => 20,
+    "harmonium" => 1,
+    "harmonization" => 1,
+    "harmonize" => 4,
+    "harmonized" => 20,
+    "harmony" => 1,
+    "harness" => 5,
+    "harnessed" => 20,
+    "harnesses" => 5,
+    "harp" => 5,
+    "harper" => 2,
+    "harris" => 2,
+    "harrison" => 2,
+    "harrow" => 5,
+    "harrowed" => 20,
+    "harrowing" => 21,
+    "harsh" => 20,
+    "harshly" => 32,
+    "harshness" => 21,
+    "hart" => 3,
+    "hartford" => 2,
+    "harts" => 1,
+    "harvard" => 2,
+    "harvest" => 5,
+    "harvesting" => 21,
+    "harvey" => 2,
+    "has" => 4,
+    "hash" => 5,
+    "hashed" => 20,
+    "hashes" => 5,
+    "hashing" => 21,
+    "hasn't" => 4,
+    "hasp" => 5,
+    "hassle" => 21,
+    "haste" => 5,
+    "hasten" => 4,
+    "hastened" => 20,
+    "hastening" => 21,
+    "hastily" => 32,
+    "hasty" => 16,
+    "hat" => 5,
+    "hatch" => 5,
+    "hatchet" => 5,
+    "hatching" => 21,
+    "hate" => 5,
+    "hated" => 20,
+    "hateful" => 16,
+    "hates" => 5,
+    "hating" => 21,
+    "hatred" => 1,
+    "hats" => 5,
+    "hatter" => 5,
+    "hatters" => 5,
+    "hauberks" => 1,
+    "haughtily" => 32,
+    "haughty" => 16,
+    "haul" => 5,
+    "hauled" => 20,
+    "hauling" => 21,
+    "haunches" => 5,
+    "haunt" => 5,
+    "haunted" => 20,
+    "haunting" => 21,
+    "haunts" => 5,
+    "hausa" => 3,
+    "hauteur" => 1,
+    "have" => 9,
+    "haven" => 5,
+    "haven't" => 4,
+    "haves" => 1,
+    "having" => 21,
+    "havoc" => 5,
+    "hawk" => 5,
+    "hawks" => 7,
+    "hawthorn" => 1,
+    "hay" => 7,
+    "hayes" => 2,
+    "hayloft" => 1,
+    "hays" => 7,
+    "haystack" => 1,
+    "haystacks" => 1,
+    "hazard" => 5,
+    "hazarded" => 20,
+    "hazardous" => 16,
+    "hazards" => 5,
+    "haze" => 5,
+    "hazy" => 17,
+    "he" => 258,
+    "he's" => 1,
+    "head" => 23,
+    "headache" => 1,
+    "headaches" => 1,
+    "headboard" => 1,
+    "headed" => 20,
+    "header" => 21,
+    "headers" => 21,
+    "headgear" => 1,
+    "heading" => 21,
+    "headings" => 21,
+    "headless" => 16,
+    "headlights" => 1,
+    "headline" => 5,
+    "headlong" => 20,
+    "headquarters" => 5,
+    "heads" => 5,
+    "headstrong" => 16,
+    "headway" => 1,
+    "heady" => 16,
+    "heal" => 5,
+    "healed" => 20,
+    "healey" => 2,
+    "healing" => 21,
+    "health" => 5,
+    "healthy" => 16,
+    "heap" => 5,
+    "heaped" => 20,
+    "heaps" => 5,
+    "hear" => 4,
+    "heard" => 20,
+    "hearer" => 1,
+    "hearers" => 1,
+    "hearing" => 21,
+    "hears" => 4,
+    "hearse" => 5,
+    "heart" => 5,
+    "heartbreak" => 1,
+    "heartbreaking" => 16,
+    "heartfelt" => 16,
+    "hearth" => 5,
+    "hearthrug" => 1,
+    "heartier" => 16,
+    "heartily" => 32,
+    "heartless" => 16,
+    "hearts" => 5,
+    "hearty" => 17,
+    "heat" => 5,
+    "heated" => 20,
+    "heath" => 3,
+    "heathen" => 17,
+    "heathenish" => 16,
+    "heather" => 19,
+    "heaths" => 1,
+    "heave" => 5,
+    "heaved" => 20,
+    "heaven" => 5,
+    "heavenly" => 48,
+    "heavens" => 5,
+    "heavenward" => 16,
+    "heavier" => 17,
+    "heavily" => 32,
+    "heaving" => 21,
+    "heavy" => 21,
+    "heavyweight" => 17,
+    "hebrew" => 17,
+    "heck" => 5,
+    "hedge" => 5,
+    "hedged" => 20,
+    "hedgehog" => 5,
+    "hedgehogs" => 5,
+    "hedges" => 5,
+    "heed" => 5,
+    "heeded" => 20,
+    "heedful" => 16,
+    "heedless" => 16,
+    "heel" => 5,
+    "heels" => 5,
+    "height" => 1,
+    "heightened" => 20,
+    "heights" => 1,
+    "heinous" => 16,
+    "heir" => 5,
+    "heiress" => 1,
+    "heirs" => 5,
+    "held" => 4,
+    "helen" => 3,
+    "helium" => 1,
+    "helix" => 5,
+    "hell" => 7,
+    "hellenic" => 18,
+    "hellish" => 16,
+    "hello" => 5,
+    "helm" => 5,
+    "help" => 5,
+    "helped" => 20,
+    "helper" => 1,
+    "helpers" => 5,
+    "helpful" => 16,
+    "helpfully" => 32,
+    "helping" => 21,
+    "helpless" => 16,
+    "helplessly" => 32,
+    "helplessness" => 17,
+    "helps" => 5,
+    "hematite" => 1,
+    "hemisphere" => 1,
+    "hemp" => 1,
+    "hen" => 21,
+    "hence" => 4,
+    "henderson" => 2,
+    "henrietta" => 2,
+    "henry" => 3,
+    "her" => 384,
+    "herald" => 5,
+    "herb" => 1,
+    "herbage" => 1,
+    "herbert" => 2,
+    "hercules" => 3,
+    "herd" => 5,
+    "herded" => 20,
+    "herding" => 21,
+    "herds" => 5,
+    "here" => 49,
+    "hereafter" => 17,
+    "hereditary" => 17,
+    "hereford" => 3,
+    "herman" => 2,
+    "hermes" => 3,
+    "hermit" => 1,
+    "hermits" => 1,
+    "hero" => 1,
+    "heroes" => 1,
+    "heroic" => 17,
+    "heroine" => 17,
+    "heroism" => 1,
+    "herons" => 1,
+    "hers" => 277,
+    "herself" => 256,
+    "hesitant" => 16,
+    "hesitantly" => 32,
+    "hesitate" => 4,
+    "hesitated" => 20,
+    "hesitating" => 21,
+    "hesitatingly" => 32,
+    "hesitation" => 5,
+    "hess" => 2,
+    "heterogeneous" => 16,
+    "heuristic" => 17,
+    "heuristics" => 1,
+    "hew" => 5,
+    "hewed" => 20,
+    "hewitt" => 2,
+    "hex" => 5,
+    "hexadecimal" => 17,
+    "hexagon" => 1,
+    "hexagonal" => 16,
+    "hexagons" => 1,
+    "hexameter" => 1,
+    "hey" => 1,
+    "hi" => 19,
+    "hiccups" => 5,
+    "hick" => 5,
+    "hickey" => 1,
+    "hickory" => 17,
+    "hicks" => 7,
+    "hid" => 4,
+    "hidden" => 20,
+    "hide" => 5,
+    "hideous" => 16,
+    "hideously" => 32,
+    "hideousness" => 17,
+    "hides" => 5,
+    "hiding" => 21,
+    "hierarchical" => 16,
+    "hierarchically" => 32,
+    "hierarchies" => 1,
+    "hierarchy" => 1,
+    "hieroglyphs" => 5,
+    "high" => 21,
+    "highballs" => 5,
+    "higher" => 17,
+    "highest" => 16,
+    "highlands" => 3,
+    "highlight" => 5,
+    "highlighted" => 20,
+    "highlighter" => 1,
+    "highlighting" => 21,
+    "highlights" => 5,
+    "highly" => 32,
+    "highness" => 21,
+    "highroad" => 1,
+    "highs" => 5,
+    "highway" => 5,
+    "highways" => 5,
+    "hijack" => 5,
+    "hijacked" => 20,
+    "hilarious" => 16,
+    "hilariously" => 32,
+    "hilarity" => 1,
+    "hill" => 7,
+    "hills" => 5,
+    "hillside" => 1,
+    "hilly" => 16,
+    "hilt" => 5,
+    "hilts" => 5,
+    "him" => 256,
+    "himalaya" => 2,
+    "himself" => 256,
+    "hind" => 17,
+    "hinder" => 21,
+    "hindered" => 20,
+    "hinders" => 17,
+    "hindi" => 18,
+    "hindrance" => 1,
+    "hindrances" => 1,
+    "hindsight" => 1,
+    "hinges" => 5,
+    "hint" => 5,
+    "hinted" => 20,
+    "hinting" => 21,
+    "hints" => 5,
+    "hip" => 21,
+    "hippopotamus" => 1,
+    "hips" => 5,
+    "hire" => 5,
+    "hired" => 20,
+    "hires" => 5,
+    "his" => 384,
+    "hiss" => 5,
+    "hissed" => 20,
+    "hissing" => 21,
+    "histogram" => 5,
+    "histograms" => 5,
+    "historians" => 1,
+    "historic" => 17,
+    "historical" => 17,
+    "historically" => 32,
+    "histories" => 1,
+    "history" => 1,
+    "hit" => 21,
+    "hitch" => 5,
+    "hitchcock" => 2,
+    "hitched" => 20,
+    "hither" => 16,
+    "hits" => 5,
+    "hitting" => 5,
+    "hiv" => 1,
+    "hive" => 21,
+    "hoard" => 5,
+    "hoarse" => 21,
+    "hoarsely" => 32,
+    "hoarser" => 17,
+    "hoax" => 5,
+    "hobbies" => 1,
+    "hobby" => 1,
+    "hobbyists" => 1,
+    "hockey" => 1,
+    "hodgepodge" => 5,
+    "hodges" => 2,
+    "hog" => 5,
+    "hogged" => 20,
+    "hogs" => 5,
+    "hogshead" => 1,
+    "hogsheads" => 1,
+    "hoist" => 5,
+    "hoisted" => 20,
+    "hoisting" => 21,
+    "hold" => 21,
+    "holder" => 19,
+    "holders" => 21,
+    "holding" => 21,
+    "holdings" => 21,
+    "holds" => 5,
+    "hole" => 5,
+    "holes" => 5,
+    "holiday" => 7,
+    "holidays" => 5,
+    "holiest" => 16,
+    "holistic" => 16,
+    "holland" => 2,
+    "holler" => 21,
+    "hollis" => 2,
+    "hollow" => 21,
+    "holloway" => 2,
+    "hollowed" => 20,
+    "hollowly" => 32,
+    "holly" => 3,
+    "hollywood" => 23,
+    "holmes" => 2,
+    "holocaust" => 7,
+    "holy" => 49,
+    "homage" => 5,
+    "home" => 21,
+    "homecoming" => 1,
+    "homed" => 20,
+    "homeless" => 17,
+    "homely" => 48,
+    "homer" => 23,
+    "homes" => 5,
+    "homesick" => 16,
+    "homesickness" => 17,
+    "homesteads" => 5,
+    "homeward" => 16,
+    "homework" => 1,
+    "homogeneity" => 1,
+    "homogeneous" => 16,
+    "homograph" => 1,
+    "honest" => 21,
+    "honestly" => 32,
+    "honesty" => 1,
+    "honey" => 21,
+    "honeycomb" => 5,
+    "honeymoon" => 5,
+    "honeypot" => 1,
+    "hong" => 2,
+    "honor" => 5,
+    "honorable" => 21,
+    "honored" => 20,
+    "honoring" => 21,
+    "honors" => 5,
+    "honour" => 5,
+    "honourable" => 5,
+    "honoured" => 20,
+    "honours" => 5,
+    "hood" => 23,
+    "hoofs" => 5,
+    "hook" => 5,
+    "hookah" => 1,
+    "hooked" => 20,
+    "hooking" => 21,
+    "hooks" => 5,
+    "hookup" => 1,
+    "hoop" => 5,
+    "hooray" => 5,
+    "hooting" => 21,
+    "hop" => 5,
+    "hope" => 7,
+    "hoped" => 20,
+    "hopeful" => 17,
+    "hopefully" => 32,
+    "hopeless" => 16,
+    "hopelessly" => 32,
+    "hopelessness" => 17,
+    "hopes" => 5,
+    "hoping" => 21,
+    "hopkins" => 2,
+    "hopped" => 20,
+    "hopper" => 3,
+    "hopping" => 21,
+    "hops" => 5,
+    "horace" => 2,
+    "horde" => 5,
+    "horizon" => 1,
+    "horizontal" => 17,
+    "horizontally" => 32,
+    "horn" => 7,
+    "horns" => 5,
+    "horrible" => 17,
+    "horribly" => 32,
+    "horrid" => 16,
+    "horridly" => 32,
+    "horrific" => 16,
+    "horrify" => 4,
+    "horror" => 1,
+    "horrors" => 1,
+    "horse" => 5,
+    "horseback" => 1,
+    "horsemen" => 1,
+    "horses" => 5,
+    "horsewhipped" => 4,
+    "horsey" => 17,
+    "hosea" => 2,
+    "hosed" => 20,
+    "hospitable" => 16,
+    "hospital" => 17,
+    "hospitality" => 1,
+    "hospitals" => 1,
+    "host" => 5,
+    "hosted" => 20,
+    "hostile" => 17,
+    "hostility" => 1,
+    "hosting" => 21,
+    "hostname" => 1,
+    "hosts" => 5,
+    "hot" => 21,
+    "hotel" => 1,
+    "hotels" => 1,
+    "hotkey" => 5,
+    "hotkeys" => 5,
+    "hotter" => 21,
+    "hound" => 5,
+    "hour" => 1,
+    "hourly" => 48,
+    "hours" => 1,
+    "house" => 7,
+    "housed" => 20,
+    "household" => 17,
+    "households" => 1,
+    "housekeeper" => 1,
+    "housemaid" => 5,
+    "houses" => 5,
+    "housing" => 21,
+    "hove" => 4,
+    "hovel" => 5,
+    "hovels" => 5,
+    "hover" => 5,
+    "hovered" => 20,
+    "hovering" => 21,
+    "hovers" => 5,
+    "how" => 513,
+    "howard" => 2,
+    "howe" => 2,
+    "howell" => 2,
+    "however" => 512,
+    "howl" => 5,
+    "howled" => 20,
+    "howling" => 21,
+    "hub" => 3,
+    "hubbub" => 5,
+    "hubert" => 2,
+    "hubris" => 1,
+    "huckleberry" => 5,
+    "huddled" => 20,
+    "hudson" => 3,
+    "hue" => 1,
+    "huff" => 7,
+    "huffed" => 20,
+    "hug" => 5,
+    "huge" => 16,
+    "hugged" => 4,
+    "hugging" => 5,
+    "hugh" => 2,
+    "hughes" => 2,
+    "hugo" => 3,
+    "hulk" => 5,
+    "hulking" => 21,
+    "hull" => 7,
+    "hulled" => 20,
+    "hum" => 5,
+    "human" => 21,
+    "humane" => 16,
+    "humanist" => 17,
+    "humanists" => 1,
+    "humanities" => 1,
+    "humanity" => 1,
+    "humanization" => 1,
+    "humanize" => 4,
+    "humankind" => 1,
+    "humans" => 5,
+    "humble" => 21,
+    "humbler" => 17,
+    "humbly" => 32,
+    "humdrum" => 17,
+    "humid" => 16,
+    "humidor" => 1,
+    "humiliated" => 20,
+    "humiliation" => 5,
+    "humility" => 1,
+    "humming" => 21,
+    "humorous" => 16,
+    "humorously" => 32,
+    "humour" => 5,
+    "humoured" => 20,
+    "humours" => 5,
+    "hump" => 5,
+    "humpbacked" => 20,
+    "humph" => 4,
+    "hundred" => 1,
+    "hundredfold" => 20,
+    "hundreds" => 1,
+    "hundredth" => 17,
+    "hung" => 22,
+    "hungarian" => 17,
+    "hungary" => 2,
+    "hunger" => 5,
+    "hungered" => 20,
+    "hungrier" => 16,
+    "hungrily" => 32,
+    "hungry" => 16,
+    "hunk" => 1,
+    "hunks" => 1,
+    "hunt" => 7,
+    "hunted" => 20,
+    "hunter" => 3,
+    "hunter's" => 1,
+    "hunting" => 21,
+    "hurd" => 2,
+    "hurdles" => 5,
+    "hurled" => 20,
+    "hurling" => 21,
+    "hurrah" => 5,
+    "hurrahs" => 5,
+    "hurricane" => 5,
+    "hurried" => 20,
+    "hurriedly" => 32,
+    "hurries" => 5,
+    "hurry" => 5,
+    "hurrying" => 21,
+    "hurt" => 21,
+    "hurts" => 5,
+    "husband" => 5,
+    "husbands" => 5,
+    "hush" => 5,
+    "hushed" => 20,
+    "hushing" => 21,
+    "huskier" => 16,
+    "husky" => 17,
+    "hustled" => 20,
+    "hut" => 5,
+    "huts" => 5,
+    "huzzah" => 5,
+    "hybrid" => 17,
+    "hyde" => 2,
+    "hydrate" => 5,
+    "hydrates" => 5,
+    "hydration" => 5,
+    "hydraulic" => 20,
+    "hydraulics" => 5,
+    "hydroplane" => 5,
+    "hygiene" => 1,
+    "hygienic" => 16,
+    "hymn" => 5,
+    "hype" => 21,
+    "hyper" => 17,
+    "hyperbola" => 1,
+    "hyperbolic" => 16,
+    "hypercritical" => 16,
+    "hypertext" => 1,
+    "hyphen" => 517,
+    "hyphenating" => 21,
+    "hyphenation" => 5,
+    "hyphens" => 5,
+    "hypocritical" => 16,
+    "hypotenuse" => 1,
+    "hypotheses" => 1,
+    "hypothesis" => 1,
+    "hypothetical" => 17,
+    "hysteria" => 1,
+    "hysterical" => 16,
+    "hysterics" => 1,
+    "i" => 256,
+    "ian" => 2,
+    "ice" => 7,
+    "iceberg" => 1,
+    "iced" => 20,
+    "icelandic" => 19,
+    "ices" => 5,
+    "icon" => 1,
+    "iconic" => 16,
+    "iconography" => 1,
+    "icons" => 1,
+    "icy" => 16,
+    "ida" => 2,
+    "ide" => 1,
+    "idea" => 1,
+    "ideal" => 17,
+    "idealised" => 20,
+    "ideally" => 32,
+    "ideas" => 1,
+    "idem" => 256,
+    "idempotent" => 17,
+    "identical" => 17,
+    "identically" => 32,
+    "identifiable" => 16,
+    "identification" => 5,
+    "identified" => 20,
+    "identifier" => 1,
+    "identifiers" => 4,
+    "identifies" => 4,
+    "identify" => 4,
+    "identifying" => 21,
+    "identities" => 1,
+    "identity" => 1,
+    "ideograph" => 1,
+    "ideographs" => 1,
+    "ideological" => 16,
+    "ideologically" => 32,
+    "ideologues" => 1,
+    "ideology" => 1,
+    "ides" => 1,
+    "idiom" => 1,
+    "idiomatic" => 17,
+    "idiomatically" => 17,
+    "idioms" => 1,
+    "idiosyncrasies" => 1,
+    "idiosyncratic" => 16,
+    "idiot" => 17,
+    "idiotic" => 16,
+    "idiotically" => 16,
+    "idle" => 21,
+    "idled" => 20,
+    "idleness" => 21,
+    "idler" => 17,
+    "idlers" => 21,
+    "idles" => 5,
+    "idling" => 21,
+    "idly" => 32,
+    "idol" => 1,
+    "idols" => 1,
+    "ids" => 5,
+    "if" => 513,
+    "ifs" => 1,
+    "ignoble" => 20,
+    "ignominious" => 16,
+    "ignominy" => 1,
+    "ignorance" => 1,
+    "ignorant" => 17,
+    "ignorantly" => 32,
+    "ignore" => 4,
+    "ignored" => 20,
+    "ignores" => 4,
+    "ignoring" => 21,
+    "ill" => 21,
+    "illegal" => 17,
+    "illegally" => 32,
+    "illinois" => 3,
+    "illiterate" => 17,
+    "illness" => 21,
+    "illogical" => 16,
+    "illuminate" => 21,
+    "illuminated" => 20,
+    "illuminating" => 21,
+    "illumined" => 20,
+    "illusion" => 1,
+    "illustrate" => 4,
+    "illustrated" => 20,
+    "illustrates" => 4,
+    "illustrating" => 21,
+    "illustration" => 5,
+    "illustrations" => 4,
+    "illustrative" => 20,
+    "illustrator" => 1,
+    "illustrious" => 16,
+    "image" => 5,
+    "imaged" => 20,
+    "imagery" => 1,
+    "images" => 5,
+    "imaginable" => 21,
+    "imaginary" => 17,
+    "imagination" => 1,
+    "imaginations" => 1,
+    "imaginative" => 16,
+    "imagine" => 5,
+    "imagined" => 20,
+    "imaging" => 21,
+    "imagining" => 21,
+    "imbecile" => 17,
+    "imbecility" => 1,
+    "imbibed" => 20,
+    "imbue" => 4,
+    "imbued" => 20,
+    "imf" => 2,
+    "imitate" => 4,
+    "imitated" => 20,
+    "imitates" => 4,
+    "imitation" => 5,
+    "imitations" => 5,
+    "immaculate" => 16,
+    "immature" => 17,
+    "immaturity" => 1,
+    "immeasurable" => 17,
+    "immeasurably" => 32,
+    "immediate" => 16,
+    "immediately" => 32,
+    "immense" => 17,
+    "immensely" => 32,
+    "immensity" => 1,
+    "immersed" => 20,
+    "immersing" => 21,
+    "immigrants" => 1,
+    "imminence" => 1,
+    "imminent" => 16,
+    "immoderate" => 16,
+    "immoderately" => 32,
+    "immorality" => 1,
+    "immortal" => 17,
+    "immortality" => 1,
+    "immovable" => 17,
+    "immune" => 21,
+    "immunity" => 1,
+    "immunization" => 1,
+    "immured" => 20,
+    "immutability" => 1,
+    "immutable" => 17,
+    "immutably" => 32,
+    "imo" => 3,
+    "imp" => 5,
+    "impact" => 5,
+    "impacted" => 20,
+    "impacting" => 21,
+    "impacts" => 5,
+    "impaired" => 21,
+    "impairing" => 21,
+    "impart" => 4,
+    "imparted" => 20,
+    "impartial" => 16,
+    "imparting" => 21,
+    "impassable" => 1,
+    "impasse" => 1,
+    "impassible" => 16,
+    "impassioned" => 16,
+    "impassive" => 17,
+    "impatience" => 1,
+    "impatient" => 16,
+    "impatiently" => 32,
+    "impeachment" => 5,
+    "impeccable" => 16,
+    "impedance" => 1,
+    "impede" => 4,
+    "impeded" => 20,
+    "impediment" => 1,
+    "impediments" => 1,
+    "impeding" => 21,
+    "impelled" => 4,
+    "impend" => 4,
+    "impending" => 21,
+    "impenetrable" => 17,
+    "imperative" => 17,
+    "imperatively" => 32,
+    "imperceptible" => 16,
+    "imperceptibly" => 32,
+    "imperfect" => 21,
+    "imperfections" => 1,
+    "imperial" => 17,
+    "imperialism" => 1,
+    "imperil" => 4,
+    "imperilled" => 4,
+    "imperious" => 16,
+    "imperishable" => 17,
+    "impersonal" => 17,
+    "impersonally" => 32,
+    "impersonate" => 4,
+    "impersonation" => 5,
+    "impertinent" => 17,
+    "imperturbable" => 16,
+    "imperturbably" => 32,
+    "impervious" => 16,
+    "impetuous" => 16,
+    "impetuously" => 32,
+    "impetus" => 1,
+    "implacable" => 16,
+    "implausible" => 16,
+    "implement" => 5,
+    "implementation" => 1,
+    "implementations" => 1,
+    "implemented" => 20,
+    "implementer" => 1,
+    "implementing" => 21,
+    "implements" => 5,
+    "implicate" => 5,
+    "implicated" => 20,
+    "implicates" => 5,
+    "implicating" => 21,
+    "implication" => 5,
+    "implications" => 4,
+    "implicit" => 16,
+    "implicitly" => 32,
+    "implied" => 20,
+    "implies" => 4,
+    "implode" => 4,
+    "implore" => 5,
+    "implored" => 20,
+    "implores" => 5,
+    "imploring" => 21,
+    "imply" => 4,
+    "implying" => 21,
+    "import" => 5,
+    "importable" => 5,
+    "importance" => 1,
+    "important" => 16,
+    "importantly" => 32,
+    "importation" => 1,
+    "imported" => 20,
+    "importer" => 1,
+    "importers" => 5,
+    "importing" => 21,
+    "imports" => 5,
+    "importunate" => 20,
+    "impose" => 5,
+    "imposed" => 20,
+    "imposes" => 5,
+    "imposing" => 21,
+    "impossibilities" => 1,
+    "impossibility" => 1,
+    "impossible" => 17,
+    "impossibly" => 32,
+    "imposts" => 1,
+    "impotence" => 1,
+    "impotent" => 17,
+    "impracticability" => 1,
+    "impracticable" => 17,
+    "impractical" => 16,
+    "imprecate" => 4,
+    "imprecations" => 4,
+    "imprecise" => 16,
+    "imprecisely" => 32,
+    "imprecision" => 17,
+    "impress" => 5,
+    "impressed" => 20,
+    "impression" => 5,
+    "impressionability" => 1,
+    "impressions" => 5,
+    "impressive" => 21,
+    "impressively" => 32,
+    "impressiveness" => 17,
+    "imprint" => 5,
+    "imprinted" => 20,
+    "imprisoned" => 20,
+    "imprisoning" => 21,
+    "imprisonment" => 5,
+    "imprisons" => 4,
+    "improbabilities" => 1,
+    "improbable" => 16,
+    "improper" => 20,
+    "improperly" => 32,
+    "improve" => 4,
+    "improved" => 20,
+    "improvement" => 5,
+    "improvements" => 1,
+    "improves" => 4,
+    "improving" => 21,
+    "improvisations" => 1,
+    "improvised" => 20,
+    "imprudence" => 1,
+    "imprudent" => 16,
+    "imprudently" => 32,
+    "impudence" => 1,
+    "impulse" => 5,
+    "impulses" => 5,
+    "impulsive" => 21,
+    "impulsively" => 32,
+    "impunity" => 1,
+    "impure" => 20,
+    "in" => 83,
+    "ina" => 2,
+    "inability" => 1,
+    "inaccessible" => 16,
+    "inaccuracies" => 1,
+    "inaccurate" => 16,
+    "inaction" => 1,
+    "inactive" => 17,
+    "inactivity" => 1,
+    "inadequate" => 20,
+    "inadmissible" => 16,
+    "inadvertent" => 16,
+    "inadvertently" => 32,
+    "inadvisable" => 16,
+    "inanimate" => 21,
+    "inapplicable" => 16,
+    "inappropriate" => 16,
+    "inappropriately" => 32,
+    "inarticulate" => 17,
+    "inarticulately" => 32,
+    "inaudible" => 16,
+    "inaugurated" => 20,
+    "inauguration" => 21,
+    "inbound" => 21,
+    "inbuilt" => 21,
+    "incalculable" => 16,
+    "incantation" => 1,
+    "incantations" => 1,
+    "incapable" => 16,
+    "incarnate" => 20,
+    "incarnation" => 21,
+    "incarnations" => 20,
+    "incendiaries" => 1,
+    "incentives" => 1,
+    "inception" => 1,
+    "incessant" => 16,
+    "incessantly" => 32,
+    "inch" => 277,
+    "inches" => 5,
+    "incident" => 17,
+    "incidental" => 17,
+    "incidentally" => 32,
+    "incidents" => 1,
+    "incipient" => 17,
+    "incisive" => 20,
+    "incitement" => 1,
+    "incl" => 64,
+    "inclemency" => 1,
+    "inclination" => 1,
+    "inclinations" => 1,
+    "incline" => 5,
+    "inclined" => 20,
+    "include" => 5,
+    "included" => 20,
+    "includes" => 5,
+    "including" => 21,
+    "inclusion" => 1,
+    "inclusions" => 1,
+    "inclusive" => 16,
+    "inclusively" => 32,
+    "incognito" => 17,
+    "incoherent" => 16,
+    "income" => 69,
+    "incommoded" => 20,
+    "incomparable" => 17,
+    "incompatibilities" => 1,
+    "incompatibility" => 1,
+    "incompatible" => 17,
+    "incompatibly" => 32,
+    "incomplete" => 17,
+    "incompletely" => 32,
+    "incomprehensible" => 16,
+    "inconceivable" => 16,
+    "incongruous" => 16,
+    "inconsequential" => 16,
+    "inconsiderate" => 20,
+    "inconsistency" => 1,
+    "inconsistent" => 17,
+    "inconstant" => 16,
+    "inconvenience" => 5,
+    "inconvenienced" => 20,
+    "inconvenient" => 16,
+    "incorporate" => 20,
+    "incorporated" => 20,
+    "incorporates" => 4,
+    "incorporating" => 21,
+    "incorrect" => 17,
+    "incorrectly" => 32,
+    "incorrigible" => 17,
+    "incorruptible" => 17,
+    "increase" => 5,
+    "increasing" => 21,
+    "increasingly" => 32,
+    "incredible" => 16,
+    "incredibly" => 32,
+    "incredulity" => 1,
+    "incredulous" => 16,
+    "increment" => 5,
+    "incremental" => 16,
+    "incrementally" => 32,
+    "incremented" => 20,
+    "incrementing" => 21,
+    "increments" => 5,
+    "incriminate" => 4,
+    "incriminated" => 20,
+    "incubation" => 5,
+    "incumbent" => 17,
+    "incur" => 4,
+    "incurable" => 21,
+    "incurably" => 32,
+    "incurred" => 4,
+    "incurring" => 4,
+    "incurs" => 4,
+    "indebted" => 20,
+    "indecent" => 16,
+    "indecipherable" => 16,
+    "indecision" => 5,
+    "indefatigable" => 16,
+    "indefatigably" => 32,
+    "indefinable" => 16,
+    "indefinite" => 17,
+    "indelible" => 16,
+    "indelibly" => 32,
+    "indemnified" => 20,
+    "indent" => 5,
+    "indentation" => 1,
+    "indentations" => 1,
+    "independence" => 3,
+    "independent" => 17,
+    "indescribable" => 16,
+    "indestructible" => 16,
+    "indeterminate" => 17,
+    "index" => 5,
+    "indexed" => 20,
+    "indexes" => 5,
+    "indexing" => 21,
+    "india" => 3,
+    "indian" => 19,
+    "indiana" => 2,
+    "indianapolis" => 2,
+    "indians" => 1,
+    "indicate" => 4,
+    "indicated" => 20,
+    "indicates" => 4,
+    "indicating" => 21,
+    "indication" => 5,
+    "indications" => 4,
+    "indicative" => 21,
+    "indicator" => 1,
+    "indicators" => 1,
+    "indices" => 5,
+    "indictment" => 5,
+    "indies" => 3,
+    "indifference" => 5,
+    "indifferent" => 17,
+    "indigenous" => 16,
+    "indigestion" => 1,
+    "indignant" => 16,
+    "indignantly" => 32,
+    "indignation" => 1,
+    "indignity" => 1,
+    "indigo" => 17,
+    "indirect" => 21,
+    "indirection" => 1,
+    "indirectly" => 32,
+    "indiscernible" => 16,
+    "indiscreet" => 16,
+    "indiscreetly" => 32,
+    "indiscretion" => 1,
+    "indiscriminately" => 32,
+    "indispensable" => 17,
+    "indisposition" => 5,
+    "indistinct" => 20,
+    "indistinguishable" => 16,
+    "indistinguishably" => 32,
+    "individual" => 17,
+    "individuality" => 1,
+    "individualized" => 20,
+    "individually" => 32,
+    "individuals" => 1,
+    "indivisible" => 17,
+    "indolence" => 1,
+    "indonesia" => 2,
+    "indonesian" => 17,
+    "induce" => 4,
+    "induced" => 20,
+    "inducing" => 21,
+    "induction" => 1,
+    "inductive" => 20,
+    "indulge" => 4,
+    "indulged" => 20,
+    "indulgence" => 5,
+    "indulgently" => 32,
+    "indulging" => 21,
+    "indus" => 2,
+    "industrial" => 17,
+    "industrialization" => 1,
+    "industries" => 1,
+    "industrious" => 16,
+    "industry" => 1,
+    "ineffable" => 16,
+    "ineffective" => 17,
+    "ineffectual" => 16,
+    "inefficient" => 17,
+    "inelegant" => 17,
+    "ineligible" => 17,
+    "ineptly" => 32,
+    "inequalities" => 1,
+    "inequality" => 1,
+    "inert" => 21,
+    "inescapable" => 16,
+    "inessential" => 17,
+    "inestimable" => 16,
+    "inevitable" => 17,
+    "inevitably" => 32,
+    "inexact" => 16,
+    "inexactness" => 1,
+    "inexhaustible" => 16,
+    "inexorable" => 16,
+    "inexpensive" => 16,
+    "inexperience" => 5,
+    "inexplicable" => 16,
+    "inexpressible" => 16,
+    "inextinguishable" => 16,
+    "inextricable" => 16,
+    "inextricably" => 32,
+    "inf" => 1,
+    "infallibility" => 1,
+    "infallible" => 17,
+    "infallibly" => 32,
+    "infamous" => 20,
+    "infamy" => 1,
+    "infancy" => 1,
+    "infant" => 5,
+    "infantile" => 16,
+    "infantry" => 1,
+    "infatuated" => 20,
+    "infatuation" => 21,
+    "infeasible" => 16,
+    "infect" => 20,
+    "infected" => 21,
+    "infection" => 1,
+    "infections" => 1,
+    "infectious" => 16,
+    "infer" => 4,
+    "inference" => 1,
+    "inferences" => 1,
+    "inferior" => 17,
+    "inferiors" => 1,
+    "infernal" => 17,
+    "inferno" => 1,
+    "inferred" => 4,
+    "inferring" => 5,
+    "infers" => 4,
+    "infested" => 20,
+    "infidels" => 1,
+    "infiltration" => 1,
+    "infinite" => 17,
+    "infinitesimal" => 17,
+    "infinities" => 1,
+    "infinity" => 1,
+    "infirmities" => 1,
+    "infirmity" => 1,
+    "infix" => 5,
+    "inflamed" => 20,
+    "inflate" => 4,
+    "inflated" => 20,
+    "inflates" => 4,
+    "inflating" => 21,
+    "inflation" => 1,
+    "inflected" => 20,
+    "inflection" => 1,
+    "inflectional" => 16,
+    "inflections" => 1,
+    "inflexibility" => 1,
+    "inflexible" => 17,
+    "inflict" => 4,
+    "inflicted" => 20,
+    "inflicting" => 21,
+    "infliction" => 1,
+    "inflicts" => 4,
+    "influence" => 5,
+    "influenced" => 20,
+    "influences" => 5,
+    "influencing" => 21,
+    "influential" => 17,
+    "info" => 1,
+    "inform" => 21,
+    "informal" => 16,
+    "informality" => 1,
+    "informally" => 32,
+    "informatics" => 1,
+    "information" => 1,
+    "informational" => 16,
+    "informative" => 16,
+    "informed" => 20,
+    "informer" => 17,
+    "informers" => 20,
+    "infra" => 1,
+    "infrastructure" => 1,
+    "infrequent" => 16,
+    "infrequently" => 32,
+    "infringe" => 21,
+    "infringement" => 1,
+    "infuse" => 5,
+    "ingenious" => 16,
+    "ingenuity" => 1,
+    "ingest" => 5,
+    "ingested" => 20,
+    "ingestion" => 1,
+    "inglorious" => 16,
+    "ingot" => 5,
+    "ingratiate" => 4,
+    "ingratitude" => 1,
+    "ingredient" => 1,
+    "inhabit" => 5,
+    "inhabitable" => 16,
+    "inhabitant" => 17,
+    "inhabitants" => 1,
+    "inhabited" => 20,
+    "inhale" => 21,
+    "inherent" => 16,
+    "inherently" => 32,
+    "inherit" => 4,
+    "inheritable" => 16,
+    "inheritance" => 1,
+    "inherited" => 20,
+    "inheriting" => 21,
+    "inherits" => 4,
+    "inhibit" => 4,
+    "inhibited" => 20,
+    "inhibits" => 4,
+    "inhospitably" => 32,
+    "inhuman" => 16,
+    "inimitably" => 32,
+    "initial" => 21,
+    "initialisation" => 1,
+    "initialisations" => 1,
+    "initialise" => 4,
+    "initialised" => 20,
+    "initialization" => 1,
+    "initializations" => 1,
+    "initialize" => 4,
+    "initialized" => 20,
+    "initializes" => 4,
+    "initializing" => 21,
+    "initially" => 32,
+    "initials" => 5,
+    "initiate" => 21,
+    "initiated" => 20,
+    "initiates" => 5,
+    "initiating" => 21,
+    "initiation" => 21,
+    "initiative" => 21,
+    "initiatives" => 1,
+    "initiator" => 1,
+    "inject" => 4,
+    "injected" => 20,
+    "injecting" => 21,
+    "injection" => 1,
+    "injections" => 1,
+    "injector" => 1,
+    "injects" => 4,
+    "injunction" => 5,
+    "injure" => 4,
+    "injured" => 20,
+    "injuring" => 21,
+    "injury" => 21,
+    "injustice" => 1,
+    "ink" => 5,
+    "inks" => 5,
+    "inkstand" => 1,
+    "inkwell" => 1,
+    "inky" => 16,
+    "inlaid" => 20,
+    "inline" => 21,
+    "inmate" => 1,
+    "inmates" => 1,
+    "inmost" => 17,
+    "inn" => 533,
+    "inner" => 1,
+    "innermost" => 17,
+    "innocence" => 1,
+    "innocent" => 19,
+    "innocently" => 32,
+    "innocents" => 1,
+    "innovating" => 21,
+    "innovation" => 5,
+    "innovations" => 4,
+    "innovative" => 4,
+    "inns" => 5,
+    "innuendo" => 5,
+    "innumerable" => 16,
+    "input" => 5,
+    "inputted" => 4,
+    "inputting" => 5,
+    "inquest" => 5,
+    "inquietude" => 1,
+    "inquire" => 5,
+    "inquired" => 20,
+    "inquirer" => 1,
+    "inquirers" => 4,
+    "inquiries" => 1,
+    "inquiring" => 21,
+    "inquiringly" => 32,
+    "inquiry" => 1,
+    "inquisitions" => 5,
+    "inquisitive" => 16,
+    "inquisitively" => 32,
+    "inquisitiveness" => 17,
+    "ins" => 3,
+    "insane" => 16,
+    "insanity" => 1,
+    "insatiable" => 16,
+    "inscribed" => 20,
+    "inscription" => 1,
+    "inscriptions" => 1,
+    "inscrutable" => 17,
+    "insect" => 1,
+    "insecure" => 16,
+    "insensibility" => 1,
+    "insensible" => 16,
+    "insensibly" => 32,
+    "insensitive" => 16,
+    "insensitively" => 32,
+    "insensitivity" => 1,
+    "inseparable" => 17,
+    "insert" => 5,
+    "inserted" => 20,
+    "inserting" => 21,
+    "insertion" => 1,
+    "insertions" => 1,
+    "inserts" => 5,
+    "inside" => 81,
+    "insider" => 17,
+    "insidious" => 16,
+    "insight" => 1,
+    "insightful" => 16,
+    "insights" => 1,
+    "insignia" => 1,
+    "insignificance" => 1,
+    "insignificant" => 17,
+    "insincerity" => 1,
+    "insinuating" => 21,
+    "insipid" => 16,
+    "insist" => 4,
+    "insisted" => 20,
+    "insistently" => 32,
+    "insisting" => 21,
+    "insists" => 4,
+    "insolence" => 5,
+    "insolent" => 17,
+    "insolently" => 32,
+    "inspect" => 4,
+    "inspected" => 20,
+    "inspecting" => 21,
+    "inspection" => 1,
+    "inspections" => 1,
+    "inspector" => 1,
+    "inspectors" => 1,
+    "inspects" => 4,
+    "inspiration" => 1,
+    "inspirational" => 17,
+    "inspirations" => 1,
+    "inspire" => 5,
+    "inspired" => 20,
+    "inspiring" => 21,
+    "instability" => 1,
+    "install" => 5,
+    "installation" => 1,
+    "installations" => 1,
+    "installed" => 20,
+    "installer" => 1,
+    "installers" => 5,
+    "installing" => 21,
+    "installs" => 5,
+    "instance" => 5,
+    "instanced" => 20,
+    "instant" => 21,
+    "instantaneous" => 16,
+    "instantaneously" => 32,
+    "instantly" => 32,
+    "instants" => 5,
+    "instep" => 5,
+    "instigate" => 4,
+    "instigated" => 20,
+    "instigating" => 21,
+    "instinct" => 17,
+    "instinctive" => 17,
+    "instinctively" => 32,
+    "instincts" => 1,
+    "institute" => 21,
+    "instituted" => 20,
+    "institutes" => 5,
+    "institution" => 21,
+    "institutions" => 21,
+    "instruct" => 21,
+    "instructed" => 20,
+    "instructing" => 21,
+    "instruction" => 1,
+    "instructional" => 17,
+    "instructions" => 1,
+    "instructive" => 21,
+    "instructor" => 1,
+    "instructors" => 1,
+    "instructs" => 5,
+    "instrument" => 5,
+    "instrumental" => 17,
+    "instrumentation" => 1,
+    "instruments" => 5,
+    "insufferably" => 32,
+    "insufficient" => 16,
+    "insular" => 17,
+    "insulate" => 4,
+    "insult" => 5,
+    "insulted" => 20,
+    "insulting" => 21,
+    "insultingly" => 32,
+    "insuperable" => 16,
+    "insupportable" => 16,
+    "insurance" => 1,
+    "insure" => 4,
+    "insures" => 4,
+    "insurmountable" => 16,
+    "insurrection" => 1,
+    "insurrections" => 1,
+    "intact" => 16,
+    "intangible" => 17,
+    "integer" => 1,
+    "integers" => 1,
+    "integral" => 17,
+    "integrate" => 4,
+    "integrated" => 20,
+    "integrates" => 4,
+    "integrating" => 21,
+    "integration" => 5,
+    "integrator" => 1,
+    "integrators" => 1,
+    "integrity" => 1,
+    "intel" => 3,
+    "intellect" => 1,
+    "intellectual" => 17,
+    "intellectually" => 32,
+    "intelligence" => 1,
+    "intelligent" => 17,
+    "intelligently" => 32,
+    "intelligible" => 16,
+    "intend" => 4,
+    "intended" => 21,
+    "intense" => 16,
+    "intensely" => 32,
+    "intensified" => 20,
+    "intensity" => 1,
+    "intensive" => 17,
+    "intent" => 17,
+    "intention" => 5,
+    "intentional" => 17,
+    "intentionally" => 32,
+    "intentions" => 5,
+    "intently" => 32,
+    "intents" => 1,
+    "inter" => 4,
+    "interact" => 5,
+    "interacted" => 20,
+    "interacting" => 21,
+    "interaction" => 1,
+    "interactions" => 1,
+    "interactive" => 21,
+    "interactively" => 32,
+    "interacts" => 5,
+    "intercept" => 5,
+    "intercepted" => 20,
+    "intercepting" => 21,
+    "interception" => 1,
+    "interceptions" => 1,
+    "interceptor" => 1,
+    "intercepts" => 5,
+    "interchange" => 5,
+    "interchangeable" => 17,
+    "interchangeably" => 32,
+    "interchanged" => 20,
+    "interchanging" => 21,
+    "intercom" => 5,
+    "interconnectedness" => 1,
+    "intercourse" => 5,
+    "interdependent" => 16,
+    "interdisciplinary" => 16,
+    "interest" => 5,
+    "interested" => 20,
+    "interesting" => 20,
+    "interestingly" => 32,
+    "interests" => 5,
+    "interface" => 5,
+    "interfaces" => 5,
+    "interfacing" => 21,
+    "interfere" => 4,
+    "interfered" => 20,
+    "interference" => 5,
+    "interferes" => 4,
+    "interfering" => 21,
+    "interim" => 17,
+    "interior" => 17,
+    "interjected" => 20,
+    "interjection" => 1,
+    "interjections" => 1,
+    "interlarded" => 20,
+    "interleave" => 5,
+    "interleaved" => 20,
+    "interleaves" => 5,
+    "interleaving" => 21,
+    "interlined" => 20,
+    "interlocutor" => 1,
+    "intermarriage" => 1,
+    "intermediaries" => 1,
+    "intermediary" => 17,
+    "intermediate" => 21,
+    "intermediately" => 32,
+    "interment" => 5,
+    "intermezzo" => 1,
+    "interminable" => 16,
+    "intermingling" => 21,
+    "intermittent" => 17,
+    "intermittently" => 32,
+    "intermix" => 5,
+    "intermixed" => 20,
+    "intern" => 21,
+    "internal" => 16,
+    "internalized" => 20,
+    "internally" => 32,
+    "internals" => 16,
+    "international" => 17,
+    "internationalisation" => 1,
+    "internationalization" => 1,
+    "internationalize" => 4,
+    "internationalized" => 20,
+    "internationally" => 32,
+    "interned" => 20,
+    "internet" => 7,
+    "interning" => 21,
+    "internment" => 21,
+    "interpol" => 2,
+    "interpolate" => 4,
+    "interpolated" => 20,
+    "interpolates" => 4,
+    "interpolating" => 21,
+    "interpolation" => 5,
+    "interpolations" => 4,
+    "interposed" => 20,
+    "interpret" => 4,
+    "interpretation" => 1,
+    "interpretations" => 1,
+    "interpretative" => 16,
+    "interpreted" => 20,
+    "interpreter" => 1,
+    "interpreters" => 1,
+    "interpreting" => 21,
+    "interprets" => 4,
+    "interrelated" => 20,
+    "interrogate" => 4,
+    "interrogatives" => 1,
+    "interrupt" => 5,
+    "interrupted" => 20,
+    "interrupting" => 21,
+    "interruption" => 1,
+    "interrupts" => 5,
+    "intersect" => 4,
+    "intersected" => 20,
+    "intersection" => 1,
+    "intersections" => 1,
+    "intersects" => 4,
+    "interspersed" => 20,
+    "interspersing" => 21,
+    "interstellar" => 16,
+    "intertwined" => 20,
+    "interval" => 1,
+    "intervals" => 1,
+    "intervene" => 4,
+    "intervened" => 20,
+    "intervening" => 21,
+    "intervention" => 1,
+    "interview" => 5,
+    "interviewed" => 20,
+    "intimacy" => 1,
+    "intimate" => 21,
+    "intimated" => 20,
+    "intimately" => 32,
+    "intimation" => 21,
+    "intimidated" => 20,
+    "into" => 64,
+    "intolerable" => 16,
+    "intonation" => 1,
+    "intonations" => 1,
+    "intoxicated" => 20,
+    "intoxicating" => 21,
+    "intoxication" => 21,
+    "intractable" => 16,
+    "intrepid" => 16,
+    "intricacies" => 1,
+    "intricate" => 20,
+    "intrigue" => 5,
+    "intriguing" => 21,
+    "intrinsic" => 17,
+    "intrinsically" => 32,
+    "intro" => 5,
+    "introduce" => 4,
+    "introduced" => 20,
+    "introduces" => 4,
+    "introducing" => 21,
+    "introduction" => 1,
+    "introductions" => 1,
+    "introductory" => 16,
+    "introspect" => 4,
+    "introspected" => 20,
+    "introspecting" => 21,
+    "introspection" => 1,
+    "introspective" => 20,
+    "intrude" => 4,
+    "intruded" => 20,
+    "intruder" => 1,
+    "intruding" => 21,
+    "intrusion" => 1,
+    "intrusions" => 1,
+    "intrusive" => 17,
+    "intuiting" => 21,
+    "intuition" => 1,
+    "intuitions" => 1,
+    "intuitive" => 21,
+    "intuitively" => 32,
+    "inuit" => 19,
+    "inundation" => 5,
+    "inuring" => 21,
+    "invade" => 4,
+    "invaded" => 20,
+    "invader" => 1,
+    "invaders" => 4,
+    "invalid" => 21,
+    "invalidate" => 4,
+    "invalidation" => 1,
+    "invalided" => 20,
+    "invaluable" => 16,
+    "invariable" => 17,
+    "invariably" => 32,
+    "invariant" => 17,
+    "invasion" => 1,
+    "invasions" => 1,
+    "invasive" => 17,
+    "invective" => 17,
+    "inveigh" => 4,
+    "inveigled" => 20,
+    "invent" => 4,
+    "invented" => 20,
+    "inventing" => 21,
+    "invention" => 1,
+    "inventions" => 1,
+    "inventor" => 1,
+    "inventors" => 1,
+    "inventory" => 5,
+    "inverse" => 21,
+    "inversely" => 32,
+    "inverses" => 5,
+    "inversion" => 5,
+    "invert" => 21,
+    "inverted" => 20,
+    "inverting" => 21,
+    "inverts" => 5,
+    "invest" => 5,
+    "invested" => 20,
+    "investigate" => 4,
+    "investigated" => 20,
+    "investigates" => 4,
+    "investigating" => 21,
+    "investigation" => 5,
+    "investigations" => 4,
+    "investing" => 21,
+    "investment" => 5,
+    "investor" => 1,
+    "invests" => 5,
+    "inveterate" => 20,
+    "invigorated" => 20,
+    "invincible" => 16,
+    "inviolate" => 16,
+    "invisible" => 16,
+    "invisibly" => 32,
+    "invitation" => 1,
+    "invitations" => 1,
+    "invite" => 5,
+    "invited" => 21,
+    "invites" => 5,
+    "inviting" => 21,
+    "invocable" => 16,
+    "invocation" => 1,
+    "invoke" => 4,
+    "invoked" => 20,
+    "invokes" => 4,
+    "invoking" => 21,
+    "involuntarily" => 32,
+    "involuntary" => 16,
+    "involve" => 4,
+    "involved" => 20,
+    "involvement" => 5,
+    "involves" => 4,
+    "involving" => 21,
+    "invulnerable" => 16,
+    "inward" => 17,
+    "inwardly" => 32,
+    "inwards" => 1,
+    "ionic" => 19,
+    "ions" => 1,
+    "iota" => 1,
+    "iowa" => 3,
+    "ipa" => 3,
+    "ireland" => 2,
+    "irene" => 2,
+    "iris" => 7,
+    "irish" => 19,
+    "irksome" => 16,
+    "iron" => 21,
+    "ironed" => 20,
+    "ironic" => 16,
+    "ironically" => 32,
+    "irons" => 5,
+    "irony" => 17,
+    "irradiated" => 20,
+    "irradiation" => 21,
+    "irrecoverable" => 16,
+    "irreducible" => 17,
+    "irrefutable" => 16,
+    "irregular" => 17,
+    "irregularities" => 1,
+    "irregularity" => 1,
+    "irregularly" => 32,
+    "irrelevant" => 16,
+    "irrelevantly" => 32,
+    "irremediable" => 16,
+    "irreparable" => 16,
+    "irreproachable" => 16,
+    "irresistible" => 16,
+    "irresistibly" => 32,
+    "irresolute" => 16,
+    "irresolutely" => 32,
+    "irresolution" => 17,
+    "irrespective" => 16,
+    "irresponsible" => 17,
+    "irretrievable" => 16,
+    "irretrievably" => 32,
+    "irrevocably" => 32,
+    "irrigated" => 20,
+    "irritability" => 1,
+    "irritable" => 16,
+    "irritated" => 20,
+    "irritating" => 21,
+    "irritation" => 5,
+    "is" => 12,
+    "isaac" => 2,
+    "isbn" => 1,
+    "isis" => 2,
+    "island" => 5,
+    "islands" => 5,
+    "isle" => 1,
+    "isles" => 1,
+    "islets" => 1,
+    "isn't" => 5,
+    "iso" => 67,
+    "isolate" => 21,
+    "isolated" => 20,
+    "isolates" => 5,
+    "isolating" => 21,
+    "isolation" => 21,
+    "isomorphic" => 16,
+    "israel" => 2,
+    "israeli" => 19,
+    "issue" => 5,
+    "issued" => 20,
+    "issuer" => 1,
+    "issues" => 5,
+    "issuing" => 21,
+    "it" => 259,
+    "italian" => 17,
+    "italians" => 1,
+    "italic" => 17,
+    "italicized" => 20,
+    "italics" => 1,
+    "italy" => 2,
+    "itch" => 5,
+    "itched" => 20,
+    "item" => 5,
+    "items" => 5,
+    "iterate" => 21,
+    "iterated" => 20,
+    "iterates" => 5,
+    "iterating" => 21,
+    "iteration" => 21,
+    "iterations" => 21,
+    "iterative" => 21,
+    "iterator" => 1,
+    "itinerant" => 17,
+    "itinerary" => 17,
+    "its" => 384,
+    "itself" => 256,
+    "ivan" => 2,
+    "ivory" => 19,
+    "ivy" => 3,
+    "jabber" => 5,
+    "jabbered" => 20,
+    "jack" => 23,
+    "jacket" => 5,
+    "jackets" => 5,
+    "jackpot" => 7,
+    "jackson" => 2,
+    "jacob" => 3,
+    "jacquard" => 3,
+    "jacqueline" => 2,
+    "jacques" => 2,
+    "jade" => 21,
+    "jaded" => 20,
+    "jagged" => 20,
+    "jail" => 5,
+    "jailed" => 20,
+    "jailing" => 21,
+    "jakarta" => 2,
+    "jake" => 2,
+    "jam" => 5,
+    "jamaica" => 2,
+    "james" => 7,
+    "jamie" => 2,
+    "jammed" => 20,
+    "jan" => 2,
+    "jane" => 3,
+    "janitor" => 1,
+    "january" => 2,
+    "japan" => 7,
+    "japanese" => 19,
+    "jar" => 5,
+    "jarring" => 21,
+    "jars" => 5,
+    "jason" => 2,
+    "jauntily" => 32,
+    "jauntiness" => 17,
+    "jaunty" => 17,
+    "java" => 3,
+    "javanese" => 3,
+    "jaw" => 21,
+    "jaws" => 5,
+    "jay" => 3,
+    "jazz" => 5,
+    "jealous" => 20,
+    "jealously" => 32,
+    "jealousy" => 1,
+    "jean" => 3,
+    "jeer" => 5,
+    "jeering" => 21,
+    "jeers" => 5,
+    "jeff" => 2,
+    "jefferson" => 3,
+    "jeffrey" => 2,
+    "jelly" => 21,
+    "jellyfish" => 1,
+    "jenkins" => 3,
+    "jennings" => 2,
+    "jeopardize" => 4,
+    "jeopardy" => 5,
+    "jeremiah" => 3,
+    "jeremy" => 2,
+    "jerk" => 5,
+    "jerked" => 20,
+    "jerkily" => 32,
+    "jerking" => 21,
+    "jerome" => 2,
+    "jerry" => 3,
+    "jersey" => 3,
+    "jessica" => 2,
+    "jest" => 5,
+    "jesting" => 21,
+    "jests" => 5,
+    "jet" => 21,
+    "jets" => 5,
+    "jetty" => 21,
+    "jew" => 23,
+    "jewel" => 7,
+    "jeweled" => 20,
+    "jewellers" => 1,
+    "jewellery" => 1,
+    "jewelry" => 5,
+    "jewels" => 5,
+    "jewess" => 1,
+    "jews" => 5,
+    "jib" => 5,
+    "jiff" => 5,
+    "jiffy" => 1,
+    "jim" => 2,
+    "jimmy" => 7,
+    "jingle" => 5,
+    "jingled" => 20,
+    "jitter" => 5,
+    "jitters" => 5,
+    "jittery" => 16,
+    "joanne" => 2,
+    "job" => 7,
+    "jobs" => 7,
+    "joe" => 3,
+    "joel" => 2,
+    "jog" => 5,
+    "jogged" => 4,
+    "joggle" => 5,
+    "johannes" => 3,
+    "john" => 3,
+    "johnny" => 3,
+    "johns" => 3,
+    "johnson" => 3,
+    "johnston" => 2,
+    "join" => 5,
+    "joined" => 20,
+    "joiner" => 1,
+    "joiners" => 1,
+    "joinery" => 1,
+    "joining" => 21,
+    "joins" => 5,
+    "joint" => 21,
+    "jointed" => 20,
+    "jointly" => 32,
+    "joints" => 5,
+    "joke" => 5,
+    "joker" => 1,
+    "jokes" => 5,
+    "joking" => 21,
+    "jollification" => 1,
+    "jolly" => 21,
+    "jolted" => 20,
+    "jon" => 2,
+    "jonathan" => 3,
+    "jones" => 7,
+    "jonquils" => 1,
+    "jordan" => 2,
+    "jose" => 2,
+    "joseph" => 3,
+    "josh" => 7,
+    "joshua" => 3,
+    "jostled" => 20,
+    "jostling" => 21,
+    "jot" => 5,
+    "joule" => 3,
+    "journal" => 21,
+    "journalism" => 1,
+    "journals" => 5,
+    "journey" => 5,
+    "journeyed" => 20,
+    "journeying" => 21,
+    "journeys" => 5,
+    "jove" => 2,
+    "jovial" => 16,
+    "jovially" => 32,
+    "jowl" => 5,
+    "joy" => 7,
+    "joyful" => 16,
+    "joyfully" => 32,
+    "joyous" => 16,
+    "joyously" => 32,
+    "joys" => 5,
+    "joystick" => 5,
+    "jubilant" => 16,
+    "judge" => 5,
+    "judged" => 20,
+    "judgement" => 1,
+    "judges" => 7,
+    "judging" => 21,
+    "judgment" => 1,
+    "judgments" => 1,
+    "judicial" => 17,
+    "judicious" => 16,
+    "judith" => 2,
+    "judy" => 3,
+    "jug" => 5,
+    "jugged" => 20,
+    "juggernaut" => 3,
+    "juggle" => 5,
+    "juggled" => 20,
+    "juggler" => 1,
+    "jugglers" => 5,
+    "juggling" => 21,
+    "juice" => 21,
+    "jukebox" => 5,
+    "julep" => 1,
+    "julia" => 2,
+    "julian" => 18,
+    "julius" => 2,
+    "july" => 2,
+    "jumbo" => 17,
+    "jump" => 21,
+    "jumped" => 20,
+    "jumper" => 21,
+    "jumping" => 21,
+    "jumps" => 4,
+    "junction" => 5,
+    "junctions" => 5,
+    "juncture" => 1,
+    "june" => 2,
+    "jung" => 2,
+    "jungle" => 17,
+    "jungles" => 1,
+    "junior" => 23,
+    "juniper" => 1,
+    "junk" => 5,
+    "junks" => 5,
+    "jupiter" => 3,
+    "jurisdiction" => 1,
+    "jurisdictions" => 1,
+    "juror" => 1,
+    "jurors" => 1,
+    "jury" => 21,
+    "juryman" => 1,
+    "jurymen" => 1,
+    "just" => 20,
+    "justice" => 3,
+    "justifiable" => 16,
+    "justifiably" => 32,
+    "justification" => 5,
+    "justifications" => 5,
+    "justified" => 20,
+    "justifies" => 4,
+    "justify" => 4,
+    "justifying" => 21,
+    "justin" => 2,
+    "justine" => 2,
+    "justly" => 32,
+    "jut" => 5,
+    "jutted" => 4,
+    "jutting" => 5,
+    "juvenile" => 17,
+    "juveniles" => 1,
+    "juxtaposition" => 5,
+    "kafka" => 2,
+    "kaiser" => 3,
+    "kansas" => 2,
+    "kappa" => 1,
+    "karen" => 3,
+    "karl" => 2,
+    "kate" => 2,
+    "kay" => 3,
+    "kayo" => 5,
+    "kebab" => 5,
+    "keel" => 5,
+    "keen" => 21,
+    "keener" => 17,
+    "keenest" => 16,
+    "keenly" => 32,
+    "keep" => 5,
+    "keeper" => 1,
+    "keeping" => 21,
+    "keeps" => 5,
+    "keg" => 5,
+    "keith" => 2,
+    "kelly" => 2,
+    "kelvin" => 3,
+    "ken" => 7,
+    "kennel" => 5,
+    "kenneth" => 2,
+    "kensington" => 2,
+    "kent" => 2,
+    "kept" => 4,
+    "kermit" => 2,
+    "kernel" => 5,
+    "kernels" => 5,
+    "kerr" => 2,
+    "ketch" => 5,
+    "kettle" => 5,
+    "kevin" => 2,
+    "key" => 23,
+    "keyboard" => 5,
+    "keyboards" => 5,
+    "keyed" => 20,
+    "keyhole" => 5,
+    "keying" => 21,
+    "keynote" => 5,
+    "keypad" => 1,
+    "keypress" => 1,
+    "keypresses" => 1,
+    "keys" => 5,
+    "keystroke" => 5,
+    "keystrokes" => 5,
+    "keyword" => 5,
+    "keywords" => 5,
+    "khaki" => 17,
+    "khmer" => 19,
+    "kick" => 5,
+    "kicked" => 20,
+    "kicking" => 21,
+    "kicks" => 5,
+    "kid" => 5,
+    "kidding" => 5,
+    "kidnapped" => 20,
+    "kids" => 5,
+    "kikuyu" => 3,
+    "kill" => 5,
+    "killed" => 20,
+    "killer" => 17,
+    "killers" => 5,
+    "killing" => 21,
+    "kills" => 5,
+    "kilo" => 1,
+    "kilobits" => 1,
+    "kilobyte" => 1,
+    "kilobytes" => 1,
+    "kilometer" => 1,
+    "kim" => 2,
+    "kin" => 21,
+    "kind" => 17,
+    "kinder" => 16,
+    "kindergarten" => 1,
+    "kindest" => 16,
+    "kindle" => 21,
+    "kindled" => 20,
+    "kindliness" => 1,
+    "kindling" => 21,
+    "kindly" => 48,
+    "kindness" => 17,
+    "kinds" => 1,
+    "king" => 23,
+    "king's" => 1,
+    "kingdom" => 1,
+    "kingdoms" => 1,
+    "kings" => 5,
+    "kingston" => 2,
+    "kinsman" => 1,
+    "kinswoman" => 1,
+    "kirghiz" => 19,
+    "kiss" => 5,
+    "kissed" => 20,
+    "kisses" => 5,
+    "kissing" => 21,
+    "kit" => 7,
+    "kitchen" => 5,
+    "kitchens" => 5,
+    "kite" => 5,
+    "kites" => 5,
+    "kitten" => 5,
+    "kittens" => 5,
+    "kitty" => 3,
+    "kiwi" => 1,
+    "klan" => 2,
+    "klaus" => 2,
+    "knack" => 5,
+    "knackered" => 20,
+    "knave" => 1,
+    "knee" => 5,
+    "kneel" => 4,
+    "kneeling" => 21,
+    "knees" => 5,
+    "knell" => 5,
+    "knelt" => 4,
+    "knew" => 4,
+    "knickerbockers" => 1,
+    "knife" => 5,
+    "knight" => 7,
+    "knightly" => 32,
+    "knights" => 5,
+    "knit" => 5,
+    "knitted" => 20,
+    "knitting" => 5,
+    "knives" => 5,
+    "knob" => 5,
+    "knobs" => 5,
+    "knock" => 5,
+    "knocked" => 20,
+    "knocking" => 21,
+    "knot" => 5,
+    "knots" => 5,
+    "knotted" => 20,
+    "know" => 5,
+    "knowing" => 85,
+    "knowingly" => 32,
+    "knowledge" => 1,
+    "knowledgeable" => 17,
+    "known" => 20,
+    "knows" => 5,
+    "knox" => 3,
+    "knuckle" => 5,
+    "knuckles" => 5,
+    "knurling" => 21,
+    "kong" => 2,
+    "korea" => 2,
+    "korean" => 19,
+    "kosher" => 20,
+    "kraft" => 2,
+    "krishna" => 2,
+    "kurdish" => 17,
+    "kurt" => 2,
+    "kyle" => 2,
+    "kyoto" => 2,
+    "lab" => 3,
+    "label" => 5,
+    "labeled" => 20,
+    "labeling" => 21,
+    "labelled" => 20,
+    "labelling" => 5,
+    "labels" => 5,
+    "labor" => 7,
+    "laboratory" => 1,
+    "labored" => 20,
+    "laborious" => 16,
+    "laboriously" => 32,
+    "labors" => 5,
+    "labour" => 7,
+    "labourers" => 5,
+    "labours" => 5,
+    "labs" => 1,
+    "labyrinth" => 5,
+    "lac" => 1,
+    "lace" => 5,
+    "laced" => 20,
+    "lacerate" => 20,
+    "lacey" => 2,
+    "lacing" => 21,
+    "lack" => 5,
+    "lacked" => 20,
+    "lacking" => 21,
+    "lacks" => 5,
+    "lacy" => 18,
+    "lad" => 1,
+    "ladder" => 5,
+    "ladders" => 5,
+    "laden" => 21,
+    "ladies" => 5,
+    "lads" => 1,
+    "lady" => 7,
+    "lady's" => 1,
+    "lag" => 21,
+    "laggards" => 1,
+    "lagged" => 4,
+    "lagging" => 21,
+    "lags" => 5,
+    "laid" => 20,
+    "lain" => 4,
+    "lake" => 5,
+    "lakes" => 5,
+    "lakeside" => 17,
+    "lam" => 5,
+    "lamb" => 7,
+    "lambda" => 1,
+    "lambert" => 2,
+    "lame" => 21,
+    "lamed" => 20,
+    "lameness" => 21,
+    "lament" => 5,
+    "lamentations" => 3,
+    "lamented" => 20,
+    "lamp" => 5,
+    "lamps" => 5,
+    "lancaster" => 2,
+    "lances" => 5,
+    "land" => 7,
+    "landau" => 1,
+    "landed" => 20,
+    "landing" => 21,
+    "landlady" => 1,
+    "landlord" => 1,
+    "landmark" => 5,
+    "landmarks" => 5,
+    "landowner" => 1,
+    "lands" => 5,
+    "landscape" => 5,
+    "landscapes" => 5,
+    "landslide" => 5,
+    "landward" => 17,
+    "lane" => 3,
+    "lanes" => 1,
+    "lang" => 2,
+    "langley" => 2,
+    "language" => 5,
+    "languages" => 5,
+    "languid" => 17,
+    "languidly" => 32,
+    "languishing" => 21,
+    "languor" => 5,
+    "lank" => 20,
+    "lanka" => 2,
+    "lantern" => 21,
+    "lanterns" => 5,
+    "lao" => 19,
+    "lap" => 21,
+    "lapel" => 1,
+    "lapels" => 1,
+    "lapped" => 20,
+    "lappet" => 5,
+    "lappets" => 5,
+    "laps" => 5,
+    "lapse" => 5,
+    "lapsed" => 20,
+    "laptop" => 17,
+    "laptops" => 1,
+    "laramie" => 2,
+    "large" => 17,
+    "largely" => 32,
+    "largeness" => 17,
+    "larger" => 16,
+    "largest" => 16,
+    "largo" => 17,
+    "lark" => 5,
+    "larry" => 2,
+    "las" => 1,
+    "laser" => 5,
+    "lash" => 21,
+    "lashed" => 20,
+    "lashes" => 5,
+    "lashing" => 21,
+    "lashings" => 21,
+    "lass" => 1,
+    "lassitude" => 1,
+    "lasso" => 5,
+    "last" => 21,
+    "lasted" => 20,
+    "lasting" => 21,
+    "lastly" => 32,
+    "lasts" => 5,
+    "latch" => 5,
+    "latching" => 21,
+    "late" => 17,
+    "lately" => 32,
+    "latency" => 1,
+    "lateness" => 17,
+    "latent" => 17,
+    "later" => 16,
+    "lateral" => 21,
+    "laterally" => 32,
+    "latest" => 17,
+    "latex" => 1,
+    "lath" => 5,
+    "lather" => 5,
+    "latin" => 19,
+    "latitude" => 1,
+    "latitudes" => 1,
+    "latrine" => 1,
+    "latter" => 17,
+    "latterly" => 32,
+    "lattice" => 5,
+    "latvian" => 17,
+    "laudable" => 5,
+    "laudanum" => 5,
+    "lauded" => 20,
+    "laugh" => 5,
+    "laughed" => 20,
+    "laughing" => 21,
+    "laughs" => 5,
+    "laughter" => 1,
+    "launch" => 5,
+    "launched" => 20,
+    "launches" => 5,
+    "launching" => 21,
+    "laundered" => 20,
+    "laundry" => 1,
+    "laurel" => 7,
+    "lav" => 1,
+    "lavender" => 21,
+    "lavish" => 21,
+    "lavished" => 20,
+    "lavishly" => 32,
+    "law" => 5,
+    "lawful" => 17,
+    "lawgivers" => 1,
+    "lawless" => 16,
+    "lawn" => 5,
+    "lawns" => 5,
+    "lawrence" => 2,
+    "laws" => 5,
+    "lawsuit" => 1,
+    "lawyer" => 5,
+    "lawyers" => 5,
+    "lax" => 17,
+    "laxness" => 17,
+    "lay" => 21,
+    "layer" => 5,
+    "layered" => 20,
+    "layering" => 5,
+    "layers" => 5,
+    "laying" => 21,
+    "layout" => 1,
+    "layouts" => 1,
+    "lays" => 5,
+    "lazier" => 17,
+    "lazily" => 32,
+    "laziness" => 21,
+    "lazy" => 21,
+    "lcd" => 1,
+    "lea" => 3,
+    "leach" => 7,
+    "lead" => 21,
+    "leaden" => 21,
+    "leader" => 17,
+    "leaders" => 21,
+    "leadership" => 1,
+    "leading" => 21,
+    "leads" => 5,
+    "leaf" => 5,
+    "leafs" => 5,
+    "league" => 5,
+    "leagues" => 5,
+    "leah" => 2,
+    "leak" => 21,
+    "leakage" => 1,
+    "leaked" => 20,
+    "leaking" => 21,
+    "leaks" => 5,
+    "leaky" => 16,
+    "lean" => 23,
+    "leaned" => 20,
+    "leaner" => 17,
+    "leaning" => 21,
+    "leans" => 5,
+    "leap" => 21,
+    "leaped" => 20,
+    "leaping" => 21,
+    "leaps" => 5,
+    "lear" => 2,
+    "learn" => 5,
+    "learned" => 20,
+    "learner" => 1,
+    "learners" => 1,
+    "learning" => 21,
+    "learns" => 5,
+    "learnt" => 4,
+    "lease" => 5,
+    "leased" => 20,
+    "leash" => 5,
+    "leasing" => 21,
+    "least" => 17,
+    "leather" => 21,
+    "leave" => 5,
+    "leaved" => 20,
+    "leaves" => 5,
+    "leaving" => 21,
+    "lebanon" => 2,
+    "lech" => 5,
+    "lecture" => 5,
+    "lectures" => 5,
+    "lecturing" => 21,
+    "led" => 21,
+    "ledge" => 5,
+    "ledger" => 5,
+    "ledgers" => 5,
+    "lee" => 19,
+    "leeches" => 5,
+    "leer" => 21,
+    "leeway" => 1,
+    "left" => 23,
+    "leftmost" => 16,
+    "leftover" => 17,
+    "leftovers" => 1,
+    "leftwards" => 16,
+    "leg" => 21,
+    "legacy" => 17,
+    "legal" => 17,
+    "legality" => 1,
+    "legalization" => 1,
+    "legally" => 32,
+    "legend" => 5,
+    "legendary" => 17,
+    "legends" => 5,
+    "legged" => 21,
+    "leggings" => 5,
+    "legibility" => 1,
+    "legible" => 16,
+    "legion" => 21,
+    "legislation" => 5,
+    "legislative" => 21,
+    "legislators" => 1,
+    "legislature" => 1,
+    "legislatures" => 1,
+    "legitimate" => 21,
+    "legitimately" => 32,
+    "legs" => 5,
+    "lei" => 1,
+    "leisure" => 1,
+    "leisurely" => 32,
+    "lemma" => 1,
+    "lemon" => 21,
+    "lemonade" => 1,
+    "lemons" => 5,
+    "len" => 19,
+    "lend" => 5,
+    "lender" => 1,
+    "lending" => 21,
+    "lends" => 5,
+    "length" => 5,
+    "lengthen" => 5,
+    "lengthened" => 20,
+    "lengthens" => 5,
+    "lengths" => 5,
+    "lengthy" => 16,
+    "leniency" => 1,
+    "lenient" => 17,
+    "lenin" => 2,
+    "lens" => 5,
+    "lenses" => 5,
+    "lent" => 7,
+    "leo" => 3,
+    "leon" => 2,
+    "leonard" => 2,
+    "leonardo" => 3,
+    "leopard" => 1,
+    "leotard" => 1,
+    "leroy" => 2,
+    "less" => 596,
+    "lessen" => 596,
+    "lessened" => 20,
+    "lessening" => 21,
+    "lesser" => 17,
+    "lesson" => 5,
+    "lessons" => 5,
+    "lest" => 1,
+    "lester" => 2,
+    "let" => 5,
+    "lethargic" => 16,
+    "lethargy" => 1,
+    "lets" => 5,
+    "letter" => 5,
+    "letterpress" => 1,
+    "letters" => 5,
+    "letting" => 5,
+    "level" => 21,
+    "levelled" => 4,
+    "levels" => 5,
+    "lever" => 5,
+    "leverage" => 5,
+    "leveraged" => 20,
+    "leverages" => 5,
+    "leveraging" => 21,
+    "levers" => 5,
+    "levity" => 1,
+    "levy" => 7,
+    "levying" => 21,
+    "lewis" => 2,
+    "lex" => 5,
+    "lexical" => 16,
+    "lexicographic" => 16,
+    "lexicographical" => 16,
+    "lexicon" => 5,
+    "lexington" => 2,
+    "liability" => 1,
+    "liable" => 16,
+    "liaisons" => 5,
+    "liar" => 1,
+    "lib" => 5,
+    "libel" => 5,
+    "liberal" => 19,
+    "liberally" => 32,
+    "liberated" => 20,
+    "liberties" => 1,
+    "libertine" => 17,
+    "liberty" => 1,
+    "libra" => 3,
+    "librarian" => 1,
+    "librarians" => 1,
+    "libraries" => 1,
+    "library" => 1,
+    "lice" => 1,
+    "licence" => 5,
+    "licences" => 5,
+    "license" => 5,
+    "licensed" => 20,
+    "licensee" => 1,
+    "licenses" => 5,
+    "licensing" => 21,
+    "lichen" => 5,
+    "lick" => 5,
+    "licked" => 20,
+    "licking" => 21,
+    "licks" => 5,
+    "lid" => 5,
+    "lidded" => 20,
+    "lidless" => 16,
+    "lids" => 5,
+    "lie" => 7,
+    "lied" => 21,
+    "lies" => 5,
+    "lieu" => 1,
+    "lieutenant" => 17,
+    "lieutenants" => 1,
+    "life" => 5,
+    "lifeless" => 16,
+    "lifelessness" => 17,
+    "lifelines" => 1,
+    "lifelong" => 16,
+    "lifespan" => 1,
+    "lifetime" => 1,
+    "lifetimes" => 1,
+    "lift" => 5,
+    "lifted" => 20,
+    "lifting" => 21,
+    "lifts" => 5,
+    "ligaments" => 1,
+    "ligature" => 5,
+    "ligatures" => 5,
+    "light" => 21,
+    "lighted" => 20,
+    "lighten" => 4,
+    "lightened" => 20,
+    "lightening" => 21,
+    "lighter" => 21,
+    "lightest" => 16,
+    "lighthearted" => 16,
+    "lighthouse" => 1,
+    "lighting" => 21,
+    "lightly" => 32,
+    "lightness" => 1,
+    "lightning" => 21,
+    "lights" => 5,
+    "lightweight" => 21,
+    "like" => 597,
+    "liked" => 20,
+    "likelihood" => 1,
+    "likely" => 17,
+    "likened" => 20,
+    "likes" => 5,
+    "liking" => 21,
+    "lilac" => 17,
+    "lilian" => 2,
+    "lilly" => 3,
+    "lilt" => 5,
+    "lily" => 51,
+    "lima" => 3,
+    "limb" => 5,
+    "limbo" => 5,
+    "limbs" => 5,
+    "limburger" => 1,
+    "lime" => 21,
+    "limerick" => 1,
+    "limestone" => 1,
+    "limit" => 21,
+    "limitation" => 1,
+    "limitations" => 1,
+    "limited" => 21,
+    "limiting" => 21,
+    "limitless" => 16,
+    "limits" => 5,
+    "limousine" => 1,
+    "limp" => 21,
+    "limped" => 20,
+    "limpid" => 16,
+    "limping" => 21,
+    "limps" => 5,
+    "lincoln" => 3,
+    "linda" => 2,
+    "line" => 5,
+    "lineage" => 1,
+    "lineaments" => 1,
+    "linear" => 17,
+    "linearity" => 1,
+    "linearly" => 32,
+    "lined" => 20,
+    "linefeed" => 1,
+    "linen" => 17,
+    "liner" => 5,
+    "liners" => 5,
+    "lines" => 5,
+    "ling" => 21,
+    "linger" => 4,
+    "lingered" => 20,
+    "lingering" => 21,
+    "lingeringly" => 32,
+    "lingers" => 4,
+    "lingo" => 1,
+    "lingual" => 17,
+    "linguist" => 1,
+    "linguistic" => 16,
+    "linguistics" => 17,
+    "linguists" => 1,
+    "lining" => 21,
+    "link" => 5,
+    "linkage" => 1,
+    "linkages" => 1,
+    "linked" => 20,
+    "linker" => 1,
+    "linkers" => 1,
+    "linking" => 21,
+    "links" => 5,
+    "linoleum" => 1,
+    "lint" => 5,
+    "linter" => 1,
+    "lion" => 17,
+    "lioness" => 1,
+    "lip" => 5,
+    "lipped" => 20,
+    "lips" => 5,
+    "liquid" => 17,
+    "liquidator" => 1,
+    "liquidity" => 1,
+    "liquids" => 1,
+    "liquor" => 5,
+    "liquors" => 5,
+    "lira" => 1,
+    "lisp" => 5,
+    "lisped" => 20,
+    "lisps" => 5,
+    "list" => 5,
+    "listed" => 20,
+    "listen" => 5,
+    "listened" => 20,
+    "listener" => 1,
+    "listeners" => 5,
+    "listening" => 21,
+    "listens" => 5,
+    "listing" => 21,
+    "listings" => 5,
+    "listless" => 16,
+    "listlessly" => 32,
+    "lists" => 5,
+    "lit" => 21,
+    "lite" => 21,
+    "literacy" => 1,
+    "literal" => 17,
+    "literally" => 32,
+    "literals" => 1,
+    "literary" => 16,
+    "literate" => 17,
+    "literature" => 1,
+    "lithe" => 21,
+    "lithuanian" => 17,
+    "litmus" => 1,
+    "litter" => 21,
+    "litters" => 5,
+    "little" => 403,
+    "live" => 20,
+    "lived" => 20,
+    "livelihood" => 1,
+    "liveliness" => 17,
+    "lively" => 17,
+    "liver" => 17,
+    "liverpool" => 3,
+    "livery" => 21,
+    "lives" => 4,
+    "livid" => 16,
+    "living" => 21,
+    "liz" => 2,
+    "lizard" => 1,
+    "llama" => 1,
+    "lloyd" => 2,
+    "lloyd's" => 1,
+    "load" => 5,
+    "loaded" => 20,
+    "loader" => 1,
+    "loaders" => 1,
+    "loading" => 21,
+    "loads" => 5,
+    "loaf" => 5,
+    "loafer" => 5,
+    "loafing" => 21,
+    "loafs" => 5,
+    "loan" => 5,
+    "loaned" => 20,
+    "loans" => 5,
+    "loathed" => 20,
+    "loathing" => 21,
+    "loathsome" => 16,
+    "loaves" => 1,
+    "lob" => 5,
+    "lobby" => 5,
+    "lobster" => 21,
+    "lobsters" => 5,
+    "local" => 17,
+    "locale" => 1,
+    "locales" => 1,
+    "localisation" => 1,
+    "localities" => 1,
+    "locality" => 1,
+    "localization" => 1,
+    "localize" => 4,
+    "localized" => 20,
+    "localizing" => 21,
+    "locally" => 32,
+    "locals" => 1,
+    "locate" => 4,
+    "located" => 20,
+    "locates" => 4,
+    "locating" => 21,
+    "location" => 5,
+    "locations" => 1,
+    "locator" => 1,
+    "locators" => 1,
+    "lock" => 5,
+    "lockable" => 5,
+    "locked" => 20,
+    "locker" => 1,
+    "locket" => 1,
+    "locking" => 21,
+    "locks" => 5,
+    "lockstep" => 1,
+    "lockup" => 1,
+    "loco" => 21,
+    "locomotion" => 1,
+    "locomotive" => 17,
+    "locomotives" => 1,
+    "locus" => 1,
+    "locust" => 5,
+    "locusts" => 5,
+    "lodge" => 7,
+    "lodged" => 20,
+    "lodger" => 1,
+    "lodging" => 21,
+    "lodgings" => 5,
+    "loft" => 21,
+    "loftily" => 32,
+    "lofty" => 16,
+    "log" => 5,
+    "logan" => 2,
+    "logarithm" => 1,
+    "logarithmic" => 16,
+    "logarithms" => 1,
+    "logged" => 4,
+    "logger" => 1,
+    "loggers" => 1,
+    "logging" => 5,
+    "logic" => 21,
+    "logical" => 16,
+    "logically" => 32,
+    "logician" => 1,
+    "login" => 5,
+    "logistic" => 17,
+    "logistics" => 1,
+    "logo" => 1,
+    "logos" => 1,
+    "logout" => 1,
+    "logs" => 5,
+    "loins" => 1,
+    "loitered" => 20,
+    "loitering" => 21,
+    "london" => 2,
+    "londoner" => 3,
+    "londoners" => 2,
+    "lone" => 16,
+    "lonelier" => 16,
+    "loneliest" => 16,
+    "loneliness" => 17,
+    "lonely" => 48,
+    "lonesome" => 17,
+    "long" => 23,
+    "longed" => 20,
+    "longer" => 17,
+    "longest" => 16,
+    "longevity" => 1,
+    "longhand" => 1,
+    "longing" => 21,
+    "longingly" => 32,
+    "longings" => 5,
+    "longitude" => 1,
+    "longitudes" => 1,
+    "longstanding" => 16,
+    "look" => 5,
+    "looked" => 20,
+    "lookers" => 5,
+    "looking" => 21,
+    "lookout" => 1,
+    "looks" => 5,
+    "lookup" => 5,
+    "loom" => 5,
+    "loomed" => 20,
+    "looming" => 21,
+    "loop" => 5,
+    "looped" => 20,
+    "loophole" => 5,
+    "looping" => 21,
+    "loops" => 5,
+    "loose" => 21,
+    "loosed" => 20,
+    "loosely" => 32,
+    "loosen" => 5,
+    "loosened" => 20,
+    "loosening" => 21,
+    "looser" => 1,
+    "loot" => 5,
+    "lop" => 5,
+    "lope" => 5,
+    "lopes" => 5,
+    "lopped" => 4,
+    "loquacious" => 16,
+    "lord" => 7,
+    "lord's" => 1,
+    "lords" => 5,
+    "lordship" => 1,
+    "lose" => 5,
+    "loser" => 1,
+    "loses" => 5,
+    "losing" => 21,
+    "loss" => 5,
+    "losses" => 5,
+    "lost" => 20,
+    "lot" => 7,
+    "lots" => 5,
+    "lotus" => 1,
+    "lou" => 2,
+    "loud" => 17,
+    "louder" => 16,
+    "loudly" => 32,
+    "loudness" => 17,
+    "louis" => 3,
+    "louisa" => 2,
+    "louisiana" => 2,
+    "louisville" => 2,
+    "lounged" => 20,
+    "loungers" => 5,
+    "lounging" => 21,
+    "lousy" => 16,
+    "love" => 7,
+    "loved" => 20,
+    "lovelace" => 3,
+    "loveliest" => 16,
+    "loveliness" => 17,
+    "lovely" => 17,
+    "lover" => 1,
+    "lovers" => 5,
+    "loves" => 5,
+    "loving" => 21,
+    "lovingly" => 32,
+    "low" => 21,
+    "lowed" => 20,
+    "lower" => 21,
+    "lowercase" => 21,
+    "lowered" => 20,
+    "lowering" => 21,
+    "lowers" => 21,
+    "lowest" => 16,
+    "lowing" => 21,
+    "lowliest" => 16,
+    "lozenge" => 5,
+    "ltd" => 17,
+    "luau" => 1,
+    "lucas" => 6,
+    "lucid" => 17,
+    "lucifer" => 2,
+    "lucille" => 2,
+    "luck" => 5,
+    "luckily" => 32,
+    "luckless" => 16,
+    "lucky" => 17,
+    "lucrative" => 16,
+    "lucy" => 2,
+    "ludicrous" => 16,
+    "luff" => 5,
+    "lug" => 5,
+    "luggage" => 1,
+    "lugged" => 20,
+    "lugubrious" => 16,
+    "luis" => 2,
+    "luke" => 2,
+    "lukewarm" => 16,
+    "lull" => 5,
+    "lullaby" => 5,
+    "lulled" => 20,
+    "lulling" => 21,
+    "lumber" => 5,
+    "luminosity" => 1,
+    "luminous" => 16,
+    "lump" => 5,
+    "lumps" => 5,
+    "lunar" => 17,
+    "lunatic" => 17,
+    "lunch" => 5,
+    "lunched" => 20,
+    "luncheon" => 5,
+    "lunching" => 21,
+    "lurch" => 5,
+    "lurched" => 20,
+    "lurches" => 5,
+    "lure" => 5,
+    "lured" => 20,
+    "lurid" => 16,
+    "lurked" => 20,
+    "lurking" => 21,
+    "lust" => 5,
+    "lustily" => 32,
+    "lustre" => 5,
+    "lustreless" => 16,
+    "lustrous" => 16,
+    "lutheran" => 17,
+    "luxuriant" => 16,
+    "luxuriated" => 20,
+    "luxuries" => 1,
+    "luxurious" => 16,
+    "luxury" => 17,
+    "lying" => 21,
+    "lynch" => 7,
+    "lynn" => 2,
+    "lynne" => 2,
+    "lynx" => 1,
+    "lyon" => 2,
+    "lyons" => 2,
+    "lyre" => 5,
+    "lyric" => 17,
+    "lyrics" => 1,
+    "ma'am" => 5,
+    "mabel" => 2,
+    "mac" => 3,
+    "macadamised" => 20,
+    "macao" => 2,
+    "macdonald" => 2,
+    "macedonian" => 17,
+    "mach" => 3,
+    "machete" => 5,
+    "machinations" => 5,
+    "machine" => 5,
+    "machinery" => 1,
+    "machines" => 5,
+    "macho" => 17,
+    "macintosh" => 3,
+    "mackintosh" => 1,
+    "macmillan" => 3,
+    "macro" => 17,
+    "macron" => 1,
+    "macros" => 1,
+    "macs" => 1,
+    "mad" => 20,
+    "madam" => 5,
+    "madame" => 1,
+    "maddened" => 20,
+    "maddening" => 21,
+    "made" => 4,
+    "madison" => 3,
+    "madly" => 32,
+    "madman" => 1,
+    "madness" => 21,
+    "madras" => 3,
+    "maestro" => 1,
+    "magazine" => 1,
+    "magazines" => 1,
+    "magenta" => 17,
+    "maggie" => 2,
+    "magic" => 21,
+    "magical" => 16,
+    "magically" => 32,
+    "magician" => 1,
+    "magistrate" => 1,
+    "magistrates" => 1,
+    "magnanimous" => 16,
+    "magnesium" => 1,
+    "magnet" => 1,
+    "magnetic" => 16,
+    "magnetized" => 20,
+    "magnetometer" => 1,
+    "magnificence" => 1,
+    "magnificent" => 16,
+    "magnificently" => 32,
+    "magnified" => 20,
+    "magnifying" => 21,
+    "magnitude" => 1,
+    "magnitudes" => 1,
+    "magpie" => 5,
+    "mahogany" => 17,
+    "maid" => 1,
+    "maiden" => 17,
+    "maids" => 1,
+    "mail" => 5,
+    "mailbox" => 1,
+    "mailed" => 20,
+    "mailer" => 3,
+    "mailing" => 21,
+    "mailman" => 1,
+    "mails" => 5,
+    "main" => 21,
+    "maine" => 2,
+    "mainframe" => 1,
+    "mainland" => 1,
+    "mainline" => 21,
+    "mainly" => 32,
+    "mains" => 5,
+    "mainstream" => 21,
+    "maintain" => 4,
+    "maintainability" => 1,
+    "maintainable" => 20,
+    "maintained" => 20,
+    "maintainer" => 1,
+    "maintainers" => 4,
+    "maintaining" => 21,
+    "maintains" => 4,
+    "maintenance" => 1,
+    "majestic" => 16,
+    "majesty" => 257,
+    "major" => 23,
+    "majority" => 1,
+    "majors" => 5,
+    "make" => 5,
+    "maker" => 3,
+    "makers" => 1,
+    "makes" => 5,
+    "makeup" => 5,
+    "making" => 21,
+    "makings" => 5,
+    "malachite" => 17,
+    "malagasy" => 17,
+    "malay" => 19,
+    "malays" => 1,
+    "malaysia" => 2,
+    "malcolm" => 2,
+    "male" => 19,
+    "maledictions" => 1,
+    "males" => 1,
+    "malevolence" => 1,
+    "malformed" => 16,
+    "malfunctions" => 5,
+    "malice" => 5,
+    "malicious" => 16,
+    "malignant" => 17,
+    "malignity" => 1,
+    "mall" => 5,
+    "malleability" => 1,
+    "malleable" => 16,
+    "mallets" => 5,
+    "malta" => 2,
+    "maltese" => 19,
+    "mamma" => 1,
+    "man" => 23,
+    "manacled" => 20,
+    "manage" => 5,
+    "manageable" => 16,
+    "managed" => 20,
+    "management" => 5,
+    "manager" => 1,
+    "manageress" => 1,
+    "managers" => 5,
+    "manages" => 5,
+    "managing" => 21,
+    "manchester" => 2,
+    "mandarin" => 17,
+    "mandate" => 5,
+    "mandated" => 20,
+    "mandates" => 5,
+    "mandating" => 21,
+    "mandatory" => 17,
+    "manes" => 1,
+    "manfred" => 2,
+    "manfully" => 32,
+    "mangle" => 5,
+    "mangled" => 20,
+    "mangler" => 1,
+    "mangles" => 5,
+    "mangling" => 21,
+    "mangoes" => 5,
+    "mania" => 1,
+    "manifest" => 21,
+    "manifestation" => 1,
+    "manifestations" => 1,
+    "manifested" => 20,
+    "manifesting" => 21,
+    "manifestly" => 32,
+    "manifesto" => 5,
+    "manifests" => 5,
+    "manifold" => 21,
+    "manipulate" => 4,
+    "manipulated" => 20,
+    "manipulates" => 4,
+    "manipulating" => 21,
+    "manipulation" => 5,
+    "manipulations" => 5,
+    "mankind" => 1,
+    "manly" => 48,
+    "mann" => 3,
+    "manned" => 20,
+    "manner" => 1,
+    "mannered" => 16,
+    "manners" => 1,
+    "mannheim" => 2,
+    "manning" => 23,
+    "manoeuvre" => 5,
+    "manoeuvres" => 5,
+    "manor" => 1,
+    "manpower" => 1,
+    "mansfield" => 2,
+    "mansion" => 5,
+    "mansions" => 5,
+    "mantel" => 5,
+    "mantelpiece" => 1,
+    "mantissa" => 1,
+    "mantissas" => 1,
+    "mantle" => 7,
+    "manual" => 17,
+    "manually" => 32,
+    "manuals" => 1,
+    "manufacture" => 5,
+    "manufactured" => 20,
+    "manufacturer" => 1,
+    "manufacturers" => 5,
+    "manufacturing" => 21,
+    "manuscript" => 17,
+    "manuscripts" => 1,
+    "manx" => 19,
+    "many" => 400,
+    "mao" => 2,
+    "maori" => 19,
+    "map" => 5,
+    "maple" => 1,
+    "mapped" => 4,
+    "mapping" => 5,
+    "mappings" => 5,
+    "maps" => 5,
+    "mar" => 7,
+    "marble" => 21,
+    "marbles" => 5,
+    "marcel" => 2,
+    "march" => 7,
+    "marched" => 20,
+    "marching" => 21,
+    "marco" => 2,
+    "marcus" => 2,
+    "margaret" => 2,
+    "margin" => 5,
+    "marginal" => 17,
+    "marginally" => 32,
+    "margins" => 5,
+    "maria" => 3,
+    "marian" => 19,
+    "marie" => 2,
+    "mariner" => 17,
+    "mariners" => 21,
+    "marines" => 5,
+    "maritime" => 16,
+    "mark" => 7,
+    "markdown" => 3,
+    "marked" => 20,
+    "marker" => 5,
+    "markers" => 5,
+    "market" => 5,
+    "marketing" => 21,
+    "marketplace" => 1,
+    "markets" => 5,
+    "marking" => 21,
+    "marks" => 7,
+    "markup" => 1,
+    "marlin" => 3,
+    "marmalade" => 5,
+    "maroon" => 21,
+    "marque" => 1,
+    "marred" => 20,
+    "marriage" => 1,
+    "married" => 21,
+    "marring" => 5,
+    "marriott" => 2,
+    "marry" => 4,
+    "marrying" => 21,
+    "mars" => 7,
+    "marseilles" => 3,
+    "marsh" => 3,
+    "marshal" => 5,
+    "marshaling" => 21,
+    "marshall" => 2,
+    "marshes" => 1,
+    "marshmallow" => 5,
+    "marshy" => 16,
+    "martin" => 3,
+    "martyr" => 5,
+    "martyrdom" => 1,
+    "martyrs" => 5,
+    "marvel" => 5,
+    "marvelled" => 4,
+    "marvelling" => 5,
+    "marvellous" => 16,
+    "marvellously" => 32,
+    "marvelous" => 16,
+    "marvels" => 5,
+    "marvin" => 2,
+    "mary" => 2,
+    "maryland" => 2,
+    "mascot" => 5,
+    "masculine" => 17,
+    "mashed" => 20,
+    "mashing" => 21,
+    "mask" => 5,
+    "masked" => 20,
+    "masking" => 21,
+    "masks" => 5,
+    "mason" => 7,
+    "masonry" => 1,
+    "masquerades" => 5,
+    "mass" => 23,
+    "massachusetts" => 2,
+    "massacre" => 5,
+    "massacred" => 20,
+    "massacring" => 21,
+    "massage" => 5,
+    "massaged" => 20,
+    "massaging" => 21,
+    "massed" => 20,
+    "masses" => 5,
+    "massing" => 21,
+    "massive" => 21,
+    "massively" => 32,
+    "mast" => 5,
+    "master" => 23,
+    "mastered" => 20,
+    "mastering" => 21,
+    "masterly" => 48,
+    "masterpieces" => 1,
+    "masters" => 7,
+    "mastery" => 1,
+    "mastiff" => 1,
+    "mastodon" => 1,
+    "masts" => 5,
+    "mat" => 21,
+    "match" => 5,
+    "matched" => 5,
+    "matches" => 5,
+    "matching" => 21,
+    "matchless" => 16,
+    "mate" => 5,
+    "material" => 21,
+    "materialised" => 20,
+    "materialization" => 1,
+    "materialize" => 4,
+    "materialized" => 20,
+    "materializes" => 4,
+    "materializing" => 21,
+    "materially" => 32,
+    "materials" => 5,
+    "maternal" => 17,
+    "maternity" => 1,
+    "mates" => 5,
+    "math" => 1,
+    "mathematical" => 16,
+    "mathematically" => 32,
+    "mathematician" => 1,
+    "mathematicians" => 1,
+    "mathematics" => 1,
+    "mathias" => 2,
+    "maths" => 5,
+    "matrices" => 1,
+    "matrix" => 1,
+    "matron" => 1,
+    "matrons" => 1,
+    "mats" => 5,
+    "matt" => 23,
+    "matte" => 17,
+    "matter" => 5,
+    "mattered" => 20,
+    "matters" => 21,
+    "matthew" => 2,
+    "mattress" => 5,
+    "mature" => 20,
+    "matured" => 20,
+    "matures" => 4,
+    "maturity" => 1,
+    "maurice" => 2,
+    "mauritius" => 2,
+    "maven" => 1,
+    "mavericks" => 5,
+    "maw" => 1,
+    "max" => 23,
+    "maxi" => 17,
+    "maxim" => 1,
+    "maxima" => 1,
+    "maximal" => 17,
+    "maximally" => 32,
+    "maximilian" => 2,
+    "maximise" => 4,
+    "maximised" => 20,
+    "maximises" => 4,
+    "maximising" => 21,
+    "maximize" => 4,
+    "maximized" => 20,
+    "maximizes" => 4,
+    "maximizing" => 21,
+    "maximum" => 17,
+    "maximums" => 1,
+    "may" => 11,
+    "maybe" => 49,
+    "mayn't" => 4,
+    "mayor" => 1,
+    "mays" => 3,
+    "maze" => 5,
+    "mazes" => 5,
+    "mccarthy" => 2,
+    "mccarty" => 2,
+    "mccoy" => 3,
+    "mcdonald" => 2,
+    "mcgowan" => 2,
+    "mckee" => 2,
+    "mckinney" => 2,
+    "mcpherson" => 2,
+    "me" => 259,
+    "mead" => 3,
+    "meadow" => 5,
+    "meadows" => 7,
+    "meager" => 20,
+    "meagre" => 21,
+    "meal" => 5,
+    "meals" => 5,
+    "mean" => 21,
+    "meandering" => 21,
+    "meanest" => 16,
+    "meaning" => 21,
+    "meaningful" => 16,
+    "meaningfully" => 32,
+    "meaningless" => 16,
+    "meanings" => 21,
+    "meanly" => 32,
+    "means" => 5,
+    "meant" => 4,
+    "meantime" => 1,
+    "meanwhile" => 1,
+    "measles" => 5,
+    "measurable" => 17,
+    "measurably" => 32,
+    "measure" => 5,
+    "measured" => 20,
+    "measureless" => 16,
+    "measurement" => 1,
+    "measurements" => 1,
+    "measures" => 5,
+    "measuring" => 21,
+    "meat" => 1,
+    "mechanical" => 17,
+    "mechanically" => 32,
+    "mechanics" => 1,
+    "mechanism" => 1,
+    "mechanisms" => 1,
+    "medal" => 5,
+    "medals" => 5,
+    "meddle" => 4,
+    "meddler" => 1,
+    "media" => 19,
+    "medial" => 17,
+    "median" => 17,
+    "medians" => 1,
+    "mediated" => 20,
+    "mediation" => 21,
+    "medical" => 17,
+    "medicine" => 5,
+    "medicines" => 5,
+    "medieval" => 17,
+    "mediocre" => 17,
+    "meditate" => 4,
+    "meditating" => 21,
+    "meditation" => 5,
+    "meditative" => 20,
+    "meditatively" => 32,
+    "mediterranean" => 19,
+    "medium" => 17,
+    "mediums" => 1,
+    "meed" => 5,
+    "meekly" => 32,
+    "meet" => 21,
+    "meeting" => 21,
+    "meetings" => 21,
+    "meets" => 5,
+    "mega" => 17,
+    "megabits" => 1,
+    "megabyte" => 1,
+    "megabytes" => 1,
+    "megahertz" => 1,
+    "mel" => 2,
+    "melancholy" => 17,
+    "melbourne" => 2,
+    "meld" => 5,
+    "melissa" => 2,
+    "mellow" => 21,
+    "mellows" => 5,
+    "melodic" => 16,
+    "melodious" => 16,
+    "melody" => 3,
+    "melon" => 17,
+    "melt" => 5,
+    "melted" => 20,
+    "melting" => 21,
+    "member" => 5,
+    "members" => 5,
+    "membership" => 5,
+    "memberships" => 5,
+    "memo" => 5,
+    "memoir" => 1,
+    "memorable" => 17,
+    "memorandum" => 1,
+    "memorial" => 17,
+    "memories" => 1,
+    "memorize" => 4,
+    "memorized" => 20,
+    "memorizes" => 4,
+    "memorizing" => 21,
+    "memory" => 1,
+    "memos" => 5,
+    "memphis" => 2,
+    "men" => 1,
+    "menaced" => 20,
+    "menaces" => 5,
+    "menacing" => 21,
+    "menagerie" => 1,
+    "mend" => 5,
+    "mended" => 20,
+    "mendicant" => 17,
+    "mendicants" => 1,
+    "mending" => 21,
+    "menial" => 17,
+    "mental" => 17,
+    "mentally" => 32,
+    "mention" => 5,
+    "mentioned" => 20,
+    "mentioning" => 21,
+    "mentions" => 5,
+    "menu" => 5,
+    "menus" => 5,
+    "meow" => 5,
+    "meowing" => 21,
+    "meows" => 5,
+    "mercenary" => 17,
+    "merchandise" => 5,
+    "merchant" => 5,
+    "merchantman" => 1,
+    "merchants" => 5,
+    "mercia" => 2,
+    "merciful" => 16,
+    "mercifully" => 32,
+    "merciless" => 16,
+    "mercurial" => 17,
+    "mercury" => 3,
+    "mercy" => 5,
+    "mere" => 21,
+    "meredith" => 2,
+    "merely" => 32,
+    "merest" => 16,
+    "meretricious" => 16,
+    "merge" => 5,
+    "merged" => 20,
+    "merger" => 1,
+    "merges" => 5,
+    "merging" => 21,
+    "meridian" => 21,
+    "meridians" => 5,
+    "merit" => 5,
+    "merits" => 5,
+    "merlin" => 2,
+    "mermaid" => 1,
+    "merrily" => 32,
+    "merriment" => 1,
+    "merry" => 17,
+    "merton" => 2,
+    "mesa" => 3,
+    "mesh" => 5,
+    "meshes" => 5,
+    "meson" => 1,
+    "mess" => 5,
+    "message" => 5,
+    "messaged" => 20,
+    "messages" => 5,
+    "messaging" => 21,
+    "messed" => 20,
+    "messenger" => 5,
+    "messengers" => 5,
+    "messes" => 5,
+    "messing" => 21,
+    "messy" => 16,
+    "met" => 4,
+    "metadata" => 1,
+    "metal" => 21,
+    "metallic" => 17,
+    "metals" => 5,
+    "metamorphosis" => 1,
+    "metaphor" => 5,
+    "metaphorical" => 16,
+    "metaphorically" => 32,
+    "metaphysical" => 16,
+    "meteorologist" => 1,
+    "meteorology" => 1,
+    "meter" => 21,
+    "metered" => 20,
+    "metering" => 21,
+    "meters" => 21,
+    "meth" => 1,
+    "method" => 5,
+    "methodical" => 16,
+    "methodically" => 32,
+    "methodological" => 16,
+    "methodologies" => 1,
+    "methodology" => 1,
+    "methods" => 5,
+    "meticulous" => 16,
+    "meticulously" => 32,
+    "metric" => 21,
+    "metrics" => 5,
+    "metropolis" => 1,
+    "metropolitan" => 17,
+    "mew" => 5,
+    "mewing" => 21,
+    "mews" => 5,
+    "mexico" => 2,
+    "mica" => 1,
+    "micah" => 2,
+    "mice" => 5,
+    "michael" => 2,
+    "michele" => 2,
+    "michigan" => 3,
+    "mick" => 19,
+    "micro" => 21,
+    "microchip" => 5,
+    "microcomputer" => 1,
+    "microfiche" => 5,
+    "microfilm" => 5,
+    "microfilmed" => 20,
+    "microfilming" => 21,
+    "microphone" => 5,
+    "microprocessor" => 1,
+    "microprocessors" => 1,
+    "microscope" => 5,
+    "microsecond" => 1,
+    "microseconds" => 1,
+    "microsoft" => 7,
+    "microwave" => 5,
+    "mid" => 81,
+    "midas" => 3,
+    "midday" => 1,
+    "middle" => 21,
+    "middleman" => 5,
+    "middleware" => 1,
+    "middling" => 21,
+    "midi" => 19,
+    "midlands" => 19,
+    "midnight" => 17,
+    "midpoint" => 1,
+    "midst" => 65,
+    "midsummer" => 17,
+    "midway" => 19,
+    "mien" => 1,
+    "miffed" => 20,
+    "might" => 25,
+    "mightier" => 16,
+    "mightily" => 32,
+    "mightn't" => 8,
+    "mighty" => 17,
+    "migrate" => 4,
+    "migrated" => 20,
+    "migrating" => 21,
+    "migration" => 1,
+    "migrations" => 1,
+    "miguel" => 2,
+    "mikado" => 1,
+    "mike" => 7,
+    "milan" => 2,
+    "milanese" => 19,
+    "mild" => 17,
+    "mildly" => 32,
+    "mildness" => 17,
+    "mile" => 1,
+    "mileage" => 1,
+    "miles" => 3,
+    "milestone" => 5,
+    "milestones" => 5,
+    "military" => 17,
+    "militia" => 1,
+    "milk" => 5,
+    "milksop" => 1,
+    "milky" => 16,
+    "mill" => 7,
+    "millennium" => 1,
+    "miller" => 3,
+    "miller's" => 1,
+    "millimeters" => 1,
+    "millionaire" => 1,
+    "millionaires" => 1,
+    "millions" => 5,
+    "millionth" => 17,
+    "millisecond" => 1,
+    "milliseconds" => 1,
+    "mills" => 7,
+    "milo" => 2,
+    "milton" => 2,
+    "milwaukee" => 2,
+    "mime" => 5,
+    "mimic" => 21,
+    "mimicking" => 5,
+    "mimics" => 5,
+    "mimosa" => 3,
+    "min" => 23,
+    "minarets" => 1,
+    "mincing" => 21,
+    "mind" => 5,
+    "minded" => 20,
+    "mindedness" => 20,
+    "mindful" => 17,
+    "minding" => 21,
+    "mindlessly" => 32,
+    "minds" => 5,
+    "mine" => 261,
+    "minefield" => 1,
+    "miner" => 1,
+    "mineral" => 17,
+    "miners" => 261,
+    "mines" => 5,
+    "minesweeper" => 1,
+    "ming" => 23,
+    "mingle" => 5,
+    "mingled" => 20,
+    "mingling" => 21,
+    "mini" => 17,
+    "miniature" => 21,
+    "miniaturized" => 20,
+    "minify" => 4,
+    "minifying" => 21,
+    "minim" => 1,
+    "minima" => 1,
+    "minimal" => 17,
+    "minimalism" => 1,
+    "minimalist" => 17,
+    "minimally" => 32,
+    "minimise" => 4,
+    "minimised" => 20,
+    "minimising" => 21,
+    "minimization" => 1,
+    "minimize" => 4,
+    "minimized" => 20,
+    "minimizes" => 4,
+    "minimizing" => 21,
+    "minimum" => 17,
+    "minimums" => 1,
+    "mining" => 21,
+    "minister" => 5,
+    "ministered" => 20,
+    "ministering" => 21,
+    "ministers" => 5,
+    "minnesota" => 2,
+    "minor" => 21,
+    "minority" => 17,
+    "minsk" => 2,
+    "minstrel" => 5,
+    "mint" => 21,
+    "minted" => 20,
+    "minter" => 17,
+    "minting" => 21,
+    "mints" => 5,
+    "minus" => 85,
+    "minute" => 21,
+    "minutely" => 32,
+    "minuteness" => 21,
+    "minutes" => 5,
+    "minutiae" => 1,
+    "miracle" => 5,
+    "miracles" => 5,
+    "miraculous" => 16,
+    "miraculously" => 32,
+    "mirror" => 5,
+    "mirrored" => 20,
+    "mirroring" => 21,
+    "mirrors" => 5,
+    "mirth" => 1,
+    "misadventures" => 1,
+    "misalignment" => 1,
+    "misapplication" => 5,
+    "misbehave" => 4,
+    "misbehaves" => 4,
+    "misbehavior" => 1,
+    "miscarried" => 20,
+    "miscellaneous" => 16,
+    "miscellany" => 1,
+    "mischance" => 5,
+    "mischief" => 5,
+    "mischievous" => 16,
+    "misconception" => 1,
+    "misconceptions" => 1,
+    "misconduct" => 5,
+    "miscreant" => 17,
+    "misdeed" => 1,
+    "misdemeanors" => 1,
+    "misdemeanour" => 1,
+    "miserable" => 1,
+    "miserably" => 32,
+    "miseries" => 1,
+    "misery" => 1,
+    "misfortune" => 1,
+    "misfortunes" => 1,
+    "misgiving" => 1,
+    "misgivings" => 1,
+    "misguided" => 20,
+    "mishandling" => 21,
+    "mishap" => 5,
+    "misinterpret" => 4,
+    "misinterpreted" => 20,
+    "misjudged" => 20,
+    "misleading" => 21,
+    "misleadingly" => 32,
+    "misled" => 4,
+    "mismatch" => 5,
+    "mismatched" => 20,
+    "mismatches" => 5,
+    "mismatching" => 21,
+    "misnamed" => 20,
+    "misnomer" => 5,
+    "misplaced" => 20,
+    "misplacing" => 21,
+    "misread" => 5,
+    "misrepresent" => 4,
+    "misrepresented" => 20,
+    "miss" => 5,
+    "missed" => 20,
+    "misses" => 5,
+    "missile" => 1,
+    "missing" => 21,
+    "mission" => 5,
+    "missionary" => 17,
+    "mississippi" => 3,
+    "missouri" => 2,
+    "misspelled" => 20,
+    "misspelling" => 21,
+    "misspellings" => 4,
+    "mist" => 5,
+    "mistake" => 5,
+    "mistaken" => 20,
+    "mistakenly" => 32,
+    "mistakes" => 5,
+    "mistaking" => 21,
+    "mister" => 1,
+    "mistral" => 1,
+    "mistreats" => 4,
+    "mistress" => 5,
+    "mistrust" => 5,
+    "mists" => 5,
+    "misty" => 18,
+    "mistype" => 4,
+    "mistyped" => 20,
+    "mistyping" => 21,
+    "misunderstanding" => 21,
+    "misunderstandings" => 4,
+    "misunderstood" => 20,
+    "misuse" => 5,
+    "misused" => 20,
+    "misuses" => 5,
+    "misusing" => 21,
+    "mit" => 2,
+    "miter" => 5,
+    "mitigate" => 4,
+    "mitigated" => 20,
+    "mitigates" => 4,
+    "mitigating" => 21,
+    "mitigation" => 5,
+    "mitochondria" => 1,
+    "mitre" => 5,
+    "mitres" => 5,
+    "mittens" => 1,
+    "mix" => 5,
+    "mixed" => 20,
+    "mixer" => 1,
+    "mixes" => 5,
+    "mixing" => 21,
+    "mixture" => 1,
+    "mnemonic" => 17,
+    "mnemonics" => 1,
+    "moan" => 5,
+    "moaned" => 20,
+    "moaning" => 21,
+    "mob" => 5,
+    "mobile" => 19,
+    "mobilize" => 4,
+    "moccasins" => 1,
+    "mocha" => 17,
+    "mock" => 21,
+    "mocked" => 20,
+    "mocker" => 17,
+    "mockers" => 21,
+    "mockery" => 1,
+    "mocking" => 21,
+    "mocks" => 5,
+    "mod" => 21,
+    "modal" => 17,
+    "mode" => 1,
+    "model" => 21,
+    "modeled" => 20,
+    "modeling" => 21,
+    "modelled" => 20,
+    "modelling" => 5,
+    "models" => 5,
+    "modem" => 5,
+    "moderate" => 21,
+    "moderated" => 20,
+    "moderately" => 32,
+    "moderation" => 21,
+    "moderator" => 1,
+    "moderators" => 1,
+    "modern" => 17,
+    "modernize" => 4,
+    "modernized" => 20,
+    "modernizing" => 21,
+    "modes" => 1,
+    "modest" => 16,
+    "modestly" => 32,
+    "modesty" => 1,
+    "modifiable" => 16,
+    "modification" => 5,
+    "modifications" => 4,
+    "modified" => 21,
+    "modifier" => 1,
+    "modifiers" => 4,
+    "modifies" => 4,
+    "modify" => 4,
+    "modifying" => 21,
+    "modish" => 16,
+    "modular" => 16,
+    "modularity" => 1,
+    "modulated" => 20,
+    "modulating" => 21,
+    "module" => 1,
+    "modules" => 1,
+    "modulo" => 65,
+    "modulus" => 1,
+    "moguls" => 5,
+    "mohammedan" => 17,
+    "mohammedans" => 1,
+    "moiety" => 1,
+    "moire" => 1,
+    "moist" => 21,
+    "moistened" => 20,
+    "moisture" => 1,
+    "molars" => 1,
+    "mold" => 5,
+    "molding" => 21,
+    "mole" => 1,
+    "molecular" => 17,
+    "moment" => 1,
+    "momentarily" => 32,
+    "momentary" => 16,
+    "moments" => 1,
+    "momentum" => 1,
+    "monad" => 1,
+    "monarch" => 1,
+    "monarchies" => 1,
+    "monarchs" => 1,
+    "monasteries" => 1,
+    "monastery" => 1,
+    "monday" => 1,
+    "mondays" => 1,
+    "monetary" => 16,
+    "money" => 17,
+    "money's" => 1,
+    "moneyed" => 16,
+    "monger" => 5,
+    "mongolia" => 2,
+    "mongolian" => 17,
+    "monica" => 2,
+    "monitor" => 5,
+    "monitored" => 20,
+    "monitoring" => 21,
+    "monitors" => 5,
+    "monk" => 7,
+    "monkey" => 5,
+    "monkeys" => 5,
+    "mono" => 17,
+    "monochrome" => 17,
+    "monogram" => 5,
+    "monograms" => 5,
+    "monograph" => 5,
+    "monographs" => 1,
+    "monolingual" => 17,
+    "monolithic" => 16,
+    "monomaniac" => 17,
+    "monopolize" => 4,
+    "monopolizing" => 21,
+    "monosyllable" => 1,
+    "monosyllables" => 1,
+    "monotone" => 21,
+    "monotonic" => 16,
+    "monotonically" => 32,
+    "monotonous" => 16,
+    "monotonously" => 32,
+    "monotony" => 1,
+    "monsieur" => 1,
+    "monster" => 21,
+    "monsters" => 5,
+    "monstrosity" => 1,
+    "monstrous" => 16,
+    "montague" => 2,
+    "montana" => 2,
+    "montenegrin" => 17,
+    "montenegro" => 2,
+    "monterey" => 18,
+    "montgomery" => 2,
+    "month" => 1,
+    "monthly" => 49,
+    "months" => 1,
+    "montpelier" => 2,
+    "montreal" => 2,
+    "montserrat" => 2,
+    "monument" => 5,
+    "monuments" => 5,
+    "moo" => 5,
+    "mood" => 1,
+    "moodily" => 32,
+    "moods" => 1,
+    "moody" => 18,
+    "moon" => 7,
+    "moonless" => 16,
+    "moonlight" => 5,
+    "moonlit" => 16,
+    "moons" => 5,
+    "moonshine" => 5,
+    "moonstone" => 1,
+    "moore" => 2,
+    "moored" => 20,
+    "moot" => 21,
+    "moped" => 21,
+    "moping" => 21,
+    "mopping" => 5,
+    "mops" => 5,
+    "moral" => 21,
+    "moralizing" => 21,
+    "morals" => 5,
+    "morbid" => 16,
+    "more" => 407,
+    "morgan" => 3,
+    "mormon" => 19,
+    "mormonism" => 3,
+    "mormons" => 1,
+    "morning" => 21,
+    "mornings" => 1,
+    "morocco" => 3,
+    "morose" => 16,
+    "morosely" => 32,
+    "morphological" => 16,
+    "morphology" => 1,
+    "morris" => 2,
+    "morrow" => 7,
+    "morsel" => 5,
+    "mortal" => 17,
+    "mortally" => 32,
+    "mortals" => 1,
+    "mortar" => 5,
+    "mortgage" => 5,
+    "mortification" => 5,
+    "mortified" => 20,
+    "morton" => 2,
+    "mosaic" => 21,
+    "mosques" => 1,
+    "moss" => 7,
+    "mossy" => 17,
+    "most" => 433,
+    "mostly" => 32,
+    "mother" => 5,
+    "mother's" => 1,
+    "motherless" => 16,
+    "mothers" => 5,
+    "moths" => 5,
+    "motif" => 1,
+    "motion" => 5,
+    "motioned" => 4,
+    "motionless" => 16,
+    "motions" => 5,
+    "motivate" => 4,
+    "motivated" => 20,
+    "motivates" => 4,
+    "motivating" => 21,
+    "motivation" => 1,
+    "motivational" => 16,
+    "motivations" => 1,
+    "motive" => 21,
+    "motives" => 5,
+    "motley" => 17,
+    "motor" => 21,
+    "motorcycle" => 5,
+    "motorists" => 1,
+    "motorola" => 2,
+    "motors" => 5,
+    "mottled" => 20,
+    "motto" => 5,
+    "mottoes" => 1,
+    "mould" => 5,
+    "moulded" => 20,
+    "moulding" => 21,
+    "moulton" => 2,
+    "mount" => 7,
+    "mountain" => 1,
+    "mountaineers" => 5,
+    "mountainous" => 16,
+    "mountains" => 1,
+    "mountebanks" => 5,
+    "mounted" => 20,
+    "mounting" => 21,
+    "mounts" => 5,
+    "mourn" => 5,
+    "mourned" => 20,
+    "mourner" => 1,
+    "mourners" => 5,
+    "mournful" => 16,
+    "mournfully" => 32,
+    "mourning" => 21,
+    "mouse" => 5,
+    "moused" => 20,
+    "moustache" => 1,
+    "moustached" => 16,
+    "mouth" => 5,
+    "mouthed" => 20,
+    "mouthpiece" => 1,
+    "mouths" => 5,
+    "movable" => 21,
+    "move" => 5,
+    "moved" => 20,
+    "movement" => 1,
+    "movements" => 1,
+    "moves" => 5,
+    "movie" => 1,
+    "movies" => 1,
+    "moving" => 21,
+    "mower" => 1,
+    "mrs" => 1,
+    "much" => 432,
+    "muchness" => 432,
+    "muck" => 5,
+    "mucking" => 21,
+    "mud" => 5,
+    "muddied" => 20,
+    "muddle" => 5,
+    "muddled" => 20,
+    "muddy" => 21,
+    "muesli" => 1,
+    "muff" => 5,
+    "muffled" => 20,
+    "muhammadan" => 17,
+    "muir" => 2,
+    "mulatto" => 1,
+    "mule" => 5,
+    "muller" => 2,
+    "multi" => 1,
+    "multibyte" => 16,
+    "multidimensional" => 16,
+    "multifarious" => 16,
+    "multilingual" => 17,
+    "multimedia" => 17,
+    "multiparty" => 16,
+    "multiple" => 145,
+    "multiples" => 1,
+    "multiplex" => 21,
+    "multiplexed" => 20,
+    "multiplexes" => 5,
+    "multiplexing" => 21,
+    "multiplicand" => 1,
+    "multiplication" => 5,
+    "multiplications" => 5,
+    "multiplicative" => 17,
+    "multiplicity" => 1,
+    "multiplied" => 20,
+    "multiplier" => 1,
+    "multipliers" => 5,
+    "multiplies" => 5,
+    "multiply" => 5,
+    "multiplying" => 21,
+    "multiprocessing" => 1,
+    "multiprocessor" => 1,
+    "multipurpose" => 16,
+    "multitask" => 20,
+    "multitasking" => 21,
+    "multitude" => 1,
+    "multitudes" => 1,
+    "multivariate" => 17,
+    "mum" => 21,
+    "mumble" => 5,
+    "mumbled" => 20,
+    "mumbles" => 5,
+    "mumbling" => 21,
+    "mummy" => 5,
+    "munched" => 20,
+    "muncher" => 1,
+    "munching" => 21,
+    "munich" => 2,
+    "municipal" => 17,
+    "munificent" => 16,
+    "munro" => 3,
+    "murder" => 5,
+    "murdered" => 20,
+    "murderer" => 1,
+    "murderers" => 5,
+    "murderess" => 1,
+    "murdering" => 21,
+    "murderous" => 16,
+    "murders" => 5,
+    "murky" => 16,
+    "murmur" => 5,
+    "murmured" => 20,
+    "murmuring" => 21,
+    "murmurs" => 5,
+    "murray" => 2,
+    "muscle" => 5,
+    "muscles" => 5,
+    "muscular" => 16,
+    "mused" => 20,
+    "museum" => 5,
+    "museums" => 5,
+    "mush" => 5,
+    "mushroom" => 21,
+    "mushrooms" => 5,
+    "music" => 21,
+    "musical" => 17,
+    "musician" => 1,
+    "musicians" => 1,
+    "musing" => 21,
+    "musings" => 5,
+    "muskets" => 1,
+    "muslin" => 1,
+    "must" => 5,
+    "mustache" => 1,
+    "mustachios" => 5,
+    "mustard" => 17,
+    "muster" => 5,
+    "mustn't" => 4,
+    "musts" => 5,
+    "musty" => 21,
+    "mutability" => 1,
+    "mutable" => 21,
+    "mutably" => 32,
+    "mutants" => 1,
+    "mutate" => 4,
+    "mutated" => 20,
+    "mutates" => 4,
+    "mutating" => 21,
+    "mutation" => 5,
+    "mutations" => 5,
+    "mute" => 21,
+    "mutilated" => 20,
+    "mutilation" => 21,
+    "mutinous" => 16,
+    "mutiny" => 5,
+    "mutt" => 1,
+    "muttered" => 20,
+    "muttering" => 21,
+    "mutterings" => 5,
+    "mutton" => 17,
+    "mutual" => 17,
+    "mutually" => 32,
+    "muzzle" => 5,
+    "my" => 128,
+    "myriad" => 17,
+    "myrtle" => 3,
+    "myself" => 256,
+    "mysteries" => 1,
+    "mysterious" => 16,
+    "mysteriously" => 32,
+    "mystery" => 1,
+    "mystic" => 17,
+    "mystical" => 16,
+    "myth" => 1,
+    "mythology" => 1,
+    "myths" => 1,
+    "nag" => 5,
+    "nagasaki" => 2,
+    "nags" => 5,
+    "nail" => 5,
+    "nails" => 5,
+    "naive" => 17,
+    "naively" => 32,
+    "naked" => 20,
+    "nakedness" => 21,
+    "nam" => 2,
+    "name" => 5,
+    "nameable" => 16,
+    "named" => 20,
+    "nameless" => 17,
+    "names" => 5,
+    "namesakes" => 5,
+    "namespace" => 5,
+    "naming" => 21,
+    "nan" => 3,
+    "nancy" => 2,
+    "nanosecond" => 1,
+    "nanoseconds" => 1,
+    "nap" => 5,
+    "napkin" => 1,
+    "naples" => 2,
+    "napoleons" => 1,
+    "napped" => 4,
+    "narcotic" => 17,
+    "narrated" => 20,
+    "narration" => 5,
+    "narrations" => 4,
+    "narrative" => 21,
+    "narratives" => 1,
+    "narrow" => 21,
+    "narrowed" => 20,
+    "narrower" => 17,
+    "narrowing" => 21,
+    "narrowly" => 32,
+    "nasa" => 2,
+    "nasal" => 17,
+    "nasals" => 1,
+    "nascent" => 16,
+    "nastier" => 16,
+    "nasty" => 17,
+    "nat" => 3,
+    "nathan" => 3,
+    "nathaniel" => 2,
+    "nation" => 3,
+    "national" => 17,
+    "nationalities" => 1,
+    "nationality" => 1,
+    "nations" => 1,
+    "nationwide" => 16,
+    "native" => 17,
+    "natively" => 32,
+    "natives" => 1,
+    "natty" => 17,
+    "natural" => 17,
+    "naturalization" => 1,
+    "naturalized" => 20,
+    "naturally" => 32,
+    "nature" => 5,
+    "natures" => 5,
+    "naughty" => 20,
+    "nautical" => 16,
+    "nautilus" => 1,
+    "navajo" => 3,
+    "naval" => 16,
+    "navigable" => 16,
+    "navigate" => 4,
+    "navigated" => 20,
+    "navigates" => 4,
+    "navigating" => 21,
+    "navigation" => 5,
+    "navigational" => 16,
+    "navigator" => 1,
+    "navigators" => 1,
+    "navy" => 17,
+    "nay" => 21,
+    "nays" => 5,
+    "nazis" => 1,
+    "naïve" => 17,
+    "near" => 85,
+    "nearby" => 17,
+    "neared" => 20,
+    "nearer" => 17,
+    "nearest" => 16,
+    "nearing" => 21,
+    "nearly" => 32,
+    "neat" => 17,
+    "neater" => 16,
+    "neatly" => 32,
+    "neatness" => 17,
+    "nebraska" => 2,
+    "nebula" => 1,
+    "nebulous" => 16,
+    "necessarily" => 32,
+    "necessary" => 17,
+    "necessitate" => 4,
+    "necessitates" => 4,
+    "necessities" => 1,
+    "necessity" => 1,
+    "neck" => 5,
+    "necklace" => 5,
+    "necks" => 5,
+    "necktie" => 1,
+    "ned" => 18,
+    "need" => 9,
+    "needed" => 20,
+    "needful" => 17,
+    "needing" => 21,
+    "needle" => 5,
+    "needles" => 5,
+    "needless" => 16,
+    "needlessly" => 32,
+    "needs" => 1,
+    "needy" => 16,
+    "neg" => 21,
+    "negate" => 4,
+    "negated" => 20,
+    "negates" => 4,
+    "negating" => 21,
+    "negation" => 5,
+    "negations" => 4,
+    "negative" => 21,
+    "negatively" => 32,
+    "negatives" => 5,
+    "neglect" => 5,
+    "neglected" => 20,
+    "negligence" => 1,
+    "negligently" => 32,
+    "negligible" => 16,
+    "negligibly" => 32,
+    "negotiable" => 17,
+    "negotiate" => 4,
+    "negotiated" => 20,
+    "negotiates" => 4,
+    "negotiating" => 21,
+    "negotiation" => 5,
+    "negotiations" => 1,
+    "negro" => 17,
+    "negroes" => 1,
+    "neighbor" => 5,
+    "neighborhood" => 1,
+    "neighborhoods" => 1,
+    "neighboring" => 21,
+    "neighbors" => 5,
+    "neighbour" => 5,
+    "neighbourhood" => 1,
+    "neighbourhoods" => 1,
+    "neighbouring" => 21,
+    "neighbours" => 5,
+    "neil" => 2,
+    "neither" => 768,
+    "nelson" => 3,
+    "neo" => 2,
+    "neon" => 17,
+    "nepali" => 19,
+    "nephew" => 1,
+    "neptune" => 2,
+    "nerd" => 1,
+    "nerve" => 5,
+    "nerved" => 20,
+    "nerveless" => 16,
+    "nerves" => 5,
+    "nervous" => 16,
+    "nervously" => 32,
+    "nest" => 21,
+    "nested" => 20,
+    "nesting" => 21,
+    "nestling" => 21,
+    "nests" => 5,
+    "net" => 21,
+    "netherlands" => 19,
+    "nets" => 5,
+    "nettle" => 5,
+    "network" => 5,
+    "networked" => 20,
+    "networking" => 21,
+    "networks" => 5,
+    "neural" => 16,
+    "neutral" => 17,
+    "neutrality" => 1,
+    "neutrino" => 1,
+    "nevada" => 2,
+    "never" => 32,
+    "neville" => 2,
+    "new" => 21,
+    "newcomer" => 1,
+    "newcomers" => 1,
+    "newer" => 17,
+    "newest" => 16,
+    "newfound" => 16,
+    "newfoundland" => 3,
+    "newline" => 1,
+    "newly" => 32,
+    "newness" => 21,
+    "news" => 5,
+    "newsletter" => 1,
+    "newsletters" => 1,
+    "newspaper" => 5,
+    "newspapers" => 5,
+    "newsreader" => 1,
+    "newt" => 1,
+    "newton" => 3,
+    "next" => 81,
+    "niagara" => 3,
+    "nib" => 5,
+    "nibble" => 5,
+    "nibbled" => 20,
+    "nibbles" => 5,
+    "nibbling" => 21,
+    "nice" => 23,
+    "nicely" => 32,
+    "nicer" => 17,
+    "nicest" => 16,
+    "niceties" => 1,
+    "niche" => 21,
+    "niches" => 5,
+    "nicholas" => 2,
+    "nick" => 7,
+    "nickel" => 21,
+    "nickname" => 5,
+    "nicknamed" => 20,
+    "nicknames" => 5,
+    "niece" => 1,
+    "nifty" => 17,
+    "nigel" => 3,
+    "nigeria" => 2,
+    "nigger" => 5,
+    "niggers" => 5,
+    "nigh" => 84,
+    "night" => 5,
+    "nightfall" => 1,
+    "nightingale" => 3,
+    "nightly" => 48,
+    "nightmare" => 5,
+    "nights" => 5,
+    "nil" => 1,
+    "nile" => 2,
+    "nimble" => 20,
+    "nine" => 1,
+    "nineteenth" => 17,
+    "ninth" => 21,
+    "nip" => 5,
+    "nipped" => 4,
+    "nipper" => 5,
+    "nit" => 5,
+    "nitrate" => 5,
+    "nix" => 5,
+    "no" => 67,
+    "noah" => 3,
+    "nobility" => 1,
+    "noble" => 19,
+    "nobleman" => 1,
+    "nobler" => 16,
+    "nobles" => 1,
+    "noblest" => 16,
+    "nobody" => 257,
+    "nobs" => 5,
+    "nocturnal" => 17,
+    "nod" => 5,
+    "nodded" => 4,
+    "nodding" => 21,
+    "node" => 1,
+    "nodes" => 1,
+    "noise" => 5,
+    "noised" => 20,
+    "noiseless" => 16,
+    "noiselessly" => 32,
+    "noises" => 5,
+    "noisier" => 16,
+    "noisily" => 32,
+    "noisome" => 16,
+    "noisy" => 16,
+    "nomenclature" => 1,
+    "nominal" => 17,
+    "nominally" => 32,
+    "nominate" => 20,
+    "nominating" => 21,
+    "nominative" => 21,
+    "non" => 1,
+    "nonce" => 17,
+    "noncommittal" => 17,
+    "noncompliance" => 1,
+    "nonconformist" => 17,
+    "none" => 257,
+    "nonentity" => 1,
+    "nonexistent" => 17,
+    "nonlinear" => 16,
+    "nonprofit" => 17,
+    "nonsense" => 21,
+    "nonsensical" => 16,
+    "nonstandard" => 17,
+    "nonstop" => 17,
+    "nonzero" => 17,
+    "noodles" => 5,
+    "nook" => 5,
+    "nooks" => 5,
+    "noon" => 5,
+    "noonday" => 1,
+    "noose" => 5,
+    "nor" => 513,
+    "norbert" => 2,
+    "nordic" => 17,
+    "norm" => 5,
+    "normal" => 17,
+    "normalisation" => 1,
+    "normalised" => 20,
+    "normalises" => 4,
+    "normalization" => 3,
+    "normalize" => 4,
+    "normalized" => 20,
+    "normalizes" => 4,
+    "normalizing" => 21,
+    "normally" => 32,
+    "normals" => 1,
+    "norman" => 19,
+    "normandy" => 2,
+    "normans" => 1,
+    "normative" => 17,
+    "norms" => 5,
+    "norse" => 19,
+    "north" => 23,
+    "northeast" => 19,
+    "northerly" => 49,
+    "northern" => 17,
+    "northward" => 17,
+    "northwards" => 1,
+    "northwest" => 17,
+    "norton" => 3,
+    "norway" => 3,
+    "norwegian" => 17,
+    "nose" => 5,
+    "nosed" => 20,
+    "noses" => 5,
+    "nostril" => 1,
+    "nostrils" => 1,
+    "not" => 513,
+    "notable" => 529,
+    "notably" => 32,
+    "notate" => 20,
+    "notation" => 1,
+    "notations" => 1,
+    "notch" => 5,
+    "notching" => 21,
+    "note" => 5,
+    "notebook" => 1,
+    "notebooks" => 1,
+    "noted" => 20,
+    "notepad" => 1,
+    "notes" => 5,
+    "noteworthy" => 17,
+    "nothing" => 273,
+    "nothingness" => 273,
+    "notice" => 5,
+    "noticeable" => 16,
+    "noticeably" => 32,
+    "noticed" => 20,
+    "notices" => 5,
+    "noticing" => 21,
+    "notification" => 5,
+    "notifications" => 4,
+    "notified" => 20,
+    "notifies" => 4,
+    "notify" => 4,
+    "notifying" => 21,
+    "noting" => 21,
+    "notion" => 1,
+    "notional" => 17,
+    "notions" => 1,
+    "notoriety" => 1,
+    "notorious" => 16,
+    "notoriously" => 32,
+    "nottingham" => 2,
+    "notwithstanding" => 576,
+    "nought" => 309,
+    "noun" => 5,
+    "nouns" => 5,
+    "nourished" => 20,
+    "nourishment" => 5,
+    "nous" => 1,
+    "nova" => 3,
+    "novel" => 17,
+    "novelties" => 1,
+    "novelty" => 17,
+    "november" => 3,
+    "novice" => 1,
+    "novices" => 1,
+    "now" => 535,
+    "nowhere" => 17,
+    "nth" => 529,
+    "nuance" => 5,
+    "nuances" => 5,
+    "nuclear" => 17,
+    "nucleic" => 16,
+    "nucleus" => 1,
+    "nudge" => 5,
+    "nudged" => 20,
+    "nudges" => 5,
+    "nuisance" => 1,
+    "null" => 21,
+    "nullify" => 4,
+    "nulls" => 5,
+    "number" => 21,
+    "numbered" => 20,
+    "numbering" => 21,
+    "numbers" => 23,
+    "numeral" => 17,
+    "numerals" => 1,
+    "numerator" => 1,
+    "numerators" => 1,
+    "numeric" => 17,
+    "numerical" => 16,
+    "numerically" => 32,
+    "numerous" => 16,
+    "numskull" => 1,
+    "nun" => 257,
+    "nuptial" => 16,
+    "nurse" => 5,
+    "nursed" => 20,
+    "nursery" => 1,
+    "nursing" => 21,
+    "nurtured" => 20,
+    "nut" => 5,
+    "nutmeg" => 5,
+    "nuts" => 5,
+    "nutshell" => 5,
+    "nymphs" => 5,
+    "oak" => 21,
+    "oaken" => 21,
+    "oakland" => 2,
+    "oakley" => 2,
+    "oaks" => 5,
+    "oar" => 5,
+    "oars" => 5,
+    "oaten" => 1,
+    "oath" => 5,
+    "oaths" => 5,
+    "oatmeal" => 17,
+    "obadiah" => 2,
+    "obdurate" => 20,
+    "obedience" => 1,
+    "obedient" => 17,
+    "obese" => 17,
+    "obey" => 4,
+    "obeyed" => 20,
+    "obeying" => 21,
+    "obeys" => 4,
+    "obfuscated" => 20,
+    "obfuscating" => 21,
+    "obfuscation" => 21,
+    "object" => 5,
+    "objected" => 20,
+    "objection" => 1,
+    "objections" => 1,
+    "objective" => 21,
+    "objectives" => 1,
+    "objects" => 5,
+    "obligate" => 20,
+    "obligated" => 20,
+    "obligation" => 21,
+    "obligations" => 20,
+    "obligatory" => 16,
+    "oblige" => 4,
+    "obliged" => 20,
+    "obliging" => 21,
+    "obligingly" => 32,
+    "obliterate" => 20,
+    "obliterated" => 20,
+    "oblivion" => 5,
+    "oblivious" => 16,
+    "oblong" => 21,
+    "obnoxious" => 16,
+    "oboe" => 1,
+    "oboes" => 1,
+    "obscene" => 20,
+    "obscure" => 20,
+    "obscured" => 20,
+    "obscurely" => 32,
+    "obscurity" => 1,
+    "observable" => 21,
+    "observably" => 32,
+    "observant" => 16,
+    "observation" => 1,
+    "observations" => 1,
+    "observe" => 5,
+    "observed" => 20,
+    "observer" => 1,
+    "observers" => 5,
+    "observes" => 5,
+    "observing" => 21,
+    "obsession" => 1,
+    "obsolescent" => 16,
+    "obsolete" => 20,
+    "obsoleted" => 20,
+    "obstacle" => 1,
+    "obstacles" => 1,
+    "obstetrical" => 16,
+    "obstinacy" => 1,
+    "obstinate" => 16,
+    "obstinately" => 32,
+    "obstruct" => 4,
+    "obstructed" => 20,
+    "obstructing" => 21,
+    "obstruction" => 1,
+    "obtain" => 4,
+    "obtainable" => 20,
+    "obtained" => 20,
+    "obtaining" => 21,
+    "obtains" => 4,
+    "obtruded" => 20,
+    "obtrusive" => 16,
+    "obtuse" => 20,
+    "obviated" => 20,
+    "obvious" => 16,
+    "obviously" => 32,
+    "occasion" => 5,
+    "occasional" => 17,
+    "occasionally" => 32,
+    "occasioned" => 20,
+    "occasions" => 5,
+    "occidental" => 19,
+    "occipital" => 17,
+    "occluded" => 20,
+    "occupant" => 1,
+    "occupants" => 1,
+    "occupation" => 1,
+    "occupations" => 1,
+    "occupied" => 20,
+    "occupies" => 4,
+    "occupy" => 4,
+    "occupying" => 21,
+    "occur" => 4,
+    "occurred" => 4,
+    "occurrence" => 1,
+    "occurrences" => 1,
+    "occurring" => 21,
+    "occurs" => 4,
+    "ocean" => 1,
+    "oceans" => 1,
+    "ochre" => 21,
+    "octal" => 17,
+    "octave" => 21,
+    "octaves" => 5,
+    "octavo" => 1,
+    "octet" => 1,
+    "octets" => 1,
+    "october" => 7,
+    "octopus" => 5,
+    "oculist" => 1,
+    "odd" => 17,
+    "oddball" => 17,
+    "oddest" => 16,
+    "oddity" => 1,
+    "oddly" => 32,
+    "odds" => 1,
+    "ode" => 1,
+    "oder" => 2,
+    "odes" => 1,
+    "odessa" => 2,
+    "odin" => 2,
+    "odious" => 16,
+    "odor" => 1,
+    "odorous" => 16,
+    "odour" => 1,
+    "odourless" => 16,
+    "ods" => 5,
+    "of" => 64,
+    "off" => 85,
+    "offence" => 1,
+    "offences" => 1,
+    "offend" => 4,
+    "offended" => 20,
+    "offender" => 1,
+    "offenders" => 5,
+    "offending" => 21,
+    "offense" => 1,
+    "offensive" => 17,
+    "offer" => 21,
+    "offered" => 20,
+    "offering" => 21,
+    "offerings" => 5,
+    "offers" => 85,
+    "offhand" => 16,
+    "office" => 5,
+    "officer" => 5,
+    "officers" => 5,
+    "offices" => 5,
+    "official" => 17,
+    "officially" => 32,
+    "officials" => 1,
+    "offing" => 21,
+    "offline" => 20,
+    "offload" => 5,
+    "offloaded" => 20,
+    "offloading" => 21,
+    "offscreen" => 48,
+    "offset" => 21,
+    "offsets" => 5,
+    "offsetting" => 5,
+    "offshoot" => 1,
+    "offshore" => 21,
+    "offspring" => 1,
+    "often" => 32,
+    "oftener" => 32,
+    "ogre" => 1,
+    "oh" => 7,
+    "ohio" => 18,
+    "ohm" => 1,
+    "oil" => 5,
+    "oiled" => 20,
+    "oily" => 17,
+    "ointment" => 1,
+    "ok" => 23,
+    "okay" => 21,
+    "olaf" => 2,
+    "old" => 17,
+    "older" => 16,
+    "oldest" => 16,
+    "olfactory" => 17,
+    "olin" => 2,
+    "olive" => 19,
+    "oliver" => 18,
+    "olympic" => 17,
+    "omaha" => 3,
+    "omega" => 17,
+    "omen" => 5,
+    "omicron" => 1,
+    "ominous" => 16,
+    "omission" => 1,
+    "omissions" => 1,
+    "omit" => 4,
+    "omits" => 4,
+    "omitted" => 4,
+    "omitting" => 5,
+    "omnibus" => 21,
+    "omnibuses" => 5,
+    "omnipotence" => 1,
+    "omnipotent" => 19,
+    "omniscient" => 17,
+    "on" => 80,
+    "once" => 513,
+    "one" => 277,
+    "one's" => 1,
+    "onerous" => 16,
+    "ones" => 5,
+    "oneself" => 256,
+    "ongoing" => 21,
+    "onion" => 277,
+    "onions" => 277,
+    "online" => 20,
+    "only" => 560,
+    "ono" => 2,
+    "onscreen" => 16,
+    "onset" => 5,
+    "onsets" => 5,
+    "onslaught" => 1,
+    "onto" => 80,
+    "onus" => 1,
+    "onward" => 20,
+    "onwards" => 32,
+    "oodles" => 1,
+    "oops" => 5,
+    "oozing" => 21,
+    "opacity" => 1,
+    "opal" => 3,
+    "opaque" => 21,
+    "opaquely" => 32,
+    "opaqueness" => 21,
+    "open" => 21,
+    "opened" => 20,
+    "opener" => 17,
+    "opening" => 21,
+    "openings" => 21,
+    "openly" => 32,
+    "openness" => 21,
+    "opens" => 5,
+    "opera" => 1,
+    "operability" => 1,
+    "operand" => 1,
+    "operands" => 1,
+    "operate" => 4,
+    "operated" => 20,
+    "operates" => 4,
+    "operatic" => 16,
+    "operating" => 21,
+    "operation" => 5,
+    "operational" => 16,
+    "operations" => 5,
+    "operative" => 21,
+    "operator" => 1,
+    "operators" => 1,
+    "opined" => 20,
+    "opinion" => 5,
+    "opinionated" => 20,
+    "opinions" => 5,
+    "opium" => 1,
+    "opponent" => 17,
+    "opportune" => 16,
+    "opportunities" => 1,
+    "opportunity" => 1,
+    "opposed" => 20,
+    "opposing" => 21,
+    "opposite" => 81,
+    "opposition" => 81,
+    "oppositions" => 81,
+    "oppressed" => 20,
+    "oppresses" => 4,
+    "oppression" => 1,
+    "oppressive" => 20,
+    "oppressively" => 32,
+    "opprobrium" => 1,
+    "opt" => 4,
+    "opted" => 20,
+    "optic" => 17,
+    "optical" => 17,
+    "optics" => 1,
+    "optimal" => 17,
+    "optimally" => 32,
+    "optimisation" => 1,
+    "optimisations" => 1,
+    "optimise" => 4,
+    "optimised" => 20,
+    "optimiser" => 1,
+    "optimising" => 21,
+    "optimism" => 1,
+    "optimistic" => 16,
+    "optimistically" => 16,
+    "optimization" => 1,
+    "optimizations" => 1,
+    "optimize" => 4,
+    "optimized" => 20,
+    "optimizer" => 1,
+    "optimizes" => 4,
+    "optimizing" => 21,
+    "optimum" => 17,
+    "opting" => 21,
+    "option" => 5,
+    "optional" => 17,
+    "optionally" => 32,
+    "options" => 5,
+    "opts" => 4,
+    "opulence" => 1,
+    "opulent" => 16,
+    "opus" => 1,
+    "or" => 515,
+    "oracle" => 7,
+    "oracles" => 5,
+    "oral" => 17,
+    "orange" => 23,
+    "oranges" => 5,
+    "oration" => 21,
+    "orb" => 5,
+    "orbit" => 5,
+    "orbital" => 17,
+    "orbs" => 5,
+    "orchestra" => 1,
+    "orchestras" => 1,
+    "orchestrated" => 20,
+    "orchestrates" => 4,
+    "orchestrating" => 21,
+    "orchestration" => 5,
+    "orchestrator" => 1,
+    "orchestrators" => 1,
+    "orchid" => 17,
+    "orchids" => 1,
+    "ordain" => 4,
+    "ordeal" => 1,
+    "order" => 5,
+    "ordered" => 20,
+    "ordering" => 21,
+    "orderings" => 1,
+    "orderly" => 17,
+    "orders" => 5,
+    "ordinal" => 17,
+    "ordinarily" => 32,
+    "ordinary" => 17,
+    "ordinate" => 21,
+    "ordnance" => 1,
+    "ore" => 67,
+    "oregon" => 2,
+    "organ" => 5,
+    "organic" => 48,
+    "organically" => 48,
+    "organisation" => 1,
+    "organisations" => 1,
+    "organise" => 4,
+    "organised" => 20,
+    "organization" => 1,
+    "organizational" => 16,
+    "organizationally" => 32,
+    "organizations" => 1,
+    "organize" => 4,
+    "organized" => 20,
+    "organizes" => 4,
+    "organizing" => 21,
+    "organs" => 5,
+    "orgies" => 1,
+    "orient" => 23,
+    "oriental" => 17,
+    "orientated" => 20,
+    "orientation" => 5,
+    "orientations" => 1,
+    "oriented" => 20,
+    "orienting" => 21,
+    "orig" => 1,
+    "origin" => 1,
+    "original" => 17,
+    "originality" => 1,
+    "originally" => 32,
+    "originals" => 1,
+    "originate" => 4,
+    "originated" => 20,
+    "originates" => 4,
+    "originating" => 21,
+    "origins" => 1,
+    "orkney" => 2,
+    "orleans" => 3,
+    "ornament" => 5,
+    "ornamentation" => 1,
+    "ornamented" => 20,
+    "ornaments" => 5,
+    "ornery" => 16,
+    "orphan" => 21,
+    "orphanage" => 1,
+    "orphaned" => 20,
+    "orphaning" => 21,
+    "orphans" => 5,
+    "orthogonal" => 17,
+    "orthographic" => 16,
+    "orthographically" => 16,
+    "orthography" => 5,
+    "oscillated" => 20,
+    "oscillates" => 4,
+    "oscillation" => 5,
+    "oslo" => 2,
+    "ostensibly" => 32,
+    "ostentation" => 1,
+    "ostentatious" => 16,
+    "ostentatiously" => 32,
+    "ostlers" => 1,
+    "ostrich" => 1,
+    "oswald" => 2,
+    "other" => 21,
+    "others" => 5,
+    "otherwise" => 16,
+    "otter" => 1,
+    "otters" => 1,
+    "ouch" => 5,
+    "ought" => 265,
+    "oughtn't" => 8,
+    "ounce" => 1,
+    "our" => 128,
+    "ours" => 256,
+    "ourselves" => 256,
+    "out" => 117,
+    "outages" => 1,
+    "outbound" => 17,
+    "outbreak" => 5,
+    "outbreaks" => 5,
+    "outburst" => 5,
+    "outbursts" => 5,
+    "outcast" => 21,
+    "outcome" => 1,
+    "outcomes" => 1,
+    "outcry" => 5,
+    "outdated" => 16,
+    "outdoor" => 20,
+    "outdoors" => 5,
+    "outer" => 17,
+    "outermost" => 17,
+    "outfit" => 5,
+    "outgoing" => 21,
+    "outgrows" => 4,
+    "outhouse" => 5,
+    "outlast" => 4,
+    "outlaw" => 5,
+    "outlawed" => 20,
+    "outlaws" => 5,
+    "outlet" => 1,
+    "outlets" => 1,
+    "outline" => 5,
+    "outlined" => 20,
+    "outlines" => 5,
+    "outlining" => 21,
+    "outlive" => 4,
+    "outlives" => 4,
+    "outlook" => 5,
+    "outlying" => 17,
+    "outnumber" => 4,
+    "outpace" => 4,
+    "outpaced" => 20,
+    "outperform" => 4,
+    "outperforms" => 4,
+    "outpoint" => 5,
+    "output" => 5,
+    "outputs" => 5,
+    "outputted" => 4,
+    "outputting" => 4,
+    "outraged" => 20,
+    "outrageously" => 32,
+    "outrages" => 5,
+    "outreach" => 5,
+    "outright" => 20,
+    "outs" => 5,
+    "outset" => 5,
+    "outside" => 85,
+    "outsides" => 5,
+    "outskirts" => 5,
+    "outspoken" => 20,
+    "outstanding" => 20,
+    "outstay" => 4,
+    "outstretched" => 20,
+    "outward" => 21,
+    "outwardly" => 32,
+    "outwards" => 5,
+    "outweigh" => 4,
+    "outweighed" => 20,
+    "outweighs" => 4,
+    "ovals" => 1,
+    "over" => 81,
+    "overall" => 17,
+    "overblown" => 20,
+    "overboard" => 20,
+    "overcame" => 4,
+    "overcast" => 21,
+    "overcoat" => 5,
+    "overcome" => 5,
+    "overcoming" => 21,
+    "overdid" => 4,
+    "overdo" => 4,
+    "overemphasize" => 4,
+    "overestimated" => 20,
+    "overflow" => 5,
+    "overflowed" => 20,
+    "overflowing" => 21,
+    "overflows" => 5,
+    "overfull" => 17,
+    "overhanging" => 21,
+    "overhangs" => 5,
+    "overhaul" => 5,
+    "overhauled" => 20,
+    "overhead" => 81,
+    "overhear" => 4,
+    "overheard" => 4,
+    "overhearing" => 21,
+    "overheating" => 21,
+    "overhung" => 20,
+    "overjoyed" => 20,
+    "overkill" => 5,
+    "overlaid" => 4,
+    "overlap" => 5,
+    "overlapped" => 4,
+    "overlapping" => 21,
+    "overlaps" => 5,
+    "overlay" => 5,
+    "overlaying" => 21,
+    "overlays" => 5,
+    "overload" => 5,
+    "overloaded" => 20,
+    "overloading" => 21,
+    "overloads" => 5,
+    "overlong" => 16,
+    "overlook" => 5,
+    "overlooked" => 20,
+    "overlooking" => 21,
+    "overlooks" => 5,
+    "overly" => 48,
+    "overnight" => 21,
+    "overpopulated" => 20,
+    "overpowered" => 20,
+    "overpowering" => 21,
+    "overprint" => 5,
+    "overridden" => 4,
+    "override" => 5,
+    "overrides" => 5,
+    "overriding" => 21,
+    "overripe" => 16,
+    "overrule" => 4,
+    "overruled" => 20,
+    "overrules" => 4,
+    "overruling" => 21,
+    "overrun" => 5,
+    "overruns" => 5,
+    "overseas" => 16,
+    "overseen" => 4,
+    "overshadowed" => 20,
+    "overshadows" => 4,
+    "oversight" => 5,
+    "oversights" => 5,
+    "oversleep" => 4,
+    "overspread" => 4,
+    "overstate" => 4,
+    "overstrung" => 16,
+    "overt" => 17,
+    "overtake" => 5,
+    "overtaken" => 20,
+    "overtaking" => 21,
+    "overtaxed" => 20,
+    "overthrow" => 5,
+    "overtook" => 4,
+    "overturned" => 20,
+    "overturning" => 21,
+    "overview" => 5,
+    "overviews" => 5,
+    "overwhelm" => 5,
+    "overwhelmed" => 20,
+    "overwhelming" => 21,
+    "overwhelmingly" => 32,
+    "overwrite" => 5,
+    "overwrites" => 5,
+    "overwriting" => 21,
+    "overwritten" => 4,
+    "overwrote" => 4,
+    "owe" => 4,
+    "owed" => 20,
+    "owen" => 2,
+    "owes" => 4,
+    "owing" => 21,
+    "owl" => 5,
+    "own" => 21,
+    "owned" => 20,
+    "owner" => 1,
+    "owners" => 1,
+    "ownership" => 1,
+    "owning" => 21,
+    "owns" => 5,
+    "ox" => 1,
+    "oxen" => 1,
+    "oxford" => 3,
+    "oxidation" => 1,
+    "oxide" => 1,
+    "oxidised" => 20,
+    "oxidize" => 4,
+    "oxygen" => 1,
+    "oyster" => 21,
+    "pablo" => 2,
+    "pace" => 87,
+    "paced" => 20,
+    "paces" => 5,
+    "pacific" => 19,
+    "pacifist" => 17,
+    "pacing" => 21,
+    "pack" => 5,
+    "package" => 5,
+    "packaged" => 20,
+    "packages" => 5,
+    "packaging" => 21,
+    "packard" => 2,
+    "packed" => 20,
+    "packer" => 1,
+    "packet" => 5,
+    "packets" => 5,
+    "packing" => 21,
+    "packs" => 5,
+    "pad" => 5,
+    "padded" => 20,
+    "padding" => 5,
+    "paddle" => 5,
+    "paddled" => 20,
+    "paddles" => 5,
+    "paddling" => 21,
+    "padlock" => 5,
+    "padlocked" => 20,
+    "padlocks" => 5,
+    "pads" => 5,
+    "page" => 7,
+    "pageant" => 5,
+    "pager" => 1,
+    "pagers" => 5,
+    "pages" => 5,
+    "paginated" => 20,
+    "pagination" => 5,
+    "paging" => 21,
+    "pagoda" => 1,
+    "pagodas" => 1,
+    "paid" => 20,
+    "pail" => 1,
+    "pain" => 5,
+    "pained" => 20,
+    "painful" => 16,
+    "painfully" => 32,
+    "painkiller" => 1,
+    "painless" => 16,
+    "pains" => 5,
+    "painstaking" => 17,
+    "painstakingly" => 32,
+    "paint" => 5,
+    "painted" => 20,
+    "painters" => 5,
+    "painting" => 21,
+    "paintings" => 5,
+    "paints" => 5,
+    "pair" => 5,
+    "paired" => 20,
+    "pairing" => 21,
+    "pairings" => 5,
+    "pairs" => 5,
+    "pajama" => 1,
+    "pajamas" => 1,
+    "pakistan" => 2,
+    "pal" => 5,
+    "palace" => 5,
+    "palaces" => 5,
+    "palanquin" => 1,
+    "palanquins" => 1,
+    "palatable" => 21,
+    "palatial" => 16,
+    "pale" => 21,
+    "paled" => 20,
+    "paleness" => 21,
+    "paler" => 17,
+    "palette" => 1,
+    "palettes" => 1,
+    "paling" => 21,
+    "palings" => 21,
+    "palisades" => 7,
+    "pall" => 5,
+    "pallet" => 5,
+    "pallid" => 16,
+    "pallor" => 1,
+    "palm" => 5,
+    "palmetto" => 1,
+    "palms" => 5,
+    "palpable" => 16,
+    "palpitate" => 4,
+    "palpitated" => 20,
+    "palpitating" => 21,
+    "palpitation" => 5,
+    "pals" => 5,
+    "palsy" => 21,
+    "pam" => 2,
+    "pamela" => 2,
+    "pamphlets" => 5,
+    "pan" => 23,
+    "pandas" => 1,
+    "pandemic" => 17,
+    "pandered" => 20,
+    "pane" => 5,
+    "panegyric" => 17,
+    "panel" => 5,
+    "panelist" => 1,
+    "panelled" => 20,
+    "panelling" => 5,
+    "panes" => 1,
+    "pang" => 5,
+    "pangs" => 5,
+    "panic" => 21,
+    "panicked" => 20,
+    "panicking" => 5,
+    "panics" => 5,
+    "panning" => 5,
+    "panoply" => 5,
+    "panorama" => 1,
+    "panoramic" => 17,
+    "pans" => 5,
+    "pansy" => 23,
+    "pantaloons" => 1,
+    "panted" => 20,
+    "panther" => 1,
+    "panthers" => 1,
+    "panting" => 21,
+    "pantries" => 1,
+    "pantry" => 1,
+    "pants" => 5,
+    "pap" => 21,
+    "papa" => 1,
+    "papaya" => 1,
+    "paper" => 21,
+    "paperback" => 21,
+    "papers" => 5,
+    "papyrus" => 1,
+    "par" => 85,
+    "para" => 17,
+    "parade" => 5,
+    "paraded" => 20,
+    "paradigm" => 1,
+    "paradigms" => 1,
+    "paradise" => 7,
+    "paradox" => 1,
+    "paradoxical" => 16,
+    "paradoxically" => 32,
+    "paragraph" => 5,
+    "paragraphs" => 1,
+    "parallel" => 21,
+    "parallelism" => 1,
+    "parallels" => 5,
+    "paralytic" => 17,
+    "paralyzed" => 20,
+    "parameter" => 1,
+    "parameters" => 1,
+    "parametric" => 17,
+    "paramount" => 19,
+    "paranoid" => 17,
+    "parapet" => 1,
+    "paraphrase" => 5,
+    "paraphrased" => 20,
+    "parasitically" => 32,
+    "parcel" => 5,
+    "parcelled" => 4,
+    "parcelling" => 5,
+    "parched" => 20,
+    "parchment" => 5,
+    "pardon" => 5,
+    "pardonable" => 21,
+    "pardoned" => 20,
+    "pardoning" => 21,
+    "pardons" => 5,
+    "parens" => 1,
+    "parent" => 5,
+    "parentage" => 1,
+    "parentheses" => 1,
+    "parenthesis" => 1,
+    "parenthesized" => 20,
+    "parenthetical" => 17,
+    "parenthetically" => 32,
+    "parents" => 5,
+    "pariah" => 1,
+    "parietal" => 17,
+    "paris" => 2,
+    "parish" => 5,
+    "parisian" => 17,
+    "parity" => 1,
+    "park" => 7,
+    "parked" => 20,
+    "parking" => 21,
+    "parks" => 7,
+    "parlance" => 1,
+    "parley" => 5,
+    "parliament" => 3,
+    "parlor" => 1,
+    "paroxysm" => 1,
+    "paroxysms" => 1,
+    "parquet" => 5,
+    "parr" => 2,
+    "parry" => 5,
+    "parse" => 5,
+    "parsec" => 1,
+    "parsed" => 20,
+    "parser" => 1,
+    "parsers" => 1,
+    "parses" => 5,
+    "parsing" => 21,
+    "parson" => 1,
+    "parsonage" => 1,
+    "part" => 21,
+    "partake" => 4,
+    "partaken" => 4,
+    "parted" => 20,
+    "parterres" => 1,
+    "partial" => 21,
+    "partiality" => 1,
+    "partially" => 32,
+    "partials" => 5,
+    "participant" => 17,
+    "participants" => 1,
+    "participate" => 20,
+    "participated" => 20,
+    "participates" => 4,
+    "participating" => 21,
+    "participation" => 21,
+    "particle" => 1,
+    "particles" => 1,
+    "particular" => 17,
+    "particularities" => 1,
+    "particularity" => 1,
+    "particularly" => 32,
+    "particulars" => 1,
+    "parties" => 5,
+    "parting" => 21,
+    "partisans" => 1,
+    "partition" => 5,
+    "partitioned" => 20,
+    "partitioning" => 21,
+    "partitions" => 5,
+    "partner" => 5,
+    "partners" => 5,
+    "partnership" => 1,
+    "partridge" => 1,
+    "parts" => 5,
+    "party" => 21,
+    "pascal" => 3,
+    "pass" => 5,
+    "passage" => 21,
+    "passages" => 5,
+    "passed" => 20,
+    "passenger" => 5,
+    "passengers" => 5,
+    "passers" => 20,
+    "passes" => 4,
+    "passing" => 21,
+    "passion" => 23,
+    "passionate" => 21,
+    "passionately" => 32,
+    "passions" => 22,
+    "passive" => 21,
+    "passively" => 32,
+    "passkey" => 1,
+    "passport" => 5,
+    "passports" => 5,
+    "password" => 5,
+    "passwords" => 5,
+    "past" => 85,
+    "pasta" => 1,
+    "paste" => 5,
+    "pasted" => 20,
+    "pastel" => 1,
+    "pastes" => 5,
+    "pastime" => 5,
+    "pastimes" => 5,
+    "pasting" => 21,
+    "pastoral" => 17,
+    "pastry" => 1,
+    "pasture" => 5,
+    "pasty" => 17,
+    "pat" => 23,
+    "patch" => 5,
+    "patched" => 20,
+    "patches" => 5,
+    "patching" => 21,
+    "pate" => 3,
+    "patent" => 21,
+    "patently" => 32,
+    "paternal" => 16,
+    "path" => 5,
+    "pathetic" => 16,
+    "pathetically" => 32,
+    "pathfinder" => 1,
+    "pathless" => 16,
+    "pathological" => 16,
+    "pathologies" => 1,
+    "pathos" => 1,
+    "paths" => 5,
+    "pathway" => 1,
+    "pathways" => 1,
+    "patience" => 1,
+    "patient" => 17,
+    "patiently" => 32,
+    "patients" => 1,
+    "patina" => 17,
+    "patna" => 2,
+    "patriarchal" => 16,
+    "patricia" => 2,
+    "patrick" => 2,
+    "patriot" => 1,
+    "patriotic" => 17,
+    "patrol" => 5,
+    "patron" => 5,
+    "patronage" => 5,
+    "patrons" => 5,
+    "patted" => 4,
+    "pattered" => 20,
+    "pattering" => 21,
+    "pattern" => 21,
+    "patterns" => 5,
+    "patters" => 5,
+    "patting" => 5,
+    "paul" => 2,
+    "paula" => 2,
+    "pauper" => 5,
+    "pause" => 5,
+    "paused" => 20,
+    "pauses" => 5,
+    "pausing" => 21,
+    "pavement" => 1,
+    "paves" => 4,
+    "pavilions" => 5,
+    "paving" => 21,
+    "pavlov" => 6,
+    "paw" => 5,
+    "pawing" => 21,
+    "pawnbroker" => 1,
+    "pawnees" => 1,
+    "paws" => 5,
+    "pay" => 21,
+    "payable" => 21,
+    "payed" => 4,
+    "payer" => 1,
+    "paying" => 21,
+    "payload" => 1,
+    "payment" => 21,
+    "payments" => 1,
+    "payne" => 2,
+    "payoff" => 1,
+    "pays" => 5,
+    "pcb" => 1,
+    "pea" => 1,
+    "peace" => 7,
+    "peaceable" => 16,
+    "peaceably" => 32,
+    "peaceful" => 17,
+    "peacefully" => 32,
+    "peach" => 21,
+    "peak" => 21,
+    "peaked" => 20,
+    "peaks" => 5,
+    "peal" => 5,
+    "peals" => 5,
+    "pearl" => 7,
+    "pearls" => 5,
+    "pearly" => 17,
+    "pears" => 1,
+    "pearson" => 2,
+    "peasant" => 17,
+    "peasantry" => 1,
+    "peasants" => 1,
+    "pebble" => 5,
+    "pebbles" => 5,
+    "peculiar" => 17,
+    "peculiarities" => 1,
+    "peculiarity" => 1,
+    "peculiarly" => 32,
+    "pecuniary" => 16,
+    "pedantic" => 16,
+    "pedantically" => 16,
+    "pedantry" => 1,
+    "pedestrian" => 17,
+    "pedestrians" => 1,
+    "peek" => 5,
+    "peeked" => 20,
+    "peeking" => 21,
+    "peeks" => 5,
+    "peel" => 7,
+    "peeled" => 20,
+    "peeling" => 21,
+    "peep" => 5,
+    "peeped" => 20,
+    "peeping" => 21,
+    "peer" => 5,
+    "peered" => 20,
+    "peeress" => 1,
+    "peering" => 21,
+    "peers" => 5,
+    "peg" => 7,
+    "pegasus" => 3,
+    "pegged" => 20,
+    "pegs" => 5,
+    "pejorative" => 17,
+    "pelicans" => 1,
+    "pen" => 87,
+    "penal" => 16,
+    "penalized" => 20,
+    "penalizes" => 4,
+    "penalties" => 1,
+    "penalty" => 1,
+    "pence" => 1,
+    "pencil" => 5,
+    "pencils" => 5,
+    "pend" => 5,
+    "pendants" => 1,
+    "pending" => 21,
+    "pends" => 5,
+    "pendulum" => 1,
+    "penelope" => 2,
+    "penetrate" => 4,
+    "penetrated" => 20,
+    "penetrating" => 21,
+    "penetration" => 5,
+    "peninsula" => 1,
+    "peninsular" => 17,
+    "penitent" => 17,
+    "penitentiary" => 17,
+    "penn" => 2,
+    "pennies" => 5,
+    "penniless" => 16,
+    "pennsylvania" => 2,
+    "penny" => 7,
+    "pennyworth" => 1,
+    "pens" => 69,
+    "pension" => 5,
+    "pensioned" => 20,
+    "pensioners" => 5,
+    "pensions" => 5,
+    "pensive" => 16,
+    "pent" => 21,
+    "pentagon" => 3,
+    "pentium" => 1,
+    "penultimate" => 17,
+    "penury" => 1,
+    "people" => 5,
+    "peopled" => 20,
+    "peoples" => 5,
+    "pep" => 5,
+    "pepper" => 5,
+    "peppering" => 21,
+    "per" => 1,
+    "perambulating" => 21,
+    "perambulations" => 4,
+    "perceivable" => 4,
+    "perceive" => 4,
+    "perceived" => 20,
+    "perceiving" => 21,
+    "percent" => 1,
+    "percentage" => 1,
+    "percentile" => 1,
+    "percentiles" => 1,
+    "percents" => 1,
+    "perceptible" => 17,
+    "perceptibly" => 32,
+    "perceptions" => 1,
+    "perceptual" => 16,
+    "perch" => 5,
+    "perched" => 20,
+    "percussion" => 1,
+    "percy" => 2,
+    "perdition" => 1,
+    "peremptorily" => 32,
+    "peremptory" => 17,
+    "perennial" => 17,
+    "perfect" => 21,
+    "perfecting" => 21,
+    "perfection" => 5,
+    "perfectly" => 32,
+    "perforce" => 4,
+    "perform" => 4,
+    "performance" => 1,
+    "performances" => 1,
+    "performed" => 20,
+    "performer" => 1,
+    "performers" => 4,
+    "performing" => 21,
+    "performs" => 4,
+    "perfume" => 5,
+    "perfumes" => 5,
+    "perhaps" => 33,
+    "peril" => 5,
+    "perilous" => 16,
+    "perils" => 5,
+    "perimeter" => 1,
+    "perimeters" => 1,
+    "period" => 21,
+    "periodic" => 16,
+    "periodically" => 32,
+    "periodicals" => 1,
+    "periods" => 5,
+    "peripheral" => 17,
+    "peripherals" => 1,
+    "periphrastic" => 16,
+    "perish" => 4,
+    "perishable" => 21,
+    "perished" => 20,
+    "perishing" => 21,
+    "perkins" => 2,
+    "perm" => 7,
+    "permanence" => 1,
+    "permanency" => 1,
+    "permanent" => 21,
+    "permanently" => 32,
+    "permeate" => 5,
+    "permeated" => 20,
+    "permeates" => 5,
+    "permissible" => 16,
+    "permission" => 5,
+    "permissions" => 5,
+    "permissive" => 16,
+    "permissively" => 32,
+    "permit" => 5,
+    "permits" => 5,
+    "permitted" => 20,
+    "permitting" => 5,
+    "permutation" => 1,
+    "permutations" => 1,
+    "permute" => 4,
+    "permuted" => 20,
+    "permutes" => 4,
+    "permuting" => 21,
+    "pernicious" => 16,
+    "perpendicular" => 17,
+    "perpendicularity" => 1,
+    "perpendicularly" => 32,
+    "perpetrate" => 4,
+    "perpetrated" => 20,
+    "perpetrators" => 1,
+    "perpetual" => 17,
+    "perpetually" => 32,
+    "perplexed" => 20,
+    "perplexing" => 21,
+    "perplexity" => 1,
+    "persecuted" => 20,
+    "persecution" => 5,
+    "persecutions" => 4,
+    "persecutor" => 1,
+    "persecutors" => 1,
+    "perseus" => 2,
+    "perseverance" => 1,
+    "persevere" => 4,
+    "persevering" => 21,
+    "persian" => 17,
+    "persians" => 1,
+    "persist" => 4,
+    "persisted" => 20,
+    "persistence" => 1,
+    "persistency" => 1,
+    "persistent" => 16,
+    "persistently" => 32,
+    "persisting" => 21,
+    "persists" => 4,
+    "person" => 5,
+    "personage" => 1,
+    "personages" => 1,
+    "personal" => 17,
+    "personalities" => 1,
+    "personality" => 1,
+    "personally" => 32,
+    "personified" => 20,
+    "persons" => 5,
+    "perspective" => 17,
+    "perspiration" => 1,
+    "perspired" => 20,
+    "perspiring" => 21,
+    "persuade" => 4,
+    "persuaded" => 20,
+    "persuades" => 4,
+    "persuading" => 21,
+    "persuasion" => 1,
+    "persuasions" => 1,
+    "persuasive" => 17,
+    "persuasively" => 32,
+    "pert" => 21,
+    "pertain" => 4,
+    "pertaining" => 21,
+    "perth" => 2,
+    "pertinacity" => 1,
+    "pertinent" => 17,
+    "perturbation" => 1,
+    "perturbations" => 1,
+    "perturbed" => 20,
+    "perturbs" => 4,
+    "peru" => 2,
+    "perusal" => 1,
+    "peruse" => 5,
+    "perused" => 20,
+    "pervaded" => 20,
+    "pervading" => 21,
+    "pervasive" => 16,
+    "pervasively" => 32,
+    "perversity" => 1,
+    "peseta" => 1,
+    "pesky" => 16,
+    "pessimist" => 1,
+    "pessimistic" => 16,
+    "pessimistically" => 32,
+    "pest" => 69,
+    "pestered" => 20,
+    "pestering" => 21,
+    "pet" => 23,
+    "petals" => 5,
+    "pete" => 2,
+    "peter" => 23,
+    "petered" => 20,
+    "peters" => 23,
+    "peterson" => 2,
+    "petition" => 5,
+    "petrified" => 20,
+    "petroleum" => 1,
+    "pets" => 5,
+    "petted" => 4,
+    "petticoat" => 21,
+    "petting" => 5,
+    "pettishly" => 32,
+    "petty" => 19,
+    "petulance" => 1,
+    "pew" => 5,
+    "pews" => 5,
+    "pewter" => 21,
+    "phantom" => 17,
+    "pharmacological" => 16,
+    "phase" => 7,
+    "phased" => 20,
+    "phases" => 5,
+    "phd" => 1,
+    "pheasant" => 1,
+    "phelps" => 2,
+    "phenomena" => 1,
+    "phenomenal" => 16,
+    "phenomenon" => 1,
+    "phi" => 1,
+    "phil" => 19,
+    "philadelphia" => 2,
+    "philanthropist" => 1,
+    "philip" => 2,
+    "philippines" => 18,
+    "philological" => 16,
+    "philosopher" => 1,
+    "philosophers" => 1,
+    "philosophical" => 16,
+    "philosophically" => 32,
+    "philosophies" => 5,
+    "philosophy" => 5,
+    "phlegm" => 1,
+    "phlegmatic" => 17,
+    "phone" => 5,
+    "phoned" => 20,
+    "phonemes" => 1,
+    "phonemic" => 16,
+    "phonemically" => 16,
+    "phones" => 5,
+    "phonetic" => 17,
+    "phonetically" => 17,
+    "phonetics" => 1,
+    "phonograph" => 5,
+    "phonology" => 1,
+    "phosphate" => 5,
+    "phosphor" => 1,
+    "phosphorescent" => 17,
+    "phosphorus" => 1,
+    "photo" => 5,
+    "photocopier" => 1,
+    "photocopies" => 5,
+    "photocopy" => 5,
+    "photocopying" => 21,
+    "photograph" => 5,
+    "photographed" => 20,
+    "photographer" => 1,
+    "photographers" => 5,
+    "photographic" => 16,
+    "photographs" => 5,
+    "photography" => 1,
+    "photon" => 1,
+    "phrase" => 5,
+    "phrased" => 20,
+    "phraseology" => 1,
+    "phrases" => 5,
+    "phrasing" => 21,
+    "phrasings" => 5,
+    "phrenologist" => 1,
+    "physical" => 17,
+    "physically" => 32,
+    "physician" => 1,
+    "physicians" => 1,
+    "physics" => 5,
+    "physiognomy" => 1,
+    "physiology" => 1,
+    "piano" => 21,
+    "pianos" => 5,
+    "piccolos" => 1,
+    "pick" => 5,
+    "picked" => 20,
+    "picker" => 1,
+    "pickers" => 5,
+    "picking" => 21,
+    "pickle" => 5,
+    "picks" => 5,
+    "picky" => 17,
+    "picnic" => 5,
+    "picnickers" => 1,
+    "picnics" => 5,
+    "pictorial" => 17,
+    "picture" => 5,
+    "pictured" => 20,
+    "pictures" => 5,
+    "picturesque" => 16,
+    "picturing" => 21,
+    "pie" => 5,
+    "piece" => 5,
+    "pieces" => 5,
+    "pier" => 17,
+    "pierce" => 7,
+    "pierced" => 20,
+    "piercing" => 21,
+    "pierre" => 2,
+    "pig" => 5,
+    "pigeon" => 5,
+    "pigeons" => 5,
+    "piggyback" => 21,
+    "pigments" => 5,
+    "pigs" => 5,
+    "pigsty" => 1,
+    "pike" => 7,
+    "pikestaff" => 1,
+    "pile" => 5,
+    "piled" => 20,
+    "piles" => 5,
+    "pilgrimage" => 5,
+    "pilgrims" => 5,
+    "piling" => 21,
+    "pillage" => 5,
+    "pillaged" => 20,
+    "pillaging" => 21,
+    "pillared" => 20,
+    "pillars" => 5,
+    "pilled" => 20,
+    "pilling" => 21,
+    "pillow" => 5,
+    "pillows" => 5,
+    "pills" => 5,
+    "pilot" => 21,
+    "pin" => 5,
+    "pincers" => 5,
+    "pinch" => 5,
+    "pinched" => 20,
+    "pine" => 5,
+    "pines" => 5,
+    "ping" => 21,
+    "pinging" => 21,
+    "pings" => 69,
+    "pink" => 21,
+    "pinnacle" => 5,
+    "pinnacles" => 5,
+    "pinned" => 20,
+    "pinning" => 5,
+    "pinpoint" => 21,
+    "pins" => 5,
+    "pinyin" => 3,
+    "pioneer" => 5,
+    "pioneered" => 20,
+    "pioneering" => 21,
+    "pioneers" => 5,
+    "pious" => 16,
+    "pip" => 5,
+    "pipe" => 5,
+    "piped" => 20,
+    "pipeline" => 5,
+    "pipelines" => 5,
+    "piper" => 1,
+    "pipes" => 5,
+    "pipette" => 5,
+    "piping" => 21,
+    "pips" => 5,
+    "piquant" => 16,
+    "piqued" => 20,
+    "piracy" => 1,
+    "pirate" => 21,
+    "pirates" => 5,
+    "piratical" => 16,
+    "pirating" => 21,
+    "pistol" => 5,
+    "pistols" => 5,
+    "piston" => 5,
+    "pit" => 5,
+    "pitch" => 21,
+    "pitched" => 20,
+    "pitches" => 5,
+    "pitching" => 21,
+    "piteous" => 16,
+    "pitfall" => 1,
+    "pitfalls" => 1,
+    "pitiable" => 16,
+    "pitied" => 20,
+    "pities" => 5,
+    "pitiful" => 16,
+    "pitiless" => 16,
+    "pitilessly" => 32,
+    "pits" => 5,
+    "pitt" => 2,
+    "pittance" => 1,
+    "pittsburgh" => 2,
+    "pity" => 5,
+    "pityingly" => 32,
+    "pivot" => 5,
+    "pivotal" => 16,
+    "pixel" => 5,
+    "pixels" => 5,
+    "placard" => 5,
+    "place" => 5,
+    "placebo" => 1,
+    "placed" => 20,
+    "placeholder" => 1,
+    "placement" => 5,
+    "places" => 5,
+    "placid" => 16,
+    "placidly" => 32,
+    "placing" => 21,
+    "plagued" => 20,
+    "plaid" => 21,
+    "plaids" => 5,
+    "plain" => 21,
+    "plainer" => 17,
+    "plainest" => 16,
+    "plainly" => 32,
+    "plains" => 5,
+    "plaintively" => 32,
+    "plaited" => 20,
+    "plan" => 5,
+    "planar" => 16,
+    "plane" => 21,
+    "planes" => 5,
+    "planet" => 1,
+    "planetary" => 17,
+    "plank" => 5,
+    "planked" => 20,
+    "planking" => 21,
+    "planks" => 5,
+    "planned" => 20,
+    "planner" => 1,
+    "planning" => 5,
+    "plans" => 5,
+    "plant" => 5,
+    "plantagenet" => 3,
+    "plantation" => 1,
+    "plantations" => 1,
+    "planted" => 20,
+    "planter" => 1,
+    "plants" => 5,
+    "plasma" => 5,
+    "plaster" => 5,
+    "plastered" => 20,
+    "plasters" => 5,
+    "plat" => 21,
+    "plate" => 5,
+    "platen" => 21,
+    "plates" => 5,
+    "platform" => 5,
+    "platforms" => 5,
+    "platinum" => 21,
+    "platitudes" => 1,
+    "platonic" => 17,
+    "platte" => 2,
+    "platter" => 1,
+    "platters" => 1,
+    "plausible" => 16,
+    "plausibly" => 32,
+    "play" => 5,
+    "playable" => 17,
+    "playback" => 1,
+    "played" => 20,
+    "player" => 1,
+    "players" => 1,
+    "playfellow" => 1,
+    "playfellows" => 1,
+    "playfully" => 32,
+    "playground" => 1,
+    "playing" => 21,
+    "playmate" => 1,
+    "playmates" => 1,
+    "playpen" => 1,
+    "plays" => 5,
+    "plaything" => 17,
+    "playthings" => 1,
+    "plaza" => 1,
+    "plea" => 5,
+    "plead" => 4,
+    "pleaded" => 20,
+    "pleading" => 21,
+    "pleadingly" => 32,
+    "pleas" => 5,
+    "pleasant" => 17,
+    "pleasanter" => 16,
+    "pleasantest" => 16,
+    "pleasantly" => 32,
+    "please" => 4,
+    "pleased" => 20,
+    "pleases" => 4,
+    "pleasing" => 21,
+    "pleasurable" => 5,
+    "pleasure" => 5,
+    "pleasures" => 5,
+    "plebs" => 1,
+    "pledge" => 5,
+    "pledged" => 20,
+    "plentiful" => 16,
+    "plentifully" => 32,
+    "plenty" => 273,
+    "plethora" => 1,
+    "pliant" => 16,
+    "plied" => 20,
+    "plonk" => 5,
+    "plosives" => 1,
+    "plot" => 5,
+    "plots" => 5,
+    "plotters" => 5,
+    "plotting" => 5,
+    "ploughed" => 20,
+    "ploughing" => 21,
+    "pluck" => 5,
+    "plucked" => 20,
+    "plucking" => 21,
+    "plug" => 5,
+    "pluggable" => 16,
+    "plugged" => 4,
+    "plugin" => 1,
+    "plugs" => 5,
+    "plum" => 21,
+    "plumb" => 21,
+    "plumber" => 17,
+    "plumbing" => 21,
+    "plumed" => 20,
+    "plumes" => 5,
+    "plump" => 21,
+    "plumped" => 20,
+    "plunder" => 5,
+    "plundering" => 21,
+    "plunge" => 5,
+    "plunged" => 20,
+    "plunges" => 5,
+    "plunging" => 21,
+    "plural" => 17,
+    "pluralize" => 4,
+    "plurals" => 1,
+    "plus" => 597,
+    "plush" => 17,
+    "plutarch" => 3,
+    "ply" => 5,
+    "plying" => 21,
+    "pneumatic" => 17,
+    "pocked" => 20,
+    "pocket" => 21,
+    "pocketed" => 20,
+    "pocketing" => 21,
+    "pockets" => 5,
+    "pod" => 5,
+    "poem" => 1,
+    "poems" => 1,
+    "poet" => 1,
+    "poetic" => 16,
+    "poetical" => 16,
+    "poetry" => 1,
+    "poets" => 1,
+    "poignant" => 16,
+    "poignantly" => 32,
+    "point" => 5,
+    "pointed" => 20,
+    "pointer" => 1,
+    "pointers" => 5,
+    "pointing" => 21,
+    "pointless" => 16,
+    "pointlessly" => 32,
+    "points" => 5,
+    "poise" => 5,
+    "poised" => 20,
+    "poison" => 5,
+    "poisoned" => 20,
+    "poisoner" => 1,
+    "poisoning" => 21,
+    "poisons" => 5,
+    "poisson" => 1,
+    "poke" => 5,
+    "poked" => 20,
+    "poker" => 5,
+    "pokers" => 5,
+    "poking" => 21,
+    "poky" => 17,
+    "poland" => 2,
+    "polar" => 17,
+    "polarity" => 1,
+    "pole" => 7,
+    "poles" => 5,
+    "police" => 5,
+    "policeman" => 1,
+    "policemen" => 1,
+    "policies" => 5,
+    "policy" => 5,
+    "polish" => 21,
+    "polished" => 20,
+    "polisher" => 1,
+    "polishing" => 21,
+    "polite" => 20,
+    "politely" => 32,
+    "politeness" => 21,
+    "politer" => 17,
+    "politic" => 21,
+    "political" => 17,
+    "politician" => 1,
+    "politicians" => 1,
+    "politics" => 5,
+    "polity" => 1,
+    "poll" => 21,
+    "polled" => 20,
+    "polling" => 21,
+    "polls" => 5,
+    "pollster" => 1,
+    "pollute" => 20,
+    "polluted" => 20,
+    "pollutes" => 4,
+    "polluting" => 21,
+    "pollution" => 21,
+    "polly" => 34,
+    "polo" => 3,
+    "poly" => 21,
+    "polygamists" => 1,
+    "polygamy" => 1,
+    "polyglot" => 17,
+    "polygon" => 1,
+    "polygonal" => 16,
+    "polygons" => 1,
+    "polyhedron" => 1,
+    "polymorphic" => 16,
+    "polynomial" => 17,
+    "polynomials" => 1,
+    "pom" => 1,
+    "pommel" => 5,
+    "pomp" => 5,
+    "pompadour" => 5,
+    "pomposity" => 1,
+    "pompous" => 16,
+    "pond" => 5,
+    "pondered" => 20,
+    "pondering" => 21,
+    "ponderous" => 16,
+    "ponderously" => 32,
+    "pong" => 5,
+    "ponies" => 5,
+    "pony" => 21,
+    "poodle" => 1,
+    "poodles" => 1,
+    "pooh" => 7,
+    "pool" => 5,
+    "pooled" => 20,
+    "pooling" => 21,
+    "poolroom" => 1,
+    "poolrooms" => 1,
+    "pools" => 5,
+    "poop" => 5,
+    "poor" => 21,
+    "poorer" => 17,
+    "poorly" => 32,
+    "pop" => 21,
+    "pope" => 7,
+    "popped" => 4,
+    "popping" => 5,
+    "pops" => 5,
+    "popular" => 17,
+    "popularised" => 20,
+    "popularity" => 1,
+    "popularized" => 20,
+    "populate" => 20,
+    "populated" => 20,
+    "populates" => 4,
+    "populating" => 21,
+    "population" => 1,
+    "populations" => 1,
+    "populous" => 16,
+    "popup" => 1,
+    "porcelain" => 5,
+    "porch" => 1,
+    "porches" => 1,
+    "pore" => 5,
+    "pores" => 5,
+    "pork" => 5,
+    "porphyry" => 1,
+    "porpoise" => 5,
+    "port" => 23,
+    "portability" => 1,
+    "portable" => 17,
+    "portal" => 21,
+    "portals" => 5,
+    "ported" => 20,
+    "portend" => 4,
+    "portentous" => 16,
+    "porter" => 7,
+    "porters" => 5,
+    "portfolio" => 1,
+    "portico" => 1,
+    "porting" => 21,
+    "portion" => 5,
+    "portions" => 5,
+    "portly" => 16,
+    "portmanteau" => 21,
+    "portrait" => 21,
+    "portrayed" => 20,
+    "portrays" => 4,
+    "ports" => 5,
+    "portugal" => 3,
+    "portuguese" => 19,
+    "pos" => 1,
+    "pose" => 5,
+    "posed" => 20,
+    "poseidon" => 2,
+    "poses" => 5,
+    "posh" => 21,
+    "posited" => 20,
+    "position" => 5,
+    "positional" => 17,
+    "positioned" => 20,
+    "positioning" => 5,
+    "positions" => 5,
+    "positive" => 21,
+    "positively" => 32,
+    "positives" => 1,
+    "posse" => 1,
+    "possess" => 4,
+    "possessed" => 20,
+    "possesses" => 4,
+    "possessing" => 21,
+    "possession" => 5,
+    "possessions" => 5,
+    "possessive" => 21,
+    "possessively" => 32,
+    "possibilities" => 1,
+    "possibility" => 1,
+    "possible" => 17,
+    "possibly" => 32,
+    "post" => 71,
+    "postage" => 1,
+    "postal" => 16,
+    "postcard" => 5,
+    "postcards" => 5,
+    "postdoctoral" => 17,
+    "posted" => 20,
+    "posterior" => 17,
+    "posterity" => 1,
+    "posters" => 69,
+    "posting" => 21,
+    "postman" => 1,
+    "postmark" => 5,
+    "postmarks" => 5,
+    "postmaster" => 1,
+    "postmortem" => 17,
+    "postpone" => 4,
+    "postponed" => 20,
+    "postpones" => 4,
+    "postponing" => 21,
+    "posts" => 5,
+    "postscript" => 5,
+    "posture" => 5,
+    "pot" => 5,
+    "potatoes" => 1,
+    "potent" => 17,
+    "potential" => 17,
+    "potentially" => 32,
+    "pots" => 5,
+    "potter" => 7,
+    "potter's" => 1,
+    "pottery" => 1,
+    "poultry" => 1,
+    "pounce" => 5,
+    "pounced" => 20,
+    "pound" => 7,
+    "pounding" => 21,
+    "pounds" => 5,
+    "pour" => 5,
+    "poured" => 20,
+    "pouring" => 21,
+    "pours" => 5,
+    "poverty" => 1,
+    "pow" => 1,
+    "powder" => 5,
+    "powdered" => 20,
+    "powdering" => 21,
+    "powdery" => 16,
+    "power" => 21,
+    "powered" => 20,
+    "powerful" => 16,
+    "powering" => 21,
+    "powerless" => 16,
+    "powers" => 7,
+    "powwow" => 5,
+    "ppm" => 1,
+    "practicable" => 5,
+    "practical" => 17,
+    "practicalities" => 1,
+    "practicality" => 1,
+    "practically" => 32,
+    "practice" => 5,
+    "practiced" => 20,
+    "practices" => 5,
+    "practise" => 5,
+    "practised" => 20,
+    "practitioners" => 1,
+    "pragmatic" => 17,
+    "pragmatically" => 32,
+    "pragmatics" => 1,
+    "prague" => 2,
+    "prairie" => 1,
+    "prairies" => 1,
+    "praise" => 5,
+    "praised" => 20,
+    "praises" => 5,
+    "praising" => 21,
+    "prance" => 5,
+    "pranced" => 20,
+    "prancing" => 21,
+    "prank" => 21,
+    "pratt" => 2,
+    "prattle" => 5,
+    "pray" => 4,
+    "prayed" => 20,
+    "prayer" => 1,
+    "prayers" => 4,
+    "praying" => 21,
+    "pre" => 69,
+    "preach" => 5,
+    "preached" => 20,
+    "preacher" => 1,
+    "preachers" => 5,
+    "preamble" => 5,
+    "preambles" => 5,
+    "prearranged" => 20,
+    "precarious" => 16,
+    "precaution" => 5,
+    "precautions" => 5,
+    "precede" => 5,
+    "preceded" => 20,
+    "precedence" => 1,
+    "precedent" => 21,
+    "precedes" => 5,
+    "preceding" => 21,
+    "preceptors" => 1,
+    "precepts" => 5,
+    "precious" => 17,
+    "precipice" => 1,
+    "precipices" => 1,
+    "precipitate" => 21,
+    "precipitated" => 20,
+    "precipitately" => 32,
+    "precipitation" => 21,
+    "precipitous" => 16,
+    "precipitously" => 32,
+    "precise" => 20,
+    "precisely" => 32,
+    "preciseness" => 21,
+    "precision" => 21,
+    "preclude" => 4,
+    "precluded" => 20,
+    "precludes" => 4,
+    "precomputed" => 20,
+    "precondition" => 5,
+    "preconditions" => 5,
+    "precursor" => 17,
+    "predate" => 5,
+    "predates" => 5,
+    "predecessor" => 1,
+    "predecessors" => 1,
+    "predefined" => 20,
+    "predestined" => 20,
+    "predetermined" => 20,
+    "predicate" => 21,
+    "predicated" => 20,
+    "predicates" => 5,
+    "predication" => 21,
+    "predict" => 5,
+    "predictability" => 1,
+    "predictable" => 21,
+    "predictably" => 32,
+    "predicted" => 20,
+    "prediction" => 1,
+    "predictions" => 1,
+    "predictive" => 5,
+    "predictor" => 1,
+    "predicts" => 5,
+    "predilection" => 1,
+    "predominant" => 17,
+    "predominated" => 20,
+    "predominates" => 4,
+    "preempt" => 5,
+    "preempted" => 20,
+    "preemption" => 1,
+    "preemptive" => 21,
+    "prefab" => 21,
+    "prefabs" => 5,
+    "preface" => 5,
+    "prefaced" => 20,
+    "prefatory" => 16,
+    "prefer" => 4,
+    "preferable" => 4,
+    "preferably" => 32,
+    "preference" => 5,
+    "preferences" => 5,
+    "preferentially" => 32,
+    "preferred" => 21,
+    "preferring" => 5,
+    "prefers" => 4,
+    "prefix" => 5,
+    "prefixed" => 20,
+    "prefixes" => 5,
+    "prefixing" => 21,
+    "pregnant" => 17,
+    "prehistoric" => 16,
+    "prejudice" => 21,
+    "prejudiced" => 20,
+    "prejudices" => 5,
+    "preliminaries" => 1,
+    "preliminary" => 17,
+    "prelude" => 5,
+    "preludes" => 5,
+    "premature" => 17,
+    "prematurely" => 32,
+    "premise" => 5,
+    "premises" => 5,
+    "premium" => 17,
+    "preoccupation" => 1,
+    "preoccupied" => 20,
+    "prep" => 5,
+    "preparation" => 1,
+    "preparations" => 1,
+    "preparatory" => 16,
+    "prepare" => 4,
+    "prepared" => 20,
+    "prepares" => 4,
+    "preparing" => 21,
+    "preposition" => 5,
+    "prepositions" => 5,
+    "prepossess" => 4,
+    "preposterous" => 16,
+    "preprocessor" => 1,
+    "prerequisite" => 17,
+    "prerequisites" => 1,
+    "presbyterian" => 17,
+    "presbyterians" => 1,
+    "prescribe" => 4,
+    "prescribed" => 20,
+    "prescription" => 17,
+    "prescriptive" => 17,
+    "presence" => 5,
+    "present" => 21,
+    "presentable" => 21,
+    "presentation" => 1,
+    "presentations" => 1,
+    "presented" => 20,
+    "presenters" => 21,
+    "presentiment" => 1,
+    "presenting" => 21,
+    "presently" => 32,
+    "presents" => 5,
+    "preservation" => 1,
+    "preserve" => 5,
+    "preserved" => 20,
+    "preserver" => 1,
+    "preserves" => 5,
+    "preserving" => 21,
+    "preset" => 21,
+    "presets" => 5,
+    "presetting" => 4,
+    "preside" => 4,
+    "presidency" => 1,
+    "president" => 5,
+    "press" => 5,
+    "pressed" => 20,
+    "presses" => 5,
+    "pressing" => 21,
+    "pressure" => 5,
+    "pressured" => 20,
+    "pressurized" => 20,
+    "prestige" => 21,
+    "prestigious" => 16,
+    "preston" => 2,
+    "presumably" => 32,
+    "presume" => 4,
+    "presumed" => 20,
+    "presuming" => 21,
+    "presumption" => 1,
+    "presumptions" => 1,
+    "presumptuous" => 16,
+    "pretence" => 1,
+    "pretend" => 21,
+    "pretended" => 20,
+    "pretending" => 21,
+    "pretends" => 5,
+    "pretenses" => 1,
+    "pretension" => 5,
+    "pretensions" => 5,
+    "pretext" => 5,
+    "pretexts" => 5,
+    "prettier" => 17,
+    "prettiest" => 16,
+    "prettified" => 20,
+    "prettifying" => 21,
+    "prettily" => 32,
+    "pretty" => 53,
+    "prevail" => 4,
+    "prevailed" => 20,
+    "prevailing" => 21,
+    "prevails" => 4,
+    "prevalent" => 16,
+    "prevent" => 4,
+    "prevented" => 20,
+    "preventing" => 21,
+    "prevention" => 1,
+    "prevents" => 4,
+    "preview" => 5,
+    "previewed" => 20,
+    "previewing" => 21,
+    "previews" => 5,
+    "previous" => 17,
+    "previously" => 32,
+    "prey" => 5,
+    "preyed" => 20,
+    "price" => 7,
+    "priceless" => 16,
+    "prices" => 5,
+    "pricing" => 21,
+    "prick" => 5,
+    "pricked" => 20,
+    "prickly" => 49,
+    "pride" => 5,
+    "prided" => 20,
+    "pried" => 20,
+    "priest" => 5,
+    "priests" => 5,
+    "prig" => 5,
+    "primaries" => 5,
+    "primarily" => 32,
+    "primary" => 21,
+    "prime" => 21,
+    "primer" => 17,
+    "primes" => 5,
+    "primitive" => 17,
+    "primitives" => 1,
+    "primp" => 4,
+    "prince" => 7,
+    "princes" => 5,
+    "princess" => 1,
+    "princeton" => 2,
+    "principal" => 17,
+    "principally" => 32,
+    "principals" => 1,
+    "principle" => 5,
+    "principled" => 20,
+    "principles" => 5,
+    "print" => 21,
+    "printable" => 17,
+    "printed" => 20,
+    "printer" => 1,
+    "printers" => 1,
+    "printing" => 21,
+    "printout" => 1,
+    "printouts" => 1,
+    "prints" => 5,
+    "prior" => 17,
+    "priorities" => 1,
+    "prioritise" => 4,
+    "prioritised" => 20,
+    "prioritises" => 4,
+    "prioritising" => 21,
+    "prioritization" => 1,
+    "prioritize" => 4,
+    "prioritized" => 20,
+    "prioritizes" => 4,
+    "prioritizing" => 21,
+    "priority" => 1,
+    "prisms" => 1,
+    "prison" => 5,
+    "prisoner" => 1,
+    "prisoners" => 5,
+    "pristine" => 16,
+    "privacy" => 1,
+    "private" => 21,
+    "privately" => 32,
+    "privilege" => 5,
+    "privileged" => 20,
+    "privileges" => 5,
+    "privy" => 17,
+    "prize" => 21,
+    "prized" => 20,
+    "prizes" => 5,
+    "pro" => 81,
+    "proactive" => 17,
+    "probabilistic" => 16,
+    "probabilities" => 1,
+    "probability" => 1,
+    "probable" => 21,
+    "probably" => 32,
+    "probe" => 5,
+    "probed" => 20,
+    "probes" => 5,
+    "probing" => 21,
+    "probity" => 1,
+    "problem" => 17,
+    "problematic" => 17,
+    "problematical" => 16,
+    "problems" => 1,
+    "procedural" => 17,
+    "procedure" => 1,
+    "procedures" => 1,
+    "proceed" => 4,
+    "proceeded" => 20,
+    "proceeding" => 21,
+    "proceedings" => 4,
+    "proceeds" => 5,
+    "process" => 5,
+    "processed" => 20,
+    "processes" => 5,
+    "processing" => 21,
+    "procession" => 5,
+    "processor" => 1,
+    "processors" => 1,
+    "proclaim" => 5,
+    "proclamation" => 1,
+    "procrastinate" => 4,
+    "proctor" => 5,
+    "procure" => 5,
+    "procurement" => 1,
+    "prod" => 5,
+    "prodigal" => 17,
+    "prodigality" => 1,
+    "prodigious" => 16,
+    "prodigiously" => 32,
+    "prodigy" => 1,
+    "produce" => 5,
+    "produced" => 20,
+    "producer" => 1,
+    "producers" => 5,
+    "produces" => 5,
+    "producing" => 21,
+    "product" => 5,
+    "production" => 1,
+    "productions" => 1,
+    "productive" => 16,
+    "productively" => 32,
+    "productivity" => 1,
+    "prof" => 1,
+    "profane" => 21,
+    "profaned" => 20,
+    "profanity" => 1,
+    "profession" => 1,
+    "professional" => 17,
+    "professionally" => 32,
+    "professionals" => 1,
+    "professions" => 1,
+    "professor" => 1,
+    "professors" => 1,
+    "professorship" => 1,
+    "proffered" => 20,
+    "proficiency" => 1,
+    "proficient" => 17,
+    "profile" => 5,
+    "profiler" => 1,
+    "profilers" => 1,
+    "profit" => 21,
+    "profitable" => 21,
+    "profitably" => 32,
+    "profited" => 20,
+    "profiting" => 21,
+    "profound" => 21,
+    "profoundly" => 32,
+    "profundity" => 1,
+    "profusion" => 5,
+    "progeny" => 1,
+    "prognosis" => 1,
+    "prognosticate" => 4,
+    "prognosticated" => 20,
+    "program" => 7,
+    "programmable" => 21,
+    "programmatic" => 16,
+    "programme" => 5,
+    "programmed" => 20,
+    "programmer" => 1,
+    "programmers" => 5,
+    "programmes" => 5,
+    "programming" => 21,
+    "programs" => 5,
+    "progress" => 5,
+    "progresses" => 5,
+    "progressing" => 21,
+    "progression" => 1,
+    "progressive" => 21,
+    "progressively" => 32,
+    "prohibit" => 4,
+    "prohibited" => 20,
+    "prohibiting" => 21,
+    "prohibitions" => 1,
+    "prohibitive" => 21,
+    "prohibitively" => 32,
+    "prohibits" => 4,
+    "project" => 5,
+    "projected" => 20,
+    "projecting" => 21,
+    "projection" => 1,
+    "projections" => 1,
+    "projectors" => 1,
+    "projects" => 5,
+    "proliferated" => 20,
+    "proliferating" => 21,
+    "proliferation" => 5,
+    "prologue" => 5,
+    "prologues" => 5,
+    "prolong" => 21,
+    "promenade" => 5,
+    "promenading" => 21,
+    "prometheus" => 2,
+    "prominence" => 1,
+    "prominent" => 17,
+    "prominently" => 32,
+    "promise" => 5,
+    "promised" => 20,
+    "promises" => 5,
+    "promising" => 21,
+    "promontory" => 1,
+    "promote" => 5,
+    "promoted" => 20,
+    "promoter" => 1,
+    "promoting" => 21,
+    "promotion" => 5,
+    "prompt" => 21,
+    "prompted" => 20,
+    "prompting" => 21,
+    "promptly" => 32,
+    "prompts" => 5,
+    "prone" => 20,
+    "pronoun" => 5,
+    "pronounce" => 5,
+    "pronounced" => 20,
+    "pronounces" => 5,
+    "pronouncing" => 21,
+    "pronunciation" => 1,
+    "pronunciations" => 1,
+    "proof" => 21,
+    "proofing" => 21,
+    "proofreading" => 21,
+    "proofs" => 5,
+    "prop" => 5,
+    "propaganda" => 1,
+    "propagate" => 4,
+    "propagated" => 20,
+    "propagates" => 4,
+    "propagating" => 21,
+    "propagation" => 5,
+    "propagator" => 1,
+    "propagators" => 1,
+    "propel" => 4,
+    "propensity" => 1,
+    "proper" => 17,
+    "properly" => 32,
+    "properties" => 5,
+    "property" => 5,
+    "prophecies" => 5,
+    "prophecy" => 5,
+    "prophesied" => 20,
+    "prophesying" => 21,
+    "prophet" => 1,
+    "prophetic" => 16,
+    "prophets" => 3,
+    "propitiating" => 21,
+    "propitious" => 16,
+    "proponents" => 1,
+    "proportion" => 5,
+    "proportional" => 17,
+    "proportionally" => 32,
+    "proportionate" => 20,
+    "proportions" => 5,
+    "proposal" => 1,
+    "proposals" => 1,
+    "propose" => 5,
+    "proposition" => 5,
+    "propound" => 5,
+    "propped" => 4,
+    "proprietary" => 17,
+    "proprietor" => 1,
+    "propriety" => 1,
+    "props" => 5,
+    "pros" => 1,
+    "prose" => 5,
+    "prosecute" => 4,
+    "prosecuted" => 20,
+    "prosecution" => 5,
+    "prosecutions" => 5,
+    "proselytes" => 5,
+    "prospect" => 5,
+    "prospecting" => 21,
+    "prospective" => 21,
+    "prospects" => 5,
+    "prosper" => 4,
+    "prosperity" => 1,
+    "prosperous" => 16,
+    "prosperously" => 32,
+    "prostrate" => 20,
+    "prostrated" => 20,
+    "prostration" => 21,
+    "prosy" => 16,
+    "protect" => 4,
+    "protected" => 20,
+    "protecting" => 21,
+    "protection" => 1,
+    "protections" => 1,
+    "protective" => 21,
+    "protector" => 1,
+    "protectors" => 1,
+    "protects" => 4,
+    "protest" => 5,
+    "protestation" => 1,
+    "protestations" => 1,
+    "protocol" => 5,
+    "protocols" => 5,
+    "prototype" => 5,
+    "prototypes" => 5,
+    "prototypical" => 16,
+    "protracted" => 20,
+    "protraction" => 5,
+    "protruded" => 20,
+    "protruding" => 21,
+    "proud" => 16,
+    "prouder" => 16,
+    "proudest" => 16,
+    "proudly" => 32,
+    "provably" => 32,
+    "prove" => 5,
+    "proved" => 20,
+    "proven" => 20,
+    "provenance" => 5,
+    "proves" => 5,
+    "provide" => 4,
+    "provided" => 532,
+    "providence" => 3,
+    "provider" => 1,
+    "providers" => 4,
+    "provides" => 4,
+    "providing" => 21,
+    "province" => 1,
+    "provinces" => 1,
+    "provincial" => 17,
+    "proving" => 21,
+    "provision" => 5,
+    "provisional" => 17,
+    "provisionally" => 32,
+    "provocation" => 1,
+    "provoke" => 4,
+    "provoked" => 20,
+    "provoking" => 21,
+    "provost" => 5,
+    "prowler" => 1,
+    "proxies" => 5,
+    "proximity" => 1,
+    "proxy" => 21,
+    "prudence" => 3,
+    "prudent" => 16,
+    "prune" => 5,
+    "pruning" => 21,
+    "prussian" => 19,
+    "prying" => 21,
+    "psalm" => 5,
+    "pseudo" => 17,
+    "psi" => 1,
+    "psych" => 21,
+    "psyche" => 7,
+    "psychic" => 17,
+    "psycho" => 17,
+    "psychological" => 16,
+    "pta" => 5,
+    "pub" => 5,
+    "public" => 21,
+    "publication" => 1,
+    "publications" => 1,
+    "publicity" => 1,
+    "publicly" => 32,
+    "publish" => 4,
+    "publishable" => 16,
+    "published" => 20,
+    "publisher" => 1,
+    "publishers" => 1,
+    "publishes" => 4,
+    "publishing" => 21,
+    "pubs" => 5,
+    "puckered" => 20,
+    "pudding" => 1,
+    "puddle" => 5,
+    "puff" => 5,
+    "puffed" => 20,
+    "puffin" => 1,
+    "puffing" => 21,
+    "puffs" => 5,
+    "pugilistic" => 16,
+    "pull" => 5,
+    "pulled" => 20,
+    "pulley" => 5,
+    "pulling" => 21,
+    "pulls" => 5,
+    "pulp" => 21,
+    "pulpit" => 1,
+    "pulsar" => 1,
+    "pulsations" => 5,
+    "pulse" => 5,
+    "pulses" => 5,
+    "pulsing" => 21,
+    "pummelling" => 5,
+    "pump" => 5,
+    "pumped" => 20,
+    "pumps" => 5,
+    "pun" => 5,
+    "punch" => 7,
+    "punched" => 20,
+    "punches" => 5,
+    "punching" => 21,
+    "punctilious" => 16,
+    "punctual" => 16,
+    "punctuality" => 1,
+    "punctually" => 32,
+    "punctuate" => 20,
+    "punctuation" => 21,
+    "punctures" => 5,
+    "pungent" => 16,
+    "punish" => 4,
+    "punished" => 20,
+    "punishes" => 4,
+    "punishing" => 21,
+    "punishment" => 5,
+    "punishments" => 1,
+    "punjabi" => 19,
+    "punned" => 4,
+    "punning" => 21,
+    "puns" => 5,
+    "puny" => 17,
+    "pup" => 5,
+    "pupil" => 1,
+    "pupils" => 1,
+    "puppet" => 5,
+    "puppeteer" => 5,
+    "puppies" => 5,
+    "puppy" => 5,
+    "purchase" => 5,
+    "purchased" => 20,
+    "purchases" => 5,
+    "purchasing" => 21,
+    "purdue" => 2,
+    "pure" => 21,
+    "purely" => 32,
+    "purest" => 16,
+    "purge" => 5,
+    "purges" => 5,
+    "purging" => 21,
+    "purify" => 4,
+    "purity" => 1,
+    "purloined" => 20,
+    "purple" => 21,
+    "purport" => 5,
+    "purpose" => 5,
+    "purposed" => 20,
+    "purposeful" => 16,
+    "purposefully" => 32,
+    "purposeless" => 16,
+    "purposely" => 32,
+    "purposes" => 5,
+    "purring" => 21,
+    "purse" => 5,
+    "purser" => 1,
+    "purses" => 5,
+    "pursuance" => 1,
+    "pursue" => 4,
+    "pursued" => 20,
+    "pursuers" => 4,
+    "pursues" => 4,
+    "pursuing" => 21,
+    "pursuit" => 1,
+    "pursuits" => 1,
+    "purveyor" => 1,
+    "purview" => 1,
+    "push" => 5,
+    "pushed" => 20,
+    "pusher" => 1,
+    "pushes" => 5,
+    "pushing" => 21,
+    "puss" => 1,
+    "put" => 5,
+    "puts" => 5,
+    "putted" => 20,
+    "putter" => 5,
+    "putting" => 21,
+    "putty" => 21,
+    "puzzle" => 5,
+    "puzzled" => 20,
+    "puzzlement" => 5,
+    "puzzles" => 5,
+    "puzzling" => 21,
+    "pyramid" => 5,
+    "pyramids" => 5,
+    "pyre" => 1,
+    "python" => 3,
+    "pyx" => 5,
+    "quack" => 21,
+    "quacks" => 5,
+    "quad" => 21,
+    "quadrant" => 1,
+    "quadratic" => 17,
+    "quadrille" => 21,
+    "quadrillion" => 21,
+    "quadruple" => 21,
+    "quadruplet" => 1,
+    "quadruplets" => 1,
+    "quads" => 5,
+    "quailed" => 20,
+    "quails" => 5,
+    "quaint" => 17,
+    "quake" => 5,
+    "quaked" => 20,
+    "quaking" => 21,
+    "qualification" => 5,
+    "qualifications" => 5,
+    "qualified" => 20,
+    "qualifier" => 1,
+    "qualifiers" => 1,
+    "qualifies" => 5,
+    "qualify" => 5,
+    "qualifying" => 21,
+    "qualitative" => 17,
+    "qualities" => 1,
+    "quality" => 17,
+    "quanta" => 1,
+    "quantification" => 5,
+    "quantifier" => 1,
+    "quantifiers" => 4,
+    "quantifies" => 4,
+    "quantify" => 4,
+    "quantitative" => 16,
+    "quantitatively" => 32,
+    "quantities" => 1,
+    "quantity" => 1,
+    "quantum" => 17,
+    "quarrel" => 5,
+    "quarrelled" => 4,
+    "quarrelling" => 5,
+    "quarrels" => 5,
+    "quarry" => 5,
+    "quart" => 21,
+    "quarter" => 21,
+    "quartered" => 20,
+    "quartering" => 21,
+    "quarters" => 5,
+    "quartet" => 1,
+    "quartets" => 1,
+    "quartz" => 1,
+    "quasi" => 16,
+    "quavering" => 21,
+    "quay" => 21,
+    "quays" => 5,
+    "quebec" => 3,
+    "queen" => 7,
+    "queen's" => 1,
+    "queens" => 7,
+    "queer" => 21,
+    "queerest" => 16,
+    "queerly" => 32,
+    "quelling" => 21,
+    "quench" => 5,
+    "quenched" => 20,
+    "quenching" => 21,
+    "quentin" => 2,
+    "queried" => 20,
+    "queries" => 5,
+    "query" => 5,
+    "querying" => 21,
+    "quest" => 5,
+    "question" => 5,
+    "questionable" => 21,
+    "questioned" => 20,
+    "questioning" => 21,
+    "questioningly" => 32,
+    "questions" => 5,
+    "queue" => 5,
+    "queued" => 20,
+    "queueing" => 5,
+    "queues" => 5,
+    "queuing" => 5,
+    "quick" => 21,
+    "quickened" => 20,
+    "quickening" => 21,
+    "quicker" => 17,
+    "quickest" => 16,
+    "quickly" => 32,
+    "quicksand" => 1,
+    "quicksilver" => 21,
+    "quiet" => 21,
+    "quieted" => 20,
+    "quieter" => 17,
+    "quieting" => 21,
+    "quietly" => 32,
+    "quietness" => 21,
+    "quill" => 5,
+    "quinsy" => 1,
+    "quintessential" => 17,
+    "quirk" => 5,
+    "quirks" => 5,
+    "quit" => 21,
+    "quite" => 32,
+    "quits" => 5,
+    "quitting" => 5,
+    "quiver" => 21,
+    "quivered" => 20,
+    "quivering" => 21,
+    "quizzes" => 5,
+    "quorum" => 1,
+    "quota" => 1,
+    "quotas" => 1,
+    "quotation" => 1,
+    "quotations" => 1,
+    "quote" => 5,
+    "quoted" => 20,
+    "quotes" => 5,
+    "quotient" => 1,
+    "quoting" => 21,
+    "qwerty" => 17,
+    "rabbi" => 1,
+    "rabbit" => 5,
+    "rabbits" => 5,
+    "race" => 5,
+    "raced" => 20,
+    "racehorse" => 1,
+    "racer" => 1,
+    "races" => 5,
+    "rachel" => 2,
+    "racing" => 21,
+    "rack" => 5,
+    "racket" => 5,
+    "racking" => 21,
+    "racy" => 16,
+    "rad" => 17,
+    "radar" => 5,
+    "radial" => 17,
+    "radiance" => 1,
+    "radians" => 1,
+    "radiant" => 17,
+    "radiantly" => 32,
+    "radiate" => 21,
+    "radiated" => 20,
+    "radical" => 17,
+    "radically" => 32,
+    "radicals" => 1,
+    "radices" => 1,
+    "radio" => 5,
+    "radioactive" => 17,
+    "radios" => 5,
+    "radium" => 5,
+    "radius" => 5,
+    "radix" => 1,
+    "raf" => 2,
+    "raft" => 5,
+    "rafters" => 5,
+    "rafts" => 5,
+    "rag" => 5,
+    "rage" => 5,
+    "raged" => 20,
+    "rages" => 5,
+    "ragged" => 20,
+    "raging" => 21,
+    "rags" => 5,
+    "raid" => 5,
+    "rail" => 5,
+    "railed" => 20,
+    "railing" => 21,
+    "railings" => 5,
+    "raillery" => 1,
+    "railroad" => 5,
+    "railroading" => 21,
+    "railroads" => 5,
+    "rails" => 5,
+    "railway" => 1,
+    "railways" => 1,
+    "rain" => 5,
+    "rainbow" => 21,
+    "raincoat" => 1,
+    "rained" => 20,
+    "raining" => 21,
+    "rains" => 5,
+    "raise" => 5,
+    "raised" => 20,
+    "raises" => 5,
+    "raising" => 21,
+    "rajah" => 1,
+    "rajahs" => 1,
+    "rake" => 5,
+    "raked" => 20,
+    "rakish" => 16,
+    "rallying" => 21,
+    "ralph" => 2,
+    "ram" => 23,
+    "ramadan" => 2,
+    "ramble" => 5,
+    "rambled" => 20,
+    "rambles" => 5,
+    "rambling" => 21,
+    "ramblings" => 5,
+    "ramifications" => 4,
+    "ramp" => 5,
+    "rampage" => 5,
+    "rampant" => 16,
+    "ran" => 5,
+    "ranches" => 5,
+    "rancor" => 1,
+    "rand" => 7,
+    "randall" => 2,
+    "random" => 21,
+    "randomising" => 21,
+    "randomization" => 1,
+    "randomize" => 4,
+    "randomized" => 20,
+    "randomizing" => 21,
+    "randomly" => 32,
+    "randomness" => 21,
+    "randy" => 19,
+    "rang" => 4,
+    "range" => 5,
+    "ranged" => 20,
+    "ranges" => 5,
+    "ranging" => 21,
+    "rangoon" => 2,
+    "rank" => 21,
+    "ranked" => 20,
+    "rankin" => 2,
+    "ranking" => 21,
+    "rankle" => 5,
+    "rankling" => 21,
+    "ranks" => 5,
+    "ransacked" => 20,
+    "ransom" => 5,
+    "rap" => 5,
+    "rapacity" => 1,
+    "raphael" => 2,
+    "rapid" => 17,
+    "rapidity" => 1,
+    "rapidly" => 32,
+    "rapids" => 1,
+    "rapier" => 17,
+    "rapine" => 5,
+    "rapped" => 4,
+    "rapt" => 21,
+    "rapture" => 5,
+    "rapturously" => 32,
+    "rare" => 21,
+    "rarely" => 32,
+    "rarer" => 17,
+    "rarity" => 1,
+    "rascal" => 17,
+    "rascally" => 32,
+    "rascals" => 1,
+    "rash" => 21,
+    "rashers" => 21,
+    "rashly" => 32,
+    "rashness" => 21,
+    "raspberry" => 21,
+    "raster" => 5,
+    "rat" => 5,
+    "ratchet" => 21,
+    "ratchets" => 5,
+    "rate" => 5,
+    "rated" => 20,
+    "rates" => 5,
+    "rather" => 55,
+    "ratification" => 5,
+    "ratified" => 20,
+    "ratifying" => 21,
+    "rating" => 21,
+    "ratings" => 5,
+    "ratio" => 5,
+    "rational" => 17,
+    "rationale" => 1,
+    "rationalist" => 1,
+    "rationalize" => 4,
+    "rationalized" => 20,
+    "ratios" => 5,
+    "rats" => 5,
+    "rattle" => 5,
+    "rattled" => 20,
+    "rattles" => 5,
+    "rattlesnake" => 1,
+    "rattling" => 21,
+    "ravages" => 5,
+    "raved" => 20,
+    "raven" => 21,
+    "ravenous" => 16,
+    "ravenously" => 32,
+    "ravens" => 5,
+    "ravine" => 1,
+    "ravines" => 1,
+    "raving" => 21,
+    "ravings" => 5,
+    "ravish" => 4,
+    "ravished" => 20,
+    "ravishing" => 21,
+    "raw" => 21,
+    "rawness" => 21,
+    "ray" => 7,
+    "rayleigh" => 2,
+    "raymond" => 2,
+    "rayon" => 1,
+    "rays" => 5,
+    "re" => 67,
+    "reach" => 5,
+    "reachable" => 21,
+    "reached" => 20,
+    "reaches" => 5,
+    "reaching" => 21,
+    "react" => 5,
+    "reaction" => 21,
+    "reactivate" => 4,
+    "reactive" => 5,
+    "reactively" => 32,
+    "reactivity" => 1,
+    "reactor" => 1,
+    "read" => 5,
+    "readability" => 1,
+    "readable" => 5,
+    "reader" => 1,
+    "readers" => 5,
+    "readier" => 17,
+    "readies" => 5,
+    "readily" => 32,
+    "readiness" => 21,
+    "reading" => 23,
+    "readings" => 5,
+    "reads" => 5,
+    "ready" => 21,
+    "real" => 17,
+    "realise" => 4,
+    "realised" => 20,
+    "realising" => 21,
+    "realism" => 1,
+    "realistic" => 16,
+    "realistically" => 16,
+    "realities" => 1,
+    "reality" => 1,
+    "realization" => 1,
+    "realizations" => 1,
+    "realize" => 4,
+    "realized" => 20,
+    "realizes" => 4,
+    "realizing" => 21,
+    "reallocate" => 4,
+    "reallocation" => 1,
+    "reallocations" => 1,
+    "really" => 32,
+    "realm" => 1,
+    "reap" => 5,
+    "reaped" => 20,
+    "reaping" => 21,
+    "reappear" => 4,
+    "reappearance" => 1,
+    "reapply" => 20,
+    "rear" => 21,
+    "reared" => 20,
+    "rearguard" => 1,
+    "rearing" => 21,
+    "rearrange" => 5,
+    "rears" => 5,
+    "rearward" => 17,
+    "reason" => 5,
+    "reasonable" => 21,
+    "reasonably" => 32,
+    "reasoned" => 20,
+    "reasoning" => 21,
+    "reasons" => 5,
+    "reassembly" => 1,
+    "reassign" => 5,
+    "reassurance" => 1,
+    "reassure" => 4,
+    "reassuring" => 21,
+    "reassuringly" => 32,
+    "reattachment" => 1,
+    "rebecca" => 2,
+    "rebel" => 5,
+    "rebellion" => 1,
+    "rebind" => 5,
+    "reboot" => 5,
+    "rebound" => 21,
+    "rebroadcast" => 21,
+    "rebuild" => 5,
+    "rebuilding" => 21,
+    "rebuilds" => 5,
+    "rebuilt" => 21,
+    "rebuke" => 5,
+    "rec" => 21,
+    "recalculate" => 4,
+    "recall" => 5,
+    "recap" => 5,
+    "recapitulation" => 1,
+    "recapped" => 20,
+    "recast" => 21,
+    "receipt" => 5,
+    "receipts" => 5,
+    "receive" => 5,
+    "received" => 20,
+    "receiver" => 1,
+    "receivers" => 5,
+    "receives" => 5,
+    "receiving" => 21,
+    "recent" => 17,
+    "recently" => 32,
+    "receptacle" => 1,
+    "receptacles" => 1,
+    "reception" => 1,
+    "receptive" => 16,
+    "recess" => 21,
+    "recesses" => 5,
+    "recipe" => 1,
+    "recipes" => 1,
+    "recipient" => 17,
+    "recipients" => 1,
+    "reciprocal" => 17,
+    "reciprocals" => 1,
+    "reciprocity" => 1,
+    "recital" => 1,
+    "recitation" => 1,
+    "recite" => 5,
+    "reckless" => 16,
+    "reckon" => 5,
+    "reckoned" => 20,
+    "reckoning" => 21,
+    "reclaim" => 5,
+    "reclaimable" => 17,
+    "reclamation" => 1,
+    "reclining" => 21,
+    "recognise" => 4,
+    "recognised" => 20,
+    "recognises" => 4,
+    "recognising" => 21,
+    "recognition" => 1,
+    "recognizable" => 20,
+    "recognize" => 4,
+    "recognized" => 20,
+    "recognizes" => 4,
+    "recognizing" => 21,
+    "recoil" => 5,
+    "recollect" => 21,
+    "recollection" => 1,
+    "recombine" => 5,
+    "recommence" => 4,
+    "recommend" => 5,
+    "recommendation" => 1,
+    "recompense" => 5,
+    "recompensing" => 21,
+    "recompile" => 5,
+    "recompiled" => 20,
+    "recompiles" => 5,
+    "recompiling" => 21,
+    "recompute" => 5,
+    "reconcile" => 4,
+    "reconciled" => 20,
+    "reconciliation" => 1,
+    "reconfigure" => 4,
+    "reconfigured" => 20,
+    "reconfigures" => 4,
+    "reconfiguring" => 21,
+    "reconnaissance" => 1,
+    "reconnect" => 5,
+    "reconsider" => 4,
+    "reconsideration" => 1,
+    "reconstitute" => 5,
+    "reconstruct" => 5,
+    "reconstructed" => 20,
+    "reconstruction" => 3,
+    "reconvert" => 5,
+    "record" => 5,
+    "recorded" => 20,
+    "recorder" => 1,
+    "recording" => 5,
+    "recordings" => 5,
+    "recourse" => 5,
+    "recover" => 21,
+    "recoverable" => 17,
+    "recovery" => 1,
+    "recreate" => 20,
+    "recreation" => 1,
+    "rectangle" => 17,
+    "rectangles" => 1,
+    "rectangular" => 16,
+    "rectify" => 4,
+    "rectilinear" => 16,
+    "rectitude" => 1,
+    "recur" => 4,
+    "recurred" => 4,
+    "recurrence" => 1,
+    "recurrent" => 17,
+    "recurring" => 21,
+    "recursion" => 1,
+    "recursive" => 17,
+    "recuse" => 4,
+    "recycle" => 5,
+    "recycled" => 20,
+    "recycles" => 4,
+    "recycling" => 21,
+    "red" => 17,
+    "redact" => 4,
+    "redacted" => 20,
+    "redaction" => 1,
+    "reddened" => 20,
+    "redder" => 16,
+    "redeem" => 5,
+    "redefine" => 5,
+    "redefinition" => 1,
+    "redesign" => 5,
+    "redirect" => 20,
+    "redirection" => 1,
+    "redistribute" => 4,
+    "redistribution" => 5,
+    "redo" => 5,
+    "redoes" => 4,
+    "redolent" => 16,
+    "redraw" => 5,
+    "redrawing" => 21,
+    "redrawn" => 20,
+    "redraws" => 5,
+    "redress" => 5,
+    "redrew" => 4,
+    "reduce" => 4,
+    "reduced" => 20,
+    "reducer" => 1,
+    "reduces" => 4,
+    "reducing" => 21,
+    "reduction" => 1,
+    "reductions" => 1,
+    "redundancies" => 1,
+    "redundancy" => 1,
+    "redundant" => 16,
+    "reed" => 3,
+    "reedy" => 16,
+    "reef" => 21,
+    "reefed" => 20,
+    "reel" => 5,
+    "reeled" => 20,
+    "reeling" => 21,
+    "reels" => 5,
+    "reevaluate" => 4,
+    "reexamine" => 5,
+    "ref" => 5,
+    "refactor" => 5,
+    "refer" => 5,
+    "referee" => 5,
+    "reference" => 5,
+    "referenced" => 20,
+    "references" => 5,
+    "referencing" => 21,
+    "referent" => 1,
+    "referential" => 16,
+    "referents" => 1,
+    "referred" => 4,
+    "referring" => 5,
+    "refers" => 5,
+    "refill" => 5,
+    "refine" => 21,
+    "refined" => 21,
+    "refinement" => 1,
+    "refinements" => 1,
+    "refinery" => 1,
+    "reflect" => 4,
+    "reflected" => 20,
+    "reflecting" => 21,
+    "reflection" => 1,
+    "reflections" => 1,
+    "reflective" => 20,
+    "reflectors" => 1,
+    "reflects" => 4,
+    "reflexive" => 17,
+    "reflexivity" => 1,
+    "reform" => 5,
+    "reformat" => 4,
+    "reformatory" => 17,
+    "reformats" => 4,
+    "reformatted" => 20,
+    "reformatting" => 5,
+    "reformer" => 17,
+    "refrain" => 5,
+    "refrained" => 20,
+    "refresh" => 5,
+    "refreshed" => 20,
+    "refresher" => 1,
+    "refreshes" => 5,
+    "refreshing" => 21,
+    "refreshingly" => 32,
+    "refreshment" => 5,
+    "refreshments" => 1,
+    "refs" => 5,
+    "refuge" => 5,
+    "refugee" => 5,
+    "refund" => 5,
+    "refusal" => 1,
+    "refuse" => 5,
+    "refutable" => 4,
+    "reg" => 1,
+    "regain" => 85,
+    "regal" => 17,
+    "regalia" => 1,
+    "regard" => 5,
+    "regarded" => 20,
+    "regarding" => 21,
+    "regardless" => 80,
+    "regards" => 5,
+    "regatta" => 1,
+    "regency" => 1,
+    "regenerate" => 21,
+    "regeneration" => 1,
+    "regent" => 17,
+    "regiment" => 5,
+    "region" => 1,
+    "regional" => 17,
+    "regions" => 1,
+    "register" => 5,
+    "registered" => 20,
+    "registering" => 21,
+    "registers" => 5,
+    "registrar" => 1,
+    "registration" => 1,
+    "registrations" => 1,
+    "registries" => 1,
+    "registry" => 1,
+    "regress" => 5,
+    "regressed" => 20,
+    "regression" => 1,
+    "regressions" => 1,
+    "regret" => 5,
+    "regretful" => 16,
+    "regrets" => 5,
+    "regretted" => 4,
+    "regroups" => 5,
+    "regular" => 17,
+    "regularisation" => 1,
+    "regularised" => 20,
+    "regularity" => 1,
+    "regularization" => 1,
+    "regularly" => 32,
+    "regulate" => 4,
+    "regulated" => 20,
+    "regulating" => 21,
+    "regulation" => 21,
+    "regulations" => 1,
+    "regulator" => 1,
+    "regurgitation" => 5,
+    "rehearse" => 5,
+    "rehearsed" => 20,
+    "rehearsing" => 21,
+    "reifying" => 21,
+    "reign" => 5,
+    "reigned" => 20,
+    "reigning" => 21,
+    "reilly" => 2,
+    "reinforcement" => 5,
+    "reinforcing" => 21,
+    "reinitialize" => 4,
+    "reinitialized" => 4,
+    "reinstall" => 5,
+    "reinterpret" => 4,
+    "reinterpretation" => 1,
+    "reintroduce" => 4,
+    "reinvent" => 4,
+    "reiterate" => 21,
+    "reject" => 5,
+    "rejected" => 20,
+    "rejecting" => 21,
+    "rejection" => 1,
+    "rejections" => 1,
+    "rejects" => 5,
+    "rejoice" => 4,
+    "rejoiced" => 20,
+    "rejoices" => 4,
+    "rejoin" => 5,
+    "relabel" => 5,
+    "relapse" => 5,
+    "relate" => 4,
+    "related" => 16,
+    "relates" => 4,
+    "relating" => 21,
+    "relation" => 5,
+    "relational" => 16,
+    "relations" => 4,
+    "relationship" => 1,
+    "relationships" => 1,
+    "relative" => 21,
+    "relatively" => 32,
+    "relatives" => 1,
+    "relativistic" => 16,
+    "relativity" => 1,
+    "relax" => 4,
+    "relaxation" => 1,
+    "relaxed" => 20,
+    "relaxes" => 4,
+    "relaxing" => 21,
+    "relay" => 21,
+    "release" => 5,
+    "released" => 4,
+    "relegated" => 20,
+    "relentless" => 16,
+    "relentlessly" => 32,
+    "relevance" => 1,
+    "relevant" => 16,
+    "reliability" => 1,
+    "reliable" => 17,
+    "reliably" => 32,
+    "reliance" => 1,
+    "reliant" => 16,
+    "relic" => 21,
+    "relics" => 5,
+    "relied" => 20,
+    "relief" => 17,
+    "relies" => 4,
+    "relieve" => 4,
+    "relieved" => 20,
+    "relieving" => 21,
+    "religion" => 5,
+    "religions" => 5,
+    "religious" => 17,
+    "religiously" => 32,
+    "relinquish" => 4,
+    "relinquished" => 20,
+    "relinquishes" => 4,
+    "relinquishing" => 21,
+    "relish" => 5,
+    "reload" => 5,
+    "relocatable" => 4,
+    "reluctance" => 1,
+    "reluctant" => 16,
+    "reluctantly" => 32,
+    "rely" => 36,
+    "relying" => 21,
+    "rem" => 21,
+    "remade" => 4,
+    "remain" => 5,
+    "remainder" => 21,
+    "remained" => 20,
+    "remaining" => 21,
+    "remains" => 5,
+    "remanded" => 20,
+    "remark" => 5,
+    "remarkable" => 5,
+    "remarkably" => 32,
+    "remarked" => 20,
+    "rembrandt" => 3,
+    "remedied" => 20,
+    "remedies" => 5,
+    "remedy" => 5,
+    "remember" => 5,
+    "remembered" => 20,
+    "remembering" => 21,
+    "remembrance" => 5,
+    "remembrances" => 5,
+    "remind" => 5,
+    "reminder" => 1,
+    "reminiscence" => 1,
+    "reminiscent" => 17,
+    "remissness" => 17,
+    "remit" => 5,
+    "remnants" => 1,
+    "remonstrance" => 1,
+    "remonstrate" => 4,
+    "remorse" => 5,
+    "remorseless" => 16,
+    "remorselessly" => 32,
+    "remote" => 21,
+    "remotely" => 32,
+    "remoter" => 17,
+    "remotest" => 16,
+    "removal" => 1,
+    "removals" => 1,
+    "remove" => 5,
+    "remunerative" => 4,
+    "renaissance" => 19,
+    "rename" => 5,
+    "renault" => 2,
+    "rend" => 5,
+    "render" => 5,
+    "rendered" => 20,
+    "rendering" => 21,
+    "renderings" => 5,
+    "renders" => 5,
+    "rendezvous" => 5,
+    "rendezvoused" => 20,
+    "rending" => 21,
+    "rendition" => 5,
+    "renditions" => 5,
+    "rends" => 5,
+    "renegotiate" => 4,
+    "renegotiation" => 1,
+    "renew" => 5,
+    "renewal" => 1,
+    "renewals" => 1,
+    "renewed" => 20,
+    "renewing" => 21,
+    "reno" => 2,
+    "renounce" => 5,
+    "renovate" => 4,
+    "renovated" => 20,
+    "renovating" => 21,
+    "renowned" => 20,
+    "rent" => 21,
+    "rental" => 17,
+    "rented" => 20,
+    "renting" => 21,
+    "renumber" => 21,
+    "reopen" => 4,
+    "reopened" => 20,
+    "reopening" => 21,
+    "reorder" => 5,
+    "reorganise" => 4,
+    "reorganization" => 1,
+    "reorganize" => 4,
+    "rep" => 5,
+    "repack" => 5,
+    "repackage" => 5,
+    "repaid" => 20,
+    "repainting" => 21,
+    "repair" => 5,
+    "reparation" => 1,
+    "repartee" => 5,
+    "repast" => 85,
+    "repay" => 21,
+    "repeat" => 5,
+    "repeatable" => 21,
+    "repeated" => 20,
+    "repeatedly" => 32,
+    "repeater" => 1,
+    "repeating" => 21,
+    "repeats" => 5,
+    "repel" => 4,
+    "repelled" => 4,
+    "repent" => 20,
+    "repentance" => 1,
+    "repentant" => 17,
+    "repented" => 20,
+    "repetition" => 5,
+    "repetitions" => 5,
+    "repetitive" => 16,
+    "rephrase" => 5,
+    "replace" => 4,
+    "replaceable" => 17,
+    "replaced" => 20,
+    "replacement" => 1,
+    "replaces" => 4,
+    "replacing" => 21,
+    "replay" => 5,
+    "replenish" => 4,
+    "replenished" => 20,
+    "replenishes" => 4,
+    "replete" => 21,
+    "replica" => 1,
+    "replicas" => 1,
+    "replicate" => 21,
+    "replicated" => 20,
+    "replicating" => 21,
+    "replication" => 21,
+    "reply" => 5,
+    "repo" => 5,
+    "report" => 5,
+    "reported" => 20,
+    "reportedly" => 32,
+    "reporter" => 5,
+    "reporting" => 21,
+    "reports" => 5,
+    "repos" => 5,
+    "repose" => 5,
+    "reposeful" => 16,
+    "reposition" => 5,
+    "repositories" => 1,
+    "repository" => 1,
+    "represent" => 4,
+    "representation" => 1,
+    "representative" => 17,
+    "representatives" => 1,
+    "represented" => 20,
+    "representing" => 21,
+    "represents" => 4,
+    "repress" => 5,
+    "reprieves" => 5,
+    "reprimand" => 5,
+    "reprint" => 21,
+    "reprisal" => 1,
+    "reproach" => 5,
+    "reproached" => 20,
+    "reproaches" => 5,
+    "reproachfully" => 32,
+    "reproduce" => 5,
+    "reproducible" => 16,
+    "reproduction" => 1,
+    "reproductive" => 17,
+    "reproof" => 21,
+    "republic" => 21,
+    "republican" => 17,
+    "republics" => 1,
+    "republish" => 4,
+    "repugnance" => 1,
+    "repugnant" => 16,
+    "repulsion" => 1,
+    "repulsive" => 16,
+    "reputation" => 1,
+    "repute" => 5,
+    "reputed" => 20,
+    "request" => 5,
+    "requested" => 20,
+    "requester" => 1,
+    "requesting" => 21,
+    "requests" => 5,
+    "require" => 5,
+    "required" => 20,
+    "requirement" => 5,
+    "requirements" => 1,
+    "requiring" => 21,
+    "requisite" => 17,
+    "requisites" => 1,
+    "requisition" => 21,
+    "requited" => 20,
+    "reran" => 5,
+    "reread" => 5,
+    "rerun" => 5,
+    "rerunning" => 4,
+    "res" => 1,
+    "rescue" => 5,
+    "rescued" => 20,
+    "rescuing" => 21,
+    "research" => 5,
+    "researched" => 20,
+    "researchers" => 1,
+    "researches" => 5,
+    "researching" => 21,
+    "resemblance" => 1,
+    "resemble" => 4,
+    "resembled" => 20,
+    "resembles" => 4,
+    "resembling" => 21,
+    "resented" => 20,
+    "resenting" => 21,
+    "resentment" => 5,
+    "reservation" => 1,
+    "reservations" => 1,
+    "reserve" => 5,
+    "reserved" => 20,
+    "reset" => 21,
+    "resetting" => 5,
+    "reside" => 21,
+    "residence" => 1,
+    "resident" => 17,
+    "residential" => 17,
+    "residents" => 1,
+    "residual" => 17,
+    "residuals" => 1,
+    "residue" => 1,
+    "resign" => 5,
+    "resignation" => 1,
+    "resigned" => 20,
+    "resilience" => 1,
+    "resilient" => 16,
+    "resin" => 5,
+    "resist" => 5,
+    "resistance" => 1,
+    "resistant" => 17,
+    "resisted" => 20,
+    "resisting" => 21,
+    "resize" => 5,
+    "resolute" => 21,
+    "resolve" => 5,
+    "resolved" => 20,
+    "resort" => 5,
+    "resounded" => 20,
+    "resource" => 5,
+    "resourcefulness" => 17,
+    "respect" => 5,
+    "respectable" => 17,
+    "respected" => 20,
+    "respectful" => 16,
+    "respectfully" => 32,
+    "respecting" => 21,
+    "respective" => 21,
+    "respectively" => 32,
+    "respects" => 5,
+    "respite" => 69,
+    "respond" => 5,
+    "responded" => 20,
+    "respondents" => 1,
+    "responder" => 1,
+    "responding" => 21,
+    "responds" => 5,
+    "response" => 1,
+    "responses" => 1,
+    "responsibilities" => 1,
+    "responsibility" => 1,
+    "responsible" => 17,
+    "responsibly" => 32,
+    "responsive" => 16,
+    "responsiveness" => 17,
+    "rest" => 69,
+    "restart" => 5,
+    "restating" => 21,
+    "restaurant" => 1,
+    "restaurants" => 1,
+    "rested" => 20,
+    "restful" => 16,
+    "resting" => 21,
+    "restive" => 21,
+    "restless" => 16,
+    "restlessly" => 32,
+    "restlessness" => 17,
+    "restoration" => 3,
+    "restorative" => 17,
+    "restore" => 5,
+    "restrain" => 5,
+    "restrained" => 20,
+    "restraint" => 1,
+    "restraints" => 1,
+    "restrict" => 20,
+    "restricted" => 20,
+    "restricting" => 21,
+    "restriction" => 1,
+    "restrictions" => 1,
+    "restrictive" => 21,
+    "restricts" => 4,
+    "restructure" => 5,
+    "restructuring" => 5,
+    "rests" => 5,
+    "resubmit" => 4,
+    "result" => 5,
+    "resultant" => 17,
+    "resulted" => 20,
+    "resulting" => 21,
+    "results" => 5,
+    "resume" => 5,
+    "resumed" => 20,
+    "resumes" => 5,
+    "resuming" => 21,
+    "resumption" => 1,
+    "resurrected" => 20,
+    "resurrection" => 3,
+    "resuscitated" => 20,
+    "retain" => 5,
+    "retained" => 20,
+    "retaining" => 21,
+    "retains" => 5,
+    "retake" => 5,
+    "retaken" => 20,
+    "retaliating" => 21,
+    "retard" => 5,
+    "retarded" => 20,
+    "retards" => 5,
+    "retention" => 1,
+    "rethink" => 5,
+    "rethinking" => 21,
+    "retinas" => 1,
+    "retire" => 5,
+    "retirement" => 1,
+    "retold" => 4,
+    "retort" => 21,
+    "retorted" => 20,
+    "retracted" => 20,
+    "retractions" => 1,
+    "retransmission" => 1,
+    "retreat" => 5,
+    "retribution" => 1,
+    "retrievable" => 5,
+    "retrieval" => 1,
+    "retrievals" => 1,
+    "retrieve" => 5,
+    "retrieved" => 20,
+    "retrieves" => 5,
+    "retrieving" => 21,
+    "retroactive" => 16,
+    "retroactively" => 32,
+    "retrod" => 5,
+    "retrogression" => 1,
+    "retrospect" => 5,
+    "retrospective" => 21,
+    "retrospectively" => 32,
+    "retry" => 21,
+    "return" => 5,
+    "returnable" => 17,
+    "reusable" => 16,
+    "reuse" => 5,
+    "rev" => 71,
+    "reveal" => 5,
+    "revealed" => 20,
+    "revealing" => 21,
+    "reveals" => 5,
+    "revel" => 5,
+    "revelation" => 3,
+    "revelations" => 3,
+    "revelled" => 4,
+    "revellers" => 1,
+    "revenge" => 5,
+    "revengeful" => 16,
+    "revenue" => 1,
+    "reverberated" => 20,
+    "reverberations" => 20,
+    "reverence" => 5,
+    "reverend" => 17,
+    "reverent" => 16,
+    "reverential" => 16,
+    "reverently" => 32,
+    "reverie" => 5,
+    "reveries" => 5,
+    "reversal" => 17,
+    "reverse" => 21,
+    "reversibility" => 1,
+    "reversible" => 17,
+    "revert" => 21,
+    "reverted" => 20,
+    "reverting" => 21,
+    "reverts" => 5,
+    "review" => 5,
+    "reviewer" => 1,
+    "revise" => 5,
+    "revision" => 5,
+    "revisions" => 5,
+    "revisit" => 5,
+    "revival" => 1,
+    "revive" => 5,
+    "revived" => 20,
+    "reviving" => 21,
+    "revocable" => 16,
+    "revocation" => 1,
+    "revoke" => 5,
+    "revoked" => 20,
+    "revoking" => 21,
+    "revolt" => 5,
+    "revolted" => 20,
+    "revolting" => 21,
+    "revolution" => 1,
+    "revolutionary" => 17,
+    "revolutionize" => 4,
+    "revolutionizing" => 21,
+    "revolutions" => 1,
+    "revolve" => 5,
+    "revolved" => 20,
+    "revolver" => 1,
+    "revolvers" => 5,
+    "revolves" => 5,
+    "revolving" => 21,
+    "reward" => 5,
+    "rewarded" => 4,
+    "rewarding" => 20,
+    "rewind" => 5,
+    "rework" => 5,
+    "rewound" => 4,
+    "rewrite" => 5,
+    "rewrites" => 5,
+    "rewriting" => 21,
+    "rewritten" => 20,
+    "rewrote" => 4,
+    "rex" => 2,
+    "rfc" => 3,
+    "rhapsody" => 5,
+    "rhine" => 2,
+    "rho" => 1,
+    "rhode" => 2,
+    "rhone" => 2,
+    "rhubarb" => 21,
+    "rhyme" => 5,
+    "rhythm" => 5,
+    "ribbed" => 20,
+    "ribbon" => 5,
+    "ribbons" => 5,
+    "rice" => 7,
+    "rich" => 23,
+    "richard" => 2,
+    "richardson" => 2,
+    "richer" => 17,
+    "riches" => 5,
+    "richest" => 16,
+    "richly" => 32,
+    "richness" => 21,
+    "rick" => 7,
+    "rickety" => 16,
+    "ricky" => 2,
+    "rid" => 4,
+    "ridden" => 20,
+    "riddle" => 7,
+    "riddles" => 5,
+    "ride" => 5,
+    "rider" => 1,
+    "riders" => 5,
+    "rides" => 5,
+    "ridge" => 5,
+    "ridges" => 5,
+    "ridicule" => 21,
+    "ridiculous" => 16,
+    "ridiculously" => 32,
+    "riding" => 21,
+    "riff" => 5,
+    "rifle" => 5,
+    "rifled" => 20,
+    "rifles" => 5,
+    "rift" => 5,
+    "rifts" => 5,
+    "rig" => 5,
+    "rigged" => 20,
+    "rigging" => 5,
+    "right" => 23,
+    "rightfully" => 32,
+    "rightly" => 32,
+    "rightmost" => 16,
+    "rights" => 5,
+    "rightward" => 16,
+    "rightwards" => 16,
+    "rigid" => 17,
+    "rigidity" => 1,
+    "rigidly" => 32,
+    "rigorous" => 16,
+    "rigorously" => 32,
+    "rigs" => 5,
+    "rimmed" => 20,
+    "rind" => 5,
+    "rinds" => 5,
+    "ring" => 21,
+    "ringing" => 21,
+    "ringlets" => 5,
+    "rings" => 65,
+    "rinse" => 5,
+    "rio" => 2,
+    "riot" => 5,
+    "rioters" => 5,
+    "rioting" => 21,
+    "riotous" => 16,
+    "rip" => 7,
+    "ripe" => 21,
+    "ripen" => 5,
+    "riper" => 1,
+    "ripley" => 2,
+    "ripped" => 20,
+    "ripper" => 17,
+    "ripple" => 5,
+    "rippled" => 20,
+    "ripples" => 5,
+    "rippling" => 21,
+    "rips" => 5,
+    "rise" => 5,
+    "risen" => 20,
+    "riser" => 1,
+    "risers" => 5,
+    "rises" => 5,
+    "rising" => 85,
+    "risk" => 5,
+    "risked" => 20,
+    "risking" => 21,
+    "risks" => 5,
+    "risky" => 16,
+    "rite" => 17,
+    "rites" => 1,
+    "ritz" => 2,
+    "rival" => 21,
+    "rivalry" => 1,
+    "rivals" => 5,
+    "river" => 21,
+    "rivers" => 2,
+    "riverside" => 19,
+    "riveted" => 20,
+    "rivulets" => 1,
+    "rna" => 1,
+    "road" => 17,
+    "roadhouse" => 1,
+    "roads" => 1,
+    "roadside" => 17,
+    "roadster" => 1,
+    "roadway" => 1,
+    "roamed" => 20,
+    "roaming" => 21,
+    "roar" => 5,
+    "roared" => 20,
+    "roaring" => 21,
+    "roast" => 21,
+    "roasted" => 20,
+    "roasting" => 21,
+    "rob" => 7,
+    "robbed" => 4,
+    "robber" => 1,
+    "robberies" => 1,
+    "robbers" => 1,
+    "robbery" => 1,
+    "robbing" => 5,
+    "robbins" => 2,
+    "robe" => 5,
+    "robed" => 20,
+    "robert" => 2,
+    "roberts" => 2,
+    "robes" => 5,
+    "robin" => 3,
+    "robinson" => 2,
+    "robot" => 1,
+    "robotics" => 17,
+    "robots" => 1,
+    "robs" => 5,
+    "robust" => 16,
+    "robustly" => 32,
+    "robustness" => 17,
+    "rock" => 7,
+    "rocked" => 20,
+    "rockefeller" => 2,
+    "rocket" => 5,
+    "rockies" => 3,
+    "rocking" => 21,
+    "rocks" => 5,
+    "rocky" => 18,
+    "rococo" => 17,
+    "rod" => 7,
+    "rode" => 5,
+    "rodeo" => 5,
+    "rodriguez" => 2,
+    "rods" => 5,
+    "roebuck" => 1,
+    "roger" => 7,
+    "rogers" => 7,
+    "rogue" => 21,
+    "rogues" => 5,
+    "roland" => 2,
+    "role" => 1,
+    "roles" => 1,
+    "roll" => 5,
+    "rollback" => 5,
+    "rolled" => 20,
+    "roller" => 5,
+    "rollicking" => 21,
+    "rolling" => 21,
+    "rolls" => 5,
+    "rom" => 19,
+    "roman" => 19,
+    "romance" => 5,
+    "romances" => 5,
+    "romanian" => 17,
+    "romans" => 3,
+    "romansh" => 2,
+    "romantic" => 17,
+    "rome" => 2,
+    "romp" => 5,
+    "romper" => 5,
+    "romping" => 21,
+    "romulus" => 2,
+    "ron" => 2,
+    "ronald" => 2,
+    "roof" => 5,
+    "roofed" => 20,
+    "roofs" => 5,
+    "room" => 21,
+    "rooms" => 5,
+    "roosevelt" => 2,
+    "root" => 7,
+    "rooted" => 20,
+    "rooting" => 21,
+    "roots" => 5,
+    "rope" => 5,
+    "ropes" => 5,
+    "rosary" => 1,
+    "rose" => 23,
+    "rosenthal" => 2,
+    "roses" => 5,
+    "rosin" => 5,
+    "ross" => 2,
+    "rosy" => 21,
+    "rot" => 5,
+    "rotate" => 20,
+    "rotated" => 20,
+    "rotates" => 4,
+    "rotating" => 21,
+    "rotation" => 21,
+    "rotational" => 17,
+    "rotations" => 21,
+    "rotogravure" => 1,
+    "rotor" => 1,
+    "rotors" => 1,
+    "rotten" => 16,
+    "rotterdam" => 2,
+    "rotting" => 5,
+    "rouble" => 1,
+    "rouged" => 20,
+    "rough" => 21,
+    "rougher" => 17,
+    "roughest" => 16,
+    "roughly" => 32,
+    "roughness" => 21,
+    "roughs" => 5,
+    "roulette" => 5,
+    "round" => 85,
+    "roundabout" => 21,
+    "rounded" => 20,
+    "rounding" => 21,
+    "rounds" => 5,
+    "rouse" => 5,
+    "roused" => 20,
+    "rouses" => 5,
+    "rout" => 5,
+    "route" => 5,
+    "routed" => 20,
+    "routes" => 5,
+    "routine" => 17,
+    "routinely" => 32,
+    "routines" => 1,
+    "routing" => 21,
+    "roux" => 1,
+    "roved" => 20,
+    "rovers" => 5,
+    "row" => 5,
+    "rowan" => 1,
+    "rowboat" => 5,
+    "rowdy" => 17,
+    "rowed" => 20,
+    "rowing" => 21,
+    "rows" => 5,
+    "royal" => 19,
+    "royalties" => 1,
+    "royalty" => 1,
+    "royce" => 2,
+    "rpm" => 3,
+    "rsa" => 1,
+    "rub" => 5,
+    "rubato" => 1,
+    "rubbed" => 20,
+    "rubber" => 21,
+    "rubbers" => 5,
+    "rubbing" => 5,
+    "rubbish" => 21,
+    "rubbishy" => 16,
+    "rube" => 1,
+    "rubicund" => 16,
+    "rubies" => 5,
+    "rubric" => 21,
+    "ruby" => 23,
+    "rudder" => 1,
+    "ruddy" => 21,
+    "rude" => 16,
+    "rudely" => 32,
+    "rudeness" => 17,
+    "rudimentary" => 17,
+    "rue" => 5,
+    "rueful" => 16,
+    "ruefully" => 32,
+    "ruffian" => 21,
+    "ruffians" => 5,
+    "ruffled" => 20,
+    "rug" => 21,
+    "rugged" => 20,
+    "ruggedness" => 21,
+    "rugs" => 5,
+    "ruin" => 5,
+    "ruination" => 1,
+    "ruined" => 20,
+    "ruining" => 21,
+    "ruinous" => 16,
+    "ruinously" => 32,
+    "ruins" => 5,
+    "rule" => 5,
+    "ruled" => 20,
+    "ruler" => 5,
+    "rulers" => 5,
+    "rules" => 5,
+    "ruling" => 21,
+    "rum" => 17,
+    "rumble" => 5,
+    "rumbling" => 21,
+    "rumblings" => 5,
+    "ruminating" => 21,
+    "rummage" => 5,
+    "rummaged" => 20,
+    "rumor" => 5,
+    "rumored" => 20,
+    "rumors" => 5,
+    "rumour" => 5,
+    "rumours" => 5,
+    "rump" => 5,
+    "rumpled" => 20,
+    "run" => 5,
+    "runaway" => 17,
+    "rung" => 21,
+    "runic" => 16,
+    "runner" => 1,
+    "runners" => 1,
+    "running" => 85,
+    "runs" => 5,
+    "runtime" => 17,
+    "rural" => 17,
+    "ruse" => 5,
+    "rush" => 23,
+    "rushed" => 20,
+    "rushes" => 5,
+    "rushing" => 21,
+    "russell" => 2,
+    "russia" => 3,
+    "russian" => 21,
+    "russians" => 5,
+    "rust" => 5,
+    "rusted" => 20,
+    "rustic" => 17,
+    "rustier" => 16,
+    "rustle" => 5,
+    "rustled" => 20,
+    "rustler" => 1,
+    "rustling" => 21,
+    "rusts" => 5,
+    "rusty" => 19,
+    "rutgers" => 2,
+    "ruthless" => 16,
+    "ruthlessly" => 32,
+    "ryan" => 2,
+    "ryder" => 2,
+    "sabbath" => 1,
+    "sable" => 17,
+    "sabre" => 5,
+    "sabres" => 5,
+    "sachem" => 1,
+    "sackcloth" => 1,
+    "sacks" => 5,
+    "sacramento" => 2,
+    "sacred" => 20,
+    "sacredness" => 21,
+    "sacrifice" => 5,
+    "sacrificed" => 20,
+    "sacrifices" => 5,
+    "sacrificing" => 21,
+    "sacrilege" => 1,
+    "sad" => 21,
+    "saddened" => 20,
+    "saddening" => 21,
+    "saddest" => 16,
+    "saddle" => 5,
+    "saddled" => 20,
+    "saddles" => 5,
+    "sadly" => 32,
+    "sadness" => 21,
+    "safari" => 5,
+    "safe" => 21,
+    "safeguard" => 5,
+    "safeguarded" => 20,
+    "safeguards" => 5,
+    "safely" => 32,
+    "safer" => 17,
+    "safes" => 5,
+    "safest" => 16,
+    "safety" => 5,
+    "saffron" => 21,
+    "sagacity" => 1,
+    "sage" => 21,
+    "sagely" => 32,
+    "sages" => 5,
+    "sago" => 1,
+    "said" => 20,
+    "sail" => 5,
+    "sailed" => 20,
+    "sailfish" => 1,
+    "sailing" => 21,
+    "sailor" => 1,
+    "sailors" => 1,
+    "sails" => 5,
+    "saint" => 5,
+    "saintly" => 48,
+    "saints" => 5,
+    "sake" => 1,
+    "saki" => 3,
+    "sal" => 2,
+    "salads" => 1,
+    "salaries" => 5,
+    "salary" => 21,
+    "sale" => 1,
+    "sales" => 1,
+    "salesman" => 1,
+    "salesmen" => 1,
+    "salient" => 17,
+    "sallied" => 20,
+    "sallies" => 5,
+    "sallow" => 21,
+    "sally" => 39,
+    "salmon" => 21,
+    "salon" => 1,
+    "salons" => 1,
+    "saloon" => 1,
+    "saloons" => 1,
+    "salsa" => 5,
+    "salt" => 23,
+    "salts" => 5,
+    "salubrious" => 16,
+    "salutation" => 1,
+    "salutations" => 1,
+    "salute" => 5,
+    "saluted" => 20,
+    "salvation" => 5,
+    "salvo" => 5,
+    "sam" => 3,
+    "samba" => 5,
+    "same" => 272,
+    "samoan" => 17,
+    "sample" => 5,
+    "sampled" => 20,
+    "sampler" => 1,
+    "samplers" => 5,
+    "samples" => 5,
+    "sampling" => 21,
+    "samson" => 2,
+    "samuel" => 2,
+    "san" => 3,
+    "sanction" => 5,
+    "sanctioned" => 20,
+    "sanctions" => 5,
+    "sand" => 21,
+    "sandals" => 1,
+    "sandbox" => 5,
+    "sander" => 17,
+    "sanders" => 23,
+    "sands" => 5,
+    "sandwich" => 21,
+    "sandwiched" => 20,
+    "sandwiches" => 5,
+    "sandy" => 19,
+    "sane" => 16,
+    "sanely" => 32,
+    "sang" => 7,
+    "sanguinary" => 17,
+    "sanguine" => 21,
+    "sanitation" => 1,
+    "sanitize" => 4,
+    "sanitized" => 20,
+    "sanitizes" => 4,
+    "sanity" => 1,
+    "sank" => 4,
+    "sans" => 81,
+    "sanskrit" => 17,
+    "santa" => 3,
+    "sapling" => 1,
+    "sapphire" => 17,
+    "sappy" => 16,
+    "sarah" => 2,
+    "sarcasms" => 1,
+    "sardines" => 5,
+    "sardonic" => 16,
+    "sash" => 5,
+    "sashes" => 5,
+    "sass" => 5,
+    "sat" => 21,
+    "satan" => 3,
+    "satellite" => 5,
+    "satiate" => 20,
+    "satiated" => 20,
+    "satin" => 21,
+    "satirical" => 16,
+    "satisfaction" => 1,
+    "satisfactorily" => 32,
+    "satisfactory" => 17,
+    "satisfied" => 20,
+    "satisfies" => 4,
+    "satisfy" => 4,
+    "satisfying" => 21,
+    "saturate" => 21,
+    "saturated" => 20,
+    "saturates" => 5,
+    "saturating" => 21,
+    "saturation" => 21,
+    "saturday" => 5,
+    "saturdays" => 5,
+    "sauce" => 5,
+    "saucepan" => 5,
+    "saucepans" => 5,
+    "saucer" => 5,
+    "saunders" => 2,
+    "sauntered" => 20,
+    "sauntering" => 21,
+    "sausages" => 5,
+    "savage" => 23,
+    "savagely" => 32,
+    "savagery" => 1,
+    "savages" => 5,
+    "savannah" => 3,
+    "save" => 581,
+    "saved" => 20,
+    "saver" => 1,
+    "saves" => 5,
+    "saving" => 85,
+    "savings" => 581,
+    "saviour" => 1,
+    "savors" => 5,
+    "savory" => 17,
+    "savoury" => 17,
+    "savoy" => 3,
+    "savvy" => 21,
+    "saw" => 5,
+    "sawdust" => 5,
+    "sawed" => 20,
+    "sawyer" => 3,
+    "sax" => 5,
+    "saxon" => 19,
+    "saxons" => 1,
+    "saxony" => 3,
+    "saxophones" => 5,
+    "say" => 5,
+    "saying" => 21,
+    "sayings" => 5,
+    "says" => 5,
+    "scaffold" => 5,
+    "scaffolding" => 21,
+    "scalable" => 16,
+    "scalar" => 17,
+    "scalars" => 1,
+    "scale" => 5,
+    "scaled" => 20,
+    "scales" => 5,
+    "scaling" => 21,
+    "scalloped" => 20,
+    "scalp" => 5,
+    "scalped" => 20,
+    "scalping" => 21,
+    "scaly" => 17,
+    "scamper" => 5,
+    "scampered" => 20,
+    "scan" => 5,
+    "scandal" => 5,
+    "scandals" => 5,
+    "scandinavia" => 2,
+    "scandinavian" => 17,
+    "scanned" => 20,
+    "scanner" => 1,
+    "scanners" => 1,
+    "scanning" => 5,
+    "scans" => 5,
+    "scant" => 21,
+    "scanty" => 16,
+    "scar" => 5,
+    "scarce" => 16,
+    "scarcely" => 32,
+    "scare" => 21,
+    "scarecrows" => 5,
+    "scared" => 20,
+    "scarf" => 5,
+    "scarfs" => 5,
+    "scarify" => 4,
+    "scaring" => 21,
+    "scarlet" => 21,
+    "scary" => 17,
+    "scat" => 5,
+    "scatter" => 5,
+    "scattered" => 20,
+    "scattering" => 21,
+    "scatters" => 5,
+    "scavenge" => 4,
+    "scenario" => 1,
+    "scenarios" => 1,
+    "scene" => 5,
+    "scenery" => 1,
+    "scenes" => 5,
+    "scent" => 5,
+    "scents" => 5,
+    "sceptic" => 17,
+    "sceptically" => 32,
+    "scepticism" => 1,
+    "schedule" => 5,
+    "scheduled" => 21,
+    "scheduler" => 1,
+    "schedulers" => 1,
+    "schedules" => 5,
+    "scheduling" => 21,
+    "schema" => 1,
+    "schemas" => 1,
+    "scheme" => 5,
+    "schemer" => 1,
+    "schemes" => 5,
+    "scheming" => 21,
+    "scholar" => 1,
+    "scholarly" => 32,
+    "scholars" => 1,
+    "scholarship" => 5,
+    "school" => 5,
+    "schoolboy" => 17,
+    "schoolboys" => 1,
+    "schoolgirl" => 5,
+    "schoolhouse" => 1,
+    "schooling" => 21,
+    "schoolmaster" => 5,
+    "schoolmates" => 1,
+    "schoolroom" => 1,
+    "schools" => 5,
+    "schubert" => 2,
+    "sci" => 1,
+    "science" => 5,
+    "sciences" => 5,
+    "scientific" => 16,
+    "scientist" => 1,
+    "scientists" => 1,
+    "scintillating" => 21,
+    "scion" => 1,
+    "scissor" => 5,
+    "scissoring" => 21,
+    "scissors" => 5,
+    "scoffing" => 21,
+    "scold" => 5,
+    "scolded" => 20,
+    "scolding" => 21,
+    "scoop" => 5,
+    "scooped" => 20,
+    "scope" => 5,
+    "scopes" => 5,
+    "scorched" => 20,
+    "scorching" => 21,
+    "score" => 5,
+    "scored" => 20,
+    "scorer" => 1,
+    "scores" => 5,
+    "scoring" => 21,
+    "scorn" => 5,
+    "scorned" => 20,
+    "scornful" => 16,
+    "scornfully" => 32,
+    "scotch" => 23,
+    "scotia" => 2,
+    "scotland" => 2,
+    "scots" => 1,
+    "scott" => 3,
+    "scottish" => 17,
+    "scoundrel" => 1,
+    "scoundrels" => 1,
+    "scoured" => 20,
+    "scourge" => 5,
+    "scouring" => 21,
+    "scouted" => 20,
+    "scouting" => 21,
+    "scraggy" => 16,
+    "scrambled" => 20,
+    "scrambling" => 21,
+    "scrape" => 5,
+    "scraped" => 20,
+    "scraper" => 1,
+    "scraping" => 21,
+    "scrapped" => 4,
+    "scratch" => 21,
+    "scratched" => 20,
+    "scratching" => 21,
+    "scrawl" => 5,
+    "scrawled" => 20,
+    "scrawny" => 16,
+    "scream" => 5,
+    "screamed" => 20,
+    "screaming" => 21,
+    "screams" => 5,
+    "screech" => 5,
+    "screen" => 5,
+    "screening" => 21,
+    "screenplay" => 5,
+    "screens" => 5,
+    "screw" => 5,
+    "screwed" => 20,
+    "screwing" => 21,
+    "scribble" => 5,
+    "scribbled" => 20,
+    "scribbling" => 21,
+    "script" => 5,
+    "scripting" => 21,
+    "scripts" => 5,
+    "scriptural" => 16,
+    "scripture" => 1,
+    "scroll" => 5,
+    "scrollable" => 16,
+    "scrollbar" => 1,
+    "scrolled" => 20,
+    "scrolling" => 21,
+    "scrolls" => 5,
+    "scrub" => 21,
+    "scrubbed" => 20,
+    "scrubbing" => 5,
+    "scruples" => 5,
+    "scrupulous" => 16,
+    "scrupulously" => 32,
+    "scrutinised" => 20,
+    "scrutinized" => 20,
+    "scrutiny" => 5,
+    "scudded" => 4,
+    "scudding" => 21,
+    "scuffle" => 5,
+    "scuffling" => 21,
+    "sculpt" => 5,
+    "sculptor" => 1,
+    "sculptors" => 1,
+    "sculpture" => 5,
+    "scurried" => 20,
+    "scurvy" => 17,
+    "scuttle" => 5,
+    "sea" => 1,
+    "seaboard" => 1,
+    "seafaring" => 17,
+    "seagull" => 5,
+    "seal" => 5,
+    "sealed" => 20,
+    "sealing" => 21,
+    "seals" => 5,
+    "seaman" => 1,
+    "seamed" => 20,
+    "seamen" => 5,
+    "seamless" => 16,
+    "sean" => 2,
+    "seaports" => 1,
+    "sear" => 21,
+    "search" => 5,
+    "searched" => 20,
+    "searcher" => 1,
+    "searchers" => 5,
+    "searches" => 5,
+    "searching" => 21,
+    "searchingly" => 32,
+    "seared" => 20,
+    "seas" => 1,
+    "seashore" => 1,
+    "seaside" => 19,
+    "season" => 5,
+    "seasonable" => 21,
+    "seasoned" => 20,
+    "seasons" => 5,
+    "seat" => 5,
+    "seated" => 20,
+    "seating" => 21,
+    "seats" => 5,
+    "seaward" => 16,
+    "sebastian" => 2,
+    "sec" => 3,
+    "secant" => 17,
+    "secluded" => 20,
+    "seclusion" => 1,
+    "second" => 21,
+    "secondarily" => 32,
+    "secondary" => 17,
+    "secondly" => 32,
+    "seconds" => 5,
+    "secrecy" => 1,
+    "secret" => 21,
+    "secretary" => 7,
+    "secreted" => 20,
+    "secreting" => 21,
+    "secretive" => 21,
+    "secretly" => 32,
+    "secrets" => 5,
+    "sect" => 1,
+    "section" => 5,
+    "sections" => 5,
+    "sector" => 1,
+    "sectors" => 1,
+    "secure" => 20,
+    "secured" => 20,
+    "securely" => 32,
+    "securer" => 17,
+    "secures" => 4,
+    "securing" => 21,
+    "securities" => 1,
+    "security" => 1,
+    "sed" => 2,
+    "sedately" => 32,
+    "sedative" => 21,
+    "sedentary" => 17,
+    "sediment" => 5,
+    "seduction" => 1,
+    "seductions" => 1,
+    "seductive" => 16,
+    "sedulous" => 16,
+    "see" => 5,
+    "seed" => 5,
+    "seeded" => 20,
+    "seeder" => 1,
+    "seeding" => 21,
+    "seeds" => 5,
+    "seedy" => 16,
+    "seeing" => 533,
+    "seek" => 5,
+    "seeking" => 21,
+    "seeks" => 5,
+    "seem" => 4,
+    "seemed" => 20,
+    "seeming" => 21,
+    "seemingly" => 32,
+    "seems" => 4,
+    "seen" => 5,
+    "sees" => 5,
+    "segment" => 5,
+    "segmentation" => 1,
+    "segmented" => 20,
+    "segments" => 5,
+    "segregate" => 21,
+    "segregated" => 20,
+    "segue" => 5,
+    "seize" => 4,
+    "seized" => 20,
+    "seizes" => 4,
+    "seizing" => 21,
+    "seizure" => 5,
+    "seizures" => 5,
+    "seldom" => 32,
+    "select" => 20,
+    "selected" => 20,
+    "selecting" => 21,
+    "selection" => 1,
+    "selections" => 1,
+    "selective" => 20,
+    "selectively" => 32,
+    "selectivity" => 1,
+    "selector" => 1,
+    "selectors" => 1,
+    "selects" => 4,
+    "self" => 277,
+    "selfish" => 16,
+    "selfishness" => 17,
+    "sell" => 5,
+    "seller" => 1,
+    "sellers" => 7,
+    "selling" => 21,
+    "selves" => 1,
+    "semantic" => 17,
+    "semantically" => 17,
+    "semantics" => 1,
+    "semaphore" => 5,
+    "semaphores" => 5,
+    "semblance" => 1,
+    "semi" => 1,
+    "semicircle" => 5,
+    "semicolon" => 1,
+    "semicolons" => 1,
+    "semiconductors" => 1,
+    "semiotics" => 17,
+    "senate" => 3,
+    "senator" => 1,
+    "senators" => 1,
+    "send" => 5,
+    "sender" => 1,
+    "senders" => 5,
+    "sending" => 21,
+    "sends" => 5,
+    "senile" => 17,
+    "senility" => 1,
+    "senior" => 19,
+    "sensation" => 1,
+    "sensational" => 16,
+    "sensationalism" => 1,
+    "sensations" => 1,
+    "sense" => 5,
+    "senseless" => 16,
+    "senselessness" => 17,
+    "senses" => 5,
+    "sensibilities" => 1,
+    "sensibility" => 1,
+    "sensible" => 17,
+    "sensibly" => 32,
+    "sensitive" => 17,
+    "sensitively" => 32,
+    "sensitiveness" => 17,
+    "sensitivity" => 1,
+    "sensor" => 1,
+    "sensors" => 1,
+    "sensuous" => 16,
+    "sensuously" => 32,
+    "sent" => 5,
+    "sentence" => 5,
+    "sentenced" => 20,
+    "sentences" => 5,
+    "sentiment" => 1,
+    "sentimental" => 16,
+    "sentimentality" => 1,
+    "sentiments" => 1,
+    "sentinel" => 5,
+    "sentinels" => 5,
+    "sentry" => 1,
+    "seoul" => 2,
+    "separable" => 16,
+    "separate" => 21,
+    "separated" => 20,
+    "separately" => 32,
+    "separates" => 5,
+    "separating" => 21,
+    "separation" => 21,
+    "separator" => 1,
+    "separators" => 1,
+    "sept" => 2,
+    "september" => 2,
+    "septic" => 17,
+    "sepulchral" => 16,
+    "sepulchre" => 5,
+    "sequel" => 1,
+    "sequence" => 5,
+    "sequencer" => 1,
+    "sequences" => 5,
+    "sequencing" => 21,
+    "sequential" => 16,
+    "sequentially" => 32,
+    "sequoia" => 1,
+    "serape" => 1,
+    "serbian" => 17,
+    "serendipity" => 1,
+    "serene" => 21,
+    "serenely" => 32,
+    "serenity" => 1,
+    "serfs" => 1,
+    "serge" => 5,
+    "sergeant" => 1,
+    "sergei" => 2,
+    "serial" => 21,
+    "serialisation" => 1,
+    "serialise" => 4,
+    "serialised" => 20,
+    "serialising" => 21,
+    "serialization" => 1,
+    "serializations" => 1,
+    "serialize" => 4,
+    "serialized" => 20,
+    "serializes" => 4,
+    "serializing" => 21,
+    "serially" => 32,
+    "serials" => 5,
+    "series" => 1,
+    "serif" => 17,
+    "serious" => 16,
+    "seriously" => 32,
+    "seriousness" => 17,
+    "sermon" => 5,
+    "sermons" => 5,
+    "serpent" => 5,
+    "serpentine" => 21,
+    "serpents" => 5,
+    "servant" => 5,
+    "servants" => 5,
+    "serve" => 5,
+    "served" => 20,
+    "server" => 1,
+    "servers" => 1,
+    "serves" => 5,
+    "service" => 5,
+    "serviceability" => 1,
+    "serviceable" => 16,
+    "serviced" => 4,
+    "services" => 5,
+    "servicing" => 5,
+    "serving" => 21,
+    "servitude" => 1,
+    "servo" => 5,
+    "session" => 5,
+    "sessions" => 5,
+    "set" => 23,
+    "sets" => 5,
+    "settable" => 5,
+    "settee" => 1,
+    "setter" => 5,
+    "setters" => 5,
+    "setting" => 21,
+    "settings" => 5,
+    "settle" => 5,
+    "settled" => 20,
+    "settlement" => 1,
+    "settler" => 1,
+    "settlers" => 1,
+    "settles" => 5,
+    "settling" => 21,
+    "setup" => 5,
+    "setups" => 5,
+    "seven" => 1,
+    "seventeenth" => 17,
+    "seventh" => 17,
+    "several" => 144,
+    "severe" => 16,
+    "severed" => 20,
+    "severely" => 32,
+    "severer" => 16,
+    "severing" => 21,
+    "severity" => 1,
+    "severn" => 2,
+    "sew" => 5,
+    "sewed" => 20,
+    "sewing" => 21,
+    "sewn" => 4,
+    "sews" => 5,
+    "sex" => 5,
+    "sexes" => 5,
+    "sexy" => 16,
+    "shabbier" => 17,
+    "shabbily" => 32,
+    "shabby" => 20,
+    "shackles" => 5,
+    "shade" => 5,
+    "shaded" => 16,
+    "shades" => 5,
+    "shading" => 21,
+    "shadow" => 21,
+    "shadowed" => 20,
+    "shadowing" => 21,
+    "shadows" => 5,
+    "shadowy" => 16,
+    "shady" => 16,
+    "shaft" => 5,
+    "shag" => 21,
+    "shah" => 3,
+    "shake" => 5,
+    "shaken" => 21,
+    "shakes" => 5,
+    "shakespeare" => 7,
+    "shaking" => 21,
+    "shaky" => 16,
+    "shall" => 8,
+    "shallow" => 21,
+    "shallows" => 5,
+    "sham" => 21,
+    "shame" => 21,
+    "shamefaced" => 16,
+    "shameful" => 16,
+    "shamefully" => 32,
+    "shameless" => 16,
+    "shamelessly" => 32,
+    "shan't" => 4,
+    "shane" => 2,
+    "shanghai" => 7,
+    "shank" => 21,
+    "shannon" => 2,
+    "shanty" => 21,
+    "shape" => 5,
+    "shaped" => 20,
+    "shapeless" => 16,
+    "shapely" => 16,
+    "shapes" => 5,
+    "shaping" => 21,
+    "shard" => 5,
+    "shards" => 5,
+    "share" => 5,
+    "shareable" => 17,
+    "shared" => 20,
+    "shares" => 5,
+    "shareware" => 1,
+    "sharing" => 21,
+    "shark" => 5,
+    "sharks" => 5,
+    "sharon" => 3,
+    "sharp" => 23,
+    "sharpened" => 20,
+    "sharpening" => 21,
+    "sharper" => 17,
+    "sharply" => 32,
+    "shatter" => 5,
+    "shattered" => 20,
+    "shaun" => 2,
+    "shave" => 5,
+    "shaved" => 20,
+    "shaven" => 20,
+    "shaves" => 5,
+    "shaving" => 21,
+    "shaw" => 2,
+    "shawl" => 5,
+    "shawls" => 5,
+    "she" => 256,
+    "shear" => 21,
+    "shears" => 5,
+    "sheathed" => 20,
+    "sheathing" => 21,
+    "sheaves" => 5,
+    "shebang" => 1,
+    "shed" => 5,
+    "shedding" => 5,
+    "sheen" => 21,
+    "sheep" => 1,
+    "sheepish" => 16,
+    "sheepishly" => 32,
+    "sheer" => 21,
+    "sheered" => 20,
+    "sheet" => 5,
+    "sheets" => 5,
+    "sheik" => 1,
+    "shelf" => 1,
+    "shell" => 7,
+    "shelley" => 2,
+    "shelling" => 21,
+    "shells" => 5,
+    "shelter" => 5,
+    "sheltered" => 20,
+    "sheltering" => 21,
+    "shelved" => 20,
+    "shelves" => 5,
+    "shenanigans" => 1,
+    "shepherd" => 7,
+    "shepherd's" => 1,
+    "sheridan" => 2,
+    "sheriff" => 5,
+    "sheriffs" => 5,
+    "sherlock" => 6,
+    "sherry" => 3,
+    "sherwood" => 2,
+    "shield" => 5,
+    "shields" => 7,
+    "shift" => 5,
+    "shifted" => 20,
+    "shifting" => 21,
+    "shiftless" => 16,
+    "shiftlessness" => 17,
+    "shifts" => 5,
+    "shilling" => 21,
+    "shillings" => 5,
+    "shim" => 5,
+    "shimmer" => 5,
+    "shimmering" => 21,
+    "shimming" => 5,
+    "shims" => 5,
+    "shin" => 5,
+    "shine" => 5,
+    "shines" => 5,
+    "shingle" => 5,
+    "shingles" => 5,
+    "shining" => 21,
+    "shiny" => 17,
+    "ship" => 5,
+    "shipped" => 20,
+    "shipping" => 5,
+    "ships" => 5,
+    "shipwreck" => 5,
+    "shipwrecks" => 5,
+    "shirt" => 5,
+    "shirts" => 5,
+    "shirtwaist" => 1,
+    "shiva" => 2,
+    "shiver" => 5,
+    "shivered" => 20,
+    "shivering" => 21,
+    "shoal" => 21,
+    "shock" => 21,
+    "shocked" => 20,
+    "shocking" => 21,
+    "shocks" => 5,
+    "shoe" => 5,
+    "shoes" => 5,
+    "shone" => 4,
+    "shooing" => 21,
+    "shook" => 21,
+    "shoot" => 5,
+    "shooter" => 1,
+    "shooting" => 21,
+    "shoots" => 5,
+    "shop" => 5,
+    "shopping" => 21,
+    "shops" => 5,
+    "shore" => 5,
+    "shores" => 5,
+    "short" => 87,
+    "shortage" => 1,
+    "shortcoming" => 1,
+    "shortcomings" => 1,
+    "shortcut" => 5,
+    "shortcuts" => 5,
+    "shorten" => 85,
+    "shortened" => 20,
+    "shortening" => 21,
+    "shortens" => 85,
+    "shorter" => 17,
+    "shortest" => 16,
+    "shorthand" => 5,
+    "shortly" => 32,
+    "shorts" => 5,
+    "shortsighted" => 16,
+    "shot" => 21,
+    "shots" => 5,
+    "should" => 8,
+    "shoulder" => 5,
+    "shouldered" => 20,
+    "shoulders" => 5,
+    "shouldn't" => 4,
+    "shout" => 5,
+    "shouted" => 20,
+    "shouting" => 21,
+    "shouts" => 5,
+    "shove" => 5,
+    "shoved" => 20,
+    "shovel" => 5,
+    "shovels" => 5,
+    "shoves" => 5,
+    "shoving" => 21,
+    "show" => 5,
+    "showcase" => 5,
+    "showcased" => 20,
+    "showcases" => 5,
+    "showcasing" => 21,
+    "showed" => 20,
+    "shower" => 5,
+    "showered" => 20,
+    "showers" => 5,
+    "showing" => 21,
+    "showman" => 1,
+    "shown" => 4,
+    "shows" => 5,
+    "showy" => 16,
+    "shrank" => 5,
+    "shred" => 21,
+    "shreds" => 5,
+    "shrewd" => 16,
+    "shrewdly" => 32,
+    "shrewdness" => 17,
+    "shriek" => 5,
+    "shrieked" => 20,
+    "shrieks" => 5,
+    "shrift" => 1,
+    "shrill" => 21,
+    "shrilly" => 48,
+    "shrimp" => 5,
+    "shrine" => 5,
+    "shrink" => 5,
+    "shrinking" => 21,
+    "shrinks" => 5,
+    "shrivelled" => 20,
+    "shroud" => 5,
+    "shrouded" => 20,
+    "shrubbery" => 1,
+    "shrubs" => 5,
+    "shrug" => 5,
+    "shrugged" => 4,
+    "shrugging" => 5,
+    "shrunk" => 20,
+    "shucks" => 5,
+    "shudder" => 5,
+    "shuddered" => 20,
+    "shuddering" => 21,
+    "shuffle" => 5,
+    "shuffled" => 20,
+    "shuffles" => 5,
+    "shuffling" => 21,
+    "shun" => 4,
+    "shunned" => 4,
+    "shunting" => 21,
+    "shut" => 21,
+    "shutdown" => 1,
+    "shuts" => 5,
+    "shutter" => 5,
+    "shuttered" => 20,
+    "shutters" => 5,
+    "shutting" => 5,
+    "shuttle" => 5,
+    "shuttlecocks" => 5,
+    "shy" => 21,
+    "shying" => 21,
+    "shyly" => 32,
+    "siam" => 2,
+    "siberian" => 17,
+    "sibilants" => 1,
+    "sibling" => 1,
+    "siblings" => 1,
+    "sichuan" => 2,
+    "sick" => 21,
+    "sickbed" => 1,
+    "sicken" => 21,
+    "sickened" => 20,
+    "sickening" => 21,
+    "sickness" => 21,
+    "sid" => 2,
+    "side" => 21,
+    "sideboard" => 5,
+    "sided" => 20,
+    "sidelong" => 16,
+    "sides" => 5,
+    "sidesteps" => 5,
+    "sidewalk" => 1,
+    "sidewalks" => 1,
+    "sideways" => 17,
+    "sidled" => 20,
+    "sidling" => 21,
+    "sidney" => 2,
+    "siege" => 5,
+    "sierra" => 1,
+    "sieve" => 5,
+    "sift" => 5,
+    "sifting" => 21,
+    "sifts" => 5,
+    "sigh" => 5,
+    "sighed" => 20,
+    "sighing" => 21,
+    "sighs" => 5,
+    "sight" => 5,
+    "sighted" => 20,
+    "sights" => 5,
+    "sightseeing" => 5,
+    "sigma" => 1,
+    "sign" => 5,
+    "signal" => 21,
+    "signaled" => 20,
+    "signaling" => 21,
+    "signalled" => 4,
+    "signalling" => 5,
+    "signals" => 5,
+    "signatory" => 17,
+    "signature" => 17,
+    "signatures" => 1,
+    "signed" => 20,
+    "signer" => 1,
+    "signers" => 1,
+    "signet" => 1,
+    "significance" => 1,
+    "significant" => 17,
+    "significantly" => 32,
+    "signification" => 5,
+    "signified" => 20,
+    "signifies" => 4,
+    "signify" => 4,
+    "signifying" => 21,
+    "signing" => 21,
+    "signpost" => 5,
+    "signs" => 5,
+    "silence" => 5,
+    "silenced" => 20,
+    "silences" => 5,
+    "silencing" => 21,
+    "silent" => 17,
+    "silently" => 32,
+    "silhouette" => 5,
+    "silicon" => 1,
+    "silk" => 5,
+    "silken" => 5,
+    "sill" => 17,
+    "silly" => 17,
+    "silo" => 5,
+    "silos" => 5,
+    "silver" => 21,
+    "silvered" => 20,
+    "similar" => 17,
+    "similarities" => 1,
+    "similarity" => 1,
+    "similarly" => 32,
+    "simmering" => 21,
+    "simon" => 3,
+    "simpering" => 21,
+    "simple" => 21,
+    "simpler" => 17,
+    "simplest" => 16,
+    "simpleton" => 1,
+    "simplex" => 17,
+    "simplicity" => 1,
+    "simplification" => 5,
+    "simplifications" => 5,
+    "simplified" => 20,
+    "simplifies" => 4,
+    "simplify" => 4,
+    "simplifying" => 21,
+    "simplistic" => 16,
+    "simply" => 32,
+    "simpson" => 2,
+    "simulacra" => 1,
+    "simulate" => 20,
+    "simulated" => 20,
+    "simulates" => 4,
+    "simulating" => 21,
+    "simulation" => 21,
+    "simulations" => 1,
+    "simulator" => 1,
+    "simultaneous" => 16,
+    "simultaneously" => 32,
+    "sin" => 5,
+    "sinai" => 2,
+    "since" => 576,
+    "sincere" => 16,
+    "sincerely" => 32,
+    "sincerest" => 16,
+    "sincerity" => 1,
+    "sinclair" => 2,
+    "sine" => 1,
+    "sinewy" => 16,
+    "sinful" => 16,
+    "sing" => 5,
+    "singapore" => 2,
+    "singed" => 20,
+    "singer" => 3,
+    "singers" => 5,
+    "singh" => 2,
+    "singing" => 21,
+    "single" => 21,
+    "singleton" => 3,
+    "singletons" => 1,
+    "singly" => 32,
+    "sings" => 5,
+    "singular" => 17,
+    "singularity" => 1,
+    "singularly" => 32,
+    "sinhalese" => 17,
+    "sinister" => 16,
+    "sink" => 5,
+    "sinking" => 21,
+    "sinks" => 5,
+    "sinless" => 16,
+    "sinned" => 4,
+    "sins" => 5,
+    "sinuous" => 16,
+    "sioux" => 3,
+    "sip" => 5,
+    "sir" => 7,
+    "sister" => 5,
+    "sisters" => 5,
+    "sit" => 5,
+    "site" => 5,
+    "sites" => 5,
+    "sits" => 5,
+    "sitter" => 1,
+    "sitting" => 21,
+    "situated" => 20,
+    "situation" => 21,
+    "situations" => 20,
+    "six" => 1,
+    "sixpence" => 1,
+    "sixteenth" => 17,
+    "sixth" => 21,
+    "sixths" => 1,
+    "sixtieth" => 17,
+    "sizable" => 16,
+    "size" => 5,
+    "sized" => 20,
+    "sizes" => 5,
+    "sizing" => 21,
+    "skeleton" => 5,
+    "skeletons" => 5,
+    "sketch" => 21,
+    "sketched" => 20,
+    "sketches" => 5,
+    "sketching" => 21,
+    "sketchy" => 16,
+    "skew" => 21,
+    "skewed" => 20,
+    "skewing" => 21,
+    "ski" => 5,
+    "skid" => 5,
+    "skies" => 5,
+    "skiff" => 5,
+    "skiffs" => 5,
+    "skilful" => 16,
+    "skilfully" => 32,
+    "skill" => 21,
+    "skilled" => 20,
+    "skillet" => 5,
+    "skills" => 5,
+    "skim" => 21,
+    "skimmed" => 4,
+    "skimming" => 5,
+    "skims" => 5,
+    "skin" => 5,
+    "skinned" => 20,
+    "skinning" => 21,
+    "skinny" => 21,
+    "skins" => 5,
+    "skip" => 5,
+    "skipped" => 4,
+    "skipper" => 5,
+    "skipping" => 5,
+    "skips" => 5,
+    "skirmishes" => 5,
+    "skirt" => 5,
+    "skirted" => 20,
+    "skirting" => 21,
+    "skirts" => 5,
+    "skittishness" => 17,
+    "skull" => 5,
+    "sky" => 5,
+    "skylark" => 5,
+    "skylarking" => 21,
+    "skylight" => 1,
+    "slab" => 21,
+    "slabs" => 5,
+    "slack" => 21,
+    "slacken" => 21,
+    "slackened" => 20,
+    "slackening" => 21,
+    "slain" => 5,
+    "slaked" => 20,
+    "slam" => 5,
+    "slammed" => 20,
+    "slander" => 5,
+    "slang" => 5,
+    "slant" => 21,
+    "slanting" => 21,
+    "slap" => 21,
+    "slapped" => 4,
+    "slapping" => 21,
+    "slash" => 517,
+    "slashed" => 20,
+    "slashes" => 5,
+    "slate" => 21,
+    "slated" => 20,
+    "slates" => 5,
+    "slaughter" => 5,
+    "slave" => 5,
+    "slavery" => 17,
+    "slaves" => 5,
+    "slavic" => 17,
+    "slavonic" => 18,
+    "sled" => 5,
+    "sledge" => 5,
+    "sledgehammer" => 21,
+    "sledges" => 5,
+    "sleek" => 21,
+    "sleep" => 5,
+    "sleeper" => 5,
+    "sleepers" => 5,
+    "sleepiest" => 16,
+    "sleepily" => 32,
+    "sleeping" => 21,
+    "sleepless" => 16,
+    "sleeplessness" => 17,
+    "sleeps" => 5,
+    "sleepy" => 17,
+    "sleeve" => 5,
+    "sleeves" => 5,
+    "sleigh" => 21,
+    "slender" => 16,
+    "slept" => 4,
+    "sleuth" => 5,
+    "slew" => 5,
+    "slice" => 21,
+    "sliced" => 20,
+    "slices" => 5,
+    "slicing" => 21,
+    "slick" => 21,
+    "slicked" => 20,
+    "slid" => 4,
+    "slide" => 5,
+    "slides" => 5,
+    "sliding" => 21,
+    "slight" => 21,
+    "slighted" => 20,
+    "slighter" => 17,
+    "slightest" => 16,
+    "slightly" => 32,
+    "slim" => 21,
+    "slims" => 5,
+    "slink" => 21,
+    "slip" => 5,
+    "slipped" => 20,
+    "slipper" => 21,
+    "slippers" => 5,
+    "slippery" => 16,
+    "slipping" => 5,
+    "slips" => 5,
+    "slit" => 21,
+    "slits" => 5,
+    "slitting" => 5,
+    "sloane" => 3,
+    "slog" => 5,
+    "slogan" => 1,
+    "sloop" => 1,
+    "slop" => 5,
+    "slope" => 21,
+    "slopes" => 5,
+    "sloping" => 21,
+    "sloppy" => 16,
+    "slops" => 5,
+    "slosh" => 5,
+    "slot" => 5,
+    "sloth" => 5,
+    "slothful" => 16,
+    "slots" => 5,
+    "slotted" => 20,
+    "slouch" => 5,
+    "slough" => 5,
+    "slovak" => 17,
+    "slovenian" => 17,
+    "slovenly" => 48,
+    "slow" => 21,
+    "slowdown" => 1,
+    "slowdowns" => 1,
+    "slowed" => 20,
+    "slower" => 17,
+    "slowest" => 16,
+    "slowing" => 21,
+    "slowly" => 32,
+    "slowness" => 21,
+    "slows" => 5,
+    "slug" => 5,
+    "sluggard" => 1,
+    "sluggishly" => 32,
+    "sluggishness" => 17,
+    "slugs" => 5,
+    "sluice" => 5,
+    "slum" => 5,
+    "slumber" => 5,
+    "slumbered" => 20,
+    "slumbering" => 21,
+    "slumbers" => 5,
+    "slump" => 5,
+    "slums" => 5,
+    "slung" => 4,
+    "slunk" => 5,
+    "slur" => 5,
+    "slurp" => 5,
+    "slurped" => 20,
+    "slurping" => 21,
+    "slurred" => 4,
+    "slurring" => 5,
+    "slut" => 5,
+    "sly" => 16,
+    "smack" => 5,
+    "smacks" => 5,
+    "small" => 23,
+    "smaller" => 17,
+    "smallest" => 16,
+    "smallish" => 16,
+    "smart" => 21,
+    "smarter" => 17,
+    "smartest" => 16,
+    "smarting" => 21,
+    "smartness" => 21,
+    "smarts" => 5,
+    "smash" => 5,
+    "smashed" => 20,
+    "smasher" => 1,
+    "smashing" => 21,
+    "smattering" => 5,
+    "smear" => 5,
+    "smeared" => 20,
+    "smearing" => 21,
+    "smell" => 5,
+    "smelling" => 21,
+    "smelt" => 5,
+    "smile" => 5,
+    "smiled" => 20,
+    "smiles" => 5,
+    "smiling" => 21,
+    "smirk" => 21,
+    "smith" => 7,
+    "smithsonian" => 18,
+    "smithy" => 5,
+    "smitten" => 20,
+    "smoke" => 5,
+    "smoked" => 20,
+    "smokeless" => 16,
+    "smoker" => 1,
+    "smokers" => 5,
+    "smokes" => 5,
+    "smoking" => 21,
+    "smoky" => 16,
+    "smooth" => 21,
+    "smoothed" => 20,
+    "smoother" => 17,
+    "smoothing" => 21,
+    "smoothly" => 32,
+    "smoothness" => 21,
+    "smorgasbord" => 1,
+    "smote" => 4,
+    "smothered" => 20,
+    "smothering" => 21,
+    "smothers" => 5,
+    "smouldering" => 21,
+    "smudge" => 5,
+    "smuggled" => 20,
+    "smuggling" => 21,
+    "snafu" => 5,
+    "snag" => 5,
+    "snail" => 5,
+    "snail's" => 1,
+    "snake" => 7,
+    "snaked" => 20,
+    "snakes" => 5,
+    "snap" => 21,
+    "snapped" => 4,
+    "snapping" => 5,
+    "snappishly" => 32,
+    "snappy" => 16,
+    "snaps" => 5,
+    "snapshot" => 5,
+    "snapshots" => 5,
+    "snark" => 5,
+    "snarl" => 5,
+    "snarled" => 20,
+    "snarling" => 21,
+    "snatch" => 5,
+    "snatched" => 20,
+    "snatches" => 5,
+    "snatching" => 21,
+    "snazzy" => 16,
+    "sneak" => 21,
+    "sneaked" => 20,
+    "sneakers" => 21,
+    "sneaking" => 21,
+    "sneaky" => 17,
+    "sneer" => 5,
+    "sneering" => 21,
+    "sneeze" => 5,
+    "sneezed" => 20,
+    "sneezes" => 5,
+    "sneezing" => 21,
+    "snell" => 2,
+    "sniff" => 5,
+    "sniffing" => 21,
+    "snifter" => 5,
+    "snippet" => 5,
+    "snippets" => 5,
+    "snob" => 1,
+    "snobbery" => 1,
+    "snobbishly" => 32,
+    "snobs" => 1,
+    "snooper" => 1,
+    "snoopers" => 5,
+    "snore" => 5,
+    "snored" => 20,
+    "snorer" => 1,
+    "snores" => 5,
+    "snoring" => 21,
+    "snort" => 5,
+    "snorted" => 20,
+    "snorting" => 21,
+    "snout" => 5,
+    "snow" => 7,
+    "snowball" => 21,
+    "snowflake" => 5,
+    "snowing" => 21,
+    "snows" => 5,
+    "snowy" => 17,
+    "snub" => 21,
+    "snuck" => 4,
+    "snuff" => 5,
+    "snuffbox" => 1,
+    "snuffle" => 5,
+    "snuffled" => 20,
+    "snuffling" => 21,
+    "snug" => 21,
+    "snuggest" => 16,
+    "snugly" => 32,
+    "so" => 785,
+    "soaked" => 20,
+    "soap" => 5,
+    "soar" => 5,
+    "soared" => 20,
+    "soaring" => 21,
+    "sob" => 5,
+    "sobbed" => 4,
+    "sobbing" => 21,
+    "sober" => 20,
+    "sobered" => 20,
+    "sobs" => 5,
+    "social" => 17,
+    "socials" => 1,
+    "societal" => 16,
+    "societies" => 1,
+    "society" => 1,
+    "sock" => 21,
+    "socket" => 5,
+    "sockets" => 5,
+    "socks" => 5,
+    "sod" => 21,
+    "soda" => 1,
+    "sodden" => 20,
+    "sodium" => 1,
+    "sofa" => 5,
+    "soft" => 17,
+    "soften" => 21,
+    "softened" => 20,
+    "softer" => 16,
+    "softly" => 32,
+    "softness" => 17,
+    "software" => 1,
+    "softy" => 1,
+    "soggy" => 16,
+    "soil" => 5,
+    "soiled" => 20,
+    "sojourning" => 21,
+    "solar" => 17,
+    "sold" => 5,
+    "solder" => 5,
+    "soldier" => 5,
+    "soldiers" => 5,
+    "sole" => 21,
+    "soled" => 20,
+    "solely" => 32,
+    "solemn" => 16,
+    "solemnisation" => 1,
+    "solemnising" => 21,
+    "solemnities" => 1,
+    "solemnity" => 1,
+    "solemnly" => 32,
+    "soles" => 5,
+    "solicit" => 5,
+    "solicitation" => 1,
+    "solicitations" => 1,
+    "solicited" => 20,
+    "soliciting" => 21,
+    "solicitor" => 1,
+    "solicitude" => 1,
+    "solid" => 17,
+    "solidarity" => 1,
+    "solidified" => 20,
+    "solidity" => 1,
+    "solidly" => 32,
+    "solidus" => 1,
+    "soliloquized" => 20,
+    "solitary" => 17,
+    "solitude" => 1,
+    "solo" => 21,
+    "solomon" => 3,
+    "solution" => 21,
+    "solutions" => 21,
+    "solvable" => 16,
+    "solve" => 5,
+    "solved" => 20,
+    "solver" => 1,
+    "solvers" => 1,
+    "solves" => 5,
+    "solving" => 21,
+    "somali" => 17,
+    "somalis" => 1,
+    "sombre" => 21,
+    "sombrero" => 1,
+    "sombreros" => 1,
+    "some" => 432,
+    "somebody" => 257,
+    "someone" => 257,
+    "somersault" => 5,
+    "somersaults" => 5,
+    "something" => 277,
+    "sometime" => 16,
+    "sometimes" => 32,
+    "somewhat" => 289,
+    "somewhere" => 1,
+    "son" => 7,
+    "song" => 1,
+    "songbird" => 1,
+    "songs" => 1,
+    "sonic" => 16,
+    "sonny" => 3,
+    "sons" => 5,
+    "sony" => 3,
+    "soon" => 48,
+    "sooner" => 16,
+    "soothe" => 4,
+    "soothed" => 20,
+    "soothing" => 21,
+    "soothingly" => 32,
+    "sophie" => 3,
+    "sophism" => 1,
+    "sophisticated" => 20,
+    "sophistication" => 21,
+    "soprano" => 5,
+    "sorcerer" => 1,
+    "sorcery" => 1,
+    "sore" => 21,
+    "sorely" => 32,
+    "sorer" => 17,
+    "sorrow" => 5,
+    "sorrowful" => 16,
+    "sorrowfully" => 32,
+    "sorrowing" => 21,
+    "sorrows" => 5,
+    "sorry" => 21,
+    "sort" => 5,
+    "sorted" => 20,
+    "sorting" => 21,
+    "sorts" => 5,
+    "sos" => 1,
+    "sots" => 5,
+    "sought" => 4,
+    "soul" => 5,
+    "soulless" => 16,
+    "souls" => 5,
+    "sound" => 21,
+    "sounded" => 20,
+    "sounding" => 21,
+    "soundly" => 32,
+    "soundness" => 21,
+    "sounds" => 5,
+    "soup" => 5,
+    "sour" => 21,
+    "source" => 5,
+    "sources" => 5,
+    "souring" => 21,
+    "south" => 23,
+    "southampton" => 2,
+    "southeast" => 17,
+    "southeastern" => 16,
+    "southerly" => 17,
+    "southern" => 17,
+    "southward" => 17,
+    "southwards" => 1,
+    "southwestern" => 16,
+    "souvenir" => 5,
+    "souvenirs" => 5,
+    "sovereign" => 21,
+    "sovereigns" => 5,
+    "sow" => 5,
+    "sown" => 5,
+    "spa" => 1,
+    "space" => 5,
+    "spaced" => 20,
+    "spaces" => 5,
+    "spaceship" => 1,
+    "spacing" => 21,
+    "spacious" => 16,
+    "spade" => 5,
+    "spades" => 5,
+    "spam" => 7,
+    "span" => 7,
+    "spangles" => 5,
+    "spaniard" => 1,
+    "spanish" => 19,
+    "spanking" => 21,
+    "spanned" => 4,
+    "spanning" => 5,
+    "spans" => 5,
+    "spare" => 21,
+    "spared" => 20,
+    "spares" => 5,
+    "sparingly" => 32,
+    "spark" => 5,
+    "sparkle" => 5,
+    "sparkled" => 20,
+    "sparkles" => 5,
+    "sparkling" => 21,
+    "sparrow" => 1,
+    "sparrows" => 1,
+    "spars" => 5,
+    "sparse" => 20,
+    "spas" => 1,
+    "spasm" => 5,
+    "spasmodic" => 17,
+    "spasms" => 5,
+    "spat" => 5,
+    "spatial" => 16,
+    "spattered" => 20,
+    "spawn" => 5,
+    "spawned" => 20,
+    "spawning" => 21,
+    "spawns" => 5,
+    "speak" => 5,
+    "speaker" => 1,
+    "speakers" => 5,
+    "speaking" => 21,
+    "speaks" => 5,
+    "spec" => 5,
+    "special" => 21,
+    "specialisation" => 1,
+    "specialised" => 20,
+    "specialist" => 17,
+    "specialists" => 1,
+    "speciality" => 1,
+    "specialization" => 1,
+    "specialize" => 4,
+    "specialized" => 20,
+    "specializes" => 4,
+    "specializing" => 21,
+    "specially" => 32,
+    "specials" => 5,
+    "specialties" => 1,
+    "species" => 1,
+    "specifiable" => 16,
+    "specific" => 17,
+    "specifically" => 17,
+    "specification" => 5,
+    "specifications" => 4,
+    "specificity" => 1,
+    "specifics" => 1,
+    "specified" => 20,
+    "specifier" => 1,
+    "specifiers" => 4,
+    "specifies" => 4,
+    "specify" => 4,
+    "specifying" => 21,
+    "specimen" => 1,
+    "specimens" => 1,
+    "speciously" => 32,
+    "speck" => 5,
+    "specked" => 20,
+    "speckled" => 20,
+    "speckles" => 5,
+    "specs" => 5,
+    "spectacle" => 1,
+    "spectacles" => 1,
+    "spectators" => 1,
+    "specter" => 1,
+    "spectra" => 1,
+    "spectral" => 16,
+    "spectre" => 1,
+    "spectroscopic" => 16,
+    "spectrum" => 1,
+    "speculated" => 20,
+    "speculation" => 5,
+    "speculations" => 4,
+    "speculative" => 20,
+    "speculatively" => 32,
+    "sped" => 5,
+    "speech" => 5,
+    "speeches" => 5,
+    "speechless" => 16,
+    "speed" => 5,
+    "speedily" => 32,
+    "speeding" => 21,
+    "speeds" => 5,
+    "speedup" => 1,
+    "speedy" => 20,
+    "spell" => 5,
+    "spellbound" => 16,
+    "spelled" => 20,
+    "spelling" => 21,
+    "spellings" => 5,
+    "spencer" => 2,
+    "spend" => 5,
+    "spender" => 1,
+    "spending" => 21,
+    "spends" => 5,
+    "spent" => 20,
+    "spew" => 5,
+    "spews" => 5,
+    "sphere" => 5,
+    "spheres" => 5,
+    "spherical" => 16,
+    "sphinx" => 7,
+    "spice" => 5,
+    "spiced" => 20,
+    "spider" => 5,
+    "spidery" => 16,
+    "spied" => 20,
+    "spies" => 5,
+    "spike" => 5,
+    "spikes" => 5,
+    "spill" => 5,
+    "spilled" => 20,
+    "spilling" => 21,
+    "spills" => 5,
+    "spilt" => 20,
+    "spin" => 5,
+    "spine" => 1,
+    "spinner" => 1,
+    "spinners" => 1,
+    "spinning" => 5,
+    "spinster" => 1,
+    "spirals" => 5,
+    "spire" => 5,
+    "spires" => 5,
+    "spirit" => 5,
+    "spirited" => 20,
+    "spiritless" => 16,
+    "spirits" => 5,
+    "spiritual" => 17,
+    "spit" => 5,
+    "spite" => 69,
+    "spits" => 5,
+    "splash" => 5,
+    "splashed" => 20,
+    "splashing" => 21,
+    "splat" => 5,
+    "splay" => 21,
+    "splendid" => 16,
+    "splendidly" => 32,
+    "splendor" => 1,
+    "splendour" => 1,
+    "spliced" => 20,
+    "splicing" => 21,
+    "splines" => 5,
+    "splint" => 5,
+    "splintered" => 20,
+    "split" => 21,
+    "splits" => 5,
+    "splitting" => 21,
+    "splutter" => 5,
+    "spoil" => 5,
+    "spoiled" => 20,
+    "spoiler" => 5,
+    "spoils" => 5,
+    "spoke" => 5,
+    "spoken" => 20,
+    "spokes" => 5,
+    "sponge" => 5,
+    "sponged" => 20,
+    "spongy" => 16,
+    "sponsor" => 5,
+    "sponsored" => 20,
+    "sponsoring" => 21,
+    "sponsors" => 5,
+    "sponsorship" => 1,
+    "sponsorships" => 1,
+    "spontaneously" => 32,
+    "spoofing" => 21,
+    "spooky" => 16,
+    "spool" => 5,
+    "spoon" => 5,
+    "spoonful" => 1,
+    "spooning" => 21,
+    "sporadic" => 16,
+    "sporadically" => 16,
+    "sport" => 5,
+    "sporting" => 21,
+    "sports" => 5,
+    "sportswoman" => 1,
+    "spot" => 21,
+    "spots" => 5,
+    "spotted" => 20,
+    "spotty" => 17,
+    "spouse" => 5,
+    "spout" => 5,
+    "spouting" => 21,
+    "sprang" => 4,
+    "sprawl" => 5,
+    "sprawled" => 20,
+    "sprawling" => 21,
+    "spray" => 5,
+    "sprays" => 5,
+    "spread" => 5,
+    "spreading" => 21,
+    "spreads" => 5,
+    "spreadsheet" => 5,
+    "spreadsheets" => 5,
+    "spree" => 5,
+    "sprig" => 5,
+    "sprightly" => 16,
+    "spring" => 5,
+    "springing" => 21,
+    "springs" => 5,
+    "sprinkling" => 21,
+    "sprint" => 5,
+    "sprite" => 7,
+    "sprung" => 20,
+    "spume" => 5,
+    "spun" => 4,
+    "spunk" => 5,
+    "spur" => 5,
+    "spurious" => 16,
+    "spuriously" => 32,
+    "spurn" => 5,
+    "spurned" => 20,
+    "spurred" => 20,
+    "spurs" => 5,
+    "sputtering" => 21,
+    "spy" => 5,
+    "spying" => 21,
+    "squabble" => 5,
+    "squad" => 5,
+    "squalid" => 17,
+    "squall" => 5,
+    "squalls" => 5,
+    "squander" => 4,
+    "square" => 21,
+    "squared" => 20,
+    "squarely" => 32,
+    "squares" => 5,
+    "squaring" => 21,
+    "squash" => 5,
+    "squashed" => 20,
+    "squashes" => 5,
+    "squashing" => 21,
+    "squat" => 21,
+    "squatted" => 4,
+    "squatting" => 5,
+    "squawk" => 5,
+    "squeak" => 5,
+    "squeaked" => 20,
+    "squeaking" => 21,
+    "squeamishness" => 17,
+    "squeeze" => 5,
+    "squeezed" => 20,
+    "squeezes" => 5,
+    "squeezing" => 21,
+    "squinted" => 20,
+    "squire" => 5,
+    "squirm" => 5,
+    "squirrel" => 5,
+    "squirreling" => 21,
+    "squirrels" => 5,
+    "sse" => 19,
+    "stabbed" => 4,
+    "stabilisation" => 1,
+    "stabilise" => 4,
+    "stability" => 1,
+    "stabilization" => 1,
+    "stabilize" => 4,
+    "stabilized" => 20,
+    "stabilizes" => 4,
+    "stabilizing" => 21,
+    "stable" => 21,
+    "stables" => 5,
+    "stably" => 33,
+    "staccato" => 17,
+    "stack" => 5,
+    "stackable" => 5,
+    "stacked" => 20,
+    "stacking" => 21,
+    "stacks" => 5,
+    "staff" => 5,
+    "staffs" => 5,
+    "stag" => 5,
+    "stage" => 5,
+    "stagecoach" => 5,
+    "staged" => 20,
+    "stages" => 5,
+    "stagger" => 5,
+    "staggered" => 20,
+    "staggering" => 21,
+    "staging" => 21,
+    "stagnant" => 16,
+    "stagnation" => 21,
+    "staid" => 20,
+    "stain" => 5,
+    "stained" => 20,
+    "staining" => 21,
+    "stainless" => 17,
+    "stains" => 5,
+    "stair" => 1,
+    "staircase" => 5,
+    "staircases" => 5,
+    "stairs" => 1,
+    "stairway" => 1,
+    "stake" => 5,
+    "staked" => 20,
+    "stakeholders" => 1,
+    "stakes" => 5,
+    "staking" => 21,
+    "stalactite" => 1,
+    "stalactites" => 1,
+    "stalagmite" => 1,
+    "stalagmites" => 1,
+    "stale" => 21,
+    "staleness" => 21,
+    "stalk" => 5,
+    "stalked" => 20,
+    "stall" => 5,
+    "stalled" => 20,
+    "stalling" => 21,
+    "stalls" => 5,
+    "stalwart" => 17,
+    "stammer" => 5,
+    "stammered" => 20,
+    "stamp" => 5,
+    "stamped" => 20,
+    "stamping" => 1,
+    "stamps" => 5,
+    "stan" => 3,
+    "stance" => 5,
+    "stand" => 5,
+    "standalone" => 17,
+    "standard" => 17,
+    "standardisation" => 1,
+    "standardised" => 20,
+    "standardization" => 1,
+    "standardize" => 4,
+    "standardized" => 20,
+    "standardizing" => 21,
+    "standards" => 1,
+    "standing" => 21,
+    "standout" => 17,
+    "standpoint" => 1,
+    "stands" => 5,
+    "standstill" => 1,
+    "stanford" => 2,
+    "stanzas" => 1,
+    "stapled" => 20,
+    "staples" => 7,
+    "stapling" => 21,
+    "star" => 5,
+    "starboard" => 5,
+    "starch" => 21,
+    "stardust" => 1,
+    "stare" => 5,
+    "stared" => 20,
+    "stares" => 5,
+    "staring" => 21,
+    "stark" => 23,
+    "starkly" => 32,
+    "starlight" => 1,
+    "starred" => 20,
+    "starring" => 21,
+    "starry" => 16,
+    "stars" => 5,
+    "start" => 5,
+    "started" => 20,
+    "starter" => 1,
+    "starters" => 1,
+    "starting" => 21,
+    "startled" => 20,
+    "startling" => 21,
+    "startlingly" => 32,
+    "starts" => 5,
+    "startup" => 1,
+    "starvation" => 1,
+    "starve" => 4,
+    "starved" => 20,
+    "starving" => 21,
+    "stash" => 5,
+    "stashed" => 20,
+    "stashes" => 5,
+    "stashing" => 21,
+    "state" => 5,
+    "stated" => 20,
+    "stateless" => 16,
+    "stately" => 16,
+    "statement" => 21,
+    "statements" => 5,
+    "states" => 7,
+    "statewide" => 17,
+    "static" => 17,
+    "statically" => 32,
+    "statics" => 1,
+    "stating" => 21,
+    "station" => 5,
+    "stationary" => 17,
+    "stationed" => 20,
+    "stations" => 5,
+    "statistic" => 21,
+    "statistical" => 16,
+    "statistically" => 32,
+    "statistician" => 1,
+    "statistics" => 5,
+    "statue" => 5,
+    "stature" => 1,
+    "status" => 1,
+    "statuses" => 1,
+    "stay" => 21,
+    "stayed" => 20,
+    "staying" => 21,
+    "stays" => 5,
+    "std" => 17,
+    "stead" => 5,
+    "steadfast" => 16,
+    "steadily" => 32,
+    "steady" => 21,
+    "steal" => 5,
+    "stealer" => 1,
+    "stealers" => 1,
+    "stealing" => 21,
+    "steals" => 5,
+    "stealth" => 21,
+    "stealthily" => 32,
+    "stealthy" => 16,
+    "steam" => 21,
+    "steamboat" => 5,
+    "steamboats" => 5,
+    "steamed" => 20,
+    "steamer" => 21,
+    "steamers" => 21,
+    "steaming" => 21,
+    "steamship" => 1,
+    "steed" => 1,
+    "steel" => 23,
+    "steely" => 16,
+    "steep" => 21,
+    "steeped" => 20,
+    "steeper" => 17,
+    "steepest" => 16,
+    "steeple" => 5,
+    "steeples" => 5,
+    "steer" => 5,
+    "steered" => 20,
+    "stefan" => 2,
+    "steiner" => 2,
+    "stella" => 3,
+    "stellar" => 16,
+    "stem" => 5,
+    "stemming" => 5,
+    "stems" => 5,
+    "stencil" => 5,
+    "step" => 5,
+    "stepdaughter" => 1,
+    "stepfather" => 1,
+    "stephen" => 2,
+    "stephenson" => 2,
+    "stepmother" => 1,
+    "stepped" => 16,
+    "stepping" => 21,
+    "steps" => 5,
+    "stereo" => 21,
+    "stereotype" => 5,
+    "sterling" => 19,
+    "stern" => 23,
+    "sterner" => 17,
+    "sternly" => 32,
+    "steroids" => 1,
+    "stethoscope" => 5,
+    "steve" => 3,
+    "stevedore" => 5,
+    "steven" => 2,
+    "stevens" => 2,
+    "stevenson" => 2,
+    "steward" => 5,
+    "stewart" => 2,
+    "stick" => 21,
+    "sticker" => 21,
+    "stickers" => 21,
+    "sticking" => 21,
+    "sticks" => 5,
+    "sticky" => 21,
+    "stiff" => 21,
+    "stiffened" => 20,
+    "stiffly" => 32,
+    "stiffness" => 21,
+    "stifle" => 5,
+    "stifled" => 20,
+    "stifling" => 21,
+    "stigma" => 1,
+    "stigmatised" => 20,
+    "stile" => 5,
+    "still" => 21,
+    "stilled" => 20,
+    "stillness" => 1,
+    "stimulant" => 17,
+    "stimulate" => 4,
+    "stimulated" => 20,
+    "stimulating" => 21,
+    "stimulus" => 1,
+    "sting" => 5,
+    "stinging" => 21,
+    "stings" => 5,
+    "stingy" => 16,
+    "stipulate" => 20,
+    "stipulated" => 20,
+    "stir" => 5,
+    "stirred" => 4,
+    "stirring" => 21,
+    "stitch" => 5,
+    "stitches" => 5,
+    "stitching" => 21,
+    "stochastic" => 16,
+    "stock" => 21,
+    "stocked" => 20,
+    "stockings" => 5,
+    "stoical" => 16,
+    "stoke" => 5,
+    "stoker" => 1,
+    "stokers" => 5,
+    "stol" => 1,
+    "stole" => 5,
+    "stolen" => 21,
+    "stolid" => 16,
+    "stomach" => 5,
+    "stomped" => 20,
+    "stomping" => 21,
+    "stone" => 23,
+    "stones" => 5,
+    "stonewall" => 21,
+    "stony" => 16,
+    "stood" => 4,
+    "stool" => 5,
+    "stoop" => 5,
+    "stooped" => 20,
+    "stooping" => 21,
+    "stop" => 5,
+    "stopgap" => 21,
+    "stoplight" => 1,
+    "stoppages" => 1,
+    "stopped" => 20,
+    "stopper" => 5,
+    "stopping" => 5,
+    "stops" => 5,
+    "storage" => 5,
+    "store" => 5,
+    "stored" => 20,
+    "stores" => 5,
+    "storied" => 20,
+    "stories" => 5,
+    "storing" => 21,
+    "stork" => 1,
+    "storm" => 5,
+    "stormily" => 32,
+    "storming" => 21,
+    "storms" => 5,
+    "stormy" => 16,
+    "story" => 5,
+    "stout" => 23,
+    "stoutly" => 32,
+    "stove" => 5,
+    "straddling" => 21,
+    "straggle" => 5,
+    "straggled" => 20,
+    "stragglers" => 5,
+    "straggling" => 21,
+    "straight" => 21,
+    "straighten" => 21,
+    "straightened" => 20,
+    "straightening" => 21,
+    "straighter" => 17,
+    "straightforward" => 16,
+    "straightway" => 1,
+    "strain" => 5,
+    "strained" => 20,
+    "straining" => 21,
+    "strains" => 5,
+    "strait" => 21,
+    "straitened" => 20,
+    "straits" => 5,
+    "strand" => 5,
+    "strange" => 21,
+    "strangely" => 32,
+    "stranger" => 21,
+    "strangers" => 21,
+    "strangest" => 16,
+    "strangle" => 5,
+    "strangled" => 20,
+    "stranglers" => 5,
+    "strangling" => 21,
+    "strata" => 1,
+    "strategic" => 16,
+    "strategically" => 32,
+    "strategies" => 1,
+    "strategy" => 1,
+    "stratum" => 1,
+    "straw" => 21,
+    "strawberry" => 21,
+    "stray" => 21,
+    "strayed" => 20,
+    "straying" => 21,
+    "streak" => 5,
+    "streaked" => 20,
+    "stream" => 5,
+    "streamed" => 20,
+    "streamer" => 1,
+    "streamers" => 5,
+    "streaming" => 21,
+    "streamline" => 5,
+    "streamlined" => 20,
+    "streamlines" => 5,
+    "streamlining" => 21,
+    "streams" => 5,
+    "street" => 21,
+    "streets" => 5,
+    "strength" => 5,
+    "strengthen" => 4,
+    "strengthened" => 20,
+    "strengths" => 5,
+    "strenuously" => 32,
+    "stress" => 5,
+    "stressed" => 20,
+    "stresses" => 5,
+    "stressing" => 21,
+    "stretch" => 5,
+    "stretched" => 20,
+    "stretcher" => 5,
+    "stretches" => 5,
+    "stretching" => 21,
+    "strewed" => 20,
+    "strewn" => 4,
+    "stricken" => 20,
+    "strict" => 16,
+    "stricter" => 16,
+    "strictest" => 16,
+    "strictly" => 32,
+    "stride" => 5,
+    "strident" => 17,
+    "strides" => 5,
+    "striding" => 21,
+    "strife" => 1,
+    "strike" => 5,
+    "strikes" => 5,
+    "striking" => 21,
+    "strikingly" => 32,
+    "string" => 5,
+    "stringed" => 20,
+    "stringent" => 16,
+    "strings" => 5,
+    "stringy" => 16,
+    "strip" => 5,
+    "stripe" => 5,
+    "striped" => 20,
+    "stripes" => 5,
+    "stripped" => 20,
+    "stripping" => 5,
+    "strips" => 5,
+    "strive" => 5,
+    "strives" => 5,
+    "striving" => 21,
+    "strobe" => 5,
+    "strode" => 4,
+    "stroke" => 5,
+    "stroked" => 20,
+    "strokes" => 5,
+    "stroking" => 21,
+    "stroll" => 5,
+    "strolled" => 20,
+    "strolling" => 21,
+    "strong" => 18,
+    "stronger" => 16,
+    "strongest" => 16,
+    "stronghold" => 1,
+    "strongholds" => 1,
+    "strongly" => 32,
+    "strove" => 4,
+    "struck" => 4,
+    "structural" => 17,
+    "structurally" => 32,
+    "structure" => 5,
+    "structured" => 20,
+    "structures" => 5,
+    "structuring" => 21,
+    "struggle" => 5,
+    "struggled" => 20,
+    "struggles" => 5,
+    "struggling" => 21,
+    "strum" => 5,
+    "strumming" => 5,
+    "strung" => 4,
+    "struts" => 5,
+    "stuart" => 2,
+    "stub" => 5,
+    "stubbed" => 20,
+    "stubborn" => 17,
+    "stubs" => 5,
+    "stuck" => 21,
+    "student" => 1,
+    "students" => 1,
+    "studied" => 20,
+    "studies" => 5,
+    "studio" => 1,
+    "studios" => 1,
+    "studiously" => 32,
+    "study" => 5,
+    "studying" => 21,
+    "stuff" => 5,
+    "stuffed" => 20,
+    "stuffing" => 21,
+    "stuffs" => 5,
+    "stumble" => 5,
+    "stumbled" => 20,
+    "stumbling" => 21,
+    "stump" => 5,
+    "stun" => 5,
+    "stunned" => 20,
+    "stunning" => 21,
+    "stunt" => 5,
+    "stunts" => 5,
+    "stupefaction" => 1,
+    "stupefied" => 20,
+    "stupefying" => 21,
+    "stupendous" => 16,
+    "stupid" => 17,
+    "stupidest" => 16,
+    "stupidity" => 1,
+    "stupidly" => 32,
+    "stupor" => 5,
+    "sturdily" => 32,
+    "sturdy" => 17,
+    "stutters" => 5,
+    "sty" => 5,
+    "style" => 5,
+    "styled" => 20,
+    "styles" => 5,
+    "styling" => 21,
+    "stylish" => 16,
+    "stylistic" => 16,
+    "stylize" => 4,
+    "stylized" => 20,
+    "suavely" => 32,
+    "sub" => 69,
+    "subclass" => 5,
+    "subcommand" => 1,
+    "subdirectories" => 1,
+    "subdirectory" => 1,
+    "subdivide" => 4,
+    "subdivided" => 20,
+    "subdividing" => 21,
+    "subdivisions" => 5,
+    "subdue" => 4,
+    "subdued" => 20,
+    "subduing" => 21,
+    "subfamilies" => 1,
+    "subfamily" => 1,
+    "subgroup" => 5,
+    "subgroups" => 5,
+    "subheading" => 21,
+    "subheadings" => 1,
+    "subject" => 21,
+    "subjected" => 20,
+    "subjecting" => 21,
+    "subjective" => 21,
+    "subjectivity" => 1,
+    "subjects" => 5,
+    "sublime" => 21,
+    "sublimity" => 1,
+    "submerged" => 20,
+    "submission" => 1,
+    "submissions" => 1,
+    "submit" => 4,
+    "submits" => 4,
+    "submitted" => 4,
+    "submitting" => 5,
+    "subnormal" => 17,
+    "subordinates" => 5,
+    "subprocess" => 1,
+    "subprograms" => 1,
+    "subroutine" => 1,
+    "subroutines" => 1,
+    "subs" => 5,
+    "subscribe" => 4,
+    "subscribed" => 20,
+    "subscriber" => 1,
+    "subscribers" => 1,
+    "subscribes" => 4,
+    "subscribing" => 21,
+    "subscript" => 21,
+    "subscription" => 1,
+    "subscriptions" => 1,
+    "subscripts" => 5,
+    "subsection" => 5,
+    "subsections" => 5,
+    "subsequence" => 1,
+    "subsequent" => 17,
+    "subsequently" => 32,
+    "subset" => 5,
+    "subsets" => 5,
+    "subside" => 4,
+    "subsided" => 20,
+    "subsidence" => 1,
+    "subsidiaries" => 1,
+    "subsidy" => 1,
+    "subsist" => 4,
+    "subsisted" => 20,
+    "subsistence" => 1,
+    "substance" => 5,
+    "substantial" => 17,
+    "substantially" => 32,
+    "substantive" => 21,
+    "substantively" => 32,
+    "substitute" => 5,
+    "substituted" => 20,
+    "substitutes" => 5,
+    "substituting" => 21,
+    "substitution" => 5,
+    "substitutions" => 5,
+    "substrate" => 21,
+    "substring" => 5,
+    "subsumed" => 20,
+    "subsumes" => 4,
+    "subsystem" => 1,
+    "subsystems" => 1,
+    "subterfuges" => 1,
+    "subterranean" => 16,
+    "subtitle" => 5,
+    "subtle" => 16,
+    "subtleties" => 1,
+    "subtlety" => 1,
+    "subtly" => 32,
+    "subtract" => 4,
+    "subtracted" => 20,
+    "subtracting" => 21,
+    "subtraction" => 1,
+    "subtractions" => 1,
+    "subtracts" => 4,
+    "subtype" => 1,
+    "subtypes" => 1,
+    "suburb" => 1,
+    "suburban" => 17,
+    "subversion" => 1,
+    "subvert" => 5,
+    "subverts" => 5,
+    "subway" => 5,
+    "succeed" => 4,
+    "succeeded" => 20,
+    "succeeding" => 21,
+    "succeeds" => 4,
+    "success" => 1,
+    "successes" => 1,
+    "successful" => 16,
+    "successfully" => 32,
+    "succession" => 1,
+    "successive" => 17,
+    "successively" => 32,
+    "successor" => 1,
+    "successors" => 1,
+    "succinct" => 16,
+    "succinctly" => 32,
+    "succour" => 5,
+    "succulent" => 17,
+    "succumb" => 4,
+    "such" => 257,
+    "suchlike" => 273,
+    "suck" => 5,
+    "sucked" => 20,
+    "sucking" => 21,
+    "sucks" => 5,
+    "sudden" => 17,
+    "suddenly" => 32,
+    "suddenness" => 17,
+    "suds" => 5,
+    "sue" => 7,
+    "suet" => 1,
+    "suez" => 2,
+    "suffer" => 4,
+    "suffered" => 20,
+    "sufferer" => 1,
+    "suffering" => 21,
+    "sufferings" => 4,
+    "suffers" => 4,
+    "suffice" => 4,
+    "sufficed" => 20,
+    "suffices" => 4,
+    "sufficiency" => 1,
+    "sufficient" => 16,
+    "sufficiently" => 32,
+    "suffix" => 5,
+    "suffixed" => 20,
+    "suffixes" => 5,
+    "suffixing" => 21,
+    "suffocated" => 20,
+    "suffocating" => 21,
+    "suffrage" => 1,
+    "sugar" => 5,
+    "sugary" => 16,
+    "suggest" => 4,
+    "suggested" => 20,
+    "suggesting" => 21,
+    "suggestion" => 1,
+    "suggestions" => 1,
+    "suggestive" => 20,
+    "suggestiveness" => 17,
+    "suggests" => 4,
+    "sui" => 19,
+    "suicide" => 5,
+    "suit" => 5,
+    "suitability" => 1,
+    "suitable" => 5,
+    "suitably" => 32,
+    "suite" => 1,
+    "suited" => 20,
+    "suites" => 1,
+    "suitor" => 5,
+    "suits" => 5,
+    "sulked" => 20,
+    "sulkily" => 32,
+    "sulking" => 21,
+    "sulky" => 17,
+    "sullen" => 21,
+    "sullenly" => 32,
+    "sullied" => 20,
+    "sullivan" => 2,
+    "sultan" => 1,
+    "sum" => 5,
+    "sumatra" => 3,
+    "summaries" => 1,
+    "summarily" => 32,
+    "summarise" => 4,
+    "summarised" => 20,
+    "summarises" => 4,
+    "summarization" => 1,
+    "summarize" => 4,
+    "summarized" => 20,
+    "summarizes" => 4,
+    "summarizing" => 21,
+    "summary" => 17,
+    "summation" => 1,
+    "summed" => 4,
+    "summer" => 7,
+    "summers" => 7,
+    "summing" => 5,
+    "summit" => 5,
+    "summits" => 5,
+    "summoned" => 20,
+    "summoning" => 21,
+    "summons" => 5,
+    "summonses" => 5,
+    "sumptuous" => 16,
+    "sumptuously" => 32,
+    "sumptuousness" => 17,
+    "sums" => 5,
+    "sun" => 7,
+    "sunbeam" => 3,
+    "sunday" => 5,
+    "sundays" => 5,
+    "sundial" => 1,
+    "sundials" => 1,
+    "sung" => 6,
+    "sunk" => 4,
+    "sunken" => 4,
+    "sunlight" => 5,
+    "sunny" => 17,
+    "sunrise" => 5,
+    "suns" => 5,
+    "sunset" => 5,
+    "sunshine" => 17,
+    "sunstroke" => 1,
+    "suntanned" => 20,
+    "sup" => 21,
+    "super" => 21,
+    "superabundance" => 1,
+    "superb" => 16,
+    "supercilious" => 16,
+    "superficial" => 17,
+    "superficiality" => 1,
+    "superfluous" => 16,
+    "superhuman" => 17,
+    "superimpose" => 4,
+    "superimposed" => 20,
+    "superintendent" => 17,
+    "superior" => 19,
+    "superiority" => 1,
+    "superiors" => 1,
+    "superlative" => 17,
+    "superman" => 3,
+    "supernatural" => 17,
+    "superpower" => 5,
+    "superpowers" => 5,
+    "superscript" => 21,
+    "superscription" => 1,
+    "superscripts" => 5,
+    "superseded" => 20,
+    "supersedes" => 5,
+    "superset" => 5,
+    "supersets" => 5,
+    "superstition" => 1,
+    "superstitions" => 1,
+    "superstitious" => 16,
+    "supervised" => 20,
+    "supervision" => 5,
+    "supervisor" => 1,
+    "supper" => 5,
+    "supplanted" => 20,
+    "supple" => 20,
+    "supplement" => 21,
+    "supplemental" => 17,
+    "supplementary" => 17,
+    "supplemented" => 20,
+    "supplementing" => 21,
+    "suppleness" => 21,
+    "suppliant" => 17,
+    "supplication" => 5,
+    "supplied" => 20,
+    "supplier" => 1,
+    "suppliers" => 5,
+    "supplies" => 5,
+    "supply" => 5,
+    "supplying" => 21,
+    "support" => 5,
+    "supported" => 20,
+    "supporter" => 1,
+    "supporters" => 5,
+    "supporting" => 21,
+    "supportive" => 5,
+    "supports" => 5,
+    "suppose" => 4,
+    "supposed" => 20,
+    "supposedly" => 32,
+    "supposes" => 4,
+    "supposing" => 21,
+    "supposition" => 1,
+    "suppress" => 4,
+    "suppressed" => 20,
+    "suppresses" => 4,
+    "suppressing" => 21,
+    "suppression" => 1,
+    "supremacy" => 1,
+    "supreme" => 21,
+    "sure" => 16,
+    "surely" => 32,
+    "surer" => 16,
+    "surest" => 16,
+    "surf" => 5,
+    "surface" => 5,
+    "surfaced" => 20,
+    "surfaces" => 5,
+    "surfacing" => 21,
+    "surfing" => 21,
+    "surge" => 5,
+    "surgeon" => 1,
+    "surgery" => 1,
+    "surges" => 5,
+    "surgically" => 32,
+    "surly" => 16,
+    "surmise" => 5,
+    "surmount" => 4,
+    "surmountable" => 20,
+    "surmounted" => 20,
+    "surmounting" => 21,
+    "surname" => 5,
+    "surnames" => 5,
+    "surpassed" => 20,
+    "surpassing" => 21,
+    "surprise" => 5,
+    "surprised" => 20,
+    "surprises" => 5,
+    "surprising" => 21,
+    "surprisingly" => 32,
+    "surrender" => 5,
+    "surrey" => 1,
+    "surrogate" => 21,
+    "surrogates" => 5,
+    "surround" => 5,
+    "surrounded" => 20,
+    "surrounding" => 21,
+    "surroundings" => 5,
+    "surrounds" => 5,
+    "survey" => 5,
+    "surveyed" => 20,
+    "surveying" => 21,
+    "surveys" => 5,
+    "survive" => 4,
+    "survived" => 20,
+    "survives" => 4,
+    "surviving" => 21,
+    "survivor" => 1,
+    "survivors" => 1,
+    "susan" => 19,
+    "susceptibility" => 1,
+    "susceptible" => 17,
+    "suspect" => 21,
+    "suspected" => 20,
+    "suspecting" => 21,
+    "suspects" => 5,
+    "suspend" => 4,
+    "suspended" => 20,
+    "suspender" => 1,
+    "suspending" => 21,
+    "suspends" => 4,
+    "suspense" => 17,
+    "suspension" => 17,
+    "suspicion" => 5,
+    "suspicions" => 5,
+    "suspicious" => 16,
+    "suspiciously" => 32,
+    "sussex" => 3,
+    "sustain" => 5,
+    "sustainable" => 21,
+    "sustained" => 20,
+    "sustaining" => 21,
+    "sustenance" => 1,
+    "sutherland" => 2,
+    "suttee" => 1,
+    "suzuki" => 2,
+    "svelte" => 18,
+    "swabbed" => 4,
+    "swag" => 5,
+    "swagger" => 21,
+    "swahili" => 3,
+    "swain" => 1,
+    "swallow" => 5,
+    "swallowed" => 20,
+    "swallowing" => 21,
+    "swallows" => 5,
+    "swam" => 4,
+    "swamp" => 5,
+    "swamping" => 21,
+    "swamps" => 5,
+    "swan" => 5,
+    "swank" => 21,
+    "swap" => 5,
+    "swapped" => 4,
+    "swapping" => 21,
+    "swaps" => 5,
+    "swarm" => 5,
+    "swarmed" => 20,
+    "swarming" => 21,
+    "swarthy" => 17,
+    "swash" => 21,
+    "swastika" => 1,
+    "swatches" => 5,
+    "swathed" => 16,
+    "swathes" => 1,
+    "swaths" => 1,
+    "sway" => 5,
+    "swayed" => 20,
+    "swaying" => 21,
+    "swear" => 21,
+    "swears" => 5,
+    "sweat" => 5,
+    "sweated" => 20,
+    "sweating" => 21,
+    "swede" => 5,
+    "sweden" => 2,
+    "swedish" => 19,
+    "sweep" => 5,
+    "sweeping" => 21,
+    "sweeps" => 5,
+    "sweet" => 23,
+    "sweetened" => 20,
+    "sweeter" => 17,
+    "sweetest" => 16,
+    "sweetheart" => 1,
+    "sweethearts" => 1,
+    "sweetie" => 1,
+    "sweetly" => 32,
+    "sweetmeats" => 1,
+    "sweetness" => 21,
+    "sweets" => 5,
+    "swell" => 21,
+    "swelled" => 20,
+    "swelling" => 21,
+    "swells" => 5,
+    "swept" => 20,
+    "swift" => 19,
+    "swifter" => 16,
+    "swiftly" => 32,
+    "swiftness" => 17,
+    "swim" => 5,
+    "swimmer" => 1,
+    "swimmers" => 1,
+    "swimming" => 5,
+    "swindler" => 1,
+    "swing" => 5,
+    "swinging" => 21,
+    "swirls" => 5,
+    "swish" => 21,
+    "swiss" => 19,
+    "switch" => 21,
+    "switchboard" => 1,
+    "switched" => 20,
+    "switcher" => 17,
+    "switches" => 5,
+    "switching" => 21,
+    "switzerland" => 3,
+    "swivel" => 5,
+    "swizzle" => 5,
+    "swollen" => 20,
+    "swoon" => 5,
+    "swooned" => 20,
+    "swooning" => 21,
+    "sword" => 5,
+    "swords" => 5,
+    "swordsman" => 1,
+    "swore" => 4,
+    "sworn" => 20,
+    "swung" => 4,
+    "sycamore" => 1,
+    "syllabic" => 17,
+    "syllable" => 5,
+    "syllables" => 5,
+    "syllabus" => 1,
+    "symbol" => 5,
+    "symbolic" => 16,
+    "symbolically" => 32,
+    "symbolise" => 4,
+    "symbolize" => 4,
+    "symbols" => 5,
+    "symmetric" => 16,
+    "symmetrical" => 16,
+    "symmetrically" => 32,
+    "symmetry" => 1,
+    "sympathetic" => 17,
+    "sympathies" => 1,
+    "sympathise" => 4,
+    "sympathised" => 20,
+    "sympathising" => 21,
+    "sympathized" => 20,
+    "sympathizing" => 21,
+    "sympathy" => 1,
+    "symposium" => 1,
+    "symptom" => 1,
+    "symptoms" => 1,
+    "synagogues" => 1,
+    "sync" => 5,
+    "synchronisation" => 1,
+    "synchronised" => 20,
+    "synchronises" => 4,
+    "synchronising" => 21,
+    "synchronization" => 1,
+    "synchronize" => 4,
+    "synchronized" => 20,
+    "synchronizes" => 4,
+    "synchronizing" => 21,
+    "synchronous" => 16,
+    "synchronously" => 32,
+    "syndication" => 5,
+    "syndrome" => 1,
+    "synergy" => 1,
+    "synonym" => 1,
+    "synonymous" => 16,
+    "synonyms" => 1,
+    "synopsis" => 1,
+    "syntactic" => 16,
+    "syntactical" => 16,
+    "syntactically" => 32,
+    "syntax" => 1,
+    "synthesis" => 1,
+    "synthesize" => 4,
+    "synthesized" => 20,
+    "synthesizers" => 4,
+    "synthesizes" => 4,
+    "synthetic" => 17,
+    "syrup" => 5,
+    "system" => 1,
+    "systematic" => 16,
+    "systematically" => 32,
+    "systems" => 1,
+    "tab" => 5,
+    "tabbed" => 20,
+    "table" => 5,
+    "tableau" => 1,
+    "tabled" => 20,
+    "tables" => 5,
+    "tabs" => 5,
+    "tabular" => 17,
+    "tabulation" => 21,
+    "tabulator" => 1,
+    "taciturn" => 16,
+    "tack" => 5,
+    "tackle" => 5,
+    "tackled" => 20,
+    "tackling" => 21,
+    "tactful" => 16,
+    "tactfully" => 32,
+    "tactical" => 17,
+    "tactlessly" => 32,
+    "tad" => 3,
+    "tadpoles" => 1,
+    "tads" => 1,
+    "taffy" => 1,
+    "tag" => 5,
+    "tagged" => 20,
+    "tagging" => 5,
+    "tags" => 5,
+    "tahitian" => 19,
+    "tahoe" => 2,
+    "tail" => 21,
+    "tailed" => 20,
+    "tailing" => 21,
+    "tailless" => 16,
+    "tailor" => 5,
+    "tailored" => 20,
+    "tailoring" => 21,
+    "tails" => 5,
+    "tailwind" => 5,
+    "taint" => 5,
+    "tainted" => 20,
+    "taiwan" => 2,
+    "taiwanese" => 19,
+    "take" => 5,
+    "takeaway" => 17,
+    "taken" => 20,
+    "takes" => 5,
+    "taking" => 21,
+    "takings" => 5,
+    "talc" => 5,
+    "tale" => 5,
+    "talent" => 1,
+    "talented" => 16,
+    "talents" => 1,
+    "tales" => 5,
+    "talisman" => 5,
+    "talk" => 5,
+    "talkative" => 16,
+    "talked" => 20,
+    "talker" => 1,
+    "talking" => 21,
+    "talks" => 5,
+    "tall" => 17,
+    "taller" => 16,
+    "tallied" => 20,
+    "tallish" => 16,
+    "tallow" => 5,
+    "tally" => 5,
+    "tam" => 1,
+    "tamara" => 2,
+    "tambourines" => 5,
+    "tame" => 20,
+    "tamer" => 17,
+    "tamil" => 17,
+    "tamper" => 5,
+    "tampered" => 20,
+    "tampering" => 21,
+    "tams" => 1,
+    "tan" => 21,
+    "tandem" => 21,
+    "tangent" => 17,
+    "tangents" => 1,
+    "tangible" => 17,
+    "tangle" => 5,
+    "tangled" => 20,
+    "tango" => 5,
+    "tank" => 5,
+    "tanked" => 20,
+    "tanned" => 20,
+    "tanner" => 19,
+    "tannery" => 1,
+    "tantalizing" => 21,
+    "tanzania" => 2,
+    "tao" => 3,
+    "tap" => 5,
+    "tape" => 5,
+    "tapers" => 5,
+    "tapes" => 5,
+    "tapestry" => 5,
+    "tapped" => 20,
+    "tapping" => 5,
+    "taproot" => 1,
+    "taps" => 5,
+    "tar" => 5,
+    "tardily" => 32,
+    "target" => 5,
+    "targeted" => 20,
+    "targeting" => 21,
+    "targets" => 5,
+    "tarnish" => 5,
+    "tarnished" => 20,
+    "taro" => 1,
+    "tarry" => 21,
+    "tart" => 21,
+    "tarts" => 5,
+    "task" => 5,
+    "tasked" => 20,
+    "tasking" => 21,
+    "tasks" => 5,
+    "tassel" => 5,
+    "taste" => 21,
+    "tasted" => 20,
+    "tasteful" => 16,
+    "taster" => 17,
+    "tastes" => 5,
+    "tattered" => 20,
+    "tatters" => 5,
+    "tattle" => 5,
+    "tattoo" => 5,
+    "tattooed" => 20,
+    "tau" => 1,
+    "taught" => 4,
+    "taunt" => 21,
+    "taunted" => 20,
+    "tavern" => 1,
+    "taverns" => 1,
+    "tawny" => 21,
+    "tax" => 5,
+    "taxed" => 20,
+    "taxes" => 5,
+    "taxi" => 5,
+    "taxicabs" => 5,
+    "taxis" => 5,
+    "taxonomic" => 16,
+    "taylor" => 2,
+    "tcp" => 18,
+    "tea" => 5,
+    "teach" => 5,
+    "teacher" => 1,
+    "teachers" => 5,
+    "teaches" => 5,
+    "teaching" => 21,
+    "teacup" => 17,
+    "teacups" => 1,
+    "teal" => 17,
+    "team" => 5,
+    "teammates" => 1,
+    "teams" => 5,
+    "teamwork" => 1,
+    "teapot" => 1,
+    "tear" => 5,
+    "teared" => 20,
+    "tearful" => 16,
+    "tearing" => 21,
+    "tears" => 5,
+    "teased" => 20,
+    "teases" => 5,
+    "teasing" => 21,
+    "teaspoon" => 5,
+    "teaspoonful" => 1,
+    "tech" => 5,
+    "technical" => 17,
+    "technicalities" => 1,
+    "technically" => 32,
+    "technician" => 1,
+    "technicians" => 1,
+    "technique" => 1,
+    "techniques" => 1,
+    "technocratic" => 16,
+    "technological" => 16,
+    "technologically" => 32,
+    "technologies" => 1,
+    "technologists" => 1,
+    "technology" => 1,
+    "ted" => 23,
+    "tedious" => 16,
+    "tee" => 5,
+    "teeing" => 4,
+    "teeth" => 1,
+    "teething" => 21,
+    "teetotaler" => 17,
+    "telecommunication" => 1,
+    "telecommunications" => 1,
+    "telegram" => 5,
+    "telegrams" => 5,
+    "telegraph" => 5,
+    "telegraphed" => 20,
+    "telegraphic" => 16,
+    "telemetry" => 1,
+    "telephone" => 5,
+    "telephoned" => 20,
+    "telescope" => 5,
+    "telescopes" => 5,
+    "teletype" => 5,
+    "television" => 5,
+    "telex" => 5,
+    "tell" => 7,
+    "teller" => 3,
+    "telling" => 21,
+    "tells" => 5,
+    "telltale" => 17,
+    "temp" => 21,
+    "temper" => 21,
+    "temperament" => 1,
+    "temperance" => 1,
+    "temperate" => 20,
+    "temperature" => 1,
+    "temperatures" => 1,
+    "tempered" => 20,
+    "tempest" => 21,
+    "tempests" => 5,
+    "tempestuous" => 16,
+    "template" => 5,
+    "templates" => 5,
+    "temple" => 7,
+    "temples" => 5,
+    "tempo" => 1,
+    "temporal" => 17,
+    "temporaries" => 1,
+    "temporarily" => 32,
+    "temporary" => 17,
+    "temps" => 5,
+    "tempt" => 4,
+    "temptation" => 1,
+    "tempted" => 20,
+    "tempting" => 21,
+    "ten" => 1,
+    "tenable" => 17,
+    "tenacious" => 16,
+    "tenacity" => 1,
+    "tenant" => 5,
+    "tend" => 4,
+    "tended" => 20,
+    "tendencies" => 1,
+    "tendency" => 1,
+    "tender" => 21,
+    "tenderest" => 16,
+    "tenderly" => 32,
+    "tenderness" => 21,
+    "tendril" => 17,
+    "tendrils" => 1,
+    "tends" => 4,
+    "tenement" => 1,
+    "tenets" => 1,
+    "tenfold" => 20,
+    "tennessee" => 3,
+    "tennis" => 5,
+    "tenor" => 17,
+    "tens" => 1,
+    "tense" => 21,
+    "tenses" => 5,
+    "tension" => 21,
+    "tensor" => 5,
+    "tensors" => 5,
+    "tent" => 5,
+    "tentative" => 17,
+    "tentatively" => 32,
+    "tenth" => 21,
+    "tenths" => 1,
+    "tents" => 5,
+    "tenure" => 5,
+    "term" => 21,
+    "termed" => 20,
+    "terminal" => 21,
+    "terminals" => 5,
+    "terminate" => 20,
+    "terminated" => 20,
+    "terminates" => 4,
+    "terminating" => 21,
+    "termination" => 21,
+    "terminations" => 21,
+    "terminator" => 1,
+    "terminators" => 1,
+    "terminology" => 1,
+    "terminus" => 1,
+    "terms" => 5,
+    "ternary" => 17,
+    "terra" => 2,
+    "terrace" => 5,
+    "terraced" => 20,
+    "terrain" => 1,
+    "terrestrial" => 17,
+    "terrible" => 16,
+    "terribly" => 32,
+    "terrier" => 1,
+    "terrific" => 16,
+    "terrifically" => 16,
+    "terrified" => 20,
+    "territories" => 1,
+    "territory" => 1,
+    "terror" => 1,
+    "terrorising" => 21,
+    "terrors" => 1,
+    "terry" => 3,
+    "terse" => 16,
+    "tertiary" => 19,
+    "tesla" => 3,
+    "tessa" => 2,
+    "tessellation" => 21,
+    "test" => 5,
+    "testability" => 1,
+    "testable" => 16,
+    "testament" => 5,
+    "tested" => 20,
+    "tester" => 1,
+    "testers" => 1,
+    "testified" => 20,
+    "testify" => 4,
+    "testifying" => 21,
+    "testimonies" => 1,
+    "testimony" => 1,
+    "testing" => 21,
+    "tests" => 5,
+    "tetrahedron" => 1,
+    "teutonic" => 17,
+    "texas" => 2,
+    "text" => 5,
+    "textbook" => 17,
+    "textiles" => 1,
+    "texts" => 5,
+    "textual" => 16,
+    "textually" => 32,
+    "textural" => 16,
+    "texture" => 5,
+    "textured" => 20,
+    "textures" => 5,
+    "thai" => 17,
+    "thames" => 2,
+    "than" => 576,
+    "thank" => 5,
+    "thanked" => 20,
+    "thankful" => 16,
+    "thankfully" => 32,
+    "thankfulness" => 17,
+    "thanking" => 21,
+    "thanks" => 5,
+    "thanksgivings" => 3,
+    "that" => 896,
+    "that's" => 1,
+    "thatch" => 5,
+    "thatched" => 20,
+    "thatcher" => 3,
+    "thatchers" => 5,
+    "the" => 128,
+    "thea" => 2,
+    "theatre" => 1,
+    "theatrical" => 17,
+    "thee" => 256,
+    "theft" => 1,
+    "their" => 128,
+    "theirs" => 256,
+    "them" => 256,
+    "thematically" => 17,
+    "theme" => 5,
+    "themes" => 5,
+    "themselves" => 256,
+    "then" => 529,
+    "theodore" => 3,
+    "theologians" => 1,
+    "theological" => 16,
+    "theorem" => 5,
+    "theorems" => 5,
+    "theoretic" => 16,
+    "theoretical" => 16,
+    "theoretically" => 32,
+    "theories" => 1,
+    "theorist" => 1,
+    "theorize" => 4,
+    "theory" => 1,
+    "thereabouts" => 5,
+    "thereafter" => 1,
+    "therefor" => 32,
+    "therefore" => 32,
+    "thermodynamic" => 16,
+    "thesaurus" => 1,
+    "these" => 384,
+    "theses" => 1,
+    "thesis" => 1,
+    "theta" => 1,
+    "they" => 256,
+    "thick" => 21,
+    "thickened" => 20,
+    "thickening" => 21,
+    "thickest" => 16,
+    "thicket" => 1,
+    "thickly" => 32,
+    "thickness" => 21,
+    "thief" => 1,
+    "thieves" => 4,
+    "thimble" => 5,
+    "thimblefuls" => 1,
+    "thin" => 21,
+    "thine" => 256,
+    "thing" => 5,
+    "things" => 133,
+    "think" => 5,
+    "thinker" => 1,
+    "thinkers" => 5,
+    "thinking" => 21,
+    "thinks" => 5,
+    "thinned" => 4,
+    "thinner" => 17,
+    "thinness" => 21,
+    "thinning" => 21,
+    "third" => 21,
+    "thirdly" => 32,
+    "thirds" => 5,
+    "thirst" => 5,
+    "thirsted" => 20,
+    "thirsting" => 21,
+    "thirties" => 1,
+    "thirty" => 1,
+    "this" => 384,
+    "thistle" => 1,
+    "thither" => 16,
+    "thomas" => 3,
+    "thompson" => 3,
+    "thorn" => 5,
+    "thorns" => 5,
+    "thorny" => 16,
+    "thorough" => 81,
+    "thoroughfare" => 1,
+    "thoroughly" => 32,
+    "thoroughness" => 81,
+    "those" => 384,
+    "thou" => 256,
+    "though" => 516,
+    "thought" => 5,
+    "thoughtful" => 16,
+    "thoughtfully" => 32,
+    "thoughtfulness" => 17,
+    "thoughtless" => 16,
+    "thoughtlessly" => 32,
+    "thoughts" => 5,
+    "thousand" => 1,
+    "thousands" => 1,
+    "thousandth" => 17,
+    "thrash" => 5,
+    "thrashing" => 21,
+    "thread" => 5,
+    "threadbare" => 16,
+    "threaded" => 20,
+    "threading" => 21,
+    "threads" => 5,
+    "threat" => 5,
+    "threaten" => 5,
+    "threatened" => 20,
+    "threatening" => 21,
+    "threatens" => 5,
+    "threats" => 5,
+    "three" => 1,
+    "threshold" => 1,
+    "thresholding" => 21,
+    "thresholds" => 1,
+    "threw" => 4,
+    "thrift" => 5,
+    "thrifty" => 16,
+    "thrill" => 5,
+    "thrilling" => 21,
+    "throat" => 5,
+    "throats" => 5,
+    "throb" => 5,
+    "throbbed" => 4,
+    "throbbing" => 21,
+    "throne" => 5,
+    "throng" => 21,
+    "thronged" => 20,
+    "throttle" => 5,
+    "throttling" => 21,
+    "through" => 81,
+    "throughout" => 64,
+    "throughput" => 1,
+    "throw" => 5,
+    "throwback" => 1,
+    "throwing" => 21,
+    "thrown" => 20,
+    "throws" => 5,
+    "thrush" => 1,
+    "thrust" => 5,
+    "thrusting" => 21,
+    "thud" => 5,
+    "thudding" => 5,
+    "thumb" => 5,
+    "thumbnail" => 5,
+    "thumbnails" => 5,
+    "thumbprint" => 1,
+    "thumbprints" => 1,
+    "thumbs" => 5,
+    "thump" => 5,
+    "thumped" => 20,
+    "thumps" => 5,
+    "thunder" => 5,
+    "thunderbird" => 3,
+    "thunderbolt" => 5,
+    "thunders" => 5,
+    "thunderstorm" => 1,
+    "thunderstruck" => 16,
+    "thursday" => 1,
+    "thus" => 1,
+    "thwarts" => 5,
+    "thy" => 128,
+    "thyself" => 256,
+    "tiara" => 5,
+    "tibetan" => 17,
+    "tick" => 5,
+    "ticked" => 20,
+    "ticker" => 1,
+    "ticket" => 5,
+    "ticketing" => 21,
+    "tickets" => 5,
+    "ticking" => 21,
+    "tickled" => 20,
+    "ticks" => 5,
+    "tide" => 5,
+    "tidings" => 5,
+    "tidy" => 21,
+    "tie" => 5,
+    "tiebreaker" => 1,
+    "tied" => 20,
+    "tier" => 5,
+    "tiered" => 20,
+    "tiers" => 1,
+    "ties" => 5,
+    "tiff" => 5,
+    "tiger" => 1,
+    "tigers" => 1,
+    "tight" => 20,
+    "tighten" => 20,
+    "tightened" => 20,
+    "tightening" => 21,
+    "tighter" => 17,
+    "tightly" => 32,
+    "tights" => 5,
+    "til" => 577,
+    "tilde" => 1,
+    "tildes" => 1,
+    "tile" => 5,
+    "tiled" => 20,
+    "tiles" => 5,
+    "tiling" => 21,
+    "till" => 581,
+    "tilt" => 5,
+    "tilted" => 20,
+    "tilting" => 21,
+    "tim" => 3,
+    "timbered" => 20,
+    "timbers" => 5,
+    "time" => 5,
+    "timed" => 20,
+    "timely" => 48,
+    "timeout" => 1,
+    "timepiece" => 1,
+    "timer" => 1,
+    "timers" => 5,
+    "times" => 5,
+    "timestamp" => 5,
+    "timetable" => 5,
+    "timetables" => 5,
+    "timid" => 16,
+    "timidly" => 32,
+    "timing" => 21,
+    "timings" => 5,
+    "timorous" => 16,
+    "timothy" => 3,
+    "tin" => 21,
+    "ting" => 23,
+    "tinge" => 5,
+    "tinged" => 20,
+    "tingle" => 5,
+    "tingled" => 20,
+    "tingling" => 21,
+    "tiniest" => 16,
+    "tinkered" => 20,
+    "tinkled" => 20,
+    "tinkling" => 21,
+    "tinny" => 17,
+    "tint" => 5,
+    "tinted" => 20,
+    "tiny" => 17,
+    "tip" => 5,
+    "tipped" => 4,
+    "tipping" => 5,
+    "tips" => 5,
+    "tipsy" => 16,
+    "tiptoe" => 21,
+    "tiptoed" => 20,
+    "tiptoeing" => 4,
+    "tiptoes" => 5,
+    "tire" => 5,
+    "tired" => 20,
+    "tiresome" => 16,
+    "tissue" => 5,
+    "titan" => 3,
+    "tither" => 17,
+    "title" => 5,
+    "titled" => 20,
+    "titles" => 5,
+    "titter" => 5,
+    "tittered" => 20,
+    "tittering" => 21,
+    "titus" => 2,
+    "to" => 64,
+    "toast" => 5,
+    "tobacco" => 5,
+    "tobacconist" => 1,
+    "today" => 17,
+    "todd" => 2,
+    "toe" => 5,
+    "toes" => 5,
+    "toffee" => 5,
+    "together" => 16,
+    "toggle" => 5,
+    "toggled" => 20,
+    "toggles" => 5,
+    "toggling" => 21,
+    "toil" => 5,
+    "toiled" => 20,
+    "toilet" => 5,
+    "toilets" => 5,
+    "toils" => 5,
+    "toilsome" => 16,
+    "token" => 21,
+    "tokenizing" => 21,
+    "tokens" => 5,
+    "told" => 4,
+    "tolerable" => 16,
+    "tolerably" => 32,
+    "tolerance" => 5,
+    "tolerances" => 5,
+    "tolerant" => 16,
+    "tolerate" => 4,
+    "tolerated" => 20,
+    "tolerates" => 4,
+    "tolerating" => 21,
+    "toll" => 5,
+    "tom" => 7,
+    "tomato" => 5,
+    "tomb" => 5,
+    "tomboy" => 1,
+    "tombstone" => 5,
+    "tombstones" => 5,
+    "tome" => 1,
+    "tomes" => 1,
+    "tomfoolery" => 1,
+    "tommy" => 3,
+    "tomorrow" => 1,
+    "toms" => 5,
+    "ton" => 1,
+    "tone" => 261,
+    "toned" => 20,
+    "toner" => 1,
+    "tones" => 5,
+    "tong" => 5,
+    "tonga" => 2,
+    "tongs" => 5,
+    "tongue" => 5,
+    "tongues" => 5,
+    "tonic" => 21,
+    "tonnage" => 1,
+    "tons" => 1,
+    "tony" => 19,
+    "took" => 4,
+    "tool" => 5,
+    "toolbar" => 1,
+    "toolbox" => 1,
+    "toolchain" => 1,
+    "tooled" => 20,
+    "tooling" => 21,
+    "toolkit" => 1,
+    "toolkits" => 1,
+    "tools" => 5,
+    "tooth" => 5,
+    "toothache" => 1,
+    "top" => 21,
+    "topic" => 17,
+    "topics" => 1,
+    "topmost" => 16,
+    "topography" => 1,
+    "topological" => 16,
+    "topology" => 1,
+    "topped" => 20,
+    "toppers" => 1,
+    "toppled" => 20,
+    "toppling" => 21,
+    "tops" => 5,
+    "tor" => 17,
+    "torch" => 5,
+    "torches" => 5,
+    "torchlight" => 1,
+    "tore" => 21,
+    "torment" => 5,
+    "tormented" => 20,
+    "tormenting" => 21,
+    "tormentor" => 1,
+    "torments" => 5,
+    "torn" => 20,
+    "torpedoes" => 5,
+    "torpor" => 1,
+    "torrent" => 21,
+    "torrents" => 5,
+    "torsion" => 1,
+    "tort" => 17,
+    "tortoise" => 1,
+    "tortuously" => 32,
+    "torture" => 5,
+    "tortured" => 20,
+    "torturer" => 1,
+    "tortures" => 5,
+    "torturing" => 21,
+    "tory" => 19,
+    "toss" => 5,
+    "tossed" => 20,
+    "tossing" => 21,
+    "total" => 21,
+    "totally" => 32,
+    "totals" => 5,
+    "tote" => 5,
+    "totter" => 5,
+    "tottered" => 20,
+    "tottering" => 21,
+    "touch" => 5,
+    "touched" => 20,
+    "touches" => 5,
+    "touching" => 85,
+    "touchingly" => 32,
+    "tough" => 21,
+    "tougher" => 17,
+    "tour" => 5,
+    "tourist" => 5,
+    "tourists" => 5,
+    "tournament" => 1,
+    "tournaments" => 1,
+    "tours" => 5,
+    "tout" => 5,
+    "toward" => 80,
+    "towards" => 64,
+    "towel" => 5,
+    "towels" => 5,
+    "tower" => 5,
+    "towered" => 20,
+    "towering" => 21,
+    "towers" => 5,
+    "town" => 1,
+    "towns" => 1,
+    "toxic" => 16,
+    "toy" => 21,
+    "toys" => 5,
+    "trace" => 5,
+    "traceable" => 16,
+    "traced" => 20,
+    "tracer" => 1,
+    "traces" => 5,
+    "tracing" => 21,
+    "track" => 5,
+    "tracked" => 20,
+    "tracker" => 1,
+    "tracking" => 21,
+    "trackless" => 16,
+    "tracks" => 5,
+    "tract" => 5,
+    "tractable" => 16,
+    "traction" => 5,
+    "tractor" => 5,
+    "tracts" => 5,
+    "tracy" => 2,
+    "trade" => 21,
+    "traded" => 20,
+    "trademark" => 21,
+    "trademarks" => 5,
+    "tradeoff" => 1,
+    "trader" => 17,
+    "trades" => 5,
+    "tradesman" => 1,
+    "tradesmen" => 1,
+    "tradespeople" => 1,
+    "trading" => 21,
+    "tradition" => 5,
+    "traditional" => 17,
+    "traditionalists" => 1,
+    "traditionally" => 32,
+    "traditions" => 5,
+    "trafalgar" => 2,
+    "traffic" => 21,
+    "tragedy" => 1,
+    "tragic" => 17,
+    "tragically" => 32,
+    "trail" => 5,
+    "trailer" => 5,
+    "trailers" => 5,
+    "trailing" => 21,
+    "trails" => 5,
+    "train" => 5,
+    "trained" => 20,
+    "training" => 21,
+    "trains" => 5,
+    "trait" => 1,
+    "traitor" => 21,
+    "traits" => 1,
+    "trajectory" => 1,
+    "tramp" => 5,
+    "tramped" => 20,
+    "trample" => 5,
+    "trampled" => 20,
+    "trampling" => 21,
+    "trampoline" => 5,
+    "trance" => 5,
+    "tranquil" => 16,
+    "tranquility" => 1,
+    "tranquillise" => 4,
+    "tranquillised" => 20,
+    "tranquillity" => 1,
+    "tranquilly" => 32,
+    "trans" => 21,
+    "transact" => 4,
+    "transacted" => 20,
+    "transacting" => 21,
+    "transaction" => 1,
+    "transactional" => 16,
+    "transactions" => 1,
+    "transatlantic" => 16,
+    "transceiver" => 1,
+    "transcendent" => 17,
+    "transcendental" => 17,
+    "transcribe" => 4,
+    "transcribed" => 20,
+    "transcriber" => 1,
+    "transcribers" => 4,
+    "transcribes" => 4,
+    "transcribing" => 21,
+    "transcript" => 5,
+    "transcription" => 1,
+    "transcriptions" => 1,
+    "transcripts" => 5,
+    "transducer" => 1,
+    "transducers" => 1,
+    "transfer" => 5,
+    "transferable" => 5,
+    "transferred" => 4,
+    "transferring" => 5,
+    "transfers" => 5,
+    "transfixed" => 20,
+    "transform" => 5,
+    "transformable" => 5,
+    "transformation" => 1,
+    "transformations" => 1,
+    "transformed" => 20,
+    "transformer" => 1,
+    "transformers" => 5,
+    "transforming" => 21,
+    "transforms" => 5,
+    "transient" => 17,
+    "transiently" => 32,
+    "transit" => 5,
+    "transition" => 5,
+    "transitional" => 16,
+    "transitions" => 5,
+    "transitive" => 17,
+    "transitively" => 32,
+    "transitivity" => 1,
+    "transitory" => 16,
+    "translatable" => 21,
+    "translate" => 5,
+    "translated" => 20,
+    "translates" => 5,
+    "translating" => 21,
+    "translation" => 5,
+    "translations" => 5,
+    "translator" => 1,
+    "translators" => 1,
+    "transliterate" => 4,
+    "transliterated" => 20,
+    "transliterates" => 4,
+    "transliterating" => 21,
+    "transliteration" => 5,
+    "transliterations" => 4,
+    "transmission" => 1,
+    "transmit" => 4,
+    "transmits" => 4,
+    "transmitted" => 20,
+    "transmitter" => 1,
+    "transmitters" => 1,
+    "transmitting" => 5,
+    "transmogrified" => 20,
+    "transmogrifying" => 21,
+    "transmutable" => 4,
+    "transmutation" => 1,
+    "transmutations" => 1,
+    "transmute" => 4,
+    "transmuted" => 20,
+    "transmutes" => 4,
+    "transmuting" => 21,
+    "transparency" => 1,
+    "transparent" => 16,
+    "transparently" => 32,
+    "transpired" => 20,
+    "transport" => 5,
+    "transportation" => 1,
+    "transported" => 20,
+    "transporting" => 21,
+    "transports" => 5,
+    "transpose" => 21,
+    "transposed" => 20,
+    "transposes" => 5,
+    "transposing" => 21,
+    "transposition" => 5,
+    "transpositions" => 5,
+    "transverse" => 21,
+    "transversely" => 32,
+    "trap" => 5,
+    "trapped" => 20,
+    "trapping" => 5,
+    "traps" => 5,
+    "trash" => 5,
+    "trashed" => 20,
+    "travail" => 5,
+    "travel" => 5,
+    "traveling" => 21,
+    "travelled" => 20,
+    "traveller" => 1,
+    "traveller's" => 1,
+    "travellers" => 1,
+    "travelling" => 21,
+    "travels" => 5,
+    "traversal" => 1,
+    "traversals" => 1,
+    "traverse" => 21,
+    "traversed" => 20,
+    "traverses" => 5,
+    "traversing" => 21,
+    "travis" => 2,
+    "trawl" => 5,
+    "tray" => 5,
+    "trays" => 5,
+    "treacherous" => 16,
+    "treachery" => 1,
+    "treacle" => 5,
+    "tread" => 5,
+    "treading" => 21,
+    "treadmill" => 5,
+    "treason" => 1,
+    "treasure" => 5,
+    "treasured" => 20,
+    "treasures" => 5,
+    "treasury" => 3,
+    "treat" => 5,
+    "treated" => 20,
+    "treaties" => 5,
+    "treating" => 21,
+    "treatises" => 1,
+    "treatment" => 1,
+    "treats" => 5,
+    "treaty" => 5,
+    "treble" => 21,
+    "tree" => 5,
+    "trees" => 5,
+    "trellis" => 5,
+    "tremble" => 5,
+    "trembled" => 20,
+    "trembling" => 21,
+    "tremendous" => 16,
+    "tremendously" => 32,
+    "tremor" => 5,
+    "tremulous" => 16,
+    "trend" => 5,
+    "trends" => 5,
+    "trespasser" => 1,
+    "trespassers" => 5,
+    "tresses" => 5,
+    "trevor" => 2,
+    "triad" => 1,
+    "triage" => 5,
+    "trial" => 21,
+    "trials" => 5,
+    "triangle" => 1,
+    "triangles" => 1,
+    "triangular" => 16,
+    "triangulated" => 20,
+    "triangulates" => 4,
+    "triangulation" => 21,
+    "tribal" => 17,
+    "tribe" => 5,
+    "tribes" => 5,
+    "tribunals" => 1,
+    "tribune" => 1,
+    "tributaries" => 1,
+    "tributary" => 17,
+    "tribute" => 5,
+    "trice" => 5,
+    "trick" => 21,
+    "tricked" => 20,
+    "trickery" => 1,
+    "trickier" => 16,
+    "tricking" => 21,
+    "trickle" => 5,
+    "trickled" => 20,
+    "trickling" => 21,
+    "tricks" => 5,
+    "tricky" => 16,
+    "tried" => 20,
+    "triennial" => 17,
+    "tries" => 5,
+    "trifle" => 5,
+    "trifled" => 20,
+    "trifles" => 5,
+    "trifling" => 21,
+    "trigger" => 21,
+    "triggered" => 20,
+    "triggering" => 21,
+    "triggers" => 5,
+    "trigonometric" => 16,
+    "trigonometry" => 1,
+    "trilled" => 20,
+    "trillium" => 1,
+    "trim" => 21,
+    "trimly" => 32,
+    "trimmed" => 20,
+    "trimming" => 5,
+    "trims" => 5,
+    "trinity" => 3,
+    "trinkets" => 5,
+    "trip" => 21,
+    "triple" => 21,
+    "triples" => 5,
+    "triplet" => 1,
+    "triplets" => 1,
+    "tripoli" => 2,
+    "tripped" => 4,
+    "tripping" => 21,
+    "trips" => 5,
+    "tristan" => 2,
+    "trite" => 17,
+    "triumph" => 5,
+    "triumphant" => 16,
+    "triumphantly" => 32,
+    "triumphed" => 20,
+    "trivia" => 1,
+    "trivial" => 17,
+    "trivially" => 32,
+    "trod" => 5,
+    "trojan" => 17,
+    "trolley" => 5,
+    "trolling" => 21,
+    "trombones" => 5,
+    "troop" => 5,
+    "trooped" => 20,
+    "troopers" => 5,
+    "trooping" => 21,
+    "troops" => 5,
+    "trophy" => 5,
+    "tropic" => 17,
+    "tropical" => 17,
+    "tropics" => 1,
+    "trot" => 5,
+    "trotted" => 4,
+    "trotting" => 21,
+    "trouble" => 5,
+    "troubled" => 20,
+    "troubles" => 5,
+    "troubleshooting" => 21,
+    "troublesome" => 16,
+    "troubling" => 21,
+    "trough" => 5,
+    "trounce" => 5,
+    "troupe" => 5,
+    "trouser" => 5,
+    "trousers" => 5,
+    "trout" => 5,
+    "trove" => 1,
+    "troy" => 18,
+    "truce" => 1,
+    "truck" => 5,
+    "truculent" => 16,
+    "trudge" => 5,
+    "true" => 21,
+    "truest" => 16,
+    "truly" => 48,
+    "trump" => 7,
+    "trumpery" => 17,
+    "trumpet" => 5,
+    "trumps" => 7,
+    "truncate" => 20,
+    "truncated" => 20,
+    "truncates" => 4,
+    "truncating" => 21,
+    "truncation" => 21,
+    "trundled" => 20,
+    "trunk" => 5,
+    "trunks" => 5,
+    "trust" => 21,
+    "trusted" => 20,
+    "trustees" => 5,
+    "trusting" => 21,
+    "trusts" => 5,
+    "trustworthiness" => 17,
+    "trustworthy" => 16,
+    "trusty" => 17,
+    "truth" => 5,
+    "truthful" => 16,
+    "truthfulness" => 17,
+    "try" => 21,
+    "trying" => 21,
+    "tub" => 5,
+    "tube" => 5,
+    "tubes" => 5,
+    "tuck" => 5,
+    "tucked" => 20,
+    "tucker" => 7,
+    "tudor" => 17,
+    "tuesday" => 1,
+    "tug" => 5,
+    "tugged" => 4,
+    "tugging" => 5,
+    "tulip" => 1,
+    "tumbled" => 20,
+    "tumbler" => 1,
+    "tumbling" => 21,
+    "tumult" => 5,
+    "tumultuous" => 16,
+    "tumultuously" => 32,
+    "tune" => 5,
+    "tuned" => 20,
+    "tunelessly" => 32,
+    "tunes" => 5,
+    "tungsten" => 1,
+    "tunic" => 1,
+    "tuning" => 21,
+    "tunnel" => 5,
+    "tunneled" => 20,
+    "tunneling" => 21,
+    "tunnels" => 5,
+    "tuple" => 1,
+    "turban" => 5,
+    "turbaned" => 20,
+    "turbans" => 5,
+    "turbine" => 1,
+    "turbo" => 21,
+    "turbulence" => 1,
+    "turbulent" => 16,
+    "tureen" => 1,
+    "turf" => 5,
+    "turgid" => 16,
+    "turin" => 2,
+    "turing" => 2,
+    "turk" => 19,
+    "turkey" => 19,
+    "turkeys" => 1,
+    "turkish" => 18,
+    "turks" => 1,
+    "turmoil" => 5,
+    "turn" => 5,
+    "turnaround" => 1,
+    "turned" => 20,
+    "turner" => 3,
+    "turning" => 21,
+    "turnings" => 5,
+    "turnkey" => 21,
+    "turnkeys" => 5,
+    "turns" => 5,
+    "turtle" => 5,
+    "turtles" => 5,
+    "tut" => 7,
+    "tutor" => 5,
+    "tutored" => 20,
+    "tutorial" => 17,
+    "tutorials" => 1,
+    "tutors" => 5,
+    "tux" => 1,
+    "twain" => 23,
+    "tweak" => 5,
+    "tweaked" => 20,
+    "tweaking" => 21,
+    "tweaks" => 5,
+    "twee" => 17,
+    "tweed" => 3,
+    "tweet" => 5,
+    "tweets" => 5,
+    "twelfth" => 17,
+    "twelve" => 1,
+    "twentieth" => 17,
+    "twenty" => 1,
+    "twiddling" => 21,
+    "twig" => 5,
+    "twiggy" => 16,
+    "twilight" => 21,
+    "twin" => 21,
+    "twine" => 5,
+    "twined" => 20,
+    "twinkle" => 5,
+    "twinkled" => 20,
+    "twinkling" => 21,
+    "twins" => 5,
+    "twist" => 5,
+    "twisted" => 20,
+    "twister" => 1,
+    "twisting" => 21,
+    "twitch" => 5,
+    "twitching" => 21,
+    "twitter" => 7,
+    "two" => 1,
+    "twofold" => 16,
+    "twopence" => 1,
+    "twos" => 1,
+    "tycoon" => 1,
+    "tying" => 5,
+    "tyler" => 2,
+    "tympani" => 1,
+    "type" => 5,
+    "typecast" => 4,
+    "typecasts" => 4,
+    "typed" => 20,
+    "types" => 5,
+    "typescript" => 5,
+    "typeset" => 20,
+    "typesetting" => 5,
+    "typewrite" => 4,
+    "typewriter" => 1,
+    "typewriting" => 21,
+    "typewritten" => 20,
+    "typhoon" => 5,
+    "typical" => 17,
+    "typically" => 32,
+    "typifies" => 4,
+    "typify" => 4,
+    "typing" => 21,
+    "typo" => 5,
+    "typographic" => 16,
+    "typographical" => 16,
+    "typography" => 1,
+    "typos" => 5,
+    "tyrannical" => 16,
+    "tyranny" => 1,
+    "tyrant" => 21,
+    "tyrants" => 5,
+    "tyres" => 5,
+    "tyros" => 1,
+    "ubiquitous" => 16,
+    "uganda" => 2,
+    "ugandans" => 1,
+    "ugliest" => 16,
+    "ugliness" => 21,
+    "ugly" => 21,
+    "ukraine" => 2,
+    "ukrainian" => 19,
+    "ulster" => 3,
+    "ultimate" => 21,
+    "ultimately" => 32,
+    "ultra" => 17,
+    "ultramarine" => 17,
+    "ultraviolet" => 17,
+    "ulysses" => 2,
+    "umbrella" => 5,
+    "umlaut" => 5,
+    "unabated" => 20,
+    "unable" => 21,
+    "unabridged" => 17,
+    "unacceptable" => 17,
+    "unacceptably" => 32,
+    "unaccountable" => 16,
+    "unaccountably" => 32,
+    "unaccustomed" => 20,
+    "unacknowledged" => 20,
+    "unacquainted" => 20,
+    "unaddressed" => 20,
+    "unadorned" => 20,
+    "unaffected" => 21,
+    "unaligned" => 20,
+    "unalloyed" => 20,
+    "unalterable" => 17,
+    "unaltered" => 21,
+    "unambiguous" => 16,
+    "unanimous" => 16,
+    "unanimously" => 32,
+    "unanswered" => 20,
+    "unanticipated" => 20,
+    "unapproachable" => 16,
+    "unapproved" => 20,
+    "unarmed" => 20,
+    "unary" => 17,
+    "unashamed" => 20,
+    "unassigned" => 20,
+    "unattainable" => 17,
+    "unattended" => 20,
+    "unattributed" => 20,
+    "unauthenticated" => 4,
+    "unauthorized" => 20,
+    "unavailability" => 1,
+    "unavailable" => 16,
+    "unavailing" => 16,
+    "unavoidable" => 17,
+    "unavoidably" => 32,
+    "unaware" => 20,
+    "unawares" => 16,
+    "unbarred" => 20,
+    "unbearable" => 16,
+    "unbeatable" => 16,
+    "unbelief" => 1,
+    "unbelievable" => 16,
+    "unbending" => 21,
+    "unbiased" => 20,
+    "unbind" => 5,
+    "unblock" => 5,
+    "unbound" => 20,
+    "unbounded" => 20,
+    "unboxed" => 20,
+    "unbreakable" => 17,
+    "unbridled" => 20,
+    "unbroken" => 20,
+    "unbutton" => 5,
+    "uncased" => 20,
+    "uncaught" => 20,
+    "unceasing" => 16,
+    "uncertain" => 272,
+    "uncertainty" => 1,
+    "unchain" => 5,
+    "unchangeable" => 17,
+    "unchanged" => 20,
+    "unchanging" => 5,
+    "unchecked" => 20,
+    "uncial" => 17,
+    "uncivil" => 16,
+    "uncivilized" => 20,
+    "unclassified" => 21,
+    "uncle" => 5,
+    "unclean" => 16,
+    "unclear" => 20,
+    "uncles" => 5,
+    "uncollected" => 20,
+    "uncolored" => 21,
+    "uncombed" => 20,
+    "uncomfortable" => 16,
+    "uncomfortably" => 32,
+    "uncommitted" => 20,
+    "uncommon" => 21,
+    "uncomplainingly" => 32,
+    "uncompressed" => 20,
+    "uncompromising" => 16,
+    "unconcealed" => 20,
+    "unconcerned" => 20,
+    "unconditional" => 17,
+    "unconditionally" => 32,
+    "uncongenial" => 16,
+    "unconscious" => 17,
+    "unconstrained" => 20,
+    "unconsumed" => 20,
+    "uncontrollable" => 17,
+    "uncontrollably" => 32,
+    "uncontrolled" => 20,
+    "uncontroversial" => 16,
+    "unconverted" => 20,
+    "uncork" => 21,
+    "uncountable" => 17,
+    "uncouth" => 16,
+    "uncover" => 21,
+    "unction" => 1,
+    "uncut" => 16,
+    "undated" => 20,
+    "undeceive" => 4,
+    "undecided" => 21,
+    "undeclared" => 20,
+    "undefined" => 20,
+    "undeniable" => 16,
+    "under" => 81,
+    "underbrush" => 5,
+    "underestimates" => 5,
+    "underflow" => 5,
+    "undergo" => 4,
+    "undergoes" => 4,
+    "undergoing" => 21,
+    "undergone" => 4,
+    "undergraduate" => 17,
+    "underground" => 21,
+    "undergrowth" => 1,
+    "underhand" => 21,
+    "underlie" => 4,
+    "underlies" => 4,
+    "underline" => 21,
+    "underlined" => 20,
+    "underlines" => 5,
+    "underlining" => 21,
+    "underlying" => 21,
+    "underneath" => 81,
+    "underpinning" => 5,
+    "underpinnings" => 5,
+    "underpins" => 4,
+    "underscore" => 5,
+    "underscored" => 20,
+    "underscores" => 5,
+    "underscoring" => 21,
+    "understand" => 4,
+    "understandable" => 4,
+    "understandably" => 32,
+    "understanding" => 21,
+    "understandingly" => 32,
+    "understands" => 4,
+    "understood" => 20,
+    "understudy" => 5,
+    "undertake" => 5,
+    "undertaken" => 4,
+    "undertaking" => 21,
+    "undertone" => 5,
+    "undertook" => 4,
+    "underway" => 1,
+    "underwear" => 1,
+    "underwent" => 4,
+    "underwriting" => 21,
+    "undesirable" => 17,
+    "undesired" => 20,
+    "undetectable" => 16,
+    "undetected" => 20,
+    "undid" => 4,
+    "undiminished" => 20,
+    "undirected" => 16,
+    "undiscovered" => 4,
+    "undisturbed" => 20,
+    "undivided" => 20,
+    "undo" => 21,
+    "undoable" => 17,
+    "undocumented" => 4,
+    "undoes" => 4,
+    "undoing" => 5,
+    "undone" => 21,
+    "undoubtedly" => 32,
+    "undreamed" => 4,
+    "undue" => 16,
+    "undulations" => 20,
+    "uneasily" => 32,
+    "uneasiness" => 1,
+    "uneasy" => 21,
+    "unedited" => 20,
+    "uneducated" => 20,
+    "unemployed" => 16,
+    "unencumbered" => 20,
+    "unenforced" => 4,
+    "unequal" => 17,
+    "unequalled" => 20,
+    "unequally" => 32,
+    "unequivocal" => 17,
+    "unessential" => 16,
+    "uneven" => 20,
+    "unexampled" => 4,
+    "unexpanded" => 16,
+    "unexpected" => 17,
+    "unexpectedly" => 32,
+    "unexplored" => 4,
+    "unfailing" => 16,
+    "unfailingly" => 32,
+    "unfair" => 20,
+    "unfamiliar" => 17,
+    "unfathomable" => 16,
+    "unfavorably" => 32,
+    "unfavourable" => 17,
+    "unfeasible" => 16,
+    "unfeeling" => 16,
+    "unfeigned" => 20,
+    "unfilled" => 20,
+    "unfiltered" => 20,
+    "unfinished" => 20,
+    "unfit" => 20,
+    "unfitness" => 1,
+    "unfitted" => 21,
+    "unfixed" => 20,
+    "unflagging" => 21,
+    "unfold" => 5,
+    "unforced" => 20,
+    "unforeseen" => 4,
+    "unforgettable" => 16,
+    "unforgivably" => 32,
+    "unformed" => 4,
+    "unfortunate" => 17,
+    "unfrequented" => 4,
+    "unfriendly" => 17,
+    "unfulfilled" => 20,
+    "ungovernable" => 16,
+    "ungraceful" => 16,
+    "ungracefully" => 32,
+    "ungrateful" => 16,
+    "unguarded" => 16,
+    "unhallowed" => 20,
+    "unhandled" => 16,
+    "unhappily" => 32,
+    "unhappiness" => 1,
+    "unhappy" => 21,
+    "unharmed" => 4,
+    "unhealthy" => 16,
+    "unheard" => 20,
+    "unheeded" => 4,
+    "unhelpful" => 16,
+    "unholy" => 17,
+    "unhygienic" => 16,
+    "unidentified" => 4,
+    "unidiomatic" => 17,
+    "unidirectional" => 17,
+    "unification" => 5,
+    "unified" => 20,
+    "unifies" => 4,
+    "uniform" => 21,
+    "uniformly" => 32,
+    "unify" => 4,
+    "unifying" => 21,
+    "unimaginable" => 16,
+    "unimpeachable" => 16,
+    "unimplemented" => 4,
+    "unimportant" => 16,
+    "uninflected" => 16,
+    "uninformed" => 20,
+    "uninhabited" => 16,
+    "uninitialized" => 4,
+    "uninitiated" => 4,
+    "uninstructed" => 4,
+    "unintelligible" => 16,
+    "unintended" => 16,
+    "unintentional" => 17,
+    "uninterested" => 20,
+    "uninteresting" => 16,
+    "uninterpreted" => 20,
+    "uninterrupted" => 16,
+    "union" => 23,
+    "unionise" => 4,
+    "unions" => 5,
+    "unique" => 17,
+    "uniquely" => 32,
+    "uniqueness" => 17,
+    "unison" => 1,
+    "unit" => 1,
+    "unitary" => 17,
+    "unite" => 5,
+    "united" => 20,
+    "unites" => 5,
+    "units" => 1,
+    "unity" => 1,
+    "universal" => 17,
+    "universalised" => 20,
+    "universally" => 32,
+    "universe" => 3,
+    "universities" => 1,
+    "university" => 1,
+    "unix" => 3,
+    "unjust" => 16,
+    "unjustly" => 32,
+    "unkempt" => 16,
+    "unkindly" => 48,
+    "unkindness" => 1,
+    "unknown" => 21,
+    "unknowns" => 5,
+    "unlabeled" => 20,
+    "unlawful" => 17,
+    "unleash" => 5,
+    "unless" => 512,
+    "unlike" => 85,
+    "unlikely" => 17,
+    "unlimited" => 21,
+    "unlink" => 5,
+    "unlinked" => 20,
+    "unlisted" => 20,
+    "unload" => 5,
+    "unlock" => 5,
+    "unlocked" => 20,
+    "unlocking" => 21,
+    "unlocks" => 5,
+    "unluckily" => 32,
+    "unlucky" => 17,
+    "unmaintained" => 20,
+    "unmanageable" => 16,
+    "unmarked" => 20,
+    "unmarried" => 17,
+    "unmask" => 5,
+    "unmatched" => 5,
+    "unmentionable" => 17,
+    "unmentioned" => 4,
+    "unmerged" => 16,
+    "unmet" => 16,
+    "unmistakable" => 16,
+    "unmistakably" => 32,
+    "unmodified" => 21,
+    "unmolested" => 4,
+    "unmoved" => 20,
+    "unnameable" => 16,
+    "unnamed" => 20,
+    "unnatural" => 17,
+    "unnecessarily" => 32,
+    "unnecessary" => 16,
+    "unneeded" => 20,
+    "unnoticed" => 4,
+    "unnumbered" => 4,
+    "unobservant" => 16,
+    "unobserved" => 4,
+    "unobtrusive" => 16,
+    "unoccupied" => 20,
+    "unofficial" => 16,
+    "unopened" => 20,
+    "unordered" => 20,
+    "unorganized" => 20,
+    "unoriginal" => 17,
+    "unorthodox" => 16,
+    "unpack" => 5,
+    "unpaired" => 4,
+    "unpalatable" => 16,
+    "unparalleled" => 4,
+    "unpardonable" => 16,
+    "unperceived" => 20,
+    "unpin" => 4,
+    "unpinned" => 20,
+    "unplaced" => 4,
+    "unpleasant" => 17,
+    "unpleasantness" => 1,
+    "unplug" => 5,
+    "unpolished" => 20,
+    "unpopulated" => 20,
+    "unpractised" => 20,
+    "unprecedented" => 16,
+    "unpredictability" => 1,
+    "unpredictable" => 17,
+    "unpredictably" => 32,
+    "unprejudiced" => 20,
+    "unprepared" => 20,
+    "unprintable" => 17,
+    "unprivileged" => 20,
+    "unprocessed" => 20,
+    "unprofitable" => 16,
+    "unpromising" => 20,
+    "unpropitious" => 16,
+    "unprotected" => 20,
+    "unproven" => 20,
+    "unpublished" => 20,
+    "unqualified" => 20,
+    "unquenchable" => 16,
+    "unquestionable" => 16,
+    "unquestionably" => 32,
+    "unquiet" => 20,
+    "unravel" => 5,
+    "unravelled" => 20,
+    "unravelling" => 5,
+    "unreachable" => 17,
+    "unread" => 21,
+    "unreadable" => 21,
+    "unreal" => 16,
+    "unrealistic" => 16,
+    "unreality" => 1,
+    "unreasonable" => 16,
+    "unreasonably" => 32,
+    "unreasoning" => 16,
+    "unrecognized" => 20,
+    "unrecoverable" => 17,
+    "unrefined" => 21,
+    "unregistered" => 20,
+    "unrelated" => 16,
+    "unreleased" => 4,
+    "unreliability" => 1,
+    "unreliable" => 17,
+    "unremitting" => 21,
+    "unreported" => 16,
+    "unresolved" => 20,
+    "unrest" => 1,
+    "unrestrained" => 20,
+    "unrestricted" => 20,
+    "unrewarding" => 20,
+    "unroll" => 4,
+    "unrolled" => 20,
+    "unrolling" => 21,
+    "unrolls" => 4,
+    "unromantic" => 16,
+    "unsafe" => 20,
+    "unsaid" => 20,
+    "unsatisfactory" => 17,
+    "unsatisfied" => 20,
+    "unsatisfying" => 20,
+    "unsaved" => 20,
+    "unscientific" => 16,
+    "unseal" => 5,
+    "unseat" => 5,
+    "unseen" => 21,
+    "unselected" => 20,
+    "unselfish" => 16,
+    "unset" => 20,
+    "unshaken" => 21,
+    "unsigned" => 20,
+    "unsocial" => 16,
+    "unsolved" => 20,
+    "unsophisticated" => 20,
+    "unsorted" => 20,
+    "unsought" => 4,
+    "unsound" => 16,
+    "unsoundness" => 17,
+    "unspeakable" => 17,
+    "unspeakably" => 32,
+    "unspecified" => 20,
+    "unstable" => 20,
+    "unstained" => 20,
+    "unsteady" => 20,
+    "unstressed" => 20,
+    "unstructured" => 20,
+    "unsuccessful" => 16,
+    "unsuitable" => 16,
+    "unsullied" => 20,
+    "unsupervised" => 20,
+    "unsupported" => 20,
+    "unsure" => 16,
+    "unsurprising" => 21,
+    "unsympathetic" => 17,
+    "unsystematic" => 16,
+    "untagged" => 16,
+    "untamed" => 20,
+    "untaught" => 4,
+    "untenable" => 16,
+    "unterminated" => 16,
+    "untested" => 4,
+    "unthinkable" => 16,
+    "untidy" => 20,
+    "until" => 576,
+    "untimely" => 16,
+    "untitled" => 20,
+    "unto" => 64,
+    "untouched" => 20,
+    "untracked" => 16,
+    "untranslated" => 4,
+    "untrue" => 16,
+    "untrusted" => 16,
+    "untwist" => 5,
+    "unusable" => 16,
+    "unused" => 20,
+    "unusual" => 17,
+    "unutterable" => 17,
+    "unutterably" => 32,
+    "unvarying" => 5,
+    "unvoiced" => 20,
+    "unwanted" => 20,
+    "unwashed" => 20,
+    "unwavering" => 16,
+    "unwearied" => 4,
+    "unwelcome" => 20,
+    "unwelcoming" => 21,
+    "unwell" => 16,
+    "unwholesome" => 16,
+    "unwieldy" => 16,
+    "unwilling" => 21,
+    "unwillingness" => 1,
+    "unwind" => 5,
+    "unwise" => 16,
+    "unwonted" => 16,
+    "unworldly" => 16,
+    "unworthiness" => 1,
+    "unworthy" => 21,
+    "unwound" => 4,
+    "unwrap" => 5,
+    "unwrapped" => 20,
+    "unwrapping" => 5,
+    "unwrinkled" => 20,
+    "unwritten" => 20,
+    "unyielding" => 16,
+    "unzip" => 5,
+    "unzipped" => 20,
+    "unzipping" => 4,
+    "up" => 85,
+    "upbraid" => 5,
+    "upbraided" => 20,
+    "upcoming" => 21,
+    "update" => 5,
+    "updated" => 20,
+    "updates" => 5,
+    "updating" => 21,
+    "upfront" => 21,
+    "upgrade" => 5,
+    "upgraded" => 20,
+    "upgrades" => 5,
+    "upgrading" => 21,
+    "upheld" => 4,
+    "uphill" => 17,
+    "uphold" => 4,
+    "upholding" => 21,
+    "upholds" => 4,
+    "upholstery" => 1,
+    "upkeep" => 5,
+    "uplift" => 5,
+    "uplifted" => 20,
+    "upload" => 5,
+    "uploaded" => 20,
+    "uploading" => 21,
+    "uploads" => 5,
+    "upon" => 64,
+    "upper" => 17,
+    "uppercase" => 21,
+    "uppercased" => 20,
+    "uppermost" => 16,
+    "upraised" => 20,
+    "upright" => 21,
+    "uproar" => 5,
+    "ups" => 7,
+    "upset" => 21,
+    "upsetting" => 21,
+    "upshot" => 1,
+    "upside" => 65,
+    "upsilon" => 1,
+    "upstairs" => 17,
+    "upstart" => 21,
+    "upstream" => 21,
+    "upturn" => 5,
+    "upward" => 17,
+    "upwards" => 1,
+    "uranus" => 2,
+    "urban" => 18,
+    "urbanization" => 1,
+    "urchin" => 1,
+    "urdu" => 18,
+    "urge" => 5,
+    "urged" => 20,
+    "urgency" => 1,
+    "urgent" => 16,
+    "urgently" => 32,
+    "urging" => 21,
+    "urn" => 5,
+    "us" => 258,
+    "usa" => 3,
+    "usability" => 1,
+    "usable" => 16,
+    "usage" => 1,
+    "usages" => 1,
+    "use" => 5,
+    "used" => 20,
+    "useful" => 16,
+    "usefully" => 32,
+    "usefulness" => 17,
+    "useless" => 16,
+    "uselessly" => 32,
+    "uselessness" => 17,
+    "user" => 1,
+    "username" => 1,
+    "users" => 1,
+    "uses" => 5,
+    "ushered" => 20,
+    "ushering" => 21,
+    "using" => 21,
+    "usual" => 17,
+    "usually" => 32,
+    "usurpation" => 1,
+    "utah" => 2,
+    "utensils" => 1,
+    "utilise" => 4,
+    "utilises" => 4,
+    "utilising" => 21,
+    "utilities" => 1,
+    "utility" => 17,
+    "utilization" => 1,
+    "utilize" => 4,
+    "utilized" => 20,
+    "utilizes" => 4,
+    "utilizing" => 21,
+    "utmost" => 17,
+    "utter" => 20,
+    "utterance" => 1,
+    "utterances" => 1,
+    "uttered" => 20,
+    "uttering" => 21,
+    "utterly" => 32,
+    "uzbek" => 19,
+    "vacancies" => 1,
+    "vacancy" => 1,
+    "vacant" => 16,
+    "vacantly" => 32,
+    "vacated" => 20,
+    "vacation" => 5,
+    "vacillating" => 21,
+    "vacuous" => 16,
+    "vagabond" => 21,
+    "vagabonds" => 5,
+    "vagaries" => 1,
+    "vagrant" => 17,
+    "vagrants" => 1,
+    "vague" => 21,
+    "vaguely" => 32,
+    "vagueness" => 21,
+    "vaguest" => 16,
+    "vain" => 20,
+    "vainly" => 32,
+    "val" => 3,
+    "valance" => 1,
+    "vale" => 1,
+    "valence" => 1,
+    "valencia" => 3,
+    "valentine" => 3,
+    "vales" => 1,
+    "valet" => 5,
+    "valid" => 16,
+    "validate" => 4,
+    "validated" => 20,
+    "validates" => 4,
+    "validating" => 21,
+    "validation" => 5,
+    "validations" => 1,
+    "validity" => 1,
+    "valise" => 1,
+    "valley" => 5,
+    "valleys" => 5,
+    "valor" => 1,
+    "valour" => 1,
+    "valuable" => 17,
+    "valuables" => 1,
+    "value" => 5,
+    "valued" => 20,
+    "values" => 5,
+    "valuing" => 21,
+    "valve" => 5,
+    "valves" => 5,
+    "vampire" => 5,
+    "van" => 7,
+    "vandyke" => 1,
+    "vanilla" => 17,
+    "vanish" => 5,
+    "vanished" => 20,
+    "vanishes" => 5,
+    "vanishing" => 21,
+    "vanity" => 1,
+    "vanquish" => 4,
+    "vanquished" => 20,
+    "vans" => 5,
+    "variability" => 1,
+    "variable" => 17,
+    "variables" => 1,
+    "variance" => 1,
+    "variances" => 1,
+    "variant" => 17,
+    "variants" => 1,
+    "variation" => 5,
+    "variations" => 5,
+    "varied" => 20,
+    "variegated" => 20,
+    "varies" => 5,
+    "varieties" => 1,
+    "variety" => 1,
+    "various" => 16,
+    "variously" => 32,
+    "varnish" => 5,
+    "varnished" => 20,
+    "vary" => 5,
+    "varying" => 21,
+    "vase" => 5,
+    "vases" => 5,
+    "vast" => 17,
+    "vastly" => 32,
+    "vastness" => 17,
+    "vat" => 21,
+    "vault" => 5,
+    "vaulted" => 20,
+    "vaults" => 5,
+    "vcr" => 1,
+    "vector" => 5,
+    "vectored" => 20,
+    "vectors" => 5,
+    "veer" => 5,
+    "veered" => 20,
+    "veering" => 21,
+    "vegetable" => 17,
+    "vegetables" => 1,
+    "vegetarian" => 17,
+    "vegetation" => 5,
+    "vehemence" => 1,
+    "vehement" => 16,
+    "vehemently" => 32,
+    "vehicle" => 5,
+    "vehicles" => 5,
+    "veil" => 5,
+    "veiled" => 20,
+    "vein" => 5,
+    "veins" => 5,
+    "velar" => 17,
+    "velocity" => 1,
+    "velvet" => 21,
+    "vendor" => 5,
+    "vendors" => 5,
+    "veneer" => 5,
+    "venerable" => 16,
+    "venerated" => 20,
+    "vengeance" => 1,
+    "vengeful" => 16,
+    "venial" => 16,
+    "venice" => 2,
+    "venom" => 21,
+    "venomous" => 16,
+    "vent" => 5,
+    "ventilate" => 4,
+    "ventilation" => 5,
+    "ventilator" => 1,
+    "ventilators" => 1,
+    "venting" => 21,
+    "venture" => 5,
+    "ventured" => 20,
+    "ventures" => 5,
+    "venturesome" => 16,
+    "venturing" => 21,
+    "vera" => 2,
+    "veranda" => 1,
+    "verandas" => 1,
+    "verb" => 5,
+    "verbal" => 21,
+    "verbatim" => 17,
+    "verbose" => 16,
+    "verbosely" => 32,
+    "verbosity" => 1,
+    "verbs" => 5,
+    "verdant" => 16,
+    "verdict" => 1,
+    "verdicts" => 1,
+    "verdure" => 5,
+    "verge" => 5,
+    "verifiable" => 16,
+    "verification" => 5,
+    "verified" => 21,
+    "verifier" => 1,
+    "verifiers" => 1,
+    "verifies" => 4,
+    "verify" => 4,
+    "verifying" => 21,
+    "vermilion" => 21,
+    "vermont" => 2,
+    "vernacular" => 17,
+    "versailles" => 2,
+    "versatile" => 16,
+    "versatility" => 1,
+    "verse" => 5,
+    "versed" => 20,
+    "verses" => 5,
+    "version" => 5,
+    "versions" => 5,
+    "versus" => 68,
+    "vertex" => 1,
+    "vertical" => 17,
+    "vertically" => 32,
+    "vertices" => 1,
+    "very" => 48,
+    "vessel" => 5,
+    "vessels" => 5,
+    "vest" => 5,
+    "vested" => 20,
+    "vestibule" => 5,
+    "vestibules" => 5,
+    "vestige" => 1,
+    "vestiges" => 1,
+    "vestry" => 1,
+    "vet" => 5,
+    "veteran" => 17,
+    "veterans" => 1,
+    "vets" => 5,
+    "vetted" => 20,
+    "vex" => 5,
+    "vexation" => 1,
+    "vexations" => 1,
+    "vexatiously" => 32,
+    "vexed" => 20,
+    "vexing" => 21,
+    "via" => 65,
+    "viability" => 1,
+    "viable" => 17,
+    "viaducts" => 1,
+    "viands" => 1,
+    "vibes" => 5,
+    "vibrancy" => 1,
+    "vibrant" => 17,
+    "vibrated" => 20,
+    "vicar" => 1,
+    "vicariously" => 32,
+    "vice" => 85,
+    "vices" => 5,
+    "vicinity" => 1,
+    "vicious" => 16,
+    "vicissitudes" => 1,
+    "victim" => 5,
+    "victims" => 5,
+    "victor" => 3,
+    "victoria" => 3,
+    "victorious" => 16,
+    "victory" => 5,
+    "vid" => 1,
+    "video" => 5,
+    "videodisc" => 1,
+    "videodiscs" => 1,
+    "videos" => 5,
+    "videotape" => 5,
+    "vie" => 5,
+    "vietnamese" => 19,
+    "view" => 5,
+    "viewable" => 16,
+    "viewed" => 20,
+    "viewer" => 1,
+    "viewers" => 5,
+    "viewing" => 21,
+    "views" => 5,
+    "vigil" => 5,
+    "vigilance" => 1,
+    "vigilant" => 16,
+    "vigor" => 1,
+    "vigorous" => 16,
+    "vigorously" => 32,
+    "vigour" => 1,
+    "vile" => 16,
+    "vilest" => 16,
+    "villa" => 3,
+    "village" => 1,
+    "villager" => 1,
+    "villagers" => 1,
+    "villages" => 1,
+    "villain" => 5,
+    "villainous" => 16,
+    "villains" => 5,
+    "villainy" => 17,
+    "villas" => 3,
+    "vim" => 1,
+    "vincent" => 2,
+    "vindicating" => 21,
+    "vindictive" => 16,
+    "vine" => 1,
+    "vinegar" => 5,
+    "vines" => 1,
+    "vineyards" => 1,
+    "vinous" => 16,
+    "vintage" => 21,
+    "vinyl" => 17,
+    "viol" => 5,
+    "violate" => 4,
+    "violated" => 20,
+    "violates" => 4,
+    "violating" => 21,
+    "violation" => 5,
+    "violations" => 4,
+    "violence" => 5,
+    "violent" => 21,
+    "violently" => 32,
+    "violet" => 19,
+    "violets" => 1,
+    "violin" => 5,
+    "violins" => 5,
+    "viols" => 5,
+    "viral" => 17,
+    "virgil" => 2,
+    "virgin" => 17,
+    "virginia" => 2,
+    "virgins" => 1,
+    "virtual" => 17,
+    "virtually" => 32,
+    "virtue" => 1,
+    "virtues" => 1,
+    "virtuous" => 16,
+    "visa" => 7,
+    "visage" => 1,
+    "vise" => 5,
+    "vishnu" => 2,
+    "visibility" => 1,
+    "visible" => 16,
+    "visibly" => 32,
+    "vision" => 5,
+    "visionary" => 17,
+    "visions" => 5,
+    "visit" => 5,
+    "visitation" => 1,
+    "visited" => 20,
+    "visiting" => 21,
+    "visitor" => 1,
+    "visitors" => 1,
+    "visits" => 5,
+    "vista" => 5,
+    "visual" => 17,
+    "visualise" => 4,
+    "visualises" => 4,
+    "visualising" => 21,
+    "visualization" => 1,
+    "visualizations" => 1,
+    "visualize" => 4,
+    "visualized" => 20,
+    "visualizes" => 4,
+    "visualizing" => 21,
+    "visually" => 32,
+    "visuals" => 1,
+    "vita" => 1,
+    "vital" => 16,
+    "vitality" => 1,
+    "vitals" => 17,
+    "vitriol" => 5,
+    "vivacity" => 1,
+    "vivid" => 17,
+    "vividly" => 32,
+    "viz" => 21,
+    "vladimir" => 2,
+    "vocabularies" => 1,
+    "vocabulary" => 1,
+    "vocal" => 17,
+    "vocation" => 1,
+    "vogue" => 7,
+    "voice" => 5,
+    "voiced" => 20,
+    "voiceless" => 16,
+    "voices" => 5,
+    "voicing" => 21,
+    "void" => 21,
+    "voids" => 5,
+    "volatile" => 17,
+    "volatility" => 1,
+    "volcanic" => 17,
+    "volcano" => 5,
+    "volley" => 5,
+    "volleying" => 21,
+    "voltage" => 1,
+    "volts" => 1,
+    "volubly" => 32,
+    "volume" => 5,
+    "volumes" => 5,
+    "voluntarily" => 32,
+    "voluntary" => 17,
+    "volunteer" => 5,
+    "volunteered" => 20,
+    "volunteers" => 5,
+    "voluptuous" => 16,
+    "vomited" => 20,
+    "voodoo" => 5,
+    "voracious" => 16,
+    "voraciously" => 32,
+    "votary" => 17,
+    "vote" => 5,
+    "voted" => 20,
+    "voter" => 1,
+    "voters" => 1,
+    "votes" => 5,
+    "voting" => 21,
+    "vouching" => 21,
+    "vow" => 5,
+    "vowed" => 20,
+    "vowel" => 5,
+    "vowels" => 5,
+    "vows" => 5,
+    "voyage" => 5,
+    "voyages" => 5,
+    "vulgar" => 17,
+    "vulnerability" => 1,
+    "vulnerable" => 16,
+    "vulture" => 21,
+    "wad" => 5,
+    "wadding" => 5,
+    "waddling" => 21,
+    "wade" => 7,
+    "waded" => 20,
+    "wading" => 21,
+    "wads" => 5,
+    "wafer" => 5,
+    "wafted" => 20,
+    "wag" => 5,
+    "wager" => 5,
+    "wagers" => 5,
+    "wages" => 5,
+    "wagged" => 4,
+    "waggled" => 20,
+    "wagon" => 5,
+    "wagons" => 5,
+    "wags" => 5,
+    "waifs" => 5,
+    "wail" => 5,
+    "wailed" => 20,
+    "wailing" => 21,
+    "waist" => 1,
+    "waistcoat" => 1,
+    "waists" => 1,
+    "wait" => 5,
+    "waited" => 20,
+    "waiter" => 5,
+    "waiting" => 21,
+    "waits" => 5,
+    "wake" => 7,
+    "waked" => 20,
+    "wakefulness" => 17,
+    "wakes" => 5,
+    "waking" => 21,
+    "walk" => 5,
+    "walked" => 20,
+    "walker" => 3,
+    "walkers" => 5,
+    "walking" => 21,
+    "walks" => 5,
+    "wall" => 7,
+    "wallace" => 2,
+    "walled" => 20,
+    "wallet" => 1,
+    "wallets" => 1,
+    "walloon" => 19,
+    "wallowed" => 20,
+    "walls" => 7,
+    "walnut" => 17,
+    "walrus" => 5,
+    "walsh" => 2,
+    "walter" => 3,
+    "walters" => 3,
+    "walton" => 2,
+    "waltz" => 5,
+    "wan" => 21,
+    "wand" => 5,
+    "wander" => 5,
+    "wandered" => 20,
+    "wanderer" => 1,
+    "wanderers" => 5,
+    "wandering" => 21,
+    "wanderings" => 5,
+    "wanders" => 5,
+    "wane" => 5,
+    "waned" => 20,
+    "wang" => 2,
+    "waning" => 21,
+    "wanly" => 32,
+    "want" => 5,
+    "wanted" => 20,
+    "wanting" => 21,
+    "wantonly" => 32,
+    "wantonness" => 21,
+    "wants" => 5,
+    "war" => 5,
+    "warble" => 5,
+    "warbler" => 1,
+    "warbling" => 21,
+    "ward" => 7,
+    "wardrobe" => 5,
+    "wardrobes" => 5,
+    "warehouse" => 5,
+    "warehoused" => 20,
+    "warehouses" => 5,
+    "warily" => 32,
+    "warlike" => 16,
+    "warm" => 21,
+    "warmed" => 20,
+    "warmer" => 17,
+    "warmest" => 16,
+    "warmly" => 32,
+    "warmth" => 21,
+    "warn" => 4,
+    "warned" => 20,
+    "warner" => 2,
+    "warning" => 21,
+    "warnings" => 4,
+    "warns" => 4,
+    "warp" => 5,
+    "warpath" => 1,
+    "warped" => 20,
+    "warping" => 21,
+    "warrant" => 5,
+    "warranted" => 20,
+    "warranties" => 5,
+    "warrants" => 5,
+    "warranty" => 5,
+    "warren" => 3,
+    "warring" => 21,
+    "warrior" => 1,
+    "wars" => 5,
+    "warsaw" => 2,
+    "wart" => 1,
+    "warts" => 1,
+    "warwick" => 2,
+    "wary" => 20,
+    "was" => 4,
+    "wash" => 7,
+    "washed" => 20,
+    "washing" => 21,
+    "washington" => 3,
+    "wasn't" => 4,
+    "waste" => 21,
+    "wasted" => 20,
+    "wasteful" => 16,
+    "wastes" => 5,
+    "wasting" => 21,
+    "watch" => 5,
+    "watched" => 20,
+    "watcher" => 1,
+    "watchers" => 5,
+    "watches" => 5,
+    "watchful" => 16,
+    "watchfully" => 32,
+    "watching" => 21,
+    "watchman" => 1,
+    "water" => 5,
+    "watered" => 20,
+    "waterfall" => 5,
+    "waterfalls" => 5,
+    "watering" => 21,
+    "waterloo" => 3,
+    "watermarking" => 21,
+    "watermarks" => 5,
+    "waterproof" => 21,
+    "waters" => 7,
+    "waterspout" => 1,
+    "watertight" => 16,
+    "waterway" => 1,
+    "watery" => 16,
+    "watson" => 2,
+    "watt" => 3,
+    "wave" => 5,
+    "waved" => 20,
+    "wavefront" => 1,
+    "waver" => 5,
+    "wavered" => 20,
+    "waverer" => 1,
+    "wavering" => 21,
+    "waves" => 5,
+    "waving" => 21,
+    "wax" => 21,
+    "waxed" => 20,
+    "waxing" => 21,
+    "way" => 17,
+    "waylaid" => 4,
+    "waylay" => 4,
+    "wayne" => 2,
+    "ways" => 1,
+    "wayside" => 17,
+    "wayward" => 16,
+    "we" => 256,
+    "weak" => 16,
+    "weaken" => 20,
+    "weakened" => 20,
+    "weakening" => 21,
+    "weaker" => 16,
+    "weakest" => 16,
+    "weaklings" => 1,
+    "weakly" => 32,
+    "weakness" => 17,
+    "weaknesses" => 1,
+    "wealth" => 5,
+    "wealthier" => 16,
+    "wealthy" => 17,
+    "wean" => 5,
+    "weapon" => 5,
+    "weapons" => 5,
+    "wear" => 5,
+    "wearer" => 1,
+    "wearied" => 20,
+    "wearily" => 32,
+    "weariness" => 21,
+    "wearing" => 21,
+    "wearisome" => 16,
+    "wears" => 5,
+    "weary" => 20,
+    "wearying" => 21,
+    "weather" => 21,
+    "weathered" => 20,
+    "weatherproof" => 20,
+    "weave" => 5,
+    "weaved" => 20,
+    "weaver" => 3,
+    "web" => 7,
+    "webb" => 2,
+    "weber" => 2,
+    "website" => 1,
+    "webster" => 2,
+    "wed" => 5,
+    "wedding" => 5,
+    "wedge" => 5,
+    "wedged" => 20,
+    "wedges" => 5,
+    "wedging" => 21,
+    "wedlock" => 1,
+    "wednesday" => 3,
+    "wee" => 277,
+    "weed" => 5,
+    "weeding" => 21,
+    "weeds" => 5,
+    "weedy" => 16,
+    "week" => 1,
+    "weekday" => 1,
+    "weekdays" => 1,
+    "weekend" => 21,
+    "weekly" => 49,
+    "weeks" => 3,
+    "weep" => 5,
+    "weeping" => 21,
+    "weeps" => 5,
+    "weigh" => 5,
+    "weighed" => 20,
+    "weighing" => 21,
+    "weighs" => 4,
+    "weight" => 5,
+    "weighted" => 20,
+    "weighting" => 21,
+    "weights" => 5,
+    "weighty" => 16,
+    "weird" => 21,
+    "weirdly" => 32,
+    "weirdness" => 21,
+    "welcome" => 21,
+    "welcomed" => 20,
+    "welcomes" => 5,
+    "welcoming" => 21,
+    "welded" => 20,
+    "welding" => 21,
+    "welfare" => 5,
+    "well" => 21,
+    "wellington" => 3,
+    "welsh" => 23,
+    "welshman" => 1,
+    "weltering" => 21,
+    "wen" => 769,
+    "wended" => 20,
+    "wending" => 21,
+    "went" => 5,
+    "wept" => 4,
+    "were" => 5,
+    "weren't" => 4,
+    "wesley" => 2,
+    "wessex" => 2,
+    "west" => 23,
+    "western" => 19,
+    "westerners" => 17,
+    "westminster" => 2,
+    "westward" => 17,
+    "wet" => 21,
+    "wetting" => 21,
+    "whack" => 21,
+    "whacks" => 5,
+    "whale" => 5,
+    "whaler" => 1,
+    "wharf" => 5,
+    "wharves" => 1,
+    "what" => 257,
+    "whatever" => 273,
+    "whatnot" => 1,
+    "whatsoever" => 256,
+    "wheat" => 17,
+    "wheedle" => 5,
+    "wheel" => 5,
+    "wheelbarrow" => 5,
+    "wheeled" => 20,
+    "wheeler" => 3,
+    "wheeling" => 23,
+    "wheels" => 5,
+    "when" => 769,
+    "whence" => 512,
+    "whenever" => 512,
+    "where" => 513,
+    "whereabouts" => 1,
+    "whereas" => 513,
+    "wherefore" => 513,
+    "wherein" => 512,
+    "whereof" => 512,
+    "whereupon" => 512,
+    "wherever" => 512,
+    "whether" => 768,
+    "whetted" => 20,
+    "whew" => 4,
+    "which" => 768,
+    "whichever" => 256,
+    "whiff" => 21,
+    "while" => 581,
+    "whiles" => 5,
+    "whilst" => 512,
+    "whim" => 5,
+    "whimpered" => 20,
+    "whims" => 5,
+    "whimsical" => 16,
+    "whine" => 5,
+    "whined" => 20,
+    "whining" => 21,
+    "whip" => 5,
+    "whipcord" => 1,
+    "whipped" => 20,
+    "whipping" => 5,
+    "whippings" => 5,
+    "whippoorwill" => 1,
+    "whips" => 5,
+    "whirled" => 20,
+    "whirling" => 21,
+    "whirlpool" => 5,
+    "whirlwind" => 17,
+    "whirlwinds" => 1,
+    "whiskered" => 16,
+    "whiskers" => 5,
+    "whiskey" => 1,
+    "whisky" => 1,
+    "whisper" => 5,
+    "whispered" => 20,
+    "whispering" => 21,
+    "whispers" => 5,
+    "whist" => 21,
+    "whistle" => 5,
+    "whistled" => 20,
+    "whistles" => 5,
+    "whistling" => 21,
+    "whit" => 65,
+    "white" => 23,
+    "whitebait" => 5,
+    "whitehead" => 3,
+    "whiten" => 69,
+    "whitened" => 20,
+    "whiteness" => 21,
+    "whiter" => 65,
+    "whitespace" => 1,
+    "whitewash" => 5,
+    "whitewashed" => 20,
+    "whitewashing" => 21,
+    "whither" => 5,
+    "whiting" => 21,
+    "whitney" => 2,
+    "whiz" => 69,
+    "whizz" => 5,
+    "whizzed" => 20,
+    "who" => 259,
+    "who's" => 1,
+    "whoa" => 4,
+    "whoever" => 256,
+    "whole" => 17,
+    "wholesale" => 21,
+    "wholesome" => 16,
+    "wholly" => 32,
+    "whom" => 256,
+    "whomsoever" => 256,
+    "whoop" => 5,
+    "whooped" => 20,
+    "whooping" => 21,
+    "whoops" => 5,
+    "whose" => 256,
+    "whosoever" => 256,
+    "why" => 5,
+    "wick" => 21,
+    "wicked" => 20,
+    "wickedly" => 32,
+    "wickedness" => 21,
+    "wicker" => 17,
+    "wicket" => 1,
+    "wide" => 17,
+    "widely" => 32,
+    "widen" => 4,
+    "widened" => 20,
+    "widening" => 21,
+    "widens" => 4,
+    "wider" => 16,
+    "widespread" => 16,
+    "widest" => 16,
+    "widget" => 1,
+    "widgets" => 1,
+    "widow" => 5,
+    "widow's" => 1,
+    "widower" => 1,
+    "width" => 1,
+    "widths" => 1,
+    "wife" => 5,
+    "wig" => 5,
+    "wiggle" => 5,
+    "wight" => 17,
+    "wigs" => 5,
+    "wilbur" => 2,
+    "wild" => 21,
+    "wildcard" => 5,
+    "wilder" => 19,
+    "wilderness" => 1,
+    "wildest" => 16,
+    "wildly" => 32,
+    "wildness" => 21,
+    "wilds" => 5,
+    "wiles" => 7,
+    "wilful" => 16,
+    "wiliness" => 17,
+    "will" => 11,
+    "william" => 3,
+    "williams" => 3,
+    "willie" => 2,
+    "willing" => 21,
+    "willingly" => 32,
+    "willingness" => 21,
+    "willow" => 5,
+    "willows" => 5,
+    "willowy" => 16,
+    "willy" => 23,
+    "wilson" => 2,
+    "wilt" => 5,
+    "wilted" => 20,
+    "wilton" => 3,
+    "wily" => 16,
+    "win" => 5,
+    "winced" => 20,
+    "winch" => 5,
+    "winchester" => 3,
+    "wincing" => 21,
+    "wind" => 5,
+    "winded" => 20,
+    "windfall" => 1,
+    "windfalls" => 1,
+    "winding" => 21,
+    "window" => 5,
+    "windowed" => 20,
+    "windowing" => 21,
+    "windowless" => 16,
+    "windows" => 7,
+    "windowsill" => 1,
+    "winds" => 5,
+    "windsor" => 2,
+    "windy" => 17,
+    "wine" => 5,
+    "wines" => 5,
+    "wing" => 5,
+    "winged" => 20,
+    "winging" => 21,
+    "wingless" => 16,
+    "wings" => 5,
+    "wink" => 5,
+    "winked" => 20,
+    "winking" => 21,
+    "winks" => 5,
+    "winner" => 1,
+    "winning" => 21,
+    "winnings" => 5,
+    "winnow" => 5,
+    "wins" => 5,
+    "winter" => 5,
+    "winters" => 7,
+    "wintry" => 16,
+    "wipe" => 5,
+    "wiped" => 20,
+    "wipes" => 5,
+    "wiping" => 21,
+    "wire" => 5,
+    "wired" => 20,
+    "wireless" => 21,
+    "wires" => 5,
+    "wiring" => 21,
+    "wiry" => 16,
+    "wisconsin" => 2,
+    "wisdom" => 1,
+    "wise" => 23,
+    "wised" => 20,
+    "wisely" => 32,
+    "wiser" => 17,
+    "wisest" => 16,
+    "wish" => 5,
+    "wished" => 20,
+    "wishes" => 5,
+    "wishing" => 21,
+    "wisp" => 5,
+    "wistfully" => 32,
+    "wistfulness" => 17,
+    "wit" => 69,
+    "witch" => 5,
+    "witched" => 20,
+    "witches" => 5,
+    "witching" => 21,
+    "with" => 64,
+    "withal" => 64,
+    "withdraw" => 5,
+    "withdrawal" => 1,
+    "withdrawals" => 1,
+    "withdrawing" => 21,
+    "withdrawn" => 20,
+    "withdrew" => 4,
+    "withered" => 20,
+    "withers" => 5,
+    "withheld" => 20,
+    "withhold" => 5,
+    "within" => 80,
+    "without" => 576,
+    "withstand" => 4,
+    "withstood" => 4,
+    "witness" => 5,
+    "witnessed" => 20,
+    "witnesses" => 5,
+    "witnessing" => 21,
+    "wits" => 5,
+    "witted" => 20,
+    "witty" => 16,
+    "wives" => 4,
+    "wizard" => 21,
+    "wizardry" => 1,
+    "woe" => 17,
+    "woeful" => 16,
+    "woefully" => 32,
+    "woes" => 1,
+    "woke" => 21,
+    "woken" => 5,
+    "wolf" => 7,
+    "wolfgang" => 2,
+    "wolfram" => 1,
+    "wolves" => 5,
+    "woman" => 5,
+    "womanhood" => 1,
+    "womanly" => 16,
+    "womb" => 5,
+    "women" => 1,
+    "women's" => 1,
+    "won" => 5,
+    "won't" => 4,
+    "wonder" => 5,
+    "wondered" => 20,
+    "wonderful" => 16,
+    "wonderfully" => 32,
+    "wondering" => 21,
+    "wonderland" => 1,
+    "wonders" => 5,
+    "wondrous" => 16,
+    "wondrously" => 32,
+    "wonky" => 17,
+    "wont" => 21,
+    "wood" => 23,
+    "woodcock" => 1,
+    "wooded" => 20,
+    "wooden" => 21,
+    "woodpecker" => 1,
+    "woods" => 7,
+    "woodshed" => 5,
+    "woody" => 17,
+    "wooed" => 20,
+    "wooing" => 21,
+    "wool" => 1,
+    "woollen" => 17,
+    "woolly" => 17,
+    "wop" => 5,
+    "word" => 5,
+    "worded" => 20,
+    "wording" => 21,
+    "words" => 5,
+    "wordy" => 16,
+    "wore" => 4,
+    "work" => 5,
+    "workable" => 16,
+    "workbench" => 1,
+    "workbook" => 1,
+    "worked" => 20,
+    "worker" => 1,
+    "workers" => 1,
+    "workflow" => 1,
+    "workflows" => 1,
+    "workhorse" => 1,
+    "working" => 21,
+    "workings" => 5,
+    "workload" => 1,
+    "workloads" => 1,
+    "workman" => 3,
+    "workmen" => 1,
+    "workplace" => 1,
+    "workplaces" => 1,
+    "works" => 5,
+    "worksheet" => 5,
+    "worksheets" => 5,
+    "workshop" => 5,
+    "workshops" => 5,
+    "workspace" => 1,
+    "workstation" => 1,
+    "workstations" => 1,
+    "world" => 5,
+    "worldly" => 16,
+    "worlds" => 5,
+    "worldwide" => 16,
+    "worm" => 5,
+    "wormed" => 20,
+    "worms" => 7,
+    "worn" => 20,
+    "worried" => 20,
+    "worries" => 5,
+    "worry" => 5,
+    "worrying" => 21,
+    "worse" => 21,
+    "worsening" => 21,
+    "worship" => 5,
+    "worshipful" => 17,
+    "worshipped" => 4,
+    "worst" => 21,
+    "worsted" => 21,
+    "wort" => 1,
+    "worth" => 21,
+    "worthless" => 16,
+    "worthwhile" => 16,
+    "worthy" => 21,
+    "would" => 8,
+    "wouldn't" => 8,
+    "wound" => 5,
+    "wounded" => 20,
+    "wounds" => 5,
+    "woven" => 21,
+    "wow" => 5,
+    "wrack" => 5,
+    "wrangle" => 5,
+    "wrangling" => 21,
+    "wrap" => 5,
+    "wraparound" => 17,
+    "wrapped" => 20,
+    "wrapper" => 1,
+    "wrappers" => 1,
+    "wrapping" => 5,
+    "wrappings" => 5,
+    "wraps" => 5,
+    "wrath" => 21,
+    "wreak" => 5,
+    "wreaked" => 20,
+    "wreath" => 5,
+    "wreaths" => 5,
+    "wreck" => 5,
+    "wrecked" => 20,
+    "wrench" => 5,
+    "wrenched" => 20,
+    "wrenching" => 21,
+    "wrestle" => 5,
+    "wrestled" => 20,
+    "wrestling" => 21,
+    "wretch" => 5,
+    "wretched" => 16,
+    "wretchedly" => 32,
+    "wretchedness" => 17,
+    "wriggled" => 20,
+    "wriggling" => 21,
+    "wring" => 5,
+    "wringing" => 21,
+    "wrinkle" => 5,
+    "wrinkled" => 20,
+    "wrinkles" => 5,
+    "wrist" => 5,
+    "wrists" => 5,
+    "writ" => 5,
+    "writable" => 5,
+    "write" => 5,
+    "writer" => 1,
+    "writer's" => 1,
+    "writers" => 5,
+    "writes" => 5,
+    "writhed" => 20,
+    "writhing" => 21,
+    "writing" => 21,
+    "writings" => 5,
+    "writs" => 5,
+    "written" => 20,
+    "wrong" => 21,
+    "wronged" => 20,
+    "wrongfully" => 32,
+    "wrongly" => 32,
+    "wrote" => 4,
+    "wrought" => 20,
+    "wrung" => 4,
+    "wry" => 21,
+    "wyatt" => 2,
+    "wynn" => 2,
+    "wyoming" => 2,
+    "wysiwyg" => 1,
+    "xavier" => 2,
+    "xerox" => 5,
+    "xmas" => 6,
+    "xxx" => 1,
+    "yacht" => 5,
+    "yachting" => 21,
+    "yachts" => 5,
+    "yahoo" => 5,
+    "yak" => 5,
+    "yale" => 2,
+    "yank" => 5,
+    "yanked" => 20,
+    "yankee" => 5,
+    "yanking" => 21,
+    "yanks" => 5,
+    "yap" => 5,
+    "yard" => 5,
+    "yards" => 5,
+    "yarn" => 5,
+    "yarns" => 5,
+    "yaw" => 5,
+    "yawl" => 5,
+    "yawn" => 5,
+    "yawned" => 20,
+    "yawning" => 21,
+    "yaws" => 5,
+    "ye" => 385,
+    "yea" => 513,
+    "yeah" => 1,
+    "year" => 1,
+    "yearly" => 48,
+    "yearned" => 20,
+    "yearning" => 21,
+    "years" => 1,
+    "yeas" => 1,
+    "yell" => 21,
+    "yelled" => 20,
+    "yelling" => 21,
+    "yellow" => 21,
+    "yellowed" => 20,
+    "yellowing" => 21,
+    "yellowish" => 16,
+    "yells" => 5,
+    "yelp" => 5,
+    "yelps" => 5,
+    "yen" => 5,
+    "yep" => 1,
+    "yes" => 5,
+    "yesterday" => 1,
+    "yet" => 517,
+    "yew" => 273,
+    "yiddish" => 18,
+    "yield" => 5,
+    "yielded" => 20,
+    "yielding" => 21,
+    "yields" => 5,
+    "yip" => 5,
+    "yoga" => 1,
+    "yoke" => 5,
+    "yoking" => 21,
+    "yokohama" => 2,
+    "yolks" => 5,
+    "yon" => 256,
+    "yonder" => 17,
+    "york" => 2,
+    "yorkshire" => 3,
+    "yoruba" => 3,
+    "you" => 256,
+    "young" => 23,
+    "younger" => 17,
+    "youngest" => 16,
+    "youngster" => 1,
+    "your" => 128,
+    "yours" => 256,
+    "yourself" => 256,
+    "yourselves" => 256,
+    "youth" => 1,
+    "youthful" => 16,
+    "yuan" => 3,
+    "yukon" => 3,
+    "yummy" => 17,
+    "yup" => 1,
+    "zachary" => 2,
+    "zeal" => 1,
+    "zealand" => 2,
+    "zebra" => 1,
+    "zebras" => 1,
+    "zebus" => 1,
+    "zed" => 21,
+    "zen" => 19,
+    "zenith" => 1,
+    "zephyr" => 5,
+    "zephyrs" => 5,
+    "zero" => 21,
+    "zeroed" => 20,
+    "zeroes" => 5,
+    "zeroing" => 21,
+    "zeros" => 5,
+    "zest" => 21,
+    "zeta" => 1,
+    "ziegler" => 2,
+    "zigzag" => 21,
+    "zip" => 5,
+    "zipped" => 20,
+    "zipping" => 4,
+    "zips" => 5,
+    "zircon" => 1,
+    "zodiac" => 1,
+    "zoe" => 2,
+    "zombie" => 1,
+    "zombies" => 1,
+    "zone" => 5,
+    "zoned" => 20,
+    "zones" => 5,
+    "zoo" => 1,
+    "zoom" => 5,
+    "zooming" => 21,
+    "zoroaster" => 2,
+    "zulu" => 19,
+};
+
+/// How often a word of one class is followed by a word of another in the
+/// corpus, relative to chance, indexed by the first class and then the second
+pub static CLASS_PAIR_RATIOS: [[f64; CLASS_COUNT]; CLASS_COUNT] = [
+    [
+        0.8985, 0.4292, 1.1978, 1.2848, 0.4403, 0.5338, 1.7765, 0.1418, 0.5581, 0.6436,
+    ],
+    [
+        0.4883, 10.3105, 0.9406, 0.8586, 0.2603, 0.7718, 0.2941, 0.1357, 0.5510, 0.5689,
+    ],
+    [
+        0.7777, 0.1990, 0.6929, 0.0833, 1.6609, 2.9269, 0.8707, 1.5434, 1.2738, 0.5321,
+    ],
+    [
+        0.0176, 0.0286, 2.4982, 0.0312, 0.9428, 4.8435, 0.0378, 0.0589, 1.1792, 0.3879,
+    ],
+    [
+        1.8074, 0.3540, 0.2867, 0.2385, 0.7893, 0.3338, 1.1701, 0.2411, 0.7373, 0.6492,
+    ],
+    [
+        0.1667, 0.1301, 3.1279, 0.7379, 3.0976, 1.0835, 0.5677, 0.4883, 1.0633, 0.8227,
+    ],
+    [
+        0.7051, 1.1280, 1.6271, 0.0999, 1.0820, 0.6695, 0.1341, 3.6474, 0.6297, 0.1354,
+    ],
+    [
+        2.3411, 1.4286, 0.1093, 0.0489, 1.7297, 0.2104, 0.0582, 0.0058, 0.0306, 0.0143,
+    ],
+    [
+        0.0475, 0.1259, 5.0975, 15.0895, 0.4457, 3.9704, 0.6180, 0.0749, 0.4104, 0.3698,
+    ],
+    [
+        0.2145, 0.2133, 0.5395, 0.4655, 1.0725, 1.5985, 0.3009, 1.8719, 21.6072, 0.7711,
+    ],
+];
@@ -0,0 +1,150 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_security::confusable_detection::skeleton;
+
+use crate::script::{dominant_script, is_mixed_script, Script};
+
+/// A homoglyph replaced by the Latin letter it imitates, see [`unconfuse`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Substitution {
+    /// Byte offset of the character in the original text
+    pub index: usize,
+    /// The character that was replaced, e.g. Cyrillic 'а'
+    pub original: char,
+    /// The Latin letter it was read as, e.g. 'a'
+    pub replacement: char,
+}
+
+/// Text with homoglyphs mapped to Latin, see [`unconfuse`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unconfused {
+    /// The text with every substitution applied
+    pub text: String,
+    /// Substituted characters in order of appearance
+    pub substitutions: Vec<Substitution>,
+}
+
+impl Unconfused {
+    /// Returns true if any character was replaced
+    pub fn is_spoofed(&self) -> bool {
+        !self.substitutions.is_empty()
+    }
+}
+
+/// The ASCII letter a non-ASCII letter is confusable with, if any.
+///
+/// Compatibility forms such as fullwidth or mathematical letters decompose to
+/// their letter, anything else goes through the Unicode confusables skeleton.
+/// Accented letters like 'é' are not confusables and have no lookalike.
+fn latin_lookalike(c: char) -> Option<char> {
+    if c.is_ascii() || !c.is_alphabetic() {
+        return None;
+    }
+    let single_ascii_letter =
+        |mut chars: std::vec::IntoIter<char>| match (chars.next(), chars.next()) {
+            (Some(letter), None) if letter.is_ascii_alphabetic() => Some(letter),
+            _ => None,
+        };
+    let compatible: Vec<char> = c.nfkc().collect();
+    single_ascii_letter(compatible.into_iter())
+        .or_else(|| single_ascii_letter(skeleton(&c.to_string()).collect::<Vec<_>>().into_iter()))
+}
+
+/// Map homoglyphs to the Latin letters they imitate, e.g. "pаypаl" with
+/// Cyrillic "а" reads as "paypal", based on the Unicode confusables data.
+///
+/// A word is only rewritten if every non-ASCII letter in it has a Latin
+/// lookalike, and either it mixes scripts or the text is otherwise written in
+/// Latin. Genuine Cyrillic or Greek text is left alone, as are accented letters.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::unconfuse;
+///
+/// let unconfused = unconfuse("log in to p\u{430}yp\u{430}l");
+/// assert_eq!(unconfused.text, "log in to paypal");
+/// assert_eq!(unconfused.substitutions.len(), 2);
+/// assert_eq!(unconfused.substitutions[0].original, '\u{430}');
+///
+/// assert!(!unconfuse("привет, café").is_spoofed());
+/// ```
+pub fn unconfuse(text: &str) -> Unconfused {
+    let latin_text = matches!(dominant_script(text), None | Some(Script::Latin));
+    let mut unconfused = Unconfused {
+        text: String::with_capacity(text.len()),
+        substitutions: Vec::new(),
+    };
+
+    let mut offset = 0;
+    for token in text.split_inclusive(char::is_whitespace) {
+        let lookalikes: Vec<(usize, char, Option<char>)> = token
+            .char_indices()
+            .map(|(index, c)| (offset + index, c, latin_lookalike(c)))
+            .collect();
+        let has_lookalike = lookalikes
+            .iter()
+            .any(|(_, _, lookalike)| lookalike.is_some());
+        let all_mappable = lookalikes
+            .iter()
+            .all(|&(_, c, lookalike)| c.is_ascii() || !c.is_alphabetic() || lookalike.is_some());
+
+        if has_lookalike && all_mappable && (latin_text || is_mixed_script(token)) {
+            for (index, original, lookalike) in lookalikes {
+                match lookalike {
+                    Some(replacement) => {
+                        unconfused.text.push(replacement);
+                        unconfused.substitutions.push(Substitution {
+                            index,
+                            original,
+                            replacement,
+                        });
+                    }
+                    None => unconfused.text.push(original),
+                }
+            }
+        } else {
+            unconfused.text.push_str(token);
+        }
+        offset += token.len();
+    }
+    unconfused
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookalikes() {
+        assert_eq!(latin_lookalike('\u{430}'), Some('a')); // Cyrillic а
+        assert_eq!(latin_lookalike('\u{3bf}'), Some('o')); // Greek omicron
+        assert_eq!(latin_lookalike('\u{ff41}'), Some('a')); // fullwidth a
+        assert_eq!(latin_lookalike('\u{1d41a}'), Some('a')); // mathematical bold a
+        assert_eq!(latin_lookalike('é'), None);
+        assert_eq!(latin_lookalike('ж'), None);
+        assert_eq!(latin_lookalike('a'), None);
+    }
+
+    #[test]
+    fn test_substitution_offsets() {
+        let unconfused = unconfuse("Apple \u{430}pple");
+        assert_eq!(unconfused.text, "Apple apple");
+        assert_eq!(
+            unconfused.substitutions,
+            [Substitution {
+                index: 6,
+                original: '\u{430}',
+                replacement: 'a'
+            }]
+        );
+    }
+
+    #[test]
+    fn test_real_cyrillic_is_kept() {
+        // Every letter here has a Latin lookalike, but the text is Russian
+        let text = "а у сосеса рос";
+        assert_eq!(unconfuse(text).text, text);
+        // A Latin word spoofed inside Russian text is still caught
+        assert!(unconfuse("привет от p\u{430}ypal").is_spoofed());
+    }
+}
@@ -0,0 +1,132 @@
+use regex::Regex;
+
+/// A known-plaintext fragment that is expected to appear in English output.
+///
+/// Typical cribs are flag formats such as `flag{`, common words like `the`,
+/// or usernames known to be in the plaintext.
+#[derive(Debug, Clone)]
+pub enum Crib {
+    /// Substring match, ignoring ASCII case
+    Literal(String),
+    /// Regular expression match
+    Pattern(Regex),
+}
+
+impl Crib {
+    /// Create a case-insensitive literal crib
+    pub fn literal(text: impl Into<String>) -> Self {
+        Crib::Literal(text.into())
+    }
+
+    /// Create a crib from a regular expression
+    pub fn pattern(pattern: &str) -> Result<Self, regex::Error> {
+        Regex::new(pattern).map(Crib::Pattern)
+    }
+
+    /// Find the first match of this crib in the text, returning the matched text
+    fn find<'a>(&self, text: &'a str) -> Option<&'a str> {
+        match self {
+            Crib::Literal(literal) => {
+                if literal.is_empty() {
+                    return None;
+                }
+                text.to_ascii_lowercase()
+                    .find(&literal.to_ascii_lowercase())
+                    .map(|start| &text[start..start + literal.len()])
+            }
+            Crib::Pattern(regex) => regex
+                .find(text)
+                .map(|m| m.as_str())
+                .filter(|m| !m.is_empty()),
+        }
+    }
+}
+
+/// What happens to the verdict when a crib matches
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CribEffect {
+    /// Add this amount to the composite score before the thresholds are applied
+    Boost(f64),
+    /// Classify the text as English regardless of the other checks
+    ForceEnglish,
+}
+
+impl Default for CribEffect {
+    fn default() -> Self {
+        CribEffect::Boost(0.2)
+    }
+}
+
+/// The crib that matched a text
+#[derive(Debug, Clone, PartialEq)]
+pub struct CribHit {
+    /// Index of the crib, in the order the cribs were added to the detector
+    pub index: usize,
+    /// The text that matched
+    pub matched: String,
+}
+
+/// Return the first crib that matches the text
+pub(crate) fn find_crib(cribs: &[Crib], text: &str) -> Option<CribHit> {
+    cribs.iter().enumerate().find_map(|(index, crib)| {
+        crib.find(text).map(|matched| CribHit {
+            index,
+            matched: matched.to_string(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GibberishDetector, Sensitivity};
+
+    #[test]
+    fn test_literal_crib_ignores_case() {
+        let cribs = [Crib::literal("FLAG{")];
+        let hit = find_crib(&cribs, "xx flag{abc} yy").unwrap();
+        assert_eq!(hit.index, 0);
+        assert_eq!(hit.matched, "flag{");
+    }
+
+    #[test]
+    fn test_pattern_crib() {
+        let cribs = [
+            Crib::literal("admin"),
+            Crib::pattern(r"CTF\{[a-z_]+\}").unwrap(),
+        ];
+        let hit = find_crib(&cribs, "zz CTF{hidden_flag} qq").unwrap();
+        assert_eq!(hit.index, 1);
+        assert_eq!(hit.matched, "CTF{hidden_flag}");
+        assert!(find_crib(&cribs, "nothing to see").is_none());
+    }
+
+    #[test]
+    fn test_empty_crib_never_matches() {
+        assert!(find_crib(&[Crib::literal("")], "anything").is_none());
+        assert!(find_crib(&[Crib::pattern("x*").unwrap()], "abc").is_none());
+    }
+
+    #[test]
+    fn test_detector_crib_boost() {
+        let text = "Rcl maocr otmwi lit dnoen oehc 13 iron seah.";
+        let plain = GibberishDetector::new();
+        assert!(plain.is_gibberish(text, Sensitivity::Low));
+
+        let boosted = GibberishDetector::new()
+            .with_crib(Crib::literal("maocr"))
+            .with_crib_effect(CribEffect::Boost(0.5));
+        let analysis = boosted.analyze(text, Sensitivity::Low);
+        assert!(!analysis.is_gibberish);
+        assert!((analysis.score - plain.score(text) - 0.5).abs() < 1e-9);
+        assert_eq!(analysis.crib_hit.unwrap().matched, "maocr");
+    }
+
+    #[test]
+    fn test_detector_without_crib_hit() {
+        let detector = GibberishDetector::new().with_crib(Crib::literal("flag{"));
+        let analysis = detector.analyze("h2=ReOrS9DAnED8o", Sensitivity::Medium);
+        assert!(analysis.is_gibberish);
+        assert!(analysis.crib_hit.is_none());
+    }
+}
@@ -0,0 +1,636 @@
+use crate::{is_gibberish, Sensitivity};
+
+/// Text encodings that can be recognised by [`identify_encodings`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// RFC 4648 base64, standard or URL-safe alphabet
+    Base64,
+    /// RFC 4648 base32
+    Base32,
+    /// Bitcoin-style base58
+    Base58,
+    /// Hexadecimal bytes, optionally separated by spaces or colons
+    Hex,
+    /// URL percent-encoding, e.g. `hello%20world`
+    UrlEncoded,
+    /// Binary digit strings, e.g. `01101000 01101001`
+    Binary,
+    /// International Morse code using `.`, `-` and `/` as word separator
+    Morse,
+    /// Decimal character codes, e.g. `104 105`
+    Decimal,
+    /// Octal character codes, e.g. `150 151`
+    Octal,
+    /// MIME quoted-printable, e.g. `caf=C3=A9`
+    QuotedPrintable,
+}
+
+const ALL_ENCODINGS: [Encoding; 10] = [
+    Encoding::Base64,
+    Encoding::Base32,
+    Encoding::Base58,
+    Encoding::Hex,
+    Encoding::UrlEncoded,
+    Encoding::Binary,
+    Encoding::Morse,
+    Encoding::Decimal,
+    Encoding::Octal,
+    Encoding::QuotedPrintable,
+];
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Longest text tried as base58. Base58 is used for keys and addresses rather
+/// than bulk data, and decoding takes time quadratic in the length.
+const MAX_BASE58_LEN: usize = 1024;
+
+impl Encoding {
+    /// Decode the text with this encoding, returning `None` if it is not valid
+    pub fn decode(&self, text: &str) -> Option<Vec<u8>> {
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+
+        match self {
+            Encoding::Base64 => decode_base64(text),
+            Encoding::Base32 => decode_base32(text),
+            Encoding::Base58 => decode_base58(text),
+            Encoding::Hex => decode_hex(text),
+            Encoding::UrlEncoded => decode_url(text),
+            Encoding::Binary => decode_binary(text),
+            Encoding::Morse => decode_morse(text),
+            Encoding::Decimal => decode_char_codes(text, 10),
+            Encoding::Octal => decode_char_codes(text, 8),
+            Encoding::QuotedPrintable => decode_quoted_printable(text),
+        }
+    }
+
+    /// How strongly the shape of the text suggests this encoding, before decoding
+    fn shape_confidence(&self, text: &str) -> f64 {
+        let text = text.trim();
+        let compact: String = text.chars().filter(|&c| !is_line_break(c)).collect();
+
+        match self {
+            Encoding::Base64 => {
+                let has_padding = compact.ends_with('=');
+                let has_upper = compact.chars().any(|c| c.is_ascii_uppercase());
+                let has_lower = compact.chars().any(|c| c.is_ascii_lowercase());
+                let has_digit = compact.chars().any(|c| c.is_ascii_digit());
+                let mut confidence: f64 = 0.4;
+                if compact.len().is_multiple_of(4) {
+                    confidence += 0.2;
+                }
+                if has_padding {
+                    confidence += 0.1;
+                }
+                if has_upper && has_lower && has_digit {
+                    confidence += 0.1;
+                }
+                confidence
+            }
+            Encoding::Base32 => {
+                if compact.len().is_multiple_of(8) {
+                    0.7
+                } else {
+                    0.5
+                }
+            }
+            // Any alphanumeric word fits the base58 alphabet, so stay sceptical
+            Encoding::Base58 => 0.3,
+            Encoding::Hex => {
+                if compact.chars().all(|c| c.is_ascii_digit()) {
+                    0.3
+                } else {
+                    0.7
+                }
+            }
+            Encoding::UrlEncoded => {
+                let escapes = text.matches('%').count();
+                (0.4 + escapes as f64 * 3.0 / text.len() as f64).min(0.9)
+            }
+            Encoding::Binary => 0.9,
+            Encoding::Morse => 0.9,
+            Encoding::Decimal => {
+                let printable = decode_char_codes(text, 10)
+                    .map(|bytes| bytes.iter().all(|&b| is_printable_byte(b)))
+                    .unwrap_or(false);
+                if printable {
+                    0.7
+                } else {
+                    0.5
+                }
+            }
+            Encoding::Octal => {
+                if split_codes(text).all(|code| code.len() == 3) {
+                    0.6
+                } else {
+                    0.4
+                }
+            }
+            Encoding::QuotedPrintable => 0.6,
+        }
+    }
+}
+
+/// Identifies which encodings the text could plausibly be in.
+///
+/// Returns every encoding that the text is a valid instance of, paired with a
+/// confidence between 0.0 and 1.0, most likely first. Confidence is raised when
+/// the decoded payload is itself English text.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::{identify_encodings, Encoding};
+///
+/// let encodings = identify_encodings("SGVsbG8gd29ybGQh");
+/// assert_eq!(encodings[0].0, Encoding::Base64);
+///
+/// let encodings = identify_encodings("68656c6c6f20776f726c64");
+/// assert_eq!(encodings[0].0, Encoding::Hex);
+/// ```
+pub fn identify_encodings(text: &str) -> Vec<(Encoding, f64)> {
+    let mut matches: Vec<(Encoding, f64)> = ALL_ENCODINGS
+        .iter()
+        .filter_map(|&encoding| {
+            let decoded = encoding.decode(text)?;
+            let mut confidence = encoding.shape_confidence(text);
+            if decodes_to_english(&decoded) {
+                confidence = (confidence + 1.0) / 2.0;
+            }
+            Some((encoding, confidence))
+        })
+        .collect();
+
+    // Stable sort keeps declaration order for equal confidences
+    matches.sort_by(|a, b| b.1.total_cmp(&a.1));
+    matches
+}
+
+/// Returns true if the decoded payload is UTF-8 text that passes the English checks
+fn decodes_to_english(decoded: &[u8]) -> bool {
+    std::str::from_utf8(decoded)
+        .map(|payload| !is_gibberish(payload, Sensitivity::Medium))
+        .unwrap_or(false)
+}
+
+fn is_printable_byte(b: u8) -> bool {
+    (0x20..0x7f).contains(&b) || b == b'\n' || b == b'\r' || b == b'\t'
+}
+
+/// Base64 and base32 payloads are often wrapped, so line breaks are ignored
+fn is_line_break(c: char) -> bool {
+    c == '\n' || c == '\r'
+}
+
+/// Parse a byte from digits in the given radix.
+///
+/// Unlike [`u8::from_str_radix`], a leading sign is rejected.
+fn parse_byte(digits: &str, radix: u32) -> Option<u8> {
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    u8::from_str_radix(digits, radix).ok()
+}
+
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let compact: Vec<u8> = text
+        .bytes()
+        .filter(|&b| !is_line_break(b as char))
+        .collect();
+    let data = compact
+        .strip_suffix(b"==")
+        .or_else(|| compact.strip_suffix(b"="));
+    let padded = data.is_some();
+    let data = data.unwrap_or(&compact);
+
+    if compact.len() < 4 || data.len() % 4 == 1 || (padded && !compact.len().is_multiple_of(4)) {
+        return None;
+    }
+
+    let mut bits: u32 = 0;
+    let mut bit_count = 0;
+    let mut output = Vec::with_capacity(data.len() * 3 / 4);
+    for &b in data {
+        let value = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return None,
+        };
+        bits = (bits << 6) | value as u32;
+        bit_count += 6;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            output.push((bits >> bit_count) as u8);
+            bits &= (1 << bit_count) - 1;
+        }
+    }
+
+    Some(output)
+}
+
+fn decode_base32(text: &str) -> Option<Vec<u8>> {
+    let compact: Vec<u8> = text
+        .bytes()
+        .filter(|&b| !is_line_break(b as char))
+        .collect();
+    let data_len = compact
+        .iter()
+        .rposition(|&b| b != b'=')
+        .map_or(0, |i| i + 1);
+    let (data, padding) = compact.split_at(data_len);
+
+    if data.len() < 8
+        || padding.len() > 6
+        || (!padding.is_empty() && !compact.len().is_multiple_of(8))
+    {
+        return None;
+    }
+
+    let mut bits: u64 = 0;
+    let mut bit_count = 0;
+    let mut output = Vec::with_capacity(data.len() * 5 / 8);
+    for &b in data {
+        let value = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'2'..=b'7' => b - b'2' + 26,
+            _ => return None,
+        };
+        bits = (bits << 5) | value as u64;
+        bit_count += 5;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            output.push((bits >> bit_count) as u8);
+            bits &= (1 << bit_count) - 1;
+        }
+    }
+
+    Some(output)
+}
+
+fn decode_base58(text: &str) -> Option<Vec<u8>> {
+    if text.len() < 6 || text.len() > MAX_BASE58_LEN {
+        return None;
+    }
+
+    // Little-endian base256 number built up digit by digit, reversed at the end
+    let mut output: Vec<u8> = Vec::new();
+    for b in text.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == b)? as u32;
+        for byte in output.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            output.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    // Each leading '1' encodes a leading zero byte
+    let leading_zeros = text.bytes().take_while(|&b| b == b'1').count();
+    output.extend(std::iter::repeat_n(0, leading_zeros));
+    output.reverse();
+    Some(output)
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    let text = text
+        .strip_prefix("0x")
+        .or_else(|| text.strip_prefix("0X"))
+        .unwrap_or(text);
+    let digits: Vec<u8> = text
+        .bytes()
+        .filter(|&b| !b.is_ascii_whitespace() && b != b':')
+        .collect();
+
+    if digits.len() < 4 || !digits.len().is_multiple_of(2) {
+        return None;
+    }
+
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
+
+fn decode_url(text: &str) -> Option<Vec<u8>> {
+    if !text.contains('%') || text.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let bytes = text.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = text.get(i + 1..i + 3)?;
+                output.push(parse_byte(hex, 16)?);
+                i += 3;
+            }
+            b'+' => {
+                output.push(b' ');
+                i += 1;
+            }
+            b => {
+                output.push(b);
+                i += 1;
+            }
+        }
+    }
+
+    Some(output)
+}
+
+fn decode_binary(text: &str) -> Option<Vec<u8>> {
+    if !text
+        .chars()
+        .all(|c| c == '0' || c == '1' || c.is_whitespace())
+    {
+        return None;
+    }
+
+    let groups: Vec<&str> = text.split_whitespace().collect();
+    let groups: Vec<&str> = if groups.len() == 1 {
+        // Contiguous bits, split into bytes
+        let bits = groups[0];
+        if !bits.len().is_multiple_of(8) {
+            return None;
+        }
+        (0..bits.len())
+            .step_by(8)
+            .map(|i| &bits[i..i + 8])
+            .collect()
+    } else {
+        groups
+    };
+
+    if groups.len() < 2 || groups.iter().any(|g| g.is_empty() || g.len() > 8) {
+        return None;
+    }
+
+    groups.iter().map(|g| parse_byte(g, 2)).collect()
+}
+
+fn decode_morse(text: &str) -> Option<Vec<u8>> {
+    if !text
+        .chars()
+        .all(|c| c == '.' || c == '-' || c == '/' || c == ' ')
+        || (!text.contains('.') && !text.contains('-'))
+    {
+        return None;
+    }
+
+    let mut output = Vec::new();
+    for (i, word) in text.split('/').enumerate() {
+        if i > 0 {
+            output.push(b' ');
+        }
+        for letter in word.split_whitespace() {
+            output.push(morse_letter(letter)?);
+        }
+    }
+
+    Some(output)
+}
+
+fn morse_letter(code: &str) -> Option<u8> {
+    let letter = match code {
+        ".-" => b'A',
+        "-..." => b'B',
+        "-.-." => b'C',
+        "-.." => b'D',
+        "." => b'E',
+        "..-." => b'F',
+        "--." => b'G',
+        "...." => b'H',
+        ".." => b'I',
+        ".---" => b'J',
+        "-.-" => b'K',
+        ".-.." => b'L',
+        "--" => b'M',
+        "-." => b'N',
+        "---" => b'O',
+        ".--." => b'P',
+        "--.-" => b'Q',
+        ".-." => b'R',
+        "..." => b'S',
+        "-" => b'T',
+        "..-" => b'U',
+        "...-" => b'V',
+        ".--" => b'W',
+        "-..-" => b'X',
+        "-.--" => b'Y',
+        "--.." => b'Z',
+        "-----" => b'0',
+        ".----" => b'1',
+        "..---" => b'2',
+        "...--" => b'3',
+        "....-" => b'4',
+        "....." => b'5',
+        "-...." => b'6',
+        "--..." => b'7',
+        "---.." => b'8',
+        "----." => b'9',
+        ".-.-.-" => b'.',
+        "--..--" => b',',
+        "..--.." => b'?',
+        ".----." => b'\'',
+        "-.-.--" => b'!',
+        "-..-." => b'/',
+        "---..." => b':',
+        "-...-" => b'=',
+        ".-.-." => b'+',
+        "-....-" => b'-',
+        ".--.-." => b'@',
+        _ => return None,
+    };
+    Some(letter)
+}
+
+/// Split a list of character codes on whitespace and commas
+fn split_codes(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|code| !code.is_empty())
+}
+
+fn decode_char_codes(text: &str, radix: u32) -> Option<Vec<u8>> {
+    let codes: Vec<&str> = split_codes(text).collect();
+    if codes.len() < 2 {
+        return None;
+    }
+
+    codes.iter().map(|code| parse_byte(code, radix)).collect()
+}
+
+fn decode_quoted_printable(text: &str) -> Option<Vec<u8>> {
+    let bytes = text.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut escapes = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'=' {
+            output.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+
+        // Soft line break
+        if text[i + 1..].starts_with("\r\n") {
+            i += 3;
+        } else if text[i + 1..].starts_with('\n') {
+            i += 2;
+        } else {
+            let hex = text.get(i + 1..i + 3)?;
+            // Quoted-printable mandates uppercase hex digits
+            if hex.bytes().any(|b| b.is_ascii_lowercase()) {
+                return None;
+            }
+            output.push(parse_byte(hex, 16)?);
+            i += 3;
+        }
+        escapes += 1;
+    }
+
+    if escapes == 0 {
+        return None;
+    }
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn top_encoding(text: &str) -> Option<Encoding> {
+        identify_encodings(text)
+            .first()
+            .map(|(encoding, _)| *encoding)
+    }
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(
+            Encoding::Base64.decode("SGVsbG8gd29ybGQh").unwrap(),
+            b"Hello world!"
+        );
+        assert_eq!(Encoding::Base64.decode("aGk=").unwrap(), b"hi");
+        assert_eq!(top_encoding("SGVsbG8gd29ybGQh"), Some(Encoding::Base64));
+        assert!(Encoding::Base64.decode("SGVsb=G8").is_none());
+    }
+
+    #[test]
+    fn test_base32() {
+        assert_eq!(
+            Encoding::Base32.decode("NBSWY3DPEB3W64TMMQ======").unwrap(),
+            b"hello world"
+        );
+        assert_eq!(
+            top_encoding("NBSWY3DPEB3W64TMMQ======"),
+            Some(Encoding::Base32)
+        );
+    }
+
+    #[test]
+    fn test_base58() {
+        assert_eq!(
+            Encoding::Base58.decode("StV1DL6CwTryKyV").unwrap(),
+            b"hello world"
+        );
+        assert!(Encoding::Base58.decode("0OIl00").is_none());
+        assert_eq!(
+            Encoding::Base58.decode("11StV1DL6CwTryKyV").unwrap(),
+            b"\0\0hello world"
+        );
+        assert!(Encoding::Base58
+            .decode(&"StV1DL6CwTryKyV".repeat(3000))
+            .is_none());
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(Encoding::Hex.decode("68 65 6c 6c 6f").unwrap(), b"hello");
+        assert_eq!(top_encoding("68656c6c6f20776f726c64"), Some(Encoding::Hex));
+    }
+
+    #[test]
+    fn test_url_encoded() {
+        assert_eq!(
+            Encoding::UrlEncoded.decode("hello%20world%21").unwrap(),
+            b"hello world!"
+        );
+        assert_eq!(
+            top_encoding("hello%20world%2C%20how%20are%20you"),
+            Some(Encoding::UrlEncoded)
+        );
+        assert!(Encoding::UrlEncoded.decode("a%+1b").is_none());
+    }
+
+    #[test]
+    fn test_binary() {
+        assert_eq!(Encoding::Binary.decode("01101000 01101001").unwrap(), b"hi");
+        assert_eq!(Encoding::Binary.decode("0110100001101001").unwrap(), b"hi");
+        assert_eq!(
+            top_encoding("01101000 01100101 01101100 01101100 01101111"),
+            Some(Encoding::Binary)
+        );
+    }
+
+    #[test]
+    fn test_morse() {
+        assert_eq!(
+            Encoding::Morse
+                .decode(".... . .-.. .-.. --- / .-- --- .-. .-.. -..")
+                .unwrap(),
+            b"HELLO WORLD"
+        );
+        assert_eq!(
+            top_encoding(".... . .-.. .-.. --- / .-- --- .-. .-.. -.."),
+            Some(Encoding::Morse)
+        );
+    }
+
+    #[test]
+    fn test_char_codes() {
+        assert_eq!(
+            Encoding::Decimal.decode("104, 101, 108, 108, 111").unwrap(),
+            b"hello"
+        );
+        assert_eq!(
+            Encoding::Octal.decode("150 145 154 154 157").unwrap(),
+            b"hello"
+        );
+        assert!(Encoding::Octal.decode("104 108").is_none());
+        assert!(Encoding::Decimal.decode("+104 +105").is_none());
+    }
+
+    #[test]
+    fn test_quoted_printable() {
+        assert_eq!(
+            Encoding::QuotedPrintable
+                .decode("caf=C3=A9 au lait")
+                .unwrap(),
+            "café au lait".as_bytes()
+        );
+        assert!(Encoding::QuotedPrintable
+            .decode("no escapes here")
+            .is_none());
+        assert!(Encoding::QuotedPrintable.decode("a=+1b").is_none());
+    }
+
+    #[test]
+    fn test_plain_english_has_no_strong_encoding() {
+        let encodings = identify_encodings("The quick brown fox jumps over the lazy dog");
+        assert!(encodings.iter().all(|(_, confidence)| *confidence < 0.5));
+    }
+}
@@ -0,0 +1,25850 @@
+use phf::phf_map;
+
+/// Zipf frequency of words in tenths, e.g. 78 for "the"
+pub static WORD_ZIPF: phf::Map<&'static str, u8> = phf_map! {
+    "a" => 74,
+    "aaa" => 38,
+    "aaron" => 42,
+    "aba" => 37,
+    "abacus" => 24,
+    "abandon" => 39,
+    "abandoned" => 41,
+    "abandoning" => 32,
+    "abandons" => 31,
+    "abash" => 24,
+    "abashed" => 27,
+    "abating" => 24,
+    "abbey" => 24,
+    "abbot" => 24,
+    "abbots" => 24,
+    "abbreviate" => 33,
+    "abbreviated" => 38,
+    "abbreviating" => 24,
+    "abbreviation" => 44,
+    "abbreviations" => 43,
+    "abc" => 41,
+    "abduction" => 27,
+    "abe" => 30,
+    "abel" => 32,
+    "aberdeen" => 27,
+    "aberration" => 24,
+    "abhor" => 31,
+    "abhorred" => 35,
+    "abhorrence" => 32,
+    "abhorrent" => 27,
+    "abide" => 37,
+    "abides" => 24,
+    "abiding" => 30,
+    "abilities" => 33,
+    "ability" => 47,
+    "abject" => 27,
+    "abjure" => 24,
+    "ablaut" => 24,
+    "ablaze" => 24,
+    "able" => 53,
+    "ablutions" => 24,
+    "abnormal" => 33,
+    "abnormally" => 32,
+    "abo" => 27,
+    "aboard" => 32,
+    "abode" => 33,
+    "abolished" => 24,
+    "abolishing" => 24,
+    "abominable" => 31,
+    "abominably" => 24,
+    "abomination" => 24,
+    "abort" => 46,
+    "aborted" => 41,
+    "aborting" => 43,
+    "abortion" => 32,
+    "abortive" => 29,
+    "aborts" => 40,
+    "abound" => 24,
+    "abounded" => 24,
+    "abounding" => 27,
+    "about" => 59,
+    "above" => 58,
+    "abraham" => 24,
+    "abreast" => 30,
+    "abridge" => 24,
+    "abridged" => 30,
+    "abridging" => 24,
+    "abroad" => 37,
+    "abrupt" => 32,
+    "abruptly" => 37,
+    "abseil" => 33,
+    "absence" => 42,
+    "absent" => 39,
+    "absented" => 24,
+    "absently" => 30,
+    "absolute" => 51,
+    "absolutely" => 44,
+    "absolution" => 24,
+    "absolved" => 24,
+    "absorb" => 32,
+    "absorbed" => 37,
+    "absorbing" => 34,
+    "absorbs" => 24,
+    "abstain" => 24,
+    "abstained" => 24,
+    "abstract" => 46,
+    "abstracted" => 35,
+    "abstractedly" => 24,
+    "abstracting" => 35,
+    "abstraction" => 47,
+    "abstractions" => 46,
+    "abstractive" => 24,
+    "abstractly" => 32,
+    "abstracts" => 39,
+    "abstruse" => 24,
+    "absurd" => 36,
+    "absurdity" => 24,
+    "absurdly" => 31,
+    "abundance" => 24,
+    "abundant" => 24,
+    "abundantly" => 27,
+    "abuse" => 37,
+    "abused" => 29,
+    "abusing" => 30,
+    "abusive" => 24,
+    "abutted" => 27,
+    "abyss" => 27,
+    "acacias" => 29,
+    "academia" => 29,
+    "academic" => 38,
+    "academics" => 24,
+    "academy" => 27,
+    "acceded" => 24,
+    "accelerate" => 35,
+    "accelerated" => 42,
+    "accelerates" => 33,
+    "accelerating" => 32,
+    "acceleration" => 40,
+    "accelerations" => 24,
+    "accelerator" => 36,
+    "accelerators" => 32,
+    "accelerometer" => 27,
+    "accent" => 36,
+    "accented" => 35,
+    "accents" => 36,
+    "accentuated" => 24,
+    "accept" => 50,
+    "acceptability" => 24,
+    "acceptable" => 41,
+    "acceptably" => 24,
+    "acceptance" => 33,
+    "acceptances" => 24,
+    "acceptation" => 24,
+    "accepted" => 48,
+    "accepting" => 41,
+    "acceptor" => 24,
+    "accepts" => 47,
+    "access" => 57,
+    "accessed" => 48,
+    "accesses" => 46,
+    "accessibility" => 40,
+    "accessible" => 46,
+    "accessing" => 48,
+    "accessory" => 24,
+    "accident" => 40,
+    "accidental" => 39,
+    "accidentally" => 46,
+    "accidents" => 34,
+    "accommodate" => 39,
+    "accommodated" => 24,
+    "accommodates" => 27,
+    "accommodating" => 24,
+    "accommodation" => 24,
+    "accompanied" => 42,
+    "accompanies" => 29,
+    "accompany" => 34,
+    "accompanying" => 36,
+    "accompli" => 24,
+    "accomplice" => 32,
+    "accomplish" => 41,
+    "accomplished" => 41,
+    "accomplishes" => 31,
+    "accomplishing" => 30,
+    "accomplishment" => 27,
+    "accomplishments" => 30,
+    "accord" => 27,
+    "accordance" => 38,
+    "accorded" => 27,
+    "according" => 53,
+    "accordingly" => 43,
+    "accosted" => 30,
+    "account" => 50,
+    "accountant" => 24,
+    "accounted" => 38,
+    "accounting" => 34,
+    "accounts" => 45,
+    "accoutred" => 24,
+    "accoutrements" => 24,
+    "accreditation" => 27,
+    "accredited" => 30,
+    "accumulate" => 43,
+    "accumulated" => 37,
+    "accumulates" => 29,
+    "accumulating" => 35,
+    "accumulation" => 36,
+    "accumulations" => 30,
+    "accumulative" => 24,
+    "accumulator" => 40,
+    "accuracy" => 43,
+    "accurate" => 44,
+    "accurately" => 39,
+    "accursed" => 30,
+    "accusation" => 29,
+    "accusations" => 30,
+    "accusative" => 32,
+    "accuse" => 29,
+    "accused" => 37,
+    "accuser" => 27,
+    "accuses" => 24,
+    "accusing" => 27,
+    "accusingly" => 27,
+    "accustomed" => 38,
+    "ace" => 24,
+    "aces" => 24,
+    "acetones" => 24,
+    "ache" => 29,
+    "ached" => 24,
+    "aches" => 24,
+    "achievable" => 29,
+    "achieve" => 45,
+    "achieved" => 43,
+    "achievement" => 27,
+    "achievements" => 24,
+    "achieves" => 37,
+    "achieving" => 37,
+    "aching" => 30,
+    "acid" => 37,
+    "acknowledge" => 35,
+    "acknowledged" => 36,
+    "acknowledgement" => 35,
+    "acknowledgements" => 41,
+    "acknowledges" => 27,
+    "acknowledging" => 27,
+    "acknowledgment" => 36,
+    "acknowledgments" => 37,
+    "acme" => 31,
+    "acorns" => 27,
+    "acoustic" => 24,
+    "acquaintance" => 36,
+    "acquaintances" => 29,
+    "acquainted" => 37,
+    "acquiesce" => 24,
+    "acquiesced" => 24,
+    "acquire" => 44,
+    "acquired" => 43,
+    "acquirement" => 30,
+    "acquires" => 38,
+    "acquiring" => 38,
+    "acquisition" => 36,
+    "acquisitions" => 29,
+    "acquit" => 24,
+    "acquittal" => 24,
+    "acquitted" => 30,
+    "acre" => 24,
+    "acres" => 29,
+    "acrobat" => 24,
+    "acrobatic" => 30,
+    "acrobatics" => 24,
+    "acrobats" => 27,
+    "acronym" => 31,
+    "acronyms" => 24,
+    "acropolis" => 24,
+    "across" => 52,
+    "acrylic" => 24,
+    "act" => 45,
+    "acted" => 36,
+    "acting" => 39,
+    "action" => 48,
+    "actionable" => 30,
+    "actions" => 50,
+    "activate" => 44,
+    "activated" => 42,
+    "activates" => 37,
+    "activating" => 35,
+    "activation" => 36,
+    "active" => 51,
+    "actively" => 41,
+    "activities" => 37,
+    "activity" => 44,
+    "actor" => 40,
+    "actors" => 39,
+    "actress" => 27,
+    "acts" => 42,
+    "actual" => 50,
+    "actuality" => 29,
+    "actually" => 52,
+    "actuated" => 24,
+    "actuators" => 24,
+    "acute" => 40,
+    "acuteness" => 24,
+    "acyclic" => 32,
+    "ada" => 42,
+    "adam" => 37,
+    "adam's" => 27,
+    "adamantine" => 24,
+    "adams" => 33,
+    "adapt" => 40,
+    "adaptability" => 24,
+    "adaptable" => 27,
+    "adaptation" => 34,
+    "adaptations" => 29,
+    "adapted" => 40,
+    "adapter" => 44,
+    "adapters" => 41,
+    "adapting" => 29,
+    "adaption" => 27,
+    "adaptive" => 36,
+    "adaptor" => 32,
+    "adaptors" => 32,
+    "adapts" => 29,
+    "add" => 63,
+    "added" => 57,
+    "addenda" => 24,
+    "adder" => 36,
+    "addicted" => 27,
+    "adding" => 54,
+    "addison" => 30,
+    "addition" => 51,
+    "additional" => 57,
+    "additionally" => 50,
+    "additions" => 42,
+    "additive" => 38,
+    "address" => 54,
+    "addressable" => 35,
+    "addressed" => 41,
+    "addresses" => 49,
+    "addressing" => 38,
+    "adds" => 52,
+    "adduced" => 27,
+    "adelaide" => 24,
+    "aden" => 33,
+    "adept" => 27,
+    "adequate" => 36,
+    "adequately" => 27,
+    "adhere" => 39,
+    "adherence" => 29,
+    "adherent" => 24,
+    "adheres" => 33,
+    "adhering" => 31,
+    "adhesion" => 24,
+    "adhesive" => 24,
+    "adieu" => 29,
+    "adjacency" => 32,
+    "adjacent" => 46,
+    "adjacently" => 31,
+    "adjective" => 32,
+    "adjectives" => 36,
+    "adjoin" => 29,
+    "adjoined" => 24,
+    "adjoining" => 24,
+    "adjourn" => 31,
+    "adjournment" => 29,
+    "adjunct" => 24,
+    "adjuration" => 24,
+    "adjust" => 51,
+    "adjustable" => 29,
+    "adjusted" => 44,
+    "adjusting" => 38,
+    "adjustment" => 33,
+    "adjustments" => 36,
+    "adjusts" => 35,
+    "adler" => 41,
+    "admin" => 35,
+    "administer" => 27,
+    "administered" => 31,
+    "administrating" => 24,
+    "administration" => 35,
+    "administrative" => 33,
+    "administratively" => 27,
+    "administrator" => 35,
+    "administrators" => 33,
+    "admirable" => 34,
+    "admirably" => 32,
+    "admiralty" => 24,
+    "admiration" => 37,
+    "admire" => 32,
+    "admired" => 34,
+    "admirers" => 27,
+    "admiring" => 31,
+    "admiringly" => 27,
+    "admissibility" => 24,
+    "admission" => 33,
+    "admit" => 37,
+    "admits" => 24,
+    "admittance" => 24,
+    "admitted" => 36,
+    "admitting" => 27,
+    "ado" => 30,
+    "adobe" => 34,
+    "adopt" => 36,
+    "adopted" => 39,
+    "adopter" => 27,
+    "adopters" => 32,
+    "adopting" => 34,
+    "adoption" => 37,
+    "adopts" => 31,
+    "adorable" => 29,
+    "adoration" => 29,
+    "adore" => 24,
+    "adored" => 30,
+    "adorn" => 27,
+    "adorned" => 34,
+    "adorns" => 24,
+    "adrian" => 35,
+    "adriatic" => 24,
+    "adrift" => 29,
+    "adroit" => 27,
+    "adroitness" => 24,
+    "ads" => 29,
+    "adult" => 29,
+    "adults" => 27,
+    "adv" => 34,
+    "advance" => 44,
+    "advanced" => 49,
+    "advancement" => 32,
+    "advancements" => 27,
+    "advances" => 38,
+    "advancing" => 39,
+    "advantage" => 47,
+    "advantageous" => 32,
+    "advantages" => 43,
+    "advent" => 29,
+    "adventitious" => 24,
+    "adventure" => 40,
+    "adventurer" => 24,
+    "adventurers" => 24,
+    "adventures" => 38,
+    "adventurous" => 31,
+    "adverb" => 29,
+    "adverbs" => 29,
+    "adversarial" => 27,
+    "adversaries" => 30,
+    "adversary" => 34,
+    "adverse" => 30,
+    "adversely" => 24,
+    "adversity" => 29,
+    "advertise" => 35,
+    "advertised" => 32,
+    "advertisement" => 38,
+    "advertisements" => 24,
+    "advertises" => 27,
+    "advertising" => 30,
+    "advice" => 41,
+    "advices" => 24,
+    "advisable" => 35,
+    "advise" => 36,
+    "advised" => 37,
+    "adviser" => 32,
+    "advises" => 31,
+    "advisory" => 38,
+    "advocate" => 31,
+    "advocated" => 32,
+    "advocates" => 31,
+    "aegis" => 24,
+    "aerial" => 27,
+    "aerospace" => 24,
+    "aesthetic" => 29,
+    "aesthetically" => 24,
+    "aesthetics" => 27,
+    "afar" => 24,
+    "affability" => 24,
+    "affair" => 39,
+    "affaire" => 24,
+    "affairs" => 37,
+    "affect" => 47,
+    "affectation" => 27,
+    "affectations" => 24,
+    "affected" => 44,
+    "affecting" => 41,
+    "affection" => 40,
+    "affectionate" => 33,
+    "affectionately" => 30,
+    "affections" => 33,
+    "affects" => 45,
+    "affiliated" => 33,
+    "affiliates" => 24,
+    "affiliation" => 27,
+    "affiliations" => 24,
+    "affinities" => 27,
+    "affinity" => 32,
+    "affirm" => 27,
+    "affirmation" => 30,
+    "affirmative" => 27,
+    "affirmed" => 24,
+    "affix" => 42,
+    "affixed" => 24,
+    "affixes" => 35,
+    "afflict" => 24,
+    "afflicted" => 32,
+    "afflicting" => 24,
+    "affliction" => 24,
+    "affluence" => 24,
+    "afford" => 38,
+    "affordability" => 24,
+    "affordable" => 32,
+    "afforded" => 35,
+    "affording" => 29,
+    "affords" => 30,
+    "affright" => 27,
+    "affront" => 24,
+    "afghan" => 24,
+    "afghanistan" => 24,
+    "afloat" => 24,
+    "afoot" => 24,
+    "afore" => 24,
+    "aforementioned" => 38,
+    "afoul" => 27,
+    "afraid" => 43,
+    "afresh" => 27,
+    "africa" => 35,
+    "african" => 31,
+    "africans" => 24,
+    "afrikaans" => 27,
+    "afro" => 24,
+    "aft" => 24,
+    "after" => 61,
+    "afternoon" => 43,
+    "afternoons" => 27,
+    "afterward" => 37,
+    "afterwards" => 45,
+    "again" => 55,
+    "against" => 53,
+    "agatha" => 37,
+    "agave" => 24,
+    "age" => 44,
+    "aged" => 35,
+    "agencies" => 27,
+    "agency" => 33,
+    "agenda" => 27,
+    "agent" => 42,
+    "agents" => 37,
+    "ages" => 33,
+    "agglutinative" => 24,
+    "aggravate" => 24,
+    "aggravated" => 24,
+    "aggravation" => 24,
+    "aggregate" => 39,
+    "aggregated" => 34,
+    "aggregates" => 31,
+    "aggregating" => 29,
+    "aggregation" => 37,
+    "aggregations" => 24,
+    "aggressive" => 34,
+    "aggressively" => 32,
+    "agile" => 29,
+    "agility" => 29,
+    "agitated" => 35,
+    "agitates" => 27,
+    "agitation" => 37,
+    "agnostic" => 44,
+    "ago" => 45,
+    "agonies" => 32,
+    "agonised" => 24,
+    "agonising" => 27,
+    "agonizing" => 27,
+    "agonizingly" => 24,
+    "agony" => 39,
+    "agora" => 31,
+    "agree" => 42,
+    "agreeable" => 33,
+    "agreed" => 41,
+    "agreeing" => 29,
+    "agreement" => 41,
+    "agreements" => 32,
+    "agrees" => 27,
+    "agricultural" => 37,
+    "agriculture" => 30,
+    "agronomy" => 27,
+    "aground" => 24,
+    "agues" => 24,
+    "aha" => 27,
+    "ahead" => 43,
+    "ahem" => 24,
+    "aid" => 40,
+    "aida" => 27,
+    "aide" => 24,
+    "aided" => 34,
+    "aides" => 24,
+    "aiding" => 32,
+    "aids" => 33,
+    "ailing" => 24,
+    "ailment" => 24,
+    "ails" => 27,
+    "aim" => 43,
+    "aimed" => 35,
+    "aiming" => 34,
+    "aimlessly" => 27,
+    "aims" => 46,
+    "ain't" => 45,
+    "air" => 46,
+    "aircraft" => 24,
+    "aired" => 27,
+    "airedale" => 29,
+    "airily" => 27,
+    "airplane" => 24,
+    "airplanes" => 24,
+    "airport" => 24,
+    "airs" => 31,
+    "airy" => 27,
+    "aisle" => 34,
+    "aisles" => 32,
+    "ajar" => 29,
+    "akimbo" => 24,
+    "akin" => 37,
+    "ala" => 27,
+    "alabama" => 31,
+    "alacrity" => 30,
+    "aladdin" => 24,
+    "alan" => 35,
+    "alarm" => 37,
+    "alarmed" => 35,
+    "alarming" => 30,
+    "alarms" => 24,
+    "alas" => 39,
+    "alb" => 24,
+    "albanian" => 27,
+    "albany" => 24,
+    "albatross" => 24,
+    "albeit" => 33,
+    "albert" => 33,
+    "alchemists" => 27,
+    "alcohol" => 24,
+    "alderman" => 24,
+    "aldershot" => 24,
+    "ale" => 32,
+    "alec" => 24,
+    "alert" => 38,
+    "alerting" => 24,
+    "alertly" => 27,
+    "alerts" => 31,
+    "alex" => 37,
+    "alexander" => 34,
+    "alexandria" => 27,
+    "alexis" => 24,
+    "alfred" => 33,
+    "alga" => 30,
+    "algebra" => 40,
+    "algebraic" => 40,
+    "algorithm" => 52,
+    "algorithmic" => 32,
+    "algorithmically" => 29,
+    "algorithms" => 49,
+    "alias" => 49,
+    "aliases" => 46,
+    "alice" => 50,
+    "alicia" => 24,
+    "alien" => 27,
+    "alighted" => 29,
+    "alighting" => 24,
+    "align" => 43,
+    "aligned" => 50,
+    "aligning" => 36,
+    "alignment" => 51,
+    "alignments" => 34,
+    "aligns" => 35,
+    "alike" => 37,
+    "alive" => 45,
+    "all" => 64,
+    "allan" => 31,
+    "alleged" => 24,
+    "allegiance" => 24,
+    "alleging" => 29,
+    "allegro" => 27,
+    "allen" => 33,
+    "allergic" => 24,
+    "alleviate" => 34,
+    "alleviated" => 29,
+    "alleviates" => 27,
+    "alley" => 36,
+    "alleys" => 31,
+    "alliance" => 32,
+    "allied" => 29,
+    "allies" => 29,
+    "alligators" => 24,
+    "allison" => 24,
+    "alliterative" => 24,
+    "allocate" => 49,
+    "allocated" => 51,
+    "allocates" => 43,
+    "allocating" => 45,
+    "allocation" => 52,
+    "allocations" => 47,
+    "allocator" => 50,
+    "allophones" => 24,
+    "allotted" => 27,
+    "allow" => 55,
+    "allowable" => 33,
+    "allowance" => 36,
+    "allowances" => 32,
+    "allowed" => 53,
+    "allowing" => 49,
+    "allows" => 56,
+    "alloy" => 43,
+    "allsorts" => 24,
+    "allude" => 30,
+    "alluded" => 30,
+    "allured" => 27,
+    "allurements" => 24,
+    "alluring" => 27,
+    "allusion" => 33,
+    "allusions" => 29,
+    "ally" => 33,
+    "almanac" => 24,
+    "almighty" => 24,
+    "almost" => 50,
+    "alms" => 24,
+    "aloft" => 29,
+    "alone" => 47,
+    "along" => 51,
+    "alongside" => 42,
+    "aloud" => 37,
+    "alpaca" => 24,
+    "alpha" => 44,
+    "alphabet" => 37,
+    "alphabetic" => 40,
+    "alphabetical" => 38,
+    "alphabetically" => 34,
+    "alphabetize" => 24,
+    "alphabets" => 32,
+    "alphanumeric" => 39,
+    "alphanumerics" => 27,
+    "alphas" => 24,
+    "alpine" => 35,
+    "alps" => 31,
+    "already" => 55,
+    "alright" => 33,
+    "also" => 63,
+    "alt" => 45,
+    "altar" => 33,
+    "alter" => 41,
+    "alteration" => 33,
+    "alterations" => 30,
+    "altered" => 39,
+    "altering" => 32,
+    "alternate" => 46,
+    "alternately" => 32,
+    "alternates" => 30,
+    "alternating" => 34,
+    "alternation" => 31,
+    "alternative" => 50,
+    "alternatively" => 48,
+    "alternatives" => 46,
+    "alters" => 32,
+    "although" => 51,
+    "altitude" => 24,
+    "altogether" => 39,
+    "altos" => 27,
+    "altruistic" => 24,
+    "aluminum" => 27,
+    "alumni" => 27,
+    "alveolar" => 24,
+    "always" => 58,
+    "am" => 52,
+    "amalgam" => 24,
+    "amalgamation" => 24,
+    "amassed" => 27,
+    "amateur" => 30,
+    "amazed" => 33,
+    "amazement" => 30,
+    "amazing" => 37,
+    "amazingly" => 30,
+    "amazon" => 42,
+    "ambassadors" => 31,
+    "amber" => 24,
+    "ambience" => 24,
+    "ambient" => 34,
+    "ambiguities" => 34,
+    "ambiguity" => 47,
+    "ambiguous" => 44,
+    "ambiguously" => 29,
+    "ambition" => 36,
+    "ambitions" => 27,
+    "ambitious" => 30,
+    "ambulance" => 24,
+    "ambuscade" => 27,
+    "ambush" => 24,
+    "ameliorate" => 24,
+    "amen" => 29,
+    "amenable" => 32,
+    "amend" => 31,
+    "amended" => 31,
+    "amending" => 27,
+    "amendment" => 27,
+    "amendments" => 30,
+    "america" => 40,
+    "american" => 45,
+    "americans" => 34,
+    "amethyst" => 24,
+    "amharic" => 24,
+    "ami" => 33,
+    "amiable" => 37,
+    "amicable" => 24,
+    "amid" => 38,
+    "amidships" => 24,
+    "amidst" => 33,
+    "amir" => 27,
+    "amiss" => 31,
+    "amman" => 27,
+    "ammonia" => 33,
+    "ammunition" => 24,
+    "among" => 49,
+    "amongst" => 32,
+    "amorphous" => 24,
+    "amortised" => 27,
+    "amortization" => 24,
+    "amortize" => 24,
+    "amortized" => 37,
+    "amortizing" => 24,
+    "amos" => 24,
+    "amount" => 51,
+    "amounted" => 31,
+    "amounts" => 41,
+    "amour" => 24,
+    "amp" => 24,
+    "ampersand" => 29,
+    "ampersands" => 24,
+    "amphitheatre" => 24,
+    "ample" => 34,
+    "amplification" => 29,
+    "amplified" => 24,
+    "amplify" => 27,
+    "amplifying" => 30,
+    "amply" => 31,
+    "amputated" => 24,
+    "amsterdam" => 29,
+    "amuse" => 30,
+    "amused" => 31,
+    "amusement" => 35,
+    "amusements" => 24,
+    "amusing" => 30,
+    "amy" => 36,
+    "an" => 67,
+    "anagrams" => 29,
+    "analog" => 35,
+    "analogous" => 41,
+    "analogously" => 30,
+    "analogue" => 32,
+    "analogues" => 24,
+    "analogy" => 27,
+    "analyse" => 29,
+    "analyser" => 24,
+    "analyses" => 34,
+    "analysing" => 27,
+    "analysis" => 48,
+    "analyst" => 27,
+    "analysts" => 24,
+    "analytic" => 31,
+    "analytical" => 35,
+    "analytically" => 24,
+    "analyze" => 39,
+    "analyzed" => 34,
+    "analyzer" => 38,
+    "analyzers" => 24,
+    "analyzes" => 31,
+    "analyzing" => 36,
+    "anatomise" => 24,
+    "anatomy" => 34,
+    "ancestor" => 38,
+    "ancestors" => 35,
+    "ancestral" => 27,
+    "ancestry" => 32,
+    "anchor" => 38,
+    "anchored" => 32,
+    "anchors" => 37,
+    "ancient" => 39,
+    "ancillary" => 36,
+    "and" => 73,
+    "andersen" => 29,
+    "anderson" => 33,
+    "andes" => 24,
+    "andiron" => 29,
+    "andover" => 24,
+    "andre" => 35,
+    "andrea" => 27,
+    "andrew" => 41,
+    "andrews" => 24,
+    "android" => 49,
+    "ands" => 24,
+    "andy" => 41,
+    "anecdotal" => 30,
+    "anemic" => 24,
+    "anew" => 30,
+    "angel" => 39,
+    "angelic" => 29,
+    "angelica" => 27,
+    "angels" => 24,
+    "anger" => 38,
+    "angle" => 41,
+    "angled" => 29,
+    "angles" => 34,
+    "anglia" => 24,
+    "anglicised" => 24,
+    "anglo" => 35,
+    "anglophones" => 24,
+    "angrily" => 34,
+    "angry" => 40,
+    "angstrom" => 24,
+    "anguish" => 38,
+    "anguished" => 24,
+    "angular" => 27,
+    "angus" => 27,
+    "anima" => 24,
+    "animal" => 37,
+    "animals" => 37,
+    "animate" => 33,
+    "animated" => 37,
+    "animating" => 27,
+    "animation" => 38,
+    "animations" => 37,
+    "animator" => 27,
+    "animosity" => 24,
+    "ankle" => 24,
+    "ankles" => 24,
+    "ann" => 30,
+    "annals" => 29,
+    "anne" => 32,
+    "annealing" => 24,
+    "annex" => 37,
+    "annexed" => 24,
+    "annihilation" => 29,
+    "anniversary" => 24,
+    "annotate" => 43,
+    "annotated" => 45,
+    "annotates" => 32,
+    "annotating" => 38,
+    "annotation" => 47,
+    "annotations" => 46,
+    "announce" => 34,
+    "announced" => 39,
+    "announcement" => 35,
+    "announcements" => 30,
+    "announcing" => 30,
+    "annoy" => 30,
+    "annoyance" => 32,
+    "annoyances" => 30,
+    "annoyed" => 34,
+    "annoying" => 40,
+    "annual" => 30,
+    "annually" => 27,
+    "anoints" => 24,
+    "anomalies" => 33,
+    "anomaly" => 27,
+    "anon" => 31,
+    "anonymity" => 24,
+    "anonymous" => 44,
+    "anonymously" => 29,
+    "another" => 58,
+    "anselm" => 24,
+    "ansi" => 47,
+    "answer" => 47,
+    "answered" => 44,
+    "answering" => 37,
+    "answers" => 37,
+    "ant" => 35,
+    "antagonist" => 24,
+    "antagonists" => 29,
+    "antecedents" => 27,
+    "antedating" => 24,
+    "antelope" => 27,
+    "anterior" => 24,
+    "anteroom" => 27,
+    "anthony" => 24,
+    "anthropological" => 24,
+    "anti" => 38,
+    "anticipate" => 33,
+    "anticipated" => 36,
+    "anticipates" => 24,
+    "anticipating" => 29,
+    "anticipation" => 31,
+    "anticipations" => 29,
+    "antics" => 24,
+    "antidote" => 24,
+    "antipathies" => 24,
+    "antipathy" => 24,
+    "antipodes" => 30,
+    "antique" => 27,
+    "antiquity" => 30,
+    "antithesis" => 31,
+    "antoine" => 27,
+    "antoinette" => 24,
+    "anton" => 35,
+    "antonio" => 34,
+    "antony" => 35,
+    "ants" => 24,
+    "anvil" => 29,
+    "anxieties" => 24,
+    "anxiety" => 38,
+    "anxious" => 39,
+    "anxiously" => 38,
+    "any" => 64,
+    "anybody" => 43,
+    "anyhow" => 40,
+    "anymore" => 42,
+    "anyone" => 45,
+    "anything" => 52,
+    "anytime" => 34,
+    "anyway" => 46,
+    "anywhere" => 46,
+    "apache" => 60,
+    "apaches" => 24,
+    "apart" => 41,
+    "apartment" => 38,
+    "apartments" => 30,
+    "apathetically" => 24,
+    "apathy" => 27,
+    "ape" => 24,
+    "aperture" => 31,
+    "apex" => 24,
+    "apiece" => 33,
+    "apis" => 53,
+    "apollo" => 34,
+    "apologetically" => 24,
+    "apologies" => 27,
+    "apologise" => 27,
+    "apologize" => 24,
+    "apology" => 33,
+    "apoplectic" => 27,
+    "apostrophe" => 33,
+    "apostrophes" => 24,
+    "apothecary" => 24,
+    "appalled" => 29,
+    "appalling" => 32,
+    "apparatus" => 32,
+    "apparel" => 29,
+    "apparelled" => 24,
+    "apparent" => 35,
+    "apparently" => 41,
+    "apparition" => 32,
+    "appeal" => 33,
+    "appealed" => 27,
+    "appealing" => 34,
+    "appealingly" => 24,
+    "appeals" => 29,
+    "appear" => 51,
+    "appearance" => 44,
+    "appearances" => 31,
+    "appeared" => 46,
+    "appearing" => 39,
+    "appears" => 47,
+    "appeased" => 29,
+    "appellate" => 24,
+    "append" => 48,
+    "appendages" => 27,
+    "appended" => 44,
+    "appendices" => 29,
+    "appending" => 44,
+    "appendix" => 41,
+    "appendixes" => 24,
+    "appends" => 41,
+    "appertaining" => 27,
+    "appetite" => 33,
+    "appetites" => 24,
+    "applauded" => 24,
+    "applause" => 33,
+    "apple" => 47,
+    "apples" => 35,
+    "applicability" => 30,
+    "applicable" => 45,
+    "applicant" => 27,
+    "application" => 54,
+    "applications" => 52,
+    "applied" => 50,
+    "applies" => 49,
+    "apply" => 51,
+    "applying" => 45,
+    "appoint" => 32,
+    "appointed" => 36,
+    "appointment" => 35,
+    "appointments" => 27,
+    "apportioned" => 24,
+    "apportionment" => 24,
+    "appreciate" => 37,
+    "appreciated" => 40,
+    "appreciation" => 30,
+    "appreciative" => 24,
+    "apprehended" => 24,
+    "apprehending" => 24,
+    "apprehension" => 32,
+    "apprehensions" => 29,
+    "apprehensive" => 27,
+    "apprehensively" => 24,
+    "apprentice" => 24,
+    "apprenticed" => 24,
+    "apprise" => 24,
+    "apprised" => 29,
+    "approach" => 49,
+    "approachable" => 24,
+    "approached" => 41,
+    "approaches" => 42,
+    "approaching" => 37,
+    "approbation" => 27,
+    "appropriate" => 51,
+    "appropriated" => 24,
+    "appropriately" => 41,
+    "appropriateness" => 27,
+    "appropriation" => 24,
+    "appropriations" => 24,
+    "approval" => 40,
+    "approvals" => 24,
+    "approve" => 35,
+    "approved" => 39,
+    "approves" => 24,
+    "approving" => 36,
+    "approvingly" => 24,
+    "approximant" => 30,
+    "approximate" => 43,
+    "approximated" => 31,
+    "approximately" => 42,
+    "approximates" => 27,
+    "approximating" => 24,
+    "approximation" => 41,
+    "approximations" => 31,
+    "apr" => 42,
+    "april" => 37,
+    "apron" => 27,
+    "apt" => 34,
+    "aptly" => 24,
+    "aptness" => 24,
+    "aqua" => 24,
+    "aquamarine" => 33,
+    "aquaplanes" => 24,
+    "aquiline" => 27,
+    "aquinas" => 24,
+    "arab" => 29,
+    "arabian" => 35,
+    "arabic" => 47,
+    "arabs" => 24,
+    "aran" => 24,
+    "arbiter" => 24,
+    "arbiters" => 24,
+    "arbitrarily" => 40,
+    "arbitrary" => 51,
+    "arc" => 43,
+    "arcane" => 29,
+    "arch" => 43,
+    "archaeological" => 29,
+    "archaic" => 30,
+    "archangel" => 31,
+    "archbishop" => 27,
+    "arched" => 24,
+    "archery" => 29,
+    "arches" => 32,
+    "archetype" => 27,
+    "archie" => 30,
+    "archimedes" => 24,
+    "architect" => 24,
+    "architects" => 27,
+    "architectural" => 33,
+    "architecturally" => 30,
+    "architecture" => 50,
+    "architectures" => 46,
+    "archival" => 40,
+    "archive" => 46,
+    "archived" => 34,
+    "archives" => 41,
+    "archiving" => 31,
+    "archivist" => 24,
+    "archivists" => 27,
+    "archway" => 24,
+    "arcs" => 33,
+    "arcsine" => 30,
+    "arctangent" => 32,
+    "arctic" => 31,
+    "ardent" => 34,
+    "ardently" => 34,
+    "ardour" => 35,
+    "arduous" => 29,
+    "are" => 68,
+    "area" => 49,
+    "areas" => 42,
+    "aren" => 24,
+    "aren't" => 48,
+    "arena" => 41,
+    "arenas" => 33,
+    "ares" => 24,
+    "argh" => 30,
+    "argon" => 37,
+    "arguably" => 35,
+    "argue" => 35,
+    "argued" => 40,
+    "argues" => 27,
+    "arguing" => 27,
+    "argument" => 59,
+    "argumentation" => 24,
+    "arguments" => 56,
+    "ariadne" => 30,
+    "aright" => 27,
+    "arise" => 39,
+    "arisen" => 24,
+    "arises" => 40,
+    "arising" => 38,
+    "aristocracy" => 24,
+    "aristocratic" => 29,
+    "aristotle" => 24,
+    "arithmetic" => 48,
+    "arizona" => 27,
+    "ark" => 34,
+    "arkansas" => 27,
+    "arks" => 29,
+    "arm" => 53,
+    "armada" => 24,
+    "armchair" => 36,
+    "armchairs" => 24,
+    "armed" => 35,
+    "armenian" => 29,
+    "armenians" => 24,
+    "armies" => 29,
+    "arming" => 27,
+    "armistice" => 29,
+    "armored" => 24,
+    "armour" => 24,
+    "armoury" => 30,
+    "arms" => 45,
+    "army" => 36,
+    "arnold" => 24,
+    "arose" => 38,
+    "around" => 55,
+    "arouse" => 24,
+    "aroused" => 32,
+    "arrange" => 36,
+    "arranged" => 38,
+    "arrangement" => 35,
+    "arrangements" => 32,
+    "arranges" => 24,
+    "arranging" => 33,
+    "array" => 55,
+    "arrayed" => 27,
+    "arrays" => 49,
+    "arrest" => 38,
+    "arrested" => 37,
+    "arresting" => 27,
+    "arrival" => 39,
+    "arrivals" => 29,
+    "arrive" => 42,
+    "arrived" => 44,
+    "arrives" => 36,
+    "arriving" => 35,
+    "arrogant" => 27,
+    "arrow" => 45,
+    "arrows" => 32,
+    "arsenal" => 29,
+    "arsenals" => 24,
+    "art" => 42,
+    "artefacts" => 27,
+    "artem" => 29,
+    "arteries" => 27,
+    "artery" => 27,
+    "artfully" => 24,
+    "arthur" => 38,
+    "article" => 43,
+    "articles" => 38,
+    "articulate" => 30,
+    "articulated" => 27,
+    "articulation" => 24,
+    "articulatory" => 24,
+    "artifact" => 43,
+    "artifacts" => 47,
+    "artifice" => 24,
+    "artificial" => 38,
+    "artificially" => 30,
+    "artillery" => 27,
+    "artisans" => 24,
+    "artist" => 32,
+    "artistic" => 31,
+    "artists" => 32,
+    "artlessly" => 24,
+    "arts" => 35,
+    "as" => 68,
+    "asa" => 29,
+    "ascend" => 32,
+    "ascended" => 34,
+    "ascender" => 24,
+    "ascending" => 39,
+    "ascends" => 24,
+    "ascent" => 33,
+    "ascertain" => 34,
+    "ascertained" => 32,
+    "ascertaining" => 30,
+    "ascertains" => 24,
+    "ascii" => 52,
+    "ascribe" => 29,
+    "ascribed" => 31,
+    "ascription" => 32,
+    "ash" => 37,
+    "ashamed" => 35,
+    "ashen" => 30,
+    "ashes" => 36,
+    "ashley" => 27,
+    "ashore" => 33,
+    "asia" => 33,
+    "asian" => 36,
+    "aside" => 41,
+    "asinine" => 24,
+    "ask" => 48,
+    "askance" => 27,
+    "asked" => 49,
+    "asking" => 42,
+    "asks" => 39,
+    "asleep" => 41,
+    "asp" => 36,
+    "aspect" => 43,
+    "aspects" => 42,
+    "aspirated" => 24,
+    "aspirations" => 24,
+    "aspire" => 24,
+    "aspired" => 29,
+    "aspires" => 24,
+    "ass" => 31,
+    "assailants" => 24,
+    "assailed" => 24,
+    "assassin" => 27,
+    "assassinated" => 27,
+    "assassination" => 27,
+    "assassins" => 24,
+    "assault" => 31,
+    "assaulted" => 24,
+    "assemblage" => 29,
+    "assemblages" => 24,
+    "assemble" => 36,
+    "assembled" => 38,
+    "assembler" => 41,
+    "assemblers" => 24,
+    "assemblies" => 24,
+    "assembling" => 33,
+    "assembly" => 49,
+    "assent" => 24,
+    "assented" => 27,
+    "assert" => 48,
+    "asserted" => 35,
+    "asserting" => 38,
+    "assertion" => 45,
+    "assertions" => 45,
+    "asserts" => 44,
+    "assess" => 32,
+    "assessed" => 36,
+    "assesses" => 24,
+    "assessing" => 27,
+    "assessments" => 24,
+    "asset" => 43,
+    "assets" => 43,
+    "assiduously" => 24,
+    "assign" => 49,
+    "assignable" => 27,
+    "assigned" => 47,
+    "assignee" => 37,
+    "assigning" => 44,
+    "assignment" => 49,
+    "assignments" => 42,
+    "assigns" => 39,
+    "assimilated" => 24,
+    "assist" => 42,
+    "assistance" => 38,
+    "assistant" => 37,
+    "assistants" => 33,
+    "assisted" => 33,
+    "assisting" => 30,
+    "assists" => 24,
+    "assizes" => 33,
+    "associable" => 27,
+    "associate" => 41,
+    "associated" => 55,
+    "associates" => 34,
+    "associating" => 35,
+    "association" => 39,
+    "associations" => 33,
+    "associative" => 38,
+    "assuage" => 24,
+    "assume" => 48,
+    "assumed" => 45,
+    "assumes" => 46,
+    "assuming" => 46,
+    "assumption" => 41,
+    "assumptions" => 43,
+    "assurance" => 33,
+    "assurances" => 24,
+    "assure" => 37,
+    "assured" => 39,
+    "assuredly" => 31,
+    "assures" => 30,
+    "assuring" => 27,
+    "assyrian" => 24,
+    "ast" => 46,
+    "asterisk" => 36,
+    "asterisks" => 31,
+    "asteroids" => 24,
+    "astir" => 24,
+    "astonish" => 27,
+    "astonished" => 36,
+    "astonishing" => 35,
+    "astonishment" => 39,
+    "astoria" => 27,
+    "astounded" => 29,
+    "astounding" => 31,
+    "astounds" => 27,
+    "astrakhan" => 24,
+    "astral" => 24,
+    "astride" => 27,
+    "astrologers" => 24,
+    "astronaut" => 24,
+    "astronomer" => 27,
+    "astronomers" => 24,
+    "astronomical" => 30,
+    "astronomy" => 33,
+    "astute" => 24,
+    "astuteness" => 24,
+    "asunder" => 24,
+    "asylum" => 31,
+    "asymmetric" => 38,
+    "asymmetrical" => 24,
+    "asymptotic" => 24,
+    "asymptotically" => 30,
+    "asynchronous" => 51,
+    "asynchronously" => 44,
+    "at" => 65,
+    "ate" => 36,
+    "athena" => 44,
+    "athens" => 24,
+    "athlete" => 24,
+    "athwart" => 24,
+    "atkinson" => 27,
+    "atlantic" => 37,
+    "atlas" => 35,
+    "atlases" => 32,
+    "atmosphere" => 34,
+    "atom" => 43,
+    "atomic" => 50,
+    "atomically" => 42,
+    "atomics" => 42,
+    "atoms" => 37,
+    "atone" => 27,
+    "atop" => 39,
+    "atrium" => 24,
+    "atrocious" => 30,
+    "attach" => 42,
+    "attached" => 45,
+    "attaches" => 34,
+    "attaching" => 37,
+    "attachment" => 34,
+    "attachments" => 24,
+    "attack" => 40,
+    "attacked" => 34,
+    "attacker" => 39,
+    "attackers" => 29,
+    "attacks" => 44,
+    "attain" => 34,
+    "attainder" => 29,
+    "attained" => 32,
+    "attaining" => 24,
+    "attainment" => 24,
+    "attainments" => 24,
+    "attainted" => 24,
+    "attempt" => 51,
+    "attempted" => 45,
+    "attempting" => 46,
+    "attempts" => 48,
+    "attend" => 36,
+    "attendance" => 30,
+    "attendant" => 32,
+    "attendants" => 27,
+    "attended" => 35,
+    "attendees" => 32,
+    "attending" => 33,
+    "attends" => 27,
+    "attention" => 46,
+    "attentions" => 33,
+    "attentive" => 29,
+    "attentively" => 33,
+    "attenuated" => 24,
+    "attest" => 24,
+    "attested" => 24,
+    "attests" => 24,
+    "attic" => 31,
+    "attics" => 24,
+    "attired" => 30,
+    "attitude" => 32,
+    "attorney" => 27,
+    "attract" => 30,
+    "attracted" => 37,
+    "attracting" => 24,
+    "attraction" => 30,
+    "attractions" => 29,
+    "attractive" => 32,
+    "attractively" => 24,
+    "attractiveness" => 24,
+    "attracts" => 24,
+    "attributable" => 24,
+    "attribute" => 56,
+    "attributed" => 36,
+    "attributes" => 56,
+    "attribution" => 38,
+    "attributions" => 35,
+    "attrition" => 24,
+    "auburn" => 24,
+    "auckland" => 24,
+    "audacious" => 24,
+    "audacity" => 29,
+    "audible" => 34,
+    "audibly" => 30,
+    "audience" => 39,
+    "audiences" => 29,
+    "audio" => 46,
+    "audit" => 42,
+    "audited" => 39,
+    "auditing" => 36,
+    "auditor" => 30,
+    "auditory" => 24,
+    "audits" => 36,
+    "audrey" => 24,
+    "aught" => 27,
+    "augment" => 32,
+    "augmented" => 38,
+    "augmenting" => 31,
+    "augments" => 27,
+    "auguries" => 24,
+    "augury" => 24,
+    "august" => 37,
+    "aunt" => 44,
+    "auntie" => 36,
+    "aunts" => 24,
+    "aunty" => 24,
+    "aurora" => 24,
+    "auspices" => 27,
+    "auspicious" => 24,
+    "austere" => 24,
+    "austin" => 32,
+    "australasia" => 24,
+    "australia" => 36,
+    "australian" => 30,
+    "australians" => 24,
+    "austria" => 27,
+    "austrian" => 27,
+    "authentic" => 35,
+    "authentically" => 24,
+    "authenticate" => 37,
+    "authenticated" => 40,
+    "authenticates" => 27,
+    "authenticating" => 33,
+    "authentication" => 48,
+    "authenticator" => 29,
+    "authenticity" => 32,
+    "author" => 47,
+    "authored" => 36,
+    "authoring" => 32,
+    "authoritative" => 32,
+    "authoritatively" => 24,
+    "authorities" => 34,
+    "authority" => 43,
+    "authorization" => 40,
+    "authorize" => 30,
+    "authorized" => 36,
+    "authorizer" => 24,
+    "authors" => 47,
+    "authorship" => 42,
+    "auto" => 52,
+    "autobahn" => 32,
+    "automata" => 35,
+    "automate" => 37,
+    "automated" => 43,
+    "automates" => 31,
+    "automatic" => 49,
+    "automatically" => 55,
+    "automatics" => 24,
+    "automating" => 29,
+    "automation" => 39,
+    "automaton" => 36,
+    "automobile" => 27,
+    "automobiles" => 31,
+    "automotive" => 27,
+    "autonomy" => 24,
+    "autosuggestion" => 24,
+    "autumn" => 35,
+    "autumnal" => 27,
+    "auxiliary" => 39,
+    "avail" => 34,
+    "availability" => 41,
+    "available" => 59,
+    "availed" => 27,
+    "avalanche" => 34,
+    "avalanching" => 24,
+    "avant" => 24,
+    "avarice" => 24,
+    "avaricious" => 24,
+    "avariciously" => 24,
+    "ave" => 29,
+    "avenge" => 27,
+    "avenged" => 24,
+    "avenger" => 31,
+    "avenue" => 37,
+    "avenues" => 34,
+    "average" => 45,
+    "averages" => 30,
+    "averaging" => 24,
+    "averred" => 24,
+    "averse" => 32,
+    "aversion" => 31,
+    "avert" => 32,
+    "averted" => 29,
+    "avery" => 24,
+    "avian" => 24,
+    "aviation" => 24,
+    "avidity" => 24,
+    "avocations" => 24,
+    "avoid" => 56,
+    "avoidable" => 24,
+    "avoidance" => 27,
+    "avoided" => 42,
+    "avoiding" => 43,
+    "avoids" => 47,
+    "avow" => 24,
+    "avowal" => 24,
+    "avowed" => 27,
+    "avowedly" => 24,
+    "await" => 46,
+    "awaited" => 38,
+    "awaiting" => 39,
+    "awaits" => 34,
+    "awake" => 36,
+    "awaken" => 30,
+    "awakened" => 35,
+    "awakening" => 29,
+    "awaking" => 24,
+    "award" => 32,
+    "awarded" => 27,
+    "aware" => 48,
+    "awareness" => 33,
+    "away" => 52,
+    "awe" => 31,
+    "awed" => 32,
+    "awesome" => 40,
+    "awful" => 42,
+    "awfully" => 29,
+    "awfulness" => 24,
+    "awhile" => 36,
+    "awkward" => 38,
+    "awkwardly" => 29,
+    "awnings" => 24,
+    "awoke" => 38,
+    "awoken" => 32,
+    "awry" => 24,
+    "axe" => 24,
+    "axes" => 34,
+    "axial" => 24,
+    "axiom" => 27,
+    "axiomatic" => 24,
+    "axioms" => 27,
+    "axis" => 38,
+    "aye" => 32,
+    "azerbaijani" => 24,
+    "aztec" => 32,
+    "azure" => 38,
+    "babbled" => 24,
+    "babe" => 24,
+    "babel" => 24,
+    "babes" => 24,
+    "babies" => 24,
+    "baboon" => 30,
+    "baby" => 38,
+    "bachelor" => 33,
+    "bachelors" => 24,
+    "back" => 56,
+    "backbone" => 32,
+    "backbones" => 24,
+    "backdown" => 24,
+    "backed" => 45,
+    "backend" => 52,
+    "backer" => 24,
+    "backers" => 27,
+    "backgammon" => 24,
+    "background" => 49,
+    "backgrounds" => 30,
+    "backing" => 41,
+    "backlog" => 27,
+    "backlogs" => 27,
+    "backs" => 33,
+    "backslash" => 49,
+    "backslashes" => 45,
+    "backspace" => 44,
+    "backspaced" => 24,
+    "backspaces" => 27,
+    "backspacing" => 38,
+    "backstop" => 24,
+    "backtrack" => 31,
+    "backtracking" => 42,
+    "backtracks" => 27,
+    "backup" => 45,
+    "backups" => 32,
+    "backward" => 46,
+    "backwards" => 47,
+    "backwater" => 27,
+    "backyard" => 24,
+    "bacon" => 33,
+    "bad" => 48,
+    "bade" => 33,
+    "badge" => 53,
+    "badges" => 35,
+    "badly" => 39,
+    "badness" => 24,
+    "baffled" => 33,
+    "bag" => 41,
+    "baggage" => 32,
+    "bagged" => 24,
+    "bagging" => 27,
+    "baggy" => 27,
+    "bags" => 30,
+    "bah" => 29,
+    "bail" => 43,
+    "bailey" => 24,
+    "bailiff" => 32,
+    "bailing" => 29,
+    "bails" => 30,
+    "baird" => 29,
+    "bait" => 27,
+    "baits" => 24,
+    "bake" => 31,
+    "baked" => 35,
+    "baker" => 43,
+    "baker's" => 27,
+    "bakers" => 27,
+    "bakes" => 30,
+    "baking" => 29,
+    "balance" => 41,
+    "balanced" => 35,
+    "balancer" => 27,
+    "balancers" => 27,
+    "balances" => 31,
+    "balancing" => 37,
+    "balcony" => 24,
+    "bald" => 30,
+    "baldwin" => 24,
+    "baleful" => 24,
+    "balefully" => 24,
+    "balked" => 24,
+    "ball" => 38,
+    "ballast" => 24,
+    "ballista" => 24,
+    "balloon" => 44,
+    "ballooned" => 24,
+    "balloons" => 31,
+    "ballot" => 29,
+    "ballots" => 29,
+    "ballpark" => 29,
+    "ballroom" => 27,
+    "balls" => 32,
+    "balm" => 24,
+    "balminess" => 24,
+    "balmoral" => 30,
+    "balmy" => 27,
+    "baltic" => 32,
+    "baltimore" => 29,
+    "balzac" => 24,
+    "bam" => 29,
+    "bamboo" => 31,
+    "bamboos" => 24,
+    "ban" => 27,
+    "bananas" => 24,
+    "band" => 39,
+    "bandage" => 30,
+    "bandaged" => 24,
+    "bandages" => 27,
+    "bandanna" => 24,
+    "banded" => 24,
+    "bandied" => 24,
+    "bands" => 32,
+    "bandwidth" => 38,
+    "bandy" => 24,
+    "bang" => 38,
+    "banged" => 27,
+    "banging" => 27,
+    "bangs" => 24,
+    "banish" => 29,
+    "banished" => 31,
+    "banishment" => 24,
+    "banisters" => 24,
+    "banjo" => 24,
+    "bank" => 43,
+    "banker" => 35,
+    "bankers" => 30,
+    "banking" => 30,
+    "banknotes" => 34,
+    "bankruptcies" => 24,
+    "bankruptcy" => 24,
+    "bankrupted" => 24,
+    "banks" => 37,
+    "banned" => 32,
+    "banner" => 35,
+    "banners" => 31,
+    "banns" => 24,
+    "banquet" => 24,
+    "bantering" => 27,
+    "bar" => 48,
+    "barbara" => 30,
+    "barbaric" => 24,
+    "barbarity" => 27,
+    "barbarous" => 30,
+    "barbarously" => 24,
+    "barbed" => 24,
+    "barber" => 24,
+    "barber's" => 24,
+    "bare" => 48,
+    "bared" => 29,
+    "barefoot" => 24,
+    "barelegged" => 24,
+    "barely" => 33,
+    "bargain" => 33,
+    "bargaining" => 24,
+    "barges" => 24,
+    "bark" => 35,
+    "barker" => 24,
+    "barking" => 27,
+    "barks" => 24,
+    "barley" => 30,
+    "barlow" => 30,
+    "barmaid" => 29,
+    "barn" => 27,
+    "barnes" => 24,
+    "barnett" => 27,
+    "barnyard" => 24,
+    "barometer" => 29,
+    "barometric" => 24,
+    "baronet" => 24,
+    "baroque" => 24,
+    "barque" => 29,
+    "barred" => 35,
+    "barrel" => 30,
+    "barrelled" => 27,
+    "barrels" => 27,
+    "barren" => 29,
+    "barricade" => 27,
+    "barricaded" => 27,
+    "barrier" => 40,
+    "barriers" => 35,
+    "barring" => 31,
+    "barrow" => 27,
+    "barrows" => 24,
+    "bars" => 40,
+    "bart" => 29,
+    "bartenders" => 24,
+    "barton" => 24,
+    "baryta" => 24,
+    "bas" => 30,
+    "basalt" => 24,
+    "base" => 53,
+    "baseball" => 24,
+    "based" => 57,
+    "baseline" => 41,
+    "baselines" => 31,
+    "bases" => 40,
+    "basest" => 24,
+    "bash" => 43,
+    "bashful" => 24,
+    "basic" => 54,
+    "basically" => 44,
+    "basics" => 40,
+    "basil" => 24,
+    "basin" => 33,
+    "basing" => 24,
+    "basis" => 44,
+    "basket" => 36,
+    "basketful" => 24,
+    "baskets" => 27,
+    "basque" => 24,
+    "bass" => 29,
+    "bastards" => 24,
+    "bastion" => 27,
+    "bat" => 42,
+    "batch" => 45,
+    "batched" => 35,
+    "batches" => 34,
+    "batching" => 31,
+    "bated" => 24,
+    "bath" => 32,
+    "bathed" => 27,
+    "bathing" => 30,
+    "bathroom" => 27,
+    "bathrooms" => 27,
+    "baths" => 32,
+    "baton" => 24,
+    "bats" => 33,
+    "battalion" => 24,
+    "battered" => 29,
+    "batteries" => 35,
+    "battery" => 33,
+    "battle" => 38,
+    "battledores" => 24,
+    "battlefield" => 24,
+    "battles" => 24,
+    "battleship" => 24,
+    "bauble" => 24,
+    "baud" => 32,
+    "bavarian" => 24,
+    "bawled" => 24,
+    "bay" => 36,
+    "baying" => 27,
+    "bazaar" => 30,
+    "bazaars" => 24,
+    "bbc" => 29,
+    "be" => 69,
+    "beach" => 36,
+    "beacon" => 32,
+    "beaded" => 29,
+    "beads" => 30,
+    "beak" => 24,
+    "beam" => 33,
+    "beamed" => 32,
+    "beaming" => 27,
+    "beams" => 27,
+    "bean" => 33,
+    "bear" => 41,
+    "bearable" => 24,
+    "beard" => 33,
+    "bearded" => 29,
+    "bearer" => 31,
+    "bearing" => 38,
+    "bearings" => 27,
+    "bears" => 32,
+    "beast" => 36,
+    "beasts" => 33,
+    "beat" => 39,
+    "beaten" => 34,
+    "beating" => 36,
+    "beats" => 31,
+    "beau" => 33,
+    "beaufort" => 32,
+    "beauteous" => 24,
+    "beauties" => 33,
+    "beautiful" => 44,
+    "beautifully" => 34,
+    "beautify" => 27,
+    "beauty" => 41,
+    "beaux" => 24,
+    "beaver" => 24,
+    "became" => 47,
+    "because" => 59,
+    "beck" => 27,
+    "beckoned" => 24,
+    "beckoning" => 27,
+    "becky" => 44,
+    "become" => 50,
+    "becomes" => 49,
+    "becoming" => 42,
+    "bed" => 44,
+    "bedchamber" => 27,
+    "bedclothes" => 24,
+    "bedded" => 29,
+    "bedewed" => 24,
+    "bedim" => 24,
+    "bedpost" => 27,
+    "bedridden" => 24,
+    "bedrock" => 29,
+    "bedroom" => 39,
+    "bedrooms" => 32,
+    "beds" => 33,
+    "bedside" => 31,
+    "bedsteads" => 24,
+    "bedtime" => 27,
+    "bee" => 27,
+    "beech" => 24,
+    "beeches" => 35,
+    "beef" => 32,
+    "beefy" => 24,
+    "beehive" => 29,
+    "been" => 59,
+    "beep" => 39,
+    "beeped" => 24,
+    "beeping" => 27,
+    "beeps" => 34,
+    "beer" => 32,
+    "bees" => 27,
+    "beet" => 24,
+    "beetle" => 34,
+    "beetles" => 24,
+    "befall" => 29,
+    "befallen" => 30,
+    "befitted" => 24,
+    "befitting" => 30,
+    "before" => 60,
+    "beforehand" => 37,
+    "befriend" => 24,
+    "befriended" => 24,
+    "beg" => 39,
+    "began" => 49,
+    "beget" => 24,
+    "beggar" => 34,
+    "beggars" => 24,
+    "beggary" => 24,
+    "begged" => 36,
+    "begging" => 32,
+    "begin" => 47,
+    "beginner" => 30,
+    "beginners" => 36,
+    "beginning" => 49,
+    "beginnings" => 29,
+    "begins" => 45,
+    "begone" => 32,
+    "begotten" => 24,
+    "begs" => 24,
+    "begun" => 38,
+    "behalf" => 36,
+    "behave" => 46,
+    "behaved" => 35,
+    "behaves" => 45,
+    "behaving" => 32,
+    "behavior" => 56,
+    "behavioral" => 31,
+    "behaviors" => 42,
+    "behaviour" => 46,
+    "behaviours" => 29,
+    "behead" => 24,
+    "beheaded" => 29,
+    "beheading" => 24,
+    "beheld" => 39,
+    "behind" => 51,
+    "behold" => 37,
+    "beholden" => 24,
+    "beholding" => 29,
+    "beige" => 24,
+    "being" => 57,
+    "beings" => 38,
+    "belaboring" => 24,
+    "belated" => 29,
+    "beleaguered" => 27,
+    "belgium" => 24,
+    "belie" => 24,
+    "belief" => 36,
+    "beliefs" => 27,
+    "believe" => 46,
+    "believed" => 41,
+    "believes" => 35,
+    "believing" => 31,
+    "belittled" => 24,
+    "bell" => 42,
+    "belle" => 24,
+    "bellman" => 29,
+    "bellow" => 29,
+    "bellows" => 24,
+    "bells" => 35,
+    "belly" => 24,
+    "belong" => 40,
+    "belonged" => 36,
+    "belonging" => 37,
+    "belongings" => 24,
+    "belongs" => 41,
+    "beloved" => 38,
+    "below" => 55,
+    "belt" => 34,
+    "belted" => 24,
+    "belting" => 24,
+    "beluga" => 24,
+    "ben" => 44,
+    "bench" => 44,
+    "benches" => 39,
+    "benchmark" => 50,
+    "benchmarks" => 52,
+    "bend" => 35,
+    "bender" => 34,
+    "bending" => 35,
+    "beneath" => 40,
+    "benediction" => 30,
+    "benefactor" => 32,
+    "benefactors" => 27,
+    "beneficence" => 24,
+    "beneficial" => 35,
+    "benefit" => 44,
+    "benefited" => 24,
+    "benefiting" => 30,
+    "benefits" => 43,
+    "benevolence" => 32,
+    "benevolent" => 37,
+    "benevolently" => 24,
+    "bengal" => 31,
+    "bengali" => 24,
+    "benignity" => 27,
+    "benjamin" => 36,
+    "benny" => 30,
+    "bent" => 40,
+    "benumbed" => 24,
+    "beowulf" => 24,
+    "bequeath" => 24,
+    "bequeathed" => 29,
+    "bequest" => 24,
+    "bereaved" => 24,
+    "berg" => 24,
+    "bergen" => 24,
+    "berkeley" => 33,
+    "berkshire" => 31,
+    "bermuda" => 24,
+    "bernard" => 27,
+    "bernoulli" => 27,
+    "bernstein" => 27,
+    "berries" => 32,
+    "berry" => 24,
+    "bert" => 33,
+    "berth" => 31,
+    "berths" => 29,
+    "bertrand" => 24,
+    "beryl" => 30,
+    "beseeching" => 24,
+    "besetting" => 24,
+    "beside" => 42,
+    "besides" => 45,
+    "besieged" => 24,
+    "besought" => 24,
+    "bespoke" => 34,
+    "bespoken" => 24,
+    "best" => 52,
+    "bestow" => 35,
+    "bestowed" => 36,
+    "bestowing" => 29,
+    "bestride" => 24,
+    "bet" => 41,
+    "beta" => 43,
+    "betel" => 24,
+    "bethesda" => 27,
+    "bethought" => 24,
+    "betook" => 29,
+    "betray" => 34,
+    "betrayal" => 24,
+    "betrayed" => 38,
+    "betraying" => 33,
+    "betrays" => 27,
+    "betrothal" => 24,
+    "bets" => 29,
+    "better" => 54,
+    "betters" => 27,
+    "betting" => 30,
+    "betty" => 24,
+    "between" => 57,
+    "bevel" => 27,
+    "beverage" => 24,
+    "beverages" => 24,
+    "bevy" => 46,
+    "beware" => 42,
+    "bewildered" => 32,
+    "bewilderment" => 29,
+    "bewitched" => 24,
+    "bewitching" => 24,
+    "beyond" => 48,
+    "bias" => 30,
+    "biased" => 30,
+    "biases" => 24,
+    "biasing" => 24,
+    "biassed" => 24,
+    "bib" => 27,
+    "bible" => 35,
+    "bibles" => 24,
+    "biblical" => 24,
+    "bibliographic" => 37,
+    "bibliographies" => 32,
+    "bibliography" => 33,
+    "bicameral" => 24,
+    "bicep" => 27,
+    "bicycles" => 27,
+    "bicycling" => 24,
+    "bid" => 32,
+    "bidding" => 27,
+    "bide" => 24,
+    "bidirectional" => 37,
+    "bids" => 24,
+    "bier" => 24,
+    "big" => 51,
+    "bigger" => 41,
+    "biggest" => 36,
+    "bight" => 24,
+    "bigness" => 24,
+    "bijou" => 24,
+    "bile" => 24,
+    "bilinear" => 31,
+    "bilingual" => 27,
+    "bill" => 41,
+    "billet" => 27,
+    "billion" => 37,
+    "billions" => 35,
+    "billow" => 24,
+    "billows" => 24,
+    "billowy" => 24,
+    "bills" => 33,
+    "billy" => 27,
+    "bin" => 46,
+    "binaries" => 50,
+    "binary" => 56,
+    "bind" => 45,
+    "binder" => 37,
+    "binders" => 29,
+    "binding" => 50,
+    "bindings" => 54,
+    "binds" => 41,
+    "bine" => 24,
+    "bingo" => 24,
+    "binomial" => 24,
+    "bins" => 37,
+    "biograph" => 24,
+    "biographical" => 24,
+    "biographies" => 24,
+    "biography" => 24,
+    "biological" => 24,
+    "biology" => 29,
+    "biomedical" => 24,
+    "bionic" => 24,
+    "bios" => 29,
+    "bipartite" => 24,
+    "bird" => 41,
+    "birds" => 39,
+    "birmingham" => 24,
+    "birth" => 34,
+    "birthday" => 38,
+    "biscuit" => 29,
+    "biscuits" => 29,
+    "bisect" => 29,
+    "bishops" => 27,
+    "bisulphate" => 24,
+    "bit" => 64,
+    "bitch" => 30,
+    "bite" => 32,
+    "biting" => 24,
+    "bits" => 56,
+    "bitten" => 30,
+    "bitter" => 38,
+    "bitterest" => 31,
+    "bitterly" => 36,
+    "bitterness" => 34,
+    "bitwise" => 50,
+    "bizarre" => 33,
+    "blab" => 24,
+    "black" => 47,
+    "blackbird" => 24,
+    "blackboard" => 27,
+    "blackened" => 24,
+    "blackest" => 27,
+    "blackguard" => 24,
+    "blackguards" => 24,
+    "blacking" => 24,
+    "blacklist" => 27,
+    "blacklisted" => 24,
+    "blacklisting" => 24,
+    "blackmailing" => 24,
+    "blackness" => 29,
+    "blacksmith" => 24,
+    "bladder" => 24,
+    "blade" => 32,
+    "bladed" => 24,
+    "blades" => 24,
+    "blake" => 43,
+    "blamable" => 24,
+    "blame" => 39,
+    "blamed" => 32,
+    "blameless" => 24,
+    "blaming" => 29,
+    "blanc" => 34,
+    "blanchard" => 24,
+    "blanche" => 27,
+    "blanched" => 29,
+    "blanching" => 27,
+    "bland" => 24,
+    "blandly" => 29,
+    "blank" => 46,
+    "blanked" => 24,
+    "blanket" => 40,
+    "blanketed" => 24,
+    "blankets" => 30,
+    "blanking" => 24,
+    "blankly" => 30,
+    "blanks" => 39,
+    "blast" => 35,
+    "blasted" => 34,
+    "blasts" => 29,
+    "blatantly" => 27,
+    "blaze" => 32,
+    "blazed" => 29,
+    "blazers" => 24,
+    "blazing" => 37,
+    "blazingly" => 35,
+    "bleach" => 27,
+    "bleak" => 32,
+    "bleakness" => 24,
+    "bleared" => 24,
+    "bled" => 27,
+    "bleed" => 29,
+    "bleeding" => 38,
+    "bleeds" => 27,
+    "blemishes" => 24,
+    "blend" => 36,
+    "blended" => 27,
+    "blender" => 24,
+    "blending" => 29,
+    "blends" => 33,
+    "bless" => 36,
+    "blessed" => 38,
+    "blessing" => 31,
+    "blessings" => 32,
+    "blew" => 37,
+    "blight" => 27,
+    "blighted" => 29,
+    "blind" => 39,
+    "blinded" => 31,
+    "blindfold" => 27,
+    "blinding" => 34,
+    "blindly" => 33,
+    "blindness" => 27,
+    "blinds" => 32,
+    "blink" => 38,
+    "blinked" => 24,
+    "blinking" => 39,
+    "blinks" => 30,
+    "bliss" => 32,
+    "blissful" => 24,
+    "blister" => 24,
+    "blithely" => 24,
+    "blitz" => 24,
+    "bloat" => 33,
+    "bloated" => 29,
+    "bloating" => 27,
+    "blob" => 49,
+    "blobs" => 40,
+    "bloc" => 29,
+    "block" => 57,
+    "blockaded" => 24,
+    "blocked" => 42,
+    "blocking" => 48,
+    "blocks" => 51,
+    "blond" => 27,
+    "blonde" => 24,
+    "blood" => 42,
+    "blooded" => 27,
+    "bloodiest" => 24,
+    "bloodless" => 29,
+    "bloodshed" => 24,
+    "bloodstains" => 27,
+    "bloody" => 31,
+    "bloom" => 40,
+    "bloomed" => 31,
+    "blooming" => 30,
+    "blooms" => 24,
+    "bloomsbury" => 24,
+    "bloop" => 24,
+    "blossom" => 24,
+    "blossomed" => 24,
+    "blossoms" => 29,
+    "blot" => 31,
+    "blotched" => 24,
+    "blotches" => 27,
+    "blotted" => 29,
+    "blotting" => 27,
+    "blow" => 39,
+    "blowfish" => 40,
+    "blowing" => 33,
+    "blown" => 35,
+    "blows" => 36,
+    "blowup" => 30,
+    "blubber" => 24,
+    "blubbering" => 24,
+    "blue" => 46,
+    "blueberries" => 24,
+    "blueprint" => 24,
+    "bluer" => 24,
+    "blues" => 24,
+    "bluest" => 24,
+    "bluff" => 32,
+    "bluffs" => 27,
+    "blunder" => 30,
+    "blundering" => 27,
+    "blunders" => 24,
+    "blunt" => 33,
+    "blur" => 34,
+    "blurb" => 24,
+    "blurred" => 30,
+    "blurring" => 24,
+    "blurry" => 24,
+    "blurs" => 27,
+    "blurted" => 27,
+    "blush" => 30,
+    "blushed" => 27,
+    "blushing" => 27,
+    "bluster" => 27,
+    "blustered" => 24,
+    "boa" => 37,
+    "boar" => 24,
+    "board" => 45,
+    "boarded" => 24,
+    "boarder" => 29,
+    "boarding" => 29,
+    "boards" => 35,
+    "boast" => 30,
+    "boasted" => 24,
+    "boastful" => 27,
+    "boasting" => 27,
+    "boat" => 42,
+    "boats" => 35,
+    "bob" => 38,
+    "bobbed" => 24,
+    "bode" => 24,
+    "bodes" => 24,
+    "bodies" => 42,
+    "bodily" => 32,
+    "boding" => 24,
+    "body" => 52,
+    "boggling" => 24,
+    "bogs" => 24,
+    "bogus" => 36,
+    "bohemia" => 35,
+    "bohemian" => 30,
+    "boil" => 29,
+    "boiled" => 30,
+    "boiler" => 32,
+    "boilerplate" => 44,
+    "boilers" => 27,
+    "boiling" => 27,
+    "boils" => 33,
+    "boisterous" => 32,
+    "boisterously" => 24,
+    "bold" => 45,
+    "bolder" => 27,
+    "boldly" => 30,
+    "boldness" => 29,
+    "bollard" => 32,
+    "bolstering" => 24,
+    "bolt" => 33,
+    "bolted" => 24,
+    "bolts" => 24,
+    "bomb" => 36,
+    "bombay" => 42,
+    "bombs" => 24,
+    "bona" => 29,
+    "bond" => 33,
+    "bondage" => 24,
+    "bonding" => 24,
+    "bonds" => 34,
+    "bone" => 31,
+    "bones" => 36,
+    "bonnet" => 34,
+    "bonnets" => 24,
+    "bonniest" => 24,
+    "bonny" => 24,
+    "bonsai" => 24,
+    "bonus" => 38,
+    "bonuses" => 24,
+    "booby" => 24,
+    "book" => 51,
+    "bookcases" => 24,
+    "bookkeeper" => 24,
+    "bookkeeping" => 32,
+    "bookmark" => 34,
+    "bookmarks" => 37,
+    "books" => 45,
+    "bookshelf" => 27,
+    "bookworm" => 24,
+    "boolean" => 54,
+    "boom" => 34,
+    "boomed" => 24,
+    "booming" => 27,
+    "boon" => 27,
+    "boost" => 37,
+    "boosted" => 24,
+    "boosting" => 24,
+    "boosts" => 24,
+    "boot" => 39,
+    "booted" => 24,
+    "booting" => 29,
+    "bootlegger" => 29,
+    "bootleggers" => 24,
+    "boots" => 38,
+    "bootstrap" => 38,
+    "bootstrapped" => 24,
+    "bootstrapping" => 35,
+    "bootstraps" => 24,
+    "bordeaux" => 32,
+    "border" => 40,
+    "bordered" => 31,
+    "bordering" => 27,
+    "borderline" => 24,
+    "borders" => 37,
+    "bore" => 39,
+    "bored" => 32,
+    "boredom" => 24,
+    "bores" => 24,
+    "boring" => 35,
+    "boris" => 29,
+    "born" => 38,
+    "borne" => 36,
+    "borough" => 24,
+    "borrow" => 49,
+    "borrowed" => 49,
+    "borrower" => 31,
+    "borrowing" => 44,
+    "borrows" => 45,
+    "bosch" => 24,
+    "bosh" => 24,
+    "bosnian" => 24,
+    "bosom" => 37,
+    "bosoms" => 24,
+    "boss" => 27,
+    "boston" => 29,
+    "boswell" => 24,
+    "botany" => 24,
+    "botch" => 24,
+    "both" => 58,
+    "bother" => 38,
+    "bothered" => 33,
+    "bothering" => 32,
+    "bothersome" => 27,
+    "bottle" => 38,
+    "bottleneck" => 33,
+    "bottlenecks" => 32,
+    "bottles" => 32,
+    "bottom" => 46,
+    "bottommost" => 24,
+    "bottoms" => 24,
+    "bough" => 24,
+    "boughs" => 24,
+    "bought" => 38,
+    "boulevard" => 29,
+    "bounce" => 24,
+    "bounced" => 29,
+    "bouncing" => 29,
+    "bound" => 52,
+    "boundaries" => 44,
+    "boundary" => 49,
+    "bounded" => 44,
+    "bounding" => 37,
+    "boundless" => 32,
+    "bounds" => 52,
+    "bounties" => 29,
+    "bounty" => 30,
+    "bouquet" => 29,
+    "bourne" => 33,
+    "bout" => 36,
+    "boutiques" => 24,
+    "bouts" => 24,
+    "bow" => 37,
+    "bowed" => 37,
+    "bowels" => 24,
+    "bowers" => 24,
+    "bowie" => 29,
+    "bowing" => 31,
+    "bowl" => 29,
+    "bowlder" => 24,
+    "bowler" => 24,
+    "bowls" => 29,
+    "bows" => 24,
+    "box" => 50,
+    "boxed" => 43,
+    "boxer" => 24,
+    "boxes" => 40,
+    "boxing" => 33,
+    "boy" => 47,
+    "boyce" => 24,
+    "boycott" => 27,
+    "boyhood" => 29,
+    "boyish" => 27,
+    "boys" => 46,
+    "brace" => 40,
+    "braced" => 32,
+    "bracelets" => 29,
+    "braces" => 44,
+    "bracing" => 27,
+    "bracket" => 42,
+    "bracketed" => 39,
+    "brackets" => 45,
+    "brad" => 27,
+    "bradford" => 27,
+    "bradshaw" => 30,
+    "brag" => 24,
+    "bragging" => 24,
+    "brahma" => 24,
+    "brahmin" => 29,
+    "braid" => 24,
+    "braided" => 27,
+    "braille" => 27,
+    "brain" => 38,
+    "brains" => 32,
+    "brainstorm" => 27,
+    "brake" => 27,
+    "brakes" => 32,
+    "bramble" => 24,
+    "brambles" => 24,
+    "branch" => 53,
+    "branched" => 33,
+    "branches" => 49,
+    "branching" => 41,
+    "brand" => 38,
+    "branded" => 30,
+    "branding" => 29,
+    "brandon" => 33,
+    "brands" => 24,
+    "brandy" => 35,
+    "brass" => 36,
+    "brassy" => 24,
+    "brave" => 37,
+    "braved" => 30,
+    "bravely" => 30,
+    "bravery" => 24,
+    "braving" => 24,
+    "bravo" => 27,
+    "brawling" => 24,
+    "brawls" => 24,
+    "brawny" => 24,
+    "brazen" => 24,
+    "brazier" => 27,
+    "brazil" => 29,
+    "brazilian" => 24,
+    "breach" => 31,
+    "breaches" => 24,
+    "bread" => 38,
+    "breadcrumb" => 31,
+    "breadcrumbs" => 34,
+    "breadth" => 31,
+    "break" => 52,
+    "breakable" => 29,
+    "breakage" => 38,
+    "breakages" => 31,
+    "breakdown" => 33,
+    "breakers" => 29,
+    "breakfast" => 42,
+    "breakfasted" => 30,
+    "breakfasts" => 24,
+    "breaking" => 51,
+    "breakout" => 29,
+    "breakpoint" => 42,
+    "breakpoints" => 41,
+    "breaks" => 45,
+    "breakthrough" => 27,
+    "breast" => 37,
+    "breasted" => 27,
+    "breath" => 41,
+    "breathe" => 33,
+    "breathed" => 33,
+    "breathing" => 33,
+    "breathings" => 24,
+    "breathless" => 34,
+    "breathlessly" => 31,
+    "breaths" => 27,
+    "bred" => 31,
+    "breed" => 34,
+    "breeding" => 29,
+    "breeze" => 38,
+    "breezes" => 27,
+    "breezy" => 27,
+    "brendan" => 29,
+    "brennan" => 24,
+    "brent" => 31,
+    "brest" => 24,
+    "brethren" => 24,
+    "breton" => 27,
+    "breve" => 35,
+    "breviate" => 33,
+    "brevity" => 35,
+    "brew" => 29,
+    "brewer" => 31,
+    "brews" => 24,
+    "brian" => 36,
+    "briar" => 24,
+    "bribe" => 24,
+    "bribed" => 27,
+    "bribery" => 27,
+    "bribes" => 24,
+    "brick" => 37,
+    "bricks" => 33,
+    "bridal" => 29,
+    "bride" => 33,
+    "bridegroom" => 32,
+    "brides" => 24,
+    "bridesmaid" => 24,
+    "bridge" => 43,
+    "bridged" => 29,
+    "bridges" => 36,
+    "bridging" => 32,
+    "bridle" => 24,
+    "brief" => 41,
+    "briefly" => 40,
+    "brigade" => 24,
+    "brigadier" => 30,
+    "brigadiers" => 24,
+    "brigantine" => 30,
+    "bright" => 44,
+    "brighten" => 27,
+    "brightened" => 31,
+    "brighter" => 30,
+    "brightest" => 30,
+    "brightly" => 32,
+    "brightness" => 33,
+    "brill" => 27,
+    "brilliancy" => 24,
+    "brilliant" => 35,
+    "brilliantly" => 29,
+    "brilliants" => 24,
+    "brim" => 27,
+    "brimful" => 27,
+    "brimmed" => 30,
+    "brimming" => 24,
+    "brims" => 24,
+    "brimstone" => 24,
+    "brindisi" => 33,
+    "bring" => 47,
+    "bringing" => 41,
+    "brings" => 41,
+    "brink" => 29,
+    "brisk" => 34,
+    "briskly" => 32,
+    "bristling" => 30,
+    "bristol" => 32,
+    "britain" => 35,
+    "britannica" => 27,
+    "british" => 39,
+    "brittle" => 37,
+    "brixton" => 32,
+    "broad" => 42,
+    "broadcast" => 43,
+    "broadcaster" => 27,
+    "broadcasters" => 27,
+    "broadcasting" => 34,
+    "broadcasts" => 41,
+    "broadened" => 30,
+    "broadening" => 24,
+    "broader" => 38,
+    "broadest" => 30,
+    "broadly" => 36,
+    "broadside" => 29,
+    "broadsides" => 33,
+    "broadway" => 32,
+    "broccoli" => 27,
+    "brochure" => 27,
+    "broiled" => 24,
+    "broiling" => 24,
+    "broke" => 43,
+    "broken" => 48,
+    "broker" => 33,
+    "brokers" => 29,
+    "bronze" => 27,
+    "brooch" => 24,
+    "brood" => 24,
+    "brooded" => 30,
+    "brooding" => 30,
+    "brook" => 33,
+    "brooks" => 24,
+    "broom" => 24,
+    "brothel" => 24,
+    "brother" => 41,
+    "brotherly" => 24,
+    "brothers" => 34,
+    "brougham" => 30,
+    "brought" => 47,
+    "brow" => 34,
+    "brown" => 42,
+    "browned" => 24,
+    "brownian" => 24,
+    "brownish" => 24,
+    "brownstone" => 24,
+    "brows" => 32,
+    "browse" => 43,
+    "browsed" => 30,
+    "browser" => 49,
+    "browsers" => 44,
+    "browsing" => 43,
+    "bruce" => 31,
+    "bruise" => 29,
+    "bruised" => 27,
+    "bruises" => 24,
+    "bruno" => 29,
+    "brunswick" => 24,
+    "brush" => 37,
+    "brushed" => 33,
+    "brushes" => 24,
+    "brushing" => 27,
+    "brutality" => 29,
+    "brute" => 37,
+    "bryan" => 27,
+    "bubble" => 34,
+    "bubbles" => 27,
+    "bubbling" => 24,
+    "buchanan" => 37,
+    "buck" => 31,
+    "bucket" => 42,
+    "bucketing" => 27,
+    "buckets" => 34,
+    "buckles" => 24,
+    "bucks" => 24,
+    "bud" => 29,
+    "buddhism" => 24,
+    "buddhist" => 29,
+    "budding" => 27,
+    "buddy" => 24,
+    "budge" => 31,
+    "budged" => 24,
+    "budget" => 37,
+    "budgets" => 31,
+    "buds" => 24,
+    "buff" => 24,
+    "buffalo" => 29,
+    "buffaloes" => 32,
+    "buffer" => 60,
+    "buffered" => 43,
+    "buffering" => 40,
+    "buffers" => 51,
+    "buffet" => 24,
+    "buffeted" => 24,
+    "buffs" => 24,
+    "bug" => 51,
+    "bugaboo" => 24,
+    "bugger" => 24,
+    "buggy" => 37,
+    "bugs" => 48,
+    "build" => 61,
+    "builder" => 47,
+    "builders" => 40,
+    "building" => 55,
+    "buildings" => 31,
+    "builds" => 51,
+    "built" => 55,
+    "bulbous" => 24,
+    "bulgarian" => 32,
+    "bulge" => 24,
+    "bulged" => 24,
+    "bulk" => 39,
+    "bulkiness" => 24,
+    "bulky" => 32,
+    "bull" => 34,
+    "bulldog" => 27,
+    "bullet" => 35,
+    "bulletin" => 30,
+    "bulletproof" => 29,
+    "bullets" => 27,
+    "bullion" => 27,
+    "bullseye" => 27,
+    "bully" => 32,
+    "bum" => 24,
+    "bumming" => 24,
+    "bump" => 47,
+    "bumped" => 39,
+    "bumpers" => 24,
+    "bumping" => 39,
+    "bumps" => 40,
+    "bun" => 32,
+    "bunch" => 42,
+    "bundle" => 42,
+    "bundled" => 44,
+    "bundler" => 34,
+    "bundles" => 37,
+    "bundling" => 33,
+    "bungalow" => 30,
+    "bungalows" => 27,
+    "bunks" => 27,
+    "bunsen" => 24,
+    "bunyan" => 31,
+    "buoyancy" => 24,
+    "buoyed" => 24,
+    "burden" => 40,
+    "burdened" => 24,
+    "burdens" => 29,
+    "burdensome" => 29,
+    "bureau" => 36,
+    "bureaus" => 36,
+    "burglar" => 27,
+    "burglars" => 30,
+    "burgled" => 27,
+    "buried" => 36,
+    "burke" => 29,
+    "burlington" => 24,
+    "burly" => 24,
+    "burmese" => 24,
+    "burn" => 44,
+    "burned" => 36,
+    "burner" => 30,
+    "burning" => 38,
+    "burnished" => 24,
+    "burnout" => 24,
+    "burns" => 32,
+    "burnt" => 32,
+    "burp" => 24,
+    "burrow" => 27,
+    "burrowing" => 27,
+    "burst" => 40,
+    "bursting" => 31,
+    "bursts" => 32,
+    "burton" => 30,
+    "bury" => 35,
+    "burying" => 24,
+    "bus" => 42,
+    "busby" => 24,
+    "buses" => 30,
+    "bush" => 30,
+    "bushel" => 24,
+    "bushes" => 36,
+    "bushy" => 30,
+    "busied" => 30,
+    "busier" => 27,
+    "busily" => 31,
+    "business" => 46,
+    "businesslike" => 24,
+    "bust" => 27,
+    "busted" => 29,
+    "bustle" => 27,
+    "bustled" => 27,
+    "bustling" => 27,
+    "busts" => 24,
+    "busy" => 43,
+    "busybody" => 24,
+    "but" => 64,
+    "butcher" => 29,
+    "butchers" => 24,
+    "butler" => 37,
+    "butt" => 30,
+    "butted" => 24,
+    "butter" => 34,
+    "buttercup" => 24,
+    "buttered" => 24,
+    "butterflies" => 27,
+    "butterfly" => 24,
+    "buttery" => 24,
+    "button" => 47,
+    "buttoned" => 32,
+    "buttoning" => 27,
+    "buttons" => 42,
+    "buy" => 39,
+    "buying" => 31,
+    "buys" => 24,
+    "buzz" => 29,
+    "buzzing" => 27,
+    "by" => 67,
+    "bye" => 34,
+    "byelorussian" => 27,
+    "bygone" => 24,
+    "bypass" => 39,
+    "bypassed" => 27,
+    "bypasses" => 34,
+    "bypassing" => 37,
+    "byproduct" => 27,
+    "byron" => 27,
+    "byronic" => 24,
+    "bystander" => 24,
+    "bystanders" => 24,
+    "byte" => 57,
+    "bytecode" => 41,
+    "bytes" => 57,
+    "byzantine" => 24,
+    "cab" => 39,
+    "cabal" => 27,
+    "cabby" => 24,
+    "cabin" => 38,
+    "cabinet" => 27,
+    "cabinets" => 27,
+    "cabins" => 30,
+    "cable" => 27,
+    "cabman" => 32,
+    "cabriolet" => 24,
+    "cabs" => 27,
+    "cache" => 52,
+    "cacheable" => 32,
+    "cached" => 43,
+    "caches" => 42,
+    "caching" => 44,
+    "cackled" => 24,
+    "cactus" => 27,
+    "cad" => 30,
+    "cadaver" => 31,
+    "cadaverous" => 27,
+    "caddy" => 24,
+    "cadence" => 38,
+    "cadet" => 24,
+    "cadets" => 27,
+    "caffeine" => 36,
+    "cage" => 24,
+    "caged" => 24,
+    "cahoots" => 24,
+    "cairo" => 45,
+    "caitiff" => 24,
+    "cake" => 35,
+    "cakes" => 31,
+    "cal" => 30,
+    "calais" => 27,
+    "calamine" => 29,
+    "calamities" => 24,
+    "calamity" => 27,
+    "calculate" => 44,
+    "calculated" => 42,
+    "calculates" => 46,
+    "calculating" => 41,
+    "calculation" => 42,
+    "calculations" => 42,
+    "calculator" => 36,
+    "calculi" => 27,
+    "calcutta" => 39,
+    "calendar" => 40,
+    "calendars" => 34,
+    "calendrical" => 31,
+    "calf" => 24,
+    "calgary" => 27,
+    "calibration" => 29,
+    "calibre" => 29,
+    "california" => 38,
+    "californian" => 27,
+    "call" => 59,
+    "callable" => 38,
+    "callback" => 52,
+    "called" => 57,
+    "caller" => 49,
+    "callers" => 40,
+    "calling" => 55,
+    "callous" => 24,
+    "calls" => 54,
+    "calm" => 40,
+    "calmed" => 31,
+    "calmer" => 32,
+    "calmly" => 36,
+    "calmness" => 32,
+    "calves" => 24,
+    "calvin" => 24,
+    "cam" => 27,
+    "camberwell" => 27,
+    "cambrian" => 27,
+    "cambric" => 24,
+    "cambridge" => 32,
+    "came" => 52,
+    "camel" => 36,
+    "camellia" => 36,
+    "camellias" => 24,
+    "camera" => 37,
+    "cameras" => 30,
+    "camomile" => 24,
+    "camp" => 38,
+    "campaign" => 29,
+    "campaigner" => 24,
+    "campbell" => 41,
+    "campus" => 35,
+    "campuses" => 24,
+    "can" => 67,
+    "can't" => 55,
+    "canada" => 34,
+    "canadian" => 35,
+    "canal" => 29,
+    "canaries" => 33,
+    "canary" => 32,
+    "canberra" => 24,
+    "cancel" => 41,
+    "cancelable" => 27,
+    "canceled" => 36,
+    "canceling" => 27,
+    "cancellation" => 35,
+    "cancelled" => 37,
+    "cancelling" => 38,
+    "cancels" => 36,
+    "cancer" => 29,
+    "candid" => 34,
+    "candidate" => 41,
+    "candidates" => 39,
+    "candle" => 41,
+    "candles" => 34,
+    "candour" => 27,
+    "cane" => 30,
+    "canes" => 24,
+    "canister" => 31,
+    "canisters" => 30,
+    "canna" => 30,
+    "cannes" => 24,
+    "cannibals" => 24,
+    "cannon" => 31,
+    "cannot" => 58,
+    "canon" => 24,
+    "canonical" => 46,
+    "canopied" => 24,
+    "canopy" => 27,
+    "cans" => 33,
+    "canst" => 24,
+    "canterbury" => 30,
+    "canton" => 24,
+    "canvas" => 37,
+    "canvassed" => 30,
+    "cap" => 40,
+    "capabilities" => 46,
+    "capability" => 43,
+    "capable" => 44,
+    "capacious" => 24,
+    "capacities" => 34,
+    "capacity" => 51,
+    "caparisoned" => 24,
+    "cape" => 27,
+    "capering" => 29,
+    "capes" => 24,
+    "capital" => 49,
+    "capitalists" => 24,
+    "capitalization" => 34,
+    "capitalize" => 36,
+    "capitalized" => 34,
+    "capitalizes" => 29,
+    "capitalizing" => 24,
+    "capitals" => 27,
+    "capitation" => 24,
+    "capitulated" => 24,
+    "capped" => 33,
+    "capping" => 27,
+    "caprice" => 27,
+    "caprices" => 24,
+    "capricious" => 30,
+    "capriciously" => 24,
+    "caps" => 37,
+    "capstone" => 33,
+    "capsules" => 24,
+    "captain" => 41,
+    "caption" => 24,
+    "captive" => 29,
+    "captives" => 24,
+    "captivity" => 30,
+    "captors" => 24,
+    "capture" => 50,
+    "captured" => 47,
+    "captures" => 44,
+    "capturing" => 44,
+    "car" => 45,
+    "caramel" => 24,
+    "caravansary" => 24,
+    "carbon" => 33,
+    "carbonized" => 24,
+    "carbuncle" => 32,
+    "card" => 40,
+    "cardboard" => 31,
+    "cardiff" => 34,
+    "cardinal" => 24,
+    "cardinality" => 30,
+    "cards" => 38,
+    "care" => 50,
+    "cared" => 35,
+    "career" => 35,
+    "careers" => 27,
+    "careful" => 46,
+    "carefully" => 44,
+    "careless" => 34,
+    "carelessly" => 33,
+    "carelessness" => 30,
+    "cares" => 38,
+    "caress" => 24,
+    "caressed" => 24,
+    "caresses" => 27,
+    "caressing" => 29,
+    "caret" => 37,
+    "caretaker" => 24,
+    "cargo" => 60,
+    "cargos" => 27,
+    "caribbean" => 24,
+    "caricature" => 24,
+    "caring" => 27,
+    "carl" => 37,
+    "carnage" => 24,
+    "carnegie" => 24,
+    "carnival" => 29,
+    "carol" => 31,
+    "carolina" => 29,
+    "carolinas" => 24,
+    "caroline" => 30,
+    "carousing" => 24,
+    "carpenter" => 27,
+    "carpet" => 36,
+    "carpeted" => 27,
+    "carpeting" => 24,
+    "carpets" => 24,
+    "carriage" => 43,
+    "carriages" => 33,
+    "carried" => 44,
+    "carrier" => 32,
+    "carriers" => 24,
+    "carries" => 38,
+    "carroll" => 24,
+    "carry" => 47,
+    "carrying" => 40,
+    "cars" => 39,
+    "carson" => 24,
+    "cart" => 35,
+    "carte" => 27,
+    "carter" => 27,
+    "cartesian" => 29,
+    "carthage" => 24,
+    "carton" => 24,
+    "cartoons" => 24,
+    "carts" => 24,
+    "cartwheels" => 24,
+    "carve" => 27,
+    "carved" => 27,
+    "carver" => 31,
+    "carving" => 27,
+    "cas" => 41,
+    "cascade" => 32,
+    "cascades" => 24,
+    "cascading" => 29,
+    "case" => 58,
+    "cased" => 36,
+    "casement" => 24,
+    "cases" => 55,
+    "cash" => 29,
+    "cashier" => 29,
+    "cashmere" => 24,
+    "casing" => 33,
+    "casino" => 27,
+    "casket" => 24,
+    "cassowary" => 34,
+    "cast" => 49,
+    "castaway" => 33,
+    "casted" => 24,
+    "castile" => 24,
+    "castilian" => 27,
+    "casting" => 45,
+    "castle" => 31,
+    "castles" => 30,
+    "casts" => 46,
+    "casual" => 35,
+    "casually" => 34,
+    "casualness" => 24,
+    "casualties" => 24,
+    "cat" => 44,
+    "cat's" => 27,
+    "catalan" => 32,
+    "catalog" => 37,
+    "catalogue" => 36,
+    "catalogued" => 24,
+    "catalogues" => 31,
+    "cataloguing" => 34,
+    "catalyst" => 33,
+    "catalytic" => 29,
+    "catalyze" => 24,
+    "catalyzed" => 27,
+    "catapult" => 24,
+    "cataract" => 24,
+    "cataracts" => 24,
+    "catastrophe" => 33,
+    "catastrophic" => 32,
+    "catbird" => 24,
+    "catch" => 49,
+    "catcher" => 29,
+    "catches" => 37,
+    "catching" => 41,
+    "catchwords" => 24,
+    "catechism" => 24,
+    "categorical" => 24,
+    "categorically" => 24,
+    "categories" => 43,
+    "categorization" => 24,
+    "categorized" => 32,
+    "categorizes" => 27,
+    "categorizing" => 24,
+    "category" => 42,
+    "cater" => 24,
+    "caterers" => 24,
+    "catering" => 27,
+    "caterpillar" => 38,
+    "caters" => 27,
+    "caterwauling" => 27,
+    "catfish" => 24,
+    "cathedral" => 29,
+    "catherine" => 36,
+    "catholic" => 29,
+    "catlike" => 27,
+    "cats" => 38,
+    "cattle" => 29,
+    "caucus" => 29,
+    "caught" => 46,
+    "cauldron" => 27,
+    "causal" => 27,
+    "causality" => 32,
+    "causation" => 24,
+    "cause" => 54,
+    "caused" => 48,
+    "causes" => 51,
+    "causing" => 47,
+    "caution" => 40,
+    "cautioned" => 29,
+    "cautions" => 27,
+    "cautious" => 33,
+    "cautiously" => 36,
+    "cavalier" => 24,
+    "cave" => 40,
+    "caveat" => 41,
+    "caveats" => 42,
+    "caved" => 27,
+    "cavern" => 32,
+    "caves" => 31,
+    "caw" => 24,
+    "caxton" => 24,
+    "cbe" => 29,
+    "cdt" => 24,
+    "cease" => 35,
+    "ceased" => 40,
+    "ceaseless" => 29,
+    "ceaselessly" => 27,
+    "ceases" => 29,
+    "ceasing" => 29,
+    "cecil" => 27,
+    "cedar" => 39,
+    "cedars" => 30,
+    "cedilla" => 37,
+    "cedit" => 32,
+    "ceiling" => 37,
+    "celebrate" => 24,
+    "celebrated" => 35,
+    "celebrates" => 24,
+    "celebrating" => 24,
+    "celebration" => 24,
+    "celebrities" => 24,
+    "celebrity" => 24,
+    "celerity" => 24,
+    "celestial" => 34,
+    "cell" => 47,
+    "cellar" => 37,
+    "cellars" => 24,
+    "cells" => 41,
+    "cellular" => 31,
+    "celsius" => 27,
+    "celtic" => 24,
+    "cement" => 27,
+    "cemetery" => 30,
+    "censor" => 24,
+    "censorship" => 24,
+    "census" => 29,
+    "cent" => 32,
+    "center" => 42,
+    "centered" => 33,
+    "centering" => 27,
+    "centerpiece" => 24,
+    "centers" => 29,
+    "central" => 41,
+    "centralised" => 27,
+    "centralization" => 24,
+    "centralize" => 31,
+    "centralized" => 37,
+    "centralizing" => 24,
+    "centre" => 40,
+    "centred" => 30,
+    "centres" => 24,
+    "centric" => 33,
+    "centrifugal" => 24,
+    "centroid" => 27,
+    "cents" => 32,
+    "centuries" => 35,
+    "century" => 41,
+    "cereals" => 24,
+    "ceremonies" => 29,
+    "ceremoniously" => 24,
+    "ceremony" => 36,
+    "cert" => 33,
+    "certain" => 52,
+    "certainly" => 46,
+    "certainty" => 35,
+    "certificate" => 48,
+    "certificates" => 44,
+    "certification" => 32,
+    "certified" => 29,
+    "certify" => 27,
+    "cession" => 24,
+    "cetera" => 30,
+    "ceylon" => 24,
+    "ceylonese" => 24,
+    "chad" => 24,
+    "chafed" => 24,
+    "chaff" => 27,
+    "chaffed" => 24,
+    "chaffing" => 24,
+    "chagrin" => 31,
+    "chagrined" => 24,
+    "chain" => 47,
+    "chained" => 40,
+    "chaining" => 42,
+    "chains" => 43,
+    "chair" => 44,
+    "chairman" => 29,
+    "chairs" => 32,
+    "chaise" => 27,
+    "chalk" => 32,
+    "challenge" => 40,
+    "challenged" => 24,
+    "challenges" => 38,
+    "challenging" => 35,
+    "challengingly" => 24,
+    "chamber" => 38,
+    "chamberlain" => 24,
+    "chambermaids" => 24,
+    "chambers" => 31,
+    "chamois" => 29,
+    "champagne" => 32,
+    "champion" => 27,
+    "champions" => 24,
+    "champs" => 24,
+    "chance" => 46,
+    "chanced" => 29,
+    "chancery" => 29,
+    "chances" => 37,
+    "chandelier" => 24,
+    "change" => 59,
+    "changeable" => 27,
+    "changed" => 55,
+    "changelog" => 49,
+    "changes" => 57,
+    "changing" => 51,
+    "channel" => 56,
+    "channels" => 47,
+    "chaos" => 34,
+    "chap" => 33,
+    "chaps" => 24,
+    "chapter" => 54,
+    "chapters" => 41,
+    "char" => 52,
+    "character" => 58,
+    "characterise" => 24,
+    "characterised" => 27,
+    "characterises" => 24,
+    "characteristic" => 36,
+    "characteristically" => 24,
+    "characteristics" => 42,
+    "characterization" => 27,
+    "characterize" => 29,
+    "characterized" => 31,
+    "characterizing" => 24,
+    "characters" => 57,
+    "charcoal" => 27,
+    "charge" => 41,
+    "charged" => 35,
+    "charges" => 33,
+    "charging" => 24,
+    "charily" => 24,
+    "charities" => 24,
+    "charity" => 30,
+    "charles" => 42,
+    "charlie" => 29,
+    "charm" => 35,
+    "charmed" => 31,
+    "charming" => 36,
+    "charms" => 31,
+    "charnel" => 27,
+    "charred" => 24,
+    "chars" => 44,
+    "chart" => 37,
+    "charter" => 29,
+    "chartered" => 24,
+    "chartreuse" => 24,
+    "charts" => 36,
+    "chary" => 24,
+    "chase" => 39,
+    "chased" => 30,
+    "chasing" => 33,
+    "chasm" => 24,
+    "chasms" => 29,
+    "chastened" => 24,
+    "chastise" => 24,
+    "chat" => 51,
+    "chatted" => 31,
+    "chatter" => 32,
+    "chattered" => 27,
+    "chattering" => 27,
+    "chatting" => 32,
+    "chaucer" => 24,
+    "chauffeur" => 33,
+    "chauffeurs" => 24,
+    "cheap" => 41,
+    "cheapened" => 24,
+    "cheaper" => 35,
+    "cheapest" => 27,
+    "cheaply" => 35,
+    "cheat" => 35,
+    "cheated" => 27,
+    "cheating" => 29,
+    "cheats" => 27,
+    "check" => 59,
+    "checked" => 50,
+    "checker" => 44,
+    "checkered" => 24,
+    "checkers" => 36,
+    "checking" => 52,
+    "checklist" => 33,
+    "checkmate" => 24,
+    "checkout" => 41,
+    "checkouts" => 32,
+    "checkpointing" => 29,
+    "checkpoints" => 29,
+    "checks" => 53,
+    "checksum" => 43,
+    "checksummed" => 24,
+    "checksumming" => 29,
+    "checksums" => 38,
+    "cheek" => 33,
+    "cheekbone" => 24,
+    "cheekbones" => 24,
+    "cheeked" => 24,
+    "cheeks" => 38,
+    "cheer" => 32,
+    "cheered" => 32,
+    "cheerful" => 37,
+    "cheerfully" => 31,
+    "cheerfulness" => 32,
+    "cheerily" => 30,
+    "cheeriness" => 24,
+    "cheering" => 29,
+    "cheerless" => 27,
+    "cheers" => 24,
+    "cheery" => 27,
+    "cheese" => 33,
+    "cheetah" => 32,
+    "chef" => 27,
+    "chefs" => 27,
+    "chemical" => 39,
+    "chemically" => 24,
+    "chemist" => 24,
+    "chemistry" => 37,
+    "chemists" => 29,
+    "cheque" => 29,
+    "cheques" => 24,
+    "cherish" => 27,
+    "cherished" => 31,
+    "cherry" => 36,
+    "cherub" => 24,
+    "cheshire" => 33,
+    "chess" => 34,
+    "chest" => 33,
+    "chester" => 30,
+    "chesterfield" => 24,
+    "chestnut" => 27,
+    "chests" => 27,
+    "chew" => 29,
+    "chewed" => 27,
+    "chewing" => 30,
+    "chews" => 24,
+    "chi" => 34,
+    "chic" => 24,
+    "chicago" => 39,
+    "chickadee" => 24,
+    "chicken" => 33,
+    "chief" => 39,
+    "chiefly" => 35,
+    "chiefs" => 27,
+    "chiffon" => 29,
+    "child" => 52,
+    "child's" => 36,
+    "childhood" => 32,
+    "childish" => 31,
+    "childless" => 27,
+    "children" => 46,
+    "chili" => 24,
+    "chill" => 36,
+    "chilled" => 31,
+    "chillier" => 24,
+    "chilly" => 31,
+    "chimed" => 27,
+    "chimera" => 27,
+    "chimeras" => 24,
+    "chimerical" => 24,
+    "chimney" => 35,
+    "chimneys" => 31,
+    "chin" => 41,
+    "china" => 37,
+    "chinamen" => 27,
+    "chinchilla" => 24,
+    "chine" => 24,
+    "chinese" => 44,
+    "chink" => 30,
+    "chinks" => 27,
+    "chins" => 24,
+    "chip" => 41,
+    "chipped" => 24,
+    "chips" => 35,
+    "chirp" => 27,
+    "chirped" => 24,
+    "chirping" => 24,
+    "chisel" => 27,
+    "chivalrous" => 27,
+    "chivalry" => 27,
+    "chlorine" => 24,
+    "choice" => 48,
+    "choices" => 41,
+    "choicest" => 27,
+    "choir" => 29,
+    "choke" => 29,
+    "choked" => 35,
+    "choking" => 29,
+    "cholera" => 24,
+    "chomp" => 32,
+    "choose" => 49,
+    "chooser" => 32,
+    "choosers" => 24,
+    "chooses" => 39,
+    "choosing" => 44,
+    "chop" => 32,
+    "chopped" => 24,
+    "chopping" => 29,
+    "chord" => 32,
+    "chords" => 31,
+    "chore" => 33,
+    "chores" => 27,
+    "chorus" => 33,
+    "chose" => 40,
+    "chosen" => 47,
+    "chow" => 33,
+    "chris" => 41,
+    "christ" => 33,
+    "christendom" => 24,
+    "christian" => 52,
+    "christianity" => 24,
+    "christians" => 24,
+    "christmas" => 37,
+    "christopher" => 29,
+    "chrome" => 42,
+    "chromes" => 24,
+    "chromium" => 36,
+    "chronic" => 29,
+    "chronicle" => 32,
+    "chronicled" => 24,
+    "chronicler" => 24,
+    "chronological" => 34,
+    "chronometer" => 27,
+    "chronometers" => 27,
+    "chrysalis" => 24,
+    "chubb" => 24,
+    "chuck" => 27,
+    "chucked" => 24,
+    "chuckled" => 34,
+    "chuckling" => 27,
+    "chunk" => 47,
+    "chunked" => 36,
+    "chunking" => 31,
+    "chunks" => 46,
+    "church" => 41,
+    "churches" => 32,
+    "churchyard" => 24,
+    "churn" => 30,
+    "ciborium" => 34,
+    "cicada" => 24,
+    "cid" => 33,
+    "cigar" => 35,
+    "cigarette" => 33,
+    "cigarettes" => 32,
+    "cigars" => 33,
+    "cinder" => 24,
+    "cinnamon" => 29,
+    "cipher" => 45,
+    "ciphered" => 24,
+    "ciphers" => 42,
+    "circa" => 27,
+    "circle" => 41,
+    "circled" => 29,
+    "circles" => 36,
+    "circling" => 24,
+    "circuit" => 37,
+    "circuited" => 27,
+    "circuiting" => 37,
+    "circuits" => 38,
+    "circular" => 40,
+    "circularly" => 32,
+    "circulate" => 24,
+    "circulates" => 24,
+    "circulating" => 29,
+    "circulation" => 29,
+    "circumference" => 29,
+    "circumflex" => 38,
+    "circumspect" => 24,
+    "circumstance" => 37,
+    "circumstances" => 44,
+    "circumstantial" => 32,
+    "circumvent" => 30,
+    "circumvented" => 27,
+    "circumventing" => 27,
+    "circumvents" => 27,
+    "circus" => 36,
+    "circuses" => 24,
+    "cirrus" => 37,
+    "cisterns" => 24,
+    "citadel" => 24,
+    "citation" => 33,
+    "citations" => 31,
+    "cite" => 33,
+    "cited" => 31,
+    "cites" => 24,
+    "cities" => 33,
+    "citified" => 24,
+    "citing" => 29,
+    "citizen" => 34,
+    "citizens" => 38,
+    "citrus" => 24,
+    "city" => 43,
+    "civet" => 29,
+    "civil" => 36,
+    "civilians" => 24,
+    "civilisation" => 27,
+    "civilised" => 30,
+    "civilization" => 35,
+    "civilly" => 24,
+    "clack" => 29,
+    "clad" => 36,
+    "claim" => 42,
+    "claimed" => 34,
+    "claiming" => 30,
+    "claims" => 41,
+    "clairvoyant" => 24,
+    "clam" => 24,
+    "clambered" => 24,
+    "clambering" => 24,
+    "clammy" => 24,
+    "clamor" => 27,
+    "clamored" => 24,
+    "clamorous" => 27,
+    "clamorously" => 24,
+    "clamour" => 24,
+    "clamped" => 29,
+    "clamping" => 24,
+    "clamps" => 33,
+    "clan" => 29,
+    "clandestinely" => 24,
+    "clang" => 46,
+    "clanging" => 31,
+    "clank" => 24,
+    "clanking" => 24,
+    "clap" => 42,
+    "clapped" => 32,
+    "clapping" => 29,
+    "claps" => 24,
+    "clare" => 24,
+    "clarence" => 24,
+    "claret" => 31,
+    "clarification" => 31,
+    "clarifications" => 32,
+    "clarified" => 35,
+    "clarifies" => 24,
+    "clarify" => 42,
+    "clarifying" => 24,
+    "clarity" => 38,
+    "clark" => 30,
+    "clash" => 37,
+    "clashed" => 24,
+    "clashes" => 35,
+    "clashing" => 34,
+    "clasp" => 27,
+    "clasped" => 33,
+    "clasping" => 32,
+    "class" => 55,
+    "classes" => 48,
+    "classic" => 38,
+    "classical" => 37,
+    "classicist" => 27,
+    "classicists" => 24,
+    "classics" => 34,
+    "classification" => 41,
+    "classifications" => 31,
+    "classified" => 34,
+    "classifier" => 30,
+    "classifies" => 29,
+    "classify" => 32,
+    "classifying" => 30,
+    "classroom" => 29,
+    "classrooms" => 24,
+    "clatter" => 27,
+    "clattered" => 29,
+    "claudia" => 24,
+    "claudio" => 27,
+    "clause" => 48,
+    "clauses" => 42,
+    "clawed" => 27,
+    "claws" => 35,
+    "clay" => 39,
+    "clean" => 48,
+    "cleaned" => 40,
+    "cleaner" => 37,
+    "cleanest" => 24,
+    "cleaning" => 40,
+    "cleanliness" => 24,
+    "cleanly" => 38,
+    "cleans" => 36,
+    "cleanup" => 45,
+    "cleanups" => 31,
+    "clear" => 52,
+    "clearance" => 24,
+    "cleared" => 45,
+    "clearer" => 41,
+    "clearest" => 27,
+    "clearing" => 42,
+    "clearings" => 24,
+    "clearly" => 43,
+    "clearness" => 27,
+    "clears" => 41,
+    "cleaver" => 27,
+    "clemency" => 24,
+    "clenched" => 29,
+    "clergy" => 24,
+    "clergyman" => 35,
+    "clerics" => 24,
+    "clerk" => 34,
+    "clerks" => 32,
+    "clever" => 40,
+    "cleverly" => 30,
+    "cleverness" => 30,
+    "clew" => 27,
+    "clews" => 27,
+    "click" => 48,
+    "clicked" => 36,
+    "clicking" => 43,
+    "clicks" => 39,
+    "client" => 54,
+    "clientele" => 24,
+    "clients" => 47,
+    "cliff" => 27,
+    "clifford" => 32,
+    "cliffs" => 27,
+    "climate" => 31,
+    "climax" => 27,
+    "climb" => 32,
+    "climbed" => 33,
+    "climbing" => 35,
+    "clime" => 24,
+    "climes" => 24,
+    "clinched" => 24,
+    "cline" => 30,
+    "cling" => 29,
+    "clinging" => 29,
+    "clings" => 30,
+    "clinic" => 27,
+    "clinical" => 34,
+    "clink" => 27,
+    "clinked" => 24,
+    "clip" => 38,
+    "clipboard" => 49,
+    "clipped" => 24,
+    "clipper" => 24,
+    "clippers" => 24,
+    "clipping" => 36,
+    "clippings" => 24,
+    "cloak" => 36,
+    "cloaks" => 27,
+    "clobber" => 36,
+    "clobbered" => 36,
+    "clobbering" => 29,
+    "clobbers" => 41,
+    "clock" => 45,
+    "clocks" => 33,
+    "clockwise" => 35,
+    "clockwork" => 24,
+    "clods" => 27,
+    "clog" => 24,
+    "clogging" => 24,
+    "clogs" => 24,
+    "clone" => 49,
+    "cloned" => 44,
+    "clones" => 42,
+    "cloning" => 45,
+    "close" => 51,
+    "closed" => 51,
+    "closely" => 43,
+    "closer" => 41,
+    "closes" => 59,
+    "closest" => 41,
+    "closet" => 32,
+    "closets" => 24,
+    "closing" => 48,
+    "closure" => 54,
+    "closures" => 48,
+    "cloth" => 35,
+    "clothe" => 24,
+    "clothed" => 32,
+    "clothes" => 42,
+    "clothing" => 32,
+    "cloud" => 43,
+    "clouded" => 31,
+    "clouding" => 24,
+    "cloudless" => 32,
+    "clouds" => 39,
+    "cloudy" => 31,
+    "clove" => 27,
+    "clover" => 27,
+    "cloves" => 24,
+    "clown" => 32,
+    "clowns" => 30,
+    "club" => 41,
+    "clubs" => 30,
+    "clue" => 37,
+    "clues" => 31,
+    "clump" => 32,
+    "clumps" => 27,
+    "clumsy" => 37,
+    "clung" => 34,
+    "cluster" => 44,
+    "clustered" => 30,
+    "clustering" => 35,
+    "clusters" => 41,
+    "clutched" => 30,
+    "clutches" => 27,
+    "clutching" => 27,
+    "clutter" => 34,
+    "cluttered" => 30,
+    "cluttering" => 24,
+    "clutters" => 27,
+    "clyde" => 24,
+    "coach" => 30,
+    "coaches" => 27,
+    "coachman" => 29,
+    "coal" => 36,
+    "coalesce" => 30,
+    "coalesced" => 30,
+    "coalescence" => 24,
+    "coalescing" => 30,
+    "coarse" => 35,
+    "coarsely" => 24,
+    "coarser" => 27,
+    "coarsest" => 24,
+    "coast" => 37,
+    "coasting" => 29,
+    "coasts" => 29,
+    "coat" => 41,
+    "coated" => 27,
+    "coats" => 31,
+    "coaxed" => 24,
+    "coaxing" => 29,
+    "cob" => 24,
+    "cobalt" => 29,
+    "cobbled" => 27,
+    "cobol" => 30,
+    "cobs" => 32,
+    "cobwebs" => 24,
+    "cocaine" => 30,
+    "cock" => 27,
+    "cocked" => 32,
+    "cocking" => 24,
+    "cockney" => 24,
+    "cockroaches" => 24,
+    "cocks" => 24,
+    "cocksure" => 27,
+    "cocktail" => 30,
+    "cocktails" => 31,
+    "cocoa" => 34,
+    "coconut" => 24,
+    "coda" => 29,
+    "codas" => 27,
+    "code" => 63,
+    "codebase" => 42,
+    "coded" => 38,
+    "coder" => 32,
+    "codes" => 49,
+    "codex" => 24,
+    "codifies" => 24,
+    "codify" => 24,
+    "coding" => 41,
+    "coefficient" => 29,
+    "coefficients" => 31,
+    "coerce" => 45,
+    "coerced" => 42,
+    "coercers" => 30,
+    "coerces" => 34,
+    "coercible" => 24,
+    "coercing" => 32,
+    "coercion" => 45,
+    "coercive" => 27,
+    "coexist" => 30,
+    "coexists" => 24,
+    "cofactor" => 33,
+    "cofactors" => 24,
+    "coffee" => 39,
+    "coffers" => 27,
+    "coffin" => 33,
+    "cogitating" => 29,
+    "cognitive" => 27,
+    "cognizant" => 24,
+    "cohabiting" => 24,
+    "cohen" => 29,
+    "cohere" => 24,
+    "coherence" => 38,
+    "coherent" => 35,
+    "cohesive" => 27,
+    "coi" => 24,
+    "coil" => 27,
+    "coils" => 24,
+    "coin" => 38,
+    "coincide" => 32,
+    "coincided" => 24,
+    "coincidence" => 31,
+    "coincidences" => 27,
+    "coincident" => 24,
+    "coincidental" => 24,
+    "coincides" => 27,
+    "coinciding" => 24,
+    "coined" => 27,
+    "coins" => 36,
+    "col" => 46,
+    "cold" => 45,
+    "colder" => 32,
+    "coldly" => 36,
+    "coldness" => 29,
+    "cole" => 29,
+    "colicky" => 24,
+    "colin" => 31,
+    "collaborate" => 37,
+    "collaborated" => 24,
+    "collaborating" => 35,
+    "collaboration" => 34,
+    "collaborative" => 34,
+    "collaborator" => 32,
+    "collaborators" => 36,
+    "collapse" => 40,
+    "collapsed" => 36,
+    "collapses" => 24,
+    "collapsing" => 31,
+    "collar" => 36,
+    "collaring" => 24,
+    "collate" => 32,
+    "collated" => 27,
+    "collateral" => 24,
+    "collating" => 32,
+    "collation" => 37,
+    "collator" => 29,
+    "colleague" => 33,
+    "colleagues" => 34,
+    "collect" => 45,
+    "collectable" => 24,
+    "collected" => 44,
+    "collecting" => 42,
+    "collection" => 51,
+    "collections" => 48,
+    "collective" => 40,
+    "collectively" => 34,
+    "collectives" => 24,
+    "collector" => 39,
+    "collectors" => 31,
+    "collects" => 39,
+    "college" => 38,
+    "colleges" => 30,
+    "collegial" => 24,
+    "collide" => 35,
+    "collides" => 24,
+    "colliding" => 30,
+    "collinear" => 27,
+    "collins" => 27,
+    "collision" => 43,
+    "collisions" => 41,
+    "colloquially" => 33,
+    "colloquy" => 27,
+    "cologne" => 24,
+    "colombo" => 33,
+    "colon" => 45,
+    "colonel" => 43,
+    "colonial" => 24,
+    "colonialism" => 24,
+    "colonies" => 32,
+    "colonisation" => 27,
+    "colonise" => 24,
+    "colonising" => 24,
+    "colonization" => 24,
+    "colons" => 35,
+    "colony" => 32,
+    "colophon" => 27,
+    "color" => 54,
+    "colorado" => 29,
+    "colored" => 44,
+    "colorful" => 33,
+    "coloring" => 41,
+    "colorize" => 35,
+    "colorized" => 34,
+    "colorizes" => 24,
+    "colorizing" => 32,
+    "colorless" => 24,
+    "colors" => 52,
+    "colossal" => 31,
+    "colour" => 41,
+    "coloured" => 38,
+    "colourful" => 24,
+    "colouring" => 30,
+    "colourless" => 24,
+    "colours" => 39,
+    "cols" => 34,
+    "colt" => 27,
+    "columbia" => 29,
+    "columbus" => 31,
+    "column" => 52,
+    "columnar" => 34,
+    "columned" => 24,
+    "columns" => 47,
+    "comb" => 29,
+    "combat" => 30,
+    "combatants" => 29,
+    "combed" => 24,
+    "combination" => 47,
+    "combinations" => 44,
+    "combinatorial" => 27,
+    "combine" => 45,
+    "combined" => 46,
+    "combines" => 41,
+    "combining" => 44,
+    "combo" => 30,
+    "combustibles" => 24,
+    "combustion" => 24,
+    "come" => 53,
+    "comely" => 24,
+    "comer" => 29,
+    "comes" => 50,
+    "comet" => 24,
+    "comfits" => 27,
+    "comfort" => 39,
+    "comfortable" => 41,
+    "comfortably" => 34,
+    "comforted" => 30,
+    "comforter" => 30,
+    "comforting" => 29,
+    "comfortless" => 24,
+    "comforts" => 24,
+    "comfy" => 36,
+    "comic" => 24,
+    "comical" => 29,
+    "coming" => 47,
+    "comma" => 48,
+    "command" => 62,
+    "commanded" => 33,
+    "commander" => 31,
+    "commanders" => 24,
+    "commanding" => 30,
+    "commands" => 57,
+    "commas" => 41,
+    "commence" => 37,
+    "commenced" => 35,
+    "commencement" => 33,
+    "commences" => 24,
+    "commendation" => 24,
+    "commended" => 24,
+    "commensurate" => 27,
+    "comment" => 52,
+    "commentary" => 24,
+    "commented" => 40,
+    "commenting" => 35,
+    "comments" => 52,
+    "commerce" => 32,
+    "commercial" => 42,
+    "commercially" => 29,
+    "commiserate" => 27,
+    "commission" => 38,
+    "commissionaire" => 30,
+    "commissioned" => 27,
+    "commissioner" => 29,
+    "commissions" => 27,
+    "commit" => 53,
+    "commitment" => 37,
+    "commitments" => 34,
+    "commits" => 48,
+    "committed" => 44,
+    "committee" => 32,
+    "committees" => 33,
+    "committing" => 39,
+    "commodity" => 24,
+    "commodore" => 24,
+    "common" => 55,
+    "commonly" => 46,
+    "commonplace" => 36,
+    "commonplaces" => 27,
+    "commons" => 40,
+    "commotion" => 29,
+    "communicate" => 44,
+    "communicated" => 37,
+    "communicates" => 33,
+    "communicating" => 40,
+    "communication" => 47,
+    "communications" => 37,
+    "communicative" => 29,
+    "communicator" => 24,
+    "communing" => 27,
+    "communion" => 27,
+    "communities" => 33,
+    "community" => 48,
+    "commutation" => 24,
+    "commutative" => 29,
+    "commuting" => 29,
+    "comp" => 32,
+    "compact" => 45,
+    "compacted" => 24,
+    "compaction" => 27,
+    "compactly" => 31,
+    "compactness" => 24,
+    "companies" => 37,
+    "companion" => 44,
+    "companionless" => 24,
+    "companions" => 38,
+    "companionship" => 29,
+    "company" => 44,
+    "comparability" => 24,
+    "comparable" => 39,
+    "comparably" => 29,
+    "comparative" => 33,
+    "comparatively" => 31,
+    "comparatives" => 24,
+    "comparator" => 36,
+    "compare" => 52,
+    "compared" => 48,
+    "compares" => 47,
+    "comparing" => 47,
+    "comparison" => 51,
+    "comparisons" => 44,
+    "compartmentalized" => 27,
+    "compartments" => 24,
+    "compass" => 32,
+    "compassed" => 24,
+    "compasses" => 24,
+    "compassion" => 35,
+    "compassionate" => 27,
+    "compatibility" => 52,
+    "compatible" => 54,
+    "compel" => 29,
+    "compelled" => 36,
+    "compelling" => 32,
+    "compendium" => 24,
+    "compensate" => 33,
+    "compensated" => 30,
+    "compensating" => 24,
+    "compensation" => 32,
+    "compensations" => 24,
+    "compete" => 31,
+    "competence" => 24,
+    "competent" => 29,
+    "competing" => 32,
+    "competition" => 33,
+    "competitive" => 38,
+    "competitor" => 24,
+    "competitors" => 29,
+    "compilation" => 52,
+    "compilations" => 34,
+    "compile" => 58,
+    "compiled" => 55,
+    "compiler" => 58,
+    "compilers" => 45,
+    "compiles" => 46,
+    "compiling" => 51,
+    "complacency" => 27,
+    "complacent" => 27,
+    "complain" => 38,
+    "complainants" => 27,
+    "complained" => 35,
+    "complaining" => 32,
+    "complains" => 42,
+    "complaint" => 32,
+    "complaints" => 31,
+    "complement" => 41,
+    "complementary" => 35,
+    "complemented" => 29,
+    "complements" => 29,
+    "complete" => 53,
+    "completed" => 46,
+    "completely" => 48,
+    "completeness" => 40,
+    "completes" => 43,
+    "completing" => 42,
+    "completion" => 53,
+    "completions" => 41,
+    "complex" => 53,
+    "complexion" => 33,
+    "complexioned" => 27,
+    "complexions" => 27,
+    "complexities" => 30,
+    "complexity" => 44,
+    "compliance" => 42,
+    "compliancy" => 24,
+    "compliant" => 44,
+    "complicate" => 29,
+    "complicated" => 46,
+    "complicates" => 29,
+    "complicating" => 29,
+    "complication" => 29,
+    "complications" => 27,
+    "complicity" => 24,
+    "complied" => 24,
+    "complies" => 38,
+    "compliment" => 32,
+    "complimentary" => 30,
+    "complimented" => 24,
+    "compliments" => 31,
+    "comply" => 38,
+    "complying" => 24,
+    "component" => 50,
+    "components" => 50,
+    "compose" => 40,
+    "composed" => 49,
+    "composer" => 32,
+    "composers" => 24,
+    "composes" => 31,
+    "composing" => 45,
+    "composite" => 41,
+    "composites" => 24,
+    "compositing" => 30,
+    "composition" => 41,
+    "compositional" => 24,
+    "compositions" => 32,
+    "compositor" => 32,
+    "compositors" => 27,
+    "composure" => 33,
+    "compound" => 45,
+    "compounded" => 31,
+    "compounding" => 36,
+    "compounds" => 24,
+    "comprehend" => 33,
+    "comprehended" => 32,
+    "comprehensible" => 27,
+    "comprehension" => 32,
+    "comprehensive" => 44,
+    "comprehensively" => 24,
+    "comprehensiveness" => 27,
+    "compress" => 42,
+    "compressed" => 45,
+    "compresses" => 35,
+    "compressible" => 24,
+    "compressing" => 37,
+    "compression" => 49,
+    "compressor" => 33,
+    "comprise" => 32,
+    "comprised" => 35,
+    "comprises" => 29,
+    "comprising" => 32,
+    "compromise" => 34,
+    "compromised" => 33,
+    "compromises" => 32,
+    "compromising" => 33,
+    "compulsion" => 27,
+    "compulsory" => 31,
+    "compunction" => 29,
+    "computability" => 24,
+    "computable" => 27,
+    "computation" => 47,
+    "computational" => 40,
+    "computations" => 41,
+    "compute" => 51,
+    "computed" => 45,
+    "computer" => 49,
+    "computerization" => 24,
+    "computerized" => 31,
+    "computers" => 43,
+    "computes" => 48,
+    "computing" => 45,
+    "comrade" => 33,
+    "comrades" => 32,
+    "con" => 39,
+    "concatenate" => 43,
+    "concatenated" => 41,
+    "concatenates" => 36,
+    "concatenating" => 39,
+    "concatenation" => 43,
+    "conceal" => 44,
+    "concealable" => 31,
+    "concealed" => 40,
+    "concealing" => 35,
+    "concealment" => 30,
+    "concede" => 29,
+    "conceded" => 34,
+    "conceding" => 27,
+    "conceit" => 27,
+    "conceited" => 27,
+    "conceits" => 24,
+    "conceivable" => 24,
+    "conceivably" => 29,
+    "conceive" => 36,
+    "conceived" => 36,
+    "conceives" => 24,
+    "conceiving" => 24,
+    "concentrate" => 35,
+    "concentrated" => 32,
+    "concentrating" => 29,
+    "concentration" => 29,
+    "concept" => 46,
+    "conception" => 34,
+    "conceptions" => 24,
+    "concepts" => 45,
+    "conceptual" => 32,
+    "conceptualized" => 24,
+    "conceptually" => 39,
+    "concern" => 42,
+    "concerned" => 42,
+    "concerning" => 44,
+    "concerns" => 43,
+    "concert" => 32,
+    "concerts" => 24,
+    "concession" => 29,
+    "concessions" => 24,
+    "conciliating" => 27,
+    "concise" => 41,
+    "concisely" => 33,
+    "conciseness" => 30,
+    "conclude" => 35,
+    "concluded" => 39,
+    "concludes" => 29,
+    "concluding" => 32,
+    "conclusion" => 39,
+    "conclusions" => 37,
+    "conclusive" => 27,
+    "concocted" => 24,
+    "concordance" => 24,
+    "concordances" => 30,
+    "concrete" => 47,
+    "concretely" => 32,
+    "concur" => 27,
+    "concurred" => 27,
+    "concurrence" => 30,
+    "concurrency" => 46,
+    "concurrent" => 47,
+    "concurrently" => 44,
+    "concurring" => 24,
+    "concussion" => 29,
+    "condemn" => 31,
+    "condemnation" => 24,
+    "condemned" => 35,
+    "condemns" => 24,
+    "condensed" => 24,
+    "condescend" => 29,
+    "condescending" => 24,
+    "condescension" => 27,
+    "condition" => 50,
+    "conditional" => 47,
+    "conditionally" => 43,
+    "conditionals" => 39,
+    "conditioned" => 27,
+    "conditioning" => 24,
+    "conditions" => 56,
+    "conduce" => 24,
+    "conducive" => 29,
+    "conduct" => 48,
+    "conducted" => 38,
+    "conducting" => 32,
+    "conductive" => 27,
+    "conductor" => 37,
+    "conductors" => 24,
+    "conduit" => 31,
+    "coney" => 27,
+    "confederate" => 30,
+    "confederates" => 24,
+    "confederation" => 27,
+    "confer" => 24,
+    "conference" => 33,
+    "conferences" => 35,
+    "conferred" => 24,
+    "conferring" => 27,
+    "confers" => 29,
+    "confess" => 38,
+    "confessed" => 38,
+    "confessing" => 27,
+    "confession" => 33,
+    "confessor" => 27,
+    "confidant" => 29,
+    "confide" => 33,
+    "confided" => 31,
+    "confidence" => 39,
+    "confidences" => 29,
+    "confident" => 38,
+    "confidential" => 32,
+    "confidentiality" => 30,
+    "confidentially" => 27,
+    "confidently" => 33,
+    "configurability" => 30,
+    "configurable" => 45,
+    "configuration" => 54,
+    "configurations" => 44,
+    "configure" => 54,
+    "configured" => 48,
+    "configures" => 39,
+    "configuring" => 42,
+    "confine" => 31,
+    "confined" => 36,
+    "confinement" => 24,
+    "confines" => 27,
+    "confining" => 24,
+    "confirm" => 43,
+    "confirmation" => 37,
+    "confirmed" => 39,
+    "confirming" => 24,
+    "confirms" => 29,
+    "confiscated" => 24,
+    "conflagration" => 27,
+    "conflagrations" => 24,
+    "conflict" => 45,
+    "conflicted" => 29,
+    "conflicting" => 39,
+    "conflicts" => 44,
+    "conform" => 40,
+    "conformal" => 27,
+    "conformance" => 40,
+    "conformation" => 24,
+    "conformed" => 27,
+    "conforming" => 33,
+    "conforms" => 34,
+    "confound" => 29,
+    "confounded" => 24,
+    "confounding" => 24,
+    "confront" => 24,
+    "confronted" => 27,
+    "confronts" => 27,
+    "confucius" => 24,
+    "confusable" => 34,
+    "confuse" => 36,
+    "confused" => 43,
+    "confusedly" => 27,
+    "confuses" => 33,
+    "confusing" => 47,
+    "confusingly" => 24,
+    "confusion" => 44,
+    "congeal" => 24,
+    "congenial" => 30,
+    "conger" => 24,
+    "congestion" => 29,
+    "congratulate" => 30,
+    "congratulated" => 24,
+    "congratulating" => 24,
+    "congratulations" => 30,
+    "congratulatory" => 29,
+    "congregate" => 27,
+    "congregated" => 24,
+    "congregation" => 32,
+    "congress" => 44,
+    "congressional" => 24,
+    "conic" => 24,
+    "conjecture" => 35,
+    "conjectured" => 35,
+    "conjectures" => 27,
+    "conjoining" => 24,
+    "conjugate" => 40,
+    "conjugates" => 39,
+    "conjugation" => 35,
+    "conjugations" => 24,
+    "conjunct" => 29,
+    "conjunction" => 43,
+    "conjunctions" => 31,
+    "conjure" => 31,
+    "conjured" => 27,
+    "conjuring" => 24,
+    "conjurors" => 24,
+    "conn" => 24,
+    "connect" => 46,
+    "connected" => 46,
+    "connecticut" => 24,
+    "connecting" => 41,
+    "connection" => 51,
+    "connections" => 48,
+    "connectivity" => 35,
+    "connector" => 36,
+    "connectors" => 27,
+    "connects" => 39,
+    "conned" => 24,
+    "connexion" => 24,
+    "connivance" => 27,
+    "connotations" => 24,
+    "conquer" => 30,
+    "conquered" => 27,
+    "conquering" => 27,
+    "conqueror" => 31,
+    "conquest" => 32,
+    "cons" => 41,
+    "conscience" => 38,
+    "consciences" => 24,
+    "conscientious" => 29,
+    "conscientiously" => 29,
+    "conscious" => 35,
+    "consciously" => 27,
+    "consciousness" => 34,
+    "consecrate" => 27,
+    "consecrated" => 27,
+    "consecutive" => 40,
+    "consecutively" => 29,
+    "consensus" => 42,
+    "consent" => 39,
+    "consented" => 35,
+    "consequence" => 43,
+    "consequences" => 40,
+    "consequent" => 32,
+    "consequential" => 34,
+    "consequently" => 40,
+    "conservancy" => 38,
+    "conservation" => 32,
+    "conservative" => 41,
+    "conservatively" => 35,
+    "conservatory" => 24,
+    "conserve" => 27,
+    "conserved" => 29,
+    "consider" => 52,
+    "considerable" => 41,
+    "considerably" => 39,
+    "considerate" => 24,
+    "considerateness" => 24,
+    "consideration" => 41,
+    "considerations" => 43,
+    "considered" => 52,
+    "considering" => 39,
+    "considers" => 41,
+    "consigned" => 27,
+    "consist" => 41,
+    "consisted" => 38,
+    "consistency" => 42,
+    "consistent" => 47,
+    "consistently" => 42,
+    "consisting" => 41,
+    "consists" => 46,
+    "consolation" => 37,
+    "console" => 51,
+    "consoled" => 29,
+    "consoles" => 33,
+    "consolidate" => 40,
+    "consolidated" => 30,
+    "consolidates" => 24,
+    "consolidating" => 24,
+    "consoling" => 29,
+    "consonant" => 34,
+    "consonants" => 34,
+    "consortium" => 31,
+    "conspicuous" => 32,
+    "conspicuously" => 27,
+    "conspicuousness" => 24,
+    "conspiracy" => 34,
+    "conspired" => 24,
+    "conspiring" => 27,
+    "const" => 53,
+    "constable" => 30,
+    "constables" => 30,
+    "constabulary" => 24,
+    "constant" => 51,
+    "constantinople" => 30,
+    "constantly" => 38,
+    "constants" => 49,
+    "consternation" => 30,
+    "constituent" => 30,
+    "constituents" => 27,
+    "constitute" => 38,
+    "constituted" => 32,
+    "constitutes" => 38,
+    "constituting" => 24,
+    "constitution" => 38,
+    "constitutional" => 27,
+    "constitutionally" => 24,
+    "constrain" => 36,
+    "constrained" => 40,
+    "constraining" => 33,
+    "constrains" => 33,
+    "constraint" => 46,
+    "constraints" => 46,
+    "construct" => 48,
+    "constructable" => 24,
+    "constructed" => 46,
+    "constructible" => 31,
+    "constructing" => 44,
+    "construction" => 45,
+    "constructions" => 37,
+    "constructive" => 27,
+    "constructor" => 49,
+    "constructors" => 44,
+    "constructs" => 47,
+    "construed" => 30,
+    "consul" => 38,
+    "consulate" => 33,
+    "consuls" => 31,
+    "consult" => 42,
+    "consultant" => 24,
+    "consultation" => 30,
+    "consultations" => 24,
+    "consultative" => 29,
+    "consulted" => 35,
+    "consulting" => 34,
+    "consults" => 27,
+    "consumable" => 29,
+    "consume" => 45,
+    "consumed" => 44,
+    "consumer" => 44,
+    "consumers" => 43,
+    "consumes" => 45,
+    "consuming" => 45,
+    "consummate" => 27,
+    "consummated" => 24,
+    "consummation" => 30,
+    "consumption" => 41,
+    "contact" => 43,
+    "contacted" => 27,
+    "contacting" => 32,
+    "contacts" => 32,
+    "contain" => 54,
+    "contained" => 51,
+    "container" => 47,
+    "containerized" => 30,
+    "containers" => 43,
+    "containing" => 53,
+    "containment" => 31,
+    "contains" => 57,
+    "contaminate" => 24,
+    "contaminates" => 24,
+    "contaminating" => 24,
+    "contamination" => 29,
+    "contemplate" => 32,
+    "contemplated" => 35,
+    "contemplating" => 24,
+    "contemplation" => 30,
+    "contemplative" => 24,
+    "contemporaries" => 24,
+    "contemporary" => 32,
+    "contempt" => 34,
+    "contemptible" => 24,
+    "contemptuous" => 32,
+    "contemptuously" => 30,
+    "contend" => 24,
+    "contended" => 36,
+    "contending" => 32,
+    "content" => 51,
+    "contented" => 34,
+    "contention" => 38,
+    "contentions" => 24,
+    "contentious" => 33,
+    "contentment" => 31,
+    "contents" => 54,
+    "contest" => 29,
+    "contested" => 24,
+    "contesting" => 24,
+    "context" => 54,
+    "contexts" => 47,
+    "contextual" => 38,
+    "contiguous" => 45,
+    "contiguously" => 37,
+    "continent" => 35,
+    "continental" => 32,
+    "continents" => 27,
+    "contingencies" => 24,
+    "contingency" => 24,
+    "continua" => 24,
+    "continual" => 33,
+    "continually" => 40,
+    "continuance" => 27,
+    "continuation" => 45,
+    "continuations" => 32,
+    "continue" => 50,
+    "continued" => 45,
+    "continues" => 44,
+    "continuing" => 42,
+    "continuity" => 24,
+    "continuous" => 44,
+    "continuously" => 40,
+    "continuum" => 32,
+    "contortions" => 30,
+    "contour" => 33,
+    "contours" => 32,
+    "contra" => 29,
+    "contract" => 48,
+    "contracted" => 27,
+    "contracting" => 30,
+    "contraction" => 29,
+    "contractor" => 30,
+    "contractors" => 29,
+    "contracts" => 44,
+    "contractual" => 24,
+    "contradict" => 29,
+    "contradicted" => 27,
+    "contradicting" => 27,
+    "contradiction" => 24,
+    "contradictory" => 32,
+    "contradicts" => 30,
+    "contralto" => 29,
+    "contrary" => 40,
+    "contrast" => 45,
+    "contrasted" => 29,
+    "contrasting" => 27,
+    "contrasts" => 30,
+    "contravariant" => 36,
+    "contribute" => 47,
+    "contributed" => 40,
+    "contributes" => 34,
+    "contributing" => 53,
+    "contribution" => 57,
+    "contributions" => 50,
+    "contributor" => 44,
+    "contributors" => 47,
+    "contrition" => 24,
+    "contrived" => 35,
+    "contriving" => 24,
+    "control" => 54,
+    "controllable" => 32,
+    "controlled" => 44,
+    "controller" => 40,
+    "controllers" => 35,
+    "controlling" => 41,
+    "controls" => 46,
+    "controversial" => 24,
+    "controversies" => 27,
+    "controversy" => 29,
+    "contumely" => 24,
+    "conundrum" => 24,
+    "conundrums" => 24,
+    "convalescence" => 29,
+    "convalescent" => 24,
+    "convene" => 27,
+    "convened" => 24,
+    "convenience" => 48,
+    "conveniences" => 32,
+    "convenient" => 48,
+    "conveniently" => 40,
+    "convent" => 24,
+    "convention" => 47,
+    "conventional" => 39,
+    "conventionalities" => 24,
+    "conventionally" => 32,
+    "conventions" => 46,
+    "converge" => 24,
+    "converged" => 24,
+    "convergence" => 31,
+    "convergent" => 24,
+    "conversation" => 43,
+    "conversational" => 24,
+    "conversations" => 36,
+    "converse" => 34,
+    "conversed" => 29,
+    "conversely" => 39,
+    "conversing" => 27,
+    "conversion" => 52,
+    "conversions" => 47,
+    "convert" => 55,
+    "converted" => 50,
+    "converter" => 35,
+    "converters" => 27,
+    "convertibility" => 24,
+    "convertible" => 38,
+    "converting" => 49,
+    "converts" => 50,
+    "convex" => 30,
+    "convey" => 36,
+    "conveyance" => 32,
+    "conveyances" => 24,
+    "conveyed" => 36,
+    "conveying" => 27,
+    "conveys" => 33,
+    "convict" => 24,
+    "convicted" => 31,
+    "conviction" => 36,
+    "convince" => 33,
+    "convinced" => 39,
+    "convincing" => 32,
+    "convivial" => 24,
+    "convoluted" => 30,
+    "convolution" => 27,
+    "convolutions" => 27,
+    "convoy" => 24,
+    "convulse" => 24,
+    "convulsed" => 29,
+    "convulsion" => 27,
+    "convulsions" => 24,
+    "convulsive" => 27,
+    "convulsively" => 24,
+    "conway" => 24,
+    "cooee" => 32,
+    "cook" => 37,
+    "cookbook" => 34,
+    "cooked" => 36,
+    "cookie" => 38,
+    "cookies" => 36,
+    "cooking" => 29,
+    "cooks" => 27,
+    "cool" => 41,
+    "cooled" => 27,
+    "cooler" => 27,
+    "coolest" => 24,
+    "coolies" => 24,
+    "cooling" => 24,
+    "coolly" => 32,
+    "coolness" => 32,
+    "cooped" => 27,
+    "cooper" => 33,
+    "cooperate" => 32,
+    "cooperation" => 35,
+    "cooperative" => 36,
+    "cooperatively" => 27,
+    "coordinate" => 42,
+    "coordinated" => 32,
+    "coordinates" => 42,
+    "coordinating" => 27,
+    "coordination" => 36,
+    "coordinator" => 38,
+    "coordinators" => 24,
+    "coot" => 27,
+    "cope" => 33,
+    "copenhagen" => 24,
+    "copied" => 54,
+    "copier" => 24,
+    "copies" => 48,
+    "copilot" => 29,
+    "copper" => 36,
+    "coppers" => 29,
+    "copse" => 24,
+    "copses" => 24,
+    "copula" => 24,
+    "copy" => 56,
+    "copying" => 47,
+    "copyright" => 49,
+    "copyrighted" => 31,
+    "copyrights" => 33,
+    "coq" => 30,
+    "coquettish" => 24,
+    "coral" => 24,
+    "cord" => 32,
+    "cordial" => 29,
+    "cordially" => 27,
+    "cordials" => 24,
+    "core" => 53,
+    "cores" => 41,
+    "corey" => 29,
+    "corkscrew" => 24,
+    "corky" => 24,
+    "cormorants" => 24,
+    "corn" => 34,
+    "cornelius" => 33,
+    "cornell" => 40,
+    "corner" => 45,
+    "cornered" => 24,
+    "corners" => 37,
+    "cornets" => 24,
+    "cornices" => 27,
+    "cornish" => 24,
+    "cornwall" => 24,
+    "corollaries" => 29,
+    "corollary" => 34,
+    "coroner" => 36,
+    "coronet" => 38,
+    "coroutine" => 45,
+    "corpora" => 31,
+    "corporate" => 35,
+    "corporation" => 34,
+    "corporations" => 24,
+    "corps" => 27,
+    "corpse" => 37,
+    "corpses" => 24,
+    "corpulent" => 24,
+    "corpus" => 42,
+    "corpuses" => 24,
+    "correct" => 52,
+    "corrected" => 40,
+    "correcting" => 34,
+    "correction" => 39,
+    "corrections" => 36,
+    "correctly" => 51,
+    "correctness" => 45,
+    "corrects" => 37,
+    "correlate" => 33,
+    "correlated" => 24,
+    "correlates" => 24,
+    "correlating" => 24,
+    "correlation" => 31,
+    "correspond" => 43,
+    "corresponded" => 24,
+    "correspondence" => 33,
+    "correspondent" => 27,
+    "corresponding" => 57,
+    "correspondingly" => 32,
+    "corresponds" => 48,
+    "corridor" => 36,
+    "corridors" => 30,
+    "corroborate" => 27,
+    "corroborated" => 24,
+    "corroborating" => 27,
+    "corroboration" => 24,
+    "corrugated" => 27,
+    "corrupt" => 38,
+    "corrupted" => 38,
+    "corrupting" => 32,
+    "corruption" => 39,
+    "corrupts" => 27,
+    "corsican" => 24,
+    "cortex" => 47,
+    "cos" => 32,
+    "cosh" => 32,
+    "cosily" => 27,
+    "cosine" => 38,
+    "cosiness" => 24,
+    "cosmetic" => 30,
+    "cosmic" => 30,
+    "cosmopolitan" => 29,
+    "cosmos" => 31,
+    "cost" => 49,
+    "costed" => 24,
+    "costing" => 27,
+    "costly" => 35,
+    "costs" => 43,
+    "costume" => 34,
+    "costumes" => 24,
+    "cosy" => 29,
+    "cot" => 33,
+    "cots" => 27,
+    "cottage" => 41,
+    "cottager" => 24,
+    "cottagers" => 37,
+    "cottages" => 32,
+    "cotton" => 31,
+    "couch" => 36,
+    "cough" => 24,
+    "could" => 58,
+    "couldn't" => 44,
+    "council" => 29,
+    "counsel" => 34,
+    "counseled" => 24,
+    "counsellor" => 27,
+    "counsellors" => 24,
+    "count" => 55,
+    "countable" => 24,
+    "countdown" => 30,
+    "counted" => 45,
+    "countenance" => 41,
+    "countenances" => 34,
+    "counter" => 47,
+    "counterbalanced" => 24,
+    "countered" => 27,
+    "counterfeit" => 27,
+    "counterfeited" => 24,
+    "counterfeiting" => 24,
+    "counterintuitive" => 29,
+    "counterpart" => 42,
+    "counterparts" => 38,
+    "counters" => 38,
+    "countersign" => 24,
+    "countersigned" => 24,
+    "countess" => 33,
+    "counties" => 27,
+    "counting" => 45,
+    "countless" => 31,
+    "countries" => 42,
+    "country" => 46,
+    "countryman" => 27,
+    "countrymen" => 27,
+    "countryside" => 27,
+    "counts" => 44,
+    "county" => 34,
+    "couple" => 46,
+    "coupled" => 37,
+    "couples" => 31,
+    "coupling" => 32,
+    "coupé" => 35,
+    "courage" => 38,
+    "courageous" => 30,
+    "courageously" => 27,
+    "courgette" => 24,
+    "courier" => 33,
+    "course" => 50,
+    "coursed" => 27,
+    "courses" => 33,
+    "courseware" => 29,
+    "court" => 43,
+    "courted" => 24,
+    "courtesy" => 33,
+    "courtiers" => 27,
+    "courts" => 31,
+    "courtyard" => 29,
+    "cousin" => 41,
+    "cousins" => 31,
+    "covariance" => 31,
+    "covariant" => 40,
+    "covenant" => 37,
+    "covent" => 29,
+    "coventry" => 24,
+    "cover" => 47,
+    "coverage" => 50,
+    "coveralls" => 37,
+    "covered" => 48,
+    "covering" => 38,
+    "coverlet" => 24,
+    "covers" => 43,
+    "covet" => 24,
+    "coveted" => 27,
+    "cow" => 38,
+    "coward" => 31,
+    "cowardice" => 30,
+    "cowardly" => 24,
+    "cowards" => 24,
+    "cowered" => 24,
+    "cowhide" => 24,
+    "coworker" => 27,
+    "coworkers" => 24,
+    "cows" => 29,
+    "cox" => 27,
+    "coz" => 24,
+    "cpu" => 52,
+    "crab" => 33,
+    "crabbing" => 24,
+    "crack" => 36,
+    "cracked" => 35,
+    "crackers" => 24,
+    "cracking" => 27,
+    "crackling" => 29,
+    "cracks" => 27,
+    "cradles" => 24,
+    "craft" => 38,
+    "crafted" => 36,
+    "crafting" => 31,
+    "craggy" => 29,
+    "crags" => 27,
+    "craig" => 32,
+    "cram" => 30,
+    "cramp" => 24,
+    "cramped" => 27,
+    "crane" => 31,
+    "cranes" => 24,
+    "crank" => 24,
+    "cranking" => 24,
+    "crap" => 24,
+    "crash" => 53,
+    "crashed" => 38,
+    "crashes" => 43,
+    "crashing" => 37,
+    "crate" => 64,
+    "crater" => 29,
+    "crates" => 60,
+    "cravat" => 30,
+    "cravats" => 24,
+    "crave" => 27,
+    "craved" => 24,
+    "craving" => 27,
+    "cravings" => 24,
+    "crawl" => 30,
+    "crawled" => 30,
+    "crawler" => 24,
+    "crawling" => 30,
+    "crawls" => 24,
+    "cray" => 24,
+    "craze" => 24,
+    "crazed" => 24,
+    "crazy" => 37,
+    "creak" => 27,
+    "creaked" => 29,
+    "creaking" => 30,
+    "cream" => 34,
+    "creases" => 29,
+    "create" => 57,
+    "created" => 55,
+    "creates" => 53,
+    "creating" => 53,
+    "creation" => 48,
+    "creations" => 27,
+    "creative" => 38,
+    "creativity" => 30,
+    "creator" => 39,
+    "creators" => 34,
+    "creature" => 42,
+    "creatures" => 40,
+    "credential" => 45,
+    "credentials" => 43,
+    "credibility" => 27,
+    "credible" => 27,
+    "credit" => 42,
+    "creditable" => 24,
+    "credited" => 32,
+    "creditor" => 24,
+    "creditors" => 24,
+    "credits" => 43,
+    "credulity" => 24,
+    "credulous" => 24,
+    "creek" => 34,
+    "creeks" => 29,
+    "creep" => 32,
+    "creeping" => 32,
+    "creole" => 30,
+    "crept" => 37,
+    "crescendo" => 24,
+    "crescent" => 24,
+    "crest" => 24,
+    "crestfallen" => 29,
+    "crevice" => 37,
+    "crevices" => 29,
+    "crew" => 35,
+    "crewe" => 24,
+    "cri" => 24,
+    "crib" => 27,
+    "crichton" => 33,
+    "cricket" => 29,
+    "crickets" => 24,
+    "cried" => 47,
+    "cries" => 38,
+    "crime" => 42,
+    "crimes" => 37,
+    "criminal" => 37,
+    "criminality" => 24,
+    "criminals" => 30,
+    "crimson" => 32,
+    "cringe" => 24,
+    "cringing" => 24,
+    "crinkled" => 24,
+    "cripple" => 31,
+    "crippled" => 32,
+    "cripples" => 24,
+    "crises" => 24,
+    "crisis" => 32,
+    "crisp" => 34,
+    "crisply" => 24,
+    "criteria" => 41,
+    "criterion" => 43,
+    "critical" => 46,
+    "criticality" => 27,
+    "critically" => 31,
+    "criticised" => 24,
+    "criticising" => 24,
+    "criticism" => 31,
+    "criticized" => 24,
+    "critics" => 27,
+    "critique" => 24,
+    "critiques" => 27,
+    "croak" => 27,
+    "croaking" => 24,
+    "croatian" => 27,
+    "crocodile" => 24,
+    "crocuses" => 24,
+    "cromarty" => 35,
+    "crony" => 27,
+    "crooked" => 24,
+    "crookedness" => 24,
+    "crooned" => 24,
+    "crop" => 35,
+    "cropped" => 27,
+    "crops" => 24,
+    "croquet" => 33,
+    "cross" => 53,
+    "crossbar" => 24,
+    "crossbeam" => 39,
+    "crossbones" => 24,
+    "crossed" => 40,
+    "crosses" => 33,
+    "crosshatch" => 27,
+    "crossing" => 37,
+    "crossly" => 29,
+    "crossover" => 24,
+    "crossroads" => 32,
+    "crouched" => 29,
+    "crouching" => 24,
+    "crow" => 27,
+    "crowd" => 42,
+    "crowded" => 38,
+    "crowding" => 24,
+    "crowds" => 29,
+    "crown" => 36,
+    "crowned" => 31,
+    "crowns" => 27,
+    "crows" => 24,
+    "crt" => 38,
+    "crucial" => 38,
+    "crucially" => 32,
+    "crucible" => 24,
+    "crucified" => 24,
+    "crud" => 29,
+    "crude" => 29,
+    "crudely" => 24,
+    "crudest" => 24,
+    "cruel" => 33,
+    "cruellest" => 24,
+    "cruelly" => 32,
+    "cruelty" => 32,
+    "crumbled" => 24,
+    "crumbling" => 24,
+    "crumbly" => 24,
+    "crumbs" => 30,
+    "crump" => 27,
+    "crumpled" => 29,
+    "crunch" => 29,
+    "crunched" => 24,
+    "crunching" => 27,
+    "crunchy" => 30,
+    "crusades" => 24,
+    "crush" => 32,
+    "crushed" => 35,
+    "crushing" => 29,
+    "crusoe" => 35,
+    "crust" => 27,
+    "crusted" => 24,
+    "crusts" => 24,
+    "crusty" => 24,
+    "crux" => 24,
+    "cry" => 42,
+    "crying" => 38,
+    "crypt" => 42,
+    "cryptanalysis" => 27,
+    "cryptanalytic" => 27,
+    "cryptic" => 29,
+    "crypto" => 46,
+    "cryptographer" => 27,
+    "cryptographers" => 27,
+    "cryptographic" => 46,
+    "cryptography" => 45,
+    "cryptology" => 24,
+    "crystal" => 33,
+    "crystallised" => 24,
+    "crystals" => 27,
+    "crêpe" => 24,
+    "cub" => 24,
+    "cube" => 41,
+    "cubes" => 32,
+    "cubic" => 35,
+    "cuboids" => 27,
+    "cuckoo" => 34,
+    "cucumber" => 37,
+    "cud" => 24,
+    "cudgelled" => 27,
+    "cue" => 27,
+    "cuff" => 30,
+    "cuffed" => 24,
+    "cuffing" => 24,
+    "cuffs" => 24,
+    "cul" => 31,
+    "cull" => 24,
+    "culled" => 29,
+    "culminated" => 24,
+    "culmination" => 24,
+    "culprit" => 29,
+    "cultivate" => 29,
+    "cultivated" => 31,
+    "cultivating" => 24,
+    "cultivation" => 30,
+    "cultural" => 32,
+    "culturally" => 27,
+    "culture" => 32,
+    "cultured" => 24,
+    "cultures" => 24,
+    "culverts" => 24,
+    "cum" => 24,
+    "cumberland" => 29,
+    "cumbersome" => 36,
+    "cumbrous" => 27,
+    "cumulative" => 34,
+    "cunard" => 29,
+    "cunning" => 33,
+    "cup" => 37,
+    "cupboard" => 32,
+    "cupboards" => 27,
+    "cups" => 30,
+    "cur" => 34,
+    "curators" => 24,
+    "curb" => 30,
+    "curbed" => 24,
+    "curdles" => 24,
+    "cure" => 33,
+    "cured" => 27,
+    "curiosities" => 27,
+    "curiosity" => 40,
+    "curious" => 43,
+    "curiously" => 33,
+    "curl" => 40,
+    "curled" => 34,
+    "curling" => 31,
+    "curls" => 32,
+    "curly" => 44,
+    "currants" => 24,
+    "currencies" => 29,
+    "currency" => 32,
+    "current" => 59,
+    "currently" => 56,
+    "currents" => 30,
+    "curricula" => 27,
+    "curricular" => 24,
+    "curriculum" => 29,
+    "curried" => 24,
+    "curry" => 24,
+    "currying" => 24,
+    "curs" => 30,
+    "curse" => 35,
+    "cursed" => 36,
+    "curses" => 37,
+    "cursing" => 29,
+    "cursive" => 29,
+    "cursor" => 58,
+    "cursors" => 37,
+    "cursory" => 31,
+    "curt" => 27,
+    "curtail" => 24,
+    "curtain" => 33,
+    "curtained" => 24,
+    "curtains" => 33,
+    "curtsey" => 27,
+    "curtseying" => 24,
+    "curtsy" => 24,
+    "curve" => 48,
+    "curved" => 32,
+    "curves" => 42,
+    "curving" => 27,
+    "cushion" => 29,
+    "cushioned" => 24,
+    "cushions" => 27,
+    "cuss" => 24,
+    "cussed" => 27,
+    "custard" => 24,
+    "custodian" => 24,
+    "custody" => 32,
+    "custom" => 55,
+    "customarily" => 24,
+    "customary" => 32,
+    "customer" => 35,
+    "customers" => 35,
+    "customisable" => 30,
+    "customise" => 33,
+    "customised" => 30,
+    "customising" => 27,
+    "customizable" => 41,
+    "customization" => 43,
+    "customizations" => 32,
+    "customize" => 45,
+    "customized" => 43,
+    "customizes" => 30,
+    "customizing" => 40,
+    "customs" => 32,
+    "cut" => 46,
+    "cute" => 30,
+    "cutlass" => 29,
+    "cutler" => 24,
+    "cutoff" => 24,
+    "cuts" => 30,
+    "cutter" => 29,
+    "cutting" => 36,
+    "cuttings" => 24,
+    "cwt" => 29,
+    "cyan" => 34,
+    "cybernetics" => 24,
+    "cycle" => 44,
+    "cycles" => 41,
+    "cyclic" => 38,
+    "cyclical" => 27,
+    "cyclically" => 27,
+    "cycling" => 29,
+    "cyclone" => 24,
+    "cylinder" => 24,
+    "cylinders" => 29,
+    "cylindrical" => 29,
+    "cymbals" => 30,
+    "cynical" => 29,
+    "cynically" => 24,
+    "cypher" => 30,
+    "cypress" => 24,
+    "cyril" => 24,
+    "cyrillic" => 44,
+    "czech" => 34,
+    "dabble" => 24,
+    "dabbled" => 24,
+    "dad" => 34,
+    "daddy" => 29,
+    "daemon" => 39,
+    "daemons" => 33,
+    "dagger" => 33,
+    "daily" => 40,
+    "dainties" => 24,
+    "daintiest" => 24,
+    "daintily" => 24,
+    "dainty" => 30,
+    "dairy" => 24,
+    "daisies" => 24,
+    "daisy" => 46,
+    "dakota" => 24,
+    "dal" => 24,
+    "dale" => 24,
+    "dalek" => 40,
+    "daleks" => 33,
+    "dalton" => 24,
+    "dam" => 27,
+    "damage" => 32,
+    "damaged" => 29,
+    "damages" => 39,
+    "damaging" => 32,
+    "damascened" => 24,
+    "dames" => 24,
+    "dammit" => 24,
+    "damn" => 31,
+    "damned" => 31,
+    "damning" => 27,
+    "damp" => 35,
+    "dampened" => 24,
+    "damper" => 24,
+    "damps" => 29,
+    "damsel" => 24,
+    "dan" => 41,
+    "dana" => 27,
+    "dance" => 38,
+    "danced" => 30,
+    "dancers" => 24,
+    "dances" => 29,
+    "dancing" => 35,
+    "dander" => 24,
+    "dane" => 24,
+    "danelaw" => 24,
+    "danger" => 41,
+    "dangerous" => 41,
+    "dangerously" => 29,
+    "dangers" => 35,
+    "dangle" => 34,
+    "dangled" => 27,
+    "dangles" => 24,
+    "dangling" => 44,
+    "daniel" => 44,
+    "danish" => 34,
+    "dank" => 29,
+    "danny" => 27,
+    "dante" => 27,
+    "dap" => 27,
+    "dare" => 39,
+    "dared" => 38,
+    "dares" => 29,
+    "daring" => 35,
+    "dark" => 47,
+    "darken" => 24,
+    "darkened" => 32,
+    "darkening" => 30,
+    "darker" => 32,
+    "darkest" => 24,
+    "darkness" => 41,
+    "darling" => 34,
+    "darlington" => 24,
+    "darn" => 27,
+    "dart" => 42,
+    "darted" => 33,
+    "darting" => 29,
+    "dartmouth" => 24,
+    "darwin" => 41,
+    "dash" => 42,
+    "dashboard" => 35,
+    "dashboards" => 30,
+    "dashed" => 36,
+    "dasher" => 24,
+    "dashes" => 40,
+    "dashing" => 34,
+    "dat" => 36,
+    "data" => 61,
+    "database" => 50,
+    "databases" => 42,
+    "dataset" => 41,
+    "date" => 50,
+    "dated" => 33,
+    "dates" => 42,
+    "dating" => 30,
+    "dative" => 24,
+    "daubed" => 24,
+    "daubing" => 24,
+    "daughter" => 42,
+    "daunted" => 24,
+    "daunting" => 24,
+    "dauntless" => 29,
+    "dave" => 35,
+    "davenport" => 24,
+    "david" => 45,
+    "davidson" => 27,
+    "davis" => 24,
+    "davit" => 27,
+    "daw" => 35,
+    "dawdling" => 24,
+    "dawn" => 37,
+    "dawned" => 33,
+    "day" => 52,
+    "daybreak" => 27,
+    "daydreams" => 27,
+    "daylight" => 38,
+    "days" => 48,
+    "daytime" => 32,
+    "daze" => 24,
+    "dazed" => 30,
+    "dazzled" => 24,
+    "dazzling" => 33,
+    "deactivate" => 34,
+    "deactivated" => 32,
+    "deactivates" => 24,
+    "deactivating" => 24,
+    "deactivation" => 24,
+    "dead" => 48,
+    "deadliest" => 27,
+    "deadline" => 36,
+    "deadlines" => 29,
+    "deadlock" => 41,
+    "deadlocked" => 29,
+    "deadlocking" => 24,
+    "deadlocks" => 36,
+    "deadly" => 38,
+    "deaf" => 32,
+    "deafen" => 24,
+    "deafening" => 31,
+    "deal" => 47,
+    "dealer" => 30,
+    "dealers" => 24,
+    "dealing" => 45,
+    "dealings" => 33,
+    "deallocate" => 39,
+    "deallocated" => 40,
+    "deallocates" => 32,
+    "deallocating" => 34,
+    "deals" => 37,
+    "dealt" => 33,
+    "dean" => 24,
+    "dear" => 47,
+    "dearer" => 31,
+    "dearest" => 37,
+    "dearly" => 31,
+    "dears" => 29,
+    "dearth" => 29,
+    "death" => 46,
+    "deathbed" => 27,
+    "deathless" => 24,
+    "deathlike" => 24,
+    "deaths" => 31,
+    "deathtrap" => 24,
+    "deb" => 29,
+    "debar" => 24,
+    "debarred" => 24,
+    "debasement" => 27,
+    "debasing" => 24,
+    "debatable" => 27,
+    "debate" => 33,
+    "debated" => 27,
+    "debauchee" => 24,
+    "debilitated" => 24,
+    "debility" => 27,
+    "debit" => 24,
+    "debiting" => 24,
+    "debris" => 24,
+    "debt" => 32,
+    "debtor" => 24,
+    "debts" => 34,
+    "debug" => 51,
+    "debugged" => 36,
+    "debugger" => 49,
+    "debuggers" => 38,
+    "debugging" => 50,
+    "debunked" => 24,
+    "decade" => 35,
+    "decades" => 33,
+    "decamped" => 27,
+    "decanter" => 24,
+    "decanters" => 24,
+    "decay" => 33,
+    "decayed" => 27,
+    "decaying" => 27,
+    "decease" => 24,
+    "deceased" => 30,
+    "deceit" => 29,
+    "deceive" => 24,
+    "deceived" => 32,
+    "december" => 39,
+    "decencies" => 24,
+    "decency" => 27,
+    "decent" => 37,
+    "decently" => 30,
+    "decentralized" => 32,
+    "deception" => 24,
+    "deceptive" => 24,
+    "deceptively" => 24,
+    "decide" => 46,
+    "decided" => 43,
+    "decidedly" => 34,
+    "decides" => 38,
+    "deciding" => 40,
+    "decimal" => 48,
+    "decimals" => 39,
+    "decipher" => 35,
+    "deciphered" => 24,
+    "deciphering" => 29,
+    "decision" => 42,
+    "decisions" => 42,
+    "decisive" => 27,
+    "decisively" => 31,
+    "deck" => 40,
+    "decked" => 27,
+    "decking" => 24,
+    "decks" => 24,
+    "declamatory" => 27,
+    "declarable" => 24,
+    "declaration" => 49,
+    "declarations" => 48,
+    "declarative" => 43,
+    "declaratively" => 32,
+    "declarator" => 27,
+    "declare" => 47,
+    "declared" => 49,
+    "declares" => 39,
+    "declaring" => 44,
+    "decline" => 33,
+    "declined" => 35,
+    "declining" => 29,
+    "declivity" => 24,
+    "deco" => 30,
+    "decode" => 48,
+    "decoded" => 41,
+    "decoder" => 45,
+    "decoders" => 38,
+    "decodes" => 40,
+    "decoding" => 49,
+    "decomposable" => 24,
+    "decompose" => 27,
+    "decomposed" => 32,
+    "decomposes" => 32,
+    "decomposing" => 27,
+    "decomposition" => 35,
+    "decompress" => 40,
+    "decompressed" => 34,
+    "decompresses" => 33,
+    "decompressing" => 38,
+    "decompression" => 42,
+    "decompressions" => 24,
+    "decompressor" => 37,
+    "deconstruct" => 27,
+    "deconstructing" => 24,
+    "decorate" => 32,
+    "decorated" => 37,
+    "decorates" => 27,
+    "decorating" => 30,
+    "decoration" => 29,
+    "decorations" => 34,
+    "decorator" => 36,
+    "decorators" => 37,
+    "decorum" => 33,
+    "decouple" => 27,
+    "decoy" => 24,
+    "decoyed" => 29,
+    "decrease" => 38,
+    "decreased" => 33,
+    "decreases" => 35,
+    "decreasing" => 33,
+    "decreed" => 27,
+    "decrement" => 41,
+    "decremented" => 33,
+    "decrementing" => 34,
+    "decrements" => 32,
+    "decrepit" => 24,
+    "decrepitude" => 24,
+    "decrypt" => 38,
+    "decrypted" => 35,
+    "decrypting" => 29,
+    "decryption" => 40,
+    "decrypts" => 29,
+    "dedicate" => 29,
+    "dedicated" => 43,
+    "dedication" => 24,
+    "deduce" => 36,
+    "deduced" => 32,
+    "deduces" => 29,
+    "deduct" => 24,
+    "deducted" => 24,
+    "deductible" => 27,
+    "deduction" => 32,
+    "deductions" => 30,
+    "deductive" => 27,
+    "deductively" => 24,
+    "deed" => 37,
+    "deeds" => 29,
+    "deem" => 32,
+    "deemed" => 36,
+    "deems" => 24,
+    "deep" => 46,
+    "deepened" => 30,
+    "deepening" => 30,
+    "deeper" => 40,
+    "deepest" => 35,
+    "deeply" => 42,
+    "deeps" => 27,
+    "deer" => 29,
+    "def" => 48,
+    "default" => 62,
+    "defaulted" => 37,
+    "defaulting" => 36,
+    "defaults" => 50,
+    "defeat" => 29,
+    "defeated" => 27,
+    "defeating" => 30,
+    "defeats" => 30,
+    "defect" => 27,
+    "defective" => 27,
+    "defects" => 32,
+    "defence" => 33,
+    "defend" => 34,
+    "defended" => 29,
+    "defending" => 27,
+    "defense" => 33,
+    "defenses" => 24,
+    "defensive" => 29,
+    "defer" => 36,
+    "deference" => 29,
+    "deferral" => 24,
+    "deferred" => 40,
+    "deferring" => 31,
+    "defers" => 29,
+    "defiance" => 30,
+    "defiant" => 29,
+    "defiantly" => 29,
+    "deficiencies" => 35,
+    "deficiency" => 27,
+    "deficient" => 24,
+    "defies" => 24,
+    "defiles" => 30,
+    "definable" => 27,
+    "define" => 54,
+    "defined" => 59,
+    "defines" => 51,
+    "defining" => 49,
+    "definite" => 36,
+    "definitely" => 42,
+    "definiteness" => 24,
+    "definition" => 52,
+    "definitions" => 50,
+    "definitive" => 34,
+    "definitively" => 27,
+    "deflate" => 42,
+    "deflated" => 27,
+    "deflates" => 24,
+    "deflating" => 24,
+    "deflation" => 24,
+    "deflections" => 24,
+    "deformed" => 32,
+    "deformity" => 33,
+    "defray" => 24,
+    "deft" => 27,
+    "deftly" => 24,
+    "defunct" => 29,
+    "defuses" => 24,
+    "defy" => 27,
+    "degenerate" => 33,
+    "degenerating" => 27,
+    "degradation" => 32,
+    "degrade" => 31,
+    "degraded" => 32,
+    "degrades" => 27,
+    "degrading" => 24,
+    "degree" => 43,
+    "degrees" => 39,
+    "deign" => 24,
+    "deities" => 29,
+    "deity" => 31,
+    "dejected" => 29,
+    "dejection" => 29,
+    "del" => 43,
+    "delaware" => 24,
+    "delay" => 46,
+    "delayed" => 40,
+    "delaying" => 34,
+    "delays" => 40,
+    "delectable" => 24,
+    "delegate" => 39,
+    "delegated" => 37,
+    "delegates" => 36,
+    "delegating" => 33,
+    "delegation" => 34,
+    "delegations" => 27,
+    "delete" => 53,
+    "deleted" => 49,
+    "deletes" => 45,
+    "deleting" => 47,
+    "deletion" => 40,
+    "deletions" => 33,
+    "deliberate" => 33,
+    "deliberately" => 38,
+    "deliberations" => 24,
+    "delicacy" => 32,
+    "delicate" => 37,
+    "delicately" => 31,
+    "delicatessen" => 24,
+    "delicious" => 32,
+    "delight" => 41,
+    "delighted" => 38,
+    "delightful" => 35,
+    "delights" => 31,
+    "delimit" => 31,
+    "delimitation" => 27,
+    "delimited" => 42,
+    "delimiter" => 41,
+    "delimiters" => 41,
+    "delimits" => 29,
+    "delineate" => 27,
+    "delineated" => 29,
+    "delinquent" => 24,
+    "delirious" => 30,
+    "delirium" => 34,
+    "deliver" => 37,
+    "deliverable" => 24,
+    "delivered" => 40,
+    "deliverer" => 31,
+    "deliverers" => 24,
+    "delivering" => 31,
+    "delivers" => 32,
+    "delivery" => 39,
+    "dell" => 24,
+    "delphi" => 30,
+    "delta" => 38,
+    "deltas" => 29,
+    "deluded" => 27,
+    "deluge" => 27,
+    "delusion" => 31,
+    "deluxe" => 33,
+    "delve" => 24,
+    "demaine" => 24,
+    "demand" => 42,
+    "demanded" => 39,
+    "demanding" => 31,
+    "demands" => 34,
+    "demeanour" => 30,
+    "demerit" => 24,
+    "demo" => 45,
+    "democracy" => 24,
+    "demographic" => 24,
+    "demolition" => 24,
+    "demon" => 24,
+    "demoniac" => 24,
+    "demoniacal" => 29,
+    "demonstrable" => 27,
+    "demonstrably" => 24,
+    "demonstrate" => 43,
+    "demonstrated" => 42,
+    "demonstrates" => 44,
+    "demonstrating" => 39,
+    "demonstration" => 40,
+    "demonstrations" => 29,
+    "demos" => 38,
+    "demoted" => 33,
+    "demoting" => 29,
+    "demotion" => 33,
+    "demurely" => 24,
+    "den" => 37,
+    "denial" => 38,
+    "denied" => 39,
+    "denies" => 32,
+    "denis" => 27,
+    "denizen" => 24,
+    "dennis" => 31,
+    "denominator" => 32,
+    "denominators" => 29,
+    "denote" => 38,
+    "denoted" => 37,
+    "denotes" => 39,
+    "denoting" => 35,
+    "denounce" => 24,
+    "denouncing" => 24,
+    "dens" => 27,
+    "dense" => 38,
+    "densely" => 31,
+    "density" => 32,
+    "dental" => 30,
+    "dentist" => 24,
+    "denver" => 27,
+    "deny" => 42,
+    "denying" => 34,
+    "depart" => 35,
+    "departed" => 38,
+    "departing" => 27,
+    "department" => 37,
+    "departments" => 33,
+    "departs" => 29,
+    "departure" => 40,
+    "depend" => 50,
+    "dependable" => 27,
+    "dependably" => 24,
+    "dependant" => 29,
+    "dependants" => 27,
+    "depended" => 37,
+    "dependence" => 32,
+    "dependencies" => 56,
+    "dependency" => 56,
+    "dependent" => 46,
+    "dependents" => 34,
+    "depending" => 51,
+    "depends" => 52,
+    "depicted" => 27,
+    "depicts" => 24,
+    "deplorable" => 24,
+    "deplorably" => 24,
+    "deplored" => 27,
+    "deploy" => 40,
+    "deployed" => 38,
+    "deploying" => 36,
+    "deployment" => 40,
+    "deployments" => 35,
+    "deportment" => 24,
+    "depose" => 24,
+    "deposed" => 30,
+    "deposes" => 24,
+    "deposit" => 37,
+    "deposited" => 35,
+    "depositing" => 24,
+    "deposition" => 30,
+    "depositor" => 24,
+    "depositors" => 24,
+    "depository" => 27,
+    "deposits" => 24,
+    "depot" => 31,
+    "depraved" => 27,
+    "depravity" => 24,
+    "deprecate" => 35,
+    "deprecated" => 52,
+    "deprecates" => 36,
+    "deprecating" => 32,
+    "deprecation" => 43,
+    "deprecations" => 38,
+    "depressed" => 32,
+    "depressing" => 29,
+    "depression" => 33,
+    "depressions" => 24,
+    "deprive" => 27,
+    "deprived" => 36,
+    "deprives" => 24,
+    "depth" => 45,
+    "depths" => 36,
+    "deputation" => 24,
+    "deputy" => 27,
+    "derail" => 27,
+    "derange" => 24,
+    "deranged" => 32,
+    "derbies" => 24,
+    "derby" => 24,
+    "dereference" => 45,
+    "derek" => 30,
+    "derision" => 29,
+    "derivable" => 37,
+    "derivation" => 41,
+    "derivations" => 32,
+    "derivative" => 37,
+    "derivatives" => 36,
+    "derive" => 53,
+    "derived" => 48,
+    "derives" => 44,
+    "deriving" => 43,
+    "derrick" => 29,
+    "des" => 38,
+    "descend" => 35,
+    "descendant" => 29,
+    "descendants" => 35,
+    "descended" => 39,
+    "descendent" => 31,
+    "descender" => 24,
+    "descending" => 35,
+    "descends" => 34,
+    "descent" => 38,
+    "describable" => 24,
+    "describe" => 45,
+    "described" => 50,
+    "describes" => 47,
+    "describing" => 45,
+    "description" => 50,
+    "descriptions" => 42,
+    "descriptive" => 39,
+    "descriptor" => 48,
+    "descriptors" => 44,
+    "deselected" => 24,
+    "deselecting" => 27,
+    "deserialize" => 46,
+    "desert" => 36,
+    "deserted" => 36,
+    "deserting" => 27,
+    "desertion" => 24,
+    "deserts" => 33,
+    "deserve" => 34,
+    "deserved" => 32,
+    "deserves" => 32,
+    "deserving" => 31,
+    "desiderata" => 27,
+    "desideratum" => 29,
+    "design" => 50,
+    "designate" => 35,
+    "designated" => 38,
+    "designates" => 27,
+    "designating" => 27,
+    "designation" => 29,
+    "designator" => 29,
+    "designators" => 31,
+    "designed" => 50,
+    "designer" => 29,
+    "designers" => 29,
+    "designing" => 38,
+    "designs" => 39,
+    "desirability" => 29,
+    "desirable" => 42,
+    "desire" => 43,
+    "desired" => 48,
+    "desires" => 36,
+    "desiring" => 30,
+    "desirous" => 32,
+    "desk" => 37,
+    "desks" => 30,
+    "desktop" => 45,
+    "desktops" => 24,
+    "desolate" => 35,
+    "desolated" => 27,
+    "desolating" => 24,
+    "desolation" => 33,
+    "despair" => 42,
+    "despaired" => 27,
+    "despairing" => 31,
+    "despairingly" => 24,
+    "desperate" => 35,
+    "desperately" => 32,
+    "desperation" => 29,
+    "despicable" => 29,
+    "despise" => 29,
+    "despised" => 31,
+    "despising" => 24,
+    "despite" => 44,
+    "despond" => 24,
+    "despondence" => 27,
+    "despondency" => 31,
+    "despondent" => 24,
+    "despotic" => 24,
+    "destination" => 46,
+    "destinations" => 36,
+    "destined" => 33,
+    "destiny" => 36,
+    "destitute" => 27,
+    "destroy" => 43,
+    "destroyed" => 46,
+    "destroyer" => 32,
+    "destroying" => 38,
+    "destroys" => 33,
+    "destruct" => 24,
+    "destruction" => 41,
+    "destructive" => 33,
+    "destructor" => 46,
+    "desultory" => 24,
+    "desynchronise" => 24,
+    "detach" => 36,
+    "detachable" => 29,
+    "detached" => 40,
+    "detaches" => 27,
+    "detaching" => 29,
+    "detachment" => 30,
+    "detail" => 48,
+    "detailed" => 48,
+    "detailing" => 36,
+    "details" => 57,
+    "detain" => 32,
+    "detained" => 32,
+    "detaining" => 24,
+    "detect" => 49,
+    "detectable" => 24,
+    "detected" => 47,
+    "detecting" => 43,
+    "detection" => 49,
+    "detective" => 43,
+    "detectives" => 34,
+    "detector" => 34,
+    "detectors" => 27,
+    "detects" => 48,
+    "deteriorated" => 30,
+    "deteriorates" => 27,
+    "deteriorating" => 32,
+    "deterioration" => 27,
+    "determinable" => 24,
+    "determinant" => 24,
+    "determination" => 37,
+    "determine" => 49,
+    "determined" => 48,
+    "determinedly" => 24,
+    "determiner" => 29,
+    "determiners" => 32,
+    "determines" => 46,
+    "determining" => 43,
+    "determinism" => 33,
+    "deterministic" => 46,
+    "detest" => 24,
+    "detestable" => 27,
+    "detestation" => 29,
+    "detested" => 31,
+    "detour" => 29,
+    "detract" => 24,
+    "detracted" => 24,
+    "detracts" => 24,
+    "detrimental" => 27,
+    "detroit" => 31,
+    "deuce" => 27,
+    "devaluation" => 30,
+    "develop" => 43,
+    "developed" => 47,
+    "developer" => 47,
+    "developers" => 49,
+    "developing" => 44,
+    "development" => 53,
+    "developmental" => 27,
+    "developments" => 32,
+    "develops" => 31,
+    "deviate" => 27,
+    "deviated" => 24,
+    "deviates" => 30,
+    "deviating" => 29,
+    "deviation" => 32,
+    "deviations" => 27,
+    "device" => 48,
+    "devices" => 45,
+    "devil" => 39,
+    "devil's" => 24,
+    "devilish" => 30,
+    "devilment" => 24,
+    "devils" => 34,
+    "devise" => 29,
+    "devised" => 32,
+    "devising" => 24,
+    "devoid" => 29,
+    "devolved" => 29,
+    "devonshire" => 24,
+    "devote" => 33,
+    "devoted" => 39,
+    "devotedly" => 27,
+    "devotees" => 24,
+    "devoting" => 31,
+    "devotion" => 33,
+    "devoured" => 32,
+    "devouring" => 33,
+    "dew" => 30,
+    "dewar" => 33,
+    "dewdrops" => 27,
+    "dews" => 27,
+    "dewy" => 27,
+    "dexterous" => 24,
+    "diabetes" => 27,
+    "diabolical" => 24,
+    "diabolically" => 24,
+    "diacritic" => 27,
+    "diacritics" => 29,
+    "diadem" => 24,
+    "diaeresis" => 37,
+    "diagnose" => 33,
+    "diagnosed" => 27,
+    "diagnostic" => 48,
+    "diagnostics" => 47,
+    "diagonal" => 30,
+    "diagonally" => 27,
+    "diagram" => 39,
+    "diagrams" => 36,
+    "dial" => 29,
+    "dialect" => 40,
+    "dialectal" => 24,
+    "dialects" => 40,
+    "dialog" => 48,
+    "dialogue" => 31,
+    "dialogues" => 27,
+    "dials" => 24,
+    "diamond" => 43,
+    "diamonds" => 32,
+    "diana" => 27,
+    "diane" => 24,
+    "diary" => 32,
+    "diatomic" => 29,
+    "dice" => 35,
+    "dichotomy" => 24,
+    "dickens" => 30,
+    "dickey" => 24,
+    "dickinson" => 29,
+    "dictate" => 33,
+    "dictated" => 30,
+    "dictates" => 32,
+    "dictator" => 33,
+    "dictionaries" => 42,
+    "dictionary" => 50,
+    "dictum" => 24,
+    "did" => 54,
+    "didn't" => 53,
+    "die" => 42,
+    "died" => 43,
+    "diego" => 29,
+    "diem" => 36,
+    "dies" => 33,
+    "diesel" => 42,
+    "diesels" => 24,
+    "diet" => 27,
+    "dietrich" => 24,
+    "differ" => 47,
+    "differed" => 32,
+    "difference" => 50,
+    "differences" => 50,
+    "different" => 58,
+    "differential" => 33,
+    "differentiate" => 36,
+    "differentiated" => 32,
+    "differentiates" => 29,
+    "differentiating" => 30,
+    "differentiation" => 34,
+    "differently" => 47,
+    "differing" => 36,
+    "differs" => 44,
+    "difficult" => 48,
+    "difficulties" => 38,
+    "difficulty" => 41,
+    "diffidence" => 24,
+    "diffident" => 27,
+    "diffused" => 29,
+    "diffusers" => 24,
+    "diffusing" => 24,
+    "diffusion" => 36,
+    "dig" => 40,
+    "digest" => 42,
+    "digestible" => 27,
+    "digesting" => 24,
+    "digests" => 37,
+    "digger" => 24,
+    "digging" => 35,
+    "diggings" => 27,
+    "digit" => 48,
+    "digital" => 46,
+    "digitally" => 30,
+    "digitization" => 29,
+    "digitize" => 31,
+    "digitized" => 34,
+    "digitizing" => 36,
+    "digits" => 47,
+    "dignified" => 32,
+    "dignitaries" => 24,
+    "dignity" => 34,
+    "digraph" => 45,
+    "digraphs" => 43,
+    "digs" => 30,
+    "dilapidated" => 24,
+    "dilate" => 24,
+    "dilation" => 29,
+    "dilatoriness" => 24,
+    "dilatory" => 29,
+    "dilemma" => 27,
+    "diligence" => 32,
+    "diligent" => 29,
+    "diligently" => 30,
+    "diluted" => 24,
+    "dim" => 40,
+    "dime" => 24,
+    "dimension" => 36,
+    "dimensional" => 40,
+    "dimensioned" => 24,
+    "dimensions" => 40,
+    "dimes" => 24,
+    "diminish" => 27,
+    "diminished" => 34,
+    "diminishing" => 31,
+    "diminutive" => 24,
+    "dimly" => 34,
+    "dimmed" => 31,
+    "dimming" => 24,
+    "dimpled" => 24,
+    "dimples" => 24,
+    "din" => 30,
+    "dine" => 24,
+    "dined" => 30,
+    "diners" => 24,
+    "ding" => 27,
+    "dingy" => 24,
+    "dining" => 33,
+    "dinner" => 41,
+    "dinosaur" => 24,
+    "dint" => 24,
+    "dip" => 32,
+    "diphthongs" => 24,
+    "diploma" => 24,
+    "diplomacy" => 31,
+    "diplomat" => 24,
+    "diplomatic" => 24,
+    "dipped" => 33,
+    "dipping" => 24,
+    "dire" => 30,
+    "direct" => 48,
+    "directed" => 41,
+    "directing" => 24,
+    "direction" => 46,
+    "directional" => 34,
+    "directionality" => 34,
+    "directions" => 39,
+    "directive" => 41,
+    "directives" => 43,
+    "directly" => 55,
+    "director" => 41,
+    "directories" => 51,
+    "directors" => 31,
+    "directory" => 59,
+    "directs" => 27,
+    "dirge" => 24,
+    "dirk" => 29,
+    "dirt" => 31,
+    "dirtiness" => 24,
+    "dirty" => 40,
+    "disability" => 24,
+    "disable" => 52,
+    "disabled" => 51,
+    "disables" => 44,
+    "disabling" => 45,
+    "disadvantage" => 40,
+    "disadvantages" => 37,
+    "disagree" => 34,
+    "disagreeable" => 27,
+    "disagreed" => 24,
+    "disagreement" => 27,
+    "disagreements" => 27,
+    "disallow" => 44,
+    "disallowed" => 39,
+    "disallowing" => 29,
+    "disallows" => 35,
+    "disambiguate" => 38,
+    "disambiguated" => 31,
+    "disambiguating" => 34,
+    "disambiguation" => 35,
+    "disappear" => 39,
+    "disappearance" => 35,
+    "disappeared" => 42,
+    "disappearing" => 31,
+    "disappears" => 37,
+    "disappoint" => 27,
+    "disappointed" => 35,
+    "disappointment" => 39,
+    "disappointments" => 24,
+    "disapproval" => 24,
+    "disapprove" => 24,
+    "disapproved" => 27,
+    "disapproving" => 24,
+    "disarray" => 24,
+    "disassemble" => 42,
+    "disassembled" => 24,
+    "disassembler" => 32,
+    "disassembles" => 27,
+    "disassembling" => 27,
+    "disassembly" => 33,
+    "disassociate" => 24,
+    "disassociated" => 29,
+    "disassociates" => 29,
+    "disassociating" => 24,
+    "disaster" => 33,
+    "disasters" => 27,
+    "disastrous" => 29,
+    "disbelief" => 24,
+    "disbelieve" => 24,
+    "disc" => 32,
+    "discard" => 41,
+    "discarded" => 42,
+    "discarding" => 39,
+    "discards" => 35,
+    "discern" => 27,
+    "discerned" => 24,
+    "discernible" => 32,
+    "discerning" => 27,
+    "discernment" => 24,
+    "discharge" => 34,
+    "discharged" => 30,
+    "discharging" => 29,
+    "disciple" => 29,
+    "disciples" => 31,
+    "disciplinary" => 29,
+    "discipline" => 37,
+    "disciplined" => 24,
+    "disciplines" => 36,
+    "disciplining" => 24,
+    "disclaim" => 29,
+    "disclaimed" => 24,
+    "disclaimer" => 39,
+    "disclaimers" => 27,
+    "disclaims" => 30,
+    "disclose" => 30,
+    "disclosed" => 30,
+    "discloses" => 24,
+    "disclosing" => 27,
+    "disclosure" => 33,
+    "disco" => 24,
+    "discoloured" => 31,
+    "discomfort" => 31,
+    "discomforted" => 24,
+    "discomforting" => 24,
+    "discomforts" => 24,
+    "discompose" => 24,
+    "disconcert" => 24,
+    "disconcerted" => 27,
+    "disconcerting" => 24,
+    "disconnect" => 38,
+    "disconnected" => 41,
+    "disconnecting" => 30,
+    "disconnection" => 32,
+    "disconnects" => 34,
+    "disconsolate" => 29,
+    "discontent" => 27,
+    "discontented" => 29,
+    "discontinue" => 24,
+    "discontinued" => 24,
+    "discontinues" => 24,
+    "discontinuities" => 27,
+    "discontinuous" => 27,
+    "discord" => 50,
+    "discordant" => 29,
+    "discount" => 29,
+    "discourage" => 29,
+    "discouraged" => 41,
+    "discourages" => 24,
+    "discouraging" => 29,
+    "discourse" => 36,
+    "discover" => 43,
+    "discoverable" => 24,
+    "discovered" => 44,
+    "discoverer" => 24,
+    "discoverers" => 24,
+    "discoveries" => 35,
+    "discovering" => 41,
+    "discovers" => 24,
+    "discovery" => 45,
+    "discreet" => 30,
+    "discreetly" => 27,
+    "discrepancies" => 27,
+    "discrepancy" => 29,
+    "discrete" => 38,
+    "discretely" => 24,
+    "discretion" => 36,
+    "discriminant" => 44,
+    "discriminate" => 27,
+    "discriminated" => 24,
+    "discriminates" => 24,
+    "discriminating" => 27,
+    "discrimination" => 27,
+    "discriminator" => 37,
+    "discriminators" => 24,
+    "discuss" => 46,
+    "discussed" => 46,
+    "discusses" => 37,
+    "discussing" => 35,
+    "discussion" => 47,
+    "discussions" => 44,
+    "disdain" => 32,
+    "disdaining" => 24,
+    "disease" => 31,
+    "disembark" => 24,
+    "disembarked" => 32,
+    "disembodied" => 24,
+    "disencumbered" => 24,
+    "disentangle" => 24,
+    "disentangled" => 24,
+    "disfigured" => 24,
+    "disgorges" => 27,
+    "disgrace" => 33,
+    "disgraced" => 24,
+    "disgraceful" => 27,
+    "disguise" => 35,
+    "disguised" => 30,
+    "disguises" => 24,
+    "disgust" => 33,
+    "disgusted" => 29,
+    "disgusting" => 24,
+    "dish" => 33,
+    "dishes" => 31,
+    "dishevelled" => 27,
+    "dishonest" => 24,
+    "dishonesty" => 24,
+    "dishonourable" => 24,
+    "dishonoured" => 29,
+    "dishwashers" => 24,
+    "disinclined" => 27,
+    "disinformation" => 27,
+    "disingenuous" => 24,
+    "disintegrating" => 24,
+    "disinterested" => 24,
+    "disjoint" => 39,
+    "disjointed" => 27,
+    "disjunction" => 24,
+    "disk" => 48,
+    "disks" => 34,
+    "dislike" => 33,
+    "disliked" => 30,
+    "dislikes" => 24,
+    "dismal" => 35,
+    "dismally" => 29,
+    "dismantled" => 30,
+    "dismay" => 31,
+    "dismayed" => 27,
+    "dismembered" => 24,
+    "dismissal" => 24,
+    "dismissed" => 33,
+    "dismissing" => 24,
+    "dismount" => 24,
+    "dismounted" => 24,
+    "disobey" => 27,
+    "disorder" => 31,
+    "disordered" => 27,
+    "disorderly" => 27,
+    "disorganised" => 24,
+    "disorganized" => 24,
+    "disown" => 24,
+    "disowned" => 27,
+    "disparage" => 24,
+    "disparate" => 29,
+    "dispatch" => 44,
+    "dispatched" => 38,
+    "dispatcher" => 34,
+    "dispatches" => 29,
+    "dispatching" => 37,
+    "dispel" => 29,
+    "dispelled" => 24,
+    "dispelling" => 27,
+    "dispense" => 24,
+    "dispensed" => 27,
+    "dispersed" => 30,
+    "displacement" => 33,
+    "display" => 53,
+    "displayable" => 27,
+    "displayed" => 51,
+    "displaying" => 45,
+    "displays" => 47,
+    "displeases" => 24,
+    "disposable" => 33,
+    "disposal" => 33,
+    "dispose" => 34,
+    "disposed" => 31,
+    "disposes" => 24,
+    "disposing" => 24,
+    "disposition" => 36,
+    "dispositions" => 32,
+    "disproportionate" => 24,
+    "disproportionately" => 24,
+    "disputatious" => 24,
+    "dispute" => 32,
+    "disputed" => 29,
+    "disputes" => 27,
+    "disputing" => 24,
+    "disqualification" => 24,
+    "disqualifies" => 29,
+    "disqualify" => 24,
+    "disquiet" => 24,
+    "disquieted" => 24,
+    "disquieting" => 27,
+    "disquisition" => 24,
+    "disregard" => 35,
+    "disregarded" => 24,
+    "disregarding" => 31,
+    "disregards" => 30,
+    "disrepair" => 24,
+    "disreputable" => 29,
+    "disrespect" => 27,
+    "disrupt" => 24,
+    "disruption" => 30,
+    "disruptions" => 29,
+    "disruptive" => 30,
+    "dissatisfaction" => 24,
+    "dissatisfied" => 30,
+    "dissect" => 27,
+    "dissected" => 24,
+    "dissecting" => 24,
+    "dissects" => 24,
+    "disseminate" => 31,
+    "disseminated" => 27,
+    "disseminating" => 30,
+    "dissemination" => 38,
+    "disseminators" => 24,
+    "dissension" => 24,
+    "dissent" => 24,
+    "dissertation" => 27,
+    "dissimilar" => 30,
+    "dissimilarities" => 24,
+    "dissimilarity" => 27,
+    "dissipate" => 32,
+    "dissipated" => 29,
+    "dissipates" => 24,
+    "dissociated" => 24,
+    "dissociating" => 24,
+    "dissoluble" => 24,
+    "dissolute" => 24,
+    "dissolve" => 29,
+    "dissolved" => 24,
+    "dissolving" => 24,
+    "dissuade" => 24,
+    "distaff" => 24,
+    "distance" => 46,
+    "distances" => 34,
+    "distant" => 38,
+    "distaste" => 27,
+    "distasteful" => 24,
+    "distemper" => 24,
+    "distill" => 27,
+    "distilled" => 24,
+    "distinct" => 45,
+    "distinction" => 42,
+    "distinctions" => 32,
+    "distinctive" => 31,
+    "distinctly" => 35,
+    "distinctness" => 24,
+    "distinguish" => 43,
+    "distinguishable" => 30,
+    "distinguished" => 41,
+    "distinguishes" => 36,
+    "distinguishing" => 35,
+    "distorted" => 33,
+    "distortion" => 27,
+    "distortions" => 27,
+    "distorts" => 24,
+    "distract" => 27,
+    "distracting" => 32,
+    "distraction" => 32,
+    "distraught" => 24,
+    "distress" => 36,
+    "distressed" => 27,
+    "distressing" => 27,
+    "distributable" => 32,
+    "distribute" => 41,
+    "distributed" => 51,
+    "distributes" => 33,
+    "distributing" => 38,
+    "distribution" => 47,
+    "distributions" => 42,
+    "distributive" => 27,
+    "distributor" => 27,
+    "distributors" => 24,
+    "district" => 37,
+    "distrust" => 29,
+    "distrusted" => 27,
+    "distrustful" => 24,
+    "disturb" => 36,
+    "disturbance" => 32,
+    "disturbances" => 24,
+    "disturbed" => 38,
+    "disturbing" => 30,
+    "disturbs" => 24,
+    "disulfide" => 24,
+    "disunion" => 24,
+    "disuse" => 24,
+    "dit" => 36,
+    "ditch" => 33,
+    "dither" => 24,
+    "dithered" => 27,
+    "dithering" => 36,
+    "dithers" => 24,
+    "ditto" => 27,
+    "div" => 32,
+    "divan" => 31,
+    "dive" => 36,
+    "dived" => 24,
+    "diverge" => 37,
+    "diverged" => 35,
+    "divergence" => 37,
+    "divergent" => 29,
+    "diverges" => 35,
+    "diverging" => 41,
+    "diverse" => 35,
+    "diversified" => 24,
+    "diversify" => 24,
+    "diversion" => 27,
+    "diversity" => 33,
+    "divert" => 24,
+    "diverted" => 30,
+    "diverting" => 24,
+    "divi" => 30,
+    "divide" => 43,
+    "divided" => 42,
+    "dividend" => 27,
+    "divider" => 24,
+    "divides" => 39,
+    "dividing" => 37,
+    "divine" => 36,
+    "divined" => 29,
+    "divinely" => 27,
+    "diving" => 31,
+    "divinities" => 24,
+    "divinity" => 24,
+    "divisibility" => 24,
+    "divisible" => 31,
+    "division" => 46,
+    "divisional" => 24,
+    "divisions" => 33,
+    "divisor" => 37,
+    "divisors" => 27,
+    "divorce" => 27,
+    "divorced" => 24,
+    "divot" => 24,
+    "divulge" => 29,
+    "divulged" => 24,
+    "diy" => 27,
+    "dizziness" => 24,
+    "dizzy" => 30,
+    "dna" => 30,
+    "do" => 61,
+    "doc" => 57,
+    "docile" => 27,
+    "dock" => 34,
+    "docker" => 43,
+    "docket" => 27,
+    "docketing" => 24,
+    "docking" => 24,
+    "docks" => 34,
+    "dockyard" => 24,
+    "doctor" => 42,
+    "doctoral" => 24,
+    "doctors" => 31,
+    "document" => 51,
+    "documentary" => 27,
+    "documentation" => 63,
+    "documented" => 48,
+    "documenting" => 40,
+    "documents" => 47,
+    "doddering" => 24,
+    "dodge" => 27,
+    "dodged" => 29,
+    "dodgy" => 24,
+    "dodo" => 35,
+    "doe" => 30,
+    "does" => 61,
+    "doesn't" => 58,
+    "dog" => 42,
+    "dog's" => 27,
+    "dogged" => 24,
+    "dogleg" => 24,
+    "dogmatic" => 24,
+    "dogmatically" => 24,
+    "dogmatism" => 24,
+    "dogs" => 38,
+    "doh" => 36,
+    "doing" => 53,
+    "doings" => 29,
+    "doit" => 30,
+    "doll" => 27,
+    "dollar" => 38,
+    "dollars" => 39,
+    "dolly" => 24,
+    "dolor" => 30,
+    "dolphin" => 24,
+    "dom" => 44,
+    "domain" => 48,
+    "domains" => 38,
+    "dome" => 29,
+    "domes" => 27,
+    "domestic" => 36,
+    "domesticated" => 30,
+    "domestics" => 27,
+    "domicile" => 24,
+    "dominance" => 27,
+    "dominant" => 33,
+    "dominate" => 24,
+    "dominated" => 27,
+    "dominates" => 29,
+    "dominion" => 29,
+    "dominions" => 24,
+    "dominique" => 53,
+    "don" => 27,
+    "don't" => 59,
+    "donald" => 32,
+    "donate" => 36,
+    "donated" => 31,
+    "donates" => 24,
+    "donating" => 32,
+    "donation" => 35,
+    "donations" => 36,
+    "doncaster" => 24,
+    "done" => 56,
+    "dong" => 30,
+    "donna" => 29,
+    "donned" => 24,
+    "doodle" => 31,
+    "doolittle" => 24,
+    "doom" => 32,
+    "doomed" => 32,
+    "door" => 49,
+    "doorknob" => 24,
+    "doors" => 39,
+    "doorsteps" => 29,
+    "doorway" => 31,
+    "doppler" => 27,
+    "dormant" => 27,
+    "dormitory" => 27,
+    "dormouse" => 40,
+    "dorothy" => 32,
+    "dos" => 49,
+    "dosing" => 24,
+    "dot" => 48,
+    "doted" => 27,
+    "doting" => 27,
+    "dots" => 40,
+    "dotted" => 36,
+    "double" => 55,
+    "doubled" => 36,
+    "doubles" => 33,
+    "doublet" => 24,
+    "doubling" => 37,
+    "doubly" => 36,
+    "doubt" => 46,
+    "doubted" => 33,
+    "doubtful" => 31,
+    "doubtfully" => 31,
+    "doubting" => 24,
+    "doubtless" => 37,
+    "doubts" => 33,
+    "doug" => 41,
+    "doughnut" => 24,
+    "douglas" => 36,
+    "dover" => 29,
+    "dow" => 30,
+    "down" => 56,
+    "downcast" => 37,
+    "downgrade" => 37,
+    "downgraded" => 29,
+    "downgrades" => 24,
+    "downgrading" => 32,
+    "downhearted" => 24,
+    "downhill" => 24,
+    "download" => 49,
+    "downloaded" => 44,
+    "downloading" => 41,
+    "downloads" => 48,
+    "downright" => 27,
+    "downs" => 24,
+    "downside" => 39,
+    "downsides" => 34,
+    "downstairs" => 33,
+    "downstream" => 42,
+    "downtime" => 24,
+    "downward" => 36,
+    "downwards" => 38,
+    "dowry" => 29,
+    "doyle" => 27,
+    "doze" => 31,
+    "dozen" => 41,
+    "dozens" => 34,
+    "dozing" => 29,
+    "dpp" => 36,
+    "drab" => 24,
+    "draft" => 41,
+    "drafts" => 35,
+    "drag" => 41,
+    "dragged" => 37,
+    "dragging" => 42,
+    "draggled" => 24,
+    "dragon" => 24,
+    "dragonfly" => 36,
+    "dragons" => 24,
+    "drags" => 24,
+    "drain" => 39,
+    "drained" => 34,
+    "draining" => 36,
+    "drains" => 27,
+    "drake" => 24,
+    "dram" => 24,
+    "drama" => 30,
+    "dramatic" => 32,
+    "dramatically" => 34,
+    "drank" => 33,
+    "drastic" => 30,
+    "drastically" => 36,
+    "drat" => 24,
+    "draught" => 31,
+    "draughts" => 24,
+    "draw" => 45,
+    "drawable" => 29,
+    "drawback" => 35,
+    "drawbacks" => 38,
+    "drawbridge" => 24,
+    "drawer" => 34,
+    "drawers" => 29,
+    "drawing" => 45,
+    "drawings" => 42,
+    "drawled" => 24,
+    "drawling" => 29,
+    "drawn" => 44,
+    "draws" => 37,
+    "dread" => 36,
+    "dreaded" => 32,
+    "dreadful" => 40,
+    "dreadfully" => 34,
+    "dreading" => 29,
+    "dreads" => 24,
+    "dream" => 42,
+    "dreamed" => 30,
+    "dreamily" => 24,
+    "dreaming" => 30,
+    "dreams" => 39,
+    "dreamt" => 31,
+    "dreamy" => 30,
+    "drearier" => 24,
+    "drearily" => 24,
+    "dreariness" => 24,
+    "dreary" => 35,
+    "dregs" => 24,
+    "drenched" => 32,
+    "drenching" => 24,
+    "dress" => 42,
+    "dressed" => 42,
+    "dresser" => 24,
+    "dresses" => 34,
+    "dressing" => 38,
+    "drew" => 44,
+    "dried" => 36,
+    "driest" => 24,
+    "drift" => 38,
+    "drifted" => 36,
+    "drifting" => 36,
+    "drifts" => 27,
+    "driftwood" => 24,
+    "drill" => 27,
+    "drilling" => 24,
+    "drink" => 41,
+    "drinking" => 33,
+    "drinks" => 24,
+    "drip" => 32,
+    "dripping" => 32,
+    "drippings" => 24,
+    "drips" => 27,
+    "drive" => 44,
+    "drivel" => 27,
+    "driven" => 43,
+    "driver" => 47,
+    "drivers" => 41,
+    "drives" => 35,
+    "driving" => 39,
+    "drizzle" => 27,
+    "drone" => 29,
+    "droned" => 24,
+    "droning" => 27,
+    "drooped" => 27,
+    "drooping" => 32,
+    "drop" => 51,
+    "dropout" => 24,
+    "dropouts" => 27,
+    "dropped" => 51,
+    "dropping" => 46,
+    "drops" => 42,
+    "dross" => 24,
+    "drove" => 41,
+    "drown" => 24,
+    "drowned" => 35,
+    "drowning" => 29,
+    "drowsed" => 24,
+    "drowsiness" => 31,
+    "drowsing" => 30,
+    "drowsy" => 30,
+    "drudgery" => 24,
+    "drug" => 36,
+    "drugstore" => 24,
+    "druid" => 38,
+    "drum" => 31,
+    "drummer" => 24,
+    "drums" => 30,
+    "drunk" => 39,
+    "drunkard" => 27,
+    "drunken" => 33,
+    "drunkenness" => 29,
+    "drury" => 24,
+    "dry" => 41,
+    "dryad" => 24,
+    "dryly" => 24,
+    "dss" => 24,
+    "dual" => 55,
+    "duality" => 27,
+    "dually" => 24,
+    "dubbed" => 30,
+    "dubious" => 30,
+    "dublin" => 32,
+    "duchess" => 40,
+    "duck" => 36,
+    "ducked" => 24,
+    "ducks" => 24,
+    "duckweed" => 24,
+    "duct" => 32,
+    "due" => 52,
+    "duel" => 29,
+    "duelling" => 24,
+    "duellists" => 24,
+    "duets" => 24,
+    "dug" => 31,
+    "duke" => 34,
+    "dukedom" => 29,
+    "dukes" => 27,
+    "dull" => 38,
+    "duller" => 24,
+    "dully" => 24,
+    "duluth" => 24,
+    "duly" => 29,
+    "dumb" => 37,
+    "dumbbell" => 24,
+    "dumber" => 24,
+    "dumbness" => 24,
+    "dummies" => 24,
+    "dummy" => 44,
+    "dump" => 44,
+    "dumped" => 32,
+    "dumping" => 33,
+    "dumps" => 40,
+    "dun" => 29,
+    "duncan" => 33,
+    "dunce" => 27,
+    "dunces" => 24,
+    "dundee" => 31,
+    "dungeon" => 31,
+    "dungeons" => 27,
+    "dupes" => 27,
+    "duplex" => 43,
+    "duplicate" => 47,
+    "duplicated" => 43,
+    "duplicates" => 44,
+    "duplicating" => 37,
+    "duplication" => 41,
+    "duplications" => 24,
+    "durability" => 38,
+    "durable" => 32,
+    "duration" => 47,
+    "durations" => 37,
+    "during" => 53,
+    "dusk" => 34,
+    "dusky" => 24,
+    "dust" => 38,
+    "duster" => 27,
+    "dustin" => 27,
+    "dusty" => 29,
+    "dutch" => 36,
+    "dutchmen" => 24,
+    "duties" => 40,
+    "dutifully" => 27,
+    "duty" => 40,
+    "duvet" => 27,
+    "dwarf" => 38,
+    "dwarfed" => 27,
+    "dwell" => 32,
+    "dweller" => 24,
+    "dwellers" => 24,
+    "dwelling" => 35,
+    "dwellings" => 24,
+    "dwelt" => 27,
+    "dwindled" => 27,
+    "dyed" => 24,
+    "dying" => 38,
+    "dylan" => 32,
+    "dynamic" => 52,
+    "dynamical" => 27,
+    "dynamically" => 48,
+    "dynamics" => 32,
+    "dynamo" => 30,
+    "dysfunctional" => 24,
+    "début" => 24,
+    "each" => 60,
+    "eager" => 40,
+    "eagerly" => 43,
+    "eagerness" => 34,
+    "eagle" => 29,
+    "eagles" => 27,
+    "eaglet" => 29,
+    "ear" => 42,
+    "earl" => 27,
+    "earlier" => 49,
+    "earliest" => 37,
+    "earls" => 27,
+    "early" => 49,
+    "earn" => 34,
+    "earned" => 29,
+    "earnest" => 37,
+    "earnestly" => 36,
+    "earnestness" => 31,
+    "earning" => 27,
+    "earrings" => 29,
+    "ears" => 40,
+    "earshot" => 24,
+    "earth" => 43,
+    "earthly" => 24,
+    "earthquake" => 27,
+    "earthquakes" => 24,
+    "ease" => 43,
+    "eased" => 24,
+    "eases" => 27,
+    "easier" => 50,
+    "easiest" => 44,
+    "easily" => 51,
+    "easing" => 30,
+    "east" => 42,
+    "easterly" => 31,
+    "eastern" => 36,
+    "eastward" => 34,
+    "eastwood" => 24,
+    "easy" => 53,
+    "eat" => 41,
+    "eaten" => 35,
+    "eater" => 24,
+    "eating" => 35,
+    "eats" => 32,
+    "eaves" => 24,
+    "eavesdroppers" => 27,
+    "ebb" => 24,
+    "ebbing" => 24,
+    "ebony" => 24,
+    "ebullient" => 24,
+    "eccentric" => 34,
+    "eccentricities" => 24,
+    "eccentricity" => 31,
+    "ecclesiastical" => 24,
+    "echo" => 46,
+    "echoed" => 37,
+    "echoes" => 38,
+    "echoing" => 33,
+    "eclat" => 24,
+    "eclipse" => 31,
+    "eclipsed" => 24,
+    "eclipses" => 24,
+    "economic" => 36,
+    "economical" => 27,
+    "economically" => 24,
+    "economics" => 30,
+    "economies" => 24,
+    "economize" => 24,
+    "economized" => 24,
+    "economy" => 32,
+    "ecosystem" => 47,
+    "ecosystems" => 32,
+    "ecstasies" => 27,
+    "ecstasy" => 33,
+    "ecstatic" => 30,
+    "ecstatically" => 24,
+    "ect" => 24,
+    "ectoplasm" => 24,
+    "ecumenical" => 24,
+    "eddies" => 29,
+    "eddy" => 24,
+    "eden" => 29,
+    "edgar" => 29,
+    "edge" => 46,
+    "edged" => 33,
+    "edges" => 40,
+    "edibles" => 24,
+    "edification" => 24,
+    "edifice" => 27,
+    "edifying" => 24,
+    "edinburgh" => 32,
+    "edit" => 54,
+    "editable" => 33,
+    "edited" => 45,
+    "edith" => 29,
+    "editing" => 52,
+    "edition" => 53,
+    "editions" => 46,
+    "editor" => 48,
+    "editorial" => 34,
+    "editorials" => 27,
+    "editors" => 40,
+    "edits" => 39,
+    "edmunds" => 24,
+    "edp" => 29,
+    "educate" => 30,
+    "educated" => 33,
+    "educating" => 24,
+    "education" => 41,
+    "educational" => 36,
+    "educationally" => 24,
+    "educations" => 24,
+    "educators" => 27,
+    "educe" => 30,
+    "educes" => 24,
+    "edward" => 33,
+    "edwards" => 39,
+    "edwin" => 34,
+    "eel" => 27,
+    "eels" => 24,
+    "eerie" => 24,
+    "efface" => 24,
+    "effaced" => 24,
+    "effect" => 51,
+    "effected" => 29,
+    "effective" => 43,
+    "effectively" => 45,
+    "effectiveness" => 32,
+    "effects" => 47,
+    "effectual" => 27,
+    "effectually" => 27,
+    "effeminate" => 27,
+    "efficiencies" => 24,
+    "efficiency" => 41,
+    "efficient" => 50,
+    "efficiently" => 45,
+    "effort" => 47,
+    "effortless" => 29,
+    "effortlessly" => 29,
+    "efforts" => 40,
+    "effusion" => 24,
+    "effusions" => 24,
+    "effusive" => 27,
+    "eft" => 24,
+    "egg" => 41,
+    "eggs" => 34,
+    "ego" => 27,
+    "egotism" => 27,
+    "egregious" => 27,
+    "egregiously" => 24,
+    "egypt" => 29,
+    "egyptian" => 24,
+    "egyptians" => 24,
+    "eiffel" => 33,
+    "eight" => 47,
+    "eighteen" => 35,
+    "eighteenth" => 33,
+    "eighth" => 32,
+    "eighths" => 29,
+    "eightieth" => 27,
+    "eighty" => 39,
+    "eisenhower" => 24,
+    "either" => 58,
+    "ejaculated" => 31,
+    "ejaculation" => 29,
+    "ejaculations" => 27,
+    "ejected" => 24,
+    "eke" => 24,
+    "elaborate" => 38,
+    "elaborated" => 30,
+    "elaborately" => 27,
+    "elaborateness" => 24,
+    "elaborates" => 27,
+    "elaboration" => 30,
+    "elapse" => 24,
+    "elapsed" => 42,
+    "elapses" => 30,
+    "elastic" => 33,
+    "elastically" => 24,
+    "elasticity" => 24,
+    "elbow" => 35,
+    "elbowed" => 24,
+    "elbows" => 32,
+    "elder" => 34,
+    "elderly" => 33,
+    "eldest" => 30,
+    "elect" => 36,
+    "elected" => 34,
+    "election" => 35,
+    "elections" => 27,
+    "elector" => 27,
+    "electors" => 35,
+    "electra" => 24,
+    "electric" => 35,
+    "electrical" => 31,
+    "electricity" => 30,
+    "electrified" => 24,
+    "electrocuted" => 24,
+    "electromechanical" => 29,
+    "electron" => 33,
+    "electronic" => 49,
+    "electronically" => 32,
+    "electronics" => 33,
+    "elegance" => 24,
+    "elegant" => 36,
+    "elegantly" => 27,
+    "elem" => 24,
+    "element" => 59,
+    "elemental" => 29,
+    "elementary" => 34,
+    "elements" => 61,
+    "elephant" => 38,
+    "elephants" => 32,
+    "elevate" => 27,
+    "elevated" => 35,
+    "elevates" => 27,
+    "elevating" => 27,
+    "elevation" => 30,
+    "elevator" => 31,
+    "eleven" => 40,
+    "eleventh" => 27,
+    "elf" => 46,
+    "eli" => 31,
+    "elias" => 36,
+    "elicit" => 29,
+    "elicitation" => 29,
+    "elicited" => 29,
+    "elide" => 34,
+    "elided" => 41,
+    "eliding" => 24,
+    "eligible" => 35,
+    "eliminate" => 40,
+    "eliminated" => 34,
+    "eliminates" => 36,
+    "eliminating" => 38,
+    "elimination" => 29,
+    "elise" => 24,
+    "elision" => 44,
+    "elixir" => 38,
+    "elizabeth" => 43,
+    "ell" => 33,
+    "ella" => 29,
+    "elliott" => 24,
+    "ellipse" => 32,
+    "ellipses" => 29,
+    "ellipsis" => 36,
+    "elliptic" => 44,
+    "ellis" => 24,
+    "elm" => 36,
+    "elms" => 27,
+    "elocution" => 24,
+    "elongating" => 24,
+    "elopement" => 24,
+    "eloquence" => 31,
+    "eloquent" => 32,
+    "eloquently" => 24,
+    "else" => 52,
+    "elsewhere" => 43,
+    "elsie" => 24,
+    "elude" => 30,
+    "eluded" => 33,
+    "eludes" => 24,
+    "eluding" => 24,
+    "elusive" => 29,
+    "elvis" => 37,
+    "elvish" => 27,
+    "elysian" => 24,
+    "emaciated" => 29,
+    "emaciation" => 24,
+    "emacs" => 41,
+    "email" => 44,
+    "emancipation" => 24,
+    "embalmed" => 27,
+    "embankment" => 24,
+    "embargo" => 24,
+    "embark" => 40,
+    "embarkation" => 27,
+    "embarked" => 32,
+    "embarking" => 27,
+    "embarks" => 24,
+    "embarrass" => 24,
+    "embarrassed" => 32,
+    "embarrassment" => 31,
+    "embassy" => 38,
+    "embattled" => 24,
+    "embed" => 45,
+    "embedded" => 51,
+    "embedder" => 37,
+    "embedding" => 42,
+    "embeds" => 36,
+    "embellish" => 27,
+    "embellishment" => 24,
+    "embellishments" => 24,
+    "embers" => 29,
+    "embittered" => 24,
+    "emblem" => 24,
+    "emblems" => 24,
+    "embodies" => 24,
+    "embodiment" => 24,
+    "emboldening" => 30,
+    "emboss" => 24,
+    "embrace" => 31,
+    "embraced" => 33,
+    "embraces" => 30,
+    "embracing" => 24,
+    "embroidered" => 29,
+    "emendation" => 24,
+    "emerald" => 24,
+    "emerge" => 33,
+    "emerged" => 39,
+    "emergence" => 31,
+    "emergencies" => 24,
+    "emergency" => 30,
+    "emerges" => 24,
+    "emerging" => 31,
+    "emigrant" => 24,
+    "emigrants" => 24,
+    "emigrated" => 24,
+    "emigration" => 24,
+    "emily" => 29,
+    "eminence" => 24,
+    "eminent" => 24,
+    "eminently" => 24,
+    "emir" => 34,
+    "emission" => 35,
+    "emissions" => 29,
+    "emit" => 48,
+    "emits" => 44,
+    "emitted" => 52,
+    "emitter" => 37,
+    "emitters" => 30,
+    "emitting" => 41,
+    "emmanuel" => 27,
+    "emolument" => 27,
+    "emoluments" => 24,
+    "emotion" => 38,
+    "emotional" => 24,
+    "emotionally" => 24,
+    "emotions" => 36,
+    "emperor" => 29,
+    "emphases" => 27,
+    "emphasis" => 38,
+    "emphasising" => 24,
+    "emphasize" => 36,
+    "emphasized" => 34,
+    "emphasizes" => 24,
+    "emphasizing" => 29,
+    "emphatically" => 24,
+    "empire" => 35,
+    "empires" => 30,
+    "empirical" => 33,
+    "empirically" => 24,
+    "employ" => 37,
+    "employed" => 39,
+    "employee" => 29,
+    "employer" => 32,
+    "employers" => 27,
+    "employing" => 31,
+    "employment" => 34,
+    "employments" => 24,
+    "employs" => 36,
+    "emporium" => 24,
+    "empower" => 32,
+    "empowering" => 24,
+    "empowers" => 32,
+    "emptied" => 30,
+    "empties" => 32,
+    "emptiness" => 32,
+    "empty" => 56,
+    "emptying" => 30,
+    "emulate" => 40,
+    "emulated" => 38,
+    "emulates" => 36,
+    "emulating" => 37,
+    "emulation" => 41,
+    "emulator" => 42,
+    "emulators" => 35,
+    "enable" => 56,
+    "enabled" => 56,
+    "enables" => 55,
+    "enabling" => 49,
+    "enact" => 24,
+    "enacted" => 29,
+    "encamp" => 24,
+    "encamped" => 24,
+    "encapsulate" => 34,
+    "encapsulated" => 37,
+    "encapsulates" => 35,
+    "encapsulating" => 32,
+    "encapsulation" => 36,
+    "encase" => 27,
+    "enchanted" => 35,
+    "enchanter" => 24,
+    "enchanting" => 30,
+    "enchantment" => 24,
+    "encipher" => 24,
+    "enciphered" => 29,
+    "encircle" => 24,
+    "encircled" => 24,
+    "enclave" => 24,
+    "enclaves" => 29,
+    "enclitic" => 29,
+    "enclose" => 34,
+    "enclosed" => 42,
+    "encloses" => 24,
+    "enclosing" => 42,
+    "enclosure" => 30,
+    "enclosures" => 24,
+    "encode" => 48,
+    "encoded" => 51,
+    "encoder" => 44,
+    "encodes" => 42,
+    "encoding" => 56,
+    "encomiums" => 24,
+    "encompass" => 30,
+    "encompassed" => 30,
+    "encompasses" => 30,
+    "encompassing" => 35,
+    "encounter" => 43,
+    "encountered" => 45,
+    "encountering" => 38,
+    "encounters" => 39,
+    "encourage" => 41,
+    "encouraged" => 43,
+    "encouragement" => 31,
+    "encourages" => 34,
+    "encouraging" => 32,
+    "encroached" => 24,
+    "encrusted" => 27,
+    "encrypt" => 38,
+    "encrypted" => 44,
+    "encrypting" => 32,
+    "encryption" => 47,
+    "encrypts" => 29,
+    "encumbered" => 24,
+    "encumbrances" => 24,
+    "encyclopaedia" => 31,
+    "encyclopaedias" => 24,
+    "end" => 58,
+    "endangered" => 24,
+    "endeared" => 24,
+    "endeavor" => 29,
+    "endeavors" => 31,
+    "endeavour" => 35,
+    "endeavoured" => 40,
+    "endeavouring" => 36,
+    "endeavours" => 32,
+    "ended" => 44,
+    "endian" => 50,
+    "ending" => 45,
+    "endings" => 38,
+    "endive" => 24,
+    "endless" => 39,
+    "endlessly" => 34,
+    "endorsed" => 32,
+    "endorsement" => 31,
+    "endow" => 24,
+    "endowed" => 32,
+    "endowing" => 24,
+    "endowment" => 29,
+    "endowments" => 24,
+    "endpoint" => 42,
+    "endpoints" => 41,
+    "ends" => 49,
+    "endued" => 31,
+    "endurance" => 27,
+    "endure" => 38,
+    "endured" => 38,
+    "enduring" => 27,
+    "ene" => 30,
+    "enemies" => 35,
+    "enemy" => 39,
+    "energetic" => 32,
+    "energetically" => 24,
+    "energies" => 29,
+    "energy" => 36,
+    "enfield" => 24,
+    "enforce" => 44,
+    "enforced" => 40,
+    "enforcement" => 31,
+    "enforces" => 38,
+    "enforcing" => 38,
+    "enfranchised" => 24,
+    "engage" => 34,
+    "engaged" => 42,
+    "engagement" => 32,
+    "engagements" => 24,
+    "engages" => 30,
+    "engaging" => 33,
+    "engine" => 50,
+    "engineer" => 40,
+    "engineered" => 24,
+    "engineering" => 41,
+    "engineers" => 37,
+    "engines" => 42,
+    "england" => 44,
+    "english" => 51,
+    "englishman" => 36,
+    "englishmen" => 32,
+    "engraved" => 29,
+    "engravings" => 24,
+    "engrossed" => 29,
+    "enhance" => 36,
+    "enhanced" => 41,
+    "enhancement" => 36,
+    "enhancements" => 38,
+    "enhances" => 33,
+    "enhancing" => 32,
+    "enharmonic" => 24,
+    "enigmatic" => 24,
+    "enjoined" => 29,
+    "enjoy" => 40,
+    "enjoyable" => 27,
+    "enjoyed" => 37,
+    "enjoying" => 31,
+    "enjoyment" => 34,
+    "enjoyments" => 27,
+    "enjoys" => 29,
+    "enkindled" => 24,
+    "enlaces" => 30,
+    "enlarge" => 27,
+    "enlarged" => 29,
+    "enlargement" => 24,
+    "enlightened" => 32,
+    "enlightening" => 24,
+    "enlightenment" => 27,
+    "enlist" => 24,
+    "ennui" => 27,
+    "enormity" => 27,
+    "enormous" => 39,
+    "enormously" => 27,
+    "enough" => 52,
+    "enquire" => 24,
+    "enquired" => 27,
+    "enquiry" => 24,
+    "enraged" => 31,
+    "enraptured" => 27,
+    "enrich" => 27,
+    "enriched" => 31,
+    "enriching" => 27,
+    "enrichment" => 24,
+    "ens" => 32,
+    "ensconced" => 31,
+    "ensemble" => 27,
+    "ensembles" => 24,
+    "enshrined" => 24,
+    "enslave" => 24,
+    "enslaved" => 24,
+    "ensue" => 27,
+    "ensued" => 32,
+    "ensuing" => 30,
+    "ensure" => 53,
+    "ensured" => 37,
+    "ensures" => 46,
+    "ensuring" => 44,
+    "ent" => 27,
+    "entail" => 32,
+    "entailed" => 29,
+    "entails" => 33,
+    "entangled" => 31,
+    "enter" => 51,
+    "entered" => 47,
+    "entering" => 46,
+    "enterprise" => 38,
+    "enterprises" => 29,
+    "enterprising" => 27,
+    "enters" => 40,
+    "entertain" => 29,
+    "entertained" => 31,
+    "entertainer" => 24,
+    "entertaining" => 29,
+    "entertainment" => 32,
+    "enthusiasm" => 37,
+    "enthusiast" => 24,
+    "enthusiastic" => 36,
+    "enthusiastically" => 31,
+    "enthusiasts" => 27,
+    "enticed" => 24,
+    "enticement" => 24,
+    "enticements" => 27,
+    "enticing" => 24,
+    "entire" => 51,
+    "entirely" => 48,
+    "entirety" => 38,
+    "entities" => 43,
+    "entitled" => 35,
+    "entitlements" => 24,
+    "entitles" => 24,
+    "entity" => 44,
+    "entomologists" => 24,
+    "entrance" => 35,
+    "entranced" => 24,
+    "entrant" => 30,
+    "entreat" => 32,
+    "entreated" => 32,
+    "entreaties" => 32,
+    "entreating" => 27,
+    "entrench" => 24,
+    "entries" => 51,
+    "entropy" => 44,
+    "entrusted" => 24,
+    "entry" => 53,
+    "entwined" => 24,
+    "enumerate" => 37,
+    "enumerated" => 35,
+    "enumerates" => 27,
+    "enumerating" => 33,
+    "enumeration" => 42,
+    "enumerators" => 24,
+    "enunciation" => 24,
+    "envelop" => 24,
+    "envelope" => 37,
+    "enveloped" => 31,
+    "envelopes" => 29,
+    "enviable" => 24,
+    "envied" => 31,
+    "envious" => 24,
+    "environ" => 33,
+    "environment" => 57,
+    "environmental" => 36,
+    "environments" => 48,
+    "environs" => 31,
+    "envision" => 27,
+    "envisions" => 24,
+    "envy" => 36,
+    "envying" => 24,
+    "enwrapped" => 24,
+    "enzyme" => 27,
+    "ephemeral" => 37,
+    "epic" => 24,
+    "epicurean" => 24,
+    "epidemic" => 24,
+    "epigram" => 24,
+    "epilogue" => 33,
+    "episode" => 32,
+    "episodes" => 27,
+    "epistemology" => 24,
+    "epistle" => 29,
+    "epithet" => 24,
+    "epithets" => 24,
+    "epoch" => 42,
+    "epochs" => 33,
+    "eponymous" => 27,
+    "epsilon" => 35,
+    "equal" => 54,
+    "equaling" => 24,
+    "equality" => 46,
+    "equalize" => 31,
+    "equalized" => 27,
+    "equalizing" => 24,
+    "equalled" => 31,
+    "equally" => 40,
+    "equals" => 41,
+    "equanimity" => 24,
+    "equated" => 27,
+    "equation" => 34,
+    "equations" => 35,
+    "equator" => 27,
+    "equidistant" => 29,
+    "equilibrium" => 24,
+    "equinoctial" => 27,
+    "equinox" => 24,
+    "equinoxes" => 24,
+    "equipages" => 27,
+    "equipment" => 38,
+    "equipped" => 32,
+    "equips" => 24,
+    "equitable" => 27,
+    "equitably" => 27,
+    "equity" => 24,
+    "equivalence" => 40,
+    "equivalences" => 24,
+    "equivalent" => 54,
+    "equivalently" => 39,
+    "equivalents" => 39,
+    "equivocations" => 24,
+    "era" => 34,
+    "eradicated" => 24,
+    "eradicating" => 27,
+    "erase" => 39,
+    "erased" => 41,
+    "erases" => 29,
+    "erasing" => 27,
+    "erasure" => 32,
+    "ere" => 31,
+    "erect" => 32,
+    "erected" => 32,
+    "erection" => 24,
+    "ergonomic" => 44,
+    "ergonomically" => 33,
+    "ergonomics" => 40,
+    "eric" => 38,
+    "ernest" => 36,
+    "ernie" => 34,
+    "err" => 42,
+    "errand" => 33,
+    "errands" => 27,
+    "errant" => 24,
+    "errata" => 31,
+    "erratic" => 24,
+    "erratically" => 24,
+    "erred" => 27,
+    "erroneous" => 37,
+    "erroneously" => 35,
+    "error" => 62,
+    "errors" => 56,
+    "esc" => 48,
+    "escalation" => 29,
+    "escapable" => 30,
+    "escapade" => 29,
+    "escape" => 51,
+    "escaped" => 46,
+    "escapes" => 43,
+    "escaping" => 43,
+    "escargot" => 30,
+    "escort" => 32,
+    "escorted" => 29,
+    "escorting" => 24,
+    "esoteric" => 34,
+    "esp" => 44,
+    "especial" => 27,
+    "especially" => 49,
+    "esperanto" => 33,
+    "espied" => 32,
+    "espoused" => 24,
+    "espresso" => 27,
+    "espy" => 24,
+    "espying" => 27,
+    "esquire" => 24,
+    "essay" => 31,
+    "essayed" => 27,
+    "essays" => 27,
+    "essence" => 35,
+    "essential" => 41,
+    "essentially" => 46,
+    "essentials" => 24,
+    "est" => 27,
+    "establish" => 39,
+    "established" => 43,
+    "establishes" => 35,
+    "establishing" => 36,
+    "establishment" => 33,
+    "establishments" => 24,
+    "estate" => 35,
+    "estates" => 27,
+    "esteem" => 30,
+    "esteemed" => 27,
+    "estimable" => 24,
+    "estimate" => 39,
+    "estimated" => 36,
+    "estimates" => 33,
+    "estimating" => 31,
+    "estimation" => 34,
+    "estimations" => 24,
+    "estimator" => 24,
+    "estimators" => 24,
+    "estonian" => 30,
+    "estoppel" => 27,
+    "estuary" => 24,
+    "eta" => 30,
+    "etc" => 54,
+    "etcetera" => 31,
+    "etchings" => 24,
+    "eternal" => 36,
+    "eternally" => 27,
+    "eternity" => 30,
+    "ether" => 27,
+    "ethereal" => 24,
+    "ethers" => 39,
+    "ethical" => 30,
+    "ethics" => 32,
+    "ethnically" => 24,
+    "ethnomusicology" => 24,
+    "ethos" => 27,
+    "eton" => 24,
+    "etymology" => 27,
+    "euclid" => 29,
+    "euclidean" => 38,
+    "eugene" => 31,
+    "eulogies" => 24,
+    "eulogy" => 24,
+    "euphemisms" => 24,
+    "euphoria" => 37,
+    "euro" => 39,
+    "europe" => 39,
+    "european" => 40,
+    "europeans" => 32,
+    "eustace" => 24,
+    "evaded" => 24,
+    "evaluable" => 27,
+    "evaluate" => 46,
+    "evaluated" => 48,
+    "evaluates" => 46,
+    "evaluating" => 45,
+    "evaluation" => 48,
+    "evaluations" => 35,
+    "evaluative" => 27,
+    "evaluator" => 36,
+    "evan" => 24,
+    "evanescent" => 24,
+    "evans" => 27,
+    "evasion" => 24,
+    "evasions" => 24,
+    "eve" => 30,
+    "even" => 57,
+    "evening" => 45,
+    "evenings" => 29,
+    "evenly" => 35,
+    "evens" => 24,
+    "event" => 57,
+    "eventful" => 24,
+    "events" => 52,
+    "eventual" => 36,
+    "eventuality" => 24,
+    "eventually" => 45,
+    "ever" => 52,
+    "evergreen" => 27,
+    "everlasting" => 31,
+    "everlastingly" => 24,
+    "every" => 56,
+    "everybody" => 43,
+    "everyday" => 32,
+    "everyone" => 44,
+    "everything" => 51,
+    "everywhere" => 44,
+    "evict" => 33,
+    "evicted" => 32,
+    "eviction" => 35,
+    "evictions" => 27,
+    "evicts" => 24,
+    "evidence" => 42,
+    "evidenced" => 24,
+    "evidences" => 27,
+    "evident" => 35,
+    "evidently" => 40,
+    "evil" => 40,
+    "evils" => 30,
+    "evinced" => 27,
+    "evoked" => 27,
+    "evokes" => 27,
+    "evolution" => 37,
+    "evolutionary" => 27,
+    "evolve" => 38,
+    "evolved" => 37,
+    "evolves" => 34,
+    "evolving" => 36,
+    "ewe" => 24,
+    "ex" => 58,
+    "exact" => 49,
+    "exacted" => 27,
+    "exacting" => 27,
+    "exactitude" => 27,
+    "exactly" => 52,
+    "exactness" => 24,
+    "exaggerate" => 24,
+    "exaggerated" => 29,
+    "exaggeration" => 30,
+    "exaltation" => 24,
+    "exalted" => 33,
+    "exalting" => 24,
+    "exam" => 24,
+    "examination" => 38,
+    "examine" => 41,
+    "examined" => 40,
+    "examines" => 30,
+    "examining" => 38,
+    "example" => 63,
+    "examples" => 61,
+    "exasperate" => 24,
+    "exasperated" => 31,
+    "excavating" => 24,
+    "exceed" => 41,
+    "exceeded" => 37,
+    "exceeding" => 35,
+    "exceedingly" => 36,
+    "exceeds" => 40,
+    "excel" => 34,
+    "excellence" => 30,
+    "excellent" => 46,
+    "excelling" => 27,
+    "excels" => 27,
+    "except" => 53,
+    "excepted" => 29,
+    "excepting" => 33,
+    "exception" => 54,
+    "exceptional" => 37,
+    "exceptionally" => 31,
+    "exceptions" => 57,
+    "excerpt" => 27,
+    "excess" => 40,
+    "excesses" => 24,
+    "excessive" => 38,
+    "excessively" => 34,
+    "exchange" => 44,
+    "exchanged" => 38,
+    "exchanger" => 24,
+    "exchanges" => 31,
+    "exchanging" => 32,
+    "exchequer" => 24,
+    "excises" => 27,
+    "excitable" => 24,
+    "excite" => 31,
+    "excited" => 39,
+    "excitedly" => 32,
+    "excitement" => 38,
+    "excitements" => 24,
+    "excites" => 24,
+    "exciting" => 36,
+    "excitingly" => 27,
+    "exclaim" => 24,
+    "exclaimed" => 42,
+    "exclaiming" => 27,
+    "exclamation" => 38,
+    "exclamations" => 29,
+    "exclude" => 44,
+    "excluded" => 41,
+    "excludes" => 39,
+    "excluding" => 44,
+    "exclusion" => 37,
+    "exclusions" => 31,
+    "exclusive" => 47,
+    "exclusively" => 41,
+    "exclusiveness" => 24,
+    "exclusivity" => 24,
+    "excommunication" => 24,
+    "exculpated" => 24,
+    "excursion" => 30,
+    "excursions" => 24,
+    "excuse" => 38,
+    "excused" => 30,
+    "excuses" => 24,
+    "exe" => 47,
+    "exec" => 40,
+    "execrate" => 24,
+    "execrated" => 24,
+    "execration" => 24,
+    "execs" => 29,
+    "executable" => 51,
+    "execute" => 54,
+    "executed" => 52,
+    "executes" => 45,
+    "executing" => 50,
+    "execution" => 53,
+    "executioner" => 32,
+    "executioners" => 27,
+    "executions" => 35,
+    "executive" => 37,
+    "executives" => 27,
+    "executor" => 46,
+    "executors" => 40,
+    "executorship" => 24,
+    "exemplary" => 29,
+    "exemplified" => 29,
+    "exemplifies" => 30,
+    "exempt" => 41,
+    "exempted" => 32,
+    "exemptions" => 31,
+    "exercise" => 41,
+    "exercised" => 31,
+    "exercises" => 37,
+    "exercising" => 30,
+    "exert" => 31,
+    "exerted" => 30,
+    "exerting" => 27,
+    "exertion" => 33,
+    "exertions" => 32,
+    "exhaust" => 30,
+    "exhausted" => 40,
+    "exhausting" => 29,
+    "exhaustion" => 39,
+    "exhaustive" => 44,
+    "exhaustively" => 36,
+    "exhaustiveness" => 35,
+    "exhausts" => 24,
+    "exhibit" => 36,
+    "exhibited" => 35,
+    "exhibition" => 30,
+    "exhibits" => 32,
+    "exhilarated" => 24,
+    "exhilarating" => 29,
+    "exhortations" => 27,
+    "exile" => 31,
+    "exist" => 52,
+    "existed" => 39,
+    "existence" => 45,
+    "existent" => 35,
+    "existential" => 24,
+    "existing" => 53,
+    "exists" => 52,
+    "exit" => 52,
+    "exited" => 42,
+    "exiting" => 45,
+    "exits" => 45,
+    "exordium" => 24,
+    "exotic" => 35,
+    "exotically" => 24,
+    "exp" => 37,
+    "expand" => 49,
+    "expanded" => 47,
+    "expander" => 37,
+    "expanding" => 46,
+    "expands" => 43,
+    "expanse" => 31,
+    "expansion" => 47,
+    "expansions" => 37,
+    "expansive" => 27,
+    "expat" => 27,
+    "expect" => 49,
+    "expectancies" => 24,
+    "expectancy" => 24,
+    "expectant" => 24,
+    "expectantly" => 29,
+    "expectation" => 40,
+    "expectations" => 42,
+    "expected" => 52,
+    "expecting" => 41,
+    "expectorate" => 24,
+    "expectoration" => 24,
+    "expects" => 43,
+    "expedience" => 27,
+    "expedient" => 27,
+    "expedite" => 30,
+    "expedition" => 34,
+    "expeditions" => 27,
+    "expeditiously" => 24,
+    "expel" => 24,
+    "expend" => 27,
+    "expendable" => 24,
+    "expended" => 29,
+    "expending" => 24,
+    "expenditure" => 24,
+    "expenditures" => 24,
+    "expense" => 38,
+    "expenses" => 32,
+    "expensive" => 44,
+    "experience" => 47,
+    "experienced" => 39,
+    "experiences" => 33,
+    "experiencing" => 34,
+    "experiment" => 42,
+    "experimental" => 50,
+    "experimentally" => 27,
+    "experimentation" => 33,
+    "experimented" => 29,
+    "experimenter" => 24,
+    "experimenting" => 34,
+    "experiments" => 38,
+    "expert" => 34,
+    "expertise" => 32,
+    "experts" => 32,
+    "expiration" => 39,
+    "expirations" => 24,
+    "expire" => 33,
+    "expired" => 37,
+    "expires" => 32,
+    "expiring" => 29,
+    "expiry" => 24,
+    "explain" => 45,
+    "explainable" => 24,
+    "explained" => 46,
+    "explaining" => 40,
+    "explains" => 41,
+    "explanation" => 50,
+    "explanations" => 39,
+    "explanatory" => 34,
+    "explicable" => 24,
+    "explicit" => 50,
+    "explicitly" => 56,
+    "explicitness" => 24,
+    "explode" => 24,
+    "exploded" => 31,
+    "exploding" => 24,
+    "exploit" => 38,
+    "exploitable" => 27,
+    "exploitation" => 30,
+    "exploited" => 27,
+    "exploiting" => 33,
+    "exploits" => 32,
+    "exploration" => 35,
+    "exploratory" => 24,
+    "explore" => 44,
+    "explored" => 36,
+    "explorer" => 42,
+    "explorers" => 24,
+    "explores" => 33,
+    "exploring" => 36,
+    "explosion" => 32,
+    "explosive" => 27,
+    "exponent" => 45,
+    "exponential" => 40,
+    "exponentially" => 31,
+    "exponentiation" => 36,
+    "exponents" => 30,
+    "export" => 48,
+    "exportation" => 24,
+    "exported" => 47,
+    "exporter" => 38,
+    "exporters" => 35,
+    "exporting" => 42,
+    "exports" => 48,
+    "expose" => 47,
+    "exposed" => 47,
+    "exposes" => 48,
+    "exposing" => 42,
+    "exposition" => 33,
+    "expository" => 24,
+    "expostulate" => 24,
+    "expostulating" => 24,
+    "expostulation" => 24,
+    "exposure" => 35,
+    "expound" => 24,
+    "express" => 45,
+    "expressed" => 44,
+    "expresses" => 37,
+    "expressible" => 29,
+    "expressing" => 36,
+    "expression" => 58,
+    "expressions" => 53,
+    "expressive" => 40,
+    "expressiveness" => 31,
+    "expressly" => 31,
+    "exquisite" => 32,
+    "exquisitely" => 24,
+    "extant" => 27,
+    "extemporizing" => 24,
+    "extend" => 49,
+    "extendability" => 24,
+    "extendable" => 31,
+    "extended" => 48,
+    "extender" => 24,
+    "extending" => 44,
+    "extends" => 46,
+    "extensibility" => 32,
+    "extensible" => 40,
+    "extension" => 52,
+    "extensions" => 50,
+    "extensive" => 43,
+    "extensively" => 39,
+    "extent" => 41,
+    "extents" => 30,
+    "exterior" => 30,
+    "extern" => 50,
+    "external" => 53,
+    "externalities" => 32,
+    "externalized" => 27,
+    "externally" => 40,
+    "externals" => 24,
+    "externs" => 24,
+    "extinct" => 29,
+    "extinction" => 31,
+    "extinguish" => 33,
+    "extinguished" => 36,
+    "extinguisher" => 24,
+    "extinguishes" => 24,
+    "extort" => 24,
+    "extra" => 54,
+    "extract" => 47,
+    "extractable" => 27,
+    "extracted" => 43,
+    "extracting" => 41,
+    "extraction" => 40,
+    "extractions" => 24,
+    "extractive" => 24,
+    "extractor" => 36,
+    "extractors" => 36,
+    "extracts" => 44,
+    "extradition" => 29,
+    "extraneous" => 32,
+    "extraordinarily" => 24,
+    "extraordinary" => 39,
+    "extrapolate" => 27,
+    "extrapolating" => 24,
+    "extrapolation" => 24,
+    "extras" => 35,
+    "extravagantly" => 24,
+    "extravaganza" => 24,
+    "extreme" => 40,
+    "extremely" => 46,
+    "extremes" => 24,
+    "extremities" => 24,
+    "extremity" => 30,
+    "extremum" => 24,
+    "extricate" => 24,
+    "exuberant" => 39,
+    "exult" => 24,
+    "exultation" => 33,
+    "exulting" => 27,
+    "eye" => 44,
+    "eyeball" => 24,
+    "eyeballs" => 31,
+    "eyebrow" => 24,
+    "eyebrows" => 34,
+    "eyed" => 37,
+    "eyeglasses" => 24,
+    "eyelashes" => 27,
+    "eyelids" => 27,
+    "eyes" => 50,
+    "eyesore" => 27,
+    "eying" => 29,
+    "eyre" => 35,
+    "fab" => 24,
+    "fabian" => 32,
+    "fable" => 24,
+    "fabric" => 24,
+    "fabricate" => 27,
+    "fabrication" => 24,
+    "fabulous" => 29,
+    "facade" => 37,
+    "face" => 50,
+    "faced" => 38,
+    "faces" => 41,
+    "facet" => 32,
+    "facetious" => 24,
+    "facetiously" => 24,
+    "facets" => 29,
+    "facile" => 24,
+    "facilitate" => 41,
+    "facilitated" => 29,
+    "facilitates" => 35,
+    "facilitating" => 31,
+    "facilities" => 42,
+    "facility" => 37,
+    "facing" => 39,
+    "facsimile" => 33,
+    "facsimiles" => 31,
+    "fact" => 49,
+    "facto" => 36,
+    "factor" => 43,
+    "factored" => 24,
+    "factorial" => 24,
+    "factories" => 31,
+    "factoring" => 29,
+    "factorization" => 29,
+    "factorizations" => 27,
+    "factors" => 41,
+    "factory" => 37,
+    "facts" => 41,
+    "factual" => 24,
+    "faculties" => 33,
+    "faculty" => 32,
+    "fad" => 24,
+    "faddy" => 27,
+    "fade" => 31,
+    "faded" => 36,
+    "fading" => 29,
+    "fads" => 27,
+    "fagged" => 27,
+    "fahrenheit" => 29,
+    "fail" => 53,
+    "failed" => 49,
+    "failing" => 46,
+    "fails" => 56,
+    "failsafe" => 24,
+    "failure" => 51,
+    "failures" => 44,
+    "fain" => 27,
+    "faint" => 38,
+    "fainted" => 36,
+    "fainter" => 27,
+    "faintest" => 29,
+    "fainting" => 33,
+    "faintly" => 36,
+    "faintness" => 24,
+    "fair" => 43,
+    "fairer" => 31,
+    "fairest" => 24,
+    "fairies" => 24,
+    "fairly" => 45,
+    "fairness" => 33,
+    "fairy" => 29,
+    "fait" => 24,
+    "faith" => 36,
+    "faithful" => 36,
+    "faithfully" => 35,
+    "fake" => 37,
+    "faked" => 27,
+    "fakers" => 24,
+    "fakes" => 24,
+    "fakirs" => 32,
+    "falcon" => 27,
+    "falkland" => 24,
+    "fall" => 46,
+    "fallback" => 47,
+    "fallen" => 40,
+    "fallibility" => 29,
+    "fallible" => 40,
+    "fallibly" => 24,
+    "falling" => 40,
+    "falls" => 43,
+    "false" => 49,
+    "falsehood" => 29,
+    "falsehoods" => 29,
+    "falsely" => 29,
+    "falsi" => 24,
+    "faltered" => 24,
+    "faltering" => 24,
+    "fame" => 29,
+    "famed" => 24,
+    "familiar" => 45,
+    "familiarised" => 24,
+    "familiarity" => 36,
+    "familiarize" => 32,
+    "familiarizing" => 24,
+    "familiarly" => 29,
+    "families" => 38,
+    "family" => 49,
+    "famine" => 27,
+    "famished" => 32,
+    "famous" => 39,
+    "famously" => 24,
+    "fan" => 36,
+    "fanatical" => 24,
+    "fanaticism" => 24,
+    "fanatics" => 29,
+    "fancied" => 31,
+    "fancier" => 27,
+    "fancies" => 33,
+    "fanciest" => 24,
+    "fanciful" => 32,
+    "fancy" => 43,
+    "fancying" => 27,
+    "fang" => 24,
+    "fangled" => 27,
+    "fangs" => 29,
+    "fanlight" => 24,
+    "fanned" => 30,
+    "fanning" => 24,
+    "fans" => 30,
+    "fantastic" => 39,
+    "fantasy" => 27,
+    "far" => 50,
+    "fare" => 33,
+    "fared" => 24,
+    "fareham" => 24,
+    "farewell" => 35,
+    "farm" => 36,
+    "farmer" => 30,
+    "farmers" => 27,
+    "farmhouse" => 24,
+    "farming" => 27,
+    "farms" => 32,
+    "farsi" => 41,
+    "farther" => 39,
+    "farthest" => 27,
+    "farthing" => 27,
+    "farting" => 24,
+    "fascinate" => 24,
+    "fascinated" => 31,
+    "fascinating" => 31,
+    "fascination" => 31,
+    "fascinations" => 24,
+    "fashion" => 44,
+    "fashionable" => 31,
+    "fashionably" => 29,
+    "fashioned" => 35,
+    "fashions" => 24,
+    "fast" => 53,
+    "fasten" => 29,
+    "fastened" => 37,
+    "fasteners" => 24,
+    "fastening" => 24,
+    "fastenings" => 24,
+    "fastens" => 24,
+    "faster" => 51,
+    "fastest" => 44,
+    "fastidious" => 29,
+    "fat" => 41,
+    "fatal" => 42,
+    "fatality" => 31,
+    "fatally" => 31,
+    "fate" => 40,
+    "fated" => 24,
+    "fateful" => 24,
+    "father" => 48,
+    "father's" => 40,
+    "fathers" => 35,
+    "fathom" => 27,
+    "fathomed" => 24,
+    "fatigue" => 36,
+    "fatigued" => 29,
+    "fatigues" => 24,
+    "fatiguing" => 24,
+    "fatten" => 24,
+    "fattened" => 27,
+    "fattest" => 24,
+    "faucet" => 31,
+    "fault" => 41,
+    "faultiness" => 24,
+    "faults" => 34,
+    "faulty" => 33,
+    "favor" => 42,
+    "favored" => 29,
+    "favoring" => 24,
+    "favorite" => 39,
+    "favors" => 33,
+    "favour" => 41,
+    "favourable" => 36,
+    "favourably" => 27,
+    "favoured" => 33,
+    "favouring" => 24,
+    "favourite" => 37,
+    "favourites" => 24,
+    "favours" => 27,
+    "fawn" => 24,
+    "fax" => 40,
+    "fay" => 24,
+    "façade" => 24,
+    "fear" => 44,
+    "feared" => 39,
+    "fearful" => 35,
+    "fearfully" => 29,
+    "fearing" => 31,
+    "fearless" => 37,
+    "fearlessly" => 27,
+    "fears" => 38,
+    "feasibility" => 31,
+    "feasible" => 37,
+    "feasibly" => 24,
+    "feast" => 30,
+    "feasting" => 24,
+    "feat" => 48,
+    "feather" => 34,
+    "feathers" => 30,
+    "feats" => 24,
+    "feature" => 61,
+    "featured" => 39,
+    "featureless" => 27,
+    "features" => 60,
+    "featuring" => 35,
+    "february" => 35,
+    "fed" => 35,
+    "federal" => 35,
+    "federated" => 27,
+    "federation" => 30,
+    "fedora" => 39,
+    "fee" => 39,
+    "feeble" => 35,
+    "feebleness" => 24,
+    "feebly" => 33,
+    "feed" => 42,
+    "feedback" => 44,
+    "feeder" => 29,
+    "feeding" => 34,
+    "feeds" => 36,
+    "feel" => 49,
+    "feeler" => 24,
+    "feelers" => 24,
+    "feeling" => 43,
+    "feelings" => 43,
+    "feels" => 36,
+    "fees" => 32,
+    "feet" => 45,
+    "feigned" => 27,
+    "feigning" => 24,
+    "fein" => 24,
+    "feint" => 24,
+    "felicity" => 24,
+    "felix" => 41,
+    "fell" => 45,
+    "felled" => 27,
+    "feller" => 31,
+    "fellow" => 45,
+    "fellows" => 35,
+    "fellowship" => 24,
+    "felonies" => 24,
+    "felony" => 31,
+    "felt" => 47,
+    "felts" => 27,
+    "female" => 33,
+    "females" => 24,
+    "feminine" => 31,
+    "femme" => 24,
+    "fen" => 30,
+    "fence" => 44,
+    "fenced" => 31,
+    "fences" => 35,
+    "fenchurch" => 27,
+    "fencing" => 24,
+    "fender" => 29,
+    "fenders" => 24,
+    "fennel" => 24,
+    "ferguson" => 30,
+    "fermentation" => 24,
+    "fern" => 35,
+    "ferns" => 27,
+    "ferocious" => 32,
+    "ferociously" => 24,
+    "ferocity" => 24,
+    "ferrari" => 27,
+    "ferret" => 29,
+    "ferrets" => 27,
+    "ferris" => 32,
+    "ferrous" => 30,
+    "ferry" => 29,
+    "fertile" => 32,
+    "ferule" => 27,
+    "fervent" => 30,
+    "fervently" => 24,
+    "fervour" => 29,
+    "festering" => 24,
+    "festival" => 27,
+    "festivals" => 24,
+    "festive" => 24,
+    "festivities" => 27,
+    "festivity" => 24,
+    "festooning" => 24,
+    "festoons" => 24,
+    "fetch" => 47,
+    "fetched" => 38,
+    "fetches" => 40,
+    "fetching" => 40,
+    "fetter" => 24,
+    "fettered" => 24,
+    "fetters" => 27,
+    "feudal" => 24,
+    "fever" => 36,
+    "feverish" => 34,
+    "feverishly" => 24,
+    "few" => 55,
+    "fewer" => 43,
+    "fewest" => 29,
+    "fiancé" => 24,
+    "fiat" => 38,
+    "fiber" => 33,
+    "fibers" => 33,
+    "fibre" => 24,
+    "fibres" => 24,
+    "fickle" => 27,
+    "fiction" => 24,
+    "fictional" => 27,
+    "fiddle" => 27,
+    "fiddles" => 27,
+    "fiddlesticks" => 24,
+    "fiddling" => 24,
+    "fiddly" => 24,
+    "fide" => 29,
+    "fidelity" => 37,
+    "fidget" => 27,
+    "fidgeted" => 30,
+    "field" => 56,
+    "fields" => 54,
+    "fiend" => 39,
+    "fiendish" => 32,
+    "fiendishly" => 27,
+    "fierce" => 35,
+    "fiercely" => 30,
+    "fierceness" => 24,
+    "fiery" => 30,
+    "fifteen" => 38,
+    "fifteenth" => 27,
+    "fifth" => 38,
+    "fifths" => 29,
+    "fifties" => 24,
+    "fiftieth" => 29,
+    "fifty" => 42,
+    "fig" => 45,
+    "fight" => 39,
+    "fighting" => 33,
+    "fights" => 27,
+    "figment" => 33,
+    "figs" => 29,
+    "figurative" => 24,
+    "figure" => 50,
+    "figured" => 35,
+    "figures" => 38,
+    "figuring" => 35,
+    "fijian" => 24,
+    "file" => 65,
+    "filed" => 35,
+    "filename" => 52,
+    "filenames" => 43,
+    "filer" => 24,
+    "files" => 67,
+    "filesystem" => 48,
+    "filet" => 24,
+    "filial" => 29,
+    "filigree" => 27,
+    "filing" => 35,
+    "fill" => 46,
+    "filled" => 47,
+    "filler" => 40,
+    "filling" => 41,
+    "fillip" => 24,
+    "fills" => 40,
+    "film" => 37,
+    "filming" => 24,
+    "films" => 29,
+    "filter" => 50,
+    "filtered" => 38,
+    "filtering" => 44,
+    "filters" => 46,
+    "filthy" => 32,
+    "fin" => 33,
+    "final" => 50,
+    "finalised" => 24,
+    "finalists" => 29,
+    "finality" => 30,
+    "finalize" => 39,
+    "finalized" => 32,
+    "finalizes" => 24,
+    "finalizing" => 24,
+    "finally" => 50,
+    "finals" => 24,
+    "finance" => 32,
+    "financial" => 37,
+    "financially" => 27,
+    "financier" => 27,
+    "find" => 56,
+    "finder" => 31,
+    "finding" => 47,
+    "findings" => 37,
+    "finds" => 45,
+    "fine" => 49,
+    "finely" => 29,
+    "finer" => 36,
+    "finery" => 24,
+    "fines" => 27,
+    "finest" => 33,
+    "finger" => 40,
+    "fingered" => 24,
+    "fingerless" => 24,
+    "fingerprint" => 39,
+    "fingerprinting" => 27,
+    "fingerprints" => 34,
+    "fingers" => 40,
+    "fingertips" => 30,
+    "finis" => 24,
+    "finish" => 46,
+    "finished" => 47,
+    "finishes" => 41,
+    "finishing" => 35,
+    "finite" => 42,
+    "finitely" => 27,
+    "finn" => 37,
+    "finnish" => 32,
+    "finns" => 24,
+    "fir" => 30,
+    "fire" => 46,
+    "firearms" => 24,
+    "fireball" => 24,
+    "firecracker" => 29,
+    "fired" => 37,
+    "firelight" => 24,
+    "fireman" => 27,
+    "firemen" => 24,
+    "fireplace" => 34,
+    "fires" => 37,
+    "fireside" => 24,
+    "firesides" => 24,
+    "firewall" => 34,
+    "fireworks" => 24,
+    "firing" => 32,
+    "firm" => 38,
+    "firmest" => 24,
+    "firmly" => 36,
+    "firmness" => 33,
+    "firmware" => 36,
+    "firs" => 24,
+    "first" => 60,
+    "firsthand" => 24,
+    "firstly" => 30,
+    "fiscal" => 24,
+    "fischer" => 24,
+    "fish" => 41,
+    "fished" => 24,
+    "fisher" => 36,
+    "fishermen" => 30,
+    "fishers" => 24,
+    "fishes" => 31,
+    "fishing" => 36,
+    "fist" => 32,
+    "fists" => 35,
+    "fit" => 48,
+    "fitfully" => 24,
+    "fitness" => 37,
+    "fits" => 42,
+    "fitted" => 34,
+    "fitter" => 24,
+    "fitting" => 35,
+    "fittings" => 24,
+    "fitzgerald" => 24,
+    "five" => 49,
+    "fiver" => 27,
+    "fix" => 56,
+    "fixable" => 30,
+    "fixed" => 54,
+    "fixer" => 29,
+    "fixes" => 48,
+    "fixing" => 41,
+    "fixture" => 37,
+    "fixtures" => 38,
+    "flabby" => 24,
+    "flag" => 57,
+    "flagged" => 38,
+    "flagging" => 24,
+    "flagrant" => 24,
+    "flags" => 55,
+    "flagship" => 24,
+    "flake" => 27,
+    "flakes" => 30,
+    "flaky" => 47,
+    "flame" => 36,
+    "flamed" => 27,
+    "flames" => 30,
+    "flaming" => 31,
+    "flamingo" => 31,
+    "flanagan" => 33,
+    "flank" => 27,
+    "flanks" => 24,
+    "flannel" => 29,
+    "flannels" => 24,
+    "flap" => 30,
+    "flapped" => 30,
+    "flapper" => 24,
+    "flappers" => 24,
+    "flare" => 24,
+    "flaring" => 24,
+    "flash" => 41,
+    "flashed" => 35,
+    "flashes" => 30,
+    "flashing" => 32,
+    "flask" => 24,
+    "flat" => 42,
+    "flatboat" => 24,
+    "flatly" => 24,
+    "flatness" => 24,
+    "flatten" => 37,
+    "flattened" => 36,
+    "flattening" => 35,
+    "flattens" => 33,
+    "flatter" => 24,
+    "flattered" => 32,
+    "flattery" => 24,
+    "flaubert" => 24,
+    "flavor" => 40,
+    "flavored" => 36,
+    "flavors" => 39,
+    "flavour" => 24,
+    "flavours" => 32,
+    "flaw" => 33,
+    "flawed" => 30,
+    "flawless" => 24,
+    "flaws" => 36,
+    "flax" => 24,
+    "flaying" => 24,
+    "flecked" => 24,
+    "fled" => 37,
+    "fledged" => 34,
+    "flee" => 24,
+    "fleecy" => 27,
+    "fleet" => 32,
+    "fleetest" => 24,
+    "fleeting" => 29,
+    "fleming" => 24,
+    "flemish" => 24,
+    "flesh" => 35,
+    "fleshed" => 27,
+    "fleshless" => 27,
+    "flew" => 37,
+    "flex" => 35,
+    "flexibility" => 44,
+    "flexible" => 46,
+    "flexibly" => 32,
+    "flicked" => 27,
+    "flicker" => 36,
+    "flickered" => 24,
+    "flickering" => 33,
+    "flickers" => 32,
+    "flicking" => 24,
+    "flies" => 29,
+    "flight" => 39,
+    "flighty" => 24,
+    "flinch" => 24,
+    "flinched" => 24,
+    "fling" => 27,
+    "flinging" => 24,
+    "flint" => 24,
+    "flip" => 37,
+    "flipped" => 32,
+    "flipper" => 27,
+    "flipping" => 29,
+    "flips" => 32,
+    "flirt" => 24,
+    "flirtation" => 27,
+    "flirting" => 24,
+    "flit" => 29,
+    "flitted" => 29,
+    "flitting" => 27,
+    "float" => 51,
+    "floated" => 34,
+    "floating" => 58,
+    "floats" => 46,
+    "flock" => 32,
+    "flocked" => 30,
+    "flocking" => 24,
+    "flocks" => 24,
+    "flogged" => 27,
+    "flogging" => 27,
+    "floggings" => 24,
+    "flood" => 32,
+    "flooded" => 24,
+    "flooding" => 24,
+    "floods" => 27,
+    "floor" => 45,
+    "flooring" => 29,
+    "flop" => 24,
+    "floppy" => 32,
+    "flora" => 33,
+    "florid" => 29,
+    "florida" => 29,
+    "florins" => 24,
+    "floss" => 24,
+    "flotilla" => 24,
+    "flounced" => 24,
+    "floundered" => 24,
+    "floundering" => 27,
+    "flourish" => 24,
+    "flourished" => 29,
+    "flourishing" => 29,
+    "flow" => 47,
+    "flowed" => 33,
+    "flower" => 36,
+    "flowered" => 27,
+    "flowers" => 38,
+    "flowery" => 27,
+    "flowing" => 39,
+    "flown" => 24,
+    "flows" => 36,
+    "floyd" => 29,
+    "flp" => 29,
+    "fluctuate" => 27,
+    "fluctuating" => 30,
+    "fluctuations" => 24,
+    "fluent" => 41,
+    "fluently" => 24,
+    "fluff" => 27,
+    "fluffy" => 29,
+    "fluid" => 30,
+    "fluids" => 24,
+    "flume" => 33,
+    "flung" => 35,
+    "flunkey" => 24,
+    "flurried" => 29,
+    "flurry" => 29,
+    "flush" => 43,
+    "flushed" => 42,
+    "flushes" => 36,
+    "flushing" => 39,
+    "flustered" => 27,
+    "flutes" => 24,
+    "flutter" => 39,
+    "fluttered" => 31,
+    "fluttering" => 31,
+    "flux" => 32,
+    "fly" => 42,
+    "flying" => 36,
+    "flyway" => 27,
+    "foam" => 30,
+    "foaming" => 24,
+    "foamy" => 24,
+    "fob" => 24,
+    "focal" => 24,
+    "foci" => 24,
+    "focus" => 48,
+    "focused" => 43,
+    "focuses" => 39,
+    "focusing" => 37,
+    "focussed" => 27,
+    "focussing" => 24,
+    "foe" => 30,
+    "foes" => 27,
+    "fog" => 32,
+    "fogs" => 30,
+    "foil" => 24,
+    "fold" => 51,
+    "foldable" => 29,
+    "folded" => 43,
+    "folder" => 48,
+    "folders" => 40,
+    "folding" => 47,
+    "folds" => 48,
+    "foliage" => 35,
+    "folk" => 34,
+    "folklore" => 24,
+    "folks" => 38,
+    "follies" => 27,
+    "follow" => 52,
+    "followed" => 52,
+    "followers" => 27,
+    "following" => 60,
+    "followings" => 31,
+    "follows" => 51,
+    "folly" => 35,
+    "fond" => 36,
+    "fonder" => 24,
+    "fondled" => 29,
+    "fondly" => 27,
+    "fondness" => 29,
+    "font" => 51,
+    "fonts" => 47,
+    "food" => 40,
+    "fool" => 40,
+    "fooled" => 29,
+    "foolhardy" => 27,
+    "fooling" => 24,
+    "foolish" => 36,
+    "foolishly" => 24,
+    "foolishness" => 30,
+    "fools" => 31,
+    "foolscap" => 27,
+    "foot" => 43,
+    "footage" => 27,
+    "football" => 27,
+    "footed" => 27,
+    "footer" => 35,
+    "footers" => 24,
+    "footfall" => 27,
+    "footfalls" => 27,
+    "foothold" => 30,
+    "footing" => 29,
+    "footlights" => 24,
+    "footman" => 36,
+    "footmarks" => 29,
+    "footmen" => 27,
+    "footnote" => 33,
+    "footnotes" => 34,
+    "footpaths" => 24,
+    "footprint" => 39,
+    "footprints" => 29,
+    "footstep" => 24,
+    "footsteps" => 34,
+    "for" => 71,
+    "forbade" => 27,
+    "forbear" => 27,
+    "forbearance" => 24,
+    "forbid" => 39,
+    "forbidden" => 43,
+    "forbidding" => 30,
+    "forbids" => 39,
+    "forbore" => 24,
+    "force" => 49,
+    "forced" => 43,
+    "forceful" => 27,
+    "forcefully" => 33,
+    "forceps" => 24,
+    "forces" => 43,
+    "forcible" => 24,
+    "forcibly" => 36,
+    "forcing" => 40,
+    "ford" => 27,
+    "forded" => 24,
+    "fore" => 32,
+    "forearm" => 24,
+    "forebodings" => 30,
+    "forecast" => 27,
+    "forecasting" => 27,
+    "forecastle" => 24,
+    "forefinger" => 31,
+    "forefingers" => 24,
+    "foregoing" => 27,
+    "foreground" => 43,
+    "forehead" => 37,
+    "foreign" => 47,
+    "foreigner" => 24,
+    "foreigners" => 30,
+    "foreman" => 27,
+    "foremost" => 32,
+    "forenoon" => 24,
+    "forepaws" => 24,
+    "forerunner" => 24,
+    "foresail" => 27,
+    "foresaw" => 31,
+    "foresee" => 31,
+    "foreseeable" => 31,
+    "foreseen" => 34,
+    "foreshadowed" => 24,
+    "foresight" => 32,
+    "forest" => 39,
+    "forestalled" => 24,
+    "forestalling" => 24,
+    "forester" => 24,
+    "forests" => 35,
+    "foretaste" => 24,
+    "foretell" => 24,
+    "foretold" => 24,
+    "forever" => 43,
+    "forewarned" => 24,
+    "foreword" => 24,
+    "forfeit" => 27,
+    "forfeiture" => 27,
+    "forgave" => 24,
+    "forge" => 30,
+    "forged" => 32,
+    "forger" => 24,
+    "forgeries" => 24,
+    "forgery" => 35,
+    "forges" => 24,
+    "forget" => 45,
+    "forgetfulness" => 30,
+    "forgets" => 32,
+    "forgetting" => 37,
+    "forgive" => 36,
+    "forgiven" => 29,
+    "forgiveness" => 31,
+    "forgiving" => 27,
+    "forgo" => 29,
+    "forgot" => 40,
+    "forgotten" => 40,
+    "fork" => 49,
+    "forked" => 41,
+    "forking" => 38,
+    "forks" => 35,
+    "forlorn" => 30,
+    "forlornly" => 24,
+    "form" => 54,
+    "forma" => 24,
+    "formal" => 43,
+    "formalism" => 30,
+    "formalities" => 29,
+    "formality" => 27,
+    "formalize" => 24,
+    "formalized" => 29,
+    "formally" => 40,
+    "format" => 57,
+    "formation" => 29,
+    "formations" => 24,
+    "formative" => 24,
+    "formats" => 50,
+    "formatted" => 47,
+    "formatter" => 42,
+    "formatters" => 37,
+    "formatting" => 51,
+    "formed" => 44,
+    "former" => 43,
+    "formerly" => 39,
+    "formidable" => 36,
+    "forming" => 35,
+    "formless" => 24,
+    "formosa" => 24,
+    "forms" => 48,
+    "formula" => 36,
+    "formulae" => 24,
+    "formulas" => 38,
+    "formulate" => 27,
+    "formulated" => 29,
+    "formulates" => 24,
+    "formulation" => 29,
+    "formulations" => 24,
+    "forrest" => 24,
+    "forsake" => 24,
+    "forsaken" => 29,
+    "forsakes" => 24,
+    "forsook" => 27,
+    "forster" => 31,
+    "forsythe" => 24,
+    "fort" => 38,
+    "forth" => 44,
+    "forthcoming" => 24,
+    "forthwith" => 29,
+    "forties" => 24,
+    "fortieth" => 24,
+    "fortifications" => 27,
+    "fortified" => 29,
+    "fortify" => 33,
+    "fortitude" => 31,
+    "fortnight" => 34,
+    "fortran" => 42,
+    "fortress" => 24,
+    "forts" => 30,
+    "fortuitously" => 24,
+    "fortunate" => 36,
+    "fortunately" => 40,
+    "fortune" => 40,
+    "fortunes" => 31,
+    "forty" => 41,
+    "forum" => 38,
+    "forums" => 27,
+    "forward" => 50,
+    "forwarded" => 39,
+    "forwarding" => 38,
+    "forwards" => 42,
+    "fossil" => 31,
+    "foster" => 32,
+    "fosterage" => 24,
+    "fostering" => 27,
+    "fought" => 33,
+    "foul" => 33,
+    "fouls" => 24,
+    "found" => 56,
+    "foundation" => 42,
+    "foundations" => 35,
+    "founded" => 37,
+    "founder" => 31,
+    "founders" => 24,
+    "founding" => 35,
+    "foundries" => 24,
+    "foundry" => 33,
+    "fountain" => 31,
+    "fountains" => 29,
+    "four" => 51,
+    "fourier" => 32,
+    "fours" => 24,
+    "fourteen" => 34,
+    "fourteenth" => 27,
+    "fourth" => 41,
+    "fourths" => 29,
+    "fowl" => 24,
+    "fowler" => 33,
+    "fowls" => 30,
+    "fox" => 33,
+    "foxes" => 27,
+    "fractals" => 24,
+    "fraction" => 43,
+    "fractional" => 40,
+    "fractions" => 33,
+    "fractiousness" => 24,
+    "fragile" => 32,
+    "fragment" => 45,
+    "fragmentary" => 24,
+    "fragmentation" => 36,
+    "fragmented" => 30,
+    "fragmenting" => 27,
+    "fragments" => 41,
+    "fragrance" => 24,
+    "fragrant" => 24,
+    "frail" => 30,
+    "frame" => 48,
+    "framed" => 36,
+    "frames" => 46,
+    "framework" => 50,
+    "frameworks" => 42,
+    "framing" => 34,
+    "franc" => 24,
+    "franca" => 30,
+    "france" => 37,
+    "franchise" => 24,
+    "francis" => 41,
+    "francisco" => 40,
+    "franco" => 24,
+    "frank" => 38,
+    "frankenstein" => 38,
+    "franklin" => 45,
+    "frankly" => 33,
+    "frankness" => 24,
+    "frantic" => 32,
+    "frantically" => 29,
+    "fraternal" => 24,
+    "fraternity" => 24,
+    "fraud" => 30,
+    "frauds" => 24,
+    "fraudulent" => 27,
+    "fraught" => 29,
+    "fray" => 24,
+    "frayed" => 24,
+    "freak" => 24,
+    "freaks" => 27,
+    "freckled" => 27,
+    "frederick" => 24,
+    "free" => 54,
+    "freed" => 47,
+    "freedom" => 38,
+    "freeing" => 42,
+    "freelance" => 27,
+    "freely" => 42,
+    "freeman" => 39,
+    "freemason" => 24,
+    "freemasonry" => 27,
+    "freer" => 24,
+    "frees" => 35,
+    "freeze" => 35,
+    "freezed" => 29,
+    "freezes" => 32,
+    "freezing" => 32,
+    "freight" => 31,
+    "fremont" => 24,
+    "french" => 43,
+    "frenchman" => 37,
+    "frenchmen" => 24,
+    "frenchwoman" => 24,
+    "frenzy" => 33,
+    "frequencies" => 35,
+    "frequency" => 39,
+    "frequent" => 40,
+    "frequented" => 24,
+    "frequently" => 43,
+    "frescoed" => 27,
+    "fresh" => 43,
+    "freshened" => 24,
+    "fresher" => 24,
+    "freshest" => 24,
+    "freshly" => 31,
+    "freshness" => 32,
+    "fresno" => 27,
+    "fret" => 24,
+    "fretting" => 27,
+    "friar" => 24,
+    "friars" => 24,
+    "fricative" => 29,
+    "fricatives" => 29,
+    "friction" => 34,
+    "frictions" => 24,
+    "friday" => 39,
+    "fried" => 29,
+    "friend" => 47,
+    "friendless" => 30,
+    "friendlier" => 32,
+    "friendliness" => 31,
+    "friendly" => 46,
+    "friends" => 45,
+    "friendship" => 34,
+    "friendships" => 24,
+    "fries" => 24,
+    "fright" => 35,
+    "frighten" => 31,
+    "frightened" => 39,
+    "frightening" => 31,
+    "frightful" => 36,
+    "frill" => 24,
+    "fringe" => 30,
+    "fringed" => 31,
+    "frisco" => 31,
+    "frisian" => 34,
+    "frisking" => 24,
+    "fritz" => 37,
+    "frivolity" => 24,
+    "frivolous" => 24,
+    "frivolously" => 24,
+    "fro" => 34,
+    "frock" => 34,
+    "frocks" => 27,
+    "frog" => 30,
+    "frogged" => 24,
+    "froggy" => 24,
+    "frogs" => 29,
+    "frolic" => 27,
+    "frolicked" => 24,
+    "frolicking" => 24,
+    "from" => 66,
+    "front" => 49,
+    "frontend" => 38,
+    "frontier" => 24,
+    "frontiers" => 24,
+    "fronting" => 27,
+    "frontispiece" => 27,
+    "fronts" => 29,
+    "frost" => 34,
+    "frosted" => 27,
+    "frosts" => 24,
+    "frosty" => 29,
+    "frothy" => 27,
+    "frown" => 24,
+    "frowned" => 32,
+    "frowning" => 33,
+    "frowns" => 24,
+    "froze" => 24,
+    "frozen" => 39,
+    "fructose" => 24,
+    "fruit" => 36,
+    "fruiterer" => 24,
+    "fruitful" => 24,
+    "fruitless" => 29,
+    "fruitlessly" => 24,
+    "fruits" => 30,
+    "fruity" => 27,
+    "frustrated" => 24,
+    "frustrating" => 34,
+    "frustratingly" => 24,
+    "frustration" => 32,
+    "fry" => 24,
+    "frying" => 27,
+    "fuchs" => 31,
+    "fuchsia" => 44,
+    "fuck" => 24,
+    "fuddled" => 27,
+    "fuel" => 31,
+    "fuelled" => 24,
+    "fuelling" => 24,
+    "fuels" => 24,
+    "fugitives" => 30,
+    "fuji" => 24,
+    "fujitsu" => 24,
+    "fulcrum" => 24,
+    "fulfil" => 33,
+    "fulfill" => 36,
+    "fulfilled" => 42,
+    "fulfilling" => 31,
+    "fulfillment" => 30,
+    "fulfills" => 43,
+    "fulfilment" => 30,
+    "fulfils" => 24,
+    "full" => 55,
+    "fuller" => 27,
+    "fullest" => 27,
+    "fully" => 51,
+    "fulness" => 24,
+    "fulton" => 24,
+    "fumbled" => 30,
+    "fumes" => 30,
+    "fun" => 42,
+    "function" => 64,
+    "functional" => 42,
+    "functionality" => 54,
+    "functionally" => 37,
+    "functionary" => 24,
+    "functioning" => 33,
+    "functions" => 59,
+    "fund" => 35,
+    "fundamental" => 43,
+    "fundamentally" => 38,
+    "fundamentals" => 33,
+    "funded" => 33,
+    "funding" => 38,
+    "funds" => 36,
+    "funeral" => 37,
+    "funerals" => 27,
+    "funereal" => 24,
+    "fungal" => 24,
+    "fungi" => 24,
+    "fungible" => 34,
+    "funky" => 29,
+    "funnel" => 34,
+    "funniest" => 27,
+    "funnily" => 24,
+    "funny" => 39,
+    "fur" => 33,
+    "furies" => 24,
+    "furious" => 34,
+    "furiously" => 32,
+    "furled" => 27,
+    "furlong" => 24,
+    "furnace" => 27,
+    "furnaces" => 27,
+    "furnish" => 32,
+    "furnished" => 37,
+    "furnishes" => 29,
+    "furniture" => 36,
+    "furred" => 24,
+    "furrow" => 24,
+    "furrowed" => 24,
+    "furrows" => 24,
+    "furs" => 30,
+    "further" => 51,
+    "furtherance" => 24,
+    "furthermore" => 44,
+    "furthers" => 27,
+    "furthest" => 24,
+    "furtive" => 31,
+    "furtively" => 29,
+    "fury" => 35,
+    "fuse" => 24,
+    "fused" => 39,
+    "fusing" => 24,
+    "fusion" => 36,
+    "fuss" => 30,
+    "futile" => 27,
+    "futility" => 27,
+    "future" => 55,
+    "futures" => 49,
+    "futurity" => 24,
+    "fuzz" => 44,
+    "fuzzed" => 36,
+    "fuzziness" => 24,
+    "fuzzy" => 43,
+    "gable" => 24,
+    "gables" => 24,
+    "gabriel" => 35,
+    "gadget" => 31,
+    "gadgets" => 29,
+    "gaelic" => 27,
+    "gag" => 24,
+    "gagged" => 24,
+    "gaggle" => 24,
+    "gaiety" => 30,
+    "gaily" => 27,
+    "gain" => 43,
+    "gained" => 42,
+    "gainer" => 24,
+    "gaining" => 35,
+    "gains" => 35,
+    "gainsay" => 24,
+    "gait" => 32,
+    "gaiters" => 30,
+    "gal" => 29,
+    "galactic" => 27,
+    "gale" => 34,
+    "gales" => 32,
+    "galileo" => 27,
+    "gall" => 27,
+    "gallant" => 33,
+    "galled" => 24,
+    "gallery" => 33,
+    "galleys" => 24,
+    "gallons" => 24,
+    "gallop" => 27,
+    "galloping" => 24,
+    "galloway" => 24,
+    "gallows" => 27,
+    "galois" => 35,
+    "gals" => 27,
+    "galvanised" => 27,
+    "galvanism" => 24,
+    "gamble" => 24,
+    "gambled" => 24,
+    "gambler" => 29,
+    "gamblers" => 24,
+    "game" => 46,
+    "games" => 39,
+    "gaming" => 27,
+    "gamma" => 36,
+    "gamut" => 24,
+    "gang" => 37,
+    "ganges" => 34,
+    "gantlet" => 24,
+    "gaol" => 29,
+    "gaolers" => 24,
+    "gap" => 43,
+    "gape" => 24,
+    "gaped" => 29,
+    "gaping" => 29,
+    "gaps" => 37,
+    "garage" => 38,
+    "garages" => 24,
+    "garb" => 27,
+    "garbage" => 46,
+    "garbled" => 32,
+    "garden" => 43,
+    "gardener" => 32,
+    "gardeners" => 33,
+    "gardens" => 33,
+    "gardner" => 27,
+    "garment" => 27,
+    "garments" => 33,
+    "garner" => 24,
+    "garnished" => 29,
+    "garret" => 29,
+    "garrison" => 24,
+    "garrulous" => 24,
+    "garth" => 24,
+    "gary" => 41,
+    "gas" => 41,
+    "gash" => 24,
+    "gaslight" => 24,
+    "gasoline" => 29,
+    "gasp" => 27,
+    "gasped" => 35,
+    "gasping" => 30,
+    "gate" => 41,
+    "gated" => 41,
+    "gatekeeper" => 24,
+    "gates" => 38,
+    "gateway" => 40,
+    "gateways" => 29,
+    "gather" => 41,
+    "gathered" => 41,
+    "gatherer" => 24,
+    "gathering" => 36,
+    "gathers" => 30,
+    "gating" => 31,
+    "gatt" => 31,
+    "gaudier" => 24,
+    "gaudily" => 24,
+    "gaudiness" => 24,
+    "gaudy" => 29,
+    "gauge" => 32,
+    "gauges" => 27,
+    "gauging" => 24,
+    "gaunt" => 30,
+    "gaunter" => 24,
+    "gauss" => 27,
+    "gaussian" => 31,
+    "gauze" => 24,
+    "gave" => 48,
+    "gavin" => 24,
+    "gawk" => 27,
+    "gawky" => 24,
+    "gay" => 38,
+    "gayety" => 31,
+    "gaze" => 34,
+    "gazed" => 39,
+    "gazes" => 24,
+    "gazetteer" => 24,
+    "gazing" => 36,
+    "gce" => 27,
+    "gear" => 29,
+    "geared" => 30,
+    "gears" => 27,
+    "gecko" => 33,
+    "geese" => 36,
+    "gem" => 38,
+    "gemini" => 24,
+    "gems" => 37,
+    "gen" => 41,
+    "gender" => 33,
+    "gene" => 24,
+    "genealogical" => 24,
+    "genera" => 24,
+    "general" => 53,
+    "generalise" => 24,
+    "generalities" => 27,
+    "generalization" => 31,
+    "generalize" => 30,
+    "generalized" => 36,
+    "generalizes" => 27,
+    "generalizing" => 34,
+    "generally" => 51,
+    "generals" => 24,
+    "generate" => 54,
+    "generated" => 56,
+    "generates" => 49,
+    "generating" => 49,
+    "generation" => 50,
+    "generations" => 34,
+    "generative" => 24,
+    "generator" => 48,
+    "generators" => 44,
+    "generic" => 55,
+    "generically" => 37,
+    "generosity" => 33,
+    "generous" => 39,
+    "generously" => 31,
+    "genesis" => 36,
+    "genetic" => 24,
+    "geneva" => 39,
+    "genevan" => 24,
+    "genial" => 32,
+    "geniality" => 27,
+    "genially" => 27,
+    "genie" => 24,
+    "genii" => 24,
+    "genitive" => 30,
+    "genius" => 34,
+    "genoa" => 31,
+    "genres" => 24,
+    "genteel" => 24,
+    "gentiles" => 24,
+    "gentle" => 41,
+    "gentleman" => 44,
+    "gentleman's" => 32,
+    "gentlemanly" => 29,
+    "gentlemen" => 39,
+    "gentleness" => 34,
+    "gentler" => 24,
+    "gently" => 38,
+    "genuine" => 32,
+    "genuinely" => 34,
+    "genuineness" => 24,
+    "genus" => 24,
+    "geo" => 36,
+    "geodesic" => 29,
+    "geoff" => 27,
+    "geoffrey" => 27,
+    "geographic" => 31,
+    "geographical" => 32,
+    "geographically" => 24,
+    "geography" => 32,
+    "geology" => 27,
+    "geometric" => 37,
+    "geometrically" => 24,
+    "geometry" => 40,
+    "geopolitical" => 24,
+    "george" => 43,
+    "georgetown" => 30,
+    "georgia" => 30,
+    "georgian" => 30,
+    "gerald" => 24,
+    "german" => 42,
+    "germanic" => 37,
+    "germans" => 27,
+    "germany" => 36,
+    "gerry" => 24,
+    "gestalt" => 24,
+    "gesticulating" => 24,
+    "gesticulation" => 24,
+    "gesticulations" => 24,
+    "gesture" => 35,
+    "gestures" => 33,
+    "get" => 59,
+    "gets" => 51,
+    "getting" => 52,
+    "geyser" => 29,
+    "ghastly" => 35,
+    "gherkin" => 34,
+    "ghost" => 40,
+    "ghostly" => 33,
+    "ghosts" => 35,
+    "giant" => 35,
+    "giants" => 24,
+    "gibberish" => 30,
+    "gibbs" => 24,
+    "gibe" => 27,
+    "giblet" => 24,
+    "gibraltar" => 24,
+    "giddy" => 32,
+    "gifford" => 38,
+    "gift" => 34,
+    "gifted" => 27,
+    "gifts" => 24,
+    "gig" => 27,
+    "gigabits" => 24,
+    "gigabyte" => 32,
+    "gigabytes" => 30,
+    "gigantic" => 36,
+    "giggles" => 24,
+    "gilded" => 29,
+    "gilding" => 24,
+    "gilt" => 27,
+    "gimme" => 29,
+    "gimp" => 24,
+    "gin" => 30,
+    "gingerly" => 27,
+    "gins" => 24,
+    "gipsies" => 32,
+    "gipsy" => 24,
+    "girded" => 24,
+    "girders" => 27,
+    "girding" => 24,
+    "girl" => 46,
+    "girlfriend" => 24,
+    "girlhood" => 24,
+    "girls" => 41,
+    "girt" => 24,
+    "gis" => 27,
+    "gist" => 30,
+    "git" => 55,
+    "gits" => 24,
+    "give" => 54,
+    "giveaway" => 24,
+    "given" => 57,
+    "giver" => 24,
+    "gives" => 51,
+    "giveth" => 24,
+    "giving" => 46,
+    "gizmos" => 29,
+    "glacial" => 24,
+    "glacier" => 31,
+    "glaciers" => 29,
+    "glad" => 43,
+    "glade" => 30,
+    "gladly" => 34,
+    "gladness" => 31,
+    "gladstone" => 27,
+    "glam" => 33,
+    "glamour" => 27,
+    "glance" => 41,
+    "glanced" => 41,
+    "glances" => 32,
+    "glancing" => 39,
+    "glands" => 24,
+    "glare" => 33,
+    "glared" => 29,
+    "glaring" => 32,
+    "glasgow" => 24,
+    "glass" => 42,
+    "glasses" => 36,
+    "glazed" => 27,
+    "glazes" => 24,
+    "gleam" => 33,
+    "gleamed" => 24,
+    "gleaming" => 32,
+    "glean" => 24,
+    "gleaned" => 24,
+    "glen" => 27,
+    "glenn" => 29,
+    "glens" => 24,
+    "glib" => 40,
+    "glide" => 27,
+    "glided" => 32,
+    "glides" => 24,
+    "gliding" => 24,
+    "glimmer" => 32,
+    "glimmered" => 29,
+    "glimmering" => 27,
+    "glimmers" => 24,
+    "glimpse" => 37,
+    "glimpsed" => 27,
+    "glimpses" => 31,
+    "glint" => 24,
+    "glinted" => 24,
+    "glinting" => 24,
+    "glints" => 27,
+    "glisten" => 24,
+    "glistened" => 24,
+    "glistening" => 31,
+    "glitch" => 30,
+    "glitches" => 33,
+    "glitter" => 30,
+    "glittered" => 24,
+    "glittering" => 33,
+    "gloated" => 24,
+    "gloating" => 24,
+    "glob" => 49,
+    "global" => 54,
+    "globally" => 43,
+    "globe" => 34,
+    "globs" => 40,
+    "gloom" => 38,
+    "gloomiest" => 24,
+    "gloomily" => 31,
+    "gloomy" => 36,
+    "gloria" => 24,
+    "gloried" => 24,
+    "glorified" => 27,
+    "glorious" => 35,
+    "gloriously" => 24,
+    "glory" => 37,
+    "glorying" => 24,
+    "gloss" => 24,
+    "glossary" => 36,
+    "glossed" => 29,
+    "glossy" => 27,
+    "glottal" => 24,
+    "glove" => 31,
+    "gloved" => 24,
+    "gloves" => 36,
+    "glow" => 36,
+    "glowed" => 31,
+    "glowering" => 24,
+    "glowing" => 34,
+    "glue" => 42,
+    "glued" => 29,
+    "glues" => 24,
+    "gluon" => 29,
+    "glut" => 27,
+    "glutted" => 24,
+    "glyph" => 42,
+    "glyphs" => 40,
+    "gmt" => 32,
+    "gnarly" => 24,
+    "gnashed" => 30,
+    "gnashes" => 24,
+    "gnashing" => 24,
+    "gnat" => 39,
+    "gnawed" => 24,
+    "gnawing" => 24,
+    "gnome" => 44,
+    "gnosis" => 27,
+    "gnu" => 49,
+    "go" => 56,
+    "goading" => 24,
+    "goal" => 48,
+    "goals" => 46,
+    "goat" => 29,
+    "goatee" => 24,
+    "goats" => 24,
+    "goblin" => 31,
+    "goby" => 30,
+    "god" => 44,
+    "goddard" => 24,
+    "goddess" => 31,
+    "godfrey" => 31,
+    "godlike" => 30,
+    "gods" => 30,
+    "godwin" => 24,
+    "goes" => 49,
+    "goggles" => 24,
+    "going" => 52,
+    "gold" => 41,
+    "golden" => 38,
+    "goldfish" => 27,
+    "goldilocks" => 24,
+    "golf" => 33,
+    "gone" => 47,
+    "goner" => 30,
+    "goners" => 27,
+    "gong" => 24,
+    "gongs" => 24,
+    "goo" => 24,
+    "good" => 54,
+    "goodbye" => 24,
+    "goodies" => 27,
+    "goodly" => 29,
+    "goodness" => 37,
+    "goods" => 27,
+    "goodwill" => 27,
+    "goody" => 27,
+    "gooey" => 24,
+    "goose" => 40,
+    "gooseberry" => 24,
+    "gordon" => 35,
+    "gorgeous" => 32,
+    "gorgeously" => 24,
+    "gorgeousness" => 24,
+    "goring" => 24,
+    "gory" => 27,
+    "gosh" => 24,
+    "gospel" => 29,
+    "gossip" => 33,
+    "gossiped" => 24,
+    "gossiping" => 24,
+    "gossips" => 24,
+    "got" => 52,
+    "gothic" => 30,
+    "gotten" => 35,
+    "gouging" => 27,
+    "gourd" => 27,
+    "gourmands" => 24,
+    "gov" => 33,
+    "govern" => 33,
+    "governance" => 24,
+    "governed" => 35,
+    "governess" => 29,
+    "governesses" => 27,
+    "governing" => 32,
+    "government" => 41,
+    "governments" => 30,
+    "governor" => 37,
+    "governors" => 24,
+    "governs" => 27,
+    "gown" => 33,
+    "gowned" => 24,
+    "gowns" => 27,
+    "gpo" => 24,
+    "grab" => 38,
+    "grabbed" => 31,
+    "grabber" => 30,
+    "grabbing" => 32,
+    "grabs" => 29,
+    "grace" => 36,
+    "graceful" => 39,
+    "gracefully" => 41,
+    "graceless" => 24,
+    "graces" => 24,
+    "gracie" => 27,
+    "gracious" => 30,
+    "grade" => 36,
+    "graders" => 27,
+    "grades" => 29,
+    "gradient" => 38,
+    "gradients" => 36,
+    "gradual" => 30,
+    "gradually" => 40,
+    "graduate" => 38,
+    "graduated" => 29,
+    "graduating" => 24,
+    "graham" => 27,
+    "grail" => 27,
+    "grain" => 37,
+    "grained" => 42,
+    "grains" => 24,
+    "gram" => 24,
+    "grammar" => 46,
+    "grammarians" => 24,
+    "grammars" => 39,
+    "grammatical" => 34,
+    "grammatically" => 24,
+    "grams" => 27,
+    "grand" => 37,
+    "grander" => 24,
+    "grandeur" => 32,
+    "grandfather" => 29,
+    "grandly" => 27,
+    "grandmothers" => 27,
+    "grandpa" => 31,
+    "grandparent" => 24,
+    "granite" => 24,
+    "granny" => 24,
+    "grant" => 40,
+    "granted" => 41,
+    "grantee" => 24,
+    "granting" => 29,
+    "grants" => 31,
+    "granular" => 37,
+    "granularity" => 36,
+    "granularly" => 27,
+    "granule" => 29,
+    "grape" => 29,
+    "grapes" => 24,
+    "graph" => 47,
+    "grapheme" => 41,
+    "graphemes" => 34,
+    "graphic" => 37,
+    "graphical" => 39,
+    "graphics" => 46,
+    "graphing" => 24,
+    "graphite" => 24,
+    "graphs" => 41,
+    "grapple" => 24,
+    "grappled" => 24,
+    "grappling" => 24,
+    "grasp" => 38,
+    "grasped" => 35,
+    "grasping" => 29,
+    "grass" => 40,
+    "grasses" => 24,
+    "grate" => 29,
+    "grated" => 27,
+    "grateful" => 37,
+    "gratefully" => 30,
+    "gratefulness" => 24,
+    "grates" => 24,
+    "gratification" => 31,
+    "gratified" => 32,
+    "gratify" => 24,
+    "gratifying" => 27,
+    "grating" => 32,
+    "gratings" => 24,
+    "gratitude" => 39,
+    "gratuitous" => 24,
+    "gratuitously" => 24,
+    "grave" => 41,
+    "gravel" => 35,
+    "gravely" => 35,
+    "graven" => 24,
+    "graver" => 24,
+    "graves" => 30,
+    "gravesend" => 31,
+    "gravest" => 27,
+    "graveyard" => 33,
+    "gravitated" => 27,
+    "gravitating" => 24,
+    "gravitation" => 24,
+    "graviton" => 35,
+    "gravity" => 33,
+    "gravy" => 27,
+    "gray" => 42,
+    "grayish" => 24,
+    "grayscale" => 33,
+    "grazed" => 24,
+    "grease" => 33,
+    "greasy" => 27,
+    "great" => 51,
+    "greatcoat" => 24,
+    "greater" => 51,
+    "greatest" => 43,
+    "greatly" => 42,
+    "greatness" => 27,
+    "greece" => 32,
+    "greedily" => 29,
+    "greedy" => 33,
+    "greek" => 46,
+    "greeks" => 31,
+    "green" => 42,
+    "greene" => 24,
+    "greenest" => 24,
+    "greenhouse" => 24,
+    "greenland" => 24,
+    "greenness" => 24,
+    "greenwich" => 31,
+    "greenwood" => 24,
+    "greet" => 24,
+    "greeted" => 27,
+    "greeting" => 34,
+    "greetings" => 24,
+    "greets" => 27,
+    "greg" => 30,
+    "gregarious" => 24,
+    "gregorian" => 35,
+    "gregory" => 32,
+    "grenadier" => 24,
+    "grew" => 42,
+    "grey" => 43,
+    "greyish" => 29,
+    "grid" => 43,
+    "grids" => 32,
+    "grief" => 40,
+    "grievance" => 24,
+    "grievances" => 27,
+    "grieve" => 29,
+    "grieved" => 30,
+    "grieving" => 30,
+    "grievous" => 27,
+    "grievously" => 24,
+    "griffin" => 24,
+    "grim" => 30,
+    "grimace" => 24,
+    "grimaces" => 29,
+    "grime" => 24,
+    "grimly" => 27,
+    "grin" => 35,
+    "grind" => 24,
+    "grinder" => 27,
+    "grinding" => 24,
+    "grinned" => 30,
+    "grinning" => 32,
+    "grins" => 24,
+    "grip" => 32,
+    "gripped" => 24,
+    "gripping" => 27,
+    "grisly" => 29,
+    "grit" => 32,
+    "gritty" => 33,
+    "grizzled" => 32,
+    "groan" => 35,
+    "groaned" => 33,
+    "groaning" => 33,
+    "groans" => 33,
+    "grocer" => 27,
+    "groceries" => 24,
+    "grocery" => 27,
+    "groin" => 24,
+    "groom" => 31,
+    "groomed" => 24,
+    "groovy" => 29,
+    "grope" => 24,
+    "groped" => 32,
+    "groping" => 32,
+    "gross" => 31,
+    "grossly" => 24,
+    "grossness" => 24,
+    "grotesque" => 33,
+    "grottoes" => 24,
+    "ground" => 45,
+    "grounded" => 27,
+    "grounds" => 37,
+    "groundwork" => 29,
+    "group" => 53,
+    "grouped" => 40,
+    "grouping" => 39,
+    "groups" => 49,
+    "grove" => 24,
+    "grovel" => 24,
+    "groves" => 27,
+    "grow" => 44,
+    "growing" => 41,
+    "growl" => 30,
+    "growled" => 27,
+    "growling" => 27,
+    "growls" => 29,
+    "grown" => 40,
+    "grows" => 38,
+    "growth" => 39,
+    "growths" => 24,
+    "grub" => 27,
+    "grudge" => 27,
+    "grudges" => 24,
+    "grudging" => 24,
+    "gruff" => 30,
+    "gruffly" => 24,
+    "gruffness" => 24,
+    "grumbled" => 29,
+    "grunt" => 24,
+    "grunted" => 32,
+    "grunting" => 24,
+    "gryphon" => 41,
+    "guarantee" => 48,
+    "guaranteed" => 51,
+    "guaranteeing" => 34,
+    "guarantees" => 49,
+    "guaranties" => 24,
+    "guarantying" => 24,
+    "guard" => 48,
+    "guarded" => 40,
+    "guardian" => 32,
+    "guardians" => 24,
+    "guardianship" => 24,
+    "guarding" => 33,
+    "guards" => 42,
+    "guardsmen" => 29,
+    "guess" => 44,
+    "guessed" => 38,
+    "guesses" => 35,
+    "guessing" => 40,
+    "guesswork" => 24,
+    "guest" => 44,
+    "guests" => 36,
+    "guidance" => 40,
+    "guide" => 51,
+    "guided" => 41,
+    "guideline" => 33,
+    "guidelines" => 45,
+    "guides" => 39,
+    "guiding" => 33,
+    "guild" => 32,
+    "guilds" => 24,
+    "guile" => 27,
+    "guileful" => 24,
+    "guilford" => 24,
+    "guillotining" => 24,
+    "guilt" => 36,
+    "guiltiest" => 24,
+    "guiltily" => 24,
+    "guiltless" => 31,
+    "guiltlessness" => 24,
+    "guilty" => 37,
+    "guinea" => 34,
+    "guineas" => 32,
+    "guise" => 27,
+    "guitar" => 30,
+    "gujarati" => 27,
+    "gulf" => 29,
+    "gulfs" => 24,
+    "gullet" => 24,
+    "gullible" => 27,
+    "gulls" => 29,
+    "gully" => 24,
+    "gulp" => 27,
+    "gum" => 29,
+    "gummed" => 24,
+    "gun" => 35,
+    "guns" => 33,
+    "gunshots" => 24,
+    "gunwale" => 24,
+    "guppy" => 27,
+    "gurgling" => 24,
+    "guru" => 27,
+    "gus" => 24,
+    "gush" => 32,
+    "gushed" => 27,
+    "gushes" => 24,
+    "gust" => 29,
+    "gusts" => 27,
+    "gut" => 24,
+    "guts" => 31,
+    "gutter" => 27,
+    "guttering" => 24,
+    "guy" => 34,
+    "guys" => 27,
+    "gym" => 27,
+    "gymnast" => 27,
+    "gymnastic" => 27,
+    "gymnastics" => 27,
+    "gymnasts" => 27,
+    "gyp" => 36,
+    "gypsies" => 24,
+    "gyroscope" => 24,
+    "ha" => 39,
+    "habeas" => 24,
+    "habiliments" => 27,
+    "habit" => 39,
+    "habitable" => 24,
+    "habitation" => 31,
+    "habitations" => 27,
+    "habitats" => 24,
+    "habits" => 37,
+    "habitual" => 29,
+    "hack" => 41,
+    "hacked" => 29,
+    "hacker" => 32,
+    "hackers" => 27,
+    "hackett" => 24,
+    "hacking" => 36,
+    "hacks" => 34,
+    "had" => 60,
+    "hadn't" => 41,
+    "hag" => 24,
+    "haggard" => 32,
+    "hague" => 24,
+    "hah" => 27,
+    "haiku" => 41,
+    "hail" => 29,
+    "hailed" => 33,
+    "hair" => 44,
+    "hair's" => 24,
+    "haired" => 31,
+    "hairline" => 24,
+    "hairs" => 31,
+    "haitian" => 27,
+    "hal" => 44,
+    "hale" => 24,
+    "half" => 55,
+    "halftone" => 31,
+    "halftones" => 33,
+    "halfway" => 42,
+    "halifax" => 24,
+    "hall" => 42,
+    "hallo" => 27,
+    "halls" => 27,
+    "hallway" => 24,
+    "halo" => 30,
+    "hals" => 34,
+    "halt" => 36,
+    "halted" => 32,
+    "halting" => 32,
+    "halton" => 24,
+    "halts" => 24,
+    "halve" => 34,
+    "halved" => 32,
+    "halves" => 40,
+    "halving" => 32,
+    "ham" => 31,
+    "hamburg" => 27,
+    "hamlet" => 27,
+    "hamlets" => 24,
+    "hammer" => 27,
+    "hammered" => 24,
+    "hammerheads" => 24,
+    "hammering" => 27,
+    "hammers" => 27,
+    "hamming" => 35,
+    "hampered" => 24,
+    "hampers" => 24,
+    "hampshire" => 31,
+    "hampstead" => 24,
+    "hams" => 27,
+    "hancock" => 24,
+    "hand" => 52,
+    "handbook" => 27,
+    "handcuffs" => 24,
+    "handed" => 41,
+    "handful" => 38,
+    "handfuls" => 24,
+    "handheld" => 24,
+    "handily" => 24,
+    "handing" => 33,
+    "handkerchief" => 36,
+    "handkerchiefs" => 27,
+    "handle" => 55,
+    "handlebars" => 38,
+    "handled" => 49,
+    "handler" => 50,
+    "handlers" => 45,
+    "handles" => 49,
+    "handling" => 53,
+    "handmade" => 29,
+    "handout" => 24,
+    "hands" => 48,
+    "handshake" => 41,
+    "handshakes" => 24,
+    "handshaking" => 30,
+    "handsome" => 36,
+    "handsomely" => 29,
+    "handsprings" => 24,
+    "handwork" => 24,
+    "handwrite" => 24,
+    "handwriting" => 32,
+    "handwritten" => 38,
+    "handy" => 42,
+    "hang" => 45,
+    "hanged" => 29,
+    "hanging" => 39,
+    "hangman" => 24,
+    "hangs" => 43,
+    "hangup" => 27,
+    "hanoi" => 27,
+    "hanover" => 27,
+    "hans" => 29,
+    "hansom" => 32,
+    "haphazardly" => 24,
+    "hapless" => 29,
+    "happen" => 49,
+    "happened" => 46,
+    "happening" => 41,
+    "happenings" => 27,
+    "happens" => 50,
+    "happenstance" => 24,
+    "happier" => 33,
+    "happiest" => 30,
+    "happily" => 40,
+    "happiness" => 42,
+    "happy" => 46,
+    "harassed" => 29,
+    "harbor" => 24,
+    "harbour" => 35,
+    "hard" => 50,
+    "hardcopy" => 42,
+    "harden" => 27,
+    "hardened" => 34,
+    "hardening" => 30,
+    "harder" => 41,
+    "hardest" => 33,
+    "hardhat" => 24,
+    "hardihood" => 24,
+    "harding" => 27,
+    "hardly" => 46,
+    "hardness" => 24,
+    "hardship" => 30,
+    "hardships" => 32,
+    "hardware" => 50,
+    "hardy" => 32,
+    "hare" => 39,
+    "harem" => 24,
+    "hark" => 24,
+    "harlequin" => 24,
+    "harm" => 40,
+    "harmed" => 27,
+    "harmful" => 33,
+    "harmless" => 35,
+    "harmlessly" => 24,
+    "harmonic" => 27,
+    "harmonious" => 27,
+    "harmonised" => 24,
+    "harmonium" => 24,
+    "harmonization" => 27,
+    "harmonize" => 24,
+    "harmonized" => 27,
+    "harmony" => 36,
+    "harness" => 42,
+    "harnessed" => 27,
+    "harnesses" => 32,
+    "harp" => 24,
+    "harper" => 40,
+    "harpers" => 29,
+    "harris" => 27,
+    "harrison" => 36,
+    "harrow" => 29,
+    "harrowed" => 24,
+    "harrowing" => 29,
+    "harsh" => 35,
+    "harshly" => 29,
+    "harshness" => 24,
+    "hart" => 38,
+    "hartford" => 24,
+    "harts" => 24,
+    "harvard" => 36,
+    "harvest" => 30,
+    "harvesting" => 24,
+    "harvey" => 24,
+    "has" => 62,
+    "hash" => 52,
+    "hashed" => 41,
+    "hasher" => 44,
+    "hashes" => 43,
+    "hashing" => 46,
+    "hasn't" => 42,
+    "hasp" => 24,
+    "hassle" => 35,
+    "haste" => 32,
+    "hasten" => 34,
+    "hastened" => 36,
+    "hastening" => 27,
+    "hastily" => 39,
+    "hasty" => 34,
+    "hat" => 43,
+    "hatch" => 34,
+    "hatchet" => 24,
+    "hatching" => 27,
+    "hate" => 38,
+    "hated" => 36,
+    "hateful" => 34,
+    "hates" => 27,
+    "hating" => 31,
+    "hatless" => 24,
+    "hatred" => 36,
+    "hats" => 32,
+    "hatter" => 41,
+    "hatters" => 24,
+    "hauberks" => 24,
+    "haughtily" => 27,
+    "haughty" => 29,
+    "haul" => 27,
+    "hauled" => 24,
+    "hauling" => 24,
+    "haunches" => 24,
+    "haunt" => 24,
+    "haunted" => 36,
+    "haunting" => 24,
+    "haunts" => 27,
+    "hausa" => 24,
+    "hauteur" => 24,
+    "have" => 64,
+    "haven" => 32,
+    "haven't" => 45,
+    "haves" => 24,
+    "having" => 52,
+    "havoc" => 32,
+    "hawk" => 24,
+    "hawks" => 24,
+    "hawthorn" => 24,
+    "hay" => 32,
+    "hayes" => 32,
+    "hayling" => 24,
+    "hayloft" => 24,
+    "hays" => 24,
+    "haystack" => 38,
+    "haystacks" => 29,
+    "hazard" => 35,
+    "hazarded" => 24,
+    "hazardous" => 30,
+    "hazards" => 33,
+    "haze" => 27,
+    "hazy" => 24,
+    "he" => 61,
+    "he'd" => 39,
+    "he'll" => 35,
+    "he's" => 43,
+    "head" => 50,
+    "headache" => 29,
+    "headaches" => 30,
+    "headboard" => 27,
+    "headed" => 39,
+    "header" => 52,
+    "headers" => 50,
+    "headgear" => 24,
+    "heading" => 37,
+    "headings" => 27,
+    "headless" => 35,
+    "headlights" => 27,
+    "headline" => 29,
+    "headlong" => 24,
+    "headquarters" => 30,
+    "heads" => 40,
+    "headstrong" => 24,
+    "headway" => 27,
+    "heady" => 24,
+    "heal" => 24,
+    "healed" => 27,
+    "healey" => 34,
+    "healing" => 29,
+    "health" => 40,
+    "healthy" => 32,
+    "heap" => 51,
+    "heaped" => 30,
+    "heaps" => 32,
+    "hear" => 46,
+    "heard" => 49,
+    "hearer" => 24,
+    "hearers" => 27,
+    "hearing" => 38,
+    "hears" => 29,
+    "hearse" => 29,
+    "heart" => 48,
+    "heartbreak" => 24,
+    "heartbreaking" => 24,
+    "hearted" => 36,
+    "heartfelt" => 29,
+    "hearth" => 27,
+    "hearthrug" => 24,
+    "heartier" => 24,
+    "heartily" => 36,
+    "heartless" => 30,
+    "hearts" => 37,
+    "hearty" => 33,
+    "heat" => 38,
+    "heated" => 29,
+    "heath" => 29,
+    "heathen" => 24,
+    "heathenish" => 24,
+    "heather" => 24,
+    "heaths" => 27,
+    "heave" => 29,
+    "heaved" => 29,
+    "heaven" => 39,
+    "heavenly" => 30,
+    "heavens" => 39,
+    "heavenward" => 29,
+    "heavier" => 34,
+    "heavily" => 45,
+    "heaving" => 29,
+    "heavy" => 46,
+    "heavyweight" => 29,
+    "hebrew" => 43,
+    "heck" => 32,
+    "hedge" => 33,
+    "hedged" => 24,
+    "hedgehog" => 32,
+    "hedgehogs" => 29,
+    "hedges" => 32,
+    "heed" => 37,
+    "heeded" => 27,
+    "heedful" => 24,
+    "heedless" => 24,
+    "heel" => 32,
+    "heels" => 36,
+    "height" => 48,
+    "heightened" => 31,
+    "heights" => 32,
+    "heinous" => 27,
+    "heir" => 24,
+    "heiress" => 27,
+    "heirs" => 24,
+    "held" => 46,
+    "helen" => 31,
+    "helium" => 27,
+    "helix" => 35,
+    "hell" => 36,
+    "hellenic" => 24,
+    "hellish" => 33,
+    "hello" => 46,
+    "helm" => 27,
+    "helmut" => 34,
+    "help" => 56,
+    "helped" => 40,
+    "helper" => 48,
+    "helpers" => 45,
+    "helpful" => 46,
+    "helpfully" => 32,
+    "helping" => 40,
+    "helpless" => 36,
+    "helplessly" => 32,
+    "helplessness" => 27,
+    "helps" => 47,
+    "helvetica" => 24,
+    "hematite" => 30,
+    "hemisphere" => 27,
+    "hemp" => 30,
+    "hempstead" => 24,
+    "hen" => 29,
+    "hence" => 45,
+    "henceforth" => 24,
+    "henderson" => 27,
+    "henrietta" => 38,
+    "henry" => 41,
+    "her" => 57,
+    "herald" => 27,
+    "herb" => 29,
+    "herbage" => 27,
+    "herbert" => 24,
+    "hercules" => 29,
+    "herd" => 31,
+    "herded" => 24,
+    "herding" => 24,
+    "herds" => 24,
+    "here" => 57,
+    "hereafter" => 30,
+    "hereby" => 36,
+    "hereditary" => 30,
+    "hereford" => 27,
+    "herefordshire" => 27,
+    "herein" => 32,
+    "hereunto" => 24,
+    "herman" => 24,
+    "hermes" => 24,
+    "hermit" => 39,
+    "hermits" => 27,
+    "hero" => 34,
+    "heroes" => 33,
+    "heroic" => 30,
+    "heroine" => 24,
+    "heroism" => 27,
+    "herons" => 24,
+    "hers" => 35,
+    "herself" => 46,
+    "hesitant" => 24,
+    "hesitantly" => 24,
+    "hesitate" => 40,
+    "hesitated" => 36,
+    "hesitating" => 31,
+    "hesitatingly" => 30,
+    "hesitation" => 34,
+    "hess" => 30,
+    "hessians" => 24,
+    "het" => 27,
+    "heterogeneous" => 36,
+    "heuristic" => 36,
+    "heuristics" => 38,
+    "hew" => 24,
+    "hewed" => 24,
+    "hewitt" => 27,
+    "hex" => 48,
+    "hexadecimal" => 45,
+    "hexagon" => 42,
+    "hexagonal" => 33,
+    "hexagons" => 24,
+    "hexameter" => 24,
+    "hey" => 34,
+    "hi" => 43,
+    "hiccups" => 27,
+    "hick" => 24,
+    "hickey" => 24,
+    "hickory" => 35,
+    "hicks" => 24,
+    "hid" => 41,
+    "hidden" => 48,
+    "hide" => 45,
+    "hideous" => 36,
+    "hideously" => 29,
+    "hideousness" => 27,
+    "hides" => 37,
+    "hiding" => 42,
+    "hierarchical" => 37,
+    "hierarchically" => 29,
+    "hierarchies" => 33,
+    "hierarchy" => 42,
+    "hieroglyphs" => 24,
+    "high" => 53,
+    "highballs" => 29,
+    "higher" => 51,
+    "highest" => 45,
+    "highlands" => 27,
+    "highlight" => 51,
+    "highlighted" => 49,
+    "highlighter" => 29,
+    "highlighting" => 53,
+    "highlights" => 41,
+    "highly" => 47,
+    "highness" => 24,
+    "highroad" => 31,
+    "highs" => 33,
+    "highway" => 27,
+    "highways" => 24,
+    "hijack" => 24,
+    "hijacked" => 24,
+    "hilarious" => 27,
+    "hilariously" => 27,
+    "hilarity" => 31,
+    "hill" => 40,
+    "hills" => 37,
+    "hillside" => 24,
+    "hilly" => 24,
+    "hilt" => 27,
+    "hilts" => 24,
+    "him" => 57,
+    "himalaya" => 29,
+    "himself" => 50,
+    "hind" => 27,
+    "hinder" => 24,
+    "hindered" => 24,
+    "hinders" => 27,
+    "hindi" => 33,
+    "hindrance" => 29,
+    "hindrances" => 24,
+    "hindsight" => 29,
+    "hinges" => 29,
+    "hint" => 46,
+    "hinted" => 32,
+    "hinting" => 35,
+    "hints" => 44,
+    "hip" => 40,
+    "hippopotamus" => 24,
+    "hips" => 29,
+    "hire" => 34,
+    "hired" => 33,
+    "hires" => 24,
+    "his" => 60,
+    "hiss" => 27,
+    "hissed" => 24,
+    "hissing" => 24,
+    "histogram" => 40,
+    "histograms" => 34,
+    "historians" => 32,
+    "historic" => 33,
+    "historical" => 43,
+    "historically" => 40,
+    "histories" => 35,
+    "history" => 51,
+    "hit" => 47,
+    "hitch" => 31,
+    "hitchcock" => 24,
+    "hitched" => 24,
+    "hither" => 31,
+    "hitherto" => 37,
+    "hits" => 34,
+    "hitting" => 40,
+    "hiv" => 24,
+    "hive" => 24,
+    "hoard" => 27,
+    "hoarse" => 33,
+    "hoarsely" => 27,
+    "hoarser" => 24,
+    "hoax" => 24,
+    "hobbies" => 24,
+    "hobby" => 32,
+    "hobbyists" => 24,
+    "hoc" => 33,
+    "hockey" => 38,
+    "hodgepodge" => 24,
+    "hodges" => 24,
+    "hog" => 30,
+    "hogged" => 24,
+    "hogs" => 27,
+    "hogshead" => 29,
+    "hogsheads" => 30,
+    "hoist" => 29,
+    "hoisted" => 34,
+    "hoisting" => 24,
+    "holborn" => 27,
+    "hold" => 49,
+    "holder" => 38,
+    "holders" => 34,
+    "holding" => 45,
+    "holdings" => 29,
+    "holds" => 46,
+    "hole" => 41,
+    "holes" => 37,
+    "holiday" => 34,
+    "holidays" => 24,
+    "holiest" => 24,
+    "holistic" => 31,
+    "holland" => 35,
+    "holler" => 29,
+    "hollis" => 29,
+    "hollow" => 36,
+    "holloway" => 29,
+    "hollowed" => 27,
+    "hollowly" => 27,
+    "holly" => 24,
+    "hollywood" => 27,
+    "holm" => 24,
+    "holmes" => 51,
+    "holocaust" => 24,
+    "holy" => 32,
+    "homage" => 24,
+    "home" => 51,
+    "homecoming" => 24,
+    "homed" => 24,
+    "homeless" => 29,
+    "homely" => 31,
+    "homer" => 24,
+    "homes" => 30,
+    "homesick" => 27,
+    "homesickness" => 27,
+    "homesteads" => 24,
+    "homeward" => 34,
+    "homework" => 24,
+    "homogeneity" => 24,
+    "homogeneous" => 35,
+    "homograph" => 24,
+    "honest" => 40,
+    "honestly" => 30,
+    "honesty" => 29,
+    "honey" => 27,
+    "honeycomb" => 29,
+    "honeymoon" => 27,
+    "honeypot" => 27,
+    "hong" => 43,
+    "honor" => 36,
+    "honorable" => 27,
+    "honored" => 33,
+    "honoring" => 24,
+    "honors" => 31,
+    "honour" => 41,
+    "honourable" => 37,
+    "honoured" => 30,
+    "honours" => 29,
+    "hood" => 42,
+    "hoofs" => 29,
+    "hook" => 49,
+    "hookah" => 31,
+    "hooked" => 27,
+    "hookey" => 31,
+    "hooking" => 30,
+    "hooks" => 47,
+    "hookup" => 24,
+    "hoop" => 27,
+    "hooray" => 24,
+    "hooting" => 27,
+    "hop" => 37,
+    "hope" => 47,
+    "hoped" => 40,
+    "hopeful" => 31,
+    "hopefully" => 41,
+    "hopeless" => 35,
+    "hopelessly" => 30,
+    "hopelessness" => 27,
+    "hopes" => 41,
+    "hoping" => 36,
+    "hopkins" => 30,
+    "hopped" => 27,
+    "hopper" => 24,
+    "hopping" => 27,
+    "hops" => 27,
+    "horace" => 27,
+    "horde" => 24,
+    "horizon" => 37,
+    "horizontal" => 44,
+    "horizontally" => 43,
+    "horn" => 33,
+    "hornbeams" => 24,
+    "horns" => 30,
+    "horrible" => 40,
+    "horribly" => 33,
+    "horrid" => 36,
+    "horridly" => 24,
+    "horrific" => 24,
+    "horrify" => 27,
+    "horror" => 42,
+    "horrors" => 34,
+    "hors" => 27,
+    "horse" => 38,
+    "horseback" => 32,
+    "horsemen" => 27,
+    "horses" => 36,
+    "horsewhipped" => 29,
+    "horsey" => 27,
+    "horsham" => 34,
+    "hosea" => 27,
+    "hosed" => 24,
+    "hospitable" => 29,
+    "hospital" => 27,
+    "hospitality" => 32,
+    "hospitals" => 24,
+    "host" => 52,
+    "hosted" => 41,
+    "hostile" => 32,
+    "hostility" => 24,
+    "hosting" => 39,
+    "hostname" => 40,
+    "hosts" => 43,
+    "hot" => 43,
+    "hotdog" => 24,
+    "hotel" => 39,
+    "hotels" => 31,
+    "hotkey" => 32,
+    "hotkeys" => 30,
+    "hotter" => 24,
+    "hound" => 35,
+    "hour" => 48,
+    "hourly" => 29,
+    "hours" => 47,
+    "house" => 51,
+    "housed" => 24,
+    "household" => 35,
+    "households" => 24,
+    "housekeeper" => 27,
+    "housemaid" => 33,
+    "houses" => 41,
+    "housing" => 27,
+    "hove" => 30,
+    "hovel" => 35,
+    "hovels" => 24,
+    "hover" => 31,
+    "hovered" => 33,
+    "hovering" => 31,
+    "hovers" => 27,
+    "how" => 59,
+    "howard" => 34,
+    "howe" => 33,
+    "howell" => 27,
+    "however" => 57,
+    "howl" => 32,
+    "howled" => 27,
+    "howling" => 33,
+    "hub" => 36,
+    "hubbub" => 30,
+    "hubert" => 24,
+    "hubris" => 24,
+    "huckleberry" => 38,
+    "huddled" => 31,
+    "hudson" => 33,
+    "hue" => 32,
+    "huff" => 24,
+    "huffed" => 24,
+    "hug" => 30,
+    "huge" => 45,
+    "hugged" => 27,
+    "hugging" => 33,
+    "hugh" => 31,
+    "hughes" => 24,
+    "hugo" => 29,
+    "huh" => 27,
+    "hulk" => 27,
+    "hulking" => 30,
+    "hull" => 33,
+    "hulled" => 27,
+    "hullo" => 30,
+    "hum" => 35,
+    "human" => 49,
+    "humane" => 24,
+    "humanist" => 24,
+    "humanists" => 24,
+    "humanities" => 42,
+    "humanity" => 34,
+    "humanization" => 24,
+    "humanize" => 24,
+    "humankind" => 30,
+    "humans" => 38,
+    "humble" => 32,
+    "humbler" => 24,
+    "humbly" => 27,
+    "humdrum" => 24,
+    "humid" => 24,
+    "humidor" => 24,
+    "humiliated" => 27,
+    "humiliation" => 32,
+    "humility" => 24,
+    "humming" => 29,
+    "humorous" => 24,
+    "humorously" => 24,
+    "humour" => 31,
+    "humoured" => 24,
+    "humours" => 24,
+    "hump" => 27,
+    "humpbacked" => 24,
+    "humph" => 24,
+    "hundred" => 46,
+    "hundredfold" => 24,
+    "hundreds" => 40,
+    "hundredth" => 24,
+    "hung" => 41,
+    "hungarian" => 33,
+    "hungary" => 24,
+    "hunger" => 37,
+    "hungered" => 24,
+    "hungrier" => 24,
+    "hungrily" => 27,
+    "hungry" => 36,
+    "hunk" => 30,
+    "hunks" => 24,
+    "hunt" => 35,
+    "hunted" => 33,
+    "hunter" => 37,
+    "hunter's" => 24,
+    "hunting" => 36,
+    "hurd" => 35,
+    "hurdles" => 27,
+    "hurled" => 32,
+    "hurling" => 27,
+    "hurrah" => 32,
+    "hurrahs" => 27,
+    "hurricane" => 29,
+    "hurried" => 42,
+    "hurriedly" => 33,
+    "hurries" => 24,
+    "hurry" => 40,
+    "hurrying" => 32,
+    "hurt" => 38,
+    "hurts" => 32,
+    "husband" => 40,
+    "husbands" => 24,
+    "hush" => 36,
+    "hushed" => 31,
+    "hushing" => 24,
+    "huskier" => 24,
+    "husky" => 33,
+    "hustled" => 24,
+    "hut" => 33,
+    "huts" => 30,
+    "huzzah" => 30,
+    "hyaline" => 24,
+    "hybrid" => 37,
+    "hyde" => 24,
+    "hydrate" => 24,
+    "hydrates" => 24,
+    "hydration" => 30,
+    "hydraulic" => 34,
+    "hydraulics" => 24,
+    "hydrochloric" => 24,
+    "hydroplane" => 30,
+    "hygiene" => 40,
+    "hygienic" => 24,
+    "hymn" => 32,
+    "hype" => 24,
+    "hyper" => 44,
+    "hyperbola" => 24,
+    "hyperbolic" => 37,
+    "hypercritical" => 27,
+    "hyperfine" => 29,
+    "hypersonic" => 24,
+    "hypertext" => 31,
+    "hyphen" => 34,
+    "hyphenating" => 30,
+    "hyphenation" => 32,
+    "hyphens" => 35,
+    "hypocritical" => 24,
+    "hypotenuse" => 29,
+    "hypotheses" => 29,
+    "hypothesis" => 33,
+    "hypothetical" => 35,
+    "hysteria" => 27,
+    "hysterical" => 29,
+    "hysterics" => 24,
+    "i" => 65,
+    "i'd" => 45,
+    "i'll" => 48,
+    "i'm" => 48,
+    "i've" => 47,
+    "ian" => 35,
+    "ice" => 41,
+    "iceberg" => 27,
+    "iced" => 37,
+    "icelandic" => 34,
+    "ices" => 27,
+    "icon" => 45,
+    "iconic" => 29,
+    "iconography" => 30,
+    "icons" => 42,
+    "icosahedron" => 27,
+    "icy" => 32,
+    "ida" => 24,
+    "ide" => 43,
+    "idea" => 50,
+    "ideal" => 42,
+    "idealised" => 24,
+    "ideally" => 41,
+    "ideas" => 45,
+    "idem" => 38,
+    "idempotent" => 32,
+    "identical" => 47,
+    "identically" => 38,
+    "identifiable" => 30,
+    "identification" => 37,
+    "identified" => 43,
+    "identifier" => 53,
+    "identifiers" => 48,
+    "identifies" => 39,
+    "identify" => 45,
+    "identifying" => 40,
+    "identities" => 33,
+    "identity" => 45,
+    "ideograph" => 34,
+    "ideographs" => 27,
+    "ideological" => 24,
+    "ideologically" => 24,
+    "ideologues" => 24,
+    "ideology" => 24,
+    "ides" => 36,
+    "idiom" => 36,
+    "idiomatic" => 45,
+    "idiomatically" => 30,
+    "idioms" => 38,
+    "idiosyncrasies" => 24,
+    "idiosyncratic" => 24,
+    "idiot" => 30,
+    "idiotic" => 27,
+    "idiotically" => 24,
+    "idle" => 41,
+    "idled" => 27,
+    "idleness" => 30,
+    "idler" => 27,
+    "idlers" => 24,
+    "idles" => 24,
+    "idling" => 24,
+    "idly" => 27,
+    "idol" => 27,
+    "idols" => 24,
+    "idris" => 27,
+    "ids" => 44,
+    "if" => 68,
+    "ifs" => 27,
+    "igbo" => 24,
+    "ignoble" => 24,
+    "ignominious" => 24,
+    "ignominy" => 30,
+    "ignorance" => 35,
+    "ignorant" => 38,
+    "ignorantly" => 24,
+    "ignore" => 51,
+    "ignored" => 51,
+    "ignores" => 43,
+    "ignoring" => 45,
+    "iii" => 38,
+    "ill" => 43,
+    "illegal" => 46,
+    "illegally" => 24,
+    "illinois" => 34,
+    "illiterate" => 27,
+    "illness" => 34,
+    "illogical" => 32,
+    "illuminate" => 29,
+    "illuminated" => 32,
+    "illuminating" => 24,
+    "illumined" => 24,
+    "illusion" => 24,
+    "illustrate" => 39,
+    "illustrated" => 42,
+    "illustrates" => 41,
+    "illustrating" => 27,
+    "illustration" => 36,
+    "illustrations" => 34,
+    "illustrative" => 30,
+    "illustrator" => 27,
+    "illustrious" => 32,
+    "image" => 55,
+    "imaged" => 24,
+    "imagery" => 34,
+    "images" => 49,
+    "imaginable" => 29,
+    "imaginary" => 39,
+    "imagination" => 39,
+    "imaginations" => 30,
+    "imaginative" => 27,
+    "imagine" => 44,
+    "imagined" => 38,
+    "imaging" => 39,
+    "imagining" => 27,
+    "imbecile" => 29,
+    "imbecility" => 24,
+    "imbedded" => 24,
+    "imbibed" => 27,
+    "imbue" => 24,
+    "imbued" => 30,
+    "imf" => 29,
+    "imitate" => 32,
+    "imitated" => 27,
+    "imitates" => 24,
+    "imitation" => 32,
+    "imitations" => 29,
+    "immaculate" => 24,
+    "immature" => 30,
+    "immaturity" => 24,
+    "immeasurable" => 24,
+    "immeasurably" => 24,
+    "immediate" => 46,
+    "immediately" => 50,
+    "immense" => 39,
+    "immensely" => 31,
+    "immensity" => 24,
+    "immersed" => 24,
+    "immersing" => 24,
+    "immigrants" => 27,
+    "imminence" => 24,
+    "imminent" => 31,
+    "immoderate" => 24,
+    "immoderately" => 24,
+    "immorality" => 24,
+    "immortal" => 29,
+    "immortality" => 24,
+    "immovable" => 29,
+    "immune" => 31,
+    "immunities" => 24,
+    "immunity" => 24,
+    "immunization" => 24,
+    "immured" => 27,
+    "immutability" => 34,
+    "immutable" => 49,
+    "immutably" => 38,
+    "imo" => 24,
+    "imp" => 29,
+    "impact" => 43,
+    "impacted" => 33,
+    "impacting" => 31,
+    "impacts" => 32,
+    "impaired" => 27,
+    "impairing" => 24,
+    "impart" => 27,
+    "imparted" => 30,
+    "impartial" => 24,
+    "imparting" => 27,
+    "impassable" => 27,
+    "impasse" => 27,
+    "impassible" => 27,
+    "impassioned" => 30,
+    "impassive" => 24,
+    "impatience" => 36,
+    "impatient" => 37,
+    "impatiently" => 36,
+    "impeachment" => 31,
+    "impeccable" => 24,
+    "impedance" => 24,
+    "impede" => 24,
+    "impeded" => 24,
+    "impediment" => 32,
+    "impediments" => 24,
+    "impeding" => 27,
+    "impelled" => 31,
+    "impend" => 24,
+    "impending" => 29,
+    "impenetrable" => 31,
+    "imperative" => 34,
+    "imperatively" => 27,
+    "imperceptible" => 24,
+    "imperceptibly" => 24,
+    "imperfect" => 34,
+    "imperfections" => 24,
+    "imperial" => 29,
+    "imperialism" => 24,
+    "imperil" => 24,
+    "imperilled" => 24,
+    "imperious" => 24,
+    "imperishable" => 24,
+    "impersonal" => 29,
+    "impersonally" => 24,
+    "impersonate" => 24,
+    "impersonation" => 29,
+    "impertinent" => 30,
+    "imperturbable" => 27,
+    "imperturbably" => 24,
+    "impervious" => 24,
+    "impetuous" => 27,
+    "impetuously" => 24,
+    "impetus" => 24,
+    "implacable" => 24,
+    "implausible" => 24,
+    "implement" => 56,
+    "implementation" => 59,
+    "implementations" => 54,
+    "implemented" => 55,
+    "implementer" => 27,
+    "implementing" => 52,
+    "implementor" => 37,
+    "implements" => 54,
+    "implicate" => 29,
+    "implicated" => 27,
+    "implicates" => 24,
+    "implicating" => 24,
+    "implication" => 30,
+    "implications" => 41,
+    "implicit" => 48,
+    "implicitly" => 47,
+    "implied" => 42,
+    "implies" => 45,
+    "implode" => 27,
+    "implore" => 30,
+    "implored" => 30,
+    "implores" => 24,
+    "imploring" => 24,
+    "imply" => 39,
+    "implying" => 34,
+    "import" => 52,
+    "importable" => 29,
+    "importance" => 40,
+    "important" => 52,
+    "importantly" => 38,
+    "importation" => 31,
+    "imported" => 48,
+    "importer" => 33,
+    "importers" => 31,
+    "importing" => 44,
+    "imports" => 47,
+    "importunate" => 27,
+    "impose" => 37,
+    "imposed" => 37,
+    "imposes" => 36,
+    "imposing" => 34,
+    "impossibilities" => 24,
+    "impossibility" => 31,
+    "impossible" => 47,
+    "impossibly" => 24,
+    "imposts" => 30,
+    "impotence" => 27,
+    "impotent" => 27,
+    "impracticability" => 24,
+    "impracticable" => 27,
+    "impractical" => 34,
+    "imprecate" => 24,
+    "imprecations" => 24,
+    "imprecise" => 29,
+    "imprecisely" => 24,
+    "imprecision" => 24,
+    "impress" => 27,
+    "impressed" => 36,
+    "impression" => 40,
+    "impressionability" => 24,
+    "impressions" => 32,
+    "impressive" => 35,
+    "impressively" => 27,
+    "impressiveness" => 27,
+    "imprint" => 24,
+    "imprinted" => 27,
+    "imprisoned" => 30,
+    "imprisoning" => 24,
+    "imprisonment" => 33,
+    "imprisons" => 24,
+    "improbabilities" => 24,
+    "improbable" => 31,
+    "improper" => 37,
+    "improperly" => 32,
+    "improve" => 49,
+    "improved" => 47,
+    "improvement" => 42,
+    "improvements" => 47,
+    "improves" => 41,
+    "improving" => 43,
+    "improvisations" => 24,
+    "improvised" => 24,
+    "imprudence" => 29,
+    "imprudent" => 27,
+    "imprudently" => 27,
+    "impudence" => 24,
+    "impulse" => 36,
+    "impulses" => 29,
+    "impulsive" => 24,
+    "impulsively" => 24,
+    "impunity" => 31,
+    "impure" => 24,
+    "in" => 72,
+    "ina" => 24,
+    "inability" => 33,
+    "inaccessible" => 36,
+    "inaccuracies" => 24,
+    "inaccurate" => 34,
+    "inaction" => 27,
+    "inactive" => 38,
+    "inactivity" => 34,
+    "inadequate" => 32,
+    "inadmissible" => 24,
+    "inadvertent" => 27,
+    "inadvertently" => 34,
+    "inadvisable" => 29,
+    "inanimate" => 32,
+    "inapplicable" => 24,
+    "inappropriate" => 34,
+    "inappropriately" => 29,
+    "inarticulate" => 29,
+    "inarticulately" => 24,
+    "inasmuch" => 29,
+    "inaudible" => 27,
+    "inaugurated" => 24,
+    "inauguration" => 24,
+    "inbound" => 27,
+    "inbuilt" => 24,
+    "incalculable" => 29,
+    "incantation" => 30,
+    "incantations" => 30,
+    "incapable" => 33,
+    "incarnate" => 24,
+    "incarnation" => 30,
+    "incarnations" => 24,
+    "incendiaries" => 24,
+    "incentives" => 27,
+    "inception" => 30,
+    "incessant" => 30,
+    "incessantly" => 34,
+    "inch" => 35,
+    "inches" => 34,
+    "incident" => 38,
+    "incidental" => 27,
+    "incidentally" => 27,
+    "incidents" => 36,
+    "incipient" => 24,
+    "incisive" => 29,
+    "incitement" => 24,
+    "incites" => 24,
+    "inciting" => 27,
+    "incl" => 29,
+    "inclemency" => 27,
+    "inclination" => 29,
+    "inclinations" => 27,
+    "incline" => 24,
+    "inclined" => 37,
+    "include" => 56,
+    "included" => 54,
+    "includes" => 53,
+    "including" => 55,
+    "inclusion" => 55,
+    "inclusions" => 24,
+    "inclusive" => 45,
+    "inclusively" => 34,
+    "incognito" => 27,
+    "incoherent" => 33,
+    "income" => 34,
+    "incomes" => 24,
+    "incoming" => 43,
+    "incommoded" => 24,
+    "incomparable" => 30,
+    "incompatibilities" => 37,
+    "incompatibility" => 37,
+    "incompatible" => 47,
+    "incompatibles" => 24,
+    "incompatibly" => 24,
+    "incomplete" => 45,
+    "incompletely" => 24,
+    "incomprehensible" => 24,
+    "inconceivable" => 30,
+    "incongruities" => 29,
+    "incongruous" => 24,
+    "inconsequential" => 31,
+    "inconsequentially" => 24,
+    "inconsiderate" => 24,
+    "inconsistencies" => 35,
+    "inconsistency" => 34,
+    "inconsistent" => 44,
+    "inconsistently" => 35,
+    "inconstant" => 27,
+    "inconvenience" => 33,
+    "inconvenienced" => 24,
+    "inconveniences" => 24,
+    "inconvenient" => 39,
+    "incorporate" => 37,
+    "incorporated" => 35,
+    "incorporates" => 32,
+    "incorporating" => 33,
+    "incorrect" => 47,
+    "incorrectly" => 43,
+    "incorrigible" => 24,
+    "incorruptible" => 27,
+    "increase" => 47,
+    "increased" => 45,
+    "increases" => 42,
+    "increasing" => 44,
+    "increasingly" => 38,
+    "incredible" => 32,
+    "incredibly" => 38,
+    "incredulity" => 30,
+    "incredulous" => 32,
+    "incredulously" => 31,
+    "increment" => 44,
+    "incremental" => 43,
+    "incrementally" => 37,
+    "incremented" => 38,
+    "incrementing" => 38,
+    "increments" => 39,
+    "incriminate" => 24,
+    "incriminated" => 27,
+    "incubation" => 24,
+    "incumbent" => 24,
+    "incur" => 36,
+    "incurable" => 24,
+    "incurably" => 24,
+    "incurred" => 27,
+    "incurring" => 32,
+    "incurs" => 35,
+    "inde" => 27,
+    "indebted" => 30,
+    "indecent" => 24,
+    "indecipherable" => 24,
+    "indecision" => 27,
+    "indecisively" => 24,
+    "indeed" => 47,
+    "indefatigable" => 29,
+    "indefatigably" => 24,
+    "indefinable" => 24,
+    "indefinite" => 32,
+    "indefinitely" => 38,
+    "indelible" => 27,
+    "indelibly" => 24,
+    "indemnified" => 24,
+    "indent" => 51,
+    "indentation" => 43,
+    "indentations" => 24,
+    "indented" => 43,
+    "indenting" => 45,
+    "indents" => 39,
+    "independence" => 31,
+    "independent" => 47,
+    "independently" => 43,
+    "indescribable" => 27,
+    "indestructible" => 24,
+    "indeterminate" => 37,
+    "index" => 55,
+    "indexed" => 45,
+    "indexes" => 44,
+    "indexing" => 46,
+    "india" => 42,
+    "indian" => 43,
+    "indiana" => 29,
+    "indianapolis" => 24,
+    "indians" => 40,
+    "indicate" => 49,
+    "indicated" => 44,
+    "indicates" => 50,
+    "indicating" => 46,
+    "indication" => 39,
+    "indications" => 30,
+    "indicative" => 32,
+    "indicator" => 37,
+    "indicators" => 29,
+    "indices" => 48,
+    "indictment" => 27,
+    "indies" => 24,
+    "indifference" => 32,
+    "indifferent" => 33,
+    "indigenous" => 27,
+    "indigestion" => 24,
+    "indignant" => 32,
+    "indignantly" => 32,
+    "indignation" => 34,
+    "indignity" => 24,
+    "indigo" => 24,
+    "indirect" => 40,
+    "indirection" => 38,
+    "indirectly" => 40,
+    "indiscernible" => 24,
+    "indiscreet" => 24,
+    "indiscreetly" => 24,
+    "indiscretion" => 24,
+    "indiscriminately" => 24,
+    "indispensable" => 30,
+    "indisposition" => 24,
+    "indistinct" => 24,
+    "indistinctly" => 24,
+    "indistinguishable" => 32,
+    "indistinguishably" => 24,
+    "individual" => 48,
+    "individuality" => 27,
+    "individualized" => 27,
+    "individually" => 41,
+    "individuals" => 35,
+    "indivisible" => 24,
+    "indolence" => 24,
+    "indonesia" => 24,
+    "indonesian" => 29,
+    "indoors" => 29,
+    "induce" => 35,
+    "induced" => 35,
+    "inducing" => 29,
+    "induction" => 24,
+    "inductive" => 24,
+    "indulge" => 30,
+    "indulged" => 31,
+    "indulgence" => 29,
+    "indulgently" => 24,
+    "indulging" => 27,
+    "indus" => 24,
+    "industrial" => 32,
+    "industrialization" => 24,
+    "industries" => 30,
+    "industrious" => 24,
+    "industry" => 37,
+    "ineffable" => 24,
+    "ineffective" => 32,
+    "ineffectual" => 31,
+    "inefficiencies" => 27,
+    "inefficient" => 41,
+    "inefficiently" => 24,
+    "inelegant" => 24,
+    "ineligible" => 24,
+    "ineptly" => 24,
+    "inequalities" => 27,
+    "inequality" => 38,
+    "inert" => 33,
+    "inescapable" => 24,
+    "inessential" => 24,
+    "inestimable" => 29,
+    "inevitable" => 34,
+    "inevitably" => 33,
+    "inexact" => 24,
+    "inexactness" => 24,
+    "inexhaustible" => 30,
+    "inexorable" => 31,
+    "inexpensive" => 33,
+    "inexpensively" => 24,
+    "inexperience" => 29,
+    "inexperienced" => 29,
+    "inexplicable" => 30,
+    "inexpressible" => 27,
+    "inextinguishable" => 24,
+    "inextricable" => 24,
+    "inextricably" => 24,
+    "inf" => 36,
+    "infallibility" => 30,
+    "infallible" => 39,
+    "infallibly" => 27,
+    "infamous" => 30,
+    "infamously" => 24,
+    "infamy" => 24,
+    "infancy" => 31,
+    "infant" => 32,
+    "infantile" => 24,
+    "infantry" => 29,
+    "infatuated" => 24,
+    "infatuation" => 24,
+    "infeasible" => 24,
+    "infect" => 24,
+    "infected" => 27,
+    "infection" => 27,
+    "infections" => 24,
+    "infectious" => 29,
+    "infer" => 42,
+    "inference" => 44,
+    "inferences" => 31,
+    "inferior" => 33,
+    "inferiors" => 24,
+    "infernal" => 30,
+    "inferno" => 33,
+    "inferred" => 45,
+    "inferring" => 33,
+    "infers" => 32,
+    "infested" => 27,
+    "infidels" => 24,
+    "infiltration" => 24,
+    "infinite" => 44,
+    "infinitely" => 37,
+    "infinitesimal" => 27,
+    "infinities" => 29,
+    "infinity" => 44,
+    "infirmities" => 24,
+    "infirmity" => 24,
+    "infix" => 30,
+    "inflamed" => 31,
+    "inflate" => 35,
+    "inflated" => 27,
+    "inflates" => 24,
+    "inflating" => 24,
+    "inflation" => 24,
+    "inflected" => 34,
+    "inflection" => 29,
+    "inflectional" => 33,
+    "inflections" => 30,
+    "inflexibility" => 24,
+    "inflexible" => 29,
+    "inflict" => 32,
+    "inflicted" => 32,
+    "inflicting" => 24,
+    "infliction" => 24,
+    "inflicts" => 24,
+    "influence" => 42,
+    "influenced" => 39,
+    "influences" => 35,
+    "influencing" => 30,
+    "influential" => 24,
+    "info" => 51,
+    "inform" => 39,
+    "informal" => 35,
+    "informality" => 24,
+    "informally" => 31,
+    "informatica" => 24,
+    "informatics" => 30,
+    "information" => 58,
+    "informational" => 35,
+    "informative" => 36,
+    "informed" => 39,
+    "informer" => 29,
+    "informers" => 24,
+    "informing" => 30,
+    "informs" => 32,
+    "infra" => 35,
+    "infrastructure" => 44,
+    "infrastructures" => 24,
+    "infrequent" => 30,
+    "infrequently" => 24,
+    "infringe" => 29,
+    "infringed" => 27,
+    "infringement" => 24,
+    "infuse" => 24,
+    "infused" => 29,
+    "infusing" => 24,
+    "ingenious" => 30,
+    "ingenuity" => 30,
+    "ingest" => 36,
+    "ingested" => 29,
+    "ingestion" => 24,
+    "inglorious" => 24,
+    "ingot" => 27,
+    "ingratiate" => 24,
+    "ingratitude" => 29,
+    "ingredient" => 24,
+    "inhabit" => 35,
+    "inhabitable" => 27,
+    "inhabitant" => 30,
+    "inhabitants" => 36,
+    "inhabited" => 38,
+    "inhabits" => 27,
+    "inhale" => 24,
+    "inhaled" => 24,
+    "inhaling" => 24,
+    "inherent" => 44,
+    "inherently" => 39,
+    "inherit" => 42,
+    "inheritable" => 31,
+    "inheritance" => 42,
+    "inherited" => 42,
+    "inheriting" => 38,
+    "inherits" => 39,
+    "inhibit" => 30,
+    "inhibited" => 29,
+    "inhibits" => 31,
+    "inhospitably" => 27,
+    "inhuman" => 24,
+    "inimitably" => 24,
+    "initial" => 49,
+    "initialisation" => 34,
+    "initialisations" => 24,
+    "initialise" => 32,
+    "initialised" => 32,
+    "initialization" => 48,
+    "initializations" => 38,
+    "initialize" => 49,
+    "initialized" => 49,
+    "initializes" => 40,
+    "initializing" => 42,
+    "initially" => 41,
+    "initials" => 33,
+    "initiate" => 37,
+    "initiated" => 36,
+    "initiates" => 36,
+    "initiating" => 30,
+    "initiation" => 27,
+    "initiative" => 36,
+    "initiatives" => 29,
+    "initiator" => 27,
+    "inject" => 39,
+    "injected" => 40,
+    "injecting" => 33,
+    "injection" => 38,
+    "injections" => 24,
+    "injector" => 27,
+    "injects" => 32,
+    "injunction" => 27,
+    "injure" => 29,
+    "injured" => 34,
+    "injuries" => 33,
+    "injuring" => 27,
+    "injuriously" => 24,
+    "injury" => 35,
+    "injustice" => 33,
+    "ink" => 43,
+    "inks" => 24,
+    "inkstand" => 24,
+    "inkwell" => 24,
+    "inky" => 24,
+    "inlaid" => 24,
+    "inline" => 49,
+    "inman" => 24,
+    "inmate" => 24,
+    "inmates" => 27,
+    "inmost" => 24,
+    "inn" => 36,
+    "inner" => 50,
+    "innermost" => 35,
+    "innocence" => 38,
+    "innocent" => 40,
+    "innocently" => 32,
+    "innocents" => 24,
+    "innovating" => 24,
+    "innovation" => 29,
+    "innovations" => 32,
+    "innovative" => 31,
+    "inns" => 24,
+    "innuendo" => 27,
+    "innumerable" => 36,
+    "input" => 56,
+    "inputs" => 49,
+    "inputted" => 29,
+    "inputting" => 33,
+    "inquest" => 33,
+    "inquests" => 29,
+    "inquietude" => 24,
+    "inquire" => 31,
+    "inquired" => 40,
+    "inquirer" => 29,
+    "inquirers" => 27,
+    "inquiries" => 35,
+    "inquiring" => 32,
+    "inquiringly" => 24,
+    "inquiry" => 36,
+    "inquisitions" => 24,
+    "inquisitive" => 27,
+    "inquisitively" => 24,
+    "inquisitiveness" => 27,
+    "inroads" => 24,
+    "ins" => 42,
+    "insane" => 27,
+    "insanely" => 27,
+    "insanity" => 30,
+    "insatiable" => 29,
+    "insatiate" => 24,
+    "inscribed" => 24,
+    "inscription" => 24,
+    "inscriptions" => 27,
+    "inscrutable" => 30,
+    "insect" => 31,
+    "insects" => 27,
+    "insecure" => 39,
+    "insensibility" => 24,
+    "insensible" => 27,
+    "insensibly" => 27,
+    "insensitive" => 43,
+    "insensitively" => 31,
+    "insensitivity" => 29,
+    "inseparable" => 27,
+    "insert" => 56,
+    "inserted" => 49,
+    "inserting" => 47,
+    "insertion" => 44,
+    "insertions" => 37,
+    "inserts" => 45,
+    "inside" => 54,
+    "insider" => 29,
+    "insidious" => 24,
+    "insight" => 36,
+    "insightful" => 30,
+    "insights" => 33,
+    "insignia" => 24,
+    "insignificance" => 24,
+    "insignificant" => 27,
+    "insincerity" => 27,
+    "insinuating" => 27,
+    "insipid" => 24,
+    "insist" => 33,
+    "insisted" => 37,
+    "insistently" => 24,
+    "insisting" => 27,
+    "insists" => 27,
+    "insofar" => 32,
+    "insolence" => 27,
+    "insolent" => 30,
+    "insolently" => 27,
+    "inspect" => 44,
+    "inspected" => 39,
+    "inspecting" => 41,
+    "inspection" => 41,
+    "inspections" => 24,
+    "inspector" => 44,
+    "inspectors" => 24,
+    "inspects" => 33,
+    "inspiration" => 41,
+    "inspirational" => 24,
+    "inspirations" => 31,
+    "inspire" => 33,
+    "inspired" => 47,
+    "inspiring" => 32,
+    "inspirited" => 29,
+    "inspiriting" => 24,
+    "instability" => 29,
+    "install" => 54,
+    "installation" => 51,
+    "installations" => 35,
+    "installed" => 52,
+    "installer" => 42,
+    "installers" => 29,
+    "installing" => 46,
+    "installs" => 39,
+    "instance" => 56,
+    "instanced" => 24,
+    "instances" => 51,
+    "instant" => 45,
+    "instantaneous" => 32,
+    "instantaneously" => 24,
+    "instantly" => 42,
+    "instants" => 32,
+    "instead" => 60,
+    "instep" => 24,
+    "instigate" => 24,
+    "instigated" => 29,
+    "instigating" => 27,
+    "instinct" => 34,
+    "instinctive" => 24,
+    "instinctively" => 30,
+    "instincts" => 29,
+    "institute" => 35,
+    "instituted" => 27,
+    "institutes" => 24,
+    "institution" => 37,
+    "institutions" => 37,
+    "instruct" => 39,
+    "instructed" => 34,
+    "instructing" => 32,
+    "instruction" => 52,
+    "instructional" => 31,
+    "instructions" => 53,
+    "instructive" => 29,
+    "instructor" => 24,
+    "instructors" => 24,
+    "instructs" => 38,
+    "instrument" => 41,
+    "instrumental" => 27,
+    "instrumentation" => 41,
+    "instruments" => 36,
+    "insufferably" => 24,
+    "insufficient" => 43,
+    "insufficiently" => 37,
+    "insular" => 27,
+    "insulate" => 27,
+    "insult" => 30,
+    "insulted" => 31,
+    "insulting" => 24,
+    "insultingly" => 24,
+    "insuperable" => 24,
+    "insupportable" => 29,
+    "insurance" => 27,
+    "insure" => 27,
+    "insures" => 24,
+    "insurmountable" => 32,
+    "insurrection" => 31,
+    "insurrections" => 24,
+    "intact" => 36,
+    "intangible" => 24,
+    "integer" => 58,
+    "integers" => 58,
+    "integral" => 41,
+    "integrate" => 45,
+    "integrated" => 43,
+    "integrates" => 39,
+    "integrating" => 41,
+    "integration" => 52,
+    "integrator" => 29,
+    "integrators" => 29,
+    "integrity" => 43,
+    "intel" => 47,
+    "intellect" => 30,
+    "intellectual" => 38,
+    "intellectually" => 24,
+    "intelligence" => 38,
+    "intelligent" => 38,
+    "intelligently" => 33,
+    "intelligible" => 27,
+    "intend" => 41,
+    "intended" => 52,
+    "intending" => 33,
+    "intends" => 37,
+    "intense" => 37,
+    "intensely" => 24,
+    "intensified" => 29,
+    "intensity" => 34,
+    "intensive" => 36,
+    "intent" => 41,
+    "intention" => 40,
+    "intentional" => 37,
+    "intentionally" => 55,
+    "intentions" => 33,
+    "intently" => 34,
+    "intents" => 32,
+    "inter" => 39,
+    "interact" => 45,
+    "interacted" => 30,
+    "interacting" => 44,
+    "interaction" => 42,
+    "interactions" => 41,
+    "interactive" => 44,
+    "interactively" => 35,
+    "interacts" => 39,
+    "intercept" => 35,
+    "intercepted" => 33,
+    "intercepting" => 29,
+    "interception" => 27,
+    "interceptions" => 24,
+    "interceptor" => 24,
+    "intercepts" => 33,
+    "interchange" => 38,
+    "interchangeable" => 36,
+    "interchangeably" => 33,
+    "interchanged" => 24,
+    "interchanging" => 24,
+    "intercom" => 24,
+    "interconnectedness" => 27,
+    "intercourse" => 32,
+    "interdependencies" => 24,
+    "interdependent" => 24,
+    "interdisciplinary" => 27,
+    "interest" => 47,
+    "interested" => 47,
+    "interesting" => 46,
+    "interestingly" => 31,
+    "interests" => 37,
+    "interface" => 55,
+    "interfaces" => 48,
+    "interfacing" => 38,
+    "interfere" => 42,
+    "interfered" => 32,
+    "interference" => 32,
+    "interferes" => 34,
+    "interfering" => 33,
+    "interim" => 32,
+    "interior" => 46,
+    "interjected" => 29,
+    "interjection" => 24,
+    "interjections" => 24,
+    "interlarded" => 24,
+    "interleave" => 42,
+    "interleaved" => 39,
+    "interleaves" => 32,
+    "interleaving" => 32,
+    "interlined" => 29,
+    "interlocutor" => 24,
+    "intermarriage" => 24,
+    "intermediaries" => 24,
+    "intermediary" => 29,
+    "intermediate" => 52,
+    "intermediately" => 27,
+    "interment" => 27,
+    "intermezzo" => 24,
+    "interminable" => 30,
+    "intermingling" => 24,
+    "intermittent" => 30,
+    "intermittently" => 24,
+    "intermix" => 27,
+    "intermixed" => 32,
+    "intern" => 33,
+    "internal" => 53,
+    "internalized" => 24,
+    "internally" => 49,
+    "internals" => 41,
+    "international" => 43,
+    "internationalisation" => 24,
+    "internationalization" => 36,
+    "internationalize" => 24,
+    "internationalized" => 35,
+    "internationally" => 27,
+    "interned" => 35,
+    "internet" => 45,
+    "interning" => 36,
+    "internment" => 30,
+    "interns" => 27,
+    "interpol" => 24,
+    "interpolate" => 29,
+    "interpolated" => 38,
+    "interpolates" => 27,
+    "interpolating" => 34,
+    "interpolation" => 41,
+    "interpolations" => 27,
+    "interpolator" => 24,
+    "interposed" => 27,
+    "interpret" => 43,
+    "interpretation" => 43,
+    "interpretations" => 29,
+    "interpretative" => 27,
+    "interpreted" => 49,
+    "interpreter" => 44,
+    "interpreters" => 36,
+    "interpreting" => 36,
+    "interprets" => 39,
+    "interrelated" => 27,
+    "interrogate" => 24,
+    "interrogatives" => 24,
+    "interrupt" => 46,
+    "interruptable" => 24,
+    "interrupted" => 43,
+    "interruptible" => 29,
+    "interrupting" => 38,
+    "interruption" => 35,
+    "interrupts" => 42,
+    "intersect" => 29,
+    "intersected" => 27,
+    "intersection" => 39,
+    "intersections" => 32,
+    "intersects" => 24,
+    "interspersed" => 31,
+    "interspersing" => 24,
+    "interstellar" => 27,
+    "intertwined" => 31,
+    "interval" => 47,
+    "intervals" => 39,
+    "intervene" => 36,
+    "intervened" => 27,
+    "intervening" => 33,
+    "intervention" => 32,
+    "interview" => 35,
+    "interviewed" => 24,
+    "intimacy" => 31,
+    "intimate" => 36,
+    "intimated" => 24,
+    "intimately" => 30,
+    "intimation" => 24,
+    "intimidated" => 24,
+    "into" => 62,
+    "intolerable" => 34,
+    "intonation" => 27,
+    "intonations" => 27,
+    "intoxicated" => 27,
+    "intoxicating" => 27,
+    "intoxication" => 27,
+    "intra" => 37,
+    "intractable" => 24,
+    "intrepid" => 27,
+    "intricacies" => 27,
+    "intricate" => 33,
+    "intrigue" => 27,
+    "intriguing" => 27,
+    "intrinsic" => 51,
+    "intrinsically" => 31,
+    "intro" => 53,
+    "introduce" => 46,
+    "introduced" => 47,
+    "introduces" => 43,
+    "introducing" => 43,
+    "introduction" => 47,
+    "introductions" => 31,
+    "introductory" => 33,
+    "introspect" => 31,
+    "introspected" => 24,
+    "introspecting" => 24,
+    "introspection" => 40,
+    "introspective" => 29,
+    "intrude" => 30,
+    "intruded" => 24,
+    "intruder" => 27,
+    "intruding" => 27,
+    "intrusion" => 31,
+    "intrusions" => 27,
+    "intrusive" => 40,
+    "intrusted" => 27,
+    "intuiting" => 24,
+    "intuition" => 33,
+    "intuitions" => 24,
+    "intuitive" => 39,
+    "intuitively" => 32,
+    "inuit" => 24,
+    "inundation" => 24,
+    "inuring" => 24,
+    "invade" => 24,
+    "invaded" => 31,
+    "invader" => 24,
+    "invaders" => 27,
+    "invalid" => 53,
+    "invalidate" => 41,
+    "invalidated" => 37,
+    "invalidates" => 37,
+    "invalidating" => 29,
+    "invalidation" => 29,
+    "invalided" => 24,
+    "invaluable" => 32,
+    "invariable" => 24,
+    "invariably" => 34,
+    "invariance" => 29,
+    "invariant" => 44,
+    "invasion" => 29,
+    "invasions" => 27,
+    "invasive" => 27,
+    "invective" => 24,
+    "inveigh" => 24,
+    "inveigled" => 24,
+    "invent" => 34,
+    "invented" => 35,
+    "inventing" => 27,
+    "invention" => 31,
+    "inventions" => 27,
+    "inventor" => 24,
+    "inventors" => 24,
+    "inventory" => 34,
+    "inverse" => 42,
+    "inversely" => 24,
+    "inverses" => 27,
+    "inversion" => 37,
+    "inversions" => 24,
+    "invert" => 40,
+    "inverted" => 37,
+    "inverting" => 27,
+    "inverts" => 24,
+    "invest" => 29,
+    "invested" => 29,
+    "investigate" => 37,
+    "investigated" => 32,
+    "investigates" => 24,
+    "investigating" => 32,
+    "investigation" => 38,
+    "investigations" => 31,
+    "investing" => 24,
+    "investment" => 36,
+    "investments" => 31,
+    "investor" => 27,
+    "invests" => 24,
+    "inveterate" => 29,
+    "invigorated" => 24,
+    "invincible" => 29,
+    "inviolate" => 29,
+    "invisible" => 40,
+    "invisibly" => 30,
+    "invitation" => 34,
+    "invitations" => 29,
+    "invite" => 36,
+    "invited" => 38,
+    "invites" => 27,
+    "inviting" => 29,
+    "invocable" => 24,
+    "invocation" => 49,
+    "invocations" => 43,
+    "invoke" => 47,
+    "invoked" => 51,
+    "invokes" => 41,
+    "invoking" => 45,
+    "involuntarily" => 31,
+    "involuntary" => 29,
+    "involve" => 40,
+    "involved" => 46,
+    "involvement" => 29,
+    "involves" => 43,
+    "involving" => 41,
+    "invulnerable" => 27,
+    "inward" => 30,
+    "inwardly" => 30,
+    "inwards" => 27,
+    "ionic" => 24,
+    "ions" => 29,
+    "iota" => 38,
+    "ious" => 27,
+    "iow" => 24,
+    "iowa" => 29,
+    "ipa" => 29,
+    "ireland" => 34,
+    "irene" => 36,
+    "iris" => 31,
+    "irish" => 33,
+    "irksome" => 32,
+    "iron" => 41,
+    "ironed" => 24,
+    "ironic" => 24,
+    "ironically" => 29,
+    "irons" => 24,
+    "irony" => 24,
+    "irradiated" => 24,
+    "irradiation" => 24,
+    "irrecoverable" => 24,
+    "irreducible" => 32,
+    "irrefutable" => 42,
+    "irregular" => 35,
+    "irregularities" => 24,
+    "irregularity" => 24,
+    "irregularly" => 27,
+    "irrelevant" => 37,
+    "irrelevantly" => 24,
+    "irremediable" => 24,
+    "irreparable" => 29,
+    "irreproachable" => 24,
+    "irresistible" => 35,
+    "irresistibly" => 27,
+    "irresolute" => 24,
+    "irresolutely" => 24,
+    "irresolution" => 24,
+    "irrespective" => 32,
+    "irresponsible" => 24,
+    "irretrievable" => 27,
+    "irretrievably" => 24,
+    "irrevocably" => 29,
+    "irrigated" => 24,
+    "irritability" => 24,
+    "irritable" => 24,
+    "irritated" => 29,
+    "irritating" => 24,
+    "irritation" => 24,
+    "is" => 72,
+    "isaac" => 32,
+    "isbn" => 30,
+    "isis" => 32,
+    "island" => 41,
+    "islands" => 36,
+    "isle" => 32,
+    "isles" => 31,
+    "islets" => 24,
+    "isn't" => 53,
+    "iso" => 47,
+    "isocline" => 24,
+    "isolate" => 34,
+    "isolated" => 39,
+    "isolates" => 24,
+    "isolating" => 27,
+    "isolation" => 39,
+    "isomorphic" => 34,
+    "israel" => 29,
+    "israeli" => 24,
+    "issue" => 55,
+    "issued" => 41,
+    "issuer" => 36,
+    "issues" => 53,
+    "issuing" => 34,
+    "it" => 69,
+    "it'd" => 32,
+    "it'll" => 41,
+    "it's" => 58,
+    "italian" => 38,
+    "italians" => 27,
+    "italic" => 40,
+    "italicized" => 27,
+    "italics" => 36,
+    "italy" => 33,
+    "itch" => 24,
+    "itched" => 24,
+    "item" => 56,
+    "items" => 58,
+    "iterate" => 44,
+    "iterated" => 36,
+    "iterates" => 39,
+    "iterating" => 44,
+    "iteration" => 48,
+    "iterations" => 38,
+    "iterative" => 38,
+    "iterator" => 55,
+    "itinerant" => 24,
+    "itinerary" => 27,
+    "its" => 60,
+    "itself" => 54,
+    "ivan" => 33,
+    "ivory" => 29,
+    "ivy" => 24,
+    "jabber" => 24,
+    "jabbered" => 24,
+    "jack" => 40,
+    "jacket" => 37,
+    "jackets" => 24,
+    "jackpot" => 24,
+    "jackson" => 38,
+    "jacob" => 36,
+    "jacobian" => 24,
+    "jacquard" => 24,
+    "jacqueline" => 30,
+    "jacques" => 31,
+    "jade" => 29,
+    "jaded" => 29,
+    "jaeger" => 39,
+    "jagged" => 27,
+    "jail" => 34,
+    "jailed" => 32,
+    "jailing" => 24,
+    "jailor" => 24,
+    "jakarta" => 27,
+    "jake" => 31,
+    "jam" => 33,
+    "jamaica" => 24,
+    "james" => 48,
+    "jamie" => 27,
+    "jammed" => 24,
+    "jan" => 43,
+    "jane" => 32,
+    "janitor" => 24,
+    "january" => 40,
+    "japan" => 36,
+    "japanese" => 44,
+    "jar" => 30,
+    "jarring" => 24,
+    "jars" => 24,
+    "jason" => 41,
+    "jauntily" => 27,
+    "jauntiness" => 24,
+    "jaunty" => 27,
+    "java" => 47,
+    "javanese" => 24,
+    "jaw" => 29,
+    "jaws" => 33,
+    "jay" => 36,
+    "jazz" => 30,
+    "jcb" => 24,
+    "jealous" => 27,
+    "jealously" => 24,
+    "jealousy" => 31,
+    "jean" => 38,
+    "jeer" => 27,
+    "jeering" => 27,
+    "jeers" => 24,
+    "jeff" => 37,
+    "jefferson" => 30,
+    "jeffrey" => 27,
+    "jelly" => 29,
+    "jellyfish" => 24,
+    "jenkins" => 30,
+    "jennings" => 27,
+    "jeopardize" => 24,
+    "jeopardy" => 24,
+    "jeremiah" => 24,
+    "jeremy" => 32,
+    "jerk" => 30,
+    "jerked" => 27,
+    "jerkily" => 24,
+    "jerking" => 27,
+    "jerome" => 27,
+    "jerry" => 27,
+    "jersey" => 34,
+    "jessica" => 38,
+    "jest" => 29,
+    "jesting" => 24,
+    "jests" => 24,
+    "jet" => 31,
+    "jets" => 24,
+    "jetty" => 24,
+    "jew" => 27,
+    "jewel" => 36,
+    "jeweled" => 24,
+    "jewellers" => 24,
+    "jewellery" => 27,
+    "jewelry" => 27,
+    "jewels" => 34,
+    "jewess" => 24,
+    "jews" => 27,
+    "jib" => 33,
+    "jiff" => 41,
+    "jiffy" => 24,
+    "jim" => 38,
+    "jimmy" => 32,
+    "jingle" => 24,
+    "jingled" => 29,
+    "jingo" => 24,
+    "jingoes" => 27,
+    "jitter" => 32,
+    "jitters" => 24,
+    "jittery" => 24,
+    "joanne" => 32,
+    "job" => 52,
+    "jobs" => 43,
+    "joe" => 45,
+    "joel" => 32,
+    "jog" => 27,
+    "jogged" => 24,
+    "joggle" => 24,
+    "johannes" => 38,
+    "john" => 48,
+    "johnny" => 33,
+    "johns" => 24,
+    "johnson" => 40,
+    "johnston" => 24,
+    "join" => 48,
+    "joined" => 43,
+    "joiner" => 27,
+    "joiners" => 24,
+    "joinery" => 31,
+    "joining" => 41,
+    "joins" => 37,
+    "joint" => 38,
+    "jointed" => 24,
+    "jointly" => 33,
+    "joints" => 27,
+    "joke" => 36,
+    "joker" => 24,
+    "jokes" => 29,
+    "joking" => 32,
+    "jollification" => 24,
+    "jolly" => 36,
+    "jolted" => 27,
+    "jon" => 35,
+    "jonathan" => 35,
+    "jones" => 37,
+    "jonquils" => 24,
+    "jordan" => 42,
+    "jose" => 32,
+    "joseph" => 33,
+    "josh" => 31,
+    "joshua" => 31,
+    "jostled" => 27,
+    "jostling" => 24,
+    "jot" => 24,
+    "joule" => 24,
+    "journal" => 42,
+    "journalism" => 24,
+    "journals" => 38,
+    "journey" => 44,
+    "journeyed" => 24,
+    "journeying" => 30,
+    "journeys" => 27,
+    "jove" => 27,
+    "jovial" => 29,
+    "jovially" => 24,
+    "jowl" => 24,
+    "joy" => 42,
+    "joyful" => 30,
+    "joyfully" => 24,
+    "joyous" => 31,
+    "joyously" => 24,
+    "joys" => 33,
+    "joystick" => 24,
+    "jubilant" => 30,
+    "judge" => 42,
+    "judged" => 34,
+    "judgement" => 29,
+    "judges" => 33,
+    "judging" => 30,
+    "judgment" => 38,
+    "judgments" => 32,
+    "judicial" => 34,
+    "judicious" => 27,
+    "judith" => 34,
+    "judy" => 24,
+    "jug" => 30,
+    "jugged" => 24,
+    "juggernaut" => 29,
+    "juggle" => 27,
+    "juggled" => 24,
+    "juggler" => 27,
+    "jugglers" => 35,
+    "juggling" => 30,
+    "juice" => 27,
+    "jukebox" => 27,
+    "julep" => 29,
+    "julia" => 35,
+    "julian" => 33,
+    "julius" => 24,
+    "july" => 36,
+    "jumbo" => 24,
+    "jump" => 50,
+    "jumped" => 41,
+    "jumper" => 27,
+    "jumping" => 43,
+    "jumps" => 43,
+    "junction" => 35,
+    "junctions" => 29,
+    "juncture" => 24,
+    "june" => 38,
+    "jung" => 32,
+    "jungle" => 24,
+    "jungles" => 30,
+    "junior" => 32,
+    "juniper" => 27,
+    "junk" => 27,
+    "junks" => 27,
+    "jupiter" => 29,
+    "jura" => 31,
+    "jurisdiction" => 33,
+    "jurisdictions" => 27,
+    "juror" => 24,
+    "jurors" => 30,
+    "jury" => 40,
+    "juryman" => 24,
+    "jurymen" => 30,
+    "just" => 58,
+    "justice" => 39,
+    "justifiable" => 27,
+    "justifiably" => 24,
+    "justification" => 35,
+    "justifications" => 24,
+    "justified" => 35,
+    "justifies" => 30,
+    "justify" => 37,
+    "justifying" => 29,
+    "justin" => 34,
+    "justine" => 41,
+    "justly" => 24,
+    "jut" => 27,
+    "jutted" => 24,
+    "jutting" => 29,
+    "juvenile" => 29,
+    "juveniles" => 27,
+    "juxtaposition" => 24,
+    "kafka" => 36,
+    "kahn" => 35,
+    "kaiser" => 27,
+    "kansas" => 24,
+    "kappa" => 27,
+    "karen" => 27,
+    "karl" => 29,
+    "kate" => 30,
+    "kay" => 27,
+    "kayo" => 24,
+    "kbyte" => 32,
+    "kbytes" => 29,
+    "kebab" => 36,
+    "keel" => 31,
+    "keen" => 37,
+    "keener" => 24,
+    "keenest" => 30,
+    "keenly" => 35,
+    "keep" => 53,
+    "keeper" => 35,
+    "keeping" => 46,
+    "keeps" => 45,
+    "keg" => 24,
+    "keith" => 37,
+    "kelly" => 33,
+    "kelvin" => 31,
+    "kempt" => 24,
+    "ken" => 51,
+    "kennel" => 24,
+    "kenneth" => 29,
+    "kenney" => 37,
+    "kensington" => 24,
+    "kent" => 33,
+    "kept" => 48,
+    "kermit" => 27,
+    "kernel" => 48,
+    "kernels" => 44,
+    "kerning" => 32,
+    "kerr" => 24,
+    "ketch" => 27,
+    "kettle" => 27,
+    "kevin" => 34,
+    "key" => 58,
+    "keyboard" => 46,
+    "keyboards" => 36,
+    "keyed" => 38,
+    "keyhole" => 27,
+    "keying" => 39,
+    "keynote" => 27,
+    "keypad" => 39,
+    "keypress" => 27,
+    "keypresses" => 27,
+    "keys" => 54,
+    "keystroke" => 33,
+    "keystrokes" => 35,
+    "keyword" => 52,
+    "keywords" => 48,
+    "khaki" => 24,
+    "khmer" => 24,
+    "khz" => 27,
+    "kick" => 32,
+    "kicked" => 31,
+    "kicking" => 29,
+    "kicks" => 32,
+    "kid" => 33,
+    "kidding" => 24,
+    "kidnapped" => 24,
+    "kids" => 24,
+    "kikuyu" => 24,
+    "kill" => 43,
+    "killed" => 41,
+    "killer" => 33,
+    "killers" => 27,
+    "killing" => 33,
+    "kills" => 32,
+    "kilo" => 29,
+    "kilobaud" => 24,
+    "kilobits" => 24,
+    "kilobyte" => 29,
+    "kilobytes" => 35,
+    "kilometer" => 27,
+    "kim" => 33,
+    "kimball" => 27,
+    "kin" => 24,
+    "kind" => 52,
+    "kinder" => 24,
+    "kindergarten" => 24,
+    "kindest" => 24,
+    "kindle" => 24,
+    "kindled" => 27,
+    "kindliness" => 27,
+    "kindling" => 24,
+    "kindly" => 38,
+    "kindness" => 39,
+    "kinds" => 49,
+    "king" => 44,
+    "king's" => 30,
+    "kingdom" => 34,
+    "kingdoms" => 24,
+    "kings" => 33,
+    "kingston" => 24,
+    "kinsman" => 24,
+    "kinswoman" => 24,
+    "kirghiz" => 24,
+    "kiss" => 36,
+    "kissed" => 37,
+    "kisses" => 27,
+    "kissing" => 27,
+    "kit" => 37,
+    "kitchen" => 39,
+    "kitchens" => 24,
+    "kite" => 33,
+    "kites" => 27,
+    "kitten" => 27,
+    "kittens" => 32,
+    "kitty" => 38,
+    "kiwi" => 30,
+    "klan" => 27,
+    "klaus" => 33,
+    "knack" => 24,
+    "knackered" => 24,
+    "knave" => 34,
+    "knee" => 36,
+    "kneel" => 30,
+    "kneeling" => 27,
+    "knees" => 38,
+    "knell" => 24,
+    "knelt" => 35,
+    "knew" => 48,
+    "knickerbockers" => 24,
+    "knife" => 40,
+    "knight" => 27,
+    "knightly" => 24,
+    "knights" => 27,
+    "knit" => 24,
+    "knitted" => 29,
+    "knitting" => 27,
+    "knives" => 27,
+    "knob" => 32,
+    "knobs" => 27,
+    "knock" => 36,
+    "knocked" => 35,
+    "knocking" => 32,
+    "knot" => 33,
+    "knots" => 33,
+    "knotted" => 24,
+    "know" => 55,
+    "knowing" => 43,
+    "knowingly" => 24,
+    "knowledge" => 46,
+    "knowledgeable" => 24,
+    "known" => 53,
+    "knows" => 46,
+    "knox" => 27,
+    "knuckle" => 24,
+    "knuckles" => 27,
+    "knurling" => 30,
+    "kong" => 42,
+    "korea" => 24,
+    "korean" => 39,
+    "kosher" => 24,
+    "kraft" => 24,
+    "krishna" => 31,
+    "kurdish" => 24,
+    "kurt" => 29,
+    "kurtosis" => 27,
+    "kyle" => 27,
+    "kyoto" => 24,
+    "lab" => 37,
+    "label" => 47,
+    "labeled" => 42,
+    "labeling" => 27,
+    "labelled" => 34,
+    "labelling" => 24,
+    "labels" => 45,
+    "labor" => 35,
+    "laboratory" => 35,
+    "labored" => 27,
+    "laborious" => 31,
+    "laboriously" => 27,
+    "labors" => 24,
+    "labour" => 37,
+    "labourers" => 27,
+    "labours" => 36,
+    "labs" => 36,
+    "labyrinth" => 32,
+    "lac" => 24,
+    "lace" => 32,
+    "laced" => 24,
+    "lacerate" => 24,
+    "lacey" => 33,
+    "lacing" => 24,
+    "lack" => 44,
+    "lacked" => 32,
+    "lacking" => 37,
+    "lacks" => 41,
+    "lacy" => 24,
+    "lad" => 38,
+    "ladder" => 31,
+    "ladders" => 27,
+    "laden" => 31,
+    "ladies" => 39,
+    "lads" => 35,
+    "lady" => 46,
+    "lady's" => 37,
+    "lag" => 32,
+    "laggards" => 24,
+    "lagged" => 24,
+    "lagging" => 27,
+    "lags" => 37,
+    "laid" => 43,
+    "lain" => 27,
+    "lake" => 41,
+    "lakes" => 33,
+    "lakeside" => 24,
+    "lam" => 32,
+    "lamb" => 32,
+    "lambda" => 48,
+    "lambert" => 27,
+    "lame" => 27,
+    "lamed" => 31,
+    "lameness" => 24,
+    "lament" => 30,
+    "lamentations" => 24,
+    "lamented" => 24,
+    "lamp" => 39,
+    "lamps" => 30,
+    "lancaster" => 29,
+    "lances" => 24,
+    "land" => 43,
+    "landau" => 32,
+    "landed" => 37,
+    "landing" => 36,
+    "landlady" => 29,
+    "landlord" => 33,
+    "landmark" => 24,
+    "landmarks" => 24,
+    "landowner" => 24,
+    "lands" => 38,
+    "landscape" => 35,
+    "landscapes" => 24,
+    "landslide" => 24,
+    "landward" => 24,
+    "lane" => 49,
+    "lanes" => 47,
+    "lang" => 50,
+    "langley" => 24,
+    "language" => 56,
+    "languages" => 51,
+    "languid" => 32,
+    "languidly" => 27,
+    "languishing" => 24,
+    "languor" => 31,
+    "lank" => 24,
+    "lanka" => 24,
+    "lantern" => 37,
+    "lanterns" => 27,
+    "lao" => 24,
+    "lap" => 36,
+    "lapel" => 27,
+    "lapels" => 24,
+    "lapped" => 24,
+    "lappet" => 24,
+    "lappets" => 24,
+    "laps" => 24,
+    "lapse" => 30,
+    "lapsed" => 30,
+    "laptop" => 36,
+    "laptops" => 27,
+    "laramie" => 27,
+    "large" => 53,
+    "largely" => 42,
+    "largeness" => 24,
+    "larger" => 49,
+    "largest" => 44,
+    "largo" => 24,
+    "lark" => 24,
+    "larry" => 27,
+    "las" => 35,
+    "laser" => 27,
+    "lash" => 32,
+    "lashed" => 27,
+    "lashes" => 29,
+    "lashing" => 24,
+    "lashings" => 30,
+    "lass" => 24,
+    "lassitude" => 27,
+    "lasso" => 24,
+    "last" => 57,
+    "lasted" => 35,
+    "lasting" => 27,
+    "lastly" => 37,
+    "lasts" => 33,
+    "latch" => 27,
+    "latching" => 24,
+    "late" => 45,
+    "lately" => 36,
+    "latencies" => 29,
+    "latency" => 44,
+    "lateness" => 24,
+    "latent" => 24,
+    "later" => 53,
+    "lateral" => 27,
+    "laterally" => 24,
+    "latest" => 53,
+    "latex" => 39,
+    "lath" => 27,
+    "lather" => 27,
+    "latin" => 51,
+    "latitude" => 35,
+    "latitudes" => 30,
+    "latrine" => 27,
+    "latter" => 46,
+    "latterly" => 29,
+    "lattice" => 27,
+    "latvian" => 27,
+    "laudable" => 24,
+    "laudanum" => 27,
+    "lauded" => 24,
+    "laugh" => 38,
+    "laughed" => 41,
+    "laughing" => 39,
+    "laughs" => 24,
+    "laughter" => 39,
+    "launch" => 42,
+    "launched" => 39,
+    "launches" => 34,
+    "launching" => 36,
+    "laundered" => 24,
+    "laundry" => 27,
+    "laurel" => 27,
+    "lausanne" => 24,
+    "lav" => 24,
+    "lavender" => 32,
+    "lavish" => 29,
+    "lavished" => 24,
+    "lavishly" => 27,
+    "law" => 44,
+    "lawful" => 24,
+    "lawgivers" => 24,
+    "lawless" => 29,
+    "lawn" => 40,
+    "lawns" => 30,
+    "lawrence" => 33,
+    "laws" => 40,
+    "lawsuit" => 27,
+    "lawyer" => 37,
+    "lawyers" => 27,
+    "lax" => 24,
+    "laxness" => 24,
+    "lay" => 46,
+    "layer" => 46,
+    "layered" => 36,
+    "layering" => 33,
+    "layers" => 41,
+    "laying" => 37,
+    "layout" => 52,
+    "layouts" => 40,
+    "lays" => 32,
+    "lazier" => 24,
+    "lazily" => 43,
+    "laziness" => 27,
+    "lazy" => 46,
+    "lcd" => 40,
+    "lea" => 27,
+    "leach" => 24,
+    "lead" => 48,
+    "leaden" => 27,
+    "leader" => 42,
+    "leaders" => 30,
+    "leadership" => 27,
+    "leading" => 48,
+    "leads" => 42,
+    "leaf" => 40,
+    "leafs" => 32,
+    "league" => 37,
+    "leagues" => 29,
+    "leah" => 24,
+    "leak" => 48,
+    "leakage" => 32,
+    "leaked" => 39,
+    "leaking" => 44,
+    "leaks" => 44,
+    "leaky" => 34,
+    "lean" => 36,
+    "leaned" => 38,
+    "leaner" => 27,
+    "leaning" => 38,
+    "leans" => 27,
+    "leant" => 24,
+    "leap" => 40,
+    "leaped" => 31,
+    "leaping" => 24,
+    "leaps" => 24,
+    "lear" => 40,
+    "learn" => 49,
+    "learned" => 44,
+    "learner" => 24,
+    "learners" => 24,
+    "learning" => 44,
+    "learns" => 27,
+    "learnt" => 32,
+    "lease" => 24,
+    "leased" => 24,
+    "leash" => 29,
+    "leasing" => 24,
+    "least" => 53,
+    "leastways" => 27,
+    "leather" => 35,
+    "leatherhead" => 30,
+    "leathern" => 24,
+    "leave" => 49,
+    "leaved" => 27,
+    "leaves" => 46,
+    "leaving" => 47,
+    "lebanon" => 24,
+    "lech" => 42,
+    "lecture" => 36,
+    "lectures" => 31,
+    "lecturing" => 24,
+    "led" => 44,
+    "ledge" => 27,
+    "ledger" => 35,
+    "ledgers" => 24,
+    "lee" => 37,
+    "leeches" => 24,
+    "leer" => 24,
+    "leeway" => 24,
+    "left" => 55,
+    "lefthand" => 29,
+    "leftmost" => 35,
+    "leftover" => 32,
+    "leftovers" => 24,
+    "leftwards" => 35,
+    "leg" => 36,
+    "legacy" => 50,
+    "legal" => 43,
+    "legality" => 24,
+    "legalization" => 29,
+    "legally" => 31,
+    "legend" => 34,
+    "legendary" => 24,
+    "legends" => 32,
+    "legged" => 33,
+    "leggings" => 24,
+    "legibility" => 32,
+    "legible" => 33,
+    "legion" => 30,
+    "legislation" => 27,
+    "legislative" => 24,
+    "legislators" => 24,
+    "legislature" => 34,
+    "legislatures" => 31,
+    "legitimate" => 31,
+    "legitimately" => 27,
+    "legs" => 39,
+    "lei" => 24,
+    "leisure" => 33,
+    "leisurely" => 27,
+    "lemma" => 24,
+    "lemmatized" => 24,
+    "lemon" => 35,
+    "lemonade" => 33,
+    "lemons" => 29,
+    "len" => 44,
+    "lend" => 31,
+    "lender" => 27,
+    "lending" => 29,
+    "lends" => 27,
+    "length" => 55,
+    "lengthen" => 24,
+    "lengthened" => 27,
+    "lengthens" => 24,
+    "lengths" => 42,
+    "lengthy" => 34,
+    "leniency" => 27,
+    "lenient" => 37,
+    "lenin" => 24,
+    "lens" => 39,
+    "lenses" => 35,
+    "lent" => 29,
+    "lenticular" => 30,
+    "leo" => 27,
+    "leon" => 32,
+    "leonard" => 32,
+    "leonardo" => 31,
+    "leopard" => 29,
+    "leotard" => 24,
+    "leroy" => 24,
+    "less" => 54,
+    "lessen" => 29,
+    "lessened" => 29,
+    "lessening" => 29,
+    "lesser" => 36,
+    "lesson" => 34,
+    "lessons" => 38,
+    "lest" => 35,
+    "lester" => 30,
+    "let" => 54,
+    "lethargic" => 24,
+    "lethargy" => 27,
+    "lets" => 47,
+    "letter" => 54,
+    "letterpress" => 24,
+    "letters" => 48,
+    "letting" => 40,
+    "level" => 57,
+    "levelled" => 29,
+    "levels" => 46,
+    "lever" => 24,
+    "leverage" => 41,
+    "leveraged" => 32,
+    "leverages" => 39,
+    "leveraging" => 39,
+    "levers" => 29,
+    "levin" => 24,
+    "levity" => 24,
+    "levy" => 24,
+    "levying" => 24,
+    "lewis" => 33,
+    "lex" => 48,
+    "lexical" => 44,
+    "lexically" => 31,
+    "lexicographic" => 34,
+    "lexicographical" => 33,
+    "lexicographically" => 39,
+    "lexicon" => 27,
+    "lexington" => 24,
+    "liability" => 35,
+    "liable" => 38,
+    "liaisons" => 24,
+    "liar" => 30,
+    "lib" => 49,
+    "libel" => 31,
+    "liberal" => 33,
+    "liberally" => 30,
+    "liberated" => 29,
+    "liberties" => 24,
+    "libertine" => 24,
+    "liberty" => 36,
+    "libra" => 24,
+    "librarian" => 36,
+    "librarians" => 34,
+    "libraries" => 54,
+    "library" => 62,
+    "lice" => 24,
+    "licence" => 43,
+    "licences" => 32,
+    "licensable" => 24,
+    "license" => 64,
+    "licensed" => 59,
+    "licensee" => 31,
+    "licenses" => 45,
+    "licensing" => 43,
+    "lichen" => 29,
+    "lick" => 34,
+    "licked" => 31,
+    "licking" => 27,
+    "licks" => 27,
+    "lid" => 32,
+    "lidded" => 24,
+    "lidless" => 24,
+    "lids" => 32,
+    "lie" => 42,
+    "lied" => 29,
+    "lies" => 39,
+    "lieu" => 30,
+    "lieutenant" => 32,
+    "lieutenants" => 32,
+    "life" => 50,
+    "lifeless" => 34,
+    "lifelessness" => 24,
+    "lifelines" => 30,
+    "lifelong" => 24,
+    "lifespan" => 36,
+    "lifetime" => 54,
+    "lifetimes" => 50,
+    "lift" => 34,
+    "lifted" => 40,
+    "lifting" => 36,
+    "lifts" => 32,
+    "ligaments" => 24,
+    "ligature" => 34,
+    "ligatures" => 34,
+    "light" => 49,
+    "lighted" => 36,
+    "lighten" => 27,
+    "lightened" => 30,
+    "lightening" => 24,
+    "lighter" => 35,
+    "lightest" => 27,
+    "lighthearted" => 27,
+    "lighthouse" => 27,
+    "lighting" => 32,
+    "lightly" => 34,
+    "lightness" => 24,
+    "lightning" => 39,
+    "lights" => 39,
+    "lightweight" => 45,
+    "like" => 62,
+    "liked" => 37,
+    "likelihood" => 33,
+    "likely" => 51,
+    "likened" => 29,
+    "likes" => 34,
+    "likewise" => 41,
+    "liking" => 34,
+    "lilac" => 24,
+    "lilian" => 27,
+    "lilly" => 24,
+    "lilt" => 29,
+    "lily" => 24,
+    "lima" => 27,
+    "limb" => 35,
+    "limbo" => 29,
+    "limbs" => 39,
+    "limburger" => 24,
+    "lime" => 30,
+    "limerick" => 24,
+    "limestone" => 27,
+    "limit" => 49,
+    "limitation" => 42,
+    "limitations" => 48,
+    "limited" => 49,
+    "limiting" => 40,
+    "limitless" => 27,
+    "limits" => 46,
+    "limousine" => 29,
+    "limp" => 29,
+    "limped" => 24,
+    "limpid" => 27,
+    "limping" => 24,
+    "limps" => 24,
+    "lincoln" => 24,
+    "linda" => 29,
+    "line" => 62,
+    "lineage" => 27,
+    "lineaments" => 27,
+    "linear" => 46,
+    "linearity" => 24,
+    "linearly" => 34,
+    "lined" => 34,
+    "linefeed" => 35,
+    "linen" => 33,
+    "liner" => 30,
+    "liners" => 24,
+    "lines" => 57,
+    "ling" => 35,
+    "linger" => 31,
+    "lingered" => 32,
+    "lingering" => 33,
+    "lingeringly" => 24,
+    "lingers" => 24,
+    "lingo" => 24,
+    "lingua" => 30,
+    "lingual" => 24,
+    "linguist" => 27,
+    "linguistic" => 35,
+    "linguistics" => 32,
+    "linguists" => 27,
+    "lining" => 31,
+    "link" => 56,
+    "linkage" => 40,
+    "linkages" => 24,
+    "linked" => 49,
+    "linker" => 49,
+    "linkers" => 36,
+    "linking" => 49,
+    "links" => 49,
+    "linoleum" => 24,
+    "lint" => 53,
+    "linter" => 37,
+    "lion" => 32,
+    "lioness" => 31,
+    "lip" => 37,
+    "lipped" => 24,
+    "lips" => 42,
+    "liquid" => 40,
+    "liquidator" => 27,
+    "liquidity" => 32,
+    "liquids" => 24,
+    "liquor" => 35,
+    "liquors" => 27,
+    "lira" => 24,
+    "lis" => 30,
+    "lisp" => 41,
+    "lisped" => 24,
+    "lisps" => 24,
+    "list" => 60,
+    "listed" => 49,
+    "listen" => 44,
+    "listened" => 42,
+    "listener" => 48,
+    "listeners" => 45,
+    "listening" => 44,
+    "listens" => 35,
+    "listing" => 55,
+    "listings" => 35,
+    "listless" => 30,
+    "listlessly" => 29,
+    "lists" => 49,
+    "lit" => 41,
+    "lite" => 38,
+    "literacy" => 27,
+    "literal" => 54,
+    "literally" => 43,
+    "literals" => 49,
+    "literary" => 34,
+    "literate" => 32,
+    "literature" => 37,
+    "lithe" => 24,
+    "lithuanian" => 29,
+    "litmus" => 29,
+    "litter" => 29,
+    "litters" => 24,
+    "little" => 55,
+    "live" => 47,
+    "lived" => 44,
+    "livelihood" => 24,
+    "liveliness" => 24,
+    "lively" => 37,
+    "liver" => 27,
+    "liverpool" => 38,
+    "livery" => 29,
+    "lives" => 43,
+    "livid" => 30,
+    "living" => 42,
+    "liz" => 24,
+    "lizard" => 32,
+    "llama" => 24,
+    "lld" => 38,
+    "lloyd" => 24,
+    "lloyd's" => 24,
+    "load" => 53,
+    "loaded" => 50,
+    "loader" => 43,
+    "loaders" => 37,
+    "loading" => 51,
+    "loads" => 46,
+    "loaf" => 30,
+    "loafer" => 29,
+    "loafing" => 29,
+    "loafs" => 24,
+    "loan" => 30,
+    "loaned" => 24,
+    "loans" => 24,
+    "loathed" => 31,
+    "loathing" => 32,
+    "loathsome" => 32,
+    "loaves" => 27,
+    "lob" => 24,
+    "lobby" => 31,
+    "lobster" => 33,
+    "lobsters" => 32,
+    "local" => 56,
+    "locale" => 48,
+    "locales" => 39,
+    "localisation" => 24,
+    "localities" => 27,
+    "locality" => 38,
+    "localization" => 39,
+    "localize" => 32,
+    "localized" => 37,
+    "localizing" => 27,
+    "locally" => 47,
+    "locals" => 36,
+    "locate" => 42,
+    "located" => 46,
+    "locates" => 30,
+    "locating" => 36,
+    "location" => 53,
+    "locations" => 45,
+    "locator" => 27,
+    "locators" => 24,
+    "loch" => 24,
+    "lock" => 52,
+    "lockable" => 24,
+    "locked" => 45,
+    "locker" => 29,
+    "locket" => 27,
+    "locking" => 44,
+    "locks" => 44,
+    "lockstep" => 32,
+    "lockup" => 24,
+    "loco" => 27,
+    "locomotion" => 24,
+    "locomotive" => 38,
+    "locomotives" => 27,
+    "locus" => 27,
+    "locust" => 29,
+    "locusts" => 24,
+    "lodge" => 38,
+    "lodged" => 31,
+    "lodger" => 27,
+    "lodging" => 27,
+    "lodgings" => 33,
+    "loft" => 24,
+    "loftily" => 24,
+    "lofty" => 33,
+    "log" => 51,
+    "logan" => 24,
+    "logarithm" => 40,
+    "logarithmic" => 32,
+    "logarithms" => 24,
+    "logged" => 37,
+    "logger" => 42,
+    "loggers" => 33,
+    "logging" => 47,
+    "logic" => 50,
+    "logical" => 47,
+    "logically" => 40,
+    "logician" => 24,
+    "login" => 41,
+    "logistic" => 24,
+    "logistics" => 27,
+    "logo" => 43,
+    "logos" => 38,
+    "logout" => 35,
+    "logs" => 44,
+    "loins" => 24,
+    "loitered" => 30,
+    "loitering" => 27,
+    "london" => 45,
+    "londoner" => 24,
+    "londoners" => 24,
+    "lone" => 34,
+    "lonelier" => 24,
+    "loneliest" => 24,
+    "loneliness" => 32,
+    "lonely" => 37,
+    "lonesome" => 30,
+    "long" => 56,
+    "longed" => 37,
+    "longer" => 53,
+    "longest" => 41,
+    "longevity" => 30,
+    "longhand" => 27,
+    "longing" => 32,
+    "longingly" => 24,
+    "longings" => 27,
+    "longitude" => 34,
+    "longitudes" => 30,
+    "longstanding" => 24,
+    "look" => 55,
+    "looked" => 49,
+    "lookers" => 24,
+    "looking" => 51,
+    "lookout" => 31,
+    "looks" => 51,
+    "lookup" => 48,
+    "loom" => 37,
+    "loomed" => 31,
+    "looming" => 27,
+    "loop" => 54,
+    "looped" => 31,
+    "loophole" => 24,
+    "looping" => 39,
+    "loops" => 46,
+    "loose" => 40,
+    "loosed" => 24,
+    "loosely" => 35,
+    "loosen" => 31,
+    "loosened" => 29,
+    "loosening" => 27,
+    "looser" => 31,
+    "loot" => 24,
+    "lop" => 29,
+    "lope" => 24,
+    "lopes" => 24,
+    "lopped" => 24,
+    "loquacious" => 27,
+    "lord" => 43,
+    "lord's" => 31,
+    "lords" => 27,
+    "lordship" => 24,
+    "los" => 38,
+    "lose" => 45,
+    "loser" => 27,
+    "loses" => 34,
+    "losing" => 41,
+    "loss" => 44,
+    "losses" => 30,
+    "lost" => 48,
+    "lot" => 51,
+    "lots" => 44,
+    "lotus" => 27,
+    "lou" => 27,
+    "loud" => 39,
+    "louder" => 34,
+    "loudly" => 35,
+    "loudness" => 24,
+    "louis" => 32,
+    "louisa" => 24,
+    "louisiana" => 27,
+    "louisville" => 32,
+    "lounged" => 30,
+    "loungers" => 27,
+    "lounging" => 32,
+    "loupe" => 27,
+    "lousy" => 24,
+    "love" => 46,
+    "loved" => 42,
+    "lovelace" => 27,
+    "loveliest" => 27,
+    "loveliness" => 31,
+    "lovely" => 41,
+    "lover" => 36,
+    "lovers" => 30,
+    "loves" => 33,
+    "loving" => 34,
+    "lovingly" => 24,
+    "low" => 53,
+    "lowed" => 27,
+    "lower" => 56,
+    "lowercase" => 46,
+    "lowered" => 36,
+    "lowering" => 36,
+    "lowers" => 30,
+    "lowest" => 45,
+    "lowing" => 24,
+    "lowliest" => 24,
+    "lozenge" => 24,
+    "lse" => 32,
+    "ltd" => 29,
+    "luau" => 34,
+    "lucas" => 30,
+    "lucerne" => 27,
+    "lucid" => 27,
+    "lucifer" => 24,
+    "lucille" => 32,
+    "luck" => 38,
+    "luckily" => 38,
+    "luckless" => 27,
+    "lucky" => 37,
+    "lucrative" => 24,
+    "lucy" => 31,
+    "ludicrous" => 24,
+    "luff" => 24,
+    "lug" => 24,
+    "luggage" => 32,
+    "lugged" => 24,
+    "lugubrious" => 29,
+    "luis" => 32,
+    "luke" => 24,
+    "lukewarm" => 24,
+    "lull" => 24,
+    "lullaby" => 27,
+    "lulled" => 29,
+    "lulling" => 24,
+    "lumber" => 29,
+    "luminance" => 27,
+    "luminosity" => 27,
+    "luminous" => 24,
+    "lump" => 30,
+    "lumps" => 27,
+    "lunar" => 24,
+    "lunate" => 24,
+    "lunatic" => 27,
+    "lunch" => 38,
+    "lunched" => 24,
+    "luncheon" => 32,
+    "lunching" => 24,
+    "lundy" => 24,
+    "lunettes" => 24,
+    "lurch" => 27,
+    "lurched" => 27,
+    "lurches" => 27,
+    "lure" => 24,
+    "lured" => 24,
+    "lurid" => 27,
+    "lurked" => 27,
+    "lurking" => 33,
+    "lust" => 30,
+    "lustily" => 27,
+    "lustre" => 27,
+    "lustreless" => 24,
+    "lustrous" => 29,
+    "lutheran" => 27,
+    "luxuriant" => 30,
+    "luxuriated" => 24,
+    "luxuries" => 27,
+    "luxurious" => 30,
+    "luxury" => 32,
+    "lychee" => 24,
+    "lying" => 40,
+    "lynch" => 40,
+    "lynn" => 29,
+    "lynne" => 32,
+    "lynx" => 34,
+    "lyon" => 36,
+    "lyons" => 27,
+    "lyre" => 24,
+    "lyric" => 24,
+    "lyrics" => 29,
+    "ma'am" => 24,
+    "mabel" => 30,
+    "mac" => 51,
+    "macadamised" => 24,
+    "macao" => 29,
+    "macdonald" => 24,
+    "macedonian" => 24,
+    "macerator" => 24,
+    "mach" => 38,
+    "machete" => 24,
+    "machinations" => 29,
+    "machine" => 51,
+    "machinery" => 36,
+    "machines" => 44,
+    "macho" => 27,
+    "macintosh" => 41,
+    "mackintosh" => 27,
+    "macmillan" => 24,
+    "macro" => 58,
+    "macron" => 37,
+    "macros" => 55,
+    "macs" => 34,
+    "mad" => 41,
+    "madam" => 39,
+    "madame" => 34,
+    "maddened" => 24,
+    "maddening" => 30,
+    "made" => 56,
+    "madison" => 33,
+    "madly" => 27,
+    "madman" => 33,
+    "madness" => 35,
+    "madras" => 29,
+    "maestro" => 24,
+    "magazine" => 30,
+    "magazines" => 31,
+    "magenta" => 35,
+    "maggie" => 27,
+    "magic" => 45,
+    "magical" => 32,
+    "magically" => 31,
+    "magician" => 24,
+    "magistrate" => 36,
+    "magistrates" => 27,
+    "magna" => 27,
+    "magnanimous" => 27,
+    "magnesium" => 24,
+    "magnet" => 24,
+    "magnetic" => 33,
+    "magnetized" => 27,
+    "magnetometer" => 27,
+    "magnificence" => 27,
+    "magnificent" => 36,
+    "magnificently" => 24,
+    "magnified" => 27,
+    "magnifying" => 32,
+    "magnitude" => 40,
+    "magnitudes" => 27,
+    "magpie" => 24,
+    "mahogany" => 27,
+    "maid" => 38,
+    "maiden" => 31,
+    "maids" => 29,
+    "mail" => 47,
+    "mailbox" => 29,
+    "mailed" => 30,
+    "mailer" => 27,
+    "mailing" => 37,
+    "mailman" => 24,
+    "mails" => 33,
+    "main" => 56,
+    "maine" => 24,
+    "mainframe" => 31,
+    "mainland" => 27,
+    "mainline" => 35,
+    "mainly" => 44,
+    "mains" => 24,
+    "mainstream" => 32,
+    "maintain" => 45,
+    "maintainability" => 36,
+    "maintainable" => 35,
+    "maintained" => 48,
+    "maintainer" => 45,
+    "maintainers" => 48,
+    "maintaining" => 43,
+    "maintains" => 42,
+    "maintenance" => 45,
+    "mainz" => 27,
+    "majestic" => 35,
+    "majesty" => 39,
+    "major" => 50,
+    "majority" => 42,
+    "majors" => 27,
+    "make" => 61,
+    "maker" => 31,
+    "makers" => 27,
+    "makes" => 55,
+    "makeup" => 24,
+    "making" => 53,
+    "makings" => 24,
+    "malachite" => 35,
+    "malagasy" => 24,
+    "malay" => 27,
+    "malays" => 24,
+    "malaysia" => 24,
+    "malcolm" => 27,
+    "male" => 33,
+    "maledictions" => 27,
+    "males" => 24,
+    "malevolence" => 24,
+    "malformed" => 40,
+    "malfunctions" => 27,
+    "malice" => 33,
+    "malicious" => 40,
+    "malignant" => 29,
+    "malignity" => 32,
+    "mall" => 30,
+    "malleability" => 35,
+    "malleable" => 34,
+    "mallets" => 24,
+    "malta" => 24,
+    "maltese" => 27,
+    "mamma" => 27,
+    "man" => 53,
+    "manacled" => 24,
+    "manage" => 47,
+    "manageable" => 33,
+    "managed" => 46,
+    "management" => 48,
+    "manager" => 47,
+    "manageress" => 24,
+    "managers" => 40,
+    "manages" => 41,
+    "managing" => 44,
+    "manchester" => 27,
+    "mandarin" => 27,
+    "mandate" => 29,
+    "mandated" => 29,
+    "mandates" => 31,
+    "mandating" => 29,
+    "mandatory" => 42,
+    "manes" => 27,
+    "manfred" => 27,
+    "manfully" => 24,
+    "mangle" => 36,
+    "mangled" => 39,
+    "mangler" => 24,
+    "mangles" => 30,
+    "mangling" => 39,
+    "mangoes" => 27,
+    "mania" => 24,
+    "manifest" => 50,
+    "manifestation" => 29,
+    "manifestations" => 27,
+    "manifested" => 32,
+    "manifesting" => 27,
+    "manifestly" => 24,
+    "manifesto" => 24,
+    "manifests" => 38,
+    "manifold" => 27,
+    "manipulate" => 43,
+    "manipulated" => 35,
+    "manipulates" => 30,
+    "manipulating" => 41,
+    "manipulation" => 44,
+    "manipulations" => 34,
+    "mankind" => 35,
+    "manly" => 29,
+    "mann" => 27,
+    "manned" => 24,
+    "manner" => 47,
+    "mannered" => 24,
+    "manners" => 39,
+    "mannheim" => 24,
+    "manning" => 24,
+    "manoeuvre" => 24,
+    "manoeuvres" => 24,
+    "manor" => 29,
+    "manpower" => 24,
+    "mansfield" => 24,
+    "mansion" => 35,
+    "mansions" => 27,
+    "mantel" => 27,
+    "mantelpiece" => 33,
+    "mantissa" => 40,
+    "mantissas" => 39,
+    "mantle" => 24,
+    "manu" => 24,
+    "manual" => 49,
+    "manually" => 51,
+    "manuals" => 37,
+    "manufactory" => 24,
+    "manufacture" => 29,
+    "manufactured" => 30,
+    "manufacturer" => 34,
+    "manufacturers" => 27,
+    "manufacturing" => 24,
+    "manuscript" => 37,
+    "manuscripts" => 33,
+    "manx" => 34,
+    "many" => 57,
+    "mao" => 27,
+    "maori" => 24,
+    "map" => 54,
+    "maple" => 30,
+    "mapped" => 48,
+    "mapping" => 53,
+    "mappings" => 51,
+    "maps" => 49,
+    "mar" => 41,
+    "marble" => 35,
+    "marbles" => 32,
+    "marcel" => 29,
+    "march" => 43,
+    "marched" => 31,
+    "marching" => 30,
+    "marco" => 36,
+    "marcus" => 29,
+    "margaret" => 34,
+    "margin" => 38,
+    "marginal" => 32,
+    "marginally" => 27,
+    "margins" => 31,
+    "maria" => 32,
+    "marian" => 30,
+    "marie" => 24,
+    "mariner" => 31,
+    "mariners" => 24,
+    "marines" => 24,
+    "marino" => 24,
+    "maritime" => 24,
+    "mark" => 52,
+    "markdown" => 45,
+    "marked" => 50,
+    "marker" => 46,
+    "markers" => 43,
+    "market" => 40,
+    "marketing" => 35,
+    "marketplace" => 31,
+    "markets" => 32,
+    "marking" => 42,
+    "marks" => 49,
+    "markup" => 42,
+    "marlin" => 24,
+    "marmalade" => 24,
+    "maroon" => 24,
+    "marque" => 27,
+    "marred" => 30,
+    "marriage" => 41,
+    "married" => 42,
+    "marring" => 24,
+    "marriott" => 44,
+    "marry" => 36,
+    "marrying" => 33,
+    "mars" => 32,
+    "marseilles" => 27,
+    "marsh" => 24,
+    "marshal" => 27,
+    "marshaling" => 29,
+    "marshall" => 27,
+    "marshes" => 27,
+    "marshmallow" => 30,
+    "marshy" => 24,
+    "martin" => 40,
+    "martyr" => 29,
+    "martyrdom" => 24,
+    "martyrs" => 24,
+    "marvel" => 30,
+    "marvelled" => 27,
+    "marvelling" => 24,
+    "marvellous" => 33,
+    "marvellously" => 24,
+    "marvelous" => 24,
+    "marvels" => 31,
+    "marvin" => 30,
+    "mary" => 42,
+    "maryland" => 33,
+    "marylebone" => 24,
+    "mascot" => 32,
+    "masculine" => 29,
+    "mashed" => 24,
+    "mashing" => 24,
+    "mask" => 57,
+    "masked" => 37,
+    "masking" => 35,
+    "masks" => 42,
+    "mason" => 27,
+    "masonry" => 24,
+    "masquerades" => 24,
+    "mass" => 38,
+    "massachusetts" => 27,
+    "massacre" => 24,
+    "massacred" => 24,
+    "massacring" => 24,
+    "massage" => 24,
+    "massaged" => 24,
+    "massaging" => 24,
+    "masse" => 27,
+    "massed" => 29,
+    "masses" => 29,
+    "massing" => 24,
+    "massive" => 37,
+    "massively" => 32,
+    "mast" => 32,
+    "master" => 49,
+    "mastered" => 29,
+    "mastering" => 27,
+    "masterly" => 24,
+    "masterpieces" => 24,
+    "masters" => 32,
+    "mastery" => 24,
+    "mastiff" => 27,
+    "mastodon" => 27,
+    "masts" => 32,
+    "mat" => 36,
+    "match" => 57,
+    "matched" => 48,
+    "matches" => 54,
+    "matching" => 53,
+    "matchless" => 24,
+    "mate" => 33,
+    "material" => 47,
+    "materialised" => 31,
+    "materialization" => 24,
+    "materialize" => 36,
+    "materialized" => 27,
+    "materializes" => 27,
+    "materializing" => 27,
+    "materially" => 31,
+    "materials" => 45,
+    "maternal" => 24,
+    "maternity" => 24,
+    "mates" => 29,
+    "math" => 45,
+    "mathematic" => 27,
+    "mathematical" => 42,
+    "mathematically" => 37,
+    "mathematician" => 24,
+    "mathematicians" => 24,
+    "mathematics" => 39,
+    "mathias" => 34,
+    "maths" => 30,
+    "matlock" => 29,
+    "matrices" => 39,
+    "matrix" => 48,
+    "matrixes" => 24,
+    "matron" => 29,
+    "matrons" => 24,
+    "mats" => 24,
+    "matt" => 38,
+    "matte" => 24,
+    "matter" => 50,
+    "mattered" => 32,
+    "matters" => 44,
+    "matthew" => 37,
+    "mattress" => 29,
+    "mature" => 36,
+    "matured" => 27,
+    "matures" => 24,
+    "maturity" => 24,
+    "maurice" => 30,
+    "mauritius" => 24,
+    "maven" => 33,
+    "mavericks" => 24,
+    "maw" => 24,
+    "max" => 48,
+    "maxi" => 24,
+    "maxim" => 35,
+    "maxima" => 31,
+    "maximal" => 37,
+    "maximally" => 32,
+    "maximilian" => 24,
+    "maximise" => 24,
+    "maximised" => 24,
+    "maximises" => 24,
+    "maximising" => 24,
+    "maximize" => 36,
+    "maximized" => 37,
+    "maximizes" => 30,
+    "maximizing" => 32,
+    "maximum" => 52,
+    "maximums" => 24,
+    "may" => 63,
+    "maybe" => 46,
+    "mayn't" => 27,
+    "mayor" => 31,
+    "mays" => 24,
+    "maze" => 32,
+    "mazes" => 24,
+    "mbe" => 24,
+    "mcallister" => 29,
+    "mccarthy" => 40,
+    "mccarty" => 24,
+    "mccauley" => 27,
+    "mccoy" => 45,
+    "mcdermott" => 24,
+    "mcdonald" => 24,
+    "mcdougall" => 27,
+    "mcgowan" => 24,
+    "mckee" => 36,
+    "mckinney" => 24,
+    "mcpherson" => 24,
+    "me" => 58,
+    "mead" => 24,
+    "meadow" => 29,
+    "meadows" => 30,
+    "meager" => 24,
+    "meagre" => 24,
+    "meal" => 36,
+    "meals" => 32,
+    "mean" => 48,
+    "meandering" => 24,
+    "meanest" => 27,
+    "meaning" => 50,
+    "meaningful" => 42,
+    "meaningfully" => 29,
+    "meaningless" => 38,
+    "meanings" => 36,
+    "meanly" => 27,
+    "means" => 56,
+    "meant" => 47,
+    "meantime" => 41,
+    "meanwhile" => 39,
+    "measles" => 29,
+    "measurable" => 32,
+    "measurably" => 27,
+    "measure" => 42,
+    "measured" => 40,
+    "measureless" => 27,
+    "measurement" => 39,
+    "measurements" => 39,
+    "measures" => 41,
+    "measuring" => 39,
+    "meat" => 32,
+    "mechanical" => 37,
+    "mechanically" => 32,
+    "mechanics" => 36,
+    "mechanism" => 49,
+    "mechanisms" => 43,
+    "medal" => 24,
+    "medals" => 24,
+    "meddle" => 27,
+    "meddler" => 24,
+    "media" => 43,
+    "medial" => 27,
+    "median" => 33,
+    "medians" => 30,
+    "mediated" => 29,
+    "mediation" => 24,
+    "medical" => 38,
+    "medicine" => 36,
+    "medicines" => 29,
+    "medieval" => 27,
+    "mediocre" => 24,
+    "meditate" => 27,
+    "meditating" => 27,
+    "meditation" => 29,
+    "meditative" => 24,
+    "meditatively" => 24,
+    "mediterranean" => 29,
+    "medium" => 43,
+    "mediums" => 24,
+    "meed" => 27,
+    "meekly" => 27,
+    "meet" => 45,
+    "meeting" => 40,
+    "meetings" => 33,
+    "meets" => 40,
+    "mega" => 27,
+    "megabits" => 24,
+    "megabyte" => 35,
+    "megabytes" => 33,
+    "megahertz" => 27,
+    "mel" => 27,
+    "melancholy" => 40,
+    "melbourne" => 27,
+    "meld" => 27,
+    "melissa" => 24,
+    "mellow" => 29,
+    "mellows" => 24,
+    "melodic" => 24,
+    "melodious" => 29,
+    "melody" => 29,
+    "melon" => 27,
+    "melt" => 29,
+    "melted" => 29,
+    "melting" => 24,
+    "member" => 47,
+    "members" => 48,
+    "membership" => 39,
+    "memberships" => 24,
+    "membra" => 24,
+    "memo" => 32,
+    "memoir" => 24,
+    "memorable" => 29,
+    "memoranda" => 27,
+    "memorandum" => 27,
+    "memoria" => 27,
+    "memorial" => 24,
+    "memories" => 36,
+    "memorize" => 24,
+    "memorized" => 27,
+    "memorizes" => 27,
+    "memorizing" => 24,
+    "memory" => 60,
+    "memos" => 29,
+    "memphis" => 24,
+    "men" => 47,
+    "menaced" => 30,
+    "menaces" => 27,
+    "menacing" => 24,
+    "menagerie" => 27,
+    "mend" => 29,
+    "mended" => 24,
+    "mendicant" => 24,
+    "mendicants" => 24,
+    "mending" => 27,
+    "menial" => 27,
+    "ment" => 30,
+    "mental" => 35,
+    "mentally" => 33,
+    "mention" => 44,
+    "mentioned" => 48,
+    "mentioning" => 38,
+    "mentions" => 37,
+    "menu" => 54,
+    "menus" => 47,
+    "meow" => 30,
+    "meowing" => 24,
+    "meows" => 29,
+    "mer" => 27,
+    "mercenary" => 24,
+    "merchandise" => 24,
+    "merchant" => 34,
+    "merchantman" => 24,
+    "merchants" => 33,
+    "mercia" => 27,
+    "mercian" => 27,
+    "merciful" => 27,
+    "mercifully" => 24,
+    "merciless" => 27,
+    "mercurial" => 34,
+    "mercury" => 27,
+    "mercy" => 33,
+    "mere" => 38,
+    "meredith" => 24,
+    "merely" => 42,
+    "merest" => 29,
+    "meretricious" => 24,
+    "merge" => 49,
+    "merged" => 46,
+    "merger" => 27,
+    "merges" => 40,
+    "merging" => 44,
+    "meridian" => 32,
+    "meridians" => 27,
+    "merit" => 32,
+    "merits" => 30,
+    "merlin" => 38,
+    "mermaid" => 36,
+    "merrily" => 24,
+    "merriment" => 30,
+    "merry" => 33,
+    "merton" => 24,
+    "mesa" => 29,
+    "mesh" => 36,
+    "meshes" => 33,
+    "meson" => 27,
+    "mess" => 40,
+    "message" => 58,
+    "messaged" => 27,
+    "messages" => 55,
+    "messaging" => 34,
+    "messed" => 40,
+    "messenger" => 31,
+    "messengers" => 24,
+    "messes" => 37,
+    "messing" => 35,
+    "messrs" => 24,
+    "messy" => 35,
+    "met" => 46,
+    "metadata" => 51,
+    "metal" => 46,
+    "metallic" => 34,
+    "metals" => 24,
+    "metamorphosis" => 24,
+    "metaphor" => 30,
+    "metaphorical" => 24,
+    "metaphorically" => 24,
+    "metaphysical" => 24,
+    "metcalf" => 24,
+    "meteorologist" => 24,
+    "meteorology" => 27,
+    "meter" => 32,
+    "metered" => 27,
+    "metering" => 34,
+    "meters" => 30,
+    "meth" => 24,
+    "methinks" => 24,
+    "method" => 60,
+    "methodical" => 29,
+    "methodically" => 27,
+    "methodological" => 24,
+    "methodologies" => 29,
+    "methodology" => 33,
+    "methods" => 57,
+    "meticulous" => 24,
+    "meticulously" => 24,
+    "metric" => 40,
+    "metrics" => 47,
+    "metropolis" => 29,
+    "metropolitan" => 30,
+    "mev" => 27,
+    "mew" => 29,
+    "mewing" => 24,
+    "mews" => 29,
+    "mexico" => 30,
+    "mhz" => 33,
+    "mica" => 27,
+    "micah" => 30,
+    "mice" => 33,
+    "michael" => 44,
+    "michele" => 24,
+    "michigan" => 27,
+    "mick" => 24,
+    "micro" => 40,
+    "microchip" => 24,
+    "microcomputer" => 32,
+    "microfiche" => 24,
+    "microfilm" => 42,
+    "microfilmed" => 27,
+    "microfilming" => 33,
+    "microphone" => 33,
+    "microprocessor" => 27,
+    "microprocessors" => 24,
+    "microscope" => 24,
+    "microsecond" => 34,
+    "microseconds" => 37,
+    "microsoft" => 44,
+    "microwave" => 27,
+    "mid" => 39,
+    "midas" => 24,
+    "midday" => 27,
+    "middle" => 48,
+    "middleman" => 24,
+    "middlesex" => 24,
+    "middleware" => 44,
+    "middling" => 27,
+    "midi" => 38,
+    "midlands" => 27,
+    "midnight" => 40,
+    "midpoint" => 31,
+    "midst" => 39,
+    "midsummer" => 27,
+    "midway" => 29,
+    "mien" => 29,
+    "miffed" => 24,
+    "might" => 57,
+    "mightier" => 24,
+    "mightily" => 27,
+    "mightn't" => 27,
+    "mighty" => 40,
+    "migrate" => 44,
+    "migrated" => 36,
+    "migrating" => 41,
+    "migration" => 46,
+    "migrations" => 42,
+    "miguel" => 24,
+    "mikado" => 24,
+    "mike" => 46,
+    "milan" => 31,
+    "milanese" => 24,
+    "mild" => 33,
+    "mildly" => 29,
+    "mildness" => 29,
+    "mile" => 40,
+    "mileage" => 32,
+    "miles" => 44,
+    "milestone" => 31,
+    "milestones" => 33,
+    "military" => 34,
+    "militia" => 32,
+    "milk" => 36,
+    "milksop" => 24,
+    "milky" => 24,
+    "mill" => 27,
+    "millennium" => 31,
+    "miller" => 35,
+    "miller's" => 27,
+    "milli" => 24,
+    "millimeters" => 24,
+    "million" => 40,
+    "millionaire" => 29,
+    "millionaires" => 24,
+    "millions" => 37,
+    "millionth" => 30,
+    "millisecond" => 41,
+    "milliseconds" => 46,
+    "mills" => 27,
+    "milo" => 27,
+    "milton" => 24,
+    "milwaukee" => 24,
+    "mime" => 44,
+    "mimic" => 36,
+    "mimicking" => 27,
+    "mimics" => 33,
+    "mimosa" => 24,
+    "min" => 46,
+    "minarets" => 30,
+    "mincing" => 24,
+    "mind" => 50,
+    "minded" => 33,
+    "mindedness" => 29,
+    "mindful" => 32,
+    "minding" => 24,
+    "mindlessly" => 24,
+    "minds" => 35,
+    "mine" => 44,
+    "minefield" => 24,
+    "miner" => 34,
+    "mineral" => 29,
+    "miners" => 27,
+    "mines" => 30,
+    "minesweeper" => 24,
+    "ming" => 47,
+    "mingle" => 27,
+    "mingled" => 35,
+    "mingling" => 32,
+    "mini" => 42,
+    "miniature" => 29,
+    "miniaturized" => 27,
+    "minify" => 36,
+    "minifying" => 29,
+    "minim" => 27,
+    "minima" => 30,
+    "minimal" => 50,
+    "minimalism" => 24,
+    "minimalist" => 29,
+    "minimally" => 33,
+    "minimise" => 30,
+    "minimised" => 24,
+    "minimising" => 24,
+    "minimization" => 24,
+    "minimize" => 42,
+    "minimized" => 37,
+    "minimizes" => 33,
+    "minimizing" => 35,
+    "minimum" => 56,
+    "minimums" => 24,
+    "mining" => 35,
+    "minister" => 36,
+    "ministered" => 24,
+    "ministering" => 24,
+    "ministers" => 32,
+    "minnesota" => 27,
+    "minor" => 51,
+    "minority" => 29,
+    "minsk" => 24,
+    "minstrel" => 24,
+    "mint" => 42,
+    "minted" => 29,
+    "minter" => 31,
+    "minting" => 24,
+    "mints" => 30,
+    "minus" => 41,
+    "minute" => 45,
+    "minutely" => 32,
+    "minuteness" => 24,
+    "minutes" => 47,
+    "minutiae" => 27,
+    "miracle" => 27,
+    "miracles" => 24,
+    "miraculous" => 29,
+    "miraculously" => 24,
+    "mirror" => 39,
+    "mirrored" => 35,
+    "mirroring" => 36,
+    "mirrors" => 37,
+    "mirth" => 29,
+    "misadventures" => 24,
+    "misalignment" => 24,
+    "misapplication" => 27,
+    "misbehave" => 24,
+    "misbehaves" => 27,
+    "misbehavior" => 27,
+    "miscarried" => 24,
+    "miscellaneous" => 39,
+    "miscellany" => 24,
+    "mischance" => 24,
+    "mischief" => 35,
+    "mischievous" => 27,
+    "misconception" => 27,
+    "misconceptions" => 24,
+    "misconduct" => 24,
+    "miscreant" => 24,
+    "misdeed" => 24,
+    "misdemeanors" => 24,
+    "misdemeanour" => 24,
+    "miserable" => 43,
+    "miserably" => 33,
+    "miseries" => 35,
+    "misery" => 42,
+    "misfortune" => 39,
+    "misfortunes" => 36,
+    "misgive" => 24,
+    "misgiving" => 24,
+    "misgivings" => 32,
+    "misguided" => 24,
+    "mishandling" => 24,
+    "mishap" => 24,
+    "misinterpret" => 24,
+    "misinterpreted" => 31,
+    "misjudged" => 24,
+    "misleading" => 37,
+    "misleadingly" => 24,
+    "misled" => 24,
+    "mismatch" => 39,
+    "mismatched" => 32,
+    "mismatches" => 37,
+    "mismatching" => 32,
+    "misnamed" => 27,
+    "misnomer" => 24,
+    "misplaced" => 37,
+    "misplacing" => 24,
+    "misread" => 24,
+    "misrepresent" => 27,
+    "misrepresented" => 32,
+    "miss" => 47,
+    "missed" => 40,
+    "misses" => 35,
+    "missile" => 27,
+    "missing" => 53,
+    "mission" => 33,
+    "missionary" => 27,
+    "missis" => 29,
+    "mississippi" => 30,
+    "missouri" => 33,
+    "misspelled" => 36,
+    "misspelling" => 29,
+    "misspellings" => 30,
+    "mist" => 36,
+    "mistake" => 44,
+    "mistaken" => 38,
+    "mistakenly" => 34,
+    "mistakes" => 44,
+    "mistaking" => 24,
+    "mister" => 30,
+    "mistral" => 27,
+    "mistreats" => 24,
+    "mistress" => 36,
+    "mistrust" => 27,
+    "mists" => 29,
+    "misty" => 29,
+    "mistype" => 29,
+    "mistyped" => 27,
+    "mistyping" => 27,
+    "misunderstanding" => 24,
+    "misunderstandings" => 27,
+    "misunderstood" => 29,
+    "misuse" => 38,
+    "misused" => 32,
+    "misuses" => 27,
+    "misusing" => 29,
+    "mit" => 60,
+    "miter" => 24,
+    "mitigate" => 38,
+    "mitigated" => 29,
+    "mitigates" => 29,
+    "mitigating" => 32,
+    "mitigation" => 38,
+    "mitochondria" => 24,
+    "mitre" => 30,
+    "mitres" => 27,
+    "mittens" => 27,
+    "mitter" => 24,
+    "mix" => 43,
+    "mixed" => 45,
+    "mixer" => 32,
+    "mixes" => 33,
+    "mixing" => 42,
+    "mixture" => 37,
+    "mixup" => 33,
+    "mixups" => 24,
+    "mnemonic" => 37,
+    "mnemonics" => 32,
+    "moan" => 30,
+    "moaned" => 30,
+    "moaning" => 27,
+    "mob" => 24,
+    "mobile" => 39,
+    "mobilize" => 24,
+    "moccasins" => 24,
+    "mocha" => 24,
+    "mock" => 46,
+    "mocked" => 40,
+    "mocker" => 27,
+    "mockers" => 27,
+    "mockery" => 32,
+    "mocking" => 39,
+    "mocks" => 37,
+    "mod" => 44,
+    "modal" => 33,
+    "mode" => 59,
+    "model" => 51,
+    "modeled" => 37,
+    "modeling" => 37,
+    "modelled" => 30,
+    "modelling" => 33,
+    "models" => 46,
+    "modem" => 29,
+    "moderate" => 34,
+    "moderated" => 27,
+    "moderately" => 29,
+    "moderation" => 30,
+    "moderator" => 36,
+    "moderators" => 30,
+    "modern" => 47,
+    "modernize" => 24,
+    "modernized" => 27,
+    "modernizing" => 24,
+    "modes" => 47,
+    "modest" => 33,
+    "modestly" => 29,
+    "modesty" => 32,
+    "modifiable" => 40,
+    "modification" => 42,
+    "modifications" => 43,
+    "modified" => 50,
+    "modifier" => 47,
+    "modifiers" => 47,
+    "modifies" => 41,
+    "modify" => 49,
+    "modifying" => 44,
+    "modish" => 24,
+    "modular" => 43,
+    "modularity" => 34,
+    "modularized" => 32,
+    "modulated" => 24,
+    "modulating" => 24,
+    "module" => 58,
+    "modules" => 53,
+    "moduli" => 27,
+    "modulo" => 40,
+    "modulus" => 36,
+    "moguls" => 24,
+    "mohammed" => 24,
+    "mohammedan" => 27,
+    "mohammedans" => 24,
+    "mohr" => 24,
+    "moiety" => 24,
+    "moines" => 24,
+    "moire" => 30,
+    "moiré" => 24,
+    "moist" => 30,
+    "moistened" => 24,
+    "moisture" => 32,
+    "molars" => 24,
+    "mold" => 29,
+    "molding" => 24,
+    "mole" => 30,
+    "molecular" => 27,
+    "moliere" => 24,
+    "moment" => 50,
+    "momentarily" => 33,
+    "momentary" => 32,
+    "moments" => 40,
+    "momentum" => 24,
+    "monad" => 24,
+    "monadic" => 29,
+    "monarch" => 24,
+    "monarchies" => 27,
+    "monarchs" => 24,
+    "monasteries" => 24,
+    "monastery" => 24,
+    "monday" => 39,
+    "mondays" => 24,
+    "monetary" => 24,
+    "money" => 46,
+    "money's" => 27,
+    "moneyed" => 27,
+    "monger" => 24,
+    "mongolia" => 38,
+    "mongolian" => 27,
+    "monica" => 29,
+    "monitor" => 41,
+    "monitored" => 24,
+    "monitoring" => 37,
+    "monitors" => 32,
+    "monk" => 27,
+    "monkey" => 31,
+    "monkeys" => 31,
+    "mono" => 34,
+    "monochrome" => 27,
+    "monogram" => 27,
+    "monograms" => 24,
+    "monograph" => 30,
+    "monographs" => 30,
+    "monolingual" => 24,
+    "monolithic" => 31,
+    "monomaniac" => 24,
+    "monomials" => 27,
+    "monopolize" => 24,
+    "monopolizing" => 24,
+    "monosyllable" => 24,
+    "monosyllables" => 24,
+    "monotone" => 27,
+    "monotonic" => 40,
+    "monotonically" => 32,
+    "monotonous" => 32,
+    "monotonously" => 27,
+    "monotony" => 24,
+    "monsieur" => 38,
+    "monster" => 39,
+    "monsters" => 27,
+    "monstrosity" => 24,
+    "monstrous" => 32,
+    "montague" => 24,
+    "montana" => 27,
+    "montenegrin" => 24,
+    "montenegro" => 31,
+    "monterey" => 24,
+    "montgomery" => 36,
+    "month" => 44,
+    "monthly" => 30,
+    "months" => 45,
+    "montpelier" => 24,
+    "montreal" => 27,
+    "montserrat" => 24,
+    "monument" => 29,
+    "monuments" => 24,
+    "moo" => 29,
+    "mood" => 35,
+    "moodily" => 29,
+    "moods" => 27,
+    "moody" => 29,
+    "moon" => 42,
+    "moonless" => 24,
+    "moonlight" => 35,
+    "moonlit" => 24,
+    "moons" => 24,
+    "moonshine" => 31,
+    "moonstone" => 37,
+    "moore" => 36,
+    "moored" => 27,
+    "moot" => 24,
+    "moped" => 27,
+    "moping" => 24,
+    "mopping" => 24,
+    "mops" => 29,
+    "moral" => 38,
+    "moralizing" => 24,
+    "morals" => 24,
+    "morbid" => 27,
+    "more" => 63,
+    "moreover" => 40,
+    "morgan" => 34,
+    "mormon" => 35,
+    "mormonism" => 27,
+    "mormons" => 33,
+    "morning" => 48,
+    "mornings" => 29,
+    "morocco" => 27,
+    "morose" => 29,
+    "morosely" => 24,
+    "morphological" => 34,
+    "morphologically" => 27,
+    "morphology" => 27,
+    "morris" => 33,
+    "morrow" => 41,
+    "morsel" => 29,
+    "mortal" => 36,
+    "mortally" => 29,
+    "mortals" => 27,
+    "mortar" => 27,
+    "mortem" => 31,
+    "mortgage" => 24,
+    "mortification" => 24,
+    "mortified" => 27,
+    "morton" => 29,
+    "mosaic" => 27,
+    "mosques" => 27,
+    "moss" => 27,
+    "mossy" => 24,
+    "most" => 58,
+    "mostly" => 49,
+    "mother" => 43,
+    "mother's" => 34,
+    "motherless" => 24,
+    "mothers" => 27,
+    "moths" => 29,
+    "motif" => 47,
+    "motion" => 47,
+    "motioned" => 27,
+    "motionless" => 36,
+    "motions" => 40,
+    "motivate" => 27,
+    "motivated" => 34,
+    "motivates" => 30,
+    "motivating" => 32,
+    "motivation" => 44,
+    "motivational" => 24,
+    "motivations" => 31,
+    "motive" => 35,
+    "motives" => 34,
+    "motley" => 24,
+    "motor" => 36,
+    "motorcycle" => 27,
+    "motorists" => 24,
+    "motorola" => 32,
+    "motors" => 24,
+    "mottled" => 24,
+    "motto" => 24,
+    "mottoes" => 24,
+    "mould" => 31,
+    "moulded" => 27,
+    "moulding" => 24,
+    "moulton" => 30,
+    "mount" => 36,
+    "mountain" => 38,
+    "mountaineers" => 24,
+    "mountainous" => 31,
+    "mountains" => 42,
+    "mountebanks" => 24,
+    "mounted" => 39,
+    "mounting" => 33,
+    "mounts" => 31,
+    "mourn" => 32,
+    "mourned" => 27,
+    "mourner" => 24,
+    "mourners" => 27,
+    "mournful" => 32,
+    "mournfully" => 30,
+    "mourning" => 32,
+    "mouse" => 53,
+    "moused" => 24,
+    "moustache" => 29,
+    "moustached" => 24,
+    "mouth" => 42,
+    "mouthed" => 27,
+    "mouthpiece" => 24,
+    "mouths" => 31,
+    "movable" => 32,
+    "move" => 56,
+    "moveable" => 27,
+    "moved" => 51,
+    "movement" => 44,
+    "movements" => 36,
+    "moves" => 48,
+    "movie" => 27,
+    "movies" => 33,
+    "moving" => 49,
+    "mower" => 24,
+    "mps" => 35,
+    "mrs" => 44,
+    "msc" => 32,
+    "much" => 55,
+    "muchness" => 29,
+    "muck" => 29,
+    "mucking" => 24,
+    "mud" => 32,
+    "mudd" => 24,
+    "muddied" => 24,
+    "muddle" => 27,
+    "muddled" => 24,
+    "muddy" => 29,
+    "muesli" => 24,
+    "muff" => 37,
+    "muffled" => 33,
+    "muhammadan" => 24,
+    "muir" => 27,
+    "mulatto" => 24,
+    "mule" => 27,
+    "muller" => 27,
+    "multi" => 50,
+    "multibyte" => 49,
+    "multidimensional" => 30,
+    "multifarious" => 24,
+    "multilingual" => 36,
+    "multimedia" => 41,
+    "multinomial" => 24,
+    "multiparty" => 24,
+    "multiple" => 57,
+    "multiples" => 29,
+    "multiplex" => 30,
+    "multiplexed" => 27,
+    "multiplexes" => 24,
+    "multiplexing" => 33,
+    "multiplicand" => 24,
+    "multiplication" => 46,
+    "multiplications" => 33,
+    "multiplicative" => 30,
+    "multiplicity" => 29,
+    "multiplied" => 38,
+    "multiplier" => 27,
+    "multipliers" => 24,
+    "multiplies" => 42,
+    "multiply" => 51,
+    "multiplying" => 36,
+    "multiprocessing" => 24,
+    "multiprocessor" => 30,
+    "multipurpose" => 33,
+    "multitask" => 24,
+    "multitasking" => 31,
+    "multitude" => 37,
+    "multitudes" => 24,
+    "multivariate" => 30,
+    "mum" => 35,
+    "mumble" => 29,
+    "mumbled" => 30,
+    "mumbles" => 24,
+    "mumbling" => 27,
+    "mummy" => 29,
+    "munched" => 27,
+    "muncher" => 29,
+    "munching" => 24,
+    "munich" => 24,
+    "municipal" => 24,
+    "munificent" => 24,
+    "munro" => 27,
+    "murder" => 40,
+    "murdered" => 39,
+    "murderer" => 39,
+    "murderers" => 24,
+    "murderess" => 24,
+    "murdering" => 29,
+    "murderous" => 32,
+    "murders" => 29,
+    "murky" => 30,
+    "murmur" => 37,
+    "murmured" => 35,
+    "murmuring" => 29,
+    "murmurs" => 29,
+    "murray" => 24,
+    "muscle" => 32,
+    "muscles" => 32,
+    "muscular" => 29,
+    "mused" => 24,
+    "museum" => 32,
+    "museums" => 27,
+    "mush" => 27,
+    "mushroom" => 33,
+    "mushrooms" => 24,
+    "music" => 41,
+    "musical" => 31,
+    "musician" => 24,
+    "musicians" => 27,
+    "musing" => 30,
+    "musings" => 27,
+    "muskets" => 24,
+    "muslin" => 29,
+    "must" => 61,
+    "mustache" => 33,
+    "mustachios" => 24,
+    "mustard" => 30,
+    "muster" => 29,
+    "mustn't" => 31,
+    "musts" => 24,
+    "musty" => 29,
+    "mutability" => 45,
+    "mutable" => 53,
+    "mutably" => 42,
+    "mutants" => 24,
+    "mutate" => 43,
+    "mutated" => 40,
+    "mutates" => 37,
+    "mutating" => 40,
+    "mutation" => 40,
+    "mutations" => 33,
+    "mute" => 24,
+    "mutex" => 46,
+    "mutilated" => 24,
+    "mutilation" => 24,
+    "mutinous" => 24,
+    "mutiny" => 29,
+    "mutt" => 24,
+    "muttered" => 38,
+    "muttering" => 34,
+    "mutterings" => 27,
+    "mutton" => 27,
+    "mutual" => 38,
+    "mutually" => 42,
+    "muzzle" => 29,
+    "my" => 60,
+    "mycelium" => 27,
+    "myriad" => 27,
+    "myrtle" => 37,
+    "myself" => 49,
+    "mysteries" => 32,
+    "mysterious" => 38,
+    "mysteriously" => 29,
+    "mystery" => 40,
+    "mystic" => 27,
+    "mystical" => 24,
+    "myth" => 24,
+    "mythology" => 24,
+    "myths" => 27,
+    "métier" => 24,
+    "nag" => 27,
+    "nagasaki" => 30,
+    "nags" => 24,
+    "nail" => 29,
+    "nails" => 27,
+    "naive" => 41,
+    "naively" => 34,
+    "naked" => 42,
+    "nakedness" => 24,
+    "nam" => 39,
+    "name" => 61,
+    "nameable" => 30,
+    "named" => 53,
+    "nameless" => 24,
+    "namely" => 41,
+    "names" => 56,
+    "namesakes" => 24,
+    "namespace" => 50,
+    "naming" => 45,
+    "nan" => 49,
+    "nancy" => 33,
+    "nanosecond" => 39,
+    "nanoseconds" => 42,
+    "nap" => 27,
+    "napkin" => 27,
+    "naples" => 24,
+    "napoleons" => 27,
+    "napped" => 24,
+    "narcotic" => 29,
+    "narrated" => 32,
+    "narration" => 31,
+    "narrations" => 24,
+    "narrative" => 37,
+    "narratives" => 30,
+    "narrow" => 43,
+    "narrowed" => 32,
+    "narrower" => 32,
+    "narrowing" => 33,
+    "narrowly" => 34,
+    "nasa" => 31,
+    "nasal" => 24,
+    "nasals" => 24,
+    "nascent" => 24,
+    "nastier" => 24,
+    "nasty" => 36,
+    "nat" => 29,
+    "nathan" => 29,
+    "nathaniel" => 29,
+    "nation" => 32,
+    "national" => 43,
+    "nationalities" => 27,
+    "nationality" => 24,
+    "nations" => 35,
+    "nationwide" => 24,
+    "native" => 53,
+    "natively" => 41,
+    "natives" => 30,
+    "natty" => 24,
+    "natural" => 46,
+    "naturalization" => 24,
+    "naturalized" => 24,
+    "naturally" => 42,
+    "nature" => 47,
+    "natured" => 29,
+    "natures" => 30,
+    "naughty" => 24,
+    "nautical" => 29,
+    "nautilus" => 24,
+    "navaho" => 24,
+    "navajo" => 24,
+    "naval" => 29,
+    "navigable" => 24,
+    "navigate" => 39,
+    "navigated" => 24,
+    "navigates" => 24,
+    "navigating" => 35,
+    "navigation" => 38,
+    "navigational" => 24,
+    "navigator" => 24,
+    "navigators" => 30,
+    "navy" => 29,
+    "nay" => 33,
+    "nays" => 27,
+    "nazis" => 24,
+    "naïve" => 29,
+    "ne'er" => 24,
+    "near" => 48,
+    "nearby" => 33,
+    "neared" => 24,
+    "nearer" => 37,
+    "nearest" => 51,
+    "nearing" => 30,
+    "nearly" => 46,
+    "neat" => 39,
+    "neater" => 27,
+    "neatly" => 35,
+    "neatness" => 24,
+    "nebraska" => 30,
+    "nebula" => 27,
+    "nebulous" => 30,
+    "necessarily" => 45,
+    "necessary" => 52,
+    "necessitate" => 33,
+    "necessitates" => 27,
+    "necessities" => 24,
+    "necessity" => 35,
+    "neck" => 41,
+    "necklace" => 24,
+    "necks" => 24,
+    "necktie" => 29,
+    "ned" => 29,
+    "need" => 60,
+    "needed" => 53,
+    "needful" => 30,
+    "needing" => 43,
+    "needle" => 35,
+    "needles" => 30,
+    "needless" => 34,
+    "needlessly" => 32,
+    "needs" => 54,
+    "needy" => 31,
+    "neg" => 33,
+    "negate" => 38,
+    "negated" => 44,
+    "negates" => 37,
+    "negating" => 32,
+    "negation" => 41,
+    "negations" => 27,
+    "negative" => 50,
+    "negatively" => 30,
+    "negatives" => 30,
+    "neglect" => 29,
+    "neglected" => 35,
+    "negligence" => 34,
+    "negligently" => 24,
+    "negligible" => 33,
+    "negligibly" => 24,
+    "negotiable" => 27,
+    "negotiate" => 29,
+    "negotiated" => 33,
+    "negotiates" => 24,
+    "negotiating" => 24,
+    "negotiation" => 38,
+    "negotiations" => 30,
+    "negro" => 32,
+    "negroes" => 29,
+    "neighbor" => 37,
+    "neighborhood" => 32,
+    "neighborhoods" => 24,
+    "neighboring" => 35,
+    "neighbors" => 36,
+    "neighbour" => 30,
+    "neighbourhood" => 37,
+    "neighbourhoods" => 24,
+    "neighbouring" => 34,
+    "neighbours" => 31,
+    "neil" => 36,
+    "neither" => 47,
+    "nelson" => 24,
+    "nemo" => 27,
+    "neo" => 36,
+    "neon" => 44,
+    "nepali" => 24,
+    "nephew" => 29,
+    "neptune" => 35,
+    "nerd" => 30,
+    "nerve" => 31,
+    "nerved" => 24,
+    "nerveless" => 27,
+    "nerves" => 34,
+    "nervous" => 38,
+    "nervously" => 33,
+    "ness" => 35,
+    "nest" => 39,
+    "nested" => 50,
+    "nesting" => 44,
+    "nestling" => 24,
+    "nests" => 32,
+    "net" => 47,
+    "netherlands" => 29,
+    "nets" => 29,
+    "nettle" => 24,
+    "network" => 52,
+    "networked" => 39,
+    "networking" => 43,
+    "networks" => 43,
+    "neural" => 37,
+    "neurophysiology" => 24,
+    "neutral" => 36,
+    "neutrality" => 24,
+    "neutrino" => 37,
+    "nevada" => 31,
+    "never" => 55,
+    "nevertheless" => 41,
+    "neville" => 37,
+    "new" => 61,
+    "newcomer" => 29,
+    "newcomers" => 27,
+    "newell" => 27,
+    "newer" => 49,
+    "newest" => 39,
+    "newfound" => 27,
+    "newfoundland" => 24,
+    "newline" => 44,
+    "newly" => 45,
+    "newness" => 24,
+    "news" => 43,
+    "newsletter" => 24,
+    "newsletters" => 24,
+    "newspaper" => 36,
+    "newspapers" => 34,
+    "newsreader" => 24,
+    "newt" => 24,
+    "newton" => 34,
+    "next" => 56,
+    "niagara" => 24,
+    "nib" => 27,
+    "nibble" => 29,
+    "nibbled" => 29,
+    "nibbles" => 27,
+    "nibbling" => 30,
+    "nice" => 47,
+    "nicely" => 41,
+    "nicer" => 40,
+    "nicest" => 27,
+    "niceties" => 27,
+    "niche" => 38,
+    "niches" => 31,
+    "nicholas" => 31,
+    "nick" => 39,
+    "nickel" => 32,
+    "nickname" => 27,
+    "nicknamed" => 24,
+    "nicknames" => 27,
+    "niece" => 36,
+    "nifty" => 29,
+    "nigel" => 30,
+    "nigeria" => 24,
+    "nigger" => 33,
+    "niggers" => 24,
+    "nigh" => 30,
+    "night" => 50,
+    "nightfall" => 29,
+    "nightingale" => 29,
+    "nightly" => 52,
+    "nightmare" => 29,
+    "nights" => 38,
+    "nil" => 31,
+    "nile" => 24,
+    "nimble" => 24,
+    "nine" => 44,
+    "nineteen" => 34,
+    "nineteenth" => 32,
+    "ninety" => 31,
+    "ninth" => 32,
+    "nip" => 30,
+    "nipped" => 27,
+    "nipper" => 27,
+    "nisi" => 24,
+    "nit" => 24,
+    "nitrate" => 24,
+    "nitty" => 32,
+    "nix" => 41,
+    "no" => 63,
+    "noah" => 27,
+    "nobility" => 30,
+    "noble" => 40,
+    "nobleman" => 29,
+    "nobler" => 24,
+    "nobles" => 24,
+    "noblest" => 30,
+    "nobody" => 42,
+    "nobs" => 24,
+    "nocturnal" => 29,
+    "nod" => 32,
+    "nodded" => 37,
+    "nodding" => 33,
+    "node" => 56,
+    "nodes" => 46,
+    "noh" => 27,
+    "noise" => 44,
+    "noised" => 24,
+    "noiseless" => 27,
+    "noiselessly" => 30,
+    "noises" => 29,
+    "noisier" => 24,
+    "noisily" => 27,
+    "noisome" => 24,
+    "noisy" => 38,
+    "nom" => 45,
+    "nombre" => 24,
+    "nomenclature" => 24,
+    "nominal" => 38,
+    "nominally" => 27,
+    "nominate" => 29,
+    "nominating" => 24,
+    "nominative" => 30,
+    "non" => 58,
+    "nonce" => 38,
+    "noncommittal" => 24,
+    "noncompliance" => 27,
+    "nonconformist" => 24,
+    "none" => 53,
+    "nonentity" => 24,
+    "nonetheless" => 35,
+    "nonexistent" => 34,
+    "nonlinear" => 27,
+    "nonprofit" => 29,
+    "nonsense" => 37,
+    "nonsensical" => 32,
+    "nonstandard" => 34,
+    "nonstop" => 29,
+    "nonzero" => 38,
+    "noodles" => 24,
+    "nook" => 31,
+    "nooks" => 27,
+    "noon" => 41,
+    "noonday" => 24,
+    "noose" => 24,
+    "nor" => 49,
+    "norbert" => 27,
+    "nordic" => 31,
+    "norm" => 44,
+    "normal" => 55,
+    "normalisation" => 30,
+    "normalised" => 27,
+    "normalises" => 24,
+    "normalization" => 42,
+    "normalize" => 41,
+    "normalized" => 43,
+    "normalizes" => 31,
+    "normalizing" => 35,
+    "normally" => 50,
+    "normals" => 32,
+    "norman" => 34,
+    "normandy" => 24,
+    "normans" => 24,
+    "normative" => 33,
+    "norms" => 32,
+    "norse" => 35,
+    "north" => 41,
+    "northeast" => 27,
+    "northerly" => 24,
+    "northern" => 36,
+    "northumberland" => 24,
+    "northumbria" => 27,
+    "northumbrian" => 29,
+    "northward" => 32,
+    "northwards" => 27,
+    "northwest" => 24,
+    "norton" => 32,
+    "norway" => 33,
+    "norwegian" => 33,
+    "nose" => 40,
+    "nosed" => 29,
+    "noses" => 36,
+    "nostril" => 24,
+    "nostrils" => 32,
+    "not" => 68,
+    "nota" => 24,
+    "notable" => 42,
+    "notably" => 42,
+    "notate" => 24,
+    "notation" => 46,
+    "notations" => 33,
+    "notch" => 24,
+    "notching" => 24,
+    "note" => 60,
+    "notebook" => 35,
+    "notebooks" => 33,
+    "noted" => 45,
+    "notepad" => 33,
+    "notes" => 50,
+    "noteworthy" => 30,
+    "nothing" => 53,
+    "nothingness" => 24,
+    "notice" => 49,
+    "noticeable" => 36,
+    "noticeably" => 27,
+    "noticed" => 42,
+    "notices" => 38,
+    "noticing" => 34,
+    "notification" => 44,
+    "notifications" => 42,
+    "notified" => 38,
+    "notifies" => 30,
+    "notify" => 43,
+    "notifying" => 34,
+    "noting" => 40,
+    "notion" => 41,
+    "notional" => 24,
+    "notions" => 32,
+    "notoriety" => 29,
+    "notorious" => 31,
+    "notoriously" => 31,
+    "nottingham" => 24,
+    "notwithstanding" => 35,
+    "nought" => 29,
+    "noun" => 40,
+    "nouns" => 39,
+    "nourished" => 29,
+    "nourishment" => 30,
+    "nous" => 24,
+    "nova" => 24,
+    "novel" => 37,
+    "novelties" => 29,
+    "novelty" => 27,
+    "november" => 36,
+    "novice" => 32,
+    "novices" => 29,
+    "novo" => 30,
+    "now" => 60,
+    "nowadays" => 33,
+    "nowhere" => 35,
+    "nth" => 41,
+    "nuance" => 29,
+    "nuances" => 24,
+    "nuclear" => 27,
+    "nucleic" => 24,
+    "nucleotides" => 24,
+    "nucleus" => 29,
+    "nudge" => 24,
+    "nudged" => 24,
+    "nudges" => 29,
+    "nuisance" => 27,
+    "nul" => 49,
+    "null" => 54,
+    "nullify" => 24,
+    "nulls" => 30,
+    "number" => 61,
+    "numbered" => 43,
+    "numbering" => 37,
+    "numbers" => 54,
+    "numeral" => 38,
+    "numerals" => 34,
+    "numerator" => 27,
+    "numerators" => 29,
+    "numeric" => 50,
+    "numerical" => 41,
+    "numerically" => 34,
+    "numero" => 24,
+    "numerous" => 43,
+    "numskull" => 24,
+    "nun" => 33,
+    "nuptial" => 24,
+    "nurse" => 36,
+    "nursed" => 31,
+    "nursery" => 32,
+    "nursing" => 29,
+    "nurtured" => 24,
+    "nut" => 27,
+    "nutmeg" => 27,
+    "nuts" => 30,
+    "nutshell" => 32,
+    "nymphs" => 24,
+    "née" => 24,
+    "o'clock" => 45,
+    "o'er" => 24,
+    "oak" => 35,
+    "oaken" => 24,
+    "oakland" => 27,
+    "oakley" => 24,
+    "oaks" => 27,
+    "oap" => 24,
+    "oar" => 29,
+    "oars" => 30,
+    "oaten" => 24,
+    "oath" => 35,
+    "oaths" => 31,
+    "oatmeal" => 27,
+    "obadiah" => 32,
+    "obdurate" => 24,
+    "obedience" => 30,
+    "obedient" => 29,
+    "obese" => 24,
+    "obey" => 37,
+    "obeyed" => 31,
+    "obeying" => 29,
+    "obeys" => 32,
+    "obfuscated" => 31,
+    "obfuscating" => 24,
+    "obfuscation" => 36,
+    "object" => 59,
+    "objected" => 33,
+    "objection" => 32,
+    "objections" => 32,
+    "objective" => 42,
+    "objectives" => 30,
+    "objects" => 55,
+    "obligate" => 24,
+    "obligated" => 27,
+    "obligation" => 35,
+    "obligations" => 36,
+    "obligatory" => 30,
+    "oblige" => 27,
+    "obliged" => 40,
+    "obliging" => 29,
+    "obligingly" => 24,
+    "obliterate" => 24,
+    "obliterated" => 32,
+    "oblivion" => 31,
+    "oblivious" => 31,
+    "oblong" => 24,
+    "obnoxious" => 24,
+    "oboe" => 33,
+    "oboes" => 24,
+    "obscene" => 29,
+    "obscure" => 39,
+    "obscured" => 31,
+    "obscurely" => 29,
+    "obscurity" => 27,
+    "observable" => 38,
+    "observably" => 24,
+    "observant" => 27,
+    "observation" => 38,
+    "observations" => 29,
+    "observe" => 42,
+    "observed" => 45,
+    "observer" => 36,
+    "observers" => 37,
+    "observes" => 32,
+    "observing" => 37,
+    "obsession" => 27,
+    "obsolescent" => 27,
+    "obsolete" => 42,
+    "obsoleted" => 24,
+    "obstacle" => 35,
+    "obstacles" => 33,
+    "obstetrical" => 24,
+    "obstinacy" => 24,
+    "obstinate" => 32,
+    "obstinately" => 30,
+    "obstruct" => 24,
+    "obstructed" => 27,
+    "obstructing" => 24,
+    "obstruction" => 24,
+    "obtain" => 47,
+    "obtainable" => 31,
+    "obtained" => 46,
+    "obtaining" => 43,
+    "obtains" => 34,
+    "obtruded" => 27,
+    "obtrusive" => 24,
+    "obtuse" => 24,
+    "obviated" => 24,
+    "obvious" => 45,
+    "obviously" => 42,
+    "occasion" => 39,
+    "occasional" => 38,
+    "occasionally" => 41,
+    "occasioned" => 31,
+    "occasions" => 36,
+    "occidental" => 24,
+    "occipital" => 24,
+    "occluded" => 24,
+    "occupant" => 31,
+    "occupants" => 24,
+    "occupation" => 35,
+    "occupations" => 35,
+    "occupied" => 42,
+    "occupies" => 35,
+    "occupy" => 37,
+    "occupying" => 33,
+    "occur" => 49,
+    "occurred" => 47,
+    "occurrence" => 44,
+    "occurrences" => 40,
+    "occurring" => 38,
+    "occurs" => 48,
+    "ocean" => 39,
+    "oceans" => 24,
+    "ochre" => 24,
+    "octal" => 44,
+    "octave" => 32,
+    "octaves" => 24,
+    "octavo" => 24,
+    "octet" => 34,
+    "octets" => 37,
+    "october" => 39,
+    "octopus" => 27,
+    "oculist" => 24,
+    "odd" => 42,
+    "oddball" => 24,
+    "oddest" => 24,
+    "oddity" => 29,
+    "oddly" => 30,
+    "odds" => 33,
+    "ode" => 29,
+    "oder" => 24,
+    "odes" => 24,
+    "odessa" => 24,
+    "odin" => 24,
+    "odious" => 31,
+    "odor" => 30,
+    "odorous" => 27,
+    "odour" => 24,
+    "odourless" => 24,
+    "ods" => 30,
+    "of" => 73,
+    "off" => 55,
+    "offence" => 31,
+    "offences" => 27,
+    "offend" => 29,
+    "offended" => 36,
+    "offender" => 24,
+    "offenders" => 24,
+    "offending" => 33,
+    "offense" => 27,
+    "offensive" => 29,
+    "offer" => 46,
+    "offered" => 42,
+    "offering" => 41,
+    "offerings" => 33,
+    "offers" => 47,
+    "offhand" => 24,
+    "office" => 45,
+    "officer" => 36,
+    "officers" => 39,
+    "offices" => 37,
+    "official" => 48,
+    "officially" => 41,
+    "officials" => 30,
+    "offing" => 24,
+    "offline" => 42,
+    "offload" => 34,
+    "offloaded" => 27,
+    "offloading" => 24,
+    "offscreen" => 24,
+    "offset" => 51,
+    "offsets" => 43,
+    "offsetting" => 32,
+    "offshoot" => 27,
+    "offshore" => 24,
+    "offspring" => 29,
+    "oft" => 29,
+    "often" => 53,
+    "oftener" => 24,
+    "oftentimes" => 29,
+    "ogre" => 27,
+    "oh" => 49,
+    "ohio" => 30,
+    "ohm" => 24,
+    "oil" => 32,
+    "oiled" => 24,
+    "oily" => 24,
+    "ointment" => 24,
+    "ok" => 50,
+    "okay" => 38,
+    "olaf" => 36,
+    "old" => 54,
+    "older" => 50,
+    "oldest" => 39,
+    "olfactory" => 24,
+    "olin" => 27,
+    "olive" => 24,
+    "oliver" => 24,
+    "olympic" => 24,
+    "omaha" => 38,
+    "omega" => 31,
+    "omen" => 24,
+    "omicron" => 30,
+    "ominous" => 32,
+    "omission" => 32,
+    "omissions" => 29,
+    "omit" => 42,
+    "omits" => 35,
+    "omitted" => 46,
+    "omitting" => 36,
+    "omne" => 24,
+    "omnibus" => 27,
+    "omnibuses" => 27,
+    "omnipotence" => 27,
+    "omnipotent" => 29,
+    "omniscient" => 24,
+    "on" => 68,
+    "once" => 56,
+    "one" => 63,
+    "one's" => 40,
+    "onerous" => 33,
+    "ones" => 50,
+    "oneself" => 27,
+    "ongoing" => 40,
+    "onion" => 27,
+    "onions" => 24,
+    "online" => 46,
+    "only" => 63,
+    "ono" => 38,
+    "onscreen" => 31,
+    "onset" => 24,
+    "onsets" => 27,
+    "onslaught" => 24,
+    "onto" => 45,
+    "onus" => 24,
+    "onward" => 36,
+    "onwards" => 35,
+    "oodles" => 24,
+    "oops" => 32,
+    "oozing" => 27,
+    "opacity" => 29,
+    "opal" => 27,
+    "opaque" => 44,
+    "opaquely" => 24,
+    "opaqueness" => 24,
+    "open" => 55,
+    "opened" => 49,
+    "opener" => 29,
+    "opening" => 49,
+    "openings" => 27,
+    "openly" => 29,
+    "openness" => 24,
+    "opens" => 42,
+    "opera" => 31,
+    "operability" => 24,
+    "operand" => 49,
+    "operands" => 47,
+    "operate" => 44,
+    "operated" => 41,
+    "operates" => 43,
+    "operatic" => 24,
+    "operating" => 50,
+    "operation" => 54,
+    "operational" => 35,
+    "operations" => 55,
+    "operative" => 27,
+    "operator" => 52,
+    "operators" => 49,
+    "opined" => 31,
+    "opinion" => 42,
+    "opinionated" => 38,
+    "opinions" => 27,
+    "opium" => 38,
+    "opponent" => 30,
+    "opportune" => 24,
+    "opportunities" => 37,
+    "opportunity" => 42,
+    "opposed" => 43,
+    "opposers" => 24,
+    "opposing" => 30,
+    "opposite" => 44,
+    "opposition" => 32,
+    "oppositions" => 24,
+    "oppressed" => 34,
+    "oppresses" => 24,
+    "oppression" => 27,
+    "oppressive" => 32,
+    "oppressively" => 24,
+    "opprobrium" => 24,
+    "opt" => 50,
+    "opted" => 35,
+    "optic" => 24,
+    "optical" => 37,
+    "optics" => 24,
+    "optimal" => 44,
+    "optimally" => 33,
+    "optimisation" => 32,
+    "optimisations" => 36,
+    "optimise" => 32,
+    "optimised" => 38,
+    "optimiser" => 27,
+    "optimising" => 27,
+    "optimism" => 32,
+    "optimistic" => 32,
+    "optimistically" => 27,
+    "optimization" => 47,
+    "optimizations" => 48,
+    "optimize" => 44,
+    "optimized" => 49,
+    "optimizer" => 39,
+    "optimizes" => 35,
+    "optimizing" => 39,
+    "optimum" => 29,
+    "opting" => 36,
+    "option" => 61,
+    "optional" => 55,
+    "optionally" => 48,
+    "options" => 58,
+    "opts" => 35,
+    "opulence" => 24,
+    "opulent" => 24,
+    "opus" => 38,
+    "or" => 68,
+    "oracle" => 38,
+    "oracles" => 27,
+    "oral" => 24,
+    "orange" => 36,
+    "oranges" => 31,
+    "oration" => 24,
+    "orb" => 27,
+    "orbit" => 31,
+    "orbital" => 29,
+    "orbs" => 24,
+    "orchestra" => 33,
+    "orchestras" => 24,
+    "orchestrated" => 24,
+    "orchestrates" => 24,
+    "orchestrating" => 24,
+    "orchestration" => 24,
+    "orchestrator" => 29,
+    "orchestrators" => 24,
+    "orchid" => 27,
+    "orchids" => 27,
+    "ordain" => 27,
+    "ordeal" => 27,
+    "order" => 57,
+    "ordered" => 46,
+    "ordering" => 49,
+    "orderings" => 38,
+    "orderly" => 24,
+    "orders" => 41,
+    "ordinal" => 40,
+    "ordinarily" => 35,
+    "ordinary" => 42,
+    "ordinate" => 27,
+    "ordnance" => 24,
+    "ore" => 31,
+    "oregon" => 24,
+    "organ" => 24,
+    "organic" => 24,
+    "organically" => 24,
+    "organisation" => 32,
+    "organisations" => 31,
+    "organise" => 27,
+    "organised" => 30,
+    "organization" => 43,
+    "organizational" => 33,
+    "organizationally" => 27,
+    "organizations" => 37,
+    "organize" => 38,
+    "organized" => 41,
+    "organizes" => 30,
+    "organizing" => 35,
+    "organs" => 24,
+    "orgies" => 31,
+    "orient" => 27,
+    "oriental" => 33,
+    "orientated" => 27,
+    "orientation" => 37,
+    "orientations" => 24,
+    "oriented" => 45,
+    "orienting" => 24,
+    "orig" => 36,
+    "origin" => 43,
+    "original" => 54,
+    "originality" => 30,
+    "originally" => 47,
+    "originals" => 32,
+    "originate" => 33,
+    "originated" => 38,
+    "originates" => 34,
+    "originating" => 33,
+    "origins" => 36,
+    "orkney" => 24,
+    "orkneys" => 24,
+    "orleans" => 24,
+    "ornament" => 30,
+    "ornamentation" => 27,
+    "ornamented" => 29,
+    "ornaments" => 27,
+    "ornery" => 24,
+    "orphan" => 39,
+    "orphanage" => 24,
+    "orphaned" => 24,
+    "orphaning" => 24,
+    "orphans" => 27,
+    "ors" => 32,
+    "orthogonal" => 32,
+    "orthographic" => 29,
+    "orthographically" => 24,
+    "orthography" => 32,
+    "oscillated" => 24,
+    "oscillates" => 24,
+    "oscillation" => 24,
+    "oslo" => 24,
+    "ostensibly" => 24,
+    "ostentation" => 24,
+    "ostentatious" => 24,
+    "ostentatiously" => 24,
+    "ostlers" => 27,
+    "ostrich" => 24,
+    "oswald" => 24,
+    "other" => 62,
+    "others" => 50,
+    "otherwise" => 58,
+    "otter" => 27,
+    "otters" => 27,
+    "ouch" => 29,
+    "ought" => 43,
+    "oughtn't" => 31,
+    "ounce" => 27,
+    "our" => 57,
+    "ours" => 35,
+    "ourselves" => 41,
+    "out" => 61,
+    "outages" => 27,
+    "outbound" => 32,
+    "outbreak" => 29,
+    "outbreaks" => 24,
+    "outburst" => 24,
+    "outbursts" => 24,
+    "outcast" => 32,
+    "outcome" => 39,
+    "outcomes" => 35,
+    "outcry" => 27,
+    "outdated" => 45,
+    "outdoor" => 24,
+    "outdoors" => 29,
+    "outer" => 47,
+    "outermost" => 33,
+    "outfit" => 27,
+    "outgoing" => 39,
+    "outgrows" => 24,
+    "outhouse" => 27,
+    "outlast" => 24,
+    "outlaw" => 24,
+    "outlawed" => 24,
+    "outlaws" => 32,
+    "outlet" => 29,
+    "outlets" => 30,
+    "outline" => 38,
+    "outlined" => 40,
+    "outlines" => 36,
+    "outlining" => 32,
+    "outlive" => 41,
+    "outlives" => 39,
+    "outlook" => 29,
+    "outlying" => 24,
+    "outnumber" => 24,
+    "outpace" => 24,
+    "outpaced" => 24,
+    "outperform" => 33,
+    "outperforms" => 31,
+    "outpoint" => 24,
+    "output" => 58,
+    "outputs" => 47,
+    "outputted" => 31,
+    "outputting" => 35,
+    "outraged" => 27,
+    "outrageously" => 27,
+    "outrageousness" => 24,
+    "outrages" => 29,
+    "outreach" => 24,
+    "outright" => 31,
+    "outré" => 27,
+    "outs" => 27,
+    "outset" => 33,
+    "outside" => 51,
+    "outsides" => 24,
+    "outskirts" => 27,
+    "outspoken" => 27,
+    "outstanding" => 37,
+    "outstay" => 24,
+    "outstretched" => 27,
+    "outward" => 33,
+    "outwardly" => 24,
+    "outwards" => 27,
+    "outweigh" => 32,
+    "outweighed" => 24,
+    "outweighs" => 27,
+    "ovals" => 24,
+    "over" => 58,
+    "overall" => 43,
+    "overblown" => 24,
+    "overboard" => 27,
+    "overcame" => 32,
+    "overcast" => 29,
+    "overcoat" => 32,
+    "overcome" => 39,
+    "overcoming" => 27,
+    "overdid" => 24,
+    "overdo" => 24,
+    "overemphasize" => 27,
+    "overestimated" => 24,
+    "overflow" => 52,
+    "overflowed" => 34,
+    "overflowing" => 39,
+    "overflows" => 42,
+    "overfull" => 24,
+    "overhanging" => 30,
+    "overhangs" => 27,
+    "overhaul" => 31,
+    "overhauled" => 27,
+    "overhead" => 49,
+    "overhear" => 24,
+    "overheard" => 29,
+    "overhearing" => 24,
+    "overheating" => 24,
+    "overhung" => 30,
+    "overjoyed" => 29,
+    "overkill" => 32,
+    "overlaid" => 24,
+    "overlap" => 44,
+    "overlapped" => 32,
+    "overlapping" => 43,
+    "overlaps" => 36,
+    "overlay" => 36,
+    "overlaying" => 24,
+    "overlays" => 27,
+    "overline" => 24,
+    "overload" => 36,
+    "overloaded" => 38,
+    "overloading" => 40,
+    "overloads" => 31,
+    "overlong" => 33,
+    "overlook" => 31,
+    "overlooked" => 32,
+    "overlooking" => 30,
+    "overlooks" => 27,
+    "overly" => 35,
+    "overnight" => 31,
+    "overpopulated" => 24,
+    "overpowered" => 24,
+    "overpowering" => 30,
+    "overprint" => 27,
+    "overridden" => 44,
+    "override" => 49,
+    "overrides" => 47,
+    "overriding" => 42,
+    "overripe" => 24,
+    "overrule" => 39,
+    "overruled" => 35,
+    "overrules" => 35,
+    "overruling" => 30,
+    "overrun" => 32,
+    "overruns" => 24,
+    "overseas" => 30,
+    "overseen" => 24,
+    "overshadowed" => 24,
+    "overshadows" => 24,
+    "oversight" => 32,
+    "oversights" => 29,
+    "oversleep" => 24,
+    "overspread" => 30,
+    "overstate" => 24,
+    "overstrike" => 32,
+    "overstrung" => 24,
+    "overt" => 29,
+    "overtake" => 29,
+    "overtaken" => 29,
+    "overtaking" => 27,
+    "overtaxed" => 24,
+    "overthrow" => 27,
+    "overtook" => 29,
+    "overturned" => 24,
+    "overturning" => 27,
+    "overtype" => 27,
+    "overview" => 50,
+    "overviews" => 24,
+    "overweigh" => 24,
+    "overwhelm" => 33,
+    "overwhelmed" => 35,
+    "overwhelming" => 36,
+    "overwhelmingly" => 30,
+    "overwrite" => 45,
+    "overwrites" => 40,
+    "overwriting" => 42,
+    "overwritten" => 43,
+    "overwrote" => 32,
+    "owe" => 35,
+    "owed" => 34,
+    "owen" => 32,
+    "owes" => 31,
+    "owing" => 37,
+    "owl" => 35,
+    "own" => 56,
+    "owned" => 48,
+    "owner" => 45,
+    "owners" => 42,
+    "ownership" => 50,
+    "owning" => 40,
+    "owns" => 41,
+    "ox" => 27,
+    "oxen" => 24,
+    "oxford" => 38,
+    "oxfordshire" => 24,
+    "oxidation" => 24,
+    "oxide" => 34,
+    "oxidised" => 24,
+    "oxidize" => 24,
+    "oxygen" => 24,
+    "oyster" => 27,
+    "pablo" => 27,
+    "pace" => 36,
+    "paced" => 32,
+    "paces" => 31,
+    "pacific" => 38,
+    "pacifist" => 24,
+    "pacing" => 31,
+    "pack" => 45,
+    "package" => 56,
+    "packaged" => 39,
+    "packages" => 51,
+    "packaging" => 39,
+    "packard" => 40,
+    "packed" => 60,
+    "packer" => 24,
+    "packet" => 44,
+    "packets" => 43,
+    "packing" => 41,
+    "packs" => 35,
+    "pad" => 38,
+    "padded" => 35,
+    "padding" => 47,
+    "paddington" => 32,
+    "paddle" => 29,
+    "paddled" => 24,
+    "paddles" => 27,
+    "paddling" => 24,
+    "padlock" => 33,
+    "padlocked" => 24,
+    "padlocks" => 27,
+    "pads" => 24,
+    "page" => 54,
+    "pageant" => 27,
+    "pager" => 38,
+    "pagers" => 24,
+    "pages" => 48,
+    "paginated" => 24,
+    "pagination" => 36,
+    "paging" => 33,
+    "pagoda" => 38,
+    "pagodas" => 30,
+    "paid" => 40,
+    "pail" => 32,
+    "pain" => 42,
+    "pained" => 27,
+    "painful" => 37,
+    "painfully" => 31,
+    "painkiller" => 24,
+    "painless" => 36,
+    "pains" => 34,
+    "painstaking" => 24,
+    "painstakingly" => 24,
+    "paint" => 35,
+    "painted" => 34,
+    "painters" => 24,
+    "painting" => 35,
+    "paintings" => 24,
+    "paints" => 24,
+    "pair" => 48,
+    "paired" => 39,
+    "pairing" => 38,
+    "pairings" => 30,
+    "pairs" => 48,
+    "pajama" => 24,
+    "pajamas" => 24,
+    "pakistan" => 24,
+    "pal" => 30,
+    "palace" => 34,
+    "palaces" => 29,
+    "palanquin" => 31,
+    "palanquins" => 31,
+    "palatable" => 27,
+    "palatial" => 24,
+    "pale" => 41,
+    "paled" => 24,
+    "paleness" => 27,
+    "paler" => 24,
+    "palette" => 38,
+    "palettes" => 27,
+    "paling" => 24,
+    "palings" => 24,
+    "palisades" => 24,
+    "pall" => 31,
+    "pallet" => 32,
+    "pallid" => 27,
+    "pallor" => 27,
+    "palm" => 35,
+    "palmetto" => 24,
+    "palms" => 32,
+    "palo" => 24,
+    "palpable" => 27,
+    "palpitate" => 24,
+    "palpitated" => 24,
+    "palpitating" => 27,
+    "palpitation" => 24,
+    "pals" => 24,
+    "palsy" => 24,
+    "pam" => 27,
+    "pamela" => 33,
+    "pamphlets" => 32,
+    "pan" => 33,
+    "pandas" => 27,
+    "pandemic" => 30,
+    "pandered" => 24,
+    "pane" => 31,
+    "panegyric" => 24,
+    "panel" => 36,
+    "panelist" => 29,
+    "panelled" => 27,
+    "panelling" => 24,
+    "panes" => 27,
+    "pang" => 32,
+    "pangs" => 29,
+    "panic" => 54,
+    "panicked" => 37,
+    "panicking" => 45,
+    "panics" => 53,
+    "panning" => 27,
+    "panoply" => 27,
+    "panorama" => 30,
+    "panoramic" => 27,
+    "pans" => 24,
+    "pansy" => 27,
+    "pantaloons" => 24,
+    "panted" => 27,
+    "panther" => 29,
+    "panthers" => 24,
+    "panting" => 33,
+    "pantries" => 24,
+    "pantry" => 30,
+    "pants" => 24,
+    "pap" => 33,
+    "papa" => 29,
+    "papaya" => 27,
+    "paper" => 47,
+    "paperback" => 27,
+    "papers" => 44,
+    "papier" => 24,
+    "papuans" => 24,
+    "papyrus" => 33,
+    "par" => 40,
+    "para" => 39,
+    "parade" => 24,
+    "paraded" => 24,
+    "paradigm" => 38,
+    "paradigms" => 34,
+    "paradise" => 32,
+    "paradox" => 24,
+    "paradoxical" => 27,
+    "paradoxically" => 24,
+    "paragraph" => 44,
+    "paragraphs" => 40,
+    "parallel" => 48,
+    "parallelism" => 42,
+    "parallels" => 27,
+    "paralytic" => 27,
+    "paralyzed" => 29,
+    "parameter" => 56,
+    "parameters" => 54,
+    "parametric" => 27,
+    "paramount" => 32,
+    "paranoid" => 32,
+    "parapet" => 24,
+    "paraphrase" => 27,
+    "paraphrased" => 24,
+    "parasitically" => 24,
+    "parcel" => 33,
+    "parcelled" => 24,
+    "parcelling" => 29,
+    "parched" => 31,
+    "parchment" => 27,
+    "pard" => 29,
+    "pardon" => 38,
+    "pardonable" => 24,
+    "pardoned" => 24,
+    "pardoning" => 24,
+    "pardons" => 24,
+    "parens" => 38,
+    "parent" => 51,
+    "parentage" => 24,
+    "parentheses" => 44,
+    "parenthesis" => 41,
+    "parenthesized" => 40,
+    "parenthetical" => 29,
+    "parenthetically" => 24,
+    "parents" => 41,
+    "pariah" => 24,
+    "parietal" => 24,
+    "paris" => 39,
+    "parish" => 30,
+    "parisian" => 29,
+    "parity" => 43,
+    "park" => 40,
+    "parked" => 33,
+    "parking" => 38,
+    "parks" => 24,
+    "parlance" => 31,
+    "parley" => 35,
+    "parliament" => 29,
+    "parlor" => 24,
+    "paroxysm" => 27,
+    "paroxysms" => 24,
+    "parquet" => 37,
+    "parr" => 27,
+    "parry" => 29,
+    "parse" => 52,
+    "parsec" => 31,
+    "parsed" => 49,
+    "parser" => 52,
+    "parsers" => 47,
+    "parses" => 45,
+    "parsing" => 53,
+    "parson" => 24,
+    "parsonage" => 24,
+    "part" => 56,
+    "partake" => 29,
+    "partaken" => 24,
+    "parte" => 24,
+    "parted" => 36,
+    "parterres" => 24,
+    "partial" => 49,
+    "partiality" => 29,
+    "partially" => 43,
+    "partials" => 33,
+    "participant" => 24,
+    "participants" => 37,
+    "participate" => 36,
+    "participated" => 30,
+    "participates" => 30,
+    "participating" => 36,
+    "participation" => 32,
+    "particle" => 24,
+    "particles" => 24,
+    "particular" => 53,
+    "particularities" => 34,
+    "particularity" => 24,
+    "particularly" => 47,
+    "particulars" => 36,
+    "parties" => 40,
+    "parting" => 31,
+    "partisans" => 29,
+    "partition" => 36,
+    "partitioned" => 35,
+    "partitioning" => 34,
+    "partitions" => 34,
+    "partly" => 44,
+    "partner" => 35,
+    "partners" => 39,
+    "partnership" => 24,
+    "partridge" => 24,
+    "parts" => 51,
+    "party" => 47,
+    "pascal" => 40,
+    "pashto" => 24,
+    "pass" => 54,
+    "passage" => 41,
+    "passages" => 32,
+    "passed" => 56,
+    "passenger" => 35,
+    "passengers" => 42,
+    "passers" => 32,
+    "passes" => 47,
+    "passing" => 53,
+    "passion" => 39,
+    "passionate" => 33,
+    "passionately" => 29,
+    "passions" => 35,
+    "passive" => 34,
+    "passively" => 31,
+    "passkey" => 24,
+    "passport" => 37,
+    "passports" => 30,
+    "password" => 45,
+    "passwords" => 38,
+    "past" => 49,
+    "pasta" => 30,
+    "paste" => 47,
+    "pasted" => 39,
+    "pastel" => 24,
+    "pastes" => 29,
+    "pastime" => 24,
+    "pastimes" => 24,
+    "pasting" => 43,
+    "pastoral" => 24,
+    "pastry" => 24,
+    "pasture" => 24,
+    "pasty" => 24,
+    "pat" => 45,
+    "patch" => 66,
+    "patchable" => 29,
+    "patched" => 37,
+    "patches" => 46,
+    "patching" => 37,
+    "pate" => 24,
+    "patent" => 34,
+    "patentee" => 24,
+    "patently" => 27,
+    "paternal" => 30,
+    "path" => 58,
+    "pathetic" => 31,
+    "pathetically" => 24,
+    "pathfinder" => 27,
+    "pathless" => 29,
+    "pathologic" => 27,
+    "pathological" => 33,
+    "pathologies" => 24,
+    "pathos" => 29,
+    "paths" => 53,
+    "pathway" => 24,
+    "pathways" => 24,
+    "patience" => 36,
+    "patient" => 36,
+    "patiently" => 34,
+    "patients" => 27,
+    "patina" => 24,
+    "patna" => 24,
+    "patriarchal" => 24,
+    "patricia" => 35,
+    "patrick" => 38,
+    "patriot" => 24,
+    "patriotic" => 24,
+    "patrol" => 24,
+    "patron" => 33,
+    "patronage" => 27,
+    "patrons" => 32,
+    "patted" => 29,
+    "pattered" => 29,
+    "pattering" => 30,
+    "pattern" => 57,
+    "patterns" => 55,
+    "patters" => 24,
+    "patting" => 27,
+    "paul" => 41,
+    "paula" => 24,
+    "paulo" => 24,
+    "pauper" => 24,
+    "pause" => 43,
+    "paused" => 42,
+    "pauses" => 33,
+    "pausing" => 32,
+    "pavement" => 34,
+    "paves" => 24,
+    "pavilions" => 27,
+    "paving" => 27,
+    "pavlov" => 38,
+    "paw" => 29,
+    "pawing" => 24,
+    "pawnbroker" => 27,
+    "pawnees" => 27,
+    "paws" => 32,
+    "pax" => 27,
+    "pay" => 43,
+    "payable" => 30,
+    "payed" => 24,
+    "payer" => 24,
+    "paying" => 37,
+    "payload" => 44,
+    "payment" => 33,
+    "payments" => 29,
+    "payne" => 24,
+    "payoff" => 24,
+    "pays" => 34,
+    "pcb" => 24,
+    "pea" => 29,
+    "peace" => 41,
+    "peaceable" => 27,
+    "peaceably" => 27,
+    "peaceful" => 34,
+    "peacefully" => 29,
+    "peach" => 24,
+    "peak" => 36,
+    "peaked" => 27,
+    "peaks" => 33,
+    "peal" => 31,
+    "peals" => 24,
+    "pearl" => 27,
+    "pearls" => 32,
+    "pearly" => 24,
+    "pears" => 27,
+    "pearson" => 24,
+    "peasant" => 31,
+    "peasantry" => 24,
+    "peasants" => 32,
+    "pebble" => 29,
+    "pebbles" => 30,
+    "peculiar" => 39,
+    "peculiarities" => 29,
+    "peculiarity" => 24,
+    "peculiarly" => 34,
+    "pecuniary" => 24,
+    "pedantic" => 32,
+    "pedantically" => 24,
+    "pedantry" => 27,
+    "pedestrian" => 27,
+    "pedestrians" => 24,
+    "peek" => 36,
+    "peeked" => 32,
+    "peeking" => 35,
+    "peeks" => 29,
+    "peel" => 24,
+    "peeled" => 24,
+    "peeling" => 24,
+    "peep" => 30,
+    "peeped" => 33,
+    "peeping" => 31,
+    "peer" => 44,
+    "peered" => 29,
+    "peeress" => 24,
+    "peering" => 34,
+    "peers" => 36,
+    "peg" => 33,
+    "pegasus" => 27,
+    "pegged" => 24,
+    "pegs" => 29,
+    "pejorative" => 24,
+    "pelicans" => 24,
+    "pelisse" => 24,
+    "pen" => 39,
+    "penal" => 24,
+    "penalized" => 32,
+    "penalizes" => 24,
+    "penalties" => 30,
+    "penalty" => 39,
+    "pence" => 29,
+    "pencil" => 32,
+    "pencils" => 27,
+    "pend" => 29,
+    "pendants" => 24,
+    "pending" => 46,
+    "pends" => 29,
+    "pendulum" => 27,
+    "penelope" => 39,
+    "penetrate" => 32,
+    "penetrated" => 33,
+    "penetrating" => 32,
+    "penetration" => 27,
+    "peninsula" => 33,
+    "peninsular" => 31,
+    "penitent" => 24,
+    "penitentiary" => 24,
+    "penn" => 31,
+    "pennies" => 31,
+    "penniless" => 29,
+    "pennsylvania" => 35,
+    "penny" => 32,
+    "pennyworth" => 24,
+    "pens" => 27,
+    "pension" => 24,
+    "pensioned" => 24,
+    "pensioners" => 24,
+    "pensions" => 24,
+    "pensive" => 30,
+    "pent" => 24,
+    "pentagon" => 24,
+    "pentium" => 37,
+    "penultimate" => 24,
+    "penury" => 27,
+    "people" => 52,
+    "peopled" => 27,
+    "peoples" => 24,
+    "pep" => 42,
+    "pepper" => 35,
+    "peppering" => 24,
+    "per" => 52,
+    "perambulating" => 24,
+    "perambulations" => 24,
+    "perceivable" => 27,
+    "perceive" => 36,
+    "perceived" => 41,
+    "perceiving" => 34,
+    "percent" => 44,
+    "percentage" => 41,
+    "percentile" => 34,
+    "percentiles" => 30,
+    "percents" => 24,
+    "perceptible" => 34,
+    "perceptibly" => 24,
+    "perceptions" => 24,
+    "perceptual" => 24,
+    "perch" => 29,
+    "perched" => 32,
+    "percussion" => 29,
+    "percy" => 29,
+    "perdition" => 24,
+    "pere" => 24,
+    "peremptorily" => 24,
+    "peremptory" => 24,
+    "perennial" => 24,
+    "perfect" => 45,
+    "perfecting" => 24,
+    "perfection" => 29,
+    "perfectly" => 44,
+    "perforce" => 36,
+    "perform" => 51,
+    "performance" => 56,
+    "performances" => 34,
+    "performed" => 49,
+    "performer" => 27,
+    "performers" => 24,
+    "performing" => 46,
+    "performs" => 49,
+    "perfume" => 27,
+    "perfumes" => 24,
+    "perhaps" => 48,
+    "peril" => 32,
+    "perilous" => 31,
+    "perils" => 30,
+    "perimeter" => 30,
+    "perimeters" => 24,
+    "period" => 44,
+    "periodic" => 34,
+    "periodically" => 39,
+    "periodicals" => 27,
+    "periods" => 36,
+    "peripheral" => 42,
+    "peripherals" => 41,
+    "periphrastic" => 29,
+    "perish" => 33,
+    "perishable" => 24,
+    "perished" => 30,
+    "perishing" => 29,
+    "perkins" => 30,
+    "perm" => 29,
+    "permanence" => 29,
+    "permanency" => 27,
+    "permanent" => 37,
+    "permanently" => 38,
+    "permeate" => 24,
+    "permeated" => 24,
+    "permeates" => 24,
+    "permissible" => 34,
+    "permission" => 46,
+    "permissions" => 46,
+    "permissive" => 37,
+    "permissively" => 29,
+    "permit" => 41,
+    "permits" => 40,
+    "permitted" => 43,
+    "permitting" => 32,
+    "permutation" => 30,
+    "permutations" => 34,
+    "permute" => 32,
+    "permuted" => 29,
+    "permutes" => 27,
+    "permuting" => 24,
+    "pernicious" => 24,
+    "perpendicular" => 29,
+    "perpendicularity" => 24,
+    "perpendicularly" => 24,
+    "perpetrate" => 24,
+    "perpetrated" => 27,
+    "perpetrators" => 29,
+    "perpetual" => 35,
+    "perpetually" => 31,
+    "perplexed" => 33,
+    "perplexing" => 24,
+    "perplexity" => 32,
+    "persecuted" => 27,
+    "persecution" => 27,
+    "persecutions" => 27,
+    "persecutor" => 29,
+    "persecutors" => 24,
+    "perseus" => 43,
+    "perseverance" => 27,
+    "persevere" => 24,
+    "persevering" => 27,
+    "persian" => 33,
+    "persians" => 24,
+    "persist" => 38,
+    "persisted" => 35,
+    "persistence" => 38,
+    "persistency" => 29,
+    "persistent" => 42,
+    "persistently" => 32,
+    "persisting" => 29,
+    "persists" => 34,
+    "person" => 47,
+    "personage" => 32,
+    "personages" => 24,
+    "personal" => 43,
+    "personalities" => 27,
+    "personality" => 32,
+    "personally" => 37,
+    "personate" => 24,
+    "personating" => 27,
+    "personified" => 24,
+    "persons" => 40,
+    "perspective" => 40,
+    "perspiration" => 27,
+    "perspired" => 27,
+    "perspiring" => 24,
+    "persuade" => 36,
+    "persuaded" => 35,
+    "persuades" => 24,
+    "persuading" => 29,
+    "persuasion" => 27,
+    "persuasions" => 30,
+    "persuasive" => 24,
+    "persuasively" => 24,
+    "pert" => 27,
+    "pertain" => 27,
+    "pertaining" => 34,
+    "perth" => 29,
+    "pertinacity" => 24,
+    "pertinent" => 24,
+    "perturbation" => 24,
+    "perturbations" => 27,
+    "perturbed" => 31,
+    "perturbs" => 24,
+    "peru" => 27,
+    "perusal" => 27,
+    "peruse" => 24,
+    "perused" => 27,
+    "pervaded" => 27,
+    "pervading" => 31,
+    "pervasive" => 37,
+    "pervasively" => 27,
+    "perversity" => 24,
+    "peseta" => 24,
+    "pesky" => 24,
+    "pessimist" => 24,
+    "pessimistic" => 29,
+    "pessimistically" => 24,
+    "pest" => 34,
+    "pestered" => 24,
+    "pestering" => 27,
+    "pet" => 34,
+    "petals" => 24,
+    "pete" => 24,
+    "peter" => 41,
+    "petered" => 24,
+    "peters" => 39,
+    "petersburg" => 32,
+    "petersfield" => 24,
+    "peterson" => 35,
+    "petition" => 30,
+    "petri" => 27,
+    "petrified" => 27,
+    "petroleum" => 24,
+    "pets" => 24,
+    "petted" => 24,
+    "petticoat" => 24,
+    "petting" => 24,
+    "pettishly" => 24,
+    "petty" => 29,
+    "petulance" => 24,
+    "pew" => 34,
+    "pews" => 27,
+    "pewter" => 24,
+    "phantom" => 37,
+    "pharmacological" => 24,
+    "phase" => 42,
+    "phased" => 32,
+    "phases" => 36,
+    "phd" => 24,
+    "pheasant" => 24,
+    "phelps" => 24,
+    "phenomena" => 29,
+    "phenomenal" => 24,
+    "phenomenon" => 33,
+    "phew" => 27,
+    "phi" => 31,
+    "phil" => 30,
+    "philadelphia" => 29,
+    "philanthropist" => 24,
+    "philip" => 29,
+    "philippines" => 24,
+    "philological" => 24,
+    "philosopher" => 30,
+    "philosophers" => 32,
+    "philosophical" => 27,
+    "philosophically" => 27,
+    "philosophies" => 24,
+    "philosophy" => 41,
+    "phlegm" => 24,
+    "phlegmatic" => 30,
+    "phone" => 42,
+    "phoned" => 24,
+    "phonemes" => 31,
+    "phonemic" => 27,
+    "phonemically" => 29,
+    "phones" => 24,
+    "phonetic" => 33,
+    "phonetically" => 27,
+    "phonetics" => 27,
+    "phonograph" => 24,
+    "phonology" => 32,
+    "phosphate" => 24,
+    "phosphor" => 32,
+    "phosphorescent" => 24,
+    "phosphorus" => 24,
+    "photo" => 32,
+    "photocopier" => 24,
+    "photocopies" => 30,
+    "photocopy" => 35,
+    "photocopying" => 29,
+    "photograph" => 40,
+    "photographed" => 24,
+    "photographer" => 27,
+    "photographers" => 31,
+    "photographic" => 34,
+    "photographs" => 36,
+    "photography" => 30,
+    "photometric" => 24,
+    "photon" => 39,
+    "phrase" => 38,
+    "phrased" => 24,
+    "phraseology" => 24,
+    "phrases" => 36,
+    "phrasing" => 24,
+    "phrasings" => 24,
+    "phrenologist" => 24,
+    "physical" => 44,
+    "physically" => 34,
+    "physician" => 27,
+    "physicians" => 24,
+    "physics" => 34,
+    "physiognomy" => 27,
+    "physiology" => 24,
+    "piano" => 32,
+    "pianos" => 24,
+    "piccolos" => 24,
+    "pick" => 45,
+    "picked" => 42,
+    "picker" => 32,
+    "pickers" => 24,
+    "picking" => 40,
+    "pickle" => 32,
+    "picks" => 38,
+    "picky" => 30,
+    "picnic" => 33,
+    "picnickers" => 24,
+    "picnics" => 24,
+    "pictorial" => 27,
+    "picture" => 42,
+    "pictured" => 32,
+    "pictures" => 40,
+    "picturesque" => 34,
+    "picturing" => 27,
+    "pie" => 36,
+    "piece" => 46,
+    "pieces" => 45,
+    "pier" => 29,
+    "pierce" => 32,
+    "pierced" => 33,
+    "piercing" => 27,
+    "pierre" => 32,
+    "pig" => 35,
+    "pigeon" => 35,
+    "pigeons" => 24,
+    "piggyback" => 24,
+    "pigments" => 24,
+    "pigs" => 32,
+    "pigsty" => 27,
+    "pike" => 30,
+    "pikestaff" => 24,
+    "pile" => 36,
+    "piled" => 29,
+    "piles" => 27,
+    "pilgrimage" => 31,
+    "pilgrims" => 27,
+    "piling" => 24,
+    "pillage" => 29,
+    "pillaged" => 24,
+    "pillaging" => 24,
+    "pillared" => 24,
+    "pillars" => 30,
+    "pilled" => 27,
+    "pilling" => 32,
+    "pillow" => 30,
+    "pillows" => 29,
+    "pills" => 24,
+    "pilot" => 39,
+    "pin" => 46,
+    "pina" => 27,
+    "pincers" => 24,
+    "pinch" => 32,
+    "pinched" => 30,
+    "pine" => 32,
+    "pines" => 30,
+    "ping" => 39,
+    "pinging" => 24,
+    "pings" => 30,
+    "pink" => 35,
+    "pinnacle" => 24,
+    "pinnacles" => 24,
+    "pinned" => 43,
+    "pinning" => 43,
+    "pinpoint" => 32,
+    "pins" => 38,
+    "piny" => 27,
+    "pinyin" => 32,
+    "pioneer" => 29,
+    "pioneered" => 27,
+    "pioneering" => 24,
+    "pioneers" => 24,
+    "pious" => 29,
+    "pip" => 32,
+    "pipe" => 47,
+    "piped" => 38,
+    "pipeline" => 44,
+    "pipelines" => 42,
+    "piper" => 27,
+    "pipes" => 45,
+    "pipette" => 24,
+    "piping" => 37,
+    "pips" => 35,
+    "piquant" => 24,
+    "piqued" => 27,
+    "piracies" => 29,
+    "piracy" => 24,
+    "pirate" => 36,
+    "pirates" => 36,
+    "piratical" => 24,
+    "pirating" => 30,
+    "pistol" => 35,
+    "pistols" => 31,
+    "piston" => 29,
+    "pit" => 32,
+    "pitch" => 36,
+    "pitched" => 24,
+    "pitches" => 27,
+    "pitching" => 24,
+    "pitchy" => 24,
+    "piteous" => 29,
+    "pitfall" => 29,
+    "pitfalls" => 37,
+    "pitiable" => 31,
+    "pitied" => 29,
+    "pities" => 24,
+    "pitiful" => 31,
+    "pitiless" => 24,
+    "pitilessly" => 24,
+    "pits" => 24,
+    "pitt" => 24,
+    "pittance" => 30,
+    "pittsburgh" => 29,
+    "pity" => 39,
+    "pityingly" => 24,
+    "pivot" => 36,
+    "pivotal" => 24,
+    "pixel" => 43,
+    "pixels" => 42,
+    "placard" => 29,
+    "place" => 55,
+    "placebo" => 24,
+    "placed" => 48,
+    "placeholder" => 44,
+    "placement" => 36,
+    "places" => 49,
+    "placid" => 33,
+    "placidly" => 24,
+    "placing" => 42,
+    "plagued" => 24,
+    "plaid" => 27,
+    "plaids" => 24,
+    "plain" => 47,
+    "plainer" => 27,
+    "plainest" => 24,
+    "plainly" => 38,
+    "plains" => 35,
+    "plaintively" => 24,
+    "plaited" => 30,
+    "plan" => 45,
+    "planar" => 27,
+    "plane" => 37,
+    "planes" => 32,
+    "planet" => 35,
+    "planetary" => 24,
+    "plank" => 32,
+    "planked" => 24,
+    "planking" => 27,
+    "planks" => 29,
+    "planned" => 44,
+    "planner" => 24,
+    "planning" => 40,
+    "plans" => 43,
+    "plant" => 29,
+    "plantagenet" => 24,
+    "plantation" => 32,
+    "plantations" => 29,
+    "planted" => 29,
+    "planter" => 24,
+    "plants" => 32,
+    "plasma" => 29,
+    "plaster" => 27,
+    "plastered" => 24,
+    "plasters" => 24,
+    "plat" => 24,
+    "plate" => 38,
+    "platen" => 24,
+    "plates" => 33,
+    "platform" => 55,
+    "platforms" => 54,
+    "platinum" => 32,
+    "platitudes" => 24,
+    "platonic" => 24,
+    "platte" => 33,
+    "platter" => 24,
+    "platters" => 27,
+    "plausible" => 33,
+    "plausibly" => 27,
+    "play" => 45,
+    "playable" => 27,
+    "playback" => 37,
+    "played" => 39,
+    "player" => 37,
+    "players" => 35,
+    "playfellow" => 24,
+    "playfellows" => 24,
+    "playfully" => 29,
+    "playground" => 41,
+    "playing" => 41,
+    "playmate" => 27,
+    "playmates" => 24,
+    "playpen" => 24,
+    "plays" => 37,
+    "plaything" => 27,
+    "playthings" => 24,
+    "plaza" => 30,
+    "plea" => 24,
+    "plead" => 27,
+    "pleaded" => 33,
+    "pleading" => 24,
+    "pleadingly" => 24,
+    "pleas" => 24,
+    "pleasant" => 40,
+    "pleasanter" => 27,
+    "pleasantest" => 24,
+    "pleasantly" => 30,
+    "please" => 56,
+    "pleased" => 39,
+    "pleases" => 32,
+    "pleasing" => 31,
+    "pleasurable" => 30,
+    "pleasure" => 41,
+    "pleasures" => 31,
+    "plebs" => 24,
+    "pledge" => 31,
+    "pledged" => 27,
+    "plentiful" => 32,
+    "plentifully" => 27,
+    "plenty" => 39,
+    "plethora" => 29,
+    "pliant" => 24,
+    "plied" => 27,
+    "plink" => 24,
+    "plonk" => 27,
+    "plosives" => 24,
+    "plot" => 36,
+    "plots" => 32,
+    "plotters" => 38,
+    "plotting" => 32,
+    "ploughed" => 29,
+    "ploughing" => 24,
+    "pluck" => 30,
+    "plucked" => 29,
+    "plucking" => 27,
+    "plug" => 41,
+    "pluggable" => 37,
+    "plugged" => 31,
+    "plugin" => 53,
+    "plugs" => 27,
+    "plum" => 32,
+    "plumb" => 24,
+    "plumber" => 30,
+    "plumbing" => 38,
+    "plumed" => 24,
+    "plumes" => 24,
+    "plump" => 24,
+    "plumped" => 27,
+    "plunder" => 29,
+    "plundering" => 24,
+    "plunge" => 31,
+    "plunged" => 36,
+    "plunges" => 27,
+    "plunging" => 32,
+    "plural" => 40,
+    "pluralize" => 27,
+    "plurals" => 30,
+    "plus" => 47,
+    "plush" => 29,
+    "plutarch" => 27,
+    "ply" => 27,
+    "plying" => 27,
+    "pneumatic" => 24,
+    "pocked" => 24,
+    "pocket" => 42,
+    "pocketed" => 24,
+    "pocketing" => 24,
+    "pockets" => 37,
+    "pod" => 38,
+    "poem" => 38,
+    "poems" => 24,
+    "poet" => 27,
+    "poetic" => 24,
+    "poetical" => 24,
+    "poetry" => 32,
+    "poets" => 30,
+    "poignant" => 29,
+    "poignantly" => 24,
+    "point" => 60,
+    "pointed" => 45,
+    "pointer" => 58,
+    "pointers" => 52,
+    "pointing" => 47,
+    "pointless" => 33,
+    "pointlessly" => 29,
+    "points" => 51,
+    "poise" => 33,
+    "poised" => 27,
+    "poison" => 37,
+    "poisoned" => 43,
+    "poisoner" => 24,
+    "poisoning" => 40,
+    "poisons" => 29,
+    "poisson" => 24,
+    "poke" => 31,
+    "poked" => 24,
+    "poker" => 30,
+    "pokers" => 24,
+    "poking" => 29,
+    "poky" => 27,
+    "poland" => 24,
+    "polar" => 27,
+    "polarity" => 29,
+    "pole" => 32,
+    "poles" => 30,
+    "police" => 43,
+    "policeman" => 38,
+    "policemen" => 24,
+    "policies" => 43,
+    "policy" => 50,
+    "polish" => 37,
+    "polished" => 33,
+    "polisher" => 24,
+    "polishing" => 29,
+    "polite" => 35,
+    "politely" => 37,
+    "politeness" => 27,
+    "politer" => 24,
+    "politic" => 24,
+    "political" => 35,
+    "politician" => 27,
+    "politicians" => 24,
+    "politics" => 35,
+    "polity" => 24,
+    "poll" => 41,
+    "polled" => 40,
+    "polling" => 41,
+    "polls" => 37,
+    "pollster" => 30,
+    "pollute" => 32,
+    "polluted" => 27,
+    "pollutes" => 29,
+    "polluting" => 33,
+    "pollution" => 30,
+    "polly" => 40,
+    "polo" => 32,
+    "poly" => 39,
+    "polygamists" => 24,
+    "polygamy" => 29,
+    "polyglot" => 34,
+    "polygon" => 40,
+    "polygonal" => 24,
+    "polygons" => 36,
+    "polyhedron" => 24,
+    "polymorphic" => 34,
+    "polymorphism" => 35,
+    "polynomial" => 40,
+    "polynomials" => 39,
+    "pom" => 29,
+    "pomelo" => 27,
+    "pommel" => 24,
+    "pomp" => 29,
+    "pompadour" => 27,
+    "pomposity" => 24,
+    "pompous" => 24,
+    "pond" => 24,
+    "pondered" => 24,
+    "pondering" => 27,
+    "ponderous" => 27,
+    "ponderously" => 24,
+    "pong" => 31,
+    "ponies" => 29,
+    "pony" => 24,
+    "poodle" => 27,
+    "poodles" => 24,
+    "pooh" => 29,
+    "pool" => 49,
+    "pooled" => 33,
+    "pooling" => 37,
+    "poolroom" => 24,
+    "poolrooms" => 24,
+    "pools" => 39,
+    "poon" => 24,
+    "poop" => 29,
+    "poor" => 47,
+    "poorer" => 24,
+    "poorly" => 34,
+    "pop" => 43,
+    "pope" => 37,
+    "popped" => 34,
+    "popping" => 35,
+    "pops" => 33,
+    "popular" => 45,
+    "popularised" => 24,
+    "popularity" => 30,
+    "popularized" => 27,
+    "populate" => 35,
+    "populated" => 38,
+    "populates" => 34,
+    "populating" => 29,
+    "population" => 37,
+    "populations" => 24,
+    "populous" => 29,
+    "popup" => 54,
+    "porcelain" => 31,
+    "porch" => 36,
+    "porches" => 24,
+    "pore" => 29,
+    "pores" => 24,
+    "pork" => 24,
+    "porphyry" => 24,
+    "porpoise" => 30,
+    "port" => 51,
+    "portability" => 43,
+    "portable" => 47,
+    "portal" => 38,
+    "portals" => 32,
+    "ported" => 41,
+    "portend" => 24,
+    "portentous" => 29,
+    "porter" => 32,
+    "porters" => 29,
+    "portfolio" => 30,
+    "portico" => 24,
+    "porting" => 39,
+    "portion" => 45,
+    "portions" => 41,
+    "portly" => 30,
+    "portmanteau" => 31,
+    "portrait" => 34,
+    "portrayed" => 24,
+    "portrays" => 24,
+    "ports" => 43,
+    "portugal" => 27,
+    "portuguese" => 35,
+    "pos" => 44,
+    "pose" => 30,
+    "posed" => 32,
+    "poseidon" => 34,
+    "poses" => 32,
+    "posh" => 24,
+    "posited" => 24,
+    "position" => 54,
+    "positional" => 42,
+    "positioned" => 41,
+    "positioning" => 40,
+    "positions" => 48,
+    "positive" => 46,
+    "positively" => 33,
+    "positives" => 36,
+    "posse" => 24,
+    "possess" => 37,
+    "possessed" => 41,
+    "possesses" => 27,
+    "possessing" => 29,
+    "possession" => 41,
+    "possessions" => 31,
+    "possessive" => 33,
+    "possessively" => 24,
+    "possibilities" => 39,
+    "possibility" => 45,
+    "possible" => 58,
+    "possibly" => 49,
+    "post" => 47,
+    "postage" => 35,
+    "postal" => 24,
+    "postcard" => 36,
+    "postcards" => 24,
+    "postdoctoral" => 24,
+    "posted" => 33,
+    "posterior" => 24,
+    "posterity" => 27,
+    "postern" => 24,
+    "posters" => 24,
+    "posting" => 33,
+    "postman" => 24,
+    "postmark" => 30,
+    "postmarks" => 24,
+    "postmaster" => 24,
+    "postmortem" => 30,
+    "postpone" => 38,
+    "postponed" => 38,
+    "postpones" => 27,
+    "postponing" => 30,
+    "posts" => 39,
+    "postscript" => 43,
+    "posture" => 29,
+    "pot" => 32,
+    "potatoes" => 24,
+    "potent" => 27,
+    "potential" => 46,
+    "potentially" => 47,
+    "pots" => 29,
+    "potter" => 40,
+    "potter's" => 34,
+    "pottery" => 24,
+    "poultry" => 24,
+    "pounce" => 24,
+    "pounced" => 24,
+    "pound" => 37,
+    "pounding" => 30,
+    "pounds" => 45,
+    "pour" => 29,
+    "poured" => 37,
+    "pouring" => 33,
+    "pours" => 24,
+    "poverty" => 35,
+    "pow" => 35,
+    "powder" => 30,
+    "powdered" => 30,
+    "powdering" => 24,
+    "powdery" => 24,
+    "power" => 48,
+    "powered" => 39,
+    "powerful" => 46,
+    "powerfulness" => 24,
+    "powering" => 30,
+    "powerless" => 27,
+    "powers" => 41,
+    "powwow" => 24,
+    "ppm" => 24,
+    "practicable" => 29,
+    "practical" => 43,
+    "practicalities" => 24,
+    "practicality" => 24,
+    "practically" => 38,
+    "practice" => 46,
+    "practiced" => 24,
+    "practices" => 41,
+    "practise" => 29,
+    "practised" => 29,
+    "practitioners" => 24,
+    "pragmatic" => 32,
+    "pragmatically" => 24,
+    "pragmatics" => 24,
+    "prague" => 24,
+    "prairie" => 27,
+    "prairies" => 31,
+    "praise" => 30,
+    "praised" => 27,
+    "praises" => 24,
+    "praising" => 24,
+    "prance" => 24,
+    "pranced" => 24,
+    "prancing" => 27,
+    "prank" => 27,
+    "pratt" => 33,
+    "prattle" => 24,
+    "pray" => 41,
+    "prayed" => 32,
+    "prayer" => 34,
+    "prayers" => 31,
+    "praying" => 24,
+    "pre" => 50,
+    "preach" => 27,
+    "preached" => 24,
+    "preacher" => 24,
+    "preachers" => 24,
+    "preallocated" => 30,
+    "preamble" => 29,
+    "preambles" => 24,
+    "prearranged" => 30,
+    "precarious" => 29,
+    "precaution" => 34,
+    "precautions" => 33,
+    "precede" => 38,
+    "preceded" => 41,
+    "precedence" => 46,
+    "precedent" => 33,
+    "precedes" => 38,
+    "preceding" => 45,
+    "preceptors" => 24,
+    "precepts" => 24,
+    "precious" => 39,
+    "precipice" => 29,
+    "precipices" => 33,
+    "precipitance" => 24,
+    "precipitate" => 30,
+    "precipitated" => 27,
+    "precipitately" => 24,
+    "precipitation" => 24,
+    "precipitous" => 31,
+    "precipitously" => 24,
+    "precise" => 45,
+    "precisely" => 43,
+    "preciseness" => 24,
+    "precision" => 58,
+    "preclude" => 24,
+    "precluded" => 31,
+    "precludes" => 29,
+    "precomputed" => 31,
+    "precondition" => 33,
+    "preconditions" => 37,
+    "precursor" => 31,
+    "predate" => 30,
+    "predates" => 32,
+    "predecessor" => 35,
+    "predecessors" => 27,
+    "predefined" => 41,
+    "predestined" => 24,
+    "predetermined" => 30,
+    "predicate" => 46,
+    "predicated" => 27,
+    "predicates" => 40,
+    "predication" => 29,
+    "predict" => 36,
+    "predictability" => 30,
+    "predictable" => 39,
+    "predictably" => 24,
+    "predicted" => 34,
+    "prediction" => 33,
+    "predictions" => 29,
+    "predictive" => 24,
+    "predictor" => 30,
+    "predicts" => 24,
+    "predilection" => 24,
+    "predominant" => 24,
+    "predominated" => 24,
+    "predominates" => 24,
+    "preempt" => 29,
+    "preempted" => 24,
+    "preemption" => 34,
+    "preemptive" => 29,
+    "prefab" => 29,
+    "prefabs" => 24,
+    "preface" => 27,
+    "prefaced" => 27,
+    "prefatory" => 24,
+    "prefer" => 48,
+    "preferable" => 40,
+    "preferably" => 36,
+    "preference" => 41,
+    "preferences" => 38,
+    "preferentially" => 29,
+    "preferred" => 47,
+    "preferring" => 34,
+    "prefers" => 36,
+    "prefix" => 51,
+    "prefixed" => 45,
+    "prefixes" => 44,
+    "prefixing" => 37,
+    "preflight" => 27,
+    "pregnant" => 24,
+    "prehistoric" => 30,
+    "prejudice" => 31,
+    "prejudiced" => 27,
+    "prejudices" => 27,
+    "preliminaries" => 24,
+    "preliminary" => 35,
+    "prelude" => 48,
+    "preludes" => 43,
+    "premature" => 34,
+    "prematurely" => 34,
+    "premise" => 27,
+    "premises" => 35,
+    "premium" => 32,
+    "preoccupation" => 24,
+    "preoccupied" => 27,
+    "prep" => 33,
+    "preparation" => 38,
+    "preparations" => 34,
+    "preparatory" => 27,
+    "prepare" => 42,
+    "prepared" => 42,
+    "prepares" => 27,
+    "preparing" => 40,
+    "preposition" => 29,
+    "prepositions" => 29,
+    "prepossess" => 24,
+    "preposterous" => 31,
+    "preprocessor" => 41,
+    "prerequisite" => 32,
+    "prerequisites" => 38,
+    "presbyterian" => 24,
+    "presbyterians" => 24,
+    "prescribe" => 29,
+    "prescribed" => 33,
+    "prescription" => 24,
+    "prescriptive" => 27,
+    "presence" => 46,
+    "present" => 52,
+    "presentable" => 27,
+    "presentation" => 43,
+    "presentations" => 35,
+    "presented" => 43,
+    "presenters" => 32,
+    "presentiment" => 32,
+    "presenting" => 32,
+    "presently" => 45,
+    "presents" => 38,
+    "preservation" => 44,
+    "preserve" => 45,
+    "preserved" => 44,
+    "preserver" => 30,
+    "preserves" => 41,
+    "preserving" => 43,
+    "preset" => 34,
+    "presets" => 30,
+    "presetting" => 24,
+    "preside" => 27,
+    "presidency" => 24,
+    "president" => 45,
+    "press" => 47,
+    "pressed" => 43,
+    "presses" => 35,
+    "pressing" => 46,
+    "pressure" => 37,
+    "pressured" => 24,
+    "pressurized" => 24,
+    "prestige" => 30,
+    "prestigious" => 24,
+    "preston" => 24,
+    "presumably" => 37,
+    "presume" => 35,
+    "presumed" => 30,
+    "presuming" => 27,
+    "presumption" => 32,
+    "presumptions" => 24,
+    "presumptuous" => 27,
+    "pretence" => 31,
+    "pretend" => 37,
+    "pretended" => 33,
+    "pretending" => 33,
+    "pretends" => 29,
+    "pretenses" => 24,
+    "pretension" => 24,
+    "pretensions" => 24,
+    "pretext" => 31,
+    "pretexts" => 24,
+    "prettier" => 31,
+    "prettiest" => 24,
+    "prettified" => 27,
+    "prettifying" => 24,
+    "prettily" => 24,
+    "pretty" => 49,
+    "prevail" => 30,
+    "prevailed" => 27,
+    "prevailing" => 24,
+    "prevails" => 24,
+    "prevalent" => 32,
+    "prevent" => 50,
+    "prevented" => 38,
+    "preventing" => 42,
+    "prevention" => 33,
+    "prevents" => 46,
+    "preview" => 46,
+    "previewed" => 24,
+    "previewing" => 24,
+    "previews" => 27,
+    "previous" => 54,
+    "previously" => 50,
+    "prey" => 33,
+    "preyed" => 31,
+    "price" => 43,
+    "priceless" => 27,
+    "prices" => 37,
+    "pricing" => 33,
+    "prick" => 27,
+    "pricked" => 29,
+    "prickly" => 24,
+    "pride" => 38,
+    "prided" => 24,
+    "pried" => 27,
+    "priest" => 30,
+    "priests" => 37,
+    "prig" => 24,
+    "prima" => 27,
+    "primaries" => 29,
+    "primarily" => 47,
+    "primary" => 49,
+    "prime" => 43,
+    "primer" => 32,
+    "primes" => 34,
+    "primitive" => 50,
+    "primitives" => 48,
+    "primp" => 24,
+    "prince" => 31,
+    "princes" => 24,
+    "princess" => 24,
+    "princeton" => 30,
+    "principal" => 40,
+    "principally" => 34,
+    "principals" => 24,
+    "principle" => 39,
+    "principled" => 29,
+    "principles" => 41,
+    "print" => 52,
+    "printable" => 42,
+    "printed" => 48,
+    "printer" => 42,
+    "printers" => 37,
+    "printing" => 49,
+    "printout" => 32,
+    "printouts" => 27,
+    "prints" => 48,
+    "prior" => 48,
+    "priori" => 29,
+    "priorities" => 37,
+    "prioritise" => 24,
+    "prioritised" => 24,
+    "prioritises" => 29,
+    "prioritising" => 24,
+    "prioritization" => 30,
+    "prioritize" => 35,
+    "prioritized" => 32,
+    "prioritizes" => 33,
+    "prioritizing" => 30,
+    "priority" => 47,
+    "prisms" => 24,
+    "prison" => 39,
+    "prisoner" => 37,
+    "prisoners" => 34,
+    "pristine" => 24,
+    "pritchard" => 24,
+    "privacy" => 40,
+    "private" => 50,
+    "privately" => 35,
+    "privilege" => 35,
+    "privileged" => 37,
+    "privileges" => 37,
+    "privy" => 24,
+    "prize" => 36,
+    "prized" => 27,
+    "prizes" => 33,
+    "pro" => 54,
+    "proactive" => 32,
+    "proactively" => 31,
+    "probabilistic" => 32,
+    "probabilistically" => 24,
+    "probabilities" => 35,
+    "probability" => 40,
+    "probable" => 38,
+    "probably" => 50,
+    "probe" => 41,
+    "probed" => 30,
+    "probes" => 35,
+    "probing" => 37,
+    "probity" => 24,
+    "problem" => 66,
+    "problematic" => 39,
+    "problematical" => 29,
+    "problems" => 52,
+    "procedural" => 49,
+    "procedure" => 40,
+    "procedures" => 39,
+    "proceed" => 43,
+    "proceeded" => 41,
+    "proceeding" => 35,
+    "proceedings" => 37,
+    "proceeds" => 35,
+    "process" => 56,
+    "processed" => 44,
+    "processes" => 47,
+    "processing" => 49,
+    "procession" => 37,
+    "processions" => 29,
+    "processor" => 45,
+    "processors" => 44,
+    "proclaim" => 27,
+    "proclaimed" => 27,
+    "proclaiming" => 24,
+    "proclamation" => 24,
+    "procrastinate" => 24,
+    "proctor" => 37,
+    "procure" => 34,
+    "procured" => 35,
+    "procurement" => 24,
+    "prod" => 24,
+    "prodigal" => 24,
+    "prodigality" => 24,
+    "prodigious" => 29,
+    "prodigiously" => 27,
+    "prodigy" => 27,
+    "produce" => 53,
+    "produced" => 48,
+    "producer" => 43,
+    "producers" => 38,
+    "produces" => 48,
+    "producing" => 48,
+    "product" => 46,
+    "production" => 47,
+    "productions" => 34,
+    "productive" => 35,
+    "productively" => 24,
+    "productivity" => 34,
+    "products" => 41,
+    "prof" => 36,
+    "profane" => 24,
+    "profaned" => 24,
+    "profanity" => 24,
+    "professing" => 24,
+    "profession" => 37,
+    "professional" => 38,
+    "professionally" => 29,
+    "professionals" => 31,
+    "professions" => 29,
+    "professor" => 35,
+    "professors" => 33,
+    "professorship" => 29,
+    "proffered" => 27,
+    "proficiency" => 29,
+    "proficient" => 30,
+    "profile" => 49,
+    "profiled" => 32,
+    "profiler" => 42,
+    "profilers" => 34,
+    "profiles" => 44,
+    "profiling" => 46,
+    "profit" => 33,
+    "profitable" => 24,
+    "profitably" => 24,
+    "profited" => 30,
+    "profiting" => 27,
+    "profits" => 34,
+    "profound" => 36,
+    "profoundly" => 32,
+    "profundity" => 24,
+    "profusion" => 24,
+    "progeny" => 24,
+    "prognosis" => 29,
+    "prognosticate" => 24,
+    "prognosticated" => 24,
+    "program" => 56,
+    "programmable" => 34,
+    "programmatic" => 37,
+    "programme" => 39,
+    "programmed" => 29,
+    "programmer" => 43,
+    "programmers" => 41,
+    "programmes" => 27,
+    "programming" => 50,
+    "programs" => 52,
+    "progress" => 49,
+    "progresses" => 27,
+    "progressing" => 30,
+    "progression" => 29,
+    "progressive" => 31,
+    "progressively" => 33,
+    "prohibit" => 33,
+    "prohibited" => 35,
+    "prohibiting" => 24,
+    "prohibitions" => 24,
+    "prohibitive" => 27,
+    "prohibitively" => 29,
+    "prohibits" => 27,
+    "project" => 59,
+    "projected" => 30,
+    "projecting" => 33,
+    "projection" => 40,
+    "projections" => 35,
+    "projective" => 29,
+    "projectors" => 24,
+    "projects" => 51,
+    "proliferated" => 24,
+    "proliferating" => 24,
+    "proliferation" => 29,
+    "prologue" => 36,
+    "prologues" => 24,
+    "prolong" => 32,
+    "prolonged" => 33,
+    "prolonging" => 24,
+    "promenade" => 24,
+    "promenading" => 29,
+    "prometheus" => 42,
+    "prominence" => 29,
+    "prominent" => 31,
+    "prominently" => 29,
+    "promise" => 50,
+    "promised" => 40,
+    "promises" => 43,
+    "promising" => 36,
+    "promontory" => 31,
+    "promote" => 34,
+    "promoted" => 37,
+    "promoter" => 24,
+    "promotes" => 30,
+    "promoting" => 31,
+    "promotion" => 36,
+    "promotions" => 27,
+    "prompt" => 50,
+    "prompted" => 35,
+    "prompting" => 32,
+    "promptly" => 32,
+    "prompts" => 37,
+    "prone" => 41,
+    "pronoun" => 31,
+    "pronounce" => 32,
+    "pronounced" => 39,
+    "pronounces" => 24,
+    "pronouncing" => 29,
+    "pronouns" => 36,
+    "pronunciation" => 37,
+    "pronunciations" => 27,
+    "proof" => 45,
+    "proofing" => 27,
+    "proofreading" => 24,
+    "proofs" => 42,
+    "prop" => 42,
+    "propaganda" => 27,
+    "propagate" => 39,
+    "propagated" => 42,
+    "propagates" => 33,
+    "propagating" => 38,
+    "propagation" => 40,
+    "propagator" => 24,
+    "propagators" => 29,
+    "propel" => 24,
+    "propensity" => 29,
+    "proper" => 47,
+    "properly" => 53,
+    "properties" => 52,
+    "property" => 53,
+    "prophecies" => 24,
+    "prophecy" => 29,
+    "prophesied" => 24,
+    "prophesying" => 24,
+    "prophet" => 30,
+    "prophetic" => 27,
+    "prophets" => 24,
+    "propitiating" => 24,
+    "propitious" => 30,
+    "proponents" => 32,
+    "proportion" => 36,
+    "proportional" => 36,
+    "proportionally" => 27,
+    "proportionate" => 24,
+    "proportions" => 30,
+    "proposal" => 43,
+    "proposals" => 39,
+    "propose" => 38,
+    "proposed" => 43,
+    "proposes" => 27,
+    "proposing" => 36,
+    "proposition" => 30,
+    "propound" => 24,
+    "propped" => 29,
+    "proprietary" => 36,
+    "proprietor" => 33,
+    "propriety" => 27,
+    "props" => 40,
+    "pros" => 38,
+    "prose" => 33,
+    "prosecute" => 24,
+    "prosecuted" => 24,
+    "prosecution" => 32,
+    "prosecutions" => 24,
+    "proselytes" => 24,
+    "prospect" => 37,
+    "prospecting" => 24,
+    "prospective" => 31,
+    "prospects" => 34,
+    "prosper" => 24,
+    "prosperity" => 30,
+    "prosperous" => 30,
+    "prosperously" => 24,
+    "prostrate" => 24,
+    "prostrated" => 24,
+    "prostration" => 24,
+    "prosy" => 24,
+    "protect" => 42,
+    "protected" => 41,
+    "protecting" => 35,
+    "protection" => 47,
+    "protections" => 31,
+    "protective" => 29,
+    "protector" => 34,
+    "protectors" => 35,
+    "protects" => 39,
+    "protest" => 27,
+    "protestation" => 24,
+    "protestations" => 24,
+    "protested" => 30,
+    "protesting" => 24,
+    "protocol" => 53,
+    "protocols" => 46,
+    "prototypal" => 24,
+    "prototype" => 44,
+    "prototypes" => 40,
+    "prototypical" => 27,
+    "protracted" => 27,
+    "protraction" => 24,
+    "protruded" => 29,
+    "protruding" => 33,
+    "proud" => 36,
+    "prouder" => 24,
+    "proudest" => 29,
+    "proudly" => 27,
+    "provable" => 27,
+    "provably" => 30,
+    "prove" => 43,
+    "proved" => 39,
+    "proven" => 35,
+    "provenance" => 46,
+    "proves" => 36,
+    "provide" => 56,
+    "provided" => 57,
+    "providence" => 31,
+    "provider" => 47,
+    "providers" => 44,
+    "provides" => 58,
+    "providing" => 50,
+    "province" => 31,
+    "provinces" => 29,
+    "provincial" => 29,
+    "proving" => 38,
+    "provision" => 33,
+    "provisional" => 24,
+    "provisionally" => 24,
+    "provisioning" => 30,
+    "provisions" => 35,
+    "provocation" => 29,
+    "provoke" => 27,
+    "provoked" => 30,
+    "provoking" => 24,
+    "provost" => 27,
+    "prowler" => 24,
+    "proxies" => 38,
+    "proximity" => 29,
+    "proxy" => 44,
+    "prudence" => 29,
+    "prudent" => 35,
+    "prune" => 30,
+    "pruning" => 27,
+    "prussian" => 27,
+    "prying" => 24,
+    "psalm" => 24,
+    "pseudo" => 41,
+    "psi" => 27,
+    "psych" => 27,
+    "psyche" => 27,
+    "psychic" => 27,
+    "psycho" => 24,
+    "psychological" => 24,
+    "pta" => 24,
+    "pub" => 40,
+    "public" => 54,
+    "publication" => 39,
+    "publications" => 37,
+    "publicity" => 29,
+    "publicly" => 41,
+    "publish" => 47,
+    "publishable" => 24,
+    "published" => 48,
+    "publisher" => 37,
+    "publishers" => 39,
+    "publishes" => 33,
+    "publishing" => 46,
+    "pubs" => 27,
+    "puckered" => 24,
+    "pudding" => 24,
+    "puddle" => 24,
+    "puff" => 24,
+    "puffed" => 29,
+    "puffin" => 35,
+    "puffing" => 33,
+    "puffs" => 24,
+    "pugilistic" => 24,
+    "pull" => 52,
+    "pulled" => 43,
+    "pulley" => 33,
+    "pulling" => 40,
+    "pulls" => 39,
+    "pulp" => 27,
+    "pulpit" => 30,
+    "pulsar" => 24,
+    "pulsations" => 24,
+    "pulse" => 31,
+    "pulses" => 29,
+    "pulsing" => 24,
+    "pummelling" => 24,
+    "pump" => 34,
+    "pumped" => 24,
+    "pumps" => 24,
+    "pun" => 32,
+    "punch" => 31,
+    "punched" => 30,
+    "punches" => 27,
+    "punching" => 27,
+    "punctilious" => 24,
+    "punctual" => 27,
+    "punctuality" => 27,
+    "punctually" => 24,
+    "punctuate" => 24,
+    "punctuation" => 42,
+    "punctures" => 24,
+    "pungent" => 27,
+    "punish" => 32,
+    "punished" => 27,
+    "punishes" => 24,
+    "punishing" => 24,
+    "punishment" => 35,
+    "punishments" => 24,
+    "punjabi" => 24,
+    "punned" => 29,
+    "punning" => 30,
+    "puns" => 27,
+    "puny" => 24,
+    "pup" => 27,
+    "pupil" => 29,
+    "pupils" => 34,
+    "puppet" => 29,
+    "puppeteer" => 31,
+    "puppies" => 29,
+    "puppy" => 32,
+    "puppyish" => 24,
+    "purchase" => 35,
+    "purchased" => 34,
+    "purchases" => 32,
+    "purchasing" => 33,
+    "purdue" => 27,
+    "pure" => 50,
+    "purely" => 42,
+    "purest" => 29,
+    "purge" => 27,
+    "purges" => 24,
+    "purging" => 27,
+    "purify" => 27,
+    "purity" => 30,
+    "purloined" => 27,
+    "purple" => 34,
+    "purport" => 30,
+    "purpose" => 50,
+    "purposed" => 24,
+    "purposeful" => 24,
+    "purposefully" => 29,
+    "purposeless" => 29,
+    "purposely" => 30,
+    "purposes" => 47,
+    "purring" => 29,
+    "purse" => 29,
+    "purser" => 30,
+    "purses" => 29,
+    "pursuance" => 24,
+    "pursue" => 37,
+    "pursued" => 38,
+    "pursuers" => 24,
+    "pursues" => 24,
+    "pursuing" => 33,
+    "pursuit" => 37,
+    "pursuits" => 30,
+    "purveyor" => 24,
+    "purview" => 24,
+    "push" => 49,
+    "pushed" => 45,
+    "pusher" => 27,
+    "pushes" => 40,
+    "pushing" => 43,
+    "puss" => 24,
+    "put" => 55,
+    "puts" => 44,
+    "putted" => 27,
+    "putter" => 24,
+    "putting" => 47,
+    "putty" => 36,
+    "puzzle" => 31,
+    "puzzled" => 37,
+    "puzzlement" => 24,
+    "puzzles" => 24,
+    "puzzling" => 32,
+    "pwr" => 27,
+    "pyramid" => 33,
+    "pyramids" => 27,
+    "pyre" => 32,
+    "python" => 55,
+    "pyx" => 33,
+    "pâté" => 24,
+    "quack" => 29,
+    "quacks" => 24,
+    "quad" => 35,
+    "quadrant" => 29,
+    "quadratic" => 39,
+    "quadrille" => 30,
+    "quadrillion" => 24,
+    "quadruple" => 33,
+    "quadruplet" => 34,
+    "quadruplets" => 35,
+    "quads" => 29,
+    "quailed" => 24,
+    "quails" => 24,
+    "quaint" => 27,
+    "quake" => 35,
+    "quaked" => 29,
+    "quaking" => 27,
+    "qualification" => 30,
+    "qualifications" => 32,
+    "qualified" => 43,
+    "qualifier" => 38,
+    "qualifiers" => 43,
+    "qualifies" => 27,
+    "qualify" => 34,
+    "qualifying" => 27,
+    "qualitative" => 29,
+    "qualities" => 36,
+    "quality" => 48,
+    "quanta" => 32,
+    "quantification" => 29,
+    "quantifier" => 27,
+    "quantifiers" => 29,
+    "quantifies" => 24,
+    "quantify" => 29,
+    "quantitative" => 31,
+    "quantitatively" => 29,
+    "quantities" => 35,
+    "quantity" => 39,
+    "quantum" => 34,
+    "quarrel" => 33,
+    "quarrelled" => 27,
+    "quarrelling" => 31,
+    "quarrels" => 27,
+    "quarry" => 29,
+    "quart" => 24,
+    "quarter" => 42,
+    "quartered" => 24,
+    "quartering" => 29,
+    "quarters" => 37,
+    "quartet" => 24,
+    "quartets" => 24,
+    "quartz" => 32,
+    "quasi" => 35,
+    "quavering" => 27,
+    "quay" => 36,
+    "quays" => 32,
+    "quebec" => 24,
+    "queen" => 42,
+    "queen's" => 33,
+    "queens" => 27,
+    "queer" => 36,
+    "queerest" => 24,
+    "queerly" => 24,
+    "quelling" => 24,
+    "quench" => 24,
+    "quenched" => 29,
+    "quenching" => 24,
+    "quentin" => 31,
+    "queried" => 39,
+    "queries" => 45,
+    "query" => 50,
+    "querying" => 41,
+    "quest" => 34,
+    "question" => 49,
+    "questionable" => 31,
+    "questioned" => 32,
+    "questioning" => 35,
+    "questioningly" => 24,
+    "questions" => 48,
+    "queue" => 48,
+    "queued" => 40,
+    "queueing" => 24,
+    "queues" => 41,
+    "queuing" => 32,
+    "quick" => 49,
+    "quickened" => 27,
+    "quickening" => 24,
+    "quicker" => 37,
+    "quickest" => 24,
+    "quickly" => 48,
+    "quicksand" => 32,
+    "quicksilver" => 24,
+    "quiet" => 44,
+    "quieted" => 27,
+    "quieter" => 27,
+    "quieting" => 27,
+    "quietly" => 41,
+    "quietness" => 24,
+    "quill" => 27,
+    "quinsy" => 24,
+    "quintessential" => 24,
+    "quintillion" => 24,
+    "quirk" => 29,
+    "quirks" => 35,
+    "quit" => 45,
+    "quite" => 51,
+    "quits" => 31,
+    "quitted" => 38,
+    "quitting" => 38,
+    "quiver" => 29,
+    "quivered" => 31,
+    "quivering" => 31,
+    "quizzes" => 27,
+    "quorum" => 29,
+    "quota" => 36,
+    "quotas" => 24,
+    "quotation" => 39,
+    "quotations" => 27,
+    "quote" => 47,
+    "quoted" => 43,
+    "quotes" => 47,
+    "quotient" => 35,
+    "quoting" => 40,
+    "qwerty" => 27,
+    "rabbi" => 24,
+    "rabbit" => 41,
+    "rabbits" => 27,
+    "race" => 44,
+    "raced" => 29,
+    "racehorse" => 29,
+    "racer" => 24,
+    "races" => 42,
+    "rachel" => 38,
+    "racing" => 30,
+    "rack" => 37,
+    "racket" => 38,
+    "racking" => 24,
+    "racy" => 27,
+    "rad" => 33,
+    "radar" => 27,
+    "radial" => 24,
+    "radiance" => 30,
+    "radians" => 38,
+    "radiant" => 32,
+    "radiantly" => 24,
+    "radiate" => 24,
+    "radiated" => 24,
+    "radical" => 30,
+    "radically" => 31,
+    "radicals" => 24,
+    "radices" => 30,
+    "radio" => 37,
+    "radioactive" => 27,
+    "radios" => 32,
+    "radium" => 27,
+    "radius" => 32,
+    "radix" => 42,
+    "raf" => 32,
+    "raft" => 36,
+    "rafters" => 24,
+    "rafts" => 24,
+    "rag" => 29,
+    "rage" => 39,
+    "raged" => 27,
+    "rages" => 24,
+    "ragged" => 36,
+    "raging" => 30,
+    "rags" => 32,
+    "raid" => 27,
+    "rail" => 32,
+    "railed" => 27,
+    "railing" => 27,
+    "railings" => 27,
+    "raillery" => 24,
+    "railroad" => 46,
+    "railroading" => 24,
+    "railroads" => 27,
+    "rails" => 35,
+    "railway" => 40,
+    "railways" => 29,
+    "rain" => 42,
+    "rainbow" => 33,
+    "raincoat" => 24,
+    "rained" => 24,
+    "raining" => 29,
+    "rains" => 24,
+    "raise" => 45,
+    "raised" => 47,
+    "raises" => 41,
+    "raising" => 38,
+    "raison" => 24,
+    "rajah" => 34,
+    "rajahs" => 29,
+    "rake" => 24,
+    "raked" => 27,
+    "rakish" => 24,
+    "rallying" => 27,
+    "ralph" => 36,
+    "ralston" => 29,
+    "ram" => 43,
+    "ramadan" => 24,
+    "ramble" => 27,
+    "rambled" => 24,
+    "rambles" => 31,
+    "rambling" => 30,
+    "ramblings" => 24,
+    "ramifications" => 30,
+    "ramp" => 34,
+    "rampage" => 27,
+    "rampant" => 27,
+    "ran" => 45,
+    "ranches" => 24,
+    "rancor" => 30,
+    "rand" => 42,
+    "randall" => 32,
+    "random" => 50,
+    "randomising" => 24,
+    "randomization" => 31,
+    "randomize" => 27,
+    "randomized" => 36,
+    "randomizing" => 24,
+    "randomly" => 42,
+    "randomness" => 41,
+    "randy" => 24,
+    "rang" => 37,
+    "range" => 56,
+    "ranged" => 31,
+    "ranges" => 47,
+    "ranging" => 35,
+    "rangoon" => 37,
+    "rank" => 37,
+    "ranked" => 41,
+    "rankin" => 24,
+    "ranking" => 32,
+    "rankle" => 24,
+    "rankling" => 24,
+    "ranks" => 33,
+    "ransacked" => 31,
+    "ransom" => 27,
+    "rap" => 29,
+    "rapacity" => 24,
+    "raphael" => 31,
+    "rapid" => 40,
+    "rapidity" => 33,
+    "rapidly" => 41,
+    "rapids" => 33,
+    "rapier" => 31,
+    "rapine" => 24,
+    "rapped" => 30,
+    "rapt" => 27,
+    "rapture" => 32,
+    "rapturously" => 24,
+    "rare" => 44,
+    "rarely" => 42,
+    "rarer" => 27,
+    "rarity" => 27,
+    "ras" => 24,
+    "rascal" => 32,
+    "rascally" => 27,
+    "rascals" => 24,
+    "rash" => 34,
+    "rashers" => 24,
+    "rashly" => 31,
+    "rashness" => 24,
+    "raspberry" => 39,
+    "raspingly" => 24,
+    "raster" => 34,
+    "rat" => 34,
+    "ratchet" => 24,
+    "ratchets" => 29,
+    "rate" => 45,
+    "rated" => 24,
+    "rates" => 37,
+    "rather" => 54,
+    "ratification" => 27,
+    "ratified" => 30,
+    "ratifying" => 24,
+    "rating" => 29,
+    "ratings" => 32,
+    "ratio" => 40,
+    "rational" => 37,
+    "rationale" => 39,
+    "rationalist" => 24,
+    "rationalize" => 24,
+    "rationalized" => 24,
+    "ratios" => 32,
+    "rats" => 30,
+    "rattle" => 32,
+    "rattled" => 32,
+    "rattles" => 24,
+    "rattlesnake" => 24,
+    "rattling" => 32,
+    "ravages" => 27,
+    "raved" => 27,
+    "raven" => 27,
+    "ravenous" => 27,
+    "ravenously" => 27,
+    "ravens" => 27,
+    "ravine" => 29,
+    "ravines" => 24,
+    "raving" => 27,
+    "ravings" => 31,
+    "ravish" => 24,
+    "ravished" => 27,
+    "ravishing" => 24,
+    "raw" => 55,
+    "rawness" => 24,
+    "ray" => 36,
+    "rayleigh" => 24,
+    "raymond" => 34,
+    "rayon" => 43,
+    "rays" => 33,
+    "re" => 52,
+    "reabsorbed" => 24,
+    "reach" => 46,
+    "reachable" => 42,
+    "reached" => 47,
+    "reaches" => 40,
+    "reaching" => 40,
+    "react" => 38,
+    "reacted" => 24,
+    "reacting" => 31,
+    "reaction" => 35,
+    "reactions" => 24,
+    "reactivate" => 24,
+    "reactive" => 39,
+    "reactively" => 24,
+    "reactivity" => 35,
+    "reactor" => 37,
+    "reactors" => 27,
+    "read" => 58,
+    "readability" => 40,
+    "readable" => 49,
+    "reader" => 47,
+    "readers" => 43,
+    "readier" => 24,
+    "readies" => 24,
+    "readily" => 39,
+    "readiness" => 35,
+    "reading" => 53,
+    "readings" => 24,
+    "reads" => 50,
+    "ready" => 49,
+    "real" => 50,
+    "realise" => 32,
+    "realised" => 31,
+    "realising" => 29,
+    "realism" => 29,
+    "realistic" => 36,
+    "realistically" => 29,
+    "realities" => 29,
+    "reality" => 40,
+    "realization" => 27,
+    "realizations" => 24,
+    "realize" => 36,
+    "realized" => 37,
+    "realizes" => 24,
+    "realizing" => 30,
+    "reallocate" => 39,
+    "reallocated" => 34,
+    "reallocates" => 30,
+    "reallocating" => 38,
+    "reallocation" => 36,
+    "reallocations" => 37,
+    "really" => 51,
+    "realm" => 32,
+    "realtime" => 36,
+    "reap" => 32,
+    "reaped" => 29,
+    "reaping" => 24,
+    "reappear" => 30,
+    "reappearance" => 27,
+    "reappeared" => 32,
+    "reappears" => 29,
+    "reapplied" => 24,
+    "reapplies" => 24,
+    "reapply" => 34,
+    "reapplying" => 24,
+    "rear" => 36,
+    "reared" => 27,
+    "rearguard" => 24,
+    "rearing" => 27,
+    "rearrange" => 34,
+    "rearranged" => 27,
+    "rearranges" => 24,
+    "rearranging" => 29,
+    "rears" => 24,
+    "rearward" => 30,
+    "reason" => 51,
+    "reasonable" => 44,
+    "reasonably" => 41,
+    "reasoned" => 34,
+    "reasoning" => 39,
+    "reasons" => 48,
+    "reassembled" => 29,
+    "reassembling" => 24,
+    "reassembly" => 32,
+    "reassign" => 29,
+    "reassigned" => 24,
+    "reassigning" => 24,
+    "reassurance" => 24,
+    "reassure" => 32,
+    "reassured" => 30,
+    "reassuring" => 24,
+    "reassuringly" => 24,
+    "reattachment" => 24,
+    "rebecca" => 29,
+    "rebel" => 27,
+    "rebellion" => 32,
+    "rebind" => 29,
+    "rebinding" => 32,
+    "rebinds" => 24,
+    "reboot" => 33,
+    "rebooting" => 27,
+    "reboots" => 27,
+    "rebound" => 32,
+    "rebroadcast" => 24,
+    "rebuild" => 41,
+    "rebuilding" => 36,
+    "rebuilds" => 37,
+    "rebuilt" => 38,
+    "rebuke" => 24,
+    "rec" => 30,
+    "recalculate" => 24,
+    "recalculated" => 30,
+    "recalculating" => 27,
+    "recall" => 42,
+    "recalled" => 36,
+    "recalling" => 33,
+    "recap" => 33,
+    "recapitulation" => 24,
+    "recapped" => 24,
+    "recaptured" => 24,
+    "recast" => 24,
+    "receded" => 24,
+    "recedes" => 24,
+    "receipt" => 36,
+    "receipts" => 29,
+    "receive" => 48,
+    "received" => 49,
+    "receiver" => 46,
+    "receivers" => 37,
+    "receives" => 45,
+    "receiving" => 45,
+    "recent" => 48,
+    "recently" => 41,
+    "receptacle" => 24,
+    "receptacles" => 24,
+    "reception" => 35,
+    "receptive" => 27,
+    "recess" => 33,
+    "recesses" => 30,
+    "recht" => 24,
+    "recipe" => 30,
+    "recipes" => 33,
+    "recipient" => 35,
+    "recipients" => 34,
+    "reciprocal" => 42,
+    "reciprocals" => 27,
+    "reciprocity" => 27,
+    "recital" => 24,
+    "recitation" => 24,
+    "recite" => 29,
+    "recited" => 29,
+    "reciting" => 24,
+    "reckless" => 29,
+    "reckon" => 43,
+    "reckoned" => 32,
+    "reckoner" => 24,
+    "reckoning" => 30,
+    "reclaim" => 29,
+    "reclaimable" => 24,
+    "reclaimed" => 29,
+    "reclamation" => 33,
+    "reclining" => 24,
+    "recognise" => 34,
+    "recognised" => 37,
+    "recognises" => 27,
+    "recognising" => 29,
+    "recognition" => 40,
+    "recognizable" => 32,
+    "recognize" => 49,
+    "recognized" => 50,
+    "recognizes" => 39,
+    "recognizing" => 38,
+    "recoil" => 24,
+    "recoiled" => 24,
+    "recollect" => 34,
+    "recollected" => 29,
+    "recollecting" => 24,
+    "recollection" => 34,
+    "recollections" => 30,
+    "recollects" => 24,
+    "recombine" => 27,
+    "recombined" => 24,
+    "recombines" => 24,
+    "recommence" => 30,
+    "recommencing" => 24,
+    "recommend" => 47,
+    "recommendable" => 24,
+    "recommendation" => 40,
+    "recommendations" => 38,
+    "recommended" => 52,
+    "recommending" => 24,
+    "recommends" => 33,
+    "recommits" => 24,
+    "recompense" => 31,
+    "recompensing" => 24,
+    "recompile" => 37,
+    "recompiled" => 32,
+    "recompiles" => 30,
+    "recompiling" => 32,
+    "recompute" => 38,
+    "recomputed" => 32,
+    "recomputes" => 27,
+    "reconcile" => 32,
+    "reconciled" => 27,
+    "reconciliation" => 27,
+    "reconfigure" => 24,
+    "reconfigured" => 24,
+    "reconfigures" => 24,
+    "reconfiguring" => 27,
+    "reconnaissance" => 27,
+    "reconnect" => 30,
+    "reconnected" => 29,
+    "reconnecting" => 31,
+    "reconsider" => 30,
+    "reconsideration" => 24,
+    "reconsidered" => 29,
+    "reconstitute" => 27,
+    "reconstituted" => 24,
+    "reconstruct" => 32,
+    "reconstructed" => 33,
+    "reconstructing" => 27,
+    "reconstruction" => 31,
+    "reconvert" => 29,
+    "record" => 47,
+    "recorded" => 43,
+    "recorder" => 30,
+    "recording" => 43,
+    "recordings" => 30,
+    "records" => 46,
+    "recounted" => 32,
+    "recounting" => 27,
+    "recourse" => 31,
+    "recover" => 44,
+    "recoverability" => 24,
+    "recoverable" => 37,
+    "recovered" => 42,
+    "recovering" => 40,
+    "recovers" => 27,
+    "recovery" => 44,
+    "recreate" => 32,
+    "recreated" => 29,
+    "recreating" => 29,
+    "recreation" => 27,
+    "recreations" => 27,
+    "rectangle" => 41,
+    "rectangles" => 38,
+    "rectangular" => 34,
+    "rectify" => 29,
+    "rectilinear" => 24,
+    "rectitude" => 24,
+    "recur" => 24,
+    "recurred" => 30,
+    "recurrence" => 24,
+    "recurrent" => 29,
+    "recurring" => 31,
+    "recursion" => 42,
+    "recursive" => 47,
+    "recursively" => 46,
+    "recuse" => 24,
+    "recycle" => 31,
+    "recycled" => 30,
+    "recycles" => 27,
+    "recycling" => 33,
+    "red" => 47,
+    "redact" => 24,
+    "redacted" => 30,
+    "redaction" => 24,
+    "reddened" => 24,
+    "redder" => 24,
+    "redeem" => 27,
+    "redefine" => 40,
+    "redefined" => 33,
+    "redefines" => 32,
+    "redefining" => 38,
+    "redefinition" => 31,
+    "redesign" => 24,
+    "redesigned" => 24,
+    "redesigns" => 24,
+    "redirect" => 42,
+    "redirected" => 36,
+    "redirecting" => 38,
+    "redirection" => 39,
+    "redirects" => 34,
+    "redisplay" => 31,
+    "redistribute" => 35,
+    "redistributed" => 24,
+    "redistributing" => 27,
+    "redistribution" => 27,
+    "redo" => 44,
+    "redoes" => 29,
+    "redolent" => 27,
+    "redoubled" => 24,
+    "redraw" => 49,
+    "redrawing" => 45,
+    "redrawn" => 41,
+    "redraws" => 33,
+    "redress" => 27,
+    "redrew" => 24,
+    "reduce" => 49,
+    "reduced" => 44,
+    "reducer" => 27,
+    "reduces" => 41,
+    "reducing" => 42,
+    "reduction" => 42,
+    "reductions" => 27,
+    "redundancies" => 27,
+    "redundancy" => 33,
+    "redundant" => 42,
+    "reed" => 35,
+    "reeds" => 31,
+    "reedy" => 30,
+    "reef" => 24,
+    "reefed" => 27,
+    "reel" => 27,
+    "reeled" => 24,
+    "reeling" => 27,
+    "reels" => 30,
+    "reenable" => 29,
+    "reevaluate" => 30,
+    "reexamine" => 24,
+    "reexamined" => 27,
+    "ref" => 46,
+    "refactor" => 43,
+    "refer" => 51,
+    "referee" => 27,
+    "reference" => 58,
+    "referenced" => 45,
+    "references" => 54,
+    "referencing" => 41,
+    "referent" => 35,
+    "referential" => 37,
+    "referentially" => 29,
+    "referents" => 32,
+    "referred" => 45,
+    "referring" => 41,
+    "refers" => 46,
+    "refill" => 27,
+    "refilled" => 24,
+    "refine" => 36,
+    "refined" => 36,
+    "refinement" => 33,
+    "refinements" => 31,
+    "refinery" => 35,
+    "refining" => 32,
+    "reflect" => 43,
+    "reflected" => 41,
+    "reflecting" => 33,
+    "reflection" => 40,
+    "reflections" => 38,
+    "reflective" => 27,
+    "reflectors" => 29,
+    "reflects" => 38,
+    "reflexive" => 36,
+    "reflexivity" => 34,
+    "reform" => 40,
+    "reformat" => 31,
+    "reformatory" => 30,
+    "reformats" => 27,
+    "reformatted" => 24,
+    "reformatting" => 35,
+    "reformer" => 24,
+    "reforms" => 27,
+    "refrain" => 31,
+    "refrained" => 29,
+    "refresh" => 39,
+    "refreshed" => 35,
+    "refresher" => 30,
+    "refreshes" => 27,
+    "refreshing" => 34,
+    "refreshingly" => 30,
+    "refreshment" => 24,
+    "refreshments" => 24,
+    "refs" => 36,
+    "refuge" => 35,
+    "refugee" => 24,
+    "refund" => 24,
+    "refusal" => 30,
+    "refuse" => 40,
+    "refused" => 40,
+    "refuses" => 31,
+    "refusing" => 31,
+    "refutable" => 42,
+    "reg" => 41,
+    "regain" => 30,
+    "regained" => 31,
+    "regaining" => 30,
+    "regains" => 27,
+    "regal" => 24,
+    "regalia" => 29,
+    "regard" => 41,
+    "regarded" => 37,
+    "regarding" => 41,
+    "regardless" => 47,
+    "regards" => 36,
+    "regatta" => 24,
+    "regency" => 24,
+    "regenerable" => 24,
+    "regenerate" => 36,
+    "regenerated" => 36,
+    "regenerates" => 27,
+    "regenerating" => 34,
+    "regeneration" => 27,
+    "regent" => 30,
+    "regiment" => 27,
+    "region" => 48,
+    "regional" => 33,
+    "regions" => 45,
+    "register" => 54,
+    "registered" => 47,
+    "registering" => 38,
+    "registers" => 49,
+    "registrable" => 29,
+    "registrar" => 24,
+    "registration" => 41,
+    "registrations" => 27,
+    "registries" => 43,
+    "registry" => 51,
+    "regress" => 33,
+    "regressed" => 27,
+    "regression" => 39,
+    "regressions" => 36,
+    "regret" => 36,
+    "regretful" => 24,
+    "regrets" => 27,
+    "regretted" => 33,
+    "regroups" => 24,
+    "regula" => 24,
+    "regular" => 50,
+    "regularisation" => 24,
+    "regularised" => 24,
+    "regularity" => 32,
+    "regularization" => 24,
+    "regularly" => 40,
+    "regulate" => 34,
+    "regulated" => 32,
+    "regulating" => 24,
+    "regulation" => 31,
+    "regulations" => 33,
+    "regulator" => 24,
+    "regurgitation" => 24,
+    "rehashing" => 24,
+    "rehearse" => 24,
+    "rehearsed" => 27,
+    "rehearsing" => 27,
+    "reifying" => 24,
+    "reign" => 29,
+    "reigned" => 31,
+    "reigning" => 29,
+    "reilly" => 38,
+    "reinforcement" => 24,
+    "reinforcing" => 24,
+    "reinitialize" => 27,
+    "reinitialized" => 27,
+    "reinitializes" => 24,
+    "reinstall" => 30,
+    "reinstates" => 24,
+    "reinstating" => 24,
+    "reinterpret" => 31,
+    "reinterpretation" => 24,
+    "reinterpretations" => 24,
+    "reinterpreted" => 33,
+    "reinterpreting" => 31,
+    "reinterprets" => 30,
+    "reintroduce" => 32,
+    "reintroduced" => 24,
+    "reintroduces" => 24,
+    "reintroducing" => 24,
+    "reinvent" => 24,
+    "reinvented" => 24,
+    "reinventing" => 27,
+    "reinvents" => 24,
+    "reiterate" => 29,
+    "reiterated" => 30,
+    "reiterating" => 27,
+    "reject" => 43,
+    "rejected" => 45,
+    "rejecting" => 35,
+    "rejection" => 39,
+    "rejections" => 36,
+    "rejects" => 38,
+    "rejoice" => 24,
+    "rejoiced" => 30,
+    "rejoices" => 24,
+    "rejoin" => 30,
+    "rejoined" => 31,
+    "rejoining" => 24,
+    "rekindled" => 24,
+    "relabel" => 24,
+    "relapse" => 30,
+    "relapsed" => 27,
+    "relapses" => 24,
+    "relapsing" => 24,
+    "relate" => 39,
+    "related" => 52,
+    "relates" => 35,
+    "relating" => 34,
+    "relation" => 41,
+    "relational" => 32,
+    "relations" => 37,
+    "relationship" => 42,
+    "relationships" => 38,
+    "relative" => 51,
+    "relatively" => 43,
+    "relatives" => 35,
+    "relativistic" => 24,
+    "relativity" => 24,
+    "relax" => 32,
+    "relaxation" => 27,
+    "relaxed" => 42,
+    "relaxes" => 32,
+    "relaxing" => 27,
+    "relay" => 31,
+    "relays" => 24,
+    "relearns" => 24,
+    "release" => 55,
+    "released" => 48,
+    "releases" => 50,
+    "releasing" => 39,
+    "relegated" => 24,
+    "relentless" => 29,
+    "relentlessly" => 27,
+    "relevance" => 34,
+    "relevant" => 48,
+    "reliability" => 36,
+    "reliable" => 43,
+    "reliably" => 40,
+    "reliance" => 33,
+    "reliant" => 33,
+    "relic" => 29,
+    "relics" => 27,
+    "relied" => 41,
+    "relief" => 37,
+    "relies" => 44,
+    "relieve" => 35,
+    "relieved" => 35,
+    "relieving" => 27,
+    "religion" => 34,
+    "religions" => 30,
+    "religious" => 33,
+    "religiously" => 24,
+    "relinquish" => 27,
+    "relinquished" => 30,
+    "relinquishes" => 24,
+    "relinquishing" => 27,
+    "relish" => 27,
+    "reload" => 40,
+    "reloaded" => 36,
+    "reloading" => 40,
+    "reloads" => 31,
+    "relocatable" => 35,
+    "relocated" => 32,
+    "relocation" => 36,
+    "reluctance" => 31,
+    "reluctant" => 34,
+    "reluctantly" => 29,
+    "rely" => 46,
+    "relying" => 43,
+    "rem" => 32,
+    "remade" => 27,
+    "remain" => 48,
+    "remainder" => 45,
+    "remained" => 44,
+    "remaining" => 48,
+    "remains" => 46,
+    "remanded" => 24,
+    "remark" => 43,
+    "remarkable" => 38,
+    "remarkably" => 35,
+    "remarked" => 44,
+    "remarking" => 32,
+    "remarks" => 42,
+    "rembrandt" => 24,
+    "remedied" => 24,
+    "remedies" => 27,
+    "remedy" => 32,
+    "remember" => 49,
+    "remembered" => 44,
+    "remembering" => 37,
+    "remembers" => 38,
+    "remembrance" => 35,
+    "remembrancers" => 24,
+    "remembrances" => 24,
+    "remind" => 36,
+    "reminded" => 35,
+    "reminder" => 34,
+    "reminding" => 29,
+    "reminds" => 33,
+    "reminiscence" => 24,
+    "reminiscent" => 24,
+    "remissness" => 24,
+    "remit" => 24,
+    "remnants" => 24,
+    "remonstrance" => 27,
+    "remonstrate" => 27,
+    "remorse" => 36,
+    "remorseless" => 24,
+    "remorselessly" => 24,
+    "remote" => 51,
+    "remotely" => 36,
+    "remoter" => 27,
+    "remotest" => 24,
+    "remounted" => 24,
+    "removable" => 30,
+    "removal" => 43,
+    "removals" => 32,
+    "remove" => 56,
+    "removed" => 53,
+    "removes" => 48,
+    "removing" => 48,
+    "remunerative" => 24,
+    "renaissance" => 24,
+    "rename" => 48,
+    "renamed" => 46,
+    "renames" => 37,
+    "renaming" => 41,
+    "renault" => 24,
+    "rend" => 30,
+    "render" => 46,
+    "rendered" => 44,
+    "rendering" => 48,
+    "renderings" => 27,
+    "renders" => 38,
+    "rendezvous" => 33,
+    "rendezvoused" => 29,
+    "rending" => 29,
+    "rendition" => 27,
+    "renditions" => 24,
+    "rends" => 24,
+    "renegotiate" => 29,
+    "renegotiation" => 35,
+    "renew" => 32,
+    "renewal" => 30,
+    "renewals" => 24,
+    "renewed" => 34,
+    "renewing" => 24,
+    "reno" => 24,
+    "renounce" => 24,
+    "renovate" => 32,
+    "renovated" => 24,
+    "renovating" => 24,
+    "renowned" => 27,
+    "rent" => 34,
+    "rental" => 37,
+    "rented" => 29,
+    "renting" => 24,
+    "renumber" => 32,
+    "reopen" => 24,
+    "reopened" => 27,
+    "reopening" => 27,
+    "reorder" => 40,
+    "reordered" => 37,
+    "reordering" => 37,
+    "reorders" => 30,
+    "reorganise" => 24,
+    "reorganization" => 27,
+    "reorganize" => 31,
+    "reorganized" => 31,
+    "reorganizes" => 24,
+    "reorganizing" => 27,
+    "rep" => 35,
+    "repack" => 30,
+    "repackage" => 27,
+    "repaid" => 29,
+    "repainting" => 24,
+    "repair" => 34,
+    "repaired" => 34,
+    "repairing" => 29,
+    "repairs" => 32,
+    "reparation" => 24,
+    "repartee" => 24,
+    "repast" => 27,
+    "repay" => 30,
+    "repeat" => 48,
+    "repeatable" => 32,
+    "repeated" => 47,
+    "repeatedly" => 42,
+    "repeater" => 27,
+    "repeating" => 44,
+    "repeats" => 40,
+    "repel" => 24,
+    "repelled" => 29,
+    "repent" => 29,
+    "repentance" => 29,
+    "repentant" => 27,
+    "repented" => 24,
+    "repetition" => 44,
+    "repetitions" => 39,
+    "repetitive" => 35,
+    "rephrase" => 27,
+    "repined" => 24,
+    "replace" => 51,
+    "replaceable" => 24,
+    "replaced" => 49,
+    "replacement" => 48,
+    "replacements" => 38,
+    "replaces" => 45,
+    "replacing" => 45,
+    "replay" => 36,
+    "replayed" => 31,
+    "replaying" => 29,
+    "replays" => 29,
+    "replenish" => 24,
+    "replenished" => 29,
+    "replenishes" => 27,
+    "replete" => 29,
+    "replica" => 24,
+    "replicas" => 24,
+    "replicate" => 38,
+    "replicated" => 35,
+    "replicating" => 29,
+    "replication" => 30,
+    "replied" => 46,
+    "replies" => 36,
+    "reply" => 41,
+    "replying" => 29,
+    "repo" => 48,
+    "report" => 51,
+    "reported" => 48,
+    "reportedly" => 24,
+    "reporter" => 38,
+    "reporters" => 36,
+    "reporting" => 47,
+    "reports" => 48,
+    "repos" => 36,
+    "repose" => 36,
+    "reposed" => 27,
+    "reposeful" => 24,
+    "reposes" => 24,
+    "reposing" => 27,
+    "reposition" => 29,
+    "repositories" => 44,
+    "repository" => 54,
+    "represent" => 50,
+    "representable" => 40,
+    "representation" => 52,
+    "representations" => 44,
+    "representative" => 38,
+    "representatives" => 39,
+    "represented" => 49,
+    "representing" => 50,
+    "represents" => 50,
+    "repress" => 24,
+    "repressed" => 24,
+    "reprieves" => 24,
+    "reprimand" => 24,
+    "reprint" => 24,
+    "reprints" => 27,
+    "reprisal" => 27,
+    "repro" => 24,
+    "reproach" => 33,
+    "reproached" => 24,
+    "reproaches" => 24,
+    "reproachfully" => 27,
+    "reprobated" => 24,
+    "reprocessed" => 24,
+    "reprocessing" => 24,
+    "reproduce" => 40,
+    "reproduced" => 34,
+    "reproduces" => 27,
+    "reproducibility" => 33,
+    "reproducible" => 38,
+    "reproducibly" => 27,
+    "reproducing" => 29,
+    "reproduction" => 37,
+    "reproductions" => 30,
+    "reproductive" => 24,
+    "reproof" => 24,
+    "republic" => 29,
+    "republican" => 31,
+    "republics" => 24,
+    "republish" => 24,
+    "republishing" => 27,
+    "repugnance" => 30,
+    "repugnant" => 24,
+    "repulsed" => 27,
+    "repulses" => 24,
+    "repulsion" => 24,
+    "repulsive" => 31,
+    "reputation" => 34,
+    "repute" => 27,
+    "reputed" => 24,
+    "request" => 54,
+    "requested" => 46,
+    "requester" => 36,
+    "requesting" => 40,
+    "requests" => 51,
+    "require" => 54,
+    "required" => 55,
+    "requirement" => 49,
+    "requirements" => 52,
+    "requires" => 56,
+    "requiring" => 45,
+    "requisite" => 35,
+    "requisites" => 32,
+    "requisition" => 27,
+    "requited" => 24,
+    "reran" => 24,
+    "reread" => 27,
+    "rerum" => 24,
+    "rerun" => 36,
+    "rerunning" => 29,
+    "reruns" => 30,
+    "res" => 43,
+    "reschedules" => 24,
+    "rescheduling" => 24,
+    "rescript" => 24,
+    "rescue" => 36,
+    "rescued" => 27,
+    "rescuing" => 24,
+    "research" => 46,
+    "researched" => 24,
+    "researchers" => 37,
+    "researches" => 29,
+    "researching" => 27,
+    "resemblance" => 34,
+    "resemble" => 34,
+    "resembled" => 34,
+    "resembles" => 36,
+    "resembling" => 32,
+    "resented" => 30,
+    "resenting" => 24,
+    "resentment" => 31,
+    "reservation" => 32,
+    "reservations" => 24,
+    "reserve" => 43,
+    "reserved" => 50,
+    "reserves" => 37,
+    "reserving" => 34,
+    "reset" => 51,
+    "resets" => 41,
+    "resetting" => 40,
+    "reside" => 37,
+    "resided" => 32,
+    "residence" => 36,
+    "resident" => 35,
+    "residential" => 24,
+    "residents" => 24,
+    "resides" => 35,
+    "residing" => 30,
+    "residual" => 32,
+    "residuals" => 27,
+    "residue" => 24,
+    "resign" => 29,
+    "resignation" => 30,
+    "resigned" => 31,
+    "resilience" => 32,
+    "resilient" => 31,
+    "resin" => 24,
+    "resist" => 34,
+    "resistance" => 40,
+    "resistant" => 38,
+    "resisted" => 29,
+    "resisting" => 27,
+    "resize" => 43,
+    "resized" => 38,
+    "resizes" => 34,
+    "resizing" => 43,
+    "resolute" => 30,
+    "resolution" => 51,
+    "resolutions" => 38,
+    "resolve" => 48,
+    "resolved" => 49,
+    "resolves" => 42,
+    "resolving" => 44,
+    "resort" => 35,
+    "resorted" => 27,
+    "resorting" => 30,
+    "resounded" => 32,
+    "resource" => 50,
+    "resourcefulness" => 24,
+    "resources" => 50,
+    "respect" => 46,
+    "respectable" => 35,
+    "respected" => 40,
+    "respectful" => 30,
+    "respectfully" => 29,
+    "respecting" => 37,
+    "respective" => 46,
+    "respectively" => 48,
+    "respects" => 40,
+    "respite" => 30,
+    "respond" => 41,
+    "responded" => 37,
+    "respondents" => 27,
+    "responder" => 31,
+    "responding" => 36,
+    "responds" => 38,
+    "response" => 51,
+    "responses" => 44,
+    "responsibilities" => 37,
+    "responsibility" => 44,
+    "responsible" => 45,
+    "responsibly" => 24,
+    "responsive" => 29,
+    "responsiveness" => 27,
+    "rest" => 50,
+    "restart" => 41,
+    "restarted" => 32,
+    "restarting" => 34,
+    "restarts" => 29,
+    "restating" => 24,
+    "restaurant" => 37,
+    "restaurants" => 29,
+    "rested" => 38,
+    "restful" => 24,
+    "resting" => 34,
+    "restive" => 27,
+    "restless" => 36,
+    "restlessly" => 27,
+    "restlessness" => 27,
+    "restoration" => 30,
+    "restorative" => 24,
+    "restore" => 49,
+    "restored" => 45,
+    "restores" => 38,
+    "restoring" => 43,
+    "restrain" => 30,
+    "restrained" => 31,
+    "restraint" => 29,
+    "restraints" => 24,
+    "restrict" => 44,
+    "restricted" => 45,
+    "restricting" => 35,
+    "restriction" => 45,
+    "restrictions" => 46,
+    "restrictive" => 38,
+    "restricts" => 38,
+    "restructure" => 27,
+    "restructured" => 31,
+    "restructuring" => 29,
+    "rests" => 31,
+    "resubmit" => 24,
+    "result" => 59,
+    "resultant" => 38,
+    "resulted" => 40,
+    "resulting" => 50,
+    "results" => 58,
+    "resumable" => 29,
+    "resume" => 41,
+    "resumed" => 43,
+    "resumes" => 35,
+    "resuming" => 33,
+    "resumption" => 36,
+    "resurrected" => 24,
+    "resurrection" => 27,
+    "resuscitated" => 24,
+    "retain" => 41,
+    "retained" => 41,
+    "retaining" => 37,
+    "retains" => 38,
+    "retake" => 24,
+    "retaken" => 24,
+    "retakes" => 27,
+    "retaliating" => 24,
+    "retard" => 27,
+    "retarded" => 29,
+    "retards" => 24,
+    "retention" => 24,
+    "rethink" => 27,
+    "rethinking" => 24,
+    "retinas" => 24,
+    "retire" => 35,
+    "retired" => 40,
+    "retirement" => 29,
+    "retires" => 24,
+    "retiring" => 32,
+    "retold" => 24,
+    "retort" => 24,
+    "retorted" => 31,
+    "retracted" => 24,
+    "retractions" => 24,
+    "retransmission" => 27,
+    "retreat" => 36,
+    "retreated" => 29,
+    "retreating" => 24,
+    "retreats" => 27,
+    "retribution" => 29,
+    "retried" => 37,
+    "retries" => 34,
+    "retrievable" => 27,
+    "retrieval" => 42,
+    "retrievals" => 27,
+    "retrieve" => 45,
+    "retrieved" => 43,
+    "retrieves" => 38,
+    "retrieving" => 41,
+    "retroactive" => 24,
+    "retroactively" => 27,
+    "retrod" => 24,
+    "retrogression" => 27,
+    "retrospect" => 24,
+    "retrospective" => 27,
+    "retrospectively" => 24,
+    "retry" => 44,
+    "retrying" => 32,
+    "return" => 59,
+    "returnable" => 24,
+    "returned" => 57,
+    "returning" => 52,
+    "returns" => 61,
+    "retyping" => 24,
+    "reusable" => 40,
+    "reuse" => 45,
+    "reused" => 41,
+    "reuses" => 34,
+    "reusing" => 39,
+    "rev" => 38,
+    "revalidation" => 32,
+    "revalued" => 24,
+    "reveal" => 36,
+    "revealed" => 38,
+    "revealing" => 30,
+    "reveals" => 29,
+    "revel" => 24,
+    "revelation" => 27,
+    "revelations" => 27,
+    "revelled" => 24,
+    "revellers" => 24,
+    "revenge" => 40,
+    "revengeful" => 27,
+    "revenue" => 27,
+    "reverberated" => 24,
+    "reverberations" => 24,
+    "reverence" => 31,
+    "reverend" => 30,
+    "reverent" => 27,
+    "reverential" => 24,
+    "reverently" => 24,
+    "reverie" => 30,
+    "reveries" => 31,
+    "reversal" => 30,
+    "reverse" => 48,
+    "reversed" => 41,
+    "reverses" => 37,
+    "reversibility" => 24,
+    "reversible" => 27,
+    "reversing" => 37,
+    "revert" => 41,
+    "reverted" => 35,
+    "reverting" => 27,
+    "reverts" => 27,
+    "review" => 45,
+    "reviewable" => 24,
+    "reviewed" => 40,
+    "reviewer" => 27,
+    "reviewers" => 29,
+    "reviewing" => 33,
+    "reviews" => 34,
+    "revise" => 27,
+    "revised" => 33,
+    "revision" => 45,
+    "revisions" => 39,
+    "revisit" => 31,
+    "revisited" => 27,
+    "revisiting" => 29,
+    "revisits" => 24,
+    "revitalized" => 24,
+    "revival" => 27,
+    "revive" => 32,
+    "revived" => 31,
+    "reviving" => 29,
+    "revocable" => 27,
+    "revocation" => 36,
+    "revocations" => 27,
+    "revoke" => 34,
+    "revoked" => 35,
+    "revoking" => 24,
+    "revolt" => 27,
+    "revolted" => 24,
+    "revolting" => 24,
+    "revolution" => 30,
+    "revolutionary" => 27,
+    "revolutionize" => 24,
+    "revolutionizing" => 27,
+    "revolutions" => 24,
+    "revolve" => 24,
+    "revolved" => 33,
+    "revolver" => 35,
+    "revolvers" => 32,
+    "revolves" => 30,
+    "revolving" => 29,
+    "revs" => 27,
+    "reward" => 39,
+    "rewarded" => 31,
+    "rewarding" => 27,
+    "rewards" => 32,
+    "rewind" => 36,
+    "rewinding" => 29,
+    "rewinds" => 31,
+    "rework" => 33,
+    "reworked" => 29,
+    "reworks" => 27,
+    "rewound" => 24,
+    "rewrite" => 43,
+    "rewrites" => 32,
+    "rewriting" => 38,
+    "rewritten" => 41,
+    "rewrote" => 27,
+    "rex" => 33,
+    "rfc" => 52,
+    "rhapsody" => 24,
+    "rhine" => 32,
+    "rho" => 30,
+    "rhode" => 24,
+    "rhone" => 27,
+    "rhs" => 44,
+    "rhubarb" => 24,
+    "rhyme" => 27,
+    "rhythm" => 32,
+    "ribbed" => 24,
+    "ribbon" => 33,
+    "ribbons" => 24,
+    "rice" => 31,
+    "rich" => 44,
+    "richard" => 36,
+    "richardson" => 29,
+    "richer" => 37,
+    "riches" => 29,
+    "richest" => 30,
+    "richly" => 24,
+    "richness" => 30,
+    "rick" => 33,
+    "rickety" => 24,
+    "ricky" => 34,
+    "rid" => 40,
+    "ridden" => 24,
+    "riddle" => 24,
+    "riddles" => 27,
+    "ride" => 33,
+    "rider" => 24,
+    "riders" => 27,
+    "rides" => 24,
+    "ridge" => 32,
+    "ridges" => 29,
+    "ridicule" => 27,
+    "ridiculous" => 31,
+    "ridiculously" => 31,
+    "riding" => 32,
+    "rien" => 24,
+    "riff" => 31,
+    "rifle" => 24,
+    "rifled" => 27,
+    "rifles" => 24,
+    "rift" => 29,
+    "rifts" => 30,
+    "rig" => 24,
+    "rigged" => 30,
+    "rigging" => 24,
+    "right" => 57,
+    "rightfully" => 29,
+    "righthand" => 31,
+    "rightly" => 32,
+    "rightmost" => 37,
+    "rights" => 42,
+    "rightward" => 27,
+    "rightwards" => 34,
+    "rigid" => 32,
+    "rigidity" => 24,
+    "rigidly" => 29,
+    "rigorous" => 32,
+    "rigorously" => 33,
+    "rigs" => 24,
+    "rimmed" => 24,
+    "rind" => 27,
+    "rinds" => 24,
+    "ring" => 45,
+    "ringing" => 31,
+    "ringlets" => 27,
+    "rings" => 33,
+    "rinse" => 24,
+    "rio" => 27,
+    "riot" => 29,
+    "rioters" => 24,
+    "rioting" => 24,
+    "riotous" => 27,
+    "rip" => 30,
+    "ripe" => 24,
+    "ripen" => 24,
+    "riper" => 24,
+    "ripley" => 24,
+    "ripped" => 31,
+    "ripper" => 24,
+    "ripple" => 31,
+    "rippled" => 29,
+    "ripples" => 24,
+    "rippling" => 29,
+    "rips" => 24,
+    "rise" => 39,
+    "risen" => 34,
+    "riser" => 24,
+    "risers" => 24,
+    "rises" => 30,
+    "rising" => 40,
+    "risk" => 46,
+    "risked" => 27,
+    "risking" => 29,
+    "risks" => 36,
+    "risky" => 30,
+    "rite" => 36,
+    "rites" => 24,
+    "ritz" => 27,
+    "rival" => 29,
+    "rivalry" => 24,
+    "rivals" => 24,
+    "river" => 43,
+    "rivers" => 34,
+    "riverside" => 27,
+    "riveted" => 24,
+    "rivulets" => 24,
+    "rna" => 27,
+    "road" => 44,
+    "roadhouse" => 24,
+    "roads" => 34,
+    "roadside" => 24,
+    "roadstead" => 27,
+    "roadster" => 27,
+    "roadway" => 24,
+    "roamed" => 27,
+    "roaming" => 31,
+    "roar" => 30,
+    "roared" => 33,
+    "roaring" => 37,
+    "roast" => 29,
+    "roasted" => 27,
+    "roasting" => 27,
+    "rob" => 37,
+    "robbed" => 27,
+    "robber" => 39,
+    "robberies" => 27,
+    "robbers" => 33,
+    "robbery" => 37,
+    "robbing" => 31,
+    "robbins" => 24,
+    "robe" => 29,
+    "robed" => 27,
+    "robert" => 39,
+    "roberts" => 29,
+    "robes" => 29,
+    "robin" => 36,
+    "robinson" => 40,
+    "robot" => 29,
+    "robotics" => 33,
+    "robots" => 27,
+    "robs" => 24,
+    "robust" => 44,
+    "robustly" => 29,
+    "robustness" => 35,
+    "rock" => 40,
+    "rocked" => 29,
+    "rockefeller" => 24,
+    "rocket" => 41,
+    "rockies" => 24,
+    "rocking" => 24,
+    "rocks" => 33,
+    "rocky" => 34,
+    "rococo" => 27,
+    "rod" => 31,
+    "rode" => 31,
+    "rodeo" => 27,
+    "rodriguez" => 24,
+    "rods" => 27,
+    "roebuck" => 24,
+    "roger" => 30,
+    "rogers" => 34,
+    "rogue" => 33,
+    "rogues" => 31,
+    "roland" => 35,
+    "role" => 42,
+    "roles" => 38,
+    "roll" => 38,
+    "rollback" => 37,
+    "rolled" => 39,
+    "roller" => 24,
+    "rollicking" => 24,
+    "rolling" => 40,
+    "rolls" => 33,
+    "rom" => 44,
+    "roman" => 40,
+    "romance" => 30,
+    "romances" => 24,
+    "romanian" => 30,
+    "romans" => 24,
+    "romansh" => 24,
+    "romantic" => 37,
+    "rome" => 34,
+    "romp" => 24,
+    "romper" => 24,
+    "romping" => 24,
+    "romulus" => 24,
+    "ron" => 42,
+    "ronald" => 34,
+    "roof" => 38,
+    "roofed" => 24,
+    "roofs" => 33,
+    "room" => 50,
+    "rooms" => 41,
+    "roosevelt" => 30,
+    "root" => 53,
+    "rooted" => 34,
+    "rooting" => 24,
+    "roots" => 42,
+    "rope" => 38,
+    "ropes" => 31,
+    "ropey" => 39,
+    "rosary" => 24,
+    "rose" => 45,
+    "rosenthal" => 24,
+    "roses" => 31,
+    "rosin" => 24,
+    "ross" => 36,
+    "rosy" => 33,
+    "rot" => 36,
+    "rotate" => 42,
+    "rotated" => 32,
+    "rotates" => 36,
+    "rotating" => 34,
+    "rotation" => 37,
+    "rotational" => 24,
+    "rotations" => 32,
+    "rotogravure" => 24,
+    "rotor" => 30,
+    "rotors" => 30,
+    "rotten" => 35,
+    "rotterdam" => 29,
+    "rotting" => 24,
+    "rouble" => 31,
+    "rouged" => 24,
+    "rough" => 40,
+    "rougher" => 24,
+    "roughest" => 24,
+    "roughly" => 44,
+    "roughness" => 24,
+    "roughs" => 29,
+    "roulette" => 24,
+    "round" => 56,
+    "roundabout" => 32,
+    "rounded" => 43,
+    "rounding" => 54,
+    "rounds" => 41,
+    "rouse" => 29,
+    "roused" => 36,
+    "rouses" => 24,
+    "rout" => 27,
+    "route" => 43,
+    "routed" => 35,
+    "routes" => 37,
+    "routine" => 39,
+    "routinely" => 30,
+    "routines" => 41,
+    "routing" => 39,
+    "roux" => 24,
+    "roved" => 24,
+    "rovers" => 24,
+    "row" => 47,
+    "rowan" => 33,
+    "rowboat" => 24,
+    "rowdy" => 24,
+    "rowed" => 24,
+    "rowing" => 30,
+    "rows" => 43,
+    "royal" => 33,
+    "royalties" => 27,
+    "royalty" => 29,
+    "royce" => 24,
+    "rpm" => 34,
+    "rsa" => 43,
+    "rtf" => 29,
+    "rub" => 31,
+    "rubato" => 29,
+    "rubbed" => 34,
+    "rubber" => 35,
+    "rubbers" => 27,
+    "rubbing" => 33,
+    "rubbish" => 27,
+    "rubbishy" => 24,
+    "rube" => 24,
+    "rubicund" => 27,
+    "rubies" => 29,
+    "rubric" => 24,
+    "ruby" => 50,
+    "rudder" => 32,
+    "ruddy" => 27,
+    "rude" => 35,
+    "rudely" => 29,
+    "rudeness" => 24,
+    "rudimentary" => 33,
+    "rue" => 24,
+    "rueful" => 24,
+    "ruefully" => 30,
+    "ruffian" => 29,
+    "ruffians" => 27,
+    "ruffled" => 27,
+    "rug" => 30,
+    "rugged" => 31,
+    "ruggedness" => 24,
+    "rugs" => 24,
+    "ruin" => 38,
+    "ruination" => 24,
+    "ruined" => 37,
+    "ruining" => 24,
+    "ruinous" => 27,
+    "ruinously" => 24,
+    "ruins" => 31,
+    "rule" => 49,
+    "ruled" => 32,
+    "ruler" => 42,
+    "rulers" => 24,
+    "rules" => 54,
+    "ruling" => 27,
+    "rum" => 24,
+    "rumble" => 30,
+    "rumbling" => 27,
+    "rumblings" => 24,
+    "ruminating" => 24,
+    "rummage" => 24,
+    "rummaged" => 29,
+    "rumor" => 24,
+    "rumored" => 24,
+    "rumors" => 29,
+    "rumour" => 24,
+    "rumours" => 30,
+    "rump" => 24,
+    "rumpled" => 27,
+    "run" => 59,
+    "runaway" => 24,
+    "rung" => 24,
+    "runic" => 27,
+    "runner" => 45,
+    "runners" => 33,
+    "running" => 56,
+    "runs" => 50,
+    "runtime" => 59,
+    "rural" => 33,
+    "ruse" => 24,
+    "rush" => 37,
+    "rushed" => 42,
+    "rushes" => 24,
+    "rushing" => 35,
+    "russell" => 29,
+    "russia" => 27,
+    "russian" => 41,
+    "russians" => 27,
+    "rust" => 65,
+    "rusted" => 27,
+    "rustic" => 32,
+    "rustier" => 24,
+    "rustle" => 27,
+    "rustled" => 27,
+    "rustler" => 24,
+    "rustless" => 24,
+    "rustling" => 33,
+    "rusts" => 30,
+    "rusty" => 43,
+    "rutgers" => 32,
+    "ruthless" => 24,
+    "ruthlessly" => 27,
+    "ryan" => 32,
+    "ryder" => 37,
+    "sabbath" => 30,
+    "sable" => 24,
+    "sabre" => 24,
+    "sabres" => 30,
+    "sachem" => 24,
+    "sackcloth" => 27,
+    "sacks" => 27,
+    "sacramento" => 32,
+    "sacred" => 37,
+    "sacredness" => 24,
+    "sacrifice" => 38,
+    "sacrificed" => 33,
+    "sacrifices" => 30,
+    "sacrificing" => 33,
+    "sacrilege" => 32,
+    "sad" => 41,
+    "saddened" => 24,
+    "saddening" => 24,
+    "saddest" => 27,
+    "saddle" => 31,
+    "saddled" => 29,
+    "saddles" => 24,
+    "sadler" => 27,
+    "sadly" => 37,
+    "sadness" => 33,
+    "sae" => 47,
+    "safari" => 24,
+    "safe" => 56,
+    "safeguard" => 29,
+    "safeguarded" => 24,
+    "safeguards" => 24,
+    "safely" => 47,
+    "safer" => 41,
+    "safes" => 24,
+    "safest" => 32,
+    "safety" => 54,
+    "saffron" => 29,
+    "sagacity" => 29,
+    "sage" => 32,
+    "sagely" => 24,
+    "sages" => 29,
+    "sago" => 32,
+    "said" => 57,
+    "sail" => 38,
+    "sailed" => 33,
+    "sailfish" => 24,
+    "sailing" => 35,
+    "sailor" => 33,
+    "sailors" => 36,
+    "sails" => 38,
+    "saint" => 29,
+    "saintly" => 27,
+    "saints" => 29,
+    "sake" => 41,
+    "sakes" => 24,
+    "saki" => 24,
+    "sal" => 31,
+    "salads" => 24,
+    "salaries" => 24,
+    "salary" => 32,
+    "sale" => 32,
+    "sales" => 24,
+    "salesman" => 34,
+    "salesmen" => 24,
+    "salient" => 24,
+    "sallied" => 24,
+    "sallies" => 27,
+    "sallow" => 31,
+    "sally" => 32,
+    "salmi" => 27,
+    "salmon" => 27,
+    "salon" => 24,
+    "salons" => 27,
+    "saloon" => 34,
+    "saloons" => 24,
+    "salsa" => 35,
+    "salt" => 41,
+    "salts" => 27,
+    "salubrious" => 24,
+    "salutation" => 24,
+    "salutations" => 24,
+    "salute" => 24,
+    "saluted" => 30,
+    "salvation" => 24,
+    "salvo" => 24,
+    "sam" => 34,
+    "samba" => 32,
+    "same" => 61,
+    "samoan" => 24,
+    "sample" => 45,
+    "sampled" => 33,
+    "sampler" => 38,
+    "samplers" => 33,
+    "samples" => 42,
+    "sampling" => 39,
+    "samson" => 24,
+    "samuel" => 37,
+    "san" => 40,
+    "sanction" => 24,
+    "sanctioned" => 27,
+    "sanctions" => 30,
+    "sand" => 39,
+    "sandals" => 24,
+    "sandbox" => 43,
+    "sander" => 24,
+    "sanders" => 27,
+    "sands" => 32,
+    "sandwich" => 27,
+    "sandwiched" => 27,
+    "sandwiches" => 24,
+    "sandy" => 31,
+    "sane" => 33,
+    "sanely" => 24,
+    "sang" => 33,
+    "sanguinary" => 27,
+    "sanguine" => 24,
+    "sanitation" => 27,
+    "sanitize" => 34,
+    "sanitized" => 34,
+    "sanitizes" => 27,
+    "sanity" => 35,
+    "sank" => 40,
+    "sans" => 35,
+    "sanskrit" => 27,
+    "santa" => 29,
+    "sapling" => 27,
+    "sapphire" => 32,
+    "sappy" => 24,
+    "sarah" => 24,
+    "sarcasms" => 24,
+    "sardines" => 24,
+    "sardinian" => 24,
+    "sardonic" => 24,
+    "sas" => 31,
+    "sash" => 30,
+    "sashes" => 27,
+    "sass" => 31,
+    "sat" => 46,
+    "satan" => 30,
+    "satellite" => 29,
+    "satiate" => 24,
+    "satiated" => 29,
+    "satin" => 24,
+    "satirical" => 24,
+    "satisfaction" => 38,
+    "satisfactorily" => 24,
+    "satisfactory" => 36,
+    "satisfied" => 43,
+    "satisfies" => 40,
+    "satisfy" => 46,
+    "satisfying" => 36,
+    "saturate" => 35,
+    "saturated" => 38,
+    "saturates" => 32,
+    "saturating" => 45,
+    "saturation" => 45,
+    "saturday" => 40,
+    "saturdays" => 27,
+    "sauce" => 30,
+    "saucepan" => 24,
+    "saucepans" => 24,
+    "saucer" => 32,
+    "saunders" => 27,
+    "sauntered" => 31,
+    "sauntering" => 24,
+    "sausages" => 24,
+    "sauterne" => 24,
+    "savage" => 37,
+    "savagely" => 30,
+    "savagery" => 24,
+    "savages" => 24,
+    "savannah" => 31,
+    "save" => 51,
+    "saved" => 46,
+    "saver" => 27,
+    "saves" => 39,
+    "saving" => 45,
+    "savings" => 33,
+    "saviour" => 29,
+    "savors" => 24,
+    "savory" => 24,
+    "savoury" => 24,
+    "savoy" => 24,
+    "savvy" => 24,
+    "saw" => 50,
+    "sawdust" => 24,
+    "sawed" => 24,
+    "sawfish" => 24,
+    "sawyer" => 39,
+    "sax" => 24,
+    "saxe" => 32,
+    "saxon" => 36,
+    "saxons" => 30,
+    "saxony" => 24,
+    "saxophones" => 27,
+    "say" => 52,
+    "saying" => 44,
+    "sayings" => 24,
+    "says" => 46,
+    "scaffold" => 31,
+    "scaffolding" => 30,
+    "scalable" => 38,
+    "scalar" => 48,
+    "scalars" => 38,
+    "scale" => 49,
+    "scaled" => 43,
+    "scales" => 39,
+    "scaling" => 38,
+    "scalloped" => 24,
+    "scalp" => 24,
+    "scalped" => 24,
+    "scalping" => 24,
+    "scaly" => 24,
+    "scamper" => 24,
+    "scampered" => 24,
+    "scan" => 42,
+    "scandal" => 36,
+    "scandals" => 24,
+    "scandinavia" => 27,
+    "scandinavian" => 24,
+    "scanned" => 39,
+    "scanner" => 37,
+    "scanners" => 32,
+    "scanning" => 45,
+    "scans" => 36,
+    "scant" => 24,
+    "scanty" => 30,
+    "scar" => 30,
+    "scarce" => 30,
+    "scarcely" => 40,
+    "scare" => 24,
+    "scarecrows" => 24,
+    "scared" => 36,
+    "scarf" => 24,
+    "scarfs" => 24,
+    "scarify" => 24,
+    "scaring" => 29,
+    "scarlet" => 31,
+    "scary" => 31,
+    "scat" => 24,
+    "scatter" => 38,
+    "scattered" => 39,
+    "scattering" => 24,
+    "scatters" => 24,
+    "scavenge" => 24,
+    "scenario" => 43,
+    "scenarios" => 45,
+    "scene" => 43,
+    "scenery" => 32,
+    "scenes" => 40,
+    "scent" => 35,
+    "scents" => 27,
+    "sceptic" => 24,
+    "sceptically" => 24,
+    "scepticism" => 29,
+    "schedule" => 42,
+    "scheduled" => 43,
+    "scheduler" => 39,
+    "schedulers" => 33,
+    "schedules" => 37,
+    "scheduling" => 41,
+    "schema" => 48,
+    "schemas" => 41,
+    "scheme" => 47,
+    "schemer" => 24,
+    "schemes" => 43,
+    "scheming" => 24,
+    "scholar" => 33,
+    "scholarly" => 42,
+    "scholars" => 43,
+    "scholarship" => 37,
+    "school" => 45,
+    "schoolboy" => 24,
+    "schoolboys" => 24,
+    "schoolgirl" => 24,
+    "schoolhouse" => 34,
+    "schooling" => 24,
+    "schoolmaster" => 32,
+    "schoolmates" => 29,
+    "schoolroom" => 24,
+    "schools" => 37,
+    "schubert" => 24,
+    "sci" => 29,
+    "science" => 46,
+    "sciences" => 36,
+    "scientific" => 41,
+    "scientist" => 32,
+    "scientists" => 30,
+    "scintillating" => 24,
+    "scion" => 24,
+    "scissor" => 24,
+    "scissoring" => 27,
+    "scissors" => 29,
+    "scoffing" => 27,
+    "scold" => 24,
+    "scolded" => 27,
+    "scolding" => 31,
+    "scoop" => 30,
+    "scooped" => 27,
+    "scope" => 55,
+    "scopes" => 48,
+    "scorched" => 27,
+    "scorching" => 24,
+    "score" => 40,
+    "scored" => 29,
+    "scorer" => 31,
+    "scores" => 36,
+    "scoring" => 34,
+    "scorn" => 33,
+    "scorned" => 24,
+    "scornful" => 27,
+    "scornfully" => 24,
+    "scotch" => 31,
+    "scotia" => 24,
+    "scotland" => 37,
+    "scots" => 29,
+    "scott" => 37,
+    "scottish" => 29,
+    "scoundrel" => 31,
+    "scoundrelly" => 24,
+    "scoundrels" => 27,
+    "scoured" => 24,
+    "scourge" => 24,
+    "scouring" => 24,
+    "scouted" => 24,
+    "scouting" => 24,
+    "scraggy" => 24,
+    "scrambled" => 29,
+    "scrambling" => 27,
+    "scrape" => 37,
+    "scraped" => 33,
+    "scraper" => 31,
+    "scraping" => 32,
+    "scrapped" => 27,
+    "scratch" => 42,
+    "scratched" => 29,
+    "scratching" => 29,
+    "scrawl" => 27,
+    "scrawled" => 32,
+    "scrawny" => 24,
+    "scream" => 34,
+    "screamed" => 35,
+    "screaming" => 35,
+    "screams" => 24,
+    "screech" => 27,
+    "screen" => 54,
+    "screening" => 31,
+    "screenplay" => 27,
+    "screens" => 37,
+    "screw" => 31,
+    "screwed" => 27,
+    "screwing" => 24,
+    "scribble" => 27,
+    "scribbled" => 24,
+    "scribbling" => 24,
+    "script" => 58,
+    "scripting" => 40,
+    "scripts" => 51,
+    "scriptural" => 30,
+    "scripture" => 27,
+    "scroll" => 48,
+    "scrollable" => 30,
+    "scrollbar" => 45,
+    "scrolled" => 40,
+    "scrolling" => 46,
+    "scrolls" => 39,
+    "scrub" => 29,
+    "scrubbed" => 24,
+    "scrubbing" => 24,
+    "scruples" => 27,
+    "scrupulous" => 24,
+    "scrupulously" => 24,
+    "scrutinised" => 29,
+    "scrutinized" => 33,
+    "scrutiny" => 30,
+    "scudded" => 24,
+    "scudding" => 24,
+    "scuffle" => 30,
+    "scuffling" => 24,
+    "sculpt" => 24,
+    "sculptor" => 24,
+    "sculptors" => 24,
+    "sculpture" => 24,
+    "scurried" => 24,
+    "scurvy" => 24,
+    "scuttle" => 27,
+    "sdp" => 30,
+    "sea" => 45,
+    "seaboard" => 27,
+    "seafaring" => 27,
+    "seagull" => 24,
+    "seal" => 32,
+    "sealed" => 38,
+    "sealing" => 27,
+    "seals" => 30,
+    "seaman" => 27,
+    "seamed" => 24,
+    "seamen" => 24,
+    "seamless" => 38,
+    "sean" => 37,
+    "seaports" => 24,
+    "sear" => 27,
+    "search" => 56,
+    "searched" => 46,
+    "searcher" => 36,
+    "searchers" => 32,
+    "searches" => 46,
+    "searching" => 49,
+    "searchingly" => 24,
+    "seared" => 27,
+    "seas" => 37,
+    "seashore" => 24,
+    "seaside" => 24,
+    "season" => 38,
+    "seasonable" => 24,
+    "seasoned" => 24,
+    "seasons" => 31,
+    "seat" => 40,
+    "seated" => 38,
+    "seating" => 24,
+    "seats" => 35,
+    "seaward" => 24,
+    "sebastian" => 33,
+    "sec" => 40,
+    "secant" => 24,
+    "secluded" => 31,
+    "seclusion" => 24,
+    "second" => 54,
+    "secondarily" => 30,
+    "secondary" => 41,
+    "secondly" => 35,
+    "seconds" => 47,
+    "secrecy" => 38,
+    "secret" => 48,
+    "secretary" => 30,
+    "secreted" => 24,
+    "secreting" => 24,
+    "secretive" => 24,
+    "secretly" => 32,
+    "secrets" => 41,
+    "sect" => 30,
+    "section" => 56,
+    "sections" => 48,
+    "sector" => 29,
+    "sectors" => 24,
+    "secure" => 48,
+    "secured" => 34,
+    "securely" => 39,
+    "securer" => 27,
+    "secures" => 27,
+    "securing" => 32,
+    "securities" => 29,
+    "security" => 51,
+    "sed" => 39,
+    "sedately" => 24,
+    "sedative" => 24,
+    "sedentary" => 27,
+    "sediment" => 24,
+    "seduction" => 24,
+    "seductions" => 24,
+    "seductive" => 24,
+    "sedulous" => 24,
+    "see" => 64,
+    "seed" => 44,
+    "seeded" => 35,
+    "seeder" => 27,
+    "seeding" => 31,
+    "seeds" => 36,
+    "seedy" => 27,
+    "seeing" => 43,
+    "seek" => 44,
+    "seeking" => 38,
+    "seeks" => 37,
+    "seem" => 45,
+    "seemed" => 49,
+    "seeming" => 32,
+    "seemingly" => 36,
+    "seems" => 46,
+    "seen" => 50,
+    "sees" => 42,
+    "segment" => 42,
+    "segmentation" => 38,
+    "segmented" => 31,
+    "segments" => 42,
+    "segregate" => 24,
+    "segregated" => 32,
+    "segue" => 24,
+    "seize" => 33,
+    "seized" => 41,
+    "seizes" => 24,
+    "seizing" => 30,
+    "seizure" => 24,
+    "seizures" => 24,
+    "seldom" => 37,
+    "select" => 52,
+    "selectable" => 24,
+    "selected" => 51,
+    "selecting" => 46,
+    "selection" => 51,
+    "selections" => 36,
+    "selective" => 34,
+    "selectively" => 35,
+    "selectivity" => 24,
+    "selector" => 43,
+    "selectors" => 40,
+    "selects" => 42,
+    "self" => 52,
+    "selfish" => 32,
+    "selfishness" => 32,
+    "sell" => 39,
+    "seller" => 24,
+    "sellers" => 24,
+    "selling" => 34,
+    "selves" => 24,
+    "semantic" => 45,
+    "semantically" => 42,
+    "semantics" => 48,
+    "semaphore" => 33,
+    "semaphores" => 24,
+    "semblance" => 24,
+    "semi" => 39,
+    "semicircle" => 24,
+    "semicolon" => 43,
+    "semicolons" => 36,
+    "semiconductors" => 24,
+    "semiotics" => 24,
+    "senate" => 37,
+    "senator" => 33,
+    "senators" => 35,
+    "send" => 52,
+    "sender" => 42,
+    "senders" => 35,
+    "sending" => 48,
+    "sends" => 46,
+    "senile" => 27,
+    "senility" => 24,
+    "senior" => 35,
+    "sensation" => 37,
+    "sensational" => 29,
+    "sensationalism" => 27,
+    "sensations" => 39,
+    "sense" => 48,
+    "senseless" => 33,
+    "senselessness" => 24,
+    "senses" => 35,
+    "sensibilities" => 29,
+    "sensibility" => 29,
+    "sensible" => 38,
+    "sensibly" => 32,
+    "sensitive" => 45,
+    "sensitively" => 24,
+    "sensitiveness" => 29,
+    "sensitivity" => 37,
+    "sensor" => 29,
+    "sensors" => 32,
+    "sensuous" => 27,
+    "sensuously" => 24,
+    "sent" => 51,
+    "sentence" => 44,
+    "sentenced" => 24,
+    "sentences" => 38,
+    "sentiment" => 34,
+    "sentimental" => 31,
+    "sentimentality" => 24,
+    "sentiments" => 32,
+    "sentinel" => 36,
+    "sentinels" => 27,
+    "sentry" => 44,
+    "seoul" => 24,
+    "separable" => 24,
+    "separate" => 52,
+    "separated" => 50,
+    "separately" => 46,
+    "separates" => 38,
+    "separating" => 38,
+    "separation" => 38,
+    "separator" => 47,
+    "separators" => 44,
+    "sepoy" => 29,
+    "sept" => 30,
+    "september" => 37,
+    "septic" => 24,
+    "sepulchral" => 24,
+    "sepulchre" => 24,
+    "sequel" => 27,
+    "sequence" => 53,
+    "sequencer" => 27,
+    "sequences" => 49,
+    "sequencing" => 29,
+    "sequential" => 41,
+    "sequentially" => 39,
+    "sequoia" => 24,
+    "ser" => 36,
+    "serape" => 24,
+    "serbian" => 29,
+    "serendipity" => 29,
+    "serene" => 34,
+    "serenely" => 29,
+    "serenity" => 35,
+    "serfs" => 24,
+    "serge" => 27,
+    "sergeant" => 30,
+    "sergei" => 27,
+    "serial" => 45,
+    "serialisation" => 32,
+    "serialise" => 30,
+    "serialised" => 31,
+    "serialising" => 32,
+    "serialization" => 51,
+    "serializations" => 27,
+    "serialize" => 46,
+    "serialized" => 47,
+    "serializes" => 38,
+    "serializing" => 45,
+    "serially" => 32,
+    "serials" => 30,
+    "series" => 47,
+    "serif" => 29,
+    "serious" => 42,
+    "seriously" => 38,
+    "seriousness" => 24,
+    "sermon" => 35,
+    "sermons" => 27,
+    "serpent" => 35,
+    "serpentine" => 33,
+    "serpents" => 30,
+    "servant" => 42,
+    "servants" => 39,
+    "serve" => 45,
+    "served" => 41,
+    "server" => 56,
+    "servers" => 46,
+    "serves" => 43,
+    "service" => 50,
+    "serviceability" => 24,
+    "serviceable" => 30,
+    "serviced" => 29,
+    "services" => 47,
+    "servicing" => 24,
+    "serving" => 41,
+    "servitude" => 29,
+    "servo" => 38,
+    "session" => 51,
+    "sessions" => 43,
+    "set" => 63,
+    "sets" => 54,
+    "settable" => 24,
+    "settee" => 29,
+    "setter" => 41,
+    "setters" => 40,
+    "setting" => 55,
+    "settings" => 51,
+    "settle" => 38,
+    "settled" => 40,
+    "settlement" => 27,
+    "settler" => 24,
+    "settlers" => 27,
+    "settles" => 29,
+    "settling" => 30,
+    "setup" => 48,
+    "setups" => 35,
+    "seven" => 44,
+    "seventeen" => 33,
+    "seventeenth" => 27,
+    "seventh" => 30,
+    "seventy" => 36,
+    "several" => 54,
+    "severe" => 36,
+    "severed" => 27,
+    "severely" => 35,
+    "severer" => 24,
+    "severing" => 24,
+    "severity" => 36,
+    "severn" => 24,
+    "sew" => 27,
+    "sewed" => 30,
+    "sewing" => 24,
+    "sewn" => 24,
+    "sews" => 27,
+    "sex" => 32,
+    "sexes" => 29,
+    "sexy" => 24,
+    "shabbier" => 27,
+    "shabbily" => 24,
+    "shabby" => 32,
+    "shackles" => 27,
+    "shade" => 38,
+    "shaded" => 32,
+    "shades" => 32,
+    "shading" => 32,
+    "shadow" => 46,
+    "shadowed" => 40,
+    "shadowing" => 41,
+    "shadows" => 38,
+    "shadowy" => 24,
+    "shady" => 24,
+    "shaft" => 24,
+    "shag" => 30,
+    "shah" => 24,
+    "shake" => 39,
+    "shaken" => 34,
+    "shakes" => 24,
+    "shakespeare" => 30,
+    "shaking" => 38,
+    "shaky" => 29,
+    "shall" => 56,
+    "shallow" => 40,
+    "shallows" => 24,
+    "sham" => 24,
+    "shame" => 33,
+    "shamefaced" => 27,
+    "shameful" => 24,
+    "shamefully" => 29,
+    "shameless" => 24,
+    "shamelessly" => 24,
+    "shan't" => 32,
+    "shane" => 33,
+    "shanghai" => 36,
+    "shank" => 35,
+    "shanks's" => 24,
+    "shannon" => 29,
+    "shanty" => 24,
+    "shape" => 46,
+    "shaped" => 36,
+    "shapeless" => 29,
+    "shapely" => 24,
+    "shaper" => 32,
+    "shapes" => 41,
+    "shaping" => 40,
+    "sharable" => 27,
+    "shard" => 38,
+    "shards" => 37,
+    "share" => 49,
+    "shareable" => 32,
+    "shared" => 53,
+    "shares" => 40,
+    "shareware" => 24,
+    "sharing" => 43,
+    "shark" => 24,
+    "sharks" => 30,
+    "sharon" => 24,
+    "sharp" => 43,
+    "sharpened" => 24,
+    "sharpening" => 24,
+    "sharper" => 29,
+    "sharply" => 36,
+    "shatter" => 29,
+    "shattered" => 35,
+    "shaun" => 27,
+    "shave" => 32,
+    "shaved" => 29,
+    "shaven" => 29,
+    "shaves" => 27,
+    "shaving" => 30,
+    "shaw" => 30,
+    "shawl" => 30,
+    "shawls" => 24,
+    "she" => 57,
+    "she'd" => 37,
+    "she'll" => 35,
+    "she's" => 41,
+    "shear" => 24,
+    "shears" => 24,
+    "sheathed" => 24,
+    "sheathing" => 24,
+    "sheaves" => 24,
+    "shebang" => 39,
+    "shed" => 36,
+    "shedding" => 30,
+    "sheen" => 24,
+    "sheep" => 30,
+    "sheepish" => 24,
+    "sheepishly" => 29,
+    "sheer" => 32,
+    "sheered" => 24,
+    "sheet" => 41,
+    "sheets" => 36,
+    "sheik" => 24,
+    "shelf" => 36,
+    "shell" => 52,
+    "shelley" => 27,
+    "shelling" => 24,
+    "shells" => 40,
+    "shelter" => 35,
+    "sheltered" => 31,
+    "sheltering" => 24,
+    "shelved" => 24,
+    "shelves" => 35,
+    "shenanigans" => 27,
+    "shepherd" => 27,
+    "shepherd's" => 27,
+    "sheridan" => 27,
+    "sheriff" => 32,
+    "sheriffs" => 24,
+    "sherlock" => 44,
+    "sherry" => 30,
+    "sherwood" => 27,
+    "shield" => 39,
+    "shields" => 31,
+    "shift" => 51,
+    "shifted" => 43,
+    "shifting" => 46,
+    "shiftless" => 24,
+    "shiftlessness" => 24,
+    "shifts" => 43,
+    "shilling" => 29,
+    "shillings" => 33,
+    "shim" => 37,
+    "shimmer" => 32,
+    "shimmering" => 27,
+    "shimming" => 24,
+    "shims" => 33,
+    "shin" => 34,
+    "shine" => 32,
+    "shines" => 29,
+    "shingle" => 30,
+    "shingles" => 24,
+    "shining" => 38,
+    "shiny" => 32,
+    "ship" => 44,
+    "ship's" => 29,
+    "shipped" => 39,
+    "shipping" => 34,
+    "ships" => 40,
+    "shipwreck" => 24,
+    "shipwrecks" => 24,
+    "shirt" => 38,
+    "shirts" => 35,
+    "shirtwaist" => 27,
+    "shiva" => 24,
+    "shiver" => 33,
+    "shivered" => 30,
+    "shivering" => 34,
+    "shoal" => 27,
+    "shock" => 36,
+    "shocked" => 31,
+    "shocking" => 29,
+    "shocks" => 30,
+    "shoe" => 31,
+    "shoeless" => 24,
+    "shoes" => 40,
+    "shone" => 36,
+    "shooing" => 24,
+    "shook" => 42,
+    "shoot" => 31,
+    "shooter" => 24,
+    "shooting" => 29,
+    "shoots" => 24,
+    "shop" => 35,
+    "shopping" => 29,
+    "shops" => 29,
+    "shore" => 40,
+    "shores" => 33,
+    "shorn" => 27,
+    "short" => 51,
+    "shortage" => 27,
+    "shortcoming" => 24,
+    "shortcomings" => 31,
+    "shortcut" => 42,
+    "shortcuts" => 39,
+    "shorten" => 39,
+    "shortened" => 38,
+    "shortening" => 34,
+    "shortens" => 32,
+    "shorter" => 44,
+    "shortest" => 36,
+    "shorthand" => 43,
+    "shortly" => 40,
+    "shorts" => 24,
+    "shortsighted" => 24,
+    "shot" => 42,
+    "shots" => 33,
+    "should" => 61,
+    "shoulder" => 41,
+    "shouldered" => 27,
+    "shoulders" => 41,
+    "shouldn't" => 44,
+    "shout" => 35,
+    "shouted" => 40,
+    "shouting" => 33,
+    "shouts" => 29,
+    "shove" => 24,
+    "shoved" => 24,
+    "shovel" => 32,
+    "shovels" => 27,
+    "shoves" => 24,
+    "shoving" => 27,
+    "show" => 54,
+    "showcase" => 36,
+    "showcased" => 24,
+    "showcases" => 38,
+    "showcasing" => 33,
+    "showed" => 43,
+    "shower" => 31,
+    "showered" => 24,
+    "showers" => 24,
+    "showing" => 46,
+    "showman" => 24,
+    "shown" => 51,
+    "shows" => 52,
+    "showy" => 24,
+    "shrank" => 27,
+    "shred" => 29,
+    "shreds" => 24,
+    "shrewd" => 31,
+    "shrewdly" => 24,
+    "shrewdness" => 24,
+    "shriek" => 33,
+    "shrieked" => 34,
+    "shrieks" => 29,
+    "shrift" => 24,
+    "shrill" => 35,
+    "shrilly" => 24,
+    "shrimp" => 27,
+    "shrine" => 32,
+    "shrink" => 41,
+    "shrinking" => 38,
+    "shrinks" => 37,
+    "shrivelled" => 24,
+    "shroud" => 27,
+    "shrouded" => 24,
+    "shrubbery" => 32,
+    "shrubs" => 29,
+    "shrug" => 24,
+    "shrugged" => 34,
+    "shrugging" => 27,
+    "shrunk" => 35,
+    "shucks" => 33,
+    "shudder" => 35,
+    "shuddered" => 35,
+    "shuddering" => 30,
+    "shuffle" => 44,
+    "shuffled" => 29,
+    "shuffles" => 38,
+    "shuffling" => 30,
+    "shun" => 27,
+    "shunned" => 30,
+    "shunting" => 24,
+    "shut" => 43,
+    "shutdown" => 40,
+    "shuts" => 34,
+    "shutter" => 30,
+    "shuttered" => 27,
+    "shutters" => 35,
+    "shutting" => 35,
+    "shuttle" => 27,
+    "shuttlecocks" => 24,
+    "shy" => 32,
+    "shying" => 24,
+    "shyly" => 27,
+    "siam" => 24,
+    "siberian" => 24,
+    "sibilants" => 24,
+    "sibling" => 39,
+    "siblings" => 35,
+    "sichuan" => 24,
+    "sick" => 38,
+    "sickbed" => 27,
+    "sicken" => 24,
+    "sickened" => 31,
+    "sickening" => 31,
+    "sickness" => 34,
+    "sid" => 45,
+    "side" => 53,
+    "sideboard" => 32,
+    "sided" => 35,
+    "sidelong" => 24,
+    "sides" => 42,
+    "sidesteps" => 24,
+    "sidewalk" => 32,
+    "sidewalks" => 27,
+    "sideways" => 32,
+    "sidewise" => 24,
+    "sidled" => 24,
+    "sidling" => 24,
+    "sidney" => 24,
+    "siege" => 24,
+    "sierra" => 32,
+    "sieve" => 35,
+    "sift" => 29,
+    "sifting" => 29,
+    "sifts" => 24,
+    "sigh" => 35,
+    "sighed" => 33,
+    "sighing" => 33,
+    "sighs" => 24,
+    "sight" => 44,
+    "sighted" => 29,
+    "sights" => 33,
+    "sightseeing" => 24,
+    "sigma" => 40,
+    "sign" => 54,
+    "signal" => 50,
+    "signaled" => 29,
+    "signaling" => 43,
+    "signalled" => 30,
+    "signalling" => 27,
+    "signals" => 45,
+    "signatory" => 27,
+    "signature" => 51,
+    "signatures" => 47,
+    "signed" => 54,
+    "signer" => 39,
+    "signers" => 32,
+    "signet" => 27,
+    "significance" => 34,
+    "significant" => 49,
+    "significantly" => 44,
+    "signification" => 27,
+    "signified" => 27,
+    "signifies" => 34,
+    "signify" => 34,
+    "signifying" => 29,
+    "signing" => 43,
+    "signpost" => 24,
+    "signs" => 46,
+    "silence" => 45,
+    "silenced" => 32,
+    "silences" => 27,
+    "silencing" => 24,
+    "silent" => 48,
+    "silently" => 45,
+    "silhouette" => 29,
+    "silicon" => 37,
+    "silk" => 36,
+    "silken" => 31,
+    "sill" => 33,
+    "silly" => 33,
+    "silo" => 27,
+    "silos" => 30,
+    "silver" => 40,
+    "silvered" => 24,
+    "similar" => 55,
+    "similarities" => 32,
+    "similarity" => 37,
+    "similarly" => 48,
+    "simmering" => 24,
+    "simon" => 41,
+    "simpering" => 24,
+    "simple" => 55,
+    "simpler" => 45,
+    "simplest" => 44,
+    "simpleton" => 24,
+    "simplex" => 30,
+    "simplicity" => 42,
+    "simplification" => 34,
+    "simplifications" => 29,
+    "simplified" => 44,
+    "simplifier" => 24,
+    "simplifies" => 40,
+    "simplify" => 45,
+    "simplifying" => 36,
+    "simplistic" => 37,
+    "simply" => 52,
+    "simpson" => 24,
+    "simulacra" => 29,
+    "simulate" => 40,
+    "simulated" => 36,
+    "simulates" => 33,
+    "simulating" => 36,
+    "simulation" => 38,
+    "simulations" => 27,
+    "simulator" => 40,
+    "simultaneous" => 36,
+    "simultaneously" => 43,
+    "sin" => 37,
+    "sinai" => 24,
+    "since" => 56,
+    "sincere" => 32,
+    "sincerely" => 33,
+    "sincerest" => 24,
+    "sincerity" => 27,
+    "sinclair" => 27,
+    "sine" => 37,
+    "sinewy" => 24,
+    "sinful" => 24,
+    "sing" => 36,
+    "singapore" => 36,
+    "singed" => 24,
+    "singer" => 27,
+    "singers" => 27,
+    "singh" => 27,
+    "singing" => 35,
+    "single" => 58,
+    "singleton" => 39,
+    "singletons" => 32,
+    "singly" => 31,
+    "sings" => 29,
+    "singular" => 42,
+    "singularity" => 24,
+    "singularly" => 31,
+    "sinh" => 32,
+    "sinhalese" => 24,
+    "sinister" => 36,
+    "sink" => 41,
+    "sinking" => 33,
+    "sinks" => 33,
+    "sinless" => 24,
+    "sinned" => 27,
+    "sins" => 33,
+    "sinuous" => 24,
+    "sioux" => 36,
+    "sip" => 35,
+    "sir" => 48,
+    "sister" => 43,
+    "sisters" => 30,
+    "sit" => 42,
+    "site" => 46,
+    "sites" => 39,
+    "sits" => 35,
+    "sitter" => 42,
+    "sitting" => 43,
+    "situated" => 35,
+    "situation" => 48,
+    "situations" => 49,
+    "sive" => 24,
+    "six" => 47,
+    "sixpence" => 32,
+    "sixteen" => 40,
+    "sixteenth" => 27,
+    "sixth" => 33,
+    "sixths" => 24,
+    "sixtieth" => 24,
+    "sixty" => 38,
+    "sizable" => 30,
+    "size" => 57,
+    "sized" => 49,
+    "sizes" => 45,
+    "sizing" => 35,
+    "skeleton" => 35,
+    "skeletons" => 24,
+    "sketch" => 33,
+    "sketched" => 27,
+    "sketches" => 29,
+    "sketching" => 27,
+    "sketchy" => 24,
+    "skew" => 33,
+    "skewed" => 29,
+    "skewing" => 27,
+    "ski" => 24,
+    "skid" => 27,
+    "skies" => 31,
+    "skiff" => 35,
+    "skiffs" => 29,
+    "skilful" => 31,
+    "skilfully" => 24,
+    "skill" => 35,
+    "skilled" => 27,
+    "skillet" => 24,
+    "skills" => 34,
+    "skim" => 34,
+    "skimmed" => 29,
+    "skimming" => 29,
+    "skims" => 24,
+    "skin" => 38,
+    "skinned" => 29,
+    "skinning" => 24,
+    "skinny" => 24,
+    "skins" => 24,
+    "skip" => 52,
+    "skipped" => 46,
+    "skipper" => 27,
+    "skipping" => 45,
+    "skips" => 41,
+    "skirmishes" => 24,
+    "skirt" => 30,
+    "skirted" => 29,
+    "skirting" => 27,
+    "skirts" => 29,
+    "skittishness" => 24,
+    "skr" => 24,
+    "skull" => 29,
+    "sky" => 41,
+    "skylark" => 24,
+    "skylarking" => 30,
+    "skylight" => 30,
+    "slab" => 39,
+    "slabs" => 31,
+    "slack" => 41,
+    "slacken" => 27,
+    "slackened" => 31,
+    "slackening" => 27,
+    "slain" => 24,
+    "slaked" => 24,
+    "slam" => 27,
+    "slammed" => 30,
+    "slander" => 27,
+    "slang" => 27,
+    "slant" => 29,
+    "slanting" => 24,
+    "slap" => 29,
+    "slapped" => 27,
+    "slapping" => 27,
+    "slash" => 45,
+    "slashed" => 24,
+    "slashes" => 41,
+    "slate" => 36,
+    "slated" => 29,
+    "slates" => 32,
+    "slaughter" => 33,
+    "slave" => 36,
+    "slavery" => 31,
+    "slaves" => 24,
+    "slavic" => 27,
+    "slavonic" => 29,
+    "sled" => 35,
+    "sledge" => 39,
+    "sledgehammer" => 34,
+    "sledges" => 29,
+    "sleek" => 24,
+    "sleep" => 47,
+    "sleeper" => 30,
+    "sleepers" => 27,
+    "sleepiest" => 24,
+    "sleepily" => 24,
+    "sleeping" => 39,
+    "sleepless" => 29,
+    "sleeplessness" => 24,
+    "sleeps" => 34,
+    "sleepy" => 35,
+    "sleeve" => 33,
+    "sleeves" => 33,
+    "sleigh" => 27,
+    "slender" => 34,
+    "slenderly" => 27,
+    "slept" => 38,
+    "sleuth" => 24,
+    "slew" => 27,
+    "slice" => 56,
+    "sliceable" => 24,
+    "sliced" => 32,
+    "slices" => 50,
+    "slicing" => 40,
+    "slick" => 27,
+    "slicked" => 24,
+    "slid" => 32,
+    "slide" => 35,
+    "slides" => 35,
+    "sliding" => 29,
+    "slight" => 41,
+    "slighted" => 24,
+    "slighter" => 24,
+    "slightest" => 33,
+    "slightly" => 46,
+    "slim" => 33,
+    "slims" => 24,
+    "slink" => 27,
+    "slip" => 38,
+    "slipped" => 39,
+    "slipper" => 27,
+    "slippers" => 31,
+    "slippery" => 27,
+    "slipping" => 33,
+    "slips" => 24,
+    "slit" => 31,
+    "slits" => 24,
+    "slitting" => 27,
+    "sloane" => 34,
+    "slog" => 41,
+    "slogan" => 27,
+    "sloggers" => 30,
+    "sloop" => 24,
+    "slop" => 32,
+    "slope" => 33,
+    "slopes" => 24,
+    "sloping" => 29,
+    "sloppy" => 27,
+    "slops" => 27,
+    "slosh" => 24,
+    "slot" => 42,
+    "sloth" => 24,
+    "slothful" => 24,
+    "slots" => 44,
+    "slotted" => 27,
+    "slouch" => 27,
+    "slough" => 24,
+    "slovak" => 32,
+    "slovenian" => 29,
+    "slovenly" => 31,
+    "slow" => 50,
+    "slowdown" => 32,
+    "slowdowns" => 27,
+    "slowed" => 33,
+    "slower" => 47,
+    "slowest" => 34,
+    "slowing" => 24,
+    "slowly" => 43,
+    "slowness" => 29,
+    "slows" => 36,
+    "slug" => 33,
+    "sluggard" => 24,
+    "sluggishly" => 24,
+    "sluggishness" => 24,
+    "slugs" => 27,
+    "sluice" => 24,
+    "slum" => 24,
+    "slumber" => 27,
+    "slumbered" => 24,
+    "slumbering" => 24,
+    "slumbers" => 29,
+    "slump" => 24,
+    "slums" => 27,
+    "slung" => 29,
+    "slunk" => 27,
+    "slur" => 24,
+    "slurp" => 24,
+    "slurped" => 24,
+    "slurping" => 24,
+    "slurred" => 24,
+    "slurring" => 24,
+    "slut" => 24,
+    "sly" => 32,
+    "smack" => 33,
+    "smacks" => 24,
+    "small" => 55,
+    "smaller" => 49,
+    "smallest" => 43,
+    "smallish" => 24,
+    "smart" => 48,
+    "smarter" => 34,
+    "smartest" => 24,
+    "smarting" => 24,
+    "smartness" => 24,
+    "smarts" => 24,
+    "smash" => 24,
+    "smashed" => 30,
+    "smasher" => 24,
+    "smashing" => 32,
+    "smattering" => 24,
+    "smear" => 24,
+    "smeared" => 30,
+    "smearing" => 27,
+    "smell" => 33,
+    "smelling" => 32,
+    "smelt" => 27,
+    "smile" => 40,
+    "smiled" => 39,
+    "smiles" => 36,
+    "smiling" => 38,
+    "smirk" => 24,
+    "smith" => 38,
+    "smithsonian" => 29,
+    "smithy" => 41,
+    "smitten" => 27,
+    "smoke" => 41,
+    "smoked" => 34,
+    "smokeless" => 24,
+    "smoker" => 24,
+    "smokers" => 27,
+    "smokes" => 27,
+    "smoking" => 37,
+    "smoky" => 24,
+    "smooth" => 38,
+    "smoothed" => 32,
+    "smoother" => 29,
+    "smoothing" => 32,
+    "smoothly" => 35,
+    "smoothness" => 24,
+    "smorgasbord" => 24,
+    "smote" => 29,
+    "smothered" => 31,
+    "smothering" => 24,
+    "smothers" => 24,
+    "smouldering" => 27,
+    "smudge" => 32,
+    "smuggled" => 24,
+    "smuggling" => 27,
+    "snafu" => 27,
+    "snag" => 24,
+    "snail" => 29,
+    "snail's" => 24,
+    "snake" => 42,
+    "snaked" => 24,
+    "snakes" => 27,
+    "snap" => 37,
+    "snapped" => 33,
+    "snapping" => 24,
+    "snappishly" => 24,
+    "snappy" => 37,
+    "snaps" => 27,
+    "snapshot" => 47,
+    "snapshots" => 42,
+    "snark" => 35,
+    "snarl" => 27,
+    "snarled" => 27,
+    "snarling" => 24,
+    "snatch" => 33,
+    "snatched" => 36,
+    "snatches" => 29,
+    "snatching" => 24,
+    "snazzy" => 27,
+    "sneak" => 29,
+    "sneaked" => 31,
+    "sneakers" => 24,
+    "sneaking" => 24,
+    "sneaky" => 27,
+    "sneer" => 32,
+    "sneering" => 24,
+    "sneeze" => 30,
+    "sneezed" => 24,
+    "sneezes" => 27,
+    "sneezing" => 32,
+    "snell" => 24,
+    "sniff" => 39,
+    "sniffing" => 29,
+    "snifter" => 24,
+    "snigger" => 24,
+    "snippet" => 42,
+    "snippets" => 40,
+    "snob" => 24,
+    "snobbery" => 24,
+    "snobbishly" => 27,
+    "snobs" => 24,
+    "snooper" => 24,
+    "snoopers" => 24,
+    "snore" => 31,
+    "snored" => 24,
+    "snorer" => 24,
+    "snores" => 27,
+    "snoring" => 30,
+    "snort" => 27,
+    "snorted" => 24,
+    "snorting" => 24,
+    "snout" => 24,
+    "snow" => 41,
+    "snowball" => 29,
+    "snowflake" => 37,
+    "snowing" => 29,
+    "snows" => 29,
+    "snowy" => 33,
+    "snub" => 24,
+    "snuck" => 24,
+    "snuff" => 30,
+    "snuffbox" => 24,
+    "snuffle" => 27,
+    "snuffled" => 27,
+    "snuffling" => 27,
+    "snug" => 31,
+    "snuggest" => 24,
+    "snugly" => 24,
+    "so" => 62,
+    "soaked" => 31,
+    "soap" => 29,
+    "soar" => 24,
+    "soared" => 24,
+    "soaring" => 27,
+    "sob" => 32,
+    "sobbed" => 33,
+    "sobbing" => 33,
+    "sober" => 32,
+    "sobered" => 27,
+    "sobs" => 34,
+    "social" => 37,
+    "socials" => 24,
+    "societal" => 27,
+    "societies" => 32,
+    "society" => 41,
+    "sock" => 34,
+    "socket" => 53,
+    "sockets" => 46,
+    "socks" => 39,
+    "sod" => 24,
+    "soda" => 30,
+    "sodden" => 27,
+    "sodium" => 33,
+    "sofa" => 36,
+    "soft" => 43,
+    "soften" => 27,
+    "softened" => 30,
+    "softer" => 29,
+    "softly" => 36,
+    "softness" => 27,
+    "software" => 52,
+    "softy" => 24,
+    "soggy" => 27,
+    "soh" => 29,
+    "soil" => 33,
+    "soiled" => 24,
+    "sojourning" => 24,
+    "solar" => 24,
+    "solaris" => 43,
+    "sold" => 38,
+    "solder" => 24,
+    "soldier" => 33,
+    "soldiers" => 38,
+    "sole" => 37,
+    "soled" => 24,
+    "solely" => 39,
+    "solemn" => 39,
+    "solemnisation" => 27,
+    "solemnising" => 24,
+    "solemnities" => 27,
+    "solemnity" => 32,
+    "solemnly" => 35,
+    "soles" => 30,
+    "solicit" => 24,
+    "solicitation" => 27,
+    "solicitations" => 24,
+    "solicited" => 27,
+    "soliciting" => 24,
+    "solicitor" => 24,
+    "solicitude" => 24,
+    "solid" => 41,
+    "solidarity" => 24,
+    "solidified" => 24,
+    "solidity" => 41,
+    "solidly" => 24,
+    "solidus" => 27,
+    "soliloquized" => 27,
+    "solitary" => 36,
+    "solitude" => 36,
+    "solitudes" => 24,
+    "solo" => 30,
+    "solomon" => 35,
+    "solution" => 66,
+    "solutions" => 42,
+    "solvable" => 24,
+    "solve" => 44,
+    "solved" => 41,
+    "solver" => 38,
+    "solvers" => 36,
+    "solves" => 38,
+    "solving" => 38,
+    "somali" => 24,
+    "somalis" => 24,
+    "sombre" => 33,
+    "sombrero" => 24,
+    "sombreros" => 24,
+    "some" => 62,
+    "somebody" => 41,
+    "someday" => 30,
+    "somehow" => 41,
+    "someone" => 45,
+    "somersault" => 27,
+    "somersaults" => 24,
+    "something" => 54,
+    "sometime" => 32,
+    "sometimes" => 52,
+    "somewhat" => 45,
+    "somewhere" => 46,
+    "son" => 43,
+    "song" => 37,
+    "songbird" => 35,
+    "songs" => 33,
+    "sonic" => 30,
+    "sonny" => 24,
+    "sons" => 34,
+    "sony" => 35,
+    "soon" => 50,
+    "sooner" => 39,
+    "soothe" => 31,
+    "soothed" => 32,
+    "soothing" => 35,
+    "soothingly" => 24,
+    "sophie" => 27,
+    "sophism" => 27,
+    "sophisms" => 24,
+    "sophisticated" => 39,
+    "sophistication" => 24,
+    "soprano" => 24,
+    "sorcerer" => 24,
+    "sorcery" => 24,
+    "sore" => 33,
+    "sorely" => 32,
+    "sorensen" => 24,
+    "sorer" => 24,
+    "sorrow" => 37,
+    "sorrowful" => 34,
+    "sorrowfully" => 27,
+    "sorrowing" => 27,
+    "sorrows" => 35,
+    "sorry" => 41,
+    "sort" => 50,
+    "sorted" => 47,
+    "sorting" => 45,
+    "sorts" => 42,
+    "sos" => 24,
+    "sots" => 29,
+    "sought" => 39,
+    "soul" => 42,
+    "soulless" => 27,
+    "souls" => 35,
+    "sound" => 49,
+    "sounded" => 38,
+    "sounding" => 29,
+    "soundly" => 35,
+    "soundness" => 41,
+    "sounds" => 42,
+    "soup" => 38,
+    "sour" => 31,
+    "source" => 57,
+    "sources" => 49,
+    "souring" => 24,
+    "south" => 41,
+    "southampton" => 32,
+    "southeast" => 24,
+    "southeastern" => 24,
+    "southerly" => 24,
+    "southern" => 36,
+    "southward" => 30,
+    "southwards" => 29,
+    "southwestern" => 24,
+    "souvenir" => 27,
+    "souvenirs" => 24,
+    "sovereign" => 32,
+    "sovereigns" => 24,
+    "sow" => 24,
+    "sown" => 27,
+    "spa" => 27,
+    "space" => 55,
+    "spacebar" => 24,
+    "spaced" => 31,
+    "spaces" => 50,
+    "spaceship" => 24,
+    "spacing" => 39,
+    "spacings" => 24,
+    "spacious" => 29,
+    "spade" => 34,
+    "spades" => 30,
+    "spam" => 36,
+    "span" => 47,
+    "spangles" => 24,
+    "spaniard" => 35,
+    "spanish" => 37,
+    "spanking" => 24,
+    "spanned" => 32,
+    "spanning" => 34,
+    "spans" => 44,
+    "spare" => 38,
+    "spared" => 33,
+    "spares" => 24,
+    "sparingly" => 33,
+    "spark" => 34,
+    "sparkle" => 27,
+    "sparkled" => 31,
+    "sparkles" => 30,
+    "sparkling" => 27,
+    "sparrow" => 24,
+    "sparrows" => 24,
+    "spars" => 24,
+    "sparse" => 43,
+    "spas" => 27,
+    "spasm" => 27,
+    "spasmodic" => 24,
+    "spasms" => 24,
+    "spat" => 27,
+    "spatial" => 36,
+    "spattered" => 27,
+    "spawn" => 43,
+    "spawned" => 45,
+    "spawning" => 43,
+    "spawns" => 38,
+    "speak" => 44,
+    "speaker" => 35,
+    "speakers" => 41,
+    "speaking" => 42,
+    "speaks" => 33,
+    "spec" => 49,
+    "special" => 55,
+    "specialisation" => 27,
+    "specialised" => 27,
+    "specialist" => 30,
+    "specialists" => 29,
+    "speciality" => 24,
+    "specialization" => 37,
+    "specialize" => 30,
+    "specialized" => 42,
+    "specializes" => 29,
+    "specializing" => 24,
+    "specially" => 38,
+    "specials" => 27,
+    "specialties" => 24,
+    "species" => 35,
+    "specifiable" => 24,
+    "specific" => 57,
+    "specifically" => 50,
+    "specification" => 51,
+    "specifications" => 43,
+    "specificity" => 29,
+    "specifics" => 38,
+    "specified" => 58,
+    "specifier" => 45,
+    "specifiers" => 42,
+    "specifies" => 51,
+    "specify" => 54,
+    "specifying" => 50,
+    "specimen" => 30,
+    "specimens" => 24,
+    "speciously" => 24,
+    "speck" => 31,
+    "specked" => 24,
+    "speckled" => 32,
+    "speckles" => 24,
+    "specs" => 39,
+    "spectacle" => 35,
+    "spectacled" => 24,
+    "spectacles" => 34,
+    "spectators" => 31,
+    "specter" => 24,
+    "spectra" => 27,
+    "spectral" => 31,
+    "spectre" => 33,
+    "spectroscopic" => 24,
+    "spectrum" => 34,
+    "speculated" => 27,
+    "speculation" => 32,
+    "speculations" => 24,
+    "speculative" => 30,
+    "speculatively" => 37,
+    "sped" => 34,
+    "speech" => 44,
+    "speeches" => 24,
+    "speechless" => 30,
+    "speed" => 49,
+    "speedily" => 36,
+    "speeding" => 34,
+    "speeds" => 39,
+    "speedup" => 38,
+    "speedy" => 38,
+    "spell" => 50,
+    "spellbound" => 24,
+    "spellchecker" => 29,
+    "spelled" => 37,
+    "spelling" => 42,
+    "spellings" => 29,
+    "spencer" => 24,
+    "spend" => 40,
+    "spender" => 24,
+    "spending" => 35,
+    "spends" => 24,
+    "spent" => 44,
+    "spew" => 24,
+    "spews" => 27,
+    "sphere" => 34,
+    "spheres" => 24,
+    "spherical" => 29,
+    "sphinx" => 24,
+    "spice" => 33,
+    "spiced" => 29,
+    "spider" => 24,
+    "spidery" => 24,
+    "spied" => 29,
+    "spies" => 27,
+    "spike" => 24,
+    "spikes" => 24,
+    "spill" => 30,
+    "spilled" => 31,
+    "spilling" => 24,
+    "spills" => 29,
+    "spilt" => 24,
+    "spin" => 40,
+    "spine" => 24,
+    "spinner" => 30,
+    "spinners" => 30,
+    "spinning" => 36,
+    "spinster" => 24,
+    "spirals" => 27,
+    "spire" => 29,
+    "spires" => 29,
+    "spirit" => 42,
+    "spirited" => 24,
+    "spiritless" => 24,
+    "spirits" => 41,
+    "spiritual" => 29,
+    "spit" => 27,
+    "spite" => 38,
+    "spits" => 32,
+    "splash" => 38,
+    "splashed" => 31,
+    "splashing" => 30,
+    "splat" => 37,
+    "splay" => 32,
+    "splendid" => 36,
+    "splendidly" => 27,
+    "splendor" => 33,
+    "splendour" => 27,
+    "spliced" => 24,
+    "splicing" => 29,
+    "splines" => 29,
+    "splint" => 34,
+    "splintered" => 24,
+    "split" => 51,
+    "splits" => 43,
+    "splitted" => 24,
+    "splitting" => 45,
+    "splutter" => 24,
+    "spoil" => 24,
+    "spoiled" => 31,
+    "spoiler" => 32,
+    "spoils" => 24,
+    "spoke" => 44,
+    "spoken" => 40,
+    "spokes" => 27,
+    "sponge" => 33,
+    "sponged" => 29,
+    "spongy" => 24,
+    "sponsor" => 40,
+    "sponsored" => 36,
+    "sponsoring" => 38,
+    "sponsors" => 43,
+    "sponsorship" => 34,
+    "sponsorships" => 24,
+    "spontaneously" => 29,
+    "spoofing" => 30,
+    "spooky" => 27,
+    "spool" => 24,
+    "spoon" => 27,
+    "spoonful" => 24,
+    "spooning" => 27,
+    "sporadic" => 29,
+    "sporadically" => 27,
+    "sport" => 41,
+    "sporting" => 24,
+    "sports" => 32,
+    "sportswoman" => 24,
+    "spot" => 44,
+    "spots" => 32,
+    "spotted" => 34,
+    "spotty" => 24,
+    "spouse" => 24,
+    "spout" => 24,
+    "spouting" => 27,
+    "sprague" => 24,
+    "sprang" => 40,
+    "sprawl" => 24,
+    "sprawled" => 24,
+    "sprawling" => 32,
+    "spray" => 27,
+    "sprays" => 24,
+    "spread" => 44,
+    "spreading" => 36,
+    "spreads" => 27,
+    "spreadsheet" => 35,
+    "spreadsheets" => 29,
+    "spree" => 29,
+    "sprig" => 24,
+    "sprightly" => 24,
+    "spring" => 41,
+    "springer" => 27,
+    "springing" => 30,
+    "springs" => 29,
+    "sprinkling" => 24,
+    "sprint" => 27,
+    "sprite" => 34,
+    "sprung" => 32,
+    "spume" => 24,
+    "spun" => 31,
+    "spunk" => 33,
+    "spur" => 24,
+    "spurious" => 40,
+    "spuriously" => 34,
+    "spurn" => 27,
+    "spurned" => 30,
+    "spurred" => 27,
+    "spurs" => 29,
+    "sputtering" => 24,
+    "spy" => 33,
+    "spying" => 32,
+    "squabble" => 24,
+    "squad" => 29,
+    "squalid" => 30,
+    "squall" => 30,
+    "squalls" => 24,
+    "squander" => 24,
+    "square" => 47,
+    "squared" => 29,
+    "squarely" => 29,
+    "squares" => 31,
+    "squaring" => 30,
+    "squash" => 33,
+    "squashed" => 29,
+    "squashes" => 24,
+    "squashing" => 29,
+    "squat" => 27,
+    "squatted" => 24,
+    "squatting" => 24,
+    "squawk" => 24,
+    "squeak" => 24,
+    "squeaked" => 27,
+    "squeaking" => 29,
+    "squeamishness" => 24,
+    "squeeze" => 32,
+    "squeezed" => 33,
+    "squeezes" => 24,
+    "squeezing" => 24,
+    "squinted" => 24,
+    "squire" => 24,
+    "squirm" => 24,
+    "squirrel" => 31,
+    "squirreling" => 24,
+    "squirrels" => 24,
+    "sri" => 29,
+    "sse" => 45,
+    "stabbed" => 24,
+    "stabilisation" => 24,
+    "stabilise" => 24,
+    "stability" => 51,
+    "stabilization" => 38,
+    "stabilize" => 38,
+    "stabilized" => 47,
+    "stabilizes" => 32,
+    "stabilizing" => 24,
+    "stable" => 54,
+    "stables" => 32,
+    "stably" => 29,
+    "staccato" => 24,
+    "stack" => 53,
+    "stackable" => 29,
+    "stacked" => 30,
+    "stacker" => 24,
+    "stacking" => 30,
+    "stacks" => 37,
+    "staff" => 38,
+    "staffs" => 27,
+    "stag" => 31,
+    "stage" => 45,
+    "stagecoach" => 24,
+    "staged" => 41,
+    "stages" => 40,
+    "stagger" => 24,
+    "staggered" => 35,
+    "staggering" => 31,
+    "staging" => 42,
+    "stagnant" => 27,
+    "stagnation" => 31,
+    "staid" => 24,
+    "stain" => 32,
+    "stained" => 32,
+    "staining" => 24,
+    "stainless" => 24,
+    "stains" => 33,
+    "stair" => 33,
+    "staircase" => 27,
+    "staircases" => 24,
+    "stairs" => 41,
+    "stairway" => 31,
+    "stake" => 37,
+    "staked" => 24,
+    "stakeholders" => 24,
+    "stakes" => 24,
+    "staking" => 32,
+    "stalactite" => 24,
+    "stalactites" => 27,
+    "stalagmite" => 27,
+    "stalagmites" => 24,
+    "stale" => 39,
+    "staleness" => 32,
+    "stalk" => 27,
+    "stalked" => 27,
+    "stall" => 32,
+    "stalled" => 31,
+    "stalling" => 27,
+    "stalls" => 30,
+    "stalwart" => 33,
+    "stammer" => 24,
+    "stammered" => 31,
+    "stamp" => 37,
+    "stamped" => 32,
+    "stamping" => 29,
+    "stamps" => 24,
+    "stan" => 27,
+    "stance" => 31,
+    "stand" => 44,
+    "standalone" => 44,
+    "standard" => 57,
+    "standardisation" => 24,
+    "standardised" => 32,
+    "standardization" => 34,
+    "standardize" => 24,
+    "standardized" => 39,
+    "standardizing" => 24,
+    "standards" => 47,
+    "standi" => 24,
+    "standing" => 43,
+    "standout" => 32,
+    "standpoint" => 30,
+    "stands" => 44,
+    "standstill" => 24,
+    "stanford" => 27,
+    "stanzas" => 24,
+    "stapled" => 24,
+    "staples" => 24,
+    "stapling" => 24,
+    "star" => 43,
+    "starboard" => 24,
+    "starch" => 29,
+    "stardust" => 24,
+    "stare" => 34,
+    "stared" => 40,
+    "stares" => 27,
+    "staring" => 37,
+    "stark" => 39,
+    "starkly" => 27,
+    "starlight" => 27,
+    "starred" => 33,
+    "starring" => 31,
+    "starry" => 24,
+    "stars" => 41,
+    "start" => 58,
+    "started" => 53,
+    "starter" => 35,
+    "starters" => 27,
+    "starting" => 54,
+    "startled" => 37,
+    "startling" => 27,
+    "startlingly" => 24,
+    "starts" => 51,
+    "startup" => 49,
+    "starvation" => 30,
+    "starve" => 24,
+    "starved" => 29,
+    "starving" => 29,
+    "stash" => 37,
+    "stashed" => 33,
+    "stashes" => 30,
+    "stashing" => 32,
+    "state" => 58,
+    "stated" => 41,
+    "stateless" => 34,
+    "stately" => 32,
+    "statement" => 52,
+    "statements" => 49,
+    "states" => 49,
+    "statewide" => 24,
+    "static" => 53,
+    "statically" => 48,
+    "statics" => 44,
+    "stating" => 33,
+    "station" => 44,
+    "stationary" => 29,
+    "stationed" => 24,
+    "stations" => 33,
+    "statistic" => 31,
+    "statistical" => 38,
+    "statistically" => 30,
+    "statistician" => 24,
+    "statistics" => 42,
+    "statue" => 31,
+    "stature" => 33,
+    "status" => 58,
+    "statuses" => 29,
+    "stay" => 45,
+    "stayed" => 37,
+    "staying" => 37,
+    "stays" => 40,
+    "std" => 52,
+    "stead" => 29,
+    "steadfast" => 24,
+    "steadily" => 35,
+    "steady" => 37,
+    "steal" => 36,
+    "stealer" => 27,
+    "stealers" => 24,
+    "stealing" => 40,
+    "steals" => 24,
+    "stealth" => 24,
+    "stealthily" => 31,
+    "stealthy" => 27,
+    "steam" => 39,
+    "steamboat" => 27,
+    "steamboats" => 30,
+    "steamed" => 27,
+    "steamer" => 43,
+    "steamers" => 37,
+    "steaming" => 30,
+    "steamship" => 24,
+    "steed" => 32,
+    "steel" => 32,
+    "steely" => 24,
+    "steep" => 31,
+    "steeped" => 24,
+    "steeper" => 24,
+    "steepest" => 24,
+    "steeple" => 31,
+    "steeples" => 24,
+    "steer" => 27,
+    "steered" => 27,
+    "stefan" => 32,
+    "steinberg" => 33,
+    "steiner" => 31,
+    "stella" => 24,
+    "stellar" => 34,
+    "stem" => 36,
+    "stemming" => 30,
+    "stems" => 32,
+    "stencil" => 24,
+    "step" => 49,
+    "stepdaughter" => 24,
+    "stepfather" => 37,
+    "stephen" => 36,
+    "stephenson" => 24,
+    "stepmother" => 24,
+    "stepped" => 40,
+    "stepping" => 37,
+    "steps" => 49,
+    "stereo" => 24,
+    "stereotype" => 27,
+    "sterling" => 29,
+    "stern" => 32,
+    "sterner" => 27,
+    "sternly" => 30,
+    "steroids" => 30,
+    "stethoscope" => 24,
+    "steve" => 38,
+    "stevedore" => 24,
+    "steven" => 33,
+    "stevens" => 34,
+    "stevenson" => 24,
+    "steward" => 32,
+    "stewart" => 33,
+    "stick" => 42,
+    "sticker" => 30,
+    "stickers" => 24,
+    "sticking" => 34,
+    "sticks" => 34,
+    "sticky" => 36,
+    "stiff" => 36,
+    "stiffened" => 27,
+    "stiffly" => 24,
+    "stiffness" => 24,
+    "stifle" => 27,
+    "stifled" => 29,
+    "stifling" => 27,
+    "stigma" => 29,
+    "stigmatised" => 24,
+    "stile" => 32,
+    "still" => 57,
+    "stilled" => 24,
+    "stillness" => 35,
+    "stillwater" => 31,
+    "stimulant" => 24,
+    "stimulate" => 30,
+    "stimulated" => 29,
+    "stimulating" => 27,
+    "stimulus" => 24,
+    "sting" => 30,
+    "stinging" => 24,
+    "stings" => 24,
+    "stingy" => 24,
+    "stipulate" => 27,
+    "stipulated" => 27,
+    "stir" => 37,
+    "stirred" => 35,
+    "stirring" => 34,
+    "stitch" => 29,
+    "stitches" => 24,
+    "stitching" => 24,
+    "stochastic" => 30,
+    "stochastically" => 24,
+    "stock" => 40,
+    "stocked" => 27,
+    "stockfish" => 29,
+    "stockings" => 29,
+    "stoical" => 24,
+    "stoke" => 34,
+    "stoker" => 29,
+    "stokers" => 27,
+    "stol" => 24,
+    "stole" => 36,
+    "stolen" => 37,
+    "stolid" => 29,
+    "stomach" => 33,
+    "stomped" => 27,
+    "stomping" => 24,
+    "stone" => 42,
+    "stones" => 36,
+    "stonewall" => 24,
+    "stony" => 31,
+    "stood" => 46,
+    "stool" => 27,
+    "stoop" => 30,
+    "stooped" => 30,
+    "stooping" => 30,
+    "stop" => 51,
+    "stopgap" => 30,
+    "stoplight" => 27,
+    "stoppages" => 24,
+    "stopped" => 46,
+    "stopper" => 24,
+    "stopping" => 40,
+    "stops" => 44,
+    "storage" => 50,
+    "store" => 59,
+    "stored" => 52,
+    "stores" => 50,
+    "storied" => 27,
+    "stories" => 35,
+    "storing" => 47,
+    "stork" => 24,
+    "storm" => 39,
+    "stormily" => 24,
+    "storming" => 27,
+    "storms" => 24,
+    "stormy" => 27,
+    "story" => 46,
+    "stout" => 35,
+    "stoutly" => 27,
+    "stove" => 32,
+    "straddling" => 24,
+    "straggle" => 24,
+    "straggled" => 29,
+    "stragglers" => 27,
+    "straggling" => 31,
+    "straight" => 43,
+    "straighten" => 27,
+    "straightened" => 31,
+    "straightening" => 27,
+    "straighter" => 24,
+    "straightforward" => 43,
+    "straightway" => 32,
+    "strain" => 36,
+    "strained" => 33,
+    "straining" => 27,
+    "strains" => 27,
+    "strait" => 29,
+    "straitened" => 24,
+    "straits" => 30,
+    "strand" => 29,
+    "strange" => 46,
+    "strangely" => 33,
+    "stranger" => 40,
+    "strangers" => 35,
+    "strangest" => 30,
+    "strangle" => 24,
+    "strangled" => 30,
+    "stranglers" => 24,
+    "strangling" => 27,
+    "strata" => 27,
+    "strategic" => 33,
+    "strategically" => 32,
+    "strategies" => 44,
+    "strategy" => 46,
+    "stratton" => 24,
+    "stratum" => 36,
+    "straw" => 37,
+    "strawberry" => 33,
+    "stray" => 38,
+    "strayed" => 27,
+    "straying" => 24,
+    "streak" => 29,
+    "streaked" => 24,
+    "stream" => 55,
+    "streamed" => 37,
+    "streamer" => 24,
+    "streamers" => 29,
+    "streaming" => 46,
+    "streamline" => 32,
+    "streamlined" => 24,
+    "streamlines" => 27,
+    "streamlining" => 27,
+    "streams" => 50,
+    "streatham" => 30,
+    "street" => 45,
+    "streets" => 41,
+    "strength" => 41,
+    "strengthen" => 29,
+    "strengthened" => 27,
+    "strengths" => 35,
+    "strenuously" => 27,
+    "stress" => 35,
+    "stressed" => 37,
+    "stresses" => 24,
+    "stressing" => 24,
+    "stretch" => 36,
+    "stretched" => 39,
+    "stretcher" => 24,
+    "stretches" => 27,
+    "stretching" => 32,
+    "strewed" => 27,
+    "strewn" => 24,
+    "stricken" => 33,
+    "strict" => 48,
+    "stricter" => 40,
+    "strictest" => 27,
+    "strictly" => 44,
+    "stride" => 36,
+    "strident" => 27,
+    "strides" => 27,
+    "striding" => 29,
+    "strife" => 24,
+    "strike" => 37,
+    "strikes" => 32,
+    "striking" => 36,
+    "strikingly" => 24,
+    "string" => 62,
+    "stringed" => 24,
+    "stringent" => 29,
+    "strings" => 55,
+    "stringy" => 24,
+    "strip" => 41,
+    "stripe" => 35,
+    "striped" => 27,
+    "stripes" => 29,
+    "stripped" => 40,
+    "stripping" => 33,
+    "strips" => 33,
+    "strive" => 38,
+    "strives" => 36,
+    "striving" => 32,
+    "strobe" => 33,
+    "strode" => 32,
+    "stroke" => 39,
+    "stroked" => 24,
+    "strokes" => 36,
+    "stroking" => 32,
+    "stroll" => 24,
+    "strolled" => 32,
+    "strolling" => 30,
+    "strong" => 47,
+    "stronger" => 40,
+    "strongest" => 35,
+    "stronghold" => 34,
+    "strongholds" => 27,
+    "strongly" => 45,
+    "stroud" => 24,
+    "strove" => 29,
+    "struck" => 42,
+    "structural" => 40,
+    "structurally" => 33,
+    "structure" => 53,
+    "structured" => 46,
+    "structures" => 50,
+    "structuring" => 33,
+    "struggle" => 39,
+    "struggled" => 36,
+    "struggles" => 29,
+    "struggling" => 35,
+    "strum" => 35,
+    "strumming" => 24,
+    "strung" => 24,
+    "struts" => 24,
+    "sts" => 37,
+    "stuart" => 40,
+    "stub" => 35,
+    "stubbed" => 29,
+    "stubborn" => 27,
+    "stubs" => 34,
+    "stuck" => 43,
+    "student" => 33,
+    "students" => 42,
+    "studied" => 34,
+    "studies" => 39,
+    "studio" => 42,
+    "studios" => 33,
+    "studiously" => 24,
+    "study" => 44,
+    "studying" => 36,
+    "stuff" => 46,
+    "stuffed" => 29,
+    "stuffing" => 32,
+    "stuffs" => 27,
+    "stumble" => 30,
+    "stumbled" => 32,
+    "stumbling" => 27,
+    "stump" => 35,
+    "stun" => 27,
+    "stunned" => 32,
+    "stunning" => 27,
+    "stunt" => 24,
+    "stunts" => 27,
+    "stupefaction" => 24,
+    "stupefied" => 33,
+    "stupefying" => 29,
+    "stupendous" => 30,
+    "stupid" => 37,
+    "stupidest" => 24,
+    "stupidity" => 24,
+    "stupidly" => 27,
+    "stupor" => 30,
+    "sturdily" => 24,
+    "sturdy" => 30,
+    "stutters" => 24,
+    "sty" => 30,
+    "style" => 53,
+    "styled" => 36,
+    "styles" => 43,
+    "styling" => 40,
+    "stylish" => 24,
+    "stylistic" => 33,
+    "stylize" => 27,
+    "stylized" => 24,
+    "sua" => 27,
+    "suavely" => 27,
+    "sub" => 49,
+    "subchapters" => 27,
+    "subclass" => 40,
+    "subclasses" => 37,
+    "subcommand" => 42,
+    "subdirectories" => 39,
+    "subdirectory" => 43,
+    "subdivide" => 29,
+    "subdivided" => 31,
+    "subdividing" => 24,
+    "subdivisions" => 24,
+    "subdue" => 24,
+    "subdued" => 34,
+    "subduing" => 27,
+    "subfamilies" => 24,
+    "subfamily" => 24,
+    "subgroup" => 38,
+    "subgroups" => 29,
+    "subheading" => 24,
+    "subheadings" => 24,
+    "subject" => 50,
+    "subjected" => 32,
+    "subjecting" => 24,
+    "subjective" => 38,
+    "subjectivity" => 24,
+    "subjects" => 38,
+    "sublime" => 36,
+    "sublimity" => 24,
+    "submenu" => 36,
+    "submerged" => 29,
+    "submission" => 36,
+    "submissions" => 33,
+    "submit" => 45,
+    "submits" => 32,
+    "submitted" => 55,
+    "submitting" => 39,
+    "subnormal" => 36,
+    "subordinates" => 24,
+    "subprocess" => 38,
+    "subprograms" => 27,
+    "subroutine" => 24,
+    "subroutines" => 32,
+    "subs" => 30,
+    "subscribe" => 38,
+    "subscribed" => 34,
+    "subscriber" => 41,
+    "subscribers" => 38,
+    "subscribes" => 29,
+    "subscribing" => 29,
+    "subscript" => 39,
+    "subscripted" => 29,
+    "subscripting" => 24,
+    "subscription" => 40,
+    "subscriptions" => 38,
+    "subscripts" => 31,
+    "subsection" => 32,
+    "subsections" => 32,
+    "subsequence" => 33,
+    "subsequent" => 46,
+    "subsequently" => 37,
+    "subset" => 46,
+    "subsets" => 36,
+    "subside" => 24,
+    "subsided" => 29,
+    "subsidence" => 24,
+    "subsidiaries" => 24,
+    "subsidy" => 24,
+    "subsist" => 24,
+    "subsisted" => 27,
+    "subsistence" => 24,
+    "substance" => 31,
+    "substantial" => 40,
+    "substantiality" => 24,
+    "substantially" => 37,
+    "substantive" => 32,
+    "substantively" => 27,
+    "substitutable" => 27,
+    "substitute" => 48,
+    "substituted" => 41,
+    "substitutes" => 32,
+    "substituting" => 36,
+    "substitution" => 40,
+    "substitutions" => 35,
+    "substraction" => 24,
+    "substrate" => 40,
+    "substring" => 44,
+    "subsumed" => 24,
+    "subsumes" => 27,
+    "subsystem" => 41,
+    "subsystems" => 30,
+    "subterfuges" => 24,
+    "subterranean" => 24,
+    "subtitle" => 24,
+    "subtle" => 43,
+    "subtleties" => 29,
+    "subtlety" => 29,
+    "subtly" => 32,
+    "subtract" => 49,
+    "subtracted" => 35,
+    "subtracting" => 36,
+    "subtraction" => 43,
+    "subtractions" => 27,
+    "subtracts" => 41,
+    "subtype" => 39,
+    "subtypes" => 32,
+    "suburb" => 24,
+    "suburban" => 31,
+    "subversion" => 44,
+    "subvert" => 24,
+    "subverts" => 24,
+    "subway" => 31,
+    "succeed" => 44,
+    "succeeded" => 47,
+    "succeeding" => 31,
+    "succeeds" => 43,
+    "success" => 47,
+    "successes" => 29,
+    "successful" => 47,
+    "successfully" => 47,
+    "succession" => 33,
+    "successive" => 39,
+    "successively" => 30,
+    "successor" => 39,
+    "successors" => 32,
+    "succinct" => 35,
+    "succinctly" => 36,
+    "succour" => 30,
+    "succulent" => 29,
+    "succumb" => 24,
+    "such" => 60,
+    "suchlike" => 24,
+    "suck" => 32,
+    "sucked" => 24,
+    "sucking" => 24,
+    "sucks" => 34,
+    "sudden" => 41,
+    "suddenly" => 46,
+    "suddenness" => 29,
+    "suds" => 24,
+    "sue" => 24,
+    "suet" => 27,
+    "suez" => 38,
+    "suffer" => 39,
+    "suffered" => 39,
+    "sufferer" => 33,
+    "suffering" => 38,
+    "sufferings" => 36,
+    "suffers" => 29,
+    "suffice" => 36,
+    "sufficed" => 30,
+    "suffices" => 35,
+    "sufficiency" => 27,
+    "sufficient" => 46,
+    "sufficiently" => 43,
+    "suffix" => 49,
+    "suffixed" => 39,
+    "suffixes" => 42,
+    "suffixing" => 24,
+    "suffocated" => 27,
+    "suffocating" => 29,
+    "suffrage" => 24,
+    "sugar" => 40,
+    "sugary" => 27,
+    "suggest" => 44,
+    "suggested" => 45,
+    "suggesting" => 34,
+    "suggestion" => 42,
+    "suggestions" => 46,
+    "suggestive" => 34,
+    "suggestiveness" => 27,
+    "suggests" => 40,
+    "sui" => 33,
+    "suicide" => 33,
+    "suit" => 42,
+    "suitability" => 32,
+    "suitable" => 47,
+    "suitably" => 33,
+    "suite" => 49,
+    "suited" => 40,
+    "suites" => 39,
+    "suitor" => 24,
+    "suits" => 36,
+    "sulked" => 24,
+    "sulkily" => 29,
+    "sulking" => 24,
+    "sulky" => 30,
+    "sullen" => 31,
+    "sullenly" => 27,
+    "sullied" => 24,
+    "sullivan" => 33,
+    "sultan" => 27,
+    "sum" => 47,
+    "sumatra" => 24,
+    "summaries" => 35,
+    "summarily" => 27,
+    "summarise" => 27,
+    "summarised" => 24,
+    "summarises" => 24,
+    "summarization" => 32,
+    "summarize" => 38,
+    "summarized" => 27,
+    "summarizes" => 35,
+    "summarizing" => 30,
+    "summary" => 47,
+    "summation" => 27,
+    "summed" => 32,
+    "summer" => 42,
+    "summers" => 24,
+    "summing" => 31,
+    "summit" => 35,
+    "summits" => 32,
+    "summoned" => 31,
+    "summoning" => 27,
+    "summons" => 27,
+    "summonses" => 24,
+    "sumptuous" => 30,
+    "sumptuously" => 27,
+    "sumptuousness" => 24,
+    "sums" => 38,
+    "sun" => 46,
+    "sunbeam" => 24,
+    "sunburnt" => 27,
+    "sunday" => 40,
+    "sundays" => 30,
+    "sundial" => 31,
+    "sundials" => 24,
+    "sung" => 37,
+    "sunk" => 37,
+    "sunken" => 29,
+    "sunlight" => 33,
+    "sunny" => 31,
+    "sunrise" => 27,
+    "suns" => 24,
+    "sunset" => 32,
+    "sunshine" => 34,
+    "sunstroke" => 24,
+    "suntanned" => 24,
+    "sup" => 24,
+    "super" => 45,
+    "superabundance" => 24,
+    "superb" => 32,
+    "supercilious" => 27,
+    "superficial" => 29,
+    "superficiality" => 27,
+    "superfluous" => 39,
+    "superhuman" => 24,
+    "superhumanly" => 24,
+    "superimpose" => 24,
+    "superimposed" => 24,
+    "superintendent" => 32,
+    "superior" => 39,
+    "superiority" => 29,
+    "superiors" => 24,
+    "superlative" => 29,
+    "superman" => 24,
+    "supernatural" => 30,
+    "superpower" => 24,
+    "superpowers" => 32,
+    "superscript" => 37,
+    "superscription" => 27,
+    "superscripts" => 29,
+    "superseded" => 33,
+    "supersedes" => 29,
+    "superset" => 41,
+    "supersets" => 27,
+    "superstition" => 33,
+    "superstitions" => 24,
+    "superstitious" => 24,
+    "supervised" => 24,
+    "supervision" => 29,
+    "supervisor" => 37,
+    "supper" => 38,
+    "supplanted" => 24,
+    "supple" => 30,
+    "supplement" => 30,
+    "supplemental" => 30,
+    "supplementary" => 33,
+    "supplemented" => 24,
+    "supplementing" => 24,
+    "suppleness" => 24,
+    "suppliant" => 24,
+    "supplication" => 27,
+    "supplied" => 47,
+    "supplier" => 27,
+    "suppliers" => 27,
+    "supplies" => 37,
+    "supply" => 42,
+    "supplying" => 39,
+    "support" => 61,
+    "supported" => 59,
+    "supporter" => 27,
+    "supporters" => 30,
+    "supporting" => 47,
+    "supportive" => 29,
+    "supports" => 56,
+    "suppose" => 47,
+    "supposed" => 43,
+    "supposedly" => 31,
+    "supposes" => 24,
+    "supposing" => 32,
+    "supposition" => 30,
+    "suppress" => 56,
+    "suppressed" => 48,
+    "suppresses" => 32,
+    "suppressing" => 33,
+    "suppression" => 33,
+    "suppressions" => 24,
+    "supremacy" => 24,
+    "supreme" => 35,
+    "sur" => 30,
+    "sure" => 53,
+    "surely" => 40,
+    "surer" => 29,
+    "surest" => 27,
+    "surf" => 33,
+    "surface" => 44,
+    "surfaced" => 27,
+    "surfaces" => 33,
+    "surfacing" => 24,
+    "surfing" => 24,
+    "surge" => 27,
+    "surgeon" => 30,
+    "surgery" => 27,
+    "surges" => 24,
+    "surgically" => 27,
+    "surly" => 24,
+    "surmise" => 29,
+    "surmount" => 27,
+    "surmountable" => 24,
+    "surmounted" => 24,
+    "surmounting" => 24,
+    "surname" => 30,
+    "surnames" => 27,
+    "surpassed" => 29,
+    "surpassing" => 29,
+    "surprise" => 42,
+    "surprised" => 43,
+    "surprises" => 34,
+    "surprising" => 41,
+    "surprisingly" => 37,
+    "surrender" => 24,
+    "surrey" => 32,
+    "surrogate" => 39,
+    "surrogates" => 32,
+    "surround" => 32,
+    "surrounded" => 42,
+    "surrounding" => 42,
+    "surroundings" => 30,
+    "surrounds" => 30,
+    "survey" => 29,
+    "surveyed" => 32,
+    "surveying" => 24,
+    "surveys" => 24,
+    "survive" => 37,
+    "survived" => 24,
+    "survives" => 32,
+    "surviving" => 29,
+    "survivor" => 27,
+    "survivors" => 27,
+    "sus" => 31,
+    "susan" => 36,
+    "susceptibility" => 24,
+    "susceptible" => 35,
+    "suspect" => 35,
+    "suspected" => 36,
+    "suspecting" => 31,
+    "suspects" => 27,
+    "suspend" => 41,
+    "suspended" => 40,
+    "suspender" => 29,
+    "suspending" => 37,
+    "suspends" => 30,
+    "suspense" => 34,
+    "suspension" => 32,
+    "suspicion" => 37,
+    "suspicions" => 35,
+    "suspicious" => 36,
+    "suspiciously" => 29,
+    "sussex" => 24,
+    "sustain" => 33,
+    "sustainable" => 30,
+    "sustained" => 34,
+    "sustaining" => 27,
+    "sustenance" => 27,
+    "sutherland" => 35,
+    "suttee" => 30,
+    "suzuki" => 29,
+    "svelte" => 29,
+    "swabbed" => 24,
+    "swag" => 30,
+    "swagger" => 40,
+    "swahili" => 27,
+    "swain" => 27,
+    "swallow" => 27,
+    "swallowed" => 35,
+    "swallowing" => 27,
+    "swallows" => 27,
+    "swam" => 34,
+    "swamp" => 24,
+    "swamping" => 24,
+    "swamps" => 27,
+    "swan" => 29,
+    "swank" => 24,
+    "swap" => 50,
+    "swapped" => 39,
+    "swapping" => 38,
+    "swaps" => 38,
+    "swarm" => 32,
+    "swarmed" => 27,
+    "swarming" => 27,
+    "swarthy" => 24,
+    "swash" => 32,
+    "swastika" => 24,
+    "swatches" => 24,
+    "swathed" => 24,
+    "swathes" => 24,
+    "swaths" => 24,
+    "sway" => 31,
+    "swayed" => 27,
+    "swaying" => 31,
+    "swear" => 38,
+    "swears" => 24,
+    "sweat" => 34,
+    "sweated" => 29,
+    "sweating" => 27,
+    "swede" => 24,
+    "sweden" => 29,
+    "swedish" => 33,
+    "sweep" => 33,
+    "sweeping" => 31,
+    "sweeps" => 24,
+    "sweet" => 40,
+    "sweetened" => 24,
+    "sweeter" => 24,
+    "sweetest" => 24,
+    "sweetheart" => 30,
+    "sweethearts" => 24,
+    "sweetie" => 24,
+    "sweetly" => 29,
+    "sweetmeats" => 27,
+    "sweetness" => 32,
+    "sweets" => 24,
+    "swell" => 32,
+    "swelled" => 33,
+    "swelling" => 32,
+    "swells" => 24,
+    "swept" => 37,
+    "swift" => 41,
+    "swifter" => 24,
+    "swiftly" => 36,
+    "swiftness" => 30,
+    "swim" => 35,
+    "swimmer" => 24,
+    "swimmers" => 27,
+    "swimming" => 36,
+    "swindler" => 24,
+    "swindon" => 24,
+    "swing" => 32,
+    "swinging" => 35,
+    "swirls" => 24,
+    "swish" => 24,
+    "swiss" => 32,
+    "switch" => 50,
+    "switchboard" => 30,
+    "switched" => 42,
+    "switcher" => 29,
+    "switches" => 41,
+    "switching" => 46,
+    "switzerland" => 34,
+    "swivel" => 24,
+    "swizzle" => 31,
+    "swollen" => 30,
+    "swoon" => 24,
+    "swooned" => 27,
+    "swooning" => 24,
+    "sword" => 34,
+    "swords" => 24,
+    "swordsman" => 24,
+    "swore" => 34,
+    "sworn" => 33,
+    "swung" => 34,
+    "sycamore" => 36,
+    "syllabic" => 29,
+    "syllable" => 38,
+    "syllables" => 37,
+    "syllabus" => 24,
+    "symbol" => 49,
+    "symbolic" => 46,
+    "symbolically" => 32,
+    "symbolise" => 24,
+    "symbolize" => 29,
+    "symbols" => 48,
+    "symmetric" => 40,
+    "symmetrical" => 30,
+    "symmetrically" => 29,
+    "symmetry" => 36,
+    "sympathetic" => 29,
+    "sympathies" => 30,
+    "sympathise" => 30,
+    "sympathised" => 31,
+    "sympathising" => 27,
+    "sympathized" => 24,
+    "sympathizing" => 24,
+    "sympathy" => 39,
+    "symposium" => 30,
+    "symptom" => 29,
+    "symptoms" => 33,
+    "synagogues" => 24,
+    "sync" => 49,
+    "synch" => 24,
+    "synchronisation" => 34,
+    "synchronised" => 24,
+    "synchronises" => 27,
+    "synchronising" => 27,
+    "synchronization" => 46,
+    "synchronize" => 40,
+    "synchronized" => 39,
+    "synchronizes" => 34,
+    "synchronizing" => 38,
+    "synchronous" => 47,
+    "synchronously" => 41,
+    "syndic" => 24,
+    "syndication" => 29,
+    "syndics" => 24,
+    "syndrome" => 27,
+    "synergy" => 27,
+    "synonym" => 37,
+    "synonymous" => 31,
+    "synonyms" => 31,
+    "synopsis" => 40,
+    "syntactic" => 40,
+    "syntactical" => 32,
+    "syntactically" => 38,
+    "syntax" => 59,
+    "syntaxes" => 37,
+    "synthesis" => 32,
+    "synthesised" => 24,
+    "synthesize" => 27,
+    "synthesized" => 27,
+    "synthesizers" => 24,
+    "synthesizes" => 27,
+    "synthetic" => 36,
+    "syrup" => 24,
+    "system" => 58,
+    "systematic" => 31,
+    "systematically" => 30,
+    "systems" => 54,
+    "t'other" => 33,
+    "tab" => 53,
+    "tabbed" => 24,
+    "table" => 54,
+    "tableau" => 27,
+    "tabled" => 24,
+    "tables" => 48,
+    "tabs" => 46,
+    "tabular" => 33,
+    "tabulation" => 31,
+    "tabulator" => 24,
+    "taciturn" => 29,
+    "tack" => 27,
+    "tackle" => 36,
+    "tackled" => 29,
+    "tackling" => 27,
+    "tactful" => 24,
+    "tactfully" => 24,
+    "tactical" => 24,
+    "tactlessly" => 24,
+    "tad" => 27,
+    "tadpoles" => 24,
+    "tads" => 27,
+    "taffy" => 30,
+    "tag" => 54,
+    "tagged" => 43,
+    "tagging" => 42,
+    "tags" => 52,
+    "tahitian" => 24,
+    "tahoe" => 24,
+    "tai" => 37,
+    "tail" => 44,
+    "tailed" => 27,
+    "tailing" => 24,
+    "tailless" => 24,
+    "tailor" => 32,
+    "tailored" => 39,
+    "tailoring" => 33,
+    "tails" => 33,
+    "tailwind" => 30,
+    "taint" => 27,
+    "tainted" => 29,
+    "taiwan" => 24,
+    "taiwanese" => 29,
+    "take" => 56,
+    "takeaway" => 27,
+    "taken" => 50,
+    "takes" => 54,
+    "taking" => 49,
+    "takings" => 29,
+    "talc" => 24,
+    "tale" => 41,
+    "talent" => 32,
+    "talented" => 27,
+    "talents" => 30,
+    "tales" => 33,
+    "talisman" => 24,
+    "talk" => 47,
+    "talkative" => 27,
+    "talked" => 42,
+    "talker" => 24,
+    "talking" => 44,
+    "talks" => 37,
+    "tall" => 39,
+    "taller" => 31,
+    "tallied" => 24,
+    "tallish" => 24,
+    "tallow" => 32,
+    "tally" => 24,
+    "tam" => 27,
+    "tamara" => 24,
+    "tambourines" => 32,
+    "tame" => 29,
+    "tamer" => 24,
+    "tamil" => 30,
+    "tamper" => 24,
+    "tampered" => 29,
+    "tampering" => 27,
+    "tams" => 27,
+    "tan" => 33,
+    "tandem" => 33,
+    "tangent" => 35,
+    "tangents" => 27,
+    "tangible" => 32,
+    "tangle" => 31,
+    "tangled" => 34,
+    "tango" => 31,
+    "tanh" => 33,
+    "tank" => 24,
+    "tanked" => 24,
+    "tanned" => 27,
+    "tanner" => 30,
+    "tannery" => 24,
+    "tantalizing" => 24,
+    "tanzania" => 24,
+    "tao" => 32,
+    "tap" => 37,
+    "tape" => 31,
+    "tapers" => 24,
+    "tapes" => 34,
+    "tapestried" => 24,
+    "tapestry" => 24,
+    "tapped" => 34,
+    "tapping" => 36,
+    "taproot" => 27,
+    "taps" => 27,
+    "tar" => 40,
+    "tardily" => 24,
+    "target" => 59,
+    "targeted" => 38,
+    "targeting" => 43,
+    "targets" => 54,
+    "tarnish" => 24,
+    "tarnished" => 24,
+    "taro" => 45,
+    "tarry" => 24,
+    "tart" => 29,
+    "tarts" => 32,
+    "task" => 50,
+    "tasked" => 24,
+    "tasking" => 24,
+    "tasks" => 49,
+    "tassel" => 24,
+    "taste" => 38,
+    "tasted" => 33,
+    "tasteful" => 24,
+    "taster" => 24,
+    "tastes" => 32,
+    "tattered" => 30,
+    "tatters" => 24,
+    "tattle" => 29,
+    "tattoo" => 27,
+    "tattooed" => 24,
+    "tau" => 29,
+    "taught" => 37,
+    "taunt" => 24,
+    "taunted" => 24,
+    "tavern" => 37,
+    "taverns" => 30,
+    "taw" => 27,
+    "tawny" => 24,
+    "tax" => 35,
+    "taxed" => 27,
+    "taxes" => 29,
+    "taxi" => 33,
+    "taxicabs" => 27,
+    "taxis" => 24,
+    "taxonomic" => 24,
+    "tay" => 24,
+    "taylor" => 32,
+    "tcp" => 47,
+    "tea" => 40,
+    "teach" => 36,
+    "teacher" => 32,
+    "teachers" => 39,
+    "teaches" => 30,
+    "teaching" => 35,
+    "teacup" => 29,
+    "teacups" => 27,
+    "teakwood" => 24,
+    "teal" => 24,
+    "team" => 48,
+    "teammates" => 24,
+    "teams" => 41,
+    "teamwork" => 24,
+    "teapot" => 24,
+    "tear" => 40,
+    "teared" => 24,
+    "tearful" => 29,
+    "tearing" => 35,
+    "tears" => 42,
+    "teased" => 27,
+    "teases" => 24,
+    "teasing" => 24,
+    "teaspoon" => 27,
+    "teaspoonful" => 24,
+    "tech" => 32,
+    "technical" => 44,
+    "technicalities" => 27,
+    "technically" => 42,
+    "technician" => 27,
+    "technicians" => 24,
+    "technique" => 43,
+    "techniques" => 41,
+    "technocratic" => 24,
+    "technological" => 30,
+    "technologically" => 27,
+    "technologies" => 40,
+    "technologists" => 24,
+    "technology" => 45,
+    "ted" => 27,
+    "tedious" => 40,
+    "tee" => 40,
+    "teeing" => 24,
+    "teensy" => 24,
+    "teeth" => 38,
+    "teething" => 24,
+    "teetotaler" => 24,
+    "telecommunication" => 24,
+    "telecommunications" => 32,
+    "telegram" => 37,
+    "telegrams" => 24,
+    "telegraph" => 34,
+    "telegraphed" => 24,
+    "telegraphic" => 31,
+    "telemetry" => 42,
+    "telephone" => 38,
+    "telephoned" => 29,
+    "telescope" => 30,
+    "telescopes" => 30,
+    "teletype" => 24,
+    "television" => 27,
+    "telex" => 24,
+    "tell" => 52,
+    "teller" => 24,
+    "telling" => 42,
+    "tells" => 47,
+    "telltale" => 24,
+    "temp" => 43,
+    "temper" => 38,
+    "temperament" => 30,
+    "temperance" => 31,
+    "temperate" => 24,
+    "temperature" => 34,
+    "temperatures" => 27,
+    "tempered" => 30,
+    "tempest" => 35,
+    "tempests" => 24,
+    "tempestuous" => 27,
+    "template" => 48,
+    "templates" => 43,
+    "temple" => 37,
+    "temples" => 33,
+    "tempo" => 24,
+    "temporal" => 43,
+    "temporality" => 24,
+    "temporaries" => 40,
+    "temporarily" => 44,
+    "temporary" => 49,
+    "temps" => 27,
+    "tempt" => 24,
+    "temptation" => 35,
+    "tempted" => 36,
+    "tempting" => 30,
+    "ten" => 47,
+    "tenable" => 24,
+    "tenacious" => 24,
+    "tenacity" => 24,
+    "tenant" => 27,
+    "tenantless" => 24,
+    "tend" => 40,
+    "tended" => 31,
+    "tendencies" => 24,
+    "tendency" => 33,
+    "tender" => 33,
+    "tenderest" => 27,
+    "tenderly" => 32,
+    "tenderness" => 32,
+    "tendril" => 32,
+    "tendrils" => 24,
+    "tends" => 39,
+    "tenement" => 24,
+    "tenets" => 27,
+    "tenfold" => 27,
+    "tennessee" => 29,
+    "tennis" => 27,
+    "tenor" => 27,
+    "tens" => 31,
+    "tense" => 32,
+    "tenses" => 24,
+    "tension" => 32,
+    "tensor" => 41,
+    "tensors" => 39,
+    "tent" => 31,
+    "tentative" => 32,
+    "tentatively" => 31,
+    "tenth" => 31,
+    "tenths" => 27,
+    "tents" => 29,
+    "tenure" => 24,
+    "term" => 53,
+    "termed" => 31,
+    "terminal" => 58,
+    "terminals" => 46,
+    "terminate" => 43,
+    "terminated" => 45,
+    "terminates" => 41,
+    "terminating" => 39,
+    "termination" => 41,
+    "terminations" => 27,
+    "terminator" => 41,
+    "terminators" => 32,
+    "terminology" => 36,
+    "terminus" => 29,
+    "terms" => 56,
+    "ternary" => 37,
+    "terra" => 31,
+    "terrace" => 33,
+    "terraced" => 27,
+    "terrain" => 24,
+    "terrestrial" => 30,
+    "terrible" => 41,
+    "terribly" => 36,
+    "terrier" => 24,
+    "terrific" => 35,
+    "terrifically" => 24,
+    "terrified" => 32,
+    "territories" => 29,
+    "territory" => 37,
+    "terror" => 39,
+    "terrorising" => 24,
+    "terrors" => 29,
+    "terry" => 24,
+    "terse" => 34,
+    "tertiary" => 30,
+    "tesla" => 29,
+    "tessa" => 24,
+    "tessellation" => 29,
+    "test" => 64,
+    "testability" => 30,
+    "testable" => 35,
+    "testament" => 30,
+    "tested" => 53,
+    "tester" => 31,
+    "testers" => 29,
+    "testified" => 29,
+    "testify" => 24,
+    "testifying" => 27,
+    "testimonies" => 24,
+    "testimony" => 32,
+    "testing" => 54,
+    "tests" => 59,
+    "tetrahedron" => 24,
+    "teutonic" => 24,
+    "texas" => 32,
+    "text" => 60,
+    "textbook" => 27,
+    "textiles" => 24,
+    "texts" => 46,
+    "textual" => 43,
+    "textually" => 30,
+    "textural" => 24,
+    "texture" => 43,
+    "textured" => 29,
+    "textures" => 36,
+    "thai" => 35,
+    "thames" => 27,
+    "than" => 60,
+    "thank" => 44,
+    "thanked" => 33,
+    "thankful" => 32,
+    "thankfully" => 31,
+    "thankfulness" => 24,
+    "thanking" => 27,
+    "thanks" => 49,
+    "thanksgivings" => 24,
+    "that" => 69,
+    "that's" => 52,
+    "thatch" => 24,
+    "thatched" => 27,
+    "thatcher" => 40,
+    "thatchers" => 24,
+    "the" => 78,
+    "thea" => 24,
+    "theatre" => 32,
+    "theatrical" => 29,
+    "thee" => 36,
+    "theft" => 24,
+    "their" => 58,
+    "theirs" => 36,
+    "them" => 59,
+    "thematically" => 24,
+    "theme" => 43,
+    "themes" => 39,
+    "themselves" => 48,
+    "then" => 62,
+    "thence" => 33,
+    "thenceforth" => 27,
+    "theodore" => 24,
+    "theologians" => 27,
+    "theological" => 24,
+    "theorem" => 27,
+    "theorems" => 24,
+    "theoretic" => 27,
+    "theoretical" => 38,
+    "theoretically" => 38,
+    "theories" => 33,
+    "theorist" => 24,
+    "theorize" => 24,
+    "theory" => 43,
+    "there" => 62,
+    "there's" => 50,
+    "thereabout" => 24,
+    "thereabouts" => 30,
+    "thereafter" => 33,
+    "thereby" => 38,
+    "therefor" => 27,
+    "therefore" => 52,
+    "therein" => 34,
+    "thereof" => 39,
+    "thereupon" => 30,
+    "thermodynamic" => 24,
+    "thesaurus" => 39,
+    "these" => 61,
+    "theses" => 24,
+    "thesis" => 24,
+    "theta" => 30,
+    "they" => 61,
+    "they'd" => 37,
+    "they'll" => 39,
+    "they're" => 48,
+    "they've" => 35,
+    "thick" => 41,
+    "thickened" => 24,
+    "thickening" => 24,
+    "thickest" => 24,
+    "thicket" => 30,
+    "thickish" => 24,
+    "thickly" => 30,
+    "thickness" => 29,
+    "thief" => 35,
+    "thieves" => 29,
+    "thimble" => 32,
+    "thimblefuls" => 24,
+    "thin" => 45,
+    "thine" => 29,
+    "thing" => 51,
+    "things" => 53,
+    "think" => 52,
+    "thinker" => 27,
+    "thinkers" => 24,
+    "thinking" => 44,
+    "thinks" => 38,
+    "thinned" => 27,
+    "thinner" => 29,
+    "thinness" => 24,
+    "thinning" => 29,
+    "third" => 49,
+    "thirdly" => 24,
+    "thirds" => 36,
+    "thirst" => 36,
+    "thirsted" => 27,
+    "thirsting" => 24,
+    "thirteen" => 33,
+    "thirties" => 24,
+    "thirty" => 43,
+    "this" => 70,
+    "thistle" => 27,
+    "thither" => 33,
+    "thomas" => 41,
+    "thompson" => 34,
+    "thorn" => 29,
+    "thorns" => 24,
+    "thorny" => 27,
+    "thorough" => 38,
+    "thoroughfare" => 27,
+    "thoroughly" => 41,
+    "thoroughness" => 27,
+    "those" => 56,
+    "thou" => 37,
+    "though" => 53,
+    "thought" => 51,
+    "thoughtful" => 32,
+    "thoughtfully" => 32,
+    "thoughtfulness" => 24,
+    "thoughtless" => 27,
+    "thoughtlessly" => 24,
+    "thoughts" => 42,
+    "thousand" => 46,
+    "thousands" => 41,
+    "thousandth" => 24,
+    "thrash" => 24,
+    "thrashing" => 27,
+    "thread" => 56,
+    "threadbare" => 29,
+    "threaded" => 45,
+    "threading" => 40,
+    "threads" => 53,
+    "threat" => 34,
+    "threaten" => 30,
+    "threatened" => 36,
+    "threatening" => 32,
+    "threatens" => 27,
+    "threats" => 33,
+    "three" => 55,
+    "threshold" => 41,
+    "thresholding" => 33,
+    "thresholds" => 32,
+    "threw" => 42,
+    "thrice" => 27,
+    "thrift" => 38,
+    "thrifty" => 24,
+    "thrill" => 31,
+    "thrilling" => 32,
+    "throat" => 37,
+    "throats" => 29,
+    "throb" => 24,
+    "throbbed" => 29,
+    "throbbing" => 27,
+    "throne" => 30,
+    "throng" => 30,
+    "thronged" => 24,
+    "throttle" => 30,
+    "throttling" => 27,
+    "through" => 57,
+    "throughout" => 43,
+    "throughput" => 44,
+    "throve" => 24,
+    "throw" => 47,
+    "throwback" => 24,
+    "throwing" => 41,
+    "thrown" => 49,
+    "throws" => 44,
+    "thrush" => 27,
+    "thrust" => 37,
+    "thrusting" => 30,
+    "thud" => 24,
+    "thudding" => 27,
+    "thumb" => 44,
+    "thumbnail" => 24,
+    "thumbnails" => 24,
+    "thumbprint" => 24,
+    "thumbprints" => 24,
+    "thumbs" => 24,
+    "thump" => 27,
+    "thumped" => 24,
+    "thumps" => 24,
+    "thunder" => 37,
+    "thunderbird" => 30,
+    "thunderbolt" => 27,
+    "thunders" => 24,
+    "thunderstorm" => 29,
+    "thunderstruck" => 27,
+    "thursday" => 35,
+    "thus" => 54,
+    "thwarts" => 24,
+    "thy" => 37,
+    "thyself" => 24,
+    "tiara" => 24,
+    "tibetan" => 24,
+    "tick" => 42,
+    "ticked" => 24,
+    "ticker" => 27,
+    "ticket" => 40,
+    "ticketing" => 27,
+    "tickets" => 39,
+    "ticking" => 32,
+    "tickled" => 27,
+    "ticks" => 32,
+    "tide" => 42,
+    "tidings" => 29,
+    "tidy" => 33,
+    "tie" => 37,
+    "tiebreaker" => 24,
+    "tied" => 41,
+    "tier" => 50,
+    "tiered" => 27,
+    "tiers" => 35,
+    "ties" => 38,
+    "tiff" => 38,
+    "tiger" => 29,
+    "tigers" => 32,
+    "tight" => 38,
+    "tighten" => 30,
+    "tightened" => 29,
+    "tightening" => 29,
+    "tighter" => 31,
+    "tightly" => 37,
+    "tights" => 27,
+    "til" => 27,
+    "tilbury" => 24,
+    "tilde" => 41,
+    "tildes" => 24,
+    "tile" => 41,
+    "tiled" => 24,
+    "tiles" => 36,
+    "tiling" => 27,
+    "till" => 45,
+    "tilt" => 24,
+    "tilted" => 27,
+    "tilting" => 24,
+    "tim" => 39,
+    "timbered" => 24,
+    "timbers" => 24,
+    "time" => 61,
+    "timed" => 37,
+    "timely" => 35,
+    "timeout" => 49,
+    "timepiece" => 24,
+    "timer" => 48,
+    "timers" => 46,
+    "times" => 55,
+    "timestamp" => 45,
+    "timet" => 24,
+    "timetable" => 24,
+    "timetables" => 24,
+    "timid" => 33,
+    "timidly" => 35,
+    "timing" => 45,
+    "timings" => 39,
+    "timorous" => 24,
+    "timothy" => 24,
+    "tin" => 35,
+    "ting" => 32,
+    "tinge" => 29,
+    "tinged" => 29,
+    "tingle" => 24,
+    "tingled" => 24,
+    "tingling" => 29,
+    "tiniest" => 24,
+    "tinkered" => 24,
+    "tinkled" => 24,
+    "tinkling" => 24,
+    "tinny" => 24,
+    "tint" => 35,
+    "tinted" => 35,
+    "tiny" => 48,
+    "tip" => 41,
+    "tipped" => 31,
+    "tipping" => 24,
+    "tips" => 41,
+    "tipsy" => 24,
+    "tiptoe" => 31,
+    "tiptoed" => 30,
+    "tiptoeing" => 27,
+    "tiptoes" => 30,
+    "tire" => 29,
+    "tired" => 38,
+    "tiresome" => 31,
+    "tissue" => 29,
+    "titan" => 27,
+    "tither" => 24,
+    "title" => 48,
+    "titled" => 24,
+    "titles" => 35,
+    "titter" => 29,
+    "tittered" => 27,
+    "tittering" => 30,
+    "titus" => 27,
+    "to" => 74,
+    "toast" => 33,
+    "tobacco" => 35,
+    "tobacconist" => 24,
+    "today" => 43,
+    "todd" => 27,
+    "toe" => 34,
+    "toes" => 34,
+    "toffee" => 24,
+    "together" => 52,
+    "toggle" => 42,
+    "toggled" => 34,
+    "toggles" => 37,
+    "toggling" => 32,
+    "toil" => 33,
+    "toiled" => 30,
+    "toilet" => 32,
+    "toilets" => 27,
+    "toils" => 33,
+    "toilsome" => 24,
+    "token" => 54,
+    "tokenizing" => 32,
+    "tokens" => 50,
+    "told" => 47,
+    "tolerable" => 29,
+    "tolerably" => 29,
+    "tolerance" => 36,
+    "tolerances" => 30,
+    "tolerant" => 34,
+    "tolerate" => 36,
+    "tolerated" => 29,
+    "tolerates" => 24,
+    "tolerating" => 24,
+    "toll" => 24,
+    "toller" => 36,
+    "tollers" => 24,
+    "tom" => 53,
+    "tomato" => 24,
+    "tomb" => 31,
+    "tomboy" => 24,
+    "tombstone" => 29,
+    "tombstones" => 24,
+    "tome" => 24,
+    "tomes" => 27,
+    "tomfoolery" => 24,
+    "tomlinson" => 27,
+    "tommy" => 29,
+    "tomorrow" => 34,
+    "toms" => 24,
+    "ton" => 32,
+    "tone" => 43,
+    "toned" => 30,
+    "toner" => 24,
+    "tones" => 35,
+    "tong" => 27,
+    "tonga" => 27,
+    "tongs" => 27,
+    "tongue" => 39,
+    "tongues" => 29,
+    "tonic" => 40,
+    "tonnage" => 29,
+    "tons" => 35,
+    "tony" => 45,
+    "too" => 55,
+    "took" => 50,
+    "tool" => 52,
+    "toolbar" => 45,
+    "toolbox" => 32,
+    "toolchain" => 50,
+    "tooled" => 24,
+    "tooling" => 43,
+    "toolkit" => 43,
+    "toolkits" => 27,
+    "tools" => 53,
+    "tooth" => 35,
+    "toothache" => 27,
+    "top" => 53,
+    "topic" => 45,
+    "topics" => 43,
+    "topmost" => 31,
+    "topography" => 27,
+    "topological" => 32,
+    "topology" => 36,
+    "topped" => 27,
+    "toppers" => 29,
+    "toppled" => 24,
+    "toppling" => 24,
+    "tops" => 32,
+    "tor" => 37,
+    "torch" => 36,
+    "torches" => 32,
+    "torchlight" => 24,
+    "tore" => 37,
+    "torment" => 33,
+    "tormented" => 33,
+    "tormenting" => 27,
+    "tormentor" => 24,
+    "torments" => 29,
+    "torn" => 38,
+    "torpedoes" => 24,
+    "torpor" => 29,
+    "torrent" => 33,
+    "torrents" => 30,
+    "torsion" => 27,
+    "tort" => 33,
+    "tortious" => 32,
+    "tortoise" => 31,
+    "tortuously" => 24,
+    "torture" => 36,
+    "tortured" => 34,
+    "torturer" => 24,
+    "tortures" => 32,
+    "torturing" => 27,
+    "tory" => 30,
+    "toss" => 31,
+    "tossed" => 36,
+    "tossing" => 32,
+    "total" => 48,
+    "totally" => 40,
+    "totals" => 29,
+    "tote" => 24,
+    "tottenham" => 30,
+    "totter" => 24,
+    "tottered" => 29,
+    "tottering" => 24,
+    "touch" => 42,
+    "touched" => 39,
+    "touches" => 35,
+    "touching" => 37,
+    "touchingly" => 24,
+    "tough" => 32,
+    "tougher" => 27,
+    "tour" => 40,
+    "tourist" => 29,
+    "tourists" => 27,
+    "tournament" => 30,
+    "tournaments" => 24,
+    "tours" => 24,
+    "tout" => 30,
+    "toward" => 46,
+    "towards" => 48,
+    "towel" => 35,
+    "towels" => 27,
+    "tower" => 45,
+    "towered" => 24,
+    "towering" => 27,
+    "towers" => 31,
+    "town" => 45,
+    "towns" => 35,
+    "toxic" => 24,
+    "toy" => 35,
+    "toys" => 30,
+    "trace" => 47,
+    "traceable" => 33,
+    "traced" => 37,
+    "tracer" => 30,
+    "traces" => 43,
+    "tracing" => 48,
+    "track" => 49,
+    "tracked" => 42,
+    "tracker" => 43,
+    "tracking" => 48,
+    "trackless" => 24,
+    "tracks" => 41,
+    "tract" => 29,
+    "tractable" => 24,
+    "traction" => 29,
+    "tractor" => 24,
+    "tracts" => 27,
+    "tracy" => 35,
+    "trade" => 44,
+    "traded" => 30,
+    "trademark" => 32,
+    "trademarks" => 38,
+    "tradeoff" => 35,
+    "trader" => 24,
+    "trades" => 35,
+    "tradesman" => 24,
+    "tradesmen" => 27,
+    "tradespeople" => 24,
+    "trading" => 35,
+    "tradition" => 30,
+    "traditional" => 44,
+    "traditionalists" => 24,
+    "traditionally" => 37,
+    "traditions" => 29,
+    "trafalgar" => 24,
+    "traffic" => 40,
+    "tragedy" => 32,
+    "tragic" => 32,
+    "tragically" => 29,
+    "trail" => 34,
+    "trailer" => 34,
+    "trailers" => 36,
+    "trailing" => 49,
+    "trails" => 27,
+    "train" => 46,
+    "trained" => 38,
+    "training" => 40,
+    "trains" => 39,
+    "trait" => 59,
+    "traitor" => 29,
+    "traits" => 56,
+    "trajectory" => 27,
+    "tramp" => 24,
+    "tramped" => 27,
+    "trample" => 29,
+    "trampled" => 30,
+    "trampling" => 24,
+    "trampoline" => 27,
+    "trance" => 27,
+    "tranquil" => 37,
+    "tranquility" => 24,
+    "tranquillise" => 24,
+    "tranquillised" => 24,
+    "tranquillity" => 36,
+    "tranquilly" => 31,
+    "trans" => 34,
+    "transact" => 24,
+    "transacted" => 29,
+    "transacting" => 24,
+    "transaction" => 45,
+    "transactional" => 35,
+    "transactions" => 45,
+    "transatlantic" => 27,
+    "transceiver" => 24,
+    "transcendent" => 29,
+    "transcendental" => 29,
+    "transcribe" => 27,
+    "transcribed" => 32,
+    "transcriber" => 33,
+    "transcribers" => 29,
+    "transcribes" => 27,
+    "transcribing" => 27,
+    "transcript" => 36,
+    "transcription" => 37,
+    "transcriptions" => 30,
+    "transcripts" => 29,
+    "transducer" => 29,
+    "transducers" => 27,
+    "transfer" => 45,
+    "transferable" => 33,
+    "transferred" => 42,
+    "transferring" => 40,
+    "transfers" => 37,
+    "transfixed" => 24,
+    "transform" => 44,
+    "transformable" => 27,
+    "transformation" => 43,
+    "transformations" => 41,
+    "transformed" => 40,
+    "transformer" => 34,
+    "transformers" => 35,
+    "transforming" => 38,
+    "transforms" => 40,
+    "transient" => 34,
+    "transiently" => 24,
+    "transit" => 29,
+    "transition" => 44,
+    "transitional" => 31,
+    "transitionary" => 24,
+    "transitions" => 36,
+    "transitive" => 41,
+    "transitively" => 38,
+    "transitivity" => 33,
+    "transitory" => 31,
+    "translatable" => 29,
+    "translate" => 44,
+    "translated" => 45,
+    "translates" => 38,
+    "translating" => 38,
+    "translation" => 47,
+    "translations" => 45,
+    "translator" => 27,
+    "translators" => 27,
+    "transliterate" => 32,
+    "transliterated" => 29,
+    "transliterates" => 27,
+    "transliterating" => 24,
+    "transliteration" => 29,
+    "transliterations" => 27,
+    "transmission" => 41,
+    "transmit" => 38,
+    "transmits" => 32,
+    "transmitted" => 38,
+    "transmitter" => 36,
+    "transmitters" => 24,
+    "transmitting" => 32,
+    "transmogrified" => 24,
+    "transmogrifying" => 27,
+    "transmutability" => 29,
+    "transmutable" => 27,
+    "transmutation" => 36,
+    "transmutations" => 31,
+    "transmute" => 38,
+    "transmuted" => 36,
+    "transmutes" => 36,
+    "transmuting" => 38,
+    "transparency" => 36,
+    "transparent" => 43,
+    "transparently" => 39,
+    "transpired" => 31,
+    "transport" => 46,
+    "transportation" => 29,
+    "transported" => 33,
+    "transporting" => 24,
+    "transports" => 38,
+    "transpose" => 32,
+    "transposed" => 24,
+    "transposes" => 29,
+    "transposing" => 24,
+    "transposition" => 27,
+    "transpositions" => 29,
+    "transverse" => 24,
+    "transversely" => 24,
+    "trap" => 39,
+    "trapped" => 24,
+    "trapping" => 32,
+    "traps" => 33,
+    "trash" => 33,
+    "trashed" => 24,
+    "travail" => 24,
+    "travel" => 38,
+    "traveling" => 30,
+    "travelled" => 37,
+    "traveller" => 33,
+    "traveller's" => 24,
+    "travellers" => 39,
+    "travelling" => 37,
+    "travels" => 32,
+    "traversal" => 40,
+    "traversals" => 32,
+    "traverse" => 39,
+    "traversed" => 38,
+    "traverses" => 33,
+    "traversing" => 35,
+    "travis" => 48,
+    "trawl" => 24,
+    "tray" => 38,
+    "trays" => 24,
+    "treacherous" => 30,
+    "treachery" => 31,
+    "treacle" => 32,
+    "tread" => 33,
+    "treading" => 29,
+    "treadmill" => 27,
+    "treason" => 33,
+    "treasure" => 39,
+    "treasured" => 24,
+    "treasures" => 34,
+    "treasury" => 33,
+    "treat" => 45,
+    "treated" => 47,
+    "treaties" => 29,
+    "treating" => 38,
+    "treatises" => 24,
+    "treatment" => 38,
+    "treats" => 40,
+    "treaty" => 31,
+    "treble" => 29,
+    "tree" => 53,
+    "trees" => 46,
+    "trellis" => 24,
+    "tremble" => 32,
+    "trembled" => 36,
+    "trembling" => 39,
+    "tremendous" => 37,
+    "tremendously" => 30,
+    "tremor" => 27,
+    "tremulous" => 27,
+    "trend" => 35,
+    "trends" => 37,
+    "trespasser" => 24,
+    "trespassers" => 24,
+    "tresses" => 30,
+    "trevor" => 31,
+    "triad" => 24,
+    "triage" => 24,
+    "trial" => 39,
+    "trials" => 36,
+    "triangle" => 39,
+    "triangles" => 33,
+    "triangular" => 32,
+    "triangulated" => 24,
+    "triangulates" => 24,
+    "triangulation" => 34,
+    "tribal" => 24,
+    "tribe" => 27,
+    "tribes" => 30,
+    "tribunals" => 29,
+    "tribune" => 24,
+    "tributaries" => 29,
+    "tributary" => 24,
+    "tribute" => 31,
+    "trice" => 27,
+    "trick" => 43,
+    "tricked" => 24,
+    "trickery" => 30,
+    "trickier" => 34,
+    "tricking" => 24,
+    "trickle" => 24,
+    "trickled" => 24,
+    "trickling" => 29,
+    "tricks" => 40,
+    "tricky" => 41,
+    "tried" => 47,
+    "triennial" => 24,
+    "tries" => 47,
+    "trifle" => 37,
+    "trifled" => 27,
+    "trifles" => 30,
+    "trifling" => 32,
+    "trigger" => 48,
+    "triggered" => 47,
+    "triggering" => 41,
+    "triggers" => 42,
+    "trigonometric" => 32,
+    "trigonometry" => 24,
+    "trilled" => 24,
+    "trillium" => 24,
+    "trim" => 40,
+    "trimly" => 24,
+    "trimmed" => 37,
+    "trimming" => 34,
+    "trims" => 30,
+    "trinity" => 29,
+    "trinkets" => 24,
+    "trinomial" => 24,
+    "trip" => 38,
+    "triple" => 45,
+    "triples" => 37,
+    "triplet" => 30,
+    "triplets" => 32,
+    "tripoli" => 24,
+    "tripped" => 29,
+    "tripping" => 32,
+    "trips" => 31,
+    "tristan" => 24,
+    "trite" => 24,
+    "triumph" => 35,
+    "triumphant" => 30,
+    "triumphantly" => 32,
+    "triumphed" => 27,
+    "trivia" => 27,
+    "trivial" => 44,
+    "trivially" => 40,
+    "trod" => 27,
+    "trojan" => 31,
+    "trolley" => 24,
+    "trolling" => 24,
+    "trombones" => 24,
+    "troop" => 27,
+    "trooped" => 27,
+    "troopers" => 24,
+    "trooping" => 24,
+    "troops" => 29,
+    "trophy" => 29,
+    "tropic" => 24,
+    "tropical" => 31,
+    "tropics" => 24,
+    "trot" => 32,
+    "trotted" => 27,
+    "trotting" => 30,
+    "trouble" => 47,
+    "troubled" => 38,
+    "troubles" => 36,
+    "troubleshooting" => 40,
+    "troublesome" => 30,
+    "troubling" => 24,
+    "trough" => 30,
+    "trounce" => 24,
+    "troupe" => 30,
+    "trouser" => 24,
+    "trousers" => 36,
+    "trout" => 24,
+    "trove" => 24,
+    "troy" => 24,
+    "truce" => 27,
+    "truck" => 29,
+    "truculent" => 24,
+    "trudge" => 24,
+    "true" => 53,
+    "truest" => 24,
+    "truly" => 40,
+    "trump" => 30,
+    "trumpery" => 24,
+    "trumpet" => 32,
+    "trumps" => 30,
+    "truncate" => 51,
+    "truncated" => 44,
+    "truncates" => 37,
+    "truncating" => 39,
+    "truncation" => 46,
+    "trundled" => 24,
+    "trunk" => 36,
+    "trunks" => 33,
+    "trust" => 46,
+    "trusted" => 40,
+    "trustees" => 29,
+    "trusting" => 34,
+    "trustless" => 27,
+    "trusts" => 29,
+    "trustworthiness" => 30,
+    "trustworthy" => 30,
+    "trusty" => 37,
+    "truth" => 43,
+    "truthful" => 24,
+    "truthfulness" => 24,
+    "try" => 55,
+    "trying" => 50,
+    "tub" => 24,
+    "tube" => 24,
+    "tubes" => 27,
+    "tuck" => 24,
+    "tucked" => 30,
+    "tucker" => 24,
+    "tudor" => 24,
+    "tuesday" => 34,
+    "tug" => 24,
+    "tugged" => 31,
+    "tugging" => 29,
+    "tulip" => 24,
+    "tumbled" => 32,
+    "tumbler" => 24,
+    "tumbling" => 32,
+    "tumult" => 31,
+    "tumultuous" => 32,
+    "tumultuously" => 24,
+    "tune" => 38,
+    "tuned" => 39,
+    "tunelessly" => 24,
+    "tunes" => 27,
+    "tungsten" => 24,
+    "tunic" => 27,
+    "tuning" => 37,
+    "tunnel" => 34,
+    "tunneled" => 24,
+    "tunneling" => 29,
+    "tunnels" => 27,
+    "tuple" => 52,
+    "turban" => 27,
+    "turbaned" => 24,
+    "turbans" => 24,
+    "turbine" => 24,
+    "turbo" => 35,
+    "turbulence" => 29,
+    "turbulent" => 27,
+    "tureen" => 24,
+    "turf" => 29,
+    "turgid" => 24,
+    "turin" => 27,
+    "turing" => 33,
+    "turk" => 34,
+    "turkey" => 30,
+    "turkeys" => 24,
+    "turkish" => 38,
+    "turkmen" => 27,
+    "turks" => 24,
+    "turmoil" => 31,
+    "turn" => 51,
+    "turnaround" => 24,
+    "turned" => 49,
+    "turner" => 38,
+    "turning" => 45,
+    "turnings" => 24,
+    "turnkey" => 27,
+    "turnkeys" => 27,
+    "turns" => 44,
+    "turtle" => 42,
+    "turtles" => 29,
+    "tut" => 35,
+    "tutor" => 41,
+    "tutored" => 24,
+    "tutorial" => 43,
+    "tutorials" => 35,
+    "tutors" => 24,
+    "tux" => 29,
+    "twain" => 24,
+    "tweak" => 38,
+    "tweaked" => 33,
+    "tweaking" => 33,
+    "tweaks" => 35,
+    "twee" => 27,
+    "tweed" => 29,
+    "tweet" => 29,
+    "tweets" => 24,
+    "twelfth" => 32,
+    "twelve" => 42,
+    "twentieth" => 32,
+    "twenty" => 47,
+    "twice" => 50,
+    "twiddling" => 27,
+    "twig" => 29,
+    "twiggy" => 24,
+    "twilight" => 37,
+    "twin" => 24,
+    "twine" => 24,
+    "twined" => 24,
+    "twinkle" => 35,
+    "twinkled" => 32,
+    "twinkling" => 32,
+    "twins" => 29,
+    "twirp" => 29,
+    "twist" => 35,
+    "twisted" => 37,
+    "twister" => 30,
+    "twisting" => 31,
+    "twitch" => 33,
+    "twitching" => 29,
+    "twitter" => 37,
+    "two" => 60,
+    "twofold" => 27,
+    "twopence" => 27,
+    "twos" => 24,
+    "tycoon" => 24,
+    "tying" => 32,
+    "tyler" => 30,
+    "tympani" => 24,
+    "type" => 64,
+    "typecast" => 38,
+    "typecasts" => 32,
+    "typed" => 50,
+    "types" => 61,
+    "typescript" => 42,
+    "typeset" => 33,
+    "typesetting" => 35,
+    "typewrite" => 24,
+    "typewriter" => 31,
+    "typewriting" => 30,
+    "typewritten" => 32,
+    "typhoon" => 32,
+    "typical" => 45,
+    "typically" => 51,
+    "typifies" => 24,
+    "typify" => 36,
+    "typing" => 50,
+    "typo" => 43,
+    "typographic" => 24,
+    "typographical" => 27,
+    "typography" => 34,
+    "typos" => 43,
+    "tyrannical" => 27,
+    "tyranny" => 27,
+    "tyrant" => 27,
+    "tyrants" => 24,
+    "tyres" => 24,
+    "tyros" => 24,
+    "ub" => 43,
+    "ubiquitous" => 33,
+    "uganda" => 34,
+    "ugandans" => 24,
+    "ugh" => 27,
+    "ugliest" => 24,
+    "uglify" => 24,
+    "ugliness" => 30,
+    "ugly" => 39,
+    "ukraine" => 30,
+    "ukrainian" => 34,
+    "ulster" => 32,
+    "ultimate" => 31,
+    "ultimately" => 42,
+    "ultra" => 35,
+    "ultramarine" => 24,
+    "ultraviolet" => 27,
+    "ulysses" => 24,
+    "umbrella" => 34,
+    "umlaut" => 31,
+    "una" => 39,
+    "unabated" => 24,
+    "unable" => 44,
+    "unabridged" => 24,
+    "unacceptable" => 37,
+    "unacceptably" => 24,
+    "unaccountable" => 31,
+    "unaccountably" => 24,
+    "unaccustomed" => 24,
+    "unacknowledged" => 27,
+    "unacquainted" => 31,
+    "unadaptable" => 24,
+    "unaddressed" => 24,
+    "unadept" => 24,
+    "unadorned" => 27,
+    "unaffected" => 36,
+    "unaligned" => 43,
+    "unallocated" => 27,
+    "unalloyed" => 24,
+    "unalterable" => 24,
+    "unaltered" => 31,
+    "unambiguity" => 24,
+    "unambiguous" => 37,
+    "unambiguously" => 34,
+    "unanimous" => 27,
+    "unanimously" => 24,
+    "unanswered" => 27,
+    "unanticipated" => 24,
+    "unappeasable" => 27,
+    "unapproachable" => 24,
+    "unapproved" => 24,
+    "unarmed" => 24,
+    "unary" => 40,
+    "unashamed" => 24,
+    "unassigned" => 29,
+    "unattainable" => 24,
+    "unattended" => 27,
+    "unattributed" => 24,
+    "unauthenticated" => 32,
+    "unauthorized" => 27,
+    "unavailability" => 24,
+    "unavailable" => 41,
+    "unavailing" => 27,
+    "unavenged" => 24,
+    "unavoidable" => 34,
+    "unavoidably" => 24,
+    "unaware" => 29,
+    "unawares" => 27,
+    "unbalanced" => 30,
+    "unbarred" => 24,
+    "unbearable" => 24,
+    "unbeatable" => 27,
+    "unbelief" => 27,
+    "unbelievable" => 24,
+    "unbending" => 24,
+    "unbiased" => 27,
+    "unbind" => 29,
+    "unblock" => 29,
+    "unblocked" => 31,
+    "unblocking" => 24,
+    "unblocks" => 30,
+    "unbound" => 37,
+    "unbounded" => 42,
+    "unboundedly" => 24,
+    "unboxed" => 27,
+    "unbreakable" => 27,
+    "unbridled" => 24,
+    "unbroken" => 31,
+    "unbuffered" => 27,
+    "unburned" => 24,
+    "unbutton" => 24,
+    "unbuttoned" => 24,
+    "uncarpeted" => 24,
+    "uncased" => 27,
+    "uncaught" => 40,
+    "unceasing" => 24,
+    "uncertain" => 37,
+    "uncertainly" => 24,
+    "uncertainty" => 35,
+    "unchain" => 24,
+    "unchained" => 24,
+    "unchangeable" => 31,
+    "unchanged" => 45,
+    "unchanging" => 24,
+    "unchecked" => 42,
+    "uncial" => 24,
+    "uncivil" => 24,
+    "uncivilized" => 27,
+    "unclasping" => 24,
+    "unclassified" => 24,
+    "uncle" => 39,
+    "unclean" => 24,
+    "unclear" => 39,
+    "uncles" => 24,
+    "unclosed" => 36,
+    "uncollected" => 24,
+    "uncolored" => 24,
+    "uncombed" => 24,
+    "uncomfortable" => 36,
+    "uncomfortably" => 29,
+    "uncommitted" => 34,
+    "uncommon" => 38,
+    "uncommonly" => 27,
+    "uncomplainingly" => 24,
+    "uncompressed" => 37,
+    "uncompromising" => 24,
+    "unconcealed" => 24,
+    "unconcerned" => 24,
+    "unconditional" => 35,
+    "unconditionally" => 41,
+    "uncongenial" => 24,
+    "unconscious" => 36,
+    "unconsciously" => 32,
+    "unconstrained" => 27,
+    "unconsumed" => 27,
+    "uncontrollable" => 32,
+    "uncontrollably" => 24,
+    "uncontrolled" => 27,
+    "uncontroversial" => 27,
+    "unconverted" => 24,
+    "unconvincingly" => 24,
+    "uncork" => 27,
+    "uncorked" => 24,
+    "uncountable" => 24,
+    "uncoupled" => 24,
+    "uncouth" => 30,
+    "uncover" => 24,
+    "uncovered" => 46,
+    "unction" => 30,
+    "uncut" => 24,
+    "undated" => 24,
+    "undeceive" => 24,
+    "undeceiving" => 24,
+    "undecided" => 33,
+    "undeclared" => 24,
+    "undecorated" => 24,
+    "undefined" => 53,
+    "undeniable" => 24,
+    "undeprecated" => 30,
+    "under" => 59,
+    "underbrush" => 24,
+    "underestimates" => 24,
+    "underflow" => 34,
+    "undergo" => 33,
+    "undergoes" => 27,
+    "undergoing" => 30,
+    "undergone" => 33,
+    "undergraduate" => 32,
+    "underground" => 30,
+    "undergrowth" => 24,
+    "underhand" => 24,
+    "underlie" => 27,
+    "underlies" => 31,
+    "underline" => 40,
+    "underlined" => 35,
+    "underlines" => 24,
+    "underlining" => 31,
+    "underlying" => 52,
+    "underneath" => 34,
+    "underpinning" => 29,
+    "underpinnings" => 24,
+    "underpins" => 24,
+    "underscore" => 45,
+    "underscored" => 24,
+    "underscores" => 43,
+    "underscoring" => 24,
+    "understand" => 49,
+    "understandable" => 31,
+    "understandably" => 24,
+    "understanding" => 43,
+    "understandingly" => 27,
+    "understands" => 39,
+    "understood" => 42,
+    "understudy" => 24,
+    "undertake" => 32,
+    "undertaken" => 27,
+    "undertaking" => 36,
+    "undertone" => 27,
+    "undertook" => 24,
+    "underway" => 30,
+    "underwear" => 27,
+    "underwent" => 24,
+    "underwriting" => 24,
+    "undesirable" => 37,
+    "undesired" => 35,
+    "undespairingly" => 24,
+    "undetectable" => 24,
+    "undetected" => 27,
+    "undid" => 24,
+    "undiminished" => 24,
+    "undirected" => 31,
+    "undiscovered" => 27,
+    "undisturbed" => 32,
+    "undivided" => 24,
+    "undo" => 51,
+    "undoable" => 31,
+    "undocumented" => 38,
+    "undoes" => 36,
+    "undoing" => 34,
+    "undone" => 39,
+    "undoubtedly" => 35,
+    "undreamed" => 24,
+    "undressed" => 27,
+    "undropped" => 24,
+    "undue" => 31,
+    "undulations" => 27,
+    "unearthed" => 24,
+    "unearthly" => 29,
+    "uneasily" => 29,
+    "uneasiness" => 32,
+    "uneasy" => 36,
+    "unedited" => 24,
+    "uneducated" => 24,
+    "unemployed" => 24,
+    "unencumbered" => 30,
+    "unenforced" => 24,
+    "unequal" => 31,
+    "unequalled" => 24,
+    "unequally" => 27,
+    "unequivocal" => 27,
+    "unessential" => 24,
+    "uneven" => 29,
+    "unexampled" => 24,
+    "unexpanded" => 30,
+    "unexpected" => 46,
+    "unexpectedly" => 41,
+    "unexperienced" => 24,
+    "unexplored" => 29,
+    "unexported" => 27,
+    "unfailing" => 24,
+    "unfailingly" => 24,
+    "unfair" => 32,
+    "unfamiliar" => 36,
+    "unfashioned" => 24,
+    "unfathomable" => 27,
+    "unfavorably" => 24,
+    "unfavourable" => 30,
+    "unfeasible" => 27,
+    "unfeeling" => 30,
+    "unfeigned" => 27,
+    "unfenced" => 24,
+    "unfettered" => 24,
+    "unfilled" => 35,
+    "unfiltered" => 24,
+    "unfinished" => 38,
+    "unfit" => 29,
+    "unfitness" => 24,
+    "unfitted" => 24,
+    "unfixed" => 24,
+    "unflagging" => 24,
+    "unflattened" => 24,
+    "unfold" => 29,
+    "unfolded" => 32,
+    "unfolding" => 29,
+    "unfolds" => 24,
+    "unforced" => 24,
+    "unforeseen" => 32,
+    "unforgettable" => 24,
+    "unforgivably" => 24,
+    "unformed" => 24,
+    "unfortunate" => 40,
+    "unfortunately" => 46,
+    "unfrequented" => 24,
+    "unfriendly" => 24,
+    "unfulfilled" => 33,
+    "unfurled" => 27,
+    "ungated" => 24,
+    "ungenerously" => 24,
+    "ungovernable" => 24,
+    "ungraceful" => 24,
+    "ungracefully" => 24,
+    "ungrateful" => 29,
+    "unguarded" => 27,
+    "unhallowed" => 30,
+    "unhandled" => 38,
+    "unhappily" => 30,
+    "unhappiness" => 30,
+    "unhappy" => 39,
+    "unharmed" => 24,
+    "unhealthy" => 27,
+    "unheard" => 30,
+    "unheeded" => 24,
+    "unhelpful" => 27,
+    "unhidden" => 24,
+    "unhighlighted" => 24,
+    "unholy" => 27,
+    "unhygienic" => 29,
+    "uni" => 30,
+    "unideal" => 24,
+    "unidentified" => 24,
+    "unidiomatic" => 29,
+    "unidirectional" => 27,
+    "unification" => 38,
+    "unified" => 42,
+    "unifies" => 31,
+    "uniform" => 42,
+    "uniformly" => 38,
+    "unify" => 38,
+    "unifying" => 35,
+    "unimaginable" => 24,
+    "unimpeachable" => 27,
+    "unimplemented" => 42,
+    "unimportant" => 33,
+    "unindented" => 30,
+    "uninflected" => 24,
+    "uninformed" => 24,
+    "uninhabited" => 39,
+    "uninitialized" => 50,
+    "uninitiated" => 24,
+    "uninstructed" => 24,
+    "unintelligibility" => 24,
+    "unintelligible" => 29,
+    "unintended" => 36,
+    "unintentional" => 33,
+    "unintentionally" => 35,
+    "uninterested" => 27,
+    "uninteresting" => 24,
+    "uninterpreted" => 24,
+    "uninterrupted" => 27,
+    "union" => 49,
+    "unionise" => 27,
+    "unions" => 41,
+    "unique" => 50,
+    "uniquely" => 40,
+    "uniqueness" => 34,
+    "unirrigated" => 24,
+    "unison" => 29,
+    "unit" => 51,
+    "unitary" => 24,
+    "unite" => 24,
+    "united" => 46,
+    "unites" => 27,
+    "units" => 47,
+    "unity" => 31,
+    "universal" => 44,
+    "universalised" => 24,
+    "universally" => 32,
+    "universe" => 34,
+    "universities" => 34,
+    "university" => 44,
+    "unix" => 56,
+    "unjust" => 30,
+    "unjustly" => 30,
+    "unkempt" => 29,
+    "unkindly" => 24,
+    "unkindness" => 27,
+    "unknowingly" => 31,
+    "unknown" => 51,
+    "unknowns" => 27,
+    "unlabeled" => 31,
+    "unlawful" => 24,
+    "unleash" => 31,
+    "unleashing" => 27,
+    "unless" => 56,
+    "unlike" => 50,
+    "unlikely" => 44,
+    "unlimited" => 37,
+    "unlink" => 33,
+    "unlinked" => 29,
+    "unlisted" => 37,
+    "unload" => 35,
+    "unloaded" => 38,
+    "unloading" => 36,
+    "unloads" => 27,
+    "unlock" => 38,
+    "unlocked" => 41,
+    "unlocking" => 34,
+    "unlocks" => 31,
+    "unloosed" => 24,
+    "unluckily" => 24,
+    "unlucky" => 29,
+    "unmaintained" => 40,
+    "unmanageable" => 24,
+    "unmanaged" => 31,
+    "unmangled" => 27,
+    "unmapped" => 29,
+    "unmarked" => 27,
+    "unmarried" => 24,
+    "unmask" => 24,
+    "unmasked" => 31,
+    "unmatched" => 37,
+    "unmentionable" => 24,
+    "unmentioned" => 24,
+    "unmerged" => 29,
+    "unmet" => 24,
+    "unmistakable" => 30,
+    "unmistakably" => 29,
+    "unmodified" => 40,
+    "unmolested" => 24,
+    "unmoved" => 24,
+    "unnameable" => 32,
+    "unnamed" => 45,
+    "unnatural" => 33,
+    "unnaturally" => 27,
+    "unnecessarily" => 40,
+    "unnecessary" => 46,
+    "unneeded" => 34,
+    "unnoticed" => 36,
+    "unnotified" => 27,
+    "unnumbered" => 29,
+    "uno" => 31,
+    "unobservable" => 24,
+    "unobservant" => 24,
+    "unobserved" => 27,
+    "unobtrusive" => 24,
+    "unobtrusively" => 24,
+    "unoccupied" => 24,
+    "unofficial" => 37,
+    "unopened" => 27,
+    "unordered" => 40,
+    "unorganized" => 24,
+    "unoriginal" => 27,
+    "unorthodox" => 24,
+    "unowned" => 24,
+    "unpack" => 44,
+    "unpacked" => 35,
+    "unpacking" => 38,
+    "unpacks" => 38,
+    "unpadded" => 24,
+    "unpaired" => 34,
+    "unpalatable" => 24,
+    "unpapered" => 24,
+    "unparalleled" => 32,
+    "unpardonable" => 24,
+    "unpatched" => 24,
+    "unperceived" => 30,
+    "unpin" => 31,
+    "unpinned" => 29,
+    "unpiped" => 27,
+    "unplaced" => 24,
+    "unplastered" => 27,
+    "unpleasant" => 36,
+    "unpleasantness" => 29,
+    "unplug" => 24,
+    "unpoisoned" => 27,
+    "unpolished" => 24,
+    "unpopulated" => 24,
+    "unpractised" => 24,
+    "unprecedented" => 27,
+    "unpredictability" => 27,
+    "unpredictable" => 38,
+    "unpredictably" => 24,
+    "unprejudiced" => 27,
+    "unprepared" => 31,
+    "unprintable" => 38,
+    "unprivileged" => 33,
+    "unprocessed" => 27,
+    "unprofitable" => 24,
+    "unpromising" => 24,
+    "unpropitious" => 27,
+    "unprotected" => 29,
+    "unproven" => 29,
+    "unpublished" => 35,
+    "unpunctual" => 24,
+    "unqualified" => 35,
+    "unquenchable" => 24,
+    "unquenched" => 24,
+    "unquestionable" => 24,
+    "unquestionably" => 27,
+    "unquiet" => 30,
+    "unquoted" => 36,
+    "unravel" => 27,
+    "unravelled" => 24,
+    "unravelling" => 24,
+    "unreachable" => 42,
+    "unread" => 27,
+    "unreadable" => 32,
+    "unreal" => 29,
+    "unrealistic" => 24,
+    "unreality" => 24,
+    "unreasonable" => 30,
+    "unreasonably" => 24,
+    "unreasoning" => 30,
+    "unrecognized" => 38,
+    "unrecoverable" => 37,
+    "unreduced" => 27,
+    "unrefined" => 24,
+    "unregistered" => 29,
+    "unrelated" => 39,
+    "unreleased" => 37,
+    "unreliability" => 24,
+    "unreliable" => 36,
+    "unremitted" => 24,
+    "unremitting" => 29,
+    "unreported" => 24,
+    "unreservedly" => 24,
+    "unresolved" => 32,
+    "unrest" => 24,
+    "unrestrained" => 29,
+    "unrestricted" => 27,
+    "unrewarding" => 27,
+    "unroll" => 35,
+    "unrolled" => 35,
+    "unrolling" => 37,
+    "unrolls" => 27,
+    "unromantic" => 24,
+    "unsafe" => 54,
+    "unsaid" => 24,
+    "unsatisfactory" => 24,
+    "unsatisfied" => 31,
+    "unsatisfying" => 24,
+    "unsaved" => 32,
+    "unscaled" => 27,
+    "unscientific" => 27,
+    "unscrewed" => 24,
+    "unscrewing" => 24,
+    "unscrupulously" => 24,
+    "unseal" => 24,
+    "unseat" => 24,
+    "unseen" => 24,
+    "unselected" => 24,
+    "unselfish" => 24,
+    "unseparated" => 31,
+    "unset" => 41,
+    "unsets" => 32,
+    "unsetting" => 31,
+    "unsettled" => 27,
+    "unshaken" => 24,
+    "unsigned" => 54,
+    "unsocial" => 24,
+    "unsoftened" => 24,
+    "unsolved" => 29,
+    "unsophisticated" => 27,
+    "unsorted" => 37,
+    "unsought" => 27,
+    "unsound" => 40,
+    "unsoundness" => 35,
+    "unspeakable" => 29,
+    "unspeakably" => 24,
+    "unspecified" => 48,
+    "unstable" => 50,
+    "unstaged" => 36,
+    "unstained" => 24,
+    "unsteady" => 27,
+    "unstressed" => 33,
+    "unstructured" => 35,
+    "unsubscribed" => 27,
+    "unsuccessful" => 36,
+    "unsuccessfully" => 31,
+    "unsuitable" => 35,
+    "unsullied" => 27,
+    "unsupervised" => 30,
+    "unsupported" => 45,
+    "unsure" => 35,
+    "unsurprising" => 27,
+    "unsympathetic" => 24,
+    "unsynchronized" => 31,
+    "unsystematic" => 24,
+    "untagged" => 36,
+    "untamed" => 27,
+    "untaught" => 24,
+    "untenable" => 27,
+    "unterminated" => 31,
+    "untested" => 37,
+    "unthinkable" => 24,
+    "unthoughtful" => 24,
+    "untidy" => 27,
+    "untied" => 24,
+    "until" => 54,
+    "untimely" => 30,
+    "untitled" => 24,
+    "unto" => 27,
+    "untouched" => 38,
+    "untracked" => 37,
+    "untransformed" => 24,
+    "untranslated" => 33,
+    "untrodden" => 24,
+    "untrue" => 29,
+    "untrusted" => 41,
+    "untrusting" => 24,
+    "untwist" => 24,
+    "untyped" => 37,
+    "unusable" => 34,
+    "unused" => 50,
+    "unusual" => 42,
+    "unusually" => 31,
+    "unutterable" => 30,
+    "unutterably" => 27,
+    "unvalidated" => 24,
+    "unvarying" => 24,
+    "unveiled" => 24,
+    "unvisited" => 24,
+    "unvoiced" => 24,
+    "unwanted" => 37,
+    "unwashed" => 24,
+    "unwavering" => 24,
+    "unwearied" => 24,
+    "unwearying" => 24,
+    "unwelcome" => 24,
+    "unwelcoming" => 24,
+    "unwell" => 24,
+    "unwhitewashed" => 24,
+    "unwholesome" => 29,
+    "unwieldy" => 33,
+    "unwilling" => 32,
+    "unwillingly" => 31,
+    "unwillingness" => 27,
+    "unwind" => 45,
+    "unwinding" => 46,
+    "unwinds" => 37,
+    "unwise" => 27,
+    "unwittingly" => 24,
+    "unwonted" => 24,
+    "unworldly" => 24,
+    "unworthiness" => 24,
+    "unworthy" => 29,
+    "unwound" => 32,
+    "unwrap" => 37,
+    "unwrapped" => 36,
+    "unwrapping" => 34,
+    "unwraps" => 36,
+    "unwrinkled" => 24,
+    "unwritten" => 30,
+    "unyielded" => 24,
+    "unyielding" => 27,
+    "unzip" => 34,
+    "unzipped" => 24,
+    "unzipping" => 29,
+    "unzips" => 24,
+    "up" => 60,
+    "upbraid" => 24,
+    "upbraided" => 24,
+    "upcoming" => 36,
+    "update" => 54,
+    "updated" => 51,
+    "updates" => 48,
+    "updating" => 48,
+    "upfront" => 35,
+    "upgrade" => 45,
+    "upgraded" => 39,
+    "upgrades" => 37,
+    "upgrading" => 42,
+    "upheld" => 40,
+    "uphill" => 24,
+    "uphold" => 43,
+    "upholding" => 32,
+    "upholds" => 33,
+    "upholstery" => 24,
+    "upkeep" => 24,
+    "uplift" => 24,
+    "uplifted" => 32,
+    "upload" => 40,
+    "uploaded" => 38,
+    "uploading" => 37,
+    "uploads" => 34,
+    "upon" => 55,
+    "upper" => 55,
+    "uppercase" => 45,
+    "uppercased" => 35,
+    "uppermost" => 29,
+    "upraised" => 24,
+    "upright" => 33,
+    "uproar" => 27,
+    "ups" => 32,
+    "upset" => 33,
+    "upsetting" => 29,
+    "upshot" => 24,
+    "upside" => 32,
+    "upsilon" => 32,
+    "upstairs" => 34,
+    "upstart" => 24,
+    "upstream" => 46,
+    "upturn" => 27,
+    "upward" => 37,
+    "upwards" => 39,
+    "uranus" => 24,
+    "urban" => 29,
+    "urbanization" => 24,
+    "urchin" => 24,
+    "urdu" => 27,
+    "urge" => 24,
+    "urged" => 40,
+    "urgency" => 27,
+    "urgent" => 30,
+    "urgently" => 24,
+    "urging" => 29,
+    "urn" => 29,
+    "us" => 54,
+    "usa" => 37,
+    "usability" => 38,
+    "usable" => 46,
+    "usage" => 58,
+    "usages" => 37,
+    "use" => 66,
+    "useable" => 30,
+    "used" => 64,
+    "useful" => 56,
+    "usefully" => 31,
+    "usefulness" => 37,
+    "useless" => 43,
+    "uselessly" => 27,
+    "uselessness" => 29,
+    "user" => 57,
+    "username" => 39,
+    "users" => 54,
+    "uses" => 58,
+    "ushered" => 27,
+    "ushering" => 24,
+    "using" => 65,
+    "usual" => 47,
+    "usually" => 52,
+    "usurpation" => 24,
+    "utah" => 32,
+    "utensils" => 27,
+    "utilise" => 31,
+    "utilises" => 27,
+    "utilising" => 24,
+    "utilities" => 48,
+    "utility" => 48,
+    "utilization" => 34,
+    "utilize" => 40,
+    "utilized" => 36,
+    "utilizes" => 36,
+    "utilizing" => 37,
+    "utmost" => 34,
+    "utter" => 37,
+    "utterance" => 30,
+    "utterances" => 24,
+    "uttered" => 37,
+    "uttering" => 32,
+    "utterly" => 38,
+    "uzbek" => 24,
+    "vacancies" => 33,
+    "vacancy" => 35,
+    "vacant" => 35,
+    "vacantly" => 24,
+    "vacated" => 24,
+    "vacation" => 33,
+    "vacillating" => 24,
+    "vacuous" => 29,
+    "vagabond" => 30,
+    "vagabonds" => 24,
+    "vagaries" => 24,
+    "vagrant" => 33,
+    "vagrants" => 24,
+    "vague" => 38,
+    "vaguely" => 33,
+    "vagueness" => 24,
+    "vaguest" => 24,
+    "vain" => 38,
+    "vainly" => 32,
+    "val" => 41,
+    "valance" => 24,
+    "vale" => 24,
+    "valence" => 32,
+    "valencia" => 31,
+    "valentine" => 24,
+    "vales" => 27,
+    "valet" => 29,
+    "valid" => 55,
+    "validate" => 44,
+    "validated" => 41,
+    "validates" => 38,
+    "validating" => 39,
+    "validation" => 48,
+    "validations" => 37,
+    "validity" => 45,
+    "valise" => 24,
+    "valley" => 39,
+    "valleys" => 24,
+    "valor" => 29,
+    "valour" => 24,
+    "valuable" => 39,
+    "valuables" => 27,
+    "value" => 63,
+    "valued" => 39,
+    "values" => 59,
+    "valuing" => 24,
+    "valve" => 29,
+    "valves" => 30,
+    "vampire" => 24,
+    "van" => 37,
+    "vandyke" => 27,
+    "vanilla" => 35,
+    "vanish" => 33,
+    "vanished" => 39,
+    "vanishes" => 27,
+    "vanishing" => 31,
+    "vanishingly" => 27,
+    "vanity" => 31,
+    "vanquish" => 24,
+    "vanquished" => 29,
+    "vans" => 24,
+    "variability" => 32,
+    "variable" => 58,
+    "variables" => 56,
+    "variance" => 42,
+    "variances" => 29,
+    "variant" => 52,
+    "variants" => 52,
+    "variates" => 24,
+    "variation" => 39,
+    "variations" => 39,
+    "varied" => 36,
+    "variegated" => 24,
+    "varies" => 44,
+    "varieties" => 39,
+    "variety" => 47,
+    "various" => 54,
+    "variously" => 24,
+    "varnish" => 27,
+    "varnished" => 24,
+    "vary" => 42,
+    "varying" => 37,
+    "vas" => 24,
+    "vase" => 24,
+    "vases" => 29,
+    "vast" => 42,
+    "vastly" => 32,
+    "vastness" => 24,
+    "vat" => 24,
+    "vault" => 34,
+    "vaulted" => 24,
+    "vaults" => 27,
+    "vcr" => 24,
+    "vector" => 57,
+    "vectored" => 27,
+    "vectors" => 50,
+    "veer" => 24,
+    "veered" => 27,
+    "veering" => 24,
+    "vegetable" => 24,
+    "vegetables" => 33,
+    "vegetarian" => 24,
+    "vegetation" => 24,
+    "vehemence" => 24,
+    "vehement" => 24,
+    "vehemently" => 24,
+    "vehicle" => 33,
+    "vehicles" => 27,
+    "veil" => 35,
+    "veiled" => 31,
+    "vein" => 29,
+    "veins" => 33,
+    "velar" => 27,
+    "velocity" => 34,
+    "velvet" => 31,
+    "vendor" => 44,
+    "vendors" => 38,
+    "veneer" => 24,
+    "venerable" => 32,
+    "venerated" => 24,
+    "vengeance" => 38,
+    "vengeful" => 24,
+    "venial" => 38,
+    "venice" => 24,
+    "venom" => 24,
+    "venomous" => 24,
+    "vent" => 29,
+    "ventilate" => 24,
+    "ventilation" => 24,
+    "ventilator" => 35,
+    "ventilators" => 24,
+    "venting" => 24,
+    "venture" => 38,
+    "ventured" => 36,
+    "ventures" => 29,
+    "venturesome" => 24,
+    "venturing" => 24,
+    "vera" => 24,
+    "veranda" => 30,
+    "verandas" => 30,
+    "verb" => 38,
+    "verbal" => 24,
+    "verbatim" => 41,
+    "verbose" => 48,
+    "verbosely" => 27,
+    "verbosity" => 37,
+    "verbs" => 36,
+    "verdant" => 30,
+    "verdict" => 33,
+    "verdicts" => 27,
+    "verdure" => 29,
+    "verge" => 33,
+    "verifiable" => 31,
+    "verification" => 47,
+    "verified" => 43,
+    "verifier" => 40,
+    "verifiers" => 32,
+    "verifies" => 36,
+    "verify" => 48,
+    "verifying" => 42,
+    "vermilion" => 24,
+    "vermont" => 29,
+    "vernacular" => 27,
+    "versa" => 42,
+    "versailles" => 29,
+    "versatile" => 36,
+    "versatility" => 27,
+    "verse" => 32,
+    "versed" => 27,
+    "verses" => 35,
+    "version" => 63,
+    "versions" => 56,
+    "versus" => 41,
+    "vertex" => 40,
+    "vertical" => 46,
+    "vertically" => 42,
+    "vertices" => 35,
+    "verus" => 24,
+    "very" => 58,
+    "vessel" => 39,
+    "vessels" => 35,
+    "vest" => 29,
+    "vested" => 30,
+    "vestibule" => 30,
+    "vestibules" => 24,
+    "vestige" => 29,
+    "vestiges" => 24,
+    "vestry" => 24,
+    "vet" => 29,
+    "veteran" => 24,
+    "veterans" => 24,
+    "vets" => 24,
+    "vetted" => 29,
+    "vex" => 33,
+    "vexation" => 32,
+    "vexations" => 27,
+    "vexatiously" => 24,
+    "vexed" => 29,
+    "vexing" => 24,
+    "via" => 57,
+    "viability" => 32,
+    "viable" => 35,
+    "viaducts" => 24,
+    "viands" => 27,
+    "vibes" => 24,
+    "vibrancy" => 31,
+    "vibrant" => 32,
+    "vibrated" => 27,
+    "vicar" => 24,
+    "vicariously" => 24,
+    "vice" => 45,
+    "vices" => 29,
+    "vicinity" => 33,
+    "vicious" => 32,
+    "vicissitudes" => 24,
+    "victim" => 39,
+    "victims" => 32,
+    "victor" => 39,
+    "victoria" => 34,
+    "victorious" => 29,
+    "victory" => 32,
+    "vid" => 24,
+    "video" => 43,
+    "videodisc" => 32,
+    "videodiscs" => 24,
+    "videos" => 33,
+    "videotape" => 24,
+    "vie" => 27,
+    "vietnamese" => 32,
+    "view" => 50,
+    "viewable" => 24,
+    "viewed" => 41,
+    "viewer" => 37,
+    "viewers" => 24,
+    "viewing" => 40,
+    "views" => 43,
+    "vigil" => 30,
+    "vigilance" => 24,
+    "vigilant" => 27,
+    "vigor" => 27,
+    "vigorous" => 32,
+    "vigorously" => 35,
+    "vigour" => 27,
+    "vile" => 35,
+    "vilest" => 29,
+    "villa" => 32,
+    "village" => 43,
+    "villager" => 24,
+    "villagers" => 33,
+    "villages" => 33,
+    "villain" => 34,
+    "villainous" => 24,
+    "villains" => 31,
+    "villainy" => 24,
+    "villas" => 27,
+    "vim" => 66,
+    "vincent" => 34,
+    "vindicating" => 24,
+    "vindictive" => 27,
+    "vine" => 27,
+    "vinegar" => 24,
+    "vines" => 34,
+    "vineyards" => 24,
+    "vinous" => 24,
+    "vintage" => 27,
+    "vinyl" => 24,
+    "viol" => 24,
+    "violate" => 41,
+    "violated" => 41,
+    "violates" => 35,
+    "violating" => 38,
+    "violation" => 40,
+    "violations" => 35,
+    "violence" => 40,
+    "violent" => 38,
+    "violently" => 36,
+    "violet" => 31,
+    "violets" => 24,
+    "violin" => 32,
+    "violins" => 24,
+    "viols" => 27,
+    "viral" => 24,
+    "virgil" => 24,
+    "virgin" => 24,
+    "virginia" => 33,
+    "virgins" => 24,
+    "virtual" => 49,
+    "virtually" => 34,
+    "virtue" => 37,
+    "virtues" => 34,
+    "virtuous" => 31,
+    "vis" => 41,
+    "visa" => 33,
+    "visage" => 30,
+    "vise" => 24,
+    "vishnu" => 29,
+    "visibility" => 46,
+    "visible" => 47,
+    "visibly" => 30,
+    "vision" => 39,
+    "visionary" => 27,
+    "visions" => 33,
+    "visit" => 46,
+    "visitation" => 32,
+    "visited" => 40,
+    "visiting" => 38,
+    "visitor" => 41,
+    "visitors" => 36,
+    "visits" => 37,
+    "vista" => 34,
+    "visual" => 54,
+    "visualise" => 24,
+    "visualises" => 24,
+    "visualising" => 24,
+    "visualization" => 39,
+    "visualizations" => 33,
+    "visualize" => 35,
+    "visualized" => 30,
+    "visualizes" => 24,
+    "visualizing" => 32,
+    "visually" => 40,
+    "visuals" => 24,
+    "vita" => 35,
+    "vital" => 32,
+    "vitality" => 32,
+    "vitals" => 29,
+    "vitriol" => 24,
+    "vivacity" => 30,
+    "vivid" => 33,
+    "vividly" => 24,
+    "viz" => 30,
+    "vladimir" => 33,
+    "vocabularies" => 24,
+    "vocabulary" => 34,
+    "vocal" => 29,
+    "vocation" => 24,
+    "vogue" => 24,
+    "voice" => 48,
+    "voiced" => 33,
+    "voiceless" => 33,
+    "voices" => 38,
+    "voicing" => 24,
+    "void" => 43,
+    "voids" => 24,
+    "volatile" => 42,
+    "volatility" => 27,
+    "volcanic" => 24,
+    "volcano" => 24,
+    "volley" => 30,
+    "volleying" => 24,
+    "voltage" => 30,
+    "volts" => 24,
+    "volubly" => 24,
+    "volume" => 43,
+    "volumes" => 39,
+    "voluntarily" => 29,
+    "voluntary" => 32,
+    "volunteer" => 33,
+    "volunteered" => 27,
+    "volunteers" => 32,
+    "voluptuous" => 24,
+    "vomited" => 24,
+    "voodoo" => 24,
+    "voracious" => 24,
+    "voraciously" => 24,
+    "votary" => 24,
+    "vote" => 38,
+    "voted" => 31,
+    "voter" => 27,
+    "voters" => 27,
+    "votes" => 36,
+    "voting" => 32,
+    "vouching" => 24,
+    "vow" => 31,
+    "vowed" => 27,
+    "vowel" => 39,
+    "vowels" => 37,
+    "vows" => 29,
+    "vox" => 27,
+    "voyage" => 39,
+    "voyages" => 30,
+    "vroom" => 24,
+    "vulgar" => 38,
+    "vulnerability" => 37,
+    "vulnerable" => 37,
+    "vulture" => 27,
+    "wad" => 24,
+    "wadding" => 24,
+    "waddling" => 24,
+    "wade" => 27,
+    "waded" => 27,
+    "wading" => 29,
+    "wads" => 24,
+    "wafer" => 24,
+    "wafted" => 30,
+    "wag" => 27,
+    "wager" => 38,
+    "wagers" => 29,
+    "wages" => 31,
+    "wagged" => 27,
+    "waggled" => 24,
+    "wagon" => 34,
+    "wagons" => 27,
+    "wags" => 24,
+    "waifs" => 24,
+    "wail" => 30,
+    "wailed" => 24,
+    "wailing" => 24,
+    "waist" => 31,
+    "waistcoat" => 35,
+    "waists" => 27,
+    "wait" => 51,
+    "waited" => 44,
+    "waiter" => 34,
+    "waiters" => 32,
+    "waiting" => 49,
+    "waits" => 42,
+    "wake" => 43,
+    "waked" => 29,
+    "wakefield" => 24,
+    "wakefulness" => 24,
+    "wakes" => 31,
+    "waking" => 34,
+    "walk" => 43,
+    "walked" => 44,
+    "walker" => 39,
+    "walkers" => 29,
+    "walking" => 42,
+    "walks" => 38,
+    "wall" => 43,
+    "wallace" => 24,
+    "walled" => 24,
+    "wallet" => 40,
+    "wallets" => 35,
+    "walloon" => 24,
+    "wallowed" => 24,
+    "walls" => 38,
+    "walnut" => 24,
+    "walrus" => 29,
+    "walsall" => 24,
+    "walsh" => 24,
+    "walsingham" => 24,
+    "walter" => 40,
+    "walters" => 33,
+    "walton" => 33,
+    "waltz" => 24,
+    "wan" => 31,
+    "wand" => 24,
+    "wander" => 35,
+    "wandered" => 40,
+    "wanderer" => 27,
+    "wanderers" => 24,
+    "wandering" => 36,
+    "wanderings" => 30,
+    "wanders" => 24,
+    "wane" => 27,
+    "waned" => 27,
+    "wang" => 33,
+    "waning" => 24,
+    "wanly" => 24,
+    "want" => 59,
+    "wanted" => 48,
+    "wanting" => 39,
+    "wantonly" => 27,
+    "wantonness" => 24,
+    "wants" => 45,
+    "war" => 40,
+    "warble" => 24,
+    "warbler" => 24,
+    "warbling" => 24,
+    "ward" => 27,
+    "wardrobe" => 31,
+    "wardrobes" => 24,
+    "warehouse" => 27,
+    "warehoused" => 24,
+    "warehouses" => 27,
+    "warily" => 31,
+    "warlike" => 24,
+    "warm" => 39,
+    "warmed" => 33,
+    "warmer" => 27,
+    "warmest" => 31,
+    "warmly" => 33,
+    "warmth" => 36,
+    "warn" => 45,
+    "warned" => 38,
+    "warner" => 24,
+    "warning" => 54,
+    "warnings" => 52,
+    "warns" => 45,
+    "warp" => 38,
+    "warpath" => 24,
+    "warped" => 27,
+    "warping" => 24,
+    "warrant" => 38,
+    "warranted" => 31,
+    "warranties" => 38,
+    "warrants" => 29,
+    "warranty" => 38,
+    "warren" => 24,
+    "warring" => 27,
+    "warrior" => 24,
+    "wars" => 30,
+    "warsaw" => 27,
+    "wart" => 31,
+    "warts" => 33,
+    "warwick" => 24,
+    "wary" => 32,
+    "was" => 64,
+    "wash" => 35,
+    "washed" => 27,
+    "washing" => 34,
+    "washington" => 39,
+    "wasn't" => 46,
+    "waste" => 38,
+    "wasted" => 38,
+    "wasteful" => 32,
+    "wastes" => 33,
+    "wasting" => 36,
+    "watch" => 46,
+    "watched" => 41,
+    "watcher" => 34,
+    "watchers" => 33,
+    "watches" => 31,
+    "watchful" => 31,
+    "watchfully" => 24,
+    "watching" => 42,
+    "watchman" => 27,
+    "water" => 45,
+    "watered" => 30,
+    "waterfall" => 24,
+    "waterfalls" => 24,
+    "watering" => 27,
+    "waterloo" => 33,
+    "waterman" => 30,
+    "watermarking" => 24,
+    "watermarks" => 24,
+    "waterproof" => 27,
+    "waters" => 40,
+    "waterspout" => 24,
+    "watertight" => 24,
+    "waterway" => 24,
+    "watery" => 27,
+    "watson" => 43,
+    "watt" => 27,
+    "wave" => 38,
+    "waved" => 35,
+    "wavefront" => 38,
+    "waver" => 24,
+    "wavered" => 30,
+    "waverer" => 24,
+    "wavering" => 29,
+    "waves" => 40,
+    "waving" => 34,
+    "wax" => 35,
+    "waxed" => 24,
+    "waxing" => 24,
+    "way" => 59,
+    "waylaid" => 30,
+    "waylay" => 27,
+    "wayne" => 30,
+    "ways" => 52,
+    "wayside" => 29,
+    "wayward" => 27,
+    "we" => 63,
+    "we'd" => 42,
+    "we'll" => 53,
+    "we're" => 50,
+    "we've" => 49,
+    "weak" => 46,
+    "weaken" => 30,
+    "weakened" => 29,
+    "weakening" => 30,
+    "weaker" => 35,
+    "weakest" => 30,
+    "weaklings" => 24,
+    "weakly" => 33,
+    "weakness" => 34,
+    "weaknesses" => 33,
+    "wealth" => 36,
+    "wealthier" => 24,
+    "wealthy" => 34,
+    "wean" => 24,
+    "weapon" => 35,
+    "weapons" => 32,
+    "wear" => 37,
+    "wearer" => 24,
+    "wearied" => 27,
+    "wearily" => 29,
+    "weariness" => 27,
+    "wearing" => 33,
+    "wearisome" => 29,
+    "wears" => 29,
+    "weary" => 37,
+    "wearying" => 24,
+    "weather" => 40,
+    "weatherbeaten" => 24,
+    "weathered" => 24,
+    "weatherproof" => 24,
+    "weave" => 27,
+    "weaved" => 27,
+    "weaver" => 27,
+    "web" => 52,
+    "webb" => 40,
+    "weber" => 35,
+    "website" => 46,
+    "webster" => 29,
+    "wed" => 24,
+    "wedding" => 39,
+    "wedge" => 29,
+    "wedged" => 24,
+    "wedges" => 27,
+    "wedging" => 24,
+    "wedlock" => 24,
+    "wednesday" => 36,
+    "wee" => 29,
+    "weed" => 29,
+    "weeding" => 24,
+    "weeds" => 33,
+    "weedy" => 27,
+    "week" => 45,
+    "weekday" => 36,
+    "weekdays" => 32,
+    "weekend" => 27,
+    "weekly" => 29,
+    "weeks" => 42,
+    "weep" => 34,
+    "weeping" => 33,
+    "weeps" => 24,
+    "weigh" => 30,
+    "weighed" => 35,
+    "weighing" => 31,
+    "weighs" => 24,
+    "weight" => 42,
+    "weighted" => 36,
+    "weighting" => 27,
+    "weights" => 38,
+    "weighty" => 24,
+    "weird" => 41,
+    "weirdly" => 24,
+    "weirdness" => 27,
+    "welch" => 24,
+    "welcome" => 50,
+    "welcomed" => 37,
+    "welcomes" => 38,
+    "welcoming" => 33,
+    "welded" => 24,
+    "welding" => 24,
+    "welfare" => 30,
+    "well" => 57,
+    "wellington" => 24,
+    "wellnigh" => 24,
+    "welsh" => 24,
+    "welshman" => 35,
+    "weltering" => 24,
+    "wen" => 33,
+    "wended" => 27,
+    "wending" => 24,
+    "went" => 51,
+    "wept" => 37,
+    "were" => 58,
+    "weren't" => 38,
+    "wesley" => 29,
+    "wessex" => 24,
+    "west" => 43,
+    "westbury" => 24,
+    "western" => 36,
+    "westerners" => 24,
+    "westminster" => 27,
+    "westmorland" => 27,
+    "westward" => 31,
+    "wet" => 38,
+    "wether" => 24,
+    "wetting" => 24,
+    "whack" => 32,
+    "whacks" => 24,
+    "whale" => 30,
+    "whaler" => 24,
+    "wharf" => 32,
+    "wharves" => 31,
+    "what" => 60,
+    "whatever" => 47,
+    "whatnot" => 27,
+    "whatsoever" => 38,
+    "wheat" => 32,
+    "wheedle" => 24,
+    "wheel" => 42,
+    "wheelbarrow" => 24,
+    "wheeled" => 32,
+    "wheeler" => 30,
+    "wheeling" => 24,
+    "wheels" => 37,
+    "when" => 67,
+    "whence" => 37,
+    "whenever" => 49,
+    "where" => 60,
+    "whereabouts" => 32,
+    "whereas" => 45,
+    "whereby" => 31,
+    "wherefore" => 29,
+    "wherein" => 33,
+    "whereof" => 30,
+    "whereon" => 27,
+    "whereupon" => 35,
+    "wherever" => 42,
+    "whether" => 55,
+    "whetted" => 24,
+    "whew" => 32,
+    "which" => 65,
+    "whichever" => 40,
+    "whiff" => 27,
+    "while" => 58,
+    "whiles" => 24,
+    "whilst" => 39,
+    "whim" => 30,
+    "whimpered" => 24,
+    "whims" => 24,
+    "whimsical" => 30,
+    "whine" => 30,
+    "whined" => 24,
+    "whining" => 24,
+    "whip" => 31,
+    "whipcord" => 29,
+    "whipped" => 32,
+    "whipping" => 29,
+    "whippings" => 24,
+    "whippoorwill" => 24,
+    "whips" => 24,
+    "whirled" => 29,
+    "whirling" => 33,
+    "whirlpool" => 24,
+    "whirlwind" => 27,
+    "whirlwinds" => 27,
+    "whiskered" => 24,
+    "whiskers" => 35,
+    "whiskey" => 35,
+    "whisky" => 27,
+    "whisper" => 38,
+    "whispered" => 42,
+    "whispering" => 31,
+    "whisperings" => 31,
+    "whispers" => 34,
+    "whist" => 36,
+    "whistle" => 38,
+    "whistled" => 31,
+    "whistles" => 34,
+    "whistling" => 32,
+    "whit" => 24,
+    "white" => 51,
+    "whitebait" => 24,
+    "whitehead" => 24,
+    "whiten" => 24,
+    "whitened" => 24,
+    "whiteness" => 27,
+    "whiter" => 24,
+    "whitespace" => 48,
+    "whitewash" => 33,
+    "whitewashed" => 34,
+    "whitewashing" => 30,
+    "whither" => 33,
+    "whiting" => 33,
+    "whitney" => 32,
+    "whiz" => 29,
+    "whizz" => 24,
+    "whizzed" => 29,
+    "who" => 55,
+    "who'd" => 31,
+    "who's" => 37,
+    "whoa" => 29,
+    "whoever" => 35,
+    "whole" => 52,
+    "wholesale" => 24,
+    "wholesome" => 29,
+    "wholly" => 36,
+    "whom" => 47,
+    "whomsoever" => 24,
+    "whoop" => 30,
+    "whooped" => 24,
+    "whooping" => 29,
+    "whoops" => 24,
+    "whose" => 51,
+    "whosoever" => 24,
+    "why" => 54,
+    "wick" => 27,
+    "wicked" => 36,
+    "wickedly" => 27,
+    "wickedness" => 33,
+    "wicker" => 29,
+    "wicket" => 24,
+    "wide" => 50,
+    "widely" => 44,
+    "widen" => 27,
+    "widened" => 30,
+    "widening" => 33,
+    "widens" => 24,
+    "wider" => 40,
+    "widespread" => 36,
+    "widest" => 31,
+    "widget" => 40,
+    "widgets" => 41,
+    "widow" => 39,
+    "widow's" => 34,
+    "widower" => 29,
+    "width" => 51,
+    "widths" => 37,
+    "wife" => 44,
+    "wig" => 34,
+    "wiggle" => 32,
+    "wight" => 24,
+    "wigs" => 27,
+    "wilbur" => 24,
+    "wild" => 43,
+    "wildcard" => 45,
+    "wilder" => 24,
+    "wilderness" => 24,
+    "wildest" => 30,
+    "wildly" => 34,
+    "wildness" => 30,
+    "wilds" => 29,
+    "wiles" => 24,
+    "wilful" => 24,
+    "wiliness" => 24,
+    "will" => 66,
+    "william" => 42,
+    "williams" => 46,
+    "willie" => 27,
+    "willing" => 40,
+    "willingly" => 34,
+    "willingness" => 27,
+    "willoughby" => 24,
+    "willow" => 24,
+    "willows" => 27,
+    "willowy" => 24,
+    "willy" => 27,
+    "wilson" => 43,
+    "wilt" => 24,
+    "wilted" => 27,
+    "wilton" => 24,
+    "wily" => 24,
+    "win" => 55,
+    "winced" => 29,
+    "winch" => 24,
+    "winchester" => 34,
+    "wincing" => 24,
+    "wind" => 44,
+    "winded" => 29,
+    "windfall" => 29,
+    "windfalls" => 24,
+    "winding" => 36,
+    "windings" => 30,
+    "window" => 60,
+    "windowed" => 27,
+    "windowing" => 38,
+    "windowless" => 24,
+    "windows" => 60,
+    "windowsill" => 24,
+    "winds" => 36,
+    "windsor" => 27,
+    "windy" => 27,
+    "wine" => 36,
+    "wines" => 24,
+    "wing" => 34,
+    "winged" => 27,
+    "winging" => 24,
+    "wingless" => 24,
+    "wings" => 36,
+    "wink" => 32,
+    "winked" => 30,
+    "winking" => 29,
+    "winks" => 24,
+    "winner" => 29,
+    "winning" => 33,
+    "winnings" => 24,
+    "winnow" => 30,
+    "wins" => 37,
+    "winter" => 39,
+    "winters" => 30,
+    "wintry" => 24,
+    "wipe" => 38,
+    "wiped" => 37,
+    "wipes" => 33,
+    "wiping" => 36,
+    "wire" => 43,
+    "wired" => 33,
+    "wireless" => 27,
+    "wires" => 32,
+    "wiring" => 27,
+    "wiry" => 24,
+    "wisconsin" => 27,
+    "wisdom" => 33,
+    "wise" => 44,
+    "wised" => 24,
+    "wisely" => 30,
+    "wiser" => 30,
+    "wisest" => 29,
+    "wish" => 49,
+    "wished" => 42,
+    "wishes" => 41,
+    "wishing" => 39,
+    "wisp" => 29,
+    "wistfully" => 31,
+    "wistfulness" => 24,
+    "wit" => 39,
+    "witch" => 29,
+    "witched" => 24,
+    "witches" => 30,
+    "witching" => 29,
+    "with" => 69,
+    "withal" => 27,
+    "withdraw" => 27,
+    "withdrawal" => 24,
+    "withdrawals" => 24,
+    "withdrawing" => 24,
+    "withdrawn" => 29,
+    "withdrew" => 27,
+    "withered" => 29,
+    "withers" => 30,
+    "withheld" => 24,
+    "withhold" => 24,
+    "within" => 56,
+    "without" => 61,
+    "withstand" => 27,
+    "withstood" => 24,
+    "witness" => 42,
+    "witnessed" => 32,
+    "witnesses" => 37,
+    "witnessing" => 29,
+    "wits" => 31,
+    "witted" => 27,
+    "witty" => 24,
+    "wives" => 33,
+    "wizard" => 27,
+    "wizardry" => 24,
+    "woe" => 32,
+    "woeful" => 27,
+    "woefully" => 24,
+    "woes" => 30,
+    "woke" => 35,
+    "woken" => 37,
+    "wolf" => 29,
+    "wolfgang" => 29,
+    "wolfram" => 27,
+    "wolves" => 30,
+    "woman" => 46,
+    "womanhood" => 24,
+    "womanly" => 27,
+    "womb" => 24,
+    "women" => 41,
+    "women's" => 30,
+    "won" => 38,
+    "won't" => 52,
+    "wonder" => 44,
+    "wondered" => 39,
+    "wonderful" => 41,
+    "wonderfully" => 33,
+    "wondering" => 39,
+    "wonderland" => 29,
+    "wonders" => 34,
+    "wondrous" => 32,
+    "wondrously" => 24,
+    "wonky" => 24,
+    "wont" => 33,
+    "wood" => 42,
+    "woodcock" => 27,
+    "wooded" => 27,
+    "wooden" => 37,
+    "woodpecker" => 30,
+    "woods" => 40,
+    "woodshed" => 31,
+    "woody" => 27,
+    "wooed" => 24,
+    "wooing" => 27,
+    "wool" => 27,
+    "woollen" => 24,
+    "woolly" => 24,
+    "woolwich" => 24,
+    "wop" => 27,
+    "word" => 55,
+    "worded" => 24,
+    "wording" => 29,
+    "words" => 53,
+    "wordy" => 29,
+    "wore" => 38,
+    "work" => 61,
+    "workable" => 27,
+    "workbench" => 33,
+    "workbook" => 30,
+    "workbox" => 24,
+    "worked" => 46,
+    "worker" => 48,
+    "workers" => 42,
+    "workflow" => 46,
+    "workflows" => 44,
+    "workhorse" => 24,
+    "working" => 54,
+    "workings" => 31,
+    "workload" => 37,
+    "workloads" => 40,
+    "workman" => 24,
+    "workmen" => 30,
+    "workplace" => 29,
+    "workplaces" => 24,
+    "works" => 56,
+    "worksheet" => 29,
+    "worksheets" => 24,
+    "workshop" => 43,
+    "workshops" => 24,
+    "workspace" => 50,
+    "workstation" => 38,
+    "workstations" => 33,
+    "world" => 51,
+    "worldly" => 32,
+    "worlds" => 37,
+    "worldwide" => 34,
+    "worm" => 35,
+    "wormed" => 24,
+    "worms" => 30,
+    "worn" => 38,
+    "worried" => 34,
+    "worries" => 30,
+    "worry" => 44,
+    "worrying" => 36,
+    "worse" => 42,
+    "worsening" => 27,
+    "worship" => 31,
+    "worshipful" => 24,
+    "worshipped" => 30,
+    "worst" => 43,
+    "worsted" => 24,
+    "wort" => 24,
+    "worth" => 45,
+    "worthless" => 24,
+    "worthwhile" => 32,
+    "worthy" => 39,
+    "would" => 60,
+    "wouldn't" => 46,
+    "wound" => 37,
+    "wounded" => 33,
+    "wounds" => 29,
+    "woven" => 29,
+    "wow" => 35,
+    "wrack" => 24,
+    "wrangle" => 29,
+    "wrangling" => 24,
+    "wrap" => 50,
+    "wraparound" => 30,
+    "wrapped" => 49,
+    "wrapper" => 51,
+    "wrappers" => 46,
+    "wrapping" => 49,
+    "wrappings" => 24,
+    "wraps" => 45,
+    "wrath" => 29,
+    "wreak" => 30,
+    "wreaked" => 24,
+    "wreath" => 30,
+    "wreaths" => 32,
+    "wreck" => 35,
+    "wrecked" => 27,
+    "wrench" => 24,
+    "wrenched" => 24,
+    "wrenching" => 24,
+    "wrestle" => 24,
+    "wrestled" => 24,
+    "wrestling" => 30,
+    "wretch" => 38,
+    "wretched" => 39,
+    "wretchedly" => 24,
+    "wretchedness" => 36,
+    "wriggled" => 24,
+    "wriggling" => 27,
+    "wring" => 24,
+    "wringing" => 24,
+    "wrinkle" => 31,
+    "wrinkled" => 33,
+    "wrinkles" => 27,
+    "wrist" => 33,
+    "wrists" => 29,
+    "writ" => 27,
+    "writable" => 46,
+    "write" => 57,
+    "writer" => 46,
+    "writer's" => 29,
+    "writers" => 41,
+    "writes" => 49,
+    "writeup" => 27,
+    "writhed" => 32,
+    "writhing" => 32,
+    "writing" => 55,
+    "writings" => 30,
+    "writs" => 27,
+    "written" => 55,
+    "wrong" => 54,
+    "wronged" => 27,
+    "wrongfully" => 29,
+    "wrongly" => 35,
+    "wrote" => 44,
+    "wrought" => 32,
+    "wrung" => 31,
+    "wry" => 33,
+    "wyatt" => 27,
+    "wynn" => 24,
+    "wyoming" => 27,
+    "wysiwyg" => 24,
+    "xavier" => 37,
+    "xerox" => 38,
+    "xii" => 32,
+    "xiii" => 29,
+    "xiv" => 29,
+    "xix" => 29,
+    "xmas" => 24,
+    "xray" => 24,
+    "xvi" => 32,
+    "xvii" => 29,
+    "xviii" => 29,
+    "xxi" => 29,
+    "xxii" => 29,
+    "xxiii" => 29,
+    "xxiv" => 29,
+    "xxix" => 29,
+    "xxv" => 29,
+    "xxvi" => 29,
+    "xxvii" => 29,
+    "xxviii" => 29,
+    "xxx" => 40,
+    "yacht" => 32,
+    "yachting" => 29,
+    "yachts" => 24,
+    "yahoo" => 27,
+    "yak" => 24,
+    "yale" => 39,
+    "yank" => 45,
+    "yanked" => 41,
+    "yankee" => 30,
+    "yanking" => 37,
+    "yanks" => 32,
+    "yap" => 27,
+    "yard" => 39,
+    "yards" => 38,
+    "yarn" => 29,
+    "yarns" => 29,
+    "yaw" => 24,
+    "yawl" => 24,
+    "yawn" => 29,
+    "yawned" => 33,
+    "yawning" => 31,
+    "yaws" => 24,
+    "ye" => 36,
+    "yea" => 24,
+    "yeah" => 32,
+    "year" => 47,
+    "yearly" => 27,
+    "yearned" => 29,
+    "yearning" => 27,
+    "years" => 49,
+    "yeas" => 27,
+    "yell" => 29,
+    "yelled" => 29,
+    "yelling" => 30,
+    "yellow" => 43,
+    "yellowed" => 24,
+    "yellowing" => 24,
+    "yellowish" => 24,
+    "yells" => 24,
+    "yelp" => 29,
+    "yelps" => 24,
+    "yen" => 24,
+    "yep" => 29,
+    "yes" => 54,
+    "yesterday" => 39,
+    "yet" => 54,
+    "yew" => 40,
+    "yiddish" => 30,
+    "yield" => 46,
+    "yielded" => 42,
+    "yielding" => 40,
+    "yields" => 47,
+    "yip" => 29,
+    "yoga" => 33,
+    "yoke" => 31,
+    "yoking" => 24,
+    "yokohama" => 40,
+    "yolks" => 24,
+    "yon" => 24,
+    "yonder" => 32,
+    "york" => 42,
+    "yorkshire" => 30,
+    "yoruba" => 24,
+    "you" => 68,
+    "you'd" => 48,
+    "you'll" => 52,
+    "you're" => 53,
+    "you've" => 48,
+    "young" => 48,
+    "younger" => 37,
+    "youngest" => 29,
+    "youngster" => 29,
+    "your" => 64,
+    "yours" => 41,
+    "yourself" => 49,
+    "yourselves" => 33,
+    "youth" => 40,
+    "youthful" => 30,
+    "yuan" => 24,
+    "yukon" => 24,
+    "yummy" => 24,
+    "yup" => 30,
+    "zachary" => 24,
+    "zeal" => 33,
+    "zealand" => 33,
+    "zebra" => 32,
+    "zebras" => 24,
+    "zebus" => 27,
+    "zed" => 36,
+    "zen" => 32,
+    "zend" => 24,
+    "zenith" => 30,
+    "zephyr" => 24,
+    "zephyrs" => 24,
+    "zero" => 57,
+    "zeroed" => 53,
+    "zeroes" => 39,
+    "zeroing" => 35,
+    "zeros" => 47,
+    "zest" => 24,
+    "zeta" => 32,
+    "ziegler" => 24,
+    "zigzag" => 32,
+    "zip" => 45,
+    "zipped" => 32,
+    "zipping" => 27,
+    "zips" => 32,
+    "zircon" => 27,
+    "zodiac" => 24,
+    "zoe" => 24,
+    "zombie" => 32,
+    "zombies" => 29,
+    "zone" => 46,
+    "zoned" => 24,
+    "zones" => 39,
+    "zoo" => 24,
+    "zoom" => 32,
+    "zooming" => 24,
+    "zoroaster" => 24,
+    "zulu" => 27,
+};
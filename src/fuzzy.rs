@@ -0,0 +1,303 @@
+use once_cell::sync::Lazy;
+
+use crate::lexicon::Lexicon;
+use crate::words::folded_words;
+
+/// Most edits a lookup allows, since every allowed edit multiplies the size
+/// of the deletion index
+const MAX_DISTANCE: usize = 2;
+
+/// Only this many leading characters are indexed. Longer words are told apart
+/// by the full edit distance check on each candidate.
+const PREFIX_LEN: usize = 7;
+
+/// Deletion index over the lowercased dictionary, built on first use
+static DELETE_INDEX: Lazy<DeleteIndex> = Lazy::new(|| {
+    DeleteIndex::new(folded_words().filter(|word| word.chars().all(char::is_alphabetic)))
+});
+
+/// A dictionary word found within a small edit distance of the input
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzyMatch {
+    /// The word as it appeared in the text
+    pub word: String,
+    /// The closest dictionary word
+    pub matched: String,
+    /// Edit distance between the two, counting adjacent transpositions as one edit
+    pub distance: usize,
+}
+
+impl FuzzyMatch {
+    /// How much this match counts towards the English word ratio.
+    ///
+    /// An exact dictionary word counts 1.0; each edit halves the contribution.
+    pub fn weight(&self) -> f64 {
+        0.5f64.powi(self.distance as i32)
+    }
+}
+
+/// Symmetric delete index, as used by SymSpell.
+///
+/// Every word is stored under each string reachable by deleting up to
+/// [`MAX_DISTANCE`] characters from its prefix. Two words within that many
+/// edits share at least one such string, so a lookup only has to check the
+/// words stored under the deletes of the input.
+struct DeleteIndex {
+    words: Vec<&'static str>,
+    /// (hash of a delete, index into `words`), sorted by hash. Hash collisions
+    /// only add candidates, which the edit distance check then rejects.
+    deletes: Vec<(u32, u32)>,
+}
+
+impl DeleteIndex {
+    fn new(words: impl Iterator<Item = &'static str>) -> Self {
+        let words: Vec<&'static str> = words.collect();
+        let mut deletes = Vec::new();
+        for (index, word) in words.iter().enumerate() {
+            for_each_delete(word, MAX_DISTANCE, |hash| {
+                deletes.push((hash, index as u32))
+            });
+        }
+        deletes.sort_unstable();
+        deletes.dedup();
+        DeleteIndex { words, deletes }
+    }
+
+    /// Closest allowed word within `max_distance`, see [`Closest`]
+    fn closest(
+        &self,
+        word: &str,
+        max_distance: usize,
+        allowed: impl Fn(&str) -> bool,
+    ) -> Option<(&'static str, usize)> {
+        let mut candidates = Vec::new();
+        for_each_delete(word, max_distance, |hash| {
+            let start = self.deletes.partition_point(|&(h, _)| h < hash);
+            candidates.extend(
+                self.deletes[start..]
+                    .iter()
+                    .take_while(|&&(h, _)| h == hash)
+                    .map(|&(_, index)| index),
+            );
+        });
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        let length = word.chars().count();
+        let mut best = Closest::new(word);
+        for candidate in candidates
+            .into_iter()
+            .map(|index| self.words[index as usize])
+        {
+            // Each character of length difference costs at least one edit
+            if candidate.chars().count().abs_diff(length) > max_distance {
+                continue;
+            }
+            let distance = edit_distance(word, candidate);
+            if distance <= max_distance && allowed(candidate) {
+                best.offer(candidate, distance);
+            }
+        }
+        best.best
+    }
+}
+
+/// Call `f` with the hash of every string made by deleting up to `max_deletes`
+/// characters from the first [`PREFIX_LEN`] characters of `word`
+fn for_each_delete(word: &str, max_deletes: usize, mut f: impl FnMut(u32)) {
+    let prefix: Vec<char> = word.chars().take(PREFIX_LEN).collect();
+    let hash_without = |skip: &[usize]| {
+        // FNV-1a, skipping the deleted positions
+        let mut hash: u32 = 0x811c_9dc5;
+        for (i, &c) in prefix.iter().enumerate() {
+            if !skip.contains(&i) {
+                hash = (hash ^ c as u32).wrapping_mul(0x0100_0193);
+            }
+        }
+        hash
+    };
+
+    f(hash_without(&[]));
+    if max_deletes >= 1 {
+        for i in 0..prefix.len() {
+            f(hash_without(&[i]));
+            if max_deletes >= 2 {
+                for j in i + 1..prefix.len() {
+                    f(hash_without(&[i, j]));
+                }
+            }
+        }
+    }
+}
+
+/// Tracks the best candidate for a word.
+///
+/// Ties prefer anagrams of the input, since swapped letters are the most
+/// common typo, and are then broken alphabetically.
+struct Closest<'a> {
+    letters: Vec<char>,
+    best: Option<(&'a str, usize)>,
+}
+
+impl<'a> Closest<'a> {
+    fn new(word: &str) -> Self {
+        Closest {
+            letters: sorted_letters(word),
+            best: None,
+        }
+    }
+
+    fn rank(&self, candidate: &'a str, distance: usize) -> (usize, bool, &'a str) {
+        (
+            distance,
+            sorted_letters(candidate) != self.letters,
+            candidate,
+        )
+    }
+
+    fn offer(&mut self, candidate: &'a str, distance: usize) {
+        let better = match self.best {
+            None => true,
+            Some((best_word, best_distance)) => {
+                self.rank(candidate, distance) < self.rank(best_word, best_distance)
+            }
+        };
+        if better {
+            self.best = Some((candidate, distance));
+        }
+    }
+}
+
+fn sorted_letters(word: &str) -> Vec<char> {
+    let mut letters: Vec<char> = word.chars().collect();
+    letters.sort_unstable();
+    letters
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent transpositions
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // Three rolling rows: two back, previous and current
+    let mut two_back: Vec<usize> = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current: Vec<usize> = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(two_back[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut two_back, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Find the closest dictionary word within `max_distance` edits.
+///
+/// Adjacent transpositions count as a single edit, so "teh" matches "the".
+/// The allowed distance is capped at two edits, and at a third of the word
+/// length, which keeps short words from matching almost anything.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::fuzzy_lookup;
+///
+/// let found = fuzzy_lookup("quikc", 2).unwrap();
+/// assert_eq!(found.matched, "quick");
+/// assert_eq!(found.distance, 1);
+/// ```
+pub fn fuzzy_lookup(word: &str, max_distance: usize) -> Option<FuzzyMatch> {
+    fuzzy_lookup_in(word, max_distance, &Lexicon::default())
+}
+
+/// [`fuzzy_lookup`] against a lexicon with runtime words added or denied
+pub(crate) fn fuzzy_lookup_in(
+    word: &str,
+    max_distance: usize,
+    lexicon: &Lexicon,
+) -> Option<FuzzyMatch> {
+    let lowered = word.to_lowercase();
+    if !lowered.chars().all(char::is_alphabetic) {
+        return None;
+    }
+
+    let max_distance = max_distance
+        .min(MAX_DISTANCE)
+        .min(lowered.chars().count() / 3);
+    if max_distance == 0 {
+        return None;
+    }
+
+    let mut best = Closest::new(&lowered);
+    if let Some((matched, distance)) = DELETE_INDEX.closest(&lowered, max_distance, |candidate| {
+        !lexicon.is_denied(candidate)
+    }) {
+        best.offer(matched, distance);
+    }
+    // Runtime words are few, so a linear scan is fine
+    for extra in lexicon.extra_words() {
+        let distance = edit_distance(&lowered, extra);
+        if distance <= max_distance {
+            best.offer(extra, distance);
+        }
+    }
+
+    best.best.map(|(matched, distance)| FuzzyMatch {
+        word: word.to_string(),
+        matched: matched.to_string(),
+        distance,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("teh", "the"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+
+    #[test]
+    fn test_fuzzy_lookup_typos() {
+        assert_eq!(fuzzy_lookup("teh", 2).unwrap().matched, "the");
+        assert_eq!(fuzzy_lookup("brwon", 2).unwrap().matched, "brown");
+    }
+
+    #[test]
+    fn test_fuzzy_lookup_beyond_indexed_prefix() {
+        let found = fuzzy_lookup("extraordinarly", 2).unwrap();
+        assert_eq!(found.matched, "extraordinarily");
+        // An edit at the start shifts the whole indexed prefix
+        assert_eq!(fuzzy_lookup("xelephant", 2).unwrap().matched, "elephant");
+        assert_eq!(fuzzy_lookup("elephnat", 5).unwrap().distance, 1);
+    }
+
+    #[test]
+    fn test_fuzzy_lookup_limits() {
+        // Too short for any edits
+        assert!(fuzzy_lookup("xq", 2).is_none());
+        assert!(fuzzy_lookup("abc123", 2).is_none());
+        assert!(fuzzy_lookup("zzxqjvkw", 1).is_none());
+    }
+
+    #[test]
+    fn test_weight() {
+        let found = fuzzy_lookup("quikc", 2).unwrap();
+        assert_eq!(found.weight(), 0.5);
+    }
+}
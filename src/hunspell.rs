@@ -0,0 +1,594 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::Path;
+use thiserror::Error;
+
+/// Errors that can occur while loading a Hunspell dictionary
+#[derive(Error, Debug)]
+pub enum HunspellError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Unsupported encoding: {0}")]
+    Encoding(String),
+
+    #[error("Invalid affix file, line {line}: {message}")]
+    InvalidAffix { line: usize, message: String },
+
+    #[error("Invalid dictionary file, line {line}: {message}")]
+    InvalidDictionary { line: usize, message: String },
+}
+
+/// How affix flags are written, set by the `FLAG` directive
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FlagMode {
+    /// One character per flag, the default
+    Short,
+    /// Two characters per flag
+    Long,
+    /// Comma-separated numbers
+    Numeric,
+}
+
+impl FlagMode {
+    fn parse(&self, flags: &str) -> Vec<String> {
+        match self {
+            FlagMode::Short => flags.chars().map(String::from).collect(),
+            FlagMode::Long => {
+                let chars: Vec<char> = flags.chars().collect();
+                chars.chunks(2).map(|pair| pair.iter().collect()).collect()
+            }
+            FlagMode::Numeric => flags
+                .split(',')
+                .map(str::trim)
+                .filter(|flag| !flag.is_empty())
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+}
+
+/// One character position in an affix condition
+#[derive(Debug, Clone)]
+enum CharClass {
+    Any,
+    Set { chars: Vec<char>, negated: bool },
+}
+
+impl CharClass {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            CharClass::Any => true,
+            CharClass::Set { chars, negated } => chars.contains(&c) != *negated,
+        }
+    }
+}
+
+/// A single prefix or suffix rule
+#[derive(Debug, Clone)]
+struct AffixRule {
+    strip: String,
+    add: String,
+    /// Affix flags that may be applied on top of this one
+    continuation: Vec<String>,
+    condition: Vec<CharClass>,
+}
+
+#[derive(Debug, Clone)]
+struct AffixGroup {
+    is_prefix: bool,
+    /// Whether this affix combines with affixes of the other kind
+    cross_product: bool,
+    rules: Vec<AffixRule>,
+}
+
+impl AffixGroup {
+    /// All forms of `word` produced by this group's rules
+    fn apply(&self, word: &str) -> Vec<(String, &AffixRule)> {
+        let chars: Vec<char> = word.chars().collect();
+        self.rules
+            .iter()
+            .filter_map(|rule| {
+                let condition_len = rule.condition.len();
+                if chars.len() < condition_len {
+                    return None;
+                }
+                let window = if self.is_prefix {
+                    &chars[..condition_len]
+                } else {
+                    &chars[chars.len() - condition_len..]
+                };
+                if !window
+                    .iter()
+                    .zip(&rule.condition)
+                    .all(|(&c, class)| class.matches(c))
+                {
+                    return None;
+                }
+
+                let form = if self.is_prefix {
+                    let rest = word.strip_prefix(rule.strip.as_str())?;
+                    format!("{}{}", rule.add, rest)
+                } else {
+                    let rest = word.strip_suffix(rule.strip.as_str())?;
+                    format!("{}{}", rest, rule.add)
+                };
+                (!form.is_empty()).then_some((form, rule))
+            })
+            .collect()
+    }
+}
+
+/// Parsed `.aff` file
+#[derive(Debug)]
+struct AffixFile {
+    flag_mode: FlagMode,
+    /// Flag sets from `AF` directives, referred to by their 1-based position
+    flag_aliases: Vec<Vec<String>>,
+    groups: HashMap<String, AffixGroup>,
+    /// Flags marking entries that are never words on their own
+    forbidden_flags: Vec<String>,
+    /// Flags marking entries that are only words with an affix attached
+    need_affix_flags: Vec<String>,
+}
+
+impl AffixFile {
+    fn parse(text: &str) -> Result<Self, HunspellError> {
+        let mut flag_mode = FlagMode::Short;
+        let mut flag_aliases: Vec<Vec<String>> = Vec::new();
+        // The first `AF` line of a block is a count rather than an alias
+        let mut seen_alias_count = false;
+        let mut groups: HashMap<String, AffixGroup> = HashMap::new();
+        let mut forbidden_flags = Vec::new();
+        let mut need_affix_flags = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let invalid = |message: &str| HunspellError::InvalidAffix {
+                line: line_number,
+                message: message.to_string(),
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let Some(&directive) = fields.first() else {
+                continue;
+            };
+
+            match directive {
+                "FLAG" => {
+                    flag_mode = match fields.get(1).copied() {
+                        Some("long") => FlagMode::Long,
+                        Some("num") => FlagMode::Numeric,
+                        // UTF-8 flags are single characters, like the default
+                        Some("UTF-8") => FlagMode::Short,
+                        _ => return Err(invalid("unknown FLAG type")),
+                    };
+                }
+                "AF" => {
+                    let field = fields.get(1).ok_or_else(|| invalid("AF has no flags"))?;
+                    if seen_alias_count {
+                        flag_aliases.push(flag_mode.parse(field));
+                    } else {
+                        field
+                            .parse::<usize>()
+                            .map_err(|_| invalid("AF block does not start with a count"))?;
+                        seen_alias_count = true;
+                    }
+                }
+                // Morphological aliases only abbreviate morphology, which is not used
+                "AM" => {}
+                "FORBIDDENWORD" | "ONLYINCOMPOUND" => {
+                    if let Some(flag) = fields.get(1) {
+                        forbidden_flags.push(flag.to_string());
+                    }
+                }
+                "NEEDAFFIX" => {
+                    if let Some(flag) = fields.get(1) {
+                        need_affix_flags.push(flag.to_string());
+                    }
+                }
+                "PFX" | "SFX" => {
+                    if fields.len() < 4 {
+                        return Err(invalid("affix line has too few fields"));
+                    }
+                    let flag = fields[1].to_string();
+                    let is_prefix = directive == "PFX";
+
+                    // The first line of a group is a header: flag, cross product, rule count
+                    let group = match groups.entry(flag) {
+                        Entry::Vacant(entry) => {
+                            entry.insert(AffixGroup {
+                                is_prefix,
+                                cross_product: fields[2] == "Y",
+                                rules: Vec::new(),
+                            });
+                            continue;
+                        }
+                        Entry::Occupied(entry) => entry.into_mut(),
+                    };
+
+                    if fields.len() < 5 {
+                        return Err(invalid("affix rule has no condition"));
+                    }
+                    let (add, continuation) = match fields[3].split_once('/') {
+                        Some((add, flags)) => (
+                            add,
+                            resolve_flags(flags, flag_mode, &flag_aliases)
+                                .ok_or_else(|| invalid("unknown AF alias"))?,
+                        ),
+                        None => (fields[3], Vec::new()),
+                    };
+                    let rule = AffixRule {
+                        strip: zero_as_empty(fields[2]),
+                        add: zero_as_empty(add),
+                        continuation,
+                        condition: parse_condition(fields[4])
+                            .ok_or_else(|| invalid("unterminated [ in condition"))?,
+                    };
+                    group.rules.push(rule);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(AffixFile {
+            flag_mode,
+            flag_aliases,
+            groups,
+            forbidden_flags,
+            need_affix_flags,
+        })
+    }
+}
+
+/// Parse the flags of an entry or rule, which are an `AF` alias number when
+/// the affix file defines aliases. An entry without flags has none either way.
+fn resolve_flags(flags: &str, mode: FlagMode, aliases: &[Vec<String>]) -> Option<Vec<String>> {
+    if flags.is_empty() {
+        return Some(Vec::new());
+    }
+    if aliases.is_empty() {
+        return Some(mode.parse(flags));
+    }
+    let index = flags.parse::<usize>().ok()?;
+    aliases.get(index.checked_sub(1)?).cloned()
+}
+
+fn zero_as_empty(field: &str) -> String {
+    if field == "0" {
+        String::new()
+    } else {
+        field.to_string()
+    }
+}
+
+/// Parse a condition such as `[^aeiou]y` into one class per character
+fn parse_condition(condition: &str) -> Option<Vec<CharClass>> {
+    if condition == "." {
+        return Some(Vec::new());
+    }
+
+    let mut classes = Vec::new();
+    let mut chars = condition.chars();
+    while let Some(c) = chars.next() {
+        let class = match c {
+            '.' => CharClass::Any,
+            '[' => {
+                let mut set = Vec::new();
+                let mut negated = false;
+                loop {
+                    match chars.next()? {
+                        ']' => break,
+                        '^' if set.is_empty() && !negated => negated = true,
+                        c => set.push(c),
+                    }
+                }
+                CharClass::Set {
+                    chars: set,
+                    negated,
+                }
+            }
+            c => CharClass::Set {
+                chars: vec![c],
+                negated: false,
+            },
+        };
+        classes.push(class);
+    }
+    Some(classes)
+}
+
+/// Decode a Hunspell file, using the `SET` directive from the affix file
+fn decode(bytes: &[u8], label: &str) -> Result<String, HunspellError> {
+    let encoding = encoding_rs::Encoding::for_label(label.as_bytes())
+        .ok_or_else(|| HunspellError::Encoding(label.to_string()))?;
+    let (text, _, _) = encoding.decode(bytes);
+    Ok(text.into_owned())
+}
+
+/// Find the `SET` directive. It is ASCII, so it can be read before decoding.
+fn encoding_label(aff: &[u8]) -> String {
+    String::from_utf8_lossy(aff)
+        .lines()
+        .find_map(|line| {
+            let mut fields = line.split_whitespace();
+            (fields.next() == Some("SET")).then(|| fields.next().map(str::to_string))?
+        })
+        .unwrap_or_else(|| "ISO-8859-1".to_string())
+}
+
+/// A word list expanded from a Hunspell `.dic`/`.aff` pair.
+///
+/// Prefix and suffix rules are applied to every dictionary entry, including
+/// cross products and one level of continuation suffixes. Flag aliases (`AF`)
+/// are resolved, while compound rules and morphology are not expanded. Entries flagged `FORBIDDENWORD` or `ONLYINCOMPOUND` are left
+/// out, and entries flagged `NEEDAFFIX` only contribute their affixed forms.
+///
+/// Add the words to a detector with [`GibberishDetector::with_hunspell`](crate::GibberishDetector::with_hunspell).
+#[derive(Debug, Clone, Default)]
+pub struct HunspellDictionary {
+    words: HashSet<String>,
+}
+
+impl HunspellDictionary {
+    /// Load and expand a dictionary, e.g. `en_GB.dic` and `en_GB.aff`
+    pub fn from_files<P: AsRef<Path>, Q: AsRef<Path>>(
+        dic_path: P,
+        aff_path: Q,
+    ) -> Result<Self, HunspellError> {
+        let aff = std::fs::read(aff_path)?;
+        let dic = std::fs::read(dic_path)?;
+        let label = encoding_label(&aff);
+        Self::parse(&decode(&dic, &label)?, &decode(&aff, &label)?)
+    }
+
+    /// Expand a dictionary from the decoded contents of its `.dic` and `.aff` files
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gibberish_or_not::HunspellDictionary;
+    ///
+    /// let aff = "SFX S Y 1\nSFX S 0 s .\n";
+    /// let dic = "1\ncat/S\n";
+    /// let dictionary = HunspellDictionary::parse(dic, aff).unwrap();
+    /// assert!(dictionary.contains("cats"));
+    /// ```
+    pub fn parse(dic: &str, aff: &str) -> Result<Self, HunspellError> {
+        let affixes = AffixFile::parse(strip_bom(aff))?;
+        let mut words = HashSet::new();
+
+        let mut lines = strip_bom(dic).lines().enumerate().peekable();
+        // The first line is an approximate entry count
+        if lines
+            .peek()
+            .is_some_and(|(_, line)| line.trim().parse::<usize>().is_ok())
+        {
+            lines.next();
+        }
+
+        for (index, line) in lines {
+            // Morphological fields follow a tab or space; a slash starts the flags
+            let entry = line.split(['\t', ' ']).next().unwrap_or("").trim();
+            if entry.is_empty() {
+                continue;
+            }
+            let (word, flags) = split_entry(entry);
+            let flags = resolve_flags(flags, affixes.flag_mode, &affixes.flag_aliases).ok_or_else(
+                || HunspellError::InvalidDictionary {
+                    line: index + 1,
+                    message: format!("unknown AF alias {flags:?}"),
+                },
+            )?;
+            expand_entry(&word, &flags, &affixes, &mut words);
+        }
+
+        Ok(HunspellDictionary { words })
+    }
+
+    /// Returns true if the word, lowercased, is in the dictionary
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&word.to_lowercase())
+    }
+
+    /// All words in the dictionary, lowercased, in no particular order
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.words.iter().map(String::as_str)
+    }
+
+    /// Number of distinct words after expansion
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns true if the dictionary has no words
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+/// Drop a leading byte order mark left over from decoding
+fn strip_bom(text: &str) -> &str {
+    text.strip_prefix('\u{feff}').unwrap_or(text)
+}
+
+/// Split `word/FLAGS` into its parts, honouring `\/` escapes in the word
+fn split_entry(entry: &str) -> (String, &str) {
+    let mut word = String::new();
+    let mut chars = entry.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    word.push(escaped);
+                }
+            }
+            '/' => return (word, &entry[index + 1..]),
+            c => word.push(c),
+        }
+    }
+    (word, "")
+}
+
+fn expand_entry(word: &str, flags: &[String], affixes: &AffixFile, words: &mut HashSet<String>) {
+    if flags
+        .iter()
+        .any(|flag| affixes.forbidden_flags.contains(flag))
+    {
+        return;
+    }
+    let mut forms = Vec::new();
+    if !flags
+        .iter()
+        .any(|flag| affixes.need_affix_flags.contains(flag))
+    {
+        forms.push(word.to_string());
+    }
+
+    // Suffixes, with one level of continuation
+    let mut suffixed = Vec::new();
+    for group in flags.iter().filter_map(|flag| affixes.groups.get(flag)) {
+        if group.is_prefix {
+            continue;
+        }
+        for (form, rule) in group.apply(word) {
+            for continued in rule
+                .continuation
+                .iter()
+                .filter_map(|flag| affixes.groups.get(flag))
+                .filter(|group| !group.is_prefix)
+            {
+                forms.extend(continued.apply(&form).into_iter().map(|(form, _)| form));
+            }
+            suffixed.push((form, group.cross_product));
+        }
+    }
+
+    // Prefixes, combined with cross-product suffixes
+    for group in flags.iter().filter_map(|flag| affixes.groups.get(flag)) {
+        if !group.is_prefix {
+            continue;
+        }
+        forms.extend(group.apply(word).into_iter().map(|(form, _)| form));
+        if group.cross_product {
+            for (form, _) in suffixed.iter().filter(|(_, cross)| *cross) {
+                forms.extend(group.apply(form).into_iter().map(|(form, _)| form));
+            }
+        }
+    }
+
+    forms.extend(suffixed.into_iter().map(|(form, _)| form));
+    words.extend(forms.into_iter().map(|form| form.to_lowercase()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFF: &str = "SET UTF-8
+NEEDAFFIX X
+FORBIDDENWORD F
+
+PFX U Y 1
+PFX U 0 un .
+
+SFX S Y 3
+SFX S y ies [^aeiou]y
+SFX S 0 s [aeiou]y
+SFX S 0 s [^y]
+
+SFX D Y 1
+SFX D 0 ed/S [^y]
+";
+
+    const DIC: &str = "6
+fly/S
+play/SU
+happy/U
+colour/S	po:noun
+stem/DX
+colr/SF
+";
+
+    #[test]
+    fn test_suffix_conditions() {
+        let dictionary = HunspellDictionary::parse(DIC, AFF).unwrap();
+        assert!(dictionary.contains("flies"));
+        assert!(!dictionary.contains("flys"));
+        assert!(dictionary.contains("plays"));
+        assert!(dictionary.contains("colours"));
+    }
+
+    #[test]
+    fn test_prefixes_and_cross_product() {
+        let dictionary = HunspellDictionary::parse(DIC, AFF).unwrap();
+        assert!(dictionary.contains("unhappy"));
+        assert!(dictionary.contains("unplay"));
+        assert!(dictionary.contains("unplays"));
+    }
+
+    #[test]
+    fn test_flagged_entries() {
+        let dictionary = HunspellDictionary::parse(DIC, AFF).unwrap();
+        assert!(!dictionary.contains("stem"));
+        assert!(dictionary.contains("stemed"));
+        assert!(!dictionary.contains("colr"));
+        assert!(!dictionary.contains("colrs"));
+    }
+
+    #[test]
+    fn test_continuation_flags() {
+        let aff = "SFX D Y 1\nSFX D 0 ed/S .\nSFX S Y 1\nSFX S 0 s .\n";
+        let dictionary = HunspellDictionary::parse("1\nwalk/D\n", aff).unwrap();
+        assert!(dictionary.contains("walked"));
+        assert!(dictionary.contains("walkeds"));
+        assert_eq!(dictionary.len(), 3);
+    }
+
+    #[test]
+    fn test_long_flags_and_escapes() {
+        let aff = "FLAG long\nSFX Aa Y 1\nSFX Aa 0 s .\n";
+        let dictionary = HunspellDictionary::parse("2\ndog/Aa\nand\\/or\n", aff).unwrap();
+        assert!(dictionary.contains("dogs"));
+        assert!(dictionary.contains("and/or"));
+    }
+
+    #[test]
+    fn test_flag_aliases() {
+        let aff = "AF 2\nAF S # plural\nAF DS\nAM 1\nAM po:noun\n\
+                   SFX S Y 1\nSFX S 0 s .\nSFX D Y 1\nSFX D 0 ed/1 .\n";
+        let dictionary = HunspellDictionary::parse("2\ncat/1\nwalk/2\n", aff).unwrap();
+        assert!(dictionary.contains("cats"));
+        assert!(dictionary.contains("walks"));
+        assert!(dictionary.contains("walkeds"));
+
+        let error = HunspellDictionary::parse("1\ncat/3\n", aff).unwrap_err();
+        assert!(matches!(
+            error,
+            HunspellError::InvalidDictionary { line: 2, .. }
+        ));
+    }
+
+    #[test]
+    fn test_flag_aliases_with_bare_entries() {
+        let aff = "AF 1\nAF S\nSFX S Y 1\nSFX S 0 s .\n";
+        let dictionary = HunspellDictionary::parse("2\ncat/1\ndog\n", aff).unwrap();
+        assert!(dictionary.contains("cats"));
+        assert!(dictionary.contains("dog"));
+        assert!(!dictionary.contains("dogs"));
+    }
+
+    #[test]
+    fn test_byte_order_mark() {
+        let aff = "\u{feff}SFX S Y 1\nSFX S 0 s .\n";
+        let dictionary = HunspellDictionary::parse("\u{feff}2\ncat/S\ndog\n", aff).unwrap();
+        assert!(dictionary.contains("cats"));
+        assert_eq!(dictionary.len(), 3);
+    }
+
+    #[test]
+    fn test_invalid_affix_file() {
+        let error = HunspellDictionary::parse("", "SFX S Y 1\nSFX S 0\n").unwrap_err();
+        assert!(matches!(error, HunspellError::InvalidAffix { line: 2, .. }));
+    }
+}
@@ -0,0 +1,133 @@
+use unicode_general_category::{get_general_category, GeneralCategory};
+
+/// Why a character is invisible, see [`invisible_characters`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvisibleCategory {
+    /// Zero-width spaces, joiners and non-joiners, word joiners and byte order marks
+    ZeroWidth,
+    /// Bidirectional embeddings, overrides, isolates and marks, as used in
+    /// Trojan Source attacks to reorder how text is displayed
+    Bidi,
+    /// Tag characters (U+E0000 to U+E007F), which can spell out a hidden ASCII payload
+    Tag,
+    /// Variation selectors, which change how the previous character is drawn
+    VariationSelector,
+    /// Control characters other than tabs and line breaks
+    Control,
+    /// Any other format character, e.g. a soft hyphen
+    Format,
+}
+
+impl InvisibleCategory {
+    /// The category of an invisible character, or `None` if it is visible or whitespace
+    pub fn of(c: char) -> Option<Self> {
+        match c {
+            '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}' | '\u{180E}' => Some(Self::ZeroWidth),
+            '\u{200E}'
+            | '\u{200F}'
+            | '\u{061C}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2066}'..='\u{2069}' => Some(Self::Bidi),
+            '\u{E0000}'..='\u{E007F}' => Some(Self::Tag),
+            '\u{FE00}'..='\u{FE0F}'
+            | '\u{E0100}'..='\u{E01EF}'
+            | '\u{180B}'..='\u{180D}'
+            | '\u{180F}' => Some(Self::VariationSelector),
+            '\t' | '\n' | '\r' => None,
+            _ => match get_general_category(c) {
+                GeneralCategory::Control => Some(Self::Control),
+                GeneralCategory::Format => Some(Self::Format),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Every invisible character in the text, with its byte offset and category.
+///
+/// Covers zero-width characters, bidirectional controls, tag characters,
+/// variation selectors, control characters and other format characters, which
+/// can hide a payload or make text display differently from how it reads.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::{invisible_characters, InvisibleCategory};
+///
+/// let found = invisible_characters("pass\u{200B}word \u{202E}txt.exe");
+/// assert_eq!(
+///     found,
+///     [
+///         (4, '\u{200B}', InvisibleCategory::ZeroWidth),
+///         (12, '\u{202E}', InvisibleCategory::Bidi),
+///     ]
+/// );
+/// ```
+pub fn invisible_characters(text: &str) -> Vec<(usize, char, InvisibleCategory)> {
+    text.char_indices()
+        .filter_map(|(index, c)| InvisibleCategory::of(c).map(|category| (index, c, category)))
+        .collect()
+}
+
+/// The text with every character reported by [`invisible_characters`] removed
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::strip_invisible;
+///
+/// assert_eq!(strip_invisible("he\u{200D}llo\u{E0041}\u{E0042}"), "hello");
+/// ```
+pub fn strip_invisible(text: &str) -> String {
+    text.chars()
+        .filter(|&c| InvisibleCategory::of(c).is_none())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_categories() {
+        assert_eq!(
+            InvisibleCategory::of('\u{FEFF}'),
+            Some(InvisibleCategory::ZeroWidth)
+        );
+        assert_eq!(
+            InvisibleCategory::of('\u{2067}'),
+            Some(InvisibleCategory::Bidi)
+        );
+        assert_eq!(
+            InvisibleCategory::of('\u{E0068}'),
+            Some(InvisibleCategory::Tag)
+        );
+        assert_eq!(
+            InvisibleCategory::of('\u{FE0F}'),
+            Some(InvisibleCategory::VariationSelector)
+        );
+        assert_eq!(
+            InvisibleCategory::of('\u{7}'),
+            Some(InvisibleCategory::Control)
+        );
+        assert_eq!(
+            InvisibleCategory::of('\u{AD}'),
+            Some(InvisibleCategory::Format)
+        );
+        assert_eq!(InvisibleCategory::of('\n'), None);
+        assert_eq!(InvisibleCategory::of('a'), None);
+        assert_eq!(InvisibleCategory::of('\u{A0}'), None);
+    }
+
+    #[test]
+    fn test_tag_payload() {
+        // "hi" hidden as tag characters after visible text
+        let text = "hello\u{E0068}\u{E0069}";
+        let found = invisible_characters(text);
+        assert_eq!(found.len(), 2);
+        assert!(found
+            .iter()
+            .all(|&(_, _, category)| category == InvisibleCategory::Tag));
+        assert_eq!(strip_invisible(text), "hello");
+    }
+}
@@ -0,0 +1,184 @@
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
+
+use crate::{clean_text, dictionary, English};
+
+/// Index from letter pattern (e.g. "ABCCD") to the dictionary words sharing it
+static PATTERN_INDEX: Lazy<HashMap<String, Vec<String>>> = Lazy::new(|| {
+    let mut index: HashMap<String, HashSet<String>> = HashMap::new();
+    for word in dictionary::ENGLISH_WORDS.iter() {
+        if !word.chars().all(|c| c.is_ascii_alphabetic()) {
+            continue;
+        }
+        let word = word.to_ascii_lowercase();
+        if let Some(pattern) = word_pattern(&word) {
+            index.entry(pattern).or_default().insert(word);
+        }
+    }
+
+    index
+        .into_iter()
+        .map(|(pattern, words)| {
+            let mut words: Vec<String> = words.into_iter().collect();
+            words.sort();
+            (pattern, words)
+        })
+        .collect()
+});
+
+/// Computes the letter pattern of a word, also known as its isomorph.
+///
+/// Each distinct letter is replaced by `A`, `B`, `C`... in order of first
+/// appearance, ignoring case. Monoalphabetic substitution preserves this
+/// pattern, so ciphertext words can be matched against dictionary words.
+/// Returns `None` if the word contains anything other than ASCII letters.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::word_pattern;
+///
+/// assert_eq!(word_pattern("hello").as_deref(), Some("ABCCD"));
+/// assert_eq!(word_pattern("Xfmmp").as_deref(), Some("ABCCD"));
+/// assert_eq!(word_pattern("it's"), None);
+/// ```
+pub fn word_pattern(word: &str) -> Option<String> {
+    if word.is_empty() || !word.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut seen: Vec<char> = Vec::new();
+    let pattern = word
+        .chars()
+        .map(|c| {
+            let c = c.to_ascii_lowercase();
+            let position = seen.iter().position(|&s| s == c).unwrap_or_else(|| {
+                seen.push(c);
+                seen.len() - 1
+            });
+            (b'A' + position as u8) as char
+        })
+        .collect();
+
+    Some(pattern)
+}
+
+/// Returns the dictionary words (lowercased, sorted) that share the word's letter pattern
+pub fn pattern_candidates(word: &str) -> &'static [String] {
+    word_pattern(word)
+        .and_then(|pattern| PATTERN_INDEX.get(&pattern))
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+/// Candidate plaintext words for a single ciphertext word
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordCandidates {
+    /// The word as it appears in the cleaned text
+    pub word: String,
+    /// Its letter pattern, e.g. "ABCCD"
+    pub pattern: String,
+    /// Dictionary words sharing the pattern
+    pub candidates: &'static [String],
+}
+
+/// Result of matching every word in a text against the pattern index
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsomorphReport {
+    /// Number of words that have a letter pattern (purely alphabetic words)
+    pub total_words: usize,
+    /// Number of those words with at least one English candidate
+    pub matched_words: usize,
+    /// Per-word candidates, in text order
+    pub words: Vec<WordCandidates>,
+}
+
+impl IsomorphReport {
+    /// Fraction of words with at least one English candidate
+    pub fn match_ratio(&self) -> f64 {
+        if self.total_words == 0 {
+            0.0
+        } else {
+            self.matched_words as f64 / self.total_words as f64
+        }
+    }
+}
+
+/// Matches each word's letter pattern against the English dictionary.
+///
+/// A high match ratio on text that is otherwise gibberish is a strong sign that
+/// it is a monoalphabetic substitution of English. The per-word candidates can
+/// be fed to a substitution solver.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::isomorph_report;
+///
+/// // "hello world" under a substitution cipher
+/// let report = isomorph_report("Xqrrz kzfrw");
+/// assert_eq!(report.matched_words, 2);
+/// assert!(report.words[0].candidates.iter().any(|w| w == "hello"));
+/// ```
+pub fn isomorph_report(text: &str) -> IsomorphReport {
+    let cleaned = clean_text(text, &English);
+    let words: Vec<WordCandidates> = cleaned
+        .split_whitespace()
+        .filter_map(|word| {
+            word_pattern(word).map(|pattern| WordCandidates {
+                word: word.to_string(),
+                candidates: PATTERN_INDEX
+                    .get(&pattern)
+                    .map(Vec::as_slice)
+                    .unwrap_or(&[]),
+                pattern,
+            })
+        })
+        .collect();
+
+    IsomorphReport {
+        total_words: words.len(),
+        matched_words: words.iter().filter(|w| !w.candidates.is_empty()).count(),
+        words,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_pattern() {
+        assert_eq!(word_pattern("hello").as_deref(), Some("ABCCD"));
+        assert_eq!(word_pattern("HeLLo").as_deref(), Some("ABCCD"));
+        assert_eq!(word_pattern("that").as_deref(), Some("ABCA"));
+        assert_eq!(word_pattern(""), None);
+        assert_eq!(word_pattern("abc1"), None);
+    }
+
+    #[test]
+    fn test_pattern_candidates() {
+        let candidates = pattern_candidates("xyzzw");
+        assert!(candidates.iter().any(|w| w == "hello"));
+        assert!(candidates
+            .iter()
+            .all(|w| word_pattern(w).as_deref() == Some("ABCCD")));
+        assert!(pattern_candidates("abc1").is_empty());
+    }
+
+    #[test]
+    fn test_isomorph_report_substitution() {
+        // "the quick brown fox" with a shifted alphabet
+        let report = isomorph_report("Uif rvjdl cspxo gpy");
+        assert_eq!(report.total_words, 4);
+        assert_eq!(report.matched_words, 4);
+        assert!((report.match_ratio() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_isomorph_report_no_words() {
+        let report = isomorph_report("12345 !!!");
+        assert_eq!(report.total_words, 0);
+        assert_eq!(report.match_ratio(), 0.0);
+    }
+}
@@ -0,0 +1,334 @@
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use unicode_script::Script;
+
+use crate::{dominant_script, words, GibberishDetector, Score, Sensitivity};
+
+#[cfg(feature = "arabic")]
+mod arabic;
+#[cfg(feature = "french")]
+mod french;
+#[cfg(feature = "german")]
+mod german;
+#[cfg(feature = "greek")]
+mod greek;
+#[cfg(feature = "russian")]
+mod russian;
+#[cfg(feature = "spanish")]
+mod spanish;
+
+#[cfg(feature = "arabic")]
+pub use arabic::Arabic;
+#[cfg(feature = "french")]
+pub use french::French;
+#[cfg(feature = "german")]
+pub use german::German;
+#[cfg(feature = "greek")]
+pub use greek::Greek;
+#[cfg(feature = "russian")]
+pub use russian::Russian;
+#[cfg(feature = "spanish")]
+pub use spanish::Spanish;
+
+/// Identifies one of the languages the detector ships data for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LanguageId {
+    English,
+    French,
+    German,
+    Spanish,
+    Russian,
+    Greek,
+    Arabic,
+}
+
+/// The language-specific data the heuristics rely on.
+///
+/// Implemented by [`English`] and, behind the cargo features of the same name,
+/// [`French`](crate::French), [`German`](crate::German) and
+/// [`Spanish`](crate::Spanish), plus [`Russian`](crate::Russian),
+/// [`Greek`](crate::Greek) and [`Arabic`](crate::Arabic) for non-Latin scripts.
+/// Pass one to
+/// [`GibberishDetector::with_language`](crate::GibberishDetector::with_language).
+///
+/// N-gram methods receive lowercased text.
+pub trait Language: Debug + Send + Sync {
+    /// Which language this is
+    fn id(&self) -> LanguageId;
+
+    /// The script the language is written in
+    fn script(&self) -> Script {
+        Script::Latin
+    }
+
+    /// Returns true if the character belongs to the alphabet, in either case
+    fn is_letter(&self, c: char) -> bool;
+
+    /// Returns true if the lowercase letter is a vowel
+    fn is_vowel(&self, c: char) -> bool;
+
+    /// Returns true if the two-letter sequence is common in this language
+    fn is_common_pair(&self, pair: &str) -> bool;
+
+    /// Returns true if the three-letter sequence is common in this language
+    fn is_common_trigram(&self, trigram: &str) -> bool;
+
+    /// Returns true if the four-letter sequence is common in this language
+    fn is_common_quadgram(&self, quadgram: &str) -> bool;
+
+    /// Returns true if the word is in the dictionary, ignoring case
+    fn contains_word(&self, word: &str) -> bool;
+
+    /// How much a word counts as evidence for this language, or `None` if it
+    /// is not a word. Defaults to 1.0 for every dictionary word.
+    fn word_weight(&self, word: &str) -> Option<f64> {
+        self.contains_word(word).then_some(1.0)
+    }
+
+    /// Character entropy, in bits, typical of ordinary text
+    fn entropy_range(&self) -> RangeInclusive<f64>;
+}
+
+/// English, the default language
+#[derive(Debug, Clone, Copy, Default)]
+pub struct English;
+
+impl Language for English {
+    fn id(&self) -> LanguageId {
+        LanguageId::English
+    }
+
+    fn is_letter(&self, c: char) -> bool {
+        c.is_ascii_alphabetic()
+    }
+
+    fn is_vowel(&self, c: char) -> bool {
+        matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+    }
+
+    fn is_common_pair(&self, pair: &str) -> bool {
+        COMMON_CHAR_PAIRS.contains(pair)
+    }
+
+    fn is_common_trigram(&self, trigram: &str) -> bool {
+        COMMON_TRIGRAMS.contains(trigram)
+    }
+
+    fn is_common_quadgram(&self, quadgram: &str) -> bool {
+        COMMON_QUADGRAMS.contains(quadgram)
+    }
+
+    fn contains_word(&self, word: &str) -> bool {
+        words::contains_word(word)
+    }
+
+    fn word_weight(&self, word: &str) -> Option<f64> {
+        words::word_weight(word)
+    }
+
+    fn entropy_range(&self) -> RangeInclusive<f64> {
+        3.5..=4.5
+    }
+}
+
+// Common character pairs in English
+pub(crate) static COMMON_CHAR_PAIRS: phf::Set<&'static str> = phf::phf_set! {
+    "th", "he", "in", "er", "an", "re", "on", "at", "en", "nd",
+    "ti", "es", "or", "te", "of", "ed", "is", "it", "al", "ar",
+    "st", "to", "nt", "ng", "se", "ha", "as", "ou", "io", "le",
+    "ve", "co", "me", "de", "hi", "ri", "ro", "ic", "ne", "ea",
+    "ra", "ce", "li", "ch", "ll", "be", "ma", "si", "om", "ur"
+};
+
+pub(crate) static COMMON_QUADGRAMS: phf::Set<&'static str> = phf::phf_set! {
+    "tion", "atio", "that", "ther", "with", "ment", "ions", "this",
+    "here", "from", "ould", "ting", "hich", "whic", "ctio", "ever",
+    "they", "thin", "have", "othe", "were", "tive", "ough", "ight"
+};
+
+pub(crate) static COMMON_TRIGRAMS: phf::Set<&'static str> = phf::phf_set! {
+    "the", "and", "ing", "ion", "tio", "ent", "ati", "for", "her", "ter",
+    "hat", "tha", "ere", "con", "res", "ver", "all", "ons", "nce", "men",
+    "ith", "ted", "ers", "pro", "thi", "wit", "are", "ess", "not", "ive",
+    "was", "ect", "rea", "com", "eve", "per", "int", "est", "sta", "cti",
+    "ica", "ist", "ear", "ain", "one", "our", "iti", "rat", "ell", "ant"
+};
+
+/// Every language compiled in, English first
+fn supported_languages() -> Vec<Arc<dyn Language>> {
+    #[allow(unused_mut)]
+    let mut languages: Vec<Arc<dyn Language>> = vec![Arc::new(English)];
+    #[cfg(feature = "french")]
+    languages.push(Arc::new(French));
+    #[cfg(feature = "german")]
+    languages.push(Arc::new(German));
+    #[cfg(feature = "spanish")]
+    languages.push(Arc::new(Spanish));
+    #[cfg(feature = "russian")]
+    languages.push(Arc::new(Russian));
+    #[cfg(feature = "greek")]
+    languages.push(Arc::new(Greek));
+    #[cfg(feature = "arabic")]
+    languages.push(Arc::new(Arabic));
+    languages
+}
+
+/// The bundled profile for a non-Latin script, used to judge text in that
+/// script instead of the detector's own language
+pub(crate) fn script_profile(script: Script) -> Option<Arc<dyn Language>> {
+    match script {
+        #[cfg(feature = "russian")]
+        Script::Cyrillic => Some(Arc::new(Russian)),
+        #[cfg(feature = "greek")]
+        Script::Greek => Some(Arc::new(Greek)),
+        #[cfg(feature = "arabic")]
+        Script::Arabic => Some(Arc::new(Arabic)),
+        _ => None,
+    }
+}
+
+/// Which supported languages the text could be, best fit first.
+///
+/// Each language scores the text against its own character n-gram profile
+/// (common pairs, trigrams and quadgrams) together with its dictionary and
+/// entropy band. Languages written in another script than the text never fit;
+/// the rest fit if [`GibberishDetector`] with that language would not
+/// call it gibberish at [`Sensitivity::Medium`]. An empty list means the text
+/// is gibberish in every supported language.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::{identify_language, LanguageId};
+///
+/// let languages = identify_language("the quick brown fox jumps over the lazy dog");
+/// assert_eq!(languages[0].0, LanguageId::English);
+/// assert!(identify_language("xqzvbk wpjfhg lmnrtz qwpfk").is_empty());
+/// ```
+pub fn identify_language(text: &str) -> Vec<(LanguageId, Score)> {
+    // A language can only fit text written in its own script
+    let script = dominant_script(text);
+    let mut fits: Vec<(LanguageId, Score)> = supported_languages()
+        .into_iter()
+        .filter(|language| script.is_none_or(|script| script == language.script()))
+        .filter_map(|language| {
+            let id = language.id();
+            let mut detector = GibberishDetector::new();
+            detector.lexicon.set_language(language);
+            let analysis = detector.analyze(text, Sensitivity::Medium);
+            (!analysis.is_gibberish).then_some((id, analysis.score))
+        })
+        .collect();
+    fits.sort_by(|a, b| b.1.total_cmp(&a.1));
+    fits
+}
+
+/// Like [`is_gibberish`](crate::is_gibberish), but text only counts as gibberish
+/// if it is gibberish in every supported language, so valid foreign text passes.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::{is_gibberish, is_gibberish_in_every_language, Sensitivity};
+///
+/// let text = "wir haben die nachricht im haus meines vaters gefunden";
+/// # #[cfg(feature = "german")]
+/// assert!(!is_gibberish_in_every_language(text, Sensitivity::Low));
+/// assert!(is_gibberish_in_every_language("xqzvbk wpjfhg lmnrtz", Sensitivity::Low));
+/// ```
+pub fn is_gibberish_in_every_language(text: &str, sensitivity: Sensitivity) -> bool {
+    supported_languages().into_iter().all(|language| {
+        let mut detector = GibberishDetector::new();
+        detector.lexicon.set_language(language);
+        detector.is_gibberish(text, sensitivity)
+    })
+}
+
+/// Look up a word in a lowercase word list, ignoring case
+#[cfg(any(
+    feature = "french",
+    feature = "german",
+    feature = "spanish",
+    feature = "russian",
+    feature = "greek"
+))]
+fn contains_lowercase(words: &phf::Set<&'static str>, word: &str) -> bool {
+    if word.chars().any(char::is_uppercase) {
+        words.contains(word.to_lowercase().as_str())
+    } else {
+        words.contains(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_english() {
+        assert_eq!(English.id(), LanguageId::English);
+        assert!(English.is_letter('Q'));
+        assert!(!English.is_letter('é'));
+        assert!(English.is_common_trigram("the"));
+        assert!(English.contains_word("house"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "french", feature = "german", feature = "spanish"))]
+    fn test_identify_language() {
+        let cases = [
+            (
+                "we found the message in my father's house yesterday",
+                LanguageId::English,
+            ),
+            (
+                "nous avons trouvé le message dans la maison de mon père",
+                LanguageId::French,
+            ),
+            (
+                "wir haben die nachricht im haus meines vaters gefunden",
+                LanguageId::German,
+            ),
+            (
+                "encontramos el mensaje en la casa de mi padre ayer",
+                LanguageId::Spanish,
+            ),
+        ];
+        for (text, expected) in cases {
+            let languages = identify_language(text);
+            assert_eq!(languages[0].0, expected, "{}", text);
+            assert!(languages.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+            assert!(!is_gibberish_in_every_language(text, Sensitivity::Low));
+        }
+        assert!(identify_language("qwpfk zxvbn mlkjh gfdsa").is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "french", feature = "german", feature = "spanish"))]
+    fn test_packs() {
+        assert!(French.is_letter('É') && French.is_vowel('é'));
+        assert!(German.is_letter('ß') && German.is_vowel('ü'));
+        assert!(Spanish.is_letter('ñ') && !Spanish.is_vowel('ñ'));
+        assert!(French.contains_word("Maison"));
+        assert!(German.contains_word("haus"));
+        assert!(Spanish.contains_word("casa"));
+        assert!(!French.contains_word("house"));
+        assert!(French.contains_word("fenêtre") && French.contains_word("lendemain"));
+        assert!(German.contains_word("Fenster") && Spanish.contains_word("ventana"));
+        assert!(!German.contains_word("the") && !Spanish.contains_word("the"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "russian", feature = "greek", feature = "arabic"))]
+    fn test_script_packs() {
+        assert!(Russian.contains_word("Москва") && Russian.contains_word("дом"));
+        assert!(Greek.contains_word("δικαίωμα") && Greek.contains_word("Άνθρωπος"));
+        // Listed without accents
+        assert!(Greek.contains_word("αλλά"));
+        assert!(!Greek.contains_word("ανθρωπος"));
+        assert!(Arabic.contains_word("العلم"));
+    }
+}
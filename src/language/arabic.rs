@@ -0,0 +1,74 @@
+use std::ops::RangeInclusive;
+
+use phf::phf_set;
+use unicode_script::{Script, UnicodeScript};
+
+use super::{Language, LanguageId};
+
+mod words;
+
+use words::WORDS;
+
+/// Arabic, behind the `arabic` cargo feature. Also the profile used for
+/// Arabic script text.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Arabic;
+
+impl Language for Arabic {
+    fn id(&self) -> LanguageId {
+        LanguageId::Arabic
+    }
+
+    fn script(&self) -> Script {
+        Script::Arabic
+    }
+
+    fn is_letter(&self, c: char) -> bool {
+        c.is_alphabetic() && c.script() == Script::Arabic
+    }
+
+    /// Short vowels are rarely written, so only the long vowel letters count
+    fn is_vowel(&self, c: char) -> bool {
+        matches!(c, 'ا' | 'و' | 'ي' | 'ى' | 'آ')
+    }
+
+    fn is_common_pair(&self, pair: &str) -> bool {
+        COMMON_CHAR_PAIRS.contains(pair)
+    }
+
+    fn is_common_trigram(&self, trigram: &str) -> bool {
+        COMMON_TRIGRAMS.contains(trigram)
+    }
+
+    fn is_common_quadgram(&self, quadgram: &str) -> bool {
+        COMMON_QUADGRAMS.contains(quadgram)
+    }
+
+    /// Arabic has no case, and words are looked up as written
+    fn contains_word(&self, word: &str) -> bool {
+        WORDS.contains(word)
+    }
+
+    fn entropy_range(&self) -> RangeInclusive<f64> {
+        3.5..=4.8
+    }
+}
+
+static COMMON_CHAR_PAIRS: phf::Set<&'static str> = phf_set! {
+    "ال", "لا", "ان", "من", "في", "ين", "ون", "ما", "ها", "ات",
+    "لم", "ية", "ار", "ير", "عل", "لى", "ول", "لت", "ري", "تا",
+    "را", "با", "نا", "اء", "ست", "قا", "ام", "لس", "اس", "مع",
+    "اد", "ته", "كا", "يا", "وا", "اق", "لع", "له", "لك", "جد",
+};
+
+static COMMON_TRIGRAMS: phf::Set<&'static str> = phf_set! {
+    "الم", "الت", "الا", "است", "على", "لتي", "الع", "الق", "الس", "الح",
+    "الب", "الن", "الد", "الر", "الف", "الك", "الج", "الش", "الذ", "الي",
+    "ذلك", "هذا", "هذه", "كان", "ليه", "عند", "انت", "الل", "لله", "ولا",
+    "ماء", "تها", "ملك", "رسا", "سال",
+};
+
+static COMMON_QUADGRAMS: phf::Set<&'static str> = phf_set! {
+    "الله", "التي", "الذي", "المد", "عليه", "وجدن", "رسال", "سالة",
+    "لمدي", "مدين", "دينة", "ايضا", "كانت", "الان", "الحي", "البي",
+};
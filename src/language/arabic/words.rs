@@ -0,0 +1,4977 @@
+use phf::phf_set;
+
+pub static WORDS: phf::Set<&'static str> = phf_set! {
+    "آباء",
+    "آتى",
+    "آتيه",
+    "آحاد",
+    "آحاداً",
+    "آخر",
+    "آخران",
+    "آخره",
+    "آخرها",
+    "آلاف",
+    "آلام",
+    "آلة",
+    "آلي",
+    "آلياً",
+    "آه",
+    "آها",
+    "آي",
+    "آيسته",
+    "أبانت",
+    "أبداً",
+    "أبدع",
+    "أبسط",
+    "أبصار",
+    "أبطأ",
+    "أبعاد",
+    "أبعادا",
+    "أبعاض",
+    "أبعاضه",
+    "أبناء",
+    "أتت",
+    "أتعشى",
+    "أتوا",
+    "أتى",
+    "أثر",
+    "أثراً",
+    "أثناء",
+    "أجرام",
+    "أجزاء",
+    "أجزائه",
+    "أجزائها",
+    "أجسام",
+    "أجله",
+    "أجمعين",
+    "أحاط",
+    "أحجام",
+    "أحد",
+    "أحدهم",
+    "أحدهما",
+    "أحسن",
+    "أحطنا",
+    "أحكام",
+    "أحياناً",
+    "أخذ",
+    "أخر",
+    "أخرج",
+    "أخرى",
+    "أخطأنا",
+    "أخطاء",
+    "أخلاق",
+    "أخير",
+    "أداء",
+    "أداة",
+    "أدركه",
+    "أدناه",
+    "أدوات",
+    "أراد",
+    "أرادنا",
+    "أربعة",
+    "أردت",
+    "أردنا",
+    "أرسطو",
+    "أرشيف",
+    "أرشيفات",
+    "أرضية",
+    "أرفع",
+    "أزلي",
+    "أزيل",
+    "أساس",
+    "أساسها",
+    "أساسي",
+    "أساسيات",
+    "أساسية",
+    "أساليب",
+    "أساميها",
+    "أسباب",
+    "أسرع",
+    "أسطر",
+    "أسلحة",
+    "أسلحته",
+    "أسماء",
+    "أسماءها",
+    "أسمائها",
+    "أسهل",
+    "أسوأ",
+    "أسّاً",
+    "أشبه",
+    "أشخاص",
+    "أشخاصه",
+    "أشخاصها",
+    "أشد",
+    "أشرف",
+    "أشركونا",
+    "أشياء",
+    "أصبح",
+    "أصغر",
+    "أضداده",
+    "أضعاف",
+    "أضف",
+    "أضيف",
+    "أضيفت",
+    "أطال",
+    "أطرافا",
+    "أطل",
+    "أطول",
+    "أعالي",
+    "أعداد",
+    "أعراض",
+    "أعشى",
+    "أعظم",
+    "أعظمهما",
+    "أعلام",
+    "أعلاه",
+    "أعلى",
+    "أعمدة",
+    "أعمى",
+    "أعني",
+    "أعيانها",
+    "أغراض",
+    "أغراضنا",
+    "أغلبية",
+    "أف",
+    "أفادونا",
+    "أفراس",
+    "أفرد",
+    "أفضل",
+    "أقاويلنا",
+    "أقدم",
+    "أقرب",
+    "أقسام",
+    "أقصد",
+    "أقصر",
+    "أقصى",
+    "أقل",
+    "أكبر",
+    "أكتوبر",
+    "أكثر",
+    "ألا",
+    "ألسنة",
+    "ألف",
+    "ألفا",
+    "ألفاظنا",
+    "أم",
+    "أما",
+    "أمام",
+    "أمثال",
+    "أمثلة",
+    "أمراً",
+    "أمكن",
+    "أمل",
+    "أن",
+    "أنا",
+    "أنبوباً",
+    "أنت",
+    "أنتم",
+    "أنتما",
+    "أنتن",
+    "أنساباً",
+    "أنشطة",
+    "أنشطتها",
+    "أنظمة",
+    "أنفسهم",
+    "أنقص",
+    "أنماط",
+    "أنه",
+    "أنها",
+    "أنهل",
+    "أنهم",
+    "أنواع",
+    "أنواعه",
+    "أنى",
+    "أنيات",
+    "أنية",
+    "أنيته",
+    "أنيتها",
+    "أهل",
+    "أهمية",
+    "أو",
+    "أوائل",
+    "أوائله",
+    "أوائلها",
+    "أواخرها",
+    "أوامر",
+    "أوتار",
+    "أوجب",
+    "أوجه",
+    "أوحاد",
+    "أودعت",
+    "أوسع",
+    "أوصاف",
+    "أوضحنا",
+    "أول",
+    "أولئك",
+    "أولاء",
+    "أولاً",
+    "أولى",
+    "أولية",
+    "أوه",
+    "أي",
+    "أيا",
+    "أية",
+    "أيس",
+    "أيساً",
+    "أيسه",
+    "أيضاً",
+    "أين",
+    "أينما",
+    "أيها",
+    "أيهما",
+    "أُدرجت",
+    "أُصدرت",
+    "أُضيف",
+    "أُعطيت",
+    "أُنتجت",
+    "أُنشئ",
+    "إبقاء",
+    "إثارة",
+    "إجراء",
+    "إجمالي",
+    "إحاطتنا",
+    "إحالة",
+    "إحداث",
+    "إحضار",
+    "إدخا",
+    "إدراج",
+    "إدراك",
+    "إذ",
+    "إذا",
+    "إذاً",
+    "إذما",
+    "إذن",
+    "إذناً",
+    "إرسال",
+    "إزاحة",
+    "إزالة",
+    "إستعملنا",
+    "إسقاط",
+    "إسهاباً",
+    "إشعار",
+    "إشعارات",
+    "إصابة",
+    "إصدار",
+    "إصدارات",
+    "إضافة",
+    "إضافية",
+    "إضعاف",
+    "إظهار",
+    "إعادة",
+    "إعدادات",
+    "إعطاء",
+    "إغلاق",
+    "إقتناءها",
+    "إقناعاً",
+    "إلا",
+    "إلحاق",
+    "إلغاء",
+    "إلى",
+    "إليك",
+    "إليكم",
+    "إليكما",
+    "إليكن",
+    "إليه",
+    "إليها",
+    "إليهما",
+    "إما",
+    "إمساكه",
+    "إمكانية",
+    "إن",
+    "إنا",
+    "إنتاج",
+    "إنساني",
+    "إنشاء",
+    "إنشاؤها",
+    "إنما",
+    "إنه",
+    "إنها",
+    "إنهاء",
+    "إنهما",
+    "إي",
+    "إياه",
+    "إياها",
+    "إيثرنت",
+    "إيضاحه",
+    "إيه",
+    "ئ",
+    "ا",
+    "اء",
+    "ابتداء",
+    "ابدأ",
+    "ابن",
+    "ات",
+    "اتحاد",
+    "اتحادها",
+    "اتحدت",
+    "اتخاذ",
+    "اتخذته",
+    "اتسعت",
+    "اتصال",
+    "اتصاله",
+    "اتصل",
+    "اتضح",
+    "اتفاق",
+    "اتفقت",
+    "اثان",
+    "اثنان",
+    "اثنين",
+    "اجتمع",
+    "اجتمعا",
+    "اجتهادنا",
+    "اجراء",
+    "اح",
+    "احتاج",
+    "احتمال",
+    "احدى",
+    "اختبر",
+    "اختر",
+    "اختصار",
+    "اختلاف",
+    "اختلافات",
+    "اختيار",
+    "اختياري",
+    "اختيارية",
+    "ادخال",
+    "ارتضى",
+    "ارجي",
+    "استبدال",
+    "استثناء",
+    "استحال",
+    "استحالة",
+    "استحسان",
+    "استحقاق",
+    "استحقاقاً",
+    "استخدام",
+    "استخداماً",
+    "استخدامه",
+    "استخدامها",
+    "استخدم",
+    "استدعاء",
+    "استعادة",
+    "استعمال",
+    "استعمالها",
+    "استعملها",
+    "استعملوا",
+    "استغرقت",
+    "استقرار",
+    "استكشاف",
+    "استمسك",
+    "استنتاج",
+    "استهلاك",
+    "استُخدم",
+    "اسم",
+    "اسمان",
+    "اسمه",
+    "اشتباه",
+    "اشتراك",
+    "اشتراكها",
+    "اشتراكهما",
+    "اضبط",
+    "اضطرار",
+    "اضطرارا",
+    "اضطراراً",
+    "اضطراري",
+    "اطار",
+    "اطبع",
+    "اطلاق",
+    "اعاد",
+    "اعتبار",
+    "اعتماداً",
+    "اعرض",
+    "اعلان",
+    "اعلانات",
+    "افتراق",
+    "افترضت",
+    "اقتباس",
+    "اقتراح",
+    "اقتناؤها",
+    "اقليم",
+    "اقليمها",
+    "اكتشاف",
+    "اكتشافها",
+    "اكتم",
+    "اكتُشفت",
+    "اكنها",
+    "ال",
+    "الآتي",
+    "الآحاد",
+    "الآخر",
+    "الآفاق",
+    "الآلة",
+    "الآلي",
+    "الآمن",
+    "الآن",
+    "الآنات",
+    "الأبحاث",
+    "الأبد",
+    "الأبطأ",
+    "الأتقن",
+    "الأثر",
+    "الأجرام",
+    "الأجزاء",
+    "الأجسام",
+    "الأجناس",
+    "الأحجام",
+    "الأحدث",
+    "الأحياء",
+    "الأخبار",
+    "الأخر",
+    "الأخرى",
+    "الأخطاء",
+    "الأخير",
+    "الأخيرة",
+    "الأداء",
+    "الأداة",
+    "الأدنى",
+    "الأذونات",
+    "الأربع",
+    "الأرشيف",
+    "الأرض",
+    "الأرضية",
+    "الأرقام",
+    "الأركان",
+    "الأزلي",
+    "الأزمنة",
+    "الأساسي",
+    "الأسباب",
+    "الأسرع",
+    "الأسطر",
+    "الأسفل",
+    "الأسلحة",
+    "الأسماء",
+    "الأشخاص",
+    "الأشرف",
+    "الأشكال",
+    "الأشياء",
+    "الأصغر",
+    "الأصفار",
+    "الأصلي",
+    "الأصوات",
+    "الأصول",
+    "الأضرار",
+    "الأضعاف",
+    "الأطراف",
+    "الأعداء",
+    "الأعداد",
+    "الأعراض",
+    "الأعضاء",
+    "الأعظام",
+    "الأعلام",
+    "الأعلى",
+    "الأعمدة",
+    "الأغراض",
+    "الأفراس",
+    "الأفضل",
+    "الأقدم",
+    "الأقرب",
+    "الأقصى",
+    "الأقل",
+    "الأكبر",
+    "الأكثر",
+    "الألفاظ",
+    "الأماكن",
+    "الأمان",
+    "الأمثال",
+    "الأمر",
+    "الأمريكية",
+    "الأمصار",
+    "الأمل",
+    "الأمم",
+    "الأمنية",
+    "الأمين",
+    "الأنابيب",
+    "الأنسب",
+    "الأنش",
+    "الأنشطة",
+    "الأنظمة",
+    "الأنفاع",
+    "الأنماط",
+    "الأنواع",
+    "الأنيات",
+    "الأنية",
+    "الأهم",
+    "الأوائل",
+    "الأواخر",
+    "الأوامر",
+    "الأوقات",
+    "الأول",
+    "الأولى",
+    "الأيس",
+    "الإبداع",
+    "الإتفاق",
+    "الإجماليات",
+    "الإجمالية",
+    "الإدخال",
+    "الإسهاب",
+    "الإصدار",
+    "الإصدارات",
+    "الإصداران",
+    "الإضافية",
+    "الإضرار",
+    "الإطلاق",
+    "الإعداد",
+    "الإعدادات",
+    "الإقناع",
+    "الإقناعات",
+    "الإكمال",
+    "الإلهي",
+    "الإمكان",
+    "الإنجليزي",
+    "الإنسان",
+    "الإنساني",
+    "الإنسانية",
+    "الإيجابيات",
+    "الاتحاد",
+    "الاتساع",
+    "الاتفاق",
+    "الاثنان",
+    "الاثنين",
+    "الاجازة",
+    "الاحتلال",
+    "الاحتياطات",
+    "الاختلاف",
+    "الاختلافات",
+    "الاسبانية",
+    "الاستثناء",
+    "الاستحالة",
+    "الاستخدام",
+    "الاسترشاد",
+    "الاستقرار",
+    "الاستلام",
+    "الاسم",
+    "الاسمين",
+    "الاسهام",
+    "الاشتراك",
+    "الاشتراكية",
+    "الاعتبار",
+    "الاعتقاد",
+    "الاعتماد",
+    "الاعلان",
+    "الافتراضية",
+    "الاقتصادي",
+    "الاقتضاء",
+    "الاقناع",
+    "الالتزام",
+    "الامتناع",
+    "الانحصار",
+    "الانسان",
+    "الانسانية",
+    "الانسحاب",
+    "الانضمام",
+    "الانفراد",
+    "الانفعال",
+    "الانقسام",
+    "الباب",
+    "الباقي",
+    "الباقية",
+    "البايت",
+    "البايتات",
+    "البحار",
+    "البحث",
+    "البخت",
+    "البدء",
+    "البداية",
+    "البدن",
+    "البديل",
+    "البرء",
+    "البرامج",
+    "البرنامج",
+    "البرهان",
+    "البرهاني",
+    "البسيط",
+    "البسيطة",
+    "البشرية",
+    "البصري",
+    "البعد",
+    "البعض",
+    "البلاغة",
+    "البلدان",
+    "البناءات",
+    "البهيمية",
+    "البيئة",
+    "البيانات",
+    "البيت",
+    "البينة",
+    "التأثير",
+    "التأكيد",
+    "التابعون",
+    "التابعين",
+    "التاريخ",
+    "التاسعة",
+    "التالفة",
+    "التالي",
+    "التالية",
+    "التام",
+    "التامة",
+    "التبادل",
+    "التباين",
+    "التبدل",
+    "التج",
+    "التجربة",
+    "التجريبي",
+    "التجريبية",
+    "التجميع",
+    "التحذير",
+    "التحذيرات",
+    "التحقق",
+    "التحكم",
+    "التحليل",
+    "التحويل",
+    "التخزين",
+    "التدابير",
+    "التدبير",
+    "التدفق",
+    "التدفقات",
+    "التدمير",
+    "الترتيب",
+    "الترشيح",
+    "التركيب",
+    "الترميز",
+    "الترويسات",
+    "الترويسة",
+    "التزام",
+    "التزايد",
+    "التساوي",
+    "التسهيلات",
+    "التشابه",
+    "التشبيه",
+    "التشغيل",
+    "التصحيح",
+    "التصديق",
+    "التطابق",
+    "التطوير",
+    "التعاون",
+    "التعب",
+    "التعديل",
+    "التعديلات",
+    "التعرف",
+    "التعليم",
+    "التعليمات",
+    "التعليمي",
+    "التغاير",
+    "التفاهم",
+    "التفريغ",
+    "التفصيلي",
+    "التفكير",
+    "التقد",
+    "التقدم",
+    "التقليدية",
+    "التكثر",
+    "التكثير",
+    "التكرار",
+    "التماس",
+    "التمام",
+    "التمامية",
+    "التمثيل",
+    "التمرير",
+    "التمسك",
+    "التملك",
+    "التمييزية",
+    "التنسيق",
+    "التنفيذي",
+    "التنفيذية",
+    "التهوي",
+    "التوافق",
+    "التوالي",
+    "التوطين",
+    "التوقف",
+    "التي",
+    "الثالث",
+    "الثالثة",
+    "الثامنة",
+    "الثاني",
+    "الثانية",
+    "الثبوتية",
+    "الثلاثة",
+    "الثنائية",
+    "الجاري",
+    "الجامع",
+    "الجداول",
+    "الجدول",
+    "الجدولين",
+    "الجدية",
+    "الجديد",
+    "الجدير",
+    "الجرم",
+    "الجرمين",
+    "الجزء",
+    "الجزئيات",
+    "الجزئية",
+    "الجسم",
+    "الجلالة",
+    "الجليل",
+    "الجمعية",
+    "الجملة",
+    "الجمهور",
+    "الجمهوريات",
+    "الجميع",
+    "الجنس",
+    "الجهات",
+    "الجهة",
+    "الجهل",
+    "الجوامع",
+    "الجواهر",
+    "الجوهر",
+    "الجوهري",
+    "الجوهرية",
+    "الجوي",
+    "الجيد",
+    "الجيدة",
+    "الجيران",
+    "الحادة",
+    "الحادية",
+    "الحاس",
+    "الحال",
+    "الحالات",
+    "الحالة",
+    "الحالية",
+    "الحامل",
+    "الحاوية",
+    "الحجة",
+    "الحجم",
+    "الحجية",
+    "الحد",
+    "الحدود",
+    "الحدودية",
+    "الحذف",
+    "الحربية",
+    "الحرف",
+    "الحركات",
+    "الحركة",
+    "الحروف",
+    "الحس",
+    "الحساب",
+    "الحسد",
+    "الحسنات",
+    "الحشو",
+    "الحصول",
+    "الحظ",
+    "الحفاظ",
+    "الحق",
+    "الحقائق",
+    "الحقة",
+    "الحقية",
+    "الحقيقية",
+    "الحكوم",
+    "الحكومات",
+    "الحكومية",
+    "الحلول",
+    "الحمار",
+    "الحمولة",
+    "الحواس",
+    "الحي",
+    "الحيوان",
+    "الخ",
+    "الخارجي",
+    "الخاص",
+    "الخاصة",
+    "الخاصية",
+    "الخاطئة",
+    "الخام",
+    "الخامسة",
+    "الخرافات",
+    "الخرج",
+    "الخروج",
+    "الخط",
+    "الخطأ",
+    "الخطب",
+    "الخطوط",
+    "الخفية",
+    "الخلاء",
+    "الخلف",
+    "الخوارزمية",
+    "الخيار",
+    "الخيارات",
+    "الخيارين",
+    "الخيرات",
+    "الخيط",
+    "الخيوط",
+    "الد",
+    "الدأب",
+    "الدائرة",
+    "الدخل",
+    "الدعاية",
+    "الدقيق",
+    "الدلتا",
+    "الدليل",
+    "الدمج",
+    "الدنيا",
+    "الدهر",
+    "الدو",
+    "الدول",
+    "الدولة",
+    "الدولي",
+    "الدولية",
+    "الدوليين",
+    "الدين",
+    "الذات",
+    "الذاكرة",
+    "الذبح",
+    "الذكر",
+    "الذي",
+    "الذين",
+    "الرأي",
+    "الرئيسي",
+    "الرابع",
+    "الرابعة",
+    "الرابي",
+    "الرامية",
+    "الربو",
+    "الربوبية",
+    "الربوية",
+    "الرد",
+    "الرذائل",
+    "الرذيلة",
+    "الرسائل",
+    "الرسل",
+    "الرطل",
+    "الرغم",
+    "الرقمية",
+    "الرمزية",
+    "الروبوت",
+    "الرياضي",
+    "الرياضية",
+    "الزائدة",
+    "الزمان",
+    "الزمانين",
+    "الزمن",
+    "الزوجية",
+    "الزيادة",
+    "السابع",
+    "السابعة",
+    "السابق",
+    "السابقة",
+    "السادات",
+    "السادسة",
+    "الساكن",
+    "الساكنة",
+    "السالف",
+    "السالفة",
+    "السبب",
+    "السبع",
+    "السبيل",
+    "الست",
+    "السجل",
+    "السرعة",
+    "السطح",
+    "السطر",
+    "السطرين",
+    "السعادات",
+    "السكربتات",
+    "السكون",
+    "السلاسل",
+    "السلامة",
+    "السلسلة",
+    "السلم",
+    "السلمي",
+    "السلمية",
+    "السما",
+    "السمات",
+    "السماوية",
+    "السوفياتية",
+    "السيادة",
+    "السير",
+    "الشائعة",
+    "الشاسعة",
+    "الشامل",
+    "الشاملة",
+    "الشبكة",
+    "الشبيه",
+    "الشجرة",
+    "الشخص",
+    "الشخصية",
+    "الشرائط",
+    "الشرط",
+    "الشروط",
+    "الشروع",
+    "الشعر",
+    "الشعوب",
+    "الشكل",
+    "الشمالية",
+    "الشمس",
+    "الشيء",
+    "الشيئين",
+    "الشَرطة",
+    "الصادقة",
+    "الصار",
+    "الصبي",
+    "الصحيح",
+    "الصحيحة",
+    "الصدفة",
+    "الصدق",
+    "الصغر",
+    "الصغير",
+    "الصغيرة",
+    "الصفري",
+    "الصلة",
+    "الصناعات",
+    "الصناعة",
+    "الصناعي",
+    "الصوت",
+    "الصوتية",
+    "الصور",
+    "الصورة",
+    "الضاغط",
+    "الضامر",
+    "الضبط",
+    "الضد",
+    "الضعف",
+    "الضغط",
+    "الضمر",
+    "الطب",
+    "الطباع",
+    "الطبية",
+    "الطبيعة",
+    "الطبيعي",
+    "الطبيعيات",
+    "الطبيعية",
+    "الطبيعيين",
+    "الطرف",
+    "الطرفية",
+    "الطرق",
+    "الطريقة",
+    "الطول",
+    "الطويل",
+    "الظن",
+    "العادي",
+    "العادية",
+    "العارضة",
+    "العارف",
+    "العاشرة",
+    "العاصمة",
+    "العالمين",
+    "العالي",
+    "العالية",
+    "العام",
+    "العامة",
+    "العتاد",
+    "العثور",
+    "العدد",
+    "العددي",
+    "العديد",
+    "العرض",
+    "العرضي",
+    "العرضية",
+    "العريض",
+    "العسكرية",
+    "العشوائي",
+    "العظام",
+    "العظم",
+    "العظمى",
+    "العظيم",
+    "العقال",
+    "العقل",
+    "العقلية",
+    "العلاقات",
+    "العلامات",
+    "العلة",
+    "العلل",
+    "العلم",
+    "العلمي",
+    "العلمية",
+    "العلوم",
+    "العمق",
+    "العمل",
+    "العمليات",
+    "العملية",
+    "العمود",
+    "العميق",
+    "العناصر",
+    "العناوين",
+    "العنصر",
+    "العنوان",
+    "العويص",
+    "العين",
+    "العينفي",
+    "الغامضة",
+    "الغربة",
+    "الغرض",
+    "الـ",
+    "الفائض",
+    "الفارق",
+    "الفاسد",
+    "الفاصل",
+    "الفاعل",
+    "الفحص",
+    "الفراق",
+    "الفرد",
+    "الفردية",
+    "الفرعي",
+    "الفساد",
+    "الفصل",
+    "الفض",
+    "الفضاء",
+    "الفضائل",
+    "الفضائية",
+    "الفضائيون",
+    "الفضائيين",
+    "الفضيلة",
+    "الفعالة",
+    "الفعل",
+    "الفعلي",
+    "الفعلية",
+    "الفك",
+    "الفكر",
+    "الفكرة",
+    "الفكرية",
+    "الفلج",
+    "الفلسفة",
+    "الفلسفية",
+    "الفن",
+    "الفواصل",
+    "الفيزيائية",
+    "الفيلسوف",
+    "القائم",
+    "القائمة",
+    "القابل",
+    "القابلة",
+    "القاسمة",
+    "القاصية",
+    "القامعة",
+    "القاموس",
+    "القانون",
+    "القانونية",
+    "القانونيين",
+    "القدرة",
+    "القدم",
+    "القدماء",
+    "القديم",
+    "القديمة",
+    "القراءة",
+    "القرار",
+    "القرص",
+    "القسمة",
+    "القصص",
+    "القصوى",
+    "القليل",
+    "القم",
+    "القمر",
+    "القنية",
+    "القواميس",
+    "القوانين",
+    "القوة",
+    "القول",
+    "القومي",
+    "القومية",
+    "القوي",
+    "القياسي",
+    "القياسية",
+    "القيام",
+    "القيد",
+    "القيم",
+    "القيمة",
+    "القيود",
+    "الكائن",
+    "الكائنات",
+    "الكاتب",
+    "الكافرين",
+    "الكاملة",
+    "الكبير",
+    "الكبيرة",
+    "الكتابة",
+    "الكتل",
+    "الكتلة",
+    "الكثرة",
+    "الكثير",
+    "الكثيرة",
+    "الكشف",
+    "الكل",
+    "الكلاء",
+    "الكلام",
+    "الكلب",
+    "الكلي",
+    "الكلية",
+    "الكميات",
+    "الكمية",
+    "الكندي",
+    "الكوكب",
+    "الكون",
+    "الكيف",
+    "الكيفية",
+    "الكيلوبايتات",
+    "اللائي",
+    "اللاتي",
+    "اللاحقة",
+    "اللازمة",
+    "اللتان",
+    "اللتيا",
+    "اللتين",
+    "اللحم",
+    "اللذان",
+    "اللذين",
+    "اللسان",
+    "اللفظ",
+    "الله",
+    "اللواتي",
+    "اللون",
+    "اللوني",
+    "المؤدية",
+    "المؤقت",
+    "المؤقتة",
+    "الماء",
+    "المادة",
+    "الماس",
+    "الماضي",
+    "المالك",
+    "المبادئ",
+    "المباشرة",
+    "المباينة",
+    "المبدئي",
+    "المبدئية",
+    "المبدع",
+    "المبرزين",
+    "المتاحة",
+    "المتبادل",
+    "المتبدل",
+    "المتبدلة",
+    "المتبقي",
+    "المتتالية",
+    "المتحدة",
+    "المتحرك",
+    "المتدفقة",
+    "المترادفة",
+    "المتساوية",
+    "المتسلسلة",
+    "المتسمين",
+    "المتشابهة",
+    "المتصلة",
+    "المتعدد",
+    "المتعددة",
+    "المتعلمين",
+    "المتغايرات",
+    "المتغير",
+    "المتفرقة",
+    "المتفلسفين",
+    "المتقادمة",
+    "المتقاربة",
+    "المتقدمين",
+    "المتمكن",
+    "المتناهي",
+    "المتناهية",
+    "المتهويات",
+    "المتوازي",
+    "المتوازية",
+    "المتوافقة",
+    "المثال",
+    "المثل",
+    "المجتمع",
+    "المجتمعة",
+    "المجموعة",
+    "المحاذاة",
+    "المحالات",
+    "المحتمل",
+    "المحتملة",
+    "المحتويات",
+    "المحدد",
+    "المحددة",
+    "المحدود",
+    "المحدودة",
+    "المحرك",
+    "المحسوس",
+    "المحسوسات",
+    "المحسوسة",
+    "المحطات",
+    "المحلل",
+    "المحلية",
+    "المحمول",
+    "المحمولة",
+    "المحيط",
+    "المختار",
+    "المختارة",
+    "المختصة",
+    "المختلفة",
+    "المخرج",
+    "المخرجات",
+    "المخزنة",
+    "المخصصة",
+    "المخلفات",
+    "المدة",
+    "المدخل",
+    "المدخلات",
+    "المدرك",
+    "المدعوم",
+    "المدعومة",
+    "المدمجة",
+    "المدية",
+    "المذكورة",
+    "المذكورون",
+    "المرء",
+    "المراد",
+    "المرادفة",
+    "المرتبطة",
+    "المرتضاة",
+    "المردية",
+    "المرسل",
+    "المرشح",
+    "المرشحات",
+    "المرشحة",
+    "المرفق",
+    "المركب",
+    "المرمز",
+    "المرمّز",
+    "المرن",
+    "المريحة",
+    "المزامنة",
+    "المزمعة",
+    "المزورة",
+    "المزيد",
+    "المسألتين",
+    "المسؤولية",
+    "المسائل",
+    "المساعدة",
+    "المسافة",
+    "المساواة",
+    "المسبق",
+    "المسبقة",
+    "المستثنى",
+    "المستخدم",
+    "المستخدمة",
+    "المستعرض",
+    "المستعملة",
+    "المستفاد",
+    "المستقبل",
+    "المستقبلية",
+    "المستقرة",
+    "المستمر",
+    "المستهلكة",
+    "المستوى",
+    "المستويات",
+    "المستويين",
+    "المسجلة",
+    "المسماة",
+    "المسمى",
+    "المسهلة",
+    "المشاركة",
+    "المشاورات",
+    "المشتبهة",
+    "المشترك",
+    "المشتركات",
+    "المشتركة",
+    "المشفر",
+    "المشكلات",
+    "المشكلة",
+    "المصادر",
+    "المصدر",
+    "المصلحة",
+    "المصور",
+    "المضادة",
+    "المضادين",
+    "المضاف",
+    "المضغوط",
+    "المضغوطة",
+    "المطالب",
+    "المطبوعة",
+    "المطلع",
+    "المطلق",
+    "المطلقة",
+    "المطلوب",
+    "المطلوبات",
+    "المطلوبة",
+    "المظنون",
+    "المعا",
+    "المعادين",
+    "المعالج",
+    "المعالجات",
+    "المعالجة",
+    "المعاهدة",
+    "المعتاد",
+    "المعتادان",
+    "المعتادة",
+    "المعتصم",
+    "المعدة",
+    "المعدل",
+    "المعرفة",
+    "المعروض",
+    "المعروف",
+    "المعروفة",
+    "المعطاة",
+    "المعطيات",
+    "المعقول",
+    "المعقولات",
+    "المعقولة",
+    "المعلقة",
+    "المعلول",
+    "المعلولات",
+    "المعلولة",
+    "المعلوم",
+    "المعلومات",
+    "المعنية",
+    "المفاتيح",
+    "المفاجآت",
+    "المفترض",
+    "المفترضة",
+    "المفرق",
+    "المفروض",
+    "المفصول",
+    "المفصولة",
+    "المفكر",
+    "المفكوك",
+    "المفكوكة",
+    "المفوضون",
+    "المفيد",
+    "المفيدات",
+    "المفيدة",
+    "المقابلة",
+    "المقالات",
+    "المقدر",
+    "المقدمات",
+    "المقدمة",
+    "المقر",
+    "المقررة",
+    "المقروءة",
+    "المقسوم",
+    "المقصر",
+    "المقصودة",
+    "المقول",
+    "المقولات",
+    "المقولة",
+    "المكان",
+    "المكانية",
+    "المكركب",
+    "المكونة",
+    "الملاء",
+    "الملاحظات",
+    "الملاحون",
+    "الملاحين",
+    "الملاكات",
+    "الملتبسة",
+    "الملحدين",
+    "الملحق",
+    "الملحقات",
+    "الملخص",
+    "الملف",
+    "الملفات",
+    "الملون",
+    "المماثلة",
+    "الممارسة",
+    "الممتدة",
+    "الممثلون",
+    "الممسك",
+    "الممكن",
+    "الممكنة",
+    "المناسب",
+    "المناسبة",
+    "المنتج",
+    "المنتجة",
+    "المنسوبة",
+    "المنشأة",
+    "المنضمة",
+    "المنظمات",
+    "المنظمة",
+    "المنفصلة",
+    "المنقضي",
+    "المهدرة",
+    "المهم",
+    "الموازاة",
+    "المواقف",
+    "الموجود",
+    "الموجودة",
+    "الموحد",
+    "الموحدات",
+    "الموروث",
+    "الموزعة",
+    "الموضحة",
+    "الموضع",
+    "الموضوع",
+    "الموقعة",
+    "الموقعون",
+    "الميت",
+    "الميجابايتات",
+    "الميزات",
+    "الميزة",
+    "النائلين",
+    "الناتج",
+    "الناتجة",
+    "الناحية",
+    "الناس",
+    "الناطق",
+    "الناظرين",
+    "النافذة",
+    "النبات",
+    "النبي",
+    "النتائج",
+    "النتيجة",
+    "النسبة",
+    "النسبية",
+    "النسخ",
+    "النشاط",
+    "النشطة",
+    "النشوء",
+    "النص",
+    "النصوص",
+    "النصي",
+    "النصية",
+    "النظام",
+    "النظر",
+    "النفس",
+    "النفي",
+    "النفيسة",
+    "النقص",
+    "النقطية",
+    "النمط",
+    "النموذجي",
+    "النهايات",
+    "النهاية",
+    "النواحي",
+    "النوع",
+    "الهابطة",
+    "الهاش",
+    "الهدف",
+    "الهزلية",
+    "الهوية",
+    "الهيئات",
+    "الهيولاني",
+    "الهيولانية",
+    "الهيولى",
+    "الواترة",
+    "الواجد",
+    "الواحد",
+    "الواحدات",
+    "الواحدة",
+    "الواسعة",
+    "الواضحة",
+    "الواقع",
+    "الواقية",
+    "الوجود",
+    "الوحدانيات",
+    "الوحدانية",
+    "الوحدة",
+    "الوحيد",
+    "الوحيدة",
+    "الودية",
+    "الوديعة",
+    "الورق",
+    "الوسيطة",
+    "الوصايا",
+    "الوصف",
+    "الوصفية",
+    "الوصلات",
+    "الوصول",
+    "الوضع",
+    "الوطواط",
+    "الوقت",
+    "الول",
+    "اليها",
+    "اليوم",
+    "اليومي",
+    "اليونانيين",
+    "ان",
+    "انتقاص",
+    "انتهينا",
+    "انخفاض",
+    "انشاء",
+    "انضمام",
+    "انضمامها",
+    "انطباق",
+    "انظر",
+    "انفصل",
+    "انفعال",
+    "انقسم",
+    "انماء",
+    "ايداع",
+    "بآحاد",
+    "بأبعاده",
+    "بأحجام",
+    "بأحد",
+    "بأدوات",
+    "بأزلي",
+    "بأعظم",
+    "بأقدار",
+    "بأقسام",
+    "بأكثر",
+    "بألسنتهم",
+    "بأمر",
+    "بأن",
+    "بأنه",
+    "بأنها",
+    "بأنواع",
+    "بأي",
+    "بأية",
+    "بإعدادات",
+    "بإفادة",
+    "بإمكان",
+    "بإنشاء",
+    "بإيداع",
+    "بائنة",
+    "باتحادها",
+    "باتخاذ",
+    "باجتلاب",
+    "باجتماع",
+    "باحث",
+    "باحثات",
+    "باحثة",
+    "بارسال",
+    "باستثناء",
+    "باستخدام",
+    "باسم",
+    "باشتباه",
+    "باشر",
+    "باضطرار",
+    "باع",
+    "باعتبارها",
+    "باعلام",
+    "باعلان",
+    "باعه",
+    "باقي",
+    "باقيها",
+    "بالآتي",
+    "بالأبحاث",
+    "بالأسماء",
+    "بالأشخاص",
+    "بالأشياء",
+    "بالأكثر",
+    "بالإضافة",
+    "بالإقرار",
+    "بالإمكان",
+    "بالإيجاد",
+    "بالاتصال",
+    "بالاتفاق",
+    "بالاجماع",
+    "بالاستفادة",
+    "بالاستمرار",
+    "بالاسم",
+    "بالاشتراك",
+    "بالاضطرار",
+    "بالبايت",
+    "بالبخت",
+    "بالبرنامج",
+    "بالبرهان",
+    "بالبرودة",
+    "بالبعد",
+    "بالتحرك",
+    "بالتخزين",
+    "بالترتيب",
+    "بالتشبيه",
+    "بالتفصيل",
+    "بالتمثيل",
+    "بالتنسيق",
+    "بالجزئية",
+    "بالجنس",
+    "بالجوهر",
+    "بالحجج",
+    "بالحرف",
+    "بالحركة",
+    "بالحس",
+    "بالحق",
+    "بالحقيقة",
+    "بالحلاوة",
+    "بالحواس",
+    "بالخمسة",
+    "بالدين",
+    "بالذات",
+    "بالذاتي",
+    "بالذكر",
+    "بالذين",
+    "بالرباط",
+    "بالزمان",
+    "بالسماح",
+    "بالشرف",
+    "بالشكل",
+    "بالشيء",
+    "بالصدفة",
+    "بالصورة",
+    "بالضرورة",
+    "بالضغط",
+    "بالطبع",
+    "بالطريقة",
+    "بالطول",
+    "بالعدد",
+    "بالعرض",
+    "بالعنصر",
+    "بالفعل",
+    "بالقرب",
+    "بالقوة",
+    "بالكامل",
+    "بالكثرة",
+    "بالكلي",
+    "بالله",
+    "باللون",
+    "بالمجاز",
+    "بالمرشح",
+    "بالمساواة",
+    "بالمستخدم",
+    "بالمستويات",
+    "بالمعلومات",
+    "بالملحق",
+    "بالمهنة",
+    "بالنسبة",
+    "بالنظام",
+    "بالنظر",
+    "بالنفس",
+    "بالنوع",
+    "بالهيولى",
+    "بالواحد",
+    "بالوضع",
+    "بالولاية",
+    "بالوهم",
+    "باليبس",
+    "بانتقال",
+    "بانسحا",
+    "بانقسام",
+    "بايت",
+    "بايتات",
+    "ببايب",
+    "ببرنامج",
+    "ببساطة",
+    "ببسيط",
+    "ببطلانه",
+    "ببعده",
+    "ببعديه",
+    "ببعض",
+    "بت",
+    "بتأخير",
+    "بتأسيس",
+    "بتأييد",
+    "بتات",
+    "بتة",
+    "بتةً",
+    "بتجاوز",
+    "بتحديد",
+    "بتحويل",
+    "بتخصيص",
+    "بتسجيل",
+    "بتعدد",
+    "بتعديل",
+    "بتفضله",
+    "بتقديم",
+    "بتقسيم",
+    "بتقليل",
+    "بتنسيق",
+    "بتوقيع",
+    "بتولجها",
+    "بتيجان",
+    "بثلاث",
+    "بجزء",
+    "بجميع",
+    "بجهة",
+    "بجهد",
+    "بجوهر",
+    "بجوهري",
+    "بحاجة",
+    "بحال",
+    "بحالة",
+    "بحاملاتها",
+    "بحث",
+    "بحثنا",
+    "بحثه",
+    "بحجم",
+    "بحد",
+    "بحذف",
+    "بحرف",
+    "بحركة",
+    "بحصن",
+    "بحق",
+    "بحقائقها",
+    "بحقيقة",
+    "بحقيقته",
+    "بحقيقية",
+    "بحكم",
+    "بحوالي",
+    "بحيث",
+    "بخ",
+    "بخس",
+    "بخطوات",
+    "بخلاء",
+    "بخلاف",
+    "بخمس",
+    "بدء",
+    "بدعوى",
+    "بدقة",
+    "بدل",
+    "بدلاً",
+    "بدن",
+    "بدون",
+    "بدونها",
+    "بديلة",
+    "بذاته",
+    "بذاتي",
+    "بذاكرة",
+    "بذلك",
+    "برامج",
+    "بربوبية",
+    "برسم",
+    "برمجي",
+    "برمجياً",
+    "برنامج",
+    "برهان",
+    "برهاناً",
+    "بزيار",
+    "بس",
+    "بسبب",
+    "بسدف",
+    "بسهولة",
+    "بسيط",
+    "بسيطان",
+    "بسيطين",
+    "بشأن",
+    "بشع",
+    "بشكل",
+    "بشيء",
+    "بصادق",
+    "بصدد",
+    "بصدق",
+    "بصمت",
+    "بصيغ",
+    "بصيغة",
+    "بضبط",
+    "بضبطه",
+    "بضده",
+    "بضع",
+    "بضعة",
+    "بطالب",
+    "بطباعه",
+    "بطباعها",
+    "بطبيعي",
+    "بطرفية",
+    "بطريق",
+    "بطريقة",
+    "بطريقتين",
+    "بطل",
+    "بطلت",
+    "بطول",
+    "بطيئاً",
+    "بعد",
+    "بعدة",
+    "بعدد",
+    "بعدم",
+    "بعدمها",
+    "بعده",
+    "بعدها",
+    "بعرض",
+    "بعز",
+    "بعض",
+    "بعضاً",
+    "بعضه",
+    "بعضها",
+    "بعلامات",
+    "بعلة",
+    "بعلم",
+    "بعهده",
+    "بعود",
+    "بعينه",
+    "بغاية",
+    "بغية",
+    "بغير",
+    "بغيرية",
+    "بـ",
+    "بفاصلة",
+    "بفترة",
+    "بفك",
+    "بفواصل",
+    "بقاءك",
+    "بقائله",
+    "بقدر",
+    "بقسم",
+    "بقسمة",
+    "بقسمين",
+    "بقليل",
+    "بقول",
+    "بقي",
+    "بقية",
+    "بقيم",
+    "بقيمة",
+    "بك",
+    "بكتل",
+    "بكثير",
+    "بكسل",
+    "بكل",
+    "بكم",
+    "بكما",
+    "بكماله",
+    "بكمية",
+    "بكن",
+    "بكيف",
+    "بل",
+    "بلا",
+    "بلاحقة",
+    "بلى",
+    "بم",
+    "بما",
+    "بماذا",
+    "بمبدأ",
+    "بمتحرك",
+    "بمتحركة",
+    "بمتساوية",
+    "بمتصل",
+    "بمتصلة",
+    "بمثابة",
+    "بمثل",
+    "بمجانسة",
+    "بمجرد",
+    "بمحرف",
+    "بمدة",
+    "بمراجع",
+    "بمسافات",
+    "بمشاركة",
+    "بمشتبهة",
+    "بمعدل",
+    "بمعرف",
+    "بمعلوم",
+    "بمعنى",
+    "بمكان",
+    "بملف",
+    "بمن",
+    "بموافاة",
+    "بموجود",
+    "بموجودة",
+    "بنا",
+    "بناء",
+    "بناءً",
+    "بنجاح",
+    "بنسبة",
+    "بنفس",
+    "بنقطتين",
+    "بنهاياته",
+    "بنهاية",
+    "بنوع",
+    "به",
+    "بها",
+    "بهاتين",
+    "بهديهم",
+    "بهذا",
+    "بهذه",
+    "بهم",
+    "بهما",
+    "بهن",
+    "بهوية",
+    "بهويته",
+    "بواجبة",
+    "بواحد",
+    "بواسطة",
+    "بوجوب",
+    "بوجود",
+    "بوجودها",
+    "بوسائل",
+    "بي",
+    "بيئة",
+    "بيان",
+    "بيانات",
+    "بيتا",
+    "بيد",
+    "بيسير",
+    "بين",
+    "بينا",
+    "بينما",
+    "بينه",
+    "بينها",
+    "تأثير",
+    "تأثيرات",
+    "تأخذ",
+    "تأمين",
+    "تأويل",
+    "تؤثر",
+    "تاريخ",
+    "تالف",
+    "تالفاً",
+    "تام",
+    "تاماً",
+    "تامة",
+    "تباشرها",
+    "تباين",
+    "تبحث",
+    "تبحثان",
+    "تبدل",
+    "تبدلاً",
+    "تبقى",
+    "تبلده",
+    "تبين",
+    "تتأثر",
+    "تتاح",
+    "تتبدل",
+    "تتبع",
+    "تتجاهل",
+    "تتجاوز",
+    "تتحد",
+    "تتحسن",
+    "تتداخل",
+    "تتدفق",
+    "تترتب",
+    "تتردد",
+    "تتسبب",
+    "تتشابه",
+    "تتصل",
+    "تتضمن",
+    "تتطلب",
+    "تتعلق",
+    "تتعهد",
+    "تتغير",
+    "تتم",
+    "تتميم",
+    "تتوجوا",
+    "تتوفر",
+    "تتوقف",
+    "تتيح",
+    "تثبيت",
+    "تثني",
+    "تثنية",
+    "تجاهل",
+    "تجاهله",
+    "تجاهلها",
+    "تجاوز",
+    "تجاوزت",
+    "تجاوزه",
+    "تجب",
+    "تجر",
+    "تجريبا",
+    "تجريبيا",
+    "تجريبية",
+    "تجعل",
+    "تجنب",
+    "تحاول",
+    "تحت",
+    "تحتاج",
+    "تحتفظ",
+    "تحتوي",
+    "تحدث",
+    "تحدد",
+    "تحديد",
+    "تحديداً",
+    "تحذير",
+    "تحذيرات",
+    "تحذيراً",
+    "تحرك",
+    "تحسين",
+    "تحصينات",
+    "تحفظنا",
+    "تحليل",
+    "تحليلها",
+    "تحمل",
+    "تحويل",
+    "تحير",
+    "تختلف",
+    "تخزن",
+    "تخزين",
+    "تخزينها",
+    "تخصيص",
+    "تخضع",
+    "تخطر",
+    "تخلو",
+    "تخمين",
+    "تدرك",
+    "تدعم",
+    "تدفق",
+    "تدفقات",
+    "تذكر",
+    "تذهب",
+    "تراعي",
+    "ترتبط",
+    "ترتيب",
+    "ترتيبها",
+    "ترسله",
+    "ترقيم",
+    "تركب",
+    "تركيب",
+    "تركيبه",
+    "ترميز",
+    "ترويسات",
+    "ترويسة",
+    "ترى",
+    "تريد",
+    "تزال",
+    "تزده",
+    "تزيل",
+    "تساعد",
+    "تسبب",
+    "تسبقه",
+    "تستحق",
+    "تستخدم",
+    "تستغرق",
+    "تستفيد",
+    "تستلهم",
+    "تسري",
+    "تسريع",
+    "تسع",
+    "تسلسل",
+    "تسلسلات",
+    "تسمح",
+    "تسهيل",
+    "تشابهاً",
+    "تشبه",
+    "تشجيع",
+    "تشرين",
+    "تشغل",
+    "تشغيل",
+    "تشغيلاً",
+    "تشفير",
+    "تشفيرها",
+    "تشير",
+    "تصديق",
+    "تصديقها",
+    "تصر",
+    "تصف",
+    "تصل",
+    "تصميم",
+    "تضاف",
+    "تضبط",
+    "تضبطه",
+    "تضبطها",
+    "تضعيف",
+    "تضيف",
+    "تطابق",
+    "تطابقات",
+    "تطبع",
+    "تطبيقه",
+    "تطلب",
+    "تطلق",
+    "تظهر",
+    "تعاون",
+    "تعتقد",
+    "تعتمد",
+    "تعدد",
+    "تعديل",
+    "تعذر",
+    "تعرض",
+    "تعرف",
+    "تعريض",
+    "تعزيز",
+    "تعزيزا",
+    "تعطل",
+    "تعطي",
+    "تعطيله",
+    "تعليمات",
+    "تعمل",
+    "تعني",
+    "تعود",
+    "تعيين",
+    "تغاير",
+    "تغطي",
+    "تغيير",
+    "تغييرات",
+    "تغييره",
+    "تغييرها",
+    "تفادي",
+    "تفريغ",
+    "تفشل",
+    "تفصيلاً",
+    "تفعله",
+    "تفعيل",
+    "تفي",
+    "تقارن",
+    "تقال",
+    "تقبل",
+    "تقبلها",
+    "تقدم",
+    "تقدماً",
+    "تقدمت",
+    "تقديم",
+    "تقديمه",
+    "تقرأ",
+    "تقريب",
+    "تقريبات",
+    "تقريباً",
+    "تقسمه",
+    "تقسيم",
+    "تقع",
+    "تقليل",
+    "تقوم",
+    "تكتب",
+    "تكتشفها",
+    "تكثراً",
+    "تكثير",
+    "تكرار",
+    "تكن",
+    "تكون",
+    "تلتزم",
+    "تلحق",
+    "تلحقه",
+    "تلقي",
+    "تلك",
+    "تلكم",
+    "تلكما",
+    "تلواً",
+    "تلويث",
+    "تليها",
+    "تم",
+    "تمام",
+    "تماماً",
+    "تمت",
+    "تمتلك",
+    "تمثل",
+    "تمثلها",
+    "تمثيل",
+    "تمثيلاً",
+    "تمرير",
+    "تمريرها",
+    "تمع",
+    "تمكين",
+    "تملك",
+    "تمهراً",
+    "تميل",
+    "تمييز",
+    "تمييزي",
+    "تنتقص",
+    "تنتهِ",
+    "تنسيق",
+    "تنسيقات",
+    "تنشأ",
+    "تنشئ",
+    "تنفيذ",
+    "تنفيذات",
+    "تنفيذي",
+    "تنفيذية",
+    "تنقسم",
+    "تنهي",
+    "ته",
+    "تهم",
+    "تهوي",
+    "تهويها",
+    "تهيئة",
+    "توافق",
+    "توثيق",
+    "توجب",
+    "توجد",
+    "توحداً",
+    "تود",
+    "توزيعات",
+    "توسط",
+    "توفر",
+    "توفير",
+    "توفيرها",
+    "توقعها",
+    "توقياً",
+    "توقيع",
+    "توكاني",
+    "توهم",
+    "تي",
+    "تيسير",
+    "تين",
+    "تينك",
+    "تُتبع",
+    "تُحدد",
+    "تُحلل",
+    "تُخزن",
+    "تُخفض",
+    "تُدرج",
+    "تُدعم",
+    "تُزال",
+    "تُستخدم",
+    "تُشفر",
+    "تُضاهي",
+    "تُضبط",
+    "تُضغط",
+    "تُطبع",
+    "تُظهر",
+    "تُعامل",
+    "تُعتبر",
+    "تُعد",
+    "تُعدل",
+    "تُعرض",
+    "تُعطَ",
+    "تُفضل",
+    "تُفك",
+    "تُقاس",
+    "تُقبل",
+    "تُقرأ",
+    "تُقرب",
+    "تُكتب",
+    "تُمرر",
+    "تُنسخ",
+    "تُنشأ",
+    "تُهمَل",
+    "ثابت",
+    "ثابتاً",
+    "ثابتة",
+    "ثانية",
+    "ثانيتين",
+    "ثلاث",
+    "ثلاثات",
+    "ثلاثة",
+    "ثم",
+    "ثمار",
+    "ثمانية",
+    "ثمة",
+    "ثناؤه",
+    "ثنائية",
+    "ثوانٍ",
+    "جامع",
+    "جامعاً",
+    "جانب",
+    "جاهز",
+    "جبل",
+    "جداً",
+    "جدولة",
+    "جديد",
+    "جديدة",
+    "جرب",
+    "جرم",
+    "جرماً",
+    "جرمه",
+    "جرمين",
+    "جرى",
+    "جزء",
+    "جزءاً",
+    "جزؤه",
+    "جزئيات",
+    "جزئياً",
+    "جزم",
+    "جسم",
+    "جسماً",
+    "جعل",
+    "جعلته",
+    "جل",
+    "جليل",
+    "جماع",
+    "جماعات",
+    "جماعة",
+    "جمع",
+    "جمعا",
+    "جملة",
+    "جميع",
+    "جميعاً",
+    "جميعها",
+    "جميعهم",
+    "جنته",
+    "جنس",
+    "جنساً",
+    "جنسه",
+    "جنسها",
+    "جنونية",
+    "جهات",
+    "جهاز",
+    "جهة",
+    "جهدنا",
+    "جواهر",
+    "جوهر",
+    "جوهره",
+    "جوهرها",
+    "جوهرياً",
+    "جوهرية",
+    "جوهرين",
+    "جيجابايت",
+    "جيجابايتات",
+    "جيد",
+    "جيداً",
+    "جيدة",
+    "جُمد",
+    "ح",
+    "حاجة",
+    "حاد",
+    "حادث",
+    "حاسراً",
+    "حاشا",
+    "حال",
+    "حالة",
+    "حاله",
+    "حالياً",
+    "حامل",
+    "حبذا",
+    "حتى",
+    "حجم",
+    "حجماً",
+    "حد",
+    "حداً",
+    "حدة",
+    "حدث",
+    "حدد",
+    "حددنا",
+    "حدده",
+    "حده",
+    "حدها",
+    "حدوث",
+    "حدود",
+    "حدودها",
+    "حديثاً",
+    "حديثة",
+    "حديدة",
+    "حذف",
+    "حرا",
+    "حراصاً",
+    "حررت",
+    "حرف",
+    "حرفاً",
+    "حركة",
+    "حركته",
+    "حروف",
+    "حرية",
+    "حزم",
+    "حساب",
+    "حساً",
+    "حسب",
+    "حسي",
+    "حسياً",
+    "حشو",
+    "حصول",
+    "حط",
+    "حفظ",
+    "حق",
+    "حقائقها",
+    "حقاً",
+    "حقيقة",
+    "حقيقته",
+    "حقيقية",
+    "حكومات",
+    "حل",
+    "حواسيب",
+    "حوالي",
+    "حول",
+    "حي",
+    "حياة",
+    "حيث",
+    "حيثما",
+    "حيز",
+    "حيزهما",
+    "حين",
+    "حُدد",
+    "حُددت",
+    "حُذف",
+    "حُفظت",
+    "خارج",
+    "خارجة",
+    "خارجياً",
+    "خاص",
+    "خاصان",
+    "خاصاً",
+    "خاصة",
+    "خاطئ",
+    "خالفنا",
+    "خام",
+    "خرج",
+    "خرجت",
+    "خرجنا",
+    "خرق",
+    "خروج",
+    "خشباً",
+    "خشنة",
+    "خصوصاً",
+    "خط",
+    "خطأ",
+    "خطوطنا",
+    "خطية",
+    "خفض",
+    "خلا",
+    "خلاء",
+    "خلاف",
+    "خلف",
+    "خمس",
+    "خمسة",
+    "خوارزميات",
+    "خيار",
+    "خيارات",
+    "خياراً",
+    "خيط",
+    "خيطاً",
+    "خيوط",
+    "د",
+    "دائم",
+    "دائماً",
+    "دائمة",
+    "داخل",
+    "دار",
+    "دال",
+    "دراسة",
+    "درجة",
+    "دعم",
+    "دقة",
+    "دلتا",
+    "دليل",
+    "دليلاً",
+    "دمج",
+    "دهرنا",
+    "دولة",
+    "دولي",
+    "دولية",
+    "دون",
+    "دونه",
+    "دى",
+    "ديد",
+    "ديسمبر",
+    "ذ",
+    "ذا",
+    "ذات",
+    "ذاته",
+    "ذاتها",
+    "ذاتهما",
+    "ذاتي",
+    "ذاتياً",
+    "ذاتية",
+    "ذاك",
+    "ذاكرة",
+    "ذان",
+    "ذانك",
+    "ذبا",
+    "ذرى",
+    "ذكرنا",
+    "ذكرها",
+    "ذلك",
+    "ذلكم",
+    "ذلكما",
+    "ذلكن",
+    "ذه",
+    "ذو",
+    "ذوا",
+    "ذوات",
+    "ذواتا",
+    "ذواتها",
+    "ذواتي",
+    "ذوي",
+    "ذي",
+    "ذين",
+    "ذينك",
+    "ر",
+    "رأسيتين",
+    "رؤية",
+    "رائحة",
+    "رابي",
+    "رابياً",
+    "رب",
+    "ربما",
+    "ربو",
+    "ربوبيته",
+    "رجل",
+    "رسائل",
+    "رسالة",
+    "رسم",
+    "رسمية",
+    "رطل",
+    "رغم",
+    "رقم",
+    "رقمان",
+    "رقماً",
+    "ركب",
+    "ركن",
+    "ركناً",
+    "ركنه",
+    "رمزياً",
+    "رمنا",
+    "ريب",
+    "ريث",
+    "زاحد",
+    "زادت",
+    "زمان",
+    "زمانان",
+    "زماننا",
+    "زمن",
+    "زمنية",
+    "زوال",
+    "زوايا",
+    "زوجاً",
+    "زوجياً",
+    "زيادة",
+    "زيارة",
+    "زيارته",
+    "زيد",
+    "سابقاً",
+    "ساكن",
+    "ساكناً",
+    "ساكنة",
+    "سالمين",
+    "سبب",
+    "سبق",
+    "سبل",
+    "سبلاً",
+    "سبله",
+    "سبيل",
+    "سبيلنا",
+    "ستستمر",
+    "ستعيد",
+    "سجلها",
+    "سجوفه",
+    "سرائرنا",
+    "سرابيل",
+    "سرد",
+    "سرعة",
+    "سرمداً",
+    "سرور",
+    "سريعة",
+    "سطح",
+    "سطر",
+    "سطراً",
+    "سطوح",
+    "سعة",
+    "سعد",
+    "سكربت",
+    "سكربتات",
+    "سكون",
+    "سلاسل",
+    "سلامة",
+    "سلسلة",
+    "سلك",
+    "سلكت",
+    "سلمية",
+    "سلوك",
+    "سلوكاً",
+    "سماوي",
+    "سماوية",
+    "سمىً",
+    "سمي",
+    "سنبين",
+    "سنة",
+    "سهلاً",
+    "سهلت",
+    "سوء",
+    "سواء",
+    "سوف",
+    "سوى",
+    "سيؤدي",
+    "سيئة",
+    "سياق",
+    "سيتجاوز",
+    "سيتم",
+    "سيجري",
+    "سيجعل",
+    "سيحاول",
+    "سيستخدم",
+    "سيستمر",
+    "سيسهم",
+    "سيعرض",
+    "سيعطي",
+    "سيعمل",
+    "سيفك",
+    "سيكون",
+    "سينشئها",
+    "سيُظهر",
+    "شأ",
+    "شأن",
+    "شأنه",
+    "شائعة",
+    "شاملة",
+    "شبه",
+    "شتان",
+    "شتى",
+    "شجرة",
+    "شخص",
+    "شخصاً",
+    "شخصية",
+    "شدة",
+    "شديد",
+    "شرطات",
+    "شرطة",
+    "شرطتين",
+    "شروط",
+    "شعاع",
+    "شك",
+    "شكرنا",
+    "شكل",
+    "شكله",
+    "شناعة",
+    "شهادات",
+    "شهر",
+    "شيء",
+    "شيئان",
+    "شيئاً",
+    "شيئين",
+    "شيفرة",
+    "شيوعاً",
+    "صاحبة",
+    "صادق",
+    "صادقاً",
+    "صادقين",
+    "صار",
+    "صارت",
+    "صحتهم",
+    "صحيح",
+    "صدر",
+    "صدور",
+    "صراحة",
+    "صراحةً",
+    "صغير",
+    "صغيراً",
+    "صغيرة",
+    "صغيرين",
+    "صفات",
+    "صفر",
+    "صفراً",
+    "صفرية",
+    "صلبة",
+    "صلوات",
+    "صناعة",
+    "صناعياً",
+    "صنع",
+    "صنفنا",
+    "صوت",
+    "صور",
+    "صورة",
+    "صورته",
+    "صورتها",
+    "صوره",
+    "صورها",
+    "صياغة",
+    "صيانة",
+    "صيغ",
+    "صيغة",
+    "صيغتها",
+    "ضئيل",
+    "ضئيلة",
+    "ضار",
+    "ضارة",
+    "ضاغط",
+    "ضبط",
+    "ضد",
+    "ضرب",
+    "ضرر",
+    "ضعف",
+    "ضغط",
+    "ضغطه",
+    "ضغطها",
+    "ضمر",
+    "ضمن",
+    "ضُبط",
+    "ضُبطت",
+    "ضُغطت",
+    "طاقة",
+    "طاقتنا",
+    "طالما",
+    "طاليس",
+    "طباع",
+    "طباعه",
+    "طباعي",
+    "طبع",
+    "طبعه",
+    "طبيعة",
+    "طبيعي",
+    "طبيعياً",
+    "طة",
+    "طرف",
+    "طرفا",
+    "طرفية",
+    "طرفيه",
+    "طرق",
+    "طرقاً",
+    "طريق",
+    "طريقة",
+    "طعم",
+    "طلب",
+    "طلبات",
+    "طلبه",
+    "طلبها",
+    "طول",
+    "طولاً",
+    "طويل",
+    "طويلاً",
+    "طويلة",
+    "طير",
+    "طُبق",
+    "ظاهرة",
+    "ظلمة",
+    "ظن",
+    "ظنية",
+    "ظهوراً",
+    "عاد",
+    "عاداتنا",
+    "عاداتهم",
+    "عادة",
+    "عادةً",
+    "عادت",
+    "عادي",
+    "عادياً",
+    "عادية",
+    "عارض",
+    "عارضاً",
+    "عاقلة",
+    "عالمة",
+    "عالية",
+    "عام",
+    "عاماً",
+    "عامة",
+    "عاند",
+    "عبر",
+    "عتادياً",
+    "عدا",
+    "عدات",
+    "عداد",
+    "عدة",
+    "عدد",
+    "عدداً",
+    "عددي",
+    "عددية",
+    "عدم",
+    "عدماء",
+    "عدواني",
+    "عديدة",
+    "عرض",
+    "عرضاً",
+    "عرضها",
+    "عرضي",
+    "عرضياً",
+    "عرضية",
+    "عرقلة",
+    "عريض",
+    "عريضة",
+    "عزه",
+    "عسر",
+    "عسكرية",
+    "عسى",
+    "عسيراً",
+    "عشرات",
+    "عشرة",
+    "عشري",
+    "عشوائي",
+    "عصر",
+    "عصراً",
+    "عظم",
+    "عظيم",
+    "عظيماً",
+    "عظيمة",
+    "عقد",
+    "عقل",
+    "عقلك",
+    "عقلي",
+    "عكس",
+    "عل",
+    "علامة",
+    "علامتي",
+    "علة",
+    "علته",
+    "علتها",
+    "علل",
+    "علم",
+    "علماً",
+    "علمه",
+    "علمها",
+    "علمية",
+    "علميه",
+    "علو",
+    "على",
+    "عليك",
+    "علينا",
+    "عليه",
+    "عليها",
+    "عليهم",
+    "عما",
+    "عمق",
+    "عمقاً",
+    "عمل",
+    "عمله",
+    "عملي",
+    "عمليات",
+    "عملياً",
+    "عملية",
+    "عمن",
+    "عميق",
+    "عن",
+    "عنا",
+    "عناوين",
+    "عند",
+    "عندما",
+    "عندنا",
+    "عنده",
+    "عندها",
+    "عنصر",
+    "عنصراً",
+    "عنصره",
+    "عنصرها",
+    "عنه",
+    "عنها",
+    "عنهما",
+    "عنوان",
+    "عورات",
+    "عين",
+    "عينه",
+    "عينها",
+    "عُثر",
+    "عُينت",
+    "غالباً",
+    "غرض",
+    "غروب",
+    "غلواته",
+    "غير",
+    "غيرنا",
+    "غيره",
+    "غيرها",
+    "غيرهما",
+    "غيرية",
+    "فأصغر",
+    "فأصغرهما",
+    "فأقول",
+    "فأما",
+    "فأنية",
+    "فأول",
+    "فإذ",
+    "فإذا",
+    "فإذاً",
+    "فإذن",
+    "فإلى",
+    "فإما",
+    "فإن",
+    "فإنا",
+    "فإنك",
+    "فإنما",
+    "فإنه",
+    "فإنها",
+    "فإنهم",
+    "فإنهما",
+    "فائدة",
+    "فاجتمع",
+    "فاحفظ",
+    "فارغة",
+    "فارق",
+    "فارقت",
+    "فاسد",
+    "فاسداً",
+    "فاصلتان",
+    "فاضلاً",
+    "فاعل",
+    "فاعلة",
+    "فالأثر",
+    "فالأزلي",
+    "فالاثنان",
+    "فالانتقال",
+    "فالجرم",
+    "فالجزء",
+    "فالجنس",
+    "فالحال",
+    "فالحد",
+    "فالحركة",
+    "فالحق",
+    "فالحياة",
+    "فالذي",
+    "فالزمان",
+    "فالشيء",
+    "فالطبيعي",
+    "فالعارض",
+    "فالعرض",
+    "فالعقل",
+    "فالفاسد",
+    "فالفحص",
+    "فالفكر",
+    "فالكثرة",
+    "فالكل",
+    "فالمحسوس",
+    "فالمرسل",
+    "فالمستدير",
+    "فالمفضول",
+    "فالموجود",
+    "فالنوع",
+    "فالهوية",
+    "فالواحد",
+    "فالواقع",
+    "فالوحدة",
+    "فانقسامه",
+    "فباضطرار",
+    "فباقي",
+    "فبالاتحاد",
+    "فبالوحدة",
+    "فبحق",
+    "فبعد",
+    "فبعضه",
+    "فبقي",
+    "فبما",
+    "فبين",
+    "فتحها",
+    "فتكون",
+    "فجرم",
+    "فجزء",
+    "فجميع",
+    "فحركة",
+    "فحص",
+    "فحقيقته",
+    "فحوصات",
+    "فخاصية",
+    "فذاته",
+    "فذلك",
+    "فذو",
+    "فراغ",
+    "فرد",
+    "فرداً",
+    "فردي",
+    "فردياً",
+    "فرسخ",
+    "فرصة",
+    "فرض",
+    "فرضنا",
+    "فرع",
+    "فرعية",
+    "فرق",
+    "فساد",
+    "فساده",
+    "فستتم",
+    "فستظل",
+    "فستُستخدم",
+    "فسد",
+    "فسمي",
+    "فسيتم",
+    "فسيحاول",
+    "فسيحصل",
+    "فسيقوم",
+    "فسيُستخدم",
+    "فسيُعرض",
+    "فشل",
+    "فصار",
+    "فصاعداً",
+    "فصل",
+    "فصلاً",
+    "فصله",
+    "فصلها",
+    "فضائحهم",
+    "فضائية",
+    "فضل",
+    "فضلاً",
+    "فطنتهم",
+    "فعرض",
+    "فعل",
+    "فعلة",
+    "فعله",
+    "فعلياً",
+    "فعلية",
+    "فغنها",
+    "فغير",
+    "ففيه",
+    "ففيها",
+    "فقابل",
+    "فقال",
+    "فقد",
+    "فقدان",
+    "فقسمة",
+    "فقط",
+    "فك",
+    "فكالذي",
+    "فكان",
+    "فكبدن",
+    "فكذلك",
+    "فكر",
+    "فكرة",
+    "فكرنا",
+    "فكرهم",
+    "فكل",
+    "فكله",
+    "فكلها",
+    "فكليات",
+    "فكنوع",
+    "فكيف",
+    "فلآحاد",
+    "فلأنه",
+    "فلا",
+    "فلذلك",
+    "فلم",
+    "فلن",
+    "فلنبين",
+    "فلنتل",
+    "فلنقل",
+    "فلنكمل",
+    "فله",
+    "فليس",
+    "فليست",
+    "فما",
+    "فمتكثر",
+    "فمتناه",
+    "فمثلاً",
+    "فمحمول",
+    "فمدة",
+    "فمع",
+    "فمعرضه",
+    "فممتنع",
+    "فمن",
+    "فمنقسم",
+    "فنحن",
+    "فنظن",
+    "فنقول",
+    "فهذا",
+    "فهذه",
+    "فهما",
+    "فهو",
+    "فهي",
+    "فواجب",
+    "فواصل",
+    "فوجب",
+    "فور",
+    "فورا",
+    "فوق",
+    "فوقه",
+    "فوقهما",
+    "فوقية",
+    "في",
+    "فيؤديها",
+    "فيبقى",
+    "فيجب",
+    "فيدخل",
+    "فير",
+    "فيسمى",
+    "فيض",
+    "فيعرض",
+    "فيقال",
+    "فيقالان",
+    "فيقبل",
+    "فيقع",
+    "فيكون",
+    "فيلحق",
+    "فيلحقها",
+    "فيلزم",
+    "فيلزمها",
+    "فيم",
+    "فيما",
+    "فيمكن",
+    "فينبغي",
+    "فيه",
+    "فيها",
+    "فيوجد",
+    "قائلون",
+    "قائم",
+    "قائمة",
+    "قابل",
+    "قابلاً",
+    "قابلة",
+    "قادرة",
+    "قاصداً",
+    "قاطبة",
+    "قال",
+    "قالوا",
+    "قام",
+    "قاموس",
+    "قاموساً",
+    "قاموسها",
+    "قبل",
+    "قبلنا",
+    "قبله",
+    "قبلها",
+    "قبول",
+    "قبولاً",
+    "قبيحة",
+    "قد",
+    "قدر",
+    "قدم",
+    "قدمنا",
+    "قديم",
+    "قديمة",
+    "قراءة",
+    "قريب",
+    "قسم",
+    "قسمة",
+    "قسمين",
+    "قسميه",
+    "قسي",
+    "قصر",
+    "قصروا",
+    "قفز",
+    "قلنا",
+    "قليل",
+    "قليلاً",
+    "قليلة",
+    "قم",
+    "قنية",
+    "قوائم",
+    "قواعد",
+    "قوام",
+    "قوامه",
+    "قواميس",
+    "قوة",
+    "قوته",
+    "قوسي",
+    "قوسية",
+    "قول",
+    "قولاً",
+    "قولنا",
+    "قوى",
+    "قيام",
+    "قيل",
+    "قيم",
+    "قيمة",
+    "قيمته",
+    "قيوداً",
+    "ك",
+    "كآخر",
+    "كأن",
+    "كأنما",
+    "كأي",
+    "كأين",
+    "كائن",
+    "كائناً",
+    "كاذب",
+    "كاسم",
+    "كافة",
+    "كالأشياء",
+    "كالإنسان",
+    "كالإنسانية",
+    "كالباب",
+    "كالبياض",
+    "كالبيت",
+    "كالحرارة",
+    "كالحروف",
+    "كالحمرة",
+    "كالحي",
+    "كالحياة",
+    "كالحيوان",
+    "كالذي",
+    "كالرائد",
+    "كالربو",
+    "كالسبع",
+    "كالشفرة",
+    "كالشكل",
+    "كالضحك",
+    "كالطبيات",
+    "كالطول",
+    "كالعظم",
+    "كالغضب",
+    "كالفساد",
+    "كالكتابة",
+    "كاللاحقة",
+    "كالماء",
+    "كالمخطوط",
+    "كالميل",
+    "كالناطق",
+    "كالواحد",
+    "كامل",
+    "كاملة",
+    "كان",
+    "كانا",
+    "كانت",
+    "كانوا",
+    "كانون",
+    "كبديل",
+    "كبر",
+    "كبير",
+    "كبيراً",
+    "كتاب",
+    "كتابة",
+    "كتابتها",
+    "كتابنا",
+    "كتابي",
+    "كتطابقات",
+    "كتل",
+    "كتلة",
+    "كثرة",
+    "كثير",
+    "كثيراً",
+    "كثيرة",
+    "كثيرين",
+    "كجميع",
+    "كحجارة",
+    "كحجر",
+    "كحد",
+    "كحروف",
+    "كخيار",
+    "كذا",
+    "كذلك",
+    "كراسيهم",
+    "كرقم",
+    "كسر",
+    "كشيء",
+    "كعلامة",
+    "كـ",
+    "كفاية",
+    "كفراً",
+    "كقائمة",
+    "كقاعدة",
+    "كقولنا",
+    "كقيمة",
+    "ككتلة",
+    "كل",
+    "كلا",
+    "كلاء",
+    "كلاهما",
+    "كلاً",
+    "كلب",
+    "كلباً",
+    "كلتا",
+    "كلما",
+    "كله",
+    "كلها",
+    "كليات",
+    "كلياتها",
+    "كلياً",
+    "كلية",
+    "كليكما",
+    "كليهما",
+    "كم",
+    "كما",
+    "كمال",
+    "كمحمد",
+    "كمرادفات",
+    "كمرشح",
+    "كمستعرض",
+    "كمعاملات",
+    "كملحق",
+    "كمية",
+    "كميته",
+    "كن",
+    "كنا",
+    "كنت",
+    "كنسبة",
+    "كنوع",
+    "كهذا",
+    "كوباً",
+    "كود",
+    "كون",
+    "كوناً",
+    "كونها",
+    "كونهم",
+    "كوني",
+    "كي",
+    "كيت",
+    "كيف",
+    "كيفما",
+    "كيفياته",
+    "كيفية",
+    "كيلوبايت",
+    "كُتب",
+    "كُتم",
+    "ل",
+    "لآخر",
+    "لأجزائه",
+    "لأحدهما",
+    "لأدوات",
+    "لأربعة",
+    "لأسبق",
+    "لأسماء",
+    "لأشياء",
+    "لأغراض",
+    "لألفا",
+    "لأن",
+    "لأنا",
+    "لأنشطة",
+    "لأنفي",
+    "لأنه",
+    "لأنها",
+    "لأنواع",
+    "لأنيته",
+    "لأي",
+    "لأية",
+    "لإجباره",
+    "لإذ",
+    "لإذاعتها",
+    "لإذن",
+    "لإصدارات",
+    "لإصلاح",
+    "لإعدادات",
+    "لإعطاء",
+    "لإنشاء",
+    "لئن",
+    "لا",
+    "لاتاحة",
+    "لاتصاله",
+    "لاحتمال",
+    "لاحظ",
+    "لاحقات",
+    "لاحقة",
+    "لازمة",
+    "لاستخدام",
+    "لاستدعاء",
+    "لاستهلاك",
+    "لاسيما",
+    "لاشتراكها",
+    "لاشتراكهما",
+    "لانقطع",
+    "لانهاية",
+    "لاه",
+    "لباحث",
+    "لبدء",
+    "لبداية",
+    "لبرنامج",
+    "لبريطانيا",
+    "لبعض",
+    "لبيتا",
+    "لتجاوز",
+    "لتحديد",
+    "لتحقيق",
+    "لتخزين",
+    "لتصديق",
+    "لتطابق",
+    "لتطبيق",
+    "لتعدد",
+    "لتعطيل",
+    "لتقليل",
+    "لتكون",
+    "لتلك",
+    "لتمرير",
+    "لتمكين",
+    "لتناسب",
+    "لتنسيق",
+    "لتنسيقات",
+    "لتوزيع",
+    "لتوفيرها",
+    "لتوقيع",
+    "لجعل",
+    "لجميع",
+    "لحق",
+    "لحقه",
+    "لحم",
+    "لدى",
+    "لديها",
+    "لذا",
+    "لذاته",
+    "لذاكرة",
+    "لذلك",
+    "لذي",
+    "لرؤية",
+    "لرطل",
+    "لزم",
+    "لزمت",
+    "لزمته",
+    "لزوال",
+    "لساننا",
+    "لست",
+    "لستم",
+    "لستما",
+    "لستن",
+    "لسجوف",
+    "لسطر",
+    "لسلاسل",
+    "لسن",
+    "لسنا",
+    "لشامل",
+    "لشيء",
+    "لصلابته",
+    "لصورته",
+    "لضبط",
+    "لضغط",
+    "لضمان",
+    "لضيق",
+    "لعادتهم",
+    "لعدة",
+    "لعدد",
+    "لعدم",
+    "لعرض",
+    "لعشق",
+    "لعقد",
+    "لعل",
+    "لعلة",
+    "لغات",
+    "لغلواته",
+    "لـ",
+    "لفصله",
+    "لفظ",
+    "لفظنا",
+    "لفك",
+    "لقصر",
+    "لك",
+    "لكتم",
+    "لكسر",
+    "لكفالة",
+    "لكفرهم",
+    "لكل",
+    "لكلامهن",
+    "لكم",
+    "لكما",
+    "لكن",
+    "لكنما",
+    "لكنها",
+    "لكي",
+    "لكيفية",
+    "لكيلا",
+    "للآتين",
+    "للآخر",
+    "للأ",
+    "للأشخاص",
+    "للأشياء",
+    "للأعلى",
+    "للأغراض",
+    "للأمام",
+    "للأمر",
+    "للأمم",
+    "للأنظمة",
+    "للأنواع",
+    "للإشارة",
+    "للإصدارات",
+    "للإضافة",
+    "للإعدادات",
+    "للاستخدام",
+    "للاستكشاف",
+    "للالتزام",
+    "للاهتمام",
+    "للبحث",
+    "للبرهان",
+    "للبشرية",
+    "للبيانات",
+    "للتجزئة",
+    "للتحقق",
+    "للتحكم",
+    "للتحليل",
+    "للتدفقات",
+    "للترؤس",
+    "للترويسة",
+    "للتطابق",
+    "للتعاون",
+    "للتعرف",
+    "للتفاصيل",
+    "للتفضيل",
+    "للتقصير",
+    "للتنفيذ",
+    "للتوافق",
+    "للتي",
+    "للثلاثة",
+    "للجبل",
+    "للجرم",
+    "للجزء",
+    "للجسم",
+    "للجنس",
+    "للجوهر",
+    "للحالات",
+    "للحد",
+    "للحديث",
+    "للحروف",
+    "للحس",
+    "للحصول",
+    "للحفاظ",
+    "للحفظ",
+    "للخطر",
+    "للخلاء",
+    "للخلف",
+    "للخيار",
+    "للدلتا",
+    "للدول",
+    "للذاكرة",
+    "للزمان",
+    "للسلم",
+    "للسماح",
+    "للشخص",
+    "للشيء",
+    "للصور",
+    "للضاغط",
+    "للضعف",
+    "للضغط",
+    "للعدد",
+    "للعرض",
+    "للعظيم",
+    "للعكس",
+    "للعلل",
+    "للعمليات",
+    "للعنصر",
+    "للغاية",
+    "للغوات",
+    "للفرسخ",
+    "للفضاء",
+    "للفضائل",
+    "للفقرة",
+    "للقانون",
+    "للقراءة",
+    "للقرص",
+    "للقمر",
+    "للقوة",
+    "للكائن",
+    "للكتلة",
+    "للكثرة",
+    "للكثير",
+    "للكمية",
+    "للمادة",
+    "للماضي",
+    "للمباد",
+    "للمترادفة",
+    "للمخرج",
+    "للمدة",
+    "للمرء",
+    "للمراقبة",
+    "للمركبة",
+    "للمستخدمين",
+    "للمستقر",
+    "للمستويات",
+    "للمصالح",
+    "للمعالجة",
+    "للملف",
+    "للملفات",
+    "للنظام",
+    "للنفس",
+    "لله",
+    "للهناة",
+    "للواحد",
+    "للواقع",
+    "للوحدة",
+    "للوصول",
+    "لم",
+    "لما",
+    "لمثل",
+    "لمحاذاة",
+    "لمحمد",
+    "لمختلف",
+    "لمخرج",
+    "لمراقبة",
+    "لمرشح",
+    "لمرشحات",
+    "لمزيد",
+    "لمشفر",
+    "لمعرفة",
+    "لمعنى",
+    "لمفكك",
+    "لمقاصد",
+    "لمقاطعة",
+    "لملف",
+    "لملفات",
+    "لملفين",
+    "لممثلي",
+    "لمنع",
+    "لمواءمة",
+    "لموازاة",
+    "لموضع",
+    "لن",
+    "لنا",
+    "لندن",
+    "لنسخة",
+    "لنصوص",
+    "لنفس",
+    "لنهايات",
+    "لنهاياته",
+    "لنوع",
+    "له",
+    "لها",
+    "لهذا",
+    "لهذه",
+    "لهم",
+    "لهما",
+    "لهن",
+    "لهويته",
+    "لو",
+    "لواحد",
+    "لواحق",
+    "لوثائق",
+    "لوجدانه",
+    "لوجودها",
+    "لوحة",
+    "لوسائط",
+    "لوضع",
+    "لوضعها",
+    "لوعود",
+    "لولا",
+    "لوما",
+    "لون",
+    "لي",
+    "ليت",
+    "ليتم",
+    "ليجده",
+    "ليس",
+    "ليسا",
+    "ليساً",
+    "ليست",
+    "ليستا",
+    "ليسن",
+    "ليسوا",
+    "ليظهر",
+    "ليعمل",
+    "ليُضغط",
+    "م",
+    "مؤتلفة",
+    "مؤثر",
+    "مؤخراً",
+    "مؤدية",
+    "مؤشر",
+    "مؤقتاً",
+    "مؤلف",
+    "مؤلفاً",
+    "مؤلماً",
+    "مؤونة",
+    "مئات",
+    "مئوية",
+    "ما",
+    "ماء",
+    "مائية",
+    "مات",
+    "ماذا",
+    "ماضيه",
+    "مال",
+    "مباشرة",
+    "مباينة",
+    "مبدأ",
+    "مبدئي",
+    "مبدئياً",
+    "مبدئية",
+    "مبدع",
+    "مبرز",
+    "مبسط",
+    "مبعوثي",
+    "مبين",
+    "مبينة",
+    "متأصلاً",
+    "متاح",
+    "متاحاً",
+    "متاحة",
+    "متباينان",
+    "متباينة",
+    "متبدل",
+    "متبقية",
+    "متبوعاً",
+    "متتالية",
+    "متتاليتان",
+    "متجانسة",
+    "متجانسين",
+    "متجزء",
+    "متجزئة",
+    "متجزىء",
+    "متحد",
+    "متحدة",
+    "متحرك",
+    "متحركاً",
+    "متحركة",
+    "مترادفان",
+    "مترادفة",
+    "متساوية",
+    "متشابهاتهما",
+    "متشابهة",
+    "متصل",
+    "متصلاً",
+    "متصلة",
+    "متطابقان",
+    "متطابقاً",
+    "متطابقة",
+    "متطلبات",
+    "متعدد",
+    "متعددة",
+    "متغير",
+    "متغيرات",
+    "متفرقاً",
+    "متفقة",
+    "متكثر",
+    "متكثرة",
+    "متكررة",
+    "متماثلة",
+    "متماثلي",
+    "متمثل",
+    "متمكن",
+    "متممة",
+    "متناه",
+    "متناهي",
+    "متناهيان",
+    "متناهياً",
+    "متناهية",
+    "متناهيي",
+    "متنقل",
+    "متهو",
+    "متواطئاً",
+    "متوافقاً",
+    "متوافقة",
+    "متوحد",
+    "متوحدة",
+    "متوسط",
+    "متوفرة",
+    "متى",
+    "متيقن",
+    "مثال",
+    "مثالي",
+    "مثل",
+    "مثلاً",
+    "مثله",
+    "مثلها",
+    "مجانسه",
+    "مجتمعاً",
+    "مجتمعة",
+    "مجرد",
+    "مجرى",
+    "مجموع",
+    "مجموعات",
+    "مجموعة",
+    "محاذاة",
+    "محاذى",
+    "محارف",
+    "محال",
+    "محاولة",
+    "محتمل",
+    "محتملة",
+    "محتويات",
+    "محدثة",
+    "محدد",
+    "محدداً",
+    "محددة",
+    "محدود",
+    "محدودان",
+    "محدوداً",
+    "محدودة",
+    "محسوس",
+    "محسوسة",
+    "محض",
+    "محفوظات",
+    "محقق",
+    "محمالة",
+    "محمد",
+    "محمول",
+    "محمولها",
+    "محنة",
+    "محيط",
+    "مختلف",
+    "مختلفات",
+    "مختلفة",
+    "مختلفتين",
+    "مختلفين",
+    "مخرج",
+    "مخرجات",
+    "مخرجه",
+    "مخرجها",
+    "مخزن",
+    "مخزناً",
+    "مخزنين",
+    "مخصص",
+    "مخصصان",
+    "مخصصة",
+    "مخلفات",
+    "مدار",
+    "مدة",
+    "مدته",
+    "مدخلات",
+    "مددنا",
+    "مدرك",
+    "مدعوم",
+    "مدعومان",
+    "مدعومة",
+    "مدمج",
+    "مدمجاً",
+    "مدن",
+    "مذ",
+    "مر",
+    "مرات",
+    "مراعاة",
+    "مرافق",
+    "مرة",
+    "مرتبة",
+    "مرتفع",
+    "مرتين",
+    "مرسل",
+    "مرسلاً",
+    "مرشح",
+    "مرشحات",
+    "مركب",
+    "مركباً",
+    "مركبة",
+    "مركبتهم",
+    "مركز",
+    "مركزه",
+    "مرن",
+    "مرناً",
+    "مرور",
+    "مزامنة",
+    "مزعجاً",
+    "مزمعا",
+    "مزيج",
+    "مسؤولية",
+    "مسائل",
+    "مساحة",
+    "مساعدة",
+    "مسافة",
+    "مسافته",
+    "مسامتها",
+    "مساو",
+    "مساوياً",
+    "مساوية",
+    "مسبق",
+    "مسبقاً",
+    "مسبقة",
+    "مسبقًا",
+    "مستحيل",
+    "مستحيلاً",
+    "مستخدمة",
+    "مستخدمو",
+    "مستعار",
+    "مستعارة",
+    "مستعدا",
+    "مستعداً",
+    "مستفاد",
+    "مستفادة",
+    "مستقبلاً",
+    "مستقبلي",
+    "مستقرة",
+    "مستقل",
+    "مستقيمة",
+    "مستوى",
+    "مستويات",
+    "مستويين",
+    "مسموح",
+    "مسهباً",
+    "مشابهة",
+    "مشاركة",
+    "مشاكل",
+    "مشاكلة",
+    "مشتبه",
+    "مشترك",
+    "مشتركاً",
+    "مشتركة",
+    "مشكلة",
+    "مصدرية",
+    "مصدق",
+    "مصدقة",
+    "مصورة",
+    "مضادة",
+    "مضاعف",
+    "مضاعفاً",
+    "مضبوط",
+    "مضبوطة",
+    "مضغوط",
+    "مضغوطة",
+    "مضللة",
+    "مضمحل",
+    "مطابق",
+    "مطابقات",
+    "مطابقاً",
+    "مطابقة",
+    "مطالبنا",
+    "مطلق",
+    "مطلقاً",
+    "مطلقة",
+    "مطلوب",
+    "مطلوباتنا",
+    "مطلوبة",
+    "مطلوبه",
+    "مع",
+    "معالجة",
+    "معالجتها",
+    "معامل",
+    "معاهدة",
+    "معاً",
+    "معد",
+    "معدات",
+    "معداً",
+    "معدة",
+    "معدلات",
+    "معدودات",
+    "معدودة",
+    "معرف",
+    "معرفة",
+    "معرفتها",
+    "معرفتهم",
+    "معروف",
+    "معروفاً",
+    "معروفة",
+    "معرّف",
+    "معطل",
+    "معطلة",
+    "معظم",
+    "معقول",
+    "معقولاً",
+    "معقولة",
+    "معلمة",
+    "معلول",
+    "معلولة",
+    "معلوم",
+    "معلومات",
+    "معلومة",
+    "معن",
+    "معنى",
+    "معه",
+    "معها",
+    "معهما",
+    "معينة",
+    "مفارقة",
+    "مفترقاً",
+    "مفرداً",
+    "مفرقاً",
+    "مفروض",
+    "مفصول",
+    "مفصولة",
+    "مفعوله",
+    "مفكات",
+    "مفكك",
+    "مفكوك",
+    "مفيد",
+    "مفيداً",
+    "مفيدة",
+    "مقابل",
+    "مقارنة",
+    "مقاصد",
+    "مقاطعة",
+    "مقبلاً",
+    "مقبولاً",
+    "مقدار",
+    "مقسوم",
+    "مقصوداتنا",
+    "مقول",
+    "مقولان",
+    "مقولة",
+    "مقولنا",
+    "مقوم",
+    "مقيدة",
+    "مكافئاً",
+    "مكان",
+    "مكاناً",
+    "مكتبة",
+    "مكيال",
+    "ملاء",
+    "ملابس",
+    "ملاحظات",
+    "ملحق",
+    "ملحقاً",
+    "ملف",
+    "ملفات",
+    "ملفان",
+    "ملفاً",
+    "ملفوظ",
+    "ملكية",
+    "ملموس",
+    "مم",
+    "مما",
+    "مماثلة",
+    "ممتنع",
+    "ممتنعاً",
+    "ممكن",
+    "ممكناً",
+    "ممكنة",
+    "مملوءة",
+    "ممن",
+    "مميزات",
+    "من",
+    "منا",
+    "مناسب",
+    "مناسبة",
+    "مناطق",
+    "منافعنا",
+    "مناه",
+    "مناورات",
+    "منبىء",
+    "منخفضاً",
+    "منخفضة",
+    "منذ",
+    "منزلة",
+    "منشآ",
+    "منشآت",
+    "منطو",
+    "منفصل",
+    "منفصلة",
+    "منقسم",
+    "منقسماً",
+    "منقسمة",
+    "منه",
+    "منها",
+    "منهم",
+    "منهما",
+    "مه",
+    "مهجور",
+    "مهجورة",
+    "مهم",
+    "مهما",
+    "مهوية",
+    "مواد",
+    "مواضع",
+    "مواطنيها",
+    "موجب",
+    "موجز",
+    "موجود",
+    "موجودان",
+    "موجوداً",
+    "موجودة",
+    "موسوماً",
+    "موصوف",
+    "موضع",
+    "موضوع",
+    "موضوعاتنا",
+    "موضوعاته",
+    "موضوعة",
+    "ميت",
+    "ميثاق",
+    "ميجابايت",
+    "ميجابايتات",
+    "ميدان",
+    "ميدانا",
+    "ميزات",
+    "ميزة",
+    "ميل",
+    "ميلي",
+    "نادراً",
+    "ناطق",
+    "نافذة",
+    "نافع",
+    "ناقصاً",
+    "نال",
+    "نبين",
+    "نتائج",
+    "نجح",
+    "نجد",
+    "نحتاج",
+    "نحلهم",
+    "نحن",
+    "نحو",
+    "نذم",
+    "نذهب",
+    "نذهبن",
+    "نرجع",
+    "نسأل",
+    "نسبة",
+    "نسبت",
+    "نسبتها",
+    "نستحي",
+    "نسخ",
+    "نسخة",
+    "نسخها",
+    "نسعى",
+    "نسيان",
+    "نشاطا",
+    "نشاطات",
+    "نشكر",
+    "نشوئنا",
+    "نص",
+    "نصبوها",
+    "نصرة",
+    "نصف",
+    "نصي",
+    "نصية",
+    "نطاق",
+    "نطاقات",
+    "نطلب",
+    "نظام",
+    "نظامك",
+    "نظرائها",
+    "نظراً",
+    "نظره",
+    "نظري",
+    "نظن",
+    "نعد",
+    "نعلم",
+    "نعم",
+    "نعمته",
+    "نفاذ",
+    "نفاذها",
+    "نفس",
+    "نفسه",
+    "نقدم",
+    "نقر",
+    "نقص",
+    "نقصد",
+    "نقطتان",
+    "نقطتين",
+    "نقلة",
+    "نقول",
+    "نلزم",
+    "نمائها",
+    "نمسك",
+    "نمط",
+    "نهاة",
+    "نهايات",
+    "نهاياتها",
+    "نهاياتهما",
+    "نهاية",
+    "نهايتان",
+    "نهايته",
+    "نواة",
+    "نور",
+    "نوضح",
+    "نوع",
+    "نوعاً",
+    "نوعنا",
+    "نوعه",
+    "نوعها",
+    "نوعي",
+    "نوعين",
+    "نوفمبر",
+    "نووية",
+    "نيتنا",
+    "نيته",
+    "نيل",
+    "نيلها",
+    "ه",
+    "هؤلاء",
+    "ها",
+    "هاتان",
+    "هاته",
+    "هاتي",
+    "هاتين",
+    "هاش",
+    "هاك",
+    "هاهنا",
+    "هبوط",
+    "هدة",
+    "هدر",
+    "هذا",
+    "هذان",
+    "هذه",
+    "هذي",
+    "هذين",
+    "هكذا",
+    "هل",
+    "هلا",
+    "هم",
+    "هما",
+    "هن",
+    "هنا",
+    "هناة",
+    "هناك",
+    "هنالك",
+    "هو",
+    "هويا",
+    "هوية",
+    "هويته",
+    "هي",
+    "هيا",
+    "هيت",
+    "هيهات",
+    "هيولاني",
+    "هيولانياً",
+    "هيولى",
+    "و",
+    "وآثر",
+    "وآحاد",
+    "وآلات",
+    "وآله",
+    "وأبعاد",
+    "وأبعد",
+    "وأجزاء",
+    "وأجناسها",
+    "وأحاط",
+    "وأحرى",
+    "وأحياناً",
+    "وأخيراً",
+    "وأدوات",
+    "وأسهلها",
+    "وأشخاص",
+    "وأشدها",
+    "وأشرف",
+    "وأشرفها",
+    "وأصغر",
+    "وأطراف",
+    "وأطرافا",
+    "وأعلاها",
+    "وأعمدة",
+    "وأعني",
+    "وأفضل",
+    "وأقدم",
+    "وأقصر",
+    "وأقل",
+    "وأم",
+    "وأما",
+    "وأمام",
+    "وأن",
+    "وأنه",
+    "وأنواع",
+    "وأوتار",
+    "وأوردة",
+    "وأول",
+    "وأي",
+    "وأية",
+    "وأيسه",
+    "وأيضاً",
+    "وأيهما",
+    "وإحاطة",
+    "وإذ",
+    "وإذا",
+    "وإذن",
+    "وإرسال",
+    "وإزالة",
+    "وإعطاء",
+    "وإلا",
+    "وإلطاف",
+    "وإما",
+    "وإن",
+    "وإنما",
+    "وإيثار",
+    "وإيثارها",
+    "وإيضاح",
+    "وا",
+    "واتصاله",
+    "واثباتا",
+    "واجب",
+    "واجراء",
+    "واجهات",
+    "واجهت",
+    "واحد",
+    "واحداً",
+    "واحدة",
+    "واحدين",
+    "واخرج",
+    "واستبدالها",
+    "واستخدام",
+    "واستخدامه",
+    "واستكشاف",
+    "واستهلاك",
+    "واستيفاء",
+    "واسع",
+    "واسم",
+    "واسماً",
+    "واسمه",
+    "واشتد",
+    "واضح",
+    "واقتناء",
+    "واقتناعا",
+    "واقع",
+    "واقعة",
+    "والآخر",
+    "والآني",
+    "والأثر",
+    "والأجرام",
+    "والأجزاء",
+    "والأحجام",
+    "والأذونات",
+    "والأزلي",
+    "والأزمنة",
+    "والأشبه",
+    "والأشخاص",
+    "والأشد",
+    "والأشياء",
+    "والأضداد",
+    "والأضعف",
+    "والأقصى",
+    "والأقل",
+    "والأمم",
+    "والأمن",
+    "والأنواع",
+    "والإخراج",
+    "والإشراف",
+    "والإصدارات",
+    "والإضافة",
+    "والإعدادات",
+    "والائتلاف",
+    "والابتداء",
+    "والاتصال",
+    "والاتفاق",
+    "والاثنان",
+    "والاثنين",
+    "والاجتهاد",
+    "والاحتراس",
+    "والاختلاف",
+    "والاستثناء",
+    "والاستحالة",
+    "والاضمحلال",
+    "والافتراق",
+    "والانتهاء",
+    "والانكليزية",
+    "والبحث",
+    "والبرهان",
+    "والبعد",
+    "والبعض",
+    "والبقية",
+    "والتأليف",
+    "والتأييد",
+    "والتام",
+    "والتباين",
+    "والتبدل",
+    "والتجارة",
+    "والتحويل",
+    "والتدفقات",
+    "والتركيب",
+    "والتساعد",
+    "والتساوي",
+    "والتفاهم",
+    "والتكثير",
+    "والتمسك",
+    "والتي",
+    "والثنان",
+    "والجديدة",
+    "والجرم",
+    "والجزء",
+    "والجميع",
+    "والجنس",
+    "والجواد",
+    "والجوهر",
+    "والجوهري",
+    "والحاجب",
+    "والحالات",
+    "والحجم",
+    "والحرف",
+    "والحركة",
+    "والحزن",
+    "والحشو",
+    "والحمد",
+    "والحمرة",
+    "والخاصة",
+    "والخروج",
+    "والخط",
+    "والخطيب",
+    "والخلف",
+    "والخيارات",
+    "والذاتي",
+    "والذي",
+    "والذين",
+    "والربو",
+    "والروسية",
+    "والزمان",
+    "والساكن",
+    "والسبيل",
+    "والسرير",
+    "والسطح",
+    "والسكربتات",
+    "والسكون",
+    "والسكين",
+    "والشخص",
+    "والشعوب",
+    "والشفرة",
+    "والشكل",
+    "والشيء",
+    "والصغير",
+    "والصورة",
+    "والصينية",
+    "والضعف",
+    "والضمر",
+    "والطبيعة",
+    "والطعم",
+    "والطويل",
+    "والظفر",
+    "والعائدين",
+    "والعارض",
+    "والعالم",
+    "والعدد",
+    "والعرض",
+    "والعشرين",
+    "والعظم",
+    "والعظيم",
+    "والعقل",
+    "والعلة",
+    "والعمق",
+    "والعين",
+    "والغيرية",
+    "والفرح",
+    "والفرد",
+    "والفردية",
+    "والفرس",
+    "والفرسية",
+    "والفرق",
+    "والفرنسية",
+    "والفساد",
+    "والفصل",
+    "والفلسفة",
+    "والقصر",
+    "والقصير",
+    "والقطن",
+    "والقليل",
+    "والقوة",
+    "والقول",
+    "والقيام",
+    "والقيم",
+    "والكائن",
+    "والكاتب",
+    "والكافرين",
+    "والكثرة",
+    "والكثير",
+    "والكرى",
+    "والكل",
+    "والكليات",
+    "والكوكب",
+    "والكون",
+    "واللاحقة",
+    "واللفظ",
+    "واللون",
+    "والم",
+    "والمؤثر",
+    "والمؤلفة",
+    "والماء",
+    "والمباشرة",
+    "والمبرئات",
+    "والمتساويان",
+    "والمتساوية",
+    "والمتصل",
+    "والمتمكن",
+    "والمجموعة",
+    "والمحسوس",
+    "والمحفوظة",
+    "والمخبرة",
+    "والمخرجات",
+    "والمدية",
+    "والمراعاة",
+    "والمراقبة",
+    "والمركب",
+    "والمركبات",
+    "والمستثنى",
+    "والمشتبه",
+    "والمضادة",
+    "والمضغوطة",
+    "والمطالب",
+    "والمطلقة",
+    "والمعرفة",
+    "والمعلول",
+    "والمفكر",
+    "والمقيد",
+    "والمكان",
+    "والمكتبات",
+    "والملاء",
+    "والملاحظات",
+    "والملفوظ",
+    "والمملكة",
+    "والمنشآت",
+    "والمنقسم",
+    "والناتج",
+    "والناقص",
+    "والنزع",
+    "والنصف",
+    "والنفس",
+    "والنقص",
+    "والنقصية",
+    "والنهيق",
+    "والنوع",
+    "والهاتكة",
+    "والواحد",
+    "والواقع",
+    "والوحدة",
+    "والوسط",
+    "والوقت",
+    "والولايات",
+    "وانتقاصه",
+    "وايرلندا",
+    "وبأنه",
+    "وباحث",
+    "وباقي",
+    "وبالتالي",
+    "وبالتفصيل",
+    "وبالجرم",
+    "وبالحركة",
+    "وبالمثل",
+    "وبحق",
+    "وبخلاف",
+    "وبدون",
+    "وبشاعة",
+    "وبعد",
+    "وبعدمها",
+    "وبعضه",
+    "وبعضها",
+    "وبعضهم",
+    "وبقدر",
+    "وبلزوم",
+    "وبلغم",
+    "وبهذا",
+    "وبيان",
+    "وبين",
+    "وتأليف",
+    "وتأييد",
+    "وتاريخ",
+    "وتبدل",
+    "وتبدله",
+    "وتتميم",
+    "وتتولى",
+    "وتثنية",
+    "وتجاهل",
+    "وتجري",
+    "وتجريب",
+    "وتحديد",
+    "وتختلف",
+    "وتر",
+    "وتراعي",
+    "وترتيبها",
+    "وترد",
+    "وترك",
+    "وتركيبه",
+    "وتري",
+    "وترية",
+    "وتزويدهم",
+    "وتستهلك",
+    "وتسعمائة",
+    "وتسمح",
+    "وتشجيع",
+    "وتصبح",
+    "وتضعيف",
+    "وتطبعها",
+    "وتعالى",
+    "وتعدد",
+    "وتعذر",
+    "وتعزيز",
+    "وتعيين",
+    "وتغاير",
+    "وتفاضل",
+    "وتقوم",
+    "وتكون",
+    "وتلتزم",
+    "وتلزمه",
+    "وتنفذ",
+    "وتوجد",
+    "وتوحدها",
+    "وتودع",
+    "وتيسير",
+    "وتُتجاهل",
+    "وتُحسب",
+    "وثائق",
+    "وثباته",
+    "وثباتها",
+    "وثيقة",
+    "وجب",
+    "وجد",
+    "وجدان",
+    "وجدت",
+    "وجدناه",
+    "وجرم",
+    "وجزء",
+    "وجزؤها",
+    "وجزئية",
+    "وجعلها",
+    "وجلد",
+    "وجملة",
+    "وجميع",
+    "وجه",
+    "وجوب",
+    "وجود",
+    "وجودان",
+    "وجوداً",
+    "وجودنا",
+    "وجوده",
+    "وجودها",
+    "وجيزة",
+    "وحجب",
+    "وحجم",
+    "وحد",
+    "وحدانيات",
+    "وحدانيته",
+    "وحدة",
+    "وحدته",
+    "وحده",
+    "وحدود",
+    "وحدودها",
+    "وحركة",
+    "وحشو",
+    "وحقول",
+    "وخفي",
+    "وخلف",
+    "وخيارات",
+    "ودار",
+    "ودبر",
+    "ودبرت",
+    "ودليله",
+    "ودم",
+    "وديعة",
+    "وذاته",
+    "وذب",
+    "وذلك",
+    "وذو",
+    "وذوي",
+    "ورئة",
+    "وربما",
+    "ورسم",
+    "ورود",
+    "وزينك",
+    "وسبعة",
+    "وستين",
+    "وستُحذف",
+    "وسط",
+    "وسعيد",
+    "وسفل",
+    "وسكون",
+    "وسلاسل",
+    "وسم",
+    "وسماها",
+    "وسمي",
+    "وسنة",
+    "وسهلوا",
+    "وسيط",
+    "وسيقوم",
+    "وسيلانه",
+    "وسيلة",
+    "وسيما",
+    "وسيُحذف",
+    "وسيُدعم",
+    "وشجبت",
+    "وشركاء",
+    "وشمال",
+    "وشهاباً",
+    "وشيئان",
+    "وصار",
+    "وصانك",
+    "وصغير",
+    "وصف",
+    "وصفاق",
+    "وصفه",
+    "وصلاً",
+    "وصلة",
+    "وصلواته",
+    "وصورة",
+    "وصورتها",
+    "وصول",
+    "وضاغط",
+    "وضح",
+    "وضع",
+    "وضعت",
+    "وضعي",
+    "وضغطها",
+    "وضوحاً",
+    "وضوحه",
+    "وطهرك",
+    "وعبر",
+    "وعدد",
+    "وعدم",
+    "وعدمه",
+    "وعرض",
+    "وعروق",
+    "وعرى",
+    "وعسر",
+    "وعصب",
+    "وعظم",
+    "وعلة",
+    "وعلتها",
+    "وعلم",
+    "وعلى",
+    "وعن",
+    "وعند",
+    "وعنصر",
+    "وغالباً",
+    "وغلى",
+    "وغير",
+    "وفارق",
+    "وفساده",
+    "وفصل",
+    "وفصله",
+    "وفقا",
+    "وفقاً",
+    "وفقط",
+    "وفك",
+    "وفي",
+    "وفيما",
+    "وقابل",
+    "وقبل",
+    "وقت",
+    "وقتاً",
+    "وقد",
+    "وقلة",
+    "وقوته",
+    "وقول",
+    "وقيم",
+    "وقيمته",
+    "وكالفرس",
+    "وكان",
+    "وكانت",
+    "وكانوا",
+    "وكثرة",
+    "وكثيراً",
+    "وكدر",
+    "وكذلك",
+    "وكل",
+    "وكلما",
+    "وكيف",
+    "ول",
+    "ولأن",
+    "ولأنه",
+    "ولأنها",
+    "ولا",
+    "ولتفادي",
+    "ولجزئه",
+    "ولحم",
+    "ولدرانة",
+    "ولذا",
+    "ولذلك",
+    "ولزوم",
+    "ولسنا",
+    "ولطف",
+    "ولغيره",
+    "ولكل",
+    "ولكن",
+    "ولكنك",
+    "ولكنه",
+    "ولكنها",
+    "وللأجرام",
+    "وللأشجار",
+    "وللبعد",
+    "وللجنس",
+    "ولم",
+    "ولنتله",
+    "ولنقدم",
+    "ولنكمل",
+    "ولنوضح",
+    "ولهذه",
+    "ولو",
+    "ولوج",
+    "ولي",
+    "وليس",
+    "وليست",
+    "وما",
+    "ومبادئه",
+    "ومباشر",
+    "ومتصور",
+    "ومتوحدة",
+    "ومتى",
+    "ومثل",
+    "ومجتمع",
+    "ومجتمعة",
+    "ومحمول",
+    "ومخ",
+    "ومدخلات",
+    "ومدعوم",
+    "ومرة",
+    "ومرشحات",
+    "ومركب",
+    "ومركزه",
+    "ومصالح",
+    "ومع",
+    "ومفصول",
+    "ومفصولة",
+    "ومفكك",
+    "ومكان",
+    "وملاطه",
+    "وممكن",
+    "ومن",
+    "ومنه",
+    "ومنها",
+    "وموسكو",
+    "ونبين",
+    "ونتائجها",
+    "ونحن",
+    "ونسب",
+    "ونسبة",
+    "ونشرها",
+    "ونظمها",
+    "ونعني",
+    "ونهايات",
+    "ونهاياته",
+    "ونهاية",
+    "ونهايتيه",
+    "وهذا",
+    "وهذه",
+    "وهم",
+    "وهما",
+    "وهن",
+    "وهنالك",
+    "وهو",
+    "وهوة",
+    "وهي",
+    "وواحدة",
+    "وواشنطن",
+    "ووثائق",
+    "ووجود",
+    "ووجودهم",
+    "ووحدات",
+    "ووحدة",
+    "ووراء",
+    "ووضعهم",
+    "ووفقا",
+    "ووقت",
+    "ووهب",
+    "ويبادر",
+    "ويبلغنا",
+    "وية",
+    "ويتبعه",
+    "ويتخطى",
+    "ويتم",
+    "ويجب",
+    "ويجري",
+    "ويجعل",
+    "ويجوز",
+    "ويحظر",
+    "ويحق",
+    "ويخرج",
+    "ويراعي",
+    "ويستبدلها",
+    "ويسرع",
+    "ويسري",
+    "ويشير",
+    "ويضبط",
+    "ويعرض",
+    "ويفاضل",
+    "ويفشل",
+    "ويقال",
+    "ويكتب",
+    "ويكون",
+    "ويكونان",
+    "ويلحق",
+    "ويمثله",
+    "ويمكن",
+    "ويمين",
+    "وينبغي",
+    "وينصرم",
+    "وينقسم",
+    "ويهب",
+    "ويُتخطى",
+    "ويُخزن",
+    "ويُفترض",
+    "وُجدت",
+    "وُضع",
+    "وُفر",
+    "يأتلف",
+    "يؤتى",
+    "يؤثر",
+    "يؤدي",
+    "يا",
+    "يباشر",
+    "يباشرا",
+    "يبحث",
+    "يبخس",
+    "يبدأ",
+    "يبدل",
+    "يبق",
+    "يبلغنا",
+    "يبنى",
+    "ية",
+    "يتأثر",
+    "يتبدل",
+    "يتبع",
+    "يتبعه",
+    "يتجاهل",
+    "يتجاوز",
+    "يتحرك",
+    "يتحقق",
+    "يتحول",
+    "يتراوح",
+    "يتسبب",
+    "يتضمن",
+    "يتطلب",
+    "يتعرف",
+    "يتعرى",
+    "يتفوق",
+    "يتكثر",
+    "يتكون",
+    "يتلو",
+    "يتلوه",
+    "يتم",
+    "يتمثل",
+    "يتمكن",
+    "يتناهى",
+    "يتناول",
+    "يتوفر",
+    "يتوقف",
+    "يتوهم",
+    "يثبت",
+    "يجب",
+    "يجتمع",
+    "يجد",
+    "يجده",
+    "يجدها",
+    "يجعل",
+    "يجعلها",
+    "يجمعها",
+    "يجوز",
+    "يحاول",
+    "يحب",
+    "يحتاج",
+    "يحتمل",
+    "يحتوي",
+    "يحد",
+    "يحدث",
+    "يحدد",
+    "يحدده",
+    "يحسن",
+    "يحصروا",
+    "يحط",
+    "يحظر",
+    "يحلق",
+    "يحلل",
+    "يحملها",
+    "يحملهم",
+    "يحوطنا",
+    "يخالف",
+    "يختبر",
+    "يختلف",
+    "يخرج",
+    "يخرجه",
+    "يخص",
+    "يخلو",
+    "يخلون",
+    "يدركه",
+    "يدعم",
+    "يدعمه",
+    "يدعمها",
+    "يدعو",
+    "يذهب",
+    "يراد",
+    "يرام",
+    "يرجع",
+    "يرغب",
+    "يرفض",
+    "يركب",
+    "يزاد",
+    "يزال",
+    "يزد",
+    "يزداد",
+    "يزل",
+    "يزيد",
+    "يساعد",
+    "يساو",
+    "يساوي",
+    "يسبب",
+    "يسببها",
+    "يسبق",
+    "يستأهل",
+    "يستثني",
+    "يستحق",
+    "يستحيل",
+    "يستخدم",
+    "يستخدمان",
+    "يستخدمها",
+    "يستدعي",
+    "يستطيع",
+    "يستعمل",
+    "يستفيد",
+    "يسري",
+    "يسمح",
+    "يسير",
+    "يسيراً",
+    "يشترك",
+    "يشرفه",
+    "يشمل",
+    "يشير",
+    "يصبح",
+    "يصغر",
+    "يصل",
+    "يصير",
+    "يضاف",
+    "يضغط",
+    "يطابق",
+    "يطبع",
+    "يطلق",
+    "يظن",
+    "يظهر",
+    "يعادل",
+    "يعتبر",
+    "يعتقد",
+    "يعتمد",
+    "يعثر",
+    "يعد",
+    "يعدها",
+    "يعرض",
+    "يعرى",
+    "يعطل",
+    "يعطوا",
+    "يعطي",
+    "يعطيها",
+    "يعظم",
+    "يعقل",
+    "يعمل",
+    "يعمها",
+    "يعني",
+    "يعيد",
+    "يغير",
+    "يفترض",
+    "يفد",
+    "يفسد",
+    "يفصل",
+    "يفضل",
+    "يفضلان",
+    "يفعل",
+    "يفعله",
+    "يفك",
+    "يقارن",
+    "يقال",
+    "يقالان",
+    "يقبل",
+    "يقتضي",
+    "يقرأ",
+    "يقطع",
+    "يقع",
+    "يقل",
+    "يقلل",
+    "يقولوا",
+    "يقوم",
+    "يقيس",
+    "يك",
+    "يكافئ",
+    "يكتب",
+    "يكفي",
+    "يكن",
+    "يكون",
+    "يكونا",
+    "يكونوا",
+    "يلبسنا",
+    "يلحق",
+    "يلحقنا",
+    "يلحقه",
+    "يلحقها",
+    "يلخص",
+    "يلزم",
+    "يلي",
+    "يماثل",
+    "يمتلك",
+    "يمتلكها",
+    "يمتلكوا",
+    "يمثله",
+    "يمسح",
+    "يمكن",
+    "يمكنه",
+    "يملك",
+    "يمنع",
+    "ين",
+    "يناير",
+    "ينبغي",
+    "ينتج",
+    "ينتقل",
+    "ينتهى",
+    "ينتهي",
+    "ينسب",
+    "ينسخ",
+    "ينشئها",
+    "ينطبق",
+    "ينقسم",
+    "ينل",
+    "ينهي",
+    "ينوع",
+    "يهم",
+    "يهوي",
+    "يوجب",
+    "يوجد",
+    "يوصى",
+    "يوفر",
+    "يولى",
+    "يُتجاهل",
+    "يُتوقع",
+    "يُحدد",
+    "يُحذف",
+    "يُرجح",
+    "يُزال",
+    "يُستخدم",
+    "يُشتق",
+    "يُضبط",
+    "يُضغط",
+    "يُطبع",
+    "يُعتبر",
+    "يُعد",
+    "يُعرض",
+    "يُفترض",
+    "يُفضل",
+    "يُفعل",
+    "يُقصد",
+    "يُكتب",
+    "يُكتشف",
+    "يُلحق",
+    "يُنتج",
+    "يُنصح",
+    "يُوصى",
+};
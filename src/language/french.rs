@@ -0,0 +1,70 @@
+use std::ops::RangeInclusive;
+
+use phf::phf_set;
+
+use super::{contains_lowercase, Language, LanguageId};
+
+mod words;
+
+use words::WORDS;
+
+/// French, behind the `french` cargo feature
+#[derive(Debug, Clone, Copy, Default)]
+pub struct French;
+
+impl Language for French {
+    fn id(&self) -> LanguageId {
+        LanguageId::French
+    }
+
+    fn is_letter(&self, c: char) -> bool {
+        c.is_ascii_alphabetic() || "àâæçéèêëîïôœùûüÿ".contains(c.to_lowercase().next().unwrap_or(c))
+    }
+
+    fn is_vowel(&self, c: char) -> bool {
+        "aeiouyàâæéèêëîïôœùûüÿ".contains(c)
+    }
+
+    fn is_common_pair(&self, pair: &str) -> bool {
+        COMMON_CHAR_PAIRS.contains(pair)
+    }
+
+    fn is_common_trigram(&self, trigram: &str) -> bool {
+        COMMON_TRIGRAMS.contains(trigram)
+    }
+
+    fn is_common_quadgram(&self, quadgram: &str) -> bool {
+        COMMON_QUADGRAMS.contains(quadgram)
+    }
+
+    fn contains_word(&self, word: &str) -> bool {
+        contains_lowercase(&WORDS, word)
+    }
+
+    fn entropy_range(&self) -> RangeInclusive<f64> {
+        3.5..=4.6
+    }
+}
+
+static COMMON_CHAR_PAIRS: phf::Set<&'static str> = phf_set! {
+    "es", "le", "de", "en", "re", "nt", "on", "er", "te", "el",
+    "an", "se", "et", "la", "ai", "it", "me", "ou", "em", "ne",
+    "ie", "qu", "ti", "ur", "ra", "us", "co", "is", "ce", "in",
+    "ns", "ue", "eu", "ar", "ll", "ri", "ut", "ss", "tr", "pa",
+    "al", "ta", "ma", "di", "ve", "or", "na", "lo", "ch", "io",
+    "au", "ép", "té", "ré", "dé", "ét",
+};
+
+static COMMON_TRIGRAMS: phf::Set<&'static str> = phf_set! {
+    "ent", "les", "que", "des", "ion", "tio", "men", "ait", "lle", "ons",
+    "est", "our", "res", "pou", "par", "ans", "dan", "eme", "ati", "eur",
+    "ire", "ess", "une", "con", "com", "ien", "tre", "ure", "ell", "ont",
+    "nte", "ais", "ant", "pre", "sur", "qui", "ous", "mme", "sse", "tou",
+    "ter", "ver", "ine", "ité", "ett", "omm", "eux", "tai", "oir", "ave",
+};
+
+static COMMON_QUADGRAMS: phf::Set<&'static str> = phf_set! {
+    "ment", "tion", "ique", "pour", "dans", "elle", "ette", "omme",
+    "emen", "ions", "ence", "ance", "ille", "ours", "aire", "tout",
+    "plus", "mais", "sont", "leur", "nous", "vous", "avec", "être",
+};
@@ -0,0 +1,19826 @@
+use phf::phf_set;
+
+pub static WORDS: phf::Set<&'static str> = phf_set! {
+    "abailard",
+    "abaissait",
+    "abaissant",
+    "abaisse",
+    "abaissement",
+    "abaissent",
+    "abaisser",
+    "abaisserai",
+    "abaissé",
+    "abandon",
+    "abandonna",
+    "abandonnait",
+    "abandonnant",
+    "abandonne",
+    "abandonner",
+    "abandonnera",
+    "abandonnerait",
+    "abandonnez",
+    "abandonné",
+    "abandonnée",
+    "abandonnés",
+    "abasourdi",
+    "abat",
+    "abattant",
+    "abattement",
+    "abattit",
+    "abattre",
+    "abbaye",
+    "abbesse",
+    "abbé",
+    "abbés",
+    "abeille",
+    "abhorrait",
+    "abhorre",
+    "abhorré",
+    "abject",
+    "abjurant",
+    "ablutions",
+    "abnégation",
+    "aboiements",
+    "aboiera",
+    "abolir",
+    "abominable",
+    "abominablement",
+    "abominables",
+    "abomination",
+    "abondaient",
+    "abondamment",
+    "abondance",
+    "abondant",
+    "abondante",
+    "abonde",
+    "abonnement",
+    "abonné",
+    "abord",
+    "aborda",
+    "abordaient",
+    "abordait",
+    "abordant",
+    "aborder",
+    "abords",
+    "abordèrent",
+    "abordée",
+    "abordés",
+    "aboutit",
+    "aboyaient",
+    "aboyèrent",
+    "abraham",
+    "abreuvoir",
+    "abri",
+    "abritent",
+    "abritât",
+    "abrité",
+    "abritée",
+    "abrités",
+    "abrutis",
+    "abrutissant",
+    "abrutissement",
+    "abruzze",
+    "abrège",
+    "abrégea",
+    "abrégeaient",
+    "abrégeait",
+    "abrégeant",
+    "abréger",
+    "abrégera",
+    "abrégerait",
+    "abrégé",
+    "abrégés",
+    "abréviations",
+    "absences",
+    "absent",
+    "absentait",
+    "absente",
+    "absents",
+    "absolu",
+    "absolue",
+    "absolument",
+    "absolus",
+    "absolutisme",
+    "absorba",
+    "absorbaient",
+    "absorbait",
+    "absorbé",
+    "absorbée",
+    "absorbés",
+    "absoudre",
+    "abstenait",
+    "abstenez",
+    "abstiendrai",
+    "abstrait",
+    "absurde",
+    "absurdes",
+    "absurdité",
+    "absurdités",
+    "abus",
+    "abusa",
+    "abusait",
+    "abusant",
+    "abuse",
+    "abuser",
+    "abusez",
+    "abusif",
+    "abusé",
+    "abîmait",
+    "abîme",
+    "abîmé",
+    "abîmée",
+    "acabit",
+    "acacia",
+    "acacias",
+    "académicien",
+    "académie",
+    "académies",
+    "académique",
+    "acajou",
+    "accabla",
+    "accablaient",
+    "accablait",
+    "accablant",
+    "accablante",
+    "accable",
+    "accablement",
+    "accabler",
+    "accableraient",
+    "accablèrent",
+    "accablé",
+    "accablée",
+    "accalmie",
+    "accaparé",
+    "accent",
+    "accents",
+    "accentua",
+    "accepta",
+    "acceptables",
+    "acceptais",
+    "acceptait",
+    "accepte",
+    "acceptent",
+    "accepter",
+    "acceptera",
+    "accepterai",
+    "accepterait",
+    "accepteriez",
+    "acceptes",
+    "acceptez",
+    "acception",
+    "acceptons",
+    "accepté",
+    "acceptée",
+    "accidents",
+    "accidenté",
+    "acclamation",
+    "acclamations",
+    "accommodé",
+    "accompagnaient",
+    "accompagnait",
+    "accompagnant",
+    "accompagne",
+    "accompagnement",
+    "accompagnent",
+    "accompagner",
+    "accompagniez",
+    "accompagnèrent",
+    "accompagné",
+    "accompagnée",
+    "accompli",
+    "accomplie",
+    "accomplies",
+    "accomplir",
+    "accomplirait",
+    "accomplis",
+    "accomplissaient",
+    "accomplissais",
+    "accomplissait",
+    "accomplissant",
+    "accomplissement",
+    "accomplissent",
+    "accomplit",
+    "accord",
+    "accorda",
+    "accordait",
+    "accordant",
+    "accorde",
+    "accorder",
+    "accorderai",
+    "accorderait",
+    "accorderez",
+    "accordez",
+    "accordèrent",
+    "accordé",
+    "accordée",
+    "accordées",
+    "accosta",
+    "accostait",
+    "accoster",
+    "accosté",
+    "accoté",
+    "accouchement",
+    "accouraient",
+    "accourait",
+    "accourant",
+    "accourez",
+    "accourir",
+    "accourue",
+    "accoururent",
+    "accourus",
+    "accourut",
+    "accoutrement",
+    "accoutume",
+    "accoutumer",
+    "accoutumes",
+    "accoutumât",
+    "accoutumé",
+    "accoutumée",
+    "accoutumées",
+    "accoutumés",
+    "accrochaient",
+    "accrochant",
+    "accrocher",
+    "accroché",
+    "accroissaient",
+    "accroissait",
+    "accroissant",
+    "accroître",
+    "accru",
+    "accrue",
+    "accrut",
+    "accréditer",
+    "accrédité",
+    "accueil",
+    "accueillait",
+    "accueillent",
+    "accueilli",
+    "accueillie",
+    "accueillies",
+    "accueillir",
+    "accueillis",
+    "accumulation",
+    "accumulent",
+    "accumuler",
+    "accumulé",
+    "accumulées",
+    "accumulés",
+    "accusa",
+    "accusait",
+    "accusant",
+    "accusateur",
+    "accusation",
+    "accusations",
+    "accuse",
+    "accuser",
+    "accuseront",
+    "accusez",
+    "accusé",
+    "accusés",
+    "accède",
+    "accès",
+    "accéder",
+    "accéléra",
+    "accélération",
+    "achalandage",
+    "acharnant",
+    "acharnés",
+    "achat",
+    "achemina",
+    "acheminait",
+    "acheminé",
+    "acheta",
+    "achetait",
+    "achetant",
+    "achete",
+    "acheter",
+    "achetez",
+    "acheté",
+    "achetées",
+    "achetés",
+    "acheva",
+    "achevaient",
+    "achevait",
+    "achevant",
+    "achever",
+    "achevez",
+    "achevé",
+    "achevée",
+    "achille",
+    "achète",
+    "achèterons",
+    "achètes",
+    "achève",
+    "achèvement",
+    "achèvera",
+    "acier",
+    "acolyte",
+    "acoustiques",
+    "acquerrait",
+    "acquiert",
+    "acquis",
+    "acquise",
+    "acquises",
+    "acquisition",
+    "acquisitions",
+    "acquit",
+    "acquitta",
+    "acquittait",
+    "acquitte",
+    "acquittement",
+    "acquitter",
+    "acquitté",
+    "acquittés",
+    "acquérait",
+    "acrobates",
+    "acrobatique",
+    "acte",
+    "actes",
+    "acteur",
+    "acteurs",
+    "actif",
+    "actionnaires",
+    "activement",
+    "actives",
+    "activité",
+    "actrice",
+    "actrices",
+    "actuel",
+    "actuelle",
+    "actuellement",
+    "actuelles",
+    "actuels",
+    "ad",
+    "adaptant",
+    "adeline",
+    "aden",
+    "adieu",
+    "adieux",
+    "adjoint",
+    "adjonction",
+    "adjudant",
+    "adjudication",
+    "adjudications",
+    "adjugeait",
+    "adjuger",
+    "admet",
+    "admettait",
+    "admettant",
+    "admettent",
+    "admettons",
+    "admettre",
+    "administra",
+    "administrateur",
+    "administrateurs",
+    "administration",
+    "administre",
+    "administrer",
+    "administrez",
+    "administré",
+    "administrés",
+    "admira",
+    "admirable",
+    "admirablement",
+    "admirables",
+    "admirais",
+    "admirait",
+    "admirant",
+    "admirateurs",
+    "admiration",
+    "admire",
+    "admirent",
+    "admirer",
+    "admirera",
+    "admiriez",
+    "admirèrent",
+    "admiré",
+    "admirée",
+    "admirées",
+    "admirés",
+    "admis",
+    "admise",
+    "admises",
+    "admission",
+    "admonestant",
+    "admît",
+    "adolescent",
+    "adolphe",
+    "adonnent",
+    "adonné",
+    "adonnée",
+    "adopta",
+    "adopte",
+    "adopter",
+    "adoptif",
+    "adopté",
+    "adoptée",
+    "adorable",
+    "adoraient",
+    "adorais",
+    "adorait",
+    "adorant",
+    "adorateur",
+    "adorateurs",
+    "adoration",
+    "adorations",
+    "adoratrices",
+    "adore",
+    "adorent",
+    "adorer",
+    "adorera",
+    "adoré",
+    "adorée",
+    "adouci",
+    "adoucir",
+    "adoucissait",
+    "adoucissent",
+    "adressa",
+    "adressaient",
+    "adressait",
+    "adressant",
+    "adresse",
+    "adressent",
+    "adresser",
+    "adresserai",
+    "adresses",
+    "adressez",
+    "adressât",
+    "adressèrent",
+    "adressé",
+    "adressée",
+    "adressées",
+    "adressés",
+    "adrien",
+    "adroit",
+    "adroite",
+    "adroitement",
+    "adroites",
+    "adroits",
+    "adsum",
+    "adultère",
+    "adverbe",
+    "adversaire",
+    "adversaires",
+    "adverse",
+    "adversité",
+    "advint",
+    "affabilité",
+    "affadissantes",
+    "affaibli",
+    "affaiblie",
+    "affaiblissait",
+    "affaiblissement",
+    "affaiblit",
+    "affaire",
+    "affaires",
+    "affairé",
+    "affairés",
+    "affaissé",
+    "affamer",
+    "affamé",
+    "affamés",
+    "affecta",
+    "affectaient",
+    "affectait",
+    "affectant",
+    "affectation",
+    "affectations",
+    "affecte",
+    "affecter",
+    "affectionnait",
+    "affectionné",
+    "affections",
+    "affectueux",
+    "affecté",
+    "affectée",
+    "affectées",
+    "affectés",
+    "affermissant",
+    "affermit",
+    "affichait",
+    "affiche",
+    "affichent",
+    "afficher",
+    "affiches",
+    "afficheur",
+    "affiché",
+    "affichée",
+    "affidé",
+    "affidés",
+    "affilier",
+    "affilié",
+    "affilée",
+    "affinités",
+    "affirmait",
+    "affirmant",
+    "affirmatif",
+    "affirme",
+    "affirment",
+    "affirmer",
+    "affirmerai",
+    "affirmé",
+    "afflictive",
+    "afflige",
+    "affligea",
+    "affliger",
+    "affligez",
+    "affligé",
+    "affligée",
+    "afflua",
+    "affluaient",
+    "affluence",
+    "affluent",
+    "affluents",
+    "affluer",
+    "affolait",
+    "affolées",
+    "affranchi",
+    "affreuse",
+    "affreuses",
+    "affreux",
+    "affriolait",
+    "affront",
+    "affrontais",
+    "affronter",
+    "affrète",
+    "affublé",
+    "afin",
+    "afrique",
+    "agde",
+    "agen",
+    "agencées",
+    "agenouilla",
+    "agenouillant",
+    "agenouillé",
+    "agenouillée",
+    "agents",
+    "agglomération",
+    "aggrottato",
+    "agi",
+    "agile",
+    "agilité",
+    "agir",
+    "agirait",
+    "agis",
+    "agissaient",
+    "agissait",
+    "agissant",
+    "agissante",
+    "agisse",
+    "agissent",
+    "agissez",
+    "agissons",
+    "agit",
+    "agita",
+    "agitaient",
+    "agitait",
+    "agitant",
+    "agitation",
+    "agite",
+    "agitent",
+    "agiter",
+    "agitèrent",
+    "agité",
+    "agitée",
+    "agitées",
+    "agités",
+    "agneaux",
+    "agonie",
+    "agra",
+    "agrandis",
+    "agriculteur",
+    "agriculture",
+    "agrès",
+    "agréable",
+    "agréablement",
+    "agréables",
+    "agréer",
+    "agréez",
+    "agrément",
+    "agréments",
+    "agrémenté",
+    "agrémentée",
+    "agrémentées",
+    "agrémentés",
+    "ah",
+    "ahméhnagara",
+    "ahuri",
+    "ai",
+    "aida",
+    "aidait",
+    "aidant",
+    "aide",
+    "aider",
+    "aidera",
+    "aiderai",
+    "aiderez",
+    "aides",
+    "aidez",
+    "aidiez",
+    "aidèrent",
+    "aidé",
+    "aidée",
+    "aidées",
+    "aidés",
+    "aie",
+    "aient",
+    "aies",
+    "aigle",
+    "aigre",
+    "aigrelets",
+    "aigrelettes",
+    "aigrement",
+    "aigres",
+    "aigreur",
+    "aigrirait",
+    "aiguille",
+    "aiguilles",
+    "aiguillon",
+    "aiguiser",
+    "aigus",
+    "aile",
+    "ailes",
+    "aille",
+    "ailles",
+    "ailleurs",
+    "aima",
+    "aimable",
+    "aimables",
+    "aimaient",
+    "aimais",
+    "aimait",
+    "aimant",
+    "aime",
+    "aiment",
+    "aimer",
+    "aimera",
+    "aimerai",
+    "aimerais",
+    "aimerait",
+    "aimerez",
+    "aimes",
+    "aimez",
+    "aimiez",
+    "aimons",
+    "aimât",
+    "aimé",
+    "aimée",
+    "aimées",
+    "aimés",
+    "aine",
+    "ainsi",
+    "aire",
+    "airs",
+    "aisance",
+    "aise",
+    "aisé",
+    "aisées",
+    "aisément",
+    "aisés",
+    "ait",
+    "ajournement",
+    "ajouta",
+    "ajoutaient",
+    "ajoutait",
+    "ajoutant",
+    "ajoute",
+    "ajouter",
+    "ajoutera",
+    "ajouterai",
+    "ajouteraient",
+    "ajouterais",
+    "ajouterez",
+    "ajouterons",
+    "ajoutèrent",
+    "ajouté",
+    "ajoutées",
+    "ajuster",
+    "al",
+    "alabama",
+    "alacoque",
+    "alari",
+    "alarma",
+    "alarmaient",
+    "alarmants",
+    "alarme",
+    "alarmer",
+    "alarmes",
+    "alarmé",
+    "alarmée",
+    "alas",
+    "albermale",
+    "album",
+    "albâtre",
+    "alcooliques",
+    "ale",
+    "alembert",
+    "alençon",
+    "alerte",
+    "alertes",
+    "alexandre",
+    "alfieri",
+    "alfred",
+    "alger",
+    "alhambra",
+    "alignées",
+    "aligre",
+    "aliment",
+    "alimentation",
+    "alimenter",
+    "alimento",
+    "alinéa",
+    "aliène",
+    "alla",
+    "allahabad",
+    "allaient",
+    "allais",
+    "allait",
+    "allant",
+    "allege",
+    "allemagne",
+    "allemand",
+    "allemande",
+    "allemands",
+    "aller",
+    "allez",
+    "alliage",
+    "alliance",
+    "alliez",
+    "alligators",
+    "allions",
+    "allié",
+    "alliés",
+    "allocution",
+    "allonge",
+    "allongeait",
+    "allongée",
+    "allons",
+    "alloue",
+    "alluma",
+    "allumait",
+    "allume",
+    "allumer",
+    "allumé",
+    "allumée",
+    "allumées",
+    "allumés",
+    "allure",
+    "allures",
+    "allusion",
+    "allusions",
+    "allât",
+    "allège",
+    "allègre",
+    "allèrent",
+    "allé",
+    "alléché",
+    "alléchés",
+    "allée",
+    "allées",
+    "allégation",
+    "allégresse",
+    "alléguant",
+    "allés",
+    "almanach",
+    "almaviva",
+    "alors",
+    "alouettes",
+    "alourdie",
+    "alpes",
+    "alphabet",
+    "alphabets",
+    "alsace",
+    "altamira",
+    "altesse",
+    "altesses",
+    "altier",
+    "altitude",
+    "altière",
+    "altéra",
+    "altérait",
+    "altérer",
+    "altéré",
+    "altérée",
+    "altérés",
+    "alvizi",
+    "alzar",
+    "aléa",
+    "ama",
+    "amabilité",
+    "amadouer",
+    "amadoué",
+    "amai",
+    "amaigris",
+    "amand",
+    "amanda",
+    "amant",
+    "amantes",
+    "amants",
+    "amarres",
+    "amarrés",
+    "amas",
+    "amassaient",
+    "amassé",
+    "amassée",
+    "amateur",
+    "amateurs",
+    "ambassade",
+    "ambassadeur",
+    "ambassadeurs",
+    "ambiguë",
+    "ambitieuse",
+    "ambitieux",
+    "ambition",
+    "ambulance",
+    "ambulant",
+    "ambulantes",
+    "ambulants",
+    "amena",
+    "amenait",
+    "amende",
+    "amendes",
+    "amener",
+    "amenez",
+    "ameni",
+    "amené",
+    "amenée",
+    "amer",
+    "amers",
+    "amertume",
+    "ameublement",
+    "ameutant",
+    "ami",
+    "amiable",
+    "amical",
+    "amicale",
+    "amicalement",
+    "amicizia",
+    "amie",
+    "amiens",
+    "amies",
+    "amis",
+    "amitié",
+    "amitiés",
+    "amoindri",
+    "amor",
+    "amorce",
+    "amorces",
+    "amortir",
+    "amortirait",
+    "amour",
+    "amourachée",
+    "amoureuse",
+    "amoureusement",
+    "amoureux",
+    "amours",
+    "amphigouriques",
+    "amphion",
+    "amphithéâtre",
+    "ample",
+    "amplification",
+    "amplifications",
+    "amputés",
+    "amusa",
+    "amusaient",
+    "amusait",
+    "amusant",
+    "amusante",
+    "amusantes",
+    "amusants",
+    "amuse",
+    "amusement",
+    "amuser",
+    "amusera",
+    "amusé",
+    "amène",
+    "amènera",
+    "amènerai",
+    "amènerait",
+    "amère",
+    "amèrement",
+    "amères",
+    "aménagements",
+    "aménagé",
+    "américain",
+    "américaine",
+    "américains",
+    "amérique",
+    "anachorète",
+    "anachronisme",
+    "analyser",
+    "ananas",
+    "anathème",
+    "anathèmes",
+    "ancien",
+    "ancienne",
+    "anciennes",
+    "anciens",
+    "ancre",
+    "ancrés",
+    "ancêtres",
+    "andaman",
+    "anderson",
+    "andryane",
+    "anecdote",
+    "anecdotes",
+    "anecdotique",
+    "anetta",
+    "ange",
+    "angela",
+    "angelica",
+    "anges",
+    "anglais",
+    "anglaise",
+    "anglaises",
+    "angles",
+    "angleterre",
+    "anglo",
+    "angoisse",
+    "angoisses",
+    "angoulême",
+    "angélina",
+    "angélique",
+    "aniken",
+    "anima",
+    "animaient",
+    "animait",
+    "animal",
+    "animant",
+    "animation",
+    "animaux",
+    "anime",
+    "animer",
+    "animèrent",
+    "animé",
+    "animée",
+    "animées",
+    "animés",
+    "annales",
+    "annam",
+    "anneau",
+    "annexait",
+    "annibal",
+    "anno",
+    "annonce",
+    "annoncent",
+    "annoncer",
+    "annoncerait",
+    "annoncerez",
+    "annonces",
+    "annoncèrent",
+    "annoncé",
+    "annoncée",
+    "annoncées",
+    "annoncés",
+    "annonça",
+    "annonçaient",
+    "annonçait",
+    "annonçant",
+    "annuel",
+    "annuelle",
+    "annuellement",
+    "annuleront",
+    "annulé",
+    "annulée",
+    "année",
+    "années",
+    "anobli",
+    "anoblir",
+    "anoblis",
+    "anoblissement",
+    "anoblit",
+    "anodines",
+    "anonyme",
+    "anonymement",
+    "anonymes",
+    "ans",
+    "anse",
+    "antagoniste",
+    "anthropophages",
+    "anti",
+    "antibes",
+    "antichambre",
+    "antichambres",
+    "antidata",
+    "antidatée",
+    "antigénéreuses",
+    "antijacobine",
+    "antimonarchique",
+    "antipathie",
+    "antipathique",
+    "antipode",
+    "antipodes",
+    "antiquaires",
+    "antique",
+    "antiques",
+    "antiquité",
+    "antiquités",
+    "antithèse",
+    "antoine",
+    "antérieure",
+    "antérieurs",
+    "anxieux",
+    "anxiété",
+    "anéanti",
+    "anéantie",
+    "anéantir",
+    "anéantis",
+    "anéantissait",
+    "anéantit",
+    "aouda",
+    "août",
+    "apaisa",
+    "apaiser",
+    "apaisera",
+    "apaisée",
+    "apathie",
+    "apathique",
+    "apennin",
+    "apens",
+    "apercevait",
+    "apercevant",
+    "apercevoir",
+    "apercevons",
+    "apercevrait",
+    "aperçois",
+    "aperçoit",
+    "aperçoive",
+    "aperçu",
+    "aperçue",
+    "aperçurent",
+    "aperçus",
+    "aperçut",
+    "aperçût",
+    "aplaties",
+    "aplatit",
+    "aplomb",
+    "apocalypse",
+    "apocalyptique",
+    "apoplectique",
+    "apoplexie",
+    "apoplexies",
+    "apostat",
+    "apostille",
+    "apostolique",
+    "apostoliques",
+    "aposté",
+    "apothicaire",
+    "apparaissaient",
+    "apparaissait",
+    "apparait",
+    "apparat",
+    "apparaît",
+    "apparaîtrait",
+    "apparaître",
+    "appareil",
+    "appareillait",
+    "appareiller",
+    "appareils",
+    "apparemment",
+    "apparence",
+    "apparences",
+    "apparent",
+    "apparente",
+    "apparition",
+    "apparitions",
+    "appartement",
+    "appartements",
+    "appartenaient",
+    "appartenait",
+    "appartenant",
+    "appartenir",
+    "appartenu",
+    "appartiendront",
+    "appartiennent",
+    "appartient",
+    "appartint",
+    "appartînt",
+    "apparu",
+    "apparut",
+    "appel",
+    "appela",
+    "appelaient",
+    "appelait",
+    "appelant",
+    "appeler",
+    "appelez",
+    "appelle",
+    "appellent",
+    "appellera",
+    "appellerai",
+    "appellerait",
+    "appellerez",
+    "appelleront",
+    "appelles",
+    "appelât",
+    "appelé",
+    "appelée",
+    "appelées",
+    "appelés",
+    "appendices",
+    "appert",
+    "appesantie",
+    "appesantis",
+    "applaudi",
+    "applaudie",
+    "applaudir",
+    "applaudirent",
+    "applaudissaient",
+    "applaudissements",
+    "applaudit",
+    "applicables",
+    "appliqua",
+    "appliquait",
+    "applique",
+    "appliquer",
+    "appliquez",
+    "appliqué",
+    "appliquée",
+    "appoint",
+    "appointements",
+    "appointés",
+    "apporta",
+    "apportaient",
+    "apportait",
+    "apportant",
+    "apporte",
+    "apporter",
+    "apporterai",
+    "apporterait",
+    "apportez",
+    "apportiez",
+    "apporté",
+    "apportée",
+    "apportées",
+    "apportés",
+    "apposa",
+    "apposer",
+    "apposés",
+    "apprenaient",
+    "apprenait",
+    "apprenant",
+    "apprend",
+    "apprendra",
+    "apprendrais",
+    "apprendrait",
+    "apprendre",
+    "apprendrez",
+    "apprendrions",
+    "apprendront",
+    "apprends",
+    "apprenez",
+    "apprennes",
+    "apprirent",
+    "appris",
+    "apprise",
+    "apprises",
+    "apprit",
+    "apprivoisés",
+    "approbation",
+    "approcha",
+    "approchaient",
+    "approchait",
+    "approchant",
+    "approche",
+    "approchent",
+    "approcher",
+    "approches",
+    "approchèrent",
+    "approché",
+    "approchée",
+    "approchés",
+    "approfondie",
+    "approprié",
+    "approuva",
+    "approuvera",
+    "approuves",
+    "approuvé",
+    "approuvée",
+    "apprécie",
+    "apprécier",
+    "appréciée",
+    "appréciés",
+    "appréhension",
+    "apprêtait",
+    "apprêter",
+    "apprêtons",
+    "appui",
+    "appuie",
+    "appuya",
+    "appuyaient",
+    "appuyais",
+    "appuyait",
+    "appuyant",
+    "appuyer",
+    "appuyé",
+    "appuyée",
+    "appuyées",
+    "appât",
+    "appétit",
+    "april",
+    "après",
+    "aptitude",
+    "apôtre",
+    "apôtres",
+    "aquarelle",
+    "aquetta",
+    "aquilin",
+    "aquitaine",
+    "arabe",
+    "arabes",
+    "arabique",
+    "araceli",
+    "arbitraire",
+    "arbitraires",
+    "arbitre",
+    "arbitres",
+    "arborer",
+    "arborescentes",
+    "arboré",
+    "arbre",
+    "arbres",
+    "arbrisseaux",
+    "arceaux",
+    "arche",
+    "archet",
+    "archevêché",
+    "archevêque",
+    "archevêques",
+    "archiduc",
+    "archifolle",
+    "archiprêtre",
+    "architecte",
+    "archiépiscopal",
+    "archiépiscopale",
+    "arcole",
+    "ardemment",
+    "ardent",
+    "ardente",
+    "ardentes",
+    "ardents",
+    "ardeur",
+    "ardu",
+    "arecs",
+    "aresi",
+    "argent",
+    "argentée",
+    "argile",
+    "arides",
+    "arioste",
+    "aristocrate",
+    "aristocratie",
+    "aristocratique",
+    "aristocratiques",
+    "arithmétique",
+    "arkansas",
+    "arlequin",
+    "arma",
+    "armait",
+    "armant",
+    "armateur",
+    "armateurs",
+    "arme",
+    "armer",
+    "armes",
+    "armide",
+    "armoire",
+    "armoires",
+    "armoiries",
+    "armonica",
+    "armurier",
+    "armé",
+    "armée",
+    "armées",
+    "arméniennes",
+    "arméniens",
+    "armés",
+    "arnauld",
+    "arpent",
+    "arpents",
+    "arquebuser",
+    "arquées",
+    "arqués",
+    "arracha",
+    "arrachait",
+    "arrachant",
+    "arrache",
+    "arrachent",
+    "arracher",
+    "arrachèrent",
+    "arraché",
+    "arrachée",
+    "arrachés",
+    "arrange",
+    "arrangea",
+    "arrangeaient",
+    "arrangeait",
+    "arrangement",
+    "arrangements",
+    "arrangent",
+    "arranger",
+    "arrangera",
+    "arrangerait",
+    "arrangé",
+    "arrangée",
+    "arrangées",
+    "arrangés",
+    "arrestation",
+    "arrestations",
+    "arrhes",
+    "arrien",
+    "arriva",
+    "arrivai",
+    "arrivaient",
+    "arrivais",
+    "arrivait",
+    "arrivant",
+    "arrivants",
+    "arrivent",
+    "arriver",
+    "arrivera",
+    "arriveraient",
+    "arriverais",
+    "arriverait",
+    "arriverez",
+    "arriverions",
+    "arriverons",
+    "arriveront",
+    "arrives",
+    "arrivez",
+    "arrivons",
+    "arrivât",
+    "arrivèrent",
+    "arrivé",
+    "arrivée",
+    "arrivées",
+    "arrivés",
+    "arrière",
+    "arrogante",
+    "arrondies",
+    "arrondis",
+    "arrondissait",
+    "arrondissement",
+    "arrondit",
+    "arrosé",
+    "arrosées",
+    "arrérages",
+    "arrêt",
+    "arrêta",
+    "arrêtaient",
+    "arrêtait",
+    "arrêtant",
+    "arrête",
+    "arrêtent",
+    "arrêter",
+    "arrêtera",
+    "arrêterai",
+    "arrêterait",
+    "arrêterons",
+    "arrêteront",
+    "arrêtez",
+    "arrêtiez",
+    "arrêtons",
+    "arrêts",
+    "arrêtèrent",
+    "arrêté",
+    "arrêtée",
+    "arrêtées",
+    "arrêtés",
+    "arsenal",
+    "arsenic",
+    "arsène",
+    "arte",
+    "arthémidore",
+    "articles",
+    "articulait",
+    "articulant",
+    "articuler",
+    "articulée",
+    "articulés",
+    "artifice",
+    "artillerie",
+    "artisans",
+    "artiste",
+    "artistes",
+    "arts",
+    "artère",
+    "arçon",
+    "arêtes",
+    "as",
+    "ascagne",
+    "ascanio",
+    "ascétique",
+    "asie",
+    "asile",
+    "asphaltite",
+    "asphyxie",
+    "asphyxié",
+    "aspiciam",
+    "aspira",
+    "aspirait",
+    "aspirants",
+    "aspirations",
+    "aspire",
+    "aspirer",
+    "aspirez",
+    "aspérités",
+    "assaillaient",
+    "assaillant",
+    "assaillants",
+    "assailli",
+    "assaillie",
+    "assaillis",
+    "assassin",
+    "assassinait",
+    "assassinat",
+    "assassine",
+    "assassiner",
+    "assassins",
+    "assassiné",
+    "assassinés",
+    "assaut",
+    "assembla",
+    "assemblée",
+    "assentiment",
+    "asseoir",
+    "asseyaient",
+    "asseyait",
+    "asseyant",
+    "asseyez",
+    "assez",
+    "assidue",
+    "assiduité",
+    "assiduités",
+    "assidûment",
+    "assied",
+    "assiette",
+    "assignats",
+    "assigne",
+    "assigner",
+    "assigné",
+    "assirent",
+    "assis",
+    "assise",
+    "assises",
+    "assista",
+    "assistaient",
+    "assistait",
+    "assistance",
+    "assistant",
+    "assistants",
+    "assiste",
+    "assister",
+    "assistera",
+    "assisté",
+    "assit",
+    "assiéger",
+    "assiégé",
+    "assiégée",
+    "assiégés",
+    "associaient",
+    "association",
+    "associations",
+    "associe",
+    "associé",
+    "assoient",
+    "assombri",
+    "assombrit",
+    "assommant",
+    "assommer",
+    "assommoir",
+    "assommé",
+    "assommés",
+    "assoupie",
+    "assoupissantes",
+    "assoupissement",
+    "assoupit",
+    "assourdissant",
+    "assourdit",
+    "assura",
+    "assuraient",
+    "assurait",
+    "assurance",
+    "assurances",
+    "assure",
+    "assurer",
+    "assurerait",
+    "assurerez",
+    "assureront",
+    "assurghur",
+    "assuré",
+    "assurée",
+    "assurées",
+    "assurément",
+    "asthme",
+    "asti",
+    "astre",
+    "astreindre",
+    "astreins",
+    "astres",
+    "astrologie",
+    "astrologique",
+    "astrologiques",
+    "astrologues",
+    "astronomes",
+    "astronomie",
+    "astronomique",
+    "astuce",
+    "astéroïdes",
+    "atelier",
+    "athènes",
+    "atlantique",
+    "atmosphère",
+    "atome",
+    "atours",
+    "atouts",
+    "atroce",
+    "atrocement",
+    "atroces",
+    "atrocité",
+    "attacha",
+    "attachaient",
+    "attachait",
+    "attachant",
+    "attache",
+    "attachement",
+    "attachent",
+    "attacher",
+    "attachera",
+    "attacherai",
+    "attacherait",
+    "attachèrent",
+    "attaché",
+    "attachée",
+    "attachées",
+    "attachés",
+    "attaqua",
+    "attaquaient",
+    "attaquait",
+    "attaque",
+    "attaquer",
+    "attaques",
+    "attaqué",
+    "attaquée",
+    "attaqués",
+    "attardé",
+    "atteignaient",
+    "atteignait",
+    "atteignirent",
+    "atteignit",
+    "atteindrait",
+    "atteindre",
+    "atteint",
+    "atteintes",
+    "atteints",
+    "attelage",
+    "attelages",
+    "attelait",
+    "attelle",
+    "attelé",
+    "attelée",
+    "attelés",
+    "attenait",
+    "attenant",
+    "attenante",
+    "attend",
+    "attendaient",
+    "attendais",
+    "attendait",
+    "attendant",
+    "attendent",
+    "attendez",
+    "attendirent",
+    "attendit",
+    "attendons",
+    "attendra",
+    "attendrai",
+    "attendrait",
+    "attendre",
+    "attendri",
+    "attendrie",
+    "attendrir",
+    "attendriraient",
+    "attendris",
+    "attendrissant",
+    "attendrissement",
+    "attendrit",
+    "attends",
+    "attendu",
+    "attendue",
+    "attendus",
+    "attente",
+    "attenter",
+    "attentif",
+    "attentifs",
+    "attentions",
+    "attentive",
+    "attentivement",
+    "attenté",
+    "atterra",
+    "atterrages",
+    "atterré",
+    "atterrée",
+    "attestaient",
+    "attira",
+    "attiraient",
+    "attirait",
+    "attire",
+    "attirent",
+    "attirer",
+    "attirerait",
+    "attirèrent",
+    "attiré",
+    "attirés",
+    "attitude",
+    "attiédi",
+    "attraction",
+    "attrait",
+    "attrapera",
+    "attrapé",
+    "attrayant",
+    "attrayantes",
+    "attribuaient",
+    "attribuait",
+    "attribuer",
+    "attribuera",
+    "attributions",
+    "attribué",
+    "attribuée",
+    "attristait",
+    "attrister",
+    "attristé",
+    "attristée",
+    "attroupement",
+    "attrouper",
+    "au",
+    "aubaine",
+    "aubaines",
+    "aube",
+    "auberge",
+    "auberges",
+    "aubergistes",
+    "aubigné",
+    "aubry",
+    "auburn",
+    "aucun",
+    "aucune",
+    "aucunement",
+    "audace",
+    "audacieuse",
+    "audacieuses",
+    "audacieux",
+    "audience",
+    "audiences",
+    "auditeur",
+    "auditeurs",
+    "auditoire",
+    "augmenta",
+    "augmentaient",
+    "augmentait",
+    "augmentant",
+    "augmentation",
+    "augmente",
+    "augmenter",
+    "augmentera",
+    "augmenterait",
+    "augmenté",
+    "augmentée",
+    "augure",
+    "augurer",
+    "auguste",
+    "augustes",
+    "augustin",
+    "aujourd",
+    "aumône",
+    "aumônes",
+    "aumônier",
+    "aunes",
+    "auparavant",
+    "auprès",
+    "auquel",
+    "aura",
+    "aurai",
+    "auraient",
+    "aurais",
+    "aurait",
+    "auras",
+    "aureng",
+    "aurez",
+    "auriez",
+    "aurions",
+    "aurons",
+    "auront",
+    "aurungabad",
+    "aussi",
+    "aussitôt",
+    "austral",
+    "austère",
+    "austères",
+    "austérité",
+    "autant",
+    "autel",
+    "autels",
+    "auteur",
+    "auteurs",
+    "authenticité",
+    "automate",
+    "automatique",
+    "automne",
+    "autorisait",
+    "autorisation",
+    "autorisations",
+    "autorise",
+    "autorisent",
+    "autorisera",
+    "autoriserait",
+    "autorisé",
+    "autorisée",
+    "autorité",
+    "autorités",
+    "autour",
+    "autre",
+    "autrefois",
+    "autrement",
+    "autres",
+    "autriche",
+    "autrichien",
+    "autrichienne",
+    "autrichiennes",
+    "autrichiens",
+    "aux",
+    "auxiliaire",
+    "auxquelles",
+    "auxquels",
+    "av",
+    "avaient",
+    "avais",
+    "avait",
+    "avala",
+    "avalé",
+    "avance",
+    "avancement",
+    "avancements",
+    "avancent",
+    "avancer",
+    "avancera",
+    "avancerait",
+    "avances",
+    "avancez",
+    "avancèrent",
+    "avancé",
+    "avancée",
+    "avancés",
+    "avanie",
+    "avanies",
+    "avant",
+    "avantage",
+    "avantages",
+    "avantageuse",
+    "avantageusement",
+    "avantageux",
+    "avança",
+    "avançaient",
+    "avançait",
+    "avançant",
+    "avare",
+    "avarice",
+    "avarie",
+    "avaries",
+    "ave",
+    "avec",
+    "avenir",
+    "aventure",
+    "aventurent",
+    "aventurer",
+    "aventures",
+    "aventureuse",
+    "aventureux",
+    "avenues",
+    "averse",
+    "aversion",
+    "averti",
+    "avertie",
+    "averties",
+    "avertir",
+    "avertira",
+    "avertis",
+    "avertissait",
+    "avertissant",
+    "avertissement",
+    "avertit",
+    "aveu",
+    "aveugle",
+    "aveuglement",
+    "aveugles",
+    "aveuglé",
+    "aveuglée",
+    "aveuglément",
+    "aveux",
+    "aveyron",
+    "avez",
+    "avide",
+    "avidement",
+    "avidité",
+    "aviez",
+    "avilie",
+    "avilir",
+    "avilirait",
+    "avilissant",
+    "avilissantes",
+    "avilit",
+    "avions",
+    "aviron",
+    "avis",
+    "avisa",
+    "avisais",
+    "avisait",
+    "avise",
+    "aviser",
+    "aviserai",
+    "aviserait",
+    "aviserons",
+    "avises",
+    "avisé",
+    "avisée",
+    "avocat",
+    "avocats",
+    "avoine",
+    "avoir",
+    "avons",
+    "avoua",
+    "avouai",
+    "avouait",
+    "avoue",
+    "avouent",
+    "avouer",
+    "avouera",
+    "avouerai",
+    "avouerons",
+    "avouez",
+    "avouât",
+    "avoué",
+    "avoués",
+    "avril",
+    "avviamento",
+    "avéré",
+    "axe",
+    "ayant",
+    "ayante",
+    "ayantes",
+    "ayants",
+    "ayez",
+    "aymon",
+    "ayons",
+    "azur",
+    "aérienne",
+    "aîné",
+    "aînée",
+    "aînés",
+    "aïeul",
+    "aïeux",
+    "b",
+    "bab",
+    "babouches",
+    "babylone",
+    "bac",
+    "bacchus",
+    "badaud",
+    "badauderie",
+    "baden",
+    "badin",
+    "badine",
+    "bafouait",
+    "bagage",
+    "bagages",
+    "bagarre",
+    "bagatelle",
+    "bagne",
+    "bague",
+    "bagues",
+    "baguette",
+    "bah",
+    "baie",
+    "baigner",
+    "baigné",
+    "baignée",
+    "baignés",
+    "baisa",
+    "baisai",
+    "baisait",
+    "baisant",
+    "baise",
+    "baiser",
+    "baisers",
+    "baissa",
+    "baissait",
+    "baissant",
+    "baisse",
+    "baisser",
+    "baissèrent",
+    "baissé",
+    "baissée",
+    "baissés",
+    "bajocs",
+    "bal",
+    "baladins",
+    "balancement",
+    "balancer",
+    "balances",
+    "balancier",
+    "balancée",
+    "balançait",
+    "balayé",
+    "balayés",
+    "balbi",
+    "balbutia",
+    "balbutiant",
+    "balcon",
+    "balcons",
+    "baldaquin",
+    "baldi",
+    "balivernes",
+    "balland",
+    "ballants",
+    "balle",
+    "balles",
+    "ballet",
+    "ballon",
+    "balourd",
+    "balourdise",
+    "balourdises",
+    "bals",
+    "balustrade",
+    "bamboches",
+    "bambou",
+    "bambous",
+    "bambousiers",
+    "ban",
+    "bananiers",
+    "banc",
+    "bancs",
+    "bande",
+    "banderoles",
+    "bandes",
+    "bandits",
+    "bandoulière",
+    "bandé",
+    "banians",
+    "banni",
+    "bannie",
+    "bannir",
+    "bannières",
+    "banque",
+    "banqueroute",
+    "banquette",
+    "banquettes",
+    "banquier",
+    "banquiers",
+    "banquière",
+    "baptisé",
+    "baraque",
+    "barbare",
+    "barbares",
+    "barbarie",
+    "barbe",
+    "barbiche",
+    "barbier",
+    "barbone",
+    "barbotement",
+    "barbouillait",
+    "bari",
+    "baring",
+    "bariolée",
+    "barnave",
+    "barnum",
+    "baromètre",
+    "baromètres",
+    "baron",
+    "baronne",
+    "baronnie",
+    "barons",
+    "baroque",
+    "barque",
+    "barques",
+    "barra",
+    "barre",
+    "barreaux",
+    "barrer",
+    "barricader",
+    "barricadés",
+    "barrière",
+    "barrières",
+    "barthélemy",
+    "barême",
+    "bas",
+    "basalte",
+    "basile",
+    "basilique",
+    "basse",
+    "bassement",
+    "basses",
+    "bassesse",
+    "bassesses",
+    "bassin",
+    "bassinet",
+    "bassins",
+    "bassompierre",
+    "bastingages",
+    "bastion",
+    "bastions",
+    "basée",
+    "bataille",
+    "batailles",
+    "bataillon",
+    "bataillons",
+    "bateau",
+    "bateaux",
+    "batelier",
+    "bateliers",
+    "bathurst",
+    "bats",
+    "battaient",
+    "battait",
+    "battant",
+    "battante",
+    "battants",
+    "batte",
+    "battement",
+    "battements",
+    "battent",
+    "batterie",
+    "battez",
+    "battistin",
+    "battit",
+    "battrai",
+    "battrais",
+    "battras",
+    "battre",
+    "battrez",
+    "battriez",
+    "battu",
+    "battue",
+    "battus",
+    "batulcar",
+    "baume",
+    "bavard",
+    "bavardage",
+    "bavardages",
+    "bavarde",
+    "bavardes",
+    "bavards",
+    "bayadères",
+    "bayard",
+    "baylen",
+    "bazar",
+    "bazars",
+    "bazin",
+    "baïonnette",
+    "baïonnettes",
+    "beau",
+    "beaucoup",
+    "beauharnais",
+    "beaujolais",
+    "beaumarchais",
+    "beauté",
+    "beautés",
+    "beauvais",
+    "beauvaisis",
+    "beauvoisis",
+    "beaux",
+    "bec",
+    "becs",
+    "beefsteacks",
+    "bel",
+    "belagio",
+    "belgique",
+    "belgirate",
+    "belle",
+    "belles",
+    "belliqueux",
+    "belphégor",
+    "belvédère",
+    "benefaciendo",
+    "bengale",
+    "benjamin",
+    "benten",
+    "bentivoglio",
+    "benêt",
+    "berceau",
+    "bercer",
+    "bergère",
+    "bergères",
+    "bernard",
+    "berne",
+    "bertolotti",
+    "bertuccio",
+    "besace",
+    "besançon",
+    "besenval",
+    "besogne",
+    "besoin",
+    "besoins",
+    "betrays",
+    "bettina",
+    "beuglements",
+    "beurre",
+    "beuve",
+    "biais",
+    "biaiser",
+    "bible",
+    "bibliomane",
+    "bibliothèque",
+    "bibliothèques",
+    "biblique",
+    "bibliques",
+    "bielle",
+    "bien",
+    "bienfait",
+    "bienfaiteur",
+    "bienfaitrice",
+    "bienfaits",
+    "biens",
+    "bientôt",
+    "bienveillance",
+    "bienveillant",
+    "bienvenu",
+    "biftecks",
+    "bigarrée",
+    "bijou",
+    "bijoux",
+    "bilieuse",
+    "bilieux",
+    "billard",
+    "billards",
+    "billet",
+    "billets",
+    "binder",
+    "binet",
+    "biographie",
+    "birmingham",
+    "bisaïeul",
+    "biscaïens",
+    "biscuit",
+    "biscuits",
+    "bisogna",
+    "bisons",
+    "bisontine",
+    "bisontins",
+    "bisontium",
+    "bitter",
+    "bivouac",
+    "bivouacs",
+    "bivouaquaient",
+    "bizarre",
+    "bizarrerie",
+    "bizarres",
+    "bière",
+    "blackest",
+    "blafarde",
+    "blanc",
+    "blanche",
+    "blanches",
+    "blancheur",
+    "blanchie",
+    "blanchies",
+    "blanchis",
+    "blanchisseuse",
+    "blancs",
+    "bland",
+    "blanès",
+    "blason",
+    "blasphème",
+    "blasphémé",
+    "blessa",
+    "blessaient",
+    "blessant",
+    "blesse",
+    "blesser",
+    "blesserait",
+    "blessez",
+    "blessure",
+    "blessures",
+    "blessé",
+    "blessée",
+    "blessés",
+    "bleu",
+    "bleue",
+    "bleues",
+    "bleus",
+    "bleuâtre",
+    "bleuâtres",
+    "bloc",
+    "blocs",
+    "blond",
+    "blonde",
+    "blondin",
+    "blonds",
+    "blottie",
+    "blottirent",
+    "blottit",
+    "bluffs",
+    "blushes",
+    "blâma",
+    "blâmable",
+    "blâmables",
+    "blâmait",
+    "blâme",
+    "blâmer",
+    "blâmé",
+    "blâmée",
+    "blâmés",
+    "blé",
+    "blés",
+    "blême",
+    "boats",
+    "boeuf",
+    "boeufs",
+    "boire",
+    "bois",
+    "boiserie",
+    "boisson",
+    "boissons",
+    "boisé",
+    "boit",
+    "boiteux",
+    "bologne",
+    "bombace",
+    "bombardé",
+    "bombe",
+    "bombes",
+    "bombés",
+    "bon",
+    "bona",
+    "bonaparte",
+    "bonapartiste",
+    "bonaventure",
+    "bondir",
+    "bondissait",
+    "bondissant",
+    "bondit",
+    "bonds",
+    "bone",
+    "bonheur",
+    "bonheurs",
+    "bonhomie",
+    "bonhomme",
+    "boniface",
+    "bonne",
+    "bonnement",
+    "bonnes",
+    "bonnet",
+    "bonnets",
+    "bons",
+    "bonshommes",
+    "bonsoir",
+    "bonté",
+    "bontés",
+    "bonzeries",
+    "bonzes",
+    "booby",
+    "bord",
+    "borda",
+    "bordaient",
+    "bordait",
+    "bordeaux",
+    "bordent",
+    "bords",
+    "bordures",
+    "bordé",
+    "bordée",
+    "bordées",
+    "bordés",
+    "borgo",
+    "borna",
+    "bornaient",
+    "bornait",
+    "borne",
+    "bornes",
+    "borné",
+    "borone",
+    "borso",
+    "bosco",
+    "bosse",
+    "bosses",
+    "bossi",
+    "bossuet",
+    "botanique",
+    "botte",
+    "bottes",
+    "bottier",
+    "bouche",
+    "boucher",
+    "boucherie",
+    "bouchers",
+    "bouches",
+    "boucles",
+    "bouclez",
+    "bouclier",
+    "bouclés",
+    "bouddhisme",
+    "bouddhiste",
+    "bouder",
+    "boudeur",
+    "boudeurs",
+    "boue",
+    "bouffe",
+    "bouffes",
+    "bouffonnerie",
+    "bouffonneries",
+    "bouffée",
+    "bouffées",
+    "bougeait",
+    "bougeoir",
+    "bouger",
+    "bougera",
+    "bougez",
+    "bougie",
+    "bougies",
+    "bougon",
+    "bouillait",
+    "bouillant",
+    "bouillante",
+    "bouilli",
+    "bouillir",
+    "bouillon",
+    "boulangers",
+    "boule",
+    "bouledogue",
+    "boulet",
+    "boulets",
+    "boulettes",
+    "boulevard",
+    "bouleversa",
+    "bouleversaient",
+    "bouleverse",
+    "bouleversé",
+    "bouleversée",
+    "bouleversés",
+    "boulogne",
+    "boulons",
+    "boulot",
+    "bouquer",
+    "bouquet",
+    "bouquets",
+    "bourbeux",
+    "bourbier",
+    "bourbiers",
+    "bourbons",
+    "bourdon",
+    "bourdonnement",
+    "bourdonnements",
+    "bourg",
+    "bourgade",
+    "bourgades",
+    "bourgeois",
+    "bourgeoise",
+    "bourgeoises",
+    "bourgeoisie",
+    "bourgeons",
+    "bourgogne",
+    "bourguignon",
+    "bourguignons",
+    "bourrasque",
+    "bourrasques",
+    "bourreau",
+    "bourreaux",
+    "bourrelée",
+    "bourrèlements",
+    "bourrées",
+    "bourse",
+    "bourses",
+    "boursicot",
+    "bousculés",
+    "bout",
+    "boutade",
+    "bouteille",
+    "bouteilles",
+    "boutique",
+    "boutiques",
+    "boutiquiers",
+    "bouton",
+    "boutonnière",
+    "boutonné",
+    "boutons",
+    "bouts",
+    "bow",
+    "boxe",
+    "boîte",
+    "boîtes",
+    "bracelets",
+    "bradshaw",
+    "brahma",
+    "brahmanes",
+    "brahmanique",
+    "brahmaniques",
+    "branchages",
+    "branche",
+    "brandissant",
+    "brandy",
+    "branlante",
+    "branle",
+    "brantôme",
+    "bras",
+    "brasses",
+    "brasseur",
+    "bravade",
+    "bravait",
+    "brave",
+    "bravement",
+    "bravent",
+    "braver",
+    "braverais",
+    "braves",
+    "bravi",
+    "bravissimo",
+    "bravo",
+    "bravoure",
+    "bravé",
+    "bravée",
+    "bray",
+    "brebis",
+    "bref",
+    "brefs",
+    "brescia",
+    "bretagne",
+    "brevet",
+    "brevets",
+    "brick",
+    "bride",
+    "bridger",
+    "bridon",
+    "bridés",
+    "brigade",
+    "brigadier",
+    "brigadiers",
+    "brigand",
+    "brigands",
+    "brigantine",
+    "brigham",
+    "brighella",
+    "brilla",
+    "brillaient",
+    "brillait",
+    "brillamment",
+    "brillant",
+    "brillante",
+    "brillantes",
+    "brillants",
+    "brille",
+    "brillent",
+    "briller",
+    "brillèrent",
+    "brillé",
+    "brindisi",
+    "brins",
+    "brio",
+    "brique",
+    "briques",
+    "brisa",
+    "brisaient",
+    "brisait",
+    "brise",
+    "briser",
+    "brises",
+    "brisèrent",
+    "brisé",
+    "brisée",
+    "brisées",
+    "brisés",
+    "britannique",
+    "britannisée",
+    "british",
+    "broadway",
+    "brocanteur",
+    "brocart",
+    "brochant",
+    "brochure",
+    "brochées",
+    "brocs",
+    "broderie",
+    "broderies",
+    "brodé",
+    "brodées",
+    "brodés",
+    "broncher",
+    "bronché",
+    "bronze",
+    "brosser",
+    "brouette",
+    "brouettes",
+    "brougham",
+    "brouhaha",
+    "brouillaient",
+    "brouillais",
+    "brouillard",
+    "brouillards",
+    "brouille",
+    "brouiller",
+    "brouillerie",
+    "brouilleriez",
+    "brouillerons",
+    "brouilles",
+    "brouillon",
+    "brouillons",
+    "brouillé",
+    "brouillée",
+    "broussailles",
+    "bruissement",
+    "bruit",
+    "bruits",
+    "brume",
+    "brumes",
+    "brun",
+    "brune",
+    "bruno",
+    "bruns",
+    "brusque",
+    "brusquement",
+    "brusquer",
+    "brusques",
+    "brut",
+    "brutal",
+    "brutale",
+    "brutalement",
+    "brutalité",
+    "brutes",
+    "brutus",
+    "bruxelles",
+    "bruyamment",
+    "bruyant",
+    "bruyante",
+    "bruyantes",
+    "bruyants",
+    "brève",
+    "brèves",
+    "brésil",
+    "bréviaire",
+    "brûla",
+    "brûlaient",
+    "brûlait",
+    "brûlant",
+    "brûlante",
+    "brûlantes",
+    "brûle",
+    "brûler",
+    "brûlerait",
+    "brûlerez",
+    "brûlons",
+    "brûlure",
+    "brûlé",
+    "brûlée",
+    "brûlées",
+    "brûlés",
+    "bu",
+    "bubna",
+    "budget",
+    "bue",
+    "buffa",
+    "buffalos",
+    "buffet",
+    "buffles",
+    "buis",
+    "buissons",
+    "buli",
+    "bull",
+    "bulle",
+    "bulletin",
+    "bulletins",
+    "bulo",
+    "bundelkund",
+    "bungalow",
+    "bungalows",
+    "bunhyas",
+    "bunsby",
+    "buonaparte",
+    "buonaparté",
+    "burati",
+    "burdivan",
+    "burdwan",
+    "bureau",
+    "bureaucratique",
+    "bureaux",
+    "burhampour",
+    "burlesque",
+    "burlington",
+    "burning",
+    "busqué",
+    "buste",
+    "bustos",
+    "butcher",
+    "butte",
+    "buvait",
+    "buvez",
+    "buvions",
+    "buvons",
+    "buxar",
+    "byron",
+    "bâbord",
+    "bâillait",
+    "bâillant",
+    "bâillement",
+    "bâiller",
+    "bâillon",
+    "bâtardise",
+    "bâti",
+    "bâtie",
+    "bâties",
+    "bâtiment",
+    "bâtiments",
+    "bâtir",
+    "bâtis",
+    "bâtissait",
+    "bâtissant",
+    "bâtisses",
+    "bâtisseur",
+    "bâtit",
+    "bâton",
+    "bâtonne",
+    "bâtons",
+    "béante",
+    "béatitudes",
+    "bédouin",
+    "bégayait",
+    "béhar",
+    "békir",
+    "bélan",
+    "bénarès",
+    "bénis",
+    "bénit",
+    "bénite",
+    "bénitier",
+    "bénédiction",
+    "bénédictions",
+    "bénéfice",
+    "bénéfices",
+    "béranger",
+    "bérésina",
+    "bétail",
+    "bétel",
+    "bévue",
+    "bévues",
+    "bêchait",
+    "bêcher",
+    "bêché",
+    "bêta",
+    "bête",
+    "bêtement",
+    "bêtes",
+    "bêtise",
+    "bêtises",
+    "bûcher",
+    "c",
+    "cab",
+    "cabale",
+    "cabane",
+    "cabanes",
+    "cabaret",
+    "cabarets",
+    "cabine",
+    "cabines",
+    "cabinet",
+    "cabinets",
+    "cabotage",
+    "cabra",
+    "cabrait",
+    "cabrer",
+    "cabriolet",
+    "cacha",
+    "cachaient",
+    "cachais",
+    "cachait",
+    "cachant",
+    "cachemire",
+    "cachent",
+    "cacher",
+    "cachera",
+    "cacherai",
+    "cacherait",
+    "cacherons",
+    "cachet",
+    "cacheter",
+    "cachette",
+    "cachettes",
+    "cacheté",
+    "cachons",
+    "cachot",
+    "cachots",
+    "caché",
+    "cachée",
+    "cachées",
+    "cachés",
+    "cacolet",
+    "cacolets",
+    "cadavre",
+    "cadavres",
+    "cadeau",
+    "cadeaux",
+    "cadenabia",
+    "cadenas",
+    "cadet",
+    "cadets",
+    "cadette",
+    "cadran",
+    "cadre",
+    "cadres",
+    "café",
+    "caféiers",
+    "cafés",
+    "cage",
+    "cages",
+    "cagnola",
+    "cahier",
+    "cahutes",
+    "cailles",
+    "cailloux",
+    "caillé",
+    "caisse",
+    "caisses",
+    "caissier",
+    "caissons",
+    "cajoleries",
+    "calais",
+    "calcul",
+    "calculait",
+    "calculant",
+    "calculateur",
+    "calculer",
+    "calculs",
+    "calculé",
+    "calculée",
+    "calculées",
+    "calcutta",
+    "calembour",
+    "calembours",
+    "calendrier",
+    "californie",
+    "californienne",
+    "califourchon",
+    "callyan",
+    "calma",
+    "calme",
+    "calmer",
+    "calmerai",
+    "calmes",
+    "calmez",
+    "calmèrent",
+    "calomnie",
+    "calomnier",
+    "calomnieront",
+    "calomnies",
+    "calomnieuses",
+    "calomnié",
+    "calomniée",
+    "calomniées",
+    "calotin",
+    "calotte",
+    "calèche",
+    "camarade",
+    "camarades",
+    "camarilla",
+    "cambaye",
+    "cambodge",
+    "cambrai",
+    "cambrure",
+    "cameriere",
+    "camp",
+    "campagnards",
+    "campagne",
+    "campagnes",
+    "campaient",
+    "campé",
+    "camélias",
+    "camériste",
+    "canaille",
+    "canal",
+    "canapé",
+    "canards",
+    "cancer",
+    "candeur",
+    "candidat",
+    "candidats",
+    "candidature",
+    "candide",
+    "cangos",
+    "cani",
+    "caniches",
+    "canif",
+    "canne",
+    "cannelle",
+    "cannes",
+    "canon",
+    "canonique",
+    "canonnade",
+    "canonniers",
+    "canons",
+    "canot",
+    "canots",
+    "canova",
+    "canta",
+    "cantate",
+    "cantatrice",
+    "cantilène",
+    "cantinière",
+    "cantiques",
+    "canton",
+    "cantonnées",
+    "cantons",
+    "capacité",
+    "caparaçonnés",
+    "cape",
+    "capharnaüm",
+    "capillaire",
+    "capitaine",
+    "capitaines",
+    "capitale",
+    "capitani",
+    "capitulation",
+    "capon",
+    "caporal",
+    "capot",
+    "caprice",
+    "caprices",
+    "capricieuse",
+    "capricieusement",
+    "capricieuses",
+    "captivait",
+    "captivité",
+    "capuche",
+    "capuchons",
+    "capucin",
+    "carabine",
+    "carabines",
+    "carabiniers",
+    "caractère",
+    "caractères",
+    "caractérisait",
+    "caractérise",
+    "caractéristique",
+    "carafe",
+    "carbonaro",
+    "carburé",
+    "carcasse",
+    "cardif",
+    "cardinal",
+    "cardinaux",
+    "caressa",
+    "caressait",
+    "caressant",
+    "caressants",
+    "caresse",
+    "caressent",
+    "caresser",
+    "caresseraient",
+    "caresses",
+    "cargaison",
+    "caricature",
+    "carlino",
+    "carlo",
+    "carlone",
+    "carnassiers",
+    "carnatic",
+    "carnaval",
+    "carnet",
+    "carnot",
+    "caro",
+    "caroline",
+    "caron",
+    "caroubiers",
+    "carpe",
+    "carreau",
+    "carreaux",
+    "carrefours",
+    "carrière",
+    "carrières",
+    "carrosse",
+    "carrosses",
+    "carré",
+    "carrée",
+    "carrément",
+    "carrés",
+    "cars",
+    "carson",
+    "carta",
+    "carte",
+    "cartes",
+    "carthage",
+    "carton",
+    "cartons",
+    "cartouche",
+    "cartouches",
+    "casa",
+    "casal",
+    "casanier",
+    "cascade",
+    "cascades",
+    "caserne",
+    "casi",
+    "casimir",
+    "casino",
+    "casque",
+    "casques",
+    "cassa",
+    "cassait",
+    "cassandre",
+    "cassano",
+    "casse",
+    "cassent",
+    "casser",
+    "casserais",
+    "casseroles",
+    "cassette",
+    "cassine",
+    "cassât",
+    "cassé",
+    "cassée",
+    "cassées",
+    "castanède",
+    "caste",
+    "castelnovo",
+    "casti",
+    "casto",
+    "castorine",
+    "castres",
+    "castries",
+    "casuel",
+    "casuistes",
+    "catanzara",
+    "catastrophe",
+    "catena",
+    "cathelineau",
+    "catherine",
+    "cathédrale",
+    "cattaro",
+    "catéchisme",
+    "cauchemar",
+    "cauchemars",
+    "caudataire",
+    "causa",
+    "causait",
+    "causant",
+    "causer",
+    "causerait",
+    "causeur",
+    "caustique",
+    "causèrent",
+    "causé",
+    "causée",
+    "causés",
+    "cauteleuse",
+    "cauteleux",
+    "cautions",
+    "cavalerie",
+    "cavalier",
+    "cavaliers",
+    "cavallo",
+    "caverne",
+    "caves",
+    "cavi",
+    "caylus",
+    "ce",
+    "ceci",
+    "ceignait",
+    "ceinture",
+    "cela",
+    "cella",
+    "celle",
+    "celles",
+    "cellier",
+    "cellule",
+    "celui",
+    "cendres",
+    "cendré",
+    "cendrés",
+    "cenis",
+    "censure",
+    "cent",
+    "centaine",
+    "centaines",
+    "centime",
+    "centimes",
+    "centième",
+    "centièmes",
+    "centrale",
+    "centres",
+    "cents",
+    "centuplais",
+    "centuplait",
+    "centuplée",
+    "cependant",
+    "cercle",
+    "cercles",
+    "cerf",
+    "cerisiers",
+    "certaine",
+    "certainement",
+    "certaines",
+    "certains",
+    "certes",
+    "certi",
+    "certificat",
+    "certificats",
+    "certifiera",
+    "certitude",
+    "certitudes",
+    "cerveau",
+    "cervelle",
+    "ces",
+    "cesare",
+    "cessa",
+    "cessaient",
+    "cessait",
+    "cessant",
+    "cessante",
+    "cessation",
+    "cesse",
+    "cesser",
+    "cessera",
+    "cesserais",
+    "cesseras",
+    "cesseront",
+    "cessiez",
+    "cessions",
+    "cessèrent",
+    "cessé",
+    "cet",
+    "cette",
+    "ceux",
+    "ceylan",
+    "ceylandais",
+    "chacun",
+    "chacune",
+    "chagrin",
+    "chagrine",
+    "chagriner",
+    "chagrins",
+    "chaire",
+    "chaise",
+    "chaises",
+    "chalands",
+    "chaleur",
+    "chaleurs",
+    "chalvet",
+    "chamarrées",
+    "chamarrés",
+    "chambellan",
+    "chambellans",
+    "chambranle",
+    "chambre",
+    "chambres",
+    "chambrette",
+    "chameau",
+    "champ",
+    "champagne",
+    "champions",
+    "champs",
+    "champêtre",
+    "chancelant",
+    "chancelier",
+    "chancellerie",
+    "chances",
+    "chanceux",
+    "chandelle",
+    "chandernagor",
+    "changea",
+    "changeaient",
+    "changeait",
+    "changeant",
+    "changeante",
+    "changeants",
+    "changement",
+    "changements",
+    "changent",
+    "changer",
+    "changerait",
+    "changeront",
+    "changèrent",
+    "changé",
+    "changée",
+    "changés",
+    "chanoine",
+    "chanoines",
+    "chanoinesse",
+    "chanson",
+    "chansons",
+    "chant",
+    "chanta",
+    "chantai",
+    "chantaient",
+    "chantait",
+    "chantant",
+    "chante",
+    "chanter",
+    "chanteur",
+    "chanteuses",
+    "chantiez",
+    "chantonnant",
+    "chants",
+    "chantèrent",
+    "chanté",
+    "chantée",
+    "chantés",
+    "chanvre",
+    "chaos",
+    "chap",
+    "chapeau",
+    "chapeaux",
+    "chapelet",
+    "chapelle",
+    "chapes",
+    "chapitre",
+    "chapitres",
+    "chapons",
+    "chaque",
+    "charade",
+    "charbon",
+    "charbonnier",
+    "charcot",
+    "chargea",
+    "chargeaient",
+    "chargeait",
+    "chargeant",
+    "chargement",
+    "charger",
+    "chargera",
+    "chargerais",
+    "chargerait",
+    "charges",
+    "chargez",
+    "chargèrent",
+    "chargé",
+    "chargée",
+    "chargées",
+    "chargés",
+    "charing",
+    "charitable",
+    "charité",
+    "charlatanisme",
+    "charlatans",
+    "charlemagne",
+    "charleroi",
+    "charlotte",
+    "charma",
+    "charmant",
+    "charmante",
+    "charmantes",
+    "charmants",
+    "charme",
+    "charmer",
+    "charmes",
+    "charmier",
+    "charmé",
+    "charmée",
+    "charpente",
+    "charpentier",
+    "charrette",
+    "charrettes",
+    "charron",
+    "charrues",
+    "charte",
+    "chartreuse",
+    "chartreux",
+    "chas",
+    "chassa",
+    "chassaient",
+    "chassait",
+    "chassant",
+    "chasse",
+    "chassent",
+    "chasser",
+    "chassera",
+    "chasseur",
+    "chasseurs",
+    "chassez",
+    "chassé",
+    "chassée",
+    "chassés",
+    "chasuble",
+    "chasubles",
+    "chatouillement",
+    "chatouilleuse",
+    "chats",
+    "chaud",
+    "chaude",
+    "chaudement",
+    "chaudes",
+    "chaudière",
+    "chaudières",
+    "chauffage",
+    "chauffaient",
+    "chauffer",
+    "chauffeur",
+    "chauffeurs",
+    "chauffez",
+    "chauffons",
+    "chauffé",
+    "chauffée",
+    "chaulnes",
+    "chaume",
+    "chaumière",
+    "chaumières",
+    "chaussa",
+    "chaussettes",
+    "chaussure",
+    "chaussures",
+    "chaussé",
+    "chaussée",
+    "chaussés",
+    "chauve",
+    "chaux",
+    "chavira",
+    "chavirer",
+    "chazel",
+    "chaîne",
+    "chaînes",
+    "chaînon",
+    "chaînons",
+    "che",
+    "chef",
+    "chefs",
+    "chekina",
+    "chelem",
+    "chelles",
+    "chemin",
+    "cheminant",
+    "chemins",
+    "cheminée",
+    "cheminées",
+    "chemise",
+    "chemises",
+    "chenu",
+    "cher",
+    "chercha",
+    "cherchaient",
+    "cherchais",
+    "cherchait",
+    "cherchant",
+    "cherche",
+    "cherchent",
+    "chercher",
+    "cherchera",
+    "chercherai",
+    "chercherait",
+    "chercherez",
+    "cherches",
+    "cherchez",
+    "cherchons",
+    "cherchèrent",
+    "cherché",
+    "cherchée",
+    "chers",
+    "chester",
+    "cheval",
+    "chevaleresque",
+    "chevalerie",
+    "chevalier",
+    "chevaliers",
+    "chevaux",
+    "chevelure",
+    "chevet",
+    "cheveu",
+    "cheveux",
+    "chevreuse",
+    "cheyenne",
+    "chez",
+    "chiaramonti",
+    "chicago",
+    "chicorée",
+    "chien",
+    "chiens",
+    "chiffonna",
+    "chiffonnait",
+    "chiffons",
+    "chiffre",
+    "chiffrer",
+    "chiffres",
+    "chiffrée",
+    "chiffrées",
+    "chili",
+    "chimère",
+    "chimères",
+    "chimériques",
+    "china",
+    "chine",
+    "chinois",
+    "chinoise",
+    "chinoises",
+    "chiper",
+    "chiperait",
+    "chirurgicales",
+    "chirurgie",
+    "chirurgien",
+    "chlemm",
+    "choc",
+    "chocolat",
+    "choeur",
+    "choeurs",
+    "choisi",
+    "choisie",
+    "choisies",
+    "choisir",
+    "choisirai",
+    "choisirez",
+    "choisis",
+    "choisissait",
+    "choisissez",
+    "choisit",
+    "choix",
+    "cholin",
+    "choqua",
+    "choquait",
+    "choquante",
+    "choque",
+    "choquer",
+    "choquera",
+    "choqueraient",
+    "choquèrent",
+    "choqué",
+    "choquée",
+    "choqués",
+    "chorus",
+    "choses",
+    "chouart",
+    "choucroute",
+    "choyé",
+    "christ",
+    "christianisme",
+    "chromatique",
+    "chronicle",
+    "chronique",
+    "chronomètre",
+    "chronomètres",
+    "chronométriquement",
+    "chrétien",
+    "chrétienne",
+    "chrétiennes",
+    "chrétiens",
+    "chunar",
+    "chut",
+    "chute",
+    "chutes",
+    "chuté",
+    "châle",
+    "châlon",
+    "châlons",
+    "châsse",
+    "châssis",
+    "châtaignes",
+    "châtaignier",
+    "châtaigniers",
+    "châtain",
+    "châtains",
+    "château",
+    "châteaux",
+    "châtier",
+    "châtiment",
+    "chènevière",
+    "chèque",
+    "chèques",
+    "chère",
+    "chèrement",
+    "chères",
+    "chèvrefeuilles",
+    "chèvres",
+    "chékina",
+    "chélan",
+    "chéri",
+    "chérie",
+    "chérir",
+    "chéris",
+    "chétif",
+    "chétifs",
+    "chétive",
+    "chêne",
+    "chênes",
+    "ci",
+    "ciceri",
+    "cicéron",
+    "cicéronien",
+    "ciel",
+    "cierge",
+    "cierges",
+    "cieux",
+    "cigales",
+    "cigare",
+    "cigares",
+    "ciguë",
+    "cils",
+    "cimabué",
+    "cimarosa",
+    "cime",
+    "cimes",
+    "cimetière",
+    "cinnamome",
+    "cinq",
+    "cinquantaine",
+    "cinquante",
+    "cinquantième",
+    "cinquième",
+    "cipayes",
+    "circonférence",
+    "circonspection",
+    "circonstance",
+    "circonstances",
+    "circonstancié",
+    "circonvenir",
+    "circula",
+    "circulaient",
+    "circulaire",
+    "circulairement",
+    "circulaires",
+    "circulant",
+    "circulante",
+    "circulation",
+    "circuler",
+    "circulerait",
+    "circumnavigation",
+    "circé",
+    "cire",
+    "cirque",
+    "cirques",
+    "cisalpin",
+    "cisalpine",
+    "cisco",
+    "ciseaux",
+    "cita",
+    "citadelle",
+    "citaient",
+    "citait",
+    "citant",
+    "citation",
+    "citations",
+    "cite",
+    "citer",
+    "citernes",
+    "citoyen",
+    "citoyennes",
+    "citoyens",
+    "citronnier",
+    "cité",
+    "citée",
+    "cités",
+    "civil",
+    "civile",
+    "civilisation",
+    "civilisées",
+    "civilisés",
+    "civils",
+    "clair",
+    "claire",
+    "clairement",
+    "claires",
+    "clairière",
+    "clairs",
+    "clairvoyance",
+    "clairvoyant",
+    "clairvoyante",
+    "clairvoyants",
+    "clameurs",
+    "clandestin",
+    "clara",
+    "claret",
+    "clarté",
+    "classait",
+    "classe",
+    "classé",
+    "classés",
+    "clayonnage",
+    "clef",
+    "clefs",
+    "clerc",
+    "clercs",
+    "clergé",
+    "clignant",
+    "climat",
+    "clin",
+    "clinquant",
+    "clippers",
+    "clique",
+    "cliquettes",
+    "cloaque",
+    "cloche",
+    "clocher",
+    "clochers",
+    "cloches",
+    "clochettes",
+    "clopant",
+    "clopin",
+    "clore",
+    "clos",
+    "clou",
+    "cloua",
+    "clous",
+    "cloutier",
+    "cloué",
+    "clouée",
+    "clown",
+    "clowns",
+    "cloître",
+    "clélia",
+    "clémence",
+    "clément",
+    "clôture",
+    "co",
+    "coach",
+    "coaches",
+    "coadjuteur",
+    "coblentz",
+    "cocarde",
+    "cocher",
+    "cochers",
+    "cochinchine",
+    "cochère",
+    "coconasso",
+    "cocotiers",
+    "cocotte",
+    "coeur",
+    "coeurs",
+    "coffre",
+    "coffres",
+    "coffré",
+    "cohue",
+    "coidet",
+    "coiffa",
+    "coiffure",
+    "coiffé",
+    "coiffée",
+    "coiffés",
+    "coin",
+    "coins",
+    "cola",
+    "colaba",
+    "coldness",
+    "colfax",
+    "colifichet",
+    "colimaçon",
+    "colique",
+    "colis",
+    "collaborateurs",
+    "collait",
+    "collants",
+    "colle",
+    "collecteur",
+    "collecteurs",
+    "coller",
+    "collet",
+    "collier",
+    "colliers",
+    "colline",
+    "collines",
+    "colloque",
+    "collège",
+    "collègue",
+    "collègues",
+    "collé",
+    "collés",
+    "colmar",
+    "colombes",
+    "colombier",
+    "colonels",
+    "colonie",
+    "colonisateur",
+    "coloniser",
+    "colonnade",
+    "colonne",
+    "colonnes",
+    "colorado",
+    "coloris",
+    "colorié",
+    "colorno",
+    "coloré",
+    "colorée",
+    "colossal",
+    "colossale",
+    "colossales",
+    "colpa",
+    "colt",
+    "columbus",
+    "colère",
+    "colères",
+    "combat",
+    "combats",
+    "combattaient",
+    "combattants",
+    "combattis",
+    "combattit",
+    "combattre",
+    "combattu",
+    "combattue",
+    "combien",
+    "combina",
+    "combinaisons",
+    "combinant",
+    "combiné",
+    "combinés",
+    "comble",
+    "combler",
+    "comblerait",
+    "combles",
+    "comblé",
+    "combustible",
+    "comestible",
+    "comestibles",
+    "comique",
+    "comiques",
+    "comité",
+    "commanda",
+    "commandaient",
+    "commandait",
+    "commandant",
+    "commande",
+    "commandement",
+    "commandements",
+    "commander",
+    "commandera",
+    "commanderez",
+    "commandons",
+    "commandé",
+    "commandée",
+    "comme",
+    "commence",
+    "commencement",
+    "commencements",
+    "commencent",
+    "commencer",
+    "commencera",
+    "commencerai",
+    "commencerait",
+    "commencez",
+    "commencèrent",
+    "commencé",
+    "commencée",
+    "commencés",
+    "commensal",
+    "commentaire",
+    "commentaires",
+    "commentateurs",
+    "commenter",
+    "commentée",
+    "commentés",
+    "commença",
+    "commençai",
+    "commençaient",
+    "commençait",
+    "commençant",
+    "commençons",
+    "commerce",
+    "commerciale",
+    "commerçante",
+    "commerçantes",
+    "commerçants",
+    "commets",
+    "commette",
+    "commettent",
+    "commettra",
+    "commettrais",
+    "commettre",
+    "commis",
+    "commise",
+    "commissaire",
+    "commission",
+    "commissions",
+    "commisération",
+    "commode",
+    "commodément",
+    "commotion",
+    "commuer",
+    "commuera",
+    "commun",
+    "communauté",
+    "communaux",
+    "commune",
+    "communes",
+    "communicatif",
+    "communications",
+    "communicative",
+    "communie",
+    "communion",
+    "communiqua",
+    "communiquaient",
+    "communiquait",
+    "communique",
+    "communiquer",
+    "communiqué",
+    "communiquée",
+    "communs",
+    "communément",
+    "commutation",
+    "commuée",
+    "compagne",
+    "compagnie",
+    "compagnies",
+    "compagnon",
+    "compagnons",
+    "compara",
+    "comparable",
+    "comparaient",
+    "comparaison",
+    "comparait",
+    "comparant",
+    "comparativement",
+    "comparaîtrez",
+    "comparer",
+    "compartiment",
+    "compartiments",
+    "comparé",
+    "comparée",
+    "compas",
+    "compassé",
+    "compassées",
+    "compatibles",
+    "compatriote",
+    "compatriotes",
+    "compensation",
+    "compense",
+    "compenser",
+    "compensé",
+    "complaisait",
+    "complaisamment",
+    "complaisance",
+    "complaisances",
+    "complaisant",
+    "complaisante",
+    "complaisantes",
+    "complaisants",
+    "complet",
+    "complets",
+    "complication",
+    "complice",
+    "complices",
+    "complicité",
+    "compliment",
+    "complimenteur",
+    "complimenteurs",
+    "complimenteuse",
+    "compliments",
+    "complimenté",
+    "complique",
+    "compliqué",
+    "compliquée",
+    "compliquées",
+    "compliqués",
+    "complot",
+    "complète",
+    "complètement",
+    "complètes",
+    "complément",
+    "complétaient",
+    "complétait",
+    "compléter",
+    "complété",
+    "componction",
+    "comporta",
+    "comportait",
+    "comporte",
+    "comporté",
+    "comportée",
+    "composa",
+    "composaient",
+    "composait",
+    "composant",
+    "composent",
+    "composer",
+    "compositions",
+    "composé",
+    "composée",
+    "composées",
+    "comprato",
+    "comprenaient",
+    "comprenait",
+    "comprenant",
+    "comprend",
+    "comprendra",
+    "comprendrai",
+    "comprendrait",
+    "comprendre",
+    "comprendrez",
+    "comprends",
+    "comprenez",
+    "comprenne",
+    "comprennent",
+    "comprimant",
+    "comprimées",
+    "comprimés",
+    "comprirent",
+    "compris",
+    "comprise",
+    "comprit",
+    "compromet",
+    "compromets",
+    "compromettait",
+    "compromettant",
+    "compromettante",
+    "compromettantes",
+    "compromette",
+    "compromettra",
+    "compromettrais",
+    "compromettre",
+    "compromis",
+    "compromise",
+    "compromises",
+    "comprît",
+    "compta",
+    "comptabilité",
+    "comptaient",
+    "comptais",
+    "comptait",
+    "comptant",
+    "compte",
+    "comptent",
+    "compter",
+    "compterai",
+    "comptes",
+    "comptez",
+    "comptiez",
+    "comptoir",
+    "comptoirs",
+    "comptons",
+    "comptât",
+    "comptèrent",
+    "compté",
+    "comptées",
+    "comptés",
+    "compère",
+    "comte",
+    "comtes",
+    "comtesse",
+    "comtois",
+    "comtoise",
+    "comté",
+    "comtés",
+    "comédie",
+    "comédien",
+    "comédiens",
+    "comédies",
+    "concentrer",
+    "concentré",
+    "concentrés",
+    "concernaient",
+    "concernant",
+    "concerné",
+    "concert",
+    "concerts",
+    "concerté",
+    "concession",
+    "concessions",
+    "concevaient",
+    "concevait",
+    "concevez",
+    "concevoir",
+    "concierge",
+    "concilier",
+    "concilié",
+    "concises",
+    "concitoyens",
+    "conclu",
+    "concluaient",
+    "concluantes",
+    "concluants",
+    "conclue",
+    "conclure",
+    "conclurent",
+    "conclusion",
+    "conclusions",
+    "conclut",
+    "concordance",
+    "concordant",
+    "concordat",
+    "concourent",
+    "concours",
+    "concurrents",
+    "condamnable",
+    "condamnait",
+    "condamnant",
+    "condamnation",
+    "condamne",
+    "condamnent",
+    "condamner",
+    "condamneront",
+    "condamné",
+    "condamnée",
+    "condamnés",
+    "condescendance",
+    "condillac",
+    "condiments",
+    "conditionnée",
+    "condoléance",
+    "conducteur",
+    "conduira",
+    "conduirait",
+    "conduire",
+    "conduisaient",
+    "conduisait",
+    "conduisant",
+    "conduisent",
+    "conduisez",
+    "conduisit",
+    "conduit",
+    "conduite",
+    "conduites",
+    "conduits",
+    "condé",
+    "confection",
+    "confessant",
+    "confesser",
+    "confesserai",
+    "confesseur",
+    "confesseurs",
+    "confession",
+    "confessionnal",
+    "confessionnaux",
+    "confessions",
+    "confia",
+    "confiance",
+    "confidence",
+    "confidences",
+    "confident",
+    "confidente",
+    "confier",
+    "confiner",
+    "confiné",
+    "confirma",
+    "confirmait",
+    "confirmer",
+    "confirmèrent",
+    "confirmée",
+    "confiscation",
+    "confisque",
+    "confisqué",
+    "confit",
+    "confié",
+    "confiée",
+    "confiées",
+    "confiés",
+    "confluent",
+    "confondait",
+    "confonde",
+    "confondent",
+    "confondre",
+    "confondu",
+    "confondus",
+    "conformait",
+    "conforme",
+    "conformer",
+    "confort",
+    "confortable",
+    "confortablement",
+    "confortables",
+    "confrère",
+    "confrères",
+    "confrérie",
+    "confucius",
+    "confus",
+    "confuse",
+    "confusions",
+    "confusément",
+    "confédéré",
+    "conférait",
+    "conférence",
+    "conférences",
+    "conférer",
+    "congestionnée",
+    "congratulation",
+    "congrès",
+    "congréganiste",
+    "congréganistes",
+    "congrégation",
+    "congé",
+    "congédia",
+    "congédiant",
+    "congédié",
+    "congédiés",
+    "congénères",
+    "conjecture",
+    "conjecturer",
+    "conjectures",
+    "conjonctions",
+    "conjugal",
+    "conjugale",
+    "conjuguer",
+    "conjurait",
+    "conjurant",
+    "conjure",
+    "conjurer",
+    "conjurée",
+    "connais",
+    "connaissaient",
+    "connaissais",
+    "connaissait",
+    "connaissance",
+    "connaissances",
+    "connaissent",
+    "connaisseurs",
+    "connaissez",
+    "connaissiez",
+    "connaissons",
+    "connaitre",
+    "connaît",
+    "connaîtrait",
+    "connaître",
+    "connivence",
+    "connu",
+    "connue",
+    "connues",
+    "connus",
+    "connut",
+    "conquis",
+    "conquise",
+    "conquérir",
+    "conquête",
+    "conradin",
+    "consacrait",
+    "consacre",
+    "consacrer",
+    "consacrerai",
+    "consacré",
+    "consacrée",
+    "consacrées",
+    "consacrés",
+    "conscience",
+    "consciencieusement",
+    "conscription",
+    "conscrit",
+    "conseil",
+    "conseilla",
+    "conseillaient",
+    "conseillait",
+    "conseille",
+    "conseiller",
+    "conseillerai",
+    "conseillerais",
+    "conseillerait",
+    "conseilleriez",
+    "conseillers",
+    "conseillé",
+    "conseillée",
+    "conseils",
+    "consens",
+    "consentait",
+    "consente",
+    "consentement",
+    "consentez",
+    "consenti",
+    "consentions",
+    "consentir",
+    "consentirai",
+    "consentirais",
+    "consentirent",
+    "consentit",
+    "conservait",
+    "conservant",
+    "conservation",
+    "conservatoire",
+    "conserve",
+    "conservent",
+    "conserver",
+    "conserverai",
+    "conserves",
+    "conservé",
+    "conservée",
+    "conservées",
+    "considère",
+    "considéra",
+    "considérable",
+    "considérablement",
+    "considérables",
+    "considérait",
+    "considérant",
+    "considération",
+    "considérations",
+    "considérer",
+    "considérerais",
+    "considéré",
+    "considérée",
+    "considérées",
+    "considérés",
+    "consigne",
+    "consistait",
+    "consistance",
+    "consiste",
+    "consister",
+    "consola",
+    "consolaient",
+    "consolait",
+    "consolation",
+    "consolations",
+    "consoler",
+    "consolé",
+    "consolée",
+    "consomma",
+    "consommateur",
+    "consommateurs",
+    "consommer",
+    "consommez",
+    "consommé",
+    "consommée",
+    "conspirais",
+    "conspirante",
+    "conspirateur",
+    "conspirateurs",
+    "conspiration",
+    "conspirer",
+    "conspiré",
+    "conspué",
+    "constamment",
+    "constance",
+    "constante",
+    "constantin",
+    "constata",
+    "constater",
+    "constaté",
+    "consternation",
+    "consterné",
+    "consternée",
+    "constituait",
+    "constitue",
+    "constituer",
+    "constituera",
+    "constitution",
+    "constitutionnel",
+    "constitutionnels",
+    "constitué",
+    "construire",
+    "construisait",
+    "construit",
+    "construite",
+    "construites",
+    "construits",
+    "consul",
+    "consulaire",
+    "consulaires",
+    "consulat",
+    "consulta",
+    "consultait",
+    "consultant",
+    "consultative",
+    "consulter",
+    "consulterais",
+    "consultons",
+    "consulté",
+    "consultés",
+    "consumé",
+    "conséquemment",
+    "conséquence",
+    "conséquences",
+    "conséquent",
+    "conta",
+    "contagieux",
+    "contais",
+    "contait",
+    "contarini",
+    "conte",
+    "contemplait",
+    "contemplant",
+    "contemplation",
+    "contemple",
+    "contempler",
+    "contemplerait",
+    "contemporains",
+    "contenaient",
+    "contenait",
+    "contenance",
+    "contenant",
+    "contenir",
+    "contenta",
+    "contentait",
+    "contentant",
+    "contente",
+    "contentement",
+    "contenterait",
+    "contenu",
+    "contenue",
+    "contenues",
+    "contenus",
+    "conter",
+    "conterai",
+    "conterait",
+    "contes",
+    "contessina",
+    "contester",
+    "conti",
+    "contiennent",
+    "contient",
+    "contine",
+    "continent",
+    "continental",
+    "continents",
+    "contino",
+    "continu",
+    "continua",
+    "continuait",
+    "continuant",
+    "continuel",
+    "continuelle",
+    "continuellement",
+    "continuelles",
+    "continuels",
+    "continuer",
+    "continuera",
+    "continuerait",
+    "continueront",
+    "continuez",
+    "continuité",
+    "continuons",
+    "continué",
+    "contorsions",
+    "contour",
+    "contournait",
+    "contournant",
+    "contourne",
+    "contours",
+    "contractait",
+    "contracter",
+    "contractions",
+    "contracté",
+    "contractée",
+    "contractés",
+    "contradictoirement",
+    "contradictoires",
+    "contraindre",
+    "contraint",
+    "contrainte",
+    "contraintes",
+    "contraire",
+    "contraires",
+    "contraria",
+    "contrariait",
+    "contrariant",
+    "contrarie",
+    "contrarier",
+    "contrariera",
+    "contrariez",
+    "contrarié",
+    "contrariée",
+    "contrariés",
+    "contrariété",
+    "contrariétés",
+    "contrastait",
+    "contraste",
+    "contrastes",
+    "contrat",
+    "contravention",
+    "contraventions",
+    "contre",
+    "contrebandier",
+    "contrebandiers",
+    "contrebas",
+    "contrebasse",
+    "contrebasses",
+    "contrecoup",
+    "contrecoups",
+    "contredanse",
+    "contredanses",
+    "contredirait",
+    "contredire",
+    "contredisait",
+    "contredisant",
+    "contredit",
+    "contrefaite",
+    "contrefera",
+    "contrefort",
+    "contresens",
+    "contresigner",
+    "contretemps",
+    "contribua",
+    "contribuai",
+    "contribuer",
+    "contribué",
+    "contrit",
+    "contrition",
+    "contrée",
+    "contrées",
+    "contrôle",
+    "contrôler",
+    "contrôles",
+    "contumace",
+    "contusions",
+    "conté",
+    "contée",
+    "contînt",
+    "convaincre",
+    "convaincu",
+    "convaincue",
+    "convenable",
+    "convenablement",
+    "convenables",
+    "convenaient",
+    "convenait",
+    "convenance",
+    "convenances",
+    "convenez",
+    "convenir",
+    "convenons",
+    "convenu",
+    "convenue",
+    "convenus",
+    "converger",
+    "conversations",
+    "convertie",
+    "convertir",
+    "convertis",
+    "conviction",
+    "convictions",
+    "conviendra",
+    "conviendrait",
+    "conviendrez",
+    "conviendrons",
+    "convienne",
+    "conviennent",
+    "conviens",
+    "convient",
+    "convier",
+    "convint",
+    "convives",
+    "convocation",
+    "convoi",
+    "convois",
+    "convoitise",
+    "convulsif",
+    "convulsifs",
+    "convulsion",
+    "convulsionnait",
+    "convulsive",
+    "convulsivement",
+    "convînt",
+    "conçois",
+    "conçoit",
+    "conçu",
+    "conçue",
+    "conçut",
+    "coolies",
+    "copeaux",
+    "copiait",
+    "copiant",
+    "copie",
+    "copient",
+    "copier",
+    "copieusement",
+    "copiez",
+    "copiste",
+    "copié",
+    "copiée",
+    "coq",
+    "coque",
+    "coquets",
+    "coquette",
+    "coquetterie",
+    "coquetteries",
+    "coquille",
+    "coquin",
+    "coquine",
+    "coquinerie",
+    "coquineries",
+    "coquins",
+    "coran",
+    "corbeau",
+    "corbeaux",
+    "corbeille",
+    "corday",
+    "corde",
+    "cordes",
+    "cordon",
+    "cordons",
+    "corea",
+    "cormorans",
+    "cornac",
+    "corne",
+    "corneille",
+    "corneilles",
+    "cornelia",
+    "cornelii",
+    "cornes",
+    "corniche",
+    "cornichon",
+    "cornwallis",
+    "corporation",
+    "corps",
+    "corrals",
+    "correcte",
+    "correctement",
+    "correctif",
+    "correction",
+    "correctionnelle",
+    "correspondait",
+    "correspondance",
+    "correspondances",
+    "correspondant",
+    "correspondants",
+    "correspondre",
+    "corridor",
+    "corridors",
+    "corrigeait",
+    "corriger",
+    "corrigerez",
+    "corrigée",
+    "corrodant",
+    "corrompre",
+    "corrompu",
+    "corrosif",
+    "corruption",
+    "corrège",
+    "cors",
+    "corso",
+    "cortellate",
+    "cortège",
+    "corvée",
+    "cosa",
+    "cosaques",
+    "cosi",
+    "cosmopolite",
+    "costume",
+    "costumes",
+    "cotait",
+    "cote",
+    "coteaux",
+    "coterie",
+    "coteries",
+    "coton",
+    "cotonnades",
+    "cotons",
+    "cotre",
+    "cotte",
+    "cotée",
+    "cou",
+    "coucha",
+    "couchait",
+    "couchant",
+    "couche",
+    "couchent",
+    "coucher",
+    "coucherai",
+    "coucherait",
+    "couchettes",
+    "couchez",
+    "couchèrent",
+    "couché",
+    "couchée",
+    "couchés",
+    "coude",
+    "coudes",
+    "coudre",
+    "coudrier",
+    "couds",
+    "coulaient",
+    "coulait",
+    "coulant",
+    "coule",
+    "couler",
+    "couleur",
+    "couleurs",
+    "coulisse",
+    "coulisses",
+    "couloirs",
+    "coulon",
+    "coulèrent",
+    "coulé",
+    "council",
+    "coup",
+    "coupa",
+    "coupable",
+    "coupables",
+    "coupaient",
+    "coupait",
+    "coupant",
+    "coupe",
+    "coupent",
+    "couper",
+    "couperosée",
+    "coupes",
+    "coupez",
+    "couples",
+    "couplet",
+    "couplets",
+    "coups",
+    "coupure",
+    "coupât",
+    "coupé",
+    "coupée",
+    "coupées",
+    "coupés",
+    "cour",
+    "courage",
+    "courages",
+    "courageuse",
+    "courageusement",
+    "courageuses",
+    "courageux",
+    "couraient",
+    "courait",
+    "courant",
+    "courants",
+    "courbait",
+    "courbe",
+    "courber",
+    "courbes",
+    "courbure",
+    "courbé",
+    "courbés",
+    "coure",
+    "courent",
+    "coureur",
+    "coureurs",
+    "courez",
+    "courier",
+    "courir",
+    "couronnaient",
+    "couronne",
+    "couronnement",
+    "couronnent",
+    "couronner",
+    "couronnes",
+    "couronné",
+    "couronnées",
+    "courons",
+    "courrais",
+    "courrez",
+    "courrier",
+    "courriers",
+    "courroucés",
+    "courroux",
+    "cours",
+    "courses",
+    "courte",
+    "courtes",
+    "courtier",
+    "courtiers",
+    "courtine",
+    "courtisan",
+    "courtisanerie",
+    "courtisanesque",
+    "courtisans",
+    "courts",
+    "couru",
+    "coururent",
+    "courus",
+    "courut",
+    "courût",
+    "cousait",
+    "cousine",
+    "cousines",
+    "coussin",
+    "coussins",
+    "cousu",
+    "couteau",
+    "couteaux",
+    "couter",
+    "coutil",
+    "coutume",
+    "coutumes",
+    "couvent",
+    "couvents",
+    "couvert",
+    "couverte",
+    "couvertes",
+    "couverts",
+    "couverture",
+    "couvertures",
+    "couvraient",
+    "couvrait",
+    "couvrant",
+    "couvre",
+    "couvrent",
+    "couvrir",
+    "couvrira",
+    "couvrit",
+    "coïncidence",
+    "coûta",
+    "coûtaient",
+    "coûtait",
+    "coûtant",
+    "coûte",
+    "coûtent",
+    "coûter",
+    "coûtera",
+    "coûterait",
+    "coûteront",
+    "coûtèrent",
+    "coûté",
+    "cracher",
+    "craché",
+    "craignais",
+    "craignait",
+    "craignant",
+    "craignez",
+    "craignions",
+    "craignirent",
+    "craignit",
+    "craignons",
+    "craindra",
+    "craindrai",
+    "craindre",
+    "crains",
+    "craint",
+    "crainte",
+    "craintes",
+    "craintifs",
+    "cramoisi",
+    "cramoisie",
+    "cramoisis",
+    "crampe",
+    "cramponne",
+    "craquement",
+    "crasseux",
+    "cravache",
+    "cravate",
+    "crayon",
+    "credete",
+    "creek",
+    "creeks",
+    "crescentini",
+    "crescentius",
+    "crescenzi",
+    "creusaient",
+    "creusait",
+    "creuses",
+    "creusé",
+    "creux",
+    "crevé",
+    "cri",
+    "cria",
+    "criaient",
+    "criait",
+    "criant",
+    "criante",
+    "criards",
+    "criblé",
+    "crie",
+    "crier",
+    "criez",
+    "crimes",
+    "criminel",
+    "criminelle",
+    "criminelles",
+    "criminels",
+    "crin",
+    "crinière",
+    "crinières",
+    "crins",
+    "cris",
+    "crise",
+    "crispe",
+    "cristal",
+    "cristaux",
+    "critique",
+    "critiquent",
+    "critiques",
+    "critiqué",
+    "crièrent",
+    "crié",
+    "criés",
+    "crochet",
+    "croie",
+    "croient",
+    "croira",
+    "croirai",
+    "croiraient",
+    "croirais",
+    "croirait",
+    "croire",
+    "croirez",
+    "croiriez",
+    "croirons",
+    "croiront",
+    "crois",
+    "croisa",
+    "croisade",
+    "croisades",
+    "croisaient",
+    "croisait",
+    "croisenois",
+    "croissaient",
+    "croissait",
+    "croissance",
+    "croissant",
+    "croissante",
+    "croisé",
+    "croisée",
+    "croisées",
+    "croisés",
+    "croit",
+    "croix",
+    "cromarty",
+    "crotte",
+    "crotté",
+    "crottées",
+    "crouler",
+    "croupe",
+    "croupir",
+    "croyaient",
+    "croyais",
+    "croyait",
+    "croyance",
+    "croyant",
+    "croyante",
+    "croyants",
+    "croyez",
+    "croyiez",
+    "croyons",
+    "croître",
+    "cru",
+    "cruauté",
+    "cruautés",
+    "cruciales",
+    "crucifix",
+    "crue",
+    "cruel",
+    "cruelle",
+    "cruellement",
+    "cruelles",
+    "cruels",
+    "crues",
+    "crurent",
+    "crus",
+    "crut",
+    "crâne",
+    "crème",
+    "crèvera",
+    "créance",
+    "créature",
+    "créatures",
+    "crédit",
+    "créditeur",
+    "crédulité",
+    "créole",
+    "crépuscule",
+    "créé",
+    "crête",
+    "crûment",
+    "crût",
+    "cueilli",
+    "cuir",
+    "cuirasse",
+    "cuirasses",
+    "cuirassier",
+    "cuirassiers",
+    "cuisant",
+    "cuisante",
+    "cuisine",
+    "cuisines",
+    "cuisinier",
+    "cuisiniers",
+    "cuisinière",
+    "cuisse",
+    "cuisses",
+    "cuistre",
+    "cuistres",
+    "cuite",
+    "cuivre",
+    "cuivres",
+    "culbute",
+    "culotte",
+    "culottes",
+    "culpa",
+    "culpabilité",
+    "culte",
+    "cultivent",
+    "cultiver",
+    "cultivée",
+    "cultivées",
+    "cunard",
+    "cunctando",
+    "cupidité",
+    "cure",
+    "cures",
+    "curieuse",
+    "curieusement",
+    "curieuses",
+    "curieux",
+    "curiosité",
+    "curé",
+    "curés",
+    "cuves",
+    "cuvette",
+    "cygne",
+    "cymbales",
+    "cynique",
+    "cyr",
+    "câline",
+    "cède",
+    "cèdre",
+    "cèdres",
+    "céda",
+    "cédait",
+    "cédant",
+    "cédar",
+    "céder",
+    "cédera",
+    "céderons",
+    "cédez",
+    "cédé",
+    "céleste",
+    "célibataires",
+    "célèbre",
+    "célèbres",
+    "célébra",
+    "célébraient",
+    "célébrait",
+    "célébrèrent",
+    "célébré",
+    "célébrée",
+    "célébrés",
+    "cérès",
+    "cérémonie",
+    "cérémonies",
+    "cérémonieuse",
+    "césar",
+    "côme",
+    "côte",
+    "côtes",
+    "côtoyant",
+    "côtoyé",
+    "côté",
+    "côtés",
+    "d",
+    "da",
+    "dague",
+    "daigna",
+    "daignaient",
+    "daignait",
+    "daignant",
+    "daigne",
+    "daignent",
+    "daigner",
+    "daignera",
+    "daignerait",
+    "daignerez",
+    "daignez",
+    "daignât",
+    "daignèrent",
+    "daigné",
+    "daim",
+    "dais",
+    "dakota",
+    "dalles",
+    "dalliance",
+    "dalmate",
+    "damas",
+    "damasquinés",
+    "dame",
+    "dames",
+    "damnent",
+    "damné",
+    "damnée",
+    "damnés",
+    "dandin",
+    "dandinant",
+    "dandy",
+    "dandys",
+    "dangereuse",
+    "dangereusement",
+    "dangereuses",
+    "dangereux",
+    "dangers",
+    "dans",
+    "dansa",
+    "dansaient",
+    "dansait",
+    "dansant",
+    "danse",
+    "dansent",
+    "danser",
+    "danses",
+    "danseuses",
+    "dansèrent",
+    "dansé",
+    "dante",
+    "danton",
+    "datait",
+    "dattiers",
+    "daté",
+    "datée",
+    "datées",
+    "davantage",
+    "davenport",
+    "davide",
+    "de",
+    "debile",
+    "debout",
+    "dedans",
+    "deem",
+    "degré",
+    "degrés",
+    "dehors",
+    "delavigne",
+    "delille",
+    "dell",
+    "della",
+    "delà",
+    "demain",
+    "demanda",
+    "demandai",
+    "demandaient",
+    "demandais",
+    "demandait",
+    "demandant",
+    "demande",
+    "demander",
+    "demandera",
+    "demanderai",
+    "demanderaient",
+    "demanderait",
+    "demanderont",
+    "demandes",
+    "demandez",
+    "demandiez",
+    "demandons",
+    "demandèrent",
+    "demandé",
+    "demandée",
+    "demandées",
+    "demandés",
+    "demesurement",
+    "demeura",
+    "demeurait",
+    "demeure",
+    "demeurer",
+    "demeurerait",
+    "demeurèrent",
+    "demeuré",
+    "demeurés",
+    "demi",
+    "demie",
+    "demoiselle",
+    "demoiselles",
+    "deniers",
+    "densité",
+    "dent",
+    "dentelle",
+    "dentelles",
+    "dents",
+    "denver",
+    "deo",
+    "depuis",
+    "derechef",
+    "dernier",
+    "dernieres",
+    "derniers",
+    "dernière",
+    "dernières",
+    "derrière",
+    "derville",
+    "des",
+    "desaix",
+    "desceller",
+    "descend",
+    "descendaient",
+    "descendait",
+    "descendant",
+    "descendante",
+    "descendants",
+    "descende",
+    "descendent",
+    "descendirent",
+    "descendit",
+    "descendrait",
+    "descendre",
+    "descendrez",
+    "descends",
+    "descendu",
+    "descendue",
+    "descendus",
+    "descente",
+    "descoulis",
+    "despote",
+    "despotes",
+    "despotique",
+    "despotiquement",
+    "despotiques",
+    "despotisme",
+    "despotismes",
+    "desquelles",
+    "desquels",
+    "dessein",
+    "desseins",
+    "dessert",
+    "desservait",
+    "desservant",
+    "desservi",
+    "dessiller",
+    "dessillèrent",
+    "dessin",
+    "dessina",
+    "dessinaient",
+    "dessinait",
+    "dessine",
+    "dessinent",
+    "dessiner",
+    "dessins",
+    "dessiné",
+    "dessous",
+    "dessus",
+    "desséchant",
+    "desséché",
+    "desséchée",
+    "destin",
+    "destinait",
+    "destine",
+    "destins",
+    "destiné",
+    "destinée",
+    "destinées",
+    "destinés",
+    "destitua",
+    "destituer",
+    "destituera",
+    "destituerai",
+    "destitution",
+    "destitué",
+    "dette",
+    "dettes",
+    "deuil",
+    "deuils",
+    "deum",
+    "deux",
+    "deuxième",
+    "devaient",
+    "devais",
+    "devait",
+    "devancer",
+    "devant",
+    "devants",
+    "devançant",
+    "devenaient",
+    "devenais",
+    "devenait",
+    "devenant",
+    "devenez",
+    "devenir",
+    "devenu",
+    "devenue",
+    "devenus",
+    "devez",
+    "deviendra",
+    "deviendrai",
+    "deviendraient",
+    "deviendrais",
+    "deviendrait",
+    "deviendrez",
+    "deviendriez",
+    "deviennent",
+    "deviens",
+    "devient",
+    "deviez",
+    "devina",
+    "devinait",
+    "devinant",
+    "devine",
+    "deviner",
+    "devinera",
+    "devinerait",
+    "devineront",
+    "devines",
+    "devinez",
+    "devinrent",
+    "devins",
+    "devint",
+    "deviné",
+    "devinée",
+    "devinées",
+    "devis",
+    "devo",
+    "devoir",
+    "devoirs",
+    "devons",
+    "devoret",
+    "devra",
+    "devrai",
+    "devraient",
+    "devrais",
+    "devrait",
+    "devriez",
+    "devrons",
+    "devront",
+    "devînmes",
+    "di",
+    "diable",
+    "diables",
+    "diacre",
+    "dialogue",
+    "dialogues",
+    "diamant",
+    "diamants",
+    "diamanté",
+    "diane",
+    "diar",
+    "dicta",
+    "dictait",
+    "dictateur",
+    "dicter",
+    "diction",
+    "dictionnaire",
+    "dicton",
+    "dicté",
+    "dictée",
+    "dictées",
+    "dictés",
+    "diderot",
+    "didon",
+    "diego",
+    "dieu",
+    "dieux",
+    "difficile",
+    "difficilement",
+    "difficiles",
+    "difficultueux",
+    "difficulté",
+    "difficultés",
+    "difforme",
+    "diffèrent",
+    "différence",
+    "différences",
+    "différend",
+    "différends",
+    "différent",
+    "différente",
+    "différentes",
+    "différents",
+    "différer",
+    "digne",
+    "dignement",
+    "dignes",
+    "dignité",
+    "dignités",
+    "digue",
+    "digère",
+    "dijon",
+    "dilettanti",
+    "diligence",
+    "diligente",
+    "dimanche",
+    "dimanches",
+    "dimension",
+    "diminua",
+    "diminuaient",
+    "diminuait",
+    "diminuant",
+    "diminue",
+    "diminuer",
+    "diminution",
+    "diminué",
+    "diminuée",
+    "dindon",
+    "diocèse",
+    "diplomate",
+    "diplomates",
+    "diplomatie",
+    "diplomatique",
+    "diplomatiquement",
+    "diplomatiques",
+    "dira",
+    "dirai",
+    "diraient",
+    "dirais",
+    "dirait",
+    "diras",
+    "dire",
+    "directe",
+    "directement",
+    "directes",
+    "directeur",
+    "directions",
+    "directoire",
+    "directs",
+    "dirent",
+    "direz",
+    "dirige",
+    "dirigea",
+    "dirigeait",
+    "dirigeant",
+    "dirigeante",
+    "dirigent",
+    "diriger",
+    "dirigerait",
+    "dirigez",
+    "dirigèrent",
+    "dirigé",
+    "dirigée",
+    "dirigées",
+    "dirigés",
+    "dirions",
+    "dirons",
+    "diront",
+    "dis",
+    "disaient",
+    "disais",
+    "disait",
+    "disant",
+    "disc",
+    "discerna",
+    "disciple",
+    "disciples",
+    "discontinuaient",
+    "discontinuer",
+    "disconvenir",
+    "discordant",
+    "discours",
+    "discret",
+    "discrets",
+    "discrète",
+    "discrétion",
+    "discuta",
+    "discutaient",
+    "discutait",
+    "discutant",
+    "discute",
+    "discuter",
+    "discutèrent",
+    "discutée",
+    "discutés",
+    "dise",
+    "disent",
+    "disgracie",
+    "disgracierez",
+    "disgracié",
+    "disgrâce",
+    "disinvoltura",
+    "disloqué",
+    "disparaissaient",
+    "disparaissait",
+    "disparaissent",
+    "disparaît",
+    "disparaître",
+    "disparition",
+    "disparu",
+    "disparurent",
+    "disparussent",
+    "disparut",
+    "dispendieuses",
+    "dispenser",
+    "dispensé",
+    "dispersaient",
+    "dispersés",
+    "dispos",
+    "disposa",
+    "disposaient",
+    "disposait",
+    "dispose",
+    "disposer",
+    "disposez",
+    "disposition",
+    "dispositions",
+    "disposons",
+    "disposé",
+    "disposée",
+    "disposées",
+    "disposés",
+    "disproportion",
+    "disputa",
+    "disputaient",
+    "disputait",
+    "dispute",
+    "disputer",
+    "disputons",
+    "disputèrent",
+    "disque",
+    "dissembles",
+    "dissent",
+    "dissertation",
+    "dissertations",
+    "disserte",
+    "dissimulaient",
+    "dissimulait",
+    "dissimulant",
+    "dissimule",
+    "dissimuler",
+    "dissimulerai",
+    "dissimulerons",
+    "dissimulons",
+    "dissimulée",
+    "dissipait",
+    "dissipation",
+    "dissipations",
+    "dissiper",
+    "dissipèrent",
+    "dissipé",
+    "dissipée",
+    "dissipées",
+    "dissolution",
+    "disséquée",
+    "distances",
+    "distancé",
+    "distant",
+    "distillée",
+    "distinctement",
+    "distinctes",
+    "distinctions",
+    "distincts",
+    "distinguaient",
+    "distinguait",
+    "distinguant",
+    "distingue",
+    "distinguer",
+    "distinguons",
+    "distingué",
+    "distinguée",
+    "distinguées",
+    "distingués",
+    "distraction",
+    "distractions",
+    "distrairait",
+    "distraire",
+    "distrait",
+    "distraite",
+    "distraites",
+    "distrayaient",
+    "distrayait",
+    "distribua",
+    "distribuait",
+    "distribue",
+    "distribuent",
+    "distribuer",
+    "distribuez",
+    "distributive",
+    "distribué",
+    "dit",
+    "dite",
+    "dites",
+    "dithyrambe",
+    "dits",
+    "diurne",
+    "divan",
+    "divers",
+    "diverses",
+    "diversion",
+    "divertir",
+    "divertissant",
+    "divertissements",
+    "divin",
+    "divine",
+    "divinité",
+    "divins",
+    "divisa",
+    "divisait",
+    "divise",
+    "divisions",
+    "divisèrent",
+    "divisé",
+    "divisée",
+    "divisées",
+    "divisés",
+    "divulguer",
+    "dix",
+    "dixerunt",
+    "dixième",
+    "dizaine",
+    "diègue",
+    "docilité",
+    "docks",
+    "docteur",
+    "doctrine",
+    "doctrines",
+    "dodge",
+    "doge",
+    "dogmatiquement",
+    "dogme",
+    "doigt",
+    "doigts",
+    "dois",
+    "doit",
+    "doivent",
+    "dolci",
+    "dollar",
+    "dollars",
+    "dolman",
+    "doléances",
+    "domaine",
+    "domaniale",
+    "domesticité",
+    "domestique",
+    "domestiques",
+    "domestiqué",
+    "domicile",
+    "domina",
+    "dominaient",
+    "dominait",
+    "dominant",
+    "dominante",
+    "dominateur",
+    "domination",
+    "domine",
+    "domingue",
+    "domini",
+    "dominé",
+    "dominée",
+    "dommage",
+    "dommages",
+    "dompter",
+    "dompté",
+    "don",
+    "donation",
+    "donc",
+    "dongo",
+    "donjon",
+    "donna",
+    "donnai",
+    "donnaient",
+    "donnait",
+    "donnant",
+    "donnassent",
+    "donne",
+    "donnent",
+    "donner",
+    "donnera",
+    "donnerai",
+    "donneraient",
+    "donnerais",
+    "donnerait",
+    "donneras",
+    "donnerez",
+    "donneriez",
+    "donneront",
+    "donnes",
+    "donneur",
+    "donnez",
+    "donnions",
+    "donnons",
+    "donnât",
+    "donnâtes",
+    "donnèrent",
+    "donné",
+    "donnée",
+    "données",
+    "donnés",
+    "dont",
+    "dorat",
+    "dormaient",
+    "dormait",
+    "dormantes",
+    "dormeurs",
+    "dormi",
+    "dormir",
+    "dormirai",
+    "dormit",
+    "dormît",
+    "dort",
+    "dortoir",
+    "dortoirs",
+    "dorures",
+    "doré",
+    "dorée",
+    "dorées",
+    "dorénavant",
+    "dorés",
+    "dose",
+    "dossier",
+    "dossiers",
+    "douaire",
+    "douairière",
+    "douane",
+    "douanier",
+    "douaniers",
+    "doubla",
+    "doublant",
+    "doublement",
+    "doubler",
+    "doubles",
+    "doublure",
+    "doublé",
+    "doublée",
+    "doubs",
+    "douce",
+    "doucement",
+    "douces",
+    "douceur",
+    "douceurs",
+    "douleur",
+    "douleurs",
+    "douloureuse",
+    "douloureuses",
+    "douloureux",
+    "douta",
+    "doutait",
+    "doute",
+    "douter",
+    "douterez",
+    "doutes",
+    "douteuse",
+    "douteuses",
+    "douteux",
+    "doutez",
+    "doutât",
+    "douté",
+    "doutée",
+    "douvres",
+    "doux",
+    "douzaine",
+    "douzaines",
+    "douze",
+    "doué",
+    "douée",
+    "doués",
+    "doyen",
+    "dragon",
+    "dragons",
+    "dramatique",
+    "dramatiques",
+    "drame",
+    "drap",
+    "drapeau",
+    "drapeaux",
+    "drapier",
+    "draps",
+    "drapée",
+    "dressaient",
+    "dressait",
+    "dresse",
+    "dresser",
+    "dressé",
+    "dressée",
+    "dressés",
+    "drisses",
+    "drogue",
+    "drogues",
+    "droit",
+    "droite",
+    "droites",
+    "droits",
+    "droiture",
+    "drome",
+    "dromes",
+    "drôle",
+    "drôles",
+    "du",
+    "dublin",
+    "dubois",
+    "duc",
+    "ducats",
+    "duchesse",
+    "duchesses",
+    "duché",
+    "ducrest",
+    "ducros",
+    "ducs",
+    "dudit",
+    "duel",
+    "duellistes",
+    "duettino",
+    "dugnani",
+    "dunette",
+    "dupe",
+    "duperie",
+    "dupes",
+    "duplicité",
+    "duquel",
+    "dur",
+    "dura",
+    "durable",
+    "durables",
+    "duraient",
+    "durait",
+    "durand",
+    "durant",
+    "durati",
+    "durcie",
+    "dure",
+    "durement",
+    "durent",
+    "durer",
+    "durera",
+    "dureraient",
+    "durerait",
+    "dureront",
+    "dures",
+    "dureté",
+    "durini",
+    "durs",
+    "durât",
+    "duré",
+    "durée",
+    "dus",
+    "dussé",
+    "dut",
+    "duvoisin",
+    "dès",
+    "dé",
+    "débarqua",
+    "débarquaient",
+    "débarquait",
+    "débarquant",
+    "débarque",
+    "débarquement",
+    "débarquer",
+    "débarquèrent",
+    "débarqué",
+    "débarrasser",
+    "débarrassez",
+    "débarrassé",
+    "débarrassée",
+    "débarrassés",
+    "débat",
+    "débats",
+    "débattait",
+    "débattant",
+    "débattrais",
+    "débattre",
+    "débattue",
+    "débauche",
+    "débit",
+    "débita",
+    "débitaient",
+    "débitant",
+    "débite",
+    "débiter",
+    "débiteur",
+    "débitées",
+    "débonnaireté",
+    "débordait",
+    "déborder",
+    "débordé",
+    "débouchaient",
+    "débouche",
+    "déboucher",
+    "déboursé",
+    "débrider",
+    "débris",
+    "débrouiller",
+    "débrouillée",
+    "début",
+    "débuta",
+    "débute",
+    "débuter",
+    "débuté",
+    "décachetait",
+    "décachetée",
+    "décachetées",
+    "décadence",
+    "décampa",
+    "décampe",
+    "décamper",
+    "décampons",
+    "décampé",
+    "décapité",
+    "décelât",
+    "décembre",
+    "décemment",
+    "décence",
+    "décent",
+    "décente",
+    "décents",
+    "déceptions",
+    "décharge",
+    "déchargement",
+    "décharger",
+    "décharges",
+    "déchaînait",
+    "déchaînement",
+    "déchaîner",
+    "déchaînât",
+    "déchira",
+    "déchiraient",
+    "déchirait",
+    "déchirant",
+    "déchire",
+    "déchirements",
+    "déchirer",
+    "déchirures",
+    "déchiré",
+    "déchirée",
+    "déchirées",
+    "déchirés",
+    "déchoir",
+    "déchu",
+    "décida",
+    "décidaient",
+    "décidait",
+    "décidant",
+    "décide",
+    "décident",
+    "décider",
+    "décidera",
+    "déciderait",
+    "déciderez",
+    "décidèrent",
+    "décidé",
+    "décidée",
+    "décidées",
+    "décidément",
+    "décimus",
+    "décisif",
+    "décision",
+    "décisions",
+    "décisive",
+    "décius",
+    "déclamer",
+    "déclara",
+    "déclarait",
+    "déclarant",
+    "déclaration",
+    "déclarations",
+    "déclare",
+    "déclarer",
+    "déclarerait",
+    "déclarez",
+    "déclarèrent",
+    "déclaré",
+    "déclarée",
+    "déclarés",
+    "déclassés",
+    "décoloré",
+    "décolorée",
+    "décolorées",
+    "décomposition",
+    "déconcerta",
+    "déconcertait",
+    "déconcerter",
+    "déconcerté",
+    "déconcertée",
+    "déconcertés",
+    "déconfit",
+    "décontenancé",
+    "déconvenues",
+    "décorateur",
+    "décoration",
+    "décore",
+    "décorum",
+    "décoré",
+    "décorée",
+    "découchait",
+    "découpait",
+    "découpant",
+    "découpés",
+    "découragement",
+    "découragements",
+    "décourager",
+    "découragé",
+    "décousus",
+    "découvert",
+    "découverte",
+    "découvertes",
+    "découverts",
+    "découvrait",
+    "découvrant",
+    "découvre",
+    "découvrir",
+    "découvrira",
+    "découvrirent",
+    "découvrit",
+    "décret",
+    "décrets",
+    "décrire",
+    "décrit",
+    "décrivaient",
+    "décrivait",
+    "décrivant",
+    "décrocha",
+    "décrépitude",
+    "décès",
+    "dédaignant",
+    "dédaigneuse",
+    "dédaigneux",
+    "dédaignée",
+    "dédain",
+    "dédains",
+    "dédommageait",
+    "dédommagement",
+    "dédommagent",
+    "dédommagerez",
+    "déduire",
+    "déduisit",
+    "déesse",
+    "défaillante",
+    "défaillir",
+    "défaire",
+    "défais",
+    "défaisait",
+    "défait",
+    "défaite",
+    "défaites",
+    "défasse",
+    "défaut",
+    "défauts",
+    "défaveur",
+    "défavorable",
+    "défection",
+    "défend",
+    "défendaient",
+    "défendait",
+    "défendant",
+    "défende",
+    "défendent",
+    "défendez",
+    "défendit",
+    "défendons",
+    "défendre",
+    "défends",
+    "défendu",
+    "défendue",
+    "défendues",
+    "défense",
+    "défenses",
+    "défenseurs",
+    "défensive",
+    "déferai",
+    "défiait",
+    "défiance",
+    "défiant",
+    "défie",
+    "défigurait",
+    "défiguré",
+    "défila",
+    "défiler",
+    "défilé",
+    "défilés",
+    "définissable",
+    "définitive",
+    "définitivement",
+    "défit",
+    "défoncèrent",
+    "défroque",
+    "défunt",
+    "déférence",
+    "dégagea",
+    "dégagement",
+    "dégager",
+    "dégagé",
+    "dégagée",
+    "dégoût",
+    "dégoûtant",
+    "dégoûtante",
+    "dégoûté",
+    "dégoûtée",
+    "dégradent",
+    "dégrader",
+    "dégradée",
+    "dégrisa",
+    "déguenillée",
+    "déguerpir",
+    "déguisa",
+    "déguisait",
+    "déguise",
+    "déguisement",
+    "déguiser",
+    "déguisèrent",
+    "déguisé",
+    "déguisée",
+    "déguisés",
+    "dégât",
+    "dégénéré",
+    "déjetées",
+    "déjeuna",
+    "déjeunait",
+    "déjeunant",
+    "déjeuner",
+    "déjeunons",
+    "déjeunèrent",
+    "déjeuné",
+    "déjouer",
+    "déjoués",
+    "déjà",
+    "délabrement",
+    "délabré",
+    "délabrée",
+    "délai",
+    "délais",
+    "délaissé",
+    "délasser",
+    "délations",
+    "délayé",
+    "délibéra",
+    "délibération",
+    "délibérer",
+    "délibérons",
+    "délicat",
+    "délicate",
+    "délicates",
+    "délicatesse",
+    "délicats",
+    "délices",
+    "délicieuse",
+    "délicieusement",
+    "délicieuses",
+    "délicieux",
+    "délier",
+    "délire",
+    "délit",
+    "délivra",
+    "délivrance",
+    "délivre",
+    "délivrer",
+    "délivrera",
+    "délivré",
+    "délivrée",
+    "délégation",
+    "démantelé",
+    "démarche",
+    "démarches",
+    "démarquer",
+    "démasquer",
+    "démenaient",
+    "démenait",
+    "démenti",
+    "démentir",
+    "démentis",
+    "démesurée",
+    "démesurément",
+    "démission",
+    "démit",
+    "démolir",
+    "démolissent",
+    "démolition",
+    "démon",
+    "démonstration",
+    "démonter",
+    "démontra",
+    "démontrait",
+    "démontré",
+    "démonté",
+    "démontée",
+    "démontés",
+    "déménager",
+    "démêloir",
+    "dénaturée",
+    "dénigrement",
+    "dénomination",
+    "dénonce",
+    "dénoncer",
+    "dénoncera",
+    "dénoncez",
+    "dénonciateur",
+    "dénonciation",
+    "dénonciations",
+    "dénoncé",
+    "dénonçait",
+    "dénotaient",
+    "dénotait",
+    "dénoter",
+    "dénouement",
+    "dénoûment",
+    "dénué",
+    "dénuées",
+    "déparait",
+    "déparant",
+    "dépareillé",
+    "départ",
+    "département",
+    "déparée",
+    "dépassaient",
+    "dépassait",
+    "dépasse",
+    "dépasser",
+    "dépassé",
+    "dépassées",
+    "dépassés",
+    "dépeint",
+    "dépeintes",
+    "dépenaillé",
+    "dépend",
+    "dépendait",
+    "dépendance",
+    "dépendant",
+    "dépendre",
+    "dépendu",
+    "dépens",
+    "dépensait",
+    "dépense",
+    "dépenser",
+    "dépenses",
+    "dépensé",
+    "dépensées",
+    "dépeuplèrent",
+    "dépister",
+    "dépisté",
+    "dépistées",
+    "dépit",
+    "dépitée",
+    "déplace",
+    "déplacement",
+    "déplacé",
+    "déplacées",
+    "déplairais",
+    "déplaire",
+    "déplairez",
+    "déplaisait",
+    "déplaise",
+    "déplaisent",
+    "déplaisir",
+    "déplaçait",
+    "déplaît",
+    "dépliage",
+    "déploie",
+    "déploierais",
+    "déplorable",
+    "déplorables",
+    "déplorait",
+    "déploré",
+    "déploya",
+    "déployaient",
+    "déployer",
+    "déployé",
+    "déployée",
+    "déplu",
+    "déplut",
+    "déporta",
+    "déportés",
+    "déposa",
+    "déposait",
+    "déposant",
+    "dépose",
+    "déposer",
+    "dépositaire",
+    "déposition",
+    "dépositions",
+    "dépossédé",
+    "déposât",
+    "déposèrent",
+    "déposé",
+    "déposée",
+    "déposées",
+    "déposés",
+    "dépouillait",
+    "dépouille",
+    "dépouiller",
+    "dépouillé",
+    "dépourvu",
+    "dépression",
+    "dépressions",
+    "déprimée",
+    "députation",
+    "député",
+    "députés",
+    "dépérir",
+    "dépêcha",
+    "dépêche",
+    "dépêches",
+    "dépêchez",
+    "dépêchons",
+    "dépêché",
+    "dépôt",
+    "dépôts",
+    "déraillement",
+    "déraillements",
+    "déraison",
+    "déraisonnable",
+    "déraisonnables",
+    "déraisonner",
+    "dérangeait",
+    "dérangements",
+    "déranger",
+    "dérangé",
+    "dérangés",
+    "déridait",
+    "dérision",
+    "dérobaient",
+    "dérober",
+    "déroberait",
+    "dérobé",
+    "dérobée",
+    "déroger",
+    "déroula",
+    "déroulant",
+    "déroulèrent",
+    "déroute",
+    "désabusé",
+    "désagréable",
+    "désagréablement",
+    "désagréables",
+    "désagrément",
+    "désagréments",
+    "désaltéré",
+    "désappointement",
+    "désappointé",
+    "désapprouver",
+    "désarmer",
+    "désarmé",
+    "désastre",
+    "désastres",
+    "désavantage",
+    "désavantages",
+    "désavoue",
+    "désennuyait",
+    "désennuyer",
+    "désert",
+    "désertais",
+    "déserte",
+    "déserter",
+    "désertes",
+    "déserteur",
+    "désertez",
+    "désertion",
+    "déserté",
+    "désespoir",
+    "désespère",
+    "désespérais",
+    "désespérait",
+    "désespérant",
+    "désespérante",
+    "désespérants",
+    "désespérer",
+    "désespéré",
+    "désespérée",
+    "déshabillaient",
+    "déshabiller",
+    "déshonneur",
+    "déshonorait",
+    "déshonorant",
+    "déshonorante",
+    "déshonore",
+    "déshonorent",
+    "déshonorer",
+    "déshonorez",
+    "déshonoré",
+    "déshonorée",
+    "désignant",
+    "désigne",
+    "désigner",
+    "désignez",
+    "désignèrent",
+    "désigné",
+    "désignés",
+    "désintéresse",
+    "désintéressement",
+    "désintéressé",
+    "désintéressées",
+    "désintérêt",
+    "désir",
+    "désira",
+    "désirable",
+    "désirait",
+    "désire",
+    "désirent",
+    "désirer",
+    "désirerais",
+    "désirez",
+    "désirs",
+    "désiré",
+    "désirée",
+    "désirées",
+    "désoblige",
+    "désobligeante",
+    "désobligeants",
+    "désobéi",
+    "désobéissante",
+    "désoeuvrement",
+    "désolait",
+    "désolant",
+    "désolation",
+    "désole",
+    "désolé",
+    "désolée",
+    "désordonnée",
+    "désordonnés",
+    "désordre",
+    "désorienté",
+    "désormais",
+    "désunir",
+    "détacha",
+    "détachaient",
+    "détachait",
+    "détachant",
+    "détache",
+    "détachement",
+    "détacher",
+    "détachèrent",
+    "détaché",
+    "détachée",
+    "détachées",
+    "détail",
+    "détailla",
+    "détaillant",
+    "détaille",
+    "détailler",
+    "détaillé",
+    "détaillée",
+    "détaillées",
+    "détails",
+    "détaler",
+    "détective",
+    "détectives",
+    "détendu",
+    "détendue",
+    "détendus",
+    "détente",
+    "détention",
+    "détenus",
+    "détermina",
+    "déterminaient",
+    "déterminait",
+    "détermination",
+    "déterminations",
+    "déterminer",
+    "déterminera",
+    "déterminât",
+    "déterminé",
+    "déterminées",
+    "déterrer",
+    "détestable",
+    "détestables",
+    "détestaient",
+    "détestait",
+    "déteste",
+    "détestent",
+    "détester",
+    "détesterez",
+    "détesté",
+    "détonation",
+    "détonations",
+    "détour",
+    "détourna",
+    "détournaient",
+    "détourné",
+    "détours",
+    "détraquer",
+    "détresse",
+    "détriment",
+    "détroit",
+    "détrompé",
+    "détruire",
+    "détruisaient",
+    "détruisit",
+    "détruit",
+    "détruites",
+    "détruits",
+    "développa",
+    "développaient",
+    "développait",
+    "développement",
+    "développée",
+    "déverse",
+    "déverser",
+    "dévisageait",
+    "dévisager",
+    "dévisser",
+    "dévoilé",
+    "dévora",
+    "dévoraient",
+    "dévorait",
+    "dévorante",
+    "dévore",
+    "dévorer",
+    "dévoreront",
+    "dévorez",
+    "dévorât",
+    "dévoré",
+    "dévorée",
+    "dévorés",
+    "dévot",
+    "dévote",
+    "dévotement",
+    "dévotes",
+    "dévotion",
+    "dévots",
+    "dévouement",
+    "dévouements",
+    "dévouer",
+    "dévoué",
+    "dévouée",
+    "dévoués",
+    "dîme",
+    "dîna",
+    "dînait",
+    "dînant",
+    "dînent",
+    "dîner",
+    "dînerai",
+    "dînerait",
+    "dîners",
+    "dînèrent",
+    "dîné",
+    "dînée",
+    "dînées",
+    "dît",
+    "dôle",
+    "dôme",
+    "dû",
+    "dûment",
+    "dût",
+    "e",
+    "earnshaw",
+    "eastern",
+    "eau",
+    "eaux",
+    "eccellenza",
+    "ecclesiam",
+    "ecclésiastique",
+    "ecclésiastiques",
+    "echiquier",
+    "ecoutez",
+    "ecumeur",
+    "edimbourg",
+    "edinburgh",
+    "edrisi",
+    "efface",
+    "effacer",
+    "effacé",
+    "effacée",
+    "effacées",
+    "effarouchant",
+    "effarouche",
+    "effaroucher",
+    "effarouches",
+    "effarouché",
+    "effarouchés",
+    "effaré",
+    "effaça",
+    "effectivement",
+    "effectives",
+    "effervescence",
+    "effet",
+    "effets",
+    "effeuilla",
+    "effigie",
+    "effleurer",
+    "efforçait",
+    "effraie",
+    "effraya",
+    "effrayaient",
+    "effrayait",
+    "effrayant",
+    "effrayante",
+    "effraye",
+    "effrayent",
+    "effrayer",
+    "effrayèrent",
+    "effrayé",
+    "effrayée",
+    "effrayées",
+    "effrayés",
+    "effroi",
+    "effronterie",
+    "effronté",
+    "effrontément",
+    "effrontés",
+    "effroyable",
+    "effroyablement",
+    "effréné",
+    "effrénée",
+    "effusion",
+    "efféminées",
+    "ego",
+    "egypte",
+    "egyptiens",
+    "eh",
+    "el",
+    "elder",
+    "elko",
+    "elle",
+    "elles",
+    "ellora",
+    "elysées",
+    "eléphanta",
+    "emballer",
+    "emballée",
+    "embarcation",
+    "embarcations",
+    "embardée",
+    "embardées",
+    "embarqua",
+    "embarquait",
+    "embarquant",
+    "embarque",
+    "embarquement",
+    "embarquer",
+    "embarqué",
+    "embarqués",
+    "embarras",
+    "embarrassa",
+    "embarrassait",
+    "embarrasse",
+    "embarrasser",
+    "embarrassé",
+    "embarrassée",
+    "embarrassés",
+    "embaumé",
+    "embaumée",
+    "embelli",
+    "embellie",
+    "embellir",
+    "emblème",
+    "emblée",
+    "embonpoint",
+    "embossent",
+    "embouchure",
+    "embouquait",
+    "embourbant",
+    "embourser",
+    "embranchement",
+    "embranchements",
+    "embrassa",
+    "embrassaient",
+    "embrassait",
+    "embrassant",
+    "embrasse",
+    "embrassements",
+    "embrasser",
+    "embrasserais",
+    "embrassèrent",
+    "embrassé",
+    "embrasé",
+    "embrasée",
+    "embrouilla",
+    "embrouillant",
+    "embrouille",
+    "embrouiller",
+    "embrumé",
+    "embrunir",
+    "embruns",
+    "embuscade",
+    "embûche",
+    "embûches",
+    "emmancher",
+    "emmena",
+    "emmenaient",
+    "emmenait",
+    "emmener",
+    "emmené",
+    "emmène",
+    "emmènerait",
+    "emmènes",
+    "emmêlés",
+    "empaquetée",
+    "empara",
+    "emparaient",
+    "emparait",
+    "emparant",
+    "emparer",
+    "emparèrent",
+    "emparé",
+    "emparés",
+    "empaumer",
+    "empereur",
+    "empereurs",
+    "empestée",
+    "empesé",
+    "emphase",
+    "emphatique",
+    "emphatiques",
+    "empilé",
+    "empir",
+    "empire",
+    "empires",
+    "emplacement",
+    "emplettes",
+    "emplissent",
+    "emploi",
+    "emploie",
+    "emploient",
+    "emploierai",
+    "emploieraient",
+    "emploierais",
+    "emploierait",
+    "emplois",
+    "employa",
+    "employaient",
+    "employait",
+    "employant",
+    "employer",
+    "employez",
+    "employât",
+    "employâtes",
+    "employé",
+    "employée",
+    "employées",
+    "employés",
+    "emplumés",
+    "empoché",
+    "empoignera",
+    "empoigné",
+    "empoisonna",
+    "empoisonnaient",
+    "empoisonnait",
+    "empoisonne",
+    "empoisonnement",
+    "empoisonnent",
+    "empoisonner",
+    "empoisonnerai",
+    "empoisonnerait",
+    "empoisonneur",
+    "empoisonneurs",
+    "empoisonné",
+    "empoisonnée",
+    "empoisonnés",
+    "emporta",
+    "emportaient",
+    "emportait",
+    "emportant",
+    "emporte",
+    "emportement",
+    "emportent",
+    "emporter",
+    "emporterait",
+    "emporterez",
+    "emporteront",
+    "emporté",
+    "emportée",
+    "emportées",
+    "emportés",
+    "empreinte",
+    "empressa",
+    "empressement",
+    "empressements",
+    "empressèrent",
+    "empressé",
+    "empressée",
+    "empressées",
+    "empressés",
+    "emprisonne",
+    "emprisonnement",
+    "emprisonné",
+    "emprisonnée",
+    "emprunt",
+    "emprunta",
+    "emprunter",
+    "emprunté",
+    "empruntés",
+    "empêcha",
+    "empêchaient",
+    "empêchait",
+    "empêche",
+    "empêchent",
+    "empêcher",
+    "empêchera",
+    "empêcherai",
+    "empêcheraient",
+    "empêcherait",
+    "empêcheront",
+    "empêches",
+    "empêchez",
+    "empêchât",
+    "empêchèrent",
+    "empêché",
+    "empêchée",
+    "empêtrés",
+    "en",
+    "encablure",
+    "encadre",
+    "encadré",
+    "encadrées",
+    "enceinte",
+    "encens",
+    "enchantait",
+    "enchantement",
+    "enchanter",
+    "enchanteresses",
+    "enchanteur",
+    "enchanteurs",
+    "enchanté",
+    "enchantée",
+    "enchantés",
+    "enchaîné",
+    "enchaînée",
+    "enchaînés",
+    "enchâsser",
+    "enchère",
+    "enchères",
+    "enclos",
+    "enclume",
+    "encolure",
+    "encombraient",
+    "encombrant",
+    "encombrante",
+    "encombre",
+    "encombré",
+    "encombrée",
+    "encombrées",
+    "encombrés",
+    "encore",
+    "encourageante",
+    "encouragement",
+    "encourager",
+    "encouragé",
+    "encouragés",
+    "encourir",
+    "encouru",
+    "encrassaient",
+    "encre",
+    "encroûtées",
+    "encyclopédie",
+    "endoctriné",
+    "endommagé",
+    "endormi",
+    "endormir",
+    "endormis",
+    "endormit",
+    "endors",
+    "endosser",
+    "endossé",
+    "endroit",
+    "endroits",
+    "enduisirent",
+    "enduraient",
+    "endurant",
+    "endurci",
+    "endurcie",
+    "endurées",
+    "enfance",
+    "enfant",
+    "enfantillage",
+    "enfantillages",
+    "enfantine",
+    "enfantines",
+    "enfants",
+    "enfer",
+    "enferma",
+    "enfermais",
+    "enfermait",
+    "enfermant",
+    "enferme",
+    "enfermer",
+    "enfermèrent",
+    "enfermé",
+    "enfermée",
+    "enfermés",
+    "enferrer",
+    "enfers",
+    "enfield",
+    "enfin",
+    "enflamma",
+    "enflammer",
+    "enflammèrent",
+    "enflammé",
+    "enflammée",
+    "enflammées",
+    "enflammés",
+    "enflée",
+    "enflées",
+    "enfonce",
+    "enfoncer",
+    "enfonceront",
+    "enfonça",
+    "enfonçaient",
+    "enfonçait",
+    "enfonçât",
+    "enfouis",
+    "enfourcher",
+    "enfui",
+    "enfuie",
+    "enfuir",
+    "enfuirai",
+    "enfuis",
+    "enfuit",
+    "enfumé",
+    "enfuyaient",
+    "enfuyait",
+    "engage",
+    "engagea",
+    "engageaient",
+    "engageait",
+    "engageant",
+    "engagement",
+    "engagements",
+    "engagent",
+    "engager",
+    "engagerai",
+    "engagé",
+    "engagée",
+    "engagées",
+    "engeance",
+    "engendre",
+    "englishman",
+    "engloutir",
+    "engouffrées",
+    "engourdis",
+    "engourdissement",
+    "engoués",
+    "enivraient",
+    "enivrer",
+    "enivré",
+    "enivrée",
+    "enivrées",
+    "enjambée",
+    "enjeu",
+    "enjouement",
+    "enleva",
+    "enlevaient",
+    "enlevait",
+    "enlever",
+    "enlevez",
+    "enlevât",
+    "enlevèrent",
+    "enlevé",
+    "enlevée",
+    "enlevées",
+    "enluminée",
+    "enlève",
+    "enlèvement",
+    "enlèvent",
+    "enlèverais",
+    "enlèverez",
+    "ennemi",
+    "ennemie",
+    "ennemis",
+    "ennius",
+    "ennnui",
+    "ennui",
+    "ennuie",
+    "ennuient",
+    "ennuiera",
+    "ennuierais",
+    "ennuierait",
+    "ennuieront",
+    "ennuies",
+    "ennuis",
+    "ennuya",
+    "ennuyaient",
+    "ennuyais",
+    "ennuyait",
+    "ennuyant",
+    "ennuyer",
+    "ennuyeuse",
+    "ennuyeusement",
+    "ennuyeuses",
+    "ennuyeux",
+    "ennuyez",
+    "ennuyé",
+    "ennuyée",
+    "ennuyés",
+    "enorgueilli",
+    "enquérait",
+    "enquête",
+    "enraciner",
+    "enragé",
+    "enragés",
+    "enrayer",
+    "enrayés",
+    "enregistrement",
+    "enregistrer",
+    "enrhumaient",
+    "enrhumé",
+    "enrichi",
+    "enrichir",
+    "enrichis",
+    "enrichissait",
+    "enrichissent",
+    "enrichit",
+    "enrouaient",
+    "enroulait",
+    "enroulant",
+    "enrégimentés",
+    "ensachés",
+    "ensanglanté",
+    "enseignait",
+    "enseigne",
+    "enseignements",
+    "enseignent",
+    "enseigner",
+    "enseignera",
+    "enseigné",
+    "enseignées",
+    "ensemble",
+    "enserrer",
+    "ensevelir",
+    "ensorcela",
+    "ensuit",
+    "ensuite",
+    "ensuivit",
+    "entachés",
+    "entama",
+    "entame",
+    "entamer",
+    "entamée",
+    "entasse",
+    "entassent",
+    "entassée",
+    "entend",
+    "entendaient",
+    "entendais",
+    "entendait",
+    "entendant",
+    "entendent",
+    "entendez",
+    "entendirent",
+    "entendit",
+    "entendra",
+    "entendrait",
+    "entendre",
+    "entendrez",
+    "entendrons",
+    "entends",
+    "entendu",
+    "entendue",
+    "entendus",
+    "entendît",
+    "enterrer",
+    "enterrez",
+    "enthousiasmait",
+    "enthousiasme",
+    "enthousiasmé",
+    "enthousiasmées",
+    "enthousiaste",
+    "enthousiastes",
+    "entiché",
+    "entier",
+    "entiers",
+    "entière",
+    "entièrement",
+    "entières",
+    "entomologique",
+    "entonnant",
+    "entonner",
+    "entonné",
+    "entortiller",
+    "entortillés",
+    "entour",
+    "entoura",
+    "entouraient",
+    "entourait",
+    "entoure",
+    "entourent",
+    "entouré",
+    "entourée",
+    "entourées",
+    "entourés",
+    "entra",
+    "entrai",
+    "entraient",
+    "entrailles",
+    "entrait",
+    "entrant",
+    "entraves",
+    "entraîna",
+    "entraînait",
+    "entraînant",
+    "entraîne",
+    "entraînement",
+    "entraîner",
+    "entraînera",
+    "entraîné",
+    "entraînée",
+    "entraînés",
+    "entre",
+    "entrecoupée",
+    "entrecoupées",
+    "entrefaites",
+    "entrelacement",
+    "entremets",
+    "entremettre",
+    "entremise",
+    "entremêlées",
+    "entremêlés",
+    "entrent",
+    "entreprenait",
+    "entreprenant",
+    "entreprend",
+    "entreprendre",
+    "entrepreneur",
+    "entrepris",
+    "entreprise",
+    "entreprises",
+    "entreprit",
+    "entrepôts",
+    "entrer",
+    "entreraient",
+    "entreras",
+    "entresol",
+    "entretenait",
+    "entretenir",
+    "entretenu",
+    "entretenus",
+    "entretien",
+    "entretiens",
+    "entretient",
+    "entretinrent",
+    "entretint",
+    "entrevit",
+    "entrevoir",
+    "entrevois",
+    "entrevoit",
+    "entrevoyaient",
+    "entrevoyait",
+    "entrevoyant",
+    "entrevu",
+    "entrevue",
+    "entrevues",
+    "entrez",
+    "entrons",
+    "entrouverte",
+    "entrouvrant",
+    "entrèrent",
+    "entré",
+    "entrée",
+    "entrées",
+    "entrés",
+    "entêté",
+    "envahi",
+    "envahie",
+    "envahir",
+    "envahissaient",
+    "envahit",
+    "enveloppaient",
+    "enveloppait",
+    "enveloppe",
+    "envelopper",
+    "enveloppes",
+    "enveloppé",
+    "enveloppée",
+    "envenimée",
+    "enverguait",
+    "envergure",
+    "enverra",
+    "enverrai",
+    "enverrais",
+    "enverras",
+    "enverrez",
+    "envers",
+    "envi",
+    "enviable",
+    "enviait",
+    "enviant",
+    "envie",
+    "envieux",
+    "environ",
+    "environnaient",
+    "environnait",
+    "environnantes",
+    "environne",
+    "environnent",
+    "environner",
+    "environné",
+    "environnée",
+    "environnés",
+    "environs",
+    "envisageons",
+    "envisager",
+    "envisagez",
+    "envié",
+    "enviée",
+    "envoi",
+    "envoie",
+    "envola",
+    "envolant",
+    "envole",
+    "envoler",
+    "envolées",
+    "envolés",
+    "envoya",
+    "envoyaient",
+    "envoyait",
+    "envoyant",
+    "envoyer",
+    "envoyez",
+    "envoyât",
+    "envoyâtes",
+    "envoyé",
+    "envoyée",
+    "envoyées",
+    "envoyés",
+    "equinoctial",
+    "equus",
+    "er",
+    "erit",
+    "ermitages",
+    "ernest",
+    "erra",
+    "erraient",
+    "errait",
+    "errant",
+    "errante",
+    "errer",
+    "erreur",
+    "erreurs",
+    "erré",
+    "es",
+    "escadron",
+    "escalader",
+    "escaladé",
+    "escale",
+    "escalier",
+    "escaliers",
+    "escamoter",
+    "escamotée",
+    "escapade",
+    "escarpins",
+    "escarpés",
+    "escaut",
+    "esclandre",
+    "esclave",
+    "esclaves",
+    "escofié",
+    "escogriffe",
+    "escortaient",
+    "escorte",
+    "escorté",
+    "escortée",
+    "escouade",
+    "escrime",
+    "escrit",
+    "espace",
+    "espaces",
+    "espagne",
+    "espagnol",
+    "espagnole",
+    "espagnoles",
+    "espagnolette",
+    "espagnols",
+    "esparres",
+    "espion",
+    "espionnage",
+    "espionnes",
+    "espionné",
+    "espions",
+    "espiègle",
+    "esplanade",
+    "espoir",
+    "esprit",
+    "esprits",
+    "espèce",
+    "espèces",
+    "espère",
+    "espères",
+    "espéra",
+    "espérais",
+    "espérait",
+    "espérance",
+    "espérances",
+    "espérant",
+    "espérer",
+    "espérez",
+    "espérons",
+    "espéré",
+    "espérée",
+    "esq",
+    "esquire",
+    "esquisse",
+    "esquisser",
+    "esquiva",
+    "esquiveras",
+    "essai",
+    "essaiera",
+    "essaierai",
+    "essaierait",
+    "essais",
+    "essaya",
+    "essayaient",
+    "essayait",
+    "essayant",
+    "essaye",
+    "essayer",
+    "essayons",
+    "essayé",
+    "essayées",
+    "essence",
+    "essences",
+    "essentiel",
+    "essentielle",
+    "essentiellement",
+    "essieux",
+    "essoufflé",
+    "essoufflée",
+    "essuie",
+    "essuyait",
+    "essuyer",
+    "essuyé",
+    "est",
+    "estafette",
+    "estafier",
+    "estafiers",
+    "estampe",
+    "estampes",
+    "estimable",
+    "estimables",
+    "estimaient",
+    "estimait",
+    "estimant",
+    "estime",
+    "estimer",
+    "estimeraient",
+    "estimez",
+    "estimé",
+    "estimée",
+    "estomac",
+    "estomacs",
+    "et",
+    "etaient",
+    "etait",
+    "etant",
+    "etat",
+    "etats",
+    "ethnographiques",
+    "etrangleurs",
+    "etre",
+    "eu",
+    "eue",
+    "eues",
+    "eugène",
+    "eunuque",
+    "euphorbes",
+    "eurent",
+    "europe",
+    "européen",
+    "européenne",
+    "européennes",
+    "européens",
+    "eus",
+    "eusse",
+    "eussent",
+    "eusses",
+    "eussiez",
+    "eussions",
+    "eut",
+    "eux",
+    "evans",
+    "evidemment",
+    "exacte",
+    "exactement",
+    "exactes",
+    "exactitude",
+    "exacts",
+    "exagère",
+    "exagèrent",
+    "exagéra",
+    "exagérait",
+    "exagérant",
+    "exagération",
+    "exagérations",
+    "exagérer",
+    "exagéré",
+    "exagérée",
+    "exagérées",
+    "exagérés",
+    "exalta",
+    "exaltait",
+    "exaltation",
+    "exalte",
+    "exalter",
+    "exalté",
+    "exaltée",
+    "exaltées",
+    "examen",
+    "examens",
+    "examina",
+    "examinait",
+    "examinant",
+    "examinateur",
+    "examinateurs",
+    "examiner",
+    "examinez",
+    "examiné",
+    "examinée",
+    "exaspérait",
+    "exaspérer",
+    "exaspéré",
+    "exaspérée",
+    "excellence",
+    "excellente",
+    "excellentes",
+    "excellents",
+    "excentricité",
+    "excentrique",
+    "excepté",
+    "excessif",
+    "excessifs",
+    "excessive",
+    "excessivement",
+    "excessives",
+    "excita",
+    "excitait",
+    "excitation",
+    "excitent",
+    "exciter",
+    "exciterai",
+    "excité",
+    "excitée",
+    "excitées",
+    "exclama",
+    "exclamation",
+    "exclamations",
+    "exclu",
+    "exclusivement",
+    "excusa",
+    "excusable",
+    "excusait",
+    "excusant",
+    "excuse",
+    "excuser",
+    "excuserez",
+    "excuses",
+    "excusez",
+    "excusée",
+    "excès",
+    "excéder",
+    "excédé",
+    "excédée",
+    "exemplaire",
+    "exemplaires",
+    "exemple",
+    "exemples",
+    "exempte",
+    "exempté",
+    "exerce",
+    "exercent",
+    "exercer",
+    "exercerait",
+    "exercice",
+    "exercices",
+    "exercée",
+    "exercés",
+    "exerçait",
+    "exerçant",
+    "exhibant",
+    "exhibe",
+    "exhibition",
+    "exhibitions",
+    "exhortait",
+    "exige",
+    "exigea",
+    "exigeait",
+    "exigences",
+    "exigeons",
+    "exiger",
+    "exigez",
+    "exigus",
+    "exiguë",
+    "exigé",
+    "exil",
+    "exila",
+    "exilait",
+    "exile",
+    "exiler",
+    "exilé",
+    "exilées",
+    "exista",
+    "existaient",
+    "existait",
+    "existe",
+    "existent",
+    "exister",
+    "existât",
+    "existé",
+    "exorbitante",
+    "exotiques",
+    "expansif",
+    "expiation",
+    "expira",
+    "expirer",
+    "expiré",
+    "explicable",
+    "explication",
+    "explications",
+    "explicite",
+    "expliqua",
+    "expliquait",
+    "expliquant",
+    "explique",
+    "expliquer",
+    "expliquez",
+    "expliqué",
+    "expliquée",
+    "expliqués",
+    "exploit",
+    "exploits",
+    "explosion",
+    "exposa",
+    "exposais",
+    "exposait",
+    "exposant",
+    "exposer",
+    "exposerai",
+    "exposerais",
+    "exposerait",
+    "exposez",
+    "exposé",
+    "exposée",
+    "exposées",
+    "exposés",
+    "expresse",
+    "expressifs",
+    "expressément",
+    "exprima",
+    "exprimaient",
+    "exprimait",
+    "exprimant",
+    "exprime",
+    "exprimer",
+    "exprimeront",
+    "exprimèrent",
+    "exprimé",
+    "exprimée",
+    "exprès",
+    "expédia",
+    "expédier",
+    "expédition",
+    "expéditions",
+    "expédié",
+    "expédiée",
+    "expérience",
+    "exquise",
+    "extase",
+    "extases",
+    "extatique",
+    "exterminés",
+    "extinction",
+    "extorqué",
+    "extradition",
+    "extrait",
+    "extraites",
+    "extraordinaire",
+    "extraordinairement",
+    "extraordinaires",
+    "extravagant",
+    "extravagante",
+    "extravagantes",
+    "extrémité",
+    "extrémités",
+    "extrême",
+    "extrêmement",
+    "extrêmes",
+    "extérieur",
+    "extérieure",
+    "extérieures",
+    "extérieurs",
+    "exècre",
+    "exécrable",
+    "exécrait",
+    "exécration",
+    "exécrer",
+    "exécuta",
+    "exécutait",
+    "exécutant",
+    "exécute",
+    "exécuter",
+    "exécuterai",
+    "exécuterait",
+    "exécuteras",
+    "exécuteur",
+    "exécution",
+    "exécutions",
+    "exécutoire",
+    "exécuté",
+    "exécutée",
+    "exécutées",
+    "exécutés",
+    "eûmes",
+    "eût",
+    "eûtes",
+    "f",
+    "fa",
+    "fabio",
+    "fable",
+    "fables",
+    "fabricant",
+    "fabricants",
+    "fabrice",
+    "fabriquaient",
+    "fabrique",
+    "fabriquer",
+    "fabriques",
+    "fabriquée",
+    "fabriquées",
+    "fabuleux",
+    "fabuliste",
+    "facile",
+    "facilement",
+    "facilitait",
+    "facilitent",
+    "faciliter",
+    "facilité",
+    "facio",
+    "faconde",
+    "facteur",
+    "faction",
+    "factotum",
+    "faculté",
+    "facultés",
+    "fade",
+    "fagots",
+    "fahrenheit",
+    "faible",
+    "faibles",
+    "faiblesse",
+    "faiblesses",
+    "faiblir",
+    "faiblirent",
+    "faict",
+    "failli",
+    "faillir",
+    "faillirent",
+    "faillit",
+    "faim",
+    "fainéant",
+    "faire",
+    "fais",
+    "faisable",
+    "faisaient",
+    "faisais",
+    "faisait",
+    "faisant",
+    "faisceaux",
+    "faisiez",
+    "faisons",
+    "fait",
+    "faite",
+    "faites",
+    "faits",
+    "fakirs",
+    "falcoz",
+    "faliero",
+    "fallait",
+    "fallentin",
+    "falloir",
+    "fallu",
+    "fallut",
+    "fameuse",
+    "fameusement",
+    "fameux",
+    "familiarisé",
+    "familiarité",
+    "familier",
+    "familièrement",
+    "famille",
+    "familles",
+    "fanal",
+    "fanatique",
+    "fanatiques",
+    "fanatisme",
+    "fanfarons",
+    "fange",
+    "fangeuses",
+    "fangeux",
+    "fantaisie",
+    "fantaisies",
+    "fantaisistes",
+    "fantasque",
+    "fantastique",
+    "fantastiques",
+    "fantôme",
+    "faquins",
+    "faraud",
+    "farceur",
+    "farceurs",
+    "farci",
+    "fardeau",
+    "fare",
+    "farine",
+    "farmer",
+    "farnèse",
+    "farouche",
+    "farouches",
+    "farthing",
+    "fascinant",
+    "fascination",
+    "fasciner",
+    "fasciné",
+    "fasse",
+    "fassent",
+    "fassiez",
+    "faste",
+    "fastenet",
+    "fata",
+    "fatale",
+    "fatales",
+    "fatalité",
+    "fatigant",
+    "fatigante",
+    "fatigua",
+    "fatiguaient",
+    "fatigue",
+    "fatiguer",
+    "fatiguerai",
+    "fatigues",
+    "fatigué",
+    "fatiguée",
+    "fatiguées",
+    "fatuité",
+    "faublas",
+    "faubourg",
+    "faudra",
+    "faudrait",
+    "faufilant",
+    "fausse",
+    "fausses",
+    "fausseté",
+    "faussetés",
+    "fausta",
+    "faut",
+    "faute",
+    "fautes",
+    "fauteuil",
+    "fauteuils",
+    "fauve",
+    "fauves",
+    "faux",
+    "faveur",
+    "faveurs",
+    "favorable",
+    "favorablement",
+    "favorables",
+    "favori",
+    "favoris",
+    "favorisa",
+    "favorisait",
+    "favoriser",
+    "favorite",
+    "fay",
+    "fayette",
+    "façade",
+    "façades",
+    "façon",
+    "façons",
+    "faîte",
+    "feci",
+    "feignait",
+    "feignant",
+    "feignent",
+    "feignit",
+    "feindre",
+    "feins",
+    "feint",
+    "feinte",
+    "felice",
+    "felino",
+    "fellah",
+    "fellahs",
+    "femme",
+    "femmelette",
+    "femmes",
+    "fendaient",
+    "fendait",
+    "fendues",
+    "fenestrelles",
+    "fente",
+    "fenêtre",
+    "fenêtres",
+    "fer",
+    "fera",
+    "ferai",
+    "feraient",
+    "ferais",
+    "ferait",
+    "feras",
+    "feretrius",
+    "ferez",
+    "feriez",
+    "feringhea",
+    "ferions",
+    "ferma",
+    "fermage",
+    "fermaient",
+    "fermait",
+    "fermant",
+    "ferme",
+    "fermement",
+    "fermentation",
+    "fermer",
+    "fermerait",
+    "fermerons",
+    "fermes",
+    "fermeture",
+    "fermeté",
+    "fermier",
+    "fermiers",
+    "fermât",
+    "fermèrent",
+    "fermé",
+    "fermée",
+    "fermées",
+    "fermés",
+    "ferons",
+    "feront",
+    "ferrante",
+    "ferrare",
+    "ferrures",
+    "ferry",
+    "ferrée",
+    "fers",
+    "fertile",
+    "fertiles",
+    "fervaques",
+    "fervent",
+    "fervente",
+    "fervents",
+    "ferveur",
+    "feu",
+    "feuillage",
+    "feuille",
+    "feuilles",
+    "feuilleta",
+    "feuilletait",
+    "feuilletant",
+    "feuillets",
+    "feutre",
+    "feux",
+    "fi",
+    "fiacre",
+    "ficelles",
+    "ficelé",
+    "fiches",
+    "fichée",
+    "fidèle",
+    "fidèlement",
+    "fidèles",
+    "fidélité",
+    "fie",
+    "fier",
+    "fierai",
+    "fiers",
+    "fierté",
+    "figaro",
+    "figura",
+    "figuraient",
+    "figurais",
+    "figurait",
+    "figurer",
+    "figurerai",
+    "figureriez",
+    "figures",
+    "figurez",
+    "figurât",
+    "figuré",
+    "figurés",
+    "fil",
+    "fila",
+    "filait",
+    "filant",
+    "filasse",
+    "filent",
+    "filer",
+    "filerai",
+    "filet",
+    "filez",
+    "fili",
+    "filiale",
+    "filigrane",
+    "filins",
+    "fille",
+    "filles",
+    "filons",
+    "fils",
+    "filtrait",
+    "filé",
+    "fin",
+    "finance",
+    "finances",
+    "financier",
+    "financièrement",
+    "fines",
+    "finesse",
+    "finesses",
+    "fini",
+    "finie",
+    "finies",
+    "finir",
+    "finira",
+    "finiraient",
+    "finirait",
+    "finirent",
+    "finis",
+    "finissait",
+    "finissant",
+    "finisse",
+    "finissent",
+    "finissons",
+    "finit",
+    "fins",
+    "fiole",
+    "fir",
+    "firent",
+    "fis",
+    "fiscal",
+    "fisse",
+    "fitz",
+    "fixa",
+    "fixaient",
+    "fixait",
+    "fixant",
+    "fixe",
+    "fixement",
+    "fixer",
+    "fixité",
+    "fixèrent",
+    "fixé",
+    "fixée",
+    "fixés",
+    "fière",
+    "fièrement",
+    "fières",
+    "fièvre",
+    "fiévreux",
+    "flacon",
+    "flagrant",
+    "flair",
+    "flairait",
+    "flamand",
+    "flamande",
+    "flambeaux",
+    "flamboyants",
+    "flambé",
+    "flambés",
+    "flamme",
+    "flammes",
+    "flanagan",
+    "flanc",
+    "flancs",
+    "flatta",
+    "flattait",
+    "flatte",
+    "flatter",
+    "flatteries",
+    "flatteur",
+    "flatteurs",
+    "flatteuse",
+    "flatteuses",
+    "flatté",
+    "flattée",
+    "flegmatique",
+    "flegme",
+    "fleur",
+    "fleurs",
+    "fleury",
+    "fleuve",
+    "fleuves",
+    "flexibles",
+    "flocons",
+    "florence",
+    "florissante",
+    "flot",
+    "flots",
+    "flottaient",
+    "flottant",
+    "flottantes",
+    "flottants",
+    "flotter",
+    "flottille",
+    "flâna",
+    "flânait",
+    "flânant",
+    "flèche",
+    "flèches",
+    "fléchir",
+    "flétri",
+    "flétrirait",
+    "flûtes",
+    "fo",
+    "foc",
+    "focs",
+    "fogg",
+    "foi",
+    "foin",
+    "fois",
+    "foison",
+    "foisonnait",
+    "foisonne",
+    "fokelberg",
+    "fol",
+    "folie",
+    "folies",
+    "folio",
+    "folke",
+    "folle",
+    "follement",
+    "folles",
+    "foncièrement",
+    "fonctionnaire",
+    "fonctionnaires",
+    "fonctionne",
+    "fonctions",
+    "foncé",
+    "fond",
+    "fonda",
+    "fondaient",
+    "fondait",
+    "fondant",
+    "fondateur",
+    "fondations",
+    "fondements",
+    "fondent",
+    "fonder",
+    "fonderies",
+    "fondirent",
+    "fondit",
+    "fondre",
+    "fonds",
+    "fondu",
+    "fondue",
+    "fondé",
+    "fondée",
+    "fondées",
+    "fondés",
+    "fontaine",
+    "fontaines",
+    "fontan",
+    "fontana",
+    "fontenoy",
+    "forcer",
+    "forcera",
+    "forcerai",
+    "forcez",
+    "forcèrent",
+    "forcé",
+    "forcée",
+    "forcés",
+    "foretells",
+    "forets",
+    "forfaitz",
+    "forgé",
+    "forma",
+    "formaient",
+    "formait",
+    "formalisait",
+    "formaliste",
+    "formalistes",
+    "formalité",
+    "formalités",
+    "formant",
+    "formation",
+    "forme",
+    "formel",
+    "formellement",
+    "forment",
+    "formera",
+    "formes",
+    "formez",
+    "formidable",
+    "formidables",
+    "formose",
+    "formula",
+    "formule",
+    "formuler",
+    "formules",
+    "formulée",
+    "formèrent",
+    "formé",
+    "formée",
+    "formées",
+    "formés",
+    "forster",
+    "fort",
+    "forte",
+    "fortement",
+    "forteresse",
+    "fortes",
+    "fortifications",
+    "fortifier",
+    "fortifions",
+    "fortifié",
+    "fortifiée",
+    "fortifiées",
+    "forts",
+    "fortunes",
+    "fortuné",
+    "fortunées",
+    "fortunés",
+    "força",
+    "forçaient",
+    "forçais",
+    "forçait",
+    "forçant",
+    "forêt",
+    "forêts",
+    "foscarini",
+    "fosse",
+    "fosses",
+    "fossé",
+    "fossés",
+    "fou",
+    "foudre",
+    "foudroyer",
+    "fouet",
+    "fouettait",
+    "fouetter",
+    "fouetterait",
+    "fouetté",
+    "fougueuse",
+    "fougueux",
+    "fougères",
+    "fouillait",
+    "fouille",
+    "fouiller",
+    "fouilles",
+    "fouillez",
+    "fouillât",
+    "fouillé",
+    "fouillés",
+    "foule",
+    "foules",
+    "foulé",
+    "fouqué",
+    "fourbes",
+    "fourgons",
+    "fourmilière",
+    "fourmillait",
+    "fourmillement",
+    "fourmis",
+    "fourmont",
+    "fourneaux",
+    "fourni",
+    "fournie",
+    "fournir",
+    "fourniront",
+    "fournis",
+    "fournissaient",
+    "fournissait",
+    "fournissant",
+    "fournisseurs",
+    "fournit",
+    "fourniture",
+    "fournitures",
+    "fourraient",
+    "fourreau",
+    "fourrer",
+    "fourrier",
+    "fourriers",
+    "fourrures",
+    "fourré",
+    "fous",
+    "fox",
+    "foyer",
+    "frac",
+    "fracas",
+    "fracassée",
+    "fracassés",
+    "fragile",
+    "frailty",
+    "frais",
+    "franc",
+    "france",
+    "francese",
+    "francfort",
+    "franche",
+    "franchement",
+    "franchi",
+    "franchie",
+    "franchies",
+    "franchir",
+    "franchis",
+    "franchise",
+    "franchissait",
+    "franchissant",
+    "franchissent",
+    "franchit",
+    "francs",
+    "français",
+    "française",
+    "françaises",
+    "françois",
+    "frappa",
+    "frappaient",
+    "frappait",
+    "frappant",
+    "frappante",
+    "frappantes",
+    "frappe",
+    "frappent",
+    "frapper",
+    "frapperai",
+    "frappât",
+    "frappé",
+    "frappée",
+    "frappées",
+    "frappés",
+    "fraternelle",
+    "fraternisaient",
+    "fraude",
+    "frayeur",
+    "frayée",
+    "fraîche",
+    "fraîches",
+    "fraîcheur",
+    "fraîchir",
+    "fraîchissait",
+    "fraîchit",
+    "freins",
+    "freluquets",
+    "fremont",
+    "fresque",
+    "fret",
+    "frilair",
+    "fringante",
+    "friperie",
+    "friperies",
+    "fripon",
+    "friponner",
+    "friponnerie",
+    "friponneries",
+    "fripons",
+    "frisaient",
+    "frisait",
+    "friser",
+    "frises",
+    "frisson",
+    "frisés",
+    "frivole",
+    "froc",
+    "froid",
+    "froide",
+    "froidement",
+    "froides",
+    "froideur",
+    "froids",
+    "froissa",
+    "froissant",
+    "froissants",
+    "froissé",
+    "fromage",
+    "froment",
+    "froncement",
+    "froncer",
+    "froncera",
+    "froncé",
+    "fronde",
+    "frondeur",
+    "frontins",
+    "frontière",
+    "frontières",
+    "fronts",
+    "fronçait",
+    "fronçant",
+    "frotta",
+    "frottait",
+    "frottant",
+    "frottements",
+    "frotté",
+    "fruit",
+    "fruitières",
+    "fruits",
+    "frère",
+    "frères",
+    "frédéric",
+    "frémi",
+    "frémir",
+    "frémis",
+    "frémit",
+    "frénétique",
+    "fréquemment",
+    "fréquence",
+    "fréquent",
+    "fréquente",
+    "fréquenter",
+    "fréquentes",
+    "fréquents",
+    "fréquenté",
+    "fréquentée",
+    "fréquentées",
+    "fréter",
+    "frêle",
+    "frênes",
+    "fugitif",
+    "fugitifs",
+    "fui",
+    "fuient",
+    "fuir",
+    "fuirais",
+    "fuis",
+    "fuite",
+    "fulgence",
+    "fuligineuses",
+    "fulminante",
+    "fumaient",
+    "fumant",
+    "fume",
+    "fumer",
+    "fumeur",
+    "fumeurs",
+    "fumée",
+    "fumées",
+    "funeste",
+    "funestes",
+    "funèbre",
+    "funèbres",
+    "fur",
+    "furent",
+    "fureur",
+    "furia",
+    "furibond",
+    "furibonde",
+    "furibondes",
+    "furibonds",
+    "furieuse",
+    "furieusement",
+    "furieuses",
+    "furieux",
+    "furtivement",
+    "fus",
+    "fusait",
+    "fuseau",
+    "fuseaux",
+    "fusil",
+    "fusiliers",
+    "fusiller",
+    "fusillé",
+    "fusillés",
+    "fusils",
+    "fusse",
+    "fussent",
+    "fusses",
+    "fussiez",
+    "fussions",
+    "fut",
+    "futilité",
+    "futilités",
+    "futur",
+    "futura",
+    "futurs",
+    "futé",
+    "fuyaient",
+    "fuyait",
+    "fuyant",
+    "fuyards",
+    "fuyez",
+    "fâcha",
+    "fâchait",
+    "fâche",
+    "fâchent",
+    "fâcher",
+    "fâcheuse",
+    "fâcheuses",
+    "fâcheux",
+    "fâchez",
+    "fâchât",
+    "fâchèrent",
+    "fâché",
+    "fâchée",
+    "fâchés",
+    "fé",
+    "fébrile",
+    "fébrilement",
+    "fécond",
+    "féconde",
+    "félicita",
+    "félicitait",
+    "félicite",
+    "félicité",
+    "félicités",
+    "félix",
+    "féminin",
+    "féminine",
+    "fénelon",
+    "féodal",
+    "féroce",
+    "féroces",
+    "férocité",
+    "fête",
+    "fêtes",
+    "fêté",
+    "fît",
+    "fûmes",
+    "fût",
+    "fûtes",
+    "g",
+    "gabarit",
+    "gabrielle",
+    "gage",
+    "gages",
+    "gageure",
+    "gagna",
+    "gagnaient",
+    "gagnais",
+    "gagnait",
+    "gagnant",
+    "gagne",
+    "gagnent",
+    "gagner",
+    "gagnerai",
+    "gagneras",
+    "gagnez",
+    "gagnons",
+    "gagnèrent",
+    "gagné",
+    "gagnée",
+    "gagnées",
+    "gagnés",
+    "gai",
+    "gaie",
+    "gaiement",
+    "gaies",
+    "gaieté",
+    "gaillard",
+    "gaillardement",
+    "gains",
+    "gais",
+    "gala",
+    "galamment",
+    "galant",
+    "galante",
+    "galanterie",
+    "galantes",
+    "galerie",
+    "galeries",
+    "galeuse",
+    "galeux",
+    "gallicane",
+    "galon",
+    "galonnées",
+    "galons",
+    "galop",
+    "galopa",
+    "galopaient",
+    "galopait",
+    "galopant",
+    "galope",
+    "galoper",
+    "galopera",
+    "galoperai",
+    "galopez",
+    "galvanisées",
+    "galère",
+    "galères",
+    "galéas",
+    "galériens",
+    "gambadant",
+    "gamins",
+    "gamme",
+    "ganaches",
+    "gange",
+    "gangrenée",
+    "gangrenées",
+    "gangrène",
+    "gant",
+    "gants",
+    "garantie",
+    "garanties",
+    "garantissait",
+    "garda",
+    "gardai",
+    "gardaient",
+    "gardait",
+    "gardant",
+    "garde",
+    "gardens",
+    "garder",
+    "gardera",
+    "garderai",
+    "garderais",
+    "garderait",
+    "garderas",
+    "gardes",
+    "gardez",
+    "gardien",
+    "gardiens",
+    "gardé",
+    "gardée",
+    "gardées",
+    "gardés",
+    "gare",
+    "garnement",
+    "garni",
+    "garnie",
+    "garnies",
+    "garnis",
+    "garnison",
+    "garnit",
+    "garrot",
+    "garrotté",
+    "garçon",
+    "garçons",
+    "gascons",
+    "gaspilla",
+    "gauche",
+    "gaucherie",
+    "gaucheries",
+    "gauches",
+    "gauthier",
+    "gay",
+    "gaz",
+    "gaze",
+    "gazes",
+    "gazette",
+    "gazettes",
+    "gazon",
+    "gazouillements",
+    "gaîment",
+    "gaîté",
+    "gendarme",
+    "gendarmer",
+    "gendarmerie",
+    "gendarmes",
+    "gendre",
+    "genevois",
+    "genlis",
+    "genoux",
+    "genre",
+    "genres",
+    "gens",
+    "gentil",
+    "gentilhomme",
+    "gentille",
+    "gentils",
+    "gentilshommes",
+    "gentlemen",
+    "genèse",
+    "genève",
+    "gerbes",
+    "germain",
+    "geste",
+    "gestes",
+    "gesticulant",
+    "geôlier",
+    "geôliers",
+    "geôlière",
+    "ghari",
+    "ghazepour",
+    "gherardi",
+    "ghisleri",
+    "ghisolfi",
+    "ghita",
+    "ghâtes",
+    "gia",
+    "giacomo",
+    "gibelotte",
+    "giberne",
+    "gibier",
+    "gibraltar",
+    "gigantesque",
+    "gigantesques",
+    "gilet",
+    "gilets",
+    "giletti",
+    "gin",
+    "gina",
+    "giovanni",
+    "giovannone",
+    "giovita",
+    "girafe",
+    "giration",
+    "giraud",
+    "girodet",
+    "girofliers",
+    "girondin",
+    "gisaient",
+    "gisant",
+    "giulia",
+    "giuseppe",
+    "glace",
+    "glacer",
+    "glaces",
+    "glacial",
+    "glaciale",
+    "glacé",
+    "glacée",
+    "glacées",
+    "glances",
+    "glapissante",
+    "glasgow",
+    "glaçaient",
+    "glaçait",
+    "glissa",
+    "glissaient",
+    "glissait",
+    "glissant",
+    "glisse",
+    "glissent",
+    "glisser",
+    "glissera",
+    "glissons",
+    "glissé",
+    "globe",
+    "gloire",
+    "glorieux",
+    "glory",
+    "gloutons",
+    "glu",
+    "goa",
+    "gobelet",
+    "godart",
+    "godavery",
+    "godille",
+    "goethe",
+    "goguenard",
+    "goguenards",
+    "golconde",
+    "goldoni",
+    "golfe",
+    "golfes",
+    "golgonda",
+    "golgonde",
+    "gonds",
+    "gongs",
+    "gonzo",
+    "gorge",
+    "gorges",
+    "gorgées",
+    "gothard",
+    "gothique",
+    "gothiques",
+    "gouailler",
+    "goudron",
+    "goulée",
+    "gour",
+    "gourmande",
+    "gourmandise",
+    "gourmets",
+    "gousset",
+    "goutte",
+    "gouttes",
+    "gouvernail",
+    "gouvernait",
+    "gouverne",
+    "gouvernement",
+    "gouvernements",
+    "gouvernent",
+    "gouverner",
+    "gouverneriez",
+    "gouverneur",
+    "gouverneurs",
+    "gouverné",
+    "gouvion",
+    "goélette",
+    "goélettes",
+    "goût",
+    "goûta",
+    "goûtant",
+    "goûte",
+    "goûter",
+    "goûterait",
+    "goûts",
+    "goûté",
+    "goûtées",
+    "goûtés",
+    "gracieuse",
+    "gracieusement",
+    "gracieuses",
+    "gracieux",
+    "grade",
+    "graduellement",
+    "graduée",
+    "grain",
+    "grains",
+    "graisse",
+    "graisser",
+    "grand",
+    "grande",
+    "grandement",
+    "grandes",
+    "grandeur",
+    "grandeurs",
+    "grandi",
+    "grandie",
+    "grandiose",
+    "grandis",
+    "grandit",
+    "grands",
+    "granit",
+    "granvelle",
+    "grappe",
+    "gras",
+    "grasse",
+    "gratification",
+    "gratifié",
+    "gratis",
+    "gratius",
+    "gratter",
+    "gratuite",
+    "gratuitement",
+    "gravement",
+    "graver",
+    "graves",
+    "graveur",
+    "gravir",
+    "gravissaient",
+    "gravitaient",
+    "gravité",
+    "gravures",
+    "gravé",
+    "grec",
+    "grecque",
+    "grecques",
+    "gredin",
+    "greenwich",
+    "greffier",
+    "grenade",
+    "grenadier",
+    "grenadiers",
+    "grenier",
+    "grianta",
+    "griefs",
+    "griffe",
+    "griffonnages",
+    "griffonnait",
+    "grillages",
+    "grille",
+    "grilles",
+    "grillo",
+    "grillé",
+    "grillée",
+    "grillées",
+    "grimace",
+    "grimaces",
+    "grimaçant",
+    "grimaçante",
+    "grimaçantes",
+    "grimaçants",
+    "grimer",
+    "grimpait",
+    "grimpant",
+    "gringalet",
+    "gris",
+    "grise",
+    "grisonnants",
+    "grisâtre",
+    "grisé",
+    "grivelé",
+    "grièvement",
+    "grogeot",
+    "grognement",
+    "grognon",
+    "gronda",
+    "grondait",
+    "grondant",
+    "gronde",
+    "gronder",
+    "gronderez",
+    "grondez",
+    "grondé",
+    "grondée",
+    "groom",
+    "gros",
+    "groseilles",
+    "grosse",
+    "grosses",
+    "grossesse",
+    "grosseur",
+    "grossie",
+    "grossier",
+    "grossiers",
+    "grossière",
+    "grossièrement",
+    "grossières",
+    "grossièreté",
+    "grossièretés",
+    "grotesque",
+    "grotesques",
+    "grotte",
+    "grottes",
+    "grouillait",
+    "groupe",
+    "groupes",
+    "grues",
+    "grâce",
+    "grâces",
+    "grèce",
+    "grève",
+    "gré",
+    "gréer",
+    "grégoire",
+    "gréé",
+    "grêles",
+    "guardate",
+    "guenilles",
+    "guerchin",
+    "guerre",
+    "guerres",
+    "guerriers",
+    "guet",
+    "guetta",
+    "guettait",
+    "guette",
+    "guettent",
+    "guetteurs",
+    "gueule",
+    "gueux",
+    "gui",
+    "guichet",
+    "guichetier",
+    "guidant",
+    "guider",
+    "guidon",
+    "guidé",
+    "guidée",
+    "guidés",
+    "guignon",
+    "guillotinaient",
+    "guillotine",
+    "guillotiner",
+    "guillotiné",
+    "guillotinés",
+    "guinder",
+    "guindé",
+    "guinée",
+    "guinées",
+    "guise",
+    "guitare",
+    "gustave",
+    "guy",
+    "guèbres",
+    "guère",
+    "guépards",
+    "guéri",
+    "guérin",
+    "guérir",
+    "guérison",
+    "guérissez",
+    "guérit",
+    "guérite",
+    "guérites",
+    "gymnase",
+    "gymnaste",
+    "gymnastes",
+    "gymnastique",
+    "gâchis",
+    "gâtaient",
+    "gâtait",
+    "gâte",
+    "gâteau",
+    "gâter",
+    "gâterait",
+    "gâté",
+    "gâtée",
+    "gâtées",
+    "gâtés",
+    "géant",
+    "géants",
+    "gémi",
+    "gémissait",
+    "gémissant",
+    "gémissements",
+    "génie",
+    "génies",
+    "génoise",
+    "généalogie",
+    "généalogique",
+    "général",
+    "générale",
+    "généralement",
+    "générales",
+    "généraux",
+    "généreuse",
+    "généreusement",
+    "généreuses",
+    "généreux",
+    "générosité",
+    "géographie",
+    "géomètre",
+    "géométrie",
+    "géorgiques",
+    "géronimo",
+    "gênaient",
+    "gênait",
+    "gênant",
+    "gênante",
+    "gêne",
+    "gênent",
+    "gêner",
+    "gênerait",
+    "gênes",
+    "gêné",
+    "gît",
+    "h",
+    "ha",
+    "habile",
+    "habilement",
+    "habiles",
+    "habileté",
+    "habilla",
+    "habillait",
+    "habillant",
+    "habille",
+    "habillement",
+    "habiller",
+    "habillé",
+    "habillée",
+    "habillés",
+    "habit",
+    "habitais",
+    "habitait",
+    "habitant",
+    "habitants",
+    "habitation",
+    "habite",
+    "habiter",
+    "habiterai",
+    "habiteras",
+    "habitez",
+    "habits",
+    "habitude",
+    "habitudes",
+    "habituel",
+    "habituelle",
+    "habituellement",
+    "habituelles",
+    "habituels",
+    "habituée",
+    "habitués",
+    "habité",
+    "habitée",
+    "habités",
+    "hache",
+    "haches",
+    "hagard",
+    "hagards",
+    "haie",
+    "haies",
+    "haillons",
+    "haine",
+    "haines",
+    "haineuse",
+    "haineuses",
+    "hais",
+    "hait",
+    "halage",
+    "haleine",
+    "haletant",
+    "halleck",
+    "halte",
+    "haltes",
+    "hambourg",
+    "hambourgeois",
+    "hambourgeoise",
+    "hameau",
+    "hampe",
+    "hanche",
+    "hangar",
+    "hantée",
+    "hantées",
+    "harangua",
+    "haranguer",
+    "harassés",
+    "hardes",
+    "hardi",
+    "hardie",
+    "hardies",
+    "hardiesse",
+    "hardiment",
+    "hardis",
+    "harmonie",
+    "harmonieusement",
+    "harmonieux",
+    "harmoniques",
+    "harpe",
+    "hasard",
+    "hasarda",
+    "hasardaient",
+    "hasarde",
+    "hasarder",
+    "hasardera",
+    "hasardeuse",
+    "hasardeuses",
+    "hasardeux",
+    "hasards",
+    "hasardé",
+    "haubans",
+    "haubert",
+    "haus",
+    "haussant",
+    "haussement",
+    "haussé",
+    "haut",
+    "hautain",
+    "hautaine",
+    "haute",
+    "hautement",
+    "hautes",
+    "hauteur",
+    "hauteurs",
+    "hauts",
+    "havre",
+    "hay",
+    "hayez",
+    "haï",
+    "haïr",
+    "haïraient",
+    "haïssaient",
+    "haïssait",
+    "haïssent",
+    "heaviest",
+    "hein",
+    "helvétie",
+    "hennissant",
+    "hennissement",
+    "hennissements",
+    "hennit",
+    "henné",
+    "henri",
+    "henriet",
+    "henrietta",
+    "herbe",
+    "herbes",
+    "hercule",
+    "herculéenne",
+    "hermétiquement",
+    "hernani",
+    "hers",
+    "heure",
+    "heures",
+    "heureuse",
+    "heureusement",
+    "heureuses",
+    "heureux",
+    "heurtaient",
+    "heurte",
+    "heurter",
+    "hic",
+    "hideuse",
+    "hideuses",
+    "hideux",
+    "hier",
+    "himalaya",
+    "hindous",
+    "hippolyte",
+    "hissa",
+    "hissé",
+    "hissées",
+    "histoire",
+    "histoires",
+    "historien",
+    "historiens",
+    "historique",
+    "historiques",
+    "histrion",
+    "hitch",
+    "hiver",
+    "hiérarchie",
+    "ho",
+    "hobbes",
+    "hobereau",
+    "hobereaux",
+    "hoc",
+    "hochant",
+    "hoche",
+    "holland",
+    "hollandais",
+    "hollandaises",
+    "hollande",
+    "holà",
+    "hommage",
+    "hommages",
+    "homme",
+    "hommes",
+    "homère",
+    "homélie",
+    "homélies",
+    "hongrois",
+    "honneur",
+    "honneurs",
+    "honnête",
+    "honnêtes",
+    "honnêteté",
+    "honnêtetés",
+    "honorabilité",
+    "honorable",
+    "honorables",
+    "honore",
+    "honorent",
+    "honorer",
+    "honorera",
+    "honoré",
+    "honorée",
+    "honorés",
+    "honte",
+    "honteuse",
+    "honteusement",
+    "honteux",
+    "honur",
+    "horace",
+    "horde",
+    "horions",
+    "horizon",
+    "horloge",
+    "horlogerie",
+    "horloges",
+    "horreur",
+    "horreurs",
+    "horriblement",
+    "horribles",
+    "hors",
+    "hospice",
+    "hospitalité",
+    "hostie",
+    "hostile",
+    "hostiles",
+    "hostilité",
+    "hotte",
+    "hottentots",
+    "hougly",
+    "houille",
+    "houle",
+    "houles",
+    "houleuse",
+    "housards",
+    "housse",
+    "hudson",
+    "hugo",
+    "hui",
+    "huile",
+    "huilé",
+    "huissier",
+    "huit",
+    "huitième",
+    "humain",
+    "humaine",
+    "humainement",
+    "humaines",
+    "humains",
+    "humait",
+    "humaniste",
+    "humanité",
+    "humanités",
+    "humble",
+    "humblement",
+    "humbles",
+    "humboldt",
+    "hume",
+    "humer",
+    "humeur",
+    "humide",
+    "humides",
+    "humidité",
+    "humiliant",
+    "humiliante",
+    "humiliantes",
+    "humiliants",
+    "humiliation",
+    "humiliations",
+    "humilie",
+    "humilient",
+    "humilier",
+    "humiliez",
+    "humilité",
+    "humilié",
+    "humiliée",
+    "hune",
+    "huniers",
+    "hurlante",
+    "hurlements",
+    "hurlent",
+    "hurler",
+    "hurrah",
+    "hurrahs",
+    "hussard",
+    "hussarde",
+    "hussards",
+    "hutte",
+    "huttes",
+    "huîtres",
+    "hvram",
+    "hydrographique",
+    "hydrogène",
+    "hypocrisie",
+    "hypocrisies",
+    "hypocrite",
+    "hypocrites",
+    "hypogées",
+    "hypothèse",
+    "hyères",
+    "hâla",
+    "hâle",
+    "hâta",
+    "hâtait",
+    "hâte",
+    "hâter",
+    "hâtez",
+    "hâtons",
+    "hâtèrent",
+    "hâté",
+    "hère",
+    "hères",
+    "hé",
+    "hébété",
+    "hébétés",
+    "héla",
+    "hélas",
+    "hélice",
+    "héloïse",
+    "hélène",
+    "hémisphère",
+    "héraut",
+    "hérauts",
+    "hérisse",
+    "hérissé",
+    "hérissée",
+    "hérissées",
+    "hérissés",
+    "héritage",
+    "héritier",
+    "héritière",
+    "hérité",
+    "hérodiade",
+    "hérodiades",
+    "héron",
+    "héros",
+    "héroïne",
+    "héroïque",
+    "héroïquement",
+    "héroïques",
+    "héroïsme",
+    "héréditaire",
+    "hérédité",
+    "hérésie",
+    "hérésies",
+    "hésita",
+    "hésitait",
+    "hésitant",
+    "hésitation",
+    "hésite",
+    "hésiter",
+    "hésiterais",
+    "hésiteront",
+    "hésitiez",
+    "hésité",
+    "hêtre",
+    "hêtres",
+    "hôpital",
+    "hôpitaux",
+    "hôte",
+    "hôtel",
+    "hôtels",
+    "hôtes",
+    "hôtesse",
+    "hôtesses",
+    "iago",
+    "ici",
+    "identique",
+    "identité",
+    "idiots",
+    "idole",
+    "idolâtrie",
+    "idéal",
+    "idéale",
+    "idée",
+    "idées",
+    "ignares",
+    "ignes",
+    "ignoble",
+    "ignobles",
+    "ignominie",
+    "ignoraient",
+    "ignorais",
+    "ignorait",
+    "ignorance",
+    "ignorant",
+    "ignorante",
+    "ignorent",
+    "ignorer",
+    "ignoriez",
+    "ii",
+    "iii",
+    "il",
+    "illicites",
+    "illimité",
+    "illimitée",
+    "illinois",
+    "illisible",
+    "illisibles",
+    "illumina",
+    "illumination",
+    "illuminer",
+    "illuminé",
+    "illuminée",
+    "illusion",
+    "illusions",
+    "illustration",
+    "illustre",
+    "illustrer",
+    "illustrissime",
+    "ils",
+    "imagina",
+    "imaginaient",
+    "imaginaire",
+    "imaginaires",
+    "imaginais",
+    "imaginait",
+    "imaginant",
+    "imagination",
+    "imaginations",
+    "imaginative",
+    "imaginer",
+    "imaginé",
+    "imbécile",
+    "imbéciles",
+    "imita",
+    "imitait",
+    "imitant",
+    "imitation",
+    "imiter",
+    "imité",
+    "imitées",
+    "imman",
+    "immanquable",
+    "immanquablement",
+    "immense",
+    "immenses",
+    "immensité",
+    "immensément",
+    "imminence",
+    "imminent",
+    "immobile",
+    "immobiles",
+    "immoler",
+    "immolé",
+    "immonde",
+    "immoral",
+    "immorales",
+    "immoralité",
+    "immortaliser",
+    "immortalisé",
+    "immortel",
+    "immuable",
+    "immédiat",
+    "immédiate",
+    "immédiatement",
+    "immédiates",
+    "immédiats",
+    "imola",
+    "impardonnable",
+    "impardonnables",
+    "imparfait",
+    "imparfaite",
+    "imparfaitement",
+    "impassibilité",
+    "impassible",
+    "impatience",
+    "impatient",
+    "impatienta",
+    "impatientait",
+    "impatientant",
+    "impatientante",
+    "impatiente",
+    "impatienter",
+    "impatientera",
+    "impatienté",
+    "impatientée",
+    "impayable",
+    "imperceptibles",
+    "imperfection",
+    "impertinence",
+    "impertinences",
+    "impertinent",
+    "impertinente",
+    "impertinentes",
+    "imperturbable",
+    "imperturbablement",
+    "impie",
+    "impies",
+    "impitoyable",
+    "impitoyablement",
+    "impiété",
+    "implacable",
+    "implicite",
+    "implora",
+    "implorer",
+    "impoli",
+    "impolie",
+    "impoliment",
+    "importait",
+    "importante",
+    "importantes",
+    "importants",
+    "importe",
+    "importent",
+    "importun",
+    "importuna",
+    "importune",
+    "importunités",
+    "importuns",
+    "importée",
+    "importés",
+    "imposa",
+    "imposaient",
+    "imposait",
+    "imposant",
+    "imposante",
+    "impose",
+    "imposent",
+    "imposer",
+    "imposera",
+    "impositions",
+    "impossibilité",
+    "impossibles",
+    "imposèrent",
+    "imposé",
+    "imposée",
+    "imposées",
+    "imposés",
+    "impraticable",
+    "impresario",
+    "imprescriptibles",
+    "impressionna",
+    "impressionnait",
+    "impressions",
+    "imprima",
+    "imprimait",
+    "imprime",
+    "imprimer",
+    "imprimerie",
+    "imprimeur",
+    "imprimeurs",
+    "imprimé",
+    "imprimée",
+    "imprimées",
+    "imprimés",
+    "improbable",
+    "improbables",
+    "impromptu",
+    "impromptue",
+    "impropre",
+    "improprement",
+    "improvisais",
+    "improvisait",
+    "improvises",
+    "improviste",
+    "improvisée",
+    "improvisées",
+    "improvisés",
+    "imprudemment",
+    "imprudence",
+    "imprudences",
+    "imprudent",
+    "imprudente",
+    "imprudentes",
+    "imprudents",
+    "imprécations",
+    "imprégnait",
+    "imprégné",
+    "imprévu",
+    "imprévue",
+    "imprévues",
+    "impudence",
+    "impudent",
+    "impudents",
+    "impuissante",
+    "impuissants",
+    "impulsion",
+    "impunité",
+    "impunément",
+    "imputant",
+    "imputations",
+    "imputer",
+    "imputées",
+    "impénétrable",
+    "impératif",
+    "impératrice",
+    "impérial",
+    "impériale",
+    "impériales",
+    "impérieuse",
+    "impérieusement",
+    "impérieux",
+    "impétueusement",
+    "ina",
+    "inaccessible",
+    "inaccessibles",
+    "inaccoutumé",
+    "inaccoutumée",
+    "inaccoutumées",
+    "inaction",
+    "inadmissible",
+    "inadmissibles",
+    "inaltérable",
+    "inamovible",
+    "inanimé",
+    "inanimée",
+    "inanimés",
+    "inaperçu",
+    "inaperçue",
+    "inaperçues",
+    "inarticulés",
+    "inattaquable",
+    "inattaquables",
+    "inattendu",
+    "inattendue",
+    "inattention",
+    "inauguration",
+    "inaugurer",
+    "incapable",
+    "incarne",
+    "incarnée",
+    "incartade",
+    "incedo",
+    "incendiaires",
+    "incendie",
+    "incendies",
+    "incertain",
+    "incertaine",
+    "incertains",
+    "incertitude",
+    "incertitudes",
+    "incessamment",
+    "incessant",
+    "incessants",
+    "inceste",
+    "incident",
+    "incidenter",
+    "incidents",
+    "incisif",
+    "incisions",
+    "incisive",
+    "incisives",
+    "inclina",
+    "inclinaient",
+    "inclination",
+    "inclinera",
+    "incliné",
+    "inclinée",
+    "inclus",
+    "incluse",
+    "incognito",
+    "incommensurable",
+    "incommode",
+    "incommodes",
+    "incommodité",
+    "incommodée",
+    "incomodo",
+    "incompatibles",
+    "incomplètes",
+    "incongruité",
+    "inconnu",
+    "inconnue",
+    "inconnus",
+    "inconsciemment",
+    "inconsciente",
+    "inconsidérée",
+    "inconsolable",
+    "inconstance",
+    "inconstant",
+    "inconstante",
+    "incontestable",
+    "incontestablement",
+    "inconvenance",
+    "inconvenant",
+    "inconvenante",
+    "inconvenantes",
+    "inconvénient",
+    "inconvénients",
+    "incorporer",
+    "incriminait",
+    "incroyable",
+    "incroyables",
+    "incrusta",
+    "incruster",
+    "incrustés",
+    "incrédule",
+    "incrédulité",
+    "incurie",
+    "inde",
+    "indemne",
+    "independance",
+    "indes",
+    "indiana",
+    "indication",
+    "indications",
+    "indicible",
+    "indicibles",
+    "indien",
+    "indienne",
+    "indiens",
+    "indifférence",
+    "indifférent",
+    "indifférents",
+    "indigna",
+    "indignation",
+    "indignations",
+    "indigne",
+    "indignement",
+    "indigner",
+    "indignes",
+    "indigné",
+    "indignée",
+    "indignés",
+    "indigo",
+    "indigène",
+    "indigènes",
+    "indiqua",
+    "indiquaient",
+    "indiquait",
+    "indiquant",
+    "indique",
+    "indiquent",
+    "indiquer",
+    "indiquera",
+    "indiquerait",
+    "indiquez",
+    "indiqué",
+    "indiquée",
+    "indiqués",
+    "indirecte",
+    "indirectement",
+    "indirectes",
+    "indiscret",
+    "indiscrets",
+    "indiscrète",
+    "indiscrètes",
+    "indiscrétion",
+    "indiscrétions",
+    "indispensable",
+    "indispensables",
+    "indisposition",
+    "indisposé",
+    "indisposée",
+    "indistincte",
+    "individu",
+    "individuelle",
+    "individus",
+    "indou",
+    "indoue",
+    "indoues",
+    "indous",
+    "indubitable",
+    "indue",
+    "indulgence",
+    "indulgent",
+    "indulgente",
+    "indulgents",
+    "indus",
+    "industrie",
+    "industriel",
+    "industrielle",
+    "industriels",
+    "industrieux",
+    "indécence",
+    "indécent",
+    "indécentes",
+    "indécents",
+    "indéchiffrable",
+    "indécis",
+    "indécise",
+    "indéfini",
+    "indéfinissable",
+    "indélicatesse",
+    "indépendamment",
+    "indépendance",
+    "indépendant",
+    "indépendante",
+    "indépendantes",
+    "indépendants",
+    "inedit",
+    "ineffaçable",
+    "inepte",
+    "ineptes",
+    "inerte",
+    "inespéré",
+    "inexactitude",
+    "inexorable",
+    "inexplicable",
+    "inexprimable",
+    "inexprimables",
+    "inexpérimenté",
+    "inextricable",
+    "inexécutable",
+    "infamie",
+    "infamies",
+    "infanterie",
+    "infatigable",
+    "infernale",
+    "infidèle",
+    "infidélité",
+    "infini",
+    "infinie",
+    "infinies",
+    "infiniment",
+    "infinis",
+    "infinité",
+    "infirmerie",
+    "infirmités",
+    "inflammation",
+    "infliger",
+    "infligé",
+    "infligée",
+    "influencer",
+    "influent",
+    "influents",
+    "influer",
+    "infléchit",
+    "informa",
+    "informait",
+    "informations",
+    "informes",
+    "informée",
+    "informés",
+    "infortune",
+    "infortunes",
+    "infortuné",
+    "infortunée",
+    "infortunés",
+    "infraction",
+    "infusion",
+    "infâme",
+    "infâmes",
+    "inférieur",
+    "inférieure",
+    "inférieures",
+    "inférieurs",
+    "infériorité",
+    "ingrat",
+    "ingrate",
+    "ingratitude",
+    "ingénieur",
+    "ingénieurs",
+    "ingénieuse",
+    "ingénieusement",
+    "ingénieuses",
+    "ingénieux",
+    "ingénu",
+    "inhabileté",
+    "inhabitée",
+    "inhalation",
+    "inhumaine",
+    "inhumé",
+    "inhérent",
+    "inhérente",
+    "inintelligible",
+    "iniques",
+    "iniquité",
+    "initiative",
+    "initièrent",
+    "injure",
+    "injures",
+    "injurieuse",
+    "injurieuses",
+    "injurieux",
+    "injurié",
+    "injuste",
+    "injustement",
+    "injustes",
+    "injustice",
+    "injustices",
+    "inn",
+    "innocemment",
+    "innocence",
+    "innocente",
+    "innocentes",
+    "innombrable",
+    "innombrables",
+    "innovation",
+    "inné",
+    "innée",
+    "inoccupées",
+    "inoculé",
+    "inoffensif",
+    "inondaient",
+    "inondait",
+    "inondation",
+    "inonder",
+    "inondèrent",
+    "inondé",
+    "inondée",
+    "inondées",
+    "inondés",
+    "inopinément",
+    "inopportunes",
+    "inopportuns",
+    "inouï",
+    "inouïs",
+    "inqualifiable",
+    "inquiet",
+    "inquiets",
+    "inquisiteurs",
+    "inquisition",
+    "inquiète",
+    "inquiéta",
+    "inquiétait",
+    "inquiétant",
+    "inquiétantes",
+    "inquiétants",
+    "inquiéter",
+    "inquiétude",
+    "inquiétudes",
+    "insaisissable",
+    "inscription",
+    "inscriptions",
+    "inscrire",
+    "inscrit",
+    "inscrits",
+    "inscrivit",
+    "insectes",
+    "insensibilité",
+    "insensible",
+    "insensiblement",
+    "insensibles",
+    "insensé",
+    "insensée",
+    "insensés",
+    "insigne",
+    "insignes",
+    "insignifiance",
+    "insignifiant",
+    "insignifiante",
+    "insignifiants",
+    "insinuera",
+    "insipide",
+    "insipides",
+    "insista",
+    "insistait",
+    "insistance",
+    "insistant",
+    "insiste",
+    "insister",
+    "insisté",
+    "insociable",
+    "insolemment",
+    "insolence",
+    "insolences",
+    "insolent",
+    "insolente",
+    "insolents",
+    "insolite",
+    "insolites",
+    "insouciance",
+    "insouciant",
+    "inspecteur",
+    "inspecteurs",
+    "inspira",
+    "inspiraient",
+    "inspirait",
+    "inspirant",
+    "inspirations",
+    "inspire",
+    "inspirent",
+    "inspirer",
+    "inspirerait",
+    "inspires",
+    "inspirez",
+    "inspirèrent",
+    "inspiré",
+    "inspirée",
+    "inspirées",
+    "inspirés",
+    "instable",
+    "installèrent",
+    "installé",
+    "installée",
+    "installés",
+    "instantané",
+    "instantanément",
+    "instante",
+    "instants",
+    "instar",
+    "instinct",
+    "instinctif",
+    "instinctivement",
+    "institution",
+    "institutions",
+    "instructif",
+    "instruire",
+    "instruisait",
+    "instruit",
+    "instruments",
+    "insu",
+    "insuccès",
+    "insuffisant",
+    "insuffisante",
+    "insuffisantes",
+    "insulta",
+    "insultant",
+    "insultante",
+    "insulte",
+    "insulter",
+    "insultes",
+    "insulteur",
+    "insulté",
+    "insupportable",
+    "insupportables",
+    "insurmontable",
+    "insurmontables",
+    "insurrection",
+    "inséparable",
+    "insérer",
+    "intact",
+    "intellectuel",
+    "intellectuelle",
+    "intelligence",
+    "intelligences",
+    "intelligent",
+    "intelligente",
+    "intelligenti",
+    "intelligents",
+    "intelligible",
+    "intelligibles",
+    "intempestive",
+    "intendant",
+    "intense",
+    "intenses",
+    "intensité",
+    "intentionné",
+    "intentions",
+    "interceptait",
+    "intercepter",
+    "intercepterait",
+    "intercepté",
+    "interceptée",
+    "interceptées",
+    "intercession",
+    "interdire",
+    "interdis",
+    "interdit",
+    "interdite",
+    "interet",
+    "interets",
+    "interjections",
+    "interlocuteur",
+    "interlocuteurs",
+    "interlope",
+    "interminable",
+    "interminables",
+    "intermédiaire",
+    "interpeller",
+    "interpellé",
+    "interposé",
+    "interprètes",
+    "interpréta",
+    "interprétation",
+    "interprétations",
+    "interprété",
+    "interprétée",
+    "interrogatif",
+    "interrogation",
+    "interrogatoire",
+    "interrogatoires",
+    "interroge",
+    "interrogea",
+    "interrogeant",
+    "interroger",
+    "interrogerait",
+    "interrogez",
+    "interrogé",
+    "interrompait",
+    "interrompant",
+    "interrompirent",
+    "interrompit",
+    "interromprait",
+    "interrompre",
+    "interrompt",
+    "interrompu",
+    "interrompue",
+    "interrompues",
+    "interrompus",
+    "interrupteur",
+    "interruption",
+    "intervalle",
+    "intervalles",
+    "intervention",
+    "interviendrais",
+    "intima",
+    "intime",
+    "intimement",
+    "intimes",
+    "intimida",
+    "intimident",
+    "intimider",
+    "intimidé",
+    "intimité",
+    "intitulés",
+    "intolérable",
+    "intolérables",
+    "intonation",
+    "intraitable",
+    "intrigant",
+    "intrigante",
+    "intrigants",
+    "intriguait",
+    "intrigue",
+    "intrigues",
+    "intrigué",
+    "introduire",
+    "introduis",
+    "introduisit",
+    "introduit",
+    "introduite",
+    "introduits",
+    "intrus",
+    "intrépide",
+    "intrépidement",
+    "intrépidité",
+    "intègres",
+    "intéressa",
+    "intéressaient",
+    "intéressait",
+    "intéressant",
+    "intéressante",
+    "intéressantes",
+    "intéressants",
+    "intéresse",
+    "intéressent",
+    "intéresser",
+    "intéresseront",
+    "intéressé",
+    "intéressée",
+    "intéressés",
+    "intérieur",
+    "intérieure",
+    "intérieurement",
+    "intérieurs",
+    "intérêt",
+    "intérêts",
+    "inusitée",
+    "inutile",
+    "inutilement",
+    "inutiles",
+    "inutilite",
+    "inutilité",
+    "invalides",
+    "invariable",
+    "invariablement",
+    "invariables",
+    "invasion",
+    "inventaire",
+    "inventait",
+    "inventant",
+    "invente",
+    "inventer",
+    "inventeur",
+    "inventif",
+    "invention",
+    "inventions",
+    "inventèrent",
+    "inventé",
+    "inventée",
+    "inventées",
+    "invincible",
+    "invinciblement",
+    "invisibles",
+    "invita",
+    "invitait",
+    "invitant",
+    "invitation",
+    "invitations",
+    "invite",
+    "inviter",
+    "inviterai",
+    "inviti",
+    "invité",
+    "invités",
+    "involontaire",
+    "involontairement",
+    "invraisemblable",
+    "invraisemblables",
+    "invraisemblance",
+    "invulnérable",
+    "inébranlable",
+    "inédit",
+    "inégal",
+    "inégalement",
+    "inégales",
+    "inélégantes",
+    "inéprouvé",
+    "inépuisable",
+    "inévitablement",
+    "inévitables",
+    "ioniques",
+    "iowa",
+    "ira",
+    "irai",
+    "iraient",
+    "irais",
+    "irait",
+    "iras",
+    "irascible",
+    "irez",
+    "iriez",
+    "irlande",
+    "ironie",
+    "ironique",
+    "irons",
+    "iront",
+    "irresponsable",
+    "irriguaient",
+    "irrita",
+    "irritable",
+    "irritaient",
+    "irritait",
+    "irritant",
+    "irritation",
+    "irrite",
+    "irriter",
+    "irritèrent",
+    "irrité",
+    "irritée",
+    "irruption",
+    "irréguliers",
+    "irrégulière",
+    "irrégulièrement",
+    "irrémissiblement",
+    "irrémédiable",
+    "irréparable",
+    "irréparablement",
+    "irréprochable",
+    "irréprochables",
+    "irrésistible",
+    "irrésistiblement",
+    "irrésistibles",
+    "irrésolution",
+    "irrésolutions",
+    "irréussites",
+    "irrévocablement",
+    "isole",
+    "isolement",
+    "isolé",
+    "isolée",
+    "isolés",
+    "isota",
+    "israël",
+    "issu",
+    "ita",
+    "italie",
+    "italien",
+    "italienne",
+    "italiennes",
+    "italiens",
+    "itinéraire",
+    "itou",
+    "iv",
+    "ivoire",
+    "ivre",
+    "ivres",
+    "ivresse",
+    "ivrogne",
+    "ivrognes",
+    "ix",
+    "j",
+    "jacobin",
+    "jacobine",
+    "jacobinisme",
+    "jacobins",
+    "jacopo",
+    "jacques",
+    "jadis",
+    "jaggernaut",
+    "jailli",
+    "jaillissaient",
+    "jais",
+    "jalousait",
+    "jalouse",
+    "jalouses",
+    "jalousie",
+    "jalousies",
+    "jalousé",
+    "jaloux",
+    "jamais",
+    "jambe",
+    "jambes",
+    "jansénisme",
+    "janséniste",
+    "jansénistes",
+    "jante",
+    "janvier",
+    "japon",
+    "japonais",
+    "japonaise",
+    "japonaises",
+    "japonaisé",
+    "jaquette",
+    "jardin",
+    "jardinage",
+    "jardinier",
+    "jardins",
+    "jarnac",
+    "jarrets",
+    "jasant",
+    "jasent",
+    "jaser",
+    "jasmin",
+    "jaugeant",
+    "jaune",
+    "jaunes",
+    "jaunets",
+    "jaunies",
+    "jaunâtres",
+    "je",
+    "jean",
+    "jeannes",
+    "jejeebhoy",
+    "jejeeh",
+    "jenrel",
+    "jersey",
+    "jest",
+    "jeta",
+    "jetaient",
+    "jetait",
+    "jetant",
+    "jeter",
+    "jetez",
+    "jetons",
+    "jette",
+    "jettent",
+    "jetterais",
+    "jetterait",
+    "jetterez",
+    "jetât",
+    "jetèrent",
+    "jeté",
+    "jetée",
+    "jetées",
+    "jetés",
+    "jeu",
+    "jeudi",
+    "jeudis",
+    "jeun",
+    "jeune",
+    "jeunes",
+    "jeunesse",
+    "jeux",
+    "jeûnant",
+    "jeûne",
+    "jeûnes",
+    "joie",
+    "joies",
+    "joignait",
+    "joignant",
+    "joignit",
+    "joindre",
+    "joint",
+    "jointe",
+    "jointes",
+    "joli",
+    "jolie",
+    "jolies",
+    "joliment",
+    "jolis",
+    "jonc",
+    "jonché",
+    "jonglaient",
+    "jonglait",
+    "jonglerie",
+    "jongleurs",
+    "jonques",
+    "jonquille",
+    "joseph",
+    "joua",
+    "jouaient",
+    "jouait",
+    "jouant",
+    "joue",
+    "jouer",
+    "jouera",
+    "jouerai",
+    "joueraient",
+    "jouerais",
+    "jouerait",
+    "joues",
+    "joueur",
+    "joueurs",
+    "jouez",
+    "joui",
+    "jouir",
+    "jouira",
+    "jouirait",
+    "jouiras",
+    "jouis",
+    "jouissaient",
+    "jouissait",
+    "jouissance",
+    "jouissances",
+    "jouissant",
+    "jouisse",
+    "jouissent",
+    "jouissons",
+    "jouit",
+    "joujoux",
+    "joumate",
+    "jouons",
+    "jour",
+    "jourdain",
+    "jourdan",
+    "journalistes",
+    "journalière",
+    "journaux",
+    "journellement",
+    "journée",
+    "journées",
+    "jours",
+    "jouât",
+    "joué",
+    "jouée",
+    "joyeuse",
+    "joyeusement",
+    "joyeux",
+    "juan",
+    "jucha",
+    "juché",
+    "judiciaires",
+    "judicieux",
+    "judith",
+    "juge",
+    "jugea",
+    "jugeait",
+    "jugeant",
+    "jugement",
+    "jugements",
+    "jugent",
+    "juger",
+    "jugera",
+    "jugerai",
+    "jugeront",
+    "juges",
+    "jugez",
+    "jugé",
+    "jugés",
+    "juif",
+    "juifs",
+    "juillet",
+    "juin",
+    "jules",
+    "julesburgh",
+    "julia",
+    "julien",
+    "jumna",
+    "junction",
+    "jungles",
+    "junior",
+    "jupon",
+    "jura",
+    "juraient",
+    "jurait",
+    "jurant",
+    "jure",
+    "jurement",
+    "jurements",
+    "jurer",
+    "jurerai",
+    "jurerais",
+    "jures",
+    "jurez",
+    "jurisconsulte",
+    "jurisconsultes",
+    "juron",
+    "jurons",
+    "juré",
+    "jurée",
+    "jurés",
+    "jusqu",
+    "jusque",
+    "jusques",
+    "juste",
+    "justement",
+    "justes",
+    "justesse",
+    "justice",
+    "justifia",
+    "justifiait",
+    "justificatif",
+    "justification",
+    "justifications",
+    "justifie",
+    "justifier",
+    "justifié",
+    "justifiée",
+    "jérusalem",
+    "jérémiades",
+    "jérôme",
+    "jésuite",
+    "jésuites",
+    "jésuitique",
+    "jésuitisme",
+    "jésus",
+    "kalisky",
+    "kallenger",
+    "kama",
+    "kamerfield",
+    "kandallah",
+    "kanhérie",
+    "kansas",
+    "kant",
+    "kauffmann",
+    "kearney",
+    "kehl",
+    "kent",
+    "khajours",
+    "khandeish",
+    "kholby",
+    "kien",
+    "kilomètres",
+    "kiouni",
+    "kirimon",
+    "kirkland",
+    "kléber",
+    "korasoff",
+    "kâli",
+    "l",
+    "la",
+    "laborieusement",
+    "laborieux",
+    "labourée",
+    "lac",
+    "lachaise",
+    "laconiquement",
+    "lacs",
+    "ladislas",
+    "ladri",
+    "lafayette",
+    "lago",
+    "laide",
+    "laides",
+    "laideur",
+    "laideurs",
+    "laine",
+    "laissa",
+    "laissaient",
+    "laissais",
+    "laissait",
+    "laissant",
+    "laisse",
+    "laissent",
+    "laisser",
+    "laissera",
+    "laisserai",
+    "laisserais",
+    "laisserait",
+    "laisserez",
+    "laissez",
+    "laissions",
+    "laissons",
+    "laissât",
+    "laissèrent",
+    "laissé",
+    "laissée",
+    "laissées",
+    "laissés",
+    "lait",
+    "laiterie",
+    "laiton",
+    "laitue",
+    "lambeau",
+    "lambeaux",
+    "lambris",
+    "lambrissée",
+    "lame",
+    "lamentable",
+    "lamentables",
+    "lames",
+    "lampe",
+    "lampes",
+    "lamée",
+    "lance",
+    "lancer",
+    "lancé",
+    "lancée",
+    "lancés",
+    "landau",
+    "landes",
+    "landriani",
+    "langage",
+    "lange",
+    "langue",
+    "languedoc",
+    "langues",
+    "langueur",
+    "languir",
+    "languis",
+    "languissait",
+    "languissante",
+    "lanterne",
+    "lanternes",
+    "lança",
+    "lançaient",
+    "lançait",
+    "lançant",
+    "lapidaire",
+    "lapin",
+    "laps",
+    "laquais",
+    "laque",
+    "laquelle",
+    "laramie",
+    "lard",
+    "larga",
+    "largement",
+    "larges",
+    "largeur",
+    "larguait",
+    "largue",
+    "larme",
+    "larmes",
+    "las",
+    "lascia",
+    "lassa",
+    "lasse",
+    "lasser",
+    "lasseront",
+    "lataniers",
+    "latinam",
+    "latine",
+    "latines",
+    "latiniste",
+    "latinité",
+    "latins",
+    "latérale",
+    "latérales",
+    "laudanum",
+    "laughter",
+    "laurent",
+    "laurier",
+    "lauriers",
+    "lauréat",
+    "lausanne",
+    "lavalette",
+    "lave",
+    "laveno",
+    "laver",
+    "laye",
+    "laïc",
+    "laïcs",
+    "laïque",
+    "le",
+    "lecco",
+    "lecteur",
+    "lecteurs",
+    "lectrice",
+    "lecture",
+    "ledit",
+    "leger",
+    "legitime",
+    "legs",
+    "leipsick",
+    "lek",
+    "lendemain",
+    "lent",
+    "lente",
+    "lentement",
+    "lentes",
+    "lenteur",
+    "lenteurs",
+    "lents",
+    "leo",
+    "lequel",
+    "leroy",
+    "les",
+    "lescaut",
+    "lesdits",
+    "lesquelles",
+    "lesquels",
+    "lesseps",
+    "lest",
+    "leste",
+    "lestement",
+    "lester",
+    "lettre",
+    "lettres",
+    "leur",
+    "leurs",
+    "leva",
+    "levait",
+    "levant",
+    "lever",
+    "levez",
+    "levier",
+    "leviers",
+    "levis",
+    "levèrent",
+    "levé",
+    "levée",
+    "levées",
+    "leçon",
+    "leçons",
+    "lia",
+    "liaison",
+    "liaisons",
+    "liant",
+    "liard",
+    "liasse",
+    "liasses",
+    "libellé",
+    "libellée",
+    "libertin",
+    "libertinage",
+    "liberté",
+    "libraire",
+    "libraires",
+    "libre",
+    "librement",
+    "libres",
+    "libéral",
+    "libérales",
+    "libéralisme",
+    "libérateur",
+    "libéraux",
+    "lichtenberg",
+    "lien",
+    "liens",
+    "lier",
+    "lierre",
+    "lieu",
+    "lieue",
+    "lieues",
+    "lieutenance",
+    "lieutenant",
+    "lieutenants",
+    "lieux",
+    "ligne",
+    "lignes",
+    "ligny",
+    "ligorio",
+    "ligue",
+    "ligués",
+    "lilliputiens",
+    "limercati",
+    "limite",
+    "limites",
+    "limpide",
+    "limpides",
+    "lincoln",
+    "linge",
+    "lingot",
+    "linguam",
+    "lingère",
+    "lion",
+    "lip",
+    "liqueur",
+    "liqueurs",
+    "liquide",
+    "liquidée",
+    "lira",
+    "lirai",
+    "liras",
+    "lire",
+    "lis",
+    "lisaient",
+    "lisait",
+    "lisant",
+    "lisard",
+    "lisent",
+    "lisez",
+    "lisible",
+    "lisière",
+    "lisse",
+    "lisses",
+    "liste",
+    "litanies",
+    "lithographies",
+    "lithographiée",
+    "litière",
+    "litières",
+    "lits",
+    "littéraire",
+    "littéraires",
+    "littéralement",
+    "littérature",
+    "liverpool",
+    "liveru",
+    "livra",
+    "livraient",
+    "livrais",
+    "livrait",
+    "livre",
+    "livrer",
+    "livres",
+    "livret",
+    "livré",
+    "livrée",
+    "livrées",
+    "livrés",
+    "liât",
+    "liège",
+    "lièvre",
+    "lièvres",
+    "lié",
+    "liée",
+    "liées",
+    "liés",
+    "liéven",
+    "ll",
+    "locarno",
+    "locataire",
+    "loch",
+    "locke",
+    "locomotion",
+    "locomotive",
+    "locomotives",
+    "lodge",
+    "lodi",
+    "loge",
+    "logeable",
+    "logement",
+    "logements",
+    "loger",
+    "loges",
+    "logions",
+    "logique",
+    "logiques",
+    "logis",
+    "logé",
+    "logés",
+    "loi",
+    "loin",
+    "lointain",
+    "lointaine",
+    "lointaines",
+    "lointains",
+    "lois",
+    "loisir",
+    "lombarde",
+    "lombardie",
+    "lombardo",
+    "lombards",
+    "lonato",
+    "londonner",
+    "londres",
+    "longanimité",
+    "longe",
+    "longeant",
+    "longerons",
+    "longs",
+    "longsferry",
+    "longtemps",
+    "longue",
+    "longuement",
+    "longues",
+    "longueur",
+    "longueville",
+    "longévité",
+    "lope",
+    "loquerisne",
+    "loques",
+    "loquet",
+    "lords",
+    "lorgnette",
+    "lorgnettes",
+    "lors",
+    "lorsqu",
+    "lorsque",
+    "loterie",
+    "lotus",
+    "loua",
+    "louables",
+    "louage",
+    "louaient",
+    "louange",
+    "louanges",
+    "louant",
+    "louche",
+    "loue",
+    "louer",
+    "louera",
+    "loueras",
+    "loueur",
+    "loueuse",
+    "louez",
+    "louiez",
+    "louis",
+    "louise",
+    "louons",
+    "loup",
+    "loups",
+    "lourd",
+    "lourde",
+    "lourdement",
+    "lourdes",
+    "lourdeur",
+    "loutre",
+    "louvre",
+    "louèrent",
+    "loué",
+    "louée",
+    "louées",
+    "lowe",
+    "loyal",
+    "loyalement",
+    "loyauté",
+    "loyaux",
+    "lu",
+    "lucides",
+    "lucrative",
+    "ludovic",
+    "lue",
+    "lues",
+    "lueur",
+    "lueurs",
+    "lugano",
+    "lugubre",
+    "lugubrement",
+    "lugubres",
+    "lui",
+    "luisante",
+    "lumineuse",
+    "lumière",
+    "lumières",
+    "luna",
+    "lunch",
+    "lundi",
+    "lune",
+    "lunette",
+    "luoghi",
+    "lustre",
+    "lustres",
+    "lut",
+    "luther",
+    "luttaient",
+    "luttait",
+    "lutte",
+    "luttent",
+    "lutter",
+    "luttera",
+    "luttes",
+    "luxe",
+    "luxembourg",
+    "luxuriantes",
+    "luxée",
+    "luz",
+    "lycéens",
+    "lyon",
+    "lyre",
+    "là",
+    "lâcha",
+    "lâchait",
+    "lâche",
+    "lâchement",
+    "lâcher",
+    "lâches",
+    "lâcheté",
+    "lâchez",
+    "lègue",
+    "lèse",
+    "lève",
+    "lèvent",
+    "lèvera",
+    "lèvre",
+    "lèvres",
+    "légale",
+    "légalement",
+    "légalité",
+    "légation",
+    "légendaire",
+    "légendes",
+    "léger",
+    "légers",
+    "légion",
+    "législateur",
+    "législateurs",
+    "législature",
+    "légitime",
+    "légitimes",
+    "légitimité",
+    "légua",
+    "légué",
+    "légère",
+    "légèrement",
+    "légères",
+    "légèreté",
+    "lélio",
+    "léonard",
+    "léontine",
+    "léotard",
+    "lépidoptères",
+    "lés",
+    "lésineries",
+    "léthargique",
+    "lévite",
+    "m",
+    "ma",
+    "macadamisées",
+    "macao",
+    "machiavel",
+    "machiavelli",
+    "machiavélisme",
+    "machinalement",
+    "mackintosh",
+    "macon",
+    "madame",
+    "madeleine",
+    "mademoiselle",
+    "madone",
+    "madras",
+    "madriers",
+    "maestro",
+    "magalon",
+    "magasin",
+    "magasins",
+    "maggiore",
+    "magicien",
+    "magie",
+    "magique",
+    "magistral",
+    "magistrat",
+    "magistrats",
+    "magistrature",
+    "magnanime",
+    "magnificence",
+    "magnifique",
+    "magnifiquement",
+    "magnifiques",
+    "magot",
+    "mahomet",
+    "mahout",
+    "mai",
+    "maigre",
+    "maigres",
+    "maigreur",
+    "maigri",
+    "maigrie",
+    "mailles",
+    "mains",
+    "maintenaient",
+    "maintenait",
+    "maintenant",
+    "maintenir",
+    "maintenue",
+    "maintien",
+    "maintint",
+    "maire",
+    "maires",
+    "mairie",
+    "mais",
+    "maison",
+    "maisons",
+    "maistre",
+    "maitre",
+    "majestueuse",
+    "majestueusement",
+    "majestueux",
+    "majesté",
+    "majeur",
+    "majeure",
+    "majordome",
+    "majordomes",
+    "majorité",
+    "majorités",
+    "mal",
+    "malacca",
+    "malade",
+    "malades",
+    "maladie",
+    "maladies",
+    "maladive",
+    "maladresse",
+    "maladresses",
+    "maladroit",
+    "maladroite",
+    "maladroitement",
+    "malaga",
+    "malagrida",
+    "malais",
+    "malaise",
+    "malaisie",
+    "maldonne",
+    "malebar",
+    "malencontreuse",
+    "malencontreusement",
+    "malencontreux",
+    "malentendu",
+    "malgré",
+    "malhabile",
+    "malheur",
+    "malheureuse",
+    "malheureusement",
+    "malheureuses",
+    "malheureux",
+    "malheurs",
+    "malhonnête",
+    "malhonnêteté",
+    "malice",
+    "malicieux",
+    "maligne",
+    "malignité",
+    "malin",
+    "mall",
+    "malle",
+    "malles",
+    "malligaum",
+    "malmaison",
+    "malmener",
+    "maltraite",
+    "maltraiter",
+    "maltraité",
+    "maltraitées",
+    "malédiction",
+    "maman",
+    "mameluks",
+    "mammacia",
+    "manant",
+    "mancar",
+    "manche",
+    "manches",
+    "manchester",
+    "manda",
+    "mandarin",
+    "mandat",
+    "mandeb",
+    "mandement",
+    "mander",
+    "mandiboy",
+    "mandé",
+    "manette",
+    "mange",
+    "mangea",
+    "mangeaient",
+    "mangeais",
+    "mangeait",
+    "mangeant",
+    "mangent",
+    "mangeoire",
+    "manger",
+    "mangerai",
+    "mangerait",
+    "mangeur",
+    "mangoustes",
+    "mangèrent",
+    "mangé",
+    "maniable",
+    "maniait",
+    "maniant",
+    "maniaque",
+    "manie",
+    "manier",
+    "manifestation",
+    "manifester",
+    "manifesté",
+    "manière",
+    "manières",
+    "manié",
+    "mannequins",
+    "manoeuvraient",
+    "manoeuvrant",
+    "manoeuvre",
+    "manoeuvrer",
+    "manoeuvres",
+    "manoeuvré",
+    "manoeuvrés",
+    "manon",
+    "manouvriers",
+    "manqua",
+    "manquaient",
+    "manquais",
+    "manquait",
+    "manquant",
+    "manque",
+    "manquent",
+    "manquer",
+    "manquera",
+    "manquerai",
+    "manqueraient",
+    "manquerais",
+    "manquerait",
+    "manquerez",
+    "manqueront",
+    "manques",
+    "manquez",
+    "manquons",
+    "manquât",
+    "manquèrent",
+    "manqué",
+    "manquée",
+    "mansarde",
+    "manteau",
+    "manteaux",
+    "mantoue",
+    "manuel",
+    "manufacture",
+    "manufactures",
+    "manufacturiers",
+    "manufacturières",
+    "manuscrit",
+    "manuscrites",
+    "manuscrits",
+    "manège",
+    "maquignon",
+    "marais",
+    "marbre",
+    "marbres",
+    "marcha",
+    "marchaient",
+    "marchait",
+    "marchand",
+    "marchande",
+    "marchandise",
+    "marchandises",
+    "marchands",
+    "marchant",
+    "marche",
+    "marchent",
+    "marchepied",
+    "marchepieds",
+    "marcher",
+    "marcherez",
+    "marcherions",
+    "marcherons",
+    "marches",
+    "marchesi",
+    "marchesine",
+    "marchesino",
+    "marcheur",
+    "marcheurs",
+    "marchez",
+    "marchiez",
+    "marchions",
+    "marchons",
+    "marché",
+    "marchés",
+    "mardi",
+    "mare",
+    "marengo",
+    "marge",
+    "marges",
+    "margot",
+    "marguerite",
+    "marguillier",
+    "mari",
+    "maria",
+    "mariage",
+    "mariages",
+    "mariait",
+    "marie",
+    "marier",
+    "mariera",
+    "marietta",
+    "mariettina",
+    "marin",
+    "marini",
+    "mariniers",
+    "marino",
+    "marins",
+    "maris",
+    "maritime",
+    "maritimes",
+    "marié",
+    "mariée",
+    "mariées",
+    "markésine",
+    "marmande",
+    "marmots",
+    "maroquin",
+    "marote",
+    "marotte",
+    "marqua",
+    "marquaient",
+    "marquait",
+    "marquant",
+    "marque",
+    "marquent",
+    "marquer",
+    "marquerai",
+    "marques",
+    "marqueterie",
+    "marquinot",
+    "marquis",
+    "marquise",
+    "marquèrent",
+    "marqué",
+    "marquée",
+    "marquées",
+    "marqués",
+    "marronnier",
+    "marronniers",
+    "marrons",
+    "mars",
+    "marseille",
+    "marteau",
+    "marteaux",
+    "martial",
+    "martiale",
+    "martyr",
+    "martyre",
+    "marâtre",
+    "marécages",
+    "marécageuse",
+    "maréchal",
+    "maréchale",
+    "maréchaux",
+    "marée",
+    "mascarilles",
+    "masculin",
+    "masculine",
+    "maslon",
+    "masquait",
+    "masque",
+    "masquer",
+    "masques",
+    "masqué",
+    "masquée",
+    "masqués",
+    "massacre",
+    "massacrer",
+    "massacrés",
+    "massant",
+    "masse",
+    "masses",
+    "massif",
+    "massifs",
+    "massillon",
+    "massinger",
+    "mat",
+    "matelas",
+    "matelots",
+    "maternel",
+    "mathilde",
+    "mathématique",
+    "mathématiquement",
+    "mathématiques",
+    "matiere",
+    "matieres",
+    "matin",
+    "matinale",
+    "matins",
+    "matinée",
+    "matière",
+    "matières",
+    "matrimoniale",
+    "matrimonio",
+    "matériel",
+    "matérielle",
+    "matériellement",
+    "matérielles",
+    "matériels",
+    "maubeuge",
+    "maudire",
+    "maudis",
+    "maudissait",
+    "maudissant",
+    "maudissez",
+    "maudit",
+    "maudite",
+    "maudits",
+    "maugiron",
+    "maugirons",
+    "maugréait",
+    "maury",
+    "mausolée",
+    "maussade",
+    "mauvais",
+    "mauvaise",
+    "mauvaises",
+    "mauve",
+    "maux",
+    "maxime",
+    "maximes",
+    "mayence",
+    "maçon",
+    "maçonnerie",
+    "maçons",
+    "maître",
+    "maîtres",
+    "maîtresse",
+    "maîtresses",
+    "maïs",
+    "me",
+    "meako",
+    "medicine",
+    "meilleur",
+    "meilleure",
+    "meilleures",
+    "meilleurs",
+    "melzi",
+    "membre",
+    "membres",
+    "membrure",
+    "meme",
+    "menace",
+    "menacer",
+    "menaces",
+    "menacé",
+    "menacée",
+    "menagio",
+    "menait",
+    "menaça",
+    "menaçaient",
+    "menaçais",
+    "menaçait",
+    "menaçant",
+    "mendiant",
+    "mendiante",
+    "mendicité",
+    "mener",
+    "meneurs",
+    "menez",
+    "menottes",
+    "mens",
+    "mensonge",
+    "mensonges",
+    "mentait",
+    "mentales",
+    "mente",
+    "menteurs",
+    "menteuse",
+    "mentez",
+    "menti",
+    "mentionne",
+    "mentionner",
+    "mentir",
+    "mentirais",
+    "menton",
+    "menues",
+    "menuiserie",
+    "menuisier",
+    "menuisiers",
+    "menèrent",
+    "mené",
+    "menée",
+    "menées",
+    "mer",
+    "mercadante",
+    "mercantile",
+    "merci",
+    "mercredi",
+    "mercure",
+    "mers",
+    "merveille",
+    "merveilles",
+    "merveilleuse",
+    "merveilleusement",
+    "merveilleuses",
+    "merveilleux",
+    "mes",
+    "mesi",
+    "mesquin",
+    "mesquine",
+    "mesquins",
+    "messager",
+    "messageries",
+    "messe",
+    "messes",
+    "messieurs",
+    "messire",
+    "mesure",
+    "mesurer",
+    "mesures",
+    "mesuré",
+    "mesurée",
+    "mesurées",
+    "mesurés",
+    "mets",
+    "mettaient",
+    "mettais",
+    "mettait",
+    "mettant",
+    "mette",
+    "mettent",
+    "mettez",
+    "mettions",
+    "mettons",
+    "mettra",
+    "mettrai",
+    "mettraient",
+    "mettrais",
+    "mettrait",
+    "mettras",
+    "mettre",
+    "mettrez",
+    "mettriez",
+    "mettrons",
+    "mettront",
+    "metz",
+    "meuble",
+    "meubler",
+    "meubles",
+    "meublée",
+    "meublées",
+    "meudon",
+    "meunier",
+    "meure",
+    "meures",
+    "meurs",
+    "meurt",
+    "meurtre",
+    "meurtres",
+    "meurtrier",
+    "meurtris",
+    "meurtrissure",
+    "meurtrière",
+    "meus",
+    "mexicain",
+    "mexique",
+    "mezzo",
+    "mi",
+    "mia",
+    "miaulé",
+    "michaud",
+    "michel",
+    "michele",
+    "michelet",
+    "micheli",
+    "michigan",
+    "microscope",
+    "microscopique",
+    "microscopiques",
+    "midi",
+    "mielleuse",
+    "mielleuses",
+    "mien",
+    "mienne",
+    "miens",
+    "miettes",
+    "mieux",
+    "mignonnes",
+    "migraine",
+    "mikado",
+    "milan",
+    "milanais",
+    "milanaise",
+    "milieu",
+    "militaire",
+    "militaires",
+    "mille",
+    "milles",
+    "milliard",
+    "milliards",
+    "milliers",
+    "millionnaire",
+    "millionnaires",
+    "millions",
+    "mimosées",
+    "minaret",
+    "minarets",
+    "mince",
+    "minces",
+    "minerve",
+    "mines",
+    "mineurs",
+    "miniature",
+    "ministre",
+    "ministres",
+    "ministère",
+    "ministères",
+    "ministériel",
+    "ministérielle",
+    "minuit",
+    "minéralogie",
+    "minéraux",
+    "mirabeau",
+    "miracle",
+    "miracles",
+    "miraculeuse",
+    "mirari",
+    "miraut",
+    "mire",
+    "mirent",
+    "miroir",
+    "mis",
+    "misaine",
+    "mise",
+    "mises",
+    "missel",
+    "mission",
+    "missionnaire",
+    "missions",
+    "mississippi",
+    "missive",
+    "missouri",
+    "misène",
+    "misère",
+    "misères",
+    "misérable",
+    "misérables",
+    "miséricorde",
+    "mitiger",
+    "mitre",
+    "mitres",
+    "mm",
+    "mme",
+    "mobile",
+    "mobiles",
+    "mobilité",
+    "modelée",
+    "moderne",
+    "modernes",
+    "modeste",
+    "modestie",
+    "modifia",
+    "modifierait",
+    "modifiée",
+    "modèle",
+    "modèles",
+    "modène",
+    "modéra",
+    "modération",
+    "modérez",
+    "modéré",
+    "modérée",
+    "modérés",
+    "moelleux",
+    "moeurs",
+    "moi",
+    "moindre",
+    "moindres",
+    "moine",
+    "moineaux",
+    "moines",
+    "moins",
+    "moirod",
+    "mois",
+    "moisson",
+    "moitié",
+    "moka",
+    "mole",
+    "molière",
+    "molle",
+    "mollement",
+    "molleton",
+    "mollissait",
+    "mollit",
+    "moltiplico",
+    "momentanée",
+    "momie",
+    "momies",
+    "mon",
+    "monaca",
+    "monarchie",
+    "monarchies",
+    "monarchique",
+    "monarchiques",
+    "monastères",
+    "moncade",
+    "monceau",
+    "moncontour",
+    "mondain",
+    "mondaine",
+    "mondaines",
+    "mondains",
+    "monde",
+    "monfleury",
+    "monférine",
+    "monghir",
+    "mongolia",
+    "moniteur",
+    "monnaie",
+    "monologue",
+    "monomanes",
+    "monopole",
+    "monosyllabes",
+    "monotone",
+    "monotones",
+    "monseigneur",
+    "monsieur",
+    "monsignor",
+    "monsignore",
+    "monstre",
+    "monstres",
+    "monstrueuses",
+    "mont",
+    "monta",
+    "montagne",
+    "montagnes",
+    "montagneuse",
+    "montagneux",
+    "montagnola",
+    "montaient",
+    "montais",
+    "montait",
+    "montant",
+    "monte",
+    "montent",
+    "monter",
+    "montera",
+    "monterai",
+    "montesson",
+    "montez",
+    "montgommery",
+    "monti",
+    "montmartre",
+    "montmirail",
+    "montmorency",
+    "montoir",
+    "montons",
+    "montra",
+    "montraient",
+    "montrait",
+    "montrant",
+    "montre",
+    "montrent",
+    "montrer",
+    "montrera",
+    "montrerai",
+    "montrerait",
+    "montreriez",
+    "montres",
+    "montreur",
+    "montrez",
+    "montrât",
+    "montrèrent",
+    "montré",
+    "montrée",
+    "montrés",
+    "monts",
+    "monture",
+    "montât",
+    "montèrent",
+    "monté",
+    "montée",
+    "montés",
+    "monument",
+    "monumental",
+    "monuments",
+    "moqua",
+    "moquai",
+    "moquaient",
+    "moquais",
+    "moquait",
+    "moquant",
+    "moque",
+    "moquent",
+    "moquer",
+    "moquera",
+    "moquerais",
+    "moquerait",
+    "moquerie",
+    "moqueur",
+    "moqueurs",
+    "moqueuse",
+    "moqué",
+    "moral",
+    "morale",
+    "morales",
+    "moralité",
+    "moralités",
+    "moraux",
+    "moravie",
+    "morbleu",
+    "morceau",
+    "morceaux",
+    "mord",
+    "mordait",
+    "mordant",
+    "mordantes",
+    "moreri",
+    "morfondre",
+    "mormon",
+    "mormone",
+    "mormones",
+    "mormonisme",
+    "mormons",
+    "morne",
+    "mornes",
+    "morom",
+    "morose",
+    "moroses",
+    "mort",
+    "mortaretti",
+    "morte",
+    "mortel",
+    "mortelle",
+    "mortellement",
+    "mortelles",
+    "mortels",
+    "mortes",
+    "mortier",
+    "mortiers",
+    "mortifiantes",
+    "mortifier",
+    "mortifié",
+    "morts",
+    "morveux",
+    "mosca",
+    "moscou",
+    "moskova",
+    "mosquées",
+    "mot",
+    "moteur",
+    "motifs",
+    "motivât",
+    "motivé",
+    "motrices",
+    "mots",
+    "motu",
+    "mouche",
+    "mouchent",
+    "moucher",
+    "moucheurs",
+    "mouchoir",
+    "mouchoirs",
+    "mouettes",
+    "mouilla",
+    "mouiller",
+    "mouillèrent",
+    "mouillé",
+    "mouillés",
+    "moule",
+    "moulin",
+    "moulus",
+    "mourais",
+    "mourait",
+    "mourant",
+    "mourante",
+    "mourants",
+    "mourions",
+    "mourir",
+    "mourons",
+    "mourra",
+    "mourrai",
+    "mourrais",
+    "mourrait",
+    "mourras",
+    "mourrez",
+    "mourrons",
+    "mourront",
+    "mourshedabad",
+    "mourusse",
+    "mourut",
+    "mourût",
+    "mousqueterie",
+    "mousseline",
+    "mousseux",
+    "moustache",
+    "moustaches",
+    "mouton",
+    "moutonnaient",
+    "moutons",
+    "mouvement",
+    "mouvements",
+    "mouvoir",
+    "moyen",
+    "moyenne",
+    "moyennes",
+    "moyens",
+    "moyeux",
+    "mozart",
+    "mr",
+    "muddy",
+    "mudge",
+    "muet",
+    "muets",
+    "mugissements",
+    "mulet",
+    "muletiers",
+    "mulhouse",
+    "multicolores",
+    "multiplications",
+    "multiplicité",
+    "multiplie",
+    "multiplier",
+    "multipliés",
+    "muni",
+    "munich",
+    "municipal",
+    "munir",
+    "munis",
+    "munster",
+    "mur",
+    "muraille",
+    "murailles",
+    "mural",
+    "murat",
+    "murmura",
+    "murmurait",
+    "murmure",
+    "murmures",
+    "murs",
+    "murées",
+    "muscadiers",
+    "muscles",
+    "musculaire",
+    "musculature",
+    "mushroom",
+    "musicien",
+    "musiciens",
+    "musique",
+    "musset",
+    "musée",
+    "mutilation",
+    "mutilé",
+    "mutsh",
+    "mutuel",
+    "mylord",
+    "mysticité",
+    "mystification",
+    "mystifie",
+    "mystifier",
+    "mystifié",
+    "mystifiée",
+    "mystique",
+    "mystère",
+    "mystères",
+    "mystérieuse",
+    "mystérieusement",
+    "mystérieux",
+    "mâchais",
+    "mâchait",
+    "mâle",
+    "mâles",
+    "mânes",
+    "mât",
+    "mâts",
+    "mâture",
+    "mâtures",
+    "mèche",
+    "mène",
+    "mènera",
+    "mènerai",
+    "mènerais",
+    "mènerait",
+    "mère",
+    "mètres",
+    "mécanicien",
+    "mécanique",
+    "mécaniquement",
+    "mécanisme",
+    "méchanceté",
+    "méchancetés",
+    "méchant",
+    "méchante",
+    "méchantes",
+    "méchants",
+    "mécomptes",
+    "méconnaissez",
+    "mécontent",
+    "mécontente",
+    "mécontentement",
+    "mécontents",
+    "mécène",
+    "médaille",
+    "médecin",
+    "médecine",
+    "médecins",
+    "médicis",
+    "médiocre",
+    "médiocrement",
+    "médiocres",
+    "médiocrité",
+    "médire",
+    "médisance",
+    "médita",
+    "méditait",
+    "méditation",
+    "méditations",
+    "méditer",
+    "méditée",
+    "médée",
+    "médît",
+    "méfiance",
+    "méfiant",
+    "méfie",
+    "méfier",
+    "mélancolie",
+    "mélancolique",
+    "mélancoliquement",
+    "mélange",
+    "mélangé",
+    "mélangée",
+    "mélangées",
+    "mélodie",
+    "mélodieuse",
+    "mélodrame",
+    "mémoire",
+    "mémoires",
+    "mémorial",
+    "ménage",
+    "ménagement",
+    "ménagements",
+    "ménager",
+    "ménagera",
+    "ménages",
+    "ménagez",
+    "ménagé",
+    "méphistophélès",
+    "méprenait",
+    "méprendre",
+    "mépris",
+    "méprisa",
+    "méprisable",
+    "méprisais",
+    "méprisait",
+    "méprisant",
+    "méprise",
+    "méprisent",
+    "mépriser",
+    "méprisera",
+    "mépriserai",
+    "mépriseraient",
+    "mépriserait",
+    "méprisez",
+    "méprisé",
+    "méprisée",
+    "méridien",
+    "méridiens",
+    "méridional",
+    "méridionale",
+    "méridionaux",
+    "mérimée",
+    "méritait",
+    "méritant",
+    "mérite",
+    "mériter",
+    "méritera",
+    "mériterais",
+    "mérites",
+    "méritez",
+    "méritoire",
+    "méritèrent",
+    "mérité",
+    "méritée",
+    "méritées",
+    "mérités",
+    "méry",
+    "mésalliance",
+    "mésaventure",
+    "métal",
+    "métalliques",
+    "métaphysiques",
+    "méthodes",
+    "méthodique",
+    "méthodiquement",
+    "méthodisme",
+    "méticuleuse",
+    "méticuleux",
+    "métier",
+    "métiers",
+    "métropole",
+    "métropolitaine",
+    "météorologiste",
+    "mêla",
+    "mêlaient",
+    "mêlait",
+    "mêlant",
+    "mêle",
+    "mêlent",
+    "mêler",
+    "mêlez",
+    "mêlé",
+    "mêlée",
+    "mêlés",
+    "même",
+    "mêmes",
+    "mît",
+    "mû",
+    "mûr",
+    "mûrement",
+    "mûrir",
+    "n",
+    "nadir",
+    "nagasaki",
+    "nage",
+    "nagent",
+    "nager",
+    "naguère",
+    "nains",
+    "naissance",
+    "naissant",
+    "naissante",
+    "naissent",
+    "nanan",
+    "nani",
+    "nankin",
+    "nanking",
+    "napier",
+    "naples",
+    "napolitain",
+    "napoléon",
+    "napoléons",
+    "nappe",
+    "narcotique",
+    "narra",
+    "narrant",
+    "narrateur",
+    "narration",
+    "narrer",
+    "nasillard",
+    "nassik",
+    "natal",
+    "natif",
+    "nation",
+    "nationale",
+    "nationales",
+    "nationalité",
+    "nationalités",
+    "nations",
+    "naturel",
+    "naturelle",
+    "naturellement",
+    "naturelles",
+    "naturels",
+    "naufrages",
+    "nauséabondes",
+    "nausées",
+    "nautique",
+    "nauvoo",
+    "navarre",
+    "navigateur",
+    "navigateurs",
+    "navigation",
+    "navigua",
+    "naviguant",
+    "naviguent",
+    "navire",
+    "navires",
+    "navré",
+    "navrée",
+    "nazaro",
+    "naît",
+    "naître",
+    "naïf",
+    "naïfs",
+    "naïve",
+    "naïvement",
+    "naïves",
+    "naïveté",
+    "ne",
+    "nebraska",
+    "nef",
+    "nefs",
+    "neige",
+    "neigeait",
+    "neiges",
+    "neptune",
+    "nerfs",
+    "nerval",
+    "nerveuse",
+    "nerveux",
+    "nets",
+    "nette",
+    "nettement",
+    "nettes",
+    "netteté",
+    "nettoya",
+    "nettoyer",
+    "neuf",
+    "neufs",
+    "neutralité",
+    "neutre",
+    "neuvaine",
+    "neuve",
+    "neuves",
+    "neuvième",
+    "nevada",
+    "neveu",
+    "neveux",
+    "ney",
+    "nez",
+    "ni",
+    "niais",
+    "niaiserie",
+    "niaiseries",
+    "niaises",
+    "niche",
+    "nicolas",
+    "nids",
+    "nie",
+    "nier",
+    "nierais",
+    "nierons",
+    "nigaud",
+    "nigauderie",
+    "nigauds",
+    "nil",
+    "niveau",
+    "nivelées",
+    "nizam",
+    "nièce",
+    "nièces",
+    "nié",
+    "nobiliaires",
+    "nobles",
+    "noblesse",
+    "noce",
+    "noces",
+    "nocturne",
+    "nocturnes",
+    "noeud",
+    "noeuds",
+    "noie",
+    "noir",
+    "noirci",
+    "noircie",
+    "noircies",
+    "noircis",
+    "noire",
+    "noires",
+    "noiroud",
+    "noirs",
+    "noix",
+    "nombre",
+    "nombreuse",
+    "nombreuses",
+    "nombreux",
+    "nominal",
+    "nominale",
+    "nomination",
+    "nommait",
+    "nommant",
+    "nomme",
+    "nommer",
+    "nommez",
+    "nommé",
+    "nommée",
+    "nommés",
+    "noms",
+    "nonante",
+    "nonchalance",
+    "nonchalant",
+    "norbert",
+    "nord",
+    "norimons",
+    "normale",
+    "normandie",
+    "normands",
+    "nos",
+    "notablement",
+    "notables",
+    "notaire",
+    "notaires",
+    "noter",
+    "notez",
+    "notices",
+    "notions",
+    "notoire",
+    "notoirement",
+    "notre",
+    "noté",
+    "nouant",
+    "nourri",
+    "nourrice",
+    "nourrie",
+    "nourrir",
+    "nourrirait",
+    "nourris",
+    "nourrissait",
+    "nourrissant",
+    "nourrisse",
+    "nourrissent",
+    "nourrit",
+    "nourriture",
+    "nous",
+    "nouveau",
+    "nouveauté",
+    "nouveautés",
+    "nouveaux",
+    "nouvel",
+    "nouvelle",
+    "nouvellement",
+    "nouvelles",
+    "nouée",
+    "novare",
+    "novateurs",
+    "novembre",
+    "novi",
+    "novice",
+    "noyer",
+    "noyers",
+    "noyée",
+    "noyés",
+    "nu",
+    "nuage",
+    "nuages",
+    "nuance",
+    "nuances",
+    "nudité",
+    "nue",
+    "nues",
+    "nui",
+    "nuira",
+    "nuiraient",
+    "nuirait",
+    "nuire",
+    "nuisait",
+    "nuisant",
+    "nuisible",
+    "nuisibles",
+    "nuit",
+    "nuits",
+    "nulle",
+    "nullement",
+    "nullité",
+    "nuls",
+    "numéro",
+    "numéros",
+    "numérotées",
+    "nunc",
+    "nuremberg",
+    "nus",
+    "nuées",
+    "nègre",
+    "nègres",
+    "né",
+    "néanmoins",
+    "néant",
+    "nébieu",
+    "nécessaire",
+    "nécessairement",
+    "nécessaires",
+    "nécessitant",
+    "nécessité",
+    "née",
+    "nées",
+    "négatif",
+    "néglige",
+    "négligeait",
+    "négligemment",
+    "négligence",
+    "négligents",
+    "négliger",
+    "négligerait",
+    "négligé",
+    "négociant",
+    "négociants",
+    "négociateur",
+    "négociation",
+    "négociations",
+    "nés",
+    "nôtre",
+    "nôtres",
+    "o",
+    "oakland",
+    "oaths",
+    "obadiah",
+    "objecter",
+    "objection",
+    "objections",
+    "objet",
+    "objets",
+    "obligation",
+    "obligations",
+    "obligatoire",
+    "oblige",
+    "obligea",
+    "obligeait",
+    "obligeamment",
+    "obligeances",
+    "obligeant",
+    "obligeante",
+    "obligeantes",
+    "obligeants",
+    "obligent",
+    "obliger",
+    "obligera",
+    "obligeraient",
+    "obligerez",
+    "obligez",
+    "obligeât",
+    "obligé",
+    "obligée",
+    "obligés",
+    "obliqua",
+    "obole",
+    "obscur",
+    "obscurcit",
+    "obscure",
+    "obscures",
+    "obscurité",
+    "obscurément",
+    "observa",
+    "observaient",
+    "observait",
+    "observance",
+    "observant",
+    "observateur",
+    "observateurs",
+    "observation",
+    "observations",
+    "observatoire",
+    "observer",
+    "observèrent",
+    "observé",
+    "observés",
+    "obstacle",
+    "obstacles",
+    "obstina",
+    "obstinait",
+    "obstinant",
+    "obstination",
+    "obstiner",
+    "obstiné",
+    "obstinément",
+    "obstruaient",
+    "obséda",
+    "obsédé",
+    "obtenait",
+    "obtenez",
+    "obtenir",
+    "obtenu",
+    "obtenue",
+    "obtenus",
+    "obtiendrai",
+    "obtiendrais",
+    "obtiendront",
+    "obtienne",
+    "obtiennent",
+    "obtiens",
+    "obtient",
+    "obtinrent",
+    "obtint",
+    "obéi",
+    "obéie",
+    "obéir",
+    "obéira",
+    "obéirai",
+    "obéirais",
+    "obéirez",
+    "obéis",
+    "obéissaient",
+    "obéissait",
+    "obéissance",
+    "obéissant",
+    "obéissants",
+    "obéisse",
+    "obéissez",
+    "obéit",
+    "occasion",
+    "occasionner",
+    "occasionnés",
+    "occasions",
+    "occidentales",
+    "occulte",
+    "occultes",
+    "occupa",
+    "occupaient",
+    "occupait",
+    "occupant",
+    "occupante",
+    "occupation",
+    "occupations",
+    "occupe",
+    "occupent",
+    "occuper",
+    "occupera",
+    "occuperai",
+    "occupiez",
+    "occupons",
+    "occupât",
+    "occupèrent",
+    "occupé",
+    "occupée",
+    "occupées",
+    "occupés",
+    "ocean",
+    "ocre",
+    "octave",
+    "octobre",
+    "octroi",
+    "océan",
+    "océans",
+    "odalisque",
+    "ode",
+    "odes",
+    "odeur",
+    "odieuse",
+    "odieux",
+    "odorante",
+    "odorat",
+    "oeil",
+    "oeuf",
+    "oeufs",
+    "oeuvre",
+    "oeuvres",
+    "offensa",
+    "offensait",
+    "offensant",
+    "offensante",
+    "offensantes",
+    "offense",
+    "offenser",
+    "offenserait",
+    "offenses",
+    "offensive",
+    "offensât",
+    "offensé",
+    "offensée",
+    "offensés",
+    "offert",
+    "offerte",
+    "offerts",
+    "offices",
+    "officiant",
+    "officiel",
+    "officielle",
+    "officiellement",
+    "officielles",
+    "officiels",
+    "officier",
+    "officiers",
+    "offraient",
+    "offrais",
+    "offrait",
+    "offrande",
+    "offrandes",
+    "offrant",
+    "offre",
+    "offrent",
+    "offres",
+    "offrir",
+    "offrira",
+    "offrirai",
+    "offriraient",
+    "offrirait",
+    "offris",
+    "offrit",
+    "offusqué",
+    "ogden",
+    "ogive",
+    "ogre",
+    "ohio",
+    "oies",
+    "oiseau",
+    "oiseaux",
+    "oisifs",
+    "oisive",
+    "olivier",
+    "olo",
+    "omaha",
+    "ombrages",
+    "ombragée",
+    "ombre",
+    "ombres",
+    "ombreuses",
+    "omelette",
+    "omis",
+    "omission",
+    "omnia",
+    "omnibus",
+    "on",
+    "onces",
+    "oncle",
+    "oncles",
+    "onction",
+    "ondulation",
+    "ondulations",
+    "ont",
+    "onze",
+    "opera",
+    "opiner",
+    "opinions",
+    "opiniâtrement",
+    "opium",
+    "opportun",
+    "opportune",
+    "opportunité",
+    "opposaient",
+    "opposant",
+    "oppose",
+    "opposent",
+    "opposer",
+    "opposerai",
+    "opposition",
+    "opposé",
+    "opposée",
+    "opposées",
+    "opposés",
+    "opprimait",
+    "opprimés",
+    "opprobre",
+    "opter",
+    "optime",
+    "opulence",
+    "opulentes",
+    "opulents",
+    "opère",
+    "opéra",
+    "opéraient",
+    "opérait",
+    "opération",
+    "opérations",
+    "opérer",
+    "opérée",
+    "ora",
+    "orage",
+    "orages",
+    "orange",
+    "oranger",
+    "orangerie",
+    "orangers",
+    "oranges",
+    "orangé",
+    "orateur",
+    "orateurs",
+    "orbite",
+    "orchestre",
+    "ordinaire",
+    "ordinairement",
+    "ordinaires",
+    "ordonna",
+    "ordonnait",
+    "ordonnance",
+    "ordonnant",
+    "ordonne",
+    "ordonner",
+    "ordonnera",
+    "ordonnes",
+    "ordonné",
+    "ordonnée",
+    "ordonnés",
+    "ordre",
+    "ordres",
+    "oregon",
+    "oreille",
+    "oreiller",
+    "oreillers",
+    "oreilles",
+    "orfraie",
+    "orfèvre",
+    "orfèvrerie",
+    "orfèvres",
+    "organes",
+    "organisa",
+    "organisait",
+    "organisation",
+    "organiser",
+    "organisèrent",
+    "organisé",
+    "organisée",
+    "orge",
+    "orgie",
+    "orgue",
+    "orgueil",
+    "orgueilleuse",
+    "orgueilleusement",
+    "orgueilleux",
+    "orient",
+    "oriental",
+    "orientale",
+    "orientalistes",
+    "orienter",
+    "originale",
+    "originalité",
+    "originaux",
+    "origine",
+    "orléans",
+    "ornaient",
+    "ornait",
+    "orne",
+    "ornement",
+    "ornementation",
+    "ornements",
+    "ornent",
+    "orner",
+    "ornière",
+    "ornèrent",
+    "orné",
+    "ornée",
+    "ornées",
+    "ornés",
+    "orpheline",
+    "orphelins",
+    "orta",
+    "orteils",
+    "orthographe",
+    "orties",
+    "ory",
+    "os",
+    "osa",
+    "osai",
+    "osaient",
+    "osais",
+    "osait",
+    "osant",
+    "oscillaient",
+    "oscillait",
+    "oscuro",
+    "ose",
+    "oseille",
+    "osent",
+    "oser",
+    "osera",
+    "oserai",
+    "oseraient",
+    "oserais",
+    "oserait",
+    "oserons",
+    "osez",
+    "ostensiblement",
+    "osât",
+    "osèrent",
+    "osé",
+    "osées",
+    "othello",
+    "ottomane",
+    "ou",
+    "ouailles",
+    "oubli",
+    "oublia",
+    "oubliai",
+    "oubliais",
+    "oubliait",
+    "oubliant",
+    "oublie",
+    "oublient",
+    "oublier",
+    "oubliera",
+    "oublierai",
+    "oublierais",
+    "oublierait",
+    "oublierez",
+    "oublies",
+    "oubliez",
+    "oublions",
+    "oublié",
+    "oubliée",
+    "oubliées",
+    "ouest",
+    "oui",
+    "ouragan",
+    "ouragans",
+    "ourdies",
+    "ours",
+    "outils",
+    "outrage",
+    "outrageait",
+    "outrageantes",
+    "outrageants",
+    "outrager",
+    "outrages",
+    "outrageusement",
+    "outragée",
+    "outragés",
+    "outrait",
+    "outrance",
+    "outrant",
+    "outre",
+    "outrepasser",
+    "outrez",
+    "outré",
+    "outrée",
+    "ouvert",
+    "ouverte",
+    "ouvertement",
+    "ouvertes",
+    "ouverts",
+    "ouverture",
+    "ouvrage",
+    "ouvrages",
+    "ouvragé",
+    "ouvraient",
+    "ouvrait",
+    "ouvrant",
+    "ouvrante",
+    "ouvre",
+    "ouvrez",
+    "ouvrier",
+    "ouvriers",
+    "ouvrir",
+    "ouvrira",
+    "ouvrirait",
+    "ouvrirent",
+    "ouvrit",
+    "ouvrière",
+    "ouvrît",
+    "ouï",
+    "ouïe",
+    "ovale",
+    "oxydé",
+    "oysterpuf",
+    "oysters",
+    "où",
+    "p",
+    "pablo",
+    "pace",
+    "pacha",
+    "pacific",
+    "pacifique",
+    "pacifiques",
+    "padoue",
+    "paganisme",
+    "pagato",
+    "pagina",
+    "pagode",
+    "pagodes",
+    "paie",
+    "paiement",
+    "paiements",
+    "paient",
+    "paierai",
+    "paierait",
+    "paieras",
+    "paieriez",
+    "paillasse",
+    "paillasses",
+    "paille",
+    "pains",
+    "paire",
+    "paires",
+    "pairie",
+    "paisible",
+    "paisiblement",
+    "paix",
+    "pal",
+    "palais",
+    "palanquin",
+    "palanquins",
+    "palanza",
+    "palazzeto",
+    "palefrenier",
+    "palestine",
+    "paletot",
+    "palier",
+    "palissades",
+    "palki",
+    "palkigharis",
+    "pall",
+    "palla",
+    "pallagi",
+    "pallida",
+    "palmiers",
+    "palpitait",
+    "palpitant",
+    "palpitante",
+    "palpitants",
+    "palpiter",
+    "pamphlet",
+    "pamphlets",
+    "pan",
+    "panache",
+    "panier",
+    "panneaux",
+    "panorama",
+    "panoramique",
+    "pansa",
+    "pansaient",
+    "pansement",
+    "panser",
+    "pansez",
+    "pantalon",
+    "pantalons",
+    "panthères",
+    "pantoufles",
+    "pança",
+    "paolina",
+    "papa",
+    "pape",
+    "paperasses",
+    "paperassière",
+    "papier",
+    "papiers",
+    "papillon",
+    "papillons",
+    "papillotes",
+    "papouas",
+    "papyrus",
+    "paquebot",
+    "paquebots",
+    "paquet",
+    "paquets",
+    "paquetées",
+    "par",
+    "para",
+    "parade",
+    "parader",
+    "paradis",
+    "parages",
+    "paragraphe",
+    "parais",
+    "paraissaient",
+    "paraissais",
+    "paraissait",
+    "paraisse",
+    "paraissent",
+    "parait",
+    "parallèle",
+    "parallèlement",
+    "paralysaient",
+    "paralyse",
+    "paralyser",
+    "paralysé",
+    "paralytique",
+    "parant",
+    "parapet",
+    "paraphait",
+    "paraphe",
+    "paraphrasa",
+    "parapluie",
+    "paratonnerre",
+    "paratonnerres",
+    "paravent",
+    "paraît",
+    "paraîtra",
+    "paraîtrai",
+    "paraîtraient",
+    "paraître",
+    "paraîtrons",
+    "parbleu",
+    "parc",
+    "parce",
+    "parchemin",
+    "parcouraient",
+    "parcourait",
+    "parcourant",
+    "parcourent",
+    "parcourir",
+    "parcourrai",
+    "parcours",
+    "parcourt",
+    "parcouru",
+    "parcourue",
+    "parcoururent",
+    "parcourus",
+    "parcourut",
+    "pardessus",
+    "pardi",
+    "pardieu",
+    "pardon",
+    "pardonna",
+    "pardonnable",
+    "pardonnables",
+    "pardonnait",
+    "pardonne",
+    "pardonnent",
+    "pardonner",
+    "pardonnera",
+    "pardonnerai",
+    "pardonnerais",
+    "pardonnerait",
+    "pardonnerez",
+    "pardonnes",
+    "pardonnez",
+    "pardonniez",
+    "pardonné",
+    "pardonnées",
+    "pardons",
+    "pare",
+    "pareil",
+    "pareille",
+    "pareilles",
+    "pareils",
+    "parente",
+    "parentes",
+    "parenthèse",
+    "parer",
+    "paresse",
+    "paresseuse",
+    "paresseux",
+    "parfait",
+    "parfaite",
+    "parfaitement",
+    "parfaites",
+    "parfaits",
+    "parfois",
+    "parfum",
+    "parfums",
+    "parfumée",
+    "pari",
+    "paria",
+    "parie",
+    "parient",
+    "parier",
+    "parierais",
+    "parieurs",
+    "paris",
+    "parisien",
+    "parisienne",
+    "parisiennes",
+    "parisiens",
+    "parièrent",
+    "parié",
+    "pariétaires",
+    "parla",
+    "parlaient",
+    "parlais",
+    "parlait",
+    "parlant",
+    "parlantes",
+    "parlants",
+    "parlassent",
+    "parle",
+    "parlement",
+    "parlent",
+    "parler",
+    "parlera",
+    "parlerai",
+    "parlerais",
+    "parlerait",
+    "parlerez",
+    "parlerons",
+    "parleront",
+    "parles",
+    "parleur",
+    "parleurs",
+    "parlez",
+    "parliez",
+    "parlions",
+    "parloir",
+    "parlons",
+    "parlât",
+    "parlèrent",
+    "parlé",
+    "parma",
+    "parme",
+    "parmesan",
+    "parmesans",
+    "parmi",
+    "parois",
+    "paroisse",
+    "paroisses",
+    "paroissiale",
+    "paroissiens",
+    "parole",
+    "paroles",
+    "paroxysme",
+    "parquet",
+    "parquetée",
+    "parrain",
+    "pars",
+    "parsemée",
+    "parsi",
+    "parsie",
+    "parsis",
+    "partage",
+    "partagea",
+    "partageait",
+    "partager",
+    "partagerai",
+    "partagerais",
+    "partagé",
+    "partagée",
+    "partaient",
+    "partait",
+    "partana",
+    "partance",
+    "partant",
+    "parte",
+    "partenaire",
+    "partenaires",
+    "partent",
+    "parterre",
+    "parterres",
+    "partes",
+    "partez",
+    "parti",
+    "particularité",
+    "particularités",
+    "particulier",
+    "particuliers",
+    "particulière",
+    "particulièrement",
+    "partie",
+    "partiez",
+    "partir",
+    "partira",
+    "partirai",
+    "partirais",
+    "partirait",
+    "partirent",
+    "partirez",
+    "partirons",
+    "partis",
+    "partisan",
+    "partisans",
+    "partit",
+    "partner",
+    "partons",
+    "partout",
+    "partît",
+    "paru",
+    "parurent",
+    "parut",
+    "parvenaient",
+    "parvenait",
+    "parvenir",
+    "parvenons",
+    "parvenu",
+    "parvenue",
+    "parviendrai",
+    "parviendraient",
+    "parviendrait",
+    "parviendras",
+    "parviendrons",
+    "parviendront",
+    "parviens",
+    "parvient",
+    "parvinrent",
+    "parvint",
+    "paré",
+    "parée",
+    "parût",
+    "pas",
+    "passa",
+    "passable",
+    "passablement",
+    "passables",
+    "passager",
+    "passagers",
+    "passages",
+    "passagère",
+    "passagères",
+    "passaient",
+    "passais",
+    "passait",
+    "passant",
+    "passants",
+    "passar",
+    "passe",
+    "passementier",
+    "passent",
+    "passepartout",
+    "passeport",
+    "passeports",
+    "passer",
+    "passera",
+    "passerai",
+    "passerais",
+    "passerait",
+    "passerelle",
+    "passerelles",
+    "passerons",
+    "passeront",
+    "passez",
+    "passif",
+    "passion",
+    "passionnait",
+    "passionnant",
+    "passionne",
+    "passionner",
+    "passionné",
+    "passionnée",
+    "passionnées",
+    "passionnément",
+    "passionnés",
+    "passions",
+    "passive",
+    "passons",
+    "passèrent",
+    "passé",
+    "passée",
+    "passées",
+    "passés",
+    "pastoral",
+    "pastorale",
+    "pataugeant",
+    "patelin",
+    "patente",
+    "pater",
+    "paterne",
+    "paternel",
+    "paternelle",
+    "pathos",
+    "pathétique",
+    "pathétiques",
+    "patiemment",
+    "patience",
+    "patinant",
+    "patna",
+    "patois",
+    "patrician",
+    "patricien",
+    "patricienne",
+    "patriciens",
+    "patrie",
+    "patriotes",
+    "patriotisme",
+    "patron",
+    "patronage",
+    "patronne",
+    "patronnes",
+    "patrouille",
+    "patte",
+    "pauca",
+    "paupière",
+    "paupières",
+    "pauvre",
+    "pauvrement",
+    "pauvres",
+    "pauvreté",
+    "pauwell",
+    "pavie",
+    "pavillon",
+    "pavillons",
+    "pavois",
+    "pavé",
+    "pavée",
+    "pawnies",
+    "paya",
+    "payables",
+    "payait",
+    "payant",
+    "paye",
+    "payent",
+    "payer",
+    "payera",
+    "payiez",
+    "payons",
+    "pays",
+    "paysage",
+    "paysages",
+    "paysan",
+    "paysanne",
+    "paysans",
+    "payé",
+    "payée",
+    "payés",
+    "païen",
+    "peak",
+    "peau",
+    "peccadille",
+    "peccavi",
+    "pectorale",
+    "pedroti",
+    "peignaient",
+    "peignait",
+    "peignant",
+    "peignez",
+    "peignit",
+    "peigné",
+    "peindra",
+    "peindre",
+    "peine",
+    "peines",
+    "peint",
+    "peinte",
+    "peintes",
+    "peintre",
+    "peintres",
+    "peints",
+    "peinture",
+    "peintures",
+    "peiné",
+    "peinés",
+    "pelegrino",
+    "pelisse",
+    "pelisses",
+    "pelletées",
+    "pellico",
+    "peloton",
+    "penaud",
+    "penauds",
+    "pence",
+    "pencha",
+    "penchaient",
+    "penchant",
+    "pencher",
+    "penché",
+    "pend",
+    "pendable",
+    "pendables",
+    "pendaient",
+    "pendait",
+    "pendant",
+    "pendante",
+    "pendantes",
+    "pendre",
+    "pendu",
+    "pendue",
+    "pendule",
+    "pendules",
+    "pendus",
+    "peninsular",
+    "pennsylvanie",
+    "penny",
+    "pensa",
+    "pensai",
+    "pensaient",
+    "pensais",
+    "pensait",
+    "pensant",
+    "pensante",
+    "pensants",
+    "pense",
+    "pensent",
+    "penser",
+    "pensera",
+    "penserais",
+    "penserait",
+    "penseur",
+    "pensez",
+    "pensif",
+    "pension",
+    "pensionnaire",
+    "pensive",
+    "pensons",
+    "pensât",
+    "pensèrent",
+    "pensé",
+    "pensée",
+    "pensées",
+    "pentagone",
+    "pentateuque",
+    "pente",
+    "pentes",
+    "pequigny",
+    "perce",
+    "percepteur",
+    "percer",
+    "perche",
+    "perché",
+    "percussion",
+    "percèrent",
+    "percé",
+    "perd",
+    "perdaient",
+    "perdais",
+    "perdait",
+    "perdant",
+    "perde",
+    "perdent",
+    "perdez",
+    "perdirent",
+    "perdit",
+    "perdition",
+    "perdons",
+    "perdra",
+    "perdrai",
+    "perdrais",
+    "perdrait",
+    "perdras",
+    "perdre",
+    "perdreau",
+    "perdriez",
+    "perdrix",
+    "perds",
+    "perdu",
+    "perdue",
+    "perdus",
+    "pereire",
+    "perfection",
+    "perfectionner",
+    "pergolèse",
+    "perles",
+    "permanence",
+    "permanente",
+    "permet",
+    "permets",
+    "permettaient",
+    "permettait",
+    "permettant",
+    "permette",
+    "permettent",
+    "permettez",
+    "permettra",
+    "permettrai",
+    "permettrait",
+    "permettre",
+    "permettrez",
+    "permettront",
+    "permirent",
+    "permis",
+    "permise",
+    "permît",
+    "pernice",
+    "perpendiculairement",
+    "perplexités",
+    "perpétuelle",
+    "perpétuels",
+    "perquisition",
+    "perqué",
+    "perron",
+    "perruque",
+    "perruques",
+    "persans",
+    "perses",
+    "persienne",
+    "persiennes",
+    "persiflage",
+    "persiflèrent",
+    "persista",
+    "persistance",
+    "personnage",
+    "personnages",
+    "personnalité",
+    "personne",
+    "personnel",
+    "personnelle",
+    "personnellement",
+    "personnelles",
+    "personnels",
+    "personnes",
+    "personnification",
+    "personnifiée",
+    "perspectives",
+    "perspicacité",
+    "persuader",
+    "persuadé",
+    "persuadée",
+    "persuasion",
+    "persécutait",
+    "persécute",
+    "persécuterait",
+    "persécutions",
+    "persécuté",
+    "persécutés",
+    "perte",
+    "pertes",
+    "pertuis",
+    "perturbations",
+    "pervers",
+    "perversité",
+    "pervertisse",
+    "perçaient",
+    "perçait",
+    "perçant",
+    "perçante",
+    "pesait",
+    "pesant",
+    "pesante",
+    "pesanteur",
+    "pesants",
+    "peser",
+    "pesez",
+    "pessimisme",
+    "peste",
+    "pester",
+    "pesé",
+    "pesés",
+    "petit",
+    "petite",
+    "petites",
+    "petitesse",
+    "petitesses",
+    "petits",
+    "petto",
+    "peu",
+    "peuple",
+    "peuplent",
+    "peuples",
+    "peuplier",
+    "peuplé",
+    "peuplée",
+    "peuplés",
+    "peur",
+    "peureux",
+    "peurs",
+    "peut",
+    "peuvent",
+    "peux",
+    "pharsale",
+    "pherson",
+    "phileas",
+    "philippe",
+    "philosophant",
+    "philosophe",
+    "philosophes",
+    "philosophie",
+    "philosophique",
+    "philosophiquement",
+    "philosophiques",
+    "phosphorescentes",
+    "photographie",
+    "phrase",
+    "phrases",
+    "physiognomoniques",
+    "physionomie",
+    "physionomies",
+    "physionomistes",
+    "physique",
+    "physiquement",
+    "physiques",
+    "piacere",
+    "pianiste",
+    "piano",
+    "pianos",
+    "pic",
+    "pichegru",
+    "pics",
+    "pie",
+    "pied",
+    "pieds",
+    "pieges",
+    "pier",
+    "pierre",
+    "pierres",
+    "pietragrua",
+    "pietranera",
+    "pieuse",
+    "pieusement",
+    "pieuses",
+    "pieux",
+    "pigeon",
+    "pigeons",
+    "pilastre",
+    "pile",
+    "piler",
+    "pilier",
+    "piliers",
+    "pillage",
+    "pillages",
+    "pillaient",
+    "pillaji",
+    "pille",
+    "pillent",
+    "pillé",
+    "pillée",
+    "pillés",
+    "pilori",
+    "pilote",
+    "pilotes",
+    "pilé",
+    "pimentel",
+    "pinceau",
+    "pincette",
+    "pincée",
+    "pintes",
+    "piochant",
+    "pioneer",
+    "piqua",
+    "piquait",
+    "piquant",
+    "piquante",
+    "piquantes",
+    "pique",
+    "piquer",
+    "piquerait",
+    "piquez",
+    "piqué",
+    "piquée",
+    "piqués",
+    "pirard",
+    "pirate",
+    "pire",
+    "pis",
+    "piste",
+    "pistolet",
+    "pistolets",
+    "piston",
+    "pistons",
+    "pitance",
+    "piteuse",
+    "piteusement",
+    "piteux",
+    "pitié",
+    "pitoyable",
+    "pitt",
+    "pittoresque",
+    "pittoresques",
+    "pittsburg",
+    "pièce",
+    "pièces",
+    "piège",
+    "pièges",
+    "piètre",
+    "piécettes",
+    "piémont",
+    "piémontais",
+    "piémontaise",
+    "piéton",
+    "piétons",
+    "piété",
+    "più",
+    "placard",
+    "placer",
+    "placera",
+    "placerai",
+    "placerait",
+    "placerez",
+    "placers",
+    "placez",
+    "placidité",
+    "placèrent",
+    "placé",
+    "placée",
+    "placées",
+    "placés",
+    "plafond",
+    "plafonds",
+    "plaida",
+    "plaidait",
+    "plaider",
+    "plaidoirie",
+    "plaie",
+    "plaignait",
+    "plaignant",
+    "plaignants",
+    "plaignez",
+    "plaignirent",
+    "plaignit",
+    "plaindrai",
+    "plaindrais",
+    "plaindre",
+    "plaine",
+    "plaines",
+    "plains",
+    "plaint",
+    "plainte",
+    "plaintes",
+    "plaintive",
+    "plaints",
+    "plaira",
+    "plairait",
+    "plaire",
+    "plais",
+    "plaisaient",
+    "plaisait",
+    "plaisamment",
+    "plaisance",
+    "plaisant",
+    "plaisanta",
+    "plaisantaient",
+    "plaisantait",
+    "plaisantant",
+    "plaisante",
+    "plaisantent",
+    "plaisanter",
+    "plaisanterie",
+    "plaisanteries",
+    "plaisantes",
+    "plaisants",
+    "plaisantât",
+    "plaisanté",
+    "plaise",
+    "plaisent",
+    "plaisir",
+    "plaisirs",
+    "planait",
+    "planche",
+    "plancher",
+    "planches",
+    "planchette",
+    "planisphère",
+    "plant",
+    "planta",
+    "plantation",
+    "plantations",
+    "plante",
+    "planter",
+    "planterez",
+    "planterons",
+    "plantes",
+    "plantiez",
+    "planté",
+    "plantés",
+    "plaque",
+    "plaques",
+    "plastron",
+    "plat",
+    "platanes",
+    "plate",
+    "plateau",
+    "platement",
+    "plates",
+    "platitude",
+    "platitudes",
+    "platonique",
+    "plats",
+    "platte",
+    "plausible",
+    "plaça",
+    "plaçait",
+    "plaçant",
+    "plaît",
+    "plein",
+    "pleine",
+    "pleinement",
+    "pleines",
+    "pleins",
+    "pleura",
+    "pleurait",
+    "pleurant",
+    "pleure",
+    "pleurer",
+    "pleurera",
+    "pleurerait",
+    "pleureur",
+    "pleurez",
+    "pleurs",
+    "pleurèrent",
+    "pleuré",
+    "pleutre",
+    "pleuvaient",
+    "pleuvait",
+    "pleuvent",
+    "pli",
+    "plia",
+    "pliaient",
+    "plier",
+    "pliniana",
+    "plis",
+    "plissa",
+    "plié",
+    "pliée",
+    "plomb",
+    "plombées",
+    "plonge",
+    "plongea",
+    "plongeaient",
+    "plongeait",
+    "plongeant",
+    "plonger",
+    "plongerais",
+    "plongé",
+    "plongée",
+    "plongés",
+    "plours",
+    "plu",
+    "pluie",
+    "pluies",
+    "plum",
+    "plume",
+    "plumes",
+    "plupart",
+    "plusieurs",
+    "plut",
+    "plutôt",
+    "plâtre",
+    "plébéien",
+    "plébéiennes",
+    "plébéiens",
+    "plût",
+    "poche",
+    "poches",
+    "poco",
+    "podestat",
+    "podestats",
+    "poemi",
+    "poids",
+    "poignante",
+    "poignantes",
+    "poignard",
+    "poignarda",
+    "poignarder",
+    "poignards",
+    "poignardé",
+    "poignardée",
+    "poigne",
+    "poignée",
+    "poignées",
+    "poils",
+    "poindre",
+    "poing",
+    "poings",
+    "pointe",
+    "pointes",
+    "pointus",
+    "poireaux",
+    "poison",
+    "poisons",
+    "poisson",
+    "poissonnerie",
+    "poissons",
+    "poissy",
+    "poitrail",
+    "poitrine",
+    "poivriers",
+    "pole",
+    "poli",
+    "policeman",
+    "policemen",
+    "polices",
+    "polichinelle",
+    "polidori",
+    "polie",
+    "polies",
+    "poliment",
+    "polis",
+    "polish",
+    "polisson",
+    "politesse",
+    "politesses",
+    "politique",
+    "politiques",
+    "polonais",
+    "polyeucte",
+    "polygames",
+    "polygamie",
+    "polygones",
+    "pommades",
+    "pomme",
+    "pommeau",
+    "pommelé",
+    "pommes",
+    "pommettes",
+    "pommiers",
+    "pompe",
+    "pompes",
+    "pompiers",
+    "pompon",
+    "ponceaux",
+    "poncet",
+    "ponctualité",
+    "ponctuel",
+    "ponctuelle",
+    "ponctuellement",
+    "pondéré",
+    "poney",
+    "poneys",
+    "pont",
+    "pontarlier",
+    "ponte",
+    "ponton",
+    "ponts",
+    "populace",
+    "populaire",
+    "population",
+    "populations",
+    "porcelaine",
+    "porco",
+    "porcs",
+    "porphyre",
+    "porta",
+    "portaient",
+    "portait",
+    "portant",
+    "portative",
+    "porte",
+    "portefaix",
+    "portefeuille",
+    "portefeuilles",
+    "portemanteau",
+    "portent",
+    "porter",
+    "portera",
+    "porteras",
+    "porterez",
+    "porteront",
+    "portes",
+    "porteur",
+    "porteurs",
+    "portez",
+    "portier",
+    "portiers",
+    "portique",
+    "portière",
+    "portières",
+    "porto",
+    "portons",
+    "portrait",
+    "portraits",
+    "portugais",
+    "portugaise",
+    "portât",
+    "portèrent",
+    "porté",
+    "portée",
+    "portées",
+    "portés",
+    "posa",
+    "posant",
+    "poser",
+    "positif",
+    "positivement",
+    "possesseur",
+    "possessions",
+    "possibilité",
+    "possibilités",
+    "possibles",
+    "possède",
+    "possédait",
+    "possédant",
+    "posséder",
+    "possédé",
+    "possédés",
+    "poste",
+    "postes",
+    "posthume",
+    "postillon",
+    "posté",
+    "postérité",
+    "posé",
+    "posée",
+    "posément",
+    "posés",
+    "potage",
+    "potager",
+    "potagères",
+    "potentats",
+    "pouce",
+    "pouces",
+    "poudrait",
+    "poudre",
+    "poudrées",
+    "poudrés",
+    "pouffer",
+    "poule",
+    "poulet",
+    "poulets",
+    "poulies",
+    "poumons",
+    "pounah",
+    "poupée",
+    "poupées",
+    "pour",
+    "pourceaux",
+    "pourparlers",
+    "pourpensées",
+    "pourpoint",
+    "pourpres",
+    "pourquoi",
+    "pourra",
+    "pourrai",
+    "pourraient",
+    "pourrais",
+    "pourrait",
+    "pourras",
+    "pourrez",
+    "pourriez",
+    "pourrions",
+    "pourriture",
+    "pourrons",
+    "pourront",
+    "poursuit",
+    "poursuite",
+    "poursuites",
+    "poursuivaient",
+    "poursuivait",
+    "poursuivant",
+    "poursuivez",
+    "poursuivi",
+    "poursuivie",
+    "poursuivis",
+    "poursuivit",
+    "poursuivrai",
+    "poursuivraient",
+    "poursuivrait",
+    "poursuivre",
+    "poursuivront",
+    "pourtant",
+    "pourvoir",
+    "pourvu",
+    "poussa",
+    "poussaient",
+    "poussait",
+    "poussant",
+    "pousse",
+    "poussent",
+    "pousser",
+    "poussera",
+    "poussez",
+    "poussière",
+    "poussé",
+    "poussée",
+    "poussés",
+    "poutre",
+    "poutres",
+    "pouvaient",
+    "pouvais",
+    "pouvait",
+    "pouvant",
+    "pouvez",
+    "pouviez",
+    "pouvions",
+    "pouvoir",
+    "pouvons",
+    "pozzo",
+    "poème",
+    "poète",
+    "poètes",
+    "poésie",
+    "poésies",
+    "poétique",
+    "poêle",
+    "pradt",
+    "prairie",
+    "prairies",
+    "praticable",
+    "pratiquais",
+    "pratique",
+    "pratiquer",
+    "pratiques",
+    "pratiqué",
+    "pratiquée",
+    "pratiquées",
+    "premier",
+    "premiers",
+    "première",
+    "premièrement",
+    "premières",
+    "prenaient",
+    "prenais",
+    "prenait",
+    "prenant",
+    "prend",
+    "prendra",
+    "prendrai",
+    "prendrais",
+    "prendrait",
+    "prendras",
+    "prendre",
+    "prendrez",
+    "prendront",
+    "prends",
+    "prenez",
+    "prenne",
+    "prennent",
+    "prenons",
+    "prepotenze",
+    "presbytère",
+    "prescrira",
+    "prescrire",
+    "prescrit",
+    "prescrite",
+    "prescrivaient",
+    "prescrivait",
+    "presqu",
+    "presque",
+    "pressa",
+    "pressaient",
+    "pressais",
+    "pressait",
+    "pressant",
+    "pressante",
+    "presse",
+    "pressent",
+    "pressentiment",
+    "pressentiments",
+    "pressentir",
+    "presser",
+    "pression",
+    "pressoir",
+    "pressure",
+    "pressé",
+    "pressée",
+    "pressés",
+    "prestige",
+    "prestigieuse",
+    "prestigieux",
+    "preuve",
+    "preuves",
+    "preux",
+    "pria",
+    "priais",
+    "priait",
+    "priant",
+    "prie",
+    "prier",
+    "prierai",
+    "prieur",
+    "priez",
+    "primer",
+    "primes",
+    "primitif",
+    "primo",
+    "primé",
+    "prina",
+    "prince",
+    "princes",
+    "princesse",
+    "princesses",
+    "principale",
+    "principalement",
+    "principales",
+    "principaux",
+    "principe",
+    "principes",
+    "printemps",
+    "prirent",
+    "pris",
+    "prise",
+    "prises",
+    "prison",
+    "prisonnier",
+    "prisonniers",
+    "prisonnière",
+    "prisons",
+    "prit",
+    "priva",
+    "privation",
+    "prive",
+    "priverai",
+    "privilège",
+    "privilèges",
+    "privilégié",
+    "privilégiée",
+    "privé",
+    "privée",
+    "privées",
+    "privés",
+    "prix",
+    "prière",
+    "prièrent",
+    "prières",
+    "prié",
+    "probabilité",
+    "probabilités",
+    "probable",
+    "probablement",
+    "probantes",
+    "probes",
+    "probité",
+    "problème",
+    "problèmes",
+    "procession",
+    "processionnellement",
+    "processions",
+    "prochain",
+    "prochaine",
+    "proche",
+    "proches",
+    "proclamation",
+    "proclamations",
+    "proclamer",
+    "proclamée",
+    "proctor",
+    "procuration",
+    "procure",
+    "procurer",
+    "procurerai",
+    "procureur",
+    "procureurs",
+    "procuré",
+    "procurée",
+    "procès",
+    "procédure",
+    "procédé",
+    "procédés",
+    "prodige",
+    "prodiges",
+    "prodigieuse",
+    "prodigieusement",
+    "prodigieux",
+    "prodiguait",
+    "prodigue",
+    "prodiguer",
+    "prodiguera",
+    "prodiguerait",
+    "prodiguèrent",
+    "produirait",
+    "produire",
+    "produis",
+    "produisaient",
+    "produisait",
+    "produisant",
+    "produise",
+    "produisit",
+    "produit",
+    "produite",
+    "produites",
+    "produits",
+    "profanateur",
+    "profane",
+    "profanes",
+    "professait",
+    "professeur",
+    "professeurs",
+    "profession",
+    "professions",
+    "profil",
+    "profilait",
+    "profilant",
+    "profils",
+    "profilée",
+    "profit",
+    "profita",
+    "profitable",
+    "profitait",
+    "profitant",
+    "profiter",
+    "profiterait",
+    "profitons",
+    "profits",
+    "profité",
+    "profond",
+    "profonde",
+    "profondes",
+    "profondeur",
+    "profondeurs",
+    "profonds",
+    "profondément",
+    "proférait",
+    "proférant",
+    "proférer",
+    "proférées",
+    "proférés",
+    "programme",
+    "progrès",
+    "prohibée",
+    "proie",
+    "projectiles",
+    "projet",
+    "projets",
+    "projeté",
+    "projetée",
+    "prolonge",
+    "prolongea",
+    "prolongeaient",
+    "prolongeant",
+    "prolongent",
+    "prolonger",
+    "prolongé",
+    "prolongée",
+    "prolongés",
+    "promena",
+    "promenade",
+    "promenades",
+    "promenaient",
+    "promenait",
+    "promenant",
+    "promener",
+    "promeneur",
+    "promeneurs",
+    "promenez",
+    "promenions",
+    "promenèrent",
+    "promené",
+    "promenés",
+    "promesse",
+    "promesses",
+    "promet",
+    "promets",
+    "promettait",
+    "promette",
+    "promettent",
+    "promettez",
+    "promettons",
+    "promettre",
+    "promis",
+    "promit",
+    "promontoire",
+    "promotion",
+    "prompte",
+    "promptement",
+    "promène",
+    "promènent",
+    "prononce",
+    "prononcer",
+    "prononcera",
+    "prononcerait",
+    "prononciation",
+    "prononcèrent",
+    "prononcé",
+    "prononcée",
+    "prononcées",
+    "prononcés",
+    "prononça",
+    "prononçait",
+    "prononçant",
+    "prononçons",
+    "pronostics",
+    "propageaient",
+    "propagèrent",
+    "propension",
+    "prophète",
+    "prophètes",
+    "prophétie",
+    "prophéties",
+    "propice",
+    "propices",
+    "propitiatoires",
+    "proportion",
+    "proportionné",
+    "proportions",
+    "propos",
+    "proposa",
+    "proposait",
+    "propose",
+    "proposer",
+    "proposerait",
+    "proposez",
+    "proposition",
+    "propositions",
+    "proposé",
+    "proposés",
+    "propre",
+    "proprement",
+    "propres",
+    "propreté",
+    "proprio",
+    "propriétaire",
+    "propriétaires",
+    "propriété",
+    "propriétés",
+    "prosaïquement",
+    "prosaïques",
+    "proscrivent",
+    "prose",
+    "prospèrent",
+    "prospérité",
+    "prospérités",
+    "prosterna",
+    "prosternait",
+    "prosternant",
+    "prostration",
+    "prosélytisme",
+    "protecteur",
+    "protecteurs",
+    "protections",
+    "protesta",
+    "protestait",
+    "protestant",
+    "protestante",
+    "protestantisme",
+    "protestants",
+    "protestations",
+    "proteste",
+    "protester",
+    "protesté",
+    "protège",
+    "protégeaient",
+    "protégeait",
+    "protéger",
+    "protégera",
+    "protégez",
+    "protégé",
+    "protégés",
+    "prouesse",
+    "prouva",
+    "prouvaient",
+    "prouvait",
+    "prouvant",
+    "prouve",
+    "prouvent",
+    "prouver",
+    "prouveraient",
+    "prouvez",
+    "prouvé",
+    "prouvée",
+    "prouvées",
+    "provenaient",
+    "provenait",
+    "provence",
+    "proverbe",
+    "proverbiale",
+    "providence",
+    "provient",
+    "province",
+    "provinces",
+    "provincial",
+    "provinciale",
+    "provinciaux",
+    "provision",
+    "provisions",
+    "provocante",
+    "provocatrice",
+    "provoqua",
+    "provoquait",
+    "provoquer",
+    "provoqué",
+    "provoquée",
+    "prude",
+    "prudemment",
+    "prudence",
+    "prudent",
+    "prudente",
+    "prudentes",
+    "prudents",
+    "pruderie",
+    "prunelle",
+    "pruniers",
+    "prusse",
+    "prussien",
+    "prussienne",
+    "prussiens",
+    "près",
+    "pré",
+    "préalable",
+    "préalablement",
+    "préambule",
+    "précaution",
+    "précautions",
+    "précepte",
+    "préceptes",
+    "précepteur",
+    "précepteurs",
+    "précieuse",
+    "précieusement",
+    "précieuses",
+    "précieux",
+    "précipices",
+    "précipita",
+    "précipitaient",
+    "précipitait",
+    "précipitamment",
+    "précipitant",
+    "précipitation",
+    "précipite",
+    "précipitent",
+    "précipiter",
+    "précipitèrent",
+    "précipité",
+    "précipités",
+    "précis",
+    "précise",
+    "précises",
+    "précision",
+    "précisément",
+    "précoces",
+    "précurseur",
+    "précurseurs",
+    "précéda",
+    "précédait",
+    "précédent",
+    "précédente",
+    "précédents",
+    "précédèrent",
+    "précédé",
+    "précédée",
+    "précédés",
+    "prédestiné",
+    "prédestinée",
+    "prédicateur",
+    "prédication",
+    "prédications",
+    "prédiction",
+    "prédictions",
+    "prédire",
+    "prédispose",
+    "prédit",
+    "prédécesseur",
+    "préface",
+    "préfecture",
+    "préfet",
+    "préfets",
+    "préfère",
+    "préfète",
+    "préféra",
+    "préférable",
+    "préférables",
+    "préférait",
+    "préférence",
+    "préférences",
+    "préférer",
+    "préférerez",
+    "préférez",
+    "préfériez",
+    "préféré",
+    "préjudice",
+    "préjudiciable",
+    "préjudicierait",
+    "préjugé",
+    "préjugés",
+    "prélat",
+    "prélature",
+    "prélevés",
+    "préliminaires",
+    "préludé",
+    "prématurément",
+    "préméditation",
+    "prémédité",
+    "préoccupa",
+    "préoccupait",
+    "préoccupation",
+    "préoccupations",
+    "préoccuper",
+    "préoccupé",
+    "préoccupée",
+    "prépara",
+    "préparaient",
+    "préparais",
+    "préparait",
+    "préparant",
+    "préparatifs",
+    "préparation",
+    "préparatoire",
+    "préparatoires",
+    "prépare",
+    "préparer",
+    "préparera",
+    "préparez",
+    "préparât",
+    "préparèrent",
+    "préparé",
+    "préparés",
+    "préposé",
+    "prérogative",
+    "présage",
+    "présageait",
+    "présages",
+    "présence",
+    "présent",
+    "présenta",
+    "présentable",
+    "présentaient",
+    "présentait",
+    "présentant",
+    "présentation",
+    "présentations",
+    "présente",
+    "présentement",
+    "présentent",
+    "présenter",
+    "présentera",
+    "présenterait",
+    "présenterez",
+    "présentes",
+    "présentez",
+    "présents",
+    "présentèrent",
+    "présenté",
+    "présentée",
+    "présentées",
+    "présentés",
+    "préservant",
+    "préserve",
+    "préserver",
+    "préservé",
+    "préservée",
+    "présidait",
+    "présidence",
+    "président",
+    "présidente",
+    "présidents",
+    "présider",
+    "présomption",
+    "présomptions",
+    "présume",
+    "présumé",
+    "préséance",
+    "préséances",
+    "prétend",
+    "prétendaient",
+    "prétendait",
+    "prétendant",
+    "prétendent",
+    "prétendez",
+    "prétendit",
+    "prétendons",
+    "prétendrait",
+    "prétendre",
+    "prétends",
+    "prétendu",
+    "prétendue",
+    "prétendues",
+    "prétendus",
+    "prétention",
+    "prétentions",
+    "prétexta",
+    "prétextant",
+    "prétexte",
+    "prétextes",
+    "prétoire",
+    "prévalant",
+    "prévaloir",
+    "prévenait",
+    "prévenances",
+    "prévenez",
+    "prévenir",
+    "préventions",
+    "prévenu",
+    "prévenus",
+    "prévienne",
+    "préviens",
+    "prévision",
+    "prévisions",
+    "prévoir",
+    "prévois",
+    "prévoit",
+    "prévost",
+    "prévoyait",
+    "prévoyant",
+    "prévu",
+    "prévue",
+    "prêcha",
+    "prêchaient",
+    "prêchait",
+    "prêchant",
+    "prêcher",
+    "prêchera",
+    "prêcherai",
+    "prêcherait",
+    "prêches",
+    "prêché",
+    "prêt",
+    "prêta",
+    "prêtait",
+    "prêtant",
+    "prête",
+    "prêter",
+    "prêtes",
+    "prêtez",
+    "prêtre",
+    "prêtres",
+    "prêts",
+    "prêté",
+    "prêtée",
+    "prêtés",
+    "prît",
+    "prôner",
+    "psalmodie",
+    "psaume",
+    "psaumes",
+    "pseudonyme",
+    "psyché",
+    "pu",
+    "publia",
+    "publicité",
+    "publics",
+    "publie",
+    "publique",
+    "publiquement",
+    "publiques",
+    "publièrent",
+    "publié",
+    "publiée",
+    "publiées",
+    "pudeur",
+    "puis",
+    "puisait",
+    "puisant",
+    "puiser",
+    "puisqu",
+    "puisque",
+    "puissamment",
+    "puissance",
+    "puissances",
+    "puissant",
+    "puissante",
+    "puissantes",
+    "puissants",
+    "puisse",
+    "puissent",
+    "puissiez",
+    "puissions",
+    "puisée",
+    "puisés",
+    "puits",
+    "pullulaient",
+    "pullulent",
+    "punch",
+    "puni",
+    "punie",
+    "punir",
+    "punira",
+    "punirait",
+    "punirmi",
+    "punis",
+    "punisse",
+    "punit",
+    "punition",
+    "punitions",
+    "puntiglio",
+    "punîtes",
+    "pupille",
+    "pupitre",
+    "pur",
+    "purement",
+    "purent",
+    "pureté",
+    "purgeait",
+    "purger",
+    "puritaine",
+    "purs",
+    "purser",
+    "pusillanime",
+    "pusillanimes",
+    "pusillanimité",
+    "pusse",
+    "pussent",
+    "putiphar",
+    "putréfaction",
+    "puériles",
+    "pyramide",
+    "pyrénées",
+    "pâle",
+    "pâles",
+    "pâleur",
+    "pâli",
+    "pâlir",
+    "pâlissaient",
+    "pâlissant",
+    "pâlissantes",
+    "pâlissent",
+    "pâlit",
+    "pâture",
+    "pâté",
+    "pèlerinage",
+    "pèlerinages",
+    "pèlerine",
+    "pèlerins",
+    "père",
+    "pères",
+    "pèse",
+    "péchais",
+    "pécher",
+    "pécheur",
+    "péché",
+    "péchés",
+    "pécule",
+    "pécuniaire",
+    "pédant",
+    "pédanterie",
+    "pédantesque",
+    "pékin",
+    "pélicans",
+    "pénal",
+    "pénible",
+    "péniblement",
+    "pénibles",
+    "péninsulaire",
+    "péninsule",
+    "pénitence",
+    "pénurie",
+    "pénètre",
+    "pénétra",
+    "pénétrait",
+    "pénétrant",
+    "pénétration",
+    "pénétrer",
+    "pénétrera",
+    "pénétrèrent",
+    "pénétré",
+    "pénétrée",
+    "pépites",
+    "pépé",
+    "péri",
+    "péril",
+    "périlleux",
+    "périls",
+    "périmètre",
+    "période",
+    "périodique",
+    "périr",
+    "périraient",
+    "péris",
+    "périsse",
+    "périssent",
+    "périssez",
+    "péristyle",
+    "péristyles",
+    "périt",
+    "pérorait",
+    "péroré",
+    "pérou",
+    "pérouse",
+    "péruvien",
+    "pétaudière",
+    "pétillante",
+    "pétition",
+    "pétitions",
+    "pétrarque",
+    "pétrifié",
+    "pétrifiée",
+    "pétrole",
+    "pétrone",
+    "pétulance",
+    "pêche",
+    "pêcheurs",
+    "pêle",
+    "pô",
+    "pût",
+    "qu",
+    "qua",
+    "quadrille",
+    "quadruple",
+    "quadrupède",
+    "quai",
+    "quais",
+    "quakeresse",
+    "qualifiait",
+    "qualificatif",
+    "qualification",
+    "qualité",
+    "qualités",
+    "quand",
+    "quando",
+    "quant",
+    "quanti",
+    "quantité",
+    "quantième",
+    "quantièmes",
+    "quarantaine",
+    "quarante",
+    "quarantième",
+    "quart",
+    "quarti",
+    "quartier",
+    "quartiers",
+    "quarto",
+    "quarts",
+    "quatorze",
+    "quatre",
+    "quatrième",
+    "quatrièmes",
+    "que",
+    "queenstown",
+    "quel",
+    "quelconque",
+    "quelle",
+    "quelles",
+    "quelqu",
+    "quelque",
+    "quelquefois",
+    "quelques",
+    "quels",
+    "quem",
+    "querelle",
+    "querelles",
+    "questionner",
+    "qui",
+    "quibus",
+    "quichotte",
+    "quiconque",
+    "quid",
+    "quille",
+    "quint",
+    "quintaux",
+    "quinte",
+    "quinzaine",
+    "quinze",
+    "quinzième",
+    "quitta",
+    "quittai",
+    "quittais",
+    "quittait",
+    "quittant",
+    "quitte",
+    "quittent",
+    "quitter",
+    "quittera",
+    "quitterai",
+    "quitterait",
+    "quitterez",
+    "quitterons",
+    "quittes",
+    "quittez",
+    "quittons",
+    "quittât",
+    "quittèrent",
+    "quitté",
+    "quittés",
+    "quoerens",
+    "quoi",
+    "quoiqu",
+    "quoique",
+    "quotidien",
+    "quotidienne",
+    "quotidiennement",
+    "quotité",
+    "quête",
+    "quêtes",
+    "quêteurs",
+    "r",
+    "rabaisser",
+    "rabâchage",
+    "raccommodait",
+    "raccommodant",
+    "raccommodements",
+    "raccommoder",
+    "raccommodé",
+    "raccordent",
+    "raccourcis",
+    "racheter",
+    "rachète",
+    "racine",
+    "raconta",
+    "racontait",
+    "racontant",
+    "raconte",
+    "racontent",
+    "raconter",
+    "raconterai",
+    "raconteraient",
+    "raconterait",
+    "raconterons",
+    "racontez",
+    "racontât",
+    "racontèrent",
+    "raconté",
+    "racontés",
+    "rade",
+    "radieux",
+    "radouci",
+    "rafale",
+    "rafales",
+    "raffinée",
+    "rafraîchie",
+    "rafraîchir",
+    "rafraîchissements",
+    "rafraîchit",
+    "rage",
+    "rageant",
+    "rages",
+    "rageurs",
+    "raide",
+    "raidi",
+    "raidie",
+    "raidirent",
+    "raies",
+    "rail",
+    "raillerie",
+    "rails",
+    "railways",
+    "raison",
+    "raisonna",
+    "raisonnable",
+    "raisonnablement",
+    "raisonnables",
+    "raisonnais",
+    "raisonnait",
+    "raisonnante",
+    "raisonne",
+    "raisonnement",
+    "raisonnements",
+    "raisonner",
+    "raisonneur",
+    "raisons",
+    "rajah",
+    "rajahs",
+    "rajeuni",
+    "ralentie",
+    "ralentir",
+    "ralentissant",
+    "ralentit",
+    "ralliait",
+    "rallier",
+    "ralluma",
+    "ralph",
+    "ramassa",
+    "ramassaient",
+    "ramassait",
+    "ramasse",
+    "ramasser",
+    "ramasseraient",
+    "ramassis",
+    "ramassé",
+    "ramassés",
+    "ramayana",
+    "rambarde",
+    "rambo",
+    "rame",
+    "ramena",
+    "ramenait",
+    "ramenant",
+    "ramener",
+    "rames",
+    "rameurs",
+    "ramifiant",
+    "ramifications",
+    "ramifiées",
+    "rampant",
+    "rampe",
+    "rampes",
+    "ramure",
+    "ramures",
+    "ranchos",
+    "rancune",
+    "rancunier",
+    "rang",
+    "rangea",
+    "rangeait",
+    "ranger",
+    "rangerait",
+    "rangoon",
+    "rangs",
+    "rangèrent",
+    "rangé",
+    "rangée",
+    "rangées",
+    "rangés",
+    "ranima",
+    "ranimer",
+    "ranimèrent",
+    "ranimé",
+    "ranuce",
+    "rapacité",
+    "rapatrier",
+    "rapide",
+    "rapidement",
+    "rapides",
+    "rapidité",
+    "rappela",
+    "rappelaient",
+    "rappelait",
+    "rappelant",
+    "rappeler",
+    "rappelez",
+    "rappelle",
+    "rappellent",
+    "rappellera",
+    "rappellerai",
+    "rappellerais",
+    "rappellerait",
+    "rappellerez",
+    "rappelât",
+    "rappelèrent",
+    "rappelé",
+    "rappelée",
+    "rappelés",
+    "rapport",
+    "rapporta",
+    "rapportaient",
+    "rapportait",
+    "rapportant",
+    "rapporte",
+    "rapporter",
+    "rapporterait",
+    "rapporterons",
+    "rapportes",
+    "rapporteur",
+    "rapports",
+    "rapportât",
+    "rapportèrent",
+    "rapporté",
+    "rapportée",
+    "rapportées",
+    "rapprocha",
+    "rapprochaient",
+    "rapprochait",
+    "rapprochant",
+    "rapproche",
+    "rapprocher",
+    "rapprocherait",
+    "rapprochèrent",
+    "rapproché",
+    "rapprochées",
+    "rapprochés",
+    "raquettes",
+    "rarement",
+    "rares",
+    "raréfiaient",
+    "ras",
+    "rasant",
+    "raser",
+    "raserait",
+    "rassasier",
+    "rassasié",
+    "rassasiée",
+    "rassemblait",
+    "rassembler",
+    "rassemblé",
+    "rassemblés",
+    "rasseoir",
+    "rassi",
+    "rassit",
+    "rassura",
+    "rassuraient",
+    "rassurait",
+    "rassure",
+    "rassurer",
+    "rassurez",
+    "rassuré",
+    "rassurée",
+    "rasséréna",
+    "rasséréner",
+    "rasé",
+    "rasée",
+    "rasées",
+    "ratine",
+    "rationnelle",
+    "rationnellement",
+    "rats",
+    "rattachait",
+    "ravages",
+    "ravaler",
+    "ravenne",
+    "raversi",
+    "ravi",
+    "ravie",
+    "ravins",
+    "ravir",
+    "ravis",
+    "raviser",
+    "ravissait",
+    "ravissant",
+    "ravissante",
+    "ravissantes",
+    "ravissants",
+    "ravissement",
+    "ravisseur",
+    "ravisseurs",
+    "ravit",
+    "ravoir",
+    "raynal",
+    "rayonna",
+    "rayons",
+    "rayées",
+    "razori",
+    "rebelle",
+    "rebelles",
+    "rebâtir",
+    "recette",
+    "recettes",
+    "recevaient",
+    "recevait",
+    "recevant",
+    "recevez",
+    "receviez",
+    "recevoir",
+    "recevra",
+    "recevrai",
+    "recevrait",
+    "recevrez",
+    "recharger",
+    "rechargez",
+    "rechercha",
+    "recherchait",
+    "recherche",
+    "rechercher",
+    "rechercheront",
+    "recherches",
+    "recherché",
+    "recherchés",
+    "rechignant",
+    "rechigné",
+    "rechute",
+    "reciproquement",
+    "recit",
+    "reclus",
+    "recommanda",
+    "recommandait",
+    "recommandant",
+    "recommandation",
+    "recommandations",
+    "recommande",
+    "recommander",
+    "recommandez",
+    "recommandé",
+    "recommandés",
+    "recommence",
+    "recommencer",
+    "recommencerai",
+    "recommenceraient",
+    "recommencerait",
+    "recommencez",
+    "recommencèrent",
+    "recommencé",
+    "recommença",
+    "recommençaient",
+    "recommençait",
+    "recommençât",
+    "recomptait",
+    "reconduire",
+    "reconduisait",
+    "reconduisant",
+    "reconduisit",
+    "reconduit",
+    "reconnais",
+    "reconnaissable",
+    "reconnaissaient",
+    "reconnaissait",
+    "reconnaissance",
+    "reconnaissant",
+    "reconnaisse",
+    "reconnaissent",
+    "reconnaissez",
+    "reconnaît",
+    "reconnaîtra",
+    "reconnaître",
+    "reconnu",
+    "reconnue",
+    "reconnurent",
+    "reconnus",
+    "reconnut",
+    "reconquerrait",
+    "reconquis",
+    "reconquérir",
+    "recoucher",
+    "recoudre",
+    "recourir",
+    "recours",
+    "recouvert",
+    "recouverte",
+    "recouverts",
+    "recouvra",
+    "recouvrait",
+    "recouvrer",
+    "recouvrir",
+    "recouvrée",
+    "recouvrît",
+    "recrues",
+    "recrute",
+    "recruter",
+    "rectifia",
+    "rectifiait",
+    "rectifier",
+    "rectiligne",
+    "recueil",
+    "recueillent",
+    "recueilli",
+    "recueillir",
+    "recueillirent",
+    "recula",
+    "reculant",
+    "recule",
+    "reculer",
+    "reculé",
+    "redemande",
+    "redescend",
+    "redescendant",
+    "redescendit",
+    "redescendre",
+    "redescendue",
+    "redevenaient",
+    "redevenait",
+    "redevenant",
+    "redevenu",
+    "redevenue",
+    "redevint",
+    "redingote",
+    "redingotes",
+    "redira",
+    "redits",
+    "redonner",
+    "redoubla",
+    "redoublaient",
+    "redoublait",
+    "redoublant",
+    "redouble",
+    "redoublement",
+    "redoubler",
+    "redoublerait",
+    "redoublèrent",
+    "redoublé",
+    "redoutables",
+    "redoutaient",
+    "redoutait",
+    "redoute",
+    "redouter",
+    "redoutons",
+    "redouté",
+    "redressait",
+    "redresser",
+    "refaire",
+    "refaisait",
+    "refaites",
+    "referma",
+    "refermer",
+    "refermé",
+    "refermée",
+    "reflet",
+    "reflets",
+    "reflua",
+    "refluait",
+    "reflète",
+    "reflétait",
+    "refouler",
+    "refrain",
+    "refrains",
+    "refroidies",
+    "refroidir",
+    "refuge",
+    "refus",
+    "refusa",
+    "refusait",
+    "refusant",
+    "refusent",
+    "refuser",
+    "refuserait",
+    "refuseriez",
+    "refusez",
+    "refusiez",
+    "refusé",
+    "refusée",
+    "refusées",
+    "regagna",
+    "regagnaient",
+    "regagnait",
+    "regagner",
+    "regagné",
+    "regarda",
+    "regardaient",
+    "regardais",
+    "regardait",
+    "regardant",
+    "regarde",
+    "regardent",
+    "regarder",
+    "regardera",
+    "regarderai",
+    "regarderez",
+    "regarderont",
+    "regardez",
+    "regards",
+    "regardèrent",
+    "regardé",
+    "regardée",
+    "regardés",
+    "regent",
+    "regimbait",
+    "registre",
+    "registres",
+    "regorgeant",
+    "regorgent",
+    "regret",
+    "regrets",
+    "regrettaient",
+    "regrettait",
+    "regrette",
+    "regretter",
+    "regretterais",
+    "regretteriez",
+    "regrettez",
+    "regrettée",
+    "regrettées",
+    "rehausse",
+    "rein",
+    "reina",
+    "reine",
+    "reins",
+    "rejaillir",
+    "rejeta",
+    "rejettera",
+    "rejeté",
+    "rejoignait",
+    "rejoignant",
+    "rejoignent",
+    "rejoignirent",
+    "rejoignit",
+    "rejoindra",
+    "rejoindre",
+    "rejoint",
+    "relais",
+    "relancer",
+    "relatif",
+    "relatifs",
+    "relations",
+    "relativement",
+    "relatives",
+    "releva",
+    "relevaient",
+    "relevait",
+    "relever",
+    "relevé",
+    "relevée",
+    "relevées",
+    "relief",
+    "reliefs",
+    "relier",
+    "religieuse",
+    "religieusement",
+    "religieuses",
+    "religieux",
+    "religion",
+    "religions",
+    "religiosité",
+    "reliquat",
+    "relique",
+    "relire",
+    "relis",
+    "relisais",
+    "relisait",
+    "relise",
+    "relié",
+    "reliée",
+    "reliées",
+    "reliés",
+    "relu",
+    "relut",
+    "relâchait",
+    "relâche",
+    "relâcher",
+    "relâché",
+    "relâchées",
+    "relèvent",
+    "relèveras",
+    "reléguer",
+    "relégué",
+    "reléguée",
+    "rem",
+    "remariais",
+    "remarqua",
+    "remarquable",
+    "remarquablement",
+    "remarquables",
+    "remarquaient",
+    "remarquait",
+    "remarquant",
+    "remarque",
+    "remarquer",
+    "remarquera",
+    "remarquerez",
+    "remarques",
+    "remarquez",
+    "remarquâtes",
+    "remarquèrent",
+    "remarqué",
+    "remarquée",
+    "remarqués",
+    "rembourse",
+    "remboursement",
+    "rembourser",
+    "rembrunit",
+    "remercia",
+    "remerciait",
+    "remerciant",
+    "remercie",
+    "remerciements",
+    "remercier",
+    "remercierais",
+    "remercierait",
+    "remercié",
+    "remerciée",
+    "remercîment",
+    "remercîments",
+    "remet",
+    "remets",
+    "remettait",
+    "remettant",
+    "remette",
+    "remettent",
+    "remettra",
+    "remettrai",
+    "remettrais",
+    "remettrait",
+    "remettras",
+    "remettre",
+    "remettrez",
+    "remiers",
+    "remirent",
+    "remis",
+    "remise",
+    "remises",
+    "remit",
+    "remonta",
+    "remontait",
+    "remontant",
+    "remonte",
+    "remontent",
+    "remonter",
+    "remonterait",
+    "remontez",
+    "remontrance",
+    "remontrances",
+    "remontrer",
+    "remonté",
+    "remords",
+    "remorquant",
+    "remous",
+    "rempart",
+    "remparts",
+    "remplace",
+    "remplacement",
+    "remplacer",
+    "remplacera",
+    "remplacé",
+    "remplacée",
+    "remplacés",
+    "remplaça",
+    "remplaçaient",
+    "remplaçait",
+    "rempli",
+    "remplie",
+    "remplies",
+    "remplir",
+    "remplirent",
+    "remplis",
+    "remplissaient",
+    "remplissait",
+    "remplissant",
+    "remplissent",
+    "remplit",
+    "remplumai",
+    "remportant",
+    "remporter",
+    "remua",
+    "remuant",
+    "remue",
+    "remuer",
+    "remues",
+    "remuée",
+    "remède",
+    "remèdes",
+    "remédier",
+    "renaissant",
+    "renaissantes",
+    "renard",
+    "renaître",
+    "rencontra",
+    "rencontrai",
+    "rencontrait",
+    "rencontrant",
+    "rencontrassent",
+    "rencontre",
+    "rencontrent",
+    "rencontrer",
+    "rencontrerai",
+    "rencontreraient",
+    "rencontres",
+    "rencontrâmes",
+    "rencontrât",
+    "rencontrèrent",
+    "rencontré",
+    "rencontrée",
+    "rencontrées",
+    "rencontrés",
+    "rend",
+    "rendaient",
+    "rendait",
+    "rendant",
+    "rende",
+    "rendent",
+    "rendez",
+    "rendirent",
+    "rendit",
+    "rendra",
+    "rendrai",
+    "rendrait",
+    "rendre",
+    "rendrez",
+    "rendriez",
+    "rendrons",
+    "rendront",
+    "rends",
+    "rendu",
+    "rendue",
+    "rendus",
+    "rendît",
+    "renfermait",
+    "renfermant",
+    "renferme",
+    "renfermer",
+    "renfermé",
+    "renfermée",
+    "renforcé",
+    "renfort",
+    "renfrogné",
+    "reniera",
+    "reno",
+    "renom",
+    "renommé",
+    "renommée",
+    "renonce",
+    "renoncer",
+    "renoncera",
+    "renoncerais",
+    "renoncez",
+    "renoncé",
+    "renonça",
+    "renonçait",
+    "renouer",
+    "renouvela",
+    "renouvelait",
+    "renouvelant",
+    "renouveler",
+    "renouvellement",
+    "renouvellent",
+    "renouvelèrent",
+    "renouvelé",
+    "renouvelées",
+    "renoué",
+    "renseignement",
+    "renseignements",
+    "rente",
+    "rentes",
+    "rentra",
+    "rentrait",
+    "rentrant",
+    "rentre",
+    "rentrent",
+    "rentrer",
+    "rentrera",
+    "rentrerai",
+    "rentrerez",
+    "rentrons",
+    "rentrât",
+    "rentrèrent",
+    "rentré",
+    "rentrée",
+    "rentrés",
+    "renverra",
+    "renverraient",
+    "renverrez",
+    "renverrons",
+    "renversa",
+    "renversant",
+    "renverse",
+    "renversement",
+    "renverser",
+    "renverseront",
+    "renversé",
+    "renversée",
+    "renvoie",
+    "renvoya",
+    "renvoyait",
+    "renvoyant",
+    "renvoyer",
+    "renvoyez",
+    "renvoyiez",
+    "renvoyons",
+    "renvoyèrent",
+    "renvoyé",
+    "renvoyée",
+    "renza",
+    "rené",
+    "repaire",
+    "reparaissaient",
+    "reparaissait",
+    "reparaîtra",
+    "reparaîtrai",
+    "reparaîtrait",
+    "reparaître",
+    "reparaîtrons",
+    "reparlait",
+    "repartaient",
+    "reparti",
+    "repartie",
+    "reparties",
+    "repartir",
+    "repartirent",
+    "repartit",
+    "repartons",
+    "reparu",
+    "reparurent",
+    "reparut",
+    "repas",
+    "repassa",
+    "repassai",
+    "repassait",
+    "repassant",
+    "repasser",
+    "repasserait",
+    "repens",
+    "repensant",
+    "repenser",
+    "repentie",
+    "repentir",
+    "reperdre",
+    "repeter",
+    "replacer",
+    "replaça",
+    "replaçant",
+    "replis",
+    "replièrent",
+    "repliée",
+    "reportant",
+    "reporters",
+    "reporté",
+    "repos",
+    "reposa",
+    "reposait",
+    "repose",
+    "reposer",
+    "reposera",
+    "reposoir",
+    "reposoirs",
+    "reposé",
+    "repoussa",
+    "repoussais",
+    "repoussait",
+    "repoussant",
+    "repousse",
+    "repousser",
+    "repousserait",
+    "repoussez",
+    "repoussé",
+    "repoussée",
+    "repoussées",
+    "repoussés",
+    "reprenaient",
+    "reprenait",
+    "reprenant",
+    "reprend",
+    "reprendra",
+    "reprendrai",
+    "reprendrait",
+    "reprendre",
+    "reprendrons",
+    "reprends",
+    "reprenez",
+    "reprirent",
+    "repris",
+    "reprise",
+    "reprises",
+    "reprit",
+    "reprocha",
+    "reprochaient",
+    "reprochait",
+    "reprochant",
+    "reproche",
+    "reprocher",
+    "reprochera",
+    "reprocherai",
+    "reprocherais",
+    "reproches",
+    "reprochons",
+    "reproché",
+    "reprochée",
+    "reproduire",
+    "reproduisait",
+    "reproduisent",
+    "reproduisirent",
+    "reproduisit",
+    "reproduit",
+    "reprouvez",
+    "représenta",
+    "représentaient",
+    "représentait",
+    "représentant",
+    "représentation",
+    "représentations",
+    "représente",
+    "représenter",
+    "représenté",
+    "reprît",
+    "reptation",
+    "reptiles",
+    "republican",
+    "requiers",
+    "requise",
+    "rescousse",
+    "rescrit",
+    "resegon",
+    "resembleth",
+    "respecta",
+    "respectable",
+    "respectables",
+    "respectais",
+    "respectait",
+    "respectant",
+    "respectante",
+    "respecte",
+    "respecter",
+    "respecterais",
+    "respectueuse",
+    "respectueusement",
+    "respectueuses",
+    "respectueux",
+    "respecté",
+    "respectés",
+    "respira",
+    "respiraient",
+    "respirait",
+    "respirant",
+    "respiration",
+    "respire",
+    "respirer",
+    "respirerai",
+    "respirons",
+    "resplendissant",
+    "resplendissante",
+    "resplendissent",
+    "responsable",
+    "ressemblaient",
+    "ressemblait",
+    "ressemblance",
+    "ressemblant",
+    "ressemble",
+    "ressemblent",
+    "ressembler",
+    "ressemblera",
+    "ressemblez",
+    "ressemblât",
+    "ressemblé",
+    "ressens",
+    "ressentait",
+    "ressentir",
+    "ressentit",
+    "resserré",
+    "ressort",
+    "ressorti",
+    "ressortir",
+    "ressorts",
+    "ressource",
+    "ressources",
+    "ressuscitèrent",
+    "ressuscité",
+    "resta",
+    "restai",
+    "restaient",
+    "restait",
+    "restant",
+    "restassent",
+    "restaurant",
+    "restaurants",
+    "restauration",
+    "restaurations",
+    "restauré",
+    "reste",
+    "restent",
+    "rester",
+    "restera",
+    "resterai",
+    "resteraient",
+    "resterais",
+    "resterait",
+    "resteras",
+    "resterez",
+    "resteront",
+    "restes",
+    "restez",
+    "restiez",
+    "restituit",
+    "restitution",
+    "restitué",
+    "restituée",
+    "restât",
+    "restèrent",
+    "resté",
+    "restée",
+    "restées",
+    "restés",
+    "retard",
+    "retardait",
+    "retardant",
+    "retardataire",
+    "retarde",
+    "retardent",
+    "retarder",
+    "retardez",
+    "retards",
+    "retardé",
+    "retardée",
+    "retenaient",
+    "retenait",
+    "retenant",
+    "retenez",
+    "retenir",
+    "retenti",
+    "retentir",
+    "retentiraient",
+    "retentirait",
+    "retentirent",
+    "retentissaient",
+    "retentissait",
+    "retentissant",
+    "retentissement",
+    "retentit",
+    "retenu",
+    "retenue",
+    "retenues",
+    "retenus",
+    "retiendrai",
+    "retienne",
+    "retiennent",
+    "retient",
+    "retinrent",
+    "retint",
+    "retira",
+    "retiraient",
+    "retirait",
+    "retirant",
+    "retire",
+    "retirer",
+    "retirerais",
+    "retirerait",
+    "retirerez",
+    "retirez",
+    "retirons",
+    "retirât",
+    "retirèrent",
+    "retiré",
+    "retirée",
+    "retirées",
+    "retirés",
+    "retomba",
+    "retombait",
+    "retombant",
+    "retombe",
+    "retomber",
+    "retomberait",
+    "retombes",
+    "retombée",
+    "retour",
+    "retourna",
+    "retournaient",
+    "retournait",
+    "retournant",
+    "retourne",
+    "retourner",
+    "retournez",
+    "retournèrent",
+    "retourné",
+    "retracer",
+    "retraite",
+    "retraites",
+    "retrancher",
+    "retranchés",
+    "retrempait",
+    "retrempé",
+    "retrouva",
+    "retrouvaient",
+    "retrouvait",
+    "retrouvant",
+    "retrouve",
+    "retrouver",
+    "retrouvera",
+    "retrouverai",
+    "retrouverez",
+    "retrouverons",
+    "retrouvât",
+    "retrouvèrent",
+    "retrouvé",
+    "retrouvée",
+    "retz",
+    "revanche",
+    "revaudrai",
+    "revenaient",
+    "revenait",
+    "revenant",
+    "revendeur",
+    "revendre",
+    "revenez",
+    "revenir",
+    "revenons",
+    "revenu",
+    "revenue",
+    "revenus",
+    "reverrai",
+    "reverrais",
+    "reverrait",
+    "reverrez",
+    "reverrons",
+    "reverront",
+    "revers",
+    "reviendra",
+    "reviendrai",
+    "reviendrais",
+    "reviendrait",
+    "reviendrez",
+    "reviendront",
+    "revienne",
+    "reviennent",
+    "reviens",
+    "revient",
+    "revinrent",
+    "revinssent",
+    "revint",
+    "revirement",
+    "revirent",
+    "revissent",
+    "revit",
+    "revivaient",
+    "revoie",
+    "revoies",
+    "revoir",
+    "revois",
+    "revolver",
+    "revolvers",
+    "revoyait",
+    "revoyant",
+    "revu",
+    "revue",
+    "revues",
+    "revêtir",
+    "revêtu",
+    "revêtus",
+    "rez",
+    "reçois",
+    "reçoit",
+    "reçoive",
+    "reçoivent",
+    "reçu",
+    "reçue",
+    "reçues",
+    "reçurent",
+    "reçus",
+    "reçut",
+    "reçût",
+    "rhin",
+    "rhubarbe",
+    "rhume",
+    "rhône",
+    "ri",
+    "riaient",
+    "riais",
+    "riait",
+    "riant",
+    "riante",
+    "riants",
+    "ricanant",
+    "ricanement",
+    "ricciarda",
+    "richards",
+    "riche",
+    "richelieu",
+    "richement",
+    "richemond",
+    "riches",
+    "richesse",
+    "richesses",
+    "richissime",
+    "rideau",
+    "rideaux",
+    "rides",
+    "ridicule",
+    "ridicules",
+    "rie",
+    "rien",
+    "riens",
+    "rient",
+    "rienzi",
+    "rigides",
+    "rigoureuse",
+    "rigoureusement",
+    "rigoureux",
+    "rigueur",
+    "rigueurs",
+    "rimailleur",
+    "rimes",
+    "rios",
+    "riposta",
+    "ripostaient",
+    "rire",
+    "rires",
+    "ris",
+    "riscara",
+    "risqua",
+    "risquaient",
+    "risque",
+    "risquer",
+    "risquerai",
+    "risques",
+    "risquiez",
+    "risquons",
+    "risqué",
+    "rit",
+    "ritournelle",
+    "riva",
+    "rivage",
+    "rival",
+    "rivale",
+    "rivales",
+    "rivalité",
+    "rivarol",
+    "rivaux",
+    "rive",
+    "riverisco",
+    "rives",
+    "rivière",
+    "rivières",
+    "rivoli",
+    "riz",
+    "rizières",
+    "roads",
+    "roastbeef",
+    "robe",
+    "robert",
+    "robes",
+    "robespierre",
+    "robre",
+    "robres",
+    "robuste",
+    "robustes",
+    "roc",
+    "roch",
+    "rocher",
+    "rochers",
+    "roches",
+    "rocheuses",
+    "rocky",
+    "roclin",
+    "rogations",
+    "rognure",
+    "rogné",
+    "rogue",
+    "roguerie",
+    "roi",
+    "rois",
+    "roiville",
+    "roland",
+    "romagnan",
+    "romagnano",
+    "romagne",
+    "romain",
+    "romaine",
+    "romains",
+    "romanesque",
+    "romanesques",
+    "romans",
+    "rome",
+    "rompe",
+    "rompez",
+    "rompit",
+    "rompre",
+    "rompt",
+    "rompu",
+    "rompus",
+    "ronces",
+    "ronceux",
+    "rond",
+    "ronde",
+    "rondement",
+    "rondes",
+    "ronds",
+    "ronflait",
+    "ronflantes",
+    "ronfle",
+    "ronflement",
+    "ronflements",
+    "rongeait",
+    "ronsard",
+    "roseau",
+    "roseaux",
+    "roses",
+    "rosier",
+    "rosiers",
+    "rosse",
+    "rosses",
+    "rossini",
+    "rossé",
+    "rosée",
+    "rothal",
+    "rothschild",
+    "rotrou",
+    "roue",
+    "rouer",
+    "roueries",
+    "roues",
+    "rouffles",
+    "rouge",
+    "rouges",
+    "rougeur",
+    "rougeâtre",
+    "rougi",
+    "rougir",
+    "rougira",
+    "rougirait",
+    "rougissait",
+    "rougissant",
+    "rougit",
+    "rouille",
+    "rouillé",
+    "roula",
+    "roulaient",
+    "roulait",
+    "roulant",
+    "roule",
+    "rouleau",
+    "roulement",
+    "roulis",
+    "roulèrent",
+    "rousseau",
+    "routes",
+    "rouvert",
+    "rouvray",
+    "rouvrit",
+    "roué",
+    "rovere",
+    "rovère",
+    "rowan",
+    "royal",
+    "royale",
+    "royaliste",
+    "royalistes",
+    "royaume",
+    "ruban",
+    "rubempré",
+    "rubigneau",
+    "rude",
+    "rudement",
+    "rudes",
+    "rudesse",
+    "rue",
+    "rues",
+    "ruga",
+    "rugissements",
+    "ruina",
+    "ruinait",
+    "ruine",
+    "ruinent",
+    "ruiner",
+    "ruines",
+    "ruineux",
+    "ruinez",
+    "ruiné",
+    "ruinée",
+    "ruinées",
+    "ruinés",
+    "ruisseau",
+    "ruisseaux",
+    "ruminait",
+    "ruminants",
+    "rupture",
+    "rus",
+    "rusca",
+    "ruse",
+    "russe",
+    "russell",
+    "russes",
+    "russie",
+    "rusé",
+    "râlaient",
+    "râpé",
+    "râpés",
+    "règle",
+    "règlement",
+    "règlements",
+    "règles",
+    "règne",
+    "règnent",
+    "réaction",
+    "réal",
+    "réalisable",
+    "réaliser",
+    "réaliste",
+    "réalité",
+    "réapprovisionner",
+    "rébarbatif",
+    "rébarbative",
+    "rébellion",
+    "récapitulant",
+    "récemment",
+    "récente",
+    "réception",
+    "réceptions",
+    "réchauffé",
+    "récidive",
+    "réciproquement",
+    "récit",
+    "récita",
+    "récitaient",
+    "récitait",
+    "récitant",
+    "récitatif",
+    "récitation",
+    "récite",
+    "réciter",
+    "réciterai",
+    "réciterez",
+    "récits",
+    "récité",
+    "réclamait",
+    "réclame",
+    "réclamer",
+    "réclamée",
+    "réclamés",
+    "réclusion",
+    "récolte",
+    "récompense",
+    "récompensé",
+    "récompensée",
+    "réconciliation",
+    "réconcilie",
+    "récriait",
+    "récrier",
+    "récrièrent",
+    "récréation",
+    "récréations",
+    "rédacteur",
+    "rédacteurs",
+    "rédaction",
+    "rédigea",
+    "rédigerait",
+    "rédigée",
+    "réduiras",
+    "réduire",
+    "réduirons",
+    "réduisait",
+    "réduisent",
+    "réduisit",
+    "réduit",
+    "réduite",
+    "réduites",
+    "réduits",
+    "réel",
+    "réelle",
+    "réellement",
+    "réelles",
+    "réels",
+    "réexpédié",
+    "réfectoire",
+    "réflexion",
+    "réflexions",
+    "réfléchi",
+    "réfléchir",
+    "réfléchirez",
+    "réfléchissait",
+    "réfléchissant",
+    "réfléchissez",
+    "réfléchit",
+    "réfléchît",
+    "réfractaire",
+    "réfractaires",
+    "réfugia",
+    "réfugiait",
+    "réfugiant",
+    "réfugie",
+    "réfugier",
+    "réfugieront",
+    "réfugiez",
+    "réfugié",
+    "réfugiée",
+    "réfugiés",
+    "réfutés",
+    "références",
+    "régalait",
+    "régate",
+    "régent",
+    "régime",
+    "régiment",
+    "régiments",
+    "régimes",
+    "région",
+    "régions",
+    "régla",
+    "réglant",
+    "réglementaire",
+    "réglementaires",
+    "réglementation",
+    "réglementée",
+    "régler",
+    "réglera",
+    "réglé",
+    "réglée",
+    "réglés",
+    "régna",
+    "régnaient",
+    "régnait",
+    "régnant",
+    "régnante",
+    "régner",
+    "régnera",
+    "régnez",
+    "régnons",
+    "régné",
+    "régularisé",
+    "régularité",
+    "régulateur",
+    "régulier",
+    "régulière",
+    "régulièrement",
+    "régénérateur",
+    "régénérer",
+    "réintégré",
+    "réintégrés",
+    "réitérée",
+    "réjouie",
+    "réjouir",
+    "réjouirez",
+    "réjouissait",
+    "réjouissances",
+    "réjouit",
+    "rémission",
+    "rémunération",
+    "répandaient",
+    "répandait",
+    "répandant",
+    "répandent",
+    "répandit",
+    "répandrait",
+    "répandre",
+    "répandu",
+    "répandue",
+    "répandues",
+    "répandît",
+    "réparation",
+    "réparations",
+    "réparent",
+    "réparer",
+    "répareraient",
+    "réparerait",
+    "répareras",
+    "répartition",
+    "réparée",
+    "répit",
+    "répliqua",
+    "répliquait",
+    "réplique",
+    "répliquer",
+    "répliquerai",
+    "répliques",
+    "répond",
+    "répondaient",
+    "répondais",
+    "répondait",
+    "répondant",
+    "répondez",
+    "répondiez",
+    "répondirent",
+    "répondit",
+    "répondra",
+    "répondrai",
+    "répondrais",
+    "répondrait",
+    "répondras",
+    "répondre",
+    "répondrez",
+    "réponds",
+    "répondu",
+    "répondît",
+    "réponse",
+    "réponses",
+    "réprimande",
+    "réprimer",
+    "républicain",
+    "républicains",
+    "république",
+    "républiques",
+    "répudié",
+    "répugnait",
+    "répugnance",
+    "réputation",
+    "réputations",
+    "répète",
+    "répètent",
+    "répéta",
+    "répétaient",
+    "répétait",
+    "répétant",
+    "répéter",
+    "répétera",
+    "répéterai",
+    "répéterait",
+    "répéterons",
+    "répéteront",
+    "répétez",
+    "répétions",
+    "répétiteur",
+    "répétât",
+    "répété",
+    "répétée",
+    "répétées",
+    "réquisitionnaire",
+    "réseau",
+    "réserva",
+    "réservait",
+    "réserve",
+    "réserver",
+    "réserves",
+    "réservoir",
+    "réservé",
+    "réservée",
+    "réservées",
+    "réservés",
+    "résidais",
+    "résidait",
+    "résidence",
+    "résigna",
+    "résignation",
+    "résigner",
+    "résigné",
+    "résignée",
+    "résines",
+    "résista",
+    "résistaient",
+    "résistait",
+    "résistance",
+    "résistant",
+    "résiste",
+    "résister",
+    "résisterez",
+    "résistes",
+    "résolu",
+    "résolue",
+    "résolues",
+    "résolument",
+    "résolurent",
+    "résolut",
+    "résolution",
+    "résolutions",
+    "résonnaient",
+    "résoudre",
+    "résultait",
+    "résultat",
+    "résultats",
+    "résumait",
+    "résumer",
+    "résumons",
+    "résumé",
+    "résurrection",
+    "rétabli",
+    "rétablie",
+    "rétablir",
+    "rétabliras",
+    "rétablissait",
+    "rétablissement",
+    "rétablit",
+    "rétracte",
+    "rétribution",
+    "rétrospectif",
+    "rétréci",
+    "rétrécir",
+    "rétrécissait",
+    "réuni",
+    "réunie",
+    "réunies",
+    "réunion",
+    "réunir",
+    "réunirent",
+    "réuniront",
+    "réunis",
+    "réunissaient",
+    "réunissait",
+    "réunissant",
+    "réunissent",
+    "réunissez",
+    "réunit",
+    "réussi",
+    "réussir",
+    "réussirais",
+    "réussis",
+    "réussissait",
+    "réussissez",
+    "réussit",
+    "réussite",
+    "réveil",
+    "réveilla",
+    "réveillait",
+    "réveillant",
+    "réveille",
+    "réveiller",
+    "réveillât",
+    "réveillèrent",
+    "réveillé",
+    "réveillée",
+    "réveillés",
+    "révolta",
+    "révoltante",
+    "révoltantes",
+    "révolte",
+    "révolter",
+    "révoltât",
+    "révolté",
+    "révoltée",
+    "révolues",
+    "révolus",
+    "révolution",
+    "révolutionnaire",
+    "révolutionnaires",
+    "révolutions",
+    "révoquerai",
+    "révoquerez",
+    "révoquée",
+    "révéla",
+    "révélait",
+    "révélation",
+    "révéler",
+    "révélé",
+    "révérence",
+    "révérences",
+    "révérencieuses",
+    "révérend",
+    "révérendissime",
+    "révéré",
+    "réélection",
+    "rênal",
+    "rêne",
+    "rêva",
+    "rêvait",
+    "rêvant",
+    "rêve",
+    "rêver",
+    "rêverie",
+    "rêveries",
+    "rêves",
+    "rêveur",
+    "rêveuse",
+    "rêvez",
+    "rêvé",
+    "rît",
+    "rôdait",
+    "rôdant",
+    "rôder",
+    "rôle",
+    "rôles",
+    "rôti",
+    "rôtie",
+    "rôties",
+    "s",
+    "sa",
+    "sablé",
+    "sablées",
+    "sabre",
+    "sabrer",
+    "sabres",
+    "sabré",
+    "sabrés",
+    "sac",
+    "sacca",
+    "saccadés",
+    "sachant",
+    "sache",
+    "saches",
+    "sachez",
+    "sachiez",
+    "sachons",
+    "sacramentelle",
+    "sacramento",
+    "sacre",
+    "sacrement",
+    "sacrements",
+    "sacrifiait",
+    "sacrifiant",
+    "sacrifice",
+    "sacrifices",
+    "sacrifie",
+    "sacrifier",
+    "sacrifierait",
+    "sacrifiez",
+    "sacrifié",
+    "sacrifiées",
+    "sacrifiés",
+    "sacrilège",
+    "sacristie",
+    "sacré",
+    "sacrédié",
+    "sacrée",
+    "sacrées",
+    "sacrés",
+    "saddle",
+    "sagacité",
+    "sage",
+    "sagement",
+    "sages",
+    "sagesse",
+    "sagoutiers",
+    "saigna",
+    "saignait",
+    "saigner",
+    "saigné",
+    "saignées",
+    "saillant",
+    "saillante",
+    "saillantes",
+    "saillants",
+    "saillie",
+    "saillies",
+    "sain",
+    "sainclair",
+    "saine",
+    "sains",
+    "saint",
+    "sainte",
+    "saintes",
+    "sainteté",
+    "saints",
+    "sais",
+    "saisi",
+    "saisie",
+    "saisines",
+    "saisir",
+    "saisirais",
+    "saisirent",
+    "saisis",
+    "saisissait",
+    "saisissant",
+    "saisissante",
+    "saisissement",
+    "saisissez",
+    "saisit",
+    "saison",
+    "saisîtes",
+    "sait",
+    "saki",
+    "salaire",
+    "salanganes",
+    "salcette",
+    "sale",
+    "salement",
+    "sales",
+    "saleté",
+    "salis",
+    "salle",
+    "salles",
+    "salomon",
+    "salon",
+    "salons",
+    "saltimbanques",
+    "salua",
+    "saluaient",
+    "saluait",
+    "saluant",
+    "salue",
+    "saluer",
+    "salure",
+    "salut",
+    "salutaire",
+    "saluèrent",
+    "salué",
+    "salé",
+    "samedi",
+    "samedis",
+    "samuel",
+    "sancho",
+    "sanctifie",
+    "sanctuaire",
+    "sanctus",
+    "sandales",
+    "sandolaro",
+    "sandrino",
+    "sandy",
+    "sang",
+    "sanglant",
+    "sanglante",
+    "sanglier",
+    "sanglot",
+    "sanglotaient",
+    "sanglotant",
+    "sanglots",
+    "sangsues",
+    "sanguigna",
+    "sanguinaire",
+    "sannazaro",
+    "sans",
+    "sanseverina",
+    "santal",
+    "santé",
+    "saper",
+    "sapin",
+    "sapins",
+    "saragosse",
+    "sarasine",
+    "sarcasmes",
+    "sardonique",
+    "sarono",
+    "sarà",
+    "satin",
+    "satirique",
+    "satiriques",
+    "satisfaction",
+    "satisfaire",
+    "satisfaisant",
+    "satisfait",
+    "satisfera",
+    "satiété",
+    "saturaient",
+    "saturée",
+    "sauce",
+    "saucisses",
+    "sauders",
+    "sauf",
+    "saugrenues",
+    "saule",
+    "saules",
+    "saura",
+    "saurai",
+    "sauraient",
+    "saurais",
+    "saurait",
+    "saurez",
+    "sauriez",
+    "saurons",
+    "sauront",
+    "saut",
+    "sauta",
+    "sautait",
+    "sautant",
+    "saute",
+    "sauter",
+    "sauterai",
+    "sauterait",
+    "sauteur",
+    "sauteuse",
+    "sautillant",
+    "sautoir",
+    "sauts",
+    "sautèrent",
+    "sauté",
+    "sautés",
+    "sauva",
+    "sauvage",
+    "sauvages",
+    "sauvait",
+    "sauvant",
+    "sauve",
+    "sauvegarder",
+    "sauvent",
+    "sauver",
+    "sauvera",
+    "sauverai",
+    "sauveraient",
+    "sauveront",
+    "sauveur",
+    "sauveurs",
+    "sauvez",
+    "sauvions",
+    "sauvons",
+    "sauvé",
+    "sauvée",
+    "sauvés",
+    "savaient",
+    "savais",
+    "savait",
+    "savamment",
+    "savant",
+    "savante",
+    "savantes",
+    "savants",
+    "savent",
+    "saveur",
+    "savez",
+    "saviez",
+    "saville",
+    "savoir",
+    "savons",
+    "saxe",
+    "saxon",
+    "saxons",
+    "saïd",
+    "sbires",
+    "scabreuses",
+    "scabreux",
+    "scagliola",
+    "scala",
+    "scalpent",
+    "scandale",
+    "scandaleuse",
+    "scandalisaient",
+    "scandalisera",
+    "scandalisé",
+    "scandalisée",
+    "scandalisés",
+    "scandinavie",
+    "sceau",
+    "sceaux",
+    "scellé",
+    "schiller",
+    "schuyler",
+    "sciant",
+    "scie",
+    "sciemment",
+    "sciences",
+    "scier",
+    "scierie",
+    "scies",
+    "scieur",
+    "scintillants",
+    "scintillations",
+    "scié",
+    "scotland",
+    "scott",
+    "scotti",
+    "scrupule",
+    "scrupules",
+    "scrupuleuse",
+    "scrupuleusement",
+    "scrutateur",
+    "sculpteur",
+    "sculpteurs",
+    "sculptée",
+    "sculptées",
+    "sculptés",
+    "scène",
+    "scènes",
+    "scélérat",
+    "scélératesse",
+    "scélérats",
+    "se",
+    "secatore",
+    "secondaient",
+    "seconde",
+    "secondent",
+    "seconderait",
+    "secondes",
+    "secondé",
+    "secoua",
+    "secouaient",
+    "secouait",
+    "secouant",
+    "secoue",
+    "secourable",
+    "secourez",
+    "secourir",
+    "secours",
+    "secouru",
+    "secousse",
+    "secousses",
+    "secouée",
+    "secoués",
+    "secrète",
+    "secrètement",
+    "secrètes",
+    "secrétaire",
+    "secrétaires",
+    "secs",
+    "sectaire",
+    "sectateur",
+    "sectateurs",
+    "secte",
+    "secundo",
+    "sedgwick",
+    "sediola",
+    "segreto",
+    "seigneur",
+    "seigneuries",
+    "seigneurs",
+    "sein",
+    "seine",
+    "seize",
+    "sel",
+    "sella",
+    "selle",
+    "sellette",
+    "sellé",
+    "selon",
+    "sema",
+    "semaine",
+    "semaines",
+    "sembla",
+    "semblable",
+    "semblables",
+    "semblaient",
+    "semblait",
+    "semblant",
+    "semble",
+    "semblent",
+    "sembler",
+    "semblera",
+    "semblerait",
+    "semblez",
+    "semblât",
+    "semblèrent",
+    "semblé",
+    "semelle",
+    "semelles",
+    "semer",
+    "semez",
+    "semi",
+    "sempans",
+    "semé",
+    "semée",
+    "semées",
+    "semés",
+    "sens",
+    "sensation",
+    "sensations",
+    "sensibilité",
+    "sensible",
+    "sensiblement",
+    "sensibles",
+    "sensée",
+    "sensés",
+    "sentaient",
+    "sentais",
+    "sentait",
+    "sentant",
+    "sentences",
+    "sentent",
+    "sentez",
+    "senti",
+    "sentie",
+    "sentier",
+    "sentiers",
+    "senties",
+    "sentiment",
+    "sentimentale",
+    "sentimentales",
+    "sentiments",
+    "sentinelle",
+    "sentinelles",
+    "sentir",
+    "sentira",
+    "sentirez",
+    "sentisses",
+    "sentit",
+    "sept",
+    "septembre",
+    "septentrional",
+    "septentrionale",
+    "septentrionales",
+    "septième",
+    "sequin",
+    "sequins",
+    "sera",
+    "serai",
+    "seraient",
+    "serais",
+    "serait",
+    "seras",
+    "serez",
+    "sergent",
+    "serieusement",
+    "seriez",
+    "serions",
+    "serment",
+    "serments",
+    "sermon",
+    "sermons",
+    "serons",
+    "seront",
+    "serpent",
+    "serpentent",
+    "serpents",
+    "serpolet",
+    "serra",
+    "serraient",
+    "serrait",
+    "serrant",
+    "serre",
+    "serrements",
+    "serrent",
+    "serrer",
+    "serres",
+    "serrure",
+    "serrures",
+    "serrurier",
+    "serrèrent",
+    "serré",
+    "serrée",
+    "serrées",
+    "serrés",
+    "sert",
+    "servaient",
+    "servais",
+    "servait",
+    "servante",
+    "servent",
+    "servi",
+    "serviable",
+    "servie",
+    "serviettes",
+    "servilement",
+    "servir",
+    "servira",
+    "servirai",
+    "serviraient",
+    "servirait",
+    "servirez",
+    "servis",
+    "servit",
+    "serviteur",
+    "serviteurs",
+    "ses",
+    "seuil",
+    "seul",
+    "seule",
+    "seulement",
+    "seules",
+    "seuls",
+    "sexagésimales",
+    "sexe",
+    "sexes",
+    "sfondrata",
+    "sforce",
+    "sforze",
+    "shako",
+    "shakos",
+    "shakspeare",
+    "shangaï",
+    "sheppard",
+    "sheridan",
+    "sherry",
+    "shilling",
+    "shillings",
+    "shipping",
+    "shiva",
+    "si",
+    "siamo",
+    "sien",
+    "sienne",
+    "siennes",
+    "siens",
+    "sierra",
+    "sieste",
+    "sieur",
+    "sieyès",
+    "siffla",
+    "sifflaient",
+    "sifflait",
+    "sifflant",
+    "sifflement",
+    "sifflements",
+    "siffler",
+    "sifflet",
+    "sifflets",
+    "sighs",
+    "sigisbée",
+    "sigismond",
+    "signa",
+    "signait",
+    "signala",
+    "signale",
+    "signalement",
+    "signalements",
+    "signaler",
+    "signalerait",
+    "signalé",
+    "signalée",
+    "signant",
+    "signaux",
+    "signe",
+    "signer",
+    "signera",
+    "signerai",
+    "signes",
+    "signets",
+    "signez",
+    "signifiait",
+    "significatif",
+    "signification",
+    "significative",
+    "significatives",
+    "signifie",
+    "signifier",
+    "signor",
+    "signora",
+    "signorino",
+    "signé",
+    "signée",
+    "signées",
+    "silencieuse",
+    "silencieusement",
+    "silencieux",
+    "silhouette",
+    "sillonnaient",
+    "sillonne",
+    "sillonné",
+    "sillonnés",
+    "sillons",
+    "simagrées",
+    "simonie",
+    "simplement",
+    "simples",
+    "simplicité",
+    "simplifiée",
+    "simule",
+    "sinant",
+    "sincère",
+    "sincèrement",
+    "sincères",
+    "sincérité",
+    "sindes",
+    "sine",
+    "singapore",
+    "singe",
+    "singeries",
+    "singes",
+    "singularité",
+    "singularités",
+    "singulier",
+    "singuliers",
+    "singulière",
+    "singulièrement",
+    "singulières",
+    "sinistre",
+    "sinistres",
+    "sinon",
+    "sinuosités",
+    "sinécures",
+    "sioux",
+    "sire",
+    "sites",
+    "situé",
+    "située",
+    "situés",
+    "sitôt",
+    "sixième",
+    "sixte",
+    "sixtine",
+    "siècle",
+    "siècles",
+    "siège",
+    "sièges",
+    "sleeping",
+    "sloop",
+    "smith",
+    "smollett",
+    "smyth",
+    "social",
+    "sociale",
+    "sociales",
+    "sociaux",
+    "société",
+    "sociétés",
+    "socques",
+    "soeur",
+    "soeurs",
+    "soi",
+    "soie",
+    "soient",
+    "soif",
+    "soigna",
+    "soignait",
+    "soignant",
+    "soigne",
+    "soigner",
+    "soigneusement",
+    "soigneux",
+    "soigné",
+    "soignée",
+    "soignés",
+    "soin",
+    "soins",
+    "soir",
+    "soirs",
+    "soirée",
+    "soirées",
+    "sois",
+    "soissons",
+    "soit",
+    "soixante",
+    "soixantième",
+    "sol",
+    "solaire",
+    "soldant",
+    "soldat",
+    "soldats",
+    "solde",
+    "soldé",
+    "soldés",
+    "soleil",
+    "solennel",
+    "solennelle",
+    "solennels",
+    "solide",
+    "solidement",
+    "solides",
+    "solidité",
+    "solitaire",
+    "solitaires",
+    "solitude",
+    "sollicita",
+    "sollicitait",
+    "sollicitations",
+    "sollicite",
+    "solliciter",
+    "solliciteur",
+    "sollicitez",
+    "sollicité",
+    "sols",
+    "somanlis",
+    "sombre",
+    "sombrer",
+    "sombreros",
+    "sombres",
+    "sommaire",
+    "sommariva",
+    "sommation",
+    "somme",
+    "sommeil",
+    "sommeillait",
+    "sommer",
+    "sommes",
+    "sommet",
+    "sommets",
+    "sommités",
+    "somptueuse",
+    "somptueusement",
+    "somptueux",
+    "somptuosité",
+    "son",
+    "sonder",
+    "songe",
+    "songea",
+    "songeaient",
+    "songeais",
+    "songeait",
+    "songeant",
+    "songent",
+    "songeons",
+    "songer",
+    "songera",
+    "songerai",
+    "songerait",
+    "songes",
+    "songez",
+    "songeât",
+    "songèrent",
+    "songé",
+    "sonna",
+    "sonnaient",
+    "sonnait",
+    "sonnant",
+    "sonne",
+    "sonner",
+    "sonnera",
+    "sonneries",
+    "sonneront",
+    "sonnet",
+    "sonnets",
+    "sonnette",
+    "sonnettes",
+    "sonneurs",
+    "sonnât",
+    "sonnèrent",
+    "sonné",
+    "sonnée",
+    "sonnées",
+    "sonnés",
+    "sonore",
+    "sonores",
+    "sons",
+    "sont",
+    "sorbonne",
+    "sorcier",
+    "sordide",
+    "sorel",
+    "sorezana",
+    "sorgho",
+    "sors",
+    "sortaient",
+    "sortais",
+    "sortait",
+    "sortant",
+    "sorte",
+    "sortent",
+    "sortes",
+    "sortez",
+    "sorti",
+    "sortie",
+    "sortiez",
+    "sortir",
+    "sortira",
+    "sortirai",
+    "sortirais",
+    "sortirait",
+    "sortiras",
+    "sortirent",
+    "sortirez",
+    "sortirions",
+    "sortirons",
+    "sortis",
+    "sortit",
+    "sortît",
+    "sot",
+    "sots",
+    "sotte",
+    "sottement",
+    "sottes",
+    "sottise",
+    "sottises",
+    "sou",
+    "soubrette",
+    "souci",
+    "soucia",
+    "souciait",
+    "soucier",
+    "soucieuse",
+    "soucieux",
+    "soucis",
+    "soudain",
+    "soudaine",
+    "soudainement",
+    "souffert",
+    "souffla",
+    "soufflait",
+    "soufflant",
+    "souffle",
+    "soufflent",
+    "souffler",
+    "soufflet",
+    "soufflets",
+    "souffleur",
+    "souffrais",
+    "souffrait",
+    "souffrance",
+    "souffrances",
+    "souffrant",
+    "souffrante",
+    "souffre",
+    "souffrent",
+    "souffres",
+    "souffrez",
+    "souffrir",
+    "souffrira",
+    "souffrirai",
+    "souffrirais",
+    "souffriras",
+    "souffrissent",
+    "souffrit",
+    "souhait",
+    "souhaitant",
+    "souhaite",
+    "souhaité",
+    "souiller",
+    "souillé",
+    "soulagement",
+    "soulagé",
+    "soulagée",
+    "soulcy",
+    "souleva",
+    "soulevait",
+    "soulevant",
+    "soulever",
+    "soulevée",
+    "soulevées",
+    "soulier",
+    "souliers",
+    "soulte",
+    "soulèvent",
+    "soulèverai",
+    "soumettait",
+    "soumettre",
+    "soumis",
+    "soumise",
+    "soumises",
+    "soumission",
+    "soumit",
+    "soupait",
+    "soupapes",
+    "soupe",
+    "souper",
+    "soupers",
+    "soupez",
+    "soupir",
+    "soupirant",
+    "soupire",
+    "soupirer",
+    "souple",
+    "souplesse",
+    "soupçon",
+    "soupçonna",
+    "soupçonnaient",
+    "soupçonnait",
+    "soupçonne",
+    "soupçonner",
+    "soupçonneux",
+    "soupçonné",
+    "soupçonnée",
+    "soupçonnés",
+    "soupçons",
+    "soupé",
+    "sourcil",
+    "sourciliers",
+    "sourcilla",
+    "sourciller",
+    "sourcillé",
+    "sourcils",
+    "sourd",
+    "sourde",
+    "souri",
+    "souriait",
+    "souriant",
+    "souriante",
+    "souriantes",
+    "sourire",
+    "sourires",
+    "sourit",
+    "sournois",
+    "sournoise",
+    "sous",
+    "soustrairais",
+    "soustraire",
+    "soustrait",
+    "soutane",
+    "soutenaient",
+    "soutenait",
+    "soutenant",
+    "soutenir",
+    "soutenu",
+    "soutenue",
+    "soutenues",
+    "souterrain",
+    "souterraines",
+    "soutes",
+    "southampton",
+    "southey",
+    "soutiennent",
+    "soutiens",
+    "soutient",
+    "soutint",
+    "soutènement",
+    "souvenaient",
+    "souvenait",
+    "souvenant",
+    "souvenez",
+    "souvenir",
+    "souvenirs",
+    "souvent",
+    "souvenus",
+    "souverain",
+    "souveraine",
+    "souverainement",
+    "souveraineté",
+    "souverains",
+    "souviendra",
+    "souviendrez",
+    "souvienne",
+    "souviennent",
+    "souviens",
+    "souvient",
+    "souvint",
+    "soyeux",
+    "soyez",
+    "soyons",
+    "spardeck",
+    "spectacle",
+    "spectacles",
+    "spectateur",
+    "spectateurs",
+    "spectrale",
+    "spectre",
+    "speedy",
+    "sphère",
+    "sphéroïde",
+    "spielberg",
+    "spirale",
+    "spirales",
+    "spirituel",
+    "spirituelle",
+    "spirituelles",
+    "splendide",
+    "splendides",
+    "spécial",
+    "spéciale",
+    "spécialement",
+    "spécialité",
+    "spécifier",
+    "spécifique",
+    "spéculation",
+    "spéculations",
+    "squares",
+    "squelette",
+    "st",
+    "stalles",
+    "stamp",
+    "stanislas",
+    "starke",
+    "stationnera",
+    "stationnés",
+    "stations",
+    "statuaire",
+    "statue",
+    "statues",
+    "stature",
+    "staub",
+    "staël",
+    "steam",
+    "steamboats",
+    "steamers",
+    "steccata",
+    "stefano",
+    "stentor",
+    "stephenson",
+    "sterling",
+    "sterne",
+    "steward",
+    "stigmates",
+    "stigmatiser",
+    "stimuler",
+    "stolen",
+    "strabon",
+    "stramonium",
+    "strand",
+    "strasbourg",
+    "stratagème",
+    "straw",
+    "stricte",
+    "strictement",
+    "strombeck",
+    "strongest",
+    "strychnine",
+    "studbook",
+    "stupefie",
+    "stupeur",
+    "stupide",
+    "stupides",
+    "stupéfaction",
+    "stupéfait",
+    "stupéfiante",
+    "stupéfie",
+    "stupéfier",
+    "stupéfié",
+    "stylé",
+    "stylés",
+    "su",
+    "suave",
+    "subalterne",
+    "subalternes",
+    "subi",
+    "subir",
+    "subira",
+    "subirait",
+    "subis",
+    "subissant",
+    "subit",
+    "subite",
+    "subitement",
+    "subjugue",
+    "subjuguer",
+    "subjugué",
+    "sublime",
+    "sublimes",
+    "sublimité",
+    "submerge",
+    "submergèrent",
+    "submergé",
+    "subordonnés",
+    "suborner",
+    "subsistance",
+    "subsiste",
+    "subsistent",
+    "substance",
+    "substituer",
+    "substitut",
+    "substitué",
+    "substituée",
+    "substituées",
+    "subtilité",
+    "subvention",
+    "successeur",
+    "successeurs",
+    "successifs",
+    "succession",
+    "successive",
+    "successivement",
+    "successives",
+    "successor",
+    "succombant",
+    "succombent",
+    "succomber",
+    "succombé",
+    "succulentes",
+    "succulents",
+    "succède",
+    "succès",
+    "succéda",
+    "succédaient",
+    "succédait",
+    "succéder",
+    "succédèrent",
+    "succédé",
+    "suchet",
+    "sucre",
+    "sucreries",
+    "sud",
+    "sueur",
+    "suez",
+    "suffers",
+    "suffi",
+    "suffira",
+    "suffiraient",
+    "suffirait",
+    "suffire",
+    "suffirent",
+    "suffiront",
+    "suffisaient",
+    "suffisait",
+    "suffisamment",
+    "suffisance",
+    "suffisant",
+    "suffisante",
+    "suffisantes",
+    "suffisent",
+    "suffit",
+    "suffocations",
+    "suffoqua",
+    "suffoquerait",
+    "suffoqué",
+    "suggéra",
+    "suggérer",
+    "suggéré",
+    "suggérée",
+    "suicide",
+    "suie",
+    "suis",
+    "suisse",
+    "suisses",
+    "suites",
+    "suivaient",
+    "suivais",
+    "suivait",
+    "suivant",
+    "suivante",
+    "suivantes",
+    "suivants",
+    "suivent",
+    "suivez",
+    "suivi",
+    "suivie",
+    "suivies",
+    "suiviez",
+    "suivirent",
+    "suivis",
+    "suivit",
+    "suivons",
+    "suivra",
+    "suivrai",
+    "suivraient",
+    "suivras",
+    "suivre",
+    "suivront",
+    "sujet",
+    "sujets",
+    "sujette",
+    "sujettes",
+    "sullivan",
+    "sultan",
+    "sumatra",
+    "superbe",
+    "superbes",
+    "superficie",
+    "superficiel",
+    "superflu",
+    "supplia",
+    "suppliaient",
+    "suppliait",
+    "suppliant",
+    "suppliante",
+    "supplications",
+    "supplice",
+    "supplices",
+    "supplie",
+    "supplier",
+    "suppliez",
+    "supplément",
+    "supplémentaire",
+    "suppléé",
+    "supportable",
+    "supportait",
+    "supportent",
+    "supporter",
+    "supporterait",
+    "supposait",
+    "supposant",
+    "supposer",
+    "supposez",
+    "supposition",
+    "suppositions",
+    "supposons",
+    "supposé",
+    "supposée",
+    "supposés",
+    "suppression",
+    "supprimant",
+    "supprime",
+    "supprimer",
+    "supprimons",
+    "supprimé",
+    "supprimée",
+    "supputait",
+    "suprême",
+    "supérieur",
+    "supérieure",
+    "supérieurement",
+    "supérieures",
+    "supérieurs",
+    "supériorité",
+    "sur",
+    "surannée",
+    "surate",
+    "surchargés",
+    "surchauffée",
+    "surcroît",
+    "surenchère",
+    "surent",
+    "suresnes",
+    "surexcité",
+    "surgir",
+    "surgirait",
+    "surgirent",
+    "surhumain",
+    "surhumaine",
+    "surlendemain",
+    "surmontant",
+    "surmonte",
+    "surmonter",
+    "surmontée",
+    "surmontées",
+    "surnage",
+    "surnagea",
+    "surnageait",
+    "surnaturelle",
+    "surnom",
+    "surnommèrent",
+    "surpassa",
+    "surpasse",
+    "surpassent",
+    "surpassé",
+    "surplis",
+    "surplus",
+    "surprenait",
+    "surprenant",
+    "surprend",
+    "surprendre",
+    "surprirent",
+    "surpris",
+    "surprises",
+    "surprit",
+    "surrey",
+    "sursaut",
+    "surtout",
+    "surveillait",
+    "surveillance",
+    "surveillants",
+    "surveille",
+    "surveiller",
+    "surveillera",
+    "surveillé",
+    "surveillée",
+    "survenait",
+    "survenir",
+    "survenue",
+    "survinrent",
+    "survint",
+    "survit",
+    "survivant",
+    "survivrai",
+    "survivrais",
+    "survivre",
+    "survivrez",
+    "survécut",
+    "surélevés",
+    "sus",
+    "susceptible",
+    "susceptibles",
+    "susciter",
+    "suscitèrent",
+    "susdit",
+    "suspect",
+    "suspecte",
+    "suspecter",
+    "suspects",
+    "suspendre",
+    "suspendu",
+    "suspendue",
+    "suspendus",
+    "suspens",
+    "suspicion",
+    "sut",
+    "sutpour",
+    "sutty",
+    "suédois",
+    "svelte",
+    "sweeter",
+    "sydenham",
+    "syllabe",
+    "syllabes",
+    "syllogisme",
+    "symbole",
+    "symboles",
+    "symbolisent",
+    "sympathie",
+    "sympathisait",
+    "sympathise",
+    "symphonie",
+    "symptôme",
+    "symptômes",
+    "symétrie",
+    "symétriques",
+    "synagogues",
+    "synonyme",
+    "synonymes",
+    "système",
+    "syénites",
+    "sèche",
+    "sèchement",
+    "sèches",
+    "sème",
+    "séance",
+    "sécher",
+    "sécheresse",
+    "séché",
+    "séchée",
+    "séculaires",
+    "sécurité",
+    "sédentaire",
+    "séditieuse",
+    "séditieux",
+    "sédition",
+    "séducteur",
+    "séduction",
+    "séductions",
+    "séduire",
+    "séduisait",
+    "séduisant",
+    "séduisante",
+    "séduisants",
+    "séduit",
+    "séduite",
+    "séduits",
+    "séjour",
+    "séjournait",
+    "séjourner",
+    "séjournât",
+    "sémillant",
+    "sémillante",
+    "séminaire",
+    "séminariste",
+    "séminaristes",
+    "sépara",
+    "séparaient",
+    "séparait",
+    "séparant",
+    "séparation",
+    "sépare",
+    "séparent",
+    "séparer",
+    "séparera",
+    "séparons",
+    "séparé",
+    "séparée",
+    "séparées",
+    "séparés",
+    "sérail",
+    "série",
+    "sérieuse",
+    "sérieusement",
+    "sérieuses",
+    "sérieux",
+    "sérénade",
+    "sérénades",
+    "sérénissime",
+    "sérénité",
+    "sévère",
+    "sévèrement",
+    "sévères",
+    "sévérité",
+    "sévérités",
+    "sûr",
+    "sûre",
+    "sûrement",
+    "sûreté",
+    "sûrs",
+    "sût",
+    "t",
+    "ta",
+    "tabac",
+    "tabagie",
+    "tabagies",
+    "tabatière",
+    "tabernacle",
+    "tableau",
+    "tableaux",
+    "tablette",
+    "tablier",
+    "tabouret",
+    "tache",
+    "taches",
+    "tachetée",
+    "taché",
+    "tachée",
+    "tachées",
+    "tacite",
+    "tacites",
+    "taciti",
+    "taciturnes",
+    "tact",
+    "taffetas",
+    "tailla",
+    "taillanderie",
+    "taillant",
+    "taille",
+    "tailler",
+    "tailleur",
+    "taillis",
+    "taillé",
+    "taillées",
+    "taillés",
+    "taire",
+    "tais",
+    "taisaient",
+    "taisait",
+    "taisant",
+    "taisez",
+    "tait",
+    "talent",
+    "talents",
+    "talleyrand",
+    "talma",
+    "taloches",
+    "talons",
+    "talus",
+    "tam",
+    "tamarins",
+    "tambour",
+    "tambourins",
+    "tambours",
+    "tams",
+    "tanari",
+    "tanbeau",
+    "tancrède",
+    "tandis",
+    "tangage",
+    "tangles",
+    "tankadère",
+    "tankardere",
+    "tankardère",
+    "tankas",
+    "tannah",
+    "tanquam",
+    "tant",
+    "tante",
+    "tantes",
+    "tantôt",
+    "tanzi",
+    "tapage",
+    "tapis",
+    "tapisserie",
+    "tapissier",
+    "tapissiers",
+    "tapissée",
+    "tapty",
+    "tapé",
+    "tard",
+    "tarda",
+    "tardaient",
+    "tardait",
+    "tarde",
+    "tarder",
+    "tarderait",
+    "tardive",
+    "tardèrent",
+    "tardé",
+    "taries",
+    "tarirent",
+    "tarissait",
+    "tarit",
+    "tarots",
+    "tarragone",
+    "tartines",
+    "tartufe",
+    "tarés",
+    "tas",
+    "tasse",
+    "tasses",
+    "taudis",
+    "taureaux",
+    "taverne",
+    "taxes",
+    "taxis",
+    "taïkoun",
+    "te",
+    "tecks",
+    "teindre",
+    "teint",
+    "teinte",
+    "teintes",
+    "tel",
+    "telegraph",
+    "telle",
+    "tellement",
+    "telles",
+    "tels",
+    "temoigne",
+    "tempe",
+    "tempes",
+    "tempest",
+    "temple",
+    "temples",
+    "temps",
+    "tempérait",
+    "tempérament",
+    "température",
+    "tempérée",
+    "tempête",
+    "tenable",
+    "tenaient",
+    "tenais",
+    "tenait",
+    "tenant",
+    "tenante",
+    "tendaient",
+    "tendait",
+    "tendance",
+    "tendent",
+    "tender",
+    "tendit",
+    "tendre",
+    "tendrement",
+    "tendres",
+    "tendresse",
+    "tendresses",
+    "tendu",
+    "tendue",
+    "tendues",
+    "tendus",
+    "tenerani",
+    "tenere",
+    "teneur",
+    "tenez",
+    "tenir",
+    "tenons",
+    "tenta",
+    "tentait",
+    "tentation",
+    "tentations",
+    "tentative",
+    "tentatives",
+    "tente",
+    "tenter",
+    "tenteras",
+    "tentez",
+    "tentures",
+    "tenté",
+    "tenu",
+    "tenue",
+    "tenues",
+    "tenus",
+    "terme",
+    "termes",
+    "termina",
+    "terminaient",
+    "terminait",
+    "termine",
+    "terminer",
+    "terminera",
+    "terminèrent",
+    "terminé",
+    "terminée",
+    "terminées",
+    "ternir",
+    "terrain",
+    "terrains",
+    "terrasse",
+    "terrasses",
+    "terrassiers",
+    "terrassé",
+    "terre",
+    "terres",
+    "terrestre",
+    "terrestres",
+    "terreur",
+    "terreurs",
+    "terriblement",
+    "terribles",
+    "territoire",
+    "tertio",
+    "tertre",
+    "terzo",
+    "tes",
+    "testa",
+    "testament",
+    "teulier",
+    "texte",
+    "textuellement",
+    "thaler",
+    "theft",
+    "thermomètre",
+    "thrilling",
+    "thugs",
+    "thème",
+    "thèmes",
+    "thé",
+    "thémistocle",
+    "théodolinde",
+    "théologie",
+    "théologien",
+    "théorie",
+    "théories",
+    "théoriquement",
+    "thérèse",
+    "thésée",
+    "théâtral",
+    "théâtre",
+    "théâtres",
+    "tibi",
+    "tibère",
+    "tien",
+    "tiendra",
+    "tiendrait",
+    "tiendras",
+    "tiendrez",
+    "tiendront",
+    "tienne",
+    "tiennent",
+    "tiens",
+    "tient",
+    "tierce",
+    "tiers",
+    "tige",
+    "tiges",
+    "tigre",
+    "tigres",
+    "tilbury",
+    "tilburys",
+    "tilleul",
+    "tilleuls",
+    "timbre",
+    "timbres",
+    "timbrée",
+    "timbrées",
+    "timide",
+    "timidement",
+    "timides",
+    "timidité",
+    "timorée",
+    "tingou",
+    "tinrent",
+    "tinssent",
+    "tint",
+    "tintamarre",
+    "tinte",
+    "tira",
+    "tirade",
+    "tirades",
+    "tirage",
+    "tiraient",
+    "tirailler",
+    "tiraillé",
+    "tirait",
+    "tirant",
+    "tire",
+    "tirent",
+    "tirer",
+    "tirera",
+    "tireraient",
+    "tirerais",
+    "tirerait",
+    "tirerez",
+    "tirez",
+    "tiroir",
+    "tirât",
+    "tirèrent",
+    "tiré",
+    "tirée",
+    "tirés",
+    "tissu",
+    "tissue",
+    "tite",
+    "titre",
+    "titres",
+    "titubant",
+    "titulaire",
+    "toi",
+    "toile",
+    "toiles",
+    "toilette",
+    "toilettes",
+    "toisait",
+    "toisant",
+    "toises",
+    "toison",
+    "toit",
+    "toits",
+    "toiture",
+    "tolly",
+    "tolède",
+    "tolèrent",
+    "tolérer",
+    "tomba",
+    "tombaient",
+    "tombait",
+    "tombant",
+    "tombante",
+    "tombassent",
+    "tombe",
+    "tombeau",
+    "tombeaux",
+    "tombent",
+    "tomber",
+    "tomberai",
+    "tomberaient",
+    "tomberais",
+    "tomberont",
+    "tombez",
+    "tombone",
+    "tombât",
+    "tombèrent",
+    "tombé",
+    "tombée",
+    "tombés",
+    "tome",
+    "tomes",
+    "ton",
+    "tonalités",
+    "tondre",
+    "tonnage",
+    "tonnaient",
+    "tonnante",
+    "tonne",
+    "tonneaux",
+    "tonnelier",
+    "tonnerre",
+    "tonnerres",
+    "tonnes",
+    "tonte",
+    "toque",
+    "torche",
+    "torches",
+    "torchon",
+    "tordait",
+    "tordant",
+    "tordit",
+    "tordre",
+    "tords",
+    "torpeur",
+    "torrent",
+    "torrents",
+    "torsade",
+    "torses",
+    "tort",
+    "torto",
+    "tortoni",
+    "torts",
+    "tortue",
+    "tortues",
+    "tortura",
+    "torturait",
+    "torture",
+    "torturer",
+    "tortures",
+    "torturé",
+    "toscane",
+    "totale",
+    "totalement",
+    "toto",
+    "toucha",
+    "touchaient",
+    "touchais",
+    "touchait",
+    "touchant",
+    "touchante",
+    "touchantes",
+    "touchants",
+    "touche",
+    "toucher",
+    "touchera",
+    "toucherez",
+    "toucheront",
+    "touches",
+    "touchât",
+    "touché",
+    "touchée",
+    "touchés",
+    "touffe",
+    "touffes",
+    "touffu",
+    "touffus",
+    "toujours",
+    "toulouse",
+    "toupie",
+    "toupies",
+    "tourbillon",
+    "tourbillonnait",
+    "tourbillons",
+    "touriste",
+    "tourment",
+    "tourmentait",
+    "tourmente",
+    "tourmentin",
+    "tourments",
+    "tourmenté",
+    "tourmentée",
+    "tourmentés",
+    "tourna",
+    "tournai",
+    "tournaient",
+    "tournait",
+    "tournant",
+    "tournante",
+    "tournantes",
+    "tourne",
+    "tournent",
+    "tourner",
+    "tournera",
+    "tourniquets",
+    "tournoyant",
+    "tournure",
+    "tournures",
+    "tournèrent",
+    "tourné",
+    "tournée",
+    "tournées",
+    "tours",
+    "tous",
+    "toussa",
+    "tousser",
+    "tout",
+    "toute",
+    "toutefois",
+    "toutes",
+    "tr",
+    "trac",
+    "tracasse",
+    "tracasserie",
+    "tracasseries",
+    "tracer",
+    "traction",
+    "tracé",
+    "tracée",
+    "tracées",
+    "tracés",
+    "tradition",
+    "traduction",
+    "traduire",
+    "traduis",
+    "traduit",
+    "traduite",
+    "traduites",
+    "tragique",
+    "tragiques",
+    "tragédie",
+    "trahi",
+    "trahie",
+    "trahies",
+    "trahir",
+    "trahira",
+    "trahirais",
+    "trahirait",
+    "trahiriez",
+    "trahiront",
+    "trahis",
+    "trahison",
+    "trahissaient",
+    "trahissait",
+    "trahissant",
+    "trahisse",
+    "trahissez",
+    "trahit",
+    "trahît",
+    "trains",
+    "traita",
+    "traitait",
+    "traitant",
+    "traite",
+    "traitement",
+    "traitent",
+    "traiter",
+    "traitera",
+    "traitez",
+    "traitât",
+    "traitèrent",
+    "traité",
+    "traitée",
+    "traités",
+    "trajectoires",
+    "trajet",
+    "trame",
+    "tramezzina",
+    "tramways",
+    "tranchait",
+    "tranchant",
+    "tranchants",
+    "tranche",
+    "tranches",
+    "tranchée",
+    "tranchées",
+    "tranchés",
+    "tranquille",
+    "tranquillement",
+    "tranquilles",
+    "tranquillise",
+    "tranquilliserait",
+    "tranquillisez",
+    "tranquillisée",
+    "tranquillité",
+    "transatlantique",
+    "transatlantiques",
+    "transcrire",
+    "transcrit",
+    "transcrivait",
+    "transcrivant",
+    "transcrivit",
+    "transes",
+    "transfigure",
+    "transforma",
+    "transformant",
+    "transforment",
+    "transformer",
+    "transformé",
+    "transformée",
+    "transformés",
+    "transfuge",
+    "transfèrement",
+    "transférer",
+    "transgression",
+    "transie",
+    "transiger",
+    "transire",
+    "transit",
+    "transmettre",
+    "transmise",
+    "transmissible",
+    "transmit",
+    "transocéanienne",
+    "transocéaniennes",
+    "transparents",
+    "transporta",
+    "transportait",
+    "transporter",
+    "transports",
+    "transportât",
+    "transporté",
+    "transportée",
+    "transversale",
+    "trapp",
+    "trappe",
+    "trappes",
+    "trappiste",
+    "trapues",
+    "traqué",
+    "trattamento",
+    "trattoria",
+    "travail",
+    "travailla",
+    "travaillaient",
+    "travaillait",
+    "travaille",
+    "travailler",
+    "travailleurs",
+    "travaillé",
+    "travaux",
+    "travers",
+    "traversa",
+    "traversaient",
+    "traversait",
+    "traversant",
+    "traverse",
+    "traversee",
+    "traversent",
+    "traverser",
+    "traverserai",
+    "traversez",
+    "traversèrent",
+    "traversé",
+    "traversée",
+    "traversées",
+    "traversés",
+    "traçait",
+    "traînaient",
+    "traînait",
+    "traînante",
+    "traînard",
+    "traîneau",
+    "traîneaux",
+    "traîner",
+    "traîné",
+    "traînée",
+    "traînées",
+    "traître",
+    "traîtreusement",
+    "tredici",
+    "treize",
+    "treizième",
+    "trembla",
+    "tremblaient",
+    "tremblais",
+    "tremblait",
+    "tremblant",
+    "tremblante",
+    "tremblantes",
+    "tremble",
+    "tremblement",
+    "trembler",
+    "tremblera",
+    "tremblerai",
+    "trembleront",
+    "tremblez",
+    "tremblé",
+    "trempe",
+    "tremper",
+    "tremplin",
+    "trempé",
+    "tremulously",
+    "trentaine",
+    "trente",
+    "tres",
+    "tressailli",
+    "tressaillir",
+    "tressaillit",
+    "tresse",
+    "tressé",
+    "triangle",
+    "triangulaire",
+    "tribu",
+    "tribun",
+    "tribunal",
+    "tribunaux",
+    "tribune",
+    "tributaires",
+    "tric",
+    "tricolore",
+    "trinquette",
+    "trio",
+    "triompha",
+    "triomphait",
+    "triomphale",
+    "triomphant",
+    "triomphante",
+    "triomphe",
+    "triomphent",
+    "triompher",
+    "triomphera",
+    "triompherait",
+    "triompheront",
+    "triomphes",
+    "triomphé",
+    "triplé",
+    "triste",
+    "tristement",
+    "tristes",
+    "tristesse",
+    "tristesses",
+    "triumvirat",
+    "triviale",
+    "trois",
+    "troisième",
+    "troisièmes",
+    "trombe",
+    "trompa",
+    "trompaient",
+    "trompait",
+    "trompant",
+    "trompe",
+    "tromper",
+    "tromperai",
+    "tromperais",
+    "trompes",
+    "trompez",
+    "trompé",
+    "trompée",
+    "trompés",
+    "tronc",
+    "troncs",
+    "tronçon",
+    "trop",
+    "trophée",
+    "tropicale",
+    "tropicales",
+    "tropique",
+    "tropiques",
+    "troppo",
+    "trot",
+    "trottait",
+    "trotter",
+    "trottoir",
+    "trottoirs",
+    "trou",
+    "troubla",
+    "troublaient",
+    "troublait",
+    "troublant",
+    "troubler",
+    "troublera",
+    "troubleront",
+    "troubles",
+    "troublez",
+    "troublèrent",
+    "troublé",
+    "troublée",
+    "troublées",
+    "troublés",
+    "troupe",
+    "troupeau",
+    "troupes",
+    "troupiers",
+    "trous",
+    "trousses",
+    "trouva",
+    "trouvai",
+    "trouvaient",
+    "trouvais",
+    "trouvait",
+    "trouvant",
+    "trouve",
+    "trouvent",
+    "trouver",
+    "trouvera",
+    "trouverai",
+    "trouveraient",
+    "trouverais",
+    "trouverait",
+    "trouveras",
+    "trouverez",
+    "trouveriez",
+    "trouverions",
+    "trouverons",
+    "trouveront",
+    "trouves",
+    "trouvez",
+    "trouvons",
+    "trouvât",
+    "trouvèrent",
+    "trouvé",
+    "trouvée",
+    "trouvées",
+    "trouvés",
+    "truites",
+    "trunk",
+    "très",
+    "trébuchant",
+    "trépas",
+    "trésor",
+    "trésors",
+    "trévise",
+    "trêve",
+    "trône",
+    "tu",
+    "tua",
+    "tuais",
+    "tuait",
+    "tuant",
+    "tudieu",
+    "tue",
+    "tuent",
+    "tuer",
+    "tuera",
+    "tuerai",
+    "tuerais",
+    "tuerait",
+    "tuez",
+    "tuileries",
+    "tuiles",
+    "tuilla",
+    "tumulte",
+    "tumultueuse",
+    "tunique",
+    "tunnel",
+    "tunnels",
+    "turban",
+    "turbans",
+    "turin",
+    "turpitude",
+    "tussaud",
+    "tut",
+    "tutoie",
+    "tutoiement",
+    "tutoyait",
+    "tutoyer",
+    "tutti",
+    "tutto",
+    "tuyau",
+    "tuyaux",
+    "tué",
+    "tuée",
+    "tués",
+    "twelfth",
+    "tympaniser",
+    "typhon",
+    "tyran",
+    "tyrannie",
+    "tyrannisait",
+    "tyrans",
+    "tâchait",
+    "tâchant",
+    "tâche",
+    "tâcher",
+    "tâcherai",
+    "tâchez",
+    "tâchons",
+    "tâtonnent",
+    "télescope",
+    "télescopes",
+    "télégraphe",
+    "télégraphique",
+    "télégraphiques",
+    "télégraphié",
+    "télémaque",
+    "témoignage",
+    "témoignaient",
+    "témoignait",
+    "témoigne",
+    "témoigner",
+    "témoigneront",
+    "témoigniez",
+    "témoigné",
+    "témoin",
+    "témoins",
+    "téméraire",
+    "témérité",
+    "ténacité",
+    "ténor",
+    "tête",
+    "têtes",
+    "tînt",
+    "tôt",
+    "uddaul",
+    "ultimatum",
+    "ultra",
+    "ultras",
+    "ulysse",
+    "un",
+    "unam",
+    "unanime",
+    "unanimement",
+    "uncertain",
+    "une",
+    "unes",
+    "uni",
+    "unie",
+    "unies",
+    "uniforme",
+    "uniformes",
+    "uniquement",
+    "unis",
+    "unissaient",
+    "unissait",
+    "unissez",
+    "univers",
+    "universel",
+    "universelle",
+    "unième",
+    "uns",
+    "uranus",
+    "urbanité",
+    "urne",
+    "usa",
+    "usages",
+    "usant",
+    "usine",
+    "ustensile",
+    "usure",
+    "usuriers",
+    "usurpateur",
+    "usurpations",
+    "usurpe",
+    "usurpé",
+    "usurpées",
+    "usurpés",
+    "usé",
+    "utah",
+    "utile",
+    "utiles",
+    "utilisaient",
+    "utiliser",
+    "utilité",
+    "uzeri",
+    "uzès",
+    "uçaf",
+    "v",
+    "va",
+    "vacances",
+    "vacant",
+    "vacante",
+    "vacarme",
+    "vache",
+    "vacillais",
+    "vagabond",
+    "vagabonde",
+    "vague",
+    "vaguement",
+    "vagues",
+    "vaille",
+    "vain",
+    "vaincre",
+    "vaincu",
+    "vaincue",
+    "vaincus",
+    "vaine",
+    "vainement",
+    "vaines",
+    "vainqueur",
+    "vains",
+    "vais",
+    "vaisseau",
+    "valable",
+    "valables",
+    "valaient",
+    "valait",
+    "valant",
+    "vale",
+    "valenod",
+    "valent",
+    "valet",
+    "valets",
+    "valeur",
+    "vallee",
+    "vallée",
+    "vallées",
+    "valoir",
+    "valois",
+    "valserra",
+    "valu",
+    "valurent",
+    "valut",
+    "valère",
+    "vandalisme",
+    "vane",
+    "vaneau",
+    "vaniteuse",
+    "vaniteux",
+    "vanité",
+    "vanta",
+    "vantait",
+    "vantent",
+    "vanter",
+    "vanté",
+    "vanvitelli",
+    "vapeur",
+    "vapeurs",
+    "variaient",
+    "varie",
+    "varié",
+    "variétés",
+    "varèse",
+    "vas",
+    "vase",
+    "vases",
+    "vasi",
+    "vasistas",
+    "vaste",
+    "vastes",
+    "vau",
+    "vaudeville",
+    "vaudra",
+    "vaudrait",
+    "vaudront",
+    "vaurien",
+    "vaut",
+    "vaux",
+    "vedette",
+    "vega",
+    "veilla",
+    "veillaient",
+    "veillait",
+    "veille",
+    "veillent",
+    "veiller",
+    "veillerons",
+    "veilles",
+    "veilleuse",
+    "veillât",
+    "veillé",
+    "veine",
+    "veines",
+    "velleja",
+    "velléité",
+    "velléités",
+    "velours",
+    "venaient",
+    "venait",
+    "venant",
+    "venceslas",
+    "vend",
+    "vendait",
+    "vendant",
+    "vendent",
+    "vendeur",
+    "vendeurs",
+    "vendit",
+    "vendrai",
+    "vendrais",
+    "vendrait",
+    "vendre",
+    "vendredi",
+    "vendredis",
+    "vendront",
+    "vends",
+    "vendu",
+    "vendue",
+    "vendus",
+    "vendée",
+    "venette",
+    "venez",
+    "venge",
+    "vengea",
+    "vengeait",
+    "vengeance",
+    "vengent",
+    "venger",
+    "vengera",
+    "vengerai",
+    "vengé",
+    "vengée",
+    "veniez",
+    "venin",
+    "venir",
+    "venise",
+    "venons",
+    "vent",
+    "vente",
+    "ventes",
+    "ventre",
+    "vents",
+    "venu",
+    "venue",
+    "venues",
+    "venus",
+    "verbal",
+    "verbe",
+    "verdict",
+    "verdoyantes",
+    "verdoyants",
+    "verdure",
+    "verdâtres",
+    "verger",
+    "vergogne",
+    "vergues",
+    "vergy",
+    "vermeilles",
+    "vermont",
+    "vermoulu",
+    "vermoulue",
+    "verna",
+    "vernaye",
+    "verne",
+    "vernes",
+    "verni",
+    "vernis",
+    "verona",
+    "verra",
+    "verrai",
+    "verraient",
+    "verrais",
+    "verrait",
+    "verras",
+    "verre",
+    "verres",
+    "verrez",
+    "verrières",
+    "verrons",
+    "verront",
+    "verrou",
+    "verrous",
+    "verruqueux",
+    "vers",
+    "versailles",
+    "versait",
+    "versant",
+    "versants",
+    "verse",
+    "verser",
+    "versets",
+    "vert",
+    "verte",
+    "vertement",
+    "vertes",
+    "verticale",
+    "verticalement",
+    "verts",
+    "vertu",
+    "vertueuse",
+    "vertueuses",
+    "vertueux",
+    "vertus",
+    "verve",
+    "vespasien",
+    "vesper",
+    "veste",
+    "vestes",
+    "vestibule",
+    "vestige",
+    "vetturini",
+    "vetturino",
+    "veuf",
+    "veuille",
+    "veuillez",
+    "veuilliez",
+    "veulent",
+    "veut",
+    "veuvage",
+    "veuve",
+    "veuves",
+    "veux",
+    "vexait",
+    "vexantes",
+    "vexations",
+    "vexer",
+    "vexé",
+    "vexés",
+    "vi",
+    "viaducs",
+    "viagère",
+    "viande",
+    "viandes",
+    "viatique",
+    "vibrations",
+    "vicaire",
+    "vicaires",
+    "vices",
+    "vicieux",
+    "vicissitudes",
+    "vico",
+    "vicolo",
+    "vicomte",
+    "vicomtes",
+    "victime",
+    "victimes",
+    "victoire",
+    "victoires",
+    "victor",
+    "victoria",
+    "victorieuse",
+    "vicvacarma",
+    "vida",
+    "vidaient",
+    "vidant",
+    "vide",
+    "vider",
+    "vides",
+    "videz",
+    "vidées",
+    "vie",
+    "vieil",
+    "vieillard",
+    "vieillards",
+    "vieille",
+    "vieilles",
+    "vieillesse",
+    "vieillie",
+    "vieillir",
+    "vieillit",
+    "viendra",
+    "viendrai",
+    "viendraient",
+    "viendrait",
+    "viendras",
+    "vienne",
+    "viennent",
+    "viennes",
+    "viens",
+    "vient",
+    "vierge",
+    "vies",
+    "vieux",
+    "vif",
+    "vifs",
+    "vigano",
+    "vigilante",
+    "vignano",
+    "vigne",
+    "vigoureuse",
+    "vigoureusement",
+    "vigoureuses",
+    "vigoureux",
+    "vigueur",
+    "viharis",
+    "vii",
+    "viii",
+    "vil",
+    "vilain",
+    "vilaine",
+    "vilaines",
+    "vilains",
+    "vile",
+    "vilenie",
+    "vilipendé",
+    "vilipendée",
+    "villa",
+    "villages",
+    "ville",
+    "villequier",
+    "villes",
+    "vils",
+    "vin",
+    "vinci",
+    "vindhias",
+    "vindicatif",
+    "vindicative",
+    "vingt",
+    "vingtaine",
+    "vingtième",
+    "vingts",
+    "vinrent",
+    "vins",
+    "vinsse",
+    "vint",
+    "viole",
+    "violemment",
+    "violent",
+    "violentant",
+    "violente",
+    "violentes",
+    "violents",
+    "violenté",
+    "violer",
+    "violes",
+    "violets",
+    "violette",
+    "violettes",
+    "violiers",
+    "violon",
+    "violé",
+    "vipère",
+    "virago",
+    "virent",
+    "virgile",
+    "virtue",
+    "virtuose",
+    "visa",
+    "visage",
+    "visages",
+    "visas",
+    "visconti",
+    "visent",
+    "viser",
+    "viserez",
+    "visiblement",
+    "visibles",
+    "vision",
+    "visions",
+    "visita",
+    "visitation",
+    "visite",
+    "visiter",
+    "visitera",
+    "visites",
+    "visité",
+    "visitées",
+    "viso",
+    "visons",
+    "visu",
+    "visé",
+    "vit",
+    "vite",
+    "vitesse",
+    "vitesses",
+    "vitraux",
+    "vitre",
+    "vitres",
+    "viva",
+    "vivacité",
+    "vivaient",
+    "vivais",
+    "vivait",
+    "vivandière",
+    "vivandières",
+    "vivant",
+    "vivante",
+    "vivants",
+    "vive",
+    "vivement",
+    "vivent",
+    "vives",
+    "vivez",
+    "vivons",
+    "vivoter",
+    "vivra",
+    "vivrai",
+    "vivrait",
+    "vivre",
+    "vivres",
+    "vivrez",
+    "vivrions",
+    "vivrons",
+    "vivront",
+    "vocation",
+    "vociférations",
+    "voeu",
+    "voeux",
+    "voguer",
+    "voi",
+    "voici",
+    "voie",
+    "voient",
+    "voies",
+    "voile",
+    "voiles",
+    "voilure",
+    "voilà",
+    "voilé",
+    "voilée",
+    "voilées",
+    "voilés",
+    "voir",
+    "vois",
+    "voisin",
+    "voisinage",
+    "voisine",
+    "voisines",
+    "voisins",
+    "voit",
+    "voiture",
+    "voitures",
+    "voix",
+    "vol",
+    "volaient",
+    "volaille",
+    "volait",
+    "volant",
+    "volants",
+    "volatiles",
+    "volcan",
+    "vole",
+    "volent",
+    "voler",
+    "volera",
+    "volerai",
+    "volerait",
+    "volet",
+    "volets",
+    "voleur",
+    "voleurs",
+    "volez",
+    "volière",
+    "volontaire",
+    "volontairement",
+    "volontaires",
+    "volontiers",
+    "volonté",
+    "volontés",
+    "vols",
+    "voltaire",
+    "voltige",
+    "voltigeant",
+    "voltiger",
+    "voltigeur",
+    "volubilité",
+    "volumes",
+    "voluptueuse",
+    "voluptueusement",
+    "voluptueuses",
+    "voluptueux",
+    "volupté",
+    "voluptés",
+    "volutes",
+    "volé",
+    "volée",
+    "volées",
+    "volés",
+    "vomero",
+    "vomir",
+    "vomissait",
+    "vont",
+    "voraces",
+    "vos",
+    "votaient",
+    "votant",
+    "vote",
+    "voter",
+    "votes",
+    "votre",
+    "voté",
+    "votée",
+    "voudra",
+    "voudraient",
+    "voudrais",
+    "voudrait",
+    "voudras",
+    "voudrez",
+    "voudriez",
+    "voudrions",
+    "voudront",
+    "vouent",
+    "vouer",
+    "voulaient",
+    "voulais",
+    "voulait",
+    "voulant",
+    "voulez",
+    "vouliez",
+    "vouloir",
+    "voulons",
+    "voulu",
+    "voulues",
+    "voulurent",
+    "voulus",
+    "voulussent",
+    "voulut",
+    "voulût",
+    "vous",
+    "vouèrent",
+    "voué",
+    "voyage",
+    "voyageait",
+    "voyageant",
+    "voyagent",
+    "voyageons",
+    "voyager",
+    "voyages",
+    "voyageur",
+    "voyageurs",
+    "voyageuse",
+    "voyagez",
+    "voyagé",
+    "voyaient",
+    "voyais",
+    "voyait",
+    "voyant",
+    "voyez",
+    "voyiez",
+    "voyons",
+    "voûte",
+    "voûtes",
+    "vrai",
+    "vraie",
+    "vraies",
+    "vraiment",
+    "vrais",
+    "vraisemblable",
+    "vraisemblablement",
+    "vu",
+    "vue",
+    "vues",
+    "vulgaire",
+    "vulgairement",
+    "vulgaires",
+    "vulgarité",
+    "vus",
+    "vécu",
+    "vécurent",
+    "vécut",
+    "vécût",
+    "végétaient",
+    "végéter",
+    "véhicule",
+    "véhicules",
+    "véhiculé",
+    "véhémence",
+    "véhément",
+    "vély",
+    "vénales",
+    "vénalité",
+    "vénitien",
+    "vénitiens",
+    "vénère",
+    "vénérable",
+    "vénérables",
+    "vénération",
+    "vénérer",
+    "vénérées",
+    "vérandas",
+    "véridiques",
+    "vérifia",
+    "vérification",
+    "vérifier",
+    "vérifié",
+    "véritable",
+    "véritablement",
+    "véritables",
+    "vérité",
+    "vérités",
+    "vérole",
+    "vérone",
+    "vésuve",
+    "vétille",
+    "vêtement",
+    "vêtements",
+    "vêtent",
+    "vêtu",
+    "vêtue",
+    "vêtues",
+    "vêtus",
+    "vîmes",
+    "vînt",
+    "vît",
+    "vôtre",
+    "vôtres",
+    "w",
+    "wagon",
+    "wagons",
+    "wagram",
+    "wahsatch",
+    "walbah",
+    "wandering",
+    "washington",
+    "waterloo",
+    "wayne",
+    "weber",
+    "wellington",
+    "wharf",
+    "wharfs",
+    "whisky",
+    "whisnou",
+    "whist",
+    "withdrew",
+    "wyoming",
+    "x",
+    "xavier",
+    "xi",
+    "xii",
+    "xiii",
+    "xiv",
+    "xix",
+    "xv",
+    "xvi",
+    "xvii",
+    "xviii",
+    "xx",
+    "xxi",
+    "xxii",
+    "xxiii",
+    "xxiv",
+    "xxix",
+    "xxv",
+    "xxvi",
+    "xxvii",
+    "xxviii",
+    "xxxi",
+    "xxxii",
+    "xxxiii",
+    "xxxiv",
+    "xxxv",
+    "xxxvi",
+    "xxxvii",
+    "xérès",
+    "y",
+    "yacht",
+    "yachts",
+    "yakounines",
+    "yankee",
+    "yeddo",
+    "yert",
+    "yeux",
+    "zambajon",
+    "zeb",
+    "zigzags",
+    "zingarelli",
+    "zonders",
+    "zoroastre",
+    "zurla",
+    "zèle",
+    "zébrés",
+    "zébus",
+    "zélés",
+    "zénith",
+    "à",
+    "âge",
+    "âges",
+    "âgé",
+    "âgée",
+    "âgées",
+    "âgés",
+    "âme",
+    "âmes",
+    "ânerie",
+    "ânonna",
+    "âpre",
+    "ça",
+    "çà",
+    "è",
+    "ébahi",
+    "ébahie",
+    "ébahis",
+    "ébahissement",
+    "ébattre",
+    "ébaucha",
+    "ébaudissait",
+    "ébloui",
+    "éblouissant",
+    "éblouissante",
+    "éblouissement",
+    "éblouit",
+    "ébouriffé",
+    "ébouriffée",
+    "ébranla",
+    "ébranlaient",
+    "ébranlant",
+    "ébranler",
+    "ébranlé",
+    "ébranlée",
+    "ébruité",
+    "ébréché",
+    "ébène",
+    "écailles",
+    "écarlate",
+    "écarquillés",
+    "écart",
+    "écartaient",
+    "écartait",
+    "écartant",
+    "écarte",
+    "écartelé",
+    "écarter",
+    "écarté",
+    "écartée",
+    "écartées",
+    "écervelés",
+    "échafaud",
+    "échange",
+    "échangeaient",
+    "échangeant",
+    "échanger",
+    "échangé",
+    "échangée",
+    "échangées",
+    "échangés",
+    "échantillons",
+    "échappa",
+    "échappaient",
+    "échappait",
+    "échappe",
+    "échappement",
+    "échapper",
+    "échapperait",
+    "échappons",
+    "échappé",
+    "échappée",
+    "échappées",
+    "échappés",
+    "écharpe",
+    "échauffant",
+    "échauffer",
+    "échec",
+    "échecs",
+    "échelle",
+    "échelles",
+    "échelon",
+    "échelons",
+    "échevelé",
+    "échiquiers",
+    "échoiront",
+    "échoppe",
+    "échouait",
+    "échoue",
+    "échouer",
+    "échoueraient",
+    "échoué",
+    "échus",
+    "échut",
+    "échéant",
+    "éclair",
+    "éclairaient",
+    "éclairait",
+    "éclairant",
+    "éclaircies",
+    "éclaircir",
+    "éclaircirent",
+    "éclaircissant",
+    "éclaircissement",
+    "éclaircissent",
+    "éclaircit",
+    "éclairer",
+    "éclairs",
+    "éclairèrent",
+    "éclairé",
+    "éclairée",
+    "éclairés",
+    "éclat",
+    "éclata",
+    "éclataient",
+    "éclatait",
+    "éclatant",
+    "éclatante",
+    "éclatantes",
+    "éclatants",
+    "éclate",
+    "éclatent",
+    "éclater",
+    "éclats",
+    "éclatèrent",
+    "éclaté",
+    "éclipsait",
+    "éclipse",
+    "éclipses",
+    "éclipsés",
+    "école",
+    "écolier",
+    "éconduire",
+    "éconduit",
+    "économe",
+    "économes",
+    "économie",
+    "économies",
+    "économise",
+    "économiser",
+    "économisées",
+    "économisés",
+    "écorcha",
+    "écorchaient",
+    "écorchait",
+    "écorcher",
+    "écorchures",
+    "écorché",
+    "écorchée",
+    "écorné",
+    "écossaise",
+    "écoula",
+    "écoulaient",
+    "écoulant",
+    "écoulement",
+    "écoulent",
+    "écouler",
+    "écoulèrent",
+    "écoulé",
+    "écoulée",
+    "écoulés",
+    "écouta",
+    "écoutaient",
+    "écoutais",
+    "écoutait",
+    "écoutant",
+    "écoute",
+    "écoutent",
+    "écouter",
+    "écouterai",
+    "écouteront",
+    "écoutes",
+    "écoutez",
+    "écoutiez",
+    "écouté",
+    "écoutée",
+    "écoutés",
+    "écrasaient",
+    "écraser",
+    "écrasé",
+    "écrasée",
+    "écrasées",
+    "écria",
+    "écriaient",
+    "écriait",
+    "écriant",
+    "écrie",
+    "écrient",
+    "écrier",
+    "écrin",
+    "écrira",
+    "écrirai",
+    "écrirais",
+    "écrirait",
+    "écrire",
+    "écrirez",
+    "écris",
+    "écrit",
+    "écrite",
+    "écriteau",
+    "écrites",
+    "écritoire",
+    "écrits",
+    "écriture",
+    "écritures",
+    "écrivailleur",
+    "écrivailleurs",
+    "écrivain",
+    "écrivains",
+    "écrivais",
+    "écrivait",
+    "écrivant",
+    "écrivante",
+    "écrive",
+    "écrivez",
+    "écrivirent",
+    "écrivit",
+    "écrivît",
+    "écrivîtes",
+    "écrièrent",
+    "écrié",
+    "écriée",
+    "écrou",
+    "écroula",
+    "écroulaient",
+    "écroué",
+    "écu",
+    "écueil",
+    "écuelle",
+    "écumait",
+    "écumeuses",
+    "écureuil",
+    "écurie",
+    "écuries",
+    "écus",
+    "écuyer",
+    "édification",
+    "édifice",
+    "édimbourg",
+    "éditer",
+    "éditeur",
+    "édition",
+    "éditions",
+    "éducation",
+    "éduquer",
+    "égaiera",
+    "égal",
+    "égalait",
+    "égale",
+    "également",
+    "égalent",
+    "égales",
+    "égalité",
+    "égalités",
+    "égara",
+    "égarait",
+    "égard",
+    "égards",
+    "égare",
+    "égarement",
+    "égarements",
+    "égarer",
+    "égares",
+    "égaré",
+    "égarée",
+    "égarés",
+    "égaux",
+    "égaya",
+    "égayer",
+    "égayée",
+    "égayés",
+    "église",
+    "églises",
+    "égorgent",
+    "égorger",
+    "égorgés",
+    "égout",
+    "égoïsme",
+    "égoïste",
+    "égoïstes",
+    "égratignure",
+    "égratignures",
+    "égypte",
+    "égyptiens",
+    "élan",
+    "élance",
+    "élancer",
+    "élancera",
+    "élancé",
+    "élans",
+    "élança",
+    "élançait",
+    "élançant",
+    "élargi",
+    "élastiques",
+    "électeur",
+    "électeurs",
+    "élection",
+    "élections",
+    "électives",
+    "électrique",
+    "électriques",
+    "électrisait",
+    "éleva",
+    "élevaient",
+    "élevait",
+    "élevant",
+    "élever",
+    "éleveurs",
+    "élevé",
+    "élevée",
+    "élevées",
+    "élevés",
+    "élisa",
+    "élite",
+    "élocution",
+    "éloge",
+    "éloges",
+    "éloigna",
+    "éloignaient",
+    "éloignais",
+    "éloignait",
+    "éloignant",
+    "éloigne",
+    "éloignement",
+    "éloigner",
+    "éloignerai",
+    "éloignerait",
+    "éloignez",
+    "éloigniez",
+    "éloignât",
+    "éloignèrent",
+    "éloigné",
+    "éloignée",
+    "éloignées",
+    "éloignés",
+    "éloquence",
+    "éloquent",
+    "éloquente",
+    "élu",
+    "éluder",
+    "élysées",
+    "élève",
+    "élèvent",
+    "élèvera",
+    "élèverai",
+    "élèverez",
+    "élèves",
+    "élégamment",
+    "élégance",
+    "élégant",
+    "élégante",
+    "élégantes",
+    "élégants",
+    "élémentaire",
+    "éléments",
+    "éléphant",
+    "éléphants",
+    "élévation",
+    "élévations",
+    "émaillée",
+    "émergeait",
+    "émerveillé",
+    "émerveillée",
+    "émettre",
+    "émeute",
+    "émeuvent",
+    "émigrants",
+    "émigration",
+    "émigrera",
+    "éminemment",
+    "émissaire",
+    "émoi",
+    "émotion",
+    "émotions",
+    "émoussés",
+    "émoustiller",
+    "émouvoir",
+    "ému",
+    "émue",
+    "émule",
+    "émurent",
+    "émus",
+    "émut",
+    "énergie",
+    "énergique",
+    "énergumène",
+    "énergumènes",
+    "énerver",
+    "énervé",
+    "énervée",
+    "énigmatique",
+    "énigme",
+    "énoncer",
+    "énoncé",
+    "énonçait",
+    "énorme",
+    "énormes",
+    "énormité",
+    "énumération",
+    "éolienne",
+    "épagneul",
+    "épais",
+    "épaisse",
+    "épaisseur",
+    "épanchement",
+    "épanouir",
+    "épanouissaient",
+    "épanouissait",
+    "épanouissement",
+    "épanouit",
+    "épargna",
+    "épargnait",
+    "épargne",
+    "épargner",
+    "épargnera",
+    "épargnerait",
+    "épargnez",
+    "épargniez",
+    "épargné",
+    "épargnée",
+    "éparses",
+    "éparvérés",
+    "épaule",
+    "épaules",
+    "épaulettes",
+    "épaulés",
+    "éperdu",
+    "éperdue",
+    "éperdument",
+    "éperon",
+    "éperons",
+    "épervier",
+    "éperviers",
+    "éphémère",
+    "épia",
+    "épiait",
+    "épiciers",
+    "épicée",
+    "épiderme",
+    "épie",
+    "épient",
+    "épier",
+    "épigrammatique",
+    "épigramme",
+    "épigrammes",
+    "épine",
+    "épines",
+    "épineuses",
+    "épingle",
+    "épingles",
+    "épique",
+    "épis",
+    "épiscopal",
+    "épiscopat",
+    "épisode",
+    "épitaphe",
+    "épithète",
+    "époque",
+    "époques",
+    "épousa",
+    "épousait",
+    "épousant",
+    "épouse",
+    "épouser",
+    "épousera",
+    "épouserai",
+    "épouserez",
+    "épouseur",
+    "épousez",
+    "épousseter",
+    "épousé",
+    "épousée",
+    "épousées",
+    "épouvantable",
+    "épouvantablement",
+    "épouvantables",
+    "épouvantaient",
+    "épouvante",
+    "épouvantements",
+    "épouvantée",
+    "époux",
+    "épreuve",
+    "épreuves",
+    "épris",
+    "éprise",
+    "éprouva",
+    "éprouvai",
+    "éprouvait",
+    "éprouvant",
+    "éprouve",
+    "éprouvent",
+    "éprouver",
+    "éprouverais",
+    "éprouverait",
+    "éprouvé",
+    "éprouvée",
+    "éprouvées",
+    "éprouvés",
+    "épuisait",
+    "épuisement",
+    "épuisèrent",
+    "épuisé",
+    "épuisée",
+    "épée",
+    "épées",
+    "épître",
+    "équarrir",
+    "équarrissaient",
+    "équilibre",
+    "équilibristes",
+    "équilibré",
+    "équinoxe",
+    "équinoxes",
+    "équipage",
+    "équipé",
+    "équipée",
+    "équité",
+    "équivalait",
+    "équivoques",
+    "éreinté",
+    "ériger",
+    "établi",
+    "établie",
+    "établies",
+    "établir",
+    "établira",
+    "établirent",
+    "établirez",
+    "établis",
+    "établissaient",
+    "établissait",
+    "établissant",
+    "établissement",
+    "établissements",
+    "établit",
+    "étage",
+    "étagea",
+    "étageaient",
+    "étages",
+    "étai",
+    "étaie",
+    "étaient",
+    "étain",
+    "étais",
+    "était",
+    "étalage",
+    "étalages",
+    "étalait",
+    "étalant",
+    "étale",
+    "étaler",
+    "étalé",
+    "étalée",
+    "étalés",
+    "étancher",
+    "étang",
+    "étangs",
+    "étant",
+    "étante",
+    "étantes",
+    "étants",
+    "étarquée",
+    "état",
+    "états",
+    "étaux",
+    "étayé",
+    "éteignaient",
+    "éteignait",
+    "éteignirent",
+    "éteignit",
+    "éteindra",
+    "éteindre",
+    "éteindrons",
+    "éteint",
+    "éteinte",
+    "éteints",
+    "étend",
+    "étendaient",
+    "étendait",
+    "étendant",
+    "étendent",
+    "étendirent",
+    "étendit",
+    "étendre",
+    "étendu",
+    "étendue",
+    "étendues",
+    "étendus",
+    "éternel",
+    "éternelle",
+    "éternelles",
+    "éternels",
+    "éternité",
+    "étienne",
+    "étiez",
+    "étincelante",
+    "étincelants",
+    "étincelle",
+    "étincelles",
+    "étiole",
+    "étiolés",
+    "étions",
+    "étiquette",
+    "étoffe",
+    "étoffes",
+    "étoile",
+    "étoiles",
+    "étoilé",
+    "étonna",
+    "étonnaient",
+    "étonnait",
+    "étonnant",
+    "étonnante",
+    "étonnantes",
+    "étonnants",
+    "étonne",
+    "étonnement",
+    "étonnent",
+    "étonner",
+    "étonnera",
+    "étonnerait",
+    "étonnèrent",
+    "étonné",
+    "étonnée",
+    "étonnées",
+    "étonnés",
+    "étouffaient",
+    "étouffait",
+    "étouffe",
+    "étouffer",
+    "étouffé",
+    "étouffée",
+    "étourderie",
+    "étourderies",
+    "étourdi",
+    "étourdie",
+    "étourdiment",
+    "étourdir",
+    "étourdissait",
+    "étourdissement",
+    "étourdit",
+    "étrange",
+    "étrangement",
+    "étranger",
+    "étrangers",
+    "étranges",
+    "étrangeté",
+    "étranglaient",
+    "étrangler",
+    "étrangère",
+    "étrangères",
+    "étrave",
+    "étreignit",
+    "étreinte",
+    "étrier",
+    "étriers",
+    "étrille",
+    "étroit",
+    "étroite",
+    "étroitement",
+    "étroites",
+    "étroits",
+    "étude",
+    "études",
+    "étudia",
+    "étudiait",
+    "étudiant",
+    "étudie",
+    "étudier",
+    "étudierai",
+    "étudiez",
+    "étudié",
+    "étudiée",
+    "étui",
+    "étuis",
+    "été",
+    "étée",
+    "étées",
+    "étés",
+    "évacuer",
+    "évader",
+    "évaluait",
+    "évangile",
+    "évangélique",
+    "évanoui",
+    "évanouie",
+    "évanouir",
+    "évanouirent",
+    "évanouis",
+    "évanouissait",
+    "évanouissement",
+    "évanouit",
+    "évaporatoire",
+    "évasion",
+    "éveil",
+    "éveilla",
+    "éveillant",
+    "éveiller",
+    "éveillera",
+    "éveillé",
+    "éveillée",
+    "éveillées",
+    "éveillés",
+    "éventail",
+    "éventualité",
+    "éventualités",
+    "éventée",
+    "évidaient",
+    "évidemment",
+    "évidence",
+    "évident",
+    "évidente",
+    "évidentes",
+    "évidents",
+    "évita",
+    "évitait",
+    "évitant",
+    "éviter",
+    "évitons",
+    "évitèrent",
+    "évité",
+    "événement",
+    "événements",
+    "évêché",
+    "évêque",
+    "évêques",
+    "êtes",
+    "être",
+    "êtres",
+    "île",
+    "îles",
+    "îlot",
+    "îlots",
+    "ô",
+    "ôta",
+    "ôtaient",
+    "ôtait",
+    "ôte",
+    "ôter",
+    "ôtera",
+    "ôterai",
+    "ôterait",
+    "ôterez",
+    "ôtez",
+    "ôtèrent",
+    "ôté",
+    "ôtées",
+};
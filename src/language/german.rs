@@ -0,0 +1,69 @@
+use std::ops::RangeInclusive;
+
+use phf::phf_set;
+
+use super::{contains_lowercase, Language, LanguageId};
+
+mod words;
+
+use words::WORDS;
+
+/// German, behind the `german` cargo feature
+#[derive(Debug, Clone, Copy, Default)]
+pub struct German;
+
+impl Language for German {
+    fn id(&self) -> LanguageId {
+        LanguageId::German
+    }
+
+    fn is_letter(&self, c: char) -> bool {
+        c.is_ascii_alphabetic() || matches!(c, 'ä' | 'ö' | 'ü' | 'ß' | 'Ä' | 'Ö' | 'Ü' | 'ẞ')
+    }
+
+    fn is_vowel(&self, c: char) -> bool {
+        matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'ä' | 'ö' | 'ü')
+    }
+
+    fn is_common_pair(&self, pair: &str) -> bool {
+        COMMON_CHAR_PAIRS.contains(pair)
+    }
+
+    fn is_common_trigram(&self, trigram: &str) -> bool {
+        COMMON_TRIGRAMS.contains(trigram)
+    }
+
+    fn is_common_quadgram(&self, quadgram: &str) -> bool {
+        COMMON_QUADGRAMS.contains(quadgram)
+    }
+
+    fn contains_word(&self, word: &str) -> bool {
+        contains_lowercase(&WORDS, word)
+    }
+
+    fn entropy_range(&self) -> RangeInclusive<f64> {
+        3.5..=4.6
+    }
+}
+
+static COMMON_CHAR_PAIRS: phf::Set<&'static str> = phf_set! {
+    "en", "er", "ch", "de", "ei", "ie", "in", "te", "ge", "nd",
+    "un", "st", "es", "ne", "be", "ic", "re", "an", "he", "ns",
+    "se", "di", "au", "ht", "it", "ri", "da", "ng", "sc", "el",
+    "le", "is", "ss", "ac", "ar", "ra", "at", "ll", "li", "me",
+    "ve", "ta", "mi", "ab", "rt", "ma", "hr", "wa", "ür", "üb",
+};
+
+static COMMON_TRIGRAMS: phf::Set<&'static str> = phf_set! {
+    "ein", "ich", "der", "die", "und", "sch", "den", "che", "end", "cht",
+    "ine", "gen", "ung", "nde", "ter", "ers", "ten", "ber", "ent", "eit",
+    "lic", "ach", "ste", "auf", "ren", "nen", "ist", "hen", "sie", "ver",
+    "mit", "rde", "ige", "das", "ebe", "uch", "wer", "nic", "sse", "ies",
+    "ben", "hei", "and", "ell", "ang", "ges", "ete", "ise", "ert", "ieß",
+};
+
+static COMMON_QUADGRAMS: phf::Set<&'static str> = phf_set! {
+    "chen", "lich", "icht", "sche", "eine", "heit", "keit", "nder",
+    "werd", "ende", "iche", "ngen", "rung", "isch", "acht", "sein",
+    "nich", "über", "auch", "ande", "dies", "eben", "unde", "iese",
+};
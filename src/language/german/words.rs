@@ -0,0 +1,34724 @@
+use phf::phf_set;
+
+pub static WORDS: phf::Set<&'static str> = phf_set! {
+    "aa",
+    "aalglatten",
+    "aargau",
+    "aargauer",
+    "aasen",
+    "aasgeruch",
+    "aashöllen",
+    "ab",
+    "abaddon",
+    "abarten",
+    "abbeisst",
+    "abbild",
+    "abbildung",
+    "abbiss",
+    "abbrechen",
+    "abbruch",
+    "abend",
+    "abendbrot",
+    "abenddunklen",
+    "abenddämmerung",
+    "abende",
+    "abendessen",
+    "abendherren",
+    "abendhimmel",
+    "abendlichen",
+    "abendlichte",
+    "abendluft",
+    "abendländischem",
+    "abendländischen",
+    "abendmahl",
+    "abendröte",
+    "abends",
+    "abendschein",
+    "abendscheine",
+    "abendschöppchen",
+    "abendsonne",
+    "abendstern",
+    "abendtanz",
+    "abendwind",
+    "abendzeit",
+    "abenteuer",
+    "abenteuerlich",
+    "abenteuerliche",
+    "abenteuerlichem",
+    "abenteuerliches",
+    "abenteuern",
+    "abenteuers",
+    "abenteuerspielplätzen",
+    "abenteurer",
+    "aber",
+    "aberglaube",
+    "aberglauben",
+    "abermalige",
+    "abermaligen",
+    "abermals",
+    "abertausend",
+    "aberwitzigen",
+    "abfahrt",
+    "abfall",
+    "abfallen",
+    "abfallkorb",
+    "abfeuernd",
+    "abfielen",
+    "abfinden",
+    "abflughalle",
+    "abfressen",
+    "abfuhr",
+    "abfällige",
+    "abfällsel",
+    "abfällt",
+    "abführung",
+    "abgab",
+    "abgabe",
+    "abgaben",
+    "abgang",
+    "abge",
+    "abgebe",
+    "abgeben",
+    "abgebildet",
+    "abgebracht",
+    "abgebrannten",
+    "abgebrochen",
+    "abgebrochene",
+    "abgebrochenes",
+    "abgebürstet",
+    "abgedient",
+    "abgedruckt",
+    "abgefallen",
+    "abgefangen",
+    "abgefertigt",
+    "abgefunden",
+    "abgeführt",
+    "abgegebene",
+    "abgegrenzt",
+    "abgegriffene",
+    "abgehalten",
+    "abgehandelt",
+    "abgehandelten",
+    "abgehauen",
+    "abgehen",
+    "abgeholt",
+    "abgeht",
+    "abgehängen",
+    "abgehärmte",
+    "abgehärteten",
+    "abgehört",
+    "abgekauft",
+    "abgekommen",
+    "abgeladen",
+    "abgelagertes",
+    "abgelassen",
+    "abgelaufen",
+    "abgelebte",
+    "abgelegenen",
+    "abgelegt",
+    "abgelehnt",
+    "abgeleitet",
+    "abgeleitete",
+    "abgeleiteten",
+    "abgeleitetes",
+    "abgelenkt",
+    "abgeleugnet",
+    "abgelöst",
+    "abgemagert",
+    "abgemessenheit",
+    "abgenagten",
+    "abgeneigt",
+    "abgenommen",
+    "abgenutzten",
+    "abgeordnetenversammlung",
+    "abgeordneter",
+    "abgepflückt",
+    "abgepfändet",
+    "abgeplatzt",
+    "abgeraten",
+    "abgerieben",
+    "abgerissen",
+    "abgerissenen",
+    "abgesandt",
+    "abgeschabtes",
+    "abgescheuert",
+    "abgeschieden",
+    "abgeschiedenen",
+    "abgeschlagen",
+    "abgeschliffen",
+    "abgeschmolzen",
+    "abgeschnitten",
+    "abgeschossenes",
+    "abgeschreckt",
+    "abgeschwächt",
+    "abgesehen",
+    "abgesessen",
+    "abgesondert",
+    "abgesonderten",
+    "abgespannt",
+    "abgesperrt",
+    "abgesprochen",
+    "abgesprungen",
+    "abgestellt",
+    "abgestohlene",
+    "abgestossene",
+    "abgestritten",
+    "abgetan",
+    "abgeteilt",
+    "abgeteilten",
+    "abgetreten",
+    "abgetötet",
+    "abgewandt",
+    "abgewandte",
+    "abgewandtem",
+    "abgewartet",
+    "abgewaschen",
+    "abgewendet",
+    "abgewetzte",
+    "abgewetzten",
+    "abgewichen",
+    "abgewickelt",
+    "abgewinnen",
+    "abgewirtschaftet",
+    "abgewogen",
+    "abgewöhnen",
+    "abgewürdigt",
+    "abgezehrt",
+    "abgezirkelten",
+    "abgezogen",
+    "abgezogene",
+    "abgezogenen",
+    "abgezogenheit",
+    "abgezäunt",
+    "abgeödet",
+    "abgeödete",
+    "abgibt",
+    "abglitten",
+    "abgrund",
+    "abgrunde",
+    "abgrundes",
+    "abgrunds",
+    "abgründe",
+    "abgründen",
+    "abgötterei",
+    "abgöttereien",
+    "abgöttisches",
+    "abhalten",
+    "abhandlung",
+    "abhang",
+    "abhange",
+    "abhauen",
+    "abhaut",
+    "abhebenden",
+    "abhelfen",
+    "abhielten",
+    "abhob",
+    "abhoben",
+    "abholen",
+    "abholt",
+    "abhänge",
+    "abhängen",
+    "abhängend",
+    "abhängig",
+    "abhängigen",
+    "abhängiger",
+    "abhängigkeit",
+    "abhängt",
+    "abhören",
+    "abi",
+    "abiasaph",
+    "abigrissen",
+    "abigstiegen",
+    "abihu",
+    "abimelech",
+    "abirrung",
+    "abirrungen",
+    "abjagen",
+    "abkartelt",
+    "abkaufen",
+    "abkehr",
+    "abklärungen",
+    "abkommens",
+    "abkunft",
+    "abkühlendes",
+    "abkühlung",
+    "ablasse",
+    "ablassen",
+    "ablauf",
+    "ablaufen",
+    "ablaufende",
+    "ablegen",
+    "ablehnen",
+    "ablehnung",
+    "ableiten",
+    "ableiter",
+    "ableitet",
+    "ableitungen",
+    "ablenken",
+    "ablenkend",
+    "ablesen",
+    "ablesend",
+    "ablief",
+    "abliefe",
+    "ablutschen",
+    "ablässt",
+    "abläutete",
+    "abläßt",
+    "ablösung",
+    "abmarschiert",
+    "abmühten",
+    "abnahm",
+    "abnahme",
+    "abnehmen",
+    "abnehmende",
+    "abnehmenden",
+    "abneigung",
+    "abnimmt",
+    "abnutzen",
+    "abolent",
+    "abonnieren",
+    "abquälen",
+    "abraham",
+    "abrahams",
+    "abram",
+    "abrams",
+    "abraten",
+    "abrechnen",
+    "abrede",
+    "abreiben",
+    "abreise",
+    "abreissen",
+    "abreißen",
+    "abrisse",
+    "abrufen",
+    "abrundung",
+    "absage",
+    "absalon",
+    "absatz",
+    "abschaffen",
+    "abschaffung",
+    "abschaum",
+    "abscheu",
+    "abscheuern",
+    "abscheues",
+    "abscheulich",
+    "abscheuliche",
+    "abscheulichen",
+    "abscheulicher",
+    "abscheulicheres",
+    "abscheuliches",
+    "abscheulichste",
+    "abscheus",
+    "abschied",
+    "abschiedes",
+    "abschiessen",
+    "abschlagen",
+    "abschliessen",
+    "abschliessender",
+    "abschliesst",
+    "abschliessung",
+    "abschließt",
+    "abschließung",
+    "abschloss",
+    "abschlug",
+    "abschluss",
+    "abschluß",
+    "abschlägt",
+    "abschneiden",
+    "abschneidung",
+    "abschnitt",
+    "abschnitte",
+    "abschnitten",
+    "abschnitts",
+    "abschrecken",
+    "abschrieb",
+    "abschrift",
+    "abschweifungen",
+    "abschwenken",
+    "abschwächt",
+    "abschüsse",
+    "abschüssigen",
+    "abschütteln",
+    "abschüttelten",
+    "absehbar",
+    "absehen",
+    "absehlichen",
+    "abseits",
+    "absenden",
+    "absender",
+    "absetzen",
+    "absetzte",
+    "absicht",
+    "absichten",
+    "absichtlich",
+    "absieht",
+    "absingung",
+    "absolut",
+    "absolutes",
+    "absolviert",
+    "absolvierte",
+    "absonderliche",
+    "absonderlichen",
+    "absonderlicher",
+    "absondert",
+    "absonderungen",
+    "abspenstig",
+    "abspielten",
+    "absprang",
+    "abspringend",
+    "abspülen",
+    "abstahl",
+    "abstamme",
+    "abstammen",
+    "abstammenden",
+    "abstammte",
+    "abstammten",
+    "abstammung",
+    "abstammungen",
+    "abstatten",
+    "abstechend",
+    "abstecher",
+    "abstehen",
+    "absteht",
+    "absteigen",
+    "abstellte",
+    "abstimmung",
+    "abstimmungdie",
+    "abstirbt",
+    "abstossendes",
+    "abstoßendes",
+    "abstracto",
+    "abstrahieren",
+    "abstrahiert",
+    "abstrakt",
+    "abstrakten",
+    "abstrakter",
+    "abstraktion",
+    "abstreiten",
+    "abstufungen",
+    "abstumpfung",
+    "absturz",
+    "abstürze",
+    "abstürzen",
+    "absuchen",
+    "absuchte",
+    "absurden",
+    "abteilung",
+    "abteilungen",
+    "abtraten",
+    "abtreiben",
+    "abtreten",
+    "abtun",
+    "aburteilen",
+    "abwandte",
+    "abwarten",
+    "abwaschbaren",
+    "abwaschen",
+    "abwaschung",
+    "abwechselnd",
+    "abwechselndem",
+    "abwechselnder",
+    "abwechselung",
+    "abwechslung",
+    "abwege",
+    "abwehr",
+    "abwehrend",
+    "abwehrendes",
+    "abwehrmassnahme",
+    "abwehrung",
+    "abweichen",
+    "abweicht",
+    "abweichung",
+    "abweiden",
+    "abweisen",
+    "abweisend",
+    "abweist",
+    "abweisung",
+    "abwende",
+    "abwenden",
+    "abwendend",
+    "abwendet",
+    "abwendig",
+    "abwendung",
+    "abwerfen",
+    "abwesend",
+    "abwesenden",
+    "abwesenheit",
+    "abwich",
+    "abwickeln",
+    "abwinkte",
+    "abwischen",
+    "abwischte",
+    "abwusch",
+    "abwärts",
+    "abwärtsrichtung",
+    "abwürdigten",
+    "abzehrung",
+    "abziehen",
+    "abziehenden",
+    "abzielen",
+    "abzielt",
+    "abzubeuteln",
+    "abzubinden",
+    "abzubringen",
+    "abzufinden",
+    "abzuflauen",
+    "abzug",
+    "abzuge",
+    "abzugeben",
+    "abzugewinnen",
+    "abzugsgräben",
+    "abzugsschnur",
+    "abzuhalten",
+    "abzuholen",
+    "abzuhören",
+    "abzukühlen",
+    "abzukürzen",
+    "abzuladen",
+    "abzulassen",
+    "abzulaufen",
+    "abzulauschen",
+    "abzulegen",
+    "abzulehnenden",
+    "abzuleugnen",
+    "abzumachen",
+    "abzupflücken",
+    "abzureisen",
+    "abzureissen",
+    "abzuschicken",
+    "abzuschlagen",
+    "abzuschliessen",
+    "abzuschrecken",
+    "abzuschütteln",
+    "abzusehen",
+    "abzusprengen",
+    "abzustehen",
+    "abzustreiten",
+    "abzustumpfen",
+    "abzutreten",
+    "abzuwarten",
+    "abzuwehren",
+    "abzuwenden",
+    "abzuwischen",
+    "abzuziehen",
+    "abzweckt",
+    "abzweckung",
+    "aböden",
+    "abödet",
+    "abödung",
+    "abödungen",
+    "accidens",
+    "ach",
+    "achias",
+    "achijas",
+    "achis",
+    "achitophel",
+    "achseln",
+    "achselzucken",
+    "achselzuckend",
+    "acht",
+    "achtbarsten",
+    "achte",
+    "achten",
+    "achtes",
+    "achtet",
+    "achtete",
+    "achteten",
+    "achtgab",
+    "achtgeben",
+    "achtgegeben",
+    "achthundert",
+    "achtjähriges",
+    "achtliche",
+    "achtlos",
+    "achtlose",
+    "achtsam",
+    "achtsamkeit",
+    "achtundvierzig",
+    "achtundzwanzig",
+    "achtung",
+    "achtungsvoller",
+    "achtungsvolles",
+    "achtungswerten",
+    "achtzehn",
+    "achtzehnjährigem",
+    "achtzehnjährigen",
+    "achtzig",
+    "achtziger",
+    "achtzugeben",
+    "acker",
+    "ackerfeld",
+    "ackerland",
+    "ackerleute",
+    "ackers",
+    "ackersmann",
+    "ackerstein",
+    "ackerstreifen",
+    "ackerwirtschaft",
+    "ackerzipfel",
+    "ackerzipfelchen",
+    "ad",
+    "adah",
+    "adam",
+    "adamah",
+    "adams",
+    "adamshof",
+    "ade",
+    "adel",
+    "adelaide",
+    "adele",
+    "adelsgespons",
+    "adelsherrn",
+    "adelshochmut",
+    "adelsliebchen",
+    "adepten",
+    "ader",
+    "aderlassen",
+    "aderlassmännchen",
+    "adern",
+    "adieu",
+    "adjektiv",
+    "adlerflügeln",
+    "adlerweibchen",
+    "adn",
+    "adoration",
+    "adresse",
+    "advokat",
+    "advokaten",
+    "advokatenarbeit",
+    "advokatenkreisen",
+    "advokatenzimmer",
+    "adäquat",
+    "adäquaten",
+    "aeternitatis",
+    "affe",
+    "affectio",
+    "affectiones",
+    "affekt",
+    "affen",
+    "affenschwänze",
+    "affinitates",
+    "affizierenden",
+    "affiziert",
+    "affizierten",
+    "affäre",
+    "afrika",
+    "afrikanischen",
+    "aftermiete",
+    "againdabei",
+    "agathon",
+    "agck",
+    "agenten",
+    "ager",
+    "agnetendorfer",
+    "agwnistikon",
+    "ah",
+    "aharon",
+    "aharons",
+    "ahmten",
+    "ahndung",
+    "ahne",
+    "ahnen",
+    "ahnherr",
+    "ahnl",
+    "ahnliches",
+    "ahnt",
+    "ahnte",
+    "ahnung",
+    "ahnungen",
+    "ahnungslos",
+    "ahornbäume",
+    "ahornstämmchen",
+    "ai",
+    "aimer",
+    "airfares",
+    "airline",
+    "aiso",
+    "akazien",
+    "akkommodements",
+    "akkompagnierend",
+    "akkorde",
+    "akkorden",
+    "akkordes",
+    "akkusativ",
+    "akkusative",
+    "akrat",
+    "akt",
+    "akten",
+    "aktendiebstählen",
+    "aktien",
+    "aktienbierbrauerei",
+    "aktienbrauerei",
+    "aktienhopfenpflanzung",
+    "aktienschwindels",
+    "aktion",
+    "aktionen",
+    "aktivdienstgeneration",
+    "aktiven",
+    "aktivitäten",
+    "aktuell",
+    "akustik",
+    "akustikfreien",
+    "akustische",
+    "akustisehen",
+    "akzeptiert",
+    "akzidentell",
+    "alar",
+    "alarm",
+    "alarmknopf",
+    "alato",
+    "albern",
+    "albernen",
+    "albernes",
+    "albernheit",
+    "album",
+    "alcopops",
+    "aleviten",
+    "alex",
+    "alfred",
+    "alga",
+    "algen",
+    "algier",
+    "alhjeia",
+    "aliud",
+    "alkali",
+    "alkohol",
+    "alkoholgesetz",
+    "alkoholhaltige",
+    "alkoholiker",
+    "alkoholischer",
+    "alkoholkonsum",
+    "alkoholverwaltung",
+    "allabendlich",
+    "allabentlich",
+    "allbereits",
+    "allda",
+    "alldem",
+    "alle",
+    "alledem",
+    "allee",
+    "alleebäumen",
+    "allegorie",
+    "allein",
+    "alleinherrschaft",
+    "alleinige",
+    "alleinigen",
+    "allem",
+    "allemal",
+    "allen",
+    "allendlich",
+    "allenfalls",
+    "allenthalben",
+    "aller",
+    "allerallgemeinste",
+    "allerallgemeinsten",
+    "allerbescheidenstes",
+    "allerdings",
+    "allerdurchtriebenste",
+    "allereinzelnsten",
+    "allerengsten",
+    "allerentferntesten",
+    "allererst",
+    "allerersten",
+    "allergelindeste",
+    "allergrösste",
+    "allerhand",
+    "allerheiligste",
+    "allerherzlichste",
+    "allerkleinsten",
+    "allerkleinstes",
+    "allerkostbarsten",
+    "allerkürzeste",
+    "allerlei",
+    "allerletzt",
+    "allerliebste",
+    "allerliebstes",
+    "allerniederträchtigstes",
+    "allernächster",
+    "allerorten",
+    "allerschlimmste",
+    "allerschlimmsten",
+    "allerschönste",
+    "allerschönsten",
+    "allerseits",
+    "allerseltsamsten",
+    "allerunheimlichste",
+    "allerunterste",
+    "allerverkehrteste",
+    "allervernünftigsten",
+    "allerwegen",
+    "allerweissesten",
+    "allerwenigsten",
+    "alleräusserlichste",
+    "alles",
+    "allezeit",
+    "allfort",
+    "allgegenwart",
+    "allgegenwärtig",
+    "allgemein",
+    "allgemeine",
+    "allgemeinem",
+    "allgemeinen",
+    "allgemeiner",
+    "allgemeinere",
+    "allgemeineren",
+    "allgemeines",
+    "allgemeingültigkeit",
+    "allgemeinheit",
+    "allgemeinste",
+    "allgemeinsten",
+    "allgemeinstes",
+    "allgewaltige",
+    "allgewaltigen",
+    "allgütigkeit",
+    "allherbstlich",
+    "allhier",
+    "alljährlich",
+    "allmacht",
+    "allmal",
+    "allmächtig",
+    "allmächtige",
+    "allmächtigen",
+    "allmählich",
+    "allmähliche",
+    "allmählig",
+    "allnirgends",
+    "allnächtlich",
+    "allobrogische",
+    "allobrogischen",
+    "alls",
+    "alltag",
+    "alltäglich",
+    "alltägliche",
+    "allumfassende",
+    "allumfassenden",
+    "allwaltende",
+    "allweg",
+    "allweil",
+    "allwissend",
+    "allwissenheit",
+    "allwo",
+    "allwärts",
+    "allwöchentlich",
+    "allzeit",
+    "allzu",
+    "allzulange",
+    "allzumal",
+    "allzunahen",
+    "allzusehr",
+    "alm",
+    "almen",
+    "almrausch",
+    "alpennatur",
+    "als",
+    "alsbald",
+    "alsdann",
+    "also",
+    "alsobald",
+    "alsogleich",
+    "altar",
+    "altare",
+    "altars",
+    "altbekannten",
+    "alte",
+    "altem",
+    "alten",
+    "alternativen",
+    "alternativpolitik",
+    "alternde",
+    "alternden",
+    "alters",
+    "altersleiden",
+    "altersschwäche",
+    "altert",
+    "altertum",
+    "altertums",
+    "altertümlichen",
+    "altes",
+    "alteweibersommers",
+    "altjungferngesicht",
+    "altmodisch",
+    "altranning",
+    "alts",
+    "altstimme",
+    "altväterische",
+    "altväterischer",
+    "altäre",
+    "altären",
+    "alumnus",
+    "am",
+    "amal",
+    "amalek",
+    "amalie",
+    "amazonenhafter",
+    "ambiente",
+    "ambros",
+    "ameise",
+    "ameisen",
+    "amen",
+    "amer",
+    "amerika",
+    "amerikaner",
+    "amerikanern",
+    "amerikanische",
+    "amerikanischen",
+    "amhag",
+    "amiga",
+    "amme",
+    "amminadabs",
+    "ammoniter",
+    "ammons",
+    "amnestie",
+    "amnesty",
+    "amol",
+    "amor",
+    "amores",
+    "amoribus",
+    "amoriter",
+    "amoriters",
+    "amos",
+    "amour",
+    "amrain",
+    "amram",
+    "amrans",
+    "amsterdam",
+    "amt",
+    "amte",
+    "amtes",
+    "amtleute",
+    "amtleuten",
+    "amtlich",
+    "amtsdauer",
+    "amtsdauern",
+    "amtsgeheimnisse",
+    "amtsrat",
+    "amtsrates",
+    "amtsrats",
+    "amtssprache",
+    "amtsstube",
+    "amörchen",
+    "amüsant",
+    "amüsieren",
+    "amüsiert",
+    "an",
+    "anaks",
+    "analogie",
+    "analogisch",
+    "analogon",
+    "analysierbarer",
+    "analysieren",
+    "analyticis",
+    "analytik",
+    "analytisch",
+    "analytischen",
+    "anap",
+    "anarchie",
+    "anarchischen",
+    "anaskeuazein",
+    "anaskeuazomen",
+    "anaxagoras",
+    "anbaueten",
+    "anbeginn",
+    "anbehalten",
+    "anbelangt",
+    "anbellte",
+    "anbeten",
+    "anbeter",
+    "anbetern",
+    "anbetet",
+    "anbetete",
+    "anbeteten",
+    "anbetrifft",
+    "anbetung",
+    "anbey",
+    "anbieten",
+    "anbietend",
+    "anbietet",
+    "anbildet",
+    "anbinden",
+    "anbindung",
+    "anbissen",
+    "anblick",
+    "anblicke",
+    "anblicken",
+    "anblicks",
+    "anblickte",
+    "anbot",
+    "anbringen",
+    "anbruch",
+    "anböten",
+    "andacht",
+    "andauernde",
+    "andenken",
+    "andenkens",
+    "ander",
+    "anderau",
+    "anderckens",
+    "andere",
+    "anderem",
+    "anderen",
+    "anderer",
+    "andererseits",
+    "anderes",
+    "anderl",
+    "anderls",
+    "anderm",
+    "andern",
+    "anderr",
+    "anders",
+    "anderseits",
+    "anderswo",
+    "anderswoher",
+    "anderswohin",
+    "anderthalb",
+    "anderweis",
+    "anderweitig",
+    "anderweitige",
+    "anderweitigen",
+    "anderwärts",
+    "andeutet",
+    "andeutungsweise",
+    "andre",
+    "andreas",
+    "andrer",
+    "andrerseits",
+    "andres",
+    "andringling",
+    "androhung",
+    "androhungen",
+    "andächtig",
+    "andächtige",
+    "andächtigen",
+    "andächtiger",
+    "aneignen",
+    "aneignet",
+    "aneignete",
+    "aneigneten",
+    "aneignung",
+    "aneinander",
+    "aneinandergebracht",
+    "aneinandergelehnt",
+    "aneinandergeschmiegt",
+    "anerbieten",
+    "anererbtem",
+    "anererbten",
+    "anererbtes",
+    "anerkannt",
+    "anerkannte",
+    "anerkannten",
+    "anerkanntesten",
+    "anerkenne",
+    "anerkennen",
+    "anerkennt",
+    "anerkennung",
+    "anfahren",
+    "anfall",
+    "anfang",
+    "anfange",
+    "anfangen",
+    "anfangend",
+    "anfanges",
+    "anfangs",
+    "anfassen",
+    "anfechten",
+    "anfechtende",
+    "anfechtenden",
+    "anfechter",
+    "anfechtern",
+    "anfechtung",
+    "anfechtungen",
+    "anfeinden",
+    "anfertigen",
+    "anficht",
+    "anfing",
+    "anfinge",
+    "anfingen",
+    "anflehen",
+    "anflehend",
+    "anfleht",
+    "anflehte",
+    "anflug",
+    "anfocht",
+    "anfochten",
+    "anforderungen",
+    "anforderungsprofil",
+    "anfrage",
+    "anfragen",
+    "anfälle",
+    "anfällen",
+    "anfänge",
+    "anfängen",
+    "anfänglich",
+    "anfängt",
+    "anführen",
+    "anfüllen",
+    "anfüllte",
+    "ang",
+    "angab",
+    "angabe",
+    "angaben",
+    "angafften",
+    "angeb",
+    "angebaut",
+    "angeben",
+    "angebetet",
+    "angebissene",
+    "angeblich",
+    "angeblichen",
+    "angeblicher",
+    "angeboren",
+    "angeborenen",
+    "angeborener",
+    "angeborne",
+    "angebornen",
+    "angebot",
+    "angebote",
+    "angeboten",
+    "angebotenem",
+    "angebotenen",
+    "angebotenes",
+    "angebotes",
+    "angebracht",
+    "angebrannt",
+    "angebrochen",
+    "angebunden",
+    "angebundenen",
+    "angedeutet",
+    "angedreht",
+    "angedroht",
+    "angeeignet",
+    "angefangen",
+    "angefangenen",
+    "angefasst",
+    "angefaßt",
+    "angefeindet",
+    "angefeuert",
+    "angefleht",
+    "angefochten",
+    "angefragt",
+    "angeführt",
+    "angeführten",
+    "angefüllt",
+    "angefüllten",
+    "angegeben",
+    "angegebenen",
+    "angegriffen",
+    "angehaftet",
+    "angehalten",
+    "angehe",
+    "angehende",
+    "angehenden",
+    "angehoben",
+    "angeht",
+    "angehängt",
+    "angehängte",
+    "angehängten",
+    "angehör",
+    "angehöre",
+    "angehören",
+    "angehörend",
+    "angehöret",
+    "angehörig",
+    "angehörige",
+    "angehörigen",
+    "angehöriges",
+    "angehört",
+    "angehörte",
+    "angehörten",
+    "angekauften",
+    "angeklagt",
+    "angeklagte",
+    "angeklagten",
+    "angeklagter",
+    "angeklammert",
+    "angeklatschtem",
+    "angeklebt",
+    "angeklebte",
+    "angekleidet",
+    "angeknurrt",
+    "angeknüpft",
+    "angekommen",
+    "angekündigt",
+    "angel",
+    "angelangt",
+    "angelaufen",
+    "angelegenen",
+    "angelegenheit",
+    "angelegenheiten",
+    "angelegentlich",
+    "angelegentlichen",
+    "angelegentlicher",
+    "angelegentlichste",
+    "angelegt",
+    "angelegten",
+    "angelegtes",
+    "angeleitet",
+    "angelika",
+    "angeln",
+    "angelrute",
+    "angelte",
+    "angemachten",
+    "angemasst",
+    "angemassten",
+    "angemaßt",
+    "angemessen",
+    "angemessene",
+    "angemessenen",
+    "angemessener",
+    "angemessenheit",
+    "angenagelt",
+    "angenehm",
+    "angenehme",
+    "angenehmen",
+    "angenehmer",
+    "angenehmeres",
+    "angenehmes",
+    "angenehmste",
+    "angenehmsten",
+    "angenommen",
+    "angenommene",
+    "angenommenen",
+    "angenommenes",
+    "angeordnet",
+    "angepasst",
+    "angepaßt",
+    "angepflanzt",
+    "angeplanscht",
+    "angepumpt",
+    "anger",
+    "angeredet",
+    "angeregt",
+    "angeregter",
+    "angereist",
+    "angerichtet",
+    "angeriemten",
+    "angerufen",
+    "angerührt",
+    "angesagteste",
+    "angesammelte",
+    "angeschafft",
+    "angeschaut",
+    "angeschickt",
+    "angeschlossen",
+    "angeschlurft",
+    "angeschrieben",
+    "angeschrien",
+    "angeschwollen",
+    "angesehen",
+    "angesehensten",
+    "angesetzt",
+    "angesicht",
+    "angesichte",
+    "angesichten",
+    "angesichter",
+    "angesichtern",
+    "angesichtes",
+    "angesichts",
+    "angesiedelt",
+    "angesprochen",
+    "angesteckt",
+    "angestellt",
+    "angestellte",
+    "angestellten",
+    "angestellter",
+    "angestiert",
+    "angestrengt",
+    "angestrengten",
+    "angestrengter",
+    "angestrengteste",
+    "angestrichen",
+    "angestrichene",
+    "angetan",
+    "angetane",
+    "angetastet",
+    "angetrieben",
+    "angetroffen",
+    "angewachsen",
+    "angewandt",
+    "angewandte",
+    "angewendet",
+    "angewiesen",
+    "angewöhnt",
+    "angezeigt",
+    "angezogen",
+    "angezogene",
+    "angezweckt",
+    "angezündet",
+    "angfangt",
+    "anghört",
+    "angibt",
+    "anging",
+    "angreifen",
+    "angreifenden",
+    "angreifern",
+    "angrenzenden",
+    "angriff",
+    "angriffe",
+    "angschaut",
+    "angst",
+    "angstfrühstück",
+    "angstvoll",
+    "anguckte",
+    "anguckten",
+    "angues",
+    "angustia",
+    "angähnte",
+    "anhabe",
+    "anhaben",
+    "anhaftende",
+    "anhaltend",
+    "anhaltende",
+    "anhaltenden",
+    "anhaltender",
+    "anhaltst",
+    "anhang",
+    "anhangen",
+    "anhat",
+    "anhebt",
+    "anheilte",
+    "anheim",
+    "anheimfallen",
+    "anheimfiel",
+    "anheimfällt",
+    "anheimgegeben",
+    "anher",
+    "anhieb",
+    "anhielt",
+    "anhing",
+    "anhinge",
+    "anhingen",
+    "anhängen",
+    "anhänger",
+    "anhängerinnen",
+    "anhänglich",
+    "anhänglichkeit",
+    "anhängsel",
+    "anhängt",
+    "anhäuft",
+    "anhäuften",
+    "anhöhe",
+    "anhören",
+    "anhört",
+    "anhörte",
+    "anhörten",
+    "anima",
+    "animadvertentia",
+    "animalia",
+    "animalium",
+    "animi",
+    "anjene",
+    "anjetzt",
+    "ankam",
+    "ankamen",
+    "ankara",
+    "anker",
+    "anklage",
+    "anklagen",
+    "anklagend",
+    "anklagepunkte",
+    "anklageschrift",
+    "anklagt",
+    "anklagte",
+    "anklagten",
+    "ankleben",
+    "anklebenden",
+    "anklebte",
+    "anklopfen",
+    "anknabbern",
+    "ankomme",
+    "ankommen",
+    "ankommender",
+    "ankommt",
+    "ankunft",
+    "ankunfthalle",
+    "ankäme",
+    "ankäufe",
+    "ankömmlingen",
+    "ankündigen",
+    "ankündigte",
+    "ankündigung",
+    "anlachen",
+    "anlachte",
+    "anlage",
+    "anlagen",
+    "anlangt",
+    "anlangte",
+    "anlass",
+    "anlaufstelle",
+    "anlaß",
+    "anlegen",
+    "anlegte",
+    "anlegten",
+    "anlegung",
+    "anlehnung",
+    "anleitung",
+    "anleitungen",
+    "anliegen",
+    "anliegendes",
+    "anlockte",
+    "anlockungen",
+    "anlächelte",
+    "anlässe",
+    "anläuft",
+    "anlügt",
+    "anm",
+    "anmassen",
+    "anmasst",
+    "anmassung",
+    "anmassungen",
+    "anmaßen",
+    "anmelden",
+    "anmerkung",
+    "anmerkungen",
+    "anmut",
+    "anmutig",
+    "anmutige",
+    "anmutigen",
+    "anmutiger",
+    "anmutiges",
+    "anmutigste",
+    "anmutungen",
+    "anna",
+    "annahm",
+    "annahme",
+    "annahmen",
+    "anne",
+    "annehmbaren",
+    "annehme",
+    "annehmen",
+    "annehmlich",
+    "annehmlichkeit",
+    "annehmung",
+    "annelieschen",
+    "anneliese",
+    "annelieses",
+    "annimmst",
+    "annimmt",
+    "annneliese",
+    "anno",
+    "annäherung",
+    "annäherungsweise",
+    "annähme",
+    "anordnung",
+    "anpacken",
+    "anpackt",
+    "anprallen",
+    "anpreisen",
+    "anpumpen",
+    "anratungswürdiger",
+    "anreden",
+    "anredete",
+    "anregen",
+    "anregend",
+    "anregt",
+    "anregung",
+    "anregungen",
+    "anreihte",
+    "anreizen",
+    "anrichten",
+    "anrichtete",
+    "anrief",
+    "anruf",
+    "anrufbeantworter",
+    "anrufen",
+    "anrufung",
+    "anrätig",
+    "anrühren",
+    "anrührte",
+    "ans",
+    "ansagen",
+    "ansah",
+    "ansahen",
+    "ansammelten",
+    "ansammlungen",
+    "ansatz",
+    "anschafft",
+    "anschauen",
+    "anschauend",
+    "anschaulich",
+    "anschaun",
+    "anschaut",
+    "anschauung",
+    "anschauungen",
+    "anschauungsfähigkeit",
+    "anschauungsweise",
+    "anschein",
+    "anscheine",
+    "anscheinend",
+    "anscheinender",
+    "anschlag",
+    "anschlagen",
+    "anschliessen",
+    "anschliessend",
+    "anschliesst",
+    "anschließen",
+    "anschließt",
+    "anschloss",
+    "anschloß",
+    "anschluss",
+    "anschluß",
+    "anschlägt",
+    "anschneiden",
+    "ansehe",
+    "ansehen",
+    "ansehn",
+    "ansehnliche",
+    "ansehnlicher",
+    "ansehnliches",
+    "ansehung",
+    "ansicht",
+    "ansichten",
+    "ansieht",
+    "ansitz",
+    "ansitzen",
+    "ansonst",
+    "ansonsten",
+    "anspannen",
+    "anspielte",
+    "anspielung",
+    "anspielungen",
+    "anspornung",
+    "ansprache",
+    "ansprechen",
+    "ansprechend",
+    "ansprechende",
+    "anspricht",
+    "anspringt",
+    "anspruch",
+    "anspruchlosen",
+    "anspruchlosesten",
+    "anspruchsvollen",
+    "ansprüche",
+    "anstach",
+    "anstalt",
+    "anstalten",
+    "anstand",
+    "anstarren",
+    "anstatt",
+    "ansteckend",
+    "anstehe",
+    "ansteht",
+    "ansteigende",
+    "ansteigenden",
+    "anstelle",
+    "anstellen",
+    "anstellig",
+    "anstellte",
+    "anstiess",
+    "anstoss",
+    "anstossen",
+    "anstosses",
+    "anstoß",
+    "anstoßen",
+    "anstoßes",
+    "anstrengend",
+    "anstrengende",
+    "anstrengenden",
+    "anstrengung",
+    "anstrengungen",
+    "anstrich",
+    "anständig",
+    "anständige",
+    "anständigen",
+    "anständiger",
+    "anständigere",
+    "anständiges",
+    "anstösse",
+    "anstösst",
+    "anstöße",
+    "anstößt",
+    "anstürmen",
+    "ansähe",
+    "antat",
+    "anteil",
+    "anteilhaber",
+    "anteils",
+    "antelephoniert",
+    "anthropomorphismen",
+    "anthropomorphismus",
+    "anthropomorphistisch",
+    "anthropomorphistische",
+    "anti",
+    "antikeimenon",
+    "antin",
+    "antinomie",
+    "antipathie",
+    "antlitz",
+    "antorten",
+    "antragsteller",
+    "antreffen",
+    "antreiben",
+    "antreibend",
+    "antreibt",
+    "antretet",
+    "antrieb",
+    "antriebe",
+    "antrieben",
+    "antriebs",
+    "antrifft",
+    "antritt",
+    "anträfe",
+    "anträge",
+    "antun",
+    "antut",
+    "antwort",
+    "antworte",
+    "antworten",
+    "antwortet",
+    "antwortete",
+    "antworteten",
+    "anvertraue",
+    "anvertrauet",
+    "anvertraute",
+    "anvertrauten",
+    "anverwandten",
+    "anvisierte",
+    "anwachs",
+    "anwachsen",
+    "anwandeln",
+    "anwandlung",
+    "anwandten",
+    "anweisung",
+    "anweisungen",
+    "anwendbar",
+    "anwendbare",
+    "anwenden",
+    "anwendet",
+    "anwendeten",
+    "anwendung",
+    "anwerben",
+    "anwesen",
+    "anwesend",
+    "anwesenden",
+    "anwesenheit",
+    "anwälten",
+    "anwünscht",
+    "anzahl",
+    "anzeichen",
+    "anzeige",
+    "anzeigen",
+    "anzeigete",
+    "anzengruber",
+    "anziehen",
+    "anziehend",
+    "anziehende",
+    "anziehungskraft",
+    "anzog",
+    "anzogen",
+    "anzublicken",
+    "anzudrehen",
+    "anzuerkennen",
+    "anzufahren",
+    "anzufangen",
+    "anzufechten",
+    "anzufertigen",
+    "anzuführen",
+    "anzug",
+    "anzuge",
+    "anzugeben",
+    "anzugreifen",
+    "anzuhalten",
+    "anzuheben",
+    "anzuhängen",
+    "anzukaufen",
+    "anzuklingen",
+    "anzuklopfen",
+    "anzukämpfen",
+    "anzukünden",
+    "anzulachen",
+    "anzulegen",
+    "anzumassen",
+    "anzunehmen",
+    "anzupeilen",
+    "anzurufen",
+    "anzuschauen",
+    "anzuschauenden",
+    "anzuschliessen",
+    "anzuschließen",
+    "anzusehen",
+    "anzusetzen",
+    "anzusiedeln",
+    "anzuspannen",
+    "anzusprechen",
+    "anzustellen",
+    "anzutreffen",
+    "anzutreiben",
+    "anzuweisen",
+    "anzuwenden",
+    "anzuzeigen",
+    "anzuzetteln",
+    "anzuziehen",
+    "anzüge",
+    "anzünden",
+    "anzündet",
+    "anzündete",
+    "apfel",
+    "apfelbaum",
+    "apfelbaumes",
+    "apfelblüten",
+    "apfelblütenhauchen",
+    "apfelbäumchens",
+    "apfelsaft",
+    "aphras",
+    "apodiktisch",
+    "apodiktischen",
+    "apodiktischer",
+    "apodiktisches",
+    "apokrisewv",
+    "apollyon",
+    "apologie",
+    "apostel",
+    "apostolische",
+    "apparat",
+    "apparentia",
+    "apparet",
+    "appartement",
+    "appell",
+    "appetimus",
+    "appetit",
+    "applaus",
+    "applicat",
+    "approbation",
+    "aprikosen",
+    "april",
+    "aquarien",
+    "ara",
+    "arabien",
+    "arabiens",
+    "arabische",
+    "arbeit",
+    "arbeite",
+    "arbeiten",
+    "arbeitenden",
+    "arbeiter",
+    "arbeiterkittel",
+    "arbeitern",
+    "arbeitet",
+    "arbeitete",
+    "arbeiteten",
+    "arbeitsam",
+    "arbeitsamen",
+    "arbeitsaufnahme",
+    "arbeitsbeschaffung",
+    "arbeitserfolge",
+    "arbeitserlaubnis",
+    "arbeitsfähigkeit",
+    "arbeitsgemeinschaft",
+    "arbeitsgemeinschaften",
+    "arbeitsgeminschaft",
+    "arbeitskraft",
+    "arbeitslohn",
+    "arbeitslosigkeit",
+    "arbeitsplatzes",
+    "arbeitsschurz",
+    "arbeitsstelle",
+    "arbeitsstrenge",
+    "arbeitsstube",
+    "arbeitsteilung",
+    "arbeitstreue",
+    "arbeitsverkehr",
+    "arbeitsvermittlung",
+    "arbeitsvertrag",
+    "arbeitsvolles",
+    "arbeitszimmer",
+    "arbeitszimmers",
+    "arbet",
+    "archetypa",
+    "architekt",
+    "archiv",
+    "arg",
+    "arge",
+    "argen",
+    "arges",
+    "arglos",
+    "arglosen",
+    "argloser",
+    "argloses",
+    "arguet",
+    "argumentation",
+    "argumente",
+    "argumentiert",
+    "argwohn",
+    "aristokraten",
+    "aristokratie",
+    "aristokratischer",
+    "aristoteles",
+    "aristotelischen",
+    "armbrust",
+    "armbändern",
+    "arme",
+    "armee",
+    "armeeabschaffungsinitiative",
+    "armen",
+    "armenisch",
+    "armenpfleger",
+    "armenti",
+    "armer",
+    "armes",
+    "armhöhe",
+    "armin",
+    "armkraft",
+    "armselige",
+    "armseligste",
+    "armut",
+    "army",
+    "arphachsad",
+    "arrnbrust",
+    "arrogantia",
+    "arroganz",
+    "arten",
+    "artes",
+    "arthur",
+    "artig",
+    "artige",
+    "artigen",
+    "artiger",
+    "artiges",
+    "artigkeit",
+    "artikel",
+    "artikeln",
+    "artikulierten",
+    "artilleriegeneral",
+    "artilleriewesen",
+    "artlich",
+    "arts",
+    "arznei",
+    "arzneimittel",
+    "arzneimittelvertreter",
+    "ascendenten",
+    "asche",
+    "aschen",
+    "aschenbecher",
+    "aschenbechern",
+    "ascher",
+    "aschgraue",
+    "aschur",
+    "aschurs",
+    "aseka",
+    "asiatische",
+    "asien",
+    "aspekt",
+    "aspekte",
+    "aspides",
+    "aspik",
+    "ass",
+    "assen",
+    "assertorisch",
+    "assir",
+    "assistieren",
+    "associe",
+    "assoziationen",
+    "assoziativer",
+    "assoziierung",
+    "assyrer",
+    "aste",
+    "asten",
+    "aster",
+    "astern",
+    "aststümpchen",
+    "asung",
+    "atak",
+    "atelier",
+    "ateliers",
+    "atem",
+    "atemlos",
+    "atemlose",
+    "atemlosen",
+    "atemnot",
+    "atemzug",
+    "atemzüge",
+    "atemzügen",
+    "atheist",
+    "atlantischen",
+    "atmen",
+    "atmend",
+    "atmens",
+    "atmet",
+    "atmete",
+    "atmeten",
+    "atmosphäre",
+    "atmungsbeschwerde",
+    "atome",
+    "au",
+    "auch",
+    "aue",
+    "auen",
+    "auerhahn",
+    "auf",
+    "aufatmen",
+    "aufatmend",
+    "aufatmenden",
+    "aufatmete",
+    "aufbau",
+    "aufbauen",
+    "aufbaute",
+    "aufbauten",
+    "aufbehalten",
+    "aufbewahren",
+    "aufbewahrerin",
+    "aufbewahrt",
+    "aufbewahrte",
+    "aufbewahrten",
+    "aufbewahrung",
+    "aufbietung",
+    "aufblicken",
+    "aufblickte",
+    "aufblies",
+    "aufblitzen",
+    "aufblitzte",
+    "aufblühende",
+    "aufblühenden",
+    "aufblühten",
+    "aufbrechen",
+    "aufbrennenden",
+    "aufbringen",
+    "aufbrochen",
+    "aufbruch",
+    "aufbürdete",
+    "aufdeckte",
+    "aufdermauer",
+    "aufdringen",
+    "aufdringlich",
+    "aufdringt",
+    "aufeinander",
+    "aufeinanderbiss",
+    "aufeinanderfolge",
+    "aufeinanderfolgen",
+    "aufeinanderfolgend",
+    "aufeinanderfolgende",
+    "aufeinanderfolgenden",
+    "aufeinanderfolgender",
+    "aufeinanderfolgt",
+    "aufeinanderfolgten",
+    "aufeinanderschlügen",
+    "aufenthalt",
+    "aufenthalten",
+    "aufenthaltes",
+    "auferlegen",
+    "auferlegt",
+    "auferlegten",
+    "auferstand",
+    "auferstanden",
+    "auferstehen",
+    "aufersteht",
+    "auferstehung",
+    "auferstünde",
+    "auferwecken",
+    "auferweckt",
+    "auferzogen",
+    "aufessen",
+    "auffa",
+    "auffallen",
+    "auffallend",
+    "auffallenden",
+    "auffallender",
+    "auffassen",
+    "auffasst",
+    "auffassung",
+    "auffassungsvermögen",
+    "auffaßt",
+    "auffi",
+    "auffiel",
+    "auffinden",
+    "auffindung",
+    "aufflammt",
+    "aufforderden",
+    "auffordern",
+    "aufforderte",
+    "aufforderung",
+    "auffrass",
+    "auffraß",
+    "auffressen",
+    "auffuhren",
+    "auffällig",
+    "auffälligen",
+    "auffälliges",
+    "aufführen",
+    "aufführung",
+    "aufg",
+    "aufgab",
+    "aufgabe",
+    "aufgaben",
+    "aufgabenfeld",
+    "aufgang",
+    "aufgange",
+    "aufgangs",
+    "aufgearbeitet",
+    "aufgebaut",
+    "aufgebe",
+    "aufgeben",
+    "aufgeblasen",
+    "aufgebläht",
+    "aufgebracht",
+    "aufgebrachte",
+    "aufgebrachter",
+    "aufgebrochen",
+    "aufgedeckt",
+    "aufgedrungenes",
+    "aufgefahren",
+    "aufgefallen",
+    "aufgefangen",
+    "aufgefasst",
+    "aufgefaßt",
+    "aufgefressen",
+    "aufgefunden",
+    "aufgeführt",
+    "aufgegangen",
+    "aufgegeben",
+    "aufgegebenen",
+    "aufgegessen",
+    "aufgehalten",
+    "aufgehellt",
+    "aufgehen",
+    "aufgehenden",
+    "aufgehoben",
+    "aufgehobene",
+    "aufgeht",
+    "aufgehängt",
+    "aufgehäuft",
+    "aufgehört",
+    "aufgeklärt",
+    "aufgeklärten",
+    "aufgekommen",
+    "aufgekommenen",
+    "aufgeladen",
+    "aufgelegt",
+    "aufgelegte",
+    "aufgelesen",
+    "aufgelöset",
+    "aufgelöst",
+    "aufgemacht",
+    "aufgemerkt",
+    "aufgemuntert",
+    "aufgenommen",
+    "aufgepasst",
+    "aufgepflanzt",
+    "aufgepluschtem",
+    "aufgeplusterten",
+    "aufgereckt",
+    "aufgeregt",
+    "aufgeregten",
+    "aufgeregter",
+    "aufgeregtes",
+    "aufgereihten",
+    "aufgerichtet",
+    "aufgerieben",
+    "aufgerissen",
+    "aufgerissenen",
+    "aufgeräumt",
+    "aufgerüttelt",
+    "aufgesammelt",
+    "aufgeschaltet",
+    "aufgeschichtet",
+    "aufgeschlagen",
+    "aufgeschlossen",
+    "aufgeschossener",
+    "aufgeschreckt",
+    "aufgeschrieben",
+    "aufgeschürzt",
+    "aufgesehen",
+    "aufgesperrten",
+    "aufgesprungen",
+    "aufgestachelt",
+    "aufgestanden",
+    "aufgestapelt",
+    "aufgestellt",
+    "aufgestellte",
+    "aufgestellten",
+    "aufgestiegen",
+    "aufgetan",
+    "aufgetaucht",
+    "aufgeteilt",
+    "aufgetragen",
+    "aufgewachsen",
+    "aufgeweckt",
+    "aufgeweckten",
+    "aufgeworfene",
+    "aufgeworfenen",
+    "aufgezehrt",
+    "aufgezeichnet",
+    "aufgezettelt",
+    "aufgezählt",
+    "aufging",
+    "aufginge",
+    "aufgreifen",
+    "aufgriff",
+    "aufgrund",
+    "aufgschnauft",
+    "aufgsprungen",
+    "aufhalsen",
+    "aufhalte",
+    "aufhalten",
+    "aufheben",
+    "aufhebt",
+    "aufhebung",
+    "aufheiternde",
+    "aufhellen",
+    "aufheulen",
+    "aufheulend",
+    "aufhielt",
+    "aufhob",
+    "aufhorchte",
+    "aufhält",
+    "aufhängen",
+    "aufhäuften",
+    "aufhören",
+    "aufhört",
+    "aufhörte",
+    "aufhörten",
+    "aufkam",
+    "aufkeimt",
+    "aufklappte",
+    "aufklärte",
+    "aufklärung",
+    "aufkomme",
+    "aufkommen",
+    "aufkroch",
+    "aufkrochen",
+    "auflachen",
+    "auflachten",
+    "auflage",
+    "auflas",
+    "auflegen",
+    "aufleuchtende",
+    "aufleuchtenden",
+    "aufläufen",
+    "auflösen",
+    "auflösung",
+    "aufmache",
+    "aufmachen",
+    "aufmachte",
+    "aufmerken",
+    "aufmerkend",
+    "aufmerkende",
+    "aufmerkens",
+    "aufmerksam",
+    "aufmerksame",
+    "aufmerksamen",
+    "aufmerksamer",
+    "aufmerksamkeit",
+    "aufmerksamkeiten",
+    "aufmerkt",
+    "aufmerkte",
+    "aufmunterung",
+    "aufnahm",
+    "aufnahme",
+    "aufnahmegefäss",
+    "aufnahmegefässe",
+    "aufnahmegefäß",
+    "aufnahmegefäße",
+    "aufnahmen",
+    "aufnahmeprüfungen",
+    "aufnahmsfähig",
+    "aufnahmsfähigkeit",
+    "aufnahmsorgan",
+    "aufnehme",
+    "aufnehmen",
+    "aufnehmende",
+    "aufnehmenden",
+    "aufnehmendes",
+    "aufnimmt",
+    "aufopfernd",
+    "aufopferung",
+    "aufpassen",
+    "aufpasser",
+    "aufpasst",
+    "aufpassten",
+    "aufpflanzte",
+    "aufputz",
+    "aufragen",
+    "aufrappeln",
+    "aufrechnen",
+    "aufrecht",
+    "aufrechten",
+    "aufrechterhalten",
+    "aufregen",
+    "aufregende",
+    "aufregendsten",
+    "aufreger",
+    "aufregung",
+    "aufregungen",
+    "aufreiben",
+    "aufreissend",
+    "aufreißend",
+    "aufrichten",
+    "aufrichtete",
+    "aufrichteten",
+    "aufrichtig",
+    "aufrichtige",
+    "aufrichtigen",
+    "aufrichtiger",
+    "aufrichtigkeit",
+    "aufriss",
+    "aufrissen",
+    "aufriß",
+    "aufrollten",
+    "aufrufe",
+    "aufruhr",
+    "aufräume",
+    "aufrühren",
+    "aufrührend",
+    "aufs",
+    "aufsagen",
+    "aufsah",
+    "aufschauten",
+    "aufschichtete",
+    "aufschieben",
+    "aufschlag",
+    "aufschlagen",
+    "aufschlug",
+    "aufschlugen",
+    "aufschluss",
+    "aufschluß",
+    "aufschlüsse",
+    "aufschrei",
+    "aufschreiben",
+    "aufschreiende",
+    "aufschriften",
+    "aufschub",
+    "aufschwung",
+    "aufsehen",
+    "aufseher",
+    "aufsetzen",
+    "aufsetzte",
+    "aufseufzte",
+    "aufsicht",
+    "aufsperrte",
+    "aufspielen",
+    "aufspielt",
+    "aufspiessen",
+    "aufspießen",
+    "aufsprang",
+    "aufsprangen",
+    "aufspringen",
+    "aufspringenden",
+    "aufsprossen",
+    "aufsprosst",
+    "aufsprosste",
+    "aufsproßt",
+    "aufsproßte",
+    "aufstand",
+    "aufstanden",
+    "aufstehen",
+    "aufstehend",
+    "aufstehn",
+    "aufsteht",
+    "aufsteigen",
+    "aufsteigendem",
+    "aufsteigenden",
+    "aufsteigt",
+    "aufstellen",
+    "aufstellt",
+    "aufstellte",
+    "aufstellung",
+    "aufstieg",
+    "aufstiegsangebote",
+    "aufstiegschancen",
+    "aufstiess",
+    "aufstieß",
+    "aufstöhnend",
+    "aufsuchen",
+    "aufsucht",
+    "aufsuchte",
+    "aufsätze",
+    "auftat",
+    "auftaucht",
+    "auftauchten",
+    "auftrag",
+    "auftrage",
+    "auftrags",
+    "auftragt",
+    "auftreiben",
+    "auftreten",
+    "auftrieb",
+    "auftrieben",
+    "auftritt",
+    "auftritten",
+    "auftrittslied",
+    "aufträge",
+    "auftut",
+    "aufwach",
+    "aufwachst",
+    "aufwachte",
+    "aufwachten",
+    "aufwallten",
+    "aufwallung",
+    "aufwallungen",
+    "aufwand",
+    "aufwarten",
+    "aufwartenden",
+    "aufweist",
+    "aufwendige",
+    "aufwuchsen",
+    "aufwärter",
+    "aufwärts",
+    "aufwüchse",
+    "aufzehren",
+    "aufzeichnung",
+    "aufzeigen",
+    "aufziehenden",
+    "aufzuatmen",
+    "aufzubauen",
+    "aufzublasen",
+    "aufzublicken",
+    "aufzubrauchen",
+    "aufzubrechen",
+    "aufzubringen",
+    "aufzubürden",
+    "aufzuckt",
+    "aufzuessen",
+    "aufzufassen",
+    "aufzuführen",
+    "aufzug",
+    "aufzuge",
+    "aufzugeben",
+    "aufzugskabine",
+    "aufzuhalsen",
+    "aufzuhalten",
+    "aufzuheben",
+    "aufzuheitern",
+    "aufzuhören",
+    "aufzukommen",
+    "aufzulösen",
+    "aufzumachen",
+    "aufzumuntern",
+    "aufzunehmen",
+    "aufzupassen",
+    "aufzuraffen",
+    "aufzurollen",
+    "aufzuschlagen",
+    "aufzuschreiben",
+    "aufzusehen",
+    "aufzusparen",
+    "aufzuspringen",
+    "aufzustellen",
+    "aufzustellende",
+    "aufzusuchen",
+    "aufzutragen",
+    "aufzutreiben",
+    "aufzutun",
+    "aufzuwecken",
+    "aufzuweisen",
+    "aufzuwerten",
+    "aufzuwiegen",
+    "aufzählt",
+    "aufzählung",
+    "aug",
+    "auge",
+    "augen",
+    "augenaufschlag",
+    "augenblick",
+    "augenblicke",
+    "augenblicken",
+    "augenblicklich",
+    "augenblicklichen",
+    "augenbogen",
+    "augenbraunen",
+    "augenlider",
+    "augenmerk",
+    "augenschein",
+    "augenscheinlich",
+    "augenscheinliche",
+    "augenscheinlicher",
+    "augenschwache",
+    "augenwinkel",
+    "auges",
+    "augsburg",
+    "augst",
+    "august",
+    "aura",
+    "aus",
+    "ausarbeiten",
+    "ausartet",
+    "ausartung",
+    "ausbesserung",
+    "ausbeutete",
+    "ausbeutung",
+    "ausbilden",
+    "ausbildete",
+    "ausbildung",
+    "ausblase",
+    "ausbleiben",
+    "ausbleibt",
+    "ausblick",
+    "ausbrach",
+    "ausbreche",
+    "ausbrechen",
+    "ausbrechend",
+    "ausbrechendem",
+    "ausbrechenden",
+    "ausbreite",
+    "ausbreiten",
+    "ausbreitet",
+    "ausbreitete",
+    "ausbreiteten",
+    "ausbreitung",
+    "ausbricht",
+    "ausbruch",
+    "ausbrüten",
+    "ausbund",
+    "ausdachte",
+    "ausdauer",
+    "ausdauernd",
+    "ausdehnen",
+    "ausdehnt",
+    "ausdehnung",
+    "ausdenken",
+    "ausdeuteten",
+    "ausdruck",
+    "ausdrucke",
+    "ausdrucks",
+    "ausdrucksweise",
+    "ausdrücke",
+    "ausdrücken",
+    "ausdrücklich",
+    "ausdrückliche",
+    "ausdrückt",
+    "ausdrückte",
+    "ausdrückten",
+    "ausdünstet",
+    "ausdünstungen",
+    "auseinander",
+    "auseinanderfahren",
+    "auseinanderfalteten",
+    "auseinandergeschnitten",
+    "auseinanderhuschten",
+    "auseinanderjagte",
+    "auseinanderschneiden",
+    "auseinandersetzen",
+    "auseinandersetzt",
+    "auseinandersetzung",
+    "auseinanderteilte",
+    "auseinanderzugehen",
+    "auseinanderzukeilen",
+    "auseinanderzusetzen",
+    "ausersehen",
+    "auserwählen",
+    "auserwählt",
+    "auserwählte",
+    "auserwählten",
+    "auserwähltes",
+    "ausfallen",
+    "ausfiel",
+    "ausfiele",
+    "ausfindig",
+    "ausfliesst",
+    "ausfließt",
+    "ausflucht",
+    "ausflüge",
+    "ausflüsse",
+    "ausflüssen",
+    "ausforschen",
+    "ausführen",
+    "ausführlich",
+    "ausführlichen",
+    "ausführlicher",
+    "ausführlichere",
+    "ausführlichst",
+    "ausführst",
+    "ausführt",
+    "ausführte",
+    "ausführung",
+    "ausfüllen",
+    "ausg",
+    "ausgab",
+    "ausgabe",
+    "ausgaben",
+    "ausgang",
+    "ausgangs",
+    "ausgangspunkt",
+    "ausgearbeitet",
+    "ausgeben",
+    "ausgebeutet",
+    "ausgebildet",
+    "ausgebildete",
+    "ausgeblasen",
+    "ausgeblasene",
+    "ausgeblieben",
+    "ausgebliebenen",
+    "ausgeboren",
+    "ausgebrannt",
+    "ausgebreitet",
+    "ausgebrüteten",
+    "ausgedacht",
+    "ausgedehnt",
+    "ausgedehnten",
+    "ausgedehntes",
+    "ausgediente",
+    "ausgedienten",
+    "ausgedientes",
+    "ausgedruckte",
+    "ausgedrückt",
+    "ausgedörrten",
+    "ausgedörrtes",
+    "ausgefallen",
+    "ausgeflossen",
+    "ausgeforscht",
+    "ausgefragt",
+    "ausgeführt",
+    "ausgefüllt",
+    "ausgegangen",
+    "ausgegangene",
+    "ausgegeben",
+    "ausgegebendie",
+    "ausgeglichen",
+    "ausgegossen",
+    "ausgegraben",
+    "ausgehaucht",
+    "ausgehauen",
+    "ausgehe",
+    "ausgeheckt",
+    "ausgehen",
+    "ausgehend",
+    "ausgehende",
+    "ausgehenden",
+    "ausgehn",
+    "ausgehorcht",
+    "ausgeht",
+    "ausgehungerte",
+    "ausgehungertes",
+    "ausgeklügelt",
+    "ausgekratzt",
+    "ausgelacht",
+    "ausgelassen",
+    "ausgelassene",
+    "ausgelassenheit",
+    "ausgelassenste",
+    "ausgelassensten",
+    "ausgelastet",
+    "ausgelegt",
+    "ausgelöscht",
+    "ausgelöschte",
+    "ausgemacht",
+    "ausgemachte",
+    "ausgemalten",
+    "ausgemittelt",
+    "ausgenommen",
+    "ausgenommene",
+    "ausgenossen",
+    "ausgenutzt",
+    "ausgepeitscht",
+    "ausgepustet",
+    "ausgeputzt",
+    "ausgequetschten",
+    "ausgerechnet",
+    "ausgereckt",
+    "ausgereiften",
+    "ausgerichtet",
+    "ausgerissen",
+    "ausgeritten",
+    "ausgerottet",
+    "ausgerufen",
+    "ausgerufenen",
+    "ausgerückt",
+    "ausgerüstet",
+    "ausgesagt",
+    "ausgesandt",
+    "ausgesandte",
+    "ausgeschaltet",
+    "ausgeschickt",
+    "ausgeschieden",
+    "ausgeschlachtet",
+    "ausgeschlafen",
+    "ausgeschlagen",
+    "ausgeschlossen",
+    "ausgeschnitzt",
+    "ausgeschoben",
+    "ausgeschrieben",
+    "ausgeschrieen",
+    "ausgeschweiften",
+    "ausgeschüttet",
+    "ausgesehen",
+    "ausgesetzt",
+    "ausgesetzte",
+    "ausgesondert",
+    "ausgesonnen",
+    "ausgespannt",
+    "ausgespart",
+    "ausgesprochen",
+    "ausgesprochenen",
+    "ausgestaltung",
+    "ausgestattet",
+    "ausgestatteter",
+    "ausgesteckt",
+    "ausgesteckten",
+    "ausgestellt",
+    "ausgestorben",
+    "ausgestossen",
+    "ausgestoßen",
+    "ausgestrahlt",
+    "ausgestreckt",
+    "ausgestreckte",
+    "ausgestrecktem",
+    "ausgestreckten",
+    "ausgesuchte",
+    "ausgesuchten",
+    "ausgetauscht",
+    "ausgeteilt",
+    "ausgeteilte",
+    "ausgetilgt",
+    "ausgetragen",
+    "ausgetreten",
+    "ausgetretene",
+    "ausgetrieben",
+    "ausgetrocknet",
+    "ausgewandert",
+    "ausgeweidet",
+    "ausgewischt",
+    "ausgewogen",
+    "ausgeworfen",
+    "ausgezackten",
+    "ausgezeichnet",
+    "ausgezeichnete",
+    "ausgezeichneten",
+    "ausgezeichnetes",
+    "ausgezeichnetrückbesinnung",
+    "ausgeziechnet",
+    "ausgezogen",
+    "ausgezogene",
+    "ausgezogenen",
+    "ausgeübt",
+    "ausgiebigen",
+    "ausgiebigkeit",
+    "ausgiessen",
+    "ausgießen",
+    "ausging",
+    "ausginge",
+    "ausgingen",
+    "ausgmacht",
+    "ausgoss",
+    "ausgoß",
+    "ausgredt",
+    "ausgstossen",
+    "ausgwichen",
+    "ausgworfen",
+    "ausgäben",
+    "aushalt",
+    "aushalten",
+    "ausharren",
+    "aushauchte",
+    "aushauen",
+    "aushielt",
+    "aushilfe",
+    "ausholend",
+    "aushungern",
+    "aushält",
+    "auskennen",
+    "ausklang",
+    "auskneifer",
+    "auskommen",
+    "auskommens",
+    "auskröchen",
+    "auskunft",
+    "auslachen",
+    "auslachte",
+    "ausladungen",
+    "auslagen",
+    "ausland",
+    "auslassen",
+    "auslaufen",
+    "auslebte",
+    "ausleeren",
+    "auslegen",
+    "auslegt",
+    "auslegung",
+    "ausliesse",
+    "ausließe",
+    "ausländischen",
+    "ausläuft",
+    "auslöschen",
+    "auslöscht",
+    "auslöschte",
+    "auslöschung",
+    "ausmache",
+    "ausmachen",
+    "ausmacht",
+    "ausmachte",
+    "ausmachten",
+    "ausmass",
+    "ausmaß",
+    "ausmündung",
+    "ausnahme",
+    "ausnahmen",
+    "ausnahmsweise",
+    "ausnehmen",
+    "ausnehmend",
+    "ausnutzen",
+    "ausnähme",
+    "auspfiff",
+    "ausrecken",
+    "ausreden",
+    "ausreichenden",
+    "ausreicht",
+    "ausreissen",
+    "ausrichten",
+    "ausrichtet",
+    "ausrief",
+    "ausrotten",
+    "ausrottet",
+    "ausruf",
+    "ausrufe",
+    "ausruhen",
+    "ausruhet",
+    "ausräumen",
+    "ausrücken",
+    "ausrückt",
+    "ausrüstung",
+    "ausrüstungsgegenständen",
+    "aussaat",
+    "aussage",
+    "aussagen",
+    "aussagenden",
+    "aussah",
+    "aussahen",
+    "aussatz",
+    "aussatzes",
+    "ausschalten",
+    "ausschicken",
+    "ausschied",
+    "ausschlag",
+    "ausschliesslich",
+    "ausschliessliches",
+    "ausschliesst",
+    "ausschliessungsweise",
+    "ausschließen",
+    "ausschließlich",
+    "ausschließliches",
+    "ausschlugen",
+    "ausschmückten",
+    "ausschneiden",
+    "ausschnitt",
+    "ausschnitte",
+    "ausschuss",
+    "ausschwenkend",
+    "ausschütten",
+    "aussehe",
+    "aussehen",
+    "aussehender",
+    "aussehens",
+    "aussen",
+    "aussenden",
+    "aussendung",
+    "aussenminister",
+    "aussenpolitik",
+    "aussenwerke",
+    "ausser",
+    "ausserdem",
+    "aussergewöhnliche",
+    "aussergewöhnliches",
+    "ausserhalb",
+    "ausserordentlich",
+    "ausserordentliche",
+    "ausserordentlichen",
+    "aussetzen",
+    "aussetzend",
+    "aussetzte",
+    "aussetzten",
+    "aussi",
+    "aussicht",
+    "aussichten",
+    "aussichtslos",
+    "aussichtspunkte",
+    "aussiehst",
+    "aussieht",
+    "aussigraspelt",
+    "aussigsprungen",
+    "aussiheiret",
+    "aussilassen",
+    "aussinnen",
+    "aussischickt",
+    "aussizahlt",
+    "ausspannen",
+    "ausspannt",
+    "ausspannung",
+    "ausspielen",
+    "aussprach",
+    "aussprache",
+    "aussprachen",
+    "aussprechen",
+    "ausspricht",
+    "ausspruch",
+    "ausspruche",
+    "aussprüchen",
+    "ausspuckend",
+    "ausspähenden",
+    "ausspüren",
+    "ausstand",
+    "ausstarb",
+    "ausstattung",
+    "ausstehen",
+    "ausstehendes",
+    "aussteigen",
+    "ausstellung",
+    "aussteuer",
+    "ausstieg",
+    "ausstiess",
+    "ausstieß",
+    "ausstossen",
+    "ausstoßen",
+    "ausstrahlen",
+    "ausstrahlt",
+    "ausstrahlung",
+    "ausstrecke",
+    "ausstrecken",
+    "ausstreckt",
+    "ausstreckte",
+    "ausstreckung",
+    "ausströmen",
+    "aussuchst",
+    "aussähe",
+    "aussätzig",
+    "aussätzige",
+    "aussätzigen",
+    "austausch",
+    "austauschen",
+    "austauschte",
+    "austeilende",
+    "austellers",
+    "austern",
+    "austilgten",
+    "australien",
+    "austraten",
+    "austreibe",
+    "austrinken",
+    "austritte",
+    "austrittstrenddie",
+    "austrittswelle",
+    "austrocknen",
+    "austrocknet",
+    "austrocknete",
+    "auswahl",
+    "auswandern",
+    "auswanderung",
+    "auswanderungsziel",
+    "auswarf",
+    "ausweg",
+    "auswege",
+    "ausweis",
+    "ausweisen",
+    "ausweiset",
+    "ausweisst",
+    "auswendig",
+    "auswendige",
+    "auswendigere",
+    "auswendigeren",
+    "auswendigeres",
+    "auswendiges",
+    "auswendiglernen",
+    "auswendigsten",
+    "auswerfen",
+    "auswichen",
+    "auswirft",
+    "auswuchsen",
+    "auswärtige",
+    "auswärtigem",
+    "auswärtigen",
+    "auswärts",
+    "auswüchse",
+    "auszahlen",
+    "auszahlte",
+    "auszehrung",
+    "auszeichnen",
+    "auszeichnender",
+    "auszeichnet",
+    "auszeichnung",
+    "ausziehen",
+    "ausziehenden",
+    "auszieht",
+    "ausziehtisch",
+    "auszog",
+    "auszogen",
+    "auszuarbeiten",
+    "auszubauen",
+    "auszubilden",
+    "auszudehnen",
+    "auszudrücken",
+    "auszufahren",
+    "auszuführen",
+    "auszufüllen",
+    "auszug",
+    "auszugeben",
+    "auszugehen",
+    "auszuhalten",
+    "auszuklagen",
+    "auszukneifen",
+    "auszukochen",
+    "auszukosten",
+    "auszulachen",
+    "auszulassen",
+    "auszulöschen",
+    "auszumachen",
+    "auszunutzen",
+    "auszupacken",
+    "auszuputzen",
+    "auszureissen",
+    "auszureuten",
+    "auszurichten",
+    "auszurotten",
+    "auszurufen",
+    "auszuruhen",
+    "auszurücken",
+    "auszurüsten",
+    "auszuschlagen",
+    "auszuschliessen",
+    "auszuschmücken",
+    "auszuschreiten",
+    "auszusehen",
+    "auszusetzen",
+    "auszusinnen",
+    "auszuspannen",
+    "auszusperren",
+    "auszusprechen",
+    "auszustaffieren",
+    "auszustossen",
+    "auszustrecken",
+    "auszuteilen",
+    "auszuweichen",
+    "auszuziehen",
+    "auszählung",
+    "auszüge",
+    "auszügen",
+    "ausübe",
+    "ausüben",
+    "ausübt",
+    "ausübung",
+    "autokratischen",
+    "automatisch",
+    "automobil",
+    "autonomie",
+    "autor",
+    "autorität",
+    "autos",
+    "autoverkäufen",
+    "außen",
+    "außenwerke",
+    "außer",
+    "außerdem",
+    "außergewöhnliche",
+    "außerhalb",
+    "außerordentlich",
+    "außerordentlichen",
+    "avancierte",
+    "avanti",
+    "aven",
+    "aversamur",
+    "axt",
+    "aydin",
+    "aß",
+    "aßen",
+    "b",
+    "baam",
+    "baaselbieterdütsch",
+    "babel",
+    "babels",
+    "baby",
+    "babylon",
+    "babylonische",
+    "bacchanal",
+    "bach",
+    "bache",
+    "baches",
+    "bachforellen",
+    "bachs",
+    "bachtal",
+    "bachweiden",
+    "bachwellen",
+    "backbirnen",
+    "backe",
+    "backen",
+    "backenbärtchen",
+    "backofen",
+    "backsteinplatte",
+    "backtröge",
+    "backwerk",
+    "baco",
+    "bademantels",
+    "baden",
+    "badeplatz",
+    "badepüppchen",
+    "badewanne",
+    "badische",
+    "bagatellwechseln",
+    "bagels",
+    "bah",
+    "bahn",
+    "bahnbrechenden",
+    "bahnen",
+    "bahnhof",
+    "bahnhofes",
+    "bahnte",
+    "bahrtuch",
+    "bajonett",
+    "balaenae",
+    "bald",
+    "baldige",
+    "balester",
+    "balken",
+    "balkone",
+    "balkonen",
+    "ball",
+    "ballade",
+    "balles",
+    "balsam",
+    "balsamfläschchen",
+    "band",
+    "bandapparat",
+    "bande",
+    "bandelier",
+    "bandeliere",
+    "banden",
+    "bandgerät",
+    "banditenähnlichen",
+    "bandschnippel",
+    "bang",
+    "bange",
+    "bangen",
+    "banger",
+    "bangigkeit",
+    "bankbeamte",
+    "bankbeamten",
+    "bankel",
+    "banken",
+    "bankett",
+    "bankgeschäft",
+    "bankhause",
+    "bankier",
+    "bankiere",
+    "bankiers",
+    "bankiervereinigung",
+    "bankkonto",
+    "bankl",
+    "banknote",
+    "bankratib",
+    "bankraub",
+    "bankräuber",
+    "bankräuberin",
+    "banküberfall",
+    "banküberfällen",
+    "bann",
+    "barauslage",
+    "barbaren",
+    "barbarischen",
+    "barbiergesellen",
+    "baren",
+    "bares",
+    "barfuss",
+    "barfuß",
+    "barg",
+    "barhäuptig",
+    "barmherzig",
+    "barmherziger",
+    "barmherzigkeit",
+    "barmherzigkeiten",
+    "barsch",
+    "barschaft",
+    "barsche",
+    "barsches",
+    "barsängerin",
+    "bart",
+    "barte",
+    "bartes",
+    "barthaare",
+    "barthel",
+    "bartholomaios",
+    "bartstrahn",
+    "bartwichse",
+    "baschans",
+    "basel",
+    "baselder",
+    "baseler",
+    "baselland",
+    "basellandschäftler",
+    "basilisk",
+    "basilisken",
+    "basiliskeneier",
+    "basler",
+    "bass",
+    "bassewitz",
+    "bassgeige",
+    "bassgeigenkonzert",
+    "bassgeiger",
+    "bassstimme",
+    "bast",
+    "basteln",
+    "bastelten",
+    "basthaar",
+    "baten",
+    "bath",
+    "batley",
+    "batterie",
+    "batzen",
+    "bau",
+    "bauamtsleiter",
+    "bauart",
+    "bauch",
+    "bauche",
+    "bauches",
+    "bauchflasche",
+    "bauchschmerzen",
+    "bauchwitz",
+    "baudenweibe",
+    "bauen",
+    "bauer",
+    "bauern",
+    "bauerngehöftes",
+    "bauernhand",
+    "bauernhause",
+    "bauernhof",
+    "bauernhöfe",
+    "bauernklotzes",
+    "bauernknecht",
+    "bauernmadl",
+    "bauernpack",
+    "bauernregeln",
+    "bauernsohn",
+    "bauernstube",
+    "bauernwirt",
+    "bauers",
+    "bauersleute",
+    "bauersmann",
+    "bauerssohn",
+    "baues",
+    "bauet",
+    "bauete",
+    "baufälliges",
+    "baulichkeiten",
+    "baum",
+    "baumast",
+    "baumblätter",
+    "baume",
+    "baumeln",
+    "baumelnd",
+    "baumelte",
+    "baumes",
+    "baumgespenster",
+    "baumhöhe",
+    "baumklötze",
+    "baumknospen",
+    "baumstamm",
+    "baumstamme",
+    "baumstumpf",
+    "baumstöcke",
+    "baumwipfel",
+    "baumwipfeln",
+    "baumwolle",
+    "baumwollene",
+    "baumwurzel",
+    "baut",
+    "baute",
+    "bauten",
+    "bauverständiger",
+    "bauwerk",
+    "bauz",
+    "bayer",
+    "bayerischer",
+    "bayern",
+    "bayers",
+    "baz",
+    "baßstimme",
+    "bdellium",
+    "bea",
+    "beabsichtige",
+    "beabsichtigen",
+    "beabsichtigt",
+    "beabsichtigte",
+    "beabsichtigten",
+    "beachte",
+    "beachten",
+    "beachtet",
+    "beachtete",
+    "beachteten",
+    "beachtung",
+    "beamte",
+    "beamten",
+    "beamtenschaft",
+    "beamtenstellen",
+    "beamtenwelt",
+    "beamter",
+    "beanstanden",
+    "beantworten",
+    "beantwortete",
+    "beantwortung",
+    "bearbeiten",
+    "bearbeitend",
+    "bearbeitet",
+    "bearbeitung",
+    "beatrix",
+    "beaufsichtigen",
+    "beaufsichtigungsrecht",
+    "beauftragen",
+    "beauftragt",
+    "beauftragte",
+    "beauftragter",
+    "bebauen",
+    "bebaut",
+    "bebaute",
+    "bebauung",
+    "beben",
+    "bebend",
+    "beblühte",
+    "bebte",
+    "becher",
+    "becken",
+    "bedacht",
+    "bedachte",
+    "bedachtem",
+    "bedachtlosen",
+    "bedachtsam",
+    "bedanke",
+    "bedanken",
+    "bedankt",
+    "bedankten",
+    "bedarf",
+    "bedauere",
+    "bedauerlich",
+    "bedauern",
+    "bedauernswerter",
+    "bedauerte",
+    "bedecken",
+    "bedeckt",
+    "bedeckte",
+    "bedeckten",
+    "bedeckung",
+    "bedenke",
+    "bedenken",
+    "bedenkendie",
+    "bedenklich",
+    "bedenkliche",
+    "bedenklichen",
+    "bedenklicher",
+    "bedenklichsten",
+    "bedenkt",
+    "bedeute",
+    "bedeuten",
+    "bedeutend",
+    "bedeutende",
+    "bedeutenden",
+    "bedeutendsten",
+    "bedeutet",
+    "bedeutete",
+    "bedeuteten",
+    "bedeutsam",
+    "bedeutsames",
+    "bedeutsamkeit",
+    "bedeutsamsten",
+    "bedeutung",
+    "bedeutungen",
+    "bedeutungslos",
+    "bedeutungslose",
+    "bedeutungsvollen",
+    "bediene",
+    "bedienen",
+    "bedient",
+    "bediente",
+    "bedienten",
+    "bedienung",
+    "bedinge",
+    "bedingt",
+    "bedingte",
+    "bedingten",
+    "bedingter",
+    "bedingung",
+    "bedingungen",
+    "bedrohete",
+    "bedroht",
+    "bedrohung",
+    "bedrängen",
+    "bedrängt",
+    "bedrängte",
+    "bedrücken",
+    "bedrückende",
+    "bedrückt",
+    "bedrückten",
+    "beduinen",
+    "bedurfte",
+    "bedurften",
+    "bedächtig",
+    "bedünken",
+    "bedünkte",
+    "bedürfe",
+    "bedürfen",
+    "bedürfnis",
+    "bedürfnisse",
+    "bedürfnissen",
+    "bedürfnisses",
+    "bedürftig",
+    "bedürftige",
+    "bedürftiger",
+    "bedürftiges",
+    "beeile",
+    "beeilen",
+    "beeilte",
+    "beeilten",
+    "beeindruckendsten",
+    "beeindruckt",
+    "beeindruckte",
+    "beeinflusst",
+    "beeinflußt",
+    "beeinträchtigen",
+    "beenden",
+    "beendet",
+    "beendigt",
+    "beendigung",
+    "beengte",
+    "beengung",
+    "beer",
+    "beerchen",
+    "beerdigt",
+    "beerdigungsinstitutes",
+    "beere",
+    "beeren",
+    "beerenkraut",
+    "beerensträucher",
+    "beerensträuchern",
+    "beerscheba",
+    "beet",
+    "beete",
+    "beethovens",
+    "befahl",
+    "befahlen",
+    "befand",
+    "befanden",
+    "befangen",
+    "befangenheit",
+    "befassen",
+    "befassend",
+    "befassete",
+    "befasst",
+    "befehden",
+    "befehl",
+    "befehle",
+    "befehlen",
+    "befehls",
+    "befehlsartig",
+    "befehlshaber",
+    "befehlt",
+    "befestigen",
+    "befestigt",
+    "befestigte",
+    "befestigten",
+    "befiel",
+    "befinde",
+    "befinden",
+    "befindet",
+    "befindliche",
+    "befindlichen",
+    "befleckt",
+    "befleissigt",
+    "befleissigte",
+    "beflissen",
+    "beflügeltes",
+    "befohlen",
+    "befolgen",
+    "befolgt",
+    "befolgte",
+    "befolgten",
+    "befolgung",
+    "befrachten",
+    "befrage",
+    "befragen",
+    "befragt",
+    "befragungen",
+    "befreie",
+    "befreien",
+    "befreit",
+    "befreite",
+    "befreiung",
+    "befremden",
+    "befremdet",
+    "befreundet",
+    "befriedigen",
+    "befriedigt",
+    "befriedigte",
+    "befriedigten",
+    "befriedigung",
+    "befristet",
+    "befruchten",
+    "befruchtet",
+    "befruchtung",
+    "befruchtungen",
+    "befugnis",
+    "befugnisse",
+    "befugt",
+    "befunden",
+    "befähigt",
+    "befällt",
+    "befänden",
+    "beförderlich",
+    "befördern",
+    "befördert",
+    "beförderung",
+    "beförderungsmittel",
+    "befühlt",
+    "befühlten",
+    "befürchten",
+    "befürchtend",
+    "befürchtete",
+    "befürchteten",
+    "befürchtung",
+    "befürchtungen",
+    "begab",
+    "begaben",
+    "begabt",
+    "begabteste",
+    "begangen",
+    "begangene",
+    "begangenen",
+    "begann",
+    "begannen",
+    "begeben",
+    "begebend",
+    "begebenheit",
+    "begebenheiten",
+    "begegnen",
+    "begegnet",
+    "begegnete",
+    "begegneten",
+    "begegnung",
+    "begegnungen",
+    "begehen",
+    "begehre",
+    "begehren",
+    "begehrens",
+    "begehrenswert",
+    "begehret",
+    "begehrlichkeit",
+    "begehrt",
+    "begehrte",
+    "begehrten",
+    "begehrung",
+    "begehrungen",
+    "begehrungs",
+    "begehrungsvermögen",
+    "begehrungsvermögens",
+    "begeht",
+    "begeistert",
+    "begeisterung",
+    "begeisterungsstürmen",
+    "begib",
+    "begibt",
+    "begiedren",
+    "begierde",
+    "begierden",
+    "begierig",
+    "begierigen",
+    "begieriger",
+    "begiessen",
+    "begine",
+    "beging",
+    "beginn",
+    "beginne",
+    "beginnen",
+    "beginnt",
+    "beglaubigen",
+    "beglaubigt",
+    "beglaubigte",
+    "begleiten",
+    "begleitend",
+    "begleiter",
+    "begleitern",
+    "begleiters",
+    "begleitet",
+    "begleitete",
+    "begleiteten",
+    "begleitherren",
+    "begleitung",
+    "beglücken",
+    "beglückte",
+    "beglückwünschen",
+    "begnüge",
+    "begnügt",
+    "begnügte",
+    "begonnen",
+    "begoss",
+    "begossenen",
+    "begraben",
+    "begrasten",
+    "begreif",
+    "begreife",
+    "begreifen",
+    "begreiflich",
+    "begreiflichen",
+    "begreiflicher",
+    "begreiflichkeit",
+    "begreift",
+    "begrenzen",
+    "begrenzt",
+    "begrenzte",
+    "begrenztheit",
+    "begrenzung",
+    "begriff",
+    "begriffe",
+    "begriffen",
+    "begriffener",
+    "begriffenes",
+    "begriffes",
+    "begriffs",
+    "begriffsstutziger",
+    "begriffsverhältnisse",
+    "begrub",
+    "begruben",
+    "begräbnis",
+    "begräbnisse",
+    "begründe",
+    "begründen",
+    "begründendem",
+    "begründendes",
+    "begründet",
+    "begründete",
+    "begründeten",
+    "begründung",
+    "begründungen",
+    "begrüssen",
+    "begrüssend",
+    "begrüssenden",
+    "begrüsst",
+    "begrüsste",
+    "begrüssten",
+    "begrüssung",
+    "begrüssungsknicks",
+    "begrüßen",
+    "begrüßt",
+    "begrüßung",
+    "begucken",
+    "beguckten",
+    "begünstigen",
+    "begünstigt",
+    "begünstigte",
+    "begünstigung",
+    "begürtet",
+    "begürtete",
+    "begüterte",
+    "behaarung",
+    "behaftet",
+    "behagen",
+    "behaglich",
+    "behaglicher",
+    "behagliches",
+    "behaglichkeit",
+    "behagte",
+    "behagten",
+    "behalte",
+    "behalten",
+    "behaltet",
+    "behandeln",
+    "behandelt",
+    "behandelte",
+    "behandelten",
+    "behandle",
+    "behandlung",
+    "beharre",
+    "beharren",
+    "beharrlich",
+    "beharrlichkeit",
+    "beharrt",
+    "beharrte",
+    "beharrungsvermögen",
+    "behauchten",
+    "behaupte",
+    "behaupten",
+    "behauptend",
+    "behauptet",
+    "behauptete",
+    "behaupteten",
+    "behauptung",
+    "behauptungen",
+    "behausung",
+    "behelfen",
+    "behende",
+    "beherbergen",
+    "beherbergte",
+    "beherrschen",
+    "beherrschend",
+    "beherrschenden",
+    "beherrscht",
+    "beherrschte",
+    "beherrschung",
+    "beherzten",
+    "beherzter",
+    "behielt",
+    "behilflich",
+    "behindern",
+    "behindert",
+    "behuf",
+    "behufe",
+    "behufs",
+    "behutsam",
+    "behutsames",
+    "behält",
+    "behälter",
+    "behältern",
+    "behältnis",
+    "behältnisse",
+    "behängt",
+    "behörde",
+    "behörden",
+    "behüt",
+    "behüte",
+    "behüten",
+    "behütet",
+    "bei",
+    "beibehalten",
+    "beibehaltender",
+    "beibrachte",
+    "beibringen",
+    "beichte",
+    "beichtete",
+    "beichtstuhl",
+    "beide",
+    "beiden",
+    "beider",
+    "beiderlei",
+    "beiderseitig",
+    "beiderseits",
+    "beides",
+    "beieinander",
+    "beifall",
+    "beifallklatschen",
+    "beifalls",
+    "beifallsgeschrei",
+    "beifälligem",
+    "beifälliges",
+    "beifügen",
+    "beifügten",
+    "beigebracht",
+    "beigefallen",
+    "beigefügt",
+    "beigegeben",
+    "beigegebene",
+    "beigegebenes",
+    "beigelegt",
+    "beigemessen",
+    "beigemischt",
+    "beigeordnet",
+    "beigesellen",
+    "beigesellet",
+    "beigesellt",
+    "beigesellte",
+    "beigesellten",
+    "beigesellung",
+    "beigesetzt",
+    "beigesteckt",
+    "beigestimmt",
+    "beigewohnt",
+    "beihilfe",
+    "beikommen",
+    "beile",
+    "beilege",
+    "beilegen",
+    "beilegt",
+    "beilegte",
+    "beilegung",
+    "beileib",
+    "beiliegend",
+    "beim",
+    "beimessen",
+    "beimischung",
+    "bein",
+    "beinah",
+    "beinahe",
+    "beinamen",
+    "beinchen",
+    "beinchenende",
+    "beincheneroberung",
+    "beinchengeschichte",
+    "beinchens",
+    "beine",
+    "beinen",
+    "beines",
+    "beinkleider",
+    "beinkleidern",
+    "beinwerks",
+    "beirrte",
+    "beisammen",
+    "beisatz",
+    "beiseit",
+    "beiseite",
+    "beispiel",
+    "beispiele",
+    "beispielen",
+    "beispielsweise",
+    "beissbares",
+    "beissen",
+    "beissend",
+    "beissenden",
+    "beisst",
+    "beistand",
+    "beistehen",
+    "beisteher",
+    "beistimmendes",
+    "beistimmt",
+    "beistimmte",
+    "beistimmung",
+    "beitrag",
+    "beitragen",
+    "beitritt",
+    "beitritte",
+    "beiwohnen",
+    "beizeiten",
+    "beizte",
+    "beizubringen",
+    "beizulegen",
+    "beizuspringen",
+    "beizustehen",
+    "beizusteuern",
+    "beizutragen",
+    "beizutreiben",
+    "beizutreten",
+    "beizuwohnen",
+    "beißen",
+    "beißend",
+    "beißt",
+    "bejahend",
+    "bejahrten",
+    "bejahung",
+    "bekam",
+    "bekamen",
+    "bekannt",
+    "bekannte",
+    "bekannten",
+    "bekanntgeworden",
+    "bekanntlich",
+    "bekanntschaft",
+    "bekanntschaften",
+    "bekehre",
+    "bekehren",
+    "bekehrer",
+    "bekehret",
+    "bekehrt",
+    "bekehrten",
+    "bekehrung",
+    "bekenne",
+    "bekennen",
+    "bekennt",
+    "bekenntnis",
+    "bekenntnisse",
+    "bekenntnissen",
+    "bekir",
+    "beklagen",
+    "beklagenswert",
+    "beklagt",
+    "beklagte",
+    "beklebt",
+    "bekleiden",
+    "bekleidet",
+    "bekleidete",
+    "bekleidung",
+    "beklemmung",
+    "beklommen",
+    "beklommenheit",
+    "bekomm",
+    "bekomme",
+    "bekommen",
+    "bekommst",
+    "bekommt",
+    "bekreuzte",
+    "bekreuzten",
+    "bekriegten",
+    "bekräftigt",
+    "bekräftigung",
+    "bekräftigungen",
+    "bekränzt",
+    "bekäme",
+    "bekämen",
+    "bekämpfen",
+    "bekämpft",
+    "bekämpfung",
+    "beköstigung",
+    "bekümmern",
+    "bekümmert",
+    "bekümmerte",
+    "beladen",
+    "beladenes",
+    "belagern",
+    "belagert",
+    "belagerung",
+    "belanglos",
+    "belangloseste",
+    "belangt",
+    "belassen",
+    "belastend",
+    "belastet",
+    "belastete",
+    "belastung",
+    "belaubte",
+    "belaubter",
+    "belebbar",
+    "belebe",
+    "beleben",
+    "belebende",
+    "belebt",
+    "belebte",
+    "belebtes",
+    "belege",
+    "belegen",
+    "belegexemplar",
+    "belegt",
+    "belehren",
+    "belehrt",
+    "belehrte",
+    "belehrung",
+    "beleibt",
+    "beleibtheit",
+    "beleidigen",
+    "beleidigend",
+    "beleidigender",
+    "beleidigendste",
+    "beleidigt",
+    "beleidigte",
+    "beleidigten",
+    "beleidigung",
+    "beleidigungen",
+    "beleidung",
+    "beleuchtet",
+    "beleuchtete",
+    "beleuchteten",
+    "beleuchtung",
+    "belial",
+    "belieben",
+    "beliebig",
+    "beliebige",
+    "beliebigen",
+    "beliebt",
+    "beliebte",
+    "beliebten",
+    "beliefen",
+    "bellen",
+    "bellkommando",
+    "bellprobe",
+    "bellstrophe",
+    "bellte",
+    "belobt",
+    "belobte",
+    "belog",
+    "belohnen",
+    "belohnt",
+    "belohnung",
+    "belud",
+    "beluden",
+    "belustigen",
+    "belustigt",
+    "belustigten",
+    "belustigung",
+    "belästigen",
+    "belästigt",
+    "belästigte",
+    "belüftung",
+    "belügst",
+    "bemalt",
+    "bemalte",
+    "bemalten",
+    "bemaltes",
+    "bemassen",
+    "bemerke",
+    "bemerken",
+    "bemerklich",
+    "bemerkt",
+    "bemerkte",
+    "bemerkten",
+    "bemerkung",
+    "bemerkungen",
+    "bemesset",
+    "bemitleiden",
+    "bemoosten",
+    "bemächtigen",
+    "bemühen",
+    "bemühet",
+    "bemüht",
+    "bemühte",
+    "bemühung",
+    "bemühungen",
+    "benachbarte",
+    "benachbarten",
+    "benahm",
+    "benahmen",
+    "benannt",
+    "benannten",
+    "benediktinerpater",
+    "benediktus",
+    "benefizanlass",
+    "benehmen",
+    "benehmens",
+    "beneiden",
+    "beneidenswürdige",
+    "beneidet",
+    "beneidete",
+    "benennen",
+    "benennens",
+    "benennung",
+    "benennungen",
+    "benetzt",
+    "benetzte",
+    "benieselt",
+    "benimm",
+    "benimmt",
+    "benjamin",
+    "benkert",
+    "benommen",
+    "benutzen",
+    "benutzt",
+    "benutzte",
+    "benutzten",
+    "benötigen",
+    "benötigt",
+    "benötigte",
+    "benötigten",
+    "benützte",
+    "benützten",
+    "benützung",
+    "beobachte",
+    "beobachten",
+    "beobachtens",
+    "beobachter",
+    "beobachtet",
+    "beobachtete",
+    "beobachteten",
+    "beobachtung",
+    "beobachtungen",
+    "beordert",
+    "bepackt",
+    "bepflanzt",
+    "bequem",
+    "bequeme",
+    "bequemen",
+    "bequemer",
+    "bequemes",
+    "bequemliche",
+    "bequemlichkeit",
+    "bequemlichkeiten",
+    "bequemte",
+    "beranger",
+    "beraten",
+    "berater",
+    "beratschlagen",
+    "beratschlagt",
+    "beratung",
+    "beratungen",
+    "beratungsstelle",
+    "beratungsstellen",
+    "berauben",
+    "beraubt",
+    "beraubte",
+    "beraubten",
+    "beraubter",
+    "beraubung",
+    "berauscht",
+    "berchtesgaden",
+    "berchtesgadenerland",
+    "berechnet",
+    "berechnung",
+    "berechtigen",
+    "berechtigt",
+    "berechtigung",
+    "bereden",
+    "beredet",
+    "beredete",
+    "beredeten",
+    "beredsamkeit",
+    "beredt",
+    "beredten",
+    "beredteste",
+    "beredung",
+    "beredungen",
+    "beredungsglauben",
+    "beredungsglaubens",
+    "beredungswahrheit",
+    "bereffend",
+    "bereich",
+    "bereichern",
+    "bereichert",
+    "bereicherten",
+    "bereicherung",
+    "bereiften",
+    "bereit",
+    "bereiten",
+    "bereitet",
+    "bereitete",
+    "bereiteten",
+    "bereitlagen",
+    "bereitliege",
+    "bereits",
+    "bereitschaft",
+    "bereitstand",
+    "bereitstellen",
+    "bereitung",
+    "bereitwillig",
+    "bereitwilligkeit",
+    "bereue",
+    "bereust",
+    "bereut",
+    "bereute",
+    "berg",
+    "bergamsel",
+    "berge",
+    "bergen",
+    "bergend",
+    "berges",
+    "bergfeuer",
+    "bergfeuern",
+    "berggeist",
+    "berggipfel",
+    "berggipfels",
+    "berghalde",
+    "berghang",
+    "bergherz",
+    "berghöhlen",
+    "bergier",
+    "bergigen",
+    "bergkessel",
+    "bergkräuter",
+    "bergland",
+    "berglüften",
+    "bergmann",
+    "bergnase",
+    "bergnebel",
+    "bergraben",
+    "bergschatten",
+    "bergschenke",
+    "bergschlucht",
+    "bergschluchten",
+    "bergsteiger",
+    "bergstimmen",
+    "bergstock",
+    "bergstämmen",
+    "bergwald",
+    "bergwaldes",
+    "bergwand",
+    "bergwasser",
+    "bergweg",
+    "bergwege",
+    "bergwegen",
+    "bergwellen",
+    "bergwerk",
+    "bergwiesen",
+    "bergwind",
+    "bergzinne",
+    "berhren",
+    "bericht",
+    "berichte",
+    "berichten",
+    "berichtet",
+    "berichtete",
+    "berichtigung",
+    "beriechen",
+    "berief",
+    "berieselt",
+    "berieten",
+    "berlin",
+    "berliner",
+    "berlinfergus",
+    "bern",
+    "bernan",
+    "bernard",
+    "berner",
+    "bernischen",
+    "berns",
+    "bernstein",
+    "bernwas",
+    "berthold",
+    "beruf",
+    "berufen",
+    "berufenen",
+    "beruflichen",
+    "berufsabsichten",
+    "berufsgenossen",
+    "berufsmäßigen",
+    "berufsmäßiger",
+    "berufsschulen",
+    "berufssoldat",
+    "berufswunsch",
+    "berufung",
+    "beruhe",
+    "beruhen",
+    "beruhenden",
+    "beruhet",
+    "beruhigen",
+    "beruhigend",
+    "beruhigenden",
+    "beruhigt",
+    "beruhigte",
+    "beruhigten",
+    "beruhigung",
+    "beruhigungsmittel",
+    "beruht",
+    "beruhte",
+    "berusstem",
+    "berücken",
+    "berücksichtigen",
+    "berücksichtigt",
+    "berückt",
+    "berühmen",
+    "berühmt",
+    "berühmte",
+    "berühmten",
+    "berühmtesten",
+    "berühmtheit",
+    "berühre",
+    "berühren",
+    "berührende",
+    "berührt",
+    "berührte",
+    "berührten",
+    "berührung",
+    "besagt",
+    "besagte",
+    "besagtem",
+    "besagten",
+    "besagtes",
+    "besah",
+    "besahen",
+    "besamen",
+    "besamende",
+    "besamt",
+    "besann",
+    "besannen",
+    "besass",
+    "besassen",
+    "besaufen",
+    "besaß",
+    "besaßen",
+    "beschaffen",
+    "beschaffenen",
+    "beschaffenheit",
+    "beschattet",
+    "beschaulich",
+    "beschaulichen",
+    "beschaulichkeit",
+    "beschaust",
+    "beschaute",
+    "bescheid",
+    "bescheiden",
+    "bescheidend",
+    "bescheidenem",
+    "bescheidenen",
+    "bescheidener",
+    "bescheidenere",
+    "bescheidenes",
+    "bescheidenheit",
+    "bescheinen",
+    "beschenkt",
+    "bescherung",
+    "beschieden",
+    "beschien",
+    "beschienenen",
+    "beschimmelten",
+    "beschimpft",
+    "beschimpfte",
+    "beschimpfung",
+    "beschirmt",
+    "beschirmung",
+    "beschlag",
+    "beschlagnahmt",
+    "beschleunigen",
+    "beschleunigt",
+    "beschleunigte",
+    "beschleunigung",
+    "beschlich",
+    "beschliessen",
+    "beschloss",
+    "beschlossen",
+    "beschlossenen",
+    "beschloß",
+    "beschluss",
+    "beschmiert",
+    "beschmierte",
+    "beschneiden",
+    "beschneidung",
+    "beschneit",
+    "beschnitt",
+    "beschnitten",
+    "beschnüffelte",
+    "beschreiben",
+    "beschreibt",
+    "beschreibung",
+    "beschrieb",
+    "beschriebe",
+    "beschrieben",
+    "beschriebene",
+    "beschriebenen",
+    "beschritt",
+    "beschränken",
+    "beschränkt",
+    "beschränkte",
+    "beschränkteste",
+    "beschränktheit",
+    "beschuhte",
+    "beschuldigungen",
+    "beschweige",
+    "beschweigen",
+    "beschwer",
+    "beschwerden",
+    "beschweren",
+    "beschwerliche",
+    "beschwerlichkeiten",
+    "beschwert",
+    "beschwerte",
+    "beschwerung",
+    "beschwichtigen",
+    "beschwor",
+    "beschworen",
+    "beschwören",
+    "beschwörer",
+    "beschwörung",
+    "beschwörungen",
+    "beschädigen",
+    "beschädigt",
+    "beschädigte",
+    "beschädigten",
+    "beschäftige",
+    "beschäftigen",
+    "beschäftigt",
+    "beschäftigte",
+    "beschäftigten",
+    "beschäftigter",
+    "beschäftigung",
+    "beschämen",
+    "beschämt",
+    "beschämte",
+    "beschönigen",
+    "beschönigt",
+    "beschützen",
+    "beschützer",
+    "beschützern",
+    "beschützt",
+    "beseelt",
+    "beseelte",
+    "beseeltes",
+    "besehen",
+    "besehn",
+    "beseitigen",
+    "beseitigt",
+    "beseitigung",
+    "besen",
+    "besens",
+    "besenstiel",
+    "besessen",
+    "besessener",
+    "besetzen",
+    "besetzerkneipen",
+    "besetzerleben",
+    "besetzt",
+    "besetzten",
+    "besichtigen",
+    "besichtigung",
+    "besiegen",
+    "besiegt",
+    "besiegte",
+    "besinne",
+    "besinnen",
+    "besinnt",
+    "besinnung",
+    "besinnungslose",
+    "besitz",
+    "besitze",
+    "besitzen",
+    "besitzer",
+    "besitzerin",
+    "besitzes",
+    "besitzlustige",
+    "besitzstände",
+    "besitzt",
+    "besitztum",
+    "besitztume",
+    "besitztümer",
+    "besitzungen",
+    "besoldet",
+    "besondere",
+    "besonderem",
+    "besonderen",
+    "besonderer",
+    "besonderes",
+    "besonderheiten",
+    "besondern",
+    "besonders",
+    "besonnen",
+    "besonnener",
+    "besonnenheit",
+    "besonnten",
+    "besonnter",
+    "besorgen",
+    "besorgnis",
+    "besorgnisse",
+    "besorgt",
+    "besorgte",
+    "besorgten",
+    "besorgter",
+    "besorgtes",
+    "bespannt",
+    "bespanntes",
+    "besponnen",
+    "besprechen",
+    "besprechungen",
+    "besprengung",
+    "besprochen",
+    "besprochene",
+    "besser",
+    "bessere",
+    "besseren",
+    "besserer",
+    "besseres",
+    "bessern",
+    "bessers",
+    "bessert",
+    "besserte",
+    "besserung",
+    "besserungen",
+    "besserungswerk",
+    "bestand",
+    "bestanden",
+    "bestandstück",
+    "bestandstücks",
+    "bestandteile",
+    "bestandteilen",
+    "bestani",
+    "bestaunten",
+    "beste",
+    "besteche",
+    "bestechen",
+    "bestechende",
+    "bestechliche",
+    "bestechung",
+    "bestechungssumme",
+    "besteck",
+    "besteckt",
+    "bestehe",
+    "bestehen",
+    "bestehend",
+    "bestehende",
+    "bestehendes",
+    "besteht",
+    "besteigen",
+    "bestellen",
+    "bestellenhintergrundregelmässig",
+    "bestellt",
+    "bestellte",
+    "bestellung",
+    "bestellungen",
+    "bestem",
+    "besten",
+    "bestenfalls",
+    "bestens",
+    "bester",
+    "bestia",
+    "bestiae",
+    "besticht",
+    "bestickt",
+    "bestie",
+    "bestieg",
+    "bestiegen",
+    "bestien",
+    "bestiis",
+    "bestimmbar",
+    "bestimmbare",
+    "bestimmbaren",
+    "bestimmbares",
+    "bestimmbarkeit",
+    "bestimme",
+    "bestimmen",
+    "bestimmende",
+    "bestimmenden",
+    "bestimmt",
+    "bestimmte",
+    "bestimmten",
+    "bestimmter",
+    "bestimmtes",
+    "bestimmtesten",
+    "bestimmtheit",
+    "bestimmung",
+    "bestimmungen",
+    "bestimmungsgrund",
+    "bestimmungsgrunde",
+    "bestimmungsgrundes",
+    "bestimmungsgründe",
+    "bestimmungsgründen",
+    "bestirnte",
+    "bestmöglichst",
+    "bestochen",
+    "bestrafen",
+    "bestraft",
+    "bestrafung",
+    "bestrafungen",
+    "bestreben",
+    "bestrebt",
+    "bestrebung",
+    "bestrebungen",
+    "bestreift",
+    "bestreiten",
+    "bestreitet",
+    "bestreut",
+    "bestreuten",
+    "bestritt",
+    "bestände",
+    "beständig",
+    "beständiger",
+    "beständiges",
+    "bestärken",
+    "bestärkt",
+    "bestärkte",
+    "bestärkung",
+    "bestätigen",
+    "bestätigend",
+    "bestätigenden",
+    "bestätigt",
+    "bestätigte",
+    "bestätigten",
+    "bestätigung",
+    "bestätigungen",
+    "bestätigungsgründe",
+    "bestünde",
+    "bestürmte",
+    "bestürmten",
+    "bestürzt",
+    "bestürzten",
+    "besuch",
+    "besuche",
+    "besuchen",
+    "besucher",
+    "besucht",
+    "besuchte",
+    "besuchten",
+    "besudelt",
+    "besudelten",
+    "besäen",
+    "besäest",
+    "besäet",
+    "besänftigen",
+    "besänftigt",
+    "besänftigte",
+    "besänftigung",
+    "besässe",
+    "besät",
+    "betaste",
+    "betasten",
+    "betastete",
+    "betbüchl",
+    "beteiligt",
+    "beteiligten",
+    "beten",
+    "betend",
+    "betet",
+    "betete",
+    "beteten",
+    "beteuerte",
+    "beth",
+    "bethesda",
+    "betitelt",
+    "betont",
+    "betracht",
+    "betrachte",
+    "betrachten",
+    "betrachtend",
+    "betrachtet",
+    "betrachtete",
+    "betrachteten",
+    "betrachtung",
+    "betrachtungen",
+    "betraf",
+    "betragen",
+    "betragens",
+    "betrat",
+    "betraten",
+    "betraut",
+    "betrauten",
+    "betreff",
+    "betreffen",
+    "betreffend",
+    "betreffende",
+    "betreffenden",
+    "betreffs",
+    "betreiben",
+    "betreibt",
+    "betreibung",
+    "betresst",
+    "betreten",
+    "betreuen",
+    "betreßt",
+    "betrieb",
+    "betrieben",
+    "betrifft",
+    "betritt",
+    "betroffen",
+    "betrogen",
+    "betrogene",
+    "betrogenen",
+    "betrug",
+    "betrunken",
+    "betrunkenen",
+    "betrunkener",
+    "beträchtlich",
+    "beträchtliche",
+    "beträfe",
+    "beträgt",
+    "betrübe",
+    "betrüblich",
+    "betrübnis",
+    "betrübt",
+    "betrübte",
+    "betrübten",
+    "betrüge",
+    "betrügen",
+    "betrüger",
+    "betrügereien",
+    "betrügerisch",
+    "betrügerisches",
+    "betrüglich",
+    "betrügst",
+    "bett",
+    "bettchen",
+    "bette",
+    "bettelei",
+    "betteljunge",
+    "betteln",
+    "bettelte",
+    "betten",
+    "bettentruhe",
+    "bettes",
+    "betteten",
+    "bettlächrig",
+    "bettstatt",
+    "bettstelle",
+    "bettzeug",
+    "betupft",
+    "betätigt",
+    "betäuben",
+    "betäubt",
+    "betäubung",
+    "betörenden",
+    "betört",
+    "beugen",
+    "beugt",
+    "beugte",
+    "beugten",
+    "beule",
+    "beulen",
+    "beunruhigen",
+    "beunruhigende",
+    "beunruhigt",
+    "beunruhigung",
+    "beurkundet",
+    "beurkundete",
+    "beurlaube",
+    "beurlaubten",
+    "beurlaubung",
+    "beurteilen",
+    "beurteilenden",
+    "beurteilt",
+    "beurteilte",
+    "beurteilung",
+    "beurteilungen",
+    "beute",
+    "beutel",
+    "beutelchen",
+    "beuten",
+    "bevor",
+    "bevorhautet",
+    "bevorhautete",
+    "bevorstand",
+    "bevorstehenden",
+    "bevorsteht",
+    "bevölkert",
+    "bevölkerung",
+    "bewachsen",
+    "bewachsenen",
+    "bewaffnet",
+    "bewaffnete",
+    "bewaffneten",
+    "bewaffneter",
+    "bewahre",
+    "bewahren",
+    "bewahrt",
+    "bewahrte",
+    "bewahrtest",
+    "bewahrung",
+    "bewandt",
+    "bewandtnis",
+    "bewarben",
+    "bewege",
+    "bewegen",
+    "bewegende",
+    "bewegenden",
+    "beweggründe",
+    "beweglich",
+    "bewegliche",
+    "beweglichkeit",
+    "bewegt",
+    "bewegte",
+    "bewegten",
+    "bewegtes",
+    "bewegung",
+    "bewegungen",
+    "bewegungsgrund",
+    "bewegungsgrunde",
+    "bewegungsgründe",
+    "bewegungsgründen",
+    "bewegursache",
+    "bewegursachen",
+    "beweis",
+    "beweise",
+    "beweisen",
+    "beweisend",
+    "beweises",
+    "beweiset",
+    "beweisführende",
+    "beweisgründen",
+    "beweist",
+    "beweistümer",
+    "bewerben",
+    "bewerbung",
+    "bewerkstelligte",
+    "bewies",
+    "bewiesen",
+    "bewilligen",
+    "bewilligt",
+    "bewimpelt",
+    "bewirbt",
+    "bewirke",
+    "bewirken",
+    "bewirkt",
+    "bewirkte",
+    "bewirkten",
+    "bewirkung",
+    "bewirte",
+    "bewirtete",
+    "bewirtung",
+    "bewogen",
+    "bewohnen",
+    "bewohner",
+    "bewohnern",
+    "bewohners",
+    "bewohnt",
+    "bewohnte",
+    "bewohnten",
+    "bewundern",
+    "bewundernd",
+    "bewunderten",
+    "bewunderung",
+    "bewurf",
+    "bewusst",
+    "bewusste",
+    "bewussten",
+    "bewusster",
+    "bewusstlos",
+    "bewusstlosen",
+    "bewusstsein",
+    "bewusstseins",
+    "bewußt",
+    "bewußte",
+    "bewußten",
+    "bewußtsein",
+    "bewußtseins",
+    "bewähren",
+    "bewährt",
+    "bewährte",
+    "bewährter",
+    "bewährtes",
+    "bewältigen",
+    "bewässern",
+    "bewässert",
+    "bewässerte",
+    "bewässerten",
+    "bewässerter",
+    "bewölkten",
+    "bezahlbarer",
+    "bezahlen",
+    "bezahlt",
+    "bezahlte",
+    "bezahlung",
+    "bezaleel",
+    "bezankenden",
+    "bezaubern",
+    "bezeichne",
+    "bezeichnen",
+    "bezeichnend",
+    "bezeichnende",
+    "bezeichnendes",
+    "bezeichnet",
+    "bezeichnete",
+    "bezeichneten",
+    "bezeichnung",
+    "bezeichnungen",
+    "bezeigen",
+    "bezeigten",
+    "bezeugen",
+    "bezeugst",
+    "bezeugten",
+    "beziehe",
+    "beziehen",
+    "bezieht",
+    "beziehung",
+    "beziehungen",
+    "beziehungsweise",
+    "beziele",
+    "bezielt",
+    "bezirk",
+    "bezirke",
+    "bezirksrat",
+    "bezirksversammlung",
+    "bezog",
+    "bezogen",
+    "bezug",
+    "bezwang",
+    "bezwecken",
+    "bezweckt",
+    "bezweckte",
+    "bezweifeln",
+    "bezweifle",
+    "bezwingen",
+    "bezwingt",
+    "bezwingung",
+    "bezwungen",
+    "bezähmt",
+    "bezüglich",
+    "bezüglichen",
+    "beängstigt",
+    "beängstigung",
+    "bfu",
+    "bibberten",
+    "bibel",
+    "bibelan",
+    "bibelgesellschaft",
+    "biberach",
+    "bibliothek",
+    "bibliotheken",
+    "biblische",
+    "biedenkopf",
+    "biederen",
+    "biederer",
+    "biegen",
+    "biegung",
+    "biene",
+    "bienen",
+    "bienenwachs",
+    "bier",
+    "bieranstalt",
+    "bierkrug",
+    "bierkrügen",
+    "bierkutscher",
+    "biertrinken",
+    "biertrinker",
+    "biest",
+    "biestigen",
+    "bieten",
+    "bietend",
+    "bietet",
+    "bild",
+    "bildchen",
+    "bilde",
+    "bilden",
+    "bildende",
+    "bilder",
+    "bilderbücher",
+    "bilderbücherwiese",
+    "bilderchen",
+    "bildern",
+    "bildet",
+    "bildete",
+    "bildeten",
+    "bildner",
+    "bildsauber",
+    "bildsaubre",
+    "bildschöne",
+    "bildschönes",
+    "bildsäule",
+    "bildung",
+    "bildungen",
+    "bildungschancen",
+    "bildungsgut",
+    "bildzeitung",
+    "bileam",
+    "bileams",
+    "billige",
+    "billigen",
+    "billigenden",
+    "billiger",
+    "billigkeit",
+    "billigste",
+    "billigung",
+    "bimmelglöcklein",
+    "bin",
+    "binde",
+    "binden",
+    "bindet",
+    "bindfaden",
+    "binse",
+    "biographien",
+    "birgt",
+    "birke",
+    "birkenzweiglein",
+    "birnen",
+    "birnenstiel",
+    "bis",
+    "bischof",
+    "bischofs",
+    "bischofskonferenz",
+    "bischofswiesen",
+    "bischöfe",
+    "bisher",
+    "bisherige",
+    "bisherigen",
+    "bisheriges",
+    "bislang",
+    "biss",
+    "bisschen",
+    "bissel",
+    "bissen",
+    "bisserl",
+    "bissl",
+    "bist",
+    "bistum",
+    "bisweilen",
+    "bitt",
+    "bitte",
+    "bitten",
+    "bittend",
+    "bittenden",
+    "bitter",
+    "bittere",
+    "bitteren",
+    "bitterer",
+    "bitteres",
+    "bitterkalten",
+    "bitterkeit",
+    "bitterlich",
+    "bitterlicher",
+    "bittern",
+    "bitterste",
+    "bittersten",
+    "bittest",
+    "bittet",
+    "biwakspässe",
+    "biwakspäße",
+    "bißchen",
+    "blachfeld",
+    "blagen",
+    "blanke",
+    "blanken",
+    "blanker",
+    "blankes",
+    "blankgepanzerte",
+    "blase",
+    "blasebalg",
+    "blasebälgen",
+    "blasen",
+    "blasensteigen",
+    "blasiert",
+    "blasierten",
+    "blasinstrument",
+    "blasinstrumente",
+    "blass",
+    "blasse",
+    "blassen",
+    "blasser",
+    "blasses",
+    "blatt",
+    "blatte",
+    "blatteln",
+    "blatten",
+    "blattern",
+    "blatterngeschwüre",
+    "blattes",
+    "blau",
+    "blaue",
+    "blauem",
+    "blauen",
+    "blaues",
+    "blauesten",
+    "blaufärber",
+    "blaugefärbter",
+    "blaugrün",
+    "blausamt",
+    "blauseidenen",
+    "blauweissen",
+    "blaß",
+    "bleamerln",
+    "blech",
+    "blecherne",
+    "blechkerl",
+    "blechlöffeln",
+    "blechmusik",
+    "blechschachtel",
+    "blechschilde",
+    "blei",
+    "bleib",
+    "bleibe",
+    "bleiben",
+    "bleibend",
+    "bleibende",
+    "bleibenden",
+    "bleibender",
+    "bleibendes",
+    "bleibestätten",
+    "bleibet",
+    "bleibn",
+    "bleibst",
+    "bleibt",
+    "bleich",
+    "bleiche",
+    "bleichen",
+    "bleicher",
+    "bleihände",
+    "bleikugeln",
+    "bleischweren",
+    "bleistift",
+    "bleistifte",
+    "blendend",
+    "blendenden",
+    "blendendes",
+    "blendendweisse",
+    "blendet",
+    "blendlaterne",
+    "blendwerk",
+    "blessierter",
+    "blick",
+    "blicke",
+    "blicken",
+    "blickend",
+    "blickenden",
+    "blickes",
+    "blickt",
+    "blickte",
+    "blickten",
+    "blieb",
+    "bliebe",
+    "blieben",
+    "bliebn",
+    "bliem",
+    "blies",
+    "bliesen",
+    "blind",
+    "blinde",
+    "blinden",
+    "blinder",
+    "blindes",
+    "blindgeborenen",
+    "blindlings",
+    "blinkend",
+    "blinkenden",
+    "blinkten",
+    "blinzeln",
+    "blinzelnd",
+    "blinzelte",
+    "blitz",
+    "blitzblank",
+    "blitze",
+    "blitzeblank",
+    "blitzen",
+    "blitzend",
+    "blitzende",
+    "blitzenden",
+    "blitzender",
+    "blitzendes",
+    "blitzhexe",
+    "blitzschnell",
+    "blitzschnelle",
+    "blitzschnellen",
+    "blitzschrecken",
+    "blitzt",
+    "blitzten",
+    "blockiert",
+    "blocksberg",
+    "blond",
+    "blonden",
+    "blondes",
+    "blondhaar",
+    "bloss",
+    "blosse",
+    "blossem",
+    "blossen",
+    "blosser",
+    "blosses",
+    "blossfüssiger",
+    "blosslegung",
+    "blouse",
+    "blousengestalt",
+    "blousenmann",
+    "blousenmannes",
+    "bloß",
+    "bloße",
+    "bloßen",
+    "bloßer",
+    "bloßes",
+    "bloßfüßiger",
+    "bloßlegung",
+    "blubber",
+    "blubberquacks",
+    "bluet",
+    "blume",
+    "blumen",
+    "blumenbouquets",
+    "blumenduft",
+    "blumengeschmückte",
+    "blumenglas",
+    "blumenhaufen",
+    "blumenkränzen",
+    "blumenköpfe",
+    "blumenstrauss",
+    "blumenstrauß",
+    "blumentöpfe",
+    "blut",
+    "blutarmen",
+    "blutbräutigam",
+    "blutbräutigams",
+    "blutdürstige",
+    "blute",
+    "blutes",
+    "blutete",
+    "blutgericht",
+    "blutgeruch",
+    "blutgestank",
+    "blutgieriges",
+    "blutig",
+    "blutigel",
+    "blutigen",
+    "blutlose",
+    "blutmensch",
+    "blutrot",
+    "blutroten",
+    "blutschlangen",
+    "blutschulden",
+    "blutsfreundschaft",
+    "blutsfreundschaften",
+    "blutstropfen",
+    "blutsverwandten",
+    "blutumlauf",
+    "blutungen",
+    "blutwelle",
+    "blähet",
+    "blähte",
+    "bläschen",
+    "blässe",
+    "bläst",
+    "blättchen",
+    "blätter",
+    "blättern",
+    "blätterreste",
+    "blätterte",
+    "blätterten",
+    "bläulich",
+    "bläulichen",
+    "blöcke",
+    "blödigkeit",
+    "blödsinne",
+    "blödsinnig",
+    "blödsinnige",
+    "blökende",
+    "blökender",
+    "blösse",
+    "blöße",
+    "blühen",
+    "blühend",
+    "blühendem",
+    "blühenden",
+    "blühendes",
+    "blühendsten",
+    "blühn",
+    "blüht",
+    "blühte",
+    "blühten",
+    "blümchen",
+    "blüte",
+    "blüten",
+    "blütenknopsen",
+    "blütezeit",
+    "bm",
+    "bock",
+    "bocke",
+    "bockelt",
+    "bockes",
+    "bockreiter",
+    "bocksbeutel",
+    "bocksgemäcker",
+    "bockt",
+    "boden",
+    "bodenraum",
+    "bodens",
+    "boell",
+    "bog",
+    "bogen",
+    "bohnen",
+    "bohnenstangen",
+    "bohnenwäldchen",
+    "bohrt",
+    "bohrten",
+    "bolen",
+    "bollwerk",
+    "bologna",
+    "bologneser",
+    "bolzenburg",
+    "bombardierte",
+    "bomben",
+    "bombenschlag",
+    "bona",
+    "bonbonbüchse",
+    "bonbonknospen",
+    "bonbons",
+    "bonbonsträucher",
+    "bondevik",
+    "boni",
+    "bonum",
+    "bonus",
+    "boot",
+    "bootes",
+    "bordeaux",
+    "borden",
+    "borer",
+    "born",
+    "borste",
+    "boshafte",
+    "boshaftes",
+    "bosheit",
+    "bot",
+    "bote",
+    "boten",
+    "botschaft",
+    "botschafter",
+    "botschafters",
+    "botta",
+    "bouffonerie",
+    "boutique",
+    "boykottieren",
+    "brabanter",
+    "brach",
+    "brachen",
+    "brachlägen",
+    "bracht",
+    "brachte",
+    "brachten",
+    "bracken",
+    "braffs",
+    "brand",
+    "brande",
+    "branden",
+    "brandenburg",
+    "brandgefilde",
+    "brandmauern",
+    "brandopfer",
+    "brandopfern",
+    "brandroten",
+    "brandrotes",
+    "brandschnur",
+    "brandung",
+    "brannte",
+    "brannten",
+    "bratapfel",
+    "brate",
+    "braten",
+    "bratenfett",
+    "bratenspiess",
+    "bratenspieß",
+    "bratenspässen",
+    "bratspiess",
+    "bratwurst",
+    "brauch",
+    "brauchbar",
+    "brauchbaren",
+    "brauchbarer",
+    "brauche",
+    "brauchen",
+    "brauchet",
+    "brauchst",
+    "braucht",
+    "brauchte",
+    "brauchten",
+    "brauchtet",
+    "brauen",
+    "brauervieh",
+    "braun",
+    "braune",
+    "braunem",
+    "braunen",
+    "brauner",
+    "braunes",
+    "braungebranntem",
+    "braungoldene",
+    "braunkariertes",
+    "braunrotes",
+    "braunschweig",
+    "brausen",
+    "brauste",
+    "brausten",
+    "braut",
+    "brautbett",
+    "brautgeschenke",
+    "brautpaar",
+    "brautwesen",
+    "brav",
+    "brave",
+    "braven",
+    "braver",
+    "braves",
+    "bravo",
+    "breche",
+    "brechen",
+    "brechend",
+    "brechenden",
+    "brei",
+    "breies",
+    "breit",
+    "breite",
+    "breiten",
+    "breiter",
+    "breites",
+    "breitet",
+    "breitete",
+    "breiteten",
+    "breitgerissenen",
+    "breitgewordenen",
+    "breitkrempigen",
+    "breitschultrige",
+    "breitspurigkeit",
+    "bremner",
+    "brenne",
+    "brennen",
+    "brennend",
+    "brennendem",
+    "brennenden",
+    "brennendes",
+    "brennt",
+    "brenzelte",
+    "brenzlig",
+    "brett",
+    "brette",
+    "bretter",
+    "bretternägel",
+    "bricht",
+    "bridgefreundinnen",
+    "briefe",
+    "briefes",
+    "brieflich",
+    "briefstelle",
+    "briefsteller",
+    "brieftasche",
+    "briet",
+    "brille",
+    "bringe",
+    "bringen",
+    "bringenden",
+    "bringet",
+    "bringt",
+    "brite",
+    "briten",
+    "britische",
+    "britischen",
+    "brits",
+    "brochen",
+    "brocken",
+    "brokatene",
+    "broldin",
+    "brosamen",
+    "broschek",
+    "broscheks",
+    "broschüre",
+    "brot",
+    "brotbissen",
+    "brote",
+    "broten",
+    "brotes",
+    "brotessen",
+    "brotlaib",
+    "brotrinde",
+    "brots",
+    "brotteller",
+    "brrrrrrrr",
+    "brsg",
+    "bruch",
+    "bruchstückweise",
+    "bruchus",
+    "bruchwand",
+    "brudder",
+    "bruder",
+    "bruders",
+    "bruderschaft",
+    "bruderschaften",
+    "brumm",
+    "brummbass",
+    "brummbassgezeter",
+    "brummbaßgezeter",
+    "brummbässe",
+    "brummen",
+    "brummend",
+    "brummenden",
+    "brummkonzert",
+    "brummt",
+    "brummte",
+    "brunnen",
+    "brunnenbank",
+    "brunnens",
+    "brunnenschwengel",
+    "brunnenschwengeln",
+    "brunnenstrahl",
+    "brust",
+    "brustbildern",
+    "brustfleck",
+    "brustgewand",
+    "brustkorb",
+    "brustlatz",
+    "brustschild",
+    "brustschildlein",
+    "brusttasche",
+    "brustwehr",
+    "brächt",
+    "brächte",
+    "brächten",
+    "brände",
+    "bräunliche",
+    "bräunlichen",
+    "bräutigam",
+    "bräutigams",
+    "bräutlein",
+    "bröckeln",
+    "bröckelt",
+    "brötchen",
+    "brüche",
+    "brücke",
+    "brückenbauer",
+    "brüder",
+    "brüderlich",
+    "brüdern",
+    "brüderschaft",
+    "brühe",
+    "brüllen",
+    "brüllend",
+    "brüller",
+    "brüllet",
+    "brüllte",
+    "brünstig",
+    "brünstigem",
+    "brüssel",
+    "brüste",
+    "brüstung",
+    "brütend",
+    "brüteten",
+    "brütige",
+    "bschlachter",
+    "bscht",
+    "bsorgen",
+    "bsucht",
+    "bua",
+    "bub",
+    "buben",
+    "bubenmädchen",
+    "buch",
+    "buchbinder",
+    "buchbindergesellen",
+    "buchbinders",
+    "buche",
+    "buchen",
+    "buchenast",
+    "buchenholz",
+    "buchenknorren",
+    "buchenlaub",
+    "buchenwipfel",
+    "buchenwäldchen",
+    "buches",
+    "buchhalter",
+    "buchhandlungen",
+    "buchstabe",
+    "buchstaben",
+    "buchstabens",
+    "buchstabensinn",
+    "buchstabensinne",
+    "buchstabensinns",
+    "buchstäbliche",
+    "buchstäblichen",
+    "buchungsbelegen",
+    "buckel",
+    "bucklige",
+    "buckligen",
+    "buckliger",
+    "buckliges",
+    "buckskin",
+    "buden",
+    "budgetiert",
+    "buhlerisches",
+    "buhlerlohn",
+    "buhleten",
+    "bujv",
+    "bulle",
+    "bullern",
+    "bullerte",
+    "bum",
+    "bums",
+    "bund",
+    "bunde",
+    "bundes",
+    "bundesebene",
+    "bundesgenossen",
+    "bundesgetz",
+    "bundeslade",
+    "bundesland",
+    "bundeslandes",
+    "bundesleben",
+    "bundespräsident",
+    "bundesrat",
+    "bundesräte",
+    "bundessache",
+    "bundesverwaltung",
+    "bundeswehr",
+    "bunt",
+    "buntbemalte",
+    "bunte",
+    "buntem",
+    "bunten",
+    "bunter",
+    "buntes",
+    "buntesten",
+    "buntester",
+    "buntscheckigen",
+    "buomberger",
+    "bur",
+    "bureau",
+    "bureaus",
+    "burg",
+    "burgen",
+    "burgfenster",
+    "burghofe",
+    "burgtor",
+    "burnus",
+    "burnusträgern",
+    "bursch",
+    "bursche",
+    "burschen",
+    "burschikosen",
+    "busch",
+    "buschigem",
+    "buschigen",
+    "buschwerk",
+    "busen",
+    "busens",
+    "busse",
+    "butter",
+    "butterblume",
+    "butterbrot",
+    "butterfett",
+    "buttertopf",
+    "buße",
+    "byssus",
+    "bz",
+    "bzw",
+    "bäche",
+    "bächen",
+    "bächlein",
+    "bäckchen",
+    "bäcker",
+    "bändchen",
+    "bände",
+    "bänder",
+    "bändern",
+    "bändigen",
+    "bändigung",
+    "bänglich",
+    "bänken",
+    "bänklein",
+    "bär",
+    "bären",
+    "bärenfell",
+    "bärenschinken",
+    "bärenstall",
+    "bärentanz",
+    "bäschen",
+    "bäuchen",
+    "bäuchlein",
+    "bäuerin",
+    "bäuerinnen",
+    "bäuerlein",
+    "bäuerliche",
+    "bäuerlichen",
+    "bäuerlicher",
+    "bäumchen",
+    "bäume",
+    "bäumen",
+    "bäurin",
+    "böckchen",
+    "böcke",
+    "böcken",
+    "böhmerwald",
+    "böhni",
+    "böll",
+    "böllerschüsse",
+    "börse",
+    "bös",
+    "bösartig",
+    "bösartiger",
+    "bösartigkeit",
+    "böschung",
+    "böse",
+    "bösem",
+    "bösen",
+    "böser",
+    "böses",
+    "bösestun",
+    "bösewicht",
+    "bösewichte",
+    "bössein",
+    "böswillige",
+    "böswilliges",
+    "böswilligkeit",
+    "böttcher",
+    "bübchen",
+    "bübisch",
+    "büchelchen",
+    "bücher",
+    "büchereien",
+    "büchern",
+    "bücherschränken",
+    "büchlein",
+    "büchschen",
+    "büchse",
+    "büchsen",
+    "büchsenlicht",
+    "büchsenmacher",
+    "büchsenmacherei",
+    "büchsenschmied",
+    "büchsenschuss",
+    "büchsenstein",
+    "büchsflinte",
+    "bücke",
+    "bücken",
+    "bückt",
+    "bückte",
+    "büfettier",
+    "büffel",
+    "büffet",
+    "bügeleisen",
+    "bügelgestemmte",
+    "bügelte",
+    "bühnenmanagerin",
+    "bünde",
+    "bündel",
+    "bündelchen",
+    "bündelein",
+    "bündeln",
+    "bündelturme",
+    "bündelweise",
+    "bündner",
+    "bündnis",
+    "bündnisse",
+    "bündnissen",
+    "bünger",
+    "bünzli",
+    "bünzlin",
+    "bürger",
+    "bürgerhut",
+    "bürgerinnen",
+    "bürgerkriegen",
+    "bürgerlich",
+    "bürgerliche",
+    "bürgerlichem",
+    "bürgerlichen",
+    "bürgermeister",
+    "bürgern",
+    "bürgernest",
+    "bürgerrock",
+    "bürgerschaft",
+    "bürgersinn",
+    "bürgertugend",
+    "bürgschaft",
+    "büro",
+    "bürokratischer",
+    "büroräume",
+    "büros",
+    "bürozeit",
+    "bürschchen",
+    "bürschlein",
+    "bürstchen",
+    "bürste",
+    "bürstete",
+    "bürstner",
+    "bürstners",
+    "büsche",
+    "büschel",
+    "büschen",
+    "büste",
+    "bütte",
+    "ca",
+    "cabaret",
+    "cadens",
+    "café",
+    "camelia",
+    "canaille",
+    "canailles",
+    "canoro",
+    "capua",
+    "caritas",
+    "carl",
+    "carriere",
+    "castor",
+    "catigo",
+    "causa",
+    "causam",
+    "causas",
+    "cd",
+    "cdu",
+    "cenis",
+    "ch",
+    "chaldäer",
+    "chaldäern",
+    "cham",
+    "chambery",
+    "champagner",
+    "champagnerflasche",
+    "champagnerkelch",
+    "chancen",
+    "channa",
+    "chanoch",
+    "chanochs",
+    "chaos",
+    "chaotischen",
+    "charakter",
+    "charakterisieren",
+    "charakteristik",
+    "charakteristisch",
+    "charakteristische",
+    "charakters",
+    "charakterzug",
+    "charitas",
+    "chateau",
+    "chaussee",
+    "chavah",
+    "chavillah",
+    "chazors",
+    "che",
+    "chebron",
+    "checque",
+    "cheese",
+    "chef",
+    "cheflektor",
+    "chemie",
+    "chemikalien",
+    "chemist",
+    "chen",
+    "cheres",
+    "cherub",
+    "cherube",
+    "cheruben",
+    "cherubim",
+    "cherubs",
+    "chethiterin",
+    "chezron",
+    "chiddekel",
+    "chigen",
+    "chimärische",
+    "china",
+    "chinesischen",
+    "chinesischer",
+    "chips",
+    "chirurgen",
+    "chirurgiegehilfen",
+    "chirurgische",
+    "choke",
+    "choleriker",
+    "chomer",
+    "chomers",
+    "chp",
+    "christ",
+    "christa",
+    "christdemokratischen",
+    "christen",
+    "christenglauben",
+    "christenheit",
+    "christentum",
+    "christentums",
+    "christi",
+    "christinnen",
+    "christkindchen",
+    "christkindchens",
+    "christl",
+    "christlich",
+    "christliche",
+    "christlichen",
+    "christlicher",
+    "christlicherseits",
+    "christls",
+    "christo",
+    "christoph",
+    "christopher",
+    "christus",
+    "chronik",
+    "chronischen",
+    "chrysopras",
+    "chöre",
+    "chören",
+    "cicero",
+    "citi",
+    "claire",
+    "claude",
+    "co",
+    "coeleste",
+    "coelesti",
+    "coelestis",
+    "cogitare",
+    "coles",
+    "college",
+    "colores",
+    "comedian",
+    "committee",
+    "communia",
+    "communique",
+    "comparationis",
+    "computern",
+    "computertomographie",
+    "concreto",
+    "condito",
+    "conference",
+    "conjugio",
+    "consatant",
+    "consummatum",
+    "continens",
+    "contraria",
+    "contrarias",
+    "copulatus",
+    "corpora",
+    "corporis",
+    "cosmic",
+    "costum",
+    "cotti",
+    "couch",
+    "council",
+    "couplet",
+    "cream",
+    "credos",
+    "crescenz",
+    "crossair",
+    "csp",
+    "cultores",
+    "cum",
+    "customer",
+    "cutta",
+    "cylinderhut",
+    "cyperwein",
+    "d",
+    "da",
+    "dabei",
+    "dabeistand",
+    "dabeizusein",
+    "dableiben",
+    "dach",
+    "dachboden",
+    "dache",
+    "daches",
+    "dachorganisation",
+    "dachrinne",
+    "dachs",
+    "dachse",
+    "dachsfell",
+    "dachsfellen",
+    "dachte",
+    "dachten",
+    "dachtraufe",
+    "dachtürmchen",
+    "dackel",
+    "dackeln",
+    "dackels",
+    "dackelzüchterei",
+    "dadrauf",
+    "dadurch",
+    "dafür",
+    "dag",
+    "dageblieben",
+    "dagegen",
+    "dagesessen",
+    "dagestanden",
+    "dagewesen",
+    "daheim",
+    "daher",
+    "dahergefahren",
+    "dahergerannt",
+    "dahergeschlenkert",
+    "daherging",
+    "daherkam",
+    "daherkommenden",
+    "daherkommt",
+    "daherspringen",
+    "dahi",
+    "dahin",
+    "dahinfuhr",
+    "dahinfährt",
+    "dahingerafft",
+    "dahingezogen",
+    "dahingingen",
+    "dahinschritt",
+    "dahinter",
+    "dahinterkomme",
+    "dahintersteckte",
+    "dahinzog",
+    "dalag",
+    "daliegen",
+    "daliegende",
+    "daliegenden",
+    "daliessest",
+    "damal",
+    "damalige",
+    "damaligen",
+    "damaliger",
+    "damals",
+    "damaskus",
+    "damc",
+    "dame",
+    "damen",
+    "damenbesuch",
+    "damenuhr",
+    "damenwelt",
+    "damit",
+    "dampfenden",
+    "dampfstrahl",
+    "danach",
+    "daneben",
+    "danggen",
+    "dank",
+    "dankbar",
+    "dankbare",
+    "dankbarer",
+    "dankbares",
+    "dankbarkeit",
+    "dankbarst",
+    "danke",
+    "danken",
+    "dankes",
+    "danket",
+    "dankte",
+    "dann",
+    "dannen",
+    "dantine",
+    "dar",
+    "daran",
+    "darangehen",
+    "darangesetzt",
+    "darauf",
+    "darauffolgenden",
+    "daraufstellen",
+    "daraus",
+    "darben",
+    "darbieten",
+    "darbietende",
+    "darbietendes",
+    "darbietet",
+    "darbrachte",
+    "darbrachten",
+    "darbringen",
+    "darbringt",
+    "darbringung",
+    "darein",
+    "darf",
+    "darfst",
+    "dargebracht",
+    "dargelegt",
+    "dargereicht",
+    "dargestellt",
+    "dargetan",
+    "darin",
+    "darinnen",
+    "darlegen",
+    "darlegt",
+    "darlehen",
+    "darling",
+    "darnach",
+    "darnieder",
+    "darniederliegen",
+    "darreichen",
+    "darreichte",
+    "darstelle",
+    "darstellen",
+    "darstellenden",
+    "darstelleten",
+    "darstellt",
+    "darstellte",
+    "darstellten",
+    "darstellung",
+    "darstellungsbild",
+    "dartun",
+    "dartust",
+    "darum",
+    "darunter",
+    "darzustellen",
+    "darüber",
+    "darüberhinaus",
+    "das",
+    "dasass",
+    "dasassen",
+    "dasaßen",
+    "daschiesst",
+    "daseiende",
+    "dasein",
+    "daseins",
+    "daselbst",
+    "dasige",
+    "dasitzen",
+    "dasitzenden",
+    "dasjenige",
+    "dasmal",
+    "dass",
+    "dasselbe",
+    "dastand",
+    "dastehen",
+    "dasteht",
+    "datis",
+    "dativ",
+    "dative",
+    "dativen",
+    "dato",
+    "datum",
+    "dauer",
+    "dauerhaft",
+    "dauerhafte",
+    "dauerhaftem",
+    "dauerhaften",
+    "dauerhaftigkeit",
+    "dauern",
+    "dauernd",
+    "dauernde",
+    "dauernden",
+    "dauert",
+    "dauerte",
+    "dauerten",
+    "daumen",
+    "daumschrauben",
+    "davids",
+    "davon",
+    "davonfuhr",
+    "davongangen",
+    "davongegangen",
+    "davonging",
+    "davonkäme",
+    "davonlief",
+    "davonrannte",
+    "davonzureisen",
+    "davonzutragen",
+    "davor",
+    "dawider",
+    "dazu",
+    "dazua",
+    "dazubleiben",
+    "dazumal",
+    "dazustehen",
+    "dazutun",
+    "dazwischen",
+    "dazwischenliegender",
+    "daß",
+    "ddr",
+    "de",
+    "deandl",
+    "deanerl",
+    "debatte",
+    "debatten",
+    "deberjackl",
+    "december",
+    "deckbett",
+    "deckchen",
+    "decke",
+    "deckel",
+    "decken",
+    "deckte",
+    "deckten",
+    "deckung",
+    "deduktion",
+    "deduzieren",
+    "defekt",
+    "definieren",
+    "definiert",
+    "definitum",
+    "degen",
+    "degengehänge",
+    "degradieren",
+    "dehne",
+    "dehnt",
+    "dehnte",
+    "dehnten",
+    "dei",
+    "deiche",
+    "dein",
+    "deine",
+    "deinem",
+    "deinen",
+    "deiner",
+    "deines",
+    "deinetwillen",
+    "deinigen",
+    "dekalog",
+    "dekalogs",
+    "dekalogus",
+    "deklinierbuch",
+    "delegationen",
+    "delegieren",
+    "delegiertenversammlungen",
+    "delicatessen",
+    "delikaten",
+    "delikatesse",
+    "delikt",
+    "delphin",
+    "dem",
+    "demgemäss",
+    "demgemäß",
+    "demjenigen",
+    "demnach",
+    "demokratische",
+    "demokrit",
+    "demoliert",
+    "demolierten",
+    "demselben",
+    "demselbigen",
+    "demut",
+    "demutshaltung",
+    "demutvollen",
+    "demzufolge",
+    "demütig",
+    "demütige",
+    "demütigen",
+    "demütigend",
+    "demütiger",
+    "demütigt",
+    "demütigte",
+    "demütigung",
+    "den",
+    "dene",
+    "denen",
+    "denjenigen",
+    "denk",
+    "denkbar",
+    "denkbaren",
+    "denkbarer",
+    "denke",
+    "denken",
+    "denkende",
+    "denkens",
+    "denker",
+    "denket",
+    "denkmal",
+    "denkst",
+    "denkt",
+    "denkungsart",
+    "denkvorstellung",
+    "denkvorstellungen",
+    "denkweise",
+    "denn",
+    "dennoch",
+    "denselben",
+    "dep",
+    "departements",
+    "departementschef",
+    "deperditum",
+    "depeschen",
+    "deponierten",
+    "depot",
+    "depressionen",
+    "der",
+    "derart",
+    "derartig",
+    "derartige",
+    "derartigen",
+    "derartiger",
+    "derartiges",
+    "derbarmt",
+    "derbe",
+    "derben",
+    "derbknochige",
+    "derbsten",
+    "dereinst",
+    "deren",
+    "derentweg",
+    "derentwillen",
+    "derer",
+    "dergestalt",
+    "dergibt",
+    "dergl",
+    "dergleichen",
+    "derheim",
+    "derjenige",
+    "derjenigen",
+    "derleb",
+    "derlebt",
+    "derlei",
+    "derleiden",
+    "dermassen",
+    "dern",
+    "dero",
+    "derschlagen",
+    "derselbe",
+    "derselben",
+    "derwart",
+    "derweil",
+    "derwischt",
+    "des",
+    "desdemona",
+    "deserteur",
+    "deserteure",
+    "desertion",
+    "desgleichen",
+    "deshalb",
+    "deshalben",
+    "designer",
+    "desinteressiert",
+    "desjenigen",
+    "desna",
+    "desolatio",
+    "desolationes",
+    "desselben",
+    "dessen",
+    "dessentwegen",
+    "dessentwillen",
+    "dessert",
+    "desto",
+    "deswegen",
+    "deswillen",
+    "detailliert",
+    "determinatum",
+    "detonation",
+    "deuchte",
+    "deuschland",
+    "deut",
+    "deutbare",
+    "deuten",
+    "deutete",
+    "deutlich",
+    "deutliche",
+    "deutlichen",
+    "deutlicher",
+    "deutlichere",
+    "deutliches",
+    "deutlichkeit",
+    "deutlichste",
+    "deutsch",
+    "deutsche",
+    "deutschen",
+    "deutscher",
+    "deutschland",
+    "deutschlands",
+    "deutschlehrerin",
+    "deutschschweiz",
+    "deutschsein",
+    "deutseher",
+    "deza",
+    "dezember",
+    "dezenz",
+    "dgl",
+    "di",
+    "diabolischen",
+    "diademe",
+    "diademen",
+    "diakonie",
+    "diakoniekapitel",
+    "dialectica",
+    "dialekt",
+    "dialektik",
+    "dialektikh",
+    "dialektisch",
+    "dialektische",
+    "dialektischen",
+    "dialektliedern",
+    "dialogorientierung",
+    "dialogs",
+    "diamant",
+    "diamanten",
+    "diamantenschimmer",
+    "dich",
+    "dicht",
+    "dichte",
+    "dichtem",
+    "dichten",
+    "dichter",
+    "dichtes",
+    "dichteste",
+    "dichtesten",
+    "dichtester",
+    "dichtgeschlossenen",
+    "dichtheit",
+    "dick",
+    "dickbauch",
+    "dicke",
+    "dickem",
+    "dicken",
+    "dicker",
+    "dickeren",
+    "dickicht",
+    "dickichts",
+    "dickleibig",
+    "dicks",
+    "dicksten",
+    "dickung",
+    "dictamen",
+    "dictavit",
+    "didac",
+    "dideldudeldei",
+    "dideldumdei",
+    "die",
+    "dieb",
+    "diebe",
+    "diebin",
+    "diebshehler",
+    "diebstahl",
+    "diefer",
+    "diejenige",
+    "diejenigen",
+    "diele",
+    "diemal",
+    "diene",
+    "dienen",
+    "dienend",
+    "diener",
+    "dienern",
+    "dienerschaft",
+    "dienerten",
+    "dienet",
+    "dienete",
+    "dienlich",
+    "dienst",
+    "dienstag",
+    "dienstagmorgen",
+    "dienstbar",
+    "dienstbarer",
+    "dienstbarkeit",
+    "dienstbarkeiten",
+    "dienstboten",
+    "dienstbuben",
+    "dienste",
+    "diensten",
+    "dienstes",
+    "dienstfertig",
+    "dienstleistung",
+    "dienstleistungen",
+    "dienstliche",
+    "dienstmädchen",
+    "dienstmägde",
+    "dienstmägden",
+    "dienstmänner",
+    "dienstrevier",
+    "dienstverkehr",
+    "dienstvorschriften",
+    "dienstzeit",
+    "dient",
+    "diente",
+    "dienten",
+    "dies",
+    "diesbezüglich",
+    "diese",
+    "dieselbe",
+    "dieselben",
+    "diesem",
+    "diesen",
+    "diesenging",
+    "dieser",
+    "diesergestalt",
+    "dieserhalb",
+    "dieses",
+    "diesjährigen",
+    "diesmal",
+    "diesseitigen",
+    "dietegen",
+    "dietrich",
+    "dieweil",
+    "differenz",
+    "differenzen",
+    "differenzierter",
+    "differieren",
+    "dimension",
+    "dimensionen",
+    "ding",
+    "dinge",
+    "dingen",
+    "dinger",
+    "dinges",
+    "dinkel",
+    "dinkels",
+    "dinte",
+    "diog",
+    "diogenes",
+    "diplom",
+    "dir",
+    "diras",
+    "direkt",
+    "direkten",
+    "direktion",
+    "direktionszimmer",
+    "direktor",
+    "direktorium",
+    "direktors",
+    "dirn",
+    "dirnchen",
+    "dirndl",
+    "dirne",
+    "diskursiv",
+    "diskussion",
+    "diskussionen",
+    "diskutieren",
+    "diskutierend",
+    "diskutiert",
+    "disneyland",
+    "disposition",
+    "disputation",
+    "disputieren",
+    "disputierende",
+    "disputierens",
+    "disputiert",
+    "distel",
+    "disteln",
+    "distelstauden",
+    "distinktionen",
+    "disziplin",
+    "disziplinarverfahren",
+    "dithyrambische",
+    "divergieren",
+    "diversa",
+    "diverse",
+    "dntt",
+    "docet",
+    "doch",
+    "docht",
+    "doctrina",
+    "doctrinalia",
+    "dogder",
+    "dogmata",
+    "doktor",
+    "dokumentieren",
+    "dolmetscherin",
+    "dolmusfahrer",
+    "domes",
+    "domini",
+    "dominicus",
+    "dominierten",
+    "dominikus",
+    "domplatz",
+    "domui",
+    "domus",
+    "don",
+    "donner",
+    "donnerbrummen",
+    "donnergetümmel",
+    "donnermann",
+    "donnern",
+    "donnernd",
+    "donnerndes",
+    "donnerriese",
+    "donnerriesen",
+    "donners",
+    "donnerschlag",
+    "donnerstag",
+    "donnerstimmen",
+    "donnerte",
+    "donnerwetter",
+    "doppelbüchse",
+    "doppelglas",
+    "doppelgänger",
+    "doppelkinns",
+    "doppelt",
+    "doppelte",
+    "doppelten",
+    "doppelter",
+    "dorf",
+    "dorfe",
+    "dorfes",
+    "dorfgenossen",
+    "dorfhange",
+    "dorfkretscham",
+    "dorfköter",
+    "dorfschaften",
+    "dorfschulmeistern",
+    "dorfschönen",
+    "dorfschützer",
+    "dorfschützern",
+    "dorfspaziergängen",
+    "dorfvorsteher",
+    "dorfvorstehers",
+    "dorn",
+    "dornbusch",
+    "dornbusche",
+    "dornbusches",
+    "dornbuschs",
+    "dornen",
+    "dornengesträuch",
+    "dornenrüstung",
+    "dornsträucher",
+    "dort",
+    "dorthin",
+    "dortige",
+    "dortigen",
+    "dortiger",
+    "dose",
+    "doxa",
+    "dozentin",
+    "dr",
+    "drache",
+    "drachen",
+    "drachengift",
+    "drachenknopf",
+    "drachenschlange",
+    "draht",
+    "drahtig",
+    "dramatischen",
+    "dran",
+    "drang",
+    "drange",
+    "drangegeben",
+    "drangen",
+    "drangsal",
+    "drapierte",
+    "drauf",
+    "draufging",
+    "draus",
+    "draussen",
+    "draußen",
+    "dreck",
+    "drehen",
+    "drehend",
+    "drehscheibe",
+    "dreht",
+    "drehte",
+    "drehten",
+    "drei",
+    "dreiangel",
+    "dreibeinigen",
+    "dreieck",
+    "dreiecke",
+    "dreieckes",
+    "dreieckiger",
+    "dreieinige",
+    "dreieinigkeit",
+    "dreien",
+    "dreier",
+    "dreifach",
+    "dreifache",
+    "dreifachen",
+    "dreihundert",
+    "dreihundertfünfzig",
+    "dreihundertfünfzigtausend",
+    "dreimal",
+    "drein",
+    "dreingeb",
+    "dreinsah",
+    "dreinschicken",
+    "dreiröhrenhut",
+    "dreissig",
+    "dreissiger",
+    "dreissigster",
+    "dreist",
+    "dreiundachtzig",
+    "dreiundzwanzigstem",
+    "dreizackigen",
+    "dreizehn",
+    "dreißig",
+    "dreißigster",
+    "dreißiig",
+    "dreschen",
+    "dresden",
+    "drewes",
+    "drfe",
+    "drin",
+    "dringen",
+    "dringend",
+    "dringende",
+    "dringendes",
+    "dringendste",
+    "dringlich",
+    "dringt",
+    "drinks",
+    "drinnen",
+    "drisch",
+    "dritt",
+    "dritte",
+    "drittehalb",
+    "drittel",
+    "drittem",
+    "dritten",
+    "drittenmal",
+    "drittens",
+    "dritter",
+    "drittes",
+    "dritthalb",
+    "droben",
+    "drogenpolitik",
+    "drohen",
+    "drohend",
+    "drohende",
+    "drohenden",
+    "drohendes",
+    "drohete",
+    "droht",
+    "drohte",
+    "drohten",
+    "drohungen",
+    "drollig",
+    "drollige",
+    "drolligen",
+    "drolliges",
+    "dromedare",
+    "druck",
+    "drucke",
+    "druckes",
+    "drucks",
+    "drucksorten",
+    "druckt",
+    "drum",
+    "drunt",
+    "drunten",
+    "drängen",
+    "drängt",
+    "drängte",
+    "drängten",
+    "dröhnend",
+    "dröhnte",
+    "drüben",
+    "drüber",
+    "drücken",
+    "drückend",
+    "drückenden",
+    "drückte",
+    "drückten",
+    "drüppelei",
+    "drüppeln",
+    "drüppelü",
+    "drüsen",
+    "du",
+    "ducken",
+    "duckmäuser",
+    "duckt",
+    "duckte",
+    "duckten",
+    "dudlerei",
+    "duell",
+    "duft",
+    "duftende",
+    "duftenden",
+    "duftet",
+    "duftete",
+    "duftgewebe",
+    "duftig",
+    "duftwolken",
+    "duht",
+    "dukaten",
+    "dulden",
+    "dulderin",
+    "duldete",
+    "duldeten",
+    "duldsamkeit",
+    "duldung",
+    "dumm",
+    "dumme",
+    "dummen",
+    "dummer",
+    "dummes",
+    "dummglotzenden",
+    "dummheit",
+    "dummheiten",
+    "dummkopf",
+    "dummköpfe",
+    "dummköpfen",
+    "dumms",
+    "dumpf",
+    "dumpfem",
+    "dumpfen",
+    "dumpfhallend",
+    "dunggestank",
+    "dunkel",
+    "dunkelblau",
+    "dunkelblauen",
+    "dunkelbraunen",
+    "dunkele",
+    "dunkelgrauen",
+    "dunkelgrüne",
+    "dunkelgrünen",
+    "dunkelhaariges",
+    "dunkelheit",
+    "dunkelheiten",
+    "dunkeln",
+    "dunkelrot",
+    "dunkelrotem",
+    "dunkelrotes",
+    "dunkels",
+    "dunkeltal",
+    "dunkelte",
+    "dunkle",
+    "dunklem",
+    "dunklen",
+    "dunkler",
+    "dunkleren",
+    "dunkleres",
+    "dunkles",
+    "dunst",
+    "dunstwölkchen",
+    "durch",
+    "durcharbeiten",
+    "durchaus",
+    "durchblicken",
+    "durchblättern",
+    "durchblätterte",
+    "durchbohrt",
+    "durchbohrte",
+    "durchbohrten",
+    "durchbohrter",
+    "durchbohrung",
+    "durchbrechen",
+    "durchbrochen",
+    "durchbrochenem",
+    "durchbruch",
+    "durchdacht",
+    "durchdrang",
+    "durchdringen",
+    "durchdrungen",
+    "durchduftete",
+    "durcheinander",
+    "durcheinanderhingen",
+    "durcheinanderliefen",
+    "durcheinanderwimmelnden",
+    "durcheinanderwirbelte",
+    "durchfallen",
+    "durchfliessen",
+    "durchfließen",
+    "durchforscht",
+    "durchfuhr",
+    "durchfuhren",
+    "durchführte",
+    "durchführung",
+    "durchgackerten",
+    "durchgang",
+    "durchgangsasyl",
+    "durchgeführt",
+    "durchgegeben",
+    "durchgehechelt",
+    "durchgehen",
+    "durchgeht",
+    "durchgekaute",
+    "durchgekämpft",
+    "durchgelassen",
+    "durchgelaufen",
+    "durchgereist",
+    "durchgeschleppt",
+    "durchgreifende",
+    "durchgängig",
+    "durchgängigen",
+    "durchkommen",
+    "durchleben",
+    "durchlief",
+    "durchliess",
+    "durchläuft",
+    "durchlöchern",
+    "durchmass",
+    "durchmustern",
+    "durchmusterte",
+    "durchquerte",
+    "durchs",
+    "durchschauerte",
+    "durchschaute",
+    "durchschauten",
+    "durchscheinen",
+    "durchscheinend",
+    "durchscheinenden",
+    "durchschimmern",
+    "durchschimmerte",
+    "durchschlagen",
+    "durchschneiden",
+    "durchschnittenen",
+    "durchschnittsleben",
+    "durchschritten",
+    "durchschwemmt",
+    "durchsetzen",
+    "durchsetzt",
+    "durchsetzung",
+    "durchsichtig",
+    "durchsichtige",
+    "durchsichtigem",
+    "durchsichtigen",
+    "durchsichtiger",
+    "durchsichtigkeit",
+    "durchsickern",
+    "durchsonnte",
+    "durchstrichen",
+    "durchströmt",
+    "durchsuchen",
+    "durchsucht",
+    "durchsuchte",
+    "durchsuchten",
+    "durchsuchungen",
+    "durchtrieben",
+    "durchtriebener",
+    "durchwanderten",
+    "durchweg",
+    "durchwegs",
+    "durchweicht",
+    "durchzogen",
+    "durchzogener",
+    "durchzuckte",
+    "durchzulesen",
+    "durchzuschlagen",
+    "durchzusehen",
+    "durchzuwandern",
+    "durfte",
+    "durften",
+    "durst",
+    "dursten",
+    "durstet",
+    "durstige",
+    "durstiger",
+    "duselnden",
+    "duselt",
+    "dutzend",
+    "dutzenden",
+    "dutzendmal",
+    "dutzendweise",
+    "dv",
+    "dynamischen",
+    "dyp",
+    "dächer",
+    "dächern",
+    "dächten",
+    "dämme",
+    "dämmerigen",
+    "dämmeriges",
+    "dämmerlicht",
+    "dämmern",
+    "dämmerschein",
+    "dämmerte",
+    "dämmerung",
+    "dämmrigen",
+    "dämon",
+    "dämonen",
+    "dämonische",
+    "dämpfte",
+    "dänemark",
+    "dänischen",
+    "därfst",
+    "dö",
+    "dönnerchen",
+    "dörfchens",
+    "dörfer",
+    "dörfern",
+    "dörfler",
+    "dörflerische",
+    "dös",
+    "döschen",
+    "dümmer",
+    "dümmling",
+    "dünkelvoller",
+    "dünken",
+    "dünkenden",
+    "dünkt",
+    "dünkte",
+    "dünkten",
+    "dünn",
+    "dünne",
+    "dünnen",
+    "dünner",
+    "dünnes",
+    "dünste",
+    "dünsten",
+    "dürfe",
+    "dürfen",
+    "dürfet",
+    "dürft",
+    "dürfte",
+    "dürften",
+    "dürftet",
+    "dürftig",
+    "dürftige",
+    "dürftigem",
+    "dürftigen",
+    "dürftigkeit",
+    "dürr",
+    "dürre",
+    "dürrem",
+    "dürren",
+    "dürrer",
+    "dürres",
+    "dürste",
+    "dürsten",
+    "dürstende",
+    "dürstenden",
+    "dürsteten",
+    "düster",
+    "düstere",
+    "düsterem",
+    "düsterer",
+    "düte",
+    "düten",
+    "e",
+    "eahm",
+    "eandemque",
+    "ebbes",
+    "eben",
+    "ebenbild",
+    "ebenbürtig",
+    "ebenbürtigen",
+    "ebendaher",
+    "ebendamit",
+    "ebendarum",
+    "ebendaselbst",
+    "ebendasselbe",
+    "ebendenselben",
+    "ebenderselben",
+    "ebendiese",
+    "ebendieselbe",
+    "ebendieselben",
+    "ebendort",
+    "ebene",
+    "ebenen",
+    "ebenerdige",
+    "ebenfahls",
+    "ebenfalls",
+    "ebenso",
+    "ebensogut",
+    "ebensoviel",
+    "ebensowenig",
+    "ebensowohl",
+    "eber",
+    "ebereschen",
+    "ebne",
+    "ebnete",
+    "ecclesiae",
+    "echt",
+    "echte",
+    "echten",
+    "echter",
+    "ecke",
+    "ecken",
+    "eckig",
+    "ecksäulen",
+    "eckzimmer",
+    "ectypa",
+    "eda",
+    "edel",
+    "edelgeformten",
+    "edelherr",
+    "edelherren",
+    "edelherrn",
+    "edelmann",
+    "edelmanns",
+    "edelmannstracht",
+    "edelmütig",
+    "edelpferden",
+    "edelste",
+    "edelstein",
+    "edelsteine",
+    "edelsteinen",
+    "edelsten",
+    "edelweib",
+    "edelweiss",
+    "eden",
+    "edens",
+    "ediert",
+    "edith",
+    "edle",
+    "edlem",
+    "edlen",
+    "edler",
+    "edlere",
+    "edleren",
+    "edleres",
+    "edles",
+    "edom",
+    "edoms",
+    "eeccs",
+    "eerv",
+    "efeu",
+    "effekt",
+    "efficere",
+    "effigies",
+    "egal",
+    "eglaim",
+    "eh",
+    "ehandelt",
+    "ehe",
+    "ehebrechen",
+    "ehebrecher",
+    "ehebrechers",
+    "ehebruch",
+    "ehebruchs",
+    "ehebrüche",
+    "ehedem",
+    "ehefrau",
+    "ehegemahls",
+    "ehegesponsin",
+    "eheherren",
+    "eheleuten",
+    "eheliche",
+    "ehelichen",
+    "ehemalige",
+    "ehemaligen",
+    "ehemaliger",
+    "ehemaliges",
+    "ehemals",
+    "ehemann",
+    "ehemannes",
+    "ehen",
+    "ehepaar",
+    "eher",
+    "eherne",
+    "ehesten",
+    "ehestens",
+    "eheweiber",
+    "ehr",
+    "ehrbar",
+    "ehrbare",
+    "ehrbarem",
+    "ehrbaren",
+    "ehrbarer",
+    "ehrbareres",
+    "ehrbares",
+    "ehrbarkeit",
+    "ehre",
+    "ehren",
+    "ehrenamtliche",
+    "ehrenbürgerin",
+    "ehrenbürgerinfür",
+    "ehrenbürgerrecht",
+    "ehrend",
+    "ehrenfest",
+    "ehrenfesten",
+    "ehrennamen",
+    "ehrenplatz",
+    "ehrenreich",
+    "ehrenstellen",
+    "ehrenstellung",
+    "ehrentitel",
+    "ehrenwerte",
+    "ehrerbietig",
+    "ehrerbietung",
+    "ehrest",
+    "ehrfurcht",
+    "ehrfurchtsvolle",
+    "ehrgeizigen",
+    "ehrgeiziger",
+    "ehrlich",
+    "ehrliche",
+    "ehrlichen",
+    "ehrlicher",
+    "ehrlichkeit",
+    "ehrsame",
+    "ehrsames",
+    "ehrt",
+    "ehrte",
+    "ehrvergessenen",
+    "ehrwürdig",
+    "ehrwürdige",
+    "ehrwürdiger",
+    "ei",
+    "eia",
+    "eibenbäumchen",
+    "eiche",
+    "eichen",
+    "eichenast",
+    "eichendorff",
+    "eichenen",
+    "eichenstämme",
+    "eichentisch",
+    "eichhörnchen",
+    "eichkatzl",
+    "eichwald",
+    "eiden",
+    "eidgenossenschaft",
+    "eidgenössische",
+    "eidgenössischen",
+    "eier",
+    "eierbecher",
+    "eierbrot",
+    "eierkuchen",
+    "eierkuchens",
+    "eierlast",
+    "eiern",
+    "eiertanz",
+    "eifer",
+    "eifers",
+    "eifersucht",
+    "eifersüchtig",
+    "eiferten",
+    "eiffelturm",
+    "eifrig",
+    "eifrige",
+    "eifrigen",
+    "eifriger",
+    "eifrigsten",
+    "eigen",
+    "eigenanteile",
+    "eigenartige",
+    "eigendünkel",
+    "eigene",
+    "eigenem",
+    "eigenen",
+    "eigener",
+    "eigenes",
+    "eigenheiten",
+    "eigenhändig",
+    "eigenliebe",
+    "eigenliebige",
+    "eigenname",
+    "eigennutz",
+    "eigennützige",
+    "eigennützigen",
+    "eigennütziger",
+    "eigens",
+    "eigenschafren",
+    "eigenschaft",
+    "eigenschaften",
+    "eigenschaftung",
+    "eigensinn",
+    "eigensinnig",
+    "eigensinnigen",
+    "eigensinniger",
+    "eigenst",
+    "eigenste",
+    "eigensten",
+    "eigenstes",
+    "eigensüchtigen",
+    "eigentlich",
+    "eigentliche",
+    "eigentlichen",
+    "eigentliches",
+    "eigentlichst",
+    "eigentlichste",
+    "eigentum",
+    "eigentums",
+    "eigentümer",
+    "eigentümers",
+    "eigentümlich",
+    "eigentümliche",
+    "eigentümlichem",
+    "eigentümlichen",
+    "eigentümlicher",
+    "eigentümlicherweise",
+    "eigentümliches",
+    "eigentümlichkeit",
+    "eigentümlichkeiten",
+    "eigenwillen",
+    "eigne",
+    "eignen",
+    "eignet",
+    "eignungsprüfung",
+    "eil",
+    "eilanden",
+    "eile",
+    "eilen",
+    "eilend",
+    "eilenden",
+    "eilender",
+    "eilendes",
+    "eilends",
+    "eilet",
+    "eilfertig",
+    "eilig",
+    "eiligen",
+    "eiligst",
+    "eilt",
+    "eilte",
+    "eilten",
+    "eim",
+    "eimer",
+    "eimern",
+    "eimers",
+    "ein",
+    "einander",
+    "einband",
+    "einbanddeckel",
+    "einberufung",
+    "einbilde",
+    "einbilden",
+    "einbildete",
+    "einbildeten",
+    "einbildung",
+    "einbildungen",
+    "einbildungskraft",
+    "einblick",
+    "einbrechende",
+    "einbringen",
+    "einbringt",
+    "einbringung",
+    "einbruch",
+    "einbrächten",
+    "einbusse",
+    "einbuße",
+    "einbürgern",
+    "einbüssung",
+    "einchecken",
+    "eincheckens",
+    "eindeutig",
+    "eindrang",
+    "eindringe",
+    "eindringen",
+    "eindringendsten",
+    "eindringlich",
+    "eindringlichen",
+    "eindringlichkeit",
+    "eindringlinge",
+    "eindringt",
+    "eindruck",
+    "eindrücken",
+    "eine",
+    "eineinviertel",
+    "einem",
+    "einen",
+    "einer",
+    "einerlei",
+    "einerleiheit",
+    "einernten",
+    "einerseits",
+    "eines",
+    "einesteils",
+    "einfach",
+    "einfachbauweise",
+    "einfache",
+    "einfachen",
+    "einfacher",
+    "einfaches",
+    "einfachheit",
+    "einfachste",
+    "einfachsten",
+    "einfahrt",
+    "einfall",
+    "einfallen",
+    "einfalt",
+    "einfand",
+    "einfanden",
+    "einfange",
+    "einfangen",
+    "einfasste",
+    "einfenstrige",
+    "einfenstriges",
+    "einfiel",
+    "einfinden",
+    "einfindet",
+    "einfliesse",
+    "einfliessen",
+    "einfliessende",
+    "einfliessenden",
+    "einfliessendes",
+    "einfliesst",
+    "einfließe",
+    "einfließen",
+    "einfließende",
+    "einfließenden",
+    "einfließendes",
+    "einfließt",
+    "einfloss",
+    "einfloß",
+    "einfluss",
+    "einflusse",
+    "einflusses",
+    "einflussreiche",
+    "einflussreichen",
+    "einfluß",
+    "einflußreiche",
+    "einflußreichen",
+    "einflösse",
+    "einflössen",
+    "einflösst",
+    "einflössten",
+    "einflössung",
+    "einflöße",
+    "einflößen",
+    "einflößt",
+    "einflößten",
+    "einflößung",
+    "einflüsse",
+    "einflüstern",
+    "einfuhr",
+    "einfälle",
+    "einfällt",
+    "einfältig",
+    "einfältige",
+    "einfältigem",
+    "einfältigen",
+    "einfältiger",
+    "einfältiges",
+    "einförmig",
+    "einförmige",
+    "einfühlungsvermögen",
+    "einführen",
+    "einführung",
+    "eingab",
+    "eingabe",
+    "eingaben",
+    "eingang",
+    "eingange",
+    "eingangen",
+    "eingangstor",
+    "eingangstür",
+    "eingebildet",
+    "eingebildete",
+    "eingebildeten",
+    "eingebildetes",
+    "eingeblasen",
+    "eingebogen",
+    "eingeborene",
+    "eingeborenen",
+    "eingebracht",
+    "eingebunden",
+    "eingebung",
+    "eingebüsst",
+    "eingedenk",
+    "eingedrückten",
+    "eingedämmt",
+    "eingefahren",
+    "eingefallen",
+    "eingefasst",
+    "eingefaßt",
+    "eingefleischten",
+    "eingeflochten",
+    "eingeflösst",
+    "eingeflösste",
+    "eingeflösstes",
+    "eingeflößt",
+    "eingeflößte",
+    "eingeflößtes",
+    "eingefunden",
+    "eingefügt",
+    "eingeführt",
+    "eingegangen",
+    "eingegangenen",
+    "eingegeben",
+    "eingegebnen",
+    "eingegitterten",
+    "eingegraben",
+    "eingehandelt",
+    "eingehaucht",
+    "eingehen",
+    "eingehenden",
+    "eingehet",
+    "eingeholt",
+    "eingeht",
+    "eingehängt",
+    "eingehüllt",
+    "eingekauft",
+    "eingeklebt",
+    "eingekleidet",
+    "eingeklemmt",
+    "eingekniffenem",
+    "eingeladen",
+    "eingelassen",
+    "eingelassenen",
+    "eingelassenwerden",
+    "eingelegt",
+    "eingelegte",
+    "eingeleitet",
+    "eingeleitete",
+    "eingemachte",
+    "eingemengt",
+    "eingemischt",
+    "eingenommen",
+    "eingenommenen",
+    "eingepackt",
+    "eingepackten",
+    "eingepferchte",
+    "eingepflanzt",
+    "eingepflanzte",
+    "eingepflanzten",
+    "eingepflanztes",
+    "eingepfropfte",
+    "eingeprägt",
+    "eingeredet",
+    "eingeredeten",
+    "eingereiht",
+    "eingerichtet",
+    "eingerichteten",
+    "eingerichtetes",
+    "eingerissenes",
+    "eingeräumt",
+    "eingeräumte",
+    "eingeschenkten",
+    "eingeschlafen",
+    "eingeschlagen",
+    "eingeschlagenen",
+    "eingeschlossen",
+    "eingeschlossene",
+    "eingeschlossenen",
+    "eingeschmiert",
+    "eingeschossen",
+    "eingeschrieben",
+    "eingeschriebene",
+    "eingeschränkt",
+    "eingeschränkten",
+    "eingeschult",
+    "eingeschüchterten",
+    "eingesehen",
+    "eingesetzt",
+    "eingesperrt",
+    "eingestehe",
+    "eingestehen",
+    "eingestellt",
+    "eingestreut",
+    "eingeständlich",
+    "eingesät",
+    "eingetan",
+    "eingeteilt",
+    "eingetragen",
+    "eingetreten",
+    "eingetrieben",
+    "eingetrockneten",
+    "eingetroffen",
+    "eingetürmt",
+    "eingeweide",
+    "eingeweiden",
+    "eingeweiht",
+    "eingeweihten",
+    "eingewickelte",
+    "eingewirkt",
+    "eingewobene",
+    "eingeworfene",
+    "eingewurzelt",
+    "eingewurzelte",
+    "eingezogen",
+    "eingezogenheit",
+    "eingezwängt",
+    "eingeäscherten",
+    "eingeübt",
+    "eingeübten",
+    "eingiessen",
+    "eingießen",
+    "eingingen",
+    "eingoss",
+    "eingossen",
+    "eingoß",
+    "eingraben",
+    "eingreifen",
+    "eingreifens",
+    "eingriff",
+    "eingschlafen",
+    "eingsperrt",
+    "eingstanden",
+    "eingänge",
+    "eingängen",
+    "einhauchen",
+    "einheimische",
+    "einheimsen",
+    "einheit",
+    "einheiten",
+    "einheitenkillenden",
+    "einhellig",
+    "einher",
+    "einherfährt",
+    "einhergejagt",
+    "einherging",
+    "einherkrabbelte",
+    "einherliefen",
+    "einherschritt",
+    "einherspringen",
+    "einhertanzten",
+    "einholen",
+    "einhorn",
+    "einhundertvierunddreißigmal",
+    "einhundertzwanzig",
+    "einhängen",
+    "eini",
+    "einifoahrn",
+    "einig",
+    "einige",
+    "einigem",
+    "einigemal",
+    "einigen",
+    "einiger",
+    "einigermassen",
+    "einigermaßen",
+    "einiges",
+    "einigkeit",
+    "einigsprungen",
+    "einigten",
+    "einikommt",
+    "einischütten",
+    "einispringen",
+    "einjagt",
+    "einjährige",
+    "einkassierte",
+    "einkauf",
+    "einkaufen",
+    "einkaufszentrum",
+    "einkaufszentrumdie",
+    "einkehr",
+    "einkehren",
+    "einkleben",
+    "einkommen",
+    "einkommens",
+    "einkommensunterschiede",
+    "einkrümmung",
+    "einkäufe",
+    "einkünfte",
+    "einladend",
+    "einladung",
+    "einlassen",
+    "einlaufen",
+    "einlegte",
+    "einleitenden",
+    "einleitendes",
+    "einleitung",
+    "einlenkte",
+    "einleuchten",
+    "einleuchtend",
+    "einlud",
+    "einluden",
+    "einlässt",
+    "einläßt",
+    "einmal",
+    "einmalige",
+    "einmaliger",
+    "einmengende",
+    "einmischt",
+    "einmischte",
+    "einnahm",
+    "einnahmen",
+    "einnahmenüberschuss",
+    "einnehme",
+    "einnehmen",
+    "einnimmt",
+    "einpersonenhaushalte",
+    "einpflanzt",
+    "einpflanzte",
+    "einpflanzung",
+    "einpfropfung",
+    "einprägte",
+    "einprägten",
+    "einquartierungen",
+    "einreden",
+    "einredet",
+    "einreissen",
+    "einreisst",
+    "einrichten",
+    "einrichtet",
+    "einrichtung",
+    "einrichtungen",
+    "einrichtungsfanatikern",
+    "einräumen",
+    "einräumte",
+    "einrückte",
+    "eins",
+    "einsah",
+    "einsam",
+    "einsame",
+    "einsamen",
+    "einsames",
+    "einsamkeit",
+    "einsamkeiten",
+    "einsammeln",
+    "einsamsten",
+    "einsatz",
+    "einsatze",
+    "einsaugt",
+    "einschenken",
+    "einschlafen",
+    "einschlagen",
+    "einschleichen",
+    "einschlief",
+    "einschliefen",
+    "einschliessen",
+    "einschliesst",
+    "einschließen",
+    "einschließt",
+    "einschlossen",
+    "einschlug",
+    "einschläft",
+    "einschmeichelnd",
+    "einschmeichelndsten",
+    "einschmuggelten",
+    "einschreiten",
+    "einschränke",
+    "einschränken",
+    "einschränkende",
+    "einschränkenden",
+    "einschränkt",
+    "einschränkung",
+    "einschränkungen",
+    "einschätzung",
+    "einsehen",
+    "einseht",
+    "einseitige",
+    "einsetzen",
+    "einsetzte",
+    "einsetzung",
+    "einsicht",
+    "einsichten",
+    "einsichtsfähigkeit",
+    "einsichtsvoll",
+    "einsichtsvolle",
+    "einsichtsvollen",
+    "einsichtsvolleren",
+    "einsiedler",
+    "einsieht",
+    "einsilbig",
+    "einsilbigen",
+    "einsilbiger",
+    "einsilbiges",
+    "einsinkt",
+    "einsmals",
+    "einsogen",
+    "einsperrte",
+    "einsprache",
+    "einsprechen",
+    "einspänner",
+    "einst",
+    "einsteckte",
+    "einstellen",
+    "einstellt",
+    "einstiges",
+    "einstimmen",
+    "einstimmig",
+    "einstimmige",
+    "einstimmung",
+    "einstudierten",
+    "einstweilen",
+    "einstweilige",
+    "einstündige",
+    "einsähe",
+    "eintauche",
+    "eintausend",
+    "einteilen",
+    "einteilenden",
+    "einteilung",
+    "eintraccht",
+    "eintracht",
+    "eintrafen",
+    "eintrag",
+    "eintragt",
+    "eintrat",
+    "eintraten",
+    "eintreffen",
+    "eintreiben",
+    "eintrete",
+    "eintreten",
+    "eintretende",
+    "eintretenden",
+    "eintretens",
+    "eintrieb",
+    "eintrieben",
+    "eintrinken",
+    "eintritt",
+    "eintritte",
+    "eintrittskarte",
+    "einträchtig",
+    "einträchtiger",
+    "eintägigen",
+    "eintönig",
+    "eintönigen",
+    "eintöniger",
+    "einunddreissigsten",
+    "einunddreißigsten",
+    "einundsechzigjährige",
+    "einverleibt",
+    "einvernahme",
+    "einvernahmen",
+    "einverstanden",
+    "einverständnis",
+    "einwand",
+    "einwanderer",
+    "einwanderungsland",
+    "einwandfrei",
+    "einwandfreien",
+    "einweihen",
+    "einweihung",
+    "einweihungen",
+    "einwende",
+    "einwendung",
+    "einwendungen",
+    "einwickelpapier",
+    "einwilligung",
+    "einwirken",
+    "einwirkt",
+    "einwirkte",
+    "einwirkung",
+    "einwohner",
+    "einwohnerin",
+    "einwurzelt",
+    "einwände",
+    "einwärts",
+    "einwürfe",
+    "einzahl",
+    "einzahlen",
+    "einzelfragen",
+    "einzelheit",
+    "einzelheiten",
+    "einzeln",
+    "einzelne",
+    "einzelnen",
+    "einzelner",
+    "einzelnes",
+    "einzelnheiten",
+    "einzelnste",
+    "einzelnsten",
+    "einziehen",
+    "einzig",
+    "einzige",
+    "einzigem",
+    "einzigen",
+    "einziger",
+    "einziges",
+    "einzog",
+    "einzubalsamieren",
+    "einzubilden",
+    "einzubohren",
+    "einzubürgern",
+    "einzudringen",
+    "einzufinden",
+    "einzuflössen",
+    "einzuflößen",
+    "einzuführen",
+    "einzug",
+    "einzugehen",
+    "einzugehn",
+    "einzuges",
+    "einzugestehen",
+    "einzugreifen",
+    "einzuholen",
+    "einzuhängen",
+    "einzuknöpfen",
+    "einzulassen",
+    "einzureichen",
+    "einzureiten",
+    "einzuräumen",
+    "einzuschlagen",
+    "einzuschüchtern",
+    "einzusehen",
+    "einzusperren",
+    "einzustehen",
+    "einzutreffen",
+    "einzutreten",
+    "einzuwenden",
+    "einzuwerfen",
+    "einzuwirken",
+    "einzuwühlen",
+    "einzuziehen",
+    "einöde",
+    "einöden",
+    "eis",
+    "eisblumensträusschen",
+    "eisbärenmässig",
+    "eisekalt",
+    "eisen",
+    "eisenbahn",
+    "eisenbahnkönige",
+    "eisengeschienten",
+    "eisenhaken",
+    "eisenhut",
+    "eisenketten",
+    "eisenkörben",
+    "eisens",
+    "eisenschimmel",
+    "eisenstange",
+    "eisenwerk",
+    "eiserne",
+    "eiserner",
+    "eisgeschwister",
+    "eisgeschwistern",
+    "eisgraue",
+    "eisgrauer",
+    "eisig",
+    "eiskalten",
+    "eiskalter",
+    "eiskristallen",
+    "eismax",
+    "eisschloss",
+    "eitel",
+    "eitelkeit",
+    "eitelkeiten",
+    "eiteln",
+    "eitle",
+    "eitlen",
+    "eitler",
+    "eitles",
+    "eitrig",
+    "ekd",
+    "ekelhafter",
+    "ekeln",
+    "ekels",
+    "ekelte",
+    "ekkehard",
+    "elastisch",
+    "elastisches",
+    "elater",
+    "eleasar",
+    "elefant",
+    "elefanten",
+    "elefantenzähnen",
+    "elegant",
+    "elegante",
+    "elegantem",
+    "eleganten",
+    "elektrische",
+    "elektrischen",
+    "elektrizität",
+    "elementarbegriffe",
+    "elementare",
+    "elementarfeuer",
+    "elementarlehre",
+    "elemente",
+    "elenchis",
+    "elend",
+    "elende",
+    "elenden",
+    "elender",
+    "elenderfüllten",
+    "elendern",
+    "elendes",
+    "elendiglich",
+    "elends",
+    "elfenbein",
+    "elfenbeinfarbenen",
+    "elfjährigen",
+    "elften",
+    "elias",
+    "elisa",
+    "elisabeth",
+    "elisabethen",
+    "elisabethenkrankenhaus",
+    "eliseba",
+    "elkanah",
+    "ellbogen",
+    "ellbög",
+    "elle",
+    "ellen",
+    "ellenbogen",
+    "elmsfeuerchen",
+    "elohim",
+    "eltern",
+    "elternhauses",
+    "elternpaar",
+    "elzaphan",
+    "emanuel",
+    "emanuela",
+    "emes",
+    "emigranten",
+    "emigrierten",
+    "emim",
+    "emin",
+    "eminenten",
+    "eminonü",
+    "emmeline",
+    "empfahl",
+    "empfand",
+    "empfanden",
+    "empfang",
+    "empfangen",
+    "empfangene",
+    "empfangenen",
+    "empfangszimmer",
+    "empfehlen",
+    "empfehlende",
+    "empfehlung",
+    "empfiehlt",
+    "empfinde",
+    "empfinden",
+    "empfindenden",
+    "empfindet",
+    "empfindlich",
+    "empfindliches",
+    "empfindsamen",
+    "empfindsamkeit",
+    "empfindung",
+    "empfindungen",
+    "empfindungszustand",
+    "empfing",
+    "empfingen",
+    "empfmg",
+    "empfohlen",
+    "empfunden",
+    "empfänden",
+    "empfänglichkeit",
+    "empfängnis",
+    "empfängnisse",
+    "empfängt",
+    "emphatisch",
+    "empirisch",
+    "empirische",
+    "empirischen",
+    "empirischer",
+    "empirisches",
+    "empirismus",
+    "empor",
+    "emporgehoben",
+    "emporgeschossene",
+    "emporgestiegen",
+    "emporhebt",
+    "emporhob",
+    "emporkommen",
+    "emporkömmlinge",
+    "emporragte",
+    "emporragten",
+    "emporschwangen",
+    "emporschwimmt",
+    "emporstarren",
+    "emportauchen",
+    "emportürmte",
+    "emporzuheben",
+    "emporzutragen",
+    "empörend",
+    "empörender",
+    "empört",
+    "empörter",
+    "emsig",
+    "emsigkeit",
+    "emsigsten",
+    "en",
+    "enakim",
+    "enaks",
+    "endchen",
+    "ende",
+    "enden",
+    "endete",
+    "endgültig",
+    "endgültige",
+    "endgültigen",
+    "endigen",
+    "endigt",
+    "endigte",
+    "endlich",
+    "endliche",
+    "endlichen",
+    "endlicher",
+    "endliches",
+    "endlichkeit",
+    "endlos",
+    "endlosen",
+    "endoxa",
+    "endpunkt",
+    "endpunkte",
+    "endstücke",
+    "endzweck",
+    "enen",
+    "energie",
+    "energisch",
+    "energischer",
+    "energisches",
+    "eng",
+    "engadiner",
+    "engagement",
+    "engagieren",
+    "engagiert",
+    "engagierte",
+    "engagierter",
+    "enge",
+    "engedi",
+    "engel",
+    "engelartig",
+    "engelchören",
+    "engelgeister",
+    "engelgeistes",
+    "engelische",
+    "engelischen",
+    "engelland",
+    "engeln",
+    "engels",
+    "engelscharen",
+    "engelsgeistern",
+    "engelshimmel",
+    "engelsrede",
+    "engelstimmchen",
+    "engelswesen",
+    "engen",
+    "enger",
+    "engere",
+    "engeren",
+    "engerer",
+    "englisch",
+    "englische",
+    "englischen",
+    "englischer",
+    "englisches",
+    "englischlehrerin",
+    "englischsprachige",
+    "englischsprachigen",
+    "engländer",
+    "engländern",
+    "engschlucht",
+    "engste",
+    "engsten",
+    "engtälern",
+    "enim",
+    "enk",
+    "enkel",
+    "enkelin",
+    "enosch",
+    "enoschs",
+    "ent",
+    "entartung",
+    "entbehren",
+    "entbehrlich",
+    "entbehrt",
+    "entbehrte",
+    "entbehrung",
+    "entblösst",
+    "entblößt",
+    "entbrannt",
+    "entbrannte",
+    "entbrannten",
+    "entbrennen",
+    "entdecken",
+    "entdecker",
+    "entdeckt",
+    "entdeckte",
+    "entdeckten",
+    "entdeckung",
+    "entehrt",
+    "enten",
+    "entenadler",
+    "entenfüssen",
+    "enterben",
+    "entfallen",
+    "entfalten",
+    "entfaltet",
+    "entfaltete",
+    "entferne",
+    "entfernen",
+    "entfernet",
+    "entfernst",
+    "entfernt",
+    "entfernte",
+    "entfernten",
+    "entfernter",
+    "entfernteres",
+    "entferntesten",
+    "entferntwerden",
+    "entfernung",
+    "entfernungen",
+    "entfessle",
+    "entfeßle",
+    "entflammen",
+    "entfliehen",
+    "entfloh",
+    "entflohen",
+    "entflohene",
+    "entflohenen",
+    "entfremdet",
+    "entfremdete",
+    "entfremdetem",
+    "entfremdeten",
+    "entfremdung",
+    "entfärbt",
+    "entführten",
+    "entgangen",
+    "entgegen",
+    "entgegenarbeiten",
+    "entgegenbringen",
+    "entgegeneilen",
+    "entgegenflossen",
+    "entgegengefahren",
+    "entgegengehen",
+    "entgegengekehrt",
+    "entgegengesetzt",
+    "entgegengesetzte",
+    "entgegengesetzten",
+    "entgegengesetzter",
+    "entgegengesetztes",
+    "entgegengetreten",
+    "entgegenhandle",
+    "entgegenhielt",
+    "entgegenkam",
+    "entgegenkommen",
+    "entgegenkommende",
+    "entgegennehmen",
+    "entgegensetzen",
+    "entgegensetzt",
+    "entgegensetzung",
+    "entgegenstehende",
+    "entgegensteht",
+    "entgegenstellen",
+    "entgegenstellte",
+    "entgegenstreckte",
+    "entgegenstrich",
+    "entgegentrat",
+    "entgegenwandernd",
+    "entgegenzusehen",
+    "entgegnete",
+    "entgehe",
+    "entgehen",
+    "entgeht",
+    "entgeld",
+    "entging",
+    "entgleiten",
+    "enthalte",
+    "enthalten",
+    "enthaltende",
+    "enthaltenden",
+    "enthaltene",
+    "enthaltsam",
+    "enthaltsamkeit",
+    "entheiligt",
+    "entheiligung",
+    "entheiligungen",
+    "enthielt",
+    "enthielte",
+    "enthielten",
+    "enthoben",
+    "enthusiasmus",
+    "enthusiasten",
+    "enthält",
+    "enthüllen",
+    "enthüllt",
+    "entkam",
+    "entkleidet",
+    "entkleidete",
+    "entkleideten",
+    "entkommen",
+    "entkommene",
+    "entkorkten",
+    "entkräften",
+    "entkräfteter",
+    "entlang",
+    "entlanggerast",
+    "entlass",
+    "entlasse",
+    "entlassen",
+    "entlassung",
+    "entlassungen",
+    "entlastet",
+    "entlastetder",
+    "entlaß",
+    "entledigen",
+    "entledigt",
+    "entledigung",
+    "entleeren",
+    "entleerte",
+    "entleerten",
+    "entlegene",
+    "entlegenen",
+    "entlegenste",
+    "entlehnen",
+    "entlehnten",
+    "entliess",
+    "entliessen",
+    "entließ",
+    "entlocken",
+    "entlockte",
+    "entlohnen",
+    "entlässest",
+    "entlässet",
+    "entlässt",
+    "entläßt",
+    "entnahm",
+    "entnehmen",
+    "entnommen",
+    "entpuppte",
+    "entreissen",
+    "entreißen",
+    "entrinnen",
+    "entriss",
+    "entrissen",
+    "entronnenen",
+    "entränne",
+    "entrüstet",
+    "entrüstung",
+    "entsage",
+    "entsagen",
+    "entsagens",
+    "entsagung",
+    "entsagungsfähigkeit",
+    "entsandet",
+    "entscheiden",
+    "entscheidend",
+    "entscheidende",
+    "entscheidenden",
+    "entscheidender",
+    "entscheidendes",
+    "entscheidet",
+    "entscheidung",
+    "entscheidungen",
+    "entscheidungsgrund",
+    "entscheidungskampf",
+    "entschiede",
+    "entschieden",
+    "entschiedene",
+    "entschiedenen",
+    "entschiedenheit",
+    "entschlafen",
+    "entschlief",
+    "entschliessen",
+    "entschliessung",
+    "entschliessungen",
+    "entschließung",
+    "entschloss",
+    "entschlossen",
+    "entschlossene",
+    "entschlossenen",
+    "entschlossener",
+    "entschlossenes",
+    "entschlossenheit",
+    "entschloß",
+    "entschluss",
+    "entschlusse",
+    "entschluß",
+    "entschlüpfe",
+    "entschlüpfen",
+    "entschuldige",
+    "entschuldigen",
+    "entschuldigende",
+    "entschuldigt",
+    "entschuldigung",
+    "entschwand",
+    "entschwebte",
+    "entschwindet",
+    "entschwunden",
+    "entschädigen",
+    "entschädigung",
+    "entseelt",
+    "entsetzen",
+    "entsetzlich",
+    "entsetzliche",
+    "entsetzlichen",
+    "entsetzliches",
+    "entsetzt",
+    "entsinne",
+    "entsinnen",
+    "entsinnst",
+    "entsittlichung",
+    "entspann",
+    "entspannt",
+    "entsprach",
+    "entsprangen",
+    "entspreche",
+    "entsprechen",
+    "entsprechend",
+    "entsprechende",
+    "entsprechenden",
+    "entsprechender",
+    "entsprechendes",
+    "entsprechung",
+    "entsprechungen",
+    "entsprechungsverhältnis",
+    "entspricht",
+    "entspringe",
+    "entspringen",
+    "entspringend",
+    "entspringt",
+    "entsprungen",
+    "entspränge",
+    "entstand",
+    "entstanden",
+    "entstehe",
+    "entstehen",
+    "entstehende",
+    "entstehens",
+    "entstehn",
+    "entsteht",
+    "entstehung",
+    "entstehungsursache",
+    "entstehungsursachen",
+    "entströmen",
+    "entströmten",
+    "entstände",
+    "entsündigen",
+    "entsündigung",
+    "enttäuschend",
+    "enttäuschung",
+    "enttäuschungen",
+    "entvölkerte",
+    "entwaffnet",
+    "entwand",
+    "entwarf",
+    "entweder",
+    "entweicht",
+    "entweihe",
+    "entweihen",
+    "entweiher",
+    "entweihern",
+    "entweiht",
+    "entweihte",
+    "entweihten",
+    "entweihtes",
+    "entweihung",
+    "entweihungen",
+    "entwendet",
+    "entwendete",
+    "entwerfen",
+    "entwich",
+    "entwichen",
+    "entwichene",
+    "entwichenen",
+    "entwickeln",
+    "entwickelt",
+    "entwickelte",
+    "entwickelten",
+    "entwickelung",
+    "entwicklung",
+    "entwicklungen",
+    "entwicklungsstufe",
+    "entwinden",
+    "entwirft",
+    "entwischen",
+    "entwischest",
+    "entworfen",
+    "entworfenen",
+    "entwurfes",
+    "entwöhnt",
+    "entwölkte",
+    "entwürdigend",
+    "entziehen",
+    "entziehung",
+    "entzog",
+    "entzogen",
+    "entzwei",
+    "entzweigesägt",
+    "entzweiging",
+    "entzögen",
+    "entzücken",
+    "entzückend",
+    "entzückt",
+    "entzünden",
+    "entzündete",
+    "entzündliches",
+    "eorum",
+    "epd",
+    "epha",
+    "ephas",
+    "ephod",
+    "ephode",
+    "ephods",
+    "ephraim",
+    "epidermis",
+    "epijumhtikon",
+    "epikur",
+    "epikureer",
+    "epods",
+    "er",
+    "erbarme",
+    "erbarmen",
+    "erbarmer",
+    "erbarmt",
+    "erbarmte",
+    "erbarmung",
+    "erbarmungen",
+    "erbarmungslosen",
+    "erbat",
+    "erbauer",
+    "erbaulich",
+    "erbaulichen",
+    "erbaulicher",
+    "erbauliches",
+    "erbaut",
+    "erbaute",
+    "erbauungsbuch",
+    "erbböse",
+    "erbbösen",
+    "erbböses",
+    "erbe",
+    "erbeben",
+    "erbebt",
+    "erbebte",
+    "erben",
+    "erbes",
+    "erbeten",
+    "erbetenen",
+    "erbeutete",
+    "erbeuteten",
+    "erbeutung",
+    "erbfeind",
+    "erbgrafen",
+    "erbgüter",
+    "erbherren",
+    "erbherrn",
+    "erbinnen",
+    "erbittere",
+    "erbittert",
+    "erblassen",
+    "erblasste",
+    "erblassten",
+    "erbleichende",
+    "erbleichte",
+    "erblich",
+    "erbliche",
+    "erblicke",
+    "erblicken",
+    "erblickt",
+    "erblickte",
+    "erblindeten",
+    "erbost",
+    "erboste",
+    "erbosten",
+    "erboster",
+    "erbracht",
+    "erbrechen",
+    "erbringen",
+    "erbschaft",
+    "erbschaften",
+    "erbsen",
+    "erbsenkost",
+    "erbte",
+    "erbteil",
+    "erbtum",
+    "erbärmlich",
+    "erbärmliche",
+    "erbärmlichen",
+    "erbärmliches",
+    "erbärmlichsten",
+    "erdacht",
+    "erdachten",
+    "erdball",
+    "erdbeben",
+    "erdbeere",
+    "erdboden",
+    "erdbodens",
+    "erde",
+    "erden",
+    "erdenken",
+    "erdenklichen",
+    "erdgeruch",
+    "erdgeschoss",
+    "erdgeschoßwerkstätten",
+    "erdharz",
+    "erdharzes",
+    "erdichten",
+    "erdichtete",
+    "erdichtetem",
+    "erdichteten",
+    "erdichtetes",
+    "erdkreis",
+    "erdkreises",
+    "erdmolch",
+    "erdreich",
+    "erdrosselt",
+    "erdulden",
+    "erduldet",
+    "erdwelle",
+    "ereignen",
+    "ereignenden",
+    "ereignet",
+    "ereignete",
+    "ereignis",
+    "ereignisse",
+    "ereignissen",
+    "ereignisses",
+    "ererbt",
+    "ererbten",
+    "erfahre",
+    "erfahren",
+    "erfahrene",
+    "erfahrenern",
+    "erfahrner",
+    "erfahrung",
+    "erfahrungen",
+    "erfahrungsbeweise",
+    "erfahrungsfalle",
+    "erfahrungsfolgen",
+    "erfahrungsgegenstände",
+    "erfahrungsgemäss",
+    "erfahrungsgemäß",
+    "erfahrungsurteilen",
+    "erfand",
+    "erfanden",
+    "erfassen",
+    "erfasst",
+    "erfasste",
+    "erfaßt",
+    "erfinde",
+    "erfinden",
+    "erfindung",
+    "erfindungsgabe",
+    "erfindungsgenie",
+    "erfindungsreiche",
+    "erfindungsreiches",
+    "erflehen",
+    "erfolg",
+    "erfolge",
+    "erfolgen",
+    "erfolgenden",
+    "erfolges",
+    "erfolglos",
+    "erfolglosen",
+    "erfolgreich",
+    "erfolgsrezept",
+    "erfolgt",
+    "erfolgte",
+    "erforderlich",
+    "erforderliche",
+    "erforderlichen",
+    "erfordern",
+    "erfordert",
+    "erforderte",
+    "erforsche",
+    "erforschen",
+    "erforscht",
+    "erforschten",
+    "erforschung",
+    "erforschungen",
+    "erfreuen",
+    "erfreuete",
+    "erfreulich",
+    "erfreuliche",
+    "erfreut",
+    "erfreute",
+    "erfreuten",
+    "erfrischen",
+    "erfrischte",
+    "erfrischung",
+    "erfroren",
+    "erfrorenen",
+    "erfuhr",
+    "erfuhren",
+    "erfunden",
+    "erfundenen",
+    "erfährt",
+    "erfüllen",
+    "erfüllet",
+    "erfüllt",
+    "erfüllte",
+    "erfüllten",
+    "erfüllung",
+    "ergab",
+    "ergaben",
+    "ergangen",
+    "ergebe",
+    "ergeben",
+    "ergebenen",
+    "ergebenheit",
+    "ergebenst",
+    "ergebnis",
+    "ergebnisse",
+    "ergebnissen",
+    "ergebung",
+    "ergebungsvoll",
+    "ergehen",
+    "ergeht",
+    "ergibt",
+    "ergiebiger",
+    "ergiessen",
+    "ergiessung",
+    "ergießen",
+    "ergießung",
+    "erging",
+    "erglänzten",
+    "erglühen",
+    "ergoss",
+    "ergoß",
+    "ergraute",
+    "ergrauten",
+    "ergreife",
+    "ergreifen",
+    "ergreifend",
+    "ergreift",
+    "ergriff",
+    "ergriffen",
+    "ergrimmt",
+    "ergrübeln",
+    "ergründen",
+    "ergänzen",
+    "ergänzenden",
+    "ergänzt",
+    "ergänzte",
+    "ergänzung",
+    "ergänzungsband",
+    "ergötzen",
+    "ergötzlich",
+    "ergötzt",
+    "ergötzte",
+    "ergötzten",
+    "ergötzung",
+    "ergötzungen",
+    "erhaben",
+    "erhabene",
+    "erhabenes",
+    "erhabenheit",
+    "erhabnen",
+    "erhalt",
+    "erhalte",
+    "erhalten",
+    "erhaltende",
+    "erhalter",
+    "erhaltung",
+    "erhandelt",
+    "erhaschen",
+    "erhebe",
+    "erheben",
+    "erhebend",
+    "erhebenden",
+    "erheblich",
+    "erheblichen",
+    "erheblichkeit",
+    "erhebst",
+    "erhebt",
+    "erhebung",
+    "erhebungen",
+    "erheischt",
+    "erhellen",
+    "erhellt",
+    "erhellte",
+    "erheucheltes",
+    "erhielt",
+    "erhielten",
+    "erhieltst",
+    "erhitzen",
+    "erhitzt",
+    "erhitzten",
+    "erhob",
+    "erhoben",
+    "erhobenen",
+    "erhobener",
+    "erhofft",
+    "erhofften",
+    "erholt",
+    "erholte",
+    "erholten",
+    "erholung",
+    "erholungspause",
+    "erhält",
+    "erhältst",
+    "erhängt",
+    "erhängte",
+    "erhärtet",
+    "erhöhen",
+    "erhöht",
+    "erhöhte",
+    "erhöhten",
+    "erhöhter",
+    "erhöhung",
+    "erhörest",
+    "erhört",
+    "erich",
+    "erinnere",
+    "erinnerlich",
+    "erinnern",
+    "erinnernd",
+    "erinnert",
+    "erinnerte",
+    "erinnerten",
+    "erinnerung",
+    "erinnerungen",
+    "erinnerungskräfte",
+    "eristica",
+    "eristik",
+    "eristikouv",
+    "eristische",
+    "eristischen",
+    "eriugena",
+    "erjagen",
+    "erjebenst",
+    "erkannt",
+    "erkannte",
+    "erkannten",
+    "erkaufen",
+    "erkennbar",
+    "erkennbarem",
+    "erkennbaren",
+    "erkenne",
+    "erkennen",
+    "erkennenden",
+    "erkennest",
+    "erkennet",
+    "erkennst",
+    "erkennt",
+    "erkenntnis",
+    "erkenntnisgründe",
+    "erkenntnisgründen",
+    "erkenntnisse",
+    "erkenntnissen",
+    "erkenntnisses",
+    "erkenntnisvermögen",
+    "erkenntnisvermögens",
+    "erkennung",
+    "erker",
+    "erkleckliche",
+    "erklecklichen",
+    "erkleckliches",
+    "erkletterten",
+    "erkläre",
+    "erklären",
+    "erklärlich",
+    "erklärliche",
+    "erklärst",
+    "erklärt",
+    "erklärte",
+    "erklärten",
+    "erklärung",
+    "erklärungen",
+    "erklärungsgrund",
+    "erklärungsweise",
+    "erkundigt",
+    "erkundigte",
+    "erkämpfen",
+    "erkünstelt",
+    "erlahmten",
+    "erlangen",
+    "erlangende",
+    "erlangte",
+    "erlassen",
+    "erlassung",
+    "erlaube",
+    "erlauben",
+    "erlaubnis",
+    "erlaubt",
+    "erlaubte",
+    "erlaubten",
+    "erlaucht",
+    "erlauchte",
+    "erlauchten",
+    "erle",
+    "erlebe",
+    "erleben",
+    "erlebnis",
+    "erlebnisse",
+    "erlebnissen",
+    "erlebt",
+    "erlebte",
+    "erledigen",
+    "erledigt",
+    "erledigung",
+    "erlegt",
+    "erlegten",
+    "erlegung",
+    "erleichtern",
+    "erleichternder",
+    "erleichtert",
+    "erleichterte",
+    "erleiden",
+    "erlenzeilen",
+    "erlernen",
+    "erlernt",
+    "erlernte",
+    "erleuchte",
+    "erleuchten",
+    "erleuchtet",
+    "erleuchtete",
+    "erleuchteten",
+    "erleuchtung",
+    "erleuchtungen",
+    "erlitt",
+    "erlitten",
+    "erlittenen",
+    "erloschen",
+    "erlustigen",
+    "erläutert",
+    "erläuterung",
+    "erlös",
+    "erlöschen",
+    "erlöse",
+    "erlösen",
+    "erlöser",
+    "erlöset",
+    "erlöst",
+    "erlösten",
+    "erlösung",
+    "erlösungsgelder",
+    "erlösungswerk",
+    "ermahne",
+    "ermahnen",
+    "ermahnt",
+    "ermahnte",
+    "ermahnung",
+    "ermahnungen",
+    "ermangelte",
+    "ermangelung",
+    "ermannen",
+    "ermannte",
+    "ermattetes",
+    "ermattung",
+    "ermessens",
+    "ermessensspielraum",
+    "ermesset",
+    "ermordet",
+    "ermordete",
+    "ermordeten",
+    "ermuntern",
+    "ermunterte",
+    "ermunterten",
+    "ermutigungen",
+    "ermächtigt",
+    "ermöglichen",
+    "ermöglicht",
+    "ermüden",
+    "ermüdenden",
+    "ermüdet",
+    "ermüdeten",
+    "ermüdetes",
+    "erna",
+    "ernannt",
+    "ernannte",
+    "ernennung",
+    "erneuere",
+    "erneuern",
+    "erneuerst",
+    "erneuert",
+    "erneuerte",
+    "erneuerung",
+    "erneure",
+    "erniedrige",
+    "erniedrigen",
+    "erniedrigten",
+    "erniedrigung",
+    "ernst",
+    "ernste",
+    "ernsten",
+    "ernster",
+    "ernstes",
+    "ernstesten",
+    "ernsthaft",
+    "ernsthafte",
+    "ernsthaften",
+    "ernsthafter",
+    "ernsthaftes",
+    "ernstlich",
+    "ernstliche",
+    "ernte",
+    "erntesegen",
+    "erntete",
+    "ernähren",
+    "ernährer",
+    "ernährt",
+    "ernährung",
+    "ernährungsmethode",
+    "erobern",
+    "erobert",
+    "eroberung",
+    "eroberungen",
+    "erproben",
+    "erprüften",
+    "erquicken",
+    "erquickt",
+    "erquickte",
+    "erquickung",
+    "erraten",
+    "erregen",
+    "erregend",
+    "erregt",
+    "erregte",
+    "erregten",
+    "erregung",
+    "erregungen",
+    "erreichbar",
+    "erreichbare",
+    "erreiche",
+    "erreichen",
+    "erreicht",
+    "erreichte",
+    "erreichten",
+    "erreichung",
+    "errette",
+    "erretten",
+    "errettet",
+    "errichten",
+    "errichtet",
+    "errichtete",
+    "errichteten",
+    "errichtung",
+    "erriet",
+    "erringen",
+    "errungenschaft",
+    "erröte",
+    "erröten",
+    "errötend",
+    "errötete",
+    "erröteten",
+    "ersah",
+    "ersann",
+    "ersannen",
+    "ersatz",
+    "erschaffen",
+    "erschaffenden",
+    "erschaffene",
+    "erschaffenen",
+    "erschauten",
+    "erscheine",
+    "erscheinen",
+    "erscheinende",
+    "erscheinenden",
+    "erscheinens",
+    "erscheint",
+    "erscheinung",
+    "erscheinungen",
+    "erscheinungsformen",
+    "erschien",
+    "erschiene",
+    "erschienen",
+    "erschienenen",
+    "erschlage",
+    "erschlagen",
+    "erschlagener",
+    "erschleichens",
+    "erschlichen",
+    "erschlichene",
+    "erschliessen",
+    "erschließen",
+    "erschlüge",
+    "erschnappt",
+    "erscholl",
+    "erschossen",
+    "erschrak",
+    "erschrecken",
+    "erschrecklicher",
+    "erschreckt",
+    "erschreckte",
+    "erschreckter",
+    "erschrocken",
+    "erschrockenen",
+    "erschrockener",
+    "erschwert",
+    "erschöpfen",
+    "erschöpfend",
+    "erschöpft",
+    "erschüttern",
+    "erschütternd",
+    "erschüttert",
+    "erschütterte",
+    "erschütterten",
+    "erschütterung",
+    "ersehe",
+    "ersehen",
+    "ersehnt",
+    "ersehnte",
+    "ersehnten",
+    "ersetzen",
+    "ersetzt",
+    "ersetzte",
+    "ersichtlich",
+    "ersieht",
+    "ersinnen",
+    "ersonnen",
+    "erspare",
+    "ersparen",
+    "ersparnis",
+    "ersparnisse",
+    "ersparnissen",
+    "erspart",
+    "ersparten",
+    "erspriesslich",
+    "erspriessliche",
+    "erspriessliches",
+    "ersprießlich",
+    "erspähen",
+    "erspähend",
+    "erspäht",
+    "erst",
+    "erstand",
+    "erstanden",
+    "erstandenen",
+    "erstarben",
+    "erstarken",
+    "erstarkt",
+    "erstarrt",
+    "erstarrten",
+    "erstarrung",
+    "erstatte",
+    "erstattet",
+    "erstattete",
+    "erstaunen",
+    "erstaunlich",
+    "erstaunlichen",
+    "erstaunt",
+    "erstaunte",
+    "erstaunten",
+    "erstauntes",
+    "erstdruck",
+    "erste",
+    "erstehen",
+    "ersteht",
+    "erstellt",
+    "erstellung",
+    "erstemal",
+    "ersten",
+    "erstenmal",
+    "erstens",
+    "erster",
+    "erstere",
+    "ersteren",
+    "ersterer",
+    "ersterm",
+    "erstern",
+    "erstes",
+    "erstgeborene",
+    "erstgeborenen",
+    "erstgeborener",
+    "erstgeburt",
+    "erstgeburten",
+    "ersticken",
+    "erstickend",
+    "erstickenden",
+    "erstickt",
+    "erstickte",
+    "erstickung",
+    "erstige",
+    "erstirbt",
+    "erstlich",
+    "erstlinge",
+    "erstmals",
+    "erstochene",
+    "erstreckt",
+    "erstreckte",
+    "erstreckten",
+    "erstritt",
+    "ersuche",
+    "ersucht",
+    "ersuchten",
+    "ersäufen",
+    "ersäuft",
+    "ertappen",
+    "ertappte",
+    "erteilen",
+    "erteilt",
+    "erteilte",
+    "ertrag",
+    "ertrage",
+    "ertragen",
+    "ertragener",
+    "ertrank",
+    "ertrinkens",
+    "ertrotzen",
+    "ertrug",
+    "ertrugen",
+    "erträglichen",
+    "erträglicher",
+    "erträgliches",
+    "erträgt",
+    "ertönen",
+    "ertönt",
+    "ertönte",
+    "ertönten",
+    "ertötet",
+    "erwachend",
+    "erwachende",
+    "erwachendem",
+    "erwachenden",
+    "erwachender",
+    "erwachsen",
+    "erwachsene",
+    "erwachsenem",
+    "erwachsenen",
+    "erwachsener",
+    "erwacht",
+    "erwachte",
+    "erwachten",
+    "erwarb",
+    "erwarte",
+    "erwarten",
+    "erwartest",
+    "erwartet",
+    "erwartete",
+    "erwarteten",
+    "erwartung",
+    "erwartungen",
+    "erwartungsvollem",
+    "erwecken",
+    "erweckt",
+    "erweckte",
+    "erweckten",
+    "erweicht",
+    "erweisen",
+    "erweislichen",
+    "erweisung",
+    "erweitern",
+    "erweiternd",
+    "erweiterndes",
+    "erweitert",
+    "erweiterte",
+    "erweiterten",
+    "erweiterung",
+    "erweiterungen",
+    "erwerb",
+    "erwerben",
+    "erwerbende",
+    "erwerbenden",
+    "erwerbmittel",
+    "erwerbs",
+    "erwidern",
+    "erwiderte",
+    "erwiderung",
+    "erwies",
+    "erwiesen",
+    "erwiesenermassen",
+    "erwirbt",
+    "erwischen",
+    "erwischt",
+    "erwischte",
+    "erwogen",
+    "erworben",
+    "erworbene",
+    "erworbenen",
+    "erwthsewv",
+    "erwächst",
+    "erwägen",
+    "erwägt",
+    "erwählen",
+    "erwählet",
+    "erwählt",
+    "erwählten",
+    "erwählung",
+    "erwähnen",
+    "erwähnt",
+    "erwähnte",
+    "erwähnten",
+    "erwähnung",
+    "erwähnungen",
+    "erwärmt",
+    "erwünscht",
+    "erwünschte",
+    "erwürge",
+    "erwürgen",
+    "erwürgt",
+    "erwürgte",
+    "erz",
+    "erzeigen",
+    "erzeigt",
+    "erzengel",
+    "erzes",
+    "erzeugen",
+    "erzeuger",
+    "erzeugerin",
+    "erzeugnis",
+    "erzeugnisse",
+    "erzeugnisses",
+    "erzeugt",
+    "erzeugte",
+    "erziehen",
+    "erzieher",
+    "erzieherei",
+    "erzieherin",
+    "erzieherisch",
+    "erziehung",
+    "erziehungskunst",
+    "erziehungsleute",
+    "erziehungsmassregeln",
+    "erziehungsmühe",
+    "erziehungsresultat",
+    "erziehungswerk",
+    "erziele",
+    "erzielen",
+    "erzielt",
+    "erzittere",
+    "erzittern",
+    "erzitterte",
+    "erzog",
+    "erzogen",
+    "erzwungene",
+    "erzwungenen",
+    "erzähl",
+    "erzähle",
+    "erzählen",
+    "erzählende",
+    "erzähler",
+    "erzählest",
+    "erzählet",
+    "erzählst",
+    "erzählt",
+    "erzählte",
+    "erzählten",
+    "erzähltwerden",
+    "erzählung",
+    "erzählungen",
+    "erzürnt",
+    "erzürnte",
+    "eröffnen",
+    "eröffnet",
+    "eröffnete",
+    "eröffnung",
+    "erörtert",
+    "erörterten",
+    "es",
+    "esau",
+    "esaus",
+    "eschenstab",
+    "esel",
+    "eselein",
+    "eselfüllen",
+    "eseln",
+    "esels",
+    "esers",
+    "espenlaub",
+    "essangelegenheiten",
+    "essayistischen",
+    "essbare",
+    "essbares",
+    "essbegierige",
+    "essbestecke",
+    "esse",
+    "essen",
+    "essend",
+    "essens",
+    "esser",
+    "esset",
+    "essig",
+    "essighafen",
+    "esst",
+    "esszimmer",
+    "esszimmers",
+    "est",
+    "estherchen",
+    "estherchens",
+    "estherlein",
+    "esti",
+    "estrich",
+    "estrichs",
+    "et",
+    "ethische",
+    "ethnographischer",
+    "etliche",
+    "etlicher",
+    "etwa",
+    "etwaigen",
+    "etwanigen",
+    "etwas",
+    "euch",
+    "euer",
+    "euere",
+    "euerm",
+    "euern",
+    "eule",
+    "eulen",
+    "eulenspiegelei",
+    "eulenvogel",
+    "euphrat",
+    "eure",
+    "eurem",
+    "euren",
+    "eurer",
+    "eures",
+    "euretwegen",
+    "europa",
+    "europapokal",
+    "europas",
+    "europäern",
+    "europäische",
+    "europäischen",
+    "eustachius",
+    "eva",
+    "evangelii",
+    "evangelikale",
+    "evangelikalen",
+    "evangelikalenin",
+    "evangelisch",
+    "evangelische",
+    "evangelischen",
+    "evangelischer",
+    "evangelisches",
+    "evangelium",
+    "eventuelle",
+    "evident",
+    "evidenz",
+    "ewig",
+    "ewige",
+    "ewigem",
+    "ewigen",
+    "ewiger",
+    "ewiges",
+    "ewigkeit",
+    "ewigkeiten",
+    "ewiglich",
+    "exakte",
+    "exakten",
+    "excitatur",
+    "exekutive",
+    "exempel",
+    "exemplar",
+    "exerzieren",
+    "exerziermeistern",
+    "exil",
+    "existat",
+    "existentialsatz",
+    "existentiellen",
+    "existenz",
+    "existenzminimum",
+    "existiere",
+    "existieren",
+    "existierenden",
+    "existiert",
+    "existit",
+    "exklusi",
+    "exklusives",
+    "exkrementen",
+    "expedition",
+    "experientia",
+    "expertenkommission",
+    "explorare",
+    "explosion",
+    "explosiv",
+    "explosive",
+    "expressen",
+    "extrapostillion",
+    "extras",
+    "extraurlaub",
+    "exzellenz",
+    "ezw",
+    "eßt",
+    "eßzimmer",
+    "eßzimmers",
+    "f",
+    "fabelhafte",
+    "fabelhaftes",
+    "fabeln",
+    "fabrik",
+    "fabrikant",
+    "fabrikanten",
+    "fabriken",
+    "faciles",
+    "facit",
+    "fackel",
+    "fackeln",
+    "facto",
+    "faden",
+    "fadendünnen",
+    "fadenscheinigen",
+    "fades",
+    "fahl",
+    "fahlen",
+    "fahler",
+    "fahne",
+    "fahnen",
+    "fahr",
+    "fahren",
+    "fahrenden",
+    "fahrendes",
+    "fahrenzum",
+    "fahrer",
+    "fahrhabe",
+    "fahrst",
+    "fahrt",
+    "fahrten",
+    "fahrwasser",
+    "fahrweg",
+    "fahrzeit",
+    "fahrzeug",
+    "fahrzeugs",
+    "faktischen",
+    "faktotum",
+    "faktum",
+    "fakultät",
+    "falle",
+    "fallend",
+    "fallende",
+    "fallenden",
+    "falles",
+    "fallet",
+    "falliert",
+    "fallimentes",
+    "falliten",
+    "fallstrick",
+    "fallstricke",
+    "fallt",
+    "falsch",
+    "falsche",
+    "falschem",
+    "falschen",
+    "falscher",
+    "falsches",
+    "falschesten",
+    "falschheit",
+    "falschheiten",
+    "falte",
+    "falten",
+    "falter",
+    "faltete",
+    "falteten",
+    "faltigen",
+    "fameses",
+    "familie",
+    "familien",
+    "familienerbstück",
+    "familiengeschichte",
+    "familienglück",
+    "familienvater",
+    "familienverfolgung",
+    "famosen",
+    "fanatisierten",
+    "fanatizismus",
+    "fanchon",
+    "fand",
+    "fanden",
+    "fanfaren",
+    "fang",
+    "fangen",
+    "fanges",
+    "fangnetze",
+    "fangt",
+    "fantasie",
+    "fantastischen",
+    "farbe",
+    "farben",
+    "farbenwesen",
+    "farbigen",
+    "farbiges",
+    "farblosen",
+    "farbtupfer",
+    "fare",
+    "farnblättern",
+    "farnen",
+    "farren",
+    "fas",
+    "fasching",
+    "faselnackt",
+    "faseriges",
+    "fasern",
+    "fass",
+    "fasse",
+    "fassen",
+    "fasset",
+    "fasslich",
+    "fasst",
+    "fasste",
+    "fassten",
+    "fassung",
+    "fassungen",
+    "fassungskraft",
+    "fasten",
+    "fastenopfer",
+    "fastnacht",
+    "fastnachtslüge",
+    "fastnachtszeit",
+    "fasziniert",
+    "fatale",
+    "fatalen",
+    "fatum",
+    "fauchende",
+    "fauchte",
+    "faul",
+    "faule",
+    "faulenzen",
+    "fauler",
+    "faulheit",
+    "faust",
+    "faustgrosse",
+    "faustrechtes",
+    "fauteuil",
+    "fauteuils",
+    "faxen",
+    "fazit",
+    "faßlich",
+    "faßt",
+    "faßte",
+    "faßten",
+    "febr",
+    "februar",
+    "fecht",
+    "fechten",
+    "fechtenden",
+    "fechtkunst",
+    "fechtmeister",
+    "fechtschul",
+    "feder",
+    "federführung",
+    "federhüte",
+    "federkraft",
+    "federlesens",
+    "federn",
+    "fee",
+    "feenhafte",
+    "fegefeuer",
+    "fegenden",
+    "fegezeit",
+    "fehl",
+    "fehle",
+    "fehlen",
+    "fehler",
+    "fehlern",
+    "fehlete",
+    "fehlschlug",
+    "fehlschusses",
+    "fehlt",
+    "fehlte",
+    "fehlten",
+    "feier",
+    "feierabend",
+    "feiere",
+    "feierlich",
+    "feierliche",
+    "feierlichen",
+    "feierlicher",
+    "feierliches",
+    "feiern",
+    "feiert",
+    "feiertage",
+    "feiertagen",
+    "feiertagsgwand",
+    "feiertagsröcken",
+    "feierte",
+    "feierten",
+    "feig",
+    "feige",
+    "feigen",
+    "feigenbaum",
+    "feigenbaumes",
+    "feigenbaums",
+    "feigenblätter",
+    "feigenbäume",
+    "feigern",
+    "feigheit",
+    "feigling",
+    "feigwarzen",
+    "feilenstaub",
+    "fein",
+    "feind",
+    "feinde",
+    "feinden",
+    "feindes",
+    "feindin",
+    "feindlich",
+    "feindlichen",
+    "feindschaft",
+    "feindselig",
+    "feindseligen",
+    "feindseligkeit",
+    "feindseligkeiten",
+    "feine",
+    "feinem",
+    "feinen",
+    "feiner",
+    "feinere",
+    "feineren",
+    "feinern",
+    "feines",
+    "feinfühlend",
+    "feinschaft",
+    "feinschmecker",
+    "feinsten",
+    "feist",
+    "feistes",
+    "feisthirsch",
+    "feistzeit",
+    "feixt",
+    "feld",
+    "felde",
+    "felder",
+    "feldern",
+    "felderzeugnis",
+    "feldes",
+    "feldfrüchte",
+    "feldfutter",
+    "feldgeschrei",
+    "feldherde",
+    "feldherr",
+    "feldleutnant",
+    "feldprediger",
+    "feldrande",
+    "feldseite",
+    "feldsteinen",
+    "feldwebelsgang",
+    "feldweg",
+    "feldzug",
+    "feldzuges",
+    "fellchen",
+    "felle",
+    "felleisen",
+    "felleisenfuhrwerke",
+    "felleisens",
+    "fellen",
+    "fels",
+    "felsan",
+    "felsblock",
+    "felsblöcke",
+    "felsen",
+    "felsenkette",
+    "felsennase",
+    "felsentor",
+    "felsgestalten",
+    "felsgetürme",
+    "felskegel",
+    "felskolossen",
+    "felsmassen",
+    "felsnase",
+    "felsplateau",
+    "felsplatte",
+    "felsplatten",
+    "felssteine",
+    "felstisch",
+    "felswand",
+    "felswänden",
+    "felszacke",
+    "femgesehen",
+    "fenn",
+    "fenster",
+    "fensterbrett",
+    "fensterchen",
+    "fensterflügel",
+    "fenstern",
+    "fensternische",
+    "fensterrand",
+    "fensters",
+    "fensterscheiben",
+    "fenstersimse",
+    "fenstervorhänge",
+    "fergus",
+    "ferien",
+    "ferienjob",
+    "ferienstübchens",
+    "feris",
+    "ferkelgequiek",
+    "fern",
+    "fernab",
+    "ferne",
+    "fernem",
+    "fernen",
+    "ferner",
+    "fernere",
+    "ferneren",
+    "ferneres",
+    "fernerhin",
+    "fernes",
+    "fernher",
+    "fernrohr",
+    "fernrohrs",
+    "fernsehen",
+    "fernsichtige",
+    "fernsten",
+    "ferse",
+    "fersen",
+    "fertig",
+    "fertigbrachte",
+    "fertige",
+    "fertigen",
+    "fertiggestellt",
+    "fertigzubringen",
+    "fesseln",
+    "fesselte",
+    "fest",
+    "festband",
+    "feste",
+    "festem",
+    "festen",
+    "fester",
+    "festerem",
+    "festes",
+    "festgebannt",
+    "festgebauten",
+    "festgebunden",
+    "festgehalten",
+    "festgeklebt",
+    "festgeklemmt",
+    "festgelegt",
+    "festgemachte",
+    "festgesetzte",
+    "festgestellt",
+    "festgottesdienst",
+    "festhalten",
+    "festivitatum",
+    "festland",
+    "festlich",
+    "festlichen",
+    "festmal",
+    "festnahme",
+    "festnehmen",
+    "festpredigt",
+    "festsaale",
+    "festschmaus",
+    "festsetzt",
+    "festsetzte",
+    "festsetzten",
+    "feststehende",
+    "feststellen",
+    "feststellt",
+    "feststellung",
+    "festung",
+    "festungen",
+    "festzuhalten",
+    "festzusetzen",
+    "festzustellen",
+    "fett",
+    "fette",
+    "fetten",
+    "fetter",
+    "fettes",
+    "fettigkeit",
+    "fettigkeiten",
+    "fettwerden",
+    "fetzchen",
+    "fetzel",
+    "fetzen",
+    "feucht",
+    "feuchten",
+    "feuchtes",
+    "feuer",
+    "feuerbauche",
+    "feuerberg",
+    "feuerchen",
+    "feuerduft",
+    "feuerfarben",
+    "feuerflamme",
+    "feuerflammen",
+    "feuerglanz",
+    "feuerherde",
+    "feuerhölle",
+    "feuerkohlen",
+    "feuern",
+    "feuerofen",
+    "feuerpfuhl",
+    "feuerrot",
+    "feuerröte",
+    "feuers",
+    "feuersbrünsten",
+    "feuerschlacht",
+    "feuerschlange",
+    "feuerspeienden",
+    "feuerstrom",
+    "feuersäule",
+    "feuerung",
+    "feuerwaffen",
+    "feuerwehrmann",
+    "feuerwerk",
+    "feuerzungen",
+    "feurig",
+    "feurige",
+    "feurigem",
+    "feurigen",
+    "feuriges",
+    "feurigsten",
+    "fiber",
+    "fiberchen",
+    "fibern",
+    "ficht",
+    "fichten",
+    "fichtenast",
+    "fichtenbusch",
+    "fichtenbüschlein",
+    "fichtengebüsche",
+    "fichtengestrüpp",
+    "fichtenwipfel",
+    "fichtenzweigen",
+    "fidel",
+    "fieber",
+    "fieberhaft",
+    "fieberhaften",
+    "fiebernde",
+    "fiebernder",
+    "fieberte",
+    "fiedelmann",
+    "fiel",
+    "fiele",
+    "fielen",
+    "figur",
+    "figuren",
+    "figürchen",
+    "filiale",
+    "film",
+    "filmfestival",
+    "filmfirma",
+    "filmversion",
+    "filz",
+    "filzhütchen",
+    "filzkleider",
+    "finanziell",
+    "finanzielle",
+    "finanziellen",
+    "finanzieren",
+    "finanziert",
+    "finanzleistungen",
+    "finanzpolitisches",
+    "finanzstadt",
+    "finde",
+    "finden",
+    "findende",
+    "finder",
+    "findest",
+    "findet",
+    "findige",
+    "findt",
+    "fing",
+    "fingen",
+    "fingerabdruck",
+    "fingerchen",
+    "fingerhut",
+    "fingern",
+    "fingerspitzen",
+    "fingerstellung",
+    "fink",
+    "finken",
+    "finster",
+    "finstere",
+    "finsteren",
+    "finsteres",
+    "finsterm",
+    "finstern",
+    "finsternis",
+    "finsternissen",
+    "finten",
+    "fir",
+    "firlefanz",
+    "firma",
+    "firmen",
+    "firste",
+    "firti",
+    "fisch",
+    "fischblattern",
+    "fischchen",
+    "fische",
+    "fischeimer",
+    "fischen",
+    "fischer",
+    "fischerei",
+    "fischergeräte",
+    "fischern",
+    "fisches",
+    "fischesser",
+    "fischest",
+    "fischgattungen",
+    "fischlein",
+    "fittiche",
+    "fittichen",
+    "fittichs",
+    "fixe",
+    "fixen",
+    "flachen",
+    "flachs",
+    "flachses",
+    "flackernde",
+    "flackerten",
+    "fladenkuchen",
+    "flair",
+    "flamme",
+    "flammen",
+    "flammenbart",
+    "flammende",
+    "flammenden",
+    "flammendes",
+    "flammenmeer",
+    "flanke",
+    "flasche",
+    "flaschen",
+    "flats",
+    "flattergeräusch",
+    "flattern",
+    "flatternde",
+    "flatternden",
+    "flatterspiel",
+    "flatterte",
+    "flatterten",
+    "flaum",
+    "flaumkissen",
+    "flavio",
+    "flechte",
+    "flechtenfelde",
+    "flechtengetrümmer",
+    "fleck",
+    "fleckchen",
+    "flecken",
+    "fledermäuschen",
+    "flegelei",
+    "flehen",
+    "flehend",
+    "flehenden",
+    "flehens",
+    "flehet",
+    "fleht",
+    "flehte",
+    "fleisch",
+    "fleischbrühe",
+    "fleische",
+    "fleischer",
+    "fleischermesser",
+    "fleisches",
+    "fleischigem",
+    "fleischlich",
+    "fleischliche",
+    "fleischlichen",
+    "fleiss",
+    "fleissig",
+    "fleissigen",
+    "fleissiger",
+    "fleissigsten",
+    "fleiß",
+    "fleißig",
+    "fleißiger",
+    "fleißigsten",
+    "fletschte",
+    "flicht",
+    "flickte",
+    "fliederbusch",
+    "fliederwerk",
+    "fliege",
+    "fliegeliedchen",
+    "fliegen",
+    "fliegend",
+    "fliegende",
+    "fliegenden",
+    "fliegender",
+    "fliegenmutter",
+    "fliegt",
+    "fliehen",
+    "fliehet",
+    "flieht",
+    "fliese",
+    "fliesen",
+    "fliessen",
+    "fliessende",
+    "fliessendes",
+    "fliesst",
+    "fließen",
+    "fließt",
+    "flimmerte",
+    "flink",
+    "flinke",
+    "flinker",
+    "flinkfüssigen",
+    "flinten",
+    "flittchen",
+    "flittergoldes",
+    "flitterstaat",
+    "flocht",
+    "flochten",
+    "flocke",
+    "flocken",
+    "flog",
+    "flogen",
+    "floh",
+    "flohen",
+    "flor",
+    "florenz",
+    "florian",
+    "floss",
+    "flossen",
+    "flottmachen",
+    "floß",
+    "fluch",
+    "fluchen",
+    "fluchens",
+    "flucht",
+    "fluchte",
+    "fluchten",
+    "fluchtgedanken",
+    "fluchtgelder",
+    "fluchtriss",
+    "flug",
+    "fluge",
+    "fluggesellschaft",
+    "fluggästen",
+    "flughafen",
+    "flughafengebäude",
+    "flughafenpolizei",
+    "flughafens",
+    "flugmaschinchen",
+    "flugmaschinen",
+    "flugplatz",
+    "flugs",
+    "flugzeug",
+    "fluktuationsrate",
+    "flur",
+    "flure",
+    "fluren",
+    "fluss",
+    "flusse",
+    "flusses",
+    "flut",
+    "fluten",
+    "fluß",
+    "fläche",
+    "flächen",
+    "flächendeckend",
+    "flächsenen",
+    "flämmchen",
+    "fläschchen",
+    "flögen",
+    "flössen",
+    "flösste",
+    "flöte",
+    "flöten",
+    "flötenden",
+    "flötenspieler",
+    "flößen",
+    "flüche",
+    "flüchteten",
+    "flüchtig",
+    "flüchtige",
+    "flüchtigem",
+    "flüchtigen",
+    "flüchtiger",
+    "flüchtiges",
+    "flüchtigsten",
+    "flüchtling",
+    "flüchtlinge",
+    "flüchtlingscamp",
+    "flüchtlingslager",
+    "flüchtlingslagers",
+    "flügel",
+    "flügelchen",
+    "flügelein",
+    "flügelmann",
+    "flügeln",
+    "flügelschläge",
+    "flügeltüren",
+    "flügelzuge",
+    "flügge",
+    "flüggen",
+    "flüsse",
+    "flüssen",
+    "flüstern",
+    "flüsternde",
+    "flüstert",
+    "flüsterte",
+    "flüsterten",
+    "focht",
+    "focis",
+    "fodern",
+    "foemina",
+    "folg",
+    "folge",
+    "folgen",
+    "folgend",
+    "folgende",
+    "folgendem",
+    "folgenden",
+    "folgender",
+    "folgendermassen",
+    "folgendermaßen",
+    "folgendes",
+    "folgern",
+    "folgerung",
+    "folgerungen",
+    "folget",
+    "folgezeit",
+    "folglich",
+    "folgsam",
+    "folgsames",
+    "folgsamkeit",
+    "folgt",
+    "folgte",
+    "folgten",
+    "folie",
+    "folter",
+    "foltern",
+    "fonds",
+    "fontenelle",
+    "foppe",
+    "fordere",
+    "fordern",
+    "fordert",
+    "forderten",
+    "forderung",
+    "forderungen",
+    "forelle",
+    "forellen",
+    "foren",
+    "formalen",
+    "formaler",
+    "formalien",
+    "formationen",
+    "formel",
+    "formell",
+    "formelle",
+    "formeln",
+    "formen",
+    "formenwesens",
+    "formierte",
+    "formierten",
+    "formulieren",
+    "formulierung",
+    "forschen",
+    "forschend",
+    "forschende",
+    "forscherin",
+    "forschung",
+    "forschungen",
+    "forstgehilfen",
+    "forsthaus",
+    "forsthauses",
+    "forstmannsuniformen",
+    "forstmeister",
+    "forstverwaltung",
+    "fort",
+    "fortan",
+    "fortbestand",
+    "fortbestehen",
+    "fortbetrieb",
+    "fortbewegte",
+    "fortdauer",
+    "fortdauern",
+    "fortdauernde",
+    "fortdauernden",
+    "fortdauert",
+    "fortdauerten",
+    "fortfahren",
+    "fortfahrend",
+    "fortflog",
+    "fortfuhr",
+    "fortführte",
+    "fortgang",
+    "fortgange",
+    "fortgeführt",
+    "fortgegangen",
+    "fortgeh",
+    "fortgehen",
+    "fortgehend",
+    "fortgehender",
+    "fortgejagt",
+    "fortgeleitet",
+    "fortgepflanzt",
+    "fortgepustet",
+    "fortgeritten",
+    "fortgerückt",
+    "fortgesetzt",
+    "fortgesetzte",
+    "fortgesetzten",
+    "fortgetrieben",
+    "fortgeworfen",
+    "fortgezogen",
+    "fortging",
+    "fortkomme",
+    "fortkommen",
+    "fortlassen",
+    "fortlaufend",
+    "fortlaufende",
+    "fortlaufenden",
+    "fortleben",
+    "fortlebend",
+    "fortpflanzung",
+    "fortpflanzungen",
+    "fortreisst",
+    "fortreißt",
+    "fortriss",
+    "fortriß",
+    "fortschickte",
+    "fortschreite",
+    "fortschreiten",
+    "fortschreitend",
+    "fortschreitende",
+    "fortschreitens",
+    "fortschreitet",
+    "fortschreitung",
+    "fortschritt",
+    "fortschritte",
+    "fortschritten",
+    "fortsetzen",
+    "fortsetzte",
+    "fortsetzung",
+    "forttragen",
+    "forttreiben",
+    "fortuna",
+    "fortwarf",
+    "fortwuchern",
+    "fortwährend",
+    "fortwährende",
+    "fortwährenden",
+    "fortwährender",
+    "fortwährendes",
+    "fortzeugen",
+    "fortzog",
+    "fortzubewegen",
+    "fortzureissen",
+    "fortzuscheuchen",
+    "fortzusetzen",
+    "forum",
+    "forumein",
+    "fotografiert",
+    "fotomodell",
+    "fotos",
+    "foyer",
+    "frack",
+    "frag",
+    "frage",
+    "fragebogen",
+    "fragen",
+    "fragend",
+    "fraget",
+    "fragliche",
+    "fraglichen",
+    "fragsucht",
+    "fragt",
+    "fragte",
+    "fragten",
+    "frank",
+    "franken",
+    "frankfurt",
+    "frankierten",
+    "frankreich",
+    "frankreichs",
+    "fransen",
+    "franz",
+    "franzens",
+    "franziskus",
+    "franzl",
+    "franzosen",
+    "französisch",
+    "französische",
+    "französischen",
+    "französischer",
+    "françois",
+    "frass",
+    "frassen",
+    "frau",
+    "frauchen",
+    "frauen",
+    "frauenbild",
+    "frauenbund",
+    "frauenbusen",
+    "frauenfahrzeug",
+    "frauengemütes",
+    "frauengesichter",
+    "frauenhilfe",
+    "frauenkirche",
+    "frauenkleider",
+    "frauenmund",
+    "frauennamen",
+    "frauensarg",
+    "frauensleute",
+    "frauensperson",
+    "frauenstimmen",
+    "frauentums",
+    "frauenzimmer",
+    "frauenzimmerchen",
+    "frauenzimmern",
+    "frauenzimmers",
+    "fraßen",
+    "freakszene",
+    "frech",
+    "freche",
+    "frechen",
+    "frecher",
+    "freches",
+    "frechheit",
+    "frechheiten",
+    "frechste",
+    "frechstem",
+    "frechsten",
+    "frei",
+    "freiblieb",
+    "freiburg",
+    "freiburger",
+    "freie",
+    "freiem",
+    "freien",
+    "freier",
+    "freiern",
+    "freiersleuten",
+    "freies",
+    "freigebe",
+    "freigeben",
+    "freigebig",
+    "freigebiger",
+    "freigegeben",
+    "freigelassen",
+    "freiheit",
+    "freiheiten",
+    "freiheitliche",
+    "freilassen",
+    "freilich",
+    "freiliessen",
+    "freiließen",
+    "freimanns",
+    "freimannsenkelin",
+    "freimannshäusl",
+    "freimännin",
+    "freischaren",
+    "freischärler",
+    "freisinnig",
+    "freisinnigen",
+    "freisinnigkeit",
+    "freitag",
+    "freitreppe",
+    "freiwillig",
+    "freiwillige",
+    "freiwilligem",
+    "freiwilligen",
+    "freiwilliger",
+    "freiwilliges",
+    "freiwilligkeit",
+    "freizulassen",
+    "freizustellen",
+    "freizügern",
+    "frelmännin",
+    "fremd",
+    "fremdartig",
+    "fremdartigen",
+    "fremdartiger",
+    "fremdartigkeit",
+    "fremde",
+    "fremden",
+    "fremder",
+    "fremdes",
+    "fremdling",
+    "fremdlinge",
+    "freskomalereien",
+    "fress",
+    "fresse",
+    "fressen",
+    "fresser",
+    "freud",
+    "freude",
+    "freuden",
+    "freudenberg",
+    "freudenfest",
+    "freudenreichen",
+    "freudenruf",
+    "freudenrufs",
+    "freudenschüsse",
+    "freudentage",
+    "freudentanz",
+    "freudenähnliches",
+    "freudestrahlend",
+    "freudig",
+    "freudigem",
+    "freudigen",
+    "freudiger",
+    "freudigkeit",
+    "freue",
+    "freuen",
+    "freuend",
+    "freuet",
+    "freuete",
+    "freund",
+    "freunde",
+    "freunden",
+    "freundes",
+    "freundesarme",
+    "freundin",
+    "freundinnen",
+    "freundlich",
+    "freundliche",
+    "freundlichem",
+    "freundlichen",
+    "freundlicher",
+    "freundliches",
+    "freundlichkeit",
+    "freundlichste",
+    "freundnachbarliche",
+    "freundschaft",
+    "freundschaftlich",
+    "freundschaftlichen",
+    "freut",
+    "freute",
+    "freuten",
+    "frevel",
+    "frevelhaft",
+    "frevels",
+    "fridolin",
+    "fridolins",
+    "friede",
+    "frieden",
+    "friedens",
+    "friedensbewegung",
+    "friedensbruch",
+    "friedensbund",
+    "friedensopfers",
+    "friedensprodukte",
+    "friedensrichter",
+    "friedensruhe",
+    "friedenszeiten",
+    "friedfertig",
+    "friedfertigen",
+    "friedfertiges",
+    "friedfertigkeit",
+    "friedhofscafe",
+    "friedhofsmauer",
+    "friedlich",
+    "friedliche",
+    "friedlichen",
+    "friedliches",
+    "friedlichsten",
+    "friedliebende",
+    "friedrich",
+    "frierenden",
+    "friert",
+    "friher",
+    "frisch",
+    "frischduftende",
+    "frische",
+    "frischen",
+    "frischer",
+    "frisches",
+    "frisierten",
+    "friss",
+    "frissest",
+    "frisst",
+    "frist",
+    "fristen",
+    "fristeten",
+    "fritz",
+    "fritzchen",
+    "fritzens",
+    "frißt",
+    "froh",
+    "frohe",
+    "frohen",
+    "frohlocken",
+    "frohsinn",
+    "fromm",
+    "fromme",
+    "frommen",
+    "frommer",
+    "frommes",
+    "frommt",
+    "fronen",
+    "fronten",
+    "frontpage",
+    "fronvögte",
+    "froren",
+    "frosch",
+    "froscharme",
+    "froschmaul",
+    "froschmäulig",
+    "frost",
+    "froste",
+    "frucht",
+    "fruchtbar",
+    "fruchtbaren",
+    "fruchtbarer",
+    "fruchtbaum",
+    "fruchten",
+    "fruchtlos",
+    "fruchtsaft",
+    "frumm",
+    "frummen",
+    "frustra",
+    "fräulein",
+    "fräuleins",
+    "fröhlich",
+    "fröhliche",
+    "fröhlichen",
+    "fröhlicher",
+    "fröhliches",
+    "fröhlichkeit",
+    "fröhlichste",
+    "fröhlichsten",
+    "frömmigkeit",
+    "frömmlichen",
+    "frönen",
+    "frönte",
+    "frösche",
+    "fröschen",
+    "fröstelte",
+    "früchte",
+    "früchten",
+    "früh",
+    "frühaufsteher",
+    "frühe",
+    "frühen",
+    "früher",
+    "frühere",
+    "früheren",
+    "früherer",
+    "früheres",
+    "frühesten",
+    "frühester",
+    "frühjahr",
+    "frühleben",
+    "frühlicht",
+    "frühling",
+    "frühlings",
+    "frühlingsanfang",
+    "frühlingsbäche",
+    "frühlingshimmel",
+    "frühlingslebens",
+    "frühlingslicht",
+    "frühlingslied",
+    "frühlingslüftlein",
+    "frühlingsmorgen",
+    "frühlingsritt",
+    "frühlingsritter",
+    "frühlingssonne",
+    "frühlingssonnenwelt",
+    "frühlingstag",
+    "frühlingstage",
+    "frühlingstagen",
+    "frühlingstages",
+    "frühlingswärme",
+    "frühpirsch",
+    "frühreifen",
+    "frührot",
+    "frühschoppen",
+    "frühstück",
+    "frühstücken",
+    "frühstücks",
+    "frühstücksei",
+    "frühstücksgeschirr",
+    "frühwolken",
+    "fuchs",
+    "fuchsen",
+    "fuchsriegeln",
+    "fuchtelnd",
+    "fuchtelte",
+    "fuerunt",
+    "fufzg",
+    "fuhr",
+    "fuhre",
+    "fuhren",
+    "fuhrknecht",
+    "fuhrleute",
+    "fuhrwerk",
+    "fuhrwerke",
+    "fuhrwerklein",
+    "fummelt",
+    "fundament",
+    "fundamente",
+    "funden",
+    "fundenseetauern",
+    "fundiert",
+    "fungiert",
+    "funk",
+    "funkelhelle",
+    "funkeln",
+    "funkelnden",
+    "funkelte",
+    "funkelten",
+    "funken",
+    "funkendampf",
+    "funkenknopf",
+    "funkhaus",
+    "funkhauses",
+    "funkleute",
+    "funktion",
+    "funktionen",
+    "funktionieren",
+    "funktionierende",
+    "funktionierte",
+    "fur",
+    "furche",
+    "furchen",
+    "furcht",
+    "furchtbar",
+    "furchtbare",
+    "furchtbarem",
+    "furchtbaren",
+    "furchtbarste",
+    "furchtlosigkeit",
+    "furchtsam",
+    "furchtsamen",
+    "furchtsames",
+    "furt",
+    "furtghadert",
+    "fuss",
+    "fussball",
+    "fussballteam",
+    "fussballverein",
+    "fussboden",
+    "fusse",
+    "fussende",
+    "fusses",
+    "fussgänger",
+    "fusspfad",
+    "fussschemelchen",
+    "fusssohle",
+    "fusssohlen",
+    "fussspitze",
+    "fussspitzen",
+    "fusstritt",
+    "fusstritten",
+    "futter",
+    "futterbarren",
+    "fuß",
+    "fußboden",
+    "fuße",
+    "fußes",
+    "fußsohle",
+    "fußsohlen",
+    "fußspitze",
+    "fußspitzen",
+    "fächern",
+    "fäden",
+    "fähig",
+    "fähiger",
+    "fähigkeit",
+    "fähigkeiten",
+    "fähnlein",
+    "fähnleins",
+    "fährlichkeit",
+    "fährt",
+    "fährte",
+    "fährten",
+    "fälle",
+    "fällen",
+    "fällt",
+    "fälschlich",
+    "fälschungen",
+    "fände",
+    "fängst",
+    "fängt",
+    "färbende",
+    "fässchen",
+    "fässer",
+    "fäustchen",
+    "fäuste",
+    "fäusten",
+    "föhnstiefel",
+    "föhre",
+    "föhrenstrunke",
+    "förderer",
+    "fördern",
+    "förmig",
+    "förmlich",
+    "förmliche",
+    "förmlichen",
+    "förmlichkeit",
+    "förster",
+    "försterbart",
+    "försterin",
+    "füchs",
+    "füchse",
+    "füchsin",
+    "füge",
+    "fügen",
+    "fügt",
+    "fügte",
+    "fügung",
+    "fühlbar",
+    "fühlbare",
+    "fühle",
+    "fühlen",
+    "fühlend",
+    "fühlenden",
+    "fühlerhörnchen",
+    "fühlern",
+    "fühlhörnchen",
+    "fühlhörner",
+    "fühllosen",
+    "fühllosigkeit",
+    "fühlst",
+    "fühlt",
+    "fühlte",
+    "fühlten",
+    "führe",
+    "führen",
+    "führende",
+    "führenden",
+    "führer",
+    "führerschein",
+    "führest",
+    "führet",
+    "führete",
+    "führt",
+    "führte",
+    "führten",
+    "führung",
+    "führungsleute",
+    "führungszeugnis",
+    "füll",
+    "fülle",
+    "füllen",
+    "füllenden",
+    "füllet",
+    "füllfederhalter",
+    "füllt",
+    "füllte",
+    "füllten",
+    "fünf",
+    "fünfbeiniges",
+    "fünfe",
+    "fünfen",
+    "fünfer",
+    "fünfhundert",
+    "fünfhundertmarkscheine",
+    "fünfhundertmarkscheinen",
+    "fünfjähriges",
+    "fünfte",
+    "fünftelminute",
+    "fünften",
+    "fünftes",
+    "fünfunddreissigjährigen",
+    "fünfunddreißigmal",
+    "fünfundneunzig",
+    "fünfundsechzig",
+    "fünfundvierziger",
+    "fünfundzwanzig",
+    "fünfzehn",
+    "fünfzehnmal",
+    "fünfzehnten",
+    "fünfzig",
+    "fünfziger",
+    "fünfzigjährigen",
+    "fünfzigsten",
+    "fünkchen",
+    "für",
+    "fürbass",
+    "fürbaß",
+    "fürbittbuch",
+    "fürbitte",
+    "fürbittgebet",
+    "fürbringen",
+    "fürcht",
+    "fürchte",
+    "fürchten",
+    "fürchtende",
+    "fürchtenden",
+    "fürchterlich",
+    "fürchterliche",
+    "fürchterlichen",
+    "fürchterliches",
+    "fürchtet",
+    "fürchtete",
+    "fürchteten",
+    "fürder",
+    "fürdere",
+    "füreinander",
+    "fürgschützt",
+    "fürgstreckt",
+    "fürnehmere",
+    "fürplärren",
+    "fürs",
+    "fürschlag",
+    "fürsichtige",
+    "fürst",
+    "fürsten",
+    "fürstenhochzeit",
+    "fürstenmantel",
+    "fürstentodes",
+    "fürstlich",
+    "fürstlichen",
+    "fürstpröpstlichen",
+    "fürwahr",
+    "fürwahrhalten",
+    "fürwahrhaltens",
+    "füss",
+    "füsschen",
+    "füsse",
+    "füssen",
+    "füttere",
+    "füttern",
+    "füttert",
+    "füße",
+    "füßen",
+    "g",
+    "gab",
+    "gabe",
+    "gabel",
+    "gabelhirsch",
+    "gaben",
+    "gackernden",
+    "gad",
+    "gaffen",
+    "gaffende",
+    "gaffer",
+    "gahlings",
+    "galantinen",
+    "galerie",
+    "galgen",
+    "galgenhund",
+    "galgenlaune",
+    "galion",
+    "galions",
+    "galle",
+    "gallen",
+    "galler",
+    "gallige",
+    "galligen",
+    "galopp",
+    "galoppierte",
+    "galt",
+    "galten",
+    "gamsbart",
+    "gan",
+    "gang",
+    "gangbar",
+    "gangbaren",
+    "gange",
+    "gangen",
+    "gans",
+    "ganz",
+    "ganze",
+    "ganzem",
+    "ganzen",
+    "ganzer",
+    "ganzes",
+    "gar",
+    "garantie",
+    "garaus",
+    "garben",
+    "garderobe",
+    "garderobestücken",
+    "gardine",
+    "gardinen",
+    "gardinenstange",
+    "gare",
+    "garn",
+    "garne",
+    "garnflechten",
+    "garniert",
+    "garnsbock",
+    "garstig",
+    "garstige",
+    "garstiger",
+    "garstiges",
+    "garten",
+    "garteng",
+    "gartenhäuschen",
+    "gartenmitte",
+    "gartens",
+    "gartenwege",
+    "gartenzaun",
+    "gasse",
+    "gassen",
+    "gassenbuben",
+    "gassenjunge",
+    "gassenjungen",
+    "gassenmädchen",
+    "gast",
+    "gaste",
+    "gastes",
+    "gastfreunde",
+    "gastfreundlichen",
+    "gastgebenden",
+    "gastgeber",
+    "gastgebers",
+    "gasthaus",
+    "gasthause",
+    "gasthauses",
+    "gasthof",
+    "gasthofe",
+    "gasthofes",
+    "gastliche",
+    "gastlichen",
+    "gastmahle",
+    "gastspiel",
+    "gaststube",
+    "gaststuben",
+    "gastwirt",
+    "gates",
+    "gatte",
+    "gatten",
+    "gattentreue",
+    "gattin",
+    "gattung",
+    "gattungen",
+    "gattungs",
+    "gau",
+    "gaukelei",
+    "gaumen",
+    "gazegewänder",
+    "gazeschleier",
+    "gb",
+    "ge",
+    "geachtet",
+    "geahnt",
+    "gealterten",
+    "gealttätige",
+    "geantwortet",
+    "gearbeitet",
+    "gearbeiteten",
+    "geartet",
+    "geb",
+    "gebacken",
+    "gebackene",
+    "gebackenen",
+    "gebadet",
+    "gebahnt",
+    "geballt",
+    "geballte",
+    "gebar",
+    "gebauet",
+    "gebaumelt",
+    "gebaut",
+    "gebaute",
+    "gebe",
+    "gebein",
+    "gebeine",
+    "gebeinen",
+    "gebell",
+    "gebellt",
+    "geben",
+    "gebend",
+    "gebessert",
+    "gebest",
+    "gebet",
+    "gebetbuch",
+    "gebete",
+    "gebeten",
+    "gebetet",
+    "gebetläuten",
+    "gebetskapelle",
+    "gebettet",
+    "gebeugt",
+    "gebeugte",
+    "gebeugtes",
+    "gebiert",
+    "gebiet",
+    "gebiete",
+    "gebieten",
+    "gebietenden",
+    "gebieter",
+    "gebieterin",
+    "gebieterisch",
+    "gebietes",
+    "gebietet",
+    "gebilde",
+    "gebilden",
+    "gebildet",
+    "gebildeten",
+    "gebildeter",
+    "gebirg",
+    "gebirge",
+    "gebirges",
+    "gebirgsflecken",
+    "gebirgskräutermilch",
+    "gebirgsweib",
+    "gebirgswildnis",
+    "gebissen",
+    "geblendet",
+    "geblickt",
+    "geblieben",
+    "gebliebener",
+    "geblähten",
+    "gebläuter",
+    "geblüt",
+    "geblüte",
+    "geblütes",
+    "gebogen",
+    "gebogenen",
+    "gebohntem",
+    "geboren",
+    "geborene",
+    "geborener",
+    "geborgen",
+    "geborgene",
+    "geborgt",
+    "gebot",
+    "gebote",
+    "geboten",
+    "gebotene",
+    "gebotenen",
+    "gebots",
+    "gebrach",
+    "gebracht",
+    "gebrachte",
+    "gebrannt",
+    "gebraten",
+    "gebratene",
+    "gebratenen",
+    "gebrauch",
+    "gebrauche",
+    "gebrauchen",
+    "gebrauches",
+    "gebrauchs",
+    "gebraucht",
+    "gebrauchte",
+    "gebrauchten",
+    "gebreitet",
+    "gebremst",
+    "gebricht",
+    "gebrochen",
+    "gebrochener",
+    "gebrumm",
+    "gebrummt",
+    "gebräuche",
+    "gebräuchen",
+    "gebräuchlich",
+    "gebräuchlicher",
+    "gebräunte",
+    "gebräunten",
+    "gebräuntes",
+    "gebrüll",
+    "gebt",
+    "gebumst",
+    "gebunden",
+    "gebundenen",
+    "gebundenes",
+    "geburt",
+    "geburten",
+    "geburtsorte",
+    "geburtsschein",
+    "geburtstag",
+    "geburtstage",
+    "geburtstages",
+    "geburtstags",
+    "gebälks",
+    "gebärde",
+    "gebärden",
+    "gebärdend",
+    "gebärdenden",
+    "gebärdenspiel",
+    "gebärdet",
+    "gebärdete",
+    "gebären",
+    "gebärerin",
+    "gebäude",
+    "gebückt",
+    "gebühr",
+    "gebührend",
+    "gebührt",
+    "gebührte",
+    "gebürsteten",
+    "gebürtig",
+    "gebüsch",
+    "gebüsche",
+    "gebüsst",
+    "gedacht",
+    "gedachte",
+    "gedachten",
+    "gedanke",
+    "gedanken",
+    "gedankenaustausch",
+    "gedankenform",
+    "gedankengang",
+    "gedankenlos",
+    "gedankenlose",
+    "gedankenlosen",
+    "gedankenlosigkeit",
+    "gedankenplanes",
+    "gedankenreichen",
+    "gedankenreihen",
+    "gedankens",
+    "gedankenspielen",
+    "gedankensprung",
+    "gedankenspäne",
+    "gedeckt",
+    "gedeckten",
+    "gedehnt",
+    "gedeihen",
+    "gedeihens",
+    "gedeihliche",
+    "gedeihlichste",
+    "gedeiht",
+    "gedemütigt",
+    "gedenk",
+    "gedenke",
+    "gedenken",
+    "gedenket",
+    "gedenkfeiern",
+    "gedenkmedaille",
+    "gedenkst",
+    "gedenkt",
+    "gedenktag",
+    "gedicht",
+    "gedichten",
+    "gediegenes",
+    "gediegne",
+    "gedieh",
+    "gediehen",
+    "gedient",
+    "gediente",
+    "gedonnerkracht",
+    "gedreht",
+    "gedrehter",
+    "gedruckt",
+    "gedruckten",
+    "gedrucktes",
+    "gedränge",
+    "gedrängt",
+    "gedrückt",
+    "gedrücktes",
+    "gedrücktheit",
+    "geduld",
+    "geduldet",
+    "geduldig",
+    "geduldigen",
+    "geduldspiel",
+    "geduscht",
+    "gedächten",
+    "gedächtnis",
+    "gedächtnisglauben",
+    "gedächtnisse",
+    "gedächtnissen",
+    "gedächtnisses",
+    "gedämpft",
+    "gedämpftes",
+    "gedörrt",
+    "gedörrte",
+    "gedörrten",
+    "geehrt",
+    "geehrtam",
+    "geehrte",
+    "geehrten",
+    "geehrter",
+    "geeignet",
+    "geeigneten",
+    "geeigneter",
+    "geeilt",
+    "geeinten",
+    "geendet",
+    "geendigt",
+    "geerbt",
+    "geerntet",
+    "gefahr",
+    "gefahren",
+    "gefahrlos",
+    "gefallen",
+    "gefaltet",
+    "gefalteten",
+    "gefangen",
+    "gefangene",
+    "gefangenen",
+    "gefangener",
+    "gefangennahme",
+    "gefangennehmung",
+    "gefangenschaft",
+    "gefangenschaften",
+    "gefasst",
+    "gefasste",
+    "gefassten",
+    "gefastet",
+    "gefaßt",
+    "gefecht",
+    "gefechtlärmens",
+    "gefegt",
+    "gefehlt",
+    "gefeiert",
+    "gefeierte",
+    "gefeit",
+    "gefertigt",
+    "gefesselt",
+    "gefiel",
+    "gefilde",
+    "gefilmt",
+    "gefilzt",
+    "geflammten",
+    "geflecht",
+    "geflochten",
+    "geflogen",
+    "geflohen",
+    "geflüchtet",
+    "geflügelte",
+    "geflügelten",
+    "gefolge",
+    "gefolgt",
+    "gefordert",
+    "geformt",
+    "geformte",
+    "geformten",
+    "geformtes",
+    "gefragt",
+    "gefressen",
+    "gefrorener",
+    "gefrühstückt",
+    "gefunden",
+    "gefundenen",
+    "gefundenes",
+    "gefurchte",
+    "gefurchten",
+    "gefährde",
+    "gefährdet",
+    "gefährdung",
+    "gefährlich",
+    "gefährliche",
+    "gefährlichen",
+    "gefährlicher",
+    "gefährliches",
+    "gefährlichkeit",
+    "gefährt",
+    "gefährte",
+    "gefährten",
+    "gefährtin",
+    "gefällig",
+    "gefälligkeit",
+    "gefälligst",
+    "gefällt",
+    "gefälschte",
+    "gefälseht",
+    "gefängnis",
+    "gefängnisses",
+    "gefärbt",
+    "gefärbte",
+    "gefärbten",
+    "gefäss",
+    "gefässe",
+    "gefässen",
+    "gefäß",
+    "gefäße",
+    "gefäßen",
+    "gefördert",
+    "gefühl",
+    "gefühle",
+    "gefühlen",
+    "gefühllos",
+    "gefühllosigkeit",
+    "gefühls",
+    "gefühlt",
+    "gefühlvoller",
+    "geführet",
+    "geführt",
+    "gefüllt",
+    "gefüllte",
+    "gefürchtet",
+    "gefürchtete",
+    "gefüttert",
+    "gegangen",
+    "gegangenen",
+    "gegeben",
+    "gegebene",
+    "gegebenen",
+    "gegebenheiten",
+    "gegen",
+    "gegenargument",
+    "gegenbild",
+    "gegend",
+    "gegenden",
+    "gegeneinander",
+    "gegenfinanzierung",
+    "gegengewichts",
+    "gegengründe",
+    "gegenliebe",
+    "gegensatz",
+    "gegensatze",
+    "gegenseitig",
+    "gegenseitige",
+    "gegenseitigkeit",
+    "gegenstand",
+    "gegenstande",
+    "gegenstandes",
+    "gegenstände",
+    "gegenständen",
+    "gegensätze",
+    "gegensätzen",
+    "gegenteil",
+    "gegenwart",
+    "gegenwehr",
+    "gegenwirkung",
+    "gegenwärtig",
+    "gegenwärtige",
+    "gegenwärtigem",
+    "gegenwärtigen",
+    "gegenwärtiger",
+    "gegenwärtigeren",
+    "gegenüber",
+    "gegenüberliegende",
+    "gegenüberliegenden",
+    "gegenüberstanden",
+    "gegenüberstehende",
+    "gegessen",
+    "gegewelt",
+    "geglaubt",
+    "geglänzt",
+    "geglättete",
+    "geglätteten",
+    "geglückt",
+    "geglückte",
+    "geglückten",
+    "geglüht",
+    "gegner",
+    "gegnerische",
+    "gegnern",
+    "gegners",
+    "gegolten",
+    "gegraben",
+    "gegröl",
+    "gegrübelt",
+    "gegründet",
+    "gegründeten",
+    "gegrüsst",
+    "geguckt",
+    "gegönnt",
+    "geh",
+    "gehabt",
+    "gehaftet",
+    "gehalt",
+    "gehalten",
+    "gehaltene",
+    "gehaltenes",
+    "gehaltlosigkeiten",
+    "gehalts",
+    "gehaltvollste",
+    "gehandelt",
+    "gehangen",
+    "geharrt",
+    "gehasst",
+    "gehassten",
+    "gehaucht",
+    "gehauen",
+    "gehauenen",
+    "gehaust",
+    "gehaßt",
+    "gehe",
+    "geheftet",
+    "gehefteten",
+    "gehege",
+    "geheiliget",
+    "geheiligt",
+    "geheiligte",
+    "geheilt",
+    "geheim",
+    "geheime",
+    "geheimen",
+    "geheimer",
+    "geheimes",
+    "geheimnis",
+    "geheimnisse",
+    "geheimnissen",
+    "geheimnisses",
+    "geheimnisvoll",
+    "geheimnisvolle",
+    "geheimnisvollen",
+    "geheimnisvoller",
+    "geheimnisvolles",
+    "geheimnisvollste",
+    "geheimsten",
+    "geheiratet",
+    "geheissen",
+    "geheizt",
+    "geheißen",
+    "gehemmt",
+    "gehen",
+    "gehend",
+    "gehenden",
+    "gehenkter",
+    "gehet",
+    "gehetzt",
+    "gehetzten",
+    "geheuer",
+    "gehilfe",
+    "gehilfenprüfung",
+    "gehindert",
+    "gehirn",
+    "gehirne",
+    "gehirns",
+    "gehn",
+    "gehoben",
+    "gehofft",
+    "gehoffte",
+    "geholfen",
+    "geholt",
+    "gehorchen",
+    "gehorchend",
+    "gehorchet",
+    "gehorchst",
+    "gehorcht",
+    "gehorchten",
+    "gehorsam",
+    "gehrock",
+    "gehröcken",
+    "gehst",
+    "geht",
+    "gehuret",
+    "gehuscht",
+    "gehänge",
+    "gehängen",
+    "gehängt",
+    "gehängten",
+    "gehänselt",
+    "gehässigkeiten",
+    "gehätschelt",
+    "gehäuft",
+    "gehäufte",
+    "gehäuften",
+    "gehöften",
+    "gehölz",
+    "gehölze",
+    "gehölzes",
+    "gehör",
+    "gehöre",
+    "gehören",
+    "gehörig",
+    "gehörige",
+    "gehörigen",
+    "gehöriger",
+    "gehöriges",
+    "gehörnte",
+    "gehörs",
+    "gehörst",
+    "gehört",
+    "gehörte",
+    "gehörten",
+    "gehörtest",
+    "gehüllt",
+    "gehütet",
+    "geige",
+    "geigelein",
+    "geigen",
+    "geigender",
+    "geigensumsemann",
+    "geiger",
+    "geigers",
+    "geigte",
+    "geirrt",
+    "geiss",
+    "geissel",
+    "geist",
+    "geiste",
+    "geister",
+    "geisterblaue",
+    "geistergesellschaft",
+    "geistergrauen",
+    "geisterheere",
+    "geistern",
+    "geisterrede",
+    "geistersprache",
+    "geisterwelt",
+    "geistes",
+    "geistesgaben",
+    "geistesgegenwart",
+    "geisteskranke",
+    "geisteskräfte",
+    "geistestalente",
+    "geistesübung",
+    "geistig",
+    "geistige",
+    "geistigem",
+    "geistigen",
+    "geistiger",
+    "geistigeren",
+    "geistigeres",
+    "geistiges",
+    "geistigkeit",
+    "geistigste",
+    "geistigsten",
+    "geistlich",
+    "geistliche",
+    "geistlichen",
+    "geistlichkeit",
+    "geistlose",
+    "geistreich",
+    "geistreiches",
+    "geistreichste",
+    "geiz",
+    "geißel",
+    "gejagt",
+    "gekannt",
+    "gekauft",
+    "gekauften",
+    "gekehrt",
+    "gekeltert",
+    "gekerbt",
+    "gekitzelt",
+    "geklagt",
+    "geklammert",
+    "geklappert",
+    "geklaut",
+    "geklebt",
+    "geklebten",
+    "gekleidet",
+    "gekleideten",
+    "gekleidetes",
+    "geklemmt",
+    "geklettert",
+    "gekläff",
+    "gekneift",
+    "geknurre",
+    "gekocht",
+    "gekommen",
+    "gekonnt",
+    "gekostet",
+    "gekreisst",
+    "gekreißt",
+    "gekreuzigt",
+    "gekreuzt",
+    "gekreuzten",
+    "gekriegt",
+    "gekränkt",
+    "gekräuselte",
+    "gekrönt",
+    "gekrönten",
+    "gekämmt",
+    "gekämpft",
+    "geköpft",
+    "gekühlt",
+    "gelacht",
+    "geladen",
+    "geladenen",
+    "gelage",
+    "gelagen",
+    "gelagerten",
+    "gelandet",
+    "gelang",
+    "gelangen",
+    "gelangest",
+    "gelangt",
+    "gelangte",
+    "gelangten",
+    "gelangung",
+    "gelangweilt",
+    "gelass",
+    "gelassen",
+    "gelassenen",
+    "gelassener",
+    "gelassenheit",
+    "gelaufe",
+    "gelaufen",
+    "gelaunt",
+    "gelauscht",
+    "gelb",
+    "gelbe",
+    "gelben",
+    "gelber",
+    "gelbes",
+    "gelbleuchtende",
+    "gelblich",
+    "geld",
+    "geldabfluss",
+    "geldaufzählens",
+    "geldbeutel",
+    "geldbussen",
+    "geldbörse",
+    "geldbürgschaft",
+    "geldchen",
+    "gelde",
+    "gelder",
+    "geldes",
+    "geldgier",
+    "geldklemme",
+    "geldmachen",
+    "geldmittel",
+    "geldmitteln",
+    "geldmännern",
+    "geldnöten",
+    "geldstück",
+    "geldsummen",
+    "geldtransfer",
+    "gelebt",
+    "geleckten",
+    "geleert",
+    "gelees",
+    "gelegen",
+    "gelegenen",
+    "gelegenheit",
+    "gelegenheiten",
+    "gelegentlich",
+    "gelegentliche",
+    "gelegt",
+    "gelegtes",
+    "gelehnt",
+    "gelehret",
+    "gelehrsamkeit",
+    "gelehrt",
+    "gelehrte",
+    "gelehrten",
+    "gelehrter",
+    "gelehrteste",
+    "geleistet",
+    "geleit",
+    "geleitet",
+    "geleitete",
+    "gelenkchen",
+    "gelenken",
+    "gelenkige",
+    "gelenkt",
+    "gelernt",
+    "gelesen",
+    "geleuchtet",
+    "geleugnet",
+    "geliebet",
+    "geliebt",
+    "geliebte",
+    "geliebten",
+    "geliebter",
+    "geliebtesten",
+    "geliefert",
+    "geliehen",
+    "gelinde",
+    "gelinden",
+    "gelindert",
+    "gelindes",
+    "gelindesten",
+    "gelingen",
+    "gelingens",
+    "gelingt",
+    "gelitten",
+    "gellend",
+    "gellenden",
+    "gellte",
+    "gelobet",
+    "gelobt",
+    "gelobte",
+    "gelockt",
+    "gelogen",
+    "gelt",
+    "gelte",
+    "gelten",
+    "geltend",
+    "geltende",
+    "geltenden",
+    "geltendmachen",
+    "geltens",
+    "gelungen",
+    "gelungene",
+    "gelungenen",
+    "gelächelt",
+    "gelächter",
+    "gelächters",
+    "gelähmte",
+    "gelähmten",
+    "gelände",
+    "geländer",
+    "gelänge",
+    "geläute",
+    "geläutet",
+    "gelöscht",
+    "gelöst",
+    "gelübde",
+    "gelübden",
+    "gelüste",
+    "gelüsten",
+    "gelüstig",
+    "gelüstigkeit",
+    "gemach",
+    "gemachs",
+    "gemacht",
+    "gemachten",
+    "gemachter",
+    "gemahl",
+    "gemahle",
+    "gemahlin",
+    "gemahnt",
+    "gemahnte",
+    "gemalt",
+    "gemalten",
+    "gemarterten",
+    "gemecker",
+    "gemehret",
+    "gemein",
+    "gemeinde",
+    "gemeindeammann",
+    "gemeindedienste",
+    "gemeindegliedern",
+    "gemeindegutes",
+    "gemeinden",
+    "gemeindepfarrer",
+    "gemeindepräsident",
+    "gemeindeschreiber",
+    "gemeindespritze",
+    "gemeine",
+    "gemeinen",
+    "gemeiner",
+    "gemeingut",
+    "gemeinheit",
+    "gemeinhin",
+    "gemeiniglich",
+    "gemeinnützig",
+    "gemeinnützige",
+    "gemeinsam",
+    "gemeinsame",
+    "gemeinsamen",
+    "gemeinsamkeiten",
+    "gemeinschaft",
+    "gemeinschaften",
+    "gemeinschaftlich",
+    "gemeinschaftliche",
+    "gemeinschaftlichen",
+    "gemeinschaftliches",
+    "gemeinschaftsbadezimmern",
+    "gemeinste",
+    "gemeinsten",
+    "gemeint",
+    "gemeinte",
+    "gemeinwesen",
+    "gemeisselt",
+    "gemeldet",
+    "gemengt",
+    "gemerkt",
+    "gemessene",
+    "gemessenen",
+    "gemessener",
+    "gemieden",
+    "gemiedene",
+    "gemieteten",
+    "gemildert",
+    "gemindert",
+    "gemisch",
+    "gemischt",
+    "gemischten",
+    "gemischtes",
+    "gemordet",
+    "gemsbart",
+    "gemsbärte",
+    "gemshorn",
+    "gemurrt",
+    "gemusst",
+    "gemächer",
+    "gemächern",
+    "gemächlich",
+    "gemächliches",
+    "gemähte",
+    "gemähtes",
+    "gemälde",
+    "gemäss",
+    "gemässe",
+    "gemässes",
+    "gemässigt",
+    "gemästet",
+    "gemästeten",
+    "gemäuer",
+    "gemäß",
+    "gemäßes",
+    "gemäßigt",
+    "gemüllhaufen",
+    "gemüse",
+    "gemüsegarten",
+    "gemüsegartens",
+    "gemüseplatz",
+    "gemüt",
+    "gemüte",
+    "gemüter",
+    "gemütes",
+    "gemütlich",
+    "gemütliche",
+    "gemütlichen",
+    "gemütlicher",
+    "gemütlichkeit",
+    "gemüts",
+    "gemütsanlage",
+    "gemütsart",
+    "gemütsbeschaffenheit",
+    "gemütskräfte",
+    "gemütsverfassung",
+    "gemütswesen",
+    "gemütvolle",
+    "genagelt",
+    "genannt",
+    "genannte",
+    "genanntem",
+    "genannten",
+    "genarrtes",
+    "genau",
+    "genaue",
+    "genauen",
+    "genauer",
+    "genauere",
+    "genaueres",
+    "genaues",
+    "genaueste",
+    "genauso",
+    "genausten",
+    "genehm",
+    "genehmigt",
+    "geneigt",
+    "geneigte",
+    "geneigtem",
+    "geneigter",
+    "geneigtheit",
+    "genera",
+    "generalen",
+    "generalsekretär",
+    "generalstreik",
+    "generalsynode",
+    "generell",
+    "generelles",
+    "generäle",
+    "genesen",
+    "genesis",
+    "genesung",
+    "genf",
+    "genfer",
+    "genialen",
+    "genick",
+    "genickt",
+    "genie",
+    "genien",
+    "genieren",
+    "geniert",
+    "genies",
+    "genieschwüngen",
+    "geniessen",
+    "geniessest",
+    "geniesst",
+    "genießen",
+    "genießt",
+    "genitiv",
+    "genitive",
+    "genius",
+    "genommen",
+    "genommnen",
+    "genoss",
+    "genosse",
+    "genossen",
+    "genossene",
+    "genossenen",
+    "genossin",
+    "genoß",
+    "gens",
+    "gentliche",
+    "genug",
+    "genugsam",
+    "genugsamem",
+    "genugsamer",
+    "genugtuung",
+    "genus",
+    "genuss",
+    "genussarten",
+    "genusse",
+    "genusses",
+    "genussreich",
+    "genuß",
+    "genußreich",
+    "genähert",
+    "genährt",
+    "genötigt",
+    "genüge",
+    "genügen",
+    "genügend",
+    "genügenden",
+    "genügender",
+    "genügsam",
+    "genügsamkeit",
+    "genügt",
+    "genügte",
+    "genüsse",
+    "genüssen",
+    "genützt",
+    "geoffenbart",
+    "geoffenbarte",
+    "geoffenbartem",
+    "geoffenbarten",
+    "geographiebuch",
+    "geographiebuche",
+    "geographiebuches",
+    "geohrfeigt",
+    "geometer",
+    "geometrie",
+    "geometrische",
+    "geometrischen",
+    "geopfert",
+    "geordnet",
+    "georg",
+    "gepaart",
+    "gepackt",
+    "gepanzerter",
+    "gepasst",
+    "gepaßt",
+    "gepeinigt",
+    "gepeinigter",
+    "gepeitscht",
+    "gepfeife",
+    "gepflanzt",
+    "gepflanzte",
+    "gepflasterten",
+    "gepflegt",
+    "gepflegte",
+    "gepflegter",
+    "gepflogen",
+    "gepflückt",
+    "gepflügt",
+    "gepfropft",
+    "geplagt",
+    "geplante",
+    "geplanten",
+    "geplauder",
+    "geplättet",
+    "geplünderten",
+    "gepolsterter",
+    "gepredigt",
+    "gepressten",
+    "gepresster",
+    "gepriesen",
+    "geprägt",
+    "gepränge",
+    "geprüft",
+    "geprügelt",
+    "geprügelten",
+    "geputzt",
+    "geputzte",
+    "geputzter",
+    "gepäck",
+    "gepäckes",
+    "gepäckwagen",
+    "gequält",
+    "gequälten",
+    "gerade",
+    "geraden",
+    "gerader",
+    "geradeso",
+    "geradewegs",
+    "geradezu",
+    "geradheit",
+    "geradso",
+    "gerannt",
+    "gerassel",
+    "gerasselt",
+    "gerast",
+    "gerastet",
+    "geraten",
+    "geratewohl",
+    "gerattere",
+    "gerauft",
+    "geraume",
+    "geraumer",
+    "gerdt",
+    "gerechnet",
+    "gerecht",
+    "gerechte",
+    "gerechten",
+    "gerechter",
+    "gerechtfertigt",
+    "gerechtfertigter",
+    "gerechtigkeit",
+    "gerechtigkeitsformen",
+    "gerede",
+    "geredet",
+    "geregnet",
+    "gereicht",
+    "gereigte",
+    "gereimtes",
+    "gereinigt",
+    "gereinigten",
+    "gereist",
+    "gereizt",
+    "gereiztheit",
+    "gerettet",
+    "gerettete",
+    "geretteten",
+    "gereue",
+    "gereuen",
+    "gereuend",
+    "gereut",
+    "gereute",
+    "gereuungen",
+    "gericht",
+    "gerichte",
+    "gerichten",
+    "gerichtes",
+    "gerichtet",
+    "gerichtete",
+    "gerichtetes",
+    "gerichtlich",
+    "gerichts",
+    "gerichtsbeamten",
+    "gerichtsbeschluss",
+    "gerichtsbeschluß",
+    "gerichtsdiener",
+    "gerichtshöfe",
+    "gerichtshöfen",
+    "gerichtskanzleien",
+    "gerichtsorganisation",
+    "gerichtsorganismus",
+    "gerichtssache",
+    "gerichtsschranken",
+    "gerichtsschriften",
+    "gerichtsverhandlung",
+    "gerichtswesens",
+    "gerichtszimmer",
+    "gerieben",
+    "geriebener",
+    "geriegelt",
+    "geriet",
+    "gerieten",
+    "gering",
+    "geringe",
+    "geringem",
+    "geringen",
+    "geringer",
+    "geringere",
+    "geringerer",
+    "geringeres",
+    "geringes",
+    "geringfügig",
+    "geringfügige",
+    "geringfügigsten",
+    "geringgeschätzt",
+    "geringschätzig",
+    "geringschätzung",
+    "geringste",
+    "geringsten",
+    "gerinnen",
+    "gerippe",
+    "geripptes",
+    "gerissen",
+    "geritten",
+    "germanen",
+    "germanischen",
+    "gern",
+    "gerne",
+    "gerochen",
+    "gersom",
+    "gerson",
+    "gersons",
+    "gerste",
+    "gerstenbrot",
+    "gerte",
+    "geruch",
+    "geruches",
+    "geruchs",
+    "gerufen",
+    "gerufenwerdens",
+    "geruhsamen",
+    "geruht",
+    "gerumpel",
+    "gerutscht",
+    "gerächt",
+    "gerät",
+    "gerätes",
+    "geräumige",
+    "geräumigen",
+    "geräusch",
+    "geräusches",
+    "geräuschlosen",
+    "geräuschvollen",
+    "geräuschvollsten",
+    "geröllhalde",
+    "geröllhalden",
+    "geröllhang",
+    "geröstete",
+    "gerösteten",
+    "geröstetes",
+    "gerötet",
+    "gerötete",
+    "geröteter",
+    "gerüche",
+    "gerücht",
+    "gerüchte",
+    "gerüchteweise",
+    "gerüchtweise",
+    "gerührt",
+    "gerührter",
+    "gerümpel",
+    "gerümpelfuhre",
+    "gerüste",
+    "gerüstet",
+    "gerüsteten",
+    "gerüttelt",
+    "gerütteltes",
+    "gesagt",
+    "gesagten",
+    "gesalbt",
+    "gesalbte",
+    "gesalbten",
+    "gesalzenem",
+    "gesammelt",
+    "gesammelte",
+    "gesammeltes",
+    "gesamt",
+    "gesamtanschauung",
+    "gesamte",
+    "gesamten",
+    "gesamtheit",
+    "gesamtlage",
+    "gesamtleistung",
+    "gesandt",
+    "gesandte",
+    "gesandten",
+    "gesandtschaft",
+    "gesang",
+    "gesanges",
+    "gesangs",
+    "gesangsstimmen",
+    "gesaust",
+    "geschadet",
+    "geschaffen",
+    "geschaffene",
+    "geschaffenen",
+    "geschaffenes",
+    "geschafft",
+    "geschah",
+    "geschahen",
+    "geschaut",
+    "geschehe",
+    "geschehen",
+    "geschehene",
+    "geschehenen",
+    "geschehnisse",
+    "gescheit",
+    "gescheitert",
+    "gescheites",
+    "gescheiteste",
+    "geschenk",
+    "geschenke",
+    "geschenken",
+    "geschenkt",
+    "gescherzt",
+    "gescheucht",
+    "gescheuchten",
+    "gescheuerte",
+    "geschichtartig",
+    "geschichtartigen",
+    "geschichte",
+    "geschichten",
+    "geschichtliche",
+    "geschichtlichen",
+    "geschichtliches",
+    "geschichts",
+    "geschick",
+    "geschicke",
+    "geschickes",
+    "geschicklichkeit",
+    "geschickt",
+    "geschickte",
+    "geschickten",
+    "geschickter",
+    "geschieden",
+    "geschiedenen",
+    "geschiedener",
+    "geschiedenes",
+    "geschieht",
+    "geschielt",
+    "geschildert",
+    "geschirr",
+    "geschirre",
+    "geschirrkasten",
+    "geschlafen",
+    "geschlagen",
+    "geschlagener",
+    "geschlagenes",
+    "geschlecht",
+    "geschlechte",
+    "geschlechter",
+    "geschlechtern",
+    "geschlechtes",
+    "geschlechts",
+    "geschlechtsverzeichnis",
+    "geschleift",
+    "geschleudert",
+    "geschlossen",
+    "geschlossene",
+    "geschlossenen",
+    "geschlossener",
+    "geschlungen",
+    "geschmack",
+    "geschmacke",
+    "geschmacklos",
+    "geschmacks",
+    "geschmacksgründen",
+    "geschmackvolle",
+    "geschmackvollen",
+    "geschmeckt",
+    "geschmeichelt",
+    "geschmeidigen",
+    "geschmerzt",
+    "geschmiedetes",
+    "geschmiegt",
+    "geschmissen",
+    "geschmolzenes",
+    "geschmälerten",
+    "geschmückt",
+    "geschmückten",
+    "geschmücktes",
+    "geschnappt",
+    "geschnatterwesen",
+    "geschnaubt",
+    "geschneit",
+    "geschniegelten",
+    "geschnitten",
+    "geschnittenen",
+    "geschnitzte",
+    "geschnitzten",
+    "geschnürt",
+    "geschoben",
+    "gescholten",
+    "geschoren",
+    "geschossen",
+    "geschrei",
+    "geschreis",
+    "geschrieben",
+    "geschriebene",
+    "geschriebenen",
+    "geschriebenes",
+    "geschrieen",
+    "geschrien",
+    "geschrumpft",
+    "geschulten",
+    "geschwader",
+    "geschwatzt",
+    "geschwebt",
+    "geschweifte",
+    "geschweige",
+    "geschwiegen",
+    "geschwind",
+    "geschwindigkeit",
+    "geschwister",
+    "geschwistern",
+    "geschwollen",
+    "geschwollnen",
+    "geschworen",
+    "geschwulst",
+    "geschwunden",
+    "geschwungen",
+    "geschwungener",
+    "geschwächt",
+    "geschwächten",
+    "geschwänzelt",
+    "geschwärzt",
+    "geschwätz",
+    "geschwätzig",
+    "geschwätzigen",
+    "geschwätzigkeit",
+    "geschwür",
+    "geschwüre",
+    "geschwüren",
+    "geschwürs",
+    "geschäft",
+    "geschäfte",
+    "geschäften",
+    "geschäftes",
+    "geschäftig",
+    "geschäftige",
+    "geschäftigkeit",
+    "geschäftliche",
+    "geschäftlichen",
+    "geschäftsauslagen",
+    "geschäftsbuch",
+    "geschäftsfragen",
+    "geschäftsfreund",
+    "geschäftsführung",
+    "geschäftsjahren",
+    "geschäftsleiter",
+    "geschäftsleuten",
+    "geschäftsmann",
+    "geschäftsmänner",
+    "geschäftsmännern",
+    "geschäftsprüfungskommission",
+    "geschäftsreise",
+    "geschäftsschwankung",
+    "geschäftsstelle",
+    "geschäftsweg",
+    "geschändete",
+    "geschärft",
+    "geschärftes",
+    "geschätzt",
+    "geschätzten",
+    "geschöpf",
+    "geschöpfchen",
+    "geschöpfe",
+    "geschöpfen",
+    "geschöpfes",
+    "geschöpfs",
+    "geschöpft",
+    "geschüttelt",
+    "geschüttet",
+    "geschützt",
+    "gesegnen",
+    "gesegnet",
+    "gesegneten",
+    "gesegnetes",
+    "gesehen",
+    "gesehene",
+    "gesehn",
+    "gesehnt",
+    "gesell",
+    "geselle",
+    "gesellen",
+    "gesellenschritt",
+    "gesellschaft",
+    "gesellschaften",
+    "gesellschafter",
+    "gesellschaftliche",
+    "gesellschaftlichen",
+    "gesellschafts",
+    "gesellt",
+    "gesellte",
+    "gesellten",
+    "gesendet",
+    "gesenkt",
+    "gesenktem",
+    "gesenkten",
+    "gesessen",
+    "gesetz",
+    "gesetzbücher",
+    "gesetze",
+    "gesetzen",
+    "gesetzes",
+    "gesetzesstelle",
+    "gesetzestreue",
+    "gesetzesübertretungen",
+    "gesetzgebend",
+    "gesetzgebenden",
+    "gesetzgeber",
+    "gesetzgeberin",
+    "gesetzgebung",
+    "gesetzlich",
+    "gesetzliche",
+    "gesetzlichen",
+    "gesetzmässig",
+    "gesetzmässige",
+    "gesetzmässigen",
+    "gesetzmässigkeit",
+    "gesetzsammlung",
+    "gesetzt",
+    "gesetzte",
+    "gesetzwidrig",
+    "gesichert",
+    "gesicherte",
+    "gesicht",
+    "gesichtchen",
+    "gesichte",
+    "gesichter",
+    "gesichtern",
+    "gesichtes",
+    "gesichts",
+    "gesichtsart",
+    "gesichtsfarbe",
+    "gesichtskreis",
+    "gesichtskreise",
+    "gesichtspunkt",
+    "gesichtszüge",
+    "gesichtszügen",
+    "gesiegt",
+    "gesimse",
+    "gesimsen",
+    "gesind",
+    "gesinde",
+    "gesindehaus",
+    "gesindel",
+    "gesindes",
+    "gesinnt",
+    "gesinnung",
+    "gesinnungen",
+    "gesinnungsart",
+    "gesinnungslosigkeit",
+    "gesoffen",
+    "gesonnen",
+    "gesorgt",
+    "gespann",
+    "gespannt",
+    "gespannte",
+    "gespannten",
+    "gespannter",
+    "gespart",
+    "gespeistwerden",
+    "gespenst",
+    "gespenste",
+    "gespensterbäume",
+    "gespenstisch",
+    "gesperrt",
+    "gespickt",
+    "gespickten",
+    "gespielt",
+    "gespiesen",
+    "gespitzt",
+    "gesplittertes",
+    "gesponnen",
+    "gespornt",
+    "gespornten",
+    "gespreizt",
+    "gespreizten",
+    "gesprengt",
+    "gesprenkelte",
+    "gespritzt",
+    "gesprochen",
+    "gesprochenen",
+    "gesprochenes",
+    "gesprosst",
+    "gesproßt",
+    "gesprungen",
+    "gespräch",
+    "gespräche",
+    "gesprächen",
+    "gesprächigkeit",
+    "gesprächs",
+    "gesprächskapitel",
+    "gesprächsthema",
+    "gespött",
+    "gespötte",
+    "gessen",
+    "gestalt",
+    "gestalten",
+    "gestaltet",
+    "gestaltete",
+    "gestaltung",
+    "gestand",
+    "gestanden",
+    "gestank",
+    "gestatten",
+    "gestattet",
+    "gestattete",
+    "geste",
+    "gesteckt",
+    "gesteckten",
+    "gestehen",
+    "gestehn",
+    "gesteigert",
+    "gesteigertem",
+    "gestein",
+    "gesteinigt",
+    "gestellt",
+    "gestellter",
+    "gestemmt",
+    "gestern",
+    "gestiefelt",
+    "gestiefelten",
+    "gestiegen",
+    "gestiftet",
+    "gestikulieren",
+    "gestimmt",
+    "gestirn",
+    "gestirne",
+    "gestirnes",
+    "gestochen",
+    "gestohlen",
+    "gestohlenen",
+    "gestopft",
+    "gestorben",
+    "gestossen",
+    "gestoßen",
+    "gestraft",
+    "gestrahlt",
+    "gestrebt",
+    "gestreckt",
+    "gestreckten",
+    "gestreift",
+    "gestreifte",
+    "gestreiften",
+    "gestrenge",
+    "gestrengen",
+    "gestressten",
+    "gestreut",
+    "gestrichen",
+    "gestrichenen",
+    "gestrickte",
+    "gestrige",
+    "gestrigen",
+    "gestritten",
+    "gesträuch",
+    "gesträuche",
+    "gestrüpp",
+    "gesturm",
+    "geständnis",
+    "geständnisse",
+    "gestärkt",
+    "gestört",
+    "gestürzt",
+    "gestützt",
+    "gesuch",
+    "gesucht",
+    "gesuchten",
+    "gesuchter",
+    "gesumm",
+    "gesund",
+    "gesunde",
+    "gesunden",
+    "gesunder",
+    "gesundes",
+    "gesundheit",
+    "gesundheitsamt",
+    "gesungen",
+    "gesungenen",
+    "gesunken",
+    "gesäet",
+    "gesäht",
+    "gesänge",
+    "gesängen",
+    "gesät",
+    "gesättigt",
+    "gesäuberten",
+    "gesäugt",
+    "gesünderen",
+    "gesünderer",
+    "gesündigt",
+    "getan",
+    "getanzt",
+    "getauchte",
+    "getauchten",
+    "getauft",
+    "getauften",
+    "getaumelt",
+    "getauscht",
+    "geteilt",
+    "gethsemane",
+    "getier",
+    "getilgt",
+    "getragen",
+    "getragene",
+    "getragenen",
+    "getrauert",
+    "getraut",
+    "getraute",
+    "getreide",
+    "getrennt",
+    "getrennte",
+    "getrennten",
+    "getrennter",
+    "getreten",
+    "getreu",
+    "getreue",
+    "getreuen",
+    "getreulich",
+    "getriebe",
+    "getrieben",
+    "getriebene",
+    "getriebenes",
+    "getroffen",
+    "getroffenen",
+    "getrost",
+    "getrunken",
+    "geträllert",
+    "getränk",
+    "getränke",
+    "getränkemischungen",
+    "getränken",
+    "getränkes",
+    "getränkt",
+    "getränkter",
+    "geträumt",
+    "geträumte",
+    "getröstet",
+    "getrübt",
+    "getupf",
+    "getäfelten",
+    "getändelt",
+    "getäuscht",
+    "getöne",
+    "getönt",
+    "getöse",
+    "getötet",
+    "getöteten",
+    "getöteter",
+    "geurteilt",
+    "gevatter",
+    "gevatterschaften",
+    "gevattersfrau",
+    "gewachsen",
+    "gewachsenes",
+    "gewacht",
+    "gewagt",
+    "gewahren",
+    "gewahrend",
+    "gewahrsam",
+    "gewahrt",
+    "gewahrte",
+    "gewahrten",
+    "gewalt",
+    "gewaltder",
+    "gewalthabende",
+    "gewaltig",
+    "gewaltige",
+    "gewaltigem",
+    "gewaltigen",
+    "gewaltiger",
+    "gewaltiges",
+    "gewaltigsten",
+    "gewaltlos",
+    "gewaltlosen",
+    "gewaltsam",
+    "gewaltsamer",
+    "gewalttat",
+    "gewalttätigen",
+    "gewalttätiger",
+    "gewalttätigkeit",
+    "gewand",
+    "gewande",
+    "gewandelt",
+    "gewandert",
+    "gewandes",
+    "gewandt",
+    "gewandte",
+    "gewandten",
+    "gewandtheit",
+    "gewann",
+    "gewannen",
+    "gewarnt",
+    "gewartet",
+    "gewaschen",
+    "gewaschene",
+    "gewebe",
+    "gewebeproben",
+    "gewechselt",
+    "geweckt",
+    "gewehlbe",
+    "gewehr",
+    "gewehre",
+    "gewehren",
+    "gewehrlauf",
+    "geweigert",
+    "geweih",
+    "geweihbildung",
+    "geweihes",
+    "geweihete",
+    "geweissagt",
+    "geweisst",
+    "geweisstes",
+    "gewendet",
+    "gewendeter",
+    "gewerbe",
+    "gewerkschaft",
+    "gewerkschaftsdemonstration",
+    "gewesen",
+    "gewesenen",
+    "gewichen",
+    "gewicht",
+    "gewichtigen",
+    "gewickelt",
+    "gewiegter",
+    "gewiesen",
+    "gewildes",
+    "gewillt",
+    "gewinn",
+    "gewinne",
+    "gewinnen",
+    "gewinnende",
+    "gewinnes",
+    "gewinnt",
+    "gewirkt",
+    "gewiss",
+    "gewisse",
+    "gewissem",
+    "gewissen",
+    "gewissenhaft",
+    "gewissenhafter",
+    "gewissenlos",
+    "gewissenloser",
+    "gewissenlosigkeit",
+    "gewissenlosigkeiten",
+    "gewissens",
+    "gewissensangst",
+    "gewissensbedenken",
+    "gewissensbisse",
+    "gewissensbürde",
+    "gewissensfreien",
+    "gewissensgründen",
+    "gewissenskonflikt",
+    "gewissenskonfliktes",
+    "gewissenspein",
+    "gewissensqualen",
+    "gewisser",
+    "gewissermassen",
+    "gewissermaßen",
+    "gewisses",
+    "gewisseste",
+    "gewissesten",
+    "gewissheit",
+    "gewisslich",
+    "gewitter",
+    "gewitterregen",
+    "gewitterstürme",
+    "gewitterwolken",
+    "gewitzter",
+    "gewiß",
+    "gewißheit",
+    "gewißlich",
+    "gewogen",
+    "gewogt",
+    "gewohnheit",
+    "gewohnheiten",
+    "gewohnt",
+    "gewohnten",
+    "gewohnterweise",
+    "gewollt",
+    "gewonnen",
+    "gewonnenen",
+    "geworben",
+    "geworden",
+    "gewordene",
+    "gewordenen",
+    "gewordener",
+    "geworfen",
+    "geworfene",
+    "geworfenen",
+    "gewunden",
+    "gewundene",
+    "gewurzelt",
+    "gewusst",
+    "gewußt",
+    "gewächs",
+    "gewächse",
+    "gewählt",
+    "gewählte",
+    "gewählten",
+    "gewähltfranks",
+    "gewähnt",
+    "gewähren",
+    "gewährt",
+    "gewährte",
+    "gewährten",
+    "gewänder",
+    "gewärtig",
+    "gewärtigen",
+    "gewässer",
+    "gewässerter",
+    "gewöhnen",
+    "gewöhnlich",
+    "gewöhnliche",
+    "gewöhnlichen",
+    "gewöhnlicher",
+    "gewöhnlichste",
+    "gewöhnlichsten",
+    "gewöhnt",
+    "gewöhnte",
+    "gewöhnung",
+    "gewölk",
+    "gewönne",
+    "gewühl",
+    "gewünschtdie",
+    "gewünschten",
+    "gewünschtes",
+    "gewürdigt",
+    "gewürfelte",
+    "gewürm",
+    "gewürme",
+    "gewürms",
+    "gewürzen",
+    "gewürzes",
+    "gewürzmörser",
+    "gewürznägelein",
+    "gewürzt",
+    "gezahnten",
+    "gezaubert",
+    "gezecht",
+    "gezeichnet",
+    "gezeigt",
+    "gezeigten",
+    "gezerrt",
+    "gezeugt",
+    "gezielt",
+    "geziemenden",
+    "geziemender",
+    "geziemt",
+    "geziere",
+    "geziert",
+    "gezierten",
+    "gezimmerte",
+    "gezittert",
+    "gezogen",
+    "gezogene",
+    "gezogenen",
+    "gezweig",
+    "gezwitscher",
+    "gezwungen",
+    "gezwungene",
+    "gezählt",
+    "gezücht",
+    "gezüchtet",
+    "gezüchtigt",
+    "geädert",
+    "geändert",
+    "geängstigtes",
+    "geärgert",
+    "geäussert",
+    "geäußert",
+    "geöffnet",
+    "geöffnete",
+    "geöffneten",
+    "geöffnetwerden",
+    "geölte",
+    "geübt",
+    "geübter",
+    "gfahln",
+    "gfallen",
+    "gfallt",
+    "gflickt",
+    "gfressen",
+    "gfunden",
+    "ghabt",
+    "ghear",
+    "gheart",
+    "ghört",
+    "gian",
+    "gib",
+    "gibeon",
+    "gibeoniten",
+    "gibeoniter",
+    "gibst",
+    "gibt",
+    "gibts",
+    "gichon",
+    "gichtschmerzen",
+    "gideon",
+    "gier",
+    "gierig",
+    "gierigkeit",
+    "gierten",
+    "giessbach",
+    "giessbäche",
+    "giesse",
+    "giessen",
+    "giesskanne",
+    "giesst",
+    "gießbach",
+    "gießbäche",
+    "gieße",
+    "gießt",
+    "gift",
+    "gifte",
+    "giftes",
+    "giftig",
+    "giftige",
+    "giftigen",
+    "giftmischerinnen",
+    "giftpilzen",
+    "gilead",
+    "gilt",
+    "ging",
+    "ginge",
+    "gingen",
+    "gipfel",
+    "gipfeln",
+    "gipfelpunkt",
+    "gipsarbeiter",
+    "gissgen",
+    "gitarre",
+    "gitarrebegleiten",
+    "gitter",
+    "gitterchen",
+    "glacht",
+    "gladiolos",
+    "glanz",
+    "glanzalter",
+    "glanze",
+    "glanzlosen",
+    "glanzzeit",
+    "glas",
+    "glase",
+    "glaser",
+    "glasglöckchen",
+    "glashelle",
+    "glaskanzel",
+    "glaskeulen",
+    "glaskutsche",
+    "glaskästchen",
+    "glasperlen",
+    "glasschneiden",
+    "glassteinchen",
+    "glasstoff",
+    "glaswand",
+    "glasziegeln",
+    "glatt",
+    "glatten",
+    "glaub",
+    "glaube",
+    "glauben",
+    "glaubenden",
+    "glaubens",
+    "glaubensartikel",
+    "glaubensbekenntnis",
+    "glaubenserkenntnisse",
+    "glaubenserkenntnissen",
+    "glaubensfragen",
+    "glaubensgeheimnis",
+    "glaubensgeheimnisse",
+    "glaubensguten",
+    "glaubensleben",
+    "glaubenslebens",
+    "glaubenslehre",
+    "glaubenslehren",
+    "glaubenssache",
+    "glaubenssachen",
+    "glaubensvorschriften",
+    "glaubenswahre",
+    "glaubenswahren",
+    "glaubenswahres",
+    "glaubenswahrheit",
+    "glaubenswahrheiten",
+    "glaubenswissenschaft",
+    "glaubensüberreste",
+    "glaubest",
+    "glaubet",
+    "glaubst",
+    "glaubt",
+    "glaubte",
+    "glaubten",
+    "glaubtet",
+    "glaubwürdig",
+    "glaubwürdige",
+    "glaubwürdigkeit",
+    "glegen",
+    "glegenheit",
+    "gleich",
+    "gleichartig",
+    "gleichartiges",
+    "gleichbedeutend",
+    "gleiche",
+    "gleichem",
+    "gleichen",
+    "gleichentags",
+    "gleicher",
+    "gleichermassen",
+    "gleicherzeit",
+    "gleiches",
+    "gleichfalls",
+    "gleichförmig",
+    "gleichgeschätzt",
+    "gleichgesetzt",
+    "gleichgewicht",
+    "gleichgültig",
+    "gleichgültigkeit",
+    "gleichgültigste",
+    "gleichheit",
+    "gleichmass",
+    "gleichmaß",
+    "gleichmut",
+    "gleichmässig",
+    "gleichmässige",
+    "gleichmässigen",
+    "gleichmütig",
+    "gleichnis",
+    "gleichnissen",
+    "gleichsah",
+    "gleichsam",
+    "gleichsehend",
+    "gleichstellte",
+    "gleicht",
+    "gleichung",
+    "gleichviel",
+    "gleichwie",
+    "gleichwohl",
+    "gleichzeitig",
+    "gleichzeitigen",
+    "gleichzumachen",
+    "gleis",
+    "gleise",
+    "gleissnerei",
+    "gleitet",
+    "glesen",
+    "glich",
+    "glichen",
+    "glied",
+    "glieder",
+    "gliederlein",
+    "gliedern",
+    "glimmenden",
+    "glimmrigen",
+    "glitt",
+    "glitten",
+    "glitzernde",
+    "glitzernden",
+    "glocke",
+    "glocken",
+    "glockengeläute",
+    "glockenschläge",
+    "glockenschnur",
+    "glockenschönen",
+    "glockenspiel",
+    "glockenstimme",
+    "gloria",
+    "glorie",
+    "glorreichen",
+    "glotzaugen",
+    "glotzte",
+    "glotzten",
+    "glotzäugelchen",
+    "glotzäugig",
+    "glucksen",
+    "glupft",
+    "glut",
+    "glänzen",
+    "glänzend",
+    "glänzende",
+    "glänzendem",
+    "glänzenden",
+    "glänzender",
+    "glänzendes",
+    "glänzendsten",
+    "glänzt",
+    "glänzte",
+    "glänzten",
+    "gläschen",
+    "gläser",
+    "gläsern",
+    "gläserne",
+    "gläsernen",
+    "glätte",
+    "glätten",
+    "glättete",
+    "gläubige",
+    "gläubigen",
+    "gläubiger",
+    "gläubigern",
+    "glöckchen",
+    "glöcklein",
+    "glück",
+    "glücke",
+    "glückes",
+    "glückhaften",
+    "glücklich",
+    "glückliche",
+    "glücklichen",
+    "glücklicher",
+    "glücklicheren",
+    "glücklichern",
+    "glücklicherweise",
+    "glücklichste",
+    "glücklichsten",
+    "glücks",
+    "glückselig",
+    "glückselige",
+    "glückseligen",
+    "glückseliger",
+    "glückseligkeit",
+    "glückseligkeitslehre",
+    "glückseligkeitsprinzip",
+    "glückseligkeitsprinzips",
+    "glücksfall",
+    "glücksgefühle",
+    "glückskind",
+    "glückt",
+    "glückwunsch",
+    "glühen",
+    "glühend",
+    "glühende",
+    "glühenden",
+    "glühender",
+    "glühendes",
+    "glühendrot",
+    "glühendste",
+    "glühlampe",
+    "glühte",
+    "glühten",
+    "glühwürmchen",
+    "gmacht",
+    "gmeint",
+    "gmerkt",
+    "gnade",
+    "gnaden",
+    "gnadenbrot",
+    "gnadensonne",
+    "gnadenstuhl",
+    "gnadenstuhls",
+    "gnante",
+    "gnau",
+    "gnommen",
+    "gnug",
+    "gnädig",
+    "gnädige",
+    "gnädiger",
+    "gnädiglich",
+    "gnädigste",
+    "gockelhahn",
+    "gockelhahns",
+    "goethe",
+    "gog",
+    "goldach",
+    "goldacher",
+    "goldbecher",
+    "goldbefrackter",
+    "goldbestecke",
+    "goldbeutel",
+    "goldblatt",
+    "goldblonden",
+    "goldblätter",
+    "goldbuchstaben",
+    "goldbörtchen",
+    "golddress",
+    "golddreß",
+    "golddukaten",
+    "golde",
+    "goldene",
+    "goldenem",
+    "goldenen",
+    "goldener",
+    "goldenes",
+    "goldes",
+    "goldfische",
+    "goldfuchs",
+    "goldfuchse",
+    "goldgelbes",
+    "goldgeschäfte",
+    "goldgrube",
+    "goldgülden",
+    "goldhelm",
+    "goldhäubchen",
+    "goldig",
+    "goldigen",
+    "goldketten",
+    "goldkieswege",
+    "goldknechte",
+    "goldkugeln",
+    "goldlack",
+    "goldne",
+    "goldnen",
+    "goldpapier",
+    "goldreserven",
+    "goldscheibe",
+    "goldschlangen",
+    "goldschmied",
+    "goldschnüren",
+    "goldstickereien",
+    "goldstück",
+    "goldstücke",
+    "goldtaler",
+    "goldtalern",
+    "goldtresse",
+    "goldwaage",
+    "gomorrah",
+    "gomorrahs",
+    "goodbye",
+    "gopher",
+    "gosen",
+    "goss",
+    "gotisches",
+    "gott",
+    "gottes",
+    "gottesdienst",
+    "gottesdienstbesucher",
+    "gottesdienste",
+    "gottesdiensten",
+    "gottesdienstes",
+    "gottesgelehrten",
+    "gottesgelehrter",
+    "gottesgläubig",
+    "gotteslästerlichsten",
+    "gottesmannes",
+    "gottesverehrung",
+    "gottfried",
+    "gotthard",
+    "gottheit",
+    "gottlose",
+    "gottlosen",
+    "gottloser",
+    "gotts",
+    "gottsnamen",
+    "gottverfluchts",
+    "gottverlassene",
+    "gouvernement",
+    "gouverneur",
+    "gouverneurs",
+    "goß",
+    "grab",
+    "grabbegleitung",
+    "grabe",
+    "graben",
+    "grabesstill",
+    "grabtuch",
+    "grabtuchs",
+    "grad",
+    "gradaus",
+    "gradausfahrendes",
+    "grade",
+    "graden",
+    "grades",
+    "gradezu",
+    "gradheit",
+    "gradlinigen",
+    "graf",
+    "grafen",
+    "grafenrock",
+    "grafensohn",
+    "gram",
+    "grammaticale",
+    "grammatik",
+    "grammophon",
+    "gramvollen",
+    "granatbaum",
+    "granatblütenstrauss",
+    "granatwerfer",
+    "granen",
+    "granitklotz",
+    "gras",
+    "grase",
+    "grasen",
+    "grashälmchen",
+    "grasigen",
+    "grasreich",
+    "grasreichen",
+    "grasten",
+    "grasteufeln",
+    "grasüberwachsene",
+    "grat",
+    "gratis",
+    "gratulierte",
+    "grau",
+    "graublauen",
+    "graubärtigen",
+    "graue",
+    "grauem",
+    "grauen",
+    "grauendem",
+    "grauenvollen",
+    "grauer",
+    "graues",
+    "graumeliertes",
+    "grausam",
+    "grausamen",
+    "grausamerweise",
+    "grausames",
+    "grausamkeit",
+    "grauschwarzen",
+    "grausen",
+    "grausens",
+    "graute",
+    "grauweiss",
+    "grauweissen",
+    "grauzone",
+    "gravierende",
+    "gravierenden",
+    "gravität",
+    "gravitätisch",
+    "grazilsten",
+    "gredt",
+    "grehm",
+    "greifen",
+    "greifenden",
+    "greifliche",
+    "greift",
+    "greinige",
+    "greinstimme",
+    "greis",
+    "greise",
+    "greisen",
+    "greisenalter",
+    "greisengestalt",
+    "greisenhafter",
+    "greisenhaftigkeit",
+    "greisentums",
+    "grell",
+    "grellen",
+    "gremium",
+    "grendelmeier",
+    "grenz",
+    "grenzbauden",
+    "grenzbestimmung",
+    "grenzbezirk",
+    "grenze",
+    "grenzen",
+    "grenzenlos",
+    "grenzenlose",
+    "grenzenlosen",
+    "grenzscheide",
+    "grenzschenke",
+    "grenzt",
+    "grenzte",
+    "grete",
+    "greuel",
+    "greuelhafte",
+    "greuelhaftes",
+    "greueln",
+    "greuels",
+    "greueltaten",
+    "greulich",
+    "greuliche",
+    "greulichem",
+    "greulichen",
+    "greulicher",
+    "greuliches",
+    "greve",
+    "griechen",
+    "griechenland",
+    "griechische",
+    "griechischem",
+    "griechischen",
+    "griff",
+    "griffen",
+    "grille",
+    "grillen",
+    "grimassen",
+    "grimm",
+    "grimmig",
+    "grimmige",
+    "grimmigem",
+    "grimmigen",
+    "grimmiger",
+    "grimmiges",
+    "grimmigste",
+    "grimms",
+    "grind",
+    "gringsten",
+    "grinsend",
+    "grinste",
+    "grissen",
+    "grissend",
+    "grob",
+    "grobe",
+    "grobem",
+    "groben",
+    "grober",
+    "grobes",
+    "grobheit",
+    "groll",
+    "grolle",
+    "grollend",
+    "grollendem",
+    "grollenden",
+    "grollte",
+    "groove",
+    "gross",
+    "grossartig",
+    "grossartige",
+    "grossartiger",
+    "grossartigte",
+    "grossaupa",
+    "grossbanken",
+    "grossblumigen",
+    "grossbritannien",
+    "grosse",
+    "grossem",
+    "grossen",
+    "grosser",
+    "grosses",
+    "grossherrn",
+    "grosskhan",
+    "grossknecht",
+    "grossmut",
+    "grossmutter",
+    "grossmächtige",
+    "grossmächtigen",
+    "grossmächtiges",
+    "grossmütige",
+    "grossmütigen",
+    "grossratsmitglied",
+    "grossstädte",
+    "grosstuerei",
+    "grossvater",
+    "grossvaters",
+    "grossvaterstuhl",
+    "grosszuziehen",
+    "grosszügigen",
+    "groß",
+    "großartig",
+    "großartige",
+    "großartiger",
+    "großartiges",
+    "großaupa",
+    "große",
+    "großem",
+    "großen",
+    "großer",
+    "großes",
+    "großkariertes",
+    "großmutter",
+    "großmütter",
+    "großstadt",
+    "großvater",
+    "großvaters",
+    "grub",
+    "grubach",
+    "grube",
+    "gruben",
+    "grubenkessel",
+    "grumpelt",
+    "grund",
+    "grundbegriff",
+    "grundbesitzer",
+    "grunddienst",
+    "grunde",
+    "grundes",
+    "grundfalschen",
+    "grundfesten",
+    "grundgesetz",
+    "grundgesetzes",
+    "grundherrlichen",
+    "grundlage",
+    "grundlagen",
+    "grundlegenden",
+    "grundlos",
+    "grundlose",
+    "grundmauern",
+    "grundsatz",
+    "grundsatze",
+    "grundsatzes",
+    "grundsprache",
+    "grundstein",
+    "grundsätze",
+    "grundsätzen",
+    "grundsätzlich",
+    "grundtext",
+    "grundtone",
+    "grunzen",
+    "grunzend",
+    "gruppe",
+    "gruppen",
+    "gruppenweise",
+    "gruseligen",
+    "gruss",
+    "grussadresse",
+    "grusse",
+    "grusswort",
+    "gruß",
+    "gräben",
+    "gräber",
+    "gräbern",
+    "gräfin",
+    "gräfliche",
+    "gräflichem",
+    "gräflichen",
+    "grämen",
+    "gräser",
+    "gräsern",
+    "grässliche",
+    "grässlichen",
+    "grässlichsten",
+    "gräßlich",
+    "gräßliche",
+    "gräßlichen",
+    "gröber",
+    "gröberen",
+    "gröbern",
+    "gröblich",
+    "gröbsten",
+    "grölen",
+    "grösse",
+    "grössen",
+    "grösser",
+    "grössere",
+    "grösserem",
+    "grösseren",
+    "grösserer",
+    "grösseres",
+    "grösserm",
+    "grössern",
+    "grösste",
+    "grössten",
+    "grösstenteils",
+    "grösster",
+    "grösstes",
+    "grösstmögliche",
+    "größe",
+    "größer",
+    "größere",
+    "größerem",
+    "größeren",
+    "größerer",
+    "größeres",
+    "größte",
+    "größten",
+    "größtenteils",
+    "größtes",
+    "grübeleien",
+    "grübelt",
+    "grübelte",
+    "grüblerisch",
+    "grüblerische",
+    "grün",
+    "grünbemaltes",
+    "gründe",
+    "gründen",
+    "gründende",
+    "gründer",
+    "gründet",
+    "gründete",
+    "gründlich",
+    "gründlichen",
+    "gründliches",
+    "gründlinge",
+    "gründonnerstag",
+    "gründung",
+    "grüne",
+    "grünem",
+    "grünen",
+    "grüner",
+    "grünes",
+    "grünfräckiger",
+    "grünliche",
+    "grünlichen",
+    "grünrot",
+    "grünseidenen",
+    "grünspecht",
+    "grünst",
+    "grüss",
+    "grüsse",
+    "grüssen",
+    "grüssend",
+    "grüsste",
+    "grüssten",
+    "grüße",
+    "grüßend",
+    "grüßte",
+    "grüßten",
+    "gsagt",
+    "gsangl",
+    "gsangln",
+    "gscheid",
+    "gscheids",
+    "gschicht",
+    "gschieht",
+    "gschwind",
+    "gschwister",
+    "gschäft",
+    "gsessen",
+    "gsetzt",
+    "gsicht",
+    "gspart",
+    "gsperrt",
+    "gspitzt",
+    "gstanden",
+    "gstanzln",
+    "gstorben",
+    "gstorbener",
+    "gstritten",
+    "gsucht",
+    "gsungen",
+    "guck",
+    "gucken",
+    "guckfenster",
+    "guckt",
+    "guckte",
+    "guckten",
+    "guet",
+    "guillotine",
+    "guisan",
+    "gulden",
+    "gummi",
+    "gummigegend",
+    "gummiteich",
+    "gummiteichen",
+    "gungeline",
+    "gunnar",
+    "gunst",
+    "gunsten",
+    "gurgel",
+    "gurgeln",
+    "gurgelnden",
+    "gurken",
+    "gurren",
+    "gurt",
+    "gussbild",
+    "gut",
+    "gutartig",
+    "gutartige",
+    "gutbesorgten",
+    "gutdünkt",
+    "gute",
+    "gutem",
+    "guten",
+    "gutenacht",
+    "gutenmorgenkuss",
+    "guter",
+    "gutes",
+    "gutestun",
+    "gutgesinntheit",
+    "gutheiten",
+    "gutmachen",
+    "gutmütig",
+    "gutmütige",
+    "gutmütigkeit",
+    "gutnacht",
+    "guts",
+    "gutsherren",
+    "gutsherrn",
+    "gußbild",
+    "gwalt",
+    "gwaltsame",
+    "gwart",
+    "gweint",
+    "gwesen",
+    "gwitter",
+    "gwöhnen",
+    "gwöhnt",
+    "gymnasiastin",
+    "gynäkologen",
+    "gäb",
+    "gäbe",
+    "gäbst",
+    "gähnte",
+    "gälte",
+    "gänge",
+    "gängelband",
+    "gängen",
+    "gäns",
+    "gänse",
+    "gänsen",
+    "gänsestall",
+    "gänzlich",
+    "gänzliche",
+    "gänzlichen",
+    "gänzlicher",
+    "gärende",
+    "gärenden",
+    "gärten",
+    "gärtner",
+    "gässchen",
+    "gäste",
+    "gästen",
+    "gäule",
+    "göhl",
+    "gölte",
+    "gönne",
+    "gönnen",
+    "gönner",
+    "gönnte",
+    "götter",
+    "götterbilder",
+    "göttern",
+    "göttin",
+    "göttlich",
+    "göttliche",
+    "göttlichen",
+    "göttlicher",
+    "göttliches",
+    "götzen",
+    "götzenbild",
+    "götzenbilder",
+    "götzenbildes",
+    "götzendienerisch",
+    "götzendienerische",
+    "götzendienerischen",
+    "götzendienerisches",
+    "götzendienst",
+    "götzensteine",
+    "gültbrief",
+    "gültig",
+    "gültige",
+    "gültiges",
+    "gültigkeit",
+    "gündem",
+    "günstig",
+    "günstige",
+    "günstigem",
+    "günstigen",
+    "günstiger",
+    "günstiges",
+    "gür",
+    "gürpinar",
+    "gürtel",
+    "gürteln",
+    "gürten",
+    "gürteten",
+    "güte",
+    "güter",
+    "gütern",
+    "gütige",
+    "gütigen",
+    "gütiges",
+    "gütigkeit",
+    "gütigst",
+    "gütlich",
+    "güzel",
+    "h",
+    "ha",
+    "haar",
+    "haarbürste",
+    "haardenkmal",
+    "haare",
+    "haaren",
+    "haares",
+    "haarflechten",
+    "haarige",
+    "haarschnitt",
+    "haarwild",
+    "haarwuchs",
+    "hab",
+    "habe",
+    "habel",
+    "habels",
+    "haben",
+    "habenichts",
+    "habermus",
+    "habet",
+    "habhaft",
+    "habicht",
+    "habit",
+    "habitus",
+    "habs",
+    "habseliges",
+    "habseligkeit",
+    "habseligkeiten",
+    "habsucht",
+    "habt",
+    "haci",
+    "hacken",
+    "hackn",
+    "hackte",
+    "hadad",
+    "hadep",
+    "hader",
+    "haderlumpen",
+    "hadern",
+    "haemorrheos",
+    "haferbrei",
+    "haft",
+    "hafte",
+    "haften",
+    "haftet",
+    "haftstrafen",
+    "hag",
+    "hagel",
+    "hagelflut",
+    "hagelhans",
+    "hageln",
+    "hagelperlen",
+    "hagelregen",
+    "hagelregens",
+    "hagels",
+    "hagelschlag",
+    "hagelstein",
+    "hagelsteine",
+    "hagelsteinen",
+    "hagelstücke",
+    "hagen",
+    "hagere",
+    "hagerer",
+    "haggai",
+    "haha",
+    "hahahaha",
+    "hahn",
+    "haider",
+    "hain",
+    "hains",
+    "haken",
+    "hakkari",
+    "halb",
+    "halbbogen",
+    "halbdunkel",
+    "halbe",
+    "halbem",
+    "halben",
+    "halber",
+    "halbes",
+    "halbfinal",
+    "halbfinalder",
+    "halbgeschlossenen",
+    "halbheit",
+    "halbjährigen",
+    "halbkreis",
+    "halbkreise",
+    "halbkugel",
+    "halbkugeln",
+    "halblange",
+    "halblauten",
+    "halblauter",
+    "halbreife",
+    "halbring",
+    "halbschatten",
+    "halbstummen",
+    "halbstündigen",
+    "halbstündiger",
+    "halbtagsjob",
+    "halbtot",
+    "halbtotes",
+    "halbträumender",
+    "halbvermoderte",
+    "halbvollendeten",
+    "halbwegs",
+    "halbwüchsige",
+    "halden",
+    "halfen",
+    "halle",
+    "hallen",
+    "halleo",
+    "hallo",
+    "hallunkischen",
+    "halm",
+    "halmen",
+    "hals",
+    "halsband",
+    "halse",
+    "halskette",
+    "halskragen",
+    "halstuch",
+    "halstüchelchen",
+    "halt",
+    "haltbare",
+    "haltbaren",
+    "halte",
+    "halten",
+    "haltend",
+    "haltenden",
+    "haltet",
+    "haltlosen",
+    "haltrufen",
+    "haltrufer",
+    "haltung",
+    "halunkische",
+    "ham",
+    "hamburg",
+    "hamburger",
+    "hamlet",
+    "hammelbraten",
+    "hammelkeule",
+    "hammer",
+    "hammerwerk",
+    "hampelhänschen",
+    "hampelmann",
+    "hampelmänner",
+    "hampelmännern",
+    "handbewegung",
+    "handbibel",
+    "handbuch",
+    "handel",
+    "handeln",
+    "handelnde",
+    "handelsabkommen",
+    "handelsanfang",
+    "handelserwerb",
+    "handelsfreunden",
+    "handelsgeschäfte",
+    "handelsgeschäften",
+    "handelskrisen",
+    "handelsleute",
+    "handelsstadt",
+    "handelsvertretungen",
+    "handelt",
+    "handelte",
+    "handfeste",
+    "handfester",
+    "handfestes",
+    "handgelenken",
+    "handgreiflichen",
+    "handgriffe",
+    "handgrosses",
+    "handhabe",
+    "handhaben",
+    "handhabte",
+    "handhabten",
+    "handkarren",
+    "handkuss",
+    "handkuß",
+    "handlich",
+    "handlichen",
+    "handlichkeit",
+    "handlung",
+    "handlungen",
+    "handlungsart",
+    "handlungsschwanger",
+    "handlungsschwangerer",
+    "handlungsstarke",
+    "handlungsweise",
+    "handreibungen",
+    "handreichung",
+    "handschrift",
+    "handschuh",
+    "handschuhe",
+    "handschuhen",
+    "handstreich",
+    "handstreiche",
+    "handtasche",
+    "handtäschchen",
+    "handumdrehen",
+    "handvoll",
+    "handwerk",
+    "handwerksbursch",
+    "handwerksburschen",
+    "handwerksgesell",
+    "handwerksgesellen",
+    "handzwehle",
+    "hanfschnüren",
+    "hange",
+    "hangen",
+    "hanges",
+    "hannibal",
+    "hans",
+    "hansnarren",
+    "hantierte",
+    "hantierung",
+    "hantierungen",
+    "hapert",
+    "hapertes",
+    "happs",
+    "harald",
+    "harbst",
+    "harfe",
+    "harfen",
+    "harfenspieler",
+    "harfentöne",
+    "harkenstiel",
+    "harmlos",
+    "harmlose",
+    "harmlosen",
+    "harmloser",
+    "harmlosigkeit",
+    "harmlosigkeiten",
+    "harmonie",
+    "harmonien",
+    "harmonikaförmigen",
+    "harmonisch",
+    "harmonische",
+    "harmonists",
+    "harnisch",
+    "harnischreste",
+    "harren",
+    "harrend",
+    "harrten",
+    "hart",
+    "harte",
+    "hartem",
+    "harten",
+    "harter",
+    "hartes",
+    "hartherzige",
+    "hartherzigem",
+    "hartherzigen",
+    "hartherzigkeiten",
+    "hartmann",
+    "hartnäckig",
+    "hartnäckige",
+    "hartnäckigen",
+    "hartnäckiger",
+    "hartnäckigeren",
+    "hartnäckigkeit",
+    "hartnäckigste",
+    "hartwerden",
+    "harz",
+    "hasan",
+    "hasardspiel",
+    "haschen",
+    "haschte",
+    "haselanten",
+    "haselnussstauden",
+    "haselwanter",
+    "hasen",
+    "haserl",
+    "haslers",
+    "haspelte",
+    "hass",
+    "hasse",
+    "hassen",
+    "hasser",
+    "hasses",
+    "hasst",
+    "hasste",
+    "hast",
+    "hasterer",
+    "hastig",
+    "hastige",
+    "hastigen",
+    "hat",
+    "hatr",
+    "hats",
+    "hatschen",
+    "hatt",
+    "hatte",
+    "hatten",
+    "hattest",
+    "hau",
+    "haube",
+    "hauben",
+    "hauch",
+    "hauchen",
+    "hauchte",
+    "hauchten",
+    "hauen",
+    "haufe",
+    "haufen",
+    "haufenweise",
+    "haupt",
+    "hauptaufgabe",
+    "hauptbeschäftigung",
+    "hauptbestandteil",
+    "hauptbürde",
+    "hauptchristen",
+    "haupte",
+    "haupteingang",
+    "hauptes",
+    "hauptgattungen",
+    "hauptgebäude",
+    "hauptgegenstände",
+    "hauptliebhaberei",
+    "hauptmann",
+    "hauptmittel",
+    "hauptort",
+    "hauptperson",
+    "hauptpunkte",
+    "hauptpunkten",
+    "hauptquartier",
+    "hauptredaktor",
+    "hauptsache",
+    "hauptsachen",
+    "hauptschmuckes",
+    "hauptsitzung",
+    "hauptspass",
+    "hauptstadt",
+    "hauptstrasse",
+    "hauptstück",
+    "hauptstücke",
+    "hauptsächlich",
+    "hauptsächlichstes",
+    "haupttafel",
+    "haupttreppe",
+    "haupttätigkeit",
+    "hauptursache",
+    "hauptverkehrsstrasse",
+    "hauptveränderung",
+    "hauptwahlen",
+    "hauptwechsel",
+    "hauptweg",
+    "hauptwert",
+    "hauptziel",
+    "hauptzierde",
+    "hauptzweck",
+    "haus",
+    "hausapotheke",
+    "hausbackenes",
+    "hausbau",
+    "hausbesetzer",
+    "hausdurchsuchungen",
+    "hause",
+    "hausecke",
+    "hausehre",
+    "hausen",
+    "hauses",
+    "hausflur",
+    "hausfrau",
+    "hausfreund",
+    "hausgebrauch",
+    "hausgeister",
+    "hausgenossin",
+    "hausglocke",
+    "haushalte",
+    "hausherr",
+    "hausknecht",
+    "hausknechte",
+    "hausmütterlich",
+    "hausrat",
+    "hausschlüssel",
+    "hausschwelle",
+    "hausstande",
+    "hauste",
+    "haustierchen",
+    "haustracht",
+    "haustür",
+    "haustüre",
+    "haustüren",
+    "hausvater",
+    "hauswesen",
+    "haut",
+    "hautfalte",
+    "hautfarbe",
+    "hautnah",
+    "hazim",
+    "hazims",
+    "haß",
+    "haßerfüllten",
+    "haßt",
+    "haßte",
+    "hc",
+    "hebe",
+    "heben",
+    "hebend",
+    "hebet",
+    "hebopfer",
+    "hebr",
+    "hebräer",
+    "hebräerinnen",
+    "hebräisch",
+    "hebräische",
+    "hebräischen",
+    "hebt",
+    "hecht",
+    "hechtbauche",
+    "hechte",
+    "hechtes",
+    "hechtseiten",
+    "hecken",
+    "heer",
+    "heere",
+    "heeren",
+    "heeres",
+    "heerhaufen",
+    "heerlager",
+    "hefe",
+    "heft",
+    "heften",
+    "heftete",
+    "heftig",
+    "heftige",
+    "heftigem",
+    "heftigen",
+    "heftiger",
+    "heftiges",
+    "heftigkeit",
+    "heftigste",
+    "heftigsten",
+    "hege",
+    "hegen",
+    "hegt",
+    "hegte",
+    "hegten",
+    "hehr",
+    "hei",
+    "heiden",
+    "heidengeld",
+    "heidi",
+    "heidnischen",
+    "heiducken",
+    "heil",
+    "heiland",
+    "heilbar",
+    "heile",
+    "heilig",
+    "heilige",
+    "heiligem",
+    "heiligen",
+    "heiligenschein",
+    "heiliger",
+    "heiliges",
+    "heiligkeit",
+    "heiligste",
+    "heiligsten",
+    "heiligte",
+    "heiligtum",
+    "heiligtümer",
+    "heiligung",
+    "heiligungen",
+    "heillosen",
+    "heils",
+    "heilsamer",
+    "heilsamkeit",
+    "heilstoll",
+    "heilt",
+    "heilte",
+    "heilung",
+    "heim",
+    "heimat",
+    "heimatdorf",
+    "heimatkunde",
+    "heimatlich",
+    "heimatlichen",
+    "heimatlosen",
+    "heimatschein",
+    "heimatsrecht",
+    "heimattal",
+    "heimgalopp",
+    "heimgebracht",
+    "heimgekehrt",
+    "heimgekommen",
+    "heimgeschickt",
+    "heimgeschickten",
+    "heimgesucht",
+    "heimisch",
+    "heimischen",
+    "heimkam",
+    "heimkehr",
+    "heimkommen",
+    "heimkommt",
+    "heimlaufen",
+    "heimlich",
+    "heimliche",
+    "heimlichem",
+    "heimlichen",
+    "heimlicher",
+    "heimlicherweise",
+    "heimliches",
+    "heimschicken",
+    "heimsuchen",
+    "heimsuchung",
+    "heimtückisch",
+    "heimtückische",
+    "heimweg",
+    "heimwege",
+    "heimwegs",
+    "heimweh",
+    "heimwärts",
+    "heimzog",
+    "heimzukehren",
+    "heimzusuchen",
+    "heimzuweisen",
+    "heinrich",
+    "heinrichs",
+    "heinze",
+    "heinzer",
+    "heirat",
+    "heiraten",
+    "heiratest",
+    "heiratet",
+    "heiratete",
+    "heiratsabsichten",
+    "heiratsfrage",
+    "heiratsgut",
+    "heiratslustigen",
+    "heiratssachen",
+    "heiret",
+    "heiss",
+    "heisse",
+    "heissen",
+    "heisser",
+    "heisses",
+    "heisset",
+    "heisshunger",
+    "heissmachen",
+    "heisst",
+    "heiter",
+    "heitere",
+    "heiteren",
+    "heiterer",
+    "heiterkeit",
+    "heiterm",
+    "heizen",
+    "heizet",
+    "heiß",
+    "heiße",
+    "heißen",
+    "heißet",
+    "heißt",
+    "heks",
+    "hektik",
+    "hektor",
+    "helas",
+    "helbart",
+    "helbarte",
+    "helden",
+    "heldenhaftes",
+    "heldenwerk",
+    "helena",
+    "helfe",
+    "helfen",
+    "helfet",
+    "hell",
+    "hellauf",
+    "helldunklen",
+    "helle",
+    "hellem",
+    "hellen",
+    "heller",
+    "hellerleuchteten",
+    "hellerlichten",
+    "helles",
+    "hellgrüner",
+    "hellichten",
+    "hellsehend",
+    "hellsehenden",
+    "hellste",
+    "helmspitze",
+    "helmzier",
+    "helvetas",
+    "hemd",
+    "hemdchen",
+    "hemde",
+    "hemdenmätze",
+    "hemdenmätzen",
+    "hemdkragen",
+    "hemdzipfelchen",
+    "hemdärmel",
+    "hemdärmeln",
+    "hemdärmliche",
+    "hemmen",
+    "hendeln",
+    "henkel",
+    "henken",
+    "henker",
+    "henkers",
+    "henne",
+    "hennensteigen",
+    "henrici",
+    "henriettental",
+    "hep",
+    "herab",
+    "herabfallen",
+    "herabfliesst",
+    "herabfließt",
+    "herabgebracht",
+    "herabgekommen",
+    "herabgekommener",
+    "herabgelassen",
+    "herabgeworfen",
+    "herabhing",
+    "herabkam",
+    "herabkommen",
+    "herabkommt",
+    "herablassen",
+    "herablassend",
+    "herablässt",
+    "herabläßt",
+    "herabredete",
+    "herabregnen",
+    "herabsehend",
+    "herabsetzt",
+    "herabsinken",
+    "herabsinkt",
+    "herabstimmen",
+    "herabzusetzen",
+    "herabzustimmen",
+    "heran",
+    "heranbrach",
+    "heranbrüllte",
+    "herandrängte",
+    "heranfuhren",
+    "herangebraust",
+    "herangeflogen",
+    "herangeholt",
+    "herangekommen",
+    "herangesaust",
+    "herangeschwänzelt",
+    "herangetanzt",
+    "herangetreten",
+    "herangewachsen",
+    "herankommen",
+    "heranmachten",
+    "herannahen",
+    "heranrollte",
+    "heranrutschte",
+    "heranschwoll",
+    "herantobenden",
+    "herantritt",
+    "heranziehen",
+    "heranzubilden",
+    "heranzukommen",
+    "heranzuwehen",
+    "herauf",
+    "heraufbeförderte",
+    "heraufbeschworen",
+    "heraufbringen",
+    "heraufführen",
+    "heraufgefahren",
+    "heraufgewundenen",
+    "heraufkamen",
+    "heraufkommen",
+    "heraufkäme",
+    "heraufsteigen",
+    "heraufsteigt",
+    "heraufziehen",
+    "heraufzuholen",
+    "heraufzusäuseln",
+    "heraus",
+    "herausbrachte",
+    "herausbringen",
+    "herausbrüllten",
+    "herausfischen",
+    "herausfliegen",
+    "herausforderung",
+    "herausführen",
+    "herausführt",
+    "herausgeben",
+    "herausgefordert",
+    "herausgeführt",
+    "herausgegeben",
+    "herausgegriffen",
+    "herausgehen",
+    "herausgeholt",
+    "herausgekommen",
+    "herausgelassen",
+    "herausgelesen",
+    "herausgenommen",
+    "herausgereicht",
+    "herausgeschnitten",
+    "herausgestellt",
+    "herausgezaubert",
+    "herausgezogen",
+    "herausgezupft",
+    "herausguckten",
+    "heraushilft",
+    "herausholen",
+    "herauskam",
+    "herauskamen",
+    "herausklauben",
+    "herauskommen",
+    "herauskommt",
+    "herauskriegen",
+    "herauslügens",
+    "herausmachen",
+    "herausnehmen",
+    "herausnimmt",
+    "herausplatzt",
+    "herauspurzelten",
+    "herausreisst",
+    "herausreißt",
+    "herausschneiden",
+    "herausschrieb",
+    "heraussen",
+    "herausspritzte",
+    "heraussteigt",
+    "herausstellen",
+    "herausstellt",
+    "herausstiess",
+    "heraustauchte",
+    "heraustraten",
+    "heraustritt",
+    "herausziehen",
+    "herauszubringen",
+    "herauszufordern",
+    "herauszufragen",
+    "herauszuführen",
+    "herauszugehen",
+    "herauszukommen",
+    "herauszuschneiden",
+    "herauszuschreiben",
+    "herauszustreichen",
+    "herauszwingen",
+    "herbe",
+    "herbei",
+    "herbeibringen",
+    "herbeieilenden",
+    "herbeiführe",
+    "herbeiführt",
+    "herbeiführte",
+    "herbeiführung",
+    "herbeigebracht",
+    "herbeigeführt",
+    "herbeigeführte",
+    "herbeigekommen",
+    "herbeigelaufen",
+    "herbeigetragen",
+    "herbeigezogen",
+    "herbeikam",
+    "herbeikamen",
+    "herbeikommen",
+    "herbeikommt",
+    "herbeiströmen",
+    "herbeizuführen",
+    "herbeizuholen",
+    "herbeizuschaffen",
+    "herberge",
+    "herbergskammern",
+    "herbeste",
+    "herbringen",
+    "herbst",
+    "herbstabendhimmel",
+    "herbstabends",
+    "herbsteigen",
+    "herbstes",
+    "herbstmorgens",
+    "herbstnachmittag",
+    "herbstnebel",
+    "herbstsonnenschein",
+    "herbstwetter",
+    "herchen",
+    "herd",
+    "herde",
+    "herden",
+    "herdfeuer",
+    "herein",
+    "hereinbrechen",
+    "hereinbricht",
+    "hereinbräche",
+    "hereinbrächen",
+    "hereingebrachte",
+    "hereingebrachtes",
+    "hereingepeitscht",
+    "hereingesendet",
+    "hereingetölpelt",
+    "hereingeworfenes",
+    "hereinguckte",
+    "hereinkam",
+    "hereinkommen",
+    "hereinragte",
+    "hereinwatschelte",
+    "hereinzukommen",
+    "heresibus",
+    "herfahren",
+    "herflatterte",
+    "herflatterten",
+    "herg",
+    "hergeben",
+    "hergefahren",
+    "hergeführt",
+    "hergegeben",
+    "hergehen",
+    "hergehend",
+    "hergehst",
+    "hergeht",
+    "hergekommen",
+    "hergeleitet",
+    "hergeleuchtet",
+    "hergemacht",
+    "hergenommen",
+    "hergerufen",
+    "hergesagt",
+    "hergesandt",
+    "hergestellt",
+    "hergestellten",
+    "hergetrieben",
+    "hergewandert",
+    "hergezogen",
+    "herging",
+    "hering",
+    "heringe",
+    "heringen",
+    "herjagte",
+    "herkam",
+    "herkomme",
+    "herkommen",
+    "herkommenden",
+    "herkommt",
+    "herkunft",
+    "herkämen",
+    "hermann",
+    "hermarschiert",
+    "hermsdorf",
+    "hernach",
+    "hernahm",
+    "hernehmen",
+    "hernehmt",
+    "hernieder",
+    "herniederregneten",
+    "herniedersahen",
+    "herniedersank",
+    "hernimmt",
+    "heroisch",
+    "heroischer",
+    "heroismus",
+    "heros",
+    "herr",
+    "herreden",
+    "herren",
+    "herrengesellschaften",
+    "herrenhaus",
+    "herrenlos",
+    "herrenlose",
+    "herrenlosen",
+    "herrenrointhütte",
+    "herrentafel",
+    "herrentross",
+    "herrentroß",
+    "herrgott",
+    "herrgottswinkel",
+    "herrin",
+    "herrisch",
+    "herrlich",
+    "herrliche",
+    "herrlichem",
+    "herrlichen",
+    "herrlicher",
+    "herrliches",
+    "herrlichkeit",
+    "herrlichkeiten",
+    "herrlichste",
+    "herrlichsten",
+    "herrn",
+    "herrsch",
+    "herrschaft",
+    "herrschaften",
+    "herrschaftlichen",
+    "herrschaftlicher",
+    "herrsche",
+    "herrschen",
+    "herrschend",
+    "herrschende",
+    "herrschenden",
+    "herrschens",
+    "herrscherinnen",
+    "herrschest",
+    "herrschet",
+    "herrscht",
+    "herrschte",
+    "herrschten",
+    "herrühre",
+    "herrührt",
+    "herrührten",
+    "herschritt",
+    "herstammend",
+    "herstammende",
+    "herstammenden",
+    "herstammt",
+    "herstammte",
+    "herstammten",
+    "herstelle",
+    "herstellen",
+    "hersteller",
+    "herstellung",
+    "herum",
+    "herumbiegend",
+    "herumfliegt",
+    "herumflog",
+    "herumführte",
+    "herumgeflogen",
+    "herumgefuchtelt",
+    "herumgehen",
+    "herumgeschlagen",
+    "herumgeschlüpft",
+    "herumgeschnitten",
+    "herumgesprochen",
+    "herumgetrieben",
+    "herumging",
+    "herumhing",
+    "herumhocken",
+    "herumhuschten",
+    "herumkollerte",
+    "herumlagen",
+    "herumlaufe",
+    "herumlaufen",
+    "herumlief",
+    "herumliefe",
+    "herumliegen",
+    "herumlungern",
+    "herumlungerns",
+    "herumplätscherte",
+    "herumrutschten",
+    "herumscharrten",
+    "herumschleichen",
+    "herumschleppte",
+    "herumschlichen",
+    "herumschlug",
+    "herumschlugen",
+    "herumschweben",
+    "herumschweifen",
+    "herumschwenken",
+    "herumspazieren",
+    "herumsprang",
+    "herumsprangen",
+    "herumstand",
+    "herumstanden",
+    "herumstocherte",
+    "herumstreicht",
+    "herumstreifende",
+    "herumstreifte",
+    "herumstreite",
+    "herumstrich",
+    "herumständern",
+    "herumsäete",
+    "herumtappt",
+    "herumtragen",
+    "herumtreibe",
+    "herumtreiben",
+    "herumtreiber",
+    "herumtrug",
+    "herumtrüge",
+    "herumtummelte",
+    "herumtänzeln",
+    "herumwandle",
+    "herumwarf",
+    "herumwälzte",
+    "herumzerrt",
+    "herumzerrte",
+    "herumziehende",
+    "herumzufuchteln",
+    "herumzukreuzen",
+    "herumzurasen",
+    "herumzuschweifen",
+    "herumzustreichen",
+    "herumzusummen",
+    "herumzutummeln",
+    "herumzuturnen",
+    "herumzuziehen",
+    "herumzuärgern",
+    "herunter",
+    "herunterblickte",
+    "herunterfinden",
+    "herunterflog",
+    "heruntergebracht",
+    "heruntergebrannt",
+    "heruntergekommen",
+    "heruntergekommene",
+    "herunterholen",
+    "herunterkam",
+    "herunterkommen",
+    "herunternehmen",
+    "herunterpurzeln",
+    "herunterpurzelten",
+    "herunterstreifte",
+    "herunterwarf",
+    "herunterwehte",
+    "herunterwickelte",
+    "herunterziehen",
+    "herunterzuholen",
+    "herunterzuschlagen",
+    "herunterzustossen",
+    "hervor",
+    "hervorblitzte",
+    "hervorbrach",
+    "hervorbrachte",
+    "hervorbrechen",
+    "hervorbrechenden",
+    "hervorbricht",
+    "hervorbringe",
+    "hervorbringen",
+    "hervorbringt",
+    "hervorbringung",
+    "hervorfliessen",
+    "hervorfließen",
+    "hervorgebracht",
+    "hervorgebrochen",
+    "hervorgegangen",
+    "hervorgegangene",
+    "hervorgehen",
+    "hervorgehende",
+    "hervorgehenden",
+    "hervorgeholt",
+    "hervorgeht",
+    "hervorgerufen",
+    "hervorgetan",
+    "hervorgetreten",
+    "hervorgewandelt",
+    "hervorgezogen",
+    "hervorgingen",
+    "hervorheben",
+    "hervorholen",
+    "hervorholten",
+    "hervorkehrte",
+    "hervorkommt",
+    "hervorkriechen",
+    "hervorleuchtet",
+    "hervorleuchtete",
+    "hervornahm",
+    "hervorquillt",
+    "hervorragender",
+    "hervorragten",
+    "hervorsahen",
+    "hervorschielte",
+    "hervorschimmern",
+    "hervorschimmerten",
+    "hervorsprang",
+    "hervorsprossen",
+    "hervorsprudeln",
+    "hervorstechend",
+    "hervorstrahlende",
+    "hervorstrahlt",
+    "hervorstrahlte",
+    "hervorsuchte",
+    "hervortaten",
+    "hervortrat",
+    "hervortraten",
+    "hervortreten",
+    "hervortritt",
+    "hervortrittst",
+    "hervortut",
+    "hervorwallenden",
+    "hervorwimmeln",
+    "hervorziehen",
+    "hervorzog",
+    "hervorzubringen",
+    "hervorzuheben",
+    "hervorzuholen",
+    "hervorzutreten",
+    "herwachsen",
+    "herweg",
+    "herz",
+    "herze",
+    "herzeleid",
+    "herzen",
+    "herzens",
+    "herzensfröhlichkeit",
+    "herzensgründen",
+    "herzensgutes",
+    "herzenshärtigkeit",
+    "herzensqual",
+    "herzensunterwerfung",
+    "herzensweh",
+    "herzerl",
+    "herzhafte",
+    "herziehen",
+    "herziehens",
+    "herziges",
+    "herzigs",
+    "herzklopfen",
+    "herzlich",
+    "herzliche",
+    "herzlichen",
+    "herzlos",
+    "herzloser",
+    "herzmanndi",
+    "herzmannskerl",
+    "herzmannski",
+    "herzmannskis",
+    "herzog",
+    "herzogin",
+    "herzogs",
+    "herzschlag",
+    "herzschlägen",
+    "herzu",
+    "herzufallen",
+    "herzuführen",
+    "herzugeben",
+    "herzugebrachten",
+    "herzugeritten",
+    "herzugetragen",
+    "herzunahen",
+    "herzurühren",
+    "herzuschaffen",
+    "herzustellen",
+    "herzutragen",
+    "herzutreten",
+    "herzuträte",
+    "herüber",
+    "herübergeworfen",
+    "herüberkommen",
+    "herüberzukommen",
+    "hes",
+    "hesekiel",
+    "heteronomie",
+    "hethiter",
+    "hettlinger",
+    "hetze",
+    "hetzen",
+    "hetzjagd",
+    "hetzte",
+    "heu",
+    "heuboden",
+    "heuchelei",
+    "heucheln",
+    "heuchelt",
+    "heuchler",
+    "heuchlerischem",
+    "heugabel",
+    "heulen",
+    "heulend",
+    "heulender",
+    "heulet",
+    "heult",
+    "heulte",
+    "heulten",
+    "heuschiff",
+    "heuschrecke",
+    "heuschrecken",
+    "heuschreckenschwarm",
+    "heuss",
+    "heustock",
+    "heut",
+    "heute",
+    "heutige",
+    "heutigen",
+    "heutiges",
+    "heutzutage",
+    "heviter",
+    "heviters",
+    "hexe",
+    "hexen",
+    "hexenküche",
+    "hexenmeister",
+    "hexenmeisters",
+    "hexenwerk",
+    "hexer",
+    "hexerei",
+    "hg",
+    "hie",
+    "hieb",
+    "hiebe",
+    "hiebei",
+    "hieber",
+    "hiedurch",
+    "hiefür",
+    "hiegegen",
+    "hieher",
+    "hielt",
+    "hielte",
+    "hielten",
+    "hiemit",
+    "hienach",
+    "hier",
+    "hieran",
+    "hierarchie",
+    "hierauf",
+    "hieraus",
+    "hierbei",
+    "hierbleiben",
+    "hierdurch",
+    "hierfür",
+    "hierher",
+    "hierhergekommen",
+    "hierherkamen",
+    "hierherzukommen",
+    "hierhin",
+    "hierin",
+    "hierlassen",
+    "hiermit",
+    "hieroglyphen",
+    "hierunter",
+    "hiervon",
+    "hierzu",
+    "hierzubleiben",
+    "hierzulande",
+    "hierüber",
+    "hiesigen",
+    "hiess",
+    "hiesse",
+    "hiessen",
+    "hievon",
+    "hiezu",
+    "hieß",
+    "hieße",
+    "hießen",
+    "hiifsregisseur",
+    "hildegard",
+    "hildesheim",
+    "hilf",
+    "hilfe",
+    "hilflos",
+    "hilflosigkeit",
+    "hilfsbereitschaft",
+    "hilfsbremser",
+    "hilfskräfte",
+    "hilfsmittel",
+    "hilfsquellen",
+    "hilfsregisseur",
+    "hilfsregisseurs",
+    "hilfswerk",
+    "hilfswerke",
+    "hilft",
+    "himmel",
+    "himmelbett",
+    "himmelblaue",
+    "himmelblauen",
+    "himmelblaues",
+    "himmelerschütterndem",
+    "himmelhoch",
+    "himmelhohe",
+    "himmelhohen",
+    "himmeln",
+    "himmelreich",
+    "himmels",
+    "himmelsbesen",
+    "himmelsbewohnern",
+    "himmelschoten",
+    "himmelschreiendes",
+    "himmelsfeste",
+    "himmelsfürst",
+    "himmelsgeheimnissen",
+    "himmelsgestirn",
+    "himmelsgrund",
+    "himmelsheeren",
+    "himmelsheim",
+    "himmelshöhen",
+    "himmelskühe",
+    "himmelslicht",
+    "himmelslichtes",
+    "himmelsraketenmässig",
+    "himmelsraum",
+    "himmelsraums",
+    "himmelsrichtungen",
+    "himmelsschön",
+    "himmelssphäre",
+    "himmelsweide",
+    "himmelswiese",
+    "himmelszelt",
+    "himmelsziegen",
+    "himmelsziegenhörnchen",
+    "himmelweit",
+    "himmelwärts",
+    "himmlisch",
+    "himmlische",
+    "himmlischem",
+    "himmlischen",
+    "himmlischer",
+    "himmlisches",
+    "hin",
+    "hinab",
+    "hinabfahren",
+    "hinabfuhr",
+    "hinabgegangen",
+    "hinabgelassen",
+    "hinabgerissen",
+    "hinabgesprungen",
+    "hinabgestossen",
+    "hinabgestoßen",
+    "hinabgestürzt",
+    "hinabgeworfen",
+    "hinabgingen",
+    "hinabkommen",
+    "hinablassen",
+    "hinabsanken",
+    "hinabsankst",
+    "hinabsausten",
+    "hinabsenkt",
+    "hinabsinken",
+    "hinabspringen",
+    "hinabsteigen",
+    "hinabstossen",
+    "hinabstoßen",
+    "hinabtauchte",
+    "hinabtreibend",
+    "hinabversetzt",
+    "hinabwerfen",
+    "hinabziehen",
+    "hinabzog",
+    "hinabzugehen",
+    "hinabzusteigen",
+    "hinabzustürzen",
+    "hinan",
+    "hinanfuhren",
+    "hinangeleiten",
+    "hinanstieg",
+    "hinauf",
+    "hinaufbringen",
+    "hinauffuhrwerkte",
+    "hinaufführen",
+    "hinaufführte",
+    "hinaufgefahren",
+    "hinaufgehen",
+    "hinaufgehoben",
+    "hinaufgeschossen",
+    "hinaufgestiegen",
+    "hinaufgestrauchelt",
+    "hinaufgetrieben",
+    "hinaufging",
+    "hinaufkommen",
+    "hinauflief",
+    "hinaufrufen",
+    "hinaufsprang",
+    "hinaufspähte",
+    "hinaufsteigen",
+    "hinaufstieg",
+    "hinaufstreckte",
+    "hinaufstürmte",
+    "hinaufwollte",
+    "hinaufzuführen",
+    "hinaufzukommen",
+    "hinaufzutreiben",
+    "hinaus",
+    "hinausarbeiten",
+    "hinausbefördert",
+    "hinausbegleiten",
+    "hinausfliehen",
+    "hinausg",
+    "hinausgebauten",
+    "hinausgeführt",
+    "hinausgegangen",
+    "hinausgehe",
+    "hinausgehen",
+    "hinausgeht",
+    "hinausgeleitet",
+    "hinausgerücktes",
+    "hinausgeschafft",
+    "hinausgeschlichen",
+    "hinausgeschnitten",
+    "hinausgesetzt",
+    "hinausgesprengt",
+    "hinausgeworfen",
+    "hinausginge",
+    "hinausgingen",
+    "hinauskommen",
+    "hinauskomplementiert",
+    "hinausläuft",
+    "hinaussahen",
+    "hinausschallte",
+    "hinausschauen",
+    "hinausschaute",
+    "hinausschieben",
+    "hinausschrie",
+    "hinausschweift",
+    "hinaussieht",
+    "hinausspringen",
+    "hinausspringenden",
+    "hinausstossen",
+    "hinausstoßen",
+    "hinaustrat",
+    "hinaustreten",
+    "hinaustrollte",
+    "hinauswandern",
+    "hinauswillst",
+    "hinauszufliegen",
+    "hinauszustossen",
+    "hinauszustoßen",
+    "hinauszuziehen",
+    "hinbeugte",
+    "hinblicken",
+    "hinblickte",
+    "hindern",
+    "hindernis",
+    "hindernisse",
+    "hindernisses",
+    "hindert",
+    "hinderte",
+    "hindin",
+    "hindinnen",
+    "hindostanische",
+    "hindu",
+    "hindurch",
+    "hindurchfliessen",
+    "hindurchfliessens",
+    "hindurchfliesst",
+    "hindurchfließen",
+    "hindurchfließens",
+    "hindurchfließt",
+    "hindurchfloss",
+    "hindurchfloß",
+    "hindurchführte",
+    "hindurchgegangen",
+    "hindurchgehende",
+    "hindurchgeht",
+    "hindurchgeschlüpft",
+    "hindurchging",
+    "hineilte",
+    "hinein",
+    "hineinbringen",
+    "hineinfahren",
+    "hineinfällt",
+    "hineingebaut",
+    "hineingebracht",
+    "hineingefahren",
+    "hineingegangen",
+    "hineingehen",
+    "hineingeht",
+    "hineingekrochen",
+    "hineingeladen",
+    "hineingelegten",
+    "hineingeraten",
+    "hineingesehen",
+    "hineingesperrt",
+    "hineingeworfen",
+    "hineingeworfene",
+    "hineinging",
+    "hineingrub",
+    "hineinkleben",
+    "hineinkomme",
+    "hineinkommen",
+    "hineinladen",
+    "hineinlegen",
+    "hineinliess",
+    "hineinritt",
+    "hineinschien",
+    "hineinschrie",
+    "hineinsprang",
+    "hineinstellte",
+    "hineinstrecken",
+    "hineintrugen",
+    "hineinträgt",
+    "hineinversetzen",
+    "hineinversetzt",
+    "hineinwerfen",
+    "hineinwuchs",
+    "hineinzukleben",
+    "hineinzulassen",
+    "hineinzuschreiten",
+    "hineinzusehen",
+    "hineinzuspringen",
+    "hineinzuwandeln",
+    "hinfiel",
+    "hinfort",
+    "hinführen",
+    "hing",
+    "hingabe",
+    "hingaben",
+    "hingeben",
+    "hingebettet",
+    "hingebung",
+    "hingebungsvoll",
+    "hingeführt",
+    "hingegangen",
+    "hingegeben",
+    "hingegebenes",
+    "hingegen",
+    "hingehalten",
+    "hingehen",
+    "hingekommen",
+    "hingeleiten",
+    "hingelenkt",
+    "hingen",
+    "hingenommen",
+    "hingerissen",
+    "hingeschickt",
+    "hingeschieden",
+    "hingesehen",
+    "hingestellt",
+    "hingestreckt",
+    "hingestreut",
+    "hingetragen",
+    "hingetreten",
+    "hingewandert",
+    "hingewandt",
+    "hingewendet",
+    "hingewiesen",
+    "hingeworfen",
+    "hinging",
+    "hinglitt",
+    "hinhalten",
+    "hinhältst",
+    "hinirren",
+    "hinkam",
+    "hinken",
+    "hinkenden",
+    "hinkendes",
+    "hinklopft",
+    "hinkommen",
+    "hinkt",
+    "hinleg",
+    "hinlegen",
+    "hinlief",
+    "hinlänglich",
+    "hinlängliche",
+    "hinnahm",
+    "hinnehmen",
+    "hinneigen",
+    "hinneigt",
+    "hinneigten",
+    "hinnen",
+    "hinrasen",
+    "hinreiche",
+    "hinreichen",
+    "hinreichend",
+    "hinreichende",
+    "hinreichenden",
+    "hinreichender",
+    "hinreichte",
+    "hinreissen",
+    "hinreissend",
+    "hinreißen",
+    "hinsah",
+    "hinsandte",
+    "hinschauten",
+    "hinscheiden",
+    "hinschiessenden",
+    "hinschnitt",
+    "hinschob",
+    "hinschritt",
+    "hinschwebte",
+    "hinsehen",
+    "hinsetzend",
+    "hinsicht",
+    "hinsichtlich",
+    "hinsieht",
+    "hinsingt",
+    "hinspediert",
+    "hinstellen",
+    "hinstellte",
+    "hinsterben",
+    "hinstreckten",
+    "hinstürzt",
+    "hinstürzte",
+    "hinten",
+    "hintendrein",
+    "hinter",
+    "hintereinander",
+    "hinteren",
+    "hinterfragen",
+    "hinterfragt",
+    "hinterfüsse",
+    "hintergehen",
+    "hintergestell",
+    "hintergrund",
+    "hintergrunde",
+    "hintergrundes",
+    "hintergrundregelmässig",
+    "hinterhalt",
+    "hinterher",
+    "hinterhoftheater",
+    "hinterlassen",
+    "hinterlegten",
+    "hinterliess",
+    "hinterlist",
+    "hinterlistigen",
+    "hintern",
+    "hinterrücks",
+    "hinters",
+    "hinterstübchen",
+    "hintertreiben",
+    "hintrat",
+    "hintreten",
+    "hintri",
+    "hinunter",
+    "hinunterblicken",
+    "hinunterfahren",
+    "hinunterführte",
+    "hinuntergebeugt",
+    "hinuntergesprungen",
+    "hinuntergestossen",
+    "hinuntergestoßen",
+    "hinunterging",
+    "hinunterhängenden",
+    "hinunterkommen",
+    "hinunterlachte",
+    "hinunterrutschen",
+    "hinuntersah",
+    "hinunterschaute",
+    "hinuntersenkte",
+    "hinunterstossen",
+    "hinunterstoßen",
+    "hinuntertragen",
+    "hinunterwerfen",
+    "hinunterzerrte",
+    "hinwandelt",
+    "hinwanderten",
+    "hinwandte",
+    "hinweg",
+    "hinweggegangen",
+    "hinweggehen",
+    "hinweggeholfen",
+    "hinweggerattert",
+    "hinwegkommen",
+    "hinwegliefen",
+    "hinwegnehmen",
+    "hinwegschaffen",
+    "hinweise",
+    "hinweisen",
+    "hinwenden",
+    "hinwendung",
+    "hinwerfen",
+    "hinwerfung",
+    "hinwieder",
+    "hinziehen",
+    "hinzog",
+    "hinzogen",
+    "hinzu",
+    "hinzubegeben",
+    "hinzublicken",
+    "hinzufüge",
+    "hinzufügen",
+    "hinzufügt",
+    "hinzuführen",
+    "hinzugefügt",
+    "hinzugehen",
+    "hinzugekommen",
+    "hinzugesetzt",
+    "hinzugetan",
+    "hinzuhalten",
+    "hinzukommen",
+    "hinzukommenden",
+    "hinzulegen",
+    "hinzuschielen",
+    "hinzuschleichen",
+    "hinzusehen",
+    "hinzustrecken",
+    "hinzustürzen",
+    "hinzutraten",
+    "hinzutreten",
+    "hinzutun",
+    "hinzuweisen",
+    "hinzuzufügen",
+    "hinüber",
+    "hinübergehen",
+    "hinübergetragen",
+    "hinübergewandt",
+    "hinüberkommen",
+    "hinüberreiten",
+    "hinüberrudern",
+    "hinübersah",
+    "hinüberschritten",
+    "hinübersehen",
+    "hinübertragen",
+    "hiob",
+    "hirn",
+    "hirngespenstern",
+    "hirngespinst",
+    "hirngespinste",
+    "hirnhäute",
+    "hirnschale",
+    "hirsch",
+    "hirsche",
+    "hirschen",
+    "hirsches",
+    "hirschgeweih",
+    "hirschkuh",
+    "hirt",
+    "hirte",
+    "hirten",
+    "historie",
+    "historiker",
+    "historikerin",
+    "historikers",
+    "historisch",
+    "historische",
+    "historischen",
+    "historischer",
+    "hitler",
+    "hitlerrede",
+    "hitz",
+    "hitze",
+    "hitzige",
+    "hitzigen",
+    "hitziger",
+    "hitzkopf",
+    "hm",
+    "hmmm",
+    "ho",
+    "hob",
+    "hobelbänke",
+    "hoben",
+    "hoc",
+    "hoch",
+    "hochachtung",
+    "hochachtungsvohlst",
+    "hochamt",
+    "hochaufatmend",
+    "hochdeutsch",
+    "hochebene",
+    "hochfahrenden",
+    "hochfuhr",
+    "hochgebettete",
+    "hochgebrochenen",
+    "hochgefahren",
+    "hochgehoben",
+    "hochgelegen",
+    "hochgenuss",
+    "hochgepriesene",
+    "hochgeschäfteten",
+    "hochgewölbten",
+    "hochgipfel",
+    "hochheilig",
+    "hochheilige",
+    "hochheiliges",
+    "hochkam",
+    "hochklingenden",
+    "hochmoore",
+    "hochmut",
+    "hochmütig",
+    "hochmütige",
+    "hochnäsig",
+    "hochnäsige",
+    "hochpreisungen",
+    "hochsahen",
+    "hochschätzung",
+    "hochsitze",
+    "hochsommer",
+    "hochstapfen",
+    "hochstämmige",
+    "hochtrabende",
+    "hochverpönt",
+    "hochverpönte",
+    "hochverpöntes",
+    "hochverrat",
+    "hochwild",
+    "hochzeit",
+    "hochzeitbett",
+    "hochzeiten",
+    "hochzeiter",
+    "hochzeitgeleite",
+    "hochzeitgäste",
+    "hochzeitkammer",
+    "hochzeitmahl",
+    "hochzeitmorgen",
+    "hochzeitpaar",
+    "hochzeitstruhe",
+    "hochzet",
+    "hochzubrüllen",
+    "hockte",
+    "hockten",
+    "hof",
+    "hofe",
+    "hofes",
+    "hoffart",
+    "hoffe",
+    "hoffen",
+    "hoffend",
+    "hoffenster",
+    "hoffentlich",
+    "hoffmann",
+    "hoffmannstropfen",
+    "hoffnung",
+    "hoffnungen",
+    "hoffnungsarme",
+    "hoffnungsfaser",
+    "hoffnungsfreudig",
+    "hoffnungslos",
+    "hoffnungslose",
+    "hoffnungsloser",
+    "hoffnungslosigkeit",
+    "hoffnungsreichem",
+    "hoffnungsvolle",
+    "hoffte",
+    "hoffärtigen",
+    "hoffärtiger",
+    "hofhaltung",
+    "hofleben",
+    "hofleute",
+    "hofrat",
+    "hofraum",
+    "hofstaats",
+    "hofwinkels",
+    "hohe",
+    "hoheit",
+    "hohem",
+    "hohen",
+    "hoher",
+    "hohes",
+    "hohfentlich",
+    "hohle",
+    "hohlem",
+    "hohlen",
+    "hohler",
+    "hohles",
+    "hohlziegel",
+    "hohn",
+    "hoho",
+    "hol",
+    "holde",
+    "holden",
+    "holdes",
+    "holdselig",
+    "holdseligste",
+    "holen",
+    "holimanns",
+    "holla",
+    "holle",
+    "holocaust",
+    "holsteiner",
+    "holt",
+    "holte",
+    "holten",
+    "holterdiepolter",
+    "holunderbäume",
+    "holz",
+    "holzbank",
+    "holzdieb",
+    "holze",
+    "holzes",
+    "holzgetäfel",
+    "holzhauer",
+    "holzkannen",
+    "holzkeil",
+    "holzknecht",
+    "holzknechte",
+    "holzkreuz",
+    "holzscheite",
+    "holzschwert",
+    "holzstall",
+    "holzstoss",
+    "holztisch",
+    "holztreppe",
+    "homepage",
+    "homers",
+    "hon",
+    "honecker",
+    "honetter",
+    "honig",
+    "honigfässchen",
+    "honigfäßchen",
+    "honigs",
+    "honigscheibe",
+    "honneurs",
+    "honorar",
+    "hooch",
+    "hopp",
+    "hoppelt",
+    "hoppla",
+    "hopsele",
+    "horche",
+    "horchen",
+    "horcht",
+    "horchte",
+    "horchten",
+    "horeb",
+    "horizonte",
+    "horlinger",
+    "horn",
+    "hornbläsern",
+    "hornstriegeln",
+    "horon",
+    "horst",
+    "horste",
+    "hos",
+    "hosas",
+    "hose",
+    "hosen",
+    "hosengürtel",
+    "hosenträgern",
+    "hosianna",
+    "hospiz",
+    "hospizgruppe",
+    "hotel",
+    "hoteltür",
+    "hub",
+    "huber",
+    "hubertus",
+    "hubertusjüngern",
+    "huckepack",
+    "huckige",
+    "hudelkopf",
+    "hudelvölkchen",
+    "huf",
+    "hufe",
+    "hufeisentreppe",
+    "hufen",
+    "huglieme",
+    "huhn",
+    "huhnes",
+    "huhu",
+    "hui",
+    "huld",
+    "huldigten",
+    "huldreich",
+    "huldreichen",
+    "humane",
+    "humanen",
+    "humanismus",
+    "humanistischen",
+    "humanitäre",
+    "humanitären",
+    "hume",
+    "humes",
+    "humische",
+    "humkoke",
+    "hummeln",
+    "humor",
+    "humorlose",
+    "humus",
+    "hund",
+    "hunde",
+    "hunden",
+    "hunderl",
+    "hundert",
+    "hundertdreiunddreissig",
+    "hundertdreiunddreißig",
+    "hunderte",
+    "hunderten",
+    "hunderterlei",
+    "hundertfach",
+    "hundertfacher",
+    "hundertfältig",
+    "hundertmal",
+    "hundertsiebenunddreissig",
+    "hundertsiebenunddreißig",
+    "hundertsten",
+    "hundertstimmigem",
+    "hunderttausend",
+    "hunderttausende",
+    "hundertundfünfzig",
+    "hundertvierundvierzig",
+    "hundertvierzig",
+    "hundertzwanzig",
+    "hundes",
+    "hundeseele",
+    "hundl",
+    "hundsfott",
+    "hundsseele",
+    "hunger",
+    "hungerlohn",
+    "hungern",
+    "hungers",
+    "hungerte",
+    "hungrig",
+    "hungrige",
+    "hungrigen",
+    "hungriger",
+    "hupften",
+    "hupschi",
+    "hure",
+    "hurend",
+    "hurerei",
+    "hurereien",
+    "hurra",
+    "hurtig",
+    "husaren",
+    "husch",
+    "huschen",
+    "huschte",
+    "huschten",
+    "husten",
+    "hut",
+    "hute",
+    "huwiler",
+    "hv",
+    "hyazinth",
+    "hyazinthfarbe",
+    "hypochondrischen",
+    "hypothekschulden",
+    "hypothese",
+    "hypothesen",
+    "hypothetische",
+    "hysterisch",
+    "häberlin",
+    "häcksel",
+    "häfelein",
+    "häftlein",
+    "häher",
+    "hähnekrähen",
+    "häkchen",
+    "häklich",
+    "häklichen",
+    "hälfte",
+    "hälse",
+    "hält",
+    "hältst",
+    "hämisch",
+    "hämmern",
+    "hämmerte",
+    "hämmerten",
+    "händchen",
+    "hände",
+    "händeklatschen",
+    "händel",
+    "händelführer",
+    "händeln",
+    "händels",
+    "händen",
+    "händereibend",
+    "händler",
+    "händlerin",
+    "hänge",
+    "hängen",
+    "hängend",
+    "hängenden",
+    "hängengeblieben",
+    "hängt",
+    "hängte",
+    "hängten",
+    "hänse",
+    "häretische",
+    "härmte",
+    "härte",
+    "härter",
+    "härtere",
+    "härteres",
+    "härteste",
+    "häschen",
+    "häslein",
+    "hässiges",
+    "hässlich",
+    "hässliche",
+    "hässlichen",
+    "hässlicheres",
+    "hässliches",
+    "hässlichsten",
+    "hätschelte",
+    "hätt",
+    "hätte",
+    "hätten",
+    "hättens",
+    "hättest",
+    "hättet",
+    "häufchen",
+    "häufen",
+    "häufig",
+    "häufige",
+    "häufiger",
+    "häuflein",
+    "häuft",
+    "häupten",
+    "häupter",
+    "häuptern",
+    "häuptling",
+    "häuptlingen",
+    "häuschen",
+    "häuser",
+    "häusermauer",
+    "häusermauern",
+    "häusern",
+    "häusl",
+    "häuslich",
+    "häusliche",
+    "häuslichen",
+    "häutchen",
+    "häute",
+    "häutige",
+    "häßlich",
+    "häßliche",
+    "häßlichen",
+    "häßlicheres",
+    "häßliches",
+    "höchlich",
+    "höchst",
+    "höchste",
+    "höchstem",
+    "höchsten",
+    "höchstens",
+    "höchster",
+    "höchstes",
+    "höchstnötig",
+    "höfe",
+    "höfen",
+    "höflich",
+    "höfliche",
+    "höflichem",
+    "höflichen",
+    "höflicher",
+    "höflichkeit",
+    "höflichkeiten",
+    "höft",
+    "höh",
+    "höhe",
+    "höhen",
+    "höhenflüge",
+    "höhenlüften",
+    "höhenstrichen",
+    "höhepunktes",
+    "höher",
+    "höhere",
+    "höherem",
+    "höheren",
+    "höherer",
+    "höheres",
+    "höhern",
+    "höhlen",
+    "höhlung",
+    "höhnisch",
+    "höhnischen",
+    "höhnisches",
+    "höhnten",
+    "höi",
+    "hölle",
+    "höllen",
+    "höllenfeuer",
+    "höllengeister",
+    "höllengeistern",
+    "höllenlärm",
+    "höllisch",
+    "höllische",
+    "höllischem",
+    "höllischen",
+    "höllischer",
+    "höllisches",
+    "hölzchen",
+    "hölzerne",
+    "hölzernen",
+    "hölzerner",
+    "hölzernes",
+    "hör",
+    "hörbar",
+    "höre",
+    "hören",
+    "hörend",
+    "hörende",
+    "hörer",
+    "hörerbrief",
+    "hörerin",
+    "hörest",
+    "höret",
+    "hörfolge",
+    "hörnchen",
+    "hörner",
+    "hörnern",
+    "hörrohr",
+    "hörspielabteilung",
+    "hörst",
+    "hört",
+    "hörte",
+    "hörten",
+    "hörtest",
+    "hüben",
+    "hübsch",
+    "hübsche",
+    "hübschen",
+    "hübscher",
+    "hübsches",
+    "hüfte",
+    "hüften",
+    "hügel",
+    "hügelchen",
+    "hügeligen",
+    "hügeliges",
+    "hügeln",
+    "hügels",
+    "hühner",
+    "hühnerjagd",
+    "hühnern",
+    "hühnerschwärmen",
+    "hülfe",
+    "hülflos",
+    "hülle",
+    "hüllen",
+    "hüllte",
+    "hülse",
+    "hündlein",
+    "hüpfende",
+    "hüpfenden",
+    "hüpfte",
+    "hüpften",
+    "hürde",
+    "hürden",
+    "hüseyin",
+    "hüstelte",
+    "hüt",
+    "hütchen",
+    "hüte",
+    "hüten",
+    "hüter",
+    "hüterbub",
+    "hütet",
+    "hütete",
+    "hüteten",
+    "hütl",
+    "hütte",
+    "hütten",
+    "hüttenfest",
+    "iang",
+    "ich",
+    "ichs",
+    "ideale",
+    "idealisch",
+    "idealischen",
+    "idee",
+    "ideen",
+    "ideenkiste",
+    "ideenulrich",
+    "identische",
+    "identitizierung",
+    "identität",
+    "ideologisierung",
+    "idion",
+    "idioten",
+    "ieh",
+    "ierte",
+    "igel",
+    "igelbürste",
+    "ignorieren",
+    "ihd",
+    "ihm",
+    "ihn",
+    "ihnen",
+    "ihr",
+    "ihre",
+    "ihrem",
+    "ihren",
+    "ihrer",
+    "ihres",
+    "ihrethalben",
+    "ihretwegen",
+    "ihrige",
+    "ihrigen",
+    "ihro",
+    "ii",
+    "iii",
+    "ikea",
+    "illa",
+    "ille",
+    "illegalen",
+    "illud",
+    "illumination",
+    "illusion",
+    "illusorisch",
+    "im",
+    "imago",
+    "imbiss",
+    "imgleichen",
+    "immanent",
+    "immanuel",
+    "immaterielle",
+    "immateriellen",
+    "immenstand",
+    "immer",
+    "immerdar",
+    "immerfort",
+    "immergrünen",
+    "immerhin",
+    "immerwährend",
+    "immerwährende",
+    "immerwährendes",
+    "immerzu",
+    "imogen",
+    "imperativ",
+    "imperative",
+    "imperativen",
+    "imperativs",
+    "impressum",
+    "impulse",
+    "imstande",
+    "in",
+    "inan",
+    "inbegriff",
+    "inbesondere",
+    "inbrunst",
+    "inbrünstig",
+    "indem",
+    "indes",
+    "indessen",
+    "indianer",
+    "indie",
+    "indien",
+    "indiennekleid",
+    "indierinnen",
+    "indifferent",
+    "indirekt",
+    "indischen",
+    "indobritischen",
+    "indoles",
+    "indulgent",
+    "industrie",
+    "ineinander",
+    "ineinandergefahren",
+    "inernet",
+    "infam",
+    "infanteriegewehr",
+    "infanteriekaserne",
+    "inferiore",
+    "inferiorität",
+    "infolge",
+    "infolgedessen",
+    "informationen",
+    "informationsbeauftragte",
+    "informieren",
+    "informiert",
+    "infos",
+    "ingenio",
+    "ingrimm",
+    "ingwer",
+    "inhaber",
+    "inhalt",
+    "inhalte",
+    "inhaltlich",
+    "inhalts",
+    "inhaltsverzeichnissen",
+    "initiative",
+    "inland",
+    "inlandsarbeit",
+    "inländischen",
+    "inmitten",
+    "inne",
+    "innegewordene",
+    "innegewordenen",
+    "innehalten",
+    "innehat",
+    "innehielt",
+    "innehielten",
+    "innen",
+    "innere",
+    "innerem",
+    "inneren",
+    "innerer",
+    "inneres",
+    "innerhalb",
+    "innerlich",
+    "innerliche",
+    "innerlichen",
+    "innerlicher",
+    "innerlichere",
+    "innerlicheres",
+    "innerliches",
+    "innern",
+    "innerste",
+    "innerstem",
+    "innersten",
+    "innerster",
+    "innerstes",
+    "innewerden",
+    "innewerdend",
+    "innewerdende",
+    "innewerdens",
+    "innewerdungen",
+    "innewohnen",
+    "innewohnend",
+    "innewohnt",
+    "innewohnte",
+    "innewurde",
+    "innezuwerden",
+    "innig",
+    "innige",
+    "innigen",
+    "inniger",
+    "innigere",
+    "innigkeit",
+    "inniglich",
+    "innigsten",
+    "inningst",
+    "innre",
+    "ins",
+    "insbesondere",
+    "insbesondereeine",
+    "insbesonders",
+    "inschrift",
+    "insekt",
+    "insekten",
+    "insektenbefall",
+    "insel",
+    "inselgruppe",
+    "inseln",
+    "inseratenkampagne",
+    "inservit",
+    "insgeheim",
+    "insgesamt",
+    "insitam",
+    "insofern",
+    "insonderheit",
+    "insonderlich",
+    "insoweit",
+    "instinkt",
+    "instinkte",
+    "instinkten",
+    "instinktiv",
+    "institut",
+    "institution",
+    "institutionen",
+    "instituts",
+    "instrumente",
+    "instrumenten",
+    "inszenierte",
+    "intellecta",
+    "intellectuales",
+    "intellektuell",
+    "intellektuelle",
+    "intellektuellem",
+    "intellektuellen",
+    "intellektuelles",
+    "intelligent",
+    "intelligente",
+    "intelligenz",
+    "intelligenzen",
+    "intelligibele",
+    "intelligibelen",
+    "intendant",
+    "intendanten",
+    "intensivität",
+    "inter",
+    "interessant",
+    "interessante",
+    "interessanten",
+    "interessanter",
+    "interessanterweise",
+    "interessantes",
+    "interesse",
+    "interessen",
+    "interessenvertretung",
+    "interesses",
+    "interessieren",
+    "interessiert",
+    "interessierte",
+    "interessierten",
+    "interesssant",
+    "interiori",
+    "interius",
+    "intermezzo",
+    "internationale",
+    "internationalen",
+    "internes",
+    "internetdie",
+    "interneteine",
+    "internetseiten",
+    "internetsurfenam",
+    "interpretieren",
+    "interview",
+    "intimeren",
+    "inwendig",
+    "inwendige",
+    "inwendigem",
+    "inwendigen",
+    "inwendiger",
+    "inwendigere",
+    "inwendigerem",
+    "inwendigeren",
+    "inwendigeres",
+    "inwendiges",
+    "inwendigst",
+    "inwendigste",
+    "inwendigsten",
+    "inwendigster",
+    "inwieweit",
+    "inzichten",
+    "inzwischen",
+    "ipso",
+    "irad",
+    "irard",
+    "irdene",
+    "irdenen",
+    "irdisch",
+    "irdische",
+    "irdischen",
+    "irdisches",
+    "irdisdien",
+    "irgend",
+    "irgendein",
+    "irgendeine",
+    "irgendeinem",
+    "irgendeinen",
+    "irgendeiner",
+    "irgendeines",
+    "irgendetwas",
+    "irgendwann",
+    "irgendwelche",
+    "irgendwelchen",
+    "irgendwelches",
+    "irgendwie",
+    "irgendwo",
+    "irland",
+    "irländischen",
+    "irn",
+    "ironie",
+    "ironisch",
+    "ironischen",
+    "irre",
+    "irreführendem",
+    "irregeführt",
+    "irregeführte",
+    "irreleitend",
+    "irren",
+    "irrenden",
+    "irret",
+    "irrgänger",
+    "irrlehre",
+    "irrlehren",
+    "irrlichter",
+    "irrlichterfischchen",
+    "irrsinnig",
+    "irrt",
+    "irrte",
+    "irrten",
+    "irrtum",
+    "irrtümer",
+    "irrtümern",
+    "irrwegen",
+    "isabelle",
+    "isabellen",
+    "isabellengespann",
+    "isabellenpferden",
+    "isgard",
+    "isherwood",
+    "ismail",
+    "isolation",
+    "isolationistische",
+    "isoliert",
+    "israel",
+    "israeliten",
+    "israelitische",
+    "israelitischen",
+    "israels",
+    "iss",
+    "issest",
+    "isset",
+    "isst",
+    "ist",
+    "istanbul",
+    "istanbuler",
+    "ists",
+    "italian",
+    "italien",
+    "italiener",
+    "italienisch",
+    "italienische",
+    "italienischen",
+    "italienisches",
+    "ithamar",
+    "iv",
+    "ix",
+    "izmir",
+    "ißt",
+    "j",
+    "ja",
+    "jaaa",
+    "jabal",
+    "jaccoud",
+    "jach",
+    "jache",
+    "jachen",
+    "jachenau",
+    "jachenauer",
+    "jacher",
+    "jachin",
+    "jachsten",
+    "jacke",
+    "jacobi",
+    "jadwiga",
+    "jaels",
+    "jagd",
+    "jagdart",
+    "jagdaufseher",
+    "jagdausflügen",
+    "jagdausrüstung",
+    "jagdführer",
+    "jagdgast",
+    "jagdgebieten",
+    "jagdgehilf",
+    "jagdgehilfe",
+    "jagdgehilfen",
+    "jagdgeräte",
+    "jagdgeschichten",
+    "jagdgrenze",
+    "jagdgrund",
+    "jagdherr",
+    "jagdhütte",
+    "jagdkarte",
+    "jagdtrophäen",
+    "jagdwagen",
+    "jagen",
+    "jagend",
+    "jagender",
+    "jager",
+    "jagerischen",
+    "jagern",
+    "jaget",
+    "jagt",
+    "jagte",
+    "jah",
+    "jahr",
+    "jahraus",
+    "jahre",
+    "jahrein",
+    "jahrelang",
+    "jahren",
+    "jahres",
+    "jahresbericht",
+    "jahresrechnung",
+    "jahresrente",
+    "jahrestagung",
+    "jahreszahlen",
+    "jahreszeit",
+    "jahrhundert",
+    "jahrhunderte",
+    "jahrhunderten",
+    "jahrhunderts",
+    "jahrl",
+    "jahrln",
+    "jahrmarkt",
+    "jahrmärkte",
+    "jahrs",
+    "jahrshoffnungen",
+    "jahrszeit",
+    "jahrtausendwende",
+    "jahrzahlen",
+    "jahrzehnten",
+    "jaja",
+    "jakob",
+    "jakobiner",
+    "jakobs",
+    "jakobsbrunnen",
+    "jamin",
+    "jammer",
+    "jammerbild",
+    "jammerfalten",
+    "jammergeschrei",
+    "jammern",
+    "jammernd",
+    "jammerpipps",
+    "jammerte",
+    "januar",
+    "janzer",
+    "japanischer",
+    "japheth",
+    "jappender",
+    "jared",
+    "jareds",
+    "jaspis",
+    "jatz",
+    "jauchzen",
+    "jauchzend",
+    "jauchzende",
+    "jauchzer",
+    "jauchzet",
+    "jaulte",
+    "javan",
+    "jawohl",
+    "je",
+    "jean",
+    "jebusiter",
+    "jebusiters",
+    "jede",
+    "jedem",
+    "jeden",
+    "jedenfalls",
+    "jeder",
+    "jedermann",
+    "jederzeit",
+    "jedes",
+    "jedesmal",
+    "jedoch",
+    "jedsmal",
+    "jedweden",
+    "jedweder",
+    "jefreut",
+    "jegliche",
+    "jeglichem",
+    "jeglichen",
+    "jeglicher",
+    "jegliches",
+    "jeher",
+    "jehorsamst",
+    "jehova",
+    "jehovah",
+    "jehovahs",
+    "jehovas",
+    "jehovih",
+    "jehudah",
+    "jehudahs",
+    "jehus",
+    "jekühlte",
+    "jelek",
+    "jemals",
+    "jemand",
+    "jemandem",
+    "jemanden",
+    "jemandes",
+    "jemands",
+    "jemuel",
+    "jene",
+    "jenem",
+    "jenen",
+    "jener",
+    "jenes",
+    "jenseitigen",
+    "jenseits",
+    "jereist",
+    "jerem",
+    "jeremias",
+    "jerusalem",
+    "jerusalems",
+    "jes",
+    "jesaja",
+    "jesajas",
+    "jesses",
+    "jesu",
+    "jesum",
+    "jesus",
+    "jethro",
+    "jethros",
+    "jetzigen",
+    "jetziges",
+    "jetzo",
+    "jetzt",
+    "jeweilige",
+    "jeweiligen",
+    "jeweils",
+    "jewish",
+    "jewriav",
+    "jez",
+    "jezuweilen",
+    "jhdt",
+    "jim",
+    "jisaschar",
+    "jischak",
+    "jischaks",
+    "jischmael",
+    "jischmaels",
+    "jizhar",
+    "jizhars",
+    "jletscherhafter",
+    "jobbt",
+    "jobbte",
+    "jobst",
+    "jobsten",
+    "jobstens",
+    "joch",
+    "jochebed",
+    "joches",
+    "jochs",
+    "jodelläufen",
+    "jodler",
+    "joel",
+    "joh",
+    "johann",
+    "johannes",
+    "johannis",
+    "johlend",
+    "jon",
+    "jona",
+    "jonas",
+    "jonathan",
+    "joppe",
+    "joppentasche",
+    "jordans",
+    "jos",
+    "josef",
+    "joseph",
+    "josephs",
+    "josias",
+    "josua",
+    "josuas",
+    "jota",
+    "journalisten",
+    "jreuliche",
+    "juan",
+    "jubal",
+    "jubel",
+    "jubelfeier",
+    "jubelfreude",
+    "jubelnd",
+    "jubelnder",
+    "jubelsang",
+    "jubelschrei",
+    "jubelt",
+    "jubelten",
+    "jubilieren",
+    "jubilierende",
+    "juble",
+    "juchezt",
+    "juchheen",
+    "juchhei",
+    "juchzend",
+    "judah",
+    "judas",
+    "jude",
+    "juden",
+    "judentum",
+    "judith",
+    "judithsie",
+    "jugend",
+    "jugendarbeit",
+    "jugendgarten",
+    "jugendgespielen",
+    "jugendheimat",
+    "jugendliche",
+    "jugendlichen",
+    "jugendlicher",
+    "jugendlichkeit",
+    "jugendschutzmassnahmen",
+    "jugendweihe",
+    "jugendzeit",
+    "juju",
+    "juli",
+    "julia",
+    "julie",
+    "julimonat",
+    "julisonne",
+    "julitag",
+    "julius",
+    "juliusstrasse",
+    "juliusstraße",
+    "jumoeidev",
+    "jung",
+    "junge",
+    "jungem",
+    "jungen",
+    "jungeninternat",
+    "junger",
+    "junges",
+    "jungfer",
+    "jungfern",
+    "jungfilmer",
+    "jungfrau",
+    "jungfrauen",
+    "jungfrauenalters",
+    "jungfrauschaft",
+    "jungfräulein",
+    "junggesellen",
+    "jungholz",
+    "jungunternehmer",
+    "jungweibe",
+    "juni",
+    "junker",
+    "juristen",
+    "juristische",
+    "juristischen",
+    "juvenes",
+    "jäger",
+    "jägerarbeit",
+    "jägerei",
+    "jägerheim",
+    "jägerlatein",
+    "jägerleben",
+    "jägermässigem",
+    "jägern",
+    "jägerohren",
+    "jägerruhe",
+    "jägers",
+    "jägersleute",
+    "jägersmann",
+    "jägersmanne",
+    "jägersprache",
+    "jägerssohn",
+    "jäh",
+    "jähen",
+    "jähes",
+    "jählings",
+    "jährchen",
+    "jährige",
+    "jährigen",
+    "jährlich",
+    "jährliche",
+    "jähsten",
+    "jähzorn",
+    "jähzornig",
+    "jähzornige",
+    "jähzornigen",
+    "jähzorniger",
+    "jämmerlich",
+    "jämmerlichem",
+    "jämmerlichen",
+    "jämmerlicher",
+    "jämmerliches",
+    "jämmerlichste",
+    "jätete",
+    "jöppchens",
+    "jörg",
+    "jückt",
+    "jüdisch",
+    "jüdische",
+    "jüdischen",
+    "jüdischer",
+    "jüdisches",
+    "jünger",
+    "jüngere",
+    "jüngern",
+    "jüngling",
+    "jünglinge",
+    "jünglings",
+    "jünglingsalter",
+    "jüngste",
+    "jüngsten",
+    "jüngster",
+    "jüppe",
+    "jürgen",
+    "k",
+    "kabine",
+    "kabylen",
+    "kachelofen",
+    "kaffee",
+    "kaffeegesellschaften",
+    "kaffeehaus",
+    "kaffeehauses",
+    "kaffeekanne",
+    "kaffeekannen",
+    "kaffeeklatsch",
+    "kaffeelöffelchen",
+    "kaffeetasse",
+    "kafka",
+    "kahl",
+    "kahle",
+    "kahlen",
+    "kahlköpfiger",
+    "kahn",
+    "kahne",
+    "kai",
+    "kain",
+    "kainiten",
+    "kains",
+    "kaiser",
+    "kaiserlichen",
+    "kaiserreich",
+    "kaiserstadt",
+    "kakan",
+    "kakaobüchse",
+    "kalaschnikows",
+    "kalb",
+    "kalben",
+    "kalbes",
+    "kalender",
+    "kalifornien",
+    "kalk",
+    "kalkerde",
+    "kalmus",
+    "kalt",
+    "kaltblütig",
+    "kalte",
+    "kalten",
+    "kaltenkellerschlag",
+    "kalter",
+    "kaltes",
+    "kam",
+    "kamel",
+    "kamele",
+    "kamelen",
+    "kamelhaaren",
+    "kamen",
+    "kameraden",
+    "kameraderie",
+    "kameradinnen",
+    "kamet",
+    "kaminer",
+    "kaminfeger",
+    "kamins",
+    "kamm",
+    "kammacher",
+    "kammachergeschäft",
+    "kammachergeschäfts",
+    "kammachermeister",
+    "kammer",
+    "kammerbodens",
+    "kammerdiener",
+    "kammerfrau",
+    "kammerjungfer",
+    "kammern",
+    "kammfabrikchen",
+    "kammgräsern",
+    "kammhöhe",
+    "kammmacher",
+    "kammwaren",
+    "kammwiesen",
+    "kampagne",
+    "kampf",
+    "kampfe",
+    "kampfes",
+    "kampflust",
+    "kampfplatz",
+    "kampfplatze",
+    "kampftages",
+    "kampfwahlen",
+    "kampierten",
+    "kanaan",
+    "kanaaniter",
+    "kanaanitin",
+    "kanaans",
+    "kanal",
+    "kanapee",
+    "kanarienkäficht",
+    "kandidat",
+    "kaninchen",
+    "kann",
+    "kanne",
+    "kannegiesser",
+    "kannegiesserin",
+    "kannegiessern",
+    "kannen",
+    "kannst",
+    "kannt",
+    "kannte",
+    "kannten",
+    "kanone",
+    "kanonenkugeln",
+    "kanonenkügelchen",
+    "kanonenlauf",
+    "kanonenmündung",
+    "kanonenrohres",
+    "kanonenwischer",
+    "kanonik",
+    "kant",
+    "kante",
+    "kanten",
+    "kantine",
+    "kantinenraumes",
+    "kanton",
+    "kantonalkirche",
+    "kantonalkirchen",
+    "kantonalsouveränetät",
+    "kantone",
+    "kantons",
+    "kantonsrats",
+    "kanzel",
+    "kanzlei",
+    "kanzleidirektor",
+    "kanzleien",
+    "kanäle",
+    "kap",
+    "kapital",
+    "kapitale",
+    "kapitalist",
+    "kapitaltier",
+    "kapitel",
+    "kapiteln",
+    "kapitels",
+    "kapitelsherren",
+    "kapitän",
+    "kappe",
+    "kappenzipfel",
+    "kapriolen",
+    "kapsel",
+    "kaputt",
+    "kaputtgehen",
+    "karaffe",
+    "karaffen",
+    "karbonarimantel",
+    "kardinäle",
+    "karfunkelsteinen",
+    "karikiere",
+    "karl",
+    "karmi",
+    "karneolfarbigen",
+    "karneval",
+    "karpfen",
+    "karpfenururgrosspapa",
+    "karren",
+    "karriere",
+    "karten",
+    "kartenschlagen",
+    "kartoffel",
+    "kartoffelacker",
+    "kartoffelbrei",
+    "kartoffelfeld",
+    "kartoffelgebirge",
+    "kartoffeln",
+    "kartoffelsuppe",
+    "karton",
+    "kartons",
+    "karussell",
+    "kasia",
+    "kaspar",
+    "kasparn",
+    "kassberger",
+    "kasse",
+    "kassieren",
+    "kassierer",
+    "kastanie",
+    "kastanien",
+    "kastanienblättern",
+    "kastanienbäume",
+    "kastanienritter",
+    "kasten",
+    "kastens",
+    "kasuale",
+    "kasualverschiebung",
+    "kasus",
+    "kasweiss",
+    "katalog",
+    "kataskeuazein",
+    "kataskeuazomen",
+    "katastrophal",
+    "katastrophe",
+    "katastrophen",
+    "katastropheneinsatz",
+    "katechismus",
+    "kategorie",
+    "kategorien",
+    "kategorisch",
+    "kategorische",
+    "kategorischen",
+    "kategorischer",
+    "kater",
+    "katerliede",
+    "katern",
+    "katers",
+    "kathedrale",
+    "kathinka",
+    "katholik",
+    "katholische",
+    "katholischen",
+    "katholischer",
+    "kattun",
+    "kattunhalstücher",
+    "katz",
+    "katze",
+    "katzen",
+    "katzenschmer",
+    "katzensprung",
+    "katzenwürde",
+    "katzmann",
+    "kauen",
+    "kauerte",
+    "kauf",
+    "kaufe",
+    "kaufen",
+    "kauffahrer",
+    "kaufherr",
+    "kaufleute",
+    "kaufmann",
+    "kaufmanns",
+    "kaufmannschaft",
+    "kaufmannsnamen",
+    "kaufpreis",
+    "kaufpreises",
+    "kaufst",
+    "kauft",
+    "kaufte",
+    "kaum",
+    "kausalbegriffe",
+    "kausalität",
+    "kausalverbindung",
+    "kausalverhältnisse",
+    "kaute",
+    "kaution",
+    "kavalier",
+    "kavaliere",
+    "kaweida",
+    "kdv",
+    "keck",
+    "kecken",
+    "keckes",
+    "kedars",
+    "kegel",
+    "kegelbahn",
+    "kegelbahnen",
+    "kegelpartie",
+    "kegelspiel",
+    "kehath",
+    "kehaths",
+    "kehats",
+    "kehle",
+    "kehlen",
+    "kehr",
+    "kehre",
+    "kehren",
+    "kehret",
+    "kehricht",
+    "kehrseite",
+    "kehrt",
+    "kehrte",
+    "kehrten",
+    "kei",
+    "keim",
+    "kein",
+    "keine",
+    "keinem",
+    "keinen",
+    "keiner",
+    "keinerlei",
+    "keines",
+    "keinesfalls",
+    "keineswegs",
+    "keins",
+    "keksdose",
+    "kelch",
+    "keller",
+    "kellereien",
+    "kellerhals",
+    "kellerpflanzen",
+    "kellner",
+    "kellnerin",
+    "kellnerinnen",
+    "kelter",
+    "keltern",
+    "kenan",
+    "kenans",
+    "kenn",
+    "kennbar",
+    "kenne",
+    "kennen",
+    "kennenlernen",
+    "kennens",
+    "kennenzulernen",
+    "kenner",
+    "kennerblicken",
+    "kennest",
+    "kennete",
+    "kennst",
+    "kennt",
+    "kenntlich",
+    "kenntnis",
+    "kenntnisse",
+    "kenntnissen",
+    "kennzeichen",
+    "kerbholz",
+    "kerker",
+    "kerkern",
+    "kerl",
+    "kerlchen",
+    "kerls",
+    "kern",
+    "kernpunkt",
+    "kerstin",
+    "kerze",
+    "kerzen",
+    "kesselflicken",
+    "kesselförmiges",
+    "kesseltal",
+    "kesselvolk",
+    "kette",
+    "ketten",
+    "kettlein",
+    "ketzer",
+    "ketzerei",
+    "ketzereien",
+    "ketzerische",
+    "ketzerischen",
+    "keuchend",
+    "keuchenden",
+    "keuchte",
+    "keulenkörner",
+    "keuschheit",
+    "kg",
+    "kichern",
+    "kids",
+    "kiel",
+    "kielwasser",
+    "kienholzgefüllten",
+    "kiepenheuer",
+    "kiesel",
+    "kieseln",
+    "kieselstein",
+    "kiesige",
+    "kieswege",
+    "kikeriki",
+    "kilo",
+    "kilometer",
+    "kindchen",
+    "kinde",
+    "kinder",
+    "kinderaugen",
+    "kinderchen",
+    "kinderfest",
+    "kindergärten",
+    "kinderjahren",
+    "kinderkehlen",
+    "kinderlein",
+    "kinderlos",
+    "kinderlosigkeit",
+    "kindern",
+    "kinderschar",
+    "kinderspiel",
+    "kinderstimmengelächter",
+    "kinderstube",
+    "kinderstühlchen",
+    "kindertaufen",
+    "kinderwägelchen",
+    "kinderwäsche",
+    "kinderzeit",
+    "kinderzimmerchen",
+    "kindes",
+    "kindesbeinen",
+    "kindesgedanken",
+    "kindesstatt",
+    "kindestreue",
+    "kindheit",
+    "kindisch",
+    "kindische",
+    "kindischer",
+    "kindlein",
+    "kindlich",
+    "kindliche",
+    "kindlichen",
+    "kindliches",
+    "kindlichkeit",
+    "kindskopf",
+    "kinn",
+    "kinnbacken",
+    "kinnbart",
+    "kino",
+    "kippe",
+    "kippen",
+    "kippod",
+    "kirch",
+    "kirchbankeck",
+    "kirchdorf",
+    "kirche",
+    "kirchedie",
+    "kirchemit",
+    "kirchen",
+    "kirchenauftritt",
+    "kirchenbesuch",
+    "kirchenbund",
+    "kirchenbundes",
+    "kirchenbundesdie",
+    "kirchenerhaltdie",
+    "kirchenführer",
+    "kirchenglocken",
+    "kirchenkritiker",
+    "kirchenlehre",
+    "kirchenlied",
+    "kirchenmaus",
+    "kirchenmitglieder",
+    "kirchenmitgliedschaft",
+    "kirchennahen",
+    "kirchenpatronin",
+    "kirchenpflegemitglieder",
+    "kirchenpflegen",
+    "kirchenpresse",
+    "kirchenrates",
+    "kirchenrechtlich",
+    "kirchenrechtlichen",
+    "kirchensteuer",
+    "kirchensteuerpflicht",
+    "kirchenstreit",
+    "kirchensynoden",
+    "kirchentag",
+    "kirchentage",
+    "kirchentagen",
+    "kirchentages",
+    "kirchenverantwortlichen",
+    "kirchenwesen",
+    "kirchenzeit",
+    "kirchenzeitung",
+    "kirchgang",
+    "kirchgemeinde",
+    "kirchgemeinden",
+    "kirchgemeinderäte",
+    "kirchgemeinderäten",
+    "kirchhof",
+    "kirchlich",
+    "kirchliche",
+    "kirchlichen",
+    "kirchliches",
+    "kirchstühl",
+    "kirchturmspitz",
+    "kirchtürme",
+    "kirchweih",
+    "kirkgesit",
+    "kirschen",
+    "kirschenlippen",
+    "kirschgeist",
+    "kirschkern",
+    "kirschlorbeerwasser",
+    "kirschrot",
+    "kirschrote",
+    "kirschrotem",
+    "kirschroten",
+    "kissen",
+    "kiste",
+    "kisten",
+    "kitschige",
+    "kitschiges",
+    "kittel",
+    "kitz",
+    "kitzelt",
+    "kitzlein",
+    "klack",
+    "klage",
+    "klagel",
+    "klagen",
+    "klagend",
+    "klagenden",
+    "klagetönen",
+    "klaglosen",
+    "klagt",
+    "klagte",
+    "klagten",
+    "klammerarmen",
+    "klammernd",
+    "klammerte",
+    "klang",
+    "klange",
+    "klangen",
+    "klanglos",
+    "klangvolle",
+    "klangvoller",
+    "klapperig",
+    "klappern",
+    "klappernden",
+    "klapperte",
+    "klappte",
+    "klaps",
+    "klar",
+    "klare",
+    "klaren",
+    "klarer",
+    "klareren",
+    "klares",
+    "klarheit",
+    "klarstellung",
+    "klarsten",
+    "klass",
+    "klasse",
+    "klassen",
+    "klassikers",
+    "klassische",
+    "klatschen",
+    "klatschend",
+    "klatschereien",
+    "klatschrose",
+    "klatschrosen",
+    "klatschte",
+    "klatschten",
+    "klaubte",
+    "klaue",
+    "klauen",
+    "klavierstunde",
+    "klebe",
+    "kleben",
+    "klebet",
+    "klebt",
+    "klebte",
+    "klebten",
+    "klecks",
+    "kleckschen",
+    "kleckse",
+    "klecksigen",
+    "klee",
+    "kleeblatt",
+    "kleesamen",
+    "kleid",
+    "kleide",
+    "kleiden",
+    "kleider",
+    "kleiderbedarf",
+    "kleiderkasten",
+    "kleidern",
+    "kleiderschrank",
+    "kleides",
+    "kleidete",
+    "kleidung",
+    "kleidungsstück",
+    "kleie",
+    "kleiekörner",
+    "klein",
+    "kleine",
+    "kleinedelmann",
+    "kleinedelmanne",
+    "kleinem",
+    "kleinen",
+    "kleiner",
+    "kleinere",
+    "kleineren",
+    "kleinern",
+    "kleines",
+    "kleinheit",
+    "kleinigkeit",
+    "kleinigkeiten",
+    "kleinlaut",
+    "kleinlich",
+    "kleinliche",
+    "kleinlichen",
+    "kleinlicher",
+    "kleinlichsten",
+    "kleinmachnow",
+    "kleinmütig",
+    "kleinod",
+    "kleinste",
+    "kleinsten",
+    "kleinster",
+    "kleinstes",
+    "kleinstädterinnen",
+    "kleinviehs",
+    "klemme",
+    "klemmte",
+    "kletterns",
+    "kletterte",
+    "kletterten",
+    "klickklackklecks",
+    "klicks",
+    "klient",
+    "klingel",
+    "klingelte",
+    "klingen",
+    "klingende",
+    "klingenden",
+    "klingender",
+    "klingt",
+    "klinke",
+    "klippe",
+    "klippen",
+    "klirren",
+    "klirrenden",
+    "klirrrr",
+    "klirrte",
+    "klirrten",
+    "kloan",
+    "klopfen",
+    "klopfendem",
+    "klopfenden",
+    "klopfte",
+    "kloster",
+    "klosters",
+    "klotziger",
+    "kluft",
+    "klug",
+    "kluge",
+    "klugen",
+    "kluger",
+    "kluges",
+    "klugheit",
+    "klugheitsregel",
+    "klumpen",
+    "kläffer",
+    "kläger",
+    "kläglich",
+    "klägliche",
+    "kläglicher",
+    "klänge",
+    "klärt",
+    "klösschen",
+    "klöster",
+    "klüfteten",
+    "klüger",
+    "klüglich",
+    "klügste",
+    "klümpchen",
+    "knab",
+    "knabbern",
+    "knabe",
+    "knaben",
+    "knabenalter",
+    "knackende",
+    "knall",
+    "knallen",
+    "knallgelber",
+    "knallt",
+    "knapp",
+    "knarrte",
+    "knatter",
+    "knauserig",
+    "knebel",
+    "knecht",
+    "knechtchen",
+    "knechte",
+    "knechten",
+    "knechtes",
+    "knechtisch",
+    "knechts",
+    "knechtschaft",
+    "kneipchen",
+    "kneipe",
+    "kneipen",
+    "kneipentisch",
+    "kneten",
+    "knicks",
+    "knickse",
+    "knickste",
+    "knickte",
+    "knickten",
+    "knie",
+    "kniee",
+    "knieen",
+    "knieholzäste",
+    "kniehosen",
+    "knien",
+    "kniete",
+    "knieten",
+    "kniff",
+    "knirschen",
+    "knirschend",
+    "knisternd",
+    "knisternden",
+    "knisterte",
+    "knisterten",
+    "knitternden",
+    "knobeln",
+    "knobelten",
+    "knochen",
+    "knochengerippe",
+    "knochenjobs",
+    "knochige",
+    "knochiges",
+    "knopf",
+    "knopfmacher",
+    "knopfmacherei",
+    "knorpel",
+    "knorrige",
+    "knospen",
+    "knospenden",
+    "knoten",
+    "knotige",
+    "knusprig",
+    "knutenpeitsche",
+    "knäbchen",
+    "knäblein",
+    "knäbleins",
+    "knäuel",
+    "knäufen",
+    "knöchel",
+    "knöchern",
+    "knöchernes",
+    "knöpfe",
+    "knöpfen",
+    "knösperl",
+    "knüffe",
+    "knüffen",
+    "knüllte",
+    "knüpfen",
+    "knüpfend",
+    "knüpfte",
+    "knüpperte",
+    "koalitionskrise",
+    "koalitionssystem",
+    "koalitionsversuche",
+    "koan",
+    "kobold",
+    "koch",
+    "kochen",
+    "kochherd",
+    "kocht",
+    "kochte",
+    "kock",
+    "koffer",
+    "kognak",
+    "kohl",
+    "kohle",
+    "kohlen",
+    "kohlenbrennern",
+    "kohlkräuter",
+    "kokette",
+    "koketterie",
+    "kolben",
+    "kollaboration",
+    "kollaboriert",
+    "kollakowsky",
+    "kollakowskys",
+    "kollege",
+    "kollegen",
+    "kollegium",
+    "kollekteur",
+    "koller",
+    "kollerte",
+    "kolonne",
+    "kolorit",
+    "kolossal",
+    "kolossale",
+    "kolossales",
+    "kolumbus",
+    "kom",
+    "kombattant",
+    "kombinationen",
+    "kombinierten",
+    "komet",
+    "kometenkohl",
+    "komiker",
+    "komikergrinsen",
+    "komikers",
+    "komisch",
+    "komische",
+    "komischen",
+    "komitee",
+    "komm",
+    "kommandeur",
+    "kommandieren",
+    "kommando",
+    "kommandowort",
+    "komme",
+    "kommen",
+    "kommend",
+    "kommende",
+    "kommenden",
+    "kommendes",
+    "kommens",
+    "kommentar",
+    "kommet",
+    "kommission",
+    "kommissionen",
+    "kommode",
+    "kommr",
+    "kommst",
+    "kommt",
+    "kommunalverwaltung",
+    "kommunikationshindernis",
+    "kompagnie",
+    "kompagnien",
+    "kompagnon",
+    "kompaniechef",
+    "kompass",
+    "kompensation",
+    "komplikationen",
+    "kompliment",
+    "komplimenten",
+    "komplimentierten",
+    "komponiert",
+    "komptoirs",
+    "komödie",
+    "komödien",
+    "kon",
+    "konfekt",
+    "konfirmationsschein",
+    "konfirmationsunterrichte",
+    "konfirmiert",
+    "konfisziert",
+    "konflikt",
+    "konflikten",
+    "konfrontiert",
+    "konfundieren",
+    "konfus",
+    "konjunktiv",
+    "konkreten",
+    "konkurse",
+    "konnt",
+    "konnte",
+    "konnten",
+    "konsequent",
+    "konsequente",
+    "konservatismus",
+    "konservativ",
+    "konservativen",
+    "konservativer",
+    "konserviert",
+    "konstantinopel",
+    "konstellationen",
+    "konstitutiv",
+    "konstruiert",
+    "konstruktion",
+    "konsum",
+    "konsumenten",
+    "konsumieren",
+    "konsumiert",
+    "konsumkredit",
+    "konsumwelle",
+    "kontakt",
+    "kontakte",
+    "kontemplative",
+    "kontinent",
+    "kontinental",
+    "kontinuierlichen",
+    "konto",
+    "kontorstuhl",
+    "kontradiktorisch",
+    "kontrakt",
+    "kontraktwidrigen",
+    "kontrast",
+    "kontrastierte",
+    "kontraventionsbusse",
+    "kontrolliert",
+    "kontroverse",
+    "konventikel",
+    "konvertiert",
+    "konzentriert",
+    "konzert",
+    "kooperation",
+    "koordiniert",
+    "kopf",
+    "kopfe",
+    "kopfes",
+    "kopfhängen",
+    "kopfkissen",
+    "kopfnicken",
+    "kopfputz",
+    "kopfschmerzen",
+    "kopfschüttelnd",
+    "kopftuches",
+    "kopfweh",
+    "kopfwendung",
+    "kopfzerbrechen",
+    "kopfüber",
+    "kopien",
+    "kopiermaschinen",
+    "koppe",
+    "koppelpferde",
+    "koppenhöhe",
+    "koppenluft",
+    "koppenplan",
+    "koppenplanes",
+    "kopulieren",
+    "kor",
+    "korah",
+    "korahiten",
+    "korahs",
+    "korallenschnur",
+    "korb",
+    "korbe",
+    "korbträgern",
+    "korn",
+    "kornblumen",
+    "korne",
+    "kornes",
+    "kornfeld",
+    "kornsäcken",
+    "kornähre",
+    "korrekt",
+    "korrekturen",
+    "korrespondenz",
+    "korrespondiere",
+    "korrespondieren",
+    "korrespondierend",
+    "korrespondierende",
+    "korrespondierendes",
+    "korrespondiert",
+    "korridor",
+    "korrigieren",
+    "korrigiert",
+    "kosenamen",
+    "kosmiseher",
+    "kosmologische",
+    "kost",
+    "kostbar",
+    "kostbare",
+    "kostbaren",
+    "kostbarer",
+    "kostbares",
+    "kostbarsten",
+    "kosten",
+    "kostenfreien",
+    "kostenpunkt",
+    "kostet",
+    "kostete",
+    "kosteten",
+    "kostspieliges",
+    "kostümierte",
+    "kot",
+    "kotelettes",
+    "kothöllen",
+    "krabbelige",
+    "krabbelte",
+    "krach",
+    "krachen",
+    "krachend",
+    "krachmandel",
+    "krachmandelkies",
+    "kracht",
+    "krachte",
+    "krack",
+    "kraft",
+    "kraftanwendung",
+    "kraftmänner",
+    "kragen",
+    "krakacks",
+    "kram",
+    "kramladen",
+    "krammetsvogel",
+    "krammetsvogels",
+    "krammetsvögel",
+    "krampf",
+    "krampfhaft",
+    "krampfhaften",
+    "krampfhafter",
+    "krank",
+    "kranke",
+    "kranken",
+    "krankenbett",
+    "krankenbette",
+    "krankenbettes",
+    "krankenstand",
+    "krankenversicherung",
+    "krankenwärter",
+    "krankhafter",
+    "krankheit",
+    "krankheiten",
+    "krankheitsbildern",
+    "kranz",
+    "kranze",
+    "kratz",
+    "kratzen",
+    "kratzfuss",
+    "kratzfüsschen",
+    "kratzte",
+    "kratzten",
+    "krault",
+    "kraus",
+    "krause",
+    "krausen",
+    "kraut",
+    "krautes",
+    "krauts",
+    "kravatte",
+    "kreatur",
+    "kreaturen",
+    "krebs",
+    "krebspatienten",
+    "krebsscheren",
+    "kredit",
+    "kredite",
+    "kredites",
+    "kreditgetzes",
+    "kreideblass",
+    "kreis",
+    "kreischen",
+    "kreischenden",
+    "kreischte",
+    "kreise",
+    "kreiselndes",
+    "kreiselte",
+    "kreisen",
+    "kreises",
+    "kreislauf",
+    "kreisläufe",
+    "kreisrunde",
+    "kreissen",
+    "kreissenden",
+    "kreissest",
+    "kreisst",
+    "kreiswehrersatzamt",
+    "kreizer",
+    "kreizl",
+    "kreißen",
+    "kreißenden",
+    "kreißest",
+    "kreißt",
+    "kresse",
+    "kretinismus",
+    "kreucht",
+    "kreuz",
+    "kreuzberger",
+    "kreuze",
+    "kreuzen",
+    "kreuzenden",
+    "kreuzer",
+    "kreuzes",
+    "kreuzfahrers",
+    "kreuzl",
+    "kreuzritterzuge",
+    "kreuzspinnen",
+    "kreuzstrasse",
+    "kreuzte",
+    "kreuzten",
+    "kreuzweg",
+    "kreuzwegbauer",
+    "kreuzwegbäu",
+    "kreuzweghof",
+    "kreuzweghofbauer",
+    "kreuzweis",
+    "kribbeln",
+    "krick",
+    "kriechen",
+    "kriechend",
+    "kriechende",
+    "kriechenden",
+    "kriechendes",
+    "kriecht",
+    "kriechtier",
+    "kriechtiere",
+    "kriechtieren",
+    "krieg",
+    "kriege",
+    "kriegen",
+    "krieger",
+    "kriegerisch",
+    "kriegerischen",
+    "krieges",
+    "krieget",
+    "kriegsdienst",
+    "kriegsdienste",
+    "kriegsdienstes",
+    "kriegsdienstverweigerer",
+    "kriegsdienstverweigerung",
+    "kriegsführung",
+    "kriegsgetümmel",
+    "kriegsheeren",
+    "kriegskameraden",
+    "kriegskasse",
+    "kriegskleid",
+    "kriegsleute",
+    "kriegslied",
+    "kriegslist",
+    "kriegsmann",
+    "kriegsmannes",
+    "kriegsmänner",
+    "kriegsmöglichkeiten",
+    "kriegsoberst",
+    "kriegsstrapazen",
+    "kriegsvölker",
+    "kriegswaffen",
+    "kriegswesen",
+    "kriegswichtigen",
+    "kriegt",
+    "kriegte",
+    "krieje",
+    "kriminalverbrechen",
+    "kriminell",
+    "kriminelle",
+    "kriminelles",
+    "krise",
+    "krisen",
+    "kristall",
+    "kristalle",
+    "kritik",
+    "kritikpunkt",
+    "kritisch",
+    "kritische",
+    "kritischen",
+    "kritischer",
+    "kritisieren",
+    "kritisiert",
+    "kroch",
+    "krochen",
+    "krochy",
+    "krokodil",
+    "krone",
+    "kronen",
+    "kronleuchtern",
+    "kropf",
+    "kropfige",
+    "kropfigen",
+    "kropfiger",
+    "kropfschlinge",
+    "kruge",
+    "krumm",
+    "krummbeinige",
+    "krumme",
+    "krummen",
+    "krummholz",
+    "krummholzbusche",
+    "krummholzbuschwerk",
+    "krummholzzweigen",
+    "krummrückiger",
+    "krustengebirge",
+    "kruzifix",
+    "krächzen",
+    "krächzende",
+    "kräfte",
+    "kräften",
+    "kräftig",
+    "kräftige",
+    "kräftigem",
+    "kräftigen",
+    "kräftiger",
+    "kräftigere",
+    "kräftigst",
+    "krähe",
+    "krähen",
+    "kräht",
+    "krähte",
+    "krämer",
+    "kränkelnder",
+    "kränkelt",
+    "kränken",
+    "kränkend",
+    "kränkenden",
+    "kränkt",
+    "kränkte",
+    "kränkung",
+    "kränzen",
+    "krätze",
+    "kräuselhaare",
+    "kräuselten",
+    "kräutchen",
+    "kräuter",
+    "kräutermann",
+    "kräutern",
+    "kräutersäcke",
+    "kräuterwuchs",
+    "kräuterwust",
+    "krönchen",
+    "krönen",
+    "krönte",
+    "kröten",
+    "krügelchen",
+    "krümel",
+    "krümmen",
+    "krümmte",
+    "krümmten",
+    "krümmung",
+    "krüppel",
+    "kts",
+    "kuba",
+    "kuchen",
+    "kuchenappetit",
+    "kuchenbacken",
+    "kuchenduft",
+    "kuchenpäckchen",
+    "kuchenteller",
+    "kuckuck",
+    "kugel",
+    "kugeln",
+    "kugelrund",
+    "kugelrunde",
+    "kugelrunden",
+    "kugelrundes",
+    "kugelschreiber",
+    "kugelstift",
+    "kuh",
+    "kuhgespann",
+    "kuhknecht",
+    "kuhknechte",
+    "kuhmagd",
+    "kuhpfluge",
+    "kulisse",
+    "kullerbällchen",
+    "kullerten",
+    "kullich",
+    "kultivieren",
+    "kultivierte",
+    "kultur",
+    "kulturell",
+    "kulturelle",
+    "kulturellen",
+    "kulturen",
+    "kulturvereine",
+    "kulturwort",
+    "kummen",
+    "kummer",
+    "kummervoll",
+    "kumpan",
+    "kumpane",
+    "kund",
+    "kunde",
+    "kunden",
+    "kundgab",
+    "kundgetan",
+    "kundig",
+    "kundigen",
+    "kundin",
+    "kundschaft",
+    "kundschafter",
+    "kunnt",
+    "kunnten",
+    "kunst",
+    "kunstdenkmäler",
+    "kunstfertigkeit",
+    "kunstgriff",
+    "kunstgriffe",
+    "kunsthistorische",
+    "kunstliebhaber",
+    "kunstlos",
+    "kunstpfeifer",
+    "kunstreichen",
+    "kunstreicher",
+    "kunstreiches",
+    "kunststück",
+    "kunststückchen",
+    "kunststücke",
+    "kunstverständigen",
+    "kunstvoll",
+    "kunstvoller",
+    "kunstwerke",
+    "kunstwerken",
+    "kunterbunt",
+    "kupfer",
+    "kupfern",
+    "kupferstiche",
+    "kuppel",
+    "kuppeln",
+    "kuppen",
+    "kuppler",
+    "kur",
+    "kurasch",
+    "kurden",
+    "kurdisch",
+    "kurdische",
+    "kurdischen",
+    "kurdistan",
+    "kurfürsten",
+    "kurieren",
+    "kurierte",
+    "kurrenten",
+    "kurs",
+    "kursus",
+    "kurt",
+    "kurubas",
+    "kurwochen",
+    "kurz",
+    "kurzatmiger",
+    "kurzbesuch",
+    "kurze",
+    "kurzem",
+    "kurzen",
+    "kurzer",
+    "kurzerhand",
+    "kurzes",
+    "kurzfristig",
+    "kurzgeschoren",
+    "kurzhörspiel",
+    "kurzsichtig",
+    "kurzum",
+    "kurzweg",
+    "kurzweil",
+    "kurzweilig",
+    "kurzweiligen",
+    "kurzweiliges",
+    "kurzweiligste",
+    "kusch",
+    "kuschs",
+    "kuss",
+    "kusshändchen",
+    "kutsche",
+    "kutscher",
+    "kutschers",
+    "kutschieren",
+    "kutschierte",
+    "kutte",
+    "kuv",
+    "kuß",
+    "käfer",
+    "käferchen",
+    "käferherzens",
+    "käferlein",
+    "käfermann",
+    "käfermatz",
+    "käfern",
+    "käfertierchen",
+    "käfig",
+    "kähnchens",
+    "kälbern",
+    "kälberstücke",
+    "kälte",
+    "kälter",
+    "käm",
+    "kämbel",
+    "käme",
+    "kämen",
+    "kämma",
+    "kämme",
+    "kämmen",
+    "kämmerchen",
+    "kämmet",
+    "kämmte",
+    "kämpfe",
+    "kämpfen",
+    "kämpfende",
+    "kämpfer",
+    "kämpft",
+    "kämpfte",
+    "kännchen",
+    "käpplein",
+    "käse",
+    "käsen",
+    "käsrinde",
+    "kästchen",
+    "kästchens",
+    "käthchen",
+    "kätzchen",
+    "kätzchens",
+    "kätzin",
+    "kätzlein",
+    "käufer",
+    "käuflich",
+    "käuze",
+    "käuzen",
+    "käuzlein",
+    "köchin",
+    "köllen",
+    "kölner",
+    "kölnischem",
+    "kön",
+    "könig",
+    "könige",
+    "königen",
+    "königin",
+    "königinnen",
+    "königl",
+    "königlich",
+    "königliche",
+    "königlichen",
+    "königlichsten",
+    "königreich",
+    "königreichen",
+    "königreiches",
+    "königs",
+    "königsmantel",
+    "königspaar",
+    "königsschiessen",
+    "königssee",
+    "königsseer",
+    "königssohn",
+    "könn",
+    "könne",
+    "können",
+    "könnet",
+    "könnt",
+    "könnte",
+    "könnten",
+    "könntest",
+    "köpfchen",
+    "köpfe",
+    "köpfen",
+    "köpflings",
+    "körbchen",
+    "körbe",
+    "körben",
+    "körper",
+    "körperbau",
+    "körperlich",
+    "körperliche",
+    "körperlichen",
+    "körperlicher",
+    "körperliches",
+    "körpern",
+    "körpers",
+    "körpersinne",
+    "köstlich",
+    "köstliche",
+    "köstlichen",
+    "köstliches",
+    "köstlichkeiten",
+    "köstlichste",
+    "köter",
+    "kübel",
+    "kübler",
+    "küche",
+    "küchenchef",
+    "küchenjungen",
+    "küchenlampe",
+    "küchentüre",
+    "küh",
+    "kühe",
+    "kühl",
+    "kühle",
+    "kühlen",
+    "kühler",
+    "kühlten",
+    "kühn",
+    "kühne",
+    "kühnen",
+    "kühnere",
+    "kühneren",
+    "kühnheit",
+    "kühnlich",
+    "kühnrich",
+    "kühnsten",
+    "kümmerlich",
+    "kümmerliche",
+    "kümmerlichen",
+    "kümmerlicher",
+    "kümmern",
+    "kümmernis",
+    "kümmernisse",
+    "kümmerst",
+    "kümmert",
+    "kümmerte",
+    "kümmerten",
+    "kündigen",
+    "kündigte",
+    "kündigten",
+    "kündigung",
+    "künftig",
+    "künftige",
+    "künftigen",
+    "küng",
+    "künste",
+    "künsten",
+    "künstler",
+    "künstlerische",
+    "künstlerischen",
+    "künstlers",
+    "künstlich",
+    "künstliche",
+    "künstlichem",
+    "künstlichen",
+    "künstlicher",
+    "küpe",
+    "kürassiere",
+    "kürassiers",
+    "kürbisse",
+    "kürz",
+    "kürze",
+    "kürzer",
+    "kürzere",
+    "kürzlich",
+    "küss",
+    "küsschen",
+    "küsse",
+    "küssen",
+    "küssend",
+    "küsserei",
+    "küsslein",
+    "küsst",
+    "küsste",
+    "küssten",
+    "küsten",
+    "küstern",
+    "küßt",
+    "küßte",
+    "l",
+    "la",
+    "laban",
+    "labe",
+    "laboratorium",
+    "labyrinth",
+    "labyrinthe",
+    "lachchors",
+    "lachen",
+    "lachend",
+    "lachende",
+    "lachendem",
+    "lachenden",
+    "lachendes",
+    "lachkrampf",
+    "lachs",
+    "lacht",
+    "lachte",
+    "lachten",
+    "lachtest",
+    "lachtöne",
+    "lackieren",
+    "lackiert",
+    "lackierten",
+    "lackl",
+    "lade",
+    "laden",
+    "ladend",
+    "ladendiebin",
+    "ladenkasse",
+    "ladentür",
+    "ladies",
+    "ladung",
+    "laert",
+    "laertius",
+    "lag",
+    "lage",
+    "lagen",
+    "lager",
+    "lagern",
+    "lagernd",
+    "lagers",
+    "lagerstätte",
+    "lagert",
+    "lagerte",
+    "lahme",
+    "lahmen",
+    "lahmgewordenen",
+    "lahnstreif",
+    "laie",
+    "laien",
+    "lallt",
+    "lamech",
+    "lamechs",
+    "lamentieren",
+    "lamentierte",
+    "lamm",
+    "lamme",
+    "lammes",
+    "lammgeduld",
+    "lampe",
+    "lamperl",
+    "lanciert",
+    "landarmee",
+    "landauer",
+    "landbursche",
+    "lande",
+    "landen",
+    "landes",
+    "landesautoritäten",
+    "landesfürst",
+    "landeskirche",
+    "landeskirchen",
+    "landeskirchliche",
+    "landesmehrheit",
+    "landesverteidigung",
+    "landeswohl",
+    "landesüblichen",
+    "landete",
+    "landeten",
+    "landflüchtige",
+    "landfrau",
+    "landfriedenbrecher",
+    "landgericht",
+    "landhaus",
+    "landleben",
+    "landleute",
+    "landleuten",
+    "landläufige",
+    "landmann",
+    "landmädchen",
+    "landmänner",
+    "landpartie",
+    "landpartien",
+    "landrock",
+    "landschaft",
+    "landschaften",
+    "landsitz",
+    "landsleute",
+    "landsmann",
+    "landsmannes",
+    "landstrasse",
+    "landstrassen",
+    "landstraßen",
+    "landstreicher",
+    "landstrich",
+    "landstriches",
+    "landsturm",
+    "landungsstelle",
+    "landvolk",
+    "landvolkes",
+    "landwirtschaft",
+    "langbeinen",
+    "lange",
+    "langem",
+    "langen",
+    "langer",
+    "langes",
+    "langeweile",
+    "langgezogenen",
+    "langhingestreckte",
+    "langjährige",
+    "langjährigen",
+    "langmut",
+    "langmütig",
+    "langriemige",
+    "langrollende",
+    "langsam",
+    "langsame",
+    "langsamen",
+    "langsamer",
+    "langsamkeit",
+    "langstündige",
+    "langte",
+    "langweilen",
+    "langweilig",
+    "langweilige",
+    "langweiligen",
+    "lanz",
+    "lanze",
+    "largo",
+    "larifari",
+    "las",
+    "lasen",
+    "lass",
+    "lasse",
+    "lassen",
+    "lasset",
+    "lasst",
+    "lasten",
+    "laster",
+    "lasterhaften",
+    "lastern",
+    "lastete",
+    "lastfuhren",
+    "lastschlitten",
+    "lat",
+    "lateiner",
+    "lateinerte",
+    "lateinisch",
+    "lateinische",
+    "lateinischen",
+    "lateinschuljahr",
+    "laternchen",
+    "laterne",
+    "laternen",
+    "lattenzaunes",
+    "lattichblatt",
+    "laub",
+    "laube",
+    "lauben",
+    "laubhütten",
+    "laubmassen",
+    "lauen",
+    "lauer",
+    "lauerten",
+    "lauf",
+    "laufbahn",
+    "laufe",
+    "laufen",
+    "laufend",
+    "laufendem",
+    "laufenden",
+    "laufes",
+    "laufet",
+    "laufs",
+    "lauft",
+    "laune",
+    "launen",
+    "launigen",
+    "laurens",
+    "lausanne",
+    "lauschend",
+    "lauschens",
+    "lauscher",
+    "lauschte",
+    "lauschten",
+    "lausekerl",
+    "laut",
+    "laute",
+    "lautem",
+    "lauten",
+    "lauter",
+    "lautere",
+    "lauterem",
+    "lauteren",
+    "lauterkeit",
+    "lautes",
+    "lautet",
+    "lautete",
+    "lautlos",
+    "lautlose",
+    "lautloser",
+    "lautsprecher",
+    "lautsprechern",
+    "lautstarke",
+    "lazare",
+    "lazarus",
+    "laß",
+    "laßt",
+    "le",
+    "leah",
+    "leb",
+    "lebe",
+    "leben",
+    "lebend",
+    "lebende",
+    "lebenden",
+    "lebendes",
+    "lebendig",
+    "lebendige",
+    "lebendigem",
+    "lebendigen",
+    "lebendiger",
+    "lebendiges",
+    "lebendigsten",
+    "lebens",
+    "lebensabschnitt",
+    "lebensalter",
+    "lebensart",
+    "lebensbedingungen",
+    "lebensbedürfnis",
+    "lebensbeschreibung",
+    "lebensbuche",
+    "lebenselixier",
+    "lebensfahrt",
+    "lebensfeuer",
+    "lebensformen",
+    "lebensfreude",
+    "lebensfroh",
+    "lebensgefühl",
+    "lebensgeister",
+    "lebensgeschichte",
+    "lebensglück",
+    "lebensglücke",
+    "lebensguten",
+    "lebenshelfer",
+    "lebensjahr",
+    "lebenskatastrophe",
+    "lebenskraft",
+    "lebenskräftig",
+    "lebenslanges",
+    "lebenslauf",
+    "lebenslicht",
+    "lebenslust",
+    "lebenslustigen",
+    "lebensläufen",
+    "lebensmittel",
+    "lebensmittelembargos",
+    "lebensmitteln",
+    "lebensmut",
+    "lebensordnung",
+    "lebensordnungen",
+    "lebensregeln",
+    "lebensretter",
+    "lebenssache",
+    "lebenssachverhalten",
+    "lebensseligkeit",
+    "lebenssituation",
+    "lebensstunde",
+    "lebenstagen",
+    "lebenstrieb",
+    "lebenswandel",
+    "lebenswandels",
+    "lebenswasser",
+    "lebensweg",
+    "lebensweise",
+    "lebensweisen",
+    "lebensweisheit",
+    "lebenszustand",
+    "lebenszustände",
+    "lebensähnliches",
+    "leber",
+    "leberwurst",
+    "lebest",
+    "lebewohl",
+    "lebhaft",
+    "lebhafte",
+    "lebhaftem",
+    "lebhaften",
+    "lebhafter",
+    "lebhaftesten",
+    "lebhaftigkeit",
+    "lebkuchen",
+    "leblos",
+    "leblose",
+    "leblosen",
+    "lebloses",
+    "lebst",
+    "lebt",
+    "lebtag",
+    "lebte",
+    "lebten",
+    "lebzeiten",
+    "lechleitner",
+    "lechneitner",
+    "lechzt",
+    "lechzte",
+    "lechzten",
+    "lecken",
+    "lecker",
+    "leckerbissen",
+    "leckere",
+    "leckeren",
+    "leckeres",
+    "leckermäuler",
+    "leckte",
+    "leder",
+    "lederkleidung",
+    "lederkäppchen",
+    "ledernen",
+    "lederpantoffeln",
+    "ledersofa",
+    "ledersäckchen",
+    "ledertapeten",
+    "lederzeug",
+    "ledig",
+    "ledige",
+    "lediglich",
+    "leer",
+    "leere",
+    "leeren",
+    "leerer",
+    "leeres",
+    "leerheit",
+    "leerraum",
+    "leerstehende",
+    "leerte",
+    "leg",
+    "legalität",
+    "lege",
+    "legen",
+    "legendenbildung",
+    "leget",
+    "legislative",
+    "legitimation",
+    "legitimationspapier",
+    "legitimationspapiere",
+    "legt",
+    "legte",
+    "legten",
+    "lehmann",
+    "lehmiger",
+    "lehne",
+    "lehnsessel",
+    "lehnt",
+    "lehnte",
+    "lehnten",
+    "lehr",
+    "lehramtes",
+    "lehrbegriff",
+    "lehrbegriffe",
+    "lehrbestimmungen",
+    "lehre",
+    "lehren",
+    "lehrende",
+    "lehrenden",
+    "lehrens",
+    "lehrer",
+    "lehrerin",
+    "lehrern",
+    "lehrerpult",
+    "lehret",
+    "lehrgegenstände",
+    "lehrjahren",
+    "lehrling",
+    "lehrlinge",
+    "lehrmeinungen",
+    "lehrpunkte",
+    "lehrreichen",
+    "lehrreicher",
+    "lehrreiches",
+    "lehrsatz",
+    "lehrstück",
+    "lehrstücke",
+    "lehrsätze",
+    "lehrsätzen",
+    "lehrt",
+    "lehrte",
+    "lehrten",
+    "lehrwahrheiten",
+    "leib",
+    "leibarzt",
+    "leibe",
+    "leiber",
+    "leibes",
+    "leibesgestalt",
+    "leibesleben",
+    "leibesnahrung",
+    "leibesorgane",
+    "leibesübungen",
+    "leibgericht",
+    "leibgerichte",
+    "leibhafte",
+    "leibhaftige",
+    "leibhornisten",
+    "leibjäger",
+    "leibjägern",
+    "leiblich",
+    "leibliche",
+    "leiblichen",
+    "leiblicher",
+    "leibliches",
+    "leibpferd",
+    "leibröcke",
+    "leibsessel",
+    "leibt",
+    "leibte",
+    "leibteller",
+    "leiche",
+    "leichen",
+    "leichenbegängnis",
+    "leichengestank",
+    "leichenschmaus",
+    "leichnam",
+    "leichname",
+    "leichnamen",
+    "leicht",
+    "leichtbeschwingte",
+    "leichte",
+    "leichten",
+    "leichter",
+    "leichtere",
+    "leichtes",
+    "leichtfertige",
+    "leichtfertigem",
+    "leichtfertiges",
+    "leichthin",
+    "leichtigkeit",
+    "leichtlich",
+    "leichtsinn",
+    "leichtsinnig",
+    "leichtsinnige",
+    "leichtsinnigen",
+    "leichtsinniger",
+    "leichtsinnigkeit",
+    "leichtsinns",
+    "leid",
+    "leide",
+    "leiden",
+    "leidend",
+    "leidende",
+    "leidenden",
+    "leidendes",
+    "leidens",
+    "leidenschaft",
+    "leidenschaften",
+    "leidenschaftlich",
+    "leidenschaftliche",
+    "leidenschaftlicher",
+    "leidenschaftlichkeit",
+    "leidensgenossen",
+    "leidentlich",
+    "leider",
+    "leides",
+    "leidet",
+    "leidige",
+    "leidlich",
+    "leids",
+    "leierkasten",
+    "leiermann",
+    "leiermänner",
+    "leihen",
+    "leim",
+    "leimrute",
+    "lein",
+    "leine",
+    "leinen",
+    "leinene",
+    "leinenen",
+    "leinentüchern",
+    "leins",
+    "leinwand",
+    "leinwandbinde",
+    "leinwandgewändern",
+    "leinwandne",
+    "leinwandstreifen",
+    "leipzig",
+    "leis",
+    "leisbewegte",
+    "leise",
+    "leisen",
+    "leiser",
+    "leises",
+    "leiseste",
+    "leisten",
+    "leistet",
+    "leistete",
+    "leisteten",
+    "leistung",
+    "leistungen",
+    "leistungsfähigen",
+    "leit",
+    "leite",
+    "leiten",
+    "leiter",
+    "leiterchen",
+    "leiterin",
+    "leiters",
+    "leitest",
+    "leitet",
+    "leitete",
+    "leitner",
+    "leitnermali",
+    "leitseil",
+    "leitstern",
+    "leitung",
+    "leitwort",
+    "lenden",
+    "leni",
+    "lenis",
+    "lenken",
+    "lenkerin",
+    "lenkt",
+    "lenkte",
+    "lenzlichte",
+    "lenzwind",
+    "leopard",
+    "lerche",
+    "lerchen",
+    "lerne",
+    "lernen",
+    "lernens",
+    "lernt",
+    "lernte",
+    "lesebücher",
+    "lesen",
+    "leser",
+    "leserforum",
+    "lestungen",
+    "lettern",
+    "letzt",
+    "letzte",
+    "letzten",
+    "letztenmal",
+    "letzter",
+    "letztere",
+    "letzterem",
+    "letzteren",
+    "letzterer",
+    "letzteres",
+    "letztern",
+    "letztes",
+    "letztesmal",
+    "letzthin",
+    "letztlich",
+    "leuchte",
+    "leuchten",
+    "leuchtend",
+    "leuchtende",
+    "leuchtendem",
+    "leuchtenden",
+    "leuchtender",
+    "leuchter",
+    "leuchtern",
+    "leuchtet",
+    "leuchtete",
+    "leuchteten",
+    "leuchtkäfer",
+    "leugnen",
+    "leugnet",
+    "leugneten",
+    "leugnung",
+    "leut",
+    "leutchen",
+    "leute",
+    "leuten",
+    "leutlein",
+    "leutnants",
+    "leutselig",
+    "leutseligkeit",
+    "levi",
+    "leviathan",
+    "leviathans",
+    "levis",
+    "levit",
+    "levite",
+    "leviten",
+    "lhrem",
+    "libanon",
+    "libanons",
+    "libellen",
+    "liber",
+    "liberale",
+    "liberalen",
+    "liberaler",
+    "libero",
+    "libni",
+    "libyer",
+    "lich",
+    "liche",
+    "licht",
+    "lichtarten",
+    "lichtbahn",
+    "lichtbraunes",
+    "lichte",
+    "lichtecke",
+    "lichtem",
+    "lichten",
+    "lichter",
+    "lichterchen",
+    "lichteren",
+    "lichterloh",
+    "lichtern",
+    "lichtes",
+    "lichtete",
+    "lichtfeld",
+    "lichtflocken",
+    "lichtpünktchen",
+    "lichts",
+    "lichtstrahlen",
+    "lichtstrahlungen",
+    "lichtung",
+    "lichtungen",
+    "lichtvoll",
+    "lider",
+    "lieb",
+    "liebchen",
+    "liebe",
+    "liebegutes",
+    "lieben",
+    "liebende",
+    "liebenden",
+    "liebenswürdig",
+    "liebenswürdige",
+    "liebenswürdigen",
+    "liebenswürdiger",
+    "liebenswürdigkeit",
+    "liebenswürdigste",
+    "lieber",
+    "lieberasenden",
+    "liebes",
+    "liebesaffäre",
+    "liebesarten",
+    "liebesbezeugungen",
+    "liebesbriefe",
+    "liebeserklärung",
+    "liebeserklärungen",
+    "liebesfeuer",
+    "liebesfurcht",
+    "liebesgedanken",
+    "liebesgeschichte",
+    "liebesgeschwellten",
+    "liebesgetändel",
+    "liebesglut",
+    "liebesgschicht",
+    "liebeshandel",
+    "liebeshaus",
+    "liebeslauten",
+    "liebesliebe",
+    "liebesliteratur",
+    "liebeslocken",
+    "liebesneigungen",
+    "liebespflicht",
+    "liebestolle",
+    "liebestriebe",
+    "liebestrieben",
+    "liebeswerke",
+    "liebeswerken",
+    "liebet",
+    "liebevoll",
+    "liebevollen",
+    "liebfrauenkirche",
+    "liebhaben",
+    "liebhaber",
+    "liebhabern",
+    "liebkosen",
+    "liebkosendes",
+    "liebkost",
+    "liebkoste",
+    "liebkosungen",
+    "lieblich",
+    "liebliche",
+    "lieblichem",
+    "lieblichen",
+    "lieblicher",
+    "liebliches",
+    "lieblichkeit",
+    "lieblichste",
+    "lieblichsten",
+    "liebling",
+    "lieblingsbilder",
+    "lieblos",
+    "liebreich",
+    "liebreizenden",
+    "liebs",
+    "liebsleut",
+    "liebst",
+    "liebste",
+    "liebsten",
+    "liebster",
+    "liebstes",
+    "liebt",
+    "liebte",
+    "liebten",
+    "liebtätigkeit",
+    "liebtätigkeitswerke",
+    "lied",
+    "liedchen",
+    "liede",
+    "lieder",
+    "liederbuch",
+    "liederchen",
+    "liedergäste",
+    "liederlich",
+    "liederlichen",
+    "liederlicher",
+    "liedern",
+    "liedes",
+    "liedl",
+    "liedln",
+    "lief",
+    "liefe",
+    "liefen",
+    "liefern",
+    "liefert",
+    "lieferung",
+    "lieg",
+    "liege",
+    "liegen",
+    "liegend",
+    "liegende",
+    "liegenden",
+    "liegendes",
+    "liegengeblieben",
+    "liegengelassen",
+    "liegenlassen",
+    "liegst",
+    "liegt",
+    "lieh",
+    "lieschen",
+    "liese",
+    "liess",
+    "liesse",
+    "liessen",
+    "liest",
+    "liestal",
+    "ließ",
+    "ließe",
+    "ließen",
+    "liked",
+    "limonade",
+    "limonaden",
+    "lind",
+    "linde",
+    "linden",
+    "lindenblättchen",
+    "lindenbolz",
+    "lindengebüsches",
+    "lindenkranze",
+    "lindern",
+    "lineal",
+    "linie",
+    "linien",
+    "linienschiffe",
+    "linke",
+    "linken",
+    "linkes",
+    "linkische",
+    "linnen",
+    "linum",
+    "lipp",
+    "lippe",
+    "lippen",
+    "lippenbeissen",
+    "lippenbeißen",
+    "lippenbewegungen",
+    "lipps",
+    "liquidierte",
+    "lischer",
+    "lischsprachiges",
+    "lispeln",
+    "lissabon",
+    "liste",
+    "listig",
+    "listige",
+    "listigen",
+    "listiger",
+    "listigerweise",
+    "listiges",
+    "liter",
+    "literatur",
+    "literaturliste",
+    "litt",
+    "litte",
+    "litten",
+    "livius",
+    "livree",
+    "ln",
+    "lndikativ",
+    "lob",
+    "lobe",
+    "loben",
+    "lobenden",
+    "lobet",
+    "lobgesang",
+    "lobpreisen",
+    "lobpreisung",
+    "lobsingen",
+    "lobsinget",
+    "lobt",
+    "lobte",
+    "loch",
+    "loci",
+    "locis",
+    "locken",
+    "lockend",
+    "lockenden",
+    "lockenhaar",
+    "lockenkopfe",
+    "lockerten",
+    "lockort",
+    "lockruf",
+    "lockte",
+    "locus",
+    "lodenmantel",
+    "loeb",
+    "loge",
+    "logik",
+    "logisch",
+    "logische",
+    "logischen",
+    "logouv",
+    "logwn",
+    "lohe",
+    "lohender",
+    "lohengrin",
+    "lohete",
+    "lohn",
+    "lohne",
+    "lohnes",
+    "lohnkutscher",
+    "lohnt",
+    "lohnte",
+    "lohnverhältnis",
+    "lokal",
+    "lokale",
+    "lokalen",
+    "lokomotive",
+    "londoner",
+    "lorbeeren",
+    "lorbeerkränze",
+    "los",
+    "losfahren",
+    "losgebrochen",
+    "losgebrochener",
+    "losgehen",
+    "losgerissen",
+    "losgeschlagen",
+    "losgetrennt",
+    "loskaufen",
+    "loskettete",
+    "loskommen",
+    "loslassen",
+    "losmachen",
+    "losreissen",
+    "losrennen",
+    "lossagung",
+    "losschlug",
+    "losung",
+    "loswinden",
+    "loszubinden",
+    "loszubrennen",
+    "loszubringen",
+    "loszukaufen",
+    "loszukommen",
+    "loszulassen",
+    "loszumachen",
+    "loszureissen",
+    "loszuschlagen",
+    "loszuschreien",
+    "loszuspringen",
+    "lotterie",
+    "lotteriemann",
+    "lotterien",
+    "lotteriespiel",
+    "lotterieverkehr",
+    "lotterige",
+    "lotterleben",
+    "lotterneste",
+    "louisdors",
+    "louison",
+    "louisons",
+    "lucelle",
+    "lucinde",
+    "lud",
+    "luden",
+    "luder",
+    "ludwig",
+    "luft",
+    "luftballon",
+    "luftballons",
+    "luftblasen",
+    "luftdrucks",
+    "lufterscheinung",
+    "luftgebilde",
+    "lufthauch",
+    "luftig",
+    "luftige",
+    "luftigen",
+    "luftjagd",
+    "luftlosen",
+    "luftraum",
+    "luftschloss",
+    "luftschlösser",
+    "luftsänger",
+    "luga",
+    "lugst",
+    "luitpold",
+    "luk",
+    "lukas",
+    "lukasevangelium",
+    "luke",
+    "lumpen",
+    "lumpenhund",
+    "lumpenhunde",
+    "lumpenkleider",
+    "lumpenkleidung",
+    "lunch",
+    "lunge",
+    "lungen",
+    "lungenflügel",
+    "lungenschuss",
+    "lust",
+    "lustbarkeit",
+    "lustbarkeiten",
+    "lustig",
+    "lustige",
+    "lustigen",
+    "lustiger",
+    "lustiges",
+    "lustigkeit",
+    "lustigsten",
+    "lustreiz",
+    "lustreize",
+    "lustreizen",
+    "lustspielen",
+    "lustwald",
+    "lustwandelnden",
+    "lustwandelte",
+    "lustwälder",
+    "lutherisch",
+    "lutherische",
+    "lutherischem",
+    "lutherischen",
+    "lutherischer",
+    "lutherland",
+    "lutherstädte",
+    "lutum",
+    "lux",
+    "luxerl",
+    "luxerls",
+    "luxuriösen",
+    "luzifer",
+    "lwb",
+    "lydia",
+    "lydias",
+    "lydier",
+    "lyon",
+    "lyra",
+    "lyrik",
+    "lächeln",
+    "lächelnd",
+    "lächelnde",
+    "lächelndem",
+    "lächelnden",
+    "lächelnder",
+    "lächelt",
+    "lächelte",
+    "lächelten",
+    "lächerlich",
+    "lächerlichen",
+    "lächerliches",
+    "lächerlichkeit",
+    "lächerlichsten",
+    "lädchen",
+    "lädchens",
+    "läden",
+    "lädt",
+    "läge",
+    "lägen",
+    "lämmchen",
+    "lämmer",
+    "lämmerwölkchen",
+    "lämmlein",
+    "lämpchen",
+    "ländchen",
+    "ländchens",
+    "länder",
+    "länderfernen",
+    "ländern",
+    "ländliche",
+    "ländlichen",
+    "länge",
+    "länger",
+    "längere",
+    "längeren",
+    "längerer",
+    "längliche",
+    "länglichen",
+    "längliches",
+    "längst",
+    "längste",
+    "längsten",
+    "längstens",
+    "läppische",
+    "lärchenstämmchen",
+    "lärm",
+    "lärmen",
+    "lärmende",
+    "lärmendes",
+    "lärmten",
+    "lässest",
+    "lässig",
+    "lässt",
+    "lästerliche",
+    "lästerliches",
+    "lästerlichstem",
+    "lästern",
+    "lästerns",
+    "lästert",
+    "lästerte",
+    "lästerten",
+    "lästerung",
+    "lästerungen",
+    "lästig",
+    "lästige",
+    "läufer",
+    "läuft",
+    "läuse",
+    "läusen",
+    "läuten",
+    "läutern",
+    "läuternd",
+    "läutet",
+    "läutete",
+    "läßt",
+    "löblicher",
+    "löchelchen",
+    "löcher",
+    "löckchen",
+    "löffel",
+    "löffeln",
+    "lösche",
+    "löschen",
+    "löschpapierne",
+    "löscht",
+    "lösegeld",
+    "lösen",
+    "lösenden",
+    "löst",
+    "löste",
+    "lösten",
+    "lösung",
+    "löwe",
+    "löwen",
+    "löwenhaut",
+    "löwenjagd",
+    "löwin",
+    "lücke",
+    "lücken",
+    "lückenbüsser",
+    "lückenhaft",
+    "lüftchen",
+    "lüfte",
+    "lüften",
+    "lüftete",
+    "lüge",
+    "lügen",
+    "lügenberichten",
+    "lügenhaft",
+    "lügenhafte",
+    "lügner",
+    "lügnerin",
+    "lügners",
+    "lügst",
+    "lügt",
+    "lümmel",
+    "lümmelte",
+    "lüste",
+    "lüstern",
+    "lüsternem",
+    "lüsternheit",
+    "lütütü",
+    "m",
+    "ma",
+    "maastricht",
+    "maastrichter",
+    "macbeth",
+    "macbeths",
+    "mach",
+    "mache",
+    "machen",
+    "machend",
+    "machenden",
+    "macher",
+    "machers",
+    "machet",
+    "macheten",
+    "machiavelli",
+    "machli",
+    "machst",
+    "macht",
+    "machte",
+    "machten",
+    "machtest",
+    "machthabenden",
+    "machthaber",
+    "machtlos",
+    "machtmittel",
+    "machtvoll",
+    "madehen",
+    "madeleine",
+    "madl",
+    "madln",
+    "mag",
+    "magazin",
+    "magd",
+    "magen",
+    "mager",
+    "magere",
+    "mageren",
+    "magerer",
+    "magerkeit",
+    "magerten",
+    "magi",
+    "magica",
+    "magie",
+    "magier",
+    "magiern",
+    "magisch",
+    "magische",
+    "magischen",
+    "magnaten",
+    "magnet",
+    "magnetgebirge",
+    "magst",
+    "mahal",
+    "mahalalel",
+    "mahalalels",
+    "mahl",
+    "mahle",
+    "mahlen",
+    "mahles",
+    "mahlzeit",
+    "mahlzeiten",
+    "mahm",
+    "mahnte",
+    "mahnung",
+    "mahomets",
+    "mai",
+    "maigesang",
+    "maiglöckchenblatt",
+    "maikäfer",
+    "maikäferballade",
+    "maikäferbein",
+    "maikäferbeinchen",
+    "maikäferhafte",
+    "maikäferherz",
+    "maikäferlein",
+    "maikäfermann",
+    "maikäfern",
+    "maikäfertänzchen",
+    "maikäfertänze",
+    "mailand",
+    "mailänder",
+    "mailändischen",
+    "maison",
+    "maisonne",
+    "majestät",
+    "majestätisch",
+    "majorität",
+    "makaber",
+    "makarius",
+    "mal",
+    "male",
+    "malen",
+    "maler",
+    "malerei",
+    "malerisch",
+    "malerische",
+    "mali",
+    "malis",
+    "malottke",
+    "malottkes",
+    "malte",
+    "malum",
+    "malzeichen",
+    "man",
+    "manch",
+    "manche",
+    "manchem",
+    "manchen",
+    "mancher",
+    "mancherlei",
+    "manches",
+    "manchesterjacke",
+    "manchmal",
+    "mandat",
+    "mandelkern",
+    "mandeln",
+    "mandeltorte",
+    "mandieren",
+    "manfred",
+    "mangel",
+    "mangelhaft",
+    "mangelhafte",
+    "mangelhaftem",
+    "mangelndes",
+    "mangelt",
+    "mangelte",
+    "manier",
+    "manieren",
+    "manierlich",
+    "mann",
+    "manna",
+    "mannbares",
+    "manndi",
+    "manne",
+    "mannes",
+    "mannesalter",
+    "mannesbilde",
+    "mannestränen",
+    "mannestugend",
+    "mannhaft",
+    "mannhaftigkeit",
+    "mannichfachen",
+    "mannichfaltigkeit",
+    "mannigfach",
+    "mannigfachem",
+    "mannigfacher",
+    "mannigfaltige",
+    "mannigfaltigen",
+    "mannigfaltigkeit",
+    "mannigfaltigkeiten",
+    "mannigfaltigsten",
+    "mannlich",
+    "mannschaft",
+    "mannshohen",
+    "mannsleute",
+    "mannstiefen",
+    "manoah",
+    "manschetten",
+    "mantel",
+    "mantels",
+    "mantelsack",
+    "mantelträger",
+    "manuskript",
+    "manuskripte",
+    "manuskripten",
+    "manz",
+    "manzen",
+    "manzens",
+    "manzin",
+    "manövriert",
+    "marah",
+    "marcs",
+    "mard",
+    "marderdreck",
+    "mardin",
+    "maria",
+    "mariand",
+    "marienbild",
+    "marienkäferkinderchen",
+    "marinelli",
+    "mario",
+    "marionettenspiel",
+    "maritata",
+    "markierte",
+    "markln",
+    "markstückl",
+    "marksubstanz",
+    "markt",
+    "marktplatz",
+    "marktsegment",
+    "marktwirtschaft",
+    "marmor",
+    "marotte",
+    "marquis",
+    "marsala",
+    "marsch",
+    "marschiert",
+    "marschierte",
+    "marschierten",
+    "marschrichtung",
+    "marseilingen",
+    "marseille",
+    "marterl",
+    "marterleib",
+    "marterls",
+    "marti",
+    "martialischen",
+    "martis",
+    "marx",
+    "marzipan",
+    "marzipanschweinezüchterei",
+    "marzipanstrassen",
+    "maschen",
+    "maschine",
+    "maschinengewehren",
+    "maschinenwerk",
+    "maschinenwesen",
+    "masculus",
+    "maske",
+    "masken",
+    "maskenfahrt",
+    "maskenzuges",
+    "maskerade",
+    "maskiert",
+    "mass",
+    "masse",
+    "massen",
+    "massenhaft",
+    "masses",
+    "massgabe",
+    "massgebenden",
+    "massiv",
+    "massive",
+    "massiven",
+    "massiver",
+    "massnahmen",
+    "massnehmen",
+    "massregeln",
+    "massstab",
+    "massstabe",
+    "masste",
+    "mastbaum",
+    "masterminded",
+    "mastvieh",
+    "materiale",
+    "materialen",
+    "materialer",
+    "materie",
+    "materiell",
+    "materielle",
+    "materiellen",
+    "materieller",
+    "materielles",
+    "mathematik",
+    "mathematisch",
+    "mathematische",
+    "mathematischen",
+    "mathäus",
+    "matrose",
+    "matt",
+    "matten",
+    "matth",
+    "matthias",
+    "mattigkeit",
+    "mauer",
+    "mauern",
+    "mauertür",
+    "mauerwerk",
+    "mauerwinkel",
+    "maul",
+    "maulaffen",
+    "maulbeerbäume",
+    "maule",
+    "maulkorb",
+    "maultasche",
+    "maulvoll",
+    "maulwurf",
+    "maurienne",
+    "maus",
+    "mausen",
+    "mauslöchern",
+    "maxime",
+    "maximen",
+    "maß",
+    "maße",
+    "maßen",
+    "maßes",
+    "maßgabe",
+    "maßgebenden",
+    "maßlos",
+    "maßstab",
+    "maßstabe",
+    "maßstäbe",
+    "maßte",
+    "mechanisch",
+    "mechanismus",
+    "mechujael",
+    "meckern",
+    "meckerndes",
+    "meckerte",
+    "meckerten",
+    "mecklenburg",
+    "medaille",
+    "medaillon",
+    "medien",
+    "medikament",
+    "meditativen",
+    "medizin",
+    "medizinisch",
+    "meer",
+    "meerblauen",
+    "meere",
+    "meeren",
+    "meeres",
+    "meeresgewächs",
+    "meerestiefen",
+    "meergrünen",
+    "meerschiffe",
+    "meerwasser",
+    "meerwunder",
+    "mehr",
+    "mehre",
+    "mehren",
+    "mehrenteils",
+    "mehrer",
+    "mehrere",
+    "mehrerem",
+    "mehreren",
+    "mehrerer",
+    "mehreres",
+    "mehrertrag",
+    "mehresten",
+    "mehret",
+    "mehrfach",
+    "mehrfache",
+    "mehrfachen",
+    "mehrheit",
+    "mehrheitsfähig",
+    "mehrjährige",
+    "mehrmals",
+    "mehrstimmiges",
+    "mehrte",
+    "mehrten",
+    "mehrzahl",
+    "mei",
+    "meiden",
+    "meilen",
+    "meilenstein",
+    "meilensteine",
+    "mein",
+    "meine",
+    "meineidbauer",
+    "meineidiger",
+    "meinem",
+    "meinen",
+    "meiner",
+    "meines",
+    "meinetwegen",
+    "meinetwillen",
+    "meinige",
+    "meinigen",
+    "meins",
+    "meinst",
+    "meint",
+    "meinte",
+    "meinten",
+    "meinung",
+    "meinungen",
+    "meisen",
+    "meisseln",
+    "meist",
+    "meistbietenden",
+    "meiste",
+    "meisten",
+    "meistens",
+    "meistenteils",
+    "meister",
+    "meisterhaft",
+    "meisterhand",
+    "meisterin",
+    "meisterinnen",
+    "meisterlich",
+    "meistern",
+    "meisters",
+    "meisterschaft",
+    "meitli",
+    "melancholisch",
+    "melancholische",
+    "melancholischen",
+    "melcher",
+    "melchior",
+    "melde",
+    "melden",
+    "meldet",
+    "meldete",
+    "meldung",
+    "melodie",
+    "melzergrunde",
+    "memorandum",
+    "memorierungen",
+    "menasse",
+    "menge",
+    "mengen",
+    "meninges",
+    "mensch",
+    "menschen",
+    "menschenalter",
+    "menschenauge",
+    "menschenfeindlich",
+    "menschenfresser",
+    "menschenführung",
+    "menschengeistes",
+    "menschengeschlecht",
+    "menschengeschlechte",
+    "menschengeschlechts",
+    "menschengesichter",
+    "menschenhaut",
+    "menschenherde",
+    "menschenkenntnis",
+    "menschenkind",
+    "menschenleben",
+    "menschenleeren",
+    "menschenliebe",
+    "menschenmenge",
+    "menschenmörder",
+    "menschensamen",
+    "menschenseele",
+    "menschensohn",
+    "menschensöhne",
+    "menschenvernunft",
+    "menschenverstand",
+    "menschenverstande",
+    "menschenverstandes",
+    "menschenwohnungen",
+    "menschenähnliches",
+    "menschenähnlichkeit",
+    "menschgeschlecht",
+    "menschheit",
+    "menschlein",
+    "menschlich",
+    "menschliche",
+    "menschlichen",
+    "menschlicher",
+    "menschliches",
+    "menschlichkeit",
+    "menschlichkeiten",
+    "menseh",
+    "mentale",
+    "mentalitat",
+    "menü",
+    "mer",
+    "merari",
+    "meraris",
+    "merk",
+    "merke",
+    "merken",
+    "merklich",
+    "merklichem",
+    "merkmal",
+    "merkmale",
+    "merkt",
+    "merkte",
+    "merkten",
+    "merkwürdig",
+    "merkwürdige",
+    "merkwürdigen",
+    "merkwürdiger",
+    "merkwürdigerweise",
+    "merkwürdiges",
+    "merkwürdigkeit",
+    "merkwürdigkeiten",
+    "merkwürdigste",
+    "merkwürdigsten",
+    "meschech",
+    "mesmer",
+    "messe",
+    "messen",
+    "messer",
+    "messias",
+    "messiaschristlich",
+    "messiasfrage",
+    "messingdrahtes",
+    "messmer",
+    "messrohr",
+    "messung",
+    "metall",
+    "metalle",
+    "metaphysik",
+    "metaphysische",
+    "metaphysischen",
+    "meter",
+    "meterlang",
+    "meterlange",
+    "methode",
+    "methoden",
+    "methodenlehre",
+    "methodisch",
+    "methodische",
+    "methodischen",
+    "methuschael",
+    "methuschelach",
+    "methuschelachs",
+    "metier",
+    "metropole",
+    "metropolitane",
+    "metzger",
+    "metzgerwägelchen",
+    "meuchelmörder",
+    "meusal",
+    "meßrohr",
+    "mfs",
+    "mi",
+    "miau",
+    "miauend",
+    "mich",
+    "micha",
+    "microsoftboss",
+    "midian",
+    "midianiter",
+    "midians",
+    "mieder",
+    "mielke",
+    "miene",
+    "mienen",
+    "mierte",
+    "miesen",
+    "miete",
+    "mieter",
+    "mietern",
+    "mietete",
+    "miethäuser",
+    "mietsmann",
+    "migros",
+    "mikrofon",
+    "mikrofons",
+    "milch",
+    "milchbäume",
+    "milchgesichtern",
+    "milchkannen",
+    "milchmachens",
+    "milchstrasse",
+    "milchstrassen",
+    "milchstrassenhonig",
+    "milchstrassenmann",
+    "milchstrassenmannes",
+    "milchstrassenschlagsahne",
+    "milchtöpfe",
+    "milde",
+    "mildem",
+    "milden",
+    "milder",
+    "milderen",
+    "milderes",
+    "mildert",
+    "mildes",
+    "mildesten",
+    "militär",
+    "militärdienst",
+    "militärgelände",
+    "militärhistoriker",
+    "militärisch",
+    "militärische",
+    "militärischer",
+    "militärperson",
+    "militärs",
+    "militärzeit",
+    "milliarden",
+    "millionen",
+    "millionär",
+    "millionärin",
+    "mimische",
+    "mincha",
+    "minder",
+    "mindere",
+    "minderheit",
+    "minderheiten",
+    "minderheitensituation",
+    "minderjährige",
+    "mindern",
+    "minderte",
+    "minderwertigkeit",
+    "mindeste",
+    "mindesten",
+    "mindestens",
+    "ministerpräsident",
+    "ministerpräsidenten",
+    "minna",
+    "minnespiel",
+    "minnig",
+    "minorität",
+    "minuten",
+    "minutenlang",
+    "minutenlangen",
+    "minutenzeiger",
+    "mir",
+    "mirakels",
+    "mirl",
+    "mischael",
+    "mischen",
+    "mischt",
+    "mischte",
+    "mischten",
+    "mischung",
+    "miserabligs",
+    "missbrauch",
+    "missbrauchen",
+    "missbrauchsdebatte",
+    "missbrauchten",
+    "missbräuche",
+    "missdeutung",
+    "missdeutungen",
+    "missen",
+    "missetat",
+    "missetaten",
+    "missetäter",
+    "missfiel",
+    "missfielen",
+    "missfällig",
+    "missgeschick",
+    "missgestaltetes",
+    "missgriff",
+    "missgunst",
+    "misshandeln",
+    "misshandelt",
+    "misshandelte",
+    "misshandle",
+    "misshandlung",
+    "mission",
+    "missionarisch",
+    "missionieren",
+    "missioniert",
+    "missionsfeld",
+    "missionsgesellschaft",
+    "missionswille",
+    "misslang",
+    "misslich",
+    "missliebig",
+    "misslungenen",
+    "missmanagement",
+    "missmutig",
+    "misst",
+    "misste",
+    "misstrauen",
+    "misstrauisch",
+    "misstrauischem",
+    "misstrauischen",
+    "misstrauten",
+    "missvergnügt",
+    "missverstande",
+    "missverstanden",
+    "missverstehen",
+    "missversteht",
+    "missverständlich",
+    "missverständlichen",
+    "missverständnis",
+    "missverständnisse",
+    "misswachs",
+    "mistbauer",
+    "mistviech",
+    "mit",
+    "mitanand",
+    "mitarbeit",
+    "mitarbeitenden",
+    "mitarbeiter",
+    "mitarbeiterinnen",
+    "mitarbeitern",
+    "mitass",
+    "mitaß",
+    "mitbegreifen",
+    "mitbesitzer",
+    "mitbrachte",
+    "mitbrachten",
+    "mitbringen",
+    "mitbringt",
+    "mitbrächte",
+    "mitbürger",
+    "mitbürgern",
+    "miteinand",
+    "miteinander",
+    "miteinzubeziehen",
+    "miterleben",
+    "mitfahren",
+    "mitfahrer",
+    "mitführt",
+    "mitführte",
+    "mitg",
+    "mitgeben",
+    "mitgebracht",
+    "mitgedachte",
+    "mitgeführt",
+    "mitgegeben",
+    "mitgehen",
+    "mitgeht",
+    "mitgenommen",
+    "mitgerechnet",
+    "mitgerissen",
+    "mitgesandt",
+    "mitgesellen",
+    "mitgestaltet",
+    "mitgesungen",
+    "mitgetan",
+    "mitgeteilt",
+    "mitgewirkt",
+    "mitgift",
+    "mitginge",
+    "mitglied",
+    "mitglieder",
+    "mitgliedern",
+    "mitgliederversammlung",
+    "mitgliedkirchen",
+    "mitgliedskirchen",
+    "mithalten",
+    "mithelfen",
+    "mithilfe",
+    "mithin",
+    "mithumpeln",
+    "mititium",
+    "mitkommen",
+    "mitkommt",
+    "mitlachen",
+    "mitleid",
+    "mitleidenschaft",
+    "mitleidig",
+    "mitleids",
+    "mitmachte",
+    "mitmanaged",
+    "mitmenschen",
+    "mitn",
+    "mitnahm",
+    "mitnahmen",
+    "mitnehmen",
+    "mitnichten",
+    "mitnähme",
+    "mitschuldig",
+    "mitschuldigen",
+    "mitspielen",
+    "mitstreiten",
+    "mitstritten",
+    "mitt",
+    "mittag",
+    "mittagbrot",
+    "mittagessen",
+    "mittags",
+    "mittagseite",
+    "mittagshelle",
+    "mittagsmahl",
+    "mittagspausen",
+    "mittagsschläfchen",
+    "mittagssonne",
+    "mittagsstunde",
+    "mittagszeit",
+    "mitte",
+    "mitteilen",
+    "mitteilsam",
+    "mitteilsamer",
+    "mitteilt",
+    "mitteilte",
+    "mitteilten",
+    "mitteilung",
+    "mittel",
+    "mittelalter",
+    "mittelbar",
+    "mittelbare",
+    "mittelbaren",
+    "mittelbarer",
+    "mittelchen",
+    "mittelding",
+    "mittelgrosses",
+    "mittelgroßes",
+    "mittelmässigkeit",
+    "mitteln",
+    "mittelpunkt",
+    "mittelpunkte",
+    "mittels",
+    "mittelsmann",
+    "mittelsperson",
+    "mittelst",
+    "mittelzustande",
+    "mittelzwecke",
+    "mitten",
+    "mittendrin",
+    "mittenwald",
+    "mitternacht",
+    "mitternachts",
+    "mitternachtsessen",
+    "mitternachtsgottesdiensten",
+    "mittler",
+    "mittleramt",
+    "mittlere",
+    "mittleren",
+    "mittlerer",
+    "mittleres",
+    "mittlern",
+    "mittlerweile",
+    "mittun",
+    "mittwoch",
+    "mitverursacht",
+    "mitwandern",
+    "mitwirken",
+    "mitwirkung",
+    "mitzubringen",
+    "mitzufahren",
+    "mitzugehen",
+    "mitzuhelfen",
+    "mitzumachen",
+    "mitzunehmen",
+    "mitzuteilen",
+    "mitzuzählen",
+    "mitzwanzigerin",
+    "mixer",
+    "miß",
+    "mißbrauch",
+    "mißbrauchen",
+    "mißbrauchten",
+    "mißbräuche",
+    "mißfallen",
+    "mißfällig",
+    "mißgestaltetes",
+    "mißglückte",
+    "mißhandelt",
+    "mißt",
+    "mißtrauisch",
+    "mißtrauischer",
+    "mißverstanden",
+    "mißverstehen",
+    "mißverständlich",
+    "mißverständlichen",
+    "mißverständnis",
+    "mißverständnisse",
+    "mo",
+    "moab",
+    "moabiter",
+    "moabs",
+    "mobile",
+    "mobilisieren",
+    "mochte",
+    "mochten",
+    "modalitäten",
+    "modell",
+    "moder",
+    "moderdunst",
+    "moderne",
+    "moderner",
+    "modifikationen",
+    "modifiziert",
+    "mohammedaner",
+    "mohnblume",
+    "mohnblumen",
+    "mohnrosen",
+    "molche",
+    "molestierten",
+    "molliger",
+    "momentan",
+    "momente",
+    "momenten",
+    "mon",
+    "monat",
+    "monate",
+    "monaten",
+    "monatlich",
+    "monatszeitschrift",
+    "mond",
+    "mondbeglänzten",
+    "mondberg",
+    "mondberges",
+    "mondbeschienene",
+    "mondblumen",
+    "mondbuch",
+    "mondbutter",
+    "monde",
+    "mondes",
+    "mondfahrt",
+    "mondgegend",
+    "mondhelle",
+    "mondhügel",
+    "mondkanone",
+    "mondkrone",
+    "mondkruste",
+    "mondkälber",
+    "mondkälbern",
+    "mondland",
+    "mondlandschaft",
+    "mondlederstiefel",
+    "mondlicht",
+    "mondlichtes",
+    "mondmann",
+    "mondmanns",
+    "mondnächte",
+    "mondschein",
+    "mondscheinfladen",
+    "mondschlitten",
+    "mondschäfchen",
+    "mondsichel",
+    "mondspinat",
+    "mondstaub",
+    "mondstich",
+    "mondstrahlen",
+    "mondwüste",
+    "monokel",
+    "monokelherr",
+    "monsieur",
+    "monstra",
+    "mont",
+    "montag",
+    "montagnachmittag",
+    "montarcy",
+    "montsalvatsch",
+    "montur",
+    "monument",
+    "moorlache",
+    "moorwiesen",
+    "moorzotteln",
+    "moos",
+    "moose",
+    "moosfasern",
+    "moosgraben",
+    "moosgrund",
+    "moosigen",
+    "moosklee",
+    "moosplatte",
+    "moph",
+    "moral",
+    "moralisch",
+    "moralischbesseren",
+    "moralische",
+    "moralischen",
+    "moralischer",
+    "moralisches",
+    "moralität",
+    "moralphilosoph",
+    "mord",
+    "mordbrenner",
+    "morden",
+    "mordio",
+    "mordtat",
+    "mordversuchs",
+    "morgen",
+    "morgenbrot",
+    "morgende",
+    "morgenden",
+    "morgenferne",
+    "morgenlande",
+    "morgenlandes",
+    "morgenlands",
+    "morgenliedchen",
+    "morgenluft",
+    "morgenländischen",
+    "morgenmantel",
+    "morgenpirsch",
+    "morgenrot",
+    "morgenröte",
+    "morgens",
+    "morgensonne",
+    "morgenstern",
+    "morgensterne",
+    "morgental",
+    "morgenwindchen",
+    "morgigen",
+    "moring",
+    "moritz",
+    "morose",
+    "morosität",
+    "morsche",
+    "morschen",
+    "morscher",
+    "moscheh",
+    "moschus",
+    "mose",
+    "moses",
+    "moskau",
+    "mostes",
+    "motionen",
+    "motitans",
+    "motiv",
+    "motive",
+    "motor",
+    "motorradclub",
+    "motorräder",
+    "motto",
+    "mozartsche",
+    "mta",
+    "mucksen",
+    "muckser",
+    "muckwitz",
+    "muess",
+    "muhme",
+    "mul",
+    "mulack",
+    "mulde",
+    "mullack",
+    "multext",
+    "multikulturelle",
+    "multipliziert",
+    "multis",
+    "mund",
+    "mundart",
+    "mundbärtchen",
+    "munde",
+    "munden",
+    "mundes",
+    "mundeten",
+    "mundgefässe",
+    "mundgefäße",
+    "mundkoch",
+    "mundraub",
+    "mundtot",
+    "mundwerk",
+    "mundwinkel",
+    "mundwinkeln",
+    "munter",
+    "muntere",
+    "munteren",
+    "munterkeit",
+    "muntern",
+    "munterte",
+    "murke",
+    "murkes",
+    "murmelkugeln",
+    "murmeln",
+    "murmelte",
+    "murmeltier",
+    "murmeltiere",
+    "murren",
+    "murrkopf",
+    "murrte",
+    "murrten",
+    "mus",
+    "muschelförmige",
+    "muscheln",
+    "muschelschiff",
+    "muschi",
+    "museum",
+    "musik",
+    "musikalisch",
+    "musikalisches",
+    "musikanten",
+    "musikbusiness",
+    "musiker",
+    "musikindustrie",
+    "musikstück",
+    "musikszene",
+    "musizierende",
+    "musizierte",
+    "muskelfasern",
+    "muskete",
+    "muskulatur",
+    "muss",
+    "musse",
+    "musselinhalstuch",
+    "mussestunden",
+    "musst",
+    "musste",
+    "mussten",
+    "muster",
+    "mustergültig",
+    "musterkarte",
+    "musterte",
+    "musterung",
+    "mut",
+    "mute",
+    "mutes",
+    "mutesäusserung",
+    "mutig",
+    "mutige",
+    "mutigen",
+    "mutlos",
+    "mutmassen",
+    "mutmassliche",
+    "mutoid",
+    "muttchen",
+    "mutter",
+    "mutterdurchbruch",
+    "muttergottes",
+    "mutterkirche",
+    "mutterleib",
+    "mutterleibe",
+    "mutterliebe",
+    "muttern",
+    "mutterseelenallein",
+    "muttersprache",
+    "muttersprachler",
+    "mutterwitz",
+    "mutvoll",
+    "mutwillen",
+    "mutwillens",
+    "mutwillig",
+    "mutwillige",
+    "mutwilligen",
+    "mutwilliges",
+    "mutwilligsten",
+    "muß",
+    "mußt",
+    "mußte",
+    "mußten",
+    "myriaden",
+    "myrrhen",
+    "mysterien",
+    "mystica",
+    "mystiker",
+    "mystizismus",
+    "mythen",
+    "mythos",
+    "mächte",
+    "mächten",
+    "mächtig",
+    "mächtige",
+    "mächtigen",
+    "mächtiger",
+    "mächtiges",
+    "mächtigste",
+    "mädchen",
+    "mädchenhaft",
+    "mädchenhaften",
+    "mädchens",
+    "mädel",
+    "mädels",
+    "mägde",
+    "mägden",
+    "mäglein",
+    "mählich",
+    "mähne",
+    "mängel",
+    "männchen",
+    "männchens",
+    "männer",
+    "männern",
+    "männerstimme",
+    "männin",
+    "männlein",
+    "männlich",
+    "männliche",
+    "männlichen",
+    "männlichkeit",
+    "märchen",
+    "märsche",
+    "märtyrer",
+    "märz",
+    "märzwoche",
+    "mässig",
+    "mässige",
+    "mässigen",
+    "mässiger",
+    "mässigkeit",
+    "mässigt",
+    "mässigte",
+    "mässigung",
+    "mästung",
+    "mäulchen",
+    "mäuler",
+    "mäulern",
+    "mäuschen",
+    "mäuschenstill",
+    "mäuse",
+    "mäusefallen",
+    "mäuslein",
+    "mäßiger",
+    "mäßigt",
+    "mäßigung",
+    "möbel",
+    "möbeln",
+    "möbelpacker",
+    "möbelstück",
+    "möcht",
+    "möchte",
+    "möchten",
+    "möchtest",
+    "möchtet",
+    "mödite",
+    "möge",
+    "mögen",
+    "mögest",
+    "möget",
+    "möglich",
+    "mögliche",
+    "möglichen",
+    "möglicher",
+    "möglicherweise",
+    "möglichkeit",
+    "möglichkeiten",
+    "möglichst",
+    "möglichstem",
+    "möglichster",
+    "mögt",
+    "mönch",
+    "mönchsgesicht",
+    "mörder",
+    "mörderisch",
+    "mörderischen",
+    "mörderlich",
+    "mörders",
+    "mörser",
+    "mörserchen",
+    "mörtelbrocken",
+    "mücke",
+    "mücken",
+    "müde",
+    "müden",
+    "müdes",
+    "müdigkeit",
+    "müesst",
+    "mühe",
+    "mühelosigkeit",
+    "mühen",
+    "mühevoll",
+    "mühevolle",
+    "mühevoller",
+    "mühlsteine",
+    "mühsal",
+    "mühsam",
+    "mühsame",
+    "mühselig",
+    "mühseligen",
+    "mühseligkeit",
+    "müht",
+    "mühten",
+    "müllerjungen",
+    "münchen",
+    "münchener",
+    "mündchen",
+    "mündet",
+    "mündete",
+    "mündlich",
+    "mündlichkeit",
+    "mündung",
+    "münsingen",
+    "münze",
+    "münzen",
+    "mürb",
+    "mürbe",
+    "mürrisch",
+    "müsse",
+    "müssen",
+    "müssig",
+    "müssige",
+    "müssigen",
+    "müssiges",
+    "müssiggang",
+    "müssiggange",
+    "müssigganges",
+    "müssiggänger",
+    "müsst",
+    "müsste",
+    "müssten",
+    "müsstest",
+    "mütchen",
+    "mütlichen",
+    "mütterchen",
+    "mütterlichen",
+    "mütterlicher",
+    "mütterliches",
+    "müttern",
+    "mütze",
+    "mützen",
+    "mützenabnehmen",
+    "müßig",
+    "müßigen",
+    "müßt",
+    "müßte",
+    "müßten",
+    "n",
+    "na",
+    "naamah",
+    "nabel",
+    "nach",
+    "nacha",
+    "nachahmer",
+    "nachahmt",
+    "nachahmung",
+    "nachassons",
+    "nachbar",
+    "nachbaren",
+    "nachbarhäuser",
+    "nachbarin",
+    "nachbarkirche",
+    "nachbarn",
+    "nachbarregierung",
+    "nachbars",
+    "nachbarschaft",
+    "nachbarsleute",
+    "nachbaräckern",
+    "nachbilden",
+    "nachbildeten",
+    "nachblickend",
+    "nachdachte",
+    "nachdem",
+    "nachdenken",
+    "nachdenkende",
+    "nachdenkens",
+    "nachdenklich",
+    "nachdenklichkeit",
+    "nachdenkt",
+    "nachdruck",
+    "nachdrücklichen",
+    "nacheiferten",
+    "nacheiferung",
+    "nacheinander",
+    "nacheinanderfolgende",
+    "nachen",
+    "nacher",
+    "nachernte",
+    "nachflogen",
+    "nachfolgende",
+    "nachfolgenden",
+    "nachfolgt",
+    "nachforschen",
+    "nachforscht",
+    "nachforschte",
+    "nachforschten",
+    "nachforschung",
+    "nachforschungen",
+    "nachfrage",
+    "nachfragen",
+    "nachfühlte",
+    "nachg",
+    "nachgangen",
+    "nachgeahmten",
+    "nachgeben",
+    "nachgebildet",
+    "nachgebildete",
+    "nachgedacht",
+    "nachgefolgt",
+    "nachgehen",
+    "nachgehn",
+    "nachgeht",
+    "nachgekommen",
+    "nachgeschaut",
+    "nachgeschickte",
+    "nachgeschlagen",
+    "nachgesehen",
+    "nachgesetzt",
+    "nachgestiegen",
+    "nachgewiesen",
+    "nachgezählt",
+    "nachgiebigkeit",
+    "nachging",
+    "nachgingen",
+    "nachgrübelte",
+    "nachhaltig",
+    "nachhausefahren",
+    "nachhausewege",
+    "nachher",
+    "nachherigen",
+    "nachhinein",
+    "nachholen",
+    "nachjagend",
+    "nachkam",
+    "nachkommen",
+    "nachkommenschaft",
+    "nachkommenschaften",
+    "nachlass",
+    "nachlassen",
+    "nachlaufen",
+    "nachlaß",
+    "nachlese",
+    "nachlesen",
+    "nachliefen",
+    "nachlässig",
+    "nachlässigkeit",
+    "nachmachen",
+    "nachmalen",
+    "nachmals",
+    "nachmittag",
+    "nachmittage",
+    "nachmittags",
+    "nachmittagsunterhaltung",
+    "nachricht",
+    "nachrichten",
+    "nachrichtenagentur",
+    "nachrichtenlosen",
+    "nachrichtenmagazin",
+    "nachsagen",
+    "nachsah",
+    "nachschau",
+    "nachschauen",
+    "nachschaute",
+    "nachschicken",
+    "nachsehe",
+    "nachsehen",
+    "nachsicht",
+    "nachsichtig",
+    "nachsinnen",
+    "nachspiele",
+    "nachspringen",
+    "nacht",
+    "nachtarbeit",
+    "nachtcafe",
+    "nachtcafé",
+    "nachtclub",
+    "nachteil",
+    "nachteile",
+    "nachteiligen",
+    "nachtessen",
+    "nachteulen",
+    "nachtfahne",
+    "nachtfalter",
+    "nachtfaltern",
+    "nachtfee",
+    "nachtgebet",
+    "nachtgejaide",
+    "nachthemd",
+    "nachthemdchen",
+    "nachthimmel",
+    "nachthäubchen",
+    "nachtig",
+    "nachtigall",
+    "nachtigallen",
+    "nachtisch",
+    "nachtjoppe",
+    "nachtklee",
+    "nachtluft",
+    "nachtmützen",
+    "nachtnebel",
+    "nachtragen",
+    "nachtrauern",
+    "nachträglich",
+    "nachts",
+    "nachtschmetterlinge",
+    "nachtschwarzen",
+    "nachtstillen",
+    "nachttau",
+    "nachtwandel",
+    "nachtwandler",
+    "nachtwächter",
+    "nachtwächtern",
+    "nachvollziehen",
+    "nachwandelten",
+    "nachwelt",
+    "nachwuchs",
+    "nachzottelte",
+    "nachzuahmen",
+    "nachzubilden",
+    "nachzublicken",
+    "nachzudenken",
+    "nachzufahren",
+    "nachzufragen",
+    "nachzufüllen",
+    "nachzugehen",
+    "nachzuholen",
+    "nachzuhängen",
+    "nachzurufen",
+    "nachzuschauen",
+    "nachzusehen",
+    "nachzuspüren",
+    "nachzustreben",
+    "nachzusuchen",
+    "nachzuweisen",
+    "nachzählte",
+    "nachäffen",
+    "nachäffte",
+    "nacken",
+    "nacketes",
+    "nackt",
+    "nackte",
+    "nackten",
+    "nacktheit",
+    "nadab",
+    "nadel",
+    "nadelbüchse",
+    "nadelgras",
+    "nadelzunge",
+    "nadelöhr",
+    "nagel",
+    "nagelschuhe",
+    "nagende",
+    "nagerln",
+    "nagerlstock",
+    "nah",
+    "nahe",
+    "nahegelegenen",
+    "nahegelegt",
+    "nahekommen",
+    "naheliegendste",
+    "nahen",
+    "nahend",
+    "nahende",
+    "nahenden",
+    "naher",
+    "nahezu",
+    "nahm",
+    "nahmen",
+    "nahrhaft",
+    "nahrung",
+    "nahrungsmittel",
+    "naht",
+    "nahtbeflissenen",
+    "nahte",
+    "nahum",
+    "nai",
+    "naiv",
+    "namen",
+    "namenlose",
+    "namenlosen",
+    "namens",
+    "namenstag",
+    "namenstage",
+    "namentlich",
+    "namevor",
+    "namhafte",
+    "nannte",
+    "nannten",
+    "nanu",
+    "naphthali",
+    "napoleon",
+    "napoleonischen",
+    "napoleons",
+    "napoleonsbild",
+    "narben",
+    "narbenbein",
+    "narr",
+    "narren",
+    "narrenspossen",
+    "narret",
+    "narretei",
+    "narrheit",
+    "naschen",
+    "nascherei",
+    "naschhaftigkeit",
+    "nase",
+    "nasegrün",
+    "nasen",
+    "nasenringe",
+    "nasenspitze",
+    "nasenstüber",
+    "nasenwurzel",
+    "nashorn",
+    "nass",
+    "nassauer",
+    "nasse",
+    "nassen",
+    "nati",
+    "nation",
+    "nationalbank",
+    "nationaldenkmal",
+    "nationale",
+    "nationalen",
+    "nationalhelden",
+    "nationalrat",
+    "nationalräte",
+    "nationalrätliche",
+    "nationen",
+    "natur",
+    "natura",
+    "naturae",
+    "naturanstalt",
+    "naturbedürfnis",
+    "naturbegriffe",
+    "naturbetrachtung",
+    "natureinfalt",
+    "natureinrichtungen",
+    "naturen",
+    "naturerkenntnis",
+    "naturerscheinungen",
+    "naturfreund",
+    "naturgabe",
+    "naturgange",
+    "naturganzes",
+    "naturgegenstände",
+    "naturgeheimnisse",
+    "naturgeister",
+    "naturgeistern",
+    "naturgesetz",
+    "naturgesetze",
+    "naturgesetzen",
+    "naturgesetzes",
+    "naturgewalten",
+    "naturkenntnis",
+    "naturkette",
+    "naturkräfte",
+    "naturlaufe",
+    "naturmechanismus",
+    "naturnotwendigkeit",
+    "naturordnung",
+    "naturspiel",
+    "naturursachen",
+    "naturvergötterung",
+    "naturverknüpfung",
+    "naturvermögen",
+    "naturvollkommenheit",
+    "naturwege",
+    "naturwissenschaft",
+    "naturwissenschafter",
+    "naturwüchsige",
+    "natürlich",
+    "natürliche",
+    "natürlichem",
+    "natürlichen",
+    "natürlicher",
+    "natürliches",
+    "natürlichkeit",
+    "natürlichste",
+    "natürlieh",
+    "naus",
+    "nauszahlen",
+    "nazi",
+    "nazigold",
+    "nazimi",
+    "nazis",
+    "naß",
+    "ne",
+    "neapel",
+    "neb",
+    "nebajoths",
+    "nebel",
+    "nebelfahnen",
+    "nebelfetzen",
+    "nebelfrauen",
+    "nebelhafte",
+    "nebelkrähe",
+    "nebelluft",
+    "nebelmassen",
+    "nebeln",
+    "nebelspalter",
+    "nebelstreif",
+    "nebelweg",
+    "nebelwind",
+    "nebelzug",
+    "neben",
+    "nebenan",
+    "nebenbei",
+    "nebenbestimmungen",
+    "nebenbuhler",
+    "nebenbuhlern",
+    "nebeneinander",
+    "nebeneinanderliegende",
+    "nebengebäuden",
+    "nebengesellen",
+    "nebenherlaufen",
+    "nebenmenschen",
+    "nebensache",
+    "nebenstube",
+    "nebenzimmer",
+    "nebenzimmers",
+    "nebenämter",
+    "nebst",
+    "nebukadnezar",
+    "nebukadnezars",
+    "necesse",
+    "neckt",
+    "neckte",
+    "nefas",
+    "neffe",
+    "neffen",
+    "negativ",
+    "negativen",
+    "negatives",
+    "nehme",
+    "nehmen",
+    "nehmend",
+    "nehmenden",
+    "nehmet",
+    "nehmt",
+    "neid",
+    "neides",
+    "neideswut",
+    "neidisch",
+    "neigen",
+    "neigend",
+    "neiget",
+    "neigt",
+    "neigte",
+    "neigten",
+    "neigung",
+    "neigungen",
+    "nein",
+    "nelke",
+    "nem",
+    "nen",
+    "nenne",
+    "nennen",
+    "nennest",
+    "nennete",
+    "nennst",
+    "nennt",
+    "nephaim",
+    "nephalim",
+    "nephilim",
+    "ner",
+    "nervenden",
+    "nervenfieber",
+    "nervös",
+    "nervöse",
+    "nervösem",
+    "nervöser",
+    "nessel",
+    "nesseln",
+    "nest",
+    "neste",
+    "nesterchen",
+    "nestes",
+    "netscape",
+    "nett",
+    "nettchen",
+    "nettchens",
+    "nette",
+    "netten",
+    "netter",
+    "nettes",
+    "netz",
+    "netze",
+    "netzwerk",
+    "neu",
+    "neuangekommene",
+    "neuaufbau",
+    "neubauten",
+    "neubebaute",
+    "neubelebung",
+    "neubesetzung",
+    "neue",
+    "neuem",
+    "neuen",
+    "neuer",
+    "neuerdings",
+    "neueren",
+    "neuerliche",
+    "neuern",
+    "neues",
+    "neuesdas",
+    "neueste",
+    "neuesten",
+    "neugeborene",
+    "neugeborenen",
+    "neugegründeten",
+    "neugeschaffen",
+    "neugesprochenen",
+    "neugestählter",
+    "neugier",
+    "neugierde",
+    "neugierig",
+    "neugierigen",
+    "neugieriger",
+    "neugotischem",
+    "neuigkeit",
+    "neulich",
+    "neumonde",
+    "neun",
+    "neunhundert",
+    "neunte",
+    "neunten",
+    "neuntes",
+    "neunundneunzig",
+    "neunundsechzig",
+    "neunundsiebzig",
+    "neunundvierzigsten",
+    "neunzehn",
+    "neunzig",
+    "neutestamentler",
+    "neutrale",
+    "neuwahlen",
+    "neuyork",
+    "neuübersetzung",
+    "newroz",
+    "nich",
+    "nichs",
+    "nicht",
+    "nichtachtung",
+    "nichtadligen",
+    "nichtanfechtung",
+    "nichtannahme",
+    "nichtanwendung",
+    "nichtaussprechen",
+    "nichtbefreiung",
+    "nichten",
+    "nichtglauben",
+    "nichtglaubens",
+    "nichtgute",
+    "nichtig",
+    "nichtige",
+    "nichtigen",
+    "nichtigkeiten",
+    "nichtinnewerden",
+    "nichtmaterielle",
+    "nichtmediziner",
+    "nichtmerken",
+    "nichtregistrierung",
+    "nichts",
+    "nichtsdestominder",
+    "nichtsdestoweniger",
+    "nichtsehender",
+    "nichtsein",
+    "nichtsnutzigen",
+    "nichtsnutzigsten",
+    "nichtssagend",
+    "nichtssagende",
+    "nichtssagenden",
+    "nichtstun",
+    "nichtswürdige",
+    "nichtswürdigkeit",
+    "nichttaten",
+    "nichtwahren",
+    "nichtwahrheiten",
+    "nichtwahrnehmen",
+    "nichtwähler",
+    "nichtökonomische",
+    "nicken",
+    "nickend",
+    "nickt",
+    "nickte",
+    "nickten",
+    "nidit",
+    "nie",
+    "nieder",
+    "niederbeugen",
+    "niederbeugend",
+    "niederblickend",
+    "niederdrücken",
+    "niederdrückt",
+    "niedere",
+    "niederen",
+    "niederfiel",
+    "niederfielen",
+    "niederfloss",
+    "niederfloß",
+    "niedergang",
+    "niedergebeugt",
+    "niedergebrannt",
+    "niedergefahren",
+    "niedergefallen",
+    "niedergehend",
+    "niedergelassen",
+    "niedergelegt",
+    "niedergeschlagen",
+    "niedergeschlagenen",
+    "niedergestiegen",
+    "niedergeworfen",
+    "niederging",
+    "niedergleiten",
+    "niedergschlagen",
+    "niedergänge",
+    "niederhängt",
+    "niederklatschen",
+    "niederknien",
+    "niederkniend",
+    "niederknieten",
+    "niederkommen",
+    "niederlage",
+    "niederlagen",
+    "niederlassen",
+    "niederliess",
+    "niederließ",
+    "niederlächelnd",
+    "niederregneten",
+    "niederschlagen",
+    "niederschlug",
+    "niederschlägt",
+    "niederschmetternder",
+    "niederschoss",
+    "niedersetzte",
+    "niederste",
+    "niedersten",
+    "niederstes",
+    "niederstrich",
+    "niederstäubte",
+    "niedertritt",
+    "niedertropften",
+    "niederträchtige",
+    "niederträchtigen",
+    "niederwerfen",
+    "niederwärts",
+    "niederzubeugen",
+    "niederzulassen",
+    "niedlich",
+    "niedliche",
+    "niedlichen",
+    "niedlicher",
+    "niedrig",
+    "niedrige",
+    "niedrigen",
+    "niedriger",
+    "niedrigere",
+    "niedrigeren",
+    "niedrigerer",
+    "niedrigkeit",
+    "niedrigre",
+    "niedrigste",
+    "niedrigsten",
+    "niemals",
+    "niemand",
+    "niemandem",
+    "niemanden",
+    "niemands",
+    "niemandsland",
+    "nieren",
+    "nierenfett",
+    "niesen",
+    "nieste",
+    "nietzsche",
+    "nievergelt",
+    "nigen",
+    "niggli",
+    "nihil",
+    "nikodemus",
+    "nikolaus",
+    "nikosia",
+    "nils",
+    "nimm",
+    "nimmer",
+    "nimmermehr",
+    "nimmst",
+    "nimmt",
+    "nimrien",
+    "nimriens",
+    "ninive",
+    "ninives",
+    "nippte",
+    "nirgend",
+    "nirgends",
+    "nirgendwo",
+    "nische",
+    "nisi",
+    "nisten",
+    "nisteten",
+    "nit",
+    "nitrit",
+    "nitritkapsel",
+    "nitroglyzerin",
+    "nivellierung",
+    "noaaaaaa",
+    "noach",
+    "noachs",
+    "nobel",
+    "noblen",
+    "nobler",
+    "noch",
+    "nochmal",
+    "nochmals",
+    "nod",
+    "nominationskommission",
+    "nominative",
+    "nominativen",
+    "nommen",
+    "nonnenartiger",
+    "nord",
+    "nordamerikanischen",
+    "norddeutschland",
+    "norden",
+    "nordengland",
+    "nordischen",
+    "nordlondon",
+    "nordpol",
+    "nordwestschweiz",
+    "nordwärts",
+    "nordzypern",
+    "normale",
+    "normalen",
+    "normalerweise",
+    "norwegische",
+    "norwegischen",
+    "nostalgie",
+    "notar",
+    "notaufnahme",
+    "notburga",
+    "notdürftig",
+    "notdürftigsten",
+    "notenbank",
+    "notenblätter",
+    "notenlinien",
+    "notfall",
+    "notfalls",
+    "notfällen",
+    "notgezwungenen",
+    "notieren",
+    "notiert",
+    "notierte",
+    "notizbuch",
+    "notizbücher",
+    "notizen",
+    "notlüge",
+    "notpfennige",
+    "notrfälle",
+    "notstand",
+    "notwehr",
+    "notwendig",
+    "notwendige",
+    "notwendigen",
+    "notwendiger",
+    "notwendigere",
+    "notwendigerweise",
+    "notwendiges",
+    "notwendigkeit",
+    "notwendigkeiten",
+    "notwendigste",
+    "noumen",
+    "noumenen",
+    "noumenon",
+    "novellen",
+    "november",
+    "novembertage",
+    "novum",
+    "nr",
+    "nu",
+    "nuance",
+    "nummer",
+    "nun",
+    "nunmehr",
+    "nur",
+    "nuss",
+    "nussbaumholz",
+    "nussbaumlade",
+    "nussbaumschrank",
+    "nutz",
+    "nutzbare",
+    "nutzbringende",
+    "nutzen",
+    "nutzens",
+    "nutzenschaffen",
+    "nutzenschaffens",
+    "nutzleistungen",
+    "nutzlos",
+    "nutzlose",
+    "nutzloses",
+    "nutzniessung",
+    "nutzt",
+    "nutzwirkungen",
+    "nutzzwecke",
+    "nutzzwecken",
+    "nutzzwecks",
+    "nzz",
+    "nächst",
+    "nächstbeste",
+    "nächstbesten",
+    "nächstdem",
+    "nächste",
+    "nächsten",
+    "nächstenliebe",
+    "nächstens",
+    "nächster",
+    "nächstgelegene",
+    "nächstliegenden",
+    "nächte",
+    "nächten",
+    "nächtlich",
+    "nächtliche",
+    "nächtlichen",
+    "nächtlicher",
+    "nägelein",
+    "nägeln",
+    "nähe",
+    "näher",
+    "näheren",
+    "näherer",
+    "näheres",
+    "nähern",
+    "nähert",
+    "näherte",
+    "näherten",
+    "nähertreten",
+    "nähete",
+    "näheten",
+    "nähme",
+    "nähmen",
+    "nähren",
+    "nährmitteln",
+    "nährte",
+    "nähten",
+    "näm",
+    "nämlich",
+    "nämliche",
+    "nämlichen",
+    "näpheg",
+    "närrchen",
+    "närrin",
+    "närrische",
+    "närrischem",
+    "närrischen",
+    "närrischer",
+    "näschen",
+    "nässe",
+    "nöten",
+    "nötig",
+    "nötige",
+    "nötigen",
+    "nötiger",
+    "nötigte",
+    "nötigung",
+    "nüber",
+    "nüchtern",
+    "nüchternen",
+    "nüchternes",
+    "nücken",
+    "nürnberg",
+    "nüsschen",
+    "nüsse",
+    "nüssen",
+    "nütze",
+    "nützen",
+    "nützlich",
+    "nützliche",
+    "nützlichen",
+    "nützlicher",
+    "nützlichere",
+    "nützlicheres",
+    "nützlichkeit",
+    "nützlichste",
+    "nützt",
+    "o",
+    "oam",
+    "oan",
+    "ob",
+    "obacht",
+    "obdach",
+    "oben",
+    "obenauf",
+    "obendrein",
+    "obenein",
+    "obenhin",
+    "obenhinaus",
+    "ober",
+    "oberbayrischer",
+    "oberbehörde",
+    "oberduffendorf",
+    "obere",
+    "oberen",
+    "oberes",
+    "oberfläche",
+    "oberflächen",
+    "oberflächlich",
+    "oberflächlicher",
+    "oberflächlichkeit",
+    "oberförster",
+    "oberhalb",
+    "oberhand",
+    "oberhaupt",
+    "oberherrschaft",
+    "oberkirchenrat",
+    "oberlippe",
+    "obern",
+    "oberpriester",
+    "obersatze",
+    "oberst",
+    "oberste",
+    "obersten",
+    "oberstes",
+    "oberstleutnants",
+    "obgleich",
+    "obhut",
+    "obige",
+    "obigem",
+    "obigen",
+    "obiger",
+    "objekt",
+    "objekte",
+    "objekten",
+    "objektiv",
+    "objektive",
+    "objektivem",
+    "objektiven",
+    "objektiver",
+    "objekts",
+    "oblag",
+    "obliegende",
+    "obliegenheit",
+    "obligationen",
+    "obristen",
+    "obschon",
+    "observantione",
+    "obsiegen",
+    "obst",
+    "obstbäumen",
+    "obste",
+    "obsthändler",
+    "obstinatio",
+    "obwohl",
+    "obzwar",
+    "ocak",
+    "ochak",
+    "ochs",
+    "ochse",
+    "ochsen",
+    "ochsenhorn",
+    "ochsenkopfe",
+    "odem",
+    "oder",
+    "ofen",
+    "ofenruss",
+    "ofenruß",
+    "ofens",
+    "offen",
+    "offenb",
+    "offenbach",
+    "offenbar",
+    "offenbare",
+    "offenbaren",
+    "offenbarende",
+    "offenbarer",
+    "offenbarlich",
+    "offenbart",
+    "offenbarung",
+    "offenbarungen",
+    "offene",
+    "offenekirche",
+    "offenem",
+    "offenen",
+    "offener",
+    "offenes",
+    "offengebliebene",
+    "offenheit",
+    "offenkundigen",
+    "offensichtlich",
+    "offensiv",
+    "offenstehe",
+    "offenstehend",
+    "offenstehende",
+    "offenstehenden",
+    "offizielle",
+    "offiziellen",
+    "offizier",
+    "offiziere",
+    "offizieren",
+    "offnen",
+    "oft",
+    "oftmals",
+    "ohad",
+    "ohn",
+    "ohne",
+    "ohnedem",
+    "ohnedies",
+    "ohnehin",
+    "ohnmacht",
+    "ohnmächtig",
+    "oho",
+    "ohr",
+    "ohre",
+    "ohren",
+    "ohrenbetäubener",
+    "ohres",
+    "ohrfeige",
+    "ohrfeigen",
+    "ohrfeigte",
+    "ohrlappen",
+    "oke",
+    "oktober",
+    "oldenburg",
+    "oldenburger",
+    "oleander",
+    "oleandergebüsch",
+    "olgemälde",
+    "ombudsmann",
+    "omni",
+    "omnium",
+    "onan",
+    "onkel",
+    "onkels",
+    "ontologia",
+    "ontologischen",
+    "oooh",
+    "oooooh",
+    "opera",
+    "opes",
+    "opfer",
+    "opfere",
+    "opfergabe",
+    "opfergaben",
+    "opfern",
+    "opfers",
+    "opfert",
+    "opferte",
+    "ophir",
+    "ophirs",
+    "opposition",
+    "optimismus",
+    "optischen",
+    "orangeblüten",
+    "orangefarbenen",
+    "orangen",
+    "orangenbäume",
+    "orangenhaine",
+    "orangensaft",
+    "orbitz",
+    "ordentlich",
+    "ordentliche",
+    "ordentlichen",
+    "ordentlicher",
+    "ordentliches",
+    "ordiniertdem",
+    "ordinäre",
+    "ordinärsten",
+    "ordne",
+    "ordnen",
+    "ordnende",
+    "ordnet",
+    "ordnete",
+    "ordneten",
+    "ordnung",
+    "ordnungen",
+    "ordnungs",
+    "ordnungsgemäss",
+    "ordnungsgemässe",
+    "ordnungsgesetz",
+    "ordnungsliebe",
+    "ordnungssinn",
+    "ordonnanzgewehre",
+    "organ",
+    "organe",
+    "organen",
+    "organisation",
+    "organisationen",
+    "organische",
+    "organischen",
+    "organisieren",
+    "organisiert",
+    "organisierte",
+    "organismen",
+    "organismus",
+    "orgel",
+    "orgelkasten",
+    "orgelspieler",
+    "orgelspielers",
+    "orientieren",
+    "orientteppiche",
+    "originalität",
+    "originalkorrespondenz",
+    "originarium",
+    "originelles",
+    "orione",
+    "orkan",
+    "ort",
+    "orte",
+    "orten",
+    "ortes",
+    "orthodoxe",
+    "orthodoxen",
+    "ortlichkeiten",
+    "ortsansäs",
+    "ortschaft",
+    "ortschaften",
+    "ortsvorschlägen",
+    "oslo",
+    "ost",
+    "ostberlin",
+    "ostberliner",
+    "ostdeutschen",
+    "ostdeutschland",
+    "osten",
+    "ostens",
+    "osteransprache",
+    "osterei",
+    "ostereier",
+    "osterfest",
+    "osterfestgebete",
+    "osterhäschen",
+    "ostern",
+    "osternacht",
+    "osternest",
+    "osternestes",
+    "osteuropa",
+    "ostindien",
+    "ostindischen",
+    "ostländischen",
+    "ostrolenka",
+    "ostschweiz",
+    "ostschweizer",
+    "ostwind",
+    "ostwindes",
+    "ottenschlag",
+    "otter",
+    "ottern",
+    "otterngezüchte",
+    "otterngift",
+    "oxford",
+    "ozean",
+    "p",
+    "paar",
+    "paare",
+    "paaren",
+    "paares",
+    "paarmal",
+    "paarung",
+    "paarweise",
+    "pacht",
+    "pachtzins",
+    "packen",
+    "packt",
+    "packte",
+    "packten",
+    "paddeln",
+    "paffte",
+    "pagen",
+    "paket",
+    "paketchen",
+    "palast",
+    "palea",
+    "paleae",
+    "pallu",
+    "palme",
+    "palmen",
+    "palmzweige",
+    "palmzweigen",
+    "paläste",
+    "palästen",
+    "panamahut",
+    "panier",
+    "pankraz",
+    "pankrazens",
+    "pankrazius",
+    "pankräzchens",
+    "pankräzlein",
+    "panorama",
+    "panspfeife",
+    "pantinengänger",
+    "pantoffelfüssen",
+    "pantoffeln",
+    "panzer",
+    "panzerfahrzeugen",
+    "panzerreiter",
+    "papa",
+    "papagei",
+    "papier",
+    "papiere",
+    "papieren",
+    "papierne",
+    "papierstauden",
+    "papparbeit",
+    "pappel",
+    "pappkartons",
+    "pappkästchen",
+    "papst",
+    "papstes",
+    "paradies",
+    "paradiese",
+    "paradiesgärtchen",
+    "paradiesgärtels",
+    "paradiesgärtlein",
+    "paradiesische",
+    "paradiesvogel",
+    "paraklet",
+    "parallele",
+    "paralogismen",
+    "parapluie",
+    "parat",
+    "pardel",
+    "parfümiert",
+    "parieren",
+    "paris",
+    "pariser",
+    "parke",
+    "parkett",
+    "parks",
+    "parsimoniae",
+    "parte",
+    "partei",
+    "parteien",
+    "parteigenossen",
+    "parteigänger",
+    "parteiischen",
+    "parteileute",
+    "partie",
+    "partien",
+    "partners",
+    "parvulus",
+    "pascha",
+    "pascher",
+    "paspeliert",
+    "passable",
+    "passah",
+    "passahfest",
+    "passahtieres",
+    "passanten",
+    "passargument",
+    "passen",
+    "passend",
+    "passende",
+    "passenden",
+    "passender",
+    "passendes",
+    "passeten",
+    "passieren",
+    "passiert",
+    "passierte",
+    "passiertem",
+    "passierten",
+    "passivem",
+    "passt",
+    "passte",
+    "passten",
+    "pastete",
+    "pasteten",
+    "patent",
+    "patentierung",
+    "pater",
+    "paternoster",
+    "paternosteraufzug",
+    "paternosters",
+    "paters",
+    "pathologisch",
+    "pathologischen",
+    "patieneen",
+    "patienten",
+    "patriarch",
+    "patriarchen",
+    "patron",
+    "patronen",
+    "patronhilsen",
+    "patrontasche",
+    "patrouille",
+    "patrouillen",
+    "patsch",
+    "patsche",
+    "patschelte",
+    "patschnass",
+    "pauke",
+    "pauken",
+    "paukenbegleitung",
+    "paukenden",
+    "paukenklöppel",
+    "paukenkonzert",
+    "paukenstock",
+    "pausbäckige",
+    "pausen",
+    "pavia",
+    "pavillon",
+    "pavillons",
+    "paßt",
+    "pech",
+    "pechdunkeln",
+    "peche",
+    "pechfinster",
+    "pechnarben",
+    "pechs",
+    "pechschwarz",
+    "pechschwarzen",
+    "pechsiedern",
+    "pechstreif",
+    "pechverbandes",
+    "pecoris",
+    "pedanten",
+    "pedanterie",
+    "pegasus",
+    "pein",
+    "peinigen",
+    "peiniger",
+    "peinigt",
+    "peinigte",
+    "peinlich",
+    "peinliche",
+    "peinlicher",
+    "peinlichste",
+    "peinlichsten",
+    "peinvoll",
+    "peirastik",
+    "peitsche",
+    "peitschen",
+    "peitschenknall",
+    "peleg",
+    "pelz",
+    "pelzen",
+    "pelzes",
+    "pelzmütze",
+    "pelzverbrämten",
+    "pelzwerk",
+    "pension",
+    "pentarchie",
+    "pepiniere",
+    "perceptio",
+    "perceptiones",
+    "perceptionis",
+    "perceptivum",
+    "percipere",
+    "perez",
+    "perfectissimum",
+    "peri",
+    "periode",
+    "perioden",
+    "periodischen",
+    "perlen",
+    "perlenband",
+    "perlmutter",
+    "permanenter",
+    "perpetuum",
+    "persifliert",
+    "personen",
+    "perspektive",
+    "perspicacia",
+    "persuasiones",
+    "persönlich",
+    "persönliche",
+    "persönlichem",
+    "persönlichen",
+    "persönlichkeit",
+    "persönlichkeiten",
+    "pest",
+    "pestischer",
+    "petent",
+    "peterchen",
+    "peterchens",
+    "petersburg",
+    "petitpierre",
+    "petrarum",
+    "petrus",
+    "petschiert",
+    "petz",
+    "petzbären",
+    "pfad",
+    "pfade",
+    "pfaden",
+    "pfades",
+    "pfaffen",
+    "pfahl",
+    "pfahles",
+    "pfanne",
+    "pfannen",
+    "pfarr",
+    "pfarreien",
+    "pfarrer",
+    "pfarrerdie",
+    "pfarrerstochter",
+    "pfarrerweiterbildung",
+    "pfarrerweiterbildungskommission",
+    "pfarrpersonen",
+    "pfarrverein",
+    "pfarrämter",
+    "pfauenfedern",
+    "pfeffer",
+    "pfefferkuchen",
+    "pfefferkuchenbäume",
+    "pfefferkuchenmännchen",
+    "pfefferkuchenpäckchen",
+    "pfefferkuchensprüche",
+    "pfefferkuchenzweigen",
+    "pfefferminzplätzchen",
+    "pfefferminztee",
+    "pfeifchen",
+    "pfeife",
+    "pfeifen",
+    "pfeifend",
+    "pfeifenden",
+    "pfeifenköpfen",
+    "pfeifmelodie",
+    "pfeil",
+    "pfeile",
+    "pfennig",
+    "pfennige",
+    "pferchen",
+    "pferd",
+    "pferdchen",
+    "pferde",
+    "pferdehufen",
+    "pferdekräfte",
+    "pferden",
+    "pferdes",
+    "pferdeäpfel",
+    "pfiff",
+    "pfiffe",
+    "pfiffige",
+    "pfiffigem",
+    "pfingstsonntag",
+    "pflanze",
+    "pflanzen",
+    "pflanzensamen",
+    "pflanzerzigarre",
+    "pflanzt",
+    "pflanzte",
+    "pflanzung",
+    "pflanzungen",
+    "pflaster",
+    "pflaume",
+    "pflaumen",
+    "pflaumenaug",
+    "pflaumenkern",
+    "pflege",
+    "pflegen",
+    "pflegst",
+    "pflegt",
+    "pflegte",
+    "pflegten",
+    "pflicht",
+    "pflichtbewußtsein",
+    "pflichten",
+    "pflichterfüllung",
+    "pflichtgemäss",
+    "pflichtgemässe",
+    "pflichtgemäß",
+    "pflichtmässig",
+    "pflichtmässigen",
+    "pflichtveranstaltung",
+    "pflichtvergessene",
+    "pflug",
+    "pfluge",
+    "pflugviehs",
+    "pflugzüge",
+    "pflänzchen",
+    "pflüge",
+    "pflügen",
+    "pflüger",
+    "pflügte",
+    "pflügten",
+    "pforte",
+    "pforten",
+    "pfote",
+    "pfoten",
+    "pfründe",
+    "pfuhl",
+    "pfui",
+    "pfund",
+    "pfuschern",
+    "pfuschte",
+    "pfäfflein",
+    "pfännchen",
+    "pförtlein",
+    "pfötchen",
+    "pfüet",
+    "pfühl",
+    "pfülmen",
+    "pfütze",
+    "pfützen",
+    "pfütznassen",
+    "phaethon",
+    "phantasie",
+    "phantasielos",
+    "phantasien",
+    "phantasierte",
+    "phantasterei",
+    "phantastisch",
+    "pharao",
+    "pharaos",
+    "pharisäer",
+    "pharisäern",
+    "pheresiter",
+    "pheresiters",
+    "philanthropie",
+    "philautia",
+    "philipp",
+    "philippus",
+    "philister",
+    "philisterhafter",
+    "philisterhaftigkeit",
+    "philistern",
+    "philisterseelen",
+    "philistäa",
+    "philoso",
+    "philosoph",
+    "philosophen",
+    "philosophie",
+    "philosophiert",
+    "philosophin",
+    "philosophisch",
+    "philosophischem",
+    "philosophischen",
+    "philosophischer",
+    "philosophisches",
+    "phische",
+    "photographien",
+    "phrasen",
+    "phrath",
+    "physik",
+    "physische",
+    "physischen",
+    "phäakenaufschwung",
+    "phänomene",
+    "phänomenen",
+    "pianisten",
+    "piepen",
+    "pietätlosigkeit",
+    "pijanon",
+    "pikierten",
+    "pilatus",
+    "pilgerfahrten",
+    "pilgerstab",
+    "pilotprojekt",
+    "pilze",
+    "pinehas",
+    "pineiss",
+    "pineissens",
+    "pinsel",
+    "pipsernden",
+    "pirsch",
+    "pirsche",
+    "pirschgang",
+    "pirschwetter",
+    "pischon",
+    "pispernd",
+    "pistole",
+    "pistolen",
+    "pistolengeschütz",
+    "pithom",
+    "pitschnassen",
+    "plafonds",
+    "plage",
+    "plagen",
+    "plagten",
+    "plakat",
+    "plakate",
+    "plakaten",
+    "plane",
+    "planet",
+    "planeten",
+    "plangemäss",
+    "plankenzaun",
+    "plant",
+    "plappermaul",
+    "platane",
+    "platanen",
+    "plato",
+    "platonis",
+    "platos",
+    "platschte",
+    "platte",
+    "plattenproduzent",
+    "plattform",
+    "plattner",
+    "platz",
+    "platze",
+    "platzen",
+    "platzes",
+    "platzl",
+    "platzregen",
+    "platzt",
+    "platzten",
+    "plauderei",
+    "plaudern",
+    "plaudernd",
+    "plaudert",
+    "plauderte",
+    "plauderten",
+    "plausibel",
+    "plausibilität",
+    "playactors",
+    "plebejisch",
+    "plump",
+    "plumpe",
+    "plumpen",
+    "plumpheit",
+    "plumsdibums",
+    "plunder",
+    "plusterröcken",
+    "plusterte",
+    "pläne",
+    "plättete",
+    "plätzchen",
+    "plätzchens",
+    "plätze",
+    "plätzen",
+    "plötzlich",
+    "plötzliche",
+    "plötzlichen",
+    "plötzlicher",
+    "plötzliches",
+    "plötzlichkeit",
+    "plündergut",
+    "plündern",
+    "pochen",
+    "pochenden",
+    "pochte",
+    "podium",
+    "podiums",
+    "podiumsgespräch",
+    "poesie",
+    "pokalsieger",
+    "polacke",
+    "polackei",
+    "polacken",
+    "polarkreises",
+    "polen",
+    "polenmütze",
+    "poliert",
+    "polierten",
+    "politik",
+    "politiker",
+    "politisch",
+    "politische",
+    "politischen",
+    "politischer",
+    "politisieren",
+    "polizei",
+    "polizeiakten",
+    "polizeianstalt",
+    "polizeiliches",
+    "polizeimann",
+    "polizeistation",
+    "polizeiwagen",
+    "polizist",
+    "polizisten",
+    "pollux",
+    "polnisch",
+    "polnische",
+    "polnischen",
+    "polnischer",
+    "polnisches",
+    "polster",
+    "polstersessel",
+    "polsterstuhle",
+    "polsterstühle",
+    "polterabend",
+    "poltern",
+    "polterte",
+    "pomade",
+    "pomphafte",
+    "pontius",
+    "popanze",
+    "popularitätstief",
+    "populismus",
+    "populäres",
+    "portier",
+    "portiers",
+    "portierte",
+    "portierten",
+    "portionen",
+    "portionenweise",
+    "porto",
+    "portokosten",
+    "porträt",
+    "porträts",
+    "porzellan",
+    "porzellanköpfchen",
+    "porzellansplitter",
+    "porzia",
+    "posaunen",
+    "posaunenbläser",
+    "posaunenschall",
+    "posaunensturmstimme",
+    "posaunt",
+    "posaunte",
+    "positionen",
+    "positiv",
+    "positiven",
+    "positiver",
+    "positives",
+    "positur",
+    "possen",
+    "possierliche",
+    "possierlichen",
+    "possierlichkeit",
+    "postabschluss",
+    "postabschluß",
+    "posten",
+    "postfach",
+    "posthorntönen",
+    "postieren",
+    "postulat",
+    "postulate",
+    "postulaten",
+    "postulats",
+    "postulieren",
+    "postuliert",
+    "postwendend",
+    "potsdam",
+    "potz",
+    "poularde",
+    "pracht",
+    "prachtkleid",
+    "prachtvoll",
+    "prachtvolle",
+    "praedestinatione",
+    "praedestinationes",
+    "prag",
+    "praga",
+    "pragmateia",
+    "prahlen",
+    "prahler",
+    "prahlerisch",
+    "prahlerischen",
+    "prahlerischer",
+    "praktikum",
+    "praktisch",
+    "praktische",
+    "praktischem",
+    "praktischen",
+    "praktischer",
+    "praktisches",
+    "praktizierende",
+    "prangt",
+    "prangte",
+    "prarrerschaft",
+    "prarrkapitel",
+    "prasselten",
+    "predigen",
+    "prediger",
+    "prediget",
+    "predigt",
+    "predigten",
+    "preis",
+    "preisausschreiben",
+    "preisen",
+    "preises",
+    "preiset",
+    "preisgegeben",
+    "preisgibt",
+    "preisträger",
+    "preiszugeben",
+    "prenzlauer",
+    "presse",
+    "pressedienst",
+    "pressedienstes",
+    "pressemiteilung",
+    "pressemitteilung",
+    "pressemittteilung",
+    "pressieren",
+    "presste",
+    "prester",
+    "prickelte",
+    "pries",
+    "priester",
+    "priestern",
+    "priesters",
+    "priesterstöchter",
+    "priestertalar",
+    "priestertum",
+    "priestertume",
+    "priestertums",
+    "primat",
+    "primate",
+    "principia",
+    "principiis",
+    "prinz",
+    "prinzen",
+    "prinzess",
+    "prinzip",
+    "prinzipien",
+    "prinzips",
+    "priori",
+    "priorität",
+    "prioritäten",
+    "prisen",
+    "prius",
+    "privaten",
+    "privates",
+    "privatgeschäfte",
+    "privatleidenschaft",
+    "privatneigungen",
+    "privatpersonen",
+    "privilegien",
+    "probabilia",
+    "probemetall",
+    "probesprechen",
+    "probieren",
+    "probiert",
+    "probierte",
+    "probierten",
+    "problematisch",
+    "problematischen",
+    "problematischer",
+    "probleme",
+    "problemen",
+    "problemwelle",
+    "produkt",
+    "produkte",
+    "produktion",
+    "produktionsassistentin",
+    "produktionsverbot",
+    "produzent",
+    "produzentin",
+    "produzieren",
+    "profession",
+    "professionelle",
+    "professor",
+    "profi",
+    "profil",
+    "profitieren",
+    "programm",
+    "programmheft",
+    "progressiv",
+    "progressus",
+    "prohibet",
+    "projekt",
+    "projektarbeit",
+    "projekte",
+    "projektes",
+    "prokurist",
+    "prokuristen",
+    "promenaden",
+    "promovet",
+    "promoviert",
+    "prophet",
+    "propheten",
+    "prophetenbildern",
+    "prophetische",
+    "prophetischen",
+    "prophezeiungen",
+    "propinquitates",
+    "proportion",
+    "proportionierlich",
+    "proportionierte",
+    "proportionierten",
+    "proprium",
+    "prosdialegomenwn",
+    "prospekte",
+    "protestant",
+    "protestantismus",
+    "protestieren",
+    "protestierte",
+    "protestschreien",
+    "protokoll",
+    "provenzalischen",
+    "proviant",
+    "provinz",
+    "provinzen",
+    "provision",
+    "provisorischen",
+    "provozieren",
+    "provoziert",
+    "prozent",
+    "prozentsatz",
+    "prozess",
+    "prozesse",
+    "prozesses",
+    "prozessstadien",
+    "prozessstadium",
+    "prozeß",
+    "prozeßstadien",
+    "prozeßstadium",
+    "prunkbauten",
+    "prunkenden",
+    "prunkendes",
+    "prunkwagen",
+    "prusten",
+    "prustend",
+    "prustete",
+    "prutzeln",
+    "prä",
+    "prächtig",
+    "prächtige",
+    "prächtigen",
+    "prächtiger",
+    "prächtiges",
+    "prächtigste",
+    "prädikat",
+    "prädikate",
+    "prägten",
+    "präjudizien",
+    "prälaten",
+    "präludium",
+    "präparaten",
+    "präpariere",
+    "präsent",
+    "präsentiert",
+    "präsentierteller",
+    "präses",
+    "präsident",
+    "präsidenten",
+    "präsidentin",
+    "präsidierte",
+    "präventiven",
+    "prüfe",
+    "prüfen",
+    "prüfend",
+    "prüfenden",
+    "prüfte",
+    "prüften",
+    "prüfung",
+    "prüfungslauf",
+    "prüfungsmerkmal",
+    "prüfungsraum",
+    "prügel",
+    "prügelfertig",
+    "prügeln",
+    "prügelstrafe",
+    "prügelte",
+    "prügle",
+    "prügler",
+    "ps",
+    "psalmen",
+    "psyche",
+    "psychischen",
+    "psychologie",
+    "psychologisch",
+    "psychologische",
+    "psychologischen",
+    "psychologischer",
+    "puah",
+    "publikationen",
+    "publikum",
+    "pudel",
+    "pudelhund",
+    "pudelmütze",
+    "puerulus",
+    "puffen",
+    "puffte",
+    "puh",
+    "puls",
+    "pult",
+    "pulver",
+    "pulverdampf",
+    "pulverrauch",
+    "pumpe",
+    "pumpender",
+    "punkt",
+    "punkte",
+    "punkten",
+    "punktionen",
+    "punktum",
+    "punsch",
+    "pupille",
+    "puppe",
+    "puppen",
+    "puppengarten",
+    "puppenkind",
+    "puppenshow",
+    "puppenstube",
+    "purpur",
+    "purpurlippen",
+    "purpurrose",
+    "purpurroten",
+    "purzelbaum",
+    "purzelbäumchen",
+    "purzelbäume",
+    "purzeln",
+    "purzelnd",
+    "purzelte",
+    "purzelten",
+    "pusterohr",
+    "pustete",
+    "puteo",
+    "puterrot",
+    "puth",
+    "putiels",
+    "putsch",
+    "putschpitschpatsch",
+    "puttperwutt",
+    "putz",
+    "putzen",
+    "putzer",
+    "putzläppchen",
+    "putzte",
+    "pyramide",
+    "pyrop",
+    "pyropen",
+    "päckchen",
+    "päcklein",
+    "pädagogik",
+    "pärchen",
+    "pässe",
+    "pönitenz",
+    "püffe",
+    "püffen",
+    "pünktchen",
+    "pünktlich",
+    "pünktlichen",
+    "pünktlichkeit",
+    "pünktlichste",
+    "püppchen",
+    "pütschli",
+    "qm",
+    "qua",
+    "quacken",
+    "quae",
+    "quakend",
+    "quaksend",
+    "quakte",
+    "qual",
+    "quale",
+    "qualifikation",
+    "qualität",
+    "qualm",
+    "quantität",
+    "quartierbewohnern",
+    "quartiere",
+    "quasi",
+    "quax",
+    "quell",
+    "quelle",
+    "quellen",
+    "quellenangabe",
+    "quellenden",
+    "quentchen",
+    "quer",
+    "querbalken",
+    "quere",
+    "querhölzchen",
+    "querköpfigkeiten",
+    "quertäler",
+    "quiekend",
+    "quiekenden",
+    "quiekte",
+    "quietschte",
+    "quint",
+    "quintessenz",
+    "quirlige",
+    "quirlte",
+    "quod",
+    "quoll",
+    "quollen",
+    "quoque",
+    "quäle",
+    "quälen",
+    "quälend",
+    "quälenden",
+    "quälerei",
+    "quält",
+    "quälte",
+    "quälten",
+    "r",
+    "raamas",
+    "raamses",
+    "rabbi",
+    "raben",
+    "rabenschwarzes",
+    "rabensteiner",
+    "rache",
+    "rachegefühl",
+    "rachel",
+    "rachen",
+    "rachgier",
+    "rachgierigen",
+    "rachsucht",
+    "rachsüchtigen",
+    "rad",
+    "rademacher",
+    "radfahrlegitimation",
+    "radikales",
+    "radikalismus",
+    "radio",
+    "radiosender",
+    "radmantel",
+    "radotiert",
+    "raffiniert",
+    "rafft",
+    "raffte",
+    "rage",
+    "ragte",
+    "ragten",
+    "rahm",
+    "rahmen",
+    "rahmschüsseln",
+    "raines",
+    "raka",
+    "ramsau",
+    "rande",
+    "rang",
+    "rangen",
+    "rangordnung",
+    "ranken",
+    "rankte",
+    "rankten",
+    "rann",
+    "rannte",
+    "rannten",
+    "ranzen",
+    "raphael",
+    "rappeln",
+    "rappelte",
+    "rapps",
+    "rar",
+    "rasanter",
+    "rasch",
+    "rasche",
+    "rascheln",
+    "raschelnden",
+    "raschelt",
+    "raschelte",
+    "raschem",
+    "raschen",
+    "rascher",
+    "rasches",
+    "rasen",
+    "rasenbrocken",
+    "rasend",
+    "rasender",
+    "rasendes",
+    "rasenfelder",
+    "raset",
+    "rasierapparat",
+    "rasieren",
+    "rasselfritzen",
+    "rasseln",
+    "rasselns",
+    "rasselte",
+    "rasselten",
+    "raste",
+    "rastlos",
+    "rastlosen",
+    "rastloses",
+    "rat",
+    "raten",
+    "rates",
+    "ratgeber",
+    "ratgebern",
+    "rathaus",
+    "rathause",
+    "ratiocinari",
+    "ration",
+    "rationale",
+    "rationalen",
+    "rationales",
+    "rationalismus",
+    "ratione",
+    "rationelle",
+    "rationes",
+    "ratlos",
+    "ratlosen",
+    "rats",
+    "ratsam",
+    "ratschlagten",
+    "ratschläge",
+    "ratschlägen",
+    "ratsherren",
+    "ratsvorsitzende",
+    "ratszimmer",
+    "raub",
+    "rauben",
+    "raubersbestie",
+    "raubkunst",
+    "raubt",
+    "raubte",
+    "raubtier",
+    "raubtiere",
+    "raubversuch",
+    "rauch",
+    "rauche",
+    "rauchen",
+    "rauchend",
+    "rauchende",
+    "rauchenden",
+    "rauchendes",
+    "rauchern",
+    "rauchfaden",
+    "rauchsäulen",
+    "rauchtabak",
+    "rauchte",
+    "rauchten",
+    "rauchtisch",
+    "rauchwerk",
+    "rauchwerks",
+    "rauchwolken",
+    "rauchzeug",
+    "raufereien",
+    "rauheit",
+    "rauhen",
+    "rauhes",
+    "raum",
+    "raume",
+    "raumes",
+    "raupe",
+    "raus",
+    "rausch",
+    "rausche",
+    "rauschen",
+    "rauschend",
+    "rauschende",
+    "rauschenden",
+    "rauscht",
+    "rauschte",
+    "rauschten",
+    "rausg",
+    "rausgeschmissen",
+    "rausschneiden",
+    "rauszuschneiden",
+    "ravensburg",
+    "reagieren",
+    "reale",
+    "realen",
+    "reales",
+    "realisier",
+    "realisieren",
+    "realisiert",
+    "realisierung",
+    "realistisch",
+    "realität",
+    "rebecka",
+    "rebellischen",
+    "rebenlaube",
+    "rebenschossen",
+    "rebhuhnpastete",
+    "rebhühner",
+    "rebhühnern",
+    "rechen",
+    "rechenbuch",
+    "rechenmeister",
+    "rechenschaft",
+    "recherchieren",
+    "rechne",
+    "rechnen",
+    "rechnereien",
+    "rechnet",
+    "rechnete",
+    "rechnung",
+    "rechnungen",
+    "rechnungsabschluss",
+    "rechobs",
+    "recht",
+    "rechtbehalten",
+    "rechte",
+    "rechten",
+    "rechter",
+    "rechtes",
+    "rechtfertige",
+    "rechtfertigen",
+    "rechtfertigende",
+    "rechtfertigt",
+    "rechtfertigung",
+    "rechtfertigungserklärung",
+    "rechtfertigungserklärungder",
+    "rechtfertigungslehre",
+    "rechthaben",
+    "rechtlich",
+    "rechtliche",
+    "rechtlichen",
+    "rechtlicher",
+    "rechtliches",
+    "rechtlichkeit",
+    "rechtmässig",
+    "rechtmässige",
+    "rechtmässigem",
+    "rechtmässigen",
+    "rechts",
+    "rechtsangelegenheit",
+    "rechtsbewusstsein",
+    "rechtschaffen",
+    "rechtschaffene",
+    "rechtschaffenen",
+    "rechtschaffener",
+    "rechtschaffenheit",
+    "rechtskräftig",
+    "rechtsordnungen",
+    "rechtssinn",
+    "rechtsstaat",
+    "rechtswissenschaft",
+    "rechtzeitig",
+    "recke",
+    "recken",
+    "reckend",
+    "reckte",
+    "redakteur",
+    "redaktion",
+    "redaktorin",
+    "rede",
+    "reden",
+    "redend",
+    "redende",
+    "redenden",
+    "redendes",
+    "redens",
+    "redensart",
+    "redensarten",
+    "redest",
+    "redesätze",
+    "redet",
+    "redete",
+    "redeten",
+    "redeweise",
+    "redewendung",
+    "redezeichen",
+    "redigiert",
+    "redigierte",
+    "redlich",
+    "redliche",
+    "redlichen",
+    "redlicher",
+    "redlichkeit",
+    "redner",
+    "rednerische",
+    "redselig",
+    "redt",
+    "reduktion",
+    "reduktionen",
+    "reeder",
+    "refa",
+    "refah",
+    "referent",
+    "referenz",
+    "reflektieren",
+    "reflektiert",
+    "reflexion",
+    "reformation",
+    "reformen",
+    "reformierte",
+    "reformierten",
+    "refutieren",
+    "regal",
+    "rege",
+    "regel",
+    "regelmässig",
+    "regelmässige",
+    "regelmässigen",
+    "regelmässiger",
+    "regelmässigkeit",
+    "regelmäßig",
+    "regeln",
+    "regelrecht",
+    "regelrechte",
+    "regen",
+    "regenbogen",
+    "regende",
+    "regenden",
+    "regenfritz",
+    "regenfritzen",
+    "regengüssen",
+    "regenhose",
+    "regennacht",
+    "regennassen",
+    "regenrauschen",
+    "regens",
+    "regenschirm",
+    "regentagen",
+    "regentropfen",
+    "regentschaft",
+    "regenwasserfass",
+    "regenwurm",
+    "regenwürmer",
+    "regiere",
+    "regieren",
+    "regierende",
+    "regierenden",
+    "regierer",
+    "regierers",
+    "regiert",
+    "regierte",
+    "regierung",
+    "regierungskrisedie",
+    "regierungsrat",
+    "regiment",
+    "regimente",
+    "regimentes",
+    "regimentskommandeurs",
+    "regionalstelle",
+    "regionen",
+    "registrieren",
+    "registriert",
+    "reglos",
+    "reglosen",
+    "regnat",
+    "regnen",
+    "regnerische",
+    "regnerischer",
+    "regnete",
+    "regt",
+    "regte",
+    "reguel",
+    "reguels",
+    "regula",
+    "regulative",
+    "regulieren",
+    "reguliert",
+    "regulos",
+    "regulären",
+    "regung",
+    "regungen",
+    "regungslos",
+    "regungslosen",
+    "reh",
+    "rehabilitiert",
+    "rehbock",
+    "rehböcken",
+    "rehcherl",
+    "rehe",
+    "rehen",
+    "rehes",
+    "rehfussgriff",
+    "rehgeiss",
+    "reiben",
+    "reich",
+    "reiche",
+    "reichem",
+    "reichen",
+    "reichenhall",
+    "reicher",
+    "reichere",
+    "reiches",
+    "reichgekleidete",
+    "reichhaltiges",
+    "reichlich",
+    "reichliche",
+    "reichlichem",
+    "reichlichen",
+    "reichlichere",
+    "reichlicheres",
+    "reichs",
+    "reichsfeind",
+    "reichskristallnacht",
+    "reichspogrom",
+    "reichste",
+    "reichsten",
+    "reicht",
+    "reichte",
+    "reichten",
+    "reichtstag",
+    "reichtum",
+    "reichtümer",
+    "reichtümern",
+    "reif",
+    "reife",
+    "reifen",
+    "reifende",
+    "reifenden",
+    "reiflich",
+    "reifrocke",
+    "reifte",
+    "reigen",
+    "reihe",
+    "reihen",
+    "reihenfolge",
+    "reiher",
+    "reihte",
+    "reime",
+    "reimt",
+    "rein",
+    "reine",
+    "reinem",
+    "reinen",
+    "reiner",
+    "reinerem",
+    "reineren",
+    "reines",
+    "reinheit",
+    "reinhold",
+    "reinigen",
+    "reinigend",
+    "reinigkeit",
+    "reinigung",
+    "reinigungen",
+    "reinlich",
+    "reinliche",
+    "reinlichen",
+    "reinliches",
+    "reinlichkeit",
+    "reinreichen",
+    "reinsten",
+    "reinzukleben",
+    "reis",
+    "reise",
+    "reiseanzügen",
+    "reisebegleiter",
+    "reisebeschreibung",
+    "reisebüro",
+    "reisebürofrau",
+    "reisegefährten",
+    "reisegeld",
+    "reisen",
+    "reisenden",
+    "reisender",
+    "reisern",
+    "reiset",
+    "reisetasche",
+    "reisete",
+    "reisetruhe",
+    "reiseveranstalter",
+    "reisewagen",
+    "reisig",
+    "reisigbündel",
+    "reissaus",
+    "reisse",
+    "reissen",
+    "reissende",
+    "reissenden",
+    "reissender",
+    "reisst",
+    "reist",
+    "reiste",
+    "reiten",
+    "reiter",
+    "reiterei",
+    "reitergesellschaft",
+    "reitern",
+    "reitertrupps",
+    "reitet",
+    "reitpeitsche",
+    "reitpeitschen",
+    "reittier",
+    "reiz",
+    "reizbar",
+    "reize",
+    "reizen",
+    "reizend",
+    "reizende",
+    "reizendem",
+    "reizenden",
+    "reizender",
+    "reizendes",
+    "reizte",
+    "reizvolle",
+    "reiße",
+    "reißen",
+    "reißenden",
+    "reißender",
+    "rekruten",
+    "rekrutiert",
+    "relativ",
+    "relativen",
+    "relativiert",
+    "religion",
+    "religionen",
+    "religionreligionsunterricht",
+    "religions",
+    "religionsgebräuche",
+    "religionsgemeinschaft",
+    "religionslehre",
+    "religionsleuten",
+    "religiös",
+    "religiöse",
+    "religiösen",
+    "reliquiae",
+    "remalja",
+    "remaljas",
+    "reminiszenz",
+    "rempelte",
+    "rennen",
+    "rennens",
+    "renner",
+    "rennern",
+    "rennt",
+    "renntier",
+    "renommierten",
+    "renoviert",
+    "renovierte",
+    "renten",
+    "rephaim",
+    "reporter",
+    "repräsentanten",
+    "repräsentantinnennach",
+    "republik",
+    "republikaner",
+    "residenz",
+    "residenzstadt",
+    "resolut",
+    "resoluten",
+    "respekt",
+    "respektablen",
+    "respektieren",
+    "respekts",
+    "ressiert",
+    "ressort",
+    "restchen",
+    "reste",
+    "resten",
+    "restitution",
+    "restlichen",
+    "resultat",
+    "resultate",
+    "resümiert",
+    "reto",
+    "rette",
+    "retten",
+    "rettende",
+    "rettenden",
+    "retter",
+    "retterin",
+    "rettete",
+    "rettung",
+    "rettungsanker",
+    "rettungsgedanken",
+    "rettungsgesellschaft",
+    "reue",
+    "reuen",
+    "reuet",
+    "reuete",
+    "reulos",
+    "reuss",
+    "reut",
+    "reute",
+    "revier",
+    "revolution",
+    "revolutionäre",
+    "rezept",
+    "rezepten",
+    "rezeptivität",
+    "rezin",
+    "rezins",
+    "rhauderfehn",
+    "rhein",
+    "rheinische",
+    "rheinstrom",
+    "rhetorik",
+    "rhetorischen",
+    "richard",
+    "richt",
+    "richte",
+    "richten",
+    "richter",
+    "richteramt",
+    "richterlichen",
+    "richtern",
+    "richters",
+    "richterstuhl",
+    "richtes",
+    "richtet",
+    "richtete",
+    "richteten",
+    "richtig",
+    "richtige",
+    "richtigem",
+    "richtigen",
+    "richtiger",
+    "richtiges",
+    "richtigkeit",
+    "richtigs",
+    "richtigsten",
+    "richtscheit",
+    "richtschnur",
+    "richtung",
+    "richtungen",
+    "rieb",
+    "rieben",
+    "riechen",
+    "riechschwämmchen",
+    "riecht",
+    "rief",
+    "riefen",
+    "riegel",
+    "riegelte",
+    "riemen",
+    "riese",
+    "rieseln",
+    "rieselt",
+    "rieselte",
+    "rieselten",
+    "riesen",
+    "riesenfülle",
+    "riesengebirge",
+    "riesengebirges",
+    "riesengross",
+    "riesengrosse",
+    "riesengrosser",
+    "riesengrosses",
+    "riesengrund",
+    "riesenhafte",
+    "riesenhafter",
+    "riesenhund",
+    "riesenkamme",
+    "riesenkanone",
+    "riesenkraft",
+    "riesenköpfige",
+    "riesenlanges",
+    "riesenmade",
+    "riesenmässiger",
+    "riesenpferde",
+    "riesenzacken",
+    "riesige",
+    "riesigen",
+    "riesiger",
+    "riet",
+    "rieten",
+    "rin",
+    "rina",
+    "rinde",
+    "rindenklunsen",
+    "rindensubstanz",
+    "rindensubstanzen",
+    "rinder",
+    "rindern",
+    "rinderwahn",
+    "rindes",
+    "rindfleisch",
+    "rindvieh",
+    "rindviehs",
+    "ringe",
+    "ringelchen",
+    "ringellocken",
+    "ringelreih",
+    "ringelten",
+    "ringen",
+    "ringenden",
+    "ringern",
+    "ringes",
+    "ringetausch",
+    "ringgeschmückten",
+    "ringier",
+    "ringlein",
+    "ringmauer",
+    "ringmauern",
+    "rings",
+    "ringsherum",
+    "ringsum",
+    "ringsumher",
+    "ringvorlesung",
+    "rinne",
+    "rinnen",
+    "rinnt",
+    "rippe",
+    "rippen",
+    "rippenbrüche",
+    "rippenstösse",
+    "risiken",
+    "risiko",
+    "riskiert",
+    "riss",
+    "risse",
+    "rissen",
+    "risses",
+    "rith",
+    "ritikül",
+    "ritt",
+    "ritten",
+    "ritter",
+    "ritterdienst",
+    "ritterherr",
+    "ritterherrlichen",
+    "ritterlich",
+    "ritterliche",
+    "ritterlichen",
+    "ritterlichkeit",
+    "rittern",
+    "ritternase",
+    "ritterrüstung",
+    "ritters",
+    "ritterschaft",
+    "rittersherr",
+    "rittersherrn",
+    "rittersmann",
+    "ritterstab",
+    "rittes",
+    "rittlings",
+    "rituale",
+    "ritus",
+    "ritzen",
+    "rivalen",
+    "riß",
+    "rnit",
+    "robespierre",
+    "roch",
+    "rocke",
+    "rockes",
+    "rockschlippchenende",
+    "rockschoss",
+    "rocktasche",
+    "rocktaschen",
+    "rockärmel",
+    "rodeten",
+    "roh",
+    "rohe",
+    "roheiten",
+    "rohen",
+    "roher",
+    "rohes",
+    "rohesten",
+    "rohr",
+    "rohrdurchwachsenen",
+    "rohrstock",
+    "rolf",
+    "rolle",
+    "rollen",
+    "rollenden",
+    "rollendes",
+    "rollfeld",
+    "rollsteine",
+    "rollt",
+    "rollte",
+    "rollten",
+    "rollwalze",
+    "romane",
+    "romanes",
+    "romanhelden",
+    "romanshorn",
+    "romantische",
+    "romantischer",
+    "romantisches",
+    "romeo",
+    "rosafarbenes",
+    "rosan",
+    "rosen",
+    "rosenbäumchen",
+    "rosenbüsche",
+    "rosengärtchen",
+    "rosenroten",
+    "rosenroter",
+    "rosenschimmer",
+    "rosental",
+    "rosenwäldchen",
+    "rosiges",
+    "rosigster",
+    "rosine",
+    "rosinen",
+    "rosmarin",
+    "rosmaringärtchen",
+    "rosoli",
+    "ross",
+    "rosse",
+    "rosselenker",
+    "rossen",
+    "rosstäuscher",
+    "rost",
+    "rostige",
+    "rostigen",
+    "rostiger",
+    "rostiges",
+    "rot",
+    "rotbekleidete",
+    "rotbäckigen",
+    "rotbäckiger",
+    "rotbäckiges",
+    "rote",
+    "rotem",
+    "roten",
+    "roter",
+    "rotes",
+    "rotgelbem",
+    "rotgelben",
+    "rothaarig",
+    "rothaarige",
+    "rothaarigen",
+    "rothäutigen",
+    "rotieren",
+    "rotseidenes",
+    "rotte",
+    "rottenweise",
+    "rotwein",
+    "roulierende",
+    "routine",
+    "routinesendung",
+    "roß",
+    "roßtäuscher",
+    "rpd",
+    "ruben",
+    "rubens",
+    "rubin",
+    "rubinfunken",
+    "rubrik",
+    "rubriken",
+    "ruchbar",
+    "ruchlosen",
+    "ruchlosigkeit",
+    "ruck",
+    "ruckelt",
+    "rucksack",
+    "rucksäcken",
+    "rudel",
+    "rudeln",
+    "rudels",
+    "ruder",
+    "ruderbank",
+    "rudern",
+    "ruderte",
+    "rudi",
+    "rue",
+    "ruedi",
+    "ruf",
+    "rufe",
+    "rufen",
+    "rufende",
+    "rufenden",
+    "rufens",
+    "rufes",
+    "rufet",
+    "ruft",
+    "ruh",
+    "ruhe",
+    "ruhekissen",
+    "ruhen",
+    "ruhende",
+    "ruhepunkten",
+    "ruhet",
+    "ruhete",
+    "ruhevoll",
+    "ruhig",
+    "ruhige",
+    "ruhigem",
+    "ruhigen",
+    "ruhiger",
+    "ruhigeren",
+    "ruhiges",
+    "ruhigste",
+    "ruhigsten",
+    "ruhm",
+    "ruhme",
+    "ruhmes",
+    "ruhn",
+    "ruhrgebiet",
+    "ruht",
+    "ruhte",
+    "ruhten",
+    "ruine",
+    "ruiniert",
+    "ruinierte",
+    "rum",
+    "rumgebrumsel",
+    "rummelplatz",
+    "rumorte",
+    "rumpelkammer",
+    "rumpfe",
+    "rumrennen",
+    "rund",
+    "runde",
+    "runden",
+    "runder",
+    "rundes",
+    "rundfunk",
+    "rundfunks",
+    "rundgang",
+    "rundheit",
+    "rundlich",
+    "rundlichen",
+    "rundschrift",
+    "rundung",
+    "runge",
+    "runterbracht",
+    "runzeln",
+    "runzelreiche",
+    "runzlige",
+    "runzligen",
+    "russ",
+    "russenpeitsche",
+    "russes",
+    "russgesichtige",
+    "russigen",
+    "russiger",
+    "russisch",
+    "russland",
+    "rute",
+    "ruten",
+    "rutenhieb",
+    "rutenstreiche",
+    "rutenwald",
+    "rutschbahn",
+    "rutschbahnen",
+    "rutschte",
+    "ruß",
+    "rußes",
+    "rächen",
+    "rächte",
+    "rächten",
+    "rädelsführer",
+    "räder",
+    "räderchen",
+    "rädergerassels",
+    "rädern",
+    "räkelte",
+    "ränke",
+    "ränzchen",
+    "ränzel",
+    "räsonnieren",
+    "rätsel",
+    "rätselhafte",
+    "räuber",
+    "räubereien",
+    "räuberhauptmann",
+    "räuberhöhle",
+    "räubern",
+    "räucherig",
+    "räude",
+    "räume",
+    "räumen",
+    "räumliche",
+    "räumlichkeiten",
+    "räumt",
+    "réduit",
+    "röche",
+    "röck",
+    "röckchen",
+    "röcke",
+    "röcken",
+    "röhre",
+    "römisch",
+    "römischen",
+    "röschen",
+    "röslein",
+    "röte",
+    "rötliche",
+    "rötlichem",
+    "rötlichen",
+    "rötlicher",
+    "rötliches",
+    "rüben",
+    "rübezahl",
+    "rübezahls",
+    "rübzählischen",
+    "rückantwortcouverts",
+    "rücke",
+    "rücken",
+    "rückfall",
+    "rückflug",
+    "rückgesetzten",
+    "rückgängig",
+    "rückhalt",
+    "rückhaltlos",
+    "rückhaltlose",
+    "rückhaltlosen",
+    "rückhaltlosigkeit",
+    "rückkehr",
+    "rückschauen",
+    "rückschluss",
+    "rückseiten",
+    "rücksicht",
+    "rücksichten",
+    "rücksichtslos",
+    "rücksichtsloser",
+    "rücksichtsloseste",
+    "rücksichtslosigkeit",
+    "rücksitze",
+    "rückspiel",
+    "rückt",
+    "rückte",
+    "rücktritt",
+    "rücktritten",
+    "rücktrittszahlen",
+    "rückwechsel",
+    "rückweg",
+    "rückwege",
+    "rückwirkungen",
+    "rückwärtigen",
+    "rückwärts",
+    "rückzahlungsrate",
+    "rückzug",
+    "rückübertragung",
+    "rüdiger",
+    "rüge",
+    "rühmen",
+    "rühmenswerte",
+    "rühmt",
+    "rühmte",
+    "rühre",
+    "rühren",
+    "rührend",
+    "rührenden",
+    "rührender",
+    "rühret",
+    "rühriger",
+    "rührigkeit",
+    "rührt",
+    "rührte",
+    "rührten",
+    "rührung",
+    "rümpfte",
+    "rüstet",
+    "rüstig",
+    "rüstige",
+    "rüstigen",
+    "rüstiger",
+    "rüstigkeit",
+    "rüstung",
+    "rütteln",
+    "rüttelte",
+    "rüttelten",
+    "s",
+    "saal",
+    "saale",
+    "saales",
+    "saalhälfte",
+    "saat",
+    "saaten",
+    "saatfeld",
+    "sabbath",
+    "sabbathe",
+    "sabbaths",
+    "sabbattollen",
+    "sabine",
+    "sacconex",
+    "sach",
+    "sache",
+    "sachen",
+    "sacherklärungen",
+    "sachgemäss",
+    "sachgemässen",
+    "sachlage",
+    "sachliche",
+    "sachs",
+    "sachse",
+    "sachsen",
+    "sacht",
+    "sachte",
+    "sachten",
+    "sachverständigen",
+    "sachzusammenhang",
+    "sack",
+    "sackgasse",
+    "sackuhr",
+    "sacrosanctum",
+    "saeculi",
+    "saffianschuhe",
+    "saft",
+    "sag",
+    "sage",
+    "sagen",
+    "saget",
+    "sagst",
+    "sagt",
+    "sagte",
+    "sagten",
+    "sah",
+    "sahen",
+    "sahest",
+    "sahet",
+    "saint",
+    "saiten",
+    "saiteninstrument",
+    "saiteninstrumente",
+    "saitenspieler",
+    "sakra",
+    "sakramente",
+    "sakramenten",
+    "sakramentsverwaltung",
+    "salami",
+    "salat",
+    "salatblatt",
+    "salbte",
+    "salbung",
+    "salbungsvoll",
+    "sali",
+    "salis",
+    "salomo",
+    "salomon",
+    "salomos",
+    "salutieren",
+    "salutierend",
+    "salvieren",
+    "salz",
+    "salzburg",
+    "salzburger",
+    "salzbüchsl",
+    "salze",
+    "salzgeist",
+    "salzknappe",
+    "salzknappen",
+    "sam",
+    "samaria",
+    "samen",
+    "samenkraut",
+    "samenkörner",
+    "samenreste",
+    "samens",
+    "samm",
+    "sammelaktion",
+    "sammeln",
+    "sammelnd",
+    "sammelt",
+    "sammelte",
+    "sammelten",
+    "sammet",
+    "sammetfutter",
+    "sammetkleide",
+    "sammetrasen",
+    "sammetschärpe",
+    "sammetweste",
+    "sammle",
+    "sammlung",
+    "sammlungen",
+    "samstag",
+    "samstagabend",
+    "samt",
+    "samtwämschen",
+    "samuel",
+    "samuels",
+    "sand",
+    "sande",
+    "sandes",
+    "sandige",
+    "sandkörnern",
+    "sandmann",
+    "sandmanns",
+    "sandmännchen",
+    "sandmännchens",
+    "sandmännchenschlitten",
+    "sandsiegel",
+    "sandstein",
+    "sandte",
+    "sandten",
+    "sanft",
+    "sanfte",
+    "sanftem",
+    "sanften",
+    "sanfter",
+    "sanftes",
+    "sanftesten",
+    "sanftleuchtenden",
+    "sanftmut",
+    "sanftmütige",
+    "sanftmütiger",
+    "sanftsäuselndes",
+    "sang",
+    "sangen",
+    "sangesbrüder",
+    "sanken",
+    "sankt",
+    "sanktionen",
+    "sanktionslos",
+    "sann",
+    "saphir",
+    "saphirstein",
+    "saphirsteines",
+    "saphirsteins",
+    "sapperlöter",
+    "sappermenter",
+    "sarah",
+    "sardes",
+    "sarg",
+    "sarge",
+    "sarnen",
+    "sass",
+    "sassen",
+    "satan",
+    "satanischen",
+    "satansunternehmen",
+    "satellitenschüssel",
+    "satirisch",
+    "satirische",
+    "satrap",
+    "satt",
+    "sattelbandage",
+    "satteln",
+    "sattgegessen",
+    "sattsam",
+    "sattzuessen",
+    "saturnalien",
+    "satz",
+    "satze",
+    "satzes",
+    "satzung",
+    "satzungen",
+    "sau",
+    "sauber",
+    "saubere",
+    "sauberen",
+    "sauberste",
+    "sauer",
+    "sauereien",
+    "sauerkraut",
+    "sauersüss",
+    "saugen",
+    "saugeröhrchen",
+    "sauget",
+    "saul",
+    "saum",
+    "saume",
+    "saumensch",
+    "saus",
+    "sauschwab",
+    "sausen",
+    "sausendem",
+    "sauser",
+    "sausergelüste",
+    "sausers",
+    "sauste",
+    "sausten",
+    "sautopf",
+    "savoyarde",
+    "savoyarden",
+    "savoyardenkinder",
+    "savoyardenkindern",
+    "savoyardin",
+    "savoyen",
+    "savoyens",
+    "savoyische",
+    "savoyischen",
+    "saß",
+    "saßen",
+    "schabernack",
+    "schabracken",
+    "schach",
+    "schachtel",
+    "schachteln",
+    "schad",
+    "schaddai",
+    "schaddais",
+    "schade",
+    "schaden",
+    "schadenfreude",
+    "schadenfrohem",
+    "schadet",
+    "schadete",
+    "schadhaft",
+    "schadlos",
+    "schaf",
+    "schafe",
+    "schafen",
+    "schafes",
+    "schaff",
+    "schaffen",
+    "schaffl",
+    "schafft",
+    "schaffte",
+    "schafherde",
+    "schafhirt",
+    "schafotte",
+    "schafsköpf",
+    "schafspelz",
+    "schaft",
+    "schakale",
+    "schal",
+    "schale",
+    "schalen",
+    "schalkhafter",
+    "schalkheit",
+    "schalksblick",
+    "schall",
+    "schallen",
+    "schallenden",
+    "schallplatte",
+    "schalt",
+    "schalter",
+    "schalterraum",
+    "schalterschluß",
+    "schaltete",
+    "scham",
+    "schamhaft",
+    "schamlosigkeit",
+    "schand",
+    "schande",
+    "schar",
+    "scharen",
+    "scharf",
+    "scharfe",
+    "scharfen",
+    "scharfer",
+    "scharfes",
+    "scharfsichtig",
+    "scharfsinn",
+    "scharfsinnig",
+    "scharfsinnige",
+    "scharfsinniger",
+    "scharfsinnigkeit",
+    "scharlach",
+    "scharlachuniform",
+    "scharren",
+    "scharrten",
+    "scharten",
+    "schatten",
+    "schattenbilder",
+    "schattendunklen",
+    "schattenreichen",
+    "schattens",
+    "schattenseite",
+    "schattige",
+    "schattigen",
+    "schattiger",
+    "schatz",
+    "schatze",
+    "schatzerl",
+    "schatzgräber",
+    "schatzhause",
+    "schatzhäuser",
+    "schatzhäusern",
+    "schatzkästlein",
+    "schatzung",
+    "schau",
+    "schaubrote",
+    "schaubroten",
+    "schaubühne",
+    "schauder",
+    "schauderhaft",
+    "schauderhaften",
+    "schaudern",
+    "schaudert",
+    "schaue",
+    "schauen",
+    "schauer",
+    "schauergeschichten",
+    "schauerlich",
+    "schauerlichen",
+    "schauerte",
+    "schauet",
+    "schaukelpferd",
+    "schaukelpferdchen",
+    "schaukelte",
+    "schaukelten",
+    "schaum",
+    "schaumigen",
+    "schauplatz",
+    "schauplatze",
+    "schaurig",
+    "schauspiel",
+    "schauspiele",
+    "schauspieler",
+    "schauspielerin",
+    "schauspielern",
+    "schauspielkunst",
+    "schauspielschule",
+    "schaust",
+    "schaustellung",
+    "schaustellungen",
+    "schaut",
+    "schautanz",
+    "schaute",
+    "schauten",
+    "scheba",
+    "schebas",
+    "scheelen",
+    "scheht",
+    "scheibe",
+    "scheiben",
+    "scheibenberaubten",
+    "scheibenstand",
+    "scheide",
+    "scheidebrief",
+    "scheidekünstler",
+    "scheiden",
+    "scheidendes",
+    "scheidet",
+    "scheidewege",
+    "scheidung",
+    "scheidungsritual",
+    "schein",
+    "scheinbar",
+    "scheinbare",
+    "scheinbarem",
+    "scheinbaren",
+    "scheinbarer",
+    "scheinbarere",
+    "scheinbares",
+    "scheinbarkeiten",
+    "scheine",
+    "scheinen",
+    "scheinende",
+    "scheinendes",
+    "scheines",
+    "scheinet",
+    "scheinfürsten",
+    "scheingründe",
+    "scheingutem",
+    "scheinheilig",
+    "scheins",
+    "scheint",
+    "scheintoten",
+    "scheinverkehr",
+    "scheinwahrem",
+    "scheit",
+    "scheitel",
+    "scheiterhaufen",
+    "scheiterte",
+    "scheißkasten",
+    "schelach",
+    "schellenklang",
+    "schellenspiel",
+    "schellte",
+    "schelm",
+    "schelme",
+    "schelmenkind",
+    "schelmische",
+    "scheloniten",
+    "schelten",
+    "scheltende",
+    "scheltens",
+    "schem",
+    "schemata",
+    "schemate",
+    "schemel",
+    "schemelchen",
+    "schemen",
+    "schenk",
+    "schenke",
+    "schenkeln",
+    "schenkeläufer",
+    "schenken",
+    "schenkst",
+    "schenkstuben",
+    "schenkte",
+    "schenkwirtes",
+    "schenkwirtschaft",
+    "scherben",
+    "schere",
+    "scherz",
+    "scherze",
+    "scherzen",
+    "scherzend",
+    "scherzhafte",
+    "scherzhafterweise",
+    "scherzreden",
+    "scheth",
+    "scheths",
+    "scheu",
+    "scheuchen",
+    "scheuchte",
+    "scheue",
+    "scheuen",
+    "scheuer",
+    "scheuernden",
+    "scheuert",
+    "scheuerte",
+    "scheuete",
+    "scheunchen",
+    "scheune",
+    "scheunenböden",
+    "scheunentor",
+    "scheusale",
+    "scheusslich",
+    "scheusslichen",
+    "scheut",
+    "scheuten",
+    "scheußlichen",
+    "schichors",
+    "schichten",
+    "schick",
+    "schicken",
+    "schickende",
+    "schicklich",
+    "schicklichen",
+    "schicklicher",
+    "schicksal",
+    "schicksalhaft",
+    "schicksals",
+    "schicksalsrufe",
+    "schicksalswendungen",
+    "schickt",
+    "schickte",
+    "schickten",
+    "schiebend",
+    "schiebt",
+    "schiechen",
+    "schied",
+    "schieden",
+    "schief",
+    "schiefe",
+    "schiefer",
+    "schiefertafeln",
+    "schiefes",
+    "schielen",
+    "schielten",
+    "schieläugigen",
+    "schien",
+    "schiene",
+    "schienen",
+    "schier",
+    "schiess",
+    "schiessbaumwolle",
+    "schiessbaumwollpatrone",
+    "schiessen",
+    "schiessend",
+    "schiessenden",
+    "schiessn",
+    "schiessprügel",
+    "schiesst",
+    "schießen",
+    "schiff",
+    "schiffbaren",
+    "schiffe",
+    "schiffen",
+    "schiffer",
+    "schiffern",
+    "schiffleute",
+    "schiffshauptmann",
+    "schiffsleuten",
+    "schiffslände",
+    "schild",
+    "schilde",
+    "schildern",
+    "schildert",
+    "schilderten",
+    "schilderung",
+    "schildkröte",
+    "schildkrötenbouillon",
+    "schildkrötschalen",
+    "schildpattgewölbe",
+    "schildpattkämme",
+    "schilf",
+    "schilfkeulen",
+    "schilfmeer",
+    "schilfmeeres",
+    "schilfrohr",
+    "schilfrohrs",
+    "schilfs",
+    "schilfstöckchen",
+    "schilfwiesen",
+    "schillernden",
+    "schillers",
+    "schimeon",
+    "schimeons",
+    "schimmel",
+    "schimmer",
+    "schimmern",
+    "schimmernd",
+    "schimmernde",
+    "schimmernden",
+    "schimmernder",
+    "schimmert",
+    "schimmerte",
+    "schimmerten",
+    "schimpf",
+    "schimpfen",
+    "schimpfendes",
+    "schimpflichen",
+    "schimpfworten",
+    "schindanger",
+    "schinde",
+    "schindeldach",
+    "schinden",
+    "schinken",
+    "schiphra",
+    "schirm",
+    "schirren",
+    "schirrl",
+    "schla",
+    "schlacht",
+    "schlachte",
+    "schlachten",
+    "schlachtheeren",
+    "schlachtopfer",
+    "schlachtopfern",
+    "schlachtung",
+    "schlaf",
+    "schlafe",
+    "schlafen",
+    "schlafend",
+    "schlafende",
+    "schlafenden",
+    "schlafender",
+    "schlafes",
+    "schlaffen",
+    "schlafgefährten",
+    "schlafkammer",
+    "schlafkammern",
+    "schlafkappen",
+    "schlaflied",
+    "schlaflose",
+    "schlafloser",
+    "schlaflust",
+    "schlafrock",
+    "schlafrockzipfel",
+    "schlaftrunken",
+    "schlafwandel",
+    "schlafzimmer",
+    "schlag",
+    "schlage",
+    "schlagen",
+    "schlagend",
+    "schlager",
+    "schlages",
+    "schlaget",
+    "schlagfertig",
+    "schlagn",
+    "schlagsahne",
+    "schlagsahnenkegel",
+    "schlagschatten",
+    "schlang",
+    "schlange",
+    "schlangen",
+    "schlangengifte",
+    "schlangenstirn",
+    "schlank",
+    "schlankaufgerichtet",
+    "schlanke",
+    "schlankem",
+    "schlanken",
+    "schlanker",
+    "schlankere",
+    "schlankes",
+    "schlankgewachsenes",
+    "schlankheit",
+    "schlappen",
+    "schlappschuhen",
+    "schlau",
+    "schlauchboot",
+    "schlaucherl",
+    "schlaue",
+    "schlauen",
+    "schlauer",
+    "schlaues",
+    "schlauheit",
+    "schlauheiten",
+    "schlecht",
+    "schlechte",
+    "schlechtem",
+    "schlechten",
+    "schlechter",
+    "schlechterdings",
+    "schlechteren",
+    "schlechteres",
+    "schlechtes",
+    "schlechteste",
+    "schlechtesten",
+    "schlechthin",
+    "schlechtigkeit",
+    "schlechtigkeitsmesser",
+    "schlechtweg",
+    "schlecken",
+    "schleckerfritze",
+    "schlegel",
+    "schlegelschuss",
+    "schleiche",
+    "schleichen",
+    "schleichenden",
+    "schleicher",
+    "schleichst",
+    "schleicht",
+    "schleier",
+    "schleifen",
+    "schleifte",
+    "schleiften",
+    "schlemmen",
+    "schlemmer",
+    "schlendernd",
+    "schlenderte",
+    "schlenkerte",
+    "schleppe",
+    "schleppen",
+    "schleppenden",
+    "schlepptau",
+    "schleppte",
+    "schleppten",
+    "schlesier",
+    "schleswig",
+    "schleuder",
+    "schleudern",
+    "schleuderte",
+    "schleunig",
+    "schleunigst",
+    "schleuse",
+    "schleusen",
+    "schlich",
+    "schlichen",
+    "schlicht",
+    "schlichte",
+    "schlichten",
+    "schlichtesten",
+    "schlichtet",
+    "schlickversteckens",
+    "schlief",
+    "schliefen",
+    "schliess",
+    "schliesse",
+    "schliessen",
+    "schliessend",
+    "schliesslich",
+    "schliesslichen",
+    "schliesst",
+    "schließ",
+    "schließen",
+    "schließlich",
+    "schließlichen",
+    "schließt",
+    "schlimm",
+    "schlimme",
+    "schlimmen",
+    "schlimmer",
+    "schlimmere",
+    "schlimmeres",
+    "schlimmern",
+    "schlimmes",
+    "schlimmste",
+    "schlimmsten",
+    "schlinge",
+    "schlingen",
+    "schlingt",
+    "schlitten",
+    "schlittenbahn",
+    "schlittenfahrt",
+    "schlittens",
+    "schlittenzierat",
+    "schlittenzug",
+    "schlohweissseidenen",
+    "schlohweißseidenen",
+    "schloss",
+    "schlosse",
+    "schlossen",
+    "schlosses",
+    "schlossgesellschaft",
+    "schlosshof",
+    "schlossstall",
+    "schlossstil",
+    "schlosstor",
+    "schlottrig",
+    "schlottrige",
+    "schloß",
+    "schloßgesellschaft",
+    "schloßhof",
+    "schloßstall",
+    "schloßstil",
+    "schloßtor",
+    "schlucht",
+    "schluchten",
+    "schluchzend",
+    "schluchzte",
+    "schluchzten",
+    "schluck",
+    "schluckend",
+    "schluckte",
+    "schlug",
+    "schlugen",
+    "schlummerlosen",
+    "schlummertrunkenen",
+    "schlund",
+    "schlupfwinkel",
+    "schlupfwinkeln",
+    "schlurfen",
+    "schluss",
+    "schlussabrechnung",
+    "schlusse",
+    "schlusses",
+    "schlussfolgerungen",
+    "schlussform",
+    "schlusshandlung",
+    "schlusspunkt",
+    "schlusspunkte",
+    "schlusssatze",
+    "schluß",
+    "schlußabrechnung",
+    "schlußfolgerungen",
+    "schlußform",
+    "schlußpunkt",
+    "schlußpunkte",
+    "schläfe",
+    "schläfen",
+    "schläfrig",
+    "schläfst",
+    "schläft",
+    "schläg",
+    "schläge",
+    "schlägen",
+    "schlägst",
+    "schlägt",
+    "schlängeln",
+    "schlängelte",
+    "schlänglein",
+    "schlösser",
+    "schlücklein",
+    "schlüpfen",
+    "schlüpferig",
+    "schlüpft",
+    "schlüpfte",
+    "schlüpften",
+    "schlürft",
+    "schlüsse",
+    "schlüssel",
+    "schlüsselblumen",
+    "schlüsselloch",
+    "schlüssen",
+    "schlüssig",
+    "schmach",
+    "schmachten",
+    "schmachtend",
+    "schmachtet",
+    "schmachtete",
+    "schmal",
+    "schmale",
+    "schmalem",
+    "schmalen",
+    "schmaler",
+    "schmales",
+    "schmatzend",
+    "schmatzte",
+    "schmauchend",
+    "schmaucht",
+    "schmauchte",
+    "schmeckt",
+    "schmeckte",
+    "schmeckten",
+    "schmeichel",
+    "schmeichelei",
+    "schmeichelhaft",
+    "schmeichelhafter",
+    "schmeicheln",
+    "schmeichelnder",
+    "schmeichelt",
+    "schmeichelte",
+    "schmeichelten",
+    "schmeißt",
+    "schmelzend",
+    "schmelzende",
+    "schmelzer",
+    "schmelzers",
+    "schmelzt",
+    "schmer",
+    "schmerz",
+    "schmerze",
+    "schmerzen",
+    "schmerzens",
+    "schmerzes",
+    "schmerzhaft",
+    "schmerzhaften",
+    "schmerzhafter",
+    "schmerzhaftesten",
+    "schmerzlich",
+    "schmerzlichen",
+    "schmerzlicher",
+    "schmerzlos",
+    "schmerzlose",
+    "schmerzt",
+    "schmerzte",
+    "schmerzten",
+    "schmerzvollen",
+    "schmetterlinge",
+    "schmetternde",
+    "schmetternden",
+    "schmetterten",
+    "schmid",
+    "schmied",
+    "schmiedeberg",
+    "schmiegsam",
+    "schmiegt",
+    "schmiegte",
+    "schmiegten",
+    "schmierseife",
+    "schmierte",
+    "schmirgel",
+    "schmolle",
+    "schmollen",
+    "schmollend",
+    "schmollende",
+    "schmollenden",
+    "schmollendes",
+    "schmollens",
+    "schmoller",
+    "schmollerei",
+    "schmollkunst",
+    "schmollte",
+    "schmollwesen",
+    "schmolz",
+    "schmuck",
+    "schmucke",
+    "schmucken",
+    "schmucker",
+    "schmuckes",
+    "schmuckkämme",
+    "schmucks",
+    "schmunzeln",
+    "schmunzelnd",
+    "schmunzelte",
+    "schmutz",
+    "schmutzig",
+    "schmutzige",
+    "schmutzigem",
+    "schmutzigen",
+    "schmutziges",
+    "schmächtig",
+    "schmächtige",
+    "schmäher",
+    "schmählich",
+    "schmählichste",
+    "schmäler",
+    "schmücken",
+    "schmückte",
+    "schnabulieren",
+    "schnabulierte",
+    "schnallen",
+    "schnallte",
+    "schnalzenden",
+    "schnalzte",
+    "schnappen",
+    "schnappende",
+    "schnappte",
+    "schnappten",
+    "schnaps",
+    "schnarrte",
+    "schnaubte",
+    "schnaubten",
+    "schnaufen",
+    "schnaufend",
+    "schnaufender",
+    "schnaufte",
+    "schnauze",
+    "schnauzte",
+    "schnecken",
+    "schnee",
+    "schneeberge",
+    "schneebällchen",
+    "schneeflocken",
+    "schneegeschichten",
+    "schneegestöber",
+    "schneegestöbers",
+    "schneeglanz",
+    "schneegrube",
+    "schneegrubenwand",
+    "schneehaarige",
+    "schneeige",
+    "schneeiges",
+    "schneeliedem",
+    "schneelieder",
+    "schneemeer",
+    "schneesendungen",
+    "schneeweiss",
+    "schneeweisse",
+    "schneeweissem",
+    "schneeweissen",
+    "schneeweisser",
+    "schneeweisses",
+    "schneeweißen",
+    "schneide",
+    "schneiden",
+    "schneidend",
+    "schneidenden",
+    "schneider",
+    "schneiderberufe",
+    "schneiderblütchen",
+    "schneiderfestzug",
+    "schneidergesellen",
+    "schneidergruppen",
+    "schneiderlein",
+    "schneidermeister",
+    "schneidermeisters",
+    "schneidermässig",
+    "schneiders",
+    "schneidersleute",
+    "schneiderwelt",
+    "schneiderwesen",
+    "schneidest",
+    "schneidet",
+    "schneidigste",
+    "schneien",
+    "schneit",
+    "schneite",
+    "schnell",
+    "schnelle",
+    "schnellen",
+    "schneller",
+    "schnelles",
+    "schnelligkeit",
+    "schnellsten",
+    "schnellte",
+    "schnelläufer",
+    "schnepfe",
+    "schnepfen",
+    "schnepfengarn",
+    "schnepflein",
+    "schnepper",
+    "schneppers",
+    "schneuz",
+    "schneuzen",
+    "schnippchen",
+    "schnippel",
+    "schnippeln",
+    "schnippisch",
+    "schnitt",
+    "schnitthaaren",
+    "schnitzarbeit",
+    "schnitzbild",
+    "schnitzbildern",
+    "schnitzelten",
+    "schnitzereien",
+    "schnitzwerk",
+    "schnupfen",
+    "schnupfennase",
+    "schnupft",
+    "schnupftuch",
+    "schnuppengestöber",
+    "schnuppern",
+    "schnupperte",
+    "schnur",
+    "schnurr",
+    "schnurrbart",
+    "schnurrbärtchen",
+    "schnäbel",
+    "schnäbelchen",
+    "schnäpschen",
+    "schnäpse",
+    "schnäuzer",
+    "schnöde",
+    "schnöden",
+    "schnöder",
+    "schnödes",
+    "schnörkel",
+    "schnörkels",
+    "schnüffelnd",
+    "schnüffelte",
+    "schnürchen",
+    "schnüre",
+    "schnüren",
+    "schnürlein",
+    "schnürst",
+    "schnürte",
+    "schnürten",
+    "schob",
+    "schoben",
+    "schoham",
+    "schokolade",
+    "schokoladenplätzchen",
+    "schokoladenplätzchenkies",
+    "schokoladenwege",
+    "scholl",
+    "schollen",
+    "schon",
+    "schonen",
+    "schonende",
+    "schonn",
+    "schonung",
+    "schonungslos",
+    "schopenhauer",
+    "schopenhauers",
+    "schoppen",
+    "schorf",
+    "schornstein",
+    "schornsteinfeger",
+    "schoss",
+    "schosse",
+    "schossen",
+    "schoten",
+    "schottischen",
+    "schoß",
+    "schoße",
+    "schrabenhauser",
+    "schrak",
+    "schrammelquartett",
+    "schrammen",
+    "schrank",
+    "schranken",
+    "schrankes",
+    "schrauben",
+    "schraubte",
+    "schreck",
+    "schreckbare",
+    "schreckbarste",
+    "schrecken",
+    "schreckens",
+    "schreckensherrschaft",
+    "schrecket",
+    "schrecklich",
+    "schreckliche",
+    "schrecklichen",
+    "schrecklicher",
+    "schreckmittel",
+    "schrei",
+    "schreibart",
+    "schreibe",
+    "schreiben",
+    "schreiber",
+    "schreibereien",
+    "schreibmaschinenfräulein",
+    "schreibpapier",
+    "schreibt",
+    "schreibtafel",
+    "schreibtisch",
+    "schreibtischs",
+    "schreibzeug",
+    "schreien",
+    "schreiend",
+    "schreiende",
+    "schreit",
+    "schreiten",
+    "schreitendes",
+    "schreitet",
+    "schrie",
+    "schrieb",
+    "schrieben",
+    "schrieen",
+    "schrift",
+    "schriften",
+    "schriftgelehrten",
+    "schriftgelehrter",
+    "schriftlich",
+    "schriftlicher",
+    "schriftsteller",
+    "schriftstellern",
+    "schriftstücke",
+    "schrill",
+    "schrille",
+    "schrillem",
+    "schrillend",
+    "schriller",
+    "schritt",
+    "schritte",
+    "schritten",
+    "schrittes",
+    "schrofen",
+    "schroffe",
+    "schroffes",
+    "schrotkörner",
+    "schrott",
+    "schräg",
+    "schräger",
+    "schränke",
+    "schröpfköpfe",
+    "schrörschnauzaschenbecher",
+    "schrörschnauzaschenbechern",
+    "schubert",
+    "schuberts",
+    "schublade",
+    "schubladen",
+    "schuchzend",
+    "schuf",
+    "schuft",
+    "schufte",
+    "schuh",
+    "schuhe",
+    "schuhen",
+    "schuhläden",
+    "schuhmacher",
+    "schuhnägeln",
+    "schuhsohlen",
+    "schul",
+    "schulbücher",
+    "schuld",
+    "schulden",
+    "schuldenschreiber",
+    "schuldenverkehrs",
+    "schuldenwesen",
+    "schuldig",
+    "schuldige",
+    "schuldigen",
+    "schuldigkeit",
+    "schuldlos",
+    "schuldlosen",
+    "schuldnern",
+    "schuldopfers",
+    "schuldscheinen",
+    "schuldverhältnis",
+    "schuldverschreibungen",
+    "schule",
+    "schulen",
+    "schulgehilfe",
+    "schulgehilfen",
+    "schulgerecht",
+    "schulgerechter",
+    "schulkind",
+    "schulknaben",
+    "schullehrer",
+    "schulmeister",
+    "schulmässig",
+    "schulmäßig",
+    "schulpflichtigen",
+    "schulstaub",
+    "schulten",
+    "schulter",
+    "schultern",
+    "schulterstücken",
+    "schultheisse",
+    "schuppen",
+    "schur",
+    "schurke",
+    "schuss",
+    "schusse",
+    "schusses",
+    "schusslinie",
+    "schussplatz",
+    "schuster",
+    "schusterten",
+    "schutte",
+    "schutz",
+    "schutzdienst",
+    "schutze",
+    "schutzschürze",
+    "schutzumschlag",
+    "schwaar",
+    "schwabbelgegend",
+    "schwabe",
+    "schwaben",
+    "schwach",
+    "schwache",
+    "schwachen",
+    "schwacher",
+    "schwaches",
+    "schwachheit",
+    "schwachheiten",
+    "schwachkopf",
+    "schwachköpfe",
+    "schwachnervigen",
+    "schwachsinnig",
+    "schwaden",
+    "schwalbennest",
+    "schwamm",
+    "schwammen",
+    "schwane",
+    "schwang",
+    "schwange",
+    "schwangeren",
+    "schwanken",
+    "schwankend",
+    "schwankende",
+    "schwankenden",
+    "schwankes",
+    "schwankhafte",
+    "schwankt",
+    "schwankte",
+    "schwankten",
+    "schwanz",
+    "schwanze",
+    "schwanzes",
+    "schwanzflosse",
+    "schwapp",
+    "schwartenhälse",
+    "schwarz",
+    "schwarzbrot",
+    "schwarze",
+    "schwarzem",
+    "schwarzen",
+    "schwarzer",
+    "schwarzes",
+    "schwarzgekleideter",
+    "schwarzgelbes",
+    "schwarzmeerküste",
+    "schwarzseidenes",
+    "schwarzwollene",
+    "schwatze",
+    "schwatzen",
+    "schwatzend",
+    "schwatzt",
+    "schwatzte",
+    "schwatzten",
+    "schwebe",
+    "schweben",
+    "schwebend",
+    "schwebt",
+    "schwebte",
+    "schwebten",
+    "schweden",
+    "schwedenkönigs",
+    "schwefel",
+    "schwefelduft",
+    "schwefelgelb",
+    "schwefelgelbe",
+    "schwefelgelbem",
+    "schwefelgestank",
+    "schwefelholz",
+    "schwefelhölzl",
+    "schwefelig",
+    "schwefels",
+    "schwefelstoff",
+    "schweifen",
+    "schweifte",
+    "schweig",
+    "schweige",
+    "schweigen",
+    "schweigend",
+    "schweigende",
+    "schweigenden",
+    "schweigsam",
+    "schweinchen",
+    "schweine",
+    "schweinerei",
+    "schweinestall",
+    "schweinigel",
+    "schweinsborste",
+    "schweiss",
+    "schweisse",
+    "schweisses",
+    "schweissfleckigen",
+    "schweisshund",
+    "schweisstriefend",
+    "schweiz",
+    "schweizer",
+    "schweizerdegen",
+    "schweizerische",
+    "schweizerischen",
+    "schweizerlande",
+    "schweizerorten",
+    "schweizerstadtkirche",
+    "schweizin",
+    "schweiß",
+    "schweiße",
+    "schweißes",
+    "schweißtropfen",
+    "schweißtröpfchen",
+    "schwelle",
+    "schwellt",
+    "schwellte",
+    "schwendling",
+    "schwenken",
+    "schwenkend",
+    "schwenkte",
+    "schwenkten",
+    "schwer",
+    "schwere",
+    "schwerem",
+    "schweren",
+    "schwerenöter",
+    "schwerer",
+    "schweres",
+    "schwerfällig",
+    "schwerfälliges",
+    "schwerfälligkeit",
+    "schwergeschmiedeten",
+    "schwerhörig",
+    "schwerlich",
+    "schwermut",
+    "schwermütig",
+    "schwermütigen",
+    "schwermütiges",
+    "schwert",
+    "schwerte",
+    "schwerter",
+    "schwertern",
+    "schwertes",
+    "schwertfeger",
+    "schwerts",
+    "schwerwiegende",
+    "schwester",
+    "schwesterchen",
+    "schwestern",
+    "schwieg",
+    "schwiegen",
+    "schwiegermutter",
+    "schwiegersohn",
+    "schwiegersöhne",
+    "schwiegertochter",
+    "schwiegertöchter",
+    "schwieligen",
+    "schwierig",
+    "schwierige",
+    "schwierigen",
+    "schwieriger",
+    "schwierigkeit",
+    "schwierigkeiten",
+    "schwierigsten",
+    "schwimmen",
+    "schwimmende",
+    "schwimmenden",
+    "schwindel",
+    "schwindelanfall",
+    "schwindelanfällen",
+    "schwinden",
+    "schwindet",
+    "schwindler",
+    "schwindlig",
+    "schwingen",
+    "schwingend",
+    "schwippte",
+    "schwirrte",
+    "schwitzen",
+    "schwitzte",
+    "schwitzten",
+    "schwoll",
+    "schwor",
+    "schwules",
+    "schwung",
+    "schwunge",
+    "schwupp",
+    "schwur",
+    "schwuren",
+    "schwäbchen",
+    "schwäbchens",
+    "schwäbische",
+    "schwäbischen",
+    "schwäblein",
+    "schwäche",
+    "schwächer",
+    "schwächere",
+    "schwächlichsten",
+    "schwächt",
+    "schwächte",
+    "schwäher",
+    "schwähers",
+    "schwänke",
+    "schwänzchen",
+    "schwänze",
+    "schwänzelnd",
+    "schwänzelte",
+    "schwänzen",
+    "schwänzlein",
+    "schwärme",
+    "schwärmen",
+    "schwärmende",
+    "schwärmenden",
+    "schwärmer",
+    "schwärmerei",
+    "schwärmerisches",
+    "schwärmern",
+    "schwärmte",
+    "schwärmten",
+    "schwärze",
+    "schwärzen",
+    "schwärzliche",
+    "schwärzlichen",
+    "schwärzt",
+    "schwärzte",
+    "schwätzer",
+    "schwätzerei",
+    "schwör",
+    "schwöre",
+    "schwören",
+    "schwört",
+    "schwül",
+    "schwüler",
+    "schwüles",
+    "schwüngen",
+    "schäbig",
+    "schäbige",
+    "schächtelchen",
+    "schädel",
+    "schädelplatte",
+    "schäden",
+    "schädlich",
+    "schäfchen",
+    "schäfchenhimmel",
+    "schäfchenstall",
+    "schäfer",
+    "schäferhunde",
+    "schäkernd",
+    "schäkerte",
+    "schälen",
+    "schäme",
+    "schämen",
+    "schämst",
+    "schämt",
+    "schämte",
+    "schämten",
+    "schändlich",
+    "schändliche",
+    "schärfe",
+    "schärfen",
+    "schärfer",
+    "schätzbarste",
+    "schätzchen",
+    "schätze",
+    "schätzen",
+    "schätzt",
+    "schätzte",
+    "schätzten",
+    "schäumend",
+    "schäumenden",
+    "schäumt",
+    "schön",
+    "schöne",
+    "schönem",
+    "schönen",
+    "schöner",
+    "schönere",
+    "schöneres",
+    "schönern",
+    "schöners",
+    "schönes",
+    "schöngebautes",
+    "schöngelegenes",
+    "schönheit",
+    "schöns",
+    "schönst",
+    "schönste",
+    "schönsten",
+    "schönstes",
+    "schöpfe",
+    "schöpfen",
+    "schöpfer",
+    "schöpft",
+    "schöpfte",
+    "schöpften",
+    "schöpfung",
+    "schöpfungstage",
+    "schöppchen",
+    "schöpsenfleisch",
+    "schüchtern",
+    "schüchterne",
+    "schüchternen",
+    "schüchternheit",
+    "schüler",
+    "schürze",
+    "schürzen",
+    "schürzenband",
+    "schürzte",
+    "schüsse",
+    "schüssel",
+    "schüsseln",
+    "schütteln",
+    "schüttelt",
+    "schüttelte",
+    "schüttelten",
+    "schütteren",
+    "schüttet",
+    "schüttete",
+    "schütteten",
+    "schütze",
+    "schützen",
+    "schützend",
+    "schützende",
+    "schützendem",
+    "schützengraben",
+    "schützenwaffe",
+    "schützer",
+    "schützt",
+    "schützte",
+    "schützten",
+    "scil",
+    "scotus",
+    "se",
+    "sebas",
+    "sebastian",
+    "sebulon",
+    "sechs",
+    "sechsfache",
+    "sechsmal",
+    "sechste",
+    "sechsten",
+    "sechster",
+    "sechstes",
+    "sechsunddreissig",
+    "sechsundsiebenzig",
+    "sechszehn",
+    "sechzehn",
+    "sechzehnjährigen",
+    "sechzehnten",
+    "sechzig",
+    "sechzigste",
+    "sed",
+    "seegrund",
+    "seehäfen",
+    "seejungfern",
+    "seekrank",
+    "seel",
+    "seele",
+    "seeleitner",
+    "seeleitnerhauses",
+    "seeleitnersbuben",
+    "seeleitnersleut",
+    "seeleitnersleute",
+    "seelen",
+    "seelenallein",
+    "seelenerhebung",
+    "seelengrösse",
+    "seelenkräfte",
+    "seelenkunde",
+    "seelenkämpfe",
+    "seelenleiden",
+    "seelenruhe",
+    "seelenschwäche",
+    "seelenstärke",
+    "seelenvergnügt",
+    "seelenvoll",
+    "seelenvolle",
+    "seeleute",
+    "seelon",
+    "seelsorge",
+    "seespiegel",
+    "seetal",
+    "seetang",
+    "seetiere",
+    "seeufer",
+    "seeungeheuer",
+    "segel",
+    "segelboot",
+    "segelte",
+    "segen",
+    "segens",
+    "segensreiche",
+    "segensspruch",
+    "segn",
+    "segne",
+    "segnen",
+    "segnender",
+    "segnet",
+    "segnete",
+    "segnungen",
+    "seh",
+    "sehe",
+    "sehen",
+    "sehend",
+    "sehende",
+    "sehenden",
+    "sehendie",
+    "sehenswürdigkeiten",
+    "sehest",
+    "sehet",
+    "sehn",
+    "sehnen",
+    "sehnippel",
+    "sehnlich",
+    "sehnsucht",
+    "sehnsuchten",
+    "sehnsüchtig",
+    "sehnsüchtige",
+    "sehnsüchtiger",
+    "sehnsüchtiges",
+    "sehnte",
+    "sehnten",
+    "sehr",
+    "seht",
+    "sehvermögen",
+    "sei",
+    "seicht",
+    "seichte",
+    "seichten",
+    "seichter",
+    "seichtigkeit",
+    "seid",
+    "seide",
+    "seiden",
+    "seidenartig",
+    "seidendamast",
+    "seidene",
+    "seidenen",
+    "seidenes",
+    "seidenfarben",
+    "seidenfäden",
+    "seidengewand",
+    "seidenkleid",
+    "seidens",
+    "seidenzeug",
+    "seider",
+    "seien",
+    "seiest",
+    "seife",
+    "seifenblasen",
+    "seifengalerie",
+    "seifenschaum",
+    "seifensieder",
+    "seifenstücken",
+    "seigneur",
+    "seile",
+    "sein",
+    "seine",
+    "seinem",
+    "seinen",
+    "seiner",
+    "seinerseits",
+    "seinerzeit",
+    "seines",
+    "seinesgleichen",
+    "seinetwegen",
+    "seinige",
+    "seinigen",
+    "seins",
+    "seir",
+    "seist",
+    "seit",
+    "seitdem",
+    "seite",
+    "seiten",
+    "seitenblick",
+    "seiteneingang",
+    "seitengang",
+    "seitengasse",
+    "seitenplätzchen",
+    "seitenschiff",
+    "seitenschiffe",
+    "seitenstrassen",
+    "seitenstück",
+    "seitental",
+    "seitentälern",
+    "seitenwegen",
+    "seither",
+    "seitlich",
+    "seitwärts",
+    "sek",
+    "sekelbewahrer",
+    "sekretariat",
+    "sekretärin",
+    "sekten",
+    "sektierern",
+    "sekunde",
+    "sekunden",
+    "sekundenlange",
+    "sekundenzeiger",
+    "sel",
+    "selbe",
+    "selbem",
+    "selben",
+    "selber",
+    "selbige",
+    "selbigen",
+    "selbiger",
+    "selbiges",
+    "selbigsmal",
+    "selbst",
+    "selbstanklagen",
+    "selbstbeherrschung",
+    "selbstberedung",
+    "selbstberedungen",
+    "selbstbewussten",
+    "selbstbewusstsein",
+    "selbstentäusserung",
+    "selbsterkenntnis",
+    "selbsterniedrigung",
+    "selbstgefälligkeit",
+    "selbstgemacht",
+    "selbstgenugsamkeit",
+    "selbstgerechtigkeit",
+    "selbstgeschaffenem",
+    "selbstgewählten",
+    "selbstgezogen",
+    "selbstherrlichkeit",
+    "selbstigen",
+    "selbstliebe",
+    "selbstmord",
+    "selbstprüfung",
+    "selbstregierung",
+    "selbstschätzung",
+    "selbstsucht",
+    "selbstsüchtige",
+    "selbsttätige",
+    "selbsttätigen",
+    "selbstverantwortung",
+    "selbstverdammung",
+    "selbstvergessen",
+    "selbstvergessenheit",
+    "selbstvergnügten",
+    "selbstverherrlichung",
+    "selbstverständlich",
+    "selbstverständlichen",
+    "selbstvertrauen",
+    "selbstverwalteten",
+    "selbstzufrieden",
+    "selbstzufriedenheit",
+    "selbständig",
+    "selbständige",
+    "selbständigen",
+    "selbständigkeit",
+    "seldwyl",
+    "seldwyla",
+    "seldwyler",
+    "seldwylerinnen",
+    "seldwylern",
+    "seldwylers",
+    "seldwylertagen",
+    "selig",
+    "selige",
+    "seligem",
+    "seligen",
+    "seliges",
+    "seligkeit",
+    "seligkeiten",
+    "seligmachende",
+    "seligmachenden",
+    "seligmachung",
+    "seligwerden",
+    "selk",
+    "selm",
+    "selten",
+    "seltene",
+    "seltenen",
+    "seltener",
+    "seltenheit",
+    "seltensten",
+    "seltsam",
+    "seltsame",
+    "seltsamem",
+    "seltsamen",
+    "seltsamer",
+    "seltsamerweise",
+    "seltsames",
+    "seltsamkeit",
+    "seltsamlich",
+    "sem",
+    "semen",
+    "semester",
+    "semmelbröseln",
+    "sende",
+    "sendeminute",
+    "senden",
+    "sendest",
+    "sendet",
+    "sendlinge",
+    "sendung",
+    "sendungen",
+    "senf",
+    "senfkorn",
+    "senke",
+    "senken",
+    "senkend",
+    "senket",
+    "senkrecht",
+    "senkt",
+    "senkte",
+    "senkten",
+    "senkung",
+    "sennerin",
+    "sennhütte",
+    "sensatio",
+    "sentimentale",
+    "september",
+    "septembergegend",
+    "septembermorgen",
+    "sequentium",
+    "serach",
+    "serie",
+    "serpentum",
+    "servieren",
+    "serviert",
+    "servierter",
+    "serviette",
+    "servietten",
+    "sessel",
+    "sesselchen",
+    "sesselkleber",
+    "sessellehne",
+    "sesshaft",
+    "sesshaften",
+    "settember",
+    "setz",
+    "setze",
+    "setzen",
+    "setzet",
+    "setzt",
+    "setzte",
+    "setzten",
+    "seu",
+    "seuchengefahr",
+    "seufzen",
+    "seufzend",
+    "seufzens",
+    "seufzer",
+    "seufzern",
+    "seufzt",
+    "seufzte",
+    "seufzten",
+    "sexologie",
+    "sexuelle",
+    "shakespeare",
+    "shoa",
+    "shylocks",
+    "si",
+    "sialiquid",
+    "sich",
+    "sichbearbeiten",
+    "sicheln",
+    "sicher",
+    "sichere",
+    "sicheren",
+    "sicherer",
+    "sicheres",
+    "sichergehenden",
+    "sicherheit",
+    "sicherheitsbeamten",
+    "sicherheitsbehörden",
+    "sicherheitshalber",
+    "sicherheitskontrolle",
+    "sicherheitskräfte",
+    "sicherheitskräften",
+    "sicherheitsleute",
+    "sicherheitspolizei",
+    "sicherheitsregeln",
+    "sicherheitsstation",
+    "sicherlich",
+    "sichern",
+    "sicherste",
+    "sichersten",
+    "sicherte",
+    "sicherung",
+    "sichniederwerfen",
+    "sichri",
+    "sichs",
+    "sicht",
+    "sichtbar",
+    "sichtbare",
+    "sichtbaren",
+    "sichtende",
+    "sichtlich",
+    "sichtlichen",
+    "sichtlicher",
+    "sichtweite",
+    "sickert",
+    "sicut",
+    "sie",
+    "sieben",
+    "siebenfach",
+    "siebenfältig",
+    "siebenhundert",
+    "siebenköpfigen",
+    "siebenmal",
+    "siebensachen",
+    "siebensächelchen",
+    "siebente",
+    "siebenten",
+    "siebenter",
+    "siebentes",
+    "siebenundachtzig",
+    "siebenundsiebzig",
+    "siebenundwanzig",
+    "siebenundzwanzig",
+    "siebenundzwanzigmal",
+    "siebenundzwanzigmalige",
+    "siebenzahl",
+    "siebenzig",
+    "siebenzigmal",
+    "siebsten",
+    "siebte",
+    "siebzehn",
+    "siebzehnten",
+    "siebzig",
+    "siebziger",
+    "siebzigmal",
+    "siech",
+    "siede",
+    "siedeln",
+    "siedende",
+    "siedendheiss",
+    "sieg",
+    "siege",
+    "siegel",
+    "siegellack",
+    "siegeln",
+    "siegelring",
+    "siegen",
+    "siegende",
+    "sieger",
+    "sieges",
+    "sieghafte",
+    "sieghaften",
+    "siegte",
+    "sieh",
+    "siehe",
+    "siehst",
+    "sieht",
+    "siena",
+    "sige",
+    "silbe",
+    "silber",
+    "silberbechern",
+    "silberborten",
+    "silberbschlagene",
+    "silberbügel",
+    "silbergewölk",
+    "silberglöckchen",
+    "silbergrauen",
+    "silberhaarige",
+    "silberhelle",
+    "silberkleidern",
+    "silberlicht",
+    "silbermesser",
+    "silberne",
+    "silbernem",
+    "silbernen",
+    "silberner",
+    "silbernes",
+    "silberreinen",
+    "silbers",
+    "silbersand",
+    "silberschleiern",
+    "silberschüssel",
+    "silberseidigsten",
+    "silberstimmchen",
+    "silberwolle",
+    "silbrige",
+    "siloam",
+    "simei",
+    "similitudo",
+    "simmsen",
+    "simpel",
+    "simsen",
+    "simson",
+    "simus",
+    "sinai",
+    "sind",
+    "sine",
+    "sing",
+    "singbuch",
+    "singe",
+    "singen",
+    "singend",
+    "singende",
+    "singenden",
+    "singender",
+    "singerei",
+    "singet",
+    "singst",
+    "singt",
+    "sinken",
+    "sinkende",
+    "sinkendem",
+    "sinkenden",
+    "sinkens",
+    "sinkt",
+    "sinn",
+    "sinnbild",
+    "sinnbilder",
+    "sinnbildern",
+    "sinne",
+    "sinnen",
+    "sinnend",
+    "sinnenwelt",
+    "sinnenwesens",
+    "sinnes",
+    "sinnesart",
+    "sinnesarten",
+    "sinnestäuschungen",
+    "sinnesvorstellungen",
+    "sinneswahrnehmung",
+    "sinnet",
+    "sinngebung",
+    "sinniert",
+    "sinnig",
+    "sinnige",
+    "sinnlich",
+    "sinnlichbedingten",
+    "sinnliche",
+    "sinnlichem",
+    "sinnlichen",
+    "sinnlicher",
+    "sinnliches",
+    "sinnlichkeit",
+    "sinnlos",
+    "sinnlose",
+    "sinnlosem",
+    "sinnloser",
+    "sinnloses",
+    "sinnlosigkeit",
+    "sinnorganen",
+    "sinnorganes",
+    "sinnreich",
+    "sinnreiche",
+    "sinnreichen",
+    "sinns",
+    "sinnstiftung",
+    "sinnt",
+    "sinnverwirrter",
+    "sinnvoll",
+    "sinnvolle",
+    "sinnvollere",
+    "sinnwidrigen",
+    "sinnzusammenhang",
+    "sirnak",
+    "sirrr",
+    "sirrrrrrr",
+    "sithri",
+    "sitte",
+    "sitten",
+    "sittengesetz",
+    "sittengesetze",
+    "sittengesetzes",
+    "sittenlehre",
+    "sittig",
+    "sittlich",
+    "sittliche",
+    "sittlichen",
+    "sittlicher",
+    "sittlichguten",
+    "sittlichkeit",
+    "sittsam",
+    "sittsamkeit",
+    "situationen",
+    "sitz",
+    "sitze",
+    "sitzen",
+    "sitzende",
+    "sitzerei",
+    "sitzest",
+    "sitzgegend",
+    "sitzgelegenheit",
+    "sitzplatz",
+    "sitzt",
+    "sitzung",
+    "sitzungssaal",
+    "sitzungstagen",
+    "sitzungszimmer",
+    "sitzungszimmers",
+    "skeptisch",
+    "skeptizismus",
+    "skf",
+    "skizziert",
+    "sklave",
+    "skorpionen",
+    "skribenten",
+    "skrupel",
+    "slangs",
+    "smaragd",
+    "smidt",
+    "smyrna",
+    "smyrnateppich",
+    "so",
+    "sobald",
+    "social",
+    "sociantis",
+    "socinianer",
+    "socken",
+    "sodalitia",
+    "sodann",
+    "sodass",
+    "sodom",
+    "sodoms",
+    "soeben",
+    "sofa",
+    "sofern",
+    "sofort",
+    "sofortige",
+    "sog",
+    "sogar",
+    "sogen",
+    "sogenannte",
+    "sogenannten",
+    "sogenannter",
+    "sogenanntes",
+    "sogleich",
+    "sohle",
+    "sohlen",
+    "sohn",
+    "sohne",
+    "sohnes",
+    "sohnessohn",
+    "sokrates",
+    "solang",
+    "solange",
+    "solch",
+    "solche",
+    "solchem",
+    "solchen",
+    "solchenen",
+    "solcher",
+    "solcherart",
+    "solchergestalt",
+    "solcherlei",
+    "solcherweise",
+    "solches",
+    "sold",
+    "soldat",
+    "soldaten",
+    "soldatengesicht",
+    "soldatenhaufen",
+    "soldatenlebens",
+    "soldatenrock",
+    "soldatentum",
+    "soldateska",
+    "solde",
+    "soli",
+    "solidarische",
+    "solidarisierung",
+    "solidaritässtiftung",
+    "solidarität",
+    "solidaritätsbeiträge",
+    "solidaritätsfonds",
+    "solidaritätsstiftung",
+    "soliden",
+    "solider",
+    "solipsismus",
+    "soll",
+    "solle",
+    "sollen",
+    "sollet",
+    "sollst",
+    "sollt",
+    "sollte",
+    "sollten",
+    "solltest",
+    "somit",
+    "sommer",
+    "sommerabend",
+    "sommerbier",
+    "sommerbieres",
+    "sommerferien",
+    "sommerfrische",
+    "sommerhell",
+    "sommerhirsch",
+    "sommerhitz",
+    "sommerkleid",
+    "sommernachmittag",
+    "sommernacht",
+    "sommerrehe",
+    "sommerruhe",
+    "sommers",
+    "sommersaison",
+    "sommersonne",
+    "sommerspass",
+    "sommerspaß",
+    "sommerszeit",
+    "sommertag",
+    "sommertage",
+    "sommervergnügen",
+    "sonach",
+    "sonderbar",
+    "sonderbare",
+    "sonderbaren",
+    "sonderbarer",
+    "sonderbarerweise",
+    "sonderbares",
+    "sonderbarste",
+    "sonderbarsten",
+    "sonderlich",
+    "sonderliche",
+    "sonderlichen",
+    "sonderliches",
+    "sonderling",
+    "sondern",
+    "sonders",
+    "sonderschau",
+    "sonderweg",
+    "sonn",
+    "sonnbeschienen",
+    "sonne",
+    "sonnen",
+    "sonnenartig",
+    "sonnenauf",
+    "sonnenaufgang",
+    "sonnenberg",
+    "sonnenblick",
+    "sonnenbrand",
+    "sonnenbraunes",
+    "sonnenbrille",
+    "sonnenfeuer",
+    "sonnenglut",
+    "sonnenhelle",
+    "sonnenhöhe",
+    "sonnenland",
+    "sonnenlicht",
+    "sonnenluft",
+    "sonnennebel",
+    "sonnennebeln",
+    "sonnenringel",
+    "sonnenschein",
+    "sonnenscheine",
+    "sonnenschirmchen",
+    "sonnenstrahl",
+    "sonnenstrahlen",
+    "sonnenuhr",
+    "sonnenuntergang",
+    "sonnenwärme",
+    "sonngebräunten",
+    "sonnige",
+    "sonnigen",
+    "sonniger",
+    "sonnigsten",
+    "sonntag",
+    "sonntagabend",
+    "sonntage",
+    "sonntagen",
+    "sonntagmorgen",
+    "sonntagnachmittag",
+    "sonntagnachmittags",
+    "sonntags",
+    "sonntagskleid",
+    "sonntagskleide",
+    "sonntagsrock",
+    "sonntagsschlafrock",
+    "sonntagsschuhe",
+    "sonntagsstaat",
+    "sonntagsstille",
+    "sonntagvormittag",
+    "sonnte",
+    "sonntäglich",
+    "sonntäglichen",
+    "sono",
+    "sonst",
+    "sonstige",
+    "sonstigen",
+    "sonstwie",
+    "sonstwo",
+    "sony",
+    "sooft",
+    "sopha",
+    "sophienstrasse",
+    "sophismata",
+    "sophisterei",
+    "sophisticis",
+    "sophistik",
+    "sophistische",
+    "sophistischen",
+    "sorge",
+    "sorgen",
+    "sorgenbefreite",
+    "sorgende",
+    "sorgenlos",
+    "sorgenvolle",
+    "sorgfalt",
+    "sorgfältig",
+    "sorgfältigste",
+    "sorgfältigsten",
+    "sorglich",
+    "sorglicher",
+    "sorglos",
+    "sorglosem",
+    "sorgloser",
+    "sorglosigkeit",
+    "sorgsam",
+    "sorgte",
+    "sorte",
+    "sosehr",
+    "soubrette",
+    "soundso",
+    "souper",
+    "sous",
+    "souterrain",
+    "souverän",
+    "soviel",
+    "sowas",
+    "soweit",
+    "sowenig",
+    "sowie",
+    "sowieso",
+    "sowohl",
+    "sozialarbeit",
+    "soziale",
+    "sozialen",
+    "soziales",
+    "sozialhilfe",
+    "sozialisten",
+    "sozialsystem",
+    "sozinianern",
+    "sozusagen",
+    "spalt",
+    "spalten",
+    "spaltet",
+    "spaltung",
+    "spanien",
+    "spaniern",
+    "spaniolen",
+    "spanische",
+    "spann",
+    "spanne",
+    "spannende",
+    "spannender",
+    "spannt",
+    "spannte",
+    "spannten",
+    "spannung",
+    "spannungen",
+    "spannungsgeladen",
+    "spannungsvolle",
+    "sparbüchse",
+    "spare",
+    "sparen",
+    "spargel",
+    "sparkasse",
+    "sparpfennig",
+    "sparsam",
+    "sparsame",
+    "sparsamer",
+    "sparsamkeit",
+    "sparte",
+    "spass",
+    "spasse",
+    "spasshaft",
+    "spasshafte",
+    "spasshaften",
+    "spassig",
+    "spassiges",
+    "spatzen",
+    "spazier",
+    "spazieren",
+    "spazierengeflogen",
+    "spazierengehen",
+    "spazierenzugehen",
+    "spazierest",
+    "spaziergang",
+    "spaziergange",
+    "spaziergänger",
+    "spazierstock",
+    "spazierstöckchen",
+    "spazierstöckl",
+    "spazierte",
+    "spaß",
+    "spe",
+    "spechtes",
+    "speciem",
+    "species",
+    "speck",
+    "speckriemchen",
+    "speckseite",
+    "spediteure",
+    "speeres",
+    "speichel",
+    "speichern",
+    "speise",
+    "speisedüfte",
+    "speisen",
+    "speisesaal",
+    "speiset",
+    "speisete",
+    "speisetisch",
+    "speisewagen",
+    "speisezettel",
+    "speisopfer",
+    "speisopfern",
+    "speiste",
+    "spektakel",
+    "spektrum",
+    "spekulant",
+    "spekulanten",
+    "spekulation",
+    "spekulationsbetätigung",
+    "spekulativ",
+    "spekulative",
+    "spekulativen",
+    "spekulativer",
+    "spekulatives",
+    "spekulierten",
+    "spelt",
+    "spelts",
+    "spelunke",
+    "spelunkelein",
+    "spenden",
+    "spendierte",
+    "spenser",
+    "sperling",
+    "sperlinge",
+    "sperrmäuler",
+    "sperrte",
+    "sperrten",
+    "spezereien",
+    "spezialfonds",
+    "speziellen",
+    "spezielles",
+    "spezies",
+    "spezifisch",
+    "sphäre",
+    "sphären",
+    "spieen",
+    "spiegel",
+    "spiegelbild",
+    "spiegelchen",
+    "spiegelein",
+    "spiegels",
+    "spiegelt",
+    "spiegelte",
+    "spiegelwände",
+    "spiel",
+    "spielchen",
+    "spiele",
+    "spielen",
+    "spielend",
+    "spielenden",
+    "spielerei",
+    "spielet",
+    "spielgelde",
+    "spielgesellen",
+    "spielkameraden",
+    "spielkugel",
+    "spielraum",
+    "spielregeln",
+    "spielschulden",
+    "spielstätte",
+    "spielt",
+    "spielte",
+    "spielten",
+    "spieltisch",
+    "spielwerke",
+    "spielzeug",
+    "spielzeuglandschaft",
+    "spielzeugmaikäfer",
+    "spielzeugvögelchen",
+    "spielzeugwaffen",
+    "spiess",
+    "spiesse",
+    "spiessen",
+    "spinne",
+    "spinnen",
+    "spinnenlied",
+    "spinnerei",
+    "spinneweben",
+    "spinnrocken",
+    "spinnrädchen",
+    "spinnweben",
+    "spion",
+    "spiraculim",
+    "spirieren",
+    "spirituale",
+    "spiritualis",
+    "spiritus",
+    "spiritusfeuer",
+    "spitakl",
+    "spital",
+    "spittel",
+    "spitting",
+    "spitz",
+    "spitzbart",
+    "spitzbauch",
+    "spitzbube",
+    "spitzbuben",
+    "spitze",
+    "spitzen",
+    "spitzenkandidat",
+    "spitzes",
+    "spitzfindigkeiten",
+    "spitzgras",
+    "spitzig",
+    "spitzige",
+    "spitzigen",
+    "spitzigsten",
+    "spitzte",
+    "splitternackt",
+    "sponton",
+    "sporen",
+    "spornstreichs",
+    "spornte",
+    "spott",
+    "spotten",
+    "spottet",
+    "spottete",
+    "spottgeburten",
+    "spottlaus",
+    "spottlust",
+    "spp",
+    "sprach",
+    "sprache",
+    "sprachen",
+    "sprachgebrauche",
+    "sprachigen",
+    "sprachlehre",
+    "sprachrohr",
+    "sprachst",
+    "sprangen",
+    "spreche",
+    "sprechen",
+    "sprechend",
+    "sprechenden",
+    "sprechender",
+    "sprechens",
+    "sprecht",
+    "sprechzeit",
+    "spree",
+    "spreemetropole",
+    "spreizen",
+    "spreizte",
+    "sprengen",
+    "sprengkapseln",
+    "sprengt",
+    "sprengte",
+    "spreu",
+    "sprich",
+    "sprichst",
+    "spricht",
+    "sprichwort",
+    "sprichwortes",
+    "sprichwörter",
+    "springen",
+    "springer",
+    "springt",
+    "spritze",
+    "spritzen",
+    "spross",
+    "sprosse",
+    "sprossen",
+    "sprossend",
+    "sprossenden",
+    "sprosste",
+    "sproß",
+    "sproßte",
+    "spruch",
+    "spruches",
+    "spruchversen",
+    "spruchweise",
+    "sprudel",
+    "sprudelflasche",
+    "sprung",
+    "sprungbrett",
+    "sprunge",
+    "spräche",
+    "sprächest",
+    "spröde",
+    "sprödeste",
+    "sprödigkeit",
+    "sprösslinge",
+    "sprößlinge",
+    "sprüche",
+    "sprüchlein",
+    "sprühende",
+    "sprünge",
+    "sprüngen",
+    "spucke",
+    "spuckte",
+    "spuk",
+    "spur",
+    "spuren",
+    "sputet",
+    "sputete",
+    "spähen",
+    "spähend",
+    "spähete",
+    "spähte",
+    "späne",
+    "spärliche",
+    "spärlichen",
+    "spässe",
+    "spät",
+    "späten",
+    "später",
+    "spätere",
+    "späterhin",
+    "spätesten",
+    "spätestens",
+    "spätregen",
+    "späße",
+    "spötter",
+    "spöttisch",
+    "spöttische",
+    "spöttischem",
+    "spöttischen",
+    "spülte",
+    "spür",
+    "spürbar",
+    "spüren",
+    "spürte",
+    "squat",
+    "sr",
+    "st",
+    "staat",
+    "staate",
+    "staaten",
+    "staates",
+    "staatlich",
+    "staatliche",
+    "staatlicher",
+    "staatlicherseits",
+    "staatsanwalt",
+    "staatsbürgerbewußtsein",
+    "staatskirche",
+    "staatskirchen",
+    "staatskleid",
+    "staatsknete",
+    "staatsprüfungen",
+    "staatsschuld",
+    "staatssicherheits",
+    "staatsverschuldung",
+    "staatswesen",
+    "stab",
+    "stabe",
+    "stabes",
+    "stabil",
+    "stabilisierend",
+    "stabilisierende",
+    "stabsoffiziere",
+    "stach",
+    "stachel",
+    "stachelgesträuch",
+    "stachelgewächs",
+    "stacheln",
+    "stachelstrauch",
+    "stachen",
+    "stachlige",
+    "stachligen",
+    "stad",
+    "stadien",
+    "stadium",
+    "stadt",
+    "stadtfrau",
+    "stadtgesetze",
+    "stadthexenmeister",
+    "stadtmauer",
+    "stadtname",
+    "stadtpfarrer",
+    "stadtrand",
+    "stadtschreiber",
+    "stadtschule",
+    "stadtteil",
+    "stadtviertel",
+    "stadtvierteln",
+    "stadtwohnung",
+    "stadtzürcher",
+    "staffiert",
+    "stagnation",
+    "stahl",
+    "stahlblauen",
+    "stak",
+    "staketen",
+    "stall",
+    "stalle",
+    "stallkette",
+    "stallknechte",
+    "stalltür",
+    "stalsett",
+    "stamina",
+    "stamm",
+    "stamme",
+    "stammelte",
+    "stammen",
+    "stammende",
+    "stammenden",
+    "stammlokal",
+    "stammschlosses",
+    "stammt",
+    "stammtafel",
+    "stammte",
+    "stammten",
+    "stammtischfeierlichkeit",
+    "stammvaters",
+    "stammväter",
+    "stampfe",
+    "stampfen",
+    "stampfens",
+    "stande",
+    "standen",
+    "standenes",
+    "standhalten",
+    "standort",
+    "standpunkt",
+    "standpunkte",
+    "standrecht",
+    "stange",
+    "stangen",
+    "stank",
+    "stapften",
+    "starb",
+    "starben",
+    "stark",
+    "starke",
+    "starkem",
+    "starken",
+    "starker",
+    "starkes",
+    "starnberg",
+    "starnberger",
+    "starr",
+    "starre",
+    "starren",
+    "starrkopf",
+    "starrte",
+    "starrten",
+    "starten",
+    "statdanzeiger",
+    "statistik",
+    "statistisch",
+    "statt",
+    "stattdessen",
+    "statten",
+    "stattfand",
+    "stattfanden",
+    "stattfinde",
+    "stattfinden",
+    "stattfindenden",
+    "stattfindet",
+    "stattfände",
+    "stattgefunden",
+    "statthatte",
+    "stattlich",
+    "stattliche",
+    "stattlichen",
+    "stattliches",
+    "stattlichkeit",
+    "statue",
+    "statuta",
+    "statutum",
+    "staub",
+    "staube",
+    "staubes",
+    "staubhemden",
+    "staubigen",
+    "staubte",
+    "staubwolke",
+    "staude",
+    "stauden",
+    "staune",
+    "staunen",
+    "staunend",
+    "staunte",
+    "stechen",
+    "stechend",
+    "stechender",
+    "stechpalme",
+    "stecke",
+    "stecken",
+    "steckenblieb",
+    "steckend",
+    "steckenden",
+    "steckengeblieben",
+    "steckleinspringer",
+    "stecknadel",
+    "stecknadelaugen",
+    "steckt",
+    "steckte",
+    "steckten",
+    "steg",
+    "stege",
+    "stegemann",
+    "stegreif",
+    "steh",
+    "stehe",
+    "stehen",
+    "stehenbleiben",
+    "stehenbleibend",
+    "stehenbleibens",
+    "stehenbleibt",
+    "stehenblieb",
+    "stehend",
+    "stehenden",
+    "stehender",
+    "stehengeblieben",
+    "stehenliess",
+    "stehens",
+    "stehest",
+    "stehet",
+    "stehle",
+    "stehlen",
+    "stehn",
+    "stehnblieben",
+    "stehst",
+    "steht",
+    "steif",
+    "steifbeinig",
+    "steife",
+    "steifen",
+    "steifendem",
+    "steifer",
+    "steig",
+    "steigbügel",
+    "steige",
+    "steigen",
+    "steigend",
+    "steigende",
+    "steigern",
+    "steigerte",
+    "steigerung",
+    "steiget",
+    "steigt",
+    "steil",
+    "steile",
+    "steilen",
+    "stein",
+    "steinbeschwerte",
+    "steinblöcke",
+    "steinboden",
+    "steinbruch",
+    "steinbruches",
+    "steinbrüche",
+    "steinchen",
+    "steindinge",
+    "steine",
+    "steineiche",
+    "steinen",
+    "steinerne",
+    "steinernen",
+    "steinernes",
+    "steinfleckes",
+    "steingeröll",
+    "steingraue",
+    "steinhaufen",
+    "steinige",
+    "steinigen",
+    "steinigens",
+    "steiniges",
+    "steinigung",
+    "steinkamm",
+    "steinklotz",
+    "steinlagern",
+    "steinlasten",
+    "steinmasse",
+    "steinpieper",
+    "steinpiepers",
+    "steinrand",
+    "steins",
+    "steinwerk",
+    "stell",
+    "stelle",
+    "stelledie",
+    "stellen",
+    "stelleninhaber",
+    "stellenleiters",
+    "stellt",
+    "stellte",
+    "stellten",
+    "stellung",
+    "stellungen",
+    "stellungnahmen",
+    "stellvertretende",
+    "stellvertreter",
+    "stellvertreters",
+    "stelzten",
+    "stemmte",
+    "stempel",
+    "stemraketen",
+    "sten",
+    "stengel",
+    "steppen",
+    "sterbe",
+    "sterbebegleiterdie",
+    "sterbebegleitungs",
+    "sterbebette",
+    "sterbeforscherin",
+    "sterbeforschung",
+    "sterben",
+    "sterbend",
+    "sterbenden",
+    "sterbendes",
+    "sterbenswörtchen",
+    "sterbet",
+    "sterblichen",
+    "sterblicher",
+    "sterbn",
+    "sterbstund",
+    "stern",
+    "sternbild",
+    "sternbilder",
+    "sternblumen",
+    "sternblumenklee",
+    "sternblumenwiese",
+    "sternchen",
+    "sternchenkuss",
+    "sterndeutung",
+    "sterne",
+    "sternen",
+    "sternenbilde",
+    "sternenhimmel",
+    "sternenkinder",
+    "sternenkindern",
+    "sternenmädchen",
+    "sternenmädchens",
+    "sternenschule",
+    "sternenstühlchen",
+    "sternenwiese",
+    "sternlein",
+    "sternpünktchen",
+    "sternschnuppen",
+    "sternschnuppengemüse",
+    "sternschnuppenwolke",
+    "sterzenbacher",
+    "stete",
+    "steten",
+    "stetig",
+    "stetige",
+    "stets",
+    "stett",
+    "steuer",
+    "steuerausfalls",
+    "steuerbeleg",
+    "steuerhinterziehung",
+    "steuern",
+    "steuersünder",
+    "steuert",
+    "steuerte",
+    "steward",
+    "steyrerhut",
+    "steyrerhute",
+    "stibitzt",
+    "stich",
+    "sticheln",
+    "stickerei",
+    "stickstoffgehaltes",
+    "stickstoffhaltige",
+    "stiebten",
+    "stiefel",
+    "stiefeln",
+    "stiefelsohlen",
+    "stiefmütterlich",
+    "stieg",
+    "stiegen",
+    "stiehlt",
+    "stiel",
+    "stielen",
+    "stier",
+    "stiere",
+    "stierer",
+    "stierte",
+    "stiess",
+    "stiessen",
+    "stieß",
+    "stießen",
+    "stiften",
+    "stifter",
+    "stiftern",
+    "stiftsdamen",
+    "stiftshütte",
+    "stiftung",
+    "stiftungen",
+    "stigma",
+    "stil",
+    "stile",
+    "stiles",
+    "stillblaue",
+    "stille",
+    "stillen",
+    "stiller",
+    "stilles",
+    "stillgewordenen",
+    "stillhalten",
+    "stillhielt",
+    "stillschweigend",
+    "stillschweigende",
+    "stillstand",
+    "stillständer",
+    "stimmabgabe",
+    "stimmberechtigten",
+    "stimmbezirke",
+    "stimmchen",
+    "stimme",
+    "stimmen",
+    "stimmenzähler",
+    "stimmenzählern",
+    "stimmfähig",
+    "stimmlisten",
+    "stimmt",
+    "stimmte",
+    "stimmten",
+    "stimmung",
+    "stimmungen",
+    "stimmzettel",
+    "stingel",
+    "stinke",
+    "stinken",
+    "stinkend",
+    "stinkenden",
+    "stinkige",
+    "stinkt",
+    "stirb",
+    "stirbt",
+    "stirn",
+    "stirnband",
+    "stirne",
+    "stirnen",
+    "stob",
+    "stoben",
+    "stocherte",
+    "stocke",
+    "stockender",
+    "stockfinsteren",
+    "stocksteife",
+    "stockte",
+    "stockten",
+    "stockungeheuer",
+    "stockwerk",
+    "stockwerke",
+    "stockwerkes",
+    "stockwerks",
+    "stockzähnen",
+    "stoff",
+    "stoffe",
+    "stoiker",
+    "stoikern",
+    "stoische",
+    "stoischer",
+    "stollen",
+    "stollenkleider",
+    "stolperte",
+    "stolperten",
+    "stolz",
+    "stolze",
+    "stolzen",
+    "stolzer",
+    "stolzes",
+    "stolzieren",
+    "stolzierten",
+    "stonsdorfer",
+    "stopfte",
+    "stoppelbart",
+    "stoppeln",
+    "storb",
+    "storren",
+    "stoss",
+    "stossen",
+    "stossend",
+    "stotternder",
+    "stotterte",
+    "stoß",
+    "stoßen",
+    "strack",
+    "stracks",
+    "strafe",
+    "strafen",
+    "straff",
+    "strafgerichte",
+    "strafgerichtes",
+    "strafgesetzes",
+    "strafkolonie",
+    "strafpredigt",
+    "strafprozess",
+    "strafprozeß",
+    "straft",
+    "strafte",
+    "strafwürdig",
+    "strafwürdigen",
+    "strafübel",
+    "strahl",
+    "strahle",
+    "strahlen",
+    "strahlend",
+    "strahlenden",
+    "strahlender",
+    "strahlenkrone",
+    "strahlenkronen",
+    "strahlt",
+    "strahlte",
+    "strahlten",
+    "stramen",
+    "straminis",
+    "stramm",
+    "strampampelt",
+    "strampelei",
+    "strampelte",
+    "strampelten",
+    "strapazierst",
+    "strapinski",
+    "strapinskis",
+    "strass",
+    "strassburg",
+    "strasse",
+    "strassen",
+    "strassenecke",
+    "strassenfestes",
+    "strassengraben",
+    "strassenkörper",
+    "strassenmann",
+    "strassenniveau",
+    "strassenrand",
+    "strassenschlachten",
+    "strassenseite",
+    "strassenverkehr",
+    "strassl",
+    "stratagemata",
+    "strategischen",
+    "strauch",
+    "straucheln",
+    "strauchelt",
+    "strauchwitz",
+    "strauss",
+    "strausse",
+    "straße",
+    "straßen",
+    "straßenbahn",
+    "straßenecke",
+    "straßenniveau",
+    "straßenseite",
+    "straßenverkehr",
+    "streben",
+    "strebens",
+    "strebsamer",
+    "strebt",
+    "strebte",
+    "strebten",
+    "strecke",
+    "strecken",
+    "streckt",
+    "streckte",
+    "streckten",
+    "streich",
+    "streiche",
+    "streicheln",
+    "streichelt",
+    "streichelte",
+    "streichelten",
+    "streichen",
+    "streichholz",
+    "streicht",
+    "streif",
+    "streifen",
+    "streift",
+    "streifte",
+    "streiften",
+    "streifzug",
+    "streifzügen",
+    "streit",
+    "streiten",
+    "streitende",
+    "streitenden",
+    "streiter",
+    "streites",
+    "streitet",
+    "streitige",
+    "streitigen",
+    "streitigkeiten",
+    "streits",
+    "streng",
+    "strenge",
+    "strengem",
+    "strengen",
+    "strenger",
+    "strenges",
+    "strenggenommen",
+    "strenggeschnittenen",
+    "strengste",
+    "strengsten",
+    "streue",
+    "streuen",
+    "streut",
+    "streute",
+    "streßgeplagt",
+    "strich",
+    "strichen",
+    "striches",
+    "strichlein",
+    "strichweise",
+    "strick",
+    "stricke",
+    "stricken",
+    "stricklein",
+    "strickmaschine",
+    "strickschlingen",
+    "strickzeug",
+    "stritten",
+    "strittigen",
+    "stroh",
+    "strohhut",
+    "strohhüte",
+    "strohköpfe",
+    "strohpuppe",
+    "strohs",
+    "strolche",
+    "strom",
+    "stromaufwärts",
+    "strome",
+    "stromes",
+    "stroms",
+    "strophe",
+    "strotzende",
+    "strub",
+    "strudel",
+    "strukturen",
+    "strukturwandlungen",
+    "strumpf",
+    "strumpfe",
+    "strumpfes",
+    "strunk",
+    "strychnin",
+    "strychninbrocken",
+    "strähnig",
+    "strässchen",
+    "sträubte",
+    "sträucher",
+    "sträuchern",
+    "sträusserl",
+    "ströme",
+    "strömen",
+    "strömt",
+    "strömte",
+    "strömten",
+    "strömung",
+    "strümpfe",
+    "strümpfen",
+    "stube",
+    "stuben",
+    "stubenreinheit",
+    "stubenschlüssel",
+    "stubentür",
+    "stubentüre",
+    "studen",
+    "student",
+    "studenten",
+    "studentenmütze",
+    "studentin",
+    "studie",
+    "studien",
+    "studieren",
+    "studiert",
+    "studierte",
+    "studierten",
+    "studios",
+    "studium",
+    "stufe",
+    "stufen",
+    "stufenweise",
+    "stuhl",
+    "stuhlbeine",
+    "stuhle",
+    "stuhles",
+    "stukkaturarbeiten",
+    "stumm",
+    "stumme",
+    "stummen",
+    "stummer",
+    "stumpf",
+    "stumpfbesen",
+    "stumpfheit",
+    "stumpfnäschen",
+    "stumpfsinn",
+    "stumpfsinnig",
+    "stumpfsinniges",
+    "stund",
+    "stunde",
+    "stunden",
+    "stundenlang",
+    "stundenlange",
+    "stundenlangen",
+    "stundenweit",
+    "stundenzeiger",
+    "stupender",
+    "sturm",
+    "sturmflockenwirbel",
+    "sturmhusche",
+    "sturmriese",
+    "sturmriesen",
+    "sturmwind",
+    "sturz",
+    "sturze",
+    "stuttgart",
+    "stuttgarter",
+    "stutzen",
+    "stutzerhaft",
+    "stutzte",
+    "stäbchen",
+    "stäbe",
+    "stäben",
+    "städtchen",
+    "städtchens",
+    "städte",
+    "städten",
+    "städter",
+    "städtisch",
+    "städtischen",
+    "städtlein",
+    "stählen",
+    "stäken",
+    "ställe",
+    "stämmchen",
+    "stämme",
+    "stämmen",
+    "ständchen",
+    "stände",
+    "ständerat",
+    "ständig",
+    "ständige",
+    "stänker",
+    "stärger",
+    "stärke",
+    "stärken",
+    "stärker",
+    "stärkere",
+    "stärkeren",
+    "stärkern",
+    "stärkest",
+    "stärkste",
+    "stärksten",
+    "stärkte",
+    "stärkung",
+    "stätte",
+    "stäubchen",
+    "stöberte",
+    "stöckchen",
+    "stöcke",
+    "stöcken",
+    "stöckerte",
+    "stöhnend",
+    "stöhnte",
+    "störche",
+    "störe",
+    "stören",
+    "störend",
+    "störenden",
+    "störenfried",
+    "störenfriedes",
+    "störrig",
+    "stört",
+    "störte",
+    "störung",
+    "störungen",
+    "stössel",
+    "stösst",
+    "stößt",
+    "stübchen",
+    "stübchens",
+    "stück",
+    "stückchen",
+    "stücke",
+    "stücken",
+    "stücklein",
+    "stücks",
+    "stühlchen",
+    "stühle",
+    "stühlen",
+    "stümperhaft",
+    "stündchen",
+    "stünde",
+    "stündel",
+    "stünden",
+    "stündleins",
+    "stündlich",
+    "stürme",
+    "stürmen",
+    "stürmisch",
+    "stürmischer",
+    "stürmte",
+    "stürmten",
+    "stürze",
+    "stürzen",
+    "stürzet",
+    "stürzt",
+    "stürzte",
+    "stürzten",
+    "stütze",
+    "stützen",
+    "stützend",
+    "stützt",
+    "stützte",
+    "stützten",
+    "subjectis",
+    "subjekt",
+    "subjekte",
+    "subjekten",
+    "subjektiv",
+    "subjektive",
+    "subjektiven",
+    "subjektiver",
+    "subjekts",
+    "subkultur",
+    "sublimsten",
+    "subreptionis",
+    "substantielle",
+    "substantiellen",
+    "substanz",
+    "substanzen",
+    "subsumtion",
+    "subtil",
+    "subtile",
+    "subtilen",
+    "subtiler",
+    "subtilste",
+    "successiva",
+    "suche",
+    "suchen",
+    "suchenden",
+    "suchet",
+    "suchst",
+    "sucht",
+    "suchtbereitschaft",
+    "suchte",
+    "suchten",
+    "suchtforscher",
+    "suchtmittelkonsum",
+    "sultan",
+    "summ",
+    "summarisch",
+    "summe",
+    "summen",
+    "summende",
+    "summte",
+    "sumpfig",
+    "sumpfigen",
+    "sumpflöcher",
+    "sumsemann",
+    "sumsemanns",
+    "sumsemännchen",
+    "sumsemänner",
+    "sumsemännern",
+    "sumsiselsei",
+    "sunntäg",
+    "sunt",
+    "superlativ",
+    "superstition",
+    "suph",
+    "suppe",
+    "suppenkelle",
+    "suppentopf",
+    "supremum",
+    "surfen",
+    "surfstation",
+    "suspendierten",
+    "suyu",
+    "sweetheart",
+    "swissaid",
+    "swissair",
+    "sykomoren",
+    "sylvia",
+    "symbola",
+    "symbole",
+    "symmetrie",
+    "sympathielage",
+    "sympathien",
+    "sympathiestränge",
+    "sympathische",
+    "sympathischen",
+    "sympathischer",
+    "symposium",
+    "synkretistischen",
+    "synodalrat",
+    "synodalrätin",
+    "synode",
+    "synonyme",
+    "synthesis",
+    "synthetisch",
+    "synthetische",
+    "synthetischen",
+    "synthetischer",
+    "syrien",
+    "syriens",
+    "systematisch",
+    "systematische",
+    "systematischen",
+    "systemen",
+    "sz",
+    "szene",
+    "säbel",
+    "säbelhieben",
+    "säbels",
+    "sächsische",
+    "sächsischen",
+    "säckchen",
+    "säcke",
+    "säcken",
+    "säet",
+    "sägespäneleib",
+    "sägt",
+    "sähe",
+    "sähen",
+    "sähet",
+    "säkular",
+    "säle",
+    "sämann",
+    "sämtlich",
+    "sämtliche",
+    "sämtlichen",
+    "sämtlicher",
+    "sänfte",
+    "sänften",
+    "sänge",
+    "sängen",
+    "sänger",
+    "sängerin",
+    "sänke",
+    "särgen",
+    "sässe",
+    "sässen",
+    "sät",
+    "säte",
+    "sätteln",
+    "sättigen",
+    "sättigst",
+    "sättigung",
+    "sätze",
+    "sätzen",
+    "säuberlich",
+    "säubernde",
+    "säuberte",
+    "säubrer",
+    "säugamme",
+    "säugammen",
+    "säuge",
+    "säugen",
+    "säugende",
+    "säugenden",
+    "säugendes",
+    "säugerin",
+    "säugling",
+    "säuglinge",
+    "säuglings",
+    "säugt",
+    "säugte",
+    "säugung",
+    "säule",
+    "säulen",
+    "säulengängen",
+    "säume",
+    "säumend",
+    "säumet",
+    "säumnis",
+    "säumte",
+    "säuseln",
+    "säuselte",
+    "sö",
+    "söhnchen",
+    "söhne",
+    "söhnen",
+    "söhnlein",
+    "söller",
+    "süchtig",
+    "süd",
+    "süditalien",
+    "südliches",
+    "südlondon",
+    "südseite",
+    "südwestfunk",
+    "südwärts",
+    "sühnen",
+    "sülzpastete",
+    "sümmchen",
+    "sünde",
+    "sünden",
+    "sündenlose",
+    "sünder",
+    "sündflut",
+    "sündhafte",
+    "sündigen",
+    "sündigt",
+    "süss",
+    "süsse",
+    "süssem",
+    "süssen",
+    "süsser",
+    "süsses",
+    "süsseste",
+    "süssigkeit",
+    "süssigkeiten",
+    "süsslich",
+    "süß",
+    "süße",
+    "süßen",
+    "t",
+    "ta",
+    "tabak",
+    "tabaks",
+    "tabaksqualme",
+    "tabaksteig",
+    "tadel",
+    "tadelloser",
+    "tadeln",
+    "tadelswürdig",
+    "tadelte",
+    "tafel",
+    "tafeldecker",
+    "tafeldeckern",
+    "tafelgeschenke",
+    "tafeln",
+    "tafelseite",
+    "tafeltuch",
+    "taferlklass",
+    "taft",
+    "tage",
+    "tagelang",
+    "tagelohn",
+    "tagelöhner",
+    "tagelöhnermädchen",
+    "tagen",
+    "tagereisen",
+    "tages",
+    "tagesanbruch",
+    "tagesanzeiger",
+    "tagesgäste",
+    "tageshelle",
+    "tageskasse",
+    "tageslauf",
+    "tageslicht",
+    "tagesordnung",
+    "tageszeiten",
+    "tagewerk",
+    "tagi",
+    "taglicht",
+    "taglöhners",
+    "tagreisen",
+    "tagträumen",
+    "tagtäglich",
+    "taiga",
+    "takt",
+    "takte",
+    "taktik",
+    "taktischen",
+    "tal",
+    "talar",
+    "talent",
+    "taler",
+    "talern",
+    "talerstücke",
+    "tales",
+    "talglichten",
+    "talgrunde",
+    "talhängen",
+    "tals",
+    "talwärts",
+    "tambour",
+    "tamburin",
+    "tan",
+    "tanne",
+    "tannebaum",
+    "tannen",
+    "tannenbusch",
+    "tannenbäumchen",
+    "tannenen",
+    "tannengestrüpp",
+    "tannengetäfels",
+    "tannenhähers",
+    "tannenwäldchen",
+    "tante",
+    "tanz",
+    "tanzbein",
+    "tanze",
+    "tanzen",
+    "tanzende",
+    "tanzenden",
+    "tanzendes",
+    "tanzmusik",
+    "tanzplatz",
+    "tanzsaal",
+    "tanzsaales",
+    "tanzschritten",
+    "tanzt",
+    "tanzte",
+    "tanzten",
+    "tapet",
+    "tapete",
+    "tapfer",
+    "tapfere",
+    "tapferen",
+    "tapferes",
+    "tapferkeit",
+    "tapfern",
+    "tappen",
+    "tapper",
+    "tappte",
+    "tasche",
+    "taschen",
+    "taschengeld",
+    "taschentuch",
+    "taschentüchern",
+    "taschenuhr",
+    "tasse",
+    "tassen",
+    "tasten",
+    "tasteten",
+    "tat",
+    "taten",
+    "tatendrang",
+    "tatendranges",
+    "tatendurst",
+    "tatkraft",
+    "tatkräftige",
+    "tatkräftiges",
+    "tatlust",
+    "tatsache",
+    "tatsachen",
+    "tatsächlich",
+    "tatsächlichen",
+    "tatwirklichkeiten",
+    "tatzen",
+    "tau",
+    "taub",
+    "taube",
+    "tauben",
+    "tauchen",
+    "taucht",
+    "tauchte",
+    "tauchten",
+    "taufe",
+    "taufen",
+    "taufnamen",
+    "taufschein",
+    "taufstein",
+    "taufzettel",
+    "taugen",
+    "taugenichts",
+    "tauglich",
+    "taugliche",
+    "tauglichen",
+    "tauglicheres",
+    "tauglichkeit",
+    "tauglichsten",
+    "taugt",
+    "tauige",
+    "tauigen",
+    "taumariechen",
+    "taumariechens",
+    "taumeln",
+    "taumelnd",
+    "taumelnder",
+    "taumelte",
+    "taunassen",
+    "tauschen",
+    "tauschwitz",
+    "tausee",
+    "tausees",
+    "tausend",
+    "tausende",
+    "tausenden",
+    "tausendfachem",
+    "tausendfältigen",
+    "tausendkünstlern",
+    "tausendmal",
+    "tausendsgeschichten",
+    "tausendskerl",
+    "tausendskind",
+    "tausendsmensch",
+    "tausendste",
+    "tausig",
+    "taverne",
+    "tax",
+    "taxen",
+    "taxi",
+    "taxifahrer",
+    "technicus",
+    "techniker",
+    "technisch",
+    "technischen",
+    "technisches",
+    "techno",
+    "teckel",
+    "tecnh",
+    "teebeutel",
+    "teekanne",
+    "teelöffel",
+    "tees",
+    "teich",
+    "teiche",
+    "teifi",
+    "teig",
+    "teigs",
+    "teil",
+    "teile",
+    "teilen",
+    "teilete",
+    "teilhaben",
+    "teilhaft",
+    "teilhaftig",
+    "teilnahm",
+    "teilnahme",
+    "teilnahmlos",
+    "teilnehmen",
+    "teilnehmende",
+    "teilnehmer",
+    "teilnehmung",
+    "teilnimmt",
+    "teils",
+    "teilt",
+    "teilte",
+    "teilten",
+    "teilung",
+    "teilweise",
+    "teilzunehmen",
+    "tel",
+    "telefon",
+    "telefonanrufe",
+    "telefone",
+    "telefonen",
+    "telefonhörer",
+    "telefoniert",
+    "telefonierte",
+    "telefonisch",
+    "telegrafisch",
+    "telegramm",
+    "telegraphisch",
+    "telepathische",
+    "telephonieren",
+    "telephonierten",
+    "telephonisch",
+    "telephonische",
+    "telephonischen",
+    "telephonischer",
+    "teller",
+    "tellerleckerei",
+    "tellers",
+    "tempel",
+    "tempels",
+    "temperatur",
+    "tempora",
+    "tendenz",
+    "tendenzen",
+    "tennen",
+    "tenöre",
+    "teppich",
+    "teppichartigen",
+    "teppiche",
+    "teppichen",
+    "teppichweber",
+    "termin",
+    "terminantur",
+    "terminus",
+    "terra",
+    "terrasse",
+    "terrassen",
+    "territorium",
+    "terroristisch",
+    "terroristischen",
+    "terroristischer",
+    "tertium",
+    "tessiner",
+    "testament",
+    "testamentarisch",
+    "testaments",
+    "teuer",
+    "teueren",
+    "teuerste",
+    "teufel",
+    "teufels",
+    "teufelsbrücke",
+    "teufelsgeduld",
+    "teufelshexlein",
+    "teufelshörner",
+    "teufelskünste",
+    "teufelsreitern",
+    "teuflisch",
+    "teuflische",
+    "teuflischen",
+    "teure",
+    "texas",
+    "thachpanches",
+    "thamar",
+    "tharschisch",
+    "theater",
+    "theaterbillet",
+    "theatergruppe",
+    "theaterkarten",
+    "theaterprinzessin",
+    "theaterprospekt",
+    "theaterszene",
+    "theke",
+    "thema",
+    "thematisiert",
+    "themen",
+    "themse",
+    "theodor",
+    "theologe",
+    "theologen",
+    "theologie",
+    "theologin",
+    "theologinnen",
+    "theologisch",
+    "theologische",
+    "theologischen",
+    "theophrastos",
+    "theoretisch",
+    "theoretische",
+    "theoretischen",
+    "theoretischer",
+    "theoretisches",
+    "theorie",
+    "theorien",
+    "theosophen",
+    "theosophische",
+    "theresa",
+    "thesen",
+    "thesis",
+    "thiel",
+    "thron",
+    "throne",
+    "thronen",
+    "thrones",
+    "throns",
+    "thronstufen",
+    "thront",
+    "thronte",
+    "thubal",
+    "thubalkain",
+    "thubalkains",
+    "thurgau",
+    "thv",
+    "ti",
+    "tief",
+    "tiefblauem",
+    "tiefblauen",
+    "tiefe",
+    "tiefem",
+    "tiefen",
+    "tiefer",
+    "tiefere",
+    "tieferen",
+    "tiefern",
+    "tiefes",
+    "tiefpunkt",
+    "tiefschwarzes",
+    "tiefsinnig",
+    "tiefsinnigen",
+    "tiefsinniges",
+    "tiefste",
+    "tiefsten",
+    "tiefster",
+    "tieftönenden",
+    "tierarzt",
+    "tierchen",
+    "tiere",
+    "tieren",
+    "tieres",
+    "tierfabel",
+    "tiergattungen",
+    "tierheit",
+    "tierische",
+    "tierischen",
+    "tierklaue",
+    "tierlebens",
+    "tierlein",
+    "tiernatur",
+    "tiersamen",
+    "tierschutzverein",
+    "tierseele",
+    "tierwelt",
+    "tiger",
+    "tigern",
+    "tiiid",
+    "tilgen",
+    "tinte",
+    "tinten",
+    "tintenflaschen",
+    "tintenstrichen",
+    "tippte",
+    "tirilieren",
+    "tirilierte",
+    "tisch",
+    "tischbeins",
+    "tischchen",
+    "tische",
+    "tischen",
+    "tisches",
+    "tischgebet",
+    "tischlade",
+    "tischler",
+    "tischplatte",
+    "tischtuch",
+    "tischwein",
+    "titel",
+    "titelblatt",
+    "titeln",
+    "titelt",
+    "tl",
+    "toaste",
+    "toasts",
+    "toben",
+    "tobende",
+    "tobenden",
+    "tobendes",
+    "tobias",
+    "tobt",
+    "tobte",
+    "tochter",
+    "tod",
+    "todbette",
+    "todbringend",
+    "tode",
+    "todes",
+    "todesangst",
+    "todesaussichten",
+    "todesfurcht",
+    "todeskampf",
+    "todeskandidaten",
+    "todesschattens",
+    "todesschrecken",
+    "todesschwadronen",
+    "todesstrafe",
+    "todesstrafen",
+    "todesstunde",
+    "todesurteil",
+    "todeswerkzeuge",
+    "todsünd",
+    "todsündigen",
+    "todvollste",
+    "toile",
+    "toilette",
+    "toilettenstücke",
+    "toilettenwerkzeug",
+    "tokaier",
+    "tol",
+    "toll",
+    "tolle",
+    "tollen",
+    "toller",
+    "tolleren",
+    "tolles",
+    "tollhaus",
+    "tollheit",
+    "tollhäusler",
+    "tollkühnen",
+    "tollste",
+    "tollsten",
+    "tollte",
+    "tolpatsch",
+    "tolpatschig",
+    "tombakschnalle",
+    "ton",
+    "tonart",
+    "tonbandschnippel",
+    "tonfall",
+    "tonfetzen",
+    "toni",
+    "tons",
+    "topas",
+    "topf",
+    "topfbodens",
+    "topfwagens",
+    "topheth",
+    "topi",
+    "topica",
+    "topicis",
+    "topik",
+    "topoi",
+    "topov",
+    "topus",
+    "tor",
+    "torbogen",
+    "tore",
+    "toreinfahrt",
+    "toren",
+    "torenwerk",
+    "tores",
+    "torgau",
+    "torheit",
+    "torheiten",
+    "torkelte",
+    "tornister",
+    "tort",
+    "torte",
+    "tosende",
+    "tot",
+    "totale",
+    "totalen",
+    "totalität",
+    "tote",
+    "toten",
+    "totenbahre",
+    "totenbleich",
+    "totengebeine",
+    "totenmusik",
+    "totenstill",
+    "toter",
+    "totes",
+    "totgemacht",
+    "totgeprügelt",
+    "totgeschlagen",
+    "totgestellt",
+    "totlachen",
+    "totmachen",
+    "totmachte",
+    "totschlag",
+    "totschlagen",
+    "totstellen",
+    "tottrat",
+    "touv",
+    "traam",
+    "trab",
+    "trabe",
+    "tracht",
+    "trachten",
+    "trachtet",
+    "trachtete",
+    "trachteten",
+    "tradition",
+    "traditionellen",
+    "traditionen",
+    "traf",
+    "trafen",
+    "trag",
+    "tragbar",
+    "trage",
+    "tragen",
+    "tragend",
+    "tragenden",
+    "tragik",
+    "tragisch",
+    "tragische",
+    "tragischen",
+    "traktatus",
+    "traktätchen",
+    "tranchierens",
+    "trank",
+    "tranken",
+    "trankopfer",
+    "trankst",
+    "tranlämpchen",
+    "transzendent",
+    "transzendental",
+    "transzendentale",
+    "transzendentalen",
+    "transzendentales",
+    "transzendente",
+    "transzendenten",
+    "transzendenz",
+    "trapfenden",
+    "trapfhirsch",
+    "trat",
+    "traten",
+    "trau",
+    "traube",
+    "trauben",
+    "trauen",
+    "trauer",
+    "trauere",
+    "trauergang",
+    "trauerkapelle",
+    "trauern",
+    "trauernd",
+    "trauernde",
+    "trauernden",
+    "trauernder",
+    "trauerspiel",
+    "trauerspielen",
+    "trauert",
+    "trauerte",
+    "trauerzeit",
+    "trauet",
+    "traufe",
+    "traulich",
+    "traulichen",
+    "traum",
+    "traumberuf",
+    "traumbüchlein",
+    "traume",
+    "traumhafter",
+    "traumlicht",
+    "traumseligkeit",
+    "traurig",
+    "traurige",
+    "traurigen",
+    "trauriger",
+    "trauriges",
+    "traurigkeit",
+    "traurigs",
+    "traurigste",
+    "traut",
+    "traute",
+    "trauten",
+    "trautes",
+    "trauung",
+    "treffe",
+    "treffen",
+    "treffenden",
+    "trefflich",
+    "trefflichen",
+    "trefflicher",
+    "treffliches",
+    "treib",
+    "treiben",
+    "treibend",
+    "treibenden",
+    "treiber",
+    "treibern",
+    "treibst",
+    "treibt",
+    "tremolierte",
+    "trend",
+    "trennen",
+    "trennt",
+    "trennte",
+    "trennten",
+    "trennung",
+    "trennungen",
+    "treppe",
+    "treppen",
+    "treppenabsatz",
+    "treppenaufgänge",
+    "treppengeländer",
+    "treppenhaus",
+    "treppenstufen",
+    "trete",
+    "treten",
+    "tretet",
+    "treu",
+    "treue",
+    "treuem",
+    "treuen",
+    "treuer",
+    "treuere",
+    "treues",
+    "treugeliebter",
+    "treuherzig",
+    "treuherzige",
+    "treuherzigen",
+    "treuherziges",
+    "treulich",
+    "treulos",
+    "treulosen",
+    "treuloser",
+    "treulosigkeit",
+    "treuste",
+    "triangel",
+    "tribut",
+    "trieb",
+    "triebe",
+    "trieben",
+    "triebene",
+    "triebfeder",
+    "triebfedern",
+    "triefaugen",
+    "triefend",
+    "triefenden",
+    "triefendes",
+    "triefigen",
+    "trieften",
+    "trifft",
+    "triften",
+    "triller",
+    "trillernde",
+    "trinken",
+    "trinkenden",
+    "trinkest",
+    "trinkgeld",
+    "trinkhallen",
+    "trinkschale",
+    "trinkt",
+    "tritt",
+    "trittbrett",
+    "tritte",
+    "tritten",
+    "trittst",
+    "triumph",
+    "triumphe",
+    "triumphieren",
+    "triumphierend",
+    "triviale",
+    "trivialität",
+    "trivialitäten",
+    "trocken",
+    "trockene",
+    "trockenen",
+    "trockener",
+    "trockenes",
+    "trockenstangen",
+    "trocknen",
+    "trocknend",
+    "trocknete",
+    "trockneten",
+    "troddel",
+    "troddeln",
+    "trog",
+    "troge",
+    "trollen",
+    "trollt",
+    "trommel",
+    "trommelfell",
+    "trommeln",
+    "trommelte",
+    "trompeten",
+    "trompeter",
+    "trompeters",
+    "tropf",
+    "tropfen",
+    "tropfensaum",
+    "tropfenschweren",
+    "tropft",
+    "tropften",
+    "trophäe",
+    "tross",
+    "trosse",
+    "trossknechte",
+    "trost",
+    "trostes",
+    "trostlos",
+    "trostlose",
+    "trostlosen",
+    "trostlosesten",
+    "trostlosigkeit",
+    "trottel",
+    "trottete",
+    "trotz",
+    "trotzdem",
+    "trotzig",
+    "trotzige",
+    "trotzigen",
+    "trotziges",
+    "trotzten",
+    "troß",
+    "troßknechte",
+    "trubel",
+    "trug",
+    "trugen",
+    "truggestalten",
+    "truggründe",
+    "trugvoll",
+    "trummen",
+    "trunken",
+    "trunkenen",
+    "trunkener",
+    "trupp",
+    "truppe",
+    "truppen",
+    "trupps",
+    "trutzig",
+    "träg",
+    "träge",
+    "trägen",
+    "träger",
+    "trägerschaft",
+    "trägheit",
+    "trägt",
+    "trällern",
+    "träne",
+    "tränen",
+    "tränenbrot",
+    "tränken",
+    "tränket",
+    "tränkst",
+    "tränkte",
+    "träufeln",
+    "träufelt",
+    "träufelten",
+    "träume",
+    "träumen",
+    "träumenden",
+    "träumereien",
+    "träumerisch",
+    "träumerischen",
+    "träumst",
+    "träumt",
+    "träumte",
+    "träumten",
+    "tröpfe",
+    "tröpflein",
+    "tröst",
+    "trösten",
+    "tröstende",
+    "tröstenden",
+    "tröster",
+    "tröstet",
+    "tröstete",
+    "tröstungen",
+    "trübe",
+    "trüben",
+    "trüber",
+    "trübes",
+    "trübsal",
+    "trübsale",
+    "trübselig",
+    "trübselige",
+    "trübseligen",
+    "trübseliger",
+    "trübt",
+    "trübte",
+    "trüffeln",
+    "trüge",
+    "trügerische",
+    "trügerischer",
+    "trüglich",
+    "trüglicher",
+    "trüglichkeit",
+    "trümmer",
+    "trümmerhaufen",
+    "trümmern",
+    "trüppchen",
+    "tu",
+    "tubalkain",
+    "tuch",
+    "tuche",
+    "tuchhose",
+    "tue",
+    "tuest",
+    "tugend",
+    "tugenden",
+    "tugendgesinnung",
+    "tugendhaft",
+    "tugendhafte",
+    "tugendhaften",
+    "tugendhafter",
+    "tugendhafteste",
+    "tugendsame",
+    "tummel",
+    "tummelte",
+    "tumult",
+    "tun",
+    "tundra",
+    "tunika",
+    "tunlich",
+    "tunlichkeit",
+    "tuns",
+    "turbane",
+    "turin",
+    "turiner",
+    "turkelte",
+    "turm",
+    "turmartigen",
+    "turme",
+    "turmknöpfe",
+    "turmstube",
+    "turmuhr",
+    "turnübung",
+    "turteltauben",
+    "turteltäubchen",
+    "tuscheln",
+    "tust",
+    "tut",
+    "tvn",
+    "typen",
+    "typik",
+    "typisches",
+    "typus",
+    "tyrannei",
+    "tyrannen",
+    "tyrus",
+    "täg",
+    "täglich",
+    "tägliche",
+    "täglichen",
+    "täler",
+    "tändle",
+    "tänze",
+    "tänzelnden",
+    "tänzelte",
+    "täppet",
+    "tät",
+    "täte",
+    "täten",
+    "täter",
+    "tätig",
+    "tätige",
+    "tätigen",
+    "tätiger",
+    "tätiges",
+    "tätigkeit",
+    "tätigkeiten",
+    "tätigkeitsbereiche",
+    "tätigkeitstriebe",
+    "tätschelten",
+    "täufer",
+    "täuschen",
+    "täuschender",
+    "täuscht",
+    "täuschte",
+    "täuschung",
+    "täuschungen",
+    "töchter",
+    "töchtern",
+    "tödlich",
+    "tödliche",
+    "tödlichen",
+    "tödlicher",
+    "töff",
+    "töfffahrer",
+    "tölpel",
+    "tölpelhafter",
+    "tölpeln",
+    "tölz",
+    "töne",
+    "tönen",
+    "tönende",
+    "tönt",
+    "tönte",
+    "tönten",
+    "töpfchen",
+    "töpfe",
+    "töpfen",
+    "töpfer",
+    "töpfergeschirre",
+    "töpfers",
+    "töricht",
+    "törichte",
+    "törichten",
+    "töte",
+    "töten",
+    "tötendes",
+    "tötens",
+    "tötet",
+    "tötete",
+    "töteten",
+    "tötung",
+    "tü",
+    "tüchel",
+    "tücher",
+    "tüchl",
+    "tüchtig",
+    "tüchtige",
+    "tüchtigem",
+    "tüchtigen",
+    "tüchtiger",
+    "tüchtiges",
+    "tüchtigkeit",
+    "tücke",
+    "tücken",
+    "tückischer",
+    "tüftelte",
+    "tümpel",
+    "tünche",
+    "tünchen",
+    "tüp",
+    "tür",
+    "türe",
+    "türen",
+    "türflügel",
+    "türfüllung",
+    "türken",
+    "türkisch",
+    "türkische",
+    "türkischem",
+    "türkischen",
+    "türkischer",
+    "türklinke",
+    "türmchen",
+    "türme",
+    "türmen",
+    "türmte",
+    "türschloss",
+    "türseite",
+    "türöffner",
+    "türöffners",
+    "u",
+    "uax",
+    "uberall",
+    "uchtenhagen",
+    "uf",
+    "ufer",
+    "uferloche",
+    "ufern",
+    "ufers",
+    "uff",
+    "ufhangen",
+    "ugelassen",
+    "uhr",
+    "uhren",
+    "uhrenmacher",
+    "uhrfeder",
+    "uhrgehäuse",
+    "uhrkette",
+    "uhrmacher",
+    "uhrwerk",
+    "ulla",
+    "ulm",
+    "ulmen",
+    "um",
+    "umanandhocken",
+    "umarmen",
+    "umarmt",
+    "umarmte",
+    "umarmten",
+    "umarmung",
+    "umarmungen",
+    "umband",
+    "umbarmherziger",
+    "umbildung",
+    "umblühet",
+    "umbringen",
+    "umbruchgewinnler",
+    "umdrehen",
+    "umdrehte",
+    "umdrehungen",
+    "umeinander",
+    "umfallen",
+    "umfang",
+    "umfange",
+    "umfangen",
+    "umfanges",
+    "umfassen",
+    "umfassender",
+    "umfasst",
+    "umfasste",
+    "umfassten",
+    "umfaßt",
+    "umfaßte",
+    "umfeld",
+    "umfing",
+    "umfingen",
+    "umflochten",
+    "umflossen",
+    "umfuhr",
+    "umfunkelt",
+    "umg",
+    "umgab",
+    "umgaben",
+    "umgang",
+    "umgange",
+    "umgarnt",
+    "umgebe",
+    "umgeben",
+    "umgebenden",
+    "umgebildet",
+    "umgebracht",
+    "umgebung",
+    "umgebungen",
+    "umgefallen",
+    "umgefallenen",
+    "umgehen",
+    "umgehende",
+    "umgehendes",
+    "umgeht",
+    "umgehängt",
+    "umgekehrt",
+    "umgekehrte",
+    "umgekehrten",
+    "umgekehrter",
+    "umgekommen",
+    "umgekommenen",
+    "umgepflügt",
+    "umgeschlagen",
+    "umgesehen",
+    "umgesehn",
+    "umgespritzt",
+    "umgespritzten",
+    "umgestülpt",
+    "umgestürzten",
+    "umgetan",
+    "umgewandelt",
+    "umgewandte",
+    "umgeworfene",
+    "umgezogen",
+    "umgeändert",
+    "umgibt",
+    "umgrenzen",
+    "umgriffen",
+    "umgsprungen",
+    "umgäbe",
+    "umgänglich",
+    "umgürtet",
+    "umgürtete",
+    "umhalste",
+    "umhalsten",
+    "umhang",
+    "umhangen",
+    "umhegten",
+    "umher",
+    "umherbieten",
+    "umherblickt",
+    "umherfloss",
+    "umhergehen",
+    "umhergeworfene",
+    "umherging",
+    "umherhupfte",
+    "umherirren",
+    "umherirrenden",
+    "umherlaufen",
+    "umherlief",
+    "umherrollenden",
+    "umherschweifen",
+    "umherschweiften",
+    "umhersprangen",
+    "umherstreute",
+    "umhertrat",
+    "umhertrieb",
+    "umherwatschelte",
+    "umherzubieten",
+    "umherzublicken",
+    "umhin",
+    "umhing",
+    "umkamen",
+    "umkehren",
+    "umkehrt",
+    "umkehrte",
+    "umkehrung",
+    "umklammert",
+    "umklammerte",
+    "umkleidende",
+    "umkleidete",
+    "umkleideter",
+    "umkommen",
+    "umkrallung",
+    "umkreis",
+    "umkreise",
+    "umlagert",
+    "umlagerte",
+    "umlagerung",
+    "umlauf",
+    "umlenken",
+    "umliegenden",
+    "umliegender",
+    "ummi",
+    "ummifahren",
+    "ummifahret",
+    "ummiglacht",
+    "ummispring",
+    "umpfiffen",
+    "umreissen",
+    "umreißen",
+    "umringen",
+    "umringt",
+    "umringten",
+    "umrisse",
+    "umrührten",
+    "ums",
+    "umsahen",
+    "umsatz",
+    "umschifft",
+    "umschlag",
+    "umschlang",
+    "umschleierte",
+    "umschliessen",
+    "umschliesst",
+    "umschliessung",
+    "umschließen",
+    "umschließung",
+    "umschlingen",
+    "umschloss",
+    "umschlug",
+    "umschlungen",
+    "umschläge",
+    "umschlägt",
+    "umschweben",
+    "umschwebt",
+    "umschweife",
+    "umschwunges",
+    "umsehen",
+    "umsehend",
+    "umsetzwohnung",
+    "umsicht",
+    "umsichtige",
+    "umsieht",
+    "umso",
+    "umsonst",
+    "umsonstdas",
+    "umspannte",
+    "umspielten",
+    "umstand",
+    "umstanden",
+    "umstehenden",
+    "umstellung",
+    "umstiess",
+    "umstossen",
+    "umstossend",
+    "umstoßen",
+    "umstoßend",
+    "umstrittenen",
+    "umstrittensten",
+    "umsturz",
+    "umstände",
+    "umständen",
+    "umständlich",
+    "umständlichen",
+    "umstösst",
+    "umstößt",
+    "umtreiben",
+    "umtriebe",
+    "umtrieben",
+    "umtun",
+    "umundum",
+    "umverteilen",
+    "umverteilung",
+    "umverteilungssystem",
+    "umwanden",
+    "umwandlung",
+    "umwarf",
+    "umweg",
+    "umwegen",
+    "umweltbewegung",
+    "umwerfen",
+    "umwerfend",
+    "umwickelt",
+    "umwickelte",
+    "umwogt",
+    "umwunden",
+    "umzingelt",
+    "umzublasen",
+    "umzubringen",
+    "umzuckte",
+    "umzugehen",
+    "umzugsfirma",
+    "umzukehren",
+    "umzusehen",
+    "umzustossen",
+    "umzustoßen",
+    "umänderung",
+    "un",
+    "unabhängig",
+    "unabhängige",
+    "unabhängigen",
+    "unabhängiges",
+    "unabhängigkeit",
+    "unablässig",
+    "unabsehbare",
+    "unabsehbaren",
+    "unabsehlich",
+    "unabtrennlich",
+    "unabänderlich",
+    "unabänderliche",
+    "unam",
+    "unangebautes",
+    "unangemeldet",
+    "unangenehm",
+    "unangenehme",
+    "unangenehmen",
+    "unangesehen",
+    "unangetastet",
+    "unangezündet",
+    "unannehmlichkeit",
+    "unannehmlichkeiten",
+    "unansehnliche",
+    "unansehnlicher",
+    "unanständig",
+    "unanständiges",
+    "unanständigsten",
+    "unappetitlichen",
+    "unappetitliches",
+    "unart",
+    "unartig",
+    "unartikuliertes",
+    "unaufgedeckte",
+    "unaufgelösete",
+    "unaufhaltsam",
+    "unaufhörlich",
+    "unaufhörliche",
+    "unaufhörlichen",
+    "unauflöslich",
+    "unauflösliches",
+    "unaufmerksam",
+    "unaufmerksamkeit",
+    "unausbleiblich",
+    "unausgefüllt",
+    "unausgesprochen",
+    "unauslöschlicher",
+    "unaussprechlich",
+    "unaussprechliche",
+    "unaussprechlichen",
+    "unaussprechlicher",
+    "unaussprechliches",
+    "unausstehlich",
+    "unausstehliche",
+    "unbarmherzig",
+    "unbarmherziger",
+    "unbarmherzigere",
+    "unbarmherzigkeit",
+    "unbe",
+    "unbeachtete",
+    "unbebautes",
+    "unbedacht",
+    "unbedachten",
+    "unbedenklich",
+    "unbedeutend",
+    "unbedeutende",
+    "unbedeutenden",
+    "unbedingt",
+    "unbedingte",
+    "unbedingten",
+    "unbedingterweise",
+    "unbefangen",
+    "unbefangene",
+    "unbefangenen",
+    "unbefangenes",
+    "unbefangenheit",
+    "unbegreiflich",
+    "unbegreifliche",
+    "unbegreiflichen",
+    "unbegreiflicher",
+    "unbegreifliches",
+    "unbegrenzte",
+    "unbegrenztes",
+    "unbegründet",
+    "unbehagen",
+    "unbehaglich",
+    "unbehaglicher",
+    "unbehagliches",
+    "unbeholfen",
+    "unbekannnten",
+    "unbekannt",
+    "unbekannte",
+    "unbekannten",
+    "unbekannter",
+    "unbekanntes",
+    "unbekümmert",
+    "unbelebt",
+    "unbeliebt",
+    "unbemerkt",
+    "unbemittelte",
+    "unbemittelten",
+    "unbenutzt",
+    "unbenutzten",
+    "unbeobachtet",
+    "unbequem",
+    "unbequemen",
+    "unbequemlichkeit",
+    "unberechenbare",
+    "unbescheidener",
+    "unbescheidenheit",
+    "unbeschnitten",
+    "unbeschnittene",
+    "unbeschnittenen",
+    "unbeschnittenes",
+    "unbescholtenheit",
+    "unbeschreiblich",
+    "unbeschreibliche",
+    "unbeschreiblicher",
+    "unbeschreibliches",
+    "unbeschränkt",
+    "unbeschränkte",
+    "unbeschwert",
+    "unbeschädigt",
+    "unbeschäftigt",
+    "unbeschäftigter",
+    "unbeseelt",
+    "unbeseeltes",
+    "unbesonnenen",
+    "unbesonnenheit",
+    "unbestimmbar",
+    "unbestimmt",
+    "unbestimmte",
+    "unbestimmter",
+    "unbeteiligt",
+    "unbewachten",
+    "unbeweglich",
+    "unbewohnte",
+    "unbewusst",
+    "unbewusste",
+    "unbewussten",
+    "unbezweifeltes",
+    "unbezwinglicher",
+    "unbezähmbare",
+    "unbiegsam",
+    "unbillige",
+    "unbrauchbar",
+    "unbrauchbare",
+    "unbrauchbarkeit",
+    "unbändig",
+    "und",
+    "undank",
+    "undenklichen",
+    "undenklicher",
+    "undeutlich",
+    "unding",
+    "undurchsichtige",
+    "uneben",
+    "unebene",
+    "unebenheiten",
+    "unecht",
+    "unechte",
+    "unechter",
+    "unedel",
+    "unedler",
+    "unehre",
+    "uneigennützig",
+    "uneigennützige",
+    "uneigennützigen",
+    "uneigennützigkeit",
+    "uneigennützigste",
+    "uneigentlich",
+    "uneingeschränktheit",
+    "uneinigkeit",
+    "unempfindlichen",
+    "unendlich",
+    "unendliche",
+    "unendlichen",
+    "unendlichkeit",
+    "unentbehrlich",
+    "unentbehrlichen",
+    "unentbehrliches",
+    "unentschieden",
+    "unentschlossen",
+    "unentwegten",
+    "unerachtet",
+    "unerbittlich",
+    "unerbittliche",
+    "unerbittlicher",
+    "unerfahren",
+    "unerfahrene",
+    "unerfahrener",
+    "unerfahrenheit",
+    "unerfindlichen",
+    "unerfindlicher",
+    "unerforschliche",
+    "unergründliche",
+    "unergründlichkeit",
+    "unerhört",
+    "unerhörte",
+    "unerhörten",
+    "unerhörter",
+    "unerhörtes",
+    "unerklärlichen",
+    "unerklärliches",
+    "unerlaubt",
+    "unerlaubtes",
+    "unerläßlich",
+    "unermesslich",
+    "unermessliche",
+    "unermeßlich",
+    "unermeßliche",
+    "unermüdlich",
+    "unermüdlicher",
+    "unerreichbar",
+    "unerreichbare",
+    "unerreichbaren",
+    "unerschrocken",
+    "unerschrockene",
+    "unerschrockenen",
+    "unerschrockenheit",
+    "unerschöpfliche",
+    "unerschöpflicher",
+    "unerschütterlich",
+    "unerschütterliche",
+    "unerschüttert",
+    "unersteiglicher",
+    "unersättlich",
+    "unerträglich",
+    "unerträgliche",
+    "unerwachsenen",
+    "unerwartet",
+    "unerwartete",
+    "unerwarteter",
+    "unerwarteterweise",
+    "unerwartetes",
+    "unfallopfern",
+    "unfallverhütung",
+    "unfehlbar",
+    "unfehlbare",
+    "unfehlbares",
+    "unfern",
+    "unflat",
+    "unfreiheit",
+    "unfreisinn",
+    "unfreundlich",
+    "unfreundlichen",
+    "unfreundlichkeit",
+    "unfriede",
+    "unfruchtbar",
+    "unfruchtbare",
+    "unfug",
+    "unfähig",
+    "unfähigkeit",
+    "ungangbar",
+    "ungeachtet",
+    "ungebahnten",
+    "ungebildet",
+    "ungebildeter",
+    "ungebildetes",
+    "ungebraucht",
+    "ungebrochen",
+    "ungebrochener",
+    "ungebärdig",
+    "ungeduld",
+    "ungeduldig",
+    "ungeduldigen",
+    "ungeduldigeren",
+    "ungeeignete",
+    "ungefahr",
+    "ungefunden",
+    "ungefähr",
+    "ungefährdet",
+    "ungefälliger",
+    "ungefärbten",
+    "ungefügen",
+    "ungefüger",
+    "ungefügigen",
+    "ungehalten",
+    "ungeheuer",
+    "ungeheure",
+    "ungeheuren",
+    "ungeheures",
+    "ungehindert",
+    "ungehorsam",
+    "ungehörige",
+    "ungehöriger",
+    "ungehöriges",
+    "ungeistig",
+    "ungeistigen",
+    "ungekämmten",
+    "ungelegener",
+    "ungelegten",
+    "ungelehrt",
+    "ungelenk",
+    "ungelesen",
+    "ungelinden",
+    "ungemein",
+    "ungemütlich",
+    "ungenossen",
+    "ungenügende",
+    "ungerechnet",
+    "ungerecht",
+    "ungerechte",
+    "ungerechten",
+    "ungerechterweise",
+    "ungerechtes",
+    "ungerechtigkeit",
+    "ungerechtigkeiten",
+    "ungereimt",
+    "ungern",
+    "ungerächet",
+    "ungeschehen",
+    "ungeschicklichkeit",
+    "ungeschickt",
+    "ungeschickte",
+    "ungeschickten",
+    "ungeschickterweise",
+    "ungeschicktes",
+    "ungeschoren",
+    "ungeschorene",
+    "ungesehen",
+    "ungesehene",
+    "ungesetzliche",
+    "ungestraften",
+    "ungestört",
+    "ungestörter",
+    "ungestörtheit",
+    "ungestüm",
+    "ungestüme",
+    "ungestümes",
+    "ungesungenen",
+    "ungesäuerten",
+    "ungesäumt",
+    "ungeteilt",
+    "ungetrübte",
+    "ungetüm",
+    "ungewiss",
+    "ungewisser",
+    "ungewissheit",
+    "ungewiß",
+    "ungewohnt",
+    "ungewohnte",
+    "ungewohnten",
+    "ungewöhnlich",
+    "ungewöhnliche",
+    "ungewöhnlichem",
+    "ungewöhnlichen",
+    "ungewöhnliches",
+    "ungeziefer",
+    "ungeziefers",
+    "ungezweifelt",
+    "ungezweifelten",
+    "ungezählten",
+    "ungeübten",
+    "unglaube",
+    "unglauben",
+    "unglaubens",
+    "unglaublich",
+    "unglaubliche",
+    "unglaublichen",
+    "unglaubliches",
+    "unglaubwürdige",
+    "ungleich",
+    "ungleichartig",
+    "ungleichartigen",
+    "ungleichartiger",
+    "ungleichartigkeit",
+    "ungleiche",
+    "ungleichen",
+    "ungleichheit",
+    "ungläubig",
+    "unglück",
+    "unglückes",
+    "unglücklich",
+    "unglückliche",
+    "unglücklichen",
+    "unglücklicher",
+    "unglücklicherweise",
+    "unglücks",
+    "unglückseligen",
+    "unglücksfelde",
+    "unglücksfällen",
+    "unglücksstätte",
+    "ungut",
+    "unguten",
+    "ungwohnte",
+    "ungünstig",
+    "ungünstigen",
+    "unheil",
+    "unheilbar",
+    "unheilig",
+    "unheilige",
+    "unheiligem",
+    "unheiligen",
+    "unheiliges",
+    "unheilvolle",
+    "unheimlich",
+    "unheimliche",
+    "unheimlichen",
+    "unheimliches",
+    "unheimlichsten",
+    "unhold",
+    "unholdes",
+    "unhöflich",
+    "unhöflichen",
+    "unhöfliches",
+    "unhöflichkeit",
+    "unhörbar",
+    "unifomierter",
+    "uniformen",
+    "uniformierten",
+    "uninteressiert",
+    "uniondie",
+    "universalis",
+    "universaliter",
+    "universalsprache",
+    "universelle",
+    "universellen",
+    "universeller",
+    "universelles",
+    "universität",
+    "universum",
+    "unjewöhnliche",
+    "unken",
+    "unkenntnis",
+    "unkindlichkeit",
+    "unklar",
+    "unklug",
+    "unkluge",
+    "unkonventionell",
+    "unkonventionellen",
+    "unkosten",
+    "unkraut",
+    "unkriegerisches",
+    "unkräutern",
+    "unkultivierte",
+    "unkunde",
+    "unlauterkeit",
+    "unleugbar",
+    "unleugbare",
+    "unliebenswürdigen",
+    "unliebliche",
+    "unliebsamen",
+    "unlust",
+    "unlustigen",
+    "unmenschen",
+    "unmenschlich",
+    "unmenschliche",
+    "unmenschlichste",
+    "unmerklich",
+    "unmittelbar",
+    "unmittelbare",
+    "unmittelbaren",
+    "unmittelbarer",
+    "unmittelbares",
+    "unmittelbarste",
+    "unmut",
+    "unmutig",
+    "unmutigen",
+    "unmutiges",
+    "unmännlich",
+    "unmässig",
+    "unmässige",
+    "unmöglich",
+    "unmögliche",
+    "unmöglicher",
+    "unmögliches",
+    "unmöglichkeit",
+    "unnachahmlich",
+    "unnachlasslich",
+    "unnachlasslichen",
+    "unnachsichtlich",
+    "unnachsichtlichen",
+    "unnahbar",
+    "unnatürlich",
+    "unnötig",
+    "unnötige",
+    "unnötigerweise",
+    "unnütz",
+    "unnütze",
+    "unnützen",
+    "unnützes",
+    "unordentlich",
+    "unordentliche",
+    "unordnung",
+    "unorthodoxe",
+    "unparteiischen",
+    "unparteiischer",
+    "unparteilichkeit",
+    "unrasiert",
+    "unrecht",
+    "unrechte",
+    "unrechten",
+    "unrechtes",
+    "unrechtleider",
+    "unrechtliche",
+    "unrechtliches",
+    "unrechtmässig",
+    "unrechtmässige",
+    "unrechts",
+    "unredliche",
+    "unredlichen",
+    "unredlichkeit",
+    "unregelmässigkeit",
+    "unregelmässigkeiten",
+    "unreif",
+    "unreifen",
+    "unrein",
+    "unreine",
+    "unreinem",
+    "unreinen",
+    "unreiner",
+    "unreines",
+    "unreinigkeit",
+    "unrichtig",
+    "unruh",
+    "unruhe",
+    "unruhig",
+    "unruhige",
+    "unruhigen",
+    "uns",
+    "unsaubere",
+    "unsauberen",
+    "unsauberer",
+    "unsauberes",
+    "unsauberkeiten",
+    "unsaubern",
+    "unscheinbare",
+    "unscheinbaren",
+    "unscheinbares",
+    "unscheinbarste",
+    "unschicklich",
+    "unschicklichkeiten",
+    "unschlüssig",
+    "unschuld",
+    "unschuldig",
+    "unschuldige",
+    "unschuldigen",
+    "unschuldiger",
+    "unschuldigeren",
+    "unschuldiges",
+    "unschädlich",
+    "unschädlichen",
+    "unschön",
+    "unschöne",
+    "unschönen",
+    "unselige",
+    "unseligkeit",
+    "unser",
+    "unsere",
+    "unsereinen",
+    "unsereiner",
+    "unserem",
+    "unseren",
+    "unserer",
+    "unseres",
+    "unseresgleichen",
+    "unserm",
+    "unsern",
+    "unsers",
+    "unsicher",
+    "unsicherer",
+    "unsicherheit",
+    "unsichern",
+    "unsichtbar",
+    "unsichtbaren",
+    "unsichtbarer",
+    "unsinn",
+    "unsinnig",
+    "unsinnige",
+    "unsinnigen",
+    "unsinniger",
+    "unsinniges",
+    "unsinnigkeit",
+    "unsinnigkeiten",
+    "unsinnigsten",
+    "unsittlich",
+    "unsittliche",
+    "unsittlicher",
+    "unsittlichkeit",
+    "unsre",
+    "unsrer",
+    "unsres",
+    "unsrige",
+    "unsterblich",
+    "unsterbliche",
+    "unsterblichkeit",
+    "unstern",
+    "unsterns",
+    "unstet",
+    "unstetes",
+    "unstreitig",
+    "unsträflich",
+    "unstäte",
+    "unsympathisch",
+    "unsäglich",
+    "unsägliche",
+    "unsäglicher",
+    "untat",
+    "untauglich",
+    "untaugliche",
+    "unteilbar",
+    "unteilbare",
+    "unteilbares",
+    "unten",
+    "unter",
+    "unterabteilung",
+    "unterarten",
+    "unterbezahlt",
+    "unterbrach",
+    "unterbrachen",
+    "unterbreche",
+    "unterbrechend",
+    "unterbreiten",
+    "unterbringen",
+    "unterbrochen",
+    "unterbrochenen",
+    "unterdessen",
+    "unterdrücken",
+    "unterdrückend",
+    "unterdrückt",
+    "unterdrückte",
+    "unterdrückten",
+    "unterdrückter",
+    "unterdrücktes",
+    "unterdrückung",
+    "unterdrückungen",
+    "untere",
+    "untereinander",
+    "unterem",
+    "unteren",
+    "unteres",
+    "unterfand",
+    "unterfangen",
+    "untergang",
+    "untergange",
+    "unterganges",
+    "untergangs",
+    "untergebene",
+    "untergebenen",
+    "untergebenheit",
+    "untergebracht",
+    "untergegangen",
+    "untergehen",
+    "untergehende",
+    "untergeht",
+    "untergekommen",
+    "untergelegt",
+    "untergeordnet",
+    "untergeordnete",
+    "untergeordneten",
+    "untergeordneter",
+    "untergeordnetere",
+    "untergeschoben",
+    "unterging",
+    "unterginge",
+    "untergingen",
+    "unterhalb",
+    "unterhalten",
+    "unterhaltung",
+    "unterhaltungen",
+    "unterhaltungsabteilung",
+    "unterhaltungsminute",
+    "unterhaltungsspieles",
+    "unterhandlungen",
+    "unterhielt",
+    "unterhielten",
+    "unterhält",
+    "unterhändlern",
+    "unterichtsstunden",
+    "unterjochen",
+    "unterjocht",
+    "unterjochung",
+    "unterjochungsbestreben",
+    "unterkiefer",
+    "unterkommen",
+    "unterkommst",
+    "unterköchen",
+    "unterlage",
+    "unterlagen",
+    "unterlass",
+    "unterlassen",
+    "unterlassest",
+    "unterlaufen",
+    "unterlaß",
+    "unterlegen",
+    "unterlegt",
+    "unterlegten",
+    "unterlehrer",
+    "unterliege",
+    "unterliegen",
+    "unterliegt",
+    "unterliessen",
+    "unterlippe",
+    "unterm",
+    "unterminierte",
+    "unternahm",
+    "unternahmen",
+    "unternehmen",
+    "unternehmend",
+    "unternehmender",
+    "unternehmer",
+    "unternehmung",
+    "unternehmungen",
+    "unternehmungsgeist",
+    "unternehmungslust",
+    "unternehmungslustigen",
+    "unternommen",
+    "unternähme",
+    "unteroffizier",
+    "unteroffizieren",
+    "unteroffiziers",
+    "unterordnung",
+    "unterpfand",
+    "unterredung",
+    "unterricht",
+    "unterrichten",
+    "unterrichtet",
+    "unterröcke",
+    "untersatze",
+    "untersberges",
+    "unterscheiden",
+    "unterscheidend",
+    "unterscheidet",
+    "unterscheidung",
+    "unterscheidungsgrund",
+    "unterscheidungszeichen",
+    "unterschiebt",
+    "unterschied",
+    "unterschiede",
+    "unterschieden",
+    "unterschiedene",
+    "unterschiedenem",
+    "unterschiedenen",
+    "unterschiedenes",
+    "unterschiedes",
+    "unterschiedlichdie",
+    "unterschiedliche",
+    "unterschiedlicher",
+    "unterschiedlichkeiten",
+    "unterschleif",
+    "unterschleife",
+    "unterschreiben",
+    "unterschrift",
+    "unterst",
+    "unterstand",
+    "unterste",
+    "unterstein",
+    "unterstellen",
+    "unterstellt",
+    "untersten",
+    "unterstes",
+    "unterstützen",
+    "unterstützt",
+    "unterstützte",
+    "unterstützung",
+    "untersuchen",
+    "untersuchend",
+    "untersucht",
+    "untersuchte",
+    "untersuchten",
+    "untersuchung",
+    "untersuchungen",
+    "untersuchungsansätzen",
+    "untersuchungskommission",
+    "untersuchungsraum",
+    "untersuchungsrichter",
+    "untersuchungsrichtern",
+    "untersuchungsrichters",
+    "untersuchungstag",
+    "untersuchungszimmer",
+    "untersuchungszimmers",
+    "untertan",
+    "untertanen",
+    "untertaucht",
+    "untertrat",
+    "untertreten",
+    "untertänigkeit",
+    "unterwarf",
+    "unterwarfen",
+    "unterweges",
+    "unterwegs",
+    "unterweisen",
+    "unterweisend",
+    "unterweisung",
+    "unterwelt",
+    "unterwerfen",
+    "unterwerfung",
+    "unterwies",
+    "unterwiesen",
+    "unterworfen",
+    "unterwürfigkeit",
+    "unterzeichnet",
+    "unterzeichnete",
+    "unterziehe",
+    "unterziehen",
+    "unterzog",
+    "unterzogen",
+    "unterzubringen",
+    "unterzukommen",
+    "untiefe",
+    "untier",
+    "untrennbar",
+    "untreu",
+    "untreue",
+    "untreuen",
+    "untröstlich",
+    "untätig",
+    "unum",
+    "unumgänglich",
+    "ununterbrochen",
+    "ununterbrochene",
+    "ununterbrochener",
+    "unverabredet",
+    "unverbrüchlichen",
+    "unverdaulichkeit",
+    "unverdorbener",
+    "unverdrossen",
+    "unverdächtige",
+    "unvereinbar",
+    "unverfänglich",
+    "unvergleichlich",
+    "unvergoldete",
+    "unverhehlt",
+    "unverhehlter",
+    "unverheiratet",
+    "unverhofft",
+    "unverhoffte",
+    "unverhoffter",
+    "unverhofftes",
+    "unverkennbar",
+    "unverletzlich",
+    "unverletzliche",
+    "unverletzliches",
+    "unverletzlichkeit",
+    "unvermeidlich",
+    "unvermeidliche",
+    "unvermeidlichen",
+    "unvermeidlicher",
+    "unvermengt",
+    "unvermerkt",
+    "unvermutet",
+    "unvermögend",
+    "unvermögens",
+    "unvernünftig",
+    "unvernünftige",
+    "unvernünftigen",
+    "unvernünftiger",
+    "unverputzte",
+    "unverrückbaren",
+    "unverschlossen",
+    "unverschämt",
+    "unverschämte",
+    "unverschämtheit",
+    "unversehens",
+    "unversehrt",
+    "unversehrter",
+    "unversiegbare",
+    "unverstande",
+    "unverständigen",
+    "unverständlich",
+    "unverständliche",
+    "unverständlichen",
+    "unversöhnlich",
+    "unversöhnlicher",
+    "unvertilgbaren",
+    "unverwahrt",
+    "unverwandt",
+    "unverweilt",
+    "unverwüstliche",
+    "unverwüstlichen",
+    "unverzagt",
+    "unverzeihliches",
+    "unverzeihlichsten",
+    "unverzichtbar",
+    "unverzichtbare",
+    "unverzüglich",
+    "unveränderlich",
+    "unveränderliche",
+    "unveränderlichem",
+    "unveränderlichen",
+    "unverändert",
+    "unvollkommene",
+    "unvollkommenen",
+    "unvordenklichen",
+    "unvorhergesehene",
+    "unvorsichtig",
+    "unvorsichtigkeit",
+    "unwahr",
+    "unwahrscheinlich",
+    "unwahrscheinliche",
+    "unwahrscheinlichen",
+    "unwandelbar",
+    "unwandelbaren",
+    "unwandelbarkeit",
+    "unweidmännischen",
+    "unweise",
+    "unweiser",
+    "unweit",
+    "unwert",
+    "unwerter",
+    "unwesen",
+    "unwetter",
+    "unwichtig",
+    "unwichtigen",
+    "unwiderleglich",
+    "unwiderruflich",
+    "unwiderrufliches",
+    "unwidersprechlich",
+    "unwiderstehlich",
+    "unwiderstehliche",
+    "unwiderstehlichen",
+    "unwiederbringlicher",
+    "unwillen",
+    "unwillig",
+    "unwillkommen",
+    "unwillkürlich",
+    "unwillkürliche",
+    "unwirksam",
+    "unwirsch",
+    "unwissend",
+    "unwissende",
+    "unwissenden",
+    "unwissenheit",
+    "unwohl",
+    "unwohlsein",
+    "unwohnlichen",
+    "unwort",
+    "unwürdig",
+    "unwürdige",
+    "unwürdigen",
+    "unwürdiger",
+    "unzeit",
+    "unzersplittertes",
+    "unzertrennlich",
+    "unzertrennliche",
+    "unziemlich",
+    "unziemliche",
+    "unziemliches",
+    "unziemlichsten",
+    "unzucht",
+    "unzufrieden",
+    "unzufriedenheit",
+    "unzugänglich",
+    "unzugängliche",
+    "unzukömmlichkeit",
+    "unzukömmlichkeiten",
+    "unzulänglichkeit",
+    "unzulässig",
+    "unzureichend",
+    "unzusammenhängend",
+    "unzusammenhängendes",
+    "unzweckmässigkeit",
+    "unzweifelhaft",
+    "unzweifelhafte",
+    "unzweifelhafteste",
+    "unzählig",
+    "unzählige",
+    "unzähligem",
+    "unzähligemal",
+    "unzähligen",
+    "unzähliger",
+    "unzähligere",
+    "unzähliges",
+    "unähnlich",
+    "unähnlichkeit",
+    "unästhetischem",
+    "unübertrefflich",
+    "unüberwindliche",
+    "uphas",
+    "ur",
+    "urahn",
+    "uralte",
+    "uralten",
+    "uralter",
+    "urbeginn",
+    "urbild",
+    "urbilder",
+    "urbildliche",
+    "urenkel",
+    "urfeld",
+    "urfelder",
+    "urgrossvater",
+    "urgrossvaters",
+    "urgroßvater",
+    "urgroßvaters",
+    "urgrunde",
+    "urgrundes",
+    "urheber",
+    "urhebers",
+    "urkirche",
+    "urlaub",
+    "urmensch",
+    "urmenschen",
+    "urne",
+    "urplötzlich",
+    "ursache",
+    "ursachen",
+    "ursprung",
+    "ursprunge",
+    "ursprunges",
+    "ursprungs",
+    "ursprungsort",
+    "ursprünglich",
+    "ursprüngliche",
+    "ursprünglichen",
+    "ursprüngliches",
+    "ursächlich",
+    "ursächlichen",
+    "urteil",
+    "urteile",
+    "urteilen",
+    "urteilenden",
+    "urteils",
+    "urteilskraft",
+    "urteilt",
+    "ururgrosspapa",
+    "ururgrossvater",
+    "ururgroßvater",
+    "urvater",
+    "urväter",
+    "urwald",
+    "urwesen",
+    "urwesens",
+    "urzeit",
+    "usa",
+    "usf",
+    "ussiel",
+    "ussiels",
+    "usurpator",
+    "usw",
+    "utopien",
+    "uxor",
+    "uxores",
+    "v",
+    "vagantur",
+    "vagari",
+    "vagus",
+    "vakant",
+    "vallotton",
+    "vamp",
+    "van",
+    "varia",
+    "variationen",
+    "vasallentreue",
+    "vaschwör",
+    "vastatio",
+    "vastationes",
+    "vater",
+    "vaterhaus",
+    "vaterhause",
+    "vaterhäusern",
+    "vaterland",
+    "vaterlande",
+    "vaterlandes",
+    "vatermörder",
+    "vaters",
+    "vaterunser",
+    "vatet",
+    "vatikan",
+    "vaudevilletheatern",
+    "veb",
+    "vehikel",
+    "veigerl",
+    "veilchen",
+    "veilchenblaue",
+    "veilchenburg",
+    "veilchenkoppe",
+    "veilchenzeit",
+    "veit",
+    "vemünftiger",
+    "ven",
+    "venedig",
+    "venezianischen",
+    "ver",
+    "vera",
+    "verab",
+    "verabredet",
+    "verabredeten",
+    "verabredung",
+    "verabreichten",
+    "verabscheuen",
+    "verabscheuungsvermögens",
+    "verabschiedet",
+    "verabschiedete",
+    "verabschiedeten",
+    "verachte",
+    "verachten",
+    "verachtet",
+    "verachtete",
+    "verachteten",
+    "verachtung",
+    "veraltet",
+    "veranlassen",
+    "veranlasst",
+    "veranlasste",
+    "veranlasster",
+    "veranlassung",
+    "veranlaßt",
+    "veranschaulichung",
+    "veranstalten",
+    "veranstaltet",
+    "veranstaltete",
+    "veranstalteten",
+    "veranstaltung",
+    "veranstaltungen",
+    "verantworten",
+    "verantwortete",
+    "verantwortlich",
+    "verantwortlichen",
+    "verantwortung",
+    "verarbeitet",
+    "verarmten",
+    "verarmung",
+    "verbal",
+    "verbale",
+    "verband",
+    "verbanden",
+    "verbandes",
+    "verbannt",
+    "verbannung",
+    "verbarg",
+    "verbargen",
+    "verbat",
+    "verbereitung",
+    "verbergen",
+    "verberget",
+    "verbesserlichkeit",
+    "verbessern",
+    "verbesserte",
+    "verbesserungen",
+    "verbesserungsbedürftigkeit",
+    "verbesserungsvorschläge",
+    "verbeugend",
+    "verbeugte",
+    "verbeugten",
+    "verbeugung",
+    "verbeugungen",
+    "verbeulten",
+    "verbiegt",
+    "verbiete",
+    "verbieten",
+    "verbinde",
+    "verbinden",
+    "verbindet",
+    "verbindlichkeit",
+    "verbindlichkeiten",
+    "verbindung",
+    "verbindungen",
+    "verbindungsmittel",
+    "verbirg",
+    "verbirgst",
+    "verbirgt",
+    "verbis",
+    "verbissen",
+    "verbissenheit",
+    "verbittert",
+    "verbitterte",
+    "verbitterten",
+    "verbittertwerden",
+    "verbleib",
+    "verbleibe",
+    "verbleiben",
+    "verbleibt",
+    "verblenden",
+    "verblendet",
+    "verblendeten",
+    "verblendung",
+    "verblichen",
+    "verblichenen",
+    "verbliebe",
+    "verblieben",
+    "verbliebenen",
+    "verblüffen",
+    "verblüfft",
+    "verblüfften",
+    "verblüfftesten",
+    "verblüffung",
+    "verblüht",
+    "verblümt",
+    "verblümtes",
+    "verborgen",
+    "verborgene",
+    "verborgenen",
+    "verborgener",
+    "verborgenes",
+    "verborgenheit",
+    "verborgenheiten",
+    "verborgenwerden",
+    "verbot",
+    "verboten",
+    "verbracht",
+    "verbrachte",
+    "verbrachtem",
+    "verbrachten",
+    "verbrannt",
+    "verbrannte",
+    "verbrannten",
+    "verbraucht",
+    "verbrechen",
+    "verbrecherischen",
+    "verbreiten",
+    "verbreitet",
+    "verbreitete",
+    "verbrennen",
+    "verbrennt",
+    "verbrennung",
+    "verbringe",
+    "verbringen",
+    "verbringt",
+    "verbrämt",
+    "verbrüderung",
+    "verbrühen",
+    "verbuhltes",
+    "verbum",
+    "verbunden",
+    "verbundene",
+    "verbundenen",
+    "verbundenes",
+    "verdacht",
+    "verdachte",
+    "verdamme",
+    "verdammen",
+    "verdammlich",
+    "verdammnis",
+    "verdammt",
+    "verdammte",
+    "verdammtem",
+    "verdammten",
+    "verdammungen",
+    "verdampften",
+    "verdanke",
+    "verdanken",
+    "verdankt",
+    "verdarb",
+    "verdarben",
+    "verdauen",
+    "verdeckt",
+    "verdeckte",
+    "verdenken",
+    "verderbe",
+    "verderben",
+    "verderbende",
+    "verderbenden",
+    "verderber",
+    "verderblich",
+    "verderbliche",
+    "verderblichen",
+    "verderbnis",
+    "verderbt",
+    "verderbtheit",
+    "verdichtet",
+    "verdienen",
+    "verdienst",
+    "verdienste",
+    "verdienstlich",
+    "verdienstlichen",
+    "verdienstlicher",
+    "verdient",
+    "verdiente",
+    "verdientermassen",
+    "verdientermaßen",
+    "verdingen",
+    "verdirbt",
+    "verdoppelt",
+    "verdoppeltem",
+    "verdoppelten",
+    "verdorben",
+    "verdorbene",
+    "verdorbenen",
+    "verdorbenerweise",
+    "verdorren",
+    "verdorrt",
+    "verdorrte",
+    "verdorrter",
+    "verdrehen",
+    "verdrehend",
+    "verdreht",
+    "verdrehte",
+    "verdrehten",
+    "verdrehter",
+    "verdrehtheit",
+    "verdreifachen",
+    "verdriesslich",
+    "verdrießlich",
+    "verdrossen",
+    "verdruss",
+    "verdruß",
+    "verdrängen",
+    "verdrängt",
+    "verdunkeln",
+    "verdunkelt",
+    "verdunkelte",
+    "verdunkelten",
+    "verdunkelung",
+    "verdunklung",
+    "verdursten",
+    "verdutzt",
+    "verdutzte",
+    "verdutztes",
+    "verdächtig",
+    "verdächtiges",
+    "verdächtigt",
+    "veredelt",
+    "verehelichen",
+    "verehelicht",
+    "verehren",
+    "verehrer",
+    "verehrt",
+    "verehrte",
+    "verehrten",
+    "verehrung",
+    "verehrungswürdig",
+    "verein",
+    "vereinbarung",
+    "vereine",
+    "vereinicht",
+    "vereinigen",
+    "vereinigende",
+    "vereinigt",
+    "vereinigte",
+    "vereinigung",
+    "vereins",
+    "vereinsversammlung",
+    "vereint",
+    "vereinte",
+    "vereinten",
+    "vereinzelte",
+    "vereinzelten",
+    "vereinzeltes",
+    "vereiteln",
+    "vereitelt",
+    "verena",
+    "verendet",
+    "vererbt",
+    "vererbten",
+    "vererbung",
+    "verfahre",
+    "verfahren",
+    "verfahrens",
+    "verfahrensweisen",
+    "verfall",
+    "verfalle",
+    "verfallen",
+    "verfasser",
+    "verfasst",
+    "verfassten",
+    "verfassung",
+    "verfassungs",
+    "verfassungsrevisoren",
+    "verfassungsänderung",
+    "verfaulen",
+    "verfaßt",
+    "verfeature",
+    "verfeaturt",
+    "verfechten",
+    "verfehlt",
+    "verfehlter",
+    "verfeinden",
+    "verfeindet",
+    "verfeindeten",
+    "verfertigen",
+    "verfertigt",
+    "verfertigte",
+    "verfertigten",
+    "verfertigung",
+    "verfiel",
+    "verfielen",
+    "verfilztes",
+    "verfing",
+    "verfinstern",
+    "verfinstert",
+    "verfinsterte",
+    "verfinsterten",
+    "verfinstertwerden",
+    "verflacht",
+    "verfliegt",
+    "verflochtenen",
+    "verflogen",
+    "verfloss",
+    "verflossen",
+    "verfluche",
+    "verfluchen",
+    "verfluchend",
+    "verflucht",
+    "verfluchte",
+    "verfluchten",
+    "verflüchtigt",
+    "verfolgen",
+    "verfolger",
+    "verfolgern",
+    "verfolgt",
+    "verfolgte",
+    "verfolgten",
+    "verfolgung",
+    "verfremdung",
+    "verfriere",
+    "verfuhr",
+    "verfuhren",
+    "verfährt",
+    "verfällt",
+    "verfälschen",
+    "verfälscher",
+    "verfälscht",
+    "verfälschte",
+    "verfälschtem",
+    "verfälschten",
+    "verfälschtes",
+    "verfälschung",
+    "verfälschungen",
+    "verfängliche",
+    "verfänglichen",
+    "verfügen",
+    "verfügt",
+    "verfügte",
+    "verfügung",
+    "verfügungsrecht",
+    "verführen",
+    "verführer",
+    "verführerische",
+    "verführerischen",
+    "verführt",
+    "verführte",
+    "vergabte",
+    "vergangen",
+    "vergangene",
+    "vergangenen",
+    "vergangenheit",
+    "vergass",
+    "vergassen",
+    "vergaß",
+    "vergaßen",
+    "vergeben",
+    "vergebens",
+    "vergeblich",
+    "vergebliche",
+    "vergeblichen",
+    "vergebliches",
+    "vergeblichkeit",
+    "vergebung",
+    "vergegenwärtigte",
+    "vergehen",
+    "vergehet",
+    "vergehn",
+    "vergeht",
+    "vergelten",
+    "vergelts",
+    "vergeltung",
+    "vergessen",
+    "vergessend",
+    "vergessene",
+    "vergessener",
+    "vergessenheit",
+    "vergesslichkeit",
+    "vergesst",
+    "vergeuden",
+    "vergeßlichkeit",
+    "vergib",
+    "vergibst",
+    "vergiessen",
+    "vergiessend",
+    "vergiesst",
+    "vergießen",
+    "vergießt",
+    "vergiftet",
+    "vergilbter",
+    "vergilt",
+    "verging",
+    "vergingen",
+    "vergiss",
+    "vergissest",
+    "vergissmeinnicht",
+    "vergissmeinnichtschnäpschen",
+    "vergissmeinnichtschnäpse",
+    "vergisst",
+    "vergleich",
+    "vergleiche",
+    "vergleichen",
+    "vergleichsfällen",
+    "vergleichskriterium",
+    "vergleichsweise",
+    "vergleicht",
+    "vergleichung",
+    "vergleichungen",
+    "verglichen",
+    "vergnügen",
+    "vergnügenden",
+    "vergnügens",
+    "vergnüglich",
+    "vergnüglichen",
+    "vergnügliches",
+    "vergnügt",
+    "vergnügte",
+    "vergnügtem",
+    "vergnügten",
+    "vergnügter",
+    "vergnügteste",
+    "vergnügtheit",
+    "vergnügungen",
+    "vergnügungslokalen",
+    "vergnügungssucht",
+    "vergnügungstagen",
+    "vergoldet",
+    "vergoldete",
+    "vergoldetem",
+    "vergoldeten",
+    "vergoldeter",
+    "vergoldetes",
+    "vergolten",
+    "vergoss",
+    "vergossen",
+    "vergoß",
+    "vergraben",
+    "vergrub",
+    "vergrämt",
+    "vergrössern",
+    "vergrössert",
+    "vergrößern",
+    "vergänglich",
+    "vergänglichkeit",
+    "vergönnt",
+    "vergöttert",
+    "vergüten",
+    "verhaftbefehl",
+    "verhaften",
+    "verhaftet",
+    "verhafteten",
+    "verhaftung",
+    "verhalf",
+    "verhallende",
+    "verhallte",
+    "verhalte",
+    "verhalten",
+    "verhaltend",
+    "verhaltenen",
+    "verhaltener",
+    "verhaltens",
+    "verhandeln",
+    "verhandelte",
+    "verhandlung",
+    "verhandlungen",
+    "verharre",
+    "verharrte",
+    "verhasst",
+    "verhassten",
+    "verhasster",
+    "verheerenden",
+    "verheert",
+    "verheerung",
+    "verhehlten",
+    "verheimlichen",
+    "verheiraten",
+    "verheiratet",
+    "verheiratete",
+    "verheirateten",
+    "verheiratetes",
+    "verheissen",
+    "verheissende",
+    "verheisst",
+    "verheissung",
+    "verheißen",
+    "verheißende",
+    "verheißung",
+    "verhelfen",
+    "verherrliche",
+    "verherrlichen",
+    "verherrlichet",
+    "verherrlicht",
+    "verherrlichten",
+    "verherrlichung",
+    "verhext",
+    "verhielt",
+    "verhielten",
+    "verhindere",
+    "verhindern",
+    "verhindert",
+    "verhinderte",
+    "verhinderten",
+    "verhofften",
+    "verholfen",
+    "verhundertfacht",
+    "verhungert",
+    "verhungertes",
+    "verhält",
+    "verhältnis",
+    "verhältnismässig",
+    "verhältnismäßig",
+    "verhältnisse",
+    "verhältnissen",
+    "verhältnisses",
+    "verhängnis",
+    "verhängnisvollen",
+    "verhängnisvollste",
+    "verhärmten",
+    "verhärten",
+    "verhärtet",
+    "verhärtete",
+    "verhärteten",
+    "verhärtung",
+    "verhöhnen",
+    "verhöhnt",
+    "verhöhnung",
+    "verhör",
+    "verhöre",
+    "verhören",
+    "verhülle",
+    "verhüllt",
+    "verhüllte",
+    "verhüllung",
+    "verhüten",
+    "verhütet",
+    "verint",
+    "verirren",
+    "verirrt",
+    "verirrte",
+    "verirrung",
+    "veritas",
+    "verjährt",
+    "verjährte",
+    "verkauf",
+    "verkaufen",
+    "verkaufssumme",
+    "verkauft",
+    "verkauften",
+    "verkehr",
+    "verkehren",
+    "verkehrs",
+    "verkehrt",
+    "verkehrte",
+    "verkehrten",
+    "verkehrtes",
+    "verkehrtheit",
+    "verkehrtheiten",
+    "verkehrung",
+    "verkehrungen",
+    "verkennen",
+    "verkennt",
+    "verklammert",
+    "verklang",
+    "verklebe",
+    "verklebt",
+    "verklebte",
+    "verkleiden",
+    "verkleidet",
+    "verkleidung",
+    "verkleinern",
+    "verklungen",
+    "verklärt",
+    "verklärten",
+    "verklärtes",
+    "verkneifen",
+    "verkniff",
+    "verkniffen",
+    "verkniffenen",
+    "verknüpfe",
+    "verknüpfen",
+    "verknüpft",
+    "verknüpfte",
+    "verknüpften",
+    "verknüpfung",
+    "verknüpfungen",
+    "verkommenen",
+    "verkommenheit",
+    "verkriecht",
+    "verkrochen",
+    "verkrümmelten",
+    "verkrüppelt",
+    "verkühlung",
+    "verkünden",
+    "verkündet",
+    "verkündete",
+    "verkündeten",
+    "verkündige",
+    "verkündigen",
+    "verkündigt",
+    "verkündigte",
+    "verkündigung",
+    "verkünstelt",
+    "verkürzen",
+    "verlachen",
+    "verlacht",
+    "verlag",
+    "verlages",
+    "verlagsmitteilung",
+    "verlange",
+    "verlangen",
+    "verlangend",
+    "verlangst",
+    "verlangt",
+    "verlangte",
+    "verlangten",
+    "verlas",
+    "verlass",
+    "verlassen",
+    "verlassene",
+    "verlassenen",
+    "verlassener",
+    "verlassenes",
+    "verlassenheit",
+    "verlasset",
+    "verlaub",
+    "verlauf",
+    "verlaufe",
+    "verlaufen",
+    "verlaufende",
+    "verlaufs",
+    "verlauten",
+    "verlebten",
+    "verlegen",
+    "verlegenen",
+    "verlegener",
+    "verlegenheit",
+    "verleger",
+    "verlegt",
+    "verlegte",
+    "verlegten",
+    "verleihe",
+    "verleihen",
+    "verleiht",
+    "verleiten",
+    "verleitet",
+    "verleitete",
+    "verletze",
+    "verletzen",
+    "verletzt",
+    "verletzte",
+    "verletzten",
+    "verletztheit",
+    "verletzung",
+    "verleugne",
+    "verleugnet",
+    "verleugnete",
+    "verleumden",
+    "verleumdern",
+    "verleumdet",
+    "verleumdungswesen",
+    "verlieben",
+    "verliebt",
+    "verliebte",
+    "verliebten",
+    "verliebter",
+    "verliebtheit",
+    "verlief",
+    "verlieh",
+    "verliehen",
+    "verlieren",
+    "verliert",
+    "verliess",
+    "verließ",
+    "verlobt",
+    "verlobung",
+    "verlocken",
+    "verlockend",
+    "verlockendes",
+    "verlockt",
+    "verlockte",
+    "verlockung",
+    "verlockungen",
+    "verlogenen",
+    "verlogenes",
+    "verlor",
+    "verloren",
+    "verlorene",
+    "verlorenen",
+    "verlorener",
+    "verlorenes",
+    "verlorengegangen",
+    "verlorengehe",
+    "verlorengehen",
+    "verlorengeht",
+    "verlorenging",
+    "verlorengingen",
+    "verlorensein",
+    "verlotterten",
+    "verlumpete",
+    "verlumpte",
+    "verlumpungen",
+    "verlust",
+    "verlustig",
+    "verlusts",
+    "verlängern",
+    "verlängert",
+    "verlängerten",
+    "verlässet",
+    "verlässt",
+    "verläuft",
+    "verläßt",
+    "verlöre",
+    "verlöschen",
+    "vermachte",
+    "vermag",
+    "vermahnen",
+    "vermauern",
+    "vermehren",
+    "vermehrt",
+    "vermehrten",
+    "vermehrter",
+    "vermehrung",
+    "vermehrungen",
+    "vermeiden",
+    "vermeidung",
+    "vermeinte",
+    "vermeinten",
+    "vermeinter",
+    "vermeintlich",
+    "vermeldete",
+    "vermengt",
+    "vermerkt",
+    "vermied",
+    "vermieden",
+    "vermieter",
+    "vermindern",
+    "vermindernden",
+    "vermindert",
+    "verminderung",
+    "vermischen",
+    "vermischt",
+    "vermischte",
+    "vermischten",
+    "vermischung",
+    "vermischungen",
+    "vermissen",
+    "vermisst",
+    "vermisste",
+    "vermittelnde",
+    "vermittelndes",
+    "vermittels",
+    "vermittelst",
+    "vermittlerin",
+    "vermittlung",
+    "vermittlungen",
+    "vermißte",
+    "vermocht",
+    "vermochte",
+    "vermochten",
+    "vermute",
+    "vermuten",
+    "vermutet",
+    "vermutete",
+    "vermutlich",
+    "vermutlichen",
+    "vermutung",
+    "vermutungen",
+    "vermählt",
+    "vermählte",
+    "vermählten",
+    "vermähltes",
+    "vermählung",
+    "vermählungen",
+    "vermöchte",
+    "vermöge",
+    "vermögen",
+    "vermögend",
+    "vermögenden",
+    "vermögens",
+    "vermögensstand",
+    "vernachlässige",
+    "vernachlässigt",
+    "vernachlässigte",
+    "vernachlässigten",
+    "vernachlässigung",
+    "vernagelt",
+    "vernagelten",
+    "vernahm",
+    "vernahmen",
+    "vernarbendes",
+    "vernarren",
+    "vernarrt",
+    "vernehmbar",
+    "vernehmbare",
+    "vernehmen",
+    "vernehmet",
+    "vernehmlassungsverfahrens",
+    "vernehmlichem",
+    "vernehmlichen",
+    "vernehmnisse",
+    "verneigen",
+    "verneigend",
+    "verneigt",
+    "verneigte",
+    "verneinen",
+    "verneinenden",
+    "verneinung",
+    "verneinungen",
+    "verneunfacht",
+    "vernichte",
+    "vernichten",
+    "vernichtet",
+    "vernichteten",
+    "vernichtung",
+    "vernichtungsgenie",
+    "vernimmt",
+    "vernommen",
+    "vernunft",
+    "vernunftanschauungen",
+    "vernunftbedingung",
+    "vernunftbedürfnis",
+    "vernunftbegriffe",
+    "vernunftbegriffen",
+    "vernunftbestimmung",
+    "vernunfteinsicht",
+    "vernunfterkenntnisse",
+    "vernunfterkenntnisses",
+    "vernunftgebote",
+    "vernunftgebots",
+    "vernunftgebrauch",
+    "vernunftgebrauche",
+    "vernunftgebrauchs",
+    "vernunftgemässe",
+    "vernunftgemässen",
+    "vernunftgesetz",
+    "vernunftgesetze",
+    "vernunftglaube",
+    "vernunftglaubens",
+    "vernunftgründe",
+    "vernunftgründen",
+    "vernunftidee",
+    "vernunftideen",
+    "vernunftkraft",
+    "vernunftlos",
+    "vernunftmässiges",
+    "vernunftmäßiges",
+    "vernunftprinzip",
+    "vernunftschlusses",
+    "vernunftschlüsse",
+    "vernunfttheologie",
+    "vernunftverhältnisse",
+    "vernunftvermögen",
+    "vernunftvermögens",
+    "vernunftvorstellungen",
+    "vernunftwahres",
+    "vernunftwahrheiten",
+    "vernunftwillens",
+    "vernunftwissenschaften",
+    "vernunftähnliches",
+    "vernähme",
+    "vernünftelei",
+    "vernünfteleien",
+    "vernünfteln",
+    "vernünftelnde",
+    "vernünftelt",
+    "vernünftelten",
+    "vernünftig",
+    "vernünftige",
+    "vernünftigen",
+    "vernünftiger",
+    "vernünftiges",
+    "vernünftigkeit",
+    "vernünftigsein",
+    "vernünftler",
+    "vernünftlern",
+    "verordnung",
+    "verordnungen",
+    "verorientieren",
+    "verpachteten",
+    "verpacken",
+    "verpackt",
+    "verpflichten",
+    "verpflichtet",
+    "verpflichtung",
+    "verpflichtungen",
+    "verpfändet",
+    "verpfändeten",
+    "verpichen",
+    "verpicht",
+    "verpichtwerden",
+    "verpichung",
+    "verplaudern",
+    "verpustete",
+    "verpönt",
+    "verrannt",
+    "verraten",
+    "verrechnen",
+    "verregnet",
+    "verreisen",
+    "verreiste",
+    "verrenkungen",
+    "verrichten",
+    "verrichtet",
+    "verrichtete",
+    "verrichteten",
+    "verrichtung",
+    "verrichtungen",
+    "verriet",
+    "verriete",
+    "verrieten",
+    "verringern",
+    "verringert",
+    "verrollen",
+    "verrostetes",
+    "verruckte",
+    "verrufen",
+    "verrufenen",
+    "verrät",
+    "verrückt",
+    "verrückte",
+    "vers",
+    "versagender",
+    "versagt",
+    "versagte",
+    "versagten",
+    "versah",
+    "versammeln",
+    "versammelt",
+    "versammelte",
+    "versammelten",
+    "versammle",
+    "versammlung",
+    "versammlungszimmer",
+    "versandet",
+    "versandt",
+    "versank",
+    "versanken",
+    "verschaffe",
+    "verschaffen",
+    "verschafft",
+    "verschaffte",
+    "verschattung",
+    "verschen",
+    "verschenken",
+    "verscherzt",
+    "verscheuchen",
+    "verscheuchten",
+    "verschiebung",
+    "verschieden",
+    "verschiedenartig",
+    "verschiedenartigen",
+    "verschiedenartiges",
+    "verschiedenartigste",
+    "verschiedene",
+    "verschiedenem",
+    "verschiedenen",
+    "verschiedener",
+    "verschiedenes",
+    "verschiedenheit",
+    "verschiedenheiten",
+    "verschiedenste",
+    "verschiedensten",
+    "verschiedentlich",
+    "verschlafen",
+    "verschlage",
+    "verschlagen",
+    "verschlang",
+    "verschlangen",
+    "verschlechterung",
+    "verschleiern",
+    "verschleiert",
+    "verschleudert",
+    "verschliessen",
+    "verschließen",
+    "verschlimmern",
+    "verschlimmert",
+    "verschlinge",
+    "verschlingen",
+    "verschlingungen",
+    "verschloss",
+    "verschlossen",
+    "verschlossenen",
+    "verschlossenes",
+    "verschluckt",
+    "verschlungen",
+    "verschlungenen",
+    "verschluss",
+    "verschlusses",
+    "verschmachten",
+    "verschmelzen",
+    "verschmerzen",
+    "verschmierten",
+    "verschmitzt",
+    "verschmitztheit",
+    "verschmähe",
+    "verschmähen",
+    "verschmähst",
+    "verschmäht",
+    "verschmähte",
+    "verschmähten",
+    "verschnaufen",
+    "verschnaufte",
+    "verschnittene",
+    "verschnittenen",
+    "verschnürte",
+    "verschob",
+    "verschollene",
+    "verschollenen",
+    "verschonen",
+    "verschont",
+    "verschossen",
+    "verschränkung",
+    "verschulden",
+    "verschuldet",
+    "verschuldung",
+    "verschuldungen",
+    "verschustern",
+    "verschwammen",
+    "verschwand",
+    "verschwanden",
+    "verschweigen",
+    "verschwenden",
+    "verschwenderisch",
+    "verschwendet",
+    "verschwendung",
+    "verschwieg",
+    "verschwiegenes",
+    "verschwinde",
+    "verschwinden",
+    "verschwindet",
+    "verschworen",
+    "verschworene",
+    "verschwunden",
+    "verschwundene",
+    "verschwundenen",
+    "verschämt",
+    "verschärfung",
+    "verschärfungen",
+    "verschäumte",
+    "verschönert",
+    "verschüttet",
+    "verse",
+    "versehen",
+    "versehn",
+    "versen",
+    "versengen",
+    "versenken",
+    "versenkt",
+    "versenkte",
+    "versenkten",
+    "versenkung",
+    "versenkungen",
+    "versessen",
+    "versetze",
+    "versetzen",
+    "versetzt",
+    "versetzte",
+    "versetzung",
+    "versichern",
+    "versichert",
+    "versicherte",
+    "versicherten",
+    "versicherung",
+    "versiegelt",
+    "versiegen",
+    "versiegle",
+    "versiegt",
+    "versiert",
+    "versilbert",
+    "versimpelter",
+    "versinke",
+    "versinken",
+    "versinkt",
+    "versinnlichung",
+    "versorgen",
+    "versorgt",
+    "versorgten",
+    "verspart",
+    "verspeist",
+    "versperren",
+    "versperrt",
+    "versperrte",
+    "verspotten",
+    "versprach",
+    "versprachen",
+    "verspreche",
+    "versprechen",
+    "verspricht",
+    "versprochen",
+    "versprochene",
+    "verspätet",
+    "verspätetes",
+    "verspätung",
+    "verspüren",
+    "verspürt",
+    "verspürte",
+    "verspürten",
+    "verstand",
+    "verstande",
+    "verstanden",
+    "verstandes",
+    "verstandesbegriff",
+    "verstandesbegriffe",
+    "verstandesbegriffen",
+    "verstandesbegriffs",
+    "verstandesform",
+    "verstandesgebiet",
+    "verstandesgebietes",
+    "verstandesglauben",
+    "verstandeskräfte",
+    "verstandeslicht",
+    "verstandesvermögen",
+    "verstandesvermögens",
+    "verstandeswelt",
+    "verstandeswesen",
+    "verstandnisvoll",
+    "verstatte",
+    "verstatten",
+    "verstattet",
+    "verstecke",
+    "verstecken",
+    "versteckspielen",
+    "versteckt",
+    "versteckte",
+    "verstecktes",
+    "versteh",
+    "verstehe",
+    "verstehen",
+    "verstehens",
+    "verstehet",
+    "verstehn",
+    "verstehst",
+    "versteht",
+    "versteigerung",
+    "versteinert",
+    "versteinerte",
+    "verstellen",
+    "verstellte",
+    "verstellten",
+    "verstellter",
+    "verstellung",
+    "verstimmt",
+    "verstimmter",
+    "verstocke",
+    "verstocken",
+    "verstockt",
+    "verstockte",
+    "verstocktes",
+    "verstocktwerdens",
+    "verstockung",
+    "verstohlen",
+    "verstohlener",
+    "verstopfe",
+    "verstopfen",
+    "verstopft",
+    "verstopfte",
+    "verstorben",
+    "verstorbene",
+    "verstorbenen",
+    "verstossen",
+    "verstossenen",
+    "verstossener",
+    "verstossung",
+    "verstoßen",
+    "verstoßung",
+    "verstreichen",
+    "verstummte",
+    "verstummten",
+    "verständig",
+    "verständige",
+    "verständigen",
+    "verständiger",
+    "verständiges",
+    "verständigkeit",
+    "verständigste",
+    "verständigt",
+    "verständigte",
+    "verständigung",
+    "verständlich",
+    "verständlicher",
+    "verständlicherweise",
+    "verständlichkeit",
+    "verständnis",
+    "verständnisinnig",
+    "verständnisinnigkeit",
+    "verständnislosen",
+    "verständnisse",
+    "verständnisses",
+    "verständnisvoll",
+    "verständnisvoller",
+    "verstänkert",
+    "verstärkt",
+    "verstärkte",
+    "verstärkter",
+    "verstört",
+    "verstörten",
+    "verstörtes",
+    "verstösse",
+    "verstünde",
+    "versuch",
+    "versuche",
+    "versuchen",
+    "versuchend",
+    "versuchsweise",
+    "versucht",
+    "versuchte",
+    "versuchten",
+    "versuchteste",
+    "versuchung",
+    "versuchungen",
+    "versuchungsschmerzen",
+    "versunken",
+    "versunkene",
+    "versunkenes",
+    "versäumen",
+    "versäumt",
+    "versäumte",
+    "versöhnen",
+    "versöhnlichen",
+    "versöhnt",
+    "versöhnung",
+    "versöhnungsversuch",
+    "versündigt",
+    "vertauschen",
+    "vertauscht",
+    "vertausendfacht",
+    "verteidige",
+    "verteidigen",
+    "verteidiger",
+    "verteidigern",
+    "verteidigt",
+    "verteidigte",
+    "verteidigung",
+    "verteidigungsschrift",
+    "verteilen",
+    "verteilt",
+    "verteilungsgerechtigkeit",
+    "verteuerung",
+    "vertiefen",
+    "vertieft",
+    "vertiefte",
+    "vertiefung",
+    "vertilge",
+    "vertilgen",
+    "vertilgt",
+    "vertilgte",
+    "vertilgung",
+    "vertrackt",
+    "vertrackten",
+    "vertrag",
+    "vertragen",
+    "vertrages",
+    "vertragsbestimmungen",
+    "vertragsmässig",
+    "vertragsmässige",
+    "vertraten",
+    "vertraue",
+    "vertrauen",
+    "vertrauensmann",
+    "vertraulich",
+    "vertrauliche",
+    "vertrauliches",
+    "vertraut",
+    "vertraute",
+    "vertrauten",
+    "vertrauter",
+    "vertrautester",
+    "vertreiben",
+    "vertreibenden",
+    "vertreibung",
+    "vertreter",
+    "vertretern",
+    "vertretung",
+    "vertrieb",
+    "vertrieben",
+    "vertriebenen",
+    "vertrocknen",
+    "vertrocknet",
+    "vertrockneten",
+    "vertrug",
+    "vertrunkenen",
+    "verträglich",
+    "verträgt",
+    "verträulich",
+    "verträumten",
+    "vertrödeln",
+    "vertrösten",
+    "vertröstete",
+    "vertuschen",
+    "vertäfelung",
+    "verulamio",
+    "verunglückt",
+    "verunglückten",
+    "verunreinigen",
+    "verunreinigt",
+    "verunreinigungen",
+    "verunsichert",
+    "verunstalten",
+    "verunstaltet",
+    "verursache",
+    "verursachen",
+    "verursacht",
+    "verursachte",
+    "verurteilt",
+    "verurteilung",
+    "vervielfältigen",
+    "vervielfältigend",
+    "vervielfältigt",
+    "vervielfältigten",
+    "vervielfältigungen",
+    "vervollkommnen",
+    "vervollkommnet",
+    "vervollständigen",
+    "verwahrt",
+    "verwahrte",
+    "verwahrung",
+    "verwaist",
+    "verwalten",
+    "verwaltend",
+    "verwaltenden",
+    "verwalter",
+    "verwaltete",
+    "verwaltung",
+    "verwaltungen",
+    "verwaltungsmann",
+    "verwaltungsrechnung",
+    "verwaltungsräte",
+    "verwandeln",
+    "verwandelt",
+    "verwandelte",
+    "verwandelten",
+    "verwandle",
+    "verwandlung",
+    "verwandt",
+    "verwandte",
+    "verwandten",
+    "verwandter",
+    "verwandtschaft",
+    "verwandtschaften",
+    "verwandtschaftsverhältnissen",
+    "verwarf",
+    "verwarfen",
+    "verwaschen",
+    "verwaschenen",
+    "verwaschenes",
+    "verwebt",
+    "verwechselt",
+    "verwechselten",
+    "verwegen",
+    "verwegene",
+    "verwegenen",
+    "verwehenden",
+    "verwehrt",
+    "verweht",
+    "verwehten",
+    "verweichlichen",
+    "verweigern",
+    "verweigert",
+    "verweigerte",
+    "verweigerten",
+    "verweigerung",
+    "verweilen",
+    "verweilt",
+    "verweilte",
+    "verweinte",
+    "verweis",
+    "verweise",
+    "verweisend",
+    "verweist",
+    "verweisung",
+    "verwelken",
+    "verwelkt",
+    "verwenden",
+    "verwendet",
+    "verwendete",
+    "verwendeten",
+    "verwendung",
+    "verwerfen",
+    "verwerfung",
+    "verwickeln",
+    "verwickelt",
+    "verwickelte",
+    "verwickelten",
+    "verwickelter",
+    "verwiesen",
+    "verwilderte",
+    "verwildertem",
+    "verwilderten",
+    "verwildertes",
+    "verwilderung",
+    "verwinden",
+    "verwirft",
+    "verwirklicht",
+    "verwirklichung",
+    "verwirren",
+    "verwirrende",
+    "verwirret",
+    "verwirrt",
+    "verwirrte",
+    "verwirrtem",
+    "verwirrten",
+    "verwirrung",
+    "verwirrungen",
+    "verwirrungsrecht",
+    "verwischt",
+    "verwittert",
+    "verwitterte",
+    "verwitterter",
+    "verwittertes",
+    "verworfen",
+    "verworfene",
+    "verworfnen",
+    "verworrene",
+    "verworrenen",
+    "verworrenes",
+    "verwucherten",
+    "verwunden",
+    "verwunderlicher",
+    "verwundern",
+    "verwundert",
+    "verwunderten",
+    "verwunderung",
+    "verwundet",
+    "verwundr",
+    "verwunschenen",
+    "verwässert",
+    "verwünschte",
+    "verwünschten",
+    "verwünschungen",
+    "verwüste",
+    "verwüsten",
+    "verwüstende",
+    "verwüster",
+    "verwüstet",
+    "verwüstete",
+    "verwüsteten",
+    "verwüstung",
+    "verwüstungen",
+    "verzagen",
+    "verzagt",
+    "verzagte",
+    "verzehren",
+    "verzehrende",
+    "verzehrenden",
+    "verzehrender",
+    "verzehrendes",
+    "verzehrer",
+    "verzehret",
+    "verzehrt",
+    "verzehrte",
+    "verzehrten",
+    "verzeichnen",
+    "verzeichnet",
+    "verzeichnete",
+    "verzeichnis",
+    "verzeih",
+    "verzeihen",
+    "verzeihliche",
+    "verzeihst",
+    "verzeiht",
+    "verzeihung",
+    "verzerrten",
+    "verzicht",
+    "verzichten",
+    "verzichtet",
+    "verzichtete",
+    "verzieh",
+    "verziehen",
+    "verziert",
+    "verzierte",
+    "verzierungen",
+    "verzog",
+    "verzogen",
+    "verzogene",
+    "verzweifelt",
+    "verzweifelte",
+    "verzweifelten",
+    "verzweifeltsten",
+    "verzweiflung",
+    "verzweiflungsvolles",
+    "verzwickten",
+    "verzählen",
+    "verzählt",
+    "verzäuner",
+    "verzögert",
+    "verzögerte",
+    "verächtlich",
+    "verächtlichen",
+    "verähnlicht",
+    "verähnlichten",
+    "verändere",
+    "veränderlichkeit",
+    "verändern",
+    "verändert",
+    "veränderte",
+    "veränderten",
+    "verändertes",
+    "veränderung",
+    "veränderungen",
+    "veränderungsleistung",
+    "verängstigt",
+    "verödenden",
+    "verödet",
+    "verödeten",
+    "verödung",
+    "verödungen",
+    "veröffentlicht",
+    "veröffentlichung",
+    "verüben",
+    "verübt",
+    "verübte",
+    "vesperbrote",
+    "vesperkaffees",
+    "vestalinnen",
+    "vesuvius",
+    "veterinärschul",
+    "veto",
+    "vetsch",
+    "vetter",
+    "vetters",
+    "vexieren",
+    "vexiergasse",
+    "vfb",
+    "vi",
+    "vicomtesse",
+    "victory",
+    "vide",
+    "videofilmer",
+    "videokamera",
+    "viecherl",
+    "vieh",
+    "viehhandel",
+    "viehherden",
+    "viehhürdenbewohner",
+    "viehs",
+    "viehzucht",
+    "viel",
+    "vielbesucht",
+    "viele",
+    "vielem",
+    "vielen",
+    "vieler",
+    "vielerlei",
+    "vieles",
+    "vielfach",
+    "vielfache",
+    "vielfaches",
+    "vielfalt",
+    "vielfältig",
+    "vielfältige",
+    "vielfältiger",
+    "vielgestaltige",
+    "vielgewordene",
+    "vieljährige",
+    "vielköpfigen",
+    "vielle",
+    "vielleicht",
+    "vielmals",
+    "vielmehr",
+    "vielverschlungenen",
+    "vielweniger",
+    "vier",
+    "viereckig",
+    "viereckigen",
+    "viereckiger",
+    "viereinhalb",
+    "vieren",
+    "vierhundertdrei",
+    "vierhundertdreissig",
+    "vierhundertdreißig",
+    "vierhundertertundzweimal",
+    "vierjährigen",
+    "vierköpfige",
+    "viermal",
+    "vierschrötige",
+    "vierspännige",
+    "vierspännigen",
+    "vierstündige",
+    "vierte",
+    "viertel",
+    "vierteljahr",
+    "vierteljahrhundert",
+    "vierteljährchen",
+    "viertelstunde",
+    "viertelstündchen",
+    "viertelstündl",
+    "viertelswandseite",
+    "vierten",
+    "viertes",
+    "viertägige",
+    "vierunddreißig",
+    "vierundzwanzig",
+    "vierundzwanzigstündiger",
+    "vierzehn",
+    "vierzehnten",
+    "vierzig",
+    "vierzigjähriger",
+    "vierzigste",
+    "vii",
+    "viii",
+    "villarson",
+    "violettes",
+    "viper",
+    "vipern",
+    "vipernarten",
+    "virginien",
+    "virtuelle",
+    "virtuosen",
+    "visiers",
+    "vision",
+    "visir",
+    "visitenstuben",
+    "vita",
+    "vital",
+    "vitale",
+    "vitalen",
+    "vitaler",
+    "vitalitat",
+    "vitalität",
+    "vitaminreiche",
+    "vitium",
+    "vivificandum",
+    "vogel",
+    "vogelbeerbüscheln",
+    "vogelbeeren",
+    "vogels",
+    "vogelstellers",
+    "vogelstimmen",
+    "vokabeln",
+    "vokabular",
+    "vokativ",
+    "vokative",
+    "volcker",
+    "volk",
+    "volke",
+    "volkes",
+    "volks",
+    "volksfest",
+    "volkskircheflavio",
+    "volksliedchen",
+    "volkslieder",
+    "volksliedes",
+    "volkssänger",
+    "volksversammlung",
+    "volksweisen",
+    "voll",
+    "vollauf",
+    "vollbart",
+    "vollbesetzt",
+    "vollbestand",
+    "vollbracht",
+    "vollbrachte",
+    "vollbrachten",
+    "vollbrachter",
+    "vollbrachtes",
+    "vollbringen",
+    "vollbringung",
+    "volle",
+    "vollem",
+    "vollen",
+    "vollenden",
+    "vollendet",
+    "vollendete",
+    "vollendeten",
+    "vollends",
+    "vollendung",
+    "voller",
+    "vollführt",
+    "vollführte",
+    "vollgepfropften",
+    "vollgestopft",
+    "vollkommen",
+    "vollkommene",
+    "vollkommenen",
+    "vollkommener",
+    "vollkommenere",
+    "vollkommeneren",
+    "vollkommenerer",
+    "vollkommeneres",
+    "vollkommenheit",
+    "vollkommenheiten",
+    "vollkommenste",
+    "vollkommensten",
+    "vollmacht",
+    "vollmond",
+    "vollsten",
+    "vollster",
+    "vollstreckung",
+    "vollständig",
+    "vollständige",
+    "vollständigen",
+    "vollständiger",
+    "vollständiges",
+    "vollständigkeit",
+    "vollziehend",
+    "vollziehest",
+    "vollzogen",
+    "vollzupacken",
+    "vollzählig",
+    "voluntaria",
+    "voluntarium",
+    "vom",
+    "von",
+    "voneinander",
+    "vonstatten",
+    "vor",
+    "vorabend",
+    "vorabendan",
+    "voran",
+    "vorangehen",
+    "vorangeht",
+    "vorangestellt",
+    "voranging",
+    "voranschickung",
+    "voranstellte",
+    "vorantrug",
+    "voranzuschicken",
+    "voraus",
+    "vorausfliegen",
+    "vorausgefahrenen",
+    "vorausgehen",
+    "vorausgehende",
+    "vorausgeht",
+    "vorausgeigen",
+    "vorausgesagt",
+    "vorausgeschickt",
+    "vorausgeschickten",
+    "vorausgesehen",
+    "vorausgesetzt",
+    "vorausnehmen",
+    "voraussah",
+    "voraussahen",
+    "voraussehen",
+    "voraussetze",
+    "voraussetzen",
+    "voraussetzet",
+    "voraussetzt",
+    "voraussetzte",
+    "voraussetzung",
+    "voraussetzungen",
+    "voraussicht",
+    "voraussichtlich",
+    "vorauswissen",
+    "vorauszufahren",
+    "vorauszuschickenden",
+    "vorauszusehen",
+    "vorauszusetzen",
+    "vorbedacht",
+    "vorbedachten",
+    "vorbei",
+    "vorbeigehn",
+    "vorbeikam",
+    "vorbeireitens",
+    "vorbeistob",
+    "vorbeizogen",
+    "vorbemerkungen",
+    "vorbereitender",
+    "vorbereitet",
+    "vorbereitete",
+    "vorbereitung",
+    "vorbereitungen",
+    "vorberg",
+    "vorbestimmt",
+    "vorbeugen",
+    "vorbild",
+    "vorbilde",
+    "vorbilden",
+    "vorbildende",
+    "vorbilder",
+    "vorbildern",
+    "vorbildet",
+    "vorbildete",
+    "vorbildeten",
+    "vorbildlich",
+    "vorbildliche",
+    "vorbildlichen",
+    "vorbildliches",
+    "vorbildung",
+    "vorbildungen",
+    "vorbrachte",
+    "vorbrechenden",
+    "vorbringen",
+    "vordem",
+    "vordere",
+    "vorderen",
+    "vordergrund",
+    "vorderhand",
+    "vorderkrällchen",
+    "vorderriss",
+    "vordersten",
+    "vorderster",
+    "vordringen",
+    "voreilig",
+    "voreiligen",
+    "voreinander",
+    "voreingenommenheit",
+    "voreltern",
+    "vorenthalten",
+    "vorenthielt",
+    "vorerst",
+    "vorfahren",
+    "vorfall",
+    "vorfalle",
+    "vorfalles",
+    "vorfand",
+    "vorfiel",
+    "vorfielen",
+    "vorfinden",
+    "vorfindet",
+    "vorfälle",
+    "vorg",
+    "vorgab",
+    "vorgang",
+    "vorganges",
+    "vorgeben",
+    "vorgebeugt",
+    "vorgebildet",
+    "vorgebildete",
+    "vorgebildeten",
+    "vorgebogenem",
+    "vorgebracht",
+    "vorgebrachten",
+    "vorgefahren",
+    "vorgefassten",
+    "vorgefaßten",
+    "vorgefühl",
+    "vorgegangen",
+    "vorgehalten",
+    "vorgehe",
+    "vorgehen",
+    "vorgehenden",
+    "vorgeht",
+    "vorgeladen",
+    "vorgelassen",
+    "vorgelegt",
+    "vorgelegten",
+    "vorgemacht",
+    "vorgenommen",
+    "vorgenommene",
+    "vorgerückt",
+    "vorgerückteren",
+    "vorgesagt",
+    "vorgeschlagen",
+    "vorgeschoben",
+    "vorgeschrieben",
+    "vorgeschriebene",
+    "vorgesehen",
+    "vorgesehene",
+    "vorgesetzt",
+    "vorgesetzte",
+    "vorgesetzten",
+    "vorgesetzter",
+    "vorgesetztes",
+    "vorgesteckt",
+    "vorgestellet",
+    "vorgestellt",
+    "vorgestern",
+    "vorgestreckten",
+    "vorgetragen",
+    "vorgezogen",
+    "vorgezogene",
+    "vorging",
+    "vorgänger",
+    "vorhabe",
+    "vorhaben",
+    "vorhabenden",
+    "vorhalle",
+    "vorhalten",
+    "vorhanden",
+    "vorhandenen",
+    "vorhang",
+    "vorhangs",
+    "vorhast",
+    "vorhat",
+    "vorhatte",
+    "vorhatten",
+    "vorhaut",
+    "vorhemdchen",
+    "vorhemden",
+    "vorher",
+    "vorherbestimmt",
+    "vorherbestimmten",
+    "vorherbestimmung",
+    "vorhergegangen",
+    "vorhergehen",
+    "vorhergehend",
+    "vorhergehende",
+    "vorhergehenden",
+    "vorhergehendes",
+    "vorhergehn",
+    "vorhergeht",
+    "vorhergenannten",
+    "vorhergesagt",
+    "vorhergesehen",
+    "vorherging",
+    "vorherigen",
+    "vorherrschaft",
+    "vorherrschende",
+    "vorherrscht",
+    "vorhersage",
+    "vorhersah",
+    "vorhersehe",
+    "vorhersehen",
+    "vorhersehung",
+    "vorhersieht",
+    "vorherverkündigung",
+    "vorhielte",
+    "vorhin",
+    "vorhof",
+    "vorhofes",
+    "vorhofs",
+    "vorhält",
+    "vorhänge",
+    "vorhöfe",
+    "vorhöfen",
+    "vorige",
+    "vorigen",
+    "voriger",
+    "vorkam",
+    "vorkamen",
+    "vorkomme",
+    "vorkommen",
+    "vorkommenden",
+    "vorkommendenfalls",
+    "vorkommt",
+    "vorlaut",
+    "vorlegen",
+    "vorlegung",
+    "vorlesen",
+    "vorlesung",
+    "vorlieb",
+    "vorliebe",
+    "vorliegenden",
+    "vorliegt",
+    "vorläufer",
+    "vorläufererfahrungen",
+    "vorläufig",
+    "vorläufigen",
+    "vorläßt",
+    "vormachen",
+    "vormachte",
+    "vormals",
+    "vormittag",
+    "vormittags",
+    "vormittagsarbeit",
+    "vormittagsimbiss",
+    "vormittagsstunden",
+    "vormund",
+    "vormundschaftliche",
+    "vormöge",
+    "vormünder",
+    "vorn",
+    "vornahm",
+    "vorne",
+    "vorneherein",
+    "vornehm",
+    "vornehme",
+    "vornehmen",
+    "vornehmer",
+    "vornehmes",
+    "vornehmheit",
+    "vornehmlich",
+    "vornehmste",
+    "vornehmsten",
+    "vornherein",
+    "vornüber",
+    "vorort",
+    "vorragendste",
+    "vorrang",
+    "vorrat",
+    "vorratshause",
+    "vorratshäuser",
+    "vorratshäusern",
+    "vorratslager",
+    "vorraum",
+    "vorrecht",
+    "vorrechte",
+    "vorrede",
+    "vorrichtungen",
+    "vorräte",
+    "vors",
+    "vorsagen",
+    "vorsagte",
+    "vorsatz",
+    "vorsatze",
+    "vorschein",
+    "vorschlag",
+    "vorschlagen",
+    "vorschlug",
+    "vorschläge",
+    "vorschneider",
+    "vorschreibt",
+    "vorschrift",
+    "vorschriften",
+    "vorschub",
+    "vorschulalter",
+    "vorschule",
+    "vorschussmilliarde",
+    "vorschützen",
+    "vorsehen",
+    "vorsehung",
+    "vorsetzen",
+    "vorsetzlich",
+    "vorsetzt",
+    "vorsetzte",
+    "vorsicht",
+    "vorsichtig",
+    "vorsichtigem",
+    "vorsichtiger",
+    "vorsieht",
+    "vorsingen",
+    "vorsitz",
+    "vorsitze",
+    "vorsitzender",
+    "vorsorge",
+    "vorspannt",
+    "vorspiegelungen",
+    "vorspiel",
+    "vorsprung",
+    "vorstadt",
+    "vorstadtstrasse",
+    "vorstadtstraße",
+    "vorstand",
+    "vorstandes",
+    "vorstehenden",
+    "vorsteherin",
+    "vorstelle",
+    "vorstellen",
+    "vorstellend",
+    "vorstelleten",
+    "vorstellte",
+    "vorstellten",
+    "vorstellung",
+    "vorstellungen",
+    "vorstellungsart",
+    "vorstellungsweise",
+    "vorsätzlich",
+    "vorsätzliche",
+    "vorsündflutlichen",
+    "vorteil",
+    "vorteile",
+    "vorteilen",
+    "vorteilhaft",
+    "vorteilhaften",
+    "vorteilhaftes",
+    "vorteilhaftesten",
+    "vorteils",
+    "vortrag",
+    "vortrage",
+    "vortragen",
+    "vortrefflich",
+    "vortreffliche",
+    "vortrefflichen",
+    "vortrefflicher",
+    "vortrefflichkeit",
+    "vortrefflichste",
+    "vortritt",
+    "vortrug",
+    "vorträge",
+    "vorträgen",
+    "vorträgt",
+    "vortuch",
+    "vortuchbandeln",
+    "vorurteilen",
+    "vorverhandlungen",
+    "vorversammlungen",
+    "vorwalten",
+    "vorwaltender",
+    "vorwaltet",
+    "vorwand",
+    "vorwegstiehlt",
+    "vorweihnachtlichen",
+    "vorweisen",
+    "vorwelt",
+    "vorwerfen",
+    "vorwiegend",
+    "vorwort",
+    "vorwurf",
+    "vorwärts",
+    "vorwärtsgehen",
+    "vorwärtszukommen",
+    "vorwürfe",
+    "vorwürfen",
+    "vorzeichen",
+    "vorzeichnung",
+    "vorzeit",
+    "vorziehen",
+    "vorzieht",
+    "vorzimmer",
+    "vorzimmern",
+    "vorzimmers",
+    "vorzog",
+    "vorzogen",
+    "vorzubereiten",
+    "vorzubilden",
+    "vorzubringen",
+    "vorzufliegen",
+    "vorzug",
+    "vorzuge",
+    "vorzugeben",
+    "vorzuges",
+    "vorzugsweise",
+    "vorzuhalten",
+    "vorzumachen",
+    "vorzunehmen",
+    "vorzurücken",
+    "vorzuschlagen",
+    "vorzuspielen",
+    "vorzustellen",
+    "vorzuwerfen",
+    "vorzuziehen",
+    "vorzüge",
+    "vorzügen",
+    "vorzüglich",
+    "vorzügliche",
+    "vorzüglichen",
+    "vorzügliches",
+    "vorzüglichste",
+    "vorzüglichsten",
+    "vorzüglichstes",
+    "vorüber",
+    "vorüberdrehte",
+    "vorübergegangen",
+    "vorübergehen",
+    "vorübergehend",
+    "vorübergehenden",
+    "vorübergehendste",
+    "vorübergeht",
+    "vorüberging",
+    "vorübergingen",
+    "vorüberkam",
+    "vorüberkamen",
+    "vorüberlässt",
+    "vorüberreiten",
+    "vorüberschwebte",
+    "vorüberwallte",
+    "vorüberwehen",
+    "vorüberzieht",
+    "vox",
+    "vreeli",
+    "vrenchen",
+    "vrenchens",
+    "vrenches",
+    "vrenggel",
+    "vroni",
+    "vulgo",
+    "vulkan",
+    "väter",
+    "väterliche",
+    "väterlichen",
+    "väterliches",
+    "vätern",
+    "vögel",
+    "vögelchen",
+    "vögeln",
+    "vöglein",
+    "vögte",
+    "vögten",
+    "völkchen",
+    "völker",
+    "völkern",
+    "völkerschaft",
+    "völkerschaften",
+    "völkerstrich",
+    "völkerwanderung",
+    "völklein",
+    "völlerei",
+    "völlig",
+    "völlige",
+    "völligem",
+    "völligen",
+    "völliger",
+    "w",
+    "waadtländer",
+    "waage",
+    "waagwirt",
+    "waagwirtes",
+    "waar",
+    "wach",
+    "wachdienst",
+    "wache",
+    "wachen",
+    "wachenden",
+    "wachender",
+    "wacherhalten",
+    "wachet",
+    "wacholderkraut",
+    "wachs",
+    "wachsam",
+    "wachsames",
+    "wachsen",
+    "wachsende",
+    "wachsenden",
+    "wachsender",
+    "wachskerzen",
+    "wachsleinwand",
+    "wachslichter",
+    "wachstum",
+    "wachsweissem",
+    "wacht",
+    "wachte",
+    "wachtel",
+    "wachteln",
+    "wachtmeister",
+    "wackeligen",
+    "wackeln",
+    "wackelte",
+    "wacker",
+    "wackere",
+    "wackeren",
+    "wackeres",
+    "wackern",
+    "waden",
+    "wadenmuskeln",
+    "wadenstrümpfen",
+    "waffe",
+    "waffeleisen",
+    "waffen",
+    "waffenstillstand",
+    "wagemutes",
+    "wagen",
+    "wagenpolster",
+    "wagens",
+    "wagenschmiere",
+    "wagenverkehr",
+    "wagerl",
+    "waghalsigen",
+    "wagner",
+    "wagt",
+    "wagte",
+    "wagten",
+    "wahl",
+    "wahlablauf",
+    "wahlbehinderung",
+    "wahlbenachrichtigung",
+    "wahlbeobachter",
+    "wahlbeobachterinnen",
+    "wahlbeobachtung",
+    "wahlberechtigte",
+    "wahlberechtigten",
+    "wahlberechtigung",
+    "wahlberliner",
+    "wahlbezirken",
+    "wahlbüro",
+    "wahlbüros",
+    "wahlen",
+    "wahlergebnis",
+    "wahlergebnisse",
+    "wahlgeschäft",
+    "wahlhandlung",
+    "wahlhelfer",
+    "wahljahr",
+    "wahlkabine",
+    "wahlkampagne",
+    "wahlkampf",
+    "wahlkampfes",
+    "wahlkommission",
+    "wahlkommissionen",
+    "wahlkreis",
+    "wahlkundgebung",
+    "wahlleiter",
+    "wahlleiters",
+    "wahlliste",
+    "wahllisten",
+    "wahllokal",
+    "wahlmorgen",
+    "wahlspruch",
+    "wahltag",
+    "wahltage",
+    "wahlumschlag",
+    "wahlunregelmässigkeiten",
+    "wahlurnen",
+    "wahlvorgang",
+    "wahlvorgängen",
+    "wahlzettel",
+    "wahn",
+    "wahne",
+    "wahnsinn",
+    "wahnsinnig",
+    "wahnsinns",
+    "wahr",
+    "wahre",
+    "wahrem",
+    "wahren",
+    "wahrer",
+    "wahres",
+    "wahrgenommen",
+    "wahrhaft",
+    "wahrhafte",
+    "wahrhaftig",
+    "wahrhaftige",
+    "wahrhaftiger",
+    "wahrhaftigkeit",
+    "wahrhaftigsten",
+    "wahrheit",
+    "wahrheiten",
+    "wahrlich",
+    "wahrnahm",
+    "wahrnehmbar",
+    "wahrnehmbarer",
+    "wahrnehme",
+    "wahrnehmen",
+    "wahrnehmung",
+    "wahrnehmungen",
+    "wahrnimmt",
+    "wahrsagen",
+    "wahrsager",
+    "wahrsagern",
+    "wahrschein",
+    "wahrscheinlich",
+    "wahrscheinlicherweise",
+    "wahrscheinlichkeit",
+    "wahrscheinlichste",
+    "wahrte",
+    "wahrzeichen",
+    "wahrzunehmen",
+    "waisen",
+    "waisenfelde",
+    "waisenkind",
+    "waisenvogt",
+    "walchensee",
+    "walchental",
+    "wald",
+    "waldboden",
+    "walde",
+    "waldecke",
+    "waldes",
+    "waldesel",
+    "waldflächen",
+    "waldfrevel",
+    "waldfrohen",
+    "waldgras",
+    "waldgrund",
+    "waldhorn",
+    "waldhornbläser",
+    "waldigen",
+    "waldmoose",
+    "waldpfade",
+    "waldpfades",
+    "waldrand",
+    "waldrevier",
+    "waldrücken",
+    "waldsaum",
+    "waldstrasse",
+    "waldsträsschen",
+    "waldstämme",
+    "waldtiere",
+    "waldungen",
+    "waldwegen",
+    "waldweges",
+    "walfisch",
+    "walfische",
+    "wallen",
+    "wallende",
+    "wallte",
+    "walten",
+    "waltende",
+    "waltet",
+    "waltete",
+    "walzer",
+    "wammen",
+    "wams",
+    "wand",
+    "wandel",
+    "wandellose",
+    "wandeln",
+    "wandelnd",
+    "wandelnde",
+    "wandelnden",
+    "wandelndes",
+    "wandels",
+    "wandelt",
+    "wandelte",
+    "wandelten",
+    "wanderbuch",
+    "wanderbum",
+    "wanderburn",
+    "wanderbücher",
+    "wanderer",
+    "wandergeräte",
+    "wanderjünglinge",
+    "wandern",
+    "wanderschaft",
+    "wandert",
+    "wanderte",
+    "wanderten",
+    "wanderung",
+    "wanderungen",
+    "wanderzeit",
+    "wandle",
+    "wandrer",
+    "wandschränkchen",
+    "wandte",
+    "wandten",
+    "wanduhr",
+    "wandwinkel",
+    "wange",
+    "wangen",
+    "wanke",
+    "wanken",
+    "wann",
+    "wanne",
+    "wannenbreit",
+    "wanze",
+    "wanzen",
+    "wapp",
+    "wappen",
+    "war",
+    "ward",
+    "ware",
+    "waren",
+    "warenlager",
+    "warenmagazinen",
+    "warf",
+    "warfen",
+    "warm",
+    "warme",
+    "warmen",
+    "warmer",
+    "warmes",
+    "warne",
+    "warnend",
+    "warnenden",
+    "warnt",
+    "warnung",
+    "warnungsruf",
+    "warst",
+    "wart",
+    "warte",
+    "wartei",
+    "warteliste",
+    "warten",
+    "wartendes",
+    "wartet",
+    "wartete",
+    "warteten",
+    "wartst",
+    "warum",
+    "was",
+    "waschbottich",
+    "waschbündel",
+    "waschen",
+    "waschtisch",
+    "waschungen",
+    "washingtoner",
+    "wasser",
+    "wasseramsel",
+    "wasseraugen",
+    "wasserblaue",
+    "wasserdünste",
+    "wassereimer",
+    "wasserflut",
+    "wasserfluten",
+    "wasserfläche",
+    "wasserfrau",
+    "wasserfürsten",
+    "wassergegend",
+    "wassergewimmels",
+    "wasserhose",
+    "wasserhosen",
+    "wasserleitung",
+    "wasserleitungen",
+    "wassermann",
+    "wassermanns",
+    "wassern",
+    "wassernixen",
+    "wasserpfuscherei",
+    "wasserpolacken",
+    "wasserquelle",
+    "wasserquellen",
+    "wasserrosen",
+    "wassers",
+    "wasserscheu",
+    "wasserschlange",
+    "wasserschlangen",
+    "wassersee",
+    "wasserseen",
+    "wasserspritzen",
+    "wasserstar",
+    "wasserstrahl",
+    "wasserstreif",
+    "wassersturz",
+    "wassertrog",
+    "wassertropfen",
+    "wasserträger",
+    "wassertümpel",
+    "waste",
+    "watteweissen",
+    "wattierten",
+    "watzmann",
+    "weben",
+    "weber",
+    "weberschiffchen",
+    "website",
+    "webt",
+    "webte",
+    "wechsel",
+    "wechselbalg",
+    "wechselbriefe",
+    "wechselgesang",
+    "wechseln",
+    "wechselnden",
+    "wechselseitig",
+    "wechselt",
+    "wechselte",
+    "wechselten",
+    "weck",
+    "wecken",
+    "weckt",
+    "weckte",
+    "weckten",
+    "wedelnden",
+    "weder",
+    "weg",
+    "wegbereit",
+    "wegbiegung",
+    "wegbleiben",
+    "wegblendete",
+    "wegblickt",
+    "wegbringen",
+    "wege",
+    "wegekrautes",
+    "wegen",
+    "weges",
+    "wegfahren",
+    "wegfliegen",
+    "wegführe",
+    "wegführen",
+    "wegg",
+    "weggebracht",
+    "weggefahren",
+    "weggefallen",
+    "weggefegt",
+    "weggeflogen",
+    "weggeführt",
+    "weggegangen",
+    "weggehen",
+    "weggehoben",
+    "weggeholt",
+    "weggekommen",
+    "weggeleugnet",
+    "weggenommen",
+    "weggerafft",
+    "weggerissen",
+    "weggeschafft",
+    "weggeschoben",
+    "weggespült",
+    "weggestorben",
+    "weggetrieben",
+    "weggewendet",
+    "weggeworfen",
+    "weggezerrt",
+    "weggezogen",
+    "weggingest",
+    "weggucht",
+    "wegkommen",
+    "wegliessen",
+    "wegmachen",
+    "wegmarschierte",
+    "wegnahme",
+    "wegnehme",
+    "wegnehmen",
+    "wegnimmt",
+    "wegrichtung",
+    "wegräumung",
+    "wegsah",
+    "wegschaffen",
+    "wegschaffung",
+    "wegsetzt",
+    "wegsortiert",
+    "wegspringen",
+    "wegstiesse",
+    "wegstrebten",
+    "wegströmte",
+    "wegstunde",
+    "wegtragen",
+    "wegtreiben",
+    "wegtreibt",
+    "wegtrieb",
+    "wegtrieben",
+    "wegvernünfteln",
+    "wegwenden",
+    "wegwerfen",
+    "wegwerfend",
+    "wegwischen",
+    "wegwürfe",
+    "wegzogen",
+    "wegzubringen",
+    "wegzufahren",
+    "wegzufangen",
+    "wegzugehen",
+    "wegzuheben",
+    "wegzuholen",
+    "wegzukommen",
+    "wegzumachen",
+    "wegzunehmen",
+    "wegzupraktizieren",
+    "wegzuraffen",
+    "wegzuschaffen",
+    "wegzuschicken",
+    "weh",
+    "wehe",
+    "wehen",
+    "wehendem",
+    "wehenden",
+    "wehes",
+    "wehet",
+    "wehetuender",
+    "wehklage",
+    "wehklagen",
+    "wehklagenden",
+    "wehmut",
+    "wehmutsvoll",
+    "wehmutter",
+    "wehmütig",
+    "wehmütige",
+    "wehmütter",
+    "wehmüttern",
+    "weho",
+    "wehr",
+    "wehrbaren",
+    "wehrbarkeit",
+    "wehrdienst",
+    "wehren",
+    "wehrend",
+    "wehret",
+    "wehrhaft",
+    "wehrhaften",
+    "wehrlos",
+    "wehrlosen",
+    "wehrte",
+    "wehselig",
+    "weht",
+    "wehte",
+    "wehten",
+    "wehtun",
+    "wehwehchen",
+    "weib",
+    "weibchen",
+    "weibe",
+    "weibel",
+    "weiber",
+    "weibergut",
+    "weibergute",
+    "weibergutes",
+    "weiberhaare",
+    "weiberkleidern",
+    "weiberl",
+    "weiberlaunischen",
+    "weibern",
+    "weibernücken",
+    "weiberschuhe",
+    "weibervolk",
+    "weibes",
+    "weiblein",
+    "weibleins",
+    "weiblich",
+    "weibliche",
+    "weiblichen",
+    "weiblicher",
+    "weibliches",
+    "weiblichkeit",
+    "weibsbild",
+    "weibsbilder",
+    "weibsleut",
+    "weibsleute",
+    "weibsperson",
+    "weibstück",
+    "weich",
+    "weiche",
+    "weichen",
+    "weicher",
+    "weicheren",
+    "weiches",
+    "weichet",
+    "weichherzigen",
+    "weichlichen",
+    "weichquark",
+    "weichsel",
+    "weichste",
+    "weicht",
+    "weide",
+    "weiden",
+    "weidenbaum",
+    "weidenden",
+    "weidenringe",
+    "weidenrosen",
+    "weidenröschen",
+    "weidenstauden",
+    "weidet",
+    "weidete",
+    "weidgerechteste",
+    "weidlich",
+    "weidmann",
+    "weidmannes",
+    "weidmannsfreude",
+    "weidmannspech",
+    "weidmannstod",
+    "weidwerk",
+    "weidwund",
+    "weigern",
+    "weigerst",
+    "weigert",
+    "weigerung",
+    "weih",
+    "weihbischof",
+    "weihe",
+    "weihen",
+    "weihevollen",
+    "weihnachten",
+    "weihnachtsbäumchen",
+    "weihnachtsbäumen",
+    "weihnachtsengelchen",
+    "weihnachtsgarten",
+    "weihnachtslied",
+    "weihnachtsmann",
+    "weihnachtsnüsse",
+    "weihnachtsstube",
+    "weihnachtssüssigkeiten",
+    "weihnachtstisch",
+    "weihnachtsvesper",
+    "weihnachtswiese",
+    "weihrauch",
+    "weil",
+    "weilchen",
+    "weile",
+    "weilen",
+    "weilt",
+    "weilte",
+    "wein",
+    "weinbeere",
+    "weinberg",
+    "weinberge",
+    "weinbergswachteln",
+    "weine",
+    "weinen",
+    "weinend",
+    "weinendes",
+    "weinerlich",
+    "weines",
+    "weinet",
+    "weinflaschen",
+    "weingefässe",
+    "weingefäße",
+    "weingärtner",
+    "weinreben",
+    "weinrot",
+    "weins",
+    "weinsorten",
+    "weinstock",
+    "weint",
+    "weinte",
+    "weinten",
+    "weintrinken",
+    "weintrinker",
+    "weise",
+    "weisen",
+    "weiser",
+    "weisesten",
+    "weisheit",
+    "weisheiten",
+    "weisheitslehre",
+    "weisheitslehrer",
+    "weislich",
+    "weiss",
+    "weissage",
+    "weissagen",
+    "weissagenden",
+    "weissagt",
+    "weissagte",
+    "weissagung",
+    "weissbefilzte",
+    "weissbrot",
+    "weisse",
+    "weissem",
+    "weissen",
+    "weisser",
+    "weisseren",
+    "weisses",
+    "weissgetünchten",
+    "weissgetüpfeltem",
+    "weissglasierte",
+    "weisshaarige",
+    "weisshaupt",
+    "weisskohl",
+    "weissleinen",
+    "weissseidenen",
+    "weisst",
+    "weist",
+    "weisung",
+    "weisungen",
+    "weisungsbefugnisse",
+    "weit",
+    "weite",
+    "weitem",
+    "weiten",
+    "weitentlegenen",
+    "weiter",
+    "weitere",
+    "weiterem",
+    "weiteren",
+    "weiterer",
+    "weiteres",
+    "weiterfliehen",
+    "weiterführen",
+    "weitergang",
+    "weitergebildet",
+    "weitergebracht",
+    "weitergegangen",
+    "weitergegeben",
+    "weitergehen",
+    "weitergezogen",
+    "weiterhin",
+    "weiterjagte",
+    "weitern",
+    "weiterrutschte",
+    "weiters",
+    "weiterschritten",
+    "weitersprach",
+    "weiterverkauft",
+    "weiterziehen",
+    "weiterzogen",
+    "weiterzuführen",
+    "weiterzureden",
+    "weites",
+    "weitesten",
+    "weitgedehnte",
+    "weitgehend",
+    "weitgespannten",
+    "weitherausstehenden",
+    "weithin",
+    "weithingestreckt",
+    "weitläufig",
+    "weitläufige",
+    "weitläufigen",
+    "weitschwingende",
+    "weizen",
+    "weizenbrot",
+    "weizenmehl",
+    "weizens",
+    "weiß",
+    "weißbefilzte",
+    "weiße",
+    "weißem",
+    "weißen",
+    "weißer",
+    "weißes",
+    "weißgetünchten",
+    "weißhaarige",
+    "weißleinen",
+    "weißseidenen",
+    "weißt",
+    "welch",
+    "welche",
+    "welchem",
+    "welchen",
+    "welcher",
+    "welcherart",
+    "welcherlei",
+    "welches",
+    "welk",
+    "welken",
+    "welkenden",
+    "welle",
+    "wellen",
+    "wellenfurchen",
+    "welt",
+    "weltall",
+    "weltalls",
+    "weltanschauung",
+    "weltanschauungfragen",
+    "weltbau",
+    "weltbegebenheiten",
+    "weltbetrachtung",
+    "weltbewegenden",
+    "weltbund",
+    "weltbundes",
+    "welten",
+    "weltenmenge",
+    "weltenraum",
+    "weltgebäudes",
+    "weltkongress",
+    "weltkreis",
+    "weltkreises",
+    "weltkrieg",
+    "weltkriegs",
+    "weltkörper",
+    "weltlich",
+    "weltliche",
+    "weltlichen",
+    "weltlicher",
+    "weltliches",
+    "weltlicht",
+    "weltliebe",
+    "weltmann",
+    "weltmeers",
+    "weltnacht",
+    "weltordnung",
+    "weltregierer",
+    "weltschmerzlich",
+    "weltschöpfer",
+    "weltstrome",
+    "weltteilen",
+    "welturheber",
+    "welturhebers",
+    "weltursachen",
+    "weltverantwortung",
+    "weltweisheit",
+    "weltweit",
+    "wem",
+    "wen",
+    "wende",
+    "wenden",
+    "wendend",
+    "wendenden",
+    "wendet",
+    "wendete",
+    "wendeten",
+    "wendrich",
+    "wendung",
+    "wendungen",
+    "wenig",
+    "wenige",
+    "wenigem",
+    "wenigen",
+    "weniger",
+    "wenigere",
+    "weniges",
+    "wenigsten",
+    "wenigstens",
+    "wenn",
+    "wenngleich",
+    "wenzel",
+    "wer",
+    "werbebeschränkungen",
+    "werben",
+    "werd",
+    "werde",
+    "werden",
+    "werdend",
+    "werdende",
+    "werdenden",
+    "werdest",
+    "werdet",
+    "werfe",
+    "werfen",
+    "werften",
+    "werg",
+    "wergg",
+    "werk",
+    "werke",
+    "werkeltagskleider",
+    "werken",
+    "werkes",
+    "werkführer",
+    "werkführers",
+    "werkmeister",
+    "werkstatt",
+    "werktag",
+    "werktagen",
+    "werktagsmontur",
+    "werktätigen",
+    "werktätigkeit",
+    "werkzeug",
+    "werkzeuge",
+    "werkzeugliches",
+    "wert",
+    "werte",
+    "werten",
+    "werterziehung",
+    "werteste",
+    "werteverfall",
+    "wertlos",
+    "wertlose",
+    "wertlosigkeit",
+    "werts",
+    "wertvoll",
+    "wertvolle",
+    "wertvollen",
+    "wertvollste",
+    "wes",
+    "wesen",
+    "wesenlosen",
+    "wesens",
+    "wesentlich",
+    "wesentliche",
+    "wesentlichen",
+    "wesentlicher",
+    "wesentliches",
+    "wesentlichste",
+    "weshalb",
+    "wespe",
+    "wessen",
+    "westdeutschen",
+    "westdeutscher",
+    "westdeutschland",
+    "weste",
+    "westen",
+    "westenstoff",
+    "westenstreifen",
+    "westfalen",
+    "westfirmen",
+    "westlertum",
+    "westlichen",
+    "westliches",
+    "westschweizer",
+    "westtürkischen",
+    "westwind",
+    "westwindes",
+    "weswegen",
+    "wette",
+    "wetteifern",
+    "wetteiferten",
+    "wetten",
+    "wetter",
+    "wetterbeschädigten",
+    "wetternacht",
+    "wetters",
+    "wetterwendisch",
+    "wetterwind",
+    "wettkampf",
+    "wettlauf",
+    "wettlaufens",
+    "wettstreit",
+    "wetzend",
+    "wetzte",
+    "wich",
+    "wichen",
+    "wichte",
+    "wichtig",
+    "wichtige",
+    "wichtigem",
+    "wichtigen",
+    "wichtiger",
+    "wichtigere",
+    "wichtigeren",
+    "wichtiges",
+    "wichtigkeit",
+    "wichtigste",
+    "wichtigsten",
+    "wichtigtuerei",
+    "wickelkindchen",
+    "wickelte",
+    "widder",
+    "widderfellen",
+    "widdern",
+    "widders",
+    "widerfahre",
+    "widerfahren",
+    "widerfuhr",
+    "widerfährt",
+    "widergehallt",
+    "widergöttlich",
+    "widerhaarig",
+    "widerhall",
+    "widerhallte",
+    "widerhallten",
+    "widerlegen",
+    "widerlegt",
+    "widerlegung",
+    "widerlegungen",
+    "widerlich",
+    "widerlichen",
+    "widerred",
+    "widerrede",
+    "widersacher",
+    "widerschein",
+    "widersetzen",
+    "widersetzlichkeit",
+    "widersetzt",
+    "widersetzte",
+    "widersetzten",
+    "widersetzung",
+    "widersinnig",
+    "widersinnisch",
+    "widerspenstig",
+    "widerspenstige",
+    "widerspenstigsten",
+    "widerspiele",
+    "widersprach",
+    "widerspreche",
+    "widersprechen",
+    "widersprechend",
+    "widersprechende",
+    "widersprechenden",
+    "widersprechender",
+    "widerspricht",
+    "widerspruch",
+    "widerspruchs",
+    "widersprüche",
+    "widerstand",
+    "widerstande",
+    "widerstanden",
+    "widerstandes",
+    "widerstandslos",
+    "widerstehen",
+    "widerstehende",
+    "widerstehendes",
+    "widersteht",
+    "widerstrebe",
+    "widerstreben",
+    "widerstrebend",
+    "widerstrebt",
+    "widerstrebte",
+    "widerstrebung",
+    "widerstreit",
+    "widerstreiten",
+    "widerstreitende",
+    "widerstreitet",
+    "widerstreits",
+    "widertönen",
+    "widerwillen",
+    "widerwillens",
+    "widerwillig",
+    "widerwilliges",
+    "widerwärtig",
+    "widerwärtige",
+    "widerwärtiger",
+    "widerwärtigsten",
+    "widmen",
+    "widmet",
+    "widmete",
+    "widrig",
+    "widrige",
+    "widrigen",
+    "widrigenfalls",
+    "wie",
+    "wieder",
+    "wiederaufbaus",
+    "wiederaufleben",
+    "wiederaufnahmen",
+    "wiederaufrichtungen",
+    "wiederbekam",
+    "wiederbekomme",
+    "wiederbelebt",
+    "wiederbringer",
+    "wiederentdeckung",
+    "wiedererlangte",
+    "wiederfanden",
+    "wiederfinden",
+    "wiederfindens",
+    "wiedergabe",
+    "wiedergebiert",
+    "wiedergeboren",
+    "wiedergeborene",
+    "wiedergeborenen",
+    "wiedergeburt",
+    "wiedergebären",
+    "wiedergebärer",
+    "wiedergebärers",
+    "wiedergefunden",
+    "wiedergekehrten",
+    "wiedergekommen",
+    "wiedergewann",
+    "wiedergewinnen",
+    "wiedergewonnenen",
+    "wiedergutmachung",
+    "wiederhergestellt",
+    "wiederherstellung",
+    "wiederherzustellen",
+    "wiederhole",
+    "wiederholen",
+    "wiederholt",
+    "wiederholte",
+    "wiederholten",
+    "wiederholung",
+    "wiederkam",
+    "wiederkehr",
+    "wiederkehren",
+    "wiederkehrenden",
+    "wiederkehrt",
+    "wiederklingen",
+    "wiederkommen",
+    "wiederkommt",
+    "wiederkäme",
+    "wiedersehen",
+    "wiederum",
+    "wiedervereinigung",
+    "wiedervergeltung",
+    "wiederzufinden",
+    "wiederzugebärenden",
+    "wiederzukehren",
+    "wiege",
+    "wiegen",
+    "wiegt",
+    "wiegte",
+    "wiehernde",
+    "wiehernden",
+    "wien",
+    "wiener",
+    "wies",
+    "wiese",
+    "wiesen",
+    "wiesenblühenden",
+    "wiesenfleck",
+    "wiesengarten",
+    "wiesengrunde",
+    "wiesengründe",
+    "wiesenrande",
+    "wieserl",
+    "wieso",
+    "wieviel",
+    "wieviele",
+    "wiewohl",
+    "wildbach",
+    "wildbäche",
+    "wilddieb",
+    "wilddieben",
+    "wilde",
+    "wilden",
+    "wilder",
+    "wilderer",
+    "wildes",
+    "wildeste",
+    "wildesten",
+    "wildester",
+    "wildfremde",
+    "wildfremden",
+    "wildfänge",
+    "wildgarten",
+    "wildheit",
+    "wildnis",
+    "wildnisse",
+    "wildschwein",
+    "wildschütz",
+    "wildschützen",
+    "wildtauben",
+    "wildwechseln",
+    "wildwegen",
+    "wilfriede",
+    "wilhelmine",
+    "wilhelminenburg",
+    "will",
+    "wille",
+    "willem",
+    "willen",
+    "willenbestimmend",
+    "willenlos",
+    "willens",
+    "willensanstrengung",
+    "willensbestimmung",
+    "willensbestimmungen",
+    "willensgebiet",
+    "willensgebietes",
+    "willensleben",
+    "willensvermögen",
+    "willfahren",
+    "willfährig",
+    "willfährigkeit",
+    "willig",
+    "willige",
+    "willigen",
+    "williges",
+    "willigte",
+    "willkommen",
+    "willkommene",
+    "willkommenen",
+    "willkür",
+    "willkürlich",
+    "willkürliche",
+    "willst",
+    "wimmeln",
+    "wimmelt",
+    "wimmelte",
+    "wimmelten",
+    "wimpern",
+    "windbruchfläche",
+    "winde",
+    "winden",
+    "windend",
+    "windes",
+    "windeswehen",
+    "windet",
+    "windfahne",
+    "windfaust",
+    "windhauch",
+    "windliese",
+    "windlüfte",
+    "windspiele",
+    "windstillen",
+    "windstoss",
+    "windstoß",
+    "wink",
+    "winke",
+    "winkel",
+    "winkeladvokaten",
+    "winkelei",
+    "winkeleien",
+    "winkelförmig",
+    "winkelmass",
+    "winkeln",
+    "winkelschenke",
+    "winken",
+    "winkliges",
+    "winkt",
+    "winkte",
+    "winkten",
+    "winktest",
+    "winseln",
+    "winter",
+    "winterfelder",
+    "winterfest",
+    "winterkohlköpfe",
+    "winterlichen",
+    "winterlicht",
+    "winterprogramm",
+    "winters",
+    "winterszeit",
+    "wintervormittag",
+    "winterwetter",
+    "winzig",
+    "winzige",
+    "winzigen",
+    "winziger",
+    "winziges",
+    "winzigsten",
+    "wipfel",
+    "wipfeln",
+    "wipp",
+    "wippespiel",
+    "wippte",
+    "wir",
+    "wirbel",
+    "wirbelquirlen",
+    "wirbelte",
+    "wirbelwind",
+    "wirbt",
+    "wird",
+    "wirf",
+    "wirft",
+    "wirke",
+    "wirken",
+    "wirkende",
+    "wirkenden",
+    "wirkender",
+    "wirkens",
+    "wirket",
+    "wirklich",
+    "wirkliche",
+    "wirklichen",
+    "wirklicher",
+    "wirkliches",
+    "wirklichgemacht",
+    "wirklichkeit",
+    "wirklichmachung",
+    "wirklichwerden",
+    "wirklichzumachen",
+    "wirksame",
+    "wirksamen",
+    "wirksamkeit",
+    "wirkt",
+    "wirkte",
+    "wirkung",
+    "wirkungen",
+    "wirkungskreis",
+    "wirkungsstätten",
+    "wirrbärte",
+    "wirrem",
+    "wirrsal",
+    "wirrwarr",
+    "wirst",
+    "wirt",
+    "wirte",
+    "wirten",
+    "wirtes",
+    "wirtin",
+    "wirtliche",
+    "wirtschaft",
+    "wirtschaftliche",
+    "wirtschaftlichen",
+    "wirtschaftlicher",
+    "wirtschafts",
+    "wirtschaftsmobiliar",
+    "wirtschaftsplatz",
+    "wirtschaftspolitische",
+    "wirtschaftswachstum",
+    "wirtshaus",
+    "wirtshause",
+    "wirtshauses",
+    "wirtshausfenstern",
+    "wirtshausrechnungen",
+    "wirtshäuser",
+    "wirtshäusern",
+    "wirtstisch",
+    "wirtstochter",
+    "wischen",
+    "wischer",
+    "wischt",
+    "wischte",
+    "wischten",
+    "wisperte",
+    "wiss",
+    "wissbegierde",
+    "wisse",
+    "wissen",
+    "wissend",
+    "wissende",
+    "wissenden",
+    "wissender",
+    "wissens",
+    "wissenschaft",
+    "wissenschaften",
+    "wissenschaftler",
+    "wissenschaftlich",
+    "wissenschaftliche",
+    "wissenschaftlichem",
+    "wissenschaftlichen",
+    "wissenschaftliches",
+    "wissest",
+    "wisset",
+    "wisst",
+    "wisstümlich",
+    "wisstümliche",
+    "wisstümlichem",
+    "wisstümlichen",
+    "wisstümlicher",
+    "wisstümliches",
+    "witsch",
+    "wittenberg",
+    "wittern",
+    "witterte",
+    "witterten",
+    "witterung",
+    "witterungsvermögen",
+    "witwe",
+    "witwen",
+    "witwengehalt",
+    "witwenschaft",
+    "witwer",
+    "witz",
+    "witze",
+    "witzelt",
+    "witzen",
+    "witzig",
+    "witziger",
+    "witzigste",
+    "witzigsten",
+    "wizenmann",
+    "wißbegierde",
+    "wißt",
+    "wißtümlich",
+    "wißtümliche",
+    "wißtümlichem",
+    "wißtümlichen",
+    "wißtümlicher",
+    "wißtümliches",
+    "wo",
+    "woanders",
+    "woass",
+    "wobei",
+    "woch",
+    "woche",
+    "wochen",
+    "wochenlang",
+    "wochenzeitschrift",
+    "wochenzeitung",
+    "wodurch",
+    "wofern",
+    "wofür",
+    "wogegen",
+    "wogenden",
+    "wogt",
+    "wogte",
+    "woher",
+    "wohin",
+    "wohl",
+    "wohlakzentuierte",
+    "wohlan",
+    "wohlanständigen",
+    "wohlauf",
+    "wohlbebaut",
+    "wohlbefinden",
+    "wohlbegütert",
+    "wohlbehagen",
+    "wohlbekannte",
+    "wohlbekannten",
+    "wohlbeschaffenes",
+    "wohlbesorgte",
+    "wohlbestellte",
+    "wohlbestellten",
+    "wohlbewaffnet",
+    "wohlbewusster",
+    "wohldenkenden",
+    "wohlduftende",
+    "wohldurchgebildeter",
+    "wohler",
+    "wohlergehen",
+    "wohlergehens",
+    "wohlerhaltenes",
+    "wohlerworbenes",
+    "wohlerzogenen",
+    "wohlfahrtsbeamten",
+    "wohlfeil",
+    "wohlfeiles",
+    "wohlgeartet",
+    "wohlgeartete",
+    "wohlgeborgen",
+    "wohlgefallen",
+    "wohlgefallens",
+    "wohlgefiel",
+    "wohlgefällig",
+    "wohlgefällige",
+    "wohlgefälliges",
+    "wohlgefälligsten",
+    "wohlgefällt",
+    "wohlgefüllten",
+    "wohlgefütterten",
+    "wohlgekleideten",
+    "wohlgelehrt",
+    "wohlgelungenen",
+    "wohlgemeinte",
+    "wohlgemerkt",
+    "wohlgemut",
+    "wohlgemute",
+    "wohlgenährten",
+    "wohlgeordneten",
+    "wohlgepflegte",
+    "wohlgeputzte",
+    "wohlgeratener",
+    "wohlgeratenes",
+    "wohlgeruch",
+    "wohlgerüche",
+    "wohlgesalzener",
+    "wohlgesicherte",
+    "wohlgesinneten",
+    "wohlgesinnte",
+    "wohlgestaltete",
+    "wohlgestaltetes",
+    "wohlhabende",
+    "wohlhabender",
+    "wohlhabenheit",
+    "wohlhergestellten",
+    "wohligen",
+    "wohliger",
+    "wohliges",
+    "wohlklang",
+    "wohlklingenden",
+    "wohllaut",
+    "wohllautenden",
+    "wohlleben",
+    "wohlmeinenden",
+    "wohlrasierten",
+    "wohlriechenden",
+    "wohlschmeckenden",
+    "wohlseins",
+    "wohlstand",
+    "wohlstandes",
+    "wohlständchen",
+    "wohltat",
+    "wohltaten",
+    "wohltuend",
+    "wohltun",
+    "wohltuns",
+    "wohltätigem",
+    "wohltätigen",
+    "wohltätigkeit",
+    "wohltätigste",
+    "wohlverdienten",
+    "wohlverhalten",
+    "wohlverhaltens",
+    "wohlversehen",
+    "wohlverstanden",
+    "wohlweisen",
+    "wohlwillst",
+    "wohlwollen",
+    "wohlwollend",
+    "wohlwollende",
+    "wohlwollenden",
+    "wohlwollender",
+    "wohlwollendste",
+    "wohlwollens",
+    "wohlwollte",
+    "wohlzutun",
+    "wohne",
+    "wohnen",
+    "wohnend",
+    "wohnenden",
+    "wohnet",
+    "wohnheim",
+    "wohnlich",
+    "wohnlichen",
+    "wohnraum",
+    "wohnstube",
+    "wohnstätte",
+    "wohnt",
+    "wohnte",
+    "wohnten",
+    "wohnung",
+    "wohnungen",
+    "wohnungstür",
+    "wohnzimmer",
+    "wolf",
+    "wolff",
+    "wolhyniens",
+    "wolke",
+    "wolken",
+    "wolkenbase",
+    "wolkenberge",
+    "wolkenbruch",
+    "wolkenfenster",
+    "wolkenfrau",
+    "wolkenhülle",
+    "wolkenkuppel",
+    "wolkenlos",
+    "wolkenriss",
+    "wolkenschiff",
+    "wolkenschneider",
+    "wolkensäule",
+    "wolkenwände",
+    "woll",
+    "wolle",
+    "wollen",
+    "wollende",
+    "wollenden",
+    "wollendes",
+    "wollenen",
+    "wollens",
+    "wollet",
+    "wollt",
+    "wollte",
+    "wollten",
+    "wolltest",
+    "wolltet",
+    "wollust",
+    "wollüste",
+    "wollüstig",
+    "womit",
+    "womöglich",
+    "wonach",
+    "wonne",
+    "wonnegefühl",
+    "wonnen",
+    "wonnevoll",
+    "wonnevolle",
+    "wonnigen",
+    "woran",
+    "worauf",
+    "woraus",
+    "worbs",
+    "worden",
+    "worfen",
+    "worin",
+    "workshops",
+    "wort",
+    "wortbedeutung",
+    "wortbeiträge",
+    "worte",
+    "worten",
+    "wortes",
+    "wortkarg",
+    "wortkarge",
+    "wortlaut",
+    "wortlos",
+    "wortlosen",
+    "wortschatz",
+    "wortstreit",
+    "wortwechsel",
+    "wortwechselte",
+    "worum",
+    "worunter",
+    "worüber",
+    "wos",
+    "wovon",
+    "wozu",
+    "wuchernden",
+    "wucherte",
+    "wucherten",
+    "wuchs",
+    "wuchsen",
+    "wuchses",
+    "wucht",
+    "wulla",
+    "wunde",
+    "wunden",
+    "wunder",
+    "wunderbar",
+    "wunderbare",
+    "wunderbaren",
+    "wunderbarer",
+    "wunderbares",
+    "wunderbarste",
+    "wunderbarsten",
+    "wundere",
+    "wunderkur",
+    "wunderlich",
+    "wunderliche",
+    "wunderlichen",
+    "wunderlicher",
+    "wunderlicherweise",
+    "wunderlichste",
+    "wunderlieben",
+    "wundermächtige",
+    "wundern",
+    "wunders",
+    "wundersam",
+    "wundersame",
+    "wundersamen",
+    "wundersamer",
+    "wundersames",
+    "wunderschön",
+    "wunderschöne",
+    "wunderschönen",
+    "wunderschönes",
+    "wundert",
+    "wunderte",
+    "wunderten",
+    "wundertiefen",
+    "wundertier",
+    "wundervoll",
+    "wundervolle",
+    "wundervoller",
+    "wundervolles",
+    "wunderzeichen",
+    "wundre",
+    "wundzudrücken",
+    "wunsch",
+    "wunsche",
+    "wunsches",
+    "wunsiedel",
+    "wunsiedels",
+    "wupp",
+    "wurde",
+    "wurden",
+    "wurdest",
+    "wurm",
+    "wurmisierte",
+    "wurst",
+    "wurzel",
+    "wurzelknorren",
+    "wurzeln",
+    "wurzelstock",
+    "wurzelstöcke",
+    "wurzelt",
+    "wurzweg",
+    "wusch",
+    "wuschelig",
+    "wusst",
+    "wusste",
+    "wussten",
+    "wusstest",
+    "wut",
+    "wutentbrannt",
+    "wußte",
+    "wußten",
+    "www",
+    "wächst",
+    "wächter",
+    "wächtern",
+    "wächters",
+    "wägelchen",
+    "wäget",
+    "wähle",
+    "wählen",
+    "wählenden",
+    "wähler",
+    "wählerinnen",
+    "wählete",
+    "wählig",
+    "wähligem",
+    "wählt",
+    "wählte",
+    "wählten",
+    "wähnen",
+    "wähnend",
+    "wähnt",
+    "wähnte",
+    "wähnten",
+    "währe",
+    "während",
+    "währenddessen",
+    "währete",
+    "währt",
+    "währte",
+    "währungsunion",
+    "wäldchen",
+    "wälder",
+    "wäldern",
+    "wälze",
+    "wälzen",
+    "wälzte",
+    "wämsern",
+    "wände",
+    "wänden",
+    "wär",
+    "wäre",
+    "wären",
+    "wärest",
+    "wäret",
+    "wärme",
+    "wärmen",
+    "wärmer",
+    "wärmsten",
+    "wärst",
+    "wäsche",
+    "wäschepacken",
+    "wäscherei",
+    "wäscherin",
+    "wäschestück",
+    "wässerig",
+    "wässeriger",
+    "wässerlein",
+    "wässern",
+    "wässerndem",
+    "wässert",
+    "wässerte",
+    "wölbten",
+    "wölbung",
+    "wölfe",
+    "wölkchen",
+    "wörter",
+    "wörterbuch",
+    "wörtern",
+    "wörtersprache",
+    "wörtl",
+    "wörtlein",
+    "wörtlich",
+    "wühlen",
+    "wühlte",
+    "wünsch",
+    "wünschbare",
+    "wünschbarsten",
+    "wünsche",
+    "wünschen",
+    "wünschenswert",
+    "wünschenswerten",
+    "wünschet",
+    "wünscht",
+    "wünschte",
+    "wünschten",
+    "würd",
+    "würde",
+    "würden",
+    "würdest",
+    "würdet",
+    "würdig",
+    "würdige",
+    "würdigen",
+    "würdiger",
+    "würdigkeit",
+    "würdigte",
+    "würdst",
+    "würfeln",
+    "würfen",
+    "würgen",
+    "würgte",
+    "würmchen",
+    "würmer",
+    "würstchen",
+    "würtembergischen",
+    "würze",
+    "würzigen",
+    "würzkrämer",
+    "würzte",
+    "wüsste",
+    "wüssten",
+    "wüsstens",
+    "wüsstet",
+    "wüst",
+    "wüste",
+    "wüsten",
+    "wüstenei",
+    "wüsteneien",
+    "wüstensand",
+    "wüstenwirbelwetterhut",
+    "wüster",
+    "wüten",
+    "wütend",
+    "wütenden",
+    "wütender",
+    "wütendere",
+    "wüßte",
+    "wüßten",
+    "wüßtest",
+    "wüßtet",
+    "x",
+    "yeni",
+    "z",
+    "zacken",
+    "zackenwasser",
+    "zackige",
+    "zadoks",
+    "zagen",
+    "zagend",
+    "zaghaft",
+    "zaghaftigkeit",
+    "zahl",
+    "zahle",
+    "zahlen",
+    "zahlende",
+    "zahllos",
+    "zahllose",
+    "zahllosen",
+    "zahlloser",
+    "zahlrei",
+    "zahlreich",
+    "zahlreiche",
+    "zahlreichen",
+    "zahlreicher",
+    "zahlreichsten",
+    "zahlt",
+    "zahlte",
+    "zahlungen",
+    "zahm",
+    "zahmen",
+    "zahnbürste",
+    "zahnperlen",
+    "zamm",
+    "zammklauben",
+    "zammraumen",
+    "zammtreffen",
+    "zank",
+    "zanken",
+    "zankes",
+    "zanklaute",
+    "zankt",
+    "zankte",
+    "zankten",
+    "zapfenbrett",
+    "zappeln",
+    "zappelte",
+    "zarge",
+    "zargen",
+    "zart",
+    "zartem",
+    "zarten",
+    "zarter",
+    "zarteren",
+    "zartes",
+    "zarteste",
+    "zartesten",
+    "zartgefühles",
+    "zartheit",
+    "zaster",
+    "zauber",
+    "zauberei",
+    "zaubereien",
+    "zauberer",
+    "zauberern",
+    "zauberfrau",
+    "zauberhaften",
+    "zauberhafter",
+    "zauberhaftes",
+    "zauberin",
+    "zauberische",
+    "zauberkünste",
+    "zauberlaterne",
+    "zaubermacht",
+    "zaubermeister",
+    "zaubern",
+    "zauberschlage",
+    "zauberstab",
+    "zauberstabe",
+    "zauberstücke",
+    "zauberwege",
+    "zauberworte",
+    "zauberzartes",
+    "zaum",
+    "zaume",
+    "zaun",
+    "zaunes",
+    "zaunlatten",
+    "zausen",
+    "zebaoth",
+    "zeche",
+    "zechen",
+    "zechgelage",
+    "zechten",
+    "zedekia",
+    "zeder",
+    "zedern",
+    "zedernholz",
+    "zehen",
+    "zehenspitzen",
+    "zehenten",
+    "zehn",
+    "zehnfache",
+    "zehnjährige",
+    "zehnjährigen",
+    "zehnmal",
+    "zehnsaitigen",
+    "zehntausend",
+    "zehntausende",
+    "zehnte",
+    "zehnten",
+    "zehntes",
+    "zehnzahl",
+    "zehren",
+    "zehrpfennig",
+    "zehrte",
+    "zehrung",
+    "zeichen",
+    "zeichens",
+    "zeichne",
+    "zeichners",
+    "zeichnete",
+    "zeichnung",
+    "zeichnungen",
+    "zeid",
+    "zeiding",
+    "zeig",
+    "zeige",
+    "zeigefinger",
+    "zeigen",
+    "zeiger",
+    "zeigst",
+    "zeigt",
+    "zeigte",
+    "zeigten",
+    "zeile",
+    "zeiserl",
+    "zeisig",
+    "zeit",
+    "zeitabläufe",
+    "zeitabschnitte",
+    "zeitalter",
+    "zeitaltern",
+    "zeitangabe",
+    "zeitaufwand",
+    "zeitbedingung",
+    "zeitbegriff",
+    "zeiten",
+    "zeitenwechsel",
+    "zeitgenossen",
+    "zeitlang",
+    "zeitlauf",
+    "zeitlaufes",
+    "zeitlaufs",
+    "zeitlebens",
+    "zeitliche",
+    "zeitlichen",
+    "zeitlicherem",
+    "zeitläufe",
+    "zeitmangel",
+    "zeitminute",
+    "zeitpunkt",
+    "zeitpunkte",
+    "zeitpunkten",
+    "zeitraum",
+    "zeitraume",
+    "zeitrechnung",
+    "zeiträume",
+    "zeiträumen",
+    "zeitschrift",
+    "zeitschriften",
+    "zeitung",
+    "zeitungen",
+    "zeitungsläden",
+    "zeitverhältnisse",
+    "zeitverlauf",
+    "zeitvertreib",
+    "zeitvertreiber",
+    "zeitverwendung",
+    "zeitweilig",
+    "zeitweiligen",
+    "zeitweise",
+    "zelebriert",
+    "zelt",
+    "zeltbewohners",
+    "zelte",
+    "zelten",
+    "zeltes",
+    "zeltlager",
+    "zen",
+    "zentnerlast",
+    "zentnern",
+    "zentnerschwer",
+    "zentralbüro",
+    "zentraldorf",
+    "zentralsekretär",
+    "zentralsekretärs",
+    "zentralstelle",
+    "zeph",
+    "zephanja",
+    "zeppelinballons",
+    "zepter",
+    "zerbläuen",
+    "zerbrach",
+    "zerbreche",
+    "zerbrechen",
+    "zerbrechend",
+    "zerbrechlich",
+    "zerbricht",
+    "zerbrochen",
+    "zerbrochene",
+    "zerbrochenen",
+    "zerbräche",
+    "zerdrückt",
+    "zerdrückte",
+    "zerdrückten",
+    "zeremonie",
+    "zeremoniell",
+    "zerfahrenen",
+    "zerfallenden",
+    "zerfallene",
+    "zerfallenen",
+    "zerfegte",
+    "zerfetzt",
+    "zerfliesse",
+    "zerfliessen",
+    "zerfliesst",
+    "zerfließen",
+    "zerfließt",
+    "zerflog",
+    "zerfressenen",
+    "zergeht",
+    "zergliedern",
+    "zergliederung",
+    "zerhaue",
+    "zerhauen",
+    "zerhauend",
+    "zerknicken",
+    "zerknickten",
+    "zerknirscht",
+    "zerknitterten",
+    "zerknittertes",
+    "zerkratzten",
+    "zerlegte",
+    "zerlegung",
+    "zermalme",
+    "zermalmen",
+    "zermalmend",
+    "zermalmest",
+    "zermalmt",
+    "zernagte",
+    "zerraufte",
+    "zerreibend",
+    "zerreissen",
+    "zerreissenden",
+    "zerreisst",
+    "zerreißen",
+    "zerreißenden",
+    "zerreißt",
+    "zerren",
+    "zerrieben",
+    "zerriss",
+    "zerrissen",
+    "zerrissene",
+    "zerrissenen",
+    "zerriß",
+    "zerrt",
+    "zerrte",
+    "zerschellen",
+    "zerschellt",
+    "zerschlagen",
+    "zerschlagener",
+    "zerschlitzt",
+    "zerschlug",
+    "zerschlugen",
+    "zerschmettert",
+    "zerschnitten",
+    "zerschnittenien",
+    "zerschossenen",
+    "zerspaltet",
+    "zerspellten",
+    "zerspringen",
+    "zerstampfen",
+    "zerstibten",
+    "zerstieben",
+    "zerstoben",
+    "zerstochen",
+    "zerstochene",
+    "zerstossen",
+    "zerstossene",
+    "zerstoßen",
+    "zerstoßene",
+    "zerstreuen",
+    "zerstreut",
+    "zerstreute",
+    "zerstreuten",
+    "zerstreutheit",
+    "zerstreuung",
+    "zerstäubung",
+    "zerstöre",
+    "zerstören",
+    "zerstörend",
+    "zerstörende",
+    "zerstört",
+    "zerstörte",
+    "zerstörten",
+    "zerstörung",
+    "zerteilt",
+    "zerteilten",
+    "zertrennt",
+    "zertreten",
+    "zertretenen",
+    "zertrümmert",
+    "zertrümmerte",
+    "zertrümmerten",
+    "zertrümmertes",
+    "zerwühlt",
+    "zerzausten",
+    "zerzauster",
+    "zerzaustheit",
+    "zeter",
+    "zettel",
+    "zettelchen",
+    "zeug",
+    "zeuge",
+    "zeugen",
+    "zeugenaussagen",
+    "zeughäuser",
+    "zeughäusern",
+    "zeugin",
+    "zeugnis",
+    "zeugnisse",
+    "zeugnisses",
+    "zeugschmied",
+    "zeugschmiedgesell",
+    "zeugte",
+    "zeugten",
+    "zeugung",
+    "zeugungen",
+    "zeugungsgliedern",
+    "zh",
+    "zichorierte",
+    "zickzack",
+    "zickzacklinien",
+    "zidon",
+    "zidons",
+    "ziedern",
+    "ziedert",
+    "ziegel",
+    "ziegelmachen",
+    "ziegeln",
+    "ziegelofen",
+    "ziegelsteine",
+    "ziegelsteinen",
+    "ziegen",
+    "ziegenbart",
+    "ziegenbock",
+    "ziegenböcken",
+    "ziegenkadaver",
+    "zieh",
+    "ziehbrunnen",
+    "ziehe",
+    "ziehen",
+    "ziehenden",
+    "ziehet",
+    "zieht",
+    "ziel",
+    "ziele",
+    "zielen",
+    "zieles",
+    "zielgewehren",
+    "ziell",
+    "zielort",
+    "ziels",
+    "zielsicher",
+    "zielte",
+    "ziemergerichte",
+    "ziemern",
+    "ziemet",
+    "ziemlich",
+    "ziemliche",
+    "ziemt",
+    "ziemte",
+    "ziepziep",
+    "zier",
+    "zierde",
+    "zierden",
+    "zieren",
+    "zierende",
+    "ziererei",
+    "ziergewächs",
+    "zierlich",
+    "zierliche",
+    "zierlichem",
+    "zierlichen",
+    "zierlicher",
+    "zierliches",
+    "zierlichkeit",
+    "zierlichste",
+    "zierte",
+    "zierten",
+    "ziervolles",
+    "ziffer",
+    "zifferblatt",
+    "ziffern",
+    "zigarette",
+    "zigaretten",
+    "zigarettenasche",
+    "zigarettendose",
+    "zigarettenschachtel",
+    "zigarettenschachteln",
+    "zigarre",
+    "zigarren",
+    "zigarrenbengel",
+    "zige",
+    "zigeunerbande",
+    "zillah",
+    "zillen",
+    "zimbel",
+    "zimbeln",
+    "zimmer",
+    "zimmerdecke",
+    "zimmergewehr",
+    "zimmern",
+    "zimmers",
+    "zimmervermieterin",
+    "zimmet",
+    "zimmetbüchschen",
+    "zimperlich",
+    "zimperlichen",
+    "zinn",
+    "zinne",
+    "zinnen",
+    "zinnernen",
+    "zinnoberbergwerk",
+    "zinnoberroten",
+    "zinsen",
+    "zion",
+    "zions",
+    "zipfel",
+    "zipfelhaube",
+    "zipfelkappe",
+    "zipfelkappen",
+    "zipfelmütze",
+    "zipfeln",
+    "zippora",
+    "zirka",
+    "zirkel",
+    "zirkelte",
+    "zirkuliert",
+    "zirkulierten",
+    "zirpedirp",
+    "zirpen",
+    "zisch",
+    "zischelndem",
+    "zischen",
+    "zischende",
+    "zischlauten",
+    "zischte",
+    "zisterzienserpater",
+    "zitaten",
+    "zither",
+    "zithern",
+    "zitherspiel",
+    "zitherspieler",
+    "zitherspielern",
+    "zitieren",
+    "zitiert",
+    "zitronenschale",
+    "zittern",
+    "zitternd",
+    "zitternde",
+    "zitternden",
+    "zittert",
+    "zitterte",
+    "zitterten",
+    "zitty",
+    "zivildienst",
+    "zivildienstleistende",
+    "zivildienstleistenden",
+    "zivildienstleistender",
+    "zivildienststelle",
+    "zivildienststellen",
+    "zivildiensttreffpunkt",
+    "zivildienstzeit",
+    "zivilgesellschaft",
+    "zivilpolizei",
+    "zivilpolizist",
+    "zivilpolizisten",
+    "zivis",
+    "zoba",
+    "zochar",
+    "zog",
+    "zogen",
+    "zollmass",
+    "zollstock",
+    "zoni",
+    "zony",
+    "zoo",
+    "zoodirektor",
+    "zoologen",
+    "zopf",
+    "zorn",
+    "zorne",
+    "zornerregt",
+    "zornes",
+    "zornig",
+    "zornigen",
+    "zorniger",
+    "zorns",
+    "zottelig",
+    "zotteln",
+    "zottelte",
+    "zruck",
+    "zruckfahren",
+    "zu",
+    "zubereitet",
+    "zubereitungen",
+    "zubrachte",
+    "zubringen",
+    "zucht",
+    "zuchthaus",
+    "zuchthauskost",
+    "zuckbewegungen",
+    "zuckenden",
+    "zucker",
+    "zuckerbeck",
+    "zuckerbrot",
+    "zuckerbrücken",
+    "zuckerbäcker",
+    "zuckerguss",
+    "zuckerreste",
+    "zuckerwasser",
+    "zuckerwerk",
+    "zuckte",
+    "zuckten",
+    "zudem",
+    "zudringlich",
+    "zudringlichen",
+    "zudringlicher",
+    "zudringlichkeiten",
+    "zudringlichsten",
+    "zueigne",
+    "zueignen",
+    "zueilte",
+    "zueilten",
+    "zueinander",
+    "zuerkannt",
+    "zuerst",
+    "zufall",
+    "zufalle",
+    "zufalls",
+    "zufliessen",
+    "zufließen",
+    "zuflucht",
+    "zufluss",
+    "zuflusses",
+    "zufluß",
+    "zufolge",
+    "zufrieden",
+    "zufriedenem",
+    "zufriedener",
+    "zufriedenheit",
+    "zufriedenstellend",
+    "zufälle",
+    "zufällig",
+    "zufällige",
+    "zufälligen",
+    "zufälliger",
+    "zufälligerweise",
+    "zufälliges",
+    "zufälligkeit",
+    "zufüge",
+    "zufügen",
+    "zufügt",
+    "zuführen",
+    "zuführt",
+    "zug",
+    "zugabe",
+    "zugang",
+    "zugangen",
+    "zuge",
+    "zugeben",
+    "zugebracht",
+    "zugebundenen",
+    "zugedacht",
+    "zugedachte",
+    "zugedeckt",
+    "zugeeignet",
+    "zugefügt",
+    "zugeführt",
+    "zugegangen",
+    "zugegebenermassen",
+    "zugegen",
+    "zugegriffen",
+    "zugehen",
+    "zugehörige",
+    "zugehört",
+    "zugeklappt",
+    "zugelassen",
+    "zugemutet",
+    "zugeneigt",
+    "zugereist",
+    "zugerichtet",
+    "zugerufen",
+    "zugesagt",
+    "zugeschaut",
+    "zugeschlagen",
+    "zugeschneite",
+    "zugeschoben",
+    "zugeschrieben",
+    "zugeschrieen",
+    "zugesehen",
+    "zugesprochen",
+    "zugesprungen",
+    "zugestanden",
+    "zugestehen",
+    "zugestimmt",
+    "zugestossen",
+    "zugetan",
+    "zugetragen",
+    "zugetraut",
+    "zugewandt",
+    "zugewandten",
+    "zugewendet",
+    "zugewendetes",
+    "zugewiesene",
+    "zugeworfen",
+    "zugezogen",
+    "zugibt",
+    "zuging",
+    "zugingen",
+    "zugleich",
+    "zugnetz",
+    "zugreifen",
+    "zugriff",
+    "zugrunde",
+    "zugrundegehen",
+    "zugrundeliegende",
+    "zugschlagen",
+    "zugunsten",
+    "zugut",
+    "zugute",
+    "zugäng",
+    "zuhielt",
+    "zuhilfenahme",
+    "zuhinterst",
+    "zuhälterin",
+    "zuhören",
+    "zuhörer",
+    "zuhörerinnen",
+    "zuhörers",
+    "zuhörte",
+    "zukam",
+    "zukehrte",
+    "zuknöpfte",
+    "zukomme",
+    "zukommen",
+    "zukommt",
+    "zukunft",
+    "zukünftige",
+    "zukünftigen",
+    "zulange",
+    "zulangen",
+    "zulangt",
+    "zulasse",
+    "zulassen",
+    "zulassung",
+    "zulasten",
+    "zulauf",
+    "zulegen",
+    "zuleid",
+    "zuleide",
+    "zuletzt",
+    "zulief",
+    "zulächelt",
+    "zulänglichkeit",
+    "zulässigkeit",
+    "zulässt",
+    "zuläßt",
+    "zum",
+    "zumachen",
+    "zumal",
+    "zumeist",
+    "zumessen",
+    "zumindest",
+    "zumut",
+    "zumute",
+    "zumuten",
+    "zumutung",
+    "zumutungen",
+    "zunahm",
+    "zunahme",
+    "zunahmen",
+    "zunehmen",
+    "zunehmend",
+    "zunehmende",
+    "zunehmenden",
+    "zunehmender",
+    "zuneigung",
+    "zunge",
+    "zungen",
+    "zungenfertigkeit",
+    "zungenspitze",
+    "zunichte",
+    "zunimmt",
+    "zunächst",
+    "zuoberst",
+    "zupfen",
+    "zupfte",
+    "zur",
+    "zurecht",
+    "zurechtfinden",
+    "zurechtgelegt",
+    "zurechtgerückt",
+    "zurechtgesetzt",
+    "zurechtgewiesen",
+    "zurechtschneiderte",
+    "zurechtweisen",
+    "zurechtweisung",
+    "zurechtzufinden",
+    "zurechtzulegen",
+    "zurechtzumachen",
+    "zurede",
+    "zureden",
+    "zureichend",
+    "zureichende",
+    "zureichenden",
+    "zurichten",
+    "zurief",
+    "zuruf",
+    "zurufe",
+    "zurufen",
+    "zurück",
+    "zurückbeziehen",
+    "zurückbleiben",
+    "zurückbleibt",
+    "zurückblicken",
+    "zurückblieb",
+    "zurückbogen",
+    "zurückbringe",
+    "zurückbringen",
+    "zurückdrängen",
+    "zurückdrängt",
+    "zurückerhalten",
+    "zurückerstatten",
+    "zurückfliehen",
+    "zurückfragen",
+    "zurückfuhr",
+    "zurückfällt",
+    "zurückführen",
+    "zurückführte",
+    "zurückgeben",
+    "zurückgeblieben",
+    "zurückgebliebene",
+    "zurückgebliebenen",
+    "zurückgebracht",
+    "zurückgedrängt",
+    "zurückgefallen",
+    "zurückgefunden",
+    "zurückgeführt",
+    "zurückgeführte",
+    "zurückgegangen",
+    "zurückgegeben",
+    "zurückgehalten",
+    "zurückgehen",
+    "zurückgehn",
+    "zurückgekehrt",
+    "zurückgekehrten",
+    "zurückgekommen",
+    "zurückgelassen",
+    "zurückgelegt",
+    "zurückgerufen",
+    "zurückgeschlagen",
+    "zurückgeschlendert",
+    "zurückgeschwunden",
+    "zurückgesetzt",
+    "zurückgestellt",
+    "zurückgestossen",
+    "zurückgestoßen",
+    "zurückgetreten",
+    "zurückgewichen",
+    "zurückgewiesen",
+    "zurückgewinkt",
+    "zurückging",
+    "zurückhalte",
+    "zurückhalten",
+    "zurückhaltend",
+    "zurückhaltender",
+    "zurückhaltung",
+    "zurückhältst",
+    "zurückkam",
+    "zurückkamen",
+    "zurückkehren",
+    "zurückkehrst",
+    "zurückkehrte",
+    "zurückkehrten",
+    "zurückkommen",
+    "zurücklassen",
+    "zurückliessen",
+    "zurückreichten",
+    "zurückschaudert",
+    "zurückschieben",
+    "zurücksehen",
+    "zurücksehnte",
+    "zurücksprang",
+    "zurückstehende",
+    "zurücksteht",
+    "zurückstossen",
+    "zurückstoßen",
+    "zurückstrahlte",
+    "zurückströmte",
+    "zurücktreten",
+    "zurücktritt",
+    "zurückwandte",
+    "zurückwarf",
+    "zurückweichen",
+    "zurückweichens",
+    "zurückwenden",
+    "zurückwendend",
+    "zurückziehen",
+    "zurückzog",
+    "zurückzubringen",
+    "zurückzuerobern",
+    "zurückzuführen",
+    "zurückzugeben",
+    "zurückzuhalten",
+    "zurückzukehren",
+    "zurückzukommen",
+    "zurückzureisen",
+    "zurückzuziehen",
+    "zusag",
+    "zusagen",
+    "zusagt",
+    "zusagte",
+    "zusah",
+    "zusahen",
+    "zusammen",
+    "zusammenarbeit",
+    "zusammenbrachte",
+    "zusammenbringen",
+    "zusammenfassten",
+    "zusammenfassung",
+    "zusammenfaßten",
+    "zusammenfiel",
+    "zusammenfügen",
+    "zusammenfügte",
+    "zusammengeballt",
+    "zusammengeballtes",
+    "zusammengeben",
+    "zusammengebracht",
+    "zusammengebunden",
+    "zusammengebundene",
+    "zusammengedichtete",
+    "zusammengedrängt",
+    "zusammengefalteten",
+    "zusammengefasst",
+    "zusammengefügt",
+    "zusammengehalten",
+    "zusammengekehrt",
+    "zusammengeknöpften",
+    "zusammengelebt",
+    "zusammengenommen",
+    "zusammengepasst",
+    "zusammengepaßt",
+    "zusammengerollt",
+    "zusammengerollter",
+    "zusammengeschrumpft",
+    "zusammengesetzt",
+    "zusammengesetzte",
+    "zusammengesetzten",
+    "zusammengestellt",
+    "zusammengeströmt",
+    "zusammengesucht",
+    "zusammengetan",
+    "zusammengetragen",
+    "zusammengewürfelten",
+    "zusammengezogen",
+    "zusammengezogenen",
+    "zusammenhalten",
+    "zusammenhang",
+    "zusammenhanges",
+    "zusammenhangs",
+    "zusammenhielt",
+    "zusammenhing",
+    "zusammenhingen",
+    "zusammenhängend",
+    "zusammenhängende",
+    "zusammenhängenden",
+    "zusammenhängt",
+    "zusammenkaufte",
+    "zusammenknüllte",
+    "zusammenkommen",
+    "zusammenkommens",
+    "zusammenkunft",
+    "zusammenleben",
+    "zusammenleimte",
+    "zusammenliefen",
+    "zusammennahm",
+    "zusammennehme",
+    "zusammennehmen",
+    "zusammennehmend",
+    "zusammennähen",
+    "zusammennähten",
+    "zusammenpasse",
+    "zusammenreimt",
+    "zusammenschlagen",
+    "zusammenschmelzt",
+    "zusammenschrumpften",
+    "zusammensein",
+    "zusammenseins",
+    "zusammensetzen",
+    "zusammensetzten",
+    "zusammensetzung",
+    "zusammenspeisen",
+    "zusammenspeisungen",
+    "zusammensteckte",
+    "zusammenstellte",
+    "zusammenstellung",
+    "zusammenstiessen",
+    "zusammenstimmen",
+    "zusammenstimmt",
+    "zusammenstimmte",
+    "zusammenstimmung",
+    "zusammenstoppeln",
+    "zusammenstoppelt",
+    "zusammenstoss",
+    "zusammenstoß",
+    "zusammenströmen",
+    "zusammenstösst",
+    "zusammenstößt",
+    "zusammensuchend",
+    "zusammentrafen",
+    "zusammentreffen",
+    "zusammenwirken",
+    "zusammenwohnen",
+    "zusammenziehen",
+    "zusammenzieht",
+    "zusammenzog",
+    "zusammenzogen",
+    "zusammenzu",
+    "zusammenzubringen",
+    "zusammenzuckte",
+    "zusammenzuhalten",
+    "zusammenzuraffen",
+    "zusammenzustellen",
+    "zusammenzutreffen",
+    "zusammenzählte",
+    "zuschanden",
+    "zuschauer",
+    "zuschauern",
+    "zuschauers",
+    "zuschaute",
+    "zuschauten",
+    "zuschlagen",
+    "zuschloss",
+    "zuschnitt",
+    "zuschnüren",
+    "zuschreiben",
+    "zuschreibt",
+    "zuschrieben",
+    "zuschritt",
+    "zuschulden",
+    "zuschüsse",
+    "zusehen",
+    "zusehends",
+    "zusendung",
+    "zusetzen",
+    "zusetzt",
+    "zusprangen",
+    "zuspätkommens",
+    "zustand",
+    "zustande",
+    "zustandes",
+    "zustands",
+    "zustandsveränderung",
+    "zustandsveränderungen",
+    "zustandswechsel",
+    "zustatten",
+    "zusteht",
+    "zustimme",
+    "zustimmt",
+    "zustimmung",
+    "zustände",
+    "zuständen",
+    "zuständig",
+    "zustürzte",
+    "zutage",
+    "zutageförderung",
+    "zutat",
+    "zuteil",
+    "zutragen",
+    "zutragend",
+    "zutrat",
+    "zutrauen",
+    "zutraulich",
+    "zutraulicher",
+    "zutreffen",
+    "zutreffend",
+    "zutreffende",
+    "zutrifft",
+    "zutrug",
+    "zuträgern",
+    "zuträglich",
+    "zuträgliche",
+    "zuträglichkeit",
+    "zuträgt",
+    "zutulich",
+    "zutulicher",
+    "zutulichkeit",
+    "zutütchen",
+    "zuunterst",
+    "zuverlässig",
+    "zuverlässigen",
+    "zuverlässiger",
+    "zuversicht",
+    "zuversichtlich",
+    "zuviel",
+    "zuvor",
+    "zuvorkommend",
+    "zuvorzutun",
+    "zuvörderst",
+    "zuwachs",
+    "zuwachses",
+    "zuwandte",
+    "zuwarf",
+    "zuweg",
+    "zuwege",
+    "zuweilen",
+    "zuwenden",
+    "zuwendend",
+    "zuwendet",
+    "zuwendeten",
+    "zuwider",
+    "zuwinken",
+    "zuwinkte",
+    "zuwinkten",
+    "zuzeiten",
+    "zuziehen",
+    "zuzog",
+    "zuzogen",
+    "zuzuflüstern",
+    "zuzufügen",
+    "zuzug",
+    "zuzuhören",
+    "zuzunehmen",
+    "zuzureden",
+    "zuzurichten",
+    "zuzuschauen",
+    "zuzuschlagen",
+    "zuzusehen",
+    "zuzustutzen",
+    "zuzuwenden",
+    "zuzüge",
+    "zuzügerwesen",
+    "zwa",
+    "zwackt",
+    "zwang",
+    "zwange",
+    "zwangen",
+    "zwanges",
+    "zwangslage",
+    "zwangsläufig",
+    "zwanzig",
+    "zwanzigjährigen",
+    "zwanzigjähriger",
+    "zwanzigmal",
+    "zwar",
+    "zweck",
+    "zweckdienliche",
+    "zwecke",
+    "zwecken",
+    "zweckentfremdete",
+    "zweckes",
+    "zwecklos",
+    "zwecklose",
+    "zweckmässig",
+    "zweckmässigen",
+    "zweckmässiger",
+    "zweckmässiges",
+    "zweckmässigkeit",
+    "zweckmäßig",
+    "zweckmäßiger",
+    "zwecks",
+    "zweckwidrig",
+    "zwei",
+    "zweideutig",
+    "zweideutige",
+    "zweideutiger",
+    "zweideutigkeit",
+    "zweien",
+    "zweier",
+    "zweierlei",
+    "zweifel",
+    "zweifelhaft",
+    "zweifelhafter",
+    "zweifellehre",
+    "zweifellos",
+    "zweifeln",
+    "zweifelsfreien",
+    "zweifelsohne",
+    "zweifelten",
+    "zweifelvollen",
+    "zweifenstriges",
+    "zweifler",
+    "zweig",
+    "zweigchen",
+    "zweige",
+    "zweigen",
+    "zweiges",
+    "zweiglein",
+    "zweihundert",
+    "zweihundertachtundfünfzig",
+    "zweihändigen",
+    "zweijährigen",
+    "zweikampf",
+    "zweimal",
+    "zweimonatszeitschrift",
+    "zweischneidiges",
+    "zweispra",
+    "zweispännigen",
+    "zweit",
+    "zweitausend",
+    "zweite",
+    "zweitem",
+    "zweiten",
+    "zweitenmal",
+    "zweitens",
+    "zweiter",
+    "zweites",
+    "zweithöchsten",
+    "zweitägige",
+    "zweiundachtzig",
+    "zweiunddreissig",
+    "zweiundfünfzig",
+    "zweiundsechzig",
+    "zweiundvierzig",
+    "zweiundzwanzig",
+    "zwerge",
+    "zwergfinken",
+    "zwickten",
+    "zwieback",
+    "zwiegespräch",
+    "zwiegsang",
+    "zwiespalt",
+    "zwiespaltes",
+    "zwiesprache",
+    "zwillich",
+    "zwillingsmilchflasche",
+    "zwingen",
+    "zwingend",
+    "zwinger",
+    "zwingt",
+    "zwinkert",
+    "zwinkerte",
+    "zwirchkammer",
+    "zwirn",
+    "zwirnfabrik",
+    "zwischen",
+    "zwischendurch",
+    "zwischenräumen",
+    "zwischenzeitlich",
+    "zwitschermusik",
+    "zwitschern",
+    "zwitschernd",
+    "zwitscherte",
+    "zwoa",
+    "zwungen",
+    "zwölf",
+    "zwölferstangen",
+    "zwölfhundert",
+    "zwölfjährigen",
+    "zwölfmal",
+    "zwölfte",
+    "zylinder",
+    "zylinderhut",
+    "zylinderhüte",
+    "zylinderhüten",
+    "zyniker",
+    "zynischen",
+    "zyperns",
+    "zypressen",
+    "zäh",
+    "zähe",
+    "zähl",
+    "zähle",
+    "zählen",
+    "zähler",
+    "zählt",
+    "zählte",
+    "zähmen",
+    "zähnchen",
+    "zähne",
+    "zähnefletschend",
+    "zähneklappernd",
+    "zähnen",
+    "zängchen",
+    "zänkereien",
+    "zänkischen",
+    "zärtlich",
+    "zärtliche",
+    "zärtlichen",
+    "zärtliches",
+    "zärtlichkeit",
+    "zärtlichkeiten",
+    "zärtlichste",
+    "zäune",
+    "zöge",
+    "zögern",
+    "zögernd",
+    "zögerte",
+    "zögerten",
+    "zöglinge",
+    "zöglingen",
+    "zölibat",
+    "zöpfchen",
+    "zöpfe",
+    "züchtig",
+    "züchtigen",
+    "züchtiger",
+    "züge",
+    "zügel",
+    "zügellosen",
+    "zügellosigkeit",
+    "zügelt",
+    "zügen",
+    "zünden",
+    "zündet",
+    "zündete",
+    "zündschnur",
+    "züngelten",
+    "zünglein",
+    "zürcher",
+    "zürich",
+    "zürne",
+    "zürnen",
+    "zürnet",
+    "zürnt",
+    "züs",
+    "züsens",
+    "züsi",
+    "züsis",
+    "ächzen",
+    "ächzendes",
+    "äcker",
+    "äckern",
+    "äffchen",
+    "äffen",
+    "ägypten",
+    "ägyptenland",
+    "ägyptenlandes",
+    "ägyptenlands",
+    "ägyptens",
+    "ägypter",
+    "ägyptern",
+    "ägypters",
+    "ägyptischen",
+    "ägyptischer",
+    "ähnlich",
+    "ähnliche",
+    "ähnlichem",
+    "ähnlichen",
+    "ähnlicher",
+    "ähnliches",
+    "ähnlichkeit",
+    "ähnlichkeiten",
+    "ähre",
+    "ähren",
+    "älter",
+    "ältere",
+    "älteren",
+    "älterer",
+    "ältern",
+    "älteste",
+    "ältesten",
+    "ältliche",
+    "ämtchen",
+    "ämter",
+    "ändern",
+    "ändert",
+    "änderte",
+    "änderung",
+    "änderungen",
+    "ängste",
+    "ängstigte",
+    "ängstlich",
+    "äpfel",
+    "äpfelchen",
+    "äpfeln",
+    "ärger",
+    "ärgere",
+    "ärgeren",
+    "ärgerlich",
+    "ärgerlichen",
+    "ärgern",
+    "ärgernis",
+    "ärgernisse",
+    "ärgert",
+    "ärgerte",
+    "ärgerten",
+    "ärgste",
+    "ärgsten",
+    "ärmchen",
+    "ärmere",
+    "ärmlich",
+    "ärmlichen",
+    "ärmlicher",
+    "ärmste",
+    "ärmsten",
+    "ärzte",
+    "ärztliche",
+    "ärztlicher",
+    "äsen",
+    "äsend",
+    "äser",
+    "ässen",
+    "äste",
+    "ästen",
+    "ästhetik",
+    "ästhetisch",
+    "ästhetische",
+    "ästhetischen",
+    "ästhetisches",
+    "äther",
+    "ätherglast",
+    "ätherglocke",
+    "ätherisches",
+    "ätherlüfte",
+    "äthiopien",
+    "äthiopiens",
+    "äthiopier",
+    "ätsch",
+    "äufner",
+    "äufnete",
+    "äugelchen",
+    "äugelein",
+    "äugender",
+    "äuglein",
+    "äussere",
+    "äusserem",
+    "äusseren",
+    "äusserer",
+    "äusseres",
+    "äusserlich",
+    "äusserliche",
+    "äusserlichen",
+    "äusserlicher",
+    "äusserliches",
+    "äusserlichkeiten",
+    "äusserlichsten",
+    "äussern",
+    "äussernden",
+    "äusserst",
+    "äusserste",
+    "äussersten",
+    "äusserster",
+    "äusserstes",
+    "äussert",
+    "äusserung",
+    "äusserungen",
+    "äußere",
+    "äußerem",
+    "äußeren",
+    "äußerer",
+    "äußeres",
+    "äußerlich",
+    "äußerliche",
+    "äußerlichen",
+    "äußerlicher",
+    "äußerliches",
+    "äußerlichkeiten",
+    "äußerlichsten",
+    "äußern",
+    "äußerst",
+    "äußerste",
+    "äußersten",
+    "äußerster",
+    "äußerstes",
+    "äußert",
+    "äußerung",
+    "äußerungen",
+    "äxten",
+    "äßen",
+    "öde",
+    "öden",
+    "ödungen",
+    "öfen",
+    "öffentlich",
+    "öffentliche",
+    "öffentlichen",
+    "öffentlicher",
+    "öffentliches",
+    "öffentlichkeit",
+    "öffnen",
+    "öffnet",
+    "öffnete",
+    "öffneten",
+    "öfter",
+    "öftere",
+    "öfteren",
+    "öftern",
+    "öfters",
+    "ökono",
+    "ökonomisch",
+    "ökonomischen",
+    "ökonomisches",
+    "ökumene",
+    "ökumenische",
+    "ökumenischen",
+    "öl",
+    "ölbaumes",
+    "ölfarbe",
+    "ölig",
+    "öls",
+    "örk",
+    "örter",
+    "österreich",
+    "österreichern",
+    "östlich",
+    "östliche",
+    "östlichen",
+    "özgur",
+    "üb",
+    "übel",
+    "übelgenommen",
+    "übelgeruch",
+    "übelgerüche",
+    "übelklänge",
+    "übeln",
+    "übels",
+    "übeltun",
+    "übeltäter",
+    "übelwollend",
+    "übelzunehmen",
+    "üben",
+    "über",
+    "überall",
+    "überallhin",
+    "überaus",
+    "überbelegt",
+    "überbieten",
+    "überbleibsel",
+    "überbleibseln",
+    "überbleibsels",
+    "überblick",
+    "überbot",
+    "überboten",
+    "überbracht",
+    "überbrachte",
+    "überbringen",
+    "überbringenden",
+    "überbringer",
+    "überdachte",
+    "überdachten",
+    "überdeckt",
+    "überdem",
+    "überdies",
+    "überdimensionalen",
+    "überdruss",
+    "überdrusses",
+    "überdruß",
+    "überdrüssig",
+    "übereilen",
+    "übereilung",
+    "überein",
+    "übereinand",
+    "übereinander",
+    "übereinandergestellten",
+    "übereinstimmen",
+    "übereinstimmende",
+    "übereinstimmt",
+    "übereinstimmte",
+    "übereinstimmung",
+    "übereinstimmungen",
+    "übereinwirkten",
+    "übereinzustimmen",
+    "überempfindlich",
+    "überfahren",
+    "überfall",
+    "überfalle",
+    "überfallen",
+    "überfein",
+    "überfiel",
+    "überfliessendes",
+    "überfließendes",
+    "überflog",
+    "überfloss",
+    "überfluss",
+    "überflusse",
+    "überfluten",
+    "überflutenden",
+    "überflutender",
+    "überflutung",
+    "überfluß",
+    "überflügeln",
+    "überflüssig",
+    "überflüssige",
+    "überflüssigkeit",
+    "überfordern",
+    "überfüllten",
+    "übergab",
+    "übergang",
+    "übergange",
+    "übergangsdomizil",
+    "übergangsphase",
+    "übergeben",
+    "übergegangen",
+    "übergehen",
+    "übergeht",
+    "übergeleitet",
+    "übergeordneten",
+    "übergepanscht",
+    "übergepflanzt",
+    "übergeschnappt",
+    "übergewicht",
+    "übergingen",
+    "überglücklich",
+    "übergossen",
+    "übergossenen",
+    "übergriffe",
+    "übergrosse",
+    "übergrünten",
+    "übergänge",
+    "überhasten",
+    "überhaupt",
+    "überheben",
+    "überhebung",
+    "überholte",
+    "überholten",
+    "überhuschten",
+    "überhäuft",
+    "überhäufte",
+    "überhöre",
+    "überhören",
+    "überhört",
+    "überhörte",
+    "überkam",
+    "überkamen",
+    "überkollerte",
+    "überkommen",
+    "überlagerung",
+    "überlasse",
+    "überlassen",
+    "überlasset",
+    "überlasst",
+    "überlaufenem",
+    "überlaufenes",
+    "überleben",
+    "überlebenstechnischen",
+    "überlebt",
+    "überleg",
+    "überlegen",
+    "überlegend",
+    "überlegene",
+    "überlegenheit",
+    "überlegt",
+    "überlegte",
+    "überlegten",
+    "überlegung",
+    "überlegungen",
+    "überlieferungen",
+    "überliess",
+    "überliesse",
+    "überliessen",
+    "überließ",
+    "überlistet",
+    "überlud",
+    "überlässt",
+    "überläßt",
+    "überm",
+    "übermacht",
+    "übermannt",
+    "übermorgen",
+    "übermut",
+    "übermächtigen",
+    "übermässig",
+    "übermäßig",
+    "übermüdet",
+    "übermüdeten",
+    "übermütig",
+    "übermütige",
+    "übermütigem",
+    "übermütigen",
+    "übermütiger",
+    "übernachten",
+    "übernahm",
+    "übernamen",
+    "übernehmen",
+    "übernimmt",
+    "übernommen",
+    "überparteiliche",
+    "überprüfe",
+    "überprüfen",
+    "überquer",
+    "überquert",
+    "überragte",
+    "überrasche",
+    "überraschen",
+    "überraschend",
+    "überraschenden",
+    "überraschenderweise",
+    "überrascht",
+    "überraschte",
+    "überraschten",
+    "überraschung",
+    "überreden",
+    "überredet",
+    "überredung",
+    "überredungen",
+    "überredungsgiften",
+    "überreichen",
+    "überreicht",
+    "überreizung",
+    "überrest",
+    "überreste",
+    "überresten",
+    "überrock",
+    "überrocks",
+    "überrumpeln",
+    "übers",
+    "übersandt",
+    "überschattet",
+    "überschatteten",
+    "überschauen",
+    "überschauend",
+    "überschaute",
+    "überschigg",
+    "überschlagend",
+    "überschlug",
+    "überschreiten",
+    "überschrieben",
+    "überschrift",
+    "überschriften",
+    "überschritten",
+    "überschwemmt",
+    "überschwemmung",
+    "überschwemmungen",
+    "überschwenglich",
+    "überschwengliche",
+    "überschwenglichen",
+    "überschwengliches",
+    "überschätzt",
+    "übersee",
+    "übersehbaren",
+    "übersehen",
+    "übersehenden",
+    "übersetzen",
+    "übersetzt",
+    "übersetzte",
+    "übersetzung",
+    "übersiedeln",
+    "übersiedelt",
+    "übersiedelte",
+    "übersiedelten",
+    "übersiedlung",
+    "übersinnlich",
+    "übersinnliche",
+    "übersinnlichen",
+    "übersinnlicher",
+    "übersinnliches",
+    "überspannt",
+    "übersponnen",
+    "übersprang",
+    "überstanden",
+    "überstehen",
+    "übersteigt",
+    "überstieg",
+    "überstiegen",
+    "überstrapaziert",
+    "überstrichen",
+    "überströmendem",
+    "überströmender",
+    "überstürzend",
+    "übersäete",
+    "übersät",
+    "übertrage",
+    "übertragen",
+    "übertragenen",
+    "übertreibe",
+    "übertreiben",
+    "übertreibungen",
+    "übertreten",
+    "übertretung",
+    "übertretungen",
+    "übertrieb",
+    "übertrieben",
+    "übertriebener",
+    "übertrifft",
+    "übertroffen",
+    "übertönen",
+    "überverdienstlicher",
+    "übervorteilen",
+    "übervorteilend",
+    "übervölkert",
+    "überwachen",
+    "überwachsen",
+    "überwacht",
+    "überwachte",
+    "überwachung",
+    "überwand",
+    "überweisen",
+    "überwiegend",
+    "überwiegender",
+    "überwiegt",
+    "überwies",
+    "überwiesen",
+    "überwinden",
+    "überwinder",
+    "überwindet",
+    "überwindung",
+    "überwog",
+    "überworfen",
+    "überwuchert",
+    "überwucherte",
+    "überwunden",
+    "überwundener",
+    "überwältigen",
+    "überwältigt",
+    "überzeuge",
+    "überzeugen",
+    "überzeugend",
+    "überzeugende",
+    "überzeugt",
+    "überzeugte",
+    "überzeugten",
+    "überzeugung",
+    "überzeugungen",
+    "überzieht",
+    "überzogen",
+    "überzogenen",
+    "überzuspringen",
+    "übet",
+    "üble",
+    "üblen",
+    "übler",
+    "übles",
+    "üblich",
+    "übliche",
+    "übrig",
+    "übrigbleiben",
+    "übrigbleibt",
+    "übrigblieb",
+    "übrigblieben",
+    "übrige",
+    "übrigen",
+    "übrigens",
+    "übriger",
+    "übriges",
+    "übriggeblieben",
+    "übriggebliebene",
+    "übriggebliebenen",
+    "übriggelassen",
+    "übriglassen",
+    "übriglasset",
+    "übriglässt",
+    "übt",
+    "übte",
+    "übung",
+    "übungen",
+    "üppig",
+    "üppige",
+    "üppigen",
+};
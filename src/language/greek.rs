@@ -0,0 +1,80 @@
+use std::ops::RangeInclusive;
+
+use phf::phf_set;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use unicode_script::{Script, UnicodeScript};
+
+use super::{contains_lowercase, Language, LanguageId};
+
+mod words;
+
+use words::WORDS;
+
+/// Greek, behind the `greek` cargo feature. Also the profile used for Greek
+/// script text.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Greek;
+
+impl Language for Greek {
+    fn id(&self) -> LanguageId {
+        LanguageId::Greek
+    }
+
+    fn script(&self) -> Script {
+        Script::Greek
+    }
+
+    fn is_letter(&self, c: char) -> bool {
+        c.is_alphabetic() && c.script() == Script::Greek
+    }
+
+    fn is_vowel(&self, c: char) -> bool {
+        "αεηιουωάέήίόύώϊϋΐΰ".contains(c)
+    }
+
+    fn is_common_pair(&self, pair: &str) -> bool {
+        COMMON_CHAR_PAIRS.contains(pair)
+    }
+
+    fn is_common_trigram(&self, trigram: &str) -> bool {
+        COMMON_TRIGRAMS.contains(trigram)
+    }
+
+    fn is_common_quadgram(&self, quadgram: &str) -> bool {
+        COMMON_QUADGRAMS.contains(quadgram)
+    }
+
+    /// Words are also looked up without their accents, since Greek is often
+    /// typed without them and the stop word list leaves them out
+    fn contains_word(&self, word: &str) -> bool {
+        contains_lowercase(&WORDS, word) || {
+            let plain: String = word.nfd().filter(|&c| !is_combining_mark(c)).collect();
+            plain != word && contains_lowercase(&WORDS, &plain)
+        }
+    }
+
+    fn entropy_range(&self) -> RangeInclusive<f64> {
+        3.5..=4.7
+    }
+}
+
+static COMMON_CHAR_PAIRS: phf::Set<&'static str> = phf_set! {
+    "αι", "ου", "ει", "το", "τα", "να", "κα", "πο", "ια", "ερ",
+    "ην", "ης", "ος", "στ", "ντ", "απ", "με", "ον", "ρα", "ρο",
+    "λα", "ατ", "οι", "ασ", "ετ", "ισ", "πα", "ικ", "αν", "τη",
+    "ρι", "ελ", "λο", "ορ", "αλ", "νε", "κο", "ευ", "σε", "τι",
+    "μα", "αρ", "ήτ", "επ", "ιο", "ων", "ας", "ές", "όλ", "ία",
+};
+
+static COMMON_TRIGRAMS: phf::Set<&'static str> = phf_set! {
+    "και", "του", "την", "της", "ται", "των", "στη", "που", "ειν", "ναι",
+    "ματ", "για", "απο", "από", "ους", "οντ", "παρ", "ική", "στο", "προ",
+    "ότη", "ρια", "ουν", "ετα", "ολο", "ντα", "ικο", "ένα", "μια", "σει",
+    "ηση", "τικ", "ερα", "αυτ", "λλα", "κατ", "μετ", "ενο", "όσμ", "ωση",
+};
+
+static COMMON_QUADGRAMS: phf::Set<&'static str> = phf_set! {
+    "είνα", "ίναι", "στην", "στον", "αυτό", "ματα", "όταν", "μετά",
+    "αλλά", "τους", "ήταν", "ικός", "ησης", "ότητ", "τητα", "όλοι",
+};
@@ -0,0 +1,923 @@
+use phf::phf_set;
+
+pub static WORDS: phf::Set<&'static str> = phf_set! {
+    "άδειες",
+    "άλλα",
+    "άλλες",
+    "άλλη",
+    "άλλον",
+    "άλλους",
+    "άλλων",
+    "άμεσα",
+    "άνδρας",
+    "άνθρωποι",
+    "άνθρωπος",
+    "άξιες",
+    "άρνηση",
+    "άσκηση",
+    "άσυλο",
+    "άτομο",
+    "έθνη",
+    "έκταση",
+    "έκφρασης",
+    "έμμεσα",
+    "ένα",
+    "ένδικα",
+    "έσχατο",
+    "έτσι",
+    "έχει",
+    "έχουν",
+    "ή",
+    "ίδια",
+    "ίδιο",
+    "ίδιων",
+    "ίσα",
+    "ίση",
+    "ίσης",
+    "ίσοι",
+    "ίσους",
+    "ίσχυε",
+    "ίσων",
+    "αγαθά",
+    "αδίκημα",
+    "αδελφοσύνης",
+    "αθλιότητα",
+    "αθώος",
+    "ακόμα",
+    "αλλάξει",
+    "αλλα",
+    "αλλαγή",
+    "αλληλογραφία",
+    "αμερόληπτο",
+    "αμοιβή",
+    "αμοιβής",
+    "αν",
+    "ανάλογα",
+    "ανάμεσα",
+    "ανάπαυση",
+    "ανάπτυξη",
+    "ανέλαβαν",
+    "αναγκάζεται",
+    "αναγνωρίζουν",
+    "αναγνώριση",
+    "αναζητεί",
+    "αναπαλλοτρίωτων",
+    "αναπηρία",
+    "αναπτυχθεί",
+    "ανδρών",
+    "ανεκτικότητα",
+    "ανεξάρτητα",
+    "ανεξάρτητη",
+    "ανεξάρτητο",
+    "ανεξαρτήτων",
+    "ανεργία",
+    "ανθρωπίνων",
+    "ανθρωπινα",
+    "ανθρώπινα",
+    "ανθρώπινη",
+    "ανθρώπινης",
+    "ανθρώπου",
+    "ανοικτή",
+    "αντίθετα",
+    "αντίθετες",
+    "αντίστοιχη",
+    "αντι",
+    "αντιλήψεων",
+    "αντιπροσώπους",
+    "ανώτατη",
+    "αξία",
+    "αξιοπρέπεια",
+    "αξιοπρέπειας",
+    "αξιόποινη",
+    "αξιόποινο",
+    "απάνθρωπη",
+    "απέναντι",
+    "απαγορεύονται",
+    "απαιτήσεις",
+    "απαραίτητα",
+    "απαραίτητες",
+    "απο",
+    "αποβλέπει",
+    "αποβλέπουν",
+    "αποδοχές",
+    "αποκλειστικό",
+    "απολαμβάνουν",
+    "απολύτως",
+    "απορρέουν",
+    "αποτελεί",
+    "αποτελεσματικά",
+    "αποτελεσματική",
+    "αποτελεσματικό",
+    "αποφασίσει",
+    "αποφασισμένοι",
+    "από",
+    "απόλαυση",
+    "αρθρο",
+    "αρμόδια",
+    "αρρώστια",
+    "αρχές",
+    "ασκεί",
+    "ασκούνται",
+    "ασφάλεια",
+    "ασφάλιση",
+    "αυθαίρετα",
+    "αυθαίρετες",
+    "αυτά",
+    "αυτές",
+    "αυτή",
+    "αυτήν",
+    "αυτα",
+    "αυτεσ",
+    "αυτη",
+    "αυτο",
+    "αυτοι",
+    "αυτοσ",
+    "αυτουσ",
+    "αυτού",
+    "αυτων",
+    "αυτό",
+    "αυτόν",
+    "αυτών",
+    "αἱ",
+    "αἳ",
+    "αἵ",
+    "αὐτόσ",
+    "αὐτὸς",
+    "αὖ",
+    "βάσιμο",
+    "βαθμίδα",
+    "βαρβαρότητας",
+    "βαρύτερη",
+    "βασανιστήρια",
+    "βασική",
+    "βασικό",
+    "βιοτικό",
+    "βρίσκεται",
+    "βρίσκονται",
+    "γάμο",
+    "γάμος",
+    "γάμου",
+    "γάρ",
+    "γέννηση",
+    "γίνεται",
+    "γα",
+    "γα^",
+    "γε",
+    "γενικη",
+    "γενικού",
+    "γεννιούνται",
+    "γεροντική",
+    "για",
+    "γλώσσα",
+    "γνώμες",
+    "γνώμης",
+    "γονείς",
+    "γοῦν",
+    "γυναίκα",
+    "γυναικών",
+    "γὰρ",
+    "δ'",
+    "δέ",
+    "δή",
+    "δίκαια",
+    "δίκαιες",
+    "δίκαιης",
+    "δίκαιο",
+    "δίκη",
+    "δίωξης",
+    "δαί",
+    "δαίσ",
+    "δαὶ",
+    "δαὶς",
+    "δε",
+    "δεκεμβριου",
+    "δεκτός",
+    "δεν",
+    "δημιουργήσουν",
+    "δημοκρατική",
+    "δημόσια",
+    "δημόσιας",
+    "δημόσιες",
+    "δι'",
+    "διά",
+    "διάκριση",
+    "διάλυσή",
+    "διάρκεια",
+    "διάταξη",
+    "διαδίδει",
+    "διαδικασία",
+    "διαδικασίας",
+    "διακήρυξαν",
+    "διακήρυξη",
+    "διακήρυξης",
+    "διακηρυξη",
+    "διακηρυχθεί",
+    "διακηρύσσει",
+    "διακυβέρνηση",
+    "διαμονής",
+    "διαπιστωθεί",
+    "διαρκώς",
+    "διατήρηση",
+    "διδασκαλία",
+    "διεθνές",
+    "διεθνή",
+    "διεθνής",
+    "διεθνούς",
+    "διεξάγονται",
+    "δική",
+    "δικαίου",
+    "δικαίωμα",
+    "δικαιοδοσία",
+    "δικαιοσύνης",
+    "δικαιούται",
+    "δικαιωμάτων",
+    "δικαιωματα",
+    "δικαιώματα",
+    "δικαστήρια",
+    "δικαστήριο",
+    "διὰ",
+    "δοθεί",
+    "δουλεία",
+    "δουλείας",
+    "δουλειάς",
+    "δουλεμπόριο",
+    "δραστηριοτήτων",
+    "δυνατή",
+    "δυνατότητες",
+    "δυσμενή",
+    "δυσμενείς",
+    "δωρεάν",
+    "δύο",
+    "δὲ",
+    "δὴ",
+    "δ’",
+    "είδος",
+    "είδους",
+    "είναι",
+    "είτε",
+    "εαν",
+    "εγγυήσεις",
+    "εγκαταλείπει",
+    "εδαφική",
+    "εθνικά",
+    "εθνική",
+    "εθνικότητας",
+    "εθνών",
+    "ειδικής",
+    "ειδικότερα",
+    "ειμαι",
+    "ειμαστε",
+    "ειναι",
+    "ειρήνης",
+    "ειρηνικούς",
+    "εισαι",
+    "ειστε",
+    "εκδηλώνει",
+    "εκδικάζεται",
+    "εκείνη",
+    "εκεινα",
+    "εκεινεσ",
+    "εκεινη",
+    "εκεινο",
+    "εκεινοι",
+    "εκεινοσ",
+    "εκεινουσ",
+    "εκεινων",
+    "εκλέγει",
+    "εκλεγμένους",
+    "εκλογές",
+    "εκλογής",
+    "εκπαίδευση",
+    "εκπληρωθεί",
+    "εκτελεί",
+    "εκφράζεται",
+    "ελευθερία",
+    "ελευθερίας",
+    "ελευθερίες",
+    "ελευθεριών",
+    "ελεύθερα",
+    "ελεύθερη",
+    "ελεύθερο",
+    "ελεύθεροι",
+    "ενέργειες",
+    "ενίσχυση",
+    "εναντίον",
+    "ενθαρρύνεται",
+    "ενοχή",
+    "ενω",
+    "ενός",
+    "εξέγερση",
+    "εξαγγέλλονται",
+    "εξαιρετική",
+    "εξαιτίας",
+    "εξασφαλίζει",
+    "εξασφαλίζεται",
+    "εξασφαλίσει",
+    "εξασφαλίσουν",
+    "εξασφαλιστεί",
+    "εξεγείρουν",
+    "εξορίζεται",
+    "εξουσίας",
+    "εξώγαμα",
+    "επ",
+    "επάγγελμά",
+    "επίπεδο",
+    "επίσης",
+    "επαγγελματική",
+    "επειδή",
+    "επεμβάσεις",
+    "επι",
+    "επιβάλλεται",
+    "επιδίδεται",
+    "επιδίωξη",
+    "επικαλείται",
+    "επικαλεστεί",
+    "επικρατεί",
+    "επιλέγει",
+    "επιλέγουν",
+    "επιστημονική",
+    "επιστρέφει",
+    "επιτρέπεται",
+    "εργάζεται",
+    "εργαζόμενος",
+    "εργασία",
+    "εργασίας",
+    "ερμηνευθεί",
+    "εσωτερικά",
+    "εσωτερικό",
+    "ευημερία",
+    "ευνοεί",
+    "ευρύτερης",
+    "εφαρμογή",
+    "εἰ",
+    "εἰμί",
+    "εἰμὶ",
+    "εἰς",
+    "εἰσ",
+    "εἴ",
+    "εἴμι",
+    "εἴτε",
+    "ζει",
+    "ζητά",
+    "ζωή",
+    "ζωής",
+    "η",
+    "ηθικά",
+    "ηθικής",
+    "ηλικία",
+    "ηνωμένων",
+    "θέληση",
+    "θέλησης",
+    "θα",
+    "θεμέλιο",
+    "θεμελιακά",
+    "θεμελιακών",
+    "θεωρείται",
+    "θρησκεία",
+    "θρησκείας",
+    "θρησκείες",
+    "θρησκευτικές",
+    "θρησκευτικών",
+    "ιατρική",
+    "ιδέες",
+    "ιδανικό",
+    "ιδιαίτερα",
+    "ιδιοκτησία",
+    "ιδιοκτησίας",
+    "ιδιωτικά",
+    "ιδιωτική",
+    "ιδρύει",
+    "ιδρύουν",
+    "ιθαγένειά",
+    "ιθαγένεια",
+    "ιθαγένειας",
+    "ικανοποίηση",
+    "ικανοποιητικές",
+    "ικανοποιητικής",
+    "ικανοποιούνται",
+    "ικανό",
+    "ικανότητες",
+    "ισωσ",
+    "ισότητα",
+    "κ",
+    "κάθε",
+    "καί",
+    "καίτοι",
+    "καθ",
+    "καθένας",
+    "καθήκοντα",
+    "καθεστώς",
+    "καθεστώτος",
+    "καθολική",
+    "καθώς",
+    "και",
+    "καλές",
+    "καλλιτεχνική",
+    "καλού",
+    "καλύτερες",
+    "καμία",
+    "καμιά",
+    "κανένα",
+    "κανείς",
+    "κατ",
+    "κατά",
+    "κατάσταση",
+    "κατα",
+    "καταβάλλει",
+    "καταγωγή",
+    "καταδικάζεται",
+    "καταδιώκεται",
+    "κατανόηση",
+    "καταπίεσης",
+    "καταστατικό",
+    "κατατείνουν",
+    "καταφύγιο",
+    "κατηγορίας",
+    "κατηγορούμενος",
+    "κατοικία",
+    "κατὰ",
+    "καὶ",
+    "κηδεμονία",
+    "κι",
+    "κοινού",
+    "κοινωνία",
+    "κοινωνίας",
+    "κοινωνικές",
+    "κοινωνική",
+    "κοινωνικής",
+    "κοινωνικού",
+    "κοινωνικών",
+    "κοινό",
+    "κοινότητα",
+    "κοινότητας",
+    "κράτη",
+    "κράτος",
+    "κράτους",
+    "κρατείται",
+    "κρατικής",
+    "κρατών",
+    "κυκλοφορεί",
+    "κυριαρχίας",
+    "κόσμο",
+    "κόσμου",
+    "κἀν",
+    "κἂν",
+    "λαοί",
+    "λαούς",
+    "λατρεία",
+    "λαϊκή",
+    "λογική",
+    "λογικό",
+    "λογοτεχνική",
+    "λυτρωμένοι",
+    "μέλη",
+    "μέλος",
+    "μέν",
+    "μέριμνας",
+    "μέσα",
+    "μέσο",
+    "μή",
+    "μήτε",
+    "μα",
+    "μαζί",
+    "με",
+    "μεθ",
+    "μελλονύμφων",
+    "μελών",
+    "μερικής",
+    "μετ",
+    "μετά",
+    "μετέχει",
+    "μετα",
+    "μεταξύ",
+    "μεταχείριση",
+    "μετὰ",
+    "μη",
+    "μην",
+    "μητρότητα",
+    "μια",
+    "μιας",
+    "μιλούν",
+    "μορφή",
+    "μπορεί",
+    "μπορούν",
+    "μυστική",
+    "μόνο",
+    "μόνος",
+    "μἐν",
+    "μὲν",
+    "μὴ",
+    "μὴν",
+    "να",
+    "νομικής",
+    "νομικού",
+    "νόμιμα",
+    "νόμο",
+    "νόμοι",
+    "νόμος",
+    "νόμου",
+    "νόμους",
+    "ο",
+    "οδήγησαν",
+    "οηε",
+    "οι",
+    "οικογένειά",
+    "οικογένεια",
+    "οικογένειας",
+    "οικονομικές",
+    "οικονομικών",
+    "οικουμενική",
+    "οικουμενικη",
+    "ολικής",
+    "ολοκληρωμένη",
+    "ομάδα",
+    "ομάδες",
+    "ομωσ",
+    "οποία",
+    "οποίας",
+    "οποίες",
+    "οποίο",
+    "οποιαδήποτε",
+    "οποιεσδήποτε",
+    "οποιοδήποτε",
+    "οποιονδήποτε",
+    "οπωσ",
+    "ορίζονται",
+    "οργάνωση",
+    "οργανισμό",
+    "ορισμένο",
+    "οσο",
+    "οτι",
+    "ουσιαστική",
+    "οφείλουν",
+    "ούτε",
+    "οἱ",
+    "οἳ",
+    "οἷς",
+    "οὐ",
+    "οὐδ",
+    "οὐδέ",
+    "οὐδείσ",
+    "οὐδεὶς",
+    "οὐδὲ",
+    "οὐδὲν",
+    "οὐκ",
+    "οὐχ",
+    "οὐχὶ",
+    "οὓς",
+    "οὔτε",
+    "οὕτω",
+    "οὕτως",
+    "οὕτωσ",
+    "οὖν",
+    "οὗ",
+    "οὗτος",
+    "οὗτοσ",
+    "πάλι",
+    "πάντα",
+    "πέρα",
+    "πίστη",
+    "παίρνει",
+    "παγκόσμια",
+    "παιδεία",
+    "παιδείας",
+    "παιδιά",
+    "παιδική",
+    "παντρεύονται",
+    "παρ",
+    "παρά",
+    "παρέχει",
+    "παρέχεται",
+    "παρα",
+    "παραβίαζε",
+    "παραβιάζουν",
+    "παραγνώριση",
+    "παραγωγή",
+    "παραλείψεις",
+    "παρούσα",
+    "παρούσας",
+    "παρὰ",
+    "πεποιθήσεις",
+    "πεποιθήσεων",
+    "περί",
+    "περίθαλψη",
+    "περίθαλψης",
+    "περίπτωση",
+    "περιλαμβάνεται",
+    "περιοδικά",
+    "περιοδικές",
+    "περιορισμούς",
+    "περιορισμό",
+    "περιουσία",
+    "περιοχή",
+    "περιπτώσεις",
+    "περιστάσεων",
+    "περιφρόνηση",
+    "περὶ",
+    "πιο",
+    "πιστεύουν",
+    "πλήρεις",
+    "πλήρη",
+    "πλαίσια",
+    "πληθυσμούς",
+    "πληροφορίες",
+    "πνευματική",
+    "πνεύμα",
+    "ποια",
+    "ποιεσ",
+    "ποινή",
+    "ποινική",
+    "ποινικής",
+    "ποινικού",
+    "ποινικό",
+    "ποιο",
+    "ποιοι",
+    "ποιοσ",
+    "ποιουσ",
+    "ποιων",
+    "πολιτικές",
+    "πολιτικού",
+    "πολιτιστικών",
+    "ποτε",
+    "που",
+    "ποῦ",
+    "πράξεις",
+    "πράξεων",
+    "πράξη",
+    "πρέπει",
+    "πραγματικό",
+    "πραγματώνονται",
+    "προ",
+    "προάγει",
+    "προάσπιση",
+    "προέρχεται",
+    "προικισμένοι",
+    "προκηρύσσει",
+    "προοδευτικά",
+    "προοιμιο",
+    "προοπτική",
+    "προς",
+    "προσ",
+    "προσβολές",
+    "προσπάθεια",
+    "προστασία",
+    "προστασίας",
+    "προστατεύεται",
+    "προστατεύονται",
+    "προστατεύουν",
+    "προσφεύγει",
+    "προσωπική",
+    "προσωπικότητάς",
+    "προσωπικότητας",
+    "προτεραιότητα",
+    "πρωτοβουλία",
+    "πρόκειται",
+    "πρόκληση",
+    "πρόοδο",
+    "πρόσ",
+    "πρόσβαση",
+    "πρὸ",
+    "πρὸς",
+    "πως",
+    "πωσ",
+    "ρουχισμό",
+    "σε",
+    "σεβασμού",
+    "σεβασμό",
+    "σεβασμός",
+    "σημαίνει",
+    "σημασία",
+    "σκέψη",
+    "σκέψης",
+    "σκληρή",
+    "σκοπούς",
+    "σκοπό",
+    "στα",
+    "στερείται",
+    "στερηθεί",
+    "στη",
+    "στην",
+    "στιγμή",
+    "στις",
+    "στο",
+    "στοιχείο",
+    "στοιχειώδη",
+    "στοιχειώδης",
+    "στον",
+    "στους",
+    "στρέφεται",
+    "συλλαμβάνεται",
+    "συμμετέχει",
+    "συμπεριφέρονται",
+    "συμπληρώνεται",
+    "συμφέροντά",
+    "συμφερόντων",
+    "συνέπειες",
+    "συνέρχεται",
+    "συναίνεση",
+    "συναφθεί",
+    "συνδικάτα",
+    "συνείδηση",
+    "συνείδησης",
+    "συνελευση",
+    "συνεργασία",
+    "συνεταιρίζεται",
+    "συνθήκες",
+    "συνιστούσαν",
+    "συντήρησής",
+    "συντελέσουν",
+    "συνόλου",
+    "σχέσεων",
+    "σωματείο",
+    "σόσ",
+    "σύ",
+    "σύμφυτη",
+    "σύμφωνα",
+    "σύν",
+    "σύνταγμα",
+    "σὸς",
+    "σὺ",
+    "σὺν",
+    "τά",
+    "τάξη",
+    "τάξης",
+    "τέλεση",
+    "τέτοια",
+    "τέχνες",
+    "τήν",
+    "τί",
+    "τίμιες",
+    "τίς",
+    "τίσ",
+    "τα",
+    "ταπεινωτική",
+    "ταυτότητα",
+    "ταῖς",
+    "τε",
+    "τελέστηκαν",
+    "τελέστηκε",
+    "τελετών",
+    "τεχνική",
+    "τη",
+    "την",
+    "της",
+    "τησ",
+    "τι",
+    "τιμής",
+    "τινα",
+    "τις",
+    "τισ",
+    "το",
+    "τοί",
+    "τοι",
+    "τοιοῦτος",
+    "τοιοῦτοσ",
+    "τον",
+    "τοτε",
+    "του",
+    "τουλάχιστον",
+    "τους",
+    "τούσ",
+    "τοὺς",
+    "τοῖς",
+    "τοῦ",
+    "τροφή",
+    "τρόμο",
+    "τυραννίας",
+    "των",
+    "τό",
+    "τόν",
+    "τόπο",
+    "τόσο",
+    "τότε",
+    "τὰ",
+    "τὰς",
+    "τὴν",
+    "τὸ",
+    "τὸν",
+    "τῆς",
+    "τῆσ",
+    "τῇ",
+    "τῶν",
+    "τῷ",
+    "υγεία",
+    "υλικά",
+    "υπάρχει",
+    "υπεξουσία",
+    "υπεράσπισή",
+    "υπηρεσίες",
+    "υποβάλλεται",
+    "υποστεί",
+    "υποχρέωση",
+    "υποχρεωθεί",
+    "υποχρεωτική",
+    "υποχρεώσεις",
+    "υπό",
+    "υπόθεσή",
+    "υπόκειται",
+    "υπόληψης",
+    "υφίσταται",
+    "υψηλή",
+    "φθάσουν",
+    "φιλία",
+    "φιλικών",
+    "φυλές",
+    "φυλή",
+    "φυλής",
+    "φυσικό",
+    "φύλο",
+    "χάρτη",
+    "χαίρεται",
+    "χηρεία",
+    "χρέος",
+    "χρόνο",
+    "χρόνου",
+    "χρώμα",
+    "χωρίς",
+    "χωρών",
+    "χώρα",
+    "χώρας",
+    "χώρες",
+    "ψηφοφορία",
+    "ως",
+    "ωσ",
+    "ωσότου",
+    "όλα",
+    "όλες",
+    "όλη",
+    "όλο",
+    "όλοι",
+    "όλους",
+    "όπου",
+    "όπως",
+    "όργανο",
+    "όρους",
+    "όσο",
+    "ότι",
+    "ώστε",
+    "ἀλλ'",
+    "ἀλλά",
+    "ἀλλὰ",
+    "ἀλλ’",
+    "ἀπ",
+    "ἀπό",
+    "ἀπὸ",
+    "ἀφ",
+    "ἂν",
+    "ἃ",
+    "ἄλλος",
+    "ἄλλοσ",
+    "ἄν",
+    "ἄρα",
+    "ἅμα",
+    "ἐάν",
+    "ἐγώ",
+    "ἐγὼ",
+    "ἐκ",
+    "ἐμόσ",
+    "ἐμὸς",
+    "ἐν",
+    "ἐξ",
+    "ἐπί",
+    "ἐπεὶ",
+    "ἐπὶ",
+    "ἐστι",
+    "ἐφ",
+    "ἐχει",
+    "ἐὰν",
+    "ἑαυτοῦ",
+    "ἔτι",
+    "ἡ",
+    "ἢ",
+    "ἣ",
+    "ἤ",
+    "ἥ",
+    "ἧς",
+    "ἵνα",
+    "ὁ",
+    "ὃ",
+    "ὃν",
+    "ὃς",
+    "ὅ",
+    "ὅδε",
+    "ὅθεν",
+    "ὅπερ",
+    "ὅς",
+    "ὅσ",
+    "ὅστις",
+    "ὅστισ",
+    "ὅτε",
+    "ὅτι",
+    "ὑμόσ",
+    "ὑπ",
+    "ὑπέρ",
+    "ὑπό",
+    "ὑπὲρ",
+    "ὑπὸ",
+    "ὡς",
+    "ὡσ",
+    "ὥς",
+    "ὥστε",
+    "ὦ",
+    "ᾧ",
+};
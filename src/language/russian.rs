@@ -0,0 +1,75 @@
+use std::ops::RangeInclusive;
+
+use phf::phf_set;
+use unicode_script::{Script, UnicodeScript};
+
+use super::{contains_lowercase, Language, LanguageId};
+
+mod words;
+
+use words::WORDS;
+
+/// Russian, behind the `russian` cargo feature. Also the profile used for
+/// Cyrillic text.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Russian;
+
+impl Language for Russian {
+    fn id(&self) -> LanguageId {
+        LanguageId::Russian
+    }
+
+    fn script(&self) -> Script {
+        Script::Cyrillic
+    }
+
+    fn is_letter(&self, c: char) -> bool {
+        c.is_alphabetic() && c.script() == Script::Cyrillic
+    }
+
+    fn is_vowel(&self, c: char) -> bool {
+        "аеёиоуыэюя".contains(c)
+    }
+
+    fn is_common_pair(&self, pair: &str) -> bool {
+        COMMON_CHAR_PAIRS.contains(pair)
+    }
+
+    fn is_common_trigram(&self, trigram: &str) -> bool {
+        COMMON_TRIGRAMS.contains(trigram)
+    }
+
+    fn is_common_quadgram(&self, quadgram: &str) -> bool {
+        COMMON_QUADGRAMS.contains(quadgram)
+    }
+
+    fn contains_word(&self, word: &str) -> bool {
+        contains_lowercase(&WORDS, word)
+    }
+
+    fn entropy_range(&self) -> RangeInclusive<f64> {
+        3.7..=4.9
+    }
+}
+
+static COMMON_CHAR_PAIRS: phf::Set<&'static str> = phf_set! {
+    "ст", "но", "то", "на", "ен", "ов", "ни", "ра", "во", "ко",
+    "ал", "ре", "пр", "ер", "ор", "ли", "по", "ан", "ос", "ло",
+    "го", "ет", "ка", "не", "ть", "ол", "от", "ел", "ва", "ла",
+    "ит", "ес", "ин", "ны", "ом", "ри", "он", "ве", "та", "ак",
+    "де", "ро", "ми", "ле", "ти", "ем", "ог", "ой", "ат", "ск",
+};
+
+static COMMON_TRIGRAMS: phf::Set<&'static str> = phf_set! {
+    "ени", "ост", "про", "ств", "ого", "ния", "его", "ать", "ова", "что",
+    "сто", "при", "ани", "ере", "как", "тор", "пре", "ные", "ско", "тел",
+    "ный", "ель", "ест", "нов", "енн", "ает", "она", "все", "сти", "тся",
+    "ски", "кот", "ред", "пол", "рав", "ком", "бол", "мен", "лен", "нос",
+    "дел", "вер", "был", "это", "ное", "шли", "оск", "ено", "ала", "ото",
+};
+
+static COMMON_QUADGRAMS: phf::Set<&'static str> = phf_set! {
+    "ения", "ство", "ости", "ного", "ател", "тель", "пред", "льно",
+    "ение", "ться", "этом", "было", "кото", "отор", "орый", "тобы",
+    "чтоб", "олее", "если", "очен", "сейч", "можн", "огда", "тако",
+};
@@ -0,0 +1,49677 @@
+use phf::phf_set;
+
+pub static WORDS: phf::Set<&'static str> = phf_set! {
+    "а",
+    "абиссинию",
+    "абонемента",
+    "абонировался",
+    "абонируйся",
+    "абонируюсь",
+    "абрикосы",
+    "август",
+    "августа",
+    "августе",
+    "авдотье",
+    "авдотьей",
+    "авдотьи",
+    "авдотью",
+    "авдотья",
+    "авенантненькая",
+    "аверка",
+    "аверкой",
+    "авиатор",
+    "авиаторов",
+    "авиация",
+    "авось",
+    "авраама",
+    "австрии",
+    "австрийский",
+    "автобиографию",
+    "автографом",
+    "автомобили",
+    "автомобиль",
+    "автор",
+    "авторам",
+    "авторитет",
+    "авторитета",
+    "авторитеты",
+    "авторов",
+    "автором",
+    "ага",
+    "агадир",
+    "агамемнон",
+    "агаповну",
+    "агафье",
+    "агафьей",
+    "агафьи",
+    "агафью",
+    "агафья",
+    "агента",
+    "агонии",
+    "агроном",
+    "агрономии",
+    "ад",
+    "ада",
+    "адам",
+    "адамант",
+    "адвокат",
+    "адвокатом",
+    "адвокатского",
+    "адвокатской",
+    "адвокатскую",
+    "административный",
+    "административных",
+    "администратор",
+    "адмиралы",
+    "адом",
+    "адрес",
+    "адреса",
+    "адресный",
+    "адресовано",
+    "адресом",
+    "адресу",
+    "адрианополя",
+    "адриатической",
+    "адская",
+    "адский",
+    "ажно",
+    "азарта",
+    "азартом",
+    "азбуку",
+    "азвольте",
+    "азиатской",
+    "азиатчина",
+    "азии",
+    "азию",
+    "азраил",
+    "азы",
+    "аи",
+    "ай",
+    "айвазовского",
+    "айда",
+    "ак",
+    "академии",
+    "академию",
+    "акации",
+    "акацию",
+    "акварелью",
+    "аккомпанемент",
+    "аккомпанировал",
+    "аккорд",
+    "аккорда",
+    "аккордом",
+    "аккуратнее",
+    "аккуратно",
+    "аккуратного",
+    "аккуратный",
+    "акмеизм",
+    "аксельбантами",
+    "аксинье",
+    "аксиома",
+    "акт",
+    "акта",
+    "акте",
+    "актер",
+    "актерам",
+    "актером",
+    "актеры",
+    "активной",
+    "актриса",
+    "актрисой",
+    "актрисы",
+    "акулина",
+    "акулине",
+    "акулиной",
+    "акулину",
+    "акулины",
+    "акурат",
+    "акушером",
+    "акцентом",
+    "акцизе",
+    "акции",
+    "акций",
+    "акциями",
+    "акциях",
+    "ал",
+    "алая",
+    "алгебра",
+    "алгеброй",
+    "алгебру",
+    "алебастра",
+    "алеко",
+    "александр",
+    "александра",
+    "александринского",
+    "александрович",
+    "александровна",
+    "александровне",
+    "александровной",
+    "александровну",
+    "александровны",
+    "александром",
+    "александру",
+    "алексевна",
+    "алексеев",
+    "алексеева",
+    "алексеевич",
+    "алексеевича",
+    "алексеевичем",
+    "алексееву",
+    "алексеевым",
+    "алексеевыми",
+    "алексеич",
+    "алексеичем",
+    "алексей",
+    "алексея",
+    "алела",
+    "алена",
+    "алене",
+    "алену",
+    "алены",
+    "аленькие",
+    "алеши",
+    "алешка",
+    "алеют",
+    "алея",
+    "али",
+    "аллах",
+    "аллегории",
+    "аллее",
+    "аллеи",
+    "аллею",
+    "аллея",
+    "аллеям",
+    "алмаз",
+    "алмаза",
+    "алмазах",
+    "алмазы",
+    "алой",
+    "алом",
+    "алоэ",
+    "алтарь",
+    "алтарю",
+    "алтаря",
+    "алчущим",
+    "алые",
+    "алый",
+    "алым",
+    "алыми",
+    "алых",
+    "аль",
+    "альбертыч",
+    "альбом",
+    "альбомами",
+    "альмы",
+    "альтшауер",
+    "альянов",
+    "амалией",
+    "амалии",
+    "амалию",
+    "амалия",
+    "амаль",
+    "амбар",
+    "амбара",
+    "амбарах",
+    "амбары",
+    "амбиций",
+    "амбицию",
+    "америка",
+    "американским",
+    "америке",
+    "америки",
+    "америку",
+    "аметист",
+    "аминь",
+    "амням",
+    "ан",
+    "анализ",
+    "анализа",
+    "анализировал",
+    "анализирующей",
+    "анализом",
+    "анализу",
+    "аналоем",
+    "анамнясь",
+    "ананас",
+    "ананасы",
+    "анастасий",
+    "анатомии",
+    "анатомию",
+    "ангажемент",
+    "ангары",
+    "ангел",
+    "ангела",
+    "ангелины",
+    "ангелов",
+    "ангелом",
+    "ангелочка",
+    "ангелу",
+    "ангелы",
+    "ангельски",
+    "ангельские",
+    "ангельской",
+    "англии",
+    "английская",
+    "английски",
+    "английский",
+    "английского",
+    "английском",
+    "английскому",
+    "англичан",
+    "англичанах",
+    "англичане",
+    "англичанин",
+    "англию",
+    "андрее",
+    "андреев",
+    "андреевич",
+    "андреевна",
+    "андреевне",
+    "андреевной",
+    "андреевну",
+    "андреевны",
+    "андреевым",
+    "андреем",
+    "андреич",
+    "андреича",
+    "андреичем",
+    "андреичу",
+    "андрей",
+    "андрею",
+    "андрея",
+    "андрюша",
+    "андрюшанчик",
+    "андрюше",
+    "андрюшей",
+    "андрюши",
+    "андрюшка",
+    "андрюшке",
+    "андрюшку",
+    "андрюшу",
+    "ане",
+    "аней",
+    "анекдот",
+    "анекдоты",
+    "анечка",
+    "ани",
+    "анисье",
+    "анисьей",
+    "анисьи",
+    "аниська",
+    "анисью",
+    "анисья",
+    "анна",
+    "анне",
+    "анной",
+    "анну",
+    "анны",
+    "антверпен",
+    "антип",
+    "антипа",
+    "антипка",
+    "антипке",
+    "антипу",
+    "антон",
+    "антонов",
+    "антракт",
+    "антракте",
+    "анфилада",
+    "анфиса",
+    "анфисе",
+    "анчоусы",
+    "аню",
+    "аня",
+    "апатии",
+    "апатичен",
+    "апатически",
+    "апатический",
+    "апатическим",
+    "апатию",
+    "апатия",
+    "апелляции",
+    "апельсинничаешь",
+    "апельсинов",
+    "аплодирует",
+    "аплодируя",
+    "аполлона",
+    "апоплексический",
+    "апоплексию",
+    "аппетита",
+    "аппетитно",
+    "аппетитом",
+    "апраксина",
+    "апреле",
+    "апрель",
+    "апрельских",
+    "апреля",
+    "аптека",
+    "аптекарь",
+    "аптеке",
+    "аптеки",
+    "аптеку",
+    "аптечного",
+    "апухтинские",
+    "ар",
+    "аравийской",
+    "арапка",
+    "арапку",
+    "арбуз",
+    "арбузы",
+    "аргументом",
+    "арена",
+    "арендатором",
+    "аренду",
+    "аренды",
+    "арене",
+    "арену",
+    "арены",
+    "арест",
+    "арестант",
+    "арестанта",
+    "арестантов",
+    "арестантских",
+    "арестантской",
+    "арестанту",
+    "арестовать",
+    "арестовывают",
+    "арестуйте",
+    "арестуют",
+    "аресты",
+    "арий",
+    "аринушка",
+    "аристократическим",
+    "аристократическими",
+    "аристократических",
+    "аристократического",
+    "аристократическом",
+    "аристократическою",
+    "арифметика",
+    "арифметике",
+    "арифметику",
+    "ариша",
+    "арию",
+    "аркадий",
+    "аркадина",
+    "аркадиной",
+    "аркадию",
+    "аркадия",
+    "аркой",
+    "аркою",
+    "армейский",
+    "армией",
+    "армии",
+    "армию",
+    "армиями",
+    "армяк",
+    "армяками",
+    "армяке",
+    "армячишка",
+    "аромата",
+    "аромате",
+    "ароматном",
+    "ароматные",
+    "ароматным",
+    "ароматом",
+    "ароматы",
+    "артели",
+    "артельных",
+    "артельщик",
+    "артельщика",
+    "артельщиком",
+    "артельщику",
+    "артемий",
+    "артемью",
+    "артист",
+    "артиста",
+    "артистически",
+    "артистической",
+    "артистка",
+    "артистке",
+    "артисткой",
+    "артистов",
+    "артисты",
+    "артуром",
+    "арфа",
+    "арфам",
+    "арфе",
+    "арфы",
+    "архангел",
+    "архангельск",
+    "археолог",
+    "архив",
+    "архива",
+    "архимедова",
+    "архитектор",
+    "архитектором",
+    "архитектору",
+    "архитектура",
+    "аршин",
+    "аршина",
+    "аршине",
+    "асессором",
+    "асессорше",
+    "аскет",
+    "ассигнацию",
+    "ассигнациями",
+    "ассоциациях",
+    "астма",
+    "астрахани",
+    "астраханские",
+    "астров",
+    "астрова",
+    "астрову",
+    "астровым",
+    "астрономии",
+    "ась",
+    "атакует",
+    "атласам",
+    "атласная",
+    "атласном",
+    "атласным",
+    "атласными",
+    "атмосфере",
+    "атмосферы",
+    "атомах",
+    "атомов",
+    "атрибуты",
+    "аттестат",
+    "аттестате",
+    "ау",
+    "аудитории",
+    "аукцион",
+    "аукциона",
+    "ауле",
+    "аус",
+    "афанасий",
+    "афанасию",
+    "афанасия",
+    "афанасьев",
+    "афанасьевич",
+    "афанасьевича",
+    "афанасьевичу",
+    "афиша",
+    "африке",
+    "африки",
+    "африку",
+    "афросиньюшка",
+    "афросиньюшки",
+    "аффектацией",
+    "ах",
+    "ахали",
+    "аханья",
+    "ахают",
+    "ахиллес",
+    "ахиллесовской",
+    "ахиллесу",
+    "ахиллов",
+    "ахматовой",
+    "ахнул",
+    "ахнула",
+    "ахнули",
+    "ахнуть",
+    "ацтеки",
+    "аэроплан",
+    "аэроплана",
+    "б",
+    "ба",
+    "баб",
+    "баба",
+    "бабенка",
+    "бабками",
+    "бабой",
+    "бабок",
+    "бабочка",
+    "бабочки",
+    "бабочку",
+    "бабу",
+    "бабушек",
+    "бабушка",
+    "бабушке",
+    "бабушки",
+    "бабушкин",
+    "бабушкина",
+    "бабушкиной",
+    "бабушкой",
+    "бабушку",
+    "бабы",
+    "бабье",
+    "бабьей",
+    "бабьи",
+    "бабьим",
+    "бабья",
+    "багаж",
+    "багрово",
+    "багровой",
+    "багровому",
+    "багровые",
+    "багровым",
+    "багряный",
+    "багряным",
+    "бадья",
+    "бает",
+    "базар",
+    "базарах",
+    "базаре",
+    "базилик",
+    "баиньки",
+    "бай",
+    "байро",
+    "байрон",
+    "байрона",
+    "бакалеева",
+    "бакалееву",
+    "бакенами",
+    "бакенбард",
+    "бакенбарда",
+    "бакенбардами",
+    "бакенбарду",
+    "бакенбарды",
+    "бал",
+    "бала",
+    "балаган",
+    "балаганы",
+    "балалайка",
+    "балалайками",
+    "балами",
+    "балах",
+    "бале",
+    "балерины",
+    "балета",
+    "балете",
+    "балканах",
+    "балканы",
+    "балке",
+    "балкон",
+    "балкона",
+    "балконами",
+    "балконах",
+    "балконе",
+    "балконом",
+    "баллада",
+    "баллотироваться",
+    "баловал",
+    "балованых",
+    "баловать",
+    "баловень",
+    "баловника",
+    "баловню",
+    "баловство",
+    "балочов",
+    "балу",
+    "балуй",
+    "балующий",
+    "бальзак",
+    "бальтазару",
+    "банальной",
+    "бане",
+    "бани",
+    "банк",
+    "банках",
+    "банке",
+    "банки",
+    "банкирскую",
+    "банкирша",
+    "банков",
+    "банковский",
+    "банку",
+    "баночку",
+    "барабан",
+    "барабанил",
+    "барабанит",
+    "барабанить",
+    "бараков",
+    "баран",
+    "барана",
+    "бараний",
+    "баранина",
+    "бараниной",
+    "баранину",
+    "баранины",
+    "бараном",
+    "бараньим",
+    "барашками",
+    "барашком",
+    "барже",
+    "барин",
+    "барина",
+    "барине",
+    "барином",
+    "барину",
+    "барканом",
+    "барках",
+    "барки",
+    "бармгерциге",
+    "барон",
+    "барона",
+    "бароне",
+    "бароном",
+    "барону",
+    "бароны",
+    "барская",
+    "барские",
+    "барский",
+    "барских",
+    "барского",
+    "барское",
+    "барской",
+    "барском",
+    "барскую",
+    "барство",
+    "бартола",
+    "бархат",
+    "бархата",
+    "бархате",
+    "бархатную",
+    "бархатные",
+    "бархатный",
+    "бархатных",
+    "бархату",
+    "бархатцами",
+    "барчонка",
+    "барчонком",
+    "барчонок",
+    "барщина",
+    "барщину",
+    "бары",
+    "барыни",
+    "барынин",
+    "барыню",
+    "барыня",
+    "барыш",
+    "барыше",
+    "барышень",
+    "барышне",
+    "барышней",
+    "барышни",
+    "барышниной",
+    "барышню",
+    "барышня",
+    "барышнями",
+    "барышом",
+    "бас",
+    "баск",
+    "баском",
+    "басманной",
+    "басни",
+    "баснословных",
+    "басом",
+    "баста",
+    "басурманов",
+    "батальонных",
+    "батальоны",
+    "батареи",
+    "батарейный",
+    "батарейным",
+    "батарею",
+    "батарея",
+    "батистовый",
+    "батюшка",
+    "батюшке",
+    "батюшки",
+    "батюшков",
+    "батюшкова",
+    "батюшкой",
+    "бах",
+    "бахрома",
+    "бахроме",
+    "бахромой",
+    "бахрому",
+    "бахромы",
+    "бахуса",
+    "бац",
+    "башен",
+    "башка",
+    "башлыком",
+    "башмаках",
+    "башмаки",
+    "башмачки",
+    "башмачонки",
+    "башне",
+    "башни",
+    "баюкает",
+    "бдение",
+    "бдительного",
+    "беато",
+    "беатриче",
+    "бег",
+    "бега",
+    "бегавшая",
+    "бегавшими",
+    "бегает",
+    "бегаешь",
+    "бегай",
+    "бегал",
+    "бегала",
+    "бегали",
+    "бегать",
+    "бегах",
+    "бегаю",
+    "бегают",
+    "бегающих",
+    "бегая",
+    "беги",
+    "бегите",
+    "бегло",
+    "беглый",
+    "беглым",
+    "беглых",
+    "бегом",
+    "беготне",
+    "беготней",
+    "беготни",
+    "беготня",
+    "бегство",
+    "бегу",
+    "бегуны",
+    "бегут",
+    "бегущего",
+    "бегущий",
+    "бегущую",
+    "бед",
+    "беда",
+    "бедам",
+    "беде",
+    "беден",
+    "бедная",
+    "беднее",
+    "беднейшее",
+    "беднейшей",
+    "беднейшие",
+    "беднейшую",
+    "беднел",
+    "бедненького",
+    "бедненькому",
+    "бедно",
+    "бедного",
+    "бедное",
+    "бедной",
+    "бедном",
+    "бедному",
+    "бедности",
+    "бедность",
+    "бедностью",
+    "бедною",
+    "бедную",
+    "бедны",
+    "бедные",
+    "бедный",
+    "бедным",
+    "бедных",
+    "бедняжка",
+    "бедняжки",
+    "бедняжку",
+    "бедняк",
+    "бедняки",
+    "бедовали",
+    "бедой",
+    "бедр",
+    "бедрам",
+    "бедро",
+    "бедственное",
+    "бедствий",
+    "бедствия",
+    "бедствиями",
+    "бедствует",
+    "беду",
+    "беды",
+    "бежавших",
+    "бежал",
+    "бежала",
+    "бежало",
+    "бежать",
+    "бежим",
+    "бежит",
+    "бежишь",
+    "без",
+    "безбожная",
+    "безбожник",
+    "безбожника",
+    "безбожникам",
+    "безбожно",
+    "безбожные",
+    "безбольно",
+    "безбородкин",
+    "безбрежной",
+    "безверие",
+    "безвестные",
+    "безвестный",
+    "безвкусица",
+    "безвкусно",
+    "безвозвратно",
+    "безвольно",
+    "безвреден",
+    "безвыездно",
+    "безвыходная",
+    "безвыходное",
+    "безграмотно",
+    "безграмотным",
+    "безграничная",
+    "безграничнейший",
+    "безгранично",
+    "безграничного",
+    "безграничной",
+    "безграничном",
+    "безгрешным",
+    "бездарно",
+    "бездарности",
+    "бездарность",
+    "бездарных",
+    "бездействии",
+    "бездействию",
+    "бездействия",
+    "безделицу",
+    "безделицы",
+    "безделку",
+    "бездн",
+    "бездна",
+    "бездне",
+    "бездной",
+    "бездну",
+    "бездны",
+    "бездомна",
+    "бездомней",
+    "бездомным",
+    "бездонна",
+    "бездонной",
+    "бездонностью",
+    "бездонную",
+    "бездонным",
+    "бездонных",
+    "бездорожье",
+    "бездорожью",
+    "бездушном",
+    "бездыханна",
+    "бездыханном",
+    "бездыханность",
+    "безжалостно",
+    "безжалостный",
+    "беззаботен",
+    "беззаботная",
+    "беззаботно",
+    "беззаботной",
+    "беззаботность",
+    "беззаботный",
+    "беззаботным",
+    "беззаботными",
+    "беззаветно",
+    "беззаветный",
+    "беззакатного",
+    "беззакатный",
+    "беззащитная",
+    "беззащитную",
+    "беззащитные",
+    "беззвездной",
+    "беззвездный",
+    "безземельная",
+    "беззлобен",
+    "беззубый",
+    "беззубым",
+    "безличное",
+    "безличности",
+    "безличный",
+    "безлюдном",
+    "безлюдность",
+    "безлюдьи",
+    "безмерно",
+    "безмерными",
+    "безмозглая",
+    "безмолвен",
+    "безмолвного",
+    "безмолвное",
+    "безмолвны",
+    "безмолвные",
+    "безмолвный",
+    "безмолвным",
+    "безмолвных",
+    "безмолвствует",
+    "безмужних",
+    "безмятежно",
+    "безмятежной",
+    "безмятежность",
+    "безмятежный",
+    "безнадежная",
+    "безнадежней",
+    "безнадежно",
+    "безнадежного",
+    "безнадежной",
+    "безнадежности",
+    "безнадежность",
+    "безнадежный",
+    "безнаказанно",
+    "безначальной",
+    "безначальный",
+    "безносым",
+    "безнравственная",
+    "безнравственно",
+    "безнравственны",
+    "безнравственный",
+    "безнравственным",
+    "безо",
+    "безобидно",
+    "безобидного",
+    "безоблачного",
+    "безоблачность",
+    "безоблачный",
+    "безобразен",
+    "безобразие",
+    "безобразию",
+    "безобразия",
+    "безобразна",
+    "безобразная",
+    "безобразнее",
+    "безобразнейший",
+    "безобразнейшим",
+    "безобразник",
+    "безобразничал",
+    "безобразничать",
+    "безобразно",
+    "безобразного",
+    "безобразное",
+    "безобразной",
+    "безобразном",
+    "безобразною",
+    "безобразную",
+    "безобразные",
+    "безобразный",
+    "безобразным",
+    "безобразными",
+    "безобразных",
+    "безобразью",
+    "безопасным",
+    "безответная",
+    "безответного",
+    "безответный",
+    "безотрадно",
+    "безотрадной",
+    "безотрадным",
+    "безотчетно",
+    "безотчетной",
+    "безотчетности",
+    "безотчетным",
+    "безошибочно",
+    "безошибочною",
+    "безполезны",
+    "безрадостной",
+    "безрадостность",
+    "безразличный",
+    "безрассудно",
+    "безрассудное",
+    "безрассудным",
+    "безропотно",
+    "безукоризненна",
+    "безукоризненно",
+    "безукоризненное",
+    "безумен",
+    "безумие",
+    "безумна",
+    "безумная",
+    "безумней",
+    "безумно",
+    "безумного",
+    "безумное",
+    "безумной",
+    "безумном",
+    "безумную",
+    "безумный",
+    "безумным",
+    "безумными",
+    "безумных",
+    "безумствовать",
+    "безумствует",
+    "безумца",
+    "безумцы",
+    "безумья",
+    "безупречная",
+    "безусловно",
+    "безутешная",
+    "безучастия",
+    "безучастно",
+    "безучастным",
+    "безызвестно",
+    "безыменные",
+    "безысходна",
+    "безысходней",
+    "безысходность",
+    "безысходные",
+    "безысходным",
+    "бей",
+    "бейся",
+    "бейте",
+    "бекетов",
+    "бекешах",
+    "бел",
+    "бела",
+    "белая",
+    "белее",
+    "белеет",
+    "белели",
+    "беленькая",
+    "беленькие",
+    "беленький",
+    "беленьким",
+    "беленького",
+    "белесым",
+    "белея",
+    "белизна",
+    "белизной",
+    "белизны",
+    "белильню",
+    "белинского",
+    "белится",
+    "беллетрист",
+    "беллетристов",
+    "беллетристом",
+    "беллетристу",
+    "белобрысые",
+    "белобрысыми",
+    "беловатой",
+    "беловодовым",
+    "белого",
+    "белое",
+    "белой",
+    "белокурая",
+    "белокуренькая",
+    "белокуренькое",
+    "белокурою",
+    "белокурые",
+    "белокурый",
+    "белокурыми",
+    "белом",
+    "белому",
+    "белоручка",
+    "белоснежная",
+    "белоснежной",
+    "белою",
+    "белую",
+    "белые",
+    "белый",
+    "белым",
+    "белыми",
+    "белых",
+    "бельведерского",
+    "бельгию",
+    "белье",
+    "бельем",
+    "бельмеса",
+    "белья",
+    "бензол",
+    "берг",
+    "бергом",
+    "бердичеве",
+    "берег",
+    "берега",
+    "береги",
+    "берегись",
+    "берегите",
+    "берегитесь",
+    "берегла",
+    "береглась",
+    "берегли",
+    "берегов",
+    "берегся",
+    "берегу",
+    "бередило",
+    "бережет",
+    "бережете",
+    "бережешь",
+    "бережно",
+    "березками",
+    "березку",
+    "березняк",
+    "березняка",
+    "березовая",
+    "березовую",
+    "березы",
+    "берем",
+    "беременна",
+    "берет",
+    "берете",
+    "берется",
+    "беречь",
+    "беречься",
+    "берешь",
+    "бери",
+    "берите",
+    "берлин",
+    "берлога",
+    "берлоге",
+    "беру",
+    "берусь",
+    "берут",
+    "берутся",
+    "беря",
+    "бес",
+    "бесед",
+    "беседах",
+    "беседе",
+    "беседка",
+    "беседке",
+    "беседки",
+    "беседку",
+    "беседовавшим",
+    "беседовал",
+    "беседовала",
+    "беседой",
+    "беседу",
+    "беседует",
+    "беседуют",
+    "бесенок",
+    "бесился",
+    "бесится",
+    "бесконечна",
+    "бесконечная",
+    "бесконечно",
+    "бесконечного",
+    "бесконечное",
+    "бесконечной",
+    "бесконечном",
+    "бесконечному",
+    "бесконечности",
+    "бесконечность",
+    "бесконечною",
+    "бесконечную",
+    "бесконечные",
+    "бесконечный",
+    "бесконечным",
+    "бесконечных",
+    "бескорыстно",
+    "бескорыстное",
+    "бескрайною",
+    "бескровно",
+    "бескровных",
+    "бесноватыми",
+    "беспамятная",
+    "беспамятный",
+    "беспамятстве",
+    "беспамятство",
+    "бесперечь",
+    "беспечальное",
+    "беспечнее",
+    "беспечно",
+    "беспечное",
+    "беспечности",
+    "беспечность",
+    "беспечный",
+    "беспечными",
+    "бесплодная",
+    "бесплодного",
+    "бесплодные",
+    "бесплодных",
+    "бесповоротно",
+    "бесподобно",
+    "беспокоен",
+    "беспокоил",
+    "беспокоила",
+    "беспокоилась",
+    "беспокоили",
+    "беспокоило",
+    "беспокоился",
+    "беспокоим",
+    "беспокоит",
+    "беспокоитесь",
+    "беспокоить",
+    "беспокоиться",
+    "беспокоишься",
+    "беспокой",
+    "беспокойная",
+    "беспокойнее",
+    "беспокойно",
+    "беспокойного",
+    "беспокойное",
+    "беспокойной",
+    "беспокойны",
+    "беспокойный",
+    "беспокойным",
+    "беспокойных",
+    "беспокойства",
+    "беспокойстве",
+    "беспокойство",
+    "беспокойством",
+    "беспокойся",
+    "беспокойте",
+    "беспокойтесь",
+    "беспокою",
+    "беспокоюсь",
+    "беспокоят",
+    "бесполезная",
+    "бесполезно",
+    "бесполезное",
+    "бесполезной",
+    "бесполезности",
+    "бесполезную",
+    "бесполезны",
+    "бесполезный",
+    "бесполезных",
+    "бесполый",
+    "беспомощен",
+    "беспомощно",
+    "беспомощное",
+    "беспомощном",
+    "беспомощности",
+    "беспомощность",
+    "беспорядка",
+    "беспорядках",
+    "беспорядке",
+    "беспорядков",
+    "беспорядок",
+    "беспорядочная",
+    "беспорядочно",
+    "беспорядочному",
+    "беспорядочную",
+    "беспошлинный",
+    "беспощадно",
+    "беспощадной",
+    "беспредельная",
+    "беспредельно",
+    "беспредельной",
+    "беспредельном",
+    "беспредельную",
+    "беспредметная",
+    "беспрекословно",
+    "беспременно",
+    "беспрерывная",
+    "беспрерывно",
+    "беспрерывного",
+    "беспрерывной",
+    "беспрерывную",
+    "беспрерывные",
+    "беспрерывный",
+    "беспрерывными",
+    "беспрерывных",
+    "беспрестанно",
+    "беспримерно",
+    "беспристрастно",
+    "бесприютным",
+    "беспутной",
+    "бессвязные",
+    "бессвязных",
+    "бессердечие",
+    "бессилен",
+    "бессилие",
+    "бессилии",
+    "бессилия",
+    "бессильно",
+    "бессильного",
+    "бессильное",
+    "бессильною",
+    "бессильны",
+    "бессильный",
+    "бессильных",
+    "бессилья",
+    "бесследно",
+    "бессловесный",
+    "бессловного",
+    "бессменно",
+    "бессмертной",
+    "бессмертную",
+    "бессмертный",
+    "бессмертья",
+    "бессмысленная",
+    "бессмысленно",
+    "бессмысленной",
+    "бессмысленность",
+    "бессмысленные",
+    "бессмысленный",
+    "бессмысленным",
+    "бессмысленными",
+    "бессмыслица",
+    "бессмыслице",
+    "бессмыслицей",
+    "бессмыслицу",
+    "бесснежная",
+    "бессовестно",
+    "бессознательная",
+    "бессознательно",
+    "бессознательной",
+    "бессознательном",
+    "бессознательным",
+    "бессоницы",
+    "бессонная",
+    "бессонницы",
+    "бессонной",
+    "бессонные",
+    "бессонный",
+    "бессонных",
+    "бесспорно",
+    "бесспорное",
+    "бесспорной",
+    "бесстрастен",
+    "бесстрастная",
+    "бесстрастно",
+    "бесстрастной",
+    "бесстрашная",
+    "бесстрашней",
+    "бесстыдник",
+    "бесстыдники",
+    "бесстыдно",
+    "бесстыдные",
+    "бесстыдным",
+    "бессчетно",
+    "бестактно",
+    "бестия",
+    "бестолковую",
+    "бестолковые",
+    "бестолковых",
+    "бестужеву",
+    "бесхарактерности",
+    "бесцветен",
+    "бесцветно",
+    "бесцветной",
+    "бесцветны",
+    "бесцветных",
+    "бесцельная",
+    "бесцельно",
+    "бесцельный",
+    "бесцельных",
+    "бесценное",
+    "бесценный",
+    "бесцеремонно",
+    "бесчеловечия",
+    "бесчеловечно",
+    "бесчестие",
+    "бесчестит",
+    "бесчестная",
+    "бесчестном",
+    "бесчестные",
+    "бесчестным",
+    "бесчестье",
+    "бесчестья",
+    "бесчинства",
+    "бесчисленно",
+    "бесчисленного",
+    "бесчисленных",
+    "бесчувствен",
+    "бесчувственна",
+    "бесчувственная",
+    "бесчувственного",
+    "бесчувственному",
+    "бесчувственный",
+    "бесчувственным",
+    "бесчувствия",
+    "бесшумно",
+    "бетховен",
+    "бешеная",
+    "бешеного",
+    "бешеной",
+    "бешеном",
+    "бешеною",
+    "бешенства",
+    "бешенстве",
+    "бешенство",
+    "бешенством",
+    "бешеные",
+    "бешеным",
+    "библейские",
+    "библиотек",
+    "библиотека",
+    "библиотекарем",
+    "библиотеке",
+    "библию",
+    "библия",
+    "бивал",
+    "бивали",
+    "бившего",
+    "бивший",
+    "биение",
+    "биением",
+    "биению",
+    "биения",
+    "бил",
+    "била",
+    "билась",
+    "билет",
+    "билета",
+    "билетами",
+    "билетах",
+    "билетик",
+    "билетика",
+    "билетиками",
+    "билетов",
+    "билетом",
+    "билету",
+    "билеты",
+    "били",
+    "биллиард",
+    "биллиарда",
+    "биллиарде",
+    "било",
+    "билось",
+    "бился",
+    "биль",
+    "бильярд",
+    "бильярде",
+    "бильярдной",
+    "бильярдную",
+    "бильярдный",
+    "бинокле",
+    "бинокль",
+    "биография",
+    "бирже",
+    "биржи",
+    "бис",
+    "бисер",
+    "бисером",
+    "бисквитов",
+    "бисквиты",
+    "битв",
+    "битве",
+    "битву",
+    "битвы",
+    "битком",
+    "битое",
+    "биты",
+    "битый",
+    "бить",
+    "битье",
+    "биться",
+    "бифштекс",
+    "бифштекса",
+    "бицепсы",
+    "бич",
+    "бичами",
+    "бичурину",
+    "бишь",
+    "бла",
+    "благ",
+    "блага",
+    "благим",
+    "благих",
+    "благо",
+    "благовещение",
+    "благовиднее",
+    "благоволите",
+    "благовония",
+    "благовонной",
+    "благовоспитанным",
+    "благовоспитанных",
+    "благоговеете",
+    "благоговейно",
+    "благоговейного",
+    "благоговейным",
+    "благоговела",
+    "благоговение",
+    "благоговением",
+    "благоговеть",
+    "благоговею",
+    "благодарен",
+    "благодари",
+    "благодарил",
+    "благодарила",
+    "благодарили",
+    "благодарим",
+    "благодарить",
+    "благодарна",
+    "благодарная",
+    "благодарно",
+    "благодарном",
+    "благодарности",
+    "благодарностию",
+    "благодарность",
+    "благодарностью",
+    "благодарю",
+    "благодаря",
+    "благодати",
+    "благодать",
+    "благодетелей",
+    "благодетелем",
+    "благодетели",
+    "благодетель",
+    "благодетельной",
+    "благодетельные",
+    "благодетельствовал",
+    "благодетельствовать",
+    "благодетельствует",
+    "благодетеля",
+    "благодеяние",
+    "благодеяний",
+    "благодеяния",
+    "благодушном",
+    "благолепие",
+    "благонадежный",
+    "благонамереннейший",
+    "благонамеренного",
+    "благонравной",
+    "благонравны",
+    "благополучно",
+    "благоприобретенные",
+    "благоприобретенным",
+    "благоприятного",
+    "благоприятное",
+    "благоприятный",
+    "благоприятствовавшие",
+    "благоразумие",
+    "благоразумия",
+    "благоразумная",
+    "благоразумнейшей",
+    "благоразумно",
+    "благоразумных",
+    "благороден",
+    "благородие",
+    "благородная",
+    "благороднее",
+    "благороднейшая",
+    "благороднейший",
+    "благородно",
+    "благородного",
+    "благородное",
+    "благородной",
+    "благородном",
+    "благородною",
+    "благородны",
+    "благородные",
+    "благородный",
+    "благородным",
+    "благородных",
+    "благородства",
+    "благородстве",
+    "благородство",
+    "благородством",
+    "благосклонно",
+    "благосклонной",
+    "благосклонный",
+    "благословением",
+    "благословениями",
+    "благословенно",
+    "благословенной",
+    "благословенном",
+    "благословенный",
+    "благослови",
+    "благословил",
+    "благословила",
+    "благословили",
+    "благословит",
+    "благословить",
+    "благословишь",
+    "благословлю",
+    "благословляет",
+    "благословляла",
+    "благословлять",
+    "благословляю",
+    "благословляя",
+    "благословясь",
+    "благословят",
+    "благосостояние",
+    "благосостояния",
+    "благости",
+    "благость",
+    "благотворители",
+    "благотворительности",
+    "благотворительность",
+    "благотворны",
+    "благотворный",
+    "благоугодным",
+    "благоусмотрение",
+    "благоуханное",
+    "благоуханный",
+    "благоуханных",
+    "благоуханье",
+    "благоухающая",
+    "благоухающею",
+    "благочестиво",
+    "блаженно",
+    "блаженное",
+    "блаженной",
+    "блаженные",
+    "блаженный",
+    "блаженным",
+    "блаженных",
+    "блаженства",
+    "блаженстве",
+    "блаженство",
+    "блаженствовал",
+    "блаженствовали",
+    "блаженствовать",
+    "блаженством",
+    "блаженствую",
+    "блаженствуют",
+    "блажной",
+    "блажь",
+    "блажью",
+    "бланбеками",
+    "бланбеки",
+    "бледен",
+    "бледна",
+    "бледная",
+    "бледневший",
+    "бледнее",
+    "бледнеет",
+    "бледнела",
+    "бледненькое",
+    "бледнеть",
+    "бледнеют",
+    "бледнея",
+    "бледно",
+    "бледного",
+    "бледное",
+    "бледной",
+    "бледном",
+    "бледность",
+    "бледночернильным",
+    "бледную",
+    "бледны",
+    "бледные",
+    "бледный",
+    "бледным",
+    "бледными",
+    "бледных",
+    "блеклыми",
+    "блеск",
+    "блеска",
+    "блеске",
+    "блеском",
+    "блеснет",
+    "блесну",
+    "блеснувшим",
+    "блеснул",
+    "блеснула",
+    "блеснули",
+    "блеснуло",
+    "блеснуть",
+    "блестели",
+    "блестело",
+    "блестит",
+    "блестками",
+    "блестят",
+    "блестящей",
+    "блестящем",
+    "блестящему",
+    "блестящею",
+    "блестящие",
+    "блестящий",
+    "блестящим",
+    "блестящих",
+    "блестящую",
+    "блещет",
+    "блещешь",
+    "блещут",
+    "блещущей",
+    "блещущий",
+    "блещущим",
+    "блеющие",
+    "ближайшее",
+    "ближайшем",
+    "ближайшие",
+    "ближайший",
+    "ближайшую",
+    "ближе",
+    "ближней",
+    "ближний",
+    "ближним",
+    "близ",
+    "близится",
+    "близка",
+    "близки",
+    "близкие",
+    "близким",
+    "близкими",
+    "близких",
+    "близко",
+    "близкого",
+    "близкое",
+    "близкой",
+    "близком",
+    "близкую",
+    "близлежащие",
+    "близок",
+    "близорука",
+    "близость",
+    "близь",
+    "блин",
+    "блинах",
+    "блинов",
+    "блины",
+    "блиставшего",
+    "блиставшее",
+    "блистал",
+    "блистали",
+    "блистало",
+    "блистанье",
+    "блистательной",
+    "блистательным",
+    "блистательных",
+    "блистать",
+    "блистают",
+    "блистающий",
+    "блистающими",
+    "бло",
+    "блок",
+    "блонд",
+    "блондах",
+    "блондин",
+    "блондинка",
+    "блондинки",
+    "блондины",
+    "блонды",
+    "блохи",
+    "блудницу",
+    "блуждает",
+    "блуждаете",
+    "блуждал",
+    "блуждали",
+    "блуждающий",
+    "блуждая",
+    "блузе",
+    "блузу",
+    "блю",
+    "блюд",
+    "блюда",
+    "блюдами",
+    "блюде",
+    "блюдечками",
+    "блюдечке",
+    "блюдечко",
+    "блюдо",
+    "блюдом",
+    "блюдце",
+    "блюсти",
+    "блюстителя",
+    "бо",
+    "бобах",
+    "бобик",
+    "бобика",
+    "бобику",
+    "бобов",
+    "бобровых",
+    "бобы",
+    "бог",
+    "бога",
+    "богат",
+    "богата",
+    "богатая",
+    "богатеет",
+    "богатеют",
+    "богато",
+    "богатого",
+    "богатой",
+    "богатом",
+    "богатому",
+    "богатства",
+    "богатство",
+    "богатую",
+    "богаты",
+    "богатые",
+    "богатый",
+    "богатым",
+    "богатыре",
+    "богатырь",
+    "богатырях",
+    "богатых",
+    "богач",
+    "богаче",
+    "богачи",
+    "богачом",
+    "богданович",
+    "богданыч",
+    "боге",
+    "богема",
+    "богемии",
+    "богемскую",
+    "боги",
+    "богиня",
+    "богом",
+    "богомолье",
+    "богомольно",
+    "богомольной",
+    "богомольные",
+    "богородица",
+    "богородицу",
+    "богохульник",
+    "богу",
+    "бодр",
+    "бодрая",
+    "бодрился",
+    "бодро",
+    "бодрое",
+    "бодрой",
+    "бодром",
+    "бодрости",
+    "бодрость",
+    "бодростью",
+    "бодрствовал",
+    "бодрствовали",
+    "бодрствую",
+    "бодрый",
+    "бодрым",
+    "бодрых",
+    "бодягой",
+    "боевой",
+    "боем",
+    "боец",
+    "боже",
+    "божества",
+    "божественно",
+    "божественной",
+    "божественным",
+    "божественных",
+    "божество",
+    "божие",
+    "божией",
+    "божиим",
+    "божий",
+    "божилась",
+    "божится",
+    "божию",
+    "божия",
+    "божьего",
+    "божьей",
+    "божьим",
+    "божья",
+    "боитесь",
+    "боится",
+    "боишься",
+    "бой",
+    "бойкая",
+    "бойкие",
+    "бойкий",
+    "бойким",
+    "бойко",
+    "бойкого",
+    "бойкое",
+    "бойни",
+    "бойся",
+    "бойтесь",
+    "бок",
+    "бока",
+    "бокал",
+    "бокала",
+    "бокалах",
+    "бокале",
+    "бокалу",
+    "бокам",
+    "боками",
+    "боки",
+    "бокля",
+    "боков",
+    "бокового",
+    "боковой",
+    "боковом",
+    "боковую",
+    "боком",
+    "боку",
+    "болван",
+    "болваном",
+    "болвану",
+    "боле",
+    "более",
+    "болеет",
+    "болезная",
+    "болезней",
+    "болезненная",
+    "болезненно",
+    "болезненного",
+    "болезненное",
+    "болезненной",
+    "болезненном",
+    "болезненность",
+    "болезненную",
+    "болезненные",
+    "болезненный",
+    "болезненным",
+    "болезненных",
+    "болезни",
+    "болезнию",
+    "болезнь",
+    "болезнью",
+    "болезнями",
+    "болезнях",
+    "болел",
+    "болела",
+    "болели",
+    "болело",
+    "болен",
+    "болеть",
+    "болеют",
+    "боли",
+    "болит",
+    "болосы",
+    "болот",
+    "болота",
+    "болотистым",
+    "болотной",
+    "болотные",
+    "болотный",
+    "болото",
+    "болотом",
+    "болтает",
+    "болтается",
+    "болтай",
+    "болтал",
+    "болталась",
+    "болтали",
+    "болтались",
+    "болтался",
+    "болтать",
+    "болтаю",
+    "болтают",
+    "болтаются",
+    "болтаясь",
+    "болтлив",
+    "болтлива",
+    "болтливая",
+    "болтливы",
+    "болтовней",
+    "болтовни",
+    "болтовню",
+    "болтовня",
+    "болтун",
+    "болтунишки",
+    "болтушка",
+    "боль",
+    "больна",
+    "больная",
+    "больнее",
+    "больней",
+    "больниц",
+    "больница",
+    "больнице",
+    "больницу",
+    "больницы",
+    "больно",
+    "больного",
+    "больное",
+    "больной",
+    "больном",
+    "больному",
+    "больною",
+    "больную",
+    "больны",
+    "больные",
+    "больным",
+    "больными",
+    "больных",
+    "большая",
+    "больше",
+    "большего",
+    "большеголовый",
+    "большее",
+    "большей",
+    "большем",
+    "большею",
+    "большие",
+    "большим",
+    "большими",
+    "большинстве",
+    "большинство",
+    "большинством",
+    "больших",
+    "большого",
+    "большое",
+    "большой",
+    "большом",
+    "большому",
+    "большою",
+    "большую",
+    "болью",
+    "болят",
+    "бомбами",
+    "бонжур",
+    "бонне",
+    "бор",
+    "бореля",
+    "борется",
+    "борис",
+    "бориса",
+    "борисович",
+    "борису",
+    "бормотал",
+    "бормотала",
+    "бормотали",
+    "бормотание",
+    "бормотаний",
+    "бормочет",
+    "бормочешь",
+    "боров",
+    "борода",
+    "бороде",
+    "бородкой",
+    "бородой",
+    "бороду",
+    "бороды",
+    "бороздою",
+    "боролась",
+    "боролись",
+    "боролось",
+    "боролся",
+    "бороться",
+    "борт",
+    "борта",
+    "бортов",
+    "борты",
+    "борцов",
+    "борьба",
+    "борьбах",
+    "борьбе",
+    "борьбой",
+    "борьбу",
+    "борьбы",
+    "борются",
+    "борясь",
+    "босая",
+    "босенькая",
+    "босиком",
+    "боскете",
+    "босой",
+    "босоногих",
+    "бостон",
+    "босу",
+    "босые",
+    "ботаник",
+    "ботвинью",
+    "ботвинья",
+    "ботинками",
+    "ботинках",
+    "ботинки",
+    "ботиночки",
+    "бочка",
+    "бочки",
+    "бочкой",
+    "бочком",
+    "бочонка",
+    "бочонок",
+    "бою",
+    "боюсь",
+    "боя",
+    "боявшиеся",
+    "боявшийся",
+    "боязливее",
+    "боязливо",
+    "боязливый",
+    "боязни",
+    "боязнь",
+    "боязнью",
+    "боялась",
+    "боялись",
+    "боялся",
+    "боясь",
+    "боятся",
+    "бояться",
+    "браво",
+    "бравое",
+    "брак",
+    "брака",
+    "браке",
+    "браки",
+    "браком",
+    "браку",
+    "брал",
+    "брала",
+    "бралась",
+    "брали",
+    "брался",
+    "бранда",
+    "брани",
+    "бранил",
+    "бранила",
+    "бранили",
+    "бранились",
+    "бранит",
+    "браните",
+    "бранится",
+    "бранить",
+    "браниться",
+    "бранчив",
+    "брань",
+    "бранью",
+    "бранюсь",
+    "бранясь",
+    "бранят",
+    "браслет",
+    "браслете",
+    "браслетом",
+    "браслеты",
+    "брат",
+    "брата",
+    "брате",
+    "братец",
+    "братией",
+    "братишка",
+    "братом",
+    "братской",
+    "братства",
+    "брату",
+    "братца",
+    "братце",
+    "братцем",
+    "братцу",
+    "братцы",
+    "брать",
+    "братьев",
+    "братьи",
+    "браться",
+    "братья",
+    "братьям",
+    "брачной",
+    "брачные",
+    "бревна",
+    "брег",
+    "бред",
+    "бреда",
+    "бреде",
+    "бредил",
+    "бредила",
+    "бредит",
+    "бредите",
+    "бредить",
+    "бредишь",
+    "бредом",
+    "бреду",
+    "бредят",
+    "бреется",
+    "брежжит",
+    "брежу",
+    "брезгает",
+    "брезгайте",
+    "брезгливости",
+    "брезговал",
+    "брезжит",
+    "брел",
+    "брелок",
+    "брелоков",
+    "бременем",
+    "бремени",
+    "бремя",
+    "бренно",
+    "бренный",
+    "бренчало",
+    "бренчу",
+    "бретер",
+    "бригаде",
+    "бригадой",
+    "бригаду",
+    "бриллиант",
+    "бриллианта",
+    "бриллиантовый",
+    "брился",
+    "брит",
+    "бритая",
+    "бритва",
+    "бритвы",
+    "бритой",
+    "брить",
+    "бриться",
+    "бровей",
+    "брови",
+    "бровь",
+    "бровью",
+    "бровями",
+    "бровях",
+    "бродивших",
+    "бродил",
+    "бродила",
+    "бродили",
+    "бродит",
+    "бродить",
+    "бродишь",
+    "бродяга",
+    "бродяги",
+    "бродягу",
+    "бродяжничай",
+    "бродяжничества",
+    "бродят",
+    "бродячие",
+    "бронза",
+    "бронзовую",
+    "бросает",
+    "бросаете",
+    "бросаетесь",
+    "бросается",
+    "бросал",
+    "бросала",
+    "бросалась",
+    "бросались",
+    "бросало",
+    "бросался",
+    "бросать",
+    "бросаю",
+    "бросают",
+    "бросаются",
+    "бросающееся",
+    "бросающую",
+    "бросая",
+    "бросаясь",
+    "бросив",
+    "бросившись",
+    "бросил",
+    "бросила",
+    "бросилась",
+    "бросили",
+    "бросились",
+    "бросило",
+    "бросилось",
+    "бросился",
+    "бросим",
+    "бросит",
+    "бросится",
+    "бросить",
+    "броситься",
+    "бросишь",
+    "брось",
+    "бросьте",
+    "бросят",
+    "бросятся",
+    "брошен",
+    "брошена",
+    "брошенного",
+    "брошенное",
+    "брошенном",
+    "брошенный",
+    "брошено",
+    "брошены",
+    "брошка",
+    "брошкой",
+    "брошу",
+    "брошусь",
+    "брошь",
+    "брошюр",
+    "брошюру",
+    "брошюры",
+    "бррр",
+    "брудершафт",
+    "брусничной",
+    "брут",
+    "брызг",
+    "брызгает",
+    "брызги",
+    "брызжут",
+    "брызнув",
+    "брызнул",
+    "брызнула",
+    "брызнули",
+    "брызнут",
+    "брюжжит",
+    "брюзгами",
+    "брюзгливая",
+    "брюзгливо",
+    "брюзгливою",
+    "брюзгливые",
+    "брюзжащие",
+    "брюзжит",
+    "брюках",
+    "брюквой",
+    "брюки",
+    "брюнет",
+    "брюнеты",
+    "брюсову",
+    "брюхе",
+    "брюхо",
+    "брюшком",
+    "брякает",
+    "брякну",
+    "брякнул",
+    "брякнула",
+    "брякнуть",
+    "бряцает",
+    "бубен",
+    "бубенчик",
+    "бубенчики",
+    "бубенчуками",
+    "бубновый",
+    "бубны",
+    "бугорках",
+    "бугорки",
+    "буде",
+    "будем",
+    "будемте",
+    "будет",
+    "будете",
+    "будешь",
+    "буди",
+    "будил",
+    "будила",
+    "будили",
+    "будило",
+    "будируя",
+    "будит",
+    "будить",
+    "буднее",
+    "будней",
+    "будни",
+    "будничное",
+    "будничной",
+    "будничном",
+    "будничные",
+    "будничными",
+    "будничных",
+    "будням",
+    "буднях",
+    "будок",
+    "будоражишь",
+    "будочник",
+    "будочника",
+    "будто",
+    "буду",
+    "будуар",
+    "будуаров",
+    "будут",
+    "будучи",
+    "будущая",
+    "будущего",
+    "будущее",
+    "будущей",
+    "будущем",
+    "будущему",
+    "будущие",
+    "будущий",
+    "будущим",
+    "будущих",
+    "будущности",
+    "будущность",
+    "будущую",
+    "будь",
+    "будьте",
+    "будя",
+    "будят",
+    "буеракам",
+    "бузиной",
+    "буйная",
+    "буйно",
+    "буйного",
+    "буйной",
+    "буйном",
+    "буйному",
+    "буйный",
+    "буйным",
+    "буйных",
+    "буйственных",
+    "буйство",
+    "буйством",
+    "букашками",
+    "буква",
+    "буквально",
+    "буквальное",
+    "буквами",
+    "буквой",
+    "букву",
+    "буквы",
+    "букет",
+    "букета",
+    "букетами",
+    "букетом",
+    "букеты",
+    "буки",
+    "букинистами",
+    "булавки",
+    "булавку",
+    "булавой",
+    "булка",
+    "булки",
+    "булку",
+    "булок",
+    "булочек",
+    "булочками",
+    "булочник",
+    "булочную",
+    "бултых",
+    "буль",
+    "бульвар",
+    "бульвара",
+    "бульваре",
+    "бульвару",
+    "бульдоге",
+    "бульон",
+    "бумаг",
+    "бумага",
+    "бумагам",
+    "бумагами",
+    "бумагах",
+    "бумаге",
+    "бумаги",
+    "бумагой",
+    "бумагу",
+    "бумажечки",
+    "бумажка",
+    "бумажками",
+    "бумажке",
+    "бумажки",
+    "бумажку",
+    "бумажник",
+    "бумажнике",
+    "бумажной",
+    "бумажный",
+    "бумажным",
+    "бумбия",
+    "бунтовать",
+    "бунтующее",
+    "бунчуки",
+    "буран",
+    "бурду",
+    "буре",
+    "бурей",
+    "буржуазного",
+    "бури",
+    "буркалы",
+    "бурмейстер",
+    "бурно",
+    "бурного",
+    "бурное",
+    "бурною",
+    "бурнус",
+    "бурнусик",
+    "бурнусике",
+    "бурный",
+    "бурными",
+    "бурных",
+    "бурого",
+    "бурой",
+    "бурсак",
+    "бурш",
+    "бурша",
+    "бурый",
+    "бурь",
+    "бурьян",
+    "бурьяне",
+    "бурьяном",
+    "бурю",
+    "буря",
+    "бурями",
+    "бурях",
+    "бутончик",
+    "бутошник",
+    "бутылка",
+    "бутылками",
+    "бутылки",
+    "бутылкой",
+    "бутылкою",
+    "бутылку",
+    "бутылок",
+    "бутылочки",
+    "бутылочку",
+    "бутылочным",
+    "буфера",
+    "буфет",
+    "буфета",
+    "буфете",
+    "буфету",
+    "буфетчик",
+    "буфетчика",
+    "буфеты",
+    "буффон",
+    "буха",
+    "бухте",
+    "бушевало",
+    "бушует",
+    "бушующих",
+    "буян",
+    "буянил",
+    "буянила",
+    "бы",
+    "быв",
+    "бываем",
+    "бывает",
+    "бываете",
+    "бываешь",
+    "бывал",
+    "бывала",
+    "бывали",
+    "бывало",
+    "бывалое",
+    "бывалую",
+    "бывалые",
+    "бывалый",
+    "бывалых",
+    "бывать",
+    "бываю",
+    "бывают",
+    "бывшая",
+    "бывшего",
+    "бывшее",
+    "бывшей",
+    "бывшем",
+    "бывшему",
+    "бывши",
+    "бывшие",
+    "бывший",
+    "бывшим",
+    "бывшими",
+    "бывших",
+    "бывшую",
+    "был",
+    "была",
+    "былая",
+    "были",
+    "былинки",
+    "было",
+    "былое",
+    "былой",
+    "былые",
+    "былым",
+    "былых",
+    "быльем",
+    "быстрая",
+    "быстрее",
+    "быстрей",
+    "быстрины",
+    "быстро",
+    "быстрого",
+    "быстрой",
+    "быстроте",
+    "быстротой",
+    "быстротою",
+    "быстроту",
+    "быстрые",
+    "быстрый",
+    "быстрым",
+    "быт",
+    "быта",
+    "быте",
+    "бытие",
+    "бытийственных",
+    "бытия",
+    "бытность",
+    "быту",
+    "быть",
+    "бытьи",
+    "бытья",
+    "бьемся",
+    "бьет",
+    "бьется",
+    "бьешься",
+    "бьюсь",
+    "бьют",
+    "бьются",
+    "бьющаяся",
+    "бьющимся",
+    "бюджета",
+    "бюджету",
+    "бюргера",
+    "бюргеров",
+    "бюргером",
+    "бюргерские",
+    "бюргерскими",
+    "бюро",
+    "бюст",
+    "бюста",
+    "в",
+    "вавиловка",
+    "вагнера",
+    "вагон",
+    "вагона",
+    "вагоне",
+    "вагонов",
+    "вагоном",
+    "вагоны",
+    "важная",
+    "важнее",
+    "важнейшие",
+    "важнейшими",
+    "важничал",
+    "важно",
+    "важного",
+    "важное",
+    "важной",
+    "важном",
+    "важному",
+    "важности",
+    "важностию",
+    "важность",
+    "важностью",
+    "важную",
+    "важны",
+    "важные",
+    "важный",
+    "важным",
+    "важных",
+    "вазах",
+    "вазы",
+    "вакса",
+    "вакханка",
+    "вал",
+    "валандался",
+    "валентина",
+    "валериановых",
+    "валерию",
+    "валетами",
+    "вали",
+    "валил",
+    "валился",
+    "валится",
+    "валов",
+    "вальдшнепа",
+    "вальс",
+    "вальса",
+    "вальсе",
+    "вальсишку",
+    "вальтера",
+    "валяется",
+    "валялась",
+    "валялись",
+    "валялось",
+    "валялся",
+    "валять",
+    "валяются",
+    "вам",
+    "вами",
+    "вампир",
+    "вампирственного",
+    "ване",
+    "ваней",
+    "ванечки",
+    "ванечкину",
+    "ванечку",
+    "вани",
+    "ванили",
+    "ваниль",
+    "ванилью",
+    "ванну",
+    "ванька",
+    "ваню",
+    "ванюша",
+    "ванюше",
+    "ванюши",
+    "ванюшу",
+    "ваня",
+    "варвара",
+    "варваром",
+    "варвару",
+    "варе",
+    "варений",
+    "вареники",
+    "вареников",
+    "вареного",
+    "вареной",
+    "вареную",
+    "варенц",
+    "варенье",
+    "варенью",
+    "варенья",
+    "вареньями",
+    "вари",
+    "вариаций",
+    "варили",
+    "варились",
+    "варин",
+    "варит",
+    "варить",
+    "варшава",
+    "варшаве",
+    "варшавой",
+    "варшавский",
+    "варшаву",
+    "варьяции",
+    "варьяциях",
+    "варю",
+    "варя",
+    "варяга",
+    "варят",
+    "вас",
+    "василий",
+    "василиса",
+    "василисе",
+    "васильев",
+    "васильева",
+    "васильевич",
+    "васильевна",
+    "васильевну",
+    "васильевны",
+    "васильевский",
+    "васильевского",
+    "васильевском",
+    "васильевскому",
+    "васильевым",
+    "васильем",
+    "васильич",
+    "васильичем",
+    "васька",
+    "ваське",
+    "васьки",
+    "ватага",
+    "ватерлоо",
+    "ватным",
+    "ватой",
+    "ваточной",
+    "ваточном",
+    "ватрушки",
+    "ватрушку",
+    "вату",
+    "ваты",
+    "вафля",
+    "вахрушин",
+    "вахрушина",
+    "ваш",
+    "ваша",
+    "ваше",
+    "вашего",
+    "вашей",
+    "вашем",
+    "вашему",
+    "вашею",
+    "ваши",
+    "вашим",
+    "вашими",
+    "ваших",
+    "вашу",
+    "вбегает",
+    "вбежала",
+    "вбежит",
+    "вбивали",
+    "вбил",
+    "вблизи",
+    "вбок",
+    "ввела",
+    "ввели",
+    "ввергался",
+    "ввергающий",
+    "вверенный",
+    "вверить",
+    "ввернет",
+    "вверну",
+    "ввернул",
+    "ввернуть",
+    "вверх",
+    "вверху",
+    "вверяет",
+    "ввести",
+    "ввиду",
+    "вводила",
+    "вводить",
+    "ввожу",
+    "ввозят",
+    "ввысь",
+    "ввязался",
+    "вглубь",
+    "вглядевшись",
+    "вгляделись",
+    "вглядись",
+    "вглядывается",
+    "вглядывалась",
+    "вглядывался",
+    "вглядываться",
+    "вглядываясь",
+    "вдаваться",
+    "вдавленного",
+    "вдалась",
+    "вдалеке",
+    "вдали",
+    "вдаль",
+    "вдвое",
+    "вдвоем",
+    "вдвойне",
+    "вдеваешь",
+    "вдесятеро",
+    "вдобавок",
+    "вдова",
+    "вдове",
+    "вдовеет",
+    "вдовец",
+    "вдовий",
+    "вдовиц",
+    "вдовица",
+    "вдовой",
+    "вдову",
+    "вдовы",
+    "вдогонку",
+    "вдоль",
+    "вдохновение",
+    "вдохновением",
+    "вдохновении",
+    "вдохновению",
+    "вдохновенно",
+    "вдохновенное",
+    "вдохновенный",
+    "вдохновенными",
+    "вдохновенных",
+    "вдохновенье",
+    "вдохновеньем",
+    "вдохнул",
+    "вдохнуть",
+    "вдребезги",
+    "вдруг",
+    "вдругорядь",
+    "вдумайтесь",
+    "вдуматься",
+    "вдумчиво",
+    "вдумчивое",
+    "вдумывалась",
+    "вдумывался",
+    "вдумываясь",
+    "ве",
+    "ведаем",
+    "ведает",
+    "ведаете",
+    "ведаешь",
+    "ведай",
+    "ведал",
+    "ведать",
+    "ведаю",
+    "ведая",
+    "ведению",
+    "ведения",
+    "веденной",
+    "ведет",
+    "ведешь",
+    "веди",
+    "ведите",
+    "ведома",
+    "ведомо",
+    "ведомости",
+    "ведомостях",
+    "ведомству",
+    "ведрами",
+    "ведре",
+    "ведро",
+    "ведром",
+    "веду",
+    "ведут",
+    "ведущая",
+    "ведущей",
+    "ведущие",
+    "ведущим",
+    "ведь",
+    "ведьма",
+    "ведьмой",
+    "ведьмы",
+    "ведя",
+    "веером",
+    "веет",
+    "вежды",
+    "вежлив",
+    "вежливо",
+    "вежливой",
+    "вежливости",
+    "вежливость",
+    "вежливый",
+    "вежливым",
+    "везде",
+    "везет",
+    "везешь",
+    "везла",
+    "везти",
+    "везу",
+    "везувии",
+    "везущих",
+    "век",
+    "века",
+    "векам",
+    "веками",
+    "веках",
+    "веке",
+    "веки",
+    "веков",
+    "вековечная",
+    "вековечные",
+    "вековой",
+    "вековою",
+    "вековые",
+    "векселей",
+    "векселек",
+    "вексель",
+    "векселя",
+    "веку",
+    "вел",
+    "вела",
+    "велел",
+    "велела",
+    "велели",
+    "велению",
+    "велено",
+    "велеть",
+    "вели",
+    "велик",
+    "велика",
+    "великана",
+    "великанами",
+    "великанов",
+    "великаны",
+    "великая",
+    "велики",
+    "великие",
+    "великий",
+    "великим",
+    "великих",
+    "великого",
+    "великодушен",
+    "великодушия",
+    "великодушная",
+    "великодушнее",
+    "великодушнейший",
+    "великодушно",
+    "великодушного",
+    "великодушны",
+    "великодушные",
+    "великодушный",
+    "великодушных",
+    "великое",
+    "великой",
+    "великолепий",
+    "великолепна",
+    "великолепная",
+    "великолепнее",
+    "великолепнейшие",
+    "великолепние",
+    "великолепно",
+    "великолепного",
+    "великолепное",
+    "великолепной",
+    "великолепную",
+    "великолепные",
+    "великолепный",
+    "великолепных",
+    "великом",
+    "великому",
+    "великонек",
+    "великую",
+    "велит",
+    "велите",
+    "величава",
+    "величавого",
+    "величавое",
+    "величавой",
+    "величайшая",
+    "величайшего",
+    "величайшее",
+    "величайшей",
+    "величайшем",
+    "величайшему",
+    "величайший",
+    "величайшим",
+    "величал",
+    "величают",
+    "величественная",
+    "величественно",
+    "величество",
+    "величие",
+    "величий",
+    "величина",
+    "величине",
+    "величиной",
+    "величины",
+    "величье",
+    "велишь",
+    "велосипеды",
+    "велось",
+    "вельможа",
+    "вельможе",
+    "вельможей",
+    "велю",
+    "велят",
+    "венгерский",
+    "вене",
+    "венерических",
+    "венец",
+    "венецейской",
+    "венецианская",
+    "венецианской",
+    "венеция",
+    "веник",
+    "веника",
+    "веничек",
+    "веничком",
+    "венка",
+    "венками",
+    "венке",
+    "венки",
+    "венком",
+    "венок",
+    "венца",
+    "венце",
+    "венцу",
+    "венчаемся",
+    "венчал",
+    "венчались",
+    "венчался",
+    "венчальный",
+    "венчаться",
+    "венчают",
+    "вены",
+    "вер",
+    "вера",
+    "верблюды",
+    "вербы",
+    "вере",
+    "веревках",
+    "веревке",
+    "веревки",
+    "веревкой",
+    "веревкою",
+    "веревочку",
+    "веревочное",
+    "верен",
+    "вереница",
+    "вереницей",
+    "вереницу",
+    "веретена",
+    "верил",
+    "верила",
+    "верили",
+    "верит",
+    "верите",
+    "верится",
+    "верить",
+    "веришь",
+    "верна",
+    "верная",
+    "вернее",
+    "верней",
+    "вернем",
+    "вернет",
+    "вернется",
+    "вернешь",
+    "вернешься",
+    "верни",
+    "вернись",
+    "вернитесь",
+    "верно",
+    "верного",
+    "верное",
+    "верной",
+    "верному",
+    "верности",
+    "верность",
+    "верностью",
+    "верною",
+    "вернув",
+    "вернувшись",
+    "вернулась",
+    "вернулось",
+    "вернулся",
+    "вернусь",
+    "вернутся",
+    "вернуть",
+    "вернуться",
+    "верную",
+    "верны",
+    "верные",
+    "верный",
+    "верным",
+    "верных",
+    "веровавший",
+    "веровал",
+    "веровала",
+    "верований",
+    "веровать",
+    "верой",
+    "вероломна",
+    "вероломство",
+    "вероломством",
+    "верою",
+    "вероятнее",
+    "вероятно",
+    "вероятности",
+    "вероятность",
+    "вероятны",
+    "версия",
+    "верст",
+    "верстах",
+    "версте",
+    "верстовых",
+    "версту",
+    "версты",
+    "вертел",
+    "вертелся",
+    "вертеп",
+    "вертеры",
+    "вертеть",
+    "верти",
+    "вертись",
+    "вертит",
+    "вертится",
+    "вертишься",
+    "вертлявая",
+    "вертун",
+    "вертя",
+    "вертясь",
+    "вертят",
+    "веру",
+    "верует",
+    "веруете",
+    "веруется",
+    "веруешь",
+    "веруй",
+    "верую",
+    "верующей",
+    "верующие",
+    "верующий",
+    "верующим",
+    "веруя",
+    "верфям",
+    "верх",
+    "верхлева",
+    "верхлеве",
+    "верхлево",
+    "верхлевский",
+    "верхлевского",
+    "верхнего",
+    "верхнее",
+    "верхней",
+    "верхнем",
+    "верхнею",
+    "верхние",
+    "верхний",
+    "верхнюю",
+    "верхняя",
+    "верховий",
+    "верховой",
+    "верховскому",
+    "верхом",
+    "верху",
+    "верхушки",
+    "верхушку",
+    "вершать",
+    "вершина",
+    "вершинах",
+    "вершинин",
+    "вершинина",
+    "вершинину",
+    "вершинины",
+    "вершининым",
+    "вершининых",
+    "вершины",
+    "вершков",
+    "вершок",
+    "веры",
+    "верь",
+    "верьте",
+    "верю",
+    "веря",
+    "верят",
+    "вес",
+    "весах",
+    "весел",
+    "весела",
+    "веселая",
+    "веселее",
+    "веселей",
+    "веселея",
+    "веселила",
+    "веселимся",
+    "веселиться",
+    "весело",
+    "веселого",
+    "веселое",
+    "веселой",
+    "веселости",
+    "веселость",
+    "веселы",
+    "веселые",
+    "веселый",
+    "веселым",
+    "веселых",
+    "веселье",
+    "весельем",
+    "весельчаком",
+    "веселья",
+    "весеннею",
+    "весенние",
+    "весенний",
+    "весенняя",
+    "весит",
+    "весла",
+    "весле",
+    "весло",
+    "весна",
+    "весне",
+    "весной",
+    "весну",
+    "весны",
+    "весталку",
+    "вести",
+    "вестник",
+    "весты",
+    "весть",
+    "вестью",
+    "вестями",
+    "весу",
+    "весы",
+    "весь",
+    "весьма",
+    "ветвей",
+    "ветви",
+    "ветвью",
+    "ветер",
+    "ветерка",
+    "ветерком",
+    "ветерок",
+    "ветка",
+    "ветках",
+    "ветке",
+    "ветки",
+    "веткой",
+    "ветку",
+    "ветлы",
+    "веток",
+    "ветр",
+    "ветра",
+    "ветреная",
+    "ветреность",
+    "ветреный",
+    "ветровые",
+    "ветром",
+    "ветру",
+    "ветха",
+    "ветхая",
+    "ветхий",
+    "ветхим",
+    "ветхих",
+    "ветхом",
+    "ветчиной",
+    "ветчины",
+    "вечер",
+    "вечера",
+    "вечерам",
+    "вечерах",
+    "вечере",
+    "вечереет",
+    "вечерело",
+    "вечерком",
+    "вечерне",
+    "вечернего",
+    "вечернее",
+    "вечерней",
+    "вечернем",
+    "вечерние",
+    "вечерний",
+    "вечерних",
+    "вечеров",
+    "вечерок",
+    "вечером",
+    "вечеру",
+    "вечная",
+    "вечно",
+    "вечное",
+    "вечной",
+    "вечном",
+    "вечному",
+    "вечности",
+    "вечность",
+    "вечную",
+    "вечные",
+    "вечный",
+    "вечным",
+    "вечными",
+    "вечных",
+    "вешает",
+    "вешал",
+    "вешались",
+    "вешаться",
+    "вешаются",
+    "веше",
+    "веши",
+    "вешней",
+    "вешнею",
+    "вешний",
+    "вешняя",
+    "вещам",
+    "вещами",
+    "вещания",
+    "вещах",
+    "вещей",
+    "веществ",
+    "вещественные",
+    "вещи",
+    "вещим",
+    "вещих",
+    "вещица",
+    "вещицы",
+    "вещь",
+    "вещью",
+    "веял",
+    "веяло",
+    "веянье",
+    "веяньем",
+    "взад",
+    "взаимно",
+    "взаимного",
+    "взаимное",
+    "взаимной",
+    "взаимном",
+    "взаимности",
+    "взаимность",
+    "взаимностью",
+    "взаимною",
+    "взаимные",
+    "взаимными",
+    "взаимных",
+    "взаймы",
+    "взамен",
+    "взаперти",
+    "взаправду",
+    "взапуски",
+    "взбегал",
+    "взбежав",
+    "взбежать",
+    "взбесившийся",
+    "взбесился",
+    "взбесит",
+    "взбесятся",
+    "взбешенный",
+    "взбирались",
+    "взбираться",
+    "взбираясь",
+    "взбитые",
+    "взбитыми",
+    "взбороздив",
+    "взбунтоваться",
+    "взбунтуется",
+    "взведет",
+    "взведете",
+    "взвел",
+    "взвела",
+    "взвесил",
+    "взвесила",
+    "взвешивает",
+    "взвешивал",
+    "взвешивая",
+    "взвивается",
+    "взвизги",
+    "взвизгнул",
+    "взвизгнула",
+    "взвизгов",
+    "взвизгом",
+    "взвилась",
+    "взвился",
+    "взвод",
+    "взводимых",
+    "взводом",
+    "взволновал",
+    "взволновалась",
+    "взволновало",
+    "взволновался",
+    "взволнован",
+    "взволнована",
+    "взволнованная",
+    "взволнованно",
+    "взволнованное",
+    "взволнованный",
+    "взволнованных",
+    "взволнованы",
+    "взволновать",
+    "взволнует",
+    "взвыл",
+    "взгляд",
+    "взгляда",
+    "взглядами",
+    "взгляде",
+    "взглядов",
+    "взглядом",
+    "взгляду",
+    "взгляды",
+    "взглядывает",
+    "взглядывал",
+    "взглядывала",
+    "взглядывали",
+    "взглядываниями",
+    "взглядывают",
+    "взглядывая",
+    "взглянет",
+    "взглянешь",
+    "взгляни",
+    "взгляните",
+    "взгляну",
+    "взглянув",
+    "взглянул",
+    "взглянула",
+    "взглянули",
+    "взглянуть",
+    "вздернет",
+    "вздернута",
+    "вздор",
+    "вздоре",
+    "вздорная",
+    "вздорные",
+    "вздорными",
+    "вздорожают",
+    "вздором",
+    "вздору",
+    "вздох",
+    "вздохами",
+    "вздохи",
+    "вздохнет",
+    "вздохни",
+    "вздохну",
+    "вздохнув",
+    "вздохнул",
+    "вздохнула",
+    "вздохнули",
+    "вздохнуть",
+    "вздохом",
+    "вздрагивавшие",
+    "вздрагивает",
+    "вздрагивал",
+    "вздрагивала",
+    "вздрагивали",
+    "вздрагиваньям",
+    "вздрагивают",
+    "вздрагивающей",
+    "вздрагивающими",
+    "вздрагивая",
+    "вздремнет",
+    "вздремнул",
+    "вздремнула",
+    "вздрогнет",
+    "вздрогнув",
+    "вздрогнул",
+    "вздрогнула",
+    "вздрогнули",
+    "вздрогнут",
+    "вздули",
+    "вздулись",
+    "вздумавший",
+    "вздумает",
+    "вздумается",
+    "вздумал",
+    "вздумала",
+    "вздумали",
+    "вздумалось",
+    "вздымается",
+    "вздымаются",
+    "вздыхает",
+    "вздыхал",
+    "вздыхала",
+    "вздыхатель",
+    "вздыхать",
+    "вздыхают",
+    "вздыхающем",
+    "вздыхая",
+    "взирая",
+    "взламывать",
+    "взлет",
+    "взлетающего",
+    "взлетел",
+    "взлетит",
+    "взлечу",
+    "взломом",
+    "взлохмачены",
+    "взмахнет",
+    "взмахни",
+    "взмахнув",
+    "взмахнул",
+    "взмахом",
+    "взметутся",
+    "взнос",
+    "взобраться",
+    "взобьет",
+    "взовьется",
+    "взойдет",
+    "взойду",
+    "взойдя",
+    "взойти",
+    "взор",
+    "взора",
+    "взоре",
+    "взоров",
+    "взором",
+    "взору",
+    "взоры",
+    "взошел",
+    "взошла",
+    "взошло",
+    "взрослые",
+    "взрослый",
+    "взрослыми",
+    "взрослых",
+    "взрыв",
+    "взрыва",
+    "взрывов",
+    "взрывом",
+    "взрывы",
+    "взъерошенный",
+    "взывает",
+    "взывал",
+    "взывала",
+    "взывают",
+    "взывая",
+    "взыграет",
+    "взыскание",
+    "взыскании",
+    "взысканий",
+    "взысканию",
+    "взыскания",
+    "взысканиях",
+    "взыскательна",
+    "взыскательнее",
+    "взыскательному",
+    "взыскать",
+    "взыскивать",
+    "взыскиваю",
+    "взыщите",
+    "взяв",
+    "взявши",
+    "взял",
+    "взяла",
+    "взялась",
+    "взяли",
+    "взялись",
+    "взяло",
+    "взялось",
+    "взялся",
+    "взятки",
+    "взяток",
+    "взяточник",
+    "взяточника",
+    "взяточников",
+    "взяты",
+    "взятые",
+    "взятых",
+    "взять",
+    "взяться",
+    "вив",
+    "вид",
+    "вида",
+    "видавший",
+    "видал",
+    "видала",
+    "видалась",
+    "видали",
+    "видались",
+    "видано",
+    "видать",
+    "видаться",
+    "видах",
+    "виде",
+    "видевший",
+    "видевших",
+    "видел",
+    "видела",
+    "видели",
+    "виделись",
+    "видело",
+    "виделся",
+    "видемши",
+    "виден",
+    "видена",
+    "видение",
+    "видением",
+    "видений",
+    "видения",
+    "видениям",
+    "видениями",
+    "виденною",
+    "виденный",
+    "виденных",
+    "виденье",
+    "виденья",
+    "видеть",
+    "видеться",
+    "видим",
+    "видимая",
+    "видимо",
+    "видимое",
+    "видимой",
+    "видимом",
+    "видимому",
+    "видимою",
+    "видимся",
+    "видимый",
+    "видимым",
+    "видимых",
+    "видит",
+    "видите",
+    "видится",
+    "видишь",
+    "видишься",
+    "видна",
+    "видная",
+    "виднелась",
+    "виднелись",
+    "виднелось",
+    "видно",
+    "видного",
+    "видною",
+    "видны",
+    "видов",
+    "видоизменения",
+    "видоизменяющаяся",
+    "видом",
+    "виду",
+    "виды",
+    "видывал",
+    "видывали",
+    "видя",
+    "видят",
+    "видятся",
+    "видящий",
+    "вижу",
+    "вижусь",
+    "визави",
+    "визг",
+    "визгливо",
+    "визгливый",
+    "визгом",
+    "визжала",
+    "визжаль",
+    "визжат",
+    "визжать",
+    "визжит",
+    "визит",
+    "визита",
+    "визитами",
+    "визите",
+    "визитик",
+    "визитная",
+    "визитную",
+    "визитный",
+    "визитных",
+    "визитов",
+    "визитом",
+    "визиты",
+    "вилами",
+    "виланда",
+    "вилась",
+    "вилка",
+    "вилки",
+    "вилкой",
+    "вилку",
+    "виллу",
+    "вилой",
+    "вильне",
+    "виляй",
+    "виляя",
+    "вин",
+    "вина",
+    "вине",
+    "вини",
+    "винили",
+    "вините",
+    "винительный",
+    "винить",
+    "винишь",
+    "винные",
+    "винным",
+    "вино",
+    "виноват",
+    "виновата",
+    "виноватая",
+    "виноватее",
+    "виновато",
+    "виноватого",
+    "виноватою",
+    "виноваты",
+    "виноватым",
+    "виновен",
+    "виновника",
+    "виновником",
+    "виновница",
+    "виновным",
+    "виновных",
+    "виноград",
+    "винограда",
+    "виноградные",
+    "виноградом",
+    "вином",
+    "винт",
+    "винта",
+    "винтами",
+    "винтовой",
+    "винтообразной",
+    "винтообразный",
+    "винты",
+    "вину",
+    "винца",
+    "винчи",
+    "вины",
+    "виню",
+    "винюсь",
+    "виртуоз",
+    "виртуозом",
+    "висевшие",
+    "висел",
+    "висели",
+    "висело",
+    "висеть",
+    "виси",
+    "висит",
+    "вискам",
+    "висках",
+    "виски",
+    "виску",
+    "висла",
+    "вислой",
+    "вислу",
+    "виснет",
+    "висок",
+    "висячую",
+    "висящие",
+    "висящий",
+    "висящих",
+    "витиеватой",
+    "витиеватым",
+    "витое",
+    "витринах",
+    "вифании",
+    "вифлеема",
+    "вихор",
+    "вихрастый",
+    "вихре",
+    "вихревое",
+    "вихрем",
+    "вихри",
+    "вихров",
+    "вихры",
+    "вихрь",
+    "вице",
+    "вицмундир",
+    "вицмундира",
+    "вицмундире",
+    "вишен",
+    "вишеньем",
+    "вишере",
+    "вишневого",
+    "вишневому",
+    "вишневые",
+    "вишневый",
+    "вишни",
+    "вишню",
+    "вишня",
+    "вишь",
+    "виясь",
+    "вкладывались",
+    "включая",
+    "вколачивали",
+    "вколотить",
+    "вконец",
+    "вкопанная",
+    "вкопанный",
+    "вкось",
+    "вкрадчиво",
+    "вкрадывается",
+    "вкралась",
+    "вкралось",
+    "вкривь",
+    "вкруг",
+    "вкус",
+    "вкуса",
+    "вкусе",
+    "вкусил",
+    "вкусит",
+    "вкусная",
+    "вкусно",
+    "вкусное",
+    "вкусный",
+    "вкусом",
+    "вкусу",
+    "вкушать",
+    "вл",
+    "влагает",
+    "влагала",
+    "влаги",
+    "влагой",
+    "владевшие",
+    "владеет",
+    "владел",
+    "владела",
+    "владелец",
+    "владельцу",
+    "владетельного",
+    "владеть",
+    "владею",
+    "владея",
+    "владимир",
+    "владимира",
+    "владимирка",
+    "владимирке",
+    "владимирович",
+    "владимиру",
+    "владычества",
+    "владычествовал",
+    "владычествовать",
+    "владычица",
+    "владычицу",
+    "влажна",
+    "влажны",
+    "влажные",
+    "влажный",
+    "властвовала",
+    "властвовать",
+    "властвует",
+    "властей",
+    "властелин",
+    "властен",
+    "власти",
+    "властительно",
+    "властному",
+    "властный",
+    "власть",
+    "влачат",
+    "влево",
+    "влез",
+    "влезал",
+    "влезая",
+    "влезет",
+    "влезешь",
+    "влезли",
+    "влезть",
+    "влезут",
+    "влекли",
+    "влекло",
+    "влекома",
+    "влекущая",
+    "влекущий",
+    "влетел",
+    "влетела",
+    "влетели",
+    "влетит",
+    "влечение",
+    "влечению",
+    "влечения",
+    "влеченье",
+    "влечет",
+    "влечешь",
+    "вливается",
+    "вливалась",
+    "вливают",
+    "влил",
+    "влиявшие",
+    "влияло",
+    "влияние",
+    "влиянием",
+    "влияний",
+    "влиянию",
+    "влияния",
+    "вложением",
+    "вложил",
+    "вложили",
+    "вложить",
+    "влопался",
+    "влюбилась",
+    "влюбился",
+    "влюбитесь",
+    "влюбиться",
+    "влюблен",
+    "влюблена",
+    "влюбленно",
+    "влюбленного",
+    "влюбленное",
+    "влюбленной",
+    "влюбленности",
+    "влюбленность",
+    "влюбленные",
+    "влюбленный",
+    "влюбленным",
+    "влюбленными",
+    "влюблены",
+    "влюблюсь",
+    "влюбляется",
+    "влюблялись",
+    "влюбляться",
+    "влюбляются",
+    "влюбляясь",
+    "вместе",
+    "вместо",
+    "вмешалась",
+    "вмешался",
+    "вмещал",
+    "внаем",
+    "внакидку",
+    "внакладочку",
+    "вначале",
+    "вне",
+    "внезапная",
+    "внезапно",
+    "внезапного",
+    "внезапное",
+    "внезапной",
+    "внезапном",
+    "внезапному",
+    "внезапною",
+    "внезапную",
+    "внезапные",
+    "внезапный",
+    "внезапным",
+    "внезапными",
+    "внезапных",
+    "внемлю",
+    "внемлют",
+    "внес",
+    "внесенные",
+    "внеси",
+    "внесла",
+    "внесли",
+    "внести",
+    "внесу",
+    "внеся",
+    "внешне",
+    "внешней",
+    "внешний",
+    "внешним",
+    "внешних",
+    "внешнюю",
+    "внешняя",
+    "вниз",
+    "внизу",
+    "вникаешь",
+    "вникал",
+    "вникать",
+    "вникая",
+    "вникните",
+    "вникнул",
+    "вникнуть",
+    "внимай",
+    "внимал",
+    "внимание",
+    "вниманием",
+    "внимании",
+    "внимания",
+    "вниманье",
+    "вниманью",
+    "вниманья",
+    "внимателен",
+    "внимательнее",
+    "внимательно",
+    "внимательные",
+    "внимательным",
+    "внимать",
+    "внимаю",
+    "внимая",
+    "внове",
+    "вновь",
+    "вносит",
+    "вносите",
+    "вносить",
+    "вносишь",
+    "внося",
+    "внука",
+    "внуки",
+    "внутренне",
+    "внутреннего",
+    "внутреннее",
+    "внутренней",
+    "внутреннею",
+    "внутренние",
+    "внутренний",
+    "внутренним",
+    "внутренних",
+    "внутренно",
+    "внутренности",
+    "внутреннюю",
+    "внутренняя",
+    "внутри",
+    "внутрь",
+    "внучата",
+    "внучка",
+    "внушает",
+    "внушал",
+    "внушало",
+    "внушают",
+    "внушению",
+    "внушения",
+    "внушенная",
+    "внушенным",
+    "внушив",
+    "внушил",
+    "внушите",
+    "внушительнее",
+    "внушительно",
+    "внушительный",
+    "внушительным",
+    "внушить",
+    "внятен",
+    "внятно",
+    "внятной",
+    "внятным",
+    "во",
+    "вобла",
+    "вовек",
+    "вовлечен",
+    "вовремя",
+    "вовсе",
+    "вогнать",
+    "вод",
+    "вода",
+    "водворения",
+    "водворилась",
+    "водворилось",
+    "водворить",
+    "водворялся",
+    "водворят",
+    "воде",
+    "водевиля",
+    "водил",
+    "водила",
+    "водили",
+    "водились",
+    "водилось",
+    "водит",
+    "водится",
+    "водить",
+    "водицы",
+    "водка",
+    "водки",
+    "водкой",
+    "водку",
+    "водной",
+    "водовоз",
+    "водоворот",
+    "водоеме",
+    "водой",
+    "водопад",
+    "водопады",
+    "водочки",
+    "водочку",
+    "водою",
+    "воду",
+    "воды",
+    "водяная",
+    "водянистый",
+    "водянистым",
+    "водяного",
+    "водяной",
+    "водяную",
+    "водяные",
+    "водят",
+    "воедино",
+    "военного",
+    "военное",
+    "военной",
+    "военною",
+    "военную",
+    "военные",
+    "военный",
+    "военным",
+    "военных",
+    "воет",
+    "вожак",
+    "вожди",
+    "вождь",
+    "вожжи",
+    "воз",
+    "воза",
+    "возалкал",
+    "возами",
+    "возблагодарили",
+    "возблагодарю",
+    "возбудил",
+    "возбудила",
+    "возбудило",
+    "возбудимы",
+    "возбудить",
+    "возбудят",
+    "возбуждает",
+    "возбуждаете",
+    "возбуждал",
+    "возбуждались",
+    "возбуждало",
+    "возбуждать",
+    "возбуждая",
+    "возбуждении",
+    "возбуждения",
+    "возбужденно",
+    "возбужденное",
+    "возбужденном",
+    "возбужденный",
+    "возведен",
+    "возведении",
+    "возвел",
+    "возвела",
+    "возвестили",
+    "возвещено",
+    "возврат",
+    "возврата",
+    "возвратившихся",
+    "возвратила",
+    "возвратилась",
+    "возвратился",
+    "возвратите",
+    "возвратится",
+    "возвратить",
+    "возвратиться",
+    "возвратишь",
+    "возвратного",
+    "возвратный",
+    "возвратясь",
+    "возвращавшийся",
+    "возвращается",
+    "возвращаешься",
+    "возвращал",
+    "возвращалась",
+    "возвращались",
+    "возвращалось",
+    "возвращался",
+    "возвращать",
+    "возвращаться",
+    "возвращаюсь",
+    "возвращаются",
+    "возвращающегося",
+    "возвращаясь",
+    "возвращение",
+    "возвращении",
+    "возвращения",
+    "возвращенья",
+    "возвращу",
+    "возвысив",
+    "возвысился",
+    "возвысить",
+    "возвышались",
+    "возвышалось",
+    "возвышался",
+    "возвышать",
+    "возвышаться",
+    "возвышая",
+    "возвышаясь",
+    "возвышенная",
+    "возвышенный",
+    "возглаголет",
+    "возглаголят",
+    "возглас",
+    "возгласы",
+    "воздвиглась",
+    "воздвигнутых",
+    "воздел",
+    "возделывал",
+    "воздержание",
+    "воздержнее",
+    "воздусех",
+    "воздух",
+    "воздуха",
+    "воздухе",
+    "воздухом",
+    "воздуху",
+    "воздушном",
+    "воздушные",
+    "воздушный",
+    "воздушных",
+    "воздыханий",
+    "возжаждешь",
+    "воззвал",
+    "воззвание",
+    "воззрение",
+    "воззрения",
+    "воззреньях",
+    "возила",
+    "возили",
+    "возились",
+    "возился",
+    "возить",
+    "возиться",
+    "возишься",
+    "возле",
+    "возлелеянную",
+    "возложил",
+    "возлюби",
+    "возлюбила",
+    "возлюбишь",
+    "возлюбленного",
+    "возлюбленной",
+    "возлюбленные",
+    "возлюбленный",
+    "возлюблял",
+    "возмездие",
+    "возмездия",
+    "возможна",
+    "возможно",
+    "возможности",
+    "возможность",
+    "возможную",
+    "возможным",
+    "возможных",
+    "возмутившее",
+    "возмутился",
+    "возмутительно",
+    "возмутителям",
+    "возмутить",
+    "возмутишь",
+    "возмущает",
+    "возмущающих",
+    "возмущаясь",
+    "возмущен",
+    "возмущено",
+    "вознаградить",
+    "вознаграждать",
+    "вознаграждая",
+    "вознаграждение",
+    "вознаграждением",
+    "вознамерился",
+    "возненавидел",
+    "возненавидела",
+    "возненавидит",
+    "возненавидишь",
+    "возненавижу",
+    "вознесенском",
+    "вознесены",
+    "вознесу",
+    "возни",
+    "возник",
+    "возникает",
+    "возникал",
+    "возникала",
+    "возникать",
+    "возникающей",
+    "возникла",
+    "возникло",
+    "возникнет",
+    "возникновения",
+    "возникшего",
+    "возносилась",
+    "возносится",
+    "вознося",
+    "возню",
+    "возня",
+    "возобновились",
+    "возобновить",
+    "возобновляет",
+    "возобновлялась",
+    "возов",
+    "возом",
+    "возопил",
+    "возражает",
+    "возражал",
+    "возражений",
+    "возражения",
+    "возразил",
+    "возразила",
+    "возразить",
+    "возраст",
+    "возраста",
+    "возраставшего",
+    "возраставшее",
+    "возраставшим",
+    "возрастало",
+    "возрастающей",
+    "возрастая",
+    "возрасте",
+    "возрастом",
+    "возрасты",
+    "возродить",
+    "возрождалась",
+    "возрождение",
+    "возрождению",
+    "возросла",
+    "возросло",
+    "возу",
+    "возымел",
+    "возыметь",
+    "возьмем",
+    "возьмет",
+    "возьмете",
+    "возьметесь",
+    "возьмется",
+    "возьмешь",
+    "возьми",
+    "возьмите",
+    "возьму",
+    "возьмут",
+    "возьмутся",
+    "возят",
+    "воин",
+    "воина",
+    "воину",
+    "воины",
+    "воистину",
+    "вой",
+    "войдем",
+    "войдет",
+    "войдешь",
+    "войди",
+    "войдите",
+    "войду",
+    "войдут",
+    "войдя",
+    "войлок",
+    "войлоками",
+    "войлоке",
+    "войн",
+    "война",
+    "войнами",
+    "войне",
+    "войницкая",
+    "войницкии",
+    "войницкий",
+    "войницким",
+    "войницкого",
+    "войницкому",
+    "войну",
+    "войны",
+    "войска",
+    "войско",
+    "войском",
+    "войти",
+    "вокзал",
+    "вокзале",
+    "вокзалу",
+    "вокруг",
+    "вол",
+    "волге",
+    "волгу",
+    "воле",
+    "волей",
+    "волен",
+    "волею",
+    "воли",
+    "волк",
+    "волки",
+    "волков",
+    "волн",
+    "волна",
+    "волнами",
+    "волнах",
+    "волнение",
+    "волнением",
+    "волнении",
+    "волнений",
+    "волнения",
+    "волнениями",
+    "волненье",
+    "волненьем",
+    "волненья",
+    "волновала",
+    "волновалась",
+    "волновали",
+    "волновались",
+    "волновало",
+    "волновался",
+    "волновать",
+    "волноваться",
+    "волной",
+    "волною",
+    "волну",
+    "волнуем",
+    "волнует",
+    "волнуете",
+    "волнуетесь",
+    "волнуется",
+    "волнуешь",
+    "волнуешься",
+    "волнуйся",
+    "волнуйтесь",
+    "волнуюсь",
+    "волнуют",
+    "волнуются",
+    "волнующей",
+    "волнующейся",
+    "волнующуюся",
+    "волнуясь",
+    "волны",
+    "воло",
+    "волокита",
+    "волокиты",
+    "волоком",
+    "волом",
+    "волос",
+    "волоса",
+    "волосам",
+    "волосами",
+    "волосатые",
+    "волосах",
+    "волосенки",
+    "волосики",
+    "волоска",
+    "волоски",
+    "волости",
+    "волосы",
+    "волочит",
+    "волчком",
+    "волчок",
+    "волчьим",
+    "волшебная",
+    "волшебника",
+    "волшебниц",
+    "волшебница",
+    "волшебницы",
+    "волшебного",
+    "волшебной",
+    "волшебные",
+    "волшебств",
+    "воль",
+    "вольна",
+    "вольно",
+    "вольнодумные",
+    "вольнодумцам",
+    "вольное",
+    "вольной",
+    "вольном",
+    "вольному",
+    "вольною",
+    "вольные",
+    "вольный",
+    "вольным",
+    "вольтер",
+    "волю",
+    "воля",
+    "вон",
+    "вона",
+    "вонзает",
+    "вонзай",
+    "вонзал",
+    "вонзила",
+    "вонзился",
+    "вонзить",
+    "вони",
+    "вонь",
+    "вонью",
+    "вонючего",
+    "вонючих",
+    "воображает",
+    "воображаешь",
+    "воображал",
+    "воображала",
+    "воображали",
+    "воображать",
+    "воображаю",
+    "воображают",
+    "воображая",
+    "воображение",
+    "воображением",
+    "воображении",
+    "воображению",
+    "воображения",
+    "воображениями",
+    "воображениях",
+    "вообрази",
+    "вообразил",
+    "вообразила",
+    "вообразили",
+    "вообразилось",
+    "вообразился",
+    "вообразись",
+    "вообразит",
+    "вообразите",
+    "вообразится",
+    "вообразить",
+    "вообще",
+    "вооружался",
+    "вооружаться",
+    "вооружаясь",
+    "вооружений",
+    "вооруженная",
+    "вооруженный",
+    "вооружились",
+    "вооружиться",
+    "вопил",
+    "вопила",
+    "вопиющее",
+    "вопиющим",
+    "вопле",
+    "воплей",
+    "воплем",
+    "вопли",
+    "воплотился",
+    "воплотить",
+    "воплощался",
+    "воплощение",
+    "воплощенного",
+    "вопль",
+    "вопля",
+    "воплям",
+    "воплями",
+    "вопреки",
+    "вопрос",
+    "вопроса",
+    "вопросам",
+    "вопросами",
+    "вопросах",
+    "вопросе",
+    "вопросик",
+    "вопросительно",
+    "вопросительного",
+    "вопросительные",
+    "вопросительный",
+    "вопросов",
+    "вопросом",
+    "вопросу",
+    "вопросы",
+    "вопрошаю",
+    "вопрошающей",
+    "вопрошающий",
+    "вопрошающих",
+    "вопьется",
+    "вор",
+    "вора",
+    "ворвавшись",
+    "ворвалась",
+    "ворвался",
+    "ворвется",
+    "воробей",
+    "воробушек",
+    "воробушка",
+    "воров",
+    "вороваль",
+    "воровать",
+    "воровка",
+    "воровки",
+    "воровских",
+    "ворожим",
+    "вором",
+    "ворон",
+    "вороная",
+    "воронку",
+    "вороны",
+    "воронье",
+    "воронья",
+    "ворот",
+    "ворота",
+    "воротам",
+    "воротами",
+    "воротах",
+    "вороти",
+    "воротившийся",
+    "воротившись",
+    "воротила",
+    "воротилась",
+    "воротились",
+    "воротилось",
+    "воротился",
+    "воротись",
+    "воротитесь",
+    "воротится",
+    "воротить",
+    "воротиться",
+    "воротишь",
+    "воротишься",
+    "воротник",
+    "воротников",
+    "воротником",
+    "воротничках",
+    "воротнички",
+    "воротничок",
+    "воротясь",
+    "воротятся",
+    "ворохом",
+    "ворочает",
+    "ворочается",
+    "ворочаешься",
+    "ворочал",
+    "ворочала",
+    "ворочались",
+    "ворочало",
+    "ворочалось",
+    "ворочался",
+    "ворочать",
+    "ворочаться",
+    "ворочающими",
+    "ворочаясь",
+    "ворочу",
+    "ворочусь",
+    "ворует",
+    "воруете",
+    "ворующего",
+    "ворча",
+    "ворчавшую",
+    "ворчал",
+    "ворчала",
+    "ворчанье",
+    "ворчаньем",
+    "ворчать",
+    "ворчит",
+    "ворчишь",
+    "ворчливые",
+    "ворчу",
+    "воры",
+    "восемнадцати",
+    "восемнадцать",
+    "восемь",
+    "восемьдесят",
+    "восемьсот",
+    "воск",
+    "воскликнул",
+    "воскликнула",
+    "восклицал",
+    "восклицала",
+    "восклицание",
+    "восклицанием",
+    "восклицании",
+    "восклицанию",
+    "восклицания",
+    "восклицаниями",
+    "восклицанья",
+    "восклицая",
+    "восклоняясь",
+    "воскрес",
+    "воскресать",
+    "воскресение",
+    "воскресении",
+    "воскресения",
+    "воскресенье",
+    "воскресенью",
+    "воскресенья",
+    "воскресеньям",
+    "воскресил",
+    "воскресила",
+    "воскресить",
+    "воскресла",
+    "воскресло",
+    "воскреснет",
+    "воскреснув",
+    "воскресную",
+    "воскресный",
+    "воскресных",
+    "воскресший",
+    "воскресят",
+    "воскрешает",
+    "воскрешало",
+    "вослед",
+    "воспаление",
+    "воспаления",
+    "воспаленно",
+    "воспаленного",
+    "воспаленный",
+    "воспаленным",
+    "воспаленных",
+    "воспитаешь",
+    "воспитался",
+    "воспитан",
+    "воспитана",
+    "воспитание",
+    "воспитанием",
+    "воспитанию",
+    "воспитания",
+    "воспитанная",
+    "воспитанные",
+    "воспитанный",
+    "воспитанных",
+    "воспитаны",
+    "воспитаться",
+    "воспитывалась",
+    "воспитывался",
+    "воспитывать",
+    "воспитывая",
+    "воспламенившись",
+    "воспламенился",
+    "воспламенялся",
+    "воспользовавшись",
+    "воспользовались",
+    "воспользовался",
+    "воспользоваться",
+    "воспользуется",
+    "воспоминание",
+    "воспоминанием",
+    "воспоминании",
+    "воспоминаний",
+    "воспоминанию",
+    "воспоминания",
+    "воспоминаниями",
+    "воспоминаниях",
+    "воспоминанье",
+    "воспоминанья",
+    "воспоследует",
+    "воспрещено",
+    "восприимчив",
+    "восприимчивая",
+    "восприимчивом",
+    "восприимчивость",
+    "восприимчивый",
+    "восприняла",
+    "воспринятию",
+    "воспроизводится",
+    "воспротивиться",
+    "воспрянуть",
+    "восскорбел",
+    "восстав",
+    "восставал",
+    "восставшего",
+    "восстал",
+    "восстали",
+    "восстанет",
+    "восстановил",
+    "восстановила",
+    "восстановили",
+    "восстановить",
+    "восстановлен",
+    "восстановляло",
+    "восстановляя",
+    "восстанут",
+    "восстанья",
+    "востер",
+    "восток",
+    "востока",
+    "восторг",
+    "восторга",
+    "восторгам",
+    "восторгами",
+    "восторгаться",
+    "восторге",
+    "восторги",
+    "восторгом",
+    "восторженная",
+    "восторженно",
+    "восторженного",
+    "восторженное",
+    "восторженность",
+    "восторженностью",
+    "восторженною",
+    "восторженный",
+    "восторженным",
+    "восторженными",
+    "восторженных",
+    "восточной",
+    "восточный",
+    "востреньким",
+    "востренькое",
+    "востро",
+    "востроногий",
+    "вострые",
+    "вострым",
+    "вострыми",
+    "восхвалить",
+    "восхитительная",
+    "восхищался",
+    "восхищаться",
+    "восхищение",
+    "восхищенного",
+    "восхищеньи",
+    "восходил",
+    "восходит",
+    "восходить",
+    "восходном",
+    "восхождения",
+    "восьмерка",
+    "восьми",
+    "восьмидесяти",
+    "восьмидесятых",
+    "восьмилетнего",
+    "восьмого",
+    "восьмой",
+    "восьмом",
+    "восьмому",
+    "восьмую",
+    "вот",
+    "воткнул",
+    "воткнут",
+    "вотчине",
+    "воцарилась",
+    "воцарилось",
+    "воцаряется",
+    "вочеловечить",
+    "вошедшая",
+    "вошедшего",
+    "вошедшему",
+    "вошедший",
+    "вошедшими",
+    "вошедших",
+    "вошедшую",
+    "вошел",
+    "вошла",
+    "вошли",
+    "вошло",
+    "вошь",
+    "воюет",
+    "воющую",
+    "воя",
+    "вояж",
+    "вояжа",
+    "вояжем",
+    "вояжирует",
+    "впавшими",
+    "впадавшего",
+    "впадает",
+    "впадал",
+    "впадала",
+    "впадая",
+    "впадешь",
+    "впал",
+    "впала",
+    "впали",
+    "впасть",
+    "впервой",
+    "впервые",
+    "вперегонку",
+    "вперед",
+    "впереди",
+    "вперяя",
+    "вперяясь",
+    "впечатление",
+    "впечатлении",
+    "впечатлений",
+    "впечатлению",
+    "впечатления",
+    "впечатлительное",
+    "впечатлительностью",
+    "впивался",
+    "впиваясь",
+    "впилась",
+    "впился",
+    "вплелись",
+    "вплести",
+    "вплетаю",
+    "вплоть",
+    "вповалку",
+    "вполглаза",
+    "вполголоса",
+    "вползает",
+    "вползало",
+    "вполне",
+    "вполовину",
+    "вполуоборот",
+    "впопад",
+    "впопыхах",
+    "впору",
+    "впоследствии",
+    "впотьмах",
+    "вправду",
+    "вправе",
+    "вправо",
+    "впредь",
+    "впридачу",
+    "впрок",
+    "впрочем",
+    "впрягают",
+    "впряжена",
+    "впрямь",
+    "впускает",
+    "впускала",
+    "впускать",
+    "впустить",
+    "впустят",
+    "впятеро",
+    "вр",
+    "враг",
+    "врага",
+    "врагами",
+    "враги",
+    "врагини",
+    "врагов",
+    "врагом",
+    "врагу",
+    "вражда",
+    "враждебно",
+    "враждебного",
+    "враждебное",
+    "враждебную",
+    "враждебны",
+    "враждебные",
+    "враждебными",
+    "враждебных",
+    "враждой",
+    "вражды",
+    "вражеским",
+    "вражеских",
+    "вражий",
+    "вражьей",
+    "вражьим",
+    "враздробь",
+    "вразумите",
+    "вразумихин",
+    "врали",
+    "вранье",
+    "вранью",
+    "врасплох",
+    "врать",
+    "врач",
+    "врача",
+    "врачах",
+    "врачебному",
+    "врачу",
+    "вращает",
+    "вращается",
+    "вращалось",
+    "вревской",
+    "вред",
+    "вреда",
+    "вреден",
+    "вредить",
+    "вредна",
+    "вредная",
+    "вредно",
+    "вредны",
+    "вредные",
+    "вредных",
+    "вредят",
+    "врезался",
+    "врезывается",
+    "врем",
+    "времен",
+    "времена",
+    "временам",
+    "временем",
+    "времени",
+    "временно",
+    "временного",
+    "временное",
+    "временной",
+    "временном",
+    "временным",
+    "временных",
+    "времечко",
+    "время",
+    "врет",
+    "врете",
+    "врешь",
+    "ври",
+    "вроде",
+    "врожденная",
+    "врожденную",
+    "врожденных",
+    "врозь",
+    "вросла",
+    "вру",
+    "врубелем",
+    "врубель",
+    "врубеля",
+    "врут",
+    "врученных",
+    "вручил",
+    "вручить",
+    "врывавшихся",
+    "врывается",
+    "врывалось",
+    "врываясь",
+    "врыть",
+    "вряд",
+    "всажу",
+    "все",
+    "всеблагое",
+    "всевдонимом",
+    "всевозможные",
+    "всевозможных",
+    "всевышнего",
+    "всевышнему",
+    "всегда",
+    "всегдашнее",
+    "всегдашний",
+    "всегдашним",
+    "всегдашнюю",
+    "всегдашняя",
+    "всего",
+    "вседержителя",
+    "вседневная",
+    "всеевропейской",
+    "всезнание",
+    "всезнании",
+    "всезнающей",
+    "всезнающие",
+    "всей",
+    "вселенная",
+    "вселенной",
+    "вселенской",
+    "вселились",
+    "вселился",
+    "вселитературно",
+    "вселить",
+    "вселявшиеся",
+    "вселяется",
+    "всем",
+    "всеми",
+    "всемирной",
+    "всемирную",
+    "всемирный",
+    "всему",
+    "всенаполняющего",
+    "всенародно",
+    "всенижайший",
+    "всеной",
+    "всенощной",
+    "всеобщего",
+    "всеобщее",
+    "всеобщей",
+    "всеобщих",
+    "всеобъемлемостью",
+    "всеоружии",
+    "всепоглощающий",
+    "всесветными",
+    "всесметающая",
+    "всесторонней",
+    "всеуслышание",
+    "всех",
+    "всецело",
+    "всею",
+    "вскакивает",
+    "вскакивал",
+    "вскакивала",
+    "вскакивают",
+    "вскакивая",
+    "вскачь",
+    "вскинув",
+    "вскинул",
+    "вскинулась",
+    "вскинулся",
+    "вскинутом",
+    "вскинутые",
+    "вскипев",
+    "вскипевшего",
+    "вскипел",
+    "вскипело",
+    "всклокоченный",
+    "всклоченного",
+    "вское",
+    "всколосится",
+    "всколыхнулись",
+    "вскользь",
+    "вскоре",
+    "вскочив",
+    "вскочившую",
+    "вскочил",
+    "вскочила",
+    "вскочило",
+    "вскочит",
+    "вскочишь",
+    "вскрикивает",
+    "вскрикивала",
+    "вскрикивания",
+    "вскрикивают",
+    "вскрикивая",
+    "вскрикнет",
+    "вскрикну",
+    "вскрикнул",
+    "вскрикнула",
+    "вскрикнули",
+    "вскрикнут",
+    "вскрикнуть",
+    "вскричал",
+    "вскричала",
+    "вскружить",
+    "вскрывался",
+    "вскрывать",
+    "вскрылась",
+    "вскрыть",
+    "всласть",
+    "вслед",
+    "вследствие",
+    "вслух",
+    "вслушивается",
+    "вслушивалась",
+    "вслушивался",
+    "вслушиваться",
+    "вслушиваясь",
+    "всматривается",
+    "всматривался",
+    "всматриваться",
+    "всматриваясь",
+    "всплескивая",
+    "всплеснув",
+    "всплеснул",
+    "всплеснула",
+    "всплеснулась",
+    "всплывало",
+    "всплывут",
+    "всплыла",
+    "вспоенная",
+    "всполохнутый",
+    "всполошив",
+    "всполошился",
+    "вспоминаем",
+    "вспоминает",
+    "вспоминается",
+    "вспоминаешь",
+    "вспоминай",
+    "вспоминайте",
+    "вспоминал",
+    "вспоминала",
+    "вспоминалась",
+    "вспоминали",
+    "вспоминать",
+    "вспоминаю",
+    "вспоминая",
+    "вспомни",
+    "вспомнив",
+    "вспомнил",
+    "вспомнила",
+    "вспомнилась",
+    "вспомнили",
+    "вспомнились",
+    "вспомнилось",
+    "вспомнился",
+    "вспомнит",
+    "вспомните",
+    "вспомнить",
+    "вспомнишь",
+    "вспомню",
+    "вспомнят",
+    "вспоможение",
+    "вспоможении",
+    "вспоможения",
+    "вспорхнулся",
+    "вспотевшее",
+    "вспрыгивает",
+    "вспрыгнул",
+    "вспрянула",
+    "вспугнул",
+    "вспугнуть",
+    "вспухла",
+    "вспухшею",
+    "вспылив",
+    "вспылил",
+    "вспыльчива",
+    "вспыльчиво",
+    "вспыльчивые",
+    "вспылю",
+    "вспыхивает",
+    "вспыхивал",
+    "вспыхивала",
+    "вспыхивают",
+    "вспыхнет",
+    "вспыхнув",
+    "вспыхнул",
+    "вспыхнула",
+    "вспыхнули",
+    "вспыхнуло",
+    "вспыхнуть",
+    "вспышкам",
+    "вспышки",
+    "вспышку",
+    "вспять",
+    "встав",
+    "вставай",
+    "вставайте",
+    "вставал",
+    "вставала",
+    "вставали",
+    "вставать",
+    "вставая",
+    "вставил",
+    "вставить",
+    "вставлял",
+    "вставши",
+    "вставшие",
+    "вставший",
+    "встает",
+    "встаете",
+    "встаешь",
+    "встал",
+    "встала",
+    "встали",
+    "встанемте",
+    "встанет",
+    "встанешь",
+    "встану",
+    "встанут",
+    "встань",
+    "встаньте",
+    "встарь",
+    "встать",
+    "встаю",
+    "встают",
+    "встревожен",
+    "встревоженная",
+    "встревоженно",
+    "встревоженного",
+    "встревоженную",
+    "встревоженный",
+    "встревоженным",
+    "встревоженными",
+    "встревожившись",
+    "встревожилась",
+    "встревожило",
+    "встревожился",
+    "встревожить",
+    "встрепанный",
+    "встрепенется",
+    "встрепенулась",
+    "встрепенулись",
+    "встрепенулся",
+    "встрепенутся",
+    "встретив",
+    "встретившегося",
+    "встретивший",
+    "встретившись",
+    "встретил",
+    "встретила",
+    "встретили",
+    "встретились",
+    "встретилось",
+    "встретился",
+    "встретимся",
+    "встретит",
+    "встретите",
+    "встретится",
+    "встретить",
+    "встретиться",
+    "встретишь",
+    "встретя",
+    "встретятся",
+    "встреч",
+    "встреча",
+    "встречавшемуся",
+    "встречавшиеся",
+    "встречаемся",
+    "встречает",
+    "встречается",
+    "встречаешь",
+    "встречал",
+    "встречала",
+    "встречалась",
+    "встречали",
+    "встречались",
+    "встречалось",
+    "встречался",
+    "встречать",
+    "встречаться",
+    "встречах",
+    "встречаю",
+    "встречаюсь",
+    "встречают",
+    "встречаются",
+    "встречаясь",
+    "встрече",
+    "встречен",
+    "встреченных",
+    "встречи",
+    "встречного",
+    "встречной",
+    "встречному",
+    "встречные",
+    "встречный",
+    "встречным",
+    "встречных",
+    "встречу",
+    "встряхивает",
+    "встряхну",
+    "встряхнул",
+    "встряхнулся",
+    "встряхнуть",
+    "встряхнуться",
+    "вступает",
+    "вступала",
+    "вступаю",
+    "вступают",
+    "вступая",
+    "вступив",
+    "вступившая",
+    "вступивши",
+    "вступил",
+    "вступила",
+    "вступилась",
+    "вступили",
+    "вступился",
+    "вступить",
+    "вступление",
+    "вступления",
+    "всунув",
+    "всунул",
+    "всунуть",
+    "всхлипнут",
+    "всхлипывает",
+    "всхлипывал",
+    "всхлипывала",
+    "всхлипывать",
+    "всхлипывая",
+    "всходила",
+    "всходили",
+    "всходить",
+    "всю",
+    "всюду",
+    "вся",
+    "всяк",
+    "всякая",
+    "всякие",
+    "всякий",
+    "всяким",
+    "всякими",
+    "всяких",
+    "всякого",
+    "всякое",
+    "всякой",
+    "всяком",
+    "всякому",
+    "всякою",
+    "всяку",
+    "всякую",
+    "всячески",
+    "втайне",
+    "втащила",
+    "втащили",
+    "втеснилось",
+    "втираться",
+    "втихомолку",
+    "втолковать",
+    "втоптала",
+    "вторая",
+    "вторгается",
+    "вторично",
+    "вторичный",
+    "вторник",
+    "второго",
+    "второе",
+    "второй",
+    "втором",
+    "второпях",
+    "второстепенную",
+    "вторую",
+    "вторые",
+    "вторым",
+    "вторых",
+    "втрое",
+    "втуне",
+    "втыкается",
+    "втягивает",
+    "втягивание",
+    "втягивать",
+    "втягивающее",
+    "втянет",
+    "втянется",
+    "втянув",
+    "втянул",
+    "вуали",
+    "вуалью",
+    "вулкана",
+    "вулканических",
+    "вулканической",
+    "вход",
+    "входа",
+    "входе",
+    "входи",
+    "входившего",
+    "входившим",
+    "входил",
+    "входила",
+    "входили",
+    "входило",
+    "входит",
+    "входите",
+    "входить",
+    "входя",
+    "входят",
+    "входящие",
+    "входящий",
+    "входящим",
+    "входящих",
+    "входящую",
+    "вхожу",
+    "вцепилась",
+    "вцепился",
+    "вцепится",
+    "вчера",
+    "вчерашнего",
+    "вчерашнее",
+    "вчерашней",
+    "вчерашнем",
+    "вчерашнему",
+    "вчерашние",
+    "вчерашний",
+    "вчерашним",
+    "вчерашних",
+    "вчерашнюю",
+    "вчерашняя",
+    "вчерне",
+    "вчетверо",
+    "вчуже",
+    "вшей",
+    "вши",
+    "въелась",
+    "въелись",
+    "въехал",
+    "въехали",
+    "въехать",
+    "вы",
+    "выбегает",
+    "выбегали",
+    "выбежавшего",
+    "выбежал",
+    "выбежала",
+    "выбежали",
+    "выбежит",
+    "выбежишь",
+    "выберет",
+    "выберу",
+    "выберут",
+    "выбивался",
+    "выбивая",
+    "выбилась",
+    "выбирает",
+    "выбирай",
+    "выбирать",
+    "выбираться",
+    "выбираю",
+    "выбирают",
+    "выбирая",
+    "выбить",
+    "выборам",
+    "выборгской",
+    "выборгскую",
+    "выборе",
+    "выборы",
+    "выбрав",
+    "выбрал",
+    "выбрала",
+    "выбрали",
+    "выбрался",
+    "выбран",
+    "выбрана",
+    "выбрасывает",
+    "выбрасывать",
+    "выбрать",
+    "выбраться",
+    "выбрился",
+    "выбрит",
+    "выбрито",
+    "выбритый",
+    "выбритыми",
+    "выбросил",
+    "выбросить",
+    "выбрось",
+    "выбросьте",
+    "выброшенные",
+    "выведает",
+    "выведать",
+    "выведения",
+    "выведет",
+    "выведешь",
+    "выведи",
+    "выведу",
+    "выведут",
+    "вывезенному",
+    "вывезенным",
+    "вывезти",
+    "вывел",
+    "вывела",
+    "вывели",
+    "вывело",
+    "вывернул",
+    "вывернула",
+    "вывернулся",
+    "вывернутыми",
+    "вывернуться",
+    "выверт",
+    "вывеска",
+    "вывескам",
+    "вывески",
+    "вывести",
+    "выветриваемся",
+    "выветрившегося",
+    "вывешено",
+    "вывод",
+    "вывода",
+    "выводил",
+    "выводили",
+    "выводило",
+    "выводить",
+    "выводов",
+    "выводом",
+    "выводят",
+    "вывожу",
+    "вывороти",
+    "выворотив",
+    "выворотил",
+    "выгадаешь",
+    "выгадать",
+    "выгладит",
+    "выглядишь",
+    "выглядывавшую",
+    "выглядывает",
+    "выглядывал",
+    "выглядывала",
+    "выглядывали",
+    "выглядывало",
+    "выглядывая",
+    "выглянет",
+    "выглянув",
+    "выглянула",
+    "выглянуло",
+    "выгнал",
+    "выгнали",
+    "выгнанная",
+    "выгнать",
+    "выгнутою",
+    "выговаривает",
+    "выговаривал",
+    "выговаривала",
+    "выговаривало",
+    "выговаривалось",
+    "выговаривать",
+    "выговариваться",
+    "выговаривая",
+    "выговора",
+    "выговорив",
+    "выговорил",
+    "выговорила",
+    "выговорилось",
+    "выговорить",
+    "выговорят",
+    "выгода",
+    "выгодами",
+    "выгодах",
+    "выгоднее",
+    "выгодно",
+    "выгодное",
+    "выгодной",
+    "выгодном",
+    "выгодную",
+    "выгоду",
+    "выгоды",
+    "выгонишь",
+    "выгоню",
+    "выгоняют",
+    "выгорит",
+    "выгребла",
+    "выдавай",
+    "выдавалась",
+    "выдавали",
+    "выдавались",
+    "выдавать",
+    "выдавая",
+    "выдавилась",
+    "выдавливалась",
+    "выдадите",
+    "выдает",
+    "выдаете",
+    "выдал",
+    "выдала",
+    "выдалась",
+    "выдали",
+    "выданное",
+    "выданы",
+    "выдаст",
+    "выдастся",
+    "выдать",
+    "выдают",
+    "выдаются",
+    "выдающеюся",
+    "выдвинул",
+    "выделанно",
+    "выделанным",
+    "выделка",
+    "выделывать",
+    "выделывая",
+    "выдергивает",
+    "выдержав",
+    "выдержал",
+    "выдержала",
+    "выдержали",
+    "выдержать",
+    "выдерживает",
+    "выдерживал",
+    "выдерживаю",
+    "выдерживают",
+    "выдерживая",
+    "выдержит",
+    "выдержите",
+    "выдержишь",
+    "выдержу",
+    "выдернул",
+    "выдернула",
+    "выдохся",
+    "выдрал",
+    "выдрессированная",
+    "выдумав",
+    "выдумаем",
+    "выдумает",
+    "выдумаешь",
+    "выдумай",
+    "выдумал",
+    "выдумала",
+    "выдумалась",
+    "выдумали",
+    "выдуманная",
+    "выдумать",
+    "выдумка",
+    "выдумки",
+    "выдумку",
+    "выдумок",
+    "выдумывал",
+    "выдумывала",
+    "выдумывать",
+    "выдумываю",
+    "выдумывают",
+    "выдь",
+    "выдьте",
+    "выедем",
+    "выеду",
+    "выезда",
+    "выездные",
+    "выездных",
+    "выезжаем",
+    "выезжает",
+    "выезжаете",
+    "выезжал",
+    "выезжала",
+    "выезжать",
+    "выезжают",
+    "выехал",
+    "выехала",
+    "выехали",
+    "выехать",
+    "выждал",
+    "выждать",
+    "выжечь",
+    "выжжены",
+    "выжига",
+    "выжидал",
+    "выжидая",
+    "выжил",
+    "выжили",
+    "выжимали",
+    "выжмет",
+    "вызвав",
+    "вызвал",
+    "вызвала",
+    "вызвали",
+    "вызвало",
+    "вызвался",
+    "вызванные",
+    "вызвать",
+    "выздоравливать",
+    "выздоравливаю",
+    "выздоравливая",
+    "выздоровевший",
+    "выздоровеешь",
+    "выздоровел",
+    "выздороветь",
+    "выздоровление",
+    "выздоровлению",
+    "выздоровления",
+    "выздоровлю",
+    "вызов",
+    "вызова",
+    "вызовет",
+    "вызывает",
+    "вызывала",
+    "вызывали",
+    "вызывают",
+    "вызывающее",
+    "вызывающею",
+    "вызывающий",
+    "вызывающим",
+    "вызывая",
+    "выиграет",
+    "выиграл",
+    "выиграна",
+    "выиграть",
+    "вый",
+    "выйдем",
+    "выйдемте",
+    "выйдет",
+    "выйдешь",
+    "выйди",
+    "выйду",
+    "выйдут",
+    "выйдя",
+    "выйти",
+    "выказал",
+    "выказана",
+    "выказывает",
+    "выказывал",
+    "выказывались",
+    "выказывалось",
+    "выказывать",
+    "выкатила",
+    "выкатились",
+    "выкладку",
+    "выкладывал",
+    "выковывал",
+    "выколачивать",
+    "выколоти",
+    "выколочу",
+    "выкопал",
+    "выкопать",
+    "выкрасили",
+    "выкрикивал",
+    "выкрикивала",
+    "выкричишься",
+    "выкупала",
+    "выкупила",
+    "выкупить",
+    "выкупленное",
+    "выкупленные",
+    "выкуплю",
+    "выкуренная",
+    "выкуривает",
+    "выкурить",
+    "выл",
+    "выла",
+    "вылазки",
+    "вылакать",
+    "вылез",
+    "вылезали",
+    "вылезая",
+    "вылепленными",
+    "вылериановые",
+    "вылетали",
+    "вылетел",
+    "вылетели",
+    "вылетело",
+    "вылетят",
+    "вылечитесь",
+    "вылечить",
+    "вылечу",
+    "вылил",
+    "вылитую",
+    "вылить",
+    "выложи",
+    "выложил",
+    "выложила",
+    "выложит",
+    "выложить",
+    "вылупила",
+    "вылью",
+    "вымазав",
+    "выманивать",
+    "выманила",
+    "вымела",
+    "вымерло",
+    "выметают",
+    "вымети",
+    "вымолвила",
+    "вымолвить",
+    "вымочит",
+    "вымоют",
+    "вымпелами",
+    "вымыв",
+    "вымыл",
+    "вымыла",
+    "вымыли",
+    "вымылся",
+    "вымысла",
+    "вымыслом",
+    "вымыты",
+    "вымытые",
+    "вымытый",
+    "вымытыми",
+    "вымыть",
+    "вымыться",
+    "вынес",
+    "вынесем",
+    "вынесенного",
+    "вынесет",
+    "вынеси",
+    "вынесла",
+    "вынесли",
+    "вынести",
+    "вынесу",
+    "вынет",
+    "вынимает",
+    "вынимала",
+    "вынимать",
+    "вынимая",
+    "выносившие",
+    "выносил",
+    "выносила",
+    "выносили",
+    "выносились",
+    "выносился",
+    "выносимая",
+    "выносит",
+    "выносите",
+    "выносить",
+    "выношу",
+    "вынув",
+    "вынудила",
+    "вынужденное",
+    "вынул",
+    "вынула",
+    "вынули",
+    "вынулось",
+    "вынуто",
+    "вынутым",
+    "вынуть",
+    "выпадает",
+    "выпадал",
+    "выпадала",
+    "выпадало",
+    "выпадении",
+    "выпадет",
+    "выпал",
+    "выпала",
+    "выпали",
+    "выпало",
+    "выпачкаешь",
+    "выпачкался",
+    "выпачкан",
+    "выпачканного",
+    "выпевала",
+    "выпевать",
+    "выпей",
+    "выпейте",
+    "выпивает",
+    "выпивается",
+    "выпивалось",
+    "выпивательными",
+    "выпивку",
+    "выпивши",
+    "выпил",
+    "выпила",
+    "выпили",
+    "выпиливает",
+    "выписали",
+    "выписались",
+    "выписать",
+    "выписки",
+    "выписывал",
+    "выписывать",
+    "выписываю",
+    "выпит",
+    "выпита",
+    "выпито",
+    "выпитого",
+    "выпитое",
+    "выпитою",
+    "выпитым",
+    "выпить",
+    "выплакав",
+    "выплакала",
+    "выплакаться",
+    "выплакивает",
+    "выплатил",
+    "выплатится",
+    "выплачу",
+    "выплеснет",
+    "выплыли",
+    "выполнит",
+    "выправился",
+    "выпрашивать",
+    "выпроводил",
+    "выпросила",
+    "выпросили",
+    "выпросит",
+    "выпросить",
+    "выпрошу",
+    "выпрыгнули",
+    "выпрыгнуть",
+    "выпрямилась",
+    "выпрямились",
+    "выпрямлялась",
+    "выпрямляя",
+    "выпрямляясь",
+    "выпукло",
+    "выпуклостию",
+    "выпуклою",
+    "выпуклую",
+    "выпуклые",
+    "выпускает",
+    "выпускать",
+    "выпуская",
+    "выпуске",
+    "выпустив",
+    "выпустил",
+    "выпустила",
+    "выпустили",
+    "выпустит",
+    "выпустить",
+    "выпустят",
+    "выпутаться",
+    "выпутывался",
+    "выпуча",
+    "выпучив",
+    "выпучил",
+    "выпущен",
+    "выпущенные",
+    "выпущены",
+    "выпущу",
+    "выпытает",
+    "выпытала",
+    "выпытываешь",
+    "выпытывала",
+    "выпытывая",
+    "выпьем",
+    "выпьет",
+    "выпьешь",
+    "выпью",
+    "выпьют",
+    "выработал",
+    "выработалась",
+    "выработался",
+    "выработанности",
+    "выработанные",
+    "выработать",
+    "выработаться",
+    "выработывается",
+    "выработывались",
+    "выработывалось",
+    "выражавшими",
+    "выражаете",
+    "выражаетесь",
+    "выражается",
+    "выражал",
+    "выражала",
+    "выражалась",
+    "выражали",
+    "выражало",
+    "выражалось",
+    "выражался",
+    "выражать",
+    "выражаться",
+    "выражая",
+    "выражение",
+    "выражением",
+    "выражений",
+    "выражению",
+    "выражения",
+    "выражениях",
+    "выражено",
+    "выраженье",
+    "выраженьем",
+    "выраженьице",
+    "выразил",
+    "выразила",
+    "выразилась",
+    "выразились",
+    "выразило",
+    "выразилось",
+    "выразился",
+    "выразительная",
+    "выразительно",
+    "выразительное",
+    "выразительные",
+    "выразится",
+    "выразить",
+    "выразиться",
+    "вырастало",
+    "вырастают",
+    "вырастет",
+    "вырасти",
+    "вырвавшаяся",
+    "вырвавшемся",
+    "вырвавшею",
+    "вырвавшихся",
+    "вырвал",
+    "вырвала",
+    "вырвалась",
+    "вырвали",
+    "вырвались",
+    "вырвалось",
+    "вырвался",
+    "вырванного",
+    "вырвать",
+    "вырваться",
+    "вырвется",
+    "вырву",
+    "вырезай",
+    "вырезана",
+    "вырезать",
+    "вырождение",
+    "вырождением",
+    "вырождения",
+    "выронил",
+    "выронила",
+    "вырос",
+    "выросла",
+    "выросли",
+    "выросло",
+    "выросшей",
+    "вырубить",
+    "выругает",
+    "выругать",
+    "выручает",
+    "выручена",
+    "вырученные",
+    "выручи",
+    "выручил",
+    "выручим",
+    "выручит",
+    "выручки",
+    "вырывавшиеся",
+    "вырывается",
+    "вырывалась",
+    "вырывали",
+    "вырывались",
+    "вырывалось",
+    "вырываются",
+    "вырывая",
+    "высасывал",
+    "высасывала",
+    "высвободив",
+    "высвободил",
+    "высвободился",
+    "высвобождаете",
+    "выселить",
+    "выселки",
+    "выселков",
+    "высечет",
+    "высидел",
+    "выскабливаешь",
+    "выскажет",
+    "выскажется",
+    "выскажу",
+    "выскажусь",
+    "высказала",
+    "высказалась",
+    "высказались",
+    "высказался",
+    "высказанных",
+    "высказать",
+    "высказаться",
+    "высказываете",
+    "высказывается",
+    "высказывала",
+    "высказывались",
+    "высказывало",
+    "высказывалось",
+    "высказывать",
+    "высказываться",
+    "высказываю",
+    "высказываясь",
+    "выскользало",
+    "выскользнули",
+    "выскочившего",
+    "выскочил",
+    "выскочила",
+    "выскочит",
+    "выскочить",
+    "выскочке",
+    "выскочки",
+    "выскребывались",
+    "выслали",
+    "выслать",
+    "выслежу",
+    "выслужил",
+    "выслужиться",
+    "выслушав",
+    "выслушает",
+    "выслушаете",
+    "выслушайте",
+    "выслушал",
+    "выслушала",
+    "выслушать",
+    "выслушивал",
+    "выслушивали",
+    "высматривал",
+    "высматривать",
+    "высматривая",
+    "высморкается",
+    "высморкался",
+    "высмотреть",
+    "высовывалась",
+    "высок",
+    "высокая",
+    "высокенькая",
+    "высокенькие",
+    "высоки",
+    "высокие",
+    "высокий",
+    "высоким",
+    "высокими",
+    "высоких",
+    "высоко",
+    "высокоблагородие",
+    "высокого",
+    "высокое",
+    "высокой",
+    "высоком",
+    "высокомерием",
+    "высокомерия",
+    "высокомерно",
+    "высокомерного",
+    "высокомерные",
+    "высокомерный",
+    "высокомерным",
+    "высокому",
+    "высокопарные",
+    "высокородие",
+    "высокоуважаемая",
+    "высокую",
+    "высоте",
+    "высоту",
+    "высоты",
+    "высох",
+    "высохла",
+    "высохли",
+    "высохло",
+    "высохшем",
+    "высохшею",
+    "высочайшей",
+    "высочайшим",
+    "выспался",
+    "выспится",
+    "высплюсь",
+    "выспросил",
+    "выставив",
+    "выставил",
+    "выставила",
+    "выставилась",
+    "выставили",
+    "выставит",
+    "выставлена",
+    "выставлены",
+    "выставлю",
+    "выставляется",
+    "выставлял",
+    "выставлять",
+    "выстоять",
+    "выстрадав",
+    "выстрадал",
+    "выстрел",
+    "выстрелив",
+    "выстрелила",
+    "выстрелить",
+    "выстрелишь",
+    "выстрелю",
+    "выстриженными",
+    "выстроен",
+    "выстроить",
+    "выступал",
+    "выступать",
+    "выступают",
+    "выступившей",
+    "выступившие",
+    "выступившими",
+    "выступил",
+    "выступила",
+    "выступили",
+    "выступить",
+    "выступление",
+    "высунув",
+    "высунулась",
+    "высунуть",
+    "высушила",
+    "высушит",
+    "высчитал",
+    "высшего",
+    "высшее",
+    "высшей",
+    "высшему",
+    "высший",
+    "высших",
+    "высшую",
+    "высылают",
+    "высыпает",
+    "высыпал",
+    "высыпали",
+    "высь",
+    "вытараща",
+    "вытаращены",
+    "вытаращив",
+    "вытаращил",
+    "вытаращила",
+    "вытаскивает",
+    "вытаскивала",
+    "вытаскивать",
+    "вытаскивая",
+    "вытащена",
+    "вытащил",
+    "вытащила",
+    "вытащим",
+    "вытащите",
+    "вытащить",
+    "вытащу",
+    "вытер",
+    "вытерла",
+    "вытерпел",
+    "вытерпела",
+    "вытерпели",
+    "вытерпеть",
+    "вытерпит",
+    "вытерпливающего",
+    "вытерплю",
+    "вытирает",
+    "вытирать",
+    "вытирая",
+    "вытолкай",
+    "выторговать",
+    "выточен",
+    "выточена",
+    "выточенными",
+    "выточилась",
+    "выть",
+    "вытягиваться",
+    "вытягивая",
+    "вытягушкин",
+    "вытянет",
+    "вытянув",
+    "вытянул",
+    "вытянулись",
+    "выудить",
+    "выученного",
+    "выучи",
+    "выучивал",
+    "выучил",
+    "выучила",
+    "выучилась",
+    "выучился",
+    "выучиться",
+    "выучку",
+    "выучу",
+    "выхватив",
+    "выхватил",
+    "выхватила",
+    "выхлопотанной",
+    "выхлопотать",
+    "выхлопочет",
+    "выход",
+    "выхода",
+    "выходе",
+    "выходи",
+    "выходившая",
+    "выходившем",
+    "выходившему",
+    "выходивших",
+    "выходил",
+    "выходила",
+    "выходили",
+    "выходило",
+    "выходим",
+    "выходит",
+    "выходите",
+    "выходить",
+    "выходишь",
+    "выходками",
+    "выходке",
+    "выходкой",
+    "выходку",
+    "выходной",
+    "выходов",
+    "выходом",
+    "выходу",
+    "выходя",
+    "выходят",
+    "выходящего",
+    "выходящее",
+    "выходящие",
+    "выхожу",
+    "выцвело",
+    "выцветшим",
+    "выцветших",
+    "вычеркнуть",
+    "вычертил",
+    "вычета",
+    "вычетом",
+    "вычинила",
+    "вычислять",
+    "вычистить",
+    "вычитал",
+    "вычитание",
+    "вычитанные",
+    "вычитают",
+    "вычитывая",
+    "вычищенную",
+    "вычищенный",
+    "вычищенных",
+    "вычищены",
+    "вышвырнул",
+    "выше",
+    "вышедшего",
+    "вышедши",
+    "вышедший",
+    "вышедшую",
+    "вышел",
+    "вышесказанными",
+    "вышеупомянутою",
+    "вышиб",
+    "вышивает",
+    "вышиваньем",
+    "вышивая",
+    "вышине",
+    "вышитыми",
+    "вышла",
+    "вышлем",
+    "вышли",
+    "вышло",
+    "вышлю",
+    "выщипала",
+    "выяснения",
+    "выяснилась",
+    "выяснилось",
+    "выясняться",
+    "вьется",
+    "вьюг",
+    "вьюга",
+    "вьюгам",
+    "вьюге",
+    "вьюги",
+    "вьюгой",
+    "вьюн",
+    "вьются",
+    "вьющимися",
+    "вьющихся",
+    "вяжет",
+    "вяжутся",
+    "вяз",
+    "вязанки",
+    "вязанная",
+    "вязать",
+    "вяземского",
+    "вязка",
+    "вязниковых",
+    "вязнут",
+    "вязь",
+    "вялая",
+    "вяло",
+    "вялого",
+    "вялой",
+    "вялою",
+    "вялый",
+    "вялым",
+    "вялыми",
+    "вянут",
+    "вячеславу",
+    "г",
+    "га",
+    "гавана",
+    "гавани",
+    "гаванской",
+    "гаврилович",
+    "гавриловича",
+    "гавриловичу",
+    "гаврилыч",
+    "гаврилыча",
+    "гаврилычу",
+    "гаги",
+    "гадай",
+    "гадал",
+    "гадала",
+    "гаданьи",
+    "гадать",
+    "гадают",
+    "гадая",
+    "гаденькая",
+    "гадки",
+    "гадкий",
+    "гадко",
+    "гадкое",
+    "гадкой",
+    "гадкую",
+    "гадов",
+    "гадок",
+    "гадостны",
+    "гадость",
+    "гадят",
+    "гаев",
+    "гаева",
+    "гаеве",
+    "гаеву",
+    "гаже",
+    "газели",
+    "газет",
+    "газета",
+    "газетам",
+    "газетах",
+    "газете",
+    "газетку",
+    "газетной",
+    "газетную",
+    "газетный",
+    "газетой",
+    "газету",
+    "газеты",
+    "газом",
+    "газы",
+    "гайдамаков",
+    "галатея",
+    "галдеж",
+    "галдели",
+    "галерее",
+    "галереей",
+    "галереи",
+    "галерею",
+    "галерея",
+    "галереях",
+    "галилея",
+    "галицийских",
+    "галка",
+    "галла",
+    "галлереи",
+    "галлы",
+    "галлюсинацию",
+    "галлюцинации",
+    "галстук",
+    "галстука",
+    "галстуке",
+    "галстучек",
+    "галчат",
+    "гам",
+    "гамбринусе",
+    "гамлет",
+    "гамлета",
+    "гамлетовского",
+    "гамом",
+    "гаму",
+    "ганц",
+    "гарантировали",
+    "гари",
+    "гармонией",
+    "гармонии",
+    "гармоника",
+    "гармонике",
+    "гармоники",
+    "гармоникой",
+    "гармонировавшие",
+    "гармонировал",
+    "гармонировало",
+    "гармонического",
+    "гармония",
+    "гарнитур",
+    "гарнитуром",
+    "гарпагон",
+    "гаруса",
+    "гарью",
+    "гаси",
+    "гасил",
+    "гасли",
+    "гасло",
+    "гаснет",
+    "гасну",
+    "гаснул",
+    "гаснут",
+    "гаснуть",
+    "гаснущая",
+    "гастроном",
+    "гастрономической",
+    "гастрономическом",
+    "гатей",
+    "гатчинских",
+    "гвалт",
+    "гвоздем",
+    "гвозди",
+    "гвоздиками",
+    "гвоздичку",
+    "гвоздочками",
+    "гвоздь",
+    "гвоздями",
+    "гг",
+    "где",
+    "ге",
+    "гедрюкт",
+    "гей",
+    "гелиотропом",
+    "гельд",
+    "геморрой",
+    "генерал",
+    "генерала",
+    "генералишка",
+    "генералишку",
+    "генералов",
+    "генералом",
+    "генералу",
+    "генералы",
+    "генеральские",
+    "генеральский",
+    "гениальной",
+    "гениальности",
+    "гениальные",
+    "гениальный",
+    "гениальным",
+    "гением",
+    "гении",
+    "гений",
+    "генриет",
+    "генуя",
+    "географической",
+    "географию",
+    "география",
+    "геологические",
+    "геометра",
+    "геометрия",
+    "георгий",
+    "герани",
+    "геранью",
+    "геранями",
+    "герасимович",
+    "герасимовича",
+    "герасимовичу",
+    "герасимыч",
+    "герасимычу",
+    "гербовая",
+    "гербовой",
+    "гербовыми",
+    "гердера",
+    "геркулан",
+    "геркулесовыми",
+    "германии",
+    "германию",
+    "германские",
+    "героев",
+    "героем",
+    "героинь",
+    "герой",
+    "геройски",
+    "геройства",
+    "герою",
+    "героя",
+    "герр",
+    "гертруды",
+    "герца",
+    "герцом",
+    "гершеля",
+    "гете",
+    "гиацинты",
+    "гибели",
+    "гибель",
+    "гибельно",
+    "гибельной",
+    "гибельный",
+    "гибелью",
+    "гибки",
+    "гибли",
+    "гибнет",
+    "гибнешь",
+    "гибну",
+    "гибнут",
+    "гибнуть",
+    "гибок",
+    "гигантской",
+    "гиганты",
+    "гимн",
+    "гимна",
+    "гимназии",
+    "гимназист",
+    "гимназистами",
+    "гимназическом",
+    "гимназию",
+    "гимнастика",
+    "гимнастикой",
+    "гимнастику",
+    "гимны",
+    "гиппиус",
+    "гирлянды",
+    "гиря",
+    "гитара",
+    "гитаре",
+    "гитарные",
+    "гитарой",
+    "гитару",
+    "глав",
+    "глава",
+    "главе",
+    "главная",
+    "главнейшая",
+    "главнейшего",
+    "главнейшие",
+    "главнейшим",
+    "главнейших",
+    "главного",
+    "главное",
+    "главной",
+    "главном",
+    "главною",
+    "главную",
+    "главные",
+    "главный",
+    "главным",
+    "главных",
+    "главой",
+    "главу",
+    "главы",
+    "глагол",
+    "глади",
+    "гладиатор",
+    "гладил",
+    "гладила",
+    "гладит",
+    "гладить",
+    "гладкие",
+    "гладкий",
+    "гладко",
+    "гладковыбритым",
+    "гладкой",
+    "гладкую",
+    "гладь",
+    "гладьте",
+    "глаженье",
+    "глаз",
+    "глаза",
+    "глазам",
+    "глазами",
+    "глазах",
+    "глазела",
+    "глазенками",
+    "глазеночками",
+    "глазеющих",
+    "глазея",
+    "глазищи",
+    "глазками",
+    "глазки",
+    "глазок",
+    "глазом",
+    "глазу",
+    "глас",
+    "гласила",
+    "гласное",
+    "гласности",
+    "гласом",
+    "гласят",
+    "глашатаи",
+    "глин",
+    "глиной",
+    "глину",
+    "глины",
+    "глиняной",
+    "глиняный",
+    "глиняными",
+    "глиссе",
+    "глобус",
+    "гложет",
+    "гложут",
+    "глотает",
+    "глотками",
+    "глотков",
+    "глотку",
+    "глотнул",
+    "глоток",
+    "глохнут",
+    "глубже",
+    "глуби",
+    "глубина",
+    "глубине",
+    "глубиной",
+    "глубину",
+    "глубины",
+    "глубок",
+    "глубока",
+    "глубокая",
+    "глубоки",
+    "глубокие",
+    "глубокий",
+    "глубоким",
+    "глубоких",
+    "глубоко",
+    "глубокого",
+    "глубокое",
+    "глубокой",
+    "глубоком",
+    "глубокомысленно",
+    "глубокомысленное",
+    "глубокую",
+    "глубочайшего",
+    "глубочайшее",
+    "глубочайшем",
+    "глубочайшие",
+    "глубь",
+    "глумитесь",
+    "глуп",
+    "глупа",
+    "глупая",
+    "глупее",
+    "глупей",
+    "глупейшего",
+    "глупейшею",
+    "глупейшие",
+    "глупейшими",
+    "глупенькие",
+    "глупенькое",
+    "глупо",
+    "глуповат",
+    "глуповатый",
+    "глупого",
+    "глупое",
+    "глупой",
+    "глупом",
+    "глупому",
+    "глупопоспешной",
+    "глупостей",
+    "глупости",
+    "глупостию",
+    "глупость",
+    "глупостью",
+    "глупостям",
+    "глупостях",
+    "глупою",
+    "глупую",
+    "глупца",
+    "глупы",
+    "глупые",
+    "глупый",
+    "глупым",
+    "глупыми",
+    "глупых",
+    "глух",
+    "глуха",
+    "глухари",
+    "глухая",
+    "глухи",
+    "глухие",
+    "глухим",
+    "глухими",
+    "глухих",
+    "глухо",
+    "глухого",
+    "глухое",
+    "глухой",
+    "глухом",
+    "глухонемая",
+    "глухота",
+    "глухую",
+    "глуше",
+    "глуши",
+    "глушит",
+    "глушь",
+    "глыба",
+    "глыбам",
+    "глядевшему",
+    "глядел",
+    "глядела",
+    "глядели",
+    "глядеть",
+    "гляди",
+    "глядись",
+    "глядит",
+    "глядите",
+    "глядится",
+    "глядишь",
+    "глядь",
+    "глядя",
+    "глядясь",
+    "глядят",
+    "глядящий",
+    "гляжу",
+    "гляжусь",
+    "глянет",
+    "глянец",
+    "глянув",
+    "глянул",
+    "глянула",
+    "глянцевитой",
+    "гм",
+    "гнал",
+    "гнала",
+    "гнать",
+    "гнев",
+    "гнева",
+    "гневается",
+    "гневайся",
+    "гневаться",
+    "гневно",
+    "гневной",
+    "гневность",
+    "гневный",
+    "гневными",
+    "гневом",
+    "гневу",
+    "гнедой",
+    "гнезд",
+    "гнезда",
+    "гнездится",
+    "гнездо",
+    "гнет",
+    "гнета",
+    "гнетет",
+    "гнетете",
+    "гнетом",
+    "гнешь",
+    "гниения",
+    "гниет",
+    "гниешь",
+    "гнилого",
+    "гнилое",
+    "гнилой",
+    "гнилом",
+    "гнилую",
+    "гнилыми",
+    "гниль",
+    "гнилью",
+    "гнусавой",
+    "гнусно",
+    "гнусное",
+    "гнусном",
+    "гнусному",
+    "гнусность",
+    "гнусные",
+    "гнусный",
+    "гнусным",
+    "гнусными",
+    "гнусных",
+    "го",
+    "говеть",
+    "говор",
+    "говоренного",
+    "говорено",
+    "говори",
+    "говорившего",
+    "говорившие",
+    "говоривших",
+    "говорившую",
+    "говорил",
+    "говорила",
+    "говорили",
+    "говорило",
+    "говорилось",
+    "говориль",
+    "говорим",
+    "говорит",
+    "говорите",
+    "говорится",
+    "говорить",
+    "говориться",
+    "говоришь",
+    "говорю",
+    "говоря",
+    "говорят",
+    "говорящ",
+    "говорящее",
+    "говорящей",
+    "говорящие",
+    "говорящий",
+    "говорящим",
+    "говорящих",
+    "говядина",
+    "говядиной",
+    "говядину",
+    "говядины",
+    "гоголевский",
+    "гоголя",
+    "год",
+    "года",
+    "годам",
+    "годами",
+    "годах",
+    "годен",
+    "годика",
+    "година",
+    "годину",
+    "годится",
+    "годичный",
+    "годишься",
+    "годной",
+    "годные",
+    "годов",
+    "годовой",
+    "годовщиной",
+    "годовым",
+    "годом",
+    "годочек",
+    "году",
+    "годы",
+    "годятся",
+    "голая",
+    "голгофу",
+    "голи",
+    "голиков",
+    "голландская",
+    "голландских",
+    "голландцем",
+    "голо",
+    "голов",
+    "голова",
+    "головами",
+    "головах",
+    "голове",
+    "головка",
+    "головке",
+    "головку",
+    "головлей",
+    "головного",
+    "головной",
+    "головном",
+    "головною",
+    "головную",
+    "головные",
+    "головных",
+    "головой",
+    "головокружений",
+    "головою",
+    "голову",
+    "головушка",
+    "головы",
+    "голод",
+    "голода",
+    "голоден",
+    "голодная",
+    "голодно",
+    "голодного",
+    "голодное",
+    "голодной",
+    "голодному",
+    "голодные",
+    "голодный",
+    "голодных",
+    "голоду",
+    "голой",
+    "голос",
+    "голоса",
+    "голосами",
+    "голосе",
+    "голосил",
+    "голосила",
+    "голосистый",
+    "голоском",
+    "голосов",
+    "голосок",
+    "голосом",
+    "голосу",
+    "голосят",
+    "голубая",
+    "голубиная",
+    "голубиную",
+    "голубка",
+    "голубоватый",
+    "голубоватым",
+    "голубое",
+    "голубой",
+    "голубом",
+    "голубоокий",
+    "голубою",
+    "голубушка",
+    "голубую",
+    "голубчик",
+    "голубые",
+    "голубым",
+    "голубыми",
+    "голубых",
+    "голубь",
+    "голубятни",
+    "голубятню",
+    "голую",
+    "голы",
+    "голые",
+    "голый",
+    "голым",
+    "голыми",
+    "голых",
+    "голь",
+    "гольштейн",
+    "гом",
+    "гомера",
+    "гомеридами",
+    "гомозится",
+    "гомозиться",
+    "гонга",
+    "гондол",
+    "гондоле",
+    "гонение",
+    "гонений",
+    "гони",
+    "гоним",
+    "гонима",
+    "гонимого",
+    "гонимый",
+    "гонимых",
+    "гонит",
+    "гоните",
+    "гонорарий",
+    "гончаров",
+    "гонявшийся",
+    "гоняется",
+    "гоняла",
+    "гонялся",
+    "гонят",
+    "гонять",
+    "гоп",
+    "гор",
+    "гора",
+    "горазд",
+    "гораздо",
+    "горам",
+    "горами",
+    "горах",
+    "горбатая",
+    "горбиться",
+    "горд",
+    "горда",
+    "гордая",
+    "горделиво",
+    "гордилась",
+    "гордились",
+    "гордился",
+    "гордится",
+    "гордиться",
+    "гордо",
+    "гордого",
+    "гордой",
+    "гордом",
+    "гордому",
+    "гордости",
+    "гордостию",
+    "гордость",
+    "гордостью",
+    "гордою",
+    "горды",
+    "гордые",
+    "гордый",
+    "гордым",
+    "гордыне",
+    "гордых",
+    "гордясь",
+    "гордятся",
+    "гордячка",
+    "горе",
+    "горевал",
+    "горевать",
+    "горевший",
+    "горевшим",
+    "горел",
+    "горела",
+    "горели",
+    "горелки",
+    "горело",
+    "горем",
+    "горемыка",
+    "горение",
+    "горением",
+    "горенки",
+    "горенье",
+    "горестно",
+    "горестное",
+    "горестной",
+    "горестью",
+    "горестях",
+    "гореть",
+    "горечи",
+    "горечь",
+    "горечью",
+    "горжусь",
+    "гори",
+    "горизонт",
+    "горизонта",
+    "горизонтально",
+    "горизонте",
+    "горизонтом",
+    "горизонты",
+    "гористую",
+    "горит",
+    "горке",
+    "горла",
+    "горланили",
+    "горланит",
+    "горле",
+    "горло",
+    "горловую",
+    "горлом",
+    "горлу",
+    "горлышко",
+    "горний",
+    "горнист",
+    "горниста",
+    "горницу",
+    "горницы",
+    "горничная",
+    "горничной",
+    "горничных",
+    "горном",
+    "горный",
+    "горным",
+    "горными",
+    "город",
+    "города",
+    "городам",
+    "городах",
+    "городе",
+    "городите",
+    "городишке",
+    "городишко",
+    "городишком",
+    "городке",
+    "городничего",
+    "городничий",
+    "городового",
+    "городовой",
+    "городовому",
+    "городовые",
+    "городок",
+    "городом",
+    "городская",
+    "городского",
+    "городское",
+    "городской",
+    "городском",
+    "городскую",
+    "городу",
+    "городят",
+    "горой",
+    "гороскоп",
+    "горох",
+    "горохе",
+    "гороховой",
+    "гороховую",
+    "гороховый",
+    "горохом",
+    "гороху",
+    "горошек",
+    "горошина",
+    "горошком",
+    "горсточку",
+    "горстью",
+    "гортани",
+    "гортанные",
+    "гору",
+    "горчица",
+    "горше",
+    "горшками",
+    "горшки",
+    "горшков",
+    "горшочки",
+    "горы",
+    "горькая",
+    "горькие",
+    "горький",
+    "горьким",
+    "горьких",
+    "горько",
+    "горькой",
+    "горьком",
+    "горькою",
+    "горькую",
+    "горю",
+    "горюй",
+    "горюнова",
+    "горюновы",
+    "горюновым",
+    "горючей",
+    "горючий",
+    "горючими",
+    "горя",
+    "горят",
+    "горяча",
+    "горячась",
+    "горячая",
+    "горячего",
+    "горячей",
+    "горячему",
+    "горячешных",
+    "горячею",
+    "горячившегося",
+    "горячие",
+    "горячий",
+    "горячил",
+    "горячились",
+    "горячился",
+    "горячим",
+    "горячими",
+    "горячись",
+    "горячится",
+    "горячих",
+    "горячишься",
+    "горячка",
+    "горячке",
+    "горячки",
+    "горячку",
+    "горячности",
+    "горячо",
+    "горячусь",
+    "горячую",
+    "горящее",
+    "горящей",
+    "горящий",
+    "горящим",
+    "горящими",
+    "госпитале",
+    "госпитальный",
+    "госпитальных",
+    "господ",
+    "господа",
+    "господам",
+    "господах",
+    "господи",
+    "господин",
+    "господина",
+    "господине",
+    "господином",
+    "господину",
+    "господним",
+    "господня",
+    "господняя",
+    "господом",
+    "господская",
+    "господский",
+    "господского",
+    "господской",
+    "господствовавшей",
+    "господствовала",
+    "господствующею",
+    "господствующие",
+    "господствующим",
+    "господу",
+    "господь",
+    "госпожа",
+    "госпожам",
+    "госпоже",
+    "госпожи",
+    "госпожинок",
+    "госпожой",
+    "госпожу",
+    "гостей",
+    "гостем",
+    "гостеприимном",
+    "гостеприимный",
+    "гости",
+    "гостивший",
+    "гостила",
+    "гостиная",
+    "гостинец",
+    "гостиница",
+    "гостинице",
+    "гостиницу",
+    "гостиницы",
+    "гостиного",
+    "гостиной",
+    "гостиную",
+    "гостинцу",
+    "гостинцы",
+    "гостиный",
+    "гостиных",
+    "гостит",
+    "гостить",
+    "гость",
+    "гостья",
+    "гостю",
+    "гостя",
+    "гостям",
+    "гостями",
+    "гостях",
+    "государственная",
+    "государственное",
+    "государственный",
+    "государственным",
+    "государственных",
+    "государыня",
+    "государь",
+    "государю",
+    "гот",
+    "готов",
+    "готова",
+    "готовая",
+    "готовившиеся",
+    "готовившийся",
+    "готовил",
+    "готовила",
+    "готовилась",
+    "готовили",
+    "готовились",
+    "готовился",
+    "готовит",
+    "готовитесь",
+    "готовится",
+    "готовить",
+    "готовиться",
+    "готовлюсь",
+    "готовность",
+    "готово",
+    "готового",
+    "готовое",
+    "готовой",
+    "готовом",
+    "готовую",
+    "готовы",
+    "готовые",
+    "готовый",
+    "готовыми",
+    "готовь",
+    "готовясь",
+    "готовят",
+    "готовятся",
+    "готовящегося",
+    "гофкригсрат",
+    "гр",
+    "грабеж",
+    "грабежа",
+    "грабежах",
+    "грабеже",
+    "грабежей",
+    "грабежом",
+    "грабил",
+    "грабит",
+    "грабителя",
+    "грабят",
+    "гравюр",
+    "гравюры",
+    "град",
+    "граденаплем",
+    "градирен",
+    "градуса",
+    "градусник",
+    "градусов",
+    "градусы",
+    "грады",
+    "гражданин",
+    "гражданина",
+    "гражданские",
+    "гражданский",
+    "гражданских",
+    "гражданской",
+    "гражданском",
+    "гражданскому",
+    "грамматике",
+    "грамматику",
+    "грамота",
+    "грамотеи",
+    "грамотность",
+    "грамотный",
+    "грамотных",
+    "грамоты",
+    "гранат",
+    "грандиозного",
+    "грандиозное",
+    "грандиозные",
+    "грани",
+    "гранит",
+    "гранитные",
+    "границ",
+    "граница",
+    "границей",
+    "границею",
+    "границу",
+    "границы",
+    "гранпасьянс",
+    "графа",
+    "графин",
+    "графина",
+    "графине",
+    "графини",
+    "графинина",
+    "графином",
+    "графинчик",
+    "графов",
+    "графский",
+    "графского",
+    "графской",
+    "графскому",
+    "графу",
+    "графы",
+    "грации",
+    "грациозно",
+    "грациозности",
+    "грациозную",
+    "грациозны",
+    "грациозный",
+    "грация",
+    "гребенки",
+    "гребенку",
+    "гребень",
+    "греет",
+    "грез",
+    "греза",
+    "грезами",
+    "грезах",
+    "грезил",
+    "грезилась",
+    "грезилось",
+    "грезит",
+    "грезится",
+    "грезить",
+    "грезишь",
+    "грезы",
+    "грезятся",
+    "гремел",
+    "гремела",
+    "гремело",
+    "гремит",
+    "гренадера",
+    "гренадеры",
+    "греть",
+    "грех",
+    "греха",
+    "грехам",
+    "грехе",
+    "грехи",
+    "грехов",
+    "греховной",
+    "греховные",
+    "грехом",
+    "гречневой",
+    "гречонка",
+    "грешен",
+    "греши",
+    "грешили",
+    "грешить",
+    "грешник",
+    "грешница",
+    "грешницу",
+    "грешно",
+    "грешной",
+    "грешную",
+    "грешные",
+    "грешный",
+    "грибами",
+    "грибов",
+    "грива",
+    "гривен",
+    "гривенник",
+    "гривенниками",
+    "гривенники",
+    "гривнами",
+    "гривне",
+    "гривну",
+    "гривны",
+    "григория",
+    "григорьевич",
+    "григорьевича",
+    "григорьевичем",
+    "гримасой",
+    "гримасу",
+    "гриф",
+    "гриша",
+    "гриши",
+    "гроб",
+    "гроба",
+    "гробам",
+    "гробах",
+    "гробе",
+    "гробниц",
+    "гробницей",
+    "гробов",
+    "гробовая",
+    "гробовой",
+    "гробовою",
+    "гробовые",
+    "гробом",
+    "гробу",
+    "гроз",
+    "гроза",
+    "грозе",
+    "грозил",
+    "грозила",
+    "грозило",
+    "грозим",
+    "грозись",
+    "грозит",
+    "грозится",
+    "грозишь",
+    "грозная",
+    "грозней",
+    "грозно",
+    "грозное",
+    "грозной",
+    "грозном",
+    "грозному",
+    "грозны",
+    "грозные",
+    "грозный",
+    "грозным",
+    "грозных",
+    "грозовая",
+    "грозовой",
+    "грозовые",
+    "грозовых",
+    "грозой",
+    "грозы",
+    "грозя",
+    "грозясь",
+    "грозят",
+    "грозящие",
+    "гром",
+    "грома",
+    "громад",
+    "громада",
+    "громаднейшего",
+    "громадного",
+    "громадное",
+    "громадной",
+    "громадном",
+    "громадную",
+    "громадные",
+    "громадный",
+    "громадным",
+    "громадных",
+    "громам",
+    "громами",
+    "громит",
+    "громкая",
+    "громкий",
+    "громким",
+    "громкими",
+    "громко",
+    "громкое",
+    "громком",
+    "громовая",
+    "громовой",
+    "громовые",
+    "громовым",
+    "громоздить",
+    "громоздкий",
+    "громоздко",
+    "громом",
+    "громче",
+    "громыханье",
+    "громя",
+    "грот",
+    "грохнулась",
+    "грохнулся",
+    "грохольского",
+    "грохот",
+    "грохотом",
+    "грош",
+    "гроша",
+    "грошей",
+    "грошик",
+    "грошовый",
+    "грошовых",
+    "гру",
+    "груб",
+    "груба",
+    "грубая",
+    "грубее",
+    "грубеешь",
+    "грубейшим",
+    "грубить",
+    "грубиян",
+    "грубиянит",
+    "грубо",
+    "грубоват",
+    "грубовато",
+    "грубого",
+    "грубое",
+    "грубой",
+    "грубом",
+    "грубости",
+    "грубостию",
+    "грубость",
+    "грубостью",
+    "грубою",
+    "грубые",
+    "грубый",
+    "грубым",
+    "грубыми",
+    "грубых",
+    "грудами",
+    "грудах",
+    "груде",
+    "груди",
+    "грудная",
+    "грудной",
+    "грудные",
+    "грудных",
+    "грудой",
+    "груду",
+    "груды",
+    "грудь",
+    "грудью",
+    "груз",
+    "груза",
+    "грузди",
+    "грунт",
+    "группа",
+    "группами",
+    "группы",
+    "грустен",
+    "грусти",
+    "грустим",
+    "грустит",
+    "грустишь",
+    "грустная",
+    "грустнее",
+    "грустно",
+    "грустного",
+    "грустное",
+    "грустной",
+    "грустном",
+    "грустною",
+    "грустную",
+    "грустные",
+    "грустный",
+    "грустным",
+    "грустных",
+    "грусть",
+    "грустью",
+    "грустя",
+    "грустят",
+    "груше",
+    "грушевой",
+    "груши",
+    "грущу",
+    "грызет",
+    "грызла",
+    "грызло",
+    "гряд",
+    "грядами",
+    "грядущего",
+    "грядущее",
+    "грядущей",
+    "грядущем",
+    "грядущие",
+    "грядущий",
+    "грядущим",
+    "грядущих",
+    "гряды",
+    "грязи",
+    "грязна",
+    "грязная",
+    "грязно",
+    "грязное",
+    "грязной",
+    "грязном",
+    "грязнотцой",
+    "грязною",
+    "грязную",
+    "грязны",
+    "грязные",
+    "грязный",
+    "грязными",
+    "грязных",
+    "грязня",
+    "грязь",
+    "грязью",
+    "грянет",
+    "грянул",
+    "грянулась",
+    "грянули",
+    "грянуло",
+    "губ",
+    "губа",
+    "губам",
+    "губами",
+    "губах",
+    "губернатор",
+    "губернатора",
+    "губернаторе",
+    "губернатором",
+    "губернаторстве",
+    "губернатору",
+    "губернии",
+    "губерний",
+    "губернию",
+    "губерния",
+    "губерниям",
+    "губернский",
+    "губернским",
+    "губернских",
+    "губернского",
+    "губернском",
+    "губил",
+    "губили",
+    "губите",
+    "губительнее",
+    "губительный",
+    "губительных",
+    "губить",
+    "губишь",
+    "губка",
+    "губки",
+    "губкой",
+    "губой",
+    "губок",
+    "губу",
+    "губы",
+    "губят",
+    "гувернантка",
+    "гувернантках",
+    "гувернантки",
+    "гувернанткой",
+    "гудел",
+    "гудело",
+    "гудки",
+    "гудут",
+    "гуды",
+    "гул",
+    "гуле",
+    "гулкий",
+    "гулом",
+    "гульбе",
+    "гуляет",
+    "гулякой",
+    "гулял",
+    "гуляла",
+    "гуляли",
+    "гулянье",
+    "гуляньями",
+    "гулять",
+    "гуляю",
+    "гуляют",
+    "гуляющей",
+    "гуляя",
+    "гумаге",
+    "гумагу",
+    "гуманистический",
+    "гуманитетом",
+    "гуманная",
+    "гуманно",
+    "гуманного",
+    "гуманном",
+    "гуманности",
+    "гуманные",
+    "гуманный",
+    "гуммиластик",
+    "гунял",
+    "гурьбой",
+    "гусак",
+    "гусаки",
+    "гусар",
+    "гусара",
+    "гусарские",
+    "гусарское",
+    "гусей",
+    "гуси",
+    "гусиных",
+    "густая",
+    "густейшими",
+    "густо",
+    "густой",
+    "густоту",
+    "густою",
+    "густые",
+    "густым",
+    "густыми",
+    "гусыни",
+    "гуся",
+    "гущей",
+    "гюго",
+    "д",
+    "да",
+    "дав",
+    "давай",
+    "давайте",
+    "давал",
+    "давала",
+    "давалася",
+    "давали",
+    "давался",
+    "давать",
+    "давая",
+    "даве",
+    "давеча",
+    "давешнего",
+    "давешнее",
+    "давешней",
+    "давешнему",
+    "давешний",
+    "давешних",
+    "давешнюю",
+    "давешняя",
+    "давившее",
+    "давившей",
+    "давила",
+    "давило",
+    "давит",
+    "давить",
+    "давка",
+    "давке",
+    "давлением",
+    "давней",
+    "давненько",
+    "давнишнее",
+    "давнишней",
+    "давнишние",
+    "давнишний",
+    "давнишних",
+    "давнишнюю",
+    "давно",
+    "давным",
+    "давняя",
+    "давя",
+    "давящим",
+    "дагестана",
+    "дадим",
+    "дадите",
+    "дадут",
+    "дает",
+    "даете",
+    "дается",
+    "даже",
+    "дай",
+    "дайте",
+    "дал",
+    "дала",
+    "далась",
+    "далее",
+    "далей",
+    "далек",
+    "далека",
+    "далекая",
+    "далекие",
+    "далекий",
+    "далеким",
+    "далеких",
+    "далеко",
+    "далекое",
+    "далекой",
+    "далеком",
+    "далекую",
+    "далече",
+    "дали",
+    "дались",
+    "дало",
+    "далось",
+    "дался",
+    "даль",
+    "дальнего",
+    "дальней",
+    "дальнейшего",
+    "дальнейшее",
+    "дальнейшей",
+    "дальнейшем",
+    "дальнейшему",
+    "дальнейшею",
+    "дальнейшие",
+    "дальнейших",
+    "дальнему",
+    "дальние",
+    "дальний",
+    "дальних",
+    "дальный",
+    "дальня",
+    "дальняя",
+    "дальше",
+    "далью",
+    "дам",
+    "дама",
+    "дамам",
+    "дамами",
+    "дамах",
+    "даме",
+    "дамой",
+    "дамою",
+    "дамские",
+    "дамскую",
+    "дамся",
+    "даму",
+    "дамы",
+    "дана",
+    "данного",
+    "данное",
+    "данному",
+    "данную",
+    "данным",
+    "дано",
+    "дант",
+    "данта",
+    "дантово",
+    "дань",
+    "дар",
+    "дарами",
+    "дарвина",
+    "дареную",
+    "дарил",
+    "дарить",
+    "дармоедка",
+    "дармоедом",
+    "даров",
+    "дарований",
+    "дарования",
+    "даровщинку",
+    "даром",
+    "дарь",
+    "дарье",
+    "дарьи",
+    "дарья",
+    "дарят",
+    "даст",
+    "дать",
+    "дач",
+    "дача",
+    "дачами",
+    "дачах",
+    "даче",
+    "дачей",
+    "дачи",
+    "дачник",
+    "дачники",
+    "дачников",
+    "дачном",
+    "дачные",
+    "дачу",
+    "дашенька",
+    "дашеньки",
+    "дашеньку",
+    "дашь",
+    "даю",
+    "дают",
+    "два",
+    "двадцати",
+    "двадцатилетней",
+    "двадцатирублевые",
+    "двадцатисемилетний",
+    "двадцатый",
+    "двадцать",
+    "двадцатью",
+    "дважды",
+    "две",
+    "двенадцати",
+    "двенадцатого",
+    "двенадцатом",
+    "двенадцатую",
+    "двенадцатый",
+    "двенадцать",
+    "двенадцатью",
+    "дверах",
+    "дверей",
+    "дверец",
+    "двери",
+    "дверными",
+    "дверь",
+    "дверьми",
+    "дверью",
+    "дверям",
+    "дверями",
+    "дверях",
+    "двести",
+    "двигавшимся",
+    "двигает",
+    "двигается",
+    "двигал",
+    "двигалась",
+    "двигали",
+    "двигались",
+    "двигалось",
+    "двигался",
+    "двигатель",
+    "двигателя",
+    "двигать",
+    "двигаться",
+    "двигают",
+    "двигая",
+    "двигаясь",
+    "движение",
+    "движением",
+    "движении",
+    "движений",
+    "движению",
+    "движения",
+    "движениях",
+    "движенье",
+    "движеньем",
+    "движенья",
+    "движеньях",
+    "движет",
+    "движется",
+    "движешься",
+    "движимости",
+    "движимый",
+    "движись",
+    "движутся",
+    "движущее",
+    "движущееся",
+    "движущиеся",
+    "движущихся",
+    "двинется",
+    "двинулась",
+    "двинули",
+    "двинулись",
+    "двинулся",
+    "двинутся",
+    "двинуть",
+    "двинуться",
+    "двое",
+    "двоеверьем",
+    "двои",
+    "двоим",
+    "двоих",
+    "двойку",
+    "двойник",
+    "двойной",
+    "двойные",
+    "двойным",
+    "двойных",
+    "двор",
+    "двора",
+    "дворам",
+    "дворах",
+    "дворе",
+    "дворец",
+    "дворик",
+    "дворне",
+    "дворней",
+    "дворни",
+    "дворник",
+    "дворника",
+    "дворнике",
+    "дворники",
+    "дворников",
+    "дворникова",
+    "дворником",
+    "дворнику",
+    "дворницкой",
+    "дворницкую",
+    "дворню",
+    "дворня",
+    "дворняжка",
+    "дворняжки",
+    "дворов",
+    "дворовый",
+    "двором",
+    "двору",
+    "дворца",
+    "дворце",
+    "дворцовой",
+    "дворцовым",
+    "дворцы",
+    "дворян",
+    "дворяне",
+    "дворянин",
+    "дворянина",
+    "дворянке",
+    "дворянки",
+    "дворянская",
+    "дворянские",
+    "дворянский",
+    "дворянскими",
+    "дворянского",
+    "дворянской",
+    "дворянском",
+    "двугривенного",
+    "двугривенные",
+    "двугривенный",
+    "двугривенных",
+    "двукратного",
+    "двуличного",
+    "двум",
+    "двумя",
+    "двусмысленного",
+    "двусмысленности",
+    "двусмысленную",
+    "двусмысленных",
+    "двух",
+    "двухкопеечника",
+    "двухмачтовый",
+    "двухминутной",
+    "двухнедельное",
+    "двухчасовое",
+    "двухэтажном",
+    "де",
+    "дебаркадера",
+    "дебатирован",
+    "дебош",
+    "дебютировала",
+    "дев",
+    "дева",
+    "деваешь",
+    "девал",
+    "девам",
+    "девать",
+    "деваться",
+    "деверь",
+    "девизами",
+    "девиц",
+    "девица",
+    "девицами",
+    "девицах",
+    "девице",
+    "девицей",
+    "девицу",
+    "девицы",
+    "девически",
+    "девические",
+    "девический",
+    "девического",
+    "девической",
+    "девичий",
+    "девичьего",
+    "девичьей",
+    "девичьем",
+    "девичью",
+    "девка",
+    "девкам",
+    "девке",
+    "девки",
+    "девкой",
+    "девой",
+    "девок",
+    "девочек",
+    "девочка",
+    "девочкам",
+    "девочками",
+    "девочке",
+    "девочки",
+    "девочкой",
+    "девочку",
+    "девственна",
+    "девственного",
+    "девственное",
+    "девственность",
+    "девушек",
+    "девушка",
+    "девушке",
+    "девушки",
+    "девушкой",
+    "девушку",
+    "девчонка",
+    "девчонки",
+    "девчонку",
+    "девчоночку",
+    "девчурочка",
+    "девы",
+    "девяносто",
+    "девяти",
+    "девятнадцатый",
+    "девятнадцать",
+    "девятого",
+    "девятом",
+    "девятый",
+    "девятым",
+    "девять",
+    "девятьсот",
+    "деготь",
+    "дегтем",
+    "дед",
+    "деда",
+    "дедах",
+    "дедов",
+    "дедом",
+    "деду",
+    "дедушка",
+    "дедушке",
+    "дедушки",
+    "деды",
+    "дезертировать",
+    "действенный",
+    "действие",
+    "действием",
+    "действий",
+    "действительная",
+    "действительнее",
+    "действительно",
+    "действительной",
+    "действительности",
+    "действительность",
+    "действительностью",
+    "действительные",
+    "действительный",
+    "действительным",
+    "действительных",
+    "действия",
+    "действиях",
+    "действйтельности",
+    "действовал",
+    "действовало",
+    "действовать",
+    "действует",
+    "действуют",
+    "действующие",
+    "действуя",
+    "декабре",
+    "декабрь",
+    "декабрьский",
+    "декабря",
+    "декадент",
+    "декадентах",
+    "декадентский",
+    "декадентское",
+    "декан",
+    "декламирует",
+    "декламируете",
+    "декламируя",
+    "декларируя",
+    "декорации",
+    "декораций",
+    "декорация",
+    "дел",
+    "дела",
+    "делаем",
+    "делает",
+    "делаете",
+    "делается",
+    "делаешь",
+    "делай",
+    "делайте",
+    "делал",
+    "делала",
+    "делалась",
+    "делали",
+    "делались",
+    "делалось",
+    "делаль",
+    "делам",
+    "делами",
+    "делась",
+    "делать",
+    "делах",
+    "делаю",
+    "делаюсь",
+    "делают",
+    "делающим",
+    "делая",
+    "делаясь",
+    "деле",
+    "деления",
+    "делец",
+    "деликатен",
+    "деликатная",
+    "деликатнейшим",
+    "деликатно",
+    "деликатности",
+    "деликатный",
+    "деликатным",
+    "деликатными",
+    "делила",
+    "делился",
+    "делись",
+    "делит",
+    "делишкам",
+    "делишки",
+    "дело",
+    "деловая",
+    "деловитости",
+    "деловитость",
+    "делового",
+    "деловой",
+    "деловую",
+    "деловые",
+    "деловым",
+    "деловых",
+    "делом",
+    "делось",
+    "делу",
+    "делывал",
+    "дельное",
+    "дельному",
+    "дельный",
+    "дельцам",
+    "дельце",
+    "дельцу",
+    "дементьева",
+    "демисезон",
+    "демка",
+    "демон",
+    "демона",
+    "демонизм",
+    "демонов",
+    "демонологии",
+    "демоном",
+    "демонским",
+    "демонстрации",
+    "денег",
+    "денежного",
+    "денежной",
+    "денежные",
+    "денежный",
+    "денек",
+    "денем",
+    "дение",
+    "денницу",
+    "денно",
+    "дену",
+    "денщик",
+    "денщиков",
+    "денщику",
+    "день",
+    "деньгам",
+    "деньгами",
+    "деньгах",
+    "деньги",
+    "деньжонки",
+    "денька",
+    "деньские",
+    "деньской",
+    "департамент",
+    "департамента",
+    "департаментах",
+    "департаменте",
+    "депутата",
+    "депутатами",
+    "депутатов",
+    "депутаты",
+    "дер",
+    "дергает",
+    "дергайте",
+    "дергала",
+    "дергали",
+    "дергать",
+    "дергая",
+    "дерев",
+    "дерева",
+    "дереве",
+    "деревенели",
+    "деревенская",
+    "деревенские",
+    "деревенский",
+    "деревенскими",
+    "деревенских",
+    "деревенского",
+    "деревенское",
+    "деревенской",
+    "деревень",
+    "деревеньках",
+    "деревне",
+    "деревней",
+    "деревни",
+    "деревню",
+    "деревня",
+    "деревням",
+    "дерево",
+    "деревом",
+    "дереву",
+    "деревушку",
+    "деревцо",
+    "деревьев",
+    "деревья",
+    "деревьям",
+    "деревьями",
+    "деревьях",
+    "деревянная",
+    "деревянное",
+    "деревянной",
+    "деревянною",
+    "деревянную",
+    "деревянные",
+    "деревянный",
+    "деревянным",
+    "деревянными",
+    "деревянных",
+    "дерем",
+    "дерет",
+    "держа",
+    "державный",
+    "держал",
+    "держала",
+    "держалась",
+    "держали",
+    "держалось",
+    "держась",
+    "держат",
+    "держать",
+    "держаться",
+    "держи",
+    "держим",
+    "держись",
+    "держит",
+    "держите",
+    "держится",
+    "держишь",
+    "держу",
+    "дерзкие",
+    "дерзкий",
+    "дерзким",
+    "дерзких",
+    "дерзко",
+    "дерзкого",
+    "дерзкой",
+    "дерзкую",
+    "дерзок",
+    "дерзостей",
+    "дерзости",
+    "дерзостно",
+    "дерзостные",
+    "дерзость",
+    "дерзостью",
+    "дери",
+    "дериганов",
+    "дернет",
+    "дернул",
+    "дернула",
+    "дернули",
+    "дернуло",
+    "дернуть",
+    "дерутся",
+    "десертом",
+    "дескать",
+    "десницей",
+    "деспот",
+    "деспотизм",
+    "деспотически",
+    "деспотическому",
+    "десяти",
+    "десятилетнею",
+    "десятилетнюю",
+    "десятин",
+    "десятине",
+    "десятину",
+    "десятирублевую",
+    "десятирублевый",
+    "десятка",
+    "десятки",
+    "десятков",
+    "десятого",
+    "десятое",
+    "десятом",
+    "десятую",
+    "десятый",
+    "десять",
+    "детали",
+    "детей",
+    "дети",
+    "детками",
+    "детки",
+    "деток",
+    "деточек",
+    "деточка",
+    "деточки",
+    "детская",
+    "детски",
+    "детские",
+    "детский",
+    "детским",
+    "детскими",
+    "детских",
+    "детского",
+    "детское",
+    "детской",
+    "детском",
+    "детскому",
+    "детскою",
+    "детскую",
+    "детства",
+    "детстве",
+    "детство",
+    "деть",
+    "детьми",
+    "деться",
+    "детям",
+    "детях",
+    "дешевле",
+    "дешево",
+    "дешевую",
+    "дешевый",
+    "деятелен",
+    "деятели",
+    "деятельная",
+    "деятельно",
+    "деятельности",
+    "деятельность",
+    "деятельностью",
+    "деятельною",
+    "деятельные",
+    "деятельным",
+    "джентльмен",
+    "джентльмена",
+    "джентльменничанье",
+    "джентльменски",
+    "джентльмены",
+    "ди",
+    "диалектики",
+    "диапазона",
+    "диван",
+    "дивана",
+    "диванам",
+    "диване",
+    "диванов",
+    "диваном",
+    "дивану",
+    "диванчик",
+    "диваны",
+    "дивизионно",
+    "дивились",
+    "дивился",
+    "дивись",
+    "дивитесь",
+    "дивиться",
+    "дивлюсь",
+    "дивная",
+    "дивно",
+    "дивное",
+    "дивную",
+    "дивные",
+    "дивный",
+    "дивным",
+    "диво",
+    "диву",
+    "дивясь",
+    "дикарь",
+    "дикая",
+    "дикие",
+    "дикий",
+    "диким",
+    "дико",
+    "диковина",
+    "дикого",
+    "дикое",
+    "дикой",
+    "диком",
+    "дикости",
+    "дикость",
+    "дикою",
+    "диктовала",
+    "диктовать",
+    "диктовку",
+    "диктует",
+    "дикую",
+    "дилетантизма",
+    "дилижанс",
+    "динамит",
+    "диораме",
+    "диплом",
+    "дипломат",
+    "дипломатическим",
+    "дипломаты",
+    "директор",
+    "директора",
+    "директрисы",
+    "дирижера",
+    "диск",
+    "диссертацией",
+    "диссонанс",
+    "дитюся",
+    "дитя",
+    "дифтерит",
+    "дичей",
+    "дичи",
+    "дичившийся",
+    "дичь",
+    "диэта",
+    "дланью",
+    "длилась",
+    "длились",
+    "длилось",
+    "длился",
+    "длинная",
+    "длиннее",
+    "длиннейшим",
+    "длинно",
+    "длинноволосый",
+    "длинного",
+    "длинное",
+    "длинной",
+    "длинному",
+    "длинною",
+    "длинную",
+    "длинны",
+    "длинные",
+    "длинный",
+    "длинным",
+    "длинными",
+    "длинных",
+    "длиной",
+    "длину",
+    "длится",
+    "для",
+    "длятся",
+    "дмитриев",
+    "дмитрием",
+    "дмитрий",
+    "дмитрия",
+    "дна",
+    "дне",
+    "дневная",
+    "дневник",
+    "дневника",
+    "дневного",
+    "дневное",
+    "дневной",
+    "дневном",
+    "дневному",
+    "дневных",
+    "дней",
+    "днем",
+    "дни",
+    "днища",
+    "дно",
+    "дном",
+    "дню",
+    "дня",
+    "дням",
+    "днями",
+    "днях",
+    "до",
+    "добавил",
+    "добавила",
+    "добежав",
+    "доберусь",
+    "добиваешься",
+    "добивай",
+    "добивались",
+    "добивался",
+    "добиваться",
+    "добиваются",
+    "добилась",
+    "добился",
+    "добирается",
+    "добирались",
+    "добирался",
+    "добираясь",
+    "добиться",
+    "доблести",
+    "доблестно",
+    "доблестях",
+    "добр",
+    "добра",
+    "добрался",
+    "добраться",
+    "добрая",
+    "добре",
+    "добрее",
+    "добрей",
+    "добрейший",
+    "добрели",
+    "добренький",
+    "добреющий",
+    "добро",
+    "добровольно",
+    "добровольную",
+    "доброго",
+    "добродетели",
+    "добродетель",
+    "добродетельна",
+    "добродетельная",
+    "добродетельно",
+    "добродетельного",
+    "добродетельной",
+    "добродетелям",
+    "добродетелями",
+    "добродушием",
+    "добродушнейшим",
+    "добродушно",
+    "добродушного",
+    "добродушное",
+    "добродушным",
+    "доброе",
+    "доброй",
+    "добролюбов",
+    "добром",
+    "доброму",
+    "добросовестная",
+    "добросовестный",
+    "доброте",
+    "добротой",
+    "доброту",
+    "доброты",
+    "доброю",
+    "добру",
+    "добрую",
+    "добры",
+    "добрые",
+    "добрый",
+    "добрым",
+    "добрыми",
+    "добрыни",
+    "добрынин",
+    "добрых",
+    "добряк",
+    "добудет",
+    "добываемых",
+    "добываете",
+    "добывал",
+    "добывали",
+    "добывание",
+    "добыванья",
+    "добывать",
+    "добываю",
+    "добывают",
+    "добывающим",
+    "добывая",
+    "добыл",
+    "добытые",
+    "добыть",
+    "добычу",
+    "добьется",
+    "добьюсь",
+    "добьют",
+    "довариваете",
+    "доварит",
+    "доведенные",
+    "доведенных",
+    "доведены",
+    "доведете",
+    "доведите",
+    "доведу",
+    "довез",
+    "довезем",
+    "довезу",
+    "довел",
+    "довела",
+    "довели",
+    "доверенности",
+    "доверенность",
+    "доверенностью",
+    "доверии",
+    "доверилась",
+    "доверителю",
+    "довериться",
+    "доверия",
+    "доверчив",
+    "доверчива",
+    "доверчиво",
+    "доверчивого",
+    "доверчивой",
+    "доверчивости",
+    "доверчивость",
+    "доверчивостью",
+    "доверчивый",
+    "довершению",
+    "доверши",
+    "довершили",
+    "доверьтесь",
+    "доверявшего",
+    "доверял",
+    "доверялась",
+    "доверялся",
+    "доверясь",
+    "доверяю",
+    "доверяя",
+    "довести",
+    "доводами",
+    "доводил",
+    "доводит",
+    "доводить",
+    "доводишь",
+    "доводы",
+    "доволен",
+    "довольна",
+    "довольно",
+    "довольны",
+    "довольный",
+    "довольным",
+    "довольных",
+    "довольства",
+    "довольство",
+    "довольствовался",
+    "довольством",
+    "довоспиталась",
+    "доврались",
+    "довремся",
+    "догадавшись",
+    "догадается",
+    "догадалась",
+    "догадался",
+    "догадаться",
+    "догадаются",
+    "догадка",
+    "догадками",
+    "догадках",
+    "догадки",
+    "догадок",
+    "догадывалась",
+    "догадывался",
+    "догадываюсь",
+    "догадываясь",
+    "доглядит",
+    "догматы",
+    "догнав",
+    "догнал",
+    "догнала",
+    "договариваешь",
+    "договаривала",
+    "договаривали",
+    "договаривать",
+    "договаривая",
+    "договорил",
+    "договорила",
+    "договорилась",
+    "договорились",
+    "договорился",
+    "договорить",
+    "догонишь",
+    "догонял",
+    "догонять",
+    "догоняют",
+    "догоравшего",
+    "догоравший",
+    "догорает",
+    "догорал",
+    "догорала",
+    "догоревшей",
+    "догорел",
+    "догорим",
+    "доделал",
+    "додумалась",
+    "додумался",
+    "додумывался",
+    "доедаем",
+    "доедали",
+    "доедешь",
+    "доеду",
+    "доел",
+    "доели",
+    "доехали",
+    "доехать",
+    "дожа",
+    "дождавшись",
+    "дождалась",
+    "дождался",
+    "дождаться",
+    "дождевом",
+    "дождевою",
+    "дождевые",
+    "дождей",
+    "дождем",
+    "дождемся",
+    "дождется",
+    "дождешься",
+    "дожди",
+    "дождик",
+    "дождичек",
+    "дождливую",
+    "дождливых",
+    "дождь",
+    "дождю",
+    "дождя",
+    "дождями",
+    "дожей",
+    "дожив",
+    "доживает",
+    "доживал",
+    "доживать",
+    "доживающая",
+    "доживем",
+    "дожидается",
+    "дожидался",
+    "дожидаться",
+    "дожидаюсь",
+    "дожидаются",
+    "дожидаясь",
+    "дожил",
+    "дожила",
+    "дожить",
+    "дозваться",
+    "дозволение",
+    "дозволили",
+    "дозвольте",
+    "дозволю",
+    "дозволяется",
+    "дозвонишься",
+    "доискался",
+    "доискивался",
+    "доискиваться",
+    "доить",
+    "доищусь",
+    "дойдем",
+    "дойдет",
+    "дойдешь",
+    "дойду",
+    "дойдут",
+    "дойдя",
+    "доймут",
+    "дойти",
+    "дока",
+    "докажет",
+    "докажете",
+    "докажу",
+    "доказав",
+    "доказал",
+    "доказала",
+    "доказали",
+    "доказана",
+    "доказано",
+    "доказательств",
+    "доказательства",
+    "доказательство",
+    "доказательством",
+    "доказать",
+    "доказывает",
+    "доказываете",
+    "доказывается",
+    "доказывал",
+    "доказывали",
+    "доказывалось",
+    "доказывать",
+    "докам",
+    "доканчивает",
+    "доканчивай",
+    "доклад",
+    "доклада",
+    "докладах",
+    "докладную",
+    "докладом",
+    "докладу",
+    "докладывает",
+    "докладывал",
+    "докладывать",
+    "докликался",
+    "доколе",
+    "доконал",
+    "доконало",
+    "докончив",
+    "докончил",
+    "докончить",
+    "доктор",
+    "доктора",
+    "доктором",
+    "докторской",
+    "доктору",
+    "доктрин",
+    "документ",
+    "документа",
+    "документам",
+    "документы",
+    "докучливого",
+    "докучливых",
+    "докучной",
+    "докучным",
+    "дол",
+    "долг",
+    "долга",
+    "долгая",
+    "долге",
+    "долги",
+    "долгие",
+    "долгий",
+    "долгим",
+    "долгих",
+    "долго",
+    "долгов",
+    "долговой",
+    "долговом",
+    "долговременной",
+    "долговременную",
+    "долговязо",
+    "долгого",
+    "долгогривых",
+    "долгое",
+    "долгожданный",
+    "долгой",
+    "долголетний",
+    "долгом",
+    "долгу",
+    "доле",
+    "долее",
+    "долетал",
+    "долетают",
+    "долетели",
+    "должен",
+    "должна",
+    "должником",
+    "должно",
+    "должностей",
+    "должности",
+    "должность",
+    "должны",
+    "должным",
+    "должок",
+    "доли",
+    "долин",
+    "долинам",
+    "долинах",
+    "долине",
+    "долинный",
+    "долины",
+    "долог",
+    "доложил",
+    "доложили",
+    "доложился",
+    "доложить",
+    "долой",
+    "долу",
+    "долы",
+    "дольнего",
+    "дольше",
+    "долю",
+    "доля",
+    "дом",
+    "дома",
+    "домам",
+    "домами",
+    "домах",
+    "домашнего",
+    "домашнее",
+    "домашней",
+    "домашнем",
+    "домашнему",
+    "домашние",
+    "домашний",
+    "домашним",
+    "домашними",
+    "домашних",
+    "домашнюю",
+    "домашняя",
+    "доме",
+    "домелю",
+    "домик",
+    "домика",
+    "домики",
+    "домов",
+    "домовитой",
+    "домового",
+    "домовой",
+    "домовому",
+    "домовый",
+    "домой",
+    "домом",
+    "домоседа",
+    "домоседы",
+    "домохозяйка",
+    "дому",
+    "домчавшись",
+    "домчался",
+    "домчимся",
+    "домы",
+    "дон",
+    "донельзя",
+    "донес",
+    "донесем",
+    "донесение",
+    "донесению",
+    "донесет",
+    "донеси",
+    "донесла",
+    "донесли",
+    "донести",
+    "донесу",
+    "донжуаны",
+    "донизу",
+    "донна",
+    "доннерветтеров",
+    "доном",
+    "донос",
+    "доноса",
+    "доносилась",
+    "доносились",
+    "доносился",
+    "доносится",
+    "доносить",
+    "доносу",
+    "доносы",
+    "доносятся",
+    "доношу",
+    "донского",
+    "донцем",
+    "доныне",
+    "допечет",
+    "допивая",
+    "допилась",
+    "дописать",
+    "дописывает",
+    "дописывать",
+    "допитый",
+    "доплыл",
+    "доподлинно",
+    "дополнения",
+    "дополняет",
+    "дополняла",
+    "допотопных",
+    "допрашиваемого",
+    "допрашивает",
+    "допрашивайся",
+    "допрашивал",
+    "допрашивала",
+    "допрашивались",
+    "допрашивать",
+    "допрашивающих",
+    "допрос",
+    "допроса",
+    "допросах",
+    "допросика",
+    "допросим",
+    "допросить",
+    "допроситься",
+    "допросишься",
+    "допросов",
+    "допросчика",
+    "допросы",
+    "допрошу",
+    "допускает",
+    "допускаете",
+    "допускается",
+    "допускай",
+    "допускал",
+    "допускали",
+    "допускалось",
+    "допускать",
+    "допускаю",
+    "допустил",
+    "допустила",
+    "допустили",
+    "допустим",
+    "допустит",
+    "допустить",
+    "допущу",
+    "допытывались",
+    "допьяна",
+    "допятилась",
+    "дорисовывать",
+    "дорн",
+    "дорна",
+    "дорном",
+    "дорну",
+    "дорог",
+    "дорога",
+    "дорогам",
+    "дорогами",
+    "дорогах",
+    "дорогая",
+    "дороге",
+    "дороги",
+    "дорогие",
+    "дорогим",
+    "дорогих",
+    "дорого",
+    "дороговизне",
+    "дорогое",
+    "дорогой",
+    "дорогом",
+    "дорогою",
+    "дорогу",
+    "дорогую",
+    "дородный",
+    "дорожа",
+    "дорожат",
+    "дороже",
+    "дорожил",
+    "дорожили",
+    "дорожит",
+    "дорожите",
+    "дорожка",
+    "дорожке",
+    "дорожки",
+    "дорожкой",
+    "дорожку",
+    "дорожная",
+    "дорожное",
+    "дорожной",
+    "дорожном",
+    "дорожному",
+    "дорожные",
+    "дорожный",
+    "дорожу",
+    "досада",
+    "досаде",
+    "досадливо",
+    "досадливого",
+    "досадно",
+    "досадный",
+    "досадовал",
+    "досадой",
+    "досаду",
+    "досадую",
+    "досадуя",
+    "досады",
+    "досаждают",
+    "досаждающее",
+    "доселе",
+    "доселева",
+    "доска",
+    "досказал",
+    "досказала",
+    "досказано",
+    "досказать",
+    "доскам",
+    "доскачет",
+    "доске",
+    "доски",
+    "досконально",
+    "доскою",
+    "доску",
+    "доследить",
+    "дослушал",
+    "дослушала",
+    "дослушали",
+    "дослушивает",
+    "дослушивать",
+    "досмеивался",
+    "досок",
+    "доспех",
+    "доставай",
+    "доставал",
+    "доставала",
+    "доставало",
+    "доставалось",
+    "доставался",
+    "доставать",
+    "доставаться",
+    "доставил",
+    "доставили",
+    "доставит",
+    "доставить",
+    "доставляет",
+    "доставлял",
+    "доставляло",
+    "доставшейся",
+    "доставшихся",
+    "доставшуюся",
+    "достает",
+    "достается",
+    "достал",
+    "достала",
+    "достали",
+    "достались",
+    "достало",
+    "досталось",
+    "достался",
+    "достанет",
+    "достанется",
+    "достанешь",
+    "достану",
+    "достанут",
+    "достанутся",
+    "достань",
+    "достатком",
+    "достаточная",
+    "достаточно",
+    "достаточном",
+    "достаточными",
+    "достать",
+    "достают",
+    "достаются",
+    "достающеюся",
+    "достиг",
+    "достигаешь",
+    "достигал",
+    "достигали",
+    "достигало",
+    "достигают",
+    "достигли",
+    "достигло",
+    "достигнет",
+    "достигнешь",
+    "достигну",
+    "достигнув",
+    "достигнутого",
+    "достигнуть",
+    "достижении",
+    "достичь",
+    "достодолжного",
+    "достоевский",
+    "достоин",
+    "достоинств",
+    "достоинства",
+    "достоинствами",
+    "достоинстве",
+    "достоинство",
+    "достоинством",
+    "достоинству",
+    "достойная",
+    "достойней",
+    "достойнейшей",
+    "достойно",
+    "достойное",
+    "достойной",
+    "достойный",
+    "достойным",
+    "достойных",
+    "достояньем",
+    "достроил",
+    "достроился",
+    "дострочу",
+    "доступ",
+    "доступна",
+    "доступной",
+    "доступном",
+    "доступны",
+    "доступных",
+    "достучался",
+    "досуги",
+    "досчитал",
+    "дотащился",
+    "дотащиться",
+    "дотла",
+    "дотоле",
+    "дотолкалась",
+    "дотрогиваясь",
+    "дотронется",
+    "дотронулся",
+    "дотягивает",
+    "дотянет",
+    "дотянешь",
+    "дотянул",
+    "дотянуть",
+    "доучился",
+    "дохленьких",
+    "дохнет",
+    "дохнул",
+    "дохнула",
+    "дохнут",
+    "дохнуть",
+    "доход",
+    "дохода",
+    "доходил",
+    "доходила",
+    "доходили",
+    "доходило",
+    "доходит",
+    "доходов",
+    "доходу",
+    "доходцу",
+    "доходы",
+    "доходя",
+    "доходят",
+    "доцента",
+    "дочел",
+    "дочерей",
+    "дочери",
+    "дочерью",
+    "дочерях",
+    "дочесть",
+    "дочиста",
+    "дочитав",
+    "дочитала",
+    "дочитать",
+    "дочитывал",
+    "дочитывали",
+    "дочитывать",
+    "дочка",
+    "дочке",
+    "дочки",
+    "дочку",
+    "дочла",
+    "дочли",
+    "дочь",
+    "дошедшее",
+    "дошедшие",
+    "дошел",
+    "дошила",
+    "дошла",
+    "дошли",
+    "дошло",
+    "дощечка",
+    "дощечке",
+    "дощечки",
+    "дощечку",
+    "драгоценнейшие",
+    "драгоценнейший",
+    "драгоценно",
+    "драгоценное",
+    "драгоценность",
+    "драгоценные",
+    "драгоценный",
+    "драгоценным",
+    "драгоценных",
+    "драгунские",
+    "драдедамовой",
+    "драдедамовом",
+    "драдедамовый",
+    "дразни",
+    "дразнил",
+    "дразнила",
+    "дразнили",
+    "дразнит",
+    "дразните",
+    "дразнить",
+    "дразнишь",
+    "дразня",
+    "дразнят",
+    "драка",
+    "драке",
+    "драки",
+    "дракон",
+    "драку",
+    "драл",
+    "драли",
+    "дрались",
+    "дрался",
+    "драма",
+    "драмы",
+    "дранье",
+    "драньем",
+    "драпированы",
+    "драпировка",
+    "драпируется",
+    "драпируюсь",
+    "драпри",
+    "драть",
+    "драться",
+    "дребедень",
+    "дребезжащему",
+    "дребезжащим",
+    "древ",
+    "древен",
+    "древесных",
+    "древко",
+    "древнее",
+    "древней",
+    "древнейших",
+    "древнем",
+    "древни",
+    "древние",
+    "древний",
+    "древних",
+    "древности",
+    "дремал",
+    "дремать",
+    "дремлет",
+    "дремлется",
+    "дремлешь",
+    "дремлю",
+    "дремлют",
+    "дремлющей",
+    "дремлющим",
+    "дремлющими",
+    "дремлющую",
+    "дремные",
+    "дремота",
+    "дремоте",
+    "дремотные",
+    "дремотой",
+    "дремоту",
+    "дремоты",
+    "дрему",
+    "дремучий",
+    "дремучих",
+    "дроби",
+    "дробил",
+    "дробились",
+    "дробный",
+    "дров",
+    "дрова",
+    "дровами",
+    "дрогнет",
+    "дрогнувшие",
+    "дрогнувших",
+    "дрогнул",
+    "дрогнула",
+    "дрогнули",
+    "дрогнуло",
+    "дрожа",
+    "дрожавшее",
+    "дрожавшей",
+    "дрожавшею",
+    "дрожавшие",
+    "дрожавших",
+    "дрожавшую",
+    "дрожал",
+    "дрожала",
+    "дрожали",
+    "дрожало",
+    "дрожаньем",
+    "дрожат",
+    "дрожать",
+    "дрожащая",
+    "дрожащей",
+    "дрожащею",
+    "дрожащим",
+    "дрожащими",
+    "дрожащую",
+    "дрожек",
+    "дрожи",
+    "дрожит",
+    "дрожите",
+    "дрожишь",
+    "дрожки",
+    "дрожу",
+    "дрожь",
+    "дрожью",
+    "друг",
+    "друга",
+    "другая",
+    "друге",
+    "другие",
+    "другим",
+    "другими",
+    "других",
+    "другого",
+    "другое",
+    "другой",
+    "другом",
+    "другому",
+    "другою",
+    "другу",
+    "другую",
+    "дружб",
+    "дружба",
+    "дружбе",
+    "дружбой",
+    "дружбу",
+    "дружбы",
+    "дружелюбно",
+    "дружелюбной",
+    "дружески",
+    "дружеский",
+    "дружеским",
+    "дружескими",
+    "дружеских",
+    "дружеского",
+    "дружеской",
+    "дружескому",
+    "дружескую",
+    "дружества",
+    "дружественные",
+    "дружественным",
+    "дружил",
+    "дружился",
+    "дружить",
+    "дружище",
+    "дружке",
+    "дружкой",
+    "дружна",
+    "дружно",
+    "дружной",
+    "дружном",
+    "дружную",
+    "дружочек",
+    "друзей",
+    "друзья",
+    "друзьям",
+    "друзьями",
+    "дрыхнет",
+    "дрыхнешь",
+    "дрыхни",
+    "дрыхнут",
+    "дрыхнуть",
+    "дряблое",
+    "дряблый",
+    "дряблым",
+    "дряблыми",
+    "дрязг",
+    "дряни",
+    "дрянная",
+    "дряннейшего",
+    "дрянно",
+    "дрянного",
+    "дрянное",
+    "дрянной",
+    "дрянном",
+    "дрянные",
+    "дрянным",
+    "дрянных",
+    "дрянцо",
+    "дрянь",
+    "дрянью",
+    "дряхлом",
+    "дряхлую",
+    "дряхлый",
+    "ду",
+    "дуб",
+    "дубе",
+    "дубняк",
+    "дубняком",
+    "дубовые",
+    "дубы",
+    "дубье",
+    "дуга",
+    "дуги",
+    "дугой",
+    "дугообразны",
+    "дует",
+    "дуется",
+    "дузе",
+    "дуклиду",
+    "дум",
+    "дума",
+    "думаем",
+    "думает",
+    "думаете",
+    "думаешь",
+    "думай",
+    "думайте",
+    "думал",
+    "думала",
+    "думали",
+    "думалось",
+    "думам",
+    "думать",
+    "думах",
+    "думаю",
+    "думают",
+    "думающих",
+    "думая",
+    "думу",
+    "думы",
+    "дуне",
+    "дуней",
+    "дунечка",
+    "дунечке",
+    "дунечки",
+    "дунечкино",
+    "дунечкинова",
+    "дунечкиной",
+    "дунечкины",
+    "дунечкиных",
+    "дунечкой",
+    "дунечку",
+    "дуни",
+    "дунул",
+    "дунуло",
+    "дуню",
+    "дуня",
+    "дуняша",
+    "дуняше",
+    "дуняшей",
+    "дуняши",
+    "дуняшу",
+    "дупелей",
+    "дуплет",
+    "дуплетом",
+    "дур",
+    "дура",
+    "дурак",
+    "дурака",
+    "дураками",
+    "дураки",
+    "дураков",
+    "дураком",
+    "дураку",
+    "дурацкая",
+    "дурацки",
+    "дурацкие",
+    "дурачила",
+    "дурачится",
+    "дурачить",
+    "дурачке",
+    "дурачки",
+    "дурачок",
+    "дуре",
+    "дурен",
+    "дурман",
+    "дурмана",
+    "дурмане",
+    "дурна",
+    "дурная",
+    "дурно",
+    "дурного",
+    "дурное",
+    "дурной",
+    "дурною",
+    "дурнушка",
+    "дурную",
+    "дурные",
+    "дурочке",
+    "дуррак",
+    "дуру",
+    "дурында",
+    "дурь",
+    "дуся",
+    "дуться",
+    "дух",
+    "духа",
+    "духами",
+    "духах",
+    "духе",
+    "духи",
+    "духов",
+    "духовник",
+    "духовно",
+    "духовному",
+    "духовным",
+    "духом",
+    "духота",
+    "духоте",
+    "духоту",
+    "духоты",
+    "духу",
+    "дучяша",
+    "душ",
+    "душа",
+    "душами",
+    "душат",
+    "душе",
+    "душевная",
+    "душевного",
+    "душевное",
+    "душевной",
+    "душевном",
+    "душевные",
+    "душевный",
+    "душевным",
+    "душевных",
+    "душегубец",
+    "душегубства",
+    "душегубцы",
+    "душен",
+    "душенька",
+    "душеньку",
+    "душечка",
+    "души",
+    "душил",
+    "душистая",
+    "душистою",
+    "душистым",
+    "душистыми",
+    "душит",
+    "душите",
+    "душить",
+    "душкин",
+    "душкина",
+    "душкину",
+    "душна",
+    "душно",
+    "душное",
+    "душный",
+    "душным",
+    "душных",
+    "душой",
+    "душонка",
+    "душою",
+    "душу",
+    "дуэли",
+    "дуэль",
+    "дуют",
+    "дщерь",
+    "дым",
+    "дыма",
+    "дыме",
+    "дымилась",
+    "дымился",
+    "дымится",
+    "дымка",
+    "дымно",
+    "дымной",
+    "дымные",
+    "дымный",
+    "дымных",
+    "дымом",
+    "дыму",
+    "дымят",
+    "дымящимся",
+    "дыр",
+    "дыра",
+    "дырах",
+    "дыре",
+    "дырке",
+    "дырочки",
+    "дыру",
+    "дыры",
+    "дырявая",
+    "дырявые",
+    "дырявый",
+    "дырявым",
+    "дыхание",
+    "дыхания",
+    "дыханье",
+    "дыша",
+    "дышал",
+    "дышала",
+    "дышали",
+    "дышало",
+    "дышат",
+    "дышать",
+    "дыши",
+    "дышит",
+    "дышится",
+    "дышишь",
+    "дышлом",
+    "дышу",
+    "дьявол",
+    "дьявола",
+    "дьяволе",
+    "дьяволом",
+    "дьяволу",
+    "дьявольская",
+    "дьявольский",
+    "дьякон",
+    "дьячка",
+    "дьячок",
+    "дюжего",
+    "дюжина",
+    "дюжинная",
+    "дюжину",
+    "дюжины",
+    "дюссотов",
+    "дяде",
+    "дядей",
+    "дядек",
+    "дядечка",
+    "дяди",
+    "дядин",
+    "дядьки",
+    "дядьшка",
+    "дядьшки",
+    "дядю",
+    "дядя",
+    "е",
+    "евангелие",
+    "евангелии",
+    "евангелия",
+    "евг",
+    "евгений",
+    "евгению",
+    "евнух",
+    "еврей",
+    "еврейский",
+    "еврейского",
+    "евреями",
+    "европе",
+    "европейские",
+    "европою",
+    "европу",
+    "евстигней",
+    "евстигнея",
+    "евших",
+    "египет",
+    "египетские",
+    "египетскими",
+    "египетского",
+    "египетскую",
+    "египта",
+    "египте",
+    "его",
+    "егоровна",
+    "егору",
+    "еда",
+    "едва",
+    "еде",
+    "едем",
+    "едемте",
+    "едет",
+    "едете",
+    "едешь",
+    "единичное",
+    "единичных",
+    "единоверцев",
+    "единовременного",
+    "единогласно",
+    "единогласных",
+    "единого",
+    "единой",
+    "единому",
+    "единородна",
+    "единородную",
+    "единства",
+    "единственная",
+    "единственно",
+    "единственное",
+    "единственной",
+    "единственному",
+    "единственною",
+    "единственную",
+    "единственные",
+    "единственный",
+    "единственным",
+    "единый",
+    "единым",
+    "едите",
+    "едко",
+    "едкого",
+    "едкой",
+    "едкою",
+    "еду",
+    "едут",
+    "едучи",
+    "едущая",
+    "едят",
+    "ее",
+    "ежегодно",
+    "ежедневная",
+    "ежедневно",
+    "ежедневного",
+    "ежедневное",
+    "ежедневной",
+    "ежедневном",
+    "ежедневный",
+    "ежедневным",
+    "ежедневных",
+    "ежели",
+    "ежемесячного",
+    "ежеминутно",
+    "ежеминутным",
+    "ежеминутных",
+    "еженедельная",
+    "еженочной",
+    "ежечасные",
+    "езди",
+    "ездивший",
+    "ездил",
+    "ездила",
+    "ездили",
+    "ездит",
+    "ездите",
+    "ездить",
+    "ездиют",
+    "ездой",
+    "езды",
+    "ездят",
+    "езжал",
+    "езжали",
+    "езжу",
+    "ей",
+    "екатерингоф",
+    "екатерингофе",
+    "екатеринина",
+    "екатерининского",
+    "ектеньи",
+    "ел",
+    "ела",
+    "елагин",
+    "еле",
+    "елей",
+    "елена",
+    "елене",
+    "елену",
+    "елены",
+    "елец",
+    "ели",
+    "елисаветграде",
+    "елисейские",
+    "елка",
+    "елку",
+    "еловая",
+    "еловую",
+    "ель",
+    "ельце",
+    "ем",
+    "емеле",
+    "ему",
+    "енотовых",
+    "епиходов",
+    "епиходова",
+    "епиходову",
+    "епоходов",
+    "ерани",
+    "ерань",
+    "еранью",
+    "ермак",
+    "ермака",
+    "ермолай",
+    "ермолках",
+    "ермолке",
+    "ерошил",
+    "ерунду",
+    "еруслан",
+    "ерша",
+    "ершей",
+    "ершика",
+    "если",
+    "есмь",
+    "ест",
+    "естественная",
+    "естественно",
+    "естественного",
+    "естественное",
+    "естественной",
+    "естественном",
+    "естественному",
+    "естественнонаучные",
+    "естественности",
+    "естественною",
+    "естественную",
+    "естественные",
+    "естественный",
+    "естественным",
+    "естественных",
+    "есть",
+    "есь",
+    "ет",
+    "ефим",
+    "ефимьюшка",
+    "ефтова",
+    "ефтом",
+    "ефту",
+    "ехав",
+    "ехавшему",
+    "ехал",
+    "ехала",
+    "ехали",
+    "ехать",
+    "ешь",
+    "ешьте",
+    "еще",
+    "ею",
+    "ж",
+    "жаба",
+    "жаворонка",
+    "жаворонков",
+    "жадная",
+    "жадно",
+    "жадности",
+    "жадностию",
+    "жадностью",
+    "жадные",
+    "жадный",
+    "жадным",
+    "жадными",
+    "жадных",
+    "жажда",
+    "жаждал",
+    "жаждало",
+    "жаждет",
+    "жаждой",
+    "жажду",
+    "жаждущего",
+    "жаждущей",
+    "жаждущим",
+    "жаждущую",
+    "жажды",
+    "жакетку",
+    "жал",
+    "жала",
+    "жалась",
+    "жалей",
+    "жалела",
+    "жалели",
+    "жалеть",
+    "жалею",
+    "жалеют",
+    "жалея",
+    "жали",
+    "жались",
+    "жалиться",
+    "жалка",
+    "жалкая",
+    "жалкие",
+    "жалкий",
+    "жалким",
+    "жалкими",
+    "жалких",
+    "жалко",
+    "жалкого",
+    "жалкое",
+    "жалкой",
+    "жалком",
+    "жало",
+    "жалоб",
+    "жалобились",
+    "жалобная",
+    "жалобно",
+    "жалобный",
+    "жалобным",
+    "жалобы",
+    "жаловалась",
+    "жаловались",
+    "жаловался",
+    "жалование",
+    "жалованье",
+    "жалованья",
+    "жаловаться",
+    "жалок",
+    "жалости",
+    "жалость",
+    "жалостью",
+    "жался",
+    "жалуемся",
+    "жалуетесь",
+    "жалуется",
+    "жалуйся",
+    "жалуюсь",
+    "жаль",
+    "жан",
+    "жандарма",
+    "жандармы",
+    "жанлис",
+    "жар",
+    "жара",
+    "жаргон",
+    "жаре",
+    "жареная",
+    "жареной",
+    "жарила",
+    "жарится",
+    "жарища",
+    "жаркая",
+    "жаркие",
+    "жаркий",
+    "жарким",
+    "жарких",
+    "жарко",
+    "жаркого",
+    "жаркое",
+    "жаркой",
+    "жаркую",
+    "жарок",
+    "жаром",
+    "жару",
+    "жарче",
+    "жги",
+    "жгла",
+    "жгли",
+    "жгло",
+    "жгут",
+    "жгучая",
+    "жгуче",
+    "жгучего",
+    "жгучее",
+    "жгучие",
+    "жгучий",
+    "жгучим",
+    "жгучих",
+    "жгучую",
+    "ждал",
+    "ждала",
+    "ждали",
+    "ждало",
+    "ждать",
+    "ждем",
+    "ждет",
+    "ждете",
+    "ждешь",
+    "жди",
+    "ждите",
+    "жду",
+    "ждут",
+    "ждущие",
+    "ждущую",
+    "жды",
+    "же",
+    "жевал",
+    "жеваное",
+    "жевать",
+    "жег",
+    "жезл",
+    "желаем",
+    "желаемого",
+    "желаемое",
+    "желает",
+    "желаете",
+    "желаешь",
+    "желай",
+    "желал",
+    "желала",
+    "желали",
+    "желаль",
+    "желание",
+    "желанием",
+    "желании",
+    "желаний",
+    "желанию",
+    "желания",
+    "желаниям",
+    "желанная",
+    "желанное",
+    "желанным",
+    "желанье",
+    "желанья",
+    "желательно",
+    "желать",
+    "желаю",
+    "желающего",
+    "желая",
+    "железа",
+    "железная",
+    "железней",
+    "железнодорожных",
+    "железной",
+    "железном",
+    "железною",
+    "железную",
+    "железные",
+    "железный",
+    "железным",
+    "железными",
+    "железо",
+    "железом",
+    "желоб",
+    "желобом",
+    "желт",
+    "желтенький",
+    "желтенькими",
+    "желтеют",
+    "желтизной",
+    "желтизны",
+    "желтоватая",
+    "желтовато",
+    "желтоватой",
+    "желтоватые",
+    "желтого",
+    "желтое",
+    "желтой",
+    "желтом",
+    "желтому",
+    "желтою",
+    "желтые",
+    "желтый",
+    "желтым",
+    "желтыми",
+    "желтых",
+    "желудком",
+    "желудок",
+    "желудочка",
+    "желчен",
+    "желчи",
+    "желчная",
+    "желчно",
+    "желчное",
+    "желчный",
+    "желчь",
+    "жеманства",
+    "жемчуг",
+    "жемчуга",
+    "жемчугами",
+    "жемчуге",
+    "жемчугом",
+    "жемчугу",
+    "жемчужин",
+    "жемчужина",
+    "жемчужной",
+    "жемчужный",
+    "жен",
+    "жена",
+    "женам",
+    "женами",
+    "женат",
+    "женаты",
+    "жене",
+    "женив",
+    "женился",
+    "женись",
+    "женитесь",
+    "женится",
+    "женить",
+    "женитьба",
+    "женитьбе",
+    "женитьбу",
+    "женитьбы",
+    "жениться",
+    "жених",
+    "жениха",
+    "женихами",
+    "женихи",
+    "женихов",
+    "жениховское",
+    "женихом",
+    "жениху",
+    "женишка",
+    "женишок",
+    "женишь",
+    "женишься",
+    "женой",
+    "женская",
+    "женски",
+    "женские",
+    "женский",
+    "женских",
+    "женского",
+    "женское",
+    "женской",
+    "женскому",
+    "женскою",
+    "женскую",
+    "женственной",
+    "жену",
+    "женщин",
+    "женщина",
+    "женщинам",
+    "женщинами",
+    "женщинах",
+    "женщине",
+    "женщиной",
+    "женщиною",
+    "женщину",
+    "женщины",
+    "жены",
+    "женюсь",
+    "женят",
+    "женятся",
+    "жерди",
+    "жердями",
+    "жернова",
+    "жертв",
+    "жертва",
+    "жертвами",
+    "жертвах",
+    "жертвовать",
+    "жертвой",
+    "жертвочка",
+    "жертву",
+    "жертвует",
+    "жертвую",
+    "жертвы",
+    "жест",
+    "жеста",
+    "жестами",
+    "жесте",
+    "жести",
+    "жестки",
+    "жесткие",
+    "жесткий",
+    "жестким",
+    "жесткими",
+    "жестко",
+    "жесткой",
+    "жесткости",
+    "жесткость",
+    "жесткую",
+    "жесток",
+    "жестокая",
+    "жестокие",
+    "жестокий",
+    "жестоких",
+    "жестоко",
+    "жестокой",
+    "жестоком",
+    "жестокости",
+    "жестокость",
+    "жестом",
+    "жесту",
+    "жесты",
+    "жестяной",
+    "жестяном",
+    "жетон",
+    "жечь",
+    "жженкой",
+    "жжет",
+    "жив",
+    "жива",
+    "живая",
+    "живее",
+    "живей",
+    "живем",
+    "живет",
+    "живете",
+    "живется",
+    "живешь",
+    "живи",
+    "живите",
+    "живишь",
+    "живности",
+    "живо",
+    "живого",
+    "живое",
+    "живой",
+    "живом",
+    "живописец",
+    "живописи",
+    "живописно",
+    "живописной",
+    "живописных",
+    "живописцу",
+    "живопись",
+    "живописью",
+    "живости",
+    "живость",
+    "живостью",
+    "животного",
+    "животное",
+    "животной",
+    "животный",
+    "животными",
+    "животных",
+    "животрепещущей",
+    "животрепещущею",
+    "живу",
+    "живут",
+    "живуча",
+    "живучи",
+    "живущего",
+    "живущей",
+    "живущий",
+    "живущими",
+    "живущих",
+    "живущую",
+    "живую",
+    "жившим",
+    "живших",
+    "живы",
+    "живые",
+    "живым",
+    "живыми",
+    "живых",
+    "живьем",
+    "живя",
+    "жид",
+    "жиденькие",
+    "жиденький",
+    "жиденькой",
+    "жидким",
+    "жиду",
+    "жиды",
+    "жизней",
+    "жизненная",
+    "жизненного",
+    "жизненное",
+    "жизненной",
+    "жизненную",
+    "жизненные",
+    "жизненный",
+    "жизненных",
+    "жизни",
+    "жизнию",
+    "жизнь",
+    "жизнью",
+    "жил",
+    "жила",
+    "жилам",
+    "жилах",
+    "жилет",
+    "жилета",
+    "жилете",
+    "жилетка",
+    "жилетке",
+    "жилетку",
+    "жилетный",
+    "жилету",
+    "жилец",
+    "жили",
+    "жилица",
+    "жилицу",
+    "жилища",
+    "жилище",
+    "жилками",
+    "жилку",
+    "жило",
+    "жилы",
+    "жилье",
+    "жильца",
+    "жильцам",
+    "жильцах",
+    "жильцов",
+    "жильцом",
+    "жильцу",
+    "жильцы",
+    "жилья",
+    "жир",
+    "жирна",
+    "жирно",
+    "жирного",
+    "жирное",
+    "жирной",
+    "жирные",
+    "жирный",
+    "жирных",
+    "жирок",
+    "жиром",
+    "жиру",
+    "житейские",
+    "житейских",
+    "житейского",
+    "житейское",
+    "житейской",
+    "жителей",
+    "жители",
+    "жительства",
+    "жительстве",
+    "жить",
+    "житье",
+    "житья",
+    "жмет",
+    "жмется",
+    "жмешь",
+    "жмут",
+    "жнивью",
+    "жнитво",
+    "жнут",
+    "жорж",
+    "жоржа",
+    "жребий",
+    "жребия",
+    "жрецы",
+    "жрешь",
+    "жуан",
+    "жуаном",
+    "жувеневских",
+    "жужжала",
+    "жужжаньем",
+    "жужжат",
+    "жужжащий",
+    "жужжит",
+    "жужжите",
+    "жук",
+    "жуков",
+    "жуковский",
+    "журавли",
+    "журавлиным",
+    "журавля",
+    "журил",
+    "журнал",
+    "журнала",
+    "журнале",
+    "журналистом",
+    "журналов",
+    "журналы",
+    "журнальной",
+    "журнальце",
+    "журчанье",
+    "журчаньем",
+    "журчит",
+    "жутко",
+    "жуткость",
+    "жучка",
+    "жующие",
+    "з",
+    "за",
+    "заалели",
+    "заалелся",
+    "заарестовать",
+    "забавах",
+    "забавляет",
+    "забавляло",
+    "забавляюсь",
+    "забавник",
+    "забавника",
+    "забавный",
+    "забаву",
+    "забалканский",
+    "забастовки",
+    "забвение",
+    "забвению",
+    "забвенье",
+    "забвенья",
+    "забегает",
+    "забегаешь",
+    "забегал",
+    "забегала",
+    "забегать",
+    "забегаю",
+    "забегают",
+    "забегая",
+    "забегу",
+    "забедневшее",
+    "забежали",
+    "забежать",
+    "забежит",
+    "забелеет",
+    "забелелась",
+    "заберется",
+    "заберите",
+    "заберут",
+    "забилась",
+    "забился",
+    "забирает",
+    "забирается",
+    "забирали",
+    "забирался",
+    "забираясь",
+    "забит",
+    "забита",
+    "забитым",
+    "забитых",
+    "забить",
+    "забияки",
+    "заблаговременно",
+    "заблагорассудила",
+    "заблагорассудилось",
+    "заблагорассудит",
+    "заблестят",
+    "заблистала",
+    "заблистали",
+    "заблудился",
+    "заблудитесь",
+    "заблуждение",
+    "заблуждении",
+    "заболевала",
+    "заболеваю",
+    "заболеете",
+    "заболел",
+    "заболела",
+    "заболею",
+    "заболтавшегося",
+    "заболтался",
+    "забор",
+    "забора",
+    "заборами",
+    "заборе",
+    "заборов",
+    "забором",
+    "заборы",
+    "забот",
+    "забота",
+    "заботам",
+    "заботами",
+    "заботах",
+    "заботе",
+    "заботились",
+    "заботилось",
+    "заботился",
+    "заботимся",
+    "заботит",
+    "заботится",
+    "заботиться",
+    "заботишься",
+    "заботливая",
+    "заботливо",
+    "заботливое",
+    "заботливой",
+    "заботливости",
+    "заботливостию",
+    "заботливость",
+    "заботливостью",
+    "заботливую",
+    "заботливы",
+    "заботливый",
+    "заботливым",
+    "заботливыми",
+    "заботой",
+    "заботою",
+    "заботу",
+    "заботы",
+    "заботясь",
+    "забочусь",
+    "забрал",
+    "забрала",
+    "забрали",
+    "забрались",
+    "забрасывал",
+    "забрать",
+    "забрежжит",
+    "забрезжилось",
+    "забрезжит",
+    "забрел",
+    "забродит",
+    "забросил",
+    "забросить",
+    "заброшенная",
+    "заброшенной",
+    "забубенного",
+    "забудем",
+    "забудемся",
+    "забудет",
+    "забудется",
+    "забудешь",
+    "забуду",
+    "забудут",
+    "забудь",
+    "забудьте",
+    "забулдыга",
+    "забуянила",
+    "забыв",
+    "забывает",
+    "забываете",
+    "забывай",
+    "забывайте",
+    "забывал",
+    "забывала",
+    "забывалась",
+    "забывали",
+    "забывался",
+    "забывать",
+    "забываться",
+    "забываю",
+    "забывают",
+    "забывая",
+    "забывчив",
+    "забывчивости",
+    "забывшему",
+    "забывшие",
+    "забывший",
+    "забывшись",
+    "забыл",
+    "забыла",
+    "забылась",
+    "забыли",
+    "забылись",
+    "забылось",
+    "забылся",
+    "забыт",
+    "забыто",
+    "забытого",
+    "забытое",
+    "забытой",
+    "забытом",
+    "забытую",
+    "забыты",
+    "забытые",
+    "забытый",
+    "забытым",
+    "забытых",
+    "забыть",
+    "забытье",
+    "забытьи",
+    "забыться",
+    "забытья",
+    "забьется",
+    "забьются",
+    "завадских",
+    "завален",
+    "завалена",
+    "завалившись",
+    "завалилось",
+    "завалился",
+    "завалинке",
+    "завалиться",
+    "завалявшиеся",
+    "завалялось",
+    "заваривать",
+    "заведен",
+    "заведение",
+    "заведении",
+    "заведений",
+    "заведения",
+    "заведениями",
+    "заведениях",
+    "заведет",
+    "заведется",
+    "заведи",
+    "заведите",
+    "заведовал",
+    "заведовают",
+    "заведу",
+    "заведутся",
+    "заведую",
+    "завезти",
+    "завезу",
+    "завел",
+    "завелась",
+    "завелись",
+    "завелся",
+    "завернул",
+    "завернулась",
+    "завернута",
+    "завернутою",
+    "завернуты",
+    "завернуть",
+    "завернуться",
+    "завертелись",
+    "завертелось",
+    "завертываться",
+    "завертываясь",
+    "завершила",
+    "завершители",
+    "завеса",
+    "завесила",
+    "завести",
+    "завесу",
+    "завет",
+    "завета",
+    "заветного",
+    "заветное",
+    "заветной",
+    "заветный",
+    "заветных",
+    "заветов",
+    "заветом",
+    "заветы",
+    "завещал",
+    "завещала",
+    "завещание",
+    "завещании",
+    "завещанию",
+    "завещания",
+    "завещанного",
+    "завещанных",
+    "завивала",
+    "завивать",
+    "завидели",
+    "завидит",
+    "завидно",
+    "завидовать",
+    "завидуешь",
+    "завидую",
+    "завидя",
+    "завизжала",
+    "завираюсь",
+    "зависело",
+    "зависит",
+    "зависти",
+    "завистлив",
+    "завистливо",
+    "завистливого",
+    "завистливы",
+    "зависть",
+    "завистью",
+    "зависящим",
+    "зависящими",
+    "завитой",
+    "завитые",
+    "завитых",
+    "завладев",
+    "завлек",
+    "завлекал",
+    "завлекала",
+    "завлекли",
+    "завлечен",
+    "завод",
+    "завода",
+    "заводах",
+    "заводе",
+    "заводил",
+    "заводила",
+    "заводит",
+    "заводится",
+    "заводить",
+    "заводские",
+    "заводы",
+    "завоет",
+    "завожу",
+    "завозилась",
+    "заволакивало",
+    "заволновалась",
+    "заволокла",
+    "заволокло",
+    "завопил",
+    "завопила",
+    "завопит",
+    "завороженный",
+    "заворотила",
+    "заворчал",
+    "заврался",
+    "завсегда",
+    "завтра",
+    "завтрак",
+    "завтрака",
+    "завтракает",
+    "завтракал",
+    "завтракать",
+    "завтракают",
+    "завтраком",
+    "завтраму",
+    "завтрашнем",
+    "завтрашнему",
+    "завтрашний",
+    "завтрему",
+    "завывавший",
+    "завывал",
+    "завываниями",
+    "завыванье",
+    "завяжется",
+    "завяжу",
+    "завяз",
+    "завязалась",
+    "завязались",
+    "завязалось",
+    "завязан",
+    "завязанный",
+    "завязанными",
+    "завязнет",
+    "загадал",
+    "загадала",
+    "загадать",
+    "загадка",
+    "загадками",
+    "загадки",
+    "загадкой",
+    "загадку",
+    "загадок",
+    "загадочно",
+    "загадочное",
+    "загадочной",
+    "загадочному",
+    "загадочные",
+    "загадочный",
+    "загадочным",
+    "загадывает",
+    "загадываний",
+    "загар",
+    "загвоздка",
+    "заглавие",
+    "заглавием",
+    "заглавия",
+    "заглавном",
+    "загладилось",
+    "загладится",
+    "загладить",
+    "заглохло",
+    "заглушает",
+    "заглушал",
+    "заглушала",
+    "заглушат",
+    "заглушенный",
+    "заглушенных",
+    "заглушило",
+    "заглушит",
+    "заглушить",
+    "заглушон",
+    "заглядевшись",
+    "загляделась",
+    "загляделся",
+    "заглядывавшей",
+    "заглядывает",
+    "заглядывал",
+    "заглядывала",
+    "заглядывать",
+    "заглядывая",
+    "заглянет",
+    "заглянете",
+    "загляну",
+    "заглянув",
+    "заглянул",
+    "заглянула",
+    "заглянуть",
+    "загнан",
+    "загнанная",
+    "загниют",
+    "загнутым",
+    "заговаривай",
+    "заговаривал",
+    "заговаривала",
+    "заговаривался",
+    "заговаривать",
+    "заговариваю",
+    "заговаривая",
+    "заговелись",
+    "заговенья",
+    "заговор",
+    "заговорил",
+    "заговорила",
+    "заговорили",
+    "заговорило",
+    "заговорит",
+    "заговорить",
+    "заговорщик",
+    "заговорщиков",
+    "заговорю",
+    "заговорят",
+    "заголосил",
+    "загорается",
+    "загораживаемый",
+    "загораживают",
+    "загораживая",
+    "загорались",
+    "загоралось",
+    "загораются",
+    "загордится",
+    "загоревшаяся",
+    "загоревшейся",
+    "загоревшеюся",
+    "загоревшимися",
+    "загорелась",
+    "загорели",
+    "загорелись",
+    "загорелой",
+    "загорелось",
+    "загорелую",
+    "загорелые",
+    "загорись",
+    "загородившем",
+    "загородил",
+    "загородили",
+    "загородных",
+    "загорожена",
+    "загороженные",
+    "загорячился",
+    "заготовил",
+    "заготовлю",
+    "заградил",
+    "заградить",
+    "заграничная",
+    "заграничный",
+    "заграничных",
+    "загреб",
+    "загремел",
+    "загремели",
+    "загромождена",
+    "загромождены",
+    "загрубевшими",
+    "загрубели",
+    "загрызет",
+    "загрязнилось",
+    "загрязнился",
+    "загрязнить",
+    "загубила",
+    "загудели",
+    "загулял",
+    "задаваемые",
+    "задаваемым",
+    "задавайте",
+    "задавал",
+    "задавала",
+    "задавали",
+    "задавать",
+    "задавая",
+    "задавила",
+    "задавит",
+    "задавлен",
+    "задавят",
+    "задайте",
+    "задал",
+    "задала",
+    "задало",
+    "задам",
+    "задаром",
+    "задаст",
+    "задатка",
+    "задатки",
+    "задатку",
+    "задаток",
+    "задать",
+    "задач",
+    "задача",
+    "задачею",
+    "задачи",
+    "задачу",
+    "задаю",
+    "задвигались",
+    "задвигаются",
+    "задвижкой",
+    "задвижку",
+    "задвинет",
+    "задворками",
+    "задворки",
+    "задебренные",
+    "задевает",
+    "задевающего",
+    "задевая",
+    "задевши",
+    "задел",
+    "задела",
+    "задели",
+    "задело",
+    "заденет",
+    "задержал",
+    "задержали",
+    "задержало",
+    "задержать",
+    "задерживаем",
+    "задерживай",
+    "задерживать",
+    "задерживаю",
+    "задержкам",
+    "задержками",
+    "задержу",
+    "задернется",
+    "задернул",
+    "задетым",
+    "задеть",
+    "задираете",
+    "задирательное",
+    "задирать",
+    "задней",
+    "заднем",
+    "задний",
+    "задних",
+    "задобрить",
+    "задок",
+    "задолжал",
+    "задом",
+    "задор",
+    "задора",
+    "задохнулся",
+    "задразнили",
+    "задремавший",
+    "задремал",
+    "задремали",
+    "задремать",
+    "задремлет",
+    "задремлешь",
+    "задрожав",
+    "задрожал",
+    "задрожала",
+    "задрожали",
+    "задрожало",
+    "задрожит",
+    "задул",
+    "задумавшись",
+    "задумает",
+    "задумаетесь",
+    "задумай",
+    "задумайте",
+    "задумал",
+    "задумала",
+    "задумалась",
+    "задумались",
+    "задумался",
+    "задумана",
+    "задуманного",
+    "задуманное",
+    "задуматься",
+    "задумаюсь",
+    "задумаются",
+    "задумчив",
+    "задумчивая",
+    "задумчивее",
+    "задумчиво",
+    "задумчивое",
+    "задумчивой",
+    "задумчивом",
+    "задумчивосгь",
+    "задумчивости",
+    "задумчивость",
+    "задумчивостью",
+    "задумчивую",
+    "задумчивы",
+    "задумчивые",
+    "задумчивый",
+    "задумчивым",
+    "задумчивыми",
+    "задумчивых",
+    "задумывается",
+    "задумывайтесь",
+    "задумывалась",
+    "задумывались",
+    "задумывался",
+    "задумываться",
+    "задумываются",
+    "задумываясь",
+    "задурманился",
+    "задушевный",
+    "задушил",
+    "задушила",
+    "задушило",
+    "задушить",
+    "зады",
+    "задымились",
+    "задымится",
+    "задыхавшаяся",
+    "задыхается",
+    "задыхаешься",
+    "задыхалась",
+    "задыхался",
+    "задыхаться",
+    "задыхаюсь",
+    "задыхающимся",
+    "задыхающуюся",
+    "задыхаясь",
+    "заедает",
+    "заедать",
+    "заедены",
+    "заеду",
+    "заезжая",
+    "заела",
+    "заем",
+    "заемного",
+    "заемное",
+    "заемному",
+    "заемные",
+    "заехал",
+    "заехали",
+    "заехать",
+    "зажав",
+    "зажаждал",
+    "зажал",
+    "зажаты",
+    "зажатый",
+    "зажглись",
+    "зажгло",
+    "зажгут",
+    "заждались",
+    "зажег",
+    "зажечь",
+    "зажжена",
+    "зажженный",
+    "зажженных",
+    "заживаются",
+    "заживем",
+    "заживет",
+    "заживо",
+    "зажигает",
+    "зажигал",
+    "зажигали",
+    "зажигательное",
+    "зажигать",
+    "зажигая",
+    "зажила",
+    "зажили",
+    "зажило",
+    "зажимая",
+    "зажирел",
+    "зажиточном",
+    "зажмет",
+    "зажмешь",
+    "зажмуренные",
+    "зажмурив",
+    "зажмуриваясь",
+    "зажмурил",
+    "зажмурился",
+    "зажмуря",
+    "зажужжала",
+    "зажужжали",
+    "зазвать",
+    "зазвенел",
+    "зазвенело",
+    "зазвенят",
+    "зазвучал",
+    "зазвучала",
+    "зазвучат",
+    "зазвучит",
+    "зазовет",
+    "зазору",
+    "зазубринки",
+    "заиграет",
+    "заиграешь",
+    "заиграл",
+    "заиграла",
+    "заиграли",
+    "заиграть",
+    "заиграют",
+    "заигрывал",
+    "заикается",
+    "заикаться",
+    "заикаются",
+    "заикаясь",
+    "заикнется",
+    "заикнитесь",
+    "заикнулась",
+    "заимодавец",
+    "заинтересовала",
+    "заинтересовали",
+    "заинтересовало",
+    "заинтересовался",
+    "заинтересован",
+    "заинтересованы",
+    "заинтересовать",
+    "заискать",
+    "заискивала",
+    "заискиванье",
+    "заискиваю",
+    "зайдем",
+    "зайдет",
+    "зайдете",
+    "зайдешь",
+    "зайди",
+    "зайдите",
+    "зайду",
+    "зайдя",
+    "займа",
+    "займемся",
+    "займет",
+    "займется",
+    "займешь",
+    "займите",
+    "займусь",
+    "займут",
+    "займутся",
+    "зайти",
+    "зайца",
+    "зайцами",
+    "закажу",
+    "заказ",
+    "заказал",
+    "заказали",
+    "заказанные",
+    "заказать",
+    "заказу",
+    "заказывал",
+    "заказывала",
+    "заказывались",
+    "заказывать",
+    "закалена",
+    "закаливая",
+    "заканчивается",
+    "заканчивать",
+    "закапали",
+    "закарабкался",
+    "закат",
+    "заката",
+    "закатал",
+    "закате",
+    "закатившееся",
+    "закатившись",
+    "закатилась",
+    "закатилось",
+    "закатился",
+    "закатим",
+    "закатной",
+    "закатном",
+    "закатный",
+    "закатных",
+    "закатом",
+    "закату",
+    "закатывалось",
+    "закачал",
+    "закивала",
+    "закинем",
+    "закинув",
+    "закинул",
+    "закинула",
+    "закинулось",
+    "закинуто",
+    "закинуть",
+    "закипел",
+    "закипела",
+    "закипели",
+    "закипело",
+    "закипит",
+    "заклад",
+    "заклада",
+    "закладе",
+    "закладка",
+    "закладной",
+    "закладом",
+    "закладу",
+    "закладчик",
+    "закладчика",
+    "закладчиками",
+    "закладчики",
+    "закладчиков",
+    "закладчицы",
+    "заклады",
+    "закладываемое",
+    "закладывай",
+    "закладывал",
+    "закладывала",
+    "закладывать",
+    "закладывают",
+    "заклинаю",
+    "заключает",
+    "заключаете",
+    "заключается",
+    "заключало",
+    "заключалось",
+    "заключать",
+    "заключаю",
+    "заключен",
+    "заключение",
+    "заключением",
+    "заключении",
+    "заключению",
+    "заключения",
+    "заключениям",
+    "заключив",
+    "заключил",
+    "заключила",
+    "заключили",
+    "заключить",
+    "заклятье",
+    "заколдован",
+    "заколдованных",
+    "заколот",
+    "заколотила",
+    "заколотить",
+    "заколют",
+    "закон",
+    "закона",
+    "законам",
+    "законно",
+    "законного",
+    "законное",
+    "законном",
+    "законному",
+    "законности",
+    "законность",
+    "законною",
+    "законную",
+    "законный",
+    "законным",
+    "законными",
+    "законных",
+    "законов",
+    "законодатели",
+    "законодатель",
+    "законом",
+    "закону",
+    "законфузившись",
+    "законфузилась",
+    "законфузились",
+    "законфузился",
+    "законфузиться",
+    "законченный",
+    "законченных",
+    "закончился",
+    "закончить",
+    "законы",
+    "закопал",
+    "закоптелая",
+    "закопченного",
+    "закоренелою",
+    "закоробившись",
+    "закорузлую",
+    "закорузлый",
+    "закоснелость",
+    "закоулки",
+    "закоченели",
+    "закоченелый",
+    "закрадывается",
+    "закрасить",
+    "закраснелась",
+    "закрепить",
+    "закричал",
+    "закричала",
+    "закричали",
+    "закричат",
+    "закричать",
+    "закричите",
+    "закричу",
+    "закровянилось",
+    "закроет",
+    "закроется",
+    "закрой",
+    "закройся",
+    "закрою",
+    "закроют",
+    "закругленной",
+    "закружилась",
+    "закружился",
+    "закружите",
+    "закружитесь",
+    "закружится",
+    "закрутишься",
+    "закрутят",
+    "закручивая",
+    "закрыв",
+    "закрывает",
+    "закрываешь",
+    "закрывай",
+    "закрывал",
+    "закрывали",
+    "закрывать",
+    "закрывая",
+    "закрывшись",
+    "закрыл",
+    "закрыла",
+    "закрылась",
+    "закрыли",
+    "закрылись",
+    "закрыт",
+    "закрыта",
+    "закрытое",
+    "закрыты",
+    "закрытый",
+    "закрытыми",
+    "закрытых",
+    "закудахтал",
+    "закулисную",
+    "закупить",
+    "закупка",
+    "закупками",
+    "закупкой",
+    "закупоривать",
+    "закупорилось",
+    "закуражился",
+    "закурив",
+    "закуривает",
+    "закуривать",
+    "закурил",
+    "закусив",
+    "закусил",
+    "закусить",
+    "закуска",
+    "закусками",
+    "закуске",
+    "закуски",
+    "закуску",
+    "закусывал",
+    "закусывала",
+    "закусывать",
+    "закутав",
+    "закутавшись",
+    "закутал",
+    "закуталась",
+    "закутался",
+    "закутанный",
+    "закутанным",
+    "закутаться",
+    "зал",
+    "зала",
+    "залает",
+    "залах",
+    "залаяла",
+    "зале",
+    "залег",
+    "залегла",
+    "залежавшейся",
+    "залежался",
+    "залежаться",
+    "залез",
+    "залезал",
+    "залезли",
+    "залезть",
+    "залепетала",
+    "залепила",
+    "залетает",
+    "залетал",
+    "залетела",
+    "залетело",
+    "залечить",
+    "залив",
+    "залива",
+    "заливалась",
+    "заливались",
+    "заливаться",
+    "заливаются",
+    "заливаясь",
+    "заливистый",
+    "заливного",
+    "заливные",
+    "залившею",
+    "залившись",
+    "залила",
+    "залилась",
+    "залились",
+    "залился",
+    "залитая",
+    "залито",
+    "залитое",
+    "залитые",
+    "залитый",
+    "залить",
+    "залог",
+    "залогом",
+    "заложена",
+    "заложенном",
+    "заложенный",
+    "заложив",
+    "заложил",
+    "заложила",
+    "заложили",
+    "заложит",
+    "заложить",
+    "заложу",
+    "залой",
+    "заломив",
+    "заломившаяся",
+    "заломлены",
+    "залпом",
+    "залу",
+    "залучить",
+    "залы",
+    "зальдевших",
+    "зальет",
+    "зальется",
+    "зальешь",
+    "зальюсь",
+    "замай",
+    "замайте",
+    "заманит",
+    "заманить",
+    "заманишь",
+    "заманчивей",
+    "заманчиво",
+    "замараться",
+    "замарашки",
+    "замасленной",
+    "замасленную",
+    "замасленные",
+    "замасленных",
+    "замахал",
+    "замахивается",
+    "замахиваясь",
+    "замахнется",
+    "замахнула",
+    "замахнулась",
+    "замашками",
+    "замашкой",
+    "замедленного",
+    "замедлил",
+    "замедляет",
+    "замедляй",
+    "замелькал",
+    "замелькали",
+    "заменил",
+    "заменились",
+    "заменился",
+    "заменить",
+    "замену",
+    "заменю",
+    "заменя",
+    "заменяет",
+    "заменялась",
+    "замер",
+    "замереть",
+    "замерзнет",
+    "замерзнуть",
+    "замерла",
+    "замерли",
+    "замерло",
+    "замертво",
+    "замести",
+    "заместо",
+    "заметен",
+    "заметенной",
+    "заметив",
+    "заметил",
+    "заметила",
+    "заметили",
+    "заметим",
+    "заметит",
+    "заметить",
+    "заметишь",
+    "заметку",
+    "заметливы",
+    "заметна",
+    "заметная",
+    "заметно",
+    "заметного",
+    "заметное",
+    "заметной",
+    "заметном",
+    "заметною",
+    "заметную",
+    "заметны",
+    "заметным",
+    "заметов",
+    "заметова",
+    "заметовские",
+    "заметову",
+    "заметовым",
+    "заметок",
+    "заметь",
+    "заметьте",
+    "заметят",
+    "замечаемый",
+    "замечает",
+    "замечаете",
+    "замечается",
+    "замечаешь",
+    "замечай",
+    "замечал",
+    "замечала",
+    "замечали",
+    "замечалось",
+    "замечание",
+    "замечанием",
+    "замечанию",
+    "замечания",
+    "замечаниями",
+    "замечателен",
+    "замечательная",
+    "замечательнейшая",
+    "замечательнейший",
+    "замечательно",
+    "замечательного",
+    "замечательное",
+    "замечательном",
+    "замечательные",
+    "замечательными",
+    "замечать",
+    "замечаю",
+    "замечают",
+    "замечая",
+    "замечен",
+    "замеченной",
+    "замеченным",
+    "замечтает",
+    "замечтал",
+    "замечталась",
+    "замечтался",
+    "замешается",
+    "замешался",
+    "замешан",
+    "замешаны",
+    "замешательство",
+    "замешательством",
+    "замешкали",
+    "замешкались",
+    "замешкался",
+    "замигал",
+    "замирает",
+    "замирала",
+    "замирало",
+    "замиранием",
+    "замирающие",
+    "замирающий",
+    "замирающим",
+    "замирая",
+    "замка",
+    "замке",
+    "замки",
+    "замкну",
+    "замкнут",
+    "замкнутую",
+    "замкнутых",
+    "замкнуть",
+    "замнется",
+    "замогильный",
+    "замок",
+    "замолит",
+    "замолк",
+    "замолкла",
+    "замолкли",
+    "замолкло",
+    "замолкнут",
+    "замолкнуть",
+    "замолчал",
+    "замолчала",
+    "замолчали",
+    "замолчат",
+    "замолчать",
+    "замолчи",
+    "замолчит",
+    "замолчу",
+    "заморишь",
+    "заморские",
+    "заморской",
+    "заморскую",
+    "замочились",
+    "замочился",
+    "замочной",
+    "замочную",
+    "замою",
+    "замрет",
+    "замри",
+    "замрут",
+    "замуж",
+    "замужем",
+    "замужества",
+    "замужестве",
+    "замужеству",
+    "замужнюю",
+    "замучаешься",
+    "замучай",
+    "замученные",
+    "замучил",
+    "замучила",
+    "замучилась",
+    "замучили",
+    "замучились",
+    "замучился",
+    "замучит",
+    "замучить",
+    "замшевые",
+    "замшевый",
+    "замыкалась",
+    "замыкалова",
+    "замыкаю",
+    "замыкая",
+    "замыкаясь",
+    "замысел",
+    "замысла",
+    "замыслишь",
+    "замыслов",
+    "замыслы",
+    "замыть",
+    "замышляла",
+    "замышлялась",
+    "замяла",
+    "замялся",
+    "замямлил",
+    "замять",
+    "занавес",
+    "занавесей",
+    "занавеска",
+    "занавесками",
+    "занавески",
+    "занавеску",
+    "занавесок",
+    "занавесом",
+    "занавесы",
+    "занавесью",
+    "занды",
+    "занес",
+    "занесен",
+    "занесенной",
+    "занесенными",
+    "занести",
+    "занимавшая",
+    "занимаемая",
+    "занимает",
+    "занимаетесь",
+    "занимается",
+    "занимаешься",
+    "занимай",
+    "занимайся",
+    "занимайтесь",
+    "занимал",
+    "занимала",
+    "занималась",
+    "занимали",
+    "занимались",
+    "занимало",
+    "занимался",
+    "занимательным",
+    "занимать",
+    "заниматься",
+    "занимаю",
+    "занимаюсь",
+    "занимаются",
+    "занимающаяся",
+    "заново",
+    "занозила",
+    "заносил",
+    "заносчивая",
+    "заносчиво",
+    "заносчивого",
+    "заносчивость",
+    "заносчивы",
+    "заносчивым",
+    "занося",
+    "заношенная",
+    "заношенного",
+    "заношенные",
+    "занявший",
+    "занял",
+    "заняла",
+    "занялась",
+    "занялись",
+    "заняло",
+    "занялся",
+    "занят",
+    "занята",
+    "занятая",
+    "занятие",
+    "занятии",
+    "занятий",
+    "занятия",
+    "занятиям",
+    "занято",
+    "занятого",
+    "заняты",
+    "занятые",
+    "занятый",
+    "занять",
+    "заняться",
+    "заодно",
+    "заорал",
+    "заочно",
+    "западают",
+    "западник",
+    "западню",
+    "запамятовал",
+    "запамятовали",
+    "запарился",
+    "запас",
+    "запаса",
+    "запасе",
+    "запасем",
+    "запасными",
+    "запасом",
+    "запасы",
+    "запах",
+    "запахе",
+    "запахло",
+    "запахов",
+    "запахом",
+    "запачкала",
+    "запачкался",
+    "запачканная",
+    "запачканное",
+    "запачканные",
+    "запачкаться",
+    "запашке",
+    "запевает",
+    "запевали",
+    "запевания",
+    "запевая",
+    "запекаться",
+    "запеклась",
+    "запеклись",
+    "запекшейся",
+    "запекшимися",
+    "запекшихся",
+    "запел",
+    "запела",
+    "запели",
+    "запендю",
+    "запенившимися",
+    "запер",
+    "заперев",
+    "запереть",
+    "запереться",
+    "заперла",
+    "заперлась",
+    "заперли",
+    "заперся",
+    "заперт",
+    "заперта",
+    "запертая",
+    "заперто",
+    "запертой",
+    "запертую",
+    "заперты",
+    "запертых",
+    "запершись",
+    "запечатал",
+    "запечатанную",
+    "запивает",
+    "запивать",
+    "запил",
+    "запинаются",
+    "запинаясь",
+    "запинкой",
+    "запираемы",
+    "запирает",
+    "запираете",
+    "запирается",
+    "запираешь",
+    "запиралась",
+    "запирали",
+    "запирался",
+    "запирать",
+    "запираю",
+    "запирают",
+    "запираются",
+    "записали",
+    "записались",
+    "записан",
+    "записанными",
+    "записано",
+    "записаны",
+    "записать",
+    "записка",
+    "записками",
+    "записках",
+    "записке",
+    "записки",
+    "запиской",
+    "записку",
+    "записная",
+    "записной",
+    "записную",
+    "записок",
+    "записочку",
+    "записывает",
+    "записывания",
+    "записыванья",
+    "записывать",
+    "записываю",
+    "записывают",
+    "записывая",
+    "запихивать",
+    "запихивая",
+    "запишем",
+    "запиши",
+    "запишу",
+    "заплакал",
+    "заплакала",
+    "заплакали",
+    "заплаканное",
+    "заплаканной",
+    "заплаканы",
+    "заплакать",
+    "заплатами",
+    "заплатах",
+    "заплати",
+    "заплатил",
+    "заплатила",
+    "заплатили",
+    "заплатим",
+    "заплатит",
+    "заплатите",
+    "заплатить",
+    "заплатишь",
+    "заплатки",
+    "заплаты",
+    "заплачем",
+    "заплачет",
+    "заплачешь",
+    "заплачу",
+    "заплачут",
+    "заплевавший",
+    "заплеванному",
+    "заплеванный",
+    "заплесневела",
+    "заплесть",
+    "заплетены",
+    "заплутался",
+    "заплывали",
+    "заплывшие",
+    "заплыли",
+    "заплясало",
+    "запнулась",
+    "запнулся",
+    "заподозрили",
+    "заподозрить",
+    "запоем",
+    "запоет",
+    "запоешь",
+    "запоздало",
+    "запоздалый",
+    "запоздалым",
+    "запоздать",
+    "запой",
+    "заполнить",
+    "заполняя",
+    "заполонила",
+    "заполонить",
+    "запомнивши",
+    "запомнил",
+    "запомнили",
+    "запомним",
+    "запомните",
+    "запомнить",
+    "запомню",
+    "запомнят",
+    "запонка",
+    "запонки",
+    "запор",
+    "запора",
+    "запоре",
+    "запорет",
+    "запороши",
+    "запорошит",
+    "запоя",
+    "заправить",
+    "заправленную",
+    "заправляет",
+    "запрег",
+    "запредельных",
+    "запрет",
+    "запретив",
+    "запретил",
+    "запретишь",
+    "запреты",
+    "запрещает",
+    "запрещала",
+    "запрещение",
+    "запрещения",
+    "запрещениям",
+    "запрещено",
+    "заприте",
+    "запритесь",
+    "запропастившуюся",
+    "запропастились",
+    "запросила",
+    "запросится",
+    "запросто",
+    "запросят",
+    "запружена",
+    "запрыгает",
+    "запрыгал",
+    "запрыгала",
+    "запрыгали",
+    "запрягут",
+    "запряженная",
+    "запряженной",
+    "запрячь",
+    "запугала",
+    "запуганной",
+    "запуганным",
+    "запугать",
+    "запускает",
+    "запускал",
+    "запускать",
+    "запустив",
+    "запустил",
+    "запутавшись",
+    "запутает",
+    "запутаешься",
+    "запуталось",
+    "запутанного",
+    "запутанный",
+    "запутываться",
+    "запутывая",
+    "запутываясь",
+    "запушенным",
+    "запущенностью",
+    "запущенную",
+    "запущенный",
+    "запущенным",
+    "запущенных",
+    "запущено",
+    "запылавшие",
+    "запылали",
+    "запыленное",
+    "запыленной",
+    "запыленному",
+    "запыленную",
+    "запыленные",
+    "запылены",
+    "запылилась",
+    "запылилось",
+    "запыхавшись",
+    "запыхалась",
+    "запьешь",
+    "запью",
+    "запястье",
+    "запястья",
+    "запятнаны",
+    "зарабатывал",
+    "заработает",
+    "заработал",
+    "заработались",
+    "заработался",
+    "заработать",
+    "заработка",
+    "заработком",
+    "заражал",
+    "заражалась",
+    "заражались",
+    "заражаются",
+    "заражая",
+    "зараженного",
+    "зараженные",
+    "зараженный",
+    "зараз",
+    "заразил",
+    "заразила",
+    "заразилась",
+    "заразили",
+    "заразителен",
+    "заразительная",
+    "заразительно",
+    "заразительны",
+    "заразы",
+    "зарайский",
+    "зарайских",
+    "зарайского",
+    "заранее",
+    "зарапортовался",
+    "зарастает",
+    "зарделось",
+    "заре",
+    "зарева",
+    "заревам",
+    "заревая",
+    "зареве",
+    "заревел",
+    "зарево",
+    "заревое",
+    "заревом",
+    "заревою",
+    "заревые",
+    "зарежет",
+    "зарежь",
+    "зарезал",
+    "зарезала",
+    "зарезу",
+    "зарей",
+    "заречная",
+    "заречной",
+    "зарею",
+    "заржавленным",
+    "заржет",
+    "зари",
+    "зарницы",
+    "зарницына",
+    "зарницыной",
+    "зародилась",
+    "зародились",
+    "зародыш",
+    "зарождаются",
+    "зарождение",
+    "зарождения",
+    "заройся",
+    "зароненной",
+    "заронился",
+    "заросла",
+    "заросший",
+    "заросшими",
+    "зароют",
+    "зарубежной",
+    "зарубить",
+    "зарывшись",
+    "зарыдала",
+    "зарыдаю",
+    "зарыдают",
+    "зарылись",
+    "зарыт",
+    "зарыто",
+    "зарыть",
+    "зарыться",
+    "зарычал",
+    "зарычи",
+    "зарю",
+    "заря",
+    "заряда",
+    "зарядили",
+    "заряжанье",
+    "засади",
+    "засаленной",
+    "засаленном",
+    "засаленный",
+    "засасывает",
+    "засверкав",
+    "засверкавшими",
+    "засверкал",
+    "засверкали",
+    "засверкало",
+    "засветили",
+    "засветились",
+    "засветилось",
+    "засветло",
+    "засвидетельствовал",
+    "засвидетельствовала",
+    "засвидетельствован",
+    "засвидетельствована",
+    "засвидетельствование",
+    "засвидетельствовано",
+    "засвидетельствовать",
+    "засевались",
+    "заседание",
+    "засеку",
+    "засела",
+    "заселен",
+    "засели",
+    "заселилось",
+    "засело",
+    "засиделся",
+    "засидится",
+    "засипел",
+    "засияет",
+    "засиял",
+    "засияла",
+    "засияли",
+    "засияло",
+    "заскакать",
+    "заскрежетав",
+    "заскрежетал",
+    "заскрипит",
+    "заслоненная",
+    "заслонил",
+    "заслонилась",
+    "заслонилось",
+    "заслонявший",
+    "заслонялось",
+    "заслуга",
+    "заслуги",
+    "заслуженная",
+    "заслуженного",
+    "заслуженной",
+    "заслуженном",
+    "заслуженным",
+    "заслужил",
+    "заслужу",
+    "заслушивался",
+    "заслыша",
+    "заслышавший",
+    "засматриваться",
+    "засмеется",
+    "засмеют",
+    "засмеются",
+    "засмеявшись",
+    "засмеялась",
+    "засмеялись",
+    "засмеялся",
+    "засмеяться",
+    "засмотрится",
+    "заснет",
+    "засну",
+    "заснувши",
+    "заснул",
+    "заснула",
+    "заснули",
+    "заснуть",
+    "засовы",
+    "засохла",
+    "засохли",
+    "засохло",
+    "засохшею",
+    "засохшим",
+    "засохшими",
+    "засохших",
+    "заспав",
+    "заспанная",
+    "заспанные",
+    "заспаны",
+    "заспишься",
+    "заставал",
+    "заставала",
+    "заставил",
+    "заставили",
+    "заставило",
+    "заставит",
+    "заставить",
+    "заставленное",
+    "заставлю",
+    "заставлявших",
+    "заставляет",
+    "заставлял",
+    "заставляла",
+    "заставляли",
+    "заставляю",
+    "заставляют",
+    "заставляя",
+    "заставой",
+    "заставу",
+    "заставы",
+    "заставят",
+    "застал",
+    "застала",
+    "застали",
+    "застанем",
+    "застанет",
+    "застанешь",
+    "застану",
+    "застарелой",
+    "застать",
+    "застегивался",
+    "застегивание",
+    "застегивать",
+    "застегивая",
+    "застегнут",
+    "застегнутое",
+    "застелют",
+    "застенчив",
+    "застенчива",
+    "застенчиво",
+    "застенчивости",
+    "застенчивость",
+    "застенчивы",
+    "застиг",
+    "застилает",
+    "застойки",
+    "застойкой",
+    "застольных",
+    "застонал",
+    "застонет",
+    "застрахованы",
+    "застраховать",
+    "застрелившиеся",
+    "застрелил",
+    "застрелился",
+    "застрелись",
+    "застрелитесь",
+    "застрелится",
+    "застрелиться",
+    "застрелюсь",
+    "застряло",
+    "застрянет",
+    "застукало",
+    "заступаться",
+    "заступила",
+    "заступило",
+    "заступитесь",
+    "заступиться",
+    "заступом",
+    "застучал",
+    "застучали",
+    "застучало",
+    "застучат",
+    "застучит",
+    "застывала",
+    "застывало",
+    "застывая",
+    "застыдилась",
+    "застыдился",
+    "застыдят",
+    "застыла",
+    "застыли",
+    "застынет",
+    "засудят",
+    "засуетилась",
+    "засуетились",
+    "засуетился",
+    "засуетятся",
+    "засунув",
+    "засунул",
+    "засунула",
+    "засуха",
+    "засухи",
+    "засуху",
+    "засученными",
+    "засучились",
+    "засыпает",
+    "засыпал",
+    "засыпала",
+    "засыпанная",
+    "засыпаю",
+    "засыпают",
+    "засыпи",
+    "засыплет",
+    "засыхавшая",
+    "засядем",
+    "затаенной",
+    "затаившейся",
+    "затаившиеся",
+    "затаившимся",
+    "затаил",
+    "затараторила",
+    "затасканной",
+    "затасканную",
+    "затвердил",
+    "затворе",
+    "затворена",
+    "затвори",
+    "затворив",
+    "затворилась",
+    "затворить",
+    "затворничества",
+    "затворю",
+    "затворяет",
+    "затворяется",
+    "затворялась",
+    "затворят",
+    "затворяя",
+    "затевать",
+    "затеем",
+    "затеет",
+    "затеешь",
+    "затеи",
+    "затей",
+    "затекает",
+    "затем",
+    "затемнен",
+    "затепли",
+    "затеплят",
+    "затерлось",
+    "затерся",
+    "затертого",
+    "затертый",
+    "затерявшийся",
+    "затерял",
+    "затерянная",
+    "затерянный",
+    "затеряться",
+    "затесало",
+    "затеснились",
+    "затеял",
+    "затеяли",
+    "затеялись",
+    "затеями",
+    "затеять",
+    "затих",
+    "затихать",
+    "затихла",
+    "затихли",
+    "затихло",
+    "затишье",
+    "затишьев",
+    "затишьях",
+    "заткнет",
+    "заткни",
+    "заткнутые",
+    "заткнутым",
+    "затмевал",
+    "затмение",
+    "затмений",
+    "зато",
+    "затолпилось",
+    "затопав",
+    "затопал",
+    "затопишь",
+    "затопчет",
+    "заторелым",
+    "заторопившись",
+    "заторопилась",
+    "заторопился",
+    "заторопясь",
+    "затосковал",
+    "затравленная",
+    "затратили",
+    "затревожилась",
+    "затревожит",
+    "затрепетал",
+    "затрепетала",
+    "затрепетали",
+    "затрепещет",
+    "затрется",
+    "затрогивать",
+    "затронет",
+    "затронули",
+    "затруднение",
+    "затруднении",
+    "затруднений",
+    "затруднения",
+    "затруднилась",
+    "затруднительно",
+    "затруднительное",
+    "затрудняется",
+    "затруднялась",
+    "затруднялись",
+    "затруднялся",
+    "затрудняясь",
+    "затряс",
+    "затряслась",
+    "затряслись",
+    "затрясусь",
+    "затуманила",
+    "затуманилась",
+    "затуманит",
+    "затушено",
+    "затушить",
+    "затушу",
+    "затыкать",
+    "затылка",
+    "затылке",
+    "затылок",
+    "затягивает",
+    "затягивая",
+    "затянувшийся",
+    "затянула",
+    "затянули",
+    "затянулось",
+    "затянут",
+    "заунывную",
+    "заурядным",
+    "заутрене",
+    "заутрени",
+    "заученное",
+    "заученную",
+    "заученный",
+    "заучены",
+    "заучусь",
+    "зафилософствовался",
+    "захар",
+    "захара",
+    "захарка",
+    "захарке",
+    "захаров",
+    "захарович",
+    "захаровича",
+    "захаровичем",
+    "захаровых",
+    "захаром",
+    "захару",
+    "захарыч",
+    "захарыча",
+    "захвалить",
+    "захватив",
+    "захватившие",
+    "захватил",
+    "захватила",
+    "захватили",
+    "захватило",
+    "захватит",
+    "захватить",
+    "захватывает",
+    "захватывало",
+    "захватывают",
+    "захваченный",
+    "захворал",
+    "захворала",
+    "захихикал",
+    "захлебнулся",
+    "захлебываясь",
+    "захлестнула",
+    "захлестнуло",
+    "захлопнула",
+    "захлопнулась",
+    "захлопывала",
+    "захлопывая",
+    "захмелевший",
+    "захныкал",
+    "заходил",
+    "заходила",
+    "заходили",
+    "заходило",
+    "заходит",
+    "заходить",
+    "заходя",
+    "заходящее",
+    "заходящим",
+    "захождения",
+    "захожу",
+    "захотел",
+    "захотела",
+    "захотели",
+    "захотелось",
+    "захотеть",
+    "захотите",
+    "захотят",
+    "захохотал",
+    "захохотала",
+    "захохотали",
+    "захохочет",
+    "захочет",
+    "захочется",
+    "захочешь",
+    "захочу",
+    "захрипел",
+    "захромала",
+    "захрустел",
+    "зацелованный",
+    "зацепите",
+    "зацепиться",
+    "зачало",
+    "зачастую",
+    "зачатия",
+    "зачатками",
+    "зачах",
+    "зачахла",
+    "зачахну",
+    "зачем",
+    "зачеркивает",
+    "зачеркнет",
+    "зачерпнула",
+    "зачешется",
+    "зачешутся",
+    "зачислен",
+    "зачитался",
+    "зачитывался",
+    "зачтено",
+    "зашаталась",
+    "зашаталось",
+    "зашевелились",
+    "зашевелит",
+    "зашевелится",
+    "зашевелят",
+    "зашевелятся",
+    "зашел",
+    "зашептал",
+    "зашептала",
+    "зашептались",
+    "зашивались",
+    "зашипел",
+    "зашипит",
+    "зашить",
+    "зашла",
+    "зашлепал",
+    "зашли",
+    "зашло",
+    "заштопала",
+    "заштопать",
+    "зашумит",
+    "зашумят",
+    "защелкал",
+    "защемило",
+    "защита",
+    "защите",
+    "защити",
+    "защитит",
+    "защитите",
+    "защитить",
+    "защититься",
+    "защитник",
+    "защиту",
+    "защиты",
+    "защищавших",
+    "защищает",
+    "защищал",
+    "защищала",
+    "защищался",
+    "защищать",
+    "заявил",
+    "заявила",
+    "заявить",
+    "заявлениях",
+    "заявлено",
+    "заявляет",
+    "заячья",
+    "звал",
+    "звала",
+    "звали",
+    "зваль",
+    "звание",
+    "званием",
+    "звания",
+    "званых",
+    "званья",
+    "звать",
+    "звезд",
+    "звезда",
+    "звездам",
+    "звездами",
+    "звездах",
+    "звезде",
+    "звездной",
+    "звездой",
+    "звездочка",
+    "звездою",
+    "звезду",
+    "звезды",
+    "звена",
+    "звенела",
+    "звенели",
+    "звенело",
+    "звенит",
+    "звено",
+    "звену",
+    "звеньев",
+    "звенья",
+    "звеня",
+    "звенят",
+    "звенящий",
+    "зверей",
+    "зверем",
+    "звери",
+    "звериного",
+    "звериной",
+    "звериную",
+    "звериный",
+    "зверок",
+    "зверская",
+    "зверские",
+    "зверского",
+    "зверском",
+    "зверства",
+    "зверь",
+    "зверю",
+    "зверя",
+    "звон",
+    "звоне",
+    "звонил",
+    "звонили",
+    "звонит",
+    "звонить",
+    "звонка",
+    "звонки",
+    "звонкие",
+    "звонкий",
+    "звонким",
+    "звонко",
+    "звонкой",
+    "звонком",
+    "звонок",
+    "звоном",
+    "звонят",
+    "звук",
+    "звука",
+    "звукам",
+    "звуках",
+    "звуки",
+    "звуков",
+    "звуком",
+    "звуку",
+    "звучал",
+    "звучала",
+    "звучали",
+    "звучало",
+    "звучат",
+    "звучащий",
+    "звучащим",
+    "звучен",
+    "звучит",
+    "звучным",
+    "звякание",
+    "звякают",
+    "звякнул",
+    "зги",
+    "зготовляй",
+    "здание",
+    "здания",
+    "зданиях",
+    "зданье",
+    "зданья",
+    "здесь",
+    "здеся",
+    "здешнего",
+    "здешней",
+    "здешнем",
+    "здешнему",
+    "здешнею",
+    "здешние",
+    "здешний",
+    "здешним",
+    "здешних",
+    "здешнюю",
+    "здоров",
+    "здорова",
+    "здоровается",
+    "здоровалась",
+    "здороваться",
+    "здоровая",
+    "здоровее",
+    "здоровенькие",
+    "здоровенького",
+    "здоровехонек",
+    "здорово",
+    "здорового",
+    "здоровой",
+    "здоровому",
+    "здоровы",
+    "здоровые",
+    "здоровый",
+    "здоровым",
+    "здоровыми",
+    "здоровье",
+    "здоровьем",
+    "здоровью",
+    "здоровья",
+    "здравие",
+    "здраво",
+    "здравого",
+    "здравое",
+    "здравом",
+    "здравому",
+    "здравствовать",
+    "здравствует",
+    "здравствуй",
+    "здравствуйте",
+    "здравый",
+    "здсеь",
+    "зе",
+    "зевавшему",
+    "зевает",
+    "зевак",
+    "зевал",
+    "зевать",
+    "зевая",
+    "зевес",
+    "зевнет",
+    "зевнул",
+    "зевнуть",
+    "зевотой",
+    "зевоту",
+    "зевоты",
+    "зейн",
+    "зейнрок",
+    "зелена",
+    "зеленая",
+    "зеленеет",
+    "зелененькими",
+    "зелененькую",
+    "зеленеют",
+    "зелени",
+    "зеленные",
+    "зелено",
+    "зеленоватые",
+    "зеленоватым",
+    "зеленого",
+    "зеленой",
+    "зеленом",
+    "зеленую",
+    "зеленщик",
+    "зеленщика",
+    "зеленщики",
+    "зеленщику",
+    "зелены",
+    "зеленые",
+    "зеленый",
+    "зеленым",
+    "зелеными",
+    "зеленых",
+    "зелень",
+    "зеленью",
+    "земель",
+    "земле",
+    "земледелие",
+    "землей",
+    "землетрясений",
+    "землетрясению",
+    "землею",
+    "земли",
+    "землю",
+    "земля",
+    "земляк",
+    "земляка",
+    "земляку",
+    "землям",
+    "землянику",
+    "землянке",
+    "земная",
+    "земно",
+    "земного",
+    "земное",
+    "земной",
+    "земном",
+    "земные",
+    "земского",
+    "земской",
+    "земском",
+    "земскую",
+    "земстве",
+    "земчуг",
+    "зените",
+    "зеницах",
+    "зеркал",
+    "зеркала",
+    "зеркалах",
+    "зеркале",
+    "зеркало",
+    "зеркалом",
+    "зеркальной",
+    "зеркальный",
+    "зеркальце",
+    "зеркальцем",
+    "зерна",
+    "зерно",
+    "зигзагами",
+    "зигзагом",
+    "зигфрид",
+    "зима",
+    "зиме",
+    "зимнее",
+    "зимней",
+    "зимнем",
+    "зимние",
+    "зимний",
+    "зимних",
+    "зимой",
+    "зиму",
+    "зимы",
+    "зинаиду",
+    "зияющие",
+    "зла",
+    "злаки",
+    "златая",
+    "златит",
+    "злато",
+    "златом",
+    "златым",
+    "златятся",
+    "злая",
+    "злее",
+    "злейший",
+    "злился",
+    "злит",
+    "злитесь",
+    "злится",
+    "злиться",
+    "злишься",
+    "зло",
+    "злоба",
+    "злобе",
+    "злобен",
+    "злобная",
+    "злобно",
+    "злобного",
+    "злобное",
+    "злобной",
+    "злобною",
+    "злобные",
+    "злобный",
+    "злобным",
+    "злобных",
+    "злобой",
+    "злобою",
+    "злобствуют",
+    "злобу",
+    "злобы",
+    "зловещая",
+    "зловещее",
+    "зловещей",
+    "зловещие",
+    "зловещий",
+    "зловещим",
+    "зловили",
+    "зловредную",
+    "злодее",
+    "злодеев",
+    "злодей",
+    "злодейка",
+    "злодейскою",
+    "злодейств",
+    "злодейство",
+    "злодея",
+    "злодеяние",
+    "злое",
+    "злой",
+    "злом",
+    "злонамеренная",
+    "злорадно",
+    "злорадством",
+    "злословил",
+    "злословия",
+    "злости",
+    "злостных",
+    "злость",
+    "злостью",
+    "злосчастный",
+    "злоупотреблении",
+    "злоупотреблений",
+    "злоупотреблять",
+    "злу",
+    "злую",
+    "злы",
+    "злые",
+    "злым",
+    "злыми",
+    "злых",
+    "злюсь",
+    "злющая",
+    "злющей",
+    "злясь",
+    "змее",
+    "змеей",
+    "змеею",
+    "змеи",
+    "змеилась",
+    "змеились",
+    "змеиный",
+    "змеится",
+    "змей",
+    "змею",
+    "змея",
+    "змеящихся",
+    "знавал",
+    "знавали",
+    "знавшие",
+    "знавший",
+    "знаем",
+    "знает",
+    "знаете",
+    "знаешь",
+    "знай",
+    "знайте",
+    "знак",
+    "знака",
+    "знаками",
+    "знаки",
+    "знаков",
+    "знаком",
+    "знакома",
+    "знакомая",
+    "знакомиться",
+    "знакомо",
+    "знакомого",
+    "знакомое",
+    "знакомой",
+    "знакомом",
+    "знакомому",
+    "знакомств",
+    "знакомства",
+    "знакомстве",
+    "знакомство",
+    "знакомству",
+    "знакомую",
+    "знакомы",
+    "знакомые",
+    "знакомый",
+    "знакомым",
+    "знакомыми",
+    "знакомых",
+    "знакомя",
+    "знал",
+    "знала",
+    "знали",
+    "зналь",
+    "знамен",
+    "знамена",
+    "знаменательно",
+    "знаменательного",
+    "знаменательные",
+    "знаменательный",
+    "знаменательным",
+    "знаменательных",
+    "знамением",
+    "знамений",
+    "знаменит",
+    "знаменитейший",
+    "знаменитой",
+    "знаменитости",
+    "знаменитость",
+    "знаменитый",
+    "знамения",
+    "знаменовало",
+    "знамо",
+    "знамя",
+    "знание",
+    "знанием",
+    "знаний",
+    "знания",
+    "знаниями",
+    "знаньем",
+    "знанья",
+    "знатно",
+    "знатной",
+    "знатность",
+    "знатный",
+    "знатных",
+    "знатока",
+    "знать",
+    "знаться",
+    "значат",
+    "значение",
+    "значением",
+    "значении",
+    "значения",
+    "значенье",
+    "значила",
+    "значили",
+    "значило",
+    "значит",
+    "значительная",
+    "значительно",
+    "значительное",
+    "значительной",
+    "значительном",
+    "значительному",
+    "значительную",
+    "значительные",
+    "значительный",
+    "значительным",
+    "значительных",
+    "значить",
+    "значишь",
+    "значу",
+    "знаю",
+    "знают",
+    "знающая",
+    "знающему",
+    "знающий",
+    "знающими",
+    "зная",
+    "знобить",
+    "зное",
+    "зноем",
+    "зной",
+    "знойкову",
+    "знойной",
+    "знойною",
+    "знойный",
+    "зною",
+    "зноя",
+    "зов",
+    "зова",
+    "зовем",
+    "зовет",
+    "зовете",
+    "зовешь",
+    "зови",
+    "зовом",
+    "зову",
+    "зовут",
+    "зовущих",
+    "зол",
+    "зола",
+    "золе",
+    "золовка",
+    "золой",
+    "золота",
+    "золотая",
+    "золоте",
+    "золотеет",
+    "золотистые",
+    "золотистый",
+    "золотистым",
+    "золотит",
+    "золотится",
+    "золотить",
+    "золотника",
+    "золото",
+    "золотоглавый",
+    "золотого",
+    "золотое",
+    "золотой",
+    "золотокудрый",
+    "золотом",
+    "золотопромышленника",
+    "золотопромышленность",
+    "золотою",
+    "золоту",
+    "золотухой",
+    "золотушный",
+    "золотую",
+    "золотые",
+    "золотым",
+    "золотыми",
+    "золотых",
+    "золу",
+    "зонтик",
+    "зонтика",
+    "зонтиком",
+    "зоргенфрею",
+    "зори",
+    "зоркая",
+    "зорким",
+    "зорко",
+    "зоркого",
+    "зорком",
+    "зорче",
+    "зорь",
+    "зорю",
+    "зосимов",
+    "зосимова",
+    "зосимову",
+    "зосимовым",
+    "зрак",
+    "зрачки",
+    "зреет",
+    "зрели",
+    "зрелище",
+    "зрелого",
+    "зрелой",
+    "зрелости",
+    "зрелость",
+    "зрелостью",
+    "зрелый",
+    "зрелым",
+    "зрение",
+    "зрению",
+    "зрения",
+    "зренье",
+    "зреют",
+    "зримей",
+    "зримый",
+    "зрит",
+    "зрителей",
+    "зрители",
+    "зритель",
+    "зрителями",
+    "зришь",
+    "зря",
+    "зуб",
+    "зубам",
+    "зубами",
+    "зубах",
+    "зубки",
+    "зубов",
+    "зубок",
+    "зубоскалить",
+    "зубчатой",
+    "зубчатою",
+    "зубчатый",
+    "зубы",
+    "зудят",
+    "зурна",
+    "зурны",
+    "зыбкий",
+    "зыбкой",
+    "зыбком",
+    "зыблется",
+    "зятем",
+    "и",
+    "ибо",
+    "ибсен",
+    "ибсена",
+    "иван",
+    "ивана",
+    "иване",
+    "иванов",
+    "иванове",
+    "иванович",
+    "ивановича",
+    "ивановичу",
+    "ивановна",
+    "ивановне",
+    "ивановной",
+    "ивановну",
+    "ивановны",
+    "ивановой",
+    "ивановскую",
+    "иванову",
+    "иваном",
+    "иванонва",
+    "ивану",
+    "иваныч",
+    "иваныча",
+    "иванычем",
+    "иванычу",
+    "ига",
+    "игла",
+    "иглой",
+    "иглу",
+    "иглы",
+    "игнатьевич",
+    "игнатьевича",
+    "игнатьевичем",
+    "игнатьевна",
+    "игнатьевны",
+    "игнатьич",
+    "игнатьича",
+    "игнашка",
+    "иго",
+    "иголка",
+    "иголочки",
+    "игом",
+    "игра",
+    "играем",
+    "играет",
+    "играете",
+    "играешь",
+    "играешься",
+    "играй",
+    "играйте",
+    "играл",
+    "играла",
+    "играли",
+    "играло",
+    "играль",
+    "играми",
+    "играть",
+    "играх",
+    "играю",
+    "играют",
+    "играючи",
+    "играющем",
+    "играющею",
+    "играющих",
+    "играя",
+    "игре",
+    "игривая",
+    "игривее",
+    "игривенькая",
+    "игривого",
+    "игривости",
+    "игривостию",
+    "игривую",
+    "игривый",
+    "игрой",
+    "игрок",
+    "игру",
+    "игрушечку",
+    "игрушки",
+    "игры",
+    "идеал",
+    "идеала",
+    "идеалам",
+    "идеале",
+    "идеален",
+    "идеализм",
+    "идеалист",
+    "идеалов",
+    "идеалом",
+    "идеалу",
+    "идеалы",
+    "идеальная",
+    "идеально",
+    "идеальный",
+    "идее",
+    "идеей",
+    "идеи",
+    "идей",
+    "идейка",
+    "идейку",
+    "идейных",
+    "идем",
+    "идемте",
+    "идет",
+    "идете",
+    "идеть",
+    "идешь",
+    "идею",
+    "идея",
+    "иди",
+    "идиллией",
+    "идиллий",
+    "идиот",
+    "идиотка",
+    "идиотством",
+    "идите",
+    "идола",
+    "идолов",
+    "идолом",
+    "идолу",
+    "идти",
+    "иду",
+    "идут",
+    "идучи",
+    "идущего",
+    "идущей",
+    "идущему",
+    "идущие",
+    "идущим",
+    "идущими",
+    "идущих",
+    "идущую",
+    "идя",
+    "иезуитов",
+    "иене",
+    "иерей",
+    "иероглифов",
+    "иерусалим",
+    "иерусалима",
+    "ижицы",
+    "из",
+    "изб",
+    "изба",
+    "избавил",
+    "избавились",
+    "избавите",
+    "избавить",
+    "избавлен",
+    "избавление",
+    "избавленным",
+    "избавлялись",
+    "избавляя",
+    "избавь",
+    "избаловали",
+    "избаловались",
+    "избаловался",
+    "избалован",
+    "избалованы",
+    "избах",
+    "избе",
+    "избегайте",
+    "избегал",
+    "избегала",
+    "избегали",
+    "избегать",
+    "избегая",
+    "избегнул",
+    "избегнуть",
+    "избежать",
+    "изберет",
+    "изберешь",
+    "избил",
+    "избитая",
+    "избито",
+    "избитой",
+    "избитую",
+    "избитых",
+    "избрав",
+    "избрал",
+    "избрала",
+    "избранной",
+    "избранном",
+    "избранные",
+    "избранный",
+    "избранных",
+    "избранья",
+    "избрать",
+    "избу",
+    "избудешь",
+    "избушке",
+    "избы",
+    "избытка",
+    "избытке",
+    "избыток",
+    "избыть",
+    "изведав",
+    "изведал",
+    "изведала",
+    "изведаны",
+    "изведать",
+    "изведешь",
+    "извел",
+    "изверг",
+    "изверга",
+    "извергнете",
+    "извергнуть",
+    "изверившись",
+    "изверились",
+    "известен",
+    "известившись",
+    "известие",
+    "известием",
+    "известий",
+    "известия",
+    "известиям",
+    "известиями",
+    "известка",
+    "известке",
+    "известковую",
+    "известная",
+    "известно",
+    "известного",
+    "известное",
+    "известной",
+    "известном",
+    "известному",
+    "известности",
+    "известность",
+    "известною",
+    "известную",
+    "известны",
+    "известные",
+    "известный",
+    "известным",
+    "известными",
+    "известных",
+    "известью",
+    "известясь",
+    "извечно",
+    "извечной",
+    "извещение",
+    "извещены",
+    "извив",
+    "извиваясь",
+    "извивом",
+    "извилистой",
+    "извинение",
+    "извинения",
+    "извини",
+    "извинилась",
+    "извинили",
+    "извинился",
+    "извините",
+    "извинить",
+    "извиниться",
+    "извинюсь",
+    "извиняешься",
+    "извинялся",
+    "извинят",
+    "извиняться",
+    "извиняюсь",
+    "извиняются",
+    "извиняя",
+    "извиняясь",
+    "извлек",
+    "извлекал",
+    "извлекли",
+    "извлеку",
+    "извлечь",
+    "извне",
+    "изводят",
+    "извозчик",
+    "извозчика",
+    "извозчикам",
+    "извозчике",
+    "извозчики",
+    "извозчиков",
+    "извозчиком",
+    "извозчику",
+    "изволив",
+    "изволил",
+    "изволила",
+    "изволили",
+    "изволит",
+    "изволите",
+    "изволь",
+    "извольте",
+    "изволят",
+    "изворачивался",
+    "изворачиваться",
+    "изворотиться",
+    "изворотливости",
+    "изворотов",
+    "извращенном",
+    "изгадил",
+    "изгадили",
+    "изгадит",
+    "изгиба",
+    "изгибе",
+    "изгладилась",
+    "изгладилось",
+    "изгнанник",
+    "изголовье",
+    "изголовья",
+    "изгонишь",
+    "изгоняется",
+    "изготовилась",
+    "изготовить",
+    "изготовлять",
+    "издавать",
+    "издавна",
+    "издалека",
+    "издали",
+    "издало",
+    "издание",
+    "издании",
+    "изданию",
+    "издания",
+    "изданьица",
+    "издателей",
+    "издатели",
+    "издательской",
+    "издательство",
+    "издателям",
+    "издать",
+    "издевается",
+    "издеваться",
+    "изделия",
+    "издерганы",
+    "издержанного",
+    "издержек",
+    "издержка",
+    "издержкам",
+    "издержками",
+    "издержке",
+    "издержки",
+    "издохнем",
+    "издохнуть",
+    "издрогнувший",
+    "издрогшая",
+    "издыхании",
+    "издыхающей",
+    "изжарил",
+    "изжога",
+    "иззяб",
+    "излагаете",
+    "излагал",
+    "излагать",
+    "излагающего",
+    "излагая",
+    "излер",
+    "излечивать",
+    "излив",
+    "изливавшейся",
+    "изливается",
+    "изливали",
+    "изливались",
+    "излить",
+    "излишек",
+    "излишне",
+    "излишней",
+    "излишнею",
+    "излишним",
+    "излишнюю",
+    "излияний",
+    "излияния",
+    "изловили",
+    "изловчусь",
+    "изложением",
+    "изложения",
+    "изложил",
+    "изложили",
+    "изложить",
+    "изложу",
+    "изломал",
+    "изломала",
+    "изломалась",
+    "изломались",
+    "изломан",
+    "изломанной",
+    "изломанными",
+    "изломанных",
+    "изломано",
+    "изломаться",
+    "излученье",
+    "измайлов",
+    "измалетства",
+    "измельчал",
+    "измен",
+    "измена",
+    "изменение",
+    "изменению",
+    "изменив",
+    "изменившимся",
+    "изменил",
+    "изменилась",
+    "изменили",
+    "изменились",
+    "изменилось",
+    "изменился",
+    "изменит",
+    "изменится",
+    "изменить",
+    "измениться",
+    "изменнику",
+    "изменница",
+    "изменю",
+    "изменюсь",
+    "изменяем",
+    "изменяет",
+    "изменяется",
+    "изменяла",
+    "изменят",
+    "изменятся",
+    "изменять",
+    "изменяющий",
+    "изменяя",
+    "измерили",
+    "измерит",
+    "измерить",
+    "измерял",
+    "измеряла",
+    "измеряли",
+    "измеряя",
+    "измокшее",
+    "измокшем",
+    "изморились",
+    "измучена",
+    "измученная",
+    "измученное",
+    "измученному",
+    "измученный",
+    "измучившееся",
+    "измучившись",
+    "измучил",
+    "измучилась",
+    "измучили",
+    "измучились",
+    "измучило",
+    "измучился",
+    "измучиться",
+    "измучишь",
+    "измучусь",
+    "измята",
+    "измятая",
+    "измято",
+    "измятую",
+    "измятые",
+    "измятый",
+    "изнеженному",
+    "изнеженные",
+    "изнеженным",
+    "изнемог",
+    "изнемогает",
+    "изнемогал",
+    "изнемогала",
+    "изнемогаю",
+    "изнемогают",
+    "изнемогая",
+    "изнемогли",
+    "изнеможение",
+    "изнеможении",
+    "изнеможения",
+    "изнемочь",
+    "износишь",
+    "изношенная",
+    "изношенного",
+    "изношенный",
+    "изношенными",
+    "изнурения",
+    "изнуренное",
+    "изнутри",
+    "изо",
+    "изобличена",
+    "изображавших",
+    "изображайте",
+    "изображал",
+    "изображать",
+    "изображают",
+    "изображен",
+    "изображена",
+    "изображение",
+    "изображу",
+    "изобрази",
+    "изобразилось",
+    "изобразительнее",
+    "изобразить",
+    "изобразят",
+    "изобретал",
+    "изобретатель",
+    "изобретений",
+    "изобретенном",
+    "изобьешь",
+    "изовраться",
+    "изодранные",
+    "изодранными",
+    "изойти",
+    "изорвал",
+    "изорваль",
+    "изорванное",
+    "изошла",
+    "изощрены",
+    "изощряется",
+    "израсходовано",
+    "изредка",
+    "изрек",
+    "изрекаете",
+    "изрекал",
+    "изрекала",
+    "изтопталь",
+    "изувера",
+    "изуверство",
+    "изукрашенною",
+    "изумил",
+    "изумились",
+    "изумился",
+    "изумительно",
+    "изумительного",
+    "изумительной",
+    "изумительною",
+    "изумительные",
+    "изумительный",
+    "изумится",
+    "изумить",
+    "изумлен",
+    "изумление",
+    "изумлением",
+    "изумлении",
+    "изумлению",
+    "изумления",
+    "изумленный",
+    "изумленными",
+    "изумленных",
+    "изуродованный",
+    "изустный",
+    "изучавший",
+    "изучал",
+    "изучать",
+    "изучая",
+    "изучение",
+    "изученно",
+    "изучил",
+    "изучила",
+    "изучить",
+    "изъездить",
+    "изъявил",
+    "изъявили",
+    "изъявления",
+    "изъявлял",
+    "изъяснил",
+    "изъяснить",
+    "изъясняю",
+    "изъятья",
+    "изысканная",
+    "изысканно",
+    "изыскать",
+    "изюминки",
+    "изюму",
+    "изяществом",
+    "изящна",
+    "изящная",
+    "изящно",
+    "изящного",
+    "изящной",
+    "изящную",
+    "изящны",
+    "изящные",
+    "изящный",
+    "изящным",
+    "изящных",
+    "иисус",
+    "иисусу",
+    "икнув",
+    "иконе",
+    "иконостас",
+    "икону",
+    "иконы",
+    "ил",
+    "или",
+    "илиаду",
+    "илистом",
+    "иль",
+    "илье",
+    "ильей",
+    "ильи",
+    "ильин",
+    "ильина",
+    "ильине",
+    "ильинична",
+    "ильинская",
+    "ильинские",
+    "ильинским",
+    "ильинскими",
+    "ильинских",
+    "ильинской",
+    "ильинскую",
+    "ильич",
+    "ильича",
+    "ильиче",
+    "ильичом",
+    "ильичу",
+    "илью",
+    "илья",
+    "илюша",
+    "илюше",
+    "илюши",
+    "илюшу",
+    "им",
+    "иматру",
+    "имевшая",
+    "имевшего",
+    "имевшей",
+    "имевшему",
+    "имевшею",
+    "имевшиеся",
+    "имеем",
+    "имеет",
+    "имеете",
+    "имеется",
+    "имеешь",
+    "имей",
+    "имейте",
+    "имел",
+    "имела",
+    "имели",
+    "имелись",
+    "имело",
+    "имелось",
+    "имен",
+    "имена",
+    "именами",
+    "именем",
+    "имени",
+    "имение",
+    "имением",
+    "имении",
+    "именин",
+    "именинам",
+    "именинах",
+    "именинники",
+    "именинница",
+    "именинницей",
+    "именины",
+    "имению",
+    "имения",
+    "именно",
+    "именьишко",
+    "иметь",
+    "имею",
+    "имеют",
+    "имеющего",
+    "имеющему",
+    "имеющий",
+    "имеющих",
+    "имея",
+    "ими",
+    "импровизирует",
+    "имущества",
+    "имущественные",
+    "имущество",
+    "имя",
+    "иначе",
+    "иная",
+    "инда",
+    "индеек",
+    "индейка",
+    "индейки",
+    "индейку",
+    "индейский",
+    "индивидуализма",
+    "индивидууму",
+    "индийского",
+    "индифферентизм",
+    "индию",
+    "инеем",
+    "иней",
+    "инициалы",
+    "инкрустацией",
+    "ино",
+    "иногда",
+    "иного",
+    "иное",
+    "иной",
+    "инока",
+    "иному",
+    "иностранец",
+    "иностранка",
+    "иностранного",
+    "иностранной",
+    "иностранные",
+    "иностранцам",
+    "иностранцами",
+    "иностранцем",
+    "иностранцы",
+    "иною",
+    "инспектором",
+    "инстанции",
+    "инстанцию",
+    "инстинкт",
+    "инстинкта",
+    "инстинктами",
+    "инстинктивно",
+    "инстинктивное",
+    "инстинктом",
+    "инстинкту",
+    "институт",
+    "института",
+    "институте",
+    "инструкцию",
+    "инструкция",
+    "инструмент",
+    "инструмента",
+    "инструментами",
+    "инструменте",
+    "инструментом",
+    "интегральное",
+    "интеллигента",
+    "интеллигентных",
+    "интеллигенцией",
+    "интеллигенции",
+    "интендантов",
+    "интерес",
+    "интереса",
+    "интересах",
+    "интересе",
+    "интересен",
+    "интересная",
+    "интереснейшем",
+    "интереснейшую",
+    "интересно",
+    "интересного",
+    "интересное",
+    "интересной",
+    "интересном",
+    "интересны",
+    "интересные",
+    "интересный",
+    "интересным",
+    "интересных",
+    "интересов",
+    "интересовал",
+    "интересовала",
+    "интересовало",
+    "интересовался",
+    "интересовать",
+    "интересоваться",
+    "интересом",
+    "интересует",
+    "интересуете",
+    "интересуетесь",
+    "интересуется",
+    "интересуюсь",
+    "интересуются",
+    "интересуясь",
+    "интересы",
+    "интимном",
+    "интимностей",
+    "интонацией",
+    "интонации",
+    "интриги",
+    "интриговать",
+    "интригует",
+    "ину",
+    "иную",
+    "инфлюэнца",
+    "информацию",
+    "иные",
+    "иным",
+    "иными",
+    "иных",
+    "иоаннова",
+    "иоган",
+    "ипохондрией",
+    "ипохондрик",
+    "ипохондрику",
+    "ипохондрическое",
+    "ипохондрию",
+    "ирина",
+    "ирине",
+    "ириной",
+    "ирину",
+    "ирины",
+    "ирис",
+    "ирисы",
+    "иронией",
+    "иронически",
+    "иронический",
+    "иртыша",
+    "исай",
+    "исая",
+    "искажал",
+    "искажать",
+    "искажая",
+    "искажение",
+    "искажением",
+    "искажению",
+    "искаженным",
+    "искажены",
+    "исказила",
+    "исказили",
+    "исказилось",
+    "исказить",
+    "искал",
+    "искала",
+    "искалеченной",
+    "искалеченным",
+    "искали",
+    "искам",
+    "исканий",
+    "искания",
+    "искательниц",
+    "искать",
+    "исключая",
+    "исключением",
+    "исключений",
+    "исключения",
+    "исключениями",
+    "исключительно",
+    "исключительное",
+    "исключительном",
+    "исключительный",
+    "исковеркана",
+    "исковерканную",
+    "исковеркано",
+    "искони",
+    "искоренено",
+    "искоренить",
+    "искореняет",
+    "искоса",
+    "искр",
+    "искра",
+    "искрами",
+    "искренен",
+    "искренна",
+    "искренне",
+    "искреннего",
+    "искреннее",
+    "искренней",
+    "искреннему",
+    "искренние",
+    "искренний",
+    "искренним",
+    "искренно",
+    "искренности",
+    "искренность",
+    "искренностью",
+    "искренняя",
+    "искривившеюся",
+    "искривившимися",
+    "искривившимся",
+    "искривила",
+    "искривилось",
+    "искривленном",
+    "искривленною",
+    "искривленный",
+    "искривленным",
+    "искривлено",
+    "искрились",
+    "искрой",
+    "искрометное",
+    "искры",
+    "искупался",
+    "искупит",
+    "искупительный",
+    "искупителя",
+    "искупить",
+    "искупление",
+    "искусно",
+    "искусным",
+    "искусными",
+    "искусств",
+    "искусства",
+    "искусствах",
+    "искусстве",
+    "искусственная",
+    "искусственно",
+    "искусственной",
+    "искусство",
+    "искусству",
+    "искушенье",
+    "испакостили",
+    "испанец",
+    "испанию",
+    "испанке",
+    "испанскую",
+    "испарения",
+    "испарениями",
+    "испачкав",
+    "испачкался",
+    "испейте",
+    "испекли",
+    "испекут",
+    "испепелить",
+    "испепеляешь",
+    "испепеляющие",
+    "испечет",
+    "исписанная",
+    "исписанной",
+    "исписанную",
+    "исписаны",
+    "испитое",
+    "испитым",
+    "испить",
+    "исповеди",
+    "исповедовал",
+    "исповедовала",
+    "исповедовать",
+    "исповедывал",
+    "исповедь",
+    "исповедью",
+    "исподлобья",
+    "исподтишка",
+    "исполинский",
+    "исполнен",
+    "исполнена",
+    "исполнение",
+    "исполнением",
+    "исполнении",
+    "исполнения",
+    "исполненного",
+    "исполненное",
+    "исполненной",
+    "исполненный",
+    "исполненных",
+    "исполнено",
+    "исполнены",
+    "исполнив",
+    "исполнил",
+    "исполнила",
+    "исполнились",
+    "исполнилось",
+    "исполнимость",
+    "исполнит",
+    "исполните",
+    "исполнитель",
+    "исполнителя",
+    "исполнится",
+    "исполнить",
+    "исполниться",
+    "исполнишь",
+    "исполню",
+    "исполняет",
+    "исполнял",
+    "исполняла",
+    "исполнять",
+    "исполняю",
+    "исполняют",
+    "исполняя",
+    "использован",
+    "использование",
+    "испортил",
+    "испортилось",
+    "испортите",
+    "испортить",
+    "испортишь",
+    "испорчен",
+    "испорченные",
+    "испорченный",
+    "испорчу",
+    "испошлился",
+    "исправился",
+    "исправимая",
+    "исправить",
+    "исправишь",
+    "исправлением",
+    "исправления",
+    "исправляет",
+    "исправлять",
+    "исправника",
+    "исправнику",
+    "исправно",
+    "исправным",
+    "исправных",
+    "испрашивать",
+    "испросив",
+    "испуг",
+    "испуга",
+    "испугавшись",
+    "испугается",
+    "испугал",
+    "испугалась",
+    "испугали",
+    "испугались",
+    "испугался",
+    "испугаль",
+    "испуган",
+    "испуганная",
+    "испуганно",
+    "испуганного",
+    "испуганное",
+    "испуганной",
+    "испуганном",
+    "испуганные",
+    "испуганный",
+    "испуганным",
+    "испуганными",
+    "испуганы",
+    "испугать",
+    "испугаться",
+    "испугаюсь",
+    "испуге",
+    "испугом",
+    "испугу",
+    "испужаешь",
+    "испужались",
+    "испускала",
+    "испуская",
+    "испустил",
+    "испытав",
+    "испытавшей",
+    "испытавший",
+    "испытал",
+    "испытала",
+    "испытание",
+    "испытаний",
+    "испытания",
+    "испытанные",
+    "испытанный",
+    "испытать",
+    "испытаю",
+    "испытующии",
+    "испытующим",
+    "испытывает",
+    "испытывал",
+    "испытывала",
+    "испытывали",
+    "испытывать",
+    "испытываю",
+    "исследования",
+    "исследованы",
+    "исследую",
+    "иссосали",
+    "иссохшее",
+    "иссохшей",
+    "иссохшие",
+    "иссохшими",
+    "иссохших",
+    "исстрадаешься",
+    "исстрадались",
+    "исстрадался",
+    "исступление",
+    "исступлении",
+    "исступлений",
+    "исступления",
+    "исступленно",
+    "исступленном",
+    "исступленному",
+    "истаскался",
+    "истасканное",
+    "истасканные",
+    "истасканных",
+    "истаял",
+    "истерзал",
+    "истерзала",
+    "истерзали",
+    "истерзан",
+    "истерзана",
+    "истерзанной",
+    "истерзанный",
+    "истерика",
+    "истерике",
+    "истерически",
+    "истерическим",
+    "истерических",
+    "истерического",
+    "истеричны",
+    "истечении",
+    "истин",
+    "истина",
+    "истинам",
+    "истине",
+    "истинно",
+    "истинное",
+    "истинной",
+    "истинном",
+    "истинные",
+    "истинный",
+    "истинным",
+    "истинных",
+    "истиной",
+    "истину",
+    "истины",
+    "истолкование",
+    "истолкования",
+    "истолкователя",
+    "истома",
+    "истоме",
+    "истомилась",
+    "исторг",
+    "историей",
+    "истории",
+    "историк",
+    "историческим",
+    "исторического",
+    "историю",
+    "история",
+    "источник",
+    "источника",
+    "источнике",
+    "источники",
+    "источников",
+    "истощался",
+    "истощена",
+    "истощенный",
+    "истощив",
+    "истощилась",
+    "истощился",
+    "истощится",
+    "истратила",
+    "истратили",
+    "истраченный",
+    "истраченных",
+    "истребил",
+    "истребить",
+    "истребленных",
+    "истребленья",
+    "истребляли",
+    "истреблять",
+    "истрепанная",
+    "истрепанное",
+    "истрепанном",
+    "истрепанных",
+    "истязал",
+    "истязаний",
+    "истязания",
+    "исход",
+    "исхода",
+    "исходе",
+    "исходить",
+    "исходя",
+    "исхудавшем",
+    "исхудалом",
+    "исцеленья",
+    "исцеляющую",
+    "исчахнет",
+    "исчез",
+    "исчезает",
+    "исчезал",
+    "исчезала",
+    "исчезали",
+    "исчезало",
+    "исчезать",
+    "исчезают",
+    "исчезла",
+    "исчезли",
+    "исчезло",
+    "исчезнет",
+    "исчезнете",
+    "исчезни",
+    "исчезнут",
+    "исчерпаешь",
+    "исчерпаны",
+    "исчерченных",
+    "исчисление",
+    "исчислил",
+    "исчислить",
+    "исшаркавшегося",
+    "ит",
+    "итак",
+    "итал",
+    "италии",
+    "италию",
+    "итальянски",
+    "итальянские",
+    "итальянский",
+    "итог",
+    "итога",
+    "итоги",
+    "итого",
+    "иуда",
+    "иудеев",
+    "иудеи",
+    "их",
+    "ихнего",
+    "ихнее",
+    "ихней",
+    "ихние",
+    "ихнюю",
+    "ихняя",
+    "ишь",
+    "ища",
+    "ищем",
+    "ищет",
+    "ищете",
+    "ищешь",
+    "ищи",
+    "ищите",
+    "ищу",
+    "ищут",
+    "ищущего",
+    "ищущие",
+    "июль",
+    "июля",
+    "июне",
+    "июнь",
+    "июня",
+    "й",
+    "йодоформ",
+    "к",
+    "ка",
+    "кабак",
+    "кабака",
+    "кабаке",
+    "кабаки",
+    "кабалу",
+    "кабацкой",
+    "кабачного",
+    "кабачным",
+    "кабинет",
+    "кабинета",
+    "кабинете",
+    "кабинетов",
+    "кабинетом",
+    "кабинету",
+    "каблук",
+    "каблуками",
+    "каблуки",
+    "каблучки",
+    "кабули",
+    "кабы",
+    "кавалер",
+    "кавалера",
+    "кавалерии",
+    "кавалеров",
+    "кавалеру",
+    "кавалеры",
+    "кавалькада",
+    "каватину",
+    "каватины",
+    "кавказе",
+    "кавказское",
+    "кавычках",
+    "кавычки",
+    "кадил",
+    "кадили",
+    "кадило",
+    "кадилом",
+    "кадильный",
+    "кадки",
+    "кадочка",
+    "кадочку",
+    "каждая",
+    "каждого",
+    "каждое",
+    "каждой",
+    "каждом",
+    "каждому",
+    "каждою",
+    "кажду",
+    "каждую",
+    "каждый",
+    "каждым",
+    "каждыми",
+    "кажет",
+    "кажетесь",
+    "кажется",
+    "кажешься",
+    "кажись",
+    "кажусь",
+    "кажутся",
+    "кажущуюся",
+    "казавшеюся",
+    "казавшийся",
+    "казак",
+    "казал",
+    "казалась",
+    "казались",
+    "казалось",
+    "казался",
+    "казанскою",
+    "казарм",
+    "казармах",
+    "казармой",
+    "казармы",
+    "казать",
+    "казаться",
+    "казачка",
+    "казачку",
+    "казачок",
+    "казенная",
+    "казенного",
+    "казенное",
+    "казенной",
+    "казенную",
+    "казенные",
+    "казенщину",
+    "казимир",
+    "казне",
+    "казней",
+    "казненных",
+    "казнены",
+    "казни",
+    "казнил",
+    "казнила",
+    "казнит",
+    "казните",
+    "казнить",
+    "казну",
+    "казны",
+    "казнь",
+    "казнью",
+    "казнят",
+    "казуистика",
+    "казуистики",
+    "казуистику",
+    "казус",
+    "кайма",
+    "как",
+    "какая",
+    "какие",
+    "каким",
+    "какими",
+    "каких",
+    "како",
+    "каков",
+    "какова",
+    "каково",
+    "каковы",
+    "каковые",
+    "какого",
+    "какое",
+    "какой",
+    "каком",
+    "какому",
+    "какою",
+    "кактусами",
+    "кактусов",
+    "каку",
+    "какую",
+    "кал",
+    "каламбуром",
+    "каланча",
+    "каланчой",
+    "калачей",
+    "калачом",
+    "калашный",
+    "калеб",
+    "калебов",
+    "калек",
+    "калека",
+    "календарем",
+    "календарь",
+    "календаря",
+    "калеными",
+    "калигула",
+    "калинникова",
+    "калитка",
+    "калитке",
+    "калитки",
+    "калитку",
+    "калиту",
+    "калош",
+    "калошах",
+    "калоши",
+    "камелии",
+    "камелий",
+    "камелиями",
+    "каменистом",
+    "каменистый",
+    "каменная",
+    "каменного",
+    "каменное",
+    "каменной",
+    "каменным",
+    "каменных",
+    "каменщик",
+    "камень",
+    "каменья",
+    "камер",
+    "камердинер",
+    "камердинером",
+    "камешек",
+    "камешкам",
+    "камешки",
+    "камином",
+    "камне",
+    "камней",
+    "камнем",
+    "камни",
+    "камню",
+    "камня",
+    "камням",
+    "камнях",
+    "каморка",
+    "каморке",
+    "каморки",
+    "каморку",
+    "кампанил",
+    "камушками",
+    "камфарой",
+    "камыш",
+    "камыша",
+    "камыши",
+    "канав",
+    "канавами",
+    "канаве",
+    "канавкой",
+    "канаву",
+    "канавы",
+    "канал",
+    "канала",
+    "каналы",
+    "каналью",
+    "каналья",
+    "канареек",
+    "канарейка",
+    "канарейками",
+    "канарейках",
+    "канарейки",
+    "канат",
+    "канве",
+    "канву",
+    "канвы",
+    "кандалов",
+    "канделябры",
+    "канет",
+    "канешь",
+    "каникулы",
+    "канкан",
+    "канканную",
+    "канул",
+    "канула",
+    "канцелярии",
+    "канцелярист",
+    "канцеляристов",
+    "канцеляристы",
+    "канцелярию",
+    "канцелярские",
+    "канцелярскими",
+    "канцоной",
+    "капали",
+    "капель",
+    "капельки",
+    "капельку",
+    "капернаумов",
+    "капернаумова",
+    "капернаумову",
+    "капернаумовых",
+    "капитал",
+    "капитала",
+    "капиталисты",
+    "капиталов",
+    "капиталом",
+    "капиталу",
+    "капиталы",
+    "капитальная",
+    "капитального",
+    "капитальное",
+    "капитальном",
+    "капитальному",
+    "капитальный",
+    "капитан",
+    "капитолии",
+    "капитэн",
+    "капкан",
+    "капле",
+    "каплей",
+    "каплет",
+    "капли",
+    "каплю",
+    "каплют",
+    "капля",
+    "каплям",
+    "каплями",
+    "капоте",
+    "каприз",
+    "каприза",
+    "капризам",
+    "капризах",
+    "капризен",
+    "капризная",
+    "капризница",
+    "капризничай",
+    "капризничал",
+    "капризничать",
+    "капризно",
+    "капризный",
+    "капризным",
+    "капризов",
+    "капризу",
+    "капризы",
+    "капсюль",
+    "капуста",
+    "капустой",
+    "капусту",
+    "капусты",
+    "кара",
+    "карабкался",
+    "карабкаясь",
+    "караван",
+    "карамзин",
+    "карандаш",
+    "карандашей",
+    "карандаши",
+    "карандашиком",
+    "карандашом",
+    "карать",
+    "караул",
+    "караулила",
+    "караулить",
+    "карашо",
+    "карающего",
+    "кардамонову",
+    "карет",
+    "карета",
+    "каретах",
+    "карете",
+    "каретного",
+    "каретное",
+    "каретой",
+    "карету",
+    "кареты",
+    "карий",
+    "карих",
+    "каркают",
+    "карлик",
+    "карловны",
+    "карлосу",
+    "карль",
+    "карман",
+    "кармана",
+    "карманам",
+    "карманах",
+    "кармане",
+    "карманном",
+    "карманные",
+    "карманный",
+    "карману",
+    "карманы",
+    "кармен",
+    "карменситы",
+    "карнизов",
+    "карп",
+    "карпатах",
+    "карпович",
+    "карт",
+    "карта",
+    "картавя",
+    "картам",
+    "картами",
+    "картах",
+    "карте",
+    "картежник",
+    "картин",
+    "картина",
+    "картинами",
+    "картине",
+    "картинка",
+    "картинки",
+    "картинно",
+    "картиной",
+    "картинок",
+    "картину",
+    "картины",
+    "картишки",
+    "картограмме",
+    "картограммой",
+    "картограмму",
+    "картограммы",
+    "картой",
+    "картонки",
+    "картонку",
+    "картофелем",
+    "картофель",
+    "картофелю",
+    "картофеля",
+    "карточек",
+    "карточка",
+    "карточку",
+    "карту",
+    "картуз",
+    "картуза",
+    "карты",
+    "кары",
+    "карьера",
+    "карьере",
+    "карьерой",
+    "карьеру",
+    "карьеры",
+    "касавшемуся",
+    "касавшийся",
+    "касается",
+    "касалась",
+    "касалось",
+    "касался",
+    "касаются",
+    "касающейся",
+    "касающемся",
+    "касающихся",
+    "касаясь",
+    "каске",
+    "каскетку",
+    "каски",
+    "кастаньет",
+    "кастелянши",
+    "кастрюль",
+    "кастрюлю",
+    "кастрюлями",
+    "катавшийся",
+    "катается",
+    "катай",
+    "катастроф",
+    "катастрофа",
+    "катастрофе",
+    "катастрофой",
+    "катастрофы",
+    "кататься",
+    "кате",
+    "катей",
+    "катерина",
+    "катерине",
+    "катериной",
+    "катериною",
+    "катерину",
+    "катерины",
+    "катехизис",
+    "кати",
+    "катила",
+    "катился",
+    "катит",
+    "каторгами",
+    "каторге",
+    "каторги",
+    "каторгу",
+    "каторжник",
+    "каторжного",
+    "каторжной",
+    "каторжные",
+    "каторжный",
+    "каторжных",
+    "катышек",
+    "катю",
+    "катя",
+    "катят",
+    "катятся",
+    "каучука",
+    "кафедру",
+    "кафедры",
+    "кафешантана",
+    "кафтан",
+    "кафтана",
+    "кафтане",
+    "кафтанов",
+    "кафэ",
+    "кацавеек",
+    "кацавейка",
+    "качавший",
+    "качает",
+    "качается",
+    "качал",
+    "качала",
+    "качали",
+    "качалка",
+    "качался",
+    "качанье",
+    "качаться",
+    "качающихся",
+    "качая",
+    "качели",
+    "качелях",
+    "качеств",
+    "качества",
+    "качествами",
+    "качествах",
+    "качестве",
+    "качнув",
+    "качнулся",
+    "качнусь",
+    "каша",
+    "кашей",
+    "кашель",
+    "каши",
+    "кашин",
+    "кашлем",
+    "кашля",
+    "кашляет",
+    "кашлял",
+    "кашляла",
+    "кашлянув",
+    "кашлянул",
+    "кашлять",
+    "кашляю",
+    "кашляя",
+    "каштановые",
+    "каюсь",
+    "каюта",
+    "каюте",
+    "каюту",
+    "каяться",
+    "квадратный",
+    "квадраты",
+    "квартал",
+    "квартала",
+    "квартале",
+    "квартального",
+    "квартальной",
+    "квартальному",
+    "квартальные",
+    "квартальный",
+    "квартальными",
+    "квартальных",
+    "кварташками",
+    "квартир",
+    "квартира",
+    "квартирам",
+    "квартирами",
+    "квартирах",
+    "квартире",
+    "квартиркам",
+    "квартирку",
+    "квартирная",
+    "квартирных",
+    "квартировать",
+    "квартирой",
+    "квартиру",
+    "квартиры",
+    "квас",
+    "квасом",
+    "квасу",
+    "квасцов",
+    "квасы",
+    "квентин",
+    "кверху",
+    "келье",
+    "келья",
+    "кем",
+    "кеплеровы",
+    "керченские",
+    "кивает",
+    "кивал",
+    "кивать",
+    "кивая",
+    "кивера",
+    "кивнув",
+    "кивнул",
+    "кивнула",
+    "кидает",
+    "кидается",
+    "кидал",
+    "кидала",
+    "кидать",
+    "кидаются",
+    "кидаясь",
+    "киев",
+    "киева",
+    "киеве",
+    "киевский",
+    "кий",
+    "кильватерной",
+    "кинематографе",
+    "кинжал",
+    "кинжалы",
+    "кину",
+    "кинув",
+    "кинул",
+    "кинулась",
+    "кинулись",
+    "кинулся",
+    "кинуто",
+    "кинутого",
+    "кинутые",
+    "кинуть",
+    "кинуться",
+    "киот",
+    "киотом",
+    "киоты",
+    "кипарисный",
+    "кипарисы",
+    "кипел",
+    "кипела",
+    "кипело",
+    "кипение",
+    "кипеть",
+    "кипит",
+    "кипсэком",
+    "кипучая",
+    "кипучими",
+    "кипят",
+    "кипятком",
+    "кипятят",
+    "кирбитьевна",
+    "кирбитьевне",
+    "кире",
+    "кирка",
+    "киркой",
+    "киркою",
+    "кирпич",
+    "кирпича",
+    "кирпичей",
+    "кирпичик",
+    "кирпичиков",
+    "кирпичный",
+    "кирпичных",
+    "кирсановский",
+    "кисеей",
+    "кисейная",
+    "кисейными",
+    "киселе",
+    "кисель",
+    "киселя",
+    "кислая",
+    "кисло",
+    "кислое",
+    "киснет",
+    "киссинген",
+    "кистей",
+    "кисти",
+    "кисть",
+    "кистью",
+    "китайских",
+    "китайского",
+    "китайцами",
+    "китах",
+    "кихотов",
+    "кичке",
+    "кичливости",
+    "кишат",
+    "кишела",
+    "кишели",
+    "кишки",
+    "клавикорды",
+    "клавиш",
+    "клавиши",
+    "клад",
+    "кладби",
+    "кладбища",
+    "кладбище",
+    "кладбищенской",
+    "кладбищу",
+    "кладет",
+    "кладете",
+    "кладите",
+    "кладку",
+    "кладовой",
+    "кладовую",
+    "кладом",
+    "кладу",
+    "кладут",
+    "кладя",
+    "клал",
+    "клала",
+    "кланявшиеся",
+    "кланяется",
+    "кланялась",
+    "кланялись",
+    "кланялся",
+    "кланяться",
+    "кланяются",
+    "кланяясь",
+    "кларнет",
+    "класса",
+    "классах",
+    "классе",
+    "класть",
+    "клеверными",
+    "клеверных",
+    "клевета",
+    "клевете",
+    "клеветник",
+    "клеветой",
+    "клевету",
+    "клеветы",
+    "клевещете",
+    "клевещут",
+    "клеенкой",
+    "клеенчатый",
+    "клейменые",
+    "клеймила",
+    "клеймили",
+    "клеймы",
+    "клеймят",
+    "клекот",
+    "клен",
+    "кленовые",
+    "клены",
+    "клетка",
+    "клетке",
+    "клетки",
+    "клетку",
+    "клетушка",
+    "клетушке",
+    "клетчатых",
+    "клешни",
+    "клещах",
+    "кликал",
+    "кликами",
+    "кликать",
+    "клике",
+    "клики",
+    "кликни",
+    "кликну",
+    "кликнул",
+    "кликнуть",
+    "климат",
+    "климата",
+    "климате",
+    "климатические",
+    "клинком",
+    "клинок",
+    "клич",
+    "кличет",
+    "кличешь",
+    "кличь",
+    "клоак",
+    "клоакам",
+    "клоаки",
+    "клобука",
+    "клок",
+    "клокотало",
+    "клокочут",
+    "клонил",
+    "клонилась",
+    "клонились",
+    "клонило",
+    "клонится",
+    "клоня",
+    "клонясь",
+    "клонятся",
+    "клопа",
+    "клопов",
+    "клопом",
+    "клопшток",
+    "клопы",
+    "клочка",
+    "клочками",
+    "клочке",
+    "клочки",
+    "клочком",
+    "клочок",
+    "клуб",
+    "клубами",
+    "клубе",
+    "клубный",
+    "клубов",
+    "клубок",
+    "клубы",
+    "клумбами",
+    "клюках",
+    "клюквы",
+    "клюкою",
+    "ключ",
+    "ключа",
+    "ключами",
+    "ключей",
+    "ключи",
+    "ключик",
+    "ключниц",
+    "ключница",
+    "ключом",
+    "клюют",
+    "кля",
+    "кляла",
+    "клялись",
+    "клялся",
+    "клянет",
+    "клянется",
+    "клянешь",
+    "кляни",
+    "клянусь",
+    "кляня",
+    "клясть",
+    "клясться",
+    "клятвам",
+    "клятвы",
+    "кляча",
+    "клячи",
+    "клячонка",
+    "клячу",
+    "кн",
+    "книг",
+    "книга",
+    "книгам",
+    "книгами",
+    "книгах",
+    "книге",
+    "книги",
+    "книгой",
+    "книгопродавец",
+    "книгопродавцы",
+    "книгу",
+    "книжек",
+    "книжечку",
+    "книжка",
+    "книжками",
+    "книжках",
+    "книжке",
+    "книжки",
+    "книжкой",
+    "книжку",
+    "книжной",
+    "книжные",
+    "книжный",
+    "книжонки",
+    "кнопа",
+    "кнопками",
+    "кнопку",
+    "кнут",
+    "кнута",
+    "кнутами",
+    "кнутов",
+    "кнутом",
+    "кнуту",
+    "кнуты",
+    "княгини",
+    "княгиня",
+    "княжеская",
+    "княжеский",
+    "княжеском",
+    "княжна",
+    "княжны",
+    "княжьего",
+    "князей",
+    "князь",
+    "князька",
+    "князья",
+    "князю",
+    "князя",
+    "ко",
+    "кобелева",
+    "кобыле",
+    "кобыленка",
+    "кобыленке",
+    "кобыленку",
+    "кобылиц",
+    "кобылица",
+    "кобылятниковым",
+    "ков",
+    "коварнее",
+    "коварно",
+    "коварную",
+    "коварные",
+    "коварства",
+    "коварство",
+    "коварству",
+    "кове",
+    "ковер",
+    "коверкала",
+    "коврам",
+    "коврами",
+    "коврах",
+    "ковре",
+    "коврик",
+    "ковров",
+    "ковром",
+    "ковру",
+    "ковры",
+    "ковчег",
+    "ковчега",
+    "ковчеге",
+    "ковша",
+    "ковы",
+    "ковылем",
+    "ковыли",
+    "ковыль",
+    "ковырять",
+    "когда",
+    "кого",
+    "когса",
+    "когти",
+    "когтит",
+    "когтями",
+    "когтях",
+    "кодексе",
+    "кое",
+    "коего",
+    "коему",
+    "кожаное",
+    "кожаном",
+    "кожаным",
+    "коже",
+    "кожи",
+    "коз",
+    "коза",
+    "козе",
+    "козел",
+    "козеля",
+    "козла",
+    "козловых",
+    "козлу",
+    "козни",
+    "козоедова",
+    "козы",
+    "козырев",
+    "козыри",
+    "козырьком",
+    "козьего",
+    "коим",
+    "коих",
+    "кой",
+    "кокардой",
+    "кокетку",
+    "кокетливо",
+    "кокетничает",
+    "кокетничаете",
+    "кокетничала",
+    "кокетничать",
+    "кокетничаю",
+    "кокетства",
+    "кокетстве",
+    "кокетство",
+    "кокетству",
+    "кол",
+    "кола",
+    "колбасница",
+    "колбасной",
+    "колбасы",
+    "колдовское",
+    "колдовства",
+    "колдун",
+    "колдуна",
+    "колдунья",
+    "колдуют",
+    "коле",
+    "колебалась",
+    "колебании",
+    "колебания",
+    "колеблется",
+    "колеблющейся",
+    "колеблющемся",
+    "колебля",
+    "колеблясь",
+    "колее",
+    "колеи",
+    "колен",
+    "колена",
+    "коленами",
+    "коленах",
+    "колене",
+    "коленей",
+    "колени",
+    "коленках",
+    "коленке",
+    "коленки",
+    "коленкоровые",
+    "коленку",
+    "колено",
+    "коленопреклоненным",
+    "коленочках",
+    "коленяк",
+    "коленям",
+    "коленями",
+    "коленях",
+    "колес",
+    "колеса",
+    "колесами",
+    "колесах",
+    "колеснице",
+    "колесо",
+    "колец",
+    "колечище",
+    "колечках",
+    "колечко",
+    "колечком",
+    "колею",
+    "колеями",
+    "коли",
+    "количестве",
+    "количество",
+    "количеством",
+    "колко",
+    "коллежский",
+    "коллежским",
+    "коллежского",
+    "коллежской",
+    "коллежскую",
+    "коллекцию",
+    "коллекция",
+    "колода",
+    "колодезь",
+    "колодец",
+    "колодник",
+    "колодой",
+    "колоду",
+    "колок",
+    "колокол",
+    "колокола",
+    "колоколов",
+    "колокольни",
+    "колокольный",
+    "колокольным",
+    "колокольня",
+    "колокольцы",
+    "колокольчик",
+    "колокольчика",
+    "колокольчики",
+    "колокольчиков",
+    "колокольчику",
+    "колола",
+    "кололись",
+    "коломенских",
+    "колонии",
+    "колоннами",
+    "колонной",
+    "колонны",
+    "колорит",
+    "колорита",
+    "колоритом",
+    "колос",
+    "колосья",
+    "колотилины",
+    "колотит",
+    "колотится",
+    "колотить",
+    "колотиться",
+    "колотушку",
+    "колоть",
+    "колоться",
+    "колотятся",
+    "колпак",
+    "колпаке",
+    "колпаки",
+    "колпаком",
+    "колпине",
+    "колумба",
+    "колхидой",
+    "колчан",
+    "колыбель",
+    "колыбелью",
+    "колымаге",
+    "колымягин",
+    "колыхалась",
+    "колыхаться",
+    "колыхаясь",
+    "коль",
+    "колька",
+    "кольца",
+    "кольце",
+    "кольцо",
+    "кольцом",
+    "кольцы",
+    "кольчугу",
+    "колю",
+    "колючие",
+    "колючий",
+    "колючих",
+    "коля",
+    "коляска",
+    "коляске",
+    "коляски",
+    "коляской",
+    "коляску",
+    "колясочке",
+    "колясочкой",
+    "колясочку",
+    "ком",
+    "команда",
+    "команде",
+    "командир",
+    "командиров",
+    "командировку",
+    "командиром",
+    "командовал",
+    "командор",
+    "командора",
+    "комары",
+    "комбинаций",
+    "комедии",
+    "комедию",
+    "комедия",
+    "комет",
+    "комета",
+    "кометы",
+    "комизм",
+    "комик",
+    "комиссии",
+    "комиссию",
+    "комиссия",
+    "комическая",
+    "комические",
+    "комической",
+    "комическую",
+    "комкая",
+    "комком",
+    "комку",
+    "коммерческий",
+    "коммиссаржевской",
+    "коммун",
+    "коммуна",
+    "коммуне",
+    "коммуну",
+    "коммуны",
+    "комнат",
+    "комната",
+    "комнатам",
+    "комнатах",
+    "комнате",
+    "комнатка",
+    "комнатке",
+    "комнатки",
+    "комнатку",
+    "комнатном",
+    "комнату",
+    "комнаты",
+    "комод",
+    "комода",
+    "комоде",
+    "комоду",
+    "комок",
+    "компанейские",
+    "компанией",
+    "компании",
+    "компанию",
+    "компания",
+    "компаньоном",
+    "компаньоны",
+    "комплекции",
+    "комплимент",
+    "комплимента",
+    "комплиментов",
+    "комплименты",
+    "компромиссов",
+    "кому",
+    "комфорт",
+    "комфорта",
+    "комфорте",
+    "комфортно",
+    "конверт",
+    "конвоем",
+    "конвойного",
+    "конвойный",
+    "конвульсии",
+    "конго",
+    "конгрессов",
+    "кондитера",
+    "кондитерской",
+    "кондрашки",
+    "кондуктор",
+    "коне",
+    "коней",
+    "конец",
+    "конечно",
+    "конечные",
+    "конник",
+    "конница",
+    "консервативное",
+    "консервативные",
+    "консерватории",
+    "консилиум",
+    "конская",
+    "конский",
+    "конских",
+    "конспиративным",
+    "констан",
+    "константин",
+    "константина",
+    "константином",
+    "константинополь",
+    "константину",
+    "конституцией",
+    "конституций",
+    "консультации",
+    "контор",
+    "контора",
+    "конторах",
+    "конторе",
+    "конторка",
+    "конторскую",
+    "контору",
+    "конторщик",
+    "конторщика",
+    "конторы",
+    "контрабас",
+    "контракт",
+    "контракта",
+    "контракте",
+    "контрактом",
+    "контракту",
+    "контракты",
+    "контральтом",
+    "контрдансов",
+    "контролем",
+    "контру",
+    "конур",
+    "конуре",
+    "конуру",
+    "конуры",
+    "конус",
+    "конфекты",
+    "конфетами",
+    "конфузился",
+    "конфузится",
+    "конфузишься",
+    "конфузливо",
+    "конфузливы",
+    "конфузьтесь",
+    "конфузятся",
+    "конца",
+    "концам",
+    "концах",
+    "конце",
+    "концентрировалась",
+    "концентрических",
+    "концепция",
+    "концерт",
+    "концертах",
+    "концерте",
+    "концов",
+    "концом",
+    "концу",
+    "концы",
+    "кончается",
+    "кончалась",
+    "кончались",
+    "кончалось",
+    "кончался",
+    "кончатся",
+    "кончать",
+    "кончаю",
+    "кончаются",
+    "кончен",
+    "кончена",
+    "кончено",
+    "кончив",
+    "кончивших",
+    "кончик",
+    "кончике",
+    "кончики",
+    "кончиком",
+    "кончил",
+    "кончила",
+    "кончилась",
+    "кончили",
+    "кончились",
+    "кончилось",
+    "кончился",
+    "кончим",
+    "кончит",
+    "кончится",
+    "кончить",
+    "кончиться",
+    "кончишь",
+    "кончу",
+    "конь",
+    "коньке",
+    "коньяк",
+    "коньяком",
+    "коньяку",
+    "коньячком",
+    "коню",
+    "конюшен",
+    "конюшенную",
+    "конюшне",
+    "конюшни",
+    "конюшню",
+    "конюшня",
+    "коня",
+    "копаю",
+    "копают",
+    "копеек",
+    "копеечки",
+    "копейка",
+    "копейками",
+    "копейке",
+    "копейки",
+    "копейкой",
+    "копейку",
+    "копен",
+    "коперник",
+    "коперника",
+    "копи",
+    "копии",
+    "копит",
+    "копить",
+    "копиться",
+    "копию",
+    "копна",
+    "копоть",
+    "копошились",
+    "копыт",
+    "копыта",
+    "копытами",
+    "копытом",
+    "корабли",
+    "корабль",
+    "кораблю",
+    "корабля",
+    "кораллов",
+    "кордегардии",
+    "корделией",
+    "корделии",
+    "корделия",
+    "коренной",
+    "коренные",
+    "коренным",
+    "коренными",
+    "коренных",
+    "кореньями",
+    "корешки",
+    "корзине",
+    "корзинку",
+    "корзиной",
+    "корзиночкой",
+    "корзину",
+    "корзины",
+    "коридор",
+    "коридора",
+    "коридоре",
+    "коридоров",
+    "коридором",
+    "коридору",
+    "корит",
+    "корица",
+    "корицей",
+    "корицу",
+    "корицы",
+    "коричневого",
+    "коричневое",
+    "коричневой",
+    "коричневыми",
+    "корка",
+    "корки",
+    "кормами",
+    "корми",
+    "кормил",
+    "кормилец",
+    "кормили",
+    "кормилица",
+    "кормит",
+    "кормить",
+    "кормишь",
+    "кормление",
+    "кормят",
+    "корней",
+    "корнелия",
+    "корнем",
+    "корнета",
+    "корнету",
+    "корни",
+    "корню",
+    "коробит",
+    "коробить",
+    "коробиться",
+    "коробка",
+    "коробке",
+    "коробки",
+    "коробку",
+    "коробочками",
+    "коробочке",
+    "коробочки",
+    "коробясь",
+    "корова",
+    "коровам",
+    "корове",
+    "коровник",
+    "корову",
+    "коровы",
+    "коровье",
+    "коровьими",
+    "корок",
+    "королева",
+    "королевой",
+    "король",
+    "короля",
+    "короне",
+    "корону",
+    "короны",
+    "коротаю",
+    "коротенькие",
+    "коротенький",
+    "коротеньким",
+    "коротенькой",
+    "коротеньком",
+    "коротки",
+    "короткий",
+    "короткими",
+    "коротких",
+    "коротко",
+    "короткого",
+    "короткое",
+    "коротком",
+    "короче",
+    "корпию",
+    "корпус",
+    "корпуса",
+    "корпусом",
+    "корреджиевой",
+    "корректна",
+    "корректуру",
+    "корреспонденции",
+    "корреспонденция",
+    "корточках",
+    "корчаги",
+    "корчит",
+    "коршун",
+    "коршуну",
+    "коры",
+    "корыстолюбивых",
+    "корысть",
+    "корыта",
+    "корытами",
+    "корыте",
+    "корыто",
+    "корь",
+    "кос",
+    "коса",
+    "косами",
+    "косвенно",
+    "косенький",
+    "косится",
+    "косичку",
+    "космос",
+    "коснел",
+    "косности",
+    "косноязычен",
+    "косноязычная",
+    "косноязычное",
+    "косноязычные",
+    "коснувшись",
+    "коснулись",
+    "коснулось",
+    "коснулся",
+    "коснуться",
+    "косо",
+    "косоворотке",
+    "косоворотку",
+    "косой",
+    "косоплеткой",
+    "косою",
+    "косте",
+    "костей",
+    "костер",
+    "кости",
+    "костлявой",
+    "косточкам",
+    "косточкой",
+    "косточку",
+    "костра",
+    "кострами",
+    "костров",
+    "кость",
+    "костюм",
+    "костюма",
+    "костюмах",
+    "костюме",
+    "костюмов",
+    "костюмом",
+    "костюму",
+    "костюшку",
+    "костя",
+    "костяной",
+    "костяшек",
+    "косы",
+    "косым",
+    "косынка",
+    "косынкой",
+    "косынку",
+    "косынок",
+    "косыночка",
+    "косыночки",
+    "косыночку",
+    "косяком",
+    "косясь",
+    "косят",
+    "косятся",
+    "кот",
+    "кота",
+    "котенка",
+    "котенком",
+    "котеночка",
+    "котлет",
+    "котлетам",
+    "котлетки",
+    "котлеты",
+    "котомку",
+    "которая",
+    "которого",
+    "которое",
+    "которой",
+    "котором",
+    "которому",
+    "которою",
+    "которую",
+    "которые",
+    "который",
+    "которым",
+    "которыми",
+    "которых",
+    "коттедж",
+    "коттень",
+    "коты",
+    "кофе",
+    "кофеем",
+    "кофеи",
+    "кофей",
+    "кофейник",
+    "кофейника",
+    "кофейником",
+    "кофейнику",
+    "кофейной",
+    "кофею",
+    "кофеями",
+    "кофий",
+    "кофточка",
+    "кофточки",
+    "кофточку",
+    "кох",
+    "коха",
+    "кохане",
+    "кохом",
+    "кочевые",
+    "коченеют",
+    "кочергой",
+    "кочкам",
+    "кочки",
+    "кочующих",
+    "кошачьи",
+    "кошек",
+    "кошелек",
+    "кошелька",
+    "кошельке",
+    "кошемар",
+    "кошка",
+    "кошки",
+    "кошкой",
+    "кошмар",
+    "кощунственных",
+    "кра",
+    "краб",
+    "краденое",
+    "крадеными",
+    "крадешь",
+    "краев",
+    "краем",
+    "краешком",
+    "кража",
+    "краи",
+    "край",
+    "крайне",
+    "крайнее",
+    "крайней",
+    "крайний",
+    "крайних",
+    "крайностей",
+    "крайности",
+    "крайность",
+    "крайностях",
+    "крайнюю",
+    "крако",
+    "крал",
+    "кран",
+    "крапиве",
+    "крапивой",
+    "крапиву",
+    "крапинками",
+    "краса",
+    "красавец",
+    "красавиц",
+    "красавица",
+    "красавицам",
+    "красавице",
+    "красавицей",
+    "красавицы",
+    "красавцем",
+    "красе",
+    "красен",
+    "красив",
+    "красива",
+    "красивая",
+    "красивее",
+    "красивенькая",
+    "красиво",
+    "красивое",
+    "красивой",
+    "красивом",
+    "красивую",
+    "красивы",
+    "красивые",
+    "красивый",
+    "красивыми",
+    "красивых",
+    "красил",
+    "красили",
+    "красильщик",
+    "красильщика",
+    "красильщике",
+    "красильщики",
+    "красильщиков",
+    "красит",
+    "красить",
+    "краска",
+    "красками",
+    "красках",
+    "краске",
+    "краски",
+    "краской",
+    "краскою",
+    "краску",
+    "красная",
+    "краснеет",
+    "краснеешь",
+    "краснел",
+    "краснела",
+    "красненькие",
+    "красненький",
+    "красненькой",
+    "красненькую",
+    "краснеть",
+    "краснею",
+    "краснеющим",
+    "краснея",
+    "красно",
+    "красноватое",
+    "красноваты",
+    "красноватые",
+    "красноватый",
+    "красноватыми",
+    "красного",
+    "красное",
+    "красной",
+    "красном",
+    "красному",
+    "красноречиво",
+    "красноречивое",
+    "красноречивые",
+    "красноречие",
+    "краснощекая",
+    "красною",
+    "красную",
+    "красны",
+    "красные",
+    "красный",
+    "красным",
+    "красными",
+    "красных",
+    "красовался",
+    "красой",
+    "красок",
+    "красот",
+    "красота",
+    "красоте",
+    "красотой",
+    "красотою",
+    "красоту",
+    "красоты",
+    "красочны",
+    "красть",
+    "красу",
+    "красы",
+    "кратер",
+    "краткие",
+    "кратко",
+    "кратковременного",
+    "краткости",
+    "краткую",
+    "кратчайшим",
+    "крахмалит",
+    "крашеные",
+    "крашеный",
+    "краю",
+    "края",
+    "краям",
+    "краях",
+    "кредит",
+    "кредитивные",
+    "кредитками",
+    "кредитке",
+    "кредитки",
+    "кредитку",
+    "кредитный",
+    "кредитными",
+    "кредитовать",
+    "кредиток",
+    "кредиторшей",
+    "кредиту",
+    "кремля",
+    "кремнист",
+    "кренделей",
+    "крендельков",
+    "кренделями",
+    "крепилась",
+    "крепили",
+    "крепился",
+    "крепитесь",
+    "крепится",
+    "крепка",
+    "крепкие",
+    "крепкий",
+    "крепким",
+    "крепко",
+    "крепкое",
+    "крепкой",
+    "крепкую",
+    "крепнет",
+    "крепок",
+    "крепости",
+    "крепостник",
+    "крепостники",
+    "крепостного",
+    "крепостном",
+    "крепостные",
+    "крепостным",
+    "крепостных",
+    "крепость",
+    "крепостью",
+    "крепчал",
+    "крепче",
+    "крепь",
+    "крепясь",
+    "кресел",
+    "кресла",
+    "креслами",
+    "креслах",
+    "кресле",
+    "кресло",
+    "креслом",
+    "крест",
+    "креста",
+    "крестами",
+    "кресте",
+    "крестик",
+    "крестил",
+    "крестила",
+    "крестилась",
+    "крестили",
+    "крестился",
+    "крестин",
+    "крестины",
+    "крестит",
+    "крестится",
+    "крестить",
+    "крестненький",
+    "крестную",
+    "крестным",
+    "крестов",
+    "крестовском",
+    "крестовые",
+    "крестом",
+    "кресту",
+    "кресты",
+    "крестьян",
+    "крестьянам",
+    "крестьянами",
+    "крестьяне",
+    "крестьянин",
+    "крестьянина",
+    "крестьянка",
+    "крестьянская",
+    "крестьянских",
+    "крестьянской",
+    "крестясь",
+    "крещены",
+    "крещенье",
+    "крещенью",
+    "кривая",
+    "кривизну",
+    "кривизны",
+    "кривила",
+    "кривились",
+    "криво",
+    "кривой",
+    "кривом",
+    "кривы",
+    "кривыми",
+    "кризис",
+    "крик",
+    "крика",
+    "криками",
+    "криках",
+    "крике",
+    "крики",
+    "крикливо",
+    "крикнет",
+    "крикнешь",
+    "крикнул",
+    "крикнула",
+    "крикнуть",
+    "криков",
+    "криком",
+    "крику",
+    "кринолине",
+    "кристаль",
+    "кристально",
+    "критик",
+    "критики",
+    "критиков",
+    "критиковать",
+    "крича",
+    "кричал",
+    "кричала",
+    "кричали",
+    "кричаль",
+    "кричат",
+    "кричать",
+    "кричащем",
+    "кричи",
+    "кричит",
+    "кричите",
+    "кричишь",
+    "кричу",
+    "кров",
+    "кровавая",
+    "кровавиться",
+    "кровавого",
+    "кровавой",
+    "кровавый",
+    "кровавых",
+    "кровати",
+    "кроватка",
+    "кроватке",
+    "кровать",
+    "кроватью",
+    "кроватями",
+    "кровель",
+    "крови",
+    "кровию",
+    "кровлей",
+    "кровлю",
+    "кровля",
+    "кровлями",
+    "кровная",
+    "кровное",
+    "кровные",
+    "кровный",
+    "кровом",
+    "кровообращение",
+    "кровопроливцы",
+    "кровопролитий",
+    "кровотечение",
+    "кровь",
+    "кровью",
+    "кроется",
+    "кроила",
+    "кроить",
+    "крокета",
+    "крокодилов",
+    "кроликов",
+    "кроме",
+    "кроне",
+    "крот",
+    "кроткая",
+    "кроткие",
+    "кроткий",
+    "кротким",
+    "кроткими",
+    "кротких",
+    "кротко",
+    "кроткого",
+    "кроткое",
+    "кроток",
+    "кротости",
+    "кротостию",
+    "кротость",
+    "кротостью",
+    "крошат",
+    "крошеные",
+    "крошечная",
+    "крошечное",
+    "крошечную",
+    "крошечные",
+    "крошить",
+    "крошка",
+    "крошки",
+    "кру",
+    "круазе",
+    "круг",
+    "круга",
+    "кругам",
+    "кругами",
+    "кругах",
+    "круге",
+    "круги",
+    "кругла",
+    "круглая",
+    "кругленькие",
+    "круглого",
+    "круглое",
+    "круглой",
+    "круглолицая",
+    "круглом",
+    "круглую",
+    "круглые",
+    "круглый",
+    "круглым",
+    "круглыми",
+    "круглых",
+    "кругов",
+    "круговорот",
+    "круговорота",
+    "кругом",
+    "кругу",
+    "кружатся",
+    "кружащаяся",
+    "кружащийся",
+    "кружев",
+    "кружева",
+    "кружевами",
+    "кружевах",
+    "кружевною",
+    "кружевных",
+    "кружево",
+    "кружевцом",
+    "круженье",
+    "круженьем",
+    "круженья",
+    "кружи",
+    "кружилась",
+    "кружились",
+    "кружит",
+    "кружится",
+    "кружить",
+    "кружиться",
+    "кружках",
+    "кружке",
+    "кружки",
+    "кружку",
+    "кружок",
+    "крупно",
+    "крупного",
+    "крупной",
+    "крупные",
+    "крупным",
+    "крупными",
+    "крупных",
+    "крупой",
+    "крупы",
+    "крутая",
+    "крутившимся",
+    "крутизну",
+    "крутились",
+    "крутился",
+    "круто",
+    "крутого",
+    "крутой",
+    "круторогий",
+    "крутым",
+    "крутыми",
+    "крутясь",
+    "кручами",
+    "круче",
+    "кручи",
+    "кручину",
+    "круша",
+    "крушить",
+    "крыл",
+    "крыла",
+    "крылами",
+    "крылатое",
+    "крылатый",
+    "крылах",
+    "крылий",
+    "крылись",
+    "крыло",
+    "крылова",
+    "крылом",
+    "крыльев",
+    "крыльем",
+    "крыльца",
+    "крыльце",
+    "крыльцо",
+    "крыльцом",
+    "крыльцу",
+    "крылья",
+    "крыльями",
+    "крыльях",
+    "крым",
+    "крыма",
+    "крыс",
+    "крысах",
+    "крысиную",
+    "крысой",
+    "крысы",
+    "крытая",
+    "крытой",
+    "крытые",
+    "крыш",
+    "крыше",
+    "крышей",
+    "крыши",
+    "крышку",
+    "крышу",
+    "крюк",
+    "крюков",
+    "крюком",
+    "крюку",
+    "крючка",
+    "крючках",
+    "крючке",
+    "крючком",
+    "крючкотворец",
+    "крючок",
+    "крючочки",
+    "крякнет",
+    "крякнул",
+    "кряхтела",
+    "кряхтит",
+    "кряхтите",
+    "кряхтя",
+    "кстати",
+    "ксюша",
+    "кто",
+    "кувшинами",
+    "кувыркнулся",
+    "кувырком",
+    "куда",
+    "кудахтает",
+    "кудахтал",
+    "кудахтанье",
+    "кудахтаньем",
+    "кудахтающие",
+    "кудель",
+    "кудрей",
+    "кудри",
+    "кудрино",
+    "кудрявой",
+    "кудрями",
+    "куды",
+    "кузины",
+    "кузнец",
+    "кузнеца",
+    "кузнецов",
+    "кузнецу",
+    "кузнечики",
+    "кузница",
+    "кузовковы",
+    "кузьку",
+    "кузьминична",
+    "куклы",
+    "куколку",
+    "кукушка",
+    "кулак",
+    "кулаками",
+    "кулаке",
+    "кулаки",
+    "кулаком",
+    "кулачонками",
+    "кулебяк",
+    "кулебяка",
+    "кулебяки",
+    "куликовом",
+    "куликовым",
+    "кулинарной",
+    "кулиса",
+    "кулыгин",
+    "кулыгина",
+    "кулыгину",
+    "кулыгиным",
+    "кулькова",
+    "кульком",
+    "культурные",
+    "кум",
+    "кума",
+    "кумачах",
+    "куме",
+    "кумир",
+    "кумирню",
+    "кумиром",
+    "кумиры",
+    "кумой",
+    "кумом",
+    "куму",
+    "кумы",
+    "купала",
+    "купалась",
+    "купались",
+    "купальне",
+    "купальню",
+    "купальня",
+    "купания",
+    "купаньем",
+    "купаться",
+    "купец",
+    "купеческие",
+    "купеческих",
+    "купи",
+    "купидонов",
+    "купил",
+    "купила",
+    "купит",
+    "купить",
+    "купишь",
+    "куплен",
+    "куплена",
+    "купленное",
+    "купленную",
+    "купленные",
+    "купленными",
+    "куплено",
+    "куплю",
+    "купол",
+    "куполом",
+    "купоны",
+    "купца",
+    "купцам",
+    "купцах",
+    "купцов",
+    "купцу",
+    "купцы",
+    "купчик",
+    "купчиха",
+    "кур",
+    "куражился",
+    "курами",
+    "куранты",
+    "куреньями",
+    "курил",
+    "курилась",
+    "курился",
+    "куриная",
+    "куриную",
+    "курит",
+    "курите",
+    "курить",
+    "курица",
+    "курице",
+    "курицей",
+    "курицу",
+    "курносое",
+    "курносые",
+    "курок",
+    "куролесил",
+    "куропатки",
+    "курс",
+    "курса",
+    "курсистка",
+    "курске",
+    "куртины",
+    "куртке",
+    "куртки",
+    "куртку",
+    "курточки",
+    "курточку",
+    "курчавою",
+    "курчавый",
+    "куры",
+    "курьез",
+    "курьезу",
+    "курьер",
+    "курьера",
+    "курьерский",
+    "курьеру",
+    "курю",
+    "куря",
+    "курятникам",
+    "курятники",
+    "курящейся",
+    "курящих",
+    "кусали",
+    "кусая",
+    "куска",
+    "кускам",
+    "куски",
+    "куском",
+    "кусок",
+    "кусочек",
+    "кусочка",
+    "кусочкам",
+    "кусочками",
+    "кусочки",
+    "куст",
+    "кустам",
+    "кустами",
+    "кустарник",
+    "кустах",
+    "кусте",
+    "кустика",
+    "кустов",
+    "кустом",
+    "кусты",
+    "кутает",
+    "кутались",
+    "кутаясь",
+    "кутит",
+    "кутьи",
+    "кутью",
+    "кутья",
+    "кухарка",
+    "кухарками",
+    "кухарках",
+    "кухарке",
+    "кухарки",
+    "кухаркой",
+    "кухарку",
+    "кухарок",
+    "кухмистерской",
+    "кухне",
+    "кухней",
+    "кухни",
+    "кухню",
+    "кухня",
+    "кухням",
+    "кухонной",
+    "кухонный",
+    "куцой",
+    "куча",
+    "куче",
+    "кучей",
+    "кучер",
+    "кучера",
+    "кучерам",
+    "кучерами",
+    "кучере",
+    "кучером",
+    "кучеру",
+    "кучи",
+    "кучка",
+    "кучками",
+    "кучки",
+    "кучку",
+    "кучу",
+    "кушает",
+    "кушай",
+    "кушак",
+    "кушаком",
+    "кушал",
+    "кушала",
+    "кушало",
+    "кушанье",
+    "кушанья",
+    "кушать",
+    "кушаю",
+    "кушают",
+    "кушетке",
+    "кушетку",
+    "куши",
+    "куще",
+    "кующей",
+    "кхе",
+    "кхи",
+    "л",
+    "ла",
+    "лабаз",
+    "лабазника",
+    "лабазнике",
+    "лабазнику",
+    "лабиринт",
+    "лавиза",
+    "лавизе",
+    "лавизу",
+    "лавизы",
+    "лавину",
+    "лавкам",
+    "лавках",
+    "лавке",
+    "лавки",
+    "лавкой",
+    "лавку",
+    "лавочек",
+    "лавочка",
+    "лавочкам",
+    "лавочках",
+    "лавочке",
+    "лавочки",
+    "лавочку",
+    "лавочник",
+    "лавочники",
+    "лавочнику",
+    "лавочные",
+    "лавр",
+    "лаврентий",
+    "лаврентия",
+    "лавром",
+    "лавры",
+    "лагере",
+    "лагерь",
+    "лагуне",
+    "лагуны",
+    "лад",
+    "ладан",
+    "ладах",
+    "ладил",
+    "ладить",
+    "ладно",
+    "ладони",
+    "ладонь",
+    "ладонью",
+    "ладонями",
+    "лады",
+    "ладью",
+    "лае",
+    "лаем",
+    "лает",
+    "лаетесь",
+    "лается",
+    "лазаревич",
+    "лазарь",
+    "лазаря",
+    "лазил",
+    "лазить",
+    "лазури",
+    "лазурной",
+    "лазурь",
+    "лай",
+    "лайдак",
+    "лайтесь",
+    "лакедемонов",
+    "лакее",
+    "лакеев",
+    "лакеи",
+    "лакей",
+    "лакейским",
+    "лакейской",
+    "лакейскому",
+    "лакейскую",
+    "лакейщину",
+    "лакею",
+    "лакея",
+    "лакеям",
+    "лакированные",
+    "лакомств",
+    "лампа",
+    "лампада",
+    "лампадки",
+    "лампадой",
+    "лампе",
+    "лампой",
+    "лампу",
+    "лампы",
+    "лангвагеном",
+    "лангвагену",
+    "ландышах",
+    "ландышей",
+    "ландыши",
+    "ланитам",
+    "ланиты",
+    "лапах",
+    "лаптев",
+    "лаптей",
+    "лапу",
+    "лапши",
+    "лапшу",
+    "ларец",
+    "ларцов",
+    "лас",
+    "ласк",
+    "ласка",
+    "ласкает",
+    "ласкал",
+    "ласкала",
+    "ласкалась",
+    "ласками",
+    "ласкательный",
+    "ласках",
+    "ласкаю",
+    "ласкают",
+    "ласкаются",
+    "ласкающая",
+    "ласкающем",
+    "ласкающую",
+    "лаская",
+    "ласкаясь",
+    "ласке",
+    "ласки",
+    "ласков",
+    "ласковая",
+    "ласковее",
+    "ласково",
+    "ласкового",
+    "ласковость",
+    "ласковы",
+    "ласковые",
+    "ласковый",
+    "ласковым",
+    "ласковых",
+    "лаской",
+    "ласку",
+    "ласточка",
+    "ласточкино",
+    "лат",
+    "латах",
+    "латник",
+    "латыни",
+    "латынскую",
+    "латынью",
+    "латыши",
+    "лафитом",
+    "лачуг",
+    "лая",
+    "лаяла",
+    "лаять",
+    "лба",
+    "лбов",
+    "лбом",
+    "лбу",
+    "лгал",
+    "лгала",
+    "лгали",
+    "лгать",
+    "лги",
+    "лгу",
+    "лгунишка",
+    "лгунью",
+    "лгут",
+    "лгущие",
+    "лебедей",
+    "лебеди",
+    "лебедь",
+    "лебезил",
+    "лебезятников",
+    "лебезятникова",
+    "лебезятникове",
+    "лебезятникову",
+    "лебезятниковым",
+    "левая",
+    "левого",
+    "левой",
+    "левом",
+    "левою",
+    "левую",
+    "левый",
+    "левым",
+    "лег",
+    "легавая",
+    "легион",
+    "легиона",
+    "легистраторша",
+    "легка",
+    "легкая",
+    "легкие",
+    "легкий",
+    "легким",
+    "легкими",
+    "легких",
+    "легко",
+    "легковерная",
+    "легковерные",
+    "легкого",
+    "легкое",
+    "легкой",
+    "легком",
+    "легкомыслен",
+    "легкомысленнее",
+    "легкомысленно",
+    "легкомысленного",
+    "легкомысленное",
+    "легкомысленной",
+    "легкомысленных",
+    "легкомыслие",
+    "легкомыслием",
+    "легкомыслии",
+    "легкомыслия",
+    "легкоперой",
+    "легкою",
+    "легкую",
+    "легла",
+    "легли",
+    "легло",
+    "легок",
+    "легонько",
+    "легче",
+    "лед",
+    "леденец",
+    "леденея",
+    "леденит",
+    "леденцами",
+    "леденцах",
+    "леденящий",
+    "леди",
+    "ледком",
+    "ледоход",
+    "ледяная",
+    "лежа",
+    "лежавшего",
+    "лежавшее",
+    "лежавшие",
+    "лежавший",
+    "лежавшим",
+    "лежавшими",
+    "лежавших",
+    "лежавшую",
+    "лежал",
+    "лежала",
+    "лежали",
+    "лежало",
+    "лежанке",
+    "лежанки",
+    "лежанку",
+    "лежанок",
+    "лежанье",
+    "лежаньем",
+    "лежанья",
+    "лежат",
+    "лежать",
+    "лежащего",
+    "лежащий",
+    "лежащими",
+    "лежащую",
+    "лежи",
+    "лежит",
+    "лежите",
+    "лежишь",
+    "лежу",
+    "лез",
+    "лезвие",
+    "лезвием",
+    "лезвию",
+    "лезгинку",
+    "лезет",
+    "лезешь",
+    "лезть",
+    "лезут",
+    "лейтмотив",
+    "лейтмотивом",
+    "лекарем",
+    "лекарств",
+    "лекарства",
+    "лекарствами",
+    "лекарствах",
+    "лекарственных",
+    "лекарство",
+    "лексиконе",
+    "лектор",
+    "лекции",
+    "лекций",
+    "лекцию",
+    "лелеемый",
+    "лелеет",
+    "лелей",
+    "лелеял",
+    "лена",
+    "лене",
+    "лени",
+    "ленив",
+    "ленивая",
+    "ленивее",
+    "ленивец",
+    "лениво",
+    "ленивого",
+    "ленивое",
+    "ленивой",
+    "ленивом",
+    "ленивою",
+    "ленивы",
+    "ленивые",
+    "ленивый",
+    "ленивых",
+    "ленился",
+    "ленора",
+    "лености",
+    "леночка",
+    "лентами",
+    "лентах",
+    "ленточек",
+    "ленточке",
+    "лентою",
+    "ленты",
+    "лентяй",
+    "лентяя",
+    "лень",
+    "ленью",
+    "леню",
+    "леня",
+    "леонардо",
+    "леонид",
+    "леонида",
+    "лепестки",
+    "лепет",
+    "лепетал",
+    "лепетала",
+    "лепетом",
+    "лепечет",
+    "лепилась",
+    "лепились",
+    "лепно",
+    "лепят",
+    "лепятся",
+    "лермонтов",
+    "лермонтова",
+    "лес",
+    "леса",
+    "лесами",
+    "лесах",
+    "лесе",
+    "лесничестве",
+    "лесничество",
+    "лесничий",
+    "лесной",
+    "лесные",
+    "лесных",
+    "лесов",
+    "лесок",
+    "лесом",
+    "лести",
+    "лестию",
+    "лестниц",
+    "лестница",
+    "лестницами",
+    "лестнице",
+    "лестницей",
+    "лестницу",
+    "лестницы",
+    "лестничестве",
+    "лестно",
+    "лестного",
+    "лестным",
+    "лестных",
+    "лесть",
+    "лестью",
+    "лесу",
+    "лет",
+    "лета",
+    "летает",
+    "летаешь",
+    "летай",
+    "летал",
+    "летала",
+    "летало",
+    "летам",
+    "летами",
+    "летать",
+    "летах",
+    "летают",
+    "летевшую",
+    "летел",
+    "летела",
+    "летели",
+    "лететь",
+    "лети",
+    "летим",
+    "летит",
+    "летите",
+    "летишь",
+    "летнего",
+    "летнее",
+    "летней",
+    "летнем",
+    "летнему",
+    "летние",
+    "летний",
+    "летних",
+    "летнюю",
+    "летняя",
+    "лето",
+    "летом",
+    "летописей",
+    "лету",
+    "летун",
+    "летучая",
+    "летучей",
+    "летучим",
+    "летучих",
+    "летя",
+    "летят",
+    "лечат",
+    "лечебницу",
+    "лечению",
+    "лечения",
+    "лечи",
+    "лечил",
+    "лечилась",
+    "лечит",
+    "лечить",
+    "лечиться",
+    "лечишься",
+    "лечь",
+    "лешие",
+    "леший",
+    "лешим",
+    "лжет",
+    "лжете",
+    "лжешь",
+    "лжи",
+    "лжива",
+    "лживой",
+    "лживою",
+    "ли",
+    "либерал",
+    "либерализма",
+    "либералы",
+    "либеральной",
+    "либеральный",
+    "либеральным",
+    "либо",
+    "ливень",
+    "ливингстона",
+    "ливрее",
+    "ливреи",
+    "ливрейном",
+    "ливрея",
+    "лида",
+    "лидией",
+    "лидинька",
+    "лидию",
+    "лидия",
+    "лидочка",
+    "лидочке",
+    "лидочкой",
+    "лидочку",
+    "лизавета",
+    "лизавете",
+    "лизаветин",
+    "лизаветина",
+    "лизаветой",
+    "лизавету",
+    "лизаветы",
+    "лизнет",
+    "лик",
+    "лике",
+    "ликов",
+    "ликованье",
+    "ликом",
+    "ликует",
+    "ликург",
+    "ликургами",
+    "лил",
+    "лилась",
+    "лилии",
+    "лилий",
+    "лились",
+    "лиллас",
+    "лилово",
+    "лиловое",
+    "лиловые",
+    "лиловыми",
+    "лился",
+    "лимон",
+    "лимонничаешь",
+    "лимоном",
+    "линейке",
+    "линейки",
+    "линейку",
+    "линией",
+    "линии",
+    "линий",
+    "линию",
+    "линия",
+    "линор",
+    "линялая",
+    "линять",
+    "лион",
+    "липкий",
+    "липким",
+    "липкой",
+    "липли",
+    "липовая",
+    "липового",
+    "липовые",
+    "липовым",
+    "липовых",
+    "липпевехзель",
+    "липпи",
+    "липу",
+    "липы",
+    "лира",
+    "лирическая",
+    "лисица",
+    "лиссабонского",
+    "лиссабонское",
+    "лист",
+    "листа",
+    "листами",
+    "листе",
+    "листиках",
+    "листиков",
+    "листка",
+    "листках",
+    "листке",
+    "листки",
+    "листов",
+    "листок",
+    "листом",
+    "листочки",
+    "листу",
+    "листы",
+    "листьев",
+    "листья",
+    "листьях",
+    "лись",
+    "литавр",
+    "литейной",
+    "литейном",
+    "литератор",
+    "литераторов",
+    "литератором",
+    "литераторы",
+    "литература",
+    "литературе",
+    "литературно",
+    "литературном",
+    "литературную",
+    "литературный",
+    "литературой",
+    "литературу",
+    "литературы",
+    "лития",
+    "лить",
+    "лифчик",
+    "лихо",
+    "лихом",
+    "лихоманка",
+    "лихорадка",
+    "лихорадке",
+    "лихорадки",
+    "лихорадку",
+    "лихорадочная",
+    "лихорадочно",
+    "лихорадочного",
+    "лихорадочное",
+    "лихорадочной",
+    "лихорадочность",
+    "лихорадочную",
+    "лихорадочный",
+    "лихорадочным",
+    "лихорадочных",
+    "лиц",
+    "лица",
+    "лицами",
+    "лицах",
+    "лице",
+    "лицезреть",
+    "лицемерный",
+    "лицо",
+    "лицом",
+    "лицу",
+    "личек",
+    "личика",
+    "личике",
+    "личико",
+    "личиком",
+    "личиной",
+    "личная",
+    "лично",
+    "личного",
+    "личной",
+    "личном",
+    "личному",
+    "личности",
+    "личность",
+    "личностью",
+    "личную",
+    "личные",
+    "личный",
+    "личным",
+    "личных",
+    "лишает",
+    "лишали",
+    "лишений",
+    "лишениями",
+    "лишенное",
+    "лишенной",
+    "лишенною",
+    "лишенные",
+    "лишено",
+    "лишившийся",
+    "лишил",
+    "лишились",
+    "лишился",
+    "лишить",
+    "лишком",
+    "лишнего",
+    "лишнее",
+    "лишней",
+    "лишние",
+    "лишний",
+    "лишним",
+    "лишних",
+    "лишняя",
+    "лишу",
+    "лишь",
+    "ло",
+    "лоб",
+    "лобзаний",
+    "лобызает",
+    "ловелас",
+    "лови",
+    "ловил",
+    "ловит",
+    "ловить",
+    "ловкая",
+    "ловкий",
+    "ловко",
+    "ловкость",
+    "ловкую",
+    "ловлю",
+    "ловушка",
+    "ловушкой",
+    "ловушку",
+    "ловя",
+    "ловят",
+    "лога",
+    "логика",
+    "логики",
+    "логикой",
+    "логиныч",
+    "логическая",
+    "логически",
+    "логическим",
+    "логической",
+    "логично",
+    "логичны",
+    "лодка",
+    "лодке",
+    "лодки",
+    "лодку",
+    "лодочник",
+    "лодочника",
+    "лодочнику",
+    "ложа",
+    "ложась",
+    "ложатся",
+    "ложе",
+    "ложек",
+    "ложем",
+    "ложементов",
+    "ложечка",
+    "ложечки",
+    "ложечкой",
+    "ложечку",
+    "ложи",
+    "ложилась",
+    "ложились",
+    "ложилось",
+    "ложился",
+    "ложись",
+    "ложитесь",
+    "ложится",
+    "ложиться",
+    "ложка",
+    "ложки",
+    "ложку",
+    "ложного",
+    "ложное",
+    "ложные",
+    "ложу",
+    "ложь",
+    "ложью",
+    "лоз",
+    "лозунг",
+    "лозунгом",
+    "локон",
+    "локончиками",
+    "локончики",
+    "локоть",
+    "локтей",
+    "локтем",
+    "локти",
+    "локтя",
+    "локтями",
+    "локтях",
+    "лом",
+    "ломает",
+    "ломаешь",
+    "ломайтесь",
+    "ломал",
+    "ломала",
+    "ломаль",
+    "ломаной",
+    "ломать",
+    "ломаться",
+    "ломаю",
+    "ломают",
+    "ломаются",
+    "ломая",
+    "ломбард",
+    "ломберном",
+    "ломберный",
+    "ломберными",
+    "ломились",
+    "ломится",
+    "ломка",
+    "ломки",
+    "ломовою",
+    "ломовых",
+    "ломом",
+    "ломоть",
+    "ломтем",
+    "ломтю",
+    "ломят",
+    "лона",
+    "лондон",
+    "лондона",
+    "лондоне",
+    "лоне",
+    "лоно",
+    "лопастей",
+    "лопасти",
+    "лопатками",
+    "лопатой",
+    "лопахин",
+    "лопахина",
+    "лопахину",
+    "лопахиным",
+    "лопнет",
+    "лопнувшей",
+    "лопнула",
+    "лопнуло",
+    "лорд",
+    "лордом",
+    "лорнет",
+    "лорнеткой",
+    "лорнетку",
+    "лорнировала",
+    "лоси",
+    "лоск",
+    "лоскутка",
+    "лоскутки",
+    "лоскутков",
+    "лоскутное",
+    "лоскуток",
+    "лоскуты",
+    "лоскутья",
+    "лоснилась",
+    "лоснятся",
+    "лоснящиеся",
+    "лососина",
+    "лососиной",
+    "лота",
+    "лотереей",
+    "лотереи",
+    "лотерею",
+    "лотках",
+    "лото",
+    "лохматый",
+    "лохмотник",
+    "лохмотников",
+    "лохмотьев",
+    "лохмотья",
+    "лохмотьям",
+    "лохмотьях",
+    "лошадей",
+    "лошаденка",
+    "лошаденке",
+    "лошаденку",
+    "лошади",
+    "лошадиное",
+    "лошадке",
+    "лошадки",
+    "лошадку",
+    "лошадь",
+    "лошадьми",
+    "лошадью",
+    "лошадям",
+    "лошадях",
+    "луг",
+    "луга",
+    "лугов",
+    "лугом",
+    "лугу",
+    "лужа",
+    "луже",
+    "лужи",
+    "лужин",
+    "лужина",
+    "лужине",
+    "лужинская",
+    "лужину",
+    "лужиным",
+    "лужица",
+    "лужице",
+    "лужу",
+    "луиза",
+    "лук",
+    "лука",
+    "лукав",
+    "лукавая",
+    "лукавил",
+    "лукавит",
+    "лукавить",
+    "лукавлю",
+    "лукаво",
+    "лукавого",
+    "лукавое",
+    "лукавой",
+    "лукавою",
+    "лукавства",
+    "лукавство",
+    "лукавством",
+    "лукавые",
+    "лукавый",
+    "лукавым",
+    "лукавыми",
+    "лукавых",
+    "лукавь",
+    "луковицу",
+    "луком",
+    "лукоморья",
+    "луку",
+    "луна",
+    "лунатизм",
+    "луне",
+    "лунная",
+    "лунного",
+    "лунной",
+    "лунном",
+    "лунному",
+    "лунные",
+    "лунный",
+    "лунным",
+    "луной",
+    "луну",
+    "луны",
+    "лупить",
+    "луч",
+    "луча",
+    "лучам",
+    "лучами",
+    "лучах",
+    "лучезарнее",
+    "лучей",
+    "лучи",
+    "лучинку",
+    "лучину",
+    "лучины",
+    "лучистые",
+    "лучистыми",
+    "лучом",
+    "лучшая",
+    "лучше",
+    "лучшего",
+    "лучшее",
+    "лучшей",
+    "лучшему",
+    "лучшие",
+    "лучший",
+    "лучшим",
+    "лучших",
+    "лучшую",
+    "лущить",
+    "лчать",
+    "лый",
+    "лысая",
+    "лысина",
+    "лысине",
+    "лысиной",
+    "лысину",
+    "лысый",
+    "лысым",
+    "ль",
+    "львиного",
+    "львиное",
+    "львице",
+    "львицей",
+    "львов",
+    "львович",
+    "львовна",
+    "льву",
+    "львы",
+    "льда",
+    "льдины",
+    "льдом",
+    "льет",
+    "льется",
+    "льзя",
+    "льнет",
+    "льном",
+    "льстецам",
+    "льстиво",
+    "льстивое",
+    "льстивый",
+    "льстил",
+    "льшая",
+    "льшим",
+    "льших",
+    "льшую",
+    "льщу",
+    "льюиса",
+    "льют",
+    "лю",
+    "люба",
+    "любви",
+    "любезен",
+    "любезна",
+    "любезнее",
+    "любезнейшая",
+    "любезнейший",
+    "любезно",
+    "любезной",
+    "любезностей",
+    "любезность",
+    "любезностью",
+    "любезностями",
+    "любезны",
+    "любезные",
+    "любезный",
+    "любезным",
+    "любезных",
+    "любека",
+    "люби",
+    "любивший",
+    "любил",
+    "любила",
+    "любили",
+    "любило",
+    "любим",
+    "любимая",
+    "любимец",
+    "любимице",
+    "любимо",
+    "любимого",
+    "любимое",
+    "любимой",
+    "любимую",
+    "любимца",
+    "любимцу",
+    "любимые",
+    "любимый",
+    "любимым",
+    "любимых",
+    "любит",
+    "любите",
+    "любитель",
+    "любителя",
+    "любить",
+    "любишь",
+    "люблю",
+    "любо",
+    "любовалась",
+    "любовались",
+    "любовался",
+    "любоваться",
+    "любови",
+    "любовник",
+    "любовника",
+    "любовники",
+    "любовником",
+    "любовница",
+    "любовницей",
+    "любовницы",
+    "любовно",
+    "любовные",
+    "любовных",
+    "любовь",
+    "любовью",
+    "любого",
+    "любое",
+    "любом",
+    "любопытен",
+    "любопытная",
+    "любопытнейшими",
+    "любопытно",
+    "любопытного",
+    "любопытной",
+    "любопытную",
+    "любопытные",
+    "любопытный",
+    "любопытным",
+    "любопытными",
+    "любопытных",
+    "любопытства",
+    "любопытство",
+    "любопытством",
+    "любопытствую",
+    "любуется",
+    "любуйся",
+    "любуясь",
+    "любя",
+    "любят",
+    "любящего",
+    "любящее",
+    "любящей",
+    "любящие",
+    "любящим",
+    "любящимися",
+    "любящихся",
+    "люд",
+    "люда",
+    "людвиговна",
+    "людвиговной",
+    "людвиговну",
+    "людей",
+    "люди",
+    "людовике",
+    "людом",
+    "людская",
+    "людски",
+    "людские",
+    "людских",
+    "людской",
+    "людскому",
+    "людскую",
+    "людьми",
+    "людям",
+    "людях",
+    "люли",
+    "люстра",
+    "лютни",
+    "лютой",
+    "лютые",
+    "люциферова",
+    "ля",
+    "ляг",
+    "лягаев",
+    "лягается",
+    "лягаться",
+    "лягнул",
+    "лягу",
+    "лягут",
+    "лягушек",
+    "лядащая",
+    "ляжет",
+    "ляжете",
+    "ляжешь",
+    "лязбиля",
+    "лязг",
+    "лязгают",
+    "ляп",
+    "м",
+    "ма",
+    "мавзолей",
+    "мага",
+    "магазин",
+    "магазина",
+    "магазинам",
+    "магазине",
+    "магазинов",
+    "магазины",
+    "магарыч",
+    "магдалина",
+    "магистром",
+    "магического",
+    "магнатские",
+    "магнетизер",
+    "магомет",
+    "магометами",
+    "магометом",
+    "мадам",
+    "мадемуазель",
+    "мадера",
+    "мадеры",
+    "мадонн",
+    "мадонне",
+    "мадонну",
+    "мадонны",
+    "мае",
+    "маевские",
+    "мается",
+    "маешься",
+    "мажет",
+    "мажордом",
+    "мажордомы",
+    "мажу",
+    "мазали",
+    "мази",
+    "мазилкой",
+    "мазнул",
+    "мазурика",
+    "мазурка",
+    "мазурке",
+    "мазурки",
+    "мазурку",
+    "мазью",
+    "май",
+    "майков",
+    "майор",
+    "майора",
+    "майором",
+    "майская",
+    "майские",
+    "майскими",
+    "майских",
+    "мак",
+    "макароны",
+    "маклашиных",
+    "маклера",
+    "маковой",
+    "маковый",
+    "маку",
+    "макушка",
+    "макушке",
+    "мал",
+    "мала",
+    "маланья",
+    "малейшая",
+    "малейшего",
+    "малейшей",
+    "малейшем",
+    "малейшему",
+    "малейший",
+    "малейших",
+    "малейшую",
+    "маленькая",
+    "маленькие",
+    "маленький",
+    "маленьким",
+    "маленькими",
+    "маленьких",
+    "маленько",
+    "маленького",
+    "маленькое",
+    "маленькой",
+    "маленьком",
+    "маленькому",
+    "маленькою",
+    "маленькую",
+    "малина",
+    "малинки",
+    "малиновая",
+    "малинового",
+    "малиной",
+    "малину",
+    "малины",
+    "малицкое",
+    "мало",
+    "маловажное",
+    "маловажной",
+    "малого",
+    "малограмотный",
+    "малодейственных",
+    "малодушие",
+    "малодушии",
+    "малодушия",
+    "малодушного",
+    "малодушной",
+    "малое",
+    "малой",
+    "малолетним",
+    "малолетними",
+    "малолетными",
+    "малом",
+    "малому",
+    "малоподвижная",
+    "малостоящее",
+    "малость",
+    "малую",
+    "малые",
+    "малый",
+    "малым",
+    "малыми",
+    "малых",
+    "мальски",
+    "мальчик",
+    "мальчика",
+    "мальчике",
+    "мальчики",
+    "мальчиком",
+    "мальчику",
+    "мальчишек",
+    "мальчишески",
+    "мальчишескою",
+    "мальчишечка",
+    "мальчишка",
+    "мальчишкам",
+    "мальчишками",
+    "мальчишке",
+    "мальчишки",
+    "мальчишков",
+    "мальчишкой",
+    "мальчишку",
+    "мальчонком",
+    "мальчугана",
+    "малютки",
+    "малюток",
+    "малявочка",
+    "малявочкой",
+    "маляре",
+    "маляры",
+    "мама",
+    "мамай",
+    "мамасю",
+    "мамася",
+    "мамаша",
+    "мамаше",
+    "мамашей",
+    "мамаши",
+    "мамашину",
+    "мамашу",
+    "маме",
+    "маменька",
+    "маменьки",
+    "маменькой",
+    "маменьку",
+    "мамками",
+    "мамочка",
+    "маму",
+    "мамы",
+    "ман",
+    "манго",
+    "мандолина",
+    "мандолине",
+    "манер",
+    "манера",
+    "манерами",
+    "манерах",
+    "манерой",
+    "манером",
+    "манеру",
+    "манеры",
+    "манжет",
+    "манжетах",
+    "мани",
+    "манил",
+    "манила",
+    "манило",
+    "манир",
+    "манит",
+    "манифест",
+    "манишка",
+    "манишки",
+    "манишку",
+    "манкировал",
+    "манкировали",
+    "манкировать",
+    "манкируете",
+    "мансардах",
+    "мансарде",
+    "мантилий",
+    "мантильи",
+    "мантильке",
+    "мантильку",
+    "мантилью",
+    "манто",
+    "манфредами",
+    "манящие",
+    "маргарита",
+    "марево",
+    "марии",
+    "марина",
+    "марине",
+    "мариновали",
+    "марину",
+    "марины",
+    "марионетками",
+    "марию",
+    "мария",
+    "марк",
+    "маркизы",
+    "мармелад",
+    "мармеладов",
+    "мармеладова",
+    "мармеладову",
+    "мармеладовым",
+    "мармеладовых",
+    "марсово",
+    "март",
+    "марта",
+    "марте",
+    "марфа",
+    "марфе",
+    "марфой",
+    "марфу",
+    "марфы",
+    "марш",
+    "марширующих",
+    "марье",
+    "марьей",
+    "марьи",
+    "марью",
+    "марья",
+    "марьяж",
+    "маска",
+    "маскарад",
+    "маскарада",
+    "маски",
+    "маской",
+    "маску",
+    "масла",
+    "масленица",
+    "масленице",
+    "масленицы",
+    "маслеными",
+    "масло",
+    "маслом",
+    "маслу",
+    "масляною",
+    "масок",
+    "масса",
+    "массивная",
+    "массивный",
+    "массивных",
+    "массимо",
+    "массис",
+    "массою",
+    "массу",
+    "массы",
+    "мастей",
+    "мастер",
+    "мастерица",
+    "мастеровому",
+    "мастеровым",
+    "мастеровых",
+    "мастером",
+    "мастерская",
+    "мастерски",
+    "мастерских",
+    "мастерское",
+    "мастерской",
+    "мастеру",
+    "масштабе",
+    "матвевна",
+    "матвеевич",
+    "матвеевича",
+    "матвеевичем",
+    "матвеевичу",
+    "матвеевна",
+    "матвеевне",
+    "матвеевной",
+    "матвеевну",
+    "матвеевны",
+    "матвеем",
+    "матвеич",
+    "матвеича",
+    "матвеичем",
+    "матвей",
+    "математики",
+    "математику",
+    "математически",
+    "математический",
+    "математических",
+    "математической",
+    "математическую",
+    "матерей",
+    "матери",
+    "материал",
+    "материала",
+    "материалов",
+    "материалом",
+    "материалу",
+    "материалы",
+    "материальной",
+    "материальную",
+    "материальные",
+    "материальный",
+    "материальных",
+    "материею",
+    "материи",
+    "материй",
+    "материнским",
+    "материнское",
+    "материю",
+    "материя",
+    "матерчатое",
+    "матерь",
+    "матерью",
+    "матерьялистских",
+    "матовому",
+    "матовый",
+    "матовых",
+    "матом",
+    "матрена",
+    "матрос",
+    "матросы",
+    "матушка",
+    "матушки",
+    "матушку",
+    "матчиш",
+    "мать",
+    "махал",
+    "махать",
+    "махая",
+    "махнешь",
+    "махнув",
+    "махнул",
+    "махнула",
+    "махов",
+    "махочкие",
+    "махочкую",
+    "маху",
+    "мачеха",
+    "мачехе",
+    "мачехи",
+    "мачехой",
+    "мачтам",
+    "мачтах",
+    "маша",
+    "маше",
+    "машей",
+    "машенька",
+    "машеньки",
+    "машенькой",
+    "машет",
+    "маши",
+    "машин",
+    "машина",
+    "машинально",
+    "машине",
+    "машинистом",
+    "машинку",
+    "машину",
+    "машины",
+    "машка",
+    "машу",
+    "мая",
+    "маяк",
+    "маяки",
+    "маятник",
+    "маятника",
+    "маятником",
+    "маяться",
+    "маячишь",
+    "мгла",
+    "мгле",
+    "мглист",
+    "мглистой",
+    "мглистом",
+    "мглистый",
+    "мглой",
+    "мглою",
+    "мглу",
+    "мглы",
+    "мгновение",
+    "мгновением",
+    "мгновений",
+    "мгновения",
+    "мгновениями",
+    "мгновенно",
+    "мгновенного",
+    "мгновенное",
+    "мгновенной",
+    "мгновенный",
+    "мгновенным",
+    "мгновенье",
+    "ме",
+    "мебели",
+    "мебель",
+    "мебельном",
+    "мебельщику",
+    "меблированная",
+    "меблированные",
+    "меда",
+    "медали",
+    "медаль",
+    "медальон",
+    "медальоне",
+    "медалях",
+    "медведе",
+    "медведей",
+    "медведенка",
+    "медведенко",
+    "медведенком",
+    "медведенку",
+    "медведь",
+    "медведя",
+    "медвежью",
+    "меди",
+    "медикаменты",
+    "медики",
+    "медиком",
+    "медицине",
+    "медицинский",
+    "медицинское",
+    "медицину",
+    "медицины",
+    "медленная",
+    "медленнее",
+    "медленно",
+    "медленного",
+    "медленное",
+    "медленною",
+    "медленный",
+    "медленным",
+    "медленных",
+    "медли",
+    "медлил",
+    "медлила",
+    "медлит",
+    "медлительно",
+    "медлительностью",
+    "медлю",
+    "медля",
+    "медно",
+    "медного",
+    "медной",
+    "медном",
+    "медную",
+    "медные",
+    "медный",
+    "медными",
+    "медных",
+    "медовые",
+    "медовых",
+    "меду",
+    "меды",
+    "медь",
+    "медью",
+    "меж",
+    "между",
+    "межу",
+    "мездровых",
+    "мейербер",
+    "мел",
+    "меланхолик",
+    "меланхолики",
+    "меланхолически",
+    "меланхолический",
+    "меланхолической",
+    "меланхоличный",
+    "меланхолия",
+    "меленьким",
+    "мелет",
+    "мелете",
+    "мелеют",
+    "мели",
+    "мелка",
+    "мелкая",
+    "мелкие",
+    "мелкий",
+    "мелким",
+    "мелкими",
+    "мелких",
+    "мелко",
+    "мелкого",
+    "мелкое",
+    "мелкой",
+    "мелкоозабоченные",
+    "мелкопоместный",
+    "мелкою",
+    "мелодией",
+    "мелодраме",
+    "мелом",
+    "мелочам",
+    "мелочах",
+    "мелочей",
+    "мелочи",
+    "мелочной",
+    "мелочною",
+    "мелочную",
+    "мелочных",
+    "мелочь",
+    "мелочью",
+    "мелькавшие",
+    "мелькавшим",
+    "мелькавших",
+    "мелькает",
+    "мелькал",
+    "мелькала",
+    "мелькалась",
+    "мелькали",
+    "мелькало",
+    "мелькают",
+    "мелькающем",
+    "мелькающие",
+    "мелькнет",
+    "мелькнувшей",
+    "мелькнувшем",
+    "мелькнувший",
+    "мелькнул",
+    "мелькнула",
+    "мелькнуло",
+    "мелькнуть",
+    "мельком",
+    "мельник",
+    "мельниц",
+    "мельнице",
+    "мельницей",
+    "мельницы",
+    "мельничного",
+    "мельчайшей",
+    "мельчайшие",
+    "мельчайших",
+    "мельчал",
+    "мелю",
+    "мена",
+    "мене",
+    "менее",
+    "ментоны",
+    "меньшая",
+    "меньше",
+    "меньшее",
+    "меньшей",
+    "меньшие",
+    "меньшинство",
+    "меньшого",
+    "меньшой",
+    "меньшую",
+    "меня",
+    "меняет",
+    "меняетесь",
+    "меняется",
+    "меняешь",
+    "менял",
+    "менялась",
+    "менялись",
+    "менялы",
+    "менять",
+    "меняясь",
+    "мер",
+    "мера",
+    "мерам",
+    "мере",
+    "мерещилась",
+    "мерещились",
+    "мерещилось",
+    "мерещился",
+    "мерещится",
+    "мерещиться",
+    "мерещутся",
+    "мерзавец",
+    "мерзавцы",
+    "мерзкий",
+    "мерзко",
+    "мерзкой",
+    "мерзкую",
+    "мерзлого",
+    "мерзнуть",
+    "мерзок",
+    "мерзости",
+    "мерзость",
+    "мерке",
+    "мерки",
+    "меркнет",
+    "мерку",
+    "мерлехлюндии",
+    "мерно",
+    "мерное",
+    "мерные",
+    "мерный",
+    "мерным",
+    "мерой",
+    "мертв",
+    "мертва",
+    "мертвая",
+    "мертвее",
+    "мертвец",
+    "мертвеца",
+    "мертвецам",
+    "мертвецами",
+    "мертвецах",
+    "мертвецу",
+    "мертвецы",
+    "мертвечинкой",
+    "мертво",
+    "мертвого",
+    "мертвое",
+    "мертвой",
+    "мертвому",
+    "мертвую",
+    "мертвые",
+    "мертвый",
+    "мертвым",
+    "мертвых",
+    "мертвящей",
+    "меру",
+    "мерцает",
+    "мерцание",
+    "мерцанье",
+    "мерцаньем",
+    "мерцающий",
+    "мерцающими",
+    "меры",
+    "меря",
+    "меряясь",
+    "мессианическую",
+    "мессины",
+    "мессия",
+    "мессы",
+    "мест",
+    "места",
+    "местам",
+    "местами",
+    "местах",
+    "месте",
+    "местечка",
+    "местечках",
+    "мести",
+    "местности",
+    "местность",
+    "место",
+    "местов",
+    "местом",
+    "местоположение",
+    "месту",
+    "месть",
+    "местью",
+    "месяц",
+    "месяца",
+    "месяце",
+    "месяцев",
+    "месяцем",
+    "месяцу",
+    "месяцы",
+    "метал",
+    "металась",
+    "металл",
+    "металлическая",
+    "металлической",
+    "метался",
+    "метаний",
+    "метать",
+    "метаться",
+    "метафизике",
+    "метели",
+    "метелицей",
+    "метель",
+    "метельную",
+    "метелью",
+    "метеоры",
+    "метешь",
+    "мети",
+    "метит",
+    "меткое",
+    "метлинскому",
+    "метлой",
+    "метнула",
+    "метнулись",
+    "метнулся",
+    "метод",
+    "метода",
+    "методически",
+    "метр",
+    "мету",
+    "мех",
+    "меха",
+    "механизм",
+    "механики",
+    "механически",
+    "мехмет",
+    "мехов",
+    "меховая",
+    "меховою",
+    "меховую",
+    "мехом",
+    "меч",
+    "мечами",
+    "мече",
+    "мечет",
+    "мечетесь",
+    "мечи",
+    "мечом",
+    "мечта",
+    "мечтавшую",
+    "мечтает",
+    "мечтается",
+    "мечтаешь",
+    "мечтал",
+    "мечтала",
+    "мечтали",
+    "мечталось",
+    "мечтам",
+    "мечтами",
+    "мечтаний",
+    "мечтания",
+    "мечтаниями",
+    "мечтаниях",
+    "мечтанье",
+    "мечтанья",
+    "мечтатель",
+    "мечтательница",
+    "мечтательно",
+    "мечтательностию",
+    "мечтательность",
+    "мечтательных",
+    "мечтателям",
+    "мечтать",
+    "мечтах",
+    "мечтаю",
+    "мечте",
+    "мечтой",
+    "мечту",
+    "мечты",
+    "мечусь",
+    "мечут",
+    "мечутся",
+    "мешавшее",
+    "мешавшийся",
+    "мешавших",
+    "мешаем",
+    "мешает",
+    "мешаете",
+    "мешается",
+    "мешай",
+    "мешайте",
+    "мешал",
+    "мешала",
+    "мешалась",
+    "мешали",
+    "мешались",
+    "мешало",
+    "мешать",
+    "мешаться",
+    "мешаю",
+    "мешают",
+    "мешаются",
+    "мешающего",
+    "мешающую",
+    "мешая",
+    "мешаясь",
+    "мешкать",
+    "мешкая",
+    "мешке",
+    "мешки",
+    "мешок",
+    "мещан",
+    "мещане",
+    "мещанин",
+    "мещанина",
+    "мещанинишка",
+    "мещанинишкой",
+    "мещанинишку",
+    "мещанином",
+    "мещанка",
+    "мещанок",
+    "мещаночка",
+    "мещански",
+    "мещанской",
+    "ми",
+    "миг",
+    "мига",
+    "мигавшие",
+    "мигает",
+    "мигал",
+    "мигали",
+    "мигать",
+    "мигают",
+    "мигающего",
+    "мигая",
+    "миги",
+    "мигнет",
+    "мигни",
+    "мигнувший",
+    "мигнул",
+    "мигнуть",
+    "мигом",
+    "мигрень",
+    "мизерном",
+    "мизерные",
+    "мизерных",
+    "мизинца",
+    "микельанджело",
+    "миколай",
+    "миколаю",
+    "миколая",
+    "миколка",
+    "миколке",
+    "миколки",
+    "миколкину",
+    "миколкой",
+    "миколку",
+    "микробами",
+    "микроскопические",
+    "микроскопических",
+    "микстуру",
+    "мил",
+    "мила",
+    "милашка",
+    "милая",
+    "милее",
+    "милейшая",
+    "милейший",
+    "миленькая",
+    "миленькие",
+    "миленький",
+    "милитриса",
+    "милитрисе",
+    "миллиарды",
+    "миллион",
+    "миллиона",
+    "миллионами",
+    "миллионер",
+    "миллионером",
+    "миллионная",
+    "миллионов",
+    "миллиону",
+    "миллионы",
+    "мило",
+    "миловать",
+    "милого",
+    "милое",
+    "милой",
+    "милосерд",
+    "милосердии",
+    "милосердию",
+    "милосердия",
+    "милосердному",
+    "милосердные",
+    "милосердный",
+    "милосердным",
+    "милосердые",
+    "милосердый",
+    "милости",
+    "милостив",
+    "милостивая",
+    "милостивее",
+    "милостивое",
+    "милостивый",
+    "милостисдарь",
+    "милостию",
+    "милостыни",
+    "милостыню",
+    "милость",
+    "милочка",
+    "милую",
+    "милы",
+    "милые",
+    "милый",
+    "милым",
+    "мильон",
+    "милюков",
+    "милютины",
+    "миме",
+    "мимики",
+    "мимо",
+    "мимолетное",
+    "мимолетной",
+    "мимолетных",
+    "мимоходом",
+    "миндаль",
+    "миндалю",
+    "миндаля",
+    "минералами",
+    "минет",
+    "министерства",
+    "министерстве",
+    "министр",
+    "министра",
+    "министрам",
+    "министром",
+    "министру",
+    "миновав",
+    "миновал",
+    "миновала",
+    "миновалась",
+    "миновались",
+    "миновало",
+    "миновалось",
+    "миновать",
+    "миной",
+    "мину",
+    "минувшего",
+    "минувшее",
+    "минувшем",
+    "минувших",
+    "минует",
+    "минул",
+    "минусом",
+    "минут",
+    "минута",
+    "минутами",
+    "минутах",
+    "минуте",
+    "минутки",
+    "минутку",
+    "минутно",
+    "минутного",
+    "минутное",
+    "минутны",
+    "минутный",
+    "минутой",
+    "минуток",
+    "минуточку",
+    "минутою",
+    "минуту",
+    "минуты",
+    "минуют",
+    "минуя",
+    "мины",
+    "миньятюрных",
+    "мир",
+    "мира",
+    "мираж",
+    "миражами",
+    "миражи",
+    "мире",
+    "мирен",
+    "мирилась",
+    "миримся",
+    "мирит",
+    "мирится",
+    "мирить",
+    "мириться",
+    "мирно",
+    "мирного",
+    "мирное",
+    "мирной",
+    "мирный",
+    "мирным",
+    "мирных",
+    "миров",
+    "мировая",
+    "мирового",
+    "мировое",
+    "мировой",
+    "мировою",
+    "мировую",
+    "мировых",
+    "миролюбивого",
+    "миром",
+    "миртов",
+    "мирты",
+    "миру",
+    "миры",
+    "мирянам",
+    "мирятся",
+    "мисс",
+    "мистик",
+    "мистифицировать",
+    "мистически",
+    "мистическое",
+    "мистическом",
+    "митреем",
+    "митрей",
+    "митрея",
+    "митрофаниевском",
+    "митька",
+    "митьки",
+    "митькой",
+    "митьку",
+    "михаил",
+    "михаила",
+    "михайлов",
+    "михайлович",
+    "михайловна",
+    "михайловну",
+    "михайловны",
+    "михайловским",
+    "михайлыч",
+    "михайлычем",
+    "михеем",
+    "михей",
+    "михею",
+    "михея",
+    "мицкевича",
+    "мичману",
+    "миша",
+    "мише",
+    "мишель",
+    "мишу",
+    "мишуры",
+    "младая",
+    "младенец",
+    "младенца",
+    "младенцев",
+    "младенцем",
+    "младенцу",
+    "младенческий",
+    "младенческой",
+    "младость",
+    "младшая",
+    "младшей",
+    "младшему",
+    "младший",
+    "младшую",
+    "младые",
+    "млет",
+    "мне",
+    "мнение",
+    "мнением",
+    "мнении",
+    "мнений",
+    "мнению",
+    "мнения",
+    "мнет",
+    "мнителен",
+    "мнительно",
+    "мнительность",
+    "мнительный",
+    "многие",
+    "многим",
+    "многими",
+    "многих",
+    "много",
+    "многого",
+    "многодумный",
+    "многое",
+    "многознаменателен",
+    "многозначительны",
+    "многоколонный",
+    "многократно",
+    "многократное",
+    "многолюдный",
+    "многолюдству",
+    "многом",
+    "многому",
+    "многообещавшее",
+    "многообещающих",
+    "многообразным",
+    "многопенный",
+    "многоразличны",
+    "многоразличных",
+    "многоречиво",
+    "многословные",
+    "многостороннее",
+    "многотрудной",
+    "многотрудный",
+    "многоуважаемая",
+    "многоуважаемой",
+    "многоуважаемою",
+    "многоуважаемый",
+    "многоцветными",
+    "многочисленнее",
+    "многочисленнейшее",
+    "многочисленной",
+    "многочисленные",
+    "многочисленными",
+    "многочисленных",
+    "многоэтажный",
+    "многоярусный",
+    "множественном",
+    "множество",
+    "множеством",
+    "множит",
+    "мнози",
+    "мной",
+    "мною",
+    "мня",
+    "мо",
+    "мов",
+    "мовщина",
+    "мог",
+    "могил",
+    "могила",
+    "могиле",
+    "могилка",
+    "могилке",
+    "могилки",
+    "могилкой",
+    "могилой",
+    "могилою",
+    "могилу",
+    "могилы",
+    "могильная",
+    "могильной",
+    "могильный",
+    "могильными",
+    "могильщик",
+    "могла",
+    "могли",
+    "могло",
+    "могу",
+    "могут",
+    "могуче",
+    "могучей",
+    "могучие",
+    "могучий",
+    "могучих",
+    "могущее",
+    "могущество",
+    "могущие",
+    "мода",
+    "моде",
+    "модели",
+    "моделью",
+    "модистки",
+    "модная",
+    "модничаньем",
+    "модно",
+    "модное",
+    "модной",
+    "модный",
+    "модным",
+    "мое",
+    "моего",
+    "моей",
+    "моем",
+    "моему",
+    "моет",
+    "моется",
+    "моею",
+    "може",
+    "можедом",
+    "можем",
+    "может",
+    "можете",
+    "можешь",
+    "можно",
+    "мозаи",
+    "мозг",
+    "мозга",
+    "мозги",
+    "мозгу",
+    "мозоль",
+    "мои",
+    "моим",
+    "моими",
+    "моисеич",
+    "моих",
+    "мой",
+    "мокка",
+    "мокрая",
+    "мокро",
+    "мокрое",
+    "мокрой",
+    "мокрота",
+    "мокроту",
+    "мокрую",
+    "мокры",
+    "мокрые",
+    "мокрый",
+    "мокрым",
+    "мокрыми",
+    "мокрых",
+    "мол",
+    "молва",
+    "молвив",
+    "молвил",
+    "молвит",
+    "молвой",
+    "молву",
+    "молвы",
+    "молебен",
+    "моли",
+    "молил",
+    "молила",
+    "молилась",
+    "молились",
+    "молилось",
+    "молился",
+    "молимся",
+    "молись",
+    "молитв",
+    "молитва",
+    "молитвах",
+    "молитвенное",
+    "молитвенный",
+    "молитвой",
+    "молитву",
+    "молитвы",
+    "молитесь",
+    "молить",
+    "молиться",
+    "молишься",
+    "молнией",
+    "молниеносного",
+    "молниеносных",
+    "молнии",
+    "молний",
+    "молния",
+    "молньей",
+    "молньи",
+    "молод",
+    "молода",
+    "молодая",
+    "молодеет",
+    "молодежи",
+    "молодежь",
+    "молоденькая",
+    "молоденькое",
+    "молоденькой",
+    "молодец",
+    "молодо",
+    "молодого",
+    "молодое",
+    "молодой",
+    "молодом",
+    "молодому",
+    "молодости",
+    "молодость",
+    "молодою",
+    "молодую",
+    "молодца",
+    "молодцевато",
+    "молодцы",
+    "молоды",
+    "молодые",
+    "молодым",
+    "молодыми",
+    "молодых",
+    "моложавее",
+    "моложавом",
+    "моложе",
+    "молока",
+    "молоко",
+    "молоком",
+    "молокосос",
+    "молокососа",
+    "молокососы",
+    "молола",
+    "молот",
+    "молотком",
+    "молоть",
+    "молочнице",
+    "молочный",
+    "молочным",
+    "молочных",
+    "молча",
+    "молчавшего",
+    "молчал",
+    "молчала",
+    "молчали",
+    "молчалива",
+    "молчаливая",
+    "молчаливее",
+    "молчаливо",
+    "молчаливость",
+    "молчаливую",
+    "молчаливые",
+    "молчаливый",
+    "молчаливым",
+    "молчание",
+    "молчанием",
+    "молчании",
+    "молчанию",
+    "молчания",
+    "молчановка",
+    "молчат",
+    "молчать",
+    "молчи",
+    "молчим",
+    "молчит",
+    "молчите",
+    "молчишь",
+    "молчу",
+    "моль",
+    "мольба",
+    "мольбами",
+    "мольбе",
+    "мольбой",
+    "молюсь",
+    "моля",
+    "молясь",
+    "молящий",
+    "момент",
+    "момента",
+    "моменты",
+    "монастыре",
+    "монастырем",
+    "монастырский",
+    "монастырь",
+    "монах",
+    "монахам",
+    "монахи",
+    "монахов",
+    "монаху",
+    "монашку",
+    "монблан",
+    "монета",
+    "монетку",
+    "монетой",
+    "монетчиков",
+    "монеты",
+    "монисто",
+    "монологам",
+    "монологах",
+    "монологи",
+    "мономана",
+    "мономании",
+    "мономанию",
+    "мономания",
+    "мономанов",
+    "мономаны",
+    "монополию",
+    "монотонно",
+    "монотонной",
+    "монотонные",
+    "монтировало",
+    "монумент",
+    "монументально",
+    "монументальных",
+    "мопассан",
+    "мораль",
+    "морально",
+    "моргающими",
+    "морген",
+    "морде",
+    "морду",
+    "морды",
+    "море",
+    "морем",
+    "моркови",
+    "морковь",
+    "морковью",
+    "моровой",
+    "мороженое",
+    "мороженым",
+    "мороз",
+    "мороза",
+    "морозами",
+    "морозе",
+    "морозное",
+    "морозном",
+    "морозный",
+    "морозным",
+    "морозцы",
+    "морозы",
+    "морочил",
+    "морочите",
+    "морская",
+    "морские",
+    "морским",
+    "морских",
+    "морского",
+    "морское",
+    "морской",
+    "морском",
+    "морскую",
+    "морфием",
+    "морфий",
+    "морщась",
+    "морщатся",
+    "морщила",
+    "морщились",
+    "морщился",
+    "морщин",
+    "морщинами",
+    "морщинах",
+    "морщинистое",
+    "морщинки",
+    "морщиной",
+    "морщины",
+    "морщитесь",
+    "морщится",
+    "морю",
+    "моря",
+    "мосеич",
+    "москва",
+    "москве",
+    "москви",
+    "москвой",
+    "москву",
+    "москвы",
+    "московские",
+    "московским",
+    "московских",
+    "московского",
+    "московской",
+    "московском",
+    "московскою",
+    "мост",
+    "моста",
+    "мостах",
+    "мосте",
+    "мостик",
+    "мосткам",
+    "мостки",
+    "мостов",
+    "мостовая",
+    "мостовой",
+    "мостовую",
+    "мостовых",
+    "мостом",
+    "мосту",
+    "мосты",
+    "мосье",
+    "мотать",
+    "мотаю",
+    "мотают",
+    "мотив",
+    "мотор",
+    "мотька",
+    "мох",
+    "мохнатое",
+    "мохнатой",
+    "мохнатую",
+    "моцарта",
+    "моцион",
+    "моциона",
+    "мочальное",
+    "моченье",
+    "мочи",
+    "мочили",
+    "мочками",
+    "мочь",
+    "мошенник",
+    "мошенника",
+    "мошенники",
+    "мошенников",
+    "мошенническая",
+    "мошенническим",
+    "мошенничеств",
+    "мощные",
+    "мою",
+    "моя",
+    "мрак",
+    "мрака",
+    "мраке",
+    "мраков",
+    "мраком",
+    "мрамора",
+    "мраморной",
+    "мраморном",
+    "мрачен",
+    "мрачились",
+    "мрачить",
+    "мрачна",
+    "мрачная",
+    "мрачнее",
+    "мрачней",
+    "мрачно",
+    "мрачного",
+    "мрачное",
+    "мрачной",
+    "мрачном",
+    "мрачною",
+    "мрачную",
+    "мрачные",
+    "мрачный",
+    "мрачным",
+    "мрачных",
+    "мстил",
+    "мстителен",
+    "мститель",
+    "мстительной",
+    "мстить",
+    "мстят",
+    "мсье",
+    "му",
+    "мудр",
+    "мудрая",
+    "мудрено",
+    "мудреной",
+    "мудреному",
+    "мудреные",
+    "мудреным",
+    "мудреными",
+    "мудрецов",
+    "мудрецы",
+    "мудрил",
+    "мудрить",
+    "мудро",
+    "мудрое",
+    "мудрость",
+    "мудрствуйте",
+    "мудрые",
+    "муж",
+    "мужа",
+    "муже",
+    "мужей",
+    "мужем",
+    "мужества",
+    "мужественно",
+    "мужественного",
+    "мужественное",
+    "мужественности",
+    "мужественность",
+    "мужественный",
+    "мужественным",
+    "мужеством",
+    "мужик",
+    "мужика",
+    "мужикам",
+    "мужиками",
+    "мужиках",
+    "мужики",
+    "мужиков",
+    "мужиком",
+    "мужику",
+    "мужичка",
+    "мужичком",
+    "мужичок",
+    "мужичья",
+    "мужнего",
+    "мужнина",
+    "мужниного",
+    "мужниной",
+    "мужские",
+    "мужского",
+    "мужской",
+    "мужскому",
+    "мужу",
+    "мужчин",
+    "мужчина",
+    "мужчине",
+    "мужчиной",
+    "мужчины",
+    "мужья",
+    "мужьям",
+    "мужьями",
+    "муза",
+    "музе",
+    "музее",
+    "музы",
+    "музыка",
+    "музыкальной",
+    "музыкальном",
+    "музыкальный",
+    "музыкант",
+    "музыкантам",
+    "музыкантов",
+    "музыканты",
+    "музыке",
+    "музыки",
+    "музыкой",
+    "музыку",
+    "мук",
+    "мука",
+    "муками",
+    "муке",
+    "муки",
+    "мукой",
+    "муку",
+    "мундир",
+    "мундире",
+    "мундиров",
+    "мундштук",
+    "муравей",
+    "муравейником",
+    "муравьи",
+    "муравьиною",
+    "мурашиной",
+    "мурашки",
+    "мурлыкающем",
+    "мурлычу",
+    "мурлычут",
+    "муромца",
+    "мус",
+    "мускул",
+    "мускулистых",
+    "мускулов",
+    "мускулы",
+    "мускульная",
+    "мускульной",
+    "мускульном",
+    "муссинским",
+    "муссинских",
+    "мусье",
+    "мутилось",
+    "мутит",
+    "мутится",
+    "мутить",
+    "мутна",
+    "мутно",
+    "мутны",
+    "мутные",
+    "мутный",
+    "мутным",
+    "муть",
+    "мутят",
+    "мух",
+    "муха",
+    "мухи",
+    "мухояров",
+    "мухоярова",
+    "муху",
+    "мучает",
+    "мучаете",
+    "мучаетесь",
+    "мучается",
+    "мучаешь",
+    "мучаешься",
+    "мучай",
+    "мучайся",
+    "мучайте",
+    "мучась",
+    "мучат",
+    "мучаю",
+    "мучаюсь",
+    "мучают",
+    "мучая",
+    "мучаясь",
+    "мучение",
+    "мучением",
+    "мучений",
+    "мученичество",
+    "мучения",
+    "мучениях",
+    "мученье",
+    "мученья",
+    "мученьях",
+    "мучил",
+    "мучила",
+    "мучилась",
+    "мучили",
+    "мучились",
+    "мучило",
+    "мучился",
+    "мучимый",
+    "мучит",
+    "мучителей",
+    "мучители",
+    "мучительная",
+    "мучительнее",
+    "мучительнейшее",
+    "мучительницы",
+    "мучительно",
+    "мучительного",
+    "мучительное",
+    "мучительной",
+    "мучительном",
+    "мучительною",
+    "мучительную",
+    "мучительны",
+    "мучительные",
+    "мучительный",
+    "мучительным",
+    "мучительных",
+    "мучителя",
+    "мучитесь",
+    "мучится",
+    "мучить",
+    "мучиться",
+    "мучишь",
+    "мучишься",
+    "мучнистой",
+    "мучной",
+    "мучть",
+    "мучусь",
+    "мучь",
+    "мучьте",
+    "мучьтесь",
+    "мхов",
+    "мчалась",
+    "мчались",
+    "мчатся",
+    "мчаться",
+    "мчит",
+    "мчится",
+    "мщение",
+    "мщения",
+    "мы",
+    "мыкается",
+    "мыкать",
+    "мыкаться",
+    "мыкаю",
+    "мыла",
+    "мыли",
+    "мыло",
+    "мылом",
+    "мыльный",
+    "мыслей",
+    "мысленно",
+    "мысленный",
+    "мысли",
+    "мыслил",
+    "мыслит",
+    "мыслителей",
+    "мыслитель",
+    "мыслительная",
+    "мыслительной",
+    "мыслителя",
+    "мыслителям",
+    "мыслить",
+    "мыслию",
+    "мысль",
+    "мыслью",
+    "мыслю",
+    "мыслям",
+    "мыслями",
+    "мыслят",
+    "мыслях",
+    "мыслящие",
+    "мыслящим",
+    "мыты",
+    "мыть",
+    "мытье",
+    "мытья",
+    "мычит",
+    "мышами",
+    "мышей",
+    "мыши",
+    "мышкой",
+    "мышку",
+    "мышления",
+    "мышонка",
+    "мышц",
+    "мышь",
+    "мышью",
+    "мэри",
+    "мюнхенский",
+    "мягка",
+    "мягкая",
+    "мягки",
+    "мягкие",
+    "мягкий",
+    "мягким",
+    "мягкими",
+    "мягких",
+    "мягко",
+    "мягкого",
+    "мягкое",
+    "мягкой",
+    "мягком",
+    "мягкости",
+    "мягкость",
+    "мягкостью",
+    "мягок",
+    "мягче",
+    "мяса",
+    "мясистым",
+    "мясник",
+    "мясника",
+    "мясники",
+    "мяснику",
+    "мясное",
+    "мясной",
+    "мясо",
+    "мясоед",
+    "мятеж",
+    "мятежи",
+    "мятежно",
+    "мятежность",
+    "мятежный",
+    "мятежным",
+    "мятежными",
+    "мятежных",
+    "мятой",
+    "мяты",
+    "мячик",
+    "н",
+    "на",
+    "набавил",
+    "набалдашником",
+    "набат",
+    "набата",
+    "набегал",
+    "набегала",
+    "набегами",
+    "набегают",
+    "набегающих",
+    "набежал",
+    "набежали",
+    "набекрень",
+    "набережной",
+    "набережную",
+    "набережные",
+    "набережных",
+    "наберется",
+    "наберу",
+    "наберут",
+    "набивает",
+    "набивать",
+    "набирается",
+    "набиралось",
+    "набит",
+    "набита",
+    "набитая",
+    "набито",
+    "набитую",
+    "набитый",
+    "наблюдавший",
+    "наблюдает",
+    "наблюдай",
+    "наблюдайте",
+    "наблюдал",
+    "наблюдала",
+    "наблюдали",
+    "наблюдатель",
+    "наблюдательный",
+    "наблюдателя",
+    "наблюдать",
+    "наблюдают",
+    "наблюдаются",
+    "наблюдая",
+    "наблюдение",
+    "наблюдению",
+    "наблюдения",
+    "наблюдениям",
+    "наблюдениями",
+    "набожных",
+    "набок",
+    "наболевшие",
+    "наболело",
+    "наболит",
+    "наборным",
+    "набравшуюся",
+    "набрал",
+    "набрался",
+    "набрасывала",
+    "набрасывалась",
+    "набрать",
+    "набраться",
+    "набрел",
+    "набросана",
+    "набросать",
+    "набросаю",
+    "набросившись",
+    "набросил",
+    "набросилась",
+    "набросился",
+    "набросить",
+    "наброситься",
+    "наброску",
+    "наваждения",
+    "наваленные",
+    "навалено",
+    "навалил",
+    "навалили",
+    "наварю",
+    "наведаться",
+    "наведаюсь",
+    "наведены",
+    "наведывайтесь",
+    "наведывалась",
+    "наведывался",
+    "навек",
+    "навеки",
+    "навел",
+    "навела",
+    "навели",
+    "навело",
+    "наверно",
+    "наверное",
+    "навернулись",
+    "навертел",
+    "наверх",
+    "наверху",
+    "наверчено",
+    "навесами",
+    "навеселе",
+    "навесов",
+    "навесом",
+    "навести",
+    "навестил",
+    "навестить",
+    "наветам",
+    "навешаны",
+    "навещает",
+    "навеянной",
+    "навзничь",
+    "навзрыд",
+    "навин",
+    "нависали",
+    "нависла",
+    "навлечь",
+    "наводил",
+    "наводила",
+    "наводило",
+    "наводит",
+    "наводите",
+    "наводить",
+    "наводнение",
+    "наводнениями",
+    "наводя",
+    "навозе",
+    "наволочками",
+    "наволочки",
+    "навострив",
+    "навострил",
+    "навострила",
+    "навострились",
+    "наврал",
+    "наврала",
+    "навсегда",
+    "навстречу",
+    "навыворот",
+    "навык",
+    "навыкате",
+    "навязал",
+    "навязала",
+    "навязался",
+    "навязчивая",
+    "навязывал",
+    "навязывались",
+    "навязывать",
+    "навязываться",
+    "наг",
+    "нагибается",
+    "нагибалась",
+    "нагибаться",
+    "нагибаясь",
+    "нагло",
+    "наглого",
+    "наглости",
+    "наглость",
+    "наглостью",
+    "наглухо",
+    "наглые",
+    "наглый",
+    "наглым",
+    "наглядел",
+    "нагляделась",
+    "наглядно",
+    "нагнал",
+    "нагнется",
+    "нагнись",
+    "нагнув",
+    "нагнувшись",
+    "нагнул",
+    "нагнула",
+    "нагнулся",
+    "нагнусь",
+    "нагнуться",
+    "наговариваешь",
+    "наговорил",
+    "наговорила",
+    "наговорить",
+    "наговориться",
+    "нагой",
+    "нагонит",
+    "нагорном",
+    "наготове",
+    "наготой",
+    "наград",
+    "награда",
+    "наградишь",
+    "наградой",
+    "награду",
+    "награды",
+    "награждать",
+    "награждая",
+    "награждения",
+    "награждено",
+    "нагреется",
+    "нагроможденной",
+    "нагрубила",
+    "нагрубит",
+    "нагрудник",
+    "нагружать",
+    "нагрязнили",
+    "нагрянет",
+    "нагрянул",
+    "над",
+    "надавал",
+    "надает",
+    "надарит",
+    "надбавляет",
+    "надвигается",
+    "надвинулись",
+    "надвое",
+    "надворного",
+    "надворному",
+    "надворный",
+    "надворным",
+    "надвяжем",
+    "надвяжу",
+    "надвязать",
+    "надев",
+    "надевает",
+    "надеваешь",
+    "надевала",
+    "надевали",
+    "надевать",
+    "надевают",
+    "надевая",
+    "надеемся",
+    "надеетесь",
+    "надеется",
+    "надежд",
+    "надежда",
+    "надеждами",
+    "надеждах",
+    "надежде",
+    "надеждой",
+    "надеждою",
+    "надежду",
+    "надежды",
+    "надежная",
+    "надежный",
+    "надейтесь",
+    "надел",
+    "надела",
+    "наделает",
+    "наделал",
+    "наделала",
+    "наделают",
+    "надели",
+    "наденет",
+    "наденете",
+    "наденешь",
+    "надену",
+    "надень",
+    "наденьте",
+    "надеру",
+    "надет",
+    "надета",
+    "надето",
+    "надетое",
+    "надетой",
+    "надеты",
+    "надеть",
+    "надеюсь",
+    "надеются",
+    "надеялась",
+    "надеялись",
+    "надеялся",
+    "надеясь",
+    "надеяться",
+    "надзиратель",
+    "надзирательницей",
+    "надзирателю",
+    "надзирателя",
+    "надзором",
+    "надивиться",
+    "надлежащем",
+    "надлежащими",
+    "надменен",
+    "надменная",
+    "надменно",
+    "надменной",
+    "надменном",
+    "надменность",
+    "надменный",
+    "надменным",
+    "надо",
+    "надобен",
+    "надобилось",
+    "надобно",
+    "надобностей",
+    "надобности",
+    "надобность",
+    "надобны",
+    "надоедаем",
+    "надоедал",
+    "надоедала",
+    "надоедаю",
+    "надоедлив",
+    "надоедят",
+    "надоел",
+    "надоела",
+    "надоели",
+    "надоело",
+    "надоем",
+    "надоест",
+    "надоесть",
+    "надолго",
+    "надорвала",
+    "надорвана",
+    "надорванный",
+    "надоть",
+    "надписана",
+    "надпись",
+    "надписью",
+    "надругается",
+    "надругательство",
+    "надрывает",
+    "надрываются",
+    "надрывающимся",
+    "надрываясь",
+    "надсаждаясь",
+    "надседаются",
+    "надсона",
+    "надстройками",
+    "надтреснутый",
+    "надув",
+    "надувает",
+    "надувание",
+    "надувать",
+    "надует",
+    "надуется",
+    "надуешь",
+    "надула",
+    "надуло",
+    "надулся",
+    "надумавшись",
+    "надумаем",
+    "надумаете",
+    "надумается",
+    "надумал",
+    "надумался",
+    "надуто",
+    "надутого",
+    "надуть",
+    "надушенный",
+    "надушил",
+    "надуют",
+    "надышаться",
+    "наедине",
+    "наездники",
+    "наездницы",
+    "наемной",
+    "наехали",
+    "наживать",
+    "наживешь",
+    "нажил",
+    "нажимала",
+    "нажимали",
+    "нажить",
+    "нажми",
+    "назад",
+    "назади",
+    "назвал",
+    "назвала",
+    "назвали",
+    "назван",
+    "название",
+    "названием",
+    "названии",
+    "названий",
+    "названия",
+    "названья",
+    "назвать",
+    "наземь",
+    "назидание",
+    "назидания",
+    "назидательно",
+    "назло",
+    "назначавшуюся",
+    "назначаемые",
+    "назначал",
+    "назначалась",
+    "назначали",
+    "назначались",
+    "назначался",
+    "назначать",
+    "назначаю",
+    "назначение",
+    "назначением",
+    "назначении",
+    "назначения",
+    "назначенного",
+    "назначенною",
+    "назначенную",
+    "назначенным",
+    "назначено",
+    "назначены",
+    "назначил",
+    "назначила",
+    "назначить",
+    "назначу",
+    "назовет",
+    "назовете",
+    "назовешь",
+    "назови",
+    "назовут",
+    "назойливому",
+    "назойливость",
+    "назойливостью",
+    "называвшихся",
+    "называем",
+    "называемая",
+    "называемое",
+    "называемой",
+    "называемую",
+    "называемые",
+    "называемый",
+    "называет",
+    "называете",
+    "называется",
+    "называешь",
+    "называл",
+    "называла",
+    "называли",
+    "называлось",
+    "называть",
+    "называться",
+    "называю",
+    "называют",
+    "называя",
+    "назюзюкался",
+    "наибесполезнейшую",
+    "наибеспрерывнейшие",
+    "наиблагороднейшего",
+    "наиболее",
+    "наивен",
+    "наивная",
+    "наивно",
+    "наивности",
+    "наивною",
+    "наивные",
+    "наивный",
+    "наивным",
+    "наивных",
+    "наигрывает",
+    "наигрывая",
+    "наизнанку",
+    "наизусть",
+    "наименьшим",
+    "наиполезнейший",
+    "наиприличнейшая",
+    "наискось",
+    "наитий",
+    "наихитрейшим",
+    "найдем",
+    "найден",
+    "найдена",
+    "найдено",
+    "найдены",
+    "найдет",
+    "найдете",
+    "найдется",
+    "найдешь",
+    "найди",
+    "найдите",
+    "найду",
+    "найдут",
+    "найдутся",
+    "найдя",
+    "найма",
+    "наймет",
+    "наймешь",
+    "найми",
+    "наймите",
+    "найму",
+    "найти",
+    "накажет",
+    "наказал",
+    "наказали",
+    "наказана",
+    "наказание",
+    "наказания",
+    "наказанье",
+    "наказать",
+    "наказов",
+    "наказывает",
+    "наказывай",
+    "наказывал",
+    "наказывали",
+    "накануне",
+    "накапало",
+    "накаркают",
+    "накидывает",
+    "накинет",
+    "накинете",
+    "накинув",
+    "накинул",
+    "накинула",
+    "накинулась",
+    "накинулся",
+    "накинута",
+    "накинутом",
+    "накинутся",
+    "накипала",
+    "накипевшую",
+    "накипят",
+    "накладет",
+    "накладывает",
+    "накладывала",
+    "накладывать",
+    "наклевывалась",
+    "наклеенное",
+    "наклеены",
+    "наклепал",
+    "наклонен",
+    "наклоненная",
+    "наклоненной",
+    "наклонив",
+    "наклонившегося",
+    "наклонившись",
+    "наклонил",
+    "наклонила",
+    "наклонилась",
+    "наклонился",
+    "наклонится",
+    "наклониться",
+    "наклонностей",
+    "наклонности",
+    "наклонность",
+    "наклонностях",
+    "наклонною",
+    "наклонны",
+    "наклоном",
+    "наклонялся",
+    "наклонясь",
+    "наклоняя",
+    "наклоняясь",
+    "наколем",
+    "наколет",
+    "наконец",
+    "накопившиеся",
+    "накопилось",
+    "накоплялась",
+    "накорми",
+    "накормила",
+    "накормить",
+    "накормлю",
+    "накормят",
+    "накось",
+    "накошенною",
+    "накрахмаленные",
+    "накрахмалены",
+    "накрепко",
+    "накрест",
+    "накричит",
+    "накроют",
+    "накрывавшегося",
+    "накрывает",
+    "накрываете",
+    "накрывай",
+    "накрывался",
+    "накрывают",
+    "накрыл",
+    "накрыла",
+    "накрыли",
+    "накрылся",
+    "накрыт",
+    "накрытый",
+    "накрыть",
+    "накуплю",
+    "накурено",
+    "накурил",
+    "накуролесили",
+    "налагает",
+    "налагал",
+    "налагают",
+    "наладили",
+    "наладит",
+    "налгал",
+    "налево",
+    "налег",
+    "налегая",
+    "налегке",
+    "належал",
+    "налезло",
+    "налепит",
+    "налета",
+    "налетевшим",
+    "налетел",
+    "налетели",
+    "налетело",
+    "налетит",
+    "наливает",
+    "наливается",
+    "наливай",
+    "наливал",
+    "наливала",
+    "наливая",
+    "наливка",
+    "наливной",
+    "нализаться",
+    "налил",
+    "налиновал",
+    "налитыми",
+    "налить",
+    "налицо",
+    "наличности",
+    "наличных",
+    "наложенное",
+    "наложил",
+    "наложила",
+    "наложницей",
+    "налью",
+    "налюбуется",
+    "налюбуешься",
+    "налюбуются",
+    "нам",
+    "намазанных",
+    "намахивается",
+    "намахнулся",
+    "намачивая",
+    "намашет",
+    "намедни",
+    "намеднишнему",
+    "намек",
+    "намека",
+    "намекавший",
+    "намекает",
+    "намекаете",
+    "намекал",
+    "намекала",
+    "намекам",
+    "намеками",
+    "намекать",
+    "намекают",
+    "намеке",
+    "намеки",
+    "намекнул",
+    "намеков",
+    "намеком",
+    "намелет",
+    "намелко",
+    "наменял",
+    "намеревался",
+    "намереваясь",
+    "намерен",
+    "намерена",
+    "намерение",
+    "намерением",
+    "намерении",
+    "намерений",
+    "намерению",
+    "намерения",
+    "намерениям",
+    "намерениями",
+    "намерениях",
+    "намеренно",
+    "намерены",
+    "намерила",
+    "наметался",
+    "наметил",
+    "намечтать",
+    "нами",
+    "намок",
+    "наморщивши",
+    "намотал",
+    "намочил",
+    "нанес",
+    "нанесенная",
+    "нанесенной",
+    "нанесет",
+    "нанести",
+    "нанимаете",
+    "нанимал",
+    "нанималась",
+    "нанимать",
+    "нанимают",
+    "нанкового",
+    "наносил",
+    "наносит",
+    "наносный",
+    "наносным",
+    "нанял",
+    "наняла",
+    "наняли",
+    "нанялся",
+    "нанята",
+    "нанятой",
+    "нанять",
+    "наобещал",
+    "наоборот",
+    "наобум",
+    "наотмашь",
+    "наотрез",
+    "нападает",
+    "нападал",
+    "нападая",
+    "нападение",
+    "напал",
+    "напали",
+    "напасешься",
+    "напасть",
+    "напев",
+    "напева",
+    "напевает",
+    "напевах",
+    "напевают",
+    "напевая",
+    "напевы",
+    "напекли",
+    "напекут",
+    "напел",
+    "напер",
+    "наперво",
+    "наперебив",
+    "наперед",
+    "наперекор",
+    "наперерыв",
+    "напечатали",
+    "напечатана",
+    "напечатанным",
+    "напечатано",
+    "напечатать",
+    "напечет",
+    "напивались",
+    "напивался",
+    "напиваться",
+    "напиваюсь",
+    "напившись",
+    "напилась",
+    "напился",
+    "напирали",
+    "напирать",
+    "напирают",
+    "напирая",
+    "написав",
+    "написал",
+    "написала",
+    "написали",
+    "написанная",
+    "написанного",
+    "написанное",
+    "написанной",
+    "написанную",
+    "написанный",
+    "написано",
+    "написаны",
+    "написать",
+    "напитанная",
+    "напитку",
+    "напиток",
+    "напитывается",
+    "напиться",
+    "напиханном",
+    "напишет",
+    "напишешь",
+    "напиши",
+    "напишите",
+    "напишу",
+    "напишут",
+    "наплакались",
+    "наплевал",
+    "наплевать",
+    "наплывают",
+    "наплясался",
+    "наподличал",
+    "напоенного",
+    "напоил",
+    "напоила",
+    "напоили",
+    "напой",
+    "напоказ",
+    "наполеон",
+    "наполеона",
+    "наполеонами",
+    "наполеоновской",
+    "наполеоном",
+    "наползают",
+    "наползет",
+    "наполнена",
+    "наполненная",
+    "наполненной",
+    "наполненные",
+    "наполненный",
+    "наполненным",
+    "наполненных",
+    "наполнено",
+    "наполнены",
+    "наполнил",
+    "наполнилась",
+    "наполнился",
+    "наполнит",
+    "наполнится",
+    "наполнить",
+    "наполню",
+    "наполняемы",
+    "наполняется",
+    "наполнял",
+    "наполнялись",
+    "наполняло",
+    "наполнялось",
+    "наполнялся",
+    "наполнять",
+    "наполняют",
+    "наполняя",
+    "наполовину",
+    "напомадил",
+    "напомадился",
+    "напомаженных",
+    "напоминавших",
+    "напоминает",
+    "напоминай",
+    "напоминал",
+    "напоминало",
+    "напоминания",
+    "напоминать",
+    "напоминаю",
+    "напоминают",
+    "напоминающий",
+    "напоминая",
+    "напомни",
+    "напомнив",
+    "напомнил",
+    "напомнила",
+    "напомнилась",
+    "напомнило",
+    "напомнит",
+    "напомните",
+    "напомнить",
+    "напор",
+    "напора",
+    "напорется",
+    "напою",
+    "напоят",
+    "направил",
+    "направились",
+    "направился",
+    "направить",
+    "направиться",
+    "направлен",
+    "направление",
+    "направлений",
+    "направлению",
+    "направления",
+    "направленных",
+    "направлено",
+    "направлю",
+    "направляется",
+    "направляют",
+    "направляясь",
+    "направо",
+    "направятся",
+    "напрасна",
+    "напрасная",
+    "напрасно",
+    "напрасной",
+    "напрасные",
+    "напрасный",
+    "напрасных",
+    "напрашиваетесь",
+    "напрашивается",
+    "напрашиваются",
+    "например",
+    "напролет",
+    "напросто",
+    "напротив",
+    "напрягает",
+    "напрягал",
+    "напрягались",
+    "напрягаю",
+    "напрягая",
+    "напрягаясь",
+    "напрягутся",
+    "напрягши",
+    "напряжение",
+    "напряжением",
+    "напряжения",
+    "напряженная",
+    "напряженно",
+    "напряженное",
+    "напряженной",
+    "напряженном",
+    "напряженный",
+    "напряженным",
+    "напряжены",
+    "напугал",
+    "напугали",
+    "напугались",
+    "напугана",
+    "напуганные",
+    "напугано",
+    "напугать",
+    "напудренные",
+    "напужались",
+    "напускное",
+    "напускные",
+    "напускным",
+    "напустило",
+    "напустился",
+    "напутает",
+    "напутаешь",
+    "напутствие",
+    "напутствии",
+    "напущенные",
+    "напьется",
+    "напьюсь",
+    "наравне",
+    "нараспашку",
+    "нарастала",
+    "нарастали",
+    "нарастание",
+    "нарастающие",
+    "нарах",
+    "нарву",
+    "нарезался",
+    "нарисовался",
+    "нарисованного",
+    "нарисованные",
+    "нарисовано",
+    "нарисовать",
+    "наркотических",
+    "народ",
+    "народа",
+    "народе",
+    "народец",
+    "народницей",
+    "народной",
+    "народный",
+    "народов",
+    "народовольческого",
+    "народом",
+    "народонаселения",
+    "народу",
+    "народы",
+    "народятся",
+    "нарочно",
+    "наруже",
+    "наружная",
+    "наружно",
+    "наружного",
+    "наружное",
+    "наружной",
+    "наружности",
+    "наружность",
+    "наружностью",
+    "наружную",
+    "наружные",
+    "наружу",
+    "нарукавнички",
+    "нарумяненную",
+    "нарушаемого",
+    "нарушает",
+    "нарушал",
+    "нарушалась",
+    "нарушали",
+    "нарушало",
+    "нарушалось",
+    "нарушать",
+    "нарушая",
+    "нарушена",
+    "нарушение",
+    "нарушения",
+    "нарушено",
+    "нарушил",
+    "нарушилось",
+    "нарушился",
+    "нарушит",
+    "нарушителей",
+    "нарушится",
+    "нарушить",
+    "нарушу",
+    "нарцизов",
+    "нарыв",
+    "нарывавший",
+    "нарывает",
+    "наряд",
+    "наряда",
+    "наряде",
+    "наряди",
+    "нарядить",
+    "нарядная",
+    "нарядно",
+    "нарядной",
+    "нарядом",
+    "наряжается",
+    "наряжалась",
+    "наряжаются",
+    "нас",
+    "насадил",
+    "насадим",
+    "насвистывает",
+    "наседка",
+    "наседке",
+    "наседки",
+    "наседку",
+    "насекомое",
+    "насел",
+    "население",
+    "населения",
+    "населенная",
+    "населенные",
+    "населились",
+    "населило",
+    "насердке",
+    "насидишься",
+    "насилие",
+    "насилием",
+    "насилия",
+    "насиловать",
+    "насилу",
+    "насильно",
+    "насильственно",
+    "насильственной",
+    "насильственною",
+    "насильственные",
+    "насильственным",
+    "насилья",
+    "насказала",
+    "насквозь",
+    "насколько",
+    "наскоро",
+    "наскочила",
+    "наскучивало",
+    "наскучил",
+    "наскучу",
+    "наслаждается",
+    "наслаждайся",
+    "наслаждайтесь",
+    "наслаждалась",
+    "наслаждались",
+    "наслаждался",
+    "наслаждаться",
+    "наслаждаюсь",
+    "наслаждаются",
+    "наслаждаясь",
+    "наслаждение",
+    "наслаждением",
+    "наслаждений",
+    "наслаждения",
+    "наследникам",
+    "наследники",
+    "наследнику",
+    "наследовавшие",
+    "наследовал",
+    "наследства",
+    "наследственной",
+    "наследство",
+    "наследству",
+    "наслышан",
+    "наслышка",
+    "насмеемся",
+    "насмерть",
+    "насмехается",
+    "насмехаюсь",
+    "насмешек",
+    "насмешил",
+    "насмешите",
+    "насмешка",
+    "насмешками",
+    "насмешки",
+    "насмешкой",
+    "насмешку",
+    "насмешлив",
+    "насмешливая",
+    "насмешливее",
+    "насмешливо",
+    "насмешливого",
+    "насмешливое",
+    "насмешливой",
+    "насмешливости",
+    "насмешливый",
+    "насмешливыми",
+    "насмешливых",
+    "насмеюсь",
+    "насмеялся",
+    "насмотрелся",
+    "насолить",
+    "наспал",
+    "наставал",
+    "наставало",
+    "наставил",
+    "наставительно",
+    "наставлений",
+    "наставлениях",
+    "наставлено",
+    "наставляй",
+    "наставница",
+    "настает",
+    "настаивавший",
+    "настаиваем",
+    "настаивает",
+    "настаиваете",
+    "настаивал",
+    "настаивала",
+    "настаивать",
+    "настаиваю",
+    "настал",
+    "настала",
+    "настали",
+    "настало",
+    "настанет",
+    "настанут",
+    "настасье",
+    "настасьей",
+    "настасьи",
+    "настасью",
+    "настасьюшка",
+    "настасья",
+    "настежь",
+    "настенька",
+    "настигающую",
+    "настлали",
+    "настоенной",
+    "настоит",
+    "настоится",
+    "настойчива",
+    "настойчиво",
+    "настойчивого",
+    "настойчивое",
+    "настойчивости",
+    "настойчивость",
+    "настойчивостью",
+    "настойчивые",
+    "настойчивый",
+    "настойчивым",
+    "настолько",
+    "настоял",
+    "настояла",
+    "настоянию",
+    "настояно",
+    "настоятельно",
+    "настоятельную",
+    "настоящая",
+    "настоящего",
+    "настоящее",
+    "настоящей",
+    "настоящем",
+    "настоящему",
+    "настоящею",
+    "настоящие",
+    "настоящий",
+    "настоящим",
+    "настоящих",
+    "настоящую",
+    "настраивает",
+    "настраивается",
+    "настрого",
+    "настроение",
+    "настроением",
+    "настроении",
+    "настроению",
+    "настроения",
+    "настроено",
+    "настроены",
+    "настроим",
+    "настроит",
+    "настроить",
+    "настройщика",
+    "настрочить",
+    "настудишь",
+    "настудят",
+    "наступает",
+    "наступала",
+    "наступают",
+    "наступивший",
+    "наступил",
+    "наступила",
+    "наступили",
+    "наступило",
+    "наступит",
+    "наступлением",
+    "насущного",
+    "насущной",
+    "насущные",
+    "насущным",
+    "насущными",
+    "насчет",
+    "насылает",
+    "насылая",
+    "насыпь",
+    "насыпью",
+    "насядет",
+    "наталия",
+    "натальи",
+    "наталья",
+    "наташа",
+    "наташе",
+    "наташей",
+    "наташи",
+    "наташу",
+    "натащил",
+    "натащить",
+    "нате",
+    "натекла",
+    "натерпелась",
+    "натерпелся",
+    "натертому",
+    "наткнулся",
+    "натолкнуть",
+    "натолочь",
+    "натопленных",
+    "натоптали",
+    "наточи",
+    "натруженный",
+    "натугой",
+    "натура",
+    "натурализме",
+    "натуральнее",
+    "натурально",
+    "натуральности",
+    "натуральную",
+    "натуральны",
+    "натуре",
+    "натурой",
+    "натуру",
+    "натуры",
+    "натыкается",
+    "натыкался",
+    "натягивает",
+    "натягивать",
+    "натяжек",
+    "натянул",
+    "натянулись",
+    "натянутая",
+    "натянуто",
+    "натянутый",
+    "наугад",
+    "наук",
+    "наука",
+    "науках",
+    "науке",
+    "науки",
+    "наукой",
+    "науку",
+    "наумыч",
+    "наутро",
+    "научи",
+    "научил",
+    "научила",
+    "научило",
+    "научился",
+    "научимся",
+    "научит",
+    "научите",
+    "научить",
+    "научные",
+    "научных",
+    "научусь",
+    "наущения",
+    "нафталина",
+    "нафталином",
+    "нахал",
+    "нахально",
+    "нахальное",
+    "нахальный",
+    "нахальным",
+    "нахальства",
+    "нахальство",
+    "нахальством",
+    "нахватает",
+    "нахватают",
+    "нахлебник",
+    "нахлестался",
+    "нахлобучив",
+    "нахмуренно",
+    "нахмуренной",
+    "нахмуренные",
+    "нахмуренный",
+    "нахмуренным",
+    "нахмуренными",
+    "нахмурив",
+    "нахмурившись",
+    "нахмурил",
+    "нахмурила",
+    "нахмурился",
+    "нахмурясь",
+    "находившейся",
+    "находил",
+    "находила",
+    "находилась",
+    "находили",
+    "находились",
+    "находило",
+    "находилось",
+    "находился",
+    "находит",
+    "находите",
+    "находится",
+    "находить",
+    "находиться",
+    "находишь",
+    "находку",
+    "находчивости",
+    "находя",
+    "находясь",
+    "находят",
+    "находятся",
+    "находящий",
+    "нахожу",
+    "нацарапает",
+    "нации",
+    "наций",
+    "национальности",
+    "национальные",
+    "нацию",
+    "нациями",
+    "начавшегося",
+    "начавшей",
+    "начавши",
+    "начавший",
+    "начал",
+    "начала",
+    "началами",
+    "началась",
+    "начале",
+    "начали",
+    "начались",
+    "начало",
+    "началом",
+    "началось",
+    "начался",
+    "начальник",
+    "начальника",
+    "начальнике",
+    "начальники",
+    "начальником",
+    "начальнику",
+    "начальница",
+    "начальницей",
+    "начальницу",
+    "начальства",
+    "начальство",
+    "начальством",
+    "начальству",
+    "начатки",
+    "начатое",
+    "начатые",
+    "начать",
+    "начерно",
+    "начертал",
+    "начертана",
+    "начертанной",
+    "начертанный",
+    "начертанных",
+    "начертать",
+    "начертил",
+    "начесть",
+    "начинавшаяся",
+    "начинавшее",
+    "начинавшейся",
+    "начинавший",
+    "начинаем",
+    "начинает",
+    "начинаете",
+    "начинается",
+    "начинаешь",
+    "начинай",
+    "начинайте",
+    "начинал",
+    "начинала",
+    "начиналась",
+    "начинали",
+    "начинались",
+    "начинало",
+    "начиналось",
+    "начинался",
+    "начинаний",
+    "начинать",
+    "начинаю",
+    "начинают",
+    "начинающая",
+    "начинающего",
+    "начинающий",
+    "начинающимся",
+    "начиная",
+    "начиненные",
+    "начинки",
+    "начинкой",
+    "начисто",
+    "начистоту",
+    "начнем",
+    "начнет",
+    "начнете",
+    "начнется",
+    "начнешь",
+    "начни",
+    "начну",
+    "начнут",
+    "начнутся",
+    "начудила",
+    "наш",
+    "наша",
+    "наше",
+    "нашего",
+    "нашей",
+    "нашел",
+    "нашелся",
+    "нашем",
+    "нашему",
+    "нашептывает",
+    "нашептывал",
+    "нашествие",
+    "нашествий",
+    "наши",
+    "нашим",
+    "нашими",
+    "наших",
+    "нашла",
+    "нашлась",
+    "нашли",
+    "нашлись",
+    "нашло",
+    "нашлось",
+    "нашу",
+    "нащипанными",
+    "нащупав",
+    "наяву",
+    "наяды",
+    "не",
+    "неаккуратна",
+    "неаккуратно",
+    "неаполитанский",
+    "неба",
+    "небе",
+    "небеленая",
+    "небес",
+    "небеса",
+    "небесам",
+    "небесами",
+    "небесах",
+    "небесная",
+    "небесно",
+    "небесное",
+    "небесной",
+    "небесном",
+    "небесные",
+    "небесных",
+    "небесполезно",
+    "неблаговоления",
+    "неблагодарен",
+    "неблагодарно",
+    "неблагодарности",
+    "неблагодарность",
+    "неблагодарностью",
+    "неблагодарные",
+    "неблагодарный",
+    "неблагонадежность",
+    "неблагонамеренных",
+    "неблагополучно",
+    "неблагоприятному",
+    "неблагородный",
+    "неблагородными",
+    "неблагородство",
+    "неблагосклонно",
+    "небледнеющих",
+    "небо",
+    "небогат",
+    "небогатый",
+    "небожитель",
+    "небойкие",
+    "небойсь",
+    "небольшая",
+    "небольшие",
+    "небольшим",
+    "небольшими",
+    "небольшого",
+    "небольшое",
+    "небольшой",
+    "небольшому",
+    "небольшую",
+    "небом",
+    "небосклон",
+    "небосклону",
+    "небось",
+    "небрежен",
+    "небрежнее",
+    "небрежно",
+    "небрежное",
+    "небрежной",
+    "небрежности",
+    "небрежностию",
+    "небрежностью",
+    "небрежною",
+    "небрежные",
+    "небрежный",
+    "небрежным",
+    "небритая",
+    "небритую",
+    "небритый",
+    "небу",
+    "небывалая",
+    "небывалого",
+    "небывалое",
+    "небывалой",
+    "небывалые",
+    "небывалый",
+    "небывалым",
+    "небывалыми",
+    "небывалых",
+    "небывальщину",
+    "небылицу",
+    "небытия",
+    "нева",
+    "неважно",
+    "неважное",
+    "неважной",
+    "неважны",
+    "неважным",
+    "невдомек",
+    "неве",
+    "неведении",
+    "неведения",
+    "неведом",
+    "неведомая",
+    "неведомо",
+    "неведомой",
+    "неведомою",
+    "неведомый",
+    "неведомым",
+    "неведомыми",
+    "неведомых",
+    "невежд",
+    "невежды",
+    "невежества",
+    "невежество",
+    "невежи",
+    "невежливая",
+    "невежливо",
+    "невежливою",
+    "невелик",
+    "невелика",
+    "невелико",
+    "невеликодушно",
+    "неверие",
+    "неверна",
+    "неверно",
+    "неверного",
+    "неверною",
+    "неверный",
+    "неверных",
+    "невероятно",
+    "невероятности",
+    "невероятные",
+    "невероятным",
+    "неверующий",
+    "неверующих",
+    "неверьем",
+    "невеселая",
+    "невесело",
+    "невеселые",
+    "невеста",
+    "невесте",
+    "невестка",
+    "невестки",
+    "невестой",
+    "невесту",
+    "невесты",
+    "невесть",
+    "невзгод",
+    "невзначай",
+    "невзрачный",
+    "невиданно",
+    "невиданного",
+    "невиданной",
+    "невиданные",
+    "невидимая",
+    "невидимо",
+    "невидимой",
+    "невидимую",
+    "невидимые",
+    "невидимый",
+    "невинен",
+    "невинна",
+    "невинная",
+    "невинно",
+    "невинного",
+    "невинном",
+    "невинному",
+    "невинности",
+    "невинность",
+    "невинностью",
+    "невинные",
+    "невинный",
+    "невинных",
+    "невиновных",
+    "невменяем",
+    "невнимания",
+    "невнимательным",
+    "невнятны",
+    "неводу",
+    "невозвратимо",
+    "невозвратимый",
+    "невозвратно",
+    "невозвратного",
+    "невозвратном",
+    "невозвратные",
+    "невозвратным",
+    "невоздержания",
+    "невозможен",
+    "невозможно",
+    "невозможное",
+    "невозможному",
+    "невозможности",
+    "невозможную",
+    "невозможный",
+    "невозможным",
+    "невозможных",
+    "невозмутимая",
+    "невозмутимее",
+    "невозмутимо",
+    "невозмутимого",
+    "невозмутимое",
+    "невозмутимый",
+    "невозмутимым",
+    "невой",
+    "неволе",
+    "неволей",
+    "неволит",
+    "невольно",
+    "невольного",
+    "невольное",
+    "невольной",
+    "невольном",
+    "невольною",
+    "невольный",
+    "невольных",
+    "невообразимо",
+    "невоскресшему",
+    "невоспитанный",
+    "невою",
+    "невпопад",
+    "невредим",
+    "невредимо",
+    "невредимого",
+    "невредимый",
+    "невский",
+    "невскому",
+    "неву",
+    "невы",
+    "невыгодно",
+    "невыкупленные",
+    "невыносим",
+    "невыносима",
+    "невыносимая",
+    "невыносимо",
+    "невыносимое",
+    "невыносимы",
+    "невыносимый",
+    "невыносимыми",
+    "невыразимая",
+    "невыразимо",
+    "невыразимого",
+    "невыразимое",
+    "невыразимом",
+    "невыразимым",
+    "невысказанном",
+    "невысоко",
+    "невысокому",
+    "невыстоявшеюся",
+    "невысыхаемым",
+    "невыторгованный",
+    "нег",
+    "негаданно",
+    "негаданной",
+    "негде",
+    "неге",
+    "неги",
+    "негибнущая",
+    "неглубокой",
+    "неглуп",
+    "неглупая",
+    "неглупый",
+    "него",
+    "неговорлив",
+    "негодная",
+    "негодном",
+    "негодный",
+    "негодным",
+    "негодование",
+    "негодованием",
+    "негодовании",
+    "негодования",
+    "негодуя",
+    "негодяем",
+    "негодяй",
+    "негодяя",
+    "негой",
+    "негоцианта",
+    "неграциозными",
+    "негреющий",
+    "негреющим",
+    "негромкие",
+    "негромко",
+    "негры",
+    "негустые",
+    "недавнего",
+    "недавнее",
+    "недавней",
+    "недавнем",
+    "недавнему",
+    "недавний",
+    "недавних",
+    "недавно",
+    "недавнюю",
+    "недавняя",
+    "недалеко",
+    "недалечко",
+    "недальней",
+    "недальный",
+    "недаром",
+    "недвижимо",
+    "недворянина",
+    "неделе",
+    "недели",
+    "неделикатно",
+    "неделикатным",
+    "неделовитостью",
+    "неделовых",
+    "недель",
+    "недельку",
+    "неделю",
+    "неделя",
+    "неделям",
+    "недетски",
+    "недетской",
+    "недлинный",
+    "недобор",
+    "недобровольно",
+    "недоброе",
+    "недоброжелателен",
+    "недоброжелательством",
+    "недоваренную",
+    "недоварены",
+    "недоверием",
+    "недоверия",
+    "недоверчив",
+    "недоверчива",
+    "недоверчиво",
+    "недоверчивости",
+    "недоверчивость",
+    "недоверчивостью",
+    "недоверчивые",
+    "недоверчивый",
+    "недоверчивым",
+    "недоволен",
+    "недовольна",
+    "недовольная",
+    "недовольны",
+    "недовольный",
+    "недовольным",
+    "недовольства",
+    "недовольство",
+    "недогадливый",
+    "недоглядел",
+    "недогляди",
+    "недоглядишь",
+    "недозрелости",
+    "недоимках",
+    "недоимки",
+    "недоконченное",
+    "недолго",
+    "недоносков",
+    "недоразумения",
+    "недорого",
+    "недосказанного",
+    "недосказанное",
+    "недоставало",
+    "недостает",
+    "недостало",
+    "недостатка",
+    "недостатками",
+    "недостатков",
+    "недостатком",
+    "недостаток",
+    "недостаточно",
+    "недостижимой",
+    "недостоин",
+    "недостойна",
+    "недостойному",
+    "недостойною",
+    "недостойны",
+    "недостойный",
+    "недостойных",
+    "недоступна",
+    "недоступно",
+    "недоступного",
+    "недоступность",
+    "недоступную",
+    "недоступный",
+    "недосягаем",
+    "недосягаемо",
+    "недосягаемым",
+    "недосягаемых",
+    "недотепа",
+    "недотрогой",
+    "недоумевал",
+    "недоумевали",
+    "недоумевая",
+    "недоумение",
+    "недоумением",
+    "недоумении",
+    "недоумений",
+    "недоумения",
+    "недоумениям",
+    "недоуменьем",
+    "недоучившихся",
+    "недочитанная",
+    "недр",
+    "недрах",
+    "недремлющая",
+    "недрогнувший",
+    "недруги",
+    "недружелюбным",
+    "недуг",
+    "недугами",
+    "недугом",
+    "недурна",
+    "недурно",
+    "нее",
+    "неестественно",
+    "неестественною",
+    "неестественных",
+    "нежа",
+    "нежат",
+    "нежданно",
+    "нежданной",
+    "нежданные",
+    "нежданный",
+    "нежели",
+    "нежен",
+    "неженского",
+    "неживой",
+    "неживою",
+    "нежинский",
+    "нежит",
+    "нежить",
+    "нежливенькая",
+    "нежна",
+    "нежная",
+    "нежнее",
+    "нежней",
+    "нежнейшего",
+    "нежнейшей",
+    "нежнейшими",
+    "нежно",
+    "нежного",
+    "нежное",
+    "нежной",
+    "нежном",
+    "нежности",
+    "нежность",
+    "нежностью",
+    "нежною",
+    "нежную",
+    "нежны",
+    "нежные",
+    "нежный",
+    "нежным",
+    "нежных",
+    "незабвенная",
+    "незабвенной",
+    "незабываемую",
+    "незабываемые",
+    "незабываемых",
+    "независимо",
+    "независимое",
+    "независимости",
+    "независимость",
+    "независимую",
+    "независимым",
+    "независящим",
+    "незадолго",
+    "незаживающие",
+    "незаконная",
+    "незаконною",
+    "незаметнее",
+    "незаметно",
+    "незаметное",
+    "незаметной",
+    "незаметную",
+    "незапамятные",
+    "незапамятных",
+    "незапертую",
+    "незапятнанном",
+    "незаработанном",
+    "незаработанный",
+    "незаслуженного",
+    "незаслуженным",
+    "незастенчивые",
+    "незатейливой",
+    "незачем",
+    "незваному",
+    "незваный",
+    "незвучно",
+    "нездешней",
+    "нездешнею",
+    "нездешний",
+    "нездешними",
+    "нездоров",
+    "нездорова",
+    "нездоровая",
+    "нездоровилось",
+    "нездоровится",
+    "нездорово",
+    "нездоровы",
+    "нездоровье",
+    "нездоровьем",
+    "неземная",
+    "неземного",
+    "неземной",
+    "неземные",
+    "незлобный",
+    "незнаемою",
+    "незнаком",
+    "незнакомец",
+    "незнакомого",
+    "незнакомое",
+    "незнакомой",
+    "незнакомом",
+    "незнакомому",
+    "незнакомству",
+    "незнакомую",
+    "незнакомцами",
+    "незнакомые",
+    "незнакомый",
+    "незнакомым",
+    "незнакомыми",
+    "незнание",
+    "незнанию",
+    "незначащие",
+    "незначительна",
+    "незначительное",
+    "незначительные",
+    "незрим",
+    "незримо",
+    "незримые",
+    "незримый",
+    "незримым",
+    "незыблемое",
+    "неизбежная",
+    "неизбежно",
+    "неизбежного",
+    "неизбежное",
+    "неизбежность",
+    "неизбежною",
+    "неизбежные",
+    "неизбежный",
+    "неизбежным",
+    "неизбежными",
+    "неизбежных",
+    "неизведанных",
+    "неизвестен",
+    "неизвестная",
+    "неизвестно",
+    "неизвестное",
+    "неизвестном",
+    "неизвестности",
+    "неизвестность",
+    "неизвестные",
+    "неизвестный",
+    "неизвестным",
+    "неизвестными",
+    "неизвестных",
+    "неизгладимо",
+    "неизгладимым",
+    "неизлечима",
+    "неизлечимую",
+    "неизлечимые",
+    "неизменной",
+    "неизменную",
+    "неизменный",
+    "неизменным",
+    "неизменяющейся",
+    "неизмеримою",
+    "неизреченной",
+    "неизъяснимо",
+    "неизъяснимое",
+    "неизъяснимых",
+    "неимением",
+    "неимоверно",
+    "неимоверные",
+    "неимоверным",
+    "неинтересно",
+    "неинтересны",
+    "неискусно",
+    "неисполненные",
+    "неисправим",
+    "неисправимый",
+    "неисправимым",
+    "неисправной",
+    "неистинном",
+    "неистовая",
+    "неистово",
+    "неистовое",
+    "неистовой",
+    "неистовые",
+    "неистовым",
+    "неистощимой",
+    "неистощимых",
+    "неистребима",
+    "неистребимого",
+    "неисходно",
+    "неисходный",
+    "ней",
+    "нейдем",
+    "нейдет",
+    "нейдут",
+    "нейрастения",
+    "нейти",
+    "некий",
+    "неких",
+    "некогда",
+    "некого",
+    "некоего",
+    "некоммерческих",
+    "некому",
+    "неконченные",
+    "неконченный",
+    "некормленая",
+    "некоторая",
+    "некоторого",
+    "некоторое",
+    "некоторой",
+    "некотором",
+    "некоторому",
+    "некоторою",
+    "некоторую",
+    "некоторые",
+    "некоторый",
+    "некоторым",
+    "некоторыми",
+    "некоторых",
+    "некошенном",
+    "некрасива",
+    "некрасиво",
+    "некрасивой",
+    "некрасивый",
+    "некрасивым",
+    "некраснеющих",
+    "некрасова",
+    "некрашеный",
+    "некрепкий",
+    "некрепок",
+    "некстати",
+    "некто",
+    "некуда",
+    "некупленных",
+    "некую",
+    "неладно",
+    "неласково",
+    "неласковый",
+    "нелегкая",
+    "нелегко",
+    "нелегкую",
+    "нелепа",
+    "нелепее",
+    "нелепей",
+    "нелепейшая",
+    "нелепо",
+    "нелепого",
+    "нелепое",
+    "нелепости",
+    "нелепость",
+    "нелепый",
+    "нелепым",
+    "нелживо",
+    "нелицемерной",
+    "неловкая",
+    "неловкий",
+    "неловким",
+    "неловко",
+    "неловкого",
+    "неловкое",
+    "неловкости",
+    "неловкость",
+    "неловкостью",
+    "неловок",
+    "неложно",
+    "нельзя",
+    "нельки",
+    "нелюбимый",
+    "нелюбовь",
+    "нем",
+    "немалая",
+    "немало",
+    "немедленно",
+    "немедленного",
+    "немедленной",
+    "немедленном",
+    "немедленным",
+    "немеет",
+    "немезида",
+    "немели",
+    "немеркнущей",
+    "немеркнущий",
+    "немец",
+    "немецкая",
+    "немецки",
+    "немецкие",
+    "немецкий",
+    "немецким",
+    "немецких",
+    "немецкого",
+    "немецкой",
+    "немецком",
+    "немецкую",
+    "немилосердно",
+    "неминуемая",
+    "неминуемо",
+    "неминуемое",
+    "неминуемою",
+    "неминучий",
+    "немка",
+    "немке",
+    "немки",
+    "немку",
+    "немногие",
+    "немногими",
+    "немногих",
+    "немного",
+    "немножечко",
+    "немножко",
+    "немо",
+    "немое",
+    "немой",
+    "немок",
+    "немолодой",
+    "немолодому",
+    "немолоды",
+    "немолодых",
+    "немолчный",
+    "немом",
+    "немота",
+    "немотствует",
+    "немочь",
+    "немощами",
+    "немощеного",
+    "немощеными",
+    "нему",
+    "немудреная",
+    "немудрено",
+    "немудреный",
+    "немудрый",
+    "немую",
+    "немца",
+    "немцами",
+    "немцев",
+    "немцем",
+    "немцу",
+    "немцы",
+    "немые",
+    "немым",
+    "немыслимо",
+    "немыслимы",
+    "немытого",
+    "немытую",
+    "немытый",
+    "немых",
+    "ненавидел",
+    "ненавидела",
+    "ненавидели",
+    "ненавидеть",
+    "ненавидит",
+    "ненавидите",
+    "ненавидишь",
+    "ненавидя",
+    "ненавидят",
+    "ненавижу",
+    "ненавистен",
+    "ненависти",
+    "ненавистию",
+    "ненавистна",
+    "ненавистная",
+    "ненавистничают",
+    "ненавистно",
+    "ненавистного",
+    "ненавистное",
+    "ненавистною",
+    "ненавистным",
+    "ненавистными",
+    "ненависть",
+    "ненавистью",
+    "ненаглядная",
+    "ненаглядную",
+    "ненаглядный",
+    "ненаглядным",
+    "ненадежно",
+    "ненадежных",
+    "ненадолго",
+    "ненароком",
+    "ненарушимая",
+    "ненарушимо",
+    "ненарушимого",
+    "ненастной",
+    "ненастный",
+    "ненастья",
+    "ненасытимое",
+    "ненасытимый",
+    "ненасытность",
+    "ненасытную",
+    "ненатурально",
+    "ненормальности",
+    "ненормальным",
+    "ненужная",
+    "ненужно",
+    "ненужного",
+    "ненужной",
+    "ненужность",
+    "ненужную",
+    "ненужны",
+    "ненужные",
+    "ненужный",
+    "ненужным",
+    "необдуманно",
+    "необдуманное",
+    "необитаемою",
+    "необитаемыми",
+    "необозримая",
+    "необозримо",
+    "необозримого",
+    "необозримой",
+    "необразованная",
+    "необразованный",
+    "необузданной",
+    "необходим",
+    "необходима",
+    "необходимейшие",
+    "необходимо",
+    "необходимого",
+    "необходимое",
+    "необходимости",
+    "необходимостию",
+    "необходимость",
+    "необходимостью",
+    "необходимою",
+    "необходимы",
+    "необходимые",
+    "необходимыми",
+    "необъяснимо",
+    "необъяснимое",
+    "необъяснимые",
+    "необъяснимым",
+    "необъятная",
+    "необъятно",
+    "необъятного",
+    "необъятное",
+    "необъятном",
+    "необъятною",
+    "необъятную",
+    "необъятные",
+    "необыденное",
+    "необыкновенная",
+    "необыкновенно",
+    "необыкновенного",
+    "необыкновенное",
+    "необыкновенном",
+    "необыкновенному",
+    "необыкновенною",
+    "необыкновенную",
+    "необыкновенные",
+    "необыкновенный",
+    "необыкновенным",
+    "необыкновенных",
+    "необычайнейшее",
+    "необычайное",
+    "необычайной",
+    "необычайности",
+    "необычайный",
+    "необычайным",
+    "необычный",
+    "неоднократно",
+    "неоднократный",
+    "неодобрение",
+    "неодолимая",
+    "неодушевленный",
+    "неожиданная",
+    "неожиданнейшим",
+    "неожиданно",
+    "неожиданного",
+    "неожиданное",
+    "неожиданному",
+    "неожиданность",
+    "неожиданною",
+    "неожиданную",
+    "неожиданны",
+    "неожиданные",
+    "неожиданный",
+    "неожиданным",
+    "неожиданными",
+    "неоконченная",
+    "неокрепших",
+    "неопасен",
+    "неопасно",
+    "неописанна",
+    "неописанная",
+    "неописанном",
+    "неописанный",
+    "неопределенно",
+    "неопределенное",
+    "неопределенной",
+    "неопределенною",
+    "неопределенные",
+    "неопределенным",
+    "неопределенными",
+    "неопределенных",
+    "неопровержимой",
+    "неопрятен",
+    "неопрятнее",
+    "неопрятном",
+    "неопрятный",
+    "неопытного",
+    "неопытному",
+    "неопытности",
+    "неопытные",
+    "неопытный",
+    "неосновательны",
+    "неоспоримый",
+    "неосторожнее",
+    "неосторожно",
+    "неосторожного",
+    "неосторожное",
+    "неосторожности",
+    "неосторожность",
+    "неосторожны",
+    "неосторожным",
+    "неостроумен",
+    "неосуществима",
+    "неосуществимым",
+    "неотвратимо",
+    "неотвратимый",
+    "неотвязная",
+    "неотвязный",
+    "неотвязчивую",
+    "неотвязчивый",
+    "неотделанную",
+    "неотесанный",
+    "неоткуда",
+    "неотлагательное",
+    "неотлагательные",
+    "неотлагательным",
+    "неотлучно",
+    "неотразим",
+    "неотразимо",
+    "неотразимого",
+    "неотразимое",
+    "неотразимою",
+    "неотразимые",
+    "неотразимый",
+    "неотразимых",
+    "неотсрочимый",
+    "неотступная",
+    "неотступнее",
+    "неотступно",
+    "неотступный",
+    "неотъемлемо",
+    "неохота",
+    "неохотно",
+    "неохотой",
+    "неохотою",
+    "неоцененных",
+    "непарадных",
+    "неплотно",
+    "непобедимой",
+    "непобедимый",
+    "непобедимым",
+    "неповинен",
+    "неповоротливой",
+    "неповоротливом",
+    "неповторимая",
+    "непогибшим",
+    "непогоды",
+    "неподалеку",
+    "неподвижен",
+    "неподвижна",
+    "неподвижная",
+    "неподвижнее",
+    "неподвижно",
+    "неподвижного",
+    "неподвижное",
+    "неподвижной",
+    "неподвижности",
+    "неподвижность",
+    "неподвижностью",
+    "неподвижною",
+    "неподвижные",
+    "неподвижный",
+    "неподвижным",
+    "неподвижными",
+    "неподдельного",
+    "неподдельное",
+    "неподкупная",
+    "неподкупном",
+    "неподобно",
+    "неподражаем",
+    "неподражаемо",
+    "неподходящие",
+    "непозволительно",
+    "непоказанной",
+    "непокладные",
+    "непокойна",
+    "непокойно",
+    "непоколебимее",
+    "непоколебимой",
+    "непоколебимыми",
+    "непокорных",
+    "непокрытой",
+    "неполнота",
+    "неполный",
+    "неположенные",
+    "непомерной",
+    "непомерный",
+    "непомерным",
+    "непонимании",
+    "непонятая",
+    "непонятливости",
+    "непонятливый",
+    "непонятна",
+    "непонятная",
+    "непонятно",
+    "непонятного",
+    "непонятное",
+    "непонятному",
+    "непонятный",
+    "непонятным",
+    "непорядком",
+    "непорядочно",
+    "непосвященные",
+    "непосильной",
+    "непослушны",
+    "непосредственного",
+    "непосредственное",
+    "непосредственной",
+    "непостижима",
+    "непостижимо",
+    "непостижимый",
+    "непота",
+    "непотребного",
+    "непохожее",
+    "непохожей",
+    "непочтительна",
+    "непочтительностию",
+    "неправда",
+    "неправду",
+    "неправедные",
+    "неправильного",
+    "неправильное",
+    "неправильной",
+    "неправильности",
+    "неправильность",
+    "неправильный",
+    "неправленный",
+    "неправые",
+    "непредвиденных",
+    "непреклонна",
+    "непреклонная",
+    "непрекращающегося",
+    "непреложным",
+    "непремен",
+    "непременно",
+    "непременною",
+    "непреодолимое",
+    "непрерывно",
+    "непрерывное",
+    "непрерывной",
+    "непрерывный",
+    "непрерывным",
+    "непрестанной",
+    "непривилегированные",
+    "непривлекательной",
+    "непривычки",
+    "непривычного",
+    "непривычное",
+    "непривычным",
+    "непривычных",
+    "неприготовленную",
+    "непризнанный",
+    "непризнанным",
+    "неприкосновенное",
+    "неприкосновенность",
+    "неприличия",
+    "неприлично",
+    "неприличное",
+    "неприличном",
+    "неприличности",
+    "неприличным",
+    "неприметнее",
+    "неприметно",
+    "непримиримых",
+    "непринужденности",
+    "непринужденный",
+    "неприступная",
+    "неприступны",
+    "неприступные",
+    "непритворенную",
+    "непритворно",
+    "непритворной",
+    "непритворным",
+    "непричастен",
+    "непричесанной",
+    "неприязненно",
+    "неприязнь",
+    "неприятель",
+    "неприятельский",
+    "неприятельским",
+    "неприятен",
+    "неприятнейшее",
+    "неприятно",
+    "неприятного",
+    "неприятное",
+    "неприятной",
+    "неприятном",
+    "неприятному",
+    "неприятностей",
+    "неприятности",
+    "неприятностию",
+    "неприятность",
+    "неприятностях",
+    "неприятные",
+    "неприятный",
+    "неприятным",
+    "неприятными",
+    "непробудней",
+    "непробудно",
+    "непробудной",
+    "непробудную",
+    "непробудные",
+    "непробудный",
+    "непроглядном",
+    "непроглядный",
+    "непродажных",
+    "непродолжительный",
+    "непроезжий",
+    "непролазная",
+    "непроницаема",
+    "непроницаемости",
+    "непроницаемы",
+    "непроницаемым",
+    "непроницаемых",
+    "непростительно",
+    "непростительной",
+    "непростительную",
+    "непростительные",
+    "непроходимая",
+    "непрочен",
+    "непрошеные",
+    "непрошеных",
+    "непрядва",
+    "непрядвой",
+    "непутное",
+    "неравенством",
+    "неравна",
+    "неравнодушен",
+    "нерадению",
+    "нерадостно",
+    "неразборчиво",
+    "неразвернувшийся",
+    "неразвитость",
+    "неразвиты",
+    "неразгаданного",
+    "неразговорчив",
+    "неразговорчивы",
+    "неразделенную",
+    "нераздельности",
+    "неразлучна",
+    "неразлучны",
+    "неразрезанною",
+    "неразрешаемые",
+    "неразрешенных",
+    "неразрешим",
+    "неразрешимому",
+    "неразрешимы",
+    "неразрешимый",
+    "неразрываемую",
+    "неразрывно",
+    "неразрывного",
+    "неразрывную",
+    "нерасположение",
+    "нерасчетливо",
+    "нерв",
+    "нервами",
+    "нервическая",
+    "нервический",
+    "нервическим",
+    "нервическое",
+    "нервная",
+    "нервно",
+    "нервное",
+    "нервной",
+    "нервною",
+    "нервны",
+    "нервный",
+    "нервным",
+    "нервных",
+    "нервов",
+    "нервы",
+    "нередко",
+    "нерешенное",
+    "нерешимости",
+    "нерешительно",
+    "нерешительном",
+    "нерешительности",
+    "нерешительность",
+    "нерешительностью",
+    "нерешительны",
+    "неровно",
+    "неровности",
+    "нерукотворный",
+    "неряхой",
+    "нес",
+    "несбывшееся",
+    "несбыточной",
+    "несбыточною",
+    "несбыточную",
+    "несбыточные",
+    "несбыточный",
+    "несбыточных",
+    "несвежем",
+    "несвежи",
+    "несвежую",
+    "несветский",
+    "несвойственным",
+    "несвязанный",
+    "несвязно",
+    "несвязный",
+    "несгораемые",
+    "несдобровать",
+    "несерьезным",
+    "несессер",
+    "несессеры",
+    "несет",
+    "несете",
+    "несется",
+    "несешь",
+    "неси",
+    "несказа",
+    "несказанно",
+    "нескладная",
+    "нескладно",
+    "нескладном",
+    "несколькими",
+    "нескольких",
+    "несколько",
+    "нескольку",
+    "нескончаемой",
+    "нескончаемому",
+    "нескончаемый",
+    "нескончаемым",
+    "нескромности",
+    "нескромный",
+    "нескрываемая",
+    "нескрываемым",
+    "нескучно",
+    "несла",
+    "неслабый",
+    "неслась",
+    "несли",
+    "неслись",
+    "неслиянности",
+    "несло",
+    "несловоохотлив",
+    "неслось",
+    "неслушавшие",
+    "неслыханно",
+    "неслыханное",
+    "неслыханной",
+    "неслыханном",
+    "неслыханному",
+    "неслыханные",
+    "неслыханным",
+    "неслыханными",
+    "неслыханных",
+    "неслышно",
+    "неслышным",
+    "несмелые",
+    "несметные",
+    "несметный",
+    "несмотря",
+    "несмыкаемости",
+    "несносна",
+    "несносно",
+    "несносное",
+    "несносную",
+    "несносный",
+    "несовершенно",
+    "несовершеннолетнее",
+    "несовершеннолетний",
+    "несовершенств",
+    "несогласие",
+    "несогласимых",
+    "несогласию",
+    "несомненно",
+    "несомненного",
+    "несомненною",
+    "несомненный",
+    "несомненным",
+    "несомненных",
+    "несообразный",
+    "несообщителен",
+    "несоразмерно",
+    "несостоятельны",
+    "неспешно",
+    "неспешные",
+    "неспешный",
+    "неспешным",
+    "неспокойна",
+    "неспокойно",
+    "неспособность",
+    "неспособным",
+    "несправедлива",
+    "несправедливая",
+    "несправедливо",
+    "несправедливого",
+    "несправедливое",
+    "несправедливость",
+    "несправедливостью",
+    "несправедливую",
+    "несправедливы",
+    "несправедливые",
+    "несравненном",
+    "несравненный",
+    "нессиметрично",
+    "нестерпим",
+    "нестерпима",
+    "нестерпимая",
+    "нестерпимо",
+    "нестерпимого",
+    "нестерпимой",
+    "нестерпимом",
+    "нестерпимый",
+    "нести",
+    "нестиранного",
+    "нестройном",
+    "несу",
+    "несут",
+    "несущейся",
+    "несуществующий",
+    "несущие",
+    "несущиеся",
+    "несущий",
+    "несущихся",
+    "несч",
+    "несчастен",
+    "несчастие",
+    "несчастии",
+    "несчастий",
+    "несчастию",
+    "несчастия",
+    "несчастиях",
+    "несчастлив",
+    "несчастлива",
+    "несчастливейшим",
+    "несчастливый",
+    "несчастна",
+    "несчастная",
+    "несчастнее",
+    "несчастненькой",
+    "несчастного",
+    "несчастное",
+    "несчастной",
+    "несчастном",
+    "несчастному",
+    "несчастною",
+    "несчастную",
+    "несчастны",
+    "несчастные",
+    "несчастный",
+    "несчастным",
+    "несчастными",
+    "несчастных",
+    "несчастье",
+    "несчастьем",
+    "несчастью",
+    "несчастья",
+    "несчастьями",
+    "несчетные",
+    "несытым",
+    "неся",
+    "нет",
+    "нетактично",
+    "нетвердо",
+    "нетвердой",
+    "нетвердым",
+    "нетвердыми",
+    "нетверез",
+    "нетерепении",
+    "нетерпелив",
+    "нетерпеливо",
+    "нетерпеливого",
+    "нетерпеливое",
+    "нетерпеливой",
+    "нетерпеливому",
+    "нетерпеливы",
+    "нетерпеливый",
+    "нетерпеливым",
+    "нетерпение",
+    "нетерпением",
+    "нетерпении",
+    "нетерпения",
+    "нетопленную",
+    "неторопливо",
+    "нетрезвом",
+    "нетронутую",
+    "нетронутый",
+    "нету",
+    "нетяжко",
+    "неуважения",
+    "неуважительный",
+    "неуверенном",
+    "неувядаемая",
+    "неувядающая",
+    "неувядающим",
+    "неувязанное",
+    "неугодлив",
+    "неугодливая",
+    "неугодно",
+    "неугомонный",
+    "неудавшейся",
+    "неудавшиеся",
+    "неудавшуюся",
+    "неудач",
+    "неудача",
+    "неудачами",
+    "неудаче",
+    "неудачи",
+    "неудачная",
+    "неудачно",
+    "неудержимее",
+    "неудержимо",
+    "неудержимого",
+    "неудержимую",
+    "неудержимым",
+    "неудобно",
+    "неудобной",
+    "неудобным",
+    "неудобств",
+    "неудобства",
+    "неудобствами",
+    "неудобстве",
+    "неудобству",
+    "неудовлетворенность",
+    "неудовлетворительными",
+    "неудовольствие",
+    "неудовольствием",
+    "неудовольствия",
+    "неужели",
+    "неужель",
+    "неузнаваемого",
+    "неузнаваемой",
+    "неузнанной",
+    "неуклонно",
+    "неуклюж",
+    "неуклюжая",
+    "неуклюже",
+    "неуклюжее",
+    "неуклюжий",
+    "неуклюжим",
+    "неукоснительно",
+    "неукрашенное",
+    "неукротимей",
+    "неуловимо",
+    "неуловимое",
+    "неуловимый",
+    "неуложенное",
+    "неумело",
+    "неумелые",
+    "неумен",
+    "неумением",
+    "неуменьем",
+    "неуменья",
+    "неумеренная",
+    "неумеренно",
+    "неумеренное",
+    "неуместною",
+    "неумирающего",
+    "неумной",
+    "неумолим",
+    "неумолима",
+    "неумолимо",
+    "неумолимый",
+    "неумолкаемый",
+    "неумолкающей",
+    "неумолчной",
+    "неумолчные",
+    "неумытого",
+    "неумытый",
+    "неумышленно",
+    "неумышленное",
+    "неуныло",
+    "неурожае",
+    "неурожай",
+    "неурядиц",
+    "неуследимый",
+    "неуспеха",
+    "неустанный",
+    "неустающие",
+    "неустойки",
+    "неустранимых",
+    "неустрашимо",
+    "неустройств",
+    "неусыпно",
+    "неутешными",
+    "неутолимую",
+    "неутомимая",
+    "неутомимо",
+    "неутомимым",
+    "неученых",
+    "неучтиво",
+    "неучтивости",
+    "неуютно",
+    "нехитрого",
+    "нехождению",
+    "нехороши",
+    "нехорошие",
+    "нехороших",
+    "нехорошо",
+    "нехоть",
+    "нехотя",
+    "нехристь",
+    "нецеремонные",
+    "нечальны",
+    "нечаянно",
+    "нечаянного",
+    "нечаянностей",
+    "нечаянный",
+    "нечаянным",
+    "нечего",
+    "нечеловеческих",
+    "нечем",
+    "нечему",
+    "нечесаную",
+    "нечесаный",
+    "нечет",
+    "нечиновных",
+    "нечисто",
+    "нечистота",
+    "нечистоту",
+    "нечистоты",
+    "нечистым",
+    "нечищенным",
+    "нечищеные",
+    "нечопорно",
+    "нечто",
+    "нечувствительно",
+    "нечуток",
+    "нешто",
+    "нештопанного",
+    "нештопаных",
+    "нешуточному",
+    "нею",
+    "неявившихся",
+    "неярки",
+    "неяркий",
+    "неясна",
+    "неясная",
+    "неясно",
+    "неясное",
+    "неясном",
+    "неясному",
+    "неясные",
+    "неясный",
+    "неясным",
+    "неясных",
+    "ни",
+    "нибудь",
+    "ниве",
+    "нивы",
+    "нигде",
+    "нигилизм",
+    "нигилист",
+    "нигилистов",
+    "нигилисты",
+    "ниже",
+    "нижеподписавшийся",
+    "нижнего",
+    "нижнее",
+    "нижней",
+    "нижнем",
+    "нижнему",
+    "нижнею",
+    "нижний",
+    "нижних",
+    "нижнюю",
+    "нижняя",
+    "низать",
+    "низведении",
+    "низвела",
+    "низводила",
+    "низенькие",
+    "низенький",
+    "низка",
+    "низкая",
+    "низки",
+    "низкие",
+    "низкий",
+    "низким",
+    "низких",
+    "низко",
+    "низкого",
+    "низкое",
+    "низкой",
+    "низком",
+    "низкую",
+    "низменный",
+    "низок",
+    "низости",
+    "низость",
+    "низшего",
+    "низшее",
+    "низшем",
+    "низший",
+    "никак",
+    "никакая",
+    "никакие",
+    "никаким",
+    "никакими",
+    "никаких",
+    "никакого",
+    "никакое",
+    "никакой",
+    "никанора",
+    "никем",
+    "никита",
+    "никите",
+    "никитича",
+    "никитой",
+    "никиту",
+    "никиты",
+    "никифоровна",
+    "никли",
+    "никнет",
+    "никогда",
+    "никого",
+    "никодим",
+    "никодима",
+    "никодиме",
+    "никодимом",
+    "никодиму",
+    "никоим",
+    "николавна",
+    "николаевич",
+    "николаевича",
+    "николаевичу",
+    "николаевна",
+    "николаевне",
+    "николаевной",
+    "николаевну",
+    "николаевских",
+    "николаевском",
+    "николаем",
+    "николаичем",
+    "николай",
+    "николашка",
+    "николашке",
+    "николаю",
+    "николая",
+    "николы",
+    "никому",
+    "никто",
+    "никуда",
+    "нил",
+    "ним",
+    "нимало",
+    "нимбы",
+    "ними",
+    "нимфа",
+    "нина",
+    "нине",
+    "ниной",
+    "нину",
+    "нины",
+    "ниоткуда",
+    "нипочем",
+    "нисколько",
+    "ниспосланное",
+    "нисходила",
+    "нисходит",
+    "нисходят",
+    "нити",
+    "нитка",
+    "нитками",
+    "нитки",
+    "ниткой",
+    "нитку",
+    "ниток",
+    "ниточками",
+    "ниточке",
+    "нить",
+    "нитью",
+    "нитяной",
+    "нитяный",
+    "них",
+    "ниц",
+    "ницце",
+    "ницше",
+    "ничего",
+    "ничегошечко",
+    "ничей",
+    "ничем",
+    "ничему",
+    "ничком",
+    "ничто",
+    "ничтожен",
+    "ничтожества",
+    "ничтожество",
+    "ничтожная",
+    "ничтожнейшее",
+    "ничтожнейшем",
+    "ничтожнейший",
+    "ничтожного",
+    "ничтожной",
+    "ничтожностию",
+    "ничтожною",
+    "ничтожны",
+    "ничтожные",
+    "ничтожный",
+    "ничтожным",
+    "ничтожных",
+    "ничуть",
+    "ничьего",
+    "ничьей",
+    "ничьи",
+    "ничьим",
+    "ничьих",
+    "ничья",
+    "нише",
+    "нищ",
+    "нищая",
+    "нищего",
+    "нищей",
+    "нищему",
+    "нищенские",
+    "нищенской",
+    "нищета",
+    "нищете",
+    "нищетой",
+    "нищету",
+    "нищеты",
+    "нищие",
+    "нищий",
+    "нищим",
+    "нищими",
+    "нищих",
+    "нмением",
+    "нная",
+    "нной",
+    "ннх",
+    "нных",
+    "но",
+    "новая",
+    "новейшая",
+    "новейшее",
+    "новейший",
+    "новейшими",
+    "новейших",
+    "новенькие",
+    "новеньким",
+    "новенького",
+    "новенькое",
+    "новехонькая",
+    "новизне",
+    "новичка",
+    "новичкам",
+    "новички",
+    "новичок",
+    "ново",
+    "нового",
+    "новодевичий",
+    "новое",
+    "новой",
+    "новом",
+    "новому",
+    "новорожденный",
+    "новоселье",
+    "новостей",
+    "новости",
+    "новость",
+    "новостью",
+    "новостями",
+    "новостях",
+    "новою",
+    "новоявленный",
+    "новую",
+    "новые",
+    "новый",
+    "новым",
+    "новыми",
+    "новых",
+    "новь",
+    "новью",
+    "ног",
+    "нога",
+    "ногам",
+    "ногами",
+    "ногах",
+    "ноге",
+    "ноги",
+    "ного",
+    "ногой",
+    "ноготками",
+    "ногою",
+    "ногтем",
+    "ногти",
+    "ногтями",
+    "ногу",
+    "ноев",
+    "ноет",
+    "нож",
+    "ножа",
+    "ножами",
+    "ноже",
+    "ножей",
+    "ножи",
+    "ножик",
+    "ножичек",
+    "ножищами",
+    "ножка",
+    "ножками",
+    "ножки",
+    "ножницами",
+    "ножнички",
+    "ножом",
+    "ноль",
+    "номер",
+    "номере",
+    "номинально",
+    "ноне",
+    "норе",
+    "норке",
+    "норки",
+    "норма",
+    "нормален",
+    "нормальная",
+    "нормально",
+    "нормальное",
+    "нормальной",
+    "нормальному",
+    "нормальную",
+    "нормальный",
+    "нормальным",
+    "норму",
+    "нормы",
+    "норов",
+    "норовил",
+    "норовит",
+    "норы",
+    "нос",
+    "носа",
+    "носами",
+    "носе",
+    "носики",
+    "носил",
+    "носила",
+    "носилась",
+    "носили",
+    "носились",
+    "носило",
+    "носился",
+    "носиль",
+    "носильщики",
+    "носит",
+    "носитесь",
+    "носится",
+    "носить",
+    "носиться",
+    "носишь",
+    "носка",
+    "носками",
+    "носке",
+    "носки",
+    "носков",
+    "носком",
+    "носовой",
+    "носок",
+    "носом",
+    "носу",
+    "носы",
+    "носясь",
+    "носят",
+    "носятся",
+    "носящих",
+    "нот",
+    "нота",
+    "нотами",
+    "ноте",
+    "нотка",
+    "нотке",
+    "нотки",
+    "нотой",
+    "ноту",
+    "нотунг",
+    "ноты",
+    "ночам",
+    "ночами",
+    "ночах",
+    "ночевавший",
+    "ночевал",
+    "ночевать",
+    "ночевывал",
+    "ночей",
+    "ночи",
+    "ночлег",
+    "ночная",
+    "ночно",
+    "ночного",
+    "ночное",
+    "ночной",
+    "ночном",
+    "ночною",
+    "ночную",
+    "ночные",
+    "ночным",
+    "ночными",
+    "ночных",
+    "ночуем",
+    "ночует",
+    "ночуете",
+    "ночуешь",
+    "ночую",
+    "ночь",
+    "ночью",
+    "ноша",
+    "ношей",
+    "ношу",
+    "ношусь",
+    "нощи",
+    "нощно",
+    "ноют",
+    "ноябре",
+    "ноябрь",
+    "ноября",
+    "нрав",
+    "нрава",
+    "нравах",
+    "нраве",
+    "нравилась",
+    "нравились",
+    "нравилось",
+    "нравился",
+    "нравитесь",
+    "нравится",
+    "нравиться",
+    "нравов",
+    "нравом",
+    "нравоучения",
+    "нравственная",
+    "нравственно",
+    "нравственного",
+    "нравственное",
+    "нравственной",
+    "нравственном",
+    "нравственности",
+    "нравственность",
+    "нравственною",
+    "нравственную",
+    "нравственные",
+    "нравственным",
+    "нравственными",
+    "нравственных",
+    "нраву",
+    "нравы",
+    "нравятся",
+    "ну",
+    "нудная",
+    "нудные",
+    "нудный",
+    "нужда",
+    "нуждается",
+    "нуждался",
+    "нуждах",
+    "нуждаюсь",
+    "нуждаются",
+    "нуждаясь",
+    "нужде",
+    "нуждой",
+    "нужду",
+    "нужды",
+    "нужен",
+    "нужна",
+    "нужная",
+    "нужнее",
+    "нужно",
+    "нужное",
+    "нужной",
+    "нужную",
+    "нужны",
+    "нужные",
+    "нужным",
+    "нужных",
+    "нулю",
+    "нумер",
+    "нумера",
+    "нумерам",
+    "нумерами",
+    "нумерах",
+    "нумере",
+    "нумеров",
+    "нумером",
+    "нутру",
+    "ны",
+    "ные",
+    "ныне",
+    "нынешнего",
+    "нынешнее",
+    "нынешней",
+    "нынешнем",
+    "нынешнему",
+    "нынешние",
+    "нынешний",
+    "нынешнюю",
+    "нынче",
+    "ньютон",
+    "ньютоновы",
+    "нюхает",
+    "нюхала",
+    "нюханье",
+    "нюхая",
+    "няне",
+    "няней",
+    "нянек",
+    "нянечка",
+    "няни",
+    "нянчась",
+    "нянчил",
+    "нянчились",
+    "нянчусь",
+    "нянька",
+    "няньками",
+    "няньке",
+    "няньки",
+    "няньку",
+    "няня",
+    "нях",
+    "о",
+    "оазисе",
+    "об",
+    "оба",
+    "обагри",
+    "обагрившая",
+    "обагрит",
+    "обанкрутится",
+    "обаяние",
+    "обаянием",
+    "обаянию",
+    "обаяния",
+    "обаяньем",
+    "обаятельная",
+    "обаятельного",
+    "обаятельное",
+    "обаятельной",
+    "обаятельный",
+    "обвалиться",
+    "обварил",
+    "обвел",
+    "обвела",
+    "обветшал",
+    "обвешивают",
+    "обвив",
+    "обвивал",
+    "обвивали",
+    "обвиваясь",
+    "обвила",
+    "обвились",
+    "обвинение",
+    "обвинении",
+    "обвинений",
+    "обвинению",
+    "обвинения",
+    "обвиненного",
+    "обвинив",
+    "обвинил",
+    "обвинила",
+    "обвинительные",
+    "обвинить",
+    "обвинишь",
+    "обвиняете",
+    "обвиняешь",
+    "обвинял",
+    "обвиняли",
+    "обвинять",
+    "обвиняю",
+    "обвиняющим",
+    "обвитый",
+    "обводила",
+    "обводили",
+    "обводить",
+    "обводят",
+    "обворожительно",
+    "обворожительными",
+    "обвязал",
+    "обвязано",
+    "обвязаны",
+    "обглоданной",
+    "обдавало",
+    "обдадут",
+    "обдает",
+    "обдаешь",
+    "обдал",
+    "обдать",
+    "обделаешь",
+    "обделать",
+    "обдергивались",
+    "обдерет",
+    "обдирать",
+    "обдует",
+    "обдуло",
+    "обдумав",
+    "обдумаем",
+    "обдумайте",
+    "обдумал",
+    "обдумала",
+    "обдуман",
+    "обдуманно",
+    "обдумать",
+    "обдумывает",
+    "обдумывал",
+    "обдумывая",
+    "обе",
+    "обегал",
+    "обегая",
+    "обед",
+    "обеда",
+    "обедаем",
+    "обедает",
+    "обедаешь",
+    "обедай",
+    "обедал",
+    "обедала",
+    "обедали",
+    "обедать",
+    "обедах",
+    "обедаю",
+    "обедают",
+    "обедая",
+    "обеде",
+    "обеденной",
+    "обедне",
+    "обедневший",
+    "обедней",
+    "обедни",
+    "обедню",
+    "обедня",
+    "обедов",
+    "обедом",
+    "обеду",
+    "обеды",
+    "обежал",
+    "обежит",
+    "обездоленный",
+    "обезличивают",
+    "обезображеннее",
+    "обезображенной",
+    "обезображено",
+    "обезобразили",
+    "обезоруженный",
+    "обезуме",
+    "обезумел",
+    "обеим",
+    "обеими",
+    "обеих",
+    "оберегаемая",
+    "оберегали",
+    "оберегать",
+    "оберегаю",
+    "оберечь",
+    "оберешься",
+    "обернется",
+    "обернув",
+    "обернувшись",
+    "обернул",
+    "обернулась",
+    "обернулись",
+    "обернулось",
+    "обернулся",
+    "обернуты",
+    "обернуться",
+    "обертках",
+    "обескуражены",
+    "обеспечена",
+    "обеспечение",
+    "обеспеченный",
+    "обеспечено",
+    "обеспечить",
+    "обеспокоена",
+    "обеспокоенная",
+    "обеспокоил",
+    "обеспокоила",
+    "обеспокоили",
+    "обеспокоить",
+    "обеспокою",
+    "обеспокоятся",
+    "обессиление",
+    "обессиленный",
+    "обессилил",
+    "обессилили",
+    "обетова",
+    "обетование",
+    "обетованной",
+    "обеты",
+    "обещает",
+    "обещаете",
+    "обещаетесь",
+    "обещай",
+    "обещал",
+    "обещала",
+    "обещали",
+    "обещались",
+    "обещался",
+    "обещание",
+    "обещанием",
+    "обещаний",
+    "обещанию",
+    "обещания",
+    "обещаниях",
+    "обещанья",
+    "обещать",
+    "обещаюсь",
+    "обещая",
+    "обещаясь",
+    "обжег",
+    "обжегся",
+    "обжечь",
+    "обживаясь",
+    "обжигаем",
+    "обжигаешь",
+    "обжигательная",
+    "обжог",
+    "обжора",
+    "обивал",
+    "обивать",
+    "обид",
+    "обида",
+    "обидевшись",
+    "обидел",
+    "обидела",
+    "обиделась",
+    "обиделись",
+    "обиделся",
+    "обидеть",
+    "обидеться",
+    "обиднее",
+    "обидно",
+    "обидное",
+    "обидной",
+    "обидном",
+    "обидному",
+    "обидны",
+    "обидой",
+    "обиду",
+    "обидчивость",
+    "обидчиком",
+    "обидчику",
+    "обиды",
+    "обидятся",
+    "обижает",
+    "обижаете",
+    "обижаетесь",
+    "обижается",
+    "обижаешься",
+    "обижайтесь",
+    "обижалась",
+    "обижался",
+    "обижать",
+    "обижаться",
+    "обижаю",
+    "обижают",
+    "обижаются",
+    "обижена",
+    "обиженная",
+    "обиженно",
+    "обиженный",
+    "обиженным",
+    "обилие",
+    "обилием",
+    "обилии",
+    "обилия",
+    "обильно",
+    "обильное",
+    "обильный",
+    "обильным",
+    "обиняков",
+    "обираешь",
+    "обирать",
+    "обираю",
+    "обит",
+    "обитавшие",
+    "обитатели",
+    "обитатель",
+    "обитая",
+    "обитые",
+    "обитый",
+    "обитым",
+    "обиходе",
+    "обкрадывал",
+    "облагодетельствовал",
+    "облагодетельствована",
+    "облагодетельствованных",
+    "облагороженному",
+    "облагороженных",
+    "обладателем",
+    "облак",
+    "облака",
+    "облаками",
+    "облаках",
+    "облаке",
+    "облако",
+    "облаков",
+    "облаком",
+    "обласкала",
+    "обласкали",
+    "обласкают",
+    "областей",
+    "области",
+    "область",
+    "облачка",
+    "облачко",
+    "облегчаемы",
+    "облегчает",
+    "облегчаете",
+    "облегчается",
+    "облегчал",
+    "облегчалась",
+    "облегчающих",
+    "облегчить",
+    "облезлый",
+    "облекать",
+    "облекла",
+    "обленилась",
+    "обленился",
+    "облепил",
+    "облепленный",
+    "облетели",
+    "облечена",
+    "обливает",
+    "обливала",
+    "обливалось",
+    "обливаюсь",
+    "обливая",
+    "обливаясь",
+    "облигаций",
+    "облизываясь",
+    "облик",
+    "облике",
+    "облилось",
+    "облита",
+    "облитой",
+    "облитый",
+    "облить",
+    "облиться",
+    "обличайте",
+    "обличало",
+    "обличат",
+    "обличать",
+    "обличают",
+    "обличающее",
+    "обличающие",
+    "обличению",
+    "обличения",
+    "обличенного",
+    "обличенных",
+    "обличий",
+    "обличили",
+    "обличителем",
+    "обличители",
+    "обличить",
+    "обличье",
+    "облобызал",
+    "обложив",
+    "обложил",
+    "обложила",
+    "обложит",
+    "облокотившись",
+    "облокотилась",
+    "облокотился",
+    "облокотиться",
+    "облокотясь",
+    "обломав",
+    "обломаешь",
+    "обломками",
+    "обломов",
+    "обломова",
+    "обломове",
+    "обломовка",
+    "обломовках",
+    "обломовке",
+    "обломовки",
+    "обломовкой",
+    "обломовку",
+    "обломовская",
+    "обломовский",
+    "обломовских",
+    "обломовского",
+    "обломовской",
+    "обломовском",
+    "обломовскому",
+    "обломовскую",
+    "обломову",
+    "обломовцам",
+    "обломовцев",
+    "обломовцы",
+    "обломовщина",
+    "обломовы",
+    "обломовым",
+    "обломовых",
+    "обломок",
+    "облупит",
+    "обмакнешь",
+    "обмакнул",
+    "обмакнуть",
+    "обман",
+    "обмана",
+    "обманет",
+    "обманете",
+    "обманом",
+    "обманувшие",
+    "обманул",
+    "обманула",
+    "обманули",
+    "обманут",
+    "обманутое",
+    "обманутые",
+    "обмануть",
+    "обмануться",
+    "обманчив",
+    "обманчивый",
+    "обманщика",
+    "обманщицы",
+    "обманывает",
+    "обманываете",
+    "обманываешь",
+    "обманывал",
+    "обманывали",
+    "обманывать",
+    "обманываю",
+    "обманывают",
+    "обманывающих",
+    "обмахивая",
+    "обмен",
+    "обмена",
+    "обмененном",
+    "обменялись",
+    "обменялся",
+    "обмер",
+    "обмеривали",
+    "обмеривают",
+    "обмеривая",
+    "обмерить",
+    "обмести",
+    "обметает",
+    "обмоет",
+    "обморок",
+    "обморока",
+    "обмороками",
+    "обмороке",
+    "обмороки",
+    "обмороком",
+    "обмороку",
+    "обмундировку",
+    "обмывает",
+    "обмывать",
+    "обмызганный",
+    "обнажает",
+    "обнажаться",
+    "обнаженная",
+    "обнаженную",
+    "обнаженные",
+    "обнаженных",
+    "обнажил",
+    "обнаружен",
+    "обнаружена",
+    "обнаружением",
+    "обнаруживал",
+    "обнаруживать",
+    "обнаруживая",
+    "обнаружил",
+    "обнаружили",
+    "обнаружило",
+    "обнаружилось",
+    "обнаружится",
+    "обнаружить",
+    "обнаружиться",
+    "обнеряшиться",
+    "обнес",
+    "обнесенный",
+    "обнимает",
+    "обнимаешь",
+    "обнимал",
+    "обнимала",
+    "обнимались",
+    "обнимать",
+    "обнимаю",
+    "обнимаются",
+    "обнимая",
+    "обнимемся",
+    "обнимет",
+    "обними",
+    "обновившимся",
+    "обновился",
+    "обновить",
+    "обновки",
+    "обновления",
+    "обновленного",
+    "обновлять",
+    "обноски",
+    "обнюхает",
+    "обняв",
+    "обнявшись",
+    "обнял",
+    "обняла",
+    "обнялись",
+    "обнять",
+    "обо",
+    "обобрал",
+    "обовьет",
+    "обовьют",
+    "ободком",
+    "ободранный",
+    "ободрано",
+    "ободренная",
+    "ободрившийся",
+    "ободрившись",
+    "ободрил",
+    "ободрилась",
+    "ободрило",
+    "ободрился",
+    "ободрись",
+    "ободрите",
+    "ободритесь",
+    "ободрить",
+    "ободриться",
+    "ободряла",
+    "ободряться",
+    "обоего",
+    "обожаемого",
+    "обожает",
+    "обожал",
+    "обожанием",
+    "обожания",
+    "обожающие",
+    "обожая",
+    "обожги",
+    "обожгли",
+    "обожгло",
+    "обожгу",
+    "обожжен",
+    "обожженный",
+    "обоз",
+    "обозвав",
+    "обозвал",
+    "обознать",
+    "обозначавшие",
+    "обозначается",
+    "обозначалась",
+    "обозначаются",
+    "обозначена",
+    "обозначено",
+    "обозначился",
+    "обозначить",
+    "обозники",
+    "обозреть",
+    "обозу",
+    "обои",
+    "обоим",
+    "обоими",
+    "обоих",
+    "обой",
+    "обойдет",
+    "обойдется",
+    "обойдешь",
+    "обойди",
+    "обойдусь",
+    "обойдутся",
+    "обойдя",
+    "обойной",
+    "обойти",
+    "обойтись",
+    "обокрали",
+    "обокрасть",
+    "оболочке",
+    "оболочку",
+    "обольет",
+    "обольется",
+    "обольстит",
+    "обольстительнее",
+    "обольстительной",
+    "обольстительный",
+    "обольют",
+    "обомлел",
+    "обомлели",
+    "обопрется",
+    "оборачивается",
+    "оборачивайте",
+    "оборачивались",
+    "оборачивался",
+    "оборачивая",
+    "оборачиваясь",
+    "оборвавший",
+    "оборвал",
+    "оборвала",
+    "оборвалась",
+    "оборвалось",
+    "оборванец",
+    "оборванной",
+    "оборванный",
+    "оборванца",
+    "оборванцем",
+    "оборвать",
+    "оборвет",
+    "оборвется",
+    "оборвешься",
+    "оборви",
+    "оборвыш",
+    "обороняется",
+    "оборот",
+    "оборота",
+    "оборотившийся",
+    "оборотившись",
+    "оборотил",
+    "оборотилась",
+    "оборотился",
+    "оборотится",
+    "оборотиться",
+    "оборотне",
+    "оборотнем",
+    "оборотной",
+    "оборотням",
+    "оборотом",
+    "оборотясь",
+    "оботрет",
+    "обошел",
+    "обошла",
+    "обошлась",
+    "обошлись",
+    "обошлось",
+    "обошьют",
+    "обоюдное",
+    "обоюдных",
+    "обоями",
+    "обоях",
+    "обрадовавшись",
+    "обрадовал",
+    "обрадовалась",
+    "обрадовались",
+    "обрадовался",
+    "обрадованная",
+    "обрадованного",
+    "обрадованный",
+    "обрадуемся",
+    "обрадуется",
+    "обрадуются",
+    "образ",
+    "образа",
+    "образами",
+    "образе",
+    "образина",
+    "образов",
+    "образовал",
+    "образовались",
+    "образовалось",
+    "образован",
+    "образование",
+    "образованием",
+    "образовании",
+    "образованию",
+    "образования",
+    "образованная",
+    "образованного",
+    "образованной",
+    "образованном",
+    "образованность",
+    "образованны",
+    "образованные",
+    "образованный",
+    "образованных",
+    "образок",
+    "образом",
+    "образу",
+    "образует",
+    "образумить",
+    "образумиться",
+    "образцами",
+    "образцовая",
+    "образцовое",
+    "образцовый",
+    "образцу",
+    "обрамлен",
+    "обрамлена",
+    "обрамленное",
+    "обратив",
+    "обратившись",
+    "обратил",
+    "обратила",
+    "обратилась",
+    "обратили",
+    "обратились",
+    "обратило",
+    "обратилось",
+    "обратился",
+    "обратим",
+    "обратит",
+    "обратится",
+    "обратить",
+    "обратиться",
+    "обратно",
+    "обратясь",
+    "обратят",
+    "обратятся",
+    "обращает",
+    "обращается",
+    "обращайте",
+    "обращайтесь",
+    "обращал",
+    "обращалась",
+    "обращали",
+    "обращались",
+    "обращался",
+    "обращать",
+    "обращаться",
+    "обращаюсь",
+    "обращаются",
+    "обращающийся",
+    "обращая",
+    "обращаясь",
+    "обращен",
+    "обращена",
+    "обращение",
+    "обращением",
+    "обращении",
+    "обращений",
+    "обращения",
+    "обращенная",
+    "обращенном",
+    "обращенному",
+    "обращенных",
+    "обращены",
+    "обращу",
+    "обращусь",
+    "обреешься",
+    "обрезал",
+    "обрезки",
+    "обрекает",
+    "обрел",
+    "обречен",
+    "обречена",
+    "обреченного",
+    "обреченные",
+    "обреченными",
+    "обречь",
+    "обрисовывается",
+    "обритый",
+    "обритым",
+    "обробел",
+    "обробела",
+    "оброк",
+    "оброка",
+    "оброке",
+    "оброку",
+    "обронил",
+    "обросший",
+    "оброчных",
+    "обрубила",
+    "обрубленный",
+    "обрубок",
+    "обругает",
+    "обругал",
+    "обругала",
+    "обругали",
+    "обругать",
+    "обругаться",
+    "обругаю",
+    "обругают",
+    "обруч",
+    "обручен",
+    "обручился",
+    "обручится",
+    "обручке",
+    "обрушившиеся",
+    "обрушила",
+    "обрушилась",
+    "обрушились",
+    "обрушится",
+    "обрыв",
+    "обрыва",
+    "обрывается",
+    "обрывалась",
+    "обрываю",
+    "обрывая",
+    "обрываясь",
+    "обрывках",
+    "обрывке",
+    "обрывки",
+    "обрывок",
+    "обрывы",
+    "обрюзг",
+    "обряд",
+    "обряда",
+    "обрядах",
+    "обрядов",
+    "обряды",
+    "обрящете",
+    "обсаженный",
+    "обсасывая",
+    "обслуживания",
+    "обставленная",
+    "обставленный",
+    "обстала",
+    "обстановив",
+    "обстановка",
+    "обстановке",
+    "обстановки",
+    "обстановкой",
+    "обстановку",
+    "обстоятельно",
+    "обстоятельное",
+    "обстоятельную",
+    "обстоятельным",
+    "обстоятельств",
+    "обстоятельства",
+    "обстоятельствам",
+    "обстоятельствами",
+    "обстоятельствах",
+    "обстоятельстве",
+    "обстоятельство",
+    "обстоятельством",
+    "обстоятельству",
+    "обстреливать",
+    "обстриженными",
+    "обстроилась",
+    "обструганная",
+    "обступили",
+    "обступило",
+    "обступят",
+    "обсудил",
+    "обсудим",
+    "обсудите",
+    "обсудить",
+    "обсудят",
+    "обсуждение",
+    "обсуживался",
+    "обсыплет",
+    "обтерев",
+    "обтереть",
+    "обтерпелась",
+    "обтертую",
+    "обтесанный",
+    "обтирал",
+    "обтирала",
+    "обтирая",
+    "обтирку",
+    "обтяжку",
+    "обуви",
+    "обугленный",
+    "обуется",
+    "обузу",
+    "обусловлена",
+    "обут",
+    "обутым",
+    "обухом",
+    "обучался",
+    "обучение",
+    "обуют",
+    "обуянный",
+    "обхватив",
+    "обхватил",
+    "обхватила",
+    "обхватили",
+    "обхватывает",
+    "обход",
+    "обходившую",
+    "обходил",
+    "обходили",
+    "обходилось",
+    "обходился",
+    "обходит",
+    "обходится",
+    "обходиться",
+    "обходом",
+    "обходя",
+    "обходятся",
+    "обхождении",
+    "обшаривал",
+    "обшарканные",
+    "обшарканными",
+    "обшивала",
+    "обширен",
+    "обширного",
+    "обширную",
+    "обширные",
+    "обширный",
+    "обшит",
+    "обшлагом",
+    "обшмыганные",
+    "общая",
+    "общего",
+    "общее",
+    "общей",
+    "общем",
+    "общему",
+    "общества",
+    "обществе",
+    "общественного",
+    "общественное",
+    "общественной",
+    "общественному",
+    "общественный",
+    "общественным",
+    "общественных",
+    "общество",
+    "обществом",
+    "обществу",
+    "общечеловеческая",
+    "общие",
+    "общий",
+    "общим",
+    "общих",
+    "общую",
+    "объедаете",
+    "объездили",
+    "объезжать",
+    "объем",
+    "объемистый",
+    "объемлет",
+    "объемля",
+    "объемом",
+    "объехать",
+    "объявив",
+    "объявил",
+    "объявила",
+    "объявили",
+    "объявились",
+    "объявился",
+    "объявим",
+    "объявит",
+    "объявите",
+    "объявится",
+    "объявить",
+    "объявлен",
+    "объявление",
+    "объявлены",
+    "объявляет",
+    "объявляется",
+    "объявляйте",
+    "объявлял",
+    "объявляю",
+    "объявляют",
+    "объявляя",
+    "объял",
+    "объяснение",
+    "объяснением",
+    "объяснений",
+    "объяснению",
+    "объяснения",
+    "объяснениями",
+    "объясненья",
+    "объяснил",
+    "объяснила",
+    "объяснили",
+    "объяснились",
+    "объяснилось",
+    "объяснимся",
+    "объяснимым",
+    "объяснись",
+    "объяснит",
+    "объясните",
+    "объяснитесь",
+    "объяснить",
+    "объясниться",
+    "объяснишься",
+    "объясню",
+    "объяснюсь",
+    "объясняемую",
+    "объясняет",
+    "объясняется",
+    "объясняешь",
+    "объяснял",
+    "объяснять",
+    "объясняться",
+    "объясняю",
+    "объясняя",
+    "объясняясь",
+    "объятие",
+    "объятии",
+    "объятий",
+    "объятия",
+    "объятиях",
+    "объятый",
+    "объятья",
+    "объятьях",
+    "обывательская",
+    "обывательской",
+    "обывательскую",
+    "обыденной",
+    "обыденною",
+    "обыденную",
+    "обыденных",
+    "обыкновение",
+    "обыкновению",
+    "обыкновения",
+    "обыкновенная",
+    "обыкновеннейших",
+    "обыкновенно",
+    "обыкновенного",
+    "обыкновенное",
+    "обыкновенной",
+    "обыкновенном",
+    "обыкновенною",
+    "обыкновенную",
+    "обыкновенны",
+    "обыкновенные",
+    "обыкновенный",
+    "обыкновенным",
+    "обыкновенных",
+    "обыск",
+    "обыски",
+    "обыскивает",
+    "обыскивайте",
+    "обыскивать",
+    "обыском",
+    "обычаев",
+    "обычаи",
+    "обычай",
+    "обычаю",
+    "обычное",
+    "обычной",
+    "обычную",
+    "обычные",
+    "обычным",
+    "обычными",
+    "обычных",
+    "обыщем",
+    "обыщите",
+    "обьятиях",
+    "обяжете",
+    "обязан",
+    "обязана",
+    "обязанной",
+    "обязанностей",
+    "обязанности",
+    "обязанность",
+    "обязанностью",
+    "обязанностях",
+    "обязанною",
+    "обязанным",
+    "обязаны",
+    "обязательная",
+    "обязательной",
+    "обязательны",
+    "обязательный",
+    "обязательных",
+    "обязательств",
+    "обязательства",
+    "обязательствах",
+    "обязательство",
+    "овал",
+    "овальной",
+    "овальными",
+    "овацию",
+    "овес",
+    "овечку",
+    "овеют",
+    "овеяла",
+    "овин",
+    "овином",
+    "овладевает",
+    "овладевала",
+    "овладевали",
+    "овладевало",
+    "овладевать",
+    "овладевшей",
+    "овладел",
+    "овладела",
+    "овладели",
+    "овладело",
+    "овощами",
+    "овощи",
+    "овощные",
+    "овраг",
+    "оврага",
+    "оврагам",
+    "овраге",
+    "оврагом",
+    "овса",
+    "овсе",
+    "овца",
+    "овцой",
+    "овцы",
+    "овчинин",
+    "овчинину",
+    "огарка",
+    "огарками",
+    "огарки",
+    "огарок",
+    "огибавшую",
+    "огибает",
+    "огибали",
+    "огибая",
+    "огласку",
+    "оглоблю",
+    "оглобля",
+    "оглохла",
+    "оглушение",
+    "оглушенный",
+    "оглушила",
+    "оглушили",
+    "оглядев",
+    "оглядевшись",
+    "оглядел",
+    "оглядела",
+    "огляделся",
+    "оглядеть",
+    "оглядимся",
+    "оглядки",
+    "оглядкой",
+    "оглядывает",
+    "оглядывается",
+    "оглядывайся",
+    "оглядывал",
+    "оглядывала",
+    "оглядывался",
+    "оглядывание",
+    "оглядывать",
+    "оглядывают",
+    "оглядываются",
+    "оглядывая",
+    "оглядываяась",
+    "оглядываясь",
+    "оглянемся",
+    "оглянется",
+    "оглянись",
+    "оглянитесь",
+    "оглянувшись",
+    "оглянула",
+    "оглянулась",
+    "оглянулись",
+    "оглянулся",
+    "оглянуться",
+    "огне",
+    "огневая",
+    "огневицей",
+    "огневой",
+    "огневые",
+    "огневым",
+    "огней",
+    "огнекрасная",
+    "огнекрасные",
+    "огнем",
+    "огненного",
+    "огненной",
+    "огненные",
+    "огненным",
+    "огненных",
+    "огни",
+    "огнь",
+    "огню",
+    "огня",
+    "огнями",
+    "огнях",
+    "ого",
+    "оговор",
+    "огонек",
+    "огонь",
+    "огонька",
+    "огоньки",
+    "огоньком",
+    "огород",
+    "огорода",
+    "огородам",
+    "огородами",
+    "огороде",
+    "огородить",
+    "огородишком",
+    "огородницы",
+    "огородом",
+    "огороду",
+    "огороды",
+    "огорошат",
+    "огорошивает",
+    "огорошить",
+    "огорчать",
+    "огорчен",
+    "огорчением",
+    "огорчения",
+    "огорчениями",
+    "огорченный",
+    "огорчены",
+    "огорчил",
+    "огорчился",
+    "огорчить",
+    "ограбил",
+    "ограбили",
+    "ограбить",
+    "ограблен",
+    "ограбленной",
+    "ограбленным",
+    "ограда",
+    "оградах",
+    "ограде",
+    "оградить",
+    "оградой",
+    "ограду",
+    "ограды",
+    "оградясь",
+    "огражу",
+    "ограниченные",
+    "ограничивался",
+    "ограничилось",
+    "ограничился",
+    "ограничится",
+    "ограничить",
+    "огрел",
+    "огромная",
+    "огромней",
+    "огромнейшие",
+    "огромнейшими",
+    "огромного",
+    "огромное",
+    "огромной",
+    "огромном",
+    "огромному",
+    "огромною",
+    "огромные",
+    "огромный",
+    "огромным",
+    "огромных",
+    "огрызался",
+    "огрызаться",
+    "огурец",
+    "огурцами",
+    "огурцов",
+    "огурцы",
+    "огурчик",
+    "одарен",
+    "одаренные",
+    "одев",
+    "одевается",
+    "одеваешься",
+    "одевайся",
+    "одевала",
+    "одевалась",
+    "одевали",
+    "одевался",
+    "одевать",
+    "одеваться",
+    "одевают",
+    "одевающаяся",
+    "одеваясь",
+    "одевшись",
+    "одежа",
+    "одежда",
+    "одежде",
+    "одеждой",
+    "одежду",
+    "одежды",
+    "одежке",
+    "одежонку",
+    "одеколону",
+    "одел",
+    "оделась",
+    "оделся",
+    "оденется",
+    "оденешь",
+    "одену",
+    "оденусь",
+    "оденут",
+    "одеревенелыми",
+    "одержим",
+    "одессе",
+    "одессы",
+    "одет",
+    "одета",
+    "одетая",
+    "одетого",
+    "одетой",
+    "одетому",
+    "одеты",
+    "одетые",
+    "одетый",
+    "одетым",
+    "одеть",
+    "одеться",
+    "одеяла",
+    "одеяло",
+    "одеялом",
+    "одеяние",
+    "один",
+    "одинаким",
+    "одинакова",
+    "одинаково",
+    "одинаковое",
+    "одинаковы",
+    "одинаковым",
+    "одинехонька",
+    "одинешенька",
+    "одиннадцати",
+    "одиннадцатой",
+    "одиннадцатом",
+    "одиннадцатый",
+    "одиннадцать",
+    "одинок",
+    "одинока",
+    "одинокий",
+    "одиноким",
+    "одиноко",
+    "одинокой",
+    "одиноком",
+    "одинокому",
+    "одиночества",
+    "одиночестве",
+    "одиночество",
+    "одиночку",
+    "одиночное",
+    "одичал",
+    "одичалая",
+    "одна",
+    "однажды",
+    "однако",
+    "однакож",
+    "однех",
+    "одни",
+    "одним",
+    "одними",
+    "одних",
+    "одно",
+    "одноважды",
+    "одновременно",
+    "одного",
+    "однозвучно",
+    "однозвучны",
+    "однозвучным",
+    "одноименной",
+    "одной",
+    "однократное",
+    "одном",
+    "одному",
+    "однообразие",
+    "однообразием",
+    "однообразия",
+    "однообразно",
+    "однообразное",
+    "однообразною",
+    "однообразную",
+    "однообразны",
+    "однообразный",
+    "однообразьи",
+    "одностороннего",
+    "односторонний",
+    "односторонним",
+    "одноцвет",
+    "одною",
+    "одну",
+    "одобрение",
+    "одобрением",
+    "одобрительно",
+    "одобрить",
+    "одобряла",
+    "одолеваемый",
+    "одолевает",
+    "одолевала",
+    "одолевают",
+    "одолел",
+    "одолела",
+    "одолели",
+    "одолело",
+    "одолеть",
+    "одолжайтесь",
+    "одолжение",
+    "одолжением",
+    "одолжите",
+    "одонцовым",
+    "одр",
+    "одре",
+    "одумается",
+    "одумался",
+    "одурачить",
+    "одуревшими",
+    "одуреешь",
+    "одурения",
+    "одурманения",
+    "одурманивающий",
+    "одутловатым",
+    "одушевлена",
+    "одушевление",
+    "одушевлением",
+    "одушевленные",
+    "оды",
+    "одышка",
+    "одышки",
+    "ожерельем",
+    "ожесточением",
+    "ожесточенная",
+    "ожесточенное",
+    "оживали",
+    "оживет",
+    "оживилась",
+    "оживили",
+    "оживить",
+    "оживление",
+    "оживлением",
+    "оживленнее",
+    "оживленно",
+    "оживленном",
+    "оживленностью",
+    "оживленный",
+    "оживлю",
+    "оживляет",
+    "оживлялась",
+    "оживлялись",
+    "оживлялся",
+    "оживляющих",
+    "оживляясь",
+    "оживут",
+    "ожидавшему",
+    "ожидавший",
+    "ожидает",
+    "ожидаете",
+    "ожидается",
+    "ожидал",
+    "ожидала",
+    "ожидали",
+    "ожидание",
+    "ожиданием",
+    "ожидании",
+    "ожиданий",
+    "ожидания",
+    "ожидать",
+    "ожидающий",
+    "ожидающую",
+    "ожидая",
+    "ожидовел",
+    "ожил",
+    "ожиревший",
+    "оз",
+    "озаботится",
+    "озабочен",
+    "озабоченнее",
+    "озабоченно",
+    "озабоченную",
+    "озабоченный",
+    "озабоченным",
+    "озадачен",
+    "озадаченный",
+    "озадачила",
+    "озаренная",
+    "озарены",
+    "озарил",
+    "озарила",
+    "озарили",
+    "озарилось",
+    "озарился",
+    "озарим",
+    "озарит",
+    "озарялись",
+    "озаряющего",
+    "оземь",
+    "озер",
+    "озера",
+    "озерах",
+    "озере",
+    "озерна",
+    "озеро",
+    "озером",
+    "озеру",
+    "озими",
+    "озимь",
+    "озирается",
+    "озирал",
+    "озирала",
+    "озиралась",
+    "озирался",
+    "озираньем",
+    "озираясь",
+    "озлились",
+    "озлился",
+    "озлобившись",
+    "озлобленные",
+    "ознакомиться",
+    "ознаменовало",
+    "ознаменовалось",
+    "означавшие",
+    "означает",
+    "означается",
+    "означенного",
+    "озноб",
+    "озноба",
+    "ознобил",
+    "озябла",
+    "озябшие",
+    "озябший",
+    "озябшими",
+    "ой",
+    "ока",
+    "окажется",
+    "оказалась",
+    "оказалось",
+    "оказался",
+    "оказанных",
+    "оказано",
+    "оказать",
+    "оказии",
+    "оказываемым",
+    "оказывает",
+    "оказывается",
+    "оказывала",
+    "оказывалась",
+    "оказывали",
+    "оказывалось",
+    "оказывают",
+    "окаймляющими",
+    "окаменеет",
+    "окаменел",
+    "окаменелая",
+    "окаменелость",
+    "окаменелых",
+    "окаменяющий",
+    "оканчивалось",
+    "окарикатурить",
+    "окатил",
+    "окаянная",
+    "окаянного",
+    "окаянные",
+    "окаянный",
+    "океан",
+    "океане",
+    "океаны",
+    "окидывает",
+    "окидывая",
+    "окинет",
+    "окинешь",
+    "окинув",
+    "окинул",
+    "оклад",
+    "окладов",
+    "оклеветал",
+    "оклеветали",
+    "оклеивали",
+    "окликает",
+    "окликать",
+    "окликнул",
+    "окликнули",
+    "окликнуть",
+    "окна",
+    "окнам",
+    "окнами",
+    "окнах",
+    "окне",
+    "окно",
+    "окном",
+    "окну",
+    "око",
+    "оков",
+    "окованная",
+    "окованный",
+    "оковы",
+    "оковывает",
+    "оковывать",
+    "околдован",
+    "околдованная",
+    "околицей",
+    "около",
+    "околотке",
+    "околотку",
+    "околоток",
+    "оком",
+    "окон",
+    "оконной",
+    "оконные",
+    "окончание",
+    "окончании",
+    "окончания",
+    "окончательнее",
+    "окончательно",
+    "окончательного",
+    "окончательное",
+    "окончательной",
+    "окончательному",
+    "окончательные",
+    "окончательный",
+    "окончательным",
+    "окончательных",
+    "окончен",
+    "окончив",
+    "окончил",
+    "окончила",
+    "окончит",
+    "окончится",
+    "окончить",
+    "окопать",
+    "окорока",
+    "окороков",
+    "окостенел",
+    "окостенела",
+    "окостенелый",
+    "окоченели",
+    "окоченил",
+    "окошечко",
+    "окошка",
+    "окошке",
+    "окошки",
+    "окошко",
+    "окошку",
+    "окраинах",
+    "окраску",
+    "окрашена",
+    "окрашены",
+    "окрест",
+    "окрестив",
+    "окрестил",
+    "окрестности",
+    "окрестность",
+    "окрестный",
+    "окрестных",
+    "окрик",
+    "окрика",
+    "окровавленного",
+    "окровавленную",
+    "окроме",
+    "окромя",
+    "окрошки",
+    "округлилась",
+    "округлости",
+    "округляли",
+    "округлять",
+    "окружавшее",
+    "окружавшей",
+    "окружали",
+    "окружающего",
+    "окружающее",
+    "окружающей",
+    "окружающему",
+    "окружающие",
+    "окружающий",
+    "окружающим",
+    "окружающих",
+    "окружающую",
+    "окружены",
+    "окружила",
+    "окружили",
+    "окружит",
+    "окружить",
+    "окружном",
+    "окрыляли",
+    "октавой",
+    "октаву",
+    "октябрь",
+    "октябрьском",
+    "октябрьскую",
+    "октября",
+    "окует",
+    "окунулась",
+    "окуня",
+    "окупаются",
+    "окутали",
+    "окуталось",
+    "оле",
+    "оледенела",
+    "оледенивший",
+    "оледенил",
+    "оледенило",
+    "олей",
+    "олени",
+    "оленька",
+    "олечка",
+    "олешкин",
+    "оли",
+    "олимпийские",
+    "олифе",
+    "олицетворенный",
+    "олово",
+    "олух",
+    "олухи",
+    "олухов",
+    "ольга",
+    "ольге",
+    "ольги",
+    "ольгиной",
+    "ольгой",
+    "ольгу",
+    "ольхи",
+    "ольхой",
+    "олюшка",
+    "олюшкой",
+    "оля",
+    "омаров",
+    "омбрельке",
+    "омерзение",
+    "омерзением",
+    "омерзения",
+    "омерзительно",
+    "омрачалось",
+    "омрачался",
+    "омрачение",
+    "омрачением",
+    "омрачения",
+    "омрачилось",
+    "ому",
+    "омут",
+    "омута",
+    "омуте",
+    "омываемых",
+    "омыт",
+    "омытый",
+    "он",
+    "она",
+    "онемев",
+    "онемела",
+    "онемели",
+    "онемелое",
+    "онемение",
+    "онеметь",
+    "онерами",
+    "они",
+    "онисим",
+    "онисимовна",
+    "онисимовны",
+    "онисиму",
+    "оно",
+    "оного",
+    "оную",
+    "оны",
+    "опадающий",
+    "опал",
+    "опаленная",
+    "опаленные",
+    "опаленный",
+    "опалит",
+    "опамятовавшись",
+    "опары",
+    "опасался",
+    "опасаясь",
+    "опасен",
+    "опасение",
+    "опасением",
+    "опасений",
+    "опасения",
+    "опасениях",
+    "опасливо",
+    "опасна",
+    "опасная",
+    "опаснее",
+    "опасно",
+    "опасного",
+    "опасное",
+    "опасностей",
+    "опасности",
+    "опасность",
+    "опасностями",
+    "опасные",
+    "опасный",
+    "опасным",
+    "опасных",
+    "опеки",
+    "опекой",
+    "опеку",
+    "опекун",
+    "опекуна",
+    "опекуном",
+    "опера",
+    "операции",
+    "операцию",
+    "опере",
+    "опередил",
+    "опередить",
+    "оперлась",
+    "оперном",
+    "оперся",
+    "оперу",
+    "опершись",
+    "оперы",
+    "опечаленный",
+    "опечалилась",
+    "опечалится",
+    "опечаток",
+    "опиваете",
+    "опирается",
+    "опираясь",
+    "описав",
+    "описал",
+    "описание",
+    "описанием",
+    "описании",
+    "описаний",
+    "описанная",
+    "описанное",
+    "описано",
+    "описать",
+    "описи",
+    "описывал",
+    "описывать",
+    "опишу",
+    "оплакала",
+    "оплакиваю",
+    "оплевать",
+    "оплетая",
+    "оплетет",
+    "оплетешь",
+    "оплеуха",
+    "оплеуху",
+    "оплодотворяется",
+    "оподлит",
+    "опоздавший",
+    "опоздает",
+    "опоздал",
+    "опоздала",
+    "опоздали",
+    "опоздать",
+    "опозоренная",
+    "опозорить",
+    "опозориться",
+    "опомнившись",
+    "опомнилась",
+    "опомнился",
+    "опомнись",
+    "опомнитесь",
+    "опомниться",
+    "опомнясь",
+    "опора",
+    "опору",
+    "опоры",
+    "опосля",
+    "опочиет",
+    "опошлить",
+    "оппозицию",
+    "оппонировать",
+    "оправдает",
+    "оправдан",
+    "оправдана",
+    "оправдание",
+    "оправданию",
+    "оправдания",
+    "оправдательные",
+    "оправдать",
+    "оправдаю",
+    "оправдывавшее",
+    "оправдывавшим",
+    "оправдывается",
+    "оправдываешь",
+    "оправдывалась",
+    "оправдывали",
+    "оправдывались",
+    "оправдывался",
+    "оправдывать",
+    "оправдываться",
+    "оправдываюсь",
+    "оправдывают",
+    "оправдывая",
+    "оправе",
+    "оправив",
+    "оправившись",
+    "оправила",
+    "оправилась",
+    "оправился",
+    "оправиться",
+    "оправлюсь",
+    "оправляла",
+    "оправлялась",
+    "оправляться",
+    "оправляя",
+    "опрашивает",
+    "опрашивали",
+    "определен",
+    "определения",
+    "определенная",
+    "определеннее",
+    "определенно",
+    "определенного",
+    "определенное",
+    "определенной",
+    "определенности",
+    "определенную",
+    "определенные",
+    "определенный",
+    "определенных",
+    "определив",
+    "определившеюся",
+    "определил",
+    "определился",
+    "определительно",
+    "определить",
+    "определю",
+    "определял",
+    "определяя",
+    "опредленным",
+    "опричь",
+    "опробую",
+    "опровергает",
+    "опровергал",
+    "опровергнутых",
+    "опровергнуть",
+    "опрокидыванье",
+    "опрокидываться",
+    "опрокидываясь",
+    "опрокинул",
+    "опрокинутого",
+    "опрокинутое",
+    "опрокинутою",
+    "опрокинутую",
+    "опрометчиво",
+    "опрометью",
+    "опросила",
+    "опротивели",
+    "опрыскивает",
+    "опрятно",
+    "опрятное",
+    "опрятность",
+    "оптический",
+    "оптом",
+    "опубликован",
+    "опускаем",
+    "опускает",
+    "опускаете",
+    "опускается",
+    "опускаешься",
+    "опускал",
+    "опускала",
+    "опускалась",
+    "опускали",
+    "опускалось",
+    "опускаться",
+    "опускают",
+    "опуская",
+    "опускаясь",
+    "опустевший",
+    "опустевшим",
+    "опустеет",
+    "опустел",
+    "опустели",
+    "опустело",
+    "опустелой",
+    "опусти",
+    "опустив",
+    "опустил",
+    "опустила",
+    "опустилась",
+    "опустили",
+    "опустились",
+    "опустился",
+    "опустись",
+    "опустите",
+    "опустится",
+    "опуститься",
+    "опустошает",
+    "опустошаются",
+    "опустошение",
+    "опустошенной",
+    "опустошенный",
+    "опустя",
+    "опутавшие",
+    "опухоли",
+    "опухоль",
+    "опушены",
+    "опущена",
+    "опущенная",
+    "опущенные",
+    "опущенный",
+    "опущенным",
+    "опущенными",
+    "опущены",
+    "опыт",
+    "опыта",
+    "опытах",
+    "опыте",
+    "опытная",
+    "опытнее",
+    "опытной",
+    "опытности",
+    "опытность",
+    "опытный",
+    "опытным",
+    "опыту",
+    "опыты",
+    "опьянев",
+    "опьяневший",
+    "опьянел",
+    "опьяненные",
+    "опьяненный",
+    "опьянят",
+    "опять",
+    "орава",
+    "оракула",
+    "орали",
+    "оранжа",
+    "оранжерею",
+    "оранжереям",
+    "оранжереях",
+    "орарем",
+    "оратор",
+    "оратора",
+    "ораторской",
+    "ораторствовал",
+    "ораторствуешь",
+    "орбит",
+    "органа",
+    "организацию",
+    "организм",
+    "организмами",
+    "организме",
+    "органом",
+    "органчиком",
+    "органщика",
+    "орда",
+    "орден",
+    "ордена",
+    "орденом",
+    "ордою",
+    "ореол",
+    "орет",
+    "орех",
+    "орехами",
+    "орехи",
+    "орешки",
+    "оригиналы",
+    "оригинальная",
+    "оригинально",
+    "оригинальности",
+    "оригинальность",
+    "оригинальный",
+    "оригинальным",
+    "оригинальных",
+    "оркестр",
+    "оркестре",
+    "орла",
+    "орлий",
+    "орлиный",
+    "орлиным",
+    "орлица",
+    "орлы",
+    "оробев",
+    "оробевшая",
+    "оробеет",
+    "оробела",
+    "орудий",
+    "орудия",
+    "оружие",
+    "оружием",
+    "орфографии",
+    "оса",
+    "осадила",
+    "осадить",
+    "осадка",
+    "осадой",
+    "осадок",
+    "осаду",
+    "осаждает",
+    "осанисто",
+    "осанистый",
+    "осанистым",
+    "осанка",
+    "осанкой",
+    "осведомилась",
+    "осведомился",
+    "осведомиться",
+    "освежает",
+    "освежалась",
+    "освежали",
+    "освежать",
+    "освежают",
+    "освежила",
+    "освежило",
+    "освежит",
+    "освежится",
+    "освежить",
+    "освежиться",
+    "осветил",
+    "осветилось",
+    "осветит",
+    "осветленный",
+    "осветят",
+    "освещает",
+    "освещал",
+    "освещало",
+    "освещая",
+    "освещена",
+    "освещение",
+    "освещении",
+    "освещенная",
+    "освободи",
+    "освободившись",
+    "освободилось",
+    "освободить",
+    "освободиться",
+    "освободу",
+    "освободясь",
+    "освободят",
+    "освобождался",
+    "освобождаются",
+    "освобождения",
+    "освятил",
+    "оседает",
+    "оседлать",
+    "осеклась",
+    "осеклись",
+    "осекся",
+    "осел",
+    "осела",
+    "оселся",
+    "осени",
+    "осенила",
+    "осенило",
+    "осенить",
+    "осенней",
+    "осенние",
+    "осенний",
+    "осенних",
+    "осень",
+    "осенью",
+    "осеняет",
+    "осеняли",
+    "осенялись",
+    "осеняя",
+    "осердитесь",
+    "осерчает",
+    "осетр",
+    "осетра",
+    "осетрина",
+    "осетрины",
+    "осечка",
+    "осиливал",
+    "осилил",
+    "осилила",
+    "осиновый",
+    "осину",
+    "осипшим",
+    "осквернил",
+    "осклабясь",
+    "осколки",
+    "осколок",
+    "оскопления",
+    "оскорбил",
+    "оскорбились",
+    "оскорбило",
+    "оскорбительнее",
+    "оскорбительно",
+    "оскорбительное",
+    "оскорбить",
+    "оскорблен",
+    "оскорблена",
+    "оскорбление",
+    "оскорблении",
+    "оскорблений",
+    "оскорбления",
+    "оскорбленная",
+    "оскорбленного",
+    "оскорбленное",
+    "оскорбленною",
+    "оскорбленный",
+    "оскорбленным",
+    "оскорблены",
+    "оскорблю",
+    "оскорбляет",
+    "оскорбляется",
+    "оскорблял",
+    "оскорблялась",
+    "оскорблять",
+    "оскорбляют",
+    "оскорбляясь",
+    "осла",
+    "ослаб",
+    "ослабевал",
+    "ослабевали",
+    "ослабевало",
+    "ослабевший",
+    "ослабевшим",
+    "ослабел",
+    "ослабели",
+    "ослабело",
+    "ослеп",
+    "ослепил",
+    "ослепила",
+    "ослепительно",
+    "ослепительной",
+    "ослепительные",
+    "ослепительным",
+    "ослепительных",
+    "ослепла",
+    "ослепление",
+    "ослепления",
+    "ослепленный",
+    "ослеплял",
+    "ослеплялся",
+    "ослепнув",
+    "ослепнуть",
+    "ослепшего",
+    "ослепшие",
+    "ослепший",
+    "ослободился",
+    "ослы",
+    "ослышался",
+    "осматривавшего",
+    "осматривает",
+    "осматривается",
+    "осматривал",
+    "осматривалась",
+    "осматривался",
+    "осматривать",
+    "осматриваться",
+    "осматриваю",
+    "осматривая",
+    "осматриваясь",
+    "осмеливаюсь",
+    "осмелилась",
+    "осмелились",
+    "осмелился",
+    "осмелитесь",
+    "осмелится",
+    "осмелиться",
+    "осмелишься",
+    "осмелюсь",
+    "осмеяно",
+    "осмеять",
+    "осмотр",
+    "осмотрев",
+    "осмотрел",
+    "осмотрела",
+    "осмотрелась",
+    "осмотрелся",
+    "осмотрено",
+    "осмотреть",
+    "осмотрит",
+    "осмотрительнее",
+    "осмотрится",
+    "осмыслив",
+    "осмыслилась",
+    "осмыслилось",
+    "осмыслить",
+    "оснеженном",
+    "оснеженные",
+    "оснежит",
+    "основа",
+    "основался",
+    "основание",
+    "основанием",
+    "основании",
+    "оснований",
+    "основания",
+    "основаниях",
+    "основанная",
+    "основанное",
+    "основанный",
+    "основано",
+    "основатель",
+    "основательно",
+    "основательные",
+    "основать",
+    "основная",
+    "основным",
+    "основных",
+    "основу",
+    "основы",
+    "основываете",
+    "основываясь",
+    "особа",
+    "особая",
+    "особенная",
+    "особенно",
+    "особенного",
+    "особенное",
+    "особенной",
+    "особенном",
+    "особенностей",
+    "особенности",
+    "особенность",
+    "особенною",
+    "особенную",
+    "особенные",
+    "особенный",
+    "особенным",
+    "особенных",
+    "особо",
+    "особого",
+    "особое",
+    "особой",
+    "особом",
+    "особому",
+    "особу",
+    "особую",
+    "особы",
+    "особый",
+    "особых",
+    "осой",
+    "оспа",
+    "оспаривает",
+    "оспаривали",
+    "оспаривать",
+    "оспу",
+    "осрамили",
+    "осрамлю",
+    "оставай",
+    "оставайся",
+    "оставайтесь",
+    "оставалась",
+    "оставались",
+    "оставалось",
+    "оставался",
+    "оставаться",
+    "оставаясь",
+    "оставив",
+    "оставил",
+    "оставила",
+    "оставили",
+    "оставим",
+    "оставит",
+    "оставите",
+    "оставить",
+    "оставишь",
+    "оставлена",
+    "оставленную",
+    "оставленные",
+    "оставленный",
+    "оставленных",
+    "оставлю",
+    "оставляет",
+    "оставляй",
+    "оставляйте",
+    "оставлял",
+    "оставляли",
+    "оставлять",
+    "оставляю",
+    "оставляют",
+    "оставляя",
+    "оставшийся",
+    "оставшимся",
+    "оставшись",
+    "оставшихся",
+    "оставь",
+    "оставьте",
+    "оставят",
+    "остаемся",
+    "остается",
+    "осталась",
+    "остались",
+    "осталось",
+    "остался",
+    "остальная",
+    "остального",
+    "остальное",
+    "остальной",
+    "остальном",
+    "остальную",
+    "остальные",
+    "остальным",
+    "остальных",
+    "останавливаемся",
+    "останавливаетесь",
+    "останавливается",
+    "останавливайся",
+    "останавливал",
+    "останавливалась",
+    "останавливались",
+    "останавливался",
+    "останавливать",
+    "останавливаться",
+    "останавливаются",
+    "останавливающихся",
+    "останавливая",
+    "останавливаясь",
+    "останемся",
+    "останетесь",
+    "останется",
+    "останешься",
+    "останови",
+    "остановив",
+    "остановившегося",
+    "остановившись",
+    "остановил",
+    "остановила",
+    "остановилась",
+    "остановили",
+    "остановились",
+    "остановилось",
+    "остановился",
+    "остановись",
+    "остановит",
+    "остановите",
+    "остановитесь",
+    "остановится",
+    "остановить",
+    "остановиться",
+    "остановишь",
+    "остановка",
+    "остановку",
+    "остановлюсь",
+    "остановясь",
+    "остановят",
+    "остановятся",
+    "останусь",
+    "останутся",
+    "останься",
+    "останьтесь",
+    "остатке",
+    "остатки",
+    "остаток",
+    "остаться",
+    "остаюсь",
+    "остаются",
+    "остающимися",
+    "остервенением",
+    "остервенении",
+    "остервенения",
+    "остервенясь",
+    "остереглись",
+    "остеречь",
+    "остзейскому",
+    "остолбенел",
+    "остолбенелый",
+    "осторожен",
+    "осторожна",
+    "осторожнее",
+    "осторожно",
+    "осторожного",
+    "осторожности",
+    "осторожностию",
+    "осторожность",
+    "осторожностью",
+    "осторожною",
+    "осторожны",
+    "осторожный",
+    "осторожным",
+    "осторожными",
+    "острастка",
+    "остри",
+    "острием",
+    "острия",
+    "остров",
+    "острова",
+    "островах",
+    "острове",
+    "островов",
+    "островок",
+    "островского",
+    "острову",
+    "острог",
+    "острога",
+    "остроге",
+    "острого",
+    "острое",
+    "острожной",
+    "острожных",
+    "острота",
+    "остротами",
+    "остротою",
+    "остроты",
+    "остроумие",
+    "остроумием",
+    "остроумии",
+    "остроумия",
+    "остроумная",
+    "остроумнее",
+    "остроумнейшим",
+    "остроумно",
+    "остроумного",
+    "остроумны",
+    "острые",
+    "острый",
+    "острым",
+    "остудился",
+    "оступался",
+    "оступаясь",
+    "остыл",
+    "остынет",
+    "осудили",
+    "осудит",
+    "осудить",
+    "осудят",
+    "осуждает",
+    "осуждайте",
+    "осуждала",
+    "осуждаю",
+    "осужден",
+    "осуждена",
+    "осужденные",
+    "осужденных",
+    "осунулось",
+    "осунулся",
+    "осушая",
+    "осуществилось",
+    "осуществился",
+    "осуществится",
+    "осуществить",
+    "осуществлялась",
+    "осчастливил",
+    "осчастливили",
+    "осчастливленный",
+    "осыпавшимися",
+    "осыпает",
+    "осыпается",
+    "осыпала",
+    "осыпалась",
+    "осыпали",
+    "осыпанье",
+    "осыпать",
+    "осыпка",
+    "осыплет",
+    "осьмерка",
+    "от",
+    "отбивался",
+    "отбивные",
+    "отбился",
+    "отбирал",
+    "отбить",
+    "отблагодарила",
+    "отблеск",
+    "отблеском",
+    "отборно",
+    "отборный",
+    "отбрасывать",
+    "отбросив",
+    "отбросил",
+    "отбросила",
+    "отброшенный",
+    "отваги",
+    "отвагой",
+    "отвагу",
+    "отваживает",
+    "отважно",
+    "отважные",
+    "отважный",
+    "отвален",
+    "отваливать",
+    "отвалил",
+    "отведает",
+    "отведать",
+    "отведет",
+    "отведу",
+    "отвезите",
+    "отвезли",
+    "отвезти",
+    "отвека",
+    "отвел",
+    "отвела",
+    "отвергался",
+    "отверделость",
+    "отверженец",
+    "отверженности",
+    "отверзалась",
+    "отверзалось",
+    "отверзлась",
+    "отверзший",
+    "отвернется",
+    "отвернувшееся",
+    "отвернувшись",
+    "отвернулась",
+    "отвернулись",
+    "отвернулся",
+    "отвернуться",
+    "отвертывалась",
+    "отвертываться",
+    "отвесной",
+    "отвесов",
+    "отвести",
+    "ответ",
+    "ответа",
+    "ответе",
+    "ответив",
+    "ответил",
+    "ответила",
+    "ответили",
+    "ответит",
+    "ответите",
+    "ответить",
+    "ответишь",
+    "ответный",
+    "ответов",
+    "ответом",
+    "ответственности",
+    "ответственность",
+    "ответу",
+    "ответы",
+    "ответь",
+    "ответьте",
+    "отвечает",
+    "отвечаешь",
+    "отвечай",
+    "отвечайте",
+    "отвечал",
+    "отвечала",
+    "отвечали",
+    "отвечать",
+    "отвечаю",
+    "отвечая",
+    "отвечу",
+    "отвешивает",
+    "отвинчу",
+    "отвлекайся",
+    "отвлекала",
+    "отвлекали",
+    "отвлеченен",
+    "отвлеченные",
+    "отвлеченных",
+    "отвлечь",
+    "отво",
+    "отвода",
+    "отводил",
+    "отводили",
+    "отводило",
+    "отводит",
+    "отводить",
+    "отводу",
+    "отводя",
+    "отводят",
+    "отвожу",
+    "отворачивалась",
+    "отворачиваться",
+    "отворачивая",
+    "отворачиваясь",
+    "отворен",
+    "отворена",
+    "отворенной",
+    "отворенною",
+    "отворенную",
+    "отворенные",
+    "отворенных",
+    "отворены",
+    "отвори",
+    "отворив",
+    "отворившего",
+    "отворил",
+    "отворила",
+    "отворилась",
+    "отворили",
+    "отворились",
+    "отворит",
+    "отворите",
+    "отворить",
+    "отворотами",
+    "отворотил",
+    "отворотился",
+    "отворотит",
+    "отворотясь",
+    "отворю",
+    "отворявшийся",
+    "отворяет",
+    "отворяется",
+    "отворяешь",
+    "отворяй",
+    "отворяйте",
+    "отворял",
+    "отворялась",
+    "отворялись",
+    "отворяль",
+    "отворять",
+    "отворяться",
+    "отворяют",
+    "отворяются",
+    "отворяя",
+    "отвратил",
+    "отвратительна",
+    "отвратительная",
+    "отвратительнее",
+    "отвратительно",
+    "отвратительной",
+    "отвратительные",
+    "отвратительный",
+    "отвратительным",
+    "отвратить",
+    "отвращение",
+    "отвращением",
+    "отвращении",
+    "отвращения",
+    "отвращенье",
+    "отвык",
+    "отвыкла",
+    "отвыкни",
+    "отвычки",
+    "отвяжешься",
+    "отвяжутся",
+    "отвязал",
+    "отвязался",
+    "отвязать",
+    "отвязаться",
+    "отгадать",
+    "отгадываний",
+    "отговаривался",
+    "отговариваться",
+    "отговорил",
+    "отговорился",
+    "отговорка",
+    "отговорки",
+    "отголосок",
+    "отгони",
+    "отгонит",
+    "отгонишь",
+    "отгонял",
+    "отгоняющее",
+    "отгороженное",
+    "отгуляется",
+    "отдававшаяся",
+    "отдавал",
+    "отдавала",
+    "отдавали",
+    "отдавался",
+    "отдавать",
+    "отдаваться",
+    "отдавят",
+    "отдадите",
+    "отдает",
+    "отдаете",
+    "отдается",
+    "отдай",
+    "отдайте",
+    "отдайтесь",
+    "отдал",
+    "отдала",
+    "отдалась",
+    "отдалении",
+    "отдаленно",
+    "отдаленного",
+    "отдаленное",
+    "отдаленной",
+    "отдаленную",
+    "отдаленные",
+    "отдаленный",
+    "отдаленным",
+    "отдаленных",
+    "отдали",
+    "отдалить",
+    "отдалял",
+    "отдам",
+    "отдан",
+    "отданию",
+    "отдано",
+    "отдаст",
+    "отдать",
+    "отдаться",
+    "отдашь",
+    "отдаю",
+    "отдают",
+    "отдаются",
+    "отдающих",
+    "отдающуюся",
+    "отделан",
+    "отделанную",
+    "отделаться",
+    "отделаюсь",
+    "отделение",
+    "отделении",
+    "отделению",
+    "отделения",
+    "отделенная",
+    "отделено",
+    "отделенье",
+    "отделкой",
+    "отделку",
+    "отделом",
+    "отделываемой",
+    "отделывается",
+    "отделывали",
+    "отделывать",
+    "отделываться",
+    "отделывают",
+    "отдельная",
+    "отдельно",
+    "отдельное",
+    "отдельной",
+    "отдельные",
+    "отдельных",
+    "отделявший",
+    "отделяла",
+    "отделялась",
+    "отделялось",
+    "отделять",
+    "отделяющей",
+    "отдернул",
+    "отдернула",
+    "отдохнем",
+    "отдохнет",
+    "отдохнешь",
+    "отдохни",
+    "отдохните",
+    "отдохновенья",
+    "отдохну",
+    "отдохнув",
+    "отдохнул",
+    "отдохнула",
+    "отдохнуть",
+    "отдых",
+    "отдыха",
+    "отдыхает",
+    "отдыхаете",
+    "отдыхал",
+    "отдыхала",
+    "отдыхать",
+    "отдыхаю",
+    "отдыхая",
+    "отдыхиваясь",
+    "отдыхнувшись",
+    "отдыхнулся",
+    "отдыхом",
+    "отдышалась",
+    "отдышаться",
+    "отекать",
+    "отекшим",
+    "отель",
+    "отеля",
+    "отер",
+    "отерла",
+    "отец",
+    "отеческое",
+    "отеческой",
+    "отечества",
+    "отечественные",
+    "отечеству",
+    "отжившее",
+    "отжила",
+    "отжило",
+    "отзвук",
+    "отзвучие",
+    "отзовется",
+    "отзыв",
+    "отзыва",
+    "отзывается",
+    "отзываешься",
+    "отзываться",
+    "отзывов",
+    "отзывом",
+    "отзыву",
+    "отзывы",
+    "отиравшую",
+    "отирать",
+    "отирая",
+    "откажемся",
+    "откажет",
+    "откажетесь",
+    "откажется",
+    "откажите",
+    "откажитесь",
+    "откажу",
+    "откажусь",
+    "отказа",
+    "отказавшись",
+    "отказал",
+    "отказала",
+    "отказалась",
+    "отказали",
+    "отказались",
+    "отказался",
+    "отказано",
+    "отказать",
+    "отказаться",
+    "отказом",
+    "отказывает",
+    "отказываете",
+    "отказываетесь",
+    "отказываешься",
+    "отказывайтесь",
+    "отказывал",
+    "отказывался",
+    "отказываться",
+    "отказываюсь",
+    "откапывать",
+    "откармливались",
+    "откашливаться",
+    "откашлянулся",
+    "откидные",
+    "откидываясь",
+    "откинув",
+    "откинула",
+    "откинутом",
+    "откладывал",
+    "откладывать",
+    "откладывая",
+    "откланивалась",
+    "откланиваться",
+    "откланиваясь",
+    "откланялась",
+    "откланялся",
+    "откланяться",
+    "откликалось",
+    "откликаясь",
+    "отклики",
+    "откликнулась",
+    "откликнулся",
+    "отклонив",
+    "отклонить",
+    "отколотил",
+    "отколотили",
+    "откомандировала",
+    "откопал",
+    "откопать",
+    "откровенен",
+    "откровенна",
+    "откровеннее",
+    "откровеннейшим",
+    "откровенничает",
+    "откровенничал",
+    "откровенно",
+    "откровенное",
+    "откровенности",
+    "откровенностию",
+    "откровенность",
+    "откровенностью",
+    "откровенною",
+    "откровенны",
+    "откровенный",
+    "откровенных",
+    "откроет",
+    "откроется",
+    "открой",
+    "откройся",
+    "открою",
+    "откроют",
+    "откроются",
+    "открыв",
+    "открывает",
+    "открывается",
+    "открывай",
+    "открывал",
+    "открывалась",
+    "открывались",
+    "открывалось",
+    "открывался",
+    "открывать",
+    "открываться",
+    "открываю",
+    "открываются",
+    "открывающимся",
+    "открывая",
+    "открыл",
+    "открыла",
+    "открылась",
+    "открыли",
+    "открылись",
+    "открылось",
+    "открыт",
+    "открыта",
+    "открытая",
+    "открытие",
+    "открытием",
+    "открытий",
+    "открытию",
+    "открытия",
+    "открыто",
+    "открытое",
+    "открытой",
+    "открытом",
+    "открытою",
+    "открытую",
+    "открыты",
+    "открытый",
+    "открытым",
+    "открытыми",
+    "открытых",
+    "открыть",
+    "открыться",
+    "откуда",
+    "откудова",
+    "откупа",
+    "откупам",
+    "откупиться",
+    "откупорил",
+    "откусила",
+    "откусить",
+    "откушать",
+    "откушенной",
+    "отлагается",
+    "отлагал",
+    "отлегло",
+    "отлетевший",
+    "отлетел",
+    "отлетела",
+    "отлетит",
+    "отлива",
+    "отливались",
+    "отливало",
+    "отливать",
+    "отличаемся",
+    "отличает",
+    "отличались",
+    "отличалось",
+    "отличат",
+    "отличать",
+    "отличаются",
+    "отличие",
+    "отличительные",
+    "отличить",
+    "отличная",
+    "отличнейший",
+    "отлично",
+    "отличное",
+    "отличной",
+    "отличном",
+    "отличную",
+    "отличные",
+    "отличный",
+    "отлогие",
+    "отлогих",
+    "отложат",
+    "отложенные",
+    "отложив",
+    "отложил",
+    "отложили",
+    "отложим",
+    "отложите",
+    "отложить",
+    "отложу",
+    "отломанными",
+    "отломок",
+    "отлучался",
+    "отлучился",
+    "отлучиться",
+    "отлучке",
+    "отлучусь",
+    "отмалчивается",
+    "отмахивается",
+    "отмахивался",
+    "отмахиваются",
+    "отмахиваясь",
+    "отмахнувшись",
+    "отмахнулась",
+    "отменили",
+    "отменить",
+    "отменном",
+    "отменные",
+    "отменный",
+    "отменят",
+    "отменяю",
+    "отметил",
+    "отметили",
+    "отметки",
+    "отметкою",
+    "отмеченный",
+    "отмеченных",
+    "отмечены",
+    "отмоешь",
+    "отмыв",
+    "отмывает",
+    "отмывал",
+    "отмывать",
+    "отмыли",
+    "отнекивалась",
+    "отнекивался",
+    "отнес",
+    "отнесет",
+    "отнесла",
+    "отнеслись",
+    "отнесло",
+    "отнесся",
+    "отнести",
+    "отнесу",
+    "отнимает",
+    "отнимается",
+    "отнимал",
+    "отнимала",
+    "отнимало",
+    "отнимаю",
+    "отнимая",
+    "отнимет",
+    "отнимите",
+    "отниму",
+    "отнимут",
+    "относил",
+    "относилась",
+    "относились",
+    "относительно",
+    "относительны",
+    "относитесь",
+    "относится",
+    "относиться",
+    "относятся",
+    "относящимся",
+    "отношение",
+    "отношении",
+    "отношений",
+    "отношения",
+    "отношениях",
+    "отношусь",
+    "отныне",
+    "отнюдь",
+    "отняв",
+    "отнял",
+    "отняли",
+    "отнялись",
+    "отнять",
+    "ото",
+    "отобедаем",
+    "отобедайте",
+    "отобедал",
+    "отобедать",
+    "отобедаю",
+    "отобрал",
+    "отобрать",
+    "отовсюду",
+    "отогнал",
+    "отогнать",
+    "отогрел",
+    "отогрелось",
+    "отодвигалась",
+    "отодвигалось",
+    "отодвигаться",
+    "отодвигая",
+    "отодвигаясь",
+    "отодвинув",
+    "отодвинул",
+    "отодвинула",
+    "отодвинулось",
+    "отодвинулся",
+    "отодвинуть",
+    "отозвал",
+    "отозвалась",
+    "отозвалось",
+    "отозвался",
+    "отозваться",
+    "отойдет",
+    "отойди",
+    "отойду",
+    "отойдя",
+    "отойти",
+    "отолстел",
+    "отолщением",
+    "отомкнув",
+    "отомкнула",
+    "отомстить",
+    "отопление",
+    "отопрет",
+    "отопрется",
+    "оторвавшись",
+    "оторвала",
+    "оторвало",
+    "оторвать",
+    "оторваться",
+    "оторвет",
+    "оторопев",
+    "оторопевший",
+    "оторопел",
+    "оторопью",
+    "отослал",
+    "отослана",
+    "отошел",
+    "отошла",
+    "отошли",
+    "отошло",
+    "отощал",
+    "отпаривал",
+    "отпевании",
+    "отпейте",
+    "отпер",
+    "отперла",
+    "отперли",
+    "отперта",
+    "отпертая",
+    "отперто",
+    "отпертой",
+    "отпертую",
+    "отпечаталась",
+    "отпечатают",
+    "отпив",
+    "отпивая",
+    "отпил",
+    "отпирает",
+    "отпирала",
+    "отпирать",
+    "отпирают",
+    "отписал",
+    "отпихнул",
+    "отпихнула",
+    "отпихнуть",
+    "отплясывал",
+    "отполированного",
+    "отпор",
+    "отправившимся",
+    "отправил",
+    "отправила",
+    "отправилась",
+    "отправили",
+    "отправились",
+    "отправилось",
+    "отправился",
+    "отправимся",
+    "отправитесь",
+    "отправится",
+    "отправить",
+    "отправиться",
+    "отправишь",
+    "отправишься",
+    "отправлен",
+    "отправление",
+    "отправлении",
+    "отправлений",
+    "отправления",
+    "отправлениях",
+    "отправленью",
+    "отправлю",
+    "отправлюсь",
+    "отправляетесь",
+    "отправляется",
+    "отправляйся",
+    "отправляйтесь",
+    "отправляла",
+    "отправляли",
+    "отправлялись",
+    "отправлялось",
+    "отправлялся",
+    "отправляю",
+    "отправляюсь",
+    "отправляются",
+    "отправляющей",
+    "отправляясь",
+    "отправятся",
+    "отпраздновать",
+    "отпрыск",
+    "отпрыске",
+    "отпрыски",
+    "отпрыску",
+    "отпуск",
+    "отпускает",
+    "отпускай",
+    "отпускала",
+    "отпускалась",
+    "отпускали",
+    "отпускалось",
+    "отпускать",
+    "отпуская",
+    "отпусти",
+    "отпустил",
+    "отпустит",
+    "отпустить",
+    "отпустят",
+    "отпущен",
+    "отпущено",
+    "отрав",
+    "отрава",
+    "отрави",
+    "отравил",
+    "отравилась",
+    "отравился",
+    "отравитесь",
+    "отравится",
+    "отравить",
+    "отравиться",
+    "отравлений",
+    "отравленной",
+    "отравленный",
+    "отравленным",
+    "отравлены",
+    "отравляешь",
+    "отравляла",
+    "отравлять",
+    "отрада",
+    "отрадно",
+    "отрадного",
+    "отражалось",
+    "отражать",
+    "отражаться",
+    "отражаясь",
+    "отражение",
+    "отражением",
+    "отражены",
+    "отразились",
+    "отразилось",
+    "отразился",
+    "отразится",
+    "отрапортует",
+    "отрастил",
+    "отрезал",
+    "отрезала",
+    "отрезали",
+    "отрезанные",
+    "отрезанный",
+    "отрезать",
+    "отрезвел",
+    "отрезвился",
+    "отрезвись",
+    "отрезвится",
+    "отрезвляется",
+    "отрезвлялась",
+    "отрезвляло",
+    "отрезвляться",
+    "отрезвляющее",
+    "отрезвляясь",
+    "отрезков",
+    "отрекаетесь",
+    "отрекался",
+    "отрекаюсь",
+    "отрекомендовался",
+    "отрекомендовать",
+    "отрекомендоваться",
+    "отрекся",
+    "отретировался",
+    "отречение",
+    "отречения",
+    "отречься",
+    "отрешиться",
+    "отринь",
+    "отрицаем",
+    "отрицаете",
+    "отрицал",
+    "отрицания",
+    "отрицатели",
+    "отрицательная",
+    "отрицательно",
+    "отрицательный",
+    "отрицаю",
+    "отрицают",
+    "отрицая",
+    "отроду",
+    "отродясь",
+    "отрочества",
+    "отрочестве",
+    "отрубленную",
+    "отрывает",
+    "отрывается",
+    "отрывал",
+    "отрывала",
+    "отрывать",
+    "отрывая",
+    "отрываясь",
+    "отрывисто",
+    "отрывистые",
+    "отрывки",
+    "отрывок",
+    "отрывочно",
+    "отряд",
+    "отряс",
+    "отсвет",
+    "отсветы",
+    "отсидела",
+    "отскакивавший",
+    "отскочи",
+    "отскочив",
+    "отскочил",
+    "отскочила",
+    "отскочило",
+    "отслужив",
+    "отслужить",
+    "отслужу",
+    "отсохли",
+    "отсрочивать",
+    "отсрочишь",
+    "отставал",
+    "отставила",
+    "отставке",
+    "отставку",
+    "отставного",
+    "отставной",
+    "отставные",
+    "отставшие",
+    "отставший",
+    "отставшими",
+    "отстает",
+    "отстал",
+    "отстала",
+    "отстали",
+    "отстало",
+    "отсталого",
+    "отсталостью",
+    "отсталый",
+    "отсталых",
+    "отстанет",
+    "отстану",
+    "отстанут",
+    "отстань",
+    "отстаньте",
+    "отстать",
+    "отстаю",
+    "отстегнул",
+    "отстоял",
+    "отстоялся",
+    "отстранились",
+    "отстраняются",
+    "отстраняя",
+    "отстраняясь",
+    "отстряпает",
+    "отступает",
+    "отступало",
+    "отступающих",
+    "отступая",
+    "отступив",
+    "отступил",
+    "отступила",
+    "отступили",
+    "отступило",
+    "отступилось",
+    "отступишься",
+    "отступлю",
+    "отступлюсь",
+    "отступятся",
+    "отсутствие",
+    "отсутствием",
+    "отсутствия",
+    "отсутствующему",
+    "отсутствующих",
+    "отсчитал",
+    "отсчитывать",
+    "отсылал",
+    "отсылать",
+    "отсыпной",
+    "отсюда",
+    "оттает",
+    "отталкиваешь",
+    "отталкивал",
+    "отталкивала",
+    "отталкивали",
+    "отталкивало",
+    "отталкивать",
+    "отталкивающее",
+    "отталкивающий",
+    "отталкивающим",
+    "отталкивая",
+    "оттащить",
+    "оттенка",
+    "оттенком",
+    "оттенок",
+    "оттеняет",
+    "оттеняется",
+    "оттепель",
+    "оттепелями",
+    "оттер",
+    "оттерты",
+    "оттого",
+    "оттолкнет",
+    "оттолкнешь",
+    "оттолкнув",
+    "оттолкнул",
+    "оттолкнула",
+    "оттолкнутый",
+    "оттопырившуюся",
+    "оттрепал",
+    "оттреплет",
+    "оттуда",
+    "оттудова",
+    "оттянулось",
+    "отуманенный",
+    "отуманивать",
+    "отупения",
+    "отупеть",
+    "отупления",
+    "отучены",
+    "отучит",
+    "отхаркивая",
+    "отхаркнулась",
+    "отхватит",
+    "отхлебнул",
+    "отхлебывая",
+    "отхлеставшему",
+    "отхлестал",
+    "отхлынет",
+    "отхлынули",
+    "отходи",
+    "отходил",
+    "отходила",
+    "отходит",
+    "отходной",
+    "отходят",
+    "отца",
+    "отцам",
+    "отцами",
+    "отцах",
+    "отцвели",
+    "отцветшего",
+    "отцветшими",
+    "отце",
+    "отцов",
+    "отцовские",
+    "отцовский",
+    "отцовских",
+    "отцовскую",
+    "отцом",
+    "отцу",
+    "отцы",
+    "отчаивайся",
+    "отчаивался",
+    "отчаиваться",
+    "отчасти",
+    "отчаявшийся",
+    "отчаялась",
+    "отчаялись",
+    "отчаяние",
+    "отчаянием",
+    "отчаянии",
+    "отчаянию",
+    "отчаяния",
+    "отчаянная",
+    "отчаяннее",
+    "отчаянно",
+    "отчаянного",
+    "отчаянное",
+    "отчаянной",
+    "отчаянном",
+    "отчаянному",
+    "отчаянною",
+    "отчаянные",
+    "отчаянный",
+    "отчаянным",
+    "отчаянных",
+    "отчаянье",
+    "отчаянью",
+    "отчаянья",
+    "отчаяться",
+    "отче",
+    "отчего",
+    "отчеканивая",
+    "отчеканила",
+    "отчеканилась",
+    "отчеканился",
+    "отчество",
+    "отчет",
+    "отчета",
+    "отчетливо",
+    "отчетливым",
+    "отчетов",
+    "отчетом",
+    "отчеты",
+    "отчизне",
+    "отчизну",
+    "отчий",
+    "отчиной",
+    "отчистил",
+    "отчищенных",
+    "отчуждения",
+    "отшатнулась",
+    "отшатнулся",
+    "отшельник",
+    "отшибло",
+    "отшумела",
+    "отщелкивая",
+    "отъезд",
+    "отъезда",
+    "отъезде",
+    "отъездом",
+    "отъезду",
+    "отъезжают",
+    "отъезжающими",
+    "отъехав",
+    "отъявленного",
+    "отъявленной",
+    "отъявленным",
+    "отыскав",
+    "отыскал",
+    "отыскала",
+    "отыскалась",
+    "отыскали",
+    "отыскались",
+    "отыскалось",
+    "отыскался",
+    "отыскание",
+    "отыскать",
+    "отыскивает",
+    "отыскиваете",
+    "отыскивал",
+    "отыскивали",
+    "отыскивать",
+    "отыскиваются",
+    "отыскивая",
+    "отыщется",
+    "отыщешь",
+    "отыщи",
+    "отягчены",
+    "отяжелевшую",
+    "отяжелели",
+    "отяжелелой",
+    "офелию",
+    "офицер",
+    "офицера",
+    "офицерами",
+    "офицеров",
+    "офицером",
+    "офицерская",
+    "офицерских",
+    "офицеру",
+    "офицеры",
+    "официально",
+    "официальное",
+    "официальном",
+    "официальный",
+    "официальным",
+    "официальных",
+    "офранцузят",
+    "ох",
+    "охает",
+    "охаете",
+    "охала",
+    "оханье",
+    "оханья",
+    "охапку",
+    "охая",
+    "охватил",
+    "охватила",
+    "охватили",
+    "охватило",
+    "охватить",
+    "охватывавшим",
+    "охватывал",
+    "охватывала",
+    "охватывают",
+    "охвачен",
+    "охваченный",
+    "охи",
+    "охладевших",
+    "охлаждается",
+    "охлаждался",
+    "охлаждение",
+    "охлаждений",
+    "охмелел",
+    "охмелия",
+    "охмеляющего",
+    "охнет",
+    "охнуть",
+    "охо",
+    "охота",
+    "охоте",
+    "охотку",
+    "охотливо",
+    "охотнее",
+    "охотник",
+    "охотника",
+    "охотничья",
+    "охотно",
+    "охотой",
+    "охоту",
+    "охоты",
+    "охранению",
+    "охраню",
+    "охраняет",
+    "охраняя",
+    "охрипший",
+    "охта",
+    "оцененного",
+    "оцени",
+    "оценила",
+    "оценили",
+    "оценить",
+    "оценку",
+    "оцепенеет",
+    "оцепенел",
+    "оцепенело",
+    "оцепенелой",
+    "оцепенении",
+    "оцепенения",
+    "оцепили",
+    "оч",
+    "очаг",
+    "очами",
+    "очаровал",
+    "очарован",
+    "очарована",
+    "очарование",
+    "очарования",
+    "очарованной",
+    "очарованный",
+    "очарованье",
+    "очарованьи",
+    "очарованьям",
+    "очарователен",
+    "очаровательна",
+    "очаровательная",
+    "очаровательнее",
+    "очаровательнейшая",
+    "очаровательное",
+    "очах",
+    "очевидно",
+    "очевидное",
+    "очевидности",
+    "очевидность",
+    "очевидную",
+    "очевидные",
+    "очевидный",
+    "очей",
+    "очень",
+    "очереди",
+    "очередь",
+    "очерк",
+    "очертаний",
+    "очертания",
+    "очертанье",
+    "очертил",
+    "очертя",
+    "очи",
+    "очинил",
+    "очините",
+    "очинна",
+    "очистительных",
+    "очистить",
+    "очистки",
+    "очищает",
+    "очищать",
+    "очищений",
+    "очию",
+    "очками",
+    "очках",
+    "очки",
+    "очнетесь",
+    "очнется",
+    "очнешься",
+    "очнитесь",
+    "очнувшийся",
+    "очнувшись",
+    "очнулась",
+    "очнулись",
+    "очнулся",
+    "очнусь",
+    "очнуться",
+    "очумел",
+    "очутившиеся",
+    "очутившийся",
+    "очутилась",
+    "очутились",
+    "очутилось",
+    "очутился",
+    "очутиться",
+    "очутясь",
+    "ошеломлен",
+    "ошеломленная",
+    "ошень",
+    "ошибаетесь",
+    "ошибается",
+    "ошибаешься",
+    "ошибалась",
+    "ошибались",
+    "ошибался",
+    "ошибаться",
+    "ошибаюсь",
+    "ошибаются",
+    "ошибетесь",
+    "ошибиться",
+    "ошибка",
+    "ошибке",
+    "ошибки",
+    "ошибкой",
+    "ошибку",
+    "ошиблась",
+    "ошиблись",
+    "ошибок",
+    "ошибочка",
+    "ошибочно",
+    "ошибочное",
+    "ошибочных",
+    "ошибся",
+    "ошибусь",
+    "ощеришься",
+    "ощетинившейся",
+    "ощетинился",
+    "ощупав",
+    "ощупал",
+    "ощупом",
+    "ощупывает",
+    "ощупывая",
+    "ощупью",
+    "ощутил",
+    "ощутилась",
+    "ощутим",
+    "ощутительно",
+    "ощутить",
+    "ощущаете",
+    "ощущается",
+    "ощущал",
+    "ощущала",
+    "ощущать",
+    "ощущаю",
+    "ощущая",
+    "ощущение",
+    "ощущением",
+    "ощущении",
+    "ощущений",
+    "ощущения",
+    "п",
+    "па",
+    "пава",
+    "павел",
+    "павильон",
+    "павильонам",
+    "павла",
+    "павлиний",
+    "павлиньи",
+    "павлиньим",
+    "павлович",
+    "павловна",
+    "павловне",
+    "павловной",
+    "павловну",
+    "павловны",
+    "павловцы",
+    "павлыч",
+    "павлыча",
+    "пагубная",
+    "падает",
+    "падай",
+    "падал",
+    "падала",
+    "падаль",
+    "падать",
+    "падаю",
+    "падают",
+    "падающего",
+    "падающие",
+    "падеж",
+    "падение",
+    "падением",
+    "падении",
+    "падений",
+    "падения",
+    "паденье",
+    "паденья",
+    "падет",
+    "падут",
+    "падучая",
+    "падчерицей",
+    "падчерицы",
+    "падшая",
+    "падшей",
+    "падшему",
+    "падший",
+    "падшим",
+    "падших",
+    "падшую",
+    "паек",
+    "пазухи",
+    "пазуху",
+    "пай",
+    "пакет",
+    "пакетом",
+    "пакеты",
+    "пакостей",
+    "пакостно",
+    "пал",
+    "пала",
+    "паладином",
+    "палата",
+    "палате",
+    "палату",
+    "палаты",
+    "пале",
+    "палевые",
+    "палевых",
+    "палец",
+    "пали",
+    "палил",
+    "палим",
+    "палима",
+    "палимый",
+    "палит",
+    "палка",
+    "палки",
+    "палкой",
+    "палку",
+    "палладиумом",
+    "палочкой",
+    "палочку",
+    "пальбу",
+    "пальмерстон",
+    "пальмерстоном",
+    "пальмой",
+    "пальмы",
+    "пальто",
+    "пальца",
+    "пальцам",
+    "пальцами",
+    "пальцах",
+    "пальце",
+    "пальцев",
+    "пальцем",
+    "пальцы",
+    "пальчик",
+    "пальчика",
+    "пальчики",
+    "палящая",
+    "палящих",
+    "памятен",
+    "памяти",
+    "памятлив",
+    "памятниками",
+    "памятно",
+    "памятной",
+    "память",
+    "памятью",
+    "пан",
+    "панаму",
+    "пане",
+    "панегирика",
+    "панели",
+    "пани",
+    "панихид",
+    "панихиду",
+    "панихиды",
+    "панический",
+    "панического",
+    "панн",
+    "панна",
+    "панорама",
+    "панораму",
+    "панорамы",
+    "пансион",
+    "пансиона",
+    "пансионе",
+    "пансионерки",
+    "пансионной",
+    "панталон",
+    "панталонах",
+    "панталоны",
+    "пантелеевич",
+    "пантелеевна",
+    "пантелеич",
+    "пантера",
+    "панцырь",
+    "папа",
+    "папаша",
+    "папашей",
+    "папаши",
+    "папашу",
+    "папенька",
+    "папеньке",
+    "папеньки",
+    "папенькой",
+    "паперти",
+    "папильотках",
+    "папирос",
+    "папиросами",
+    "папиросками",
+    "папироски",
+    "папироской",
+    "папироску",
+    "папиросочка",
+    "папиросочку",
+    "папиросочница",
+    "папиросочницу",
+    "папиросу",
+    "папиросы",
+    "папка",
+    "папку",
+    "папочка",
+    "папочку",
+    "папы",
+    "папье",
+    "пар",
+    "пара",
+    "параболу",
+    "парада",
+    "парадная",
+    "парадное",
+    "парадному",
+    "парадных",
+    "параду",
+    "парализованы",
+    "паралич",
+    "паралича",
+    "параллель",
+    "параллельно",
+    "параша",
+    "парашу",
+    "пардон",
+    "паре",
+    "парень",
+    "пари",
+    "париж",
+    "парижа",
+    "париже",
+    "парижем",
+    "парижских",
+    "парижскому",
+    "парикмахера",
+    "париком",
+    "парили",
+    "парит",
+    "парк",
+    "парка",
+    "парке",
+    "парку",
+    "пармезаном",
+    "парней",
+    "парнем",
+    "парнике",
+    "парнишка",
+    "парнишкой",
+    "парню",
+    "парня",
+    "парой",
+    "пароксизме",
+    "пароксизмы",
+    "паром",
+    "пароход",
+    "парохода",
+    "пароходах",
+    "пароходе",
+    "пароходы",
+    "партере",
+    "партией",
+    "партии",
+    "партию",
+    "партия",
+    "пару",
+    "парус",
+    "парусах",
+    "парчами",
+    "парче",
+    "пары",
+    "пас",
+    "паску",
+    "пасли",
+    "пасмурна",
+    "пасмурно",
+    "пасмурный",
+    "паспорт",
+    "паспорта",
+    "паспорте",
+    "паспорту",
+    "паспорты",
+    "пассивно",
+    "пастил",
+    "пастух",
+    "пастухи",
+    "пастухом",
+    "пастуший",
+    "пастушков",
+    "пастырь",
+    "пасть",
+    "пастья",
+    "пасхальной",
+    "пасхальный",
+    "пасхе",
+    "пасхи",
+    "пасху",
+    "пасьянс",
+    "патентованных",
+    "патер",
+    "патетическая",
+    "патетически",
+    "патетических",
+    "патетической",
+    "патетическому",
+    "патетическую",
+    "патоки",
+    "патриархальной",
+    "пауза",
+    "паузы",
+    "паук",
+    "пауке",
+    "пауки",
+    "пауком",
+    "паутина",
+    "паутиной",
+    "паутину",
+    "паутины",
+    "паф",
+    "пафос",
+    "пахарь",
+    "пахать",
+    "пахло",
+    "пахнет",
+    "пахнул",
+    "пахнуло",
+    "пахнут",
+    "пациент",
+    "пациента",
+    "пациентами",
+    "пациентов",
+    "паче",
+    "пачками",
+    "пачки",
+    "пачку",
+    "пачтенье",
+    "пачулями",
+    "пашен",
+    "пашенька",
+    "пашеньке",
+    "пашеньки",
+    "пашенькой",
+    "пашка",
+    "пашой",
+    "пашут",
+    "паях",
+    "паяц",
+    "паяца",
+    "пе",
+    "певал",
+    "певали",
+    "певец",
+    "певица",
+    "певицы",
+    "певуче",
+    "певучее",
+    "певучей",
+    "певучий",
+    "певучим",
+    "певучих",
+    "певца",
+    "певцы",
+    "певчий",
+    "певчих",
+    "певший",
+    "пегашке",
+    "пегашку",
+    "пегую",
+    "педагог",
+    "педагогическом",
+    "педагогов",
+    "педант",
+    "педантизма",
+    "педантизмом",
+    "педантически",
+    "педантических",
+    "педантическое",
+    "педантическую",
+    "педанты",
+    "пей",
+    "пейзаж",
+    "пейзажа",
+    "пейзажах",
+    "пейзажей",
+    "пейзажи",
+    "пейзажист",
+    "пейсы",
+    "пейте",
+    "пекли",
+    "пеклись",
+    "пекут",
+    "пекущуюся",
+    "пел",
+    "пела",
+    "пелагеи",
+    "пелагея",
+    "пеленами",
+    "пеленах",
+    "пеленой",
+    "пелериной",
+    "пели",
+    "пело",
+    "пена",
+    "пене",
+    "пени",
+    "пение",
+    "пением",
+    "пении",
+    "пенится",
+    "пения",
+    "пенки",
+    "пенкин",
+    "пенкиным",
+    "пеной",
+    "пеною",
+    "пенсии",
+    "пенсион",
+    "пенсиона",
+    "пенсионе",
+    "пенсионишко",
+    "пенсионом",
+    "пенсию",
+    "пень",
+    "пенье",
+    "пеньем",
+    "пеньи",
+    "пеняй",
+    "пеняйте",
+    "пенять",
+    "пепел",
+    "пепельницу",
+    "пепла",
+    "пеплом",
+    "пера",
+    "первая",
+    "первейшая",
+    "первейшими",
+    "первенец",
+    "первенство",
+    "первенствующей",
+    "первенствующую",
+    "первенца",
+    "первенце",
+    "перво",
+    "первобытною",
+    "первобытный",
+    "первого",
+    "первое",
+    "первозданный",
+    "первой",
+    "первом",
+    "первому",
+    "первоначальная",
+    "первоначально",
+    "первоначальное",
+    "первоначальную",
+    "первоначальные",
+    "первоначальный",
+    "первоначальных",
+    "первою",
+    "первую",
+    "первые",
+    "первый",
+    "первым",
+    "первых",
+    "пергаментным",
+    "пергаментом",
+    "перебегал",
+    "перебегала",
+    "перебегающими",
+    "перебежала",
+    "переберем",
+    "переберет",
+    "переберешься",
+    "переберись",
+    "переберусь",
+    "переберут",
+    "перебиваете",
+    "перебивай",
+    "перебивал",
+    "перебивалась",
+    "перебиванья",
+    "перебиваюсь",
+    "перебивая",
+    "перебившего",
+    "перебил",
+    "перебила",
+    "перебили",
+    "перебирает",
+    "перебирается",
+    "перебирай",
+    "перебирала",
+    "перебирать",
+    "перебирая",
+    "перебиты",
+    "перебить",
+    "переболело",
+    "перебороло",
+    "перебором",
+    "перебрал",
+    "перебрала",
+    "перебранился",
+    "перебраны",
+    "перебрасывается",
+    "перебудить",
+    "перебывает",
+    "перебывали",
+    "перебывало",
+    "перебьют",
+    "перевале",
+    "переваливаться",
+    "переваливаясь",
+    "перевалился",
+    "переварилась",
+    "переварится",
+    "переварить",
+    "переведет",
+    "перевез",
+    "перевезенную",
+    "перевезли",
+    "перевезут",
+    "перевел",
+    "перевелась",
+    "перевели",
+    "перевелись",
+    "перевернется",
+    "перевернул",
+    "перевернула",
+    "перевернули",
+    "перевернуло",
+    "перевернулось",
+    "перевернулся",
+    "перевернуть",
+    "перевертел",
+    "перевертывается",
+    "перевертывать",
+    "перевертываясь",
+    "перевес",
+    "перевести",
+    "перевесть",
+    "перевиваясь",
+    "перевод",
+    "переводе",
+    "переводили",
+    "переводить",
+    "переводной",
+    "переводов",
+    "переводом",
+    "переводы",
+    "переводя",
+    "переводят",
+    "перевожу",
+    "перевозиться",
+    "перевозка",
+    "перевозки",
+    "перевозят",
+    "переворачивается",
+    "переворачивался",
+    "переворачивая",
+    "переворот",
+    "переворотил",
+    "переворотов",
+    "переворочаешь",
+    "переврала",
+    "перевязан",
+    "перевязана",
+    "перевязочным",
+    "перевязь",
+    "переглотают",
+    "переглянулась",
+    "переглянулся",
+    "перегнувшись",
+    "переговаривал",
+    "переговорено",
+    "переговорив",
+    "переговорил",
+    "переговорили",
+    "переговорить",
+    "переговоры",
+    "переговорю",
+    "перегорает",
+    "перегородке",
+    "перегородки",
+    "перегородкой",
+    "перегородку",
+    "перед",
+    "передав",
+    "передаваемая",
+    "передаваемой",
+    "передавал",
+    "передавала",
+    "передавали",
+    "передавать",
+    "передавая",
+    "передадите",
+    "передает",
+    "передайте",
+    "передал",
+    "передала",
+    "передали",
+    "передам",
+    "переданной",
+    "передаст",
+    "передать",
+    "передаю",
+    "передвигая",
+    "переделает",
+    "переделаешь",
+    "переделала",
+    "переделано",
+    "переделать",
+    "переделки",
+    "переделку",
+    "переделывать",
+    "передергивая",
+    "передернуло",
+    "передернут",
+    "передке",
+    "передней",
+    "переднюю",
+    "передо",
+    "передовые",
+    "передовыми",
+    "передовых",
+    "передом",
+    "передразниванье",
+    "передразнил",
+    "передумав",
+    "передумает",
+    "передумал",
+    "передумала",
+    "передумано",
+    "передумать",
+    "передумывать",
+    "передушил",
+    "переедем",
+    "переедет",
+    "переедете",
+    "переедешь",
+    "перееду",
+    "переежаем",
+    "переезд",
+    "переезда",
+    "переезде",
+    "переездкой",
+    "переездом",
+    "переезжаем",
+    "переезжает",
+    "переезжай",
+    "переезжайте",
+    "переезжал",
+    "переезжали",
+    "переезжать",
+    "переезжаю",
+    "переезжают",
+    "переезжая",
+    "переехал",
+    "переехала",
+    "переехали",
+    "переехать",
+    "переешь",
+    "переждав",
+    "переждал",
+    "переждать",
+    "пережевывает",
+    "переживает",
+    "переживала",
+    "переживаю",
+    "переживем",
+    "переживешь",
+    "переживу",
+    "пережил",
+    "пережит",
+    "пережитых",
+    "пережить",
+    "перезрелою",
+    "переименованы",
+    "переимчивым",
+    "переиначил",
+    "переиначить",
+    "перейдет",
+    "перейдете",
+    "перейдут",
+    "перейдя",
+    "перейти",
+    "перекидывались",
+    "перекинет",
+    "перекинул",
+    "перекинуть",
+    "перекладных",
+    "перекладывал",
+    "перекладывала",
+    "перекликаются",
+    "перековать",
+    "перекосились",
+    "перекосилось",
+    "перекрестив",
+    "перекрестившись",
+    "перекрестила",
+    "перекрестилась",
+    "перекрестился",
+    "перекрестись",
+    "перекрестит",
+    "перекрестка",
+    "перекрестках",
+    "перекрестке",
+    "перекрестку",
+    "перекрестный",
+    "перекресток",
+    "перекрестясь",
+    "перекрещивались",
+    "перекрещивания",
+    "перекрещиваясь",
+    "перекрещу",
+    "перекупленном",
+    "перекусил",
+    "перелетная",
+    "перелетные",
+    "переливает",
+    "переливается",
+    "переливы",
+    "перелилась",
+    "перелистывает",
+    "перелистывай",
+    "перелистывал",
+    "перелистывать",
+    "перелистывая",
+    "переложил",
+    "переложили",
+    "переложит",
+    "перелом",
+    "перелома",
+    "переломали",
+    "переломаны",
+    "переломится",
+    "перельет",
+    "перемелется",
+    "перемен",
+    "перемена",
+    "перемене",
+    "перемени",
+    "переменившемся",
+    "переменившимся",
+    "переменил",
+    "переменила",
+    "переменилась",
+    "переменили",
+    "переменились",
+    "переменилось",
+    "переменился",
+    "переменит",
+    "перемените",
+    "переменить",
+    "перемениться",
+    "переменного",
+    "перемены",
+    "переменяете",
+    "переменят",
+    "переменятся",
+    "переменять",
+    "переменяя",
+    "перемешал",
+    "перемешались",
+    "перемешаны",
+    "переминаясь",
+    "перемолвить",
+    "перемолола",
+    "перемыть",
+    "перенес",
+    "перенесемся",
+    "перенесении",
+    "перенесено",
+    "перенесет",
+    "перенесешь",
+    "перенесла",
+    "перенесли",
+    "перенесся",
+    "перенести",
+    "перенестись",
+    "перенесть",
+    "перенесу",
+    "перенесут",
+    "переносил",
+    "переносила",
+    "переносили",
+    "переносит",
+    "переносится",
+    "переносить",
+    "переносье",
+    "переночуют",
+    "переношу",
+    "перенял",
+    "перенятым",
+    "переодеться",
+    "перепало",
+    "перепахали",
+    "перепел",
+    "перепела",
+    "перепелки",
+    "перепелов",
+    "переписал",
+    "переписана",
+    "переписать",
+    "переписка",
+    "переписывали",
+    "переписыванье",
+    "переписывать",
+    "перепишет",
+    "перепишете",
+    "переплет",
+    "переплете",
+    "переплетом",
+    "переплыть",
+    "переползанье",
+    "переползанья",
+    "переполненном",
+    "переполненный",
+    "переполнено",
+    "переполнять",
+    "переправил",
+    "переправился",
+    "переправить",
+    "переправиться",
+    "перепродает",
+    "перепродай",
+    "перепугается",
+    "перепугаешься",
+    "перепугалась",
+    "перепугались",
+    "перепугался",
+    "перепуганная",
+    "перепуганные",
+    "перепуганными",
+    "перепутает",
+    "перепутаешь",
+    "перепуталось",
+    "перепутанные",
+    "перепьются",
+    "переработалась",
+    "перерастает",
+    "перервал",
+    "перерву",
+    "перерезывала",
+    "переродило",
+    "перерождавшаяся",
+    "перерождения",
+    "перерыв",
+    "перерывая",
+    "перерыву",
+    "перерывы",
+    "переряженный",
+    "пересветов",
+    "пересекло",
+    "пересел",
+    "переселился",
+    "переселимся",
+    "переселить",
+    "переселиться",
+    "переселяемся",
+    "пересиливает",
+    "пересиливал",
+    "пересиливая",
+    "пересилил",
+    "пересилила",
+    "перескажу",
+    "пересказал",
+    "пересказать",
+    "пересказывал",
+    "пересказывала",
+    "пересказывать",
+    "перескакивали",
+    "перескакивать",
+    "перескочить",
+    "переславший",
+    "переслал",
+    "переслать",
+    "переслюнить",
+    "пересмешку",
+    "пересмотрю",
+    "пересохли",
+    "пересохло",
+    "переспорил",
+    "переспрашиваемый",
+    "переспросил",
+    "переспросила",
+    "перессорились",
+    "перестав",
+    "переставал",
+    "переставала",
+    "переставали",
+    "переставало",
+    "переставая",
+    "переставил",
+    "переставлял",
+    "перестает",
+    "перестал",
+    "перестала",
+    "перестали",
+    "перестало",
+    "перестанем",
+    "перестанемте",
+    "перестанет",
+    "перестанете",
+    "перестанешь",
+    "перестану",
+    "перестанут",
+    "перестань",
+    "перестаньте",
+    "перестать",
+    "перестаю",
+    "перестают",
+    "перестрадать",
+    "перестройки",
+    "перестройку",
+    "переступал",
+    "переступать",
+    "переступают",
+    "переступая",
+    "переступив",
+    "переступил",
+    "переступила",
+    "переступить",
+    "пересуды",
+    "пересчитаешь",
+    "пересчитал",
+    "пересчитают",
+    "пересчитывал",
+    "пересылала",
+    "пересылались",
+    "пересыпать",
+    "перетаскано",
+    "перетаскивать",
+    "перетасовка",
+    "перетащил",
+    "перетащить",
+    "переулка",
+    "переулкам",
+    "переулках",
+    "переулке",
+    "переулки",
+    "переулком",
+    "переулку",
+    "переулок",
+    "перехватила",
+    "перехватить",
+    "перехитренному",
+    "переход",
+    "перехода",
+    "переходил",
+    "переходила",
+    "переходили",
+    "переходим",
+    "переходит",
+    "переходить",
+    "переходя",
+    "переходят",
+    "переходящее",
+    "перехожу",
+    "перечислил",
+    "перечитав",
+    "перечитал",
+    "перечница",
+    "перешагнешь",
+    "перешагнул",
+    "перешагнуть",
+    "перешедшее",
+    "перешедший",
+    "перешел",
+    "перешептал",
+    "перешептываться",
+    "перешивал",
+    "перешить",
+    "перешла",
+    "перешли",
+    "перешло",
+    "перещеголял",
+    "перил",
+    "перила",
+    "перилам",
+    "перин",
+    "перине",
+    "перинное",
+    "перины",
+    "период",
+    "периодически",
+    "периодического",
+    "периодической",
+    "периодическою",
+    "периодическую",
+    "периферию",
+    "перл",
+    "перламутра",
+    "перлы",
+    "перо",
+    "пером",
+    "персидским",
+    "персидской",
+    "персидском",
+    "персики",
+    "персон",
+    "перспектива",
+    "перспективе",
+    "перспективой",
+    "перспективу",
+    "перспективы",
+    "перст",
+    "перстень",
+    "перстней",
+    "перстнем",
+    "перстнями",
+    "перстнях",
+    "перстом",
+    "персты",
+    "перу",
+    "перуджийский",
+    "перуджино",
+    "перуджия",
+    "перунами",
+    "перцу",
+    "перчатками",
+    "перчатках",
+    "перчатке",
+    "перчатки",
+    "перчатку",
+    "перчаток",
+    "першить",
+    "перышко",
+    "перьев",
+    "перья",
+    "перьями",
+    "пес",
+    "песен",
+    "песенка",
+    "песенки",
+    "песенку",
+    "песенники",
+    "песенников",
+    "песка",
+    "песках",
+    "песке",
+    "пескину",
+    "песком",
+    "песку",
+    "песне",
+    "песней",
+    "песни",
+    "песнию",
+    "песнь",
+    "песнью",
+    "песню",
+    "песня",
+    "песнями",
+    "песнях",
+    "песок",
+    "пестиком",
+    "пестрая",
+    "пестреют",
+    "пестрили",
+    "пестрит",
+    "пестрой",
+    "пестротой",
+    "пестрою",
+    "пеструшка",
+    "пестрые",
+    "пестрый",
+    "пестряков",
+    "пестрякова",
+    "пестряковым",
+    "песчаной",
+    "песчаную",
+    "песчаные",
+    "песчинка",
+    "петей",
+    "петель",
+    "петербург",
+    "петербурга",
+    "петербурге",
+    "петербургом",
+    "петербургская",
+    "петербургские",
+    "петербургский",
+    "петербургским",
+    "петербургских",
+    "петербургское",
+    "петербургской",
+    "петербургском",
+    "петербургскую",
+    "петербуржцу",
+    "петле",
+    "петлей",
+    "петли",
+    "петлицах",
+    "петлице",
+    "петлицу",
+    "петлю",
+    "петля",
+    "петр",
+    "петра",
+    "петре",
+    "петров",
+    "петрович",
+    "петровича",
+    "петровиче",
+    "петровичем",
+    "петровичу",
+    "петровки",
+    "петровна",
+    "петровне",
+    "петровной",
+    "петровну",
+    "петровны",
+    "петровский",
+    "петровского",
+    "петроградское",
+    "петроградском",
+    "петром",
+    "петру",
+    "петруша",
+    "петрушка",
+    "петрушке",
+    "петрушку",
+    "петруща",
+    "петух",
+    "петуха",
+    "петухи",
+    "петухом",
+    "петушка",
+    "петь",
+    "петя",
+    "пехота",
+    "пехоте",
+    "пехотного",
+    "печалей",
+    "печален",
+    "печали",
+    "печалились",
+    "печалить",
+    "печалиться",
+    "печаль",
+    "печальна",
+    "печальная",
+    "печально",
+    "печального",
+    "печальное",
+    "печальной",
+    "печальном",
+    "печальною",
+    "печальный",
+    "печальным",
+    "печальных",
+    "печалью",
+    "печалями",
+    "печами",
+    "печатаете",
+    "печатаетесь",
+    "печатал",
+    "печататься",
+    "печати",
+    "печатными",
+    "печатных",
+    "печать",
+    "печатью",
+    "печени",
+    "печений",
+    "печенка",
+    "печенками",
+    "печеную",
+    "печенья",
+    "печет",
+    "печи",
+    "печке",
+    "печки",
+    "печкой",
+    "печку",
+    "печь",
+    "пешкой",
+    "пешком",
+    "пешни",
+    "пещера",
+    "пещеры",
+    "пианино",
+    "пива",
+    "пивал",
+    "пивали",
+    "пиво",
+    "пивом",
+    "пивца",
+    "пивцо",
+    "пигмалионом",
+    "пидерита",
+    "пиджак",
+    "пиджаке",
+    "пиджаки",
+    "пик",
+    "пикирована",
+    "пикированная",
+    "пикированным",
+    "пикнуть",
+    "пил",
+    "пила",
+    "пили",
+    "пилит",
+    "пилот",
+    "пилюли",
+    "пилюлю",
+    "пион",
+    "пир",
+    "пира",
+    "пирамидами",
+    "пирамиду",
+    "пирамиды",
+    "пире",
+    "пиров",
+    "пирог",
+    "пирога",
+    "пироги",
+    "пирогов",
+    "пирогом",
+    "пирожка",
+    "пирожки",
+    "пирожное",
+    "пирожных",
+    "пиру",
+    "пирушках",
+    "пиры",
+    "писал",
+    "писала",
+    "писали",
+    "писало",
+    "писалось",
+    "писан",
+    "писаний",
+    "писанное",
+    "писанный",
+    "писаных",
+    "писаньем",
+    "писанья",
+    "писаньям",
+    "писарем",
+    "писаришек",
+    "писаришками",
+    "писаришки",
+    "писарь",
+    "писаря",
+    "писателей",
+    "писателем",
+    "писатели",
+    "писатель",
+    "писательницей",
+    "писательство",
+    "писателю",
+    "писателя",
+    "писателям",
+    "писателями",
+    "писать",
+    "писем",
+    "писец",
+    "писк",
+    "пискариной",
+    "пистолет",
+    "писца",
+    "писцов",
+    "писцом",
+    "писцы",
+    "писывал",
+    "письма",
+    "письмах",
+    "письме",
+    "письменно",
+    "письменном",
+    "письменному",
+    "письменный",
+    "письменным",
+    "письмецо",
+    "письмецом",
+    "письмо",
+    "письмоводителем",
+    "письмоводитель",
+    "письмоводителю",
+    "письмоводителя",
+    "письмом",
+    "письму",
+    "питает",
+    "питал",
+    "питала",
+    "питалась",
+    "питали",
+    "питание",
+    "питать",
+    "питаться",
+    "питаю",
+    "питая",
+    "питаясь",
+    "питейное",
+    "питейной",
+    "питер",
+    "питере",
+    "питером",
+    "питии",
+    "питомник",
+    "питомце",
+    "питомцы",
+    "пить",
+    "питье",
+    "питья",
+    "пиф",
+    "пишем",
+    "пишет",
+    "пишете",
+    "пишется",
+    "пишешь",
+    "пиши",
+    "пишите",
+    "пишу",
+    "пишут",
+    "пишутся",
+    "пишущим",
+    "пища",
+    "пищали",
+    "пищат",
+    "пище",
+    "пищи",
+    "пищик",
+    "пищика",
+    "пищиков",
+    "пищиком",
+    "пищику",
+    "пищу",
+    "пиявки",
+    "пла",
+    "плавает",
+    "плаванье",
+    "плавать",
+    "плавающих",
+    "плавит",
+    "плавно",
+    "плавное",
+    "плавный",
+    "плакавшие",
+    "плакавших",
+    "плакавшую",
+    "плакал",
+    "плакала",
+    "плакали",
+    "плакались",
+    "плакало",
+    "плакаль",
+    "плакать",
+    "плакиды",
+    "плаксиво",
+    "плакун",
+    "пламенем",
+    "пламени",
+    "пламенная",
+    "пламенной",
+    "пламенном",
+    "пламенные",
+    "пламень",
+    "пламя",
+    "план",
+    "плана",
+    "планами",
+    "планах",
+    "плане",
+    "планет",
+    "планета",
+    "планетах",
+    "планете",
+    "планету",
+    "планеты",
+    "планом",
+    "планомерно",
+    "плантатору",
+    "плану",
+    "планы",
+    "пластинка",
+    "пластырями",
+    "плат",
+    "платеже",
+    "плати",
+    "платил",
+    "платили",
+    "платиль",
+    "платим",
+    "платит",
+    "платите",
+    "платить",
+    "платишь",
+    "платка",
+    "платками",
+    "платке",
+    "платки",
+    "платком",
+    "платку",
+    "платок",
+    "платона",
+    "платочек",
+    "платочком",
+    "платою",
+    "плату",
+    "платформу",
+    "платье",
+    "платьев",
+    "платьем",
+    "платьица",
+    "платьице",
+    "платьишке",
+    "платьишки",
+    "платью",
+    "платья",
+    "платьями",
+    "платьях",
+    "платя",
+    "платят",
+    "плач",
+    "плача",
+    "плаче",
+    "плачевный",
+    "плачевным",
+    "плачем",
+    "плачет",
+    "плачете",
+    "плачешь",
+    "плачу",
+    "плачут",
+    "плачущее",
+    "плачущим",
+    "плачущих",
+    "плачущую",
+    "плачь",
+    "плачьте",
+    "плащ",
+    "плаща",
+    "плащах",
+    "плаще",
+    "плащом",
+    "пле",
+    "плевал",
+    "плеванье",
+    "плевны",
+    "плевок",
+    "плед",
+    "пледом",
+    "плелся",
+    "племени",
+    "племянника",
+    "племянниками",
+    "племянница",
+    "плен",
+    "пленительна",
+    "пленительные",
+    "пленная",
+    "пленник",
+    "пленной",
+    "плену",
+    "пленя",
+    "пленял",
+    "пленялись",
+    "пленяло",
+    "пленять",
+    "плеск",
+    "плесканье",
+    "плесну",
+    "плеснул",
+    "плеснула",
+    "плеснули",
+    "плести",
+    "плетеных",
+    "плетень",
+    "плетет",
+    "плети",
+    "плетнем",
+    "плетни",
+    "плетня",
+    "плетут",
+    "плеч",
+    "плеча",
+    "плечам",
+    "плечами",
+    "плечах",
+    "плече",
+    "плечи",
+    "плечики",
+    "плечо",
+    "плечом",
+    "плечу",
+    "плешивым",
+    "плещет",
+    "плибьет",
+    "плисовых",
+    "плита",
+    "плитам",
+    "плитами",
+    "плитах",
+    "плите",
+    "плиты",
+    "плод",
+    "плодами",
+    "плодить",
+    "плодов",
+    "плодотворная",
+    "плодотворной",
+    "плоды",
+    "плоские",
+    "плоско",
+    "плоти",
+    "плотин",
+    "плотине",
+    "плотнее",
+    "плотник",
+    "плотника",
+    "плотно",
+    "плотного",
+    "плотное",
+    "плотной",
+    "плотный",
+    "плотных",
+    "плоты",
+    "плоть",
+    "плох",
+    "плоха",
+    "плохая",
+    "плохие",
+    "плохих",
+    "плохо",
+    "плохонький",
+    "плохую",
+    "плоше",
+    "площади",
+    "площадка",
+    "площадке",
+    "площадки",
+    "площадная",
+    "площадной",
+    "площадь",
+    "площадям",
+    "площадях",
+    "плугом",
+    "плут",
+    "плутоват",
+    "плутоватою",
+    "плутовка",
+    "плутовская",
+    "плутовски",
+    "плутовскою",
+    "плутовства",
+    "плуты",
+    "плывет",
+    "плыви",
+    "плыл",
+    "плыла",
+    "плыло",
+    "плыть",
+    "плэд",
+    "плюгавенький",
+    "плюй",
+    "плюнет",
+    "плюну",
+    "плюнул",
+    "плюнуть",
+    "плюнь",
+    "плюс",
+    "плюх",
+    "плющами",
+    "плющей",
+    "плющом",
+    "плюю",
+    "плюют",
+    "пляс",
+    "плясала",
+    "плясать",
+    "пляске",
+    "пляски",
+    "плясуна",
+    "плясунья",
+    "пляшет",
+    "пляши",
+    "пляшут",
+    "пни",
+    "по",
+    "побагровел",
+    "побаивается",
+    "побаивались",
+    "побег",
+    "побегом",
+    "побегу",
+    "побегут",
+    "побегушках",
+    "победа",
+    "победам",
+    "победами",
+    "победе",
+    "победил",
+    "победила",
+    "победителем",
+    "победителя",
+    "победить",
+    "победно",
+    "победный",
+    "победой",
+    "победоносцев",
+    "победоносцевым",
+    "победу",
+    "победы",
+    "побежал",
+    "побежала",
+    "побежали",
+    "побежало",
+    "побеждает",
+    "побеждал",
+    "побеждать",
+    "побеждающею",
+    "побеждая",
+    "побежден",
+    "побежденный",
+    "побеждены",
+    "побежит",
+    "побелевшею",
+    "побелевшие",
+    "побелел",
+    "побереги",
+    "поберегите",
+    "поберегся",
+    "поберечь",
+    "поберечься",
+    "побеспокоили",
+    "побеспокоить",
+    "побивали",
+    "побили",
+    "побираться",
+    "поблагодари",
+    "поблагодарил",
+    "поблагодарила",
+    "поблагодарить",
+    "поблагодарю",
+    "побледнев",
+    "побледневшая",
+    "побледневшими",
+    "побледнеет",
+    "побледнел",
+    "побледнела",
+    "побледнели",
+    "побледнело",
+    "побледнеют",
+    "поблек",
+    "поблекла",
+    "поблекли",
+    "поблекло",
+    "поблеклые",
+    "поблекнет",
+    "поблекшей",
+    "поблескивает",
+    "поближе",
+    "поблизости",
+    "пободрее",
+    "побоев",
+    "побожился",
+    "побожиться",
+    "побои",
+    "побоится",
+    "побой",
+    "побоку",
+    "поболее",
+    "поболтать",
+    "побольше",
+    "поборемся",
+    "поборол",
+    "поборюсь",
+    "побрал",
+    "побранят",
+    "побрезгуй",
+    "побрился",
+    "побродив",
+    "побросать",
+    "побудившие",
+    "побудила",
+    "побудили",
+    "побудило",
+    "побуду",
+    "побудь",
+    "побудьте",
+    "побуждали",
+    "побуждение",
+    "побывали",
+    "побывать",
+    "побываю",
+    "побыл",
+    "побыстрей",
+    "побыть",
+    "побьет",
+    "повадился",
+    "повадки",
+    "повадятся",
+    "поважнее",
+    "повалил",
+    "повалилась",
+    "повалило",
+    "повалилось",
+    "повалился",
+    "повалится",
+    "повалиться",
+    "повальной",
+    "поваляться",
+    "повар",
+    "повара",
+    "поварам",
+    "поваров",
+    "поваром",
+    "повару",
+    "поведать",
+    "поведение",
+    "поведении",
+    "поведению",
+    "поведения",
+    "поведет",
+    "поведешь",
+    "поведу",
+    "поведут",
+    "повез",
+    "повезет",
+    "повезли",
+    "повезут",
+    "повел",
+    "повела",
+    "повелевала",
+    "повелел",
+    "повеление",
+    "повелению",
+    "повелено",
+    "повели",
+    "повелитель",
+    "повелительно",
+    "повелительным",
+    "повело",
+    "повелось",
+    "повергает",
+    "повергался",
+    "поверенная",
+    "поверенного",
+    "поверенному",
+    "поверенный",
+    "поверенным",
+    "поверженный",
+    "поверженным",
+    "поверив",
+    "поверил",
+    "поверила",
+    "поверили",
+    "поверим",
+    "поверит",
+    "поверите",
+    "поверить",
+    "поверишь",
+    "поверка",
+    "поверкою",
+    "повернись",
+    "поверну",
+    "повернувшийся",
+    "повернувшись",
+    "повернул",
+    "повернула",
+    "повернулась",
+    "повернули",
+    "повернулся",
+    "повернуть",
+    "повернуться",
+    "поверстный",
+    "повертел",
+    "повертеться",
+    "повертит",
+    "повертывая",
+    "поверх",
+    "поверхностно",
+    "поверхностным",
+    "поверхность",
+    "поверь",
+    "поверьте",
+    "поверю",
+    "поверяет",
+    "поверял",
+    "поверяла",
+    "поверят",
+    "поверять",
+    "повеселевшим",
+    "повеселее",
+    "повеселеет",
+    "повеселел",
+    "повеселела",
+    "повеселели",
+    "повеселело",
+    "повеселись",
+    "повеселиться",
+    "повеселясь",
+    "повесив",
+    "повесившиеся",
+    "повесил",
+    "повесить",
+    "повеситься",
+    "повествовала",
+    "повествований",
+    "повествовать",
+    "повествует",
+    "повести",
+    "повестка",
+    "повестке",
+    "повестки",
+    "повестку",
+    "повесть",
+    "повестям",
+    "повестях",
+    "повесьте",
+    "повеся",
+    "повечеру",
+    "повешенные",
+    "повеял",
+    "повеяло",
+    "повивальными",
+    "повивальных",
+    "повидавшись",
+    "повидать",
+    "повидаться",
+    "повилика",
+    "повинился",
+    "повинною",
+    "повинных",
+    "повиновались",
+    "повиновался",
+    "повинуйся",
+    "повинуются",
+    "повинуясь",
+    "повинюсь",
+    "повис",
+    "повисали",
+    "повисла",
+    "повисли",
+    "повиснет",
+    "повисшей",
+    "повисшими",
+    "повита",
+    "повито",
+    "повихнулся",
+    "повлекли",
+    "повлекло",
+    "повлияло",
+    "повнимательнее",
+    "повод",
+    "повода",
+    "поводил",
+    "поводом",
+    "поводу",
+    "поводя",
+    "поворачивает",
+    "поворачивается",
+    "поворачивайся",
+    "поворачивался",
+    "поворачиваю",
+    "поворачивая",
+    "поворачиваясь",
+    "поворот",
+    "поворота",
+    "повороте",
+    "поворотил",
+    "поворотили",
+    "поворотился",
+    "поворотить",
+    "поворотом",
+    "поворчав",
+    "повострее",
+    "повредил",
+    "повредит",
+    "повредить",
+    "повредиться",
+    "повсеместно",
+    "повсеместных",
+    "повстречался",
+    "повстречаться",
+    "повсюду",
+    "повторение",
+    "повторения",
+    "повторенья",
+    "повтори",
+    "повторившееся",
+    "повторил",
+    "повторила",
+    "повторили",
+    "повторило",
+    "повторился",
+    "повторим",
+    "повторит",
+    "повторится",
+    "повторить",
+    "повторю",
+    "повторяет",
+    "повторяйте",
+    "повторял",
+    "повторяла",
+    "повторялись",
+    "повторялось",
+    "повторялся",
+    "повторять",
+    "повторяться",
+    "повторяю",
+    "повторяют",
+    "повторяются",
+    "повторяя",
+    "повыскочит",
+    "повысосет",
+    "повыше",
+    "повышение",
+    "повяжется",
+    "повязан",
+    "повязаны",
+    "повязки",
+    "повязкой",
+    "повязку",
+    "поганая",
+    "поганую",
+    "погас",
+    "погасал",
+    "погасали",
+    "погасания",
+    "погаси",
+    "погасил",
+    "погасит",
+    "погасла",
+    "погасли",
+    "погасло",
+    "погасшие",
+    "погиб",
+    "погибает",
+    "погибайте",
+    "погибал",
+    "погибало",
+    "погибают",
+    "погибающий",
+    "погибели",
+    "погибельные",
+    "погибелью",
+    "погибла",
+    "погибли",
+    "погибло",
+    "погибнет",
+    "погибну",
+    "погибнуть",
+    "погибший",
+    "погибшим",
+    "погибшими",
+    "погладил",
+    "поглотила",
+    "поглотит",
+    "поглощаются",
+    "поглощен",
+    "поглощенные",
+    "поглубже",
+    "поглумился",
+    "поглупее",
+    "поглупел",
+    "поглупеть",
+    "поглядев",
+    "поглядел",
+    "поглядела",
+    "поглядели",
+    "поглядело",
+    "поглядеть",
+    "погляди",
+    "поглядим",
+    "поглядит",
+    "поглядите",
+    "поглядишь",
+    "поглядывает",
+    "поглядывал",
+    "поглядывала",
+    "поглядывали",
+    "поглядывая",
+    "погляжу",
+    "погнал",
+    "погнался",
+    "погнушались",
+    "поговаривали",
+    "поговаривать",
+    "поговаривают",
+    "поговори",
+    "поговорила",
+    "поговорили",
+    "поговорим",
+    "поговорит",
+    "поговорить",
+    "поговоришь",
+    "поговорю",
+    "погогочут",
+    "погода",
+    "погоде",
+    "погоди",
+    "погодили",
+    "погодите",
+    "погодой",
+    "погоду",
+    "погоды",
+    "погодя",
+    "погожу",
+    "погони",
+    "погонит",
+    "погоняя",
+    "погордиться",
+    "погорел",
+    "погорельцам",
+    "погорельцев",
+    "погорячатся",
+    "погорячился",
+    "погорячится",
+    "погостить",
+    "погреб",
+    "погреба",
+    "погребаете",
+    "погребался",
+    "погребальный",
+    "погребальными",
+    "погребе",
+    "погребена",
+    "погребла",
+    "погребло",
+    "погрозив",
+    "погрозил",
+    "погрозила",
+    "погрознее",
+    "погромче",
+    "погружается",
+    "погружалась",
+    "погружались",
+    "погружался",
+    "погружаюсь",
+    "погружаясь",
+    "погружен",
+    "погружена",
+    "погруженная",
+    "погруженный",
+    "погрузил",
+    "погрузилась",
+    "погрузились",
+    "погрузилось",
+    "погрузился",
+    "погрузит",
+    "погрузится",
+    "погрузиться",
+    "погрузишь",
+    "погрузясь",
+    "погрустив",
+    "погрустим",
+    "погубил",
+    "погубило",
+    "погубит",
+    "погубить",
+    "погублю",
+    "погуляет",
+    "погуляй",
+    "погуляйте",
+    "погулял",
+    "погулять",
+    "погуще",
+    "под",
+    "подавай",
+    "подавал",
+    "подавала",
+    "подавали",
+    "подавался",
+    "подавать",
+    "подавая",
+    "подавила",
+    "подавить",
+    "подавленной",
+    "подавленный",
+    "подавленным",
+    "подавленными",
+    "подавлю",
+    "подавлять",
+    "подавляются",
+    "подавляя",
+    "подавно",
+    "подагра",
+    "подагрика",
+    "подагрой",
+    "подагру",
+    "подагры",
+    "подадим",
+    "подадите",
+    "подадут",
+    "подает",
+    "подаешь",
+    "подай",
+    "подайте",
+    "подал",
+    "подала",
+    "подалась",
+    "подали",
+    "подались",
+    "подальше",
+    "подам",
+    "подан",
+    "поданный",
+    "подано",
+    "поданы",
+    "подаренное",
+    "подаренный",
+    "подари",
+    "подарил",
+    "подарила",
+    "подарили",
+    "подарите",
+    "подарить",
+    "подарка",
+    "подарки",
+    "подарков",
+    "подарок",
+    "подарю",
+    "подаст",
+    "подати",
+    "подать",
+    "подаче",
+    "подачки",
+    "подашь",
+    "подаю",
+    "подают",
+    "подающий",
+    "подающим",
+    "подаяние",
+    "подбегая",
+    "подбегут",
+    "подбежал",
+    "подбежала",
+    "подбежали",
+    "подберу",
+    "подбивался",
+    "подбирает",
+    "подбирала",
+    "подбирался",
+    "подбираю",
+    "подбирающийся",
+    "подбирая",
+    "подбитые",
+    "подбитыми",
+    "подбородка",
+    "подбородком",
+    "подбородок",
+    "подбоченясь",
+    "подбросить",
+    "подвал",
+    "подвалы",
+    "подвальной",
+    "подвальные",
+    "подвальный",
+    "подведет",
+    "подведешь",
+    "подвел",
+    "подвела",
+    "подвели",
+    "подвергаемся",
+    "подвергается",
+    "подвергалось",
+    "подвергать",
+    "подвергаться",
+    "подвергаются",
+    "подвергнуться",
+    "подвернись",
+    "подвернувшемуся",
+    "подвернулась",
+    "подвернулся",
+    "подвести",
+    "подвиг",
+    "подвига",
+    "подвигается",
+    "подвигалась",
+    "подвигалось",
+    "подвигался",
+    "подвигах",
+    "подвигая",
+    "подвигаясь",
+    "подвиги",
+    "подвигла",
+    "подвигов",
+    "подвигом",
+    "подвижника",
+    "подвижною",
+    "подвинет",
+    "подвинув",
+    "подвинул",
+    "подвинулась",
+    "подвинулись",
+    "подвинулось",
+    "подвинулся",
+    "подвинуться",
+    "подвластной",
+    "подвода",
+    "подводах",
+    "подводил",
+    "подводит",
+    "подводить",
+    "подводя",
+    "подвожу",
+    "подворотню",
+    "подвох",
+    "подвязанными",
+    "подгадил",
+    "подгадить",
+    "подгибались",
+    "подгибаться",
+    "подгибая",
+    "подглядели",
+    "подглядывавшая",
+    "подгоняют",
+    "подгорелое",
+    "подгорит",
+    "подгорюнившись",
+    "подготовки",
+    "подготовленного",
+    "подготовлено",
+    "подготовлюсь",
+    "подготовляет",
+    "подготовлялось",
+    "подгреб",
+    "подгулял",
+    "поддавалась",
+    "поддавался",
+    "поддадим",
+    "поддадитесь",
+    "поддаешься",
+    "поддакивал",
+    "поддакивала",
+    "поддакивали",
+    "поддакивают",
+    "поддакивая",
+    "поддакнул",
+    "поддалась",
+    "поддали",
+    "поддало",
+    "поддамся",
+    "поддаст",
+    "поддаться",
+    "поддеваете",
+    "поддевке",
+    "поддел",
+    "подделаться",
+    "подделаю",
+    "подделывал",
+    "подделывали",
+    "подделывателей",
+    "поддержания",
+    "поддержать",
+    "поддержи",
+    "поддерживаемую",
+    "поддерживает",
+    "поддерживал",
+    "поддерживала",
+    "поддерживалась",
+    "поддерживало",
+    "поддерживать",
+    "поддерживая",
+    "поддержите",
+    "поддержки",
+    "поддразнивает",
+    "поддразнивал",
+    "поддразнивающие",
+    "подействовал",
+    "подействовала",
+    "подействовали",
+    "подействовало",
+    "подействовать",
+    "подействует",
+    "поделаешь",
+    "поделится",
+    "поделиться",
+    "поделом",
+    "подержанная",
+    "подери",
+    "подернулась",
+    "подернулось",
+    "подернут",
+    "подернутое",
+    "подернутый",
+    "подернутым",
+    "подерутся",
+    "подешевле",
+    "поджав",
+    "поджатым",
+    "поджег",
+    "поджигал",
+    "поджигающее",
+    "поджидал",
+    "поджидала",
+    "поджидали",
+    "поджидаю",
+    "поджимают",
+    "поджимая",
+    "подзадоривая",
+    "подзатыльника",
+    "подземелье",
+    "подземной",
+    "подземный",
+    "подземных",
+    "подзывал",
+    "подзывали",
+    "поди",
+    "подивился",
+    "подивитесь",
+    "подите",
+    "подкараулил",
+    "подкараульте",
+    "подкатил",
+    "подкинуть",
+    "подкипятило",
+    "подкладке",
+    "подкладкой",
+    "подкладку",
+    "подкладывал",
+    "подкладывала",
+    "подкладывая",
+    "подков",
+    "подколенки",
+    "подколодная",
+    "подкосились",
+    "подкрадется",
+    "подкрадутся",
+    "подкрепил",
+    "подкреплена",
+    "подкрепляющим",
+    "подкупишь",
+    "подлаживаясь",
+    "подлая",
+    "подле",
+    "подлее",
+    "подлейшего",
+    "подлейший",
+    "подлейшими",
+    "подлейших",
+    "подленькая",
+    "подлец",
+    "подлеца",
+    "подлецов",
+    "подлецом",
+    "подлецу",
+    "подлецы",
+    "подливку",
+    "подлил",
+    "подлиннее",
+    "подлинно",
+    "подличают",
+    "подло",
+    "подлого",
+    "подлое",
+    "подложил",
+    "подлой",
+    "подлости",
+    "подлость",
+    "подлые",
+    "подлый",
+    "подлым",
+    "подлых",
+    "подманить",
+    "подмахнет",
+    "подмахни",
+    "подмахнул",
+    "подмести",
+    "подметено",
+    "подметишь",
+    "подметки",
+    "подметок",
+    "подмигивать",
+    "подмигивающего",
+    "подмигивающий",
+    "подмигивая",
+    "подмигнув",
+    "подмигнул",
+    "подмонтироваться",
+    "подмывает",
+    "подмывало",
+    "подмышки",
+    "подмышкой",
+    "поднадул",
+    "поднадуть",
+    "поднес",
+    "поднести",
+    "поднеся",
+    "поднимавшийся",
+    "поднимает",
+    "поднимается",
+    "поднимай",
+    "поднимал",
+    "поднимала",
+    "поднималась",
+    "поднимали",
+    "поднимались",
+    "поднималось",
+    "поднимался",
+    "поднимать",
+    "подниматься",
+    "поднимаю",
+    "поднимают",
+    "поднимающихся",
+    "поднимая",
+    "поднимаясь",
+    "поднимем",
+    "поднимет",
+    "подними",
+    "поднимут",
+    "подноготную",
+    "поднос",
+    "подноса",
+    "подносе",
+    "подносом",
+    "подносы",
+    "подносят",
+    "подняв",
+    "поднявшаяся",
+    "поднявши",
+    "поднявшись",
+    "поднявшую",
+    "поднял",
+    "подняла",
+    "поднялась",
+    "подняли",
+    "поднялись",
+    "поднялось",
+    "поднялся",
+    "поднят",
+    "поднятым",
+    "поднять",
+    "подняться",
+    "подо",
+    "подобен",
+    "подобие",
+    "подобно",
+    "подобного",
+    "подобное",
+    "подобной",
+    "подобном",
+    "подобную",
+    "подобные",
+    "подобный",
+    "подобным",
+    "подобных",
+    "подобострастия",
+    "подобострастничал",
+    "подобострастно",
+    "подобрав",
+    "подобрал",
+    "подобрала",
+    "подобрали",
+    "подобрался",
+    "подобраны",
+    "подобру",
+    "подогнутых",
+    "подогревалась",
+    "подогреть",
+    "пододвинул",
+    "пододвинула",
+    "подожгла",
+    "подождав",
+    "подождал",
+    "подождать",
+    "подождем",
+    "подождет",
+    "подождешь",
+    "подожди",
+    "подождите",
+    "подожду",
+    "подожму",
+    "подозвала",
+    "подозревает",
+    "подозреваете",
+    "подозревай",
+    "подозревал",
+    "подозревала",
+    "подозревали",
+    "подозревать",
+    "подозреваю",
+    "подозревают",
+    "подозревающего",
+    "подозревая",
+    "подозрение",
+    "подозрением",
+    "подозрении",
+    "подозрений",
+    "подозрению",
+    "подозрения",
+    "подозрениях",
+    "подозрителен",
+    "подозрительна",
+    "подозрительно",
+    "подозрительного",
+    "подозрительное",
+    "подозрительным",
+    "подозрительными",
+    "подозрительных",
+    "подойдет",
+    "подойдешь",
+    "подойди",
+    "подойду",
+    "подойдя",
+    "подойти",
+    "подоконник",
+    "подоконниках",
+    "подол",
+    "подолгу",
+    "подолом",
+    "подольстилась",
+    "подольщаюсь",
+    "подонки",
+    "подонкихотствовать",
+    "подопрет",
+    "подосадовал",
+    "подослал",
+    "подоспеет",
+    "подоспела",
+    "подоткнул",
+    "подох",
+    "подошвы",
+    "подошедшего",
+    "подошедшею",
+    "подошедший",
+    "подошел",
+    "подошла",
+    "подошли",
+    "подошло",
+    "подошлю",
+    "подпевает",
+    "подпевал",
+    "подпевать",
+    "подпер",
+    "подпереть",
+    "подпившая",
+    "подпирал",
+    "подпираясь",
+    "подписал",
+    "подписала",
+    "подписан",
+    "подписана",
+    "подписано",
+    "подписать",
+    "подписи",
+    "подписка",
+    "подписки",
+    "подписку",
+    "подписывает",
+    "подписывал",
+    "подписывалась",
+    "подписывались",
+    "подписывать",
+    "подписывают",
+    "подписывая",
+    "подпись",
+    "подписью",
+    "подпишет",
+    "подпишете",
+    "подпишешь",
+    "подпишитесь",
+    "подпишу",
+    "подползает",
+    "подползи",
+    "подползла",
+    "подполковник",
+    "подпольи",
+    "подпоручик",
+    "подпруга",
+    "подпрыгивал",
+    "подпрыгивать",
+    "подпрыгивая",
+    "подпрыгнула",
+    "подпускать",
+    "подрагивали",
+    "подражать",
+    "подразделений",
+    "подразделения",
+    "подразнить",
+    "подразумевать",
+    "подрал",
+    "подрались",
+    "подрался",
+    "подрастают",
+    "подрезаны",
+    "подрезать",
+    "подробная",
+    "подробнее",
+    "подробничать",
+    "подробно",
+    "подробностей",
+    "подробности",
+    "подробность",
+    "подробностью",
+    "подробностями",
+    "подробностях",
+    "подробную",
+    "подробные",
+    "подробный",
+    "подрос",
+    "подросли",
+    "подруг",
+    "подруга",
+    "подругам",
+    "подруге",
+    "подруги",
+    "подругою",
+    "подругу",
+    "подружились",
+    "подружится",
+    "подряд",
+    "подрядами",
+    "подряде",
+    "подрядчик",
+    "подрядчиками",
+    "подрядчиков",
+    "подряжают",
+    "подсвечник",
+    "подсвечнике",
+    "подсвистывает",
+    "подсекают",
+    "подсекли",
+    "подсекло",
+    "подсел",
+    "подскажи",
+    "подсказанное",
+    "подсказывает",
+    "подсказывала",
+    "подсказывать",
+    "подсказывая",
+    "подскакивала",
+    "подскочил",
+    "подскочит",
+    "подслеповатыми",
+    "подслепые",
+    "подслуживается",
+    "подслушал",
+    "подслушала",
+    "подслушанные",
+    "подслушивай",
+    "подслушивайте",
+    "подслушивал",
+    "подслушивали",
+    "подслушивать",
+    "подсматривать",
+    "подсмеиваешься",
+    "подсмотреть",
+    "подсобить",
+    "подсочиненные",
+    "подставил",
+    "подставит",
+    "подставить",
+    "подставляет",
+    "подставлять",
+    "подставляя",
+    "подстерегает",
+    "подстерегают",
+    "подстерегающую",
+    "подстеречь",
+    "подстилая",
+    "подстрекательные",
+    "подстреленный",
+    "подстрелили",
+    "подстрелю",
+    "подстригали",
+    "подступившего",
+    "подступило",
+    "подсудимой",
+    "подсудимому",
+    "подсудимый",
+    "подсудимых",
+    "подсунул",
+    "подсунули",
+    "подталкивал",
+    "подталкивать",
+    "подтвердил",
+    "подтвердила",
+    "подтвердите",
+    "подтвердить",
+    "подтверждает",
+    "подтверждаешь",
+    "подтверждал",
+    "подтверждала",
+    "подтверждало",
+    "подтверждалось",
+    "подтверждать",
+    "подтверждаю",
+    "подтверждением",
+    "подтвержденное",
+    "подтвержу",
+    "подтибрил",
+    "подтыканной",
+    "подтянуть",
+    "подув",
+    "подувания",
+    "подувая",
+    "подул",
+    "подумав",
+    "подумаем",
+    "подумает",
+    "подумаете",
+    "подумаешь",
+    "подумай",
+    "подумайте",
+    "подумал",
+    "подумала",
+    "подумали",
+    "подумалось",
+    "подумать",
+    "подумаю",
+    "подумают",
+    "подумывает",
+    "подумывал",
+    "подурнела",
+    "подурнели",
+    "подустроить",
+    "подушек",
+    "подушечку",
+    "подушка",
+    "подушке",
+    "подушки",
+    "подушкой",
+    "подушку",
+    "подхватил",
+    "подхватила",
+    "подхватили",
+    "подхватило",
+    "подхватим",
+    "подхватит",
+    "подхватить",
+    "подхватывает",
+    "подхватывают",
+    "подхватывая",
+    "подхлопывать",
+    "подходи",
+    "подходившие",
+    "подходил",
+    "подходили",
+    "подходило",
+    "подходим",
+    "подходит",
+    "подходите",
+    "подходить",
+    "подходной",
+    "подходя",
+    "подходят",
+    "подходящего",
+    "подходящее",
+    "подходящей",
+    "подхожу",
+    "подчас",
+    "подчеркнула",
+    "подчиненного",
+    "подчиненные",
+    "подчиненных",
+    "подчинила",
+    "подчинились",
+    "подчинить",
+    "подчиниться",
+    "подчинялась",
+    "подшиванье",
+    "подшивать",
+    "подшучивают",
+    "подъезда",
+    "подъезжает",
+    "подъезжают",
+    "подъем",
+    "подъеме",
+    "подъемлют",
+    "подъемный",
+    "подъехал",
+    "подымавшеюся",
+    "подымавшийся",
+    "подымает",
+    "подымайся",
+    "подымал",
+    "подымалась",
+    "подыманием",
+    "подымать",
+    "подыматься",
+    "подымая",
+    "подымаясь",
+    "подымусь",
+    "подыскивает",
+    "подышать",
+    "подышит",
+    "подь",
+    "подьяческой",
+    "подьячий",
+    "подьячим",
+    "подьячих",
+    "поедем",
+    "поедемте",
+    "поедет",
+    "поедете",
+    "поедешь",
+    "поединке",
+    "поединку",
+    "поеду",
+    "поедут",
+    "поедят",
+    "поезд",
+    "поезда",
+    "поезде",
+    "поездка",
+    "поездке",
+    "поездки",
+    "поездку",
+    "поездом",
+    "поезду",
+    "поезжай",
+    "поезжайте",
+    "поела",
+    "поем",
+    "поест",
+    "поесть",
+    "поет",
+    "поете",
+    "поется",
+    "поехал",
+    "поехала",
+    "поехали",
+    "поехать",
+    "поешь",
+    "пожав",
+    "пожал",
+    "пожала",
+    "пожалев",
+    "пожалеет",
+    "пожалеешь",
+    "пожалей",
+    "пожалейте",
+    "пожалел",
+    "пожалела",
+    "пожалели",
+    "пожали",
+    "пожаловал",
+    "пожаловали",
+    "пожаловался",
+    "пожаловать",
+    "пожаловаться",
+    "пожалуй",
+    "пожалуйста",
+    "пожалуйства",
+    "пожалуйте",
+    "пожар",
+    "пожара",
+    "пожарах",
+    "пожаре",
+    "пожарищ",
+    "пожарная",
+    "пожарные",
+    "пожаров",
+    "пожаром",
+    "пожары",
+    "пожатие",
+    "пожатия",
+    "пожать",
+    "пожелав",
+    "пожелавшим",
+    "пожелаете",
+    "пожелал",
+    "пожелали",
+    "пожеланий",
+    "пожелать",
+    "пожелаю",
+    "пожелают",
+    "пожелтевшую",
+    "пожелтелая",
+    "пожелтели",
+    "пожертвовал",
+    "пожертвовала",
+    "пожертвованием",
+    "пожертвовать",
+    "пожертвует",
+    "пожертвуете",
+    "пожертвуйте",
+    "поживаете",
+    "поживаешь",
+    "поживее",
+    "поживем",
+    "поживешь",
+    "пожилая",
+    "пожили",
+    "пожилой",
+    "пожилые",
+    "пожилыми",
+    "пожилых",
+    "пожимает",
+    "пожимал",
+    "пожимала",
+    "пожимая",
+    "пожинает",
+    "пожирал",
+    "пожирали",
+    "пожирающая",
+    "пожирая",
+    "пожить",
+    "пожмем",
+    "пожмет",
+    "позабавится",
+    "позаботится",
+    "позаботься",
+    "позабыв",
+    "позабыл",
+    "позабытый",
+    "позавидовали",
+    "позавидовать",
+    "позавтракал",
+    "позавтракали",
+    "позавтракать",
+    "позавтракаю",
+    "позавчера",
+    "позади",
+    "позаимствоваться",
+    "позаняться",
+    "позах",
+    "позвал",
+    "позвала",
+    "позвали",
+    "позваль",
+    "позвано",
+    "позвать",
+    "позволением",
+    "позволения",
+    "позволено",
+    "позволил",
+    "позволила",
+    "позволили",
+    "позволит",
+    "позволите",
+    "позволительна",
+    "позволительная",
+    "позволительно",
+    "позволить",
+    "позволь",
+    "позвольте",
+    "позволю",
+    "позволяет",
+    "позволяешь",
+    "позволял",
+    "позволят",
+    "позволять",
+    "позволяю",
+    "позволяют",
+    "позволяющей",
+    "позвонил",
+    "позвонила",
+    "позвякивают",
+    "позднее",
+    "поздней",
+    "позднейшее",
+    "позднейшей",
+    "позднейшие",
+    "поздний",
+    "поздно",
+    "поздняя",
+    "поздоровавшись",
+    "поздороваемся",
+    "поздоровался",
+    "поздороваться",
+    "поздоровее",
+    "поздорову",
+    "поздравил",
+    "поздравить",
+    "поздравлений",
+    "поздравляем",
+    "поздравляли",
+    "поздравлять",
+    "поздравляю",
+    "поздравляют",
+    "поздравь",
+    "позе",
+    "позевывая",
+    "позже",
+    "позицию",
+    "позлить",
+    "познав",
+    "познавала",
+    "познавший",
+    "познаем",
+    "познай",
+    "познакомил",
+    "познакомились",
+    "познакомился",
+    "познакомить",
+    "познакомиться",
+    "познакомлю",
+    "познакомясь",
+    "познал",
+    "познала",
+    "познании",
+    "познания",
+    "познаниях",
+    "позначительнее",
+    "позовет",
+    "позови",
+    "позову",
+    "позовут",
+    "позолота",
+    "позолотой",
+    "позор",
+    "позора",
+    "позорище",
+    "позорно",
+    "позорное",
+    "позорном",
+    "позорным",
+    "позором",
+    "позу",
+    "позы",
+    "позыв",
+    "позывов",
+    "позьми",
+    "пои",
+    "поиграть",
+    "поил",
+    "поила",
+    "поименно",
+    "поискав",
+    "поискал",
+    "поискать",
+    "поиски",
+    "поистине",
+    "поит",
+    "поить",
+    "поищи",
+    "поищите",
+    "поищу",
+    "пой",
+    "пойдем",
+    "пойдемте",
+    "пойдет",
+    "пойдете",
+    "пойдешь",
+    "пойду",
+    "пойдут",
+    "поймав",
+    "поймает",
+    "поймаешь",
+    "поймайте",
+    "поймал",
+    "поймала",
+    "поймали",
+    "поймана",
+    "пойманной",
+    "пойманным",
+    "поймать",
+    "поймают",
+    "поймем",
+    "поймет",
+    "поймете",
+    "поймешь",
+    "пойми",
+    "поймите",
+    "пойму",
+    "поймут",
+    "пойти",
+    "пока",
+    "покажет",
+    "покажете",
+    "покажется",
+    "покажи",
+    "покажите",
+    "покажу",
+    "покажут",
+    "покажутся",
+    "показав",
+    "показал",
+    "показала",
+    "показалась",
+    "показали",
+    "показались",
+    "показалось",
+    "показался",
+    "показание",
+    "показанием",
+    "показании",
+    "показания",
+    "показаниями",
+    "показанная",
+    "показать",
+    "показаться",
+    "показывает",
+    "показываете",
+    "показывается",
+    "показывай",
+    "показывайся",
+    "показывайте",
+    "показывал",
+    "показывала",
+    "показывалась",
+    "показывали",
+    "показывало",
+    "показывался",
+    "показывать",
+    "показываю",
+    "показывают",
+    "показываются",
+    "показывая",
+    "покамест",
+    "покатаемся",
+    "покатает",
+    "покататься",
+    "покатилась",
+    "покатились",
+    "покатился",
+    "покатится",
+    "покатости",
+    "покатываться",
+    "покатых",
+    "покачав",
+    "покачает",
+    "покачал",
+    "покачала",
+    "покачивай",
+    "покачивая",
+    "покачнулись",
+    "покачнулся",
+    "покаюсь",
+    "покаяния",
+    "покаяньи",
+    "покиванием",
+    "покидает",
+    "покидаете",
+    "покидал",
+    "покидала",
+    "покидали",
+    "покидать",
+    "покидаю",
+    "покидая",
+    "покинет",
+    "покинешь",
+    "покину",
+    "покинув",
+    "покинули",
+    "покинутой",
+    "покинуть",
+    "покладая",
+    "покладываться",
+    "поклажа",
+    "поклажу",
+    "поклал",
+    "поклон",
+    "поклонами",
+    "поклонение",
+    "поклонением",
+    "поклонения",
+    "поклонившись",
+    "поклонилась",
+    "поклонился",
+    "поклонись",
+    "поклонится",
+    "поклониться",
+    "поклонник",
+    "поклонника",
+    "поклонникам",
+    "поклонники",
+    "поклонников",
+    "поклонником",
+    "поклонов",
+    "поклоном",
+    "поклоны",
+    "поклонюсь",
+    "поклоняется",
+    "поклонялась",
+    "поклонялся",
+    "поклонясь",
+    "поклоняются",
+    "поклоняясь",
+    "поклявшись",
+    "поклялась",
+    "поклялся",
+    "поклянусь",
+    "покое",
+    "покоем",
+    "покоен",
+    "покои",
+    "покоившейся",
+    "покоилась",
+    "покоились",
+    "покоит",
+    "покоится",
+    "покоить",
+    "покоишься",
+    "покой",
+    "покойна",
+    "покойная",
+    "покойнее",
+    "покойник",
+    "покойника",
+    "покойнике",
+    "покойников",
+    "покойником",
+    "покойнику",
+    "покойница",
+    "покойницу",
+    "покойницы",
+    "покойно",
+    "покойного",
+    "покойное",
+    "покойной",
+    "покойному",
+    "покойною",
+    "покойную",
+    "покойны",
+    "покойные",
+    "покойный",
+    "покойным",
+    "покойных",
+    "пококетничать",
+    "поколебавшись",
+    "поколебалась",
+    "поколебать",
+    "поколением",
+    "поколений",
+    "поколения",
+    "поколениях",
+    "поколотил",
+    "поколотить",
+    "покомфортнее",
+    "покончен",
+    "поконченный",
+    "покончено",
+    "покончены",
+    "покончив",
+    "покончил",
+    "покончим",
+    "покончит",
+    "покончить",
+    "покорев",
+    "покорению",
+    "покорилась",
+    "покориться",
+    "покормит",
+    "покорна",
+    "покорнейше",
+    "покорнейший",
+    "покорно",
+    "покорного",
+    "покорной",
+    "покорности",
+    "покорностию",
+    "покорность",
+    "покорностью",
+    "покорную",
+    "покорны",
+    "покорный",
+    "покорным",
+    "покорных",
+    "покоробило",
+    "покоробился",
+    "покороче",
+    "покорствуй",
+    "покоряется",
+    "покорять",
+    "покоряюсь",
+    "покосившись",
+    "покосился",
+    "покою",
+    "покоюсь",
+    "покоя",
+    "покоятся",
+    "покоях",
+    "покоящимся",
+    "покрадут",
+    "покраже",
+    "покражи",
+    "покраснев",
+    "покрасневший",
+    "покраснеете",
+    "покраснел",
+    "покраснела",
+    "покрашенных",
+    "покрепче",
+    "покривившаяся",
+    "покривившимися",
+    "покрикивать",
+    "покричит",
+    "покровителей",
+    "покровитель",
+    "покровительства",
+    "покровительство",
+    "покровительствовали",
+    "покровительством",
+    "покровительствует",
+    "покровы",
+    "покроюсь",
+    "покрупнее",
+    "покрутившись",
+    "покрыв",
+    "покрывает",
+    "покрывается",
+    "покрывал",
+    "покрывала",
+    "покрывало",
+    "покрывалом",
+    "покрываясь",
+    "покрывший",
+    "покрыл",
+    "покрыла",
+    "покрылись",
+    "покрыта",
+    "покрыто",
+    "покрытый",
+    "покрытыми",
+    "покрытых",
+    "покрыть",
+    "покрышку",
+    "покуда",
+    "покупает",
+    "покупается",
+    "покупал",
+    "покупалась",
+    "покупатели",
+    "покупать",
+    "покупками",
+    "покупке",
+    "покупки",
+    "покупкой",
+    "покупкою",
+    "покупку",
+    "покупные",
+    "покуражился",
+    "покурить",
+    "покуситься",
+    "покушает",
+    "покушается",
+    "покушался",
+    "покушение",
+    "покушений",
+    "покушения",
+    "покушениях",
+    "покушенья",
+    "пол",
+    "пола",
+    "полагает",
+    "полагаете",
+    "полагается",
+    "полагал",
+    "полагали",
+    "полагать",
+    "полагаю",
+    "полагая",
+    "полакомиться",
+    "поласковее",
+    "полатве",
+    "полбутылки",
+    "полведра",
+    "полгода",
+    "полгорода",
+    "полдень",
+    "полдневный",
+    "полдня",
+    "полдороге",
+    "полдюжины",
+    "поле",
+    "полевых",
+    "полегоньку",
+    "полегче",
+    "поледенело",
+    "полежав",
+    "полежал",
+    "полежать",
+    "полежишь",
+    "полежу",
+    "полез",
+    "полезай",
+    "полезен",
+    "полезет",
+    "полезла",
+    "полезли",
+    "полезная",
+    "полезнее",
+    "полезнейшая",
+    "полезно",
+    "полезное",
+    "полезной",
+    "полезною",
+    "полезную",
+    "полезны",
+    "полезные",
+    "полезный",
+    "полезным",
+    "полей",
+    "полем",
+    "полен",
+    "поленами",
+    "поленился",
+    "поленом",
+    "поленька",
+    "поленьке",
+    "поленькой",
+    "полет",
+    "полете",
+    "полетел",
+    "полетела",
+    "полетели",
+    "полетело",
+    "полететь",
+    "полетит",
+    "полетишь",
+    "полетов",
+    "полетом",
+    "полечивать",
+    "полечка",
+    "полечке",
+    "полечкой",
+    "полечку",
+    "полечу",
+    "полжизни",
+    "ползает",
+    "ползала",
+    "ползало",
+    "ползают",
+    "ползет",
+    "ползучем",
+    "ползущий",
+    "ползущих",
+    "ползя",
+    "поливали",
+    "поливающий",
+    "полились",
+    "полина",
+    "полине",
+    "полиной",
+    "полину",
+    "полинявшая",
+    "полинявшими",
+    "полиняли",
+    "полиняло",
+    "полинялые",
+    "полинялым",
+    "полинялых",
+    "полис",
+    "политика",
+    "политике",
+    "политики",
+    "политико",
+    "политику",
+    "политическая",
+    "политически",
+    "политические",
+    "политический",
+    "политического",
+    "политической",
+    "политическую",
+    "полицейские",
+    "полицейский",
+    "полицейскому",
+    "полицианом",
+    "полицией",
+    "полиции",
+    "полицию",
+    "полиция",
+    "полицу",
+    "полишинель",
+    "полк",
+    "полками",
+    "полкане",
+    "полках",
+    "полке",
+    "полки",
+    "полковник",
+    "полковника",
+    "полковнику",
+    "полковничьем",
+    "полковничья",
+    "полководцем",
+    "полковое",
+    "полковой",
+    "полкомнаты",
+    "полку",
+    "полмиллиона",
+    "полминуты",
+    "полна",
+    "полная",
+    "полнее",
+    "полнейшего",
+    "полнейшем",
+    "полнеть",
+    "полно",
+    "полноводной",
+    "полного",
+    "полное",
+    "полной",
+    "полнокровный",
+    "полном",
+    "полностью",
+    "полноте",
+    "полнотой",
+    "полноты",
+    "полночи",
+    "полночный",
+    "полночным",
+    "полночными",
+    "полночь",
+    "полною",
+    "полную",
+    "полны",
+    "полные",
+    "полный",
+    "полным",
+    "полными",
+    "полных",
+    "полов",
+    "половецкий",
+    "половик",
+    "половина",
+    "половине",
+    "половинки",
+    "половинку",
+    "половинных",
+    "половиной",
+    "половину",
+    "половинчатой",
+    "половины",
+    "половой",
+    "половому",
+    "половую",
+    "половчей",
+    "половым",
+    "полог",
+    "пологой",
+    "положа",
+    "положат",
+    "положен",
+    "положена",
+    "положение",
+    "положением",
+    "положении",
+    "положений",
+    "положению",
+    "положения",
+    "положениях",
+    "положенного",
+    "положенной",
+    "положенные",
+    "положенный",
+    "положенными",
+    "положено",
+    "положи",
+    "положив",
+    "положил",
+    "положила",
+    "положили",
+    "положим",
+    "положись",
+    "положит",
+    "положительно",
+    "положительного",
+    "положительное",
+    "положительной",
+    "положительный",
+    "положительным",
+    "положительных",
+    "положится",
+    "положить",
+    "положиться",
+    "положишь",
+    "положу",
+    "полой",
+    "полок",
+    "полом",
+    "поломаться",
+    "поломойная",
+    "полон",
+    "полоненная",
+    "полонский",
+    "полосами",
+    "полосатой",
+    "полоскалась",
+    "полосками",
+    "полоски",
+    "полоской",
+    "полоскою",
+    "полоску",
+    "полоснет",
+    "полосой",
+    "полосою",
+    "полости",
+    "полость",
+    "полосы",
+    "полотенце",
+    "полотенцем",
+    "полотна",
+    "полотнами",
+    "полотне",
+    "полотнища",
+    "полотно",
+    "полоумная",
+    "полоумного",
+    "полоумный",
+    "полоумным",
+    "полощет",
+    "полощутся",
+    "полпивной",
+    "полпивную",
+    "полслова",
+    "полсотня",
+    "полстакана",
+    "полстраницы",
+    "полсутки",
+    "полтинник",
+    "полтинники",
+    "полтиннику",
+    "полтиной",
+    "полтора",
+    "полторы",
+    "полу",
+    "полубезумною",
+    "полубессмысленная",
+    "полубог",
+    "полубреде",
+    "полувеселый",
+    "полугода",
+    "полугоры",
+    "полудворянских",
+    "полуденными",
+    "полудне",
+    "полудня",
+    "полудремоту",
+    "полукругом",
+    "полумглу",
+    "полумертвый",
+    "полумрак",
+    "полумраке",
+    "полуно",
+    "полуночи",
+    "полуночной",
+    "полунощный",
+    "полуотворенной",
+    "полуотворенную",
+    "полуотворенные",
+    "полуотворенный",
+    "полуоткрыты",
+    "полупоклоном",
+    "полупрезрение",
+    "полупрезрением",
+    "полупросыпаясь",
+    "полупьяных",
+    "полуразвалившиеся",
+    "полуразрушенные",
+    "полуслове",
+    "полусне",
+    "полусознанием",
+    "полустрадный",
+    "полусумасшедшая",
+    "полусумасшедших",
+    "полутон",
+    "полуторы",
+    "полуформенной",
+    "получаем",
+    "получаемых",
+    "получает",
+    "получаете",
+    "получается",
+    "получаешь",
+    "получал",
+    "получала",
+    "получало",
+    "получалось",
+    "получасу",
+    "получать",
+    "получаю",
+    "получая",
+    "получение",
+    "получении",
+    "получения",
+    "полученное",
+    "полученному",
+    "полученные",
+    "полученный",
+    "полученными",
+    "полученных",
+    "получено",
+    "получены",
+    "получи",
+    "получив",
+    "получил",
+    "получила",
+    "получили",
+    "получило",
+    "получимши",
+    "получиновничьих",
+    "получит",
+    "получите",
+    "получить",
+    "получишь",
+    "получу",
+    "получше",
+    "полушепотом",
+    "полуштоф",
+    "полушубком",
+    "полушубок",
+    "полчаса",
+    "полчасика",
+    "полчища",
+    "полшага",
+    "полы",
+    "полымем",
+    "полымя",
+    "полыни",
+    "полынь",
+    "полыхнет",
+    "польза",
+    "пользовавшееся",
+    "пользовал",
+    "пользовалась",
+    "пользовалось",
+    "пользоваться",
+    "пользой",
+    "пользу",
+    "пользуетесь",
+    "пользуешься",
+    "пользуются",
+    "пользуясь",
+    "пользы",
+    "полька",
+    "польку",
+    "польски",
+    "польские",
+    "польскими",
+    "польских",
+    "польское",
+    "польстилась",
+    "польстит",
+    "польше",
+    "польши",
+    "польшу",
+    "польщено",
+    "полю",
+    "полюбил",
+    "полюбила",
+    "полюбили",
+    "полюбим",
+    "полюбит",
+    "полюбите",
+    "полюбить",
+    "полюбишь",
+    "полюблю",
+    "полюбопытствовал",
+    "полюбуйтесь",
+    "полюс",
+    "полюсах",
+    "поля",
+    "поляк",
+    "поляки",
+    "поляко",
+    "поляков",
+    "полям",
+    "полями",
+    "поляне",
+    "поляны",
+    "полярный",
+    "полях",
+    "полячишки",
+    "полячка",
+    "полячков",
+    "полячку",
+    "полячок",
+    "помадки",
+    "помадой",
+    "помаду",
+    "помаленьку",
+    "помалу",
+    "помахивая",
+    "помашет",
+    "помедли",
+    "помедлив",
+    "помедлить",
+    "помедля",
+    "помелом",
+    "помене",
+    "поменее",
+    "поменьше",
+    "поменялись",
+    "поменяться",
+    "помер",
+    "померанцевой",
+    "померанцевую",
+    "померанцевые",
+    "померанцы",
+    "помереть",
+    "померещилось",
+    "померещился",
+    "померзло",
+    "померк",
+    "померкал",
+    "померкла",
+    "померкло",
+    "померкший",
+    "померла",
+    "помертвев",
+    "помертвевшая",
+    "помертвевшей",
+    "помертвевшие",
+    "помертвевший",
+    "помертвевшими",
+    "помертвел",
+    "помертвело",
+    "померяемся",
+    "поместившись",
+    "поместил",
+    "поместились",
+    "поместился",
+    "поместим",
+    "поместительный",
+    "поместить",
+    "помесячно",
+    "помехи",
+    "помеченную",
+    "помечтаем",
+    "помешает",
+    "помешаете",
+    "помешал",
+    "помешала",
+    "помешалась",
+    "помешали",
+    "помешало",
+    "помешался",
+    "помешан",
+    "помешанная",
+    "помешанного",
+    "помешанной",
+    "помешанную",
+    "помешанные",
+    "помешанный",
+    "помешаны",
+    "помешательства",
+    "помешательстве",
+    "помешательству",
+    "помешать",
+    "помешаю",
+    "помешают",
+    "помещается",
+    "помещалась",
+    "помещался",
+    "помещаются",
+    "помещение",
+    "помещений",
+    "помещению",
+    "помещения",
+    "помещик",
+    "помещика",
+    "помещиков",
+    "помещиком",
+    "помещику",
+    "помещица",
+    "помещичьи",
+    "помещу",
+    "помещьчьих",
+    "помилует",
+    "помилуй",
+    "помилуйте",
+    "помимо",
+    "помин",
+    "поминай",
+    "поминайте",
+    "поминал",
+    "поминала",
+    "поминали",
+    "поминать",
+    "поминкам",
+    "поминках",
+    "поминки",
+    "поминок",
+    "помину",
+    "поминутно",
+    "поминутные",
+    "помирает",
+    "помирились",
+    "помирись",
+    "помирите",
+    "помиритесь",
+    "помириться",
+    "помни",
+    "помнил",
+    "помнила",
+    "помнили",
+    "помнилось",
+    "помним",
+    "помнит",
+    "помните",
+    "помнится",
+    "помнить",
+    "помнишь",
+    "помню",
+    "помня",
+    "помнят",
+    "помнятся",
+    "помнящего",
+    "помнящей",
+    "помнящую",
+    "помог",
+    "помогает",
+    "помогаешь",
+    "помогал",
+    "помогала",
+    "помогать",
+    "помогаю",
+    "помогают",
+    "помогая",
+    "помоги",
+    "помогите",
+    "помогла",
+    "помогли",
+    "помогло",
+    "помогу",
+    "помогут",
+    "поможет",
+    "помойные",
+    "помойных",
+    "помолимся",
+    "помолись",
+    "помолитесь",
+    "помолиться",
+    "помолодела",
+    "помолодело",
+    "помоложе",
+    "помолчав",
+    "помолчал",
+    "помолчала",
+    "помолчали",
+    "помолчать",
+    "помолчи",
+    "помолчим",
+    "помолюсь",
+    "поморщились",
+    "поморщился",
+    "помочах",
+    "помочи",
+    "помочил",
+    "помочь",
+    "помощи",
+    "помощию",
+    "помощник",
+    "помощнике",
+    "помощники",
+    "помощницей",
+    "помощницы",
+    "помощь",
+    "помощью",
+    "помоями",
+    "помоях",
+    "помрачался",
+    "помрачен",
+    "помрачение",
+    "помрачения",
+    "помрачилась",
+    "помрет",
+    "помудренее",
+    "помутилось",
+    "помутневший",
+    "помучается",
+    "помучить",
+    "помыкают",
+    "помыслить",
+    "помыслишь",
+    "помыслов",
+    "помыслы",
+    "помышления",
+    "помышленья",
+    "помышлял",
+    "помягче",
+    "помянет",
+    "помяни",
+    "помяните",
+    "помянул",
+    "помянут",
+    "помянуть",
+    "помятый",
+    "помять",
+    "понаведаться",
+    "понадеялась",
+    "понадеялся",
+    "понадеяться",
+    "понадобились",
+    "понадобилось",
+    "понадобился",
+    "понадобится",
+    "понадобиться",
+    "понадоблюсь",
+    "понадобятся",
+    "понапрасну",
+    "понасажать",
+    "понатужиться",
+    "поневоле",
+    "понедельник",
+    "понедельника",
+    "понедельники",
+    "понелепее",
+    "понемногу",
+    "понемножку",
+    "понес",
+    "понесем",
+    "понесет",
+    "понесется",
+    "понесли",
+    "понеслись",
+    "понесло",
+    "понесут",
+    "понижая",
+    "пониже",
+    "понижения",
+    "понизив",
+    "понизилась",
+    "поник",
+    "поникнув",
+    "понимавшая",
+    "понимавший",
+    "понимаем",
+    "понимает",
+    "понимаете",
+    "понимаешь",
+    "понимал",
+    "понимала",
+    "понимали",
+    "понимание",
+    "понимании",
+    "пониманию",
+    "понимания",
+    "понимать",
+    "понимаю",
+    "понимают",
+    "понимающею",
+    "понимающие",
+    "понимающим",
+    "понимая",
+    "понмю",
+    "поновее",
+    "поносил",
+    "поношенное",
+    "поношенном",
+    "поношенные",
+    "поношенный",
+    "поношено",
+    "понравилась",
+    "понравились",
+    "понравилось",
+    "понравился",
+    "понравится",
+    "понравиться",
+    "понудила",
+    "понуждай",
+    "понукал",
+    "понуканий",
+    "понукая",
+    "понурил",
+    "поныне",
+    "понюхав",
+    "понюхает",
+    "понюхай",
+    "понюхайте",
+    "понюхала",
+    "понюхать",
+    "поняв",
+    "понявшая",
+    "понявший",
+    "понял",
+    "поняла",
+    "поняли",
+    "понят",
+    "понятен",
+    "понятие",
+    "понятием",
+    "понятии",
+    "понятий",
+    "понятия",
+    "понятиям",
+    "понятливый",
+    "понятна",
+    "понятная",
+    "понятнее",
+    "понятней",
+    "понятно",
+    "понятное",
+    "понятны",
+    "понятным",
+    "понятое",
+    "понять",
+    "пообедав",
+    "пообедаем",
+    "пообедаете",
+    "пообедал",
+    "пообедала",
+    "пообедать",
+    "пообедаю",
+    "пообтерся",
+    "поодаль",
+    "поочередно",
+    "поощряли",
+    "поощряю",
+    "попав",
+    "попавший",
+    "попадавшиеся",
+    "попадает",
+    "попадаете",
+    "попадается",
+    "попадал",
+    "попадались",
+    "попадало",
+    "попадалось",
+    "попадать",
+    "попадают",
+    "попадаются",
+    "попадая",
+    "попадет",
+    "попадется",
+    "попадешь",
+    "попадешься",
+    "попал",
+    "попала",
+    "попалась",
+    "попали",
+    "попались",
+    "попало",
+    "попалось",
+    "попался",
+    "попами",
+    "попасть",
+    "попахивает",
+    "поперек",
+    "попеременно",
+    "поперечного",
+    "поперечных",
+    "попестрей",
+    "попечение",
+    "попираешь",
+    "попираньи",
+    "пописываете",
+    "поплавок",
+    "поплакали",
+    "поплачет",
+    "поплачут",
+    "поплевывающих",
+    "поплевывая",
+    "поплелись",
+    "поплелся",
+    "поплотнее",
+    "поплывут",
+    "попов",
+    "поповича",
+    "попозже",
+    "попойки",
+    "пополам",
+    "пополз",
+    "поползет",
+    "поползли",
+    "поползновения",
+    "пополнела",
+    "пополнит",
+    "пополудни",
+    "попользоваться",
+    "попомните",
+    "попона",
+    "попортились",
+    "попотчевать",
+    "поправил",
+    "поправила",
+    "поправилась",
+    "поправилось",
+    "поправился",
+    "поправимое",
+    "поправит",
+    "поправить",
+    "поправиться",
+    "поправка",
+    "поправке",
+    "поправки",
+    "поправлю",
+    "поправляет",
+    "поправляется",
+    "поправлял",
+    "поправляют",
+    "поправляя",
+    "поправочки",
+    "поправь",
+    "поправьте",
+    "поправят",
+    "попрекаете",
+    "попрекаешь",
+    "попрекал",
+    "попрекала",
+    "попрекать",
+    "попрекнула",
+    "попреков",
+    "попридержать",
+    "попридержу",
+    "поприща",
+    "поприще",
+    "поприщина",
+    "поприщу",
+    "попробовал",
+    "попробовала",
+    "попробовать",
+    "попробуй",
+    "попробуйте",
+    "попробую",
+    "попрозорливее",
+    "попроси",
+    "попросил",
+    "попросила",
+    "попросили",
+    "попросит",
+    "попросите",
+    "попросить",
+    "попроситься",
+    "попросишь",
+    "попросту",
+    "попросят",
+    "попрошу",
+    "попугать",
+    "попудриться",
+    "попустит",
+    "попутчиков",
+    "попытался",
+    "попытать",
+    "попытаться",
+    "попытка",
+    "попытку",
+    "попыток",
+    "попятился",
+    "пор",
+    "пора",
+    "поработает",
+    "поработать",
+    "порабощении",
+    "поравнявшись",
+    "поравнялись",
+    "поравнялся",
+    "порадовалась",
+    "порадовался",
+    "порадуется",
+    "поражает",
+    "поражал",
+    "поражало",
+    "поражен",
+    "поражена",
+    "пораженная",
+    "пораженное",
+    "пораженному",
+    "пораженную",
+    "пораженные",
+    "пораженный",
+    "пораженным",
+    "поражены",
+    "пораженья",
+    "поразившая",
+    "поразил",
+    "поразила",
+    "поразили",
+    "поразило",
+    "поразительной",
+    "поразить",
+    "пораньше",
+    "пораспотрошили",
+    "порастет",
+    "порах",
+    "порвала",
+    "порвался",
+    "порванная",
+    "порвать",
+    "порвется",
+    "поре",
+    "порешивший",
+    "порешил",
+    "порешили",
+    "порешите",
+    "порешить",
+    "порицал",
+    "порицанию",
+    "порицать",
+    "поровнявшись",
+    "поровнялся",
+    "порог",
+    "порога",
+    "порогами",
+    "пороге",
+    "пороги",
+    "пород",
+    "порода",
+    "породе",
+    "породить",
+    "порождает",
+    "порождают",
+    "порожнее",
+    "порой",
+    "порок",
+    "пороках",
+    "пороков",
+    "пороку",
+    "поросенка",
+    "поросла",
+    "порослях",
+    "поросята",
+    "порох",
+    "пороха",
+    "пороховом",
+    "пороховые",
+    "порохом",
+    "пороху",
+    "порочен",
+    "порочна",
+    "порочного",
+    "порочной",
+    "порочные",
+    "порочный",
+    "порошками",
+    "порошком",
+    "порошок",
+    "порошочек",
+    "порою",
+    "портер",
+    "портером",
+    "портеру",
+    "портила",
+    "портили",
+    "портит",
+    "портить",
+    "портмоне",
+    "портниха",
+    "портнихе",
+    "портнихи",
+    "портного",
+    "портной",
+    "портному",
+    "портным",
+    "портными",
+    "портрет",
+    "портрета",
+    "портретик",
+    "портреты",
+    "портфеля",
+    "портьер",
+    "пору",
+    "порубки",
+    "поругались",
+    "поругание",
+    "поруганный",
+    "поруганье",
+    "поруганьи",
+    "порумянее",
+    "поручат",
+    "поручают",
+    "поручение",
+    "поручением",
+    "поручений",
+    "поручению",
+    "поручения",
+    "поручениями",
+    "порученному",
+    "поручено",
+    "поручившись",
+    "поручик",
+    "поручика",
+    "поручики",
+    "поручиков",
+    "поручикова",
+    "поручиком",
+    "поручику",
+    "поручил",
+    "поручился",
+    "поручит",
+    "поручите",
+    "поручить",
+    "поручиться",
+    "поручу",
+    "порфирием",
+    "порфирии",
+    "порфирий",
+    "порфирию",
+    "порфирия",
+    "порхает",
+    "порхала",
+    "порхнула",
+    "порхнули",
+    "порцию",
+    "порчи",
+    "поры",
+    "порыв",
+    "порыва",
+    "порывается",
+    "порывались",
+    "порывалось",
+    "порывался",
+    "порывами",
+    "порываний",
+    "порываниями",
+    "порыванье",
+    "порываются",
+    "порыве",
+    "порывисто",
+    "порывистой",
+    "порывистую",
+    "порывистых",
+    "порывов",
+    "порывом",
+    "порывы",
+    "порылась",
+    "порылся",
+    "порыскать",
+    "порядка",
+    "порядке",
+    "порядки",
+    "порядков",
+    "порядком",
+    "порядку",
+    "порядок",
+    "порядочная",
+    "порядочно",
+    "порядочного",
+    "порядочное",
+    "порядочную",
+    "порядочные",
+    "порядочный",
+    "порядочным",
+    "порядочных",
+    "посади",
+    "посадив",
+    "посадил",
+    "посадила",
+    "посадили",
+    "посадит",
+    "посадите",
+    "посадить",
+    "посадят",
+    "посаженные",
+    "посаженный",
+    "посажу",
+    "посватал",
+    "посватался",
+    "посвататься",
+    "посвежее",
+    "посвистывая",
+    "посвятил",
+    "посвятить",
+    "посвящается",
+    "посвящал",
+    "посвящают",
+    "посвящена",
+    "посвящением",
+    "посев",
+    "поседевшие",
+    "посекут",
+    "поселились",
+    "поселился",
+    "поселить",
+    "поселиться",
+    "поселюсь",
+    "поселятся",
+    "посему",
+    "посередине",
+    "посетило",
+    "посетит",
+    "посетителей",
+    "посетители",
+    "посетитель",
+    "посетителю",
+    "посетителя",
+    "посетителями",
+    "посетить",
+    "посетят",
+    "посечь",
+    "посещавшие",
+    "посещавших",
+    "посещаемых",
+    "посещайте",
+    "посещал",
+    "посещали",
+    "посещать",
+    "посещают",
+    "посещающие",
+    "посещение",
+    "посещением",
+    "посещении",
+    "посещения",
+    "посещениями",
+    "посеял",
+    "посидев",
+    "посидел",
+    "посидеть",
+    "посиди",
+    "посидим",
+    "посидит",
+    "посидите",
+    "посидишь",
+    "посиживает",
+    "посижу",
+    "посильное",
+    "посильную",
+    "посильные",
+    "посимпатичнее",
+    "посиневших",
+    "поскакать",
+    "поскачет",
+    "поскольку",
+    "посконного",
+    "посконные",
+    "поскорее",
+    "поскорей",
+    "послав",
+    "послал",
+    "послала",
+    "послали",
+    "послан",
+    "послания",
+    "посланник",
+    "посланника",
+    "посланную",
+    "послать",
+    "после",
+    "последки",
+    "последнего",
+    "последнее",
+    "последней",
+    "последнем",
+    "последнему",
+    "последнею",
+    "последние",
+    "последний",
+    "последним",
+    "последними",
+    "последних",
+    "последнюю",
+    "последняя",
+    "последовавшего",
+    "последовавшее",
+    "последовал",
+    "последовала",
+    "последовало",
+    "последовательно",
+    "последовательности",
+    "последовать",
+    "последствием",
+    "последствий",
+    "последствия",
+    "последствиям",
+    "последствиями",
+    "последующее",
+    "последующий",
+    "последующим",
+    "послезавтра",
+    "послеобеденного",
+    "послеобеденную",
+    "послеобеденный",
+    "пословица",
+    "пословице",
+    "послужила",
+    "послужили",
+    "послужило",
+    "послужит",
+    "послужить",
+    "послушаем",
+    "послушает",
+    "послушается",
+    "послушаешь",
+    "послушай",
+    "послушайте",
+    "послушайтесь",
+    "послушал",
+    "послушала",
+    "послушали",
+    "послушались",
+    "послушался",
+    "послушание",
+    "послушании",
+    "послушанию",
+    "послушать",
+    "послушаюсь",
+    "послушна",
+    "послушно",
+    "послушною",
+    "послушный",
+    "послушным",
+    "послушными",
+    "послушных",
+    "послышавшийся",
+    "послышалась",
+    "послышались",
+    "послышалось",
+    "послышался",
+    "послышатся",
+    "послышится",
+    "послышишь",
+    "посматривал",
+    "посматривая",
+    "посмеемся",
+    "посмеет",
+    "посмеется",
+    "посмеивается",
+    "посмеиваться",
+    "посмел",
+    "посмели",
+    "посметь",
+    "посмешу",
+    "посмеются",
+    "посмеялись",
+    "посмеяться",
+    "посмирнее",
+    "посмотрев",
+    "посмотрел",
+    "посмотрела",
+    "посмотрели",
+    "посмотрелся",
+    "посмотреть",
+    "посмотри",
+    "посмотрим",
+    "посмотрит",
+    "посмотрите",
+    "посмотришь",
+    "посмотрю",
+    "посмотрят",
+    "поснимали",
+    "пособию",
+    "пособия",
+    "посовестился",
+    "посовестится",
+    "посоветовал",
+    "посоветовались",
+    "посоветовать",
+    "посоветоваться",
+    "посоветуется",
+    "посоветуюсь",
+    "посолиднее",
+    "посолить",
+    "посоловелые",
+    "посольства",
+    "посох",
+    "поспал",
+    "поспала",
+    "поспевала",
+    "поспевать",
+    "поспевают",
+    "поспеет",
+    "поспел",
+    "поспеть",
+    "поспешайте",
+    "поспешающих",
+    "поспешая",
+    "поспешив",
+    "поспешил",
+    "поспешила",
+    "поспешили",
+    "поспешить",
+    "поспешно",
+    "поспешное",
+    "поспешность",
+    "поспешностью",
+    "поспешные",
+    "поспешу",
+    "поспею",
+    "поспи",
+    "посплетничать",
+    "поспорил",
+    "посреди",
+    "посредине",
+    "посредника",
+    "посредником",
+    "посредство",
+    "посредством",
+    "поссорились",
+    "поссорился",
+    "поссорить",
+    "поста",
+    "поставив",
+    "поставил",
+    "поставила",
+    "поставили",
+    "поставило",
+    "поставим",
+    "поставит",
+    "поставить",
+    "поставлен",
+    "поставленная",
+    "поставленное",
+    "поставленный",
+    "поставляла",
+    "поставцами",
+    "поставь",
+    "поставьте",
+    "поставят",
+    "постараетесь",
+    "постарается",
+    "постаралась",
+    "постарались",
+    "постарался",
+    "постараться",
+    "постараюсь",
+    "постаревшем",
+    "постарел",
+    "постарела",
+    "постарели",
+    "постареть",
+    "постарше",
+    "постели",
+    "постель",
+    "постельке",
+    "постельку",
+    "постельное",
+    "постелью",
+    "постелям",
+    "постепенная",
+    "постепенно",
+    "постепенного",
+    "постепенное",
+    "постепенностью",
+    "постиг",
+    "постигать",
+    "постигнет",
+    "постигнешь",
+    "постигнуть",
+    "постилает",
+    "постилая",
+    "постичь",
+    "постлал",
+    "постлан",
+    "постлано",
+    "постлать",
+    "постного",
+    "постный",
+    "постным",
+    "постой",
+    "постойте",
+    "постольку",
+    "постом",
+    "посторонился",
+    "посторонись",
+    "постороннего",
+    "постороннее",
+    "посторонней",
+    "постороннем",
+    "постороннему",
+    "посторонние",
+    "посторонний",
+    "посторонними",
+    "посторонних",
+    "посторонннй",
+    "посторонняя",
+    "постояв",
+    "постоял",
+    "постояла",
+    "постояли",
+    "постоялом",
+    "постояннее",
+    "постоянно",
+    "постоянного",
+    "постоянное",
+    "постоянной",
+    "постоянною",
+    "постоянную",
+    "постоянные",
+    "постоянный",
+    "постоянным",
+    "постоянством",
+    "постоять",
+    "пострадавшую",
+    "пострадайте",
+    "пострадать",
+    "постращать",
+    "пострел",
+    "постреленок",
+    "постричься",
+    "построек",
+    "построен",
+    "построение",
+    "построения",
+    "построже",
+    "построив",
+    "построили",
+    "построит",
+    "построить",
+    "постройка",
+    "постройки",
+    "постройкой",
+    "постройку",
+    "посту",
+    "постукивал",
+    "постукиванье",
+    "постукивают",
+    "постукивающим",
+    "поступаешь",
+    "поступал",
+    "поступали",
+    "поступать",
+    "поступаю",
+    "поступают",
+    "поступи",
+    "поступил",
+    "поступила",
+    "поступили",
+    "поступило",
+    "поступит",
+    "поступите",
+    "поступить",
+    "поступка",
+    "поступкам",
+    "поступками",
+    "поступках",
+    "поступке",
+    "поступки",
+    "поступков",
+    "поступком",
+    "поступку",
+    "поступлю",
+    "поступок",
+    "поступь",
+    "поступью",
+    "постучав",
+    "постучал",
+    "постучались",
+    "постучаться",
+    "постучите",
+    "постучу",
+    "постыдились",
+    "постыдился",
+    "постыдится",
+    "постыдного",
+    "постылая",
+    "постыли",
+    "постылом",
+    "постылый",
+    "посуда",
+    "посудинкой",
+    "посудинку",
+    "посудите",
+    "посудой",
+    "посуду",
+    "посуды",
+    "посуетиться",
+    "посущественнее",
+    "посылаем",
+    "посылает",
+    "посылается",
+    "посылаешь",
+    "посылал",
+    "посылала",
+    "посылали",
+    "посылались",
+    "посылалось",
+    "посылать",
+    "посылают",
+    "посылки",
+    "посыльный",
+    "посыпались",
+    "посягал",
+    "пот",
+    "пота",
+    "потаенною",
+    "потанчиков",
+    "потапыча",
+    "потаскали",
+    "потасканной",
+    "потаскун",
+    "потаскуна",
+    "поташ",
+    "потащил",
+    "потащила",
+    "потащили",
+    "потащился",
+    "поте",
+    "потекла",
+    "потекло",
+    "потекут",
+    "потемневший",
+    "потемневшую",
+    "потемнее",
+    "потемнело",
+    "потемок",
+    "потеплее",
+    "потере",
+    "потерей",
+    "потерею",
+    "потери",
+    "потерпели",
+    "потерпите",
+    "потертом",
+    "потертым",
+    "потерь",
+    "потеря",
+    "потеряв",
+    "потерявший",
+    "потерявшийся",
+    "потерявшись",
+    "потеряет",
+    "потеряется",
+    "потеряешь",
+    "потерял",
+    "потеряла",
+    "потерялась",
+    "потеряли",
+    "потерялись",
+    "потерялся",
+    "потерян",
+    "потеряна",
+    "потерянная",
+    "потерянно",
+    "потерянный",
+    "потеряно",
+    "потеряны",
+    "потерять",
+    "потеснее",
+    "потехи",
+    "потешаясь",
+    "потешится",
+    "потешить",
+    "потешником",
+    "потешно",
+    "потирает",
+    "потирал",
+    "потирания",
+    "потирая",
+    "потихоньку",
+    "потише",
+    "поток",
+    "потока",
+    "потоками",
+    "потоком",
+    "потолка",
+    "потолки",
+    "потолковее",
+    "потолку",
+    "потолкуем",
+    "потолок",
+    "потолочь",
+    "потолстеет",
+    "потом",
+    "потомили",
+    "потомки",
+    "потомков",
+    "потомок",
+    "потомству",
+    "потому",
+    "потонет",
+    "потонуло",
+    "потонут",
+    "потонуть",
+    "поторапливайтесь",
+    "поторговалась",
+    "поторопился",
+    "потосковала",
+    "потратить",
+    "потрафил",
+    "потрачено",
+    "потребления",
+    "потребности",
+    "потребность",
+    "потребностью",
+    "потребностями",
+    "потребностях",
+    "потребовал",
+    "потребовала",
+    "потребовали",
+    "потребовалось",
+    "потребовать",
+    "потребу",
+    "потребует",
+    "потребуется",
+    "потребуешь",
+    "потребую",
+    "потревожить",
+    "потрепал",
+    "потрепала",
+    "потрепанным",
+    "потрепать",
+    "потрепещут",
+    "потрет",
+    "потрогивая",
+    "потроха",
+    "потрохами",
+    "потрудился",
+    "потрудитесь",
+    "потрут",
+    "потряс",
+    "потрясаемый",
+    "потрясает",
+    "потрясающие",
+    "потрясающий",
+    "потрясая",
+    "потрясен",
+    "потрясений",
+    "потрясенный",
+    "потрясла",
+    "потрясло",
+    "поту",
+    "потуг",
+    "потупив",
+    "потупившись",
+    "потупил",
+    "потупила",
+    "потупилась",
+    "потупленные",
+    "потупляет",
+    "потупляясь",
+    "потупя",
+    "потупясь",
+    "потускли",
+    "потускнели",
+    "потух",
+    "потухал",
+    "потухший",
+    "потухшим",
+    "потухших",
+    "потушая",
+    "потушены",
+    "потуши",
+    "потчеванье",
+    "потчеванья",
+    "потчевать",
+    "потчую",
+    "потягивается",
+    "потягиваться",
+    "потягиваясь",
+    "потянет",
+    "потяни",
+    "потянул",
+    "потянулась",
+    "потянулись",
+    "потянуло",
+    "поубавил",
+    "поубрать",
+    "поудалее",
+    "поужинал",
+    "поужинать",
+    "поужинаю",
+    "поумнее",
+    "поумничала",
+    "поутру",
+    "поучался",
+    "поучения",
+    "поучительно",
+    "поучить",
+    "пофанфаронить",
+    "пофилософствовал",
+    "пофилософствовать",
+    "пофилософствуем",
+    "пофилософствуешь",
+    "пофорсить",
+    "похаживая",
+    "похвал",
+    "похвала",
+    "похвалами",
+    "похвалил",
+    "похвалила",
+    "похвалить",
+    "похвалиться",
+    "похвалой",
+    "похвалы",
+    "похвальбы",
+    "похвально",
+    "похвальном",
+    "похвальный",
+    "похвастается",
+    "похвастать",
+    "похвать",
+    "похитил",
+    "похитителя",
+    "похищаю",
+    "похищенных",
+    "похлопотать",
+    "похлопочу",
+    "похлопывая",
+    "похмелье",
+    "похмельем",
+    "похмелья",
+    "поход",
+    "похода",
+    "походами",
+    "походе",
+    "походившему",
+    "походил",
+    "походила",
+    "походили",
+    "походило",
+    "походимте",
+    "походит",
+    "походите",
+    "походить",
+    "походка",
+    "походкой",
+    "походкою",
+    "походку",
+    "походной",
+    "походы",
+    "походя",
+    "похож",
+    "похожа",
+    "похожая",
+    "похождениях",
+    "похоже",
+    "похожего",
+    "похожее",
+    "похожей",
+    "похожем",
+    "похожему",
+    "похожи",
+    "похожие",
+    "похожий",
+    "похожими",
+    "похозяйничать",
+    "похолодев",
+    "похолодел",
+    "похолодела",
+    "похолодели",
+    "похолодело",
+    "похорон",
+    "похоронами",
+    "похоронах",
+    "похоронен",
+    "похоронены",
+    "похоронив",
+    "похоронил",
+    "похоронила",
+    "похоронили",
+    "похоронить",
+    "похоронная",
+    "похоронных",
+    "похороны",
+    "похороню",
+    "похоронят",
+    "похорошела",
+    "похоти",
+    "похотливой",
+    "похудевшая",
+    "похудевшее",
+    "похудеешь",
+    "похудел",
+    "похудела",
+    "похудели",
+    "похудело",
+    "похуже",
+    "поцеловав",
+    "поцеловал",
+    "поцеловала",
+    "поцеловать",
+    "поцелуе",
+    "поцелуев",
+    "поцелуем",
+    "поцелуемся",
+    "поцелует",
+    "поцелуи",
+    "поцелуй",
+    "поцелуйте",
+    "поцелую",
+    "поцелуя",
+    "поцелуями",
+    "почавкав",
+    "початая",
+    "почаще",
+    "почва",
+    "почве",
+    "почву",
+    "почвы",
+    "почел",
+    "почем",
+    "почему",
+    "почерк",
+    "почерневшее",
+    "почернели",
+    "почернелых",
+    "почерпать",
+    "почерпнешь",
+    "почерпнутые",
+    "почерпнуть",
+    "почесал",
+    "почесть",
+    "почестью",
+    "почесывает",
+    "почесывая",
+    "почетно",
+    "почетном",
+    "почетным",
+    "почетных",
+    "почетом",
+    "почивает",
+    "почивал",
+    "почивали",
+    "почивать",
+    "почивают",
+    "почиет",
+    "почил",
+    "починена",
+    "починивала",
+    "починили",
+    "починить",
+    "починки",
+    "починкова",
+    "починку",
+    "починю",
+    "почистить",
+    "почитает",
+    "почитаешь",
+    "почитай",
+    "почитал",
+    "почитали",
+    "почитатели",
+    "почитаю",
+    "почитают",
+    "почище",
+    "почищу",
+    "почиющий",
+    "почли",
+    "почмокавшись",
+    "почт",
+    "почта",
+    "почтальона",
+    "почтальону",
+    "почтамте",
+    "почте",
+    "почтение",
+    "почтении",
+    "почтенная",
+    "почтеннейшая",
+    "почтеннейшие",
+    "почтеннейший",
+    "почтеннейшим",
+    "почтенного",
+    "почтенное",
+    "почтенном",
+    "почтенному",
+    "почтенные",
+    "почтенный",
+    "почтенным",
+    "почти",
+    "почтит",
+    "почтительнейшие",
+    "почтительно",
+    "почтительной",
+    "почтительном",
+    "почтительность",
+    "почтительною",
+    "почтительные",
+    "почтительным",
+    "почтить",
+    "почтмейстером",
+    "почто",
+    "почтовой",
+    "почтовом",
+    "почтовые",
+    "почтовый",
+    "почтовым",
+    "почтовых",
+    "почтой",
+    "почту",
+    "почты",
+    "почувствовав",
+    "почувствовал",
+    "почувствовала",
+    "почувствовать",
+    "почувствует",
+    "почудилась",
+    "почудились",
+    "почудилось",
+    "почудится",
+    "почуя",
+    "пошаливают",
+    "пошарив",
+    "пошарил",
+    "пошатнется",
+    "пошатнулось",
+    "пошатывается",
+    "пошатываясь",
+    "пошевелив",
+    "пошевеливаться",
+    "пошевелил",
+    "пошевелила",
+    "пошевелилась",
+    "пошевелились",
+    "пошевелилось",
+    "пошевелился",
+    "пошевелится",
+    "пошевелить",
+    "пошевелиться",
+    "пошел",
+    "пошептал",
+    "пошепчут",
+    "пошла",
+    "пошлейшая",
+    "пошлейшую",
+    "пошлем",
+    "пошленький",
+    "пошленького",
+    "пошленькой",
+    "пошлет",
+    "пошлешь",
+    "пошли",
+    "пошлите",
+    "пошло",
+    "пошлое",
+    "пошлой",
+    "пошлом",
+    "пошлости",
+    "пошлость",
+    "пошлостью",
+    "пошлые",
+    "пошлый",
+    "пошлых",
+    "пошлю",
+    "пошляками",
+    "пошляков",
+    "пошляком",
+    "пошляческая",
+    "пошто",
+    "пошты",
+    "пошутил",
+    "пошутила",
+    "пошутили",
+    "пощаде",
+    "пощадите",
+    "пощадить",
+    "пощады",
+    "пощекотали",
+    "пощекотать",
+    "пощелкав",
+    "пощелкивал",
+    "пощупать",
+    "поэзии",
+    "поэзию",
+    "поэзия",
+    "поэма",
+    "поэму",
+    "поэмы",
+    "поэт",
+    "поэта",
+    "поэтах",
+    "поэтическая",
+    "поэтически",
+    "поэтические",
+    "поэтический",
+    "поэтическим",
+    "поэтических",
+    "поэтическое",
+    "поэтической",
+    "поэтична",
+    "поэтично",
+    "поэтов",
+    "поэтому",
+    "поэты",
+    "пою",
+    "поют",
+    "поющую",
+    "появилась",
+    "появились",
+    "появился",
+    "появится",
+    "появиться",
+    "появление",
+    "появлением",
+    "появлении",
+    "появления",
+    "появляется",
+    "появлялась",
+    "появлялось",
+    "появлялся",
+    "появляться",
+    "поярче",
+    "пояс",
+    "пояса",
+    "поясе",
+    "пояснее",
+    "пояснений",
+    "пояснить",
+    "поясом",
+    "поят",
+    "прав",
+    "права",
+    "правам",
+    "правами",
+    "правах",
+    "правая",
+    "правда",
+    "правде",
+    "правдиво",
+    "правдой",
+    "правдоподобия",
+    "правдоподобнее",
+    "правдоподобным",
+    "правдою",
+    "правду",
+    "правды",
+    "праве",
+    "праведная",
+    "праведным",
+    "праведными",
+    "правее",
+    "правел",
+    "правил",
+    "правила",
+    "правилам",
+    "правилах",
+    "правило",
+    "правильнее",
+    "правильно",
+    "правильного",
+    "правильное",
+    "правильной",
+    "правильность",
+    "правильною",
+    "правильную",
+    "правильным",
+    "правит",
+    "правительствующему",
+    "правительствующий",
+    "правителя",
+    "правление",
+    "правнуки",
+    "право",
+    "правовед",
+    "правого",
+    "правой",
+    "правом",
+    "правому",
+    "православной",
+    "православному",
+    "православную",
+    "православный",
+    "правосудие",
+    "правою",
+    "праву",
+    "правую",
+    "правы",
+    "правые",
+    "правый",
+    "правым",
+    "прадед",
+    "прадедов",
+    "прадедушки",
+    "празден",
+    "праздная",
+    "праздник",
+    "праздника",
+    "праздникам",
+    "праздниками",
+    "праздниках",
+    "празднике",
+    "праздники",
+    "праздников",
+    "празднику",
+    "празднично",
+    "праздничные",
+    "праздничный",
+    "праздничных",
+    "праздно",
+    "праздного",
+    "праздное",
+    "праздной",
+    "празднолюбцами",
+    "праздном",
+    "праздному",
+    "праздности",
+    "праздность",
+    "праздностью",
+    "праздные",
+    "праздный",
+    "праздных",
+    "празелень",
+    "практика",
+    "практике",
+    "практики",
+    "практиковать",
+    "практику",
+    "практические",
+    "практический",
+    "практических",
+    "практического",
+    "практическое",
+    "практической",
+    "практическую",
+    "праотцам",
+    "праотцев",
+    "прасковьи",
+    "прасковью",
+    "прасковья",
+    "прах",
+    "праха",
+    "прахе",
+    "прахом",
+    "прачки",
+    "прачку",
+    "преавенантненькая",
+    "преблагополучно",
+    "пребудет",
+    "пребуду",
+    "пребывали",
+    "пребывания",
+    "пребывая",
+    "превесело",
+    "превозмогало",
+    "превозмочь",
+    "превозносили",
+    "превозносят",
+    "превосходительство",
+    "превосходительством",
+    "превосходительству",
+    "превосходная",
+    "превосходнейшем",
+    "превосходно",
+    "превосходного",
+    "превосходный",
+    "превосходными",
+    "превосходных",
+    "превосходства",
+    "превратил",
+    "превратила",
+    "превратилось",
+    "превратился",
+    "превратится",
+    "превратно",
+    "превращается",
+    "превращалась",
+    "превращался",
+    "превращать",
+    "превращаться",
+    "превращения",
+    "превышает",
+    "превышать",
+    "преглупейшею",
+    "преград",
+    "преграда",
+    "преградах",
+    "преградой",
+    "преграды",
+    "пред",
+    "предавала",
+    "предавать",
+    "предаваться",
+    "предал",
+    "предала",
+    "предам",
+    "предамся",
+    "предан",
+    "предана",
+    "предание",
+    "преданий",
+    "предания",
+    "преданиями",
+    "преданнейшая",
+    "преданной",
+    "преданности",
+    "преданность",
+    "преданностью",
+    "преданную",
+    "преданные",
+    "преданный",
+    "преданьям",
+    "преданьях",
+    "предаст",
+    "предатели",
+    "предательство",
+    "предать",
+    "предаться",
+    "предварительно",
+    "предварительные",
+    "предварительных",
+    "предвестником",
+    "предвестье",
+    "предвечном",
+    "предвещало",
+    "предвзятому",
+    "предвзятых",
+    "предвидел",
+    "предвидела",
+    "предвидели",
+    "предвиделось",
+    "предвидений",
+    "предвидения",
+    "предвидеть",
+    "предвидите",
+    "предвидится",
+    "предвидя",
+    "предвижу",
+    "предводителя",
+    "предел",
+    "предела",
+    "пределов",
+    "предзнаменование",
+    "предисловие",
+    "предки",
+    "предлагавший",
+    "предлагаем",
+    "предлагаемого",
+    "предлагает",
+    "предлагается",
+    "предлагаешь",
+    "предлагал",
+    "предлагала",
+    "предлагали",
+    "предлагать",
+    "предлагаю",
+    "предлагают",
+    "предлагая",
+    "предлог",
+    "предлога",
+    "предлогам",
+    "предлогами",
+    "предлоги",
+    "предлогом",
+    "предложат",
+    "предложен",
+    "предложение",
+    "предложением",
+    "предложении",
+    "предложений",
+    "предложению",
+    "предложения",
+    "предложениями",
+    "предложил",
+    "предложила",
+    "предложит",
+    "предложите",
+    "предложить",
+    "предложу",
+    "предместье",
+    "предмет",
+    "предмета",
+    "предметам",
+    "предметах",
+    "предмете",
+    "предметов",
+    "предметом",
+    "предмету",
+    "предметы",
+    "предназначена",
+    "предназначенное",
+    "предназначенные",
+    "преднамеренно",
+    "преднамеренном",
+    "предо",
+    "предопределение",
+    "предопределением",
+    "предорогие",
+    "предоставив",
+    "предоставить",
+    "предоставляла",
+    "предоставляю",
+    "предостерегал",
+    "предостерегаю",
+    "предостережение",
+    "предостережений",
+    "предостережения",
+    "предостеречь",
+    "предосторожности",
+    "предотвращение",
+    "предохранял",
+    "предписал",
+    "предписания",
+    "предписанную",
+    "предписанным",
+    "предписано",
+    "предписываю",
+    "предполагавшийся",
+    "предполагавшихся",
+    "предполагает",
+    "предполагаете",
+    "предполагается",
+    "предполагаешь",
+    "предполагал",
+    "предполагали",
+    "предполагались",
+    "предполагалось",
+    "предполагать",
+    "предполагая",
+    "предположение",
+    "предположением",
+    "предположении",
+    "предположения",
+    "предположено",
+    "предположи",
+    "предположив",
+    "предположили",
+    "предположите",
+    "предположительно",
+    "предположить",
+    "предпослать",
+    "предпочел",
+    "предпочитают",
+    "предпочитая",
+    "предпочла",
+    "предпочтение",
+    "предпочтет",
+    "предпримет",
+    "предпринимайте",
+    "предпринималась",
+    "предпринимаю",
+    "предпринять",
+    "предприятие",
+    "предприятием",
+    "предприятии",
+    "предприятию",
+    "предприятия",
+    "предприятьях",
+    "предрассудкам",
+    "предрассудками",
+    "предрассудках",
+    "предрассудке",
+    "предрассудки",
+    "предрассудков",
+    "предрассудок",
+    "предрассудочные",
+    "предрассудочных",
+    "предрек",
+    "председателем",
+    "председатель",
+    "председательствует",
+    "председателя",
+    "предсказал",
+    "предсказания",
+    "предсказывая",
+    "предсмертного",
+    "предсмертный",
+    "представали",
+    "представив",
+    "представившейся",
+    "представил",
+    "представила",
+    "представилась",
+    "представили",
+    "представилось",
+    "представительницею",
+    "представится",
+    "представить",
+    "представиться",
+    "представление",
+    "представления",
+    "представленный",
+    "представлю",
+    "представляем",
+    "представляет",
+    "представляетесь",
+    "представляется",
+    "представлял",
+    "представляла",
+    "представлялась",
+    "представляли",
+    "представлялись",
+    "представлялось",
+    "представлялся",
+    "представлять",
+    "представляться",
+    "представляюсь",
+    "представляя",
+    "представь",
+    "представьте",
+    "представят",
+    "предстала",
+    "предстанет",
+    "предстать",
+    "предстоит",
+    "предстоял",
+    "предстояла",
+    "предстояли",
+    "предстояло",
+    "предстоящего",
+    "предстоящее",
+    "предстоящей",
+    "предстоящим",
+    "предстоящих",
+    "предубеждение",
+    "предубеждений",
+    "предубеждения",
+    "предуведомить",
+    "предуведомлены",
+    "предугадает",
+    "предугадала",
+    "предугадывает",
+    "предуготовительном",
+    "предузнало",
+    "предупредив",
+    "предупредил",
+    "предупредила",
+    "предупредительна",
+    "предупредить",
+    "предупреждал",
+    "предупреждаю",
+    "предупреждена",
+    "предусмотрела",
+    "предусмотрительностью",
+    "предчувствие",
+    "предчувствием",
+    "предчувствий",
+    "предчувствию",
+    "предчувствия",
+    "предчувствиями",
+    "предчувствовавшая",
+    "предчувствовал",
+    "предчувствовала",
+    "предчувствовалась",
+    "предчувствовались",
+    "предчувствовалось",
+    "предчувствовать",
+    "предчувствует",
+    "предчувствую",
+    "предчувствуют",
+    "предчувствуя",
+    "предшественником",
+    "предшествовавшие",
+    "предшествовавших",
+    "предъявляет",
+    "предыдущая",
+    "предыдущего",
+    "предыдущий",
+    "предыдущим",
+    "предыдущую",
+    "прежде",
+    "преждевременно",
+    "преждевременными",
+    "прежнего",
+    "прежнее",
+    "прежней",
+    "прежнему",
+    "прежнею",
+    "прежние",
+    "прежний",
+    "прежним",
+    "прежними",
+    "прежних",
+    "прежнюю",
+    "прежняя",
+    "президентом",
+    "презиравшие",
+    "презиравших",
+    "презирает",
+    "презираете",
+    "презирал",
+    "презирала",
+    "презирали",
+    "презирать",
+    "презираю",
+    "презирают",
+    "презирающие",
+    "презирая",
+    "презревши",
+    "презрение",
+    "презрением",
+    "презрения",
+    "презренная",
+    "презренно",
+    "презренного",
+    "презренье",
+    "презренья",
+    "презрительнее",
+    "презрительно",
+    "презрительной",
+    "презрительный",
+    "презрительным",
+    "презрительных",
+    "преимуществ",
+    "преимущества",
+    "преимуществе",
+    "преимущественно",
+    "преимущество",
+    "преимуществом",
+    "преимуществу",
+    "преисподнего",
+    "преисполнена",
+    "преисполнила",
+    "преклонив",
+    "преклонила",
+    "преклонился",
+    "преклониться",
+    "преклонный",
+    "преклоняться",
+    "прекомические",
+    "прекословь",
+    "прекрасен",
+    "прекрасна",
+    "прекрасная",
+    "прекраснее",
+    "прекрасней",
+    "прекраснейшая",
+    "прекрасно",
+    "прекрасного",
+    "прекрасное",
+    "прекрасной",
+    "прекрасном",
+    "прекрасною",
+    "прекрасную",
+    "прекрасны",
+    "прекрасные",
+    "прекрасный",
+    "прекрасным",
+    "прекрасными",
+    "прекрасных",
+    "прекратив",
+    "прекратились",
+    "прекратило",
+    "прекратить",
+    "прекращалось",
+    "прекращаю",
+    "прела",
+    "прелести",
+    "прелестная",
+    "прелестней",
+    "прелестной",
+    "прелестные",
+    "прелестный",
+    "прелесть",
+    "прелестями",
+    "преломились",
+    "преломлялись",
+    "прельститься",
+    "прельщает",
+    "прелюбопытный",
+    "премило",
+    "премудрости",
+    "премудрствующие",
+    "премудрые",
+    "премудрых",
+    "пренаивно",
+    "пренебрег",
+    "пренебрегал",
+    "пренебрегать",
+    "пренебрегая",
+    "пренебрежение",
+    "пренебрежением",
+    "пренебрежения",
+    "преобладали",
+    "преобладало",
+    "преображаются",
+    "преобразилась",
+    "преобразилось",
+    "преобразований",
+    "преогромнейшему",
+    "преодолела",
+    "преодолеть",
+    "преподаванию",
+    "преподал",
+    "преподана",
+    "преподаю",
+    "препочтенный",
+    "препровождал",
+    "препровожу",
+    "препьяные",
+    "препятствие",
+    "препятствий",
+    "препятствию",
+    "препятствия",
+    "препятствиями",
+    "препятствовала",
+    "препятствуют",
+    "прервав",
+    "прервал",
+    "прервала",
+    "прервали",
+    "прервался",
+    "прерванных",
+    "прервано",
+    "прерий",
+    "прерываемый",
+    "прерывает",
+    "прерывай",
+    "прерывал",
+    "прерывался",
+    "прерывать",
+    "прерываю",
+    "прерывающее",
+    "прерывающуюся",
+    "прерывая",
+    "прерывисто",
+    "прерывистым",
+    "пресвятая",
+    "пресекла",
+    "пресеклось",
+    "пресекся",
+    "пресекшую",
+    "пресинее",
+    "прескладной",
+    "преследовал",
+    "преследовала",
+    "преследования",
+    "преследовать",
+    "преследует",
+    "преследуйте",
+    "преследуют",
+    "пресмыкаюсь",
+    "преспокойно",
+    "пресс",
+    "преставлю",
+    "престарелая",
+    "престарелому",
+    "престарелую",
+    "престоле",
+    "престранная",
+    "преступать",
+    "преступают",
+    "преступление",
+    "преступлением",
+    "преступлении",
+    "преступлений",
+    "преступлению",
+    "преступления",
+    "преступлениях",
+    "преступленьице",
+    "преступленья",
+    "преступна",
+    "преступнее",
+    "преступник",
+    "преступника",
+    "преступникам",
+    "преступниками",
+    "преступнике",
+    "преступники",
+    "преступников",
+    "преступнику",
+    "преступница",
+    "преступного",
+    "преступной",
+    "преступны",
+    "преступный",
+    "претендуете",
+    "претендующих",
+    "претендуя",
+    "претензии",
+    "претензий",
+    "претензию",
+    "претензия",
+    "претензиями",
+    "претерпели",
+    "претит",
+    "преткновения",
+    "преткнулся",
+    "претрудная",
+    "претят",
+    "преувеличенно",
+    "преувеличенного",
+    "преувеличенною",
+    "преувеличенный",
+    "преувеличено",
+    "преувеличены",
+    "преувеличиваете",
+    "преувеличивал",
+    "преувеличивающий",
+    "преувеличил",
+    "преувеличили",
+    "преувеличить",
+    "преумножать",
+    "преуспеяние",
+    "преуспеяния",
+    "прехитрейшее",
+    "прехорошенькие",
+    "пречистенки",
+    "прещено",
+    "при",
+    "прибавив",
+    "прибавил",
+    "прибавила",
+    "прибавили",
+    "прибавило",
+    "прибавилось",
+    "прибавим",
+    "прибавит",
+    "прибавить",
+    "прибавишь",
+    "прибавке",
+    "прибавкой",
+    "прибавлена",
+    "прибавление",
+    "прибавлением",
+    "прибавления",
+    "прибавлено",
+    "прибавлю",
+    "прибавляет",
+    "прибавляешь",
+    "прибавлял",
+    "прибавляла",
+    "прибавлять",
+    "прибавляю",
+    "прибавляют",
+    "прибавляются",
+    "прибавочные",
+    "прибавь",
+    "прибавьте",
+    "прибегал",
+    "прибегала",
+    "прибегают",
+    "прибегая",
+    "прибегнул",
+    "прибежавшей",
+    "прибежал",
+    "прибежала",
+    "прибежали",
+    "прибежит",
+    "приберегла",
+    "приберет",
+    "приберешь",
+    "прибившему",
+    "прибил",
+    "прибили",
+    "прибиль",
+    "прибирает",
+    "прибирали",
+    "прибирать",
+    "прибираться",
+    "прибитый",
+    "приближавшейся",
+    "приближается",
+    "приближалась",
+    "приближался",
+    "приближающееся",
+    "приближая",
+    "приближения",
+    "приблизив",
+    "приблизилась",
+    "приблизился",
+    "приблизительно",
+    "прибой",
+    "прибор",
+    "прибоя",
+    "прибрал",
+    "прибрано",
+    "прибраны",
+    "прибрать",
+    "прибрежный",
+    "прибрежья",
+    "прибудет",
+    "прибудут",
+    "прибыв",
+    "прибывает",
+    "прибывшего",
+    "прибыл",
+    "прибыли",
+    "прибытия",
+    "прибыть",
+    "прибьет",
+    "прибьют",
+    "привалило",
+    "приватном",
+    "приватным",
+    "приведенное",
+    "приведены",
+    "приведет",
+    "приведи",
+    "приведу",
+    "приведут",
+    "привез",
+    "привезет",
+    "привезите",
+    "привезла",
+    "привезли",
+    "привезти",
+    "привезть",
+    "привезу",
+    "привезут",
+    "привел",
+    "привела",
+    "привели",
+    "привело",
+    "привелось",
+    "привесить",
+    "привести",
+    "привет",
+    "приветливо",
+    "приветливым",
+    "приветные",
+    "приветствие",
+    "приветствий",
+    "приветствует",
+    "приветствую",
+    "привечали",
+    "привешены",
+    "привидение",
+    "привидений",
+    "привидения",
+    "привил",
+    "привилегию",
+    "привилегия",
+    "привить",
+    "привлек",
+    "привлекает",
+    "привлекало",
+    "привлекательном",
+    "привлекать",
+    "привлекают",
+    "привлекло",
+    "привлечь",
+    "приводил",
+    "приводила",
+    "приводили",
+    "приводит",
+    "приводить",
+    "приводят",
+    "привозили",
+    "привозом",
+    "приволье",
+    "приволью",
+    "приволья",
+    "привскочив",
+    "привскочила",
+    "привстав",
+    "привставший",
+    "привстал",
+    "привстала",
+    "привстанет",
+    "привык",
+    "привыкает",
+    "привыкали",
+    "привыкла",
+    "привыкли",
+    "привыкнет",
+    "привыкнете",
+    "привыкнешь",
+    "привыкну",
+    "привыкнуть",
+    "привыкши",
+    "привыкшим",
+    "привычек",
+    "привычка",
+    "привычками",
+    "привычках",
+    "привычке",
+    "привычки",
+    "привычкой",
+    "привычку",
+    "привычно",
+    "привычной",
+    "привычную",
+    "привычны",
+    "привычный",
+    "привычными",
+    "привяжусь",
+    "привяжутся",
+    "привязал",
+    "привязалась",
+    "привязался",
+    "привязан",
+    "привязана",
+    "привязанности",
+    "привязанность",
+    "привязанностью",
+    "привязанностям",
+    "привязаны",
+    "привязаться",
+    "привязки",
+    "привязчивее",
+    "привязываетесь",
+    "пригвожден",
+    "приглаживает",
+    "пригласил",
+    "пригласила",
+    "пригласили",
+    "пригласит",
+    "пригласите",
+    "пригласить",
+    "приглашаем",
+    "приглашает",
+    "приглашайте",
+    "приглашал",
+    "приглашала",
+    "приглашалась",
+    "приглашали",
+    "приглашаль",
+    "приглашать",
+    "приглашаю",
+    "приглашают",
+    "приглашен",
+    "приглашена",
+    "приглашение",
+    "приглашением",
+    "приглашения",
+    "приглашенных",
+    "приглашены",
+    "приглашенье",
+    "приглядеть",
+    "приглядится",
+    "приглядывалась",
+    "приглядывался",
+    "приглядываться",
+    "приглядывая",
+    "приглядываясь",
+    "приглянуть",
+    "пригнул",
+    "пригнула",
+    "пригнулись",
+    "пригнулся",
+    "приговаривал",
+    "приговаривала",
+    "приговаривая",
+    "приговарнвал",
+    "приговор",
+    "приговора",
+    "приговоренного",
+    "приговоренный",
+    "приговорили",
+    "приговоркой",
+    "приговоров",
+    "приговору",
+    "пригодилась",
+    "пригодитесь",
+    "пригодится",
+    "пригодное",
+    "пригодятся",
+    "пригорка",
+    "пригорки",
+    "пригорку",
+    "пригорок",
+    "пригорюнившись",
+    "пригорюнился",
+    "приготовившись",
+    "приготовил",
+    "приготовилась",
+    "приготовился",
+    "приготовит",
+    "приготовить",
+    "приготовиться",
+    "приготовлен",
+    "приготовление",
+    "приготовлений",
+    "приготовления",
+    "приготовлениях",
+    "приготовленный",
+    "приготовленным",
+    "приготовлено",
+    "приготовлены",
+    "приготовляй",
+    "приготовлялось",
+    "приготовлять",
+    "приготовляться",
+    "приготовляюсь",
+    "приготовляясь",
+    "приготовят",
+    "пригрезился",
+    "пригрозили",
+    "придававшая",
+    "придавал",
+    "придавала",
+    "придавали",
+    "придавало",
+    "придавил",
+    "придавили",
+    "придавило",
+    "придавим",
+    "придавить",
+    "придавлено",
+    "придавливая",
+    "придает",
+    "придаете",
+    "придам",
+    "приданный",
+    "приданого",
+    "приданое",
+    "придаст",
+    "придаток",
+    "придать",
+    "придачу",
+    "придвигая",
+    "придвинул",
+    "придвинулась",
+    "придворной",
+    "придем",
+    "придержал",
+    "придержать",
+    "придержаться",
+    "придерживает",
+    "придерживал",
+    "придерживались",
+    "придерживать",
+    "придерживаться",
+    "придерживаюсь",
+    "придерживая",
+    "придерживаясь",
+    "придет",
+    "придете",
+    "придется",
+    "придешь",
+    "приди",
+    "придираетесь",
+    "придираться",
+    "придирки",
+    "придирчив",
+    "придите",
+    "придорожной",
+    "приду",
+    "придумаем",
+    "придумает",
+    "придумай",
+    "придумал",
+    "придумала",
+    "придуманное",
+    "придуманных",
+    "придумать",
+    "придумывает",
+    "придумывай",
+    "придумывала",
+    "придумывая",
+    "придут",
+    "придутся",
+    "придя",
+    "приедем",
+    "приедет",
+    "приедете",
+    "приедешь",
+    "приеду",
+    "приедут",
+    "приезд",
+    "приезда",
+    "приезде",
+    "приездом",
+    "приезду",
+    "приезжавшие",
+    "приезжаем",
+    "приезжает",
+    "приезжай",
+    "приезжайте",
+    "приезжал",
+    "приезжала",
+    "приезжали",
+    "приезжать",
+    "приезжают",
+    "приезжая",
+    "приезжее",
+    "приезжие",
+    "приезжий",
+    "приезжих",
+    "прием",
+    "приема",
+    "приемам",
+    "приемами",
+    "приеме",
+    "приемлеши",
+    "приемлю",
+    "приемная",
+    "приемной",
+    "приемные",
+    "приемов",
+    "приему",
+    "приемы",
+    "приехав",
+    "приехавшем",
+    "приехал",
+    "приехала",
+    "приехали",
+    "приехать",
+    "прижав",
+    "прижавшись",
+    "прижал",
+    "прижала",
+    "прижалась",
+    "прижали",
+    "прижата",
+    "прижатыми",
+    "приживал",
+    "приживалы",
+    "прижила",
+    "прижимается",
+    "прижимала",
+    "прижималась",
+    "прижимался",
+    "прижимаю",
+    "прижимая",
+    "прижимаясь",
+    "прижмешь",
+    "прижмись",
+    "прижму",
+    "призадумался",
+    "призван",
+    "призвание",
+    "призванием",
+    "призвании",
+    "призванию",
+    "призванную",
+    "призвать",
+    "признав",
+    "признавай",
+    "признавайся",
+    "признавал",
+    "признавала",
+    "признавалась",
+    "признавалось",
+    "признавался",
+    "признаваться",
+    "признавая",
+    "признаваясь",
+    "признает",
+    "признаете",
+    "признаетесь",
+    "признайся",
+    "признайтесь",
+    "признак",
+    "признака",
+    "признакам",
+    "признаками",
+    "признаках",
+    "признаки",
+    "признаков",
+    "признаком",
+    "признал",
+    "призналась",
+    "признали",
+    "признался",
+    "признание",
+    "признании",
+    "признания",
+    "признаниям",
+    "признанную",
+    "признанных",
+    "признанья",
+    "признательности",
+    "признательный",
+    "признать",
+    "признаться",
+    "признаю",
+    "признаюсь",
+    "призовет",
+    "призовешь",
+    "призрак",
+    "призрака",
+    "призраками",
+    "призраках",
+    "призраки",
+    "призраков",
+    "призраку",
+    "призрачное",
+    "призрение",
+    "призрения",
+    "призыв",
+    "призывал",
+    "призывать",
+    "призывающе",
+    "призывающий",
+    "призывая",
+    "призыве",
+    "призывном",
+    "призывных",
+    "приидет",
+    "прииди",
+    "приидите",
+    "приискал",
+    "приискания",
+    "приискать",
+    "приискивать",
+    "приищете",
+    "прийти",
+    "прикажет",
+    "прикажете",
+    "прикажешь",
+    "прикажите",
+    "приказ",
+    "приказал",
+    "приказала",
+    "приказали",
+    "приказан",
+    "приказание",
+    "приказанием",
+    "приказании",
+    "приказанию",
+    "приказания",
+    "приказаниями",
+    "приказано",
+    "приказать",
+    "приказного",
+    "приказчик",
+    "приказчика",
+    "приказчики",
+    "приказчиков",
+    "приказывает",
+    "приказываете",
+    "приказывайте",
+    "приказывал",
+    "приказывала",
+    "приказывали",
+    "приказывать",
+    "приказывающего",
+    "прикапливал",
+    "прикасалось",
+    "прикасаясь",
+    "прикидываются",
+    "прикинешься",
+    "прикинулся",
+    "прикинуться",
+    "прикладывал",
+    "прикладывали",
+    "прикладывая",
+    "приклеившись",
+    "приклонить",
+    "приключалась",
+    "приключение",
+    "приключений",
+    "приковалась",
+    "приковываться",
+    "приколотить",
+    "прикомандировался",
+    "прикосновением",
+    "прикосновения",
+    "прикоснулись",
+    "прикоснулось",
+    "прикоснулся",
+    "прикоснуться",
+    "прикраситься",
+    "прикрасной",
+    "прикрепил",
+    "прикрутит",
+    "прикрыв",
+    "прикрывается",
+    "прикрывала",
+    "прикрывалось",
+    "прикрываясь",
+    "прикрыл",
+    "прикрыла",
+    "прикрыли",
+    "прикрытая",
+    "прикрытого",
+    "прикрытой",
+    "прикрытую",
+    "прикрытыми",
+    "прикрытых",
+    "прикрыть",
+    "прикупить",
+    "прикусив",
+    "прилагает",
+    "приладил",
+    "приладиться",
+    "прилаженный",
+    "прилаживалась",
+    "прилаживать",
+    "приласкав",
+    "приласкаешь",
+    "приласкать",
+    "прилег",
+    "прилегавший",
+    "прилежанием",
+    "прилежно",
+    "прилежным",
+    "прилепившись",
+    "прилепилась",
+    "прилепить",
+    "прилепливаются",
+    "прилеплялся",
+    "прилетит",
+    "прилетят",
+    "прилечь",
+    "прилив",
+    "прилива",
+    "приливало",
+    "приливам",
+    "приливе",
+    "приливом",
+    "приливы",
+    "прилип",
+    "прилипчивая",
+    "прилипшие",
+    "приличие",
+    "приличием",
+    "приличий",
+    "приличия",
+    "приличнее",
+    "приличненьким",
+    "прилично",
+    "приличного",
+    "приличной",
+    "приличном",
+    "приличною",
+    "приличную",
+    "приличные",
+    "приличный",
+    "приличным",
+    "приличных",
+    "приложа",
+    "приложат",
+    "приложена",
+    "приложением",
+    "приложи",
+    "приложив",
+    "приложил",
+    "приложила",
+    "приложить",
+    "прилуковой",
+    "прильнет",
+    "прильнув",
+    "прильнул",
+    "прильнула",
+    "прильнуть",
+    "прильпне",
+    "прилягу",
+    "приляжет",
+    "приманивала",
+    "примачивайте",
+    "примелькавшиеся",
+    "примем",
+    "применены",
+    "применилась",
+    "применить",
+    "применять",
+    "пример",
+    "примерами",
+    "примерив",
+    "примеривал",
+    "примеривая",
+    "примерит",
+    "примерить",
+    "примерно",
+    "примеров",
+    "примером",
+    "примеру",
+    "примерчик",
+    "примерчики",
+    "примеры",
+    "примеряет",
+    "примерять",
+    "примесью",
+    "примет",
+    "примета",
+    "приметам",
+    "примете",
+    "приметесь",
+    "приметил",
+    "приметила",
+    "приметить",
+    "приметишь",
+    "приметная",
+    "приметнее",
+    "приметно",
+    "приметною",
+    "приметную",
+    "приметным",
+    "приметными",
+    "примется",
+    "примету",
+    "приметы",
+    "примечал",
+    "примечательную",
+    "примечать",
+    "примешивается",
+    "примешивал",
+    "примешивалась",
+    "прими",
+    "примирении",
+    "примирения",
+    "примирительный",
+    "примирительным",
+    "примирительных",
+    "примирится",
+    "примириться",
+    "примите",
+    "примолвив",
+    "примолвить",
+    "примолк",
+    "примолкла",
+    "примолкли",
+    "примочками",
+    "примочки",
+    "приму",
+    "примут",
+    "примутся",
+    "примыкавшей",
+    "примятой",
+    "принагнувшись",
+    "принадлежавшая",
+    "принадлежавшего",
+    "принадлежавшее",
+    "принадлежавший",
+    "принадлежал",
+    "принадлежала",
+    "принадлежали",
+    "принадлежало",
+    "принадлежат",
+    "принадлежащие",
+    "принадлежащий",
+    "принадлежащих",
+    "принадлежит",
+    "принадлежите",
+    "принадлежностей",
+    "принадлежности",
+    "принадлежностью",
+    "принадлежу",
+    "принарядиться",
+    "принес",
+    "принесенного",
+    "принесенное",
+    "принесенные",
+    "принесенный",
+    "принесенных",
+    "принесено",
+    "принесены",
+    "принесет",
+    "принесете",
+    "принесешь",
+    "принеси",
+    "принесите",
+    "принесла",
+    "принесли",
+    "принесло",
+    "принести",
+    "принесу",
+    "принесут",
+    "принеся",
+    "принижающее",
+    "принижение",
+    "приниженная",
+    "приниженное",
+    "приниженную",
+    "принижено",
+    "приник",
+    "приникла",
+    "приникшей",
+    "принимавшие",
+    "принимает",
+    "принимаете",
+    "принимается",
+    "принимаешь",
+    "принимай",
+    "принимайте",
+    "принимал",
+    "принимала",
+    "принималась",
+    "принимали",
+    "принимались",
+    "принимало",
+    "принимался",
+    "принимать",
+    "приниматься",
+    "принимаю",
+    "принимаюсь",
+    "принимают",
+    "принимающим",
+    "принимая",
+    "принимаясь",
+    "приносил",
+    "приносили",
+    "приносились",
+    "приносилось",
+    "приносимые",
+    "приносит",
+    "приносить",
+    "приносят",
+    "приношением",
+    "приношу",
+    "принудив",
+    "принудила",
+    "принудили",
+    "принудить",
+    "принуждал",
+    "принужден",
+    "принуждена",
+    "принуждение",
+    "принуждений",
+    "принужденным",
+    "принц",
+    "принцип",
+    "принципах",
+    "принципиально",
+    "принципу",
+    "принципы",
+    "приняв",
+    "принявшие",
+    "принял",
+    "приняла",
+    "принялась",
+    "приняли",
+    "принялись",
+    "приняло",
+    "принялся",
+    "принят",
+    "принятая",
+    "принятию",
+    "принято",
+    "приняты",
+    "принятый",
+    "принятых",
+    "принять",
+    "приняться",
+    "приобрел",
+    "приобрела",
+    "приобрести",
+    "приобресть",
+    "приобретается",
+    "приобретал",
+    "приобретала",
+    "приобреталось",
+    "приобретался",
+    "приобретать",
+    "приобретаю",
+    "приобретают",
+    "приобретая",
+    "приобретение",
+    "приобретению",
+    "приобретенного",
+    "приобретенной",
+    "приобщались",
+    "приоделась",
+    "приостановились",
+    "приостановился",
+    "приотворена",
+    "приотворенная",
+    "приотворил",
+    "приотворилась",
+    "приоткрытой",
+    "припав",
+    "припадает",
+    "припадая",
+    "припадем",
+    "припадка",
+    "припадках",
+    "припадке",
+    "припадки",
+    "припадков",
+    "припадком",
+    "припадок",
+    "припадочек",
+    "припадочного",
+    "припадочный",
+    "припал",
+    "припала",
+    "припас",
+    "припасами",
+    "припасен",
+    "припасов",
+    "припасы",
+    "припахивает",
+    "припевах",
+    "припеваючи",
+    "припер",
+    "приписанное",
+    "приписать",
+    "приписываемые",
+    "приписывает",
+    "приписывал",
+    "приписывают",
+    "приплюснут",
+    "приподнимайтесь",
+    "приподниматься",
+    "приподнимаются",
+    "приподнимая",
+    "приподнимаясь",
+    "приподняв",
+    "приподнявшись",
+    "приподнял",
+    "приподняла",
+    "приподнялась",
+    "приподняли",
+    "приподнялись",
+    "приподнялся",
+    "приподнятые",
+    "приподнять",
+    "приподняться",
+    "припомажен",
+    "припоминаемое",
+    "припоминал",
+    "припоминала",
+    "припоминался",
+    "припоминать",
+    "припоминаться",
+    "припоминая",
+    "припомни",
+    "припомнив",
+    "припомнил",
+    "припомнила",
+    "припомнилась",
+    "припомнилось",
+    "припомнился",
+    "припомните",
+    "припомнить",
+    "припомнишь",
+    "припомню",
+    "припрыгнул",
+    "припрятал",
+    "припрятано",
+    "припрятать",
+    "припускает",
+    "припутали",
+    "припухлые",
+    "припухшее",
+    "припухшем",
+    "припухшею",
+    "припухшие",
+    "припухшими",
+    "прирастала",
+    "приращение",
+    "приревнует",
+    "природа",
+    "природе",
+    "природная",
+    "природное",
+    "природной",
+    "природным",
+    "природой",
+    "природу",
+    "природы",
+    "прирожденный",
+    "прирос",
+    "приросли",
+    "приросло",
+    "приручил",
+    "присвататься",
+    "присватывался",
+    "присвистнул",
+    "присвоить",
+    "присев",
+    "приседает",
+    "приседала",
+    "приседать",
+    "приседая",
+    "присел",
+    "присела",
+    "присесть",
+    "прискорбием",
+    "прискорбию",
+    "прислал",
+    "прислала",
+    "прислали",
+    "прислан",
+    "присланные",
+    "присланных",
+    "прислать",
+    "прислоненная",
+    "прислонил",
+    "прислонилась",
+    "прислонился",
+    "прислонясь",
+    "прислуга",
+    "прислуге",
+    "прислуги",
+    "прислугой",
+    "прислуживала",
+    "прислуживать",
+    "прислужница",
+    "прислушавшись",
+    "прислушался",
+    "прислушать",
+    "прислушивавшийся",
+    "прислушивается",
+    "прислушивалась",
+    "прислушивались",
+    "прислушивался",
+    "прислушиваться",
+    "прислушиваются",
+    "прислушиваясь",
+    "присматривать",
+    "присмиреет",
+    "присмирел",
+    "присмирела",
+    "присмирели",
+    "присмотрит",
+    "присмотрите",
+    "приснившийся",
+    "приснилась",
+    "приснились",
+    "приснилось",
+    "приснился",
+    "приснится",
+    "присовокупить",
+    "присочиним",
+    "приспела",
+    "приспособились",
+    "приспособился",
+    "приспособить",
+    "приспособлено",
+    "пристав",
+    "пристава",
+    "приставай",
+    "приставал",
+    "приставала",
+    "приставания",
+    "приставать",
+    "приставил",
+    "приставить",
+    "приставлен",
+    "приставленными",
+    "приставу",
+    "пристает",
+    "пристаешь",
+    "пристал",
+    "пристала",
+    "пристало",
+    "пристальнее",
+    "пристальней",
+    "пристально",
+    "пристальном",
+    "пристальность",
+    "пристальный",
+    "пристальным",
+    "пристальными",
+    "пристанет",
+    "пристани",
+    "пристанище",
+    "пристань",
+    "пристают",
+    "пристрастно",
+    "пристроены",
+    "пристроил",
+    "пристроили",
+    "пристроились",
+    "пристройками",
+    "пристукнуло",
+    "приступил",
+    "приступила",
+    "приступим",
+    "приступить",
+    "пристяжная",
+    "присужден",
+    "присуждено",
+    "присутственного",
+    "присутственное",
+    "присутственном",
+    "присутственные",
+    "присутственным",
+    "присутственных",
+    "присутствие",
+    "присутствием",
+    "присутствии",
+    "присутствия",
+    "присутствовавших",
+    "присутствовал",
+    "присутствовала",
+    "присутствовало",
+    "присутствовать",
+    "присутствует",
+    "присутствующим",
+    "присутствующих",
+    "присылаемые",
+    "присылает",
+    "присылал",
+    "присылали",
+    "присылать",
+    "присылки",
+    "присягнул",
+    "присягу",
+    "присядет",
+    "присяду",
+    "присядь",
+    "присядьте",
+    "присяжного",
+    "притаившись",
+    "притаились",
+    "притаился",
+    "притаиться",
+    "притащи",
+    "притащит",
+    "притащить",
+    "притащу",
+    "притаясь",
+    "притворе",
+    "притворена",
+    "притворил",
+    "притворила",
+    "притворилась",
+    "притворили",
+    "притворился",
+    "притворить",
+    "притвориться",
+    "притворной",
+    "притворным",
+    "притворства",
+    "притворство",
+    "притворщик",
+    "притворяется",
+    "притворяла",
+    "притворялись",
+    "притворяться",
+    "притворяясь",
+    "притих",
+    "притихла",
+    "притихли",
+    "притихнет",
+    "притом",
+    "притон",
+    "приторности",
+    "приторный",
+    "притрогивалась",
+    "притупились",
+    "притупленными",
+    "притуши",
+    "притягивал",
+    "притягивая",
+    "притязания",
+    "притянуть",
+    "приударил",
+    "приумножают",
+    "приуныл",
+    "приучи",
+    "приучил",
+    "приучили",
+    "прихлопнуть",
+    "прихлынувшей",
+    "прихлынуло",
+    "приход",
+    "прихода",
+    "приходи",
+    "приходившая",
+    "приходивший",
+    "приходивших",
+    "приходил",
+    "приходила",
+    "приходилась",
+    "приходили",
+    "приходило",
+    "приходилось",
+    "приходит",
+    "приходите",
+    "приходится",
+    "приходить",
+    "приходишь",
+    "приходо",
+    "приходом",
+    "приходя",
+    "приходят",
+    "приходящих",
+    "прихожая",
+    "прихожей",
+    "прихожу",
+    "прихожую",
+    "прихотей",
+    "прихоти",
+    "прихотливо",
+    "прихотливой",
+    "прихотливый",
+    "прихрамывал",
+    "прицеливается",
+    "прицепилось",
+    "прицепился",
+    "прицепиться",
+    "причалены",
+    "причастись",
+    "причастных",
+    "причащение",
+    "причем",
+    "причесал",
+    "причесалась",
+    "причесан",
+    "причесана",
+    "причесанные",
+    "причесанный",
+    "причесанным",
+    "причесать",
+    "прическа",
+    "прическе",
+    "прически",
+    "прической",
+    "причесывает",
+    "причесывал",
+    "причешутся",
+    "причин",
+    "причина",
+    "причинам",
+    "причине",
+    "причиной",
+    "причиною",
+    "причину",
+    "причины",
+    "причисленных",
+    "причислила",
+    "причисляешь",
+    "причитается",
+    "причитал",
+    "причитала",
+    "причитаньях",
+    "причитающиеся",
+    "причтется",
+    "причуд",
+    "причуды",
+    "пришедшего",
+    "пришедши",
+    "пришедшим",
+    "пришедших",
+    "пришел",
+    "пришелся",
+    "пришивать",
+    "пришивая",
+    "пришили",
+    "пришить",
+    "пришла",
+    "пришлем",
+    "пришлет",
+    "пришли",
+    "пришлись",
+    "пришлите",
+    "пришло",
+    "пришлось",
+    "пришлю",
+    "пришлют",
+    "пришоль",
+    "пришпорить",
+    "прищелкивать",
+    "прищурившись",
+    "прищурил",
+    "прищурилась",
+    "прищурился",
+    "прищуря",
+    "приют",
+    "приюта",
+    "приютиться",
+    "приютов",
+    "приятелей",
+    "приятелем",
+    "приятели",
+    "приятель",
+    "приятельница",
+    "приятельницею",
+    "приятельницы",
+    "приятельские",
+    "приятелю",
+    "приятеля",
+    "приятелям",
+    "приятелями",
+    "приятелях",
+    "приятен",
+    "приятна",
+    "приятнее",
+    "приятнейшего",
+    "приятнейшие",
+    "приятно",
+    "приятного",
+    "приятное",
+    "приятной",
+    "приятном",
+    "приятности",
+    "приятною",
+    "приятную",
+    "приятные",
+    "приятный",
+    "приятным",
+    "приятными",
+    "приятных",
+    "про",
+    "пробавляться",
+    "пробавляются",
+    "пробалтывался",
+    "пробегает",
+    "пробегал",
+    "пробегала",
+    "пробегающий",
+    "пробегая",
+    "пробежал",
+    "пробежала",
+    "пробежали",
+    "пробежало",
+    "пробежит",
+    "пробиваем",
+    "пробивается",
+    "пробивалась",
+    "пробивался",
+    "пробивать",
+    "пробившись",
+    "пробил",
+    "пробили",
+    "пробило",
+    "пробирается",
+    "пробирались",
+    "пробирался",
+    "пробираться",
+    "пробираясь",
+    "пробить",
+    "пробиться",
+    "пробка",
+    "проблеск",
+    "проблеска",
+    "пробовал",
+    "пробовала",
+    "пробовали",
+    "пробои",
+    "пробок",
+    "проболтался",
+    "проболтаться",
+    "пробор",
+    "пробормотал",
+    "пробормотала",
+    "пробормотать",
+    "пробором",
+    "пробочка",
+    "пробравшись",
+    "пробралась",
+    "пробрался",
+    "пробу",
+    "пробудет",
+    "пробудившегося",
+    "пробудился",
+    "пробудись",
+    "пробуду",
+    "пробудь",
+    "пробудясь",
+    "пробуждавшимися",
+    "пробуждались",
+    "пробуждаться",
+    "пробуждающейся",
+    "пробуждения",
+    "пробужденного",
+    "пробужденные",
+    "пробуя",
+    "пробывшее",
+    "пробыл",
+    "пробыть",
+    "провале",
+    "проваливается",
+    "провалившимся",
+    "провалилась",
+    "провалился",
+    "провались",
+    "провалиться",
+    "провалишься",
+    "провалом",
+    "провалялся",
+    "провалятся",
+    "проведать",
+    "проведем",
+    "проведена",
+    "проведение",
+    "проведенного",
+    "проведенный",
+    "проведет",
+    "проведу",
+    "проведя",
+    "провезут",
+    "провел",
+    "провела",
+    "проверил",
+    "проверить",
+    "провесная",
+    "провести",
+    "проветрись",
+    "провиантский",
+    "провиантского",
+    "провиантскому",
+    "провидел",
+    "провидение",
+    "провидением",
+    "провидения",
+    "провидит",
+    "провижу",
+    "провизии",
+    "провизию",
+    "провинциалка",
+    "провинциальной",
+    "провинциальный",
+    "провинции",
+    "провинцию",
+    "провод",
+    "провода",
+    "проводи",
+    "проводив",
+    "проводил",
+    "проводила",
+    "проводили",
+    "проводим",
+    "проводимых",
+    "проводит",
+    "проводите",
+    "проводится",
+    "проводить",
+    "провожавшего",
+    "провожает",
+    "провожай",
+    "провожайте",
+    "провожал",
+    "провожала",
+    "провожали",
+    "провожать",
+    "провожаю",
+    "провожают",
+    "провожая",
+    "провождение",
+    "провожу",
+    "провозгласил",
+    "провозили",
+    "провозит",
+    "провозишься",
+    "проволок",
+    "проворен",
+    "проворковать",
+    "проворная",
+    "проворно",
+    "проворное",
+    "проворным",
+    "проворонил",
+    "проворством",
+    "проворчав",
+    "проворчал",
+    "проворчат",
+    "проврался",
+    "провспоминаешь",
+    "проглотил",
+    "проглотить",
+    "проглочу",
+    "проглядели",
+    "проглядывала",
+    "проглядывало",
+    "проглянула",
+    "прогнал",
+    "прогнала",
+    "прогнали",
+    "прогналь",
+    "прогнать",
+    "прогневали",
+    "проговаривается",
+    "проговариваться",
+    "проговаривая",
+    "проговорено",
+    "проговорив",
+    "проговорившись",
+    "проговорил",
+    "проговорила",
+    "проговорилось",
+    "проговорился",
+    "проговорится",
+    "проговориться",
+    "проговорюсь",
+    "проголодалась",
+    "прогонами",
+    "прогоните",
+    "прогоны",
+    "прогоню",
+    "прогоняли",
+    "прогонят",
+    "прогонять",
+    "программ",
+    "программе",
+    "программу",
+    "прогресс",
+    "прогресса",
+    "прогрессивно",
+    "прогрессивного",
+    "прогрессивные",
+    "прогрессиста",
+    "прогрессистов",
+    "прогрессисты",
+    "прогрессом",
+    "прогрессу",
+    "прогуливается",
+    "прогуливаясь",
+    "прогулка",
+    "прогулках",
+    "прогулке",
+    "прогулки",
+    "прогулок",
+    "прогуляемся",
+    "прогуляла",
+    "прогуляться",
+    "продав",
+    "продавайте",
+    "продавал",
+    "продавало",
+    "продавать",
+    "продаваться",
+    "продавец",
+    "продавить",
+    "продавленном",
+    "продавца",
+    "продадут",
+    "продаем",
+    "продает",
+    "продается",
+    "продаешь",
+    "продажей",
+    "продажи",
+    "продажной",
+    "продажу",
+    "продай",
+    "продал",
+    "продала",
+    "продали",
+    "продан",
+    "проданный",
+    "продано",
+    "продаст",
+    "продать",
+    "продашь",
+    "продают",
+    "продаются",
+    "продеваемой",
+    "продеваемые",
+    "продевала",
+    "продевая",
+    "проделка",
+    "проделки",
+    "продержали",
+    "продиктует",
+    "продиктую",
+    "продиралась",
+    "продлил",
+    "продлить",
+    "продлятся",
+    "продовольствие",
+    "продовольствии",
+    "продолговатым",
+    "продолжавшему",
+    "продолжавшийся",
+    "продолжает",
+    "продолжаете",
+    "продолжается",
+    "продолжай",
+    "продолжайте",
+    "продолжал",
+    "продолжала",
+    "продолжалась",
+    "продолжали",
+    "продолжалось",
+    "продолжался",
+    "продолжать",
+    "продолжаться",
+    "продолжаю",
+    "продолжают",
+    "продолжаются",
+    "продолжая",
+    "продолжена",
+    "продолжение",
+    "продолжением",
+    "продолжения",
+    "продолженье",
+    "продолжил",
+    "продолжим",
+    "продолжительно",
+    "продолжительного",
+    "продолжительное",
+    "продолжительный",
+    "продолжительным",
+    "продолжится",
+    "продранные",
+    "продранным",
+    "продувная",
+    "продувной",
+    "продувных",
+    "продукт",
+    "продуктов",
+    "продукты",
+    "продумал",
+    "продумала",
+    "проедет",
+    "проедусь",
+    "проезда",
+    "проезду",
+    "проезжавшей",
+    "проезжавший",
+    "проезжавшую",
+    "проезжает",
+    "проезжающих",
+    "проезжая",
+    "проезжий",
+    "проект",
+    "проекты",
+    "проел",
+    "проехал",
+    "проехала",
+    "проехали",
+    "проехать",
+    "прождала",
+    "прожег",
+    "прожектор",
+    "прожжено",
+    "прожжет",
+    "проживавшего",
+    "проживаем",
+    "проживает",
+    "проживаете",
+    "проживал",
+    "проживали",
+    "проживать",
+    "проживают",
+    "проживем",
+    "проживет",
+    "проживете",
+    "проживешь",
+    "проживу",
+    "проживут",
+    "прожил",
+    "прожила",
+    "прожили",
+    "прожита",
+    "прожитой",
+    "прожиток",
+    "прожитую",
+    "прожить",
+    "прожитье",
+    "прожорливым",
+    "прожужжит",
+    "прозвал",
+    "прозвали",
+    "прозвание",
+    "прозвенели",
+    "прозвучала",
+    "прозвучали",
+    "прозвучит",
+    "прозе",
+    "прозирать",
+    "прозорлив",
+    "прозорливейший",
+    "прозоров",
+    "прозорова",
+    "прозоровых",
+    "прозрачная",
+    "прозрачное",
+    "прозрачной",
+    "прозрачный",
+    "прозрачным",
+    "прозревал",
+    "прозрения",
+    "прозреть",
+    "прозябает",
+    "прозябал",
+    "проиграл",
+    "проиграла",
+    "проигрались",
+    "проигрался",
+    "проиграно",
+    "проигрыше",
+    "произведен",
+    "произведена",
+    "произведение",
+    "произведением",
+    "произведении",
+    "произведений",
+    "произведения",
+    "произведенное",
+    "произведенный",
+    "произведено",
+    "произведет",
+    "произвел",
+    "произвела",
+    "произвели",
+    "произвело",
+    "произвести",
+    "производивший",
+    "производил",
+    "производило",
+    "производилось",
+    "производился",
+    "производит",
+    "производительности",
+    "производить",
+    "производишь",
+    "производят",
+    "произвол",
+    "произволу",
+    "произвольно",
+    "произнес",
+    "произнесенные",
+    "произнесено",
+    "произнесены",
+    "произнесла",
+    "произнести",
+    "произносили",
+    "произносимые",
+    "произносит",
+    "произносить",
+    "произнося",
+    "произносят",
+    "произойдет",
+    "произойти",
+    "произошел",
+    "произошла",
+    "произошли",
+    "произошло",
+    "происходившую",
+    "происходил",
+    "происходила",
+    "происходили",
+    "происходило",
+    "происходит",
+    "происходить",
+    "происходят",
+    "происходящее",
+    "происхождение",
+    "происхождения",
+    "происшедшим",
+    "происшедшую",
+    "происшествие",
+    "происшествием",
+    "происшествии",
+    "происшествий",
+    "происшествия",
+    "пройдем",
+    "пройдемте",
+    "пройденное",
+    "пройдет",
+    "пройдете",
+    "пройдется",
+    "пройдешь",
+    "пройди",
+    "пройдите",
+    "пройдохе",
+    "пройдохой",
+    "пройду",
+    "пройдусь",
+    "пройдут",
+    "пройдя",
+    "пройдясь",
+    "пройти",
+    "проказ",
+    "проказа",
+    "проказил",
+    "проказить",
+    "проказнику",
+    "прокаркал",
+    "прокатимся",
+    "прокатимтесь",
+    "прокатитесь",
+    "прокатиться",
+    "проклинает",
+    "проклинал",
+    "проклинала",
+    "проклинали",
+    "проклинать",
+    "проклял",
+    "проклянешь",
+    "проклясть",
+    "проклят",
+    "проклятая",
+    "проклятие",
+    "проклятием",
+    "проклятий",
+    "проклятию",
+    "проклятия",
+    "проклятиями",
+    "проклято",
+    "проклятого",
+    "проклятое",
+    "проклятой",
+    "проклятом",
+    "проклятую",
+    "прокляты",
+    "проклятые",
+    "проклятый",
+    "проклятым",
+    "проклятых",
+    "проклятье",
+    "проклятья",
+    "проконсула",
+    "прокормить",
+    "прокормиться",
+    "прокормлю",
+    "прокофий",
+    "прокофьич",
+    "прокофьича",
+    "прокофьичем",
+    "прокричал",
+    "прокричала",
+    "проку",
+    "прокурора",
+    "пролегли",
+    "пролежал",
+    "пролежала",
+    "пролежали",
+    "пролежало",
+    "пролежит",
+    "пролез",
+    "пролезайте",
+    "пролезают",
+    "пролезая",
+    "пролезть",
+    "пролей",
+    "пролетает",
+    "пролетала",
+    "пролетарий",
+    "пролетария",
+    "пролетевший",
+    "пролетевших",
+    "пролетел",
+    "пролетела",
+    "пролетит",
+    "пролетки",
+    "пролив",
+    "проливать",
+    "проливают",
+    "проливая",
+    "проливной",
+    "пролил",
+    "пролилась",
+    "пролита",
+    "пролитая",
+    "пролитую",
+    "пролог",
+    "пролога",
+    "проложат",
+    "проложил",
+    "проложить",
+    "пролюбить",
+    "промалчивал",
+    "промах",
+    "промаха",
+    "промахнулся",
+    "промаху",
+    "промедленья",
+    "промедлить",
+    "промеж",
+    "промежутками",
+    "промежутках",
+    "промежуток",
+    "промежуточная",
+    "промелькнет",
+    "промелькнувшею",
+    "промелькнула",
+    "промелькнуло",
+    "променял",
+    "променяла",
+    "променять",
+    "променяю",
+    "прометеев",
+    "промозглым",
+    "промозолил",
+    "промокший",
+    "промолвил",
+    "промолвила",
+    "промолвили",
+    "промолвить",
+    "промолчал",
+    "промолчала",
+    "промочит",
+    "промучился",
+    "промысл",
+    "промысла",
+    "промычал",
+    "промычит",
+    "промышленник",
+    "промышленниками",
+    "промышленников",
+    "промышляет",
+    "промышляют",
+    "промышляющее",
+    "промямлил",
+    "пронес",
+    "пронесет",
+    "пронесется",
+    "пронесла",
+    "пронеслась",
+    "пронесли",
+    "пронеслось",
+    "пронесся",
+    "пронесу",
+    "пронзал",
+    "пронзали",
+    "пронзающий",
+    "пронзающим",
+    "пронзенная",
+    "пронзенный",
+    "пронзенными",
+    "пронзи",
+    "пронзил",
+    "пронзили",
+    "пронзило",
+    "пронзиль",
+    "пронзительные",
+    "пронзительным",
+    "пронзительных",
+    "пронизан",
+    "пронизывает",
+    "проник",
+    "проникал",
+    "проникать",
+    "проникающим",
+    "проникло",
+    "проникнет",
+    "проникнув",
+    "проникнут",
+    "проникнута",
+    "проникнуты",
+    "проникнуть",
+    "проникся",
+    "проникшись",
+    "проницал",
+    "проницательнее",
+    "проницательного",
+    "проницательности",
+    "проницательным",
+    "проницающим",
+    "проносили",
+    "проносилось",
+    "проносит",
+    "проносить",
+    "проносящейся",
+    "пронюхал",
+    "пронюхают",
+    "пропаганд",
+    "пропаганде",
+    "пропагандировать",
+    "пропагандного",
+    "пропадает",
+    "пропадай",
+    "пропадал",
+    "пропадала",
+    "пропадали",
+    "пропадало",
+    "пропадать",
+    "пропадают",
+    "пропадающие",
+    "пропадающих",
+    "пропадет",
+    "пропадете",
+    "пропадешь",
+    "пропаду",
+    "пропадут",
+    "пропал",
+    "пропала",
+    "пропали",
+    "пропало",
+    "пропастей",
+    "пропасти",
+    "пропасть",
+    "пропастью",
+    "пропастями",
+    "пропастях",
+    "пропащего",
+    "пропащий",
+    "пропев",
+    "пропекать",
+    "пропел",
+    "пропела",
+    "пропели",
+    "пропеллер",
+    "пропетая",
+    "пропивал",
+    "пропил",
+    "прописала",
+    "прописанной",
+    "прописываешь",
+    "пропитан",
+    "пропитанном",
+    "пропитано",
+    "пропитаны",
+    "проплакала",
+    "проплывет",
+    "проплывший",
+    "проплыли",
+    "проповедовал",
+    "проповедовали",
+    "проповедует",
+    "проповедуется",
+    "пропоет",
+    "проползет",
+    "пропускал",
+    "пропускающем",
+    "пропускающим",
+    "пропуская",
+    "пропусти",
+    "пропустив",
+    "пропустил",
+    "пропустили",
+    "пропустит",
+    "пропустить",
+    "пропустят",
+    "пропущенная",
+    "пропыленные",
+    "пропьет",
+    "пропятие",
+    "прорва",
+    "прорвалось",
+    "прорвался",
+    "проревел",
+    "прорезывались",
+    "прорепетировать",
+    "прореха",
+    "прорехою",
+    "прореху",
+    "пророк",
+    "пророка",
+    "пророком",
+    "проронил",
+    "проронила",
+    "проронит",
+    "проронить",
+    "пророчат",
+    "пророческая",
+    "пророчески",
+    "пророческий",
+    "пророчества",
+    "пророчество",
+    "пророчит",
+    "прорубил",
+    "прорывает",
+    "прорывами",
+    "прорывать",
+    "прорыве",
+    "просватали",
+    "просветился",
+    "просветите",
+    "просветлевшее",
+    "просветлеет",
+    "просветлели",
+    "просветлении",
+    "просвечивалось",
+    "просвещение",
+    "просвещению",
+    "просвещения",
+    "просвещенной",
+    "просвещенный",
+    "просвещенья",
+    "просвирне",
+    "просеванье",
+    "проседью",
+    "просеет",
+    "проселки",
+    "проселок",
+    "просидев",
+    "просидел",
+    "просидела",
+    "просидели",
+    "просидеть",
+    "просиди",
+    "просиживала",
+    "просиживаю",
+    "просил",
+    "просила",
+    "просилась",
+    "просили",
+    "просился",
+    "просиль",
+    "просим",
+    "просимой",
+    "просипел",
+    "просит",
+    "просите",
+    "просителей",
+    "просители",
+    "просительных",
+    "проситесь",
+    "просится",
+    "просить",
+    "просишь",
+    "просияла",
+    "проскакать",
+    "проскакивать",
+    "просквозит",
+    "проскользнет",
+    "проскользнул",
+    "проскользнула",
+    "проскользнуть",
+    "проскрежетал",
+    "проскучает",
+    "прославить",
+    "прославленный",
+    "проследил",
+    "проследовали",
+    "прослезились",
+    "прослезился",
+    "прослезится",
+    "прослужил",
+    "прослужили",
+    "прослужит",
+    "прослушал",
+    "прослушали",
+    "прослушать",
+    "прослышал",
+    "прослышали",
+    "прослышать",
+    "просмотрел",
+    "просмотреть",
+    "просмотришь",
+    "проснетесь",
+    "проснется",
+    "проснешься",
+    "проснись",
+    "проснитесь",
+    "проснувшаяся",
+    "проснувшегося",
+    "проснувшиеся",
+    "проснувшись",
+    "проснулась",
+    "проснулись",
+    "проснулся",
+    "проснусь",
+    "проснуться",
+    "просо",
+    "проспал",
+    "проспали",
+    "проспать",
+    "проспект",
+    "проспекта",
+    "проспекте",
+    "проспекту",
+    "просроченное",
+    "просроченные",
+    "просроченный",
+    "просрочить",
+    "прост",
+    "проста",
+    "простаковых",
+    "простая",
+    "простегивала",
+    "простейшей",
+    "простейшем",
+    "простейшим",
+    "простенке",
+    "простенькое",
+    "простер",
+    "простерла",
+    "простерт",
+    "простертой",
+    "простерши",
+    "прости",
+    "простившись",
+    "простил",
+    "простила",
+    "простили",
+    "простились",
+    "простился",
+    "простим",
+    "простирает",
+    "простиралось",
+    "простирать",
+    "простирая",
+    "простит",
+    "простите",
+    "простительно",
+    "проститутка",
+    "простить",
+    "проститься",
+    "простишь",
+    "просто",
+    "простоват",
+    "простоватого",
+    "простоватость",
+    "простоватый",
+    "простоволосая",
+    "простоволосые",
+    "простого",
+    "простодушие",
+    "простодушием",
+    "простодушия",
+    "простодушно",
+    "простодушное",
+    "простодушные",
+    "простодушными",
+    "простое",
+    "простой",
+    "простокваши",
+    "простоквашу",
+    "простолюдин",
+    "простом",
+    "простонал",
+    "простонало",
+    "простонародный",
+    "простонародье",
+    "простонет",
+    "простор",
+    "простора",
+    "просторе",
+    "просторно",
+    "просторное",
+    "просторную",
+    "просторные",
+    "просторный",
+    "простору",
+    "простота",
+    "простоте",
+    "простотой",
+    "простотою",
+    "простоту",
+    "простоты",
+    "простою",
+    "простоял",
+    "простояла",
+    "простоять",
+    "пространным",
+    "пространств",
+    "пространства",
+    "пространство",
+    "пространствовал",
+    "прострелено",
+    "прострет",
+    "простуда",
+    "простудилась",
+    "простудился",
+    "простудитесь",
+    "простудится",
+    "простудишь",
+    "простудишься",
+    "простуды",
+    "простуживайся",
+    "простужусь",
+    "проступали",
+    "проступок",
+    "простучат",
+    "просты",
+    "простые",
+    "простыл",
+    "простыла",
+    "простым",
+    "простыми",
+    "простыне",
+    "простыней",
+    "простыни",
+    "простыню",
+    "простыня",
+    "простынях",
+    "простых",
+    "простят",
+    "просунется",
+    "просунул",
+    "просунулась",
+    "просунуть",
+    "просушить",
+    "просыпается",
+    "просыпаешься",
+    "просыпалась",
+    "просыпали",
+    "просыпались",
+    "просыпалось",
+    "просыпался",
+    "просыпаться",
+    "просыпаюсь",
+    "просыпаются",
+    "просыпающегося",
+    "просыплю",
+    "просьб",
+    "просьба",
+    "просьбами",
+    "просьбе",
+    "просьбица",
+    "просьбой",
+    "просьбу",
+    "просьбы",
+    "прося",
+    "просясь",
+    "просят",
+    "просятся",
+    "протанцевал",
+    "протанцевала",
+    "протанцевать",
+    "протащила",
+    "протекал",
+    "протекли",
+    "протекция",
+    "протекших",
+    "протер",
+    "протереть",
+    "протерпела",
+    "протертые",
+    "протеснилась",
+    "протеснились",
+    "протеснился",
+    "протеснявшихся",
+    "протеснятся",
+    "протесняясь",
+    "протест",
+    "протестовал",
+    "протестованное",
+    "протестовать",
+    "протестом",
+    "протестуй",
+    "протестую",
+    "протесты",
+    "против",
+    "противен",
+    "противиться",
+    "противная",
+    "противнее",
+    "противник",
+    "противника",
+    "противником",
+    "противно",
+    "противное",
+    "противном",
+    "противны",
+    "противный",
+    "противным",
+    "противных",
+    "противоестественном",
+    "противоположная",
+    "противоположного",
+    "противоположной",
+    "противоположном",
+    "противоположность",
+    "противоположную",
+    "противоположные",
+    "противоположный",
+    "противоположными",
+    "противопоставить",
+    "противоречие",
+    "противоречии",
+    "противоречий",
+    "противоречило",
+    "противоречит",
+    "противоречить",
+    "противоречия",
+    "противоречу",
+    "противоречь",
+    "противуположную",
+    "противься",
+    "протискиваясь",
+    "протиснулся",
+    "проткнет",
+    "протокол",
+    "протолкнул",
+    "протолкуешь",
+    "протопопов",
+    "протопопова",
+    "протопоповым",
+    "протоптанной",
+    "проторила",
+    "протягивает",
+    "протягивай",
+    "протягивайте",
+    "протягивал",
+    "протягивала",
+    "протягивались",
+    "протягивая",
+    "протяжен",
+    "протяжно",
+    "протяжный",
+    "протянет",
+    "протянешь",
+    "протяните",
+    "протяну",
+    "протянув",
+    "протянувшеюся",
+    "протянувшиеся",
+    "протянул",
+    "протянула",
+    "протянулись",
+    "протянулся",
+    "протянут",
+    "протянута",
+    "протянутая",
+    "протянутой",
+    "протянутою",
+    "протянутую",
+    "протянутый",
+    "протянутыми",
+    "протянуть",
+    "протянуться",
+    "проулок",
+    "профессор",
+    "профессора",
+    "профессоров",
+    "профессором",
+    "профессорскую",
+    "профессору",
+    "профессоршу",
+    "профилем",
+    "профиль",
+    "профиля",
+    "прохаживается",
+    "прохаживался",
+    "прохаживаться",
+    "прохватила",
+    "прохватывали",
+    "прохлада",
+    "прохладная",
+    "прохладно",
+    "прохладной",
+    "прохладные",
+    "прохладный",
+    "прохладных",
+    "прохладой",
+    "прохлады",
+    "проход",
+    "прохода",
+    "проходи",
+    "проходившего",
+    "проходившей",
+    "проходившему",
+    "проходил",
+    "проходила",
+    "проходили",
+    "проходило",
+    "проходимцев",
+    "проходит",
+    "проходите",
+    "проходить",
+    "проходишь",
+    "проходная",
+    "проходной",
+    "проходу",
+    "проходя",
+    "проходят",
+    "проходящих",
+    "прохожего",
+    "прохожей",
+    "прохожем",
+    "прохожему",
+    "прохожие",
+    "прохожий",
+    "прохожим",
+    "прохожих",
+    "прохожу",
+    "прохора",
+    "прохрипел",
+    "процветал",
+    "процветала",
+    "процветают",
+    "процедил",
+    "процедур",
+    "процеживая",
+    "процент",
+    "процентами",
+    "процентные",
+    "процентов",
+    "процентщица",
+    "процентщицу",
+    "процентщицы",
+    "проценты",
+    "процесс",
+    "процесса",
+    "процессе",
+    "процессии",
+    "процессия",
+    "процессом",
+    "процессу",
+    "проч",
+    "проча",
+    "прочего",
+    "прочее",
+    "прочел",
+    "прочесть",
+    "прочие",
+    "прочий",
+    "прочим",
+    "прочими",
+    "прочитав",
+    "прочитайте",
+    "прочитал",
+    "прочитанного",
+    "прочитанное",
+    "прочитано",
+    "прочитать",
+    "прочитают",
+    "прочитывал",
+    "прочих",
+    "прочла",
+    "прочно",
+    "прочного",
+    "прочной",
+    "прочность",
+    "прочтем",
+    "прочтении",
+    "прочтения",
+    "прочтет",
+    "прочтете",
+    "прочтешь",
+    "прочти",
+    "прочтите",
+    "прочту",
+    "прочтя",
+    "прочь",
+    "прошагнул",
+    "прошедшая",
+    "прошедшего",
+    "прошедшее",
+    "прошедшем",
+    "прошедшие",
+    "прошедших",
+    "прошедшую",
+    "прошел",
+    "прошелся",
+    "прошептал",
+    "прошептала",
+    "прошептались",
+    "прошибет",
+    "прошипел",
+    "прошла",
+    "прошли",
+    "прошло",
+    "прошлого",
+    "прошлогоднего",
+    "прошлогодний",
+    "прошлогодним",
+    "прошлогодних",
+    "прошлое",
+    "прошлой",
+    "прошлом",
+    "прошлому",
+    "прошлую",
+    "прошлый",
+    "прошлым",
+    "прошлых",
+    "прошлялся",
+    "прошмыгнул",
+    "прошмыгнуть",
+    "прошу",
+    "прошумевшего",
+    "прошумела",
+    "прощаетесь",
+    "прощается",
+    "прощай",
+    "прощайте",
+    "прощал",
+    "прощали",
+    "прощались",
+    "прощальный",
+    "прощальным",
+    "прощание",
+    "прощании",
+    "прощанию",
+    "прощанье",
+    "прощаться",
+    "прощаю",
+    "прощаюсь",
+    "прощают",
+    "прощаются",
+    "прощая",
+    "прощаясь",
+    "проще",
+    "прощение",
+    "прощении",
+    "прощения",
+    "прощено",
+    "прощенья",
+    "прощипаны",
+    "прощу",
+    "проявилась",
+    "проявилось",
+    "проявить",
+    "проявление",
+    "проявлению",
+    "проявления",
+    "проявлениями",
+    "проявляется",
+    "проявляла",
+    "проявлялась",
+    "проявлялись",
+    "проявлялось",
+    "прояснел",
+    "прояснели",
+    "прояснившийся",
+    "прояснилось",
+    "прояснить",
+    "проясняет",
+    "прояснялось",
+    "прояснялся",
+    "пруд",
+    "пруда",
+    "прудами",
+    "пруде",
+    "прудов",
+    "прудовой",
+    "пруды",
+    "пружин",
+    "пружинами",
+    "пружинах",
+    "пружиной",
+    "пружины",
+    "прусская",
+    "прусскую",
+    "прыг",
+    "прыгавший",
+    "прыгает",
+    "прыгай",
+    "прыгал",
+    "прыгала",
+    "прыгали",
+    "прыгало",
+    "прыганье",
+    "прыгать",
+    "прыгают",
+    "прыгая",
+    "прыгнул",
+    "прыгнуть",
+    "прыжка",
+    "прыжком",
+    "прыжок",
+    "прынцессы",
+    "прыскается",
+    "прыснет",
+    "прыснула",
+    "прыснуть",
+    "прыть",
+    "прытью",
+    "прядет",
+    "прядь",
+    "пряжку",
+    "прям",
+    "прямехонько",
+    "прямились",
+    "прямо",
+    "прямого",
+    "прямодушии",
+    "прямодушия",
+    "прямодушный",
+    "прямое",
+    "прямой",
+    "прямому",
+    "прямою",
+    "прямую",
+    "прямые",
+    "прямым",
+    "прямыми",
+    "прямых",
+    "пряничного",
+    "пряно",
+    "пряное",
+    "пряности",
+    "прянул",
+    "пряный",
+    "прятал",
+    "прятала",
+    "пряталась",
+    "прятались",
+    "прятался",
+    "прятать",
+    "прятаться",
+    "пряча",
+    "прячась",
+    "прячет",
+    "прячется",
+    "прячешь",
+    "прячут",
+    "прячутся",
+    "прячущегося",
+    "пса",
+    "псами",
+    "психически",
+    "психоз",
+    "психологии",
+    "психологическая",
+    "психологически",
+    "психологические",
+    "психологический",
+    "психологическим",
+    "психологическое",
+    "психологической",
+    "психологическую",
+    "психологию",
+    "психология",
+    "психологов",
+    "психологом",
+    "психопат",
+    "псом",
+    "псу",
+    "псы",
+    "птенцов",
+    "птиц",
+    "птица",
+    "птицами",
+    "птицах",
+    "птице",
+    "птицей",
+    "птицу",
+    "птицы",
+    "птичек",
+    "птичий",
+    "птичка",
+    "птички",
+    "птичку",
+    "птичницы",
+    "птичьего",
+    "птичьем",
+    "птичьи",
+    "пуантов",
+    "публика",
+    "публике",
+    "публики",
+    "публикой",
+    "публику",
+    "публичная",
+    "публичного",
+    "публичное",
+    "публичной",
+    "публичную",
+    "публичные",
+    "публичный",
+    "публичных",
+    "пугает",
+    "пугаете",
+    "пугается",
+    "пугаешь",
+    "пугай",
+    "пугайся",
+    "пугайтесь",
+    "пугал",
+    "пугала",
+    "пугали",
+    "пугало",
+    "пугался",
+    "пугать",
+    "пугаться",
+    "пугаюсь",
+    "пугают",
+    "пугающая",
+    "пугающий",
+    "пугающим",
+    "пугаясь",
+    "пугливая",
+    "пугливее",
+    "пугливо",
+    "пугливого",
+    "пугливые",
+    "пугливых",
+    "пуговиц",
+    "пуговицами",
+    "пуговицы",
+    "пуговкой",
+    "пуд",
+    "пуда",
+    "пудами",
+    "пудинг",
+    "пудов",
+    "пудрится",
+    "пудрой",
+    "пужливые",
+    "пузатый",
+    "пузатых",
+    "пузырек",
+    "пузырь",
+    "пуль",
+    "пульс",
+    "пульса",
+    "пульхерией",
+    "пульхерии",
+    "пульхерию",
+    "пульхерия",
+    "пулю",
+    "пуля",
+    "пулями",
+    "пулярку",
+    "пункт",
+    "пункта",
+    "пунктам",
+    "пунктах",
+    "пункте",
+    "пунктов",
+    "пунктом",
+    "пункту",
+    "пункты",
+    "пуншем",
+    "пуншу",
+    "пуп",
+    "пуританский",
+    "пурпурово",
+    "пускает",
+    "пускаете",
+    "пускаешь",
+    "пускай",
+    "пускайте",
+    "пускал",
+    "пускала",
+    "пускалась",
+    "пускали",
+    "пускался",
+    "пускать",
+    "пускаться",
+    "пускают",
+    "пуст",
+    "пуста",
+    "пустая",
+    "пустейшем",
+    "пустейших",
+    "пусти",
+    "пустив",
+    "пустил",
+    "пустила",
+    "пустилась",
+    "пустили",
+    "пустились",
+    "пустило",
+    "пустился",
+    "пустим",
+    "пустит",
+    "пустите",
+    "пустить",
+    "пуститься",
+    "пусто",
+    "пустого",
+    "пустое",
+    "пустой",
+    "пустом",
+    "пустому",
+    "пустота",
+    "пустоте",
+    "пустоту",
+    "пустоты",
+    "пустоши",
+    "пустошь",
+    "пустою",
+    "пустую",
+    "пусты",
+    "пустые",
+    "пустым",
+    "пустыми",
+    "пустыне",
+    "пустыней",
+    "пустыни",
+    "пустынна",
+    "пустынного",
+    "пустынной",
+    "пустынном",
+    "пустынную",
+    "пустынные",
+    "пустынный",
+    "пустынным",
+    "пустынь",
+    "пустыню",
+    "пустырей",
+    "пустырем",
+    "пустырь",
+    "пустых",
+    "пусть",
+    "пустякам",
+    "пустяками",
+    "пустяках",
+    "пустяки",
+    "пустяков",
+    "пустят",
+    "пустячков",
+    "пустяшная",
+    "пустяшное",
+    "пустяшным",
+    "путал",
+    "путали",
+    "путались",
+    "путаница",
+    "путаницу",
+    "путанно",
+    "путаться",
+    "путаю",
+    "путаются",
+    "путая",
+    "путеводителем",
+    "путеводительный",
+    "путеводной",
+    "путей",
+    "путем",
+    "путешественники",
+    "путешественников",
+    "путешествие",
+    "путешествии",
+    "путешествий",
+    "путешествия",
+    "путешествиях",
+    "путешествует",
+    "пути",
+    "путилки",
+    "путилкой",
+    "путилку",
+    "путник",
+    "путнику",
+    "путное",
+    "путной",
+    "путь",
+    "путями",
+    "путях",
+    "пуф",
+    "пух",
+    "пуха",
+    "пухленькие",
+    "пухлое",
+    "пухлых",
+    "пуховые",
+    "пуховым",
+    "пухом",
+    "пучат",
+    "пучине",
+    "пучину",
+    "пучком",
+    "пушечный",
+    "пушистые",
+    "пушистый",
+    "пушка",
+    "пушки",
+    "пушкин",
+    "пушкина",
+    "пушкинское",
+    "пушкиным",
+    "пушкиньянца",
+    "пушку",
+    "пушок",
+    "пущай",
+    "пуще",
+    "пущенное",
+    "пущенными",
+    "пущу",
+    "пхайло",
+    "пчелка",
+    "пчеловодство",
+    "пчелы",
+    "пшеница",
+    "пшеницын",
+    "пшеницына",
+    "пшеницыной",
+    "пшеницыну",
+    "пшеницыных",
+    "пшла",
+    "пшол",
+    "пыжикова",
+    "пыл",
+    "пылает",
+    "пылал",
+    "пылала",
+    "пылают",
+    "пылающим",
+    "пылающими",
+    "пылая",
+    "пыли",
+    "пылили",
+    "пылинки",
+    "пылинку",
+    "пылким",
+    "пылко",
+    "пылкой",
+    "пылом",
+    "пылу",
+    "пыль",
+    "пыльная",
+    "пыльного",
+    "пыльной",
+    "пыльном",
+    "пыльную",
+    "пыльные",
+    "пыльный",
+    "пыльными",
+    "пылью",
+    "пылят",
+    "пытала",
+    "пытался",
+    "пытая",
+    "пытаясь",
+    "пытка",
+    "пытки",
+    "пыткой",
+    "пытку",
+    "пытливо",
+    "пытливого",
+    "пытливости",
+    "пытливый",
+    "пыток",
+    "пыхтя",
+    "пышная",
+    "пышнейшее",
+    "пышно",
+    "пышной",
+    "пышном",
+    "пышную",
+    "пышные",
+    "пышных",
+    "пышут",
+    "пьан",
+    "пьедестал",
+    "пьем",
+    "пьер",
+    "пьеса",
+    "пьесах",
+    "пьесе",
+    "пьесу",
+    "пьесы",
+    "пьет",
+    "пьете",
+    "пьешь",
+    "пью",
+    "пьют",
+    "пьющей",
+    "пьющий",
+    "пьющих",
+    "пьян",
+    "пьяна",
+    "пьяная",
+    "пьяненькие",
+    "пьяненький",
+    "пьяненькой",
+    "пьянею",
+    "пьянея",
+    "пьяниц",
+    "пьяница",
+    "пьяницей",
+    "пьяницу",
+    "пьяницы",
+    "пьяного",
+    "пьяной",
+    "пьяном",
+    "пьяному",
+    "пьянства",
+    "пьянство",
+    "пьянствовал",
+    "пьянствовать",
+    "пьянствует",
+    "пьянствуй",
+    "пьяную",
+    "пьяны",
+    "пьяные",
+    "пьяный",
+    "пьяным",
+    "пьяных",
+    "пэб",
+    "пялит",
+    "пялить",
+    "пясту",
+    "пятак",
+    "пятака",
+    "пятаками",
+    "пятаки",
+    "пятаков",
+    "пятам",
+    "пятачка",
+    "пятачку",
+    "пятачок",
+    "пятая",
+    "пятен",
+    "пятеро",
+    "пяти",
+    "пятидесяти",
+    "пятидесятилетнему",
+    "пятидесятилетнею",
+    "пятидесятирублевую",
+    "пятидесятью",
+    "пятилетнего",
+    "пятилетняя",
+    "пятиминутным",
+    "пятипроцентные",
+    "пятипроцентный",
+    "пятипроцентных",
+    "пятипудовая",
+    "пятисот",
+    "пятистах",
+    "пятить",
+    "пятиэтажного",
+    "пятками",
+    "пятки",
+    "пятна",
+    "пятнадцати",
+    "пятнадцатилетнюю",
+    "пятнадцатом",
+    "пятнадцать",
+    "пятнам",
+    "пятнами",
+    "пятнах",
+    "пятница",
+    "пятнице",
+    "пятницу",
+    "пятницы",
+    "пятно",
+    "пятном",
+    "пятнышка",
+    "пятнышко",
+    "пятого",
+    "пятое",
+    "пятой",
+    "пятом",
+    "пяточками",
+    "пятою",
+    "пятую",
+    "пятый",
+    "пять",
+    "пятьдесят",
+    "пятьсот",
+    "пятью",
+    "пятясь",
+    "р",
+    "ра",
+    "раб",
+    "раба",
+    "рабам",
+    "рабами",
+    "рабой",
+    "рабом",
+    "работ",
+    "работа",
+    "работавшая",
+    "работавши",
+    "работавший",
+    "работаем",
+    "работает",
+    "работаете",
+    "работаешь",
+    "работай",
+    "работал",
+    "работала",
+    "работали",
+    "работам",
+    "работами",
+    "работать",
+    "работах",
+    "работаю",
+    "работают",
+    "работе",
+    "работник",
+    "работника",
+    "работниках",
+    "работники",
+    "работников",
+    "работнику",
+    "работой",
+    "работу",
+    "работы",
+    "рабочие",
+    "рабочий",
+    "рабочим",
+    "рабочих",
+    "рабочую",
+    "рабская",
+    "рабски",
+    "рабских",
+    "рабстве",
+    "рабы",
+    "равенна",
+    "равеннских",
+    "равенства",
+    "равенство",
+    "равна",
+    "равнина",
+    "равнины",
+    "равно",
+    "равновесие",
+    "равновесии",
+    "равновесия",
+    "равнодушен",
+    "равнодушие",
+    "равнодушием",
+    "равнодушии",
+    "равнодушию",
+    "равнодушия",
+    "равнодушна",
+    "равнодушная",
+    "равнодушно",
+    "равнодушного",
+    "равнодушное",
+    "равнодушны",
+    "равнодушный",
+    "равное",
+    "равномерно",
+    "равносильное",
+    "равны",
+    "равным",
+    "равных",
+    "равняется",
+    "равняла",
+    "равняли",
+    "равнять",
+    "равняю",
+    "рагулиным",
+    "рад",
+    "рада",
+    "радехонек",
+    "ради",
+    "радикально",
+    "радикальный",
+    "радиус",
+    "радищев",
+    "радо",
+    "радовавшемуся",
+    "радовались",
+    "радовался",
+    "радоваться",
+    "радостей",
+    "радости",
+    "радостн",
+    "радостная",
+    "радостно",
+    "радостного",
+    "радостном",
+    "радостный",
+    "радостным",
+    "радостных",
+    "радость",
+    "радостью",
+    "радостями",
+    "радостях",
+    "радуется",
+    "радуешься",
+    "радужного",
+    "радужные",
+    "радушие",
+    "радушием",
+    "радушия",
+    "радушно",
+    "радушный",
+    "радуюсь",
+    "радуясь",
+    "рады",
+    "раз",
+    "раза",
+    "разбавлять",
+    "разбегаются",
+    "разбей",
+    "разберет",
+    "разберешь",
+    "разбери",
+    "разберу",
+    "разберут",
+    "разберутся",
+    "разбивал",
+    "разбивалась",
+    "разбиваются",
+    "разбивающее",
+    "разбившимся",
+    "разбил",
+    "разбила",
+    "разбилась",
+    "разбили",
+    "разбился",
+    "разбирал",
+    "разбирала",
+    "разбиранья",
+    "разбирать",
+    "разбирая",
+    "разбит",
+    "разбито",
+    "разбитого",
+    "разбитое",
+    "разбитой",
+    "разбитою",
+    "разбитую",
+    "разбитым",
+    "разбить",
+    "разблаготворились",
+    "разбогатевший",
+    "разбогател",
+    "разбогатеть",
+    "разбой",
+    "разбойник",
+    "разбойника",
+    "разбойниках",
+    "разбойники",
+    "разбойников",
+    "разбойником",
+    "разбойничий",
+    "разбойную",
+    "разболелась",
+    "разболелся",
+    "разболтал",
+    "разболтали",
+    "разболтался",
+    "разбор",
+    "разборчив",
+    "разборы",
+    "разбранил",
+    "разбрасывается",
+    "разбредутся",
+    "разбрелись",
+    "разбросала",
+    "разбросались",
+    "разбросанные",
+    "разбросанный",
+    "разбросанных",
+    "разбросано",
+    "разбросаны",
+    "разбуди",
+    "разбудил",
+    "разбудила",
+    "разбудили",
+    "разбудило",
+    "разбудит",
+    "разбудить",
+    "разбудишь",
+    "разваливается",
+    "развалившуюся",
+    "развалилась",
+    "развалились",
+    "развалилось",
+    "развалился",
+    "развалинами",
+    "развалинах",
+    "развалине",
+    "разве",
+    "разведу",
+    "разведывать",
+    "развел",
+    "развели",
+    "развелось",
+    "разверзается",
+    "разверзалась",
+    "развернув",
+    "развернувший",
+    "развернул",
+    "развернула",
+    "развернулись",
+    "развернутое",
+    "развернутую",
+    "развернуты",
+    "развернутые",
+    "развернуть",
+    "развертывает",
+    "развертывая",
+    "развеселившись",
+    "развеселился",
+    "развеселить",
+    "развеселясь",
+    "развести",
+    "развешивает",
+    "развешивал",
+    "развешивать",
+    "развивает",
+    "развиваете",
+    "развивается",
+    "развивал",
+    "развивалась",
+    "развивалось",
+    "развивать",
+    "развиваю",
+    "развиваются",
+    "развившись",
+    "развилась",
+    "развились",
+    "развился",
+    "развинтился",
+    "развит",
+    "развитие",
+    "развитием",
+    "развитии",
+    "развитий",
+    "развитию",
+    "развития",
+    "развитой",
+    "развитость",
+    "развитый",
+    "развитым",
+    "развить",
+    "развлекайтесь",
+    "развлекать",
+    "развлекись",
+    "развлечения",
+    "развлечь",
+    "разводить",
+    "разводя",
+    "развозимыми",
+    "разворчатся",
+    "разврат",
+    "разврата",
+    "разврате",
+    "развратен",
+    "развратник",
+    "развратника",
+    "развратничает",
+    "развратной",
+    "развратный",
+    "развратным",
+    "развратятся",
+    "развращение",
+    "развращенный",
+    "развяжешься",
+    "развяжите",
+    "развязал",
+    "развязала",
+    "развязался",
+    "развязаны",
+    "развязать",
+    "развязен",
+    "развязка",
+    "развязке",
+    "развязки",
+    "развязку",
+    "развязная",
+    "развязнее",
+    "развязно",
+    "развязности",
+    "развязным",
+    "развязных",
+    "развязывать",
+    "разгадал",
+    "разгадать",
+    "разгадка",
+    "разгадке",
+    "разгадки",
+    "разгадку",
+    "разгаре",
+    "разгибать",
+    "разгладились",
+    "разгладился",
+    "разглаживается",
+    "разглядев",
+    "разглядел",
+    "разглядела",
+    "разглядели",
+    "разглядеть",
+    "разгляди",
+    "разглядит",
+    "разглядишь",
+    "разглядывавший",
+    "разглядывает",
+    "разглядываете",
+    "разглядывал",
+    "разглядывать",
+    "разглядывая",
+    "разгневанная",
+    "разговаривавшие",
+    "разговаривает",
+    "разговаривал",
+    "разговаривала",
+    "разговаривали",
+    "разговаривать",
+    "разговаривают",
+    "разговаривая",
+    "разговеемся",
+    "разговенья",
+    "разговор",
+    "разговора",
+    "разговорам",
+    "разговорами",
+    "разговорах",
+    "разговоре",
+    "разговорился",
+    "разговоров",
+    "разговором",
+    "разговору",
+    "разговорчивым",
+    "разговоры",
+    "разговорят",
+    "разгонял",
+    "разгорался",
+    "разгоревшееся",
+    "разгорелось",
+    "разгорится",
+    "разгороженную",
+    "разгорячаясь",
+    "разгорячились",
+    "разгорячился",
+    "разгула",
+    "разгуливала",
+    "разгульная",
+    "разгульного",
+    "разгулялась",
+    "разгулялось",
+    "раздавались",
+    "раздавалось",
+    "раздавался",
+    "раздаваться",
+    "раздавил",
+    "раздавила",
+    "раздавили",
+    "раздавлен",
+    "раздавлена",
+    "раздавленного",
+    "раздавленный",
+    "раздается",
+    "раздала",
+    "раздалась",
+    "раздались",
+    "раздалось",
+    "раздался",
+    "раздарить",
+    "раздастся",
+    "раздаются",
+    "раздвигать",
+    "раздвигаются",
+    "раздвигая",
+    "раздвинув",
+    "раздвинул",
+    "раздвинулась",
+    "раздвинулись",
+    "раздвинуто",
+    "раздев",
+    "раздевала",
+    "раздевался",
+    "раздевать",
+    "раздевают",
+    "раздеваясь",
+    "раздевшись",
+    "раздел",
+    "разделаешься",
+    "разделался",
+    "разделенная",
+    "разделив",
+    "разделил",
+    "разделила",
+    "разделит",
+    "разделить",
+    "раздельнее",
+    "раздельно",
+    "разделявший",
+    "разделяет",
+    "разделял",
+    "разделяла",
+    "разделялась",
+    "разделяю",
+    "разделяются",
+    "разделяющего",
+    "разденусь",
+    "раздетого",
+    "раздетые",
+    "раздирающим",
+    "раздольем",
+    "раздольи",
+    "раздор",
+    "раздоры",
+    "раздосадованный",
+    "раздражает",
+    "раздражается",
+    "раздражайтесь",
+    "раздражал",
+    "раздражались",
+    "раздражался",
+    "раздражать",
+    "раздражают",
+    "раздражаются",
+    "раздражающие",
+    "раздражающим",
+    "раздражаясь",
+    "раздражен",
+    "раздражена",
+    "раздражение",
+    "раздражением",
+    "раздражении",
+    "раздражению",
+    "раздражения",
+    "раздраженная",
+    "раздраженнее",
+    "раздраженно",
+    "раздраженного",
+    "раздраженное",
+    "раздраженную",
+    "раздраженным",
+    "раздражившись",
+    "раздражил",
+    "раздражилась",
+    "раздражило",
+    "раздражит",
+    "раздражителен",
+    "раздражительная",
+    "раздражительнее",
+    "раздражительно",
+    "раздражительное",
+    "раздражительном",
+    "раздражительностию",
+    "раздражительную",
+    "раздражительны",
+    "раздражительные",
+    "раздражительный",
+    "раздражительным",
+    "раздражить",
+    "раздразнить",
+    "раздроблен",
+    "раздроблено",
+    "раздробляется",
+    "раздувает",
+    "раздувала",
+    "раздувающий",
+    "раздувая",
+    "раздуем",
+    "раздулась",
+    "раздумал",
+    "раздумался",
+    "раздумчиво",
+    "раздумывал",
+    "раздумывали",
+    "раздумываться",
+    "раздумывая",
+    "раздумье",
+    "раздумью",
+    "раздумья",
+    "раздуть",
+    "раздушенный",
+    "разевая",
+    "разжал",
+    "разжалобил",
+    "разжалобясь",
+    "разжалована",
+    "разжигает",
+    "раззадорило",
+    "разик",
+    "разини",
+    "разинув",
+    "разинул",
+    "разинули",
+    "разинутым",
+    "разинутыми",
+    "разиня",
+    "разит",
+    "разлакомившийся",
+    "разлакомившись",
+    "разлакомились",
+    "разлегся",
+    "разлейся",
+    "разлетясь",
+    "разлетятся",
+    "разлив",
+    "разливалась",
+    "разливались",
+    "разливался",
+    "разливах",
+    "разливающейся",
+    "разливе",
+    "разливных",
+    "разлилась",
+    "разлились",
+    "разлилось",
+    "разлился",
+    "разлито",
+    "разлитого",
+    "разлитому",
+    "разлитый",
+    "различал",
+    "различала",
+    "различать",
+    "различаю",
+    "различен",
+    "различил",
+    "различить",
+    "различия",
+    "различно",
+    "различные",
+    "различным",
+    "различными",
+    "различных",
+    "разложения",
+    "разложенными",
+    "разложено",
+    "разложенье",
+    "разломал",
+    "разлука",
+    "разлуке",
+    "разлуки",
+    "разлучаться",
+    "разлучен",
+    "разлучимся",
+    "разлучиться",
+    "разлюбил",
+    "разлюбите",
+    "разлюбить",
+    "разлюбишь",
+    "разлюблю",
+    "разлюбя",
+    "размазывать",
+    "размарать",
+    "размах",
+    "размаха",
+    "размахи",
+    "размахивается",
+    "размахивай",
+    "размахивать",
+    "размахиваются",
+    "размахивая",
+    "размахнувшись",
+    "размахнулся",
+    "размаху",
+    "размашисты",
+    "размен",
+    "разменивать",
+    "разменявший",
+    "разменявшись",
+    "разменял",
+    "разменялся",
+    "разменянного",
+    "разменять",
+    "размер",
+    "размера",
+    "размерам",
+    "размере",
+    "размеренно",
+    "размеров",
+    "размеры",
+    "разметает",
+    "размечтался",
+    "разминается",
+    "разминать",
+    "размножится",
+    "размозжу",
+    "размолвки",
+    "размучен",
+    "размышление",
+    "размышлении",
+    "размышления",
+    "размышляет",
+    "размышлял",
+    "размышлять",
+    "размышляя",
+    "размягчило",
+    "размягчилось",
+    "разная",
+    "разнежась",
+    "разнежил",
+    "разнесет",
+    "разнесется",
+    "разнесла",
+    "разнесшейся",
+    "разнился",
+    "разнимались",
+    "разница",
+    "разницей",
+    "разницу",
+    "разновековой",
+    "разного",
+    "разное",
+    "разнокалиберное",
+    "разноличного",
+    "разнообразие",
+    "разнообразило",
+    "разнообразия",
+    "разнообразна",
+    "разнообразная",
+    "разнообразно",
+    "разнообразное",
+    "разнообразные",
+    "разнообразных",
+    "разнообразят",
+    "разнородной",
+    "разнорядицу",
+    "разносились",
+    "разносилось",
+    "разносится",
+    "разносить",
+    "разностями",
+    "разносчик",
+    "разносчики",
+    "разнофасонное",
+    "разнохарактерный",
+    "разноцветные",
+    "разноцветным",
+    "разную",
+    "разные",
+    "разный",
+    "разным",
+    "разными",
+    "разных",
+    "разо",
+    "разобидел",
+    "разобижен",
+    "разобрал",
+    "разобрала",
+    "разобрало",
+    "разобрано",
+    "разобраны",
+    "разобрать",
+    "разобраться",
+    "разобьют",
+    "разовьют",
+    "разогнавший",
+    "разогнать",
+    "разодета",
+    "разодетая",
+    "разодетой",
+    "разодетых",
+    "разодранной",
+    "разодраны",
+    "разожженным",
+    "разозлившись",
+    "разозлил",
+    "разозлился",
+    "разойдемся",
+    "разойтись",
+    "разольет",
+    "разольется",
+    "разом",
+    "разомкнуть",
+    "разорвал",
+    "разорвала",
+    "разорвали",
+    "разорвалось",
+    "разорванного",
+    "разорванное",
+    "разорванной",
+    "разорванные",
+    "разорванным",
+    "разорвано",
+    "разорвать",
+    "разорвусь",
+    "разорение",
+    "разорены",
+    "разорила",
+    "разорили",
+    "разорились",
+    "разорился",
+    "разоряет",
+    "разорять",
+    "разостлали",
+    "разостлались",
+    "разочаровался",
+    "разочарование",
+    "разочарования",
+    "разочарованный",
+    "разочаровать",
+    "разочароваться",
+    "разочаровываться",
+    "разочек",
+    "разошлась",
+    "разошлись",
+    "разработана",
+    "разработкою",
+    "разработывания",
+    "разразилась",
+    "разразились",
+    "разразился",
+    "разразит",
+    "разразится",
+    "разразиться",
+    "разрежены",
+    "разрезал",
+    "разрешается",
+    "разрешаешь",
+    "разрешалась",
+    "разрешать",
+    "разрешаться",
+    "разрешаю",
+    "разрешение",
+    "разрешения",
+    "разрешено",
+    "разрешенье",
+    "разрешил",
+    "разрешила",
+    "разрешили",
+    "разрешился",
+    "разрешите",
+    "разрешится",
+    "разрешить",
+    "разрешиться",
+    "разрешишь",
+    "разрозненных",
+    "разронял",
+    "разрослась",
+    "разрушает",
+    "разрушаете",
+    "разрушаешь",
+    "разрушал",
+    "разрушать",
+    "разрушают",
+    "разрушающий",
+    "разрушающими",
+    "разрушая",
+    "разрушение",
+    "разрушений",
+    "разрушения",
+    "разрушенное",
+    "разрушено",
+    "разрушила",
+    "разрушители",
+    "разрушительного",
+    "разрушительными",
+    "разрушителями",
+    "разрушиться",
+    "разрыв",
+    "разрыва",
+    "разрывала",
+    "разрывались",
+    "разрывать",
+    "разрывающим",
+    "разрывая",
+    "разрыве",
+    "разрыву",
+    "разряд",
+    "разряда",
+    "разрядится",
+    "разрядов",
+    "разряду",
+    "разряды",
+    "разряженные",
+    "разряженных",
+    "разу",
+    "разубедить",
+    "разубеждали",
+    "разубран",
+    "разуверило",
+    "разуверит",
+    "разуверить",
+    "разуверять",
+    "разудалого",
+    "разузнавать",
+    "разузнал",
+    "разузнали",
+    "разузнамши",
+    "разузнать",
+    "разузнаю",
+    "разукрашенную",
+    "разукрашенные",
+    "разукрашенный",
+    "разум",
+    "разума",
+    "разумеет",
+    "разумеется",
+    "разумеешь",
+    "разумел",
+    "разумела",
+    "разумели",
+    "разумения",
+    "разумихин",
+    "разумихина",
+    "разумихине",
+    "разумихину",
+    "разумихиным",
+    "разумнее",
+    "разумно",
+    "разумного",
+    "разумное",
+    "разумною",
+    "разумные",
+    "разумом",
+    "разучился",
+    "разъединения",
+    "разъезде",
+    "разъездов",
+    "разъезды",
+    "разъезжать",
+    "разъезжая",
+    "разъехавшись",
+    "разъехались",
+    "разъешься",
+    "разъяснением",
+    "разъяснений",
+    "разъяснения",
+    "разъяснено",
+    "разъяснил",
+    "разъяснилось",
+    "разъяснится",
+    "разъяснить",
+    "разъяснял",
+    "разыграется",
+    "разыгралась",
+    "разыгрались",
+    "разыгрался",
+    "разыграть",
+    "разыгрывавшиеся",
+    "разыгрывается",
+    "разыгрываешь",
+    "разыгрывайте",
+    "разыгрывалась",
+    "разыгрывались",
+    "разыгрывался",
+    "разыскав",
+    "разыскал",
+    "разыскали",
+    "разыскать",
+    "разыскивал",
+    "разыскиваний",
+    "разыскивать",
+    "разыскиваю",
+    "разыщу",
+    "разящий",
+    "рай",
+    "райские",
+    "райского",
+    "райское",
+    "рак",
+    "раковина",
+    "раковинами",
+    "рама",
+    "раме",
+    "рамках",
+    "рамке",
+    "рамкой",
+    "рамой",
+    "рамочку",
+    "рампе",
+    "рамы",
+    "ран",
+    "ранах",
+    "ране",
+    "раневская",
+    "раневской",
+    "ранее",
+    "ранен",
+    "ранена",
+    "раненого",
+    "ранешенько",
+    "ранил",
+    "ранили",
+    "ранит",
+    "ранить",
+    "раннего",
+    "ранней",
+    "раннею",
+    "ранние",
+    "ранний",
+    "ранним",
+    "ранняя",
+    "рано",
+    "рану",
+    "раны",
+    "раным",
+    "раньше",
+    "ранят",
+    "рапортом",
+    "раскаетесь",
+    "раскаешься",
+    "раскаивался",
+    "раскаиваться",
+    "раскаиваюсь",
+    "раскаиваясь",
+    "раскаленные",
+    "раскаленный",
+    "раскаленными",
+    "раскат",
+    "раскаты",
+    "раскачнувшись",
+    "раскаялся",
+    "раскаяние",
+    "раскаянием",
+    "раскаянии",
+    "раскаяния",
+    "раскаяться",
+    "раскидал",
+    "раскинется",
+    "раскинув",
+    "раскинул",
+    "раскинулась",
+    "раскинулся",
+    "раскинутыми",
+    "раскладывает",
+    "раскладывать",
+    "раскладывают",
+    "раскланивается",
+    "раскланялся",
+    "расклю",
+    "расколдуешь",
+    "расколот",
+    "расколотом",
+    "раскольников",
+    "раскольникова",
+    "раскольникове",
+    "раскольниковой",
+    "раскольникову",
+    "раскольниковым",
+    "раскольничьи",
+    "раскрасавицу",
+    "раскрасневшаяся",
+    "раскрасневшимися",
+    "раскраснелся",
+    "раскричалась",
+    "раскричится",
+    "раскрывал",
+    "раскрывались",
+    "раскрываться",
+    "раскрывают",
+    "раскрывая",
+    "раскрыл",
+    "раскрыла",
+    "раскрыли",
+    "раскрылись",
+    "раскрылся",
+    "раскрытая",
+    "раскрытым",
+    "раскрытыми",
+    "раскрыть",
+    "раскуси",
+    "раскусивший",
+    "раскусить",
+    "распался",
+    "распарит",
+    "распахивает",
+    "распахнувшийся",
+    "распахнул",
+    "распахнулась",
+    "распахнулись",
+    "распахнут",
+    "распашку",
+    "распечатал",
+    "распечатали",
+    "распечатывай",
+    "распечатывать",
+    "распечатывая",
+    "распивочная",
+    "распивочной",
+    "распивочные",
+    "распивочными",
+    "распивочных",
+    "распивошной",
+    "распирать",
+    "расписалась",
+    "расписался",
+    "расписание",
+    "расписки",
+    "расписок",
+    "расписочки",
+    "расписывались",
+    "расписываться",
+    "расплакалась",
+    "расплата",
+    "расплатившись",
+    "расплатился",
+    "расплатиться",
+    "расплатишься",
+    "расплачутся",
+    "расплевывался",
+    "расплескав",
+    "расплести",
+    "расплодилось",
+    "расплывается",
+    "расплываться",
+    "расплываясь",
+    "расплылось",
+    "расплюеве",
+    "распни",
+    "распознавать",
+    "распознать",
+    "располагается",
+    "располагаешь",
+    "располагал",
+    "располагать",
+    "располагаются",
+    "располагающий",
+    "расползается",
+    "расползлась",
+    "располнел",
+    "расположен",
+    "расположение",
+    "расположении",
+    "расположения",
+    "расположены",
+    "расположил",
+    "распомаженный",
+    "распорядилась",
+    "распорядился",
+    "распорядись",
+    "распорядительнице",
+    "распорядительности",
+    "распорядительность",
+    "распорядится",
+    "распорядиться",
+    "распорядок",
+    "распорядятся",
+    "распоряжаетесь",
+    "распоряжается",
+    "распоряжалась",
+    "распоряжался",
+    "распоряжение",
+    "распоряжений",
+    "распоряжению",
+    "распоряжения",
+    "распоряжениям",
+    "распоряжусь",
+    "расправил",
+    "расправила",
+    "расправиться",
+    "расправляя",
+    "расправу",
+    "распределить",
+    "распри",
+    "распродал",
+    "распростерла",
+    "распростерлось",
+    "распростертый",
+    "распростершийся",
+    "распространение",
+    "распространенное",
+    "распространены",
+    "распространилась",
+    "распространилось",
+    "распространился",
+    "распространить",
+    "распространяется",
+    "распространять",
+    "распрях",
+    "распугала",
+    "распускается",
+    "распускал",
+    "распускала",
+    "распускались",
+    "распускать",
+    "распустив",
+    "распустившийся",
+    "распустил",
+    "распустила",
+    "распустили",
+    "распустился",
+    "распустить",
+    "распутает",
+    "распутица",
+    "распухшее",
+    "распущенность",
+    "распущенные",
+    "распущены",
+    "распылавшийся",
+    "распяв",
+    "распятие",
+    "распять",
+    "рассадить",
+    "рассажены",
+    "рассвет",
+    "рассвета",
+    "рассвете",
+    "рассветет",
+    "рассветом",
+    "рассвирепев",
+    "рассвирепел",
+    "рассеет",
+    "расселись",
+    "рассердившийся",
+    "рассердившись",
+    "рассердилась",
+    "рассердили",
+    "рассердились",
+    "рассердился",
+    "рассердитесь",
+    "рассердится",
+    "рассердиться",
+    "рассердятся",
+    "рассержен",
+    "рассерженный",
+    "рассержены",
+    "рассечь",
+    "рассеялось",
+    "рассеян",
+    "рассеянна",
+    "рассеянно",
+    "рассеянности",
+    "рассеянность",
+    "рассеянностью",
+    "рассеянны",
+    "рассеянный",
+    "рассеянным",
+    "рассеяно",
+    "рассеять",
+    "рассеяться",
+    "расскажет",
+    "расскажете",
+    "расскажешь",
+    "расскажи",
+    "расскажит",
+    "расскажите",
+    "расскажу",
+    "расскажут",
+    "рассказ",
+    "рассказа",
+    "рассказав",
+    "рассказал",
+    "рассказала",
+    "рассказали",
+    "рассказам",
+    "рассказами",
+    "рассказана",
+    "рассказанный",
+    "рассказать",
+    "рассказах",
+    "рассказе",
+    "рассказов",
+    "рассказом",
+    "рассказу",
+    "рассказчика",
+    "рассказчиц",
+    "рассказы",
+    "рассказывает",
+    "рассказываешь",
+    "рассказывай",
+    "рассказывайте",
+    "рассказывал",
+    "рассказывала",
+    "рассказывали",
+    "рассказывать",
+    "рассказываю",
+    "рассказывают",
+    "расслабленного",
+    "расслабленный",
+    "расслабленным",
+    "расследован",
+    "расслушала",
+    "расслушать",
+    "расслышав",
+    "расслышал",
+    "расслышала",
+    "расслышать",
+    "рассматривавшего",
+    "рассматривает",
+    "рассматривается",
+    "рассматривал",
+    "рассматривала",
+    "рассматривали",
+    "рассматривания",
+    "рассматривать",
+    "рассматривают",
+    "рассматривая",
+    "рассмеетесь",
+    "рассмешат",
+    "рассмешил",
+    "рассмешила",
+    "рассмешили",
+    "рассмешит",
+    "рассмеявшись",
+    "рассмеялся",
+    "рассмеяться",
+    "рассмотрев",
+    "рассмотрение",
+    "рассмотреть",
+    "рассориться",
+    "расспрашивайте",
+    "расспрашивал",
+    "расспрашивала",
+    "расспрашивали",
+    "расспрашивать",
+    "расспрашивая",
+    "расспросами",
+    "расспросила",
+    "расспросит",
+    "расспросить",
+    "расспросов",
+    "расспросы",
+    "расставаться",
+    "расставив",
+    "расставил",
+    "расставила",
+    "расставили",
+    "расставлено",
+    "расставляет",
+    "расстаемся",
+    "расстается",
+    "рассталась",
+    "расстались",
+    "расстался",
+    "расстанемся",
+    "расстановисто",
+    "расстановками",
+    "расстановкой",
+    "расстаться",
+    "расстаются",
+    "расстегнул",
+    "расстегнуть",
+    "расстелет",
+    "расстилалася",
+    "расстилались",
+    "расстилаться",
+    "расстояние",
+    "расстоянии",
+    "расстояния",
+    "расстраивались",
+    "расстроен",
+    "расстроена",
+    "расстроенного",
+    "расстроенной",
+    "расстроенную",
+    "расстроенные",
+    "расстроенный",
+    "расстроено",
+    "расстроены",
+    "расстроивает",
+    "расстроил",
+    "расстроили",
+    "расстроить",
+    "расстроиться",
+    "расстроишь",
+    "расстроишься",
+    "расстройства",
+    "расстройстве",
+    "расстройство",
+    "расступись",
+    "рассуди",
+    "рассудив",
+    "рассудил",
+    "рассудила",
+    "рассудилось",
+    "рассудит",
+    "рассудите",
+    "рассудительна",
+    "рассудительная",
+    "рассудительнее",
+    "рассудительный",
+    "рассудить",
+    "рассудка",
+    "рассудке",
+    "рассудкина",
+    "рассудком",
+    "рассудок",
+    "рассуждает",
+    "рассуждаете",
+    "рассуждаешь",
+    "рассуждал",
+    "рассуждать",
+    "рассуждаю",
+    "рассуждают",
+    "рассуждая",
+    "рассуждение",
+    "рассуждений",
+    "рассуждению",
+    "рассуждения",
+    "рассуждениями",
+    "рассчитав",
+    "рассчитайте",
+    "рассчитал",
+    "рассчитали",
+    "рассчитаны",
+    "рассчитать",
+    "рассчитываем",
+    "рассчитывает",
+    "рассчитываете",
+    "рассчитывай",
+    "рассчитывал",
+    "рассчитывали",
+    "рассчитывать",
+    "рассчитываю",
+    "рассчитывая",
+    "рассыпается",
+    "рассыпала",
+    "рассыпалась",
+    "рассыпались",
+    "рассыпался",
+    "рассыпчатые",
+    "расталкивает",
+    "расталкивать",
+    "растаял",
+    "растворена",
+    "растворенных",
+    "растворил",
+    "растворилась",
+    "растворились",
+    "растворить",
+    "растворяя",
+    "растение",
+    "растениями",
+    "растерзанном",
+    "растерявшаяся",
+    "растерявшимся",
+    "растерявшись",
+    "растеряется",
+    "растеряла",
+    "растеряли",
+    "растерялся",
+    "растерян",
+    "растерянно",
+    "растерянные",
+    "растеряют",
+    "растет",
+    "расти",
+    "растлелось",
+    "растолкала",
+    "растолковал",
+    "растолковать",
+    "растолковывать",
+    "растолкуйте",
+    "растолкую",
+    "растопалась",
+    "растопчет",
+    "растопыренных",
+    "расторопного",
+    "расторопный",
+    "расточаемые",
+    "расточает",
+    "расточаю",
+    "расточилась",
+    "расточители",
+    "расточительности",
+    "растравлять",
+    "растревожили",
+    "растреклятого",
+    "растреклятым",
+    "растрепанная",
+    "растрепанного",
+    "растрепанную",
+    "растроган",
+    "растроганная",
+    "растроганной",
+    "растроганный",
+    "растут",
+    "растущего",
+    "растущей",
+    "растягивает",
+    "растянет",
+    "растянулись",
+    "растянулся",
+    "расфранченную",
+    "расфуфыренных",
+    "расхаживаешь",
+    "расхаживают",
+    "расхищения",
+    "расхлебывают",
+    "расхлябанные",
+    "расход",
+    "расхода",
+    "расходились",
+    "расходился",
+    "расходится",
+    "расходными",
+    "расходы",
+    "расходясь",
+    "расходятся",
+    "расхохоталась",
+    "расхохотался",
+    "расцвела",
+    "расцвело",
+    "расцвет",
+    "расцветала",
+    "расцветало",
+    "расцветание",
+    "расцветания",
+    "расцветая",
+    "расцветшей",
+    "расчесанные",
+    "расчесанный",
+    "расчесывает",
+    "расчесывая",
+    "расчет",
+    "расчета",
+    "расчетам",
+    "расчетах",
+    "расчетливый",
+    "расчетов",
+    "расчетом",
+    "расчету",
+    "расчеты",
+    "расчищаем",
+    "расчувствовался",
+    "расшалясь",
+    "расшаталось",
+    "расшевелили",
+    "расшевелить",
+    "расшиб",
+    "расшибанья",
+    "расшибет",
+    "расшибется",
+    "расширением",
+    "расширены",
+    "расширялась",
+    "расширялся",
+    "расширяя",
+    "расщедрилась",
+    "расщедрился",
+    "ратую",
+    "рать",
+    "раут",
+    "рафаэлевой",
+    "рафаэлевских",
+    "рафаэля",
+    "рацею",
+    "рационально",
+    "рационального",
+    "рациональный",
+    "рачителен",
+    "рачительно",
+    "раю",
+    "рая",
+    "рвал",
+    "рвало",
+    "рвался",
+    "рвание",
+    "рваного",
+    "рваной",
+    "рванула",
+    "рванулась",
+    "рванулся",
+    "рвануться",
+    "рваные",
+    "рвать",
+    "рваться",
+    "рвение",
+    "рвет",
+    "рвется",
+    "рвешь",
+    "рвешься",
+    "рву",
+    "рдеет",
+    "рдели",
+    "рдеют",
+    "рдея",
+    "реализме",
+    "реализмом",
+    "реальное",
+    "ребенка",
+    "ребенке",
+    "ребенком",
+    "ребенку",
+    "ребенок",
+    "ребеночек",
+    "ребер",
+    "ребра",
+    "ребре",
+    "ребят",
+    "ребята",
+    "ребятишек",
+    "ребятишкам",
+    "ребятишками",
+    "ребятишки",
+    "ребяческая",
+    "ребяческий",
+    "ребячества",
+    "ребячий",
+    "ребячьих",
+    "рев",
+    "ревель",
+    "реверанс",
+    "реви",
+    "ревизор",
+    "ревматизм",
+    "ревматизмом",
+    "ревнивая",
+    "ревнивее",
+    "ревниво",
+    "ревнивой",
+    "ревнивый",
+    "ревновал",
+    "ревновала",
+    "ревновать",
+    "ревности",
+    "ревность",
+    "ревностью",
+    "ревнует",
+    "ревнуя",
+    "револьвер",
+    "револьвером",
+    "революции",
+    "революционных",
+    "революция",
+    "ревом",
+    "ревущих",
+    "регистраторша",
+    "регистраторши",
+    "регре",
+    "регулировать",
+    "регулярно",
+    "редактора",
+    "редакции",
+    "редакций",
+    "редакция",
+    "редеет",
+    "редеть",
+    "редкая",
+    "редки",
+    "редкие",
+    "редкий",
+    "редким",
+    "редкими",
+    "редких",
+    "редко",
+    "редкое",
+    "редкой",
+    "редкости",
+    "редкость",
+    "редька",
+    "редьки",
+    "редьку",
+    "реет",
+    "реже",
+    "режет",
+    "режу",
+    "резались",
+    "резанная",
+    "резать",
+    "резв",
+    "резва",
+    "резвая",
+    "резвому",
+    "резвость",
+    "резвы",
+    "резвые",
+    "резвясь",
+    "резвятся",
+    "резедой",
+    "резеду",
+    "резеды",
+    "резиденция",
+    "резиновых",
+    "резкая",
+    "резкие",
+    "резкий",
+    "резким",
+    "резкими",
+    "резких",
+    "резко",
+    "резкой",
+    "резком",
+    "резкость",
+    "резной",
+    "резню",
+    "резок",
+    "резоны",
+    "резоня",
+    "результат",
+    "результатах",
+    "результате",
+    "результатов",
+    "результату",
+    "результаты",
+    "резцом",
+    "резче",
+    "резюме",
+    "рейнгольду",
+    "рейт",
+    "рек",
+    "река",
+    "рекам",
+    "рекамье",
+    "реке",
+    "реки",
+    "рекой",
+    "рекомендации",
+    "рекомендацию",
+    "рекомендация",
+    "рекомендовал",
+    "рекомендовать",
+    "рекомендует",
+    "рекомендуется",
+    "рекомендую",
+    "рекомендуя",
+    "рекорд",
+    "рекою",
+    "ректор",
+    "реку",
+    "религией",
+    "религии",
+    "религий",
+    "религиозно",
+    "рельефностью",
+    "рельс",
+    "рельсах",
+    "рельсы",
+    "реляции",
+    "реляцию",
+    "ремень",
+    "ремесла",
+    "ремесленник",
+    "ремесленники",
+    "ремесленное",
+    "ремеслу",
+    "ремне",
+    "ремни",
+    "ренегате",
+    "реникса",
+    "ренную",
+    "рент",
+    "реомюра",
+    "репетитором",
+    "реплики",
+    "репой",
+    "репу",
+    "репутацией",
+    "репутации",
+    "репутаций",
+    "репутацию",
+    "репы",
+    "ресниц",
+    "ресницами",
+    "ресницах",
+    "ресницы",
+    "ресслих",
+    "рессорную",
+    "ресторан",
+    "ресторана",
+    "ресторане",
+    "ретивое",
+    "ретировавшегося",
+    "реторту",
+    "ретроград",
+    "ретроградна",
+    "рефлексом",
+    "реформа",
+    "реформы",
+    "рехнулись",
+    "рехнулся",
+    "рецепт",
+    "рецепта",
+    "рецептов",
+    "речами",
+    "речах",
+    "речей",
+    "речи",
+    "речисто",
+    "речка",
+    "речке",
+    "речки",
+    "речкой",
+    "речку",
+    "речной",
+    "речь",
+    "речью",
+    "решает",
+    "решаете",
+    "решается",
+    "решаешься",
+    "решай",
+    "решайтесь",
+    "решал",
+    "решала",
+    "решалась",
+    "решались",
+    "решался",
+    "решатся",
+    "решать",
+    "решаюсь",
+    "решают",
+    "решаясь",
+    "решен",
+    "решена",
+    "решение",
+    "решением",
+    "решении",
+    "решений",
+    "решения",
+    "решениям",
+    "решенном",
+    "решенный",
+    "решено",
+    "решетку",
+    "решетчатого",
+    "решетчаты",
+    "решетчатые",
+    "решетчатым",
+    "реши",
+    "решив",
+    "решившегося",
+    "решившей",
+    "решившийся",
+    "решившись",
+    "решил",
+    "решила",
+    "решилась",
+    "решили",
+    "решились",
+    "решился",
+    "решим",
+    "решимости",
+    "решимость",
+    "решимостью",
+    "решит",
+    "решите",
+    "решителем",
+    "решительная",
+    "решительнее",
+    "решительно",
+    "решительного",
+    "решительное",
+    "решительном",
+    "решительности",
+    "решительную",
+    "решительный",
+    "решительным",
+    "решится",
+    "решить",
+    "решиться",
+    "решоткой",
+    "решусь",
+    "решь",
+    "реяли",
+    "ржавой",
+    "ржавые",
+    "ржавый",
+    "ржавых",
+    "ржаной",
+    "ржи",
+    "ризами",
+    "ризой",
+    "рим",
+    "рима",
+    "римляне",
+    "римские",
+    "ринется",
+    "ринулся",
+    "ринутся",
+    "ринуться",
+    "рис",
+    "риск",
+    "риски",
+    "рискнул",
+    "рискнула",
+    "рискнули",
+    "риском",
+    "рисовал",
+    "рисовала",
+    "рисовало",
+    "рисовалось",
+    "рисовался",
+    "рисования",
+    "рисовать",
+    "рисовой",
+    "рису",
+    "рисует",
+    "рисуешь",
+    "рисунка",
+    "рисунков",
+    "рисунок",
+    "рисую",
+    "рисуюсь",
+    "рисуя",
+    "ритм",
+    "ритма",
+    "ритмическое",
+    "ритмы",
+    "риторика",
+    "риторики",
+    "рифм",
+    "рифмованную",
+    "ро",
+    "робел",
+    "робела",
+    "робели",
+    "робенок",
+    "роберте",
+    "робеют",
+    "робея",
+    "робка",
+    "робкая",
+    "робкие",
+    "робкий",
+    "робким",
+    "робко",
+    "робкого",
+    "робкой",
+    "робком",
+    "робок",
+    "робостию",
+    "робость",
+    "робостью",
+    "робче",
+    "ровесник",
+    "ровна",
+    "ровнее",
+    "ровнехонько",
+    "ровно",
+    "ровное",
+    "ровной",
+    "ровны",
+    "ровные",
+    "ровный",
+    "ровным",
+    "ровняет",
+    "рог",
+    "рога",
+    "рогам",
+    "рогами",
+    "рогатого",
+    "рогатые",
+    "рогов",
+    "роговой",
+    "рогожею",
+    "род",
+    "рода",
+    "родах",
+    "роде",
+    "родей",
+    "роденьку",
+    "роди",
+    "родила",
+    "родилась",
+    "родились",
+    "родилось",
+    "родился",
+    "родименький",
+    "родимой",
+    "родимый",
+    "родина",
+    "родине",
+    "родиной",
+    "родину",
+    "родины",
+    "родион",
+    "родиона",
+    "родионом",
+    "родиону",
+    "родионыч",
+    "родителей",
+    "родители",
+    "родитель",
+    "родительская",
+    "родительский",
+    "родительского",
+    "родительском",
+    "родителя",
+    "родителями",
+    "родится",
+    "родиться",
+    "родная",
+    "родне",
+    "роднейший",
+    "родни",
+    "роднила",
+    "родного",
+    "родное",
+    "родной",
+    "родном",
+    "родную",
+    "родные",
+    "родным",
+    "родными",
+    "родных",
+    "родню",
+    "родня",
+    "родов",
+    "родом",
+    "родства",
+    "родственник",
+    "родственника",
+    "родственнике",
+    "родственники",
+    "родственников",
+    "родственнику",
+    "родственница",
+    "родственницей",
+    "родственницу",
+    "родственницы",
+    "родственного",
+    "родственное",
+    "родственной",
+    "родственному",
+    "родственные",
+    "родственный",
+    "родственных",
+    "родство",
+    "роду",
+    "родька",
+    "родьке",
+    "родькин",
+    "родэ",
+    "родю",
+    "родя",
+    "родясь",
+    "родятся",
+    "роем",
+    "роется",
+    "роешься",
+    "рожа",
+    "рожами",
+    "рождает",
+    "рождается",
+    "рождал",
+    "рождали",
+    "рождалось",
+    "рождался",
+    "рождают",
+    "рождающейся",
+    "рождающимся",
+    "рождающихся",
+    "рождение",
+    "рождением",
+    "рождении",
+    "рождения",
+    "рожденные",
+    "рожденья",
+    "рождества",
+    "рождестве",
+    "рождественном",
+    "рождество",
+    "рождеством",
+    "рождеству",
+    "роже",
+    "рожи",
+    "рожком",
+    "рожок",
+    "рожу",
+    "рожь",
+    "рожью",
+    "роз",
+    "роза",
+    "розан",
+    "розах",
+    "розни",
+    "розно",
+    "розные",
+    "розовеет",
+    "розовеют",
+    "розовея",
+    "розовое",
+    "розовой",
+    "розовом",
+    "розовые",
+    "розовый",
+    "розовыми",
+    "розовых",
+    "розой",
+    "розоперстая",
+    "розу",
+    "розы",
+    "роится",
+    "рой",
+    "рок",
+    "рока",
+    "роковая",
+    "рокового",
+    "роковое",
+    "роковой",
+    "роковые",
+    "роковым",
+    "рокот",
+    "рокотание",
+    "рокотов",
+    "рокотом",
+    "рокоты",
+    "роланда",
+    "ролей",
+    "роли",
+    "роль",
+    "ром",
+    "роман",
+    "романа",
+    "романах",
+    "романе",
+    "романистов",
+    "романический",
+    "романическим",
+    "романического",
+    "романов",
+    "романович",
+    "романовича",
+    "романовичу",
+    "романовна",
+    "романовне",
+    "романовной",
+    "романовну",
+    "романовны",
+    "романс",
+    "романсов",
+    "романчик",
+    "романы",
+    "романыч",
+    "романыча",
+    "романычем",
+    "романычу",
+    "ромео",
+    "рому",
+    "роняет",
+    "ронял",
+    "ронять",
+    "роняя",
+    "ропот",
+    "ропота",
+    "роптал",
+    "роптали",
+    "роптать",
+    "ропщу",
+    "рос",
+    "роса",
+    "росах",
+    "росинки",
+    "росистую",
+    "роскоши",
+    "роскошная",
+    "роскошнее",
+    "роскошно",
+    "роскошного",
+    "роскошные",
+    "роскошный",
+    "роскошным",
+    "роскошных",
+    "роскошь",
+    "роскошью",
+    "росла",
+    "росли",
+    "росло",
+    "росный",
+    "росписные",
+    "россией",
+    "россии",
+    "российский",
+    "россию",
+    "россия",
+    "россияда",
+    "россиянину",
+    "рост",
+    "роста",
+    "росте",
+    "ростовщика",
+    "ростовщица",
+    "ростом",
+    "росту",
+    "росы",
+    "рот",
+    "рошую",
+    "роща",
+    "рощах",
+    "роще",
+    "рощей",
+    "рощи",
+    "рощу",
+    "роют",
+    "роются",
+    "рояле",
+    "роялем",
+    "рояль",
+    "рояля",
+    "роясь",
+    "роящихся",
+    "рт",
+    "рта",
+    "ртами",
+    "ртом",
+    "рту",
+    "рты",
+    "рубах",
+    "рубахе",
+    "рубаху",
+    "рубашек",
+    "рубашечку",
+    "рубашечный",
+    "рубашка",
+    "рубашках",
+    "рубашке",
+    "рубашки",
+    "рубашкой",
+    "рубашку",
+    "рубашонке",
+    "рубежей",
+    "рубежи",
+    "рубец",
+    "руби",
+    "рубивших",
+    "рубикон",
+    "рубила",
+    "рубили",
+    "рубин",
+    "рубини",
+    "рубинштейн",
+    "рубит",
+    "рубить",
+    "рубища",
+    "рубище",
+    "рубиянить",
+    "рублев",
+    "рублевым",
+    "рублей",
+    "рубликов",
+    "рубль",
+    "рублю",
+    "рубля",
+    "рублям",
+    "рублями",
+    "рублях",
+    "рубнуть",
+    "рубцы",
+    "ругает",
+    "ругается",
+    "ругал",
+    "ругали",
+    "ругались",
+    "ругался",
+    "ругань",
+    "ругательски",
+    "ругательским",
+    "ругательств",
+    "ругательства",
+    "ругательствами",
+    "ругать",
+    "ругаться",
+    "ругают",
+    "ругая",
+    "ругаясь",
+    "ругнул",
+    "руда",
+    "рудник",
+    "ружье",
+    "ружьем",
+    "ружья",
+    "рук",
+    "рука",
+    "рукав",
+    "рукава",
+    "рукавами",
+    "рукавом",
+    "рукам",
+    "руками",
+    "руках",
+    "руке",
+    "руки",
+    "руководитель",
+    "руководителя",
+    "руководить",
+    "руководств",
+    "руководства",
+    "руководствовался",
+    "руководством",
+    "руководствуясь",
+    "рукой",
+    "рукомесло",
+    "рукомойнику",
+    "рукописи",
+    "рукопись",
+    "рукоплесканий",
+    "рукопожатий",
+    "рукопожатья",
+    "рукою",
+    "руку",
+    "руля",
+    "румяна",
+    "румяная",
+    "румянец",
+    "румянил",
+    "румянит",
+    "румянится",
+    "румяное",
+    "румяную",
+    "румянца",
+    "румянцем",
+    "румяный",
+    "румяными",
+    "руне",
+    "рус",
+    "русака",
+    "русалке",
+    "русалкой",
+    "русалкою",
+    "русалочья",
+    "руси",
+    "русло",
+    "русская",
+    "русски",
+    "русские",
+    "русский",
+    "русским",
+    "русскими",
+    "русских",
+    "русско",
+    "русского",
+    "русской",
+    "русском",
+    "русскому",
+    "русскую",
+    "руссо",
+    "русые",
+    "русыми",
+    "русь",
+    "русью",
+    "рутина",
+    "рутине",
+    "рутинеры",
+    "рутину",
+    "рухлядь",
+    "рухнул",
+    "рухнулась",
+    "рухнуло",
+    "рухнулся",
+    "рухнут",
+    "руце",
+    "ручаетесь",
+    "ручайтесь",
+    "ручаюсь",
+    "ручей",
+    "рученьку",
+    "ручищи",
+    "ручка",
+    "ручками",
+    "ручки",
+    "ручкой",
+    "ручку",
+    "ручном",
+    "ручным",
+    "ручонками",
+    "ручонкой",
+    "ручонку",
+    "ручьем",
+    "ручьи",
+    "ручья",
+    "ручьями",
+    "рушатся",
+    "рушились",
+    "рушилось",
+    "рыба",
+    "рыбак",
+    "рыбаками",
+    "рыбачьих",
+    "рыбинскую",
+    "рыбой",
+    "рыбу",
+    "рыбы",
+    "рыбьим",
+    "рыдает",
+    "рыдал",
+    "рыдала",
+    "рыдали",
+    "рыдало",
+    "рыдалось",
+    "рыданием",
+    "рыданий",
+    "рыдания",
+    "рыданиями",
+    "рыданиях",
+    "рыдать",
+    "рыдают",
+    "рыдающие",
+    "рыдая",
+    "рыжая",
+    "рыжеватыми",
+    "рыжие",
+    "рыжики",
+    "рыкающих",
+    "рыло",
+    "рылом",
+    "рылся",
+    "рылу",
+    "рынкам",
+    "рынках",
+    "рынке",
+    "рынком",
+    "рынок",
+    "рысак",
+    "рытвину",
+    "рыться",
+    "рыхлый",
+    "рыцарей",
+    "рыцари",
+    "рыцарь",
+    "рычага",
+    "рычание",
+    "рычаньем",
+    "рыщет",
+    "рыщу",
+    "рьяно",
+    "рю",
+    "рюмка",
+    "рюмке",
+    "рюмки",
+    "рюмкой",
+    "рюмку",
+    "рюмочки",
+    "рюмочку",
+    "рюшем",
+    "рябая",
+    "рябило",
+    "рябину",
+    "рябого",
+    "рябчика",
+    "рябчики",
+    "рябчиков",
+    "рябь",
+    "ряд",
+    "ряда",
+    "рядах",
+    "рядов",
+    "рядом",
+    "ряду",
+    "ряды",
+    "рядышком",
+    "рядят",
+    "ряженые",
+    "ряженых",
+    "рязанские",
+    "с",
+    "саблей",
+    "сабли",
+    "саблю",
+    "саван",
+    "саване",
+    "савиновых",
+    "савич",
+    "савича",
+    "савишна",
+    "саврасая",
+    "савраске",
+    "савраской",
+    "савраску",
+    "сад",
+    "сада",
+    "садами",
+    "садик",
+    "садике",
+    "садику",
+    "садилась",
+    "садился",
+    "садись",
+    "садитесь",
+    "садится",
+    "садить",
+    "садиться",
+    "садки",
+    "садов",
+    "садовник",
+    "садовника",
+    "садовнику",
+    "садовой",
+    "садовского",
+    "садовскому",
+    "садовую",
+    "садовый",
+    "садовых",
+    "садом",
+    "саду",
+    "садясь",
+    "садятся",
+    "садящееся",
+    "сажает",
+    "сажал",
+    "сажала",
+    "сажать",
+    "сажаю",
+    "сажают",
+    "сажая",
+    "сажен",
+    "сажени",
+    "сажень",
+    "саженях",
+    "сажусь",
+    "сайкой",
+    "сайку",
+    "саксонии",
+    "саксонских",
+    "саксонского",
+    "салазки",
+    "салат",
+    "сале",
+    "сало",
+    "салом",
+    "саломеи",
+    "саломея",
+    "салон",
+    "салоне",
+    "салонов",
+    "салоном",
+    "салоп",
+    "салопа",
+    "салопе",
+    "салопов",
+    "салопом",
+    "салопы",
+    "салфетке",
+    "салфетку",
+    "сальная",
+    "сальной",
+    "сальный",
+    "сам",
+    "сама",
+    "самая",
+    "сами",
+    "самим",
+    "самих",
+    "само",
+    "самовар",
+    "самовара",
+    "самоваре",
+    "самоваров",
+    "самоваром",
+    "самоварчик",
+    "самовлюбленностию",
+    "самовольно",
+    "самого",
+    "самодержавный",
+    "самодовольное",
+    "самодуров",
+    "самое",
+    "самозабвением",
+    "самозабвения",
+    "самозабвенья",
+    "самой",
+    "самойлу",
+    "самолично",
+    "самолюбив",
+    "самолюбивая",
+    "самолюбивой",
+    "самолюбивы",
+    "самолюбивый",
+    "самолюбивых",
+    "самолюбие",
+    "самолюбием",
+    "самолюбия",
+    "самом",
+    "самомалейшему",
+    "самомнение",
+    "самому",
+    "самонаслаждением",
+    "самонужнейшее",
+    "самоотвержении",
+    "самоотвержения",
+    "самоотверженным",
+    "самопожертвования",
+    "самосознания",
+    "самосохранения",
+    "самостоятельная",
+    "самостоятельно",
+    "самостоятельного",
+    "самостоятельной",
+    "самостоятельности",
+    "самостоятельностию",
+    "самоубийств",
+    "самоубийства",
+    "самоубийство",
+    "самоубийством",
+    "самоубийца",
+    "самоуверенная",
+    "самоуверенно",
+    "самоуверенности",
+    "самоуверенность",
+    "самоуверенностью",
+    "самоунижения",
+    "самоуправство",
+    "самою",
+    "самую",
+    "самые",
+    "самый",
+    "самым",
+    "самыми",
+    "самых",
+    "сана",
+    "сандалий",
+    "саней",
+    "санки",
+    "санкт",
+    "сановником",
+    "сапог",
+    "сапога",
+    "сапогам",
+    "сапогами",
+    "сапогах",
+    "сапоге",
+    "сапоги",
+    "сапогов",
+    "сапогом",
+    "сапожникам",
+    "сапожной",
+    "сарае",
+    "сараев",
+    "сараи",
+    "сарай",
+    "саранча",
+    "саратов",
+    "сарафане",
+    "сарая",
+    "сараям",
+    "сараях",
+    "сарказма",
+    "сарказмами",
+    "сарказмов",
+    "саркастически",
+    "саркастическую",
+    "саркофаги",
+    "саркофагом",
+    "сатанинский",
+    "сатир",
+    "сатира",
+    "сатире",
+    "сатиров",
+    "сатиру",
+    "сатиры",
+    "сафьянные",
+    "сафьяном",
+    "сахар",
+    "сахарная",
+    "сахарница",
+    "сахарницу",
+    "сахарные",
+    "сахару",
+    "сбавка",
+    "сбавки",
+    "сбалмошные",
+    "сбегает",
+    "сбегаешь",
+    "сбегай",
+    "сбегала",
+    "сбегались",
+    "сбегать",
+    "сбегаю",
+    "сбежал",
+    "сбежала",
+    "сбежались",
+    "сбежался",
+    "сбережения",
+    "сбереженные",
+    "сбережет",
+    "сберечь",
+    "сбивает",
+    "сбиваете",
+    "сбивается",
+    "сбиваешься",
+    "сбивали",
+    "сбивался",
+    "сбивать",
+    "сбивают",
+    "сбиваются",
+    "сбивающих",
+    "сбиваясь",
+    "сбивчиво",
+    "сбивчивую",
+    "сбившейся",
+    "сбившеюся",
+    "сбившись",
+    "сбил",
+    "сбилась",
+    "сбили",
+    "сбило",
+    "сбился",
+    "сбирались",
+    "сбирался",
+    "сбит",
+    "сбитая",
+    "сбитый",
+    "сбитых",
+    "сбить",
+    "сближались",
+    "сближался",
+    "сближение",
+    "сближению",
+    "сближения",
+    "сблизил",
+    "сбоку",
+    "сболтнул",
+    "сборник",
+    "сборнике",
+    "сборное",
+    "сбрасывали",
+    "сбрасывая",
+    "сбредить",
+    "сбрил",
+    "сбрили",
+    "сброду",
+    "сбросил",
+    "сбросила",
+    "сбросили",
+    "сбросить",
+    "сбрось",
+    "сбрую",
+    "сбруя",
+    "сбудется",
+    "сбываться",
+    "сбывают",
+    "сбывающихся",
+    "сбывшимся",
+    "сбыл",
+    "сбылось",
+    "сбыта",
+    "сбыть",
+    "сбыться",
+    "сва",
+    "свадеб",
+    "свадьба",
+    "свадьбе",
+    "свадьбой",
+    "свадьбу",
+    "свадьбы",
+    "сваливается",
+    "сваливал",
+    "свалил",
+    "свалилась",
+    "свалился",
+    "свалит",
+    "свалится",
+    "свалить",
+    "свалял",
+    "свалят",
+    "сварили",
+    "сварить",
+    "сватался",
+    "сватают",
+    "сватаясь",
+    "сватовства",
+    "сватовство",
+    "све",
+    "сведений",
+    "сведения",
+    "сведениями",
+    "сведенная",
+    "сведете",
+    "сведи",
+    "сведущих",
+    "свеж",
+    "свежа",
+    "свежая",
+    "свежего",
+    "свежее",
+    "свежей",
+    "свежем",
+    "свежему",
+    "свежеодетым",
+    "свежепросоленные",
+    "свежести",
+    "свежесть",
+    "свежестью",
+    "свежею",
+    "свежие",
+    "свежий",
+    "свежим",
+    "свежими",
+    "свежих",
+    "свежо",
+    "свезти",
+    "свезу",
+    "свезут",
+    "свел",
+    "свели",
+    "свергнуть",
+    "сверка",
+    "сверкавшие",
+    "сверкает",
+    "сверкала",
+    "сверкали",
+    "сверканий",
+    "сверканьи",
+    "сверкать",
+    "сверкающие",
+    "сверкающим",
+    "сверкающими",
+    "сверкнет",
+    "сверкнув",
+    "сверкнувшее",
+    "сверкнувший",
+    "сверкнул",
+    "сверкнула",
+    "сверкнули",
+    "сверкнуло",
+    "сверкнут",
+    "сверлящих",
+    "свернет",
+    "свернется",
+    "свернула",
+    "свернули",
+    "свернулись",
+    "свернулся",
+    "свернутого",
+    "свернуть",
+    "свертков",
+    "свертывает",
+    "свертывали",
+    "свертывают",
+    "сверх",
+    "сверху",
+    "сверхъестественное",
+    "сверхъестественный",
+    "сверчок",
+    "свершай",
+    "свершать",
+    "свершая",
+    "свершив",
+    "свершил",
+    "свершилось",
+    "свершился",
+    "свершится",
+    "свершу",
+    "сверь",
+    "свесив",
+    "свести",
+    "свет",
+    "света",
+    "светает",
+    "светать",
+    "свете",
+    "светел",
+    "светелке",
+    "свети",
+    "светик",
+    "светил",
+    "светила",
+    "светилась",
+    "светились",
+    "светило",
+    "светилось",
+    "светит",
+    "светится",
+    "светить",
+    "светла",
+    "светлая",
+    "светлее",
+    "светлей",
+    "светлела",
+    "светленькие",
+    "светленькое",
+    "светлице",
+    "светлицу",
+    "светло",
+    "светловыбритого",
+    "светлого",
+    "светлое",
+    "светлой",
+    "светлом",
+    "светлою",
+    "светлую",
+    "светлы",
+    "светлые",
+    "светлый",
+    "светлым",
+    "светлыми",
+    "светлых",
+    "светляка",
+    "световой",
+    "светозарный",
+    "светом",
+    "светопреставление",
+    "светская",
+    "светский",
+    "светскими",
+    "светских",
+    "светской",
+    "светском",
+    "светскому",
+    "свету",
+    "светы",
+    "светятся",
+    "светящими",
+    "свеч",
+    "свеча",
+    "свече",
+    "свечей",
+    "свечи",
+    "свечка",
+    "свечке",
+    "свечки",
+    "свечкой",
+    "свечку",
+    "свечными",
+    "свечой",
+    "свечу",
+    "свешивалась",
+    "свивает",
+    "свидание",
+    "свидании",
+    "свиданий",
+    "свиданию",
+    "свидания",
+    "свиданиях",
+    "свиданья",
+    "свидетеле",
+    "свидетелей",
+    "свидетелем",
+    "свидетели",
+    "свидетель",
+    "свидетельница",
+    "свидетельский",
+    "свидетельства",
+    "свидетельстве",
+    "свидетельство",
+    "свидетельствовала",
+    "свидетельствовать",
+    "свидетельством",
+    "свидетельствую",
+    "свидетельствуют",
+    "свидетельствуя",
+    "свидетеля",
+    "свидетелями",
+    "свидетелях",
+    "свидригайлов",
+    "свидригайлова",
+    "свидригайлове",
+    "свидригайлову",
+    "свидригайловы",
+    "свидригайловым",
+    "свидригайловых",
+    "свинец",
+    "свинкин",
+    "свинства",
+    "свинство",
+    "свинтус",
+    "свинцовый",
+    "свинцовым",
+    "свинцом",
+    "свиным",
+    "свиньи",
+    "свинья",
+    "свирбею",
+    "свирели",
+    "свирель",
+    "свирепо",
+    "свирепость",
+    "свирепою",
+    "свирепствует",
+    "свисают",
+    "свист",
+    "свисте",
+    "свистела",
+    "свисти",
+    "свистит",
+    "свистки",
+    "свистнет",
+    "свистнула",
+    "свисток",
+    "свистя",
+    "свита",
+    "свиток",
+    "свиты",
+    "свищешь",
+    "свобода",
+    "свободе",
+    "свободен",
+    "свободна",
+    "свободная",
+    "свободнее",
+    "свободно",
+    "свободного",
+    "свободное",
+    "свободной",
+    "свободном",
+    "свободною",
+    "свободную",
+    "свободны",
+    "свободный",
+    "свободным",
+    "свободу",
+    "свободы",
+    "свод",
+    "свода",
+    "своде",
+    "сводил",
+    "сводила",
+    "сводит",
+    "сводить",
+    "сводная",
+    "сводя",
+    "свое",
+    "своевольного",
+    "своеволья",
+    "своевременно",
+    "своевременным",
+    "своего",
+    "своей",
+    "своем",
+    "своему",
+    "своею",
+    "свозили",
+    "свои",
+    "своим",
+    "своими",
+    "своих",
+    "свой",
+    "свойски",
+    "свойств",
+    "свойства",
+    "свойствам",
+    "свойствах",
+    "свойстве",
+    "свойственен",
+    "свойственная",
+    "свойственно",
+    "свойственной",
+    "свойственную",
+    "свойственным",
+    "свойство",
+    "сворачивал",
+    "своротит",
+    "своротишь",
+    "сворочен",
+    "свою",
+    "своя",
+    "свысока",
+    "свыше",
+    "свяжем",
+    "свяжись",
+    "связав",
+    "связал",
+    "связали",
+    "связался",
+    "связан",
+    "связана",
+    "связанную",
+    "связанных",
+    "связать",
+    "связаться",
+    "связей",
+    "связи",
+    "связке",
+    "связных",
+    "связывала",
+    "связывало",
+    "связываться",
+    "связь",
+    "связью",
+    "связям",
+    "связями",
+    "святая",
+    "святейшая",
+    "святках",
+    "святки",
+    "свято",
+    "святого",
+    "святое",
+    "святой",
+    "святок",
+    "святому",
+    "святую",
+    "святые",
+    "святым",
+    "святыми",
+    "святынею",
+    "святыни",
+    "святынь",
+    "святых",
+    "священная",
+    "священник",
+    "священника",
+    "священником",
+    "священнику",
+    "священной",
+    "священную",
+    "священный",
+    "священным",
+    "священных",
+    "сгиб",
+    "сгибался",
+    "сгинешь",
+    "сгинула",
+    "сгинуть",
+    "сгинь",
+    "сглаживался",
+    "сглазим",
+    "сглазить",
+    "сглупа",
+    "сглупил",
+    "сгнил",
+    "сгниют",
+    "сговаривались",
+    "сговариваются",
+    "сговорившись",
+    "сговорилась",
+    "сговорились",
+    "сговоримся",
+    "сговориться",
+    "сговорятся",
+    "сгонит",
+    "сгоняли",
+    "сгоняют",
+    "сгорал",
+    "сгорало",
+    "сгорая",
+    "сгорбленные",
+    "сгорбленный",
+    "сгорел",
+    "сгорела",
+    "сгорело",
+    "сгореть",
+    "сгорит",
+    "сгоряча",
+    "сготовила",
+    "сгреб",
+    "сгруппировались",
+    "сгубил",
+    "сгубила",
+    "сгубили",
+    "сгубило",
+    "сгущавшейся",
+    "сгущавшихся",
+    "сгущались",
+    "сгущающейся",
+    "сдав",
+    "сдавило",
+    "сдает",
+    "сдается",
+    "сдал",
+    "сдано",
+    "сдать",
+    "сдач",
+    "сдача",
+    "сдачи",
+    "сдачу",
+    "сдаю",
+    "сдаются",
+    "сдвигала",
+    "сдвинешься",
+    "сдвинув",
+    "сдвинул",
+    "сдвинулась",
+    "сдвинулись",
+    "сдвинулся",
+    "сдвинусь",
+    "сдвинуты",
+    "сдвинутых",
+    "сделав",
+    "сделаем",
+    "сделает",
+    "сделаете",
+    "сделается",
+    "сделаешь",
+    "сделай",
+    "сделайте",
+    "сделал",
+    "сделала",
+    "сделалась",
+    "сделали",
+    "сделались",
+    "сделалось",
+    "сделался",
+    "сделан",
+    "сделанная",
+    "сделанное",
+    "сделанный",
+    "сделано",
+    "сделаны",
+    "сделать",
+    "сделаться",
+    "сделаю",
+    "сделают",
+    "сделаются",
+    "сделку",
+    "сдержав",
+    "сдержал",
+    "сдержали",
+    "сдержанная",
+    "сдержанно",
+    "сдержанной",
+    "сдержанностью",
+    "сдержанны",
+    "сдержанный",
+    "сдержанным",
+    "сдержанных",
+    "сдержать",
+    "сдерживает",
+    "сдерживал",
+    "сдерживала",
+    "сдерживали",
+    "сдерживались",
+    "сдерживался",
+    "сдерживать",
+    "сдерживаться",
+    "сдерживая",
+    "сдерживаясь",
+    "сдержу",
+    "сдернет",
+    "сдернул",
+    "сдерут",
+    "сдесь",
+    "сдобные",
+    "сдружусь",
+    "сдует",
+    "сдурил",
+    "се",
+    "себе",
+    "себя",
+    "себятешение",
+    "севастополе",
+    "севастополь",
+    "севастьяныч",
+    "севе",
+    "север",
+    "севера",
+    "северного",
+    "северной",
+    "северный",
+    "северу",
+    "севшей",
+    "сегидилья",
+    "сего",
+    "сегодня",
+    "сегодняшней",
+    "сегодняшнему",
+    "сегодняшние",
+    "сегодняшний",
+    "сегодняшняя",
+    "седая",
+    "седеть",
+    "седеют",
+    "седея",
+    "седла",
+    "седлах",
+    "седого",
+    "седое",
+    "седой",
+    "седок",
+    "седоков",
+    "седому",
+    "седою",
+    "седые",
+    "седым",
+    "седыми",
+    "седых",
+    "седьмая",
+    "седьмого",
+    "седьмом",
+    "седьмую",
+    "сеет",
+    "сезон",
+    "сей",
+    "сейчас",
+    "секи",
+    "секирой",
+    "секрет",
+    "секретами",
+    "секретарем",
+    "секретарше",
+    "секретаршу",
+    "секретарь",
+    "секретарю",
+    "секретаря",
+    "секрете",
+    "секретного",
+    "секретных",
+    "секретов",
+    "секрету",
+    "секреты",
+    "сектант",
+    "секунд",
+    "секундант",
+    "секунду",
+    "секунды",
+    "секут",
+    "секучим",
+    "секущих",
+    "сел",
+    "села",
+    "селадон",
+    "селам",
+    "селе",
+    "селедка",
+    "селедкой",
+    "селедку",
+    "селения",
+    "селенья",
+    "сели",
+    "селиться",
+    "село",
+    "селу",
+    "сельди",
+    "сельский",
+    "сельской",
+    "сельтерскую",
+    "сельцо",
+    "селянку",
+    "сем",
+    "семафор",
+    "семафоре",
+    "семгой",
+    "семейного",
+    "семейное",
+    "семейной",
+    "семейном",
+    "семейною",
+    "семейные",
+    "семейный",
+    "семейным",
+    "семейных",
+    "семейств",
+    "семейства",
+    "семействе",
+    "семейство",
+    "семейством",
+    "семейству",
+    "семен",
+    "семена",
+    "семени",
+    "семенила",
+    "семенит",
+    "семенов",
+    "семенович",
+    "семеновича",
+    "семеновиче",
+    "семеновичем",
+    "семеновичу",
+    "семеновна",
+    "семеновне",
+    "семеновной",
+    "семеновну",
+    "семеновны",
+    "семеном",
+    "семену",
+    "семеныч",
+    "семеныча",
+    "семи",
+    "семидесяти",
+    "семидесятых",
+    "семик",
+    "семика",
+    "семилетний",
+    "семинарии",
+    "семинарист",
+    "семинариста",
+    "семинаристов",
+    "семицветной",
+    "семнадцати",
+    "семнадцать",
+    "семой",
+    "семом",
+    "сему",
+    "семь",
+    "семьдесят",
+    "семье",
+    "семьей",
+    "семьи",
+    "семьсот",
+    "семью",
+    "семья",
+    "семьянин",
+    "семя",
+    "сен",
+    "сена",
+    "сенат",
+    "сенате",
+    "сенатора",
+    "сенатору",
+    "сенатским",
+    "сенатского",
+    "сенату",
+    "сеней",
+    "сени",
+    "сенная",
+    "сенной",
+    "сенную",
+    "сенным",
+    "сенных",
+    "сено",
+    "сеновал",
+    "сеновала",
+    "сеновале",
+    "сеновалу",
+    "сенокос",
+    "сеном",
+    "сентенции",
+    "сентенций",
+    "сентенциозно",
+    "сентенцию",
+    "сентенциями",
+    "сентиментальности",
+    "сентябре",
+    "сентябрь",
+    "сентября",
+    "сень",
+    "сенью",
+    "сенях",
+    "септимия",
+    "сера",
+    "серафимы",
+    "серая",
+    "сервирован",
+    "сервированный",
+    "сергевна",
+    "сергевне",
+    "сергевну",
+    "сергеевич",
+    "сергеевна",
+    "сергеевне",
+    "сергеевной",
+    "сергеевну",
+    "сергеич",
+    "сергию",
+    "сердечко",
+    "сердечнее",
+    "сердечно",
+    "сердечного",
+    "сердечной",
+    "сердечные",
+    "сердечный",
+    "сердечными",
+    "сердечных",
+    "сердилась",
+    "сердился",
+    "сердимся",
+    "сердись",
+    "сердит",
+    "сердита",
+    "сердитая",
+    "сердитесь",
+    "сердито",
+    "сердится",
+    "сердитый",
+    "сердиться",
+    "сердишься",
+    "сердобольная",
+    "сердолики",
+    "сердца",
+    "сердцами",
+    "сердцах",
+    "сердце",
+    "сердцем",
+    "сердцу",
+    "сердятся",
+    "серебра",
+    "серебре",
+    "серебристо",
+    "серебристые",
+    "серебро",
+    "серебром",
+    "серебряков",
+    "серебрякова",
+    "серебрякову",
+    "серебряная",
+    "серебряника",
+    "серебряной",
+    "серебряную",
+    "серебряные",
+    "серебряный",
+    "серебряным",
+    "серебряных",
+    "серег",
+    "середи",
+    "середина",
+    "середине",
+    "серединных",
+    "середину",
+    "середины",
+    "середы",
+    "сережками",
+    "сережках",
+    "серей",
+    "серенькое",
+    "сержусь",
+    "серий",
+    "серо",
+    "серовато",
+    "серого",
+    "серое",
+    "серой",
+    "сером",
+    "серпами",
+    "серую",
+    "серчал",
+    "серы",
+    "серые",
+    "серый",
+    "серыми",
+    "серых",
+    "серьгами",
+    "серьги",
+    "серьезная",
+    "серьезнее",
+    "серьезно",
+    "серьезного",
+    "серьезное",
+    "серьезной",
+    "серьезном",
+    "серьезною",
+    "серьезную",
+    "серьезны",
+    "серьезные",
+    "серьезный",
+    "серьезным",
+    "серьезных",
+    "сестер",
+    "сестра",
+    "сестрам",
+    "сестре",
+    "сестрино",
+    "сестрину",
+    "сестриц",
+    "сестрица",
+    "сестрице",
+    "сестрицей",
+    "сестрицу",
+    "сестрицы",
+    "сестрой",
+    "сестру",
+    "сестры",
+    "сесть",
+    "сети",
+    "сетка",
+    "сетке",
+    "сетку",
+    "сетовал",
+    "сеттиньяно",
+    "сеть",
+    "сетях",
+    "сечет",
+    "сечи",
+    "сечь",
+    "сею",
+    "сеют",
+    "сея",
+    "сжав",
+    "сжавшись",
+    "сжал",
+    "сжала",
+    "сжалась",
+    "сжалилось",
+    "сжались",
+    "сжалится",
+    "сжалиться",
+    "сжало",
+    "сжалось",
+    "сжался",
+    "сжато",
+    "сжатую",
+    "сжаты",
+    "сжатые",
+    "сжатый",
+    "сжатыми",
+    "сжевать",
+    "сжег",
+    "сжечь",
+    "сжигающем",
+    "сжимал",
+    "сжимала",
+    "сжималась",
+    "сжимали",
+    "сжимались",
+    "сжимаю",
+    "сжимают",
+    "сжимая",
+    "сзади",
+    "сибарит",
+    "сибариты",
+    "сибири",
+    "сибирке",
+    "сибирь",
+    "сивиллины",
+    "сиволапым",
+    "сигар",
+    "сигарету",
+    "сигарой",
+    "сигару",
+    "сигары",
+    "сигать",
+    "сигнал",
+    "сидевшая",
+    "сидевшего",
+    "сидевшему",
+    "сидевший",
+    "сидевшим",
+    "сидел",
+    "сидела",
+    "сидели",
+    "сидело",
+    "сиделось",
+    "сиденье",
+    "сидеть",
+    "сиди",
+    "сидим",
+    "сидит",
+    "сидите",
+    "сидится",
+    "сидишь",
+    "сидя",
+    "сидят",
+    "сидящая",
+    "сидящей",
+    "сие",
+    "сиена",
+    "сиенский",
+    "сиенского",
+    "сижу",
+    "сизая",
+    "сизой",
+    "сизые",
+    "сикстинской",
+    "сил",
+    "сила",
+    "силам",
+    "силами",
+    "силах",
+    "силача",
+    "силе",
+    "силен",
+    "силилась",
+    "силился",
+    "силится",
+    "силой",
+    "силою",
+    "силу",
+    "силушки",
+    "силуэтом",
+    "силы",
+    "сильва",
+    "сильна",
+    "сильная",
+    "сильнее",
+    "сильней",
+    "сильнейшее",
+    "сильно",
+    "сильного",
+    "сильное",
+    "сильной",
+    "сильном",
+    "сильною",
+    "сильную",
+    "сильны",
+    "сильные",
+    "сильный",
+    "сильным",
+    "сильных",
+    "силясь",
+    "сим",
+    "символ",
+    "символами",
+    "символизма",
+    "символизмом",
+    "символизму",
+    "символистов",
+    "символических",
+    "симеонов",
+    "симеонова",
+    "симеоновых",
+    "сими",
+    "симметрически",
+    "симметрично",
+    "симпатией",
+    "симпатии",
+    "симпатичен",
+    "симпатичная",
+    "симпатичной",
+    "симпатичны",
+    "симпатичный",
+    "симпатию",
+    "симпатия",
+    "симплон",
+    "симптомы",
+    "синевой",
+    "синеву",
+    "синевы",
+    "синего",
+    "синее",
+    "синей",
+    "синем",
+    "синенькие",
+    "синеньким",
+    "синенькою",
+    "синеокая",
+    "синечерною",
+    "синеющих",
+    "синие",
+    "синий",
+    "синим",
+    "синими",
+    "синих",
+    "синодальный",
+    "синодальных",
+    "синонимы",
+    "синтаксис",
+    "синь",
+    "синьора",
+    "синьоры",
+    "синюю",
+    "синяках",
+    "синяя",
+    "сипел",
+    "сипенье",
+    "сипеньем",
+    "сипеть",
+    "сиплая",
+    "сипло",
+    "сиплую",
+    "сиплым",
+    "сиплыми",
+    "сиреневая",
+    "сиреневой",
+    "сиреневых",
+    "сирени",
+    "сиреной",
+    "сирень",
+    "сирины",
+    "сириус",
+    "сиропа",
+    "сирот",
+    "сирота",
+    "сиротами",
+    "сиротка",
+    "сиротливо",
+    "сиротливой",
+    "сиротские",
+    "сиротских",
+    "сиротском",
+    "сироты",
+    "систем",
+    "система",
+    "систематическом",
+    "системе",
+    "систему",
+    "системы",
+    "сите",
+    "ситху",
+    "ситцевое",
+    "ситцевом",
+    "ситцевую",
+    "ситцевыми",
+    "ситцевых",
+    "ситцем",
+    "сих",
+    "сию",
+    "сия",
+    "сияет",
+    "сияешь",
+    "сиял",
+    "сияла",
+    "сияли",
+    "сияло",
+    "сияние",
+    "сиянием",
+    "сиянии",
+    "сияния",
+    "сияньем",
+    "сияньи",
+    "сиянья",
+    "сиятельство",
+    "сиять",
+    "сияют",
+    "сияющей",
+    "сияющие",
+    "сияющий",
+    "сияющими",
+    "сияющих",
+    "сияющую",
+    "скабрезный",
+    "скажем",
+    "скажет",
+    "скажете",
+    "скажешь",
+    "скажи",
+    "скажите",
+    "скажу",
+    "скажут",
+    "сказ",
+    "сказав",
+    "сказавшись",
+    "сказал",
+    "сказала",
+    "сказали",
+    "сказались",
+    "сказалось",
+    "сказаль",
+    "сказаниям",
+    "сказаниями",
+    "сказанного",
+    "сказанное",
+    "сказанном",
+    "сказанные",
+    "сказанный",
+    "сказано",
+    "сказаны",
+    "сказать",
+    "сказка",
+    "сказкам",
+    "сказками",
+    "сказках",
+    "сказке",
+    "сказки",
+    "сказку",
+    "сказок",
+    "сказочник",
+    "сказочный",
+    "сказывай",
+    "сказывайте",
+    "сказывал",
+    "сказывала",
+    "сказывали",
+    "сказывать",
+    "сказываться",
+    "скакал",
+    "скакала",
+    "скаканье",
+    "скаканьем",
+    "скаканья",
+    "скакать",
+    "скакнет",
+    "скакнуть",
+    "скал",
+    "скалами",
+    "скалдырничество",
+    "скале",
+    "скалистый",
+    "скалить",
+    "скалишь",
+    "скалкой",
+    "скалой",
+    "скалою",
+    "скалу",
+    "скалы",
+    "скальда",
+    "скалясь",
+    "скамеечка",
+    "скамеечке",
+    "скамеечку",
+    "скамей",
+    "скамейка",
+    "скамейке",
+    "скамейки",
+    "скамейку",
+    "скамье",
+    "скамьи",
+    "скамью",
+    "скамья",
+    "скамьях",
+    "скандал",
+    "скандалезнейшем",
+    "скандалезное",
+    "скандализируя",
+    "скандально",
+    "скандальному",
+    "скандируя",
+    "сканированный",
+    "скаредной",
+    "скаредном",
+    "скаредную",
+    "скатерти",
+    "скатерть",
+    "скатертью",
+    "скатилась",
+    "скача",
+    "скачет",
+    "скачка",
+    "скачков",
+    "скважине",
+    "скважину",
+    "скверен",
+    "скверная",
+    "сквернее",
+    "сквернейшего",
+    "сквернейшим",
+    "скверно",
+    "скверное",
+    "скверном",
+    "скверность",
+    "скверные",
+    "скверный",
+    "скверным",
+    "скверных",
+    "сквозила",
+    "сквозит",
+    "сквозить",
+    "сквозного",
+    "сквозняках",
+    "сквозь",
+    "сквозя",
+    "сквозящий",
+    "скворцов",
+    "скворцом",
+    "скворцы",
+    "скелет",
+    "скептик",
+    "скидывать",
+    "ский",
+    "скинет",
+    "скинуть",
+    "скиньте",
+    "скит",
+    "скитайся",
+    "скитались",
+    "скитался",
+    "скитальцам",
+    "скитальцев",
+    "скитальцу",
+    "скитанья",
+    "скитаться",
+    "скитов",
+    "скиты",
+    "склад",
+    "склада",
+    "складам",
+    "складенные",
+    "складка",
+    "складках",
+    "складке",
+    "складки",
+    "складкой",
+    "складку",
+    "складно",
+    "складной",
+    "складок",
+    "складываете",
+    "складывались",
+    "складывая",
+    "склепе",
+    "склона",
+    "склоне",
+    "склонен",
+    "склоненный",
+    "склони",
+    "склонив",
+    "склонившейся",
+    "склонившись",
+    "склонил",
+    "склонила",
+    "склонилась",
+    "склонили",
+    "склонилось",
+    "склонился",
+    "склоним",
+    "склонится",
+    "склонить",
+    "склонность",
+    "склонны",
+    "склону",
+    "склоню",
+    "склоняет",
+    "склоняется",
+    "склоняла",
+    "склонялись",
+    "склонясь",
+    "склоняюсь",
+    "склоняют",
+    "склоняющийся",
+    "склоняясь",
+    "склубясь",
+    "склянка",
+    "склянками",
+    "склянки",
+    "скобками",
+    "скобках",
+    "сковород",
+    "сковорода",
+    "сковороде",
+    "сковородке",
+    "сковороду",
+    "сковороды",
+    "сковывал",
+    "ского",
+    "ской",
+    "сколотились",
+    "сколоченной",
+    "сколоченных",
+    "сколь",
+    "скользила",
+    "скользит",
+    "скользить",
+    "скользкие",
+    "скользких",
+    "скользкой",
+    "скользнет",
+    "скользнул",
+    "скользнула",
+    "скользнуло",
+    "скользнуть",
+    "скользя",
+    "скользят",
+    "скользящей",
+    "скользящий",
+    "скольких",
+    "сколько",
+    "скольку",
+    "ском",
+    "скомкала",
+    "скомканная",
+    "скомканное",
+    "скомпрометировать",
+    "сконфуженно",
+    "сконфуженный",
+    "сконфужены",
+    "сконфузившаяся",
+    "сконфузившийся",
+    "сконфузившись",
+    "сконфузилась",
+    "сконфузился",
+    "сконфузит",
+    "сконфузитесь",
+    "скончался",
+    "скопившегося",
+    "скопившейся",
+    "скопить",
+    "скопленных",
+    "скоплено",
+    "скорбей",
+    "скорбеть",
+    "скорби",
+    "скорбит",
+    "скорбная",
+    "скорбного",
+    "скорбное",
+    "скорбной",
+    "скорбную",
+    "скорбь",
+    "скорбя",
+    "скорбями",
+    "скорее",
+    "скорей",
+    "скоренько",
+    "скорлупами",
+    "скорлупу",
+    "скорлупы",
+    "скорняки",
+    "скоро",
+    "скорого",
+    "скороговорке",
+    "скороговоркой",
+    "скорое",
+    "скорой",
+    "скором",
+    "скорому",
+    "скоропостижно",
+    "скорчившись",
+    "скорый",
+    "скорым",
+    "скорыми",
+    "скосил",
+    "скот",
+    "скота",
+    "скотина",
+    "скотининых",
+    "скотины",
+    "скотнице",
+    "скотницу",
+    "скотный",
+    "скотоводство",
+    "скотта",
+    "скошенную",
+    "скошено",
+    "скрадывала",
+    "скрасить",
+    "скребет",
+    "скребла",
+    "скрежет",
+    "скрежета",
+    "скрежетал",
+    "скрежеща",
+    "скрежещущий",
+    "скрепил",
+    "скрепилась",
+    "скрепился",
+    "скрестив",
+    "скрестил",
+    "скривив",
+    "скривил",
+    "скривило",
+    "скривя",
+    "скрижали",
+    "скрижалями",
+    "скрип",
+    "скрипач",
+    "скрипел",
+    "скрипели",
+    "скрипи",
+    "скрипит",
+    "скрипка",
+    "скрипкам",
+    "скрипками",
+    "скрипке",
+    "скрипки",
+    "скрипкой",
+    "скрипнула",
+    "скрипок",
+    "скрипом",
+    "скрипу",
+    "скрипучие",
+    "скрипучий",
+    "скрипучим",
+    "скрипят",
+    "скроем",
+    "скроен",
+    "скроет",
+    "скроется",
+    "скромен",
+    "скромная",
+    "скромно",
+    "скромной",
+    "скромности",
+    "скромною",
+    "скромную",
+    "скромные",
+    "скромный",
+    "скрутило",
+    "скручена",
+    "скрыв",
+    "скрываемая",
+    "скрываемого",
+    "скрываемых",
+    "скрывает",
+    "скрываете",
+    "скрываешься",
+    "скрывайся",
+    "скрывал",
+    "скрывалась",
+    "скрывали",
+    "скрывались",
+    "скрывать",
+    "скрываться",
+    "скрываю",
+    "скрываются",
+    "скрывая",
+    "скрываясь",
+    "скрыл",
+    "скрыла",
+    "скрылась",
+    "скрыли",
+    "скрылись",
+    "скрылся",
+    "скрыпя",
+    "скрыт",
+    "скрыта",
+    "скрытна",
+    "скрытном",
+    "скрыто",
+    "скрытой",
+    "скрытому",
+    "скрытою",
+    "скрытую",
+    "скрыты",
+    "скрытый",
+    "скрытых",
+    "скрыть",
+    "скрыться",
+    "скрючившись",
+    "скрючился",
+    "скряга",
+    "скуден",
+    "скудной",
+    "скудном",
+    "скудную",
+    "скудны",
+    "скудный",
+    "скует",
+    "скука",
+    "скуке",
+    "скуки",
+    "скукой",
+    "скуку",
+    "скулах",
+    "скулистое",
+    "скульптору",
+    "скуп",
+    "скупа",
+    "скупает",
+    "скупал",
+    "скупо",
+    "скупости",
+    "скупость",
+    "скупцом",
+    "скупы",
+    "скучает",
+    "скучаете",
+    "скучаешь",
+    "скучай",
+    "скучал",
+    "скучала",
+    "скучать",
+    "скучаю",
+    "скучают",
+    "скучающему",
+    "скучая",
+    "скучен",
+    "скученное",
+    "скучища",
+    "скучна",
+    "скучная",
+    "скучнее",
+    "скучнейшие",
+    "скучно",
+    "скучного",
+    "скучное",
+    "скучной",
+    "скучном",
+    "скучную",
+    "скучны",
+    "скучные",
+    "скучный",
+    "скушали",
+    "скую",
+    "слаб",
+    "слаба",
+    "слабая",
+    "слабее",
+    "слабеет",
+    "слабел",
+    "слабенькая",
+    "слабенькие",
+    "слабенькою",
+    "слабея",
+    "слабо",
+    "слабого",
+    "слабое",
+    "слабой",
+    "слабом",
+    "слабонервная",
+    "слабосилие",
+    "слабости",
+    "слабостию",
+    "слабость",
+    "слабостями",
+    "слабою",
+    "слабы",
+    "слабые",
+    "слабый",
+    "слабым",
+    "слабыми",
+    "слабых",
+    "слава",
+    "славе",
+    "славит",
+    "славить",
+    "славная",
+    "славненькая",
+    "славно",
+    "славном",
+    "славны",
+    "славные",
+    "славный",
+    "славным",
+    "славных",
+    "славой",
+    "славу",
+    "славы",
+    "славянофил",
+    "славянский",
+    "славянском",
+    "слагает",
+    "слагается",
+    "сладить",
+    "сладишь",
+    "сладкая",
+    "сладкие",
+    "сладкий",
+    "сладким",
+    "сладких",
+    "сладко",
+    "сладкое",
+    "сладкой",
+    "сладкою",
+    "сладок",
+    "сладостно",
+    "сладостное",
+    "сладостной",
+    "сладостных",
+    "сладострастие",
+    "сладострастием",
+    "сладострастного",
+    "сладострастный",
+    "сладость",
+    "сладчайший",
+    "сладчайшую",
+    "слаж",
+    "слаженный",
+    "сластию",
+    "сласть",
+    "слать",
+    "слаще",
+    "слева",
+    "слег",
+    "слегка",
+    "слегла",
+    "след",
+    "следа",
+    "следам",
+    "следами",
+    "следившего",
+    "следил",
+    "следила",
+    "следили",
+    "следим",
+    "следит",
+    "следите",
+    "следить",
+    "следов",
+    "следовал",
+    "следовала",
+    "следовали",
+    "следовало",
+    "следователей",
+    "следователи",
+    "следователь",
+    "следовательно",
+    "следователю",
+    "следователя",
+    "следователями",
+    "следовать",
+    "следственно",
+    "следственных",
+    "следствие",
+    "следствии",
+    "следствия",
+    "следует",
+    "следующее",
+    "следующей",
+    "следующем",
+    "следующие",
+    "следующий",
+    "следующих",
+    "следующую",
+    "следуя",
+    "следы",
+    "следя",
+    "следят",
+    "следящий",
+    "слежу",
+    "слез",
+    "слеза",
+    "слезам",
+    "слезами",
+    "слезах",
+    "слезинками",
+    "слезинки",
+    "слезла",
+    "слезно",
+    "слезой",
+    "слезши",
+    "слезы",
+    "слезьми",
+    "слеп",
+    "слепа",
+    "слепнуть",
+    "слепо",
+    "слепого",
+    "слепой",
+    "слепому",
+    "слепота",
+    "слепоте",
+    "слепы",
+    "слепых",
+    "слепя",
+    "слепящей",
+    "слесарное",
+    "слесаря",
+    "слесарями",
+    "слетает",
+    "слетала",
+    "слетаю",
+    "слетел",
+    "слетели",
+    "слетело",
+    "слететь",
+    "слетит",
+    "слив",
+    "сливаешься",
+    "сливались",
+    "сливалось",
+    "сливался",
+    "сливами",
+    "сливках",
+    "сливки",
+    "сливок",
+    "сливочками",
+    "слившиеся",
+    "слилась",
+    "слились",
+    "слипаются",
+    "слитых",
+    "слить",
+    "слиться",
+    "сличить",
+    "слишком",
+    "слиянием",
+    "слов",
+    "слова",
+    "словам",
+    "словами",
+    "словаре",
+    "словах",
+    "слове",
+    "словесная",
+    "словесное",
+    "словечка",
+    "словечкам",
+    "словечки",
+    "словечко",
+    "словно",
+    "слово",
+    "словом",
+    "словоохотливо",
+    "словоохотливый",
+    "словоохотнее",
+    "слову",
+    "словцо",
+    "слог",
+    "слога",
+    "слоге",
+    "слогом",
+    "слоев",
+    "слоем",
+    "сложа",
+    "сложен",
+    "сложена",
+    "сложение",
+    "сложения",
+    "сложенного",
+    "сложенную",
+    "сложенный",
+    "сложенными",
+    "сложены",
+    "сложив",
+    "сложившиеся",
+    "сложил",
+    "сложилась",
+    "сложили",
+    "сложились",
+    "сложился",
+    "сложиль",
+    "сложим",
+    "сложить",
+    "сложиться",
+    "сложнее",
+    "сложно",
+    "сложного",
+    "сложный",
+    "сложных",
+    "слоистому",
+    "слоистые",
+    "сломал",
+    "сломалась",
+    "сломали",
+    "сломались",
+    "сломана",
+    "сломать",
+    "сломают",
+    "сломила",
+    "сломили",
+    "сломит",
+    "сломя",
+    "слоновой",
+    "слоняюсь",
+    "слою",
+    "слоями",
+    "слуг",
+    "слуга",
+    "слугам",
+    "слугами",
+    "слуги",
+    "слугой",
+    "служака",
+    "служаки",
+    "служанка",
+    "служанкам",
+    "служанке",
+    "служанки",
+    "служат",
+    "служащего",
+    "служащий",
+    "служб",
+    "служба",
+    "службе",
+    "службой",
+    "службу",
+    "службы",
+    "служебные",
+    "служение",
+    "служения",
+    "служи",
+    "служившая",
+    "служившей",
+    "служивший",
+    "служившую",
+    "служивый",
+    "служил",
+    "служила",
+    "служили",
+    "служились",
+    "служим",
+    "служит",
+    "служите",
+    "служить",
+    "служишь",
+    "служу",
+    "слух",
+    "слуха",
+    "слухи",
+    "слухом",
+    "слуху",
+    "случа",
+    "случае",
+    "случаев",
+    "случаем",
+    "случается",
+    "случаи",
+    "случай",
+    "случайная",
+    "случайно",
+    "случайного",
+    "случайное",
+    "случайной",
+    "случайному",
+    "случайностей",
+    "случайности",
+    "случайность",
+    "случайностью",
+    "случайностями",
+    "случайною",
+    "случайные",
+    "случайный",
+    "случайным",
+    "случайных",
+    "случались",
+    "случалось",
+    "случался",
+    "случаю",
+    "случаются",
+    "случая",
+    "случаям",
+    "случаях",
+    "случившееся",
+    "случившемся",
+    "случившимся",
+    "случилась",
+    "случились",
+    "случилось",
+    "случился",
+    "случись",
+    "случится",
+    "случиться",
+    "слушавшая",
+    "слушавшей",
+    "слушавшие",
+    "слушавший",
+    "слушает",
+    "слушаете",
+    "слушается",
+    "слушаешь",
+    "слушай",
+    "слушайся",
+    "слушайте",
+    "слушал",
+    "слушала",
+    "слушалась",
+    "слушали",
+    "слушался",
+    "слушателе",
+    "слушателей",
+    "слушатель",
+    "слушательниц",
+    "слушателя",
+    "слушателями",
+    "слушать",
+    "слушаю",
+    "слушаюсь",
+    "слушают",
+    "слушающей",
+    "слушая",
+    "слывя",
+    "слыл",
+    "слыла",
+    "слыхав",
+    "слыхал",
+    "слыхала",
+    "слыхали",
+    "слыханное",
+    "слыхано",
+    "слыхать",
+    "слыхивал",
+    "слыхивала",
+    "слыхивали",
+    "слыхом",
+    "слыша",
+    "слышал",
+    "слышала",
+    "слышалась",
+    "слышали",
+    "слышались",
+    "слышалось",
+    "слышался",
+    "слышамши",
+    "слышанное",
+    "слышанные",
+    "слышанный",
+    "слышат",
+    "слышатся",
+    "слышать",
+    "слышен",
+    "слышим",
+    "слышит",
+    "слышите",
+    "слышится",
+    "слышишь",
+    "слышна",
+    "слышнее",
+    "слышней",
+    "слышно",
+    "слышны",
+    "слышный",
+    "слышным",
+    "слышу",
+    "слышь",
+    "слюбится",
+    "слягу",
+    "слякоть",
+    "смазанные",
+    "смазано",
+    "смазаны",
+    "смазать",
+    "смазлива",
+    "смазные",
+    "смак",
+    "смастерила",
+    "сматывалось",
+    "смахнет",
+    "смахнул",
+    "смахнула",
+    "смеет",
+    "смеете",
+    "смеетесь",
+    "смеется",
+    "смеешь",
+    "смеешься",
+    "смеженные",
+    "смежил",
+    "смежную",
+    "смей",
+    "смейся",
+    "смейте",
+    "смейтесь",
+    "смекали",
+    "смекнул",
+    "смел",
+    "смела",
+    "смелая",
+    "смелее",
+    "смелей",
+    "смели",
+    "смело",
+    "смелой",
+    "смелость",
+    "смелостью",
+    "смелую",
+    "смелы",
+    "смелые",
+    "смелый",
+    "смелым",
+    "смель",
+    "смене",
+    "смени",
+    "сменил",
+    "сменились",
+    "сменилось",
+    "сменился",
+    "сменится",
+    "сменить",
+    "смену",
+    "сменяемых",
+    "сменяла",
+    "сменялась",
+    "сменялись",
+    "сменялось",
+    "сменять",
+    "сменяются",
+    "смердит",
+    "смерить",
+    "смеркается",
+    "смеркаться",
+    "смерклось",
+    "смертей",
+    "смертельно",
+    "смертельною",
+    "смертельную",
+    "смертельных",
+    "смерти",
+    "смертию",
+    "смертная",
+    "смертного",
+    "смертной",
+    "смертном",
+    "смертную",
+    "смертный",
+    "смертным",
+    "смертоубийству",
+    "смерть",
+    "смертью",
+    "смерч",
+    "смести",
+    "смесь",
+    "сметаешь",
+    "сметал",
+    "сметания",
+    "сметаной",
+    "сметаны",
+    "смети",
+    "сметливая",
+    "смету",
+    "сметы",
+    "сметь",
+    "смех",
+    "смеха",
+    "смехе",
+    "смехом",
+    "смеху",
+    "смешавшись",
+    "смешал",
+    "смешала",
+    "смешалась",
+    "смешались",
+    "смешанные",
+    "смешанный",
+    "смешения",
+    "смешивал",
+    "смешивала",
+    "смешивался",
+    "смешиваясь",
+    "смешил",
+    "смешит",
+    "смешить",
+    "смешлив",
+    "смешливая",
+    "смешливого",
+    "смешливых",
+    "смешная",
+    "смешнее",
+    "смешно",
+    "смешного",
+    "смешное",
+    "смешной",
+    "смешную",
+    "смешны",
+    "смешные",
+    "смешным",
+    "смешными",
+    "смешон",
+    "смею",
+    "смеюсь",
+    "смеют",
+    "смеются",
+    "смеющееся",
+    "смеющейся",
+    "смеющиеся",
+    "смеющимися",
+    "смея",
+    "смеялась",
+    "смеялись",
+    "смеялось",
+    "смеялся",
+    "смеясь",
+    "смеяться",
+    "смигнув",
+    "смирен",
+    "смиренен",
+    "смирением",
+    "смиренная",
+    "смиренней",
+    "смиреннейший",
+    "смиренно",
+    "смирит",
+    "смирительный",
+    "смирится",
+    "смириться",
+    "смирно",
+    "смирному",
+    "смирные",
+    "смирный",
+    "смирных",
+    "смирю",
+    "смирялась",
+    "смирялся",
+    "смиряются",
+    "смог",
+    "смогла",
+    "смогли",
+    "смогу",
+    "смоет",
+    "сможем",
+    "сможет",
+    "смоле",
+    "смоленском",
+    "смолист",
+    "смолк",
+    "смолкает",
+    "смолкай",
+    "смолкали",
+    "смолкло",
+    "смолкнет",
+    "смолой",
+    "смолол",
+    "смолчал",
+    "смольный",
+    "сморил",
+    "сморила",
+    "сморкается",
+    "сморкался",
+    "смородинной",
+    "смородинном",
+    "смородинную",
+    "смородинные",
+    "смородинным",
+    "смородиновку",
+    "смородиновой",
+    "смородиновую",
+    "сморозил",
+    "сморщенной",
+    "сморщены",
+    "сморщившись",
+    "сморщится",
+    "смотр",
+    "смотревшего",
+    "смотревшее",
+    "смотревшей",
+    "смотревшую",
+    "смотрел",
+    "смотрела",
+    "смотрели",
+    "смотрело",
+    "смотрель",
+    "смотреть",
+    "смотри",
+    "смотрим",
+    "смотрит",
+    "смотрите",
+    "смотрителя",
+    "смотрится",
+    "смотришь",
+    "смотрю",
+    "смотря",
+    "смотрят",
+    "смочена",
+    "смоченную",
+    "смочены",
+    "смою",
+    "смрад",
+    "смрадною",
+    "смрадную",
+    "смрадом",
+    "смуглая",
+    "смугловатый",
+    "смуглое",
+    "смуглом",
+    "смуглою",
+    "смуглые",
+    "смуглый",
+    "смуглым",
+    "смуглых",
+    "смутен",
+    "смутившись",
+    "смутила",
+    "смутилась",
+    "смутился",
+    "смутить",
+    "смутно",
+    "смутное",
+    "смутной",
+    "смутном",
+    "смутные",
+    "смутный",
+    "смутным",
+    "смутных",
+    "смутясь",
+    "смущавшей",
+    "смущает",
+    "смущается",
+    "смущайся",
+    "смущал",
+    "смущала",
+    "смущали",
+    "смущались",
+    "смущался",
+    "смущать",
+    "смущаться",
+    "смущаю",
+    "смущаюсь",
+    "смущаясь",
+    "смущен",
+    "смущена",
+    "смущение",
+    "смущением",
+    "смущении",
+    "смущения",
+    "смущенная",
+    "смущенно",
+    "смущенный",
+    "смущенным",
+    "смущеньи",
+    "смущенья",
+    "смываешь",
+    "смывать",
+    "смывая",
+    "смыкала",
+    "смыкая",
+    "смысл",
+    "смысла",
+    "смысле",
+    "смыслит",
+    "смыслишь",
+    "смыслом",
+    "смыслу",
+    "смысль",
+    "смысля",
+    "смыслят",
+    "смытый",
+    "смыть",
+    "смычкам",
+    "смычки",
+    "смычок",
+    "смягчают",
+    "смягчающие",
+    "смягчая",
+    "смягчению",
+    "смягчившись",
+    "смягчился",
+    "смягчить",
+    "смял",
+    "смят",
+    "смятение",
+    "смятенная",
+    "смятенье",
+    "смятеньи",
+    "смятой",
+    "смятые",
+    "смятый",
+    "сна",
+    "снабжает",
+    "снадобья",
+    "снам",
+    "снами",
+    "снаружи",
+    "снастью",
+    "снах",
+    "сначала",
+    "сне",
+    "снег",
+    "снега",
+    "снегами",
+    "снеговая",
+    "снеговой",
+    "снеговою",
+    "снеговую",
+    "снегом",
+    "снегу",
+    "снедает",
+    "снедающий",
+    "снедая",
+    "снежинок",
+    "снежки",
+    "снежком",
+    "снежку",
+    "снежная",
+    "снежно",
+    "снежной",
+    "снежном",
+    "снежному",
+    "снежную",
+    "снежные",
+    "снежный",
+    "снежных",
+    "снес",
+    "снесем",
+    "снесешь",
+    "снеси",
+    "снесите",
+    "снесла",
+    "снести",
+    "снизойти",
+    "снизошла",
+    "снизу",
+    "снилась",
+    "снились",
+    "снилось",
+    "снился",
+    "снимает",
+    "снимаете",
+    "снимаешь",
+    "снимала",
+    "снималась",
+    "снимали",
+    "снимать",
+    "снимаю",
+    "снимают",
+    "снимая",
+    "снимет",
+    "снимитесь",
+    "сниму",
+    "снисходителен",
+    "снисходительна",
+    "снисходительная",
+    "снисходительно",
+    "снисходительного",
+    "снисходительной",
+    "снисходительном",
+    "снисходительностью",
+    "снисходительною",
+    "снисходительную",
+    "снисходительным",
+    "снисхождение",
+    "снисхождением",
+    "снисхождения",
+    "снится",
+    "сниться",
+    "снов",
+    "снова",
+    "сновал",
+    "сновать",
+    "сновидений",
+    "сновидцу",
+    "сном",
+    "сноп",
+    "снопы",
+    "сноровки",
+    "сносили",
+    "сносить",
+    "сносное",
+    "сносному",
+    "сносные",
+    "сносный",
+    "сношение",
+    "сношений",
+    "сношения",
+    "сну",
+    "снурке",
+    "снурку",
+    "снурок",
+    "снуют",
+    "снующих",
+    "сны",
+    "снюхались",
+    "снял",
+    "сняла",
+    "сняли",
+    "снят",
+    "снята",
+    "снято",
+    "снятся",
+    "снять",
+    "со",
+    "собак",
+    "собака",
+    "собакам",
+    "собаками",
+    "собаке",
+    "собаки",
+    "собаку",
+    "собачий",
+    "собачкой",
+    "собачонка",
+    "собачонку",
+    "собачонок",
+    "собачьей",
+    "собачьи",
+    "собачьих",
+    "соберет",
+    "соберется",
+    "соберу",
+    "соберусь",
+    "соберутся",
+    "собеседника",
+    "собеседниками",
+    "собеседников",
+    "собеседником",
+    "собираемся",
+    "собирает",
+    "собираетесь",
+    "собирается",
+    "собираешь",
+    "собираешься",
+    "собирала",
+    "собиралась",
+    "собирали",
+    "собирались",
+    "собирался",
+    "собирательным",
+    "собирателя",
+    "собирать",
+    "собираться",
+    "собираюсь",
+    "собираются",
+    "собираясь",
+    "соблазненная",
+    "соблазнитель",
+    "соблазнительная",
+    "соблазнительно",
+    "соблазнительное",
+    "соблазнительною",
+    "соблазнительную",
+    "соблазнительные",
+    "соблазнительным",
+    "соблазнительных",
+    "соблазнителям",
+    "соблазнить",
+    "соблазнял",
+    "соблазняю",
+    "соблазняют",
+    "соблазняя",
+    "соблюдал",
+    "соблюдаю",
+    "соблюдения",
+    "соблюсти",
+    "собой",
+    "соболезнованием",
+    "соболезнуя",
+    "собор",
+    "собора",
+    "соборе",
+    "собору",
+    "собою",
+    "собрав",
+    "собравшийся",
+    "собравшимися",
+    "собравшись",
+    "собравшихся",
+    "собрал",
+    "собралась",
+    "собрали",
+    "собрались",
+    "собрался",
+    "собран",
+    "собрание",
+    "собрании",
+    "собранию",
+    "собрания",
+    "собранье",
+    "собрат",
+    "собрать",
+    "собраться",
+    "собственная",
+    "собственно",
+    "собственного",
+    "собственное",
+    "собственной",
+    "собственном",
+    "собственному",
+    "собственноручно",
+    "собственноручное",
+    "собственность",
+    "собственную",
+    "собственные",
+    "собственный",
+    "собственным",
+    "собственными",
+    "собственных",
+    "событие",
+    "событии",
+    "событий",
+    "события",
+    "событиям",
+    "событиями",
+    "событиях",
+    "собьет",
+    "собьешь",
+    "собьешься",
+    "собьют",
+    "сова",
+    "совал",
+    "соваться",
+    "совершаемой",
+    "совершает",
+    "совершается",
+    "совершал",
+    "совершала",
+    "совершались",
+    "совершалось",
+    "совершать",
+    "совершаются",
+    "совершающиеся",
+    "совершения",
+    "совершенная",
+    "совершеннейшем",
+    "совершеннейший",
+    "совершенно",
+    "совершенного",
+    "совершенное",
+    "совершеннолетия",
+    "совершенном",
+    "совершенному",
+    "совершенною",
+    "совершенный",
+    "совершенным",
+    "совершенными",
+    "совершенных",
+    "совершено",
+    "совершенства",
+    "совершенство",
+    "совершенствуя",
+    "совершившийся",
+    "совершил",
+    "совершила",
+    "совершилось",
+    "совершит",
+    "совершить",
+    "совести",
+    "совестился",
+    "совестится",
+    "совестливо",
+    "совестно",
+    "совесть",
+    "совестью",
+    "совет",
+    "совета",
+    "советами",
+    "совете",
+    "советник",
+    "советника",
+    "советники",
+    "советником",
+    "советнику",
+    "советов",
+    "советовал",
+    "советовала",
+    "советовались",
+    "советовался",
+    "советовать",
+    "советоваться",
+    "советом",
+    "совету",
+    "советует",
+    "советуйте",
+    "советуют",
+    "советы",
+    "совещались",
+    "совещание",
+    "совещаться",
+    "совиные",
+    "совладал",
+    "совладеть",
+    "совлечет",
+    "совмещаются",
+    "совокупного",
+    "совокупность",
+    "совпадает",
+    "совпадать",
+    "совпадение",
+    "совпадением",
+    "совпадений",
+    "совпало",
+    "соврав",
+    "соврал",
+    "совралась",
+    "соврать",
+    "современно",
+    "современного",
+    "современное",
+    "современный",
+    "современными",
+    "соврешь",
+    "совсем",
+    "совьется",
+    "согбен",
+    "согбенный",
+    "согласен",
+    "согласие",
+    "согласии",
+    "согласилась",
+    "согласились",
+    "согласился",
+    "согласись",
+    "согласитесь",
+    "согласится",
+    "согласиться",
+    "согласишься",
+    "согласия",
+    "согласна",
+    "согласная",
+    "согласно",
+    "согласны",
+    "согласный",
+    "согласятся",
+    "соглашаетесь",
+    "соглашается",
+    "соглашалась",
+    "соглашались",
+    "соглашался",
+    "соглашаться",
+    "соглашаюсь",
+    "соглашение",
+    "соглашении",
+    "соглашусь",
+    "соглядатай",
+    "согнать",
+    "согнется",
+    "согнув",
+    "согнувшись",
+    "согревать",
+    "согреваться",
+    "согрелась",
+    "согрет",
+    "согрето",
+    "согреться",
+    "согрешил",
+    "согрешишь",
+    "согрею",
+    "содержал",
+    "содержание",
+    "содержанием",
+    "содержании",
+    "содержания",
+    "содержатель",
+    "содержать",
+    "содержит",
+    "содом",
+    "содрал",
+    "содрогалась",
+    "содрогался",
+    "содроганием",
+    "содрогания",
+    "содрогнется",
+    "содрогнулся",
+    "содрогнуться",
+    "соды",
+    "соединенную",
+    "соединенным",
+    "соединил",
+    "соединилась",
+    "соединилось",
+    "соединимся",
+    "соединить",
+    "соединяющая",
+    "сожалел",
+    "сожаление",
+    "сожалением",
+    "сожалению",
+    "сожаления",
+    "сожаленья",
+    "сожалеть",
+    "сожалею",
+    "сожалея",
+    "сожгло",
+    "сожжен",
+    "сожжена",
+    "сожженные",
+    "сожженный",
+    "сожжено",
+    "сожителе",
+    "сожительницы",
+    "сожителя",
+    "сожмет",
+    "созвал",
+    "созваны",
+    "созвездиям",
+    "созвучий",
+    "создавали",
+    "создавать",
+    "создал",
+    "создала",
+    "создан",
+    "создана",
+    "создание",
+    "созданием",
+    "созданию",
+    "создания",
+    "созданная",
+    "созданного",
+    "созданной",
+    "созданном",
+    "созданную",
+    "созданный",
+    "созданным",
+    "создано",
+    "созданы",
+    "созданьем",
+    "созданья",
+    "создательница",
+    "создать",
+    "создают",
+    "создающими",
+    "созерцание",
+    "созерцании",
+    "созерцания",
+    "созерцательной",
+    "созерцать",
+    "сознав",
+    "сознавал",
+    "сознавала",
+    "сознавалось",
+    "сознавать",
+    "сознаваться",
+    "сознавая",
+    "сознает",
+    "сознаетесь",
+    "сознается",
+    "сознайся",
+    "сознайтесь",
+    "сознал",
+    "сознала",
+    "сознался",
+    "сознание",
+    "сознанием",
+    "сознании",
+    "сознанию",
+    "сознания",
+    "сознанье",
+    "сознаньем",
+    "сознанья",
+    "сознательная",
+    "сознательнее",
+    "сознательно",
+    "сознательные",
+    "сознательным",
+    "сознательных",
+    "сознать",
+    "сознаться",
+    "сознаю",
+    "сознаюсь",
+    "сознаются",
+    "созревает",
+    "созревало",
+    "созревшей",
+    "созреет",
+    "созрел",
+    "созрела",
+    "созрели",
+    "созрело",
+    "созреть",
+    "созывали",
+    "сойдемся",
+    "сойдет",
+    "сойдется",
+    "сойдешь",
+    "сойду",
+    "сойдусь",
+    "сойдутся",
+    "сойдясь",
+    "сойти",
+    "сойтись",
+    "сок",
+    "сока",
+    "соки",
+    "сокол",
+    "соколиными",
+    "соколов",
+    "соколы",
+    "сократить",
+    "сокращают",
+    "сокращение",
+    "сокращения",
+    "сокращены",
+    "сокровенные",
+    "сокровенный",
+    "сокровенных",
+    "сокровищ",
+    "сокровища",
+    "сокровище",
+    "сокройся",
+    "сокрушаться",
+    "сокрушением",
+    "сокрытый",
+    "солгал",
+    "солгать",
+    "солгу",
+    "солдат",
+    "солдата",
+    "солдатам",
+    "солдатка",
+    "солдатом",
+    "солдатскими",
+    "солдатское",
+    "солдатской",
+    "солдаты",
+    "солений",
+    "соленого",
+    "соленому",
+    "соленый",
+    "соленым",
+    "соленье",
+    "соленья",
+    "солжет",
+    "соли",
+    "солиднее",
+    "солиднейший",
+    "солидно",
+    "солидной",
+    "солидному",
+    "солидностью",
+    "солидный",
+    "солидным",
+    "солидными",
+    "солнечно",
+    "солнечного",
+    "солнечной",
+    "солнечный",
+    "солнечным",
+    "солнечных",
+    "солнца",
+    "солнце",
+    "солнцем",
+    "солнцу",
+    "солнышко",
+    "соловей",
+    "соловьев",
+    "соловьи",
+    "соловьиная",
+    "соловьиного",
+    "соловьином",
+    "соловьиный",
+    "соловья",
+    "солома",
+    "соломе",
+    "соломенной",
+    "соломинку",
+    "солонами",
+    "солонину",
+    "солонины",
+    "солонка",
+    "солонкой",
+    "солонку",
+    "соль",
+    "сольнес",
+    "сольнеса",
+    "солью",
+    "сольются",
+    "сом",
+    "сомкнет",
+    "сомкни",
+    "сомкнутых",
+    "сомкнуть",
+    "сомневаетесь",
+    "сомневаешься",
+    "сомневайтесь",
+    "сомневался",
+    "сомневаться",
+    "сомневаясь",
+    "сомнение",
+    "сомнением",
+    "сомнений",
+    "сомнению",
+    "сомнения",
+    "сомнениями",
+    "сомнителен",
+    "сомнительная",
+    "сомнительно",
+    "сомнительного",
+    "сон",
+    "соне",
+    "соней",
+    "сонетку",
+    "сонечка",
+    "сонечке",
+    "сонечки",
+    "сонечкин",
+    "сонечкина",
+    "сонечкиной",
+    "сонечкой",
+    "сонечку",
+    "сони",
+    "сонина",
+    "сонливости",
+    "сонливые",
+    "сонм",
+    "сонмы",
+    "сонная",
+    "сонник",
+    "сонно",
+    "сонного",
+    "сонное",
+    "сонной",
+    "сонному",
+    "сонною",
+    "сонную",
+    "сонные",
+    "сонный",
+    "сонным",
+    "сонными",
+    "сонных",
+    "сонь",
+    "соню",
+    "сонюшка",
+    "соня",
+    "соображает",
+    "соображал",
+    "соображали",
+    "соображать",
+    "соображаю",
+    "соображают",
+    "соображая",
+    "соображение",
+    "соображений",
+    "соображения",
+    "соображениям",
+    "соображениях",
+    "сообразив",
+    "сообразившись",
+    "сообразил",
+    "сообразила",
+    "сообразит",
+    "сообразите",
+    "сообразить",
+    "сообразишь",
+    "сообразно",
+    "сообразные",
+    "сообразный",
+    "сообразят",
+    "сообщает",
+    "сообщается",
+    "сообщала",
+    "сообщались",
+    "сообщать",
+    "сообщаю",
+    "сообщая",
+    "сообщению",
+    "сообщено",
+    "сообщества",
+    "сообщи",
+    "сообщил",
+    "сообщила",
+    "сообщили",
+    "сообщился",
+    "сообщим",
+    "сообщите",
+    "сообщительнее",
+    "сообщительный",
+    "сообщить",
+    "сообщу",
+    "соответственного",
+    "соответствовала",
+    "соответствует",
+    "соответствующими",
+    "соперник",
+    "соперника",
+    "соперников",
+    "соперницы",
+    "соперничать",
+    "сопеть",
+    "сопит",
+    "сопля",
+    "сопляк",
+    "сопоставить",
+    "сопоставление",
+    "сопоставлении",
+    "сопоставлять",
+    "соприкасались",
+    "соприкосновений",
+    "соприкосновения",
+    "сопричастные",
+    "сопровождаемая",
+    "сопровождаемое",
+    "сопровождаемый",
+    "сопровождает",
+    "сопровождается",
+    "сопровождала",
+    "сопровождалась",
+    "сопровождался",
+    "сопровождать",
+    "сопровождаться",
+    "сопровождают",
+    "сопровождении",
+    "сопротивление",
+    "сопротивлений",
+    "сопротивлялась",
+    "сопротивлялся",
+    "сопротивляться",
+    "сопряженные",
+    "сопутников",
+    "сопутствовал",
+    "сопутствующих",
+    "сопят",
+    "сор",
+    "соразмеряла",
+    "соратник",
+    "сорвал",
+    "сорвала",
+    "сорвалась",
+    "сорвали",
+    "сорвалось",
+    "сорвался",
+    "сорвана",
+    "сорванец",
+    "сорванным",
+    "сорвать",
+    "сорвет",
+    "сорвется",
+    "сорившему",
+    "сорила",
+    "сорин",
+    "сорина",
+    "сорину",
+    "сориным",
+    "сорить",
+    "сорная",
+    "сорок",
+    "сорока",
+    "сорокалетний",
+    "сороковой",
+    "сороковых",
+    "сором",
+    "соромники",
+    "сорта",
+    "сортировок",
+    "сортов",
+    "сору",
+    "сосал",
+    "сосед",
+    "соседа",
+    "соседей",
+    "соседи",
+    "соседнего",
+    "соседней",
+    "соседних",
+    "соседняя",
+    "соседстве",
+    "соседство",
+    "соседству",
+    "соседу",
+    "соседям",
+    "сосен",
+    "сосет",
+    "соси",
+    "соскандалит",
+    "соскочил",
+    "соскочить",
+    "соскучились",
+    "соскучился",
+    "соскучитесь",
+    "соскучишься",
+    "сослаться",
+    "сословия",
+    "сослуживец",
+    "сослуживцев",
+    "сослуживцу",
+    "сослуживцы",
+    "сосновка",
+    "сосновке",
+    "сосновки",
+    "сосновый",
+    "сосну",
+    "соснул",
+    "соснуть",
+    "сосны",
+    "сосредоточеннее",
+    "сосредоточенное",
+    "сосредоточенной",
+    "сосредоточенности",
+    "сосредоточенность",
+    "сосредоточенный",
+    "сосредоточенным",
+    "сосредоточены",
+    "сосредоточивалось",
+    "сосредоточивался",
+    "сосредоточивая",
+    "сосредоточившихся",
+    "сосредоточилась",
+    "сосредоточилось",
+    "сосредоточится",
+    "сосредоточить",
+    "сосредоточиться",
+    "состав",
+    "составе",
+    "составил",
+    "составила",
+    "составили",
+    "составит",
+    "составится",
+    "составить",
+    "составишь",
+    "составлен",
+    "составлял",
+    "составляли",
+    "составлялись",
+    "составляло",
+    "составлять",
+    "составляю",
+    "составляют",
+    "составляющих",
+    "составов",
+    "состареюсь",
+    "состарившимся",
+    "состарилась",
+    "состарилось",
+    "состарился",
+    "состариться",
+    "состоим",
+    "состоит",
+    "состоите",
+    "состоится",
+    "состоишь",
+    "состою",
+    "состоявшийся",
+    "состоял",
+    "состояла",
+    "состоялись",
+    "состояло",
+    "состоялось",
+    "состоялся",
+    "состояние",
+    "состоянием",
+    "состоянии",
+    "состояний",
+    "состоянию",
+    "состояния",
+    "состоять",
+    "состоящая",
+    "сострадай",
+    "сострадание",
+    "состраданием",
+    "сострадания",
+    "сострить",
+    "состряпала",
+    "состряпали",
+    "состязались",
+    "сосуд",
+    "сосуде",
+    "сосульках",
+    "сосчитав",
+    "сосчитает",
+    "сосчитаешь",
+    "сосчитал",
+    "сосчитала",
+    "сосчитать",
+    "сот",
+    "сотая",
+    "сотворил",
+    "соткать",
+    "соткутся",
+    "сотни",
+    "сотню",
+    "сотри",
+    "сотрясался",
+    "сотрясающий",
+    "сотрясая",
+    "сотрясения",
+    "сотый",
+    "соус",
+    "соусниками",
+    "соусов",
+    "соусу",
+    "софа",
+    "софе",
+    "софи",
+    "софистика",
+    "софой",
+    "софочка",
+    "софочкой",
+    "софочку",
+    "софье",
+    "софьей",
+    "софьи",
+    "софью",
+    "софья",
+    "сохнут",
+    "сохранение",
+    "сохранения",
+    "сохрани",
+    "сохранившее",
+    "сохранившиеся",
+    "сохранившийся",
+    "сохранившими",
+    "сохранившихся",
+    "сохранил",
+    "сохранилась",
+    "сохранились",
+    "сохранился",
+    "сохранить",
+    "сохранишь",
+    "сохранявшегося",
+    "сохраняет",
+    "сохраняете",
+    "сохраняется",
+    "сохранял",
+    "сохраняло",
+    "сохраняют",
+    "сохраняя",
+    "соху",
+    "социалистов",
+    "социальная",
+    "социального",
+    "социальную",
+    "социальные",
+    "социальный",
+    "социальных",
+    "соцьялизме",
+    "сочетаешь",
+    "сочетались",
+    "сочинена",
+    "сочинение",
+    "сочинении",
+    "сочинений",
+    "сочинения",
+    "сочинениях",
+    "сочинился",
+    "сочиниль",
+    "сочинителей",
+    "сочинители",
+    "сочинитель",
+    "сочинителя",
+    "сочинить",
+    "сочиняли",
+    "сочинять",
+    "сочиняю",
+    "сочиняют",
+    "сочла",
+    "сочли",
+    "сочная",
+    "сочной",
+    "сочтены",
+    "сочтет",
+    "сочтите",
+    "сочувственно",
+    "сочувствий",
+    "сочувствию",
+    "сочувствия",
+    "сочувствовал",
+    "сочувствовать",
+    "сочувствую",
+    "сочувствующий",
+    "сочувствуя",
+    "сошедшихся",
+    "сошел",
+    "сошелся",
+    "сошла",
+    "сошлась",
+    "сошлется",
+    "сошли",
+    "сошлись",
+    "сошло",
+    "сошлось",
+    "сошлются",
+    "сошьет",
+    "сошью",
+    "сощуришь",
+    "сощуря",
+    "спавшего",
+    "спавший",
+    "спадать",
+    "спадающая",
+    "спадая",
+    "спадет",
+    "спазму",
+    "спал",
+    "спала",
+    "спалена",
+    "спаленных",
+    "спали",
+    "спало",
+    "спалось",
+    "спальне",
+    "спальней",
+    "спальни",
+    "спальной",
+    "спальню",
+    "спальня",
+    "спанье",
+    "спанья",
+    "спаржа",
+    "спаржи",
+    "спаржу",
+    "спас",
+    "спасаешь",
+    "спасала",
+    "спасалась",
+    "спасем",
+    "спасен",
+    "спасение",
+    "спасением",
+    "спасения",
+    "спасенный",
+    "спасенных",
+    "спасены",
+    "спасенье",
+    "спасенья",
+    "спасет",
+    "спаси",
+    "спасибо",
+    "спасите",
+    "спасительнейшее",
+    "спасительницы",
+    "спасительно",
+    "спасительного",
+    "спасительной",
+    "спасительный",
+    "спасся",
+    "спасти",
+    "спастись",
+    "спасу",
+    "спасши",
+    "спать",
+    "спаяешь",
+    "спб",
+    "спектакль",
+    "спектакля",
+    "спекулянт",
+    "спела",
+    "спеленутое",
+    "спели",
+    "спелое",
+    "сперва",
+    "спереди",
+    "сперлось",
+    "спермацетной",
+    "спертый",
+    "спесивая",
+    "спеть",
+    "специальности",
+    "специальный",
+    "спеша",
+    "спешат",
+    "спешащих",
+    "спеши",
+    "спешил",
+    "спешила",
+    "спешили",
+    "спешит",
+    "спешите",
+    "спешить",
+    "спешишь",
+    "спешу",
+    "спи",
+    "спим",
+    "спина",
+    "спине",
+    "спинка",
+    "спинке",
+    "спинкой",
+    "спинку",
+    "спинного",
+    "спинном",
+    "спиной",
+    "спиною",
+    "спину",
+    "спины",
+    "спирается",
+    "спиралось",
+    "спиралью",
+    "спиридоныч",
+    "спиридоныча",
+    "спирт",
+    "спирта",
+    "спиртами",
+    "спирту",
+    "спирты",
+    "списки",
+    "списков",
+    "список",
+    "спит",
+    "спите",
+    "спитой",
+    "спится",
+    "спитым",
+    "спицей",
+    "спицы",
+    "спичей",
+    "спичек",
+    "спичка",
+    "спички",
+    "спишь",
+    "сплав",
+    "сплело",
+    "сплелось",
+    "сплести",
+    "сплетен",
+    "сплетенных",
+    "сплетеньи",
+    "сплетней",
+    "сплетни",
+    "сплетник",
+    "сплетня",
+    "сплетутся",
+    "сплин",
+    "сплошным",
+    "сплошь",
+    "сплю",
+    "спляши",
+    "сподручница",
+    "сподряд",
+    "споем",
+    "споемте",
+    "споет",
+    "спой",
+    "спойте",
+    "спокоен",
+    "спокой",
+    "спокойна",
+    "спокойная",
+    "спокойнее",
+    "спокойно",
+    "спокойного",
+    "спокойное",
+    "спокойной",
+    "спокойном",
+    "спокойною",
+    "спокойный",
+    "спокойным",
+    "спокойствие",
+    "спокойствием",
+    "спокойствии",
+    "спокойствию",
+    "спокойствия",
+    "сполетском",
+    "сполз",
+    "сползает",
+    "сползаю",
+    "сползая",
+    "сполна",
+    "спор",
+    "спора",
+    "спорах",
+    "споре",
+    "спорил",
+    "спорили",
+    "спорим",
+    "спорит",
+    "спорится",
+    "спорить",
+    "споришь",
+    "спорную",
+    "споров",
+    "спору",
+    "споры",
+    "спорю",
+    "споря",
+    "спорят",
+    "способ",
+    "способами",
+    "способе",
+    "способен",
+    "способна",
+    "способнее",
+    "способно",
+    "способное",
+    "способностей",
+    "способности",
+    "способность",
+    "способностью",
+    "способностям",
+    "способностями",
+    "способностях",
+    "способны",
+    "способные",
+    "способный",
+    "способным",
+    "способных",
+    "способом",
+    "способствовал",
+    "способствовала",
+    "способствовало",
+    "способствовать",
+    "способствует",
+    "способу",
+    "споспешествования",
+    "споткаюсь",
+    "споткнулась",
+    "споткнулись",
+    "спотыкаясь",
+    "спохватившись",
+    "спохватился",
+    "спою",
+    "справа",
+    "справедлива",
+    "справедливая",
+    "справедливее",
+    "справедливей",
+    "справедливо",
+    "справедливое",
+    "справедливом",
+    "справедливости",
+    "справедливость",
+    "справедливостью",
+    "справедливые",
+    "справится",
+    "справить",
+    "справиться",
+    "справишься",
+    "справки",
+    "справляйтесь",
+    "справлялась",
+    "справлялся",
+    "справляться",
+    "справляю",
+    "справок",
+    "справочку",
+    "справьтесь",
+    "справят",
+    "спрашивает",
+    "спрашиваете",
+    "спрашиваешь",
+    "спрашивай",
+    "спрашивайте",
+    "спрашивал",
+    "спрашивала",
+    "спрашивали",
+    "спрашивать",
+    "спрашиваю",
+    "спрашивают",
+    "спрашивая",
+    "спро",
+    "спровадить",
+    "спрос",
+    "спроси",
+    "спросив",
+    "спросил",
+    "спросила",
+    "спросили",
+    "спросим",
+    "спросит",
+    "спросите",
+    "спросить",
+    "спросишь",
+    "спросонья",
+    "спросят",
+    "спрошу",
+    "спруты",
+    "спрыгну",
+    "спрыгнувших",
+    "спрыгнул",
+    "спрятав",
+    "спрятал",
+    "спрятала",
+    "спряталась",
+    "спрятали",
+    "спрятались",
+    "спрятался",
+    "спрятана",
+    "спрятанный",
+    "спрятано",
+    "спрятаны",
+    "спрятать",
+    "спрятаться",
+    "спрячется",
+    "спрячусь",
+    "спрячьте",
+    "спугнет",
+    "спугнув",
+    "спугнул",
+    "спугнуть",
+    "спуск",
+    "спускавшая",
+    "спускавшаяся",
+    "спускает",
+    "спускал",
+    "спускала",
+    "спускалась",
+    "спускать",
+    "спускаться",
+    "спускаю",
+    "спуская",
+    "спускаясь",
+    "спустившись",
+    "спустил",
+    "спустила",
+    "спустились",
+    "спустился",
+    "спустим",
+    "спустить",
+    "спуститься",
+    "спустя",
+    "спутал",
+    "спутан",
+    "спутанных",
+    "спутник",
+    "спутника",
+    "спутники",
+    "спутнику",
+    "спущены",
+    "спущу",
+    "спьяна",
+    "спьяну",
+    "спят",
+    "спятил",
+    "спящего",
+    "спящей",
+    "спящие",
+    "спящий",
+    "спящим",
+    "спящих",
+    "спящую",
+    "сравнен",
+    "сравнение",
+    "сравнении",
+    "сравнения",
+    "сравнивать",
+    "сравнила",
+    "сравнительно",
+    "сравниться",
+    "сражает",
+    "сражается",
+    "сражениях",
+    "сраженный",
+    "сразу",
+    "срам",
+    "срама",
+    "срамил",
+    "срамила",
+    "срамится",
+    "срамить",
+    "срамишься",
+    "сребреников",
+    "сребристой",
+    "сребристый",
+    "сребристым",
+    "среда",
+    "средам",
+    "среде",
+    "среди",
+    "средиземном",
+    "средине",
+    "средины",
+    "среднего",
+    "средней",
+    "среднем",
+    "средний",
+    "средним",
+    "средних",
+    "среднюю",
+    "средой",
+    "средств",
+    "средства",
+    "средствами",
+    "средствах",
+    "средствие",
+    "средство",
+    "средством",
+    "средству",
+    "среду",
+    "среды",
+    "средь",
+    "срезал",
+    "срезала",
+    "срезана",
+    "срезать",
+    "сродни",
+    "срок",
+    "срока",
+    "сроках",
+    "сроке",
+    "сроки",
+    "сроком",
+    "сруб",
+    "срубить",
+    "срывает",
+    "срываешь",
+    "срывался",
+    "срывая",
+    "срываясь",
+    "сряду",
+    "ссадил",
+    "ссиня",
+    "ссор",
+    "ссора",
+    "ссорами",
+    "ссоре",
+    "ссорилась",
+    "ссорились",
+    "ссорился",
+    "ссоримся",
+    "ссорится",
+    "ссориться",
+    "ссору",
+    "ссорятся",
+    "ссылалась",
+    "ссылался",
+    "ссылают",
+    "ссылаясь",
+    "ссылка",
+    "ссылками",
+    "ссылке",
+    "ссылки",
+    "ссылкой",
+    "ссыльно",
+    "ссыльные",
+    "ссыпано",
+    "ста",
+    "став",
+    "ставало",
+    "ставил",
+    "ставила",
+    "ставились",
+    "ставило",
+    "ставит",
+    "ставите",
+    "ставить",
+    "ставка",
+    "ставлю",
+    "ставней",
+    "ставни",
+    "ставнями",
+    "ставших",
+    "ставь",
+    "ставьте",
+    "ставя",
+    "ставят",
+    "ставятся",
+    "стад",
+    "стада",
+    "стадвадцатипятирублевой",
+    "стадом",
+    "стай",
+    "стакан",
+    "стакана",
+    "стаканами",
+    "стакане",
+    "стаканов",
+    "стаканом",
+    "стаканчик",
+    "стаканчика",
+    "стаканчиками",
+    "стаканчики",
+    "стаканчику",
+    "стаканы",
+    "стакнулся",
+    "стал",
+    "стала",
+    "стали",
+    "сталкивала",
+    "сталкиваться",
+    "сталкиваются",
+    "сталкивающихся",
+    "сталкиваясь",
+    "стало",
+    "сталось",
+    "сталь",
+    "стальная",
+    "стальном",
+    "стальным",
+    "стальными",
+    "стальных",
+    "сталью",
+    "стами",
+    "стан",
+    "стана",
+    "стане",
+    "станем",
+    "станет",
+    "станете",
+    "станется",
+    "станешь",
+    "станислава",
+    "станке",
+    "становившиеся",
+    "становилась",
+    "становились",
+    "становилось",
+    "становился",
+    "становимся",
+    "становитесь",
+    "становится",
+    "становитца",
+    "становиться",
+    "становишься",
+    "становлюсь",
+    "становьем",
+    "становясь",
+    "становятся",
+    "станом",
+    "стану",
+    "станут",
+    "станцией",
+    "станции",
+    "станцию",
+    "станция",
+    "стань",
+    "станьте",
+    "стар",
+    "стара",
+    "стараетесь",
+    "старается",
+    "старалась",
+    "старались",
+    "старался",
+    "старанием",
+    "старания",
+    "стараниям",
+    "стараниями",
+    "стараться",
+    "стараюсь",
+    "стараются",
+    "старая",
+    "стараясь",
+    "старее",
+    "стареем",
+    "стареемся",
+    "стареньком",
+    "старец",
+    "стареющей",
+    "стареющий",
+    "старик",
+    "старика",
+    "старикам",
+    "стариками",
+    "старики",
+    "стариков",
+    "старику",
+    "старилась",
+    "старина",
+    "старине",
+    "старинная",
+    "старинное",
+    "старинной",
+    "старинном",
+    "старинному",
+    "старинную",
+    "старинные",
+    "старинный",
+    "старинным",
+    "старинными",
+    "старину",
+    "старины",
+    "старится",
+    "старичка",
+    "старичке",
+    "старички",
+    "старичок",
+    "старичонка",
+    "старо",
+    "старого",
+    "старое",
+    "старой",
+    "старом",
+    "старому",
+    "староста",
+    "старосте",
+    "старости",
+    "старостой",
+    "старосту",
+    "старосты",
+    "старость",
+    "старою",
+    "старух",
+    "старуха",
+    "старухе",
+    "старухи",
+    "старухина",
+    "старухиной",
+    "старухиному",
+    "старухиною",
+    "старухину",
+    "старухины",
+    "старухиными",
+    "старухой",
+    "старуху",
+    "старушечий",
+    "старушечьим",
+    "старушка",
+    "старушками",
+    "старушке",
+    "старушки",
+    "старушкой",
+    "старушку",
+    "старушонка",
+    "старушонке",
+    "старушонки",
+    "старушонку",
+    "старушонок",
+    "старую",
+    "старца",
+    "старцем",
+    "старцы",
+    "старческий",
+    "старческом",
+    "старческому",
+    "старшая",
+    "старше",
+    "старшей",
+    "старшенькая",
+    "старшею",
+    "старшие",
+    "старший",
+    "старшим",
+    "старших",
+    "стары",
+    "старые",
+    "старый",
+    "старым",
+    "старыми",
+    "старых",
+    "стаскивал",
+    "статей",
+    "статейка",
+    "статейки",
+    "статейку",
+    "статен",
+    "стати",
+    "статистикой",
+    "статная",
+    "статочное",
+    "статские",
+    "статский",
+    "статским",
+    "статского",
+    "статуй",
+    "статую",
+    "статуя",
+    "стать",
+    "статье",
+    "статьи",
+    "статься",
+    "статью",
+    "статья",
+    "статьях",
+    "сташная",
+    "стащил",
+    "стащила",
+    "стащит",
+    "стащить",
+    "стаю",
+    "стая",
+    "ствола",
+    "стволов",
+    "стеблях",
+    "стеганые",
+    "стегнула",
+    "стезя",
+    "стекавшую",
+    "стекла",
+    "стеклами",
+    "стеклах",
+    "стекле",
+    "стекло",
+    "стеклом",
+    "стеклу",
+    "стеклянная",
+    "стеклянную",
+    "стеклянные",
+    "стеклянный",
+    "стеклянным",
+    "стеклярус",
+    "стелется",
+    "стелющемся",
+    "стелятся",
+    "стемнело",
+    "стен",
+    "стена",
+    "стенам",
+    "стенами",
+    "стенанье",
+    "стенах",
+    "стене",
+    "стенке",
+    "стенкой",
+    "стенку",
+    "стенные",
+    "стенных",
+    "стеной",
+    "стену",
+    "стены",
+    "степанида",
+    "степаниду",
+    "степанов",
+    "степеней",
+    "степени",
+    "степенно",
+    "степень",
+    "степи",
+    "степная",
+    "степной",
+    "степном",
+    "степную",
+    "степным",
+    "степных",
+    "степь",
+    "степью",
+    "степями",
+    "степях",
+    "стер",
+    "стерва",
+    "стерегла",
+    "стерегли",
+    "стерегут",
+    "стережет",
+    "стереотипном",
+    "стереотипных",
+    "стереть",
+    "стеречь",
+    "стерляжьей",
+    "стерпел",
+    "стерт",
+    "стертых",
+    "стеснений",
+    "стесненная",
+    "стесненно",
+    "стесненное",
+    "стесненной",
+    "стеснены",
+    "стеснило",
+    "стеснилось",
+    "стеснительно",
+    "стеснить",
+    "стесняет",
+    "стесняйтесь",
+    "стеснял",
+    "стеснялись",
+    "стеснялся",
+    "стеснять",
+    "стесняя",
+    "стесняясь",
+    "сти",
+    "стиле",
+    "стилистические",
+    "стиль",
+    "стимер",
+    "стирает",
+    "стирал",
+    "стирался",
+    "стирать",
+    "стираю",
+    "стирка",
+    "стискивал",
+    "стиснув",
+    "стиснул",
+    "стиснула",
+    "стих",
+    "стиха",
+    "стихает",
+    "стихами",
+    "стихах",
+    "стихе",
+    "стихи",
+    "стихии",
+    "стихий",
+    "стихийных",
+    "стихию",
+    "стихия",
+    "стихли",
+    "стихов",
+    "стихотворений",
+    "стихотворения",
+    "стихотворца",
+    "стлал",
+    "стлалась",
+    "стлив",
+    "сто",
+    "стога",
+    "стогами",
+    "стоил",
+    "стоила",
+    "стоили",
+    "стоило",
+    "стоим",
+    "стоит",
+    "стоите",
+    "стоить",
+    "стоическою",
+    "стоишь",
+    "стой",
+    "стойке",
+    "стойкой",
+    "стойло",
+    "стойте",
+    "стол",
+    "стола",
+    "столами",
+    "столах",
+    "столб",
+    "столба",
+    "столбик",
+    "столбняк",
+    "столбняка",
+    "столбов",
+    "столбовой",
+    "столе",
+    "столетии",
+    "столетий",
+    "столетний",
+    "столечко",
+    "столик",
+    "столика",
+    "столиках",
+    "столике",
+    "столики",
+    "столиков",
+    "столиком",
+    "столица",
+    "столице",
+    "столицу",
+    "столицы",
+    "столичный",
+    "столкновений",
+    "столкнувшись",
+    "столкнулась",
+    "столкнулись",
+    "столкнулся",
+    "столковались",
+    "столов",
+    "столовая",
+    "столовой",
+    "столовую",
+    "столовый",
+    "столовых",
+    "столом",
+    "столпами",
+    "столпившихся",
+    "столпилась",
+    "столпился",
+    "столпы",
+    "столу",
+    "столы",
+    "столыпин",
+    "столь",
+    "стольких",
+    "столько",
+    "стольку",
+    "столяра",
+    "стон",
+    "стона",
+    "стонал",
+    "стонала",
+    "стонам",
+    "стонами",
+    "стонет",
+    "стонов",
+    "стоном",
+    "стонут",
+    "стоны",
+    "стопами",
+    "стоптанные",
+    "стоптанных",
+    "стопы",
+    "сторговались",
+    "сторож",
+    "сторожа",
+    "сторожевой",
+    "сторожей",
+    "сторожило",
+    "сторожит",
+    "сторожу",
+    "сторон",
+    "сторона",
+    "сторонам",
+    "стороне",
+    "сторонись",
+    "сторонитесь",
+    "сторонке",
+    "сторонкой",
+    "стороной",
+    "сторону",
+    "стороны",
+    "сторублевого",
+    "сторублевый",
+    "стотысячной",
+    "стотысячную",
+    "стошнило",
+    "стою",
+    "стоя",
+    "стоявшая",
+    "стоявшего",
+    "стоявшей",
+    "стоявшему",
+    "стоявший",
+    "стоявших",
+    "стоявшую",
+    "стоял",
+    "стояла",
+    "стояли",
+    "стояло",
+    "стоят",
+    "стоять",
+    "стоячею",
+    "стоячим",
+    "стоящего",
+    "стоящими",
+    "стравить",
+    "страдаем",
+    "страдает",
+    "страдай",
+    "страдал",
+    "страдала",
+    "страдали",
+    "страдало",
+    "страдальнее",
+    "страдальчески",
+    "страдальческого",
+    "страдание",
+    "страданием",
+    "страдании",
+    "страданий",
+    "страданию",
+    "страдания",
+    "страданиях",
+    "страданье",
+    "страданьем",
+    "страданьице",
+    "страданью",
+    "страданья",
+    "страдать",
+    "страдаю",
+    "страдающего",
+    "страдающий",
+    "страдая",
+    "страж",
+    "страже",
+    "стражей",
+    "стражем",
+    "стражу",
+    "стран",
+    "страна",
+    "странах",
+    "стране",
+    "странен",
+    "страниц",
+    "страница",
+    "страницам",
+    "странице",
+    "страницу",
+    "страницы",
+    "странная",
+    "страннее",
+    "странник",
+    "страннику",
+    "странно",
+    "странного",
+    "странное",
+    "странной",
+    "странном",
+    "странному",
+    "странностей",
+    "странности",
+    "странность",
+    "странною",
+    "странную",
+    "странны",
+    "странные",
+    "странный",
+    "странным",
+    "странными",
+    "странных",
+    "страной",
+    "странствий",
+    "странствовали",
+    "странствуя",
+    "страны",
+    "страстей",
+    "страсти",
+    "страстишек",
+    "страстию",
+    "страстная",
+    "страстно",
+    "страстного",
+    "страстное",
+    "страстной",
+    "страстном",
+    "страстною",
+    "страстную",
+    "страстные",
+    "страстный",
+    "страстным",
+    "страстными",
+    "страстных",
+    "страсть",
+    "страстью",
+    "страстям",
+    "страстями",
+    "страусового",
+    "страусовым",
+    "страх",
+    "страха",
+    "страхами",
+    "страхе",
+    "страхи",
+    "страхов",
+    "страхом",
+    "страху",
+    "страшась",
+    "страшен",
+    "страшишься",
+    "страшна",
+    "страшная",
+    "страшнее",
+    "страшней",
+    "страшно",
+    "страшного",
+    "страшное",
+    "страшной",
+    "страшном",
+    "страшною",
+    "страшную",
+    "страшны",
+    "страшные",
+    "страшный",
+    "страшным",
+    "страшных",
+    "стращай",
+    "стращал",
+    "стрекоз",
+    "стрекозу",
+    "стрекочет",
+    "стрел",
+    "стрела",
+    "стреле",
+    "стрелка",
+    "стрелой",
+    "стрелочника",
+    "стрелы",
+    "стрельба",
+    "стрельбы",
+    "стреляет",
+    "стреляй",
+    "стреляйте",
+    "стреляли",
+    "стрелялся",
+    "стрелять",
+    "стремглав",
+    "стремился",
+    "стремит",
+    "стремительно",
+    "стремительной",
+    "стремится",
+    "стремиться",
+    "стремление",
+    "стремлении",
+    "стремлений",
+    "стремления",
+    "стремлениями",
+    "стремя",
+    "стремят",
+    "стремятся",
+    "стриженая",
+    "стриженых",
+    "стриндберга",
+    "стричь",
+    "строга",
+    "строгая",
+    "строги",
+    "строгие",
+    "строгий",
+    "строгим",
+    "строгими",
+    "строгих",
+    "строго",
+    "строгого",
+    "строгое",
+    "строгой",
+    "строгом",
+    "строгому",
+    "строгости",
+    "строгостию",
+    "строгость",
+    "строгостью",
+    "строгою",
+    "строгую",
+    "строевой",
+    "строевых",
+    "строем",
+    "строен",
+    "строже",
+    "строитель",
+    "строить",
+    "строиться",
+    "строй",
+    "стройка",
+    "стройкой",
+    "стройку",
+    "стройная",
+    "стройно",
+    "стройной",
+    "стройные",
+    "стройный",
+    "строк",
+    "строкам",
+    "строках",
+    "строки",
+    "строку",
+    "строптивый",
+    "строчить",
+    "строчка",
+    "строчки",
+    "строчку",
+    "строя",
+    "строят",
+    "строящегося",
+    "струею",
+    "струи",
+    "струилась",
+    "струится",
+    "струиться",
+    "струйках",
+    "струйкой",
+    "струн",
+    "струна",
+    "струнам",
+    "струне",
+    "струнно",
+    "струну",
+    "струны",
+    "струсив",
+    "струсившего",
+    "струсил",
+    "струсила",
+    "струсили",
+    "струсит",
+    "струю",
+    "струя",
+    "струями",
+    "струящей",
+    "струящийся",
+    "стряслась",
+    "стряхивала",
+    "стряхнул",
+    "стряхнуть",
+    "сту",
+    "студенистой",
+    "студеной",
+    "студент",
+    "студента",
+    "студенте",
+    "студентиком",
+    "студентом",
+    "студенту",
+    "студенты",
+    "студенческим",
+    "студенческих",
+    "студенческое",
+    "студенческом",
+    "студень",
+    "стужа",
+    "стуже",
+    "стужи",
+    "стужу",
+    "стук",
+    "стука",
+    "стукавшее",
+    "стукал",
+    "стукало",
+    "стуканием",
+    "стуканьем",
+    "стукаюсь",
+    "стукаясь",
+    "стукнет",
+    "стукнешься",
+    "стукнув",
+    "стукнувшись",
+    "стукнул",
+    "стукнула",
+    "стукнуло",
+    "стукнулся",
+    "стукнут",
+    "стуком",
+    "стуку",
+    "стул",
+    "стула",
+    "стуле",
+    "стулом",
+    "стульев",
+    "стулья",
+    "стульям",
+    "стульями",
+    "стульях",
+    "ступает",
+    "ступай",
+    "ступайте",
+    "ступал",
+    "ступающей",
+    "ступеней",
+    "ступени",
+    "ступень",
+    "ступенькам",
+    "ступеньках",
+    "ступеньке",
+    "ступеньки",
+    "ступенькой",
+    "ступеням",
+    "ступил",
+    "ступила",
+    "ступили",
+    "ступит",
+    "ступить",
+    "ступкой",
+    "ступку",
+    "стуча",
+    "стучавших",
+    "стучал",
+    "стучалась",
+    "стучали",
+    "стучались",
+    "стучало",
+    "стучался",
+    "стучась",
+    "стучат",
+    "стучатся",
+    "стучать",
+    "стучащаяся",
+    "стучи",
+    "стучись",
+    "стучит",
+    "стучится",
+    "стучишь",
+    "стучусь",
+    "стушевался",
+    "стушевываться",
+    "стыд",
+    "стыда",
+    "стыдил",
+    "стыдилась",
+    "стыдился",
+    "стыдись",
+    "стыдитесь",
+    "стыдится",
+    "стыдиться",
+    "стыдлив",
+    "стыдлива",
+    "стыдливая",
+    "стыдливо",
+    "стыдливого",
+    "стыдливое",
+    "стыдливой",
+    "стыдливом",
+    "стыдливости",
+    "стыдливость",
+    "стыдливый",
+    "стыднее",
+    "стыдно",
+    "стыдом",
+    "стыду",
+    "стыдя",
+    "стыдясь",
+    "стыдящийся",
+    "стыдящуюся",
+    "стыжу",
+    "стыжусь",
+    "стыл",
+    "стынет",
+    "стяг",
+    "стяга",
+    "стягивает",
+    "стягивать",
+    "стяжал",
+    "стянул",
+    "стянута",
+    "стянутая",
+    "су",
+    "суббота",
+    "субботе",
+    "субботу",
+    "субъект",
+    "субъектами",
+    "субъектом",
+    "сугробе",
+    "сугробы",
+    "сугубо",
+    "сугубым",
+    "суд",
+    "суда",
+    "судака",
+    "сударыня",
+    "сударь",
+    "суде",
+    "судеб",
+    "судебной",
+    "судебному",
+    "судебные",
+    "судебными",
+    "судейски",
+    "судейские",
+    "судейский",
+    "суди",
+    "судил",
+    "судите",
+    "судить",
+    "судишь",
+    "судия",
+    "судки",
+    "судом",
+    "судопроизводства",
+    "судопроизводство",
+    "судорог",
+    "судорога",
+    "судорогами",
+    "судороги",
+    "судорогой",
+    "судорожно",
+    "судорожного",
+    "судорожной",
+    "судорожным",
+    "суды",
+    "судырь",
+    "судьба",
+    "судьбами",
+    "судьбе",
+    "судьбинский",
+    "судьбой",
+    "судьбою",
+    "судьбу",
+    "судьбы",
+    "судьей",
+    "судьи",
+    "судьям",
+    "судя",
+    "суеверен",
+    "суеверия",
+    "суеверна",
+    "суеверней",
+    "суеверным",
+    "сует",
+    "суета",
+    "суете",
+    "суетилась",
+    "суетилось",
+    "суетился",
+    "суетится",
+    "суетливо",
+    "суетливой",
+    "суетливую",
+    "суется",
+    "суету",
+    "суеты",
+    "суетясь",
+    "суешь",
+    "суешься",
+    "суждена",
+    "суждение",
+    "суждении",
+    "суждения",
+    "суждениями",
+    "сужденная",
+    "сужденный",
+    "суждено",
+    "суждены",
+    "суживая",
+    "сужу",
+    "суздальцев",
+    "сузились",
+    "суйся",
+    "сукна",
+    "сукно",
+    "суконной",
+    "сулил",
+    "сулила",
+    "сулит",
+    "султаны",
+    "сулят",
+    "сулящих",
+    "сумарокова",
+    "сумасброд",
+    "сумасбродом",
+    "сумасшедний",
+    "сумасшедшая",
+    "сумасшедшего",
+    "сумасшедшей",
+    "сумасшедшие",
+    "сумасшедший",
+    "сумасшедшим",
+    "сумасшедшими",
+    "сумасшедших",
+    "сумасшедшую",
+    "сумасшествие",
+    "сумасшествии",
+    "сумасшествию",
+    "сумасшествия",
+    "сумасшествовали",
+    "сумасшествует",
+    "суматоха",
+    "суматохе",
+    "суматохи",
+    "суматохой",
+    "сумеет",
+    "сумеешь",
+    "сумел",
+    "сумела",
+    "сумели",
+    "сумерках",
+    "сумерки",
+    "сумею",
+    "сумеют",
+    "сумки",
+    "сумкой",
+    "сумление",
+    "сумлении",
+    "сумления",
+    "сумм",
+    "сумма",
+    "суммам",
+    "сумму",
+    "суммы",
+    "сумочкой",
+    "сумрак",
+    "сумраке",
+    "сумраком",
+    "сумрачен",
+    "сумрачно",
+    "сумрачной",
+    "сумрачным",
+    "сундук",
+    "сундука",
+    "сундуке",
+    "сундуки",
+    "сундуком",
+    "сундуку",
+    "сундучок",
+    "сунет",
+    "сунув",
+    "сунул",
+    "сунула",
+    "сунуть",
+    "суп",
+    "супа",
+    "супом",
+    "супруг",
+    "супруга",
+    "супруге",
+    "супруги",
+    "супругой",
+    "супругу",
+    "супружеского",
+    "супружеское",
+    "супружеском",
+    "супружества",
+    "супружествах",
+    "супружество",
+    "супу",
+    "сургуч",
+    "сургуча",
+    "сургучом",
+    "сурдиной",
+    "сурмится",
+    "суров",
+    "сурова",
+    "суровая",
+    "суровее",
+    "сурово",
+    "суровое",
+    "суровой",
+    "суровом",
+    "суровости",
+    "суровость",
+    "суровою",
+    "суровую",
+    "суровые",
+    "суровый",
+    "суровым",
+    "сурок",
+    "сусальный",
+    "суслов",
+    "суткам",
+    "сутки",
+    "суток",
+    "суточной",
+    "суточными",
+    "суточных",
+    "сутуловатый",
+    "суть",
+    "сутяжничеством",
+    "суфлеры",
+    "сух",
+    "сухарей",
+    "сухари",
+    "сухариками",
+    "сухариков",
+    "сухарь",
+    "сухаря",
+    "сухарями",
+    "сухая",
+    "сухи",
+    "сухими",
+    "сухих",
+    "сухо",
+    "сухого",
+    "сухое",
+    "сухой",
+    "сухости",
+    "сухую",
+    "сучок",
+    "сучьях",
+    "сушеная",
+    "сушеную",
+    "сушеными",
+    "сушили",
+    "сушилось",
+    "сушильню",
+    "сушит",
+    "сущая",
+    "сущее",
+    "существ",
+    "существа",
+    "существе",
+    "существенного",
+    "существенной",
+    "существенными",
+    "существо",
+    "существовавшего",
+    "существовали",
+    "существование",
+    "существованием",
+    "существований",
+    "существования",
+    "существованья",
+    "существовать",
+    "существом",
+    "существу",
+    "существуем",
+    "существует",
+    "существуете",
+    "существую",
+    "существуют",
+    "существующим",
+    "существующих",
+    "существующую",
+    "сущности",
+    "сущность",
+    "сфер",
+    "сфера",
+    "сферах",
+    "сфере",
+    "сферой",
+    "сферою",
+    "сферу",
+    "сферы",
+    "сфинкс",
+    "сфинкса",
+    "схватив",
+    "схватившись",
+    "схватил",
+    "схватила",
+    "схватилась",
+    "схватили",
+    "схватился",
+    "схватит",
+    "схватить",
+    "схватиться",
+    "схватку",
+    "схватывает",
+    "схватывается",
+    "схватывают",
+    "схватывая",
+    "схватят",
+    "схвачена",
+    "схвачу",
+    "схимник",
+    "схитрила",
+    "схитрить",
+    "схлебнув",
+    "схода",
+    "сходи",
+    "сходил",
+    "сходила",
+    "сходили",
+    "сходились",
+    "сходим",
+    "сходит",
+    "сходите",
+    "сходится",
+    "сходить",
+    "сходиться",
+    "сходишь",
+    "сходками",
+    "сходках",
+    "сходки",
+    "сходную",
+    "сходны",
+    "сходов",
+    "сходства",
+    "сходство",
+    "сходствовал",
+    "сходством",
+    "сходы",
+    "сходя",
+    "сходясь",
+    "сходят",
+    "сходятся",
+    "схож",
+    "схожи",
+    "схожу",
+    "схоластикой",
+    "схоронены",
+    "схоронив",
+    "схоронил",
+    "схоронила",
+    "схоронить",
+    "сцен",
+    "сцена",
+    "сцене",
+    "сценой",
+    "сцену",
+    "сцены",
+    "сцепившиеся",
+    "сцепились",
+    "сцеплений",
+    "счастие",
+    "счастием",
+    "счастии",
+    "счастию",
+    "счастия",
+    "счастлив",
+    "счастлива",
+    "счастливая",
+    "счастливее",
+    "счастливей",
+    "счастливейшим",
+    "счастливец",
+    "счастливо",
+    "счастливого",
+    "счастливое",
+    "счастливой",
+    "счастливому",
+    "счастливою",
+    "счастливцев",
+    "счастливы",
+    "счастливые",
+    "счастливый",
+    "счастливым",
+    "счастливыми",
+    "счастливых",
+    "счастье",
+    "счастьем",
+    "счастьи",
+    "счастью",
+    "счастья",
+    "счел",
+    "счесть",
+    "счет",
+    "счета",
+    "счетам",
+    "счетах",
+    "счете",
+    "счетов",
+    "счетом",
+    "счету",
+    "счеты",
+    "считаем",
+    "считаемся",
+    "считает",
+    "считаете",
+    "считается",
+    "считаешь",
+    "считай",
+    "считайте",
+    "считал",
+    "считала",
+    "считали",
+    "считались",
+    "считался",
+    "считать",
+    "считаться",
+    "считаю",
+    "считают",
+    "считая",
+    "сшедший",
+    "сшиб",
+    "сшил",
+    "сшит",
+    "сшитом",
+    "съедает",
+    "съедаешь",
+    "съедаю",
+    "съедем",
+    "съедет",
+    "съеду",
+    "съежившийся",
+    "съежившись",
+    "съезди",
+    "съездил",
+    "съездили",
+    "съездит",
+    "съездить",
+    "съездов",
+    "съезжаете",
+    "съезжай",
+    "съезжайте",
+    "съезжал",
+    "съезжаль",
+    "съезжать",
+    "съезжаться",
+    "съезжаю",
+    "съезжу",
+    "съел",
+    "съели",
+    "съем",
+    "съест",
+    "съестно",
+    "съестного",
+    "съестным",
+    "съестных",
+    "съесть",
+    "съехал",
+    "съехали",
+    "съехать",
+    "съешь",
+    "сыворотка",
+    "сыграет",
+    "сыграй",
+    "сыграл",
+    "сыграла",
+    "сыграли",
+    "сыграна",
+    "сыграть",
+    "сыграю",
+    "сызмалетства",
+    "сызнова",
+    "сын",
+    "сына",
+    "сынам",
+    "сыне",
+    "сынков",
+    "сынов",
+    "сыновних",
+    "сыновняя",
+    "сыном",
+    "сыну",
+    "сыны",
+    "сыпал",
+    "сыпала",
+    "сыпались",
+    "сыплется",
+    "сыплющихся",
+    "сыпной",
+    "сыр",
+    "сырая",
+    "сыро",
+    "сырого",
+    "сырое",
+    "сырой",
+    "сыром",
+    "сырости",
+    "сырость",
+    "сыростью",
+    "сыру",
+    "сырую",
+    "сыры",
+    "сырые",
+    "сыскал",
+    "сыскали",
+    "сыскать",
+    "сыскное",
+    "сыт",
+    "сытое",
+    "сытой",
+    "сытости",
+    "сыты",
+    "сытые",
+    "сытый",
+    "сычиха",
+    "сычуга",
+    "сыщем",
+    "сыщет",
+    "сыщешь",
+    "сыщи",
+    "сыщика",
+    "сыщики",
+    "сыщности",
+    "сыщу",
+    "сыщут",
+    "сь",
+    "сю",
+    "сюда",
+    "сюжет",
+    "сюрприз",
+    "сюрпризик",
+    "сюрпризы",
+    "сюртук",
+    "сюртука",
+    "сюртуке",
+    "сюртучишка",
+    "сюртучок",
+    "сядем",
+    "сядет",
+    "сяду",
+    "сядут",
+    "сядь",
+    "сядьте",
+    "сякая",
+    "сякой",
+    "сям",
+    "т",
+    "та",
+    "табак",
+    "табакерки",
+    "табакеркой",
+    "табакерку",
+    "табаком",
+    "табаку",
+    "табачного",
+    "табачной",
+    "табачок",
+    "таблиц",
+    "таблица",
+    "таблицы",
+    "табуны",
+    "таварищество",
+    "таверны",
+    "тает",
+    "таз",
+    "таи",
+    "таившейся",
+    "таил",
+    "таила",
+    "таилась",
+    "таился",
+    "таим",
+    "таин",
+    "таинствам",
+    "таинственно",
+    "таинственного",
+    "таинственное",
+    "таинственному",
+    "таинственность",
+    "таинственны",
+    "таинственные",
+    "таинственный",
+    "таинственным",
+    "таинственных",
+    "таинство",
+    "таится",
+    "тайком",
+    "тайн",
+    "тайна",
+    "тайнами",
+    "тайная",
+    "тайне",
+    "тайниках",
+    "тайно",
+    "тайного",
+    "тайное",
+    "тайной",
+    "тайною",
+    "тайну",
+    "тайную",
+    "тайны",
+    "тайные",
+    "тайный",
+    "тайным",
+    "тайными",
+    "тайных",
+    "тайте",
+    "так",
+    "така",
+    "такая",
+    "также",
+    "таки",
+    "такие",
+    "таким",
+    "такими",
+    "таких",
+    "таков",
+    "такова",
+    "таковая",
+    "таково",
+    "таковое",
+    "таковскою",
+    "таковую",
+    "таковы",
+    "таковые",
+    "такого",
+    "такое",
+    "такой",
+    "таком",
+    "такому",
+    "такою",
+    "таксомотор",
+    "такт",
+    "такта",
+    "тактика",
+    "тактом",
+    "таку",
+    "такую",
+    "талант",
+    "таланта",
+    "талантами",
+    "талантлив",
+    "талантлива",
+    "талантливее",
+    "талантливо",
+    "талантливый",
+    "талантливым",
+    "талантливых",
+    "талантов",
+    "талантом",
+    "таланты",
+    "талеров",
+    "талией",
+    "талии",
+    "талию",
+    "талия",
+    "талый",
+    "талым",
+    "таль",
+    "тальки",
+    "тальма",
+    "тальмочку",
+    "тальму",
+    "талью",
+    "там",
+    "тама",
+    "тамаре",
+    "тамошнего",
+    "тамошней",
+    "тамошние",
+    "танец",
+    "танца",
+    "танцам",
+    "танцами",
+    "танце",
+    "танцев",
+    "танцевала",
+    "танцевали",
+    "танцевальный",
+    "танцевать",
+    "танцует",
+    "танцуют",
+    "танцуя",
+    "танцы",
+    "тара",
+    "таракана",
+    "тараканами",
+    "тараканах",
+    "тараканов",
+    "тараканы",
+    "тарантас",
+    "тарантьев",
+    "тарантьева",
+    "тарантьеву",
+    "тарантьевым",
+    "тарантьевыми",
+    "тарара",
+    "тарас",
+    "тараса",
+    "тараска",
+    "тараску",
+    "тараща",
+    "таращатся",
+    "таращил",
+    "тарелка",
+    "тарелками",
+    "тарелках",
+    "тарелке",
+    "тарелки",
+    "тарелкой",
+    "тарелку",
+    "тарелок",
+    "таскаемый",
+    "таскает",
+    "таскал",
+    "таскаль",
+    "таскаться",
+    "таскают",
+    "тасуйте",
+    "татар",
+    "татарвою",
+    "татарин",
+    "татарская",
+    "татарские",
+    "татарским",
+    "татарской",
+    "тать",
+    "татьяна",
+    "тафты",
+    "таща",
+    "тащи",
+    "тащил",
+    "тащила",
+    "тащили",
+    "тащило",
+    "тащит",
+    "тащить",
+    "тащиться",
+    "тащу",
+    "таюсь",
+    "тают",
+    "тающий",
+    "тая",
+    "таял",
+    "таяли",
+    "таянье",
+    "таясь",
+    "таятся",
+    "твари",
+    "тварь",
+    "тварью",
+    "тверд",
+    "твердая",
+    "твердеют",
+    "тверди",
+    "твердил",
+    "твердила",
+    "твердили",
+    "твердим",
+    "твердит",
+    "твердите",
+    "твердить",
+    "твердишь",
+    "твердо",
+    "твердого",
+    "твердое",
+    "твердости",
+    "твердостию",
+    "твердостью",
+    "твердым",
+    "твердых",
+    "твердь",
+    "твердя",
+    "твердят",
+    "тверезый",
+    "тверже",
+    "тверь",
+    "твое",
+    "твоего",
+    "твоей",
+    "твоем",
+    "твоему",
+    "твоею",
+    "твои",
+    "твоим",
+    "твоими",
+    "твоих",
+    "твой",
+    "творенья",
+    "творец",
+    "творил",
+    "творилось",
+    "творим",
+    "творимый",
+    "творится",
+    "творить",
+    "творогом",
+    "творца",
+    "творческие",
+    "творческий",
+    "творческого",
+    "творческою",
+    "творческую",
+    "творчества",
+    "творят",
+    "твою",
+    "твоя",
+    "те",
+    "театр",
+    "театра",
+    "театре",
+    "тебе",
+    "тебя",
+    "текла",
+    "текли",
+    "текло",
+    "текст",
+    "текста",
+    "текут",
+    "текущей",
+    "текущею",
+    "тел",
+    "тела",
+    "теле",
+    "телег",
+    "телега",
+    "телеге",
+    "телеги",
+    "телегин",
+    "телегину",
+    "телеграмма",
+    "телеграмму",
+    "телеграммы",
+    "телеграф",
+    "телеграфа",
+    "телеграфе",
+    "телеграфировали",
+    "телеграфировать",
+    "телеграфирует",
+    "телеграфисткой",
+    "телеграфные",
+    "телеграфный",
+    "телеграфных",
+    "телегу",
+    "тележка",
+    "тележке",
+    "тележку",
+    "тележный",
+    "телемака",
+    "телесная",
+    "телефон",
+    "телефона",
+    "тело",
+    "телом",
+    "телу",
+    "тельца",
+    "телята",
+    "телятина",
+    "телятиной",
+    "телятину",
+    "телятины",
+    "тем",
+    "тема",
+    "темами",
+    "темени",
+    "темень",
+    "теми",
+    "темляком",
+    "темна",
+    "темная",
+    "темневших",
+    "темневшую",
+    "темнее",
+    "темней",
+    "темнело",
+    "темненькое",
+    "темнеют",
+    "темнеющего",
+    "темнеющем",
+    "темно",
+    "темновласый",
+    "темного",
+    "темное",
+    "темной",
+    "темноликий",
+    "темном",
+    "темнота",
+    "темноте",
+    "темноту",
+    "темноты",
+    "темною",
+    "темную",
+    "темны",
+    "темные",
+    "темный",
+    "темным",
+    "темными",
+    "темных",
+    "температура",
+    "тему",
+    "темы",
+    "темя",
+    "теней",
+    "тени",
+    "тенист",
+    "тенистой",
+    "тенистом",
+    "тенистый",
+    "тенистых",
+    "тенор",
+    "тень",
+    "тенью",
+    "теодорих",
+    "теоретиком",
+    "теоретически",
+    "теорией",
+    "теории",
+    "теорий",
+    "теорийка",
+    "теорию",
+    "теория",
+    "теориям",
+    "теориях",
+    "тепел",
+    "теперешнего",
+    "теперешнее",
+    "теперешней",
+    "теперешнем",
+    "теперешние",
+    "теперешний",
+    "теперешним",
+    "теперешних",
+    "теперешнюю",
+    "теперешняя",
+    "теперича",
+    "теперь",
+    "тепла",
+    "теплая",
+    "тепле",
+    "теплее",
+    "теплилась",
+    "теплился",
+    "теплится",
+    "теплице",
+    "тепло",
+    "теплое",
+    "теплой",
+    "теплом",
+    "теплому",
+    "теплота",
+    "теплоту",
+    "теплоты",
+    "теплые",
+    "теплый",
+    "теплым",
+    "теплых",
+    "теплятся",
+    "тер",
+    "теребила",
+    "теребьева",
+    "теребьеву",
+    "теребя",
+    "терем",
+    "тереть",
+    "терзает",
+    "терзается",
+    "терзаешь",
+    "терзал",
+    "терзала",
+    "терзалась",
+    "терзали",
+    "терзало",
+    "терзался",
+    "терзание",
+    "терзаний",
+    "терзать",
+    "терзаю",
+    "терзая",
+    "терминах",
+    "терпевшая",
+    "терпел",
+    "терпелив",
+    "терпеливая",
+    "терпеливо",
+    "терпеливость",
+    "терпение",
+    "терпением",
+    "терпении",
+    "терпения",
+    "терпеть",
+    "терпи",
+    "терпит",
+    "терпите",
+    "терпится",
+    "терплю",
+    "терпят",
+    "терраса",
+    "террасах",
+    "террасе",
+    "террасой",
+    "террасу",
+    "террасы",
+    "теряет",
+    "теряете",
+    "теряется",
+    "теряешь",
+    "терял",
+    "теряла",
+    "терялась",
+    "теряли",
+    "теряло",
+    "терялся",
+    "терять",
+    "теряю",
+    "теряюсь",
+    "теряют",
+    "теряющая",
+    "теряя",
+    "тесемками",
+    "тесемочкой",
+    "тесен",
+    "теснее",
+    "теснившихся",
+    "теснила",
+    "теснились",
+    "теснилось",
+    "теснит",
+    "теснитесь",
+    "тесно",
+    "тесной",
+    "тесноте",
+    "тесную",
+    "тесные",
+    "тесный",
+    "тесных",
+    "теснюсь",
+    "теснят",
+    "теснящим",
+    "тесовый",
+    "теста",
+    "тесто",
+    "тестова",
+    "тесьму",
+    "тесьмы",
+    "тете",
+    "тетеньки",
+    "тетивою",
+    "тетиву",
+    "тетка",
+    "тетке",
+    "тетки",
+    "теткой",
+    "тетку",
+    "теток",
+    "тетрадей",
+    "тетради",
+    "тетрадка",
+    "тетрадке",
+    "тетрадки",
+    "тетрадкой",
+    "тетрадку",
+    "тетрадь",
+    "тетрадях",
+    "тетушка",
+    "тетушки",
+    "тетя",
+    "тех",
+    "техники",
+    "технолог",
+    "технологию",
+    "течение",
+    "течению",
+    "течения",
+    "теченье",
+    "течет",
+    "тешат",
+    "тешатся",
+    "тешили",
+    "тешит",
+    "тешится",
+    "тешу",
+    "теща",
+    "тещи",
+    "тиатр",
+    "тигров",
+    "тикая",
+    "тил",
+    "тимофеевна",
+    "тип",
+    "типографий",
+    "типографию",
+    "типы",
+    "тираду",
+    "тирады",
+    "тиран",
+    "тиранила",
+    "тироль",
+    "тирольской",
+    "тисках",
+    "тиски",
+    "тит",
+    "титаны",
+    "титулярный",
+    "титулярным",
+    "тиф",
+    "тифа",
+    "тих",
+    "тиха",
+    "тихая",
+    "тихие",
+    "тихий",
+    "тихим",
+    "тихими",
+    "тихих",
+    "тихо",
+    "тихого",
+    "тихое",
+    "тихой",
+    "тихом",
+    "тихоновну",
+    "тихоньким",
+    "тихонько",
+    "тихою",
+    "тихую",
+    "тициана",
+    "тише",
+    "тиши",
+    "тишина",
+    "тишине",
+    "тишиной",
+    "тишиною",
+    "тишину",
+    "тишины",
+    "тишь",
+    "тка",
+    "ткал",
+    "ткани",
+    "ткань",
+    "тканью",
+    "ткет",
+    "ткнул",
+    "тко",
+    "тлеет",
+    "тлеющая",
+    "то",
+    "тобой",
+    "тобою",
+    "товар",
+    "товаре",
+    "товарищ",
+    "товарища",
+    "товарищам",
+    "товарищами",
+    "товарище",
+    "товарищей",
+    "товарищем",
+    "товарищества",
+    "товарищи",
+    "товарищу",
+    "товарный",
+    "товаров",
+    "товаром",
+    "товару",
+    "товары",
+    "тогда",
+    "тогдашнего",
+    "тогдашнее",
+    "тогдашнему",
+    "тогдашний",
+    "тогдашнюю",
+    "того",
+    "тоже",
+    "той",
+    "токаря",
+    "токмо",
+    "толк",
+    "толкает",
+    "толкал",
+    "толкала",
+    "толкало",
+    "толкаль",
+    "толками",
+    "толкать",
+    "толкаться",
+    "толках",
+    "толкаются",
+    "толкая",
+    "толки",
+    "толкла",
+    "толкли",
+    "толкнув",
+    "толкнул",
+    "толкнулся",
+    "толкнуть",
+    "толков",
+    "толковал",
+    "толковали",
+    "толкования",
+    "толковать",
+    "толково",
+    "толковый",
+    "толковым",
+    "толкотня",
+    "толку",
+    "толкует",
+    "толкуете",
+    "толкусь",
+    "толкучего",
+    "толкучем",
+    "толкучий",
+    "толкую",
+    "толкуют",
+    "толочь",
+    "толпа",
+    "толпами",
+    "толпе",
+    "толпившимися",
+    "толпившихся",
+    "толпилась",
+    "толпились",
+    "толпилось",
+    "толпился",
+    "толпится",
+    "толпой",
+    "толпою",
+    "толпу",
+    "толпы",
+    "толст",
+    "толста",
+    "толстая",
+    "толстеет",
+    "толстенькая",
+    "толстенькие",
+    "толстоваты",
+    "толстого",
+    "толстогубый",
+    "толстой",
+    "толстоты",
+    "толстою",
+    "толстую",
+    "толстые",
+    "толстый",
+    "толстым",
+    "толстыми",
+    "толстяков",
+    "толчеи",
+    "толченье",
+    "толчет",
+    "толчки",
+    "толчков",
+    "толчком",
+    "толчок",
+    "толщиной",
+    "только",
+    "том",
+    "тома",
+    "томах",
+    "томен",
+    "томи",
+    "томик",
+    "томила",
+    "томилась",
+    "томили",
+    "томило",
+    "томился",
+    "томим",
+    "томите",
+    "томительною",
+    "томительные",
+    "томитесь",
+    "томится",
+    "томить",
+    "томиться",
+    "томишь",
+    "томление",
+    "томлений",
+    "томления",
+    "томленье",
+    "томленьем",
+    "томленьи",
+    "томленья",
+    "томлюсь",
+    "томна",
+    "томно",
+    "томного",
+    "томной",
+    "томнооких",
+    "томную",
+    "томные",
+    "томный",
+    "томным",
+    "томными",
+    "томов",
+    "тому",
+    "томясь",
+    "томят",
+    "томятся",
+    "томящихся",
+    "тон",
+    "тона",
+    "тоне",
+    "тоненькая",
+    "тоненькие",
+    "тоненький",
+    "тоненьким",
+    "тоненькими",
+    "тоненькой",
+    "тоненькою",
+    "тоненькую",
+    "тонет",
+    "тонкая",
+    "тонкие",
+    "тонкий",
+    "тонким",
+    "тонкими",
+    "тонких",
+    "тонко",
+    "тонкого",
+    "тонкое",
+    "тонкой",
+    "тонкости",
+    "тонкость",
+    "тонкостью",
+    "тонкую",
+    "тонная",
+    "тонок",
+    "тоном",
+    "тону",
+    "тонула",
+    "тонуло",
+    "тонут",
+    "тончайшая",
+    "тончайшее",
+    "тончайшей",
+    "тончайший",
+    "тоны",
+    "тоню",
+    "топ",
+    "топать",
+    "топил",
+    "топили",
+    "топит",
+    "топить",
+    "топливо",
+    "топнув",
+    "топнул",
+    "топнули",
+    "тополевой",
+    "тополевую",
+    "тополей",
+    "тополем",
+    "тополи",
+    "тополями",
+    "топор",
+    "топора",
+    "топорами",
+    "топоре",
+    "топорной",
+    "топоров",
+    "топором",
+    "топорщиться",
+    "топот",
+    "топочет",
+    "топтал",
+    "топтать",
+    "топчешь",
+    "топчу",
+    "топчут",
+    "топью",
+    "топят",
+    "торгах",
+    "торги",
+    "торговал",
+    "торговала",
+    "торговали",
+    "торговать",
+    "торговаться",
+    "торговка",
+    "торговку",
+    "торговле",
+    "торговли",
+    "торгового",
+    "торговое",
+    "торговой",
+    "торговца",
+    "торговцы",
+    "торговый",
+    "торгует",
+    "торгуй",
+    "торгуют",
+    "торжеств",
+    "торжества",
+    "торжестве",
+    "торжественно",
+    "торжественного",
+    "торжественное",
+    "торжественной",
+    "торжественном",
+    "торжественностию",
+    "торжественную",
+    "торжественные",
+    "торжественный",
+    "торжественным",
+    "торжество",
+    "торжествовал",
+    "торжествовала",
+    "торжеством",
+    "торжествует",
+    "торжествую",
+    "торжествуют",
+    "торжествующе",
+    "торжествующею",
+    "торжествуя",
+    "тороват",
+    "торопил",
+    "торопила",
+    "торопилась",
+    "торопились",
+    "торопился",
+    "торопит",
+    "торопитесь",
+    "торопится",
+    "торопить",
+    "торопиться",
+    "торопливо",
+    "торопливого",
+    "торопливой",
+    "торопливость",
+    "торопливостью",
+    "торопливою",
+    "торопливый",
+    "торопливыми",
+    "тороплюсь",
+    "торопясь",
+    "торопят",
+    "торопятся",
+    "торт",
+    "тортом",
+    "торфом",
+    "торчавшей",
+    "торчавший",
+    "торчавшими",
+    "торчал",
+    "торчала",
+    "торчат",
+    "торчит",
+    "торчком",
+    "тоска",
+    "тосканские",
+    "тосканских",
+    "тоске",
+    "тоски",
+    "тоскливо",
+    "тоскливой",
+    "тоскливом",
+    "тоскливый",
+    "тоскливым",
+    "тосковал",
+    "тосковала",
+    "тосковать",
+    "тоской",
+    "тоскою",
+    "тоску",
+    "тоскуем",
+    "тоскует",
+    "тоскуешь",
+    "тоскую",
+    "тоскуют",
+    "тоскующего",
+    "тоскующий",
+    "тоскуя",
+    "тот",
+    "тотчас",
+    "точа",
+    "точек",
+    "точил",
+    "точит",
+    "точить",
+    "точка",
+    "точками",
+    "точке",
+    "точки",
+    "точкой",
+    "точку",
+    "точнее",
+    "точнейшим",
+    "точнехонько",
+    "точно",
+    "точного",
+    "точное",
+    "точном",
+    "точности",
+    "точностию",
+    "точностью",
+    "точны",
+    "точные",
+    "точных",
+    "точь",
+    "тошней",
+    "тошно",
+    "тошноты",
+    "тощая",
+    "тощий",
+    "тощими",
+    "тощую",
+    "тою",
+    "тра",
+    "трав",
+    "трава",
+    "травами",
+    "траве",
+    "травила",
+    "травке",
+    "травку",
+    "травля",
+    "травой",
+    "траву",
+    "травы",
+    "трагедии",
+    "трагедий",
+    "трагедия",
+    "трагик",
+    "трагиков",
+    "трагически",
+    "трагические",
+    "трагического",
+    "трагическое",
+    "трагической",
+    "трактат",
+    "трактир",
+    "трактира",
+    "трактирам",
+    "трактире",
+    "трактиришке",
+    "трактиришко",
+    "трактирное",
+    "трактирной",
+    "трактирный",
+    "трактиров",
+    "трактиром",
+    "трактиры",
+    "трам",
+    "трамваи",
+    "транспарант",
+    "трата",
+    "трате",
+    "тратил",
+    "тратилось",
+    "тратит",
+    "тратите",
+    "тратится",
+    "тратить",
+    "траты",
+    "тратят",
+    "тратятся",
+    "траур",
+    "траура",
+    "трауре",
+    "траурная",
+    "траурного",
+    "траурной",
+    "траурною",
+    "траурные",
+    "траурный",
+    "траурными",
+    "трахтир",
+    "трачу",
+    "требовавшие",
+    "требовавших",
+    "требовал",
+    "требовала",
+    "требовали",
+    "требование",
+    "требовании",
+    "требований",
+    "требованию",
+    "требования",
+    "требовать",
+    "требу",
+    "требуемые",
+    "требует",
+    "требуется",
+    "требуешь",
+    "требуй",
+    "требуйте",
+    "требухой",
+    "требую",
+    "требуют",
+    "требующее",
+    "требующие",
+    "требуя",
+    "тревог",
+    "тревога",
+    "тревогам",
+    "тревогах",
+    "тревоге",
+    "тревоги",
+    "тревогой",
+    "тревогою",
+    "тревогу",
+    "тревожа",
+    "тревожась",
+    "тревожат",
+    "тревожил",
+    "тревожила",
+    "тревожилась",
+    "тревожили",
+    "тревожило",
+    "тревожился",
+    "тревожит",
+    "тревожится",
+    "тревожить",
+    "тревожиться",
+    "тревожишь",
+    "тревожная",
+    "тревожнее",
+    "тревожно",
+    "тревожного",
+    "тревожное",
+    "тревожном",
+    "тревожные",
+    "тревожный",
+    "тревожных",
+    "тревожусь",
+    "тревожь",
+    "тревожься",
+    "тревожьте",
+    "треволнения",
+    "треволненьям",
+    "трезв",
+    "трезва",
+    "трезво",
+    "трезвой",
+    "трезвонят",
+    "трезвый",
+    "трезвым",
+    "трезор",
+    "треклятая",
+    "треклятые",
+    "трельяже",
+    "тремя",
+    "тремястами",
+    "тренькала",
+    "трепала",
+    "трепаном",
+    "трепанье",
+    "трепать",
+    "трепет",
+    "трепета",
+    "трепетавшая",
+    "трепетавшем",
+    "трепетавших",
+    "трепетал",
+    "трепетала",
+    "трепетали",
+    "трепетания",
+    "трепетанье",
+    "трепете",
+    "трепетно",
+    "трепетного",
+    "трепетным",
+    "трепетом",
+    "трепету",
+    "трепеща",
+    "трепещет",
+    "трепещут",
+    "трепещущего",
+    "трепещущей",
+    "трепещущие",
+    "трепещущий",
+    "трепещущим",
+    "трепещущих",
+    "трепещущую",
+    "треплев",
+    "треплева",
+    "треплеву",
+    "треплевым",
+    "треплет",
+    "треплют",
+    "треплются",
+    "трепля",
+    "треск",
+    "трескает",
+    "треске",
+    "треском",
+    "трескотней",
+    "трескотня",
+    "трескучей",
+    "трет",
+    "третий",
+    "третного",
+    "треть",
+    "третье",
+    "третьего",
+    "третьегодичные",
+    "третьегодичных",
+    "третьегодни",
+    "третьей",
+    "третьем",
+    "третьему",
+    "третьи",
+    "третьим",
+    "третьих",
+    "третью",
+    "третья",
+    "трефовую",
+    "трех",
+    "трехлетней",
+    "трехлетний",
+    "трехлетняя",
+    "трехрублевому",
+    "трехрублевую",
+    "трехрыбное",
+    "трехсот",
+    "трехстах",
+    "трехударный",
+    "трехэтажный",
+    "треща",
+    "трещавших",
+    "трещал",
+    "трещала",
+    "трещали",
+    "трещат",
+    "трещать",
+    "трещина",
+    "трещине",
+    "трещину",
+    "трещит",
+    "три",
+    "трибуне",
+    "трибуной",
+    "тригорин",
+    "тригорина",
+    "тригорину",
+    "тригориным",
+    "тридцати",
+    "тридцатипятилетний",
+    "тридцатирублевую",
+    "тридцать",
+    "тридцатью",
+    "трижды",
+    "тризне",
+    "тринадцати",
+    "тринадцатилетнего",
+    "тринадцатого",
+    "тринадцать",
+    "триста",
+    "триумф",
+    "трихины",
+    "трогает",
+    "трогается",
+    "трогаешь",
+    "трогай",
+    "трогайте",
+    "трогал",
+    "трогала",
+    "трогали",
+    "трогались",
+    "трогательнее",
+    "трогательно",
+    "трогательные",
+    "трогательным",
+    "трогать",
+    "трогаться",
+    "трогаю",
+    "трогаясь",
+    "трое",
+    "троек",
+    "троекратно",
+    "троекратного",
+    "троекратное",
+    "троекратным",
+    "трожь",
+    "трои",
+    "троими",
+    "троих",
+    "троица",
+    "троицын",
+    "тройка",
+    "тройками",
+    "тройке",
+    "тройная",
+    "тронет",
+    "тронется",
+    "тронешь",
+    "тронул",
+    "тронулся",
+    "тронут",
+    "тронуты",
+    "тронутый",
+    "тронуть",
+    "тронуться",
+    "тронь",
+    "троньте",
+    "тропами",
+    "тропе",
+    "тропинка",
+    "тропинке",
+    "тропинки",
+    "тропинку",
+    "тропой",
+    "тропою",
+    "тропу",
+    "тросточку",
+    "трость",
+    "тростью",
+    "троттуар",
+    "троттуары",
+    "тротуар",
+    "тротуара",
+    "тротуарам",
+    "тротуарами",
+    "тротуаре",
+    "тротуаром",
+    "тротуару",
+    "трофимов",
+    "трофимова",
+    "трофимович",
+    "трофимову",
+    "трофимовым",
+    "трофимыч",
+    "трофимыча",
+    "троюродные",
+    "тррреклятые",
+    "труб",
+    "труба",
+    "трубах",
+    "трубе",
+    "трубит",
+    "трубка",
+    "трубками",
+    "трубки",
+    "трубкой",
+    "трубкою",
+    "трубку",
+    "трубные",
+    "трубный",
+    "трубочист",
+    "трубочка",
+    "трубу",
+    "трубы",
+    "труд",
+    "труда",
+    "трудам",
+    "трудами",
+    "трудах",
+    "труде",
+    "труди",
+    "трудилась",
+    "трудимся",
+    "трудись",
+    "трудится",
+    "трудиться",
+    "трудная",
+    "труднее",
+    "трудно",
+    "трудного",
+    "трудное",
+    "трудной",
+    "трудности",
+    "трудность",
+    "трудную",
+    "трудные",
+    "трудный",
+    "трудным",
+    "трудных",
+    "трудов",
+    "трудовая",
+    "трудовое",
+    "трудовой",
+    "трудовую",
+    "трудолюбивой",
+    "трудолюбивые",
+    "трудолюбивый",
+    "трудолюбие",
+    "трудолюбию",
+    "трудом",
+    "труду",
+    "труды",
+    "трудясь",
+    "трудятся",
+    "труженическое",
+    "тружусь",
+    "труп",
+    "трупный",
+    "трупом",
+    "трупы",
+    "трус",
+    "труса",
+    "трусил",
+    "трусить",
+    "трусишки",
+    "труслив",
+    "труслива",
+    "трусливо",
+    "трусливое",
+    "трусоват",
+    "трусости",
+    "трын",
+    "трюфелями",
+    "тряпка",
+    "тряпки",
+    "тряпкой",
+    "тряпку",
+    "тряпок",
+    "тряпье",
+    "тряпьем",
+    "трясется",
+    "трясла",
+    "тряслась",
+    "тряслись",
+    "трясся",
+    "трясутся",
+    "тряся",
+    "трясясь",
+    "тряхнул",
+    "тс",
+    "тсс",
+    "тся",
+    "ту",
+    "туалет",
+    "туалета",
+    "туалетную",
+    "туалеты",
+    "туго",
+    "тугой",
+    "туда",
+    "тужит",
+    "тужить",
+    "туз",
+    "тузенбах",
+    "тузенбаха",
+    "тузенбахе",
+    "тузенбаху",
+    "тузил",
+    "тузили",
+    "тузить",
+    "туловище",
+    "туловищем",
+    "тулон",
+    "тулона",
+    "тулуп",
+    "тулупе",
+    "тульские",
+    "туман",
+    "тумана",
+    "туманам",
+    "туманами",
+    "туманах",
+    "тумане",
+    "туманился",
+    "туманить",
+    "туманное",
+    "туманной",
+    "туманном",
+    "туманную",
+    "туманные",
+    "туманный",
+    "туманным",
+    "туманными",
+    "туманных",
+    "туманов",
+    "туманом",
+    "туманы",
+    "туманят",
+    "тумбе",
+    "тумбу",
+    "тунеядства",
+    "тупая",
+    "тупеет",
+    "тупею",
+    "тупик",
+    "тупицы",
+    "тупо",
+    "тупой",
+    "тупом",
+    "тупость",
+    "тупоумия",
+    "тупоумного",
+    "тупую",
+    "тупым",
+    "тур",
+    "тура",
+    "тургенев",
+    "тургенева",
+    "тургеневская",
+    "турецки",
+    "турецкий",
+    "турецким",
+    "турецкой",
+    "туриста",
+    "турку",
+    "тускло",
+    "тусклое",
+    "тусклой",
+    "тусклые",
+    "тусклый",
+    "тусклым",
+    "тусклыми",
+    "тускнело",
+    "тут",
+    "туфлей",
+    "туфли",
+    "туфлю",
+    "туфлях",
+    "тухлая",
+    "тухлой",
+    "туч",
+    "туча",
+    "тучами",
+    "тучах",
+    "туче",
+    "тучей",
+    "тучи",
+    "тучки",
+    "тучкой",
+    "тучкою",
+    "тучных",
+    "тучу",
+    "туш",
+    "тушат",
+    "тушит",
+    "тушу",
+    "тш",
+    "тщательнее",
+    "тщательно",
+    "тщеславен",
+    "тщеславие",
+    "тщеславии",
+    "тщеславия",
+    "тщеславные",
+    "тщета",
+    "тщетно",
+    "тщетным",
+    "ты",
+    "тый",
+    "тыкать",
+    "тыква",
+    "тысяч",
+    "тысяча",
+    "тысячами",
+    "тысячах",
+    "тысяче",
+    "тысячей",
+    "тысячелетием",
+    "тысячелетий",
+    "тысячелетней",
+    "тысячи",
+    "тысячная",
+    "тысячу",
+    "тысячью",
+    "тысящи",
+    "тычет",
+    "тычут",
+    "тьма",
+    "тьме",
+    "тьмой",
+    "тьму",
+    "тьмы",
+    "тьфу",
+    "тюков",
+    "тюлевом",
+    "тюменев",
+    "тюменева",
+    "тюремном",
+    "тюремные",
+    "тюремный",
+    "тюрьма",
+    "тюрьмами",
+    "тюрьме",
+    "тюрьмой",
+    "тюрьму",
+    "тюрьмы",
+    "тютчева",
+    "тюфяки",
+    "тягла",
+    "тягой",
+    "тягостию",
+    "тягостно",
+    "тягостное",
+    "тягостную",
+    "тягостны",
+    "тягость",
+    "тяготеют",
+    "тяготили",
+    "тяготились",
+    "тяготился",
+    "тяготит",
+    "тяготить",
+    "тяготишься",
+    "тяготящее",
+    "тягу",
+    "тяжбам",
+    "тяжбу",
+    "тяжебным",
+    "тяжел",
+    "тяжела",
+    "тяжелая",
+    "тяжеле",
+    "тяжелее",
+    "тяжеленько",
+    "тяжелит",
+    "тяжело",
+    "тяжеловеса",
+    "тяжелого",
+    "тяжелое",
+    "тяжелой",
+    "тяжелом",
+    "тяжелую",
+    "тяжелы",
+    "тяжелые",
+    "тяжелый",
+    "тяжелым",
+    "тяжелыми",
+    "тяжелых",
+    "тяжести",
+    "тяжесть",
+    "тяжкие",
+    "тяжкий",
+    "тяжким",
+    "тяжких",
+    "тяжко",
+    "тяжкое",
+    "тянет",
+    "тянется",
+    "тянул",
+    "тянулась",
+    "тянулись",
+    "тянуло",
+    "тянулось",
+    "тянутся",
+    "тянуть",
+    "тяп",
+    "у",
+    "уа",
+    "уайльда",
+    "убавив",
+    "убавить",
+    "убаюканные",
+    "убаюкивала",
+    "убаюкивают",
+    "убегает",
+    "убегал",
+    "убегающей",
+    "убегающим",
+    "убегая",
+    "убегу",
+    "убедилась",
+    "убедился",
+    "убедим",
+    "убедительнейшая",
+    "убедительно",
+    "убедительностью",
+    "убедительную",
+    "убедится",
+    "убедить",
+    "убедиться",
+    "убедишься",
+    "убежал",
+    "убежала",
+    "убежали",
+    "убежать",
+    "убеждаюсь",
+    "убеждая",
+    "убежден",
+    "убеждена",
+    "убеждение",
+    "убеждением",
+    "убеждении",
+    "убеждений",
+    "убеждению",
+    "убеждения",
+    "убеждениями",
+    "убеждениях",
+    "убежденнее",
+    "убежденно",
+    "убежденный",
+    "убежденным",
+    "убеждены",
+    "убежденьях",
+    "убежит",
+    "убежите",
+    "убей",
+    "убейте",
+    "убережешь",
+    "уберет",
+    "уберечь",
+    "уберешь",
+    "уберите",
+    "уберу",
+    "убив",
+    "убиваем",
+    "убивает",
+    "убиваете",
+    "убивается",
+    "убивал",
+    "убивалась",
+    "убивали",
+    "убиваль",
+    "убивать",
+    "убивают",
+    "убиваясь",
+    "убивец",
+    "убивство",
+    "убийств",
+    "убийства",
+    "убийстве",
+    "убийство",
+    "убийству",
+    "убийца",
+    "убийцам",
+    "убийцей",
+    "убийцу",
+    "убийцы",
+    "убил",
+    "убила",
+    "убили",
+    "убирает",
+    "убирай",
+    "убирайся",
+    "убирайтесь",
+    "убирал",
+    "убирала",
+    "убирании",
+    "убирать",
+    "убираю",
+    "убирают",
+    "убирая",
+    "убит",
+    "убита",
+    "убитая",
+    "убито",
+    "убитого",
+    "убитой",
+    "убитою",
+    "убитую",
+    "убитые",
+    "убитый",
+    "убитым",
+    "убитыми",
+    "убить",
+    "убогая",
+    "убогий",
+    "убор",
+    "убора",
+    "уборе",
+    "уборка",
+    "уборке",
+    "уборки",
+    "убоюсь",
+    "убоялись",
+    "убрал",
+    "убрала",
+    "убралась",
+    "убрали",
+    "убрались",
+    "убран",
+    "убранная",
+    "убранной",
+    "убранною",
+    "убранные",
+    "убранных",
+    "убранстве",
+    "убранство",
+    "убрать",
+    "убыло",
+    "убытка",
+    "убыткам",
+    "убытками",
+    "убытку",
+    "убыток",
+    "убьем",
+    "убьет",
+    "убьете",
+    "убьешь",
+    "убьешься",
+    "убью",
+    "уважавший",
+    "уважаем",
+    "уважаемой",
+    "уважаемому",
+    "уважаемым",
+    "уважает",
+    "уважаете",
+    "уважаешь",
+    "уважай",
+    "уважал",
+    "уважала",
+    "уважали",
+    "уважать",
+    "уважаю",
+    "уважают",
+    "уважающие",
+    "уважение",
+    "уважением",
+    "уважении",
+    "уважения",
+    "уварился",
+    "уведи",
+    "уведите",
+    "уведомил",
+    "уведомили",
+    "уведомить",
+    "уведомлен",
+    "уведомление",
+    "уведомлял",
+    "уведомляла",
+    "увез",
+    "увезен",
+    "увезите",
+    "увезла",
+    "увезли",
+    "увезти",
+    "увезу",
+    "увезшей",
+    "увековечить",
+    "увел",
+    "увела",
+    "увеличивается",
+    "увеличивалось",
+    "увеличиваются",
+    "увеличилась",
+    "увеличились",
+    "увеличился",
+    "увеличить",
+    "увенчали",
+    "увенчались",
+    "уверен",
+    "уверена",
+    "уверение",
+    "уверений",
+    "увереннее",
+    "уверенно",
+    "уверенности",
+    "уверенность",
+    "уверенностью",
+    "уверенные",
+    "уверенный",
+    "уверенным",
+    "уверены",
+    "уверенья",
+    "уверил",
+    "уверился",
+    "уверит",
+    "уверительно",
+    "уверить",
+    "уверишь",
+    "уверовала",
+    "уверовали",
+    "уверовать",
+    "увертел",
+    "увертка",
+    "увертки",
+    "увертывайся",
+    "уверует",
+    "уверуют",
+    "уверяет",
+    "уверяйте",
+    "уверял",
+    "уверяла",
+    "уверяли",
+    "уверять",
+    "уверяю",
+    "уверяют",
+    "уверяя",
+    "увеселения",
+    "увеселительного",
+    "увеселительные",
+    "увеселительный",
+    "увеселяли",
+    "увесистых",
+    "увести",
+    "увесть",
+    "увечному",
+    "увещевая",
+    "увидав",
+    "увидавши",
+    "увидал",
+    "увидала",
+    "увидали",
+    "увидать",
+    "увидев",
+    "увидел",
+    "увидела",
+    "увидели",
+    "увидеть",
+    "увидим",
+    "увидимся",
+    "увидит",
+    "увидите",
+    "увидишь",
+    "увидишься",
+    "увидя",
+    "увидят",
+    "увижу",
+    "увижусь",
+    "увитое",
+    "увлек",
+    "увлекаем",
+    "увлекал",
+    "увлекалась",
+    "увлекался",
+    "увлекательный",
+    "увлекаться",
+    "увлекают",
+    "увлекающаяся",
+    "увлекаясь",
+    "увлеклась",
+    "увлекли",
+    "увлекло",
+    "увлекся",
+    "увлекусь",
+    "увлечен",
+    "увлечение",
+    "увлечением",
+    "увлечений",
+    "увлечению",
+    "увлечения",
+    "увлеченный",
+    "увлечены",
+    "увлечь",
+    "увлечься",
+    "уводила",
+    "уводит",
+    "увози",
+    "уволили",
+    "уволь",
+    "увольняли",
+    "увольте",
+    "увражами",
+    "увы",
+    "увядать",
+    "увядающей",
+    "увядая",
+    "увядшей",
+    "увядшие",
+    "увяз",
+    "увязаться",
+    "увязнув",
+    "увязывает",
+    "увял",
+    "увяла",
+    "угадав",
+    "угадает",
+    "угадаете",
+    "угадай",
+    "угадал",
+    "угадала",
+    "угадали",
+    "угаданная",
+    "угадано",
+    "угаданы",
+    "угадать",
+    "угадывает",
+    "угадываете",
+    "угадываешь",
+    "угадывал",
+    "угадывало",
+    "угадывать",
+    "угадываю",
+    "угадывая",
+    "угар",
+    "угарно",
+    "угас",
+    "угасающего",
+    "угасла",
+    "угасли",
+    "угасло",
+    "угаснет",
+    "угаснувшем",
+    "угасшим",
+    "угла",
+    "углам",
+    "углах",
+    "угле",
+    "углов",
+    "угловатое",
+    "угловатую",
+    "угловатым",
+    "угловатыми",
+    "угловом",
+    "углом",
+    "углу",
+    "углубилась",
+    "углубился",
+    "углубится",
+    "углубиться",
+    "углублен",
+    "углубление",
+    "углублении",
+    "углы",
+    "угль",
+    "угнетает",
+    "угнетал",
+    "угнетена",
+    "уговаривай",
+    "уговаривал",
+    "уговаривала",
+    "уговаривая",
+    "уговор",
+    "уговора",
+    "уговорив",
+    "уговорил",
+    "уговорите",
+    "уговорить",
+    "уговориться",
+    "уговором",
+    "угодить",
+    "угодишь",
+    "угодливости",
+    "угодно",
+    "угождений",
+    "угождения",
+    "угол",
+    "уголка",
+    "уголке",
+    "уголки",
+    "уголков",
+    "уголку",
+    "уголовное",
+    "уголовных",
+    "уголок",
+    "уголь",
+    "угольев",
+    "угольком",
+    "угольной",
+    "уголья",
+    "угорелая",
+    "угорелый",
+    "угорят",
+    "угостить",
+    "угощает",
+    "угощал",
+    "угощать",
+    "угощение",
+    "угощением",
+    "угощения",
+    "угощу",
+    "угрожайте",
+    "угрожал",
+    "угрожающее",
+    "угрожающею",
+    "угрожающий",
+    "угрожающую",
+    "угроза",
+    "угрозами",
+    "угрозой",
+    "угрозою",
+    "угрозу",
+    "угрозы",
+    "угрызать",
+    "угрызение",
+    "угрызения",
+    "угрюм",
+    "угрюма",
+    "угрюмо",
+    "угрюмого",
+    "угрюмое",
+    "угрюмой",
+    "угрюмому",
+    "угрюмость",
+    "угрюмство",
+    "угрюмые",
+    "угрюмый",
+    "угрюмым",
+    "угрюмых",
+    "угрях",
+    "удавалось",
+    "удавался",
+    "удавившеюся",
+    "удавившиеся",
+    "удавился",
+    "удавиться",
+    "удается",
+    "удалась",
+    "удалении",
+    "удали",
+    "удалились",
+    "удалилось",
+    "удалился",
+    "удалиться",
+    "удалось",
+    "удался",
+    "удалюсь",
+    "удалявшейся",
+    "удалявшиеся",
+    "удаляется",
+    "удаляться",
+    "удар",
+    "удара",
+    "ударам",
+    "ударами",
+    "ударе",
+    "ударение",
+    "ударившего",
+    "ударил",
+    "ударила",
+    "ударилась",
+    "ударило",
+    "ударит",
+    "ударится",
+    "ударить",
+    "удариться",
+    "ударов",
+    "ударом",
+    "удару",
+    "удары",
+    "ударь",
+    "ударю",
+    "ударяя",
+    "ударяясь",
+    "удастся",
+    "удаче",
+    "удачнее",
+    "удачно",
+    "удвоенное",
+    "удвоенною",
+    "удвоенным",
+    "удвоивается",
+    "удвоил",
+    "удвоилась",
+    "удвоилось",
+    "удвоишь",
+    "удел",
+    "удержал",
+    "удержала",
+    "удержалась",
+    "удержали",
+    "удержались",
+    "удержался",
+    "удержанность",
+    "удержать",
+    "удержаться",
+    "удерживает",
+    "удерживается",
+    "удерживайте",
+    "удерживал",
+    "удерживала",
+    "удерживали",
+    "удерживать",
+    "удерживая",
+    "удерживаясь",
+    "удержись",
+    "удержит",
+    "удержите",
+    "удержу",
+    "удивившею",
+    "удивившийся",
+    "удивившись",
+    "удивил",
+    "удивилась",
+    "удивили",
+    "удивились",
+    "удивило",
+    "удивился",
+    "удивительная",
+    "удивительно",
+    "удивительного",
+    "удивительное",
+    "удивительном",
+    "удивительною",
+    "удивительные",
+    "удивительный",
+    "удивительным",
+    "удивить",
+    "удивлена",
+    "удивление",
+    "удивлением",
+    "удивлении",
+    "удивлению",
+    "удивления",
+    "удивленная",
+    "удивленно",
+    "удивленного",
+    "удивленной",
+    "удивленный",
+    "удивленным",
+    "удивленными",
+    "удивленья",
+    "удивлю",
+    "удивляетесь",
+    "удивляется",
+    "удивляешься",
+    "удивлял",
+    "удивлялась",
+    "удивлялись",
+    "удивлялся",
+    "удивлять",
+    "удивляться",
+    "удивляюсь",
+    "удивляясь",
+    "удивясь",
+    "удивятся",
+    "удил",
+    "удит",
+    "удить",
+    "удобнее",
+    "удобно",
+    "удобного",
+    "удобном",
+    "удобную",
+    "удобопонятную",
+    "удобств",
+    "удобство",
+    "удовлетворен",
+    "удовлетворена",
+    "удовлетворение",
+    "удовлетворения",
+    "удовлетворенного",
+    "удовлетворенное",
+    "удовлетворенной",
+    "удовлетворенный",
+    "удовлетворенных",
+    "удовлетворил",
+    "удовлетворилась",
+    "удовлетворительно",
+    "удовлетворительного",
+    "удовлетворительном",
+    "удовлетворительною",
+    "удовлетворительные",
+    "удовлетворительный",
+    "удовлетворительным",
+    "удовлетворить",
+    "удовлетворят",
+    "удовлетворяясь",
+    "удовольствие",
+    "удовольствием",
+    "удовольствии",
+    "удовольствия",
+    "удовольствиях",
+    "удовольствовался",
+    "удостаивают",
+    "удостоверения",
+    "удостоверившись",
+    "удостовериться",
+    "удостоивая",
+    "удостоила",
+    "удостоили",
+    "удостоился",
+    "удочками",
+    "удочки",
+    "удочку",
+    "удрал",
+    "удрученному",
+    "удрученный",
+    "удушливая",
+    "удушливого",
+    "удушливый",
+    "удушьем",
+    "удушья",
+    "удят",
+    "уедем",
+    "уедет",
+    "уедете",
+    "уедешь",
+    "уединение",
+    "уединении",
+    "уединения",
+    "уединеннее",
+    "уединенно",
+    "уединенное",
+    "уединенной",
+    "уединенном",
+    "уединился",
+    "уединиться",
+    "уеду",
+    "уедут",
+    "уезд",
+    "уезда",
+    "уезде",
+    "уездили",
+    "уездном",
+    "уездную",
+    "уездные",
+    "уездный",
+    "уездным",
+    "уезду",
+    "уезжавшей",
+    "уезжаем",
+    "уезжает",
+    "уезжаете",
+    "уезжаешь",
+    "уезжай",
+    "уезжайте",
+    "уезжал",
+    "уезжала",
+    "уезжали",
+    "уезжать",
+    "уезжаю",
+    "уезжают",
+    "уезжая",
+    "уехал",
+    "уехала",
+    "уехали",
+    "уехать",
+    "уж",
+    "ужаленного",
+    "ужалило",
+    "ужаль",
+    "ужас",
+    "ужаса",
+    "ужасались",
+    "ужасался",
+    "ужасам",
+    "ужасами",
+    "ужасают",
+    "ужасаясь",
+    "ужасе",
+    "ужасен",
+    "ужасная",
+    "ужаснее",
+    "ужасней",
+    "ужаснейшая",
+    "ужаснейшее",
+    "ужаснейший",
+    "ужаснейшим",
+    "ужасно",
+    "ужасного",
+    "ужасное",
+    "ужасной",
+    "ужасном",
+    "ужаснувшею",
+    "ужаснулась",
+    "ужаснулись",
+    "ужасную",
+    "ужасный",
+    "ужасным",
+    "ужасными",
+    "ужасных",
+    "ужасов",
+    "ужасом",
+    "ужасу",
+    "ужасы",
+    "уже",
+    "ужели",
+    "ужель",
+    "уживается",
+    "уживался",
+    "ужин",
+    "ужина",
+    "ужинаем",
+    "ужинает",
+    "ужинаете",
+    "ужинайте",
+    "ужинал",
+    "ужинали",
+    "ужинать",
+    "ужиная",
+    "ужином",
+    "ужину",
+    "ужо",
+    "уз",
+    "узаконенной",
+    "уздцы",
+    "узел",
+    "узелком",
+    "узелок",
+    "узенькая",
+    "узенькой",
+    "узенькую",
+    "узкая",
+    "узкие",
+    "узкий",
+    "узкое",
+    "узкой",
+    "узкому",
+    "узкую",
+    "узла",
+    "узлами",
+    "узле",
+    "узлом",
+    "узлы",
+    "узнав",
+    "узнавал",
+    "узнавала",
+    "узнавали",
+    "узнавать",
+    "узнавая",
+    "узнавшим",
+    "узнаем",
+    "узнает",
+    "узнаете",
+    "узнаешь",
+    "узнай",
+    "узнайте",
+    "узнал",
+    "узнала",
+    "узнали",
+    "узнать",
+    "узнаю",
+    "узнают",
+    "узор",
+    "узора",
+    "узоре",
+    "узорном",
+    "узорные",
+    "узорный",
+    "узорными",
+    "узоров",
+    "узором",
+    "узоры",
+    "узость",
+    "узрит",
+    "узрят",
+    "узы",
+    "уйдем",
+    "уйдемте",
+    "уйдет",
+    "уйдете",
+    "уйдешь",
+    "уйди",
+    "уйдите",
+    "уйду",
+    "уйдут",
+    "уймите",
+    "уймитесь",
+    "уйти",
+    "укажет",
+    "укажете",
+    "укажите",
+    "укажу",
+    "указав",
+    "указал",
+    "указала",
+    "указали",
+    "указание",
+    "указанием",
+    "указаний",
+    "указанию",
+    "указания",
+    "указанного",
+    "указательном",
+    "указательным",
+    "указать",
+    "указки",
+    "указывает",
+    "указывал",
+    "указывала",
+    "указывали",
+    "указывать",
+    "указывают",
+    "указывая",
+    "укачивая",
+    "уклада",
+    "укладка",
+    "укладке",
+    "укладки",
+    "укладкой",
+    "укладкою",
+    "укладку",
+    "укладок",
+    "укладывает",
+    "укладывала",
+    "укладывался",
+    "укладывать",
+    "укладываться",
+    "уклонился",
+    "уклонитесь",
+    "уклончивость",
+    "уклонюсь",
+    "уклоняете",
+    "уклоняется",
+    "уклонялась",
+    "уклонялись",
+    "уклоняюсь",
+    "уклоняясь",
+    "укокошил",
+    "укор",
+    "укора",
+    "укоренился",
+    "укоризненно",
+    "укоризной",
+    "укоризны",
+    "укоров",
+    "укором",
+    "укоряемый",
+    "укорял",
+    "укоряла",
+    "укоряли",
+    "укорять",
+    "укоряю",
+    "укоряют",
+    "украденный",
+    "украденным",
+    "украдкой",
+    "украл",
+    "украли",
+    "украсишь",
+    "украсть",
+    "укрась",
+    "украшают",
+    "украшение",
+    "украшением",
+    "украшенной",
+    "укрепился",
+    "укрепит",
+    "укрепится",
+    "укрепляет",
+    "укреплялась",
+    "укрепляться",
+    "укроете",
+    "укрощая",
+    "укрываться",
+    "укрыла",
+    "укрыться",
+    "уксуса",
+    "уксусом",
+    "уксусу",
+    "укусил",
+    "укусила",
+    "уладил",
+    "уладилось",
+    "уладится",
+    "уладить",
+    "уладь",
+    "уланом",
+    "улеглись",
+    "улеглось",
+    "улегся",
+    "улегшись",
+    "улетает",
+    "улетайте",
+    "улетал",
+    "улетающему",
+    "улетевший",
+    "улетело",
+    "улететь",
+    "улетим",
+    "улетит",
+    "улетят",
+    "улизнет",
+    "улизнул",
+    "улизнуть",
+    "улик",
+    "улика",
+    "улике",
+    "улики",
+    "уликой",
+    "улиссов",
+    "улиц",
+    "улица",
+    "улицам",
+    "улицами",
+    "улицах",
+    "улице",
+    "улицу",
+    "улицы",
+    "уличать",
+    "уличку",
+    "уличная",
+    "уличное",
+    "уличной",
+    "уличном",
+    "уличному",
+    "уличною",
+    "уличную",
+    "уличные",
+    "уличный",
+    "уличным",
+    "уловив",
+    "уловила",
+    "уловимого",
+    "уловит",
+    "уловить",
+    "уловка",
+    "уловками",
+    "уловляли",
+    "уложено",
+    "уложив",
+    "уложила",
+    "уложить",
+    "уложу",
+    "улучить",
+    "улучшение",
+    "улучшений",
+    "улучшения",
+    "улучшились",
+    "улыбавшееся",
+    "улыбаетесь",
+    "улыбается",
+    "улыбалась",
+    "улыбались",
+    "улыбалось",
+    "улыбался",
+    "улыбаться",
+    "улыбаюсь",
+    "улыбаются",
+    "улыбающегося",
+    "улыбающийся",
+    "улыбающимся",
+    "улыбающихся",
+    "улыбаясь",
+    "улыбка",
+    "улыбкам",
+    "улыбками",
+    "улыбке",
+    "улыбки",
+    "улыбкой",
+    "улыбкою",
+    "улыбку",
+    "улыбнетесь",
+    "улыбнется",
+    "улыбнешься",
+    "улыбнувшись",
+    "улыбнулась",
+    "улыбнулись",
+    "улыбнулся",
+    "улыбнусь",
+    "улыбнуться",
+    "улыбок",
+    "улыбочкой",
+    "ум",
+    "ума",
+    "умаливала",
+    "умаливания",
+    "умалчиваю",
+    "умалялось",
+    "умаялись",
+    "умбрии",
+    "умбрских",
+    "уме",
+    "умевший",
+    "умеем",
+    "умеет",
+    "умеете",
+    "умеешь",
+    "умей",
+    "умел",
+    "умела",
+    "умели",
+    "умельчились",
+    "умен",
+    "умению",
+    "уменье",
+    "уменьшение",
+    "уменьшения",
+    "уменьшить",
+    "уменья",
+    "умер",
+    "умеренная",
+    "умеренности",
+    "умеренностью",
+    "умеренную",
+    "умеренны",
+    "умеренный",
+    "умеренным",
+    "умереть",
+    "умерла",
+    "умерли",
+    "умерло",
+    "умертвила",
+    "умертвить",
+    "умершая",
+    "умершего",
+    "умершее",
+    "умершей",
+    "умершем",
+    "умерший",
+    "умершим",
+    "умершую",
+    "уметь",
+    "умещается",
+    "умею",
+    "умеют",
+    "умея",
+    "умиление",
+    "умилением",
+    "умилении",
+    "умиления",
+    "умилялась",
+    "умирает",
+    "умирал",
+    "умирала",
+    "умирали",
+    "умирать",
+    "умираю",
+    "умирают",
+    "умирающего",
+    "умирающий",
+    "умирающим",
+    "умирающих",
+    "умирая",
+    "умирит",
+    "умна",
+    "умная",
+    "умнее",
+    "умней",
+    "умнейший",
+    "умненько",
+    "умненькой",
+    "умник",
+    "умники",
+    "умников",
+    "умниц",
+    "умница",
+    "умницей",
+    "умничает",
+    "умничанья",
+    "умничать",
+    "умно",
+    "умного",
+    "умной",
+    "умны",
+    "умные",
+    "умный",
+    "умным",
+    "умными",
+    "умных",
+    "умов",
+    "умозрений",
+    "умозрительная",
+    "умозрительным",
+    "умозрительных",
+    "умолить",
+    "умолк",
+    "умолка",
+    "умолкает",
+    "умолкала",
+    "умолкать",
+    "умолкающая",
+    "умолкая",
+    "умолкло",
+    "умолкшими",
+    "умолот",
+    "умолоте",
+    "умолочено",
+    "умолчать",
+    "умолчу",
+    "умолю",
+    "умолявшего",
+    "умоляет",
+    "умолял",
+    "умоляла",
+    "умолять",
+    "умоляю",
+    "умоляюще",
+    "умоляющим",
+    "умоляющими",
+    "умоляя",
+    "умом",
+    "умопомешательства",
+    "умопомешательстве",
+    "уморил",
+    "уморить",
+    "умоюсь",
+    "умрем",
+    "умрет",
+    "умрете",
+    "умрешь",
+    "умри",
+    "умрите",
+    "умру",
+    "умрут",
+    "умственно",
+    "умственного",
+    "умственные",
+    "умственными",
+    "умственных",
+    "уму",
+    "умчались",
+    "умчало",
+    "умчит",
+    "умы",
+    "умывает",
+    "умывайтесь",
+    "умывался",
+    "умывальном",
+    "умываться",
+    "умылась",
+    "умылся",
+    "умысел",
+    "умысла",
+    "умысле",
+    "умыслом",
+    "умыться",
+    "умышленно",
+    "умышленной",
+    "умышленному",
+    "унес",
+    "унесенная",
+    "унесено",
+    "унесет",
+    "унесите",
+    "унесли",
+    "унеслось",
+    "унести",
+    "унесут",
+    "университет",
+    "университета",
+    "университете",
+    "университетские",
+    "университетской",
+    "университетскому",
+    "университету",
+    "университеты",
+    "унижается",
+    "унижал",
+    "унижающая",
+    "унижен",
+    "унижение",
+    "унижения",
+    "униженная",
+    "униженный",
+    "униженным",
+    "унижены",
+    "униженье",
+    "униженьи",
+    "унизился",
+    "унизительно",
+    "унизительного",
+    "унизительны",
+    "унизывались",
+    "унимал",
+    "унимала",
+    "унимали",
+    "унимались",
+    "унимать",
+    "унимают",
+    "уничижения",
+    "уничиженный",
+    "уничтожают",
+    "уничтожаясь",
+    "уничтожен",
+    "уничтоженный",
+    "уничтожив",
+    "уничтожил",
+    "уничтожило",
+    "уничтожим",
+    "уничтожит",
+    "уничтожится",
+    "уничтожить",
+    "уничтожиться",
+    "уносил",
+    "уносила",
+    "уносился",
+    "уносит",
+    "уноситься",
+    "уносишь",
+    "уносящая",
+    "унтер",
+    "уныл",
+    "унылая",
+    "уныло",
+    "унылого",
+    "унылом",
+    "унылый",
+    "уныние",
+    "унынием",
+    "унынии",
+    "уныния",
+    "уняли",
+    "унять",
+    "упавшего",
+    "упавший",
+    "упавшим",
+    "упадал",
+    "упадала",
+    "упадет",
+    "упадете",
+    "упади",
+    "упадка",
+    "упадку",
+    "упадок",
+    "упаду",
+    "упадут",
+    "упал",
+    "упала",
+    "упали",
+    "упало",
+    "упасла",
+    "упасть",
+    "упер",
+    "уперлась",
+    "уперлася",
+    "уперся",
+    "упечь",
+    "упивался",
+    "упиваясь",
+    "упираете",
+    "упирал",
+    "упиралось",
+    "упирался",
+    "упираться",
+    "упирая",
+    "упираясь",
+    "уписала",
+    "уписалась",
+    "упиться",
+    "уплате",
+    "уплатил",
+    "уплатили",
+    "уплатить",
+    "уплатою",
+    "уплату",
+    "уплаты",
+    "уплывешь",
+    "уплыли",
+    "упование",
+    "уповать",
+    "уподоблению",
+    "упоена",
+    "упоением",
+    "упоительно",
+    "упокоил",
+    "упокой",
+    "уполномочил",
+    "уполномочила",
+    "упоминает",
+    "упоминается",
+    "упоминайте",
+    "упоминал",
+    "упоминала",
+    "упоминать",
+    "упоминаю",
+    "упомнишь",
+    "упомню",
+    "упомянет",
+    "упомяните",
+    "упомяну",
+    "упомянул",
+    "упомянула",
+    "упомянули",
+    "упомянута",
+    "упомянуто",
+    "упомянуть",
+    "упор",
+    "упорная",
+    "упорно",
+    "упорное",
+    "упорной",
+    "упорному",
+    "упорную",
+    "упорный",
+    "упорным",
+    "упорство",
+    "упорством",
+    "употебите",
+    "употребил",
+    "употребит",
+    "употребить",
+    "употребление",
+    "употреблении",
+    "употребления",
+    "употреблю",
+    "употребляется",
+    "употреблял",
+    "употреблялись",
+    "употреблять",
+    "употребляя",
+    "управе",
+    "управимся",
+    "управитель",
+    "управится",
+    "управиться",
+    "управление",
+    "управлении",
+    "управления",
+    "управлюсь",
+    "управляет",
+    "управлял",
+    "управлять",
+    "управляющего",
+    "управляющему",
+    "управляющий",
+    "управляющим",
+    "управляющих",
+    "управляя",
+    "управу",
+    "управы",
+    "упразднена",
+    "упрашивал",
+    "упрашивала",
+    "упрашивали",
+    "упрашивания",
+    "упрашиванья",
+    "упрашивать",
+    "упрашивая",
+    "упрек",
+    "упрека",
+    "упрекал",
+    "упрекала",
+    "упрекали",
+    "упрекать",
+    "упрекаю",
+    "упреке",
+    "упреки",
+    "упрекнешь",
+    "упрекнул",
+    "упрекнули",
+    "упрекнуть",
+    "упреков",
+    "упреком",
+    "упрет",
+    "упрется",
+    "упросил",
+    "упросит",
+    "упросить",
+    "упрочились",
+    "упрочить",
+    "упругих",
+    "упругой",
+    "упругость",
+    "упрыгаетесь",
+    "упрямец",
+    "упрямо",
+    "упрямой",
+    "упрямстве",
+    "упрямство",
+    "упрямству",
+    "упрямый",
+    "упрямься",
+    "упрятал",
+    "упускай",
+    "упускал",
+    "упускать",
+    "упускаю",
+    "упущение",
+    "упущений",
+    "упущения",
+    "упущу",
+    "упыри",
+    "упьется",
+    "ура",
+    "ураган",
+    "уразумела",
+    "уразумения",
+    "урезонить",
+    "урной",
+    "урод",
+    "уродился",
+    "уродится",
+    "уродливое",
+    "уродливы",
+    "уродуется",
+    "урожаем",
+    "урожай",
+    "урожденная",
+    "урок",
+    "уроками",
+    "уроки",
+    "уроков",
+    "уроком",
+    "уроненную",
+    "уроненным",
+    "уронены",
+    "урони",
+    "уронив",
+    "уронил",
+    "уронила",
+    "уронили",
+    "уронит",
+    "уроню",
+    "урочные",
+    "урывками",
+    "урывочками",
+    "ус",
+    "усадеб",
+    "усадив",
+    "усадил",
+    "усадили",
+    "усадит",
+    "усадьба",
+    "усадьбе",
+    "усадьбу",
+    "усадьбы",
+    "усаживает",
+    "усаживал",
+    "усаживалась",
+    "усаживался",
+    "усаживать",
+    "усаживаются",
+    "усаживая",
+    "усаживаясь",
+    "усами",
+    "усатые",
+    "усахарил",
+    "усач",
+    "усача",
+    "усачу",
+    "усвоил",
+    "усвоить",
+    "уселась",
+    "уселись",
+    "уселся",
+    "усердием",
+    "усердию",
+    "усердия",
+    "усердная",
+    "усердно",
+    "усердные",
+    "усердным",
+    "усидел",
+    "усидела",
+    "усидеть",
+    "усидит",
+    "усидишь",
+    "усиками",
+    "усиления",
+    "усиленно",
+    "усиленное",
+    "усиленной",
+    "усиленному",
+    "усиленным",
+    "усиливается",
+    "усиливалась",
+    "усиливался",
+    "усиливать",
+    "усиливаясь",
+    "усилившейся",
+    "усилие",
+    "усилием",
+    "усилий",
+    "усилиться",
+    "усилия",
+    "усилиям",
+    "усилиях",
+    "усильем",
+    "усилья",
+    "ускользает",
+    "ускользала",
+    "ускользая",
+    "ускользнет",
+    "ускользнул",
+    "ускользнуть",
+    "ускоренному",
+    "ускоряла",
+    "ускоряя",
+    "услада",
+    "усладой",
+    "услады",
+    "услаждает",
+    "услаждать",
+    "услал",
+    "уследить",
+    "условием",
+    "условии",
+    "условились",
+    "условиться",
+    "условию",
+    "условиях",
+    "условно",
+    "условный",
+    "услуг",
+    "услуга",
+    "услуги",
+    "услугу",
+    "услужливая",
+    "услужливого",
+    "услыхав",
+    "услыхал",
+    "услыхала",
+    "услыхали",
+    "услыхать",
+    "услыша",
+    "услышав",
+    "услышал",
+    "услышали",
+    "услышанный",
+    "услышат",
+    "услышать",
+    "услышая",
+    "услышим",
+    "услышит",
+    "услышите",
+    "услышишь",
+    "услышу",
+    "усмехается",
+    "усмехаясь",
+    "усмехнется",
+    "усмехнувшись",
+    "усмехнулась",
+    "усмехнулись",
+    "усмехнулся",
+    "усмешка",
+    "усмешке",
+    "усмешки",
+    "усмешкой",
+    "усмотреть",
+    "усмотришь",
+    "уснете",
+    "уснешь",
+    "усните",
+    "усну",
+    "уснул",
+    "уснула",
+    "уснут",
+    "уснуть",
+    "усов",
+    "усовещивал",
+    "усомниться",
+    "усопшая",
+    "усопшем",
+    "усопших",
+    "успев",
+    "успеваем",
+    "успевает",
+    "успевал",
+    "успевая",
+    "успевший",
+    "успевшую",
+    "успеем",
+    "успеет",
+    "успеете",
+    "успеешь",
+    "успел",
+    "успела",
+    "успели",
+    "успение",
+    "успеньев",
+    "успеть",
+    "успех",
+    "успеха",
+    "успехами",
+    "успехе",
+    "успехи",
+    "успехов",
+    "успехом",
+    "успеху",
+    "успею",
+    "успеют",
+    "успокаивал",
+    "успокаивался",
+    "успокоен",
+    "успокоения",
+    "успокоенная",
+    "успокоено",
+    "успокоивал",
+    "успокоивала",
+    "успокоивали",
+    "успокоивался",
+    "успокоивать",
+    "успокоил",
+    "успокоила",
+    "успокоилась",
+    "успокоили",
+    "успокоились",
+    "успокоилось",
+    "успокоился",
+    "успокоим",
+    "успокоит",
+    "успокоительно",
+    "успокоительное",
+    "успокоительном",
+    "успокоительные",
+    "успокоительным",
+    "успокоительных",
+    "успокоитесь",
+    "успокоится",
+    "успокоить",
+    "успокоиться",
+    "успокой",
+    "успокойся",
+    "успокойтесь",
+    "успокою",
+    "успокоюсь",
+    "успокоятся",
+    "уст",
+    "уста",
+    "уставай",
+    "уставала",
+    "уставали",
+    "уставало",
+    "уставать",
+    "уставая",
+    "уставившись",
+    "уставил",
+    "уставила",
+    "уставилась",
+    "уставились",
+    "уставился",
+    "уставит",
+    "уставится",
+    "уставленных",
+    "уставом",
+    "уставу",
+    "уставши",
+    "уставший",
+    "уставшим",
+    "уставясь",
+    "устал",
+    "устала",
+    "усталая",
+    "устали",
+    "устало",
+    "усталого",
+    "усталой",
+    "усталом",
+    "усталости",
+    "усталость",
+    "усталостью",
+    "усталую",
+    "усталые",
+    "усталый",
+    "усталым",
+    "усталых",
+    "устанете",
+    "устанешь",
+    "установил",
+    "установила",
+    "установилась",
+    "установились",
+    "установителей",
+    "установители",
+    "установится",
+    "установить",
+    "установлен",
+    "установленное",
+    "установлено",
+    "устану",
+    "устанут",
+    "устаревших",
+    "устареете",
+    "устарелым",
+    "устах",
+    "устающими",
+    "устилал",
+    "устлала",
+    "устланная",
+    "устоит",
+    "устоят",
+    "устоять",
+    "устраивает",
+    "устраивается",
+    "устраивать",
+    "устраиваются",
+    "устранит",
+    "устранить",
+    "устраняйте",
+    "устранять",
+    "устраняя",
+    "устраняясь",
+    "устремив",
+    "устремил",
+    "устремила",
+    "устремились",
+    "устремлен",
+    "устремлена",
+    "устремленной",
+    "устремленные",
+    "устремленный",
+    "устремленным",
+    "устремлены",
+    "устремляется",
+    "устремлялись",
+    "устремляющий",
+    "устриц",
+    "устроен",
+    "устроена",
+    "устроенная",
+    "устроенный",
+    "устроенных",
+    "устроено",
+    "устроены",
+    "устроил",
+    "устроилась",
+    "устроили",
+    "устроилось",
+    "устроим",
+    "устроит",
+    "устроится",
+    "устроить",
+    "устроиться",
+    "устройства",
+    "устройстве",
+    "устройство",
+    "устройством",
+    "устройству",
+    "устрою",
+    "устроятся",
+    "уступает",
+    "уступал",
+    "уступали",
+    "уступаю",
+    "уступают",
+    "уступая",
+    "уступил",
+    "уступила",
+    "уступило",
+    "уступить",
+    "уступом",
+    "уступчива",
+    "устыдясь",
+    "усумнится",
+    "усчитать",
+    "усы",
+    "усыпанной",
+    "усыпаны",
+    "усыпите",
+    "усыпить",
+    "усыпленные",
+    "усыплять",
+    "усядется",
+    "усядусь",
+    "утайки",
+    "утаскивая",
+    "утащил",
+    "утащить",
+    "утвердилась",
+    "утвердилось",
+    "утвердительно",
+    "утверждал",
+    "утверждать",
+    "утверждаю",
+    "утверждают",
+    "утверждая",
+    "утвержден",
+    "утекло",
+    "утер",
+    "утерев",
+    "утерпел",
+    "утерпела",
+    "утерпеть",
+    "утечет",
+    "утешает",
+    "утешал",
+    "утешала",
+    "утешался",
+    "утешать",
+    "утешаюсь",
+    "утешают",
+    "утешение",
+    "утешений",
+    "утешения",
+    "утешенная",
+    "утешенный",
+    "утешенным",
+    "утешенье",
+    "утешенья",
+    "утешились",
+    "утешился",
+    "утешительнее",
+    "утешительное",
+    "утешится",
+    "утешить",
+    "утешишь",
+    "утешься",
+    "утирает",
+    "утирал",
+    "утирала",
+    "утирать",
+    "утирая",
+    "утихал",
+    "утихала",
+    "утихать",
+    "утихла",
+    "утихли",
+    "утихло",
+    "утихнет",
+    "утки",
+    "уткнув",
+    "утлые",
+    "утоли",
+    "утолить",
+    "утомилась",
+    "утомили",
+    "утомился",
+    "утомит",
+    "утомится",
+    "утомлен",
+    "утомлена",
+    "утомление",
+    "утомлении",
+    "утомления",
+    "утомленная",
+    "утомленно",
+    "утомленного",
+    "утомленное",
+    "утомленные",
+    "утомленный",
+    "утомленным",
+    "утомлено",
+    "утомлены",
+    "утомлюсь",
+    "утомляет",
+    "утомлялись",
+    "утомляло",
+    "утонувшая",
+    "утонувший",
+    "утонул",
+    "утонула",
+    "утонут",
+    "утонуть",
+    "утонченности",
+    "утонченность",
+    "утонченный",
+    "утопает",
+    "утопала",
+    "утопало",
+    "утопающей",
+    "утопающий",
+    "утопая",
+    "утопил",
+    "утопилась",
+    "утопится",
+    "утопить",
+    "утопиться",
+    "утопия",
+    "утопленница",
+    "утопленницей",
+    "утопленницу",
+    "уторопленно",
+    "уторопленною",
+    "утр",
+    "утра",
+    "утрам",
+    "утрат",
+    "утрата",
+    "утратив",
+    "утративший",
+    "утратил",
+    "утратила",
+    "утраты",
+    "утраченного",
+    "утраченное",
+    "утреет",
+    "утреннего",
+    "утренней",
+    "утреннем",
+    "утренние",
+    "утренний",
+    "утренник",
+    "утренним",
+    "утренними",
+    "утренних",
+    "утречком",
+    "утри",
+    "утро",
+    "утром",
+    "утру",
+    "утруждайте",
+    "утружденным",
+    "утучнялись",
+    "утыканными",
+    "уф",
+    "ух",
+    "уха",
+    "ухаживавшего",
+    "ухаживает",
+    "ухаживал",
+    "ухаживали",
+    "ухаживанье",
+    "ухватил",
+    "ухватилась",
+    "ухватит",
+    "ухватится",
+    "ухватить",
+    "ухватиться",
+    "ухватках",
+    "ухе",
+    "ухи",
+    "ухитрился",
+    "ухитрись",
+    "ухитряется",
+    "ухитрялся",
+    "ухищрений",
+    "ухлопал",
+    "ухлопали",
+    "ухлопаны",
+    "ухмыляется",
+    "ухмыляясь",
+    "ухну",
+    "ухо",
+    "уход",
+    "уходе",
+    "уходи",
+    "уходил",
+    "уходила",
+    "уходили",
+    "уходило",
+    "уходим",
+    "уходит",
+    "уходите",
+    "уходить",
+    "уходишь",
+    "уходом",
+    "уходя",
+    "уходят",
+    "уходящего",
+    "уходящий",
+    "ухожу",
+    "ухом",
+    "уху",
+    "уцелевшей",
+    "уцелевшие",
+    "уцелел",
+    "уцелела",
+    "уцелели",
+    "уцепились",
+    "уцепился",
+    "уцепиться",
+    "уцепясь",
+    "уча",
+    "участвовала",
+    "участвовали",
+    "участвовать",
+    "участвует",
+    "участи",
+    "участие",
+    "участием",
+    "участии",
+    "участий",
+    "участия",
+    "участки",
+    "участник",
+    "участниках",
+    "участница",
+    "участок",
+    "участь",
+    "участья",
+    "учат",
+    "учатся",
+    "учащенными",
+    "учащенных",
+    "учебное",
+    "учебные",
+    "ученая",
+    "учение",
+    "учений",
+    "ученик",
+    "учеников",
+    "ученику",
+    "ученические",
+    "ученого",
+    "ученой",
+    "ученом",
+    "учености",
+    "ученую",
+    "ученые",
+    "ученый",
+    "ученым",
+    "ученых",
+    "ученье",
+    "ученью",
+    "ученья",
+    "учетверю",
+    "учи",
+    "учившей",
+    "учившийся",
+    "учил",
+    "учила",
+    "училась",
+    "учили",
+    "учились",
+    "учился",
+    "учинить",
+    "учись",
+    "учит",
+    "учителей",
+    "учителем",
+    "учитель",
+    "учительницу",
+    "учительницы",
+    "учителя",
+    "учителях",
+    "учится",
+    "учить",
+    "учиться",
+    "учишь",
+    "учишься",
+    "учнет",
+    "учреждают",
+    "учтиво",
+    "учтивости",
+    "учу",
+    "учусь",
+    "ушам",
+    "ушами",
+    "ушата",
+    "ушах",
+    "ушей",
+    "ушел",
+    "уши",
+    "ушиб",
+    "ушиба",
+    "ушибется",
+    "ушибла",
+    "ушиблена",
+    "ушибленное",
+    "ушибли",
+    "ушки",
+    "ушла",
+    "ушли",
+    "ушло",
+    "ущельям",
+    "ущерб",
+    "ущербной",
+    "ущипнет",
+    "ущипнула",
+    "ую",
+    "уют",
+    "уюта",
+    "уютно",
+    "уютный",
+    "уюты",
+    "уязви",
+    "уязвило",
+    "уязвит",
+    "уязвлена",
+    "уязвленной",
+    "уязвлять",
+    "уяснил",
+    "уяснили",
+    "уяснить",
+    "ф",
+    "фабрик",
+    "фабрикам",
+    "фабриках",
+    "фабрики",
+    "фабрику",
+    "фабричные",
+    "фабричными",
+    "фабричных",
+    "фаддеевна",
+    "фаддеевны",
+    "фазисы",
+    "факел",
+    "факелом",
+    "факт",
+    "факта",
+    "фактам",
+    "фактами",
+    "фактах",
+    "факте",
+    "фактически",
+    "фактические",
+    "фактическую",
+    "фактов",
+    "фактом",
+    "факты",
+    "факультет",
+    "факультета",
+    "факультете",
+    "фаланстера",
+    "фаланстере",
+    "фаланстеры",
+    "фалду",
+    "фальшив",
+    "фальшива",
+    "фальшивая",
+    "фальшиво",
+    "фальшивое",
+    "фальшивой",
+    "фальшивом",
+    "фальшивую",
+    "фальшивы",
+    "фальшивые",
+    "фальшивый",
+    "фальшивым",
+    "фальшивых",
+    "фамилии",
+    "фамилию",
+    "фамилия",
+    "фамильной",
+    "фамильные",
+    "фамильный",
+    "фамильярен",
+    "фамильярно",
+    "фамильярное",
+    "фамильярности",
+    "фамильярностию",
+    "фамильярность",
+    "фанатизм",
+    "фанатизмом",
+    "фант",
+    "фантазерка",
+    "фантазией",
+    "фантазии",
+    "фантазий",
+    "фантазию",
+    "фантазия",
+    "фантазиям",
+    "фантазиях",
+    "фантаст",
+    "фантастичен",
+    "фантастические",
+    "фантастическим",
+    "фантастического",
+    "фантастическое",
+    "фантастическому",
+    "фантастическую",
+    "фантастична",
+    "фантастичностью",
+    "фанфарон",
+    "фанфаронишки",
+    "фанфаронства",
+    "фанфароню",
+    "фартуке",
+    "фартуком",
+    "фартучек",
+    "фарфор",
+    "фарфоровые",
+    "фарфоровых",
+    "фаршированные",
+    "фасона",
+    "фат",
+    "фата",
+    "фатальное",
+    "фатер",
+    "фатера",
+    "фатере",
+    "фатеришку",
+    "фатеру",
+    "фатеры",
+    "фатой",
+    "фатом",
+    "фатою",
+    "фаты",
+    "фауну",
+    "фауст",
+    "фаустами",
+    "феврале",
+    "февраль",
+    "февраля",
+    "федераций",
+    "федор",
+    "федора",
+    "федоровна",
+    "федоровной",
+    "федоровны",
+    "федосья",
+    "федот",
+    "федотик",
+    "федотика",
+    "федотиком",
+    "федя",
+    "федяева",
+    "фейерверк",
+    "фейерверков",
+    "фельдшер",
+    "феномен",
+    "феноменальным",
+    "ферапонт",
+    "ферапонта",
+    "ферапонтом",
+    "ферапонту",
+    "фермах",
+    "фермера",
+    "фермуар",
+    "фермы",
+    "ферулой",
+    "фески",
+    "фестонами",
+    "фестонов",
+    "фестоны",
+    "фет",
+    "фефелой",
+    "фи",
+    "фиалки",
+    "фигляр",
+    "фигура",
+    "фигурам",
+    "фигуре",
+    "фигурка",
+    "фигуркой",
+    "фигурой",
+    "фигуру",
+    "фигуры",
+    "физиологически",
+    "физиологию",
+    "физиология",
+    "физиономией",
+    "физиономии",
+    "физиономий",
+    "физиономию",
+    "физиономия",
+    "физически",
+    "физические",
+    "физическими",
+    "физического",
+    "физическое",
+    "физическом",
+    "физическую",
+    "филе",
+    "филин",
+    "филипп",
+    "филиппа",
+    "филиппе",
+    "филиппики",
+    "филиппо",
+    "филиппом",
+    "филистера",
+    "философ",
+    "философии",
+    "философистика",
+    "философию",
+    "философия",
+    "философом",
+    "философская",
+    "философских",
+    "философствовал",
+    "философствовать",
+    "философствуем",
+    "философствует",
+    "философствуете",
+    "философствуй",
+    "философствуют",
+    "философы",
+    "филька",
+    "фимиам",
+    "финансах",
+    "финансист",
+    "финансовой",
+    "финик",
+    "финифтяный",
+    "финляндии",
+    "финляндию",
+    "финляндия",
+    "финская",
+    "финский",
+    "фиолетовая",
+    "фирс",
+    "фирса",
+    "фирсом",
+    "фирсу",
+    "фистулу",
+    "флакон",
+    "фламандки",
+    "фланги",
+    "фланелевое",
+    "фланера",
+    "флегматически",
+    "флейта",
+    "флер",
+    "флеровое",
+    "флером",
+    "флигеле",
+    "флигель",
+    "флигеля",
+    "флигелям",
+    "флобера",
+    "флобером",
+    "флорентийский",
+    "флорентийской",
+    "флоренции",
+    "флоренцию",
+    "флоренция",
+    "флору",
+    "флот",
+    "фокус",
+    "фокусы",
+    "фома",
+    "фоминой",
+    "фомич",
+    "фомича",
+    "фомиче",
+    "фомичем",
+    "фомичом",
+    "фомичу",
+    "фон",
+    "фонаре",
+    "фонарей",
+    "фонарем",
+    "фонари",
+    "фонарик",
+    "фонарном",
+    "фонарь",
+    "фонаря",
+    "фонд",
+    "фондов",
+    "фоне",
+    "фонтан",
+    "фонтанов",
+    "фонтаном",
+    "фону",
+    "форелей",
+    "форели",
+    "форель",
+    "форм",
+    "форма",
+    "формалистики",
+    "формально",
+    "формальностей",
+    "формам",
+    "формами",
+    "формах",
+    "форме",
+    "форменно",
+    "форменное",
+    "форменной",
+    "форменном",
+    "формировалась",
+    "формироваться",
+    "формируется",
+    "формой",
+    "форму",
+    "формулирован",
+    "формулярных",
+    "формы",
+    "фортепианах",
+    "фортепиано",
+    "фортепьян",
+    "фортепьяно",
+    "форточек",
+    "форточки",
+    "форточку",
+    "фортуной",
+    "фортуну",
+    "фотографии",
+    "фотографию",
+    "фотография",
+    "фотографом",
+    "фра",
+    "фраз",
+    "фраза",
+    "фразами",
+    "фразах",
+    "фразе",
+    "фразер",
+    "фразу",
+    "фразы",
+    "фрак",
+    "фрака",
+    "фраке",
+    "фраком",
+    "франс",
+    "франт",
+    "франта",
+    "франтов",
+    "франтом",
+    "франты",
+    "франц",
+    "францевна",
+    "францевне",
+    "францевны",
+    "франции",
+    "франциска",
+    "францию",
+    "француженке",
+    "француженки",
+    "француженок",
+    "француз",
+    "француза",
+    "французах",
+    "французов",
+    "французски",
+    "французские",
+    "французский",
+    "французским",
+    "французского",
+    "французское",
+    "французской",
+    "французском",
+    "французскому",
+    "французы",
+    "фрегате",
+    "фреской",
+    "фри",
+    "фривольное",
+    "фруктах",
+    "фрукты",
+    "фу",
+    "фуй",
+    "фунт",
+    "фунта",
+    "фунтов",
+    "фуражечку",
+    "фуражка",
+    "фуражках",
+    "фуражке",
+    "фуражки",
+    "фуражкой",
+    "фуражку",
+    "фурор",
+    "фурье",
+    "футляр",
+    "футляра",
+    "футлярах",
+    "футляре",
+    "футляров",
+    "футляры",
+    "футуризм",
+    "футуризма",
+    "футуриста",
+    "фуфайки",
+    "фуфайку",
+    "фыркает",
+    "фыркать",
+    "фыркнул",
+    "фыркнули",
+    "фье",
+    "фьезоле",
+    "х",
+    "ха",
+    "хаживал",
+    "халат",
+    "халата",
+    "халатах",
+    "халате",
+    "халатом",
+    "хам",
+    "хандра",
+    "хандрил",
+    "хандрит",
+    "хандрить",
+    "хандрой",
+    "ханжу",
+    "ханской",
+    "хаос",
+    "хаоса",
+    "хаосе",
+    "характер",
+    "характера",
+    "характере",
+    "характеристике",
+    "характеристики",
+    "характеристику",
+    "характерная",
+    "характерно",
+    "характерность",
+    "характерные",
+    "характерный",
+    "характером",
+    "характеру",
+    "характеры",
+    "харей",
+    "хари",
+    "харкает",
+    "харламов",
+    "харламова",
+    "харчевен",
+    "харчевне",
+    "харчевни",
+    "харчевню",
+    "харчевня",
+    "харьков",
+    "харькова",
+    "харькове",
+    "харьковские",
+    "харькоеве",
+    "хата",
+    "хвалил",
+    "хвалилась",
+    "хвалили",
+    "хвалился",
+    "хвалимому",
+    "хвалит",
+    "хвалится",
+    "хвалить",
+    "хвалу",
+    "хвалы",
+    "хвалят",
+    "хвастался",
+    "хвастаться",
+    "хвастают",
+    "хвастливостей",
+    "хвастун",
+    "хватавшемуся",
+    "хватает",
+    "хватаете",
+    "хватается",
+    "хватал",
+    "хватало",
+    "хватать",
+    "хватаю",
+    "хватаются",
+    "хватающий",
+    "хватая",
+    "хватаясь",
+    "хватил",
+    "хватили",
+    "хватились",
+    "хватило",
+    "хватит",
+    "хватится",
+    "хватишься",
+    "хвать",
+    "хвораете",
+    "хворал",
+    "хвораю",
+    "хворая",
+    "хвост",
+    "хвостатой",
+    "хвостом",
+    "хе",
+    "хений",
+    "хераскова",
+    "херес",
+    "хересу",
+    "херувима",
+    "херувимов",
+    "херувимову",
+    "хижин",
+    "хижина",
+    "хижине",
+    "химерой",
+    "химеры",
+    "хины",
+    "хирург",
+    "хитер",
+    "хитон",
+    "хитра",
+    "хитрая",
+    "хитрее",
+    "хитрей",
+    "хитрейшего",
+    "хитрейшим",
+    "хитреца",
+    "хитрецы",
+    "хитрила",
+    "хитрить",
+    "хитришь",
+    "хитро",
+    "хитрого",
+    "хитрое",
+    "хитрости",
+    "хитростная",
+    "хитрость",
+    "хитростью",
+    "хитростями",
+    "хитрые",
+    "хитрый",
+    "хитрым",
+    "хитрят",
+    "хихикайте",
+    "хихикал",
+    "хихикали",
+    "хихикание",
+    "хихиканье",
+    "хихикать",
+    "хихикая",
+    "хищник",
+    "хищница",
+    "хищной",
+    "хищный",
+    "хлад",
+    "хлада",
+    "хладен",
+    "хладно",
+    "хладнокровен",
+    "хладнокровие",
+    "хладнокровием",
+    "хладнокровнее",
+    "хладнокровно",
+    "хладном",
+    "хладные",
+    "хладных",
+    "хлам",
+    "хламом",
+    "хлеб",
+    "хлеба",
+    "хлебе",
+    "хлебника",
+    "хлебнику",
+    "хлебнувшая",
+    "хлебные",
+    "хлебом",
+    "хлебцем",
+    "хлестала",
+    "хлестнул",
+    "хлещет",
+    "хлещи",
+    "хлой",
+    "хлоп",
+    "хлопает",
+    "хлопали",
+    "хлопаньем",
+    "хлопанья",
+    "хлопать",
+    "хлопнул",
+    "хлоповых",
+    "хлопот",
+    "хлопотавшей",
+    "хлопотал",
+    "хлопотала",
+    "хлопотали",
+    "хлопотам",
+    "хлопотами",
+    "хлопотать",
+    "хлопотах",
+    "хлопотливо",
+    "хлопотливой",
+    "хлопотливые",
+    "хлопотун",
+    "хлопоты",
+    "хлопочем",
+    "хлопочет",
+    "хлопочете",
+    "хлопочи",
+    "хлопочу",
+    "хлопочут",
+    "хлопьями",
+    "хлороформом",
+    "хлынет",
+    "хлынувшую",
+    "хлынул",
+    "хлынула",
+    "хлынули",
+    "хлынуло",
+    "хлынут",
+    "хлынь",
+    "хлыст",
+    "хлыста",
+    "хлыстиком",
+    "хлыстом",
+    "хме",
+    "хмелел",
+    "хмелен",
+    "хмель",
+    "хмельней",
+    "хмельной",
+    "хмелю",
+    "хмеля",
+    "хмурилась",
+    "хмурился",
+    "хмуритесь",
+    "хмурится",
+    "хмуришься",
+    "хмурые",
+    "хмурьтесь",
+    "хмурясь",
+    "хныкать",
+    "хнычет",
+    "хнычешь",
+    "хнычущих",
+    "хо",
+    "ход",
+    "хода",
+    "ходатай",
+    "ходе",
+    "ходи",
+    "ходил",
+    "ходила",
+    "ходили",
+    "ходило",
+    "ходиль",
+    "ходим",
+    "ходит",
+    "ходите",
+    "ходить",
+    "ходишь",
+    "ходу",
+    "ходьба",
+    "ходьбы",
+    "ходя",
+    "ходят",
+    "ходячей",
+    "ходящее",
+    "хожалые",
+    "хождение",
+    "хождением",
+    "хождении",
+    "хождения",
+    "хожу",
+    "хозе",
+    "хозяев",
+    "хозяева",
+    "хозяевам",
+    "хозяин",
+    "хозяина",
+    "хозяином",
+    "хозяину",
+    "хозяйка",
+    "хозяйке",
+    "хозяйки",
+    "хозяйкина",
+    "хозяйкиной",
+    "хозяйкиною",
+    "хозяйкины",
+    "хозяйкой",
+    "хозяйку",
+    "хозяйничает",
+    "хозяйничала",
+    "хозяйничать",
+    "хозяйская",
+    "хозяйские",
+    "хозяйский",
+    "хозяйским",
+    "хозяйского",
+    "хозяйское",
+    "хозяйской",
+    "хозяйскую",
+    "хозяйства",
+    "хозяйстве",
+    "хозяйственная",
+    "хозяйственно",
+    "хозяйственном",
+    "хозяйственные",
+    "хозяйственным",
+    "хозяйственных",
+    "хозяйство",
+    "хозяйством",
+    "хозяйству",
+    "хозяюшка",
+    "холера",
+    "холеру",
+    "холм",
+    "холма",
+    "холмам",
+    "холмами",
+    "холмах",
+    "холмик",
+    "холмов",
+    "холмы",
+    "холод",
+    "холода",
+    "холоде",
+    "холодеет",
+    "холодело",
+    "холоден",
+    "холодеющим",
+    "холодея",
+    "холодке",
+    "холодна",
+    "холодная",
+    "холоднее",
+    "холодно",
+    "холодного",
+    "холодное",
+    "холодной",
+    "холодном",
+    "холодности",
+    "холодность",
+    "холодною",
+    "холодную",
+    "холодный",
+    "холодным",
+    "холодными",
+    "холодных",
+    "холодок",
+    "холодом",
+    "холоду",
+    "холодя",
+    "холопов",
+    "холостой",
+    "холостою",
+    "холостых",
+    "холста",
+    "холстинные",
+    "холстинными",
+    "холстины",
+    "хомут",
+    "хомуты",
+    "хор",
+    "хора",
+    "хорватов",
+    "хоре",
+    "хорек",
+    "хоровод",
+    "хороводе",
+    "хоровую",
+    "хором",
+    "хоронит",
+    "хоронить",
+    "хоронишь",
+    "хорош",
+    "хороша",
+    "хорошая",
+    "хорошего",
+    "хорошее",
+    "хорошей",
+    "хорошем",
+    "хорошенькая",
+    "хорошенькие",
+    "хорошенький",
+    "хорошенько",
+    "хорошенького",
+    "хорошенькое",
+    "хорошенькой",
+    "хорошенькою",
+    "хорошенькую",
+    "хороши",
+    "хорошие",
+    "хороший",
+    "хорошим",
+    "хороших",
+    "хорошо",
+    "хорошую",
+    "хору",
+    "хорунжина",
+    "хотевшему",
+    "хотевшую",
+    "хотел",
+    "хотела",
+    "хотели",
+    "хотело",
+    "хотелось",
+    "хотель",
+    "хотеть",
+    "хотим",
+    "хотите",
+    "хоть",
+    "хотя",
+    "хотят",
+    "хохлами",
+    "хохлом",
+    "хохлушка",
+    "хохот",
+    "хохота",
+    "хохотал",
+    "хохотала",
+    "хохотали",
+    "хохотать",
+    "хохоте",
+    "хохотом",
+    "хохоча",
+    "хохочет",
+    "хохочешь",
+    "хохочут",
+    "хочет",
+    "хочется",
+    "хочешь",
+    "хочу",
+    "хошь",
+    "храбрая",
+    "храбреца",
+    "храбрится",
+    "храбро",
+    "храбрости",
+    "храм",
+    "храма",
+    "храни",
+    "хранил",
+    "хранилась",
+    "хранили",
+    "хранилось",
+    "хранит",
+    "хранится",
+    "хранить",
+    "храню",
+    "храня",
+    "храп",
+    "храпенье",
+    "храпенья",
+    "храпеть",
+    "храпит",
+    "храпя",
+    "хребту",
+    "хрен",
+    "хреном",
+    "хрену",
+    "хрипел",
+    "хрипенье",
+    "хрипеть",
+    "хрипит",
+    "хрипло",
+    "хриплый",
+    "хриплым",
+    "хрипят",
+    "христа",
+    "христе",
+    "христианин",
+    "христиански",
+    "христианской",
+    "христов",
+    "христовых",
+    "христом",
+    "христос",
+    "христу",
+    "хром",
+    "хромая",
+    "хромой",
+    "хроники",
+    "хроническою",
+    "хрупкие",
+    "хрупко",
+    "хрупкого",
+    "хруст",
+    "хруста",
+    "хрустали",
+    "хрусталь",
+    "хрустальная",
+    "хрустального",
+    "хрустальной",
+    "хрустальном",
+    "хрустальные",
+    "хрустальный",
+    "хрустальным",
+    "хрустел",
+    "хрусти",
+    "хрустит",
+    "хрычовки",
+    "худ",
+    "худа",
+    "худая",
+    "худенькая",
+    "худенькое",
+    "худенькой",
+    "худенькому",
+    "худо",
+    "худое",
+    "художественная",
+    "художественно",
+    "художественного",
+    "художественное",
+    "художественной",
+    "художественность",
+    "художественный",
+    "художество",
+    "художник",
+    "художника",
+    "художники",
+    "художников",
+    "худой",
+    "худосочный",
+    "худощав",
+    "худощавый",
+    "худую",
+    "худы",
+    "худые",
+    "хуже",
+    "хулений",
+    "хулу",
+    "хуторков",
+    "хуторок",
+    "хуторочки",
+    "цапли",
+    "царе",
+    "царевна",
+    "царевнах",
+    "царевне",
+    "царевной",
+    "царем",
+    "царили",
+    "цариц",
+    "царица",
+    "царицей",
+    "царский",
+    "царских",
+    "царское",
+    "царской",
+    "царств",
+    "царственный",
+    "царствие",
+    "царствии",
+    "царство",
+    "царствовал",
+    "царствовали",
+    "царством",
+    "царствует",
+    "царствуют",
+    "царь",
+    "царьграда",
+    "царьградских",
+    "царю",
+    "царя",
+    "цвел",
+    "цвела",
+    "цвели",
+    "цвело",
+    "цвет",
+    "цвета",
+    "цветам",
+    "цветами",
+    "цветах",
+    "цвете",
+    "цветенья",
+    "цветет",
+    "цветешь",
+    "цветка",
+    "цветками",
+    "цветки",
+    "цветком",
+    "цветная",
+    "цветник",
+    "цветникам",
+    "цветники",
+    "цветной",
+    "цветном",
+    "цветным",
+    "цветных",
+    "цветов",
+    "цветок",
+    "цветочками",
+    "цветочки",
+    "цветочков",
+    "цвету",
+    "цветут",
+    "цветущая",
+    "цветущие",
+    "цветы",
+    "цезаря",
+    "цел",
+    "целая",
+    "целебным",
+    "целей",
+    "цели",
+    "целию",
+    "целковому",
+    "целковый",
+    "целковыми",
+    "целковых",
+    "целовал",
+    "целовала",
+    "целовали",
+    "целовало",
+    "целовался",
+    "целовании",
+    "целовать",
+    "целоваться",
+    "целого",
+    "целое",
+    "целой",
+    "целом",
+    "целому",
+    "целомудрен",
+    "целомудренна",
+    "целомудренно",
+    "целомудренность",
+    "целомудренные",
+    "целомудрие",
+    "целомудрием",
+    "целомудрии",
+    "целостности",
+    "целость",
+    "целою",
+    "целу",
+    "целует",
+    "целуется",
+    "целуешь",
+    "целую",
+    "целуют",
+    "целуются",
+    "целующуюся",
+    "целуя",
+    "целы",
+    "целые",
+    "целый",
+    "целым",
+    "целыми",
+    "целых",
+    "цель",
+    "цельная",
+    "цельного",
+    "цельный",
+    "целью",
+    "целям",
+    "целями",
+    "цена",
+    "цене",
+    "цени",
+    "ценил",
+    "ценим",
+    "ценит",
+    "цените",
+    "ценить",
+    "ценишь",
+    "ценными",
+    "ценою",
+    "центр",
+    "центра",
+    "центров",
+    "цену",
+    "цены",
+    "ценю",
+    "ценя",
+    "ценят",
+    "цепей",
+    "цепенея",
+    "цепи",
+    "цепкими",
+    "цеплялся",
+    "цепной",
+    "цепочка",
+    "цепочками",
+    "цепочках",
+    "цепочке",
+    "цепочки",
+    "цепочку",
+    "цепь",
+    "цепями",
+    "церемонией",
+    "церемонии",
+    "церемонится",
+    "церемониться",
+    "церемонно",
+    "церемонятся",
+    "церквах",
+    "церквей",
+    "церкви",
+    "церковной",
+    "церковные",
+    "церковь",
+    "церковью",
+    "цеховое",
+    "цивилизованной",
+    "цикорию",
+    "цикория",
+    "цилиндра",
+    "цилиндре",
+    "циммермана",
+    "циммермановская",
+    "цинизм",
+    "цинизме",
+    "циник",
+    "циников",
+    "циником",
+    "циническим",
+    "циническое",
+    "цинично",
+    "циничный",
+    "цирках",
+    "циркуляция",
+    "цирфа",
+    "цирюльник",
+    "цитат",
+    "цитуется",
+    "цифр",
+    "цифрах",
+    "цифру",
+    "цифры",
+    "цицикар",
+    "цоканья",
+    "цссс",
+    "цугундер",
+    "цуниги",
+    "цусимой",
+    "цыганка",
+    "цыганкой",
+    "цыганская",
+    "цыганские",
+    "цыганских",
+    "цыганского",
+    "цыганской",
+    "цып",
+    "цыпленка",
+    "цыпленок",
+    "цыплят",
+    "цыплята",
+    "цыплятами",
+    "цыплятах",
+    "цыпочка",
+    "цыпочках",
+    "цыпочки",
+    "ча",
+    "чад",
+    "чада",
+    "чадила",
+    "чадин",
+    "чадры",
+    "чаду",
+    "чае",
+    "чаем",
+    "чаи",
+    "чаишко",
+    "чай",
+    "чайка",
+    "чайки",
+    "чайкой",
+    "чайку",
+    "чайная",
+    "чайник",
+    "чайнике",
+    "чайников",
+    "чайницы",
+    "чайного",
+    "чайное",
+    "чайной",
+    "чайном",
+    "чайным",
+    "чалма",
+    "чалме",
+    "чар",
+    "чародейную",
+    "чародею",
+    "чарой",
+    "чару",
+    "чарует",
+    "чарым",
+    "час",
+    "часа",
+    "часам",
+    "часами",
+    "часах",
+    "часик",
+    "часика",
+    "часов",
+    "часовая",
+    "часовенка",
+    "часовни",
+    "часовню",
+    "часовня",
+    "часовой",
+    "часок",
+    "часом",
+    "частенько",
+    "части",
+    "частию",
+    "частного",
+    "частной",
+    "частном",
+    "частному",
+    "частности",
+    "частную",
+    "частные",
+    "частный",
+    "частных",
+    "часто",
+    "частые",
+    "частым",
+    "частыми",
+    "часть",
+    "частью",
+    "частям",
+    "частях",
+    "часу",
+    "часы",
+    "чахнет",
+    "чахнут",
+    "чахнуть",
+    "чахотка",
+    "чахотке",
+    "чахотки",
+    "чахоткой",
+    "чахотку",
+    "чахоточная",
+    "чахоточное",
+    "чахоточной",
+    "чахоточном",
+    "чахоточному",
+    "чахоточную",
+    "чахоточный",
+    "чаша",
+    "чашей",
+    "чашек",
+    "чашечку",
+    "чаши",
+    "чашка",
+    "чашками",
+    "чашки",
+    "чашкой",
+    "чашку",
+    "чашу",
+    "чащ",
+    "чаще",
+    "чащу",
+    "чаю",
+    "чая",
+    "чаями",
+    "че",
+    "чебаров",
+    "чебарова",
+    "чебарову",
+    "чебаровым",
+    "чебутыкин",
+    "чебутыкина",
+    "чебутыкину",
+    "чебутыкины",
+    "чебутыкиным",
+    "чего",
+    "чей",
+    "чекменев",
+    "челе",
+    "челках",
+    "челки",
+    "челн",
+    "челноке",
+    "чело",
+    "человек",
+    "человека",
+    "человекам",
+    "человеками",
+    "человеке",
+    "человеком",
+    "человеку",
+    "человечек",
+    "человеческая",
+    "человечески",
+    "человеческие",
+    "человеческий",
+    "человеческим",
+    "человеческих",
+    "человеческого",
+    "человеческое",
+    "человеческой",
+    "человеческому",
+    "человеческую",
+    "человечества",
+    "человечество",
+    "человечеству",
+    "человечка",
+    "человечности",
+    "человечность",
+    "человечьем",
+    "челом",
+    "чем",
+    "чемодан",
+    "чемодана",
+    "чемоданов",
+    "чемоданом",
+    "чемоданы",
+    "чему",
+    "чепец",
+    "чепраках",
+    "чепуха",
+    "чепухою",
+    "чепуху",
+    "чепца",
+    "чепце",
+    "чепцов",
+    "чепцом",
+    "чепцу",
+    "чепцы",
+    "чепчик",
+    "чепчика",
+    "чепчике",
+    "чепчиком",
+    "чер",
+    "червовый",
+    "червонно",
+    "червонного",
+    "червь",
+    "червяки",
+    "чердак",
+    "чердаке",
+    "черед",
+    "чередовалась",
+    "чередой",
+    "через",
+    "черемухи",
+    "черемша",
+    "черемши",
+    "черен",
+    "черенками",
+    "череп",
+    "черепа",
+    "черепаха",
+    "черепка",
+    "черепки",
+    "черепок",
+    "черепом",
+    "черепу",
+    "чересчур",
+    "черна",
+    "черная",
+    "чернее",
+    "чернеет",
+    "чернеется",
+    "чернейшей",
+    "чернелись",
+    "чернелось",
+    "черненький",
+    "чернеют",
+    "черни",
+    "чернил",
+    "чернила",
+    "чернилах",
+    "чернильница",
+    "чернильнице",
+    "чернильницей",
+    "чернильницу",
+    "чернильницы",
+    "черно",
+    "черноброва",
+    "черноволосый",
+    "черноглаза",
+    "черного",
+    "черное",
+    "черноземным",
+    "черной",
+    "черном",
+    "черному",
+    "черноокая",
+    "чернорабочий",
+    "чернота",
+    "черноте",
+    "черноту",
+    "черною",
+    "черную",
+    "черные",
+    "черный",
+    "черным",
+    "черными",
+    "черных",
+    "чернь",
+    "черпай",
+    "черпают",
+    "черпнуть",
+    "черство",
+    "черствый",
+    "черствым",
+    "черт",
+    "черта",
+    "чертам",
+    "чертами",
+    "чертах",
+    "черте",
+    "чертежа",
+    "чертеже",
+    "чертежи",
+    "чертей",
+    "черти",
+    "чертиков",
+    "чертил",
+    "чертила",
+    "чертит",
+    "чертить",
+    "чертовски",
+    "чертог",
+    "чертой",
+    "черточек",
+    "черточка",
+    "черточками",
+    "черточку",
+    "чертою",
+    "черту",
+    "черты",
+    "чертя",
+    "чесал",
+    "чесался",
+    "чесать",
+    "чеснока",
+    "чествует",
+    "честей",
+    "честен",
+    "чести",
+    "честит",
+    "честна",
+    "честная",
+    "честнее",
+    "честнейшая",
+    "честнейшее",
+    "честнейшие",
+    "честно",
+    "честного",
+    "честное",
+    "честной",
+    "честности",
+    "честность",
+    "честную",
+    "честны",
+    "честные",
+    "честный",
+    "честным",
+    "честными",
+    "честных",
+    "честолюбие",
+    "честчым",
+    "честь",
+    "честью",
+    "чет",
+    "чета",
+    "четверг",
+    "четвергам",
+    "четвергу",
+    "четверо",
+    "четвертаки",
+    "четвертая",
+    "четвертей",
+    "четверти",
+    "четвертого",
+    "четвертое",
+    "четвертой",
+    "четвертом",
+    "четвертую",
+    "четвертый",
+    "четвертым",
+    "четвертых",
+    "четверть",
+    "четверым",
+    "четко",
+    "четкости",
+    "четыре",
+    "четырем",
+    "четыреста",
+    "четырех",
+    "четырехугольника",
+    "четырехэтажного",
+    "четырехэтажный",
+    "четырнадцати",
+    "четырнадцатилетний",
+    "четырнадцатилетнюю",
+    "четырнадцать",
+    "чехартма",
+    "чехартмы",
+    "чехла",
+    "чехлами",
+    "чехов",
+    "чеченская",
+    "чешет",
+    "чешется",
+    "чешутся",
+    "чешую",
+    "чи",
+    "чижами",
+    "чижи",
+    "чик",
+    "чиканием",
+    "чин",
+    "чина",
+    "чинах",
+    "чинила",
+    "чинит",
+    "чинить",
+    "чиннее",
+    "чинно",
+    "чинной",
+    "чинною",
+    "чинные",
+    "чинный",
+    "чинных",
+    "чинов",
+    "чиновник",
+    "чиновника",
+    "чиновниками",
+    "чиновники",
+    "чиновников",
+    "чиновником",
+    "чиновнику",
+    "чиновница",
+    "чиновницу",
+    "чиновницы",
+    "чиновничеством",
+    "чиновничье",
+    "чиновничьи",
+    "чиновного",
+    "чиновной",
+    "чиновный",
+    "чиновных",
+    "чином",
+    "чину",
+    "чины",
+    "чирикала",
+    "чирикали",
+    "чисел",
+    "числа",
+    "числе",
+    "численно",
+    "число",
+    "числом",
+    "числю",
+    "числюсь",
+    "чист",
+    "чиста",
+    "чистая",
+    "чистейшего",
+    "чистейший",
+    "чистенькие",
+    "чистенький",
+    "чистеньким",
+    "чистил",
+    "чистит",
+    "чистить",
+    "чистишь",
+    "чистки",
+    "чисто",
+    "чистого",
+    "чистое",
+    "чистой",
+    "чистом",
+    "чистому",
+    "чистоплотен",
+    "чистоплотно",
+    "чистосердечно",
+    "чистосердечное",
+    "чистота",
+    "чистоте",
+    "чистотой",
+    "чистоту",
+    "чистоты",
+    "чистою",
+    "чистую",
+    "чисты",
+    "чистые",
+    "чистый",
+    "чистым",
+    "чистыми",
+    "чистых",
+    "чисть",
+    "чистюлька",
+    "чит",
+    "читаем",
+    "читает",
+    "читаете",
+    "читаешь",
+    "читай",
+    "читайте",
+    "читал",
+    "читала",
+    "читалась",
+    "читали",
+    "читалось",
+    "читаль",
+    "читальни",
+    "читальню",
+    "читателем",
+    "читатель",
+    "читателю",
+    "читателя",
+    "читать",
+    "читаю",
+    "читают",
+    "читая",
+    "читка",
+    "читу",
+    "чиханье",
+    "чихать",
+    "чихнул",
+    "чихнуть",
+    "чище",
+    "чищены",
+    "чищенье",
+    "чищенья",
+    "чищу",
+    "член",
+    "члена",
+    "членам",
+    "членах",
+    "членом",
+    "члену",
+    "члены",
+    "чмокаться",
+    "чокается",
+    "чопорно",
+    "чопорного",
+    "чопорный",
+    "чорт",
+    "чортом",
+    "чревовещательница",
+    "чреду",
+    "чрез",
+    "чрезвычайная",
+    "чрезвычайно",
+    "чрезвычайного",
+    "чрезвычайное",
+    "чрезвычайной",
+    "чрезвычайном",
+    "чрезвычайною",
+    "чрезвычайный",
+    "чрезвычайным",
+    "чрезмерно",
+    "чрезмерный",
+    "чт",
+    "чтение",
+    "чтением",
+    "чтении",
+    "чтений",
+    "чтения",
+    "чтимый",
+    "чтит",
+    "чтить",
+    "что",
+    "чтоб",
+    "чтобы",
+    "чтой",
+    "чту",
+    "чу",
+    "чубуки",
+    "чубы",
+    "чув",
+    "чувств",
+    "чувства",
+    "чувствам",
+    "чувствами",
+    "чувствах",
+    "чувстве",
+    "чувствительна",
+    "чувствительно",
+    "чувствительного",
+    "чувствительное",
+    "чувствительной",
+    "чувствительном",
+    "чувствительные",
+    "чувствительный",
+    "чувствительными",
+    "чувство",
+    "чувствовал",
+    "чувствовала",
+    "чувствовали",
+    "чувствовать",
+    "чувством",
+    "чувству",
+    "чувствуем",
+    "чувствует",
+    "чувствуете",
+    "чувствуется",
+    "чувствуешь",
+    "чувствую",
+    "чувствуют",
+    "чувствующий",
+    "чувствуя",
+    "чугун",
+    "чугуна",
+    "чугуне",
+    "чугунке",
+    "чугунное",
+    "чугунной",
+    "чугунный",
+    "чугунными",
+    "чуда",
+    "чудак",
+    "чудака",
+    "чудаками",
+    "чудаки",
+    "чудаком",
+    "чудачество",
+    "чудачка",
+    "чуде",
+    "чуден",
+    "чудес",
+    "чудеса",
+    "чудесен",
+    "чудесная",
+    "чудеснее",
+    "чудеснейший",
+    "чудесно",
+    "чудесного",
+    "чудесное",
+    "чудесные",
+    "чудесный",
+    "чудесным",
+    "чудесных",
+    "чудится",
+    "чудище",
+    "чудищу",
+    "чудная",
+    "чудно",
+    "чудной",
+    "чудном",
+    "чудные",
+    "чудный",
+    "чудным",
+    "чудо",
+    "чудовищ",
+    "чудовища",
+    "чудовищам",
+    "чудовищами",
+    "чудовище",
+    "чудовищная",
+    "чудовищное",
+    "чудовищной",
+    "чудовищности",
+    "чудовищные",
+    "чудовищный",
+    "чудом",
+    "чуду",
+    "чудь",
+    "чует",
+    "чуешь",
+    "чужая",
+    "чужд",
+    "чужда",
+    "чуждается",
+    "чуждался",
+    "чуждо",
+    "чуждом",
+    "чужды",
+    "чуждые",
+    "чуждый",
+    "чужие",
+    "чужим",
+    "чужими",
+    "чужих",
+    "чужого",
+    "чужое",
+    "чужой",
+    "чужом",
+    "чужому",
+    "чужую",
+    "чулана",
+    "чулане",
+    "чуланы",
+    "чулка",
+    "чулкам",
+    "чулках",
+    "чулки",
+    "чулков",
+    "чулок",
+    "чулочки",
+    "чулочную",
+    "чуме",
+    "чумичка",
+    "чумички",
+    "чурбан",
+    "чутки",
+    "чуткие",
+    "чуткий",
+    "чуткими",
+    "чутко",
+    "чуткость",
+    "чутошном",
+    "чуть",
+    "чухонец",
+    "чухонка",
+    "чухонки",
+    "чухонца",
+    "чухонцы",
+    "чучелами",
+    "чучело",
+    "чушь",
+    "чуют",
+    "чуя",
+    "чуять",
+    "чье",
+    "чьего",
+    "чьей",
+    "чьи",
+    "чьим",
+    "чьими",
+    "чьих",
+    "чья",
+    "шабаш",
+    "шаг",
+    "шага",
+    "шагает",
+    "шагал",
+    "шагала",
+    "шагали",
+    "шагами",
+    "шагания",
+    "шагах",
+    "шагают",
+    "шагающая",
+    "шагая",
+    "шаге",
+    "шаги",
+    "шагни",
+    "шагнул",
+    "шагнула",
+    "шагнуть",
+    "шагов",
+    "шагом",
+    "шагу",
+    "шажищами",
+    "шайка",
+    "шайку",
+    "шакалы",
+    "шали",
+    "шалил",
+    "шалит",
+    "шалить",
+    "шаловлив",
+    "шаловливость",
+    "шаловливый",
+    "шалости",
+    "шалун",
+    "шалунья",
+    "шалый",
+    "шаль",
+    "шалью",
+    "шаля",
+    "шалят",
+    "шаман",
+    "шампанским",
+    "шампанского",
+    "шампанское",
+    "шампанском",
+    "шамраев",
+    "шамраева",
+    "шамраеву",
+    "шамшевки",
+    "шансы",
+    "шапка",
+    "шапке",
+    "шапки",
+    "шапку",
+    "шапочка",
+    "шапочки",
+    "шар",
+    "шара",
+    "шарах",
+    "шаре",
+    "шарил",
+    "шарить",
+    "шаркнула",
+    "шаркнуло",
+    "шарлатанства",
+    "шарлотта",
+    "шарлотте",
+    "шарлотту",
+    "шарманка",
+    "шарманки",
+    "шарманкой",
+    "шарманку",
+    "шарманщик",
+    "шарманщика",
+    "шарманщики",
+    "шарманщику",
+    "шармера",
+    "шаро",
+    "шаров",
+    "шароварах",
+    "шаром",
+    "шарфик",
+    "шаршавого",
+    "шары",
+    "шаря",
+    "шатается",
+    "шатаешься",
+    "шатайся",
+    "шаталось",
+    "шатался",
+    "шатанья",
+    "шататься",
+    "шатающимся",
+    "шатающихся",
+    "шатаясь",
+    "шатер",
+    "шатка",
+    "шаткими",
+    "шафером",
+    "шахматово",
+    "шахтах",
+    "швам",
+    "швах",
+    "швейцар",
+    "швейцарии",
+    "швейцарию",
+    "швейцарского",
+    "швейцарском",
+    "швейцары",
+    "швыряет",
+    "швыряла",
+    "швыряя",
+    "ше",
+    "шевели",
+    "шевелил",
+    "шевелилась",
+    "шевелилось",
+    "шевелился",
+    "шевелись",
+    "шевелит",
+    "шевелить",
+    "шевелиться",
+    "шевелишься",
+    "шевельнет",
+    "шевельнувшись",
+    "шевельнулась",
+    "шевельнулось",
+    "шевельнулся",
+    "шевельнуться",
+    "шевеля",
+    "шевелясь",
+    "шее",
+    "шеей",
+    "шеи",
+    "шейного",
+    "шекспир",
+    "шекспира",
+    "шел",
+    "шелест",
+    "шелестит",
+    "шелесты",
+    "шелестят",
+    "шелк",
+    "шелка",
+    "шелками",
+    "шелковистые",
+    "шелковое",
+    "шелковом",
+    "шелковою",
+    "шелковые",
+    "шелковый",
+    "шелковым",
+    "шелковых",
+    "шелком",
+    "шеловек",
+    "шеломами",
+    "шелопаева",
+    "шелохнутся",
+    "шельма",
+    "шельмой",
+    "шельму",
+    "шепнет",
+    "шепнешь",
+    "шепнул",
+    "шепнула",
+    "шепот",
+    "шепота",
+    "шепотливые",
+    "шепотом",
+    "шепоту",
+    "шептал",
+    "шептала",
+    "шепталась",
+    "шептались",
+    "шептаний",
+    "шептать",
+    "шепча",
+    "шепчем",
+    "шепчет",
+    "шепчут",
+    "шепчутся",
+    "шепчущие",
+    "шерстку",
+    "шерсть",
+    "шерстяное",
+    "шерстяной",
+    "шерстяном",
+    "шерстяную",
+    "шест",
+    "шестая",
+    "шествие",
+    "шестеро",
+    "шести",
+    "шестидесяти",
+    "шестнадцати",
+    "шестнадцатилетнего",
+    "шестнадцатилетний",
+    "шестнадцать",
+    "шестого",
+    "шестое",
+    "шестой",
+    "шестом",
+    "шестому",
+    "шесть",
+    "шестьдесят",
+    "шестьсот",
+    "шею",
+    "шея",
+    "ши",
+    "шиканье",
+    "шила",
+    "шиллер",
+    "шиллера",
+    "шиллеровских",
+    "шило",
+    "шилось",
+    "шиля",
+    "шинели",
+    "шинель",
+    "шипевших",
+    "шипел",
+    "шипенье",
+    "шипит",
+    "шипка",
+    "шиповник",
+    "шипы",
+    "шипящую",
+    "шире",
+    "шириной",
+    "шириною",
+    "ширину",
+    "ширины",
+    "ширится",
+    "ширм",
+    "ширмами",
+    "ширмой",
+    "ширму",
+    "ширмы",
+    "широк",
+    "широка",
+    "широкая",
+    "широкие",
+    "широкий",
+    "широким",
+    "широкими",
+    "широких",
+    "широко",
+    "широкого",
+    "широкое",
+    "широкой",
+    "широком",
+    "широкостью",
+    "широкою",
+    "широкую",
+    "ширь",
+    "ширятся",
+    "шитом",
+    "шитую",
+    "шитые",
+    "шить",
+    "шитье",
+    "шитьем",
+    "шитья",
+    "шиш",
+    "шиша",
+    "шишка",
+    "шкалик",
+    "шкандаль",
+    "шкапа",
+    "шкапом",
+    "шкапу",
+    "шкатулка",
+    "шкатулке",
+    "шкатулку",
+    "шкаф",
+    "шкафа",
+    "шкафах",
+    "шкафик",
+    "шкафом",
+    "шкафу",
+    "шкафчика",
+    "шкафы",
+    "школа",
+    "школах",
+    "школе",
+    "школу",
+    "школы",
+    "школьник",
+    "школьника",
+    "шкуре",
+    "шла",
+    "шлафрок",
+    "шлафрока",
+    "шлезвиг",
+    "шлеи",
+    "шлейф",
+    "шлем",
+    "шлепохвосткам",
+    "шлепохвостниц",
+    "шлет",
+    "шлешь",
+    "шли",
+    "шло",
+    "шлю",
+    "шлют",
+    "шляешься",
+    "шлялись",
+    "шлялся",
+    "шляпа",
+    "шляпах",
+    "шляпе",
+    "шляпка",
+    "шляпке",
+    "шляпки",
+    "шляпку",
+    "шляпник",
+    "шляпой",
+    "шляпу",
+    "шляпы",
+    "шляркой",
+    "шляться",
+    "шмель",
+    "шмыгали",
+    "шмыгать",
+    "шмыгнул",
+    "шнурка",
+    "шнурочек",
+    "шныряли",
+    "шныряю",
+    "шопенгауэр",
+    "шопот",
+    "шопота",
+    "шопоте",
+    "шопотом",
+    "шоркает",
+    "шоркнуло",
+    "шорох",
+    "шоссе",
+    "шоссейная",
+    "шоссейный",
+    "шотландский",
+    "шотландском",
+    "шпаги",
+    "шпал",
+    "шпиле",
+    "шпилек",
+    "шпильки",
+    "шпор",
+    "шпорою",
+    "шпоры",
+    "шпынял",
+    "шта",
+    "штаб",
+    "штабных",
+    "штабс",
+    "штанами",
+    "штаны",
+    "штат",
+    "штатам",
+    "штатах",
+    "штатские",
+    "штатский",
+    "штатскими",
+    "штатских",
+    "штатского",
+    "штатское",
+    "штату",
+    "штейн",
+    "штиблетах",
+    "што",
+    "штольне",
+    "штольц",
+    "штольца",
+    "штольце",
+    "штольцев",
+    "штольцем",
+    "штольцу",
+    "штопать",
+    "штор",
+    "штора",
+    "шторами",
+    "штору",
+    "шторы",
+    "штоф",
+    "штофа",
+    "штраф",
+    "штрих",
+    "штрихом",
+    "штрюком",
+    "штук",
+    "штука",
+    "штукатурка",
+    "штуки",
+    "штукой",
+    "штуку",
+    "штучка",
+    "штучки",
+    "штучку",
+    "штык",
+    "штыки",
+    "штыком",
+    "шуб",
+    "шубе",
+    "шубенке",
+    "шуберта",
+    "шубка",
+    "шубки",
+    "шубкой",
+    "шубу",
+    "шубы",
+    "шулер",
+    "шулерами",
+    "шулером",
+    "шум",
+    "шума",
+    "шумана",
+    "шуме",
+    "шумел",
+    "шуметь",
+    "шуми",
+    "шумиловой",
+    "шумиловское",
+    "шумит",
+    "шумна",
+    "шумней",
+    "шумно",
+    "шумного",
+    "шумной",
+    "шумный",
+    "шумных",
+    "шумом",
+    "шуму",
+    "шумят",
+    "шумящих",
+    "шурин",
+    "шурша",
+    "шуршит",
+    "шут",
+    "шути",
+    "шутил",
+    "шутила",
+    "шутили",
+    "шутим",
+    "шутит",
+    "шутите",
+    "шутить",
+    "шутишь",
+    "шутка",
+    "шутками",
+    "шутке",
+    "шутки",
+    "шуткой",
+    "шутку",
+    "шутливо",
+    "шутник",
+    "шутовский",
+    "шутовского",
+    "шуточки",
+    "шуточное",
+    "шуты",
+    "шутя",
+    "шутят",
+    "шучу",
+    "шушуканье",
+    "шхун",
+    "шьет",
+    "шьют",
+    "щадил",
+    "щадила",
+    "щадит",
+    "щадите",
+    "щадя",
+    "ще",
+    "щебет",
+    "щебечут",
+    "щеголевато",
+    "щеголиха",
+    "щегольская",
+    "щегольски",
+    "щегольские",
+    "щегольский",
+    "щегольских",
+    "щегольское",
+    "щегольской",
+    "щегольском",
+    "щеголял",
+    "щеголять",
+    "щедр",
+    "щедрина",
+    "щедрость",
+    "щедрот",
+    "щей",
+    "щек",
+    "щекам",
+    "щеками",
+    "щеках",
+    "щеке",
+    "щеки",
+    "щекотал",
+    "щекотала",
+    "щекотлив",
+    "щекотливенькая",
+    "щекотливо",
+    "щекотливое",
+    "щекотливость",
+    "щеку",
+    "щелей",
+    "щели",
+    "щелкает",
+    "щелканье",
+    "щелканья",
+    "щелкать",
+    "щелкают",
+    "щелкнул",
+    "щелкнуть",
+    "щелку",
+    "щелочка",
+    "щелочке",
+    "щелочки",
+    "щелочку",
+    "щелчки",
+    "щель",
+    "щемящей",
+    "щемящему",
+    "щемящие",
+    "щенка",
+    "щенке",
+    "щенком",
+    "щенок",
+    "щепоточку",
+    "щербатый",
+    "щетина",
+    "щетинистыми",
+    "щетину",
+    "щетками",
+    "щетки",
+    "щеткой",
+    "щетку",
+    "щечкам",
+    "щечке",
+    "щечки",
+    "щи",
+    "щина",
+    "щипал",
+    "щипала",
+    "щиплет",
+    "щипцами",
+    "щит",
+    "щите",
+    "щитом",
+    "щуки",
+    "щупать",
+    "щупленькая",
+    "щурят",
+    "ы",
+    "э",
+    "эа",
+    "эва",
+    "эвона",
+    "эвося",
+    "эге",
+    "эгоизм",
+    "эгоизма",
+    "эгоизме",
+    "эгоист",
+    "эгоистка",
+    "эгоистки",
+    "эгоистом",
+    "эгофутуризм",
+    "эдак",
+    "эдакая",
+    "эдгар",
+    "эдгара",
+    "эй",
+    "эйфелевой",
+    "эк",
+    "эка",
+    "экая",
+    "экзамен",
+    "экзаменовать",
+    "экзекутор",
+    "экземпляра",
+    "экземплярах",
+    "экземпляру",
+    "экзотический",
+    "экие",
+    "экий",
+    "экипаж",
+    "экипажа",
+    "экипажах",
+    "экипаже",
+    "экипажи",
+    "эко",
+    "экое",
+    "экой",
+    "экономией",
+    "экономии",
+    "экономическая",
+    "экономические",
+    "экономическим",
+    "экономических",
+    "экономической",
+    "экономию",
+    "экономия",
+    "экономка",
+    "экономки",
+    "экономку",
+    "экс",
+    "экспансивности",
+    "экспансивностями",
+    "экспедицию",
+    "экстраординарная",
+    "эксцентричен",
+    "эксцентрическая",
+    "эксцентрический",
+    "эксцентрических",
+    "эксцентрическом",
+    "эксцентричнее",
+    "элегий",
+    "электрическую",
+    "электричества",
+    "электронная",
+    "электронной",
+    "электронный",
+    "элементами",
+    "элементом",
+    "элементы",
+    "эмалью",
+    "эмансипации",
+    "эмансипацию",
+    "эмблема",
+    "эмеритуру",
+    "эмс",
+    "энергией",
+    "энергии",
+    "энергически",
+    "энергический",
+    "энергическим",
+    "энергично",
+    "энергия",
+    "энтузиазм",
+    "энтузиазма",
+    "энтузиазмом",
+    "энциклопедическом",
+    "эпи",
+    "эпиграфом",
+    "эпидемию",
+    "эпизод",
+    "эпизодическим",
+    "эпизодическое",
+    "эпизодов",
+    "эпикуреец",
+    "эпилог",
+    "эпилога",
+    "эпилоге",
+    "эпилогом",
+    "эпитафия",
+    "эпитет",
+    "эполеты",
+    "эпопеи",
+    "эпох",
+    "эпоха",
+    "эпохам",
+    "эпохи",
+    "эпоху",
+    "эрара",
+    "эрлангене",
+    "эрмитаж",
+    "эру",
+    "эры",
+    "эскамильо",
+    "эско",
+    "эспаньолкой",
+    "эссенция",
+    "эстет",
+    "эстетика",
+    "эстетике",
+    "эстетики",
+    "эстетическая",
+    "эстетически",
+    "эстетическое",
+    "эстраде",
+    "эстрадой",
+    "эстраду",
+    "эстрады",
+    "эта",
+    "этаж",
+    "этажа",
+    "этажах",
+    "этаже",
+    "этажерками",
+    "этажерках",
+    "этажерке",
+    "этажерки",
+    "этажерку",
+    "этажи",
+    "этажом",
+    "этажу",
+    "этак",
+    "этака",
+    "этакая",
+    "этакие",
+    "этакий",
+    "этаким",
+    "этакими",
+    "этаких",
+    "этакого",
+    "этакое",
+    "этакой",
+    "этаком",
+    "этакому",
+    "этаку",
+    "этакую",
+    "эти",
+    "этим",
+    "этими",
+    "этих",
+    "это",
+    "этого",
+    "этой",
+    "этом",
+    "этому",
+    "этот",
+    "этою",
+    "этта",
+    "эту",
+    "этюдов",
+    "эфемерная",
+    "эфемерное",
+    "эфес",
+    "эфир",
+    "эфирной",
+    "эфиром",
+    "эффект",
+    "эффекта",
+    "эх",
+    "эхо",
+    "эшафот",
+    "эшафоте",
+    "эшафоту",
+    "эшелон",
+    "ю",
+    "юбилей",
+    "юбка",
+    "юбке",
+    "юбки",
+    "юбкой",
+    "юбку",
+    "ювелира",
+    "ювелирская",
+    "ювелирский",
+    "ювенал",
+    "юг",
+    "юга",
+    "юдоли",
+    "южной",
+    "южный",
+    "южных",
+    "юла",
+    "юлишь",
+    "юмора",
+    "юная",
+    "юнкер",
+    "юного",
+    "юной",
+    "юности",
+    "юность",
+    "юностью",
+    "юноша",
+    "юношам",
+    "юношами",
+    "юношей",
+    "юношеские",
+    "юношеский",
+    "юношескою",
+    "юношественные",
+    "юношеством",
+    "юноши",
+    "юношу",
+    "юную",
+    "юный",
+    "юным",
+    "юных",
+    "юпитер",
+    "юридистике",
+    "юридические",
+    "юридический",
+    "юридическими",
+    "юридического",
+    "юридическое",
+    "юридической",
+    "юридическом",
+    "юридическому",
+    "юриспруденции",
+    "юристов",
+    "юристом",
+    "юристы",
+    "юрию",
+    "юркнула",
+    "юродивая",
+    "юродивой",
+    "юродивые",
+    "юродивым",
+    "юрты",
+    "юсупов",
+    "юсупова",
+    "юсуповом",
+    "юшин",
+    "ющинского",
+    "я",
+    "яблок",
+    "яблокам",
+    "яблоками",
+    "яблоки",
+    "яблоко",
+    "яблоку",
+    "яблони",
+    "яблонь",
+    "явившийся",
+    "явившись",
+    "явилась",
+    "явились",
+    "явилось",
+    "явился",
+    "явись",
+    "явитесь",
+    "явится",
+    "явиться",
+    "явка",
+    "явки",
+    "явку",
+    "явление",
+    "явлением",
+    "явлений",
+    "явления",
+    "явлениями",
+    "явлениях",
+    "явленьем",
+    "явлюсь",
+    "является",
+    "являйся",
+    "являлась",
+    "являлись",
+    "являлось",
+    "являлся",
+    "являться",
+    "являются",
+    "являющаяся",
+    "являясь",
+    "явная",
+    "явно",
+    "явное",
+    "явною",
+    "явный",
+    "явным",
+    "явственнее",
+    "явственно",
+    "явь",
+    "явятся",
+    "ягод",
+    "ягоды",
+    "яд",
+    "яде",
+    "ядовитая",
+    "ядовитей",
+    "ядовито",
+    "ядовитое",
+    "ядовитой",
+    "ядовиты",
+    "ядовитый",
+    "ядовитым",
+    "ядовитых",
+    "ядом",
+    "ядра",
+    "ядро",
+    "яду",
+    "язва",
+    "язвами",
+    "язвах",
+    "язве",
+    "язвила",
+    "язвили",
+    "язвите",
+    "язвительно",
+    "язвительного",
+    "язвительное",
+    "язвительность",
+    "язвительный",
+    "язвы",
+    "язык",
+    "языка",
+    "языками",
+    "языке",
+    "языки",
+    "языком",
+    "языку",
+    "яиц",
+    "яичка",
+    "яичницей",
+    "яичницу",
+    "яичными",
+    "яйца",
+    "яйцами",
+    "яйцах",
+    "яйцом",
+    "яко",
+    "якобы",
+    "яков",
+    "якову",
+    "якорей",
+    "якорь",
+    "якоря",
+    "ямайский",
+    "ямайском",
+    "ямах",
+    "ямб",
+    "ямба",
+    "ямбы",
+    "яме",
+    "ямка",
+    "ямку",
+    "ямой",
+    "ямочками",
+    "ямской",
+    "яму",
+    "ямщик",
+    "ямщика",
+    "ямы",
+    "январе",
+    "январь",
+    "января",
+    "янтарная",
+    "янтарной",
+    "янтарь",
+    "ярка",
+    "яркая",
+    "ярки",
+    "яркие",
+    "яркий",
+    "ярким",
+    "ярких",
+    "ярко",
+    "яркого",
+    "яркой",
+    "ярком",
+    "яркость",
+    "яркостью",
+    "яркую",
+    "ярлык",
+    "ярлычки",
+    "ярмарка",
+    "ярмаркам",
+    "ярмарке",
+    "ярмарку",
+    "ярмо",
+    "яровое",
+    "ярок",
+    "ярославль",
+    "ярославская",
+    "ярости",
+    "яростно",
+    "яростное",
+    "ярость",
+    "яростью",
+    "ярче",
+    "ярый",
+    "ясен",
+    "ясеневыми",
+    "ясли",
+    "ясна",
+    "ясная",
+    "яснее",
+    "яснеет",
+    "ясно",
+    "ясного",
+    "ясное",
+    "ясной",
+    "ясном",
+    "ясности",
+    "ясность",
+    "ясную",
+    "ясны",
+    "ясные",
+    "ясный",
+    "ясным",
+    "ясных",
+    "яств",
+    "ястреб",
+    "ястреба",
+    "ят",
+    "яхонтовым",
+    "ячмени",
+    "ячменный",
+    "ячмень",
+    "ячменях",
+    "яша",
+    "яше",
+    "яшей",
+    "яши",
+    "яшнево",
+    "яшу",
+    "ящик",
+    "ящика",
+    "ящикам",
+    "ящике",
+};
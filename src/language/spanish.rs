@@ -0,0 +1,70 @@
+use std::ops::RangeInclusive;
+
+use phf::phf_set;
+
+use super::{contains_lowercase, Language, LanguageId};
+
+mod words;
+
+use words::WORDS;
+
+/// Spanish, behind the `spanish` cargo feature
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Spanish;
+
+impl Language for Spanish {
+    fn id(&self) -> LanguageId {
+        LanguageId::Spanish
+    }
+
+    fn is_letter(&self, c: char) -> bool {
+        c.is_ascii_alphabetic() || "áéíóúüñ".contains(c.to_lowercase().next().unwrap_or(c))
+    }
+
+    fn is_vowel(&self, c: char) -> bool {
+        "aeiouáéíóúü".contains(c)
+    }
+
+    fn is_common_pair(&self, pair: &str) -> bool {
+        COMMON_CHAR_PAIRS.contains(pair)
+    }
+
+    fn is_common_trigram(&self, trigram: &str) -> bool {
+        COMMON_TRIGRAMS.contains(trigram)
+    }
+
+    fn is_common_quadgram(&self, quadgram: &str) -> bool {
+        COMMON_QUADGRAMS.contains(quadgram)
+    }
+
+    fn contains_word(&self, word: &str) -> bool {
+        contains_lowercase(&WORDS, word)
+    }
+
+    fn entropy_range(&self) -> RangeInclusive<f64> {
+        3.5..=4.5
+    }
+}
+
+static COMMON_CHAR_PAIRS: phf::Set<&'static str> = phf_set! {
+    "de", "es", "en", "el", "la", "os", "ue", "ar", "ra", "re",
+    "er", "as", "on", "st", "ad", "al", "or", "ta", "co", "an",
+    "se", "ci", "ie", "nt", "do", "io", "ca", "qu", "te", "to",
+    "ro", "ne", "me", "in", "lo", "di", "li", "ac", "mi", "un",
+    "po", "na", "ia", "su", "ma", "tr", "pa", "sa", "ic", "ón",
+    "añ", "ía",
+};
+
+static COMMON_TRIGRAMS: phf::Set<&'static str> = phf_set! {
+    "que", "ent", "ade", "ión", "los", "las", "del", "est", "aci", "con",
+    "ien", "nte", "res", "par", "ara", "cia", "com", "ado", "sta", "ero",
+    "tra", "ida", "mos", "ada", "pro", "ter", "ues", "nes", "era", "ica",
+    "una", "por", "des", "ndo", "ten", "tos", "ont", "eri", "ant", "ona",
+    "cio", "dad", "dos", "ció", "ela", "ore", "men", "ell", "mie", "año",
+};
+
+static COMMON_QUADGRAMS: phf::Set<&'static str> = phf_set! {
+    "ción", "ente", "para", "ando", "esta", "como", "ados", "idad",
+    "ient", "pero", "obre", "todo", "entr", "nter", "ació", "tien",
+    "reci", "anto", "ismo", "amos", "ment", "iend", "endo", "ones",
+};
@@ -0,0 +1,28048 @@
+use phf::phf_set;
+
+pub static WORDS: phf::Set<&'static str> = phf_set! {
+    "a",
+    "aarón",
+    "abaco",
+    "abajo",
+    "abandera",
+    "abandona",
+    "abandonada",
+    "abandonadas",
+    "abandonado",
+    "abandonados",
+    "abandonamos",
+    "abandonan",
+    "abandonar",
+    "abandonarlo",
+    "abandonaron",
+    "abandono",
+    "abandonó",
+    "abaratar",
+    "abarca",
+    "abarcamos",
+    "abarcan",
+    "abarcar",
+    "abarcará",
+    "abarcarán",
+    "abarcaría",
+    "abarcó",
+    "abaroa",
+    "abarroteros",
+    "abarrotó",
+    "abastece",
+    "abastecedora",
+    "abastecer",
+    "abastecimiento",
+    "abastecimientos",
+    "abasto",
+    "abatares",
+    "abatida",
+    "abatido",
+    "abatidos",
+    "abatimiento",
+    "abatir",
+    "abatirnos",
+    "abatirse",
+    "abatió",
+    "abba",
+    "abbud",
+    "abdicación",
+    "abdicar",
+    "abeba",
+    "abedrop",
+    "abel",
+    "abelardo",
+    "aberración",
+    "aberrante",
+    "abidjan",
+    "abiel",
+    "abierta",
+    "abiertamente",
+    "abiertas",
+    "abierto",
+    "abiertos",
+    "abigeo",
+    "abimael",
+    "abismo",
+    "abióticos",
+    "ablandar",
+    "ablandó",
+    "abluciones",
+    "abnegación",
+    "aboagay",
+    "abocó",
+    "abogada",
+    "abogadas",
+    "abogadete",
+    "abogadillo",
+    "abogado",
+    "abogados",
+    "abolición",
+    "abolirá",
+    "abominan",
+    "abono",
+    "abonos",
+    "aborda",
+    "abordaban",
+    "abordada",
+    "abordadas",
+    "abordan",
+    "abordando",
+    "abordar",
+    "abordaron",
+    "abordará",
+    "abordarán",
+    "aborde",
+    "abordo",
+    "abordó",
+    "abortados",
+    "abortiva",
+    "aborto",
+    "abortos",
+    "abra",
+    "abraham",
+    "abrasivo",
+    "abrazarlo",
+    "abrazarse",
+    "abrazo",
+    "abre",
+    "abrego",
+    "abren",
+    "abreu",
+    "abreviar",
+    "abridor",
+    "abriendo",
+    "abrieron",
+    "abrigan",
+    "abrigo",
+    "abrigos",
+    "abril",
+    "abriles",
+    "abrio",
+    "abrir",
+    "abrira",
+    "abrirla",
+    "abrirlos",
+    "abrirnos",
+    "abrirse",
+    "abrirá",
+    "abrirán",
+    "abriría",
+    "abrió",
+    "abrogación",
+    "abrogados",
+    "abrogar",
+    "abrupto",
+    "abrían",
+    "abs",
+    "absoletas",
+    "absoluta",
+    "absolutamente",
+    "absoluto",
+    "absolutoria",
+    "absolvió",
+    "absorba",
+    "absorber",
+    "absorbiendo",
+    "absorver",
+    "abstencionismo",
+    "abstendrá",
+    "abstenga",
+    "abstengan",
+    "abstenido",
+    "abstracción",
+    "abstracta",
+    "abstractos",
+    "abstuviera",
+    "absuelto",
+    "absurdas",
+    "absurdo",
+    "abucheado",
+    "abuchear",
+    "abuchearon",
+    "abucheo",
+    "abucheos",
+    "abuela",
+    "abuelas",
+    "abuelita",
+    "abuelitas",
+    "abuelito",
+    "abuelitos",
+    "abuelo",
+    "abuelos",
+    "abultar",
+    "abunda",
+    "abundan",
+    "abundancia",
+    "abundante",
+    "abundantes",
+    "abundar",
+    "abundaron",
+    "abundó",
+    "abur",
+    "aburridas",
+    "abuso",
+    "abusos",
+    "acaba",
+    "acababa",
+    "acabado",
+    "acabados",
+    "acabamos",
+    "acaban",
+    "acabando",
+    "acabar",
+    "acabaron",
+    "acabará",
+    "acabaría",
+    "acabe",
+    "acabo",
+    "acabó",
+    "academia",
+    "academik",
+    "academy",
+    "académica",
+    "académicamente",
+    "académicas",
+    "académico",
+    "académicos",
+    "acaeció",
+    "acallando",
+    "acallar",
+    "acampar",
+    "acapara",
+    "acaparado",
+    "acaparadores",
+    "acaparar",
+    "acaparó",
+    "acapulco",
+    "acariciarte",
+    "acarreado",
+    "acarreadores",
+    "acarreados",
+    "acarrear",
+    "acaso",
+    "acatar",
+    "acatarlas",
+    "acataré",
+    "acaten",
+    "acaudaladas",
+    "accedan",
+    "accede",
+    "acceder",
+    "accederán",
+    "accedido",
+    "accedieron",
+    "accesará",
+    "accesible",
+    "accesibles",
+    "acceso",
+    "accesorio",
+    "accesorios",
+    "accesos",
+    "accidentado",
+    "accidentados",
+    "accidental",
+    "accidentarnos",
+    "accidente",
+    "accidentes",
+    "accion",
+    "accionar",
+    "accionaria",
+    "accionarias",
+    "accionario",
+    "accione",
+    "acciones",
+    "accionista",
+    "accionistas",
+    "accival",
+    "acción",
+    "accra",
+    "aceite",
+    "aceites",
+    "aceleradas",
+    "acelerado",
+    "acelerar",
+    "acelerara",
+    "acelerarse",
+    "acelerará",
+    "aceleró",
+    "acendrada",
+    "acentuada",
+    "acentuadas",
+    "acentuar",
+    "acentuó",
+    "acepta",
+    "aceptaba",
+    "aceptaban",
+    "aceptable",
+    "aceptación",
+    "aceptada",
+    "aceptadas",
+    "aceptado",
+    "aceptados",
+    "aceptamos",
+    "aceptan",
+    "aceptar",
+    "aceptara",
+    "aceptarla",
+    "aceptaron",
+    "aceptarían",
+    "aceptas",
+    "acepte",
+    "acepten",
+    "acepto",
+    "acepté",
+    "aceptó",
+    "acer",
+    "acera",
+    "acerca",
+    "acercaba",
+    "acercaban",
+    "acercado",
+    "acercamiento",
+    "acercamientos",
+    "acercan",
+    "acercando",
+    "acercar",
+    "acercarnos",
+    "acercaron",
+    "acercarse",
+    "acercarán",
+    "acercaría",
+    "acercándonos",
+    "acercándose",
+    "acercó",
+    "acereras",
+    "acereros",
+    "acero",
+    "acerque",
+    "acerquen",
+    "acerqué",
+    "acertada",
+    "acertadamente",
+    "acertado",
+    "acervo",
+    "acetato",
+    "acevedo",
+    "aceves",
+    "achacan",
+    "achacar",
+    "achacaron",
+    "achacársela",
+    "achaques",
+    "acheson",
+    "achichincles",
+    "achique",
+    "achy",
+    "acicalar",
+    "acicate",
+    "acidez",
+    "acierto",
+    "aciertos",
+    "aclamada",
+    "aclamado",
+    "aclara",
+    "aclaraciones",
+    "aclaración",
+    "aclarado",
+    "aclaramos",
+    "aclarando",
+    "aclarar",
+    "aclarara",
+    "aclararon",
+    "aclaratorias",
+    "aclaren",
+    "aclaró",
+    "aclimatado",
+    "aclimatándose",
+    "acne",
+    "acné",
+    "acogida",
+    "acojan",
+    "acomete",
+    "acomodado",
+    "acomodados",
+    "acomodar",
+    "acomodarse",
+    "acompaña",
+    "acompañaba",
+    "acompañaban",
+    "acompañada",
+    "acompañadas",
+    "acompañado",
+    "acompañados",
+    "acompañan",
+    "acompañando",
+    "acompañante",
+    "acompañantes",
+    "acompañar",
+    "acompañaron",
+    "acompañará",
+    "acompañarán",
+    "acompañaría",
+    "acompañarían",
+    "acompañe",
+    "acompañó",
+    "acomplejados",
+    "acondicionadas",
+    "acondicionados",
+    "acondicionamiento",
+    "acongoje",
+    "aconseja",
+    "aconsejan",
+    "aconsejar",
+    "aconsejaron",
+    "aconsejará",
+    "aconsejó",
+    "aconséjeme",
+    "acontecer",
+    "acontecimiento",
+    "acontecimientos",
+    "aconteció",
+    "acopio",
+    "acoplamiento",
+    "acoplamientos",
+    "acoplando",
+    "acordada",
+    "acordado",
+    "acordados",
+    "acordaron",
+    "acorde",
+    "acordes",
+    "acordé",
+    "acordó",
+    "acorraló",
+    "acortado",
+    "acortaron",
+    "acortó",
+    "acoso",
+    "acosta",
+    "acostada",
+    "acostumbra",
+    "acostumbraba",
+    "acostumbraban",
+    "acostumbradas",
+    "acostumbrado",
+    "acostumbrados",
+    "acostumbramos",
+    "acostumbran",
+    "acostumbrando",
+    "acostumbrar",
+    "acostumbrarme",
+    "acostumbrarnos",
+    "acota",
+    "acotamiento",
+    "acrecentarlo",
+    "acrecienta",
+    "acredita",
+    "acreditable",
+    "acreditación",
+    "acreditada",
+    "acreditado",
+    "acreditados",
+    "acreditó",
+    "acreedor",
+    "acreedores",
+    "acribillado",
+    "acribillan",
+    "acrobacias",
+    "acróbata",
+    "acta",
+    "actas",
+    "actitud",
+    "actitudes",
+    "activa",
+    "activación",
+    "activamente",
+    "activas",
+    "actividad",
+    "actividades",
+    "activista",
+    "activistas",
+    "activo",
+    "activos",
+    "acto",
+    "actoral",
+    "actores",
+    "actos",
+    "actrices",
+    "actriz",
+    "actuaba",
+    "actuaciones",
+    "actuación",
+    "actuado",
+    "actuales",
+    "actualice",
+    "actualidad",
+    "actualizaciones",
+    "actualización",
+    "actualizada",
+    "actualizando",
+    "actualizar",
+    "actualmente",
+    "actuando",
+    "actuar",
+    "actuaron",
+    "actuará",
+    "actuarán",
+    "actuó",
+    "actúa",
+    "actúan",
+    "actúe",
+    "actúen",
+    "acuarios",
+    "acuciantes",
+    "acuda",
+    "acudan",
+    "acude",
+    "acuden",
+    "acudido",
+    "acudiera",
+    "acudieron",
+    "acudir",
+    "acudiremos",
+    "acudirá",
+    "acudirán",
+    "acudirían",
+    "acudió",
+    "acudí",
+    "acudía",
+    "acudían",
+    "acueducto",
+    "acuerda",
+    "acuerdas",
+    "acuerdo",
+    "acuerdos",
+    "acuesto",
+    "acumula",
+    "acumulación",
+    "acumulada",
+    "acumuladas",
+    "acumulado",
+    "acumulados",
+    "acumulan",
+    "acumulando",
+    "acumular",
+    "acumuló",
+    "acupuntura",
+    "acusa",
+    "acusaban",
+    "acusaciones",
+    "acusación",
+    "acusada",
+    "acusadas",
+    "acusado",
+    "acusador",
+    "acusadoras",
+    "acusadores",
+    "acusados",
+    "acusan",
+    "acusarlo",
+    "acusaron",
+    "acusatorias",
+    "acusándolos",
+    "acusó",
+    "acuática",
+    "acuérdese",
+    "acuña",
+    "acá",
+    "acámbaro",
+    "acústica",
+    "acústicas",
+    "ad",
+    "adam",
+    "adame",
+    "adams",
+    "adamson",
+    "adaptación",
+    "adaptada",
+    "adaptado",
+    "adaptados",
+    "adaptan",
+    "adaptando",
+    "adaptar",
+    "adaptarse",
+    "adaptarán",
+    "adapten",
+    "adaptándose",
+    "adaptó",
+    "adarme",
+    "addis",
+    "adecuaciones",
+    "adecuación",
+    "adecuada",
+    "adecuadamente",
+    "adecuadas",
+    "adecuado",
+    "adecuados",
+    "adecuar",
+    "adecuarla",
+    "adecuarlos",
+    "adecuarse",
+    "adecuarte",
+    "adela",
+    "adelaida",
+    "adelanta",
+    "adelantada",
+    "adelantado",
+    "adelantados",
+    "adelantamos",
+    "adelantando",
+    "adelantar",
+    "adelantarnos",
+    "adelantaron",
+    "adelantarse",
+    "adelantase",
+    "adelante",
+    "adelanto",
+    "adelantos",
+    "adelantó",
+    "adelgazado",
+    "adelgazar",
+    "ademanes",
+    "ademas",
+    "ademán",
+    "además",
+    "adentrarme",
+    "adentrarnos",
+    "adentro",
+    "adeptos",
+    "aderezara",
+    "adese",
+    "adeudaba",
+    "adeudo",
+    "adeudos",
+    "adherencia",
+    "adherida",
+    "adheridos",
+    "adherirse",
+    "adhesiva",
+    "adhesión",
+    "adhiera",
+    "adicional",
+    "adicionales",
+    "adicionalmente",
+    "adiciones",
+    "adicto",
+    "adictos",
+    "adiestramiento",
+    "aditamento",
+    "adivinación",
+    "adivinos",
+    "adivinó",
+    "adiós",
+    "adjetivo",
+    "adjudicación",
+    "adjudican",
+    "adjudicaron",
+    "adjudicarse",
+    "adjudicó",
+    "adjuntando",
+    "adjunto",
+    "administra",
+    "administraciones",
+    "administración",
+    "administrador",
+    "administradora",
+    "administradores",
+    "administrar",
+    "administrativa",
+    "administrativas",
+    "administrativo",
+    "administrativos",
+    "admiraban",
+    "admiración",
+    "admiradoras",
+    "admiran",
+    "admirar",
+    "admiro",
+    "admiró",
+    "admisión",
+    "admiten",
+    "admitidas",
+    "admitir",
+    "admitió",
+    "admito",
+    "adoleciendo",
+    "adoleció",
+    "adolescencia",
+    "adolescente",
+    "adolescentes",
+    "adolfo",
+    "adolorida",
+    "adopción",
+    "adopta",
+    "adoptada",
+    "adoptadas",
+    "adoptado",
+    "adoptando",
+    "adoptar",
+    "adoptaran",
+    "adoptaron",
+    "adoptará",
+    "adoptarán",
+    "adopte",
+    "adopten",
+    "adoptó",
+    "adoquín",
+    "adora",
+    "adoradores",
+    "adoratorio",
+    "adornadas",
+    "adornan",
+    "adornar",
+    "adornos",
+    "adornó",
+    "adoro",
+    "adquiera",
+    "adquiere",
+    "adquieren",
+    "adquirida",
+    "adquiridas",
+    "adquirido",
+    "adquirientes",
+    "adquiriera",
+    "adquirieran",
+    "adquirieron",
+    "adquirir",
+    "adquirirá",
+    "adquirió",
+    "adquirí",
+    "adquisiciones",
+    "adquisición",
+    "adquisitivo",
+    "adr",
+    "adriana",
+    "adrián",
+    "adrs",
+    "adscrita",
+    "adscrito",
+    "aduana",
+    "aduanal",
+    "aduanales",
+    "aductor",
+    "adueñarse",
+    "adulta",
+    "adultas",
+    "adulto",
+    "adultos",
+    "advantaje",
+    "adversario",
+    "adversarios",
+    "adversas",
+    "adversidades",
+    "adversos",
+    "advertencia",
+    "advertido",
+    "advertimos",
+    "advertir",
+    "advertirle",
+    "advertirán",
+    "advertising",
+    "advierta",
+    "advierte",
+    "advierten",
+    "advierto",
+    "advirtiendo",
+    "advirtieron",
+    "advirtiéndoles",
+    "advirtió",
+    "adán",
+    "aerodinámica",
+    "aerodinámico",
+    "aerofotogrametría",
+    "aerolito",
+    "aerolínea",
+    "aerolíneas",
+    "aeroméxico",
+    "aeronave",
+    "aeronaves",
+    "aeronáutica",
+    "aeroperú",
+    "aeroportuario",
+    "aeropuerto",
+    "aeropuertos",
+    "aerosol",
+    "aerosoles",
+    "aerovias",
+    "aerovías",
+    "aerógrafo",
+    "afa",
+    "afanes",
+    "afear",
+    "afecciones",
+    "afección",
+    "afecta",
+    "afectaba",
+    "afectaciones",
+    "afectación",
+    "afectad",
+    "afectada",
+    "afectadas",
+    "afectado",
+    "afectados",
+    "afectan",
+    "afectando",
+    "afectar",
+    "afectaron",
+    "afectarse",
+    "afectará",
+    "afectarán",
+    "afectaría",
+    "afecte",
+    "afectivas",
+    "afectivo",
+    "afecto",
+    "afectos",
+    "afectó",
+    "afeen",
+    "afeitar",
+    "aferrarse",
+    "affaire",
+    "afianzadora",
+    "afianzarse",
+    "aficionadas",
+    "aficionado",
+    "aficionados",
+    "aficiones",
+    "afición",
+    "afilada",
+    "afiliación",
+    "afiliada",
+    "afiliadas",
+    "afiliado",
+    "afiliados",
+    "afiliaron",
+    "afinaciones",
+    "afinación",
+    "afinando",
+    "afinar",
+    "afines",
+    "afinidad",
+    "afinidades",
+    "afinó",
+    "afirma",
+    "afirmaciones",
+    "afirmación",
+    "afirmado",
+    "afirman",
+    "afirmando",
+    "afirmar",
+    "afirmaron",
+    "afirmativa",
+    "afirmó",
+    "afl",
+    "aflicción",
+    "afligida",
+    "afligido",
+    "afloja",
+    "aflojadas",
+    "aflojamos",
+    "aflojar",
+    "aflora",
+    "afluencia",
+    "aforismo",
+    "afortunada",
+    "afortunadamente",
+    "afortunado",
+    "afp",
+    "afrenta",
+    "africa",
+    "africano",
+    "africanos",
+    "afro",
+    "afroamericanos",
+    "afrobrasileño",
+    "afrobrasileños",
+    "afrodisiaco",
+    "afrodisiacos",
+    "afrodisio",
+    "afrontar",
+    "afrontaron",
+    "afrontará",
+    "afronten",
+    "afuera",
+    "afueras",
+    "afán",
+    "afín",
+    "agachó",
+    "agarra",
+    "agarrada",
+    "agarrar",
+    "agarraron",
+    "agarre",
+    "agencia",
+    "agenciaron",
+    "agencias",
+    "agenda",
+    "agendas",
+    "agenl",
+    "agente",
+    "agentes",
+    "agilidad",
+    "agilización",
+    "agilizando",
+    "agilizar",
+    "agilizara",
+    "agilizará",
+    "agio",
+    "agiotistas",
+    "agitación",
+    "agitadas",
+    "agitadores",
+    "agitando",
+    "aglomeraciones",
+    "aglutina",
+    "aglutinar",
+    "agobiado",
+    "agonizante",
+    "agonía",
+    "agora",
+    "agosto",
+    "agotadas",
+    "agotado",
+    "agotamiento",
+    "agotaron",
+    "agotarse",
+    "agote",
+    "agotándolos",
+    "agraciado",
+    "agrada",
+    "agradable",
+    "agradece",
+    "agradecemos",
+    "agradecer",
+    "agradecerán",
+    "agradecería",
+    "agradeciendo",
+    "agradecieron",
+    "agradecimiento",
+    "agradeció",
+    "agradezco",
+    "agrado",
+    "agramón",
+    "agrandar",
+    "agrandarla",
+    "agraria",
+    "agrario",
+    "agraristas",
+    "agrava",
+    "agravación",
+    "agravado",
+    "agravaron",
+    "agraviado",
+    "agravios",
+    "agredido",
+    "agredidos",
+    "agredieron",
+    "agrega",
+    "agregada",
+    "agregado",
+    "agregaduría",
+    "agregan",
+    "agregando",
+    "agregar",
+    "agregaron",
+    "agregarse",
+    "agrego",
+    "agregó",
+    "agremiada",
+    "agremiados",
+    "agresiones",
+    "agresiva",
+    "agresivamente",
+    "agresivas",
+    "agresividad",
+    "agresivo",
+    "agresivos",
+    "agresión",
+    "agresor",
+    "agresores",
+    "agricultores",
+    "agricultura",
+    "agridyne",
+    "agrio",
+    "agro",
+    "agropecuaria",
+    "agropecuario",
+    "agropecuarios",
+    "agroquímico",
+    "agroquímicos",
+    "agrupa",
+    "agrupaciones",
+    "agrupación",
+    "agrupadas",
+    "agrupamiento",
+    "agrupar",
+    "agréguele",
+    "agrícola",
+    "agrícolas",
+    "agua",
+    "aguacate",
+    "aguacates",
+    "agualeguas",
+    "aguanta",
+    "aguantar",
+    "aguantarse",
+    "aguantó",
+    "aguardaba",
+    "aguardaban",
+    "aguardan",
+    "aguas",
+    "aguascalientes",
+    "aguaytía",
+    "aguda",
+    "agudas",
+    "agudiza",
+    "agudizado",
+    "agudo",
+    "agudos",
+    "aguila",
+    "aguilar",
+    "aguilas",
+    "aguilera",
+    "aguinaldo",
+    "aguirre",
+    "aguja",
+    "agujeros",
+    "agujetas",
+    "agusto",
+    "agustín",
+    "agárrese",
+    "agüites",
+    "ah",
+    "ahmsa",
+    "ahogados",
+    "ahogando",
+    "ahogaron",
+    "ahondando",
+    "ahondar",
+    "ahora",
+    "ahorita",
+    "ahorra",
+    "ahorrado",
+    "ahorrador",
+    "ahorradores",
+    "ahorramos",
+    "ahorrar",
+    "ahorraron",
+    "ahorrará",
+    "ahorrarán",
+    "ahorrativa",
+    "ahorrativo",
+    "ahorro",
+    "ahorros",
+    "ahuehuete",
+    "ahujas",
+    "ahuyenta",
+    "ahí",
+    "aikman",
+    "airadamente",
+    "airbus",
+    "aire",
+    "airecombustible",
+    "aires",
+    "airways",
+    "ais",
+    "aislacionismo",
+    "aislada",
+    "aisladas",
+    "aislados",
+    "aislamiento",
+    "aislarla",
+    "aislarlo",
+    "aispuro",
+    "aiv",
+    "aix",
+    "ajalpan",
+    "ajena",
+    "ajenas",
+    "ajeno",
+    "ajenos",
+    "ajusco",
+    "ajusta",
+    "ajustados",
+    "ajustan",
+    "ajustando",
+    "ajustar",
+    "ajustarse",
+    "ajuste",
+    "ajustes",
+    "ajusticiado",
+    "ajustándolo",
+    "ak",
+    "akita",
+    "al",
+    "ala",
+    "alababa",
+    "alabanza",
+    "alabarderos",
+    "alabó",
+    "aladino",
+    "aladro",
+    "alain",
+    "alambre",
+    "alameda",
+    "alamo",
+    "alamos",
+    "alan",
+    "alanís",
+    "alarcón",
+    "alargadas",
+    "alargar",
+    "alarido",
+    "alarma",
+    "alarmado",
+    "alarmados",
+    "alarmante",
+    "alarmaron",
+    "alarme",
+    "alarmismo",
+    "alarmó",
+    "alaska",
+    "alatriste",
+    "alavez",
+    "alba",
+    "albacete",
+    "albay",
+    "albañil",
+    "albañiles",
+    "albee",
+    "alberca",
+    "alberga",
+    "albergar",
+    "albergará",
+    "albergue",
+    "albergues",
+    "albert",
+    "alberto",
+    "albiazul",
+    "albiazules",
+    "albiceleste",
+    "alborotada",
+    "alboroto",
+    "alborotos",
+    "album",
+    "albur",
+    "albán",
+    "alcachofa",
+    "alcalde",
+    "alcaldes",
+    "alcaldesa",
+    "alcaldía",
+    "alcaldías",
+    "alcalinidad",
+    "alcalinos",
+    "alcalá",
+    "alcance",
+    "alcancemos",
+    "alcancen",
+    "alcances",
+    "alcancé",
+    "alcantarilla",
+    "alcantarillado",
+    "alcanza",
+    "alcanzaba",
+    "alcanzaban",
+    "alcanzada",
+    "alcanzado",
+    "alcanzados",
+    "alcanzan",
+    "alcanzando",
+    "alcanzar",
+    "alcanzara",
+    "alcanzaremos",
+    "alcanzarlos",
+    "alcanzaron",
+    "alcanzará",
+    "alcanzaré",
+    "alcanzaría",
+    "alcanzó",
+    "alcerreza",
+    "alcocer",
+    "alcohol",
+    "alcoholicas",
+    "alcántara",
+    "aldape",
+    "aldea",
+    "aldeas",
+    "aledañas",
+    "alega",
+    "alegando",
+    "alegar",
+    "alegara",
+    "alegaron",
+    "alegatos",
+    "alegoría",
+    "alegraron",
+    "alegre",
+    "alegremente",
+    "alegría",
+    "alegró",
+    "alegó",
+    "alegórico",
+    "alegóricos",
+    "aleja",
+    "alejaban",
+    "alejado",
+    "alejamiento",
+    "alejandra",
+    "alejandro",
+    "alejar",
+    "alejara",
+    "alejaron",
+    "alejo",
+    "alejándonos",
+    "alejándose",
+    "alejó",
+    "alemana",
+    "alemanas",
+    "alemanes",
+    "alemania",
+    "alemán",
+    "alenia",
+    "alentador",
+    "alentadora",
+    "alentadores",
+    "alentar",
+    "alentó",
+    "alergia",
+    "alergología",
+    "alerta",
+    "alertado",
+    "alerten",
+    "alessio",
+    "alex",
+    "alexander",
+    "alexandr",
+    "alexis",
+    "alfa",
+    "alfabético",
+    "alfarería",
+    "alfaro",
+    "alfilerazos",
+    "alfio",
+    "alfombra",
+    "alfonso",
+    "alfred",
+    "alfredo",
+    "algas",
+    "algo",
+    "algodón",
+    "alguien",
+    "alguna",
+    "algunas",
+    "alguno",
+    "algunos",
+    "algún",
+    "ali",
+    "aliada",
+    "aliadas",
+    "aliado",
+    "aliados",
+    "alianza",
+    "alianzas",
+    "aliar",
+    "aliaron",
+    "aliarse",
+    "alicea",
+    "alicia",
+    "aliemos",
+    "alienta",
+    "alientan",
+    "aliento",
+    "alientos",
+    "aligerar",
+    "aligerarla",
+    "alija",
+    "alimañas",
+    "alimentación",
+    "alimentada",
+    "alimentado",
+    "alimentador",
+    "alimentadores",
+    "alimentar",
+    "alimenticia",
+    "alimenticio",
+    "alimenticios",
+    "alimento",
+    "alimentos",
+    "alimentó",
+    "alineacion",
+    "alineaciones",
+    "alineación",
+    "alineado",
+    "alineó",
+    "alistan",
+    "aliuska",
+    "aliviar",
+    "aliviará",
+    "aliviaría",
+    "alivio",
+    "aliándose",
+    "aljibes",
+    "alla",
+    "allegadas",
+    "allegado",
+    "allegados",
+    "allegar",
+    "allegarle",
+    "allegarles",
+    "allegarse",
+    "allende",
+    "allá",
+    "allí",
+    "alma",
+    "almacena",
+    "almacenada",
+    "almacenadora",
+    "almacenaje",
+    "almacenamiento",
+    "almacenar",
+    "almacene",
+    "almacenes",
+    "almaguer",
+    "almaraz",
+    "almas",
+    "almohada",
+    "almonte",
+    "almorzar",
+    "almorzase",
+    "almuerzo",
+    "almuerzos",
+    "alonso",
+    "alpargatado",
+    "alquibenceno",
+    "alquilado",
+    "alquiler",
+    "alquimia",
+    "alrededor",
+    "alrededores",
+    "alta",
+    "altadena",
+    "altamente",
+    "altamira",
+    "altamirano",
+    "altas",
+    "altavista",
+    "altera",
+    "alteraciones",
+    "alteración",
+    "alterado",
+    "alterados",
+    "alterar",
+    "alteraron",
+    "alterarse",
+    "alterarán",
+    "altercado",
+    "altere",
+    "alterna",
+    "alternando",
+    "alternar",
+    "alternará",
+    "alternativa",
+    "alternativas",
+    "alternó",
+    "altibajos",
+    "altiplano",
+    "altisonantes",
+    "altitud",
+    "alto",
+    "altos",
+    "altruista",
+    "altura",
+    "alturas",
+    "altímetro",
+    "altísimos",
+    "alud",
+    "aludir",
+    "alumbraba",
+    "alumbraban",
+    "alumbrado",
+    "alumbró",
+    "aluminio",
+    "alumna",
+    "alumnado",
+    "alumnas",
+    "alumno",
+    "alumnos",
+    "aluncinógenos",
+    "alusiva",
+    "alusión",
+    "alvarado",
+    "alvarez",
+    "alvaro",
+    "alvelais",
+    "alvez",
+    "alza",
+    "alzados",
+    "alzamiento",
+    "alzas",
+    "alzheimer",
+    "alzo",
+    "ama",
+    "amaba",
+    "amaban",
+    "amabilidad",
+    "amable",
+    "amablemente",
+    "amables",
+    "amadeo",
+    "amado",
+    "amador",
+    "amagaron",
+    "amagó",
+    "amamos",
+    "amanda",
+    "amando",
+    "amanece",
+    "amanecer",
+    "amante",
+    "amantes",
+    "amanuense",
+    "amapola",
+    "amar",
+    "amarga",
+    "amargarnos",
+    "amargas",
+    "amargos",
+    "amarilla",
+    "amarillas",
+    "amarillo",
+    "amarillos",
+    "amarme",
+    "amaro",
+    "amarrado",
+    "amarrando",
+    "amarren",
+    "amarres",
+    "amarró",
+    "amaru",
+    "amaré",
+    "amas",
+    "amasando",
+    "amateur",
+    "amateurs",
+    "amatista",
+    "amatistas",
+    "amatorias",
+    "ambas",
+    "ambassador",
+    "ambicionan",
+    "ambiciosa",
+    "ambicioso",
+    "ambiciosos",
+    "ambición",
+    "ambientación",
+    "ambiental",
+    "ambientales",
+    "ambientalista",
+    "ambientalistas",
+    "ambientalmente",
+    "ambientar",
+    "ambiente",
+    "ambientes",
+    "ambigüedades",
+    "ambos",
+    "ambriz",
+    "ambrose",
+    "ambrosio",
+    "ambulancia",
+    "ambulancias",
+    "ambulantes",
+    "ambulatorio",
+    "amda",
+    "amen",
+    "amenace",
+    "amenaqzado",
+    "amenaza",
+    "amenazaba",
+    "amenazadas",
+    "amenazado",
+    "amenazados",
+    "amenazan",
+    "amenazando",
+    "amenazar",
+    "amenazaron",
+    "amenazará",
+    "amenazas",
+    "amenazó",
+    "amenizador",
+    "ameno",
+    "americana",
+    "americanas",
+    "americanismo",
+    "americanista",
+    "americanistas",
+    "americano",
+    "americanos",
+    "amerimex",
+    "ameritaba",
+    "ames",
+    "ametralladoras",
+    "amga",
+    "amieva",
+    "amiga",
+    "amigabilidad",
+    "amigable",
+    "amigas",
+    "amigo",
+    "amigos",
+    "amigote",
+    "amiguismos",
+    "amiguita",
+    "aminoró",
+    "amistad",
+    "amistades",
+    "amistosa",
+    "amistosas",
+    "amistoso",
+    "amistosos",
+    "amnistía",
+    "amo",
+    "amoldan",
+    "amonestaba",
+    "amonestados",
+    "amoniaco",
+    "amonio",
+    "amontonada",
+    "amor",
+    "amores",
+    "amoroso",
+    "amortiguadores",
+    "amosca",
+    "amotinados",
+    "amoxcalli",
+    "amp",
+    "amparado",
+    "amparar",
+    "ampararnos",
+    "ampare",
+    "amparo",
+    "amparos",
+    "amplia",
+    "ampliaciones",
+    "ampliación",
+    "ampliada",
+    "ampliadas",
+    "ampliado",
+    "ampliados",
+    "ampliamente",
+    "ampliando",
+    "ampliar",
+    "ampliarla",
+    "ampliarse",
+    "ampliará",
+    "ampliarán",
+    "amplias",
+    "amplio",
+    "amplios",
+    "amplitud",
+    "amplía",
+    "amplíe",
+    "ampudia",
+    "amputaron",
+    "amueblar",
+    "amuelan",
+    "amén",
+    "américa",
+    "américas",
+    "américo",
+    "ana",
+    "anacaborca",
+    "anacrónico",
+    "analfabetas",
+    "analfabetismo",
+    "analice",
+    "analicen",
+    "analista",
+    "analistas",
+    "analiza",
+    "analizadas",
+    "analizado",
+    "analizados",
+    "analizamos",
+    "analizando",
+    "analizar",
+    "analizarla",
+    "analizarlo",
+    "analizaron",
+    "analizarse",
+    "analizará",
+    "analizarán",
+    "analizaría",
+    "analizándolo",
+    "analizó",
+    "analogías",
+    "anamup",
+    "anarquía",
+    "anatemas",
+    "ancestrales",
+    "ancestro",
+    "ancestros",
+    "ancha",
+    "anchas",
+    "ancho",
+    "anciana",
+    "ancianas",
+    "ancianitos",
+    "anciano",
+    "ancianos",
+    "ancira",
+    "ancló",
+    "anda",
+    "andaba",
+    "andaban",
+    "andamos",
+    "andan",
+    "andar",
+    "andaría",
+    "ande",
+    "andei",
+    "anderson",
+    "andes",
+    "andina",
+    "andino",
+    "ando",
+    "andrade",
+    "andrea",
+    "andres",
+    "andrés",
+    "anduvo",
+    "anemia",
+    "anestesia",
+    "anexada",
+    "anexamos",
+    "anexas",
+    "anexos",
+    "anfibios",
+    "anfisbena",
+    "anfiteatro",
+    "anfitrión",
+    "angel",
+    "angeles",
+    "angelina",
+    "angelino",
+    "angelito",
+    "angelitos",
+    "angelo",
+    "angelus",
+    "angiosperma",
+    "angiospermas",
+    "anglicana",
+    "anglo",
+    "angola",
+    "angoleña",
+    "angoleño",
+    "angoleños",
+    "angongueo",
+    "angostura",
+    "angustia",
+    "angustiada",
+    "angustias",
+    "angustió",
+    "angélica",
+    "angélicas",
+    "anhatl",
+    "anhelado",
+    "anhelo",
+    "anillo",
+    "anima",
+    "animación",
+    "animal",
+    "animales",
+    "animalización",
+    "animar",
+    "animarán",
+    "aniversario",
+    "ann",
+    "anna",
+    "anoche",
+    "anochecer",
+    "anonas",
+    "anonimato",
+    "anotaba",
+    "anotaciones",
+    "anotación",
+    "anotado",
+    "anotados",
+    "anotando",
+    "anotar",
+    "anotaron",
+    "anotarse",
+    "anote",
+    "anoten",
+    "anotó",
+    "anselmo",
+    "ansias",
+    "ansiedad",
+    "ansioso",
+    "anstee",
+    "antad",
+    "antaño",
+    "ante",
+    "anteayer",
+    "antebrazo",
+    "antecedente",
+    "antecedentes",
+    "antecesores",
+    "antemano",
+    "anteo",
+    "anteojos",
+    "antepasado",
+    "antepenúltimo",
+    "anterior",
+    "anteriores",
+    "anterioridad",
+    "anteriormente",
+    "antes",
+    "antesala",
+    "antesalista",
+    "anthony",
+    "anti",
+    "antiaéreas",
+    "anticipaba",
+    "anticipación",
+    "anticipada",
+    "anticipadamente",
+    "anticipadas",
+    "anticipado",
+    "anticipamos",
+    "anticipando",
+    "anticipar",
+    "anticiparon",
+    "anticipos",
+    "anticonstitucional",
+    "anticontaminante",
+    "anticontaminantes",
+    "anticristo",
+    "anticuarios",
+    "antidemocrática",
+    "antiderrapante",
+    "antidoping",
+    "antidoto",
+    "antidrogas",
+    "antidumping",
+    "antier",
+    "antiestrés",
+    "antigua",
+    "antiguas",
+    "antiguo",
+    "antiguos",
+    "antigüedad",
+    "antigüedades",
+    "antillano",
+    "antillanos",
+    "antillas",
+    "antimonopolio",
+    "antinarcóticos",
+    "antinépota",
+    "antioqueña",
+    "antiparasitaria",
+    "antipatía",
+    "antisocial",
+    "antisubversiva",
+    "antoja",
+    "antojo",
+    "antonelli",
+    "antonin",
+    "antonini",
+    "antonio",
+    "antorchista",
+    "antropología",
+    "antropólogo",
+    "antídotos",
+    "antítesis",
+    "anual",
+    "anuales",
+    "anualmente",
+    "anulación",
+    "anuladas",
+    "anulados",
+    "anularlo",
+    "anuncia",
+    "anunciaba",
+    "anunciaban",
+    "anunciada",
+    "anunciadas",
+    "anunciado",
+    "anunciados",
+    "anuncian",
+    "anunciando",
+    "anunciar",
+    "anunciarlo",
+    "anunciaron",
+    "anunciará",
+    "anuncio",
+    "anuncios",
+    "anunció",
+    "anzuelo",
+    "anzuelos",
+    "anáhuac",
+    "análisis",
+    "análogas",
+    "anécdota",
+    "anémica",
+    "anímica",
+    "anímicamente",
+    "anónima",
+    "anónimas",
+    "anónimo",
+    "anónimos",
+    "ap",
+    "apabulló",
+    "apaches",
+    "apacible",
+    "apaga",
+    "apagados",
+    "apagan",
+    "apagará",
+    "apagó",
+    "apagón",
+    "apaleó",
+    "aparadores",
+    "aparato",
+    "aparatos",
+    "aparatosa",
+    "aparcar",
+    "aparece",
+    "aparecen",
+    "aparecer",
+    "aparecidas",
+    "aparecido",
+    "apareciendo",
+    "apareciera",
+    "aparecieron",
+    "apareció",
+    "aparecía",
+    "aparentan",
+    "aparentar",
+    "aparente",
+    "aparentemente",
+    "aparentes",
+    "aparentó",
+    "aparezca",
+    "aparezcan",
+    "apariciones",
+    "aparición",
+    "apariencia",
+    "apariencias",
+    "apartado",
+    "apartados",
+    "apartamento",
+    "apartamentos",
+    "apartan",
+    "aparte",
+    "apasionadamente",
+    "apasionado",
+    "apasionamiento",
+    "apasionantes",
+    "apatía",
+    "apedrean",
+    "apegada",
+    "apegadas",
+    "apegan",
+    "apegarnos",
+    "apegarse",
+    "apego",
+    "apelaciones",
+    "apelación",
+    "apelar",
+    "apelarán",
+    "apelativos",
+    "apellido",
+    "apelo",
+    "apenado",
+    "apenaron",
+    "apenas",
+    "apendicitis",
+    "apertura",
+    "apetito",
+    "apizaco",
+    "aplacarlos",
+    "aplasta",
+    "aplastada",
+    "aplastaron",
+    "aplaude",
+    "aplaudiendo",
+    "aplaudieran",
+    "aplaudieron",
+    "aplaudir",
+    "aplaudirán",
+    "aplaudió",
+    "aplauso",
+    "aplausos",
+    "aplaza",
+    "aplazada",
+    "aplazamiento",
+    "aplazó",
+    "aplica",
+    "aplicables",
+    "aplicaciones",
+    "aplicación",
+    "aplicada",
+    "aplicado",
+    "aplicados",
+    "aplican",
+    "aplicar",
+    "aplicara",
+    "aplicarla",
+    "aplicarle",
+    "aplicarlos",
+    "aplicaron",
+    "aplicarse",
+    "aplicará",
+    "aplicarán",
+    "aplicaríamos",
+    "aplico",
+    "aplicáramos",
+    "aplicó",
+    "aplique",
+    "apliquen",
+    "apo",
+    "apocada",
+    "apocalipsis",
+    "apocalíptica",
+    "apocalípticas",
+    "apoda",
+    "apodaban",
+    "apodaca",
+    "apodado",
+    "apoderado",
+    "apoderaron",
+    "apoderándose",
+    "apoderó",
+    "apodo",
+    "apogeo",
+    "apoplejía",
+    "apoquinar",
+    "aporta",
+    "aportaciones",
+    "aportación",
+    "aportado",
+    "aportamos",
+    "aportan",
+    "aportando",
+    "aportar",
+    "aportaron",
+    "aportará",
+    "aporte",
+    "aporten",
+    "aportó",
+    "aposentos",
+    "apostados",
+    "apostamos",
+    "apostar",
+    "apostemos",
+    "apostó",
+    "apostólica",
+    "apostólico",
+    "apoya",
+    "apoyaban",
+    "apoyada",
+    "apoyado",
+    "apoyados",
+    "apoyamos",
+    "apoyan",
+    "apoyando",
+    "apoyar",
+    "apoyara",
+    "apoyaremos",
+    "apoyarian",
+    "apoyarlo",
+    "apoyaron",
+    "apoyará",
+    "apoyarán",
+    "apoyaría",
+    "apoyasen",
+    "apoye",
+    "apoyen",
+    "apoyo",
+    "apoyos",
+    "apoyándola",
+    "apoyándonos",
+    "apoyándose",
+    "apoyó",
+    "appel",
+    "apra",
+    "aprecia",
+    "apreciación",
+    "apreciada",
+    "apreciado",
+    "apreciar",
+    "apreciarlo",
+    "apreciaron",
+    "apreciarse",
+    "aprecio",
+    "apreció",
+    "aprehendido",
+    "aprehension",
+    "aprehensiones",
+    "aprehensión",
+    "aprehensores",
+    "aprenda",
+    "aprendan",
+    "aprende",
+    "aprenden",
+    "aprender",
+    "aprenderlo",
+    "aprenderá",
+    "aprendices",
+    "aprendido",
+    "aprendiendo",
+    "aprendieron",
+    "aprendiste",
+    "aprendizaje",
+    "aprendió",
+    "aprestos",
+    "apresura",
+    "apresuradamente",
+    "apresurado",
+    "apresuramiento",
+    "apresurando",
+    "apresurar",
+    "apresuró",
+    "apretado",
+    "apretamos",
+    "apretar",
+    "apretarlo",
+    "apretaron",
+    "apretó",
+    "apretón",
+    "aprieta",
+    "apriete",
+    "aprieten",
+    "aprietos",
+    "apro",
+    "aprobación",
+    "aprobada",
+    "aprobadas",
+    "aprobado",
+    "aprobados",
+    "aprobar",
+    "aprobaron",
+    "aprobarse",
+    "aprobará",
+    "aprobarán",
+    "aprobatorio",
+    "aprobó",
+    "apropiada",
+    "apropiadamente",
+    "apropiadas",
+    "apropiado",
+    "apropiados",
+    "apropian",
+    "aprovecha",
+    "aprovechado",
+    "aprovechamiento",
+    "aprovechan",
+    "aprovechando",
+    "aprovechar",
+    "aprovecharla",
+    "aprovecharlas",
+    "aprovecharon",
+    "aprovecharse",
+    "aprovecharán",
+    "aproveche",
+    "aprovechó",
+    "aproximaba",
+    "aproximación",
+    "aproximada",
+    "aproximadamente",
+    "aproximado",
+    "aproximados",
+    "aproximan",
+    "aprueba",
+    "aprueban",
+    "apruebe",
+    "aprueben",
+    "aptas",
+    "aptitud",
+    "aptitudes",
+    "aptos",
+    "apuesta",
+    "apuestas",
+    "apuesto",
+    "apuestos",
+    "apunta",
+    "apuntalado",
+    "apuntalar",
+    "apuntan",
+    "apuntar",
+    "apuntaron",
+    "apuntes",
+    "apuntó",
+    "apáticas",
+    "apáticos",
+    "apéndice",
+    "apócrifo",
+    "apóstoles",
+    "aquel",
+    "aquelarre",
+    "aquelarres",
+    "aquella",
+    "aquellas",
+    "aquello",
+    "aquellos",
+    "aqui",
+    "aquiles",
+    "aquino",
+    "aquél",
+    "aquéllas",
+    "aquéllos",
+    "aquí",
+    "arabe",
+    "arado",
+    "aragua",
+    "aragón",
+    "araiza",
+    "arana",
+    "arancel",
+    "arancelarias",
+    "aranceles",
+    "aranda",
+    "arango",
+    "aranjuez",
+    "aras",
+    "arau",
+    "araujo",
+    "arañas",
+    "arbitraje",
+    "arbitral",
+    "arbitraria",
+    "arbitrarias",
+    "arbitrariedades",
+    "arbitrario",
+    "arbitrio",
+    "arboles",
+    "arbusto",
+    "arcadio",
+    "archaelogy",
+    "archiconservadores",
+    "archiduque",
+    "archipiélago",
+    "archivada",
+    "archivo",
+    "archundia",
+    "arcilla",
+    "arcillistas",
+    "arco",
+    "arcoiris",
+    "arcusa",
+    "arcángeles",
+    "ardiente",
+    "ardientes",
+    "arduo",
+    "ardía",
+    "arellanes",
+    "arenal",
+    "areoportuarias",
+    "aretes",
+    "argentina",
+    "argentinabrasil",
+    "argentino",
+    "argentinos",
+    "argoytia",
+    "argudín",
+    "arguelles",
+    "argumenta",
+    "argumentando",
+    "argumentar",
+    "argumentaron",
+    "argumentarán",
+    "argumento",
+    "argumentos",
+    "argumentó",
+    "arias",
+    "ariel",
+    "ariete",
+    "arista",
+    "aristócratas",
+    "aristóteles",
+    "aritmética",
+    "aritmético",
+    "arizmendi",
+    "arizona",
+    "arkansas",
+    "arlette",
+    "arma",
+    "armada",
+    "armadas",
+    "armadillo",
+    "armado",
+    "armadoras",
+    "armados",
+    "armagedón",
+    "armamento",
+    "armamentos",
+    "armando",
+    "armani",
+    "armar",
+    "armas",
+    "armella",
+    "armendáriz",
+    "armenio",
+    "armillita",
+    "armisticio",
+    "armoniosa",
+    "armonización",
+    "armonizar",
+    "armonizarse",
+    "armonía",
+    "armstrong",
+    "army",
+    "armó",
+    "armónica",
+    "armónico",
+    "arnaldo",
+    "arnoldo",
+    "arnulfo",
+    "aromas",
+    "aromatizantes",
+    "aromatizar",
+    "aros",
+    "arq",
+    "arqueología",
+    "arqueológica",
+    "arqueológicas",
+    "arqueológico",
+    "arqueológicos",
+    "arquero",
+    "arqueóloga",
+    "arqueólogos",
+    "arquidiócesis",
+    "arquitecto",
+    "arquitectos",
+    "arquitectura",
+    "arquitecturas",
+    "arquitectónicamente",
+    "arquitectónicas",
+    "arquitectónico",
+    "arracadas",
+    "arrachera",
+    "arraigada",
+    "arraigado",
+    "arraigo",
+    "arrambide",
+    "arranca",
+    "arrancados",
+    "arrancando",
+    "arrancar",
+    "arrancaremos",
+    "arrancaron",
+    "arrancará",
+    "arrancarán",
+    "arrancárselo",
+    "arrancó",
+    "arranque",
+    "arrasaron",
+    "arrastrada",
+    "arrastrados",
+    "arrastrando",
+    "arrastre",
+    "arrastró",
+    "arreaga",
+    "arrear",
+    "arrebatada",
+    "arrebatar",
+    "arrebatara",
+    "arrebató",
+    "arredondo",
+    "arredra",
+    "arregla",
+    "arreglada",
+    "arreglan",
+    "arreglar",
+    "arreglaran",
+    "arreglarse",
+    "arreglas",
+    "arreglo",
+    "arreglos",
+    "arregló",
+    "arremangó",
+    "arremetió",
+    "arrendaba",
+    "arrendador",
+    "arrendadora",
+    "arrendadoras",
+    "arrendamiento",
+    "arrendamientos",
+    "arrendatario",
+    "arreola",
+    "arrepentido",
+    "arrepentimiento",
+    "arrepiente",
+    "arrestado",
+    "arrestados",
+    "arrested",
+    "arresto",
+    "arrestos",
+    "arrestó",
+    "arriaga",
+    "arriba",
+    "arribo",
+    "arribó",
+    "arriesga",
+    "arriesgada",
+    "arriesgado",
+    "arriesgamos",
+    "arriesgando",
+    "arriesgar",
+    "arriesgarlo",
+    "arriesgarse",
+    "arriesgarían",
+    "arrimo",
+    "arrivederci",
+    "arrobamiento",
+    "arrodillar",
+    "arrogante",
+    "arroja",
+    "arrojaban",
+    "arrojada",
+    "arrojado",
+    "arrojados",
+    "arrojan",
+    "arrojando",
+    "arrojar",
+    "arrojarlos",
+    "arrojaron",
+    "arrojen",
+    "arrojó",
+    "arrolladas",
+    "arrollado",
+    "arrolló",
+    "arropados",
+    "arroyo",
+    "arroyos",
+    "arroyó",
+    "arroz",
+    "arrugas",
+    "arruinado",
+    "arruinan",
+    "arruinarse",
+    "arrutinamos",
+    "arrópese",
+    "arsenio",
+    "arte",
+    "arteaga",
+    "artefacto",
+    "artemio",
+    "arteria",
+    "arterias",
+    "arterioesclerosis",
+    "arteroesclerosis",
+    "artes",
+    "artesanal",
+    "artesanales",
+    "artesanas",
+    "artesanías",
+    "artesianos",
+    "arthur",
+    "articulación",
+    "articulado",
+    "artificial",
+    "artificiales",
+    "artificialmente",
+    "artificio",
+    "artilleros",
+    "artillería",
+    "artista",
+    "artistas",
+    "arturo",
+    "artículo",
+    "artículos",
+    "artífice",
+    "artística",
+    "artísticas",
+    "artístico",
+    "artísticos",
+    "arvernse",
+    "aryca",
+    "arzobispal",
+    "arzobispo",
+    "arábiga",
+    "arévalo",
+    "asa",
+    "asada",
+    "asadas",
+    "asaltaba",
+    "asaltada",
+    "asaltado",
+    "asaltados",
+    "asaltan",
+    "asaltante",
+    "asaltantes",
+    "asaltar",
+    "asaltaron",
+    "asalto",
+    "asaltos",
+    "asaltó",
+    "asamblea",
+    "asambleas",
+    "asambleísas",
+    "ascencio",
+    "ascendencia",
+    "ascendente",
+    "ascenderá",
+    "ascenderían",
+    "ascendido",
+    "ascendiendo",
+    "ascendieron",
+    "ascendió",
+    "ascenso",
+    "ascensos",
+    "asciende",
+    "ascienden",
+    "aseados",
+    "asegura",
+    "aseguraba",
+    "asegurada",
+    "aseguradas",
+    "asegurado",
+    "aseguradora",
+    "asegurados",
+    "aseguramiento",
+    "aseguran",
+    "asegurar",
+    "aseguraremos",
+    "asegurarle",
+    "aseguraron",
+    "aseguren",
+    "aseguró",
+    "asegúrese",
+    "asemeja",
+    "asemejaba",
+    "asemex",
+    "asención",
+    "asentadas",
+    "asentado",
+    "asentamiento",
+    "asentamientos",
+    "asentándose",
+    "asentó",
+    "asertivo",
+    "ases",
+    "asesinada",
+    "asesinado",
+    "asesinar",
+    "asesinaron",
+    "asesinato",
+    "asesinatos",
+    "asesino",
+    "asesinos",
+    "asesinó",
+    "asesor",
+    "asesora",
+    "asesoraban",
+    "asesorado",
+    "asesoramiento",
+    "asesorar",
+    "asesorarlo",
+    "asesores",
+    "asesoría",
+    "asestadas",
+    "asestó",
+    "asevera",
+    "aseverando",
+    "aseveraron",
+    "aseveró",
+    "asfalto",
+    "asfixia",
+    "ashida",
+    "asi",
+    "asia",
+    "asic",
+    "asiduo",
+    "asienta",
+    "asiento",
+    "asientos",
+    "asigna",
+    "asignación",
+    "asignadas",
+    "asignado",
+    "asignados",
+    "asignan",
+    "asignando",
+    "asignar",
+    "asignaron",
+    "asignen",
+    "asimilación",
+    "asimilar",
+    "asimilemos",
+    "asimismo",
+    "asimétrica",
+    "asista",
+    "asistan",
+    "asiste",
+    "asisten",
+    "asistencia",
+    "asistenciales",
+    "asistencias",
+    "asistentes",
+    "asistiendo",
+    "asistiera",
+    "asistieron",
+    "asistir",
+    "asistirá",
+    "asistirán",
+    "asistiéndose",
+    "asistió",
+    "asistían",
+    "asitex",
+    "asiático",
+    "asocia",
+    "asociaciones",
+    "asociación",
+    "asociada",
+    "asociado",
+    "asociados",
+    "asocian",
+    "asociar",
+    "asociaron",
+    "asociarse",
+    "asombra",
+    "asombradas",
+    "asombro",
+    "asombrosa",
+    "asombrosamente",
+    "asomo",
+    "asomó",
+    "asotiation",
+    "aspavientos",
+    "aspe",
+    "aspecto",
+    "aspectos",
+    "aspersión",
+    "aspes",
+    "aspetia",
+    "aspira",
+    "aspiraciones",
+    "aspiran",
+    "aspirante",
+    "aspirantes",
+    "aspirar",
+    "aspirara",
+    "assessment",
+    "associates",
+    "association",
+    "astado",
+    "asteroide",
+    "asteroides",
+    "astro",
+    "astroditomas",
+    "astrología",
+    "astrológico",
+    "astronautas",
+    "astronómicos",
+    "astros",
+    "asuma",
+    "asuman",
+    "asume",
+    "asumen",
+    "asumido",
+    "asumiendo",
+    "asumiera",
+    "asumieron",
+    "asumimos",
+    "asumir",
+    "asumirá",
+    "asumió",
+    "asuntito",
+    "asunto",
+    "asuntos",
+    "asustada",
+    "asustado",
+    "asustar",
+    "asuste",
+    "asusten",
+    "así",
+    "asímismo",
+    "asómbrese",
+    "asómese",
+    "ataca",
+    "atacada",
+    "atacado",
+    "atacados",
+    "atacan",
+    "atacando",
+    "atacante",
+    "atacantes",
+    "atacar",
+    "atacarlos",
+    "atacaron",
+    "atacará",
+    "atacarán",
+    "atacas",
+    "atacándolo",
+    "atacó",
+    "atadas",
+    "atados",
+    "atanasio",
+    "ataque",
+    "ataques",
+    "atardecer",
+    "atarlos",
+    "ataron",
+    "atascadas",
+    "atascón",
+    "atasta",
+    "ataviado",
+    "ataviados",
+    "atañe",
+    "atemorizante",
+    "atenciones",
+    "atención",
+    "atendamos",
+    "atender",
+    "atenderlos",
+    "atenderá",
+    "atenderán",
+    "atenderían",
+    "atendida",
+    "atendidas",
+    "atendido",
+    "atendidos",
+    "atendieran",
+    "atendieron",
+    "atendió",
+    "atendía",
+    "atenemos",
+    "atenta",
+    "atentado",
+    "atentados",
+    "atentamente",
+    "atentar",
+    "atenten",
+    "atento",
+    "atentos",
+    "aterra",
+    "aterrados",
+    "aterrizaje",
+    "aterrorizada",
+    "ates",
+    "athlone",
+    "atiborran",
+    "atienda",
+    "atiende",
+    "atienden",
+    "atinada",
+    "atinadamente",
+    "atinados",
+    "atlanta",
+    "atlante",
+    "atlantic",
+    "atlantis",
+    "atlantista",
+    "atlantistas",
+    "atlas",
+    "atleta",
+    "atletas",
+    "atletismo",
+    "atlántico",
+    "atlético",
+    "atmosférica",
+    "atmosférico",
+    "atmósfera",
+    "atolladero",
+    "atolondradas",
+    "atomizador",
+    "atosigaba",
+    "atp",
+    "atracados",
+    "atracción",
+    "atraco",
+    "atractiva",
+    "atractivas",
+    "atractivo",
+    "atractivos",
+    "atrae",
+    "atraen",
+    "atraer",
+    "atrajo",
+    "atrapadas",
+    "atrapado",
+    "atrapados",
+    "atrapan",
+    "atrasaditos",
+    "atrasado",
+    "atrasados",
+    "atrasarnos",
+    "atrasen",
+    "atraso",
+    "atravesado",
+    "atravesaron",
+    "atraviesa",
+    "atraviesan",
+    "atraídos",
+    "atrevería",
+    "atrevida",
+    "atrevido",
+    "atrevo",
+    "atribuciones",
+    "atribuible",
+    "atribuibles",
+    "atribuido",
+    "atribuirse",
+    "atribulado",
+    "atribulados",
+    "atributos",
+    "atribuye",
+    "atribuyó",
+    "atrofia",
+    "atropella",
+    "atropellado",
+    "atropellar",
+    "atropelló",
+    "atrápelo",
+    "atrás",
+    "atte",
+    "atuendos",
+    "atzcapotzalco",
+    "atávicos",
+    "atípico",
+    "atómica",
+    "atómicas",
+    "atónitos",
+    "atópica",
+    "auburn",
+    "audacia",
+    "audaz",
+    "audelo",
+    "audicionar",
+    "audición",
+    "audiencia",
+    "audiovisuales",
+    "auditiva",
+    "auditivo",
+    "auditores",
+    "auditoria",
+    "auditorio",
+    "auditorium",
+    "auditoría",
+    "auditorías",
+    "audífonos",
+    "auge",
+    "augura",
+    "augurio",
+    "augurios",
+    "auguró",
+    "august",
+    "augusto",
+    "augustus",
+    "aula",
+    "aulas",
+    "aumenta",
+    "aumentaba",
+    "aumentado",
+    "aumentar",
+    "aumentara",
+    "aumentaron",
+    "aumentará",
+    "aumentarán",
+    "aumente",
+    "aumenten",
+    "aumento",
+    "aumentos",
+    "aumentó",
+    "aun",
+    "aunado",
+    "aunque",
+    "aurelio",
+    "aureola",
+    "auriazul",
+    "aurio",
+    "aurora",
+    "aurrera",
+    "aurreras",
+    "aurrerá",
+    "ausencia",
+    "ausentarse",
+    "ausente",
+    "ausentes",
+    "ausentismo",
+    "auspicia",
+    "auspiciadoras",
+    "auspiciará",
+    "auspicio",
+    "auspicios",
+    "austeras",
+    "austeridad",
+    "austero",
+    "austeros",
+    "austin",
+    "australia",
+    "australian",
+    "australianos",
+    "austriacos",
+    "autoanálisis",
+    "autobalance",
+    "autobiografía",
+    "autobuses",
+    "autobús",
+    "autocalifica",
+    "autocalificación",
+    "autocalificada",
+    "autocalificar",
+    "autocontrol",
+    "autocrítica",
+    "autoestima",
+    "autoevaluación",
+    "autofinanciamiento",
+    "autofinanciamientos",
+    "autogol",
+    "automatización",
+    "automatizado",
+    "automatizados",
+    "automotor",
+    "automotores",
+    "automotrices",
+    "automotriz",
+    "automovil",
+    "automoviles",
+    "automovilista",
+    "automovilistas",
+    "automovilística",
+    "automovilístico",
+    "automovilísticos",
+    "automática",
+    "automáticamente",
+    "automáticas",
+    "automático",
+    "automóvil",
+    "automóviles",
+    "autonomía",
+    "autopartes",
+    "autopista",
+    "autopistas",
+    "autopsia",
+    "autor",
+    "autora",
+    "autoregulación",
+    "autores",
+    "autorice",
+    "autoridad",
+    "autoridades",
+    "autoritaria",
+    "autoritario",
+    "autoritarismo",
+    "autoritarismos",
+    "autoriza",
+    "autorizaciones",
+    "autorización",
+    "autorizada",
+    "autorizadas",
+    "autorizado",
+    "autorizados",
+    "autorizan",
+    "autorizando",
+    "autorizar",
+    "autorizaran",
+    "autorizarse",
+    "autorizó",
+    "autos",
+    "autoservicio",
+    "autoservicios",
+    "autosuficiencia",
+    "autosuficientes",
+    "autotanques",
+    "autotransporte",
+    "autotransportistas",
+    "autrey",
+    "autárticos",
+    "auténticamente",
+    "auténtico",
+    "auténticos",
+    "autódromo",
+    "autógrafos",
+    "autónoma",
+    "autónomo",
+    "autónomos",
+    "auxilia",
+    "auxiliado",
+    "auxiliados",
+    "auxiliando",
+    "auxiliar",
+    "auxiliares",
+    "auxiliarla",
+    "auxiliaron",
+    "auxilio",
+    "auxilios",
+    "av",
+    "aval",
+    "avala",
+    "avalaban",
+    "avaladas",
+    "avalan",
+    "avalanchas",
+    "avalando",
+    "avalar",
+    "avalaremos",
+    "avalaron",
+    "avale",
+    "avalúo",
+    "avalúos",
+    "avance",
+    "avances",
+    "avanza",
+    "avanzaba",
+    "avanzada",
+    "avanzadas",
+    "avanzado",
+    "avanzados",
+    "avanzan",
+    "avanzando",
+    "avanzar",
+    "avanzaron",
+    "avanzarán",
+    "avanzó",
+    "avaro",
+    "ave",
+    "avecina",
+    "avecinado",
+    "avelar",
+    "avelina",
+    "avenida",
+    "avenidas",
+    "aventaba",
+    "aventaja",
+    "aventura",
+    "aventuradas",
+    "aventurado",
+    "aventuras",
+    "aventó",
+    "avergonzado",
+    "avergüencen",
+    "averiguaciones",
+    "averiguación",
+    "averiguando",
+    "averiguar",
+    "averió",
+    "averoes",
+    "aves",
+    "avestruz",
+    "aviación",
+    "aviacsa",
+    "aviadurismo",
+    "avidez",
+    "avientan",
+    "avila",
+    "avilán",
+    "avilés",
+    "aviones",
+    "avisamos",
+    "avisarles",
+    "avisaron",
+    "avisará",
+    "aviso",
+    "avisora",
+    "avisó",
+    "avión",
+    "avocara",
+    "avío",
+    "awards",
+    "axilar",
+    "ay",
+    "ayacucho",
+    "ayala",
+    "ayarzagoitia",
+    "ayense",
+    "ayer",
+    "ayuda",
+    "ayudaba",
+    "ayudada",
+    "ayudado",
+    "ayudados",
+    "ayudamos",
+    "ayudan",
+    "ayudando",
+    "ayudante",
+    "ayudantes",
+    "ayudar",
+    "ayudara",
+    "ayudaran",
+    "ayudarle",
+    "ayudarlo",
+    "ayudarlos",
+    "ayudaron",
+    "ayudará",
+    "ayudaría",
+    "ayude",
+    "ayuden",
+    "ayudándole",
+    "ayudándose",
+    "ayudó",
+    "ayuntamiento",
+    "ayuntamientos",
+    "azalia",
+    "azar",
+    "azcua",
+    "azcárraga",
+    "azhamar",
+    "azido",
+    "azorrillan",
+    "azotaba",
+    "azotado",
+    "azotando",
+    "azteca",
+    "aztecas",
+    "aztlán",
+    "azucar",
+    "azucena",
+    "azufre",
+    "azul",
+    "azules",
+    "azulgrana",
+    "azulosos",
+    "azuma",
+    "azumah",
+    "azáleas",
+    "azúcar",
+    "azúcares",
+    "azúl",
+    "aérea",
+    "aéreas",
+    "aéreo",
+    "aéreos",
+    "aída",
+    "añade",
+    "añadido",
+    "añadiendo",
+    "añadieron",
+    "añadió",
+    "año",
+    "años",
+    "añosfueron",
+    "aún",
+    "b",
+    "bab",
+    "babalucas",
+    "babilonia",
+    "baby",
+    "baccantes",
+    "bacco",
+    "bacheando",
+    "bachilleres",
+    "bacteria",
+    "badmington",
+    "bae",
+    "bafsa",
+    "bagaje",
+    "bagdad",
+    "bahamas",
+    "bahía",
+    "baila",
+    "bailable",
+    "bailando",
+    "bailar",
+    "bailarines",
+    "bailarlos",
+    "baile",
+    "bailes",
+    "bailleres",
+    "baja",
+    "bajacaliforniano",
+    "bajadas",
+    "bajado",
+    "bajan",
+    "bajando",
+    "bajar",
+    "bajarle",
+    "bajaron",
+    "bajarse",
+    "bajará",
+    "bajas",
+    "baje",
+    "bajista",
+    "bajo",
+    "bajonazo",
+    "bajos",
+    "bajío",
+    "bajó",
+    "bala",
+    "balaceados",
+    "balacearlo",
+    "balacera",
+    "balaceras",
+    "balaceó",
+    "baladas",
+    "balanceadas",
+    "balanceado",
+    "balancear",
+    "balanza",
+    "balas",
+    "balazo",
+    "balazos",
+    "balbina",
+    "balcanes",
+    "balcones",
+    "balcón",
+    "balderas",
+    "baldomero",
+    "baldío",
+    "baleada",
+    "ball",
+    "ballard",
+    "ballena",
+    "ballet",
+    "ballén",
+    "balompié",
+    "balones",
+    "balsa",
+    "baltazar",
+    "baltimore",
+    "baluarte",
+    "balística",
+    "balón",
+    "bamba",
+    "bambalinas",
+    "banamex",
+    "bananero",
+    "banca",
+    "bancaria",
+    "bancarias",
+    "bancario",
+    "bancarios",
+    "bancas",
+    "banco",
+    "bancomer",
+    "bancomext",
+    "bancos",
+    "bancrecer",
+    "band",
+    "banda",
+    "bandas",
+    "bandazos",
+    "bandeja",
+    "bandera",
+    "banderas",
+    "banderazo",
+    "banderillas",
+    "banderillero",
+    "banderita",
+    "banderín",
+    "bandidos",
+    "bando",
+    "bandos",
+    "bankers",
+    "banking",
+    "bankshares",
+    "banoro",
+    "banpaís",
+    "banquero",
+    "banqueros",
+    "banqueta",
+    "banquetas",
+    "banquillo",
+    "banrural",
+    "banxico",
+    "barahona",
+    "barajas",
+    "baranda",
+    "barandal",
+    "barandilla",
+    "barata",
+    "baratas",
+    "barato",
+    "barba",
+    "barbacoa",
+    "barbados",
+    "barbarabbe",
+    "barbaridades",
+    "barbarie",
+    "barberena",
+    "barbosa",
+    "barcelona",
+    "barceloneses",
+    "barco",
+    "barcos",
+    "barda",
+    "bardas",
+    "bares",
+    "barinas",
+    "barocio",
+    "barra",
+    "barragán",
+    "barranca",
+    "barranco",
+    "barras",
+    "barre",
+    "barreira",
+    "barrenechea",
+    "barrera",
+    "barreras",
+    "barrerse",
+    "barres",
+    "barreto",
+    "barrett",
+    "barrial",
+    "barrientos",
+    "barrileras",
+    "barriles",
+    "barrio",
+    "barrios",
+    "barriéndose",
+    "barro",
+    "barroca",
+    "barroco",
+    "barros",
+    "barrote",
+    "barry",
+    "barría",
+    "bart",
+    "bartlett",
+    "bartletts",
+    "bartolo",
+    "barón",
+    "basa",
+    "basaba",
+    "basada",
+    "basadas",
+    "basado",
+    "basados",
+    "basamos",
+    "basar",
+    "basarse",
+    "basará",
+    "basarán",
+    "basave",
+    "basay",
+    "basañez",
+    "basch",
+    "basen",
+    "basicas",
+    "basile",
+    "basilio",
+    "basquetbol",
+    "basset",
+    "basta",
+    "bastado",
+    "bastan",
+    "bastante",
+    "bastantes",
+    "bastaría",
+    "baste",
+    "bastó",
+    "basualdo",
+    "basura",
+    "basuras",
+    "basurero",
+    "basureros",
+    "basándose",
+    "basáñez",
+    "basílica",
+    "basó",
+    "bata",
+    "batalla",
+    "batallan",
+    "batallar",
+    "batallaron",
+    "batallas",
+    "batallen",
+    "batalló",
+    "batallón",
+    "batas",
+    "bate",
+    "bateador",
+    "bateadores",
+    "bateo",
+    "baterista",
+    "batería",
+    "baterías",
+    "bateó",
+    "batieron",
+    "batir",
+    "batis",
+    "batistuta",
+    "batocletti",
+    "battaglia",
+    "baudelaire",
+    "bautista",
+    "bautizado",
+    "bautizaría",
+    "bautizo",
+    "bautizos",
+    "bayerische",
+    "baz",
+    "bazaine",
+    "bazaldúa",
+    "bazar",
+    "bazdresch",
+    "bazán",
+    "baña",
+    "bañada",
+    "bañan",
+    "bañar",
+    "bañarse",
+    "baño",
+    "baños",
+    "bc",
+    "bch",
+    "beach",
+    "beamish",
+    "beatriz",
+    "beauvais",
+    "beauvaisis",
+    "beaver",
+    "bebedero",
+    "beber",
+    "bebeto",
+    "bebidas",
+    "bebita",
+    "bebió",
+    "bebop",
+    "bebé",
+    "beca",
+    "becado",
+    "becario",
+    "becarios",
+    "becas",
+    "becerra",
+    "becerril",
+    "becker",
+    "becthel",
+    "bedoya",
+    "beethoven",
+    "begiristain",
+    "begonia",
+    "begoña",
+    "beige",
+    "beirut",
+    "beisbol",
+    "beisbolero",
+    "beisbolistas",
+    "beisbolísticos",
+    "beisborama",
+    "bejarano",
+    "belair",
+    "belaúnde",
+    "belden",
+    "belga",
+    "belgas",
+    "belice",
+    "beliceño",
+    "belicosos",
+    "bella",
+    "bellamente",
+    "bellas",
+    "beller",
+    "belleza",
+    "bello",
+    "bellone",
+    "belmont",
+    "belén",
+    "benavente",
+    "benavides",
+    "benchmarking",
+    "bend",
+    "bendesky",
+    "bendiciones",
+    "bendición",
+    "bendijo",
+    "bendita",
+    "bendito",
+    "beneficencia",
+    "beneficia",
+    "beneficiada",
+    "beneficiado",
+    "beneficiados",
+    "benefician",
+    "beneficiando",
+    "beneficiar",
+    "beneficiaria",
+    "beneficiará",
+    "beneficiarán",
+    "beneficiaría",
+    "beneficien",
+    "beneficio",
+    "beneficios",
+    "beneficiosas",
+    "beneficioso",
+    "benefició",
+    "beneplácito",
+    "benevolencia",
+    "bengoa",
+    "benigno",
+    "benito",
+    "benjamín",
+    "bennis",
+    "benotto",
+    "benz",
+    "benzeno",
+    "benítez",
+    "berchelmann",
+    "beregovoy",
+    "berenice",
+    "beresford",
+    "bergerac",
+    "berges",
+    "beria",
+    "berlanga",
+    "berlinguer",
+    "berlín",
+    "berman",
+    "bermudas",
+    "bermúdez",
+    "bernabe",
+    "bernabeu",
+    "bernabé",
+    "bernal",
+    "bernanos",
+    "bernard",
+    "bernardino",
+    "bernardo",
+    "bernuncio",
+    "berrinche",
+    "berrinchito",
+    "berrueto",
+    "berta",
+    "berumen",
+    "besando",
+    "besar",
+    "besara",
+    "besarla",
+    "besarle",
+    "bese",
+    "beso",
+    "bestia",
+    "bestias",
+    "besó",
+    "betancourt",
+    "betania",
+    "bi",
+    "bianchezi",
+    "bianchini",
+    "biberón",
+    "bibi",
+    "biblia",
+    "biblias",
+    "bibliografía",
+    "bibliográficas",
+    "bibliográfico",
+    "biblioteca",
+    "bibliotecas",
+    "bicampeón",
+    "bicentenario",
+    "bichos",
+    "bicicleta",
+    "bicicletas",
+    "bien",
+    "bienal",
+    "bienales",
+    "bienes",
+    "bienestar",
+    "bienio",
+    "bienvenida",
+    "bienvenidas",
+    "bienvenido",
+    "bienvenidos",
+    "bierhaus",
+    "bigfoot",
+    "bigleaguers",
+    "bigote",
+    "bigotes",
+    "bike",
+    "bilardo",
+    "bilateral",
+    "bilaterales",
+    "bilateralmente",
+    "bilbao",
+    "bilimek",
+    "billar",
+    "billete",
+    "billetes",
+    "billones",
+    "bills",
+    "billy",
+    "billón",
+    "binacional",
+    "bing",
+    "biodegradable",
+    "biodegradables",
+    "biología",
+    "biológica",
+    "biológico",
+    "biondi",
+    "biotecnología",
+    "biotecnológica",
+    "bipolar",
+    "birks",
+    "birlos",
+    "bis",
+    "bisabuela",
+    "biscaine",
+    "bismarck",
+    "bissonnette",
+    "biznietos",
+    "biólogo",
+    "bióticos",
+    "bjoern",
+    "blackhawks",
+    "bladimir",
+    "blaise",
+    "blanca",
+    "blancas",
+    "blanco",
+    "blancos",
+    "blancq",
+    "blandir",
+    "blando",
+    "blanquiazul",
+    "blanquiazules",
+    "blanquita",
+    "blas",
+    "blasa",
+    "blasco",
+    "blasio",
+    "blasón",
+    "blatidos",
+    "blocker",
+    "bloque",
+    "bloquea",
+    "bloqueadas",
+    "bloqueado",
+    "bloqueadoras",
+    "bloqueados",
+    "bloquean",
+    "bloqueando",
+    "bloquear",
+    "bloquearon",
+    "bloqueo",
+    "bloqueos",
+    "bloques",
+    "bloxcom",
+    "blues",
+    "bm",
+    "bmw",
+    "bob",
+    "bobby",
+    "bobina",
+    "boca",
+    "bocanadas",
+    "bocanegra",
+    "bocas",
+    "bochini",
+    "bocho",
+    "bochornos",
+    "bochos",
+    "bocina",
+    "bocinas",
+    "boda",
+    "bodas",
+    "bodega",
+    "bodegas",
+    "bofetón",
+    "boga",
+    "bogota",
+    "bogotá",
+    "bogue",
+    "bohemio",
+    "boicot",
+    "boicotearlas",
+    "boinas",
+    "bola",
+    "bolas",
+    "bolaños",
+    "boleados",
+    "boleando",
+    "boleo",
+    "boleta",
+    "boletas",
+    "boletines",
+    "boleto",
+    "boletos",
+    "boletín",
+    "bolillo",
+    "bolillos",
+    "bolitas",
+    "bolivar",
+    "bolivia",
+    "boloña",
+    "bolsa",
+    "bolsas",
+    "bolsillo",
+    "bolsillos",
+    "bolso",
+    "bolívar",
+    "bomba",
+    "bombardeado",
+    "bombardeos",
+    "bombas",
+    "bombear",
+    "bombeo",
+    "bomberos",
+    "bondades",
+    "bondadosas",
+    "bondadoso",
+    "bone",
+    "bonifacio",
+    "bonilla",
+    "bonita",
+    "bonitas",
+    "bonito",
+    "bonitos",
+    "bonn",
+    "bonos",
+    "boom",
+    "boomers",
+    "borax",
+    "bordadores",
+    "bordados",
+    "borde",
+    "bordo",
+    "bordos",
+    "borge",
+    "boricua",
+    "boris",
+    "borja",
+    "borla",
+    "bormann",
+    "borrables",
+    "borrachito",
+    "borracho",
+    "borrachín",
+    "borrador",
+    "borraja",
+    "borrando",
+    "borrar",
+    "borrego",
+    "borregos",
+    "borreguitos",
+    "borrero",
+    "borroso",
+    "borsalino",
+    "borssum",
+    "bosch",
+    "boscosos",
+    "bosnia",
+    "bosnio",
+    "bosque",
+    "bosquejarse",
+    "bosques",
+    "bossier",
+    "boston",
+    "bostoniano",
+    "bota",
+    "botana",
+    "botanero",
+    "botaneros",
+    "botas",
+    "bote",
+    "botella",
+    "botellas",
+    "botello",
+    "botes",
+    "botines",
+    "botones",
+    "botín",
+    "botón",
+    "bougthon",
+    "boulevard",
+    "bourbon",
+    "bours",
+    "boutique",
+    "boutiques",
+    "boutros",
+    "bovary",
+    "bovino",
+    "bowman",
+    "bowthorpe",
+    "boxeador",
+    "boxeadores",
+    "boxeo",
+    "boxeó",
+    "boxística",
+    "boyas",
+    "boyle",
+    "bracamontes",
+    "braceaba",
+    "bracho",
+    "bradenton",
+    "brambila",
+    "branco",
+    "brando",
+    "brandt",
+    "brasero",
+    "brasil",
+    "brasileira",
+    "brasileña",
+    "brasileño",
+    "brasileños",
+    "brasilia",
+    "brass",
+    "brasso",
+    "braun",
+    "bravo",
+    "bravos",
+    "bravura",
+    "brazo",
+    "brazos",
+    "brazzaville",
+    "breaky",
+    "brecha",
+    "brechas",
+    "bremer",
+    "bremond",
+    "brener",
+    "bretaña",
+    "breton",
+    "bretón",
+    "breve",
+    "brevedad",
+    "brevemente",
+    "breves",
+    "brevísima",
+    "brian",
+    "bridan",
+    "brigada",
+    "brigadas",
+    "brillaban",
+    "brillante",
+    "brillantes",
+    "brillantez",
+    "brillo",
+    "brinca",
+    "brincar",
+    "brincarse",
+    "brinckerhoff",
+    "brincó",
+    "brinda",
+    "brindado",
+    "brindar",
+    "brindarle",
+    "brindarles",
+    "brindarme",
+    "brindaron",
+    "brindará",
+    "brindaría",
+    "brinde",
+    "brinden",
+    "brindó",
+    "bringas",
+    "briones",
+    "briosos",
+    "brisas",
+    "britania",
+    "british",
+    "brito",
+    "británica",
+    "británico",
+    "británicos",
+    "brizio",
+    "brocha",
+    "broche",
+    "brock",
+    "brocker",
+    "broma",
+    "bromas",
+    "bromeaban",
+    "bromeando",
+    "bromista",
+    "bromosas",
+    "brompton",
+    "bronca",
+    "broncas",
+    "bronce",
+    "bronco",
+    "broncos",
+    "broncón",
+    "bronquitis",
+    "brooks",
+    "bros",
+    "brotan",
+    "brotaron",
+    "brothers",
+    "brownsville",
+    "bruce",
+    "bruces",
+    "brugera",
+    "bruja",
+    "brunas",
+    "brunch",
+    "bruno",
+    "brusca",
+    "bruscas",
+    "bruselas",
+    "brusquedad",
+    "brutal",
+    "brutales",
+    "brutalmente",
+    "bruto",
+    "brutos",
+    "bubka",
+    "bucareli",
+    "buckingham",
+    "bucolia",
+    "bud",
+    "buda",
+    "buddleia",
+    "buen",
+    "buena",
+    "buenas",
+    "buenaventura",
+    "buendía",
+    "bueno",
+    "buenos",
+    "buerón",
+    "buey",
+    "bueyes",
+    "bufferin",
+    "bugno",
+    "buho",
+    "buick",
+    "buitres",
+    "bulbos",
+    "bulgaria",
+    "bunbun",
+    "bunker",
+    "buque",
+    "buques",
+    "burel",
+    "burelo",
+    "burger",
+    "burgos",
+    "burgueño",
+    "burla",
+    "burlaba",
+    "burlan",
+    "burlando",
+    "burlar",
+    "burlarte",
+    "burlas",
+    "burló",
+    "burocracia",
+    "burocratismos",
+    "burocráticas",
+    "burocráticos",
+    "burro",
+    "burros",
+    "bursatil",
+    "bursatilidad",
+    "bursatilización",
+    "bursátil",
+    "bursátiles",
+    "burzatilización",
+    "burócrata",
+    "burócratas",
+    "busca",
+    "buscaba",
+    "buscaban",
+    "buscado",
+    "buscador",
+    "buscadores",
+    "buscados",
+    "buscamos",
+    "buscan",
+    "buscando",
+    "buscar",
+    "buscara",
+    "buscaran",
+    "buscaras",
+    "buscaremos",
+    "buscarla",
+    "buscarle",
+    "buscarlo",
+    "buscaron",
+    "buscarse",
+    "buscará",
+    "buscarán",
+    "buscaría",
+    "busco",
+    "buscó",
+    "bush",
+    "busque",
+    "busquen",
+    "bustamante",
+    "bustillos",
+    "bustinzer",
+    "busto",
+    "butacas",
+    "butano",
+    "butcher",
+    "butler",
+    "báez",
+    "bárbara",
+    "báscula",
+    "básculas",
+    "básica",
+    "básicamente",
+    "básicas",
+    "básico",
+    "básicos",
+    "bátik",
+    "bátiz",
+    "bélgica",
+    "bélica",
+    "bérgamo",
+    "bíblica",
+    "bíblico",
+    "bíceps",
+    "búfalo",
+    "búlgaro",
+    "búsqueda",
+    "búsquedas",
+    "búsquele",
+    "c",
+    "caad",
+    "cab",
+    "cabada",
+    "cabal",
+    "cabales",
+    "caballada",
+    "caballero",
+    "caballeros",
+    "caballo",
+    "caballos",
+    "cabalmente",
+    "cabanillas",
+    "cabaret",
+    "cabaña",
+    "cabañas",
+    "cabe",
+    "cabecillas",
+    "cabelleras",
+    "cabello",
+    "cabellos",
+    "cabestany",
+    "cabeza",
+    "cabida",
+    "cabiedes",
+    "cabildo",
+    "cabildos",
+    "cabina",
+    "cable",
+    "cableado",
+    "cables",
+    "cablevisión",
+    "cabo",
+    "cabos",
+    "cabras",
+    "cabrera",
+    "cabrito",
+    "cacallacas",
+    "cacao",
+    "cacareaban",
+    "cacerolas",
+    "cacería",
+    "cacerías",
+    "cacerío",
+    "caceríos",
+    "cachetearon",
+    "cachetes",
+    "caciques",
+    "caciquiles",
+    "cada",
+    "cadapa",
+    "cadena",
+    "cadenas",
+    "cadencia",
+    "cadereyta",
+    "cadete",
+    "cadetes",
+    "cadis",
+    "cadmio",
+    "caducidad",
+    "cadáver",
+    "cadáveres",
+    "cae",
+    "caemos",
+    "caen",
+    "caer",
+    "caerle",
+    "cafecito",
+    "cafetal",
+    "cafeteros",
+    "cafetería",
+    "café",
+    "cafés",
+    "cafú",
+    "caifanes",
+    "caigan",
+    "caintra",
+    "caja",
+    "cajal",
+    "cajas",
+    "cajuela",
+    "cajón",
+    "cala",
+    "calabaza",
+    "calamidades",
+    "calcamonía",
+    "calcamonías",
+    "calcetines",
+    "calcinado",
+    "calcomanía",
+    "calcula",
+    "calculadas",
+    "calculado",
+    "calculadora",
+    "calculadoras",
+    "calculando",
+    "calcular",
+    "calcularon",
+    "calcule",
+    "calculo",
+    "calculó",
+    "calcúlele",
+    "caldeado",
+    "caldeen",
+    "caldera",
+    "calderoni",
+    "calderón",
+    "caldillo",
+    "calefacción",
+    "calendario",
+    "calendarios",
+    "calentamiento",
+    "calentando",
+    "calentar",
+    "calentura",
+    "calgene",
+    "calibración",
+    "calibradas",
+    "calibrar",
+    "calibre",
+    "calidad",
+    "calidades",
+    "calientan",
+    "caliente",
+    "calientes",
+    "califica",
+    "calificaciones",
+    "calificación",
+    "calificada",
+    "calificadas",
+    "calificado",
+    "calificadora",
+    "calificados",
+    "calificamos",
+    "califican",
+    "calificar",
+    "calificaron",
+    "calificará",
+    "calificaría",
+    "calificativo",
+    "calificativos",
+    "calificó",
+    "califique",
+    "california",
+    "californiano",
+    "californias",
+    "caligula",
+    "calla",
+    "callada",
+    "calladito",
+    "callado",
+    "callar",
+    "callarlo",
+    "calle",
+    "callejera",
+    "callejeros",
+    "callejoneadas",
+    "calles",
+    "calloway",
+    "calma",
+    "calmar",
+    "calmarla",
+    "calo",
+    "caloca",
+    "calor",
+    "calpan",
+    "calpixques",
+    "calpullis",
+    "calumnia",
+    "calumnias",
+    "caluroso",
+    "calvillo",
+    "calzada",
+    "calzado",
+    "calzoncillo",
+    "calígula",
+    "cama",
+    "camacho",
+    "camaleónica",
+    "camaleónico",
+    "camaradería",
+    "camarero",
+    "camareros",
+    "camargo",
+    "camarlengo",
+    "camarote",
+    "camarón",
+    "camas",
+    "cambia",
+    "cambiado",
+    "cambiamos",
+    "cambian",
+    "cambiando",
+    "cambiante",
+    "cambiantes",
+    "cambiar",
+    "cambiara",
+    "cambiarias",
+    "cambiario",
+    "cambiarle",
+    "cambiarlos",
+    "cambiaron",
+    "cambiará",
+    "cambiaría",
+    "cambiarían",
+    "cambie",
+    "cambio",
+    "cambios",
+    "cambié",
+    "cambió",
+    "cambridge",
+    "camdomble",
+    "camelia",
+    "camellones",
+    "camellón",
+    "camelo",
+    "camerino",
+    "camet",
+    "camexa",
+    "caminaba",
+    "caminaban",
+    "caminan",
+    "caminando",
+    "caminar",
+    "caminaron",
+    "caminata",
+    "camine",
+    "camino",
+    "caminos",
+    "caminó",
+    "camioneras",
+    "camioneros",
+    "camiones",
+    "camioneta",
+    "camionetas",
+    "camisa",
+    "camiseta",
+    "camisetas",
+    "camisitas",
+    "camisones",
+    "camisón",
+    "camión",
+    "camorristas",
+    "camotlán",
+    "camp",
+    "campal",
+    "campamento",
+    "campamentos",
+    "campaña",
+    "campañas",
+    "campechanos",
+    "campeche",
+    "campeon",
+    "campeona",
+    "campeonato",
+    "campeonatos",
+    "campeones",
+    "campesina",
+    "campesinos",
+    "campestre",
+    "campeón",
+    "campo",
+    "campos",
+    "camposeco",
+    "campus",
+    "campuzano",
+    "camuflajes",
+    "canacintra",
+    "canaco",
+    "canada",
+    "canadian",
+    "canadiense",
+    "canadienses",
+    "canadá",
+    "canal",
+    "canalera",
+    "canalero",
+    "canales",
+    "canalicen",
+    "canalización",
+    "canalizado",
+    "canalizados",
+    "canalizando",
+    "canalizara",
+    "canapés",
+    "canasta",
+    "canasto",
+    "cancela",
+    "cancelaba",
+    "cancelación",
+    "cancelada",
+    "cancelados",
+    "cancelando",
+    "cancelar",
+    "cancelarán",
+    "canceló",
+    "cancha",
+    "canchas",
+    "canciller",
+    "cancilleres",
+    "cancillería",
+    "cancioncita",
+    "canciones",
+    "canción",
+    "cancun",
+    "cancún",
+    "candelaria",
+    "candelero",
+    "candentes",
+    "candidata",
+    "candidato",
+    "candidatos",
+    "candidatura",
+    "candidaturas",
+    "candidiasis",
+    "candil",
+    "candor",
+    "candy",
+    "canela",
+    "canels",
+    "caniggia",
+    "canijo",
+    "canion",
+    "canje",
+    "cano",
+    "canoga",
+    "canolas",
+    "cansada",
+    "cansadas",
+    "cansado",
+    "cansados",
+    "cansancio",
+    "canseco",
+    "canta",
+    "cantaba",
+    "cantaban",
+    "cantan",
+    "cantando",
+    "cantante",
+    "cantantes",
+    "cantar",
+    "cantaron",
+    "cantera",
+    "cantería",
+    "cantidad",
+    "cantidades",
+    "cantina",
+    "cantinas",
+    "cantinflas",
+    "canto",
+    "cantos",
+    "cantó",
+    "cantú",
+    "caníbales",
+    "caoba",
+    "caona",
+    "caos",
+    "capa",
+    "capaces",
+    "capacidad",
+    "capacidades",
+    "capacita",
+    "capacitación",
+    "capacitada",
+    "capacitado",
+    "capacitamos",
+    "capacitan",
+    "capacitando",
+    "capacitar",
+    "capacitarse",
+    "capas",
+    "capatacio",
+    "capaz",
+    "caperucita",
+    "capetillo",
+    "capfce",
+    "capilla",
+    "capitales",
+    "capitalina",
+    "capitalino",
+    "capitalinos",
+    "capitalista",
+    "capitalistas",
+    "capitalización",
+    "capitalizador",
+    "capitalizar",
+    "capitalizaron",
+    "capitanes",
+    "capitolio",
+    "capitonada",
+    "capitonadas",
+    "capitonados",
+    "capitulación",
+    "capitán",
+    "capo",
+    "caporal",
+    "capos",
+    "capote",
+    "cappel",
+    "capricho",
+    "caprichos",
+    "caprichosas",
+    "caprichosos",
+    "captación",
+    "captado",
+    "captamos",
+    "captar",
+    "captaron",
+    "captará",
+    "capten",
+    "captivos",
+    "captura",
+    "capturados",
+    "capturó",
+    "captó",
+    "capucha",
+    "capítulo",
+    "capítulos",
+    "cara",
+    "caracas",
+    "caracol",
+    "caracteres",
+    "caracteriza",
+    "caracterizadas",
+    "caracterizado",
+    "caracterizan",
+    "caracterizar",
+    "caracterizaron",
+    "caracterizó",
+    "característica",
+    "características",
+    "característicos",
+    "carajo",
+    "caramona",
+    "caraqueña",
+    "caras",
+    "caravana",
+    "caravanas",
+    "caravia",
+    "caray",
+    "carbajal",
+    "carballido",
+    "carballo",
+    "carbonel",
+    "carbono",
+    "carbonáceo",
+    "carburador",
+    "carbón",
+    "carcajada",
+    "carcajadas",
+    "carcaño",
+    "carcomido",
+    "cardenal",
+    "cardenales",
+    "cardenismo",
+    "cardenista",
+    "cardenistas",
+    "cardiaca",
+    "cardiaco",
+    "cardiología",
+    "cardiopulmonar",
+    "cardiólogo",
+    "cardona",
+    "cardán",
+    "cardíaca",
+    "cardíaco",
+    "cardíacos",
+    "careca",
+    "carece",
+    "carecen",
+    "carecer",
+    "careció",
+    "carecía",
+    "carecían",
+    "carencia",
+    "carencias",
+    "carente",
+    "carentes",
+    "careo",
+    "carga",
+    "cargada",
+    "cargadas",
+    "cargado",
+    "cargados",
+    "cargamento",
+    "cargamentos",
+    "cargando",
+    "cargar",
+    "cargaran",
+    "cargarle",
+    "cargaron",
+    "cargas",
+    "cargos",
+    "cargueros",
+    "cargó",
+    "carha",
+    "caribe",
+    "caribeña",
+    "caribeño",
+    "caribeños",
+    "caricatura",
+    "caricaturas",
+    "caricias",
+    "caricom",
+    "caridad",
+    "carina",
+    "carioca",
+    "cariotipos",
+    "carisma",
+    "carismático",
+    "caritativas",
+    "cariño",
+    "cariñosidad",
+    "cariñoso",
+    "carl",
+    "carla",
+    "carlitos",
+    "carlos",
+    "carlota",
+    "carman",
+    "carmen",
+    "carmona",
+    "carnaval",
+    "carnavaleros",
+    "carnavales",
+    "carnavalescas",
+    "carnavalito",
+    "carne",
+    "carneros",
+    "carnes",
+    "carnestolendas",
+    "carnet",
+    "carnicero",
+    "carnicería",
+    "caro",
+    "carolina",
+    "caronte",
+    "caros",
+    "carpa",
+    "carpera",
+    "carpeta",
+    "carpintería",
+    "carpizo",
+    "carr",
+    "carrancismo",
+    "carrancistas",
+    "carranza",
+    "carrasquel",
+    "carrera",
+    "carreras",
+    "carrero",
+    "carreta",
+    "carretera",
+    "carreteras",
+    "carretero",
+    "carreteros",
+    "carretón",
+    "carreño",
+    "carreón",
+    "carrier",
+    "carriles",
+    "carrillo",
+    "carrington",
+    "carrito",
+    "carrizalejo",
+    "carrizales",
+    "carrión",
+    "carro",
+    "carrocería",
+    "carrocerías",
+    "carros",
+    "carroza",
+    "carrozas",
+    "carruaje",
+    "carruajes",
+    "carrujo",
+    "carrujos",
+    "carso",
+    "carta",
+    "cartagena",
+    "cartas",
+    "cartay",
+    "cartel",
+    "cartelera",
+    "carteles",
+    "cartelones",
+    "carter",
+    "cartera",
+    "carteras",
+    "cartilla",
+    "cartucho",
+    "cartón",
+    "carvajal",
+    "carácter",
+    "carátula",
+    "casa",
+    "casaca",
+    "casada",
+    "casadas",
+    "casado",
+    "casados",
+    "casamentero",
+    "casando",
+    "casarme",
+    "casaron",
+    "casarse",
+    "casas",
+    "casashabitación",
+    "casavan",
+    "cascabel",
+    "cascabeles",
+    "cascada",
+    "cascos",
+    "caseras",
+    "casero",
+    "caseros",
+    "caseta",
+    "casetas",
+    "cash",
+    "casi",
+    "casilla",
+    "casillas",
+    "casillero",
+    "casimir",
+    "casino",
+    "casio",
+    "casita",
+    "caso",
+    "casos",
+    "casquillo",
+    "casquillos",
+    "cassete",
+    "cassette",
+    "cassettes",
+    "casta",
+    "castañas",
+    "castañeda",
+    "castaño",
+    "castaños",
+    "castañón",
+    "castellano",
+    "castellanos",
+    "castidad",
+    "castiga",
+    "castigadas",
+    "castigados",
+    "castigar",
+    "castigará",
+    "castigo",
+    "castigue",
+    "castilla",
+    "castilleja",
+    "castillejos",
+    "castillo",
+    "castor",
+    "castores",
+    "castrejón",
+    "castro",
+    "castruita",
+    "casual",
+    "casuales",
+    "casualidad",
+    "casuística",
+    "casó",
+    "catalanes",
+    "catalina",
+    "catalizador",
+    "catalogan",
+    "catalán",
+    "catalítico",
+    "catalíticos",
+    "catamo",
+    "catarata",
+    "cataratas",
+    "catarina",
+    "catarro",
+    "catarros",
+    "catarsis",
+    "catastral",
+    "catastrales",
+    "catastro",
+    "catastrófica",
+    "cataño",
+    "cateadas",
+    "catedral",
+    "catedrática",
+    "catedrático",
+    "catedráticos",
+    "categoría",
+    "categorías",
+    "cateos",
+    "catequesis",
+    "catolicismo",
+    "catorce",
+    "catrerina",
+    "catsup",
+    "catálogo",
+    "catársis",
+    "catástrofe",
+    "católica",
+    "católico",
+    "católicos",
+    "catón",
+    "cauce",
+    "cauces",
+    "caucional",
+    "caudalosos",
+    "caudillo",
+    "causa",
+    "causada",
+    "causadas",
+    "causado",
+    "causados",
+    "causaefecto",
+    "causales",
+    "causan",
+    "causando",
+    "causante",
+    "causantes",
+    "causar",
+    "causaron",
+    "causará",
+    "causas",
+    "causó",
+    "cautela",
+    "cautelosa",
+    "cautivarlos",
+    "cautivas",
+    "cautivos",
+    "cavazos",
+    "cavernaria",
+    "cavidad",
+    "cayeran",
+    "cayeron",
+    "cayetano",
+    "cayman",
+    "cayo",
+    "cayó",
+    "caza",
+    "cazador",
+    "cazadoras",
+    "cazadores",
+    "cazar",
+    "cazaux",
+    "cazuela",
+    "caía",
+    "caída",
+    "caídas",
+    "caído",
+    "cañada",
+    "cañamar",
+    "cañedo",
+    "cañete",
+    "cañonazos",
+    "cañones",
+    "caón",
+    "caótica",
+    "cbinbur",
+    "cbs",
+    "cbtis",
+    "ccd",
+    "cce",
+    "ccinlac",
+    "ccolonia",
+    "ccp",
+    "cd",
+    "ce",
+    "cear",
+    "cebada",
+    "cebatis",
+    "cebolla",
+    "cebollas",
+    "cebrián",
+    "cecilia",
+    "ceda",
+    "cedart",
+    "cede",
+    "cedeco",
+    "ceder",
+    "cederán",
+    "cedería",
+    "cedeño",
+    "cedido",
+    "cedieron",
+    "cedió",
+    "cedral",
+    "cee",
+    "cegarse",
+    "ceja",
+    "celadora",
+    "celadores",
+    "celaya",
+    "celda",
+    "celebra",
+    "celebraba",
+    "celebraciones",
+    "celebración",
+    "celebrada",
+    "celebradas",
+    "celebrado",
+    "celebrados",
+    "celebran",
+    "celebrar",
+    "celebrarlo",
+    "celebraron",
+    "celebrarse",
+    "celebrará",
+    "celebre",
+    "celebridad",
+    "celebridades",
+    "celebró",
+    "celebérrima",
+    "celeridad",
+    "celestes",
+    "celestial",
+    "celia",
+    "celiberia",
+    "celio",
+    "celo",
+    "celos",
+    "celso",
+    "celta",
+    "celular",
+    "celulares",
+    "celébrelas",
+    "cemcaspe",
+    "cement",
+    "cementeras",
+    "cementero",
+    "cemento",
+    "cementos",
+    "cemex",
+    "cen",
+    "cena",
+    "cenamos",
+    "cenar",
+    "cenas",
+    "cenefa",
+    "cenefas",
+    "ceniza",
+    "cenizas",
+    "cenlac",
+    "censo",
+    "censura",
+    "censurable",
+    "censurada",
+    "censuradas",
+    "censurado",
+    "censurar",
+    "censuró",
+    "centavo",
+    "centavos",
+    "centenar",
+    "centenares",
+    "centenario",
+    "centeno",
+    "centradas",
+    "centrales",
+    "centramos",
+    "centrarse",
+    "centrará",
+    "centro",
+    "centroamericana",
+    "centroamericano",
+    "centroamericanos",
+    "centroamérica",
+    "centroderechista",
+    "centros",
+    "centrándose",
+    "centésimas",
+    "centígrados",
+    "centímetros",
+    "cenó",
+    "cepa",
+    "cepal",
+    "cepeda",
+    "cepillan",
+    "cepillo",
+    "cerca",
+    "cercados",
+    "cercana",
+    "cercanas",
+    "cercando",
+    "cercano",
+    "cercanos",
+    "cercanía",
+    "cercar",
+    "cerciorarse",
+    "cerda",
+    "cerdas",
+    "cerdo",
+    "cerdos",
+    "cereales",
+    "cerebral",
+    "cerebrales",
+    "cerebro",
+    "cerebros",
+    "ceremonia",
+    "ceremonias",
+    "ceremoniosamente",
+    "cereso",
+    "cereza",
+    "cero",
+    "cerraba",
+    "cerraban",
+    "cerrada",
+    "cerradas",
+    "cerrado",
+    "cerrados",
+    "cerradura",
+    "cerraduras",
+    "cerralvo",
+    "cerramos",
+    "cerrando",
+    "cerrar",
+    "cerrarlos",
+    "cerraron",
+    "cerrará",
+    "cerrarán",
+    "cerrazón",
+    "cerrito",
+    "cerro",
+    "cerrojo",
+    "cerros",
+    "cerró",
+    "certamen",
+    "certera",
+    "certero",
+    "certeros",
+    "certeza",
+    "certidumbre",
+    "certificado",
+    "certificados",
+    "certucha",
+    "cerval",
+    "cervantes",
+    "cervantino",
+    "cerveceras",
+    "cervecería",
+    "cervera",
+    "cerveza",
+    "cervezas",
+    "cerámica",
+    "cesa",
+    "cesados",
+    "cesan",
+    "cesar",
+    "cesará",
+    "cesaría",
+    "cese",
+    "cesión",
+    "cesto",
+    "cesáreo",
+    "cesó",
+    "cetemista",
+    "cetemistas",
+    "cetes",
+    "cetro",
+    "cetros",
+    "ceu",
+    "cevallos",
+    "cezanne",
+    "ceñido",
+    "cfe",
+    "ch",
+    "chagoyos",
+    "chagui",
+    "chal",
+    "chaleco",
+    "chalina",
+    "chalmer",
+    "chamaca",
+    "chamarra",
+    "chamarras",
+    "chamba",
+    "chambelanes",
+    "chamois",
+    "chamorro",
+    "champúes",
+    "chamula",
+    "chamuscado",
+    "chan",
+    "chanel",
+    "chango",
+    "chantilly",
+    "chanza",
+    "chapa",
+    "chapala",
+    "chapo",
+    "chapoteaderos",
+    "chapultepec",
+    "chapuzas",
+    "chaqueta",
+    "chara",
+    "charchina",
+    "charchino",
+    "charco",
+    "charla",
+    "charlaron",
+    "charlas",
+    "charlie",
+    "charly",
+    "charló",
+    "charnela",
+    "charnell",
+    "charreada",
+    "charreamos",
+    "charreando",
+    "charro",
+    "charros",
+    "chase",
+    "chasis",
+    "chatarra",
+    "chatarrero",
+    "chato",
+    "chaumu",
+    "chauvet",
+    "chavarría",
+    "chavas",
+    "chavero",
+    "chavez",
+    "chavitas",
+    "chavo",
+    "chavos",
+    "che",
+    "checa",
+    "checar",
+    "checo",
+    "checoslovaquia",
+    "chedraoui",
+    "chefs",
+    "cheliabinsk",
+    "chelo",
+    "chemical",
+    "chemicalweek",
+    "chemise",
+    "chepo",
+    "cheque",
+    "chequeo",
+    "cheques",
+    "cheraw",
+    "chesca",
+    "chester",
+    "chetumal",
+    "chetumaleños",
+    "chevrolet",
+    "cheyene",
+    "cheyenne",
+    "chi",
+    "chiapaneca",
+    "chiapas",
+    "chiba",
+    "chic",
+    "chica",
+    "chicago",
+    "chicana",
+    "chicano",
+    "chicas",
+    "chicharrones",
+    "chichen",
+    "chichimecas",
+    "chicles",
+    "chico",
+    "chicos",
+    "chicotear",
+    "chicuelinas",
+    "chido",
+    "chifón",
+    "chiguagua",
+    "chihuahua",
+    "chihuahuense",
+    "chila",
+    "chilac",
+    "chilacayota",
+    "chilangolandia",
+    "chilaquiles",
+    "chile",
+    "chileno",
+    "chilenos",
+    "chiles",
+    "chili",
+    "chilpancingo",
+    "chima",
+    "china",
+    "chinche",
+    "chinches",
+    "chino",
+    "chinos",
+    "chipinque",
+    "chips",
+    "chiquita",
+    "chirac",
+    "chirinos",
+    "chisec",
+    "chismosa",
+    "chispa",
+    "chispas",
+    "chispazo",
+    "chispeando",
+    "chistes",
+    "chiva",
+    "chivas",
+    "chivo",
+    "chiítas",
+    "chocado",
+    "chocan",
+    "chocar",
+    "chocaron",
+    "chocarán",
+    "chocolate",
+    "chocolates",
+    "chocó",
+    "chofer",
+    "choferes",
+    "chontalpa",
+    "chopin",
+    "choque",
+    "choques",
+    "chorizo",
+    "chorreando",
+    "chorro",
+    "chraysler",
+    "christi",
+    "christie",
+    "christoph",
+    "christopher",
+    "chrysler",
+    "chucho",
+    "chuck",
+    "chuletón",
+    "chung",
+    "chupa",
+    "chupaban",
+    "chupaletada",
+    "chupando",
+    "chuparse",
+    "chupándose",
+    "chupón",
+    "churubusco",
+    "churumbela",
+    "chusca",
+    "chyba",
+    "chávez",
+    "chía",
+    "cia",
+    "ciangherotti",
+    "cibaeñas",
+    "cibao",
+    "cicatrización",
+    "ciclismo",
+    "ciclista",
+    "ciclistas",
+    "ciclo",
+    "ciclos",
+    "ciclosporina",
+    "ciclístico",
+    "ciclísticos",
+    "ciclónica",
+    "cide",
+    "cielo",
+    "cien",
+    "ciencia",
+    "ciencias",
+    "ciento",
+    "cientos",
+    "científica",
+    "científicas",
+    "científico",
+    "científicos",
+    "ciernes",
+    "cierra",
+    "cierran",
+    "cierre",
+    "cierres",
+    "cierta",
+    "ciertamente",
+    "ciertas",
+    "cierto",
+    "ciertos",
+    "cifra",
+    "cifrarse",
+    "cifras",
+    "cigarrera",
+    "cigarrero",
+    "cigarrillos",
+    "cigarros",
+    "cihuatepixque",
+    "cilindros",
+    "cima",
+    "cimarrones",
+    "cimbraría",
+    "cimiento",
+    "cimientos",
+    "cin",
+    "cincelado",
+    "cinco",
+    "cincuenta",
+    "cine",
+    "cineasta",
+    "cinemascope",
+    "cinematografía",
+    "cinematográfica",
+    "cinematográficas",
+    "cinematográfico",
+    "cines",
+    "cineterraza",
+    "cinismo",
+    "cinta",
+    "cintas",
+    "cintermex",
+    "cinto",
+    "cintos",
+    "cintura",
+    "cinturones",
+    "cinturón",
+    "cinética",
+    "cio",
+    "cipriano",
+    "circo",
+    "circos",
+    "circuito",
+    "circuitos",
+    "circula",
+    "circulaba",
+    "circulaban",
+    "circulación",
+    "circulan",
+    "circulando",
+    "circularon",
+    "circulará",
+    "circularán",
+    "circularía",
+    "circulatoria",
+    "circulatorios",
+    "circuló",
+    "circunscribe",
+    "circunscrito",
+    "circunstancia",
+    "circunstancias",
+    "circunvalación",
+    "circurret",
+    "cirios",
+    "cirugía",
+    "cirujanos",
+    "cirules",
+    "cisneros",
+    "cita",
+    "citada",
+    "citadas",
+    "citado",
+    "citados",
+    "citamos",
+    "citan",
+    "citar",
+    "citará",
+    "citas",
+    "citation",
+    "citatorios",
+    "cite",
+    "citen",
+    "cito",
+    "citó",
+    "ciudad",
+    "ciudadana",
+    "ciudadanas",
+    "ciudadano",
+    "ciudadanos",
+    "ciudadanía",
+    "ciudadaníapanprd",
+    "ciudadanías",
+    "ciudades",
+    "civic",
+    "civil",
+    "civiles",
+    "civilizaciones",
+    "civilización",
+    "civilizada",
+    "civilizador",
+    "civilizadora",
+    "civilizar",
+    "civismo",
+    "ciénega",
+    "clair",
+    "clamor",
+    "clan",
+    "clandestinos",
+    "clanes",
+    "clapton",
+    "clara",
+    "claramente",
+    "claras",
+    "claridad",
+    "clarifiquen",
+    "clariond",
+    "clarividencia",
+    "clarke",
+    "claro",
+    "claros",
+    "claroscuros",
+    "clarísima",
+    "clase",
+    "clases",
+    "clasico",
+    "clasificaciones",
+    "clasificación",
+    "clasificada",
+    "clasificadas",
+    "clasificado",
+    "clasificados",
+    "clasificando",
+    "clasificar",
+    "clasificatorias",
+    "classic",
+    "claude",
+    "claudia",
+    "claudicar",
+    "claudico",
+    "claudio",
+    "claustro",
+    "clausura",
+    "clausurar",
+    "clausurará",
+    "clavada",
+    "clavar",
+    "clavaremos",
+    "clave",
+    "claves",
+    "clavó",
+    "claxon",
+    "cleary",
+    "clemente",
+    "cleotilde",
+    "clero",
+    "cliente",
+    "clientela",
+    "clientes",
+    "clima",
+    "climáticas",
+    "clinton",
+    "clos",
+    "closet",
+    "clouthier",
+    "clowes",
+    "clubes",
+    "clásica",
+    "clásicas",
+    "clásico",
+    "clásicos",
+    "cláusula",
+    "cláusulas",
+    "clérigo",
+    "clímax",
+    "clínica",
+    "clínicas",
+    "clínico",
+    "clínicos",
+    "cma",
+    "cmb",
+    "cmos",
+    "cms",
+    "cna",
+    "cnca",
+    "cndh",
+    "cnet",
+    "cnn",
+    "cnpa",
+    "cnsf",
+    "cnv",
+    "co",
+    "coach",
+    "coaches",
+    "coachman",
+    "coadyuvar",
+    "coah",
+    "coahuila",
+    "coahuilense",
+    "coahuilenses",
+    "coalición",
+    "coartados",
+    "coatepec",
+    "coatlicue",
+    "coautor",
+    "cobardía",
+    "cobertura",
+    "cobija",
+    "cobijas",
+    "cobol",
+    "cobos",
+    "cobra",
+    "cobraba",
+    "cobrado",
+    "cobramos",
+    "cobran",
+    "cobrando",
+    "cobrar",
+    "cobrara",
+    "cobraran",
+    "cobrarles",
+    "cobrarte",
+    "cobrarán",
+    "cobraré",
+    "cobras",
+    "cobre",
+    "cobren",
+    "cobro",
+    "cobros",
+    "cobró",
+    "cobán",
+    "coca",
+    "cocainómano",
+    "cocaína",
+    "coche",
+    "cochero",
+    "coches",
+    "cochinilla",
+    "cocina",
+    "cocinar",
+    "cocineta",
+    "coco",
+    "cocoa",
+    "cocoyoc",
+    "coctel",
+    "cocó",
+    "codeme",
+    "codesal",
+    "codicia",
+    "codiciado",
+    "codo",
+    "coece",
+    "coello",
+    "coempresario",
+    "coequipero",
+    "coercitivas",
+    "coexistan",
+    "coexistir",
+    "cofre",
+    "coger",
+    "coggi",
+    "cogido",
+    "cognitivo",
+    "cohabita",
+    "cohecho",
+    "cohen",
+    "coherencia",
+    "coherente",
+    "cohetes",
+    "cohetitos",
+    "coincide",
+    "coinciden",
+    "coincidencia",
+    "coincidencias",
+    "coincidentemente",
+    "coincidentes",
+    "coincidiera",
+    "coincidieron",
+    "coincidirán",
+    "coincidió",
+    "coinversiones",
+    "coinversión",
+    "coinvertir",
+    "cojo",
+    "cola",
+    "colabora",
+    "colaboración",
+    "colaborado",
+    "colaborador",
+    "colaboradores",
+    "colaboran",
+    "colaborando",
+    "colaborar",
+    "colaborara",
+    "colaboraran",
+    "colaboraron",
+    "colaboraría",
+    "colabore",
+    "colapsaron",
+    "colas",
+    "colcha",
+    "colchoneros",
+    "colchones",
+    "colchón",
+    "coldwell",
+    "cole",
+    "coleadero",
+    "colecciones",
+    "coleccionismo",
+    "coleccionista",
+    "coleccionistas",
+    "colección",
+    "colecta",
+    "colectiva",
+    "colectivamente",
+    "colectividad",
+    "colectivo",
+    "colectivos",
+    "colega",
+    "colegas",
+    "colegiado",
+    "colegiatura",
+    "colegio",
+    "colegios",
+    "colesterol",
+    "colgaba",
+    "colgaban",
+    "colgada",
+    "colgadas",
+    "colgado",
+    "colgados",
+    "colgó",
+    "colgón",
+    "colima",
+    "colin",
+    "colina",
+    "colinas",
+    "coliseo",
+    "collado",
+    "colleen",
+    "colleges",
+    "collin",
+    "collor",
+    "colmada",
+    "colmo",
+    "coloca",
+    "colocaciones",
+    "colocación",
+    "colocadas",
+    "colocado",
+    "colocadores",
+    "colocados",
+    "colocan",
+    "colocando",
+    "colocar",
+    "colocara",
+    "colocarla",
+    "colocarme",
+    "colocarnos",
+    "colocaron",
+    "colocarse",
+    "colocará",
+    "colocarán",
+    "colocándose",
+    "colocó",
+    "colofón",
+    "colombia",
+    "colombiano",
+    "colombianos",
+    "colonia",
+    "colonial",
+    "coloniales",
+    "colonias",
+    "colonizadora",
+    "colonizando",
+    "coloque",
+    "coloquialmente",
+    "coloquio",
+    "colorado",
+    "colorantes",
+    "colores",
+    "colorido",
+    "coloridos",
+    "colorísticos",
+    "colosio",
+    "coloso",
+    "colosos",
+    "coludidos",
+    "columbia",
+    "columna",
+    "columnabreves",
+    "columnaconsultorio",
+    "columnaempresa",
+    "columnafricase",
+    "columnafricasé",
+    "columnamirador",
+    "columnas",
+    "columneja",
+    "columnista",
+    "colunga",
+    "colín",
+    "colón",
+    "com",
+    "comadre",
+    "comadrita",
+    "comanda",
+    "comandado",
+    "comandados",
+    "comandancia",
+    "comandante",
+    "comande",
+    "comandos",
+    "comandó",
+    "comas",
+    "combate",
+    "combaten",
+    "combates",
+    "combatido",
+    "combatir",
+    "combatirán",
+    "combatividad",
+    "combativo",
+    "combatió",
+    "combina",
+    "combinaciones",
+    "combinación",
+    "combinada",
+    "combinadas",
+    "combinado",
+    "combinados",
+    "combinan",
+    "combinando",
+    "combinar",
+    "combinarlo",
+    "combinarlos",
+    "combinará",
+    "combinen",
+    "combis",
+    "combustible",
+    "combustibles",
+    "combustión",
+    "comedia",
+    "comediante",
+    "comediógrafo",
+    "comedor",
+    "comedores",
+    "comemos",
+    "comensal",
+    "comenta",
+    "comentaba",
+    "comentaban",
+    "comentabas",
+    "comentada",
+    "comentadas",
+    "comentado",
+    "comentan",
+    "comentando",
+    "comentar",
+    "comentario",
+    "comentarios",
+    "comentaristas",
+    "comentarla",
+    "comentarle",
+    "comentaron",
+    "comentarse",
+    "comentaré",
+    "comente",
+    "comentábamos",
+    "comentó",
+    "comenzaba",
+    "comenzaban",
+    "comenzado",
+    "comenzamos",
+    "comenzar",
+    "comenzara",
+    "comenzaremos",
+    "comenzaron",
+    "comenzará",
+    "comenzarán",
+    "comenzaré",
+    "comenzó",
+    "comer",
+    "comerciaba",
+    "comercial",
+    "comerciales",
+    "comercializa",
+    "comercialización",
+    "comercializadora",
+    "comercializadoras",
+    "comercializan",
+    "comercializar",
+    "comercializarlo",
+    "comercializaría",
+    "comercialmente",
+    "comerciante",
+    "comerciantes",
+    "comerciar",
+    "comercio",
+    "comercios",
+    "comernos",
+    "comerá",
+    "cometa",
+    "cometan",
+    "cometarios",
+    "cometas",
+    "comete",
+    "cometemos",
+    "cometen",
+    "cometer",
+    "cometerse",
+    "cometeríamos",
+    "cometidas",
+    "cometido",
+    "cometidos",
+    "cometiendo",
+    "cometiera",
+    "cometieron",
+    "cometió",
+    "cometía",
+    "comezón",
+    "comicios",
+    "comida",
+    "comidas",
+    "comience",
+    "comiencen",
+    "comienza",
+    "comienzan",
+    "comienzo",
+    "comienzos",
+    "comillas",
+    "cominfinanciero",
+    "comino",
+    "comisaría",
+    "comisionado",
+    "comisionados",
+    "comisiones",
+    "comisionó",
+    "comisión",
+    "comitiva",
+    "comitivas",
+    "comité",
+    "comités",
+    "comió",
+    "commerce",
+    "communications",
+    "como",
+    "comodato",
+    "comodidad",
+    "comodidades",
+    "comodines",
+    "comodones",
+    "comodísimos",
+    "compacto",
+    "compactos",
+    "compadrazgos",
+    "companía",
+    "compaq",
+    "compara",
+    "comparable",
+    "comparaciones",
+    "comparación",
+    "comparado",
+    "comparados",
+    "comparan",
+    "comparando",
+    "comparar",
+    "compararse",
+    "comparativamente",
+    "comparecencia",
+    "comparecer",
+    "comparecerán",
+    "comparecía",
+    "comparsa",
+    "comparsas",
+    "comparte",
+    "comparten",
+    "compartido",
+    "compartimentos",
+    "compartimiento",
+    "compartir",
+    "compartió",
+    "comparto",
+    "comparándolas",
+    "comparándose",
+    "comparársele",
+    "comparó",
+    "compasiva",
+    "compasión",
+    "compatibilizar",
+    "compatibles",
+    "compatriota",
+    "compatriotas",
+    "compañera",
+    "compañeras",
+    "compañerismo",
+    "compañero",
+    "compañeros",
+    "compañía",
+    "compañías",
+    "compelen",
+    "compendio",
+    "compensa",
+    "compensaciones",
+    "compensación",
+    "compensado",
+    "compensar",
+    "compensatorio",
+    "compensatorios",
+    "competa",
+    "compete",
+    "competencia",
+    "competencial",
+    "competencias",
+    "competente",
+    "competentes",
+    "competidor",
+    "competidoras",
+    "competidores",
+    "competir",
+    "competiremos",
+    "competirle",
+    "competirán",
+    "competitiva",
+    "competitivas",
+    "competitive",
+    "competitividad",
+    "competitivo",
+    "competitivos",
+    "compilador",
+    "compilando",
+    "compilar",
+    "compite",
+    "compiten",
+    "compitiendo",
+    "compito",
+    "complacer",
+    "complacido",
+    "complació",
+    "complazca",
+    "compleja",
+    "complejas",
+    "complejidad",
+    "complejo",
+    "complejos",
+    "complementada",
+    "complementan",
+    "complementar",
+    "complementaria",
+    "complementarias",
+    "complementarios",
+    "complementarlo",
+    "complementaría",
+    "complemento",
+    "completa",
+    "completadas",
+    "completamente",
+    "completar",
+    "completaron",
+    "completarse",
+    "completarían",
+    "completas",
+    "completo",
+    "completos",
+    "completó",
+    "complexión",
+    "complica",
+    "complicaciones",
+    "complicación",
+    "complicadas",
+    "complicado",
+    "complicados",
+    "complican",
+    "complicar",
+    "complicarse",
+    "complicidad",
+    "complicó",
+    "compone",
+    "componen",
+    "componendas",
+    "componente",
+    "componentes",
+    "componga",
+    "componían",
+    "comportamiento",
+    "comportamientos",
+    "comportan",
+    "comportará",
+    "composiciones",
+    "composición",
+    "compositor",
+    "compositores",
+    "compra",
+    "compraba",
+    "compradas",
+    "comprado",
+    "comprador",
+    "compradora",
+    "compradores",
+    "compramos",
+    "compran",
+    "comprando",
+    "comprar",
+    "comprara",
+    "comprarlas",
+    "comprarle",
+    "comprarles",
+    "comprarme",
+    "compraron",
+    "comprarse",
+    "comprará",
+    "compras",
+    "compraventa",
+    "compre",
+    "comprenda",
+    "comprendan",
+    "comprendas",
+    "comprende",
+    "comprendemos",
+    "comprenden",
+    "comprender",
+    "comprenderá",
+    "comprendiera",
+    "comprendieron",
+    "comprendo",
+    "comprendía",
+    "comprensible",
+    "comprensivo",
+    "comprensión",
+    "compresor",
+    "comprimida",
+    "comprobado",
+    "comprobante",
+    "comprobar",
+    "comprobaron",
+    "comprobarse",
+    "comprobó",
+    "comprometan",
+    "compromete",
+    "comprometen",
+    "comprometernos",
+    "comprometerse",
+    "comprometido",
+    "comprometidos",
+    "comprometieran",
+    "comprometieron",
+    "comprometimos",
+    "comprometió",
+    "comprometía",
+    "comprometían",
+    "compromiso",
+    "compromisos",
+    "comprueba",
+    "comprueban",
+    "comprárselo",
+    "compré",
+    "compró",
+    "compuertas",
+    "compuesta",
+    "compuesto",
+    "compulsiva",
+    "compuso",
+    "computacional",
+    "computacionales",
+    "computación",
+    "computadora",
+    "computadoras",
+    "computados",
+    "computarizado",
+    "computarizados",
+    "computec",
+    "computo",
+    "compás",
+    "comun",
+    "comunales",
+    "comunes",
+    "comunica",
+    "comunicaciones",
+    "comunicación",
+    "comunicado",
+    "comunicados",
+    "comunican",
+    "comunicantes",
+    "comunicar",
+    "comunicara",
+    "comunicarse",
+    "comunicativa",
+    "comunicativos",
+    "comunicándose",
+    "comunicó",
+    "comunidad",
+    "comunidades",
+    "comuniquen",
+    "comuniqué",
+    "comunista",
+    "comunistas",
+    "comunitarias",
+    "comunitario",
+    "comunitarios",
+    "comuníqueme",
+    "comuníquese",
+    "comía",
+    "común",
+    "con",
+    "conacyt",
+    "conadeip",
+    "conatos",
+    "concacaf",
+    "concamin",
+    "concamín",
+    "concanaco",
+    "concebida",
+    "concebido",
+    "concebían",
+    "conceder",
+    "concederá",
+    "concedida",
+    "concedido",
+    "concediendo",
+    "concediera",
+    "concedió",
+    "concedían",
+    "concem",
+    "concentra",
+    "concentraciones",
+    "concentración",
+    "concentrada",
+    "concentradas",
+    "concentrado",
+    "concentrados",
+    "concentran",
+    "concentraran",
+    "concentraremos",
+    "concentrarse",
+    "concentrará",
+    "concentre",
+    "concentró",
+    "concepciones",
+    "concepción",
+    "concepto",
+    "conceptos",
+    "conceptual",
+    "concert",
+    "concertaciones",
+    "concertación",
+    "concertadamente",
+    "concertado",
+    "concertando",
+    "concertar",
+    "concesionadas",
+    "concesionado",
+    "concesionarias",
+    "concesionario",
+    "concesionarios",
+    "concesiones",
+    "concesión",
+    "concha",
+    "conchello",
+    "conchita",
+    "concibe",
+    "concibió",
+    "conciencia",
+    "conciencias",
+    "concientizar",
+    "concierne",
+    "concierto",
+    "conciertos",
+    "conciliación",
+    "conciliador",
+    "conciliar",
+    "concilio",
+    "conciudadanos",
+    "concluida",
+    "concluido",
+    "concluir",
+    "concluirla",
+    "concluirá",
+    "concluirán",
+    "conclusion",
+    "conclusiones",
+    "conclusión",
+    "concluya",
+    "concluyamos",
+    "concluyan",
+    "concluye",
+    "concluyen",
+    "concluyeron",
+    "concluyó",
+    "concluído",
+    "concordar",
+    "concordia",
+    "concordó",
+    "concreción",
+    "concreta",
+    "concretado",
+    "concretamente",
+    "concretar",
+    "concretarse",
+    "concretas",
+    "concretera",
+    "concreteras",
+    "concreto",
+    "concretos",
+    "concretándolo",
+    "concretó",
+    "concuerda",
+    "concupiscente",
+    "concurran",
+    "concurrencia",
+    "concurrió",
+    "concursan",
+    "concursante",
+    "concursar",
+    "concurso",
+    "concursos",
+    "condado",
+    "condados",
+    "condde",
+    "conde",
+    "condecoración",
+    "condecorado",
+    "condena",
+    "condenaban",
+    "condenada",
+    "condenadas",
+    "condenado",
+    "condenados",
+    "condenamos",
+    "condenan",
+    "condenar",
+    "condenaron",
+    "condenas",
+    "condene",
+    "condenó",
+    "condesa",
+    "condicionado",
+    "condicional",
+    "condicionamientos",
+    "condicionan",
+    "condicionar",
+    "condiciones",
+    "condicionó",
+    "condición",
+    "condominios",
+    "condones",
+    "conducción",
+    "conduce",
+    "conducen",
+    "conducentes",
+    "conducida",
+    "conducido",
+    "conducidos",
+    "conduciendo",
+    "conduciera",
+    "conducimos",
+    "conducir",
+    "conducirá",
+    "conduciéndonos",
+    "conducta",
+    "conductas",
+    "conducto",
+    "conductor",
+    "conductores",
+    "conductuales",
+    "conducía",
+    "condujeron",
+    "condujo",
+    "conduzca",
+    "conduzcan",
+    "conecta",
+    "conectaba",
+    "conectado",
+    "conectar",
+    "conectarse",
+    "conectará",
+    "conecte",
+    "conectividad",
+    "conejos",
+    "conexiones",
+    "confeccionados",
+    "confección",
+    "confederación",
+    "conferencia",
+    "conferenciantes",
+    "conferencias",
+    "conferencista",
+    "conferencistas",
+    "confesamos",
+    "confesar",
+    "confesaron",
+    "confesión",
+    "confesores",
+    "confesó",
+    "confeti",
+    "confiaba",
+    "confiabilidad",
+    "confiable",
+    "confiables",
+    "confiado",
+    "confiados",
+    "confiamos",
+    "confiana",
+    "confiant",
+    "confianza",
+    "confiar",
+    "confiarnos",
+    "confiarse",
+    "confiará",
+    "confidencias",
+    "confidente",
+    "confiera",
+    "confiesa",
+    "confiesan",
+    "configura",
+    "configuraciones",
+    "configuración",
+    "configurada",
+    "configuradas",
+    "configurado",
+    "configurados",
+    "configurar",
+    "confinado",
+    "confio",
+    "confirma",
+    "confirmación",
+    "confirmada",
+    "confirmado",
+    "confirman",
+    "confirmar",
+    "confirmaron",
+    "confirmó",
+    "confió",
+    "conflicto",
+    "conflictos",
+    "confluyen",
+    "conforma",
+    "conformaban",
+    "conformación",
+    "conformada",
+    "conforman",
+    "conformar",
+    "conformara",
+    "conformarse",
+    "conforme",
+    "conformen",
+    "conformista",
+    "conformo",
+    "confort",
+    "confrontaciones",
+    "confrontación",
+    "confrontando",
+    "confrontar",
+    "confucio",
+    "confunde",
+    "confunden",
+    "confundido",
+    "confundidos",
+    "confundimos",
+    "confundir",
+    "confundirse",
+    "confusión",
+    "confía",
+    "confían",
+    "confíen",
+    "congelación",
+    "congeladas",
+    "congelados",
+    "congelarse",
+    "congestionadas",
+    "congestionado",
+    "congestionamiento",
+    "congestionamientos",
+    "congestión",
+    "conglomerado",
+    "conglomerados",
+    "conglomerará",
+    "congo",
+    "congoja",
+    "congregación",
+    "congregada",
+    "congregados",
+    "congregara",
+    "congregaron",
+    "congresista",
+    "congresistas",
+    "congreso",
+    "congresos",
+    "congruencia",
+    "congruente",
+    "congruentes",
+    "congéneres",
+    "congénitos",
+    "conjuga",
+    "conjugada",
+    "conjugaron",
+    "conjugarse",
+    "conjunción",
+    "conjunta",
+    "conjuntamente",
+    "conjuntar",
+    "conjuntarse",
+    "conjuntarán",
+    "conjuntas",
+    "conjuntivitis",
+    "conjunto",
+    "conjuntos",
+    "conjura",
+    "conjurarse",
+    "conlleva",
+    "conllevan",
+    "conmemorar",
+    "conmemorativa",
+    "conmemorativos",
+    "conmigo",
+    "conminado",
+    "conmoción",
+    "conmovedor",
+    "conmovedora",
+    "conmovieron",
+    "connor",
+    "connotación",
+    "connotados",
+    "cono",
+    "conoce",
+    "conocedor",
+    "conocedora",
+    "conocedoras",
+    "conocedores",
+    "conocemos",
+    "conocen",
+    "conocer",
+    "conocerlo",
+    "conocerse",
+    "conocerá",
+    "conocerán",
+    "conocería",
+    "conocerían",
+    "conoces",
+    "conocida",
+    "conocidas",
+    "conocido",
+    "conocidos",
+    "conociendo",
+    "conocieron",
+    "conocimiento",
+    "conocimientos",
+    "conocimos",
+    "conociéndose",
+    "conoció",
+    "conocí",
+    "conocía",
+    "conocían",
+    "conos",
+    "conotados",
+    "conozca",
+    "conozcan",
+    "conozco",
+    "conquista",
+    "conquistar",
+    "conquistas",
+    "consagrados",
+    "consagraron",
+    "consagró",
+    "consciente",
+    "conscientemente",
+    "conscientes",
+    "consecuencia",
+    "consecuencias",
+    "consecuente",
+    "consecutiva",
+    "consecutivas",
+    "consecutivo",
+    "consecutivos",
+    "conseguida",
+    "conseguido",
+    "conseguir",
+    "conseguirle",
+    "conseguirlo",
+    "conseguirlos",
+    "conseguía",
+    "consejero",
+    "consejeros",
+    "consejo",
+    "consejos",
+    "consenso",
+    "consensual",
+    "consentido",
+    "consentidos",
+    "consentimiento",
+    "conserva",
+    "conservaba",
+    "conservaban",
+    "conservacionistas",
+    "conservación",
+    "conservado",
+    "conservador",
+    "conservadores",
+    "conservados",
+    "conservan",
+    "conservando",
+    "conservar",
+    "conservarlo",
+    "conservarse",
+    "conserven",
+    "conservó",
+    "considera",
+    "consideraba",
+    "consideraban",
+    "considerablemente",
+    "considerables",
+    "consideraciones",
+    "consideración",
+    "considerada",
+    "consideradas",
+    "considerado",
+    "considerados",
+    "consideramos",
+    "consideran",
+    "considerando",
+    "considerar",
+    "considerara",
+    "considerarlas",
+    "considerarlo",
+    "consideraron",
+    "considerarse",
+    "considerará",
+    "consideraría",
+    "considere",
+    "consideren",
+    "consideres",
+    "considero",
+    "consideró",
+    "consiga",
+    "consigna",
+    "consignación",
+    "consignado",
+    "consignados",
+    "consignará",
+    "consignó",
+    "consigo",
+    "consigue",
+    "consiguen",
+    "consigues",
+    "consiguiendo",
+    "consiguiente",
+    "consiguieron",
+    "consiguió",
+    "consiste",
+    "consisten",
+    "consistencia",
+    "consistente",
+    "consistieron",
+    "consistirá",
+    "consistiría",
+    "consolación",
+    "consolarla",
+    "consolida",
+    "consolidación",
+    "consolidad",
+    "consolidadas",
+    "consolidado",
+    "consolidar",
+    "consolidarlo",
+    "consolidaron",
+    "consolidarse",
+    "consolidó",
+    "consorcio",
+    "consorcios",
+    "conspiración",
+    "consta",
+    "constan",
+    "constancia",
+    "constancias",
+    "constante",
+    "constantemente",
+    "constantes",
+    "constantiniana",
+    "constantino",
+    "constará",
+    "constata",
+    "constatar",
+    "conste",
+    "consterna",
+    "constitucion",
+    "constitucional",
+    "constitucionales",
+    "constitucionalmente",
+    "constituciones",
+    "constitución",
+    "constituida",
+    "constituido",
+    "constituidos",
+    "constituir",
+    "constituirse",
+    "constituirá",
+    "constituirán",
+    "constituiría",
+    "constitutiva",
+    "constitutivo",
+    "constitutivos",
+    "constituya",
+    "constituye",
+    "constituyen",
+    "constituyente",
+    "constituyentes",
+    "constituyeron",
+    "constituyó",
+    "constituía",
+    "construcciones",
+    "construcción",
+    "constructivo",
+    "constructora",
+    "constructoras",
+    "constructores",
+    "construida",
+    "construidas",
+    "construido",
+    "construir",
+    "construirlo",
+    "construirse",
+    "construirá",
+    "construirán",
+    "construirían",
+    "construya",
+    "construyan",
+    "construye",
+    "construyen",
+    "construyendo",
+    "construyó",
+    "consuelo",
+    "consuetudinario",
+    "consulado",
+    "consulados",
+    "consular",
+    "consulta",
+    "consultadas",
+    "consultado",
+    "consultados",
+    "consultar",
+    "consultaron",
+    "consultas",
+    "consulte",
+    "consultivo",
+    "consultor",
+    "consultora",
+    "consultorio",
+    "consultoriobursátil",
+    "consultoría",
+    "consumados",
+    "consumaran",
+    "consumida",
+    "consumido",
+    "consumidor",
+    "consumidores",
+    "consumiendo",
+    "consumir",
+    "consumirán",
+    "consumismo",
+    "consumista",
+    "consumió",
+    "consumo",
+    "consumó",
+    "contaba",
+    "contaban",
+    "contabilidad",
+    "contabilizada",
+    "contabilizan",
+    "contabilizaron",
+    "contable",
+    "contactado",
+    "contactar",
+    "contactarse",
+    "contacto",
+    "contactos",
+    "contadas",
+    "contado",
+    "contador",
+    "contadores",
+    "contaduría",
+    "contagia",
+    "contagio",
+    "contagios",
+    "contagiosas",
+    "contamina",
+    "contaminación",
+    "contaminador",
+    "contaminadores",
+    "contaminan",
+    "contaminante",
+    "contaminantes",
+    "contaminar",
+    "contamine",
+    "contaminen",
+    "contamos",
+    "contando",
+    "contar",
+    "contara",
+    "contará",
+    "contarán",
+    "contaré",
+    "contempla",
+    "contemplaba",
+    "contemplación",
+    "contemplada",
+    "contempladas",
+    "contemplado",
+    "contemplados",
+    "contemplan",
+    "contemplando",
+    "contemplar",
+    "contemplaron",
+    "contemplarse",
+    "contemple",
+    "contemporánea",
+    "contemporáneas",
+    "contemporáneo",
+    "contemporáneos",
+    "contencioso",
+    "contención",
+    "contender",
+    "contenderán",
+    "contendientes",
+    "contener",
+    "contenga",
+    "contengan",
+    "contenidas",
+    "contenido",
+    "contenidos",
+    "contenta",
+    "contentamos",
+    "contentas",
+    "contento",
+    "contentos",
+    "contenía",
+    "contenían",
+    "conteo",
+    "contesta",
+    "contestaban",
+    "contestan",
+    "contestar",
+    "contestara",
+    "contestarle",
+    "contestarles",
+    "contestatario",
+    "contesté",
+    "contestó",
+    "contexto",
+    "conti",
+    "contienda",
+    "contiene",
+    "contienen",
+    "contigo",
+    "contiguo",
+    "continental",
+    "continente",
+    "continentes",
+    "contingencia",
+    "contingencias",
+    "contingente",
+    "contingentes",
+    "continua",
+    "continuaba",
+    "continuaban",
+    "continuación",
+    "continuada",
+    "continuado",
+    "continuamente",
+    "continuar",
+    "continuara",
+    "continuaran",
+    "continuaron",
+    "continuará",
+    "continuarán",
+    "continuas",
+    "continuen",
+    "continuidad",
+    "continuo",
+    "continuos",
+    "continuó",
+    "continúa",
+    "continúan",
+    "continúe",
+    "contorsionarse",
+    "contra",
+    "contraataque",
+    "contraataques",
+    "contrabandistas",
+    "contrabando",
+    "contracción",
+    "contractual",
+    "contractura",
+    "contradecir",
+    "contradecirle",
+    "contrademanda",
+    "contradicciones",
+    "contradicción",
+    "contradice",
+    "contradicen",
+    "contradictorias",
+    "contradigan",
+    "contraer",
+    "contragolpear",
+    "contrajo",
+    "contralor",
+    "contraloría",
+    "contralorías",
+    "contrantes",
+    "contraparte",
+    "contraposiciones",
+    "contraproducente",
+    "contraproducentes",
+    "contrapuesta",
+    "contraria",
+    "contrarias",
+    "contrario",
+    "contrarios",
+    "contrarresta",
+    "contrarrestar",
+    "contrasta",
+    "contrastan",
+    "contraste",
+    "contrastes",
+    "contrataban",
+    "contrataciones",
+    "contratación",
+    "contratadas",
+    "contratado",
+    "contratados",
+    "contratan",
+    "contratantes",
+    "contratar",
+    "contratarle",
+    "contratarlo",
+    "contrataron",
+    "contraten",
+    "contratiempo",
+    "contratiempos",
+    "contratista",
+    "contratistas",
+    "contrato",
+    "contratos",
+    "contrató",
+    "contrayente",
+    "contraídos",
+    "contreras",
+    "contribuciones",
+    "contribución",
+    "contribuido",
+    "contribuir",
+    "contribuirá",
+    "contribuiría",
+    "contribuyendo",
+    "contribuyente",
+    "contribuyentes",
+    "contribuyeron",
+    "contribuyó",
+    "contrincante",
+    "contrincantes",
+    "contritos",
+    "controla",
+    "controlables",
+    "controlada",
+    "controlado",
+    "controladora",
+    "controlando",
+    "controlar",
+    "controlarlo",
+    "controlaron",
+    "controlarse",
+    "controle",
+    "controles",
+    "controversia",
+    "controversias",
+    "controvertida",
+    "controvertido",
+    "contry",
+    "contubernio",
+    "contundencia",
+    "contundente",
+    "contundentemente",
+    "contundentes",
+    "conturbado",
+    "contínua",
+    "contínuo",
+    "contó",
+    "conurbada",
+    "conurbadas",
+    "convalecencia",
+    "convaleciente",
+    "convence",
+    "convencer",
+    "convencerle",
+    "convencerse",
+    "convencida",
+    "convencidas",
+    "convencido",
+    "convencidos",
+    "convencieron",
+    "convencimiento",
+    "convencimos",
+    "convencional",
+    "convencionales",
+    "convenciones",
+    "convenció",
+    "convención",
+    "convenencieros",
+    "convenga",
+    "convenidos",
+    "conveniencia",
+    "conveniente",
+    "convenientes",
+    "convenio",
+    "convenios",
+    "convento",
+    "convenzo",
+    "convenía",
+    "convergencia",
+    "conversaciones",
+    "conversación",
+    "conversaron",
+    "conversarán",
+    "conversión",
+    "conversó",
+    "convertibles",
+    "convertido",
+    "convertidor",
+    "convertidores",
+    "convertir",
+    "convertirnos",
+    "convertirse",
+    "convertirá",
+    "convertiría",
+    "convicciones",
+    "convicción",
+    "conviene",
+    "convierta",
+    "conviertan",
+    "convierte",
+    "convierten",
+    "convincente",
+    "convino",
+    "convirtiendo",
+    "convirtiera",
+    "convirtieron",
+    "convirtiéndola",
+    "convirtiéndolas",
+    "convirtiéndose",
+    "convirtió",
+    "convivencia",
+    "convivio",
+    "convivir",
+    "convocación",
+    "convocada",
+    "convocadas",
+    "convocado",
+    "convocados",
+    "convocan",
+    "convocar",
+    "convocaron",
+    "convocará",
+    "convocatoria",
+    "convocatorias",
+    "convocó",
+    "convoquen",
+    "convoqué",
+    "convoy",
+    "convoyes",
+    "convulsionados",
+    "convulsivas",
+    "cony",
+    "conyugal",
+    "conyugales",
+    "cooper",
+    "cooperación",
+    "cooperar",
+    "cooperaron",
+    "cooperará",
+    "cooperativa",
+    "cooperen",
+    "cooppel",
+    "cooptación",
+    "coordenadas",
+    "coordina",
+    "coordinación",
+    "coordinada",
+    "coordinadas",
+    "coordinador",
+    "coordinadora",
+    "coordinadores",
+    "coordinados",
+    "coordinan",
+    "coordinando",
+    "coordinar",
+    "coordinaron",
+    "coordinarán",
+    "copa",
+    "coparmex",
+    "copas",
+    "copia",
+    "copiar",
+    "copias",
+    "copiemos",
+    "copioso",
+    "coppel",
+    "copán",
+    "coraje",
+    "coraza",
+    "corazon",
+    "corazones",
+    "corazón",
+    "corbalá",
+    "corbata",
+    "corbatas",
+    "cordelia",
+    "corderos",
+    "cordial",
+    "cordillera",
+    "cordobés",
+    "cordura",
+    "cordón",
+    "corea",
+    "coreados",
+    "coreana",
+    "coreografías",
+    "coreográfica",
+    "coreógrafa",
+    "corinter",
+    "cornalina",
+    "cornejo",
+    "cornoyer",
+    "coro",
+    "corona",
+    "coronacion",
+    "coronación",
+    "coronada",
+    "coronado",
+    "coronas",
+    "coronelas",
+    "coronó",
+    "coros",
+    "corp",
+    "corporaciones",
+    "corporación",
+    "corporal",
+    "corporation",
+    "corporativa",
+    "corporativas",
+    "corporativo",
+    "corporativos",
+    "corpórea",
+    "corra",
+    "corral",
+    "corrales",
+    "corran",
+    "corre",
+    "correajes",
+    "correcaminos",
+    "correcciones",
+    "corrección",
+    "correcta",
+    "correctamente",
+    "correctas",
+    "correcto",
+    "corredor",
+    "corredores",
+    "correduría",
+    "corregible",
+    "corregido",
+    "corregidora",
+    "corregir",
+    "corregirla",
+    "corregirlo",
+    "correligionario",
+    "correligionarios",
+    "correlon",
+    "correlones",
+    "corremos",
+    "corren",
+    "correo",
+    "correr",
+    "correrlos",
+    "correrá",
+    "correrán",
+    "correrían",
+    "corres",
+    "correspondan",
+    "corresponde",
+    "corresponden",
+    "correspondencia",
+    "corresponder",
+    "correspondido",
+    "correspondiente",
+    "correspondientes",
+    "correspondieron",
+    "correspondió",
+    "correspondía",
+    "corresponsal",
+    "correveidile",
+    "corrida",
+    "corridas",
+    "corrido",
+    "corridos",
+    "corriendo",
+    "corriente",
+    "corrientes",
+    "corrieron",
+    "corrige",
+    "corrigen",
+    "corrijan",
+    "corrillo",
+    "corripio",
+    "corrió",
+    "corro",
+    "corrobora",
+    "corroboraban",
+    "corroboraron",
+    "corrompa",
+    "corrompe",
+    "corrosión",
+    "corrupción",
+    "corruptela",
+    "corrupto",
+    "corruptos",
+    "corrí",
+    "corría",
+    "corrían",
+    "corta",
+    "cortada",
+    "cortadillo",
+    "cortadillos",
+    "cortado",
+    "cortadores",
+    "cortando",
+    "cortantes",
+    "cortar",
+    "cortarle",
+    "cortarlos",
+    "cortarnos",
+    "cortas",
+    "corte",
+    "cortedad",
+    "cortejada",
+    "cortejar",
+    "cortesanas",
+    "cortesía",
+    "cortez",
+    "corteza",
+    "cortina",
+    "cortinas",
+    "cortines",
+    "cortito",
+    "corto",
+    "cortoplazo",
+    "cortos",
+    "cortárselo",
+    "cortázar",
+    "cortés",
+    "cortó",
+    "coruña",
+    "corvette",
+    "corvi",
+    "corán",
+    "cosa",
+    "cosas",
+    "cosaspeores",
+    "cosecha",
+    "cosechando",
+    "cosechas",
+    "coser",
+    "cosmovisión",
+    "cosméticos",
+    "coso",
+    "coss",
+    "cossío",
+    "costa",
+    "costaba",
+    "costado",
+    "costados",
+    "costal",
+    "costales",
+    "costar",
+    "costarricense",
+    "costará",
+    "costarán",
+    "costas",
+    "costeable",
+    "costee",
+    "costera",
+    "costeras",
+    "costero",
+    "costilla",
+    "costner",
+    "costo",
+    "costos",
+    "costosas",
+    "costoso",
+    "costosos",
+    "costumbre",
+    "costumbres",
+    "costura",
+    "costuras",
+    "costureras",
+    "costándole",
+    "costó",
+    "cota",
+    "cotejo",
+    "cotero",
+    "cotidiana",
+    "cotidianas",
+    "cotidianeidad",
+    "cotidiano",
+    "cotidianos",
+    "cotiza",
+    "cotizaciones",
+    "cotización",
+    "cotizado",
+    "cotizados",
+    "cotizan",
+    "cotizar",
+    "cotorreo",
+    "couches",
+    "cougar",
+    "covarrubias",
+    "covian",
+    "cowboy",
+    "cox",
+    "coyolxauhqui",
+    "coyotaje",
+    "coyote",
+    "coyotepec",
+    "coyuca",
+    "coyuntura",
+    "cozumel",
+    "cpp",
+    "crack",
+    "cravioto",
+    "crawford",
+    "crayones",
+    "crea",
+    "creacion",
+    "creaciones",
+    "creación",
+    "creada",
+    "creadas",
+    "creado",
+    "creador",
+    "creadores",
+    "creame",
+    "creamos",
+    "crean",
+    "creando",
+    "crear",
+    "creara",
+    "crearles",
+    "crearon",
+    "creará",
+    "crearán",
+    "crearía",
+    "creativa",
+    "creativas",
+    "creatividad",
+    "creativo",
+    "creativos",
+    "crece",
+    "crecen",
+    "crecer",
+    "creceremos",
+    "crecerá",
+    "crecerán",
+    "crecerían",
+    "creces",
+    "crecido",
+    "creciendo",
+    "creciente",
+    "crecieron",
+    "crecimiento",
+    "crecimientos",
+    "creció",
+    "crecí",
+    "credencial",
+    "credenciales",
+    "credencialización",
+    "credencializados",
+    "credibilidad",
+    "crediticia",
+    "crediticias",
+    "crediticio",
+    "crediticios",
+    "creditos",
+    "cree",
+    "creemos",
+    "creen",
+    "creencia",
+    "creencias",
+    "creer",
+    "creerme",
+    "creerán",
+    "creería",
+    "cremas",
+    "cremi",
+    "creo",
+    "creole",
+    "cretinismo",
+    "creyendo",
+    "creyó",
+    "crezca",
+    "creía",
+    "creíamos",
+    "creíble",
+    "creíbles",
+    "creído",
+    "creímos",
+    "creó",
+    "criada",
+    "criadas",
+    "criadero",
+    "criadita",
+    "criadores",
+    "criatura",
+    "criaturas",
+    "crimen",
+    "criminal",
+    "criminales",
+    "criminalidad",
+    "criminalista",
+    "criocirugía",
+    "criollos",
+    "crisantemo",
+    "crisantemos",
+    "crisis",
+    "cristal",
+    "cristales",
+    "cristalización",
+    "cristalizado",
+    "cristiana",
+    "cristianismo",
+    "cristiano",
+    "cristianos",
+    "cristina",
+    "cristo",
+    "cristobal",
+    "criterio",
+    "criterios",
+    "critica",
+    "criticaban",
+    "criticado",
+    "criticamente",
+    "critican",
+    "criticando",
+    "criticar",
+    "criticarlos",
+    "criticaron",
+    "criticará",
+    "criticó",
+    "croacia",
+    "croc",
+    "cromadas",
+    "cromadoras",
+    "cromados",
+    "cromera",
+    "cronista",
+    "cronistas",
+    "cronometraron",
+    "cronometró",
+    "cronómetro",
+    "cronómetros",
+    "croquetas",
+    "croquis",
+    "croquistas",
+    "croux",
+    "cruce",
+    "crucero",
+    "cruceros",
+    "cruces",
+    "crucial",
+    "cruda",
+    "crudo",
+    "crue",
+    "cruel",
+    "cruenta",
+    "cruise",
+    "cruz",
+    "cruza",
+    "cruzada",
+    "cruzadas",
+    "cruzado",
+    "cruzados",
+    "cruzan",
+    "cruzando",
+    "cruzar",
+    "cruzaron",
+    "cruzará",
+    "cruzas",
+    "cruzó",
+    "crysel",
+    "cráneo",
+    "cráter",
+    "cráteres",
+    "crédito",
+    "créditos",
+    "créeme",
+    "crímenes",
+    "crítica",
+    "críticas",
+    "crítico",
+    "críticos",
+    "crónica",
+    "crónicas",
+    "cse",
+    "csg",
+    "ctm",
+    "cu",
+    "cuadra",
+    "cuadrado",
+    "cuadrados",
+    "cuadrangular",
+    "cuadras",
+    "cuadrilla",
+    "cuadrilátero",
+    "cuadriláteros",
+    "cuadro",
+    "cuadros",
+    "cuadruplica",
+    "cuadruplicó",
+    "cuajar",
+    "cuajarle",
+    "cuajimalpa",
+    "cual",
+    "cuales",
+    "cualesquier",
+    "cualidad",
+    "cualidades",
+    "cualquier",
+    "cualquiera",
+    "cuando",
+    "cuantas",
+    "cuantifica",
+    "cuantiosamente",
+    "cuantiosos",
+    "cuanto",
+    "cuantos",
+    "cuarenta",
+    "cuarta",
+    "cuartel",
+    "cuarteles",
+    "cuarteto",
+    "cuartillas",
+    "cuartitos",
+    "cuarto",
+    "cuartos",
+    "cuarzo",
+    "cuatacho",
+    "cuate",
+    "cuatro",
+    "cuauhtémoc",
+    "cuautitlán",
+    "cuba",
+    "cubana",
+    "cubanas",
+    "cubano",
+    "cubanos",
+    "cubas",
+    "cubero",
+    "cubierta",
+    "cubiertas",
+    "cubierto",
+    "cubiertos",
+    "cubra",
+    "cubran",
+    "cubre",
+    "cubren",
+    "cubriendo",
+    "cubrimos",
+    "cubrir",
+    "cubrirá",
+    "cubrirán",
+    "cubriéndonos",
+    "cubrió",
+    "cubría",
+    "cubrían",
+    "cubículos",
+    "cuca",
+    "cuchara",
+    "cucharaditas",
+    "cucharas",
+    "cuchillada",
+    "cuchillería",
+    "cuchillo",
+    "cuchillos",
+    "cuco",
+    "cuelgan",
+    "cuelgue",
+    "cuellar",
+    "cuello",
+    "cuenca",
+    "cuenta",
+    "cuentan",
+    "cuentas",
+    "cuente",
+    "cuenten",
+    "cuento",
+    "cuentos",
+    "cuerda",
+    "cuerdas",
+    "cuerdo",
+    "cuernavaca",
+    "cuerno",
+    "cuernos",
+    "cuero",
+    "cuerpo",
+    "cuerpos",
+    "cuervo",
+    "cuesta",
+    "cuestan",
+    "cuestas",
+    "cuestion",
+    "cuestiona",
+    "cuestionada",
+    "cuestionado",
+    "cuestionados",
+    "cuestionamiento",
+    "cuestionamientos",
+    "cuestionan",
+    "cuestiones",
+    "cuestionó",
+    "cuestión",
+    "cueva",
+    "cuevas",
+    "cuicalli",
+    "cuicuilco",
+    "cuida",
+    "cuidaba",
+    "cuidad",
+    "cuidadas",
+    "cuidado",
+    "cuidador",
+    "cuidados",
+    "cuidadosa",
+    "cuidadosamente",
+    "cuidadoso",
+    "cuidan",
+    "cuidando",
+    "cuidar",
+    "cuidarlo",
+    "cuidará",
+    "cuide",
+    "cuiden",
+    "cuitlahuac",
+    "culebra",
+    "culiacan",
+    "culiacán",
+    "culinarias",
+    "culminada",
+    "culminado",
+    "culminante",
+    "culminará",
+    "culminarán",
+    "culminó",
+    "culpa",
+    "culpabilidad",
+    "culpable",
+    "culpables",
+    "culpar",
+    "culposos",
+    "culpó",
+    "culta",
+    "cultiva",
+    "cultivable",
+    "cultivadas",
+    "cultivadores",
+    "cultivan",
+    "cultivar",
+    "cultivo",
+    "cultivos",
+    "culto",
+    "cultos",
+    "cultura",
+    "cultural",
+    "culturales",
+    "culturas",
+    "culturización",
+    "cumaná",
+    "cumbre",
+    "cumbres",
+    "cumpla",
+    "cumplan",
+    "cumple",
+    "cumpleaños",
+    "cumplen",
+    "cumplidas",
+    "cumplido",
+    "cumplidoras",
+    "cumplidos",
+    "cumpliendo",
+    "cumplieron",
+    "cumplimiento",
+    "cumplimientos",
+    "cumplimos",
+    "cumplir",
+    "cumplirles",
+    "cumplirse",
+    "cumplirá",
+    "cumpliéramos",
+    "cumplió",
+    "cumplí",
+    "cumplía",
+    "cumplían",
+    "cumplírsele",
+    "cunda",
+    "cundió",
+    "cuota",
+    "cuotas",
+    "cupertino",
+    "cupo",
+    "cupón",
+    "cura",
+    "curaban",
+    "curandero",
+    "curar",
+    "cure",
+    "curia",
+    "curiosamente",
+    "curiosas",
+    "curiosidad",
+    "curiosidades",
+    "curioso",
+    "curriculum",
+    "curro",
+    "currículum",
+    "cursada",
+    "cursando",
+    "cursaron",
+    "curso",
+    "cursos",
+    "curva",
+    "curvatura",
+    "cuspinera",
+    "custodia",
+    "custodiado",
+    "custodian",
+    "custodiar",
+    "customline",
+    "cutzamala",
+    "cutáneas",
+    "cuya",
+    "cuyas",
+    "cuyo",
+    "cuyos",
+    "cuál",
+    "cuáles",
+    "cuándo",
+    "cuánta",
+    "cuántas",
+    "cuánto",
+    "cuántos",
+    "cuéllar",
+    "cuñada",
+    "cuñado",
+    "cuñados",
+    "cuño",
+    "cydsa",
+    "cyrus",
+    "cádiz",
+    "cálculo",
+    "cálculos",
+    "cálida",
+    "cálido",
+    "cálmecac",
+    "cámara",
+    "cámaras",
+    "cáncer",
+    "cándida",
+    "cárcel",
+    "cárceles",
+    "cárcova",
+    "cárdenas",
+    "cárdeno",
+    "cáritas",
+    "cárnicos",
+    "cártel",
+    "cáscara",
+    "cástulo",
+    "cátchers",
+    "cátedra",
+    "cázares",
+    "cédula",
+    "cédulas",
+    "célebre",
+    "célebres",
+    "célula",
+    "células",
+    "céntricas",
+    "céntrico",
+    "céntricos",
+    "césar",
+    "cíclicamente",
+    "cíclico",
+    "círculo",
+    "círculos",
+    "cítricos",
+    "cívica",
+    "cívicas",
+    "cívico",
+    "cívicos",
+    "cóconas",
+    "código",
+    "códigos",
+    "cólera",
+    "cómica",
+    "cómicas",
+    "cómico",
+    "cómicos",
+    "cómo",
+    "cómoda",
+    "cómodamente",
+    "cómodas",
+    "cómodo",
+    "cómodos",
+    "cómplice",
+    "cómplices",
+    "cómputo",
+    "cónsul",
+    "cónsules",
+    "cópula",
+    "córdoba",
+    "córneas",
+    "cósmica",
+    "cósmico",
+    "cóvers",
+    "cúbicos",
+    "cúpula",
+    "cúpulas",
+    "d",
+    "da",
+    "daba",
+    "daban",
+    "dacarett",
+    "dad",
+    "dada",
+    "dadas",
+    "dado",
+    "dados",
+    "dagoberto",
+    "dahn",
+    "daihim",
+    "daimatsu",
+    "daimler",
+    "dalia",
+    "dallas",
+    "daltonismo",
+    "dama",
+    "damas",
+    "dame",
+    "damnificada",
+    "damnificados",
+    "damos",
+    "dando",
+    "daniela",
+    "danny",
+    "dante",
+    "danza",
+    "danzar",
+    "danzas",
+    "dar",
+    "darci",
+    "darla",
+    "darle",
+    "darles",
+    "darnos",
+    "darse",
+    "dará",
+    "darán",
+    "darás",
+    "daré",
+    "daría",
+    "darían",
+    "darío",
+    "das",
+    "dasa",
+    "dasd",
+    "dato",
+    "datos",
+    "datsun",
+    "datum",
+    "davalillo",
+    "dave",
+    "davis",
+    "daza",
+    "daña",
+    "dañaban",
+    "dañada",
+    "dañadas",
+    "dañado",
+    "dañados",
+    "dañan",
+    "dañar",
+    "dañaran",
+    "dañarse",
+    "dañará",
+    "dañino",
+    "dañinos",
+    "daño",
+    "daños",
+    "ddf",
+    "de",
+    "dean",
+    "deba",
+    "debajo",
+    "deban",
+    "debate",
+    "debatido",
+    "debatir",
+    "debatirán",
+    "debe",
+    "debemos",
+    "deben",
+    "deber",
+    "deberes",
+    "deberá",
+    "deberán",
+    "deberás",
+    "debería",
+    "deberíamos",
+    "deberían",
+    "debes",
+    "debidamente",
+    "debido",
+    "debiera",
+    "debieran",
+    "debieron",
+    "debiles",
+    "debilidad",
+    "debilidades",
+    "debilita",
+    "debilitado",
+    "debilitar",
+    "debilitarnos",
+    "debilite",
+    "debió",
+    "debo",
+    "debut",
+    "debutado",
+    "debutante",
+    "debutarán",
+    "debutó",
+    "debí",
+    "debía",
+    "debíamos",
+    "debían",
+    "decadencia",
+    "decadente",
+    "decaer",
+    "decaería",
+    "decaiga",
+    "decanini",
+    "decano",
+    "decapitada",
+    "decapitados",
+    "decapitó",
+    "decayó",
+    "decaído",
+    "decenas",
+    "decenso",
+    "decente",
+    "decepciona",
+    "decepcionada",
+    "decepcionadas",
+    "decepcionado",
+    "decepción",
+    "decida",
+    "decidan",
+    "deciden",
+    "decidida",
+    "decididamente",
+    "decidido",
+    "decidieron",
+    "decidimos",
+    "decidir",
+    "decidirse",
+    "decidirá",
+    "decidirán",
+    "decidiría",
+    "decidió",
+    "decido",
+    "decidí",
+    "decimos",
+    "decir",
+    "decirle",
+    "decirles",
+    "decirlo",
+    "decirme",
+    "decirnos",
+    "decirse",
+    "decisiones",
+    "decisioón",
+    "decisiva",
+    "decisivo",
+    "decisivos",
+    "decisión",
+    "declamaba",
+    "declara",
+    "declaraban",
+    "declaraciones",
+    "declaración",
+    "declarada",
+    "declaradas",
+    "declarado",
+    "declarante",
+    "declarar",
+    "declararon",
+    "declararse",
+    "declaratoria",
+    "declaren",
+    "declaró",
+    "declinación",
+    "declinar",
+    "declinó",
+    "decomisadas",
+    "decomisados",
+    "decomisan",
+    "decomisaron",
+    "decomisó",
+    "decoración",
+    "decorado",
+    "decorados",
+    "decorar",
+    "decorativo",
+    "decrecimiento",
+    "decremento",
+    "decrescenzo",
+    "decretado",
+    "decretan",
+    "decretaron",
+    "decretarse",
+    "decreto",
+    "decretos",
+    "decretó",
+    "decía",
+    "decíamos",
+    "decían",
+    "decírselo",
+    "dedazo",
+    "dedazos",
+    "dedeó",
+    "dedica",
+    "dedicación",
+    "dedicada",
+    "dedicadas",
+    "dedicado",
+    "dedicados",
+    "dedicamos",
+    "dedican",
+    "dedicando",
+    "dedicar",
+    "dedicaran",
+    "dedicarme",
+    "dedicarnos",
+    "dedicaron",
+    "dedicarse",
+    "dedicarte",
+    "dedicará",
+    "dedicaré",
+    "dedico",
+    "dedicó",
+    "dedillo",
+    "dedique",
+    "dediqué",
+    "dedo",
+    "dedos",
+    "deducibles",
+    "deduje",
+    "deduzco",
+    "dedúzcale",
+    "dee",
+    "defecto",
+    "defectos",
+    "defender",
+    "defenderse",
+    "defenderá",
+    "defenderán",
+    "defendida",
+    "defendido",
+    "defendiendo",
+    "defendió",
+    "defendía",
+    "defenestradas",
+    "defensa",
+    "defensas",
+    "defensiva",
+    "defensivas",
+    "defensivo",
+    "defensor",
+    "defensora",
+    "defensores",
+    "defensorías",
+    "deferencia",
+    "defeños",
+    "deficiencia",
+    "deficiencias",
+    "deficiente",
+    "deficientes",
+    "deficit",
+    "defienda",
+    "defiende",
+    "defienden",
+    "defina",
+    "definen",
+    "definiciones",
+    "definición",
+    "definida",
+    "definidas",
+    "definido",
+    "definidos",
+    "definiendo",
+    "definir",
+    "definirse",
+    "definirá",
+    "definirán",
+    "definiría",
+    "definitiva",
+    "definitivamente",
+    "definitivas",
+    "definitivo",
+    "definitivos",
+    "definió",
+    "definía",
+    "deforma",
+    "deformaciones",
+    "defraudador",
+    "defraudadores",
+    "defraudados",
+    "defraudando",
+    "defraudar",
+    "defraudarlos",
+    "defraudaré",
+    "degollado",
+    "degradación",
+    "degradado",
+    "degradando",
+    "degüello",
+    "dehesa",
+    "deidad",
+    "deidades",
+    "deificaron",
+    "deja",
+    "dejaba",
+    "dejaban",
+    "dejada",
+    "dejadas",
+    "dejado",
+    "dejados",
+    "dejamos",
+    "dejan",
+    "dejando",
+    "dejar",
+    "dejara",
+    "dejaran",
+    "dejarla",
+    "dejarlo",
+    "dejarlos",
+    "dejarme",
+    "dejaron",
+    "dejarse",
+    "dejará",
+    "dejarán",
+    "dejaría",
+    "deje",
+    "dejemos",
+    "dejen",
+    "dejándolas",
+    "dejó",
+    "del",
+    "delano",
+    "delante",
+    "delantera",
+    "delanteras",
+    "delantero",
+    "delanteros",
+    "delatan",
+    "delató",
+    "delaware",
+    "dele",
+    "delega",
+    "delegaciones",
+    "delegación",
+    "delegada",
+    "delegado",
+    "delegados",
+    "deleitan",
+    "deleitar",
+    "deleite",
+    "delfines",
+    "delgada",
+    "delgado",
+    "delgados",
+    "delia",
+    "deliberaciones",
+    "deliberada",
+    "deliberadamente",
+    "deliberado",
+    "delicada",
+    "delicadas",
+    "delicado",
+    "delicias",
+    "delictivos",
+    "delimitada",
+    "delimitar",
+    "delimitó",
+    "delincuencia",
+    "delincuente",
+    "delincuentes",
+    "delinea",
+    "delinquen",
+    "delinquir",
+    "deliquio",
+    "delirante",
+    "delirio",
+    "delito",
+    "delitos",
+    "deliverpool",
+    "dell",
+    "delon",
+    "delors",
+    "delsur",
+    "delta",
+    "demagogia",
+    "demagógicamente",
+    "demagógico",
+    "demagógicos",
+    "demanda",
+    "demandaba",
+    "demandado",
+    "demandan",
+    "demandando",
+    "demandante",
+    "demandantes",
+    "demandar",
+    "demandaron",
+    "demandará",
+    "demandas",
+    "demandé",
+    "demandó",
+    "demarcación",
+    "demasiada",
+    "demasiadas",
+    "demasiado",
+    "demasiados",
+    "demasía",
+    "demencia",
+    "demencial",
+    "deming",
+    "democracia",
+    "democracias",
+    "democratización",
+    "democratizador",
+    "democratizante",
+    "democratizar",
+    "democratizarla",
+    "democristiano",
+    "democrática",
+    "democráticamente",
+    "democráticas",
+    "democrático",
+    "democráticos",
+    "demográfica",
+    "demográficas",
+    "demonio",
+    "demonios",
+    "demoníaca",
+    "demora",
+    "demorados",
+    "demorar",
+    "demostraciones",
+    "demostración",
+    "demostrado",
+    "demostrando",
+    "demostrar",
+    "demostrarle",
+    "demostrarnos",
+    "demostraron",
+    "demostrará",
+    "demostremos",
+    "demostró",
+    "demuestra",
+    "demuestran",
+    "demuestre",
+    "demuestren",
+    "demás",
+    "demócrata",
+    "demócratas",
+    "den",
+    "denny",
+    "denomina",
+    "denominada",
+    "denominadas",
+    "denominado",
+    "denominados",
+    "denostada",
+    "denotan",
+    "denotar",
+    "denotaron",
+    "denotó",
+    "densa",
+    "dense",
+    "densidad",
+    "densos",
+    "dental",
+    "dentales",
+    "dentelladas",
+    "dentista",
+    "dentro",
+    "denuncia",
+    "denunciada",
+    "denunciado",
+    "denunciados",
+    "denuncian",
+    "denunciante",
+    "denunciantes",
+    "denunciar",
+    "denunciaron",
+    "denunciará",
+    "denunciarán",
+    "denuncias",
+    "denuncie",
+    "denuncié",
+    "denunció",
+    "denzel",
+    "denúncielo",
+    "deparado",
+    "deparará",
+    "departamentales",
+    "departamento",
+    "departamentos",
+    "departió",
+    "depende",
+    "dependen",
+    "dependencia",
+    "dependencias",
+    "depender",
+    "dependerá",
+    "dependes",
+    "dependiendo",
+    "dependiente",
+    "dependientes",
+    "dependía",
+    "deplazamiento",
+    "deportaciones",
+    "deportado",
+    "deporte",
+    "deportes",
+    "deportista",
+    "deportistas",
+    "deportiva",
+    "deportivas",
+    "deportivo",
+    "deportivos",
+    "deposita",
+    "depositada",
+    "depositado",
+    "depositados",
+    "depositan",
+    "depositantes",
+    "depositar",
+    "depositario",
+    "depositarse",
+    "deposite",
+    "deposítele",
+    "depredadora",
+    "depredadores",
+    "depresiones",
+    "depresión",
+    "deprimente",
+    "deprimido",
+    "deprimidos",
+    "depuracion",
+    "depuración",
+    "depurado",
+    "depuradísima",
+    "depurar",
+    "depósito",
+    "depósitos",
+    "der",
+    "derecha",
+    "derechas",
+    "derechazo",
+    "derechazos",
+    "derecho",
+    "derechohabientes",
+    "derechos",
+    "deregios",
+    "derek",
+    "deri",
+    "deriva",
+    "derivación",
+    "derivadas",
+    "derivado",
+    "derivados",
+    "derivan",
+    "derivar",
+    "derivaron",
+    "derivará",
+    "derivó",
+    "derma",
+    "dermatitis",
+    "dermatología",
+    "dermatológica",
+    "derrama",
+    "derramado",
+    "derramamiento",
+    "derraman",
+    "derramar",
+    "derrame",
+    "derrames",
+    "derrapante",
+    "derrapar",
+    "derrape",
+    "derredor",
+    "derretirse",
+    "derriba",
+    "derribado",
+    "derribar",
+    "derribó",
+    "derrochando",
+    "derroche",
+    "derrota",
+    "derrotado",
+    "derrotados",
+    "derrotando",
+    "derrotar",
+    "derrotarlo",
+    "derrotaron",
+    "derrotas",
+    "derrotero",
+    "derrotó",
+    "derrumban",
+    "derrumbe",
+    "derrumbes",
+    "desaceleración",
+    "desaciertos",
+    "desacredita",
+    "desacreditados",
+    "desactivar",
+    "desactivaron",
+    "desacuerdo",
+    "desacuerdos",
+    "desafiar",
+    "desafinada",
+    "desafió",
+    "desaforados",
+    "desafortunadamente",
+    "desafortunado",
+    "desafortunados",
+    "desafíe",
+    "desafío",
+    "desafíos",
+    "desagradable",
+    "desagradables",
+    "desagüe",
+    "desahogar",
+    "desahogo",
+    "desahogos",
+    "desahucio",
+    "desairado",
+    "desaire",
+    "desalentado",
+    "desalentadores",
+    "desalentarán",
+    "desalentó",
+    "desaliento",
+    "desalientos",
+    "desalojados",
+    "desalojar",
+    "desalojaran",
+    "desalojaron",
+    "desalojará",
+    "desalojo",
+    "desalojos",
+    "desalojó",
+    "desamor",
+    "desamparados",
+    "desangraba",
+    "desangrante",
+    "desanima",
+    "desanimada",
+    "desaparecen",
+    "desaparecer",
+    "desaparecerá",
+    "desaparecerán",
+    "desaparecidas",
+    "desaparecido",
+    "desaparecidos",
+    "desaparecieron",
+    "desapareció",
+    "desaparezca",
+    "desaparezcan",
+    "desapariciones",
+    "desaparición",
+    "desapercibida",
+    "desaprobación",
+    "desaprovechando",
+    "desaprovechar",
+    "desaprovechó",
+    "desaprueba",
+    "desarme",
+    "desarrolla",
+    "desarrollada",
+    "desarrolladas",
+    "desarrollado",
+    "desarrolladores",
+    "desarrollados",
+    "desarrollamos",
+    "desarrollan",
+    "desarrollando",
+    "desarrollar",
+    "desarrollarnos",
+    "desarrollaron",
+    "desarrollarse",
+    "desarrollará",
+    "desarrollaría",
+    "desarrolle",
+    "desarrollen",
+    "desarrollo",
+    "desarrolló",
+    "desastres",
+    "desata",
+    "desatado",
+    "desatan",
+    "desatar",
+    "desatención",
+    "desatinos",
+    "desató",
+    "desayunar",
+    "desayuno",
+    "desayunos",
+    "desazolvar",
+    "desbalancean",
+    "desbancarlo",
+    "desbaratado",
+    "desbordado",
+    "desbordados",
+    "desbordamiento",
+    "desbordando",
+    "desbordará",
+    "desbordes",
+    "desbordó",
+    "desburocratizar",
+    "descabellada",
+    "descabelladas",
+    "descabello",
+    "descalabro",
+    "descalificada",
+    "descalificado",
+    "descalzos",
+    "descansaban",
+    "descansan",
+    "descansando",
+    "descansar",
+    "descansarán",
+    "descanso",
+    "descansos",
+    "descansó",
+    "descapitaliza",
+    "descarga",
+    "descargan",
+    "descargar",
+    "descargas",
+    "descargo",
+    "descargue",
+    "descarnado",
+    "descarrilamiento",
+    "descarriló",
+    "descarta",
+    "descartaba",
+    "descartado",
+    "descartan",
+    "descartando",
+    "descartaron",
+    "descartó",
+    "descastados",
+    "descendencia",
+    "descender",
+    "descenderá",
+    "descendientes",
+    "descendió",
+    "descenso",
+    "descensos",
+    "descentralización",
+    "descentralizarse",
+    "desciende",
+    "descienden",
+    "descomponía",
+    "descomposición",
+    "descomposturas",
+    "descompuesto",
+    "descomunal",
+    "desconcertado",
+    "desconcertados",
+    "desconcertante",
+    "desconcierta",
+    "desconcierto",
+    "desconfianza",
+    "desconfiara",
+    "descongelación",
+    "descongelar",
+    "descongestionar",
+    "desconoce",
+    "desconocen",
+    "desconocer",
+    "desconocerlos",
+    "desconocida",
+    "desconocidas",
+    "desconocido",
+    "desconocidos",
+    "desconocieron",
+    "desconocimiento",
+    "desconoció",
+    "desconocía",
+    "desconozco",
+    "descontando",
+    "descontar",
+    "descontentas",
+    "descontento",
+    "descontentos",
+    "descontrol",
+    "descreído",
+    "describen",
+    "describir",
+    "describió",
+    "descripción",
+    "descriptores",
+    "descrita",
+    "descrito",
+    "descritos",
+    "descrédito",
+    "descubierta",
+    "descubiertas",
+    "descubierto",
+    "descubiertos",
+    "descubran",
+    "descubre",
+    "descubridor",
+    "descubriendo",
+    "descubrieron",
+    "descubrimiento",
+    "descubrimientos",
+    "descubrir",
+    "descubrió",
+    "descuento",
+    "descuentos",
+    "descuidado",
+    "descuidan",
+    "descuide",
+    "descuido",
+    "descuidos",
+    "desde",
+    "desdecir",
+    "desdeñar",
+    "desdeño",
+    "desdice",
+    "desdichada",
+    "desdichado",
+    "desea",
+    "deseaban",
+    "deseable",
+    "deseada",
+    "deseamos",
+    "desean",
+    "deseando",
+    "desearle",
+    "desearon",
+    "deseas",
+    "desecha",
+    "desechaban",
+    "desechar",
+    "desecharon",
+    "desecho",
+    "desechos",
+    "desechó",
+    "desee",
+    "deseen",
+    "desembarcar",
+    "desembarque",
+    "desembocar",
+    "desempate",
+    "desempaña",
+    "desempeña",
+    "desempeñado",
+    "desempeñan",
+    "desempeñando",
+    "desempeñar",
+    "desempeñarse",
+    "desempeño",
+    "desempleado",
+    "desempleados",
+    "desempleo",
+    "desencajado",
+    "desencantados",
+    "desencanto",
+    "desenfreno",
+    "desengaña",
+    "desenmascaran",
+    "desentendido",
+    "desenterrados",
+    "desentonado",
+    "desentonados",
+    "desenvolverse",
+    "desenvolvimiento",
+    "desenvuelve",
+    "deseo",
+    "deseos",
+    "desequilibrada",
+    "desequilibrado",
+    "desequilibrio",
+    "desequilibrios",
+    "deserciones",
+    "desertar",
+    "desesperación",
+    "desesperada",
+    "desesperadamente",
+    "desesperados",
+    "desesperan",
+    "desestabilizar",
+    "desestímulos",
+    "deseándolo",
+    "desfasan",
+    "desfavorable",
+    "desfiguros",
+    "desfiguró",
+    "desfilan",
+    "desfile",
+    "desfiles",
+    "desfondaría",
+    "desgarra",
+    "desgarradoramente",
+    "desgarradoras",
+    "desgarre",
+    "desgarros",
+    "desgastante",
+    "desgastar",
+    "desgaste",
+    "desgañitan",
+    "desglosado",
+    "desglosados",
+    "desgracia",
+    "desgraciadamente",
+    "desgracias",
+    "deshacer",
+    "deshacerse",
+    "desharía",
+    "deshilachar",
+    "deshilvanando",
+    "deshizo",
+    "deshonestidad",
+    "deshonesto",
+    "deshumanizó",
+    "desierto",
+    "desiertos",
+    "designaciones",
+    "designación",
+    "designada",
+    "designadas",
+    "designado",
+    "designador",
+    "designados",
+    "designar",
+    "designe",
+    "designio",
+    "designó",
+    "desigualdad",
+    "desigualdades",
+    "desincorporación",
+    "desincorporó",
+    "desindigenización",
+    "desinformación",
+    "desinformados",
+    "desintegración",
+    "desintegrar",
+    "desintegrarse",
+    "desintegrará",
+    "desinterviniendo",
+    "desinterés",
+    "desiste",
+    "desistieran",
+    "desistieron",
+    "desistir",
+    "desistirse",
+    "desistió",
+    "deslaves",
+    "desleal",
+    "desleales",
+    "deslices",
+    "desligarme",
+    "deslindar",
+    "deslindarse",
+    "deslinden",
+    "deslizando",
+    "deslizarse",
+    "deslizó",
+    "deslucido",
+    "deslumbra",
+    "desmantelamiento",
+    "desmarque",
+    "desmembrada",
+    "desmembró",
+    "desmerece",
+    "desmintió",
+    "desmitificado",
+    "desmontadas",
+    "desmontarlo",
+    "desmoralizar",
+    "desmoralizarnos",
+    "desmoronando",
+    "desmotivada",
+    "desnaquización",
+    "desnivel",
+    "desnudarla",
+    "desnudaron",
+    "desnudos",
+    "desnutrición",
+    "desobedece",
+    "desocupación",
+    "desocupar",
+    "desolación",
+    "desolador",
+    "desorden",
+    "desordenado",
+    "desorganizados",
+    "desorienta",
+    "desorientación",
+    "desorientada",
+    "desorientados",
+    "despachar",
+    "despacho",
+    "despacio",
+    "despacito",
+    "desparramaron",
+    "despavorido",
+    "despedazadas",
+    "despedida",
+    "despedidas",
+    "despedido",
+    "despedidos",
+    "despedir",
+    "despedirlas",
+    "despedirlos",
+    "despedirse",
+    "despedía",
+    "despegara",
+    "despegue",
+    "despeinada",
+    "despeinarse",
+    "despejando",
+    "despejar",
+    "despensa",
+    "despensas",
+    "desperdiciar",
+    "desperdicio",
+    "despertado",
+    "despertando",
+    "despertar",
+    "despertaron",
+    "despertó",
+    "despidan",
+    "despide",
+    "despidiendo",
+    "despidió",
+    "despido",
+    "despidos",
+    "despierta",
+    "despiertan",
+    "despierte",
+    "desplace",
+    "desplazaba",
+    "desplazado",
+    "desplazados",
+    "desplazamiento",
+    "desplazamientos",
+    "desplazan",
+    "desplazando",
+    "desplazar",
+    "desplazarla",
+    "desplazándolo",
+    "desplegado",
+    "desplegará",
+    "desplegarán",
+    "despliegue",
+    "desplieguen",
+    "despoblado",
+    "despojaba",
+    "despojado",
+    "despojando",
+    "despojar",
+    "despojarlo",
+    "despojaron",
+    "despojo",
+    "despojó",
+    "despotismo",
+    "despreciable",
+    "despreciar",
+    "despreciarse",
+    "desprende",
+    "desprenderse",
+    "desprendió",
+    "desprendía",
+    "desprestigiando",
+    "desprestigio",
+    "desproporcionadas",
+    "desproporcionado",
+    "despropósito",
+    "desprotegerán",
+    "desprovisto",
+    "después",
+    "desquita",
+    "desregulación",
+    "desregulado",
+    "desregulando",
+    "desrregulación",
+    "destaca",
+    "destacaba",
+    "destacada",
+    "destacadas",
+    "destacado",
+    "destacados",
+    "destacamentados",
+    "destacan",
+    "destacando",
+    "destacar",
+    "destacaron",
+    "destacó",
+    "destanteados",
+    "destapan",
+    "destaparon",
+    "destape",
+    "destapes",
+    "destaque",
+    "destemplada",
+    "destemplado",
+    "desterrarlas",
+    "destinaban",
+    "destinada",
+    "destinadas",
+    "destinado",
+    "destinados",
+    "destinan",
+    "destinar",
+    "destinarlos",
+    "destinaron",
+    "destinarán",
+    "destinarían",
+    "destinatario",
+    "destinen",
+    "destino",
+    "destinos",
+    "destinó",
+    "destitución",
+    "destreza",
+    "destrozado",
+    "destrozados",
+    "destrucción",
+    "destructivo",
+    "destruida",
+    "destruir",
+    "destruirlo",
+    "destruirá",
+    "destruye",
+    "destruyeron",
+    "destruyó",
+    "desulfuración",
+    "desvanecen",
+    "desvanecieran",
+    "desveladas",
+    "desvelos",
+    "desventaja",
+    "desventajosa",
+    "desventajosas",
+    "desviaciones",
+    "desviadas",
+    "desviado",
+    "desviar",
+    "desviaremos",
+    "desviarse",
+    "desviaría",
+    "desvinculaba",
+    "desvincular",
+    "desvirtuación",
+    "desvirtuar",
+    "desvirtúa",
+    "desviándose",
+    "desvió",
+    "desvíe",
+    "desánimo",
+    "desórdenes",
+    "detalla",
+    "detalladamente",
+    "detalladas",
+    "detallado",
+    "detalle",
+    "detalles",
+    "detallista",
+    "detallistas",
+    "detallitos",
+    "detalló",
+    "detecciones",
+    "detecnología",
+    "detecta",
+    "detectaba",
+    "detectada",
+    "detectado",
+    "detectados",
+    "detectan",
+    "detectando",
+    "detectar",
+    "detectara",
+    "detectaron",
+    "detectó",
+    "detenciones",
+    "detención",
+    "detendrá",
+    "detener",
+    "detenerlo",
+    "detenerme",
+    "detenerse",
+    "detengan",
+    "detenida",
+    "detenidas",
+    "detenido",
+    "detenidos",
+    "detenta",
+    "detentando",
+    "detergente",
+    "detergentes",
+    "deterioradas",
+    "deterioran",
+    "deterioro",
+    "determina",
+    "determinaciones",
+    "determinación",
+    "determinada",
+    "determinado",
+    "determinados",
+    "determinan",
+    "determinante",
+    "determinantemente",
+    "determinantes",
+    "determinar",
+    "determinaran",
+    "determinaron",
+    "determinarse",
+    "determinará",
+    "determinó",
+    "deterner",
+    "detestan",
+    "detiene",
+    "detienen",
+    "detonación",
+    "detrás",
+    "detuviera",
+    "detuvieran",
+    "detuvieron",
+    "detuvo",
+    "deuda",
+    "deudas",
+    "deuteronomio",
+    "devaluación",
+    "devaluado",
+    "devalúan",
+    "devant",
+    "devanándose",
+    "devastada",
+    "devastador",
+    "develará",
+    "devenido",
+    "devenir",
+    "devociones",
+    "devoción",
+    "devolución",
+    "devolver",
+    "devolverle",
+    "devolvería",
+    "devolvió",
+    "devuelto",
+    "devueltos",
+    "devuelvan",
+    "devuelve",
+    "df",
+    "dgo",
+    "dhl",
+    "di",
+    "dia",
+    "diabetes",
+    "diablo",
+    "diablos",
+    "diadema",
+    "diagnosticamos",
+    "diagnosticar",
+    "diagnóstico",
+    "diagnósticos",
+    "diagrama",
+    "dialogado",
+    "dialogamos",
+    "dialogar",
+    "dialogaron",
+    "diamante",
+    "diana",
+    "diaria",
+    "diariamente",
+    "diarias",
+    "diario",
+    "diarios",
+    "dibujando",
+    "dibujantes",
+    "dibujo",
+    "dibujos",
+    "dibujó",
+    "dicamex",
+    "diccionario",
+    "dice",
+    "dicen",
+    "dices",
+    "dicha",
+    "dichas",
+    "dicho",
+    "dichos",
+    "diciembre",
+    "diciendo",
+    "diciéndome",
+    "dickens",
+    "dicom",
+    "dicta",
+    "dictada",
+    "dictadas",
+    "dictado",
+    "dictador",
+    "dictadura",
+    "dictaduras",
+    "dictamen",
+    "dictaminaron",
+    "dictaminó",
+    "dictar",
+    "dictarnos",
+    "dictaron",
+    "dictatorial",
+    "dictó",
+    "didáctico",
+    "diego",
+    "dientes",
+    "diera",
+    "dieran",
+    "dieron",
+    "diestro",
+    "dieta",
+    "diez",
+    "diezmado",
+    "dif",
+    "difamación",
+    "difamador",
+    "difame",
+    "diferencia",
+    "diferenciado",
+    "diferencial",
+    "diferenciales",
+    "diferenciando",
+    "diferenciar",
+    "diferenciarse",
+    "diferencias",
+    "diferendos",
+    "diferente",
+    "diferentes",
+    "diferida",
+    "diferidos",
+    "dificil",
+    "dificilmente",
+    "dificultaba",
+    "dificultad",
+    "dificultades",
+    "dificultado",
+    "dificultan",
+    "dificultar",
+    "dificultó",
+    "difieren",
+    "difunda",
+    "difunde",
+    "difunden",
+    "difundida",
+    "difundido",
+    "difundir",
+    "difundirlo",
+    "difundirá",
+    "difunto",
+    "difuntos",
+    "difusión",
+    "difícil",
+    "difíciles",
+    "difícilmente",
+    "diga",
+    "digamos",
+    "digan",
+    "digerir",
+    "digestivos",
+    "digestión",
+    "digitales",
+    "digitalización",
+    "digna",
+    "dignas",
+    "dignidad",
+    "dignifica",
+    "dignificar",
+    "dignity",
+    "digno",
+    "dignos",
+    "dignosticar",
+    "digo",
+    "dihigo",
+    "dihlgo",
+    "dije",
+    "dijera",
+    "dijeran",
+    "dijeron",
+    "dijes",
+    "dijiste",
+    "dijo",
+    "dilatadas",
+    "dilatar",
+    "dilatorias",
+    "dilema",
+    "diligencia",
+    "diligencias",
+    "diligente",
+    "dillo",
+    "dilucidar",
+    "diluido",
+    "diluir",
+    "dimas",
+    "dimensiones",
+    "dimensión",
+    "dimino",
+    "diminutas",
+    "diminutos",
+    "dimisión",
+    "dimitir",
+    "dimitri",
+    "dimos",
+    "dina",
+    "dinamarca",
+    "dinamismo",
+    "dinamiteros",
+    "dinastía",
+    "dinero",
+    "dineros",
+    "dinorah",
+    "dinosaurios",
+    "dinámica",
+    "dinámicas",
+    "dinámico",
+    "dinámicos",
+    "dio",
+    "dior",
+    "diorita",
+    "dios",
+    "diosa",
+    "dioses",
+    "diplomacia",
+    "diplomado",
+    "diplomados",
+    "diplomática",
+    "diplomáticas",
+    "diplomático",
+    "diplomáticos",
+    "diputa",
+    "diputaciones",
+    "diputación",
+    "diputada",
+    "diputado",
+    "diputados",
+    "direccionales",
+    "direcciones",
+    "dirección",
+    "directa",
+    "directamente",
+    "directas",
+    "directiva",
+    "directivas",
+    "directivo",
+    "directivos",
+    "directo",
+    "directora",
+    "directores",
+    "directorio",
+    "directorios",
+    "directos",
+    "dirige",
+    "dirigencia",
+    "dirigente",
+    "dirigentes",
+    "dirigible",
+    "dirigida",
+    "dirigidas",
+    "dirigido",
+    "dirigidos",
+    "dirigiendo",
+    "dirigieron",
+    "dirigir",
+    "dirigirla",
+    "dirigirme",
+    "dirigirse",
+    "dirigirá",
+    "dirigió",
+    "dirigía",
+    "dirigían",
+    "dirija",
+    "dirimir",
+    "dirá",
+    "dirán",
+    "diré",
+    "diría",
+    "disc",
+    "discapacidad",
+    "discapacidades",
+    "discapacitada",
+    "discapacitadas",
+    "discapacitado",
+    "discapacitados",
+    "disciplina",
+    "disciplinada",
+    "disciplinadas",
+    "disciplinaria",
+    "disciplinario",
+    "disciplinas",
+    "disco",
+    "discografía",
+    "discográfico",
+    "discos",
+    "discoteca",
+    "discotecas",
+    "discrepancia",
+    "discreto",
+    "discretos",
+    "discriminación",
+    "discs",
+    "disculpa",
+    "disculpaba",
+    "disculpe",
+    "discurso",
+    "discursos",
+    "discusiones",
+    "discusión",
+    "discuta",
+    "discutido",
+    "discutieron",
+    "discutir",
+    "discutirse",
+    "discutió",
+    "discutían",
+    "discípulos",
+    "disertará",
+    "diseña",
+    "diseñada",
+    "diseñadas",
+    "diseñado",
+    "diseñador",
+    "diseñadores",
+    "diseñados",
+    "diseñan",
+    "diseñando",
+    "diseñar",
+    "diseñaron",
+    "diseñe",
+    "diseño",
+    "diseños",
+    "diseñó",
+    "disfrace",
+    "disfraces",
+    "disfrazada",
+    "disfrazados",
+    "disfrazar",
+    "disfrazaron",
+    "disfrazarse",
+    "disfruta",
+    "disfrutando",
+    "disfrutar",
+    "disfrutaron",
+    "disfrutarse",
+    "disfrutará",
+    "disfrute",
+    "disfrutó",
+    "disgregulación",
+    "disgusta",
+    "disgustado",
+    "disgustarle",
+    "disgusto",
+    "disidente",
+    "disidentes",
+    "disimular",
+    "disipar",
+    "disipó",
+    "diskete",
+    "diskette",
+    "diskettes",
+    "disléxico",
+    "disminución",
+    "disminuidas",
+    "disminuido",
+    "disminuir",
+    "disminuiría",
+    "disminuye",
+    "disminuyendo",
+    "disminuyó",
+    "disney",
+    "disolvió",
+    "disparaba",
+    "disparado",
+    "disparan",
+    "disparando",
+    "disparar",
+    "dispararon",
+    "dispararse",
+    "disparate",
+    "disparates",
+    "disparo",
+    "disparos",
+    "disparó",
+    "dispendio",
+    "dispendiosa",
+    "dispensa",
+    "dispersan",
+    "dispersión",
+    "disperso",
+    "dispersos",
+    "dispersó",
+    "displicencia",
+    "dispone",
+    "disponen",
+    "disponga",
+    "dispongo",
+    "disponibilidad",
+    "disponible",
+    "disponibles",
+    "disponía",
+    "disposiciones",
+    "disposición",
+    "dispositivo",
+    "dispositivos",
+    "dispuesta",
+    "dispuesto",
+    "dispuestos",
+    "dispusieron",
+    "dispuso",
+    "disputa",
+    "disputadas",
+    "disputado",
+    "disputar",
+    "disputaron",
+    "disputará",
+    "disputarán",
+    "disputarían",
+    "disputas",
+    "disputó",
+    "dispóngase",
+    "disquera",
+    "disqueras",
+    "distancia",
+    "distancias",
+    "distensión",
+    "distinciones",
+    "distinción",
+    "distingos",
+    "distinguen",
+    "distinguida",
+    "distinguidas",
+    "distinguido",
+    "distinguidos",
+    "distinguimos",
+    "distinguir",
+    "distinta",
+    "distintas",
+    "distintivo",
+    "distinto",
+    "distintos",
+    "distorsione",
+    "distorsiones",
+    "distraen",
+    "distraer",
+    "distrajo",
+    "distraída",
+    "distribución",
+    "distribuidas",
+    "distribuido",
+    "distribuidor",
+    "distribuidora",
+    "distribuidoras",
+    "distribuidores",
+    "distribuidos",
+    "distribuimos",
+    "distribuir",
+    "distribuirla",
+    "distribuirse",
+    "distribuirán",
+    "distribuya",
+    "distribuye",
+    "distribuyen",
+    "distribuyendo",
+    "distribuyó",
+    "distribuía",
+    "distritales",
+    "distrito",
+    "distritos",
+    "distrofia",
+    "disturbios",
+    "disyuntiva",
+    "dit",
+    "diva",
+    "divergencias",
+    "diversa",
+    "diversas",
+    "diversidad",
+    "diversificación",
+    "diversificándose",
+    "diversificó",
+    "diversión",
+    "diverso",
+    "diversos",
+    "divertida",
+    "divertidas",
+    "divertido",
+    "divertir",
+    "divertirnos",
+    "divertirse",
+    "divertía",
+    "dividendos",
+    "dividido",
+    "divididos",
+    "dividir",
+    "dividirnos",
+    "dividiéndose",
+    "dividió",
+    "divina",
+    "divinas",
+    "divino",
+    "divirtieran",
+    "divisas",
+    "divisional",
+    "divisiones",
+    "división",
+    "divisoria",
+    "divorciado",
+    "divorciarme",
+    "divorcio",
+    "divorcios",
+    "divorció",
+    "divulgar",
+    "diván",
+    "diz",
+    "dizque",
+    "dizzie",
+    "diálogo",
+    "diálogos",
+    "diámetro",
+    "diócesis",
+    "dióxido",
+    "dls",
+    "dnx",
+    "dobla",
+    "doblaje",
+    "doblajes",
+    "doblar",
+    "doble",
+    "doblegado",
+    "doblegamiento",
+    "doblegando",
+    "doblegar",
+    "doblemente",
+    "dobles",
+    "doblinger",
+    "doblista",
+    "doblistas",
+    "dobson",
+    "doce",
+    "docena",
+    "docenas",
+    "docencia",
+    "docente",
+    "docentes",
+    "dock",
+    "docta",
+    "doctora",
+    "doctorados",
+    "doctoral",
+    "doctores",
+    "doctoró",
+    "doctrina",
+    "doctrinario",
+    "doctrinas",
+    "documentación",
+    "documentada",
+    "documentadas",
+    "documental",
+    "documentales",
+    "documento",
+    "documentos",
+    "documenté",
+    "dodgers",
+    "doger",
+    "dogs",
+    "dolares",
+    "dolce",
+    "doliéndose",
+    "dolo",
+    "dolor",
+    "dolores",
+    "dolorido",
+    "dolorosa",
+    "dolosa",
+    "doman",
+    "domar",
+    "domene",
+    "domesticados",
+    "domesticaron",
+    "domiciliado",
+    "domiciliaria",
+    "domiciliarias",
+    "domicilio",
+    "domicilios",
+    "domina",
+    "dominada",
+    "dominado",
+    "dominador",
+    "dominan",
+    "dominando",
+    "dominantes",
+    "dominar",
+    "dominarlo",
+    "dominen",
+    "domingo",
+    "domingos",
+    "dominical",
+    "dominicana",
+    "dominicano",
+    "dominicanos",
+    "dominio",
+    "dominó",
+    "domizzi",
+    "doméstica",
+    "domésticas",
+    "doméstico",
+    "domésticos",
+    "domínguez",
+    "don",
+    "dona",
+    "donadas",
+    "donado",
+    "donados",
+    "donald",
+    "donaldo",
+    "donan",
+    "donantes",
+    "donar",
+    "donará",
+    "donas",
+    "donativo",
+    "donativos",
+    "doncella",
+    "donde",
+    "dones",
+    "donna",
+    "donó",
+    "doors",
+    "doquier",
+    "dora",
+    "dorada",
+    "doradas",
+    "dorado",
+    "dorados",
+    "dorar",
+    "doria",
+    "doris",
+    "dormir",
+    "dormirme",
+    "dormirán",
+    "dormitó",
+    "dormí",
+    "dormía",
+    "dorsal",
+    "dorsets",
+    "dorsey",
+    "dosamantes",
+    "doscientas",
+    "dosis",
+    "dotación",
+    "dotadas",
+    "dotar",
+    "dotes",
+    "dow",
+    "doy",
+    "doña",
+    "dr",
+    "dra",
+    "dragster",
+    "dragón",
+    "drake",
+    "drama",
+    "dramas",
+    "dramatismo",
+    "dramaturgia",
+    "dramática",
+    "dramáticamente",
+    "dramático",
+    "dramáticos",
+    "drenaje",
+    "droga",
+    "drogadicción",
+    "drogadicto",
+    "drogadictos",
+    "drogado",
+    "drogan",
+    "drogarse",
+    "drogas",
+    "drácula",
+    "drástica",
+    "drásticamente",
+    "drásticas",
+    "drásticos",
+    "du",
+    "duarte",
+    "ducharme",
+    "ductilidad",
+    "duda",
+    "dudaba",
+    "dudaban",
+    "dudamos",
+    "dudas",
+    "dudo",
+    "dudosos",
+    "duela",
+    "duele",
+    "duelen",
+    "duelo",
+    "dueña",
+    "dueño",
+    "dueños",
+    "duke",
+    "dulce",
+    "dulces",
+    "dulles",
+    "dulzura",
+    "dumping",
+    "dune",
+    "dunkin",
+    "duo",
+    "dupla",
+    "duplicación",
+    "duplicado",
+    "duplicados",
+    "duplicar",
+    "duplicará",
+    "duplicidad",
+    "duplicidades",
+    "duplicó",
+    "dupont",
+    "duque",
+    "duques",
+    "duquesa",
+    "dura",
+    "duraban",
+    "durabilidad",
+    "duración",
+    "duradero",
+    "duramente",
+    "duran",
+    "durango",
+    "duranguense",
+    "durante",
+    "durar",
+    "durara",
+    "durará",
+    "durarán",
+    "duras",
+    "durazno",
+    "durbin",
+    "dure",
+    "duren",
+    "dureza",
+    "durmió",
+    "duro",
+    "duros",
+    "dury",
+    "durán",
+    "duró",
+    "dwyer",
+    "dye",
+    "dzul",
+    "dándole",
+    "dándonos",
+    "dárselo",
+    "dárselos",
+    "dávalos",
+    "dávila",
+    "dé",
+    "débil",
+    "débiles",
+    "débito",
+    "década",
+    "décadas",
+    "décima",
+    "décimas",
+    "décimo",
+    "déficit",
+    "déis",
+    "déjame",
+    "déjeme",
+    "déle",
+    "déme",
+    "dése",
+    "dí",
+    "día",
+    "días",
+    "díaz",
+    "díez",
+    "dígale",
+    "dígales",
+    "dígame",
+    "dígito",
+    "díle",
+    "dócil",
+    "dólar",
+    "dólares",
+    "dónde",
+    "dúo",
+    "dúrcal",
+    "e",
+    "eagles",
+    "earl",
+    "eastwood",
+    "eau",
+    "ebanista",
+    "ebanitos",
+    "eberhard",
+    "ebriedad",
+    "ebrio",
+    "ebullición",
+    "echa",
+    "echaban",
+    "echada",
+    "echado",
+    "echan",
+    "echando",
+    "echao",
+    "echar",
+    "echara",
+    "echarle",
+    "echarnos",
+    "echarán",
+    "echas",
+    "echavarría",
+    "eche",
+    "echen",
+    "echeverría",
+    "echoes",
+    "echándole",
+    "echó",
+    "eckhard",
+    "eclesiásticas",
+    "eclesíasticos",
+    "eco",
+    "ecologista",
+    "ecologistas",
+    "ecología",
+    "ecologísta",
+    "ecológica",
+    "ecológicas",
+    "ecológico",
+    "ecológicos",
+    "econometristas",
+    "economia",
+    "economic",
+    "economist",
+    "economista",
+    "economistas",
+    "economía",
+    "economías",
+    "económica",
+    "económicamente",
+    "económicas",
+    "económico",
+    "económicos",
+    "ecosistema",
+    "ecosistemas",
+    "ecotaxi",
+    "ecotaxis",
+    "ecotaxistas",
+    "ecotécnicas",
+    "ecuaciones",
+    "ecuador",
+    "ecuatoriana",
+    "ecuatoriano",
+    "ecuánime",
+    "edad",
+    "edades",
+    "edecán",
+    "edelmiro",
+    "eden",
+    "eder",
+    "edgar",
+    "edgardo",
+    "ediciones",
+    "edición",
+    "edifica",
+    "edificaciones",
+    "edificación",
+    "edificar",
+    "edificio",
+    "edificios",
+    "edifico",
+    "edil",
+    "edilberto",
+    "ediles",
+    "edison",
+    "edita",
+    "editada",
+    "editado",
+    "editados",
+    "editar",
+    "edith",
+    "editoras",
+    "editores",
+    "editorial",
+    "editoriales",
+    "editorialista",
+    "editorialy",
+    "editó",
+    "edmundo",
+    "edomex",
+    "edu",
+    "eduard",
+    "eduardo",
+    "educación",
+    "educada",
+    "educador",
+    "educadores",
+    "educando",
+    "educar",
+    "educaron",
+    "educativa",
+    "educativas",
+    "educativo",
+    "educativos",
+    "eduque",
+    "edward",
+    "edén",
+    "edú",
+    "efe",
+    "efectiva",
+    "efectivamente",
+    "efectivas",
+    "efectividad",
+    "efectivo",
+    "efectivos",
+    "efecto",
+    "efectos",
+    "efectuaba",
+    "efectuada",
+    "efectuadas",
+    "efectuado",
+    "efectuar",
+    "efectuaron",
+    "efectuarse",
+    "efectuará",
+    "efectuarán",
+    "efectuaría",
+    "efectuo",
+    "efectuó",
+    "efectúa",
+    "efectúan",
+    "efectúen",
+    "efectúo",
+    "eficaces",
+    "eficacia",
+    "eficaz",
+    "eficazmente",
+    "eficiencia",
+    "eficientarlas",
+    "eficiente",
+    "eficientemente",
+    "eficientes",
+    "eficientización",
+    "efigie",
+    "efluentes",
+    "efraín",
+    "efusivamente",
+    "efímero",
+    "egca",
+    "eggert",
+    "egipcios",
+    "egipto",
+    "ego",
+    "egocentrismo",
+    "egoísmo",
+    "egoísta",
+    "egresaban",
+    "egresada",
+    "egresados",
+    "egresan",
+    "egresaron",
+    "egresen",
+    "egreso",
+    "egresos",
+    "eiffel",
+    "eje",
+    "ejecución",
+    "ejecuta",
+    "ejecutaba",
+    "ejecutable",
+    "ejecutada",
+    "ejecutado",
+    "ejecutados",
+    "ejecutando",
+    "ejecutantes",
+    "ejecutar",
+    "ejecutarse",
+    "ejecutarán",
+    "ejecutiva",
+    "ejecutivas",
+    "ejecutivo",
+    "ejecutivos",
+    "ejecutó",
+    "ejemplar",
+    "ejemplares",
+    "ejemplarmente",
+    "ejemplificó",
+    "ejemplifique",
+    "ejemplo",
+    "ejemplos",
+    "ejerce",
+    "ejercen",
+    "ejercer",
+    "ejercerán",
+    "ejercicio",
+    "ejercicios",
+    "ejerciendo",
+    "ejerciera",
+    "ejercitación",
+    "ejercito",
+    "ejercían",
+    "ejidatarios",
+    "ejido",
+    "ejército",
+    "ejércitos",
+    "el",
+    "elaboración",
+    "elaborada",
+    "elaboradas",
+    "elaborado",
+    "elaborados",
+    "elaborar",
+    "elaborarán",
+    "elabore",
+    "elaboró",
+    "elba",
+    "elcrimen",
+    "eldridge",
+    "eleanor",
+    "eleccionario",
+    "elecciones",
+    "elección",
+    "electa",
+    "electo",
+    "elector",
+    "electorado",
+    "electoral",
+    "electorales",
+    "electoralmente",
+    "electores",
+    "electos",
+    "electricidad",
+    "electricistas",
+    "electroacústica",
+    "electrodomésticos",
+    "electromagnéticas",
+    "electrónica",
+    "electrónicas",
+    "electrónico",
+    "electrónicos",
+    "elefante",
+    "elegancia",
+    "elegante",
+    "elegantemente",
+    "elegantes",
+    "elegidas",
+    "elegido",
+    "elegidos",
+    "elegir",
+    "elegirse",
+    "elegirá",
+    "elegirán",
+    "elektra",
+    "elemental",
+    "elementales",
+    "elemento",
+    "elementos",
+    "elena",
+    "elenco",
+    "elencos",
+    "elepé",
+    "elevaban",
+    "elevación",
+    "elevada",
+    "elevadas",
+    "elevado",
+    "elevados",
+    "elevamos",
+    "elevan",
+    "elevar",
+    "elevaran",
+    "elevarse",
+    "elevaría",
+    "elevó",
+    "elia",
+    "elida",
+    "elie",
+    "elier",
+    "eligen",
+    "eligieron",
+    "eligirá",
+    "eligirán",
+    "eligió",
+    "elija",
+    "elijan",
+    "elimina",
+    "eliminación",
+    "eliminada",
+    "eliminado",
+    "eliminar",
+    "eliminarla",
+    "eliminarse",
+    "eliminará",
+    "eliminarán",
+    "eliminarían",
+    "eliminatoria",
+    "eliminatorias",
+    "eliminatorios",
+    "elimine",
+    "eliminó",
+    "eliodoro",
+    "elisa",
+    "eliseo",
+    "eliud",
+    "elizondo",
+    "ella",
+    "ellas",
+    "ellington",
+    "ello",
+    "ellos",
+    "elmer",
+    "elmo",
+    "elocuencia",
+    "elogiado",
+    "elogio",
+    "elogios",
+    "elogiosas",
+    "eloisa",
+    "elosúa",
+    "elotitos",
+    "eloxochitlán",
+    "eloy",
+    "eloísa",
+    "elton",
+    "elude",
+    "eludir",
+    "elva",
+    "elvis",
+    "elásticos",
+    "eléctrica",
+    "eléctricas",
+    "eléctrico",
+    "eléctricos",
+    "elías",
+    "em",
+    "emana",
+    "emanadas",
+    "emanciparse",
+    "ematografía",
+    "embajada",
+    "embajadas",
+    "embajador",
+    "embajadora",
+    "embajadores",
+    "embalaje",
+    "embarazada",
+    "embarazarse",
+    "embarazo",
+    "embarazosa",
+    "embarazoso",
+    "embarcaciones",
+    "embarcación",
+    "embarcadas",
+    "embarcar",
+    "embargaron",
+    "embargo",
+    "embarrarnos",
+    "embestida",
+    "embestidas",
+    "embestido",
+    "embestía",
+    "embistiendo",
+    "embistió",
+    "emblema",
+    "embolsó",
+    "emboscada",
+    "emboscado",
+    "emboscados",
+    "emboscan",
+    "embotelladora",
+    "embotelladoras",
+    "embotellamientos",
+    "embravecidas",
+    "embriagantes",
+    "embriagarse",
+    "embriagas",
+    "embutidos",
+    "emerge",
+    "emergencia",
+    "emergencias",
+    "emergente",
+    "emergentes",
+    "emerson",
+    "emes",
+    "emigramos",
+    "emigrantes",
+    "emigrar",
+    "emigraron",
+    "emiliano",
+    "emilio",
+    "eminencia",
+    "eminentemente",
+    "emirato",
+    "emisiones",
+    "emisión",
+    "emisora",
+    "emisoras",
+    "emisores",
+    "emita",
+    "emitan",
+    "emiten",
+    "emitida",
+    "emitidas",
+    "emitido",
+    "emitidos",
+    "emitiendo",
+    "emitieron",
+    "emitir",
+    "emitirá",
+    "emitirán",
+    "emitiría",
+    "emitió",
+    "emitían",
+    "emmanuel",
+    "emmitth",
+    "emocionada",
+    "emocionado",
+    "emocionados",
+    "emocional",
+    "emocionante",
+    "emocionaron",
+    "emociones",
+    "emocionó",
+    "emoción",
+    "emory",
+    "emotivamente",
+    "empacadores",
+    "empacados",
+    "empacamex",
+    "empadronados",
+    "empalagó",
+    "empanadas",
+    "empantanadas",
+    "empantanar",
+    "empape",
+    "empapé",
+    "empaquetado",
+    "empatado",
+    "empatados",
+    "empatar",
+    "empatarles",
+    "empataron",
+    "empate",
+    "empates",
+    "empató",
+    "empecinan",
+    "empecé",
+    "empeoran",
+    "empeorar",
+    "emperador",
+    "emperatriz",
+    "empero",
+    "empezaba",
+    "empezaban",
+    "empezado",
+    "empezamos",
+    "empezando",
+    "empezar",
+    "empezarlos",
+    "empezaron",
+    "empezará",
+    "empezarán",
+    "empezó",
+    "empeñado",
+    "empeñados",
+    "empeño",
+    "empiece",
+    "empiecen",
+    "empieza",
+    "empiezan",
+    "emplazamiento",
+    "emplea",
+    "empleada",
+    "empleadas",
+    "empleado",
+    "empleados",
+    "empleando",
+    "emplear",
+    "empleo",
+    "empleos",
+    "empleó",
+    "empobrecido",
+    "emporio",
+    "emprendedor",
+    "emprendedores",
+    "emprender",
+    "emprenderá",
+    "emprendido",
+    "emprendiendo",
+    "emprendimos",
+    "emprendió",
+    "empresa",
+    "empresaria",
+    "empresarial",
+    "empresariales",
+    "empresario",
+    "empresarios",
+    "empresas",
+    "empréstitos",
+    "empujamos",
+    "empujan",
+    "empujando",
+    "empujar",
+    "empujarlo",
+    "emular",
+    "en",
+    "enajenación",
+    "enamora",
+    "enamorado",
+    "enamoró",
+    "encabece",
+    "encabeza",
+    "encabezada",
+    "encabezado",
+    "encabezados",
+    "encabezar",
+    "encabezaron",
+    "encabezará",
+    "encabezó",
+    "encadenaban",
+    "encadenado",
+    "encaja",
+    "encajan",
+    "encaje",
+    "encajes",
+    "encallados",
+    "encallar",
+    "encaminadas",
+    "encaminaron",
+    "encaminará",
+    "encanta",
+    "encantada",
+    "encantador",
+    "encantados",
+    "encantan",
+    "encantaría",
+    "encanto",
+    "encantos",
+    "encapricha",
+    "encara",
+    "encarcelado",
+    "encarcelados",
+    "encarcelamiento",
+    "encarecerá",
+    "encarga",
+    "encargaba",
+    "encargaban",
+    "encargada",
+    "encargadas",
+    "encargado",
+    "encargados",
+    "encargar",
+    "encargara",
+    "encargaron",
+    "encargará",
+    "encargarán",
+    "encargo",
+    "encargue",
+    "encargué",
+    "encargó",
+    "encarna",
+    "encarnado",
+    "encarnan",
+    "encarnizan",
+    "encarno",
+    "encarnó",
+    "encauce",
+    "encañonaron",
+    "encendedor",
+    "encendedores",
+    "encendido",
+    "encendidos",
+    "encerar",
+    "enceren",
+    "encerrado",
+    "encerrados",
+    "encerrarnos",
+    "encerraron",
+    "encerrona",
+    "enchalecados",
+    "enchinchar",
+    "enciclopedia",
+    "enciclopedias",
+    "enciclopédico",
+    "encienda",
+    "encierra",
+    "encierro",
+    "encima",
+    "encke",
+    "enclaustrados",
+    "encomendadas",
+    "encomendando",
+    "encomiable",
+    "encomienda",
+    "encomiende",
+    "encontraba",
+    "encontraban",
+    "encontrada",
+    "encontradas",
+    "encontrado",
+    "encontrados",
+    "encontramos",
+    "encontrar",
+    "encontrara",
+    "encontraran",
+    "encontraremos",
+    "encontrarle",
+    "encontrarlo",
+    "encontrarme",
+    "encontraron",
+    "encontrarse",
+    "encontrará",
+    "encontrarán",
+    "encontrarás",
+    "encontrársele",
+    "encontré",
+    "encontró",
+    "encriptación",
+    "encrucijada",
+    "encuadrados",
+    "encuadren",
+    "encubierta",
+    "encubiertos",
+    "encuentra",
+    "encuentran",
+    "encuentras",
+    "encuentre",
+    "encuentro",
+    "encuentros",
+    "encuesta",
+    "encuestadora",
+    "encuestados",
+    "encuestaron",
+    "encuestas",
+    "encueste",
+    "encumbran",
+    "endeavour",
+    "endebles",
+    "enderezadas",
+    "endeudamiento",
+    "endiosado",
+    "endiosamiento",
+    "endocrinología",
+    "endocrinólogo",
+    "endulce",
+    "endulzada",
+    "enduro",
+    "enemiga",
+    "enemigo",
+    "enemigos",
+    "energética",
+    "energético",
+    "energéticos",
+    "energía",
+    "enero",
+    "enervante",
+    "enervantes",
+    "enfadaría",
+    "enfatiza",
+    "enfatizará",
+    "enfatizó",
+    "enferma",
+    "enfermarse",
+    "enfermedad",
+    "enfermedades",
+    "enfermera",
+    "enfermeras",
+    "enfermo",
+    "enfermos",
+    "enfermé",
+    "enfiladas",
+    "enfilamos",
+    "enfoca",
+    "enfocadas",
+    "enfocado",
+    "enfocados",
+    "enfocan",
+    "enfocar",
+    "enfocarse",
+    "enfocará",
+    "enfocarán",
+    "enfoque",
+    "enfoquen",
+    "enfrascados",
+    "enfrenta",
+    "enfrentaba",
+    "enfrentada",
+    "enfrentado",
+    "enfrentamiento",
+    "enfrentamientos",
+    "enfrentamos",
+    "enfrentan",
+    "enfrentando",
+    "enfrentar",
+    "enfrentaremos",
+    "enfrentarlos",
+    "enfrentarme",
+    "enfrentaron",
+    "enfrentarse",
+    "enfrentará",
+    "enfrentarán",
+    "enfrente",
+    "enfrenten",
+    "enfrentándose",
+    "enfrentó",
+    "enfriamiento",
+    "enfundaron",
+    "enfurecieron",
+    "enfóquese",
+    "engalana",
+    "engancharle",
+    "engañaba",
+    "engañado",
+    "engañar",
+    "engaño",
+    "engañosas",
+    "engendra",
+    "englutidos",
+    "engomados",
+    "engordar",
+    "engordó",
+    "engorrosas",
+    "engorrosos",
+    "engracia",
+    "engranaje",
+    "engrandecer",
+    "engrane",
+    "engrasen",
+    "enhorabuena",
+    "enigmático",
+    "enjambre",
+    "enjuiciado",
+    "enjuiciar",
+    "enlace",
+    "enlatados",
+    "enlaza",
+    "enlazadas",
+    "enlazado",
+    "enlistar",
+    "enlisten",
+    "enmarañado",
+    "enmarca",
+    "enmarcarlos",
+    "enmarcaron",
+    "enmarcarse",
+    "enmascarado",
+    "enmedio",
+    "enmendar",
+    "enoc",
+    "enojaran",
+    "enojo",
+    "enojó",
+    "enorme",
+    "enormemente",
+    "enormes",
+    "enraizada",
+    "enrazado",
+    "enrazados",
+    "enreden",
+    "enrico",
+    "enrique",
+    "enriquece",
+    "enriquecer",
+    "enriquecerse",
+    "enriquecida",
+    "enriquecimiento",
+    "enriqueció",
+    "enríquez",
+    "ensambladora",
+    "ensamblar",
+    "ensamble",
+    "ensambles",
+    "ensanchado",
+    "ensangrentados",
+    "ensaya",
+    "ensayado",
+    "ensayaron",
+    "ensayista",
+    "ensayistas",
+    "ensayo",
+    "enseguida",
+    "ensenada",
+    "enseña",
+    "enseñaba",
+    "enseñaban",
+    "enseñado",
+    "enseñan",
+    "enseñando",
+    "enseñanza",
+    "enseñanzas",
+    "enseñar",
+    "enseñarles",
+    "enseñen",
+    "enseñes",
+    "enseñó",
+    "ensoberbecerse",
+    "ensombrecían",
+    "ensorberbecera",
+    "ensordecedores",
+    "ensuciar",
+    "entabladas",
+    "entablar",
+    "entablarse",
+    "ente",
+    "entenados",
+    "entendederas",
+    "entendemos",
+    "entender",
+    "entenderá",
+    "entenderé",
+    "entenderíamos",
+    "entendible",
+    "entendibles",
+    "entendido",
+    "entendimiento",
+    "entendí",
+    "entera",
+    "enterada",
+    "enterado",
+    "enterados",
+    "enteramente",
+    "enteramos",
+    "enterar",
+    "enterarse",
+    "entere",
+    "enteremos",
+    "enteren",
+    "entereza",
+    "entero",
+    "enteros",
+    "enterrarlos",
+    "enteró",
+    "entes",
+    "entidad",
+    "entidades",
+    "entienda",
+    "entiendan",
+    "entiende",
+    "entienden",
+    "entierro",
+    "entonado",
+    "entonces",
+    "entonen",
+    "entorno",
+    "entorpece",
+    "entra",
+    "entraban",
+    "entrada",
+    "entradas",
+    "entrado",
+    "entramos",
+    "entran",
+    "entrando",
+    "entrante",
+    "entrar",
+    "entraran",
+    "entrarle",
+    "entraron",
+    "entrará",
+    "entrarán",
+    "entraría",
+    "entrarían",
+    "entraña",
+    "entrañaría",
+    "entre",
+    "entrecano",
+    "entrecomillas",
+    "entrecruzan",
+    "entrega",
+    "entregada",
+    "entregadas",
+    "entregado",
+    "entregados",
+    "entregan",
+    "entregar",
+    "entregara",
+    "entregaran",
+    "entregarla",
+    "entregarle",
+    "entregarme",
+    "entregaron",
+    "entregarse",
+    "entregará",
+    "entregarán",
+    "entregaría",
+    "entregarían",
+    "entregas",
+    "entrego",
+    "entregue",
+    "entreguen",
+    "entregándoles",
+    "entregársela",
+    "entregó",
+    "entrelazadas",
+    "entrelíneas",
+    "entren",
+    "entrena",
+    "entrenaba",
+    "entrenado",
+    "entrenador",
+    "entrenadores",
+    "entrenados",
+    "entrenamiento",
+    "entrenamientos",
+    "entrenan",
+    "entrenando",
+    "entrenar",
+    "entrenarlo",
+    "entrenarlos",
+    "entrenaron",
+    "entrenará",
+    "entrenarán",
+    "entrenó",
+    "entretejida",
+    "entretener",
+    "entretenimiento",
+    "entretenimientos",
+    "entretenían",
+    "entrever",
+    "entrevista",
+    "entrevistaba",
+    "entrevistada",
+    "entrevistado",
+    "entrevistados",
+    "entrevistar",
+    "entrevistaron",
+    "entrevistarse",
+    "entrevistas",
+    "entrevistó",
+    "entrometiera",
+    "entrándole",
+    "entré",
+    "entréguenos",
+    "entró",
+    "entusiasmada",
+    "entusiasmado",
+    "entusiasmo",
+    "entusiasta",
+    "entusiastas",
+    "enumera",
+    "enumerará",
+    "envasadora",
+    "envase",
+    "envases",
+    "envejecen",
+    "envejecimiento",
+    "envenenada",
+    "envenenarla",
+    "envia",
+    "enviada",
+    "enviadas",
+    "enviado",
+    "enviados",
+    "enviamos",
+    "enviando",
+    "enviar",
+    "enviarlas",
+    "enviaron",
+    "enviará",
+    "enviarán",
+    "enviaría",
+    "envidia",
+    "envidiables",
+    "envidiamos",
+    "envidias",
+    "envidiosos",
+    "envio",
+    "envió",
+    "envoltorio",
+    "envoltura",
+    "envolturas",
+    "envolverse",
+    "envolviéndose",
+    "envueltos",
+    "envía",
+    "envían",
+    "envíe",
+    "envío",
+    "enyesado",
+    "enérgica",
+    "enérgicas",
+    "enérgico",
+    "enésima",
+    "eory",
+    "epazote",
+    "episcopado",
+    "episcopal",
+    "episodio",
+    "episodios",
+    "epistolar",
+    "epístola",
+    "equidad",
+    "equilibrada",
+    "equilibrado",
+    "equilibrar",
+    "equilibrarlos",
+    "equilibrio",
+    "equipada",
+    "equipado",
+    "equipados",
+    "equipaje",
+    "equipan",
+    "equipar",
+    "equipararnos",
+    "equipment",
+    "equipo",
+    "equipos",
+    "equisetum",
+    "equitación",
+    "equivale",
+    "equivalen",
+    "equivalente",
+    "equivalentes",
+    "equivoca",
+    "equivocaba",
+    "equivocaban",
+    "equivocaciones",
+    "equivocados",
+    "equivocarse",
+    "equivocó",
+    "equivoqué",
+    "er",
+    "era",
+    "erais",
+    "eramos",
+    "eran",
+    "erario",
+    "eras",
+    "eres",
+    "erhard",
+    "eric",
+    "erick",
+    "erigido",
+    "erika",
+    "ermita",
+    "ernesto",
+    "erogaron",
+    "eros",
+    "erosionada",
+    "erosionadas",
+    "erraban",
+    "erradica",
+    "erradicación",
+    "errado",
+    "errores",
+    "errática",
+    "erráticos",
+    "errónea",
+    "erróneamente",
+    "erróneo",
+    "erupción",
+    "eróticas",
+    "es",
+    "esa",
+    "esai",
+    "esas",
+    "esbozo",
+    "escabrosos",
+    "escala",
+    "escalada",
+    "escalafón",
+    "escalante",
+    "escalar",
+    "escalera",
+    "escaleras",
+    "escalinata",
+    "escalona",
+    "escalonados",
+    "escalones",
+    "escalón",
+    "escamilla",
+    "escampavías",
+    "escandalosas",
+    "escapa",
+    "escapada",
+    "escapado",
+    "escapando",
+    "escapar",
+    "escaparon",
+    "escapó",
+    "escarmiento",
+    "escasa",
+    "escasas",
+    "escasez",
+    "escaso",
+    "escasos",
+    "escaños",
+    "escena",
+    "escenario",
+    "escenarios",
+    "escenas",
+    "escenciales",
+    "escenificará",
+    "escenificarán",
+    "escenografía",
+    "escenógrafos",
+    "escisiones",
+    "esclarecer",
+    "esclarecimiento",
+    "esclarezca",
+    "esclava",
+    "esclavas",
+    "esclavitud",
+    "esclavo",
+    "escoba",
+    "escobar",
+    "escobas",
+    "escobedo",
+    "escobera",
+    "escocés",
+    "escoge",
+    "escoger",
+    "escogería",
+    "escoges",
+    "escogido",
+    "escogiendo",
+    "escogieron",
+    "escogimos",
+    "escogió",
+    "escojan",
+    "escolar",
+    "escolares",
+    "escolarización",
+    "escollo",
+    "escoltado",
+    "escombrera",
+    "escombros",
+    "escondan",
+    "esconde",
+    "esconden",
+    "esconder",
+    "esconderse",
+    "escondida",
+    "escondidas",
+    "escondido",
+    "escondite",
+    "escondió",
+    "escondrijos",
+    "escore",
+    "escoriaciones",
+    "escorpión",
+    "escote",
+    "escotes",
+    "escriba",
+    "escribe",
+    "escriben",
+    "escribiendo",
+    "escribieran",
+    "escribieron",
+    "escribir",
+    "escribirlas",
+    "escribirle",
+    "escribirse",
+    "escribirá",
+    "escribió",
+    "escribo",
+    "escribía",
+    "escrita",
+    "escritas",
+    "escrito",
+    "escritor",
+    "escritora",
+    "escritores",
+    "escritorio",
+    "escritos",
+    "escritura",
+    "escrituras",
+    "escrutinio",
+    "escríbanos",
+    "escuadra",
+    "escuadras",
+    "escuadrones",
+    "escucha",
+    "escuchaba",
+    "escuchaban",
+    "escuchadas",
+    "escuchado",
+    "escuchan",
+    "escuchando",
+    "escuchar",
+    "escucharla",
+    "escucharlos",
+    "escucharon",
+    "escuchará",
+    "escucharán",
+    "escuche",
+    "escuchen",
+    "escuchándolas",
+    "escuché",
+    "escuchó",
+    "escudería",
+    "escudo",
+    "escudriñan",
+    "escuela",
+    "escuelas",
+    "escuetamente",
+    "escultor",
+    "escultura",
+    "esculturas",
+    "escultórico",
+    "escupir",
+    "escurridizo",
+    "escurridizos",
+    "escurridos",
+    "escuálidas",
+    "escándalo",
+    "escándalos",
+    "escénico",
+    "esde",
+    "ese",
+    "esencia",
+    "esencial",
+    "esencialmente",
+    "esfera",
+    "esforzamos",
+    "esforzarnos",
+    "esforzaron",
+    "esfuercen",
+    "esfuerzo",
+    "esfuerzos",
+    "esfuman",
+    "esférico",
+    "esgrimía",
+    "esguince",
+    "eslabonó",
+    "eslava",
+    "esmaltes",
+    "esmeralda",
+    "esmeraldas",
+    "esmeran",
+    "eso",
+    "esos",
+    "espacial",
+    "espaciales",
+    "espacio",
+    "espacios",
+    "espada",
+    "espalda",
+    "espaldas",
+    "espansión",
+    "espanta",
+    "espantadas",
+    "esparcimiento",
+    "esparció",
+    "esparza",
+    "espasmo",
+    "españa",
+    "español",
+    "española",
+    "españolas",
+    "españoles",
+    "españolitos",
+    "especial",
+    "especiales",
+    "especialidad",
+    "especialidades",
+    "especialista",
+    "especialistas",
+    "especializa",
+    "especialización",
+    "especializada",
+    "especializadas",
+    "especializado",
+    "especializados",
+    "especialmente",
+    "especie",
+    "especies",
+    "especifica",
+    "especificaba",
+    "especificaciones",
+    "especificado",
+    "especifican",
+    "especificar",
+    "especificó",
+    "especifiquen",
+    "espectacular",
+    "espectaculares",
+    "espectacularmente",
+    "espectador",
+    "espectadores",
+    "espectro",
+    "espectroredioeléctrico",
+    "espectrógrafo",
+    "espectrómetro",
+    "espectáculo",
+    "espectáculos",
+    "especulador",
+    "especuladores",
+    "especular",
+    "especulativos",
+    "especuló",
+    "específica",
+    "específicamente",
+    "específicas",
+    "específico",
+    "específicos",
+    "espejismo",
+    "espejo",
+    "espera",
+    "esperaba",
+    "esperaban",
+    "esperada",
+    "esperado",
+    "esperados",
+    "esperamos",
+    "esperan",
+    "esperando",
+    "esperanza",
+    "esperanzado",
+    "esperanzados",
+    "esperanzas",
+    "esperar",
+    "esperara",
+    "esperaremos",
+    "esperarlos",
+    "esperaron",
+    "esperarse",
+    "esperará",
+    "esperarán",
+    "esperaré",
+    "esperaría",
+    "espere",
+    "esperemos",
+    "espero",
+    "esperpénticas",
+    "esperábamos",
+    "esperó",
+    "espesa",
+    "espesor",
+    "espetar",
+    "espiando",
+    "espina",
+    "espinal",
+    "espinosa",
+    "espinoso",
+    "espinoza",
+    "espionaje",
+    "espiridión",
+    "espiritual",
+    "espirituales",
+    "espiritualidad",
+    "espiritualmente",
+    "esplendor",
+    "esplendores",
+    "espontánea",
+    "espontáneamente",
+    "espontáneo",
+    "esporádica",
+    "esposa",
+    "esposas",
+    "esposo",
+    "esposos",
+    "espuela",
+    "espuma",
+    "espía",
+    "espían",
+    "espíritu",
+    "espíritus",
+    "esqueleto",
+    "esquema",
+    "esquemas",
+    "esquer",
+    "esquiar",
+    "esquimal",
+    "esquina",
+    "esquinas",
+    "esquivar",
+    "esquivel",
+    "esquizofrenia",
+    "esta",
+    "estaba",
+    "estabais",
+    "estaban",
+    "estabas",
+    "estabilidad",
+    "estabilizar",
+    "estable",
+    "establece",
+    "establecemos",
+    "establecen",
+    "establecer",
+    "establecerse",
+    "establecerá",
+    "establecida",
+    "establecidas",
+    "establecido",
+    "establecidos",
+    "estableciendo",
+    "establecieron",
+    "establecimiento",
+    "establecimientos",
+    "estableció",
+    "establecía",
+    "establecían",
+    "establezca",
+    "establezcan",
+    "estacionado",
+    "estacionados",
+    "estacionamiento",
+    "estacionamientos",
+    "estacionar",
+    "estacionarios",
+    "estacionarse",
+    "estaciones",
+    "estacionó",
+    "estación",
+    "estacón",
+    "estad",
+    "estada",
+    "estadas",
+    "estadio",
+    "estadios",
+    "estadista",
+    "estadistas",
+    "estado",
+    "estados",
+    "estadounidense",
+    "estadounidenses",
+    "estadía",
+    "estadística",
+    "estadísticas",
+    "estafan",
+    "estalla",
+    "estallar",
+    "estallaría",
+    "estallido",
+    "estalló",
+    "estambre",
+    "estamos",
+    "estampas",
+    "estampilla",
+    "estampita",
+    "estancadas",
+    "estancamiento",
+    "estancarse",
+    "estancia",
+    "estancias",
+    "estandar",
+    "estandarizadas",
+    "estandarte",
+    "estando",
+    "estanques",
+    "estanzuela",
+    "estar",
+    "estaremos",
+    "estarle",
+    "estarse",
+    "estará",
+    "estarán",
+    "estarás",
+    "estaré",
+    "estaréis",
+    "estaría",
+    "estaríais",
+    "estaríamos",
+    "estarían",
+    "estarías",
+    "estas",
+    "estatal",
+    "estatales",
+    "estatua",
+    "estatuas",
+    "estatura",
+    "estatuto",
+    "estatutos",
+    "este",
+    "esteban",
+    "estelar",
+    "estelares",
+    "estelarizado",
+    "estelarizar",
+    "estemos",
+    "estereotipan",
+    "esterlinas",
+    "esternón",
+    "esthela",
+    "esther",
+    "estigma",
+    "estilo",
+    "estilos",
+    "estima",
+    "estimaba",
+    "estimaciones",
+    "estimación",
+    "estimada",
+    "estimado",
+    "estimados",
+    "estimamos",
+    "estiman",
+    "estimando",
+    "estimaron",
+    "estimarás",
+    "estimula",
+    "estimulación",
+    "estimulada",
+    "estimulado",
+    "estimulados",
+    "estimular",
+    "estimulo",
+    "estimé",
+    "estimó",
+    "estipula",
+    "estipulaba",
+    "estira",
+    "estirpe",
+    "estiró",
+    "estirón",
+    "esto",
+    "estocada",
+    "estocolmo",
+    "estomacal",
+    "estopa",
+    "estoperoles",
+    "estorba",
+    "estorbaba",
+    "estorbe",
+    "estos",
+    "estoy",
+    "estrada",
+    "estrado",
+    "estragos",
+    "estrangulado",
+    "estrasburgo",
+    "estratagemas",
+    "estratega",
+    "estrategia",
+    "estrategias",
+    "estratos",
+    "estratosféricas",
+    "estratégica",
+    "estratégicas",
+    "estratégico",
+    "estratégicos",
+    "estrecha",
+    "estrechar",
+    "estrecharon",
+    "estrechas",
+    "estrecho",
+    "estrechos",
+    "estrechándose",
+    "estrechó",
+    "estrella",
+    "estrellado",
+    "estrellamos",
+    "estrellar",
+    "estrellas",
+    "estrelló",
+    "estremeció",
+    "estrena",
+    "estrenado",
+    "estrenan",
+    "estrenando",
+    "estrenar",
+    "estrenaron",
+    "estrenará",
+    "estreno",
+    "estrenos",
+    "estrenándose",
+    "estrenó",
+    "estrepitosa",
+    "estrepitosamente",
+    "estribo",
+    "estricta",
+    "estrictamente",
+    "estrictas",
+    "estricto",
+    "estrictos",
+    "estropeadas",
+    "estropear",
+    "estructura",
+    "estructuración",
+    "estructural",
+    "estructuras",
+    "estruendo",
+    "estrépito",
+    "estudia",
+    "estudiaba",
+    "estudiaban",
+    "estudiados",
+    "estudian",
+    "estudiando",
+    "estudiante",
+    "estudiantes",
+    "estudiantil",
+    "estudiantiles",
+    "estudiar",
+    "estudiaron",
+    "estudiarse",
+    "estudiará",
+    "estudie",
+    "estudien",
+    "estudio",
+    "estudios",
+    "estudioso",
+    "estudiosos",
+    "estudié",
+    "estudió",
+    "estupefacción",
+    "estupefacta",
+    "estupendo",
+    "estupideces",
+    "estuve",
+    "estuviera",
+    "estuvierais",
+    "estuvieran",
+    "estuvieras",
+    "estuvieron",
+    "estuviese",
+    "estuvieseis",
+    "estuviesen",
+    "estuvieses",
+    "estuvimos",
+    "estuviste",
+    "estuvisteis",
+    "estuviéramos",
+    "estuviésemos",
+    "estuvo",
+    "está",
+    "estábamos",
+    "estáis",
+    "están",
+    "estándar",
+    "estándares",
+    "estás",
+    "esté",
+    "estéis",
+    "estén",
+    "estéreo",
+    "estés",
+    "estética",
+    "estéticamente",
+    "estéticas",
+    "estéticos",
+    "estévez",
+    "estímulo",
+    "estímulos",
+    "estómago",
+    "estúpida",
+    "etapa",
+    "etapas",
+    "etchohuaquila",
+    "etcétera",
+    "eternidad",
+    "eterno",
+    "ethernet",
+    "ethnology",
+    "etienne",
+    "etiopía",
+    "etiqueta",
+    "etiquetas",
+    "etnia",
+    "etnografía",
+    "etnohistórico",
+    "ettiene",
+    "etíope",
+    "eu",
+    "eucalipto",
+    "eudelio",
+    "euforia",
+    "eugenia",
+    "eugenio",
+    "eugui",
+    "eugéne",
+    "eulalio",
+    "eurobonos",
+    "europa",
+    "europea",
+    "europeas",
+    "europeo",
+    "europeos",
+    "eurovisión",
+    "eurípides",
+    "eusebio",
+    "eustolio",
+    "eva",
+    "evacuación",
+    "evacuados",
+    "evacuan",
+    "evacuar",
+    "evadir",
+    "evadió",
+    "evadía",
+    "evaluacion",
+    "evaluaciones",
+    "evaluación",
+    "evaluado",
+    "evaluador",
+    "evaluados",
+    "evaluar",
+    "evaluaron",
+    "evaluarán",
+    "evalúan",
+    "evangelización",
+    "evangelizan",
+    "evangelizar",
+    "evangélicos",
+    "evasivas",
+    "evasivo",
+    "evasión",
+    "evento",
+    "eventos",
+    "eventual",
+    "eventuales",
+    "eventualmente",
+    "everardo",
+    "everman",
+    "everton",
+    "evidencia",
+    "evidenciado",
+    "evidencian",
+    "evidencias",
+    "evidenció",
+    "evidente",
+    "evidentemente",
+    "evidentes",
+    "evita",
+    "evitado",
+    "evitan",
+    "evitando",
+    "evitar",
+    "evitara",
+    "evitaremos",
+    "evitarla",
+    "evitarle",
+    "evitarlo",
+    "evitarse",
+    "evitará",
+    "evite",
+    "eviten",
+    "evitó",
+    "evocación",
+    "evolucionando",
+    "evolucionara",
+    "evolucionarios",
+    "evolucionaron",
+    "evolucionen",
+    "evolución",
+    "exacta",
+    "exactamente",
+    "exactas",
+    "exactitud",
+    "exacto",
+    "exageraciones",
+    "exagerada",
+    "exagerado",
+    "exagerados",
+    "exageraron",
+    "exalumnas",
+    "exalumnos",
+    "examen",
+    "examina",
+    "examinado",
+    "examinados",
+    "examinando",
+    "examinar",
+    "examinen",
+    "examinó",
+    "exc",
+    "excandidato",
+    "excatedrático",
+    "excavaciones",
+    "excavando",
+    "excede",
+    "exceden",
+    "excedernos",
+    "excediendo",
+    "excedieron",
+    "excel",
+    "excelencia",
+    "excelente",
+    "excelentemente",
+    "excelentes",
+    "excelsa",
+    "excepcional",
+    "excepcionales",
+    "excepciones",
+    "excepción",
+    "excepto",
+    "excesiva",
+    "excesivamente",
+    "excesivo",
+    "excesivos",
+    "exceso",
+    "excesos",
+    "excita",
+    "excitación",
+    "excitan",
+    "excitante",
+    "excitantes",
+    "excitarse",
+    "exclama",
+    "exclamación",
+    "exclamó",
+    "excluidos",
+    "exclusiva",
+    "exclusivamente",
+    "exclusivas",
+    "exclusividad",
+    "exclusividades",
+    "exclusivo",
+    "exclusivos",
+    "exclusión",
+    "excluyendo",
+    "excolega",
+    "excomunión",
+    "excursiones",
+    "excusarse",
+    "excusas",
+    "excélsior",
+    "exención",
+    "exenta",
+    "exento",
+    "exhala",
+    "exhaustiva",
+    "exhibe",
+    "exhiben",
+    "exhibiciones",
+    "exhibición",
+    "exhibida",
+    "exhibidas",
+    "exhibido",
+    "exhibiendo",
+    "exhibiera",
+    "exhibiere",
+    "exhibieren",
+    "exhibimos",
+    "exhibirse",
+    "exhibirá",
+    "exhibirán",
+    "exhibió",
+    "exhibía",
+    "exhoneración",
+    "exhorta",
+    "exhortaban",
+    "exhortan",
+    "exhortando",
+    "exhorto",
+    "exhortó",
+    "exige",
+    "exigen",
+    "exigencia",
+    "exigencias",
+    "exigente",
+    "exigentes",
+    "exigida",
+    "exigido",
+    "exigiendo",
+    "exigieran",
+    "exigieron",
+    "exigimos",
+    "exigir",
+    "exigira",
+    "exigirle",
+    "exigirles",
+    "exigirse",
+    "exigirá",
+    "exigiría",
+    "exigió",
+    "exigían",
+    "exija",
+    "exijan",
+    "exilio",
+    "exime",
+    "eximir",
+    "eximirlos",
+    "exista",
+    "existan",
+    "existe",
+    "existen",
+    "existencia",
+    "existente",
+    "existentes",
+    "existido",
+    "existiendo",
+    "existir",
+    "existirán",
+    "existió",
+    "existía",
+    "existían",
+    "exito",
+    "exitosa",
+    "exitosamente",
+    "exitosas",
+    "exitoso",
+    "exitosos",
+    "exonera",
+    "exoticos",
+    "expander",
+    "expandera",
+    "expandido",
+    "expandieron",
+    "expandirse",
+    "expandirán",
+    "expansiva",
+    "expansión",
+    "expectación",
+    "expectativa",
+    "expectativas",
+    "expedición",
+    "expedidas",
+    "expediente",
+    "expedientes",
+    "expedir",
+    "expedirse",
+    "expedito",
+    "expenden",
+    "expendio",
+    "experiencia",
+    "experiencias",
+    "experimenta",
+    "experimentación",
+    "experimentadas",
+    "experimentado",
+    "experimentados",
+    "experimentar",
+    "experimentarse",
+    "experimento",
+    "experimentó",
+    "experto",
+    "expertos",
+    "expidan",
+    "expide",
+    "expidieron",
+    "expira",
+    "expirar",
+    "explanada",
+    "explica",
+    "explicaban",
+    "explicaciones",
+    "explicación",
+    "explicado",
+    "explicamos",
+    "explican",
+    "explicando",
+    "explicar",
+    "explicara",
+    "explicarles",
+    "explicarlo",
+    "explicaron",
+    "explicará",
+    "explicó",
+    "explique",
+    "explora",
+    "exploraban",
+    "exploración",
+    "explorado",
+    "exploran",
+    "explorar",
+    "explosiva",
+    "explosividad",
+    "explosivo",
+    "explosivos",
+    "explosión",
+    "explotación",
+    "explotado",
+    "explotan",
+    "explotar",
+    "explotarse",
+    "explotará",
+    "explotó",
+    "expo",
+    "expondremos",
+    "expondrá",
+    "expondrán",
+    "expone",
+    "exponentes",
+    "exponer",
+    "exponernos",
+    "exponerse",
+    "exponga",
+    "expongan",
+    "exponiéndose",
+    "exponían",
+    "expoplaza",
+    "exporta",
+    "exportaciones",
+    "exportación",
+    "exportado",
+    "exportador",
+    "exportadoras",
+    "exportadores",
+    "exportados",
+    "exportamos",
+    "exportan",
+    "exportando",
+    "exportar",
+    "exporters",
+    "exportó",
+    "exposiciones",
+    "exposición",
+    "expresa",
+    "expresada",
+    "expresadas",
+    "expresado",
+    "expresamente",
+    "expresan",
+    "expresando",
+    "expresar",
+    "expresaron",
+    "expresarse",
+    "expresidentes",
+    "expresiones",
+    "expresiva",
+    "expresión",
+    "expreso",
+    "expresé",
+    "expresó",
+    "expropiar",
+    "expuesta",
+    "expuesto",
+    "expuestos",
+    "expulsada",
+    "expulsado",
+    "expulsados",
+    "expulsar",
+    "expulsarlo",
+    "expulsarme",
+    "expulsaron",
+    "expulsión",
+    "expusiera",
+    "expusieron",
+    "expuso",
+    "exquisitamente",
+    "exquisiteces",
+    "exquisitos",
+    "exsecretario",
+    "extendemos",
+    "extender",
+    "extenderse",
+    "extenderá",
+    "extendido",
+    "extendieron",
+    "extendió",
+    "extensa",
+    "extensas",
+    "extensiones",
+    "extensivo",
+    "extensión",
+    "extenso",
+    "extenuación",
+    "extenuar",
+    "exterior",
+    "exteriores",
+    "externa",
+    "externado",
+    "externaron",
+    "externas",
+    "externo",
+    "externos",
+    "externó",
+    "extiendan",
+    "extiende",
+    "extienden",
+    "extinción",
+    "extinguidores",
+    "extinta",
+    "extirpar",
+    "extirparon",
+    "extorsionando",
+    "extorsionar",
+    "extorsionaron",
+    "extorsiones",
+    "extosionando",
+    "extrabajadores",
+    "extracción",
+    "extracto",
+    "extradición",
+    "extraer",
+    "extraerle",
+    "extraescolar",
+    "extrajo",
+    "extranjera",
+    "extranjeras",
+    "extranjero",
+    "extranjeros",
+    "extraoficial",
+    "extraoficiales",
+    "extraoficialmente",
+    "extraordinaria",
+    "extraordinariamente",
+    "extraordinarias",
+    "extraordinario",
+    "extraordinarios",
+    "extras",
+    "extraterrestres",
+    "extravagantes",
+    "extraviadas",
+    "extraviado",
+    "extravió",
+    "extravío",
+    "extraída",
+    "extraído",
+    "extraña",
+    "extrañaba",
+    "extrañados",
+    "extrañas",
+    "extrañe",
+    "extrañeza",
+    "extraño",
+    "extraños",
+    "extrema",
+    "extremadamente",
+    "extremo",
+    "extremos",
+    "exvotos",
+    "exxon",
+    "exámenes",
+    "exóticas",
+    "exóticos",
+    "ezeta",
+    "f",
+    "fabiana",
+    "fabio",
+    "fabián",
+    "fabrica",
+    "fabricación",
+    "fabricadas",
+    "fabricado",
+    "fabricante",
+    "fabricantes",
+    "fabricar",
+    "fabricarla",
+    "fabricará",
+    "fabricó",
+    "fabrique",
+    "fabulosas",
+    "fabuloso",
+    "fabulosos",
+    "facciones",
+    "faceta",
+    "facha",
+    "fachada",
+    "facilidad",
+    "facilidades",
+    "facilita",
+    "facilitaba",
+    "facilitados",
+    "facilitando",
+    "facilitar",
+    "facilitarles",
+    "facilitarlos",
+    "facilitará",
+    "facilitarán",
+    "facilite",
+    "faciliten",
+    "factible",
+    "factibles",
+    "facto",
+    "factorajes",
+    "factores",
+    "factura",
+    "facturación",
+    "facturada",
+    "facturados",
+    "facturar",
+    "facturas",
+    "facturó",
+    "faculta",
+    "facultad",
+    "facultades",
+    "facultados",
+    "facultativos",
+    "facundo",
+    "faena",
+    "faenas",
+    "fagotistas",
+    "fahr",
+    "fairfax",
+    "faisán",
+    "faja",
+    "fajarse",
+    "fajín",
+    "fal",
+    "falacci",
+    "falcón",
+    "falda",
+    "faldas",
+    "falla",
+    "fallado",
+    "fallan",
+    "fallar",
+    "fallaron",
+    "fallas",
+    "fallece",
+    "fallecido",
+    "fallecidos",
+    "falleciera",
+    "fallecieron",
+    "falleció",
+    "fallida",
+    "fallido",
+    "fallo",
+    "falló",
+    "falsa",
+    "falsas",
+    "falsete",
+    "falsificación",
+    "falsificados",
+    "falsificó",
+    "falso",
+    "falsos",
+    "falta",
+    "faltaba",
+    "faltaban",
+    "faltado",
+    "faltan",
+    "faltando",
+    "faltante",
+    "faltar",
+    "faltara",
+    "faltas",
+    "falte",
+    "falto",
+    "faltriquera",
+    "faltándole",
+    "falté",
+    "faltó",
+    "fama",
+    "familia",
+    "familiares",
+    "familiarice",
+    "familiarizado",
+    "familiarizarse",
+    "familias",
+    "famosa",
+    "famosas",
+    "famoso",
+    "famosos",
+    "fanaticada",
+    "fanfarrias",
+    "fango",
+    "fans",
+    "fantasmas",
+    "fantasía",
+    "fanáticas",
+    "fanático",
+    "fanáticos",
+    "farinas",
+    "farmaceútica",
+    "farmacia",
+    "farmacias",
+    "farmacodependientes",
+    "farmacología",
+    "farmar",
+    "farrugia",
+    "farsa",
+    "farsante",
+    "farías",
+    "fascinación",
+    "fascinante",
+    "fase",
+    "fases",
+    "fastuosos",
+    "fatales",
+    "fatalmente",
+    "fatiga",
+    "fatigado",
+    "fauna",
+    "faustino",
+    "fausto",
+    "favorable",
+    "favorablemente",
+    "favorables",
+    "favorece",
+    "favorecer",
+    "favorecerá",
+    "favorecerán",
+    "favorecieron",
+    "favorecimiento",
+    "favoreció",
+    "favorecía",
+    "favorecían",
+    "favores",
+    "favorezcan",
+    "favorita",
+    "favoritas",
+    "favoritismo",
+    "favorito",
+    "favoritos",
+    "faxes",
+    "fbi",
+    "fda",
+    "fe",
+    "fea",
+    "feas",
+    "feb",
+    "febrero",
+    "fecha",
+    "fechada",
+    "fechado",
+    "fechados",
+    "fechas",
+    "fechorías",
+    "fecunda",
+    "fecundación",
+    "fecundando",
+    "fecundidad",
+    "fecundínez",
+    "federación",
+    "federal",
+    "federales",
+    "federalismo",
+    "federalización",
+    "federativa",
+    "federativas",
+    "federativo",
+    "federica",
+    "federico",
+    "feeder",
+    "fehacientemente",
+    "feldhaus",
+    "felguérez",
+    "felices",
+    "feliciano",
+    "felicidad",
+    "felicitaciones",
+    "felicitación",
+    "felicitada",
+    "felicitadas",
+    "felicitado",
+    "felicitar",
+    "felicitarla",
+    "felicitarlo",
+    "felicito",
+    "felicitó",
+    "feligreses",
+    "felina",
+    "felino",
+    "felinos",
+    "felipe",
+    "feliz",
+    "felícitos",
+    "femam",
+    "femenil",
+    "femeniles",
+    "femenina",
+    "femenino",
+    "femeninos",
+    "femineidad",
+    "feminismo",
+    "feminista",
+    "feministas",
+    "femsa",
+    "fendick",
+    "feneciendo",
+    "fenómeno",
+    "fenómenos",
+    "feo",
+    "fer",
+    "fergie",
+    "ferguson",
+    "feria",
+    "ferias",
+    "fermín",
+    "fernanda",
+    "fernandez",
+    "fernando",
+    "ferniza",
+    "fernández",
+    "feroz",
+    "ferrara",
+    "ferrat",
+    "ferretería",
+    "ferreterías",
+    "ferriño",
+    "ferrocarril",
+    "ferrocarrileros",
+    "ferrocarriles",
+    "ferronales",
+    "ferroviaria",
+    "ferroviarias",
+    "ferroviario",
+    "ferroviarios",
+    "fertilidad",
+    "fertilizante",
+    "fertilizantes",
+    "fesperman",
+    "festeja",
+    "festejada",
+    "festejar",
+    "festejaron",
+    "festejo",
+    "festejos",
+    "festejó",
+    "festiva",
+    "festival",
+    "festivales",
+    "festividades",
+    "festivo",
+    "festivos",
+    "fetales",
+    "fetos",
+    "feudales",
+    "ffcc",
+    "fianza",
+    "fib",
+    "fibra",
+    "fibras",
+    "fibrosarcomas",
+    "ficción",
+    "ficha",
+    "fichado",
+    "fictícios",
+    "ficóloga",
+    "fideicomiso",
+    "fideicomisos",
+    "fidel",
+    "fidelidad",
+    "fidencio",
+    "fidenor",
+    "fidler",
+    "fiebre",
+    "fiel",
+    "fieles",
+    "fierro",
+    "fiesta",
+    "fiestas",
+    "fiestota",
+    "fifa",
+    "fife",
+    "fignon",
+    "figueroa",
+    "figura",
+    "figuraba",
+    "figurado",
+    "figuran",
+    "figuraría",
+    "figuras",
+    "figurativamente",
+    "fija",
+    "fijación",
+    "fijadas",
+    "fijado",
+    "fijados",
+    "fijamos",
+    "fijan",
+    "fijar",
+    "fijaron",
+    "fijarse",
+    "fijará",
+    "fijas",
+    "fije",
+    "fijeza",
+    "fijo",
+    "fijos",
+    "fijó",
+    "fila",
+    "filadelfia",
+    "filas",
+    "filatelia",
+    "filatelismo",
+    "filatelista",
+    "filatelistas",
+    "filatélica",
+    "filatélico",
+    "filatélicos",
+    "filete",
+    "filial",
+    "filiales",
+    "filiberto",
+    "filipinas",
+    "filipino",
+    "film",
+    "filmación",
+    "filmado",
+    "filmados",
+    "filmar",
+    "filmaron",
+    "filme",
+    "filosofía",
+    "filosófica",
+    "filtraciones",
+    "filtración",
+    "filtro",
+    "filtros",
+    "filósofo",
+    "filósofos",
+    "fin",
+    "fina",
+    "finales",
+    "finalice",
+    "finalidad",
+    "finalista",
+    "finalistas",
+    "finaliza",
+    "finalizado",
+    "finalizar",
+    "finalizara",
+    "finalizaron",
+    "finalizará",
+    "finalizó",
+    "finalmente",
+    "finamex",
+    "financiadas",
+    "financiado",
+    "financiados",
+    "financial",
+    "financiamiento",
+    "financiamientos",
+    "financiar",
+    "financiera",
+    "financieras",
+    "financiero",
+    "financieros",
+    "financing",
+    "financió",
+    "finanzas",
+    "finas",
+    "finca",
+    "fincado",
+    "fincó",
+    "fines",
+    "fingiendo",
+    "fingieron",
+    "fingir",
+    "finiquitación",
+    "finiquito",
+    "finishing",
+    "finland",
+    "fino",
+    "finos",
+    "fira",
+    "firma",
+    "firmaba",
+    "firmaban",
+    "firmada",
+    "firmadas",
+    "firmado",
+    "firmados",
+    "firmamos",
+    "firman",
+    "firmante",
+    "firmantes",
+    "firmar",
+    "firmara",
+    "firmaron",
+    "firmarse",
+    "firmará",
+    "firmarán",
+    "firmas",
+    "firme",
+    "firmemente",
+    "firmen",
+    "firmes",
+    "firmó",
+    "fiscal",
+    "fiscales",
+    "fiscalización",
+    "fiscalizado",
+    "fiscalía",
+    "fischer",
+    "fisco",
+    "fisiológicamente",
+    "fisk",
+    "fisonomía",
+    "fisura",
+    "fitopecuarias",
+    "flaco",
+    "flagrante",
+    "flamante",
+    "flamenco",
+    "flandes",
+    "flannery",
+    "flanquear",
+    "flaquito",
+    "flaubert",
+    "flavio",
+    "flavorite",
+    "flavr",
+    "flecha",
+    "flechazos",
+    "flecos",
+    "flemming",
+    "fleteros",
+    "fletes",
+    "flexibilidad",
+    "flexibles",
+    "flojos",
+    "flor",
+    "flora",
+    "floración",
+    "florales",
+    "florecita",
+    "florencia",
+    "flores",
+    "florida",
+    "flota",
+    "flotación",
+    "flotando",
+    "flotantes",
+    "flotilla",
+    "flotillas",
+    "floyd",
+    "fluctuación",
+    "fluctúan",
+    "fluidez",
+    "fluido",
+    "flujo",
+    "flujos",
+    "flume",
+    "fluordaniel",
+    "fluye",
+    "fluído",
+    "fm",
+    "fmf",
+    "fmn",
+    "fna",
+    "foco",
+    "focos",
+    "fofo",
+    "fogatas",
+    "foggia",
+    "fogueo",
+    "folclórica",
+    "folio",
+    "folklore",
+    "folklorismo",
+    "folklórica",
+    "folklórico",
+    "folleto",
+    "folletos",
+    "fomenta",
+    "fomentaba",
+    "fomentada",
+    "fomentado",
+    "fomentan",
+    "fomentar",
+    "fomento",
+    "fomerrey",
+    "fonda",
+    "fondear",
+    "fondistas",
+    "fondo",
+    "fondos",
+    "fonhapo",
+    "fonméxico",
+    "fonseca",
+    "fonética",
+    "foods",
+    "foquitos",
+    "forcados",
+    "ford",
+    "forest",
+    "forestación",
+    "forestal",
+    "forestales",
+    "forista",
+    "foristas",
+    "forja",
+    "forjan",
+    "forjarse",
+    "forma",
+    "formaban",
+    "formación",
+    "formada",
+    "formado",
+    "formados",
+    "formales",
+    "formalidades",
+    "formalización",
+    "formalizar",
+    "formalizarlo",
+    "formalizará",
+    "formalmente",
+    "formamos",
+    "forman",
+    "formando",
+    "formar",
+    "formarlo",
+    "formaron",
+    "formarse",
+    "formará",
+    "formarán",
+    "formas",
+    "formativos",
+    "formato",
+    "formatos",
+    "forme",
+    "formen",
+    "formulación",
+    "formulado",
+    "formulan",
+    "formular",
+    "formuló",
+    "formó",
+    "fornica",
+    "fornicación",
+    "fornicadora",
+    "fornicadores",
+    "fornicados",
+    "foro",
+    "foros",
+    "forrada",
+    "forro",
+    "fors",
+    "fortalece",
+    "fortalecemos",
+    "fortalecen",
+    "fortalecer",
+    "fortalecidas",
+    "fortalecido",
+    "fortaleciendo",
+    "fortalecimiento",
+    "fortaleciéndose",
+    "fortaleció",
+    "fortaleza",
+    "fortalezca",
+    "fortalezcan",
+    "fortines",
+    "fortuna",
+    "fortunas",
+    "fortunato",
+    "fortín",
+    "forzada",
+    "forzadas",
+    "forzar",
+    "forzaron",
+    "forzosa",
+    "forzosamente",
+    "forzoso",
+    "foránea",
+    "foráneo",
+    "foráneos",
+    "fosas",
+    "fosforescente",
+    "fosforescentes",
+    "foster",
+    "foto",
+    "fotocopiadoras",
+    "fotocredencialización",
+    "fotocredencializadas",
+    "fotocredencializados",
+    "fotocredencializar",
+    "fotografiar",
+    "fotografía",
+    "fotografías",
+    "fotográfica",
+    "fotográficas",
+    "fotomecánica",
+    "fotoquímicos",
+    "fotos",
+    "fotógrafo",
+    "fotógrafos",
+    "fovi",
+    "fovissste",
+    "fox",
+    "foxpro",
+    "fracasado",
+    "fracasar",
+    "fracasaron",
+    "fracase",
+    "fracaso",
+    "fracasos",
+    "fracasó",
+    "fracc",
+    "fraccionadas",
+    "fraccionador",
+    "fraccionadores",
+    "fraccionamiento",
+    "fraccionamientos",
+    "fraccionarios",
+    "fracciones",
+    "fracción",
+    "fractura",
+    "fracturado",
+    "fracturados",
+    "fracturarse",
+    "fracturas",
+    "fragancias",
+    "fragmenta",
+    "fragmentación",
+    "fragmentos",
+    "fragor",
+    "fraguando",
+    "franca",
+    "francamente",
+    "francas",
+    "france",
+    "francesa",
+    "franceses",
+    "francia",
+    "franco",
+    "francos",
+    "francotirador",
+    "francés",
+    "franela",
+    "frank",
+    "franklyn",
+    "franqueza",
+    "franqueó",
+    "franquicia",
+    "franquiciaba",
+    "franquicias",
+    "franz",
+    "frapi",
+    "frase",
+    "fraseo",
+    "frases",
+    "frasquito",
+    "fraterna",
+    "fraternal",
+    "fraternidad",
+    "fraude",
+    "fraudes",
+    "fraudulenta",
+    "fraudulentas",
+    "fraudulento",
+    "frausto",
+    "frayre",
+    "frecuencia",
+    "frecuencias",
+    "frecuentada",
+    "frecuentado",
+    "frecuentan",
+    "frecuentando",
+    "frecuentaran",
+    "frecuente",
+    "frecuentemente",
+    "frecuentes",
+    "frederick",
+    "freedom",
+    "freeport",
+    "fregado",
+    "fregonería",
+    "frenado",
+    "frenados",
+    "frenar",
+    "freno",
+    "frenos",
+    "frente",
+    "frentes",
+    "frenó",
+    "fresas",
+    "fresca",
+    "frescas",
+    "fresco",
+    "frescura",
+    "freud",
+    "frias",
+    "fricase",
+    "fricciones",
+    "frida",
+    "fried",
+    "frijol",
+    "frijoles",
+    "friolentas",
+    "friolentos",
+    "frisco",
+    "fritos",
+    "fritz",
+    "frivolidad",
+    "frontal",
+    "frontalmente",
+    "frontera",
+    "fronteras",
+    "fronteriza",
+    "fronterizo",
+    "fronterizos",
+    "fructificar",
+    "fructuoso",
+    "frustración",
+    "frustrada",
+    "frustradas",
+    "frustrado",
+    "fruta",
+    "frutales",
+    "frutas",
+    "fruto",
+    "frutos",
+    "frágil",
+    "frágiles",
+    "fría",
+    "frías",
+    "frío",
+    "fríos",
+    "fstse",
+    "fucsia",
+    "fue",
+    "fuego",
+    "fuehrer",
+    "fuente",
+    "fuentes",
+    "fuera",
+    "fuerais",
+    "fueran",
+    "fueras",
+    "fuere",
+    "fuero",
+    "fueron",
+    "fuerte",
+    "fuertemente",
+    "fuertes",
+    "fuerza",
+    "fuerzas",
+    "fuese",
+    "fueseis",
+    "fuesen",
+    "fueses",
+    "fuezas",
+    "fuga",
+    "fui",
+    "fuimos",
+    "fuiste",
+    "fuisteis",
+    "fujimori",
+    "fujimorista",
+    "fukuoka",
+    "fulano",
+    "fulgor",
+    "fuller",
+    "fulminante",
+    "fulminantemente",
+    "fulminó",
+    "fuman",
+    "fumando",
+    "fumarolas",
+    "funciona",
+    "funcionaba",
+    "funcional",
+    "funcionalidad",
+    "funcionalidades",
+    "funcionamiento",
+    "funcionan",
+    "funcionando",
+    "funcionar",
+    "funcionaran",
+    "funcionaria",
+    "funcionarias",
+    "funcionario",
+    "funcionarios",
+    "funcionaron",
+    "funcionará",
+    "funcionarán",
+    "funcione",
+    "funciones",
+    "funcionó",
+    "función",
+    "fund",
+    "funda",
+    "fundaciones",
+    "fundación",
+    "fundada",
+    "fundado",
+    "fundador",
+    "fundadora",
+    "fundadoras",
+    "fundadores",
+    "fundamenta",
+    "fundamentados",
+    "fundamentales",
+    "fundamentalismo",
+    "fundamentalmente",
+    "fundamento",
+    "fundamentos",
+    "fundar",
+    "fundara",
+    "fundaran",
+    "fundaron",
+    "fundarse",
+    "fundarán",
+    "funde",
+    "funden",
+    "fundente",
+    "fundiciones",
+    "fundición",
+    "fundidora",
+    "fundó",
+    "funerales",
+    "funge",
+    "fungir",
+    "fungió",
+    "fungía",
+    "fungían",
+    "funk",
+    "funny",
+    "furgones",
+    "furia",
+    "furibunda",
+    "furibundo",
+    "furioso",
+    "furtiva",
+    "furtivo",
+    "furtivos",
+    "fusionada",
+    "fusiones",
+    "fusión",
+    "futbol",
+    "futbolista",
+    "futbolistas",
+    "futbolística",
+    "futbolísticamente",
+    "futbolístico",
+    "futbolísticos",
+    "futura",
+    "futuras",
+    "futureando",
+    "futurismo",
+    "futurista",
+    "futuro",
+    "futuros",
+    "fuéramos",
+    "fuésemos",
+    "fxg",
+    "fábrica",
+    "fábricas",
+    "fácil",
+    "fáciles",
+    "fácilmente",
+    "fátima",
+    "félix",
+    "férreo",
+    "fértiles",
+    "fían",
+    "fíjate",
+    "fíjese",
+    "fílmicos",
+    "física",
+    "físicamente",
+    "físicas",
+    "físico",
+    "físicodeportiva",
+    "físicos",
+    "fórmula",
+    "fórmulas",
+    "fósiles",
+    "g",
+    "gabanna",
+    "gabinete",
+    "gabriel",
+    "gabriela",
+    "gado",
+    "gafete",
+    "gail",
+    "gala",
+    "galanes",
+    "galante",
+    "galardonada",
+    "galardonado",
+    "galardonados",
+    "galardones",
+    "galardón",
+    "galarza",
+    "galeana",
+    "galenos",
+    "galeones",
+    "galeras",
+    "galería",
+    "galerías",
+    "gales",
+    "galicia",
+    "galileo",
+    "galimanty",
+    "galindo",
+    "gallardo",
+    "gallego",
+    "gallegos",
+    "galleta",
+    "galletitas",
+    "gallina",
+    "gallinas",
+    "gallinero",
+    "gallo",
+    "gallont",
+    "gallos",
+    "galvak",
+    "galvamet",
+    "galvanizada",
+    "galván",
+    "galán",
+    "gama",
+    "gamma",
+    "gamuza",
+    "gana",
+    "ganaban",
+    "ganada",
+    "ganadas",
+    "ganadera",
+    "ganadero",
+    "ganaderos",
+    "ganadería",
+    "ganado",
+    "ganador",
+    "ganadora",
+    "ganadores",
+    "ganados",
+    "ganamos",
+    "ganan",
+    "ganancia",
+    "ganancias",
+    "ganando",
+    "ganar",
+    "ganarla",
+    "ganarle",
+    "ganarles",
+    "ganaron",
+    "ganarse",
+    "ganará",
+    "ganarán",
+    "ganas",
+    "gancho",
+    "ganchos",
+    "ganchó",
+    "gane",
+    "ganen",
+    "gangas",
+    "gannet",
+    "ganó",
+    "gaona",
+    "garantice",
+    "garantices",
+    "garantiza",
+    "garantizaba",
+    "garantizaban",
+    "garantizada",
+    "garantizadas",
+    "garantizar",
+    "garantizaron",
+    "garantizarse",
+    "garantizarán",
+    "garantizo",
+    "garantía",
+    "garantías",
+    "garcía",
+    "gardenia",
+    "garduño",
+    "garfield",
+    "garganta",
+    "gargantilla",
+    "gari",
+    "garibay",
+    "garitos",
+    "garma",
+    "garras",
+    "garrido",
+    "garrison",
+    "garrocha",
+    "garrotero",
+    "garroteros",
+    "garza",
+    "gasca",
+    "gasera",
+    "gaseras",
+    "gases",
+    "gasoducto",
+    "gasolina",
+    "gasolinas",
+    "gasolinera",
+    "gasolinerías",
+    "gaspar",
+    "gasparini",
+    "gasset",
+    "gastado",
+    "gastados",
+    "gastando",
+    "gastar",
+    "gastarla",
+    "gastaron",
+    "gastarán",
+    "gaste",
+    "gasto",
+    "gastolfo",
+    "gastona",
+    "gastos",
+    "gastritis",
+    "gastroenterología",
+    "gastó",
+    "gastón",
+    "gasóleo",
+    "gatauline",
+    "gates",
+    "gatica",
+    "gato",
+    "gatorade",
+    "gatos",
+    "gatt",
+    "gaucho",
+    "gauna",
+    "gavaldon",
+    "gavaldón",
+    "gavica",
+    "gaviria",
+    "gaytán",
+    "gazal",
+    "gds",
+    "gehard",
+    "geiser",
+    "gelasio",
+    "gemebundo",
+    "gemelas",
+    "gemelos",
+    "gemido",
+    "gemidos",
+    "genaro",
+    "gendarme",
+    "gene",
+    "genealógico",
+    "genera",
+    "generaciones",
+    "generación",
+    "generada",
+    "generado",
+    "generador",
+    "generadora",
+    "generadores",
+    "generados",
+    "generales",
+    "generalidad",
+    "generalista",
+    "generalizaciones",
+    "generalizada",
+    "generalizado",
+    "generalizados",
+    "generalizar",
+    "generalmente",
+    "generan",
+    "generando",
+    "generandoles",
+    "generar",
+    "generaron",
+    "generarse",
+    "generará",
+    "generarán",
+    "generis",
+    "generosa",
+    "generosas",
+    "generoso",
+    "generó",
+    "genes",
+    "genesio",
+    "genetec",
+    "genetistas",
+    "genial",
+    "geniales",
+    "genio",
+    "genios",
+    "genital",
+    "genitales",
+    "geno",
+    "genocidio",
+    "genpham",
+    "gente",
+    "gentes",
+    "gentor",
+    "genuinamente",
+    "genérico",
+    "genética",
+    "genéticamente",
+    "genético",
+    "genéticos",
+    "geo",
+    "geographic",
+    "geográficas",
+    "geográfico",
+    "geológicos",
+    "georges",
+    "gephard",
+    "gephardt",
+    "gerald",
+    "gerardo",
+    "gerda",
+    "gerencia",
+    "gerencial",
+    "gerenciales",
+    "gerencias",
+    "gerente",
+    "gerentes",
+    "germen",
+    "germán",
+    "geroncio",
+    "gerónimo",
+    "gestado",
+    "gestando",
+    "gestionando",
+    "gestionar",
+    "gestiones",
+    "gestión",
+    "gesto",
+    "gestor",
+    "gestoras",
+    "gestoría",
+    "geólogos",
+    "gfinbur",
+    "ghali",
+    "ghana",
+    "ghanés",
+    "ghersi",
+    "giancana",
+    "gianni",
+    "gielgud",
+    "gigante",
+    "gigantes",
+    "gigantesco",
+    "gigantescos",
+    "gigantezca",
+    "gijón",
+    "gil",
+    "gilbert",
+    "gilberto",
+    "gime",
+    "gimnasia",
+    "gimnasio",
+    "gimnospermas",
+    "ginebra",
+    "ginecobstetricia",
+    "ginecólogo",
+    "giovanni",
+    "gira",
+    "giraba",
+    "girado",
+    "giran",
+    "girar",
+    "girara",
+    "giraron",
+    "girará",
+    "giras",
+    "giro",
+    "girolamo",
+    "girolano",
+    "giros",
+    "giró",
+    "gises",
+    "giuseppe",
+    "gladiador",
+    "gladiadores",
+    "glamorosa",
+    "glamour",
+    "glamur",
+    "glen",
+    "glenn",
+    "glioblastomas",
+    "globales",
+    "globalización",
+    "globalizado",
+    "globalizarse",
+    "globe",
+    "globero",
+    "globo",
+    "globos",
+    "gloria",
+    "glorias",
+    "glorieta",
+    "gloriosísima",
+    "glosas",
+    "glándula",
+    "goave",
+    "gober",
+    "gobernaban",
+    "gobernabilidad",
+    "gobernación",
+    "gobernada",
+    "gobernado",
+    "gobernador",
+    "gobernadora",
+    "gobernadores",
+    "gobernante",
+    "gobernantes",
+    "gobernar",
+    "gobernarse",
+    "gobernícolas",
+    "gobernó",
+    "gobienro",
+    "gobierna",
+    "gobierne",
+    "gobierno",
+    "gobiernos",
+    "goce",
+    "gocen",
+    "goddard",
+    "godínez",
+    "goering",
+    "goeter",
+    "goethe",
+    "gol",
+    "golazo",
+    "golden",
+    "goldsmith",
+    "goleador",
+    "goleadora",
+    "goleadores",
+    "golearon",
+    "goleo",
+    "goles",
+    "golf",
+    "golfistas",
+    "golfo",
+    "golondrinas",
+    "golpe",
+    "golpeaba",
+    "golpeado",
+    "golpeador",
+    "golpeados",
+    "golpean",
+    "golpear",
+    "golpeara",
+    "golpearlo",
+    "golpearon",
+    "golpeo",
+    "golpes",
+    "golpeó",
+    "golpiza",
+    "goma",
+    "gomes",
+    "gomiz",
+    "gonzalez",
+    "gonzalitos",
+    "gonzalo",
+    "gonzález",
+    "goosen",
+    "gor",
+    "gorbachov",
+    "gorda",
+    "gordillo",
+    "gordo",
+    "gordolobo",
+    "gordon",
+    "gorgona",
+    "gorgonia",
+    "gorila",
+    "gorilas",
+    "gorios",
+    "gorra",
+    "gorras",
+    "gorro",
+    "gortari",
+    "gortázar",
+    "gorvachov",
+    "gota",
+    "gotas",
+    "goteo",
+    "goteras",
+    "gourmet",
+    "gourmets",
+    "goya",
+    "goyesca",
+    "goyesco",
+    "goyo",
+    "goza",
+    "gozado",
+    "gozan",
+    "gozar",
+    "gozo",
+    "gozoso",
+    "graba",
+    "grababan",
+    "grabaciones",
+    "grabación",
+    "grabada",
+    "grabadas",
+    "grabadista",
+    "grabado",
+    "grabadora",
+    "grabadoras",
+    "grabados",
+    "grabamos",
+    "graban",
+    "grabar",
+    "grabara",
+    "grabarle",
+    "grabaron",
+    "grabará",
+    "grabó",
+    "gracia",
+    "graciano",
+    "gracias",
+    "graciela",
+    "gracilmente",
+    "gradas",
+    "graderío",
+    "grado",
+    "gradocon",
+    "grados",
+    "graduación",
+    "graduado",
+    "graduados",
+    "gradual",
+    "gradualmente",
+    "graduaron",
+    "graduarse",
+    "grafenberg",
+    "grafiar",
+    "grafito",
+    "grajales",
+    "grajeda",
+    "gramajo",
+    "grammont",
+    "grammy",
+    "grammys",
+    "gramos",
+    "grampus",
+    "gran",
+    "grana",
+    "granada",
+    "granadas",
+    "granaderos",
+    "granados",
+    "granalguina",
+    "granata",
+    "granate",
+    "grand",
+    "grande",
+    "grandecito",
+    "grandes",
+    "grandeza",
+    "grandguillhomme",
+    "grandiosa",
+    "grandiosas",
+    "grandioso",
+    "grandota",
+    "grandote",
+    "grandotes",
+    "grandísimo",
+    "granero",
+    "granillo",
+    "granito",
+    "granja",
+    "granjas",
+    "granjeros",
+    "granma",
+    "grano",
+    "granos",
+    "gratificaciones",
+    "gratificante",
+    "gratis",
+    "gratitud",
+    "grato",
+    "gratuita",
+    "gratuitamente",
+    "gratuito",
+    "gravados",
+    "gravamen",
+    "gravar",
+    "gravedad",
+    "gravemente",
+    "graves",
+    "gravitacionales",
+    "gravoso",
+    "gravámenes",
+    "gravísimo",
+    "graña",
+    "grecia",
+    "grecorromano",
+    "greenbelt",
+    "greenspan",
+    "greg",
+    "gregorianos",
+    "gregorio",
+    "gregory",
+    "greña",
+    "griega",
+    "griego",
+    "griegos",
+    "grieta",
+    "grijalva",
+    "grill",
+    "grilletes",
+    "gripa",
+    "gripal",
+    "gripe",
+    "gris",
+    "griselda",
+    "grises",
+    "grita",
+    "gritaba",
+    "gritaban",
+    "gritando",
+    "gritar",
+    "gritarle",
+    "gritaron",
+    "gritería",
+    "grito",
+    "gritos",
+    "gritó",
+    "gro",
+    "grosera",
+    "grosería",
+    "groserías",
+    "grotewold",
+    "grrrrr",
+    "gruending",
+    "gruesa",
+    "grueso",
+    "gruesos",
+    "grumos",
+    "grupo",
+    "grupos",
+    "gráfica",
+    "gráficas",
+    "gráfico",
+    "grúa",
+    "grúas",
+    "guacamole",
+    "guadalajara",
+    "guadalcanal",
+    "guadalupe",
+    "guajardo",
+    "guamerú",
+    "guan",
+    "guanajuatense",
+    "guanajuato",
+    "guango",
+    "guantes",
+    "guapa",
+    "guapo",
+    "guarda",
+    "guardacostas",
+    "guardada",
+    "guardadita",
+    "guardados",
+    "guardameta",
+    "guardan",
+    "guardar",
+    "guardaran",
+    "guardarlas",
+    "guardarropa",
+    "guardarse",
+    "guardará",
+    "guardarán",
+    "guardas",
+    "guardería",
+    "guardia",
+    "guardias",
+    "guardián",
+    "guardó",
+    "guat",
+    "guatemala",
+    "guatemalteco",
+    "guatemaltecos",
+    "gubernamental",
+    "gubernamentales",
+    "gubernatura",
+    "guccione",
+    "guerra",
+    "guerras",
+    "guerrerense",
+    "guerreresnses",
+    "guerrero",
+    "guerrilla",
+    "guerrillera",
+    "guerrillero",
+    "guerrilleros",
+    "guetiérrez",
+    "guevara",
+    "guevarista",
+    "guiada",
+    "guiadas",
+    "guiar",
+    "guiaron",
+    "guillermina",
+    "guillermo",
+    "guillespie",
+    "guillotina",
+    "guiomar",
+    "guiones",
+    "guitarra",
+    "guitarrista",
+    "guión",
+    "guns",
+    "gurmets",
+    "gurrola",
+    "gurría",
+    "gusano",
+    "gusta",
+    "gustaba",
+    "gustaban",
+    "gustado",
+    "gustamos",
+    "gustan",
+    "gustando",
+    "gustar",
+    "gustaría",
+    "gustavo",
+    "guste",
+    "gusten",
+    "gusto",
+    "gustos",
+    "gustó",
+    "gutierrez",
+    "gutiérrez",
+    "guzmán",
+    "guía",
+    "guíe",
+    "guío",
+    "gyetko",
+    "gámez",
+    "gélida",
+    "gélido",
+    "género",
+    "géneros",
+    "gómez",
+    "güedad",
+    "güera",
+    "güerito",
+    "güeritos",
+    "güero",
+    "h",
+    "ha",
+    "habana",
+    "haber",
+    "haberlas",
+    "haberle",
+    "haberles",
+    "haberlo",
+    "haberlos",
+    "haberse",
+    "habida",
+    "habidas",
+    "habido",
+    "habidos",
+    "habiendo",
+    "habilidad",
+    "habilidades",
+    "habilitado",
+    "habilitar",
+    "habita",
+    "habitaban",
+    "habitables",
+    "habitacional",
+    "habitaciones",
+    "habitación",
+    "habitadas",
+    "habitado",
+    "habitantes",
+    "habitarán",
+    "habituados",
+    "habitual",
+    "habla",
+    "hablaba",
+    "hablado",
+    "hablador",
+    "hablados",
+    "hablamos",
+    "hablan",
+    "hablando",
+    "hablar",
+    "hablara",
+    "hablarle",
+    "hablarme",
+    "hablarnos",
+    "hablaron",
+    "hablará",
+    "hablarán",
+    "hable",
+    "hablen",
+    "hablo",
+    "hablábamos",
+    "hablándose",
+    "hablé",
+    "habló",
+    "habra",
+    "habre",
+    "habremos",
+    "habrá",
+    "habrán",
+    "habrás",
+    "habré",
+    "habréis",
+    "habría",
+    "habríais",
+    "habríamos",
+    "habrían",
+    "habrías",
+    "habéis",
+    "había",
+    "habíais",
+    "habíamos",
+    "habían",
+    "habías",
+    "hace",
+    "hacemos",
+    "hacen",
+    "hacendarias",
+    "hacendario",
+    "hacendoso",
+    "hacer",
+    "hacerla",
+    "hacerle",
+    "hacerles",
+    "hacerlo",
+    "hacerlos",
+    "hacerme",
+    "hacernos",
+    "hacerse",
+    "haces",
+    "hacha",
+    "hacia",
+    "hacienda",
+    "haciendas",
+    "haciendo",
+    "hacinamiento",
+    "haciéndola",
+    "haciéndolas",
+    "haciéndoles",
+    "haciéndolo",
+    "haciéndolos",
+    "haciéndose",
+    "hacía",
+    "hacíamos",
+    "hacían",
+    "haddad",
+    "haemophilus",
+    "haga",
+    "hagamos",
+    "hagan",
+    "hago",
+    "hagámosla",
+    "hagámoslo",
+    "hainan",
+    "haitianos",
+    "haití",
+    "halagadora",
+    "halago",
+    "halcion",
+    "halcón",
+    "halen",
+    "halla",
+    "hallaba",
+    "hallado",
+    "hallados",
+    "hallamos",
+    "hallan",
+    "hallar",
+    "hallarle",
+    "hallazgo",
+    "hallazgos",
+    "halle",
+    "halley",
+    "halógeno",
+    "hamaca",
+    "hambre",
+    "hambres",
+    "hambriento",
+    "hambrientos",
+    "hamburguesa",
+    "hamburguesas",
+    "hamel",
+    "hamilton",
+    "hampa",
+    "hamponio",
+    "han",
+    "hanchette",
+    "handicap",
+    "hangar",
+    "hank",
+    "hannover",
+    "hanover",
+    "hans",
+    "hanse",
+    "harald",
+    "harbaugh",
+    "hardcore",
+    "harefield",
+    "haremos",
+    "harina",
+    "harlem",
+    "haro",
+    "harry",
+    "hartazgo",
+    "hartmut",
+    "harto",
+    "harvard",
+    "harvest",
+    "harvey",
+    "hará",
+    "harán",
+    "harás",
+    "haré",
+    "haría",
+    "haríamos",
+    "harían",
+    "has",
+    "hassan",
+    "hasta",
+    "haugen",
+    "hausser",
+    "havelange",
+    "hawai",
+    "hawaiano",
+    "hay",
+    "haya",
+    "hayamos",
+    "hayan",
+    "hayas",
+    "hayat",
+    "hayáis",
+    "haz",
+    "hazaña",
+    "hboc",
+    "hc",
+    "he",
+    "headsh",
+    "hearts",
+    "heberto",
+    "hebilla",
+    "hebillas",
+    "hecha",
+    "hechas",
+    "hechizada",
+    "hecho",
+    "hechos",
+    "hector",
+    "hectárea",
+    "hectáreas",
+    "hedrick",
+    "hegel",
+    "heidi",
+    "heladas",
+    "helado",
+    "helados",
+    "helen",
+    "helicóptero",
+    "helicópteros",
+    "hembras",
+    "hemerográficas",
+    "hemiciclo",
+    "hemisferio",
+    "hemoglobina",
+    "hemorragias",
+    "hemos",
+    "henderson",
+    "henequenera",
+    "henk",
+    "henrique",
+    "hepburn",
+    "hepática",
+    "heralcor",
+    "herald",
+    "herbicidas",
+    "herbívoros",
+    "herce",
+    "herdez",
+    "heredados",
+    "heredero",
+    "herederos",
+    "heredia",
+    "hereditario",
+    "herencia",
+    "heriberto",
+    "herida",
+    "heridas",
+    "herido",
+    "heridos",
+    "herir",
+    "herirla",
+    "heritage",
+    "herman",
+    "hermana",
+    "hermanas",
+    "hermandad",
+    "hermanitas",
+    "hermann",
+    "hermano",
+    "hermanos",
+    "hermanó",
+    "hermenegildo",
+    "hermeticidad",
+    "hermetismo",
+    "hermila",
+    "hermosa",
+    "hermosamente",
+    "hermosas",
+    "hermosillo",
+    "hermoso",
+    "hermética",
+    "hernando",
+    "hernandéz",
+    "hernán",
+    "hernández",
+    "heroísmo",
+    "herradura",
+    "herramental",
+    "herramienta",
+    "herramientas",
+    "herrera",
+    "herzfeld",
+    "herzog",
+    "heráclito",
+    "heráldico",
+    "heróicos",
+    "heterosexual",
+    "heterosexuales",
+    "hewlett",
+    "hewlettpackard",
+    "hgo",
+    "hice",
+    "hiciera",
+    "hicieran",
+    "hicieron",
+    "hiciesen",
+    "hicimos",
+    "hiciste",
+    "hidalgo",
+    "hidalgos",
+    "hidalguense",
+    "hidalguenses",
+    "hidrocarburo",
+    "hidrocarburos",
+    "hidroelectricidad",
+    "hidrológica",
+    "hidrológicos",
+    "hidráulica",
+    "hidráulicos",
+    "hielo",
+    "hierba",
+    "hierbas",
+    "hierro",
+    "hierros",
+    "higiene",
+    "higinio",
+    "hija",
+    "hijas",
+    "hijo",
+    "hijos",
+    "hilario",
+    "hilburn",
+    "hilda",
+    "hilera",
+    "hillary",
+    "hills",
+    "hilo",
+    "hilos",
+    "hilvanó",
+    "himenia",
+    "himno",
+    "hinca",
+    "hincapié",
+    "hines",
+    "hinojosa",
+    "hipersensibilidad",
+    "hipertensión",
+    "hipertiroidismo",
+    "hipo",
+    "hiponacte",
+    "hipoteca",
+    "hipotecada",
+    "hipotecaria",
+    "hipotecarias",
+    "hipotecario",
+    "hipotéticamente",
+    "hipotéticos",
+    "hippie",
+    "hippies",
+    "hipólito",
+    "hipótesis",
+    "hiriendo",
+    "hirió",
+    "hirofumi",
+    "hiroshima",
+    "hispana",
+    "hispanio",
+    "hispanos",
+    "hispánicas",
+    "histeria",
+    "historia",
+    "historiadores",
+    "historial",
+    "historias",
+    "historico",
+    "histéricamente",
+    "histérico",
+    "histórica",
+    "históricamente",
+    "históricas",
+    "histórico",
+    "históricos",
+    "hitazo",
+    "hitler",
+    "hits",
+    "hizo",
+    "ho",
+    "hobbie",
+    "hoc",
+    "hocico",
+    "hogar",
+    "hogares",
+    "hogueras",
+    "hoja",
+    "hojalata",
+    "hojas",
+    "hojeo",
+    "hojita",
+    "hola",
+    "holanda",
+    "holandés",
+    "holanes",
+    "holgada",
+    "holgado",
+    "holguín",
+    "holliday",
+    "hollywood",
+    "holt",
+    "holzinger",
+    "homar",
+    "hombre",
+    "hombres",
+    "hombro",
+    "homenaje",
+    "homenajeado",
+    "homero",
+    "homeóstasis",
+    "homicida",
+    "homicidas",
+    "homicidio",
+    "homicidios",
+    "homilía",
+    "homologación",
+    "homologando",
+    "homologarlas",
+    "homologarlos",
+    "homologarnos",
+    "homosexual",
+    "homosexuales",
+    "homosexualismo",
+    "homs",
+    "homólogo",
+    "homólogos",
+    "homónimo",
+    "honda",
+    "hondo",
+    "honduras",
+    "hondureño",
+    "hondureños",
+    "honesta",
+    "honestamente",
+    "honestidad",
+    "honesto",
+    "honestos",
+    "honor",
+    "honorabilidad",
+    "honorable",
+    "honorables",
+    "honorarios",
+    "honores",
+    "honorífica",
+    "honrada",
+    "honradez",
+    "honrados",
+    "honrarlo",
+    "hopkins",
+    "hora",
+    "horacio",
+    "horario",
+    "horarios",
+    "horas",
+    "horcada",
+    "horchata",
+    "horizon",
+    "horizonte",
+    "horizontes",
+    "hormona",
+    "hormonas",
+    "horneados",
+    "hornos",
+    "horrenda",
+    "horrendo",
+    "horribles",
+    "horrorizado",
+    "hortalizas",
+    "horwath",
+    "hospedaba",
+    "hospederos",
+    "hospicio",
+    "hospital",
+    "hospitalario",
+    "hospitalarios",
+    "hospitales",
+    "hospitalización",
+    "hospitalizado",
+    "hospitalizados",
+    "hostile",
+    "hostilidades",
+    "hotel",
+    "hotelera",
+    "hoteleros",
+    "hoteles",
+    "houston",
+    "howard",
+    "hoy",
+    "hoyo",
+    "hoyos",
+    "hp",
+    "hristo",
+    "huacho",
+    "huahutla",
+    "huamantla",
+    "huambo",
+    "huang",
+    "huapango",
+    "huasteca",
+    "huautla",
+    "huayami",
+    "hub",
+    "hube",
+    "hubiera",
+    "hubierais",
+    "hubieran",
+    "hubieras",
+    "hubiere",
+    "hubieron",
+    "hubiese",
+    "hubieseis",
+    "hubiesen",
+    "hubieses",
+    "hubimos",
+    "hubiste",
+    "hubisteis",
+    "hubiéramos",
+    "hubiésemos",
+    "hubo",
+    "huecos",
+    "huehuetenango",
+    "huehuetlatoa",
+    "huehuetlán",
+    "huela",
+    "huele",
+    "huelen",
+    "huelga",
+    "huelguistas",
+    "huella",
+    "huellas",
+    "huerta",
+    "huertas",
+    "huerto",
+    "huertos",
+    "huesca",
+    "hueso",
+    "huestes",
+    "huevazos",
+    "huevo",
+    "huevos",
+    "hufbauer",
+    "hugo",
+    "huichapan",
+    "huichol",
+    "huicholas",
+    "huicholes",
+    "huidizo",
+    "huinalá",
+    "huir",
+    "huitzilopochtli",
+    "huitzuco",
+    "hules",
+    "humana",
+    "humanas",
+    "humanidad",
+    "humanidades",
+    "humanista",
+    "humanitario",
+    "humanitarios",
+    "humaniza",
+    "humanizar",
+    "humano",
+    "humanos",
+    "humberto",
+    "humeante",
+    "humedad",
+    "humildad",
+    "humilde",
+    "humillados",
+    "humillante",
+    "humillantemente",
+    "humillar",
+    "humo",
+    "humor",
+    "humorista",
+    "hundida",
+    "hundidas",
+    "hundido",
+    "hundidos",
+    "hundieron",
+    "hundimientos",
+    "hundir",
+    "hundió",
+    "hungo",
+    "hungría",
+    "huntsville",
+    "hurtadillas",
+    "hurtado",
+    "hurtic",
+    "huss",
+    "hussein",
+    "huyeron",
+    "huyó",
+    "huérfanos",
+    "huésped",
+    "hylsa",
+    "hábil",
+    "hábiles",
+    "hábito",
+    "hábitos",
+    "háblame",
+    "hágalas",
+    "hágalo",
+    "héctor",
+    "hércules",
+    "hérdez",
+    "héroe",
+    "héroes",
+    "híbrido",
+    "híbridos",
+    "hígado",
+    "hígados",
+    "húngaro",
+    "iaaf",
+    "iacocca",
+    "iannuzzelli",
+    "iba",
+    "iban",
+    "ibargüengoitia",
+    "ibarra",
+    "ibm",
+    "ibor",
+    "ibéricos",
+    "ica",
+    "iceberg",
+    "ichi",
+    "ici",
+    "iconografía",
+    "iconográfica",
+    "id",
+    "ida",
+    "idaho",
+    "idalia",
+    "ideales",
+    "idealistas",
+    "ideario",
+    "identidad",
+    "identifica",
+    "identificación",
+    "identificada",
+    "identificadas",
+    "identificado",
+    "identificados",
+    "identifican",
+    "identificando",
+    "identificar",
+    "identificarlo",
+    "identificaron",
+    "identificarse",
+    "identificó",
+    "identifique",
+    "identifiquen",
+    "ideología",
+    "ideologías",
+    "ideológica",
+    "ideológicas",
+    "ideológico",
+    "ideó",
+    "idioma",
+    "idiomas",
+    "idiosincrasia",
+    "idiotas",
+    "ido",
+    "idolatría",
+    "idéntica",
+    "idéntico",
+    "idólatra",
+    "idóneo",
+    "ife",
+    "ifigenia",
+    "ifigenios",
+    "iglesia",
+    "iglesiaestado",
+    "iglesias",
+    "ignacio",
+    "ignición",
+    "ignominia",
+    "ignora",
+    "ignoran",
+    "ignorancia",
+    "ignorando",
+    "ignorante",
+    "ignorar",
+    "igor",
+    "igual",
+    "iguala",
+    "igualada",
+    "igualadas",
+    "igualan",
+    "igualar",
+    "igualarlo",
+    "igualarnos",
+    "igualaron",
+    "igualdad",
+    "iguales",
+    "igualita",
+    "igualmente",
+    "ii",
+    "iii",
+    "il",
+    "ildefonso",
+    "ilegal",
+    "ilegales",
+    "ilegalmente",
+    "ilesa",
+    "ileso",
+    "ilimitada",
+    "ilimitadas",
+    "ilimitado",
+    "ilimitados",
+    "illinois",
+    "iluminación",
+    "iluminadas",
+    "iluminaron",
+    "iluminó",
+    "ilusas",
+    "ilusiones",
+    "ilusión",
+    "ilustra",
+    "ilustraciones",
+    "ilustran",
+    "ilustrar",
+    "ilustraría",
+    "ilícita",
+    "ilícito",
+    "ilícitos",
+    "ilógica",
+    "ilógico",
+    "im",
+    "imagen",
+    "imagina",
+    "imaginación",
+    "imaginar",
+    "imagino",
+    "imaginó",
+    "imagínense",
+    "imagínese",
+    "imax",
+    "imbécil",
+    "imecas",
+    "imelda",
+    "imevisión",
+    "imitable",
+    "imitaciones",
+    "imitación",
+    "imitan",
+    "imitar",
+    "imitarlo",
+    "imp",
+    "impaciencia",
+    "impacta",
+    "impactar",
+    "impactaron",
+    "impactaría",
+    "impacten",
+    "impacto",
+    "impactos",
+    "impactó",
+    "imparcial",
+    "imparcialidad",
+    "impartan",
+    "imparte",
+    "imparten",
+    "impartida",
+    "impartiendo",
+    "impartieran",
+    "impartimos",
+    "impartir",
+    "impartirá",
+    "impartirán",
+    "impartía",
+    "impasse",
+    "impecable",
+    "impedido",
+    "impedimento",
+    "impedimentos",
+    "impedir",
+    "impedirle",
+    "impedirá",
+    "impedirán",
+    "impediría",
+    "imperan",
+    "imperdonable",
+    "imperfecto",
+    "imperial",
+    "imperiales",
+    "imperialismo",
+    "imperialistas",
+    "imperio",
+    "impersonal",
+    "impida",
+    "impide",
+    "impiden",
+    "impidiera",
+    "impidieron",
+    "impidió",
+    "implacable",
+    "implacables",
+    "implantación",
+    "implantado",
+    "implantados",
+    "implantando",
+    "implantaremos",
+    "implanté",
+    "implementación",
+    "implementada",
+    "implementan",
+    "implementando",
+    "implementar",
+    "implementarla",
+    "implementarse",
+    "implementarán",
+    "implementos",
+    "implementó",
+    "implica",
+    "implicaciones",
+    "implicación",
+    "implicados",
+    "implican",
+    "implicando",
+    "implicar",
+    "implicaran",
+    "implicará",
+    "implicó",
+    "implícito",
+    "impolutos",
+    "impondrá",
+    "impondrán",
+    "impondría",
+    "impone",
+    "imponemos",
+    "imponen",
+    "imponer",
+    "imponerse",
+    "impongan",
+    "imponiendo",
+    "imponiéndose",
+    "importa",
+    "importaba",
+    "importaciones",
+    "importación",
+    "importadas",
+    "importado",
+    "importadora",
+    "importados",
+    "importan",
+    "importancia",
+    "importando",
+    "importante",
+    "importantemente",
+    "importantes",
+    "importantísimas",
+    "importantísimo",
+    "importantísimos",
+    "importar",
+    "importará",
+    "importaría",
+    "importe",
+    "importó",
+    "imposibilidad",
+    "imposibilitada",
+    "imposible",
+    "imposiciones",
+    "imposición",
+    "impositivos",
+    "impostergable",
+    "impostergables",
+    "impotencia",
+    "impotente",
+    "impregnada",
+    "impregnan",
+    "imprenta",
+    "impresa",
+    "impresas",
+    "impresionable",
+    "impresionada",
+    "impresionado",
+    "impresionante",
+    "impresionantes",
+    "impresiones",
+    "impresionismo",
+    "impresionista",
+    "impresionó",
+    "impresión",
+    "impreso",
+    "impresora",
+    "impresoras",
+    "impresores",
+    "impresos",
+    "imprevisión",
+    "imprimir",
+    "imprimirse",
+    "imprimió",
+    "improductiva",
+    "impropiedades",
+    "improvisación",
+    "improvisada",
+    "improvisados",
+    "imprudencia",
+    "imprudencial",
+    "imprudencialmente",
+    "imprudentes",
+    "impuesta",
+    "impuestas",
+    "impuesto",
+    "impuestos",
+    "impugnaciones",
+    "impugnado",
+    "impulsa",
+    "impulsan",
+    "impulsando",
+    "impulsar",
+    "impulse",
+    "impulsivo",
+    "impulso",
+    "impulsos",
+    "impunemente",
+    "impunes",
+    "impunidad",
+    "impunidades",
+    "impuntualidad",
+    "impuras",
+    "impusieron",
+    "impuso",
+    "imputaciones",
+    "imputado",
+    "imsa",
+    "imss",
+    "imágenes",
+    "imán",
+    "inaccesible",
+    "inaccesibles",
+    "inacción",
+    "inaceptable",
+    "inactividad",
+    "inadecuada",
+    "inadmisible",
+    "inadvertidas",
+    "inadvertidos",
+    "inah",
+    "inalienables",
+    "inalámbrico",
+    "inamovible",
+    "inamovibles",
+    "inaplicables",
+    "inasistencia",
+    "inaudito",
+    "inaugura",
+    "inauguraciones",
+    "inauguración",
+    "inaugurada",
+    "inaugurado",
+    "inaugural",
+    "inauguran",
+    "inaugurar",
+    "inaugurará",
+    "inauguró",
+    "inba",
+    "inc",
+    "incalculable",
+    "incapaces",
+    "incapacidad",
+    "incapacitado",
+    "incapacitados",
+    "incapaz",
+    "incautada",
+    "incautado",
+    "incautan",
+    "incauten",
+    "incautó",
+    "incendiarias",
+    "incendio",
+    "incendios",
+    "incendió",
+    "incentivar",
+    "incentivo",
+    "incentivos",
+    "incertidumbre",
+    "incesante",
+    "incesantemente",
+    "incian",
+    "incide",
+    "inciden",
+    "incidencia",
+    "incidental",
+    "incidente",
+    "incidentes",
+    "incidido",
+    "incierto",
+    "inciertos",
+    "incinerador",
+    "incisos",
+    "incitación",
+    "incitador",
+    "incitaron",
+    "inclemencias",
+    "inclina",
+    "inclinaba",
+    "inclinaciones",
+    "inclinación",
+    "inclinada",
+    "inclinados",
+    "inclinamos",
+    "inclinan",
+    "inclinándose",
+    "inclinó",
+    "incluida",
+    "incluidas",
+    "incluido",
+    "incluidos",
+    "incluimos",
+    "incluir",
+    "incluirlo",
+    "incluirlos",
+    "incluirá",
+    "incluirán",
+    "incluiría",
+    "inclusión",
+    "incluso",
+    "incluya",
+    "incluyan",
+    "incluye",
+    "incluyen",
+    "incluyendo",
+    "incluyeron",
+    "incluyó",
+    "incluía",
+    "incluír",
+    "inclán",
+    "incoherencias",
+    "incompetentes",
+    "incompleta",
+    "incompleto",
+    "incomprendidos",
+    "incomprensibles",
+    "incomprensión",
+    "incomunicación",
+    "incomunicada",
+    "incomunicadas",
+    "incondicional",
+    "incondicionales",
+    "inconformaron",
+    "inconformes",
+    "inconformidad",
+    "inconformidades",
+    "incongruencias",
+    "incongruente",
+    "inconmovibles",
+    "inconsciente",
+    "inconscientes",
+    "inconsecuencia",
+    "inconsistencia",
+    "inconsistente",
+    "inconstitucionales",
+    "inconstitucionalidad",
+    "incontenible",
+    "incontrolable",
+    "incontrolables",
+    "incontrolado",
+    "inconveniente",
+    "inconvenientes",
+    "incora",
+    "incorpora",
+    "incorporación",
+    "incorporados",
+    "incorporan",
+    "incorporando",
+    "incorporar",
+    "incorporaron",
+    "incorporarse",
+    "incorporated",
+    "incorpore",
+    "incorporen",
+    "incorporó",
+    "incosteables",
+    "incredulidad",
+    "incrementa",
+    "incrementadas",
+    "incrementado",
+    "incrementando",
+    "incrementar",
+    "incrementarles",
+    "incrementaron",
+    "incrementarse",
+    "incrementará",
+    "incremente",
+    "incrementen",
+    "incremento",
+    "incrementos",
+    "incrementándose",
+    "incrementó",
+    "increparan",
+    "increíble",
+    "increíblemente",
+    "increíbles",
+    "incriminar",
+    "incrustaciones",
+    "incrustación",
+    "incrustó",
+    "incubación",
+    "incuestionable",
+    "incuestionables",
+    "inculcar",
+    "incumbe",
+    "incumple",
+    "incumplen",
+    "incumplidas",
+    "incumplido",
+    "incumplieron",
+    "incumplimiento",
+    "incumplió",
+    "incurran",
+    "incurre",
+    "incurrido",
+    "incurrieron",
+    "incurrir",
+    "incurrió",
+    "incursiona",
+    "incursionamos",
+    "incursionar",
+    "incursión",
+    "incómodo",
+    "inda",
+    "indagación",
+    "indagando",
+    "indebido",
+    "indebidos",
+    "indecibles",
+    "indecisa",
+    "indecisión",
+    "indeciso",
+    "indefensión",
+    "indefinida",
+    "indefinidamente",
+    "indefinido",
+    "indejo",
+    "indemnizaciones",
+    "indemnización",
+    "indemnizar",
+    "indemnizó",
+    "independencia",
+    "independiente",
+    "independientemente",
+    "independientes",
+    "independiza",
+    "independizó",
+    "indeseado",
+    "indeterminado",
+    "indiana",
+    "indianapolis",
+    "indica",
+    "indicaba",
+    "indicaban",
+    "indicaciones",
+    "indicada",
+    "indicado",
+    "indicador",
+    "indicadores",
+    "indican",
+    "indicando",
+    "indicar",
+    "indicaron",
+    "indicará",
+    "indicarán",
+    "indicativa",
+    "indicators",
+    "indicio",
+    "indicios",
+    "indico",
+    "indicándome",
+    "indicó",
+    "indiferencia",
+    "indiferente",
+    "indiferentes",
+    "indigencia",
+    "indigente",
+    "indigentes",
+    "indignación",
+    "indignado",
+    "indignidad",
+    "indigno",
+    "indignó",
+    "indio",
+    "indique",
+    "indirecta",
+    "indirectamente",
+    "indirectas",
+    "indisciplina",
+    "indiscreciones",
+    "indiscriminadamente",
+    "indiscutible",
+    "indiscutibles",
+    "indispensable",
+    "indispensables",
+    "indistintos",
+    "individuales",
+    "individualidades",
+    "individualista",
+    "individualistas",
+    "individualmente",
+    "individuo",
+    "individuos",
+    "indonesia",
+    "indubitable",
+    "inducción",
+    "induce",
+    "inducir",
+    "inducirlo",
+    "indudablemente",
+    "indulgencias",
+    "indulto",
+    "indultó",
+    "indumentaria",
+    "industria",
+    "industrial",
+    "industriales",
+    "industrialización",
+    "industrializadas",
+    "industrializados",
+    "industrias",
+    "industries",
+    "industriosa",
+    "indy",
+    "indígena",
+    "indígenas",
+    "inea",
+    "ineficaces",
+    "ineficacia",
+    "ineficaz",
+    "ineficiencia",
+    "ineficiencias",
+    "ineficiente",
+    "inegi",
+    "inelegancias",
+    "ineludible",
+    "ineptas",
+    "inequívoca",
+    "inercia",
+    "inescrutable",
+    "inestabilidad",
+    "inestable",
+    "inevitable",
+    "inevitablemente",
+    "inevitables",
+    "inexactitudes",
+    "inexistente",
+    "inexorablemente",
+    "inexplicable",
+    "inexplicablemente",
+    "inexploradas",
+    "infactibilidad",
+    "infalibilidad",
+    "infalsificables",
+    "infame",
+    "infamia",
+    "infancia",
+    "infante",
+    "infantes",
+    "infantil",
+    "infantiles",
+    "infarto",
+    "infecciones",
+    "infección",
+    "infectología",
+    "inferido",
+    "inferior",
+    "inferiores",
+    "inferioridad",
+    "infernal",
+    "infernales",
+    "infestadas",
+    "infidelidad",
+    "infidelidades",
+    "infiel",
+    "infierno",
+    "infiltrados",
+    "infiltraron",
+    "infinidad",
+    "infinitum",
+    "inflaba",
+    "inflacionario",
+    "inflacionarios",
+    "inflación",
+    "inflamación",
+    "inflar",
+    "infle",
+    "influencia",
+    "influenciada",
+    "influencias",
+    "influenzae",
+    "influido",
+    "influir",
+    "influiría",
+    "influjo",
+    "influyen",
+    "influyente",
+    "influyó",
+    "infonavit",
+    "informa",
+    "informaba",
+    "informaciones",
+    "información",
+    "informada",
+    "informado",
+    "informados",
+    "informal",
+    "informan",
+    "informante",
+    "informantes",
+    "informar",
+    "informarle",
+    "informaron",
+    "informarse",
+    "informará",
+    "informarán",
+    "informatica",
+    "informativa",
+    "informativas",
+    "informativo",
+    "informativos",
+    "informe",
+    "informes",
+    "informándome",
+    "informática",
+    "informático",
+    "informé",
+    "informó",
+    "inforrey",
+    "infortunadamente",
+    "infortunado",
+    "infraccionados",
+    "infraccionar",
+    "infracción",
+    "infractor",
+    "infractores",
+    "infraestructura",
+    "infrarrojas",
+    "infrarrojos",
+    "infundados",
+    "infundios",
+    "infundir",
+    "infusiones",
+    "infusión",
+    "ing",
+    "ingando",
+    "ingeniero",
+    "ingenieros",
+    "ingeniería",
+    "ingenio",
+    "ingeniosa",
+    "ingenioso",
+    "ingenua",
+    "ingenuas",
+    "ingenuo",
+    "ingerencia",
+    "ingerir",
+    "inglaterra",
+    "inglesa",
+    "inglesas",
+    "ingleses",
+    "inglés",
+    "ingratos",
+    "ingrediente",
+    "ingredientes",
+    "ingresa",
+    "ingresaba",
+    "ingresado",
+    "ingresan",
+    "ingresando",
+    "ingresar",
+    "ingresara",
+    "ingresaron",
+    "ingreso",
+    "ingresos",
+    "ingresó",
+    "inhabilitados",
+    "inhabilitan",
+    "inhalaciones",
+    "inhalámbricos",
+    "inherente",
+    "inherentes",
+    "inhibiendo",
+    "inicado",
+    "inicará",
+    "inicia",
+    "iniciaba",
+    "iniciación",
+    "iniciada",
+    "iniciadas",
+    "iniciado",
+    "iniciadores",
+    "inicial",
+    "iniciales",
+    "inicialización",
+    "inicialmente",
+    "inician",
+    "iniciando",
+    "iniciar",
+    "iniciara",
+    "iniciarme",
+    "iniciaron",
+    "iniciarse",
+    "iniciará",
+    "iniciarán",
+    "iniciaría",
+    "iniciarían",
+    "iniciativa",
+    "iniciativas",
+    "inicie",
+    "iniciemos",
+    "inicien",
+    "inicio",
+    "inicios",
+    "inició",
+    "inimaginables",
+    "ininteligibles",
+    "ininterrumpida",
+    "injerencia",
+    "injude",
+    "injurias",
+    "injusta",
+    "injustas",
+    "injusticia",
+    "injusticias",
+    "injustificada",
+    "injustificados",
+    "injusto",
+    "injustos",
+    "inmadurez",
+    "inmaduros",
+    "inmediaciones",
+    "inmediata",
+    "inmediatamente",
+    "inmediatas",
+    "inmediato",
+    "inmediatos",
+    "inmenso",
+    "inmensos",
+    "inmigrantes",
+    "inminencia",
+    "inminente",
+    "inmiscuidos",
+    "inmiscuirse",
+    "inmobiliaria",
+    "inmobiliarias",
+    "inmobiliario",
+    "inmobiliarios",
+    "inmorales",
+    "inmortal",
+    "inmortales",
+    "inmortalidad",
+    "inmovilizó",
+    "inmueble",
+    "inmuebles",
+    "inmunes",
+    "inmunidad",
+    "inmunológica",
+    "inmutable",
+    "inn",
+    "innecesaria",
+    "innecesarias",
+    "innecesario",
+    "innecesarios",
+    "innova",
+    "innovaciones",
+    "innovación",
+    "innovador",
+    "innovadora",
+    "innovadores",
+    "innovar",
+    "inntienen",
+    "innumerables",
+    "inobservancia",
+    "inocencia",
+    "inocente",
+    "inocentes",
+    "inocular",
+    "inocularle",
+    "inofensivos",
+    "inolvidable",
+    "inoperantes",
+    "inquiere",
+    "inquieta",
+    "inquietas",
+    "inquieto",
+    "inquietos",
+    "inquietud",
+    "inquietudes",
+    "inquilinaria",
+    "inquilino",
+    "inquilinos",
+    "inquina",
+    "inquisición",
+    "insaciable",
+    "insalvables",
+    "inscribe",
+    "inscribir",
+    "inscribirse",
+    "inscribió",
+    "inscripciones",
+    "inscripción",
+    "inscrita",
+    "inscritas",
+    "inscrito",
+    "inscritos",
+    "insecticida",
+    "insecto",
+    "insectos",
+    "inseguridad",
+    "insen",
+    "insensatez",
+    "inservibles",
+    "insiders",
+    "insidiosos",
+    "insignia",
+    "insignificante",
+    "insinuó",
+    "insinúa",
+    "insista",
+    "insiste",
+    "insisten",
+    "insistencia",
+    "insistente",
+    "insistieron",
+    "insistir",
+    "insistirse",
+    "insistirá",
+    "insistiré",
+    "insististe",
+    "insistió",
+    "insisto",
+    "insistía",
+    "insistían",
+    "insospechado",
+    "inspeccionaba",
+    "inspeccionado",
+    "inspecciones",
+    "inspección",
+    "inspectores",
+    "inspiraba",
+    "inspiraciones",
+    "inspiración",
+    "inspirada",
+    "inspirado",
+    "instala",
+    "instalaciones",
+    "instalación",
+    "instalada",
+    "instaladas",
+    "instalado",
+    "instalados",
+    "instalamos",
+    "instalan",
+    "instalando",
+    "instalar",
+    "instalarlo",
+    "instalarlos",
+    "instalaron",
+    "instalarse",
+    "instalará",
+    "instalarán",
+    "instalen",
+    "instaló",
+    "instancia",
+    "instancias",
+    "instante",
+    "instantes",
+    "instantánea",
+    "instantáneamente",
+    "instará",
+    "instaurada",
+    "instinto",
+    "institucional",
+    "institucionales",
+    "institucionalizar",
+    "institucionalmente",
+    "instituciones",
+    "institución",
+    "instituida",
+    "institute",
+    "instituto",
+    "institutos",
+    "instituya",
+    "instituyan",
+    "instituír",
+    "instrucciones",
+    "instrucción",
+    "instructor",
+    "instructora",
+    "instrumentación",
+    "instrumentado",
+    "instrumentados",
+    "instrumental",
+    "instrumentar",
+    "instrumentaran",
+    "instrumentistas",
+    "instrumento",
+    "instrumentos",
+    "instruments",
+    "instrumentó",
+    "instruye",
+    "instruyó",
+    "instándolos",
+    "instó",
+    "insubordinación",
+    "insubstancial",
+    "insuficiencia",
+    "insuficiente",
+    "insuficientes",
+    "insulta",
+    "insultaba",
+    "insultando",
+    "insulto",
+    "insultos",
+    "insulté",
+    "insumo",
+    "insuperable",
+    "insurgencia",
+    "insurgentes",
+    "insurrección",
+    "insípidos",
+    "insólito",
+    "intachable",
+    "intacta",
+    "intactas",
+    "intacto",
+    "integra",
+    "integracionista",
+    "integración",
+    "integrada",
+    "integradas",
+    "integrado",
+    "integrados",
+    "integrales",
+    "integran",
+    "integrante",
+    "integrantes",
+    "integrar",
+    "integrarlo",
+    "integrarlos",
+    "integraron",
+    "integrarse",
+    "integrará",
+    "integrarán",
+    "integraría",
+    "integridad",
+    "integristas",
+    "integró",
+    "intelectual",
+    "intelectuales",
+    "intelectualmente",
+    "inteligencia",
+    "inteligente",
+    "inteligentes",
+    "intempestivamente",
+    "intempestivo",
+    "intencionado",
+    "intencional",
+    "intencionales",
+    "intenciones",
+    "intención",
+    "intensa",
+    "intensamente",
+    "intensas",
+    "intensidad",
+    "intensificado",
+    "intensificando",
+    "intensificar",
+    "intensificó",
+    "intensifiquen",
+    "intensivamente",
+    "intensión",
+    "intenso",
+    "intensos",
+    "intenta",
+    "intentaba",
+    "intentaban",
+    "intentado",
+    "intentamos",
+    "intentan",
+    "intentando",
+    "intentar",
+    "intentarlo",
+    "intentaron",
+    "intentará",
+    "intentarán",
+    "intentaré",
+    "intento",
+    "intentos",
+    "intentó",
+    "inter",
+    "interacción",
+    "interaciones",
+    "interactiva",
+    "interactivo",
+    "interamericana",
+    "interbancaria",
+    "interbancarias",
+    "interbancario",
+    "intercambian",
+    "intercambiando",
+    "intercambiar",
+    "intercambio",
+    "intercambié",
+    "intercambió",
+    "interceder",
+    "intercedido",
+    "intercepción",
+    "interceptado",
+    "interceptados",
+    "intercomunicará",
+    "interconexión",
+    "interconstruido",
+    "intercontinental",
+    "intercostal",
+    "intercostero",
+    "interdependiente",
+    "interdisciplinario",
+    "interesa",
+    "interesaba",
+    "interesada",
+    "interesadas",
+    "interesado",
+    "interesados",
+    "interesan",
+    "interesante",
+    "interesantes",
+    "interesara",
+    "interesarme",
+    "interesaron",
+    "interesaría",
+    "interescuadras",
+    "interese",
+    "interesen",
+    "intereses",
+    "interfase",
+    "interferencia",
+    "interfieran",
+    "interfieren",
+    "interfronterizo",
+    "intergrupales",
+    "interina",
+    "interinato",
+    "interinatos",
+    "interino",
+    "interiores",
+    "interioridad",
+    "interiormente",
+    "interlocutora",
+    "intermatrix",
+    "intermedia",
+    "intermediación",
+    "intermediaria",
+    "intermediarias",
+    "intermediario",
+    "intermediarios",
+    "intermediarista",
+    "intermedio",
+    "intermedios",
+    "intermitentes",
+    "interna",
+    "internacional",
+    "internacionales",
+    "internacionalización",
+    "internacionalmente",
+    "internado",
+    "internamente",
+    "internarnos",
+    "internaron",
+    "internas",
+    "interno",
+    "internos",
+    "internó",
+    "interpeló",
+    "interponer",
+    "interpreta",
+    "interpretaba",
+    "interpretaciones",
+    "interpretación",
+    "interpretado",
+    "interpretamos",
+    "interpretan",
+    "interpretando",
+    "interpretar",
+    "interpretara",
+    "interpretaremos",
+    "interpretarlas",
+    "interpretarse",
+    "interpretará",
+    "interpretarán",
+    "interprete",
+    "interpreten",
+    "interpretándolo",
+    "interpretó",
+    "interpuestas",
+    "interpuestos",
+    "interpuso",
+    "interrelacionarse",
+    "interrogada",
+    "interrogado",
+    "interrogados",
+    "interrogante",
+    "interrogar",
+    "interrogó",
+    "interrumpa",
+    "interrumpe",
+    "interrumpido",
+    "interrumpidos",
+    "interrumpieron",
+    "interrumpir",
+    "interrumpió",
+    "interrumpía",
+    "interrupción",
+    "interruptor",
+    "interticket",
+    "intervalos",
+    "intervenciones",
+    "intervencionismo",
+    "intervención",
+    "intervendrá",
+    "intervenga",
+    "intervengan",
+    "intervenido",
+    "intervenidos",
+    "intervenir",
+    "intervenirse",
+    "interventores",
+    "interviene",
+    "intervienen",
+    "interviniera",
+    "intervinieron",
+    "intervino",
+    "interés",
+    "intestino",
+    "intimate",
+    "intimidad",
+    "intimidados",
+    "intimidando",
+    "intolerable",
+    "intolerancia",
+    "intoxicación",
+    "intoxicados",
+    "intoxicando",
+    "intralox",
+    "intramuscular",
+    "intransigencia",
+    "intransigentemente",
+    "intrascendente",
+    "intrascendentes",
+    "intrincadas",
+    "introducción",
+    "introducido",
+    "introduciendo",
+    "introducir",
+    "introducirles",
+    "introducirse",
+    "introducirán",
+    "introductorio",
+    "introdujera",
+    "introdujeron",
+    "introdujo",
+    "introduzca",
+    "introspectivo",
+    "introvertido",
+    "intrusión",
+    "intrínseca",
+    "intuición",
+    "intuitiva",
+    "intérprete",
+    "intérpretes",
+    "inundaciones",
+    "inundación",
+    "inundadas",
+    "inundado",
+    "inusitada",
+    "inusitado",
+    "inusual",
+    "invadidas",
+    "invadido",
+    "invadieron",
+    "invadir",
+    "invadió",
+    "invalidan",
+    "invaluable",
+    "invasiones",
+    "invasión",
+    "invencible",
+    "invención",
+    "inventado",
+    "inventando",
+    "inventar",
+    "inventario",
+    "invente",
+    "inventiva",
+    "invento",
+    "inventé",
+    "inventó",
+    "inverlat",
+    "inverméxico",
+    "invernadero",
+    "invernal",
+    "inverosímil",
+    "inversa",
+    "inversiones",
+    "inversionista",
+    "inversionistas",
+    "inversión",
+    "invertidas",
+    "invertido",
+    "invertidos",
+    "invertimos",
+    "invertir",
+    "invertiremos",
+    "invertirla",
+    "invertirle",
+    "invertirá",
+    "invertirán",
+    "invertirían",
+    "invertía",
+    "investidura",
+    "investiga",
+    "investigaban",
+    "investigaciones",
+    "investigación",
+    "investigado",
+    "investigador",
+    "investigadora",
+    "investigadores",
+    "investigados",
+    "investigan",
+    "investigando",
+    "investigar",
+    "investigaran",
+    "investigarse",
+    "investigará",
+    "investigue",
+    "investiguen",
+    "investigó",
+    "invicta",
+    "invicto",
+    "invictos",
+    "invierno",
+    "invierta",
+    "invierte",
+    "invierten",
+    "invirtiendo",
+    "invirtieron",
+    "invirtió",
+    "invisiblemente",
+    "invita",
+    "invitaba",
+    "invitaciones",
+    "invitación",
+    "invitadas",
+    "invitado",
+    "invitados",
+    "invitamos",
+    "invitan",
+    "invitando",
+    "invitar",
+    "invitarlo",
+    "invitaron",
+    "invite",
+    "inviten",
+    "invito",
+    "invitó",
+    "invocando",
+    "involucra",
+    "involucrada",
+    "involucradas",
+    "involucrado",
+    "involucrados",
+    "involucramiento",
+    "involucran",
+    "involucrar",
+    "involucrarlo",
+    "involucrarme",
+    "involucrarse",
+    "involucre",
+    "involucro",
+    "involuntario",
+    "inyección",
+    "inyectar",
+    "inyecte",
+    "inyectó",
+    "inédita",
+    "inéditas",
+    "inédito",
+    "inés",
+    "inútil",
+    "inútiles",
+    "inútilmente",
+    "ionizados",
+    "ionizantes",
+    "ip",
+    "ipiña",
+    "ir",
+    "ira",
+    "iracheta",
+    "iraheta",
+    "iran",
+    "iraní",
+    "iraníes",
+    "irapuato",
+    "iraq",
+    "iraquí",
+    "iraquíes",
+    "iremos",
+    "iriarte",
+    "irigoyen",
+    "irineo",
+    "irlandeses",
+    "irlandés",
+    "irlos",
+    "irma",
+    "irme",
+    "irnos",
+    "ironizaba",
+    "ironía",
+    "irracional",
+    "irrealistas",
+    "irrealizables",
+    "irreconocible",
+    "irrecuperable",
+    "irredentos",
+    "irregular",
+    "irregulares",
+    "irregularidad",
+    "irregularidades",
+    "irrelevante",
+    "irrelevantes",
+    "irremisiblemente",
+    "irrenunciabilidad",
+    "irresoluto",
+    "irresolutos",
+    "irresponsabilidad",
+    "irresponsable",
+    "irresponsables",
+    "irreversible",
+    "irrita",
+    "irritaciones",
+    "irritación",
+    "irritado",
+    "irritan",
+    "irritantes",
+    "irritarse",
+    "irruga",
+    "irse",
+    "irte",
+    "irvin",
+    "irá",
+    "irán",
+    "iría",
+    "irían",
+    "irónico",
+    "isaac",
+    "isabel",
+    "isabela",
+    "isaias",
+    "isidro",
+    "isla",
+    "islam",
+    "islandia",
+    "islas",
+    "isley",
+    "isleño",
+    "isleños",
+    "islámico",
+    "ismael",
+    "israel",
+    "israelí",
+    "israelíes",
+    "issste",
+    "isssteleon",
+    "isssteleón",
+    "istmo",
+    "italia",
+    "italiana",
+    "italiano",
+    "italianos",
+    "itam",
+    "itamaraty",
+    "itesm",
+    "itinerante",
+    "itinerantes",
+    "itinerario",
+    "itinerarios",
+    "itoloca",
+    "itsmo",
+    "itzá",
+    "iv",
+    "iva",
+    "ivo",
+    "iván",
+    "iwai",
+    "ix",
+    "ixalapa",
+    "ixcaquixtla",
+    "izar",
+    "izetbegovic",
+    "izquierda",
+    "izquierdas",
+    "izquierdo",
+    "iztapalapa",
+    "izvestia",
+    "j",
+    "jaafar",
+    "jaboneras",
+    "jacinta",
+    "jackeline",
+    "jackie",
+    "jackson",
+    "jacobo",
+    "jacobs",
+    "jacques",
+    "jactancio",
+    "jacte",
+    "jade",
+    "jaguares",
+    "jaguarundis",
+    "jaibos",
+    "jaime",
+    "jalaba",
+    "jalado",
+    "jalando",
+    "jalapeños",
+    "jalar",
+    "jalarse",
+    "jale",
+    "jalisciense",
+    "jalisco",
+    "jalones",
+    "jaló",
+    "jam",
+    "jamaiquina",
+    "jamás",
+    "jamón",
+    "jana",
+    "janeiro",
+    "janice",
+    "japon",
+    "japonesa",
+    "japonesas",
+    "japoneses",
+    "japonés",
+    "japón",
+    "jaque",
+    "jaramillo",
+    "jardinero",
+    "jardinería",
+    "jardines",
+    "jardín",
+    "jarocha",
+    "jarochas",
+    "jarra",
+    "jarro",
+    "jarry",
+    "jasso",
+    "jaula",
+    "javier",
+    "jazz",
+    "jazzista",
+    "jc",
+    "jcpenney",
+    "jean",
+    "jeans",
+    "jefa",
+    "jefatura",
+    "jefaturados",
+    "jefe",
+    "jefes",
+    "jeff",
+    "jefferson",
+    "jehová",
+    "jerarcas",
+    "jerarquía",
+    "jeremie",
+    "jeremy",
+    "jerga",
+    "jersey",
+    "jerónimo",
+    "jesse",
+    "jessica",
+    "jesés",
+    "jesús",
+    "jethro",
+    "jetta",
+    "jim",
+    "jimmy",
+    "jiménez",
+    "jinete",
+    "jinetear",
+    "jinetes",
+    "jingles",
+    "jo",
+    "joachim",
+    "joao",
+    "joaquín",
+    "joel",
+    "johnny",
+    "johns",
+    "jol",
+    "jolalpán",
+    "jolopo",
+    "jonas",
+    "jones",
+    "jonrón",
+    "jorge",
+    "jornada",
+    "jornadas",
+    "joroba",
+    "jorobas",
+    "josefina",
+    "joselito",
+    "joseph",
+    "josé",
+    "jour",
+    "joven",
+    "jovencita",
+    "jovencitas",
+    "jovencito",
+    "jovencitos",
+    "jovial",
+    "joya",
+    "joyas",
+    "joyera",
+    "joyero",
+    "joyeros",
+    "joyería",
+    "joyitas",
+    "jr",
+    "juan",
+    "juana",
+    "juarez",
+    "jubilado",
+    "jubilados",
+    "jubilosa",
+    "judicial",
+    "judiciales",
+    "judith",
+    "judoka",
+    "judío",
+    "judíos",
+    "jueceo",
+    "jueces",
+    "juega",
+    "juegan",
+    "juegas",
+    "juego",
+    "juegos",
+    "juegue",
+    "jueguen",
+    "jueves",
+    "juez",
+    "jugaba",
+    "jugaban",
+    "jugada",
+    "jugado",
+    "jugador",
+    "jugadoras",
+    "jugadores",
+    "jugados",
+    "jugando",
+    "jugar",
+    "jugaran",
+    "jugaremos",
+    "jugaron",
+    "jugará",
+    "jugarán",
+    "jugaría",
+    "jugo",
+    "jugosa",
+    "juguete",
+    "juguetes",
+    "juguetito",
+    "jugué",
+    "jugó",
+    "juicio",
+    "juicios",
+    "julia",
+    "julieta",
+    "julio",
+    "julius",
+    "julián",
+    "juncal",
+    "junco",
+    "jung",
+    "junio",
+    "junior",
+    "junquia",
+    "junta",
+    "juntando",
+    "juntar",
+    "juntaron",
+    "juntas",
+    "junto",
+    "juntos",
+    "jurado",
+    "juramentado",
+    "juramentaron",
+    "juramentó",
+    "jurisdicción",
+    "juristas",
+    "juro",
+    "jurídica",
+    "jurídicamente",
+    "jurídicas",
+    "jurídico",
+    "jurídicos",
+    "justa",
+    "justice",
+    "justicia",
+    "justiciero",
+    "justifica",
+    "justificable",
+    "justificación",
+    "justificada",
+    "justificadas",
+    "justifican",
+    "justificando",
+    "justificantes",
+    "justificar",
+    "justificare",
+    "justificará",
+    "justificarán",
+    "justificó",
+    "justifique",
+    "justino",
+    "justo",
+    "justos",
+    "juvenil",
+    "juveniles",
+    "juventino",
+    "juventud",
+    "juzgaban",
+    "juzgada",
+    "juzgado",
+    "juzgador",
+    "juzgados",
+    "juzgar",
+    "juzgarlo",
+    "juzgue",
+    "juárez",
+    "jóven",
+    "jóvenes",
+    "júbilo",
+    "k",
+    "kahlo",
+    "kaiman",
+    "kansai",
+    "kantor",
+    "kaoteng",
+    "karam",
+    "karate",
+    "karcz",
+    "kardex",
+    "karen",
+    "karina",
+    "karl",
+    "karla",
+    "katheyn",
+    "katia",
+    "kaveh",
+    "kay",
+    "kb",
+    "kbytes",
+    "keldvsh",
+    "kelly",
+    "kelso",
+    "kempes",
+    "kenia",
+    "keniano",
+    "kenianos",
+    "keniata",
+    "kennan",
+    "kennedy",
+    "kenneth",
+    "kenny",
+    "kensington",
+    "kent",
+    "kentenich",
+    "kenworth",
+    "kerosina",
+    "kevin",
+    "keynesianismo",
+    "kg",
+    "kgb",
+    "khasbulatov",
+    "kibeho",
+    "kidman",
+    "kiko",
+    "kilo",
+    "kilogramos",
+    "kilometraje",
+    "kilometro",
+    "kilos",
+    "kilómetro",
+    "kilómetros",
+    "kim",
+    "kima",
+    "kinshasa",
+    "kipper",
+    "kissinger",
+    "kit",
+    "km",
+    "kmart",
+    "knight",
+    "ko",
+    "koblenz",
+    "koeman",
+    "korshak",
+    "kosovo",
+    "krafchick",
+    "kremlin",
+    "kresak",
+    "kretch",
+    "krieger",
+    "krill",
+    "kristof",
+    "kruger",
+    "kruschev",
+    "kubli",
+    "kuehnheim",
+    "kumate",
+    "kuniaki",
+    "kuperlmann",
+    "kuramura",
+    "kurdos",
+    "kutugata",
+    "kuwait",
+    "kuwaití",
+    "kytty",
+    "l",
+    "la",
+    "labastida",
+    "laberinto",
+    "labiche",
+    "labor",
+    "labora",
+    "laboral",
+    "laborales",
+    "laborando",
+    "laborar",
+    "laboratorio",
+    "laboratorios",
+    "laborde",
+    "labores",
+    "laborioso",
+    "laboró",
+    "labra",
+    "labrecque",
+    "lacónicos",
+    "lada",
+    "ladarse",
+    "ladas",
+    "ladatel",
+    "ladera",
+    "laderas",
+    "ladino",
+    "ladinos",
+    "lado",
+    "lados",
+    "ladrones",
+    "ladrón",
+    "lagerfeld",
+    "lago",
+    "lagos",
+    "laguna",
+    "lagunas",
+    "lagunera",
+    "laguneros",
+    "lagüera",
+    "laicos",
+    "lajas",
+    "lajous",
+    "lala",
+    "lamadrid",
+    "lamarche",
+    "lambisconería",
+    "lambretón",
+    "lamen",
+    "lamenta",
+    "lamentable",
+    "lamentablemente",
+    "lamentables",
+    "lamentaron",
+    "lamento",
+    "lamentó",
+    "laminadora",
+    "lamosa",
+    "lana",
+    "lanari",
+    "lance",
+    "lancen",
+    "lancha",
+    "lanchero",
+    "lancé",
+    "landois",
+    "landru",
+    "langange",
+    "langley",
+    "lantana",
+    "lantastic",
+    "lanz",
+    "lanza",
+    "lanzaba",
+    "lanzada",
+    "lanzadas",
+    "lanzado",
+    "lanzador",
+    "lanzadores",
+    "lanzagranadas",
+    "lanzamiento",
+    "lanzamientos",
+    "lanzar",
+    "lanzaran",
+    "lanzaron",
+    "lanzarse",
+    "lanzará",
+    "lanzarán",
+    "lanzó",
+    "lapso",
+    "lara",
+    "lareau",
+    "laredo",
+    "laredos",
+    "lares",
+    "larga",
+    "largamente",
+    "largas",
+    "largo",
+    "largometraje",
+    "largometrajes",
+    "largos",
+    "larguirucho",
+    "larguísimo",
+    "larios",
+    "larralde",
+    "larry",
+    "las",
+    "lascivo",
+    "laser",
+    "lasercard",
+    "lasser",
+    "lastimada",
+    "lastimadas",
+    "lastimar",
+    "lastimeramente",
+    "lata",
+    "latas",
+    "latente",
+    "lateral",
+    "laterales",
+    "lateralidad",
+    "latido",
+    "latina",
+    "latino",
+    "latinoamericanas",
+    "latinoamericano",
+    "latinoamericanos",
+    "latinoamérica",
+    "latinos",
+    "latitudes",
+    "latón",
+    "laudos",
+    "laudrup",
+    "laura",
+    "laureano",
+    "lauren",
+    "laurence",
+    "laurent",
+    "lava",
+    "lavacoches",
+    "lavaderos",
+    "lavado",
+    "lavadora",
+    "lavalle",
+    "lavan",
+    "lavar",
+    "lavarlo",
+    "lavercombe",
+    "lavolpe",
+    "laxitud",
+    "layla",
+    "lazcano",
+    "lazo",
+    "lazos",
+    "le",
+    "leal",
+    "leales",
+    "lealtad",
+    "leblanc",
+    "lecciones",
+    "lección",
+    "leche",
+    "lechero",
+    "lechería",
+    "lecho",
+    "lechuga",
+    "leckie",
+    "lecomte",
+    "lector",
+    "lectores",
+    "lectura",
+    "lecturas",
+    "ledezma",
+    "ledo",
+    "lee",
+    "leeds",
+    "leen",
+    "leer",
+    "leerlo",
+    "leerán",
+    "legado",
+    "legales",
+    "legalidad",
+    "legalizar",
+    "legalizó",
+    "legalmente",
+    "legar",
+    "legendario",
+    "legisla",
+    "legislación",
+    "legislador",
+    "legisladora",
+    "legisladores",
+    "legislan",
+    "legislar",
+    "legislativa",
+    "legislativas",
+    "legislativo",
+    "legislativos",
+    "legislatura",
+    "legislaturas",
+    "legisle",
+    "legislen",
+    "legista",
+    "legitimidad",
+    "legorreta",
+    "legrá",
+    "leguas",
+    "legue",
+    "legumbres",
+    "legítima",
+    "legítimadefensa",
+    "legítimamente",
+    "legítimo",
+    "lehnert",
+    "lejana",
+    "lejanas",
+    "lejano",
+    "lejanos",
+    "lejos",
+    "lema",
+    "lemas",
+    "lemus",
+    "lencería",
+    "lendl",
+    "lengua",
+    "lenguaje",
+    "lennard",
+    "lenny",
+    "lenta",
+    "lentamente",
+    "lentejuela",
+    "lentejuelas",
+    "lentes",
+    "lentitud",
+    "lento",
+    "lentos",
+    "leo",
+    "leonard",
+    "leonarda",
+    "leonardo",
+    "leones",
+    "leonor",
+    "leopoldo",
+    "leotardos",
+    "lerma",
+    "lermas",
+    "lerna",
+    "leroux",
+    "les",
+    "lesbianas",
+    "lesiona",
+    "lesionada",
+    "lesionadas",
+    "lesionado",
+    "lesionados",
+    "lesionan",
+    "lesionando",
+    "lesionar",
+    "lesiones",
+    "lesiono",
+    "lesionó",
+    "lesión",
+    "lessing",
+    "letal",
+    "letales",
+    "leticia",
+    "letra",
+    "letras",
+    "letrero",
+    "letreros",
+    "letrina",
+    "letrinas",
+    "letrista",
+    "lett",
+    "leucemia",
+    "levanta",
+    "levantaba",
+    "levantada",
+    "levantado",
+    "levantados",
+    "levantamiento",
+    "levantando",
+    "levantante",
+    "levantar",
+    "levantaran",
+    "levantarme",
+    "levantaron",
+    "levantarse",
+    "levantarán",
+    "levantarás",
+    "levante",
+    "levanten",
+    "levantándose",
+    "levantó",
+    "levati",
+    "leve",
+    "levemente",
+    "leves",
+    "levin",
+    "levy",
+    "levín",
+    "levítico",
+    "ley",
+    "leyenda",
+    "leyendas",
+    "leyendo",
+    "leyeron",
+    "leyes",
+    "leyva",
+    "leyó",
+    "leí",
+    "leía",
+    "leída",
+    "leído",
+    "leña",
+    "leñero",
+    "león",
+    "lianas",
+    "libanesa",
+    "libanés",
+    "libera",
+    "liberación",
+    "liberada",
+    "liberado",
+    "liberal",
+    "liberales",
+    "liberalismo",
+    "liberalización",
+    "liberalizar",
+    "liberan",
+    "liberar",
+    "liberarlo",
+    "liberaron",
+    "liberará",
+    "liberia",
+    "libero",
+    "libertad",
+    "libertades",
+    "libertador",
+    "libertario",
+    "liberó",
+    "libidinosamente",
+    "libidio",
+    "libramiento",
+    "libran",
+    "librando",
+    "librarla",
+    "librarse",
+    "librarte",
+    "librará",
+    "libras",
+    "libre",
+    "librerías",
+    "libres",
+    "libreta",
+    "librito",
+    "libro",
+    "libros",
+    "libró",
+    "lic",
+    "licea",
+    "licencia",
+    "licenciado",
+    "licencias",
+    "licenciaturas",
+    "licha",
+    "licitaciones",
+    "licitación",
+    "licitará",
+    "liconsa",
+    "licor",
+    "licuada",
+    "lid",
+    "liderato",
+    "liderazgo",
+    "liderazgos",
+    "lidereados",
+    "liderear",
+    "lideresa",
+    "lideresas",
+    "lidia",
+    "lidiado",
+    "lidiaron",
+    "lidice",
+    "lidió",
+    "lido",
+    "lienzo",
+    "liera",
+    "lievin",
+    "lifane",
+    "liga",
+    "ligados",
+    "ligamayorista",
+    "ligamento",
+    "ligar",
+    "ligarde",
+    "ligaron",
+    "ligas",
+    "ligera",
+    "ligeramente",
+    "ligeras",
+    "ligereza",
+    "ligero",
+    "ligne",
+    "ligue",
+    "liguilla",
+    "ligó",
+    "liliana",
+    "lima",
+    "limar",
+    "limita",
+    "limitaba",
+    "limitaciones",
+    "limitado",
+    "limitados",
+    "limitan",
+    "limitar",
+    "limitará",
+    "limite",
+    "limitó",
+    "limonada",
+    "limosine",
+    "limosna",
+    "limosnita",
+    "limpia",
+    "limpiamente",
+    "limpiando",
+    "limpiaparabrisas",
+    "limpiar",
+    "limpiaron",
+    "limpias",
+    "limpieza",
+    "limpio",
+    "limítrofes",
+    "limón",
+    "lina",
+    "linares",
+    "linces",
+    "linchar",
+    "lincoln",
+    "linda",
+    "linduras",
+    "linea",
+    "lineal",
+    "lineamiento",
+    "lineamientos",
+    "lineker",
+    "liner",
+    "ling",
+    "lingada",
+    "linterna",
+    "liquidaciones",
+    "liquidación",
+    "liquidar",
+    "liquidará",
+    "liquidez",
+    "liquido",
+    "lira",
+    "lisa",
+    "lista",
+    "listado",
+    "listados",
+    "listas",
+    "listo",
+    "listos",
+    "listón",
+    "literalmente",
+    "literaria",
+    "literarias",
+    "literarios",
+    "literatos",
+    "literatura",
+    "litigios",
+    "litoral",
+    "litorales",
+    "litro",
+    "litros",
+    "livas",
+    "liverpool",
+    "livestock",
+    "liviano",
+    "llaguno",
+    "llama",
+    "llamaba",
+    "llamaban",
+    "llamada",
+    "llamadas",
+    "llamado",
+    "llamados",
+    "llamamiento",
+    "llamamos",
+    "llaman",
+    "llamando",
+    "llamar",
+    "llamara",
+    "llamarlo",
+    "llamarnos",
+    "llamaron",
+    "llamarse",
+    "llamará",
+    "llamaría",
+    "llamarían",
+    "llamas",
+    "llamativa",
+    "llamativas",
+    "llamativos",
+    "llame",
+    "llamen",
+    "llamo",
+    "llamáis",
+    "llamó",
+    "llanamente",
+    "llanas",
+    "llanes",
+    "llano",
+    "llanos",
+    "llanta",
+    "llantas",
+    "llanto",
+    "llave",
+    "llaveros",
+    "llaves",
+    "lle",
+    "llega",
+    "llegaba",
+    "llegaban",
+    "llegada",
+    "llegadas",
+    "llegado",
+    "llegados",
+    "llegamos",
+    "llegan",
+    "llegando",
+    "llegar",
+    "llegara",
+    "llegaran",
+    "llegaremos",
+    "llegaron",
+    "llegarse",
+    "llegará",
+    "llegarán",
+    "llegaré",
+    "llegaría",
+    "llegarían",
+    "llegas",
+    "llego",
+    "llegue",
+    "lleguemos",
+    "lleguen",
+    "llegó",
+    "llena",
+    "llenado",
+    "llenan",
+    "llenando",
+    "llenar",
+    "llenarla",
+    "llenarlas",
+    "llenaron",
+    "llenarse",
+    "llenará",
+    "llenas",
+    "lleno",
+    "llenos",
+    "llenó",
+    "llergo",
+    "lleva",
+    "llevaba",
+    "llevaban",
+    "llevada",
+    "llevadas",
+    "llevado",
+    "llevados",
+    "llevamos",
+    "llevan",
+    "llevando",
+    "llevar",
+    "llevara",
+    "llevaran",
+    "llevarla",
+    "llevarlas",
+    "llevarle",
+    "llevarlo",
+    "llevarlos",
+    "llevaron",
+    "llevarse",
+    "llevará",
+    "llevarán",
+    "llevaría",
+    "llevarían",
+    "llevas",
+    "llevase",
+    "lleve",
+    "lleven",
+    "llevo",
+    "llevándolos",
+    "llevándose",
+    "llevárselo",
+    "llevó",
+    "llora",
+    "lloraba",
+    "lloran",
+    "llorando",
+    "llorar",
+    "lloraré",
+    "llorosa",
+    "lloró",
+    "llosa",
+    "llover",
+    "lloviendo",
+    "llovizna",
+    "lloviznas",
+    "llovió",
+    "llueva",
+    "llueve",
+    "llueven",
+    "lluvia",
+    "lluvias",
+    "lluvioso",
+    "lluviosos",
+    "llévelo",
+    "lmkf",
+    "lo",
+    "loable",
+    "loaeza",
+    "lobeira",
+    "lobo",
+    "lobos",
+    "loca",
+    "locación",
+    "locales",
+    "localice",
+    "localidad",
+    "localidades",
+    "localiza",
+    "localización",
+    "localizada",
+    "localizadas",
+    "localizado",
+    "localizados",
+    "localizar",
+    "localizaron",
+    "localizará",
+    "localizó",
+    "localmente",
+    "locka",
+    "loco",
+    "locos",
+    "locura",
+    "lodazal",
+    "lodo",
+    "lofton",
+    "logotipo",
+    "logra",
+    "lograba",
+    "lograban",
+    "lograda",
+    "logradas",
+    "logrado",
+    "logrados",
+    "logramos",
+    "logran",
+    "logrando",
+    "lograr",
+    "lograra",
+    "lograremos",
+    "lograrlo",
+    "lograron",
+    "lograrse",
+    "logrará",
+    "lograrán",
+    "logre",
+    "logren",
+    "logro",
+    "logronés",
+    "logros",
+    "logroñés",
+    "logré",
+    "logró",
+    "lolita",
+    "loma",
+    "lomas",
+    "lombardo",
+    "lomelí",
+    "lomos",
+    "lona",
+    "lonche",
+    "lonches",
+    "londinense",
+    "londres",
+    "longevo",
+    "longitud",
+    "longoria",
+    "lope",
+    "lopez",
+    "lopezportillista",
+    "loredo",
+    "lorena",
+    "lorenzo",
+    "loreto",
+    "los",
+    "losada",
+    "lote",
+    "lotería",
+    "lotes",
+    "lotus",
+    "louis",
+    "louisiana",
+    "lourdes",
+    "lover",
+    "loya",
+    "loyola",
+    "lozano",
+    "lsqb",
+    "ltd",
+    "lubricación",
+    "lucas",
+    "luce",
+    "lucen",
+    "lucero",
+    "luces",
+    "lucha",
+    "luchaba",
+    "luchado",
+    "luchador",
+    "luchadores",
+    "luchando",
+    "luchar",
+    "lucharon",
+    "luchará",
+    "luchas",
+    "lucho",
+    "luchó",
+    "luciano",
+    "lucido",
+    "luciendo",
+    "lucieron",
+    "lucila",
+    "lucimiento",
+    "lucio",
+    "lucir",
+    "lució",
+    "lucrar",
+    "lucro",
+    "luctuosas",
+    "lucía",
+    "ludmila",
+    "luego",
+    "lueguito",
+    "lugar",
+    "lugares",
+    "lugo",
+    "luis",
+    "luisa",
+    "luiz",
+    "lujo",
+    "lujos",
+    "lujoso",
+    "lumbreras",
+    "luminosas",
+    "luminosidad",
+    "luminoso",
+    "luminotécnicos",
+    "luna",
+    "lunar",
+    "lunes",
+    "luneta",
+    "lunáticos",
+    "lupe",
+    "lupercio",
+    "lupita",
+    "lusinchi",
+    "lutero",
+    "luto",
+    "luviano",
+    "luxemburgo",
+    "luz",
+    "luzcan",
+    "luzón",
+    "luévano",
+    "lwdvig",
+    "lydia",
+    "lyon",
+    "lácteos",
+    "lágrimas",
+    "lámina",
+    "láminas",
+    "lámparas",
+    "lápiz",
+    "láser",
+    "láseres",
+    "lástima",
+    "lázaro",
+    "léase",
+    "léoz",
+    "líbano",
+    "líbero",
+    "lícito",
+    "líder",
+    "líderes",
+    "límite",
+    "límites",
+    "línea",
+    "líneas",
+    "líos",
+    "líquido",
+    "líquidos",
+    "lóbulo",
+    "lógica",
+    "lógicamente",
+    "lógico",
+    "lópez",
+    "lópezfigueroa",
+    "lúcidamente",
+    "lúdica",
+    "lúdicos",
+    "lúpulo",
+    "m",
+    "ma",
+    "macabro",
+    "macartismo",
+    "macedonia",
+    "macedonio",
+    "machado",
+    "machetero",
+    "machiria",
+    "machismo",
+    "machista",
+    "macho",
+    "machuca",
+    "macizo",
+    "macotela",
+    "macroeconómica",
+    "macroeconómicas",
+    "macroplaza",
+    "macrópolis",
+    "macumba",
+    "macías",
+    "madamme",
+    "madariaga",
+    "madeja",
+    "mademoiselle",
+    "madera",
+    "maderas",
+    "madero",
+    "maderámenes",
+    "madrazo",
+    "madre",
+    "madres",
+    "madrid",
+    "madrigal",
+    "madril",
+    "madrileñas",
+    "madrileño",
+    "madrileños",
+    "madrina",
+    "madrinas",
+    "madrugada",
+    "madura",
+    "maduración",
+    "madurar",
+    "madure",
+    "madurez",
+    "maduro",
+    "maduros",
+    "maestra",
+    "maestras",
+    "maestro",
+    "maestros",
+    "maestría",
+    "maestrías",
+    "mafia",
+    "mafiosos",
+    "magala",
+    "magaña",
+    "magdalena",
+    "magia",
+    "magisterial",
+    "magisteriales",
+    "magisterio",
+    "magistrado",
+    "magistrados",
+    "magistral",
+    "magistrales",
+    "magistralmente",
+    "magma",
+    "magna",
+    "magnesia",
+    "magneto",
+    "magnetómetro",
+    "magnificencia",
+    "magnitud",
+    "magno",
+    "magnolia",
+    "magnum",
+    "magnética",
+    "magnéticamente",
+    "magnéticas",
+    "magnéticos",
+    "magnífica",
+    "magníficas",
+    "magnífico",
+    "magníficos",
+    "magotteaux",
+    "mague",
+    "mahoney",
+    "mahony",
+    "maiz",
+    "majadero",
+    "majadería",
+    "majdel",
+    "majestad",
+    "majestuosas",
+    "majestuosidad",
+    "majestuoso",
+    "maksim",
+    "mal",
+    "mala",
+    "malagón",
+    "malas",
+    "malcolm",
+    "malcriada",
+    "maldad",
+    "maldades",
+    "maldiciones",
+    "maldición",
+    "maldito",
+    "maldonado",
+    "maleantes",
+    "males",
+    "maletas",
+    "malicia",
+    "maligna",
+    "malignas",
+    "malignidad",
+    "maligno",
+    "malinche",
+    "malinchista",
+    "malintencionada",
+    "malla",
+    "mallas",
+    "malo",
+    "malograrlas",
+    "malos",
+    "malta",
+    "maltratado",
+    "maltratados",
+    "maltrato",
+    "maltrecha",
+    "malva",
+    "malvada",
+    "malvavisco",
+    "malversaciones",
+    "malversación",
+    "malvivientes",
+    "mamilas",
+    "mamparas",
+    "mamá",
+    "mamás",
+    "mamíferos",
+    "manazos",
+    "mancha",
+    "manchada",
+    "manchar",
+    "manchas",
+    "mancillados",
+    "mancomunada",
+    "mancuso",
+    "manda",
+    "mandadas",
+    "mandado",
+    "mandamiento",
+    "mandamientos",
+    "mandamos",
+    "mandan",
+    "mandar",
+    "mandarinas",
+    "mandarlas",
+    "mandarlo",
+    "mandaron",
+    "mandará",
+    "mandaríamos",
+    "mandatario",
+    "mandatarios",
+    "mandato",
+    "mandatos",
+    "manden",
+    "mandilones",
+    "mando",
+    "mandos",
+    "mandril",
+    "mandíbula",
+    "mandíbulas",
+    "mandó",
+    "maneja",
+    "manejaba",
+    "manejable",
+    "manejada",
+    "manejadas",
+    "manejado",
+    "manejador",
+    "manejadores",
+    "manejados",
+    "manejamos",
+    "manejan",
+    "manejando",
+    "manejar",
+    "manejaron",
+    "manejarse",
+    "manejará",
+    "maneje",
+    "manejen",
+    "manejes",
+    "manejo",
+    "manejos",
+    "manejó",
+    "manente",
+    "manera",
+    "maneras",
+    "manga",
+    "manganas",
+    "mangas",
+    "manglares",
+    "manguera",
+    "manhattan",
+    "maniatar",
+    "manieristas",
+    "manifestaban",
+    "manifestaciones",
+    "manifestación",
+    "manifestada",
+    "manifestadas",
+    "manifestado",
+    "manifestantes",
+    "manifestar",
+    "manifestaran",
+    "manifestaron",
+    "manifestarse",
+    "manifestó",
+    "manifiesta",
+    "manifiesto",
+    "manifold",
+    "manila",
+    "maniobra",
+    "maniobras",
+    "manipulación",
+    "manipulado",
+    "manipular",
+    "manita",
+    "manjar",
+    "manjarrez",
+    "manneck",
+    "mano",
+    "manolita",
+    "manolo",
+    "manos",
+    "manotas",
+    "manrique",
+    "manríquez",
+    "mansedumbre",
+    "mansiones",
+    "mansión",
+    "manso",
+    "mansos",
+    "mansur",
+    "mantas",
+    "mantendrá",
+    "mantenemos",
+    "mantener",
+    "mantenerlas",
+    "mantenerlo",
+    "mantenerlos",
+    "mantenerme",
+    "mantenernos",
+    "mantenerse",
+    "mantenga",
+    "mantengan",
+    "mantenidas",
+    "mantenido",
+    "manteniendo",
+    "mantenimiento",
+    "manteniéndose",
+    "mantenía",
+    "mantiene",
+    "mantienen",
+    "manto",
+    "mantuvieron",
+    "mantuvo",
+    "manu",
+    "manuales",
+    "manualidades",
+    "manuel",
+    "manufactura",
+    "manufacturar",
+    "manufacturera",
+    "manufactureros",
+    "manzana",
+    "manzanas",
+    "manzanillo",
+    "maná",
+    "manía",
+    "maoísta",
+    "maoístas",
+    "mapa",
+    "mapas",
+    "mapinfo",
+    "maqueta",
+    "maquilada",
+    "maquilador",
+    "maquiladora",
+    "maquiladoras",
+    "maquilados",
+    "maquilas",
+    "maquillado",
+    "maquillaje",
+    "maquillarlas",
+    "maquinaria",
+    "maquinas",
+    "maquinista",
+    "mara",
+    "maradona",
+    "maraldo",
+    "marasmo",
+    "maratón",
+    "maravilla",
+    "maravillando",
+    "maravillosa",
+    "maravillosamente",
+    "maravillosas",
+    "maravillosos",
+    "marc",
+    "marca",
+    "marcaba",
+    "marcación",
+    "marcada",
+    "marcadas",
+    "marcado",
+    "marcador",
+    "marcadores",
+    "marcan",
+    "marcando",
+    "marcar",
+    "marcaron",
+    "marcará",
+    "marcas",
+    "marcela",
+    "marcelino",
+    "marcelo",
+    "marcha",
+    "marchado",
+    "marchan",
+    "marcharon",
+    "marcharse",
+    "marcharían",
+    "marchas",
+    "marche",
+    "marchó",
+    "marcial",
+    "marciano",
+    "marcio",
+    "marco",
+    "marcos",
+    "marcus",
+    "marcusse",
+    "marcó",
+    "mareadas",
+    "maremoto",
+    "mares",
+    "marfil",
+    "marga",
+    "margaret",
+    "margaretha",
+    "margarita",
+    "margen",
+    "margenes",
+    "margina",
+    "marginaciones",
+    "marginación",
+    "marginadas",
+    "marginados",
+    "marginal",
+    "marginara",
+    "marginó",
+    "marguerite",
+    "margules",
+    "margáin",
+    "mari",
+    "maria",
+    "mariachi",
+    "marian",
+    "mariana",
+    "marianas",
+    "mariani",
+    "mariano",
+    "marianos",
+    "mariateguista",
+    "maribel",
+    "maricela",
+    "maridito",
+    "marido",
+    "maridos",
+    "marie",
+    "mariela",
+    "mariguana",
+    "marihuana",
+    "marijo",
+    "marilyn",
+    "marin",
+    "marina",
+    "marinas",
+    "marinera",
+    "marineros",
+    "marino",
+    "marinos",
+    "mario",
+    "marion",
+    "marionetero",
+    "mariposas",
+    "mariscal",
+    "mariscales",
+    "marisma",
+    "marissa",
+    "marita",
+    "marketing",
+    "marley",
+    "marlines",
+    "marlon",
+    "marque",
+    "marquesina",
+    "marquis",
+    "marqués",
+    "marranos",
+    "marsalis",
+    "marshal",
+    "marshall",
+    "mart",
+    "marte",
+    "martellotto",
+    "martens",
+    "martes",
+    "martha",
+    "martillo",
+    "martinez",
+    "martinica",
+    "martiniqués",
+    "martirio",
+    "martí",
+    "martín",
+    "martínez",
+    "marv",
+    "marx",
+    "maryland",
+    "marzo",
+    "maría",
+    "marín",
+    "marítima",
+    "marítimo",
+    "mas",
+    "masa",
+    "masaaki",
+    "masacre",
+    "masakre",
+    "masas",
+    "mascadas",
+    "mascota",
+    "mascotas",
+    "masculina",
+    "masculino",
+    "masiva",
+    "masivamente",
+    "masivas",
+    "masivo",
+    "masivos",
+    "masoquismo",
+    "mass",
+    "massa",
+    "massacessi",
+    "massachusetts",
+    "massachussets",
+    "massachussetts",
+    "massieu",
+    "masso",
+    "masterd",
+    "masturbación",
+    "mata",
+    "matado",
+    "matador",
+    "matadores",
+    "matamoros",
+    "matando",
+    "matanzas",
+    "matar",
+    "mataran",
+    "matarla",
+    "matarlo",
+    "matarme",
+    "matarnos",
+    "mataron",
+    "matarse",
+    "mataría",
+    "mate",
+    "matemática",
+    "matemáticas",
+    "matemático",
+    "maten",
+    "mateo",
+    "mateos",
+    "mater",
+    "materia",
+    "materiales",
+    "materializarse",
+    "materias",
+    "materna",
+    "maternal",
+    "maternidad",
+    "matices",
+    "matilde",
+    "matineé",
+    "matizada",
+    "matorrales",
+    "matos",
+    "matriarcado",
+    "matrices",
+    "matriculen",
+    "matrimonial",
+    "matrimoniales",
+    "matrimonio",
+    "matrimonios",
+    "matriz",
+    "matrícula",
+    "matt",
+    "matthew",
+    "matutina",
+    "matutino",
+    "matías",
+    "mató",
+    "maurice",
+    "mauricio",
+    "mauro",
+    "maxi",
+    "maximiliano",
+    "maximova",
+    "mayas",
+    "mayela",
+    "mayito",
+    "mayo",
+    "mayonesa",
+    "mayor",
+    "mayoreo",
+    "mayores",
+    "mayorista",
+    "mayoristas",
+    "mayoritaria",
+    "mayoritario",
+    "mayoritarios",
+    "mayoría",
+    "mayos",
+    "mayrán",
+    "mays",
+    "mayweather",
+    "mayón",
+    "mayúscula",
+    "mayúsculas",
+    "mayúsculo",
+    "maza",
+    "mazatlan",
+    "mazatleco",
+    "mazatlán",
+    "maíz",
+    "maña",
+    "mañana",
+    "mañanas",
+    "mañosamente",
+    "mc",
+    "mcallen",
+    "mccann",
+    "mcdonalds",
+    "mcdowell",
+    "mcenroe",
+    "mcgregor",
+    "mckesson",
+    "mcleod",
+    "md",
+    "me",
+    "mea",
+    "meat",
+    "mecanismo",
+    "mecanismos",
+    "mecenazgo",
+    "mecánica",
+    "mecánico",
+    "mecánicos",
+    "medalla",
+    "medallas",
+    "medallista",
+    "medallón",
+    "medellín",
+    "mediación",
+    "mediadora",
+    "mediados",
+    "mediahermana",
+    "mediana",
+    "medianas",
+    "mediano",
+    "medianoche",
+    "medianos",
+    "mediante",
+    "medias",
+    "mediata",
+    "medical",
+    "medicamentos",
+    "medicina",
+    "medicinales",
+    "medicinas",
+    "mediciones",
+    "medición",
+    "medida",
+    "medidas",
+    "medido",
+    "medievales",
+    "medina",
+    "medio",
+    "mediocampista",
+    "mediocampistas",
+    "mediocre",
+    "mediocres",
+    "mediocridad",
+    "mediodía",
+    "mediohermano",
+    "medios",
+    "medir",
+    "medirse",
+    "medirá",
+    "medirán",
+    "meditación",
+    "meditado",
+    "medite",
+    "mediterránea",
+    "mediterráneo",
+    "medrano",
+    "medugorie",
+    "medusia",
+    "mefistófeles",
+    "megabits",
+    "megabytes",
+    "megahertz",
+    "mejilla",
+    "mejillas",
+    "mejor",
+    "mejora",
+    "mejorada",
+    "mejorado",
+    "mejorados",
+    "mejoramiento",
+    "mejoran",
+    "mejorando",
+    "mejorar",
+    "mejoraran",
+    "mejorarle",
+    "mejorarlos",
+    "mejorará",
+    "mejoras",
+    "mejore",
+    "mejores",
+    "mejoría",
+    "mejrar",
+    "mejía",
+    "melanoma",
+    "melchor",
+    "melgar",
+    "melian",
+    "melisa",
+    "mella",
+    "mellizos",
+    "mello",
+    "melodía",
+    "melquiades",
+    "melé",
+    "meléndez",
+    "melódica",
+    "melón",
+    "membretado",
+    "membrete",
+    "memo",
+    "memorable",
+    "memoria",
+    "memorias",
+    "memorizar",
+    "memorándum",
+    "mena",
+    "menchú",
+    "menciona",
+    "mencionada",
+    "mencionadas",
+    "mencionado",
+    "mencionados",
+    "mencionan",
+    "mencionar",
+    "mencionaremos",
+    "mencionaron",
+    "mencionaré",
+    "mencione",
+    "menciones",
+    "mencioné",
+    "mencionó",
+    "mención",
+    "mendigo",
+    "mendiomas",
+    "mendoza",
+    "menem",
+    "meneses",
+    "mengano",
+    "menguadas",
+    "meninges",
+    "meningitis",
+    "meningoencefalitis",
+    "menopausia",
+    "menopáusica",
+    "menor",
+    "menores",
+    "menos",
+    "menosprecian",
+    "menospreciarnos",
+    "menotti",
+    "mensaje",
+    "mensajería",
+    "mensajes",
+    "mensual",
+    "mensuales",
+    "mensualidades",
+    "mensualmente",
+    "menta",
+    "mental",
+    "mentales",
+    "mentalidad",
+    "mentalidades",
+    "mente",
+    "mentha",
+    "mentimos",
+    "mentir",
+    "mentiras",
+    "mentirosas",
+    "mentiroso",
+    "mentor",
+    "mentores",
+    "menudeo",
+    "menudo",
+    "menú",
+    "mera",
+    "meramente",
+    "mercadear",
+    "mercadeo",
+    "mercader",
+    "mercaderes",
+    "mercado",
+    "mercados",
+    "mercadotecnia",
+    "mercadotencia",
+    "mercancía",
+    "mercancías",
+    "mercante",
+    "mercantes",
+    "mercantil",
+    "mercantiles",
+    "mercantilista",
+    "mercedes",
+    "mercomún",
+    "mercosur",
+    "mercurio",
+    "merece",
+    "merecedor",
+    "merecedores",
+    "merecen",
+    "merecerlo",
+    "merecida",
+    "merecido",
+    "merecimientos",
+    "mereció",
+    "merecía",
+    "merengue",
+    "merengues",
+    "merezcan",
+    "meritorio",
+    "merlene",
+    "merlis",
+    "merman",
+    "mermeladas",
+    "mero",
+    "merrill",
+    "mes",
+    "mesa",
+    "mesas",
+    "mese",
+    "meseros",
+    "meses",
+    "mesilla",
+    "mesiánica",
+    "mesoamericana",
+    "mesoamericanas",
+    "mesoamericano",
+    "mesoamericanos",
+    "mestre",
+    "meta",
+    "metabolismo",
+    "metafísica",
+    "metalero",
+    "metales",
+    "metallica",
+    "metamucil",
+    "metan",
+    "metano",
+    "metas",
+    "metate",
+    "mete",
+    "meten",
+    "meteorito",
+    "meteorológica",
+    "meteorológico",
+    "meteorológicos",
+    "meteoros",
+    "meter",
+    "meterle",
+    "meterlo",
+    "meternos",
+    "meterse",
+    "meticulosamente",
+    "metido",
+    "metidos",
+    "metiendo",
+    "metiera",
+    "metió",
+    "metodista",
+    "metodología",
+    "metodologías",
+    "metrallas",
+    "metralleta",
+    "metralletas",
+    "metro",
+    "metrología",
+    "metropolitana",
+    "metropolitano",
+    "metropolitanos",
+    "metrorrey",
+    "metros",
+    "metrópoli",
+    "metternich",
+    "metzelaars",
+    "metáfora",
+    "metálica",
+    "metálico",
+    "metálicos",
+    "metí",
+    "metían",
+    "mexica",
+    "mexicali",
+    "mexican",
+    "mexicana",
+    "mexicanas",
+    "mexicanidad",
+    "mexicanismo",
+    "mexicano",
+    "mexicanos",
+    "mexicas",
+    "mexicayótl",
+    "mexico",
+    "mexinox",
+    "mexiquense",
+    "meyer",
+    "meza",
+    "mezcal",
+    "mezcla",
+    "mezclada",
+    "mezcladas",
+    "mezclado",
+    "mezclar",
+    "mezclilla",
+    "mezclo",
+    "mezquinas",
+    "mezquino",
+    "mezquinos",
+    "mezquital",
+    "mg",
+    "mi",
+    "mia",
+    "miami",
+    "mich",
+    "michel",
+    "michelle",
+    "michoacanazo",
+    "michoacano",
+    "michoacanos",
+    "michoacán",
+    "mickey",
+    "mico",
+    "micosis",
+    "microbus",
+    "microbuses",
+    "microbús",
+    "microcomputadoras",
+    "microelectronics",
+    "microempresa",
+    "microempresario",
+    "microempresarios",
+    "microempresas",
+    "microindustriales",
+    "microprocesador",
+    "micros",
+    "microsystems",
+    "micrófono",
+    "middletown",
+    "mide",
+    "midieron",
+    "midió",
+    "mie",
+    "miedo",
+    "miedos",
+    "miel",
+    "mielenización",
+    "miembro",
+    "miembros",
+    "mientan",
+    "miente",
+    "mientras",
+    "mier",
+    "miercoles",
+    "migajas",
+    "migdalia",
+    "mignón",
+    "migraciones",
+    "migración",
+    "migrante",
+    "miguel",
+    "miguelito",
+    "mijail",
+    "mijares",
+    "mil",
+    "milagro",
+    "milagros",
+    "milagrosos",
+    "milan",
+    "milanés",
+    "mildred",
+    "milenios",
+    "mililitros",
+    "milita",
+    "militan",
+    "militancia",
+    "militante",
+    "militantes",
+    "militar",
+    "militares",
+    "militarización",
+    "militarizada",
+    "milla",
+    "millar",
+    "millares",
+    "millas",
+    "miller",
+    "milli",
+    "millonarias",
+    "millonario",
+    "millonarios",
+    "millones",
+    "millón",
+    "milmo",
+    "milton",
+    "milán",
+    "milésimas",
+    "milímetros",
+    "mima",
+    "mimi",
+    "mimiahuápam",
+    "mimí",
+    "mina",
+    "mindes",
+    "minera",
+    "mineral",
+    "minerales",
+    "mineros",
+    "minerva",
+    "minesota",
+    "minibuses",
+    "minicomputadoras",
+    "minicumbre",
+    "minilab",
+    "minima",
+    "minimizando",
+    "minimizar",
+    "ministerial",
+    "ministerio",
+    "ministro",
+    "ministros",
+    "minisubmarinos",
+    "minivolei",
+    "minivoleibol",
+    "minnesota",
+    "minoristas",
+    "minoritaria",
+    "minoritarios",
+    "minorías",
+    "mintió",
+    "minton",
+    "mintonette",
+    "minuciosa",
+    "minusvalía",
+    "minusválidos",
+    "minuta",
+    "minuto",
+    "minutos",
+    "minúsculos",
+    "miocardio",
+    "miopía",
+    "mira",
+    "miraba",
+    "mirada",
+    "miradita",
+    "mirador",
+    "miragoane",
+    "miramontes",
+    "miramón",
+    "miranda",
+    "mirando",
+    "mirar",
+    "miras",
+    "miravalle",
+    "mire",
+    "mireles",
+    "miren",
+    "mireya",
+    "miriam",
+    "mirielle",
+    "miroslava",
+    "mirren",
+    "miré",
+    "miró",
+    "mis",
+    "misa",
+    "misas",
+    "miscelanea",
+    "miserables",
+    "miseria",
+    "misericordiosos",
+    "mishima",
+    "misionero",
+    "misiones",
+    "misión",
+    "misma",
+    "mismas",
+    "mismo",
+    "mismos",
+    "missael",
+    "missisipi",
+    "mississippi",
+    "missouri",
+    "misterio",
+    "misterios",
+    "misteriosa",
+    "misterioso",
+    "mitad",
+    "mitades",
+    "mitigantes",
+    "mitin",
+    "mito",
+    "mitología",
+    "mitológico",
+    "mitos",
+    "mitotes",
+    "mitras",
+    "mitsukoshi",
+    "mitterrand",
+    "mitzy",
+    "mixtas",
+    "miércoles",
+    "mka",
+    "mnp",
+    "moais",
+    "moaré",
+    "mobiliario",
+    "mobutu",
+    "moca",
+    "mochila",
+    "mochilas",
+    "mochis",
+    "moción",
+    "moctezuma",
+    "moda",
+    "modales",
+    "modalidad",
+    "modalidades",
+    "modas",
+    "modelo",
+    "modelos",
+    "modera",
+    "moderación",
+    "moderada",
+    "moderado",
+    "moderados",
+    "moderna",
+    "modernas",
+    "modernicen",
+    "modernidad",
+    "modernismo",
+    "modernización",
+    "modernizada",
+    "modernizador",
+    "modernizar",
+    "modernizarla",
+    "modernizarse",
+    "moderno",
+    "modernos",
+    "modesta",
+    "modestas",
+    "modestia",
+    "modesto",
+    "modestos",
+    "modifica",
+    "modificaciones",
+    "modificación",
+    "modificada",
+    "modificadas",
+    "modificados",
+    "modificando",
+    "modificar",
+    "modificarse",
+    "modificó",
+    "modista",
+    "modo",
+    "modos",
+    "modulada",
+    "moisés",
+    "mol",
+    "moldeado",
+    "moldearon",
+    "mole",
+    "molesta",
+    "molestada",
+    "molestados",
+    "molestan",
+    "molestar",
+    "molestarse",
+    "molestarán",
+    "moleste",
+    "molestia",
+    "molestias",
+    "molesto",
+    "molestos",
+    "molestó",
+    "molida",
+    "moliere",
+    "molina",
+    "molino",
+    "moll",
+    "mollejas",
+    "molones",
+    "mombasa",
+    "momento",
+    "momentos",
+    "momentánea",
+    "momentáneamente",
+    "momentáneo",
+    "monarca",
+    "monarquía",
+    "monas",
+    "moncada",
+    "monclova",
+    "mondongo",
+    "moneda",
+    "monedas",
+    "monederos",
+    "monetaria",
+    "monetarias",
+    "monetario",
+    "monipodio",
+    "monitoreo",
+    "monitoreos",
+    "monitores",
+    "monjaras",
+    "monjaraz",
+    "monjas",
+    "monk",
+    "monogollas",
+    "monolito",
+    "monoplaza",
+    "monoplazas",
+    "monopolio",
+    "monopolios",
+    "monroy",
+    "monsivais",
+    "monsiváis",
+    "monstruosidad",
+    "monstruosos",
+    "monta",
+    "montada",
+    "montado",
+    "montaje",
+    "montajes",
+    "montalbán",
+    "montalvo",
+    "montana",
+    "montando",
+    "montar",
+    "montaña",
+    "montañas",
+    "montaño",
+    "monte",
+    "monteleone",
+    "montemayor",
+    "montemorelos",
+    "monten",
+    "montepíos",
+    "montera",
+    "montero",
+    "monterrey",
+    "monterreynuevo",
+    "monterrubio",
+    "montes",
+    "montgomery",
+    "montiel",
+    "monto",
+    "montos",
+    "montoya",
+    "montreal",
+    "montura",
+    "montículo",
+    "montó",
+    "montón",
+    "monumental",
+    "monumentalidad",
+    "monumento",
+    "monumentos",
+    "monárquica",
+    "monóxido",
+    "moodys",
+    "mor",
+    "mora",
+    "morada",
+    "morado",
+    "moraes",
+    "moral",
+    "morales",
+    "moralidad",
+    "moralistas",
+    "moralización",
+    "moralizarse",
+    "moralmente",
+    "moranchel",
+    "moratoria",
+    "morbilidad",
+    "morbosa",
+    "morbosos",
+    "mordida",
+    "mordidas",
+    "mordiendo",
+    "mordiscos",
+    "morelenses",
+    "morelia",
+    "morell",
+    "morelos",
+    "morena",
+    "morenas",
+    "morenitos",
+    "moreno",
+    "moresa",
+    "morgan",
+    "morillo",
+    "morir",
+    "morirse",
+    "morones",
+    "moroso",
+    "morozoff",
+    "morris",
+    "morrison",
+    "morros",
+    "mortal",
+    "mortem",
+    "moruecos",
+    "moría",
+    "morín",
+    "morón",
+    "mos",
+    "moschino",
+    "moscovita",
+    "moscu",
+    "moscú",
+    "mosquito",
+    "mostaza",
+    "mostraba",
+    "mostraban",
+    "mostrada",
+    "mostrado",
+    "mostrando",
+    "mostrar",
+    "mostrarle",
+    "mostrarles",
+    "mostrarlo",
+    "mostraron",
+    "mostrarse",
+    "mostrará",
+    "mostrándome",
+    "mostrándose",
+    "mostró",
+    "mota",
+    "moteles",
+    "motiva",
+    "motivacional",
+    "motivacionales",
+    "motivaciones",
+    "motivación",
+    "motivado",
+    "motivador",
+    "motivante",
+    "motivar",
+    "motivarlas",
+    "motivarlo",
+    "motivarlos",
+    "motivarán",
+    "motive",
+    "motivo",
+    "motivos",
+    "motivó",
+    "moto",
+    "motocicleta",
+    "motociclismo",
+    "motociclista",
+    "motociclistas",
+    "motocross",
+    "motolinía",
+    "motor",
+    "motorenwerke",
+    "motores",
+    "motors",
+    "motos",
+    "motriz",
+    "mountain",
+    "moussavi",
+    "movamos",
+    "mover",
+    "movernos",
+    "moverse",
+    "moveré",
+    "movida",
+    "movido",
+    "moviendo",
+    "movieron",
+    "movilidad",
+    "moviliza",
+    "movilizaciones",
+    "movilización",
+    "movilizar",
+    "movilizó",
+    "movimiento",
+    "movimientos",
+    "moviéndose",
+    "movió",
+    "movían",
+    "moya",
+    "moyobamba",
+    "moyssén",
+    "moza",
+    "mozart",
+    "mozo",
+    "mozos",
+    "moño",
+    "mr",
+    "mstislav",
+    "mtito",
+    "mtley",
+    "mu",
+    "mucha",
+    "muchacha",
+    "muchachas",
+    "muchacho",
+    "muchachos",
+    "muchas",
+    "muchedumbre",
+    "mucho",
+    "muchos",
+    "muchísima",
+    "muchísimas",
+    "muchísimo",
+    "muchísimos",
+    "muda",
+    "mudanza",
+    "mudarse",
+    "mudará",
+    "mueble",
+    "muebles",
+    "mueca",
+    "muelle",
+    "muelles",
+    "muera",
+    "muere",
+    "muerta",
+    "muertas",
+    "muerte",
+    "muertes",
+    "muerto",
+    "muertos",
+    "muestra",
+    "muestran",
+    "muestrario",
+    "muestras",
+    "muestre",
+    "muestren",
+    "muestreo",
+    "mueve",
+    "mueven",
+    "mugre",
+    "muguerza",
+    "muguiro",
+    "mujer",
+    "mujercita",
+    "mujeres",
+    "mula",
+    "mulato",
+    "muleta",
+    "muletas",
+    "muletazos",
+    "mulino",
+    "mulitas",
+    "muller",
+    "multa",
+    "multadas",
+    "multado",
+    "multados",
+    "multar",
+    "multas",
+    "multichip",
+    "multicolor",
+    "multiculturalismo",
+    "multilateral",
+    "multilaterales",
+    "multimedios",
+    "multimillonariamente",
+    "multimillonario",
+    "multimillonarios",
+    "multimodal",
+    "multinacionales",
+    "multipanel",
+    "multiplicación",
+    "multiplicado",
+    "multiplicar",
+    "multiplicaron",
+    "multiplicarse",
+    "multiplicó",
+    "multipliquen",
+    "multitarea",
+    "multitud",
+    "multitudes",
+    "multitudinaria",
+    "mundanales",
+    "mundial",
+    "mundiales",
+    "mundialista",
+    "mundialmente",
+    "mundillo",
+    "mundo",
+    "mundos",
+    "mundy",
+    "munguía",
+    "munich",
+    "municipal",
+    "municipales",
+    "municipio",
+    "municipios",
+    "munícipe",
+    "mural",
+    "muralismo",
+    "murat",
+    "muriendo",
+    "muriera",
+    "murieron",
+    "murillo",
+    "murió",
+    "muro",
+    "muros",
+    "muscular",
+    "musculares",
+    "museo",
+    "museografía",
+    "museos",
+    "museum",
+    "museógrafo",
+    "musica",
+    "musical",
+    "musicales",
+    "muslo",
+    "muslos",
+    "mussa",
+    "musset",
+    "mustang",
+    "musulmana",
+    "musulmanas",
+    "musulmanes",
+    "musulmán",
+    "mutación",
+    "mutilados",
+    "mutua",
+    "mutualidad",
+    "mutualista",
+    "mutuamente",
+    "mutuas",
+    "mutuo",
+    "muy",
+    "muérdago",
+    "muñeca",
+    "muñiz",
+    "muñoz",
+    "myers",
+    "mystic",
+    "mágica",
+    "mágicas",
+    "mágico",
+    "mánager",
+    "máquina",
+    "máquinas",
+    "márgen",
+    "mármol",
+    "márquez",
+    "mártires",
+    "más",
+    "máscara",
+    "máscaras",
+    "mátar",
+    "máxima",
+    "máxime",
+    "máximo",
+    "máximos",
+    "médica",
+    "médicas",
+    "médico",
+    "médicos",
+    "médula",
+    "ménagier",
+    "méndez",
+    "mérida",
+    "mérito",
+    "méritos",
+    "método",
+    "métodos",
+    "métricas",
+    "méxico",
+    "méxicoestadounidense",
+    "méxiconorteamericanos",
+    "méxicoquerétaro",
+    "mí",
+    "mía",
+    "mías",
+    "mímica",
+    "mínima",
+    "mínimas",
+    "mínimo",
+    "mínimos",
+    "mío",
+    "míos",
+    "mírale",
+    "místico",
+    "mítica",
+    "mítico",
+    "módem",
+    "módulo",
+    "módulos",
+    "mónica",
+    "móvil",
+    "móviles",
+    "múltiple",
+    "múltiples",
+    "múltiplos",
+    "músculo",
+    "música",
+    "músico",
+    "músicos",
+    "múzquiz",
+    "n",
+    "na",
+    "nabor",
+    "nace",
+    "nacen",
+    "nacer",
+    "nacho",
+    "nacida",
+    "nacido",
+    "nacidos",
+    "naciera",
+    "nacieran",
+    "nacieron",
+    "nacimiento",
+    "nacimos",
+    "nacional",
+    "nacionales",
+    "nacionalidad",
+    "nacionalista",
+    "nacionalización",
+    "nacionalizada",
+    "naciones",
+    "nació",
+    "nación",
+    "nací",
+    "nada",
+    "nadador",
+    "nadadores",
+    "nadando",
+    "nadar",
+    "nadie",
+    "nadores",
+    "nafin",
+    "nafinsa",
+    "nafta",
+    "naftacorporation",
+    "nagoya",
+    "nahuas",
+    "nailon",
+    "nairobi",
+    "naju",
+    "nancy",
+    "nandin",
+    "nandín",
+    "napoleón",
+    "naranja",
+    "naranjas",
+    "naranjo",
+    "narco",
+    "narcoanálisis",
+    "narcos",
+    "narcotraficante",
+    "narcotraficantes",
+    "narcotráfico",
+    "nariz",
+    "narozhilenko",
+    "narra",
+    "narrado",
+    "narrador",
+    "narran",
+    "narrativa",
+    "narrativo",
+    "narró",
+    "nasa",
+    "nasal",
+    "nasales",
+    "nat",
+    "nata",
+    "natación",
+    "natal",
+    "natalicio",
+    "natalie",
+    "natalio",
+    "nate",
+    "nativa",
+    "nativas",
+    "natividad",
+    "nativo",
+    "nativos",
+    "nato",
+    "naturales",
+    "naturaleza",
+    "naturalmente",
+    "naturista",
+    "naucalpan",
+    "naufragaron",
+    "naufragio",
+    "naufragios",
+    "naufragó",
+    "nava",
+    "navaja",
+    "navajas",
+    "navajazo",
+    "navajearon",
+    "naval",
+    "navales",
+    "navarrete",
+    "navarro",
+    "nave",
+    "naveda",
+    "navegación",
+    "navegar",
+    "naves",
+    "navidad",
+    "naviera",
+    "navieras",
+    "navieros",
+    "navismo",
+    "navista",
+    "navojoa",
+    "navío",
+    "navíos",
+    "nayarit",
+    "nazis",
+    "nbc",
+    "neblina",
+    "necaxa",
+    "necaxistas",
+    "necedad",
+    "necesaria",
+    "necesariamente",
+    "necesarias",
+    "necesario",
+    "necesarios",
+    "necesidad",
+    "necesidades",
+    "necesita",
+    "necesitaba",
+    "necesitaban",
+    "necesitado",
+    "necesitamos",
+    "necesitan",
+    "necesitarlas",
+    "necesitará",
+    "necesitarán",
+    "necesitaría",
+    "necesitarían",
+    "necesite",
+    "necesiten",
+    "necesito",
+    "necesitábamos",
+    "necesitó",
+    "necia",
+    "necrófilo",
+    "nedda",
+    "nefasto",
+    "nefrología",
+    "negaba",
+    "negaban",
+    "negada",
+    "negado",
+    "negando",
+    "negar",
+    "negara",
+    "negarla",
+    "negarle",
+    "negaron",
+    "negarse",
+    "negativa",
+    "negativamente",
+    "negativas",
+    "negativo",
+    "negativos",
+    "negligencia",
+    "negocia",
+    "negociables",
+    "negociaciones",
+    "negociación",
+    "negociada",
+    "negociado",
+    "negociador",
+    "negociadora",
+    "negociadores",
+    "negociando",
+    "negociantes",
+    "negociar",
+    "negociarlo",
+    "negociaron",
+    "negociarían",
+    "negociazo",
+    "negocio",
+    "negocios",
+    "negoció",
+    "negra",
+    "negras",
+    "negrete",
+    "negro",
+    "negromex",
+    "negroponte",
+    "negros",
+    "negó",
+    "nehru",
+    "neil",
+    "nejedly",
+    "nelson",
+    "neográfica",
+    "neoleonés",
+    "neoliberal",
+    "neoliberales",
+    "neoliberalismo",
+    "neoloneses",
+    "neomexicanismo",
+    "neopopulistas",
+    "neoproteccionista",
+    "neosectores",
+    "neoyorkina",
+    "neoyorkino",
+    "neoyorquina",
+    "neoyorquinas",
+    "neoyorquino",
+    "nepotismo",
+    "neptuno",
+    "nereus",
+    "neri",
+    "neruda",
+    "nerviosa",
+    "nerviosas",
+    "nerviosismo",
+    "nervioso",
+    "nerviosos",
+    "nestlé",
+    "nestor",
+    "neta",
+    "netamente",
+    "netas",
+    "neto",
+    "netos",
+    "neumología",
+    "neumonía",
+    "neurastenia",
+    "neurobiólogo",
+    "neurocirujanos",
+    "neurología",
+    "neurológicas",
+    "neurológico",
+    "neurona",
+    "neuronales",
+    "neuronas",
+    "neurosis",
+    "neutral",
+    "neutralizar",
+    "neutro",
+    "neutros",
+    "neuville",
+    "nevando",
+    "newagers",
+    "newagismo",
+    "newport",
+    "newquist",
+    "newsweek",
+    "newton",
+    "nexos",
+    "nextstep",
+    "ney",
+    "neza",
+    "neófito",
+    "neón",
+    "nfl",
+    "nhra",
+    "ni",
+    "nicaragua",
+    "nicaragüense",
+    "nicho",
+    "nichols",
+    "nichos",
+    "nickin",
+    "nicola",
+    "nicole",
+    "nicolás",
+    "nidas",
+    "nido",
+    "nidos",
+    "niebla",
+    "niega",
+    "niegan",
+    "niegue",
+    "nieguen",
+    "nieta",
+    "nietecita",
+    "nieto",
+    "nietos",
+    "nieve",
+    "nieves",
+    "nikko",
+    "nilda",
+    "nina",
+    "ninguna",
+    "ninguno",
+    "ningún",
+    "nipón",
+    "nironenko",
+    "nirvana",
+    "nissan",
+    "nissho",
+    "nitrógeno",
+    "nivea",
+    "nivel",
+    "nivelar",
+    "niveles",
+    "nixon",
+    "niña",
+    "niñas",
+    "niñez",
+    "niño",
+    "niños",
+    "nl",
+    "no",
+    "nobel",
+    "nobilísimo",
+    "nobleza",
+    "nocaut",
+    "nocauts",
+    "noche",
+    "nochebuena",
+    "noches",
+    "nociones",
+    "nocivas",
+    "nocivo",
+    "noción",
+    "nocturna",
+    "nocturnas",
+    "nocturno",
+    "nogalar",
+    "nogales",
+    "nolte",
+    "nombra",
+    "nombradas",
+    "nombrado",
+    "nombrados",
+    "nombramiento",
+    "nombramientos",
+    "nombrar",
+    "nombraria",
+    "nombrarlos",
+    "nombre",
+    "nombren",
+    "nombres",
+    "nombré",
+    "nombró",
+    "nominaciones",
+    "nominación",
+    "nominada",
+    "nominado",
+    "nominados",
+    "nominal",
+    "nominales",
+    "nominará",
+    "nomás",
+    "nonagenario",
+    "nopales",
+    "noquear",
+    "noqueará",
+    "nora",
+    "norah",
+    "noratlántico",
+    "norberta",
+    "norberto",
+    "norcarolinos",
+    "norcoreano",
+    "noreste",
+    "norestense",
+    "norias",
+    "noriega",
+    "norma",
+    "normales",
+    "normalidad",
+    "normalmente",
+    "normandía",
+    "normar",
+    "normas",
+    "normatividad",
+    "norme",
+    "noroccidental",
+    "norris",
+    "norte",
+    "norteamericana",
+    "norteamericanas",
+    "norteamericano",
+    "norteamericanos",
+    "norteamérica",
+    "norteña",
+    "norteñas",
+    "norteño",
+    "norteños",
+    "northern",
+    "norwick",
+    "nos",
+    "nosocomio",
+    "nosotras",
+    "nosotros",
+    "nostalgias",
+    "nota",
+    "notablemente",
+    "notables",
+    "notado",
+    "notamos",
+    "notar",
+    "notaria",
+    "notariado",
+    "notario",
+    "notaría",
+    "notas",
+    "noticia",
+    "noticias",
+    "noticiero",
+    "noticiosa",
+    "noticiosos",
+    "notificación",
+    "notificado",
+    "notificar",
+    "notificarle",
+    "notificaron",
+    "notificarán",
+    "notificó",
+    "notimex",
+    "notoria",
+    "notoriamente",
+    "notorias",
+    "notorio",
+    "notó",
+    "nova",
+    "novacek",
+    "novar",
+    "novatas",
+    "novatez",
+    "novato",
+    "novatos",
+    "novedad",
+    "novedades",
+    "novedosas",
+    "novedoso",
+    "novedosos",
+    "novel",
+    "novela",
+    "novelas",
+    "novelista",
+    "novell",
+    "novena",
+    "novenas",
+    "noveno",
+    "novia",
+    "novias",
+    "noviazgo",
+    "noviembre",
+    "novillada",
+    "novilladas",
+    "novillero",
+    "novilleros",
+    "novillo",
+    "novillos",
+    "novio",
+    "novios",
+    "novoa",
+    "novísimo",
+    "nox",
+    "noyola",
+    "noé",
+    "np",
+    "npp",
+    "nt",
+    "ntx",
+    "nube",
+    "nubla",
+    "nublados",
+    "nuca",
+    "nucleado",
+    "nuclear",
+    "nudillos",
+    "nudos",
+    "nueces",
+    "nuera",
+    "nuestra",
+    "nuestras",
+    "nuestro",
+    "nuestros",
+    "nueva",
+    "nuevamente",
+    "nuevas",
+    "nueve",
+    "nuevo",
+    "nuevoleonesa",
+    "nuevoleoneses",
+    "nuevoleonés",
+    "nuevos",
+    "nuff",
+    "nui",
+    "nula",
+    "nulas",
+    "nulo",
+    "numerales",
+    "numeralia",
+    "numeritos",
+    "numero",
+    "numeros",
+    "numerosa",
+    "numerosas",
+    "numerosos",
+    "numérica",
+    "nunca",
+    "nunciatura",
+    "nuncio",
+    "nunn",
+    "nupcial",
+    "nupcias",
+    "nuremberg",
+    "nurser",
+    "nutrición",
+    "nutrientes",
+    "nuñez",
+    "ny",
+    "nye",
+    "nylon",
+    "nyrup",
+    "nyt",
+    "nápoles",
+    "náufrago",
+    "náutico",
+    "náuticos",
+    "népotas",
+    "níquel",
+    "nísperos",
+    "nó",
+    "nómina",
+    "núcleo",
+    "núcleos",
+    "número",
+    "números",
+    "núñez",
+    "o",
+    "oajaca",
+    "oaxaca",
+    "oaxaqueñas",
+    "obdulia",
+    "obdulio",
+    "obedece",
+    "obedecen",
+    "obedecer",
+    "obedecerlo",
+    "obedeció",
+    "obedecía",
+    "obedecían",
+    "obediencia",
+    "obispado",
+    "obispo",
+    "obispos",
+    "objeciones",
+    "objeta",
+    "objetar",
+    "objetare",
+    "objetiva",
+    "objetividad",
+    "objetivo",
+    "objetivos",
+    "objeto",
+    "objetos",
+    "obliga",
+    "obligaba",
+    "obligaban",
+    "obligaciones",
+    "obligación",
+    "obligada",
+    "obligado",
+    "obligados",
+    "obligan",
+    "obligando",
+    "obligar",
+    "obligaron",
+    "obligará",
+    "obligaría",
+    "obligarían",
+    "obligatoria",
+    "obligatoriamente",
+    "obligatoriedad",
+    "obligatorio",
+    "obligatorios",
+    "obligo",
+    "obligue",
+    "obligándolo",
+    "obligó",
+    "oboe",
+    "oboístas",
+    "obra",
+    "obrador",
+    "obran",
+    "obrar",
+    "obras",
+    "obregón",
+    "obrera",
+    "obrero",
+    "obreros",
+    "obscena",
+    "obscenas",
+    "obscenos",
+    "obscurece",
+    "obscuro",
+    "obscuros",
+    "obsequian",
+    "obsequiar",
+    "obsequiarle",
+    "obsequiaron",
+    "obsequió",
+    "observa",
+    "observaba",
+    "observaban",
+    "observable",
+    "observaciones",
+    "observación",
+    "observada",
+    "observadas",
+    "observado",
+    "observador",
+    "observadora",
+    "observadores",
+    "observamos",
+    "observan",
+    "observancia",
+    "observando",
+    "observar",
+    "observaron",
+    "observo",
+    "observé",
+    "observó",
+    "obsesionado",
+    "obsesionarse",
+    "obsesione",
+    "obsesiones",
+    "obsesiva",
+    "obsesión",
+    "obsidiana",
+    "obsolescencia",
+    "obstaculiza",
+    "obstaculización",
+    "obstaculizado",
+    "obstante",
+    "obstinación",
+    "obstinado",
+    "obstine",
+    "obstrucción",
+    "obstáculo",
+    "obstáculos",
+    "obtención",
+    "obtendrá",
+    "obtendrán",
+    "obtener",
+    "obtenerla",
+    "obtenerlas",
+    "obtenerse",
+    "obtenga",
+    "obtengan",
+    "obtengo",
+    "obtenida",
+    "obtenidas",
+    "obtenido",
+    "obtenidos",
+    "obteniendo",
+    "obtenía",
+    "obtiene",
+    "obtienen",
+    "obtuviera",
+    "obtuvieron",
+    "obtuvimos",
+    "obtuvo",
+    "obuses",
+    "obvia",
+    "obviamente",
+    "obvio",
+    "oca",
+    "ocampo",
+    "ocasiona",
+    "ocasionadas",
+    "ocasionado",
+    "ocasionados",
+    "ocasionalmente",
+    "ocasionar",
+    "ocasionarle",
+    "ocasionaron",
+    "ocasionarse",
+    "ocasionará",
+    "ocasiones",
+    "ocasionó",
+    "ocasión",
+    "occidental",
+    "occidentales",
+    "occidente",
+    "occipital",
+    "occisa",
+    "occiso",
+    "occisos",
+    "oceano",
+    "oceanía",
+    "ocejo",
+    "oceransky",
+    "oceánico",
+    "ochenta",
+    "ochentas",
+    "ocho",
+    "ochoa",
+    "ochos",
+    "ochosi",
+    "ocio",
+    "ocremente",
+    "octava",
+    "octavio",
+    "octavo",
+    "octubre",
+    "oculta",
+    "ocultaba",
+    "ocultar",
+    "ocultaron",
+    "ocultas",
+    "oculten",
+    "oculto",
+    "ocultos",
+    "ocultó",
+    "ocupa",
+    "ocupaba",
+    "ocupaban",
+    "ocupacional",
+    "ocupación",
+    "ocupada",
+    "ocupado",
+    "ocupados",
+    "ocupan",
+    "ocupantes",
+    "ocupar",
+    "ocuparon",
+    "ocuparse",
+    "ocupen",
+    "ocupó",
+    "ocurra",
+    "ocurran",
+    "ocurre",
+    "ocurren",
+    "ocurrencia",
+    "ocurrida",
+    "ocurridas",
+    "ocurrido",
+    "ocurriendo",
+    "ocurrieron",
+    "ocurrir",
+    "ocurrirán",
+    "ocurrió",
+    "ocurría",
+    "océanica",
+    "océano",
+    "océanos",
+    "odio",
+    "odisea",
+    "odriozola",
+    "oea",
+    "oecd",
+    "oeste",
+    "ofelia",
+    "ofende",
+    "ofenden",
+    "ofender",
+    "ofenderá",
+    "ofendidas",
+    "ofendido",
+    "ofendió",
+    "ofensa",
+    "ofensiva",
+    "ofensivas",
+    "ofensivo",
+    "ofensivos",
+    "oferentes",
+    "oferta",
+    "ofertas",
+    "ofertó",
+    "officio",
+    "oficial",
+    "oficiales",
+    "oficialista",
+    "oficializar",
+    "oficializo",
+    "oficializó",
+    "oficialmente",
+    "oficialía",
+    "oficiaría",
+    "oficina",
+    "oficinas",
+    "oficinesco",
+    "oficio",
+    "oficiosas",
+    "ofrece",
+    "ofrecen",
+    "ofrecer",
+    "ofrecerla",
+    "ofrecerle",
+    "ofrecerles",
+    "ofrecerlo",
+    "ofrecerá",
+    "ofrecerán",
+    "ofreceré",
+    "ofrecería",
+    "ofrecida",
+    "ofrecidas",
+    "ofrecido",
+    "ofrecidos",
+    "ofreciendo",
+    "ofreciera",
+    "ofrecieran",
+    "ofrecieron",
+    "ofrecimiento",
+    "ofrecimientos",
+    "ofreció",
+    "ofrecía",
+    "ofrecían",
+    "ofrendas",
+    "ofrezca",
+    "ofrezcan",
+    "ofrezco",
+    "oftalmología",
+    "ohio",
+    "oiga",
+    "oigame",
+    "ojales",
+    "ojalá",
+    "ojeda",
+    "ojeras",
+    "ojo",
+    "ojos",
+    "ojuelos",
+    "oklahoma",
+    "ola",
+    "olacyr",
+    "olas",
+    "olaya",
+    "olea",
+    "oleaginoso",
+    "oleaginosos",
+    "oleaje",
+    "olga",
+    "oligopolio",
+    "olimpiadas",
+    "olimpo",
+    "olivares",
+    "olivas",
+    "oliver",
+    "olivera",
+    "oliveras",
+    "oliverio",
+    "olivia",
+    "olivier",
+    "olivo",
+    "ollas",
+    "olloqui",
+    "olmedo",
+    "olor",
+    "olores",
+    "olvera",
+    "olvida",
+    "olvidada",
+    "olvidadizo",
+    "olvidado",
+    "olvidamos",
+    "olvidan",
+    "olvidando",
+    "olvidar",
+    "olvidaron",
+    "olvido",
+    "olvidándose",
+    "olvidó",
+    "olvídese",
+    "olé",
+    "olímpica",
+    "olímpico",
+    "olímpicos",
+    "omar",
+    "omaña",
+    "omb",
+    "ominoso",
+    "omiso",
+    "omiten",
+    "omitir",
+    "omito",
+    "omnimax",
+    "omán",
+    "onceavo",
+    "oncena",
+    "onda",
+    "ondarza",
+    "ondas",
+    "onix",
+    "ontario",
+    "ontiveros",
+    "onu",
+    "onzas",
+    "opa",
+    "opcionado",
+    "opcional",
+    "opciones",
+    "opción",
+    "opera",
+    "operaba",
+    "operacionales",
+    "operaciones",
+    "operación",
+    "operada",
+    "operadas",
+    "operado",
+    "operador",
+    "operadora",
+    "operadoras",
+    "operadores",
+    "operados",
+    "operan",
+    "operando",
+    "operar",
+    "operarios",
+    "operará",
+    "operaría",
+    "operas",
+    "operativa",
+    "operativas",
+    "operativo",
+    "operativos",
+    "operen",
+    "opina",
+    "opinaban",
+    "opinar",
+    "opinas",
+    "opinen",
+    "opiniones",
+    "opinión",
+    "opiniónfinanciera",
+    "opino",
+    "opinó",
+    "opondrá",
+    "opone",
+    "oponen",
+    "oponentes",
+    "oponga",
+    "opongo",
+    "oponíamos",
+    "oportuna",
+    "oportunamente",
+    "oportunidad",
+    "oportunidades",
+    "oportuno",
+    "oposición",
+    "opositor",
+    "opositores",
+    "oprimir",
+    "opta",
+    "optaba",
+    "optar",
+    "optarse",
+    "optativo",
+    "optimismo",
+    "optimista",
+    "optimistas",
+    "optimización",
+    "optimizar",
+    "optó",
+    "opuesta",
+    "opuesto",
+    "opuestos",
+    "opusieron",
+    "oraciones",
+    "orador",
+    "oral",
+    "oralia",
+    "oralmente",
+    "oramos",
+    "orangutanes",
+    "orangután",
+    "oraría",
+    "orbe",
+    "orbitar",
+    "orco",
+    "ordaz",
+    "orden",
+    "ordena",
+    "ordenaban",
+    "ordenada",
+    "ordenadas",
+    "ordenado",
+    "ordenamos",
+    "ordenar",
+    "ordenaron",
+    "ordene",
+    "ordenen",
+    "ordenes",
+    "ordenó",
+    "ordeñarás",
+    "ordiales",
+    "ordinaria",
+    "ordinario",
+    "orduño",
+    "ordás",
+    "ordóñez",
+    "orea",
+    "oregón",
+    "oreja",
+    "orejas",
+    "orejeras",
+    "orfebres",
+    "organice",
+    "organismo",
+    "organismos",
+    "organiza",
+    "organizacional",
+    "organizaciones",
+    "organización",
+    "organizada",
+    "organizadas",
+    "organizado",
+    "organizador",
+    "organizadora",
+    "organizadoras",
+    "organizadores",
+    "organizados",
+    "organizamos",
+    "organizan",
+    "organizando",
+    "organizar",
+    "organizaron",
+    "organizarse",
+    "organizará",
+    "organizaría",
+    "organizativa",
+    "organizó",
+    "orgasmo",
+    "orgullo",
+    "orgullosa",
+    "orgullosamente",
+    "orgulloso",
+    "orgullosos",
+    "orgánicas",
+    "orgásmico",
+    "orgía",
+    "oriana",
+    "orienta",
+    "orientació",
+    "orientación",
+    "orientada",
+    "orientadas",
+    "orientado",
+    "orientadoras",
+    "orientadores",
+    "orientados",
+    "oriental",
+    "orientales",
+    "orientan",
+    "orientando",
+    "orientar",
+    "orientarte",
+    "oriente",
+    "origen",
+    "origina",
+    "originada",
+    "originadas",
+    "originado",
+    "originados",
+    "originales",
+    "originalidad",
+    "originalmente",
+    "originan",
+    "originando",
+    "originar",
+    "originario",
+    "originarios",
+    "originarla",
+    "originaron",
+    "originará",
+    "origine",
+    "originó",
+    "orilla",
+    "orillando",
+    "orillar",
+    "orillas",
+    "orilló",
+    "orina",
+    "orinar",
+    "orioles",
+    "orishas",
+    "orizaba",
+    "orlando",
+    "ornelas",
+    "ornellas",
+    "oro",
+    "oroinia",
+    "oropel",
+    "oropeza",
+    "orozco",
+    "orozquiana",
+    "orquesta",
+    "orquestas",
+    "orta",
+    "ortega",
+    "ortigoza",
+    "ortiz",
+    "ortodoxa",
+    "ortodoxas",
+    "ortodoxia",
+    "ortografía",
+    "ortopedia",
+    "ortíz",
+    "orven",
+    "orwell",
+    "orígenes",
+    "os",
+    "osados",
+    "osadía",
+    "osaka",
+    "osasuna",
+    "oscar",
+    "oscila",
+    "oscilaba",
+    "oscilaciones",
+    "oscilan",
+    "oscura",
+    "oscuras",
+    "oscurecer",
+    "oscuridad",
+    "oscuro",
+    "oscuros",
+    "osorio",
+    "osos",
+    "osservatore",
+    "ostensible",
+    "ostensiblemente",
+    "ostenta",
+    "ostentar",
+    "osuna",
+    "osvaldo",
+    "oswald",
+    "oswaldo",
+    "otan",
+    "ote",
+    "oteando",
+    "othon",
+    "othón",
+    "otila",
+    "otilia",
+    "otitis",
+    "otorga",
+    "otorgada",
+    "otorgadas",
+    "otorgado",
+    "otorgados",
+    "otorgamiento",
+    "otorgan",
+    "otorgando",
+    "otorgar",
+    "otorgaran",
+    "otorgarle",
+    "otorgarles",
+    "otorgarnos",
+    "otorgaron",
+    "otorgará",
+    "otorgarán",
+    "otorguen",
+    "otorgó",
+    "otoño",
+    "otra",
+    "otras",
+    "otro",
+    "otrora",
+    "otros",
+    "ottawa",
+    "ottey",
+    "otto",
+    "otáez",
+    "outs",
+    "ovacionada",
+    "ovacionado",
+    "ovacionados",
+    "ovacionan",
+    "ovacionarlo",
+    "ovaciones",
+    "ovacionó",
+    "ovación",
+    "oval",
+    "ovalle",
+    "ovejas",
+    "ovidio",
+    "oviedo",
+    "ovoide",
+    "ovulación",
+    "owen",
+    "oxford",
+    "oxida",
+    "oxidando",
+    "oxidar",
+    "oxxo",
+    "oxígeno",
+    "oyen",
+    "oyentes",
+    "oyeran",
+    "oyó",
+    "oziel",
+    "ozono",
+    "oído",
+    "oídos",
+    "oímos",
+    "oír",
+    "oñate",
+    "p",
+    "pa",
+    "pabellón",
+    "pabilos",
+    "pablo",
+    "pacheco",
+    "pacho",
+    "pachoneos",
+    "pachuca",
+    "paciencia",
+    "paciente",
+    "pacientes",
+    "pacifico",
+    "packaged",
+    "paco",
+    "pactada",
+    "pactarán",
+    "pacto",
+    "pactó",
+    "pacífica",
+    "pacífico",
+    "padece",
+    "padecemos",
+    "padecen",
+    "padecer",
+    "padeciendo",
+    "padecieron",
+    "padecimiento",
+    "padecimientos",
+    "padilla",
+    "padre",
+    "padrecito",
+    "padres",
+    "padrino",
+    "padrones",
+    "padró",
+    "padrón",
+    "pag",
+    "paga",
+    "pagaba",
+    "pagadas",
+    "pagado",
+    "pagados",
+    "pagamos",
+    "pagan",
+    "pagando",
+    "paganismo",
+    "pagano",
+    "paganos",
+    "pagar",
+    "pagara",
+    "pagaran",
+    "pagarla",
+    "pagarle",
+    "pagarnos",
+    "pagaron",
+    "pagarte",
+    "pagará",
+    "pagarán",
+    "pagarían",
+    "pago",
+    "pagos",
+    "pague",
+    "paguen",
+    "pagó",
+    "paisaje",
+    "paisajes",
+    "paisajista",
+    "paisajístico",
+    "paises",
+    "paja",
+    "pala",
+    "palabra",
+    "palabras",
+    "palabrería",
+    "palacetes",
+    "palacio",
+    "palacios",
+    "paladar",
+    "palanca",
+    "palapa",
+    "palas",
+    "palazuelos",
+    "palaú",
+    "palco",
+    "palcos",
+    "palencia",
+    "palenque",
+    "palenques",
+    "paleros",
+    "palestino",
+    "palestinos",
+    "palestra",
+    "paletones",
+    "palillo",
+    "paliza",
+    "palm",
+    "palma",
+    "palmas",
+    "palmeras",
+    "palmitos",
+    "palo",
+    "paloma",
+    "palomas",
+    "palomilla",
+    "palomitas",
+    "palomo",
+    "palos",
+    "palou",
+    "palpable",
+    "pam",
+    "pamela",
+    "pampas",
+    "pamplona",
+    "pan",
+    "pana",
+    "panacea",
+    "panadería",
+    "panamericana",
+    "panamericanos",
+    "panameño",
+    "panamá",
+    "pancarta",
+    "pancartas",
+    "pancho",
+    "pancracio",
+    "pancrasio",
+    "pandilla",
+    "pandillas",
+    "pandilleril",
+    "pandillerismo",
+    "pandillero",
+    "pandilleros",
+    "panecillos",
+    "panel",
+    "paneles",
+    "panes",
+    "paniagua",
+    "panista",
+    "panistas",
+    "panorama",
+    "panorámicas",
+    "panorámicos",
+    "panqué",
+    "pantalla",
+    "pantallas",
+    "pantalones",
+    "pantalón",
+    "panteones",
+    "panteras",
+    "panteón",
+    "pantorrilla",
+    "pantro",
+    "pants",
+    "panzas",
+    "paola",
+    "papa",
+    "papal",
+    "papalotla",
+    "papasquiaroa",
+    "papel",
+    "papelería",
+    "papelerías",
+    "papeles",
+    "papitas",
+    "papá",
+    "papás",
+    "paquete",
+    "paquetería",
+    "paquetes",
+    "paquiro",
+    "para",
+    "paraba",
+    "parabrisas",
+    "parabólicas",
+    "paracaídas",
+    "parada",
+    "paradas",
+    "parado",
+    "paradojas",
+    "parados",
+    "paradójicamente",
+    "paraestatal",
+    "parafinas",
+    "parafraseando",
+    "paraguay",
+    "paraguayo",
+    "paraje",
+    "paralela",
+    "paralelamente",
+    "paralelas",
+    "paralelismo",
+    "paralelo",
+    "paralelos",
+    "paralizan",
+    "paralizando",
+    "paralizante",
+    "paralizar",
+    "paralizaron",
+    "paralizó",
+    "paralítico",
+    "paran",
+    "parando",
+    "paraninfo",
+    "parar",
+    "parara",
+    "pararme",
+    "pararse",
+    "paraíso",
+    "parcial",
+    "parciales",
+    "parcialidad",
+    "parcialmente",
+    "parece",
+    "parecemos",
+    "parecen",
+    "parecer",
+    "parecerse",
+    "parecería",
+    "parecerían",
+    "parecida",
+    "parecidas",
+    "parecido",
+    "pareciendo",
+    "parecieron",
+    "pareciese",
+    "pareció",
+    "parecía",
+    "parecían",
+    "pared",
+    "paredes",
+    "pareja",
+    "parejas",
+    "parejos",
+    "paremos",
+    "pares",
+    "parezca",
+    "parga",
+    "pariente",
+    "parientes",
+    "parietal",
+    "paris",
+    "parisino",
+    "parkas",
+    "parker",
+    "parlamentaria",
+    "parlamentarias",
+    "parlamentario",
+    "parlamentarios",
+    "parlamento",
+    "parm",
+    "parmista",
+    "paro",
+    "parodiando",
+    "paros",
+    "parque",
+    "parquedad",
+    "parques",
+    "parquimetrero",
+    "parquímetro",
+    "parquímetros",
+    "parra",
+    "parranda",
+    "parricidas",
+    "parricidio",
+    "parrilla",
+    "parroquia",
+    "parroquiales",
+    "parroquiano",
+    "parroquias",
+    "parsons",
+    "parte",
+    "parteaguas",
+    "parten",
+    "partes",
+    "participa",
+    "participaba",
+    "participaban",
+    "participaciones",
+    "participación",
+    "participado",
+    "participamos",
+    "participan",
+    "participando",
+    "participante",
+    "participantes",
+    "participar",
+    "participara",
+    "participaron",
+    "participará",
+    "participarán",
+    "participarían",
+    "participativos",
+    "participe",
+    "participen",
+    "participó",
+    "particulares",
+    "particularmente",
+    "partida",
+    "partidaria",
+    "partidario",
+    "partidarios",
+    "partidas",
+    "partidazo",
+    "partidero",
+    "partidistas",
+    "partido",
+    "partidos",
+    "partiendo",
+    "partiera",
+    "partieron",
+    "partir",
+    "partirá",
+    "partirán",
+    "partió",
+    "parto",
+    "partos",
+    "parturienta",
+    "partícipes",
+    "partículas",
+    "parvada",
+    "paráfrasis",
+    "parálisis",
+    "parámetro",
+    "parámetros",
+    "parás",
+    "paréntesis",
+    "parís",
+    "paró",
+    "pasa",
+    "pasaba",
+    "pasaban",
+    "pasacassettes",
+    "pasada",
+    "pasadas",
+    "pasado",
+    "pasadores",
+    "pasados",
+    "pasaje",
+    "pasajera",
+    "pasajeros",
+    "pasajes",
+    "pasamayo",
+    "pasamos",
+    "pasan",
+    "pasando",
+    "pasante",
+    "pasaporte",
+    "pasar",
+    "pasara",
+    "pasarela",
+    "pasarelas",
+    "pasarle",
+    "pasarles",
+    "pasaron",
+    "pasarse",
+    "pasará",
+    "pasarán",
+    "pasaría",
+    "pasarían",
+    "pasatiempo",
+    "pasco",
+    "pascua",
+    "pascual",
+    "pascuense",
+    "pase",
+    "pasean",
+    "pasear",
+    "pasearon",
+    "pasen",
+    "paseo",
+    "paseos",
+    "pases",
+    "paseño",
+    "paseó",
+    "pasillo",
+    "pasillos",
+    "pasional",
+    "pasiones",
+    "pasiva",
+    "pasivo",
+    "pasivos",
+    "pasión",
+    "paso",
+    "pasos",
+    "pasquinal",
+    "passarella",
+    "pasta",
+    "pastillas",
+    "pasto",
+    "pastor",
+    "pastora",
+    "pastoral",
+    "pastoriza",
+    "pasé",
+    "pasó",
+    "pata",
+    "patadas",
+    "patas",
+    "pateadores",
+    "patearon",
+    "patentara",
+    "patentarlo",
+    "patente",
+    "patentes",
+    "paternal",
+    "paternalismo",
+    "paternalista",
+    "paternalistas",
+    "paternos",
+    "patinamos",
+    "patio",
+    "patito",
+    "patiñazo",
+    "patiño",
+    "pato",
+    "patológico",
+    "patos",
+    "patria",
+    "patriarca",
+    "patriarcas",
+    "patricia",
+    "patricio",
+    "patricios",
+    "patrick",
+    "patrimonial",
+    "patrimoniales",
+    "patrimonio",
+    "patrio",
+    "patrios",
+    "patriot",
+    "patrocinada",
+    "patrocinado",
+    "patrocinador",
+    "patrocinadores",
+    "patrocinio",
+    "patrona",
+    "patronal",
+    "patronales",
+    "patronato",
+    "patrones",
+    "patrulla",
+    "patrullas",
+    "patrulleros",
+    "patrón",
+    "patty",
+    "paty",
+    "patéticamente",
+    "patéticos",
+    "paulatina",
+    "paulatinamente",
+    "paulatino",
+    "paulina",
+    "paulino",
+    "paullada",
+    "paulo",
+    "pausa",
+    "pausada",
+    "pausadamente",
+    "pausas",
+    "pauta",
+    "pavimentación",
+    "pavimentada",
+    "pavimentado",
+    "pavimentados",
+    "pavimentar",
+    "pavimento",
+    "pavor",
+    "payasadas",
+    "payán",
+    "paz",
+    "país",
+    "países",
+    "pañales",
+    "pañuelos",
+    "paúl",
+    "pc",
+    "pclab",
+    "pdcc",
+    "pdm",
+    "peabody",
+    "peaje",
+    "pearl",
+    "peatones",
+    "pecado",
+    "pecador",
+    "pecadores",
+    "pecaminosa",
+    "pecará",
+    "pece",
+    "peces",
+    "pechera",
+    "pecho",
+    "pecina",
+    "pectoral",
+    "pectorales",
+    "peculiar",
+    "peculiares",
+    "peculiaridad",
+    "peculiaridades",
+    "pedagogía",
+    "pedagógica",
+    "pedagógicas",
+    "pedagógicos",
+    "pedalista",
+    "pedalistas",
+    "pedazos",
+    "pediatra",
+    "pediatría",
+    "pedida",
+    "pedido",
+    "pedidores",
+    "pedidos",
+    "pedimento",
+    "pedimentos",
+    "pedimos",
+    "pedir",
+    "pedirle",
+    "pedirles",
+    "pedirlo",
+    "pedirá",
+    "pedirán",
+    "pediré",
+    "pediría",
+    "pediátrica",
+    "pedomo",
+    "pedrada",
+    "pedradas",
+    "pedraza",
+    "pedreras",
+    "pedrería",
+    "pedro",
+    "pedrín",
+    "pedía",
+    "pedían",
+    "pega",
+    "pegada",
+    "pegado",
+    "pegamento",
+    "pegando",
+    "pegar",
+    "pegaran",
+    "pegarle",
+    "pegaron",
+    "pegote",
+    "pegotes",
+    "pegue",
+    "pegó",
+    "peinado",
+    "peinó",
+    "pelado",
+    "pelar",
+    "peldaños",
+    "pelea",
+    "peleado",
+    "pelean",
+    "peleando",
+    "pelear",
+    "pelearan",
+    "pelearon",
+    "peleará",
+    "peleas",
+    "peleen",
+    "peleles",
+    "peleo",
+    "peleó",
+    "pelicula",
+    "peligro",
+    "peligros",
+    "peligrosa",
+    "peligrosamente",
+    "peligrosas",
+    "peligroso",
+    "peligrosos",
+    "pelletier",
+    "pello",
+    "pelo",
+    "pelos",
+    "pelota",
+    "pelotero",
+    "peloteros",
+    "pelotón",
+    "peluche",
+    "peluquerías",
+    "peláez",
+    "pelé",
+    "película",
+    "películas",
+    "pemex",
+    "pena",
+    "penal",
+    "penales",
+    "penalidad",
+    "penalmente",
+    "penalty",
+    "penas",
+    "pendiente",
+    "pendientes",
+    "pendleton",
+    "penetracion",
+    "penetración",
+    "penetrados",
+    "penetran",
+    "penetrante",
+    "penetrar",
+    "peniche",
+    "peninsulares",
+    "penitenciarios",
+    "penn",
+    "pennsylvania",
+    "penoso",
+    "pensaba",
+    "pensabamos",
+    "pensaban",
+    "pensado",
+    "pensadores",
+    "pensamiento",
+    "pensamos",
+    "pensando",
+    "pensantes",
+    "pensar",
+    "pensarse",
+    "pensativa",
+    "pensemos",
+    "pensilvania",
+    "pensionados",
+    "pensiones",
+    "pensión",
+    "pensé",
+    "pensó",
+    "pentathlón",
+    "pentecostés",
+    "pentotal",
+    "pentágono",
+    "penurias",
+    "península",
+    "penúltimo",
+    "peor",
+    "peores",
+    "pepe",
+    "peppers",
+    "pequeña",
+    "pequeñas",
+    "pequeñita",
+    "pequeño",
+    "pequeños",
+    "pequeñísima",
+    "pequeñísimo",
+    "perales",
+    "peraza",
+    "percance",
+    "percances",
+    "percata",
+    "percatado",
+    "percatarse",
+    "percató",
+    "percepciones",
+    "percepción",
+    "perciban",
+    "percibe",
+    "perciben",
+    "percusiones",
+    "percusionistas",
+    "perdedores",
+    "perdemos",
+    "perder",
+    "perderla",
+    "perderlo",
+    "perderá",
+    "perderán",
+    "perderían",
+    "perdición",
+    "perdida",
+    "perdidas",
+    "perdido",
+    "perdidos",
+    "perdiendo",
+    "perdiera",
+    "perdieran",
+    "perdieron",
+    "perdió",
+    "perdomo",
+    "perdonarlos",
+    "perdones",
+    "perdí",
+    "perdón",
+    "perea",
+    "perecieron",
+    "pereda",
+    "peregrina",
+    "peregrinación",
+    "peregrino",
+    "perenne",
+    "pereyra",
+    "perez",
+    "perfeccionamiento",
+    "perfeccionar",
+    "perfeccionarlo",
+    "perfeccione",
+    "perfección",
+    "perfectamente",
+    "perfecto",
+    "perfectos",
+    "perfidiana",
+    "perfil",
+    "perfila",
+    "perfilaba",
+    "perfilan",
+    "perfiles",
+    "perfoliata",
+    "perforación",
+    "perforado",
+    "perforar",
+    "pergaminos",
+    "pericia",
+    "periciales",
+    "pericos",
+    "periférica",
+    "periféricas",
+    "periférico",
+    "periféricos",
+    "perigord",
+    "perillas",
+    "perimetral",
+    "periodismo",
+    "periodista",
+    "periodistas",
+    "periodo",
+    "periodos",
+    "periodística",
+    "periodísticas",
+    "periodístico",
+    "periódicamente",
+    "periódicas",
+    "periódico",
+    "periódicos",
+    "perjudica",
+    "perjudicada",
+    "perjudicado",
+    "perjudicados",
+    "perjudican",
+    "perjudicar",
+    "perjudicará",
+    "perjudicial",
+    "perjudicó",
+    "perjuicio",
+    "perjuicios",
+    "perla",
+    "perlas",
+    "permanece",
+    "permanecen",
+    "permanecer",
+    "permaneceremos",
+    "permanecerá",
+    "permanecerán",
+    "permanecido",
+    "permaneciendo",
+    "permanecieran",
+    "permanecieron",
+    "permaneció",
+    "permanecía",
+    "permanecían",
+    "permanencia",
+    "permanente",
+    "permanentemente",
+    "permanentes",
+    "permanezca",
+    "permanezcan",
+    "permisibles",
+    "permisionario",
+    "permisionarios",
+    "permiso",
+    "permisos",
+    "permita",
+    "permitan",
+    "permite",
+    "permiten",
+    "permitida",
+    "permitidas",
+    "permitido",
+    "permitiendo",
+    "permitiera",
+    "permitieron",
+    "permitir",
+    "permitirles",
+    "permitirse",
+    "permitirá",
+    "permitirán",
+    "permitiré",
+    "permitiría",
+    "permitirían",
+    "permitiéndole",
+    "permitió",
+    "permitía",
+    "permítame",
+    "pernada",
+    "perniciosas",
+    "pernicioso",
+    "pernoctó",
+    "pero",
+    "perpetra",
+    "perpetrada",
+    "perpetrados",
+    "perpetua",
+    "perpetuarse",
+    "perpetúan",
+    "perplejidad",
+    "perplejo",
+    "perredista",
+    "perredistas",
+    "perro",
+    "perros",
+    "perry",
+    "persecución",
+    "persecutoria",
+    "persecutorias",
+    "perseguida",
+    "perseguidor",
+    "perseguidores",
+    "perseguidos",
+    "perseguirlos",
+    "persiga",
+    "persiste",
+    "persisten",
+    "persistencia",
+    "persistente",
+    "persistir",
+    "persona",
+    "personaje",
+    "personajes",
+    "personales",
+    "personalidad",
+    "personalidades",
+    "personalizar",
+    "personalizarlo",
+    "personalmente",
+    "personas",
+    "personifica",
+    "personificará",
+    "perspectiva",
+    "perspectivas",
+    "perspicacia",
+    "persuadir",
+    "pertenece",
+    "pertenecen",
+    "pertenecer",
+    "perteneciendo",
+    "perteneciente",
+    "pertenecientes",
+    "pertenecieron",
+    "perteneció",
+    "pertenecía",
+    "pertenencias",
+    "pertinaces",
+    "pertinente",
+    "pertinentes",
+    "perturba",
+    "perturbación",
+    "perturbemos",
+    "perturbó",
+    "peruana",
+    "peruano",
+    "peruanos",
+    "perversas",
+    "perversiones",
+    "perversión",
+    "perversos",
+    "pervivencia",
+    "períodico",
+    "período",
+    "períodos",
+    "perú",
+    "pesa",
+    "pesadas",
+    "pesadez",
+    "pesadilla",
+    "pesado",
+    "pesados",
+    "pesaje",
+    "pesar",
+    "pesas",
+    "pesca",
+    "pescadores",
+    "pescados",
+    "pese",
+    "peseras",
+    "peseta",
+    "pesetas",
+    "pesimista",
+    "pesimistas",
+    "peso",
+    "pesos",
+    "pespuntes",
+    "pesqueira",
+    "pesqueros",
+    "pesquería",
+    "pesticida",
+    "pesticidas",
+    "pesó",
+    "petate",
+    "pete",
+    "peticiones",
+    "petición",
+    "petit",
+    "peto",
+    "petra",
+    "petrel",
+    "petrolera",
+    "petroleras",
+    "petrolero",
+    "petroleros",
+    "petrolizar",
+    "petroquímica",
+    "petroquímicas",
+    "petrov",
+    "petróleo",
+    "petróleos",
+    "peyote",
+    "peza",
+    "peña",
+    "peñascos",
+    "pfcrn",
+    "pfcyp",
+    "pfeiffer",
+    "pgjdf",
+    "pgr",
+    "ph",
+    "philip",
+    "philippe",
+    "phillips",
+    "philos",
+    "piales",
+    "pianista",
+    "piano",
+    "pib",
+    "picaba",
+    "picado",
+    "picadores",
+    "pican",
+    "picante",
+    "pichardo",
+    "pichichis",
+    "pico",
+    "picos",
+    "pictórica",
+    "pictóricas",
+    "pida",
+    "pidan",
+    "pidas",
+    "pide",
+    "piden",
+    "pidiendo",
+    "pidieron",
+    "pidiéndoles",
+    "pidió",
+    "pido",
+    "pie",
+    "piedad",
+    "piedra",
+    "piedras",
+    "piedrecita",
+    "piedritas",
+    "piel",
+    "pieles",
+    "pienda",
+    "piensa",
+    "piensan",
+    "piense",
+    "piensen",
+    "pienso",
+    "pierce",
+    "pierda",
+    "pierdan",
+    "pierde",
+    "pierden",
+    "pierdo",
+    "pierna",
+    "piernas",
+    "pierre",
+    "pies",
+    "pieza",
+    "piezas",
+    "pifia",
+    "pigmento",
+    "pigmentosa",
+    "pila",
+    "pilar",
+    "pilares",
+    "pilas",
+    "pillma",
+    "piloncillo",
+    "piloteando",
+    "piloteó",
+    "piloto",
+    "pilotos",
+    "pilón",
+    "pimentel",
+    "pinacoteca",
+    "pinal",
+    "pinchado",
+    "pinchazo",
+    "pinchó",
+    "pino",
+    "pinochet",
+    "pinos",
+    "pinta",
+    "pintaban",
+    "pintada",
+    "pintadas",
+    "pintado",
+    "pintados",
+    "pintan",
+    "pintando",
+    "pintar",
+    "pintarlas",
+    "pintarlo",
+    "pintaron",
+    "pintas",
+    "pintor",
+    "pintora",
+    "pintores",
+    "pintura",
+    "pinturas",
+    "pinzón",
+    "pionera",
+    "pionero",
+    "pioneros",
+    "pipa",
+    "piquetes",
+    "piramidal",
+    "pirata",
+    "piratas",
+    "pirate",
+    "pirateando",
+    "piretro",
+    "piri",
+    "piropeado",
+    "pirulina",
+    "pisaba",
+    "pisado",
+    "pisando",
+    "pisen",
+    "piso",
+    "pisos",
+    "pisoteaban",
+    "pisoteada",
+    "pisoteando",
+    "pisotear",
+    "pisoteen",
+    "pista",
+    "pistas",
+    "pistola",
+    "pistolas",
+    "pitas",
+    "pitcheado",
+    "pitcheo",
+    "pitcher",
+    "piti",
+    "pito",
+    "pitol",
+    "pitoncitos",
+    "pits",
+    "pittsburgh",
+    "pitufos",
+    "pivote",
+    "pizarro",
+    "pizarrón",
+    "pizpireta",
+    "pizuto",
+    "pizza",
+    "pizzas",
+    "piña",
+    "piñaolayas",
+    "piñas",
+    "pjf",
+    "placa",
+    "placas",
+    "placer",
+    "placet",
+    "placita",
+    "plaga",
+    "plagadas",
+    "plagas",
+    "plagiada",
+    "plaguicidas",
+    "plainfield",
+    "plana",
+    "planas",
+    "planauto",
+    "planchar",
+    "planchará",
+    "planchas",
+    "planea",
+    "planeaban",
+    "planeación",
+    "planeada",
+    "planeadas",
+    "planeado",
+    "planeados",
+    "planean",
+    "planeando",
+    "planear",
+    "planee",
+    "planes",
+    "planeta",
+    "planetaria",
+    "planificación",
+    "planilla",
+    "planillas",
+    "planiria",
+    "plano",
+    "planos",
+    "planta",
+    "plantaciones",
+    "plantado",
+    "plantados",
+    "plantara",
+    "plantaron",
+    "plantas",
+    "plantea",
+    "planteada",
+    "planteadas",
+    "planteado",
+    "planteados",
+    "planteamientos",
+    "planteamos",
+    "plantean",
+    "plantear",
+    "plantearan",
+    "plantearon",
+    "plantearse",
+    "plantee",
+    "planteen",
+    "plantel",
+    "planteles",
+    "planteó",
+    "plantones",
+    "plantéeles",
+    "plantón",
+    "plascencia",
+    "plasma",
+    "plasmado",
+    "plasmar",
+    "plasme",
+    "plasmó",
+    "plastas",
+    "plastilina",
+    "plata",
+    "plataforma",
+    "plataformas",
+    "plataneras",
+    "platas",
+    "plate",
+    "plateadas",
+    "plateas",
+    "platería",
+    "platicaba",
+    "platicaban",
+    "platicado",
+    "platicamos",
+    "platicando",
+    "platicar",
+    "platicas",
+    "platicábamos",
+    "platicó",
+    "platillo",
+    "platillos",
+    "platinos",
+    "platique",
+    "plato",
+    "plausibles",
+    "playa",
+    "playas",
+    "playback",
+    "playera",
+    "playeras",
+    "playtex",
+    "plaza",
+    "plazas",
+    "plazo",
+    "plazos",
+    "plebiscito",
+    "plegables",
+    "pleitesía",
+    "pleito",
+    "pleitos",
+    "plena",
+    "plenamente",
+    "plenaria",
+    "pleno",
+    "plomería",
+    "plomo",
+    "pluma",
+    "plumas",
+    "plumones",
+    "plusmarca",
+    "plusmarcas",
+    "plusvalía",
+    "plutarco",
+    "pluvial",
+    "pluviales",
+    "plymouth",
+    "plácemes",
+    "plácida",
+    "plácidamente",
+    "plástica",
+    "plásticas",
+    "plástico",
+    "plásticos",
+    "plátanos",
+    "plática",
+    "pláticas",
+    "pn",
+    "po",
+    "poblacional",
+    "poblaciones",
+    "población",
+    "poblada",
+    "pobladas",
+    "poblado",
+    "pobladores",
+    "poblados",
+    "poblano",
+    "poblanos",
+    "poblaron",
+    "pobre",
+    "pobres",
+    "pobreza",
+    "poca",
+    "pocas",
+    "pocho",
+    "pochtecas",
+    "poco",
+    "pocos",
+    "poda",
+    "podamos",
+    "podemos",
+    "poder",
+    "poderes",
+    "poderlas",
+    "poderosa",
+    "poderoso",
+    "poderosos",
+    "poderío",
+    "podido",
+    "podremos",
+    "podrá",
+    "podrán",
+    "podrás",
+    "podré",
+    "podría",
+    "podríamos",
+    "podrían",
+    "podrías",
+    "podía",
+    "podíamos",
+    "podían",
+    "poema",
+    "poemario",
+    "poemas",
+    "poesía",
+    "poeta",
+    "poetisa",
+    "polanco",
+    "polar",
+    "polemizan",
+    "polen",
+    "poleo",
+    "policarpo",
+    "policia",
+    "policiaca",
+    "policiacas",
+    "policiaco",
+    "policiacos",
+    "policial",
+    "policiales",
+    "policias",
+    "policía",
+    "policíaca",
+    "policíacas",
+    "policíaco",
+    "policíacos",
+    "policías",
+    "polifonía",
+    "poligono",
+    "polilla",
+    "polinización",
+    "polinésico",
+    "polio",
+    "poliomielitis",
+    "politeísta",
+    "politeístas",
+    "politico",
+    "politiquero",
+    "politécnico",
+    "politólogos",
+    "poliuretano",
+    "polivalente",
+    "pollo",
+    "pollution",
+    "polo",
+    "polonia",
+    "polveras",
+    "polvo",
+    "polvos",
+    "polémica",
+    "polémicas",
+    "polígono",
+    "política",
+    "políticas",
+    "político",
+    "políticos",
+    "pomadas",
+    "pomares",
+    "pomperrier",
+    "pompeyo",
+    "pompidou",
+    "ponce",
+    "ponchando",
+    "ponche",
+    "pond",
+    "pondera",
+    "ponderada",
+    "pondremos",
+    "pondrá",
+    "pondrán",
+    "pondrás",
+    "pondrían",
+    "ponds",
+    "pone",
+    "ponemos",
+    "ponen",
+    "ponencia",
+    "ponencias",
+    "ponente",
+    "ponentes",
+    "poner",
+    "ponerla",
+    "ponerle",
+    "ponerles",
+    "ponerlo",
+    "ponerlos",
+    "ponernos",
+    "ponerse",
+    "pones",
+    "ponga",
+    "pongamos",
+    "pongan",
+    "pongo",
+    "poniatowska",
+    "poniendo",
+    "poniente",
+    "pons",
+    "pontiac",
+    "pontier",
+    "pontificado",
+    "pontificia",
+    "pontificio",
+    "pontífice",
+    "pony",
+    "ponía",
+    "popa",
+    "pope",
+    "popo",
+    "poppe",
+    "populares",
+    "popularidad",
+    "popularísimo",
+    "populismo",
+    "populista",
+    "populistas",
+    "populosos",
+    "poquitas",
+    "poquitito",
+    "poquito",
+    "poquísimo",
+    "por",
+    "porcelana",
+    "porcentaje",
+    "porcentajes",
+    "porcentual",
+    "porcentuales",
+    "porciles",
+    "porcioncita",
+    "porciones",
+    "porción",
+    "porcícolas",
+    "porfesión",
+    "porfiriato",
+    "porfirio",
+    "porfía",
+    "pormenores",
+    "pormenorizados",
+    "pornográficas",
+    "pornográficos",
+    "porque",
+    "porqué",
+    "porras",
+    "porros",
+    "porta",
+    "portaba",
+    "portaban",
+    "portabilidad",
+    "portación",
+    "portador",
+    "portafolio",
+    "portal",
+    "portan",
+    "portando",
+    "portar",
+    "portarlas",
+    "portavoz",
+    "porte",
+    "porten",
+    "porter",
+    "portero",
+    "portería",
+    "portezuela",
+    "portillo",
+    "portland",
+    "portuaria",
+    "portuarias",
+    "portuario",
+    "portuarios",
+    "portugal",
+    "portugués",
+    "portátil",
+    "portátiles",
+    "porvenir",
+    "posada",
+    "posee",
+    "poseedor",
+    "poseen",
+    "poseer",
+    "poseidón",
+    "poses",
+    "posesionar",
+    "posesionase",
+    "posesivas",
+    "posesión",
+    "poseían",
+    "posgrado",
+    "posgrados",
+    "posibilidad",
+    "posibilidades",
+    "posibilitan",
+    "posible",
+    "posiblemente",
+    "posibles",
+    "posicionado",
+    "posicionamiento",
+    "posiciones",
+    "posicionó",
+    "posición",
+    "positiva",
+    "positivamente",
+    "positivas",
+    "positivo",
+    "positivos",
+    "pospuesto",
+    "pospuso",
+    "postal",
+    "poste",
+    "postelectoral",
+    "postelectorales",
+    "postergación",
+    "posterior",
+    "posteriores",
+    "posteriormente",
+    "posters",
+    "postes",
+    "postguerra",
+    "postor",
+    "postores",
+    "postre",
+    "postulaban",
+    "postulación",
+    "postulada",
+    "postulado",
+    "postulados",
+    "postular",
+    "postularse",
+    "postuló",
+    "postura",
+    "posturas",
+    "potabilizadora",
+    "potabilizadoras",
+    "potable",
+    "potencia",
+    "potencial",
+    "potenciales",
+    "potencialmente",
+    "potente",
+    "potestad",
+    "potomac",
+    "potosi",
+    "potosino",
+    "potosí",
+    "potrillo",
+    "potrillos",
+    "potros",
+    "pottlesberghe",
+    "poul",
+    "powell",
+    "powerbook",
+    "powered",
+    "pozas",
+    "pozo",
+    "pozole",
+    "pozos",
+    "poético",
+    "ppc",
+    "pps",
+    "practica",
+    "practicaban",
+    "practicable",
+    "practicadas",
+    "practicamos",
+    "practican",
+    "practicar",
+    "practicaran",
+    "practicaron",
+    "practicas",
+    "practicó",
+    "practique",
+    "practiquen",
+    "praderas",
+    "prado",
+    "prampolini",
+    "praviel",
+    "praxedis",
+    "prd",
+    "prebendas",
+    "precandidato",
+    "precaria",
+    "precario",
+    "precaución",
+    "precautorio",
+    "precavido",
+    "precavidos",
+    "precedente",
+    "precedentes",
+    "precedidas",
+    "precepto",
+    "preceptos",
+    "preciados",
+    "precio",
+    "precios",
+    "preciosas",
+    "precioso",
+    "precipitaciones",
+    "precipitación",
+    "precipitadamente",
+    "precipitadas",
+    "precipitaron",
+    "precipitarse",
+    "precipitándose",
+    "precipitó",
+    "precisa",
+    "precisado",
+    "precisamente",
+    "precisan",
+    "precisando",
+    "precisar",
+    "precisaron",
+    "precisiones",
+    "precisión",
+    "preciso",
+    "precisos",
+    "precisó",
+    "preclaros",
+    "precolombina",
+    "preconiza",
+    "precursora",
+    "precursores",
+    "precísamente",
+    "predecir",
+    "predestinado",
+    "predeterminado",
+    "predial",
+    "predica",
+    "predicación",
+    "predicador",
+    "predicar",
+    "predicciones",
+    "predice",
+    "predijo",
+    "predio",
+    "predispone",
+    "predomina",
+    "predominan",
+    "predominante",
+    "predominantes",
+    "predominaron",
+    "preelectorales",
+    "preerupción",
+    "preescolar",
+    "preestablecido",
+    "preestablecidos",
+    "prefecto",
+    "preferencia",
+    "preferencial",
+    "preferencias",
+    "preferentemente",
+    "preferible",
+    "preferida",
+    "preferirán",
+    "preferiría",
+    "prefería",
+    "prefiera",
+    "prefieran",
+    "prefiere",
+    "prefieren",
+    "prefiero",
+    "prefijado",
+    "prefirieron",
+    "prefirió",
+    "pregrado",
+    "pregunta",
+    "preguntaba",
+    "preguntamos",
+    "preguntan",
+    "preguntando",
+    "preguntar",
+    "preguntarle",
+    "preguntarnos",
+    "preguntaron",
+    "preguntarse",
+    "preguntas",
+    "pregunte",
+    "pregunto",
+    "preguntó",
+    "pregúnteles",
+    "prehispánica",
+    "prehispánicas",
+    "prehispánico",
+    "prejuicios",
+    "prejunior",
+    "prelado",
+    "prelavado",
+    "preliminar",
+    "preliminares",
+    "prematuramente",
+    "prematuro",
+    "premestizo",
+    "premiaba",
+    "premiación",
+    "premiadas",
+    "premiado",
+    "premiados",
+    "premiaron",
+    "premie",
+    "premier",
+    "premio",
+    "premios",
+    "premisa",
+    "premió",
+    "premoderno",
+    "premonición",
+    "premundialistas",
+    "prenda",
+    "prendarios",
+    "prendas",
+    "prende",
+    "prendedores",
+    "prender",
+    "prendido",
+    "prendieron",
+    "prensa",
+    "prensado",
+    "preocupa",
+    "preocupaba",
+    "preocupaciones",
+    "preocupación",
+    "preocupada",
+    "preocupadas",
+    "preocupado",
+    "preocupados",
+    "preocupan",
+    "preocupar",
+    "preocupara",
+    "preocuparle",
+    "preocuparon",
+    "preocuparse",
+    "preocupará",
+    "preocuparía",
+    "preocupe",
+    "preocupen",
+    "preocupes",
+    "prepara",
+    "preparaba",
+    "preparaban",
+    "preparación",
+    "preparada",
+    "preparadas",
+    "preparado",
+    "preparados",
+    "preparamos",
+    "preparan",
+    "preparando",
+    "preparar",
+    "prepararla",
+    "prepararse",
+    "prepararán",
+    "preparativos",
+    "preparatoria",
+    "preparatorias",
+    "preparatorio",
+    "preparatorios",
+    "preparen",
+    "preparó",
+    "preponderantemente",
+    "prepotencia",
+    "prepotente",
+    "prepotentes",
+    "preproducción",
+    "prepárate",
+    "prepárele",
+    "prepárese",
+    "prerequisitos",
+    "prerregistro",
+    "prerrogativa",
+    "prerrogativas",
+    "presa",
+    "presas",
+    "prescindir",
+    "prescriben",
+    "prescritos",
+    "preseas",
+    "preselección",
+    "presencia",
+    "presenciado",
+    "presenciados",
+    "presencial",
+    "presenciar",
+    "presenciaron",
+    "presenció",
+    "presenta",
+    "presentaba",
+    "presentaban",
+    "presentaciones",
+    "presentación",
+    "presentada",
+    "presentadas",
+    "presentado",
+    "presentador",
+    "presentadores",
+    "presentados",
+    "presentamos",
+    "presentan",
+    "presentando",
+    "presentar",
+    "presentara",
+    "presentaremos",
+    "presentarla",
+    "presentarlo",
+    "presentarlos",
+    "presentarme",
+    "presentaron",
+    "presentarse",
+    "presentará",
+    "presentarán",
+    "presente",
+    "presenten",
+    "presentes",
+    "presentándolo",
+    "presentándose",
+    "presentáramos",
+    "presentí",
+    "presentó",
+    "preservación",
+    "preservado",
+    "preservar",
+    "preservarle",
+    "presida",
+    "preside",
+    "presiden",
+    "presidencia",
+    "presidenciable",
+    "presidenciables",
+    "presidencial",
+    "presidenciales",
+    "presidencialismo",
+    "presidencialista",
+    "presidenta",
+    "presidente",
+    "presidentes",
+    "presidida",
+    "presidido",
+    "presidir",
+    "presidirá",
+    "presidium",
+    "presidió",
+    "presionado",
+    "presionando",
+    "presionar",
+    "presionarlos",
+    "presionaron",
+    "presionará",
+    "presione",
+    "presiones",
+    "presión",
+    "presley",
+    "preso",
+    "presos",
+    "pressey",
+    "presta",
+    "prestaciones",
+    "prestación",
+    "prestada",
+    "prestado",
+    "prestadores",
+    "prestan",
+    "prestancia",
+    "prestar",
+    "prestaron",
+    "prestaría",
+    "preste",
+    "prestigiado",
+    "prestigian",
+    "prestigio",
+    "prestigiosos",
+    "prestó",
+    "presume",
+    "presumiblemente",
+    "presumida",
+    "presumieron",
+    "presumir",
+    "presunción",
+    "presunta",
+    "presuntamente",
+    "presuntas",
+    "presunto",
+    "presuntos",
+    "presuntuoso",
+    "presupuestadas",
+    "presupuestal",
+    "presupuestario",
+    "presupuesto",
+    "presurosamente",
+    "preta",
+    "pretel",
+    "pretemporada",
+    "pretenda",
+    "pretende",
+    "pretendemos",
+    "pretenden",
+    "pretender",
+    "pretendido",
+    "pretendiente",
+    "pretendieran",
+    "pretendieron",
+    "pretendió",
+    "pretendía",
+    "pretendían",
+    "pretensión",
+    "pretexto",
+    "prevalece",
+    "prevalecen",
+    "prevaleciendo",
+    "prevalecientes",
+    "prevalezca",
+    "preve",
+    "prevee",
+    "prevención",
+    "prevenida",
+    "prevenir",
+    "prevenirse",
+    "preventiva",
+    "preventivas",
+    "preventivo",
+    "prever",
+    "preverificación",
+    "previa",
+    "previamente",
+    "previas",
+    "previendo",
+    "previene",
+    "previno",
+    "previo",
+    "previos",
+    "previsible",
+    "previsión",
+    "prevista",
+    "previstas",
+    "previsto",
+    "previstos",
+    "previéndolo",
+    "previó",
+    "prevoisin",
+    "prevé",
+    "preámbulo",
+    "pri",
+    "prieto",
+    "prigione",
+    "prigobierno",
+    "prima",
+    "primaria",
+    "primarias",
+    "primario",
+    "primarios",
+    "primas",
+    "primates",
+    "primavera",
+    "primer",
+    "primera",
+    "primeramente",
+    "primeras",
+    "primeriza",
+    "primero",
+    "primeros",
+    "primex",
+    "primitivas",
+    "primitivismo",
+    "primitivo",
+    "primitivos",
+    "primo",
+    "primogénita",
+    "primogénito",
+    "primordial",
+    "primordiales",
+    "princesa",
+    "princesas",
+    "princeton",
+    "principales",
+    "principalmente",
+    "principesco",
+    "principiante",
+    "principio",
+    "principios",
+    "prioridad",
+    "prioridades",
+    "prioritaria",
+    "prioritario",
+    "prioritarios",
+    "prisa",
+    "prisionero",
+    "prisioneros",
+    "prisión",
+    "pristina",
+    "priva",
+    "privacidad",
+    "privada",
+    "privadas",
+    "privado",
+    "privados",
+    "privan",
+    "privar",
+    "privativa",
+    "privativo",
+    "privatizaciones",
+    "privatización",
+    "privatizado",
+    "privatizar",
+    "privilegiada",
+    "privilegiado",
+    "privilegio",
+    "privilegios",
+    "privó",
+    "priísmo",
+    "priísta",
+    "priístas",
+    "probabilidades",
+    "probable",
+    "probablemente",
+    "probables",
+    "probada",
+    "probado",
+    "probados",
+    "probar",
+    "probara",
+    "probarle",
+    "probarlo",
+    "probaron",
+    "probarse",
+    "problema",
+    "problemas",
+    "problemática",
+    "problemático",
+    "probursa",
+    "probó",
+    "proceda",
+    "procede",
+    "proceden",
+    "procedencia",
+    "procedente",
+    "procedentes",
+    "proceder",
+    "procederá",
+    "procedimentales",
+    "procedimiento",
+    "procedimientos",
+    "procentro",
+    "procesa",
+    "procesaba",
+    "procesada",
+    "procesado",
+    "procesador",
+    "procesadora",
+    "procesadores",
+    "procesados",
+    "procesal",
+    "procesales",
+    "procesamiento",
+    "procesando",
+    "proceso",
+    "procesos",
+    "proclama",
+    "proclamaciones",
+    "proclamado",
+    "proclamó",
+    "procreación",
+    "procura",
+    "procuración",
+    "procurador",
+    "procuradora",
+    "procuraduria",
+    "procuraduría",
+    "procuramos",
+    "procurar",
+    "procurarse",
+    "procurarán",
+    "procure",
+    "procuro",
+    "procuró",
+    "procéntrico",
+    "prodigiosa",
+    "producciones",
+    "producción",
+    "producen",
+    "producida",
+    "producidas",
+    "producido",
+    "producidos",
+    "produciendo",
+    "producir",
+    "producirse",
+    "producirá",
+    "producirían",
+    "productiva",
+    "productivas",
+    "productividad",
+    "productivo",
+    "productivos",
+    "producto",
+    "productor",
+    "productora",
+    "productoras",
+    "productores",
+    "productos",
+    "producían",
+    "produjera",
+    "produjeron",
+    "produjo",
+    "produzca",
+    "produzcan",
+    "proeza",
+    "profeco",
+    "profesa",
+    "profesan",
+    "profesional",
+    "profesionales",
+    "profesionalismo",
+    "profesionalización",
+    "profesionalmente",
+    "profesionista",
+    "profesionistas",
+    "profesión",
+    "profesor",
+    "profesora",
+    "profesorado",
+    "profesores",
+    "profeta",
+    "profetas",
+    "profr",
+    "profunda",
+    "profundamente",
+    "profundas",
+    "profundicen",
+    "profundidad",
+    "profundidades",
+    "profundizado",
+    "profundizamos",
+    "profundizar",
+    "profundizará",
+    "profundo",
+    "profundos",
+    "progenitora",
+    "programa",
+    "programación",
+    "programada",
+    "programadas",
+    "programado",
+    "programados",
+    "programan",
+    "programando",
+    "programar",
+    "programas",
+    "programático",
+    "programó",
+    "progresando",
+    "progresarán",
+    "progresista",
+    "progresivamente",
+    "progresivo",
+    "progreso",
+    "progresos",
+    "prohiba",
+    "prohiban",
+    "prohibiciones",
+    "prohibición",
+    "prohibida",
+    "prohibidas",
+    "prohibido",
+    "prohibidos",
+    "prohibieron",
+    "prohibir",
+    "prohibió",
+    "prohibía",
+    "prohíbe",
+    "proletaria",
+    "proliferación",
+    "proliferan",
+    "prolijas",
+    "prologan",
+    "prolongación",
+    "prolongada",
+    "prolongadas",
+    "prolongado",
+    "prolongan",
+    "prolongar",
+    "prolongarse",
+    "prolongó",
+    "prolíder",
+    "promediar",
+    "promedio",
+    "promesa",
+    "promesas",
+    "prometan",
+    "promete",
+    "prometedor",
+    "prometen",
+    "prometerle",
+    "prometerles",
+    "prometidas",
+    "prometido",
+    "prometieron",
+    "prometimos",
+    "prometió",
+    "prometo",
+    "promex",
+    "promiscuidad",
+    "promiscuo",
+    "promociona",
+    "promocionado",
+    "promocional",
+    "promocionales",
+    "promocionan",
+    "promocionando",
+    "promocionar",
+    "promocionarlo",
+    "promocionarse",
+    "promociones",
+    "promoción",
+    "promotor",
+    "promotora",
+    "promotoras",
+    "promotores",
+    "promovemos",
+    "promover",
+    "promoverá",
+    "promoverán",
+    "promovida",
+    "promovido",
+    "promovidos",
+    "promoviendo",
+    "promovieron",
+    "promovió",
+    "promovía",
+    "promueva",
+    "promueve",
+    "promueven",
+    "promulgación",
+    "promulgada",
+    "promvido",
+    "pronasol",
+    "pronostica",
+    "pronosticada",
+    "pronosticado",
+    "pronosticaron",
+    "pronostico",
+    "pronta",
+    "pronto",
+    "pronuciados",
+    "pronuncia",
+    "pronunciadas",
+    "pronunciamiento",
+    "pronunciamos",
+    "pronunciar",
+    "pronunciaron",
+    "pronuncie",
+    "pronunció",
+    "pronóstico",
+    "pronósticos",
+    "propagación",
+    "propaganda",
+    "propagandísticas",
+    "propagaron",
+    "propensas",
+    "propia",
+    "propiamente",
+    "propias",
+    "propiciado",
+    "propiciando",
+    "propiciar",
+    "propiciarse",
+    "propiciará",
+    "propicien",
+    "propicio",
+    "propició",
+    "propiedad",
+    "propiedades",
+    "propietaria",
+    "propietario",
+    "propietarios",
+    "propinarle",
+    "propinaron",
+    "propinas",
+    "propinándole",
+    "propinó",
+    "propio",
+    "propios",
+    "propone",
+    "proponemos",
+    "proponen",
+    "proponer",
+    "proponerle",
+    "proponernos",
+    "proponga",
+    "propongo",
+    "proponían",
+    "proporciona",
+    "proporcionaba",
+    "proporcionada",
+    "proporcionadas",
+    "proporcionado",
+    "proporcionados",
+    "proporcional",
+    "proporcionan",
+    "proporcionando",
+    "proporcionar",
+    "proporcionarle",
+    "proporcionarles",
+    "proporcionará",
+    "proporcionaría",
+    "proporcione",
+    "proporcionen",
+    "proporciones",
+    "proporcionó",
+    "proporción",
+    "proposición",
+    "propositivas",
+    "propuesta",
+    "propuestas",
+    "propuesto",
+    "propuestos",
+    "propulsor",
+    "propuse",
+    "propusieron",
+    "propuso",
+    "propósito",
+    "propósitos",
+    "prorrogada",
+    "prorrumpe",
+    "prosa",
+    "prose",
+    "proseguid",
+    "proseguir",
+    "proselitismo",
+    "prosiga",
+    "prosigue",
+    "prosiguió",
+    "prosinecky",
+    "prospectiva",
+    "prospecto",
+    "prospectos",
+    "prosperado",
+    "prosperan",
+    "prospere",
+    "prosperidades",
+    "protagonismo",
+    "protagonista",
+    "protagonistas",
+    "protagonizado",
+    "protagonizados",
+    "protagonizaron",
+    "protagonizará",
+    "protagonizó",
+    "protagónicos",
+    "proteccionistas",
+    "protección",
+    "protective",
+    "protecto",
+    "protector",
+    "protectora",
+    "protectorado",
+    "protectores",
+    "protege",
+    "protegen",
+    "proteger",
+    "protegerse",
+    "protegerán",
+    "protegerían",
+    "protegido",
+    "protegidos",
+    "protegieron",
+    "protegía",
+    "protein",
+    "proteja",
+    "protejan",
+    "protesta",
+    "protestada",
+    "protestan",
+    "protestantes",
+    "protestar",
+    "protestaron",
+    "protestas",
+    "proteste",
+    "protesten",
+    "protestó",
+    "proteína",
+    "proteínas",
+    "protocolo",
+    "prototipo",
+    "prototipos",
+    "provea",
+    "provecho",
+    "provee",
+    "proveedor",
+    "proveedores",
+    "proveeduría",
+    "proveer",
+    "proveniente",
+    "provenientes",
+    "provenía",
+    "proverbio",
+    "proviene",
+    "provienen",
+    "provincia",
+    "provincial",
+    "provinciales",
+    "provinciana",
+    "provincias",
+    "provisional",
+    "provisionales",
+    "provisiones",
+    "provoca",
+    "provocaba",
+    "provocación",
+    "provocado",
+    "provocador",
+    "provocados",
+    "provocan",
+    "provocando",
+    "provocar",
+    "provocaran",
+    "provocaron",
+    "provocará",
+    "provocarán",
+    "provocativa",
+    "provocó",
+    "provoque",
+    "provoquen",
+    "provotel",
+    "proyección",
+    "proyecta",
+    "proyectaba",
+    "proyectaban",
+    "proyectada",
+    "proyectado",
+    "proyectan",
+    "proyectar",
+    "proyectarse",
+    "proyectará",
+    "proyectil",
+    "proyectito",
+    "proyecto",
+    "proyectos",
+    "proyectó",
+    "prudente",
+    "prudentemente",
+    "prudentes",
+    "prueba",
+    "pruebas",
+    "pruebita",
+    "pruneda",
+    "prusia",
+    "prusiana",
+    "prusianos",
+    "práctica",
+    "prácticamente",
+    "prácticas",
+    "práctico",
+    "prácticos",
+    "préstamo",
+    "préstamos",
+    "pría",
+    "príncipe",
+    "príncipes",
+    "prófugo",
+    "prófugos",
+    "prólogo",
+    "prórroga",
+    "próxima",
+    "próximamente",
+    "próximas",
+    "próximo",
+    "próximos",
+    "ps",
+    "pseudolíderes",
+    "psicoanalista",
+    "psicoanalítica",
+    "psicoanalítico",
+    "psicología",
+    "psicológica",
+    "psicológicamente",
+    "psicológicas",
+    "psicológico",
+    "psicológicos",
+    "psicoterapia",
+    "psicoterapéutico",
+    "psicotrópicas",
+    "psicotrópicos",
+    "psicóloga",
+    "psicólogas",
+    "psicólogo",
+    "psique",
+    "psiquiátrico",
+    "psoriasis",
+    "psíquico",
+    "pte",
+    "publica",
+    "publicaciones",
+    "publicación",
+    "publicada",
+    "publicadas",
+    "publicado",
+    "publican",
+    "publicando",
+    "publicar",
+    "publicaron",
+    "publicará",
+    "publicidad",
+    "publicistas",
+    "publicitada",
+    "publicitaria",
+    "publicitario",
+    "publicó",
+    "publio",
+    "publique",
+    "puche",
+    "pude",
+    "pudiendo",
+    "pudiera",
+    "pudieran",
+    "pudieron",
+    "pudiese",
+    "pudimos",
+    "pudo",
+    "pudor",
+    "pue",
+    "puebla",
+    "pueblerina",
+    "pueblerino",
+    "pueblito",
+    "pueblo",
+    "pueblos",
+    "pueda",
+    "puedan",
+    "puede",
+    "pueden",
+    "puedes",
+    "puedo",
+    "puente",
+    "puentes",
+    "puerta",
+    "puertas",
+    "puerto",
+    "puertorriqueño",
+    "puertos",
+    "pues",
+    "puesta",
+    "puestas",
+    "puesteros",
+    "puesto",
+    "puestos",
+    "pugilismo",
+    "pugilista",
+    "pugilística",
+    "pugnan",
+    "pugnando",
+    "pugnará",
+    "pugnaré",
+    "pugnas",
+    "puig",
+    "pujante",
+    "pulcro",
+    "pulgadas",
+    "pulgar",
+    "pulido",
+    "pulimos",
+    "pulir",
+    "pulirlos",
+    "pulitzer",
+    "pulmonares",
+    "pulsar",
+    "pulso",
+    "pululan",
+    "pulverizado",
+    "pulverizadora",
+    "pulverizar",
+    "pulverizó",
+    "pum",
+    "pumas",
+    "pump",
+    "punk",
+    "punongbayan",
+    "punta",
+    "puntadón",
+    "puntapies",
+    "puntas",
+    "punteando",
+    "puntero",
+    "punteros",
+    "puntería",
+    "punterías",
+    "punteó",
+    "punto",
+    "puntos",
+    "puntual",
+    "puntualizaciones",
+    "puntualizó",
+    "puntualmente",
+    "punzo",
+    "punzocortantes",
+    "punzones",
+    "pupila",
+    "pupilas",
+    "pupilo",
+    "pura",
+    "puramente",
+    "puras",
+    "pureza",
+    "purga",
+    "purgar",
+    "puro",
+    "puros",
+    "purpúreos",
+    "purón",
+    "puse",
+    "pusiera",
+    "pusieran",
+    "pusieron",
+    "pusiese",
+    "pusilánime",
+    "pusimos",
+    "pusiéramos",
+    "puso",
+    "putz",
+    "puñado",
+    "puñaladas",
+    "puñales",
+    "puño",
+    "pvc",
+    "pyramid",
+    "páez",
+    "pág",
+    "página",
+    "páginas",
+    "pájaro",
+    "pájaros",
+    "pálido",
+    "pánico",
+    "párpados",
+    "párrafo",
+    "párroco",
+    "pásele",
+    "pérdida",
+    "pérdidas",
+    "pérez",
+    "pértiga",
+    "pésima",
+    "pésimo",
+    "píldora",
+    "píldoras",
+    "pío",
+    "pítcher",
+    "pókar",
+    "póker",
+    "póngase",
+    "póstumo",
+    "púber",
+    "pública",
+    "públicamente",
+    "públicas",
+    "público",
+    "públicos",
+    "púgil",
+    "q",
+    "qabrija",
+    "qi",
+    "que",
+    "quebec",
+    "quebradas",
+    "quebrado",
+    "quebrados",
+    "quebrantar",
+    "quebrar",
+    "quebraron",
+    "quebró",
+    "queda",
+    "quedaba",
+    "quedaban",
+    "quedado",
+    "quedamos",
+    "quedan",
+    "quedando",
+    "quedar",
+    "quedara",
+    "quedarnos",
+    "quedaron",
+    "quedarse",
+    "quedará",
+    "quedarán",
+    "quedaría",
+    "quedaríamos",
+    "quedas",
+    "quede",
+    "queden",
+    "quedes",
+    "quedándoles",
+    "quedó",
+    "quehacer",
+    "quehaceres",
+    "queja",
+    "quejaba",
+    "quejado",
+    "quejamos",
+    "quejan",
+    "quejando",
+    "quejaron",
+    "quejas",
+    "quejoso",
+    "quejosos",
+    "quejándose",
+    "quejó",
+    "quela",
+    "quelonio",
+    "quemacocos",
+    "quemado",
+    "quemaduras",
+    "quemas",
+    "quemó",
+    "querella",
+    "queremos",
+    "querendas",
+    "querer",
+    "quererlo",
+    "queretanos",
+    "queretaro",
+    "queridas",
+    "querido",
+    "queriendo",
+    "querrá",
+    "querrían",
+    "querétaro",
+    "querétaroi",
+    "quería",
+    "queríamos",
+    "querían",
+    "queso",
+    "quesos",
+    "quesque",
+    "quezada",
+    "quiebra",
+    "quien",
+    "quienes",
+    "quiera",
+    "quieran",
+    "quieras",
+    "quiere",
+    "quieren",
+    "quieres",
+    "quiero",
+    "quihubo",
+    "quijano",
+    "quijote",
+    "quince",
+    "quinceañero",
+    "quincena",
+    "quincenales",
+    "quinielas",
+    "quinta",
+    "quintana",
+    "quintanilla",
+    "quinteto",
+    "quintetos",
+    "quinto",
+    "quiroga",
+    "quiroz",
+    "quirúrgica",
+    "quirúrgicamente",
+    "quise",
+    "quisiera",
+    "quisieran",
+    "quisieron",
+    "quisiéramos",
+    "quiso",
+    "quisquillosos",
+    "quita",
+    "quitaba",
+    "quitadme",
+    "quitado",
+    "quitan",
+    "quitando",
+    "quitar",
+    "quitara",
+    "quitarle",
+    "quitarles",
+    "quitarnos",
+    "quitaron",
+    "quitarse",
+    "quitarían",
+    "quiten",
+    "quitos",
+    "quitándole",
+    "quitándolos",
+    "quitándose",
+    "quitó",
+    "quizá",
+    "quizás",
+    "quién",
+    "quiénes",
+    "quo",
+    "qué",
+    "química",
+    "químicas",
+    "químico",
+    "qwarge",
+    "r",
+    "rabatté",
+    "rabaté",
+    "rabia",
+    "rabiar",
+    "rabino",
+    "rabo",
+    "racha",
+    "rachas",
+    "racial",
+    "raciales",
+    "racimo",
+    "racing",
+    "raciocinios",
+    "radar",
+    "radares",
+    "radiaciones",
+    "radiación",
+    "radiador",
+    "radial",
+    "radiales",
+    "radiante",
+    "radiantes",
+    "radica",
+    "radicada",
+    "radicado",
+    "radical",
+    "radicalismo",
+    "radicalización",
+    "radicalmente",
+    "radicar",
+    "radio",
+    "radioactiva",
+    "radioactividad",
+    "radioescucha",
+    "radiofónica",
+    "radiofónico",
+    "radiografías",
+    "radiología",
+    "radios",
+    "radko",
+    "rads",
+    "rafael",
+    "rafi",
+    "raiz",
+    "rajado",
+    "rajko",
+    "rajmunel",
+    "rakenel",
+    "rama",
+    "ramana",
+    "ramas",
+    "ramificaciones",
+    "ramificado",
+    "ramiro",
+    "ramo",
+    "ramon",
+    "ramos",
+    "rampas",
+    "rams",
+    "ramírez",
+    "ramón",
+    "ranchera",
+    "rancheras",
+    "rancherita",
+    "rancho",
+    "rangel",
+    "rango",
+    "rangos",
+    "rano",
+    "ranqueadas",
+    "ranulfo",
+    "rap",
+    "rapa",
+    "rapamontes",
+    "rapar",
+    "raphael",
+    "rapidez",
+    "rapport",
+    "raquet",
+    "raquítica",
+    "raquítico",
+    "rara",
+    "raraku",
+    "raro",
+    "raros",
+    "rasca",
+    "rascacielos",
+    "rascón",
+    "rasero",
+    "rasgaría",
+    "rasgos",
+    "rasmussen",
+    "raspar",
+    "rastrearlas",
+    "rastro",
+    "rateros",
+    "ratificación",
+    "ratificado",
+    "ratificar",
+    "ratificará",
+    "ratifico",
+    "ratificó",
+    "rating",
+    "ratito",
+    "rato",
+    "raton",
+    "ratoncito",
+    "ratones",
+    "ratos",
+    "ratón",
+    "rauda",
+    "raulito",
+    "ravel",
+    "ravines",
+    "ray",
+    "raya",
+    "rayada",
+    "rayaditos",
+    "rayado",
+    "rayados",
+    "rayando",
+    "rayas",
+    "rayen",
+    "raymonde",
+    "raymundo",
+    "rayo",
+    "rayones",
+    "rayos",
+    "raytheon",
+    "rayón",
+    "raza",
+    "razonable",
+    "razonablemente",
+    "razonables",
+    "razonada",
+    "razonamiento",
+    "razonan",
+    "razones",
+    "razos",
+    "razón",
+    "raí",
+    "raíces",
+    "raíz",
+    "raúl",
+    "reabierta",
+    "reabra",
+    "reabrir",
+    "reabrirse",
+    "reacaudarían",
+    "reaccionar",
+    "reaccionaria",
+    "reaccionarios",
+    "reacciones",
+    "reaccionó",
+    "reacción",
+    "reacomodados",
+    "reactivar",
+    "reactivarán",
+    "reactor",
+    "readaptación",
+    "readecuación",
+    "reafirmar",
+    "reafirmaron",
+    "reafirmé",
+    "reafirmó",
+    "reagan",
+    "reajustados",
+    "reajustarlas",
+    "reajuste",
+    "realce",
+    "reales",
+    "realeza",
+    "realice",
+    "realicen",
+    "realicé",
+    "realidad",
+    "realidades",
+    "realismo",
+    "realista",
+    "realistas",
+    "realiza",
+    "realizaba",
+    "realizaban",
+    "realización",
+    "realizada",
+    "realizadas",
+    "realizado",
+    "realizador",
+    "realizados",
+    "realizamos",
+    "realizan",
+    "realizando",
+    "realizar",
+    "realizara",
+    "realizaremos",
+    "realizarlo",
+    "realizarnos",
+    "realizaron",
+    "realizarse",
+    "realizará",
+    "realizarán",
+    "realizarían",
+    "realizó",
+    "realmente",
+    "realziados",
+    "realísticamente",
+    "reanimar",
+    "reanudar",
+    "reanudarán",
+    "reanudándose",
+    "reanudó",
+    "reaparecer",
+    "reaparecerá",
+    "reapareció",
+    "reaparezca",
+    "reaparición",
+    "reaprehensión",
+    "reavalúo",
+    "rebajar",
+    "rebasa",
+    "rebasada",
+    "rebasado",
+    "rebasados",
+    "rebasan",
+    "rebasar",
+    "rebasaron",
+    "rebasará",
+    "rebasen",
+    "rebasó",
+    "rebaño",
+    "rebeca",
+    "rebelar",
+    "rebelde",
+    "rebeldes",
+    "rebelión",
+    "rebobinar",
+    "rebolledo",
+    "rebonato",
+    "rebordados",
+    "rebotará",
+    "rebotarán",
+    "rebote",
+    "recaba",
+    "recaban",
+    "recado",
+    "recados",
+    "recalcar",
+    "recalcitrante",
+    "recalcó",
+    "recalibren",
+    "recapacite",
+    "recapitalización",
+    "recargaron",
+    "recargó",
+    "recarpetear",
+    "recarpeteo",
+    "recaudaban",
+    "recaudando",
+    "recaudar",
+    "recaudaron",
+    "recaudarían",
+    "recaude",
+    "recaudó",
+    "rece",
+    "recelar",
+    "recelos",
+    "recepción",
+    "receptor",
+    "receptores",
+    "receptoría",
+    "recesiones",
+    "recesivo",
+    "recesión",
+    "receso",
+    "receta",
+    "rechace",
+    "rechaza",
+    "rechazada",
+    "rechazadas",
+    "rechazamos",
+    "rechazan",
+    "rechazando",
+    "rechazar",
+    "rechazaron",
+    "rechazo",
+    "rechazó",
+    "rechifla",
+    "rechiflas",
+    "rechinan",
+    "rechinar",
+    "reciba",
+    "reciban",
+    "recibe",
+    "reciben",
+    "recibida",
+    "recibidas",
+    "recibido",
+    "recibidos",
+    "recibiendo",
+    "recibiera",
+    "recibieron",
+    "recibimiento",
+    "recibimos",
+    "recibir",
+    "recibirla",
+    "recibirlas",
+    "recibirlo",
+    "recibirnos",
+    "recibirá",
+    "recibirán",
+    "recibiría",
+    "recibirían",
+    "recibió",
+    "recibo",
+    "recibos",
+    "recibía",
+    "recibían",
+    "reciclaje",
+    "reciclamos",
+    "reciclando",
+    "reciclar",
+    "reciente",
+    "recientemente",
+    "recientes",
+    "recientísima",
+    "recinto",
+    "recintos",
+    "recipiente",
+    "reciprocidad",
+    "recirculado",
+    "recircularon",
+    "recitar",
+    "recitarle",
+    "recitó",
+    "recién",
+    "reclama",
+    "reclamaba",
+    "reclamación",
+    "reclamadas",
+    "reclamados",
+    "reclaman",
+    "reclamando",
+    "reclamar",
+    "reclamara",
+    "reclamarle",
+    "reclamarles",
+    "reclamaron",
+    "reclamen",
+    "reclamos",
+    "reclamó",
+    "recluido",
+    "recluidos",
+    "reclusorio",
+    "reclusorios",
+    "reclutadas",
+    "recocido",
+    "recogen",
+    "recoger",
+    "recogerla",
+    "recogerle",
+    "recogida",
+    "recogidas",
+    "recogido",
+    "recogidos",
+    "recogieron",
+    "recogió",
+    "recolección",
+    "recolectando",
+    "recolector",
+    "recomedaciones",
+    "recomendaciones",
+    "recomendación",
+    "recomendadas",
+    "recomendado",
+    "recomendamos",
+    "recomendar",
+    "recomendara",
+    "recomendaron",
+    "recomendó",
+    "recomienda",
+    "recomiendan",
+    "recomiendo",
+    "recompensa",
+    "recompra",
+    "reconciliacion",
+    "reconciliación",
+    "reconcilió",
+    "reconducción",
+    "reconformación",
+    "reconfortante",
+    "reconoce",
+    "reconocemos",
+    "reconocer",
+    "reconocerlas",
+    "reconocerlos",
+    "reconocerse",
+    "reconocida",
+    "reconocidamente",
+    "reconocidas",
+    "reconocido",
+    "reconocidos",
+    "reconociendo",
+    "reconocieron",
+    "reconocimiento",
+    "reconocimientos",
+    "reconoció",
+    "reconocía",
+    "reconozca",
+    "reconozcan",
+    "reconozco",
+    "reconsidere",
+    "reconstrucción",
+    "reconstruir",
+    "reconstruirán",
+    "reconversión",
+    "recopa",
+    "recopila",
+    "recopilación",
+    "recopiló",
+    "recordaba",
+    "recordado",
+    "recordar",
+    "recordarán",
+    "recordemos",
+    "recordó",
+    "recorre",
+    "recorrer",
+    "recorrida",
+    "recorrido",
+    "recorridos",
+    "recorrieron",
+    "recorrió",
+    "recortar",
+    "recorte",
+    "recortes",
+    "recostado",
+    "recostó",
+    "recreación",
+    "recreará",
+    "recreativas",
+    "recreativo",
+    "recrimina",
+    "recriminaba",
+    "recriminados",
+    "recronometraron",
+    "recrudecer",
+    "recrudecieron",
+    "recrudeció",
+    "recta",
+    "rectas",
+    "rectificada",
+    "rectificar",
+    "rectitud",
+    "rector",
+    "rectoría",
+    "rectos",
+    "recuerda",
+    "recuerdan",
+    "recuerde",
+    "recuerden",
+    "recuerdo",
+    "recuerdos",
+    "recupera",
+    "recuperable",
+    "recuperación",
+    "recuperado",
+    "recuperando",
+    "recuperar",
+    "recuperarla",
+    "recuperaron",
+    "recuperarse",
+    "recupere",
+    "recurre",
+    "recurrido",
+    "recurrieron",
+    "recurrir",
+    "recurrirá",
+    "recurso",
+    "recursos",
+    "recuérdele",
+    "recámara",
+    "recíprocas",
+    "recíproco",
+    "redacción",
+    "redactado",
+    "redactaron",
+    "redada",
+    "redefinir",
+    "redes",
+    "redescuentos",
+    "redilas",
+    "rediseñar",
+    "rediseños",
+    "redistribución",
+    "redituable",
+    "reditúa",
+    "redobla",
+    "redoblado",
+    "redoblar",
+    "redonda",
+    "redondear",
+    "redondez",
+    "redondo",
+    "reduccion",
+    "reducciones",
+    "reducción",
+    "reducen",
+    "reducida",
+    "reducidas",
+    "reducido",
+    "reduciendo",
+    "reducir",
+    "reducirlas",
+    "reducirles",
+    "reducirse",
+    "reducirá",
+    "reducía",
+    "redujeron",
+    "redujo",
+    "redunde",
+    "reduzca",
+    "reduzcan",
+    "reed",
+    "reedición",
+    "reeditará",
+    "reelección",
+    "reelecto",
+    "reelegido",
+    "reelegirse",
+    "reemplaza",
+    "reemplazable",
+    "reemplazado",
+    "reemplazados",
+    "reemplazar",
+    "reemplazo",
+    "reencarnación",
+    "reencuentro",
+    "reestablecieron",
+    "reestrenan",
+    "reestreno",
+    "reestructuración",
+    "reestructurar",
+    "reestructurará",
+    "reestructure",
+    "reestudiarse",
+    "refaccionarias",
+    "refaccionarios",
+    "refacciones",
+    "refacción",
+    "referencia",
+    "referencias",
+    "referente",
+    "referentes",
+    "referidos",
+    "referirme",
+    "referirse",
+    "referéndum",
+    "referí",
+    "refería",
+    "referían",
+    "refiera",
+    "refiere",
+    "refieren",
+    "refiero",
+    "refinación",
+    "refinadora",
+    "refineria",
+    "refinerías",
+    "refiriéndose",
+    "refirió",
+    "reflectores",
+    "refleja",
+    "reflejaba",
+    "reflejaban",
+    "reflejada",
+    "reflejado",
+    "reflejan",
+    "reflejando",
+    "reflejar",
+    "reflejará",
+    "reflejo",
+    "reflejos",
+    "reflejó",
+    "reflexiona",
+    "reflexionar",
+    "reflexionen",
+    "reflexiones",
+    "reflexionó",
+    "reflexión",
+    "reforestarlas",
+    "reforma",
+    "reformado",
+    "reformar",
+    "reformas",
+    "reforme",
+    "reformó",
+    "reforzada",
+    "reforzadas",
+    "reforzado",
+    "reforzamiento",
+    "reforzando",
+    "reforzar",
+    "reforzarlas",
+    "reforzó",
+    "refractarias",
+    "refranes",
+    "refrenamiento",
+    "refrendaron",
+    "refrendo",
+    "refrendó",
+    "refresca",
+    "refrescos",
+    "refriegas",
+    "refrán",
+    "refuercen",
+    "refuerzan",
+    "refuerzo",
+    "refuerzos",
+    "refugiados",
+    "refugiar",
+    "refugiarse",
+    "refugio",
+    "refugió",
+    "regadas",
+    "regalado",
+    "regalan",
+    "regalando",
+    "regalar",
+    "regalarnos",
+    "regalo",
+    "regalos",
+    "regalárselo",
+    "regalárselos",
+    "regaló",
+    "regañadientes",
+    "regañan",
+    "regaños",
+    "regente",
+    "regia",
+    "regido",
+    "regidor",
+    "regidores",
+    "regina",
+    "regio",
+    "regiomontana",
+    "regiomontanas",
+    "regiomontano",
+    "regiomontanos",
+    "regional",
+    "regionales",
+    "regionalismo",
+    "regiones",
+    "regios",
+    "regiria",
+    "regirnos",
+    "regirse",
+    "registra",
+    "registraba",
+    "registrada",
+    "registradas",
+    "registrado",
+    "registrador",
+    "registrados",
+    "registran",
+    "registrandose",
+    "registrar",
+    "registraron",
+    "registrarse",
+    "registrará",
+    "registrarán",
+    "registre",
+    "registren",
+    "registro",
+    "registros",
+    "registrándose",
+    "registró",
+    "región",
+    "regla",
+    "reglamentación",
+    "reglamentar",
+    "reglamentarios",
+    "reglamente",
+    "reglamento",
+    "reglamentos",
+    "reglas",
+    "regresa",
+    "regresaba",
+    "regresaban",
+    "regresan",
+    "regresando",
+    "regresar",
+    "regresara",
+    "regresaran",
+    "regresaremos",
+    "regresaron",
+    "regresarse",
+    "regresará",
+    "regresarán",
+    "regresaría",
+    "regresas",
+    "regrese",
+    "regreselo",
+    "regresen",
+    "regreses",
+    "regresiva",
+    "regreso",
+    "regresó",
+    "regrésese",
+    "reguera",
+    "regula",
+    "regulaciones",
+    "regulación",
+    "regulador",
+    "reguladora",
+    "reguladores",
+    "regulares",
+    "regularización",
+    "regularizar",
+    "regularizará",
+    "regularizó",
+    "regularmente",
+    "regulatoria",
+    "regule",
+    "regímenes",
+    "rehabilitaciones",
+    "rehabilitación",
+    "rehabilitado",
+    "rehacer",
+    "rehenes",
+    "rehiletes",
+    "rehuir",
+    "rehusaban",
+    "rehusaron",
+    "rehén",
+    "reimplantó",
+    "reina",
+    "reinado",
+    "reinante",
+    "reincidan",
+    "reincidencia",
+    "reincorporará",
+    "reineros",
+    "reingresó",
+    "reiniciar",
+    "reiniciaron",
+    "reino",
+    "reinstalación",
+    "reintegración",
+    "reintegrar",
+    "reintegrarme",
+    "reinterpretar",
+    "reinvertirá",
+    "reiteración",
+    "reiteradamente",
+    "reiteraron",
+    "reiterará",
+    "reiteró",
+    "reivindicación",
+    "reivindicar",
+    "reja",
+    "rejas",
+    "rejoneador",
+    "rejones",
+    "rejuvenecedores",
+    "rejón",
+    "relaciona",
+    "relacionada",
+    "relacionadas",
+    "relacionado",
+    "relacionados",
+    "relacionaron",
+    "relacionarse",
+    "relaciones",
+    "relacionó",
+    "relación",
+    "relajado",
+    "relajarse",
+    "relampaguee",
+    "relamían",
+    "relanzamiento",
+    "relata",
+    "relatar",
+    "relativa",
+    "relativamente",
+    "relativas",
+    "relativo",
+    "relativos",
+    "relato",
+    "relatos",
+    "relató",
+    "relegada",
+    "relegado",
+    "relegue",
+    "relevado",
+    "relevancia",
+    "relevantes",
+    "relevar",
+    "relevista",
+    "relevo",
+    "relevos",
+    "relevó",
+    "relieve",
+    "relieves",
+    "religiones",
+    "religiosa",
+    "religiosamente",
+    "religiosas",
+    "religioso",
+    "religiosos",
+    "religión",
+    "rellenadas",
+    "rellenarán",
+    "relleno",
+    "reloj",
+    "relojito",
+    "reluciente",
+    "remache",
+    "remarcó",
+    "rematar",
+    "remate",
+    "remates",
+    "rembolso",
+    "remediada",
+    "remediar",
+    "remedio",
+    "remedios",
+    "remineralizante",
+    "reminiscencia",
+    "remite",
+    "remitente",
+    "remitidas",
+    "remitidos",
+    "remitieron",
+    "remodelación",
+    "remodelada",
+    "remolques",
+    "remontan",
+    "remota",
+    "remotamente",
+    "remotas",
+    "remoto",
+    "remotos",
+    "remover",
+    "removibles",
+    "removida",
+    "removidos",
+    "removió",
+    "remueve",
+    "remuneración",
+    "renace",
+    "renació",
+    "renales",
+    "renato",
+    "rencillas",
+    "renconformación",
+    "rencor",
+    "rencores",
+    "rencorosos",
+    "rendido",
+    "rendimiento",
+    "rendimientos",
+    "rendir",
+    "rendirse",
+    "rendía",
+    "rendón",
+    "renee",
+    "renegar",
+    "renegociación",
+    "renegociado",
+    "renegociar",
+    "renglones",
+    "renglón",
+    "renombradas",
+    "renombre",
+    "renovables",
+    "renovación",
+    "renovada",
+    "renovado",
+    "renovadores",
+    "renovados",
+    "renovando",
+    "renovar",
+    "renovarvía",
+    "renta",
+    "rentabilidad",
+    "rentable",
+    "rentables",
+    "rentada",
+    "rentadas",
+    "rentados",
+    "rentando",
+    "rentarlas",
+    "rentas",
+    "rentería",
+    "renueve",
+    "renuncia",
+    "renunciar",
+    "renunciara",
+    "renunciaron",
+    "renunciarán",
+    "renunciaría",
+    "renunció",
+    "rené",
+    "reo",
+    "reordenamiento",
+    "reordenar",
+    "reordene",
+    "reorganice",
+    "reorganización",
+    "reorganizar",
+    "reorganizarnos",
+    "reorganizarse",
+    "reos",
+    "reparaciones",
+    "reparación",
+    "reparadas",
+    "reparando",
+    "reparar",
+    "repararlas",
+    "reparos",
+    "reparte",
+    "repartido",
+    "repartidor",
+    "repartidos",
+    "repartiendo",
+    "repartieron",
+    "repartimos",
+    "repartir",
+    "repartirlos",
+    "repartirse",
+    "repartirán",
+    "reparto",
+    "repatriación",
+    "repatriados",
+    "repavimentación",
+    "repavimentar",
+    "repente",
+    "repentina",
+    "repentinamente",
+    "repercusión",
+    "repercuten",
+    "repertorio",
+    "repeticiones",
+    "repetición",
+    "repetidamente",
+    "repetidas",
+    "repetidoras",
+    "repetidísimas",
+    "repetimos",
+    "repetir",
+    "repetira",
+    "repetirse",
+    "repetirá",
+    "repetirán",
+    "repetiría",
+    "repita",
+    "repitan",
+    "repite",
+    "repitiéndome",
+    "repitió",
+    "repito",
+    "repleta",
+    "repleto",
+    "replica",
+    "replicar",
+    "replicarle",
+    "repollos",
+    "reponen",
+    "reponer",
+    "reporta",
+    "reportadas",
+    "reportado",
+    "reportados",
+    "reportaje",
+    "reportan",
+    "reportar",
+    "reportara",
+    "reportaron",
+    "reportarán",
+    "reporte",
+    "reportera",
+    "reportero",
+    "reporteros",
+    "reportes",
+    "reportos",
+    "reportó",
+    "reposo",
+    "reprendido",
+    "represalias",
+    "representa",
+    "representaba",
+    "representación",
+    "representada",
+    "representadas",
+    "representado",
+    "representan",
+    "representando",
+    "representante",
+    "representantes",
+    "representar",
+    "representará",
+    "representarán",
+    "representaría",
+    "representativas",
+    "representatividad",
+    "representativo",
+    "representativos",
+    "representen",
+    "representó",
+    "represivo",
+    "represivos",
+    "represión",
+    "represores",
+    "reprimir",
+    "reprobadas",
+    "reprobado",
+    "reprobaron",
+    "reprobó",
+    "reprocha",
+    "reproches",
+    "reprochó",
+    "reproducciones",
+    "reproducción",
+    "reproducen",
+    "reproducir",
+    "reproducirse",
+    "reproductor",
+    "reprodujo",
+    "reproduzca",
+    "reprueba",
+    "reprueban",
+    "reptiles",
+    "republicanas",
+    "republicano",
+    "repudiando",
+    "repudio",
+    "repuesto",
+    "repugnancia",
+    "repuntando",
+    "repunte",
+    "reputación",
+    "repórtese",
+    "república",
+    "repúblicanos",
+    "repúblicas",
+    "requerida",
+    "requerido",
+    "requeridos",
+    "requerimiento",
+    "requerimientos",
+    "requerimos",
+    "requerirá",
+    "requerirán",
+    "requería",
+    "requiera",
+    "requieran",
+    "requiere",
+    "requieren",
+    "requinto",
+    "requirieran",
+    "requirió",
+    "requisito",
+    "requisitos",
+    "resalta",
+    "resaltando",
+    "resaltar",
+    "resaltaron",
+    "resalte",
+    "resaltó",
+    "resarcirán",
+    "resbaladero",
+    "resbaladizo",
+    "resbalosas",
+    "rescata",
+    "rescatable",
+    "rescatada",
+    "rescatadas",
+    "rescatado",
+    "rescatados",
+    "rescatar",
+    "rescate",
+    "rescates",
+    "rescindirles",
+    "rescindirlo",
+    "rescindirse",
+    "reseca",
+    "resentidos",
+    "resentimos",
+    "reserva",
+    "reservaciones",
+    "reservadas",
+    "reservado",
+    "reservados",
+    "reservar",
+    "reservas",
+    "reseña",
+    "reseñas",
+    "resguardado",
+    "resguardando",
+    "resguardarlo",
+    "reside",
+    "residen",
+    "residencia",
+    "residencial",
+    "residenciales",
+    "residencias",
+    "residente",
+    "residentes",
+    "residió",
+    "residuales",
+    "residuos",
+    "resiente",
+    "resignación",
+    "resignada",
+    "resignado",
+    "resigno",
+    "resillas",
+    "resintiendo",
+    "resintiera",
+    "resiste",
+    "resistencia",
+    "resistente",
+    "resistentes",
+    "resistidas",
+    "resistiera",
+    "resistió",
+    "resolucion",
+    "resoluciones",
+    "resolución",
+    "resolver",
+    "resolverla",
+    "resolverlas",
+    "resolverlo",
+    "resolverse",
+    "resolverá",
+    "resolviendo",
+    "resolvieron",
+    "resolviéndole",
+    "resolvió",
+    "resonante",
+    "resortes",
+    "respalda",
+    "respaldaba",
+    "respaldada",
+    "respaldará",
+    "respaldo",
+    "respaldos",
+    "respaldó",
+    "respecta",
+    "respectiva",
+    "respectivamente",
+    "respectivas",
+    "respectivo",
+    "respectivos",
+    "respecto",
+    "respeta",
+    "respetaban",
+    "respetable",
+    "respetado",
+    "respetados",
+    "respetamos",
+    "respetan",
+    "respetando",
+    "respetar",
+    "respetaron",
+    "respetarse",
+    "respetará",
+    "respete",
+    "respeten",
+    "respeto",
+    "respetuosa",
+    "respetuoso",
+    "respiratoria",
+    "respiratorias",
+    "respiratorio",
+    "respiro",
+    "resplandecientes",
+    "resplandor",
+    "responda",
+    "respondan",
+    "responde",
+    "responden",
+    "responder",
+    "responderle",
+    "responderá",
+    "respondido",
+    "respondiera",
+    "respondió",
+    "respondí",
+    "responsabilice",
+    "responsabilidad",
+    "responsabilidades",
+    "responsabilizaba",
+    "responsabilizarlo",
+    "responsabilizaron",
+    "responsabilizó",
+    "responsable",
+    "responsables",
+    "respuesta",
+    "respuestas",
+    "resquicio",
+    "resta",
+    "restablecer",
+    "restablecerse",
+    "restablezcan",
+    "restan",
+    "restante",
+    "restantes",
+    "restar",
+    "restarle",
+    "restas",
+    "restauración",
+    "restaurant",
+    "restaurante",
+    "restaurantera",
+    "restaurantero",
+    "restauranteros",
+    "restaurantes",
+    "restaurarle",
+    "restitución",
+    "restituir",
+    "resto",
+    "restos",
+    "restregárselo",
+    "restricciones",
+    "restricción",
+    "restrictiva",
+    "restructuración",
+    "resuelta",
+    "resuelto",
+    "resueltos",
+    "resuelva",
+    "resuelve",
+    "resuelven",
+    "resulta",
+    "resultaba",
+    "resultado",
+    "resultados",
+    "resultan",
+    "resultando",
+    "resultante",
+    "resultar",
+    "resultara",
+    "resultaran",
+    "resultaron",
+    "resultaría",
+    "resulte",
+    "resulten",
+    "resulto",
+    "resultó",
+    "resumen",
+    "resumido",
+    "resumir",
+    "resumirse",
+    "resurgente",
+    "resurgir",
+    "resurja",
+    "resurrección",
+    "reséndez",
+    "retador",
+    "retardatario",
+    "retardo",
+    "retazos",
+    "retener",
+    "retengan",
+    "retenido",
+    "retenía",
+    "reticencia",
+    "reticencias",
+    "retiene",
+    "retinitis",
+    "retira",
+    "retiraba",
+    "retirada",
+    "retirado",
+    "retirados",
+    "retiramos",
+    "retirando",
+    "retirar",
+    "retirara",
+    "retirarlas",
+    "retiraron",
+    "retirarse",
+    "retirará",
+    "retirarán",
+    "retiraré",
+    "retiraría",
+    "retire",
+    "retiro",
+    "retiros",
+    "retirándose",
+    "retiró",
+    "reto",
+    "retomado",
+    "retomados",
+    "retomando",
+    "retomar",
+    "retomarla",
+    "retomarán",
+    "retomo",
+    "retomé",
+    "retomó",
+    "retorcerse",
+    "retorcidos",
+    "retornados",
+    "retornaría",
+    "retorno",
+    "retornó",
+    "retos",
+    "retracta",
+    "retractado",
+    "retractarán",
+    "retractaré",
+    "retrasada",
+    "retrasado",
+    "retrasan",
+    "retrasando",
+    "retrasar",
+    "retrasará",
+    "retrase",
+    "retrasen",
+    "retraso",
+    "retrasos",
+    "retrasó",
+    "retratado",
+    "retratar",
+    "retrataron",
+    "retratarse",
+    "retratará",
+    "retrato",
+    "retratos",
+    "retrató",
+    "retribuido",
+    "retribuye",
+    "retroactiva",
+    "retroactivo",
+    "retroalimentan",
+    "retroceder",
+    "retrocedido",
+    "retroceso",
+    "retrospección",
+    "retrospectiva",
+    "retrotraerlas",
+    "retrovisor",
+    "retuvieron",
+    "retuvo",
+    "retó",
+    "reubicación",
+    "reubicadas",
+    "reubicado",
+    "reubicados",
+    "reubicar",
+    "reubicarlos",
+    "reubicarse",
+    "reune",
+    "reunen",
+    "reunido",
+    "reunidos",
+    "reunieron",
+    "reunion",
+    "reuniones",
+    "reunir",
+    "reunirnos",
+    "reunirse",
+    "reunirá",
+    "reunirán",
+    "reunió",
+    "reunión",
+    "reunía",
+    "reunían",
+    "reuter",
+    "reuters",
+    "reutilización",
+    "revaloración",
+    "revalorarlo",
+    "revaluándose",
+    "revancha",
+    "revela",
+    "revelaba",
+    "revelaban",
+    "revelación",
+    "revelada",
+    "reveladas",
+    "revelado",
+    "revelador",
+    "reveladora",
+    "revelan",
+    "revelarla",
+    "revelaron",
+    "reveló",
+    "revendedores",
+    "reventa",
+    "reventado",
+    "reventadores",
+    "reventó",
+    "reventón",
+    "reverencia",
+    "reverencial",
+    "reverenciales",
+    "reverenciarlo",
+    "reverendo",
+    "reversa",
+    "revertido",
+    "revertir",
+    "reveses",
+    "revierten",
+    "revillagigedo",
+    "revisado",
+    "revisados",
+    "revisan",
+    "revisando",
+    "revisar",
+    "revisaran",
+    "revisarla",
+    "revisarlos",
+    "revisaron",
+    "revisarse",
+    "revisará",
+    "revisarán",
+    "revise",
+    "revisen",
+    "revisiones",
+    "revisión",
+    "reviso",
+    "revista",
+    "revistas",
+    "revitalizar",
+    "revivir",
+    "revocar",
+    "revocó",
+    "revolcón",
+    "revolucionaban",
+    "revolucionar",
+    "revolucionaria",
+    "revolucionarias",
+    "revolucionario",
+    "revolucionarios",
+    "revoluciones",
+    "revolucionó",
+    "revolución",
+    "revolufia",
+    "revolver",
+    "revolvió",
+    "revuelo",
+    "revuelta",
+    "revuelto",
+    "revuelva",
+    "revuelvo",
+    "revólver",
+    "revólveres",
+    "rey",
+    "reyes",
+    "reyhlander",
+    "reyna",
+    "reynaldo",
+    "reynard",
+    "reynolds",
+    "reynosa",
+    "reynoso",
+    "reza",
+    "rezagada",
+    "rezago",
+    "rezagos",
+    "rezaremos",
+    "rezarlo",
+    "rezarte",
+    "rezará",
+    "rezo",
+    "rezonificación",
+    "reía",
+    "reían",
+    "reír",
+    "reírnos",
+    "reírse",
+    "reñida",
+    "reñidas",
+    "reúna",
+    "reúnan",
+    "reúne",
+    "reúnen",
+    "rhon",
+    "riachuelo",
+    "riar",
+    "riazor",
+    "rib",
+    "ribadeneyra",
+    "ribbentrop",
+    "riberas",
+    "ribereña",
+    "ribereños",
+    "rica",
+    "ricacho",
+    "ricachón",
+    "ricardo",
+    "ricas",
+    "richard",
+    "richards",
+    "richardson",
+    "ricky",
+    "rico",
+    "ricos",
+    "ridiculiza",
+    "ridiculización",
+    "ridiculizado",
+    "ridiculizan",
+    "riegas",
+    "riego",
+    "rieles",
+    "riendas",
+    "rieron",
+    "ries",
+    "riesgo",
+    "riesgos",
+    "riesgosa",
+    "riesgosísimo",
+    "rifa",
+    "rifadas",
+    "rifar",
+    "rifas",
+    "rifkin",
+    "rige",
+    "rigen",
+    "rigidez",
+    "rigoberta",
+    "rigor",
+    "rigurosa",
+    "riguroso",
+    "rigurosos",
+    "rijan",
+    "rime",
+    "rinconada",
+    "rincón",
+    "rinden",
+    "rindiendo",
+    "rindiera",
+    "rindieran",
+    "rindieron",
+    "rindió",
+    "rines",
+    "rinker",
+    "rio",
+    "riojas",
+    "rippey",
+    "riqueza",
+    "riquezas",
+    "risa",
+    "risas",
+    "risca",
+    "ristra",
+    "ristre",
+    "rita",
+    "ritchie",
+    "riteaid",
+    "ritmo",
+    "ritmos",
+    "rito",
+    "ritual",
+    "riva",
+    "rivadeneira",
+    "rival",
+    "rivales",
+    "rivapalacio",
+    "rivera",
+    "rivero",
+    "riverside",
+    "riviello",
+    "rizado",
+    "rizzo",
+    "riña",
+    "riñas",
+    "riñen",
+    "riñones",
+    "rió",
+    "rkc",
+    "rke",
+    "rkl",
+    "robada",
+    "robadas",
+    "robado",
+    "robados",
+    "roban",
+    "robar",
+    "robarle",
+    "robe",
+    "roben",
+    "robert",
+    "roberta",
+    "roberto",
+    "roberts",
+    "roble",
+    "robledo",
+    "robles",
+    "robo",
+    "robocops",
+    "robos",
+    "robot",
+    "robotizar",
+    "robots",
+    "robusta",
+    "robustecer",
+    "robó",
+    "rocaetti",
+    "rocard",
+    "rocas",
+    "roce",
+    "roces",
+    "rocha",
+    "roche",
+    "rochester",
+    "rockanroleros",
+    "rockeras",
+    "rockport",
+    "rocoso",
+    "rocosos",
+    "rocío",
+    "rod",
+    "rodado",
+    "rodaje",
+    "rodal",
+    "rodarse",
+    "rodarte",
+    "rodea",
+    "rodeaba",
+    "rodeado",
+    "rodeados",
+    "rodean",
+    "rodear",
+    "rodearon",
+    "rodearse",
+    "rodeará",
+    "rodela",
+    "rodeo",
+    "rodeos",
+    "rodilla",
+    "rodillas",
+    "rodillera",
+    "rodion",
+    "rodisonato",
+    "rodney",
+    "rodolfo",
+    "rodrigo",
+    "rodríguez",
+    "roedor",
+    "roedores",
+    "roel",
+    "rogelio",
+    "roger",
+    "rogers",
+    "rogozinski",
+    "rogó",
+    "roja",
+    "rojas",
+    "rojiblanca",
+    "rojinegra",
+    "rojo",
+    "rojos",
+    "rol",
+    "rolada",
+    "rolando",
+    "roll",
+    "rollo",
+    "rolls",
+    "roma",
+    "romana",
+    "romance",
+    "romances",
+    "romano",
+    "romanticismo",
+    "romanticonas",
+    "romeo",
+    "romero",
+    "romilio",
+    "romo",
+    "rompe",
+    "rompebrechas",
+    "rompen",
+    "romper",
+    "romperian",
+    "romperles",
+    "romperlo",
+    "romperá",
+    "rompevientos",
+    "rompiendo",
+    "rompieron",
+    "rompimiento",
+    "rompió",
+    "román",
+    "románticas",
+    "románticos",
+    "ronald",
+    "roncaba",
+    "ronda",
+    "rondallas",
+    "rondando",
+    "ronzón",
+    "roo",
+    "roosevelt",
+    "ropa",
+    "ropajes",
+    "roque",
+    "rosa",
+    "rosales",
+    "rosalinda",
+    "rosario",
+    "rosarios",
+    "rosas",
+    "rosaura",
+    "rosellini",
+    "rosencof",
+    "rosendo",
+    "roses",
+    "rosibel",
+    "rosilí",
+    "ross",
+    "rossela",
+    "rossi",
+    "rossin",
+    "rossini",
+    "roster",
+    "rosto",
+    "rostro",
+    "rostros",
+    "rota",
+    "rotación",
+    "rotario",
+    "rotarios",
+    "rotativo",
+    "rotativos",
+    "rotemberg",
+    "roto",
+    "rotonda",
+    "rotter",
+    "rotuliano",
+    "rotundamente",
+    "rotura",
+    "rousseau",
+    "roy",
+    "royce",
+    "rsm",
+    "rsqb",
+    "ru",
+    "ruandeses",
+    "rubia",
+    "rubiales",
+    "rubiano",
+    "rubio",
+    "rubor",
+    "ruborizándose",
+    "rubro",
+    "rubros",
+    "rubén",
+    "rubí",
+    "ruco",
+    "rudos",
+    "rudyard",
+    "rueda",
+    "ruedas",
+    "ruedo",
+    "ruega",
+    "ruego",
+    "ruelas",
+    "ruffo",
+    "rufino",
+    "ruge",
+    "ruggeri",
+    "rugido",
+    "rugir",
+    "ruido",
+    "ruidosas",
+    "ruina",
+    "ruinas",
+    "ruindades",
+    "ruisz",
+    "ruiz",
+    "rul",
+    "rumania",
+    "rumanos",
+    "rumberas",
+    "rumbo",
+    "rumor",
+    "rumores",
+    "ruperto",
+    "ruptura",
+    "rural",
+    "rurales",
+    "rusa",
+    "rusas",
+    "rusia",
+    "ruslan",
+    "ruso",
+    "rusos",
+    "ruta",
+    "rutas",
+    "ruteador",
+    "rutina",
+    "rutinario",
+    "rutinas",
+    "rutskoi",
+    "ruvalcaba",
+    "ruy",
+    "ruíz",
+    "ráfagas",
+    "rápida",
+    "rápidamente",
+    "rápidas",
+    "rápido",
+    "rápidos",
+    "récord",
+    "récords",
+    "rédord",
+    "réferi",
+    "régimen",
+    "rémoras",
+    "réplica",
+    "réplicas",
+    "ríe",
+    "rígidas",
+    "rígidos",
+    "río",
+    "ríos",
+    "ríspido",
+    "rítmica",
+    "rítmico",
+    "rómulo",
+    "rústica",
+    "rústico",
+    "rústicos",
+    "s",
+    "sabastian",
+    "sabatino",
+    "sabe",
+    "sabedores",
+    "sabemos",
+    "saben",
+    "saber",
+    "saberlo",
+    "saberse",
+    "sabes",
+    "sabia",
+    "sabidas",
+    "sabido",
+    "sabiduría",
+    "sabiendas",
+    "sabina",
+    "sabinas",
+    "sabino",
+    "sabio",
+    "sabios",
+    "sabor",
+    "saboreando",
+    "sabores",
+    "saborit",
+    "saborizantes",
+    "sabotaje",
+    "sabremos",
+    "sabrosa",
+    "sabroso",
+    "sabrá",
+    "sabrán",
+    "sabía",
+    "sabíamos",
+    "sabían",
+    "saca",
+    "sacada",
+    "sacadas",
+    "sacado",
+    "sacamos",
+    "sacando",
+    "sacar",
+    "sacara",
+    "sacaran",
+    "sacaremos",
+    "sacarla",
+    "sacarle",
+    "sacarles",
+    "sacarlo",
+    "sacarme",
+    "sacaron",
+    "sacerdocio",
+    "sacerdotal",
+    "sacerdotales",
+    "sacerdote",
+    "sacerdotes",
+    "saco",
+    "sacos",
+    "sacra",
+    "sacramento",
+    "sacrificado",
+    "sacrificar",
+    "sacrificarlo",
+    "sacrificio",
+    "sacrificios",
+    "sacrificó",
+    "sacristán",
+    "sacristía",
+    "sacro",
+    "sacs",
+    "sacudida",
+    "sacudimiento",
+    "sacó",
+    "sada",
+    "sadam",
+    "sadomasoquistas",
+    "saenz",
+    "saeta",
+    "sagrada",
+    "sagradas",
+    "sagrado",
+    "sahuayo",
+    "sain",
+    "saint",
+    "sainz",
+    "sal",
+    "sala",
+    "salacio",
+    "salada",
+    "salais",
+    "salamandria",
+    "salarial",
+    "salariales",
+    "salario",
+    "salarios",
+    "salas",
+    "salaz",
+    "salazar",
+    "saldar",
+    "saldarán",
+    "saldaña",
+    "saldo",
+    "saldos",
+    "saldrá",
+    "saldrán",
+    "saldría",
+    "saldívar",
+    "sale",
+    "salen",
+    "salesianas",
+    "salga",
+    "salgado",
+    "salgamos",
+    "salgan",
+    "salgo",
+    "salida",
+    "salidas",
+    "salido",
+    "saliendo",
+    "saliente",
+    "saliera",
+    "salieran",
+    "salieron",
+    "salimos",
+    "salinas",
+    "salinismo",
+    "salinista",
+    "salir",
+    "salirse",
+    "salió",
+    "salmones",
+    "salmos",
+    "salmón",
+    "salomon",
+    "salomón",
+    "salomónica",
+    "salomónico",
+    "salones",
+    "salpicadas",
+    "salsa",
+    "salsas",
+    "salta",
+    "saltaron",
+    "salte",
+    "saltillense",
+    "saltillenses",
+    "saltillo",
+    "salto",
+    "saltándose",
+    "saltó",
+    "salubridad",
+    "salud",
+    "saludable",
+    "saludables",
+    "saludando",
+    "saludar",
+    "saludarle",
+    "saludarlo",
+    "saludaron",
+    "saludemos",
+    "saludo",
+    "saludos",
+    "saludó",
+    "salutación",
+    "salva",
+    "salvación",
+    "salvado",
+    "salvador",
+    "salvadoreña",
+    "salvadoreños",
+    "salvados",
+    "salvaguardas",
+    "salvaje",
+    "salvamentos",
+    "salvar",
+    "salvara",
+    "salvarle",
+    "salvarse",
+    "salve",
+    "salvedades",
+    "salvia",
+    "salvo",
+    "salvó",
+    "salí",
+    "salía",
+    "salían",
+    "salón",
+    "sam",
+    "samaniego",
+    "samoa",
+    "sampetrinas",
+    "sampetrino",
+    "samuel",
+    "samuelito",
+    "sana",
+    "sanchez",
+    "sancho",
+    "sancionados",
+    "sancionan",
+    "sancionarlo",
+    "sancionarlos",
+    "sancionaron",
+    "sancionaría",
+    "sanciones",
+    "sanción",
+    "sandalio",
+    "sandinista",
+    "sandoval",
+    "sandra",
+    "sandrine",
+    "sandwich",
+    "saneado",
+    "saneamiento",
+    "sanear",
+    "saneara",
+    "sanearlas",
+    "sangrados",
+    "sangrar",
+    "sangre",
+    "sangrientas",
+    "sangriento",
+    "sangría",
+    "sanguinario",
+    "sanidad",
+    "sanidades",
+    "sanitaria",
+    "sanitario",
+    "sanitarios",
+    "sanjuana",
+    "sano",
+    "sansores",
+    "santa",
+    "santana",
+    "santiago",
+    "santidad",
+    "santiesteban",
+    "santillán",
+    "santiso",
+    "santistas",
+    "santo",
+    "santos",
+    "santuario",
+    "santurce",
+    "santísimo",
+    "sao",
+    "sapitos",
+    "sapién",
+    "sapo",
+    "saqueadas",
+    "saquear",
+    "saqueo",
+    "saqueos",
+    "saqué",
+    "sar",
+    "sarah",
+    "sarajevo",
+    "sarcástica",
+    "sargentos",
+    "sarh",
+    "sartenes",
+    "sastre",
+    "satanás",
+    "satelitel",
+    "satisfacciones",
+    "satisfacción",
+    "satisface",
+    "satisfacer",
+    "satisfactores",
+    "satisfactoria",
+    "satisfactoriamente",
+    "satisfactorias",
+    "satisfactorio",
+    "satisfagan",
+    "satisfecho",
+    "sato",
+    "saturación",
+    "saturada",
+    "saturadas",
+    "saturado",
+    "saturados",
+    "saturó",
+    "satélite",
+    "satélites",
+    "satín",
+    "satírica",
+    "sauceda",
+    "saucedo",
+    "sauco",
+    "saunders",
+    "sauzal",
+    "savimbi",
+    "savotino",
+    "savoy",
+    "savr",
+    "saxe",
+    "saxofonista",
+    "saxofón",
+    "saya",
+    "sazón",
+    "saínz",
+    "saúl",
+    "sc",
+    "scafadi",
+    "scalia",
+    "scatasta",
+    "scheider",
+    "schlesinghaus",
+    "schmal",
+    "schmall",
+    "schoenstat",
+    "schoenstatt",
+    "schooll",
+    "schools",
+    "schopenhahuer",
+    "scott",
+    "scout",
+    "scrooge",
+    "sct",
+    "sculley",
+    "se",
+    "sea",
+    "seamos",
+    "sean",
+    "seas",
+    "seattle",
+    "sebastian",
+    "sebastián",
+    "seca",
+    "secaba",
+    "secadora",
+    "secas",
+    "seccional",
+    "seccionales",
+    "sección",
+    "seco",
+    "secofi",
+    "secogef",
+    "secos",
+    "secreta",
+    "secretaria",
+    "secretario",
+    "secretarios",
+    "secretaría",
+    "secretarías",
+    "secretas",
+    "secreto",
+    "secretos",
+    "sectas",
+    "sector",
+    "sectores",
+    "sectoriales",
+    "secuela",
+    "secuelas",
+    "secuencial",
+    "secuestrada",
+    "secuestradas",
+    "secuestrado",
+    "secuestrador",
+    "secuestradores",
+    "secuestro",
+    "secuestros",
+    "secuestró",
+    "secular",
+    "secundada",
+    "secundados",
+    "secundaria",
+    "secundarias",
+    "secundario",
+    "secundarios",
+    "secundaron",
+    "securities",
+    "sed",
+    "seda",
+    "sedanes",
+    "sedante",
+    "sede",
+    "sedesol",
+    "sedientos",
+    "sedona",
+    "seducción",
+    "seducir",
+    "seductoras",
+    "sedujo",
+    "seduop",
+    "sedán",
+    "seelene",
+    "segmentada",
+    "segmento",
+    "segmentos",
+    "segovia",
+    "segueta",
+    "seguida",
+    "seguido",
+    "seguidora",
+    "seguidores",
+    "seguidos",
+    "seguimiento",
+    "seguimos",
+    "seguir",
+    "seguiremos",
+    "seguirlas",
+    "seguirles",
+    "seguirse",
+    "seguirá",
+    "seguirán",
+    "seguiré",
+    "seguiría",
+    "segunda",
+    "segundas",
+    "segundo",
+    "segundones",
+    "segundos",
+    "segura",
+    "seguramente",
+    "seguras",
+    "seguri",
+    "seguridad",
+    "seguro",
+    "seguros",
+    "seguy",
+    "seguí",
+    "seguía",
+    "seguían",
+    "según",
+    "seis",
+    "seiyu",
+    "seko",
+    "seleccionada",
+    "seleccionadas",
+    "seleccionado",
+    "seleccionados",
+    "seleccionar",
+    "seleccionarán",
+    "selecciones",
+    "selección",
+    "selectivo",
+    "selecto",
+    "sellada",
+    "sellar",
+    "seller",
+    "sello",
+    "selva",
+    "selvas",
+    "semana",
+    "semanal",
+    "semanales",
+    "semanalmente",
+    "semanario",
+    "semanas",
+    "semblantes",
+    "sembraban",
+    "sembrada",
+    "sembradores",
+    "sembradío",
+    "sembradíos",
+    "sembrar",
+    "sembremos",
+    "semejante",
+    "semejantes",
+    "semejanza",
+    "semejanzas",
+    "semen",
+    "semental",
+    "semestral",
+    "semestrales",
+    "semestre",
+    "semestres",
+    "semi",
+    "semiautomática",
+    "semicerrados",
+    "semidesértica",
+    "semidíos",
+    "semifinal",
+    "semifinales",
+    "semifinalistas",
+    "semilla",
+    "semillas",
+    "seminal",
+    "seminario",
+    "seminarios",
+    "seminaristas",
+    "semioscuras",
+    "semipreciosa",
+    "semivacíos",
+    "semáforos",
+    "senado",
+    "senador",
+    "senadora",
+    "senadores",
+    "sencilla",
+    "sencillamente",
+    "sencillas",
+    "sencillez",
+    "sencillo",
+    "sencillos",
+    "sendas",
+    "senderista",
+    "senderistas",
+    "sendero",
+    "sendos",
+    "seneam",
+    "seno",
+    "senos",
+    "sensacionalismo",
+    "sensacionalista",
+    "sensaciones",
+    "sensación",
+    "sensata",
+    "sensatas",
+    "sensibilidad",
+    "sensibilidades",
+    "sensibilizar",
+    "sensible",
+    "sensiblemente",
+    "sensibles",
+    "sensores",
+    "sensual",
+    "sensualidad",
+    "sentadas",
+    "sentado",
+    "sentados",
+    "sentamos",
+    "sentando",
+    "sentanes",
+    "sentar",
+    "sentaran",
+    "sentaron",
+    "sentarse",
+    "sentarían",
+    "sentencia",
+    "sentenciada",
+    "sentenciado",
+    "sentenciados",
+    "sentenció",
+    "sentid",
+    "sentida",
+    "sentidas",
+    "sentido",
+    "sentidos",
+    "sentimental",
+    "sentimiento",
+    "sentimientos",
+    "sentimos",
+    "sentir",
+    "sentirse",
+    "sentiría",
+    "sentí",
+    "sentía",
+    "sentían",
+    "sentó",
+    "sep",
+    "sepa",
+    "sepamos",
+    "sepan",
+    "separa",
+    "separaciones",
+    "separación",
+    "separada",
+    "separadas",
+    "separado",
+    "separador",
+    "separados",
+    "separar",
+    "separarlos",
+    "separaron",
+    "separarse",
+    "separará",
+    "separarán",
+    "separe",
+    "separen",
+    "sepelio",
+    "septiembre",
+    "sepultado",
+    "sepultan",
+    "sepultó",
+    "sepúlveda",
+    "ser",
+    "sera",
+    "serbia",
+    "serbio",
+    "serbios",
+    "seremos",
+    "serena",
+    "serenata",
+    "serenidad",
+    "seres",
+    "serfin",
+    "serfín",
+    "sergei",
+    "sergi",
+    "sergio",
+    "seria",
+    "seriamente",
+    "serias",
+    "serie",
+    "seriedad",
+    "serio",
+    "serios",
+    "serlo",
+    "sermonearlos",
+    "sermón",
+    "serna",
+    "serpentinero",
+    "serpiente",
+    "serra",
+    "serrano",
+    "servia",
+    "servicial",
+    "servicio",
+    "servicios",
+    "servido",
+    "servidor",
+    "servidores",
+    "servidumbre",
+    "serviles",
+    "servilleta",
+    "servimos",
+    "servios",
+    "servir",
+    "servirnos",
+    "servirá",
+    "servirán",
+    "serviría",
+    "servín",
+    "será",
+    "serán",
+    "serás",
+    "seré",
+    "seréis",
+    "sería",
+    "seríais",
+    "seríamos",
+    "serían",
+    "serías",
+    "sese",
+    "sesentas",
+    "sesgo",
+    "sesiones",
+    "sesión",
+    "sesos",
+    "setentas",
+    "seudónimo",
+    "seul",
+    "severa",
+    "severamente",
+    "severas",
+    "severo",
+    "severos",
+    "sevilla",
+    "sexenio",
+    "sexenios",
+    "sexista",
+    "sexo",
+    "sexopatía",
+    "sexos",
+    "sexta",
+    "sextetas",
+    "sexto",
+    "sexual",
+    "sexuales",
+    "sexualidad",
+    "sexualmente",
+    "sexy",
+    "sexys",
+    "seáis",
+    "seña",
+    "señal",
+    "señala",
+    "señalaban",
+    "señaladas",
+    "señalado",
+    "señalamiento",
+    "señalamientos",
+    "señalamos",
+    "señalan",
+    "señalar",
+    "señalarla",
+    "señalaron",
+    "señalarse",
+    "señalen",
+    "señales",
+    "señalo",
+    "señaló",
+    "señas",
+    "señor",
+    "señora",
+    "señoras",
+    "señores",
+    "señorita",
+    "señoría",
+    "seúl",
+    "sfara",
+    "sgpv",
+    "shannon",
+    "shaw",
+    "shcp",
+    "sheehy",
+    "sheila",
+    "shepard",
+    "sherel",
+    "sheriff",
+    "sherman",
+    "sherrit",
+    "shibata",
+    "shikang",
+    "shimizu",
+    "shorts",
+    "showtime",
+    "shrine",
+    "si",
+    "siberia",
+    "siberiana",
+    "sica",
+    "siclo",
+    "siclos",
+    "sicológica",
+    "sida",
+    "sidente",
+    "siderúrgicas",
+    "sido",
+    "siembra",
+    "siembran",
+    "siemens",
+    "siempre",
+    "siendo",
+    "sienta",
+    "sientan",
+    "siente",
+    "sienten",
+    "siento",
+    "sierra",
+    "sierva",
+    "siervo",
+    "siete",
+    "siga",
+    "sigamos",
+    "sigan",
+    "siglas",
+    "siglo",
+    "siglos",
+    "significa",
+    "significación",
+    "significado",
+    "significados",
+    "significan",
+    "significar",
+    "significaran",
+    "significará",
+    "significaría",
+    "significativa",
+    "significativamente",
+    "significativas",
+    "significativo",
+    "significativos",
+    "significo",
+    "significó",
+    "signifique",
+    "signo",
+    "signos",
+    "sigo",
+    "sigue",
+    "siguen",
+    "siguiendo",
+    "siguiente",
+    "siguientes",
+    "siguiera",
+    "siguieran",
+    "siguieron",
+    "siguió",
+    "silbante",
+    "silbaron",
+    "silbatazo",
+    "silber",
+    "silbes",
+    "silencio",
+    "silencioso",
+    "silla",
+    "sillas",
+    "sillón",
+    "silos",
+    "silueta",
+    "silva",
+    "silvestre",
+    "silvestris",
+    "silvia",
+    "silviano",
+    "silvio",
+    "simbolización",
+    "simbólica",
+    "simbólico",
+    "simeone",
+    "simeprode",
+    "similares",
+    "simpatizantes",
+    "simpatía",
+    "simplemente",
+    "simples",
+    "simpliciano",
+    "simplificación",
+    "simplificado",
+    "simplista",
+    "simposiums",
+    "simpático",
+    "simulación",
+    "simulador",
+    "simulados",
+    "simular",
+    "simultánea",
+    "simultáneamente",
+    "simultáneos",
+    "simón",
+    "sin",
+    "sinagua",
+    "sinaloa",
+    "sinaloense",
+    "sinaloenses",
+    "sinaí",
+    "sinceramente",
+    "sincero",
+    "sinclair",
+    "sincretismo",
+    "sincronización",
+    "sincronizados",
+    "sindelfingen",
+    "sindical",
+    "sindicales",
+    "sindicalismo",
+    "sindicalista",
+    "sindicalistas",
+    "sindicato",
+    "sindicatos",
+    "sindicatura",
+    "sindo",
+    "sinfonía",
+    "singapur",
+    "singer",
+    "singles",
+    "singlista",
+    "siniestra",
+    "siniestro",
+    "sinnúmero",
+    "sino",
+    "sinodal",
+    "sinovial",
+    "sintiendo",
+    "sintiéndome",
+    "sintió",
+    "sintomática",
+    "sintomáticas",
+    "sintomático",
+    "sintética",
+    "sintético",
+    "sintéticos",
+    "sinuosos",
+    "sinusitis",
+    "sinónimo",
+    "siquiatra",
+    "siquiera",
+    "sirgo",
+    "sirloin",
+    "siro",
+    "sirva",
+    "sirvan",
+    "sirve",
+    "sirven",
+    "sirves",
+    "sirvientas",
+    "sirvieran",
+    "sirvieron",
+    "sirvió",
+    "siscohm",
+    "sismología",
+    "sismógrafos",
+    "sistefin",
+    "sistema",
+    "sistemas",
+    "sistemática",
+    "sistemáticas",
+    "sistemático",
+    "sitemáticamente",
+    "sitiado",
+    "sitiados",
+    "sitio",
+    "sitios",
+    "situaciones",
+    "situación",
+    "situada",
+    "situadas",
+    "situado",
+    "situar",
+    "situarlos",
+    "situarse",
+    "situándose",
+    "sixto",
+    "sketches",
+    "slam",
+    "sledge",
+    "sli",
+    "slim",
+    "smc",
+    "smith",
+    "smuckers",
+    "snte",
+    "sobada",
+    "sobando",
+    "soberana",
+    "soberano",
+    "soberanía",
+    "soberbia",
+    "sobornados",
+    "sobornando",
+    "soborno",
+    "sobornos",
+    "sobra",
+    "sobraba",
+    "sobradamente",
+    "sobran",
+    "sobrando",
+    "sobrar",
+    "sobrarán",
+    "sobre",
+    "sobreasignación",
+    "sobrecalentada",
+    "sobrecalentamiento",
+    "sobrecalentaron",
+    "sobrecarga",
+    "sobrecargaron",
+    "sobrecargas",
+    "sobrecosto",
+    "sobrecostos",
+    "sobrecupo",
+    "sobrecupos",
+    "sobreexplotación",
+    "sobregiro",
+    "sobregiros",
+    "sobrellevar",
+    "sobremanera",
+    "sobrenombre",
+    "sobrepasa",
+    "sobrepasan",
+    "sobrepasar",
+    "sobrepase",
+    "sobrepasó",
+    "sobrepoblación",
+    "sobreponen",
+    "sobreponiéndose",
+    "sobreposición",
+    "sobresale",
+    "sobresalen",
+    "sobresaliendo",
+    "sobresaliente",
+    "sobresalir",
+    "sobresalió",
+    "sobresaltos",
+    "sobreseída",
+    "sobrestantes",
+    "sobresuscripcion",
+    "sobretasa",
+    "sobretodo",
+    "sobrevaluado",
+    "sobrevenga",
+    "sobrevida",
+    "sobreviven",
+    "sobrevivencia",
+    "sobrevivido",
+    "sobrevivientes",
+    "sobrevivieron",
+    "sobrevivir",
+    "sobrevuelan",
+    "sobrevuelos",
+    "sobriedad",
+    "sobrina",
+    "sobrino",
+    "sobrinos",
+    "socavamos",
+    "socavar",
+    "soccer",
+    "social",
+    "socialdemócrata",
+    "sociales",
+    "socialismo",
+    "socialista",
+    "socialmente",
+    "sociedad",
+    "sociedades",
+    "socio",
+    "socioeconómica",
+    "socioeconómico",
+    "sociológico",
+    "sociológicos",
+    "sociopolítica",
+    "socios",
+    "socióloga",
+    "sociólogo",
+    "socorrista",
+    "socorristas",
+    "socorro",
+    "socorros",
+    "socrático",
+    "socráticos",
+    "sodas",
+    "sodio",
+    "sofisticación",
+    "sofisticadas",
+    "sofisticado",
+    "sofisticados",
+    "sofocación",
+    "sofocado",
+    "sofocarlo",
+    "softbol",
+    "sofá",
+    "sofía",
+    "sogem",
+    "sois",
+    "sojo",
+    "sol",
+    "sola",
+    "solamente",
+    "solana",
+    "solapados",
+    "solar",
+    "solares",
+    "solas",
+    "solaza",
+    "solazo",
+    "soldada",
+    "soldado",
+    "soldador",
+    "soldados",
+    "soldadura",
+    "soldan",
+    "soldando",
+    "soldar",
+    "soldarlos",
+    "soleado",
+    "soledad",
+    "solemne",
+    "solemnidad",
+    "solicita",
+    "solicitaba",
+    "solicitada",
+    "solicitado",
+    "solicitados",
+    "solicitamos",
+    "solicitan",
+    "solicitando",
+    "solicitar",
+    "solicitarle",
+    "solicitaron",
+    "solicitará",
+    "solicitarán",
+    "solicite",
+    "solicito",
+    "solicitud",
+    "solicitudes",
+    "solicité",
+    "solicitó",
+    "solidaria",
+    "solidaridad",
+    "solidario",
+    "solidez",
+    "solista",
+    "solistas",
+    "solitario",
+    "solitarios",
+    "solito",
+    "sollozando",
+    "solo",
+    "solos",
+    "soltar",
+    "soltara",
+    "soltaran",
+    "soltera",
+    "solteras",
+    "soltó",
+    "solucionan",
+    "solucionar",
+    "solucionarse",
+    "solucione",
+    "soluciones",
+    "solucionó",
+    "solución",
+    "solvencia",
+    "solventar",
+    "solía",
+    "solían",
+    "solícito",
+    "solís",
+    "solórzano",
+    "somalia",
+    "sombra",
+    "sombras",
+    "sombrero",
+    "sombreros",
+    "sometan",
+    "somete",
+    "someter",
+    "someterlos",
+    "someterse",
+    "someterá",
+    "someterán",
+    "sometidas",
+    "sometido",
+    "sometidos",
+    "sometimiento",
+    "somos",
+    "somoza",
+    "son",
+    "sonados",
+    "sonda",
+    "sondas",
+    "sondeos",
+    "sonia",
+    "sonido",
+    "sonidos",
+    "sonora",
+    "sonorense",
+    "sonriente",
+    "sonrisa",
+    "sonrisas",
+    "sonrió",
+    "sonríes",
+    "sony",
+    "sonya",
+    "sopesar",
+    "soplando",
+    "soplarse",
+    "soporta",
+    "soportaba",
+    "soportable",
+    "soportado",
+    "soportan",
+    "soportar",
+    "soportarán",
+    "soporte",
+    "soportes",
+    "soportó",
+    "sor",
+    "sorda",
+    "sordera",
+    "sordos",
+    "sorgo",
+    "soriana",
+    "soriano",
+    "soros",
+    "sorprenda",
+    "sorprende",
+    "sorprenden",
+    "sorprendente",
+    "sorprendentes",
+    "sorprender",
+    "sorprenderá",
+    "sorprendidas",
+    "sorprendido",
+    "sorprendidos",
+    "sorprendieron",
+    "sorprendió",
+    "sorprendí",
+    "sorpresa",
+    "sorpresas",
+    "sorpresiva",
+    "sorpresivas",
+    "sorpresivo",
+    "sorpresivos",
+    "sortea",
+    "sorteo",
+    "sorteos",
+    "sosa",
+    "soslayó",
+    "sospecha",
+    "sospechaba",
+    "sospechamos",
+    "sospecho",
+    "sospechosa",
+    "sospechoso",
+    "sospechosos",
+    "sostendrá",
+    "sostendrán",
+    "sostener",
+    "sostenerla",
+    "sostenerse",
+    "sostengo",
+    "sostenible",
+    "sostenida",
+    "sostenidas",
+    "sostenido",
+    "sosteniendo",
+    "sostenía",
+    "sostenían",
+    "sostiene",
+    "sostienen",
+    "sostuvieron",
+    "sostuvo",
+    "sostén",
+    "sotanero",
+    "sotaneros",
+    "sotero",
+    "soto",
+    "soundgarden",
+    "southern",
+    "southwest",
+    "soviet",
+    "soviética",
+    "soviético",
+    "soy",
+    "soya",
+    "soñado",
+    "soñadores",
+    "soñados",
+    "soñando",
+    "soñar",
+    "soñé",
+    "soñó",
+    "sparcclassic",
+    "sparcservers",
+    "sparcstations",
+    "sparrings",
+    "spike",
+    "spivakov",
+    "sporting",
+    "sprenger",
+    "springbreakers",
+    "sprint",
+    "sprints",
+    "sr",
+    "staff",
+    "stalin",
+    "stanley",
+    "statuo",
+    "steels",
+    "stephan",
+    "stephanopoulos",
+    "stephen",
+    "stereo",
+    "stern",
+    "steve",
+    "steven",
+    "stl",
+    "stoichkov",
+    "stoickov",
+    "stoker",
+    "stor",
+    "straffon",
+    "strauss",
+    "stravinsky",
+    "strikes",
+    "strindberg",
+    "studies",
+    "stup",
+    "stuttgart",
+    "stéfano",
+    "stólica",
+    "su",
+    "suave",
+    "suavemente",
+    "suaves",
+    "suavidad",
+    "suavizamos",
+    "suavizaron",
+    "suba",
+    "subacuática",
+    "subalterna",
+    "subalterno",
+    "suban",
+    "subaru",
+    "subasta",
+    "subastas",
+    "subcampeón",
+    "subcomandante",
+    "subcomandantes",
+    "subcomisión",
+    "subcomité",
+    "subconsciente",
+    "subcontratación",
+    "subdelegado",
+    "subdesarrollada",
+    "subdesarrollados",
+    "subdirección",
+    "subdirector",
+    "subdirectora",
+    "subdistribuidores",
+    "subdivisión",
+    "suben",
+    "subes",
+    "subespecialidades",
+    "subestación",
+    "subfacturación",
+    "subgerente",
+    "subida",
+    "subidas",
+    "subido",
+    "subiendo",
+    "subieran",
+    "subieron",
+    "subir",
+    "subirse",
+    "subirá",
+    "subió",
+    "subjetiva",
+    "subjetividad",
+    "sublime",
+    "subliminal",
+    "sublíder",
+    "sublíderes",
+    "submarina",
+    "submarinas",
+    "submarino",
+    "submarinos",
+    "subordinado",
+    "subordinados",
+    "subprocurador",
+    "subproductos",
+    "subrayar",
+    "subrayaron",
+    "subrayó",
+    "subregional",
+    "subsana",
+    "subsanables",
+    "subsecretaria",
+    "subsecretario",
+    "subsecretarios",
+    "subsecretaría",
+    "subsectores",
+    "subsecuentes",
+    "subsidiada",
+    "subsidiadas",
+    "subsidiar",
+    "subsidiaria",
+    "subsidiarias",
+    "subsidiarios",
+    "subsidio",
+    "subsidios",
+    "substancia",
+    "substancial",
+    "substanciales",
+    "substancias",
+    "subsuelo",
+    "subtangente",
+    "subterránea",
+    "subterráneo",
+    "suburban",
+    "suburbanos",
+    "suburbias",
+    "suburbios",
+    "subvención",
+    "subversivo",
+    "subversivos",
+    "subversión",
+    "subvertir",
+    "subviertes",
+    "subí",
+    "subían",
+    "suceda",
+    "sucede",
+    "suceden",
+    "suceder",
+    "sucederlo",
+    "sucederá",
+    "sucedería",
+    "sucedido",
+    "sucediera",
+    "sucedieron",
+    "sucedió",
+    "sucedía",
+    "sucesion",
+    "sucesivamente",
+    "sucesión",
+    "suceso",
+    "sucesor",
+    "sucesorio",
+    "sucesos",
+    "sucia",
+    "suciedad",
+    "sucio",
+    "sucios",
+    "sucre",
+    "sucumbir",
+    "sucursal",
+    "sucursales",
+    "sudamericana",
+    "sudamericanas",
+    "sudamericano",
+    "sudamérica",
+    "sudar",
+    "sudeste",
+    "sudoccidental",
+    "sudoeste",
+    "sudoración",
+    "sudoroso",
+    "suecia",
+    "sueco",
+    "suecos",
+    "suegra",
+    "suelas",
+    "sueldo",
+    "sueldos",
+    "suele",
+    "suelen",
+    "suelo",
+    "suelos",
+    "sueltan",
+    "suena",
+    "suenen",
+    "suero",
+    "suerte",
+    "sueña",
+    "sueño",
+    "sueños",
+    "suficiente",
+    "suficientemente",
+    "suficientes",
+    "sufragar",
+    "sufragaron",
+    "sufragio",
+    "sufragios",
+    "sufrague",
+    "sufre",
+    "sufren",
+    "sufrido",
+    "sufridos",
+    "sufriendo",
+    "sufrieron",
+    "sufrimiento",
+    "sufrir",
+    "sufrió",
+    "sufrí",
+    "sufría",
+    "sugerencia",
+    "sugerencias",
+    "sugerentes",
+    "sugerido",
+    "sugeridos",
+    "sugerir",
+    "sugería",
+    "sugestivos",
+    "sugiera",
+    "sugieran",
+    "sugiere",
+    "sugieren",
+    "sugiero",
+    "sugiriendo",
+    "sugirieron",
+    "sugirió",
+    "sui",
+    "suicida",
+    "suicidaron",
+    "suicidarse",
+    "suicidio",
+    "suiza",
+    "suizos",
+    "sujeción",
+    "sujetar",
+    "sujetarlo",
+    "sujetarse",
+    "sujeto",
+    "sujetos",
+    "sulaimán",
+    "sulfito",
+    "sulfúrico",
+    "sultana",
+    "sultanes",
+    "suma",
+    "sumaban",
+    "sumado",
+    "sumados",
+    "sumamente",
+    "suman",
+    "sumando",
+    "sumar",
+    "sumario",
+    "sumaron",
+    "sumarse",
+    "sumarán",
+    "sumas",
+    "sume",
+    "sumen",
+    "sumerge",
+    "sumergibles",
+    "sumergido",
+    "sumergió",
+    "sumerio",
+    "sumido",
+    "suministraba",
+    "suministrada",
+    "suministrar",
+    "suministro",
+    "suministró",
+    "sumisa",
+    "summers",
+    "sumo",
+    "sumó",
+    "sung",
+    "sunny",
+    "suntuoso",
+    "suoo",
+    "supe",
+    "supera",
+    "superación",
+    "superada",
+    "superado",
+    "superamas",
+    "superamos",
+    "superan",
+    "superando",
+    "superar",
+    "superarla",
+    "superarlo",
+    "superarlos",
+    "superarme",
+    "superaron",
+    "superará",
+    "superavenida",
+    "superavitaria",
+    "supercenter",
+    "supercenters",
+    "superdotados",
+    "supere",
+    "superficial",
+    "superficiales",
+    "superficie",
+    "superficies",
+    "superfluas",
+    "superior",
+    "superiores",
+    "superioridad",
+    "superiorsubaru",
+    "superliderato",
+    "superligero",
+    "superligeros",
+    "superliviano",
+    "superlíder",
+    "supermediano",
+    "supermercado",
+    "supermercados",
+    "superpluma",
+    "superpotencia",
+    "supersecretarios",
+    "supersónicas",
+    "supervisa",
+    "supervisar",
+    "supervisarlos",
+    "supervisará",
+    "supervisión",
+    "supervisor",
+    "supervisora",
+    "supervisó",
+    "supervivencia",
+    "superwelter",
+    "superávit",
+    "superó",
+    "supieran",
+    "supieron",
+    "suple",
+    "suplemento",
+    "suplente",
+    "suplentes",
+    "suplicantes",
+    "suplicar",
+    "suplicará",
+    "suplir",
+    "supo",
+    "supondría",
+    "supone",
+    "suponemos",
+    "suponer",
+    "suponerse",
+    "suponga",
+    "supongo",
+    "suponiendo",
+    "suponía",
+    "suposiciones",
+    "supra",
+    "suprema",
+    "supremacia",
+    "supremacía",
+    "supremo",
+    "suprimir",
+    "suprimirlas",
+    "supuesta",
+    "supuestamente",
+    "supuestas",
+    "supuesto",
+    "supuestos",
+    "sur",
+    "surcaba",
+    "surcoreana",
+    "surcoreanas",
+    "surcoreanos",
+    "sureste",
+    "surge",
+    "surgen",
+    "surgida",
+    "surgido",
+    "surgidos",
+    "surgiendo",
+    "surgiera",
+    "surgieron",
+    "surgimiento",
+    "surgio",
+    "surgir",
+    "surgirá",
+    "surgirían",
+    "surgió",
+    "surja",
+    "surjan",
+    "suroeste",
+    "surrealista",
+    "surta",
+    "surtido",
+    "surtiendo",
+    "surtir",
+    "surtió",
+    "sus",
+    "susan",
+    "susana",
+    "susceptible",
+    "susceptibles",
+    "suscitado",
+    "suscitó",
+    "suscribir",
+    "suscribió",
+    "suscripción",
+    "suscrita",
+    "suscrito",
+    "susiflor",
+    "suspenda",
+    "suspende",
+    "suspenden",
+    "suspender",
+    "suspenderá",
+    "suspenderán",
+    "suspendida",
+    "suspendidas",
+    "suspendido",
+    "suspendidos",
+    "suspendiera",
+    "suspendieron",
+    "suspendió",
+    "suspendo",
+    "suspensiones",
+    "suspensión",
+    "suspiro",
+    "sustancia",
+    "sustancial",
+    "sustancias",
+    "sustenta",
+    "sustentables",
+    "sustentación",
+    "sustentado",
+    "sustentar",
+    "sustente",
+    "sustento",
+    "sustitución",
+    "sustituido",
+    "sustituir",
+    "sustituirá",
+    "sustituiría",
+    "sustitutiva",
+    "sustituto",
+    "sustituyamos",
+    "sustituyan",
+    "sustituye",
+    "sustituyen",
+    "sustituyendo",
+    "sustituyó",
+    "sustituían",
+    "susto",
+    "sustrajo",
+    "sustraía",
+    "suya",
+    "suyas",
+    "suyo",
+    "suyos",
+    "suárez",
+    "suéter",
+    "suéteres",
+    "svyt",
+    "swahili",
+    "swing",
+    "swycord",
+    "sx",
+    "sybilla",
+    "symington",
+    "szekely",
+    "sznajder",
+    "sábado",
+    "sábados",
+    "sáenz",
+    "sáinz",
+    "sánchez",
+    "sé",
+    "sépticas",
+    "séptima",
+    "séptimo",
+    "séquito",
+    "sí",
+    "símbolo",
+    "símbolos",
+    "síndico",
+    "síndicos",
+    "síndrome",
+    "síntesis",
+    "síntoma",
+    "síntomas",
+    "sísmica",
+    "sísmicos",
+    "sócrates",
+    "sódico",
+    "sólida",
+    "sólidas",
+    "sólido",
+    "sólidos",
+    "sólo",
+    "sórdidas",
+    "sórdido",
+    "súbita",
+    "súbitamente",
+    "súper",
+    "t",
+    "tabachín",
+    "tabaco",
+    "tabasco",
+    "tabe",
+    "tabla",
+    "tablante",
+    "tablero",
+    "tableros",
+    "tablones",
+    "taboada",
+    "tabulador",
+    "tachado",
+    "tacho",
+    "tachuelas",
+    "taco",
+    "tacos",
+    "tacto",
+    "tacón",
+    "taesa",
+    "taffarel",
+    "tagamet",
+    "tagetes",
+    "taiga",
+    "tailandia",
+    "taiwan",
+    "taiwandeses",
+    "taiwán",
+    "tajante",
+    "takatoshi",
+    "tal",
+    "talada",
+    "taladores",
+    "taladro",
+    "talante",
+    "talento",
+    "talentos",
+    "talentoso",
+    "tales",
+    "talismán",
+    "talla",
+    "tallando",
+    "tallar",
+    "taller",
+    "talleres",
+    "talones",
+    "talusa",
+    "talón",
+    "tamal",
+    "tamasopo",
+    "tamaulipas",
+    "tamaulipeca",
+    "tamayo",
+    "tamaño",
+    "tambien",
+    "también",
+    "tambor",
+    "tambores",
+    "tamez",
+    "tampico",
+    "tampoco",
+    "tamps",
+    "tan",
+    "tanasio",
+    "tancanhuitz",
+    "tancredi",
+    "tanda",
+    "tane",
+    "tanganhuato",
+    "tangibles",
+    "tanque",
+    "tanques",
+    "tanta",
+    "tantas",
+    "tanto",
+    "tantos",
+    "tanzanios",
+    "tapa",
+    "tapacalles",
+    "tapachula",
+    "tapar",
+    "tapas",
+    "tapatía",
+    "tapatías",
+    "tapatío",
+    "tapatíos",
+    "tapete",
+    "tapia",
+    "tapicería",
+    "tapitas",
+    "tapiz",
+    "tapizados",
+    "taponaron",
+    "taponeo",
+    "taquería",
+    "taquilla",
+    "taquillas",
+    "taquillera",
+    "taquillero",
+    "taquitos",
+    "tarado",
+    "tarahumara",
+    "tarahumaras",
+    "tarasov",
+    "tarciso",
+    "tarda",
+    "tardamos",
+    "tardan",
+    "tardanza",
+    "tardar",
+    "tardaron",
+    "tardará",
+    "tardarían",
+    "tarde",
+    "tardes",
+    "tardo",
+    "tardándose",
+    "tardé",
+    "tardía",
+    "tardó",
+    "tarea",
+    "tareas",
+    "tarifa",
+    "tarifario",
+    "tarifarios",
+    "tarifas",
+    "tarjeta",
+    "tarjetahabientes",
+    "tarjetas",
+    "tarjetón",
+    "tartajoso",
+    "tasa",
+    "tasas",
+    "tascón",
+    "tasmania",
+    "tataranietos",
+    "tatiana",
+    "tato",
+    "tatuajes",
+    "tauro",
+    "tavares",
+    "taxco",
+    "taxi",
+    "taxis",
+    "taxista",
+    "taxistas",
+    "taza",
+    "tazas",
+    "tazcón",
+    "tazones",
+    "tazón",
+    "te",
+    "teaanque",
+    "teatral",
+    "teatrales",
+    "teatralmente",
+    "teatro",
+    "teatros",
+    "tec",
+    "tecate",
+    "tech",
+    "techado",
+    "techo",
+    "techos",
+    "techumbre",
+    "tecihuatlanque",
+    "tecla",
+    "tecladista",
+    "teclados",
+    "tecnocracia",
+    "tecnología",
+    "tecnologías",
+    "tecnológica",
+    "tecnológicamente",
+    "tecnológicas",
+    "tecnológico",
+    "tecnológicos",
+    "tecnócratas",
+    "tecos",
+    "tecpan",
+    "tecuniapa",
+    "teddy",
+    "teddys",
+    "tedioso",
+    "teherán",
+    "tehuacan",
+    "tehuacán",
+    "tehuitzingo",
+    "teissier",
+    "tejado",
+    "tejas",
+    "tejeda",
+    "tejido",
+    "tejidos",
+    "tel",
+    "tela",
+    "telas",
+    "tele",
+    "telecom",
+    "telecomunicaciones",
+    "telefonistas",
+    "telefonía",
+    "telefónica",
+    "telefónicamente",
+    "telefónicas",
+    "telefónico",
+    "telefónicos",
+    "teleguía",
+    "telehogares",
+    "telenovela",
+    "telenovelas",
+    "telesecundaria",
+    "teleseries",
+    "televicine",
+    "televidente",
+    "televidentes",
+    "televisa",
+    "televisar",
+    "televisiones",
+    "televisiva",
+    "televisivas",
+    "televisivo",
+    "televisivos",
+    "televisión",
+    "televisora",
+    "telex",
+    "tello",
+    "telmex",
+    "teloloapan",
+    "teloloapense",
+    "telones",
+    "teléfono",
+    "teléfonos",
+    "tema",
+    "temas",
+    "temblar",
+    "temblor",
+    "temblores",
+    "teme",
+    "temecula",
+    "temen",
+    "temeraria",
+    "temerariamente",
+    "temerarios",
+    "temeroso",
+    "temible",
+    "temido",
+    "temiendo",
+    "temo",
+    "temor",
+    "temores",
+    "temperamento",
+    "temperatura",
+    "temperaturas",
+    "tempestad",
+    "templado",
+    "templados",
+    "temple",
+    "templete",
+    "templo",
+    "templos",
+    "temporada",
+    "temporadas",
+    "temporales",
+    "temporalmente",
+    "temprana",
+    "tempranamente",
+    "tempranero",
+    "temprano",
+    "tempranos",
+    "temulento",
+    "temática",
+    "temáticas",
+    "temía",
+    "tención",
+    "tendajones",
+    "tendencia",
+    "tendencias",
+    "tenderá",
+    "tenderán",
+    "tendido",
+    "tendientes",
+    "tendimos",
+    "tendió",
+    "tendremos",
+    "tendrá",
+    "tendrán",
+    "tendrás",
+    "tendré",
+    "tendréis",
+    "tendría",
+    "tendríais",
+    "tendríamos",
+    "tendrían",
+    "tendrías",
+    "tendón",
+    "tened",
+    "tenemos",
+    "tenencia",
+    "tenencias",
+    "tener",
+    "tenerife",
+    "tenerlas",
+    "tenessee",
+    "tenga",
+    "tengamos",
+    "tengan",
+    "tengas",
+    "tengo",
+    "tengáis",
+    "tenia",
+    "teniamos",
+    "tenida",
+    "tenidas",
+    "tenido",
+    "tenidos",
+    "teniendo",
+    "teniente",
+    "tenis",
+    "tenistas",
+    "tennessee",
+    "tenochtitlan",
+    "tenor",
+    "tensa",
+    "tensiones",
+    "tensión",
+    "tentación",
+    "tentativa",
+    "tenéis",
+    "tenía",
+    "teníais",
+    "teníamos",
+    "tenían",
+    "tenías",
+    "tenístico",
+    "teo",
+    "teodora",
+    "teodoro",
+    "teología",
+    "teoría",
+    "teorías",
+    "tepanco",
+    "tepatitlán",
+    "tepeaca",
+    "tepehuanes",
+    "tepeyac",
+    "tepic",
+    "tepochcalli",
+    "tepozotlán",
+    "tequila",
+    "tequisquiápan",
+    "terapia",
+    "terapias",
+    "terapéuticas",
+    "terapéuticos",
+    "tercer",
+    "tercera",
+    "terceras",
+    "tercero",
+    "terceros",
+    "tercia",
+    "tercias",
+    "tercio",
+    "terciopelo",
+    "tercios",
+    "teresa",
+    "tergiversado",
+    "termes",
+    "termina",
+    "terminaciones",
+    "terminación",
+    "terminada",
+    "terminadas",
+    "terminado",
+    "terminados",
+    "terminales",
+    "terminamos",
+    "terminan",
+    "terminando",
+    "terminar",
+    "terminarlo",
+    "terminaron",
+    "terminarse",
+    "terminará",
+    "terminarán",
+    "terminaría",
+    "terminarían",
+    "termine",
+    "terminemos",
+    "terminen",
+    "termino",
+    "terminología",
+    "terminó",
+    "termómetro",
+    "terna",
+    "ternura",
+    "terracota",
+    "terranova",
+    "terrateniente",
+    "terratenientes",
+    "terrazas",
+    "terremoto",
+    "terrenales",
+    "terreno",
+    "terrenos",
+    "terrestre",
+    "terribles",
+    "territorial",
+    "territorio",
+    "territorios",
+    "terror",
+    "terrorismo",
+    "terrorista",
+    "terroristas",
+    "terry",
+    "terán",
+    "tesis",
+    "tesitura",
+    "tesorero",
+    "tesoreros",
+    "tesorería",
+    "tesoro",
+    "tesoros",
+    "testigo",
+    "testigos",
+    "testimonial",
+    "testimoniar",
+    "testimonio",
+    "testimonios",
+    "testosterona",
+    "tetela",
+    "tetramestres",
+    "texana",
+    "texano",
+    "texanos",
+    "texas",
+    "texcoco",
+    "textil",
+    "textilera",
+    "textiles",
+    "texto",
+    "textos",
+    "textualmente",
+    "texturas",
+    "tez",
+    "tezoyuca",
+    "teólogos",
+    "teórica",
+    "teóricamente",
+    "teóricas",
+    "teóricos",
+    "thelonius",
+    "theodore",
+    "therese",
+    "thiner",
+    "thompson",
+    "thomson",
+    "thorn",
+    "thriller",
+    "thurlow",
+    "thurman",
+    "ti",
+    "tianguis",
+    "tiberio",
+    "tibet",
+    "tibio",
+    "tichavski",
+    "ticktin",
+    "tiempecito",
+    "tiempo",
+    "tiempos",
+    "tienda",
+    "tiendas",
+    "tiende",
+    "tienden",
+    "tiene",
+    "tienen",
+    "tienes",
+    "tienta",
+    "tiernamente",
+    "tierra",
+    "tierras",
+    "tigre",
+    "tigres",
+    "tigrillas",
+    "tigrillos",
+    "tijerina",
+    "tijuana",
+    "tijuanenses",
+    "tildar",
+    "tim",
+    "timbiriche",
+    "timbre",
+    "timbres",
+    "timonel",
+    "timorato",
+    "timoteo",
+    "timothy",
+    "timón",
+    "tino",
+    "tinta",
+    "tintas",
+    "tinto",
+    "tiosulfato",
+    "tipifica",
+    "tipo",
+    "tipos",
+    "tira",
+    "tirada",
+    "tiradero",
+    "tiraderos",
+    "tirado",
+    "tirados",
+    "tirando",
+    "tirantes",
+    "tirar",
+    "tirarlos",
+    "tirarán",
+    "tiras",
+    "tire",
+    "tiro",
+    "tiroideas",
+    "tiroides",
+    "tiros",
+    "tiroteo",
+    "tiroteos",
+    "tirzo",
+    "tiránica",
+    "tiró",
+    "tisa",
+    "titanic",
+    "titanica",
+    "titanio",
+    "titipuchal",
+    "tito",
+    "titula",
+    "titulada",
+    "titulado",
+    "titular",
+    "titulares",
+    "titularidad",
+    "tituló",
+    "titánica",
+    "tixkokob",
+    "tlacamécatl",
+    "tlacotepec",
+    "tlahuapán",
+    "tlalchapa",
+    "tlalpan",
+    "tlaltenango",
+    "tlamacazques",
+    "tlatelolco",
+    "tlatlahuquitepec",
+    "tlatoani",
+    "tlatoanis",
+    "tlaxcala",
+    "tlaxcalteca",
+    "tlaxcaltecas",
+    "tlc",
+    "tobillo",
+    "tobías",
+    "toca",
+    "tocaba",
+    "tocaban",
+    "tocado",
+    "tocan",
+    "tocar",
+    "tocarle",
+    "tocará",
+    "tocarán",
+    "tocco",
+    "tocó",
+    "toda",
+    "todas",
+    "todavía",
+    "todd",
+    "todo",
+    "todos",
+    "toilette",
+    "toji",
+    "tokio",
+    "toledo",
+    "tolerada",
+    "tolerancia",
+    "tolerando",
+    "tolerante",
+    "tolerantes",
+    "tolerarse",
+    "toletero",
+    "toluca",
+    "toma",
+    "tomaba",
+    "tomaban",
+    "tomada",
+    "tomadas",
+    "tomado",
+    "tomadores",
+    "tomados",
+    "toman",
+    "tomando",
+    "tomar",
+    "tomara",
+    "tomaran",
+    "tomarla",
+    "tomarle",
+    "tomarlo",
+    "tomarnos",
+    "tomaron",
+    "tomarse",
+    "tomará",
+    "tomarán",
+    "tomaría",
+    "tomarían",
+    "tomas",
+    "tomate",
+    "tome",
+    "tomemos",
+    "tomen",
+    "tomlinson",
+    "tomo",
+    "tomándola",
+    "tomás",
+    "tomé",
+    "tomó",
+    "tona",
+    "tonada",
+    "tonalidades",
+    "tonelada",
+    "toneladas",
+    "tonelaje",
+    "toninho",
+    "tono",
+    "tontería",
+    "tonto",
+    "tontos",
+    "toole",
+    "topa",
+    "toparon",
+    "toparse",
+    "topaz",
+    "tope",
+    "topes",
+    "topete",
+    "topeteaba",
+    "topia",
+    "topo",
+    "topografía",
+    "topologías",
+    "toque",
+    "toques",
+    "toqui",
+    "torcer",
+    "toreado",
+    "toreados",
+    "torear",
+    "toreara",
+    "torearlo",
+    "torero",
+    "toreros",
+    "toreándolo",
+    "toreó",
+    "torio",
+    "tormenta",
+    "tormentas",
+    "tornado",
+    "tornados",
+    "tornar",
+    "tornen",
+    "torneo",
+    "torneos",
+    "tornillo",
+    "tornillos",
+    "torniquete",
+    "torno",
+    "toro",
+    "toronto",
+    "toros",
+    "torpedearon",
+    "torpeza",
+    "torrado",
+    "torralba",
+    "torre",
+    "torrencial",
+    "torrenciales",
+    "torrente",
+    "torreon",
+    "torres",
+    "torrescano",
+    "torresmochas",
+    "torreón",
+    "torricelli",
+    "tortilla",
+    "tortillas",
+    "tortillero",
+    "tortuga",
+    "tortugueras",
+    "tortuguismo",
+    "torturados",
+    "torturaron",
+    "torturas",
+    "torturoso",
+    "tortícolis",
+    "torá",
+    "tos",
+    "toscano",
+    "tosferina",
+    "tostado",
+    "tota",
+    "totales",
+    "totalidad",
+    "totalizó",
+    "totalmente",
+    "totopos",
+    "tottenham",
+    "touché",
+    "tovar",
+    "toyota",
+    "toño",
+    "tpc",
+    "tqm",
+    "trabado",
+    "trabaja",
+    "trabajaba",
+    "trabajaban",
+    "trabajada",
+    "trabajado",
+    "trabajador",
+    "trabajadora",
+    "trabajadoras",
+    "trabajadores",
+    "trabajamos",
+    "trabajan",
+    "trabajando",
+    "trabajar",
+    "trabajara",
+    "trabajaron",
+    "trabajará",
+    "trabajarán",
+    "trabajemos",
+    "trabajen",
+    "trabajo",
+    "trabajos",
+    "trabajó",
+    "trabas",
+    "trabó",
+    "tracción",
+    "tracen",
+    "tractor",
+    "tradicional",
+    "tradicionales",
+    "tradicionalmente",
+    "tradiciones",
+    "tradición",
+    "traducciones",
+    "traducción",
+    "traduce",
+    "traducida",
+    "traducido",
+    "traducidos",
+    "traducir",
+    "traducirse",
+    "traduciría",
+    "tradujo",
+    "trae",
+    "traemos",
+    "traen",
+    "traer",
+    "traerla",
+    "traernos",
+    "traerá",
+    "traerán",
+    "traeré",
+    "traería",
+    "traes",
+    "traficante",
+    "trafico",
+    "tragar",
+    "tragarse",
+    "tragedia",
+    "tragos",
+    "trague",
+    "tragó",
+    "traicionado",
+    "traicionados",
+    "traición",
+    "traidor",
+    "traiga",
+    "traigan",
+    "traigo",
+    "trailer",
+    "trailero",
+    "traileros",
+    "trailers",
+    "traje",
+    "trajeron",
+    "trajes",
+    "trajo",
+    "trama",
+    "tramitación",
+    "tramitando",
+    "tramitar",
+    "tramitología",
+    "tramitó",
+    "tramo",
+    "tramos",
+    "trampa",
+    "trampas",
+    "tramposo",
+    "trancas",
+    "trancazo",
+    "trance",
+    "tranquila",
+    "tranquilamente",
+    "tranquilas",
+    "tranquilidad",
+    "tranquilizara",
+    "tranquilizarlo",
+    "tranquilizó",
+    "tranquilo",
+    "tranquilos",
+    "trans",
+    "transa",
+    "transacciones",
+    "transacción",
+    "transamos",
+    "transborda",
+    "transbordador",
+    "transbordadores",
+    "transbordar",
+    "transbordo",
+    "transcribe",
+    "transcurra",
+    "transcurrido",
+    "transcurridos",
+    "transcurriendo",
+    "transcurrieron",
+    "transcurrir",
+    "transcurso",
+    "transero",
+    "transeros",
+    "transexual",
+    "transexuales",
+    "transeúnte",
+    "transeúntes",
+    "transferencia",
+    "transferencias",
+    "transferir",
+    "transfieren",
+    "transfirió",
+    "transformaciones",
+    "transformación",
+    "transformado",
+    "transformador",
+    "transforman",
+    "transformando",
+    "transformar",
+    "transformaren",
+    "transformaron",
+    "transformarse",
+    "transformaría",
+    "transfronterizas",
+    "transfusiones",
+    "transición",
+    "transija",
+    "transita",
+    "transitaban",
+    "transitada",
+    "transitado",
+    "transitados",
+    "transitar",
+    "transitará",
+    "transitarán",
+    "transitorio",
+    "transitó",
+    "transmisiones",
+    "transmisión",
+    "transmita",
+    "transmitan",
+    "transmite",
+    "transmiten",
+    "transmitida",
+    "transmitido",
+    "transmitieran",
+    "transmitieron",
+    "transmitir",
+    "transmitirá",
+    "transmitirán",
+    "transnacional",
+    "transnacionales",
+    "transparencia",
+    "transparentar",
+    "transparentarse",
+    "transparente",
+    "transparentes",
+    "transpeninsular",
+    "transporta",
+    "transportaba",
+    "transportaban",
+    "transportaciones",
+    "transportación",
+    "transportada",
+    "transportadoras",
+    "transportan",
+    "transportando",
+    "transportar",
+    "transportarse",
+    "transportaría",
+    "transporte",
+    "transportes",
+    "transportista",
+    "transportistas",
+    "transversales",
+    "tranvías",
+    "trapeadores",
+    "trapeas",
+    "tras",
+    "trasatlántico",
+    "trascendencia",
+    "trascendental",
+    "trascendentales",
+    "trascender",
+    "trasera",
+    "traseras",
+    "trasero",
+    "traseros",
+    "trasfondo",
+    "trasfondos",
+    "traslación",
+    "traslada",
+    "trasladaba",
+    "trasladado",
+    "trasladados",
+    "trasladar",
+    "trasladarla",
+    "trasladarse",
+    "trasladarán",
+    "traslado",
+    "traslados",
+    "trasladó",
+    "trasládese",
+    "trasmisión",
+    "trasmite",
+    "trasmitirlo",
+    "trasnacional",
+    "trasnacionales",
+    "trasnacionalización",
+    "trasplantados",
+    "trasplante",
+    "trasplantes",
+    "trasporte",
+    "traste",
+    "trasteo",
+    "trastoca",
+    "trastocan",
+    "trastorna",
+    "trastornos",
+    "trastornó",
+    "trata",
+    "trataba",
+    "trataban",
+    "tratada",
+    "tratadas",
+    "tratado",
+    "tratadoras",
+    "tratados",
+    "tratamiento",
+    "tratamientos",
+    "tratamos",
+    "tratan",
+    "tratando",
+    "tratar",
+    "tratara",
+    "trataremos",
+    "trataron",
+    "tratarse",
+    "tratará",
+    "tratarán",
+    "trataría",
+    "tratas",
+    "trate",
+    "traten",
+    "trato",
+    "tratos",
+    "tratándose",
+    "traté",
+    "trató",
+    "traumatismo",
+    "traumatología",
+    "travesaño",
+    "travieso",
+    "través",
+    "trayecto",
+    "trayectoria",
+    "trayendo",
+    "trazadas",
+    "trazado",
+    "trazo",
+    "trazos",
+    "trazó",
+    "traía",
+    "traían",
+    "traídos",
+    "trece",
+    "trecho",
+    "trechos",
+    "tregua",
+    "treinta",
+    "trejo",
+    "tremenda",
+    "tremendamente",
+    "tremendas",
+    "tremendo",
+    "tremendos",
+    "tren",
+    "trenes",
+    "trenza",
+    "trepó",
+    "tres",
+    "tress",
+    "treta",
+    "treto",
+    "trevi",
+    "treviñada",
+    "treviñista",
+    "treviño",
+    "tri",
+    "triana",
+    "tribuna",
+    "tribunal",
+    "tribunales",
+    "tribunas",
+    "tributarias",
+    "tributario",
+    "tributarle",
+    "tributos",
+    "tricampeón",
+    "tricolor",
+    "tricolores",
+    "tridimensional",
+    "trigo",
+    "trigon",
+    "trigésimo",
+    "trilateral",
+    "trillado",
+    "trimestre",
+    "trinchera",
+    "trinidad",
+    "tripa",
+    "tripartita",
+    "tripleta",
+    "triplicaron",
+    "tripulación",
+    "tripulado",
+    "tripulados",
+    "tripulando",
+    "tripulantes",
+    "tripular",
+    "tripulará",
+    "triquiñuela",
+    "tris",
+    "triste",
+    "tristemente",
+    "tristes",
+    "tristeza",
+    "tristán",
+    "tritt",
+    "triunfa",
+    "triunfado",
+    "triunfador",
+    "triunfadora",
+    "triunfadores",
+    "triunfal",
+    "triunfalismo",
+    "triunfalista",
+    "triunfalmente",
+    "triunfantes",
+    "triunfar",
+    "triunfaron",
+    "triunfaría",
+    "triunfo",
+    "triunfos",
+    "trizados",
+    "triángulo",
+    "trofeo",
+    "trofeos",
+    "trolebús",
+    "trompeta",
+    "trompetero",
+    "trompetista",
+    "trompetística",
+    "troncales",
+    "tronco",
+    "troncos",
+    "trono",
+    "tropa",
+    "tropas",
+    "tropezar",
+    "tropezón",
+    "tropicosas",
+    "tropiezos",
+    "trota",
+    "trotamundos",
+    "trotando",
+    "trotar",
+    "trote",
+    "trousseau",
+    "trout",
+    "troy",
+    "troyanos",
+    "truenan",
+    "truene",
+    "trujillo",
+    "truman",
+    "trump",
+    "tryon",
+    "tráfico",
+    "trágica",
+    "trágico",
+    "trámite",
+    "trámites",
+    "tránsito",
+    "trío",
+    "tríos",
+    "tsi",
+    "tsmbién",
+    "tsubame",
+    "tsuru",
+    "tu",
+    "tubacero",
+    "tubería",
+    "tuberías",
+    "tubo",
+    "tubos",
+    "tuercas",
+    "tuerto",
+    "tulancingo",
+    "tules",
+    "tulin",
+    "tull",
+    "tulle",
+    "tum",
+    "tumba",
+    "tumbado",
+    "tumbas",
+    "tumor",
+    "tumoral",
+    "tumores",
+    "tumultuosa",
+    "tunguska",
+    "tupac",
+    "tupamaros",
+    "turbias",
+    "turbina",
+    "turbo",
+    "turcos",
+    "turismo",
+    "turista",
+    "turistas",
+    "turistico",
+    "turna",
+    "turnbull",
+    "turneriano",
+    "turno",
+    "turnos",
+    "turnó",
+    "turquía",
+    "turística",
+    "turísticas",
+    "turístico",
+    "turísticos",
+    "tus",
+    "tutelar",
+    "tuve",
+    "tuviera",
+    "tuvierais",
+    "tuvieran",
+    "tuvieras",
+    "tuvieron",
+    "tuviese",
+    "tuvieseis",
+    "tuviesen",
+    "tuvieses",
+    "tuvimos",
+    "tuviste",
+    "tuvisteis",
+    "tuviéramos",
+    "tuviésemos",
+    "tuvo",
+    "tuxpan",
+    "tuxtla",
+    "tuya",
+    "tuyas",
+    "tuyo",
+    "tuyos",
+    "tuzo",
+    "tuzos",
+    "tv",
+    "tyson",
+    "tácita",
+    "tácitamente",
+    "táctica",
+    "tácticas",
+    "tácticos",
+    "tálamo",
+    "támesis",
+    "té",
+    "técnica",
+    "técnicamente",
+    "técnicas",
+    "técnico",
+    "técnicos",
+    "téllez",
+    "témpano",
+    "térmica",
+    "térmicas",
+    "término",
+    "términos",
+    "térprete",
+    "tés",
+    "tésis",
+    "tía",
+    "tías",
+    "tímida",
+    "tímidamente",
+    "tímido",
+    "tío",
+    "tíos",
+    "típicamente",
+    "típico",
+    "típicos",
+    "título",
+    "títulos",
+    "tómese",
+    "tónica",
+    "tónicos",
+    "tópico",
+    "tópicos",
+    "tórax",
+    "tóxica",
+    "tóxicas",
+    "tóxicos",
+    "tú",
+    "túnel",
+    "túneles",
+    "túnicas",
+    "u",
+    "uag",
+    "uane",
+    "uanl",
+    "uap",
+    "uat",
+    "ubica",
+    "ubicaba",
+    "ubicaban",
+    "ubicación",
+    "ubicada",
+    "ubicadas",
+    "ubicado",
+    "ubicados",
+    "ubican",
+    "ubicando",
+    "ubicar",
+    "ubicara",
+    "ubicarlos",
+    "ubicarnos",
+    "ubicaron",
+    "ubicarse",
+    "ubicará",
+    "ubicarían",
+    "ubicándolo",
+    "ubicársele",
+    "ubicó",
+    "ucalli",
+    "ucayali",
+    "ucimme",
+    "ucrania",
+    "ucraniano",
+    "udeg",
+    "udem",
+    "udinese",
+    "ugalde",
+    "ugarte",
+    "uglilia",
+    "ugocp",
+    "uhagon",
+    "uhf",
+    "ulises",
+    "ullami",
+    "ulloa",
+    "ultimo",
+    "ultimos",
+    "ultra",
+    "ultrajó",
+    "ultranza",
+    "ultravioleta",
+    "umbral",
+    "un",
+    "una",
+    "unacolumna",
+    "unam",
+    "unamitas",
+    "unan",
+    "unanimidad",
+    "unas",
+    "undiano",
+    "une",
+    "unesco",
+    "unforgettable",
+    "unicamente",
+    "unico",
+    "unidad",
+    "unidades",
+    "unidas",
+    "unidimensional",
+    "unido",
+    "unidos",
+    "unieron",
+    "unifamiliares",
+    "unificación",
+    "unificadas",
+    "unificado",
+    "unificar",
+    "uniformada",
+    "uniformado",
+    "uniformados",
+    "uniformar",
+    "uniforme",
+    "uniformes",
+    "unilateral",
+    "unilaterales",
+    "unilateralmente",
+    "unimóvil",
+    "uniones",
+    "unir",
+    "unirse",
+    "unirá",
+    "uniría",
+    "unisex",
+    "unitardos",
+    "unitario",
+    "univ",
+    "universales",
+    "universalmente",
+    "universidad",
+    "universidades",
+    "universitaria",
+    "universitarias",
+    "universitario",
+    "universitarios",
+    "universo",
+    "unió",
+    "unión",
+    "uno",
+    "unos",
+    "unplugged",
+    "unzueta",
+    "unánime",
+    "unánimemente",
+    "unía",
+    "upi",
+    "ur",
+    "urbana",
+    "urbanas",
+    "urbanistas",
+    "urbano",
+    "urbanos",
+    "urbanístico",
+    "urbanísticos",
+    "urbieta",
+    "urdió",
+    "urge",
+    "urgen",
+    "urgencia",
+    "urgencias",
+    "urgente",
+    "urgentes",
+    "urgida",
+    "uribe",
+    "urista",
+    "urna",
+    "urnas",
+    "uro",
+    "urrea",
+    "urss",
+    "ursula",
+    "urtiz",
+    "uruapan",
+    "uruguay",
+    "uruguayo",
+    "urzúa",
+    "usa",
+    "usaba",
+    "usaban",
+    "usada",
+    "usadas",
+    "usado",
+    "usados",
+    "usan",
+    "usando",
+    "usar",
+    "usara",
+    "usarla",
+    "usarlos",
+    "usaron",
+    "usarse",
+    "usará",
+    "usarán",
+    "usen",
+    "usis",
+    "uso",
+    "usos",
+    "usted",
+    "ustedes",
+    "usuales",
+    "usuario",
+    "usuarios",
+    "usura",
+    "usureros",
+    "usurpación",
+    "usó",
+    "utilería",
+    "utilerías",
+    "utilice",
+    "utilidad",
+    "utilidades",
+    "utiliza",
+    "utilizaba",
+    "utilizaban",
+    "utilización",
+    "utilizada",
+    "utilizadas",
+    "utilizado",
+    "utilizados",
+    "utilizamos",
+    "utilizan",
+    "utilizando",
+    "utilizar",
+    "utilizarla",
+    "utilizarlo",
+    "utilizaron",
+    "utilizarse",
+    "utilizará",
+    "utilizarán",
+    "utilizó",
+    "utopía",
+    "utopías",
+    "utópicas",
+    "uy",
+    "uñas",
+    "v",
+    "va",
+    "vaca",
+    "vacacionar",
+    "vacaciones",
+    "vacas",
+    "vaciado",
+    "vacila",
+    "vacilada",
+    "vacilan",
+    "vacilando",
+    "vacilante",
+    "vacilar",
+    "vaciló",
+    "vacilón",
+    "vació",
+    "vacuna",
+    "vacunación",
+    "vacunas",
+    "vacía",
+    "vacías",
+    "vacío",
+    "vacíos",
+    "vado",
+    "vagabundo",
+    "vagina",
+    "vagones",
+    "vagoneta",
+    "vagos",
+    "vaivenes",
+    "vajilla",
+    "valde",
+    "valdez",
+    "valdo",
+    "valdrá",
+    "valdría",
+    "valdés",
+    "vale",
+    "valedores",
+    "valegorrista",
+    "valemos",
+    "valen",
+    "valencia",
+    "valens",
+    "valente",
+    "valentia",
+    "valentino",
+    "valentía",
+    "valentín",
+    "valenzuela",
+    "valer",
+    "valeri",
+    "valero",
+    "valet",
+    "valga",
+    "valgan",
+    "validez",
+    "valido",
+    "valiente",
+    "valieron",
+    "valiosa",
+    "valiosas",
+    "valioso",
+    "valiosos",
+    "valium",
+    "valió",
+    "valla",
+    "valladares",
+    "vallarta",
+    "vallas",
+    "valle",
+    "vallecano",
+    "vallecillo",
+    "vallecillos",
+    "vallejo",
+    "valles",
+    "valley",
+    "valor",
+    "valora",
+    "valoraba",
+    "valoración",
+    "valoradas",
+    "valorado",
+    "valorados",
+    "valorar",
+    "valorarlo",
+    "valoraron",
+    "valore",
+    "valores",
+    "valorizada",
+    "valparaíso",
+    "valtierra",
+    "valuación",
+    "valuadas",
+    "valía",
+    "vamos",
+    "vampiro",
+    "vampiros",
+    "van",
+    "vandalismo",
+    "vanderley",
+    "vandálicos",
+    "vanette",
+    "vanguardia",
+    "vanguardista",
+    "vanguardistas",
+    "vanidad",
+    "vanidades",
+    "vanilli",
+    "vanity",
+    "vano",
+    "vanos",
+    "vapor",
+    "vaporizaciones",
+    "vaporizarlas",
+    "vaporosa",
+    "vaporosos",
+    "vaquera",
+    "vaquero",
+    "vaqueros",
+    "vaquerías",
+    "varada",
+    "varados",
+    "varela",
+    "vargas",
+    "variaciones",
+    "variación",
+    "variada",
+    "variado",
+    "variados",
+    "variando",
+    "variantes",
+    "varias",
+    "varicela",
+    "variedad",
+    "variedades",
+    "variopinta",
+    "varios",
+    "varita",
+    "varones",
+    "varonil",
+    "varoniles",
+    "varía",
+    "varían",
+    "varón",
+    "vas",
+    "vasco",
+    "vasconcelos",
+    "vaso",
+    "vasos",
+    "vasto",
+    "vaticano",
+    "vaticinado",
+    "vaticinar",
+    "vaticinaron",
+    "vaticinen",
+    "vaticinios",
+    "vaticinó",
+    "vax",
+    "vaxstations",
+    "vaya",
+    "vayamos",
+    "vayan",
+    "vazquez",
+    "vcr",
+    "ve",
+    "vea",
+    "veamos",
+    "vean",
+    "veces",
+    "vecina",
+    "vecinal",
+    "vecinales",
+    "vecinas",
+    "vecindad",
+    "vecindario",
+    "vecino",
+    "vecinos",
+    "vega",
+    "vegetación",
+    "vegetales",
+    "vegetarianismo",
+    "vegetarianos",
+    "vegetativo",
+    "vehemencia",
+    "vehemente",
+    "vehicular",
+    "vehiculares",
+    "vehículo",
+    "vehículos",
+    "veinte",
+    "veinteañeros",
+    "veintena",
+    "veinticinco",
+    "veintiseis",
+    "vejarrón",
+    "vejez",
+    "vela",
+    "velada",
+    "velador",
+    "velando",
+    "velarde",
+    "velas",
+    "velasco",
+    "velero",
+    "vellones",
+    "velocidad",
+    "velocidades",
+    "velocímetro",
+    "velos",
+    "veloso",
+    "velázquez",
+    "vemos",
+    "ven",
+    "vena",
+    "venado",
+    "vence",
+    "vencedor",
+    "vencedores",
+    "vencer",
+    "vencerse",
+    "vencerás",
+    "vencida",
+    "vencidas",
+    "vencido",
+    "vencidos",
+    "vencieron",
+    "vencimiento",
+    "vencimientos",
+    "venció",
+    "venda",
+    "vendados",
+    "vendan",
+    "vendaron",
+    "vende",
+    "vendedor",
+    "vendedora",
+    "vendedores",
+    "vendemos",
+    "venden",
+    "vender",
+    "venderemos",
+    "venderla",
+    "venderlo",
+    "venderse",
+    "venderá",
+    "venderán",
+    "vendería",
+    "vendida",
+    "vendidas",
+    "vendido",
+    "vendidos",
+    "vendiendo",
+    "vendiera",
+    "vendieron",
+    "vendiéndose",
+    "vendió",
+    "vendo",
+    "vendrá",
+    "vendrán",
+    "vendía",
+    "venegas",
+    "veneno",
+    "veneraban",
+    "veneración",
+    "venezolana",
+    "venezolano",
+    "venezolanos",
+    "venezuela",
+    "venga",
+    "vengadores",
+    "vengan",
+    "venganza",
+    "vengo",
+    "venicio",
+    "venida",
+    "venido",
+    "venidos",
+    "venimos",
+    "venir",
+    "venirse",
+    "venta",
+    "ventaja",
+    "ventajas",
+    "ventajosa",
+    "ventajosas",
+    "ventana",
+    "ventanas",
+    "ventanilla",
+    "ventas",
+    "ventila",
+    "ventilación",
+    "ventilar",
+    "ventilaron",
+    "ventilas",
+    "ventre",
+    "ventura",
+    "venus",
+    "venustiano",
+    "venía",
+    "venían",
+    "veo",
+    "ver",
+    "vera",
+    "veracruz",
+    "veracruzana",
+    "veracruzano",
+    "veracruzanos",
+    "verano",
+    "veras",
+    "verbal",
+    "verbales",
+    "verbalista",
+    "verbalmente",
+    "verbo",
+    "verdad",
+    "verdadera",
+    "verdaderamente",
+    "verdaderas",
+    "verdadero",
+    "verdaderos",
+    "verdades",
+    "verde",
+    "verdes",
+    "verdirame",
+    "verdugo",
+    "verduras",
+    "verduzco",
+    "veredalta",
+    "veredas",
+    "veredicto",
+    "veremos",
+    "vergonzante",
+    "vergonzosos",
+    "vergüenza",
+    "verificaba",
+    "verificación",
+    "verifican",
+    "verificar",
+    "verificaron",
+    "verificó",
+    "verla",
+    "verlas",
+    "verlo",
+    "verlos",
+    "verme",
+    "verrugas",
+    "versace",
+    "versatilidad",
+    "verse",
+    "versificación",
+    "versiones",
+    "versión",
+    "versos",
+    "versátil",
+    "verte",
+    "vertebral",
+    "vertida",
+    "vertidas",
+    "vertido",
+    "vertientes",
+    "verá",
+    "verán",
+    "veré",
+    "vería",
+    "veríamos",
+    "verónica",
+    "verónicas",
+    "ves",
+    "vespertino",
+    "vestida",
+    "vestido",
+    "vestidos",
+    "vestidura",
+    "vestiduras",
+    "vestigio",
+    "vestimos",
+    "vestir",
+    "vestirse",
+    "vestuario",
+    "vestía",
+    "vetado",
+    "vetará",
+    "veterano",
+    "veteranos",
+    "veterinarias",
+    "veto",
+    "vetó",
+    "vez",
+    "veía",
+    "veían",
+    "vi",
+    "viabilidad",
+    "viable",
+    "viaducto",
+    "viaja",
+    "viajaba",
+    "viajaban",
+    "viajado",
+    "viajan",
+    "viajar",
+    "viajaron",
+    "viajará",
+    "viajarán",
+    "viajarían",
+    "viaje",
+    "viajen",
+    "viajero",
+    "viajeros",
+    "viajes",
+    "viajó",
+    "vial",
+    "viales",
+    "vialidad",
+    "vialidades",
+    "viandas",
+    "vianey",
+    "vibra",
+    "vibraciones",
+    "vibradores",
+    "vibrar",
+    "vicario",
+    "vicecanciller",
+    "vicente",
+    "vicepresidencia",
+    "vicepresidente",
+    "viceversa",
+    "vicio",
+    "vicios",
+    "viciosas",
+    "vicky",
+    "victimado",
+    "victoria",
+    "victoriana",
+    "victoriano",
+    "victorias",
+    "victorioso",
+    "victoriosos",
+    "vida",
+    "vidal",
+    "vidales",
+    "vidas",
+    "vidente",
+    "videntes",
+    "videocasetera",
+    "videocasetes",
+    "videocentros",
+    "videoclip",
+    "videoclubes",
+    "videohomes",
+    "videomax",
+    "videopelícula",
+    "videos",
+    "vidrio",
+    "vidrios",
+    "vieira",
+    "vieja",
+    "viejas",
+    "viejecita",
+    "viejita",
+    "viejito",
+    "viejitos",
+    "viejo",
+    "viejos",
+    "viejón",
+    "viena",
+    "viendo",
+    "viendose",
+    "viene",
+    "vienen",
+    "viento",
+    "vientos",
+    "vientre",
+    "vientres",
+    "viera",
+    "viernes",
+    "vieron",
+    "viesca",
+    "vietnam",
+    "vigas",
+    "vigencia",
+    "vigente",
+    "vigentes",
+    "vigil",
+    "vigila",
+    "vigilaban",
+    "vigilada",
+    "vigiladas",
+    "vigilado",
+    "vigilados",
+    "vigilancia",
+    "vigilante",
+    "vigilantes",
+    "vigilar",
+    "vigilarlas",
+    "vigilará",
+    "vigile",
+    "vigilen",
+    "vigilia",
+    "vigo",
+    "vigor",
+    "vigoroso",
+    "vigésima",
+    "vigésimacuarta",
+    "vigésimo",
+    "viii",
+    "villa",
+    "villacaña",
+    "villada",
+    "villagrán",
+    "villagómez",
+    "villahermosa",
+    "villalba",
+    "villaldama",
+    "villalobos",
+    "villamar",
+    "villanueva",
+    "villar",
+    "villarreal",
+    "villasana",
+    "villaseñor",
+    "villauto",
+    "villegas",
+    "villuendas",
+    "villán",
+    "vimos",
+    "vimosa",
+    "vincent",
+    "vincula",
+    "vinculación",
+    "vinculadas",
+    "vinculado",
+    "vinculados",
+    "vincular",
+    "vincularse",
+    "vinculo",
+    "vine",
+    "viniendo",
+    "viniera",
+    "vinieran",
+    "vinieron",
+    "vino",
+    "vinyard",
+    "vio",
+    "violaciones",
+    "violación",
+    "violada",
+    "violado",
+    "violador",
+    "violadores",
+    "violan",
+    "violando",
+    "violar",
+    "violatorias",
+    "violencia",
+    "violenta",
+    "violentada",
+    "violentamente",
+    "violentaría",
+    "violentas",
+    "violento",
+    "violentos",
+    "violeta",
+    "violetina",
+    "violinista",
+    "violín",
+    "violó",
+    "vips",
+    "virgen",
+    "virginia",
+    "virilidad",
+    "virreinato",
+    "virtud",
+    "virtudes",
+    "virtuosa",
+    "virtuosos",
+    "virulentos",
+    "visa",
+    "visag",
+    "viscatán",
+    "viscencio",
+    "viscerales",
+    "visibilidad",
+    "visiblemente",
+    "visionarios",
+    "visiones",
+    "visires",
+    "visita",
+    "visitaba",
+    "visitadas",
+    "visitado",
+    "visitaduría",
+    "visitan",
+    "visitando",
+    "visitante",
+    "visitantes",
+    "visitar",
+    "visitara",
+    "visitarla",
+    "visitaron",
+    "visitará",
+    "visitarán",
+    "visitaría",
+    "visitas",
+    "visite",
+    "visiten",
+    "visiteo",
+    "visitiendo",
+    "visitó",
+    "visión",
+    "viskin",
+    "vislumbra",
+    "vislumbran",
+    "vislumbrar",
+    "vislumbraron",
+    "vislumbró",
+    "visora",
+    "visos",
+    "vista",
+    "vistas",
+    "vistazo",
+    "visten",
+    "vistiendo",
+    "vistió",
+    "visto",
+    "vistos",
+    "vistoso",
+    "vistosos",
+    "visuales",
+    "visualice",
+    "visualizaban",
+    "visualización",
+    "visualizado",
+    "visualizar",
+    "visualmente",
+    "vital",
+    "vitales",
+    "vitalicio",
+    "vitalidad",
+    "vitamina",
+    "vitaminas",
+    "vite",
+    "vito",
+    "vitro",
+    "vitroles",
+    "vittorio",
+    "vituperado",
+    "vituperados",
+    "viuda",
+    "viudas",
+    "viudo",
+    "viva",
+    "vivales",
+    "vivan",
+    "vivas",
+    "vive",
+    "viven",
+    "vivero",
+    "vivible",
+    "vivido",
+    "vividos",
+    "vivienda",
+    "viviendas",
+    "viviendo",
+    "viviente",
+    "vivientes",
+    "viviera",
+    "vivieran",
+    "vivieron",
+    "vivillos",
+    "vivimos",
+    "vivir",
+    "vivirse",
+    "vivirá",
+    "vivió",
+    "vivo",
+    "vivos",
+    "viví",
+    "vivía",
+    "vivíamos",
+    "vivían",
+    "viña",
+    "vl",
+    "vladimir",
+    "vocabulario",
+    "vocacional",
+    "vocaciones",
+    "vocación",
+    "vocal",
+    "vocalista",
+    "vocalía",
+    "vocera",
+    "vocero",
+    "voceros",
+    "voces",
+    "voices",
+    "voladeros",
+    "volando",
+    "volante",
+    "volantes",
+    "volar",
+    "volará",
+    "volaría",
+    "volatilidad",
+    "volcadura",
+    "volcamos",
+    "volcanes",
+    "volcar",
+    "volcaron",
+    "volcán",
+    "volcánica",
+    "volcánico",
+    "volcó",
+    "voleibol",
+    "volga",
+    "volkswagen",
+    "volmer",
+    "voltaire",
+    "voltea",
+    "voltear",
+    "volteretas",
+    "volteó",
+    "voltios",
+    "volts",
+    "voluble",
+    "volumen",
+    "volumenes",
+    "voluminosísima",
+    "voluntad",
+    "voluntades",
+    "voluntaria",
+    "voluntariado",
+    "voluntariamente",
+    "voluntarias",
+    "voluntario",
+    "voluntarios",
+    "voluntarioso",
+    "volvamos",
+    "volver",
+    "volverlas",
+    "volverse",
+    "volverá",
+    "volverán",
+    "volveré",
+    "volvería",
+    "volviendo",
+    "volvieron",
+    "volvimos",
+    "volvió",
+    "volvo",
+    "volvoramírez",
+    "volvía",
+    "volátil",
+    "volátiles",
+    "volúmenes",
+    "vomitar",
+    "von",
+    "vorazmente",
+    "vosotras",
+    "vosotros",
+    "vosso",
+    "vota",
+    "votaciones",
+    "votación",
+    "votantes",
+    "votar",
+    "votara",
+    "votaron",
+    "votarse",
+    "votará",
+    "votarán",
+    "votaría",
+    "vote",
+    "voten",
+    "voto",
+    "votos",
+    "votó",
+    "voy",
+    "voz",
+    "vs",
+    "vudú",
+    "vuelas",
+    "vuelca",
+    "vuele",
+    "vuelo",
+    "vuelos",
+    "vuelta",
+    "vueltas",
+    "vuelto",
+    "vuelva",
+    "vuelvan",
+    "vuelve",
+    "vuelven",
+    "vuelvo",
+    "vuestra",
+    "vuestras",
+    "vuestro",
+    "vuestros",
+    "vulcanología",
+    "vulcanólogos",
+    "vulgar",
+    "vulgares",
+    "vulgaridad",
+    "vulgo",
+    "vulnerabilidad",
+    "vulnerable",
+    "vulnerables",
+    "vulneran",
+    "vw",
+    "válido",
+    "válvulas",
+    "vánitas",
+    "vásquez",
+    "vázquez",
+    "vélez",
+    "vía",
+    "vías",
+    "víbora",
+    "vícitma",
+    "víctima",
+    "víctimas",
+    "víctor",
+    "vínculo",
+    "vínculos",
+    "vírgenes",
+    "víspera",
+    "vísperas",
+    "vítores",
+    "víveres",
+    "vómitos",
+    "w",
+    "wa",
+    "wal",
+    "walberto",
+    "walcott",
+    "wallace",
+    "walt",
+    "walterio",
+    "walton",
+    "ward",
+    "warner",
+    "warrants",
+    "warren",
+    "washington",
+    "waterer",
+    "weisackaer",
+    "weiss",
+    "welch",
+    "wells",
+    "welsh",
+    "wenceslao",
+    "wendy",
+    "western",
+    "westh",
+    "westinghouse",
+    "wetherell",
+    "weule",
+    "whataburger",
+    "whisky",
+    "widnall",
+    "wiesel",
+    "wildlife",
+    "wilford",
+    "williamson",
+    "willie",
+    "winfield",
+    "winston",
+    "winstone",
+    "wisconsin",
+    "woldenberg",
+    "wong",
+    "woody",
+    "worcester",
+    "worldnet",
+    "x",
+    "xavier",
+    "xenotransplantes",
+    "xenotrasplante",
+    "xenotrasplantes",
+    "xicoténcatl",
+    "xiii",
+    "xilitla",
+    "xiv",
+    "xix",
+    "xochimilco",
+    "xochitl",
+    "xv",
+    "xvi",
+    "xvii",
+    "xviii",
+    "xx",
+    "xxi",
+    "xxiii",
+    "xxvi",
+    "xóchitl",
+    "y",
+    "ya",
+    "yacimiento",
+    "yacimientos",
+    "yacía",
+    "yanni",
+    "yardas",
+    "yarmuch",
+    "yaroslao",
+    "yate",
+    "yañez",
+    "ycosas",
+    "yeltsin",
+    "yendo",
+    "yerbaniz",
+    "yerena",
+    "yerno",
+    "yeso",
+    "yidish",
+    "ylizaliturri",
+    "yo",
+    "yodo",
+    "yoga",
+    "yogurt",
+    "yolanda",
+    "yoltectl",
+    "yonke",
+    "yonquero",
+    "yorky",
+    "youshimatz",
+    "yrapuato",
+    "yuc",
+    "yucateca",
+    "yucateco",
+    "yucatecos",
+    "yucatán",
+    "yugoslavia",
+    "yugoslavo",
+    "yukio",
+    "yunez",
+    "yunta",
+    "yuri",
+    "yuxtapuesto",
+    "yvonne",
+    "zabludowsky",
+    "zacarías",
+    "zacatecas",
+    "zacatepec",
+    "zafra",
+    "zag",
+    "zaga",
+    "zagas",
+    "zague",
+    "zaguero",
+    "zagueros",
+    "zahnle",
+    "zaid",
+    "zaire",
+    "zalea",
+    "zambrano",
+    "zamora",
+    "zamorano",
+    "zamudio",
+    "zanabria",
+    "zancadilla",
+    "zancudos",
+    "zanja",
+    "zanjas",
+    "zapalinamé",
+    "zapata",
+    "zapatera",
+    "zapatilla",
+    "zapatitos",
+    "zapatos",
+    "zapoteco",
+    "zar",
+    "zaragoza",
+    "zarape",
+    "zarapeados",
+    "zarazúa",
+    "zarco",
+    "zarpó",
+    "ze",
+    "zedillo",
+    "zelanda",
+    "zeltún",
+    "zenith",
+    "zenón",
+    "zepa",
+    "zepeda",
+    "zertuche",
+    "zig",
+    "zimmerman",
+    "zinser",
+    "zona",
+    "zonas",
+    "zoológico",
+    "zoológicos",
+    "zorkin",
+    "zorrilla",
+    "zorros",
+    "zotoluca",
+    "zotoluco",
+    "zotolucos",
+    "zuazua",
+    "zubillaga",
+    "zubizarreta",
+    "zulia",
+    "zurcos",
+    "zurda",
+    "zurdo",
+    "zurdos",
+    "zurita",
+    "zutano",
+    "zárate",
+    "zínser",
+    "zúñiga",
+    "ácida",
+    "ácidas",
+    "ácido",
+    "ácidos",
+    "ágil",
+    "águila",
+    "álbum",
+    "álgebra",
+    "ámbito",
+    "ámbitos",
+    "ánfora",
+    "ánforas",
+    "ángel",
+    "ángeles",
+    "ángulo",
+    "ánimo",
+    "ánimos",
+    "árabe",
+    "árabes",
+    "árbitro",
+    "árbitros",
+    "árbol",
+    "árboles",
+    "área",
+    "áreas",
+    "ásperamente",
+    "áspero",
+    "ávida",
+    "ávido",
+    "échate",
+    "égida",
+    "él",
+    "élite",
+    "énfasis",
+    "época",
+    "épocas",
+    "éramos",
+    "ésa",
+    "ésas",
+    "ése",
+    "ésos",
+    "ésta",
+    "éstas",
+    "éste",
+    "ésto",
+    "éstos",
+    "ética",
+    "éticas",
+    "étnica",
+    "étnicas",
+    "étnico",
+    "éxito",
+    "éxitos",
+    "éxodo",
+    "íbamos",
+    "ídem",
+    "ídolo",
+    "ídolos",
+    "índice",
+    "índices",
+    "índigo",
+    "índole",
+    "íntegramente",
+    "íntegro",
+    "íntima",
+    "íntimas",
+    "íntimo",
+    "íntimos",
+    "ñico",
+    "ó",
+    "óleo",
+    "ómnibus",
+    "ópalos",
+    "ópera",
+    "óptica",
+    "ópticas",
+    "óptico",
+    "óptimo",
+    "órbita",
+    "órdenes",
+    "órgano",
+    "órganos",
+    "óxidos",
+    "úlcera",
+    "última",
+    "últimamente",
+    "últimas",
+    "último",
+    "últimos",
+    "única",
+    "únicamente",
+    "únicas",
+    "único",
+    "únicos",
+    "útero",
+    "útil",
+};
@@ -0,0 +1,142 @@
+/// Leetspeak substitutions, most likely reading first
+const LEET_MAP: &[(char, &[char])] = &[
+    ('0', &['o']),
+    ('1', &['i', 'l']),
+    ('3', &['e']),
+    ('4', &['a']),
+    ('5', &['s']),
+    ('7', &['t']),
+    ('8', &['b']),
+    ('9', &['g']),
+    ('@', &['a']),
+    ('$', &['s']),
+    ('!', &['i']),
+    ('|', &['l']),
+    ('+', &['t']),
+];
+
+/// Ambiguous characters beyond this many only use their most likely reading,
+/// which keeps the number of readings at most 2^6
+const MAX_AMBIGUOUS: usize = 6;
+
+fn substitutes(c: char) -> Option<&'static [char]> {
+    LEET_MAP
+        .iter()
+        .find(|(leet, _)| *leet == c)
+        .map(|(_, letters)| *letters)
+}
+
+/// Whether the character at `index` stands in for a letter.
+///
+/// Digits always do. Symbols only do next to a letter or digit, so that
+/// ordinary punctuation such as a trailing "!" is left alone.
+fn is_substituted(chars: &[char], index: usize) -> bool {
+    let c = chars[index];
+    if substitutes(c).is_none() {
+        return false;
+    }
+    if c.is_ascii_digit() {
+        return true;
+    }
+
+    let is_word_char = |i: usize| {
+        chars
+            .get(i)
+            .is_some_and(|&c| c.is_alphanumeric() || substitutes(c).is_some())
+    };
+    let before = index > 0 && chars[index - 1].is_alphanumeric();
+    let after = is_word_char(index + 1);
+    if c == '!' {
+        after
+    } else {
+        before || after
+    }
+}
+
+/// Possible plain readings of leetspeak text, e.g. "h3ll0" reads as "hello".
+///
+/// Digits and symbols commonly used in place of letters (`3` for `e`, `0` for
+/// `o`, `@` for `a`, `$` for `s`, ...) are replaced. Characters with several
+/// readings, such as `1` for `i` or `l`, produce one reading per choice, most
+/// likely first. Returns an empty list if nothing in the text looks like leet.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::deleet;
+///
+/// assert_eq!(deleet("h3ll0 w0rld")[0], "hello world");
+/// assert!(deleet("1337").contains(&"leet".to_string()));
+/// assert!(deleet("plain text!").is_empty());
+/// ```
+pub fn deleet(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let positions: Vec<usize> = (0..chars.len())
+        .filter(|&index| is_substituted(&chars, index))
+        .collect();
+    if positions.is_empty() {
+        return Vec::new();
+    }
+
+    let mut readings = vec![chars];
+    let mut ambiguous = 0;
+    for &index in &positions {
+        let mut letters = substitutes(readings[0][index]).unwrap();
+        if letters.len() > 1 {
+            if ambiguous == MAX_AMBIGUOUS {
+                letters = &letters[..1];
+            } else {
+                ambiguous += 1;
+            }
+        }
+        readings = readings
+            .into_iter()
+            .flat_map(|reading| {
+                letters.iter().map(move |&letter| {
+                    let mut reading = reading.clone();
+                    reading[index] = letter;
+                    reading
+                })
+            })
+            .collect();
+    }
+
+    readings
+        .into_iter()
+        .map(|reading| reading.into_iter().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deleet() {
+        assert_eq!(deleet("p@$$w0rd")[0], "password");
+        assert_eq!(deleet("h3ll0_w0rld_1337")[0], "hello_world_ieet");
+        assert!(deleet("h3ll0_w0rld_1337").contains(&"hello_world_leet".to_string()));
+    }
+
+    #[test]
+    fn test_punctuation_is_kept() {
+        assert_eq!(deleet("h3y!")[0], "hey!");
+        assert_eq!(deleet("n1ce @ home")[0], "nice @ home");
+    }
+
+    #[test]
+    fn test_reading_count_is_bounded() {
+        let readings = deleet("1111111111");
+        assert_eq!(readings.len(), 1 << MAX_AMBIGUOUS);
+    }
+
+    #[test]
+    fn test_only_ambiguous_characters_are_bounded() {
+        // Seven unambiguous substitutions before the "1" still leave it both readings
+        let readings = deleet("h3ll0 w0rld 3v3ry0n3 1");
+        assert_eq!(
+            readings,
+            ["hello world everyone i", "hello world everyone l"]
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::language::{English, Language, LanguageId};
+use crate::stem;
+
+/// The language's dictionary with a detector's extra and denied words layered on top.
+///
+/// Every word-based feature looks words up through this, so domain vocabulary
+/// added at runtime counts everywhere the built-in dictionary does.
+#[derive(Debug, Clone)]
+pub(crate) struct Lexicon {
+    /// The language whose dictionary is used
+    language: Arc<dyn Language>,
+    /// Lowercased words added at runtime
+    extra: HashSet<String>,
+    /// Lowercased words that never count as English, even if in the dictionary
+    denied: HashSet<String>,
+}
+
+impl Default for Lexicon {
+    fn default() -> Self {
+        Self {
+            language: Arc::new(English),
+            extra: HashSet::new(),
+            denied: HashSet::new(),
+        }
+    }
+}
+
+impl Lexicon {
+    pub(crate) fn language(&self) -> &dyn Language {
+        self.language.as_ref()
+    }
+
+    pub(crate) fn set_language(&mut self, language: Arc<dyn Language>) {
+        self.language = language;
+    }
+
+    /// A copy of this lexicon that looks words up in another language
+    pub(crate) fn with_language(&self, language: Arc<dyn Language>) -> Self {
+        Self {
+            language,
+            ..self.clone()
+        }
+    }
+
+    /// Returns true for English, the only language with stemming, typo
+    /// tolerance, segmentation and word order data
+    pub(crate) fn is_english(&self) -> bool {
+        self.language.id() == LanguageId::English
+    }
+
+    pub(crate) fn add_words<I, S>(&mut self, words: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.extra.extend(
+            words
+                .into_iter()
+                .map(|word| word.as_ref().trim().to_lowercase())
+                .filter(|word| !word.is_empty()),
+        );
+    }
+
+    pub(crate) fn deny_words<I, S>(&mut self, words: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.denied.extend(
+            words
+                .into_iter()
+                .map(|word| word.as_ref().trim().to_lowercase())
+                .filter(|word| !word.is_empty()),
+        );
+    }
+
+    /// Returns true if the word was added at runtime and is not denied
+    pub(crate) fn is_extra(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        self.extra.contains(&word) && !self.denied.contains(&word)
+    }
+
+    pub(crate) fn is_denied(&self, word: &str) -> bool {
+        !self.denied.is_empty() && self.denied.contains(&word.to_lowercase())
+    }
+
+    /// Extra words, for features that search the whole vocabulary
+    pub(crate) fn extra_words(&self) -> impl Iterator<Item = &str> {
+        self.extra
+            .iter()
+            .filter(|word| !self.denied.contains(*word))
+            .map(String::as_str)
+    }
+
+    /// Returns true if the word is in the dictionary or was added, and is not denied
+    pub(crate) fn contains(&self, word: &str) -> bool {
+        if self.is_denied(word) {
+            return false;
+        }
+        self.language.contains_word(word) || self.is_extra(word)
+    }
+
+    /// Frequency weight of a known word, see [`Language::word_weight`].
+    ///
+    /// Added words carry no frequency, and count fully since the caller chose them.
+    pub(crate) fn weight(&self, word: &str) -> Option<f64> {
+        if self.is_denied(word) {
+            None
+        } else if self.is_extra(word) {
+            Some(1.0)
+        } else {
+            self.language.word_weight(word)
+        }
+    }
+
+    /// Returns true if the word or its uninflected base form is known
+    pub(crate) fn is_english_word(&self, word: &str) -> bool {
+        self.contains(word) || (self.is_english() && stem::lemmatize_in(word, self).is_some())
+    }
+}
+
+/// Read a word list with one word per line, skipping blank lines and `#` comments
+pub(crate) fn read_word_file(path: &Path) -> io::Result<Vec<String>> {
+    let text = std::fs::read_to_string(path)?;
+    Ok(text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extra_words() {
+        let mut lexicon = Lexicon::default();
+        assert!(!lexicon.contains("kubectl"));
+
+        lexicon.add_words(["Kubectl"]);
+        assert!(lexicon.contains("kubectl"));
+        assert!(lexicon.contains("KUBECTL"));
+        assert_eq!(lexicon.weight("kubectl"), Some(1.0));
+    }
+
+    #[test]
+    fn test_denied_words_win() {
+        let mut lexicon = Lexicon::default();
+        lexicon.add_words(["foo"]);
+        lexicon.deny_words(["foo", "Census"]);
+        assert!(!lexicon.contains("foo"));
+        assert!(!lexicon.contains("census"));
+        assert_eq!(lexicon.weight("census"), None);
+        assert_eq!(lexicon.extra_words().count(), 0);
+    }
+
+    #[test]
+    fn test_inflections_of_extra_words() {
+        let mut lexicon = Lexicon::default();
+        lexicon.add_words(["kubectl"]);
+        assert!(lexicon.is_english_word("kubectls"));
+    }
+}
@@ -1,17 +1,84 @@
-use phf::phf_set;
+use crib::find_crib;
+use lexicon::Lexicon;
+use std::borrow::Cow;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
+mod bigrams;
+mod bytes;
+mod cipher;
+mod classes;
 #[doc(hidden)]
 mod cli;
+mod confusables;
+mod crib;
 mod dictionary;
+mod encodings;
+mod frequencies;
+mod fuzzy;
+mod hunspell;
+mod invisible;
+mod isomorph;
+mod language;
+mod leet;
+mod lexicon;
 mod model;
+mod mojibake;
+mod normalize;
 mod passwords;
+mod quality;
+mod rank;
+mod script;
+mod segment;
+mod sequence;
+mod spans;
+mod stem;
+mod strings;
+mod words;
 
 // Core library exports
+pub use bytes::{decode_bytes, Decoded, EncodingDetection};
+pub use cipher::{atbash, caesar_shift, detect_atbash, detect_caesar, detect_reversed};
+pub use confusables::{unconfuse, Substitution, Unconfused};
+pub use crib::{Crib, CribEffect, CribHit};
+pub use encodings::{identify_encodings, Encoding};
+pub use fuzzy::{fuzzy_lookup, FuzzyMatch};
+pub use hunspell::{HunspellDictionary, HunspellError};
+pub use invisible::{invisible_characters, strip_invisible, InvisibleCategory};
+pub use isomorph::{
+    isomorph_report, pattern_candidates, word_pattern, IsomorphReport, WordCandidates,
+};
+#[cfg(feature = "arabic")]
+pub use language::Arabic;
+#[cfg(feature = "french")]
+pub use language::French;
+#[cfg(feature = "german")]
+pub use language::German;
+#[cfg(feature = "greek")]
+pub use language::Greek;
+#[cfg(feature = "russian")]
+pub use language::Russian;
+#[cfg(feature = "spanish")]
+pub use language::Spanish;
+pub use language::{
+    identify_language, is_gibberish_in_every_language, English, Language, LanguageId,
+};
+pub use leet::deleet;
 pub use model::{
     check_token_status, default_model_path, download_model, download_model_with_progress_bar,
     model_exists, ModelError, TokenStatus,
 };
+pub use mojibake::{detect_mojibake, Repair};
+pub use normalize::Normalization;
+pub use quality::{GraphemeClass, TextQuality};
+pub use rank::Ranked;
+pub use script::{dominant_script, mixed_script_tokens, Script};
+pub use segment::{segment, Segmentation};
+pub use sequence::word_sequence_plausibility;
+pub use stem::lemmatize;
+pub use strings::{english_strings, EnglishStrings, ExtractedString, StringEncoding};
+pub use words::{lookup_word, WordInfo};
 
 // CLI utilities made available for binary integration, but hidden from docs
 #[doc(hidden)]
@@ -38,19 +105,340 @@ pub enum Sensitivity {
 /// Gibberish detector with optional enhanced model
 pub struct GibberishDetector {
     model_path: Option<PathBuf>,
+    cribs: Vec<Crib>,
+    crib_effect: CribEffect,
+    typo_tolerance: usize,
+    normalization: Normalization,
+    leet_readings: bool,
+    confusables: bool,
+    strip_invisible: bool,
+    lexicon: Lexicon,
+}
+
+/// Detailed result of analysing a piece of text
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    /// Final verdict, the same as [`GibberishDetector::is_gibberish`]
+    pub is_gibberish: bool,
+    /// English-likeness score, including any crib boost
+    pub score: Score,
+    /// The crib that matched, if any
+    pub crib_hit: Option<CribHit>,
+    /// Words only recognised after stripping inflections, paired with their
+    /// dictionary base form, e.g. `("emailed", "email")`
+    pub inflected_words: Vec<(String, String)>,
+    /// Misspelled words accepted by typo-tolerant matching, see
+    /// [`GibberishDetector::with_typo_tolerance`]
+    pub fuzzy_matches: Vec<FuzzyMatch>,
+    /// Cost per character of splitting text without spaces into words, see
+    /// [`segment`]. `None` if the text has spaces or is too short to segment.
+    pub segmentation_cost: Option<f64>,
+    /// Tokens rewritten by the normalization stage, paired with the words that
+    /// were looked up instead, e.g. `("don't", "do not")`. See
+    /// [`GibberishDetector::with_normalization`].
+    pub normalized_words: Vec<(String, String)>,
+    /// The most English-like reading of the text with leetspeak undone, if any
+    /// reading passes the basic checks. Only filled in when enabled with
+    /// [`GibberishDetector::with_leet_readings`], and never affects the verdict.
+    pub leet_reading: Option<String>,
+    /// Words mixing letters from several scripts, e.g. "pаypal" with a Cyrillic
+    /// "а", see [`mixed_script_tokens`]. A spoofing signal that does not affect
+    /// the verdict.
+    pub mixed_script_tokens: Vec<String>,
+    /// Homoglyphs that were read as Latin letters before scoring, see
+    /// [`GibberishDetector::with_confusables`]. Offsets refer to the text after
+    /// any invisible characters were stripped.
+    pub confusables: Vec<Substitution>,
+    /// Zero-width, bidi, tag and other invisible characters in the original
+    /// text, see [`invisible_characters`]
+    pub invisible_characters: Vec<(usize, char, InvisibleCategory)>,
+}
+
+/// Result of analysing raw bytes, see [`GibberishDetector::analyze_bytes`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BytesAnalysis {
+    /// The analysis of the decoded text
+    pub analysis: Analysis,
+    /// The decoded text and the encoding it was read with
+    pub decoded: Decoded,
+}
+
+impl Analysis {
+    /// Returns true for English written with homoglyphs, e.g. "pаypаl" with a
+    /// Cyrillic "а": the text reads as English, but is not what it seems
+    pub fn is_spoofed(&self) -> bool {
+        !self.is_gibberish && !self.confusables.is_empty()
+    }
 }
 
 impl GibberishDetector {
     /// Create new detector with no model
     pub fn new() -> Self {
-        Self { model_path: None }
+        Self {
+            model_path: None,
+            cribs: Vec::new(),
+            crib_effect: CribEffect::default(),
+            typo_tolerance: 0,
+            normalization: Normalization::default(),
+            leet_readings: false,
+            confusables: true,
+            strip_invisible: false,
+            lexicon: Lexicon::default(),
+        }
     }
 
     /// Create new detector with model path
     pub fn with_model<P: AsRef<Path>>(path: P) -> Self {
         Self {
             model_path: Some(path.as_ref().to_path_buf()),
+            ..Self::new()
+        }
+    }
+
+    /// Add a known-plaintext crib. Text matching any crib gets the configured
+    /// [`CribEffect`] applied to its verdict.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gibberish_or_not::{Crib, CribEffect, GibberishDetector, Sensitivity};
+    ///
+    /// let detector = GibberishDetector::new()
+    ///     .with_crib(Crib::literal("flag{"))
+    ///     .with_crib_effect(CribEffect::ForceEnglish);
+    ///
+    /// let analysis = detector.analyze("flag{x7_q9z_w2}", Sensitivity::Medium);
+    /// assert!(!analysis.is_gibberish);
+    /// assert_eq!(analysis.crib_hit.unwrap().matched, "flag{");
+    /// ```
+    pub fn with_crib(mut self, crib: Crib) -> Self {
+        self.cribs.push(crib);
+        self
+    }
+
+    /// Add several known-plaintext cribs, see [`with_crib`](Self::with_crib)
+    pub fn with_cribs(mut self, cribs: impl IntoIterator<Item = Crib>) -> Self {
+        self.cribs.extend(cribs);
+        self
+    }
+
+    /// Set what happens when a crib matches. Defaults to a score boost of 0.2.
+    pub fn with_crib_effect(mut self, effect: CribEffect) -> Self {
+        self.crib_effect = effect;
+        self
+    }
+
+    /// Accept misspelled words within `max_distance` edits of a dictionary word.
+    ///
+    /// Each accepted typo counts as a fraction of a word towards the English
+    /// word ratio, halving with every edit. Disabled (0) by default; 1 or 2 is
+    /// sensible for human-typed text, and larger values allow no more than 2.
+    /// See [`fuzzy_lookup`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gibberish_or_not::{GibberishDetector, Sensitivity};
+    ///
+    /// let detector = GibberishDetector::new().with_typo_tolerance(2);
+    /// let analysis = detector.analyze("teh quikc brwon fox jumsp", Sensitivity::Medium);
+    /// assert!(analysis.fuzzy_matches.iter().any(|m| m.matched == "quick"));
+    /// ```
+    pub fn with_typo_tolerance(mut self, max_distance: usize) -> Self {
+        self.typo_tolerance = max_distance;
+        self
+    }
+
+    /// Choose which token normalizations run before the dictionary checks.
+    ///
+    /// By default contractions are expanded ("don't" to "do not"), possessives
+    /// stripped ("dog's" to "dog") and hyphenated compounds checked part by
+    /// part. Curly apostrophes are treated like straight ones. Use
+    /// [`Normalization::none`] to look tokens up exactly as written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gibberish_or_not::{GibberishDetector, Sensitivity};
+    ///
+    /// let analysis = GibberishDetector::new().analyze("I don't like my neighbour's dog", Sensitivity::Medium);
+    /// assert!(analysis.normalized_words.contains(&("don't".to_string(), "do not".to_string())));
+    /// ```
+    pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Also check whether the text reads as English once leetspeak is undone.
+    ///
+    /// Each reading from [`deleet`] is run through the basic checks, and also
+    /// counts if every word leetspeak was undone in is English, as in
+    /// "xX_l33t_Xx_n0sc0p3". The most English-like reading is reported in
+    /// [`Analysis::leet_reading`]. This is a separate signal: the verdict is
+    /// still given for the text as written.
+    /// Disabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gibberish_or_not::{GibberishDetector, Sensitivity};
+    ///
+    /// let detector = GibberishDetector::new().with_leet_readings(true);
+    /// let analysis = detector.analyze("th3 p@$$w0rd 1s h1dd3n h3r3", Sensitivity::Medium);
+    /// assert_eq!(analysis.leet_reading.as_deref(), Some("the password is hidden here"));
+    /// ```
+    pub fn with_leet_readings(mut self, enabled: bool) -> Self {
+        self.leet_readings = enabled;
+        self
+    }
+
+    /// Add words that count as English on top of the built-in dictionary.
+    ///
+    /// Useful for product names, jargon and technical tokens. Added words are
+    /// matched case-insensitively and used by every word-based check,
+    /// including inflections, typo tolerance and segmentation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gibberish_or_not::{GibberishDetector, Sensitivity};
+    ///
+    /// let text = "kubectl rollout restarted the grafana pods";
+    /// let detector = GibberishDetector::new().with_words(["kubectl", "grafana"]);
+    /// assert!(detector.score(text) > GibberishDetector::new().score(text));
+    /// ```
+    pub fn with_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.lexicon.add_words(words);
+        self
+    }
+
+    /// Add words from a file with one word per line, see [`with_words`](Self::with_words).
+    ///
+    /// Blank lines and lines starting with `#` are skipped.
+    pub fn with_word_file<P: AsRef<Path>>(mut self, path: P) -> std::io::Result<Self> {
+        let words = lexicon::read_word_file(path.as_ref())?;
+        self.lexicon.add_words(words);
+        Ok(self)
+    }
+
+    /// Add every word of a Hunspell dictionary, e.g. for an English variant.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use gibberish_or_not::{GibberishDetector, HunspellDictionary};
+    ///
+    /// let en_gb = HunspellDictionary::from_files("en_GB.dic", "en_GB.aff")?;
+    /// let detector = GibberishDetector::new().with_hunspell(&en_gb);
+    /// # Ok::<(), gibberish_or_not::HunspellError>(())
+    /// ```
+    pub fn with_hunspell(mut self, dictionary: &HunspellDictionary) -> Self {
+        self.lexicon.add_words(dictionary.words());
+        self
+    }
+
+    /// Never count these words as English, even if they are in the dictionary
+    /// or were added with [`with_words`](Self::with_words).
+    pub fn with_denied_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.lexicon.deny_words(words);
+        self
+    }
+
+    /// Judge text against another language's alphabet, n-grams, dictionary and
+    /// entropy band instead of English.
+    ///
+    /// Stemming, typo tolerance, segmentation, word order checks, contractions
+    /// and possessives only exist for English and are skipped for other languages.
+    /// Words added with [`with_words`](Self::with_words) still count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "french")]
+    /// # {
+    /// use gibberish_or_not::{French, GibberishDetector, Sensitivity};
+    ///
+    /// let detector = GibberishDetector::new().with_language(French);
+    /// assert!(!detector.is_gibberish("nous avons trouvé le message dans la maison", Sensitivity::Medium));
+    /// assert!(detector.is_gibberish("xqzvbk wpjfhg lmnrtz", Sensitivity::Medium));
+    /// # }
+    /// ```
+    pub fn with_language<L: Language + 'static>(mut self, language: L) -> Self {
+        self.lexicon.set_language(Arc::new(language));
+        self
+    }
+
+    /// Read homoglyphs as the Latin letters they imitate before scoring, see
+    /// [`unconfuse`]. Enabled by default.
+    ///
+    /// The substitutions are reported in [`Analysis::confusables`], so spoofed
+    /// English is told apart from both plain English and gibberish.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gibberish_or_not::{GibberishDetector, Sensitivity};
+    ///
+    /// let text = "please confirm your p\u{430}yp\u{430}l account details";
+    /// let analysis = GibberishDetector::new().analyze(text, Sensitivity::Medium);
+    /// assert!(!analysis.is_gibberish);
+    /// assert!(analysis.is_spoofed());
+    ///
+    /// let literal = GibberishDetector::new().with_confusables(false);
+    /// assert!(!literal.analyze(text, Sensitivity::Medium).is_spoofed());
+    /// ```
+    pub fn with_confusables(mut self, enabled: bool) -> Self {
+        self.confusables = enabled;
+        self
+    }
+
+    /// Remove invisible characters before scoring, see [`strip_invisible`].
+    /// Disabled by default.
+    ///
+    /// Without stripping, a zero-width character stays inside the word it sits
+    /// in, so "th\u{200B}is" is looked up as one unknown word, and text that is
+    /// mostly invisible counts as bad quality. Either way, the characters are
+    /// reported in [`Analysis::invisible_characters`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gibberish_or_not::{GibberishDetector, Sensitivity};
+    ///
+    /// let text = "th\u{200B}is me\u{200B}ssage is hid\u{200B}den";
+    /// let detector = GibberishDetector::new().with_strip_invisible(true);
+    /// assert!(detector.score(text) > GibberishDetector::new().score(text));
+    /// ```
+    pub fn with_strip_invisible(mut self, enabled: bool) -> Self {
+        self.strip_invisible = enabled;
+        self
+    }
+
+    /// The text as scored, with invisible characters stripped and homoglyphs
+    /// read as Latin if enabled, and the homoglyphs that were replaced
+    fn prepare<'a>(&self, text: &'a str) -> (Cow<'a, str>, Vec<Substitution>) {
+        let mut text = Cow::Borrowed(text);
+        if self.strip_invisible {
+            text = Cow::Owned(strip_invisible(&text));
+        }
+
+        let mut confusables = Vec::new();
+        if self.confusables {
+            let unconfused = unconfuse(&text);
+            if unconfused.is_spoofed() {
+                confusables = unconfused.substitutions;
+                text = Cow::Owned(unconfused.text);
+            }
         }
+        (text, confusables)
     }
 
     /// Check if model is available
@@ -61,14 +449,168 @@ impl GibberishDetector {
             .unwrap_or(false)
     }
 
+    /// Decode raw bytes with [`decode_bytes`] and analyse the text, reporting
+    /// the encoding that was chosen alongside the verdict.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gibberish_or_not::{GibberishDetector, Sensitivity};
+    ///
+    /// let bytes: Vec<u8> = "\u{feff}the meeting is at noon"
+    ///     .encode_utf16()
+    ///     .flat_map(u16::to_le_bytes)
+    ///     .collect();
+    /// let result = GibberishDetector::new().analyze_bytes(&bytes, Sensitivity::Medium);
+    /// assert!(!result.analysis.is_gibberish);
+    /// assert_eq!(result.decoded.encoding, "UTF-16LE");
+    /// ```
+    pub fn analyze_bytes(&self, bytes: &[u8], sensitivity: Sensitivity) -> BytesAnalysis {
+        let decoded = decode_bytes(bytes);
+        BytesAnalysis {
+            analysis: self.analyze(&decoded.text, sensitivity),
+            decoded,
+        }
+    }
+
+    /// Find the English parts of mixed content with this detector, see
+    /// [`find_english_spans`]
+    pub fn find_english_spans(
+        &self,
+        text: &str,
+        sensitivity: Sensitivity,
+    ) -> Vec<(Range<usize>, Score)> {
+        spans::find_spans(self, text, sensitivity)
+    }
+
+    /// Scan binary data for runs of English text with this detector, see
+    /// [`english_strings`]
+    pub fn english_strings<'a>(&'a self, bytes: &'a [u8]) -> EnglishStrings<'a> {
+        EnglishStrings::new(bytes, self)
+    }
+
+    /// [`is_gibberish`](Self::is_gibberish) for raw bytes of unknown encoding,
+    /// see [`analyze_bytes`](Self::analyze_bytes)
+    pub fn is_gibberish_bytes(&self, bytes: &[u8], sensitivity: Sensitivity) -> bool {
+        self.analyze_bytes(bytes, sensitivity).analysis.is_gibberish
+    }
+
     /// Main detection function
     pub fn is_gibberish(&self, text: &str, sensitivity: Sensitivity) -> bool {
-        if is_string_bad_quality(&text) {
-            return true;
+        self.analyze(text, sensitivity).is_gibberish
+    }
+
+    /// Run the detection and report the verdict together with the evidence behind it
+    pub fn analyze(&self, text: &str, sensitivity: Sensitivity) -> Analysis {
+        let mixed_script_tokens: Vec<String> = mixed_script_tokens(text)
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let invisible_characters = invisible_characters(text);
+        let (text, confusables) = self.prepare(text);
+        let text = text.as_ref();
+
+        let crib_hit = find_crib(&self.cribs, text);
+        let forced = crib_hit.is_some() && self.crib_effect == CribEffect::ForceEnglish;
+        let leet_reading = self.leet_reading(text, sensitivity);
+
+        if is_string_bad_quality(text) && !forced {
+            return Analysis {
+                is_gibberish: true,
+                score: 0.0,
+                crib_hit,
+                inflected_words: Vec::new(),
+                fuzzy_matches: Vec::new(),
+                segmentation_cost: None,
+                normalized_words: Vec::new(),
+                leet_reading,
+                mixed_script_tokens,
+                confusables,
+                invisible_characters,
+            };
+        }
+
+        let mut features = TextFeatures::extract(text, self);
+        if let (Some(_), CribEffect::Boost(boost)) = (&crib_hit, self.crib_effect) {
+            features.composite_score += boost;
         }
+
+        Analysis {
+            is_gibberish: !forced && self.is_gibberish_with_model(text, &features, sensitivity),
+            score: features.composite_score,
+            crib_hit,
+            inflected_words: features.inflected_words,
+            fuzzy_matches: features.fuzzy_matches,
+            segmentation_cost: features.segmentation_cost,
+            normalized_words: features.normalized_words,
+            leet_reading,
+            mixed_script_tokens,
+            confusables,
+            invisible_characters,
+        }
+    }
+
+    /// The most English-like de-leeted reading of the text, if enabled and any is English
+    fn leet_reading(&self, text: &str, sensitivity: Sensitivity) -> Option<String> {
+        if !self.leet_readings {
+            return None;
+        }
+
+        // Prefer the reading with the most dictionary words, then the best score
+        let mut best: Option<((f64, f64), String)> = None;
+        for reading in deleet(text) {
+            if is_string_bad_quality(&reading) {
+                continue;
+            }
+            let features = TextFeatures::extract(&reading, self);
+            if features.is_gibberish(sensitivity) && !self.leet_words_are_english(text, &reading) {
+                continue;
+            }
+            let rank = (features.english_word_ratio, features.composite_score);
+            if best.as_ref().is_none_or(|(best_rank, _)| rank > *best_rank) {
+                best = Some((rank, reading));
+            }
+        }
+        best.map(|(_, reading)| reading)
+    }
+
+    /// Whether every word leetspeak was undone in is English, so that handles
+    /// such as "xX_l33t_Xx_n0sc0p3" count even though "xX" is not a word.
+    ///
+    /// Only words with at least one letter as written count, since a number
+    /// such as "101" is more likely a number than leet. [`deleet`] replaces
+    /// character for character, so the words line up with the original text.
+    fn leet_words_are_english(&self, text: &str, reading: &str) -> bool {
+        let pairs: Vec<(char, char)> = text.chars().zip(reading.chars()).collect();
+        let words: Vec<String> = pairs
+            .split(|&(_, read)| !read.is_alphanumeric())
+            .filter(|word| word.iter().any(|&(written, read)| written != read))
+            .filter(|word| word.iter().any(|&(written, _)| written.is_alphabetic()))
+            .map(|word| {
+                word.iter()
+                    .map(|&(_, read)| read)
+                    .collect::<String>()
+                    .to_lowercase()
+            })
+            .collect();
+
+        !words.is_empty()
+            && words.iter().all(|word| {
+                self.lexicon.is_english_word(word)
+                    || segment::segment_in(word, &self.lexicon).cost_per_char()
+                        <= MAX_SEGMENT_COST_PER_CHAR
+            })
+    }
+
+    fn is_gibberish_with_model(
+        &self,
+        text: &str,
+        features: &TextFeatures,
+        sensitivity: Sensitivity,
+    ) -> bool {
         // Run basic checks first
         // returns true if its gibberish
-        let basic_result = run_basic_checks(text, sensitivity);
+        let basic_result = features.is_gibberish(sensitivity);
 
         // If basic checks say it's gibberish, no need for model
         if basic_result {
@@ -86,37 +628,49 @@ impl GibberishDetector {
         // Fall back to basic result
         basic_result
     }
+
+    /// Score how English-like the text is, from 0.0 (noise) up to roughly 1.0.
+    ///
+    /// Uses the same feature extraction as the basic checks in [`is_gibberish`](Self::is_gibberish),
+    /// including any crib boost. Text that is too short or mostly invisible characters scores 0.0.
+    pub fn score(&self, text: &str) -> Score {
+        let (text, _) = self.prepare(text);
+        let text = text.as_ref();
+        if is_string_bad_quality(text) {
+            return 0.0;
+        }
+
+        let boost = match (find_crib(&self.cribs, text), self.crib_effect) {
+            (Some(_), CribEffect::Boost(boost)) => boost,
+            _ => 0.0,
+        };
+        TextFeatures::extract(text, self).composite_score + boost
+    }
+}
+
+impl Default for GibberishDetector {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// is it worth it to calculate this string?
+///
+/// Text is bad quality if it has three or fewer graphemes or if more than half
+/// of its graphemes are invisible (control, format, private-use or unassigned
+/// characters). Printable text in any script, such as "café" or Cyrillic, is fine.
+/// See [`TextQuality`] for the underlying counts.
 pub fn is_string_bad_quality(s: &str) -> bool {
-    // Check for high percentage of invisible characters
-    let non_printable_ratio = calculate_non_printable_ratio(s);
-    if non_printable_ratio > 0.5 {
-        return true; // Return lowest quality for strings with >50% invisible chars
-    }
-    if s.len() <= 3 {
-        return true;
-    }
-    return false
+    TextQuality::measure(s).is_bad()
 }
 
-/// Calculate the ratio of non-printable characters in a string
+/// Calculate the ratio of invisible graphemes in a string
 /// Returns a value between 0.0 (all printable) and 1.0 (all non-printable)
+///
+/// Whitespace and printable characters of any script count as printable, and
+/// the ratio is taken over grapheme clusters rather than bytes.
 pub fn calculate_non_printable_ratio(text: &str) -> f32 {
-    let non_printable_count = text
-        .chars()
-        .filter(|&c| {
-            // Only count control characters (except common whitespace) and non-ASCII as non-printable
-            (c.is_control() && c != '\n' && c != '\r' && c != '\t') || !c.is_ascii()
-        })
-        .count();
-
-    non_printable_count as f32 / text.len() as f32
-}
-
-fn is_english_word(word: &str) -> bool {
-    dictionary::ENGLISH_WORDS.contains(word)
+    TextQuality::measure(text).invisible_ratio() as f32
 }
 
 /// Checks if the given text matches a known common password.
@@ -180,149 +734,359 @@ pub fn is_gibberish(text: &str, sensitivity: Sensitivity) -> bool {
     GibberishDetector::new().is_gibberish(text, sensitivity)
 }
 
-/// Run the basic gibberish detection algorithm without model enhancement
-fn run_basic_checks(text: &str, sensitivity: Sensitivity) -> bool {
-    // Clean the text first
-    let cleaned = clean_text(text);
+/// Find the English parts of mixed content, such as one sentence inside base64
+/// noise, as byte ranges into the text with the English score of each.
+///
+/// A window of a few words slides over the text; words covered by windows that
+/// are not gibberish are merged into spans, noise at the edges of a span is
+/// trimmed, and each span is scored on its own at [`Sensitivity::Medium`].
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::find_english_spans;
+///
+/// let text = "aGVsbG8gd29ybGQ=ZXhhbXBsZQ== the meeting has been moved to noon Zm9vYmFyYmF6cXV4";
+/// let spans = find_english_spans(text);
+/// assert_eq!(spans.len(), 1);
+/// assert_eq!(&text[spans[0].0.clone()], "the meeting has been moved to noon");
+/// ```
+pub fn find_english_spans(text: &str) -> Vec<(Range<usize>, Score)> {
+    GibberishDetector::new().find_english_spans(text, Sensitivity::Medium)
+}
 
-    // Check if empty after cleaning
-    if cleaned.is_empty() {
-        return true;
-    }
+/// Checks if raw bytes of unknown encoding are gibberish, see [`decode_bytes`]
+/// for how the encoding is detected.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::{is_gibberish_bytes, Sensitivity};
+///
+/// assert!(!is_gibberish_bytes(b"the caf\xe9 opens at nine today", Sensitivity::Medium));
+/// assert!(is_gibberish_bytes(&[0x8f, 0x1b, 0xd3, 0x02, 0x7e, 0xc4, 0x99, 0x10], Sensitivity::Medium));
+/// ```
+pub fn is_gibberish_bytes(bytes: &[u8], sensitivity: Sensitivity) -> bool {
+    GibberishDetector::new().is_gibberish_bytes(bytes, sensitivity)
+}
 
-    // For very short cleaned text, only check if it's an English word
-    if cleaned.len() < 10 {
-        let is_english = is_english_word(&cleaned);
-        return !is_english;
-    }
+/// English-likeness score produced by the composite heuristics.
+///
+/// Ranges from 0.0 (noise) up to roughly 1.0 (clearly English). Scores are only
+/// meaningful relative to each other; use [`is_gibberish`] for a verdict.
+pub type Score = f64;
 
-    // Split into words and check for English words
-    let words: Vec<&str> = cleaned
-        .split_whitespace()
-        .filter(|word| !word.is_empty())
-        .collect();
+/// Scores how English-like the text is using the same composite heuristics
+/// as [`is_gibberish`].
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::english_score;
+///
+/// assert!(english_score("The quick brown fox") > english_score("Gur dhvpx oebja sbk"));
+/// ```
+pub fn english_score(text: &str) -> Score {
+    GibberishDetector::new().score(text)
+}
 
-    // Count English words
-    let english_words: Vec<&&str> = words.iter().filter(|w| is_english_word(w)).collect();
-    let english_word_count = english_words.len();
-    let english_word_ratio = if words.is_empty() {
-        0.0
-    } else {
-        english_word_count as f64 / words.len() as f64
-    };
-
-    // Check for non-printable characters which are strong indicators of gibberish
-    let non_printable_count = text
-        .chars()
-        .filter(|&c| c < ' ' && c != '\n' && c != '\r' && c != '\t')
-        .count();
+/// Fewest words for which an implausible word order counts as word salad
+const MIN_WORD_SALAD_WORDS: usize = 4;
+
+/// Word order plausibility below which dictionary words are treated as word salad
+const WORD_SALAD_THRESHOLD: f64 = 0.62;
+
+/// Factor applied to the composite score of word salad
+const WORD_SALAD_PENALTY: f64 = 0.3;
+
+/// Shortest run of text without spaces that is worth segmenting into words
+const MIN_SEGMENT_LEN: usize = 8;
+
+/// Segmentations costing more than this per character are not trusted
+const MAX_SEGMENT_COST_PER_CHAR: f64 = 1.5;
+
+/// Features extracted from a piece of text by the basic heuristics
+struct TextFeatures {
+    cleaned: String,
+    cleaned_is_word: bool,
+    english_word_count: usize,
+    inflected_words: Vec<(String, String)>,
+    fuzzy_matches: Vec<FuzzyMatch>,
+    english_word_ratio: f64,
+    word_salad: bool,
+    segmentation_cost: Option<f64>,
+    normalized_words: Vec<(String, String)>,
+    non_printable_count: usize,
+    entropy: f64,
+    max_entropy: f64,
+    transition_score: f64,
+    composite_score: f64,
+}
 
-    // If there are non-printable characters, it's likely gibberish
-    if non_printable_count > 0 {
-        return true;
-    }
+impl TextFeatures {
+    fn extract(text: &str, detector: &GibberishDetector) -> Self {
+        // Text in another script is judged against that script's profile, if bundled
+        let script_lexicon = dominant_script(text)
+            .filter(|&script| script != detector.lexicon.language().script())
+            .and_then(language::script_profile)
+            .map(|profile| detector.lexicon.with_language(profile));
+        let lexicon = script_lexicon.as_ref().unwrap_or(&detector.lexicon);
+        let language = lexicon.language();
+
+        // Clean the text first
+        let cleaned = clean_text(text, language);
+
+        // Contractions and possessives are English; compounds split in any language
+        let normalization = if lexicon.is_english() {
+            detector.normalization
+        } else {
+            Normalization {
+                contractions: false,
+                possessives: false,
+                ..detector.normalization
+            }
+        };
 
-    // Calculate character entropy - gibberish often has unusual character distributions
-    let entropy = calculate_entropy(text);
+        // Split into tokens, normalizing contractions, possessives and compounds
+        let mut normalized_words = Vec::new();
+        let tokens: Vec<String> = cleaned
+            .split_whitespace()
+            .flat_map(
+                |token| match normalize::normalize_token(token, &normalization, lexicon) {
+                    Some(words) => {
+                        normalized_words.push((token.to_string(), words.join(" ")));
+                        words
+                    }
+                    None => vec![token.to_string()],
+                },
+            )
+            .collect();
 
-    // Calculate character transition probability - English has predictable transitions
-    let transition_score = calculate_transition_score(text);
+        // Split into words and check for English words
+        let mut words: Vec<&str> = tokens
+            .iter()
+            .map(String::as_str)
+            .filter(|word| !word.is_empty())
+            .collect();
 
-    // Calculate vowel-consonant ratio - English has a fairly consistent ratio
-    let vowel_consonant_ratio = calculate_vowel_consonant_ratio(&cleaned);
+        // Text without spaces, e.g. decoded cipher output, is one long unknown
+        // word; use the segmenter's split instead if it is a convincing one
+        let mut segmentation_cost = None;
+        if lexicon.is_english() && words.len() == 1 && words[0].chars().count() >= MIN_SEGMENT_LEN {
+            let segmentation = segment::segment_in(words[0], lexicon);
+            let cost = segmentation.cost_per_char();
+            segmentation_cost = Some(cost);
+            if cost <= MAX_SEGMENT_COST_PER_CHAR {
+                words = segmentation.words;
+            }
+        }
 
-    // Proceed with trigram/quadgram analysis (but with less weight)
-    let trigrams = generate_ngrams(&cleaned, 3);
-    let quadgrams = generate_ngrams(&cleaned, 4);
+        // Count English words, falling back to stemming for inflections missing
+        // from the dictionary. Each word is also weighted by how common it is,
+        // so chance hits on obscure entries are weaker evidence.
+        let mut inflected_words = Vec::new();
+        let mut english_word_count = 0;
+        let mut english_word_weight = 0.0;
+        for w in &words {
+            let weight = lexicon.weight(w).or_else(|| {
+                if !lexicon.is_english() {
+                    return None;
+                }
+                let base = stem::lemmatize_in(w, lexicon)?;
+                let weight = lexicon.weight(&base);
+                inflected_words.push((w.to_string(), base));
+                weight
+            });
+            if let Some(weight) = weight {
+                english_word_count += 1;
+                english_word_weight += weight;
+            }
+        }
 
-    let valid_trigrams = trigrams
-        .iter()
-        .filter(|gram| COMMON_TRIGRAMS.contains(gram.as_str()))
-        .collect::<Vec<_>>();
+        // Optionally accept typos, each counting as a fraction of a word
+        let fuzzy_matches: Vec<FuzzyMatch> = match detector.typo_tolerance {
+            _ if !lexicon.is_english() => Vec::new(),
+            0 => Vec::new(),
+            max_distance => words
+                .iter()
+                .filter(|w| !lexicon.is_english_word(w))
+                .filter_map(|w| fuzzy::fuzzy_lookup_in(w, max_distance, lexicon))
+                .collect(),
+        };
+        let fuzzy_weight: f64 = fuzzy_matches.iter().map(FuzzyMatch::weight).sum();
 
-    let valid_quadgrams = quadgrams
-        .iter()
-        .filter(|gram| COMMON_QUADGRAMS.contains(gram.as_str()))
-        .collect::<Vec<_>>();
+        let english_word_ratio = if words.is_empty() {
+            0.0
+        } else {
+            (english_word_weight + fuzzy_weight) / words.len() as f64
+        };
 
-    // Calculate scores
-    let trigram_score = if trigrams.is_empty() {
-        0.0
-    } else {
-        valid_trigrams.len() as f64 / trigrams.len() as f64
-    };
+        // Real words in an order no English sentence would use
+        let word_salad = lexicon.is_english()
+            && words.len() >= MIN_WORD_SALAD_WORDS
+            && english_word_count == words.len()
+            && word_sequence_plausibility(text)
+                .is_some_and(|plausibility| plausibility < WORD_SALAD_THRESHOLD);
 
-    let quadgram_score = if quadgrams.is_empty() {
-        0.0
-    } else {
-        valid_quadgrams.len() as f64 / quadgrams.len() as f64
-    };
+        // Check for non-printable characters which are strong indicators of gibberish
+        let non_printable_count = text
+            .chars()
+            .filter(|&c| c < ' ' && c != '\n' && c != '\r' && c != '\t')
+            .count();
 
-    // Calculate a composite score that combines multiple metrics
-    // This makes the algorithm more robust than relying heavily on n-grams
-    let mut composite_score = 0.0;
+        // Calculate character entropy - gibberish often has unusual character distributions
+        let entropy = calculate_entropy(text);
 
-    // English word ratio has high weight
-    composite_score += english_word_ratio * 0.4;
+        // Calculate character transition probability - English has predictable transitions
+        let transition_score = calculate_transition_score(text, language);
 
-    // Transition probability has medium weight
-    composite_score += transition_score * 0.25;
+        // Calculate vowel-consonant ratio - English has a fairly consistent ratio
+        let vowel_consonant_ratio = calculate_vowel_consonant_ratio(&cleaned, language);
 
-    // N-gram scores have lower weight
-    composite_score += trigram_score * 0.15;
-    composite_score += quadgram_score * 0.1;
+        // Proceed with trigram/quadgram analysis (but with less weight)
+        let trigrams = generate_ngrams(&cleaned, 3, language);
+        let quadgrams = generate_ngrams(&cleaned, 4, language);
 
-    // Vowel-consonant ratio has low weight
-    composite_score += if (0.3..=0.7).contains(&vowel_consonant_ratio) {
-        0.1
-    } else {
-        0.0
-    };
+        let valid_trigrams = trigrams
+            .iter()
+            .filter(|gram| language.is_common_trigram(gram))
+            .collect::<Vec<_>>();
 
-    // Entropy check - English text typically has entropy between 3.5-4.5
-    // If entropy is outside this range, reduce the composite score
-    if !(3.5..=4.5).contains(&entropy) {
-        composite_score *= 0.8;
-    }
+        let valid_quadgrams = quadgrams
+            .iter()
+            .filter(|gram| language.is_common_quadgram(gram))
+            .collect::<Vec<_>>();
 
-    // Adjust thresholds based on text length
-    let length_factor = match cleaned.len() {
-        0..=20 => 0.7,    // Very short text needs higher threshold
-        21..=50 => 0.8,   // Short text
-        51..=100 => 0.9,  // Medium text
-        101..=200 => 1.0, // Standard threshold
-        _ => 1.1,         // Long text can be more lenient
-    };
+        // Calculate scores
+        let trigram_score = if trigrams.is_empty() {
+            0.0
+        } else {
+            valid_trigrams.len() as f64 / trigrams.len() as f64
+        };
 
-    // Decision thresholds based on sensitivity
-    let threshold = match sensitivity {
-        Sensitivity::Low => 0.35 * length_factor, // Stricter - needs more evidence to be English
-        Sensitivity::Medium => 0.25 * length_factor, // Balanced
-        Sensitivity::High => 0.15 * length_factor, // Lenient - less evidence needed to be English
-    };
+        let quadgram_score = if quadgrams.is_empty() {
+            0.0
+        } else {
+            valid_quadgrams.len() as f64 / quadgrams.len() as f64
+        };
 
-    // If entropy is very high (above 4.5), it's likely gibberish
-    if entropy > 4.5 && sensitivity != Sensitivity::High {
-        return true;
-    }
+        // Calculate a composite score that combines multiple metrics
+        // This makes the algorithm more robust than relying heavily on n-grams
+        let mut composite_score = 0.0;
 
-    // If almost all words are English, it's definitely English
-    if english_word_ratio > 0.8 {
-        return false;
-    }
+        // English word ratio has high weight
+        composite_score += english_word_ratio * 0.4;
 
-    // If we have multiple English words, it's likely English
-    if english_word_count >= 3 && sensitivity != Sensitivity::Low {
-        return false;
-    }
+        // Transition probability has medium weight
+        composite_score += transition_score * 0.25;
+
+        // N-gram scores have lower weight
+        composite_score += trigram_score * 0.15;
+        composite_score += quadgram_score * 0.1;
+
+        // Vowel-consonant ratio has low weight
+        composite_score += if (0.3..=0.7).contains(&vowel_consonant_ratio) {
+            0.1
+        } else {
+            0.0
+        };
+
+        // Entropy check - English text typically has entropy between 3.5-4.5
+        // If entropy is outside the language's range, reduce the composite score
+        let entropy_range = language.entropy_range();
+        if !entropy_range.contains(&entropy) {
+            composite_score *= 0.8;
+        }
 
-    // If we have no English words and poor transition score, it's likely gibberish
-    if english_word_count == 0 && transition_score < 0.4 && sensitivity != Sensitivity::High {
-        return true;
+        if word_salad {
+            composite_score *= WORD_SALAD_PENALTY;
+        }
+
+        Self {
+            // Only needed for the short text check
+            cleaned_is_word: cleaned.len() < 10 && lexicon.is_english_word(&cleaned),
+            cleaned,
+            english_word_count,
+            inflected_words,
+            fuzzy_matches,
+            english_word_ratio,
+            word_salad,
+            segmentation_cost,
+            normalized_words,
+            non_printable_count,
+            entropy,
+            max_entropy: *entropy_range.end(),
+            transition_score,
+            composite_score,
+        }
     }
 
-    // For the remaining cases, use the composite score
-    composite_score < threshold
+    /// Run the basic gibberish detection algorithm without model enhancement
+    fn is_gibberish(&self, sensitivity: Sensitivity) -> bool {
+        let cleaned = &self.cleaned;
+
+        // Check if empty after cleaning
+        if cleaned.is_empty() {
+            return true;
+        }
+
+        // For very short cleaned text, only check if it's an English word
+        if cleaned.len() < 10 {
+            return !self.cleaned_is_word;
+        }
+
+        // If there are non-printable characters, it's likely gibberish
+        if self.non_printable_count > 0 {
+            return true;
+        }
+
+        // Adjust thresholds based on text length
+        let length_factor = match cleaned.len() {
+            0..=20 => 0.7,    // Very short text needs higher threshold
+            21..=50 => 0.8,   // Short text
+            51..=100 => 0.9,  // Medium text
+            101..=200 => 1.0, // Standard threshold
+            _ => 1.1,         // Long text can be more lenient
+        };
+
+        // Decision thresholds based on sensitivity
+        let threshold = match sensitivity {
+            Sensitivity::Low => 0.35 * length_factor, // Stricter - needs more evidence to be English
+            Sensitivity::Medium => 0.25 * length_factor, // Balanced
+            Sensitivity::High => 0.15 * length_factor, // Lenient - less evidence needed to be English
+        };
+
+        // If entropy is very high (above 4.5 for English), it's likely gibberish
+        if self.entropy > self.max_entropy && sensitivity != Sensitivity::High {
+            return true;
+        }
+
+        // Word salad is made of English words, so only the composite score can judge it
+        if self.word_salad {
+            return self.composite_score < threshold;
+        }
+
+        // If almost all words are English, it's definitely English
+        if self.english_word_ratio > 0.8 {
+            return false;
+        }
+
+        // If we have multiple English words, it's likely English
+        if self.english_word_count >= 3 && sensitivity != Sensitivity::Low {
+            return false;
+        }
+
+        // If we have no English words and poor transition score, it's likely gibberish
+        if self.english_word_count == 0
+            && self.transition_score < 0.4
+            && sensitivity != Sensitivity::High
+        {
+            return true;
+        }
+
+        // For the remaining cases, use the composite score
+        self.composite_score < threshold
+    }
 }
 
 /// Calculate character entropy - a measure of randomness in the text
@@ -352,8 +1116,8 @@ fn calculate_entropy(text: &str) -> f64 {
     entropy
 }
 
-/// Calculate character transition probabilities based on English patterns
-fn calculate_transition_score(text: &str) -> f64 {
+/// Calculate character transition probabilities based on the language's common pairs
+fn calculate_transition_score(text: &str, language: &dyn Language) -> f64 {
     let text = text.to_lowercase();
     let chars: Vec<char> = text.chars().collect();
 
@@ -366,7 +1130,7 @@ fn calculate_transition_score(text: &str) -> f64 {
 
     for i in 0..total_transitions {
         let pair = format!("{}{}", chars[i], chars[i + 1]);
-        if COMMON_CHAR_PAIRS.contains(&pair.as_str()) {
+        if language.is_common_pair(&pair) {
             valid_transitions += 1;
         }
     }
@@ -375,13 +1139,12 @@ fn calculate_transition_score(text: &str) -> f64 {
 }
 
 /// Calculate vowel-consonant ratio (English typically has a ratio around 0.4-0.6)
-fn calculate_vowel_consonant_ratio(text: &str) -> f64 {
-    let vowels = ['a', 'e', 'i', 'o', 'u'];
+fn calculate_vowel_consonant_ratio(text: &str, language: &dyn Language) -> f64 {
     let mut vowel_count = 0;
     let mut consonant_count = 0;
 
     for c in text.chars() {
-        if vowels.contains(&c) {
+        if language.is_vowel(c) {
             vowel_count += 1;
         } else if c.is_alphabetic() {
             consonant_count += 1;
@@ -395,42 +1158,12 @@ fn calculate_vowel_consonant_ratio(text: &str) -> f64 {
     vowel_count as f64 / (vowel_count + consonant_count) as f64
 }
 
-// Common character pairs in English
-static COMMON_CHAR_PAIRS: phf::Set<&'static str> = phf_set! {
-    "th", "he", "in", "er", "an", "re", "on", "at", "en", "nd",
-    "ti", "es", "or", "te", "of", "ed", "is", "it", "al", "ar",
-    "st", "to", "nt", "ng", "se", "ha", "as", "ou", "io", "le",
-    "ve", "co", "me", "de", "hi", "ri", "ro", "ic", "ne", "ea",
-    "ra", "ce", "li", "ch", "ll", "be", "ma", "si", "om", "ur"
-};
-
-static COMMON_QUADGRAMS: phf::Set<&'static str> = phf_set! {
-    "tion", "atio", "that", "ther", "with", "ment", "ions", "this",
-    "here", "from", "ould", "ting", "hich", "whic", "ctio", "ever",
-    "they", "thin", "have", "othe", "were", "tive", "ough", "ight"
-};
-
-static COMMON_TRIGRAMS: phf::Set<&'static str> = phf_set! {
-    "the", "and", "ing", "ion", "tio", "ent", "ati", "for", "her", "ter",
-    "hat", "tha", "ere", "con", "res", "ver", "all", "ons", "nce", "men",
-    "ith", "ted", "ers", "pro", "thi", "wit", "are", "ess", "not", "ive",
-    "was", "ect", "rea", "com", "eve", "per", "int", "est", "sta", "cti",
-    "ica", "ist", "ear", "ain", "one", "our", "iti", "rat", "ell", "ant"
-};
-
-static ENGLISH_LETTERS: phf::Set<char> = phf_set! {
-    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
-    'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
-    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
-    'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z'
-};
-
-fn clean_text(text: &str) -> String {
+fn clean_text(text: &str, language: &dyn Language) -> String {
     text.chars()
         .map(|c| {
-            if ENGLISH_LETTERS.contains(&c) || c.is_ascii_digit() {
-                c.to_ascii_lowercase()
-            } else if c.is_whitespace() || c == '_' || c == '-' || c == '/' {
+            if language.is_letter(c) || c.is_ascii_digit() {
+                c.to_lowercase().next().unwrap_or(c)
+            } else if c.is_whitespace() || c == '_' || c == '/' {
                 ' '
             } else if c == ',' || c == '.' || c == '!' || c == '?' {
                 // Keep common punctuation but add a space after it to help with word splitting
@@ -443,12 +1176,12 @@ fn clean_text(text: &str) -> String {
         .collect()
 }
 
-fn generate_ngrams(text: &str, n: usize) -> Vec<String> {
+fn generate_ngrams(text: &str, n: usize, language: &dyn Language) -> Vec<String> {
     let filtered: String = text
         .to_lowercase()
         .chars()
         .map(|ch| {
-            if ENGLISH_LETTERS.contains(&ch) || ch.is_numeric() {
+            if language.is_letter(ch) || ch.is_numeric() {
                 ch
             } else {
                 ' '
@@ -459,9 +1192,11 @@ fn generate_ngrams(text: &str, n: usize) -> Vec<String> {
     filtered
         .split_whitespace()
         .flat_map(|word| {
-            word.as_bytes()
+            let chars: Vec<char> = word.chars().collect();
+            chars
                 .windows(n)
-                .filter_map(|window| String::from_utf8(window.to_vec()).ok())
+                .map(|window| window.iter().collect())
+                .collect::<Vec<String>>()
         })
         .collect()
 }
@@ -471,6 +1206,10 @@ mod tests {
     use super::*;
     use log::{debug, info, warn};
 
+    fn is_english_word(word: &str) -> bool {
+        Lexicon::default().is_english_word(word)
+    }
+
     // Helper function to initialize logger for tests
     fn init_logger() {
         let _ = env_logger::builder()
@@ -484,7 +1223,7 @@ mod tests {
         info!("==== ANALYZING TEXT: '{}' ====", text);
 
         // Clean the text
-        let cleaned = clean_text(text);
+        let cleaned = clean_text(text, &English);
         debug!("Cleaned text: '{}'", cleaned);
 
         // Check if empty after cleaning
@@ -510,11 +1249,22 @@ mod tests {
         }
 
         // Split into words and check for English words
-        let words: Vec<&str> = cleaned
+        let mut words: Vec<&str> = cleaned
             .split_whitespace()
             .filter(|word| !word.is_empty())
             .collect();
 
+        // Text without spaces, e.g. decoded cipher output, is one long unknown
+        // word; use the segmenter's split instead if it is a convincing one
+        if words.len() == 1 && words[0].chars().count() >= MIN_SEGMENT_LEN {
+            let segmentation = segment(words[0]);
+            let cost = segmentation.cost_per_char();
+            debug!("Segmentation cost per character: {:.4}", cost);
+            if cost <= MAX_SEGMENT_COST_PER_CHAR {
+                words = segmentation.words;
+            }
+        }
+
         debug!("Word count: {}", words.len());
 
         // Count English words
@@ -551,11 +1301,11 @@ mod tests {
         debug!("Entropy score: {:.4}", entropy);
 
         // Calculate transition score
-        let transition_score = calculate_transition_score(text);
+        let transition_score = calculate_transition_score(text, &English);
         debug!("Transition score: {:.4}", transition_score);
 
         // Calculate vowel-consonant ratio
-        let vc_ratio = calculate_vowel_consonant_ratio(text);
+        let vc_ratio = calculate_vowel_consonant_ratio(text, &English);
         debug!("Vowel-consonant ratio: {:.4}", vc_ratio);
 
         // Check for substrings that are English words
@@ -573,17 +1323,17 @@ mod tests {
         debug!("English subwords found: {:?}", possible_words);
 
         // N-gram analysis
-        let trigrams = generate_ngrams(&cleaned, 3);
-        let quadgrams = generate_ngrams(&cleaned, 4);
+        let trigrams = generate_ngrams(&cleaned, 3, &English);
+        let quadgrams = generate_ngrams(&cleaned, 4, &English);
 
         let valid_trigrams = trigrams
             .iter()
-            .filter(|gram| COMMON_TRIGRAMS.contains(gram.as_str()))
+            .filter(|gram| English.is_common_trigram(gram))
             .collect::<Vec<_>>();
 
         let valid_quadgrams = quadgrams
             .iter()
-            .filter(|gram| COMMON_QUADGRAMS.contains(gram.as_str()))
+            .filter(|gram| English.is_common_quadgram(gram))
             .collect::<Vec<_>>();
 
         debug!("All trigrams: {:?}", trigrams);
@@ -704,21 +1454,21 @@ mod tests {
         println!("\nTesting text: '{}'", text);
 
         for sensitivity in [Sensitivity::Low, Sensitivity::Medium, Sensitivity::High] {
-            let cleaned = clean_text(text);
+            let cleaned = clean_text(text, &English);
             let words: Vec<&str> = cleaned.split_whitespace().collect();
             let english_words: Vec<&&str> =
                 words.iter().filter(|word| is_english_word(word)).collect();
 
-            let trigrams = generate_ngrams(&cleaned, 3);
-            let quadgrams = generate_ngrams(&cleaned, 4);
+            let trigrams = generate_ngrams(&cleaned, 3, &English);
+            let quadgrams = generate_ngrams(&cleaned, 4, &English);
 
             let valid_trigrams = trigrams
                 .iter()
-                .filter(|gram| COMMON_TRIGRAMS.contains(gram.as_str()))
+                .filter(|gram| English.is_common_trigram(gram))
                 .collect::<Vec<_>>();
             let valid_quadgrams = quadgrams
                 .iter()
-                .filter(|gram| COMMON_QUADGRAMS.contains(gram.as_str()))
+                .filter(|gram| English.is_common_quadgram(gram))
                 .collect::<Vec<_>>();
 
             println!("\nSensitivity {:?}:", sensitivity);
@@ -803,7 +1553,7 @@ mod tests {
         println!("Text: '{}'", text);
 
         // Clean and analyze text
-        let cleaned = clean_text(text);
+        let cleaned = clean_text(text, &English);
         let words: Vec<&str> = cleaned.split_whitespace().collect();
         let english_words: Vec<&&str> = words.iter().filter(|w| is_english_word(w)).collect();
 
@@ -816,17 +1566,17 @@ mod tests {
         );
 
         // Calculate n-gram scores
-        let trigrams = generate_ngrams(&cleaned, 3);
-        let quadgrams = generate_ngrams(&cleaned, 4);
+        let trigrams = generate_ngrams(&cleaned, 3, &English);
+        let quadgrams = generate_ngrams(&cleaned, 4, &English);
 
         let valid_trigrams = trigrams
             .iter()
-            .filter(|gram| COMMON_TRIGRAMS.contains(gram.as_str()))
+            .filter(|gram| English.is_common_trigram(gram))
             .collect::<Vec<_>>();
 
         let valid_quadgrams = quadgrams
             .iter()
-            .filter(|gram| COMMON_QUADGRAMS.contains(gram.as_str()))
+            .filter(|gram| English.is_common_quadgram(gram))
             .collect::<Vec<_>>();
 
         let trigram_score = if trigrams.is_empty() {
@@ -928,6 +1678,185 @@ mod tests {
         assert!(!is_gibberish("This is a longer piece of text that contains multiple sentences and should definitely be recognized as valid English content.", Sensitivity::Medium));
     }
 
+    #[test]
+    fn test_analysis_reports_inflected_words() {
+        let analysis = GibberishDetector::new()
+            .analyze("She emailed the files yesterday", Sensitivity::Medium);
+        assert!(!analysis.is_gibberish);
+        assert_eq!(
+            analysis.inflected_words,
+            vec![("emailed".to_string(), "email".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_typo_tolerance() {
+        let text = "teh quikc brwon fox jumsp ovre teh lazy dgo";
+        assert!(GibberishDetector::new().is_gibberish(text, Sensitivity::Low));
+
+        let detector = GibberishDetector::new().with_typo_tolerance(2);
+        let analysis = detector.analyze(text, Sensitivity::Low);
+        assert!(!analysis.is_gibberish);
+        assert!(analysis
+            .fuzzy_matches
+            .iter()
+            .any(|m| m.word == "brwon" && m.matched == "brown"));
+    }
+
+    #[test]
+    fn test_text_without_spaces_is_segmented() {
+        let analysis = GibberishDetector::new().analyze("meetmeattheparkatnoon", Sensitivity::Low);
+        assert!(!analysis.is_gibberish);
+        assert!(analysis.segmentation_cost.unwrap() < MAX_SEGMENT_COST_PER_CHAR);
+
+        let analysis = GibberishDetector::new().analyze("asdfkjasldkfjqwe", Sensitivity::Low);
+        assert!(analysis.is_gibberish);
+        assert!(analysis.segmentation_cost.unwrap() > MAX_SEGMENT_COST_PER_CHAR);
+    }
+
+    #[test]
+    fn test_normalization() {
+        let text = "We\u{2019}re sure they\u{2019}ll say it\u{2019}s the dog's well-known trick";
+        let normalized = GibberishDetector::new().analyze(text, Sensitivity::Low);
+        let raw = GibberishDetector::new()
+            .with_normalization(Normalization::none())
+            .analyze(text, Sensitivity::Low);
+
+        assert!(normalized.score > raw.score);
+        assert!(normalized
+            .normalized_words
+            .contains(&("dog's".to_string(), "dog".to_string())));
+        assert!(raw.normalized_words.is_empty());
+    }
+
+    #[test]
+    fn test_leet_reading_is_a_separate_signal() {
+        let text = "h3ll0_w0rld_1337";
+        let detector = GibberishDetector::new().with_leet_readings(true);
+        let analysis = detector.analyze(text, Sensitivity::Medium);
+        assert!(analysis.is_gibberish);
+        assert_eq!(
+            analysis.is_gibberish,
+            is_gibberish(text, Sensitivity::Medium)
+        );
+        assert_eq!(analysis.leet_reading.as_deref(), Some("hello_world_leet"));
+
+        let plain = GibberishDetector::new().analyze(text, Sensitivity::Medium);
+        assert!(plain.leet_reading.is_none());
+
+        let handle = detector.analyze("xX_l33t_Xx_n0sc0p3", Sensitivity::Medium);
+        assert!(handle.is_gibberish);
+        assert_eq!(handle.leet_reading.as_deref(), Some("xX_leet_Xx_noscope"));
+        assert!(detector
+            .analyze("xX_qz7k_Xx", Sensitivity::Medium)
+            .leet_reading
+            .is_none());
+    }
+
+    #[test]
+    fn test_runtime_words() {
+        let text = "Restart kubectl and grafana after the helm upgrade";
+        let plain = GibberishDetector::new().analyze(text, Sensitivity::Low);
+        let extended = GibberishDetector::new()
+            .with_words(["kubectl", "Grafana", "helm"])
+            .analyze(text, Sensitivity::Low);
+        assert!(extended.score > plain.score);
+
+        // Segmentation and typo tolerance see added words too
+        let detector = GibberishDetector::new()
+            .with_words(["grafana"])
+            .with_typo_tolerance(1);
+        let analysis = detector.analyze("please restart grafnaa now", Sensitivity::Low);
+        assert_eq!(analysis.fuzzy_matches[0].matched, "grafana");
+    }
+
+    #[test]
+    fn test_hunspell_words() {
+        let aff = "SFX S Y 1\nSFX S 0 s .\n";
+        let dictionary = HunspellDictionary::parse("1\nkubectl/S\n", aff).unwrap();
+        let detector = GibberishDetector::new().with_hunspell(&dictionary);
+        assert!(!detector.is_gibberish("kubectls", Sensitivity::Medium));
+    }
+
+    #[test]
+    fn test_denied_words() {
+        let text = "the cat sat on the mat";
+        let denied = GibberishDetector::new()
+            .with_denied_words(["cat", "sat", "mat"])
+            .score(text);
+        assert!(denied < english_score(text));
+    }
+
+    #[test]
+    fn test_word_file() {
+        let path = std::env::temp_dir().join("gibberish_or_not_test_words.txt");
+        std::fs::write(&path, "# product names\nkubectl\n\ngrafana\n").unwrap();
+        let detector = GibberishDetector::new().with_word_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(!detector.is_gibberish("kubectl", Sensitivity::Medium));
+        assert!(GibberishDetector::new()
+            .with_word_file("/nonexistent/words.txt")
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "french", feature = "german", feature = "spanish"))]
+    fn test_language_packs() {
+        let texts = [
+            (
+                "nous avons trouvé le message dans la maison de mon père",
+                GibberishDetector::new().with_language(French),
+            ),
+            (
+                "wir haben die nachricht im haus meines vaters gefunden",
+                GibberishDetector::new().with_language(German),
+            ),
+            (
+                "encontramos el mensaje en la casa de mi padre ayer",
+                GibberishDetector::new().with_language(Spanish),
+            ),
+        ];
+        for (text, detector) in texts {
+            assert!(is_gibberish(text, Sensitivity::Low), "{}", text);
+            assert!(!detector.is_gibberish(text, Sensitivity::Low), "{}", text);
+            assert!(detector.score(text) > english_score(text), "{}", text);
+            assert!(detector.is_gibberish("xqzvbk wpjfhg lmnrtz qwpfk", Sensitivity::High));
+        }
+    }
+
+    #[test]
+    fn test_word_salad() {
+        test_with_sensitivities("house the with from they we his", true, true, false);
+        test_with_sensitivities(
+            "book the of was letter she in hidden a said",
+            true,
+            true,
+            false,
+        );
+        test_with_sensitivities("blue dancing quickly elephant mountain", true, true, false);
+        assert!(!is_gibberish(
+            "The children played in a field near the mountain",
+            Sensitivity::Low
+        ));
+        assert!(!is_gibberish(
+            "The blue elephant was dancing quickly up the mountain",
+            Sensitivity::Low
+        ));
+    }
+
+    #[test]
+    fn test_headlines_are_not_word_salad() {
+        for headline in [
+            "Stock markets rally amid rate cut hopes",
+            "Heavy rain causes severe flooding across northern towns",
+            "Please review attached quarterly sales report",
+            "Senate passes sweeping climate bill",
+        ] {
+            assert!(!is_gibberish(headline, Sensitivity::Medium), "{}", headline);
+        }
+    }
+
     // Gibberish text tests
     #[test]
     fn test_numbers_only() {
@@ -1335,6 +2264,104 @@ mod tests {
         test_with_sensitivities("%B:;@J A8 4>35= CG3DFL\\ <G697 ?K HAI", true, true, true);
     }
 
+    #[test]
+    fn test_non_ascii_quality() {
+        assert!(!is_string_bad_quality("café"));
+        assert!(!is_string_bad_quality("привет мир"));
+        assert_eq!(calculate_non_printable_ratio("привет мир"), 0.0);
+        assert_eq!(calculate_non_printable_ratio("ab\u{0}\u{1}"), 0.5);
+        assert!(is_string_bad_quality("a\u{200b}\u{200b}\u{200b}\u{200b}"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "russian", feature = "greek", feature = "arabic"))]
+    fn test_non_latin_scripts() {
+        let cases = [
+            ("мы нашли это сообщение вчера в доме моего отца", false),
+            ("жщхъ фывп ьбюэ цзщк ъхжд щшгф", true),
+            ("βρήκαμε το μήνυμα στο σπίτι του πατέρα μου χθες", false),
+            ("ξψζφ χθωβ γδζξ ψφχω ζξψβ", true),
+            ("ظضغ ثخذ شصض ظطغ ضثق", true),
+        ];
+        for (text, gibberish) in cases {
+            test_with_sensitivities(text, gibberish, gibberish, gibberish);
+        }
+
+        // Low needs most words to be known, which the small Arabic word set
+        // rarely allows
+        let arabic = "وجدنا الرسالة في بيت والدي أمس";
+        assert!(!is_gibberish(arabic, Sensitivity::Medium));
+        assert!(!is_gibberish(arabic, Sensitivity::High));
+    }
+
+    #[test]
+    fn test_mixed_script_tokens_are_reported() {
+        let analysis = GibberishDetector::new().analyze(
+            "please log in to your p\u{430}ypal account",
+            Sensitivity::Medium,
+        );
+        assert_eq!(analysis.mixed_script_tokens, ["p\u{430}ypal"]);
+    }
+
+    #[test]
+    fn test_spoofed_english() {
+        // Cyrillic "а" and "е", Greek omicron
+        let text = "y\u{3bf}ur \u{430}ccount h\u{430}s b\u{435}en suspended";
+        let analysis = GibberishDetector::new().analyze(text, Sensitivity::Low);
+        assert!(!analysis.is_gibberish);
+        assert!(analysis.is_spoofed());
+        assert_eq!(analysis.confusables.len(), 4);
+
+        let plain =
+            GibberishDetector::new().analyze("your account has been suspended", Sensitivity::Low);
+        assert!(!plain.is_gibberish);
+        assert!(!plain.is_spoofed());
+    }
+
+    #[test]
+    fn test_invisible_characters_are_reported() {
+        // Trojan Source style bidi override, and a payload hidden in tag characters
+        let text = "access granted \u{202E}\u{2066}// check if admin\u{2069}\u{2066}";
+        let analysis = GibberishDetector::new().analyze(text, Sensitivity::Medium);
+        assert_eq!(analysis.invisible_characters.len(), 4);
+        assert!(analysis
+            .invisible_characters
+            .iter()
+            .all(|&(_, _, category)| category == InvisibleCategory::Bidi));
+
+        let payload: String = "the weather is lovely today"
+            .chars()
+            .chain(
+                "secret"
+                    .chars()
+                    .map(|c| char::from_u32(0xE0000 + c as u32).unwrap()),
+            )
+            .collect();
+        let stripped = GibberishDetector::new()
+            .with_strip_invisible(true)
+            .analyze(&payload, Sensitivity::Medium);
+        assert!(!stripped.is_gibberish);
+        assert_eq!(stripped.invisible_characters.len(), 6);
+    }
+
+    #[test]
+    fn test_bytes_input() {
+        let utf16: Vec<u8> = "please send the report by friday"
+            .encode_utf16()
+            .flat_map(u16::to_be_bytes)
+            .collect();
+        let result = GibberishDetector::new().analyze_bytes(&utf16, Sensitivity::Low);
+        assert!(!result.analysis.is_gibberish);
+        assert_eq!(result.decoded.encoding, "UTF-16BE");
+        assert_eq!(result.decoded.detection, EncodingDetection::NullBytes);
+
+        // Read as UTF-8 or Latin-1, UTF-16 text would be full of NUL characters
+        assert!(is_gibberish(
+            "p\0l\0e\0a\0s\0e\0 \0s\0e\0n\0d\0",
+            Sensitivity::Low
+        ));
+    }
+
     #[test]
     fn test_mixed_latin_gibberish() {
         // Test for mixed Latin character gibberish
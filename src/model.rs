@@ -209,7 +209,7 @@ impl Model {
             let mut file = File::open(&bert_config_path)?;
             let mut contents = String::new();
             file.read_to_string(&mut contents)?;
-            serde_json::from_str(&contents).map_err(|e| ModelError::Json(e))?
+            serde_json::from_str(&contents).map_err(ModelError::Json)?
         };
 
         // Load model weights using Candle
@@ -0,0 +1,184 @@
+use encoding_rs::{
+    Encoding, ISO_8859_15, ISO_8859_2, MACINTOSH, UTF_8, WINDOWS_1250, WINDOWS_1251, WINDOWS_1252,
+};
+
+use crate::lexicon::Lexicon;
+use crate::normalize::normalize_token;
+use crate::{stem, GibberishDetector, Normalization, Score, Sensitivity};
+
+/// Encodings UTF-8 text is commonly mis-decoded as, most common first
+const MIS_DECODINGS: &[&Encoding] = &[
+    WINDOWS_1252,
+    ISO_8859_15,
+    WINDOWS_1250,
+    ISO_8859_2,
+    WINDOWS_1251,
+    MACINTOSH,
+];
+
+/// Text that was decoded twice is still recoverable; deeper chains are rare
+const MAX_CHAIN_LEN: usize = 2;
+
+/// Repaired mojibake, see [`detect_mojibake`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Repair {
+    /// The text as originally written
+    pub text: String,
+    /// The wrong encodings the UTF-8 text was decoded as, by WHATWG name,
+    /// most recent first, e.g. `["windows-1252"]` for "cafÃ©"
+    pub chain: Vec<&'static str>,
+    /// English score of the repaired text
+    pub score: Score,
+}
+
+/// Undo one mis-decoding: re-encode the text the way it was wrongly decoded,
+/// then decode the bytes as the UTF-8 they really were
+fn undo(text: &str, encoding: &'static Encoding) -> Option<String> {
+    let (bytes, _, unmappable) = encoding.encode(text);
+    if unmappable {
+        return None;
+    }
+    let repaired = UTF_8.decode_without_bom_handling_and_without_replacement(&bytes)?;
+    (repaired != text).then(|| repaired.into_owned())
+}
+
+/// Whether every word of the text is an English word, once contractions and
+/// possessives are taken apart.
+///
+/// A word or two is too little text for the detector to call English, but a
+/// repair to known words, such as "don’t" from "donâ€™t", is convincing.
+fn all_words_known(text: &str) -> bool {
+    let lexicon = Lexicon::default();
+    let normalization = Normalization::default();
+    let is_known =
+        |word: &str| lexicon.weight(word).is_some() || stem::lemmatize_in(word, &lexicon).is_some();
+    let mut tokens = text
+        .split(|c: char| !c.is_alphanumeric() && !matches!(c, '\'' | '\u{2019}' | '-'))
+        .map(str::to_lowercase)
+        .filter(|token| token.chars().any(char::is_alphabetic))
+        .peekable();
+    tokens.peek().is_some()
+        && tokens.all(
+            |token| match normalize_token(&token, &normalization, &lexicon) {
+                Some(words) => words.iter().all(|word| is_known(word)),
+                None => is_known(&token),
+            },
+        )
+}
+
+/// Detect UTF-8 text that was decoded with the wrong encoding, such as
+/// "donâ€™t" or "cafÃ©" (UTF-8 read as Windows-1252), and suggest a repair.
+///
+/// Tries the common single-byte encodings, up to two mis-decodings deep, and
+/// returns the most thoroughly undone repair that is not gibberish, or that is
+/// made only of known words, as a single word such as "café" is. Only text
+/// whose bytes form valid multi-byte UTF-8 can be undone, so correctly decoded
+/// text is very unlikely to be "repaired".
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::detect_mojibake;
+///
+/// let repair = detect_mojibake("I donâ€™t know where the cafÃ© is").unwrap();
+/// assert_eq!(repair.text, "I don’t know where the café is");
+/// assert_eq!(repair.chain, ["windows-1252"]);
+///
+/// assert!(detect_mojibake("plain ASCII text is fine").is_none());
+/// ```
+pub fn detect_mojibake(text: &str) -> Option<Repair> {
+    // Mojibake of UTF-8 always has non-ASCII characters
+    if text.is_ascii() {
+        return None;
+    }
+
+    let detector = GibberishDetector::new();
+
+    let mut best: Option<Repair> = None;
+    let mut frontier = vec![(text.to_string(), Vec::new())];
+    for _ in 0..MAX_CHAIN_LEN {
+        let mut next = Vec::new();
+        for (current, chain) in &frontier {
+            for &encoding in MIS_DECODINGS {
+                let Some(repaired) = undo(current, encoding) else {
+                    continue;
+                };
+                let mut chain = chain.clone();
+                chain.push(encoding.name());
+
+                // Each undo that yields valid UTF-8 is strong evidence, so the
+                // shortest, most undone repair wins, then the best score
+                let score = detector.score(&repaired);
+                let chars = repaired.chars().count();
+                let better = best.as_ref().is_none_or(|best| {
+                    let best_chars = best.text.chars().count();
+                    chars < best_chars || (chars == best_chars && score > best.score)
+                });
+                if better
+                    && (!detector.is_gibberish(&repaired, Sensitivity::Medium)
+                        || all_words_known(&repaired))
+                {
+                    best = Some(Repair {
+                        text: repaired.clone(),
+                        chain: chain.clone(),
+                        score,
+                    });
+                }
+                next.push((repaired, chain));
+            }
+        }
+        frontier = next;
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_double_encoding() {
+        // "café" mis-decoded as Windows-1252 twice
+        let once = "the cafÃ© on the corner";
+        let twice: String = {
+            let (bytes, _, _) = UTF_8.encode(once);
+            WINDOWS_1252
+                .decode_without_bom_handling(&bytes)
+                .0
+                .into_owned()
+        };
+        let repair = detect_mojibake(&twice).unwrap();
+        assert_eq!(repair.text, "the café on the corner");
+        assert_eq!(repair.chain, ["windows-1252", "windows-1252"]);
+    }
+
+    #[test]
+    fn test_single_words() {
+        let repair = detect_mojibake("donâ€™t").unwrap();
+        assert_eq!(repair.text, "don’t");
+        assert_eq!(repair.chain, ["windows-1252"]);
+        assert_eq!(detect_mojibake("cafÃ©").unwrap().text, "café");
+        assert!(detect_mojibake("Ã©Ã©").is_none());
+    }
+
+    #[test]
+    fn test_correct_text_is_left_alone() {
+        assert!(detect_mojibake("the café on the corner").is_none());
+        assert!(detect_mojibake("naïve résumé").is_none());
+    }
+
+    #[test]
+    fn test_cyrillic_mojibake() {
+        // Russian UTF-8 read as Windows-1252
+        let (bytes, _, _) = UTF_8.encode("мы нашли это сообщение вчера в доме моего отца");
+        let garbled = WINDOWS_1252
+            .decode_without_bom_handling(&bytes)
+            .0
+            .into_owned();
+        let repair = detect_mojibake(&garbled).unwrap();
+        assert_eq!(
+            repair.text,
+            "мы нашли это сообщение вчера в доме моего отца"
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use crate::lexicon::Lexicon;
+
+/// Characters used in place of a straight apostrophe, e.g. by word processors
+const APOSTROPHE_VARIANTS: &[char] = &['\u{2019}', '\u{2018}', '\u{02BC}', '\u{2032}', '`'];
+
+/// Contractions whose stem changes when expanded
+const IRREGULAR_CONTRACTIONS: &[(&str, &str)] = &[
+    ("won't", "will not"),
+    ("can't", "can not"),
+    ("shan't", "shall not"),
+    ("ain't", "is not"),
+    ("let's", "let us"),
+];
+
+/// Contraction suffixes and their expansions, e.g. "they're" to "they are"
+const CONTRACTION_SUFFIXES: &[(&str, &str)] = &[
+    ("n't", "not"),
+    ("'re", "are"),
+    ("'ve", "have"),
+    ("'ll", "will"),
+    ("'m", "am"),
+    ("'d", "would"),
+];
+
+/// Words where a trailing "'s" means "is" rather than a possessive
+const IS_CONTRACTIONS: &[&str] = &[
+    "it", "that", "he", "she", "what", "there", "here", "who", "where", "how",
+];
+
+/// Which token normalizations run before the dictionary checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalization {
+    /// Expand contractions, e.g. "don't" to "do not"
+    pub contractions: bool,
+    /// Strip possessive endings and stray quotes, e.g. "dog's" to "dog"
+    pub possessives: bool,
+    /// Check each part of a hyphenated compound that is not itself a dictionary word
+    pub hyphenated_compounds: bool,
+}
+
+impl Normalization {
+    /// Run no normalization, looking up tokens exactly as they appear
+    pub fn none() -> Self {
+        Normalization {
+            contractions: false,
+            possessives: false,
+            hyphenated_compounds: false,
+        }
+    }
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Normalization {
+            contractions: true,
+            possessives: true,
+            hyphenated_compounds: true,
+        }
+    }
+}
+
+/// Normalize one lowercased token into the words to look up.
+///
+/// Returns `None` if the token is left unchanged.
+pub(crate) fn normalize_token(
+    token: &str,
+    options: &Normalization,
+    lexicon: &Lexicon,
+) -> Option<Vec<String>> {
+    let parts: Vec<&str> =
+        if options.hyphenated_compounds && token.contains('-') && !lexicon.contains(token) {
+            token.split('-').filter(|part| !part.is_empty()).collect()
+        } else {
+            vec![token]
+        };
+
+    let mut words = Vec::new();
+    for part in parts {
+        words.extend(normalize_apostrophes(part, options));
+    }
+
+    if words.len() == 1 && words[0] == token {
+        None
+    } else {
+        Some(words)
+    }
+}
+
+fn normalize_apostrophes(word: &str, options: &Normalization) -> Vec<String> {
+    if !options.contractions && !options.possessives {
+        return vec![word.to_string()];
+    }
+
+    let mut word = word.replace(APOSTROPHE_VARIANTS, "'");
+    if options.possessives {
+        word = word.trim_matches('\'').to_string();
+    }
+
+    if options.contractions {
+        if let Some(expanded) = expand_contraction(&word) {
+            return expanded;
+        }
+    }
+
+    if options.possessives {
+        if let Some(stem) = word.strip_suffix("'s") {
+            return vec![stem.to_string()];
+        }
+    }
+
+    if word.is_empty() {
+        Vec::new()
+    } else {
+        vec![word]
+    }
+}
+
+fn expand_contraction(word: &str) -> Option<Vec<String>> {
+    let split = |expansion: &str| expansion.split(' ').map(str::to_string).collect();
+
+    if let Some(&(_, expansion)) = IRREGULAR_CONTRACTIONS
+        .iter()
+        .find(|(contraction, _)| *contraction == word)
+    {
+        return Some(split(expansion));
+    }
+
+    if let Some(stem) = word.strip_suffix("'s") {
+        if IS_CONTRACTIONS.contains(&stem) {
+            return Some(vec![stem.to_string(), "is".to_string()]);
+        }
+    }
+
+    CONTRACTION_SUFFIXES
+        .iter()
+        .find_map(|&(suffix, expansion)| {
+            let stem = word.strip_suffix(suffix)?;
+            (!stem.is_empty()).then(|| vec![stem.to_string(), expansion.to_string()])
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(token: &str) -> Option<Vec<String>> {
+        normalize_token(token, &Normalization::default(), &Lexicon::default())
+    }
+
+    #[test]
+    fn test_contractions() {
+        assert_eq!(normalize("don't").unwrap(), ["do", "not"]);
+        assert_eq!(normalize("i'm").unwrap(), ["i", "am"]);
+        assert_eq!(normalize("won't").unwrap(), ["will", "not"]);
+        assert_eq!(normalize("it's").unwrap(), ["it", "is"]);
+    }
+
+    #[test]
+    fn test_possessives_and_curly_quotes() {
+        assert_eq!(normalize("dog's").unwrap(), ["dog"]);
+        assert_eq!(normalize("dog\u{2019}s").unwrap(), ["dog"]);
+        assert_eq!(normalize("dogs'").unwrap(), ["dogs"]);
+        assert_eq!(normalize("they\u{2019}re").unwrap(), ["they", "are"]);
+    }
+
+    #[test]
+    fn test_hyphenated_compounds() {
+        assert_eq!(
+            normalize("state-of-the-art").unwrap(),
+            ["state", "of", "the", "art"]
+        );
+        assert_eq!(normalize("well-known").unwrap(), ["well", "known"]);
+    }
+
+    #[test]
+    fn test_unchanged_tokens() {
+        assert_eq!(normalize("hello"), None);
+        assert_eq!(
+            normalize_token("don't", &Normalization::none(), &Lexicon::default()),
+            None
+        );
+    }
+}
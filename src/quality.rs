@@ -0,0 +1,184 @@
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_script::{Script, UnicodeScript};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Share of invisible graphemes above which text is not worth analysing
+const MAX_INVISIBLE_RATIO: f64 = 0.5;
+
+/// Texts with this many graphemes or fewer are too short to judge
+const MIN_GRAPHEMES: usize = 3;
+
+/// What a grapheme cluster (a user-perceived character) looks like when rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GraphemeClass {
+    /// Visible Latin-script text, e.g. "a", "é" or "e" with a combining accent
+    Latin,
+    /// Visible text in any other script, e.g. Cyrillic letters or CJK ideographs
+    NonLatin,
+    /// Visible characters shared by all scripts: digits, punctuation, symbols and emoji
+    Common,
+    /// Spaces, tabs and line breaks
+    Whitespace,
+    /// Control, format, private-use, surrogate or unassigned characters with
+    /// nothing visible to show
+    Invisible,
+}
+
+/// Returns true if the character renders as something visible on its own
+fn is_visible(c: char) -> bool {
+    use GeneralCategory::*;
+    !matches!(
+        get_general_category(c),
+        Control
+            | Format
+            | PrivateUse
+            | Surrogate
+            | Unassigned
+            | SpaceSeparator
+            | LineSeparator
+            | ParagraphSeparator
+    )
+}
+
+impl GraphemeClass {
+    /// Classify a single grapheme cluster by its visible characters.
+    ///
+    /// Joiners and variation selectors inside an emoji or a combining sequence
+    /// do not make the cluster invisible.
+    pub fn of(grapheme: &str) -> Self {
+        let mut visible = grapheme.chars().filter(|&c| is_visible(c)).peekable();
+        if visible.peek().is_none() {
+            return if grapheme.chars().all(char::is_whitespace) && !grapheme.is_empty() {
+                Self::Whitespace
+            } else {
+                Self::Invisible
+            };
+        }
+
+        match visible
+            .map(|c| c.script())
+            .find(|script| !matches!(script, Script::Common | Script::Inherited))
+        {
+            Some(Script::Latin) => Self::Latin,
+            Some(_) => Self::NonLatin,
+            None => Self::Common,
+        }
+    }
+
+    /// Returns true for graphemes that show up when the text is rendered
+    pub fn is_printable(self) -> bool {
+        matches!(self, Self::Latin | Self::NonLatin | Self::Common)
+    }
+}
+
+/// Character and grapheme counts of a text, see [`TextQuality::measure`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextQuality {
+    /// Unicode scalar values
+    pub chars: usize,
+    /// Grapheme clusters, what a reader would count as characters
+    pub graphemes: usize,
+    /// Graphemes in the Latin script
+    pub latin: usize,
+    /// Printable graphemes in other scripts
+    pub non_latin: usize,
+    /// Digits, punctuation, symbols and emoji
+    pub common: usize,
+    /// Whitespace graphemes
+    pub whitespace: usize,
+    /// Control, format and other invisible graphemes
+    pub invisible: usize,
+}
+
+impl TextQuality {
+    /// Count the graphemes of each [`GraphemeClass`] in the text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gibberish_or_not::TextQuality;
+    ///
+    /// let quality = TextQuality::measure("café\u{0}");
+    /// assert_eq!(quality.graphemes, 5);
+    /// assert_eq!(quality.latin, 4);
+    /// assert_eq!(quality.invisible, 1);
+    ///
+    /// // Printable text in other scripts is not invisible
+    /// assert_eq!(TextQuality::measure("привет").invisible, 0);
+    /// ```
+    pub fn measure(text: &str) -> Self {
+        let mut quality = Self {
+            chars: text.chars().count(),
+            ..Self::default()
+        };
+        for grapheme in text.graphemes(true) {
+            quality.graphemes += 1;
+            match GraphemeClass::of(grapheme) {
+                GraphemeClass::Latin => quality.latin += 1,
+                GraphemeClass::NonLatin => quality.non_latin += 1,
+                GraphemeClass::Common => quality.common += 1,
+                GraphemeClass::Whitespace => quality.whitespace += 1,
+                GraphemeClass::Invisible => quality.invisible += 1,
+            }
+        }
+        quality
+    }
+
+    /// Printable graphemes of any script
+    pub fn printable(&self) -> usize {
+        self.latin + self.non_latin + self.common
+    }
+
+    /// Share of graphemes that are invisible, from 0.0 to 1.0
+    pub fn invisible_ratio(&self) -> f64 {
+        if self.graphemes == 0 {
+            0.0
+        } else {
+            self.invisible as f64 / self.graphemes as f64
+        }
+    }
+
+    /// Returns true if the text is too short or mostly invisible to be worth analysing
+    pub fn is_bad(&self) -> bool {
+        self.invisible_ratio() > MAX_INVISIBLE_RATIO || self.graphemes <= MIN_GRAPHEMES
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grapheme_classes() {
+        assert_eq!(GraphemeClass::of("a"), GraphemeClass::Latin);
+        assert_eq!(GraphemeClass::of("e\u{301}"), GraphemeClass::Latin);
+        assert_eq!(GraphemeClass::of("ж"), GraphemeClass::NonLatin);
+        assert_eq!(GraphemeClass::of("中"), GraphemeClass::NonLatin);
+        assert_eq!(GraphemeClass::of("7"), GraphemeClass::Common);
+        assert_eq!(GraphemeClass::of("👍🏽"), GraphemeClass::Common);
+        assert_eq!(GraphemeClass::of("\r\n"), GraphemeClass::Whitespace);
+        assert_eq!(GraphemeClass::of("\u{0}"), GraphemeClass::Invisible);
+        assert_eq!(GraphemeClass::of("\u{200b}"), GraphemeClass::Invisible);
+        assert_eq!(GraphemeClass::of("\u{e000}"), GraphemeClass::Invisible);
+    }
+
+    #[test]
+    fn test_counts_graphemes_not_bytes() {
+        // Family emoji: one grapheme, seven chars joined by zero-width joiners
+        let quality = TextQuality::measure("👨\u{200d}👩\u{200d}👧\u{200d}👦");
+        assert_eq!(quality.graphemes, 1);
+        assert_eq!(quality.chars, 7);
+        assert_eq!(quality.invisible, 0);
+
+        assert!(!TextQuality::measure("Über").is_bad());
+        assert!(!TextQuality::measure("日本語です").is_bad());
+        assert!(TextQuality::measure("日本").is_bad());
+    }
+
+    #[test]
+    fn test_mostly_invisible_text_is_bad() {
+        assert!(TextQuality::measure("\u{1}\u{2}\u{3}ab\u{4}\u{5}").is_bad());
+        assert!(!TextQuality::measure("hello\u{0}").is_bad());
+        assert_eq!(TextQuality::measure("").invisible_ratio(), 0.0);
+    }
+}
@@ -0,0 +1,161 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+use crate::{GibberishDetector, Score};
+
+/// A candidate text together with its English-likeness score
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ranked<T> {
+    /// The candidate as it was passed in
+    pub candidate: T,
+    /// Position of the candidate in the input sequence
+    pub index: usize,
+    /// English-likeness score, see [`GibberishDetector::score`]
+    pub score: Score,
+}
+
+impl<T> Ranked<T> {
+    /// Higher scores rank first; equal scores keep their input order
+    fn rank_cmp(&self, other: &Self) -> Ordering {
+        other
+            .score
+            .total_cmp(&self.score)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+/// Heap entry ordered so that the best candidate is the greatest
+struct HeapEntry<T>(Ranked<T>);
+
+impl<T> PartialEq for HeapEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for HeapEntry<T> {}
+
+impl<T> PartialOrd for HeapEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for HeapEntry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.rank_cmp(&self.0)
+    }
+}
+
+impl GibberishDetector {
+    /// Rank candidate plaintexts by how English-like they are, best first.
+    ///
+    /// Candidates with equal scores keep their input order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gibberish_or_not::GibberishDetector;
+    ///
+    /// let detector = GibberishDetector::new();
+    /// let ranked = detector.rank(["Uryyb jbeyq", "Hello world", "Gdkkn vnqkc"]);
+    /// assert_eq!(ranked[0].candidate, "Hello world");
+    /// assert_eq!(ranked[0].index, 1);
+    /// ```
+    pub fn rank<T, I>(&self, candidates: I) -> Vec<Ranked<T>>
+    where
+        T: AsRef<str>,
+        I: IntoIterator<Item = T>,
+    {
+        let mut ranked: Vec<Ranked<T>> = candidates
+            .into_iter()
+            .enumerate()
+            .map(|(index, candidate)| self.ranked(index, candidate))
+            .collect();
+        ranked.sort_by(Ranked::rank_cmp);
+        ranked
+    }
+
+    /// Keep the `k` most English-like candidates from a stream, best first.
+    ///
+    /// Only `k` candidates are held in memory at any time, so this is suitable
+    /// for large or lazily generated candidate sets. Ordering matches [`rank`](Self::rank).
+    pub fn best_of<T, I>(&self, candidates: I, k: usize) -> Vec<Ranked<T>>
+    where
+        T: AsRef<str>,
+        I: IntoIterator<Item = T>,
+    {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        // Min-heap on rank, so the worst kept candidate is evicted first
+        let mut heap: BinaryHeap<Reverse<HeapEntry<T>>> = BinaryHeap::with_capacity(k + 1);
+        for (index, candidate) in candidates.into_iter().enumerate() {
+            heap.push(Reverse(HeapEntry(self.ranked(index, candidate))));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(HeapEntry(ranked))| ranked)
+            .collect()
+    }
+
+    fn ranked<T: AsRef<str>>(&self, index: usize, candidate: T) -> Ranked<T> {
+        Ranked {
+            score: self.score(candidate.as_ref()),
+            index,
+            candidate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CANDIDATES: [&str; 5] = [
+        "xgcyzw Snh fabkqta jedm",
+        "The quick brown fox jumps over the lazy dog",
+        "h2=ReOrS9DAnED8o",
+        "Hello world, this is a test",
+        "Vszzc hvwg wg zcbu",
+    ];
+
+    #[test]
+    fn test_rank_orders_by_score() {
+        let ranked = GibberishDetector::new().rank(CANDIDATES);
+        assert_eq!(ranked.len(), CANDIDATES.len());
+        assert!(ranked.windows(2).all(|w| w[0].score >= w[1].score));
+        assert!([1, 3].contains(&ranked[0].index));
+    }
+
+    #[test]
+    fn test_rank_ties_keep_input_order() {
+        let ranked = GibberishDetector::new().rank(["same text here", "same text here"]);
+        assert_eq!(ranked[0].index, 0);
+        assert_eq!(ranked[1].index, 1);
+    }
+
+    #[test]
+    fn test_best_of_matches_rank() {
+        let detector = GibberishDetector::new();
+        let ranked = detector.rank(CANDIDATES);
+        let best = detector.best_of(CANDIDATES.iter().map(|c| c.to_string()), 2);
+        assert_eq!(best.len(), 2);
+        for (b, r) in best.iter().zip(&ranked) {
+            assert_eq!(b.index, r.index);
+            assert_eq!(b.candidate, r.candidate);
+        }
+    }
+
+    #[test]
+    fn test_best_of_zero_and_short_input() {
+        let detector = GibberishDetector::new();
+        assert!(detector.best_of(CANDIDATES, 0).is_empty());
+        assert_eq!(detector.best_of(CANDIDATES, 10).len(), CANDIDATES.len());
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+pub use unicode_script::Script;
+use unicode_script::UnicodeScript;
+use unicode_security::MixedScript;
+
+/// The script of a character, or `None` for digits, punctuation, symbols and
+/// combining marks that are shared between scripts
+fn specific_script(c: char) -> Option<Script> {
+    match c.script() {
+        Script::Common | Script::Inherited | Script::Unknown => None,
+        script => Some(script),
+    }
+}
+
+/// The script most letters of the text are written in, e.g. [`Script::Cyrillic`]
+/// for Russian. Returns `None` if the text has no letters.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::{dominant_script, Script};
+///
+/// assert_eq!(dominant_script("Hello, world!"), Some(Script::Latin));
+/// assert_eq!(dominant_script("Привет, мир! OK"), Some(Script::Cyrillic));
+/// assert_eq!(dominant_script("1234 !?"), None);
+/// ```
+pub fn dominant_script(text: &str) -> Option<Script> {
+    let mut counts: HashMap<Script, usize> = HashMap::new();
+    for script in text.chars().filter_map(specific_script) {
+        *counts.entry(script).or_insert(0) += 1;
+    }
+    // Break ties by name so the result does not depend on hash order
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.full_name().cmp(a.0.full_name())))
+        .map(|(script, _)| script)
+}
+
+/// Words whose letters come from more than one script.
+///
+/// Real words are written in a single script, so a token like "pаypal" with a
+/// Cyrillic "а" is a classic sign of spoofing. Tokens are split on whitespace
+/// and returned in order, with surrounding punctuation intact.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::mixed_script_tokens;
+///
+/// assert_eq!(mixed_script_tokens("log in to p\u{430}ypal now"), ["p\u{430}ypal"]);
+/// assert!(mixed_script_tokens("plain English, чистый русский").is_empty());
+/// ```
+pub fn mixed_script_tokens(text: &str) -> Vec<&str> {
+    text.split_whitespace()
+        .filter(|token| is_mixed_script(token))
+        .collect()
+}
+
+/// Returns true if the letters of the token come from more than one script.
+///
+/// Scripts are compared as Unicode's augmented script sets, so Japanese mixing
+/// Han with kana and Korean mixing Han with Hangul count as a single script.
+pub(crate) fn is_mixed_script(token: &str) -> bool {
+    !token.is_single_script()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dominant_script() {
+        assert_eq!(dominant_script("Καλημέρα κόσμε"), Some(Script::Greek));
+        assert_eq!(dominant_script("مرحبا بالعالم"), Some(Script::Arabic));
+        assert_eq!(dominant_script("東京都庁 ok"), Some(Script::Han));
+        assert_eq!(dominant_script(""), None);
+    }
+
+    #[test]
+    fn test_mixed_script_tokens() {
+        // Greek omicron in "gοogle", Cyrillic "е" in "applе"
+        let text = "visit gοogle.com or applе.com today";
+        assert_eq!(mixed_script_tokens(text), ["gοogle.com", "applе.com"]);
+        // Digits and punctuation belong to every script
+        assert!(mixed_script_tokens("Москва-2024 (ok)").is_empty());
+        // Japanese and Korean combine Han with their own scripts
+        assert!(mixed_script_tokens("日本語のテキストです").is_empty());
+        assert!(mixed_script_tokens("韓國語한국어").is_empty());
+    }
+}
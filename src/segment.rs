@@ -0,0 +1,145 @@
+use crate::frequencies::WORD_ZIPF;
+use crate::lexicon::Lexicon;
+use crate::words::contains_word;
+
+/// Longest word the segmenter will consider, in characters
+const MAX_WORD_LEN: usize = 24;
+
+/// Zipf value assumed for dictionary words missing from the frequency list
+const RARE_WORD_ZIPF: f64 = 1.5;
+
+/// Zipf value assumed for words added to a detector at runtime
+const EXTRA_WORD_ZIPF: f64 = 4.0;
+
+/// Cost of a character that is not part of any known word
+const UNKNOWN_CHAR_COST: f64 = 10.0;
+
+/// Text split into words, see [`segment`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segmentation<'a> {
+    /// The words, as slices of the original text
+    pub words: Vec<&'a str>,
+    /// Total cost, the negative log10 probability of the words under a unigram model
+    pub cost: f64,
+}
+
+impl Segmentation<'_> {
+    /// Average cost per character, comparable between texts of different lengths.
+    ///
+    /// English split into common words costs around one per character, while
+    /// random letters cost several.
+    pub fn cost_per_char(&self) -> f64 {
+        let chars: usize = self.words.iter().map(|word| word.chars().count()).sum();
+        if chars == 0 {
+            0.0
+        } else {
+            self.cost / chars as f64
+        }
+    }
+}
+
+/// Cost of a single word: common words are cheap, unknown strings expensive
+fn word_cost(word: &str, lexicon: &Lexicon) -> f64 {
+    let lowered = word.to_lowercase();
+    if lexicon.is_denied(&lowered) {
+        UNKNOWN_CHAR_COST * lowered.chars().count() as f64
+    } else if let Some(&tenths) = WORD_ZIPF.get(lowered.as_str()) {
+        // Zipf is log10 occurrences per billion words
+        9.0 - f64::from(tenths) / 10.0
+    } else if lexicon.is_extra(&lowered) {
+        9.0 - EXTRA_WORD_ZIPF
+    } else if lowered.chars().count() > 1 && contains_word(&lowered) {
+        9.0 - RARE_WORD_ZIPF
+    } else {
+        UNKNOWN_CHAR_COST * lowered.chars().count() as f64
+    }
+}
+
+/// Split text without spaces into its most likely sequence of words.
+///
+/// Finds the split with the lowest total cost using dynamic programming, where
+/// each word costs the negative log of its frequency. Characters that are not
+/// part of any dictionary word are kept as single-character pieces with a high
+/// cost.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::segment;
+///
+/// let segmentation = segment("thequickbrownfox");
+/// assert_eq!(segmentation.words, ["the", "quick", "brown", "fox"]);
+/// assert!(segmentation.cost_per_char() < segment("xqzvbkwpjf").cost_per_char());
+/// ```
+pub fn segment(text: &str) -> Segmentation<'_> {
+    segment_in(text, &Lexicon::default())
+}
+
+/// [`segment`] against a lexicon with runtime words added or denied
+pub(crate) fn segment_in<'a>(text: &'a str, lexicon: &Lexicon) -> Segmentation<'a> {
+    let boundaries: Vec<usize> = text
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(text.len()))
+        .collect();
+    let n = boundaries.len() - 1;
+
+    // best[i] is the cheapest split of the first i characters and where its last word starts
+    let mut best: Vec<(f64, usize)> = vec![(0.0, 0); n + 1];
+    for end in 1..=n {
+        best[end] = (end.saturating_sub(MAX_WORD_LEN)..end)
+            .map(|start| {
+                let word = &text[boundaries[start]..boundaries[end]];
+                (best[start].0 + word_cost(word, lexicon), start)
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .unwrap();
+    }
+
+    let mut words = Vec::new();
+    let mut end = n;
+    while end > 0 {
+        let start = best[end].1;
+        words.push(&text[boundaries[start]..boundaries[end]]);
+        end = start;
+    }
+    words.reverse();
+
+    Segmentation {
+        words,
+        cost: best[n].0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments_common_words() {
+        assert_eq!(
+            segment("itwasabrightcoldday").words,
+            ["it", "was", "a", "bright", "cold", "day"]
+        );
+    }
+
+    #[test]
+    fn test_keeps_original_case() {
+        assert_eq!(segment("HelloWorld").words, ["Hello", "World"]);
+    }
+
+    #[test]
+    fn test_unknown_characters_are_expensive() {
+        let english = segment("secretmessage");
+        let noise = segment("qxzvkjwpqxzv");
+        assert!(english.cost_per_char() < 2.0);
+        assert!(noise.cost_per_char() > 5.0);
+    }
+
+    #[test]
+    fn test_empty_text() {
+        let segmentation = segment("");
+        assert!(segmentation.words.is_empty());
+        assert_eq!(segmentation.cost, 0.0);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::bigrams::{CORPUS_PAIRS, WORD_BIGRAMS};
+use crate::classes::{CLASS_COUNT, CLASS_PAIR_RATIOS, WORD_CLASSES};
+use crate::frequencies::WORD_ZIPF;
+
+/// A pair is judged by its own count only when its two words are common
+/// enough that the corpus would be expected to hold it at least this many
+/// times by chance. Rarer pairs are judged by the classes of their words,
+/// since the corpus is too small to say whether they are missing by chance.
+const MIN_EXPECTED_COUNT: f64 = 20.0;
+
+/// Added to both the observed and the expected count of a pair, so that a
+/// pair seen once or not at all is not judged on too little evidence
+const SMOOTHING: f64 = 0.5;
+
+/// Range each pair's log ratio is clamped to, so that neither a pair missing
+/// from the corpus nor a very common one decides the score on its own
+const LOWEST_LOG_RATIO: f64 = -2.0;
+const HIGHEST_LOG_RATIO: f64 = 1.0;
+
+/// Fewest pairs the model must cover before the text is scored at all
+const MIN_COVERED_PAIRS: usize = 3;
+
+/// Split text into lowercased words, one list per sentence.
+///
+/// Uses the same rules as the `dictionary` binary does when counting pairs:
+/// words are runs of letters and apostrophes, with curly apostrophes
+/// straightened and quotes around a word dropped, and sentences end at `.`,
+/// `!`, `?`, `;`, `:` or a line break.
+fn sentences(text: &str) -> Vec<Vec<String>> {
+    text.split(['.', '!', '?', ';', ':', '\n'])
+        .map(|sentence| {
+            sentence
+                .split(|c: char| !c.is_alphabetic() && !matches!(c, '\'' | '\u{2019}'))
+                .map(|word| word.replace('\u{2019}', "'"))
+                .map(|word| word.trim_matches('\'').to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect()
+        })
+        .collect()
+}
+
+/// How often a pair would occur in the corpus if its words were independent
+fn expected_count(first: &str, second: &str) -> Option<f64> {
+    // Zipf values are log10 of the frequency per billion words, in tenths
+    let probability = |word: &str| {
+        WORD_ZIPF
+            .get(word)
+            .map(|&zipf| 10f64.powf(zipf as f64 / 10.0 - 9.0))
+    };
+    Some(probability(first)? * probability(second)? * CORPUS_PAIRS as f64)
+}
+
+fn clamp_log_ratio(ratio: f64) -> f64 {
+    ratio.ln().clamp(LOWEST_LOG_RATIO, HIGHEST_LOG_RATIO)
+}
+
+/// Log of how much more often a pair occurs in the corpus than chance
+/// predicts, or `None` if the pair is too rare to judge by its own count
+fn pair_score(first: &str, second: &str) -> Option<f64> {
+    let expected = expected_count(first, second).filter(|&e| e >= MIN_EXPECTED_COUNT)?;
+    let observed = WORD_BIGRAMS
+        .get(format!("{} {}", first, second).as_str())
+        .map_or(0.0, |&count| count as f64);
+    Some(clamp_log_ratio(
+        (observed + SMOOTHING) / (expected + SMOOTHING),
+    ))
+}
+
+/// Classes a word can belong to, or `None` alone if it has none on record
+fn word_classes(word: &str) -> Vec<Option<usize>> {
+    match WORD_CLASSES.get(word) {
+        Some(&bits) => (0..CLASS_COUNT)
+            .filter(|class| bits & (1 << class) != 0)
+            .map(Some)
+            .collect(),
+        None => vec![None],
+    }
+}
+
+/// Total log ratio of the pairs of a sentence, along with the number of pairs
+/// that could be judged.
+///
+/// A pair of common words is judged by its own count, and any other pair by
+/// the classes of its words, reading each word in whichever of its classes
+/// (noun, verb, adverb, ...) gives the sentence the best total. Pairs with a
+/// word of no known class count as neither likely nor unlikely.
+fn sentence_score(words: &[String]) -> (f64, usize) {
+    let Some(first) = words.first() else {
+        return (0.0, 0);
+    };
+
+    // Best total so far for each class the latest word could have
+    let mut best: Vec<(Option<usize>, f64)> = word_classes(first)
+        .into_iter()
+        .map(|class| (class, 0.0))
+        .collect();
+    let mut covered = 0;
+    for pair in words.windows(2) {
+        let word_score = pair_score(&pair[0], &pair[1]);
+        let classes = word_classes(&pair[1]);
+        if word_score.is_some() || (best[0].0.is_some() && classes[0].is_some()) {
+            covered += 1;
+        }
+
+        best = classes
+            .into_iter()
+            .map(|class| {
+                let total = best
+                    .iter()
+                    .map(|&(previous, total)| {
+                        total
+                            + match (word_score, previous, class) {
+                                (Some(score), _, _) => score,
+                                (None, Some(previous), Some(class)) => {
+                                    clamp_log_ratio(CLASS_PAIR_RATIOS[previous][class])
+                                }
+                                _ => 0.0,
+                            }
+                    })
+                    .fold(f64::NEG_INFINITY, f64::max);
+                (class, total)
+            })
+            .collect();
+    }
+
+    let total = best
+        .iter()
+        .map(|&(_, total)| total)
+        .fold(f64::NEG_INFINITY, f64::max);
+    (total, covered)
+}
+
+/// Score how plausible the order of the words in the text is, from 0.0 to 1.0.
+///
+/// Each pair of adjacent words within a sentence is compared with a corpus of
+/// English, by the log of the ratio between how often it occurs and how often
+/// chance alone would put its words together. Pairs of common words are
+/// looked up directly, so that "of the" scores high and "the of" low. Other
+/// pairs are judged by the classes of their words, so that an adverb before a
+/// noun ("quickly elephant") scores low and an adjective before one ("blue
+/// elephant") high, with each word read in whichever of its classes makes the
+/// sentence most plausible.
+///
+/// The result is the average log ratio over all pairs, each clamped to
+/// between -2 and 1, rescaled to run from 0.0 to 1.0. Real sentences nearly
+/// always score above 0.6, while common words in a random order ("word
+/// salad") often score far lower. It is `None` unless at least three pairs
+/// and half of all pairs could be judged.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::word_sequence_plausibility;
+///
+/// let sentence = word_sequence_plausibility("They had not been able to find it").unwrap();
+/// let salad = word_sequence_plausibility("house the with from they we his").unwrap();
+/// assert!(sentence > 0.65 && salad < 0.4);
+///
+/// let headline = word_sequence_plausibility("Senate passes sweeping climate bill").unwrap();
+/// let salad = word_sequence_plausibility("blue dancing quickly elephant mountain").unwrap();
+/// assert!(headline > salad);
+/// assert_eq!(word_sequence_plausibility("hello"), None);
+/// ```
+pub fn word_sequence_plausibility(text: &str) -> Option<f64> {
+    let mut total = 0.0;
+    let mut pairs = 0;
+    let mut covered = 0;
+    for words in sentences(text) {
+        let (score, judged) = sentence_score(&words);
+        total += score;
+        pairs += words.len().saturating_sub(1);
+        covered += judged;
+    }
+
+    if covered < MIN_COVERED_PAIRS || covered * 2 < pairs {
+        None
+    } else {
+        let average = total / pairs as f64;
+        Some((average - LOWEST_LOG_RATIO) / (HIGHEST_LOG_RATIO - LOWEST_LOG_RATIO))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pair_scores_follow_counts() {
+        assert!(pair_score("of", "the").unwrap() > 0.5);
+        assert_eq!(pair_score("the", "of"), Some(LOWEST_LOG_RATIO));
+        assert_eq!(pair_score("elephant", "mountain"), None);
+    }
+
+    #[test]
+    fn test_word_salad_scores_low() {
+        let salad = word_sequence_plausibility("house the with from they we his").unwrap();
+        assert!(salad < 0.4);
+
+        let sentence =
+            word_sequence_plausibility("He has been working here for about ten years").unwrap();
+        assert!(sentence > 0.65);
+    }
+
+    #[test]
+    fn test_rare_pairs_are_judged_by_class() {
+        let salad = word_sequence_plausibility("blue dancing quickly elephant mountain").unwrap();
+        let sentence =
+            word_sequence_plausibility("The blue elephant was dancing quickly up the mountain")
+                .unwrap();
+        assert!(salad < 0.62);
+        assert!(sentence > 0.65);
+    }
+
+    #[test]
+    fn test_pairs_do_not_cross_sentences() {
+        assert_eq!(sentences("Stop. Go now!").len(), 3);
+        assert_eq!(sentences("\u{2018}Don\u{2019}t\u{2019}")[0], ["don't"]);
+        assert_eq!(word_sequence_plausibility("Stop. Go."), None);
+    }
+}
@@ -0,0 +1,108 @@
+use std::ops::Range;
+
+use crate::{GibberishDetector, Score, Sensitivity, TextFeatures};
+
+/// Words per sliding window
+const WINDOW_WORDS: usize = 4;
+
+/// Byte ranges of the whitespace-separated tokens of the text
+fn token_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+    for (index, c) in text.char_indices() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some(index),
+            (true, Some(begin)) => {
+                ranges.push(begin..index);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        ranges.push(begin..text.len());
+    }
+    ranges
+}
+
+/// English spans of the text, see [`find_english_spans`](crate::find_english_spans)
+pub(crate) fn find_spans(
+    detector: &GibberishDetector,
+    text: &str,
+    sensitivity: Sensitivity,
+) -> Vec<(Range<usize>, Score)> {
+    let tokens = token_ranges(text);
+    let window = WINDOW_WORDS.min(tokens.len());
+    let mut english = vec![false; tokens.len()];
+    if window > 0 {
+        for start in 0..=tokens.len() - window {
+            let range = tokens[start].start..tokens[start + window - 1].end;
+            if !detector.is_gibberish(&text[range], sensitivity) {
+                english[start..start + window].fill(true);
+            }
+        }
+    }
+
+    let has_english_word = |token: &Range<usize>| {
+        TextFeatures::extract(&text[token.clone()], detector).english_word_count > 0
+    };
+
+    let mut spans = Vec::new();
+    let mut index = 0;
+    while index < tokens.len() {
+        if !english[index] {
+            index += 1;
+            continue;
+        }
+        let mut end = index;
+        while end + 1 < tokens.len() && english[end + 1] {
+            end += 1;
+        }
+
+        let (mut first, mut last) = (index, end);
+        while first < last && !has_english_word(&tokens[first]) {
+            first += 1;
+        }
+        while last > first && !has_english_word(&tokens[last]) {
+            last -= 1;
+        }
+
+        let range = tokens[first].start..tokens[last].end;
+        let analysis = detector.analyze(&text[range.clone()], sensitivity);
+        if !analysis.is_gibberish {
+            spans.push((range, analysis.score));
+        }
+        index = end + 1;
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_ranges() {
+        assert_eq!(token_ranges("  ab c\td  "), [2..4, 5..6, 7..8]);
+        assert!(token_ranges("   ").is_empty());
+    }
+
+    #[test]
+    fn test_sentence_in_noise() {
+        let noise = "TWFuIGlzIGRpc3Rpbmd1aXNoZWQsIG5vdCBvbmx5IGJ5IGhpcyByZWFzb24=";
+        let sentence = "the meeting has been moved to thursday afternoon";
+        let text = format!("{noise} {noise} {sentence} {noise}");
+
+        let spans = find_spans(&GibberishDetector::new(), &text, Sensitivity::Medium);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(&text[spans[0].0.clone()], sentence);
+        assert!(spans[0].1 > 0.3);
+    }
+
+    #[test]
+    fn test_no_english() {
+        let detector = GibberishDetector::new();
+        assert!(find_spans(&detector, "", Sensitivity::Medium).is_empty());
+        assert!(find_spans(&detector, "qzx8 vbn3 kkjw plm0 zzqq", Sensitivity::Medium).is_empty());
+    }
+}
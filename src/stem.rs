@@ -0,0 +1,166 @@
+use crate::lexicon::Lexicon;
+
+/// Suffix rewrite rules, tried in order: strip the suffix, then append the replacement
+const SUFFIX_RULES: &[(&str, &str)] = &[
+    // Plurals and third person
+    ("ies", "y"),
+    ("es", ""),
+    ("s", ""),
+    // Past tense and participles
+    ("ied", "y"),
+    ("ed", ""),
+    ("ed", "e"),
+    ("ing", ""),
+    ("ing", "e"),
+    // Comparatives and agent nouns
+    ("ier", "y"),
+    ("iest", "y"),
+    ("er", ""),
+    ("er", "e"),
+    ("est", ""),
+    ("est", "e"),
+    // Derivations
+    ("ily", "y"),
+    ("ly", ""),
+    ("ness", ""),
+    ("ment", ""),
+    ("ful", ""),
+    ("less", ""),
+    ("able", ""),
+    ("able", "e"),
+];
+
+/// Suffixes after which a doubled final consonant is undone, e.g. "stopped" to "stop"
+const UNDOUBLING_SUFFIXES: &[&str] = &["ed", "ing", "er", "est"];
+
+/// Shortest stem we accept, so "as" does not become "a"
+const MIN_STEM_LEN: usize = 3;
+
+/// How many suffixes may be peeled off, e.g. "emailers" to "emailer" to "email"
+const MAX_DEPTH: usize = 2;
+
+/// Find the dictionary base form of an inflected word.
+///
+/// Applies lightweight English suffix rules (plurals, `-ed`, `-ing`, `-er`,
+/// `-ly`, ...) and returns the first candidate base that is in the dictionary.
+/// Returns `None` if the word is already a dictionary word or no base is found.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::lemmatize;
+///
+/// assert_eq!(lemmatize("emailed").as_deref(), Some("email"));
+/// assert_eq!(lemmatize("emailers").as_deref(), Some("email"));
+/// assert_eq!(lemmatize("xkcdzqing"), None);
+/// ```
+pub fn lemmatize(word: &str) -> Option<String> {
+    lemmatize_in(word, &Lexicon::default())
+}
+
+/// [`lemmatize`] against a lexicon with runtime words added or denied
+pub(crate) fn lemmatize_in(word: &str, lexicon: &Lexicon) -> Option<String> {
+    let word = word.to_lowercase();
+    if !word.chars().all(char::is_alphabetic) || lexicon.contains(&word) {
+        return None;
+    }
+    find_base(&word, MAX_DEPTH, lexicon)
+}
+
+fn find_base(word: &str, depth: usize, lexicon: &Lexicon) -> Option<String> {
+    if depth == 0 {
+        return None;
+    }
+
+    let candidates = base_candidates(word);
+    candidates
+        .iter()
+        .find(|candidate| lexicon.contains(candidate))
+        .cloned()
+        .or_else(|| {
+            candidates
+                .iter()
+                .find_map(|candidate| find_base(candidate, depth - 1, lexicon))
+        })
+}
+
+/// All plausible stems of a word, one suffix removed
+fn base_candidates(word: &str) -> Vec<String> {
+    let mut candidates = Vec::new();
+    for &(suffix, replacement) in SUFFIX_RULES {
+        let Some(stem) = word.strip_suffix(suffix) else {
+            continue;
+        };
+        // "ss" endings are not plurals, e.g. "glass"
+        if suffix == "s" && stem.ends_with('s') {
+            continue;
+        }
+        if stem.chars().count() < MIN_STEM_LEN {
+            continue;
+        }
+
+        candidates.push(format!("{}{}", stem, replacement));
+        if replacement.is_empty() && UNDOUBLING_SUFFIXES.contains(&suffix) {
+            if let Some(undoubled) = undouble(stem) {
+                candidates.push(undoubled.to_string());
+            }
+        }
+    }
+    candidates
+}
+
+/// Remove a doubled final consonant, e.g. "stopp" becomes "stop"
+fn undouble(stem: &str) -> Option<&str> {
+    let mut chars = stem.chars().rev();
+    let last = chars.next()?;
+    let previous = chars.next()?;
+    if last == previous && !"aeiou".contains(last) {
+        Some(&stem[..stem.len() - last.len_utf8()])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inflections() {
+        assert_eq!(lemmatize("emails").as_deref(), Some("email"));
+        assert_eq!(lemmatize("emailing").as_deref(), Some("email"));
+        assert_eq!(lemmatize("Emailed").as_deref(), Some("email"));
+    }
+
+    #[test]
+    fn test_two_suffixes() {
+        assert_eq!(lemmatize("emailers").as_deref(), Some("email"));
+    }
+
+    #[test]
+    fn test_technical_words() {
+        assert_eq!(lemmatize("refactored").as_deref(), Some("refactor"));
+        assert_eq!(lemmatize("refactoring").as_deref(), Some("refactor"));
+        assert_eq!(lemmatize("callbacks").as_deref(), Some("callback"));
+    }
+
+    #[test]
+    fn test_dictionary_words_are_not_lemmatized() {
+        assert_eq!(lemmatize("running"), None);
+        // Listed in the dictionary as it is, so it needs no base form
+        assert_eq!(lemmatize("debuggers"), None);
+    }
+
+    #[test]
+    fn test_undouble() {
+        assert_eq!(undouble("stopp"), Some("stop"));
+        assert_eq!(undouble("fee"), None);
+        assert_eq!(undouble("ab"), None);
+    }
+
+    #[test]
+    fn test_rejects_short_and_non_alphabetic() {
+        assert_eq!(lemmatize("as"), None);
+        assert_eq!(lemmatize("abc123s"), None);
+    }
+}
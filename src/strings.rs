@@ -0,0 +1,219 @@
+use once_cell::sync::Lazy;
+
+use crate::{GibberishDetector, Score, Sensitivity};
+
+/// Shortest run worth scoring, in characters
+const DEFAULT_MIN_LEN: usize = 6;
+
+/// Detector used by [`english_strings`]
+static DEFAULT_DETECTOR: Lazy<GibberishDetector> = Lazy::new(GibberishDetector::new);
+
+/// How a run of text was stored in the bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StringEncoding {
+    /// One byte per character
+    Ascii,
+    /// Two bytes per character, low byte first, as in Windows binaries
+    Utf16Le,
+}
+
+/// A run of English text found in binary data, see [`english_strings`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractedString {
+    /// Byte offset of the run in the input
+    pub offset: usize,
+    /// Length of the run in bytes
+    pub len: usize,
+    /// How the run was stored
+    pub encoding: StringEncoding,
+    /// The decoded text
+    pub text: String,
+    /// English score of the text
+    pub score: Score,
+}
+
+fn is_printable(byte: u8) -> bool {
+    byte.is_ascii_graphic() || byte == b' ' || byte == b'\t'
+}
+
+/// Number of printable ASCII characters stored as UTF-16LE from the start of `bytes`
+fn utf16le_run(bytes: &[u8]) -> usize {
+    bytes
+        .chunks_exact(2)
+        .take_while(|pair| is_printable(pair[0]) && pair[1] == 0)
+        .count()
+}
+
+/// Number of printable ASCII bytes from the start of `bytes`
+fn ascii_run(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|&&byte| is_printable(byte)).count()
+}
+
+/// Iterator over the English runs of text in binary data, see [`english_strings`]
+pub struct EnglishStrings<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    detector: &'a GibberishDetector,
+    min_len: usize,
+    sensitivity: Sensitivity,
+}
+
+impl<'a> EnglishStrings<'a> {
+    pub(crate) fn new(bytes: &'a [u8], detector: &'a GibberishDetector) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            detector,
+            min_len: DEFAULT_MIN_LEN,
+            sensitivity: Sensitivity::Medium,
+        }
+    }
+
+    /// Only consider runs of at least this many characters, 6 by default
+    pub fn min_len(mut self, min_len: usize) -> Self {
+        self.min_len = min_len;
+        self
+    }
+
+    /// Sensitivity used to decide whether a run is English, medium by default
+    pub fn sensitivity(mut self, sensitivity: Sensitivity) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// The next run of printable text long enough to score, English or not
+    fn next_run(&mut self) -> Option<(usize, usize, StringEncoding, String)> {
+        while self.position < self.bytes.len() {
+            let offset = self.position;
+            let rest = &self.bytes[offset..];
+
+            let chars = utf16le_run(rest);
+            if chars >= self.min_len {
+                self.position += chars * 2;
+                let text = rest[..chars * 2]
+                    .iter()
+                    .step_by(2)
+                    .map(|&b| b as char)
+                    .collect();
+                return Some((offset, chars * 2, StringEncoding::Utf16Le, text));
+            }
+
+            let len = ascii_run(rest);
+            if len >= self.min_len {
+                self.position += len;
+                let text = rest[..len].iter().map(|&b| b as char).collect();
+                return Some((offset, len, StringEncoding::Ascii, text));
+            }
+            // A short run may run straight into UTF-16 text, so step one byte
+            // at a time rather than skipping the run
+            self.position += 1;
+        }
+        None
+    }
+}
+
+impl Iterator for EnglishStrings<'_> {
+    type Item = ExtractedString;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((offset, len, encoding, text)) = self.next_run() {
+            let analysis = self.detector.analyze(text.trim(), self.sensitivity);
+            if !analysis.is_gibberish {
+                return Some(ExtractedString {
+                    offset,
+                    len,
+                    encoding,
+                    text,
+                    score: analysis.score,
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Scan arbitrary bytes, such as a binary, memory dump or packet payload, for
+/// runs of printable ASCII or UTF-16LE text, like `strings(1)`, and yield only
+/// the runs that read as English.
+///
+/// Use [`GibberishDetector::english_strings`] to scan with a configured detector.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::{english_strings, StringEncoding};
+///
+/// let mut blob = vec![0x7f, b'E', b'L', b'F', 0x02, 0x01];
+/// blob.extend(b"xK9#qZ!vB2@p\0");
+/// blob.extend(b"could not open the configuration file\0");
+/// blob.extend("access denied".encode_utf16().flat_map(u16::to_le_bytes));
+///
+/// let found: Vec<_> = english_strings(&blob).collect();
+/// assert_eq!(found.len(), 2);
+/// assert_eq!(found[0].text, "could not open the configuration file");
+/// assert_eq!(found[0].offset, 19);
+/// assert_eq!(found[1].encoding, StringEncoding::Utf16Le);
+/// ```
+pub fn english_strings(bytes: &[u8]) -> EnglishStrings<'_> {
+    EnglishStrings::new(bytes, &DEFAULT_DETECTOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runs() {
+        let mut bytes = b"\x00\x01hello world\xff".to_vec();
+        bytes.extend("wide text".encode_utf16().flat_map(u16::to_le_bytes));
+        bytes.extend(b"\x00ab\x00");
+
+        let detector = GibberishDetector::new();
+        let mut strings = EnglishStrings::new(&bytes, &detector);
+        assert_eq!(
+            strings.next_run(),
+            Some((2, 11, StringEncoding::Ascii, "hello world".to_string()))
+        );
+        assert_eq!(
+            strings.next_run(),
+            Some((14, 18, StringEncoding::Utf16Le, "wide text".to_string()))
+        );
+        assert_eq!(strings.next_run(), None);
+    }
+
+    #[test]
+    fn test_short_run_before_utf16() {
+        let mut bytes = b"\0abc".to_vec();
+        bytes.extend(
+            "hello world again"
+                .encode_utf16()
+                .flat_map(u16::to_le_bytes),
+        );
+
+        let detector = GibberishDetector::new();
+        let mut strings = EnglishStrings::new(&bytes, &detector);
+        assert_eq!(
+            strings.next_run(),
+            Some((
+                4,
+                34,
+                StringEncoding::Utf16Le,
+                "hello world again".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_noise_is_skipped() {
+        let bytes = b"\x90\x90qzxv8#kL$ppw0\x00\x12jfkd93kdls\x00";
+        assert_eq!(english_strings(bytes).count(), 0);
+        assert_eq!(english_strings(b"").count(), 0);
+    }
+
+    #[test]
+    fn test_min_len() {
+        let bytes = b"\x00hello\x00";
+        assert_eq!(english_strings(bytes).count(), 0);
+        assert_eq!(english_strings(bytes).min_len(4).count(), 1);
+    }
+}
@@ -0,0 +1,210 @@
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+use crate::dictionary;
+use crate::frequencies::WORD_ZIPF;
+
+/// Zipf value at which a word counts as full evidence of English, reached by
+/// roughly the two thousand most common words
+const COMMON_ZIPF: f64 = 5.0;
+
+/// Zipf value treated as the floor of the frequency list. Words seen only once
+/// in the corpus sit just above it.
+const RARE_ZIPF: f64 = 2.0;
+
+/// Weight of a dictionary word too rare to appear in the frequency list
+const RARE_WORD_WEIGHT: f64 = 0.7;
+
+/// What the dictionary knows about a word, independent of how it was cased
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordInfo {
+    /// Spellings of the word as they appear in the dictionary, e.g. `["Census", "census"]`
+    pub forms: Vec<&'static str>,
+    /// Only capitalised forms exist, so the word is most likely a name
+    pub is_proper_noun: bool,
+    /// An all-caps form exists, e.g. `NASA`
+    pub is_acronym: bool,
+    /// Zipf frequency, log10 of occurrences per billion words, if the word was
+    /// seen in the corpus behind the frequency list. "the" is about 7.8.
+    pub zipf: Option<f64>,
+}
+
+impl WordInfo {
+    /// How much this word counts as evidence that a text is English.
+    ///
+    /// Common words count 1.0, falling with their Zipf value to
+    /// [`RARE_WORD_WEIGHT`] for words that are too rare to be listed, so chance
+    /// hits on obscure entries count less.
+    pub fn weight(&self) -> f64 {
+        zipf_weight(self.zipf)
+    }
+}
+
+fn zipf_weight(zipf: Option<f64>) -> f64 {
+    match zipf {
+        Some(zipf) => {
+            let commonness = ((zipf - RARE_ZIPF) / (COMMON_ZIPF - RARE_ZIPF)).clamp(0.0, 1.0);
+            RARE_WORD_WEIGHT + (1.0 - RARE_WORD_WEIGHT) * commonness
+        }
+        None => RARE_WORD_WEIGHT,
+    }
+}
+
+/// Case-folded index over `dictionary::ENGLISH_WORDS`, keyed by the lowercased word
+static WORD_INDEX: Lazy<HashMap<String, WordInfo>> = Lazy::new(|| {
+    let mut index: HashMap<String, WordInfo> = HashMap::new();
+    for &form in dictionary::ENGLISH_WORDS.iter() {
+        index
+            .entry(fold_case(form))
+            .or_insert_with(|| WordInfo {
+                forms: Vec::new(),
+                is_proper_noun: false,
+                is_acronym: false,
+                zipf: None,
+            })
+            .forms
+            .push(form);
+    }
+
+    for (word, info) in index.iter_mut() {
+        info.zipf = WORD_ZIPF
+            .get(word.as_str())
+            .map(|&tenths| f64::from(tenths) / 10.0);
+        info.forms.sort_unstable();
+        info.is_acronym = info.forms.iter().any(|form| is_all_caps(form));
+        info.is_proper_noun = !info.is_acronym
+            && info
+                .forms
+                .iter()
+                .all(|form| form.chars().next().is_some_and(char::is_uppercase));
+    }
+
+    index
+});
+
+/// Normalise a dictionary entry to its lookup key.
+///
+/// Lowercases the word and drops the homograph number some source lists
+/// append to entries, e.g. `Cove2` becomes `cove`.
+fn fold_case(word: &str) -> String {
+    let stripped = word.trim_end_matches(|c: char| c.is_ascii_digit());
+    let word = if !stripped.is_empty() && stripped.chars().all(char::is_alphabetic) {
+        stripped
+    } else {
+        word
+    };
+    word.to_lowercase()
+}
+
+fn is_all_caps(word: &str) -> bool {
+    word.chars().filter(|c| c.is_alphabetic()).count() >= 2
+        && word.chars().all(|c| !c.is_alphabetic() || c.is_uppercase())
+}
+
+/// Returns true if the word is in the dictionary, ignoring case
+pub(crate) fn contains_word(word: &str) -> bool {
+    if word.chars().any(char::is_uppercase) {
+        WORD_INDEX.contains_key(&word.to_lowercase())
+    } else {
+        WORD_INDEX.contains_key(word)
+    }
+}
+
+/// Frequency weight of a word, or `None` if it is not a known word.
+///
+/// Words in the frequency list count even when the dictionary leaves them out,
+/// which covers short function words such as "is" and "to".
+pub(crate) fn word_weight(word: &str) -> Option<f64> {
+    let word = word.to_lowercase();
+    match WORD_INDEX.get(&word) {
+        Some(info) => Some(info.weight()),
+        None => WORD_ZIPF
+            .get(word.as_str())
+            .map(|&tenths| zipf_weight(Some(f64::from(tenths) / 10.0))),
+    }
+}
+
+/// All lowercased dictionary words
+pub(crate) fn folded_words() -> impl Iterator<Item = &'static str> {
+    WORD_INDEX.keys().map(String::as_str)
+}
+
+/// Look up a word in the dictionary, ignoring case.
+///
+/// The returned [`WordInfo`] keeps the original spellings, so callers can
+/// still tell names and acronyms apart from ordinary words.
+///
+/// # Examples
+///
+/// ```
+/// use gibberish_or_not::lookup_word;
+///
+/// let census = lookup_word("census").unwrap();
+/// assert!(census.forms.contains(&"Census"));
+///
+/// assert!(lookup_word("NASA").unwrap().is_acronym);
+/// assert!(lookup_word("xqzvbt").is_none());
+/// ```
+pub fn lookup_word(word: &str) -> Option<WordInfo> {
+    WORD_INDEX.get(&word.to_lowercase()).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_is_case_insensitive() {
+        for word in ["Census", "census", "CENSUS", "seabed", "Clown"] {
+            assert!(lookup_word(word).is_some(), "{} should be found", word);
+            assert!(contains_word(word));
+        }
+    }
+
+    #[test]
+    fn test_lookup_keeps_original_forms() {
+        let info = lookup_word("seabed").unwrap();
+        assert!(info.forms.contains(&"Seabed"));
+    }
+
+    #[test]
+    fn test_acronym_flag() {
+        let info = lookup_word("fbi").unwrap();
+        assert!(info.is_acronym);
+        assert!(!info.is_proper_noun);
+    }
+
+    #[test]
+    fn test_homograph_numbers_are_folded() {
+        assert_eq!(fold_case("Cove2"), "cove");
+        assert_eq!(fold_case("Test1"), "test");
+        assert_eq!(fold_case("123"), "123");
+        assert!(lookup_word("cove").unwrap().forms.contains(&"Cove2"));
+    }
+
+    #[test]
+    fn test_common_words_outweigh_rare_ones() {
+        let the = lookup_word("the").unwrap();
+        assert!(the.zipf.unwrap() > 7.0);
+        assert_eq!(the.weight(), 1.0);
+
+        let baldpate = lookup_word("baldpate").unwrap();
+        assert!(baldpate.zipf.is_none());
+        assert_eq!(baldpate.weight(), RARE_WORD_WEIGHT);
+        assert_eq!(word_weight("Baldpate"), Some(RARE_WORD_WEIGHT));
+    }
+
+    #[test]
+    fn test_weights_follow_frequency() {
+        let weight = |word| lookup_word(word).unwrap().weight();
+        assert!(weight("house") > weight("elephant"));
+        assert!(weight("elephant") > weight("baldpate"));
+        assert!(weight("elephant") < 1.0);
+    }
+
+    #[test]
+    fn test_unknown_word() {
+        assert!(lookup_word("qwxzvb").is_none());
+        assert!(!contains_word("qwxzvb"));
+    }
+}
@@ -0,0 +1,282 @@
+#!/usr/bin/env python3
+"""Rebuild the plain text corpus described in SOURCES.md.
+
+Usage: build_corpus.py [options] <output-dir>
+
+Every source is pinned to a version, so the output matches the corpus the
+committed counts were taken from. Crates are read from a directory of
+`.crate` files when one is given (such as `~/.cargo/registry/cache/*/`) and
+downloaded from crates.io otherwise; the Node.js, Vim and Python sources are
+downloaded unless a local copy is given. The Rust documentation is read from
+the `rust-docs` component of the pinned toolchain, installed with rustup.
+
+Then count the corpus with
+
+    cargo run --bin dictionary -- --count-words <output-dir> wordlists/frequencies/english.txt
+    cargo run --bin dictionary -- --count-bigrams <output-dir> wordlists/bigrams/english.txt
+"""
+
+import argparse
+import glob
+import html
+import io
+import os
+import re
+import subprocess
+import tarfile
+import urllib.request
+
+HERE = os.path.dirname(os.path.abspath(__file__))
+
+RUST_VERSION = "1.95.0"
+NODE_VERSION = "20.20.2"
+VIM_VERSION = "9.0.1378"
+PYTHON_VERSION = "3.13.0"
+
+RUST_BOOKS = [
+    "book",
+    "nomicon",
+    "reference",
+    "rust-by-example",
+    "edition-guide",
+    "embedded-book",
+    "cargo",
+    "rustc",
+    "rustdoc",
+]
+RUST_LIBRARIES = ["std", "core", "alloc", "proc_macro"]
+
+README = re.compile(r"(?i)readme(\.(md|markdown|txt|rst|adoc))?$")
+
+
+def fetch(url):
+    print("Downloading", url)
+    with urllib.request.urlopen(url) as response:
+        return response.read()
+
+
+class Crates:
+    """Files from published crates, read from a local cache if there is one"""
+
+    def __init__(self, cache):
+        self.cache = cache
+
+    def open(self, name, version):
+        file_name = f"{name}-{version}.crate"
+        if self.cache and os.path.exists(os.path.join(self.cache, file_name)):
+            return tarfile.open(os.path.join(self.cache, file_name))
+        url = f"https://static.crates.io/crates/{name}/{file_name}"
+        return tarfile.open(fileobj=io.BytesIO(fetch(url)))
+
+    def read(self, name, version, path):
+        with self.open(name, version) as crate:
+            return crate.extractfile(f"{name}-{version}/{path}").read()
+
+
+def text(data):
+    s = data.decode("utf-8", errors="replace")
+    return s.replace("\r\n", "\n").replace("\r", "\n")
+
+
+def gutenberg(s):
+    """Drop the Project Gutenberg header and licence"""
+    s = s.lstrip("﻿")
+    m = re.search(r"\*\*\* ?START OF[^\n]*\n", s)
+    if m:
+        s = s[m.end() :]
+    m = re.search(r"(\*\*\* ?END OF|End of (the )?Project Gutenberg|End of Project Gutenberg)", s)
+    if m:
+        s = s[: m.start()]
+    return s
+
+
+def clean_markdown(s):
+    """Drop code, links, markup and URLs from Markdown"""
+    s = re.sub(r"```.*?```", "\n", s, flags=re.S)
+    s = re.sub(r"~~~.*?~~~", "\n", s, flags=re.S)
+    s = re.sub(r"(?m)^(    |\t).*$", "", s)
+    s = re.sub(r"<!--.*?-->", " ", s, flags=re.S)
+    s = re.sub(r"`[^`\n]*`", " ", s)
+    s = re.sub(r"!?\[([^\]]*)\]\([^)]*\)", r"\1", s)
+    s = re.sub(r"(?m)^\[[^\]]*\]:.*$", "", s)
+    s = re.sub(r"https?://\S+", " ", s)
+    s = re.sub(r"<[^>]+>", " ", s)
+    s = re.sub(r"(?m)^\s*[#>*+|-]+", "", s)
+    return html.unescape(s)
+
+
+def clean_html(s):
+    """Drop code and tags from rendered documentation, ending each block with a full stop"""
+    s = re.sub(r"<pre.*?</pre>", "\n", s, flags=re.S)
+    s = re.sub(r"<code.*?</code>", " ", s, flags=re.S)
+    s = re.sub(r"</(p|h\d|li|div|td|th|dd|dt|blockquote)>", ".\n", s)
+    s = re.sub(r"<[^>]+>", " ", s)
+    return html.unescape(s)
+
+
+def books(crates):
+    """Books and articles that crates carry as test or benchmark data"""
+    finl = "resources/benchmark-texts"
+    snappy = "deps/snappy-1.1.7/testdata"
+    yield "sherlock-holmes", gutenberg(text(crates.read("aho-corasick", "0.5.3", "benches/sherlock.txt")))
+    yield "frankenstein", gutenberg(text(crates.read("finl_unicode", "1.2.0", f"{finl}/84-0.txt")))
+    yield "english-language-wikipedia", text(crates.read("finl_unicode", "1.2.0", f"{finl}/english.txt"))
+    yield "around-the-world-in-80-days", gutenberg(text(crates.read("esaxx-rs", "0.1.10", "data/eighty.txt")))
+    yield "alice-in-wonderland", gutenberg(text(crates.read("leveldb-sys", "2.0.8", f"{snappy}/alice29.txt")))
+    yield "workshop-on-electronic-texts", text(crates.read("leveldb-sys", "2.0.8", f"{snappy}/lcet10.txt"))
+    yield "the-moonstone", gutenberg(text(crates.read("htmlescape", "0.3.1", "moonstone-short.txt")))
+    tom_sawyer = text(crates.read("backtrace-sys", "0.1.37", "src/libbacktrace/Mark.Twain-Tom.Sawyer.txt"))
+    end = tom_sawyer.find("End of the Project Gutenberg")
+    yield "tom-sawyer", tom_sawyer[:end] if end >= 0 else tom_sawyer
+    yield "pro-git", clean_markdown(text(crates.read("comrak", "0.4.4", "script/progit.md")))
+    for name, title in [
+        ("computer-science", "Computer science"),
+        ("part-of-speech-tagging", "Part-of-speech tagging"),
+        ("us-constitution", "The Constitution of the United States"),
+        ("the-great-gatsby", "The Great Gatsby"),
+    ]:
+        yield name, text(crates.read("harper-core", "0.59.0", f"tests/text/{title}.md"))
+
+
+def crate_readmes(crates):
+    """README files of the crates listed in crates.txt, skipping duplicates"""
+    readmes = []
+    with open(os.path.join(HERE, "crates.txt")) as listing:
+        for line in listing:
+            name, version = line.split()
+            root = f"{name}-{version}/"
+            with crates.open(name, version) as crate:
+                for member in crate.getmembers():
+                    path = member.name[len(root) :]
+                    if member.isfile() and "/" not in path and README.match(path):
+                        readmes.append((root + path, text(crate.extractfile(member).read())))
+
+    out = []
+    seen = set()
+    for _, s in sorted(readmes):
+        if len(s) > 400000 or s[:2000] in seen:
+            continue
+        seen.add(s[:2000])
+        out.append(clean_markdown(s))
+    return "\n".join(out)
+
+
+def rust_docs(root):
+    """Prose of the Rust books and the standard library documentation"""
+    for book in RUST_BOOKS:
+        out = []
+        for path in sorted(glob.glob(f"{root}/{book}/**/*.html", recursive=True)):
+            rel = os.path.relpath(path, f"{root}/{book}")
+            if rel.split("/")[0] in ("first-edition", "second-edition", "2018-edition"):
+                continue
+            if rel.endswith(("print.html", "toc.html", "404.html")):
+                continue
+            with open(path, encoding="utf-8", errors="replace") as page:
+                m = re.search(r"<main[^>]*>(.*)</main>", page.read(), re.S)
+            if m:
+                out.append(clean_html(m.group(1)))
+        yield f"rust-{book}", "\n".join(out)
+
+    out = []
+    seen = set()
+    for library in RUST_LIBRARIES:
+        for path in sorted(glob.glob(f"{root}/{library}/**/*.html", recursive=True)):
+            with open(path, encoding="utf-8", errors="replace") as page:
+                s = page.read()
+            for m in re.finditer(r'<div class="docblock[^"]*">(.*?)</div>', s, re.S):
+                block = clean_html(m.group(1))
+                if block not in seen:
+                    seen.add(block)
+                    out.append(block)
+    yield "rust-std-docs", "\n".join(out)
+
+
+def rust_docs_root():
+    toolchain = f"+{RUST_VERSION}"
+    subprocess.run(["rustup", "toolchain", "install", RUST_VERSION, "--profile", "minimal", "--component", "rust-docs"], check=True)
+    sysroot = subprocess.run(["rustc", toolchain, "--print", "sysroot"], check=True, capture_output=True, text=True)
+    return os.path.join(sysroot.stdout.strip(), "share/doc/rust/html")
+
+
+def github_files(repo, tag, directory, suffix):
+    """Files directly inside one directory of a tagged GitHub source archive"""
+    archive = fetch(f"https://github.com/{repo}/archive/refs/tags/{tag}.tar.gz")
+    files = {}
+    with tarfile.open(fileobj=io.BytesIO(archive)) as tar:
+        for member in tar.getmembers():
+            parent, _, name = member.name.partition("/")[2].rpartition("/")
+            if member.isfile() and parent == directory and name.endswith(suffix):
+                files[name] = text(tar.extractfile(member).read())
+    return files
+
+
+def local_files(directory, suffix):
+    files = {}
+    for path in glob.glob(f"{directory}/*{suffix}"):
+        with open(path, "rb") as f:
+            files[os.path.basename(path)] = text(f.read())
+    return files
+
+
+def node_docs(files):
+    return "\n".join(clean_markdown(files[name]) for name in sorted(files))
+
+
+def vim_docs(files):
+    out = []
+    for name in sorted(files):
+        s = re.sub(r"(?m)^(\t| {4,}).*$", "", files[name])
+        out.append(re.sub(r"[|*`][^|*`\s]+[|*`]", " ", s))
+    return "\n".join(out)
+
+
+def python_docs(source):
+    namespace = {}
+    exec(source, namespace)
+    return "\n".join(re.sub(r"(?m)^( {3,}).*$", "", topic) for topic in namespace["topics"].values())
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("output", help="directory to write the text files to")
+    parser.add_argument("--crates", help="directory of downloaded .crate files")
+    parser.add_argument("--rust-docs", help=f"share/doc/rust/html of Rust {RUST_VERSION}")
+    parser.add_argument("--node-docs", help=f"doc/api of Node.js {NODE_VERSION}")
+    parser.add_argument("--vim-docs", help=f"runtime/doc of Vim {VIM_VERSION}")
+    parser.add_argument("--python-topics", help=f"Lib/pydoc_data/topics.py of Python {PYTHON_VERSION}")
+    args = parser.parse_args()
+
+    crates = Crates(args.crates)
+    texts = dict(books(crates))
+    texts["crate-readmes"] = crate_readmes(crates)
+    texts.update(rust_docs(args.rust_docs or rust_docs_root()))
+
+    if args.node_docs:
+        node = local_files(args.node_docs, ".md")
+    else:
+        node = github_files("nodejs/node", f"v{NODE_VERSION}", "doc/api", ".md")
+    texts["nodejs-docs"] = node_docs(node)
+
+    if args.vim_docs:
+        vim = local_files(args.vim_docs, ".txt")
+    else:
+        vim = github_files("vim/vim", f"v{VIM_VERSION}", "runtime/doc", ".txt")
+    texts["vim-docs"] = vim_docs(vim)
+
+    if args.python_topics:
+        with open(args.python_topics, "rb") as f:
+            topics = text(f.read())
+    else:
+        url = f"https://raw.githubusercontent.com/python/cpython/v{PYTHON_VERSION}/Lib/pydoc_data/topics.py"
+        topics = text(fetch(url))
+    texts["python-docs"] = python_docs(topics)
+
+    os.makedirs(args.output, exist_ok=True)
+    for name, s in texts.items():
+        with open(os.path.join(args.output, f"{name}.txt"), "w", encoding="utf-8") as out:
+            out.write(s)
+        print("Wrote", name)
+
+
+if __name__ == "__main__":
+    main()